@@ -7,7 +7,7 @@ set -euo pipefail
 # Saves a few seconds for large crates
 export CARGO_INCREMENTAL=0
 
-FEATURES="--features v${VERSION//./_}"
+FEATURES="--features v${VERSION//./_},apply-configurations,arbitrary,borrowed,builder,cbor,content-hash,cron,describe,deserialize-in-place,deserialize-with-path,erased-serde,gateway-api,manifests,protobuf-envelope,reflection,schemars-rust-names,shallow,test_util"
 if [ "$WITHOUT_API_FEATURE" = 'yes' ]; then
 	FEATURES="--no-default-features $FEATURES"
 fi
@@ -17,6 +17,15 @@ case "$OP" in
 		cargo clippy $FEATURES -- -D warnings
 		;;
 
+	'clippy-without-chrono')
+		# Disables the default `chrono` feature, and leaves out the features that require it.
+		FEATURES="--no-default-features --features v${VERSION//./_},apply-configurations,arbitrary,borrowed,builder,cbor,content-hash,describe,deserialize-in-place,deserialize-with-path,erased-serde,gateway-api,manifests,protobuf-envelope,reflection,schemars-rust-names,shallow"
+		if [ "$WITHOUT_API_FEATURE" = 'no' ]; then
+			FEATURES="$FEATURES,api"
+		fi
+		cargo clippy $FEATURES -- -D warnings
+		;;
+
 	'doc')
 		cargo doc --no-deps $FEATURES
 		;;
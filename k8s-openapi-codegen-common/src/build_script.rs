@@ -0,0 +1,212 @@
+//! Generating types from an OpenAPI spec in the build script of a crate, without having to fork the `k8s-openapi` code generator.
+//!
+//! ```rust,no_run
+//! // build.rs
+//! struct MapNamespace;
+//!
+//! impl k8s_openapi_codegen_common::MapNamespace for MapNamespace {
+//!     fn map_namespace<'a>(&self, path_parts: &[&'a str]) -> Option<Vec<&'a str>> {
+//!         match path_parts {
+//!             ["com", "github", "openshift", "api", rest @ ..] => Some(std::iter::once("crate").chain(rest.iter().copied()).collect()),
+//!             ["io", "k8s", rest @ ..] => Some(std::iter::once("k8s_openapi").chain(rest.iter().copied()).collect()),
+//!             _ => None,
+//!         }
+//!     }
+//! }
+//!
+//! let out_dir: std::path::PathBuf = std::env::var_os("OUT_DIR").unwrap().into();
+//! k8s_openapi_codegen_common::build_script::generate(
+//!     "openshift-swagger.json".as_ref(),
+//!     &out_dir.join("openshift.rs"),
+//!     &MapNamespace,
+//!     &[
+//!         k8s_openapi_codegen_common::fixups::resource_metadata_not_optional,
+//!         k8s_openapi_codegen_common::fixups::scale_subresource,
+//!     ],
+//!     &k8s_openapi_codegen_common::DefaultTemplateProvider,
+//! ).unwrap();
+//! ```
+//!
+//! ```rust,ignore
+//! // lib.rs
+//! include!(concat!(env!("OUT_DIR"), "/openshift.rs"));
+//! ```
+
+use crate::swagger20;
+
+/// Generates the types of the definitions of the OpenAPI spec JSON file at `spec_path`, and writes them to the file at `out_file`.
+///
+/// Only the definitions that `map_namespace` maps to a path under `crate` are generated. The other definitions, like the `io.k8s` definitions
+/// when they're mapped to `k8s_openapi`, are expected to already exist at the path they are mapped to.
+/// The API operations of the generated definitions are generated with them, and the other operations are skipped.
+///
+/// The file contains a tree of modules that mirrors the paths that the definitions are mapped to, like `pub mod apps { pub mod v1 { ... } }`
+/// for `crate::apps::v1`. It must be `include!`d at the root of the crate, since the generated code refers to the types by their paths from the crate root.
+/// The file is only rewritten if its contents changed, and the build script is set to rerun if the spec file changes.
+///
+/// The generated code refers to the traits and common types of the `k8s-openapi` crate through the path that `map_namespace` maps `io.k8s` to,
+/// so the crate must depend on it. The generated API operations also require its `api` feature to be enabled.
+///
+/// # Parameters
+///
+/// - `spec_path`: The path of the OpenAPI spec JSON file.
+///
+/// - `out_file`: The path of the file that the generated code will be written to, usually under the `OUT_DIR` directory of the build script.
+///
+/// - `map_namespace`: An instance of the [`MapNamespace`](crate::MapNamespace) trait that controls how OpenAPI namespaces of the definitions are mapped to rust namespaces.
+///
+/// - `fixups`: The [`Fixup`](crate::fixups::Fixup)s to apply to the spec before generating the types, in order.
+///
+/// - `template_provider`: An instance of the [`TemplateProvider`](crate::TemplateProvider) trait that can customize the code emitted for the types.
+pub fn generate(
+	spec_path: &std::path::Path,
+	out_file: &std::path::Path,
+	map_namespace: &impl crate::MapNamespace,
+	fixups: &[crate::fixups::Fixup],
+	template_provider: &impl crate::TemplateProvider,
+) -> Result<crate::RunResult, crate::Error> {
+	println!("cargo:rerun-if-changed={}", spec_path.display());
+
+	let mut spec: swagger20::Spec = {
+		let spec_file = std::fs::File::open(spec_path).map_err(|err| format!("could not open {}: {err}", spec_path.display()))?;
+		serde_json::from_reader(std::io::BufReader::new(spec_file)).map_err(|err| format!("could not parse {}: {err}", spec_path.display()))?
+	};
+
+	for fixup in fixups {
+		fixup(&mut spec)?;
+	}
+
+	let mut run_result = crate::RunResult {
+		num_generated_structs: 0,
+		num_generated_type_aliases: 0,
+		num_generated_apis: 0,
+	};
+
+	let mut root = Module::default();
+
+	for definition_path in spec.definitions.keys() {
+		let path_parts: Vec<_> = definition_path.split('.').collect();
+		match map_namespace.map_namespace(&path_parts) {
+			Some(namespace_parts) if namespace_parts.first() == Some(&"crate") => (),
+			_ => continue,
+		}
+
+		let mut state = RunState::default();
+
+		let type_run_result = crate::run(
+			&spec.definitions,
+			&mut spec.operations,
+			definition_path,
+			map_namespace,
+			&crate::RunOptions::default().template_provider(template_provider),
+			&mut state,
+		)?;
+
+		run_result.num_generated_structs += type_run_result.num_generated_structs;
+		run_result.num_generated_type_aliases += type_run_result.num_generated_type_aliases;
+		run_result.num_generated_apis += type_run_result.num_generated_apis;
+
+		// Skip the leading `crate` and the trailing type name.
+		let mut module = &mut root;
+		for part in state.parts.iter().skip(1).rev().skip(1).rev() {
+			module = module.modules.entry(crate::get_rust_ident(part).into_owned()).or_default();
+		}
+		module.types.push(state);
+	}
+
+	let mut contents = vec![];
+	root.write(&mut contents)?;
+
+	match std::fs::read(out_file) {
+		Ok(existing_contents) if existing_contents == contents => (),
+		_ => std::fs::write(out_file, contents).map_err(|err| format!("could not write {}: {err}", out_file.display()))?,
+	}
+
+	Ok(run_result)
+}
+
+/// A module of the generated code, and the modules and types in it.
+#[derive(Default)]
+struct Module {
+	modules: std::collections::BTreeMap<String, Module>,
+	types: Vec<RunState>,
+}
+
+impl Module {
+	fn write(&self, out: &mut Vec<u8>) -> std::io::Result<()> {
+		use std::io::Write;
+
+		for (mod_name, module) in &self.modules {
+			writeln!(out, "pub mod {mod_name} {{")?;
+			module.write(out)?;
+			writeln!(out, "}}")?;
+			writeln!(out)?;
+		}
+
+		for RunState { parts, operation_types, additional_types, builder_types, contents } in &self.types {
+			let type_name = parts.last().map_or("", String::as_str);
+			let mod_name = crate::get_rust_ident(type_name);
+
+			// The generated code of a type uses its deprecated fields, and the re-exports use the type itself if it's deprecated.
+			writeln!(out, "#[allow(deprecated)]")?;
+			writeln!(out, "mod {mod_name} {{")?;
+			out.extend_from_slice(contents);
+			writeln!(out, "}}")?;
+
+			write!(out, "#[allow(deprecated)]\npub use self::{mod_name}::{{{type_name}")?;
+			for re_exported_type_name in additional_types.iter().chain(builder_types).chain(operation_types) {
+				write!(out, ", {re_exported_type_name}")?;
+			}
+			writeln!(out, "}};")?;
+			writeln!(out)?;
+		}
+
+		Ok(())
+	}
+}
+
+/// The code generated for a single type, and the names of the types generated along with it that its module re-exports.
+#[derive(Default)]
+struct RunState {
+	parts: Vec<String>,
+	operation_types: Vec<String>,
+	additional_types: Vec<String>,
+	builder_types: Vec<String>,
+	contents: Vec<u8>,
+}
+
+impl crate::RunState for RunState {
+	type Writer = Vec<u8>;
+
+	fn make_writer(
+		&mut self,
+		parts: &[&str],
+		_type_feature: Option<&str>,
+	) -> std::io::Result<Self::Writer> {
+		self.parts = parts.iter().map(|&part| part.to_owned()).collect();
+		Ok(vec![])
+	}
+
+	fn handle_operation_types(
+		&mut self,
+		operation_optional_parameters_name: Option<&str>,
+		operation_result_name: Option<&str>,
+	) -> std::io::Result<()> {
+		self.operation_types.extend(operation_optional_parameters_name.into_iter().chain(operation_result_name).map(ToOwned::to_owned));
+		Ok(())
+	}
+
+	fn handle_additional_type(&mut self, additional_type_name: &str) -> std::io::Result<()> {
+		self.additional_types.push(additional_type_name.to_owned());
+		Ok(())
+	}
+
+	fn handle_builder_type(&mut self, builder_type_name: &str, _builder_feature: Option<&str>) -> std::io::Result<()> {
+		self.builder_types.push(builder_type_name.to_owned());
+		Ok(())
+	}
+
+	fn finish(&mut self, writer: Self::Writer) {
+		self.contents = writer;
+	}
+}
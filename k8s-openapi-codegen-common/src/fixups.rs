@@ -0,0 +1,319 @@
+//! Fixups that a code generator applies to a [`swagger20::Spec`](crate::swagger20::Spec) before invoking [`run`](crate::run).
+//!
+//! The generated code of a resource type implements `k8s_openapi` traits that depend on information the spec does not associate
+//! with the resource's definition, like the type of its `scale` subresource. These fixups find that information and record it in the definition,
+//! so code generators for other specs get the same impls as the types of `k8s-openapi` itself.
+//!
+//! Code generators can also define fixups of their own for the quirks of their specs, as functions of the [`Fixup`] type.
+
+use crate::swagger20;
+
+/// A function that modifies a spec before code is generated from it, such as to work around a bug in the spec.
+///
+/// The fixups of this module are of this type, so a code generator can keep a list of its own fixups and these fixups together.
+pub type Fixup = fn(&mut swagger20::Spec) -> Result<(), crate::Error>;
+
+/// Marks the `metadata` property of resource types as required.
+///
+/// The `metadata` property of resource types is generally not optional, and [`run`](crate::run) expects it to be required
+/// so that it can implement `k8s_openapi::Metadata` for the type. For situations like PATCH requests where the property *is* optional,
+/// sending an empty object works the same.
+pub fn resource_metadata_not_optional(spec: &mut swagger20::Spec) -> Result<(), crate::Error> {
+	for definition in spec.definitions.values_mut() {
+		if let swagger20::SchemaKind::Properties(properties) = &mut definition.kind {
+			let has_api_version = properties.contains_key(&swagger20::PropertyName("apiVersion".to_owned()));
+			let has_kind = properties.contains_key(&swagger20::PropertyName("kind".to_owned()));
+			if has_api_version && has_kind {
+				if let Some((_, required)) = properties.get_mut(&swagger20::PropertyName("metadata".to_owned())) {
+					*required = true;
+				}
+			}
+		}
+	}
+
+	Ok(())
+}
+
+/// Records the type of the `scale` subresource of each resource in [`swagger20::Schema::scale_definition_path`],
+/// so that [`run`](crate::run) implements `k8s_openapi::Scalable` for the resource.
+///
+/// The operations of the subresource are associated with the subresource's type, not the resource's, so the code generator would not know about it
+/// when generating the resource. So this finds the resource of each `scale` subresource from the path of the subresource's read operation.
+///
+/// Resources whose read operation doesn't exist, like extensions/v1beta1's ReplicationControllerDummy, are skipped.
+pub fn scale_subresource(spec: &mut swagger20::Spec) -> Result<(), crate::Error> {
+	let scale_definition_paths = subresource_definition_paths(spec, "/scale", swagger20::KubernetesAction::Get)?;
+	for (definition_path, scale_definition_path) in scale_definition_paths {
+		let definition = spec.definitions.get_mut(&definition_path).ok_or_else(|| format!("could not find definition {definition_path}"))?;
+		definition.scale_definition_path = Some(scale_definition_path);
+	}
+
+	Ok(())
+}
+
+/// Records the type of the `eviction` subresource of each resource in [`swagger20::Schema::eviction_definition_path`],
+/// so that [`run`](crate::run) implements `k8s_openapi::Evictable` for the resource.
+///
+/// As with [`scale_subresource`], this finds the resource of each `eviction` subresource from the path of the subresource's create operation.
+pub fn eviction_subresource(spec: &mut swagger20::Spec) -> Result<(), crate::Error> {
+	let eviction_definition_paths = subresource_definition_paths(spec, "/eviction", swagger20::KubernetesAction::Post)?;
+	for (definition_path, eviction_definition_path) in eviction_definition_paths {
+		let definition = spec.definitions.get_mut(&definition_path).ok_or_else(|| format!("could not find definition {definition_path}"))?;
+		definition.eviction_definition_path = Some(eviction_definition_path);
+	}
+
+	Ok(())
+}
+
+/// The definition paths of each resource that has the subresource with the given path suffix, and of the subresource's type.
+fn subresource_definition_paths(
+	spec: &swagger20::Spec,
+	path_suffix: &str,
+	kubernetes_action: swagger20::KubernetesAction,
+) -> Result<Vec<(swagger20::DefinitionPath, swagger20::DefinitionPath)>, crate::Error> {
+	fn find_definition_path(
+		definitions: &std::collections::BTreeMap<swagger20::DefinitionPath, swagger20::Schema>,
+		kubernetes_group_kind_version: &swagger20::KubernetesGroupKindVersion,
+	) -> Result<swagger20::DefinitionPath, crate::Error> {
+		let mut definition_paths =
+			definitions.iter()
+			.filter(|(_, definition)| definition.kubernetes_group_kind_versions == std::slice::from_ref(kubernetes_group_kind_version))
+			.map(|(definition_path, _)| definition_path);
+		match (definition_paths.next(), definition_paths.next()) {
+			(Some(definition_path), None) => Ok(definition_path.clone()),
+			_ => Err(format!("could not find a single definition for {kubernetes_group_kind_version:?}").into()),
+		}
+	}
+
+	let mut result = vec![];
+
+	for operation in &spec.operations {
+		if operation.kubernetes_action != Some(kubernetes_action) {
+			continue;
+		}
+
+		let Some(resource_path) = operation.path.strip_suffix(path_suffix) else { continue; };
+		if !resource_path.ends_with("/{name}") {
+			continue;
+		}
+
+		let Some(resource_kubernetes_group_kind_version) =
+			spec.operations.iter()
+			.find(|o| o.kubernetes_action == Some(swagger20::KubernetesAction::Get) && &*o.path == resource_path)
+			.and_then(|o| o.kubernetes_group_kind_version.as_ref())
+			else { continue; };
+
+		let subresource_kubernetes_group_kind_version =
+			operation.kubernetes_group_kind_version.as_ref()
+			.ok_or_else(|| format!("operation {} does not have a group-version-kind", operation.id))?;
+
+		result.push((
+			find_definition_path(&spec.definitions, resource_kubernetes_group_kind_version)?,
+			find_definition_path(&spec.definitions, subresource_kubernetes_group_kind_version)?,
+		));
+	}
+
+	Ok(result)
+}
+
+/// Makes the `date-time` strings of the spec plain strings, so that the generated code does not depend on `chrono`.
+///
+/// [`run`](crate::run) generates `chrono::DateTime<chrono::Utc>` for `date-time` strings, such as the inner values of
+/// `io.k8s.apimachinery.pkg.apis.meta.v1.Time` and `MicroTime`. With this fixup, those are generated as newtypes of `String` instead,
+/// which hold the RFC 3339 timestamps as they were received and are serialized as-is. Together with the `k8s-openapi` crate built without
+/// its default `chrono` feature, this lets the code generator's crate be built for targets like `wasm32-unknown-unknown` without `chrono`.
+/// Parsing and formatting the timestamps, such as with the `time` crate, is left to the crate's users.
+///
+/// The JSON schemas of the generated types describe the strings without their `date-time` format.
+pub fn date_time_as_string(spec: &mut swagger20::Spec) -> Result<(), crate::Error> {
+	fn visit(kind: &mut swagger20::SchemaKind) {
+		match kind {
+			swagger20::SchemaKind::Properties(properties) =>
+				for (schema, _) in properties.values_mut() {
+					visit(&mut schema.kind);
+				},
+
+			swagger20::SchemaKind::Ty(swagger20::Type::String { format }) =>
+				if let Some(swagger20::StringFormat::DateTime) = format {
+					*format = None;
+				},
+
+			swagger20::SchemaKind::Ty(swagger20::Type::Array { items: schema } | swagger20::Type::Object { additional_properties: schema }) =>
+				visit(&mut schema.kind),
+
+			swagger20::SchemaKind::Ty(
+				swagger20::Type::CreateOptional(properties) |
+				swagger20::Type::DeleteOptional(properties) |
+				swagger20::Type::ListOptional(properties) |
+				swagger20::Type::PatchOptional(properties) |
+				swagger20::Type::ReplaceOptional(properties) |
+				swagger20::Type::WatchOptional(properties)
+			) =>
+				for schema in properties.values_mut() {
+					visit(&mut schema.kind);
+				},
+
+			swagger20::SchemaKind::Ref(_) |
+			swagger20::SchemaKind::Ty(_) => (),
+		}
+	}
+
+	for definition in spec.definitions.values_mut() {
+		visit(&mut definition.kind);
+	}
+
+	Ok(())
+}
+
+/// Removes the definitions of the API groups under `io.k8s.api` that are not in `api_groups`, and the API operations of the removed definitions.
+///
+/// The API groups are named by their components of the definition paths, like `core` and `apps` for `io.k8s.api.core.v1` and `io.k8s.api.apps.v1`,
+/// which are also the names of their modules in the generated code. The definitions outside `io.k8s.api`, like those of apimachinery, are kept.
+/// So are the definitions of other API groups that the kept definitions and operations refer to, like `core` for `apps` since
+/// a `Deployment` has a `PodTemplateSpec`, so that the generated code still compiles.
+///
+/// Unlike the other fixups, this takes the API groups as a parameter, so code generators call it directly after applying the others.
+/// It fails if any of the API groups does not exist in the spec.
+pub fn retain_api_groups(spec: &mut swagger20::Spec, api_groups: &[&str]) -> Result<(), crate::Error> {
+	fn api_group(definition_path: &swagger20::DefinitionPath) -> Option<&str> {
+		let mut parts = definition_path.strip_prefix("io.k8s.api.")?.split('.');
+		let api_group = parts.next()?;
+		parts.next().map(|_| api_group)
+	}
+
+	fn add_references<'a>(kind: &'a swagger20::SchemaKind, references: &mut Vec<&'a str>) {
+		match kind {
+			swagger20::SchemaKind::Properties(properties) =>
+				for (schema, _) in properties.values() {
+					add_references(&schema.kind, references);
+				},
+
+			swagger20::SchemaKind::Ref(ref_path) |
+			swagger20::SchemaKind::Ty(swagger20::Type::WatchEvent(ref_path)) => references.push(&ref_path.path),
+
+			swagger20::SchemaKind::Ty(swagger20::Type::Array { items: schema } | swagger20::Type::Object { additional_properties: schema }) =>
+				add_references(&schema.kind, references),
+
+			swagger20::SchemaKind::Ty(swagger20::Type::ListDef { metadata: kind } | swagger20::Type::ListRef { items: kind }) =>
+				add_references(kind, references),
+
+			swagger20::SchemaKind::Ty(
+				swagger20::Type::CreateOptional(properties) |
+				swagger20::Type::DeleteOptional(properties) |
+				swagger20::Type::ListOptional(properties) |
+				swagger20::Type::PatchOptional(properties) |
+				swagger20::Type::ReplaceOptional(properties) |
+				swagger20::Type::WatchOptional(properties)
+			) =>
+				for schema in properties.values() {
+					add_references(&schema.kind, references);
+				},
+
+			swagger20::SchemaKind::Ty(_) => (),
+		}
+	}
+
+	for &requested_api_group in api_groups {
+		if !spec.definitions.keys().any(|definition_path| api_group(definition_path) == Some(requested_api_group)) {
+			return Err(format!("API group {requested_api_group:?} does not exist in the spec").into());
+		}
+	}
+
+	let mut retained: std::collections::BTreeSet<&str> =
+		spec.definitions.keys()
+		.filter(|definition_path| match api_group(definition_path) {
+			Some(api_group) => api_groups.contains(&api_group),
+			None => true,
+		})
+		.map(|definition_path| &**definition_path)
+		.collect();
+
+	// Operations are kept if they're associated with a kept definition, and the definitions they refer to are kept in turn,
+	// so find both together until neither changes.
+	let mut retained_operations = vec![false; spec.operations.len()];
+	let mut pending: Vec<&str> = retained.iter().copied().collect();
+	loop {
+		while let Some(definition_path) = pending.pop() {
+			let Some(definition) = spec.definitions.get(&swagger20::DefinitionPath(definition_path.to_owned())) else { continue; };
+
+			let mut references = vec![];
+			add_references(&definition.kind, &mut references);
+			references.extend(definition.list_kind.as_deref());
+			references.extend(definition.scale_definition_path.as_deref());
+			references.extend(definition.eviction_definition_path.as_deref());
+
+			for reference in references {
+				if retained.insert(reference) {
+					pending.push(reference);
+				}
+			}
+		}
+
+		let retained_kubernetes_group_kind_versions: std::collections::BTreeSet<_> =
+			spec.definitions.iter()
+			.filter(|(definition_path, _)| retained.contains(&***definition_path))
+			.flat_map(|(_, definition)| &definition.kubernetes_group_kind_versions)
+			.collect();
+
+		for (operation, is_retained) in spec.operations.iter().zip(&mut retained_operations) {
+			if *is_retained {
+				continue;
+			}
+
+			let Some(kubernetes_group_kind_version) = &operation.kubernetes_group_kind_version else { continue; };
+			if !retained_kubernetes_group_kind_versions.contains(kubernetes_group_kind_version) {
+				continue;
+			}
+
+			*is_retained = true;
+
+			let mut references = vec![];
+			for parameter in &operation.parameters {
+				add_references(&parameter.schema.kind, &mut references);
+			}
+			if let swagger20::OperationResponses::Map(responses) = &operation.responses {
+				for schema in responses.values() {
+					add_references(&schema.kind, &mut references);
+				}
+			}
+
+			for reference in references {
+				if retained.insert(reference) {
+					pending.push(reference);
+				}
+			}
+		}
+
+		if pending.is_empty() {
+			break;
+		}
+	}
+
+	// Operations that aren't associated with any definition, like the discovery operations of API groups, are kept
+	// unless they're under the URL path of an API group whose definitions were all removed.
+	let mut groups = std::collections::BTreeSet::new();
+	let mut retained_groups = std::collections::BTreeSet::new();
+	for (definition_path, definition) in &spec.definitions {
+		for kubernetes_group_kind_version in &definition.kubernetes_group_kind_versions {
+			groups.insert(&*kubernetes_group_kind_version.group);
+			if retained.contains(&**definition_path) {
+				retained_groups.insert(&*kubernetes_group_kind_version.group);
+			}
+		}
+	}
+	let removed_group_url_paths: Vec<_> = groups.difference(&retained_groups).map(|group| format!("/apis/{group}/")).collect();
+
+	for (operation, is_retained) in spec.operations.iter().zip(&mut retained_operations) {
+		if operation.kubernetes_group_kind_version.is_none() {
+			*is_retained = !removed_group_url_paths.iter().any(|removed_group_url_path| operation.path.starts_with(&**removed_group_url_path));
+		}
+	}
+
+	let retained: std::collections::BTreeSet<String> = retained.into_iter().map(ToOwned::to_owned).collect();
+	spec.definitions.retain(|definition_path, _| retained.contains(&**definition_path));
+
+	let mut retained_operations = retained_operations.into_iter();
+	spec.operations.retain(|_| retained_operations.next().unwrap_or_default());
+
+	Ok(())
+}
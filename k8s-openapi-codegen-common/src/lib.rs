@@ -24,8 +24,45 @@
 //! such as OpenShift.
 //!
 //! 1. Create a [`swagger20::Spec`] value, either by deserializing it from an OpenAPI spec JSON file or by creating it manually.
-//! 1. Invoke the [`run`] function for each definition in the spec.
+//!    Deserializing it requires the `serde` feature of this crate, and can be done with any serde format crate, like `serde_json::from_reader`.
+//! 1. Apply the [`fixups`] to the spec, along with any [`fixups::Fixup`]s of your own that work around the quirks of your spec.
+//! 1. Invoke the [`run`] function for each definition in the spec, with an impl of [`MapNamespace`] that maps the definitions to Rust paths.
 //! 1. For each left-over API operations, ie those operations that weren't associated with any definition, invoke the [`write_operation`] function.
+//!
+//! With the `build-script` feature enabled, the `build_script::generate` function does all of these in the build script of a crate,
+//! for crates that want to generate types from a spec without writing a code generator of their own.
+//!
+//! The generated code refers to the traits and common types of the `k8s-openapi` crate, like `k8s_openapi::Resource` and `k8s_openapi::ListOptional`,
+//! through the path that [`MapNamespace`] maps the `io.k8s` namespace to. So a crate of generated code that maps it to `k8s_openapi`
+//! depends on `k8s-openapi` and its types interoperate with the types of `k8s-openapi`. For example:
+//!
+//! ```rust
+//! struct MapNamespace;
+//!
+//! impl k8s_openapi_codegen_common::MapNamespace for MapNamespace {
+//!     fn map_namespace<'a>(&self, path_parts: &[&'a str]) -> Option<Vec<&'a str>> {
+//!         match path_parts {
+//!             ["com", "github", "openshift", "api", rest @ ..] => Some(std::iter::once("crate").chain(rest.iter().copied()).collect()),
+//!             ["io", "k8s", rest @ ..] => Some(std::iter::once("k8s_openapi").chain(rest.iter().copied()).collect()),
+//!             _ => None,
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! # Stability
+//!
+//! The public API of this crate follows semver, independently of how much of it the `k8s-openapi` code generator uses.
+//! The options of [`run`] are set through the `#[non_exhaustive]` [`RunOptions`] struct, so that new options can be added in minor releases.
+//! Breaking changes are listed under the `k8s-openapi-codegen-common` heading of each release in the changelog.
+//!
+//! The version of this crate matches the version of `k8s-openapi` whose traits and types the generated code refers to,
+//! so code generated by v0.13 of this crate must be compiled against v0.13 of `k8s-openapi`.
+
+#[cfg(feature = "build-script")]
+pub mod build_script;
+
+pub mod fixups;
 
 pub mod swagger20;
 
@@ -120,6 +157,35 @@ pub trait RunState {
 		operation_result_name: Option<&str>,
 	) -> std::io::Result<()>;
 
+	/// This function is invoked with the name of each additional type that is generated along with a particular type, in the same writer.
+	/// For example, the enum of the variants of a type that is a [`swagger20::Union`].
+	///
+	/// Code generators that are emitting modules can write out `use` lines in the module's `mod.rs` for each of these types.
+	fn handle_additional_type(&mut self, additional_type_name: &str) -> std::io::Result<()> {
+		let _ = additional_type_name;
+		Ok(())
+	}
+
+	/// This function is invoked with the name of the builder type that is generated along with a particular type, in the same writer,
+	/// and the feature that the builder is `cfg`-gated by, if any. See [`GenerateBuilder`].
+	///
+	/// Code generators that are emitting modules can write out a `use` line in the module's `mod.rs` for the builder type,
+	/// with a `#[cfg(feature = "<builder_feature>")]` attribute.
+	fn handle_builder_type(&mut self, builder_type_name: &str, builder_feature: Option<&str>) -> std::io::Result<()> {
+		let _ = (builder_type_name, builder_feature);
+		Ok(())
+	}
+
+	/// This function is invoked with the name of the apply configuration type that is generated along with a particular type, in the same writer,
+	/// and the feature that the apply configuration is `cfg`-gated by, if any. See [`GenerateApplyConfiguration`].
+	///
+	/// Code generators that are emitting modules can write out a `use` line in the module's `mod.rs` for the apply configuration type,
+	/// with a `#[cfg(feature = "<apply_configuration_feature>")]` attribute.
+	fn handle_apply_configuration_type(&mut self, apply_configuration_type_name: &str, apply_configuration_feature: Option<&str>) -> std::io::Result<()> {
+		let _ = (apply_configuration_type_name, apply_configuration_feature);
+		Ok(())
+	}
+
 	/// This function is invoked when `k8s_openapi_codegen_common::run` is done with the writer and completes successfully.
 	/// The implementation can do any cleanup that it wants here.
 	fn finish(&mut self, writer: Self::Writer);
@@ -144,22 +210,365 @@ impl<T> RunState for &'_ mut T where T: RunState {
 		(*self).handle_operation_types(operation_optional_parameters_name, operation_result_name)
 	}
 
+	fn handle_additional_type(&mut self, additional_type_name: &str) -> std::io::Result<()> {
+		(*self).handle_additional_type(additional_type_name)
+	}
+
+	fn handle_builder_type(&mut self, builder_type_name: &str, builder_feature: Option<&str>) -> std::io::Result<()> {
+		(*self).handle_builder_type(builder_type_name, builder_feature)
+	}
+
+	fn handle_apply_configuration_type(&mut self, apply_configuration_type_name: &str, apply_configuration_feature: Option<&str>) -> std::io::Result<()> {
+		(*self).handle_apply_configuration_type(apply_configuration_type_name, apply_configuration_feature)
+	}
+
 	fn finish(&mut self, writer: Self::Writer) {
 		(*self).finish(writer);
 	}
 }
 
+/// Used to customize the code that [`run`] emits for each type, without having to fork this crate.
+///
+/// Every method has a default implementation that keeps the code generated by the built-in templates as-is,
+/// so implementations only need to override the hooks they're interested in. Use [`DefaultTemplateProvider`] if there is nothing to customize.
+///
+/// For example, a code generator can implement [`TemplateProvider::type_attributes`] to derive `Hash` for some types,
+/// and [`TemplateProvider::type_footer`] to emit impls of its own traits for them.
+pub trait TemplateProvider {
+	/// Returns additional attributes to emit on the type, such as `#[derive(Hash)]`.
+	///
+	/// Each element is written on its own line after the attributes emitted by the built-in template, just before the type definition.
+	fn type_attributes(&self, definition_path: &swagger20::DefinitionPath) -> Vec<String> {
+		let _ = definition_path;
+		vec![]
+	}
+
+	/// Returns the short names of the resource type, like `po` for pods, that `kubectl` accepts in place of its plural name.
+	///
+	/// The spec does not include them, so they're emitted in the `k8s_openapi::Resource::SHORT_NAMES` of the type only if they're returned here.
+	fn resource_short_names(&self, definition_path: &swagger20::DefinitionPath) -> Vec<String> {
+		let _ = definition_path;
+		vec![]
+	}
+
+	/// Returns whether the built-in template for the given trait impl should be emitted for the type.
+	///
+	/// An implementation that returns `false` for a template can write its own impl of the trait from [`TemplateProvider::type_footer`] instead.
+	fn generate_impl(&self, definition_path: &swagger20::DefinitionPath, template: ImplTemplate) -> bool {
+		let _ = (definition_path, template);
+		true
+	}
+
+	/// Writes additional code after all the code generated for the type, such as impls of other traits.
+	///
+	/// # Parameters
+	///
+	/// - `writer`: The same writer that the type's generated code was written to, as returned by [`RunState::make_writer`].
+	///
+	/// - `definition_path`: The definition path of the generated type.
+	///
+	/// - `type_name`: The name of the generated type.
+	fn type_footer(
+		&self,
+		writer: &mut dyn std::io::Write,
+		definition_path: &swagger20::DefinitionPath,
+		type_name: &str,
+	) -> std::io::Result<()> {
+		let _ = (writer, definition_path, type_name);
+		Ok(())
+	}
+}
+
+/// An impl of [`TemplateProvider`] that emits the code generated by the built-in templates without any changes.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultTemplateProvider;
+
+impl TemplateProvider for DefaultTemplateProvider {}
+
+/// The built-in templates for trait impls that a [`TemplateProvider`] can suppress.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ImplTemplate {
+	/// The impl of `arbitrary::Arbitrary`
+	Arbitrary,
+
+	/// The `<type name>Builder` type and the `builder` function that creates it. See [`GenerateBuilder`].
+	///
+	/// A code generator that has its own builder for a type can suppress this one so that the two `builder` functions do not conflict.
+	Builder,
+
+	/// The impl of `serde::Deserialize`
+	Deserialize,
+
+	/// The impl of `std::fmt::Display` of top-level resources, which formats them as `<URL path segment>/<namespace>/<name>`
+	/// like `pods/kube-system/coredns-xyz`
+	///
+	/// A code generator that has its own impl of `std::fmt::Display` for a type can suppress this one so that the two do not conflict.
+	Display,
+
+	/// The impl of `k8s_openapi::Evictable`
+	Evictable,
+
+	/// The impl of `k8s_openapi::HasStatusSubresource`
+	HasStatusSubresource,
+
+	/// The impl of `k8s_openapi::ListableResource`
+	ListableResource,
+
+	/// The impl of `k8s_openapi::MergeStrategyInfo`
+	MergeStrategyInfo,
+
+	/// The impl of `k8s_openapi::Loggable`
+	Loggable,
+
+	/// The impl of `k8s_openapi::Metadata`
+	Metadata,
+
+	/// The `new` function that constructs the type from the values of its required fields.
+	///
+	/// A code generator that has its own `new` function for a type can suppress this one so that the two do not conflict.
+	New,
+
+	/// The derived impl of `PartialEq`
+	///
+	/// `Eq` and `Hash` are not derived either. The types that contain this type still derive all three if their other fields allow it,
+	/// so an implementation that suppresses this impl must write its own impls of all three.
+	PartialEq,
+
+	/// The impl of `k8s_openapi::Resource`
+	Resource,
+
+	/// The impl of `k8s_openapi::Scalable`
+	Scalable,
+
+	/// The impl of `schemars::JsonSchema`
+	Schema,
+
+	/// The impl of `serde::Serialize`
+	Serialize,
+}
+
 /// Whether [`run`] should generate an impl of [`schemars::JsonSchema`] for the type or not.
 #[derive(Clone, Copy, Debug)]
 pub enum GenerateSchema<'a> {
 	Yes {
 		/// An optional feature that the impl of [`schemars::JsonSchema`] will be `cfg`-gated by.
 		feature: Option<&'a str>,
+
+		/// If `true`, the schema of a resource type restricts its `apiVersion` and `kind` properties to the resource's API version and kind
+		/// with a single-valued `enum`, and marks them as required.
+		const_type_meta: bool,
+
+		/// How the impl of [`schemars::JsonSchema`] names the type's schema.
+		naming: SchemaNaming<'a>,
 	},
 
 	No,
 }
 
+/// The name returned by the `schemars::JsonSchema::schema_name` of a generated type, which is also the key of its schema in the definitions of a root schema.
+#[derive(Clone, Copy, Debug)]
+pub enum SchemaNaming<'a> {
+	/// The Go-style dotted definition path of the type in the OpenAPI spec, like `io.k8s.api.core.v1.PodSpec`
+	DefinitionPath,
+
+	/// The Rust path of the type relative to the root of the crate that `io.k8s` is mapped to, like `api::core::v1::PodSpec`
+	RustPath,
+
+	/// [`SchemaNaming::RustPath`] if the given feature is enabled, otherwise [`SchemaNaming::DefinitionPath`]
+	RustPathIfFeature(&'a str),
+}
+
+/// Whether [`run`] should generate an impl of [`arbitrary::Arbitrary`] for the type or not.
+#[derive(Clone, Copy, Debug)]
+pub enum GenerateArbitrary<'a> {
+	Yes {
+		/// An optional feature that the impl of [`arbitrary::Arbitrary`] will be `cfg`-gated by.
+		feature: Option<&'a str>,
+	},
+
+	No,
+}
+
+/// Whether [`run`] should generate a builder type for each struct or not.
+///
+/// The builder of a struct named `Foo` is named `FooBuilder` and is created by `Foo::builder()`. It has a chainable setter for each field,
+/// and a `build` function that fails with the `MissingFieldError` of the crate that `map_namespace` maps `io.k8s` to if a required field has not been set.
+#[derive(Clone, Copy, Debug)]
+pub enum GenerateBuilder<'a> {
+	Yes {
+		/// An optional feature that the builder type will be `cfg`-gated by.
+		feature: Option<&'a str>,
+	},
+
+	No,
+}
+
+/// Whether [`run`] should generate an impl of `serde::Deserialize::deserialize_in_place` for each struct or not.
+///
+/// The impl deserializes into the existing value, reusing the allocations of its strings, vectors and maps.
+/// It uses the helpers in the `serde2` module of the crate that the [`MapNamespace`] maps `io.k8s` to.
+/// If it is not generated, `deserialize_in_place` falls back to deserializing a new value and replacing the existing one with it.
+#[derive(Clone, Copy, Debug)]
+pub enum GenerateDeserializeInPlace<'a> {
+	Yes {
+		/// An optional feature that the impl will be `cfg`-gated by.
+		feature: Option<&'a str>,
+	},
+
+	No,
+}
+
+/// Whether [`run`] should generate an apply configuration type for each struct or not.
+///
+/// The apply configuration of a struct named `Foo` is named `FooApplyConfiguration`, like the apply configurations of client-go.
+/// It has the same fields as `Foo`, except that all of them are optional, including the ones that are required in `Foo`,
+/// and the fields whose types are structs have the apply configurations of those structs instead. Its impl of `serde::Serialize` skips the fields
+/// that are not set, so it can express the sparse objects that server-side apply expects, which only have the fields that the field manager owns.
+/// The apply configuration of a resource type always serializes its `apiVersion` and `kind`.
+#[derive(Clone, Copy, Debug)]
+pub enum GenerateApplyConfiguration<'a> {
+	Yes {
+		/// An optional feature that the apply configuration type will be `cfg`-gated by.
+		feature: Option<&'a str>,
+	},
+
+	No,
+}
+
+/// The options of [`run`] that control what is generated for a type, other than the type itself.
+///
+/// The options are created with [`RunOptions::default`] and set with the chainable setters of the same names as the fields.
+/// New options may be added in minor releases, with defaults that keep the generated code the same.
+///
+/// ```rust
+/// let options =
+///     k8s_openapi_codegen_common::RunOptions::default()
+///     .generate_builder(k8s_openapi_codegen_common::GenerateBuilder::Yes { feature: Some("builder") })
+///     .operation_feature(Some("api"));
+/// # let _ = options;
+/// ```
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+pub struct RunOptions<'a> {
+	/// The visibility modifier that should be emitted on the generated code, like `"pub "`. Defaults to `"pub "`.
+	pub vis: &'a str,
+
+	/// Whether an impl of [`schemars::JsonSchema`] should be emitted for the type. Defaults to [`GenerateSchema::No`].
+	pub generate_schema: GenerateSchema<'a>,
+
+	/// Whether an impl of [`arbitrary::Arbitrary`] should be emitted for the type. Defaults to [`GenerateArbitrary::No`].
+	///
+	/// The impl uses the helpers in the `arbitrary2` module of the crate that the [`MapNamespace`] maps `io.k8s` to.
+	pub generate_arbitrary: GenerateArbitrary<'a>,
+
+	/// Whether a builder type should be emitted for the type, if it is a struct. Defaults to [`GenerateBuilder::No`].
+	pub generate_builder: GenerateBuilder<'a>,
+
+	/// Whether an apply configuration type should be emitted for the type, if it is a struct. Defaults to [`GenerateApplyConfiguration::No`].
+	pub generate_apply_configuration: GenerateApplyConfiguration<'a>,
+
+	/// Whether an impl of `serde::Deserialize::deserialize_in_place` should be emitted for the type, if it is a struct.
+	/// Defaults to [`GenerateDeserializeInPlace::No`].
+	pub generate_deserialize_in_place: GenerateDeserializeInPlace<'a>,
+
+	/// If specified, all API functions will be emitted with a `#[cfg(feature = "<this value>")]` attribute. Defaults to `None`.
+	///
+	/// The attribute will also be applied to their optional parameters and response types, if any, and to common types for
+	/// optional parameters and response types that are shared by multiple operations.
+	pub operation_feature: Option<&'a str>,
+
+	/// Customizes the code emitted for the type. Defaults to [`DefaultTemplateProvider`], which emits the code generated by the built-in templates as-is.
+	pub template_provider: &'a dyn TemplateProvider,
+}
+
+impl Default for RunOptions<'_> {
+	fn default() -> Self {
+		RunOptions {
+			vis: "pub ",
+			generate_schema: GenerateSchema::No,
+			generate_arbitrary: GenerateArbitrary::No,
+			generate_builder: GenerateBuilder::No,
+			generate_apply_configuration: GenerateApplyConfiguration::No,
+			generate_deserialize_in_place: GenerateDeserializeInPlace::No,
+			operation_feature: None,
+			template_provider: &DefaultTemplateProvider,
+		}
+	}
+}
+
+impl std::fmt::Debug for RunOptions<'_> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("RunOptions")
+			.field("vis", &self.vis)
+			.field("generate_schema", &self.generate_schema)
+			.field("generate_arbitrary", &self.generate_arbitrary)
+			.field("generate_builder", &self.generate_builder)
+			.field("generate_apply_configuration", &self.generate_apply_configuration)
+			.field("generate_deserialize_in_place", &self.generate_deserialize_in_place)
+			.field("operation_feature", &self.operation_feature)
+			.finish_non_exhaustive()
+	}
+}
+
+impl<'a> RunOptions<'a> {
+	/// Sets [`RunOptions::vis`]
+	#[must_use]
+	pub fn vis(mut self, vis: &'a str) -> Self {
+		self.vis = vis;
+		self
+	}
+
+	/// Sets [`RunOptions::generate_schema`]
+	#[must_use]
+	pub fn generate_schema(mut self, generate_schema: GenerateSchema<'a>) -> Self {
+		self.generate_schema = generate_schema;
+		self
+	}
+
+	/// Sets [`RunOptions::generate_arbitrary`]
+	#[must_use]
+	pub fn generate_arbitrary(mut self, generate_arbitrary: GenerateArbitrary<'a>) -> Self {
+		self.generate_arbitrary = generate_arbitrary;
+		self
+	}
+
+	/// Sets [`RunOptions::generate_builder`]
+	#[must_use]
+	pub fn generate_builder(mut self, generate_builder: GenerateBuilder<'a>) -> Self {
+		self.generate_builder = generate_builder;
+		self
+	}
+
+	/// Sets [`RunOptions::generate_apply_configuration`]
+	#[must_use]
+	pub fn generate_apply_configuration(mut self, generate_apply_configuration: GenerateApplyConfiguration<'a>) -> Self {
+		self.generate_apply_configuration = generate_apply_configuration;
+		self
+	}
+
+	/// Sets [`RunOptions::generate_deserialize_in_place`]
+	#[must_use]
+	pub fn generate_deserialize_in_place(mut self, generate_deserialize_in_place: GenerateDeserializeInPlace<'a>) -> Self {
+		self.generate_deserialize_in_place = generate_deserialize_in_place;
+		self
+	}
+
+	/// Sets [`RunOptions::operation_feature`]
+	#[must_use]
+	pub fn operation_feature(mut self, operation_feature: Option<&'a str>) -> Self {
+		self.operation_feature = operation_feature;
+		self
+	}
+
+	/// Sets [`RunOptions::template_provider`]
+	#[must_use]
+	pub fn template_provider(mut self, template_provider: &'a dyn TemplateProvider) -> Self {
+		self.template_provider = template_provider;
+		self
+	}
+}
+
 /// Each invocation of this function generates a single type specified by the `definition_path` parameter along with its associated API operation functions.
 ///
 /// # Parameters
@@ -173,11 +582,7 @@ pub enum GenerateSchema<'a> {
 ///
 /// - `map_namespace`: An instance of the [`MapNamespace`] trait that controls how OpenAPI namespaces of the definitions are mapped to rust namespaces.
 ///
-/// - `vis`: The visibility modifier that should be emitted on the generated code.
-///
-/// - `operation_feature`: If specified, all API functions will be emitted with a `#[cfg(feature = "<this value>")]` attribute.
-///    The attribute will also be applied to their optional parameters and response types, if any, and to common types for
-///    optional parameters and response types that are shared by multiple operations.
+/// - `options`: The options that control what is generated for the type. See [`RunOptions`].
 ///
 /// - `state`: See the documentation of the [`RunState`] trait.
 pub fn run(
@@ -185,13 +590,22 @@ pub fn run(
 	operations: &mut Vec<swagger20::Operation>,
 	definition_path: &swagger20::DefinitionPath,
 	map_namespace: &impl MapNamespace,
-	vis: &str,
-	generate_schema: GenerateSchema<'_>,
-	operation_feature: Option<&str>,
+	options: &RunOptions<'_>,
 	mut state: impl RunState,
 ) -> Result<RunResult, Error> {
 	use std::io::Write;
 
+	let RunOptions {
+		vis,
+		generate_schema,
+		generate_arbitrary,
+		generate_builder,
+		generate_apply_configuration,
+		generate_deserialize_in_place,
+		operation_feature,
+		template_provider,
+	} = *options;
+
 	let definition = definitions.get(definition_path).ok_or_else(|| format!("definition for {} does not exist in spec", definition_path))?;
 
 	let local = map_namespace_local_to_string(map_namespace)?;
@@ -233,7 +647,17 @@ pub fn run(
 
 	let type_name = path_parts.last().ok_or_else(|| format!("path for {} has no parts", definition_path))?;
 
-	let derives = get_derives(&definition.kind, definitions, map_namespace)?;
+	let mut derives = get_derives(&definition.kind, definitions, map_namespace)?;
+	if let Some(derives) = &mut derives {
+		// A type with its own PartialEq impl, like Quantity, also has its own Eq and Hash impls that must be consistent with it.
+		if !template_provider.generate_impl(definition_path, ImplTemplate::PartialEq) {
+			derives.partial_eq = false;
+			derives.eq = false;
+			derives.hash = false;
+		}
+	}
+
+	let type_attributes = template_provider.type_attributes(definition_path);
 
 	templates::type_header::generate(
 		&mut out,
@@ -241,6 +665,7 @@ pub fn run(
 		definition.description.as_deref(),
 		type_feature,
 		derives,
+		&type_attributes,
 		vis,
 	)?;
 
@@ -367,8 +792,28 @@ pub fn run(
 				&template_properties,
 			)?;
 
+			if template_provider.generate_impl(definition_path, ImplTemplate::New) {
+				templates::new::generate(
+					&mut out,
+					vis,
+					type_name,
+					&template_properties,
+				)?;
+			}
+
+			templates::with_setters::generate(
+				&mut out,
+				vis,
+				type_name,
+				&template_properties,
+			)?;
+
 			let mut namespace_or_cluster_scoped_url_path_segment_and_scope = vec![];
 			let mut subresource_url_path_segment_and_scope = vec![];
+			let mut namespace_or_cluster_scoped_verbs = std::collections::BTreeSet::new();
+			let mut subresource_verbs = std::collections::BTreeSet::new();
+			let mut has_status_subresource = false;
+			let mut has_log_subresource = false;
 
 			if !definition.kubernetes_group_kind_versions.is_empty() {
 				let mut kubernetes_group_kind_versions: Vec<_> = definition.kubernetes_group_kind_versions.iter().collect();
@@ -402,6 +847,40 @@ pub fn run(
 							state.handle_operation_types(operation_optional_parameters_name.as_deref(), operation_result_name.as_deref())?;
 							run_result.num_generated_apis += 1;
 
+							let mut components = operation.path.rsplit('/');
+							let components = (
+								components.next().expect("str::rsplit returns at least one component"),
+								components.next(),
+								components.next(),
+								components.next(),
+							);
+
+							// Use the operation to determine the verbs that the resource supports, for its `APIResource`
+							let verb = match operation.kubernetes_action {
+								Some(swagger20::KubernetesAction::Delete) => Some("delete"),
+								Some(swagger20::KubernetesAction::DeleteCollection) => Some("deletecollection"),
+								Some(swagger20::KubernetesAction::Get) => Some("get"),
+								Some(swagger20::KubernetesAction::List) => Some("list"),
+								Some(swagger20::KubernetesAction::Patch) => Some("patch"),
+								Some(swagger20::KubernetesAction::Post) => Some("create"),
+								Some(swagger20::KubernetesAction::Put) => Some("update"),
+								Some(swagger20::KubernetesAction::Watch | swagger20::KubernetesAction::WatchList) => Some("watch"),
+								Some(swagger20::KubernetesAction::Connect | swagger20::KubernetesAction::Proxy) | None => None,
+							};
+							if let Some(verb) = verb {
+								match components {
+									("{name}", _, _, _) => namespace_or_cluster_scoped_verbs.insert(verb),
+									(_, Some("{name}"), _, _) => subresource_verbs.insert(verb),
+									_ => namespace_or_cluster_scoped_verbs.insert(verb),
+								};
+							}
+
+							match components {
+								("status", Some("{name}"), _, _) => has_status_subresource = true,
+								("log", Some("{name}"), _, _) => has_log_subresource = true,
+								_ => (),
+							}
+
 							// If this is a CRUD operation, use it to determine the resource's URL path segment and scope.
 							match operation.kubernetes_action {
 								Some(
@@ -412,13 +891,6 @@ pub fn run(
 								) => (),
 								_ => continue,
 							}
-							let mut components = operation.path.rsplit('/');
-							let components = (
-								components.next().expect("str::rsplit returns at least one component"),
-								components.next(),
-								components.next(),
-								components.next(),
-							);
 
 							// TODO:
 							// The clippy lint doesn't take into account that the order of the arms is significant.
@@ -457,7 +929,20 @@ pub fn run(
 				"io.k8s.apimachinery.pkg.apis.meta.v1.APIGroup" |
 				"io.k8s.apimachinery.pkg.apis.meta.v1.APIGroupList" |
 				"io.k8s.apimachinery.pkg.apis.meta.v1.APIResourceList" |
-				"io.k8s.apimachinery.pkg.apis.meta.v1.APIVersions" =>
+				"io.k8s.apimachinery.pkg.apis.meta.v1.APIVersions" |
+				"io.k8s.client-go.tools.clientcmd.api.v1.Config" |
+				"io.k8s.kube-proxy.config.v1alpha1.KubeProxyConfiguration" |
+				"io.k8s.kube-scheduler.config.v1beta2.DefaultPreemptionArgs" |
+				"io.k8s.kube-scheduler.config.v1beta2.InterPodAffinityArgs" |
+				"io.k8s.kube-scheduler.config.v1beta2.KubeSchedulerConfiguration" |
+				"io.k8s.kube-scheduler.config.v1beta2.NodeAffinityArgs" |
+				"io.k8s.kube-scheduler.config.v1beta2.NodeResourcesFitArgs" |
+				"io.k8s.kube-scheduler.config.v1beta2.PodTopologySpreadArgs" |
+				"io.k8s.kube-scheduler.config.v1beta2.VolumeBindingArgs" |
+				"io.k8s.kubelet.config.v1beta1.KubeletConfiguration" |
+				"io.k8s.metrics.pkg.apis.custom_metrics.v1beta1.MetricValue" |
+				"io.k8s.metrics.pkg.apis.custom_metrics.v1beta2.MetricValue" |
+				"io.k8s.metrics.pkg.apis.external_metrics.v1beta1.ExternalMetricValue" =>
 					namespace_or_cluster_scoped_url_path_segment_and_scope.push((r#""""#.to_owned(), format!("{}ClusterResourceScope", local))),
 				"io.k8s.apimachinery.pkg.apis.meta.v1.Status" =>
 					subresource_url_path_segment_and_scope.push((r#""status""#.to_owned(), format!("{}SubResourceScope", local))),
@@ -467,6 +952,34 @@ pub fn run(
 			namespace_or_cluster_scoped_url_path_segment_and_scope.dedup();
 			subresource_url_path_segment_and_scope.dedup();
 
+			// The verbs of a subresource type are only used if the type is not also a namespace- or cluster-scoped resource,
+			// the same as its URL path segment and scope.
+			let verbs: Vec<_> =
+				if namespace_or_cluster_scoped_url_path_segment_and_scope.is_empty() { subresource_verbs } else { namespace_or_cluster_scoped_verbs }
+				.into_iter()
+				.collect();
+
+			let singular = resource_metadata.as_ref().map(|(_, _, kind, _, _)| kind.to_lowercase());
+			let short_names = template_provider.resource_short_names(definition_path);
+
+			let scale_ty =
+				definition.scale_definition_path.as_ref()
+				.map(|scale_definition_path| get_fully_qualified_type_name(
+					&swagger20::RefPath {
+						path: scale_definition_path.0.clone(),
+						can_be_default: None,
+					},
+					map_namespace));
+
+			let eviction_ty =
+				definition.eviction_definition_path.as_ref()
+				.map(|eviction_definition_path| get_fully_qualified_type_name(
+					&swagger20::RefPath {
+						path: eviction_definition_path.0.clone(),
+						can_be_default: None,
+					},
+					map_namespace));
+
 			let template_resource_metadata = match (&resource_metadata, &metadata_ty) {
 				(
 					Some((api_version, group, kind, version, list_kind)),
@@ -477,7 +990,14 @@ pub fn run(
 					kind,
 					version,
 					list_kind: list_kind.as_deref(),
+					has_status_subresource,
+					has_log_subresource,
 					metadata_ty: Some(metadata_ty),
+					scale_ty: scale_ty.as_deref(),
+					eviction_ty: eviction_ty.as_deref(),
+					verbs: &verbs,
+					singular: singular.as_deref().unwrap_or_default(),
+					short_names: &short_names,
 					url_path_segment_and_scope: match (&*namespace_or_cluster_scoped_url_path_segment_and_scope, &*subresource_url_path_segment_and_scope) {
 						([(url_path_segment, scope)], _) |
 						([], [(url_path_segment, scope)]) => (&**url_path_segment, &**scope),
@@ -506,7 +1026,14 @@ pub fn run(
 					kind,
 					version,
 					list_kind: list_kind.as_deref(),
+					has_status_subresource,
+					has_log_subresource,
 					metadata_ty: None,
+					scale_ty: scale_ty.as_deref(),
+					eviction_ty: eviction_ty.as_deref(),
+					verbs: &verbs,
+					singular: singular.as_deref().unwrap_or_default(),
+					short_names: &short_names,
 					url_path_segment_and_scope: match (&*namespace_or_cluster_scoped_url_path_segment_and_scope, &*subresource_url_path_segment_and_scope) {
 						([(url_path_segment, scope)], _) |
 						([], [(url_path_segment, scope)]) => (&**url_path_segment, &**scope),
@@ -527,48 +1054,172 @@ pub fn run(
 			};
 
 			if let Some(template_resource_metadata) = &template_resource_metadata {
-				templates::impl_resource::generate(
+				if template_provider.generate_impl(definition_path, ImplTemplate::Resource) {
+					templates::impl_resource::generate(
+						&mut out,
+						type_name,
+						Default::default(),
+						map_namespace,
+						template_resource_metadata,
+					)?;
+				}
+
+				if template_provider.generate_impl(definition_path, ImplTemplate::ListableResource) {
+					templates::impl_listable_resource::generate(
+						&mut out,
+						type_name,
+						Default::default(),
+						map_namespace,
+						template_resource_metadata,
+					)?;
+				}
+
+				if template_provider.generate_impl(definition_path, ImplTemplate::Metadata) {
+					templates::impl_metadata::generate(
+						&mut out,
+						type_name,
+						Default::default(),
+						map_namespace,
+						template_resource_metadata,
+					)?;
+				}
+
+				if template_provider.generate_impl(definition_path, ImplTemplate::Display) {
+					templates::impl_display::generate(
+						&mut out,
+						type_name,
+						Default::default(),
+						map_namespace,
+						template_resource_metadata,
+					)?;
+				}
+
+				if template_provider.generate_impl(definition_path, ImplTemplate::HasStatusSubresource) {
+					templates::impl_has_status_subresource::generate(
+						&mut out,
+						type_name,
+						Default::default(),
+						map_namespace,
+						template_resource_metadata,
+					)?;
+				}
+
+				if template_provider.generate_impl(definition_path, ImplTemplate::Scalable) {
+					templates::impl_scalable::generate(
+						&mut out,
+						type_name,
+						Default::default(),
+						map_namespace,
+						template_resource_metadata,
+					)?;
+				}
+
+				if template_provider.generate_impl(definition_path, ImplTemplate::Evictable) {
+					templates::impl_evictable::generate(
+						&mut out,
+						type_name,
+						Default::default(),
+						map_namespace,
+						template_resource_metadata,
+					)?;
+				}
+
+				if template_provider.generate_impl(definition_path, ImplTemplate::Loggable) {
+					templates::impl_loggable::generate(
+						&mut out,
+						type_name,
+						Default::default(),
+						map_namespace,
+						template_resource_metadata,
+					)?;
+				}
+			}
+
+			if template_provider.generate_impl(definition_path, ImplTemplate::MergeStrategyInfo) {
+				templates::impl_merge_strategy_info::generate(
+					&mut out,
+					type_name,
+					Default::default(),
+					properties,
+					map_namespace,
+				)?;
+			}
+
+			if template_provider.generate_impl(definition_path, ImplTemplate::Deserialize) {
+				templates::impl_deserialize::generate(
+					&mut out,
+					type_name,
+					Default::default(),
+					Default::default(),
+					&template_properties,
+					map_namespace,
+					template_resource_metadata.as_ref(),
+					generate_deserialize_in_place,
+				)?;
+			}
+
+			if template_provider.generate_impl(definition_path, ImplTemplate::Serialize) {
+				templates::impl_serialize::generate(
 					&mut out,
 					type_name,
 					Default::default(),
+					&template_properties,
 					map_namespace,
-					template_resource_metadata,
+					template_resource_metadata.as_ref(),
 				)?;
+			}
+
+			if let GenerateBuilder::Yes { feature: builder_feature } = generate_builder {
+				if template_provider.generate_impl(definition_path, ImplTemplate::Builder) {
+					let builder_name = format!("{type_name}Builder");
+
+					templates::builder::generate(
+						&mut out,
+						vis,
+						type_name,
+						&builder_name,
+						&template_properties,
+						builder_feature,
+						map_namespace,
+					)?;
+
+					state.handle_builder_type(&builder_name, builder_feature)?;
+				}
+			}
 
-				templates::impl_listable_resource::generate(
-					&mut out,
-					type_name,
-					Default::default(),
-					map_namespace,
-					template_resource_metadata,
-				)?;
+			if let GenerateApplyConfiguration::Yes { feature: apply_configuration_feature } = generate_apply_configuration {
+				let apply_configuration_name = format!("{type_name}ApplyConfiguration");
+
+				let mut apply_configuration_field_type_names = Vec::with_capacity(template_properties.len());
+				for template_property in &template_properties {
+					let (_, (schema, _)) =
+						properties.iter()
+						.find(|(name, _)| name.0 == template_property.name)
+						.ok_or_else(|| format!("could not find property {} of {definition_path}", template_property.name))?;
+					let field_type_name = get_apply_configuration_rust_type(&schema.kind, definitions, map_namespace)?;
+					// Keep the `Box` of the fields that are boxed to break infinite recursion.
+					if template_property.field_type_name.trim_start_matches("Option<").starts_with("Box<") {
+						apply_configuration_field_type_names.push(format!("Box<{field_type_name}>"));
+					}
+					else {
+						apply_configuration_field_type_names.push(field_type_name.into_owned());
+					}
+				}
 
-				templates::impl_metadata::generate(
+				templates::apply_configuration::generate(
 					&mut out,
+					vis,
 					type_name,
-					Default::default(),
+					&apply_configuration_name,
+					&template_properties,
+					&apply_configuration_field_type_names,
+					apply_configuration_feature,
 					map_namespace,
-					template_resource_metadata,
+					template_resource_metadata.is_some(),
 				)?;
-			}
 
-			templates::impl_deserialize::generate(
-				&mut out,
-				type_name,
-				Default::default(),
-				&template_properties,
-				map_namespace,
-				template_resource_metadata.as_ref(),
-			)?;
-
-			templates::impl_serialize::generate(
-				&mut out,
-				type_name,
-				Default::default(),
-				&template_properties,
-				map_namespace,
-				template_resource_metadata.as_ref(),
-			)?;
+				state.handle_apply_configuration_type(&apply_configuration_name, apply_configuration_feature)?;
+			}
 
 			run_result.num_generated_structs += 1;
 		},
@@ -626,6 +1277,17 @@ pub fn run(
 			run_result.num_generated_structs += 1;
 		},
 
+		swagger20::SchemaKind::Ty(swagger20::Type::StringEnum { values }) => {
+			templates::string_enum::generate(
+				&mut out,
+				type_name,
+				values,
+				map_namespace,
+			)?;
+
+			run_result.num_generated_structs += 1;
+		},
+
 		swagger20::SchemaKind::Ty(swagger20::Type::WatchEvent(raw_extension_ref_path)) => {
 			let has_bookmark_event_type = {
 				let watch_optional_schema =
@@ -661,6 +1323,7 @@ pub fn run(
 				&error_status_rust_type,
 				&error_other_rust_type,
 				map_namespace,
+				generate_deserialize_in_place,
 			)?;
 
 			run_result.num_generated_structs += 1;
@@ -701,8 +1364,15 @@ pub fn run(
 				kind: "<T as crate::ListableResource>::LIST_KIND",
 				version: "<T as crate::Resource>::VERSION",
 				list_kind: None,
+				has_status_subresource: false,
+				has_log_subresource: false,
 				metadata_ty: Some(&metadata_rust_type),
+				scale_ty: None,
+				eviction_ty: None,
 				url_path_segment_and_scope: (r#""""#, "<T as crate::Resource>::Scope"),
+				verbs: &[],
+				singular: r#""""#,
+				short_names: &[],
 			};
 
 			templates::r#struct::generate(
@@ -713,45 +1383,55 @@ pub fn run(
 				&template_properties,
 			)?;
 
-			templates::impl_resource::generate(
-				&mut out,
-				type_name,
-				template_generics,
-				map_namespace,
-				&template_resource_metadata,
-			)?;
+			if template_provider.generate_impl(definition_path, ImplTemplate::Resource) {
+				templates::impl_resource::generate(
+					&mut out,
+					type_name,
+					template_generics,
+					map_namespace,
+					&template_resource_metadata,
+				)?;
+			}
 
-			templates::impl_listable_resource::generate(
-				&mut out,
-				type_name,
-				template_generics,
-				map_namespace,
-				&template_resource_metadata,
-			)?;
+			if template_provider.generate_impl(definition_path, ImplTemplate::ListableResource) {
+				templates::impl_listable_resource::generate(
+					&mut out,
+					type_name,
+					template_generics,
+					map_namespace,
+					&template_resource_metadata,
+				)?;
+			}
 
-			templates::impl_metadata::generate(
-				&mut out,
-				type_name,
-				template_generics,
-				map_namespace,
-				&template_resource_metadata,
-			)?;
+			if template_provider.generate_impl(definition_path, ImplTemplate::Metadata) {
+				templates::impl_metadata::generate(
+					&mut out,
+					type_name,
+					template_generics,
+					map_namespace,
+					&template_resource_metadata,
+				)?;
+			}
 
 			{
 				let template_generics_where_part = format!("T: {local}serde::Deserialize<'de> + {local}ListableResource", local = local);
-				let template_generics = templates::Generics {
+				let template_deserialize_generics = templates::Generics {
 					where_part: Some(&template_generics_where_part),
 					..template_generics
 				};
 
-				templates::impl_deserialize::generate(
-					&mut out,
-					type_name,
-					template_generics,
-					&template_properties,
-					map_namespace,
-					Some(&template_resource_metadata),
-				)?;
+				if template_provider.generate_impl(definition_path, ImplTemplate::Deserialize) {
+					templates::impl_deserialize::generate(
+						&mut out,
+						type_name,
+						template_generics,
+						template_deserialize_generics,
+						&template_properties,
+						map_namespace,
+						Some(&template_resource_metadata),
+						generate_deserialize_in_place,
+					)?;
+				}
 			}
 
 			{
@@ -761,14 +1441,16 @@ pub fn run(
 					..template_generics
 				};
 
-				templates::impl_serialize::generate(
-					&mut out,
-					type_name,
-					template_generics,
-					&template_properties,
-					map_namespace,
-					Some(&template_resource_metadata),
-				)?;
+				if template_provider.generate_impl(definition_path, ImplTemplate::Serialize) {
+					templates::impl_serialize::generate(
+						&mut out,
+						type_name,
+						template_generics,
+						&template_properties,
+						map_namespace,
+						Some(&template_resource_metadata),
+					)?;
+				}
 			}
 
 			run_result.num_generated_structs += 1;
@@ -864,14 +1546,16 @@ pub fn run(
 					)?,
 
 				swagger20::Type::DeleteOptional(_) =>
-					templates::impl_serialize::generate(
-						&mut out,
-						type_name,
-						template_generics,
-						&template_properties,
-						map_namespace,
-						None,
-					)?,
+					if template_provider.generate_impl(definition_path, ImplTemplate::Serialize) {
+						templates::impl_serialize::generate(
+							&mut out,
+							type_name,
+							template_generics,
+							&template_properties,
+							map_namespace,
+							None,
+						)?;
+					},
 
 				swagger20::Type::WatchOptional(_) =>
 					templates::query_string_optional::generate(
@@ -997,6 +1681,7 @@ pub fn run(
 				type_name,
 				&inner_type_name,
 				datetime_serialization_format,
+				matches!(definition.kind, swagger20::SchemaKind::Ty(swagger20::Type::Any)),
 				map_namespace,
 			)?;
 
@@ -1004,53 +1689,179 @@ pub fn run(
 		},
 	}
 
-	if let GenerateSchema::Yes { feature: schema_feature } = generate_schema {
-		match &definition.kind {
-			swagger20::SchemaKind::Properties(_) |
-			swagger20::SchemaKind::Ty(
-				swagger20::Type::Any |
-				swagger20::Type::Array { .. } |
-				swagger20::Type::Boolean |
-				swagger20::Type::Integer { .. } |
-				swagger20::Type::IntOrString |
-				swagger20::Type::Number { .. } |
-				swagger20::Type::Object { .. } |
-				swagger20::Type::String { .. } |
-				swagger20::Type::JsonSchemaPropsOrArray(_) |
-				swagger20::Type::JsonSchemaPropsOrBool(_) |
-				swagger20::Type::JsonSchemaPropsOrStringArray(_) |
-				swagger20::Type::Patch
-			) => {
-				templates::impl_schema::generate(
-					&mut out,
-					type_name,
-					Default::default(),
-					definition_path,
-					definition,
-					schema_feature,
-					map_namespace,
-				)?;
-			} 
+	if let GenerateSchema::Yes { feature: schema_feature, const_type_meta, naming } = generate_schema {
+		if template_provider.generate_impl(definition_path, ImplTemplate::Schema) {
+			// Expressions for the values of the `apiVersion` and `kind` properties
+			let type_meta = match (const_type_meta, &definition.kind, &definition.kubernetes_group_kind_versions[..]) {
+				(true, swagger20::SchemaKind::Ty(swagger20::Type::ListDef { .. }), _) => Some((
+					format!("<T as {local}Resource>::API_VERSION"),
+					format!("<T as {local}ListableResource>::LIST_KIND"),
+				)),
+
+				(true, _, [group_version_kind]) => {
+					let api_version =
+						if group_version_kind.group.is_empty() {
+							group_version_kind.version.clone()
+						}
+						else {
+							format!("{}/{}", group_version_kind.group, group_version_kind.version)
+						};
+					Some((format!("{api_version:?}"), format!("{:?}", group_version_kind.kind)))
+				},
 
-			swagger20::SchemaKind::Ty(swagger20::Type::WatchEvent(_)) => {
-				templates::impl_schema::generate(
-					&mut out,
-					type_name,
-					templates::Generics {
-						type_part: Some("T"),
-						where_part: None,
-					},
-					definition_path,
-					definition,
-					schema_feature,
-					map_namespace,
-				)?;
+				_ => None,
+			};
+			let type_meta = type_meta.as_ref().map(|(api_version, kind)| (&**api_version, &**kind));
+
+			match &definition.kind {
+				swagger20::SchemaKind::Properties(_) |
+				swagger20::SchemaKind::Ty(
+					swagger20::Type::Any |
+					swagger20::Type::Array { .. } |
+					swagger20::Type::Boolean |
+					swagger20::Type::Integer { .. } |
+					swagger20::Type::IntOrString |
+					swagger20::Type::Number { .. } |
+					swagger20::Type::Object { .. } |
+					swagger20::Type::String { .. } |
+					swagger20::Type::StringEnum { .. } |
+					swagger20::Type::JsonSchemaPropsOrArray(_) |
+					swagger20::Type::JsonSchemaPropsOrBool(_) |
+					swagger20::Type::JsonSchemaPropsOrStringArray(_) |
+					swagger20::Type::Patch
+				) => {
+					templates::impl_schema::generate(
+						&mut out,
+						type_name,
+						Default::default(),
+						definition_path,
+						definition,
+						type_meta,
+						naming,
+						schema_feature,
+						map_namespace,
+					)?;
+				} 
+
+				swagger20::SchemaKind::Ty(swagger20::Type::ListDef { .. }) => {
+					let template_generics_where_part = format!("T: {local}schemars::JsonSchema + {local}ListableResource");
+					templates::impl_schema::generate(
+						&mut out,
+						type_name,
+						templates::Generics {
+							type_part: Some("T"),
+							where_part: Some(&template_generics_where_part),
+						},
+						definition_path,
+						definition,
+						type_meta,
+						naming,
+						schema_feature,
+						map_namespace,
+					)?;
+				}
+
+				swagger20::SchemaKind::Ty(swagger20::Type::WatchEvent(_)) => {
+					let template_generics_where_part = format!("T: {local}schemars::JsonSchema");
+					templates::impl_schema::generate(
+						&mut out,
+						type_name,
+						templates::Generics {
+							type_part: Some("T"),
+							where_part: Some(&template_generics_where_part),
+						},
+						definition_path,
+						definition,
+						type_meta,
+						naming,
+						schema_feature,
+						map_namespace,
+					)?;
+				}
+
+				_ => (),
+			}
+		}
+	}
+
+	if let GenerateArbitrary::Yes { feature: arbitrary_feature } = generate_arbitrary {
+		if template_provider.generate_impl(definition_path, ImplTemplate::Arbitrary) {
+			match &definition.kind {
+				swagger20::SchemaKind::Properties(_) |
+				swagger20::SchemaKind::Ty(
+					swagger20::Type::Any |
+					swagger20::Type::Array { .. } |
+					swagger20::Type::Boolean |
+					swagger20::Type::Integer { .. } |
+					swagger20::Type::IntOrString |
+					swagger20::Type::Number { .. } |
+					swagger20::Type::Object { .. } |
+					swagger20::Type::String { .. } |
+					swagger20::Type::StringEnum { .. } |
+					swagger20::Type::JsonSchemaPropsOrArray(_) |
+					swagger20::Type::JsonSchemaPropsOrBool(_) |
+					swagger20::Type::JsonSchemaPropsOrStringArray(_) |
+					swagger20::Type::Patch
+				) => {
+					templates::impl_arbitrary::generate(
+						&mut out,
+						type_name,
+						Default::default(),
+						definition,
+						arbitrary_feature,
+						map_namespace,
+					)?;
+				}
+
+				swagger20::SchemaKind::Ty(swagger20::Type::ListDef { .. }) => {
+					let template_generics_where_part = format!("T: {local}arbitrary::Arbitrary<'a> + {local}ListableResource", local = local);
+
+					templates::impl_arbitrary::generate(
+						&mut out,
+						type_name,
+						templates::Generics {
+							type_part: Some("T"),
+							where_part: Some(&template_generics_where_part),
+						},
+						definition,
+						arbitrary_feature,
+						map_namespace,
+					)?;
+				}
+
+				_ => (),
 			}
+		}
+	}
 
-			_ => (),
+	if let (swagger20::SchemaKind::Properties(properties), Some(union)) = (&definition.kind, &definition.union) {
+		let mut variants = Vec::with_capacity(union.variants.len());
+		for name in &union.variants {
+			let (schema, required) =
+				properties.get(name)
+				.ok_or_else(|| format!("union {} of {} has variant {} that is not a property", union.enum_name, definition_path, name))?;
+			if *required {
+				return Err(format!("union {} of {} has variant {} that is a required property", union.enum_name, definition_path, name).into());
+			}
+			let field_name = get_rust_ident(name);
+			let variant_type_name = get_rust_type(&schema.kind, map_namespace)?;
+			variants.push((&**name, field_name, variant_type_name));
 		}
+
+		templates::union::generate(
+			&mut out,
+			vis,
+			type_name,
+			&union.accessor_name,
+			&union.enum_name,
+			&variants,
+		)?;
+
+		state.handle_additional_type(&union.enum_name)?;
 	}
 
+	template_provider.type_footer(&mut out, definition_path, type_name)?;
+
 	state.finish(out);
 
 	Ok(run_result)
@@ -1092,21 +1903,31 @@ fn get_derives(
 				swagger20::Type::WatchResponse
 			))))?;
 
+	// Structs are Copy if all their fields are, so that small structs like `DaemonEndpoint` can be copied out of the objects they're in
+	// instead of being cloned. Strings, arrays and maps are never Copy.
+	//
+	// Date-times are only Copy themselves. Structs with date-time fields are not, since the `date_time_as_string` fixup turns date-times into strings,
+	// and k8s-openapi generates its `Time` and `MicroTime` that way when its `chrono` feature is disabled.
 	let derive_copy =
-		derive_clone &&
-		evaluate_trait_bound(
-			kind,
-			false,
-			definitions,
-			map_namespace,
-			|kind, _| Ok(matches!(kind, swagger20::SchemaKind::Ty(
-				swagger20::Type::CreateOptional(_) |
-				swagger20::Type::DeleteOptional(_) |
-				swagger20::Type::ListOptional(_) |
-				swagger20::Type::PatchOptional(_) |
-				swagger20::Type::ReplaceOptional(_) |
-				swagger20::Type::WatchOptional(_)
-			))))?;
+		derive_clone && (
+			matches!(kind, swagger20::SchemaKind::Ty(swagger20::Type::String { format: Some(swagger20::StringFormat::DateTime) })) ||
+			evaluate_trait_bound(
+				kind,
+				false,
+				definitions,
+				map_namespace,
+				|kind, _| Ok(matches!(kind, swagger20::SchemaKind::Ty(
+					swagger20::Type::Boolean |
+					swagger20::Type::Integer { .. } |
+					swagger20::Type::Number { .. } |
+					swagger20::Type::CreateOptional(_) |
+					swagger20::Type::DeleteOptional(_) |
+					swagger20::Type::ListOptional(_) |
+					swagger20::Type::PatchOptional(_) |
+					swagger20::Type::ReplaceOptional(_) |
+					swagger20::Type::WatchOptional(_)
+				))))?
+		);
 
 	#[allow(clippy::match_same_arms)]
 	let is_default = evaluate_trait_bound(kind, false, definitions, map_namespace, |kind, required| match kind {
@@ -1146,8 +1967,8 @@ fn get_derives(
 	})?;
 	let derive_default =
 		is_default &&
-		// IntOrString has a manual Default impl, so don't #[derive] it.
-		!matches!(kind, swagger20::SchemaKind::Ty(swagger20::Type::IntOrString));
+		// IntOrString and string enums have manual Default impls, so don't #[derive] them.
+		!matches!(kind, swagger20::SchemaKind::Ty(swagger20::Type::IntOrString | swagger20::Type::StringEnum { .. }));
 
 	let derive_partial_eq =
 		evaluate_trait_bound(
@@ -1164,30 +1985,44 @@ fn get_derives(
 				swagger20::Type::WatchResponse
 			))))?;
 
-	// The choice of deriving Eq, Ord and PartialOrd is deliberately more conservative than the choice of deriving PartialEq,
-	// so as to not change dramatically between Kubernetes versions. For example, ObjectMeta is Ord in v1.15 but not in v1.16 because
-	// it indirectly gained a serde_json::Value field (`managed_fields.fields_v1.0`).
+	// Eq and Hash are derived for types whose fields all implement them, transitively, so that types like `ObjectReference`
+	// can be used as the keys of maps and sets. Floats and arbitrary JSON values implement neither, so eg ObjectMeta doesn't derive them
+	// because it indirectly has a serde_json::Value field (`managed_fields.fields_v1.0`).
 	//
-	// Also, being conservative means the types generated by #[derive(k8s_openapi_derive::CustomResource)] don't have to require them either.
-
+	// Types that are referenced from outside the scope being generated, like the k8s-openapi types referenced by the types generated by
+	// #[derive(k8s_openapi_derive::CustomResource)], are assumed to not implement them.
 	let derive_eq =
 		derive_partial_eq &&
-		matches!(kind, swagger20::SchemaKind::Ty(
-			swagger20::Type::IntOrString |
-			swagger20::Type::String { format: Some(swagger20::StringFormat::DateTime) }
-		));
+		evaluate_trait_bound(
+			kind,
+			true,
+			definitions,
+			map_namespace,
+			|kind, _| Ok(matches!(kind, swagger20::SchemaKind::Ty(
+				swagger20::Type::Boolean |
+				swagger20::Type::Integer { .. } |
+				swagger20::Type::String { .. } |
+				swagger20::Type::IntOrString |
+				swagger20::Type::StringEnum { .. }
+			))))?;
 
+	// The choice of deriving Ord and PartialOrd is deliberately more conservative than the choice of deriving PartialEq,
+	// so as to not change dramatically between Kubernetes versions.
 	let derive_partial_ord =
 		derive_partial_eq &&
 		matches!(kind, swagger20::SchemaKind::Ty(swagger20::Type::String { format: Some(swagger20::StringFormat::DateTime) }));
 
 	let derive_ord = derive_partial_ord && derive_eq;
 
+	// Hash is derived for the same types as Eq, since the derived impls of both only depend on the fields.
+	let derive_hash = derive_eq;
+
 	Ok(Some(templates::type_header::Derives {
 		clone: derive_clone,
 		copy: derive_copy,
 		default: derive_default,
 		eq: derive_eq,
+		hash: derive_hash,
 		ord: derive_ord,
 		partial_eq: derive_partial_eq,
 		partial_ord: derive_partial_ord,
@@ -1269,6 +2104,18 @@ fn evaluate_trait_bound(
 					f,
 				),
 
+			// Maps follow their values the same way.
+			swagger20::SchemaKind::Ty(swagger20::Type::Object { additional_properties }) if array_follows_elements =>
+				evaluate_trait_bound_inner(
+					&std::borrow::Cow::Owned(additional_properties.kind.clone()),
+					required,
+					array_follows_elements,
+					definitions,
+					map_namespace,
+					visited,
+					f,
+				),
+
 			swagger20::SchemaKind::Ty(
 				swagger20::Type::JsonSchemaPropsOrArray(namespace) |
 				swagger20::Type::JsonSchemaPropsOrBool(namespace) |
@@ -1386,10 +2233,14 @@ pub fn get_rust_ident(name: &str) -> std::borrow::Cow<'static, str> {
 	// Mostly happens with plurals of abbreviations.
 	match name {
 		"clusterIPs" => return "cluster_ips".into(),
+		"excludeCIDRs" => return "exclude_cidrs".into(),
 		"externalIPs" => return "external_ips".into(),
+		"kubeAPIQPS" => return "kube_api_qps".into(),
+		"makeIPTablesUtilChains" => return "make_iptables_util_chains".into(),
 		"nonResourceURLs" => return "non_resource_urls".into(),
 		"podCIDRs" => return "pod_cidrs".into(),
 		"podIPs" => return "pod_ips".into(),
+		"reservedSystemCPUs" => return "reserved_system_cpus".into(),
 		"serverAddressByClientCIDRs" => return "server_address_by_client_cidrs".into(),
 		"targetWWNs" => return "target_wwns".into(),
 		_ => (),
@@ -1480,6 +2331,8 @@ fn get_rust_borrow_type(
 
 		swagger20::SchemaKind::Ty(swagger20::Type::IntOrString) => Err("nothing should be trying to refer to IntOrString".into()),
 
+		swagger20::SchemaKind::Ty(swagger20::Type::StringEnum { .. }) => Err("nothing should be trying to refer to a string enum".into()),
+
 		swagger20::SchemaKind::Ty(
 			swagger20::Type::JsonSchemaPropsOrArray(_) |
 			swagger20::Type::JsonSchemaPropsOrBool(_) |
@@ -1507,6 +2360,29 @@ fn get_rust_borrow_type(
 	}
 }
 
+/// Like [`get_rust_type`], but the types of structs are replaced with their apply configurations. See [`GenerateApplyConfiguration`].
+fn get_apply_configuration_rust_type(
+	schema_kind: &swagger20::SchemaKind,
+	definitions: &std::collections::BTreeMap<swagger20::DefinitionPath, swagger20::Schema>,
+	map_namespace: &impl MapNamespace,
+) -> Result<std::borrow::Cow<'static, str>, Error> {
+	match schema_kind {
+		swagger20::SchemaKind::Ref(ref_path) => match definitions.get(&swagger20::DefinitionPath(ref_path.path.clone())) {
+			Some(swagger20::Schema { kind: swagger20::SchemaKind::Properties(_), .. }) =>
+				Ok(format!("{}ApplyConfiguration", get_fully_qualified_type_name(ref_path, map_namespace)).into()),
+			_ => get_rust_type(schema_kind, map_namespace),
+		},
+
+		swagger20::SchemaKind::Ty(swagger20::Type::Array { items }) =>
+			Ok(format!("Vec<{}>", get_apply_configuration_rust_type(&items.kind, definitions, map_namespace)?).into()),
+
+		swagger20::SchemaKind::Ty(swagger20::Type::Object { additional_properties }) =>
+			Ok(format!("std::collections::BTreeMap<String, {}>", get_apply_configuration_rust_type(&additional_properties.kind, definitions, map_namespace)?).into()),
+
+		schema_kind => get_rust_type(schema_kind, map_namespace),
+	}
+}
+
 fn get_rust_type(
 	schema_kind: &swagger20::SchemaKind,
 	map_namespace: &impl MapNamespace,
@@ -1558,6 +2434,8 @@ fn get_rust_type(
 
 		swagger20::SchemaKind::Ty(swagger20::Type::IntOrString) => Err("nothing should be trying to refer to IntOrString".into()),
 
+		swagger20::SchemaKind::Ty(swagger20::Type::StringEnum { .. }) => Err("nothing should be trying to refer to a string enum".into()),
+
 		swagger20::SchemaKind::Ty(
 			swagger20::Type::JsonSchemaPropsOrArray(_) |
 			swagger20::Type::JsonSchemaPropsOrBool(_) |
@@ -1884,6 +2762,13 @@ pub fn write_operation(
 	}
 
 	if have_path_parameters {
+		// An empty path segment would make the URL refer to a different path, like the collection instead of the object.
+		for (parameter_name, _, parameter) in &parameters {
+			if parameter.location == swagger20::ParameterLocation::Path {
+				writeln!(out, "{}    {local}validate_path_parameter({:?}, {})?;", indent, parameter_name, parameter_name, local = local)?;
+			}
+		}
+
 		write!(out, r#"{}    let __url = format!("{}"#, indent, operation.path)?;
 		if have_query_parameters {
 			write!(out, "?")?;
@@ -2090,6 +2975,10 @@ pub fn write_operation(
 				for line in get_comment_text(description, "") {
 					writeln!(out, "    ///{}", line)?;
 				}
+
+				if let Some(deprecated_attribute) = templates::get_deprecated_attribute(description, "    ") {
+					out.write_all(deprecated_attribute.as_bytes())?;
+				}
 			}
 			if let Some(borrowed_parameter_type) = parameter_type.strip_prefix('&') {
 				writeln!(out, "    {}{}: Option<&'a {}>,", vis, parameter_name, borrowed_parameter_type)?;
@@ -2228,6 +3117,58 @@ pub fn write_operation(
 	Ok(result)
 }
 
+/// Generates a static table that describes every type generated from the given definitions, except for the types of the optional parameters
+/// and responses of API operations.
+///
+/// The table is emitted as a `pub(crate) static TYPES: &[TypeInfo]`, where the `TypeInfo` type and the other types it uses are expected to be in
+/// the `reflection` module of the crate that `map_namespace` maps `io.k8s` to. The table is sorted by the definition paths of the types.
+///
+/// # Parameters
+///
+/// - `out`: An impl of `std::io::Write` that the table will be emitted to.
+///
+/// - `definitions`: The definitions parsed from the OpenAPI spec that were emitted as model types.
+///
+/// - `map_namespace`: An instance of the [`MapNamespace`] trait that controls how OpenAPI namespaces of the definitions are mapped to rust namespaces.
+///
+/// - `definition_feature`: Returns the feature that the given type is `cfg`-gated by, if any. The entry of the type in the table will be gated by the same feature.
+pub fn write_type_registry(
+	out: impl std::io::Write,
+	definitions: &std::collections::BTreeMap<swagger20::DefinitionPath, swagger20::Schema>,
+	map_namespace: &impl MapNamespace,
+	definition_feature: impl Fn(&swagger20::DefinitionPath) -> Option<&'static str>,
+) -> Result<(), Error> {
+	templates::type_registry::generate(out, definitions, map_namespace, definition_feature)
+}
+
+/// Emits impls of `TryFrom` between the resource types that are adjacent versions of the same group and kind,
+/// like `apps/v1beta2` and `apps/v1` `Deployment`s, in both directions.
+///
+/// Versions are ordered by major version, then stability, then minor version, so `apps/v1beta1` and `apps/v1` `Deployment`s
+/// do not get impls between them if `apps/v1beta2` exists.
+///
+/// The impls call the `convert_resource_version` function of the crate that `map_namespace` maps `io.k8s` to,
+/// which is expected to convert a resource value to another resource type through their JSON representation,
+/// and to return the `TryFromValueError` of that crate if it fails.
+///
+/// # Parameters
+///
+/// - `out`: An impl of `std::io::Write` that the impls will be emitted to.
+///
+/// - `definitions`: The definitions parsed from the OpenAPI spec that were emitted as model types.
+///
+/// - `map_namespace`: An instance of the [`MapNamespace`] trait that controls how OpenAPI namespaces of the definitions are mapped to rust namespaces.
+///
+/// - `definition_feature`: Returns the feature that the given type is `cfg`-gated by, if any. The impls of the type will be gated by the same feature.
+pub fn write_version_conversions(
+	out: impl std::io::Write,
+	definitions: &std::collections::BTreeMap<swagger20::DefinitionPath, swagger20::Schema>,
+	map_namespace: &impl MapNamespace,
+	definition_feature: impl Fn(&swagger20::DefinitionPath) -> Option<&'static str>,
+) -> Result<(), Error> {
+	templates::version_conversions::generate(out, definitions, map_namespace, definition_feature)
+}
+
 fn get_operation_names(
 	operation: &swagger20::Operation,
 	strip_tag: bool,
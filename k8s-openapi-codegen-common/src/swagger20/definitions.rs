@@ -104,6 +104,18 @@ pub struct Schema {
 
 	/// Used to store the definition path of the corresponding list type, if any.
 	pub list_kind: Option<String>,
+
+	/// Used to store the definition path of the type of the resource's `scale` subresource, if any.
+	pub scale_definition_path: Option<DefinitionPath>,
+
+	/// Used to store the definition path of the type of the resource's `eviction` subresource, if any.
+	pub eviction_definition_path: Option<DefinitionPath>,
+
+	/// Used to store the properties of the type that are the variants of a union, if the type is semantically a union of them.
+	pub union: Option<Union>,
+
+	/// How a strategic merge patch of a property of this schema is applied to it, if the spec annotates it.
+	pub patch_strategy: Option<PatchStrategy>,
 }
 
 #[cfg(feature = "serde")]
@@ -126,6 +138,12 @@ impl<'de> serde::Deserialize<'de> for Schema {
 
 			properties: Option<std::collections::BTreeMap<PropertyName, Schema>>,
 
+			#[serde(rename = "x-kubernetes-patch-merge-key")]
+			patch_merge_key: Option<String>,
+
+			#[serde(rename = "x-kubernetes-patch-strategy")]
+			patch_strategy: Option<String>,
+
 			#[serde(rename = "$ref")]
 			ref_path: Option<RefPath>,
 
@@ -138,6 +156,11 @@ impl<'de> serde::Deserialize<'de> for Schema {
 
 		let mut value: InnerSchema = serde::Deserialize::deserialize(deserializer)?;
 
+		let patch_strategy = match value.patch_strategy.take() {
+			Some(patch_strategy) => Some(PatchStrategy::parse::<D>(&patch_strategy, value.patch_merge_key.take())?),
+			None => None,
+		};
+
 		let kind =
 			if let Some(ref_path) = value.ref_path {
 				SchemaKind::Ref(ref_path)
@@ -174,10 +197,62 @@ impl<'de> serde::Deserialize<'de> for Schema {
 			kind,
 			kubernetes_group_kind_versions: value.kubernetes_group_kind_versions,
 			list_kind: None,
+			scale_definition_path: None,
+			eviction_definition_path: None,
+			union: None,
+			patch_strategy,
 		})
 	}
 }
 
+/// The value of the `x-kubernetes-patch-strategy` and `x-kubernetes-patch-merge-key` annotations of a property.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct PatchStrategy {
+	/// Whether the items of the list are merged with the items of the patch. If not, the list is replaced by the patch.
+	pub merge: bool,
+
+	/// Whether the fields of the object that are not in the patch are cleared.
+	pub retain_keys: bool,
+
+	/// The field of the items of the list that identifies them when merging, like `name` for `PodSpec.containers`.
+	///
+	/// This is `None` for lists of primitive values that are merged as sets.
+	pub merge_key: Option<String>,
+}
+
+#[cfg(feature = "serde")]
+impl PatchStrategy {
+	fn parse<'de, D>(s: &str, merge_key: Option<String>) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+		let mut result = PatchStrategy { merge: false, retain_keys: false, merge_key };
+
+		for strategy in s.split(',') {
+			match strategy {
+				"merge" => result.merge = true,
+				"retainKeys" => result.retain_keys = true,
+				"replace" => (),
+				strategy => return Err(serde::de::Error::invalid_value(serde::de::Unexpected::Str(strategy), &"a patch strategy")),
+			}
+		}
+
+		Ok(result)
+	}
+}
+
+/// The properties of a type that are the variants of a union, ie only one of them is expected to be set.
+///
+/// For example, only one of the source properties of a `Volume`, like `configMap` and `secret`, can be set.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Union {
+	/// The name of the method of the type that returns the variant that is set, like `source`.
+	pub accessor_name: String,
+
+	/// The name of the enum of the variants, like `VolumeSource`.
+	pub enum_name: String,
+
+	/// The properties that are the variants, in the order of the variants of the enum.
+	pub variants: Vec<PropertyName>,
+}
+
 /// The kind of a [`Schema`]
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub enum SchemaKind {
@@ -215,6 +290,10 @@ pub enum Type {
 	Patch,
 	WatchEvent(RefPath),
 
+	// Special type for string properties that only accept a fixed set of values, like `Container::image_pull_policy`.
+	// The values are not part of the spec, so they're added by a fixup.
+	StringEnum { values: Vec<String> },
+
 	// Special type for lists
 	ListDef { metadata: Box<SchemaKind> }, // The definition of the List type
 	ListRef { items: Box<SchemaKind> }, // A reference to a specialization of the List type for a particular resource type, eg List<Pod> for PodList
@@ -126,6 +126,10 @@ impl<'de> serde::Deserialize<'de> for Parameter {
 					kind: super::SchemaKind::Ty(super::Type::parse::<D>(&ty, None, None, None)?),
 					kubernetes_group_kind_versions: vec![],
 					list_kind: None,
+					scale_definition_path: None,
+					eviction_definition_path: None,
+					union: None,
+					patch_strategy: None,
 				},
 			),
 
@@ -136,6 +140,10 @@ impl<'de> serde::Deserialize<'de> for Parameter {
 					kind: super::SchemaKind::Ty(super::Type::parse::<D>(&ty, None, None, None)?),
 					kubernetes_group_kind_versions: vec![],
 					list_kind: None,
+					scale_definition_path: None,
+					eviction_definition_path: None,
+					union: None,
+					patch_strategy: None,
 				},
 			),
 
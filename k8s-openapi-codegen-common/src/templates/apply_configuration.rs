@@ -0,0 +1,87 @@
+pub(crate) fn generate(
+	mut writer: impl std::io::Write,
+	vis: &str,
+	type_name: &str,
+	apply_configuration_name: &str,
+	properties: &[super::Property<'_>],
+	field_type_names: &[String],
+	apply_configuration_feature: Option<&str>,
+	map_namespace: &impl crate::MapNamespace,
+	is_resource: bool,
+) -> Result<(), crate::Error> {
+	use std::fmt::Write;
+
+	let local = crate::map_namespace_local_to_string(map_namespace)?;
+
+	let cfg = apply_configuration_feature.map_or_else(String::new, |apply_configuration_feature| format!("#[cfg(feature = \"{apply_configuration_feature}\")]\n"));
+
+	let mut fields = String::new();
+	let mut fields_num = vec![];
+	let mut serialize_fields = String::new();
+
+	// The apply configuration of a resource is always serialized with the apiVersion and kind of the resource.
+	if is_resource {
+		fields_num.push("2".to_owned());
+		writeln!(
+			serialize_fields,
+			r#"        {local}serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <{type_name} as {local}Resource>::API_VERSION)?;"#,
+		)?;
+		writeln!(
+			serialize_fields,
+			r#"        {local}serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <{type_name} as {local}Resource>::KIND)?;"#,
+		)?;
+	}
+
+	for (super::Property { name, field_name, is_flattened, .. }, field_type_name) in properties.iter().zip(field_type_names) {
+		if *is_flattened {
+			return Err(format!("{type_name}.{field_name} is flattened, which is not supported by apply configurations").into());
+		}
+
+		if !fields.is_empty() {
+			writeln!(fields)?;
+		}
+		writeln!(fields, "    /// See [`{type_name}::{field_name}`].")?;
+		writeln!(fields, "    {vis}{field_name}: Option<{field_type_name}>,")?;
+
+		fields_num.push(format!("self.{field_name}.as_ref().map_or(0, |_| 1)"));
+
+		writeln!(serialize_fields, "        if let Some(value) = &self.{field_name} {{")?;
+		writeln!(serialize_fields, "            {local}serde::ser::SerializeStruct::serialize_field(&mut state, {name:?}, value)?;")?;
+		writeln!(serialize_fields, "        }}")?;
+	}
+
+	let state_mut = if fields_num.is_empty() { "" } else { "mut " };
+
+	let fields_num =
+		if fields_num.is_empty() {
+			"            0".to_owned()
+		}
+		else {
+			fields_num.iter().map(|field_num| format!("            {field_num}")).collect::<Vec<_>>().join(" +\n")
+		};
+
+	let serialize_type_name =
+		if is_resource {
+			format!("<{type_name} as {local}Resource>::KIND")
+		}
+		else {
+			format!("{apply_configuration_name:?}")
+		};
+
+	writeln!(
+		writer,
+		include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/apply_configuration.rs")),
+		local = local,
+		cfg = cfg,
+		vis = vis,
+		type_name = type_name,
+		apply_configuration_name = apply_configuration_name,
+		fields = fields,
+		state_mut = state_mut,
+		serialize_type_name = serialize_type_name,
+		fields_num = fields_num,
+		serialize_fields = serialize_fields,
+	)?;
+
+	Ok(())
+}
@@ -0,0 +1,63 @@
+pub(crate) fn generate(
+	mut writer: impl std::io::Write,
+	vis: &str,
+	type_name: &str,
+	builder_name: &str,
+	properties: &[super::Property<'_>],
+	builder_feature: Option<&str>,
+	map_namespace: &impl crate::MapNamespace,
+) -> Result<(), crate::Error> {
+	use std::fmt::Write;
+
+	let local = crate::map_namespace_local_to_string(map_namespace)?;
+
+	let cfg = builder_feature.map_or_else(String::new, |builder_feature| format!("#[cfg(feature = \"{builder_feature}\")]\n"));
+
+	let mut fields = String::new();
+	let mut setters = String::new();
+	let mut build_fields = String::new();
+
+	for super::Property { name, field_name, field_type_name, required, .. } in properties {
+		// The builder holds every field as an `Option`, so that `build` can tell whether the required ones have been set.
+		let value_type_name = match required {
+			super::PropertyRequired::Required => &**field_type_name,
+			super::PropertyRequired::Optional | super::PropertyRequired::OptionalDefault =>
+				field_type_name.strip_prefix("Option<").and_then(|s| s.strip_suffix('>'))
+				.ok_or_else(|| format!("optional field {type_name}.{field_name} has non-Option type {field_type_name}"))?,
+		};
+
+		writeln!(fields, "    {field_name}: Option<{value_type_name}>,")?;
+
+		writeln!(setters, "    /// Sets [`{type_name}::{field_name}`].")?;
+		writeln!(setters, "    {vis}fn {field_name}(mut self, value: impl Into<{value_type_name}>) -> Self {{")?;
+		writeln!(setters, "        self.{field_name} = Some(value.into());")?;
+		writeln!(setters, "        self")?;
+		writeln!(setters, "    }}")?;
+		writeln!(setters)?;
+
+		match required {
+			super::PropertyRequired::Required =>
+				writeln!(
+					build_fields,
+					"            {field_name}: self.{field_name}.ok_or_else(|| {local}MissingFieldError::new({type_name:?}, {name:?}))?,",
+				)?,
+			super::PropertyRequired::Optional | super::PropertyRequired::OptionalDefault =>
+				writeln!(build_fields, "            {field_name}: self.{field_name},")?,
+		}
+	}
+
+	writeln!(
+		writer,
+		include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/builder.rs")),
+		local = local,
+		cfg = cfg,
+		vis = vis,
+		type_name = type_name,
+		builder_name = builder_name,
+		fields = fields,
+		setters = setters,
+		build_fields = build_fields,
+	)?;
+
+	Ok(())
+}
@@ -0,0 +1,173 @@
+use crate::swagger20;
+
+pub(crate) fn generate(
+	mut writer: impl std::io::Write,
+	type_name: &str,
+	generics: super::Generics<'_>,
+	definition: &swagger20::Schema,
+	arbitrary_feature: Option<&str>,
+	map_namespace: &impl crate::MapNamespace,
+) -> Result<(), crate::Error> {
+	use std::fmt::Write;
+
+	let local = crate::map_namespace_local_to_string(map_namespace)?;
+
+	let type_generics_impl = generics.type_part.map_or_else(|| "<'a>".to_owned(), |part| format!("<'a, {}>", part));
+	let type_generics_type = generics.type_part.map(|part| format!("<{}>", part)).unwrap_or_default();
+	let type_generics_where = generics.where_part.map(|part| format!(" where {}", part)).unwrap_or_default();
+
+	let cfg = arbitrary_feature.map_or_else(String::new, |arbitrary_feature| format!("#[cfg(feature = \"{}\")]\n", arbitrary_feature));
+
+	let mut body = String::new();
+	let mut uses_unstructured = true;
+
+	match &definition.kind {
+		swagger20::SchemaKind::Properties(properties) => {
+			// apiVersion and kind are not fields of resource types. See the corresponding code in `crate::run`
+			let is_resource = definition.kubernetes_group_kind_versions.len() == 1;
+
+			let mut fields = String::new();
+			for (name, (schema, required)) in properties {
+				if is_resource && (name.0 == "apiVersion" || name.0 == "kind") {
+					continue;
+				}
+
+				let field_name = crate::get_rust_ident(name);
+				let field_value =
+					if *required {
+						gen_call(&schema.kind, &local)
+					}
+					else {
+						format!("{}arbitrary2::option(u, {})", local, gen_fn(&schema.kind, &local))
+					};
+				writeln!(fields, "            {}: {}?,", field_name, field_value)?;
+			}
+
+			if fields.is_empty() {
+				uses_unstructured = false;
+				writeln!(body, "        Ok({} {{}})", type_name)?;
+			}
+			else {
+				writeln!(body, "        {}arbitrary2::nested(u, |u| Ok({} {{", local, type_name)?;
+				body.push_str(&fields);
+				writeln!(body, "        }}))")?;
+			}
+		},
+
+		swagger20::SchemaKind::Ty(swagger20::Type::IntOrString) => {
+			writeln!(body, "        Ok(if u.arbitrary()? {{ {}::Int(u.arbitrary()?) }} else {{ {}::String(u.arbitrary()?) }})", type_name, type_name)?;
+		},
+
+		swagger20::SchemaKind::Ty(swagger20::Type::StringEnum { values }) => {
+			// Arbitrary strings are converted with `From<String>`, so that known values become their variants instead of `Other`.
+			writeln!(body, "        Ok(match u.int_in_range(0..={}_u8)? {{", values.len())?;
+			for (i, value) in values.iter().enumerate() {
+				writeln!(body, "            {i} => {type_name}::{},", super::string_enum::variant_name(value))?;
+			}
+			writeln!(body, "            _ => {type_name}::from(u.arbitrary::<String>()?),")?;
+			writeln!(body, "        }})")?;
+		},
+
+		swagger20::SchemaKind::Ty(swagger20::Type::JsonSchemaPropsOrArray(_)) => {
+			writeln!(body,
+				"        Ok(match {local}arbitrary2::option(u, {local}arbitrary::Arbitrary::arbitrary)? {{ Some(schema) => {type_name}::Schema(schema), None => {type_name}::Schemas({local}arbitrary2::vec(u, {local}arbitrary::Arbitrary::arbitrary)?) }})",
+				type_name = type_name, local = local)?;
+		},
+
+		swagger20::SchemaKind::Ty(swagger20::Type::JsonSchemaPropsOrBool(_)) => {
+			writeln!(body,
+				"        Ok(match {local}arbitrary2::option(u, {local}arbitrary::Arbitrary::arbitrary)? {{ Some(schema) => {type_name}::Schema(schema), None => {type_name}::Bool(u.arbitrary()?) }})",
+				type_name = type_name, local = local)?;
+		},
+
+		swagger20::SchemaKind::Ty(swagger20::Type::JsonSchemaPropsOrStringArray(_)) => {
+			writeln!(body,
+				"        Ok(match {local}arbitrary2::option(u, {local}arbitrary::Arbitrary::arbitrary)? {{ Some(schema) => {type_name}::Schema(schema), None => {type_name}::Strings({local}arbitrary2::vec(u, {local}arbitrary::Arbitrary::arbitrary)?) }})",
+				type_name = type_name, local = local)?;
+		},
+
+		swagger20::SchemaKind::Ty(swagger20::Type::Patch) => {
+			writeln!(body, "        Ok(match u.int_in_range(0..=2_u8)? {{")?;
+			writeln!(body, "            0 => {}::Json({}arbitrary2::vec(u, {}arbitrary2::json_value)?),", type_name, local, local)?;
+			writeln!(body, "            1 => {}::Merge({}arbitrary2::json_value(u)?),", type_name, local)?;
+			writeln!(body, "            _ => {}::StrategicMerge({}arbitrary2::json_value(u)?),", type_name, local)?;
+			writeln!(body, "        }})")?;
+		},
+
+		swagger20::SchemaKind::Ty(swagger20::Type::ListDef { .. }) => {
+			writeln!(body, "        Ok({} {{", type_name)?;
+			writeln!(body, "            items: {}arbitrary2::vec(u, {}arbitrary::Arbitrary::arbitrary)?,", local, local)?;
+			writeln!(body, "            metadata: u.arbitrary()?,")?;
+			writeln!(body, "        }})")?;
+		},
+
+		kind @ swagger20::SchemaKind::Ty(
+			swagger20::Type::Any |
+			swagger20::Type::Array { .. } |
+			swagger20::Type::Boolean |
+			swagger20::Type::Integer { .. } |
+			swagger20::Type::Number { .. } |
+			swagger20::Type::Object { .. } |
+			swagger20::Type::String { .. }
+		) => {
+			writeln!(body, "        Ok({}({}?))", type_name, gen_call(kind, &local))?;
+		},
+
+		kind => return Err(format!("Arbitrary impl not supported for {:?}", kind).into()),
+	}
+
+	writeln!(
+		writer,
+		include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/impl_arbitrary.rs")),
+		local = local,
+		cfg = cfg,
+		type_name = type_name,
+		type_generics_impl = type_generics_impl,
+		type_generics_type = type_generics_type,
+		type_generics_where = type_generics_where,
+		u = if uses_unstructured { "u" } else { "_u" },
+		body = body,
+	)?;
+
+	Ok(())
+}
+
+/// Whether values of this kind can be generated by their own impl of `arbitrary::Arbitrary`.
+///
+/// Collections are generated by the helpers in `arbitrary2` instead to bound their sizes,
+/// and some types like `serde_json::Value` and `chrono::DateTime` do not implement `arbitrary::Arbitrary` at all.
+fn is_plain(kind: &swagger20::SchemaKind) -> bool {
+	!matches!(kind, swagger20::SchemaKind::Ty(
+		swagger20::Type::Any |
+		swagger20::Type::Array { .. } |
+		swagger20::Type::Number { .. } |
+		swagger20::Type::Object { .. } |
+		swagger20::Type::String { format: Some(swagger20::StringFormat::DateTime) }
+	))
+}
+
+/// An expression that generates a value of this kind from `u`
+fn gen_call(kind: &swagger20::SchemaKind, local: &str) -> String {
+	match kind {
+		swagger20::SchemaKind::Ty(swagger20::Type::Any) => format!("{}arbitrary2::json_value(u)", local),
+		swagger20::SchemaKind::Ty(swagger20::Type::Array { items }) => format!("{}arbitrary2::vec(u, {})", local, gen_fn(&items.kind, local)),
+		swagger20::SchemaKind::Ty(swagger20::Type::Number { .. }) => format!("{}arbitrary2::json_f64(u)", local),
+		swagger20::SchemaKind::Ty(swagger20::Type::Object { additional_properties }) =>
+			format!("{}arbitrary2::map(u, {})", local, gen_fn(&additional_properties.kind, local)),
+		swagger20::SchemaKind::Ty(swagger20::Type::String { format: Some(swagger20::StringFormat::DateTime) }) =>
+			format!("{}arbitrary2::date_time(u)", local),
+		_ => format!("{}arbitrary::Arbitrary::arbitrary(u)", local),
+	}
+}
+
+/// An expression for a function that generates a value of this kind from its `arbitrary::Unstructured` parameter
+fn gen_fn(kind: &swagger20::SchemaKind, local: &str) -> String {
+	match kind {
+		swagger20::SchemaKind::Ty(swagger20::Type::Any) => format!("{}arbitrary2::json_value", local),
+		swagger20::SchemaKind::Ty(swagger20::Type::Number { .. }) => format!("{}arbitrary2::json_f64", local),
+		swagger20::SchemaKind::Ty(swagger20::Type::String { format: Some(swagger20::StringFormat::DateTime) }) =>
+			format!("{}arbitrary2::date_time", local),
+		kind if is_plain(kind) => format!("{}arbitrary::Arbitrary::arbitrary", local),
+		kind => format!("|u| {}", gen_call(kind, local)),
+	}
+}
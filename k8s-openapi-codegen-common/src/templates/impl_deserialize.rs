@@ -1,10 +1,12 @@
 pub(crate) fn generate(
 	mut writer: impl std::io::Write,
 	type_name: &str,
+	type_generics: super::Generics<'_>,
 	generics: super::Generics<'_>,
 	fields: &[super::Property<'_>],
 	map_namespace: &impl crate::MapNamespace,
 	resource_metadata: Option<&super::ResourceMetadata<'_>>,
+	generate_deserialize_in_place: crate::GenerateDeserializeInPlace<'_>,
 ) -> Result<(), crate::Error> {
 	use std::fmt::Write;
 
@@ -17,6 +19,20 @@ pub(crate) fn generate(
 	let type_generics_type = generics.type_part.map(|part| format!("<{}>", part)).unwrap_or_default();
 	let type_generics_where = generics.where_part.map(|part| format!(" where {}", part)).unwrap_or_default();
 
+	// The table of field names is an associated const of the type itself, so that both visitors can share it.
+	let fields_type_generics_impl = type_generics.type_part.map(|part| format!("<{part}>")).unwrap_or_default();
+	let fields_type_generics_where = type_generics.where_part.map(|part| format!(" where {part}")).unwrap_or_default();
+	let field_names =
+		if type_generics_type.is_empty() {
+			format!("{type_name}::FIELDS")
+		}
+		else {
+			format!("{type_name}::{type_generics_type}::FIELDS")
+		};
+
+	// The value of the visitor of `deserialize_in_place` is `()`, so it names the type itself.
+	let in_place_resource_type = format!("{type_name}{type_generics_type}");
+
 	let (visitor_field, visitor_create_field) =
 		if type_generics_type.is_empty() {
 			(String::new(), "")
@@ -25,42 +41,52 @@ pub(crate) fn generate(
 			(format!("(std::marker::PhantomData{})", type_generics_type), "(Default::default())")
 		};
 
-	let mut fields_string = String::new();
-	let mut str_to_field_match_arms = String::new();
 	let mut field_value_defs = String::new();
 	let mut field_value_match_arms = String::new();
 	let mut field_name_list = String::new();
 	let mut field_value_assignment = String::new();
+	let mut in_place_field_value_defs = String::new();
+	let mut in_place_field_value_match_arms = String::new();
+	let mut in_place_field_value_assignment = String::new();
 
 	let mut flattened_field = None;
 
-	if resource_metadata.is_some() {
-		writeln!(fields_string, "            Key_api_version,")?;
-		writeln!(fields_string, "            Key_kind,")?;
+	// Field identifiers are deserialized as indices into the table of field names in `field_name_list`,
+	// or as the name itself for unknown fields if there's a flattened field to collect them into.
+	let has_flattened_field = fields.iter().any(|super::Property { is_flattened, .. }| *is_flattened);
+	let key_pattern = |i: usize| if has_flattened_field { format!("Ok({i})") } else { format!("Some({i})") };
+	let mut num_keys = 0;
 
-		writeln!(str_to_field_match_arms, r#"                            "apiVersion" => Field::Key_api_version,"#)?;
-		writeln!(str_to_field_match_arms, r#"                            "kind" => Field::Key_kind,"#)?;
+	let required_fields: Vec<_> =
+		fields.iter()
+		.filter(|super::Property { required, is_flattened, .. }| !is_flattened && matches!(required, super::PropertyRequired::Required))
+		.map(|super::Property { name, .. }| format!("{name:?}"))
+		.collect();
+	let required_fields = format!("&[{}]", required_fields.join(", "));
 
-		writeln!(field_value_match_arms, r#"                        Field::Key_api_version => {{"#)?;
-		writeln!(field_value_match_arms, r#"                            let value_api_version: String = {}serde::de::MapAccess::next_value(&mut map)?;"#, local)?;
-		writeln!(field_value_match_arms, r#"                            if value_api_version != <Self::Value as {}Resource>::API_VERSION {{"#, local)?;
-		writeln!(field_value_match_arms,
-			r#"                                return Err({local}serde::de::Error::invalid_value({local}serde::de::Unexpected::Str(&value_api_version), &<Self::Value as {local}Resource>::API_VERSION));"#,
-			local = local)?;
-		writeln!(field_value_match_arms, r#"                            }}"#)?;
-		writeln!(field_value_match_arms, r#"                        }},"#)?;
+	if resource_metadata.is_some() {
+		for (field_value_match_arms, resource_type) in [(&mut field_value_match_arms, "Self::Value"), (&mut in_place_field_value_match_arms, &*in_place_resource_type)] {
+			writeln!(field_value_match_arms, r#"                        {} => {{"#, key_pattern(0))?;
+			writeln!(field_value_match_arms, r#"                            let value_api_version: String = {local}serde::de::MapAccess::next_value(&mut map)?;"#)?;
+			writeln!(field_value_match_arms, r#"                            if value_api_version != <{resource_type} as {local}Resource>::API_VERSION {{"#)?;
+			writeln!(field_value_match_arms,
+				r#"                                return Err({local}serde::de::Error::invalid_value({local}serde::de::Unexpected::Str(&value_api_version), &<{resource_type} as {local}Resource>::API_VERSION));"#)?;
+			writeln!(field_value_match_arms, r#"                            }}"#)?;
+			writeln!(field_value_match_arms, r#"                        }},"#)?;
+
+			writeln!(field_value_match_arms, r#"                        {} => {{"#, key_pattern(1))?;
+			writeln!(field_value_match_arms, r#"                            let value_kind: String = {local}serde::de::MapAccess::next_value(&mut map)?;"#)?;
+			writeln!(field_value_match_arms, r#"                            if value_kind != <{resource_type} as {local}Resource>::KIND {{"#)?;
+			writeln!(field_value_match_arms,
+				r#"                                return Err({local}serde::de::Error::invalid_value({local}serde::de::Unexpected::Str(&value_kind), &<{resource_type} as {local}Resource>::KIND));"#)?;
+			writeln!(field_value_match_arms, r#"                            }}"#)?;
+			writeln!(field_value_match_arms, r#"                        }},"#)?;
+		}
 
-		writeln!(field_value_match_arms, r#"                        Field::Key_kind => {{"#)?;
-		writeln!(field_value_match_arms, r#"                            let value_kind: String = {}serde::de::MapAccess::next_value(&mut map)?;"#, local)?;
-		writeln!(field_value_match_arms, r#"                            if value_kind != <Self::Value as {}Resource>::KIND {{"#, local)?;
-		writeln!(field_value_match_arms,
-			r#"                                return Err({local}serde::de::Error::invalid_value({local}serde::de::Unexpected::Str(&value_kind), &<Self::Value as {}Resource>::KIND));"#,
-			local = local)?;
-		writeln!(field_value_match_arms, r#"                            }}"#)?;
-		writeln!(field_value_match_arms, r#"                        }},"#)?;
+		writeln!(field_name_list, r#"        "apiVersion","#)?;
+		writeln!(field_name_list, r#"        "kind","#)?;
 
-		writeln!(field_name_list, r#"                "apiVersion","#)?;
-		writeln!(field_name_list, r#"                "kind","#)?;
+		num_keys += 2;
 	}
 	for super::Property { name, field_name, field_type_name, required, is_flattened, .. } in fields {
 		if *is_flattened {
@@ -73,58 +99,80 @@ pub(crate) fn generate(
 			writeln!(field_value_defs,
 				r#"                let mut value_{}: std::collections::BTreeMap<{local}serde_value::Value, {local}serde_value::Value> = Default::default();"#,
 				field_name, local = local)?;
+			writeln!(in_place_field_value_defs,
+				r#"                let mut value_{field_name}: std::collections::BTreeMap<{local}serde_value::Value, {local}serde_value::Value> = Default::default();"#)?;
 		}
 		else {
-			writeln!(fields_string, "            Key_{},", field_name)?;
+			let key_pattern = key_pattern(num_keys);
+			num_keys += 1;
 
-			writeln!(str_to_field_match_arms, r#"                            {:?} => Field::Key_{},"#, name, field_name)?;
+			writeln!(in_place_field_value_defs, "                let mut has_{field_name} = false;")?;
 
 			match required {
 				super::PropertyRequired::Required => {
 					writeln!(field_value_defs, r#"                let mut value_{}: Option<{}> = None;"#, field_name, field_type_name)?;
 
 					writeln!(field_value_match_arms,
-						r#"                        Field::Key_{} => value_{} = Some({}serde::de::MapAccess::next_value(&mut map)?),"#,
-						field_name, field_name, local)?;
+						r#"                        {} => value_{} = Some({}serde::de::MapAccess::next_value(&mut map)?),"#,
+						key_pattern, field_name, local)?;
 
 					writeln!(field_value_assignment,
-						"                    {}: value_{}.ok_or_else(|| {}serde::de::Error::missing_field({:?}))?,",
-						field_name, field_name, local, name)?;
+						"                    {}: value_{}.ok_or_else(|| {}serde2::missing_field({:?}, {:?}, {}))?,",
+						field_name, field_name, local, type_name, name, required_fields)?;
+
+					writeln!(in_place_field_value_match_arms,
+						r#"                        {key_pattern} => {{ {local}serde::de::MapAccess::next_value_seed(&mut map, {local}DeserializeInPlace(&mut self.0.{field_name}))?; has_{field_name} = true; }},"#)?;
+
+					writeln!(in_place_field_value_assignment, "                if !has_{field_name} {{")?;
+					writeln!(in_place_field_value_assignment,
+						"                    return Err({local}serde2::missing_field({type_name:?}, {name:?}, {required_fields}));")?;
+					writeln!(in_place_field_value_assignment, "                }}")?;
 				},
 
 				super::PropertyRequired::Optional => {
 					writeln!(field_value_defs, r#"                let mut value_{}: {} = None;"#, field_name, field_type_name)?;
 
 					writeln!(field_value_match_arms,
-						r#"                        Field::Key_{} => value_{} = {}serde::de::MapAccess::next_value(&mut map)?,"#,
-						field_name, field_name, local)?;
+						r#"                        {} => value_{} = {}serde::de::MapAccess::next_value(&mut map)?,"#,
+						key_pattern, field_name, local)?;
 
 					writeln!(field_value_assignment, "                    {}: value_{},", field_name, field_name)?;
+
+					writeln!(in_place_field_value_match_arms,
+						r#"                        {key_pattern} => {{ {local}serde::de::MapAccess::next_value_seed(&mut map, {local}serde2::OptionInPlace(&mut self.0.{field_name}))?; has_{field_name} = true; }},"#)?;
+
+					writeln!(in_place_field_value_assignment, "                if !has_{field_name} {{")?;
+					writeln!(in_place_field_value_assignment, "                    self.0.{field_name} = None;")?;
+					writeln!(in_place_field_value_assignment, "                }}")?;
 				},
 
 				super::PropertyRequired::OptionalDefault => {
 					writeln!(field_value_defs, r#"                let mut value_{}: Option<{}> = None;"#, field_name, field_type_name)?;
 
 					writeln!(field_value_match_arms,
-						r#"                        Field::Key_{} => value_{} = {}serde::de::MapAccess::next_value(&mut map)?,"#,
-						field_name, field_name, local)?;
+						r#"                        {} => value_{} = {}serde::de::MapAccess::next_value(&mut map)?,"#,
+						key_pattern, field_name, local)?;
 
 					writeln!(field_value_assignment, "                    {}: value_{}.unwrap_or_default(),", field_name, field_name)?;
+
+					writeln!(in_place_field_value_match_arms,
+						r#"                        {key_pattern} => {{ {local}serde::de::MapAccess::next_value_seed(&mut map, {local}serde2::OptionalDefaultInPlace(&mut self.0.{field_name}))?; has_{field_name} = true; }},"#)?;
+
+					writeln!(in_place_field_value_assignment, "                if !has_{field_name} {{")?;
+					writeln!(in_place_field_value_assignment, "                    self.0.{field_name} = Default::default();")?;
+					writeln!(in_place_field_value_assignment, "                }}")?;
 				},
 			}
 
-			writeln!(field_name_list, r#"                {:?},"#, name)?;
+			writeln!(field_name_list, r#"        {:?},"#, name)?;
 		}
 	}
 
 	if let Some((field_name, _)) = flattened_field {
-		writeln!(fields_string, "            Other(String),")?;
-
-		writeln!(str_to_field_match_arms, "                            v => Field::Other(v.to_owned()),")?;
-
 		writeln!(field_value_match_arms,
-			"                        Field::Other(key) => {{ value_{}.insert({local}serde_value::Value::String(key), {local}serde::de::MapAccess::next_value(&mut map)?); }},",
+			"                        Err(key) => {{ value_{}.insert({local}serde_value::Value::String(key), {local}serde::de::MapAccess::next_value(&mut map)?); }},",
 			field_name, local = local)?;
+		writeln!(field_value_match_arms, "                        Ok(_) => unreachable!(),")?;
 
 		writeln!(field_value_assignment,
 			"                    {}: {{",
@@ -143,15 +191,38 @@ pub(crate) fn generate(
 			field_name)?;
 		writeln!(field_value_assignment,
 			"                    }},")?;
-	}
-	else {
-		writeln!(fields_string, "            Other,")?;
 
-		writeln!(str_to_field_match_arms, "                            _ => Field::Other,")?;
+		writeln!(in_place_field_value_match_arms,
+			"                        Err(key) => {{ value_{field_name}.insert({local}serde_value::Value::String(key), {local}serde::de::MapAccess::next_value(&mut map)?); }},")?;
+		writeln!(in_place_field_value_match_arms, "                        Ok(_) => unreachable!(),")?;
 
+		writeln!(in_place_field_value_assignment,
+			"                let value_{field_name} = {local}serde_value::Value::Map(value_{field_name});")?;
+		writeln!(in_place_field_value_assignment,
+			"                let value_{field_name} = {local}serde_value::ValueDeserializer::new(value_{field_name});")?;
+		writeln!(in_place_field_value_assignment,
+			"                {local}serde::Deserialize::deserialize_in_place(value_{field_name}, &mut self.0.{field_name})?;")?;
+	}
+	else {
 		writeln!(field_value_match_arms,
-			"                        Field::Other => {{ let _: {local}serde::de::IgnoredAny = {local}serde::de::MapAccess::next_value(&mut map)?; }},",
+			"                        _ => {{ let _: {local}serde::de::IgnoredAny = {local}serde::de::MapAccess::next_value(&mut map)?; }},",
 			local = local)?;
+
+		writeln!(in_place_field_value_match_arms,
+			"                        _ => {{ let _: {local}serde::de::IgnoredAny = {local}serde::de::MapAccess::next_value(&mut map)?; }},")?;
+	}
+
+	// Formats that are not self-describing, like bincode, deserialize structs as sequences of all their fields in order.
+	// See the corresponding code in `super::impl_serialize`
+	let mut field_seq_assignment = String::new();
+	let mut in_place_field_seq_assignment = String::new();
+	for (i, super::Property { field_name, required, is_flattened, .. }) in fields.iter().enumerate() {
+		writeln!(field_seq_assignment,
+			"                    {field_name}: {local}serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| {local}serde::de::Error::invalid_length({i}, &self))?,")?;
+
+		let seed = if !is_flattened && matches!(required, super::PropertyRequired::Optional) { "serde2::OptionInPlace" } else { "DeserializeInPlace" };
+		writeln!(in_place_field_seq_assignment,
+			"                {local}serde::de::SeqAccess::next_element_seed(&mut seq, {local}{seed}(&mut self.0.{field_name}))?.ok_or_else(|| {local}serde::de::Error::invalid_length({i}, &self))?;")?;
 	}
 
 	let deserialize_type_name =
@@ -170,6 +241,49 @@ pub(crate) fn generate(
 			format!("{:?}", type_name)
 		};
 
+	let in_place_visitor_expecting_type_name =
+		if resource_metadata.is_some() {
+			format!("<{in_place_resource_type} as {local}Resource>::KIND")
+		}
+		else {
+			format!("{type_name:?}")
+		};
+
+	let field_index_seed =
+		if has_flattened_field {
+			format!("FieldIndexOrName({field_names})")
+		}
+		else {
+			format!("FieldIndex({type_name:?}, {field_names})")
+		};
+
+	let deserialize_in_place =
+		if let crate::GenerateDeserializeInPlace::Yes { feature } = generate_deserialize_in_place {
+			let deserialize_in_place_cfg = feature.map(|feature| format!("    #[cfg(feature = {feature:?})]\n")).unwrap_or_default();
+
+			format!(
+				include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/impl_deserialize_in_place.rs")),
+				local = local,
+				type_name = type_name,
+				type_generics_impl = type_generics_impl,
+				type_generics_type = type_generics_type,
+				type_generics_where = type_generics_where,
+				field_index_seed = field_index_seed,
+				field_names = field_names,
+				deserialize_type_name = deserialize_type_name,
+				seq_param = if fields.is_empty() { "_" } else { "mut seq" },
+				deserialize_in_place_cfg = deserialize_in_place_cfg,
+				in_place_visitor_expecting_type_name = in_place_visitor_expecting_type_name,
+				in_place_field_value_defs = in_place_field_value_defs,
+				in_place_field_value_match_arms = in_place_field_value_match_arms,
+				in_place_field_value_assignment = in_place_field_value_assignment,
+				in_place_field_seq_assignment = in_place_field_seq_assignment,
+			)
+		}
+		else {
+			String::new()
+		};
+
 	writeln!(
 		writer,
 		include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/impl_deserialize.rs")),
@@ -178,8 +292,10 @@ pub(crate) fn generate(
 		type_generics_impl = type_generics_impl,
 		type_generics_type = type_generics_type,
 		type_generics_where = type_generics_where,
-		fields = fields_string,
-		str_to_field_match_arms = str_to_field_match_arms,
+		fields_type_generics_impl = fields_type_generics_impl,
+		fields_type_generics_where = fields_type_generics_where,
+		field_names = field_names,
+		field_index_seed = field_index_seed,
 		field_value_defs = field_value_defs,
 		field_value_match_arms = field_value_match_arms,
 		field_value_assignment = field_value_assignment,
@@ -188,6 +304,9 @@ pub(crate) fn generate(
 		visitor_create_field = visitor_create_field,
 		deserialize_type_name = deserialize_type_name,
 		visitor_expecting_type_name = visitor_expecting_type_name,
+		seq_param = if fields.is_empty() { "_" } else { "mut seq" },
+		field_seq_assignment = field_seq_assignment,
+		deserialize_in_place = deserialize_in_place,
 	)?;
 
 	Ok(())
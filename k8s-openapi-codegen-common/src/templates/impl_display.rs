@@ -0,0 +1,32 @@
+pub(crate) fn generate(
+	mut writer: impl std::io::Write,
+	type_name: &str,
+	generics: super::Generics<'_>,
+	map_namespace: &impl crate::MapNamespace,
+	resource_metadata: &super::ResourceMetadata<'_>,
+) -> Result<(), crate::Error> {
+	// Only top-level resources are identified by their URL path segment, namespace and name, like `pods/kube-system/coredns-xyz`.
+	// Subresources like `Scale` share the name of their parent resource, and lists do not have names.
+	let is_object_meta = resource_metadata.metadata_ty.is_some_and(|metadata_ty| metadata_ty.ends_with("::apimachinery::pkg::apis::meta::v1::ObjectMeta"));
+	let is_subresource = resource_metadata.url_path_segment_and_scope.1.ends_with("SubResourceScope");
+
+	if is_object_meta && !is_subresource {
+		let local = crate::map_namespace_local_to_string(map_namespace)?;
+
+		let type_generics_impl = generics.type_part.map(|part| format!("<{part}>")).unwrap_or_default();
+		let type_generics_type = generics.type_part.map(|part| format!("<{part}>")).unwrap_or_default();
+		let type_generics_where = generics.where_part.map(|part| format!(" where {part}")).unwrap_or_default();
+
+		writeln!(
+			writer,
+			include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/impl_display.rs")),
+			local = local,
+			type_name = type_name,
+			type_generics_impl = type_generics_impl,
+			type_generics_type = type_generics_type,
+			type_generics_where = type_generics_where,
+		)?;
+	}
+
+	Ok(())
+}
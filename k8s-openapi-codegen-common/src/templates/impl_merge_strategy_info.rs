@@ -0,0 +1,50 @@
+pub(crate) fn generate(
+	mut writer: impl std::io::Write,
+	type_name: &str,
+	generics: super::Generics<'_>,
+	properties: &std::collections::BTreeMap<crate::swagger20::PropertyName, (crate::swagger20::Schema, bool)>,
+	map_namespace: &impl crate::MapNamespace,
+) -> Result<(), crate::Error> {
+	use std::fmt::Write;
+
+	let local = crate::map_namespace_local_to_string(map_namespace)?;
+
+	let type_generics_impl = generics.type_part.map(|part| format!("<{part}>")).unwrap_or_default();
+	let type_generics_type = generics.type_part.map(|part| format!("<{part}>")).unwrap_or_default();
+	let type_generics_where = generics.where_part.map(|part| format!(" where {part}")).unwrap_or_default();
+
+	// Most types have no annotated properties, and use the default empty list of the trait.
+	let mut field_patch_strategies = String::new();
+	for (name, (schema, _)) in properties {
+		let Some(crate::swagger20::PatchStrategy { merge, retain_keys, merge_key }) = &schema.patch_strategy else {
+			continue;
+		};
+
+		if field_patch_strategies.is_empty() {
+			writeln!(field_patch_strategies)?;
+			writeln!(field_patch_strategies, "    const FIELD_PATCH_STRATEGIES: &'static [{local}FieldPatchStrategy] = &[")?;
+		}
+
+		writeln!(
+			field_patch_strategies,
+			"        {local}FieldPatchStrategy {{ field: {name:?}, merge: {merge}, retain_keys: {retain_keys}, merge_key: {merge_key:?} }},",
+			name = &**name,
+		)?;
+	}
+	if !field_patch_strategies.is_empty() {
+		writeln!(field_patch_strategies, "    ];")?;
+	}
+
+	writeln!(
+		writer,
+		include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/impl_merge_strategy_info.rs")),
+		local = local,
+		type_name = type_name,
+		type_generics_impl = type_generics_impl,
+		type_generics_type = type_generics_type,
+		type_generics_where = type_generics_where,
+		field_patch_strategies = field_patch_strategies,
+	)?;
+
+	Ok(())
+}
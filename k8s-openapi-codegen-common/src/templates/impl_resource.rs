@@ -13,6 +13,12 @@ pub(crate) fn generate(
 
 	let (url_path_segment, scope) = resource_metadata.url_path_segment_and_scope;
 
+	let verbs: Vec<_> = resource_metadata.verbs.iter().map(|verb| format!("{verb:?}")).collect();
+	let verbs = verbs.join(", ");
+
+	let short_names: Vec<_> = resource_metadata.short_names.iter().map(|short_name| format!("{short_name:?}")).collect();
+	let short_names = short_names.join(", ");
+
 	writeln!(
 		writer,
 		include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/impl_resource.rs")),
@@ -26,6 +32,9 @@ pub(crate) fn generate(
 		kind = resource_metadata.kind,
 		version = resource_metadata.version,
 		url_path_segment = url_path_segment,
+		verbs = verbs,
+		singular = resource_metadata.singular,
+		short_names = short_names,
 		scope = scope,
 	)?;
 
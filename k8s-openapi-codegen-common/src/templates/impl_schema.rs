@@ -6,11 +6,42 @@ pub(crate) fn generate(
 	generics: super::Generics<'_>,
 	definition_path: &swagger20::DefinitionPath,
 	definition: &swagger20::Schema,
+	type_meta: Option<(&str, &str)>,
+	naming: crate::SchemaNaming<'_>,
 	schema_feature: Option<&str>,
 	map_namespace: &impl crate::MapNamespace,
 ) -> Result<(), crate::Error> {
 	let local = crate::map_namespace_local_to_string(map_namespace)?;
 
+	let rust_path = || {
+		let type_name = crate::get_fully_qualified_type_name(&swagger20::RefPath {
+			path: definition_path.0.clone(),
+			can_be_default: None,
+		}, map_namespace);
+		match type_name.strip_prefix(&*local) {
+			Some(rust_path) => rust_path.to_owned(),
+			None => type_name,
+		}
+	};
+
+	// The schemas of the generic types depend on `T`, so their names incorporate the name of `T`'s schema.
+	let schema_name_expr = |name: &str| match &definition.kind {
+		swagger20::SchemaKind::Ty(swagger20::Type::ListDef { .. }) =>
+			format!("format!(\"{{}}List\", <T as {local}schemars::JsonSchema>::schema_name())"),
+		swagger20::SchemaKind::Ty(swagger20::Type::WatchEvent(_)) =>
+			format!("format!(\"{{}}_for_{{}}\", {name:?}, <T as {local}schemars::JsonSchema>::schema_name())"),
+		_ => format!("{name:?}.to_owned()"),
+	};
+
+	let schema_name = match (naming, &definition.kind) {
+		(_, swagger20::SchemaKind::Ty(swagger20::Type::ListDef { .. })) |
+		(crate::SchemaNaming::DefinitionPath, _) => format!("        {}\n", schema_name_expr(definition_path)),
+		(crate::SchemaNaming::RustPath, _) => format!("        {}\n", schema_name_expr(&rust_path())),
+		(crate::SchemaNaming::RustPathIfFeature(feature), _) => format!(
+			"        if cfg!(feature = {feature:?}) {{\n            {}\n        }}\n        else {{\n            {}\n        }}\n",
+			schema_name_expr(&rust_path()), schema_name_expr(definition_path)),
+	};
+
 	let type_generics_impl = generics.type_part.map(|part| format!("<{}>", part)).unwrap_or_default();
 	let type_generics_type = generics.type_part.map(|part| format!("<{}>", part)).unwrap_or_default();
 	let type_generics_where = generics.where_part.map(|part| format!(" where {}", part)).unwrap_or_default();
@@ -19,7 +50,7 @@ pub(crate) fn generate(
 
 	let mut schema = String::new();
 
-	gen_schema(&mut schema, definition, &local, map_namespace, 1)?;
+	gen_schema(&mut schema, definition, type_meta, &local, map_namespace, 1)?;
 
 	writeln!(
 		writer,
@@ -30,7 +61,7 @@ pub(crate) fn generate(
 		type_generics_impl = type_generics_impl,
 		type_generics_type = type_generics_type,
 		type_generics_where = type_generics_where,
-		definition_path = &**definition_path,
+		schema_name = schema_name,
 		schema = schema,
 	)?;
 
@@ -40,6 +71,7 @@ pub(crate) fn generate(
 fn gen_schema(
 	out: &mut String,
 	definition: &swagger20::Schema,
+	type_meta: Option<(&str, &str)>,
 	local: &str,
 	map_namespace: &impl crate::MapNamespace,
 	depth: usize,
@@ -67,15 +99,41 @@ fn gen_schema(
 			let mut required_props = String::new();
 			let mut props = String::new();
 			for (name, (schema, required)) in properties {
-				if *required {
+				// The expression for the value of the `apiVersion` or `kind` property of a resource type, if it should be restricted to that value.
+				let const_value = match (type_meta, &**name) {
+					(Some((api_version, _)), "apiVersion") => Some(api_version),
+					(Some((_, kind)), "kind") => Some(kind),
+					_ => None,
+				};
+
+				if *required || const_value.is_some() {
 					writeln!(required_props, "{indent}            {:?},", &**name, indent = indent)?;
 				}
 
+				if let Some(const_value) = const_value {
+					writeln!(props, "{indent}            properties.insert(")?;
+					writeln!(props, "{indent}                {:?}.to_owned(),", &**name, indent = indent)?;
+					writeln!(props, "{indent}                {local}schemars::schema::Schema::Object({local}schemars::schema::SchemaObject {{")?;
+					if let Some(description) = &schema.description {
+						writeln!(props, "{indent}                    metadata: Some(Box::new({local}schemars::schema::Metadata {{")?;
+						writeln!(props, "{indent}                        description: Some({description:?}.to_owned()),")?;
+						writeln!(props, "{indent}                        ..Default::default()")?;
+						writeln!(props, "{indent}                    }})),")?;
+					}
+					writeln!(props,
+						"{indent}                    instance_type: Some({local}schemars::schema::SingleOrVec::Single(Box::new({local}schemars::schema::InstanceType::String))),")?;
+					writeln!(props, "{indent}                    enum_values: Some(vec![{local}serde_json::Value::String({const_value}.to_owned())]),")?;
+					writeln!(props, "{indent}                    ..Default::default()")?;
+					writeln!(props, "{indent}                }}),")?;
+					writeln!(props, "{indent}            );")?;
+					continue;
+				}
+
 				match &schema.kind {
 					swagger20::SchemaKind::Properties(_) => unreachable!("unexpected nested properties"),
 
 					swagger20::SchemaKind::Ref(ref_path) => {
-						writeln!(props, "{indent}            (", indent = indent)?;
+						writeln!(props, "{indent}            properties.insert(", indent = indent)?;
 						writeln!(props, "{indent}                {:?}.to_owned(),", &**name, indent = indent)?;
 
 						let type_name = crate::get_fully_qualified_type_name(ref_path, map_namespace);
@@ -94,22 +152,24 @@ fn gen_schema(
 							writeln!(props, "{indent}                __gen.subschema_for::<{}>(),", type_name, indent = indent)?;
 						}
 
-						writeln!(props, "{indent}            ),", indent = indent)?;
+						writeln!(props, "{indent}            );", indent = indent)?;
 					},
 
 					swagger20::SchemaKind::Ty(ty) => {
-						writeln!(props, "{indent}            (", indent = indent)?;
+						writeln!(props, "{indent}            properties.insert(", indent = indent)?;
 						writeln!(props, "{indent}                {:?}.to_owned(),", &**name, indent = indent)?;
 						gen_type_as_schema_object(&mut props, ty, schema.description.as_deref(), local, map_namespace, depth)?;
-						writeln!(props, "{indent}            ),", indent = indent)?;
+						writeln!(props, "{indent}            );", indent = indent)?;
 					},
 				}
 			}
 
 			if !props.is_empty() {
-				writeln!(out, "{indent}        properties: std::array::IntoIter::new([", indent = indent)?;
+				writeln!(out, "{indent}        properties: {{", indent = indent)?;
+				writeln!(out, "{indent}            let mut properties = {local}schemars::Map::new();")?;
 				write!(out, "{}", props)?;
-				writeln!(out, "{indent}        ]).collect(),", indent = indent)?;
+				writeln!(out, "{indent}            properties")?;
+				writeln!(out, "{indent}        }},", indent = indent)?;
 			}
 
 			if !required_props.is_empty() {
@@ -122,6 +182,9 @@ fn gen_schema(
 			writeln!(out, "{indent}    }})),", indent = indent)?;
 		},
 
+		swagger20::SchemaKind::Ty(swagger20::Type::ListDef { metadata }) =>
+			gen_list_def(out, metadata, type_meta, local, map_namespace, depth)?,
+
 		swagger20::SchemaKind::Ty(ty) =>
 			gen_type(out, ty, local, map_namespace, depth + 1)?,
 
@@ -134,6 +197,117 @@ fn gen_schema(
 	Ok(())
 }
 
+/// Generates the `object` of the schema of the generic `List<T>` type.
+///
+/// Its items are of type `T`, and its `apiVersion` and `kind` are those of `T`'s list, so they can't be derived from its definition like other types.
+fn gen_list_def(
+	out: &mut String,
+	metadata: &swagger20::SchemaKind,
+	type_meta: Option<(&str, &str)>,
+	local: &str,
+	map_namespace: &impl crate::MapNamespace,
+	depth: usize,
+) -> Result<(), crate::Error> {
+	use std::fmt::Write;
+
+	let indent = "    ".repeat(depth + 1);
+
+	let metadata_type_name = match metadata {
+		swagger20::SchemaKind::Ref(ref_path) => crate::get_fully_qualified_type_name(ref_path, map_namespace),
+		metadata => return Err(format!("unexpected List metadata type {metadata:?}").into()),
+	};
+
+	let type_meta_property = |out: &mut String, name: &str, description: &str, const_value: Option<&str>| -> std::fmt::Result {
+		writeln!(out, "{indent}            properties.insert(")?;
+		writeln!(out, "{indent}                {name:?}.to_owned(),")?;
+		writeln!(out, "{indent}                {local}schemars::schema::Schema::Object({local}schemars::schema::SchemaObject {{")?;
+		writeln!(out, "{indent}                    metadata: Some(Box::new({local}schemars::schema::Metadata {{")?;
+		writeln!(out, "{indent}                        description: Some({description:?}.to_owned()),")?;
+		writeln!(out, "{indent}                        ..Default::default()")?;
+		writeln!(out, "{indent}                    }})),")?;
+		writeln!(out,
+			"{indent}                    instance_type: Some({local}schemars::schema::SingleOrVec::Single(Box::new({local}schemars::schema::InstanceType::String))),")?;
+		if let Some(const_value) = const_value {
+			writeln!(out, "{indent}                    enum_values: Some(vec![{local}serde_json::Value::String({const_value}.to_owned())]),")?;
+		}
+		writeln!(out, "{indent}                    ..Default::default()")?;
+		writeln!(out, "{indent}                }}),")?;
+		writeln!(out, "{indent}            );")?;
+		Ok(())
+	};
+
+	writeln!(out,
+		"{indent}    instance_type: Some({local}schemars::schema::SingleOrVec::Single(Box::new({local}schemars::schema::InstanceType::Object))),")?;
+	writeln!(out, "{indent}    object: Some(Box::new({local}schemars::schema::ObjectValidation {{")?;
+	writeln!(out, "{indent}        properties: {{")?;
+	writeln!(out, "{indent}            let mut properties = {local}schemars::Map::new();")?;
+
+	type_meta_property(
+		out,
+		"apiVersion",
+		"APIVersion defines the versioned schema of this representation of an object. \
+		 Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. \
+		 More info: https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#resources",
+		type_meta.map(|(api_version, _)| api_version),
+	)?;
+
+	writeln!(out, "{indent}            properties.insert(")?;
+	writeln!(out, r#"{indent}                "items".to_owned(),"#)?;
+	writeln!(out, "{indent}                {local}schemars::schema::Schema::Object({local}schemars::schema::SchemaObject {{")?;
+	writeln!(out, "{indent}                    metadata: Some(Box::new({local}schemars::schema::Metadata {{")?;
+	writeln!(out, r#"{indent}                        description: Some("List of objects.".to_owned()),"#)?;
+	writeln!(out, "{indent}                        ..Default::default()")?;
+	writeln!(out, "{indent}                    }})),")?;
+	writeln!(out,
+		"{indent}                    instance_type: Some({local}schemars::schema::SingleOrVec::Single(Box::new({local}schemars::schema::InstanceType::Array))),")?;
+	writeln!(out, "{indent}                    array: Some(Box::new({local}schemars::schema::ArrayValidation {{")?;
+	writeln!(out, "{indent}                        items: Some({local}schemars::schema::SingleOrVec::Single(Box::new(__gen.subschema_for::<T>()))),")?;
+	writeln!(out, "{indent}                        ..Default::default()")?;
+	writeln!(out, "{indent}                    }})),")?;
+	writeln!(out, "{indent}                    ..Default::default()")?;
+	writeln!(out, "{indent}                }}),")?;
+	writeln!(out, "{indent}            );")?;
+
+	type_meta_property(
+		out,
+		"kind",
+		"Kind is a string value representing the REST resource this object represents. \
+		 Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. \
+		 More info: https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#types-kinds",
+		type_meta.map(|(_, kind)| kind),
+	)?;
+
+	writeln!(out, "{indent}            properties.insert(")?;
+	writeln!(out, r#"{indent}                "metadata".to_owned(),"#)?;
+	writeln!(out, "{indent}                {{")?;
+	writeln!(out, "{indent}                    let mut schema_obj = __gen.subschema_for::<{metadata_type_name}>().into_object();")?;
+	writeln!(out, "{indent}                    schema_obj.metadata = Some(Box::new({local}schemars::schema::Metadata {{")?;
+	writeln!(out,
+		r#"{indent}                        description: Some("Standard list metadata. More info: https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#types-kinds".to_owned()),"#)?;
+	writeln!(out, "{indent}                        ..Default::default()")?;
+	writeln!(out, "{indent}                    }}));")?;
+	writeln!(out, "{indent}                    {local}schemars::schema::Schema::Object(schema_obj)")?;
+	writeln!(out, "{indent}                }},")?;
+	writeln!(out, "{indent}            );")?;
+
+	writeln!(out, "{indent}            properties")?;
+	writeln!(out, "{indent}        }},")?;
+	writeln!(out, "{indent}        required: std::array::IntoIter::new([")?;
+	if type_meta.is_some() {
+		writeln!(out, r#"{indent}            "apiVersion","#)?;
+	}
+	writeln!(out, r#"{indent}            "items","#)?;
+	if type_meta.is_some() {
+		writeln!(out, r#"{indent}            "kind","#)?;
+	}
+	writeln!(out, r#"{indent}            "metadata","#)?;
+	writeln!(out, "{indent}        ]).map(std::borrow::ToOwned::to_owned).collect(),")?;
+	writeln!(out, "{indent}        ..Default::default()")?;
+	writeln!(out, "{indent}    }})),")?;
+
+	Ok(())
+}
+
 fn gen_type_as_schema_object(
 	out: &mut String,
 	ty: &swagger20::Type,
@@ -183,10 +357,15 @@ fn gen_type(
 	let indent = "    ".repeat(depth + 1);
 
 	match ty {
-		swagger20::Type::Any =>
+		swagger20::Type::Any => {
 			writeln!(out,
 				"{indent}instance_type: Some({local}schemars::schema::SingleOrVec::Single(Box::new({local}schemars::schema::InstanceType::Object))),",
-				indent = indent, local = local)?,
+				indent = indent, local = local)?;
+			// Without this, the API server prunes all the fields of the value when the schema is used in a CRD.
+			writeln!(out, "{indent}extensions: std::array::IntoIter::new([")?;
+			writeln!(out, r#"{indent}    ("x-kubernetes-preserve-unknown-fields".to_owned(), {local}serde_json::Value::Bool(true)),"#)?;
+			writeln!(out, "{indent}]).collect(),")?;
+		},
 
 		swagger20::Type::JsonSchemaPropsOrArray(v) => {
 			let json_schema_props_type_name = crate::get_fully_qualified_type_name(&swagger20::RefPath {
@@ -289,7 +468,7 @@ fn gen_type(
 					crate::get_fully_qualified_type_name(ref_path, map_namespace), indent = indent, local = local)?;
 			} else {
 				writeln!(out, "{indent}    items: Some({local}schemars::schema::SingleOrVec::Single(Box::new(", indent = indent, local = local)?;
-				gen_schema(out, items, local, map_namespace, depth + 2)?;
+				gen_schema(out, items, None, local, map_namespace, depth + 2)?;
 				writeln!(out, "{indent}    ))),", indent = indent)?;
 			}
 			writeln!(out, "{indent}    ..Default::default()", indent = indent)?;
@@ -340,7 +519,7 @@ fn gen_type(
 					crate::get_fully_qualified_type_name(ref_path, map_namespace), indent = indent)?;
 			} else {
 				writeln!(out, "{indent}    additional_properties: Some(Box::new(", indent = indent)?;
-				gen_schema(out, additional_properties, local, map_namespace, depth + 2)?;
+				gen_schema(out, additional_properties, None, local, map_namespace, depth + 2)?;
 				writeln!(out, "{indent}    )),", indent = indent)?;
 			}
 			writeln!(out, "{indent}    ..Default::default()", indent = indent)?;
@@ -369,24 +548,31 @@ fn gen_type(
 			writeln!(out, r#"{indent}format: Some("int-or-string".to_owned()),"#, indent = indent)?;
 		}
 
+		// The values are not constrained by the schema, since newer versions of Kubernetes may accept more values than the ones known to the enum.
+		swagger20::Type::StringEnum { .. } => {
+			writeln!(out,
+				"{indent}instance_type: Some({local}schemars::schema::SingleOrVec::Single(Box::new({local}schemars::schema::InstanceType::String))),")?;
+		}
+
 		swagger20::Type::Patch => {
 			writeln!(out,
 				"{indent}instance_type: Some({local}schemars::schema::SingleOrVec::Single(Box::new({local}schemars::schema::InstanceType::Object))),",
 				indent = indent, local = local)?;
 		}
 
-		swagger20::Type::WatchEvent(ref_path) => {
+		swagger20::Type::WatchEvent(_) => {
 			writeln!(out,
 				"{indent}instance_type: Some({local}schemars::schema::SingleOrVec::Single(Box::new({local}schemars::schema::InstanceType::Object))),",
 				indent = indent, local = local)?;
 			writeln!(out, "{indent}object: Some(Box::new({local}schemars::schema::ObjectValidation {{", indent = indent, local = local)?;
-			writeln!(out, "{indent}    properties: std::array::IntoIter::new([", indent = indent)?;
-			writeln!(out, "{indent}        (", indent = indent)?;
+			writeln!(out, "{indent}    properties: {{", indent = indent)?;
+			writeln!(out, "{indent}        let mut properties = {local}schemars::Map::new();")?;
+			writeln!(out, "{indent}        properties.insert(", indent = indent)?;
 			writeln!(out, r#"{indent}            "object".to_owned(),"#, indent = indent)?;
-			writeln!(out,  "{indent}            __gen.subschema_for::<{}>(),", crate::get_fully_qualified_type_name(ref_path, map_namespace), indent = indent)?;
-			writeln!(out, "{indent}        ),", indent = indent)?;
+			writeln!(out, "{indent}            __gen.subschema_for::<T>(),")?;
+			writeln!(out, "{indent}        );", indent = indent)?;
 
-			writeln!(out, "{indent}        (", indent = indent)?;
+			writeln!(out, "{indent}        properties.insert(", indent = indent)?;
 			writeln!(out, r#"{indent}            "type".to_owned(),"#, indent = indent)?;
 			writeln!(out, "{indent}            {local}schemars::schema::Schema::Object({local}schemars::schema::SchemaObject {{", indent = indent, local = local)?;
 			writeln!(out,
@@ -394,8 +580,9 @@ fn gen_type(
 				indent = indent, local = local)?;
 			writeln!(out, "{indent}                ..Default::default()", indent = indent)?;
 			writeln!(out, "{indent}            }}),", indent = indent)?;
-			writeln!(out, "{indent}        ),", indent = indent)?;
-			writeln!(out, "{indent}    ]).collect(),", indent = indent)?;
+			writeln!(out, "{indent}        );", indent = indent)?;
+			writeln!(out, "{indent}        properties")?;
+			writeln!(out, "{indent}    }},", indent = indent)?;
 
 			writeln!(out, "{indent}    required: std::array::IntoIter::new([", indent = indent)?;
 			writeln!(out, r#"{indent}        "object","#, indent = indent)?;
@@ -30,6 +30,13 @@ pub(crate) fn generate(
 		required_fields_num += 2;
 	}
 
+	// Formats that are not self-describing, like bincode, cannot tell which fields are present, so all fields are serialized in order.
+	// apiVersion and kind are not serialized since they are constants of resource types.
+	let mut binary_fields = String::new();
+	for super::Property { name, field_name, .. } in fields {
+		writeln!(binary_fields, "            {local}serde::ser::SerializeStruct::serialize_field(&mut state, {name:?}, &self.{field_name})?;")?;
+	}
+
 	for super::Property { name, field_name, required, is_flattened, .. } in fields {
 		if *is_flattened {
 			writeln!(fields_string, "        {}serde::Serialize::serialize(&self.{}, SerializerWrapper(&mut state))?;", local, field_name)?;
@@ -291,6 +298,9 @@ pub(crate) fn generate(
 		fields_num = fields_num,
 		fields = fields_string,
 		serialize_type_name = serialize_type_name,
+		binary_state_mut = if fields.is_empty() { "" } else { "mut " },
+		binary_fields_num = fields.len(),
+		binary_fields = binary_fields,
 	)?;
 
 	Ok(())
@@ -1,11 +1,29 @@
+pub(crate) mod apply_configuration;
+
+pub(crate) mod builder;
+
+pub(crate) mod impl_arbitrary;
+
 pub(crate) mod impl_deserialize;
 
+pub(crate) mod impl_display;
+
+pub(crate) mod impl_evictable;
+
+pub(crate) mod impl_has_status_subresource;
+
 pub(crate) mod impl_listable_resource;
 
+pub(crate) mod impl_loggable;
+
+pub(crate) mod impl_merge_strategy_info;
+
 pub(crate) mod impl_metadata;
 
 pub(crate) mod impl_resource;
 
+pub(crate) mod impl_scalable;
+
 pub(crate) mod impl_schema;
 
 pub(crate) mod impl_serialize;
@@ -14,6 +32,8 @@ pub(crate) mod int_or_string;
 
 pub(crate) mod json_schema_props_or;
 
+pub(crate) mod new;
+
 pub(crate) mod newtype;
 
 pub(crate) mod operation_response_common;
@@ -22,14 +42,24 @@ pub(crate) mod patch;
 
 pub(crate) mod query_string_optional;
 
+pub(crate) mod string_enum;
+
 pub(crate) mod r#struct;
 
 pub(crate) mod type_alias;
 
 pub(crate) mod type_header;
 
+pub(crate) mod type_registry;
+
+pub(crate) mod union;
+
+pub(crate) mod version_conversions;
+
 pub(crate) mod watch_event;
 
+pub(crate) mod with_setters;
+
 fn get_comment_text<'a>(s: &'a str, indent: &'a str) -> impl Iterator<Item = std::borrow::Cow<'static, str>> + 'a {
 	s.lines().scan(true, move |previous_line_was_empty, line|
 		if line.is_empty() {
@@ -61,6 +91,40 @@ fn get_comment_text<'a>(s: &'a str, indent: &'a str) -> impl Iterator<Item = std
 		})
 }
 
+/// Returns a `#[deprecated]` attribute for the item with the given comment if the comment has a sentence starting with the word "Deprecated" in any case,
+/// like "DeprecatedServiceAccount is a depreciated alias for ServiceAccountName. Deprecated: Use serviceAccountName instead."
+/// or "DEPRECATED - This group version of Deployment is deprecated by apps/v1/Deployment."
+///
+/// The word must start with a capital letter, since a sentence starting with "deprecated" is the description of a property named `deprecated`.
+///
+/// For "Deprecated:" and "Deprecated -", the rest of that sentence is used as the attribute's note. For "Deprecated." there is no note.
+/// For other forms, like "DEPRECATED Kubernetes will stop propagating this field in 1.20 release.", the whole sentence is used as the note.
+pub(crate) fn get_deprecated_attribute(s: &str, indent: &str) -> Option<String> {
+	const DEPRECATED: &str = "deprecated";
+
+	let sentence =
+		s.lines()
+		.flat_map(|line| std::iter::once(line).chain(line.match_indices(". ").map(move |(i, _)| &line[(i + 2)..])))
+		.find(|sentence| {
+			let Some(word) = sentence.get(..DEPRECATED.len()) else { return false; };
+			word.starts_with('D') && word.eq_ignore_ascii_case(DEPRECATED) && !sentence[DEPRECATED.len()..].starts_with(char::is_alphanumeric)
+		})?;
+
+	let rest = &sentence[DEPRECATED.len()..];
+	let note =
+		if let Some(note) = rest.strip_prefix(':').or_else(|| rest.strip_prefix(" -")) {
+			note
+		}
+		else if rest.starts_with('.') || rest.trim().is_empty() {
+			return Some(format!("{indent}#[deprecated]\n"));
+		}
+		else {
+			sentence
+		};
+	let note = note.find(". ").map_or(note, |i| &note[..=i]).trim();
+	Some(format!("{indent}#[deprecated(note = {note:?})]\n"))
+}
+
 #[derive(Clone, Copy, Default)]
 pub(crate) struct Generics<'a> {
 	pub(crate) type_part: Option<&'a str>,
@@ -95,8 +159,15 @@ pub(crate) struct ResourceMetadata<'a> {
 	pub(crate) kind: &'a str,
 	pub(crate) version: &'a str,
 	pub(crate) list_kind: Option<&'a str>,
+	pub(crate) has_status_subresource: bool,
+	pub(crate) has_log_subresource: bool,
 	pub(crate) metadata_ty: Option<&'a str>,
+	pub(crate) scale_ty: Option<&'a str>,
+	pub(crate) eviction_ty: Option<&'a str>,
 	pub(crate) url_path_segment_and_scope: (&'a str, &'a str),
+	pub(crate) verbs: &'a [&'a str],
+	pub(crate) singular: &'a str,
+	pub(crate) short_names: &'a [String],
 }
 
 #[derive(Clone, Copy)]
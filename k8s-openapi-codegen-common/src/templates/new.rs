@@ -0,0 +1,48 @@
+pub(crate) fn generate(
+	mut writer: impl std::io::Write,
+	vis: &str,
+	type_name: &str,
+	properties: &[super::Property<'_>],
+) -> Result<(), crate::Error> {
+	use std::fmt::Write;
+
+	// Types without required fields are constructed with `Default::default()`.
+	if !properties.iter().any(|super::Property { required, is_flattened, .. }| !is_flattened && matches!(required, super::PropertyRequired::Required)) {
+		return Ok(());
+	}
+
+	let mut parameters = String::new();
+	let mut fields = String::new();
+
+	for super::Property { field_name, field_type_name, required, is_flattened, .. } in properties {
+		if *is_flattened {
+			writeln!(fields, "            {field_name}: Default::default(),")?;
+			continue;
+		}
+
+		match required {
+			super::PropertyRequired::Required => {
+				if !parameters.is_empty() {
+					parameters.push_str(", ");
+				}
+				write!(parameters, "{field_name}: impl Into<{field_type_name}>")?;
+
+				writeln!(fields, "            {field_name}: {field_name}.into(),")?;
+			},
+
+			super::PropertyRequired::Optional | super::PropertyRequired::OptionalDefault =>
+				writeln!(fields, "            {field_name}: None,")?,
+		}
+	}
+
+	writeln!(
+		writer,
+		include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/new.rs")),
+		vis = vis,
+		type_name = type_name,
+		parameters = parameters,
+		fields = fields,
+	)?;
+
+	Ok(())
+}
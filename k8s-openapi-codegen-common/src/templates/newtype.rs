@@ -4,16 +4,27 @@ pub(crate) fn generate(
 	type_name: &str,
 	inner_type_name: &str,
 	datetime_serialization_format: super::DateTimeSerializationFormat,
+	is_json_value: bool,
 	map_namespace: &impl crate::MapNamespace,
 ) -> Result<(), crate::Error> {
 	let local = crate::map_namespace_local_to_string(map_namespace)?;
 
-	let inner_value = match datetime_serialization_format {
-		super::DateTimeSerializationFormat::Default => "&self.0",
-		super::DateTimeSerializationFormat::SixDecimalDigits => "&self.0.to_rfc3339_opts(chrono::SecondsFormat::Micros, true)",
-		super::DateTimeSerializationFormat::ZeroDecimalDigits => "&self.0.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)",
+	let inner_value: std::borrow::Cow<'static, str> = match datetime_serialization_format {
+		// `serde_json::Value` can only be deserialized from self-describing formats, so it is serialized as a JSON string in other formats.
+		_ if is_json_value => format!("&{local}serde2::JsonValue(&self.0)").into(),
+		super::DateTimeSerializationFormat::Default => "&self.0".into(),
+		super::DateTimeSerializationFormat::SixDecimalDigits => format!("&self.0.to_rfc3339_opts({local}chrono::SecondsFormat::Micros, true)").into(),
+		super::DateTimeSerializationFormat::ZeroDecimalDigits => format!("&self.0.to_rfc3339_opts({local}chrono::SecondsFormat::Secs, true)").into(),
 	};
 
+	let inner_deserialize =
+		if is_json_value {
+			format!("{local}serde2::deserialize_json_value")
+		}
+		else {
+			format!("{local}serde::Deserialize::deserialize")
+		};
+
 	writeln!(
 		writer,
 		include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/newtype.rs")),
@@ -22,6 +33,7 @@ pub(crate) fn generate(
 		vis = vis,
 		inner_type_name = inner_type_name,
 		inner_value = inner_value,
+		inner_deserialize = inner_deserialize,
 	)?;
 
 	Ok(())
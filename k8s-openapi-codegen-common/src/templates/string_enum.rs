@@ -0,0 +1,50 @@
+pub(crate) fn generate(
+	mut writer: impl std::io::Write,
+	type_name: &str,
+	values: &[String],
+	map_namespace: &impl crate::MapNamespace,
+) -> Result<(), crate::Error> {
+	use std::fmt::Write;
+
+	let local = crate::map_namespace_local_to_string(map_namespace)?;
+
+	let mut variants = String::new();
+	let mut as_str_arms = String::new();
+	let mut from_str_arms = String::new();
+
+	for value in values {
+		let variant_name = variant_name(value);
+
+		writeln!(variants, "    /// `{value}`")?;
+		writeln!(variants, "    {variant_name},")?;
+		writeln!(variants)?;
+
+		writeln!(as_str_arms, "            {type_name}::{variant_name} => {value:?},")?;
+
+		writeln!(from_str_arms, "            {value:?} => {type_name}::{variant_name},")?;
+	}
+
+	writeln!(
+		writer,
+		include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/string_enum.rs")),
+		local = local,
+		type_name = type_name,
+		variants = variants,
+		values = values.iter().map(|value| format!("{value:?}")).collect::<Vec<_>>().join(", "),
+		as_str_arms = as_str_arms,
+		from_str_arms = from_str_arms,
+	)?;
+
+	Ok(())
+}
+
+/// The name of the variant of a value, like `Always` for `Always`. Values that are acronyms, like `TCP`, are named like `Tcp`.
+pub(crate) fn variant_name(value: &str) -> String {
+	if value.len() > 1 && value.chars().all(|c| c.is_ascii_uppercase()) {
+		let mut chars = value.chars();
+		chars.next().into_iter().chain(chars.flat_map(char::to_lowercase)).collect()
+	}
+	else {
+		value.to_owned()
+	}
+}
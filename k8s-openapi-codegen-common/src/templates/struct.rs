@@ -21,6 +21,10 @@ pub(crate) fn generate(
 				for line in super::get_comment_text(comment, "") {
 					writeln!(&mut fields, "    ///{}", line)?;
 				}
+
+				if let Some(deprecated_attribute) = super::get_deprecated_attribute(comment, "    ") {
+					fields.push_str(&deprecated_attribute);
+				}
 			}
 
 			writeln!(&mut fields,
@@ -4,8 +4,14 @@ pub(crate) fn generate(
 	type_comment: Option<&str>,
 	type_feature: Option<&str>,
 	derives: Option<Derives>,
+	type_attributes: &[String],
 	vis: &str,
 ) -> Result<(), crate::Error> {
+	let deprecated_attribute =
+		type_comment
+		.and_then(|type_comment| super::get_deprecated_attribute(type_comment, ""))
+		.unwrap_or_default();
+
 	let type_comment: String =
 		type_comment
 		.map(|type_comment| super::get_comment_text(type_comment, "").map(|line| format!("///{}\n", line)).collect())
@@ -18,25 +24,33 @@ pub(crate) fn generate(
 
 	let derives =
 		derives
-		.map(|Derives { clone, copy, default, eq, ord, partial_eq, partial_ord }| format!(
-			"#[derive({clone}{copy}Debug{default}{eq}{ord}{partial_eq}{partial_ord})]\n",
+		.map(|Derives { clone, copy, default, eq, hash, ord, partial_eq, partial_ord }| format!(
+			"#[derive({clone}{copy}Debug{default}{eq}{hash}{ord}{partial_eq}{partial_ord})]\n",
 			clone = if clone { "Clone, " } else { "" },
 			copy = if copy { "Copy, " } else { "" },
 			default = if default { ", Default" } else { "" },
 			eq = if eq { ", Eq" } else { "" },
+			hash = if hash { ", Hash" } else { "" },
 			ord = if ord { ", Ord" } else { "" },
 			partial_eq = if partial_eq { ", PartialEq" } else { "" },
 			partial_ord = if partial_ord { ", PartialOrd" } else { "" },
 		))
 		.unwrap_or_default();
 
+	let type_attributes: String =
+		type_attributes.iter()
+		.flat_map(|type_attribute| [&**type_attribute, "\n"])
+		.collect();
+
 	write!(
 		writer,
 		include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/type_header.rs")),
 		definition_path = definition_path,
 		type_comment = type_comment,
+		deprecated_attribute = deprecated_attribute,
 		type_feature_attribute = type_feature_attribute,
 		derives = derives,
+		type_attributes = type_attributes,
 		vis = vis,
 	)?;
 
@@ -49,6 +63,7 @@ pub(crate) struct Derives {
 	pub(crate) copy: bool,
 	pub(crate) default: bool,
 	pub(crate) eq: bool,
+	pub(crate) hash: bool,
 	pub(crate) ord: bool,
 	pub(crate) partial_eq: bool,
 	pub(crate) partial_ord: bool,
@@ -0,0 +1,133 @@
+use crate::swagger20;
+
+pub(crate) fn generate(
+	mut writer: impl std::io::Write,
+	definitions: &std::collections::BTreeMap<swagger20::DefinitionPath, swagger20::Schema>,
+	map_namespace: &impl crate::MapNamespace,
+	definition_feature: impl Fn(&swagger20::DefinitionPath) -> Option<&'static str>,
+) -> Result<(), crate::Error> {
+	use std::fmt::Write;
+
+	let local = crate::map_namespace_local_to_string(map_namespace)?;
+
+	// Rust paths are relative to the crate root, like `api::core::v1::Pod`
+	let relative_rust_path = |type_name: &str| type_name.replace(&*local, "");
+
+	let mut types = String::new();
+
+	for (definition_path, definition) in definitions {
+		// Skip the types of the optional parameters and responses of API operations, since they are not models.
+		let properties = match &definition.kind {
+			swagger20::SchemaKind::Properties(properties) => Some(properties),
+
+			swagger20::SchemaKind::Ty(
+				swagger20::Type::CreateOptional(_) |
+				swagger20::Type::DeleteOptional(_) |
+				swagger20::Type::ListOptional(_) |
+				swagger20::Type::PatchOptional(_) |
+				swagger20::Type::ReplaceOptional(_) |
+				swagger20::Type::WatchOptional(_) |
+				swagger20::Type::CreateResponse |
+				swagger20::Type::DeleteResponse |
+				swagger20::Type::ListResponse |
+				swagger20::Type::PatchResponse |
+				swagger20::Type::ReplaceResponse |
+				swagger20::Type::WatchResponse
+			) => continue,
+
+			_ => None,
+		};
+		let properties = properties.into_iter().flatten();
+
+		let type_name = crate::get_fully_qualified_type_name(&swagger20::RefPath {
+			path: definition_path.0.clone(),
+			can_be_default: None,
+		}, map_namespace);
+
+		let is_resource =
+			definition.kubernetes_group_kind_versions.len() == 1 &&
+			properties.clone().any(|(name, _)| &**name == "apiVersion") &&
+			properties.clone().any(|(name, _)| &**name == "kind");
+
+		if let Some(feature) = definition_feature(definition_path) {
+			writeln!(types, r#"    #[cfg(feature = "{feature}")]"#)?;
+		}
+		writeln!(types, "    {local}reflection::TypeInfo {{")?;
+		writeln!(types, "        definition_path: {:?},", &**definition_path)?;
+		writeln!(types, "        rust_path: {:?},", relative_rust_path(&type_name))?;
+		writeln!(types, "        description: {:?},", definition.description)?;
+
+		if is_resource {
+			writeln!(types, "        resource: Some({local}reflection::ResourceInfo {{")?;
+			writeln!(types, "            api_version: <{type_name} as {local}Resource>::API_VERSION,")?;
+			writeln!(types, "            group: <{type_name} as {local}Resource>::GROUP,")?;
+			writeln!(types, "            kind: <{type_name} as {local}Resource>::KIND,")?;
+			writeln!(types, "            version: <{type_name} as {local}Resource>::VERSION,")?;
+			writeln!(types, "            api_resource: <{type_name} as {local}Resource>::api_resource,")?;
+
+			// Only resources whose metadata is an `ObjectMeta` implement `AnyObject`, so lists and the like cannot be deserialized as one.
+			let has_object_meta = properties.clone().any(|(name, (schema, _))| &**name == "metadata" && matches!(
+				&schema.kind,
+				swagger20::SchemaKind::Ref(ref_path) if ref_path.path == "io.k8s.apimachinery.pkg.apis.meta.v1.ObjectMeta"
+			));
+			writeln!(types, r#"            #[cfg(feature = "erased-serde")]"#)?;
+			if has_object_meta {
+				writeln!(types, "            deserialize_any_object: Some({local}kind_registry::deserialize_any_object::<{type_name}>),")?;
+			}
+			else {
+				writeln!(types, "            deserialize_any_object: None,")?;
+			}
+			writeln!(types, "        }}),")?;
+		}
+		else {
+			writeln!(types, "        resource: None,")?;
+		}
+
+		let mut properties = properties.peekable();
+		if properties.peek().is_none() {
+			writeln!(types, "        fields: &[],")?;
+		}
+		else {
+			writeln!(types, "        fields: &[")?;
+			for (name, (schema, required)) in properties {
+				// The struct of a resource type does not have fields for these, since they're determined by the type itself.
+				if is_resource && (&**name == "apiVersion" || &**name == "kind") {
+					continue;
+				}
+
+				let field_type_name = crate::get_rust_type(&schema.kind, map_namespace)?;
+
+				writeln!(types, "            {local}reflection::FieldInfo {{")?;
+				writeln!(types, "                name: {:?},", &**name)?;
+				writeln!(types, "                field_name: {:?},", crate::get_rust_ident(name))?;
+				writeln!(types, "                type_name: {:?},", relative_rust_path(&field_type_name))?;
+				writeln!(types, "                referenced_type: {:?},", referenced_type(&schema.kind))?;
+				writeln!(types, "                required: {required},")?;
+				writeln!(types, "                description: {:?},", schema.description)?;
+				writeln!(types, "            }},")?;
+			}
+			writeln!(types, "        ],")?;
+		}
+
+		writeln!(types, "    }},")?;
+	}
+
+	writeln!(
+		writer,
+		include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/type_registry.rs")),
+		local = local,
+		types = types,
+	)?;
+
+	Ok(())
+}
+
+/// The definition path of the type of the field, or of its items or values if it's an array or map.
+fn referenced_type(kind: &swagger20::SchemaKind) -> Option<&str> {
+	match kind {
+		swagger20::SchemaKind::Ref(ref_path) => Some(&ref_path.path),
+		swagger20::SchemaKind::Ty(swagger20::Type::Array { items }) => referenced_type(&items.kind),
+		swagger20::SchemaKind::Ty(swagger20::Type::Object { additional_properties }) => referenced_type(&additional_properties.kind),
+		_ => None,
+	}
+}
@@ -0,0 +1,43 @@
+pub(crate) fn generate(
+	mut writer: impl std::io::Write,
+	vis: &str,
+	type_name: &str,
+	accessor_name: &str,
+	enum_name: &str,
+	variants: &[(&str, std::borrow::Cow<'_, str>, std::borrow::Cow<'_, str>)],
+) -> Result<(), crate::Error> {
+	use std::fmt::Write;
+
+	let mut variants_string = String::new();
+	let mut accessor_arms = String::new();
+
+	for (i, (name, field_name, variant_type_name)) in variants.iter().enumerate() {
+		// The variants are named after their properties, like `HttpGet` for `httpGet`
+		let mut chars = name.chars();
+		let variant_name: String = chars.next().into_iter().flat_map(char::to_uppercase).chain(chars).collect();
+
+		if i > 0 {
+			writeln!(variants_string)?;
+		}
+		writeln!(variants_string, "    /// [`{type_name}::{field_name}`]")?;
+		writeln!(variants_string, "    {variant_name}(&'a {variant_type_name}),")?;
+
+		writeln!(accessor_arms, "        if let Some(value) = &self.{field_name} {{")?;
+		writeln!(accessor_arms, "            return Some({enum_name}::{variant_name}(value));")?;
+		writeln!(accessor_arms, "        }}")?;
+		writeln!(accessor_arms)?;
+	}
+
+	writeln!(
+		writer,
+		include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/union.rs")),
+		vis = vis,
+		type_name = type_name,
+		accessor_name = accessor_name,
+		enum_name = enum_name,
+		variants = variants_string,
+		accessor_arms = accessor_arms,
+	)?;
+
+	Ok(())
+}
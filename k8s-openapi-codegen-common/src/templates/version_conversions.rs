@@ -0,0 +1,102 @@
+use crate::swagger20;
+
+pub(crate) fn generate(
+	mut writer: impl std::io::Write,
+	definitions: &std::collections::BTreeMap<swagger20::DefinitionPath, swagger20::Schema>,
+	map_namespace: &impl crate::MapNamespace,
+	definition_feature: impl Fn(&swagger20::DefinitionPath) -> Option<&'static str>,
+) -> Result<(), crate::Error> {
+	use std::fmt::Write;
+
+	let local = crate::map_namespace_local_to_string(map_namespace)?;
+
+	// The resource types of each group and kind, in the order of their definition paths, along with their API versions.
+	let mut versions_by_group_kind: std::collections::BTreeMap<(&str, &str), Vec<_>> = Default::default();
+
+	for (definition_path, definition) in definitions {
+		let swagger20::SchemaKind::Properties(properties) = &definition.kind else { continue; };
+
+		let [group_version_kind] = &definition.kubernetes_group_kind_versions[..] else { continue; };
+
+		let is_resource =
+			properties.keys().any(|name| &**name == "apiVersion") &&
+			properties.keys().any(|name| &**name == "kind");
+		if !is_resource {
+			continue;
+		}
+
+		let type_name = crate::get_fully_qualified_type_name(&swagger20::RefPath {
+			path: definition_path.0.clone(),
+			can_be_default: None,
+		}, map_namespace);
+
+		versions_by_group_kind
+			.entry((&*group_version_kind.group, &*group_version_kind.kind))
+			.or_default()
+			.push((type_name, &*group_version_kind.version, definition_feature(definition_path)));
+	}
+
+	let mut conversions = String::new();
+
+	for versions in versions_by_group_kind.values() {
+		// Conversions are only emitted in both directions between adjacent versions, like v1beta1 and v1beta2 or v1beta2 and v1,
+		// not between every pair of versions.
+		let mut version_order: Vec<_> = versions.iter().map(|&(_, version, _)| version).collect();
+		version_order.sort_by_key(|version| version_sort_key(version));
+		let are_adjacent = |from_version: &str, to_version: &str| version_order.windows(2).any(|pair|
+			(pair[0] == from_version && pair[1] == to_version) ||
+			(pair[0] == to_version && pair[1] == from_version));
+
+		for (from_type_name, from_version, from_feature) in versions {
+			for (to_type_name, to_version, to_feature) in versions {
+				if !are_adjacent(from_version, to_version) {
+					continue;
+				}
+
+				writeln!(conversions)?;
+				let features: std::collections::BTreeSet<_> = from_feature.iter().chain(to_feature).collect();
+				for feature in features {
+					writeln!(conversions, r#"#[cfg(feature = "{feature}")]"#)?;
+				}
+				writeln!(conversions, "impl std::convert::TryFrom<{from_type_name}> for {to_type_name} {{")?;
+				writeln!(conversions, "    type Error = {local}TryFromValueError;")?;
+				writeln!(conversions)?;
+				writeln!(conversions, "    fn try_from(value: {from_type_name}) -> Result<Self, Self::Error> {{")?;
+				writeln!(conversions, "        {local}convert_resource_version(value)")?;
+				writeln!(conversions, "    }}")?;
+				writeln!(conversions, "}}")?;
+			}
+		}
+	}
+
+	writeln!(
+		writer,
+		include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/version_conversions.rs")),
+		conversions = conversions,
+	)?;
+
+	Ok(())
+}
+
+/// Orders API versions like `v1alpha1` < `v1beta1` < `v1beta2` < `v1` < `v2beta1`, ie by major version, then by stability, then by minor version.
+///
+/// Versions that do not have this form are ordered after all the others.
+fn version_sort_key(version: &str) -> (bool, u32, u8, u32) {
+	let parse = || -> Option<(u32, u8, u32)> {
+		let version = version.strip_prefix('v')?;
+		let major_len = version.find(|c: char| !c.is_ascii_digit()).unwrap_or(version.len());
+		let (major, rest) = version.split_at(major_len);
+		let major = major.parse().ok()?;
+		let (stability, minor) =
+			if rest.is_empty() { (2, 0) }
+			else if let Some(minor) = rest.strip_prefix("beta") { (1, minor.parse().ok()?) }
+			else if let Some(minor) = rest.strip_prefix("alpha") { (0, minor.parse().ok()?) }
+			else { return None; };
+		Some((major, stability, minor))
+	};
+
+	match parse() {
+		Some((major, stability, minor)) => (false, major, stability, minor),
+		None => (true, 0, 0, 0),
+	}
+}
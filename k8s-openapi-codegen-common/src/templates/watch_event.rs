@@ -5,6 +5,7 @@ pub(crate) fn generate(
 	error_status_rust_type: &str,
 	error_other_rust_type: &str,
 	map_namespace: &impl crate::MapNamespace,
+	generate_deserialize_in_place: crate::GenerateDeserializeInPlace<'_>,
 ) -> Result<(), crate::Error> {
 	let local = crate::map_namespace_local_to_string(map_namespace)?;
 
@@ -19,20 +20,20 @@ pub(crate) fn generate(
 		if has_bookmark_event_type {
 			(
 				"Bookmark { resource_version: String },\n    ",
-				"Bookmark,\n            ",
-				"\"BOOKMARK\" => WatchEventType::Bookmark,\n                            ",
+				"Bookmark,\n    ",
+				"\"BOOKMARK\" => WatchEventType::Bookmark,\n                    ",
 				"\"BOOKMARK\", ",
 				{
 					use std::fmt::Write;
 
 					let mut result = String::new();
-					writeln!(result, "                    WatchEventType::Bookmark => {{")?;
-					writeln!(result, "                        let value_object = {}serde_value::ValueDeserializer::new(value_object);", local)?;
-					writeln!(result, "                        let value: BookmarkObject<'static> = {}serde::Deserialize::deserialize(value_object)?;", local)?;
-					writeln!(result, "                        {type_name}::Bookmark {{", type_name = type_name)?;
-					writeln!(result, "                            resource_version: value.metadata.resource_version.into_owned(),")?;
-					writeln!(result, "                        }}")?;
-					writeln!(result, "                    }},")?;
+					writeln!(result, "            WatchEventType::Bookmark => {{")?;
+					writeln!(result, "                let value_object = {}serde_value::ValueDeserializer::new(value_object);", local)?;
+					writeln!(result, "                let value: BookmarkObject<'static> = {}serde::Deserialize::deserialize(value_object)?;", local)?;
+					writeln!(result, "                {type_name}::Bookmark {{", type_name = type_name)?;
+					writeln!(result, "                    resource_version: value.metadata.resource_version.into_owned(),")?;
+					writeln!(result, "                }}")?;
+					writeln!(result, "            }},")?;
 					result
 				},
 				{
@@ -64,6 +65,21 @@ pub(crate) fn generate(
 			)
 		};
 
+	let deserialize_in_place =
+		if let crate::GenerateDeserializeInPlace::Yes { feature } = generate_deserialize_in_place {
+			let deserialize_in_place_cfg = feature.map(|feature| format!("    #[cfg(feature = {feature:?})]\n")).unwrap_or_default();
+
+			format!(
+				include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/watch_event_deserialize_in_place.rs")),
+				local = local,
+				type_name = type_name,
+				deserialize_in_place_cfg = deserialize_in_place_cfg,
+			)
+		}
+		else {
+			String::new()
+		};
+
 	writeln!(
 		writer,
 		include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/watch_event.rs")),
@@ -77,6 +93,7 @@ pub(crate) fn generate(
 		bookmark_type_value = bookmark_type_value,
 		bookmark_value_match_arm = bookmark_value_match_arm,
 		bookmark_serialize = bookmark_serialize,
+		deserialize_in_place = deserialize_in_place,
 	)?;
 
 	if has_bookmark_event_type {
@@ -0,0 +1,47 @@
+pub(crate) fn generate(
+	mut writer: impl std::io::Write,
+	vis: &str,
+	type_name: &str,
+	properties: &[super::Property<'_>],
+) -> Result<(), crate::Error> {
+	use std::fmt::Write;
+
+	if properties.is_empty() {
+		return Ok(());
+	}
+
+	let mut setters = String::new();
+
+	for super::Property { field_name, field_type_name, required, .. } in properties {
+		// Fields that are named after keywords have a trailing `_`, like `type_`, which is not needed in the name of the setter.
+		let setter_name = format!("with_{}", field_name.trim_end_matches('_'));
+
+		let (value_type_name, field_value) = match required {
+			super::PropertyRequired::Required => (&**field_type_name, "value.into()"),
+			super::PropertyRequired::Optional | super::PropertyRequired::OptionalDefault => (
+				field_type_name.strip_prefix("Option<").and_then(|s| s.strip_suffix('>'))
+				.ok_or_else(|| format!("optional field {type_name}.{field_name} has non-Option type {field_type_name}"))?,
+				"Some(value.into())",
+			),
+		};
+
+		if !setters.is_empty() {
+			writeln!(setters)?;
+		}
+		writeln!(setters, "    /// Returns this value with [`{type_name}::{field_name}`] set to the given value.")?;
+		writeln!(setters, "    #[must_use]")?;
+		writeln!(setters, "    {vis}fn {setter_name}(mut self, value: impl Into<{value_type_name}>) -> Self {{")?;
+		writeln!(setters, "        self.{field_name} = {field_value};")?;
+		writeln!(setters, "        self")?;
+		writeln!(setters, "    }}")?;
+	}
+
+	writeln!(
+		writer,
+		include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/with_setters.rs")),
+		type_name = type_name,
+		setters = setters,
+	)?;
+
+	Ok(())
+}
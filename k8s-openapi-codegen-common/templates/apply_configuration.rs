@@ -0,0 +1,15 @@
+
+/// The apply configuration of [`{type_name}`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+{cfg}#[derive(Clone, Debug, Default, PartialEq)]
+{vis}struct {apply_configuration_name} {{
+{fields}}}
+
+{cfg}impl {local}serde::Serialize for {apply_configuration_name} {{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: {local}serde::Serializer {{
+        let {state_mut}state = serializer.serialize_struct(
+            {serialize_type_name},
+{fields_num},
+        )?;
+{serialize_fields}        {local}serde::ser::SerializeStruct::end(state)
+    }}
+}}
\ No newline at end of file
@@ -0,0 +1,21 @@
+
+/// A builder of [`{type_name}`]. Created with [`{type_name}::builder`].
+{cfg}#[derive(Clone, Debug, Default)]
+#[must_use]
+{vis}struct {builder_name} {{
+{fields}}}
+
+{cfg}impl {type_name} {{
+    /// Starts building a `{type_name}`. Its required fields must be set before [`{builder_name}::build`] is called.
+    {vis}fn builder() -> {builder_name} {{
+        Default::default()
+    }}
+}}
+
+{cfg}impl {builder_name} {{
+{setters}    /// Builds the `{type_name}`. Fails if any of its required fields has not been set.
+    {vis}fn build(self) -> Result<{type_name}, {local}MissingFieldError> {{
+        Ok({type_name} {{
+{build_fields}        }})
+    }}
+}}
\ No newline at end of file
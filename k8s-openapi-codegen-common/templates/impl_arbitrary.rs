@@ -0,0 +1,5 @@
+
+{cfg}impl{type_generics_impl} {local}arbitrary::Arbitrary<'a> for {type_name}{type_generics_type}{type_generics_where} {{
+    fn arbitrary({u}: &mut {local}arbitrary::Unstructured<'a>) -> {local}arbitrary::Result<Self> {{
+{body}    }}
+}}
\ No newline at end of file
@@ -1,31 +1,11 @@
 
+impl{fields_type_generics_impl} {type_name}{type_generics_type}{fields_type_generics_where} {{
+    const FIELDS: &'static [&'static str] = &[
+{field_name_list}    ];
+}}
+
 impl{type_generics_impl} {local}serde::Deserialize<'de> for {type_name}{type_generics_type}{type_generics_where} {{
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: {local}serde::Deserializer<'de> {{
-        #[allow(non_camel_case_types)]
-        enum Field {{
-{fields}        }}
-
-        impl<'de> {local}serde::Deserialize<'de> for Field {{
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: {local}serde::Deserializer<'de> {{
-                struct Visitor;
-
-                impl<'de> {local}serde::de::Visitor<'de> for Visitor {{
-                    type Value = Field;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
-                        f.write_str("field identifier")
-                    }}
-
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: {local}serde::de::Error {{
-                        Ok(match v {{
-{str_to_field_match_arms}                        }})
-                    }}
-                }}
-
-                deserializer.deserialize_identifier(Visitor)
-            }}
-        }}
-
         struct Visitor{type_generics_type}{visitor_field};
 
         impl{type_generics_impl} {local}serde::de::Visitor<'de> for Visitor{type_generics_type}{type_generics_where} {{
@@ -37,7 +17,7 @@ impl{type_generics_impl} {local}serde::Deserialize<'de> for {type_name}{type_gen
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: {local}serde::de::MapAccess<'de> {{
 {field_value_defs}
-                while let Some(key) = {local}serde::de::MapAccess::next_key::<Field>(&mut map)? {{
+                while let Some(key) = {local}serde::de::MapAccess::next_key_seed(&mut map, {local}serde2::{field_index_seed})? {{
                     match key {{
 {field_value_match_arms}                    }}
                 }}
@@ -45,13 +25,17 @@ impl{type_generics_impl} {local}serde::Deserialize<'de> for {type_name}{type_gen
                 Ok({type_name} {{
 {field_value_assignment}                }})
             }}
+
+            fn visit_seq<A>(self, {seq_param}: A) -> Result<Self::Value, A::Error> where A: {local}serde::de::SeqAccess<'de> {{
+                Ok({type_name} {{
+{field_seq_assignment}                }})
+            }}
         }}
 
         deserializer.deserialize_struct(
             {deserialize_type_name},
-            &[
-{field_name_list}            ],
+            {field_names},
             Visitor{visitor_create_field},
         )
     }}
-}}
\ No newline at end of file
+{deserialize_in_place}}}
\ No newline at end of file
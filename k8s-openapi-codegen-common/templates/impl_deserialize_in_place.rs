@@ -0,0 +1,32 @@
+
+{deserialize_in_place_cfg}    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: {local}serde::Deserializer<'de> {{
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl{type_generics_impl} {local}serde::de::Visitor<'de> for InPlaceVisitor<'_, {type_name}{type_generics_type}>{type_generics_where} {{
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+                f.write_str({in_place_visitor_expecting_type_name})
+            }}
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: {local}serde::de::MapAccess<'de> {{
+{in_place_field_value_defs}
+                while let Some(key) = {local}serde::de::MapAccess::next_key_seed(&mut map, {local}serde2::{field_index_seed})? {{
+                    match key {{
+{in_place_field_value_match_arms}                    }}
+                }}
+{in_place_field_value_assignment}
+                Ok(())
+            }}
+
+            fn visit_seq<A>(self, {seq_param}: A) -> Result<Self::Value, A::Error> where A: {local}serde::de::SeqAccess<'de> {{
+{in_place_field_seq_assignment}                Ok(())
+            }}
+        }}
+
+        deserializer.deserialize_struct(
+            {deserialize_type_name},
+            {field_names},
+            InPlaceVisitor(place),
+        )
+    }}
@@ -0,0 +1,10 @@
+
+impl{type_generics_impl} std::fmt::Display for {type_name}{type_generics_type}{type_generics_where} {{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+        f.write_str(<Self as {local}Resource>::URL_PATH_SEGMENT)?;
+        for part in [&self.metadata.namespace, &self.metadata.name].iter().copied().flatten() {{
+            write!(f, "/{{part}}")?;
+        }}
+        Ok(())
+    }}
+}}
\ No newline at end of file
@@ -0,0 +1,4 @@
+
+impl{type_generics_impl} {local}Evictable for {type_name}{type_generics_type}{type_generics_where} {{
+    type Eviction = {eviction_type_name};
+}}
\ No newline at end of file
@@ -0,0 +1,2 @@
+
+impl{type_generics_impl} {local}HasStatusSubresource for {type_name}{type_generics_type}{type_generics_where} {{}}
\ No newline at end of file
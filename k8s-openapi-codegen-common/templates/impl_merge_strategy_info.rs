@@ -0,0 +1,2 @@
+
+impl{type_generics_impl} {local}MergeStrategyInfo for {type_name}{type_generics_type}{type_generics_where} {{{field_patch_strategies}}}
\ No newline at end of file
@@ -5,5 +5,8 @@ impl{type_generics_impl} {local}Resource for {type_name}{type_generics_type}{typ
     const KIND: &'static str = {kind};
     const VERSION: &'static str = {version};
     const URL_PATH_SEGMENT: &'static str = {url_path_segment};
+    const VERBS: &'static [&'static str] = &[{verbs}];
+    const SINGULAR: &'static str = {singular};
+    const SHORT_NAMES: &'static [&'static str] = &[{short_names}];
     type Scope = {scope};
 }}
\ No newline at end of file
@@ -0,0 +1,4 @@
+
+impl{type_generics_impl} {local}Scalable for {type_name}{type_generics_type}{type_generics_where} {{
+    type Scale = {scale_type_name};
+}}
\ No newline at end of file
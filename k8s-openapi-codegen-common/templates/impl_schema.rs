@@ -1,8 +1,7 @@
 
 {cfg}impl{type_generics_impl} {local}schemars::JsonSchema for {type_name}{type_generics_type}{type_generics_where} {{
     fn schema_name() -> String {{
-        {definition_path:?}.to_owned()
-    }}
+{schema_name}    }}
 
     fn json_schema(__gen: &mut {local}schemars::gen::SchemaGenerator) -> {local}schemars::schema::Schema {{
 {schema}    }}
@@ -1,7 +1,12 @@
 
 impl{type_generics_impl} {local}serde::Serialize for {type_name}{type_generics_type}{type_generics_where} {{
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: {local}serde::Serializer {{
-{struct_serializer}        let mut state = serializer.serialize_struct(
+{struct_serializer}        if !serializer.is_human_readable() {{
+            let {binary_state_mut}state = serializer.serialize_struct({serialize_type_name}, {binary_fields_num})?;
+{binary_fields}            return {local}serde::ser::SerializeStruct::end(state);
+        }}
+
+        let mut state = serializer.serialize_struct(
             {serialize_type_name},
 {fields_num},
         )?;
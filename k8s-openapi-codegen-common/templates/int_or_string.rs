@@ -52,14 +52,36 @@ impl<'de> {local}serde::Deserialize<'de> for {type_name} {{
             fn visit_string<E>(self, v: String) -> Result<Self::Value, E> where E: {local}serde::de::Error {{
                 Ok({type_name}::String(v))
             }}
+
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error> where A: {local}serde::de::EnumAccess<'de> {{
+                let (variant, value) = {local}serde::de::EnumAccess::variant::<u32>(data)?;
+                match variant {{
+                    0 => Ok({type_name}::Int({local}serde::de::VariantAccess::newtype_variant(value)?)),
+                    1 => Ok({type_name}::String({local}serde::de::VariantAccess::newtype_variant(value)?)),
+                    _ => Err({local}serde::de::Error::invalid_value({local}serde::de::Unexpected::Unsigned(variant.into()), &"variant index 0 <= i < 2")),
+                }}
+            }}
         }}
 
-        deserializer.deserialize_any(Visitor)
+        if deserializer.is_human_readable() {{
+            deserializer.deserialize_any(Visitor)
+        }}
+        else {{
+            // Formats that are not self-describing cannot deserialize untagged values, so the variant is serialized explicitly.
+            deserializer.deserialize_enum({type_name:?}, &["Int", "String"], Visitor)
+        }}
     }}
 }}
 
 impl {local}serde::Serialize for {type_name} {{
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: {local}serde::Serializer {{
+        if !serializer.is_human_readable() {{
+            return match self {{
+                {type_name}::Int(i) => serializer.serialize_newtype_variant({type_name:?}, 0, "Int", i),
+                {type_name}::String(s) => serializer.serialize_newtype_variant({type_name:?}, 1, "String", s),
+            }};
+        }}
+
         match self {{
             {type_name}::Int(i) => i.serialize(serializer),
             {type_name}::String(s) => s.serialize(serializer),
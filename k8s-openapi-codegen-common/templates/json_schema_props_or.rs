@@ -18,14 +18,36 @@ impl<'de> {local}serde::Deserialize<'de> for {type_name} {{
                 Ok({type_name}::Schema({local}serde::de::Deserialize::deserialize({local}serde::de::value::MapAccessDeserializer::new(map))?))
             }}
 
-{or_visit}        }}
+{or_visit}
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error> where A: {local}serde::de::EnumAccess<'de> {{
+                let (variant, value) = {local}serde::de::EnumAccess::variant::<u32>(data)?;
+                match variant {{
+                    0 => Ok({type_name}::Schema({local}serde::de::VariantAccess::newtype_variant(value)?)),
+                    1 => Ok({type_name}::{or_variant_name}({local}serde::de::VariantAccess::newtype_variant(value)?)),
+                    _ => Err({local}serde::de::Error::invalid_value({local}serde::de::Unexpected::Unsigned(variant.into()), &"variant index 0 <= i < 2")),
+                }}
+            }}
+        }}
 
-        deserializer.deserialize_any(Visitor)
+        if deserializer.is_human_readable() {{
+            deserializer.deserialize_any(Visitor)
+        }}
+        else {{
+            // Formats that are not self-describing cannot deserialize untagged values, so the variant is serialized explicitly.
+            deserializer.deserialize_enum({type_name:?}, &["Schema", {or_variant_name:?}], Visitor)
+        }}
     }}
 }}
 
 impl {local}serde::Serialize for {type_name} {{
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: {local}serde::Serializer {{
+        if !serializer.is_human_readable() {{
+            return match self {{
+                {type_name}::Schema(value) => serializer.serialize_newtype_variant({type_name:?}, 0, "Schema", value),
+                {type_name}::{or_variant_name}(value) => serializer.serialize_newtype_variant({type_name:?}, 1, {or_variant_name:?}, value),
+            }};
+        }}
+
         match self {{
             {type_name}::Schema(value) => value.serialize(serializer),
             {type_name}::{or_variant_name}(value) => value.serialize(serializer),
@@ -0,0 +1,8 @@
+
+impl {type_name} {{
+    /// Constructs a `{type_name}` from its required fields. Its optional fields are unset.
+    {vis}fn new({parameters}) -> Self {{
+        {type_name} {{
+{fields}        }}
+    }}
+}}
\ No newline at end of file
@@ -12,7 +12,7 @@ impl<'de> {local}serde::Deserialize<'de> for {type_name} {{
             }}
 
             fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: {local}serde::Deserializer<'de> {{
-                Ok({type_name}({local}serde::Deserialize::deserialize(deserializer)?))
+                Ok({type_name}({inner_deserialize}(deserializer)?))
             }}
         }}
 
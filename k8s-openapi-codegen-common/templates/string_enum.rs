@@ -0,0 +1,101 @@
+enum {type_name} {{
+{variants}    /// A value that is not one of the values known to this version of the crate, such as one that was added in a newer version of Kubernetes.
+    Other(String),
+}}
+
+impl {type_name} {{
+    /// The values known to this version of the crate, in the order of the variants.
+    pub const VALUES: &'static [&'static str] = &[{values}];
+
+    /// The value as it is serialized.
+    pub fn as_str(&self) -> &str {{
+        match self {{
+{as_str_arms}            {type_name}::Other(value) => value,
+        }}
+    }}
+}}
+
+impl AsRef<str> for {type_name} {{
+    fn as_ref(&self) -> &str {{
+        self.as_str()
+    }}
+}}
+
+impl Default for {type_name} {{
+    fn default() -> Self {{
+        {type_name}::Other(String::new())
+    }}
+}}
+
+impl std::fmt::Display for {type_name} {{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+        f.write_str(self.as_str())
+    }}
+}}
+
+impl From<&str> for {type_name} {{
+    fn from(value: &str) -> Self {{
+        match value {{
+{from_str_arms}            value => {type_name}::Other(value.to_owned()),
+        }}
+    }}
+}}
+
+impl From<String> for {type_name} {{
+    fn from(value: String) -> Self {{
+        match &*value {{
+{from_str_arms}            _ => {type_name}::Other(value),
+        }}
+    }}
+}}
+
+impl From<{type_name}> for String {{
+    fn from(value: {type_name}) -> Self {{
+        match value {{
+            {type_name}::Other(value) => value,
+            value => value.as_str().to_owned(),
+        }}
+    }}
+}}
+
+impl PartialEq<str> for {type_name} {{
+    fn eq(&self, other: &str) -> bool {{
+        self.as_str() == other
+    }}
+}}
+
+impl PartialEq<&str> for {type_name} {{
+    fn eq(&self, other: &&str) -> bool {{
+        self.as_str() == *other
+    }}
+}}
+
+impl<'de> {local}serde::Deserialize<'de> for {type_name} {{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: {local}serde::Deserializer<'de> {{
+        struct Visitor;
+
+        impl {local}serde::de::Visitor<'_> for Visitor {{
+            type Value = {type_name};
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+                formatter.write_str({type_name:?})
+            }}
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: {local}serde::de::Error {{
+                Ok(v.into())
+            }}
+
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E> where E: {local}serde::de::Error {{
+                Ok(v.into())
+            }}
+        }}
+
+        deserializer.deserialize_string(Visitor)
+    }}
+}}
+
+impl {local}serde::Serialize for {type_name} {{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: {local}serde::Serializer {{
+        serializer.serialize_str(self.as_str())
+    }}
+}}
\ No newline at end of file
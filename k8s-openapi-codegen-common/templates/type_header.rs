@@ -1,3 +1,3 @@
 // Generated from definition {definition_path}
 
-{type_comment}{type_feature_attribute}{derives}{vis}
\ No newline at end of file
+{type_comment}{deprecated_attribute}{type_feature_attribute}{derives}{type_attributes}{vis}
\ No newline at end of file
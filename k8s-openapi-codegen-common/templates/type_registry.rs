@@ -0,0 +1,5 @@
+// Generated from all definitions
+
+/// Descriptions of the types generated from the definitions of the spec, sorted by their definition paths.
+pub(crate) static TYPES: &[{local}reflection::TypeInfo] = &[
+{types}];
@@ -0,0 +1,15 @@
+
+/// The property of a [`{type_name}`] that is set, out of the properties that are mutually exclusive. Returned by [`{type_name}::{accessor_name}`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+{vis}enum {enum_name}<'a> {{
+{variants}}}
+
+impl {type_name} {{
+    /// Returns the property of this `{type_name}` that is set, out of the properties that are mutually exclusive.
+    ///
+    /// Returns `None` if none of them is set. If more than one of them is set, which the API server rejects,
+    /// the one that comes first in the order of the variants of [`{enum_name}`] is returned.
+    {vis}fn {accessor_name}(&self) -> Option<{enum_name}<'_>> {{
+{accessor_arms}        None
+    }}
+}}
\ No newline at end of file
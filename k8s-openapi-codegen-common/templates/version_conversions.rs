@@ -0,0 +1,2 @@
+// Generated from all definitions
+{conversions}
\ No newline at end of file
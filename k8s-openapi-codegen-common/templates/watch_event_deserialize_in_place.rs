@@ -0,0 +1,78 @@
+
+{deserialize_in_place_cfg}    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: {local}serde::Deserializer<'de> {{
+        struct InPlaceVisitor<'a, T>(&'a mut {type_name}<T>);
+
+        impl<'de, T> {local}serde::de::Visitor<'de> for InPlaceVisitor<'_, T> where T: {local}serde::Deserialize<'de> {{
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+                f.write_str({type_name:?})
+            }}
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: {local}serde::de::MapAccess<'de> {{
+                let mut value_type: Option<WatchEventType> = None;
+                let mut value_object: Option<{local}serde_value::Value> = None;
+
+                // The object can only be deserialized in place if the type of the event is already known when the object is encountered,
+                // and the existing event has an object. The API server always writes the type first.
+                let mut has_object_in_place = false;
+
+                while let Some(key) = {local}serde::de::MapAccess::next_key::<Field>(&mut map)? {{
+                    match key {{
+                        Field::Key_type => {{
+                            if has_object_in_place {{
+                                return Err({local}serde::de::Error::duplicate_field("type"));
+                            }}
+                            value_type = {local}serde::de::MapAccess::next_value(&mut map)?;
+                        }},
+                        Field::Key_object => match (&value_type, &mut *self.0) {{
+                            (
+                                Some(WatchEventType::Added | WatchEventType::Deleted | WatchEventType::Modified),
+                                {type_name}::Added(object) | {type_name}::Deleted(object) | {type_name}::Modified(object),
+                            ) => {{
+                                {local}serde::de::MapAccess::next_value_seed(&mut map, {local}DeserializeInPlace(object))?;
+                                value_object = None;
+                                has_object_in_place = true;
+                            }},
+                            _ => {{
+                                value_object = {local}serde::de::MapAccess::next_value(&mut map)?;
+                                has_object_in_place = false;
+                            }},
+                        }},
+                        Field::Other => {{ let _: {local}serde::de::IgnoredAny = {local}serde::de::MapAccess::next_value(&mut map)?; }},
+                    }}
+                }}
+
+                let value_type = value_type.ok_or_else(|| {local}serde::de::Error::missing_field("type"))?;
+
+                if has_object_in_place {{
+                    // The object was deserialized into the existing event, so move it to the variant of the new event's type.
+                    let object = match std::mem::replace(self.0, {type_name}::ErrorOther(Default::default())) {{
+                        {type_name}::Added(object) | {type_name}::Deleted(object) | {type_name}::Modified(object) => object,
+                        _ => unreachable!("object was deserialized in place into an event without an object"),
+                    }};
+                    *self.0 = match value_type {{
+                        WatchEventType::Added => {type_name}::Added(object),
+                        WatchEventType::Deleted => {type_name}::Deleted(object),
+                        WatchEventType::Modified => {type_name}::Modified(object),
+                        _ => unreachable!("object was deserialized in place for an event type without an object"),
+                    }};
+                }}
+                else {{
+                    let value_object = value_object.ok_or_else(|| {local}serde::de::Error::missing_field("object"))?;
+                    *self.0 = value_type.watch_event(value_object)?;
+                }}
+
+                Ok(())
+            }}
+        }}
+
+        deserializer.deserialize_struct(
+            {type_name:?},
+            &[
+                "type",
+                "object",
+            ],
+            InPlaceVisitor(place),
+        )
+    }}
@@ -0,0 +1,3 @@
+
+impl {type_name} {{
+{setters}}}
\ No newline at end of file
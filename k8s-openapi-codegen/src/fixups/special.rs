@@ -71,6 +71,146 @@ pub(crate) mod json_ty {
 	}
 }
 
+// The `ConversionReview` type that the API server sends to CRD conversion webhooks is not part of the spec, since it isn't served by any API operation.
+// This fixup synthesizes it and its request and response types from their upstream Go definitions, so that webhook implementations can use them.
+//
+// The `apiVersion` and `kind` properties are copied from `CustomResourceDefinition`, so the fixup is a no-op for versions that don't have the apiextensions v1 API.
+// `ConversionReview` is not given a group-version-kind, since it has no URL path, so it is not a `Resource` and those properties are regular fields.
+pub(crate) fn conversion_review(spec: &mut crate::swagger20::Spec) -> Result<(), crate::Error> {
+	fn property(description: &str, kind: crate::swagger20::SchemaKind) -> crate::swagger20::Schema {
+		crate::swagger20::Schema {
+			description: Some(description.to_owned()),
+			kind,
+			kubernetes_group_kind_versions: vec![],
+			list_kind: None,
+			scale_definition_path: None,
+			eviction_definition_path: None,
+			union: None,
+			patch_strategy: None,
+		}
+	}
+
+	fn ref_kind(path: &str) -> crate::swagger20::SchemaKind {
+		crate::swagger20::SchemaKind::Ref(crate::swagger20::RefPath {
+			path: path.to_owned(),
+			can_be_default: None,
+		})
+	}
+
+	fn string_kind() -> crate::swagger20::SchemaKind {
+		crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::String { format: None })
+	}
+
+	fn raw_extension_array_kind() -> crate::swagger20::SchemaKind {
+		crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::Array {
+			items: Box::new(crate::swagger20::Schema {
+				description: None,
+				kind: ref_kind("io.k8s.apimachinery.pkg.runtime.RawExtension"),
+				kubernetes_group_kind_versions: vec![],
+				list_kind: None,
+				scale_definition_path: None,
+				eviction_definition_path: None,
+				union: None,
+				patch_strategy: None,
+			}),
+		})
+	}
+
+	let custom_resource_definition_properties =
+		match spec.definitions.get(&crate::swagger20::DefinitionPath("io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1.CustomResourceDefinition".to_owned())) {
+			Some(crate::swagger20::Schema { kind: crate::swagger20::SchemaKind::Properties(properties), .. }) => properties,
+			Some(_) => return Err("io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1.CustomResourceDefinition is not a SchemaKind::Properties".into()),
+			None => return Ok(()),
+		};
+	let api_version_property =
+		custom_resource_definition_properties.get(&crate::swagger20::PropertyName("apiVersion".to_owned()))
+		.ok_or("io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1.CustomResourceDefinition does not have an apiVersion property")?
+		.clone();
+	let kind_property =
+		custom_resource_definition_properties.get(&crate::swagger20::PropertyName("kind".to_owned()))
+		.ok_or("io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1.CustomResourceDefinition does not have a kind property")?
+		.clone();
+
+	let definitions: Vec<(&str, &str, Vec<crate::swagger20::KubernetesGroupKindVersion>, Vec<(&str, (crate::swagger20::Schema, bool))>)> = vec![
+		(
+			"io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1.ConversionRequest",
+			"ConversionRequest describes the conversion request parameters.",
+			vec![],
+			vec![
+				("desiredAPIVersion", (property(
+					"desiredAPIVersion is the version to convert given objects to. e.g. \"myapi.example.com/v1\"",
+					string_kind(),
+				), true)),
+				("objects", (property(
+					"objects is the list of custom resource objects to be converted.",
+					raw_extension_array_kind(),
+				), true)),
+				("uid", (property(
+					"uid is an identifier for the individual request/response. It allows distinguishing instances of requests which are otherwise identical (parallel requests, etc). The UID is meant to track the round trip (request/response) between the Kubernetes API server and the webhook, not the user request. It is suitable for correlating log entries between the webhook and apiserver, for either auditing or debugging.",
+					string_kind(),
+				), true)),
+			],
+		),
+		(
+			"io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1.ConversionResponse",
+			"ConversionResponse describes a conversion response.",
+			vec![],
+			vec![
+				("convertedObjects", (property(
+					"convertedObjects is the list of converted version of `request.objects` if the `result` is successful, otherwise empty. The webhook is expected to set `apiVersion` of these objects to the `request.desiredAPIVersion`. The list must also have the same size as the input list with the same objects in the same order (equal kind, metadata.uid, metadata.name and metadata.namespace). The webhook is allowed to mutate labels and annotations. Any other change to the metadata is silently ignored.",
+					raw_extension_array_kind(),
+				), true)),
+				("result", (property(
+					"result contains the result of conversion with extra details if the conversion failed. `result.status` determines if the conversion failed or succeeded. The `result.status` field is required and represents the success or failure of the conversion. A successful conversion must set `result.status` to `Success`. A failed conversion must set `result.status` to `Failure` and provide more details in `result.message` and return http status 200. The `result.message` will be used to construct an error message for the end user.",
+					ref_kind("io.k8s.apimachinery.pkg.apis.meta.v1.Status"),
+				), true)),
+				("uid", (property(
+					"uid is an identifier for the individual request/response. This should be copied over from the corresponding `request.uid`.",
+					string_kind(),
+				), true)),
+			],
+		),
+		(
+			"io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1.ConversionReview",
+			"ConversionReview describes a conversion request/response.",
+			vec![],
+			vec![
+				("apiVersion", api_version_property),
+				("kind", kind_property),
+				("request", (property(
+					"request describes the attributes for the conversion request.",
+					ref_kind("io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1.ConversionRequest"),
+				), false)),
+				("response", (property(
+					"response describes the attributes for the conversion response.",
+					ref_kind("io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1.ConversionResponse"),
+				), false)),
+			],
+		),
+	];
+
+	for (definition_path, description, kubernetes_group_kind_versions, properties) in definitions {
+		let definition_path = crate::swagger20::DefinitionPath(definition_path.to_owned());
+		if spec.definitions.contains_key(&definition_path) {
+			return Err(format!("{definition_path} is already defined in the spec").into());
+		}
+
+		spec.definitions.insert(definition_path, crate::swagger20::Schema {
+			description: Some(description.to_owned()),
+			kind: crate::swagger20::SchemaKind::Properties(
+				properties.into_iter().map(|(name, property)| (crate::swagger20::PropertyName(name.to_owned()), property)).collect()),
+			kubernetes_group_kind_versions,
+			list_kind: None,
+			scale_definition_path: None,
+			eviction_definition_path: None,
+			union: None,
+			patch_strategy: None,
+		});
+	}
+
+	Ok(())
+}
+
 // This fixup copies the `io.k8s.apimachinery.pkg.apis.meta.v1.DeleteOptions` type to `io.k8s.DeleteOptional` and modifies its parameters to be optional borrows.
 // This makes the new type consistent with `io.k8s.ListOptional` and `io.k8s.WatchOptional` and allows it to be used as a common parameter for
 // delete and delete-collection API operations.
@@ -94,6 +234,10 @@ pub(crate) fn create_delete_optional(spec: &mut crate::swagger20::Spec) -> Resul
 		kind: crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::DeleteOptional(delete_optional_properties)),
 		kubernetes_group_kind_versions: vec![],
 		list_kind: None,
+		scale_definition_path: None,
+		eviction_definition_path: None,
+		union: None,
+		patch_strategy: None,
 	});
 
 	Ok(())
@@ -128,6 +272,10 @@ pub(crate) fn create_optionals(spec: &mut crate::swagger20::Spec) -> Result<(),
 				}),
 				kubernetes_group_kind_versions: vec![],
 				list_kind: None,
+				scale_definition_path: None,
+				eviction_definition_path: None,
+				union: None,
+				patch_strategy: None,
 			},
 		});
 
@@ -189,6 +337,10 @@ pub(crate) fn create_optionals(spec: &mut crate::swagger20::Spec) -> Result<(),
 			kind: crate::swagger20::SchemaKind::Ty(ty(optional_definition)),
 			kubernetes_group_kind_versions: vec![],
 			list_kind: None,
+			scale_definition_path: None,
+			eviction_definition_path: None,
+			union: None,
+			patch_strategy: None,
 		});
 	}
 
@@ -229,6 +381,10 @@ pub(crate) fn remove_delete_collection_operations_query_parameters(spec: &mut cr
 					}),
 					kubernetes_group_kind_versions: vec![],
 					list_kind: None,
+					scale_definition_path: None,
+					eviction_definition_path: None,
+					union: None,
+					patch_strategy: None,
 				},
 			}));
 			operation.parameters.push(std::sync::Arc::new(crate::swagger20::Parameter {
@@ -243,6 +399,10 @@ pub(crate) fn remove_delete_collection_operations_query_parameters(spec: &mut cr
 					}),
 					kubernetes_group_kind_versions: vec![],
 					list_kind: None,
+					scale_definition_path: None,
+					eviction_definition_path: None,
+					union: None,
+					patch_strategy: None,
 				},
 			}));
 
@@ -282,6 +442,10 @@ pub(crate) fn remove_delete_operations_query_parameters(spec: &mut crate::swagge
 								}),
 								kubernetes_group_kind_versions: vec![],
 								list_kind: None,
+								scale_definition_path: None,
+								eviction_definition_path: None,
+								union: None,
+								patch_strategy: None,
 							},
 						}));
 						found = true;
@@ -379,6 +543,10 @@ pub(crate) fn separate_watch_from_list_operations(spec: &mut crate::swagger20::S
 		kind: crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::ListOptional(list_optional_definition)),
 		kubernetes_group_kind_versions: vec![],
 		list_kind: None,
+		scale_definition_path: None,
+		eviction_definition_path: None,
+		union: None,
+		patch_strategy: None,
 	});
 
 	spec.definitions.insert(crate::swagger20::DefinitionPath("io.k8s.WatchOptional".to_string()), crate::swagger20::Schema {
@@ -386,6 +554,10 @@ pub(crate) fn separate_watch_from_list_operations(spec: &mut crate::swagger20::S
 		kind: crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::WatchOptional(watch_optional_definition)),
 		kubernetes_group_kind_versions: vec![],
 		list_kind: None,
+		scale_definition_path: None,
+		eviction_definition_path: None,
+		union: None,
+		patch_strategy: None,
 	});
 
 	let list_optional_parameter = std::sync::Arc::new(crate::swagger20::Parameter {
@@ -400,6 +572,10 @@ pub(crate) fn separate_watch_from_list_operations(spec: &mut crate::swagger20::S
 			}),
 			kubernetes_group_kind_versions: vec![],
 			list_kind: None,
+			scale_definition_path: None,
+			eviction_definition_path: None,
+			union: None,
+			patch_strategy: None,
 		},
 	});
 
@@ -415,6 +591,10 @@ pub(crate) fn separate_watch_from_list_operations(spec: &mut crate::swagger20::S
 			}),
 			kubernetes_group_kind_versions: vec![],
 			list_kind: None,
+			scale_definition_path: None,
+			eviction_definition_path: None,
+			union: None,
+			patch_strategy: None,
 		},
 	});
 
@@ -483,6 +663,10 @@ pub(crate) fn separate_watch_from_list_operations(spec: &mut crate::swagger20::S
 				}),
 				kubernetes_group_kind_versions: vec![],
 				list_kind: None,
+				scale_definition_path: None,
+				eviction_definition_path: None,
+				union: None,
+				patch_strategy: None,
 			});
 		}
 		else {
@@ -652,10 +836,18 @@ pub(crate) fn list(spec: &mut crate::swagger20::Spec) -> Result<(), crate::Error
 								}),
 								kubernetes_group_kind_versions: vec![],
 								list_kind: None,
+								scale_definition_path: None,
+								eviction_definition_path: None,
+								union: None,
+								patch_strategy: None,
 							}),
 						}),
 						kubernetes_group_kind_versions: vec![],
 						list_kind: None,
+						scale_definition_path: None,
+						eviction_definition_path: None,
+						union: None,
+						patch_strategy: None,
 					},
 					true,
 				));
@@ -677,6 +869,10 @@ pub(crate) fn list(spec: &mut crate::swagger20::Spec) -> Result<(), crate::Error
 			kind: crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::ListDef { metadata: Box::new(metadata_schema_kind) }),
 			kubernetes_group_kind_versions: vec![],
 			list_kind: None,
+			scale_definition_path: None,
+			eviction_definition_path: None,
+			union: None,
+			patch_strategy: None,
 		});
 
 
@@ -1125,6 +1321,10 @@ pub(crate) fn response_types(spec: &mut crate::swagger20::Spec) -> Result<(), cr
 				kind: crate::swagger20::SchemaKind::Ty(ty),
 				kubernetes_group_kind_versions: vec![],
 				list_kind: None,
+				scale_definition_path: None,
+				eviction_definition_path: None,
+				union: None,
+				patch_strategy: None,
 			});
 	}
 
@@ -1134,35 +1334,631 @@ pub(crate) fn response_types(spec: &mut crate::swagger20::Spec) -> Result<(), cr
 // The `metadata` property of resource types is generally not optional, so override the property to be required.
 // For situations like PATCH requests where the property *is* optional, sending an empty object works the same.
 pub(crate) fn resource_metadata_not_optional(spec: &mut crate::swagger20::Spec) -> Result<(), crate::Error> {
+	Ok(k8s_openapi_codegen_common::fixups::resource_metadata_not_optional(spec)?)
+}
+
+// Resources with a `scale` subresource implement `k8s_openapi::Scalable`, whose associated type is the type of the subresource.
+pub(crate) fn scale_subresource(spec: &mut crate::swagger20::Spec) -> Result<(), crate::Error> {
+	k8s_openapi_codegen_common::fixups::scale_subresource(spec)?;
+
+	if spec.definitions.values().any(|definition| definition.scale_definition_path.is_some()) {
+		Ok(())
+	}
+	else {
+		Err("never found any scale subresources".into())
+	}
+}
+
+// Resources with an `eviction` subresource implement `k8s_openapi::Evictable`, whose associated type is the type of the subresource.
+pub(crate) fn eviction_subresource(spec: &mut crate::swagger20::Spec) -> Result<(), crate::Error> {
+	k8s_openapi_codegen_common::fixups::eviction_subresource(spec)?;
+
+	if spec.definitions.values().any(|definition| definition.eviction_definition_path.is_some()) {
+		Ok(())
+	}
+	else {
+		Err("never found any eviction subresources".into())
+	}
+}
+
+// Some types are semantically unions of some of their properties, ie only one of them can be set, like the source properties of a `Volume`.
+// This fixup marks them so that an enum of those properties and an accessor that returns the one that is set are generated for them.
+//
+// Properties that don't exist in a particular version are skipped, so the list of variants of each union is the union of the properties of all versions.
+pub(crate) fn unions(spec: &mut crate::swagger20::Spec) -> Result<(), crate::Error> {
+	const VOLUME_SOURCES: &[&str] = &[
+		"awsElasticBlockStore",
+		"azureDisk",
+		"azureFile",
+		"cephfs",
+		"cinder",
+		"configMap",
+		"csi",
+		"downwardAPI",
+		"emptyDir",
+		"ephemeral",
+		"fc",
+		"flexVolume",
+		"flocker",
+		"gcePersistentDisk",
+		"gitRepo",
+		"glusterfs",
+		"hostPath",
+		"iscsi",
+		"nfs",
+		"persistentVolumeClaim",
+		"photonPersistentDisk",
+		"portworxVolume",
+		"projected",
+		"quobyte",
+		"rbd",
+		"scaleIO",
+		"secret",
+		"storageos",
+		"vsphereVolume",
+	];
+
+	const PERSISTENT_VOLUME_SOURCES: &[&str] = &[
+		"awsElasticBlockStore",
+		"azureDisk",
+		"azureFile",
+		"cephfs",
+		"cinder",
+		"csi",
+		"fc",
+		"flexVolume",
+		"flocker",
+		"gcePersistentDisk",
+		"glusterfs",
+		"hostPath",
+		"iscsi",
+		"local",
+		"nfs",
+		"photonPersistentDisk",
+		"portworxVolume",
+		"quobyte",
+		"rbd",
+		"scaleIO",
+		"storageos",
+		"vsphereVolume",
+	];
+
+	const HANDLER_ACTIONS: &[&str] = &["exec", "httpGet", "tcpSocket"];
+
+	const METRIC_SOURCES: &[&str] = &["containerResource", "external", "object", "pods", "resource"];
+
+	let unions: &[(&str, &str, &str, &[&str])] = &[
+		("io.k8s.api.autoscaling.v2beta1.MetricSpec", "source", "MetricSource", METRIC_SOURCES),
+		("io.k8s.api.autoscaling.v2beta1.MetricStatus", "source", "MetricStatusSource", METRIC_SOURCES),
+		("io.k8s.api.autoscaling.v2beta2.MetricSpec", "source", "MetricSource", METRIC_SOURCES),
+		("io.k8s.api.autoscaling.v2beta2.MetricStatus", "source", "MetricStatusSource", METRIC_SOURCES),
+		("io.k8s.api.core.v1.Handler", "action", "HandlerAction", HANDLER_ACTIONS),
+		("io.k8s.api.core.v1.PersistentVolumeSpec", "source", "PersistentVolumeSource", PERSISTENT_VOLUME_SOURCES),
+		("io.k8s.api.core.v1.Probe", "action", "ProbeAction", HANDLER_ACTIONS),
+		("io.k8s.api.core.v1.Volume", "source", "VolumeSource", VOLUME_SOURCES),
+		("io.k8s.api.core.v1.VolumeProjection", "source", "VolumeProjectionSource", &["configMap", "downwardAPI", "secret", "serviceAccountToken"]),
+	];
+
 	let mut found = false;
 
-	for definition in spec.definitions.values_mut() {
-		if let crate::swagger20::SchemaKind::Properties(properties) = &mut definition.kind {
-			let mut has_api_version = false;
-			let mut has_kind = false;
-			let mut has_optional_metadata = false;
-
-			for (name, (_, required)) in &mut *properties {
-				has_api_version = has_api_version || name.0 == "apiVersion";
-				has_kind = has_kind || name.0 == "kind";
-				has_optional_metadata = has_optional_metadata || (name.0 == "metadata" && !*required);
-				if has_api_version && has_kind && has_optional_metadata {
-					break;
-				}
-			}
+	for &(definition_path, accessor_name, enum_name, variants) in unions {
+		let enum_definition_path = format!("{}.{enum_name}", &definition_path[..definition_path.rfind('.').expect("definition path has a namespace")]);
+		if spec.definitions.contains_key(&crate::swagger20::DefinitionPath(enum_definition_path.clone())) {
+			return Err(format!("union {enum_definition_path} of {definition_path} conflicts with an existing definition").into());
+		}
 
-			if has_api_version && has_kind && has_optional_metadata {
-				let metadata = properties.get_mut(&crate::swagger20::PropertyName("metadata".to_owned())).unwrap();
-				metadata.1 = true;
-				found = true;
+		let Some(definition) = spec.definitions.get_mut(&crate::swagger20::DefinitionPath(definition_path.to_owned())) else { continue; };
+
+		let crate::swagger20::SchemaKind::Properties(properties) = &definition.kind else {
+			return Err(format!("{definition_path} does not have properties").into());
+		};
+
+		let variants: Vec<_> =
+			variants.iter()
+			.map(|&variant| crate::swagger20::PropertyName(variant.to_owned()))
+			.filter(|variant| properties.contains_key(variant))
+			.collect();
+		if variants.is_empty() {
+			return Err(format!("{definition_path} does not have any of the properties of its union {enum_name}").into());
+		}
+
+		definition.union = Some(crate::swagger20::Union {
+			accessor_name: accessor_name.to_owned(),
+			enum_name: enum_name.to_owned(),
+			variants,
+		});
+		found = true;
+	}
+
+	if found {
+		Ok(())
+	}
+	else {
+		Err("never applied unions override".into())
+	}
+}
+
+// Some string properties only accept a fixed set of values, like `Container::image_pull_policy`.
+// This fixup replaces them with references to string enum types with those values and an `Other` variant for values that aren't known yet,
+// so that typos are caught at compile time. The enum types are defined in the same namespace as the first type that uses them.
+//
+// Newer specs list these values in the description of the property, after a "Possible enum values:" line, and every such property is converted.
+// Its enum type is named after the type and the property, like `ServiceSpecType`, unless the table below gives it a name.
+// Older specs, including all the ones that are currently supported, don't have these lists, so the values of the properties in the table
+// are maintained by hand.
+//
+// Properties that don't exist in a particular version are skipped, and properties that have the same enum type must agree on its values.
+pub(crate) fn string_enums(spec: &mut crate::swagger20::Spec) -> Result<(), crate::Error> {
+	const PULL_POLICY: (&str, &str, &[&str]) = (
+		"PullPolicy", "PullPolicy describes a policy for if/when to pull a container image.", &["Always", "IfNotPresent", "Never"]);
+
+	const TERMINATION_MESSAGE_POLICY: (&str, &str, &[&str]) = (
+		"TerminationMessagePolicy", "TerminationMessagePolicy describes how termination messages are retrieved from a container.", &["File", "FallbackToLogsOnError"]);
+
+	const PROTOCOL: (&str, &str, &[&str]) = (
+		"Protocol", "Protocol defines network protocols supported for things like container ports.", &["TCP", "UDP", "SCTP"]);
+
+	const TAINT_EFFECT: (&str, &str, &[&str]) = (
+		"TaintEffect", "TaintEffect is the effect of a taint on pods that do not tolerate the taint.", &["NoSchedule", "PreferNoSchedule", "NoExecute"]);
+
+	let string_enums: &[(&str, &str, (&str, &str, &[&str]))] = &[
+		("io.k8s.api.apps.v1.DaemonSetUpdateStrategy", "type", (
+			"DaemonSetUpdateStrategyType", "DaemonSetUpdateStrategyType is the type of a daemon set's update strategy.", &["OnDelete", "RollingUpdate"])),
+		("io.k8s.api.apps.v1.DeploymentStrategy", "type", (
+			"DeploymentStrategyType", "DeploymentStrategyType is the type of a deployment's strategy.", &["Recreate", "RollingUpdate"])),
+		("io.k8s.api.apps.v1.StatefulSetSpec", "podManagementPolicy", (
+			"PodManagementPolicyType", "PodManagementPolicyType defines the policy for creating pods under a stateful set.", &["OrderedReady", "Parallel"])),
+		("io.k8s.api.apps.v1.StatefulSetUpdateStrategy", "type", (
+			"StatefulSetUpdateStrategyType", "StatefulSetUpdateStrategyType is the type of a stateful set's update strategy.", &["OnDelete", "RollingUpdate"])),
+		("io.k8s.api.core.v1.Container", "imagePullPolicy", PULL_POLICY),
+		("io.k8s.api.core.v1.Container", "terminationMessagePolicy", TERMINATION_MESSAGE_POLICY),
+		("io.k8s.api.core.v1.ContainerPort", "protocol", PROTOCOL),
+		("io.k8s.api.core.v1.EphemeralContainer", "imagePullPolicy", PULL_POLICY),
+		("io.k8s.api.core.v1.EphemeralContainer", "terminationMessagePolicy", TERMINATION_MESSAGE_POLICY),
+		("io.k8s.api.core.v1.NodeSelectorRequirement", "operator", (
+			"NodeSelectorOperator", "NodeSelectorOperator is the set of operators that can be used in a node selector requirement.", &["In", "NotIn", "Exists", "DoesNotExist", "Gt", "Lt"])),
+		("io.k8s.api.core.v1.PersistentVolumeSpec", "persistentVolumeReclaimPolicy", (
+			"PersistentVolumeReclaimPolicy", "PersistentVolumeReclaimPolicy describes a policy for end-of-life maintenance of persistent volumes.", &["Delete", "Recycle", "Retain"])),
+		("io.k8s.api.core.v1.PodSpec", "dnsPolicy", (
+			"DNSPolicy", "DNSPolicy defines how a pod's DNS will be configured.", &["ClusterFirst", "ClusterFirstWithHostNet", "Default", "None"])),
+		("io.k8s.api.core.v1.PodSpec", "restartPolicy", (
+			"RestartPolicy", "RestartPolicy describes how the containers of a pod should be restarted.", &["Always", "OnFailure", "Never"])),
+		("io.k8s.api.core.v1.ServicePort", "protocol", PROTOCOL),
+		("io.k8s.api.core.v1.ServiceSpec", "externalTrafficPolicy", (
+			"ServiceExternalTrafficPolicyType", "ServiceExternalTrafficPolicyType describes how nodes route external traffic to the endpoints of a service.", &["Cluster", "Local"])),
+		("io.k8s.api.core.v1.ServiceSpec", "sessionAffinity", (
+			"ServiceAffinity", "ServiceAffinity is the type of the session affinity of a service.", &["ClientIP", "None"])),
+		("io.k8s.api.core.v1.ServiceSpec", "type", (
+			"ServiceType", "ServiceType describes how a service is exposed.", &["ClusterIP", "ExternalName", "LoadBalancer", "NodePort"])),
+		("io.k8s.api.core.v1.Taint", "effect", TAINT_EFFECT),
+		("io.k8s.api.core.v1.Toleration", "effect", TAINT_EFFECT),
+		("io.k8s.api.core.v1.Toleration", "operator", (
+			"TolerationOperator", "TolerationOperator is the set of operators that can be used in a toleration.", &["Exists", "Equal"])),
+		("io.k8s.apimachinery.pkg.apis.meta.v1.LabelSelectorRequirement", "operator", (
+			"LabelSelectorOperator", "LabelSelectorOperator is the set of operators that can be used in a label selector requirement.", &["In", "NotIn", "Exists", "DoesNotExist"])),
+	];
+
+	let mut converted: std::collections::BTreeMap<(String, String), (String, String, Vec<String>)> = Default::default();
+
+	for (definition_path, definition) in &spec.definitions {
+		let crate::swagger20::SchemaKind::Properties(properties) = &definition.kind else { continue; };
+
+		for (property_name, (property, _)) in properties {
+			if property.kind != crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::String { format: None }) {
+				continue;
 			}
+
+			let Some(values) = property.description.as_deref().and_then(possible_enum_values) else { continue; };
+
+			let type_name = &definition_path[(definition_path.rfind('.').expect("definition path has a namespace") + 1)..];
+			let mut property_chars = property_name.chars();
+			let enum_name: String =
+				property_chars.next().into_iter().flat_map(char::to_uppercase)
+				.chain(property_chars)
+				.collect();
+			let enum_name = format!("{type_name}{enum_name}");
+			let enum_description = format!("{enum_name} is the set of values of {type_name}.{property_name}.");
+			converted.insert((definition_path.to_string(), property_name.to_string()), (enum_name, enum_description, values));
+		}
+	}
+
+	for &(definition_path, property_name, (enum_name, enum_description, values)) in string_enums {
+		let Some(definition) = spec.definitions.get(&crate::swagger20::DefinitionPath(definition_path.to_owned())) else { continue; };
+
+		let crate::swagger20::SchemaKind::Properties(properties) = &definition.kind else {
+			return Err(format!("{definition_path} does not have properties").into());
+		};
+
+		if !properties.contains_key(&crate::swagger20::PropertyName(property_name.to_owned())) {
+			continue;
 		}
+
+		// Prefer the values in the spec, if it has them.
+		let key = (definition_path.to_owned(), property_name.to_owned());
+		let values = match converted.remove(&key) {
+			Some((_, _, values)) => values,
+			None => values.iter().map(|&value| value.to_owned()).collect(),
+		};
+		converted.insert(key, (enum_name.to_owned(), enum_description.to_owned(), values));
+	}
+
+	let mut found = false;
+
+	for ((definition_path, property_name), (enum_name, enum_description, values)) in converted {
+		let Some(definition) = spec.definitions.get_mut(&crate::swagger20::DefinitionPath(definition_path.clone())) else { continue; };
+
+		let crate::swagger20::SchemaKind::Properties(properties) = &mut definition.kind else {
+			return Err(format!("{definition_path} does not have properties").into());
+		};
+
+		let Some((property, _)) = properties.get_mut(&crate::swagger20::PropertyName(property_name.clone())) else { continue; };
+
+		if property.kind != crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::String { format: None }) {
+			return Err(format!("{definition_path}.{property_name} is not a string").into());
+		}
+
+		let enum_definition_path = format!("{}.{enum_name}", &definition_path[..definition_path.rfind('.').expect("definition path has a namespace")]);
+		property.kind = crate::swagger20::SchemaKind::Ref(crate::swagger20::RefPath {
+			path: enum_definition_path.clone(),
+			can_be_default: None,
+		});
+
+		let enum_kind = crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::StringEnum { values });
+
+		match spec.definitions.entry(crate::swagger20::DefinitionPath(enum_definition_path)) {
+			std::collections::btree_map::Entry::Occupied(entry) =>
+				if entry.get().kind != enum_kind {
+					return Err(format!("string enum {} of {definition_path}.{property_name} conflicts with an existing definition", entry.key()).into());
+				},
+
+			std::collections::btree_map::Entry::Vacant(entry) => {
+				entry.insert(crate::swagger20::Schema {
+					description: Some(enum_description),
+					kind: enum_kind,
+					kubernetes_group_kind_versions: vec![],
+					list_kind: None,
+					scale_definition_path: None,
+					eviction_definition_path: None,
+					union: None,
+					patch_strategy: None,
+				});
+			},
+		}
+
+		found = true;
 	}
 
 	if found {
 		Ok(())
 	}
 	else {
-		Err("never applied override to make resource metadata non-optional".into())
+		Err("never applied string enums override".into())
+	}
+}
+
+/// Parses the values listed after "Possible enum values:" in the description of a property, like `Always` in
+///
+/// ```text
+/// Possible enum values:
+///  - `"Always"` means that kubelet always attempts to pull the latest image.
+/// ```
+fn possible_enum_values(description: &str) -> Option<Vec<String>> {
+	let (_, list) = description.split_once("Possible enum values:")?;
+
+	let values: Vec<_> =
+		list.lines()
+		.filter_map(|line| line.trim_start().strip_prefix("- `\"")?.split_once("\"`"))
+		.map(|(value, _)| value.to_owned())
+		.collect();
+	if values.is_empty() {
+		return None;
+	}
+
+	Some(values)
+}
+
+// The `metrics.k8s.io` API is served by an aggregated API server like metrics-server rather than by kube-apiserver itself, so it's not part of the spec.
+// This fixup adds its types from `supplemental/metrics.json` along with their list and read operations.
+//
+// It runs after all the other fixups, so the operations are synthesized with their common `ListOptional` parameter and `ListResponse` response type directly.
+// The metrics API does not support watches.
+pub(crate) fn metrics(spec: &mut crate::swagger20::Spec) -> Result<(), crate::Error> {
+	add_supplemental_definitions(spec, include_str!("../../supplemental/metrics.json"), true)?;
+
+	let (list_optional_parameter, namespace_parameter) = {
+		let list_operation =
+			spec.operations.iter().find(|o| o.id == "listCoreV1NamespacedPod")
+			.ok_or("could not find listCoreV1NamespacedPod operation")?;
+		let list_optional_parameter =
+			list_operation.parameters.iter().find(|p| p.name == "optional")
+			.ok_or("listCoreV1NamespacedPod operation does not have an optional parameter")?
+			.clone();
+		let namespace_parameter =
+			list_operation.parameters.iter().find(|p| p.name == "namespace")
+			.ok_or("listCoreV1NamespacedPod operation does not have a namespace parameter")?
+			.clone();
+		(list_optional_parameter, namespace_parameter)
+	};
+
+	for &(kind, url_path_segment, namespaced) in &[("NodeMetrics", "nodes", false), ("PodMetrics", "pods", true)] {
+		let kubernetes_group_kind_version = crate::swagger20::KubernetesGroupKindVersion {
+			group: "metrics.k8s.io".to_owned(),
+			kind: kind.to_owned(),
+			version: "v1beta1".to_owned(),
+		};
+
+		let name_parameter = std::sync::Arc::new(crate::swagger20::Parameter {
+			location: crate::swagger20::ParameterLocation::Path,
+			name: "name".to_owned(),
+			required: true,
+			schema: crate::swagger20::Schema {
+				description: Some(format!("name of the {}", kind)),
+				kind: crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::String { format: None }),
+				kubernetes_group_kind_versions: vec![],
+				list_kind: None,
+				scale_definition_path: None,
+				eviction_definition_path: None,
+				union: None,
+				patch_strategy: None,
+			},
+		});
+
+		let list_description = format!("list objects of kind {}\n\nThis operation only supports listing all items of this type.", kind);
+
+		let mut operations = vec![];
+
+		if namespaced {
+			operations.push((
+				format!("listMetricsV1beta1Namespaced{}", kind),
+				list_description.clone(),
+				crate::swagger20::KubernetesAction::List,
+				vec![namespace_parameter.clone(), list_optional_parameter.clone()],
+				format!("/apis/metrics.k8s.io/v1beta1/namespaces/{{namespace}}/{}", url_path_segment),
+			));
+			operations.push((
+				format!("listMetricsV1beta1{}ForAllNamespaces", kind),
+				list_description,
+				crate::swagger20::KubernetesAction::List,
+				vec![list_optional_parameter.clone()],
+				format!("/apis/metrics.k8s.io/v1beta1/{}", url_path_segment),
+			));
+			operations.push((
+				format!("readMetricsV1beta1Namespaced{}", kind),
+				format!("read the specified {}", kind),
+				crate::swagger20::KubernetesAction::Get,
+				vec![name_parameter, namespace_parameter.clone()],
+				format!("/apis/metrics.k8s.io/v1beta1/namespaces/{{namespace}}/{}/{{name}}", url_path_segment),
+			));
+		}
+		else {
+			operations.push((
+				format!("listMetricsV1beta1{}", kind),
+				list_description,
+				crate::swagger20::KubernetesAction::List,
+				vec![list_optional_parameter.clone()],
+				format!("/apis/metrics.k8s.io/v1beta1/{}", url_path_segment),
+			));
+			operations.push((
+				format!("readMetricsV1beta1{}", kind),
+				format!("read the specified {}", kind),
+				crate::swagger20::KubernetesAction::Get,
+				vec![name_parameter],
+				format!("/apis/metrics.k8s.io/v1beta1/{}/{{name}}", url_path_segment),
+			));
+		}
+
+		for (id, description, kubernetes_action, parameters, path) in operations {
+			let responses = match kubernetes_action {
+				crate::swagger20::KubernetesAction::List => crate::swagger20::OperationResponses::Common(crate::swagger20::Type::ListResponse),
+				_ => crate::swagger20::OperationResponses::Map(std::iter::once((http::StatusCode::OK, crate::swagger20::Schema {
+					description: None,
+					kind: crate::swagger20::SchemaKind::Ref(crate::swagger20::RefPath {
+						path: format!("io.k8s.metrics.pkg.apis.metrics.v1beta1.{}", kind),
+						can_be_default: None,
+					}),
+					kubernetes_group_kind_versions: vec![],
+					list_kind: None,
+					scale_definition_path: None,
+					eviction_definition_path: None,
+					union: None,
+					patch_strategy: None,
+				})).collect()),
+			};
+
+			spec.operations.push(crate::swagger20::Operation {
+				description: Some(description),
+				id,
+				method: crate::swagger20::Method::Get,
+				kubernetes_action: Some(kubernetes_action),
+				kubernetes_group_kind_version: Some(kubernetes_group_kind_version.clone()),
+				parameters,
+				path: crate::swagger20::Path(path),
+				responses,
+				tag: Some("metricsV1beta1".to_owned()),
+			});
+		}
 	}
+
+	Ok(())
+}
+
+// Add the custom.metrics.k8s.io and external.metrics.k8s.io types, which are served by metrics adapters rather than the API server
+// and thus are not in the spec. Their paths depend on the described object and the metric name, so no operations are generated for them.
+pub(crate) fn custom_metrics(spec: &mut crate::swagger20::Spec) -> Result<(), crate::Error> {
+	add_supplemental_definitions(spec, include_str!("../../supplemental/custom_metrics.json"), true)
+}
+
+// Add the kubelet's `kubelet.config.k8s.io` configuration file type. It's read from a file by the kubelet rather than served by any API.
+pub(crate) fn kubelet_configuration(spec: &mut crate::swagger20::Spec) -> Result<(), crate::Error> {
+	add_supplemental_definitions(spec, include_str!("../../supplemental/kubelet_configuration.json"), false)
+}
+
+// Add the `kubescheduler.config.k8s.io` and `kubeproxy.config.k8s.io` configuration file types, along with the component-base types they share.
+//
+// The scheduler's plugin args refer to `TopologySpreadConstraint`, so the scheduler types are skipped for versions that don't have it.
+pub(crate) fn component_configuration(spec: &mut crate::swagger20::Spec) -> Result<(), crate::Error> {
+	add_supplemental_definitions(spec, include_str!("../../supplemental/component_base.json"), false)?;
+
+	add_supplemental_definitions(spec, include_str!("../../supplemental/kube_proxy_configuration.json"), false)?;
+
+	if spec.definitions.contains_key(&crate::swagger20::DefinitionPath("io.k8s.api.core.v1.TopologySpreadConstraint".to_owned())) {
+		add_supplemental_definitions(spec, include_str!("../../supplemental/kube_scheduler_configuration.json"), false)?;
+	}
+
+	Ok(())
+}
+
+// Add client-go's kubeconfig file types. The file has `apiVersion: v1` and `kind: Config`, but is not a resource of the core API group.
+pub(crate) fn kubeconfig(spec: &mut crate::swagger20::Spec) -> Result<(), crate::Error> {
+	add_supplemental_definitions(spec, include_str!("../../supplemental/kubeconfig.json"), false)
+}
+
+// Add the Gateway API (`gateway.networking.k8s.io`) types. They are installed into clusters as CRDs rather than served by the API server itself,
+// so they are not in the spec. The generated module is gated behind the `gateway-api` feature.
+//
+// The types' conditions use `meta/v1` `Condition`, so they are only added for versions that have it.
+//
+// The resources are served like any other, so their operations are cloned from those of an existing resource with the same scope.
+pub(crate) fn gateway_api(spec: &mut crate::swagger20::Spec) -> Result<(), crate::Error> {
+	// (kind, URL path segment, namespaced, has status subresource)
+	const RESOURCES: &[(&str, &str, bool, bool)] = &[
+		("Gateway", "gateways", true, true),
+		("GatewayClass", "gatewayclasses", false, true),
+		("HTTPRoute", "httproutes", true, true),
+		("ReferencePolicy", "referencepolicies", true, false),
+		("TCPRoute", "tcproutes", true, true),
+		("TLSRoute", "tlsroutes", true, true),
+		("UDPRoute", "udproutes", true, true),
+	];
+
+	if !spec.definitions.contains_key(&crate::swagger20::DefinitionPath("io.k8s.apimachinery.pkg.apis.meta.v1.Condition".to_owned())) {
+		return Ok(());
+	}
+
+	add_supplemental_definitions(spec, include_str!("../../supplemental/gateway_api.json"), true)?;
+
+	let mut new_operations = vec![];
+
+	for &(kind, url_path_segment, namespaced, has_status) in RESOURCES {
+		// (group, kind, definition path, operation ID and tag prefix, URL path prefix, URL path segment)
+		let (template_group, template_kind, template_definition_path, template_id_prefix, template_path_prefix, template_url_path_segment) =
+			if namespaced {
+				("apps", "Deployment", "io.k8s.api.apps.v1.Deployment", "AppsV1", "/apis/apps/v1/", "deployments")
+			}
+			else {
+				("", "PersistentVolume", "io.k8s.api.core.v1.PersistentVolume", "CoreV1", "/api/v1/", "persistentvolumes")
+			};
+
+		let definition_path = format!("io.k8s.gateway-api.apis.v1alpha2.{}", kind);
+
+		let kubernetes_group_kind_version = crate::swagger20::KubernetesGroupKindVersion {
+			group: "gateway.networking.k8s.io".to_owned(),
+			kind: kind.to_owned(),
+			version: "v1alpha2".to_owned(),
+		};
+
+		let replace_ref = |schema: &crate::swagger20::Schema| -> Option<crate::swagger20::Schema> {
+			match &schema.kind {
+				crate::swagger20::SchemaKind::Ref(crate::swagger20::RefPath { path, can_be_default }) if path == template_definition_path =>
+					Some(crate::swagger20::Schema {
+						kind: crate::swagger20::SchemaKind::Ref(crate::swagger20::RefPath {
+							path: definition_path.clone(),
+							can_be_default: *can_be_default,
+						}),
+						..schema.clone()
+					}),
+				_ => None,
+			}
+		};
+
+		let template_operations = spec.operations.iter().filter(|operation| matches!(
+			&operation.kubernetes_group_kind_version,
+			Some(crate::swagger20::KubernetesGroupKindVersion { group, kind, version })
+				if group == template_group && kind == template_kind && version == "v1"
+		));
+
+		let mut num_operations = 0;
+
+		for template_operation in template_operations {
+			// Skip subresources other than status, like the Deployment's scale subresource.
+			let template_path_suffix =
+				template_operation.path.rsplit_once("/{name}/")
+				.map(|(_, template_path_suffix)| template_path_suffix);
+			match template_path_suffix {
+				None => (),
+				Some("status") if has_status => (),
+				Some(_) => continue,
+			}
+
+			let parameters = template_operation.parameters.iter().map(|parameter| match (&*parameter.name, replace_ref(&parameter.schema)) {
+				("name", _) => std::sync::Arc::new(crate::swagger20::Parameter {
+					schema: crate::swagger20::Schema {
+						description: Some(format!("name of the {}", kind)),
+						..parameter.schema.clone()
+					},
+					..(**parameter).clone()
+				}),
+				(_, Some(schema)) => std::sync::Arc::new(crate::swagger20::Parameter {
+					schema,
+					..(**parameter).clone()
+				}),
+				(_, None) => parameter.clone(),
+			}).collect();
+
+			let responses = match &template_operation.responses {
+				crate::swagger20::OperationResponses::Common(type_) => crate::swagger20::OperationResponses::Common(type_.clone()),
+				crate::swagger20::OperationResponses::Map(responses) =>
+					crate::swagger20::OperationResponses::Map(
+						responses.iter()
+						.map(|(status_code, schema)| (*status_code, replace_ref(schema).unwrap_or_else(|| schema.clone())))
+						.collect()),
+			};
+
+			new_operations.push(crate::swagger20::Operation {
+				description: template_operation.description.as_ref().map(|description| description.replace(template_kind, kind)),
+				id:
+					template_operation.id
+					.replace(template_id_prefix, "GatewayNetworkingV1alpha2")
+					.replace(template_kind, kind),
+				method: template_operation.method,
+				kubernetes_action: template_operation.kubernetes_action,
+				kubernetes_group_kind_version: Some(kubernetes_group_kind_version.clone()),
+				parameters,
+				path: crate::swagger20::Path(
+					template_operation.path
+					.replacen(template_path_prefix, "/apis/gateway.networking.k8s.io/v1alpha2/", 1)
+					.replace(&format!("/{}", template_url_path_segment), &format!("/{}", url_path_segment))),
+				responses,
+				tag: Some("gatewayNetworkingV1alpha2".to_owned()),
+			});
+
+			num_operations += 1;
+		}
+
+		if num_operations == 0 {
+			return Err(format!("could not find any operations of {} to use as templates for {}", template_kind, kind).into());
+		}
+	}
+
+	spec.operations.extend(new_operations);
+
+	Ok(())
+}
+
+// Add the definitions in the given JSON object of supplemental definitions to the spec.
+//
+// Since the `list` fixup has already run by the time this is called, listable resources get their list kind set here instead.
+fn add_supplemental_definitions(spec: &mut crate::swagger20::Spec, definitions: &str, listable: bool) -> Result<(), crate::Error> {
+	let definitions: std::collections::BTreeMap<crate::swagger20::DefinitionPath, crate::swagger20::Schema> = serde_json::from_str(definitions)?;
+	for (definition_path, mut definition) in definitions {
+		if let (true, [kubernetes_group_kind_version]) = (listable, &*definition.kubernetes_group_kind_versions) {
+			definition.list_kind = Some(format!("{}List", kubernetes_group_kind_version.kind));
+		}
+
+		if spec.definitions.insert(definition_path.clone(), definition).is_some() {
+			return Err(format!("{} is already defined in the spec", definition_path).into());
+		}
+	}
+
+	Ok(())
 }
@@ -68,6 +68,10 @@ pub(crate) fn pod_exec_command_parameter_type(spec: &mut crate::swagger20::Spec)
 								kind: crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::String { format: None }),
 								kubernetes_group_kind_versions: vec![],
 								list_kind: None,
+								scale_definition_path: None,
+								eviction_definition_path: None,
+								union: None,
+								patch_strategy: None,
 							}),
 						}),
 						..(parameter.schema.clone())
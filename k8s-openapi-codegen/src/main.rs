@@ -15,6 +15,7 @@
 
 mod fixups;
 mod logger;
+mod out_dir;
 mod supported_version;
 
 use k8s_openapi_codegen_common::swagger20;
@@ -49,7 +50,12 @@ impl std::fmt::Display for Error {
 }
 
 fn main() -> Result<(), Error> {
-	let Options { versions: requested_versions } = structopt::StructOpt::from_args();
+	let Options { versions: requested_versions, api_groups, jobs } = structopt::StructOpt::from_args();
+
+	let jobs = match jobs {
+		Some(jobs) => jobs.max(1),
+		None => std::thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get),
+	};
 
 	{
 		let logger = logger::Logger;
@@ -68,6 +74,8 @@ fn main() -> Result<(), Error> {
 			.timeout(None)
 			.build().expect("could not create reqwest client"));
 
+	let api_groups = std::sync::Arc::new(api_groups);
+
 	let out_dir_base: &std::path::Path = env!("CARGO_MANIFEST_DIR").as_ref();
 	let out_dir_base = std::sync::Arc::new(out_dir_base.parent().ok_or("path does not have a parent")?.join("src"));
 
@@ -87,6 +95,7 @@ fn main() -> Result<(), Error> {
 			std::thread::Builder::new().name(version_name.to_owned()).spawn({
 				let out_dir_base = out_dir_base.clone();
 				let client = client.clone();
+				let api_groups = api_groups.clone();
 
 				move || -> Result<(), Error> {
 					{
@@ -102,7 +111,7 @@ fn main() -> Result<(), Error> {
 
 					let spec_url = overriden_spec_url.as_deref().unwrap_or_else(|| version.spec_url());
 
-					run(version, spec_url, &out_dir_base, &client)?;
+					run(version, spec_url, &out_dir_base, &client, &api_groups, jobs)?;
 
 					Ok(())
 				}
@@ -138,6 +147,19 @@ struct Options {
 	/// based on their OpenAPI specs from the https://github.com/kubernetes/kubernetes repository.
 	#[structopt(long = "generate", value_name = "VERSION")]
 	versions: Vec<RequestedVersion>,
+
+	/// This parameter restricts the API bindings to the given API groups, like `--api-group=core --api-group=apps`.
+	///
+	/// The API groups are named by their modules in the generated code. The apimachinery types are always generated,
+	/// as are the types of other API groups that the types of the given API groups refer to.
+	///
+	/// If this parameter isn't specified, the API bindings will be generated for all API groups.
+	#[structopt(long = "api-group", value_name = "GROUP")]
+	api_groups: Vec<String>,
+
+	/// The number of threads that the types of each version are generated on. Defaults to the number of CPUs.
+	#[structopt(long = "jobs", short = "j", value_name = "N")]
+	jobs: Option<usize>,
 }
 
 struct RequestedVersion {
@@ -185,6 +207,8 @@ fn run(
 	spec_url: &str,
 	out_dir_base: &std::path::Path,
 	client: &reqwest::blocking::Client,
+	api_groups: &[String],
+	jobs: usize,
 ) -> Result<(), Error> {
 	let mod_root = supported_version.mod_root();
 
@@ -207,60 +231,83 @@ fn run(
 	log::info!("Applying fixups...");
 	supported_version.fixup(&mut spec)?;
 
+	if !api_groups.is_empty() {
+		log::info!("Retaining API groups {api_groups:?} ...");
+		let api_groups: Vec<_> = api_groups.iter().map(String::as_str).collect();
+		k8s_openapi_codegen_common::fixups::retain_api_groups(&mut spec, &api_groups)?;
+	}
+
 	let expected_num_generated_types: usize = spec.definitions.len();
 	let expected_num_generated_apis: usize = spec.operations.len();
 
 	log::info!("OK. Spec has {} definitions and {} operations", expected_num_generated_types, expected_num_generated_apis);
 
-	loop {
-		log::info!("Removing output directory {} ...", out_dir.display());
-		match std::fs::remove_dir_all(&out_dir) {
-			Ok(()) => log::trace!("OK"),
-			Err(ref err) if err.kind() == std::io::ErrorKind::NotFound => {
-				log::trace!("OK. Directory doesn't exist");
-
-				log::info!("Creating output directory {} ...", out_dir.display());
-				match std::fs::create_dir(&out_dir) {
-					Ok(()) => {
-						log::trace!("OK");
-						break;
-					},
-					Err(err) => log::warn!("Error: {}", err),
-				}
-			},
-			Err(err) => log::warn!("Error: {}", err),
-		}
-	}
+	let mut generated_files = out_dir::OutDir::default();
 
 	log::info!("Generating types...");
 
-	for definition_path in spec.definitions.keys() {
-		log::trace!("Working on {} ...", definition_path);
-
-		let run_state = RunState {
-			out_dir: &out_dir,
-			parent_mod_rs_file_and_mod_name: None,
+	// The date-time newtypes `Time` and `MicroTime` are also generated as the `String`s of the `date_time_as_string` fixup,
+	// for when the `chrono` feature is disabled.
+	let definitions_without_chrono = {
+		let mut spec_without_chrono = swagger20::Spec {
+			info: swagger20::Info { title: spec.info.title.clone(), version: spec.info.version.clone() },
+			definitions: spec.definitions.clone(),
+			operations: vec![],
 		};
+		k8s_openapi_codegen_common::fixups::date_time_as_string(&mut spec_without_chrono)?;
+		spec_without_chrono.definitions
+	};
 
-		let run_result = k8s_openapi_codegen_common::run(
-			&spec.definitions,
-			&mut spec.operations,
-			definition_path,
-			&MapNamespace,
-			"pub ",
-			k8s_openapi_codegen_common::GenerateSchema::Yes { feature: Some("schemars") },
-			Some("api"),
-			run_state,
-		)?;
+	let (generated_types, leftover_operations) =
+		generate_types(&spec.definitions, &definitions_without_chrono, std::mem::take(&mut spec.operations), jobs)?;
+	spec.operations = leftover_operations;
+
+	for (generated_type, run_result) in generated_types {
+		add_generated_type(&mut generated_files, generated_type)?;
 
 		num_generated_structs += run_result.num_generated_structs;
 		num_generated_type_aliases += run_result.num_generated_type_aliases;
 		num_generated_apis += run_result.num_generated_apis;
 	}
 
+	// Type registry
+	{
+		use std::io::Write;
+
+		let mod_root_file = generated_files.file("mod.rs");
+		writeln!(mod_root_file)?;
+		writeln!(mod_root_file, r#"#[cfg(feature = "reflection")]"#)?;
+		writeln!(mod_root_file, "mod type_registry;")?;
+		writeln!(mod_root_file, r#"#[cfg(feature = "reflection")]"#)?;
+		writeln!(mod_root_file, "pub(crate) use self::type_registry::TYPES;")?;
+
+		k8s_openapi_codegen_common::write_type_registry(
+			generated_files.file("type_registry.rs"),
+			&spec.definitions,
+			&MapNamespace,
+			definition_feature,
+		)?;
+	}
+
+	// Conversions between versions of resource types
+	{
+		use std::io::Write;
+
+		let mod_root_file = generated_files.file("mod.rs");
+		writeln!(mod_root_file)?;
+		writeln!(mod_root_file, "mod version_conversions;")?;
+
+		k8s_openapi_codegen_common::write_version_conversions(
+			generated_files.file("version_conversions.rs"),
+			&spec.definitions,
+			&MapNamespace,
+			definition_feature,
+		)?;
+	}
+
 	// Top-level operations
 	{
-		let mut mod_root_file = std::io::BufWriter::new(std::fs::OpenOptions::new().append(true).open(out_dir.join("mod.rs"))?);
+		let mod_root_file = generated_files.file("mod.rs");
 
 		spec.operations.sort_by(|o1, o2| o1.id.cmp(&o2.id));
 		for operation in spec.operations {
@@ -271,7 +318,7 @@ fn run(
 			}
 
 			k8s_openapi_codegen_common::write_operation(
-				&mut mod_root_file,
+				&mut *mod_root_file,
 				&operation,
 				&MapNamespace,
 				"pub ",
@@ -296,6 +343,12 @@ fn run(
 		return Err("Did not generate expected number of API functions".into());
 	}
 
+	log::info!("Writing changed files to {} ...", out_dir.display());
+	let write_result = generated_files.write(&out_dir)?;
+	log::info!(
+		"OK. Wrote {} files, left {} files unchanged, removed {} stale files and directories",
+		write_result.written, write_result.unchanged, write_result.removed);
+
 	log::info!("");
 
 	Ok(())
@@ -312,74 +365,274 @@ impl k8s_openapi_codegen_common::MapNamespace for MapNamespace {
 	}
 }
 
-struct RunState<'a> {
-	out_dir: &'a std::path::Path,
-	parent_mod_rs_file_and_mod_name: Option<(<Self as k8s_openapi_codegen_common::RunState>::Writer, std::borrow::Cow<'static, str>)>,
-}
+struct TemplateProvider;
 
-impl k8s_openapi_codegen_common::RunState for RunState<'_> {
-	type Writer = std::io::BufWriter<std::fs::File>;
+impl k8s_openapi_codegen_common::TemplateProvider for TemplateProvider {
+	fn generate_impl(&self, definition_path: &swagger20::DefinitionPath, template: k8s_openapi_codegen_common::ImplTemplate) -> bool {
+		match (&**definition_path, template) {
+			// Quantity has hand-written impls of PartialEq, Eq and Hash that compare quantities by their numeric values.
+			("io.k8s.apimachinery.pkg.api.resource.Quantity", k8s_openapi_codegen_common::ImplTemplate::PartialEq) => false,
 
-	fn make_writer(
-		&mut self,
-		parts: &[&str],
-		type_feature: Option<&str>,
-	) -> std::io::Result<Self::Writer> {
-		use std::io::Write;
+			// These types have hand-written builders.
+			(
+				"io.k8s.api.core.v1.Affinity" |
+				"io.k8s.api.core.v1.ResourceRequirements" |
+				"io.k8s.apimachinery.pkg.apis.meta.v1.ObjectMeta",
+				k8s_openapi_codegen_common::ImplTemplate::Builder,
+			) => false,
 
-		let mut current = self.out_dir.to_owned();
+			// Quantity has a hand-written impl of Arbitrary that generates well-formed quantities.
+			("io.k8s.apimachinery.pkg.api.resource.Quantity", k8s_openapi_codegen_common::ImplTemplate::Arbitrary) => false,
 
-		for part in parts.iter().skip(1).rev().skip(1).rev() {
-			log::trace!("Current directory: {}", current.display());
+			// TokenRequest has a hand-written constructor that takes the audiences and expiration of the requested token.
+			("io.k8s.api.authentication.v1.TokenRequest", k8s_openapi_codegen_common::ImplTemplate::New) => false,
 
-			let mod_name = k8s_openapi_codegen_common::get_rust_ident(part);
+			_ => true,
+		}
+	}
 
-			current.push(&*mod_name);
+	fn resource_short_names(&self, definition_path: &swagger20::DefinitionPath) -> Vec<String> {
+		// The short names that the API server lists in its discovery documents, which are the same for all versions of a resource.
+		let Some((group_path, kind)) = definition_path.rsplit_once('.').and_then(|(path, kind)| Some((path.rsplit_once('.')?.0, kind))) else {
+			return vec![];
+		};
 
-			log::trace!("Checking if subdirectory {} exists...", current.display());
+		let short_names: &[&str] = match (group_path, kind) {
+			("io.k8s.api.core", "ComponentStatus") => &["cs"],
+			("io.k8s.api.core", "ConfigMap") => &["cm"],
+			("io.k8s.api.core", "Endpoints") => &["ep"],
+			("io.k8s.api.core" | "io.k8s.api.events", "Event") => &["ev"],
+			("io.k8s.api.core", "LimitRange") => &["limits"],
+			("io.k8s.api.core", "Namespace") => &["ns"],
+			("io.k8s.api.core", "Node") => &["no"],
+			("io.k8s.api.core", "PersistentVolume") => &["pv"],
+			("io.k8s.api.core", "PersistentVolumeClaim") => &["pvc"],
+			("io.k8s.api.core", "Pod") => &["po"],
+			("io.k8s.api.core", "ReplicationController") => &["rc"],
+			("io.k8s.api.core", "ResourceQuota") => &["quota"],
+			("io.k8s.api.core", "Service") => &["svc"],
+			("io.k8s.api.core", "ServiceAccount") => &["sa"],
+			("io.k8s.api.apps" | "io.k8s.api.extensions", "DaemonSet") => &["ds"],
+			("io.k8s.api.apps" | "io.k8s.api.extensions", "Deployment") => &["deploy"],
+			("io.k8s.api.apps" | "io.k8s.api.extensions", "ReplicaSet") => &["rs"],
+			("io.k8s.api.apps", "StatefulSet") => &["sts"],
+			("io.k8s.api.autoscaling", "HorizontalPodAutoscaler") => &["hpa"],
+			("io.k8s.api.batch", "CronJob") => &["cj"],
+			("io.k8s.api.certificates", "CertificateSigningRequest") => &["csr"],
+			("io.k8s.api.extensions" | "io.k8s.api.networking", "Ingress") => &["ing"],
+			("io.k8s.api.extensions" | "io.k8s.api.networking", "NetworkPolicy") => &["netpol"],
+			("io.k8s.api.extensions" | "io.k8s.api.policy", "PodSecurityPolicy") => &["psp"],
+			("io.k8s.api.policy", "PodDisruptionBudget") => &["pdb"],
+			("io.k8s.api.scheduling", "PriorityClass") => &["pc"],
+			("io.k8s.api.storage", "StorageClass") => &["sc"],
+			("io.k8s.apiextensions-apiserver.pkg.apis.apiextensions", "CustomResourceDefinition") => &["crd", "crds"],
+			_ => &[],
+		};
+		short_names.iter().map(|&short_name| short_name.to_owned()).collect()
+	}
+}
 
-			if !current.is_dir() {
-				log::trace!("    Subdirectory does not exist. Creating mod.rs with a reference to it...");
+// Top-level modules for types that are not part of Kubernetes itself, and the feature that each of them is gated behind.
+fn top_level_module_feature(mod_name: &str) -> Option<&'static str> {
+	match mod_name {
+		"gateway_api" => Some("gateway-api"),
+		_ => None,
+	}
+}
 
-				let current_mod_rs_path = current.with_file_name("mod.rs");
-				let append_newline = current_mod_rs_path.exists();
-				let mut parent_mod_rs = std::io::BufWriter::new(std::fs::OpenOptions::new().append(true).create(true).open(current_mod_rs_path)?);
-				if append_newline {
-					writeln!(parent_mod_rs)?;
-				}
-				writeln!(parent_mod_rs, "pub mod {};", mod_name)?;
+// The feature that the type generated from the given definition is gated behind, if any.
+fn definition_feature(definition_path: &swagger20::DefinitionPath) -> Option<&'static str> {
+	let path_parts: Vec<_> = definition_path.split('.').collect();
+	let namespace_parts = k8s_openapi_codegen_common::MapNamespace::map_namespace(&MapNamespace, &path_parts[..(path_parts.len() - 1)])?;
+	let mod_name = namespace_parts.get(1)?;
+	top_level_module_feature(&k8s_openapi_codegen_common::get_rust_ident(mod_name))
+}
 
-				log::trace!("    OK");
-				log::trace!("    Creating subdirectory...");
+// Generates the types of all the definitions, split across the given number of threads.
+//
+// Returns the generated types in the order of their definitions, and the operations that are not associated with any definition.
+fn generate_types(
+	definitions: &std::collections::BTreeMap<swagger20::DefinitionPath, swagger20::Schema>,
+	definitions_without_chrono: &std::collections::BTreeMap<swagger20::DefinitionPath, swagger20::Schema>,
+	operations: Vec<swagger20::Operation>,
+	jobs: usize,
+) -> Result<(Vec<(RunState, k8s_openapi_codegen_common::RunResult)>, Vec<swagger20::Operation>), Error> {
+	// `k8s_openapi_codegen_common::run` removes the operations associated with a definition from the list of operations it's given.
+	// Give each definition its own list up-front so that the definitions can be generated independently of each other.
+	let mut operations_by_gkv: std::collections::BTreeMap<_, Vec<_>> = Default::default();
+	for operation in operations {
+		operations_by_gkv
+			.entry(operation.kubernetes_group_kind_version.clone())
+			.or_default()
+			.push(operation);
+	}
 
-				std::fs::create_dir(&current)?;
-				log::trace!("    OK");
+	let mut work: Vec<_> =
+		definitions.iter()
+		.map(|(definition_path, definition)| {
+			let mut operations = vec![];
+			if let swagger20::SchemaKind::Properties(_) = definition.kind {
+				for kubernetes_group_kind_version in &definition.kubernetes_group_kind_versions {
+					if let Some(gkv_operations) = operations_by_gkv.remove(&Some(kubernetes_group_kind_version.clone())) {
+						operations.extend(gkv_operations);
+					}
+				}
 			}
+			(definition_path, operations)
+		})
+		.collect();
+
+	let chunk_size = work.len().div_ceil(jobs).max(1);
+
+	let generated_types = std::thread::scope(|scope| -> Result<Vec<_>, Error> {
+		let threads: Vec<_> =
+			work.chunks_mut(chunk_size)
+			.map(|chunk| scope.spawn(move ||
+				chunk.iter_mut()
+				.map(|(definition_path, operations)| generate_type(definitions, definitions_without_chrono, operations, definition_path))
+				.collect::<Result<Vec<_>, Error>>()))
+			.collect();
+
+		let mut generated_types = Vec::with_capacity(definitions.len());
+		for thread in threads {
+			generated_types.extend(thread.join().unwrap()?);
+		}
+		Ok(generated_types)
+	})?;
+
+	let leftover_operations =
+		operations_by_gkv.into_values().flatten()
+		.chain(work.into_iter().flat_map(|(_, operations)| operations))
+		.collect();
+
+	Ok((generated_types, leftover_operations))
+}
 
-			log::trace!("OK");
+fn generate_type(
+	definitions: &std::collections::BTreeMap<swagger20::DefinitionPath, swagger20::Schema>,
+	definitions_without_chrono: &std::collections::BTreeMap<swagger20::DefinitionPath, swagger20::Schema>,
+	operations: &mut Vec<swagger20::Operation>,
+	definition_path: &swagger20::DefinitionPath,
+) -> Result<(RunState, k8s_openapi_codegen_common::RunResult), Error> {
+	let mut run_state = RunState::default();
+	let run_result = run_codegen(definitions, operations, definition_path, &mut run_state)?;
+
+	if let Some(swagger20::Schema { kind: swagger20::SchemaKind::Ty(swagger20::Type::String { format: Some(swagger20::StringFormat::DateTime) }), .. }) =
+		definitions.get(definition_path)
+	{
+		let mut run_state_without_chrono = RunState::default();
+		run_codegen(definitions_without_chrono, &mut vec![], definition_path, &mut run_state_without_chrono)?;
+		run_state.contents = merge_feature_variants("chrono", &run_state.contents, &run_state_without_chrono.contents)?;
+	}
+
+	Ok((run_state, run_result))
+}
+
+fn run_codegen(
+	definitions: &std::collections::BTreeMap<swagger20::DefinitionPath, swagger20::Schema>,
+	operations: &mut Vec<swagger20::Operation>,
+	definition_path: &swagger20::DefinitionPath,
+	run_state: &mut RunState,
+) -> Result<k8s_openapi_codegen_common::RunResult, Error> {
+	let run_result = k8s_openapi_codegen_common::run(
+		definitions,
+		operations,
+		definition_path,
+		&MapNamespace,
+		&k8s_openapi_codegen_common::RunOptions::default()
+			.generate_schema(k8s_openapi_codegen_common::GenerateSchema::Yes {
+				feature: Some("schemars"),
+				const_type_meta: true,
+				naming: k8s_openapi_codegen_common::SchemaNaming::RustPathIfFeature("schemars-rust-names"),
+			})
+			.generate_arbitrary(k8s_openapi_codegen_common::GenerateArbitrary::Yes { feature: Some("arbitrary") })
+			.generate_builder(k8s_openapi_codegen_common::GenerateBuilder::Yes { feature: Some("builder") })
+			.generate_apply_configuration(k8s_openapi_codegen_common::GenerateApplyConfiguration::Yes { feature: Some("apply-configurations") })
+			.generate_deserialize_in_place(k8s_openapi_codegen_common::GenerateDeserializeInPlace::Yes { feature: Some("deserialize-in-place") })
+			.operation_feature(Some("api"))
+			.template_provider(&TemplateProvider),
+		run_state,
+	)?;
+
+	Ok(run_result)
+}
+
+// Merges the code generated for a type with and without a feature into one file, by gating the items that differ on the feature.
+//
+// Both must consist of the same top-level items in the same order. Items are separated by blank lines, which is how the templates emit them.
+fn merge_feature_variants(feature: &str, with_feature: &[u8], without_feature: &[u8]) -> Result<Vec<u8>, Error> {
+	fn split_items(contents: &str) -> Vec<&str> {
+		let mut items = vec![];
+		let mut item_start = 0;
+		let mut previous_line_is_empty = false;
+		let mut line_start = 0;
+		for line in contents.split_inclusive('\n') {
+			let starts_item = previous_line_is_empty && !line.starts_with(|c: char| c.is_whitespace() || c == '}');
+			if starts_item {
+				items.push(&contents[item_start..line_start]);
+				item_start = line_start;
+			}
+			previous_line_is_empty = line == "\n";
+			line_start += line.len();
 		}
+		items.push(&contents[item_start..]);
+		items
+	}
 
-		let type_name = parts.last().unwrap();
+	// Inserts the `cfg` attribute after the doc comment of the item, if any.
+	fn gate(result: &mut String, item: &str, cfg: &str) {
+		let doc_len: usize = item.split_inclusive('\n').take_while(|line| line.starts_with("///")).map(str::len).sum();
+		result.push_str(&item[..doc_len]);
+		result.push_str(cfg);
+		result.push_str(&item[doc_len..]);
+	}
 
-		let mod_name = k8s_openapi_codegen_common::get_rust_ident(type_name);
+	let with_feature_items = split_items(std::str::from_utf8(with_feature)?);
+	let without_feature_items = split_items(std::str::from_utf8(without_feature)?);
+	if with_feature_items.len() != without_feature_items.len() {
+		return Err(format!("the code generated with and without the {feature} feature has different items").into());
+	}
 
-		let mut parent_mod_rs = std::io::BufWriter::new(std::fs::OpenOptions::new().append(true).create(true).open(current.join("mod.rs"))?);
-		writeln!(parent_mod_rs)?;
-		if let Some(type_feature) = type_feature {
-			writeln!(parent_mod_rs, r#"#[cfg(feature = "{}")]"#, type_feature)?;
+	let mut result = String::new();
+	for (with_feature_item, without_feature_item) in with_feature_items.into_iter().zip(without_feature_items) {
+		if with_feature_item == without_feature_item {
+			result.push_str(with_feature_item);
 		}
-		writeln!(parent_mod_rs, "mod {};", mod_name)?;
-		if let Some(type_feature) = type_feature {
-			writeln!(parent_mod_rs, r#"#[cfg(feature = "{}")]"#, type_feature)?;
+		else {
+			gate(&mut result, with_feature_item, &format!("#[cfg(feature = {feature:?})]\n"));
+			if !with_feature_item.ends_with("\n\n") {
+				result.push('\n');
+			}
+			gate(&mut result, without_feature_item, &format!("#[cfg(not(feature = {feature:?}))]\n"));
 		}
-		writeln!(parent_mod_rs, "pub use self::{}::{};", mod_name, type_name)?;
+	}
+
+	Ok(result.into_bytes())
+}
 
-		let file_name = current.join(&*mod_name).with_extension("rs");
-		let file = std::io::BufWriter::new(std::fs::File::create(file_name)?);
+// The code generated for a single type, and what its module's `mod.rs` needs to declare for it.
+#[derive(Default)]
+struct RunState {
+	parts: Vec<String>,
+	type_feature: Option<String>,
+	operation_types: Vec<(Option<String>, Option<String>)>,
+	additional_types: Vec<String>,
+	builder_types: Vec<(String, Option<String>)>,
+	apply_configuration_types: Vec<(String, Option<String>)>,
+	contents: Vec<u8>,
+}
 
-		self.parent_mod_rs_file_and_mod_name = Some((parent_mod_rs, mod_name));
+impl k8s_openapi_codegen_common::RunState for RunState {
+	type Writer = Vec<u8>;
 
-		Ok(file)
+	fn make_writer(
+		&mut self,
+		parts: &[&str],
+		type_feature: Option<&str>,
+	) -> std::io::Result<Self::Writer> {
+		self.parts = parts.iter().map(|&part| part.to_owned()).collect();
+		self.type_feature = type_feature.map(ToOwned::to_owned);
+		Ok(vec![])
 	}
 
 	fn handle_operation_types(
@@ -387,30 +640,118 @@ impl k8s_openapi_codegen_common::RunState for RunState<'_> {
 		operation_optional_parameters_name: Option<&str>,
 		operation_result_name: Option<&str>,
 	) -> std::io::Result<()> {
-		use std::io::Write;
+		self.operation_types.push((operation_optional_parameters_name.map(ToOwned::to_owned), operation_result_name.map(ToOwned::to_owned)));
+		Ok(())
+	}
+
+	fn handle_additional_type(&mut self, additional_type_name: &str) -> std::io::Result<()> {
+		self.additional_types.push(additional_type_name.to_owned());
+		Ok(())
+	}
+
+	fn handle_builder_type(&mut self, builder_type_name: &str, builder_feature: Option<&str>) -> std::io::Result<()> {
+		self.builder_types.push((builder_type_name.to_owned(), builder_feature.map(ToOwned::to_owned)));
+		Ok(())
+	}
+
+	fn handle_apply_configuration_type(&mut self, apply_configuration_type_name: &str, apply_configuration_feature: Option<&str>) -> std::io::Result<()> {
+		self.apply_configuration_types.push((apply_configuration_type_name.to_owned(), apply_configuration_feature.map(ToOwned::to_owned)));
+		Ok(())
+	}
 
-		let (parent_mod_rs, mod_name) = self.parent_mod_rs_file_and_mod_name.as_mut().unwrap();
-		match (operation_optional_parameters_name, operation_result_name) {
+	fn finish(&mut self, writer: Self::Writer) {
+		self.contents = writer;
+	}
+}
+
+// Adds the file of the given generated type to the output, along with the `mod.rs` lines of its module and of any new parent modules.
+fn add_generated_type(generated_files: &mut out_dir::OutDir, generated_type: RunState) -> std::io::Result<()> {
+	use std::io::Write;
+
+	let RunState { parts, type_feature, operation_types, additional_types, builder_types, apply_configuration_types, contents } = generated_type;
+
+	let mut current = std::path::PathBuf::new();
+
+	for part in parts.iter().skip(1).rev().skip(1).rev() {
+		let mod_name = k8s_openapi_codegen_common::get_rust_ident(part);
+
+		let is_top_level = current.as_os_str().is_empty();
+		let parent_mod_rs_path = current.join("mod.rs");
+
+		current.push(&*mod_name);
+
+		if generated_files.create_dir(&current) {
+			log::trace!("Creating module {} ...", current.display());
+
+			let parent_mod_rs = generated_files.file(parent_mod_rs_path);
+			if !parent_mod_rs.is_empty() {
+				writeln!(parent_mod_rs)?;
+			}
+			if is_top_level {
+				if let Some(module_feature) = top_level_module_feature(&mod_name) {
+					writeln!(parent_mod_rs, r#"#[cfg(feature = "{module_feature}")]"#)?;
+				}
+			}
+			writeln!(parent_mod_rs, "pub mod {mod_name};")?;
+		}
+	}
+
+	let type_name = parts.last().unwrap();
+
+	let mod_name = k8s_openapi_codegen_common::get_rust_ident(type_name);
+
+	let parent_mod_rs = generated_files.file(current.join("mod.rs"));
+	writeln!(parent_mod_rs)?;
+	if let Some(type_feature) = &type_feature {
+		writeln!(parent_mod_rs, r#"#[cfg(feature = "{type_feature}")]"#)?;
+	}
+	writeln!(parent_mod_rs, "mod {mod_name};")?;
+	if let Some(type_feature) = &type_feature {
+		writeln!(parent_mod_rs, r#"#[cfg(feature = "{type_feature}")]"#)?;
+	}
+	writeln!(parent_mod_rs, "pub use self::{mod_name}::{type_name};")?;
+
+	for additional_type_name in additional_types {
+		if let Some(type_feature) = &type_feature {
+			writeln!(parent_mod_rs, r#"#[cfg(feature = "{type_feature}")]"#)?;
+		}
+		writeln!(parent_mod_rs, "pub use self::{mod_name}::{additional_type_name};")?;
+	}
+
+	for (builder_type_name, builder_feature) in builder_types {
+		if let Some(builder_feature) = builder_feature {
+			writeln!(parent_mod_rs, r#"#[cfg(feature = "{builder_feature}")]"#)?;
+		}
+		writeln!(parent_mod_rs, "pub use self::{mod_name}::{builder_type_name};")?;
+	}
+
+	for (apply_configuration_type_name, apply_configuration_feature) in apply_configuration_types {
+		if let Some(apply_configuration_feature) = apply_configuration_feature {
+			writeln!(parent_mod_rs, r#"#[cfg(feature = "{apply_configuration_feature}")]"#)?;
+		}
+		writeln!(parent_mod_rs, "pub use self::{mod_name}::{apply_configuration_type_name};")?;
+	}
+
+	for operation_types in operation_types {
+		match operation_types {
 			(Some(operation_optional_parameters_name), Some(operation_result_name)) =>
 				writeln!(
 					parent_mod_rs,
-					r#"#[cfg(feature = "api")] pub use self::{}::{{{}, {}}};"#,
-					mod_name, operation_optional_parameters_name, operation_result_name)?,
+					r#"#[cfg(feature = "api")] pub use self::{mod_name}::{{{operation_optional_parameters_name}, {operation_result_name}}};"#)?,
 			(Some(operation_optional_parameters_name), None) =>
 				writeln!(
 					parent_mod_rs,
-					r#"#[cfg(feature = "api")] pub use self::{}::{};"#,
-					mod_name, operation_optional_parameters_name)?,
+					r#"#[cfg(feature = "api")] pub use self::{mod_name}::{operation_optional_parameters_name};"#)?,
 			(None, Some(operation_result_name)) =>
 				writeln!(
 					parent_mod_rs,
-					r#"#[cfg(feature = "api")] pub use self::{}::{};"#,
-					mod_name, operation_result_name)?,
+					r#"#[cfg(feature = "api")] pub use self::{mod_name}::{operation_result_name};"#)?,
 			(None, None) =>
 				(),
 		}
-		Ok(())
 	}
 
-	fn finish(&mut self, _writer: Self::Writer) { }
+	*generated_files.file(current.join(&*mod_name).with_extension("rs")) = contents;
+
+	Ok(())
 }
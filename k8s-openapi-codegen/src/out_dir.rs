@@ -0,0 +1,91 @@
+/// The files generated for a single version, assembled in memory before being written out.
+///
+/// Writing them out only touches the files whose contents changed since the previous run, so that regenerating the bindings
+/// after a change to a template or a fixup doesn't rewrite every file and force cargo to rebuild everything.
+#[derive(Debug, Default)]
+pub(crate) struct OutDir {
+	files: std::collections::BTreeMap<std::path::PathBuf, Vec<u8>>,
+	dirs: std::collections::BTreeSet<std::path::PathBuf>,
+}
+
+/// Statistics from [`OutDir::write`]
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct WriteResult {
+	pub(crate) written: usize,
+	pub(crate) unchanged: usize,
+	pub(crate) removed: usize,
+}
+
+impl OutDir {
+	/// Returns the contents of the file at the given path relative to the output directory, creating it if it doesn't exist yet.
+	pub(crate) fn file(&mut self, path: impl Into<std::path::PathBuf>) -> &mut Vec<u8> {
+		self.files.entry(path.into()).or_default()
+	}
+
+	/// Creates the subdirectory at the given path relative to the output directory. Returns `false` if it already existed.
+	pub(crate) fn create_dir(&mut self, path: impl Into<std::path::PathBuf>) -> bool {
+		self.dirs.insert(path.into())
+	}
+
+	/// Writes out the files whose contents differ from the files that already exist in the given directory,
+	/// and removes the files and subdirectories of the directory that were not generated.
+	pub(crate) fn write(&self, out_dir: &std::path::Path) -> std::io::Result<WriteResult> {
+		let mut result = WriteResult {
+			written: 0,
+			unchanged: 0,
+			removed: 0,
+		};
+
+		std::fs::create_dir_all(out_dir)?;
+		self.remove_stale(out_dir, std::path::Path::new(""), &mut result)?;
+
+		for dir in &self.dirs {
+			match std::fs::create_dir(out_dir.join(dir)) {
+				Ok(()) => (),
+				Err(ref err) if err.kind() == std::io::ErrorKind::AlreadyExists => (),
+				Err(err) => return Err(err),
+			}
+		}
+
+		for (path, contents) in &self.files {
+			let path = out_dir.join(path);
+			match std::fs::read(&path) {
+				Ok(existing_contents) if existing_contents == *contents => result.unchanged += 1,
+				Ok(_) => {
+					std::fs::write(path, contents)?;
+					result.written += 1;
+				},
+				Err(ref err) if err.kind() == std::io::ErrorKind::NotFound => {
+					std::fs::write(path, contents)?;
+					result.written += 1;
+				},
+				Err(err) => return Err(err),
+			}
+		}
+
+		Ok(result)
+	}
+
+	fn remove_stale(&self, out_dir: &std::path::Path, dir: &std::path::Path, result: &mut WriteResult) -> std::io::Result<()> {
+		for entry in std::fs::read_dir(out_dir.join(dir))? {
+			let entry = entry?;
+			let path = dir.join(entry.file_name());
+
+			if entry.file_type()?.is_dir() {
+				if self.dirs.contains(&path) {
+					self.remove_stale(out_dir, &path, result)?;
+				}
+				else {
+					std::fs::remove_dir_all(out_dir.join(&path))?;
+					result.removed += 1;
+				}
+			}
+			else if !self.files.contains_key(&path) {
+				std::fs::remove_file(out_dir.join(&path))?;
+				result.removed += 1;
+			}
+		}
+
+		Ok(())
+	}
+}
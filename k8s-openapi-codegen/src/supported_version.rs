@@ -174,6 +174,7 @@ impl SupportedVersion {
 			crate::fixups::special::json_ty::json_schema_props_or_array,
 			crate::fixups::special::json_ty::json_schema_props_or_bool,
 			crate::fixups::special::json_ty::json_schema_props_or_string_array,
+			crate::fixups::special::conversion_review,
 			crate::fixups::special::create_delete_optional,
 			crate::fixups::special::create_optionals,
 			crate::fixups::special::patch,
@@ -184,6 +185,16 @@ impl SupportedVersion {
 			crate::fixups::special::list, // Must run after separate_watch_from_list_operations
 			crate::fixups::special::response_types,
 			crate::fixups::special::resource_metadata_not_optional,
+			crate::fixups::special::scale_subresource,
+			crate::fixups::special::eviction_subresource,
+			crate::fixups::special::string_enums,
+			crate::fixups::special::unions,
+			crate::fixups::special::metrics, // Must run after all other fixups
+			crate::fixups::special::custom_metrics, // Must run after all other fixups
+			crate::fixups::special::kubelet_configuration, // Must run after all other fixups
+			crate::fixups::special::component_configuration, // Must run after all other fixups
+			crate::fixups::special::kubeconfig, // Must run after all other fixups
+			crate::fixups::special::gateway_api, // Must run after all other fixups
 		];
 
 		for fixup in upstream_bugs_fixups.iter().chain(special_fixups) {
@@ -164,6 +164,10 @@ impl super::CustomDerive for CustomResourceDefinition {
 					}),
 					kubernetes_group_kind_versions: vec![],
 					list_kind: None,
+					scale_definition_path: None,
+					eviction_definition_path: None,
+					union: None,
+					patch_strategy: None,
 				},
 			});
 
@@ -177,6 +181,10 @@ impl super::CustomDerive for CustomResourceDefinition {
 					kind: swagger20::SchemaKind::Ty(swagger20::Type::String { format: None }),
 					kubernetes_group_kind_versions: vec![],
 					list_kind: None,
+					scale_definition_path: None,
+					eviction_definition_path: None,
+					union: None,
+					patch_strategy: None,
 				},
 			});
 
@@ -191,6 +199,10 @@ impl super::CustomDerive for CustomResourceDefinition {
 						kind: swagger20::SchemaKind::Ty(swagger20::Type::String { format: None }),
 						kubernetes_group_kind_versions: vec![],
 						list_kind: None,
+						scale_definition_path: None,
+						eviction_definition_path: None,
+						union: None,
+						patch_strategy: None,
 					},
 				})), "/namespaces/{namespace}")
 			}
@@ -212,12 +224,20 @@ impl super::CustomDerive for CustomResourceDefinition {
 							kind: swagger20::SchemaKind::Ty(swagger20::Type::String { format: None }),
 							kubernetes_group_kind_versions: vec![],
 							list_kind: None,
+							scale_definition_path: None,
+							eviction_definition_path: None,
+							union: None,
+							patch_strategy: None,
 						}, false)),
 						(swagger20::PropertyName("kind".to_owned()), (swagger20::Schema {
 							description: Some("Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#types-kinds".to_owned()),
 							kind: swagger20::SchemaKind::Ty(swagger20::Type::String { format: None }),
 							kubernetes_group_kind_versions: vec![],
 							list_kind: None,
+							scale_definition_path: None,
+							eviction_definition_path: None,
+							union: None,
+							patch_strategy: None,
 						}, false)),
 						(swagger20::PropertyName("metadata".to_owned()), (swagger20::Schema {
 							description: Some("Standard object's metadata. More info: https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#metadata".to_owned()),
@@ -227,6 +247,10 @@ impl super::CustomDerive for CustomResourceDefinition {
 							}),
 							kubernetes_group_kind_versions: vec![],
 							list_kind: None,
+							scale_definition_path: None,
+							eviction_definition_path: None,
+							union: None,
+							patch_strategy: None,
 						}, true)),
 						(swagger20::PropertyName("spec".to_owned()), (swagger20::Schema {
 							description: Some(format!("Specification of the {} custom resource", cr_name)),
@@ -236,6 +260,10 @@ impl super::CustomDerive for CustomResourceDefinition {
 							}),
 							kubernetes_group_kind_versions: vec![],
 							list_kind: None,
+							scale_definition_path: None,
+							eviction_definition_path: None,
+							union: None,
+							patch_strategy: None,
 						}, false)),
 					].into_iter().chain(
 						has_subresources.map(|has_subresources|
@@ -244,6 +272,10 @@ impl super::CustomDerive for CustomResourceDefinition {
 								kind: swagger20::SchemaKind::Ty(swagger20::Type::CustomResourceSubresources(has_subresources)),
 								kubernetes_group_kind_versions: vec![],
 								list_kind: None,
+								scale_definition_path: None,
+								eviction_definition_path: None,
+								union: None,
+								patch_strategy: None,
 							}, true)))
 					).collect()),
 					kubernetes_group_kind_versions: vec![
@@ -254,6 +286,10 @@ impl super::CustomDerive for CustomResourceDefinition {
 						},
 					],
 					list_kind: Some(cr_list_name.clone()),
+					scale_definition_path: None,
+					eviction_definition_path: None,
+					union: None,
+					patch_strategy: None,
 				}),
 
 				(swagger20::DefinitionPath(cr_list_name.clone()), swagger20::Schema {
@@ -272,6 +308,10 @@ impl super::CustomDerive for CustomResourceDefinition {
 						},
 					],
 					list_kind: None,
+					scale_definition_path: None,
+					eviction_definition_path: None,
+					union: None,
+					patch_strategy: None,
 				}),
 			].into_iter().collect(),
 			operations: vec![
@@ -319,6 +359,10 @@ impl super::CustomDerive for CustomResourceDefinition {
 								}),
 								kubernetes_group_kind_versions: vec![],
 								list_kind: None,
+								scale_definition_path: None,
+								eviction_definition_path: None,
+								union: None,
+								patch_strategy: None,
 							},
 						})),
 					].into_iter().flatten().collect(),
@@ -351,6 +395,10 @@ impl super::CustomDerive for CustomResourceDefinition {
 								}),
 								kubernetes_group_kind_versions: vec![],
 								list_kind: None,
+								scale_definition_path: None,
+								eviction_definition_path: None,
+								union: None,
+								patch_strategy: None,
 							},
 						})),
 						Some(std::sync::Arc::new(swagger20::Parameter {
@@ -365,6 +413,10 @@ impl super::CustomDerive for CustomResourceDefinition {
 								}),
 								kubernetes_group_kind_versions: vec![],
 								list_kind: None,
+								scale_definition_path: None,
+								eviction_definition_path: None,
+								union: None,
+								patch_strategy: None,
 							},
 						})),
 					].into_iter().flatten().collect(),
@@ -397,6 +449,10 @@ impl super::CustomDerive for CustomResourceDefinition {
 								}),
 								kubernetes_group_kind_versions: vec![],
 								list_kind: None,
+								scale_definition_path: None,
+								eviction_definition_path: None,
+								union: None,
+								patch_strategy: None,
 							},
 						})),
 					].into_iter().flatten().collect(),
@@ -428,6 +484,10 @@ impl super::CustomDerive for CustomResourceDefinition {
 								}),
 								kubernetes_group_kind_versions: vec![],
 								list_kind: None,
+								scale_definition_path: None,
+								eviction_definition_path: None,
+								union: None,
+								patch_strategy: None,
 							},
 						})),
 						Some(name_parameter.clone()),
@@ -444,6 +504,10 @@ impl super::CustomDerive for CustomResourceDefinition {
 								}),
 								kubernetes_group_kind_versions: vec![],
 								list_kind: None,
+								scale_definition_path: None,
+								eviction_definition_path: None,
+								union: None,
+								patch_strategy: None,
 							},
 						})),
 					].into_iter().flatten().collect(),
@@ -475,6 +539,10 @@ impl super::CustomDerive for CustomResourceDefinition {
 								}),
 								kubernetes_group_kind_versions: vec![],
 								list_kind: None,
+								scale_definition_path: None,
+								eviction_definition_path: None,
+								union: None,
+								patch_strategy: None,
 							},
 						})),
 						Some(name_parameter.clone()),
@@ -491,6 +559,10 @@ impl super::CustomDerive for CustomResourceDefinition {
 								}),
 								kubernetes_group_kind_versions: vec![],
 								list_kind: None,
+								scale_definition_path: None,
+								eviction_definition_path: None,
+								union: None,
+								patch_strategy: None,
 							},
 						})),
 					].into_iter().flatten().collect(),
@@ -523,6 +595,10 @@ impl super::CustomDerive for CustomResourceDefinition {
 							}),
 							kubernetes_group_kind_versions: vec![],
 							list_kind: None,
+							scale_definition_path: None,
+							eviction_definition_path: None,
+							union: None,
+							patch_strategy: None,
 						}),
 					].into_iter().collect()),
 					tag: None,
@@ -552,6 +628,10 @@ impl super::CustomDerive for CustomResourceDefinition {
 							}),
 							kubernetes_group_kind_versions: vec![],
 							list_kind: None,
+							scale_definition_path: None,
+							eviction_definition_path: None,
+							union: None,
+							patch_strategy: None,
 						}),
 					].into_iter().collect()),
 					tag: None,
@@ -583,6 +663,10 @@ impl super::CustomDerive for CustomResourceDefinition {
 								}),
 								kubernetes_group_kind_versions: vec![],
 								list_kind: None,
+								scale_definition_path: None,
+								eviction_definition_path: None,
+								union: None,
+								patch_strategy: None,
 							},
 						})),
 					].into_iter().flatten().collect(),
@@ -617,6 +701,10 @@ impl super::CustomDerive for CustomResourceDefinition {
 								}),
 								kubernetes_group_kind_versions: vec![],
 								list_kind: None,
+								scale_definition_path: None,
+								eviction_definition_path: None,
+								union: None,
+								patch_strategy: None,
 							},
 						})),
 					].into_iter().flatten().collect(),
@@ -649,6 +737,10 @@ impl super::CustomDerive for CustomResourceDefinition {
 								}),
 								kubernetes_group_kind_versions: vec![],
 								list_kind: None,
+								scale_definition_path: None,
+								eviction_definition_path: None,
+								union: None,
+								patch_strategy: None,
 							},
 						})),
 					].into_iter().flatten().collect(),
@@ -663,15 +755,27 @@ impl super::CustomDerive for CustomResourceDefinition {
 			writer: vec![],
 		};
 
+		let generate_schema =
+			if generate_schema {
+				k8s_openapi_codegen_common::GenerateSchema::Yes {
+					feature: None,
+					const_type_meta: false,
+					naming: k8s_openapi_codegen_common::SchemaNaming::DefinitionPath,
+				}
+			}
+			else {
+				k8s_openapi_codegen_common::GenerateSchema::No
+			};
+
+		let run_options = k8s_openapi_codegen_common::RunOptions::default().vis(&vis).generate_schema(generate_schema);
+
 		let _ =
 			k8s_openapi_codegen_common::run(
 				&spec.definitions,
 				&mut spec.operations,
 				&swagger20::DefinitionPath(cr_name),
 				&MapNamespace,
-				&vis,
-				if generate_schema { k8s_openapi_codegen_common::GenerateSchema::Yes { feature: None } } else { k8s_openapi_codegen_common::GenerateSchema::No },
-				None,
+				&run_options,
 				&mut run_state,
 			)
 			.map_err(|err| format!("#[derive(CustomResourceDefinition)] failed: {}", err))
@@ -683,9 +787,7 @@ impl super::CustomDerive for CustomResourceDefinition {
 				&mut spec.operations,
 				&swagger20::DefinitionPath(cr_list_name),
 				&MapNamespace,
-				&vis,
-				if generate_schema { k8s_openapi_codegen_common::GenerateSchema::Yes { feature: None } } else { k8s_openapi_codegen_common::GenerateSchema::No },
-				None,
+				&run_options,
 				&mut run_state,
 			)
 			.map_err(|err| format!("#[derive(CustomResourceDefinition)] failed: {}", err))
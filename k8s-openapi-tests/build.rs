@@ -38,4 +38,27 @@ fn main() {
 	else {
 		println!(r#"cargo:rustc-cfg=k8s_apiextensions="v1beta1""#);
 	}
+
+	// Generate the types of a spec with date-time strings as plain strings, for the `date_time_as_string` test.
+
+	struct MapNamespace;
+
+	impl k8s_openapi_codegen_common::MapNamespace for MapNamespace {
+		fn map_namespace<'a>(&self, path_parts: &[&'a str]) -> Option<Vec<&'a str>> {
+			match path_parts {
+				["com", "example", "backups", rest @ ..] => Some(["crate", "date_time_as_string_generated"].iter().copied().chain(rest.iter().copied()).collect()),
+				["io", "k8s", rest @ ..] => Some(std::iter::once("k8s_openapi").chain(rest.iter().copied()).collect()),
+				_ => None,
+			}
+		}
+	}
+
+	let out_dir: std::path::PathBuf = std::env::var_os("OUT_DIR").expect("OUT_DIR not set").into();
+	k8s_openapi_codegen_common::build_script::generate(
+		"build-script-fixtures/date-time-as-string.json".as_ref(),
+		&out_dir.join("date_time_as_string.rs"),
+		&MapNamespace,
+		&[k8s_openapi_codegen_common::fixups::date_time_as_string],
+		&k8s_openapi_codegen_common::DefaultTemplateProvider,
+	).expect("could not generate the types of build-script-fixtures/date-time-as-string.json");
 }
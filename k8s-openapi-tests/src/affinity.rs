@@ -0,0 +1,51 @@
+#[test]
+fn builder() {
+	use k8s_openapi::api::core::v1::{Affinity, NodeSelectorRequirement};
+
+	let affinity =
+		Affinity::builder()
+		.require_node(NodeSelectorRequirement::in_values("kubernetes.io/arch", vec!["amd64", "arm64"]))
+		.require_node(NodeSelectorRequirement::does_not_exist("node.example.com/maintenance"))
+		.prefer_node(50, NodeSelectorRequirement::exists("node.example.com/spot"))
+		.prefer_node(10, NodeSelectorRequirement::greater_than("node.example.com/generation", 3))
+		.prefer_pod_affinity(20, "topology.kubernetes.io/zone", vec![("app", "cache")])
+		.require_pod_anti_affinity("kubernetes.io/hostname", vec![("app", "web")])
+		.prefer_pod_anti_affinity(100, "topology.kubernetes.io/zone", vec![("app", "web"), ("tier", "frontend")])
+		.build();
+
+	assert_eq!(k8s_openapi::serde_json::to_value(&affinity).unwrap(), k8s_openapi::serde_json::json!({
+		"nodeAffinity": {
+			"requiredDuringSchedulingIgnoredDuringExecution": {
+				"nodeSelectorTerms": [{
+					"matchExpressions": [
+						{ "key": "kubernetes.io/arch", "operator": "In", "values": ["amd64", "arm64"] },
+						{ "key": "node.example.com/maintenance", "operator": "DoesNotExist" },
+					],
+				}],
+			},
+			"preferredDuringSchedulingIgnoredDuringExecution": [
+				{ "weight": 50, "preference": { "matchExpressions": [{ "key": "node.example.com/spot", "operator": "Exists" }] } },
+				{ "weight": 10, "preference": { "matchExpressions": [{ "key": "node.example.com/generation", "operator": "Gt", "values": ["3"] }] } },
+			],
+		},
+		"podAffinity": {
+			"preferredDuringSchedulingIgnoredDuringExecution": [{
+				"weight": 20,
+				"podAffinityTerm": { "labelSelector": { "matchLabels": { "app": "cache" } }, "topologyKey": "topology.kubernetes.io/zone" },
+			}],
+		},
+		"podAntiAffinity": {
+			"requiredDuringSchedulingIgnoredDuringExecution": [
+				{ "labelSelector": { "matchLabels": { "app": "web" } }, "topologyKey": "kubernetes.io/hostname" },
+			],
+			"preferredDuringSchedulingIgnoredDuringExecution": [{
+				"weight": 100,
+				"podAffinityTerm": { "labelSelector": { "matchLabels": { "app": "web", "tier": "frontend" } }, "topologyKey": "topology.kubernetes.io/zone" },
+			}],
+		},
+	}));
+
+	assert_eq!(Affinity::builder().build(), Affinity::default());
+	assert_eq!(NodeSelectorRequirement::not_in_values("a", vec!["b"]).operator, "NotIn");
+	assert_eq!(NodeSelectorRequirement::less_than("a", -1).values, Some(vec!["-1".to_owned()]));
+}
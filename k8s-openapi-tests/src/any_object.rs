@@ -0,0 +1,37 @@
+use k8s_openapi::serde_json;
+
+#[test]
+fn serialize() {
+	use k8s_openapi::AnyObject;
+	use k8s_openapi::api::core::v1::{ConfigMap, Pod};
+
+	let mut objects: Vec<Box<dyn AnyObject>> = vec![
+		Box::new(k8s_openapi::fixtures::pod("pod1").build()),
+		Box::new(ConfigMap {
+			metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+				name: Some("config1".to_owned()),
+				..Default::default()
+			},
+			..Default::default()
+		}),
+	];
+
+	for object in &mut objects {
+		object.metadata_mut().namespace = Some("ns1".to_owned());
+	}
+
+	let summary: Vec<_> = objects.iter().map(|object| (object.api_version(), object.kind(), object.metadata().name.as_deref())).collect();
+	assert_eq!(summary, [("v1", "Pod", Some("pod1")), ("v1", "ConfigMap", Some("config1"))]);
+
+	let value = serde_json::to_value(&objects).unwrap();
+	let value = value.as_array().unwrap();
+	assert_eq!(value.len(), 2);
+
+	let pod: Pod = serde_json::from_value(value[0].clone()).unwrap();
+	assert_eq!(pod.metadata.name.as_deref(), Some("pod1"));
+	assert_eq!(pod.metadata.namespace.as_deref(), Some("ns1"));
+
+	let config_map: ConfigMap = serde_json::from_value(value[1].clone()).unwrap();
+	assert_eq!(config_map.metadata.name.as_deref(), Some("config1"));
+	assert_eq!(config_map.metadata.namespace.as_deref(), Some("ns1"));
+}
@@ -0,0 +1,37 @@
+#[test]
+fn apply_configuration() {
+	use k8s_openapi::api::apps::v1::{DeploymentApplyConfiguration, DeploymentSpecApplyConfiguration};
+	use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMetaApplyConfiguration;
+
+	// Only the fields that are set are serialized, even the ones that are required in the full type,
+	// and the apiVersion and kind of the resource are always serialized.
+	let deployment = DeploymentApplyConfiguration {
+		metadata: Some(ObjectMetaApplyConfiguration {
+			name: Some("web".to_owned()),
+			namespace: Some("default".to_owned()),
+			..Default::default()
+		}),
+		spec: Some(DeploymentSpecApplyConfiguration {
+			replicas: Some(3),
+			..Default::default()
+		}),
+		..Default::default()
+	};
+	assert_eq!(k8s_openapi::serde_json::to_value(&deployment).unwrap(), k8s_openapi::serde_json::json!({
+		"apiVersion": "apps/v1",
+		"kind": "Deployment",
+		"metadata": {
+			"name": "web",
+			"namespace": "default",
+		},
+		"spec": {
+			"replicas": 3,
+		},
+	}));
+
+	let deployment = DeploymentApplyConfiguration::default();
+	assert_eq!(k8s_openapi::serde_json::to_value(&deployment).unwrap(), k8s_openapi::serde_json::json!({
+		"apiVersion": "apps/v1",
+		"kind": "Deployment",
+	}));
+}
@@ -0,0 +1,91 @@
+#[test]
+fn apply_and_delete() {
+	k8s_openapi::k8s_if_ge_1_16! {
+		use k8s_openapi::AnyObject;
+		use k8s_openapi::api::admissionregistration::v1::ValidatingWebhookConfiguration;
+		use k8s_openapi::api::apps::v1::Deployment;
+		use k8s_openapi::api::core::v1::{Namespace, Service, ServiceAccount};
+		use k8s_openapi::api::rbac::v1::RoleBinding;
+		use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition;
+
+		#[derive(
+			Clone, Debug, PartialEq,
+			k8s_openapi_derive::CustomResourceDefinition,
+			serde_derive::Deserialize, serde_derive::Serialize,
+		)]
+		#[custom_resource_definition(
+			group = "k8s-openapi-tests-apply-order.com",
+			version = "v1",
+			plural = "foobars",
+			namespaced,
+		)]
+		struct FooBarSpec {}
+
+		// A custom resource with the same kind as a built-in resource, but in a different group
+		mod custom {
+			#[derive(
+				Clone, Debug, PartialEq,
+				k8s_openapi_derive::CustomResourceDefinition,
+				serde_derive::Deserialize, serde_derive::Serialize,
+			)]
+			#[custom_resource_definition(
+				group = "k8s-openapi-tests-apply-order.com",
+				version = "v1",
+				plural = "services",
+				namespaced,
+			)]
+			pub(super) struct ServiceSpec {}
+		}
+
+		fn object<T>(name: &str) -> Box<dyn AnyObject> where T: AnyObject + Default + 'static {
+			let mut object: Box<dyn AnyObject> = Box::new(T::default());
+			object.metadata_mut().name = Some(name.to_owned());
+			object
+		}
+
+		fn names(objects: &[Box<dyn AnyObject>]) -> Vec<&str> {
+			objects.iter().map(|object| object.metadata().name.as_deref().unwrap()).collect()
+		}
+
+		let mut objects = vec![
+			object::<ValidatingWebhookConfiguration>("webhook"),
+			object::<Deployment>("web"),
+			object::<FooBar>("foo-bar"),
+			object::<Service>("service-a"),
+			object::<RoleBinding>("role-binding"),
+			object::<Service>("service-b"),
+			object::<custom::Service>("custom-service"),
+			object::<CustomResourceDefinition>("crd"),
+			object::<ServiceAccount>("service-account"),
+			object::<Namespace>("namespace"),
+		];
+
+		k8s_openapi::apply_order::sort_for_apply(&mut objects);
+		assert_eq!(names(&objects), [
+			"namespace",
+			"crd",
+			"service-account",
+			"role-binding",
+			"service-a",
+			"service-b",
+			"web",
+			"foo-bar",
+			"custom-service",
+			"webhook",
+		]);
+
+		k8s_openapi::apply_order::sort_for_delete(&mut objects);
+		assert_eq!(names(&objects), [
+			"webhook",
+			"foo-bar",
+			"custom-service",
+			"web",
+			"service-a",
+			"service-b",
+			"role-binding",
+			"service-account",
+			"crd",
+			"namespace",
+		]);
+	}
+}
@@ -0,0 +1,63 @@
+use k8s_openapi::{arbitrary, serde_json};
+
+// Values generated from a fixed set of pseudo-random data, so that tests are reproducible.
+pub(crate) fn values<T>() -> impl Iterator<Item = T> where T: for<'a> arbitrary::Arbitrary<'a> {
+	(0_u32..64).map(|seed| {
+		let mut state = seed;
+		let data: Vec<u8> = std::iter::repeat_with(|| {
+			state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+			state.to_be_bytes()[1]
+		}).take(4096).collect();
+		let mut u = arbitrary::Unstructured::new(&data);
+		arbitrary::Arbitrary::arbitrary(&mut u).unwrap()
+	})
+}
+
+// Objects generated from arbitrary data should survive a roundtrip through JSON.
+fn roundtrip<T>()
+where
+	T: for<'a> arbitrary::Arbitrary<'a> + PartialEq + std::fmt::Debug + serde::de::DeserializeOwned + serde::Serialize,
+{
+	for value in values::<T>() {
+		let json = serde_json::to_string(&value).unwrap();
+		let deserialized: T = serde_json::from_str(&json).unwrap();
+		assert_eq!(value, deserialized);
+	}
+}
+
+#[test]
+fn pod() {
+	roundtrip::<k8s_openapi::api::core::v1::Pod>();
+}
+
+#[test]
+fn deployment_list() {
+	roundtrip::<k8s_openapi::List<k8s_openapi::api::apps::v1::Deployment>>();
+}
+
+#[test]
+fn custom_resource_definition() {
+	k8s_openapi::k8s_if_ge_1_16! {
+		roundtrip::<k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition>();
+	}
+}
+
+#[test]
+fn collection_len_is_bounded() {
+	let data = vec![0xff_u8; 4096];
+	let mut u = arbitrary::Unstructured::new(&data);
+
+	let pod_spec: k8s_openapi::api::core::v1::PodSpec = arbitrary::Arbitrary::arbitrary(&mut u).unwrap();
+	assert!(pod_spec.containers.len() <= k8s_openapi::arbitrary2::MAX_COLLECTION_LEN);
+}
+
+#[test]
+fn quantities_are_well_formed() {
+	use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+
+	// Quantities that are not well-formed can't be ordered relative to other quantities.
+	let zero = Quantity("0".to_owned());
+	for quantity in values::<Quantity>() {
+		assert!(quantity.partial_cmp(&zero).is_some(), "{quantity:?} is not well-formed");
+	}
+}
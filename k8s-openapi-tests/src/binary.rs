@@ -0,0 +1,102 @@
+use k8s_openapi::serde_json;
+
+// Values should survive a roundtrip through binary formats that are not self-describing, and still serialize to the same JSON afterwards.
+fn roundtrip<T>(value: &T)
+where
+	T: PartialEq + std::fmt::Debug + serde::de::DeserializeOwned + serde::Serialize,
+{
+	let serialized = bincode::serialize(value).unwrap();
+	let deserialized: T = bincode::deserialize(&serialized).unwrap();
+	assert_eq!(*value, deserialized);
+
+	let serialized = postcard::to_stdvec(value).unwrap();
+	let deserialized: T = postcard::from_bytes(&serialized).unwrap();
+	assert_eq!(*value, deserialized);
+
+	assert_eq!(serde_json::to_value(value).unwrap(), serde_json::to_value(&deserialized).unwrap());
+}
+
+#[test]
+fn fixtures() {
+	let deployment = k8s_openapi::fixtures::deployment("web").replicas(3).available().build();
+	roundtrip(&deployment);
+	roundtrip(&k8s_openapi::fixtures::pod("web-0").owned_by(&deployment).running().build());
+	roundtrip(&k8s_openapi::fixtures::pod("web-1").pending().build());
+}
+
+#[test]
+fn byte_string() {
+	let secret: k8s_openapi::api::core::v1::Secret = serde_json::from_value(serde_json::json!({
+		"apiVersion": "v1",
+		"kind": "Secret",
+		"metadata": { "name": "foo" },
+		"data": { "password": "cGFzc3dvcmQ=" },
+	})).unwrap();
+	roundtrip(&secret);
+
+	// The bytes are not base64-encoded in binary formats.
+	let serialized = bincode::serialize(&secret.data.as_ref().unwrap()["password"]).unwrap();
+	assert_eq!(&serialized[8..], b"password");
+}
+
+#[test]
+fn int_or_string() {
+	let service: k8s_openapi::api::core::v1::Service = serde_json::from_value(serde_json::json!({
+		"apiVersion": "v1",
+		"kind": "Service",
+		"metadata": { "name": "foo" },
+		"spec": {
+			"ports": [
+				{ "port": 80, "targetPort": 8080 },
+				{ "port": 443, "targetPort": "https" },
+			],
+		},
+	})).unwrap();
+	roundtrip(&service);
+}
+
+#[test]
+fn json_value() {
+	k8s_openapi::k8s_if_ge_1_16! {
+		let custom_resource_definition: k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition =
+			serde_json::from_value(serde_json::json!({
+				"apiVersion": "apiextensions.k8s.io/v1",
+				"kind": "CustomResourceDefinition",
+				"metadata": { "name": "foobars.example.com" },
+				"spec": {
+					"group": "example.com",
+					"names": { "kind": "FooBar", "plural": "foobars" },
+					"scope": "Namespaced",
+					"versions": [{
+						"name": "v1",
+						"served": true,
+						"storage": true,
+						"schema": {
+							"openAPIV3Schema": {
+								"type": "object",
+								"additionalProperties": false,
+								"properties": {
+									"replicas": { "type": "integer", "default": 1, "example": { "nested": [1, "two", null] } },
+									"tags": { "type": "array", "items": { "type": "string" } },
+								},
+							},
+						},
+					}],
+				},
+			})).unwrap();
+		roundtrip(&custom_resource_definition);
+	}
+}
+
+#[test]
+fn arbitrary() {
+	for pod in crate::arbitrary::values::<k8s_openapi::api::core::v1::Pod>() {
+		roundtrip(&pod);
+	}
+
+	k8s_openapi::k8s_if_ge_1_16! {
+		for custom_resource_definition in crate::arbitrary::values::<k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition>() {
+			roundtrip(&custom_resource_definition);
+		}
+	}
+}
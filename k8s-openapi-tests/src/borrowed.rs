@@ -0,0 +1,68 @@
+use k8s_openapi::serde_json;
+
+#[test]
+fn same_metadata_as_owned() {
+	let deployment = k8s_openapi::fixtures::deployment("web").replicas(3).available().build();
+	let pods = k8s_openapi::List {
+		items: vec![
+			k8s_openapi::fixtures::pod("web-0").owned_by(&deployment).running().build(),
+			k8s_openapi::fixtures::pod("web-1").pending().build(),
+		],
+		metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ListMeta {
+			continue_: Some("abc".to_owned()),
+			resource_version: Some("123".to_owned()),
+			..Default::default()
+		},
+	};
+	let json = serde_json::to_vec(&pods).unwrap();
+
+	let list: k8s_openapi::borrowed::ListRef<'_, k8s_openapi::borrowed::ObjectRef<'_>> = serde_json::from_slice(&json).unwrap();
+	assert_eq!(list.api_version, "v1");
+	assert_eq!(list.kind, "PodList");
+	assert_eq!(list.metadata.continue_.as_deref(), Some("abc"));
+	assert_eq!(list.metadata.resource_version.as_deref(), Some("123"));
+	assert_eq!(list.items.len(), 2);
+
+	for (item, pod) in list.items.iter().zip(&pods.items) {
+		assert_eq!(item.api_version, "v1");
+		assert_eq!(item.kind, "Pod");
+
+		let metadata = item.metadata.to_object_meta();
+		assert_eq!(metadata.name, pod.metadata.name);
+		assert_eq!(metadata.namespace, pod.metadata.namespace);
+		assert_eq!(metadata.labels, pod.metadata.labels);
+		assert_eq!(metadata.annotations, pod.metadata.annotations);
+		assert_eq!(metadata.uid, pod.metadata.uid);
+
+		assert!(matches!(item.metadata.name, Some(std::borrow::Cow::Borrowed(_))));
+	}
+}
+
+#[test]
+fn escaped_strings_are_owned() {
+	let json = br#"{ "metadata": { "name": "foo", "annotations": { "description": "a \"quoted\" value" } }, "spec": {} }"#;
+
+	let object: k8s_openapi::borrowed::ObjectRef<'_> = serde_json::from_slice(json).unwrap();
+	assert_eq!(object.api_version, "");
+	assert!(matches!(object.metadata.name, Some(std::borrow::Cow::Borrowed("foo"))));
+
+	let description = &object.metadata.annotations.as_ref().unwrap()["description"];
+	assert!(matches!(description, std::borrow::Cow::Owned(_)));
+	assert_eq!(description, r#"a "quoted" value"#);
+
+	// Buffered values like serde_value::Value hand out owned strings, so every string is owned.
+	let value: k8s_openapi::serde_value::Value = serde_json::from_slice(json).unwrap();
+	let object: k8s_openapi::borrowed::ObjectRef<'static> = value.deserialize_into().unwrap();
+	assert!(matches!(object.metadata.name, Some(std::borrow::Cow::Owned(_))));
+	assert_eq!(object.metadata.name.as_deref(), Some("foo"));
+}
+
+#[test]
+fn ignores_strict_unknown_fields() {
+	let json = r#"{ "apiVersion": "v1", "kind": "ConfigMapList", "metadata": {}, "items": [{ "metadata": { "name": "foo" }, "data": { "a": "b" } }] }"#;
+	let k8s_openapi::Strict(list): k8s_openapi::Strict<k8s_openapi::borrowed::ListRef<'_, k8s_openapi::borrowed::ObjectRef<'_>>> = serde_json::from_str(json).unwrap();
+	assert_eq!(list.items[0].metadata.name.as_deref(), Some("foo"));
+
+	let err = serde_json::from_str::<k8s_openapi::borrowed::ListRef<'_, k8s_openapi::borrowed::ObjectRef<'_>>>(r#"{ "metadata": {} }"#).unwrap_err();
+	assert!(err.to_string().contains("missing field `items`"), "{}", err);
+}
@@ -0,0 +1,41 @@
+#[test]
+fn build() {
+	use k8s_openapi::api::core::v1::{Container, ContainerPort, PodSpec, Protocol};
+
+	let pod_spec =
+		PodSpec::builder()
+		.containers(vec![
+			Container::builder()
+			.name("web")
+			.image("nginx:1.21")
+			.ports(vec![ContainerPort::builder().container_port(80).protocol("TCP").build().unwrap()])
+			.build().unwrap(),
+		])
+		.service_account_name("web")
+		.build().unwrap();
+
+	assert_eq!(pod_spec, PodSpec {
+		containers: vec![Container {
+			name: "web".to_owned(),
+			image: Some("nginx:1.21".to_owned()),
+			ports: Some(vec![ContainerPort {
+				container_port: 80,
+				protocol: Some(Protocol::Tcp),
+				..Default::default()
+			}]),
+			..Default::default()
+		}],
+		service_account_name: Some("web".to_owned()),
+		..Default::default()
+	});
+}
+
+#[test]
+fn missing_required_field() {
+	use k8s_openapi::api::core::v1::PodSpec;
+
+	let err = PodSpec::builder().hostname("web-0").build().unwrap_err();
+	assert_eq!(err.type_name(), "PodSpec");
+	assert_eq!(err.field(), "containers");
+	assert_eq!(err.to_string(), "PodSpec.containers is required but was not set");
+}
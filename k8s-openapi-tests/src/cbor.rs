@@ -0,0 +1,91 @@
+use k8s_openapi::{ciborium, serde_json};
+
+// Objects should survive a roundtrip through CBOR, and still serialize to the same JSON afterwards.
+fn roundtrip<T>(value: &T)
+where
+	T: PartialEq + std::fmt::Debug + serde::de::DeserializeOwned + serde::Serialize,
+{
+	let buf = k8s_openapi::cbor::to_vec(value).unwrap();
+	assert!(buf.starts_with(&k8s_openapi::cbor::SELF_DESCRIBED_TAG));
+
+	let decoded: T = k8s_openapi::cbor::from_slice(&buf).unwrap();
+	assert_eq!(*value, decoded);
+
+	assert_eq!(serde_json::to_value(value).unwrap(), serde_json::to_value(&decoded).unwrap());
+}
+
+fn decode_raw(buf: &[u8]) -> ciborium::value::Value {
+	ciborium::de::from_reader(&buf[k8s_openapi::cbor::SELF_DESCRIBED_TAG.len()..]).unwrap()
+}
+
+fn get<'a>(value: &'a ciborium::value::Value, key: &str) -> &'a ciborium::value::Value {
+	value.as_map().unwrap()
+		.iter()
+		.find_map(|(k, v)| (k.as_text() == Some(key)).then_some(v))
+		.unwrap_or_else(|| panic!("{key:?} not found in {value:?}"))
+}
+
+#[test]
+fn fixtures() {
+	let deployment = k8s_openapi::fixtures::deployment("web").replicas(3).available().build();
+	roundtrip(&deployment);
+	roundtrip(&k8s_openapi::fixtures::pod("web-0").owned_by(&deployment).running().build());
+}
+
+#[test]
+fn structure() {
+	// Objects are encoded with the same structure as their JSON, like the API server does,
+	// not the compact form that is used for other formats that serde does not consider human-readable.
+	let service: k8s_openapi::api::core::v1::Service = serde_json::from_value(serde_json::json!({
+		"apiVersion": "v1",
+		"kind": "Service",
+		"metadata": { "name": "foo" },
+		"spec": {
+			"ports": [
+				{ "port": 80, "targetPort": 8080 },
+				{ "port": 443, "targetPort": "https" },
+			],
+		},
+	})).unwrap();
+	roundtrip(&service);
+
+	let value = decode_raw(&k8s_openapi::cbor::to_vec(&service).unwrap());
+	assert_eq!(get(&value, "apiVersion").as_text(), Some("v1"));
+	assert_eq!(get(&value, "kind").as_text(), Some("Service"));
+	assert_eq!(value.as_map().unwrap().len(), 4);
+
+	let ports = get(get(&value, "spec"), "ports").as_array().unwrap();
+	assert_eq!(get(&ports[0], "targetPort").as_integer(), Some(8080.into()));
+	assert_eq!(get(&ports[1], "targetPort").as_text(), Some("https"));
+}
+
+#[test]
+fn byte_string() {
+	let secret: k8s_openapi::api::core::v1::Secret = serde_json::from_value(serde_json::json!({
+		"apiVersion": "v1",
+		"kind": "Secret",
+		"metadata": { "name": "foo" },
+		"data": { "password": "cGFzc3dvcmQ=" },
+	})).unwrap();
+	roundtrip(&secret);
+
+	// The bytes are encoded as a CBOR byte string instead of being base64-encoded.
+	let value = decode_raw(&k8s_openapi::cbor::to_vec(&secret).unwrap());
+	assert_eq!(get(get(&value, "data"), "password").as_bytes().map(AsRef::as_ref), Some(&b"password"[..]));
+
+	// ... but only when encoding with the `cbor` module.
+	assert_eq!(serde_json::to_value(&secret).unwrap()["data"]["password"], "cGFzc3dvcmQ=");
+}
+
+#[test]
+fn arbitrary() {
+	for pod in crate::arbitrary::values::<k8s_openapi::api::core::v1::Pod>() {
+		roundtrip(&pod);
+	}
+
+	k8s_openapi::k8s_if_ge_1_16! {
+		for custom_resource_definition in crate::arbitrary::values::<k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition>() {
+			roundtrip(&custom_resource_definition);
+		}
+	}
+}
@@ -0,0 +1,63 @@
+use k8s_openapi::serde_json;
+
+#[test]
+fn kube_proxy() {
+	use k8s_openapi::kube_proxy::config::v1alpha1 as kube_proxy;
+
+	let kube_proxy_configuration: kube_proxy::KubeProxyConfiguration = serde_json::from_value(serde_json::json!({
+		"apiVersion": "kubeproxy.config.k8s.io/v1alpha1",
+		"kind": "KubeProxyConfiguration",
+		"clientConnection": { "kubeconfig": "/var/lib/kube-proxy/kubeconfig.conf", "qps": 5 },
+		"clusterCIDR": "10.244.0.0/16",
+		"ipvs": { "scheduler": "rr", "strictARP": true },
+		"mode": "ipvs",
+	})).unwrap();
+	assert_eq!(kube_proxy_configuration.mode.as_deref(), Some("ipvs"));
+	assert_eq!(kube_proxy_configuration.ipvs.as_ref().and_then(|ipvs| ipvs.strict_arp), Some(true));
+	assert_eq!(kube_proxy_configuration.client_connection.as_ref().and_then(|client_connection| client_connection.qps), Some(5.));
+}
+
+#[test]
+fn kube_scheduler() {
+	k8s_openapi::k8s_if_ge_1_16! {
+		use k8s_openapi::kube_scheduler::config::v1beta2 as kube_scheduler;
+
+		let kube_scheduler_configuration: kube_scheduler::KubeSchedulerConfiguration = serde_json::from_value(serde_json::json!({
+			"apiVersion": "kubescheduler.config.k8s.io/v1beta2",
+			"kind": "KubeSchedulerConfiguration",
+			"leaderElection": { "leaderElect": true },
+			"profiles": [{
+				"schedulerName": "bin-packing-scheduler",
+				"plugins": {
+					"score": {
+						"disabled": [{ "name": "NodeResourcesBalancedAllocation" }],
+						"enabled": [{ "name": "NodeResourcesFit", "weight": 2 }],
+					},
+				},
+				"pluginConfig": [{
+					"name": "NodeResourcesFit",
+					"args": {
+						"apiVersion": "kubescheduler.config.k8s.io/v1beta2",
+						"kind": "NodeResourcesFitArgs",
+						"scoringStrategy": {
+							"type": "MostAllocated",
+							"resources": [{ "name": "cpu", "weight": 1 }, { "name": "memory", "weight": 1 }],
+						},
+					},
+				}],
+			}],
+		})).unwrap();
+
+		let profile = &kube_scheduler_configuration.profiles.as_ref().unwrap()[0];
+		assert_eq!(profile.scheduler_name.as_deref(), Some("bin-packing-scheduler"));
+
+		let score = profile.plugins.as_ref().unwrap().score.as_ref().unwrap();
+		assert_eq!(score.enabled.as_ref().unwrap()[0].weight, Some(2));
+
+		let plugin_config = &profile.plugin_config.as_ref().unwrap()[0];
+		let args: kube_scheduler::NodeResourcesFitArgs = serde_json::from_value(plugin_config.args.clone().unwrap().0).unwrap();
+		let scoring_strategy = args.scoring_strategy.unwrap();
+		assert_eq!(scoring_strategy.type_.as_deref(), Some("MostAllocated"));
+		assert_eq!(scoring_strategy.resources.unwrap().len(), 2);
+	}
+}
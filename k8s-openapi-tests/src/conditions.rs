@@ -0,0 +1,85 @@
+#[test]
+fn conversions() {
+	k8s_openapi::k8s_if_ge_1_20! {
+		use k8s_openapi::api::core::v1 as api;
+		use k8s_openapi::apimachinery::pkg::apis::meta::v1 as meta;
+		use k8s_openapi::chrono;
+
+		let last_transition_time = meta::Time("2021-09-01T00:00:00Z".parse().unwrap());
+
+		let pod_condition = api::PodCondition {
+			last_probe_time: Some(meta::Time("2021-09-01T00:01:00Z".parse().unwrap())),
+			last_transition_time: Some(last_transition_time),
+			message: None,
+			reason: Some("ContainersNotReady".to_owned()),
+			status: "False".to_owned(),
+			type_: "Ready".to_owned(),
+		};
+		let condition: meta::Condition = pod_condition.into();
+		assert_eq!(condition, meta::Condition {
+			last_transition_time,
+			message: String::new(),
+			observed_generation: None,
+			reason: "ContainersNotReady".to_owned(),
+			status: "False".to_owned(),
+			type_: "Ready".to_owned(),
+		});
+
+		// The last probe time is lost
+		let pod_condition: api::PodCondition = condition.into();
+		assert_eq!(pod_condition, api::PodCondition {
+			last_probe_time: None,
+			last_transition_time: Some(last_transition_time),
+			message: None,
+			reason: Some("ContainersNotReady".to_owned()),
+			status: "False".to_owned(),
+			type_: "Ready".to_owned(),
+		});
+
+		let condition: meta::Condition = api::NodeCondition { status: "True".to_owned(), type_: "Ready".to_owned(), ..Default::default() }.into();
+		assert_eq!(condition.last_transition_time.0, chrono::DateTime::<chrono::Utc>::from(std::time::UNIX_EPOCH));
+	}
+}
+
+#[test]
+fn helpers() {
+	k8s_openapi::k8s_if_ge_1_20! {
+		use k8s_openapi::apimachinery::pkg::apis::meta::v1 as meta;
+		use k8s_openapi::conditions;
+
+		fn condition(type_: &str, status: &str, reason: &str, time: &str) -> meta::Condition {
+			meta::Condition {
+				last_transition_time: meta::Time(time.parse().unwrap()),
+				message: String::new(),
+				observed_generation: Some(1),
+				reason: reason.to_owned(),
+				status: status.to_owned(),
+				type_: type_.to_owned(),
+			}
+		}
+
+		let mut conditions = vec![];
+		assert!(conditions::set(&mut conditions, condition("Ready", "False", "Starting", "2021-09-01T00:00:00Z")));
+		assert!(conditions::set(&mut conditions, condition("Degraded", "False", "AsExpected", "2021-09-01T00:00:00Z")));
+		assert!(conditions::is_false(&conditions, "Ready"));
+		assert!(!conditions::is_true(&conditions, "Ready"));
+		assert!(!conditions::is_true(&conditions, "Progressing"));
+		assert!(!conditions::is_false(&conditions, "Progressing"));
+
+		// Setting the same status again does not change the last transition time
+		assert!(conditions::set(&mut conditions, condition("Ready", "False", "Pulling", "2021-09-01T00:01:00Z")));
+		assert!(!conditions::set(&mut conditions, condition("Ready", "False", "Pulling", "2021-09-01T00:02:00Z")));
+		let ready = conditions::find(&conditions, "Ready").unwrap();
+		assert_eq!(ready.reason, "Pulling");
+		assert_eq!(ready.last_transition_time, meta::Time("2021-09-01T00:00:00Z".parse().unwrap()));
+
+		assert!(conditions::set(&mut conditions, condition("Ready", "True", "Running", "2021-09-01T00:03:00Z")));
+		assert!(conditions::is_true(&conditions, "Ready"));
+		assert!(conditions::is_present_and_equal(&conditions, "Ready", "True"));
+		assert_eq!(conditions::find(&conditions, "Ready").unwrap().last_transition_time, meta::Time("2021-09-01T00:03:00Z".parse().unwrap()));
+
+		assert!(conditions::remove(&mut conditions, "Degraded"));
+		assert!(!conditions::remove(&mut conditions, "Degraded"));
+		assert_eq!(conditions.len(), 1);
+	}
+}
@@ -0,0 +1,25 @@
+use k8s_openapi::conformance;
+
+#[test]
+fn pods() {
+	conformance::assert_dir_roundtrips::<k8s_openapi::api::core::v1::Pod>(concat!(env!("CARGO_MANIFEST_DIR"), "/conformance-fixtures/pods"));
+}
+
+#[test]
+fn errors() {
+	use k8s_openapi::api::core::v1::ConfigMap;
+
+	// Unknown fields are dropped by deserialization, so they are missing from the serialized value.
+	let err = conformance::check_json::<ConfigMap>(r#"{ "apiVersion": "v1", "kind": "ConfigMap", "metadata": { "name": "foo" }, "dta": { "a": "b" } }"#).unwrap_err();
+	match err {
+		conformance::RoundtripError::Mismatch { path, expected, actual } => {
+			assert_eq!(path, ".dta");
+			assert_eq!(expected, Some(k8s_openapi::serde_json::json!({ "a": "b" })));
+			assert_eq!(actual, None);
+		},
+		err => panic!("unexpected error {:?}", err),
+	}
+
+	let err = conformance::check_yaml::<ConfigMap>("apiVersion: v1\nkind: ConfigMap\nmetadata: {}\n---\napiVersion: v1\nkind: ConfigMap\nmetadata: {}\ndata:\n  a: [b]\n").unwrap_err();
+	assert_eq!(err.to_string(), r#"document #2: could not deserialize fixture: invalid type: sequence, expected a string at .data.a"#);
+}
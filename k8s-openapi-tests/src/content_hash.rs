@@ -0,0 +1,72 @@
+#[test]
+fn config_map() {
+	use k8s_openapi::api::core::v1::ConfigMap;
+	use k8s_openapi::ByteString;
+
+	// Ref: https://github.com/kubernetes/kubectl/blob/master/pkg/util/hash/hash_test.go
+	let config_map = ConfigMap {
+		data: Some(Default::default()),
+		..Default::default()
+	};
+	assert_eq!(config_map.content_hash(), "42745tchd9");
+
+	let config_map = ConfigMap {
+		data: Some([("one", ""), ("two", "2"), ("three", "3")].iter().map(|&(key, value)| (key.to_owned(), value.to_owned())).collect()),
+		..Default::default()
+	};
+	assert_eq!(config_map.content_hash(), "f5h7t85m9b");
+
+	let config_map = ConfigMap {
+		binary_data: Some(std::iter::once(("two".to_owned(), ByteString(vec![]))).collect()),
+		data: Some(std::iter::once(("one".to_owned(), String::new())).collect()),
+		..Default::default()
+	};
+	assert_eq!(config_map.content_hash(), "698h7c7t9m");
+
+	k8s_openapi::k8s_if_ge_1_18! {
+		let mut config_map = ConfigMap {
+			data: Some(std::iter::once(("index.html".to_owned(), "<html>\n".to_owned())).collect()),
+			immutable: Some(true),
+			metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+				name: Some("app-config".to_owned()),
+				..Default::default()
+			},
+			..Default::default()
+		};
+		assert_eq!(config_map.content_hash(), "4khdd548kh");
+
+		config_map.append_content_hash_to_name();
+		assert_eq!(config_map.metadata.name.as_deref(), Some("app-config-4khdd548kh"));
+	}
+}
+
+#[test]
+fn secret() {
+	use k8s_openapi::api::core::v1::Secret;
+	use k8s_openapi::ByteString;
+
+	let secret = Secret {
+		data: Some(Default::default()),
+		type_: Some("my-type".to_owned()),
+		..Default::default()
+	};
+	assert_eq!(secret.content_hash(), "t75bgf6ctb");
+
+	assert_eq!(Secret::default().content_hash(), "f8mcc9f84m");
+
+	let mut secret = Secret {
+		data: Some(std::iter::once(("password".to_owned(), ByteString(b"hunter2".to_vec()))).collect()),
+		metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+			name: Some("db".to_owned()),
+			..Default::default()
+		},
+		// Not hashed, since only `data` is
+		string_data: Some(std::iter::once(("username".to_owned(), "admin".to_owned())).collect()),
+		type_: Some("Opaque".to_owned()),
+		..Default::default()
+	};
+	assert_eq!(secret.content_hash(), "t65c87g27b");
+
+	secret.append_content_hash_to_name();
+	assert_eq!(secret.metadata.name.as_deref(), Some("db-t65c87g27b"));
+}
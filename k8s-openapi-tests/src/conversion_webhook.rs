@@ -0,0 +1,111 @@
+use k8s_openapi::serde_json;
+
+#[test]
+fn respond() {
+	k8s_openapi::k8s_if_ge_1_16! {
+		use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1 as apiextensions;
+
+		mod v1 {
+			#[derive(
+				Clone, Debug, PartialEq,
+				k8s_openapi_derive::CustomResourceDefinition,
+				serde_derive::Deserialize, serde_derive::Serialize,
+			)]
+			#[custom_resource_definition(
+				group = "k8s-openapi-tests-conversion-webhook.com",
+				version = "v1",
+				plural = "foobars",
+				namespaced,
+			)]
+			pub(super) struct FooBarSpec {
+				pub(super) prop1: String,
+			}
+		}
+
+		mod v2 {
+			#[derive(
+				Clone, Debug, PartialEq,
+				k8s_openapi_derive::CustomResourceDefinition,
+				serde_derive::Deserialize, serde_derive::Serialize,
+			)]
+			#[custom_resource_definition(
+				group = "k8s-openapi-tests-conversion-webhook.com",
+				version = "v2",
+				plural = "foobars",
+				namespaced,
+			)]
+			pub(super) struct FooBarSpec {
+				pub(super) props: Vec<String>,
+			}
+		}
+
+		let mut conversions =
+			k8s_openapi::conversion_webhook::Conversions::new()
+			.register(|foo_bar: v1::FooBar| -> Result<v2::FooBar, String> {
+				let spec = foo_bar.spec.ok_or("v1 FooBar does not have a spec")?;
+				Ok(v2::FooBar {
+					metadata: foo_bar.metadata,
+					spec: Some(v2::FooBarSpec { props: vec![spec.prop1] }),
+					..Default::default()
+				})
+			});
+
+		let review: apiextensions::ConversionReview = serde_json::from_value(serde_json::json!({
+			"apiVersion": "apiextensions.k8s.io/v1",
+			"kind": "ConversionReview",
+			"request": {
+				"uid": "705ab4f5-6393-11e8-b7cc-42010a800002",
+				"desiredAPIVersion": "k8s-openapi-tests-conversion-webhook.com/v2",
+				"objects": [
+					{
+						"apiVersion": "k8s-openapi-tests-conversion-webhook.com/v1",
+						"kind": "FooBar",
+						"metadata": { "name": "foo" },
+						"spec": { "prop1": "a" },
+					},
+					{
+						"apiVersion": "k8s-openapi-tests-conversion-webhook.com/v2",
+						"kind": "FooBar",
+						"metadata": { "name": "bar" },
+						"spec": { "props": ["b"] },
+					},
+				],
+			},
+		})).unwrap();
+
+		let response_review = review.respond(&mut conversions);
+		assert_eq!(serde_json::to_value(&response_review).unwrap(), serde_json::json!({
+			"apiVersion": "apiextensions.k8s.io/v1",
+			"kind": "ConversionReview",
+			"response": {
+				"uid": "705ab4f5-6393-11e8-b7cc-42010a800002",
+				"convertedObjects": [
+					{
+						"apiVersion": "k8s-openapi-tests-conversion-webhook.com/v2",
+						"kind": "FooBar",
+						"metadata": { "name": "foo" },
+						"spec": { "props": ["a"] },
+					},
+					{
+						"apiVersion": "k8s-openapi-tests-conversion-webhook.com/v2",
+						"kind": "FooBar",
+						"metadata": { "name": "bar" },
+						"spec": { "props": ["b"] },
+					},
+				],
+				"result": { "apiVersion": "v1", "kind": "Status", "metadata": {}, "status": "Success" },
+			},
+		}));
+
+		let mut request = review.request.unwrap();
+		request.desired_api_version = "k8s-openapi-tests-conversion-webhook.com/v1".to_owned();
+		let response = request.respond_with(|object, desired_api_version| conversions.convert(object.0.clone(), desired_api_version).map(k8s_openapi::apimachinery::pkg::runtime::RawExtension));
+		assert!(!response.is_success());
+		assert_eq!(response.uid, "705ab4f5-6393-11e8-b7cc-42010a800002");
+		assert!(response.converted_objects.is_empty());
+		assert_eq!(
+			response.result.message.as_deref(),
+			Some("conversion from k8s-openapi-tests-conversion-webhook.com/v2 to k8s-openapi-tests-conversion-webhook.com/v1 is not supported"),
+		);
+	}
+}
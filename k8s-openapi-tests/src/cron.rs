@@ -0,0 +1,94 @@
+#[test]
+fn next_after() {
+	use k8s_openapi::chrono;
+	use k8s_openapi::CronSchedule;
+
+	fn time(s: &str) -> chrono::DateTime<chrono::Utc> {
+		s.parse().unwrap()
+	}
+
+	for &(schedule, after, expected) in &[
+		("* * * * *", "2021-08-07T12:00:30Z", Some("2021-08-07T12:01:00Z")),
+		("*/15 * * * *", "2021-08-07T12:15:00Z", Some("2021-08-07T12:30:00Z")),
+		("5/20 * * * *", "2021-08-07T12:46:00Z", Some("2021-08-07T13:05:00Z")),
+		("0 9-17/4 * * *", "2021-08-07T13:00:00Z", Some("2021-08-07T17:00:00Z")),
+		("30 9 * * MON-FRI", "2021-08-07T12:00:00Z", Some("2021-08-09T09:30:00Z")),
+		("0 0 1,15 * *", "2021-08-02T00:00:00Z", Some("2021-08-15T00:00:00Z")),
+		("0 0 * dec *", "2021-08-02T00:00:00Z", Some("2021-12-01T00:00:00Z")),
+		// Either the day of the month or the day of the week matches if neither field is `*`
+		("0 0 13 * fri", "2021-08-07T00:00:00Z", Some("2021-08-13T00:00:00Z")),
+		("0 0 13 * fri", "2021-08-13T00:00:00Z", Some("2021-08-20T00:00:00Z")),
+		("0 0 29 2 *", "2021-08-07T00:00:00Z", Some("2024-02-29T00:00:00Z")),
+		("0 0 30 2 *", "2021-08-07T00:00:00Z", None),
+		("@weekly", "2021-08-07T12:00:00Z", Some("2021-08-08T00:00:00Z")),
+		("@yearly", "2021-08-07T12:00:00Z", Some("2022-01-01T00:00:00Z")),
+		("@every 1h30m", "2021-08-07T12:00:00.5Z", Some("2021-08-07T13:30:00Z")),
+		("CRON_TZ=UTC 0 12 * * *", "2021-08-07T12:00:00Z", Some("2021-08-08T12:00:00Z")),
+	] {
+		let schedule: CronSchedule = schedule.parse().unwrap();
+		assert_eq!(schedule.next_after(&time(after)), expected.map(time), "{schedule} after {after}");
+	}
+
+	// A schedule is evaluated in the time zone of the given time
+	let schedule: CronSchedule = "0 12 * * *".parse().unwrap();
+	let after = chrono::DateTime::parse_from_rfc3339("2021-08-07T12:00:00+02:00").unwrap();
+	assert_eq!(schedule.next_after(&after), Some(chrono::DateTime::parse_from_rfc3339("2021-08-08T12:00:00+02:00").unwrap()));
+
+	// ... unless it has a time zone prefix
+	let schedule: CronSchedule = "TZ=Etc/UTC 0 12 * * *".parse().unwrap();
+	assert_eq!(schedule.next_after(&after), Some(chrono::DateTime::parse_from_rfc3339("2021-08-07T14:00:00+02:00").unwrap()));
+}
+
+#[test]
+fn parse_errors() {
+	use k8s_openapi::CronSchedule;
+
+	for &schedule in &[
+		"",
+		"* * * *",
+		"* * * * * *",
+		"60 * * * *",
+		"* 24 * * *",
+		"* * 0 * *",
+		"* * * 13 *",
+		"* * * * 7",
+		"5-1 * * * *",
+		"*/0 * * * *",
+		"* * * foo *",
+		"@fortnightly",
+		"@every forever",
+		"CRON_TZ=Europe/Berlin 0 12 * * *",
+	] {
+		let err = schedule.parse::<CronSchedule>().unwrap_err().to_string();
+		let prefix = format!("invalid cron schedule {schedule:?}: ");
+		assert_eq!(err.get(..prefix.len()), Some(&*prefix));
+	}
+}
+
+#[test]
+fn cron_job() {
+	use k8s_openapi::api::batch::v1beta1 as batch;
+	use k8s_openapi::chrono;
+
+	fn cron_job(suspend: bool) -> batch::CronJob {
+		k8s_openapi::serde_json::from_str(&format!(r#"{{
+			"apiVersion": "batch/v1beta1",
+			"kind": "CronJob",
+			"metadata": {{ "name": "backup" }},
+			"spec": {{
+				"schedule": "0 3 * * *",
+				"suspend": {suspend},
+				"jobTemplate": {{}}
+			}}
+		}}"#)).unwrap()
+	}
+
+	let after: chrono::DateTime<chrono::Utc> = "2021-08-07T12:00:00Z".parse().unwrap();
+
+	let scheduled = cron_job(false);
+	assert_eq!(scheduled.parse_schedule().unwrap().unwrap().to_string(), "0 3 * * *");
+	assert_eq!(scheduled.next_schedule_after(&after), Ok(Some("2021-08-08T03:00:00Z".parse().unwrap())));
+
+	let suspended = cron_job(true);
+	assert_eq!(suspended.next_schedule_after(&after), Ok(None));
+}
@@ -61,6 +61,9 @@ fn test() {
 	assert_eq!(<FooBarList as k8s_openapi::Resource>::KIND, "FooBarList");
 	assert_eq!(<FooBarList as k8s_openapi::Resource>::VERSION, "v1");
 
+	fn assert_has_status_subresource<T>() where T: k8s_openapi::HasStatusSubresource { }
+	assert_has_status_subresource::<FooBar>();
+
 	{
 		let fb: FooBar =
 			serde_json::from_str(r#"{ "metadata": {}, "spec": { "prop1": "foo", "prop2": [true, false], "prop3": 5 } }"#).unwrap();
@@ -163,6 +166,7 @@ fn test() {
 		};
 
 		#[cfg(k8s_apiextensions = "v1beta1")]
+		#[allow(deprecated)] // See the comment at the top of this file for why this uses the deprecated `version` field instead of `versions`.
 		let custom_resource_definition_spec = apiextensions::CustomResourceDefinitionSpec {
 			subresources: Some(apiextensions::CustomResourceSubresources {
 				status: Some(apiextensions::CustomResourceSubresourceStatus(serde_json::Value::Object(Default::default()))),
@@ -0,0 +1,69 @@
+#[test]
+fn rollout_status() {
+	use k8s_openapi::api::apps::v1 as apps;
+	use k8s_openapi::DaemonSetRolloutStatus;
+
+	fn daemon_set(update_strategy: &str, status: &str) -> apps::DaemonSet {
+		k8s_openapi::serde_json::from_str(&format!(r#"{{
+			"apiVersion": "apps/v1",
+			"kind": "DaemonSet",
+			"metadata": {{ "name": "agent", "generation": 2 }},
+			"spec": {{
+				"selector": {{ "matchLabels": {{ "app": "agent" }} }},
+				"template": {{}},
+				"updateStrategy": {{ "type": "{update_strategy}" }}
+			}},
+			"status": {status}
+		}}"#)).unwrap()
+	}
+
+	for &(update_strategy, status, expected, expected_message) in &[
+		(
+			"OnDelete",
+			r#"{ "observedGeneration": 2, "currentNumberScheduled": 3, "desiredNumberScheduled": 3, "numberMisscheduled": 0, "numberReady": 3 }"#,
+			DaemonSetRolloutStatus::NotRollingUpdate,
+			"rollout status is only available for RollingUpdate strategy type",
+		),
+		(
+			"RollingUpdate",
+			r#"{
+				"observedGeneration": 1, "currentNumberScheduled": 3, "desiredNumberScheduled": 3, "numberMisscheduled": 0, "numberReady": 3,
+				"updatedNumberScheduled": 3, "numberAvailable": 3
+			}"#,
+			DaemonSetRolloutStatus::SpecUpdateNotObserved,
+			"Waiting for daemon set spec update to be observed...",
+		),
+		(
+			"RollingUpdate",
+			r#"{
+				"observedGeneration": 2, "currentNumberScheduled": 3, "desiredNumberScheduled": 3, "numberMisscheduled": 0, "numberReady": 3,
+				"updatedNumberScheduled": 1, "numberAvailable": 3
+			}"#,
+			DaemonSetRolloutStatus::UpdatingPods { updated_number_scheduled: 1, desired_number_scheduled: 3 },
+			"Waiting for daemon set rollout to finish: 1 out of 3 new pods have been updated...",
+		),
+		(
+			"RollingUpdate",
+			r#"{
+				"observedGeneration": 2, "currentNumberScheduled": 3, "desiredNumberScheduled": 3, "numberMisscheduled": 0, "numberReady": 2,
+				"updatedNumberScheduled": 3, "numberAvailable": 2
+			}"#,
+			DaemonSetRolloutStatus::WaitingForAvailablePods { number_available: 2, desired_number_scheduled: 3 },
+			"Waiting for daemon set rollout to finish: 2 of 3 updated pods are available...",
+		),
+		(
+			"RollingUpdate",
+			r#"{
+				"observedGeneration": 2, "currentNumberScheduled": 3, "desiredNumberScheduled": 3, "numberMisscheduled": 0, "numberReady": 3,
+				"updatedNumberScheduled": 3, "numberAvailable": 3
+			}"#,
+			DaemonSetRolloutStatus::Complete,
+			"daemon set successfully rolled out",
+		),
+	] {
+		let daemon_set = daemon_set(update_strategy, status);
+		assert_eq!(daemon_set.rollout_status(), expected, "{status}");
+		assert_eq!(daemon_set.rollout_status().to_string(), expected_message);
+		assert_eq!(daemon_set.is_rollout_complete(), expected == DaemonSetRolloutStatus::Complete);
+	}
+}
@@ -0,0 +1,27 @@
+#[test]
+fn strings() {
+	use k8s_openapi::serde_json;
+
+	use crate::date_time_as_string_generated::v1::{BackupStatus, Timestamp};
+
+	let json = serde_json::json!({
+		"completionTime": "2021-08-07T12:00:00.123456789Z",
+		"history": [
+			"2021-08-06T12:00:00Z",
+			"2021-08-05T12:00:00+05:30",
+		],
+		"phase": "Completed",
+	});
+
+	let status: BackupStatus = serde_json::from_value(json.clone()).unwrap();
+
+	// The timestamps are kept as the strings they were received as, without being parsed or normalized.
+	let completion_time: Option<String> = status.completion_time.clone();
+	assert_eq!(completion_time.as_deref(), Some("2021-08-07T12:00:00.123456789Z"));
+	assert_eq!(status.history, Some(vec![
+		Timestamp("2021-08-06T12:00:00Z".to_owned()),
+		Timestamp("2021-08-05T12:00:00+05:30".to_owned()),
+	]));
+
+	assert_eq!(serde_json::to_value(&status).unwrap(), json);
+}
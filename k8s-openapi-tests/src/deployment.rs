@@ -56,3 +56,74 @@ fn list() {
 		assert!(dns_deployment_status.replicas > Some(0));
 	});
 }
+
+#[test]
+fn rollout_status() {
+	use k8s_openapi::api::apps::v1 as apps;
+	use k8s_openapi::DeploymentRolloutStatus;
+
+	fn deployment(generation: i64, status: &str) -> apps::Deployment {
+		k8s_openapi::serde_json::from_str(&format!(r#"{{
+			"apiVersion": "apps/v1",
+			"kind": "Deployment",
+			"metadata": {{ "name": "web", "generation": {generation} }},
+			"spec": {{
+				"replicas": 3,
+				"selector": {{ "matchLabels": {{ "app": "web" }} }},
+				"template": {{}}
+			}},
+			"status": {status}
+		}}"#)).unwrap()
+	}
+
+	for &(generation, status, expected, expected_message) in &[
+		(
+			2,
+			r#"{ "observedGeneration": 1, "replicas": 3, "updatedReplicas": 3, "availableReplicas": 3 }"#,
+			DeploymentRolloutStatus::SpecUpdateNotObserved,
+			"Waiting for deployment spec update to be observed...",
+		),
+		(
+			2,
+			r#"{ "observedGeneration": 2, "replicas": 4, "updatedReplicas": 1, "availableReplicas": 3 }"#,
+			DeploymentRolloutStatus::UpdatingReplicas { updated_replicas: 1, desired_replicas: 3 },
+			"Waiting for deployment rollout to finish: 1 out of 3 new replicas have been updated...",
+		),
+		(
+			2,
+			r#"{ "observedGeneration": 2, "replicas": 4, "updatedReplicas": 3, "availableReplicas": 3 }"#,
+			DeploymentRolloutStatus::TerminatingOldReplicas { old_replicas: 1 },
+			"Waiting for deployment rollout to finish: 1 old replicas are pending termination...",
+		),
+		(
+			2,
+			r#"{ "observedGeneration": 2, "replicas": 3, "updatedReplicas": 3, "availableReplicas": 2 }"#,
+			DeploymentRolloutStatus::WaitingForAvailableReplicas { available_replicas: 2, updated_replicas: 3 },
+			"Waiting for deployment rollout to finish: 2 of 3 updated replicas are available...",
+		),
+		(
+			2,
+			r#"{
+				"observedGeneration": 2, "replicas": 4, "updatedReplicas": 1, "availableReplicas": 3,
+				"conditions": [{ "type": "Progressing", "status": "False", "reason": "ProgressDeadlineExceeded" }]
+			}"#,
+			DeploymentRolloutStatus::ProgressDeadlineExceeded,
+			"deployment exceeded its progress deadline",
+		),
+		(
+			2,
+			r#"{
+				"observedGeneration": 2, "replicas": 3, "updatedReplicas": 3, "availableReplicas": 3,
+				"conditions": [{ "type": "Progressing", "status": "True", "reason": "NewReplicaSetAvailable" }]
+			}"#,
+			DeploymentRolloutStatus::Complete,
+			"deployment successfully rolled out",
+		),
+	] {
+		let deployment = deployment(generation, status);
+		assert_eq!(deployment.rollout_status(), expected, "{status}");
+		assert_eq!(deployment.rollout_status().to_string(), expected_message);
+		assert_eq!(deployment.is_rollout_complete(), expected == DeploymentRolloutStatus::Complete);
+		assert_eq!(deployment.is_progress_deadline_exceeded(), expected == DeploymentRolloutStatus::ProgressDeadlineExceeded);
+	}
+}
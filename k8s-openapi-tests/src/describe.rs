@@ -0,0 +1,125 @@
+use k8s_openapi::describe::Describe;
+use k8s_openapi::serde_json;
+
+#[test]
+fn pod() {
+	let deployment = k8s_openapi::fixtures::deployment("web").build();
+	let pod = k8s_openapi::fixtures::pod("web").owned_by(&deployment).failed().build();
+	assert_eq!(pod.describe().to_string(), "\
+Name:           web
+Namespace:      default
+Node:           node-0/172.18.0.2
+Start Time:     Fri, 01 Jan 2021 00:00:01 +0000
+Labels:         app=web
+Annotations:    <none>
+Status:         Failed
+IP:             10.244.64.105
+Controlled By:  Deployment/web
+Containers:
+  web:
+    Image:          nginx:1.21
+    State:          Terminated
+      Reason:       Error
+      Exit Code:    1
+      Started:      Fri, 01 Jan 2021 00:00:02 +0000
+      Finished:     Fri, 01 Jan 2021 00:00:04 +0000
+    Ready:          False
+    Restart Count:  0
+Conditions:
+  Type             Status
+  ----             ------
+  Initialized      True
+  Ready            False
+  ContainersReady  False
+  PodScheduled     True
+QoS Class:      BestEffort
+");
+}
+
+#[test]
+fn deployment() {
+	let deployment = k8s_openapi::fixtures::deployment("web").build();
+	assert_eq!(deployment.describe().to_string(), "\
+Name:               web
+Namespace:          default
+CreationTimestamp:  Fri, 01 Jan 2021 00:00:00 +0000
+Labels:             app=web
+Annotations:        <none>
+Selector:           app=web
+Replicas:           1 desired | 1 updated | 1 total | 0 available | 1 unavailable
+Pod Template:
+  Labels:       app=web
+  Annotations:  <none>
+  Containers:
+    web:
+      Image:  nginx:1.21
+Conditions:
+  Type         Status  Reason
+  ----         ------  ------
+  Progressing  True    ReplicaSetUpdated
+");
+}
+
+#[test]
+fn service() {
+	let service: k8s_openapi::api::core::v1::Service = serde_json::from_value(serde_json::json!({
+		"apiVersion": "v1",
+		"kind": "Service",
+		"metadata": { "name": "web", "namespace": "default" },
+		"spec": {
+			"type": "NodePort",
+			"clusterIP": "10.96.0.10",
+			"selector": { "app": "web" },
+			"ports": [{ "name": "http", "port": 80, "targetPort": "http", "nodePort": 30080 }],
+			"sessionAffinity": "None",
+		},
+	})).unwrap();
+	assert_eq!(service.describe().to_string(), "\
+Name:              web
+Namespace:         default
+Labels:            <none>
+Annotations:       <none>
+Selector:          app=web
+Type:              NodePort
+IP:                10.96.0.10
+Port:              http  80/TCP
+TargetPort:        http/TCP
+NodePort:          30080/TCP
+Session Affinity:  None
+");
+}
+
+#[test]
+fn node() {
+	let node: k8s_openapi::api::core::v1::Node = serde_json::from_value(serde_json::json!({
+		"apiVersion": "v1",
+		"kind": "Node",
+		"metadata": { "name": "node-0", "labels": { "kubernetes.io/os": "linux", "node-role.kubernetes.io/control-plane": "" } },
+		"spec": { "podCIDR": "10.244.0.0/24", "taints": [{ "key": "node-role.kubernetes.io/master", "effect": "NoSchedule" }] },
+		"status": {
+			"addresses": [{ "type": "InternalIP", "address": "172.18.0.2" }, { "type": "Hostname", "address": "node-0" }],
+			"capacity": { "cpu": "8", "memory": "16Gi" },
+			"conditions": [{ "type": "Ready", "status": "True", "reason": "KubeletReady" }],
+		},
+	})).unwrap();
+	assert_eq!(node.describe().to_string(), "\
+Name:           node-0
+Roles:          control-plane
+Labels:         kubernetes.io/os=linux
+                node-role.kubernetes.io/control-plane=
+Annotations:    <none>
+Taints:         node-role.kubernetes.io/master:NoSchedule
+Unschedulable:  false
+Conditions:
+  Type   Status  Reason
+  ----   ------  ------
+  Ready  True    KubeletReady
+Addresses:
+  InternalIP:  172.18.0.2
+  Hostname:    node-0
+Capacity:
+  cpu:         8
+  memory:      16Gi
+PodCIDR:        10.244.0.0/24
+");
+}
@@ -0,0 +1,47 @@
+use k8s_openapi::serde_json;
+
+#[test]
+fn path() {
+	let manifest = r#"
+apiVersion: v1
+kind: Pod
+metadata:
+  name: foo
+spec:
+  containers:
+  - name: bar
+    resources:
+      limits:
+        cpu:
+          value: 1
+"#;
+	let err =
+		k8s_openapi::deserialize_with_path::<_, k8s_openapi::api::core::v1::Pod>(serde_yaml::Deserializer::from_str(manifest))
+		.unwrap_err();
+	assert_eq!(err.path(), ".spec.containers[0].resources.limits.cpu");
+	assert!(err.to_string().ends_with(" at .spec.containers[0].resources.limits.cpu"), "{}", err);
+
+	let value = serde_json::json!({
+		"apiVersion": "apps/v1",
+		"kind": "Deployment",
+		"metadata": { "name": "foo", "labels": { "app": 5 } },
+	});
+	let err = k8s_openapi::deserialize_with_path::<_, k8s_openapi::api::apps::v1::Deployment>(value).unwrap_err();
+	assert_eq!(err.path(), ".metadata.labels.app");
+
+	let err = k8s_openapi::deserialize_with_path::<_, k8s_openapi::api::apps::v1::Deployment>(serde_json::json!([])).unwrap_err();
+	assert_eq!(err.path(), ".");
+}
+
+#[test]
+fn missing_field() {
+	let err = serde_json::from_value::<k8s_openapi::api::core::v1::QuobyteVolumeSource>(serde_json::json!({
+		"volume": "foo",
+	})).unwrap_err();
+	assert_eq!(err.to_string(), "QuobyteVolumeSource requires `registry` (all required fields: `registry`, `volume`)");
+
+	let err = serde_json::from_value::<k8s_openapi::api::core::v1::Pod>(serde_json::json!({
+		"spec": { "containers": [{ "image": "foo" }] },
+	})).unwrap_err();
+	assert_eq!(err.to_string(), "Container requires `name`");
+}
@@ -0,0 +1,117 @@
+use k8s_openapi::serde::de::DeserializeSeed;
+use k8s_openapi::serde_json;
+
+fn deserialize_in_place<T>(place: &mut T, s: &str) -> Result<(), serde_json::Error> where T: for<'de> k8s_openapi::serde::Deserialize<'de> {
+	let mut deserializer = serde_json::Deserializer::from_str(s);
+	k8s_openapi::DeserializeInPlace(place).deserialize(&mut deserializer)?;
+	deserializer.end()
+}
+
+#[test]
+fn pod() {
+	use k8s_openapi::api::core::v1 as api;
+
+	let mut pod: api::Pod = serde_json::from_str(r#"{
+		"apiVersion": "v1",
+		"kind": "Pod",
+		"metadata": { "name": "foo", "labels": { "app": "foo" } },
+		"spec": {
+			"containers": [
+				{ "name": "first-container", "image": "foo:1", "args": ["--verbose"] },
+				{ "name": "second-container", "image": "bar:1" }
+			]
+		},
+		"status": { "phase": "Running" }
+	}"#).unwrap();
+
+	let containers_ptr = pod.spec.as_ref().unwrap().containers.as_ptr();
+	let container_name_ptr = pod.spec.as_ref().unwrap().containers[0].name.as_ptr();
+
+	let other_pod = r#"{
+		"apiVersion": "v1",
+		"kind": "Pod",
+		"metadata": { "name": "bar" },
+		"spec": {
+			"containers": [
+				{ "name": "container", "image": "baz:1" }
+			]
+		}
+	}"#;
+	deserialize_in_place(&mut pod, other_pod).unwrap();
+
+	let expected: api::Pod = serde_json::from_str(other_pod).unwrap();
+	assert_eq!(pod, expected);
+
+	// The allocations of the existing pod were reused
+	assert_eq!(pod.spec.as_ref().unwrap().containers.as_ptr(), containers_ptr);
+	assert_eq!(pod.spec.as_ref().unwrap().containers[0].name.as_ptr(), container_name_ptr);
+
+	// Missing required fields are errors
+	let err = deserialize_in_place(&mut pod, r#"{ "apiVersion": "v1", "kind": "Pod", "metadata": {}, "spec": { "containers": [{ "image": "foo:1" }] } }"#).unwrap_err();
+	assert!(err.to_string().starts_with("Container requires `name`"), "{}", err);
+
+	// Wrong kinds are errors
+	let err = deserialize_in_place(&mut pod, r#"{ "apiVersion": "v1", "kind": "Service", "metadata": {} }"#).unwrap_err();
+	assert!(err.to_string().starts_with("invalid value: string \"Service\""), "{}", err);
+}
+
+#[test]
+fn list() {
+	use k8s_openapi::api::core::v1 as api;
+
+	let mut list: k8s_openapi::List<api::ConfigMap> = serde_json::from_str(r#"{
+		"apiVersion": "v1",
+		"kind": "ConfigMapList",
+		"metadata": { "resourceVersion": "1" },
+		"items": [
+			{ "apiVersion": "v1", "kind": "ConfigMap", "metadata": { "name": "foo" }, "data": { "a": "b" } },
+			{ "apiVersion": "v1", "kind": "ConfigMap", "metadata": { "name": "bar" } }
+		]
+	}"#).unwrap();
+
+	let other_list = r#"{
+		"apiVersion": "v1",
+		"kind": "ConfigMapList",
+		"metadata": { "resourceVersion": "2" },
+		"items": [
+			{ "apiVersion": "v1", "kind": "ConfigMap", "metadata": { "name": "baz" } },
+			{ "apiVersion": "v1", "kind": "ConfigMap", "metadata": { "name": "qux" }, "data": { "c": "d" } },
+			{ "apiVersion": "v1", "kind": "ConfigMap", "metadata": { "name": "quux" } }
+		]
+	}"#;
+	deserialize_in_place(&mut list, other_list).unwrap();
+
+	let expected: k8s_openapi::List<api::ConfigMap> = serde_json::from_str(other_list).unwrap();
+	assert_eq!(list, expected);
+}
+
+#[test]
+fn watch_event() {
+	use k8s_openapi::api::core::v1 as api;
+	use k8s_openapi::apimachinery::pkg::apis::meta::v1 as meta;
+
+	let events = [
+		r#"{ "type": "ADDED", "object": { "apiVersion": "v1", "kind": "Pod", "metadata": { "name": "foo" }, "spec": { "containers": [] } } }"#,
+		r#"{ "type": "MODIFIED", "object": { "apiVersion": "v1", "kind": "Pod", "metadata": { "name": "foo", "labels": { "app": "foo" } } } }"#,
+		// The object is buffered if it comes before the type
+		r#"{ "object": { "apiVersion": "v1", "kind": "Pod", "metadata": { "name": "bar" } }, "type": "ADDED" }"#,
+		r#"{ "type": "DELETED", "object": { "apiVersion": "v1", "kind": "Pod", "metadata": { "name": "bar" } } }"#,
+		r#"{ "type": "ERROR", "object": { "apiVersion": "v1", "kind": "Status", "metadata": {}, "code": 410, "reason": "Expired" } }"#,
+		r#"{ "type": "ADDED", "object": { "apiVersion": "v1", "kind": "Pod", "metadata": { "name": "baz" } } }"#,
+	];
+
+	let mut event: meta::WatchEvent<api::Pod> = meta::WatchEvent::ErrorOther(Default::default());
+	for s in events {
+		deserialize_in_place(&mut event, s).unwrap();
+
+		let expected: meta::WatchEvent<api::Pod> = serde_json::from_str(s).unwrap();
+		assert_eq!(event, expected);
+	}
+
+	// A type after an object that was deserialized in place is an error
+	let err = deserialize_in_place(
+		&mut event,
+		r#"{ "type": "MODIFIED", "object": { "apiVersion": "v1", "kind": "Pod", "metadata": {} }, "type": "ERROR" }"#,
+	).unwrap_err();
+	assert!(err.to_string().starts_with("duplicate field `type`"), "{}", err);
+}
@@ -0,0 +1,95 @@
+#[test]
+fn display() {
+	use k8s_openapi::api::core::v1::ObjectReference;
+	use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+	use k8s_openapi::apimachinery::pkg::apis::meta::v1::{OwnerReference, Status};
+	use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+
+	assert_eq!(Quantity("100m".to_owned()).to_string(), "100m");
+
+	assert_eq!(IntOrString::Int(8080).to_string(), "8080");
+	assert_eq!(IntOrString::String("25%".to_owned()).to_string(), "25%");
+
+	let status = Status {
+		message: Some(r#"pods "foo" not found"#.to_owned()),
+		reason: Some("NotFound".to_owned()),
+		status: Some("Failure".to_owned()),
+		..Default::default()
+	};
+	assert_eq!(status.to_string(), r#"pods "foo" not found (NotFound)"#);
+	assert_eq!(Status { reason: None, ..status.clone() }.to_string(), r#"pods "foo" not found"#);
+	assert_eq!(Status { message: None, ..status.clone() }.to_string(), "NotFound");
+	assert_eq!(Status { message: None, reason: None, ..status }.to_string(), "Failure");
+
+	let owner_reference = OwnerReference {
+		api_version: "apps/v1".to_owned(),
+		kind: "ReplicaSet".to_owned(),
+		name: "foo-5d4f8b9c7".to_owned(),
+		uid: "8f6b5c2e-0a6d-4c55-9f53-0c5c3a5c8a51".to_owned(),
+		..Default::default()
+	};
+	assert_eq!(owner_reference.to_string(), "ReplicaSet/foo-5d4f8b9c7");
+
+	let object_reference = ObjectReference {
+		kind: Some("Pod".to_owned()),
+		namespace: Some("default".to_owned()),
+		name: Some("foo".to_owned()),
+		..Default::default()
+	};
+	assert_eq!(object_reference.to_string(), "Pod/default/foo");
+	assert_eq!(ObjectReference { namespace: None, ..object_reference }.to_string(), "Pod/foo");
+}
+
+#[test]
+fn resource() {
+	use k8s_openapi::api::core::v1::{Node, Pod};
+	use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+	let pod = Pod {
+		metadata: ObjectMeta {
+			name: Some("coredns-xyz".to_owned()),
+			namespace: Some("kube-system".to_owned()),
+			..Default::default()
+		},
+		..Default::default()
+	};
+	assert_eq!(pod.to_string(), "pods/kube-system/coredns-xyz");
+
+	// Cluster-scoped resources do not have a namespace.
+	let node = Node {
+		metadata: ObjectMeta {
+			name: Some("node-0".to_owned()),
+			..Default::default()
+		},
+		..Default::default()
+	};
+	assert_eq!(node.to_string(), "nodes/node-0");
+}
+
+#[test]
+fn from_str() {
+	use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+	use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+
+	assert_eq!("8080".parse(), Ok(IntOrString::Int(8080)));
+	assert_eq!("http".parse(), Ok(IntOrString::String("http".to_owned())));
+	assert_eq!("25%".parse(), Ok(IntOrString::String("25%".to_owned())));
+	assert_eq!("99999999999".parse(), Ok(IntOrString::String("99999999999".to_owned())));
+
+	assert_eq!("1Gi".parse(), Ok(Quantity("1Gi".to_owned())));
+}
+
+#[test]
+fn from() {
+	use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+	use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+
+	assert_eq!(IntOrString::from(8080), IntOrString::Int(8080));
+	assert_eq!(IntOrString::from("http"), IntOrString::String("http".to_owned()));
+	assert_eq!(IntOrString::from("8080"), IntOrString::String("8080".to_owned()));
+	assert_eq!(IntOrString::from("25%".to_owned()), IntOrString::String("25%".to_owned()));
+
+	assert_eq!(Quantity::from("250m"), Quantity("250m".to_owned()));
+	assert_eq!(Quantity::from("1Gi".to_owned()), Quantity("1Gi".to_owned()));
+	assert_eq!(Quantity::from(3), Quantity("3".to_owned()));
+}
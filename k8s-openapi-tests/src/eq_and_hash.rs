@@ -0,0 +1,26 @@
+#[test]
+fn hash_set_keys() {
+	use k8s_openapi::api::core::v1::{ObjectReference, ResourceRequirements, Toleration};
+	use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+
+	let references: std::collections::HashSet<_> = vec![
+		ObjectReference { kind: Some("Pod".to_owned()), name: Some("web-0".to_owned()), ..Default::default() },
+		ObjectReference { kind: Some("Pod".to_owned()), name: Some("web-1".to_owned()), ..Default::default() },
+		ObjectReference { kind: Some("Pod".to_owned()), name: Some("web-0".to_owned()), ..Default::default() },
+	].into_iter().collect();
+	assert_eq!(references.len(), 2);
+
+	let tolerations: std::collections::HashSet<_> = vec![
+		Toleration { key: Some("dedicated".to_owned()), operator: Some("Exists".into()), ..Default::default() },
+		Toleration { key: Some("dedicated".to_owned()), operator: Some("Exists".into()), ..Default::default() },
+	].into_iter().collect();
+	assert_eq!(tolerations.len(), 1);
+
+	// Quantity has its own Eq and Hash impls, and the types that contain it derive theirs from them.
+	let requirements = |cpu: &str| ResourceRequirements {
+		requests: Some(std::iter::once(("cpu".to_owned(), Quantity(cpu.to_owned()))).collect()),
+		..Default::default()
+	};
+	let requirements: std::collections::HashSet<_> = vec![requirements("500m"), requirements("500m"), requirements("1")].into_iter().collect();
+	assert_eq!(requirements.len(), 2);
+}
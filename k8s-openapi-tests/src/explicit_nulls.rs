@@ -0,0 +1,76 @@
+use k8s_openapi::serde_json;
+
+#[test]
+fn explicit_nulls() {
+	use k8s_openapi::api::core::v1 as api;
+	use k8s_openapi::apimachinery::pkg::apis::meta::v1 as meta;
+
+	let pod = api::Pod {
+		metadata: meta::ObjectMeta {
+			annotations: Some(std::iter::once(("foo".to_owned(), "bar".to_owned())).collect()),
+			name: Some("foo".to_owned()),
+			..Default::default()
+		},
+		status: Some(api::PodStatus {
+			conditions: Some(vec![
+				api::PodCondition {
+					last_transition_time: Some(meta::Time("2021-01-01T00:00:00Z".parse().unwrap())),
+					status: "True".to_owned(),
+					type_: "Ready".to_owned(),
+					..Default::default()
+				},
+			]),
+			..Default::default()
+		}),
+		..Default::default()
+	};
+
+	assert_eq!(
+		serde_json::to_string(&k8s_openapi::WithExplicitNulls(&pod)).unwrap(),
+		concat!(
+			r#"{"apiVersion":"v1","kind":"Pod","#,
+			r#""metadata":{"annotations":{"foo":"bar"},"creationTimestamp":null,"name":"foo"},"#,
+			r#""status":{"conditions":[{"lastProbeTime":null,"lastTransitionTime":"2021-01-01T00:00:00Z","status":"True","type":"Ready"}]}}"#,
+		),
+	);
+
+	// Fields that are set are not duplicated
+	let config_map = api::ConfigMap {
+		metadata: meta::ObjectMeta {
+			creation_timestamp: Some(meta::Time("2021-01-01T00:00:00Z".parse().unwrap())),
+			..Default::default()
+		},
+		..Default::default()
+	};
+	assert_eq!(
+		serde_json::to_string(&k8s_openapi::WithExplicitNulls(&config_map)).unwrap(),
+		r#"{"apiVersion":"v1","kind":"ConfigMap","metadata":{"creationTimestamp":"2021-01-01T00:00:00Z"}}"#,
+	);
+
+	// Null fields that sort after all the other fields are added at the end
+	let config_map = api::ConfigMap::default();
+	assert_eq!(
+		serde_json::to_string(&k8s_openapi::WithExplicitNulls(&config_map)).unwrap(),
+		r#"{"apiVersion":"v1","kind":"ConfigMap","metadata":{"creationTimestamp":null}}"#,
+	);
+}
+
+#[test]
+fn with_omit_empty_collections() {
+	use k8s_openapi::api::core::v1 as api;
+	use k8s_openapi::apimachinery::pkg::apis::meta::v1 as meta;
+
+	let config_map = api::ConfigMap {
+		metadata: meta::ObjectMeta {
+			finalizers: Some(vec![]),
+			name: Some("foo".to_owned()),
+			..Default::default()
+		},
+		..Default::default()
+	};
+
+	assert_eq!(
+		serde_json::to_string(&k8s_openapi::WithExplicitNulls(k8s_openapi::OmitEmptyCollections(&config_map))).unwrap(),
+		r#"{"apiVersion":"v1","kind":"ConfigMap","metadata":{"creationTimestamp":null,"name":"foo"}}"#,
+	);
+}
@@ -0,0 +1,18 @@
+#[test]
+fn all() {
+	use k8s_openapi::field_selectors::{namespace, pod, METADATA_NAME, METADATA_NAMESPACE};
+
+	assert_eq!(pod::ALL, &[
+		METADATA_NAME,
+		METADATA_NAMESPACE,
+		pod::SPEC_NODE_NAME,
+		pod::SPEC_RESTART_POLICY,
+		pod::SPEC_SCHEDULER_NAME,
+		pod::SPEC_SERVICE_ACCOUNT_NAME,
+		pod::STATUS_PHASE,
+		pod::STATUS_POD_IP,
+	]);
+
+	// Cluster-scoped resource types can't be selected by namespace.
+	assert_eq!(namespace::ALL, &["metadata.name", "status.phase"]);
+}
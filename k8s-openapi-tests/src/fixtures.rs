@@ -0,0 +1,38 @@
+use k8s_openapi::fixtures;
+
+#[test]
+fn running_pod_owned_by_deployment() {
+	let deployment = fixtures::deployment("web").replicas(3).available().build();
+	let pod = fixtures::pod("web-0").owned_by(&deployment).running().build();
+
+	assert_eq!(deployment.metadata.uid.as_deref(), Some(&*fixtures::uid("Deployment", "default", "web")));
+	assert_eq!(deployment.status.as_ref().unwrap().available_replicas, Some(3));
+
+	let owner_reference = &pod.metadata.owner_references.as_ref().unwrap()[0];
+	assert_eq!(owner_reference.kind, "Deployment");
+	assert_eq!(Some(&owner_reference.uid), deployment.metadata.uid.as_ref());
+	assert_eq!(owner_reference.controller, Some(true));
+
+	let spec = pod.spec.unwrap();
+	assert_eq!(spec.node_name.as_deref(), Some(fixtures::DEFAULT_NODE));
+
+	let status = pod.status.unwrap();
+	assert_eq!(status.phase.as_deref(), Some("Running"));
+	assert!(status.conditions.unwrap().iter().any(|condition| condition.type_ == "Ready" && condition.status == "True"));
+	assert!(status.container_statuses.unwrap()[0].ready);
+}
+
+#[test]
+fn deterministic() {
+	let pod = fixtures::pod("worker").namespace("jobs").label("tier", "batch").succeeded().build();
+	assert_eq!(pod, fixtures::pod("worker").namespace("jobs").label("tier", "batch").succeeded().build());
+	assert_ne!(pod.metadata.uid, fixtures::pod("worker").build().metadata.uid);
+
+	// Every phase transition is a new resource version.
+	assert_eq!(fixtures::pod("worker").build().metadata.resource_version.as_deref(), Some("1"));
+	assert_eq!(pod.metadata.resource_version.as_deref(), Some("2"));
+	assert_eq!(fixtures::pod("worker").pending().running().build().metadata.resource_version.as_deref(), Some("3"));
+
+	let terminated = pod.status.unwrap().container_statuses.unwrap().remove(0).state.unwrap().terminated.unwrap();
+	assert_eq!(terminated.exit_code, 0);
+}
@@ -0,0 +1,55 @@
+use k8s_openapi::serde_json;
+
+#[test]
+fn http_route() {
+	k8s_openapi::k8s_if_ge_1_20! {
+		use k8s_openapi::gateway_api::apis::v1alpha2 as gateway_api;
+
+		let http_route: gateway_api::HTTPRoute = serde_json::from_value(serde_json::json!({
+			"apiVersion": "gateway.networking.k8s.io/v1alpha2",
+			"kind": "HTTPRoute",
+			"metadata": { "name": "bar-route", "namespace": "default" },
+			"spec": {
+				"parentRefs": [{ "name": "example-gateway" }],
+				"hostnames": ["bar.example.com"],
+				"rules": [{
+					"matches": [{ "headers": [{ "type": "Exact", "name": "env", "value": "canary" }] }],
+					"backendRefs": [{ "name": "bar-svc-canary", "port": 8080 }],
+				}],
+			},
+			"status": {
+				"parents": [{
+					"parentRef": { "name": "example-gateway" },
+					"controllerName": "example.net/gateway-controller",
+					"conditions": [{
+						"type": "Accepted",
+						"status": "True",
+						"lastTransitionTime": "2021-12-01T00:00:00Z",
+						"reason": "Accepted",
+						"message": "",
+					}],
+				}],
+			},
+		})).unwrap();
+
+		assert_eq!(<gateway_api::HTTPRoute as k8s_openapi::Resource>::API_VERSION, "gateway.networking.k8s.io/v1alpha2");
+		assert_eq!(<gateway_api::HTTPRoute as k8s_openapi::ListableResource>::LIST_KIND, "HTTPRouteList");
+
+		let rule = &http_route.spec.rules.as_ref().unwrap()[0];
+		assert_eq!(rule.backend_refs.as_ref().unwrap()[0].port, Some(8080));
+
+		let parent = &http_route.status.as_ref().unwrap().parents[0];
+		assert_eq!(parent.parent_ref.name, "example-gateway");
+		assert_eq!(parent.conditions.as_ref().unwrap()[0].type_, "Accepted");
+
+		let (request, _) =
+			gateway_api::HTTPRoute::read_namespaced_http_route("bar-route", "default", Default::default())
+			.expect("couldn't read HTTPRoute");
+		assert_eq!(request.uri(), "/apis/gateway.networking.k8s.io/v1alpha2/namespaces/default/httproutes/bar-route?");
+
+		let (request, _) =
+			gateway_api::GatewayClass::list_gateway_class(Default::default())
+			.expect("couldn't list GatewayClasses");
+		assert_eq!(request.uri(), "/apis/gateway.networking.k8s.io/v1alpha2/gatewayclasses?");
+	}
+}
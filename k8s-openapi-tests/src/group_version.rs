@@ -0,0 +1,25 @@
+use k8s_openapi::serde_json;
+
+#[test]
+fn group_version() {
+	use k8s_openapi::GroupVersion;
+
+	let group_version: GroupVersion = "apps/v1".parse().unwrap();
+	assert_eq!(group_version, GroupVersion { group: "apps".to_owned(), version: "v1".to_owned() });
+	assert_eq!(group_version, GroupVersion::of::<k8s_openapi::api::apps::v1::Deployment>());
+	assert_eq!(group_version.to_string(), "apps/v1");
+
+	let group_version: GroupVersion = "v1".parse().unwrap();
+	assert_eq!(group_version, GroupVersion::of::<k8s_openapi::api::core::v1::Pod>());
+	assert_eq!(group_version.to_string(), "v1");
+
+	for s in &["", "apps/", "/v1", "apps/v1/foo"] {
+		let err = s.parse::<GroupVersion>().unwrap_err();
+		assert_eq!(err.to_string(), format!("{:?} is not of the form `group/version` or `version`", s));
+	}
+
+	let group_version: GroupVersion = serde_json::from_value(serde_json::json!("batch/v1")).unwrap();
+	assert_eq!(group_version.to_string(), "batch/v1");
+	assert_eq!(serde_json::to_value(&group_version).unwrap(), serde_json::json!("batch/v1"));
+	assert!(serde_json::from_value::<GroupVersion>(serde_json::json!("batch/")).is_err());
+}
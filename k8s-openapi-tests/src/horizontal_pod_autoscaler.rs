@@ -0,0 +1,103 @@
+#[test]
+fn metric_spec() {
+	k8s_openapi::k8s_if_ge_1_12! {
+		use k8s_openapi::api::autoscaling::v2beta2 as autoscaling;
+		use k8s_openapi::apimachinery::pkg::apis::meta::v1 as meta;
+
+		let service = k8s_openapi::api::core::v1::Service {
+			metadata: meta::ObjectMeta { name: Some("web".to_owned()), ..Default::default() },
+			..Default::default()
+		};
+
+		let metrics = vec![
+			autoscaling::MetricSpec::resource_utilization("cpu", 80),
+			autoscaling::MetricSpec::resource("memory", autoscaling::MetricTarget::average_value("500Mi")),
+			autoscaling::MetricSpec::pods("packets-per-second", 1000),
+			autoscaling::MetricSpec::object(
+				autoscaling::CrossVersionObjectReference::to(&service),
+				"requests-per-second",
+				autoscaling::MetricTarget::value("10k"),
+			),
+			autoscaling::MetricSpec::external("queue_messages_ready", autoscaling::MetricTarget::average_value(30))
+				.with_metric_selector(meta::LabelSelector {
+					match_labels: Some(std::iter::once(("queue".to_owned(), "worker_tasks".to_owned())).collect()),
+					..Default::default()
+				}),
+		];
+
+		assert_eq!(k8s_openapi::serde_json::to_value(&metrics).unwrap(), k8s_openapi::serde_json::json!([
+			{
+				"type": "Resource",
+				"resource": { "name": "cpu", "target": { "type": "Utilization", "averageUtilization": 80 } },
+			},
+			{
+				"type": "Resource",
+				"resource": { "name": "memory", "target": { "type": "AverageValue", "averageValue": "500Mi" } },
+			},
+			{
+				"type": "Pods",
+				"pods": { "metric": { "name": "packets-per-second" }, "target": { "type": "AverageValue", "averageValue": "1000" } },
+			},
+			{
+				"type": "Object",
+				"object": {
+					"describedObject": { "apiVersion": "v1", "kind": "Service", "name": "web" },
+					"metric": { "name": "requests-per-second" },
+					"target": { "type": "Value", "value": "10k" },
+				},
+			},
+			{
+				"type": "External",
+				"external": {
+					"metric": { "name": "queue_messages_ready", "selector": { "matchLabels": { "queue": "worker_tasks" } } },
+					"target": { "type": "AverageValue", "averageValue": "30" },
+				},
+			},
+		]));
+
+		// Resource metrics do not have a selector
+		assert_eq!(
+			autoscaling::MetricSpec::resource_utilization("cpu", 80).with_metric_selector(Default::default()),
+			autoscaling::MetricSpec::resource_utilization("cpu", 80),
+		);
+
+		k8s_openapi::k8s_if_ge_1_20! {
+			let metric = autoscaling::MetricSpec::container_resource_utilization("cpu", "application", 60);
+			assert_eq!(k8s_openapi::serde_json::to_value(&metric).unwrap(), k8s_openapi::serde_json::json!({
+				"type": "ContainerResource",
+				"containerResource": { "name": "cpu", "container": "application", "target": { "type": "Utilization", "averageUtilization": 60 } },
+			}));
+		}
+	}
+}
+
+#[test]
+fn behavior() {
+	k8s_openapi::k8s_if_ge_1_18! {
+		use k8s_openapi::api::autoscaling::v2beta2 as autoscaling;
+
+		let behavior = autoscaling::HorizontalPodAutoscalerBehavior {
+			scale_down: Some(
+				autoscaling::HPAScalingRules::select_max(vec![autoscaling::HPAScalingPolicy::percent(10, 60), autoscaling::HPAScalingPolicy::pods(2, 60)])
+				.stabilization_window(300),
+			),
+			scale_up: Some(autoscaling::HPAScalingRules::disabled()),
+		};
+
+		assert_eq!(k8s_openapi::serde_json::to_value(&behavior).unwrap(), k8s_openapi::serde_json::json!({
+			"scaleDown": {
+				"policies": [
+					{ "type": "Percent", "value": 10, "periodSeconds": 60 },
+					{ "type": "Pods", "value": 2, "periodSeconds": 60 },
+				],
+				"selectPolicy": "Max",
+				"stabilizationWindowSeconds": 300,
+			},
+			"scaleUp": { "selectPolicy": "Disabled" },
+		}));
+
+		let scale_up = autoscaling::HPAScalingRules::select_min(vec![autoscaling::HPAScalingPolicy::pods(4, 15)]);
+		assert_eq!(scale_up.select_policy.as_deref(), Some("Min"));
+		assert_eq!(scale_up.stabilization_window_seconds, None);
+	}
+}
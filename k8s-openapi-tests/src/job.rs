@@ -28,7 +28,7 @@ fn create() {
 							..Default::default()
 						},
 					],
-					restart_policy: Some("Never".to_string()),
+					restart_policy: Some(api::RestartPolicy::Never),
 					..Default::default()
 				}),
 				..Default::default()
@@ -160,3 +160,39 @@ fn create() {
 		}
 	});
 }
+
+#[test]
+fn status() {
+	use k8s_openapi::api::batch::v1 as batch;
+
+	fn job(status: &str) -> batch::Job {
+		k8s_openapi::serde_json::from_str(&format!(r#"{{
+			"apiVersion": "batch/v1",
+			"kind": "Job",
+			"metadata": {{ "name": "migrate" }},
+			"status": {status}
+		}}"#)).unwrap()
+	}
+
+	let running = job(r#"{ "active": 2, "succeeded": 1 }"#);
+	assert!(running.condition("Complete").is_none());
+	assert!(!running.is_complete());
+	assert!(!running.is_failed());
+	assert!(!running.is_finished());
+	assert_eq!((running.active_pods(), running.succeeded_pods(), running.failed_pods()), (2, 1, 0));
+
+	let complete = job(r#"{ "succeeded": 3, "conditions": [{ "type": "Complete", "status": "True" }] }"#);
+	assert!(complete.is_complete());
+	assert!(!complete.is_failed());
+	assert!(complete.is_finished());
+	assert_eq!((complete.active_pods(), complete.succeeded_pods(), complete.failed_pods()), (0, 3, 0));
+
+	let failed = job(r#"{ "failed": 7, "conditions": [{ "type": "Failed", "status": "True", "reason": "BackoffLimitExceeded" }] }"#);
+	assert!(!failed.is_complete());
+	assert!(failed.is_failed());
+	assert!(failed.is_finished());
+	assert_eq!(failed.condition("Failed").and_then(|condition| condition.reason.as_deref()), Some("BackoffLimitExceeded"));
+	assert_eq!((failed.active_pods(), failed.succeeded_pods(), failed.failed_pods()), (0, 0, 7));
+
+	assert_eq!(job("null").active_pods(), 0);
+}
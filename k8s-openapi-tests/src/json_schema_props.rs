@@ -0,0 +1,80 @@
+#[test]
+fn prune() {
+	k8s_openapi::k8s_if_ge_1_16! {
+		use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1 as apiextensions;
+		use k8s_openapi::serde_json::json;
+
+		let string = apiextensions::JSONSchemaProps {
+			type_: Some("string".to_owned()),
+			..Default::default()
+		};
+
+		let schema = apiextensions::JSONSchemaProps {
+			type_: Some("object".to_owned()),
+			properties: Some(std::array::IntoIter::new([
+				("spec".to_owned(), apiextensions::JSONSchemaProps {
+					type_: Some("object".to_owned()),
+					properties: Some(std::array::IntoIter::new([
+						("image".to_owned(), string.clone()),
+						("values".to_owned(), apiextensions::JSONSchemaProps::preserve_unknown_fields()),
+						("ports".to_owned(), apiextensions::JSONSchemaProps {
+							type_: Some("array".to_owned()),
+							items: Some(apiextensions::JSONSchemaPropsOrArray::Schema(Box::new(apiextensions::JSONSchemaProps {
+								type_: Some("object".to_owned()),
+								properties: Some(std::array::IntoIter::new([("name".to_owned(), string.clone())]).collect()),
+								..Default::default()
+							}))),
+							..Default::default()
+						}),
+						("labels".to_owned(), apiextensions::JSONSchemaProps {
+							type_: Some("object".to_owned()),
+							additional_properties: Some(apiextensions::JSONSchemaPropsOrBool::Schema(Box::new(string))),
+							..Default::default()
+						}),
+					]).collect()),
+					..Default::default()
+				}),
+			]).collect()),
+			..Default::default()
+		};
+		assert!(!schema.preserves_unknown_fields());
+
+		let mut value = json!({
+			"apiVersion": "example.com/v1",
+			"kind": "App",
+			"metadata": { "name": "app" },
+			"spec": {
+				"image": "app:1.0",
+				"imag": "app:1.0",
+				"values": { "replicas": 3, "nested": { "key": "value" } },
+				"ports": [{ "name": "http", "port": 80 }],
+				"labels": { "app": "app" },
+			},
+			"status": {},
+		});
+
+		assert_eq!(schema.unknown_fields(&value), [".spec.imag", ".spec.ports[0].port", ".status"]);
+
+		let pruned = schema.prune(&mut value);
+		assert_eq!(pruned, [".spec.imag", ".spec.ports[0].port", ".status"]);
+		assert_eq!(value, json!({
+			"apiVersion": "example.com/v1",
+			"kind": "App",
+			"metadata": { "name": "app" },
+			"spec": {
+				"image": "app:1.0",
+				"values": { "replicas": 3, "nested": { "key": "value" } },
+				"ports": [{ "name": "http" }],
+				"labels": { "app": "app" },
+			},
+		}));
+	}
+}
+
+#[test]
+fn raw_extension_schema_preserves_unknown_fields() {
+	use k8s_openapi::{schemars, serde_json};
+
+	let schema = serde_json::to_value(schemars::schema_for!(k8s_openapi::apimachinery::pkg::runtime::RawExtension)).expect("couldn't serialize schema");
+	assert_eq!(schema["x-kubernetes-preserve-unknown-fields"], true);
+}
@@ -0,0 +1,86 @@
+#[test]
+fn builtin() {
+	use k8s_openapi::api::core::v1::ConfigMap;
+
+	// As output by `kubectl get deployments,services,configmaps -o json`
+	let list = k8s_openapi::serde_json::json!({
+		"apiVersion": "v1",
+		"kind": "List",
+		"items": [
+			{ "apiVersion": "apps/v1", "kind": "Deployment", "metadata": { "name": "web", "namespace": "default" } },
+			{ "apiVersion": "v1", "kind": "Service", "metadata": { "name": "web", "namespace": "default" } },
+			{ "apiVersion": "v1", "kind": "ConfigMap", "metadata": { "name": "web-config", "namespace": "default" }, "data": { "port": "8080" } },
+		],
+		"metadata": { "resourceVersion": "" },
+	});
+
+	let registry = k8s_openapi::KindRegistry::builtin();
+	let objects = registry.deserialize_list(list).unwrap();
+	let kinds: Vec<_> = objects.iter().map(|object| (object.api_version(), object.kind(), object.metadata().name.as_deref())).collect();
+	assert_eq!(kinds, [
+		("apps/v1", "Deployment", Some("web")),
+		("v1", "Service", Some("web")),
+		("v1", "ConfigMap", Some("web-config")),
+	]);
+
+	// The objects serialize as their original types
+	let config_map: ConfigMap = k8s_openapi::serde_json::from_value(k8s_openapi::serde_json::to_value(&objects[2]).unwrap()).unwrap();
+	assert_eq!(config_map.data.unwrap()["port"], "8080");
+
+	// Lists themselves are not objects
+	assert!(registry.contains("v1", "Pod"));
+	assert!(!registry.contains("v1", "PodList"));
+
+	let objects = registry.deserialize_list(k8s_openapi::serde_json::json!({ "apiVersion": "v1", "kind": "List", "items": [] })).unwrap();
+	assert!(objects.is_empty());
+}
+
+#[test]
+fn register() {
+	use k8s_openapi::api::core::v1::Pod;
+
+	let mut registry = k8s_openapi::KindRegistry::new();
+	assert!(!registry.contains("v1", "Pod"));
+
+	registry.register::<Pod>();
+	assert!(registry.contains("v1", "Pod"));
+
+	let object = registry.deserialize_object(k8s_openapi::serde_json::json!({ "apiVersion": "v1", "kind": "Pod", "metadata": { "name": "web-0" } })).unwrap();
+	assert_eq!(object.kind(), "Pod");
+	assert_eq!(object.metadata().name.as_deref(), Some("web-0"));
+}
+
+#[test]
+fn errors() {
+	fn error<T>(result: Result<T, k8s_openapi::serde_json::Error>) -> String {
+		match result {
+			Ok(_) => panic!("expected deserialization to fail"),
+			Err(err) => err.to_string(),
+		}
+	}
+
+	let registry = k8s_openapi::KindRegistry::builtin();
+
+	let err = error(registry.deserialize_object(k8s_openapi::serde_json::json!({ "metadata": { "name": "web-0" } })));
+	assert_eq!(err, "object does not have an apiVersion and kind");
+
+	let err = error(registry.deserialize_list(k8s_openapi::serde_json::json!({ "apiVersion": "v1", "kind": "List" })));
+	assert_eq!(err, "list does not have items");
+
+	let err = error(registry.deserialize_list(k8s_openapi::serde_json::json!({
+		"apiVersion": "v1",
+		"kind": "List",
+		"items": [
+			{ "apiVersion": "v1", "kind": "Pod", "metadata": { "name": "web-0" } },
+			{ "apiVersion": "example.com/v1", "kind": "Foo", "metadata": { "name": "foo" } },
+		],
+	})));
+	assert_eq!(err, "items[1]: no type is registered for example.com/v1 Foo");
+
+	let err = error(registry.deserialize_list(k8s_openapi::serde_json::json!({
+		"apiVersion": "v1",
+		"kind": "List",
+		"items": [{ "apiVersion": "v1", "kind": "Pod", "metadata": { "name": 5 } }],
+	})));
+	assert_eq!(err.split(':').next(), Some("items[0]"));
+}
@@ -0,0 +1,28 @@
+#[test]
+fn set_checked() {
+	use k8s_openapi::api::core::v1::{Container, PullPolicy, Taint};
+
+	let mut container = Container::default();
+	container.set_image_pull_policy_checked("IfNotPresent").unwrap();
+	assert_eq!(container.image_pull_policy, Some(PullPolicy::IfNotPresent));
+
+	let err = container.set_image_pull_policy_checked("Sometimes").unwrap_err();
+	assert_eq!(err.to_string(), r#"invalid imagePullPolicy "Sometimes", expected one of Always, IfNotPresent, Never"#);
+	assert_eq!(err.known_values(), ["Always", "IfNotPresent", "Never"]);
+	assert_eq!(err.into_value(), "Sometimes");
+	assert_eq!(container.image_pull_policy, Some(PullPolicy::IfNotPresent));
+
+	let mut taint = Taint::default();
+	taint.set_effect_checked("NoExecute").unwrap();
+	assert_eq!(taint.effect, "NoExecute");
+	assert!(taint.set_effect_checked("noexecute").is_err());
+	assert_eq!(taint.effect, "NoExecute");
+
+	k8s_openapi::k8s_if_ge_1_16! {
+		let mut ephemeral_container = k8s_openapi::api::core::v1::EphemeralContainer::default();
+		ephemeral_container.set_image_pull_policy_checked("Never").unwrap();
+		assert_eq!(ephemeral_container.image_pull_policy, Some(PullPolicy::Never));
+		assert!(ephemeral_container.set_termination_message_policy_checked("Always").is_err());
+		assert_eq!(ephemeral_container.termination_message_policy, None);
+	}
+}
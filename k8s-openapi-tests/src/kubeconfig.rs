@@ -0,0 +1,55 @@
+use k8s_openapi::serde_json;
+
+#[test]
+fn lookups() {
+	use k8s_openapi::client_go::tools::clientcmd::api::v1 as clientcmd;
+
+	let config: clientcmd::Config = serde_json::from_value(serde_json::json!({
+		"apiVersion": "v1",
+		"kind": "Config",
+		"clusters": [{
+			"name": "kind-kind",
+			"cluster": { "server": "https://127.0.0.1:6443", "certificate-authority-data": "Q0EgREFUQQ==" },
+		}],
+		"contexts": [
+			{ "name": "kind-kind", "context": { "cluster": "kind-kind", "user": "kind-kind" } },
+			{ "name": "dev", "context": { "cluster": "kind-kind", "user": "dev", "namespace": "dev" } },
+			{ "name": "dev", "context": { "cluster": "kind-kind", "user": "dev", "namespace": "duplicate" } },
+		],
+		"current-context": "dev",
+		"preferences": {},
+		"users": [
+			{ "name": "kind-kind", "user": { "client-certificate-data": "Q0VSVA==", "client-key-data": "S0VZ" } },
+			{
+				"name": "dev",
+				"user": {
+					"exec": {
+						"apiVersion": "client.authentication.k8s.io/v1beta1",
+						"command": "aws",
+						"args": ["eks", "get-token"],
+						"env": [{ "name": "AWS_PROFILE", "value": "dev" }],
+					},
+				},
+			},
+		],
+	})).unwrap();
+
+	let context = config.active_context().unwrap();
+	assert_eq!(context.namespace.as_deref(), Some("dev"));
+
+	let cluster = config.cluster(&context.cluster).unwrap();
+	assert_eq!(cluster.server, "https://127.0.0.1:6443");
+	assert_eq!(cluster.certificate_authority_data.as_ref().unwrap().0, b"CA DATA");
+
+	let exec = config.auth_info(&context.user).unwrap().exec.as_ref().unwrap();
+	assert_eq!(exec.command, "aws");
+	assert_eq!(exec.env.as_ref().unwrap()[0].value, "dev");
+
+	let auth_info = config.auth_info("kind-kind").unwrap();
+	assert_eq!(auth_info.client_key_data.as_ref().unwrap().0, b"KEY");
+
+	// The first of the entries with the same name is used
+	assert_eq!(config.context("dev").unwrap().namespace.as_deref(), Some("dev"));
+
+	assert!(config.context("missing").is_none());
+}
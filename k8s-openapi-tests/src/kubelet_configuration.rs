@@ -0,0 +1,35 @@
+use k8s_openapi::serde_json;
+
+#[test]
+fn roundtrip() {
+	use k8s_openapi::kubelet::config::v1beta1 as kubelet;
+
+	let value = serde_json::json!({
+		"apiVersion": "kubelet.config.k8s.io/v1beta1",
+		"kind": "KubeletConfiguration",
+		"authentication": {
+			"anonymous": { "enabled": false },
+			"webhook": { "cacheTTL": "2m0s", "enabled": true },
+			"x509": { "clientCAFile": "/etc/kubernetes/pki/ca.crt" },
+		},
+		"authorization": { "mode": "Webhook" },
+		"cgroupDriver": "systemd",
+		"clusterDNS": ["10.96.0.10"],
+		"clusterDomain": "cluster.local",
+		"evictionHard": { "memory.available": "100Mi" },
+		"featureGates": { "GracefulNodeShutdown": true },
+		"reservedMemory": [{ "numaNode": 0, "limits": { "memory": "1Gi" } }],
+		"shutdownGracePeriod": "30s",
+	});
+
+	let kubelet_configuration: kubelet::KubeletConfiguration = serde_json::from_value(value.clone()).unwrap();
+	assert_eq!(kubelet_configuration.cgroup_driver.as_deref(), Some("systemd"));
+	assert!(kubelet_configuration.feature_gates.as_ref().unwrap()["GracefulNodeShutdown"]);
+	assert_eq!(
+		kubelet_configuration.authentication.as_ref().and_then(|authentication| authentication.webhook.as_ref()).and_then(|webhook| webhook.cache_ttl.as_deref()),
+		Some("2m0s"),
+	);
+	assert_eq!(kubelet_configuration.reserved_memory.as_ref().unwrap()[0].limits["memory"].0, "1Gi");
+
+	assert_eq!(serde_json::to_value(&kubelet_configuration).unwrap(), value);
+}
@@ -0,0 +1,53 @@
+#[test]
+fn leader_election() {
+	k8s_openapi::k8s_if_ge_1_14! {
+		use k8s_openapi::api::coordination::v1 as coordination;
+		use k8s_openapi::chrono;
+
+		let now: chrono::DateTime<chrono::Utc> = "2021-09-01T00:00:00Z".parse().unwrap();
+		let lease_duration = chrono::Duration::seconds(15);
+
+		let lease = coordination::Lease::default();
+		assert!(lease.is_expired(now));
+		assert_eq!(lease.holder_identity(), None);
+
+		let lease = lease.acquire_or_renew("a", lease_duration, now).unwrap();
+		let spec = lease.spec.as_ref().unwrap();
+		assert_eq!(spec.holder_identity.as_deref(), Some("a"));
+		assert_eq!(spec.lease_duration_seconds, Some(15));
+		assert_eq!(spec.lease_transitions, Some(0));
+		assert_eq!(lease.expiry_time(), Some(now + lease_duration));
+
+		// Held by "a" and not expired, so "b" can't acquire it.
+		let now = now + chrono::Duration::seconds(10);
+		assert!(!lease.is_expired(now));
+		assert!(lease.acquire_or_renew("b", lease_duration, now).is_none());
+		assert!(lease.release("b", now).is_none());
+
+		// "a" can renew it, which keeps the acquire time and transitions.
+		let renewed_lease = lease.acquire_or_renew("a", lease_duration, now).unwrap();
+		assert!(!renewed_lease.holder_changed(&lease));
+		let renewed_spec = renewed_lease.spec.as_ref().unwrap();
+		assert_eq!(renewed_spec.acquire_time, spec.acquire_time);
+		assert_eq!(renewed_spec.lease_transitions, Some(0));
+		assert_eq!(renewed_spec.renew_time.as_ref().map(|renew_time| renew_time.0), Some(now));
+
+		// Once it expires, "b" can acquire it.
+		let now = now + chrono::Duration::seconds(15);
+		assert!(renewed_lease.is_expired(now));
+		let acquired_lease = renewed_lease.acquire_or_renew("b", lease_duration, now).unwrap();
+		assert!(acquired_lease.holder_changed(&renewed_lease));
+		let acquired_spec = acquired_lease.spec.as_ref().unwrap();
+		assert_eq!(acquired_spec.holder_identity.as_deref(), Some("b"));
+		assert_eq!(acquired_spec.acquire_time.as_ref().map(|acquire_time| acquire_time.0), Some(now));
+		assert_eq!(acquired_spec.lease_transitions, Some(1));
+
+		// Once "b" releases it, "a" can acquire it immediately.
+		let released_lease = acquired_lease.release("b", now).unwrap();
+		assert_eq!(released_lease.holder_identity(), None);
+		assert!(released_lease.is_expired(now));
+		let reacquired_lease = released_lease.acquire_or_renew("a", lease_duration, now).unwrap();
+		assert!(reacquired_lease.is_held_by("a"));
+		assert_eq!(reacquired_lease.spec.as_ref().unwrap().lease_transitions, Some(2));
+	}
+}
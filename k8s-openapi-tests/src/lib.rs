@@ -463,24 +463,157 @@ mod methodstring {
 	}
 }
 
+mod affinity;
+
+mod any_object;
+
 mod api_versions;
 
+mod apply_configuration;
+
+mod apply_order;
+
+mod arbitrary;
+
+mod binary;
+
+mod borrowed;
+
+mod builder;
+
+mod cbor;
+
+mod component_configuration;
+
+mod conditions;
+
+mod conformance;
+
+mod content_hash;
+
+mod conversion_webhook;
+
+mod cron;
+
 mod custom_resource_definition;
 
+mod daemon_set;
+
+mod date_time_as_string;
+
+// The types generated by the build script from build-script-fixtures/date-time-as-string.json, for the `date_time_as_string` tests.
+include!(concat!(env!("OUT_DIR"), "/date_time_as_string.rs"));
+
 mod deployment;
 
+mod describe;
+
+mod deserialize_error;
+
+mod deserialize_in_place;
+
+mod display;
+
+mod eq_and_hash;
+
+mod explicit_nulls;
+
+mod field_selectors;
+
+mod fixtures;
+
+mod gateway_api;
+
+mod group_version;
+
+mod horizontal_pod_autoscaler;
+
 mod job;
 
+mod json_schema_props;
+
+mod kind_registry;
+
+mod known_values;
+
+mod kubeconfig;
+
+mod kubelet_configuration;
+
+mod lease;
+
 mod logs;
 
+mod managed_fields;
+
+mod manifests;
+
+mod merge_strategy_info;
+
+mod metrics;
+
+mod network_policy;
+
+mod new;
+
+mod object_key;
+
+mod object_meta;
+
+mod omit_empty_collections;
+
 mod patch;
 
+mod persistent_volume_claim;
+
 mod pod;
 
+mod protobuf_envelope;
+
+mod quantity;
+
+mod raw_extension;
+
+mod reflection;
+
+mod replica_set;
+
 mod resource;
 
+mod resource_requirements;
+
+mod scale;
+
+mod schema;
+
+mod serialize;
+
+mod shallow;
+
 mod special_idents;
 
+mod stateful_set;
+
+mod status_subresource;
+
+mod streaming_list;
+
+mod string_enum;
+
+mod subresources;
+
+mod taint;
+
 mod time;
 
+mod union;
+
+mod unknown_fields;
+
+mod validation;
+
+mod version_conversions;
+
 mod watch_event;
+
+mod with_setters;
@@ -0,0 +1,87 @@
+// `ObjectMeta::managed_fields` was added in 1.14
+k8s_openapi::k8s_if_ge_1_14! {
+	use k8s_openapi::serde_json;
+
+	const POD: &str = r#"{
+		"apiVersion": "v1",
+		"kind": "Pod",
+		"metadata": {
+			"labels": { "app": "foo" },
+			"managedFields": [
+				{
+					"apiVersion": "v1",
+					"fieldsType": "FieldsV1",
+					"fieldsV1": { "f:metadata": { "f:labels": { ".": {}, "f:app": {} } } },
+					"manager": "kubectl",
+					"operation": "Apply"
+				}
+			],
+			"name": "foo",
+			"namespace": "default"
+		},
+		"spec": {
+			"containers": [{ "image": "foo:1", "name": "foo" }]
+		}
+	}"#;
+
+	#[test]
+	fn without_managed_fields() {
+		use k8s_openapi::api::core::v1::Pod;
+
+		let pod: Pod = serde_json::from_str(POD).unwrap();
+		assert_eq!(pod.metadata.managed_fields.as_ref().map(Vec::len), Some(1));
+
+		let k8s_openapi::WithoutManagedFields(pod_without_managed_fields): k8s_openapi::WithoutManagedFields<Pod> = serde_json::from_str(POD).unwrap();
+		assert_eq!(pod_without_managed_fields.metadata.managed_fields, None);
+		assert_eq!(
+			pod_without_managed_fields,
+			Pod {
+				metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+					managed_fields: None,
+					..pod.metadata.clone()
+				},
+				..pod.clone()
+			},
+		);
+
+		let k8s_openapi::WithoutManagedFields(pod_without_managed_fields): k8s_openapi::WithoutManagedFields<Pod> = serde_yaml::from_str(POD).unwrap();
+		assert_eq!(pod_without_managed_fields.metadata.managed_fields, None);
+		assert_eq!(pod_without_managed_fields.metadata.name.as_deref(), Some("foo"));
+	}
+
+	#[test]
+	fn list_and_watch_event() {
+		use k8s_openapi::api::core::v1::Pod;
+
+		let list = format!(r#"{{ "apiVersion": "v1", "kind": "PodList", "metadata": {{}}, "items": [{POD}, {POD}] }}"#);
+		let k8s_openapi::WithoutManagedFields(list): k8s_openapi::WithoutManagedFields<k8s_openapi::List<Pod>> = serde_json::from_str(&list).unwrap();
+		assert_eq!(list.items.len(), 2);
+		for pod in list.items {
+			assert_eq!(pod.metadata.name.as_deref(), Some("foo"));
+			assert_eq!(pod.metadata.managed_fields, None);
+		}
+
+		let watch_event = format!(r#"{{ "type": "ADDED", "object": {POD} }}"#);
+		let watch_event: k8s_openapi::apimachinery::pkg::apis::meta::v1::WatchEvent<k8s_openapi::WithoutManagedFields<Pod>> =
+			serde_json::from_str(&watch_event).unwrap();
+		let k8s_openapi::apimachinery::pkg::apis::meta::v1::WatchEvent::Added(k8s_openapi::WithoutManagedFields(pod)) = watch_event else {
+			panic!("unexpected watch event");
+		};
+		assert_eq!(pod.metadata.name.as_deref(), Some("foo"));
+		assert_eq!(pod.metadata.managed_fields, None);
+	}
+
+	#[test]
+	fn deserialize_with() {
+		#[derive(serde_derive::Deserialize)]
+		struct Cached {
+			#[serde(deserialize_with = "k8s_openapi::deserialize_without_managed_fields")]
+			pod: k8s_openapi::api::core::v1::Pod,
+		}
+
+		let cached = format!(r#"{{ "pod": {POD} }}"#);
+		let cached: Cached = serde_json::from_str(&cached).unwrap();
+		assert_eq!(cached.pod.metadata.name.as_deref(), Some("foo"));
+		assert_eq!(cached.pod.metadata.managed_fields, None);
+	}
+}
@@ -0,0 +1,74 @@
+use k8s_openapi::manifests::{LoadError, Manifest};
+
+#[test]
+fn load() {
+	let yaml = r#"
+---
+apiVersion: v1
+kind: Service
+metadata:
+  name: web
+spec:
+  ports:
+  - port: 80
+---
+# Only a comment
+---
+apiVersion: v1
+kind: List
+items:
+- apiVersion: apps/v1
+  kind: Deployment
+  metadata:
+    name: web
+- apiVersion: example.com/v1
+  kind: Foo
+  metadata:
+    name: foo
+  spec:
+    bar: 1
+---
+apiVersion: v1
+kind: ConfigMapList
+items:
+- apiVersion: v1
+  kind: ConfigMap
+  metadata:
+    name: config
+"#;
+
+	let manifests = k8s_openapi::manifests::load(yaml, &k8s_openapi::KindRegistry::builtin()).unwrap();
+	assert_eq!(
+		manifests.iter().map(|manifest| (manifest.api_version(), manifest.kind())).collect::<Vec<_>>(),
+		[("v1", "Service"), ("apps/v1", "Deployment"), ("example.com/v1", "Foo"), ("v1", "ConfigMap")],
+	);
+
+	let Manifest::Typed(service) = &manifests[0] else { panic!("{:?} is not typed", manifests[0]) };
+	assert_eq!(service.metadata().name.as_deref(), Some("web"));
+	assert_eq!(k8s_openapi::serde_json::to_value(service).unwrap()["spec"]["ports"][0]["port"], 80);
+
+	assert!(matches!(&manifests[1], Manifest::Typed(_)));
+
+	// Objects whose types are not registered are kept as they are.
+	let Manifest::Unstructured(foo) = &manifests[2] else { panic!("{:?} is not unstructured", manifests[2]) };
+	assert_eq!(foo["spec"]["bar"], 1);
+
+	// Manifests serialize as their objects.
+	assert_eq!(k8s_openapi::serde_json::to_value(&manifests[2]).unwrap(), *foo);
+	assert_eq!(k8s_openapi::serde_json::to_value(&manifests[3]).unwrap()["metadata"]["name"], "config");
+}
+
+#[test]
+fn errors() {
+	let registry = k8s_openapi::KindRegistry::builtin();
+
+	let err = k8s_openapi::manifests::load("kind: Service\n---\nmetadata: {}\n", &registry).unwrap_err();
+	assert!(matches!(err, LoadError::Object { document: 0, item: None, .. }), "{:?}", err);
+	assert_eq!(err.to_string(), "document #1: object does not have an apiVersion and kind");
+
+	let err = k8s_openapi::manifests::load("apiVersion: v1\nkind: List\nitems:\n- apiVersion: v1\n  kind: Pod\n  spec: 5\n", &registry).unwrap_err();
+	assert!(matches!(err, LoadError::Object { document: 0, item: Some(0), .. }), "{:?}", err);
+
+	let err = k8s_openapi::manifests::load("apiVersion: v1\n---\nkind: [\n", &registry).unwrap_err();
+	assert!(matches!(err, LoadError::ParseYaml(_)), "{:?}", err);
+}
@@ -0,0 +1,30 @@
+#[test]
+fn field_patch_strategy() {
+	use k8s_openapi::api::apps::v1::DeploymentSpec;
+	use k8s_openapi::api::core::v1::{Container, Pod, PodSpec};
+	use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+	use k8s_openapi::{FieldPatchStrategy, MergeStrategyInfo};
+
+	assert_eq!(Container::field_patch_strategy("env"), Some(&FieldPatchStrategy {
+		field: "env",
+		merge: true,
+		retain_keys: false,
+		merge_key: Some("name"),
+	}));
+
+	let volumes = PodSpec::field_patch_strategy("volumes").unwrap();
+	assert!(volumes.merge);
+	assert!(volumes.retain_keys);
+
+	let strategy = DeploymentSpec::field_patch_strategy("strategy").unwrap();
+	assert!(!strategy.merge);
+	assert!(strategy.retain_keys);
+	assert_eq!(strategy.merge_key, None);
+
+	let finalizers = ObjectMeta::field_patch_strategy("finalizers").unwrap();
+	assert!(finalizers.merge);
+	assert_eq!(finalizers.merge_key, None);
+
+	// Types without annotated fields implement the trait too.
+	assert!(Pod::FIELD_PATCH_STRATEGIES.is_empty());
+}
@@ -0,0 +1,74 @@
+use k8s_openapi::serde_json;
+
+#[test]
+fn deserialize() {
+	use k8s_openapi::metrics::pkg::apis::metrics::v1beta1 as metrics;
+
+	let node_metrics: k8s_openapi::List<metrics::NodeMetrics> = serde_json::from_value(serde_json::json!({
+		"apiVersion": "metrics.k8s.io/v1beta1",
+		"kind": "NodeMetricsList",
+		"metadata": {},
+		"items": [{
+			"metadata": { "name": "node-1" },
+			"timestamp": "2021-09-01T00:00:00Z",
+			"window": "30s",
+			"usage": { "cpu": "250m", "memory": "1024Ki" },
+		}],
+	})).unwrap();
+	let node_metrics = &node_metrics.items[0];
+	assert_eq!(node_metrics.metadata.name.as_deref(), Some("node-1"));
+	assert_eq!(node_metrics.window, "30s");
+	assert_eq!(node_metrics.usage["cpu"].0, "250m");
+
+	let pod_metrics: metrics::PodMetrics = serde_json::from_value(serde_json::json!({
+		"apiVersion": "metrics.k8s.io/v1beta1",
+		"kind": "PodMetrics",
+		"metadata": { "name": "pod-1", "namespace": "default" },
+		"timestamp": "2021-09-01T00:00:00Z",
+		"window": "30s",
+		"containers": [{ "name": "app", "usage": { "cpu": "10m", "memory": "2Mi" } }],
+	})).unwrap();
+	assert_eq!(pod_metrics.containers.len(), 1);
+	assert_eq!(pod_metrics.containers[0].usage["memory"].0, "2Mi");
+
+	let (request, _) = metrics::PodMetrics::list_namespaced_pod_metrics("default", Default::default()).unwrap();
+	assert_eq!(request.uri(), "/apis/metrics.k8s.io/v1beta1/namespaces/default/pods?");
+}
+
+#[test]
+fn deserialize_custom_and_external() {
+	use k8s_openapi::metrics::pkg::apis::{custom_metrics::v1beta2 as custom_metrics, external_metrics::v1beta1 as external_metrics};
+
+	let metric_values: k8s_openapi::List<custom_metrics::MetricValue> = serde_json::from_value(serde_json::json!({
+		"apiVersion": "custom.metrics.k8s.io/v1beta2",
+		"kind": "MetricValueList",
+		"metadata": {},
+		"items": [{
+			"describedObject": { "kind": "Pod", "namespace": "default", "name": "pod-1", "apiVersion": "/v1" },
+			"metric": { "name": "http_requests", "selector": null },
+			"timestamp": "2021-09-01T00:00:00Z",
+			"windowSeconds": 60,
+			"value": "1500m",
+		}],
+	})).unwrap();
+	let metric_value = &metric_values.items[0];
+	assert_eq!(metric_value.described_object.name.as_deref(), Some("pod-1"));
+	assert_eq!(metric_value.metric.name, "http_requests");
+	assert_eq!(metric_value.window_seconds, Some(60));
+	assert_eq!(metric_value.value.0, "1500m");
+
+	let external_metric_values: k8s_openapi::List<external_metrics::ExternalMetricValue> = serde_json::from_value(serde_json::json!({
+		"apiVersion": "external.metrics.k8s.io/v1beta1",
+		"kind": "ExternalMetricValueList",
+		"metadata": {},
+		"items": [{
+			"metricName": "queue_length",
+			"metricLabels": { "queue": "jobs" },
+			"timestamp": "2021-09-01T00:00:00Z",
+			"value": "42",
+		}],
+	})).unwrap();
+	let external_metric_value = &external_metric_values.items[0];
+	assert_eq!(external_metric_value.metric_labels["queue"], "jobs");
+	assert_eq!(external_metric_value.window, None);
+}
@@ -0,0 +1,63 @@
+use k8s_openapi::serde_json;
+
+#[test]
+fn evaluate() {
+	use k8s_openapi::api::networking::v1 as networking;
+	use k8s_openapi::network_policy::{Peer, Port};
+
+	let policy: networking::NetworkPolicy = serde_json::from_value(serde_json::json!({
+		"apiVersion": "networking.k8s.io/v1",
+		"kind": "NetworkPolicy",
+		"metadata": { "name": "db", "namespace": "prod" },
+		"spec": {
+			"podSelector": { "matchLabels": { "app": "db" } },
+			"ingress": [{
+				"from": [
+					{ "podSelector": { "matchExpressions": [{ "key": "app", "operator": "In", "values": ["api", "worker"] }] } },
+					{ "namespaceSelector": { "matchLabels": { "team": "ops" } } },
+					{ "ipBlock": { "cidr": "10.0.0.0/8", "except": ["10.1.0.0/16"] } },
+				],
+				"ports": [{ "port": 5432 }, { "protocol": "UDP", "port": "metrics" }],
+			}],
+		},
+	})).unwrap();
+
+	let labels = |pairs: &[(&str, &str)]| -> std::collections::BTreeMap<String, String> {
+		pairs.iter().map(|&(key, value)| (key.to_owned(), value.to_owned())).collect()
+	};
+	let db_labels = labels(&[("app", "db")]);
+	let api_labels = labels(&[("app", "api")]);
+	let web_labels = labels(&[("app", "web")]);
+	let prod_namespace_labels = labels(&[("team", "dev")]);
+	let ops_namespace_labels = labels(&[("team", "ops")]);
+
+	assert!(policy.selects_pod("prod", &db_labels));
+	assert!(!policy.selects_pod("prod", &api_labels));
+	assert!(!policy.selects_pod("staging", &db_labels));
+	assert_eq!(policy.effective_policy_types(), ["Ingress"]);
+
+	let postgres = Port { protocol: "TCP", number: 5432, name: None };
+	let postgres_udp = Port { protocol: "UDP", number: 5432, name: None };
+	let metrics = Port { protocol: "UDP", number: 9090, name: Some("metrics") };
+
+	let api_pod = Peer::Pod { namespace: "prod", namespace_labels: &prod_namespace_labels, labels: &api_labels, ip: None };
+	assert!(policy.allows_ingress(&api_pod, &postgres));
+	assert!(policy.allows_ingress(&api_pod, &metrics));
+	assert!(!policy.allows_ingress(&api_pod, &postgres_udp));
+	assert!(!policy.allows_egress(&api_pod, &postgres));
+
+	// podSelector without namespaceSelector only matches pods in the policy's namespace.
+	let api_pod_in_other_namespace = Peer::Pod { namespace: "staging", namespace_labels: &prod_namespace_labels, labels: &api_labels, ip: None };
+	assert!(!policy.allows_ingress(&api_pod_in_other_namespace, &postgres));
+
+	let web_pod = Peer::Pod { namespace: "prod", namespace_labels: &prod_namespace_labels, labels: &web_labels, ip: None };
+	assert!(!policy.allows_ingress(&web_pod, &postgres));
+
+	let ops_pod = Peer::Pod { namespace: "ops", namespace_labels: &ops_namespace_labels, labels: &web_labels, ip: None };
+	assert!(policy.allows_ingress(&ops_pod, &postgres));
+
+	assert!(policy.allows_ingress(&Peer::External("10.2.3.4".parse().unwrap()), &postgres));
+	assert!(!policy.allows_ingress(&Peer::External("10.1.3.4".parse().unwrap()), &postgres));
+	assert!(!policy.allows_ingress(&Peer::External("192.168.0.1".parse().unwrap()), &postgres));
+	assert!(!policy.allows_ingress(&Peer::External("::1".parse().unwrap()), &postgres));
+}
@@ -0,0 +1,23 @@
+#[test]
+fn new() {
+	use k8s_openapi::api::autoscaling::v1::{CrossVersionObjectReference, HorizontalPodAutoscalerSpec};
+	use k8s_openapi::api::core::v1::QuobyteVolumeSource;
+
+	let quobyte_volume_source = QuobyteVolumeSource::new("registry:7861", "volume");
+	assert_eq!(quobyte_volume_source, QuobyteVolumeSource {
+		registry: "registry:7861".to_owned(),
+		volume: "volume".to_owned(),
+		..Default::default()
+	});
+
+	// Required fields are parameters in the order of the fields, and the constructed value can be modified further with the setters.
+	let horizontal_pod_autoscaler_spec =
+		HorizontalPodAutoscalerSpec::new(5, CrossVersionObjectReference::new("Deployment", "web").with_api_version("apps/v1"))
+		.with_min_replicas(2);
+	assert_eq!(horizontal_pod_autoscaler_spec.max_replicas, 5);
+	assert_eq!(horizontal_pod_autoscaler_spec.min_replicas, Some(2));
+	assert_eq!(horizontal_pod_autoscaler_spec.scale_target_ref.kind, "Deployment");
+	assert_eq!(horizontal_pod_autoscaler_spec.scale_target_ref.name, "web");
+	assert_eq!(horizontal_pod_autoscaler_spec.scale_target_ref.api_version.as_deref(), Some("apps/v1"));
+	assert_eq!(horizontal_pod_autoscaler_spec.target_cpu_utilization_percentage, None);
+}
@@ -0,0 +1,32 @@
+#[test]
+fn object_key() {
+	use k8s_openapi::ObjectKey;
+
+	let pod = k8s_openapi::fixtures::pod("pod1").namespace("ns1").build();
+	let key = ObjectKey::from(&pod);
+	assert_eq!(key, ObjectKey { namespace: Some("ns1".to_owned()), name: "pod1".to_owned() });
+	assert_eq!(key.to_string(), "ns1/pod1");
+	assert_eq!("ns1/pod1".parse(), Ok(key));
+
+	let node = k8s_openapi::api::core::v1::Node {
+		metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+			name: Some("node1".to_owned()),
+			..Default::default()
+		},
+		..Default::default()
+	};
+	let key = ObjectKey::from(&node);
+	assert_eq!(key, ObjectKey { namespace: None, name: "node1".to_owned() });
+	assert_eq!(key.to_string(), "node1");
+	assert_eq!("node1".parse(), Ok(key));
+
+	for s in &["", "ns1/", "/pod1", "ns1/pod1/foo"] {
+		let err = s.parse::<ObjectKey>().unwrap_err();
+		assert_eq!(err.to_string(), format!("{:?} is not of the form `namespace/name` or `name`", s));
+	}
+
+	let mut keys: Vec<ObjectKey> = vec!["b/a".parse().unwrap(), "a/b".parse().unwrap(), "c".parse().unwrap(), "a/a".parse().unwrap()];
+	keys.sort();
+	let keys: Vec<_> = keys.iter().map(ToString::to_string).collect();
+	assert_eq!(keys, ["c", "a/a", "a/b", "b/a"]);
+}
@@ -0,0 +1,42 @@
+#[test]
+fn builder() {
+	use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference};
+
+	let deployment = k8s_openapi::fixtures::deployment("web").namespace("ns1").label("tier", "frontend").build();
+
+	let metadata =
+		ObjectMeta::builder()
+		.generate_name("web-")
+		.label("app", "api")
+		.inherit_labels(&deployment)
+		.label("pod-template-hash", "5d4f8b9c7")
+		.annotation("deployment.kubernetes.io/revision", "1")
+		.owner(&deployment)
+		.build();
+
+	assert_eq!(metadata.name, None);
+	assert_eq!(metadata.generate_name.as_deref(), Some("web-"));
+	assert_eq!(metadata.namespace.as_deref(), Some("ns1"));
+
+	// The deployment's own `app` label overrides the one added before `inherit_labels`
+	let labels = metadata.labels.unwrap();
+	assert_eq!(labels.get("app").map(String::as_str), Some("web"));
+	assert_eq!(labels.get("tier").map(String::as_str), Some("frontend"));
+	assert_eq!(labels.get("pod-template-hash").map(String::as_str), Some("5d4f8b9c7"));
+
+	assert_eq!(metadata.annotations.unwrap().len(), 1);
+
+	assert_eq!(metadata.owner_references.unwrap(), [OwnerReference {
+		api_version: "apps/v1".to_owned(),
+		block_owner_deletion: Some(true),
+		controller: Some(true),
+		kind: "Deployment".to_owned(),
+		name: "web".to_owned(),
+		uid: deployment.metadata.uid.clone().unwrap(),
+	}]);
+
+	// An explicit namespace is not overridden by the owner's
+	let metadata = ObjectMeta::builder().name("web-config").namespace("ns2").owner(&deployment).build();
+	assert_eq!(metadata.name.as_deref(), Some("web-config"));
+	assert_eq!(metadata.namespace.as_deref(), Some("ns2"));
+}
@@ -0,0 +1,109 @@
+use k8s_openapi::serde_json;
+
+#[test]
+fn omit_empty_collections() {
+	use k8s_openapi::api::core::v1 as api;
+	use k8s_openapi::apimachinery::pkg::apis::meta::v1 as meta;
+
+	let pod = api::Pod {
+		metadata: meta::ObjectMeta {
+			annotations: Some(Default::default()),
+			finalizers: Some(vec![]),
+			labels: Some(std::iter::once(("app".to_owned(), String::new())).collect()),
+			name: Some("foo".to_owned()),
+			..Default::default()
+		},
+		spec: Some(api::PodSpec {
+			containers: vec![
+				api::Container {
+					args: Some(vec![]),
+					command: Some(vec!["sh".to_owned()]),
+					name: "foo".to_owned(),
+					..Default::default()
+				},
+			],
+			volumes: Some(vec![]),
+			..Default::default()
+		}),
+		..Default::default()
+	};
+
+	assert_eq!(
+		serde_json::to_value(&pod).unwrap(),
+		serde_json::json!({
+			"apiVersion": "v1",
+			"kind": "Pod",
+			"metadata": {
+				"annotations": {},
+				"finalizers": [],
+				"labels": { "app": "" },
+				"name": "foo",
+			},
+			"spec": {
+				"containers": [{
+					"args": [],
+					"command": ["sh"],
+					"name": "foo",
+				}],
+				"volumes": [],
+			},
+		}),
+	);
+
+	assert_eq!(
+		serde_json::to_value(k8s_openapi::OmitEmptyCollections(&pod)).unwrap(),
+		serde_json::json!({
+			"apiVersion": "v1",
+			"kind": "Pod",
+			"metadata": {
+				"labels": { "app": "" },
+				"name": "foo",
+			},
+			"spec": {
+				"containers": [{
+					"command": ["sh"],
+					"name": "foo",
+				}],
+			},
+		}),
+	);
+
+	// Required lists are omitted too
+	let pod = api::Pod {
+		spec: Some(Default::default()),
+		..Default::default()
+	};
+	assert_eq!(
+		serde_json::to_value(k8s_openapi::OmitEmptyCollections(&pod)).unwrap(),
+		serde_json::json!({
+			"apiVersion": "v1",
+			"kind": "Pod",
+			"metadata": {},
+			"spec": {},
+		}),
+	);
+}
+
+#[test]
+fn serialize_with() {
+	#[derive(serde_derive::Serialize)]
+	struct Exported {
+		#[serde(serialize_with = "k8s_openapi::serialize_omitting_empty_collections")]
+		config_maps: Vec<k8s_openapi::api::core::v1::ConfigMap>,
+	}
+
+	let exported = Exported {
+		config_maps: vec![
+			k8s_openapi::api::core::v1::ConfigMap {
+				data: Some(Default::default()),
+				..Default::default()
+			},
+		],
+	};
+
+	// Empty lists and maps that are elements of lists are kept, but the empty fields of the structs in them are omitted.
+	assert_eq!(
+		serde_json::to_string(&exported).unwrap(),
+		r#"{"config_maps":[{"apiVersion":"v1","kind":"ConfigMap","metadata":{}}]}"#,
+	);
+}
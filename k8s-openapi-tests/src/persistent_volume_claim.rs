@@ -0,0 +1,50 @@
+#[test]
+fn binding_and_capacity() {
+	use k8s_openapi::api::core::v1 as api;
+	use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+
+	let pvc: api::PersistentVolumeClaim = k8s_openapi::serde_json::from_str(r#"{
+		"apiVersion": "v1",
+		"kind": "PersistentVolumeClaim",
+		"metadata": { "name": "data" },
+		"spec": {
+			"accessModes": ["ReadWriteOnce"],
+			"resources": { "requests": { "storage": "10Gi" } }
+		},
+		"status": { "phase": "Pending" }
+	}"#).unwrap();
+	assert!(!pvc.is_bound());
+	assert_eq!(pvc.volume_name(), None);
+	assert_eq!(pvc.requested_storage(), Some(&Quantity("10Gi".to_owned())));
+	assert_eq!(pvc.storage_capacity(), None);
+	assert!(!pvc.has_requested_storage());
+
+	let pvc: api::PersistentVolumeClaim = k8s_openapi::serde_json::from_str(r#"{
+		"apiVersion": "v1",
+		"kind": "PersistentVolumeClaim",
+		"metadata": { "name": "data" },
+		"spec": {
+			"accessModes": ["ReadWriteOnce"],
+			"resources": { "requests": { "storage": "10Gi" } },
+			"volumeName": "pvc-1234"
+		},
+		"status": {
+			"accessModes": ["ReadWriteOnce"],
+			"capacity": { "storage": "10240Mi" },
+			"phase": "Bound"
+		}
+	}"#).unwrap();
+	assert!(pvc.is_bound());
+	assert_eq!(pvc.volume_name(), Some("pvc-1234"));
+	assert_eq!(pvc.storage_capacity(), Some(&Quantity("10Gi".to_owned())));
+	assert!(pvc.has_requested_storage());
+
+	// Expansion in progress
+	let mut pvc = pvc;
+	pvc.spec.as_mut().unwrap().resources.as_mut().unwrap().requests.as_mut().unwrap().insert("storage".to_owned(), Quantity("20Gi".to_owned()));
+	assert!(pvc.is_bound());
+	assert!(!pvc.has_requested_storage());
+
+	assert!(!api::PersistentVolumeClaim::default().is_bound());
+	assert_eq!(api::PersistentVolumeClaim::default().requested_storage(), None);
+}
@@ -0,0 +1,31 @@
+#[test]
+fn envelope() {
+	use k8s_openapi::api::core::v1::{Pod, Service};
+	use k8s_openapi::protobuf_envelope::{DecodeError, Unknown};
+
+	let unknown = Unknown::for_resource::<Pod>(vec![0x0a, 0x00]);
+
+	let buf = unknown.encode();
+	assert_eq!(buf, &[
+		b'k', b'8', b's', 0x00,
+		0x0a, 0x09, 0x0a, 0x02, b'v', b'1', 0x12, 0x03, b'P', b'o', b'd',
+		0x12, 0x02, 0x0a, 0x00,
+		0x1a, 0x00,
+		0x22, 0x00,
+	][..]);
+
+	let decoded = Unknown::decode(&buf).unwrap();
+	assert_eq!(decoded, unknown);
+	assert!(decoded.is_resource::<Pod>());
+	assert!(!decoded.is_resource::<Service>());
+	assert_eq!(decoded.group_version(), Ok(k8s_openapi::GroupVersion::of::<Pod>()));
+	assert_eq!(Unknown::for_resource::<k8s_openapi::api::apps::v1::Deployment>(vec![]).group_version().unwrap().to_string(), "apps/v1");
+
+	// Fields that the envelope does not have, like ones added by newer versions of Kubernetes, are skipped.
+	let mut buf_with_unknown_field = buf.clone();
+	buf_with_unknown_field.extend_from_slice(&[0x28, 0x96, 0x01]);
+	assert_eq!(Unknown::decode(&buf_with_unknown_field).unwrap(), unknown);
+
+	assert_eq!(Unknown::decode(b"{\"kind\":\"Pod\"}"), Err(DecodeError::MissingMagic));
+	assert_eq!(Unknown::decode(&buf[..buf.len() - 3]), Err(DecodeError::Truncated));
+}
@@ -0,0 +1,82 @@
+#[test]
+fn eq() {
+	use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+
+	for &(left, right) in &[
+		("1Gi", "1024Mi"),
+		("1Ki", "1024"),
+		("0.5", "500m"),
+		("1.5Gi", "1536Mi"),
+		("1k", "1000"),
+		("1e3", "1k"),
+		("1E3", "1000"),
+		("100m", ".1"),
+		("+1", "1"),
+		("01.10", "1.1"),
+		("0", "-0"),
+		("0Gi", "0m"),
+		("1n", "0.000000001"),
+		("12E", "12e18"),
+		("not a quantity", "not a quantity"),
+	] {
+		assert_eq!(Quantity(left.to_owned()), Quantity(right.to_owned()), "{left} != {right}");
+	}
+
+	for &(left, right) in &[
+		("1Gi", "1G"),
+		("1", "-1"),
+		("1m", "1M"),
+		("1", "1.01"),
+		("1.2.3", "1.23"),
+		("1e", "1"),
+		("not a quantity", "Not a quantity"),
+	] {
+		assert_ne!(Quantity(left.to_owned()), Quantity(right.to_owned()), "{left} == {right}");
+	}
+}
+
+#[test]
+fn hash() {
+	use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+
+	let quantities: std::collections::HashSet<_> =
+		["1Gi", "1024Mi", "1048576Ki", "1G", "1e9", "500m", "0.5", "foo"].iter()
+		.map(|&s| Quantity(s.to_owned()))
+		.collect();
+	assert_eq!(quantities.len(), 4);
+	assert!(quantities.contains(&Quantity("1073741824".to_owned())));
+	assert!(quantities.contains(&Quantity("1000M".to_owned())));
+	assert!(quantities.contains(&Quantity(".5".to_owned())));
+	assert!(quantities.contains(&Quantity("foo".to_owned())));
+}
+
+#[test]
+fn partial_cmp() {
+	use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+
+	for &(left, right) in &[
+		("1G", "1Gi"),
+		("1023Mi", "1Gi"),
+		("500m", "1"),
+		("0", "1n"),
+		("-1", "0"),
+		("-1Gi", "-1G"),
+		("9", "10"),
+		("1.5", "1.55"),
+		("1e3", "1001"),
+		("1E", "1Ei"),
+	] {
+		let left = Quantity(left.to_owned());
+		let right = Quantity(right.to_owned());
+		assert!(left < right, "{:?} >= {:?}", left, right);
+		assert!(right > left, "{:?} <= {:?}", right, left);
+	}
+
+	assert!(Quantity("1Gi".to_owned()) <= Quantity("1024Mi".to_owned()));
+	assert!(Quantity("1Gi".to_owned()) >= Quantity("1024Mi".to_owned()));
+	assert!(Quantity("-0".to_owned()) >= Quantity("0m".to_owned()));
+
+	assert_eq!(Quantity("foo".to_owned()).partial_cmp(&Quantity("foo".to_owned())), Some(std::cmp::Ordering::Equal));
+	assert_eq!(Quantity("foo".to_owned()).partial_cmp(&Quantity("bar".to_owned())), None);
+	assert_eq!(Quantity("foo".to_owned()).partial_cmp(&Quantity("1".to_owned())), None);
+}
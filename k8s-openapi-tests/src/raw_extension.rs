@@ -0,0 +1,30 @@
+use k8s_openapi::serde_json;
+
+#[test]
+fn roundtrip() {
+	use k8s_openapi::api::core::v1::Pod;
+	use k8s_openapi::apimachinery::pkg::runtime::RawExtension;
+
+	// The `foo` field is not known to `Pod`, but is preserved by the `RawExtension` that it was parsed from.
+	let value = serde_json::json!({
+		"apiVersion": "v1",
+		"kind": "Pod",
+		"metadata": { "name": "pod1", "foo": "bar" },
+	});
+	let raw_extension: RawExtension = serde_json::from_value(value.clone()).unwrap();
+
+	let pod: Pod = raw_extension.parse().unwrap();
+	assert_eq!(pod.metadata.name.as_deref(), Some("pod1"));
+	assert_eq!(serde_json::to_value(&raw_extension).unwrap(), value);
+
+	let raw_extension = RawExtension::from_object(&pod).unwrap();
+	assert_eq!(raw_extension.0, serde_json::json!({
+		"apiVersion": "v1",
+		"kind": "Pod",
+		"metadata": { "name": "pod1" },
+	}));
+	assert_eq!(raw_extension.parse::<Pod>().unwrap(), pod);
+
+	let err = raw_extension.parse::<k8s_openapi::api::apps::v1::Deployment>().unwrap_err();
+	assert!(err.to_string().contains("apps/v1"), "{}", err);
+}
@@ -0,0 +1,56 @@
+#[test]
+fn find() {
+	let pod = k8s_openapi::reflection::find("io.k8s.api.core.v1.Pod").unwrap();
+	assert_eq!(pod.rust_path, "api::core::v1::Pod");
+	assert!(pod.description.is_some());
+
+	let resource = pod.resource.unwrap();
+	assert_eq!(resource.api_version, "v1");
+	assert_eq!(resource.group, "");
+	assert_eq!(resource.kind, "Pod");
+	assert_eq!(resource.version, "v1");
+	assert!((resource.api_resource)().namespaced);
+
+	assert!(pod.field("apiVersion").is_none());
+	let spec = pod.field("spec").unwrap();
+	assert_eq!(spec.field_name, "spec");
+	assert_eq!(spec.type_name, "api::core::v1::PodSpec");
+	assert_eq!(spec.referenced_type, Some("io.k8s.api.core.v1.PodSpec"));
+	assert!(!spec.required);
+
+	let pod_spec = k8s_openapi::reflection::find(spec.referenced_type.unwrap()).unwrap();
+	assert!(pod_spec.resource.is_none());
+	assert_eq!(pod_spec.required_fields().collect::<Vec<_>>(), ["containers"]);
+	let containers = pod_spec.field("containers").unwrap();
+	assert_eq!(containers.type_name, "Vec<api::core::v1::Container>");
+	assert_eq!(containers.referenced_type, Some("io.k8s.api.core.v1.Container"));
+
+	assert!(k8s_openapi::reflection::find("io.k8s.api.core.v1.Podd").is_none());
+}
+
+#[test]
+fn find_resource() {
+	let namespace = k8s_openapi::reflection::find_resource("v1", "Namespace").unwrap();
+	assert_eq!(namespace.definition_path, "io.k8s.api.core.v1.Namespace");
+	assert!(!(namespace.resource.unwrap().api_resource)().namespaced);
+
+	let deployment = k8s_openapi::reflection::find_resource("apps/v1", "Deployment").unwrap();
+	assert_eq!(deployment.definition_path, "io.k8s.api.apps.v1.Deployment");
+	assert_eq!(deployment.resource.unwrap().group, "apps");
+
+	assert!(k8s_openapi::reflection::find_resource("apps/v1", "Pod").is_none());
+}
+
+#[test]
+fn types() {
+	let types = k8s_openapi::reflection::types();
+	assert!(types.windows(2).all(|types| types[0].definition_path < types[1].definition_path));
+
+	for type_info in types {
+		if let Some(resource) = type_info.resource {
+			let api_resource = (resource.api_resource)();
+			assert_eq!(api_resource.kind, resource.kind, "{}", type_info.definition_path);
+			assert_eq!(api_resource.group, None, "{}", type_info.definition_path);
+		}
+	}
+}
@@ -0,0 +1,78 @@
+#[test]
+fn revisions() {
+	use k8s_openapi::api::apps::v1 as apps;
+
+	fn replica_set(name: &str, owner_uid: &str, revision: &str, hash: &str, image: &str, creation_timestamp: &str) -> apps::ReplicaSet {
+		k8s_openapi::serde_json::from_value(k8s_openapi::serde_json::json!({
+			"apiVersion": "apps/v1",
+			"kind": "ReplicaSet",
+			"metadata": {
+				"name": name,
+				"creationTimestamp": creation_timestamp,
+				"labels": { "app": "web", "pod-template-hash": hash },
+				"annotations": { "deployment.kubernetes.io/revision": revision },
+				"ownerReferences": [{ "apiVersion": "apps/v1", "kind": "Deployment", "name": "web", "uid": owner_uid, "controller": true }],
+			},
+			"spec": {
+				"selector": { "matchLabels": { "app": "web", "pod-template-hash": hash } },
+				"template": {
+					"metadata": { "labels": { "app": "web", "pod-template-hash": hash } },
+					"spec": { "containers": [{ "name": "web", "image": image }] },
+				},
+			},
+		})).unwrap()
+	}
+
+	let deployment: apps::Deployment = k8s_openapi::serde_json::from_value(k8s_openapi::serde_json::json!({
+		"apiVersion": "apps/v1",
+		"kind": "Deployment",
+		"metadata": {
+			"name": "web",
+			"uid": "0b6a8d2c-6a0e-4b6f-9d8f-3c2b1a0e9f8d",
+			"annotations": { "deployment.kubernetes.io/revision": "3" },
+		},
+		"spec": {
+			"selector": { "matchLabels": { "app": "web" } },
+			"template": {
+				"metadata": { "labels": { "app": "web" } },
+				"spec": { "containers": [{ "name": "web", "image": "nginx:1.21" }] },
+			},
+		},
+	})).unwrap();
+
+	let uid = deployment.metadata.uid.as_deref().unwrap();
+	let replica_sets = vec![
+		replica_set("web-5d4f8b7c9", uid, "1", "5d4f8b7c9", "nginx:1.19", "2021-08-01T00:00:00Z"),
+		replica_set("web-7b9c6d5f4", uid, "3", "7b9c6d5f4", "nginx:1.21", "2021-08-03T00:00:00Z"),
+		replica_set("web-6c8d7e9f5", uid, "2", "6c8d7e9f5", "nginx:1.20", "2021-08-02T00:00:00Z"),
+		replica_set("other-7b9c6d5f4", "9f8e7d6c-5b4a-4392-8170-6f5e4d3c2b1a", "3", "7b9c6d5f4", "nginx:1.21", "2021-08-01T00:00:00Z"),
+	];
+
+	assert_eq!(deployment.revision(), Some(3));
+	assert_eq!(replica_sets[0].revision(), Some(1));
+	assert_eq!(replica_sets[0].pod_template_hash(), Some("5d4f8b7c9"));
+	assert!(replica_sets[0].is_controlled_by(&deployment));
+	assert!(!replica_sets[3].is_controlled_by(&deployment));
+	assert!(!replica_sets[0].has_pod_template_of(&deployment));
+	assert!(replica_sets[1].has_pod_template_of(&deployment));
+	assert!(replica_sets[3].has_pod_template_of(&deployment));
+
+	let new_replica_set = deployment.new_replica_set(&replica_sets).unwrap();
+	assert_eq!(new_replica_set.metadata.name.as_deref(), Some("web-7b9c6d5f4"));
+	assert_eq!(new_replica_set.revision(), deployment.revision());
+
+	let old_replica_sets: Vec<_> =
+		deployment.old_replica_sets(&replica_sets).into_iter()
+		.map(|replica_set| replica_set.metadata.name.as_deref().unwrap())
+		.collect();
+	assert_eq!(old_replica_sets, ["web-5d4f8b7c9", "web-6c8d7e9f5"]);
+
+	let rollback_target = deployment.replica_set_of_revision(&replica_sets, 2).unwrap();
+	assert_eq!(rollback_target.metadata.name.as_deref(), Some("web-6c8d7e9f5"));
+	assert!(deployment.replica_set_of_revision(&replica_sets, 4).is_none());
+
+	// A deployment that has not been read from the API server does not have a UID, so it does not control any replica sets
+	let mut unsaved = deployment.clone();
+	unsaved.metadata.uid = None;
+	assert!(unsaved.new_replica_set(&replica_sets).is_none());
+}
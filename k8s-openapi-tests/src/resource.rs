@@ -8,6 +8,7 @@ fn impl_resource() {
 
 	k8s_openapi::k8s_if_ge_1_11! {
 		k8s_openapi::k8s_if_le_1_17! {
+			#[allow(deprecated)]
 			assert_is_subresource::<k8s_openapi::api::apps::v1beta1::DeploymentRollback>("rollback");
 		}
 	}
@@ -65,6 +66,7 @@ fn impl_resource() {
 
 	assert_is_subresource::<k8s_openapi::api::autoscaling::v1::Scale>("scale");
 
+	#[allow(deprecated)] // Binding is deprecated in favor of the pods/binding subresource.
 	assert_is_namespace_scoped::<k8s_openapi::api::core::v1::Binding>("bindings");
 
 	assert_is_namespace_scoped::<k8s_openapi::api::apps::v1::DaemonSet>("daemonsets");
@@ -79,6 +81,7 @@ fn impl_resource() {
 
 	k8s_openapi::k8s_if_ge_1_11! {
 		k8s_openapi::k8s_if_le_1_17! {
+			#[allow(deprecated)]
 			assert_is_subresource::<k8s_openapi::api::extensions::v1beta1::DeploymentRollback>("rollback");
 		}
 	}
@@ -125,3 +128,205 @@ fn assert_is_subresource<T: k8s_openapi::Resource<Scope = k8s_openapi::SubResour
 fn assert_inner<T: k8s_openapi::Resource>(expected_url_path_segment: &str) {
 	assert_eq!(<T as k8s_openapi::Resource>::URL_PATH_SEGMENT, expected_url_path_segment);
 }
+
+#[test]
+fn try_from_value() {
+	use k8s_openapi::{serde_json, Resource};
+	use k8s_openapi::api::{apps::v1::Deployment, core::v1::Pod};
+
+	let pod: Pod = Resource::try_from_value(serde_json::json!({
+		"apiVersion": "v1",
+		"kind": "Pod",
+		"metadata": { "name": "foo" },
+	})).unwrap();
+	assert_eq!(pod.metadata.name.as_deref(), Some("foo"));
+
+	let value = pod.to_value().unwrap();
+	assert_eq!(value["apiVersion"], "v1");
+	assert_eq!(value["kind"], "Pod");
+	assert_eq!(Pod::try_from_value(value).unwrap(), pod);
+
+	// A deployment has all the fields required by a pod, so it would be silently deserialized as one if it did not have `apiVersion` and `kind`.
+	let err = Pod::try_from_value(serde_json::json!({
+		"apiVersion": "apps/v1",
+		"kind": "Deployment",
+		"metadata": { "name": "foo" },
+	})).unwrap_err();
+	assert_eq!(err.to_string(), "expected v1 Pod but got apps/v1 Deployment");
+
+	let err = Deployment::try_from_value(serde_json::json!({
+		"metadata": { "name": "foo" },
+	})).unwrap_err();
+	match err {
+		k8s_openapi::TryFromValueError::TypeMismatch { expected_api_version, expected_kind, api_version: None, kind: None } => {
+			assert_eq!(expected_api_version, "apps/v1");
+			assert_eq!(expected_kind, "Deployment");
+		},
+		err => panic!("unexpected error {:?}", err),
+	}
+
+	let err = Pod::try_from_value(serde_json::json!({
+		"apiVersion": "v1",
+		"kind": "Pod",
+		"metadata": { "name": 5 },
+	})).unwrap_err();
+	assert!(matches!(err, k8s_openapi::TryFromValueError::Json(_)), "{:?}", err);
+}
+
+#[test]
+fn metadata_accessors() {
+	use k8s_openapi::Metadata;
+	use k8s_openapi::api::core::v1::ConfigMap;
+	use k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference;
+
+	let mut config_map: ConfigMap = Default::default();
+	assert_eq!(config_map.name(), None);
+	assert_eq!(config_map.namespace(), None);
+
+	config_map.metadata.name = Some("config1".to_owned());
+	config_map.metadata.namespace = Some("ns1".to_owned());
+	assert_eq!(config_map.name(), Some("config1"));
+	assert_eq!(config_map.namespace(), Some("ns1"));
+
+	config_map.labels_mut().insert("app".to_owned(), "web".to_owned());
+	config_map.labels_mut().insert("tier".to_owned(), "frontend".to_owned());
+	config_map.annotations_mut().insert("foo".to_owned(), "bar".to_owned());
+	config_map.owner_references_mut().push(OwnerReference { name: "web".to_owned(), ..Default::default() });
+
+	assert_eq!(config_map.metadata.labels.as_ref().map(|labels| labels.len()), Some(2));
+	assert_eq!(config_map.metadata.annotations.as_ref().and_then(|annotations| annotations.get("foo")).map(String::as_str), Some("bar"));
+	assert_eq!(config_map.metadata.owner_references.as_ref().map(|owner_references| owner_references.len()), Some(1));
+}
+
+#[test]
+fn pointers() {
+	use k8s_openapi::{ListableResource, Metadata, Resource};
+	use k8s_openapi::api::core::v1::ConfigMap;
+	use std::sync::Arc;
+
+	fn describe<T>(_: T) -> String where T: Resource {
+		format!("{} {}", T::API_VERSION, T::KIND)
+	}
+
+	fn list_kind<T>(_: &T) -> &'static str where T: ListableResource {
+		T::LIST_KIND
+	}
+
+	fn rename<T>(mut object: T, name: &str) -> T where T: Metadata<Ty = k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta> {
+		object.metadata_mut().name = Some(name.to_owned());
+		object
+	}
+
+	let config_map = ConfigMap::default();
+	assert_eq!(describe(&config_map), "v1 ConfigMap");
+	assert_eq!(describe(Box::new(config_map.clone())), "v1 ConfigMap");
+	assert_eq!(describe(Arc::new(config_map.clone())), "v1 ConfigMap");
+	assert_eq!(list_kind(&&config_map), "ConfigMapList");
+	assert_eq!(<Arc<ConfigMap> as Resource>::URL_PATH_SEGMENT, "configmaps");
+
+	let mut config_map = config_map;
+	let _ = rename(&mut config_map, "config1");
+	assert_eq!(config_map.name(), Some("config1"));
+
+	let config_map = rename(Box::new(config_map), "config2");
+	assert_eq!(config_map.name(), Some("config2"));
+
+	// Renaming a shared Arc clones its value
+	let config_map = Arc::new(*config_map);
+	let renamed = rename(Arc::clone(&config_map), "config3");
+	assert_eq!(config_map.name(), Some("config2"));
+	assert_eq!(renamed.name(), Some("config3"));
+}
+
+#[test]
+fn url_path() {
+	use k8s_openapi::{HasStatusSubresource, Resource, Scalable};
+	use k8s_openapi::api::{apps::v1::Deployment, core::v1::{Node, Pod}};
+
+	assert_eq!(Pod::url_path(None), "/api/v1/pods");
+	assert_eq!(Pod::url_path(Some("kube-system")), "/api/v1/namespaces/kube-system/pods");
+	assert_eq!(Deployment::url_path(Some("default")), "/apis/apps/v1/namespaces/default/deployments");
+	assert_eq!(Node::object_url_path(None, "node-0"), "/api/v1/nodes/node-0");
+	assert_eq!(Deployment::subresource_url_path(Some("default"), "web", "scale"), "/apis/apps/v1/namespaces/default/deployments/web/scale");
+	assert_eq!(Pod::object_url_path(Some("name space"), "web 0"), "/api/v1/namespaces/name%20space/pods/web%200");
+
+	// The paths are the same as the ones of the corresponding API operations
+	let (request, _) = Pod::read_namespaced_pod_status("web-0", "default", Default::default()).unwrap();
+	assert_eq!(request.uri().path(), Pod::subresource_url_path(Some("default"), "web-0", "status"));
+
+	let (request, _) = Node::list_node(Default::default()).unwrap();
+	assert_eq!(request.uri().path(), Node::url_path(None));
+
+	assert_eq!(Pod::status_url_path(Some("default"), "web-0"), "/api/v1/namespaces/default/pods/web-0/status");
+	assert_eq!(Deployment::scale_url_path(Some("default"), "web"), Deployment::subresource_url_path(Some("default"), "web", "scale"));
+}
+
+#[test]
+fn empty_path_parameters() {
+	use k8s_openapi::api::core::v1::ConfigMap;
+
+	// An empty name would replace the collection at /api/v1/namespaces/default/configmaps/ instead of an object
+	match ConfigMap::replace_namespaced_config_map("", "default", &Default::default(), Default::default()) {
+		Err(k8s_openapi::RequestError::InvalidParameter { name: "name", .. }) => (),
+		result => panic!("expected empty name to be rejected but got {:?}", result.map(|(request, _)| request)),
+	}
+
+	match ConfigMap::list_namespaced_config_map("", Default::default()) {
+		Err(k8s_openapi::RequestError::InvalidParameter { name: "namespace", .. }) => (),
+		result => panic!("expected empty namespace to be rejected but got {:?}", result.map(|(request, _)| request)),
+	}
+
+	let (request, _) = ConfigMap::replace_namespaced_config_map("app-config", "default", &Default::default(), Default::default()).unwrap();
+	assert_eq!(request.uri().path(), "/api/v1/namespaces/default/configmaps/app-config");
+}
+
+#[test]
+fn api_resource() {
+	use k8s_openapi::Resource;
+	use k8s_openapi::api::{apps::v1::Deployment, autoscaling::v1::Scale, core::v1::Node};
+
+	let api_resource = Deployment::api_resource();
+	assert_eq!(api_resource.group, None);
+	assert_eq!(api_resource.version, None);
+	assert_eq!(api_resource.kind, "Deployment");
+	assert_eq!(api_resource.name, "deployments");
+	assert_eq!(api_resource.singular_name, "deployment");
+	assert_eq!(api_resource.short_names, Some(vec!["deploy".to_owned()]));
+	assert!(api_resource.namespaced);
+	assert_eq!(api_resource.verbs, ["create", "delete", "deletecollection", "get", "list", "patch", "update", "watch"]);
+
+	let api_resource = Node::api_resource();
+	assert_eq!(api_resource.group, None);
+	assert_eq!(api_resource.version, None);
+	assert_eq!(api_resource.name, "nodes");
+	assert!(!api_resource.namespaced);
+
+	let api_resource = Scale::api_resource();
+	assert_eq!(api_resource.name, "scale");
+	assert_eq!(api_resource.short_names, None);
+	assert_eq!(api_resource.verbs, ["get", "patch", "update"]);
+}
+
+#[test]
+fn names() {
+	use k8s_openapi::Resource;
+	use k8s_openapi::api::core::v1::{ConfigMap, Pod};
+	use k8s_openapi::api::rbac::v1::ClusterRole;
+
+	assert_eq!(Pod::PLURAL, "pods");
+	assert_eq!(Pod::SINGULAR, "pod");
+	assert_eq!(Pod::SHORT_NAMES, ["po"]);
+
+	assert_eq!(ConfigMap::SINGULAR, "configmap");
+	assert_eq!(ConfigMap::SHORT_NAMES, ["cm"]);
+
+	k8s_openapi::k8s_if_ge_1_16! {
+		assert_eq!(k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition::SHORT_NAMES, ["crd", "crds"]);
+	}
+
+	// Not all resources have short names.
+	assert_eq!(ClusterRole::PLURAL, "clusterroles");
+	assert!(ClusterRole::SHORT_NAMES.is_empty());
+
+	assert_eq!(<k8s_openapi::List<Pod> as Resource>::SINGULAR, "");
+}
@@ -0,0 +1,26 @@
+#[test]
+fn builder() {
+	use k8s_openapi::api::core::v1::ResourceRequirements;
+
+	let resources =
+		ResourceRequirements::builder()
+		.request_cpu("250m")
+		.request_memory("512Mi")
+		.request_ephemeral_storage("1Gi")
+		.limit_cpu(2)
+		.limit_memory("1Gi")
+		.limit("nvidia.com/gpu", 1)
+		.build();
+
+	assert_eq!(k8s_openapi::serde_json::to_value(&resources).unwrap(), k8s_openapi::serde_json::json!({
+		"limits": { "cpu": "2", "memory": "1Gi", "nvidia.com/gpu": "1" },
+		"requests": { "cpu": "250m", "ephemeral-storage": "1Gi", "memory": "512Mi" },
+	}));
+
+	// Setting the same resource again replaces it
+	let resources = ResourceRequirements::builder().request_cpu("100m").request("cpu", "0.5").build();
+	assert_eq!(resources.requests.unwrap()["cpu"].0, "0.5");
+	assert_eq!(resources.limits, None);
+
+	assert_eq!(ResourceRequirements::builder().build(), ResourceRequirements::default());
+}
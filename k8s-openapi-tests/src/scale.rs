@@ -0,0 +1,50 @@
+#[test]
+fn read_scale() {
+	use k8s_openapi::{Response, Scalable};
+	use k8s_openapi::api::{apps::v1::Deployment, autoscaling::v1::Scale};
+
+	let (request, _) = Deployment::read_scale(Some("default"), "web").expect("couldn't create read scale request");
+	let (expected_request, _) = Scale::read_namespaced_deployment_scale("web", "default", Default::default()).expect("couldn't create read scale request");
+	assert_eq!(request.method(), expected_request.method());
+	assert_eq!(request.uri().path(), expected_request.uri().path());
+
+	match Deployment::read_scale(Some("default"), "") {
+		Err(k8s_openapi::RequestError::InvalidParameter { name: "name", .. }) => (),
+		result => panic!("expected empty name to be rejected but got {:?}", result.map(|(request, _)| request)),
+	}
+
+	let buf = br#"{"apiVersion":"autoscaling/v1","kind":"Scale","metadata":{"name":"web","namespace":"default"},"spec":{"replicas":3},"status":{"replicas":2}}"#;
+	let (response, read) =
+		k8s_openapi::ReadScaleResponse::<<Deployment as Scalable>::Scale>::try_from_parts(k8s_openapi::http::StatusCode::OK, buf)
+		.expect("couldn't parse read scale response");
+	assert_eq!(read, buf.len());
+	let scale = match response {
+		k8s_openapi::ReadScaleResponse::Ok(scale) => scale,
+		other => panic!("{:?}", other),
+	};
+	assert_eq!(scale.spec.and_then(|spec| spec.replicas), Some(3));
+	assert_eq!(scale.status.map(|status| status.replicas), Some(2));
+}
+
+#[test]
+fn patch_scale() {
+	use k8s_openapi::Scalable;
+	use k8s_openapi::api::{apps::v1::StatefulSet, autoscaling::v1::Scale};
+	use k8s_openapi::apimachinery::pkg::apis::meta::v1::Patch;
+
+	fn set_replicas<T>(namespace: &str, name: &str, replicas: i32) -> k8s_openapi::http::Request<Vec<u8>> where T: Scalable {
+		let patch = Patch::Merge(k8s_openapi::serde_json::json!({ "spec": { "replicas": replicas } }));
+		let (request, _) = T::patch_scale(Some(namespace), name, &patch, Default::default()).expect("couldn't create patch scale request");
+		request
+	}
+
+	let request = set_replicas::<StatefulSet>("default", "web", 5);
+	let patch = Patch::Merge(k8s_openapi::serde_json::json!({ "spec": { "replicas": 5 } }));
+	let (expected_request, _) =
+		Scale::patch_namespaced_stateful_set_scale("web", "default", &patch, Default::default())
+		.expect("couldn't create patch scale request");
+	assert_eq!(request.method(), expected_request.method());
+	assert_eq!(request.uri(), expected_request.uri());
+	assert_eq!(request.headers(), expected_request.headers());
+	assert_eq!(request.body(), expected_request.body());
+}
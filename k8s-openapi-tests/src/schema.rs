@@ -0,0 +1,76 @@
+#[test]
+fn const_type_meta() {
+	use k8s_openapi::{schemars, serde_json};
+	use k8s_openapi::api::apps::v1::{Deployment, DeploymentSpec};
+
+	let schema = serde_json::to_value(schemars::schema_for!(Deployment)).expect("couldn't serialize schema");
+	assert_eq!(schema["properties"]["apiVersion"]["enum"], serde_json::json!(["apps/v1"]));
+	assert_eq!(schema["properties"]["kind"]["enum"], serde_json::json!(["Deployment"]));
+	let required = schema["required"].as_array().expect("schema has no required properties");
+	assert!(required.contains(&serde_json::json!("apiVersion")));
+	assert!(required.contains(&serde_json::json!("kind")));
+
+	// Types that aren't resources don't have type meta to restrict.
+	let schema = serde_json::to_value(schemars::schema_for!(DeploymentSpec)).expect("couldn't serialize schema");
+	assert!(schema["properties"].get("apiVersion").is_none());
+}
+
+#[test]
+fn schema_name() {
+	use k8s_openapi::{schemars, serde_json};
+	use k8s_openapi::api::core::v1::{Pod, PodSpec};
+	use schemars::JsonSchema;
+
+	// The `schemars-rust-names` feature isn't enabled, so schemas are named after their definition paths.
+	assert_eq!(PodSpec::schema_name(), "io.k8s.api.core.v1.PodSpec");
+
+	let schema = serde_json::to_value(schemars::schema_for!(Pod)).expect("couldn't serialize schema");
+	assert_eq!(schema["properties"]["spec"]["allOf"][0]["$ref"], "#/definitions/io.k8s.api.core.v1.PodSpec");
+	assert!(schema["definitions"].get("io.k8s.api.core.v1.PodSpec").is_some());
+}
+
+#[test]
+fn list() {
+	use k8s_openapi::{schemars, serde_json};
+	use k8s_openapi::api::core::v1::Pod;
+	use schemars::JsonSchema;
+
+	assert_eq!(k8s_openapi::List::<Pod>::schema_name(), "io.k8s.api.core.v1.PodList");
+
+	let schema = serde_json::to_value(schemars::schema_for!(k8s_openapi::List<Pod>)).expect("couldn't serialize schema");
+	assert_eq!(schema["properties"]["apiVersion"]["enum"], serde_json::json!(["v1"]));
+	assert_eq!(schema["properties"]["kind"]["enum"], serde_json::json!(["PodList"]));
+	assert_eq!(schema["properties"]["items"]["items"]["$ref"], "#/definitions/io.k8s.api.core.v1.Pod");
+	assert!(schema["definitions"].get("io.k8s.api.core.v1.Pod").is_some());
+}
+
+#[test]
+fn watch_event() {
+	use k8s_openapi::{schemars, serde_json};
+	use k8s_openapi::api::core::v1::Pod;
+	use k8s_openapi::apimachinery::pkg::apis::meta::v1::WatchEvent;
+	use schemars::JsonSchema;
+
+	assert_eq!(WatchEvent::<Pod>::schema_name(), "io.k8s.apimachinery.pkg.apis.meta.v1.WatchEvent_for_io.k8s.api.core.v1.Pod");
+
+	let schema = serde_json::to_value(schemars::schema_for!(WatchEvent<Pod>)).expect("couldn't serialize schema");
+	assert_eq!(schema["properties"]["object"]["$ref"], "#/definitions/io.k8s.api.core.v1.Pod");
+	assert!(schema["definitions"].get("io.k8s.api.core.v1.Pod").is_some());
+}
+
+#[test]
+fn openapi_fragment() {
+	use k8s_openapi::{OpenApiFragment, OpenApiVersion};
+	use k8s_openapi::api::apps::v1::Deployment;
+
+	let fragment = OpenApiFragment::new(OpenApiVersion::Swagger2).add::<Deployment>().into_json();
+	let definitions = fragment["definitions"].as_object().expect("fragment has no definitions");
+	assert_eq!(definitions["io.k8s.api.apps.v1.Deployment"]["properties"]["spec"]["allOf"][0]["$ref"], "#/definitions/io.k8s.api.apps.v1.DeploymentSpec");
+	assert!(definitions.contains_key("io.k8s.api.core.v1.PodSpec"));
+	assert!(fragment.get("$schema").is_none());
+
+	let fragment = OpenApiFragment::new(OpenApiVersion::OpenApi3).add::<Deployment>().into_json();
+	let schemas = fragment["components"]["schemas"].as_object().expect("fragment has no schemas");
+	assert_eq!(schemas["io.k8s.api.apps.v1.Deployment"]["properties"]["spec"]["allOf"][0]["$ref"], "#/components/schemas/io.k8s.api.apps.v1.DeploymentSpec");
+	assert!(schemas.contains_key("io.k8s.api.core.v1.PodSpec"));
+}
@@ -0,0 +1,53 @@
+use k8s_openapi::serde_json;
+
+#[test]
+fn serialize_into() {
+	use k8s_openapi::SerializeInto;
+
+	let deployment = k8s_openapi::api::apps::v1::Deployment {
+		metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+			name: Some("foo".to_owned()),
+			..Default::default()
+		},
+		..Default::default()
+	};
+
+	let mut buf = b"[".to_vec();
+	deployment.serialize_into(&mut buf).unwrap();
+	buf.push(b',');
+	deployment.serialize_into(&mut buf).unwrap();
+	buf.push(b']');
+
+	let expected = serde_json::to_vec(&[&deployment, &deployment]).unwrap();
+	assert_eq!(buf, expected);
+
+	buf.clear();
+	deployment.serialize_into_pretty(&mut buf).unwrap();
+	assert_eq!(buf, serde_json::to_vec_pretty(&deployment).unwrap());
+}
+
+#[test]
+fn serialize_buffer() {
+	let mut buf = k8s_openapi::SerializeBuffer::with_capacity(1024);
+
+	let pod = k8s_openapi::api::core::v1::Pod {
+		metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+			name: Some("foo".to_owned()),
+			..Default::default()
+		},
+		..Default::default()
+	};
+	let serialized = buf.serialize(&pod).unwrap();
+	assert_eq!(serialized, serde_json::to_vec(&pod).unwrap());
+	let ptr = serialized.as_ptr();
+
+	let config_map = k8s_openapi::api::core::v1::ConfigMap::default();
+	let serialized = buf.serialize(&config_map).unwrap();
+	assert_eq!(serialized, serde_json::to_vec(&config_map).unwrap());
+	assert_eq!(serialized.as_ptr(), ptr);
+
+	let serialized = buf.serialize_pretty(&config_map).unwrap();
+	assert_eq!(serialized, serde_json::to_vec_pretty(&config_map).unwrap());
+
+	assert_eq!(buf.into_inner(), serde_json::to_vec_pretty(&config_map).unwrap());
+}
@@ -0,0 +1,61 @@
+use k8s_openapi::serde_json;
+
+#[test]
+fn roundtrip() {
+	let deployment = k8s_openapi::fixtures::deployment("web").replicas(3).available().build();
+	let pod = k8s_openapi::fixtures::pod("web-0").owned_by(&deployment).running().build();
+	let json = serde_json::to_string(&pod).unwrap();
+
+	let shallow: k8s_openapi::Shallow<k8s_openapi::api::core::v1::Pod> = serde_json::from_str(&json).unwrap();
+	assert_eq!(shallow.metadata, pod.metadata);
+	assert_eq!(shallow.decode_spec::<k8s_openapi::api::core::v1::PodSpec>().unwrap(), pod.spec);
+	assert_eq!(shallow.decode_status::<k8s_openapi::api::core::v1::PodStatus>().unwrap(), pod.status);
+	assert!(shallow.other_fields.is_empty());
+
+	assert_eq!(shallow.decode().unwrap(), pod);
+	assert_eq!(serde_json::to_value(&shallow).unwrap(), serde_json::to_value(&pod).unwrap());
+
+	let config_map = k8s_openapi::api::core::v1::ConfigMap {
+		metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+			name: Some("foo".to_owned()),
+			..Default::default()
+		},
+		data: Some(std::iter::once(("a".to_owned(), "b".to_owned())).collect()),
+		..Default::default()
+	};
+	let shallow: k8s_openapi::Shallow<k8s_openapi::api::core::v1::ConfigMap> = serde_json::from_str(&serde_json::to_string(&config_map).unwrap()).unwrap();
+	assert!(shallow.spec.is_none());
+	assert_eq!(shallow.other_fields["data"].get(), r#"{"a":"b"}"#);
+	assert_eq!(shallow.decode().unwrap(), config_map);
+}
+
+#[test]
+fn list() {
+	let json = r#"{
+		"apiVersion": "v1",
+		"kind": "PodList",
+		"metadata": { "resourceVersion": "123" },
+		"items": [
+			{ "metadata": { "name": "foo" }, "spec": { "containers": [] } },
+			{ "metadata": { "name": "bar" }, "spec": { "containers": [] } }
+		]
+	}"#;
+
+	let list: k8s_openapi::List<k8s_openapi::Shallow<k8s_openapi::api::core::v1::Pod>> = serde_json::from_str(json).unwrap();
+	let names: Vec<_> = list.items.iter().map(|pod| k8s_openapi::Metadata::name(pod).unwrap()).collect();
+	assert_eq!(names, ["foo", "bar"]);
+	assert_eq!(list.items[1].spec.as_ref().unwrap().get(), r#"{ "containers": [] }"#);
+}
+
+#[test]
+fn errors() {
+	let err = serde_json::from_str::<k8s_openapi::Shallow<k8s_openapi::api::core::v1::Pod>>(r#"{ "kind": "Service", "metadata": {} }"#).unwrap_err();
+	assert!(err.to_string().contains("invalid value: string \"Service\", expected Pod"), "{}", err);
+
+	let err = serde_json::from_str::<k8s_openapi::Shallow<k8s_openapi::api::core::v1::Pod>>(r#"{ "spec": {} }"#).unwrap_err();
+	assert!(err.to_string().contains("missing field `metadata`"), "{}", err);
+
+	// Watch events buffer their objects, so they can't be captured as raw JSON.
+	let event = r#"{ "type": "ADDED", "object": { "apiVersion": "v1", "kind": "Pod", "metadata": { "name": "foo" }, "spec": { "containers": [] } } }"#;
+	assert!(serde_json::from_str::<k8s_openapi::apimachinery::pkg::apis::meta::v1::WatchEvent<k8s_openapi::Shallow<k8s_openapi::api::core::v1::Pod>>>(event).is_err());
+}
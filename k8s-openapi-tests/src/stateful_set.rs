@@ -0,0 +1,77 @@
+#[test]
+fn rollout_status() {
+	use k8s_openapi::api::apps::v1 as apps;
+	use k8s_openapi::StatefulSetRolloutStatus;
+
+	fn stateful_set(update_strategy: &str, status: &str) -> apps::StatefulSet {
+		k8s_openapi::serde_json::from_str(&format!(r#"{{
+			"apiVersion": "apps/v1",
+			"kind": "StatefulSet",
+			"metadata": {{ "name": "db", "generation": 2 }},
+			"spec": {{
+				"replicas": 3,
+				"selector": {{ "matchLabels": {{ "app": "db" }} }},
+				"serviceName": "db",
+				"template": {{}},
+				"updateStrategy": {update_strategy}
+			}},
+			"status": {status}
+		}}"#)).unwrap()
+	}
+
+	for (update_strategy, status, expected, expected_message) in [
+		(
+			r#"{ "type": "OnDelete" }"#,
+			r#"{ "observedGeneration": 2, "replicas": 3, "readyReplicas": 3 }"#,
+			StatefulSetRolloutStatus::NotRollingUpdate,
+			"rollout status is only available for RollingUpdate strategy type",
+		),
+		(
+			r#"{ "type": "RollingUpdate" }"#,
+			r#"{ "observedGeneration": 1, "replicas": 3, "readyReplicas": 3 }"#,
+			StatefulSetRolloutStatus::SpecUpdateNotObserved,
+			"Waiting for statefulset spec update to be observed...",
+		),
+		(
+			r#"{ "type": "RollingUpdate" }"#,
+			r#"{ "observedGeneration": 2, "replicas": 3, "readyReplicas": 1 }"#,
+			StatefulSetRolloutStatus::WaitingForReadyReplicas { not_ready_replicas: 2 },
+			"Waiting for 2 pods to be ready...",
+		),
+		(
+			r#"{ "type": "RollingUpdate", "rollingUpdate": { "partition": 1 } }"#,
+			r#"{ "observedGeneration": 2, "replicas": 3, "readyReplicas": 3, "updatedReplicas": 1 }"#,
+			StatefulSetRolloutStatus::UpdatingPartition { updated_replicas: 1, target_replicas: 2 },
+			"Waiting for partitioned roll out to finish: 1 out of 2 new pods have been updated...",
+		),
+		(
+			r#"{ "type": "RollingUpdate", "rollingUpdate": { "partition": 1 } }"#,
+			r#"{ "observedGeneration": 2, "replicas": 3, "readyReplicas": 3, "updatedReplicas": 2 }"#,
+			StatefulSetRolloutStatus::PartitionComplete { updated_replicas: 2 },
+			"partitioned roll out complete: 2 new pods have been updated...",
+		),
+		(
+			r#"{ "type": "RollingUpdate" }"#,
+			r#"{
+				"observedGeneration": 2, "replicas": 3, "readyReplicas": 3, "currentReplicas": 2, "updatedReplicas": 1,
+				"currentRevision": "db-1", "updateRevision": "db-2"
+			}"#,
+			StatefulSetRolloutStatus::UpdatingReplicas { updated_replicas: 1, update_revision: "db-2".to_owned() },
+			"waiting for statefulset rolling update to complete 1 pods at revision db-2...",
+		),
+		(
+			r#"{ "type": "RollingUpdate" }"#,
+			r#"{
+				"observedGeneration": 2, "replicas": 3, "readyReplicas": 3, "currentReplicas": 3, "updatedReplicas": 3,
+				"currentRevision": "db-2", "updateRevision": "db-2"
+			}"#,
+			StatefulSetRolloutStatus::Complete { current_replicas: 3, current_revision: "db-2".to_owned() },
+			"statefulset rolling update complete 3 pods at revision db-2...",
+		),
+	] {
+		let stateful_set = stateful_set(update_strategy, status);
+		assert_eq!(stateful_set.rollout_status(), expected, "{status}");
+		assert_eq!(stateful_set.rollout_status().to_string(), expected_message);
+		assert_eq!(stateful_set.is_rollout_complete(), expected.is_complete());
+	}
+}
@@ -0,0 +1,58 @@
+#[test]
+fn replace_status() {
+	use k8s_openapi::HasStatusSubresource;
+	use k8s_openapi::api::apps::v1::{Deployment, DeploymentStatus};
+
+	let deployment = Deployment {
+		metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+			name: Some("web".to_owned()),
+			namespace: Some("default".to_owned()),
+			..Default::default()
+		},
+		status: Some(DeploymentStatus {
+			ready_replicas: Some(3),
+			..Default::default()
+		}),
+		..Default::default()
+	};
+
+	let (request, _) =
+		Deployment::replace_status(Some("default"), "web", &deployment, Default::default())
+		.expect("couldn't create replace status request");
+	let (expected_request, _) =
+		Deployment::replace_namespaced_deployment_status("web", "default", &deployment, Default::default())
+		.expect("couldn't create replace status request");
+	assert_eq!(request.method(), expected_request.method());
+	assert_eq!(request.uri(), expected_request.uri());
+	assert_eq!(request.headers(), expected_request.headers());
+	assert_eq!(request.body(), expected_request.body());
+}
+
+#[test]
+fn patch_status() {
+	use k8s_openapi::HasStatusSubresource;
+	use k8s_openapi::api::core::v1::{Node, Pod};
+	use k8s_openapi::apimachinery::pkg::apis::meta::v1::Patch;
+
+	fn set_condition<T>(namespace: Option<&str>, name: &str, patch: &Patch) -> k8s_openapi::http::Request<Vec<u8>> where T: HasStatusSubresource + k8s_openapi::serde::de::DeserializeOwned {
+		let (request, _) = T::patch_status(namespace, name, patch, Default::default()).expect("couldn't create patch status request");
+		request
+	}
+
+	let patch = Patch::StrategicMerge(k8s_openapi::serde_json::json!({ "status": { "conditions": [{ "type": "Ready", "status": "True" }] } }));
+
+	let request = set_condition::<Pod>(Some("default"), "web-0", &patch);
+	let (expected_request, _) =
+		Pod::patch_namespaced_pod_status("web-0", "default", &patch, Default::default())
+		.expect("couldn't create patch status request");
+	assert_eq!(request.method(), expected_request.method());
+	assert_eq!(request.uri(), expected_request.uri());
+	assert_eq!(request.headers(), expected_request.headers());
+	assert_eq!(request.body(), expected_request.body());
+
+	let request = set_condition::<Node>(None, "node-0", &patch);
+	let (expected_request, _) =
+		Node::patch_node_status("node-0", &patch, Default::default())
+		.expect("couldn't create patch status request");
+	assert_eq!(request.uri(), expected_request.uri());
+}
@@ -0,0 +1,66 @@
+use k8s_openapi::serde_json;
+
+fn config_map(name: &str) -> k8s_openapi::api::core::v1::ConfigMap {
+	k8s_openapi::api::core::v1::ConfigMap {
+		metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+			name: Some(name.to_owned()),
+			..Default::default()
+		},
+		..Default::default()
+	}
+}
+
+#[test]
+fn same_as_list() {
+	let metadata = k8s_openapi::apimachinery::pkg::apis::meta::v1::ListMeta {
+		resource_version: Some("123".to_owned()),
+		..Default::default()
+	};
+
+	let list = k8s_openapi::List {
+		items: vec![config_map("foo"), config_map("bar")],
+		metadata: metadata.clone(),
+	};
+
+	let streaming_list = k8s_openapi::StreamingList::new(metadata.clone(), ["foo", "bar"].iter().map(|name| config_map(name)));
+	assert_eq!(serde_json::to_string(&streaming_list).unwrap(), serde_json::to_string(&list).unwrap());
+
+	let streaming_list = k8s_openapi::StreamingList::new(metadata, std::iter::empty::<k8s_openapi::api::core::v1::ConfigMap>());
+	assert_eq!(
+		serde_json::to_value(&streaming_list).unwrap(),
+		serde_json::json!({
+			"apiVersion": "v1",
+			"kind": "ConfigMapList",
+			"items": [],
+			"metadata": { "resourceVersion": "123" },
+		}),
+	);
+}
+
+#[test]
+fn with_kind() {
+	let items = vec![
+		serde_json::json!({ "apiVersion": "v1", "kind": "ConfigMap", "metadata": { "name": "foo" } }),
+		serde_json::json!({ "apiVersion": "v1", "kind": "Secret", "metadata": { "name": "bar" } }),
+	];
+
+	let streaming_list = k8s_openapi::StreamingList::with_kind("v1", "List", Default::default(), items.clone());
+	assert_eq!(
+		serde_json::to_value(&streaming_list).unwrap(),
+		serde_json::json!({
+			"apiVersion": "v1",
+			"kind": "List",
+			"items": items,
+			"metadata": {},
+		}),
+	);
+}
+
+#[test]
+fn serialize_twice() {
+	let streaming_list = k8s_openapi::StreamingList::new(Default::default(), vec![config_map("foo")]);
+	let _ = serde_json::to_vec(&streaming_list).unwrap();
+
+	let err = serde_json::to_vec(&streaming_list).unwrap_err();
+	assert_eq!(err.to_string(), "StreamingList can only be serialized once");
+}
@@ -0,0 +1,21 @@
+use k8s_openapi::serde_json;
+
+#[test]
+fn known_and_unknown_values() {
+	use k8s_openapi::api::core::v1::{ContainerPort, Protocol};
+
+	let port: ContainerPort = serde_json::from_value(serde_json::json!({ "containerPort": 53, "protocol": "UDP" })).unwrap();
+	assert_eq!(port.protocol, Some(Protocol::Udp));
+	assert_eq!(port.protocol.as_ref().unwrap(), "UDP");
+	assert_eq!(port.protocol.as_ref().unwrap().to_string(), "UDP");
+
+	// Values that are not known to this version of the crate are preserved as-is.
+	let value = serde_json::json!({ "containerPort": 443, "protocol": "QUIC" });
+	let port: ContainerPort = serde_json::from_value(value.clone()).unwrap();
+	assert_eq!(port.protocol, Some(Protocol::Other("QUIC".to_owned())));
+	assert_eq!(serde_json::to_value(&port).unwrap(), value);
+
+	assert_eq!(Protocol::from("SCTP"), Protocol::Sctp);
+	assert_eq!(String::from(Protocol::Tcp), "TCP");
+	assert_eq!(String::from(Protocol::from("quic")), "quic");
+}
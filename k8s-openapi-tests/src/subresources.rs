@@ -0,0 +1,37 @@
+#[test]
+fn create_eviction() {
+	use k8s_openapi::Evictable;
+	use k8s_openapi::api::core::v1::Pod;
+
+	fn evict<T>(namespace: &str, name: &str) -> k8s_openapi::http::Request<Vec<u8>> where T: Evictable, T::Eviction: Default {
+		let (request, _) = T::create_eviction(Some(namespace), name, &Default::default(), Default::default()).expect("couldn't create eviction request");
+		request
+	}
+
+	let request = evict::<Pod>("default", "web-0");
+	let (expected_request, _) =
+		<Pod as Evictable>::Eviction::create_namespaced_pod_eviction("web-0", "default", &Default::default(), Default::default())
+		.expect("couldn't create eviction request");
+	assert_eq!(request.method(), expected_request.method());
+	assert_eq!(request.uri(), expected_request.uri());
+	assert_eq!(request.headers(), expected_request.headers());
+	assert_eq!(request.body(), expected_request.body());
+
+	match Pod::create_eviction(Some("default"), "", &Default::default(), Default::default()) {
+		Err(k8s_openapi::RequestError::InvalidParameter { name: "name", .. }) => (),
+		result => panic!("expected empty name to be rejected but got {:?}", result.map(|(request, _)| request)),
+	}
+}
+
+#[test]
+fn log_url_path() {
+	use k8s_openapi::Loggable;
+	use k8s_openapi::api::core::v1::Pod;
+
+	fn log_url_path<T>(namespace: &str, name: &str) -> String where T: Loggable {
+		T::log_url_path(Some(namespace), name)
+	}
+
+	let (expected_request, _) = Pod::read_namespaced_pod_log("web-0", "default", Default::default()).expect("couldn't create read log request");
+	assert_eq!(log_url_path::<Pod>("default", "web-0"), expected_request.uri().path());
+}
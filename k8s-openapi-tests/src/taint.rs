@@ -0,0 +1,79 @@
+#[test]
+fn taint() {
+	use k8s_openapi::api::core::v1::{Taint, TaintEffect};
+
+	let taint: Taint = "dedicated=gpu:NoSchedule".parse().unwrap();
+	assert_eq!(taint, Taint {
+		effect: TaintEffect::NoSchedule,
+		key: "dedicated".to_owned(),
+		value: Some("gpu".to_owned()),
+		..Default::default()
+	});
+	assert_eq!(taint.to_string(), "dedicated=gpu:NoSchedule");
+
+	let taint: Taint = "node.kubernetes.io/unreachable:NoExecute".parse().unwrap();
+	assert_eq!(taint.key, "node.kubernetes.io/unreachable");
+	assert_eq!(taint.value, None);
+	assert_eq!(taint.to_string(), "node.kubernetes.io/unreachable:NoExecute");
+
+	for (s, expected_err) in &[
+		("dedicated=gpu", r#"invalid taint "dedicated=gpu": a taint must have an effect"#),
+		("dedicated=gpu:Foo", r#"invalid taint "dedicated=gpu:Foo": the effect must be one of `NoSchedule`, `PreferNoSchedule` or `NoExecute`"#),
+		(":NoSchedule", r#"invalid taint ":NoSchedule": a taint must have a key"#),
+		("a=b=c:NoSchedule", r#"invalid taint "a=b=c:NoSchedule": the key and value must not contain `=`"#),
+	] {
+		assert_eq!(s.parse::<Taint>().unwrap_err().to_string(), *expected_err);
+	}
+}
+
+#[test]
+fn toleration() {
+	use k8s_openapi::api::core::v1::{TaintEffect, Toleration, TolerationOperator};
+
+	let toleration: Toleration = "dedicated=gpu:NoSchedule".parse().unwrap();
+	assert_eq!(toleration, Toleration {
+		effect: Some(TaintEffect::NoSchedule),
+		key: Some("dedicated".to_owned()),
+		operator: Some(TolerationOperator::Equal),
+		value: Some("gpu".to_owned()),
+		..Default::default()
+	});
+	assert_eq!(toleration.to_string(), "dedicated=gpu:NoSchedule");
+
+	let toleration: Toleration = "node.kubernetes.io/unreachable".parse().unwrap();
+	assert_eq!(toleration, Toleration {
+		key: Some("node.kubernetes.io/unreachable".to_owned()),
+		operator: Some(TolerationOperator::Exists),
+		..Default::default()
+	});
+	assert_eq!(toleration.to_string(), "node.kubernetes.io/unreachable");
+
+	let toleration: Toleration = "".parse().unwrap();
+	assert_eq!(toleration, Toleration { operator: Some(TolerationOperator::Exists), ..Default::default() });
+	assert_eq!(toleration.to_string(), "");
+
+	// The operator defaults to `Equal`
+	let toleration = Toleration { key: Some("dedicated".to_owned()), ..Default::default() };
+	assert_eq!(toleration.to_string(), "dedicated=");
+
+	// Tolerations without a key round-trip too.
+	for toleration in [
+		Toleration::default(),
+		Toleration { operator: Some(TolerationOperator::Equal), ..Default::default() },
+		Toleration { operator: Some(TolerationOperator::Equal), value: Some(String::new()), effect: Some(TaintEffect::NoExecute), ..Default::default() },
+		Toleration { operator: Some(TolerationOperator::Exists), effect: Some(TaintEffect::NoSchedule), ..Default::default() },
+	] {
+		let s = toleration.to_string();
+		let parsed: Toleration = s.parse().unwrap();
+		assert_eq!(parsed.key, None, "{}", s);
+		assert_eq!(parsed.operator.as_ref().map_or(TolerationOperator::Equal, Clone::clone), toleration.operator.clone().unwrap_or(TolerationOperator::Equal), "{}", s);
+		assert_eq!(parsed.value.as_deref().unwrap_or_default(), toleration.value.as_deref().unwrap_or_default(), "{}", s);
+		assert_eq!(parsed.effect, toleration.effect, "{}", s);
+		assert_eq!(parsed.to_string(), s);
+	}
+	assert_eq!(Toleration::default().to_string(), "=");
+
+	assert_eq!(
+		"=gpu".parse::<Toleration>().unwrap_err().to_string(),
+		r#"invalid toleration "=gpu": a toleration with a value must have a key"#);
+}
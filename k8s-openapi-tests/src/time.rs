@@ -35,3 +35,20 @@ fn time() {
 		assert_eq!(micro_time_serialized, expected_micro_time);
 	}
 }
+
+#[test]
+fn hash() {
+	use k8s_openapi::apimachinery::pkg::apis::meta::v1 as meta;
+
+	let times: std::collections::HashSet<meta::Time> =
+		[r#""2020-03-05T12:34:56Z""#, r#""2020-03-05T13:34:56+01:00""#, r#""2020-03-05T12:34:57Z""#].iter()
+		.map(|s| serde_json::from_str(s).unwrap())
+		.collect();
+	assert_eq!(times.len(), 2);
+
+	let micro_times: std::collections::HashSet<meta::MicroTime> =
+		[r#""2020-03-05T12:34:56.789012Z""#, r#""2020-03-05T12:34:56.789012Z""#, r#""2020-03-05T12:34:56.789013Z""#].iter()
+		.map(|s| serde_json::from_str(s).unwrap())
+		.collect();
+	assert_eq!(micro_times.len(), 2);
+}
@@ -0,0 +1,56 @@
+#[test]
+fn volume() {
+	use k8s_openapi::api::core::v1 as api;
+
+	let volumes: Vec<api::Volume> = k8s_openapi::serde_json::from_str(r#"[
+		{ "name": "config", "configMap": { "name": "app-config" } },
+		{ "name": "scratch", "emptyDir": {} },
+		{ "name": "data", "persistentVolumeClaim": { "claimName": "data" } },
+		{ "name": "nothing" }
+	]"#).unwrap();
+
+	let sources: Vec<_> = volumes.iter().map(|volume| match volume.source() {
+		Some(api::VolumeSource::ConfigMap(config_map)) => format!("config map {}", config_map.name.as_deref().unwrap()),
+		Some(api::VolumeSource::EmptyDir(_)) => "empty dir".to_owned(),
+		Some(api::VolumeSource::PersistentVolumeClaim(persistent_volume_claim)) => format!("claim {}", persistent_volume_claim.claim_name),
+		Some(source) => format!("{source:?}"),
+		None => "none".to_owned(),
+	}).collect();
+	assert_eq!(sources, ["config map app-config", "empty dir", "claim data", "none"]);
+
+	// If more than one source is set, the first one in the order of the variants is returned.
+	let volume = api::Volume {
+		name: "invalid".to_owned(),
+		secret: Some(Default::default()),
+		config_map: Some(Default::default()),
+		..Default::default()
+	};
+	assert_eq!(volume.source(), Some(api::VolumeSource::ConfigMap(&Default::default())));
+}
+
+#[test]
+fn probe() {
+	use k8s_openapi::api::core::v1 as api;
+
+	let probe: api::Probe = k8s_openapi::serde_json::from_str(r#"{
+		"httpGet": { "path": "/healthz", "port": 8080 },
+		"periodSeconds": 10
+	}"#).unwrap();
+	let Some(api::ProbeAction::HttpGet(http_get)) = probe.action() else { panic!("{:?}", probe.action()); };
+	assert_eq!(http_get.path.as_deref(), Some("/healthz"));
+
+	assert_eq!(api::Probe::default().action(), None);
+}
+
+#[test]
+fn metric_spec() {
+	use k8s_openapi::api::autoscaling::v2beta1 as autoscaling;
+
+	let metric_spec: autoscaling::MetricSpec = k8s_openapi::serde_json::from_str(r#"{
+		"type": "Resource",
+		"resource": { "name": "cpu", "targetAverageUtilization": 80 }
+	}"#).unwrap();
+	let Some(autoscaling::MetricSource::Resource(resource)) = metric_spec.source() else { panic!("{:?}", metric_spec.source()); };
+	assert_eq!(resource.name, "cpu");
+	assert_eq!(resource.target_average_utilization, Some(80));
+}
@@ -0,0 +1,80 @@
+use k8s_openapi::serde_json;
+
+static UNKNOWN_FIELDS: std::sync::Mutex<Vec<(&'static str, String)>> = std::sync::Mutex::new(vec![]);
+
+fn record_unknown_field(type_name: &'static str, field: &str) {
+	UNKNOWN_FIELDS.lock().unwrap().push((type_name, field.to_owned()));
+}
+
+fn was_reported(type_name: &str, field: &str) -> bool {
+	UNKNOWN_FIELDS.lock().unwrap().iter().any(|(t, f)| *t == type_name && f == field)
+}
+
+#[test]
+fn handler() {
+	use k8s_openapi::api::core::v1::Pod;
+
+	k8s_openapi::set_unknown_field_handler(Some(record_unknown_field));
+
+	let value = serde_json::json!({
+		"apiVersion": "v1",
+		"kind": "Pod",
+		"metadata": { "name": "foo", "unknownFieldHandlerMetadata": "bar" },
+		"spec": {
+			"containers": [{ "image": "foo:1", "name": "foo", "unknownFieldHandlerContainer": {} }],
+		},
+		"unknownFieldHandlerPod": 5,
+	});
+
+	let pod: Pod = serde_json::from_value(value.clone()).unwrap();
+	assert_eq!(pod.metadata.name.as_deref(), Some("foo"));
+	assert!(was_reported("Pod", "unknownFieldHandlerPod"));
+	assert!(was_reported("ObjectMeta", "unknownFieldHandlerMetadata"));
+	assert!(was_reported("Container", "unknownFieldHandlerContainer"));
+
+	// Known fields are not reported
+	assert!(!was_reported("Pod", "spec"));
+
+	UNKNOWN_FIELDS.lock().unwrap().clear();
+	let mut pod2 = pod;
+	k8s_openapi::serde::de::DeserializeSeed::deserialize(k8s_openapi::DeserializeInPlace(&mut pod2), value).unwrap();
+	assert!(was_reported("Pod", "unknownFieldHandlerPod"));
+
+	k8s_openapi::set_unknown_field_handler(None);
+	UNKNOWN_FIELDS.lock().unwrap().clear();
+	let _: Pod = serde_json::from_value(serde_json::json!({
+		"apiVersion": "v1",
+		"kind": "Pod",
+		"metadata": {},
+		"unknownFieldHandlerPod": 5,
+	})).unwrap();
+	assert!(!was_reported("Pod", "unknownFieldHandlerPod"));
+}
+
+#[test]
+fn strict() {
+	use k8s_openapi::api::core::v1::Pod;
+
+	let value = serde_json::json!({
+		"apiVersion": "v1",
+		"kind": "Pod",
+		"metadata": { "name": "foo" },
+		"spec": {
+			"containers": [{ "image": "foo:1", "name": "foo", "strictContainer": {} }],
+		},
+	});
+
+	let err = serde_json::from_value::<k8s_openapi::Strict<Pod>>(value.clone()).unwrap_err();
+	assert!(err.to_string().starts_with("unknown field `strictContainer`"), "{err}");
+
+	// Strictness only applies while deserializing the `Strict`
+	let pod: Pod = serde_json::from_value(value).unwrap();
+	assert_eq!(pod.metadata.name.as_deref(), Some("foo"));
+
+	let k8s_openapi::Strict(pod): k8s_openapi::Strict<Pod> = serde_json::from_value(serde_json::json!({
+		"apiVersion": "v1",
+		"kind": "Pod",
+		"metadata": { "name": "foo" },
+	})).unwrap();
+	assert_eq!(pod.metadata.name.as_deref(), Some("foo"));
+}
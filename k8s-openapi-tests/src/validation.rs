@@ -0,0 +1,84 @@
+use k8s_openapi::serde_json;
+
+#[test]
+fn valid() {
+	use k8s_openapi::Validate;
+
+	let pod = k8s_openapi::fixtures::pod("web").namespace("default").running().build();
+	assert_eq!(pod.validate(), Ok(()));
+
+	let deployment = k8s_openapi::fixtures::deployment("web").namespace("default").build();
+	assert_eq!(deployment.validate(), Ok(()));
+}
+
+#[test]
+fn violations() {
+	fn violations(value: &serde_json::Value) -> Vec<String> {
+		let type_info = k8s_openapi::reflection::find_resource("v1", "Pod").unwrap();
+		match type_info.validate(value) {
+			Ok(()) => vec![],
+			Err(violations) => violations.iter().map(ToString::to_string).collect(),
+		}
+	}
+
+	assert_eq!(
+		violations(&serde_json::json!({
+			"apiVersion": "v1",
+			"kind": "Pod",
+			"metadata": { "name": "web", "creationTimestamp": "yesterday" },
+			"spec": {
+				"containers": [
+					{
+						"name": "web",
+						"imagePullPolicy": "Sometimes",
+						"ports": [{ "containerPort": "http", "protocol": "TCP" }],
+						"resources": { "limits": { "cpu": "1 core", "memory": "1Gi" } },
+					},
+					{ "image": "sidecar:1", "imagePolicy": "Always" },
+				],
+				"restartPolicy": "OnFailure",
+			},
+		})),
+		[
+			r#"metadata.creationTimestamp: invalid RFC 3339 time "yesterday""#,
+			r#"spec.containers[0].imagePullPolicy: unsupported value "Sometimes", expected one of Always, IfNotPresent, Never"#,
+			"spec.containers[0].ports[0].containerPort: expected an integer",
+			r#"spec.containers[0].resources.limits.cpu: invalid quantity "1 core""#,
+			"spec.containers[1].imagePolicy: unknown field",
+			"spec.containers[1].name: missing required field",
+		],
+	);
+
+	assert_eq!(
+		violations(&serde_json::json!({
+			"apiVersion": "apps/v1",
+			"metadata": "web",
+			"spec": null,
+		})),
+		[
+			r#"apiVersion: expected "v1""#,
+			"kind: missing required field",
+			"metadata: expected an object",
+		],
+	);
+
+	k8s_openapi::k8s_if_ge_1_16! {
+		assert_eq!(
+			violations(&serde_json::json!({
+				"apiVersion": "v1",
+				"kind": "Pod",
+				"metadata": { "name": "web" },
+				"spec": {
+					"containers": [{ "name": "web", "imagePullPolicy": 1 }],
+					"ephemeralContainers": [{ "name": "debug", "terminationMessagePolicy": "Always" }],
+				},
+			})),
+			[
+				"spec.containers[0].imagePullPolicy: expected a string",
+				r#"spec.ephemeralContainers[0].terminationMessagePolicy: unsupported value "Always", expected one of File, FallbackToLogsOnError"#,
+			],
+		);
+	}
+
+	assert_eq!(violations(&serde_json::json!([])), ["expected an object"]);
+}
@@ -0,0 +1,61 @@
+#[test]
+fn cron_job() {
+	k8s_openapi::k8s_if_ge_1_21! {
+		use std::convert::TryFrom;
+
+		use k8s_openapi::api::batch::{v1, v1beta1};
+		use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+		let cron_job = v1beta1::CronJob {
+			metadata: ObjectMeta { name: Some("backup".to_owned()), ..Default::default() },
+			spec: Some(v1beta1::CronJobSpec {
+				schedule: "0 3 * * *".to_owned(),
+				suspend: Some(true),
+				..Default::default()
+			}),
+			..Default::default()
+		};
+
+		let cron_job = v1::CronJob::try_from(cron_job).unwrap();
+		assert_eq!(cron_job.metadata.name.as_deref(), Some("backup"));
+		let spec = cron_job.spec.as_ref().unwrap();
+		assert_eq!(spec.schedule, "0 3 * * *");
+		assert_eq!(spec.suspend, Some(true));
+
+		let cron_job = v1beta1::CronJob::try_from(cron_job).unwrap();
+		assert_eq!(cron_job.spec.unwrap().schedule, "0 3 * * *");
+	}
+}
+
+#[test]
+fn horizontal_pod_autoscaler() {
+	use std::convert::TryFrom;
+
+	use k8s_openapi::api::autoscaling::{v1, v2beta1};
+
+	let hpa = v1::HorizontalPodAutoscaler {
+		spec: Some(v1::HorizontalPodAutoscalerSpec {
+			max_replicas: 10,
+			scale_target_ref: v1::CrossVersionObjectReference {
+				api_version: Some("apps/v1".to_owned()),
+				kind: "Deployment".to_owned(),
+				name: "web".to_owned(),
+			},
+			target_cpu_utilization_percentage: Some(80),
+			..Default::default()
+		}),
+		..Default::default()
+	};
+
+	// `targetCPUUtilizationPercentage` was replaced by `metrics` in v2beta1, so the conversion fails instead of dropping it.
+	let err = v2beta1::HorizontalPodAutoscaler::try_from(hpa.clone()).unwrap_err();
+	assert!(err.to_string().starts_with("unknown field `targetCPUUtilizationPercentage`"), "{}", err);
+
+	let mut hpa = hpa;
+	hpa.spec.as_mut().unwrap().target_cpu_utilization_percentage = None;
+	let hpa = v2beta1::HorizontalPodAutoscaler::try_from(hpa).unwrap();
+	let spec = hpa.spec.unwrap();
+	assert_eq!(spec.max_replicas, 10);
+	assert_eq!(spec.scale_target_ref.name, "web");
+	assert_eq!(spec.metrics, None);
+}
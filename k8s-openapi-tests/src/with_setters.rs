@@ -0,0 +1,31 @@
+#[test]
+fn with_setters() {
+	use k8s_openapi::api::core::v1::{Container, ContainerPort, PodSpec, Protocol, Service, ServicePort, ServiceSpec};
+
+	let pod_spec =
+		PodSpec::default()
+		.with_containers(vec![
+			Container::default()
+			.with_name("web")
+			.with_image("nginx:1.21")
+			.with_ports(vec![ContainerPort::default().with_container_port(80)]),
+		])
+		.with_restart_policy("Always");
+
+	assert_eq!(pod_spec.containers[0].name, "web");
+	assert_eq!(pod_spec.containers[0].image.as_deref(), Some("nginx:1.21"));
+	assert_eq!(pod_spec.containers[0].ports.as_ref().unwrap()[0].container_port, 80);
+	assert_eq!(pod_spec.restart_policy.as_ref().unwrap(), "Always");
+
+	// Fields that are named after keywords, like `type_`, have setters without the trailing `_`.
+	let service =
+		Service::default()
+		.with_spec(
+			ServiceSpec::default()
+			.with_type("NodePort")
+			.with_ports(vec![ServicePort::default().with_port(80).with_protocol(Protocol::Tcp)]),
+		);
+	let spec = service.spec.unwrap();
+	assert_eq!(spec.type_.as_ref().unwrap(), "NodePort");
+	assert_eq!(spec.ports.unwrap()[0].protocol, Some(Protocol::Tcp));
+}
@@ -0,0 +1,194 @@
+use crate::api::core::v1::{
+    Affinity, NodeAffinity, NodeSelector, NodeSelectorOperator, NodeSelectorRequirement, NodeSelectorTerm, PodAffinity, PodAffinityTerm,
+    PodAntiAffinity, PreferredSchedulingTerm, WeightedPodAffinityTerm,
+};
+use crate::apimachinery::pkg::apis::meta::v1::LabelSelector;
+
+impl Affinity {
+    /// Starts building the scheduling constraints of a pod.
+    ///
+    /// ```rust
+    /// use k8s_openapi::api::core::v1::{Affinity, NodeSelectorRequirement};
+    ///
+    /// // Only schedule to amd64 nodes, preferably to spot instances, and spread the pods across zones.
+    /// let affinity =
+    ///     Affinity::builder()
+    ///     .require_node(NodeSelectorRequirement::in_values("kubernetes.io/arch", ["amd64"]))
+    ///     .prefer_node(50, NodeSelectorRequirement::exists("node.example.com/spot"))
+    ///     .require_pod_anti_affinity("topology.kubernetes.io/zone", [("app", "web")])
+    ///     .build();
+    ///
+    /// let node_affinity = affinity.node_affinity.unwrap();
+    /// assert_eq!(node_affinity.required_during_scheduling_ignored_during_execution.unwrap().node_selector_terms.len(), 1);
+    /// assert_eq!(node_affinity.preferred_during_scheduling_ignored_during_execution.unwrap()[0].weight, 50);
+    /// ```
+    pub fn builder() -> AffinityBuilder {
+        AffinityBuilder(Default::default())
+    }
+}
+
+/// A builder of an [`Affinity`]. Created with [`Affinity::builder`].
+///
+/// Each method adds one constraint. Constraints of a kind that is never added are left as `None`.
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct AffinityBuilder(Affinity);
+
+impl AffinityBuilder {
+    /// Requires the pod to be scheduled to a node that satisfies the given requirement.
+    ///
+    /// All the requirements added with this method must be satisfied by the same node, ie they are added to a single node selector term.
+    pub fn require_node(mut self, requirement: NodeSelectorRequirement) -> Self {
+        let node_selector =
+            self.node_affinity().required_during_scheduling_ignored_during_execution
+            .get_or_insert_with(|| NodeSelector { node_selector_terms: vec![] });
+        if node_selector.node_selector_terms.is_empty() {
+            node_selector.node_selector_terms.push(Default::default());
+        }
+        node_selector.node_selector_terms[0].match_expressions.get_or_insert_with(Default::default).push(requirement);
+        self
+    }
+
+    /// Prefers scheduling the pod to a node that satisfies the given requirement.
+    ///
+    /// The scheduler adds the weight, between 1 and 100, to the score of each node that satisfies the requirement.
+    pub fn prefer_node(mut self, weight: i32, requirement: NodeSelectorRequirement) -> Self {
+        self.node_affinity().preferred_during_scheduling_ignored_during_execution.get_or_insert_with(Default::default).push(PreferredSchedulingTerm {
+            preference: NodeSelectorTerm {
+                match_expressions: Some(vec![requirement]),
+                ..Default::default()
+            },
+            weight,
+        });
+        self
+    }
+
+    /// Requires the pod to be scheduled to a node in the same topology domain, like the same zone,
+    /// as a pod with all the given labels in the same namespace.
+    pub fn require_pod_affinity<I, K, V>(mut self, topology_key: impl Into<String>, labels: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let term = pod_affinity_term(topology_key, labels);
+        self.pod_affinity().required_during_scheduling_ignored_during_execution.get_or_insert_with(Default::default).push(term);
+        self
+    }
+
+    /// Prefers scheduling the pod to a node in the same topology domain as a pod with all the given labels in the same namespace.
+    ///
+    /// The scheduler adds the weight, between 1 and 100, to the score of each node in such a topology domain.
+    pub fn prefer_pod_affinity<I, K, V>(mut self, weight: i32, topology_key: impl Into<String>, labels: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let term = WeightedPodAffinityTerm { pod_affinity_term: pod_affinity_term(topology_key, labels), weight };
+        self.pod_affinity().preferred_during_scheduling_ignored_during_execution.get_or_insert_with(Default::default).push(term);
+        self
+    }
+
+    /// Requires the pod to be scheduled to a node in a different topology domain, like a different node or zone,
+    /// than every pod with all the given labels in the same namespace.
+    ///
+    /// With `kubernetes.io/hostname` as the topology key and the pod's own labels, this ensures that no two replicas of a workload run on the same node.
+    pub fn require_pod_anti_affinity<I, K, V>(mut self, topology_key: impl Into<String>, labels: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let term = pod_affinity_term(topology_key, labels);
+        self.pod_anti_affinity().required_during_scheduling_ignored_during_execution.get_or_insert_with(Default::default).push(term);
+        self
+    }
+
+    /// Prefers scheduling the pod to a node in a different topology domain than the pods with all the given labels in the same namespace.
+    ///
+    /// The scheduler subtracts the weight, between 1 and 100, from the score of each node in a topology domain with such a pod.
+    pub fn prefer_pod_anti_affinity<I, K, V>(mut self, weight: i32, topology_key: impl Into<String>, labels: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let term = WeightedPodAffinityTerm { pod_affinity_term: pod_affinity_term(topology_key, labels), weight };
+        self.pod_anti_affinity().preferred_during_scheduling_ignored_during_execution.get_or_insert_with(Default::default).push(term);
+        self
+    }
+
+    /// Builds the affinity.
+    pub fn build(self) -> Affinity {
+        self.0
+    }
+
+    fn node_affinity(&mut self) -> &mut NodeAffinity {
+        self.0.node_affinity.get_or_insert_with(Default::default)
+    }
+
+    fn pod_affinity(&mut self) -> &mut PodAffinity {
+        self.0.pod_affinity.get_or_insert_with(Default::default)
+    }
+
+    fn pod_anti_affinity(&mut self) -> &mut PodAntiAffinity {
+        self.0.pod_anti_affinity.get_or_insert_with(Default::default)
+    }
+}
+
+fn pod_affinity_term<I, K, V>(topology_key: impl Into<String>, labels: I) -> PodAffinityTerm
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: Into<String>,
+    V: Into<String>,
+{
+    PodAffinityTerm {
+        label_selector: Some(LabelSelector {
+            match_labels: Some(labels.into_iter().map(|(key, value)| (key.into(), value.into())).collect()),
+            ..Default::default()
+        }),
+        topology_key: topology_key.into(),
+        ..Default::default()
+    }
+}
+
+impl NodeSelectorRequirement {
+    /// A requirement that the node has the given label with one of the given values.
+    pub fn in_values<I>(key: impl Into<String>, values: I) -> Self where I: IntoIterator, I::Item: Into<String> {
+        NodeSelectorRequirement::with_operator_and_values(key, NodeSelectorOperator::In, values)
+    }
+
+    /// A requirement that the node does not have the given label with any of the given values. This includes nodes that do not have the label.
+    pub fn not_in_values<I>(key: impl Into<String>, values: I) -> Self where I: IntoIterator, I::Item: Into<String> {
+        NodeSelectorRequirement::with_operator_and_values(key, NodeSelectorOperator::NotIn, values)
+    }
+
+    /// A requirement that the node has the given label, with any value.
+    pub fn exists(key: impl Into<String>) -> Self {
+        NodeSelectorRequirement { key: key.into(), operator: NodeSelectorOperator::Exists, values: None }
+    }
+
+    /// A requirement that the node does not have the given label.
+    pub fn does_not_exist(key: impl Into<String>) -> Self {
+        NodeSelectorRequirement { key: key.into(), operator: NodeSelectorOperator::DoesNotExist, values: None }
+    }
+
+    /// A requirement that the node has the given label with an integer value greater than the given one.
+    pub fn greater_than(key: impl Into<String>, value: i64) -> Self {
+        NodeSelectorRequirement::with_operator_and_values(key, NodeSelectorOperator::Gt, std::iter::once(value.to_string()))
+    }
+
+    /// A requirement that the node has the given label with an integer value less than the given one.
+    pub fn less_than(key: impl Into<String>, value: i64) -> Self {
+        NodeSelectorRequirement::with_operator_and_values(key, NodeSelectorOperator::Lt, std::iter::once(value.to_string()))
+    }
+
+    fn with_operator_and_values<I>(key: impl Into<String>, operator: NodeSelectorOperator, values: I) -> Self where I: IntoIterator, I::Item: Into<String> {
+        NodeSelectorRequirement {
+            key: key.into(),
+            operator,
+            values: Some(values.into_iter().map(Into::into).collect()),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+/// An object-safe view of a resource value, so that values of different resource types can be stored together,
+/// such as in a `Vec<Box<dyn AnyObject>>` of all the objects in a manifest bundle.
+///
+/// [`Resource`](crate::Resource) and [`Metadata`](crate::Metadata) cannot be used as trait objects because of their associated constants and types,
+/// so this trait exposes the same information through methods instead. It is implemented for all resource types whose metadata is an [`ObjectMeta`].
+///
+/// `dyn AnyObject` implements `serde::Serialize`. Since a resource value serializes its own `apiVersion` and `kind`,
+/// the serialized values can be deserialized again as their original types, or as a single list like `kubectl apply -f` expects.
+pub trait AnyObject: erased_serde::Serialize {
+    /// The API version of the resource. This is the value of [`Resource::API_VERSION`](crate::Resource::API_VERSION) for its type.
+    fn api_version(&self) -> &'static str;
+
+    /// The kind of the resource. This is the value of [`Resource::KIND`](crate::Resource::KIND) for its type.
+    fn kind(&self) -> &'static str;
+
+    /// Gets a reference to the metadata of this resource value.
+    fn metadata(&self) -> &ObjectMeta;
+
+    /// Gets a mutable reference to the metadata of this resource value.
+    fn metadata_mut(&mut self) -> &mut ObjectMeta;
+}
+
+impl<T> AnyObject for T where T: crate::Metadata<Ty = ObjectMeta> + serde::Serialize {
+    fn api_version(&self) -> &'static str {
+        T::API_VERSION
+    }
+
+    fn kind(&self) -> &'static str {
+        T::KIND
+    }
+
+    fn metadata(&self) -> &ObjectMeta {
+        crate::Metadata::metadata(self)
+    }
+
+    fn metadata_mut(&mut self) -> &mut ObjectMeta {
+        crate::Metadata::metadata_mut(self)
+    }
+}
+
+erased_serde::serialize_trait_object!(AnyObject);
@@ -0,0 +1,101 @@
+//! Sorting the objects of a manifest bundle into an order in which they can be applied to or deleted from a cluster.
+//!
+//! Objects are applied so that the objects that others depend on exist first: namespaces before the objects in them,
+//! custom resource definitions before custom resources, service accounts and config before the workloads that use them, and so on.
+//! Admission webhooks are applied last, since the API server would otherwise send the creation of the other objects to webhooks
+//! whose services are not running yet. This is the same as the order used by Helm, except for custom resource definitions and webhooks.
+//!
+//! ```rust
+//! use k8s_openapi::AnyObject;
+//! use k8s_openapi::api::apps::v1::Deployment;
+//! use k8s_openapi::api::core::v1::{ConfigMap, Namespace};
+//! use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+//!
+//! let mut objects: Vec<Box<dyn AnyObject>> = vec![
+//!     Box::new(Deployment { metadata: ObjectMeta::builder().name("web").namespace("app").build(), ..Default::default() }),
+//!     Box::new(ConfigMap { metadata: ObjectMeta::builder().name("web-config").namespace("app").build(), ..Default::default() }),
+//!     Box::new(Namespace { metadata: ObjectMeta::builder().name("app").build(), ..Default::default() }),
+//! ];
+//!
+//! k8s_openapi::apply_order::sort_for_apply(&mut objects);
+//! assert_eq!(objects.iter().map(|object| object.kind()).collect::<Vec<_>>(), ["Namespace", "ConfigMap", "Deployment"]);
+//!
+//! k8s_openapi::apply_order::sort_for_delete(&mut objects);
+//! assert_eq!(objects.iter().map(|object| object.kind()).collect::<Vec<_>>(), ["Deployment", "ConfigMap", "Namespace"]);
+//! ```
+//!
+//! Ref: <https://github.com/helm/helm/blob/main/pkg/releaseutil/kind_sorter.go>
+
+use crate::AnyObject;
+
+/// The groups and kinds of the built-in resources in the order in which they are applied.
+///
+/// Objects of other kinds, like custom resources, are applied after all of these except the webhooks.
+const APPLY_ORDER: &[(&[&str], &str)] = &[
+    (&[""], "Namespace"),
+    (&["apiextensions.k8s.io"], "CustomResourceDefinition"),
+    (&["scheduling.k8s.io"], "PriorityClass"),
+    (&["networking.k8s.io", "extensions"], "NetworkPolicy"),
+    (&[""], "ResourceQuota"),
+    (&[""], "LimitRange"),
+    (&["policy", "extensions"], "PodSecurityPolicy"),
+    (&["policy"], "PodDisruptionBudget"),
+    (&[""], "ServiceAccount"),
+    (&[""], "Secret"),
+    (&[""], "ConfigMap"),
+    (&["storage.k8s.io"], "StorageClass"),
+    (&[""], "PersistentVolume"),
+    (&[""], "PersistentVolumeClaim"),
+    (&["rbac.authorization.k8s.io"], "ClusterRole"),
+    (&["rbac.authorization.k8s.io"], "ClusterRoleBinding"),
+    (&["rbac.authorization.k8s.io"], "Role"),
+    (&["rbac.authorization.k8s.io"], "RoleBinding"),
+    (&[""], "Service"),
+    (&["apps", "extensions"], "DaemonSet"),
+    (&[""], "Pod"),
+    (&[""], "ReplicationController"),
+    (&["apps", "extensions"], "ReplicaSet"),
+    (&["apps", "extensions"], "Deployment"),
+    (&["autoscaling"], "HorizontalPodAutoscaler"),
+    (&["apps"], "StatefulSet"),
+    (&["batch"], "Job"),
+    (&["batch"], "CronJob"),
+    (&["networking.k8s.io"], "IngressClass"),
+    (&["networking.k8s.io", "extensions"], "Ingress"),
+    (&["apiregistration.k8s.io"], "APIService"),
+];
+
+/// The groups and kinds of the resources that are applied after all others.
+const APPLY_LAST: &[(&[&str], &str)] = &[
+    (&["admissionregistration.k8s.io"], "MutatingWebhookConfiguration"),
+    (&["admissionregistration.k8s.io"], "ValidatingWebhookConfiguration"),
+];
+
+/// Sorts the given objects into the order in which they should be applied to a cluster.
+///
+/// The sort is stable, so objects of the same kind stay in their original order.
+pub fn sort_for_apply<O>(objects: &mut [O]) where O: std::ops::Deref<Target = dyn AnyObject> {
+    objects.sort_by_key(|object| apply_rank(object.api_version(), object.kind()));
+}
+
+/// Sorts the given objects into the order in which they should be deleted from a cluster, which is the reverse of the order in which they are applied.
+///
+/// The sort is stable, so objects of the same kind stay in their original order.
+pub fn sort_for_delete<O>(objects: &mut [O]) where O: std::ops::Deref<Target = dyn AnyObject> {
+    objects.sort_by_key(|object| std::cmp::Reverse(apply_rank(object.api_version(), object.kind())));
+}
+
+fn apply_rank(api_version: &str, kind: &str) -> usize {
+    let group = api_version.rsplit_once('/').map_or("", |(group, _)| group);
+    let position = |order: &[(&[&str], &str)]| order.iter().position(|&(groups, k)| k == kind && groups.contains(&group));
+
+    if let Some(position) = position(APPLY_ORDER) {
+        position
+    }
+    else if let Some(position) = position(APPLY_LAST) {
+        APPLY_ORDER.len() + 1 + position
+    }
+    else {
+        APPLY_ORDER.len()
+    }
+}
@@ -0,0 +1,95 @@
+// Constructors and status accessors for the `authentication.k8s.io/v1` review and request types.
+
+use crate::api::authentication::v1::{TokenReview, TokenReviewSpec, UserInfo};
+
+impl TokenReview {
+    k8s_if_ge_1_13! {
+        /// Constructs a review of the given bearer token, to be authenticated for the given audiences.
+        ///
+        /// If `audiences` is empty, the API server authenticates the token for its own audience.
+        pub fn for_token<I>(token: impl Into<String>, audiences: I) -> Self
+        where
+            I: IntoIterator,
+            I::Item: Into<String>,
+        {
+            let audiences: Vec<String> = audiences.into_iter().map(Into::into).collect();
+
+            TokenReview {
+                spec: TokenReviewSpec {
+                    audiences: if audiences.is_empty() { None } else { Some(audiences) },
+                    token: Some(token.into()),
+                },
+                ..Default::default()
+            }
+        }
+    }
+
+    k8s_if_le_1_12! {
+        /// Constructs a review of the given bearer token.
+        pub fn for_token(token: impl Into<String>) -> Self {
+            TokenReview {
+                spec: TokenReviewSpec {
+                    token: Some(token.into()),
+                },
+                ..Default::default()
+            }
+        }
+    }
+
+    /// Returns `true` if the API server reported that the token was successfully authenticated.
+    pub fn is_authenticated(&self) -> bool {
+        matches!(&self.status, Some(status) if status.authenticated == Some(true))
+    }
+
+    /// The user that the token was authenticated as, if it was authenticated.
+    pub fn user(&self) -> Option<&UserInfo> {
+        if self.is_authenticated() {
+            self.status.as_ref()?.user.as_ref()
+        }
+        else {
+            None
+        }
+    }
+
+    /// The error that the API server reported while authenticating the token, if any.
+    pub fn error(&self) -> Option<&str> {
+        self.status.as_ref()?.error.as_deref().filter(|error| !error.is_empty())
+    }
+}
+
+k8s_if_ge_1_16! {
+    use crate::api::authentication::v1::TokenRequest;
+
+    impl TokenRequest {
+        /// Constructs a request for a service account token for the given audiences, that expires after the given duration.
+        ///
+        /// If `expiration` is `None`, the API server uses its default expiration. The API server may also issue a token
+        /// with a different expiration than the one requested, so check [`TokenRequest::expiration_timestamp`] on the response.
+        #[cfg(feature = "chrono")]
+        pub fn new<I>(audiences: I, expiration: Option<chrono::Duration>) -> Self
+        where
+            I: IntoIterator,
+            I::Item: Into<String>,
+        {
+            TokenRequest {
+                spec: crate::api::authentication::v1::TokenRequestSpec {
+                    audiences: audiences.into_iter().map(Into::into).collect(),
+                    expiration_seconds: expiration.map(|expiration| expiration.num_seconds()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        }
+
+        /// The token that was issued by the API server, if any.
+        pub fn token(&self) -> Option<&str> {
+            self.status.as_ref().map(|status| &*status.token).filter(|token| !token.is_empty())
+        }
+
+        /// The time at which the issued token expires, if a token was issued.
+        #[cfg(feature = "chrono")]
+        pub fn expiration_timestamp(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+            self.status.as_ref().map(|status| status.expiration_timestamp.0)
+        }
+    }
+}
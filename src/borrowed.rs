@@ -0,0 +1,372 @@
+//! Views of objects and lists that borrow their strings from the input instead of allocating them.
+//!
+//! Deserializing a large list, like the `PodList` of the initial list of an informer, allocates a `String` for every name, label key and label value
+//! of every object in it. The types of this module only contain the identifying metadata of objects, which is all that code like the cache of
+//! an informer needs to index them, and their strings are [`Cow`]s that borrow from the input when they can.
+//! All other fields of the objects, like their `spec` and `status`, are skipped without being deserialized.
+//!
+//! Strings are only borrowed when the deserializer can hand out strings from the input as they are, like `serde_json::from_slice` and `serde_json::from_str` can
+//! for strings that don't contain escape sequences. Otherwise they're allocated as usual. In particular, [`WatchEvent`](crate::apimachinery::pkg::apis::meta::v1::WatchEvent)
+//! buffers its object before deserializing it, so the strings of the objects of watch events are always allocated.
+//!
+//! ```rust
+//! use k8s_openapi::borrowed::{ListRef, ObjectRef};
+//!
+//! let json = br#"{
+//!     "apiVersion": "v1",
+//!     "kind": "PodList",
+//!     "metadata": { "resourceVersion": "1234" },
+//!     "items": [
+//!         { "metadata": { "name": "web-0", "namespace": "default", "labels": { "app": "web" } }, "spec": { "containers": [] } },
+//!         { "metadata": { "name": "web-1", "namespace": "default", "labels": { "app": "web" } }, "spec": { "containers": [] } }
+//!     ]
+//! }"#;
+//!
+//! let list: ListRef<'_, ObjectRef<'_>> = k8s_openapi::serde_json::from_slice(json).unwrap();
+//! assert_eq!(list.metadata.resource_version.as_deref(), Some("1234"));
+//!
+//! let names: Vec<_> = list.items.iter().filter_map(|pod| pod.metadata.name.as_deref()).collect();
+//! assert_eq!(names, ["web-0", "web-1"]);
+//!
+//! // The strings point into `json`
+//! assert!(matches!(list.items[0].metadata.name, Some(std::borrow::Cow::Borrowed(_))));
+//!
+//! // Objects that need to outlive the input can be converted to owned metadata.
+//! let metadata = list.items[1].metadata.to_object_meta();
+//! assert_eq!(metadata.labels.unwrap()["app"], "web");
+//! ```
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+/// A view of an object that only contains its `apiVersion`, `kind` and `metadata`.
+///
+/// The `apiVersion` and `kind` are empty if the object doesn't have them, like the items of lists returned by the API server.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ObjectRef<'a> {
+    pub api_version: Cow<'a, str>,
+
+    pub kind: Cow<'a, str>,
+
+    pub metadata: ObjectMetaRef<'a>,
+}
+
+/// A view of the identifying fields of an [`ObjectMeta`](crate::apimachinery::pkg::apis::meta::v1::ObjectMeta).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ObjectMetaRef<'a> {
+    pub annotations: Option<BTreeMap<Cow<'a, str>, Cow<'a, str>>>,
+
+    pub finalizers: Option<Vec<Cow<'a, str>>>,
+
+    pub generate_name: Option<Cow<'a, str>>,
+
+    pub generation: Option<i64>,
+
+    pub labels: Option<BTreeMap<Cow<'a, str>, Cow<'a, str>>>,
+
+    pub name: Option<Cow<'a, str>>,
+
+    pub namespace: Option<Cow<'a, str>>,
+
+    pub resource_version: Option<Cow<'a, str>>,
+
+    pub uid: Option<Cow<'a, str>>,
+}
+
+impl ObjectMetaRef<'_> {
+    /// Converts this view into an owned `ObjectMeta` with the same fields. The fields that this view doesn't contain are left unset.
+    pub fn to_object_meta(&self) -> crate::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+        fn to_map(map: &BTreeMap<Cow<'_, str>, Cow<'_, str>>) -> BTreeMap<String, String> {
+            map.iter().map(|(key, value)| (key.clone().into_owned(), value.clone().into_owned())).collect()
+        }
+
+        crate::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+            annotations: self.annotations.as_ref().map(to_map),
+            finalizers: self.finalizers.as_ref().map(|finalizers| finalizers.iter().map(|finalizer| finalizer.clone().into_owned()).collect()),
+            generate_name: self.generate_name.clone().map(Cow::into_owned),
+            generation: self.generation,
+            labels: self.labels.as_ref().map(to_map),
+            name: self.name.clone().map(Cow::into_owned),
+            namespace: self.namespace.clone().map(Cow::into_owned),
+            resource_version: self.resource_version.clone().map(Cow::into_owned),
+            uid: self.uid.clone().map(Cow::into_owned),
+            ..Default::default()
+        }
+    }
+}
+
+/// A view of a list, like a `PodList`, whose items are deserialized as `T`, such as [`ObjectRef`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ListRef<'a, T> {
+    pub api_version: Cow<'a, str>,
+
+    pub kind: Cow<'a, str>,
+
+    pub metadata: ListMetaRef<'a>,
+
+    pub items: Vec<T>,
+}
+
+/// A view of a [`ListMeta`](crate::apimachinery::pkg::apis::meta::v1::ListMeta).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ListMetaRef<'a> {
+    pub continue_: Option<Cow<'a, str>>,
+
+    pub remaining_item_count: Option<i64>,
+
+    pub resource_version: Option<Cow<'a, str>>,
+}
+
+impl<'de: 'a, 'a> serde::Deserialize<'de> for ObjectRef<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        const FIELDS: &[&str] = &[
+            "apiVersion",
+            "kind",
+            "metadata",
+        ];
+
+        struct Visitor<'a>(std::marker::PhantomData<&'a ()>);
+
+        impl<'de: 'a, 'a> serde::de::Visitor<'de> for Visitor<'a> {
+            type Value = ObjectRef<'a>;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("an object")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de> {
+                let mut value = ObjectRef::default();
+
+                while let Some(key) = serde::de::MapAccess::next_key_seed(&mut map, FieldIndex(FIELDS))? {
+                    match key {
+                        Some(0) => value.api_version = serde::de::MapAccess::next_value::<Str<'a>>(&mut map)?.0,
+                        Some(1) => value.kind = serde::de::MapAccess::next_value::<Str<'a>>(&mut map)?.0,
+                        Some(2) => value.metadata = serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: serde::de::IgnoredAny = serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(value)
+            }
+        }
+
+        deserializer.deserialize_struct("ObjectRef", FIELDS, Visitor(Default::default()))
+    }
+}
+
+impl<'de: 'a, 'a> serde::Deserialize<'de> for ObjectMetaRef<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        const FIELDS: &[&str] = &[
+            "annotations",
+            "finalizers",
+            "generateName",
+            "generation",
+            "labels",
+            "name",
+            "namespace",
+            "resourceVersion",
+            "uid",
+        ];
+
+        struct Visitor<'a>(std::marker::PhantomData<&'a ()>);
+
+        impl<'de: 'a, 'a> serde::de::Visitor<'de> for Visitor<'a> {
+            type Value = ObjectMetaRef<'a>;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ObjectMeta")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de> {
+                let mut value = ObjectMetaRef::default();
+
+                while let Some(key) = serde::de::MapAccess::next_key_seed(&mut map, FieldIndex(FIELDS))? {
+                    match key {
+                        Some(0) => value.annotations = serde::de::MapAccess::next_value::<Option<StrMap<'a>>>(&mut map)?.map(|map| map.0),
+                        Some(1) => value.finalizers = serde::de::MapAccess::next_value::<Option<Vec<Str<'a>>>>(&mut map)?.map(|finalizers| finalizers.into_iter().map(|finalizer| finalizer.0).collect()),
+                        Some(2) => value.generate_name = serde::de::MapAccess::next_value::<Option<Str<'a>>>(&mut map)?.map(|s| s.0),
+                        Some(3) => value.generation = serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value.labels = serde::de::MapAccess::next_value::<Option<StrMap<'a>>>(&mut map)?.map(|map| map.0),
+                        Some(5) => value.name = serde::de::MapAccess::next_value::<Option<Str<'a>>>(&mut map)?.map(|s| s.0),
+                        Some(6) => value.namespace = serde::de::MapAccess::next_value::<Option<Str<'a>>>(&mut map)?.map(|s| s.0),
+                        Some(7) => value.resource_version = serde::de::MapAccess::next_value::<Option<Str<'a>>>(&mut map)?.map(|s| s.0),
+                        Some(8) => value.uid = serde::de::MapAccess::next_value::<Option<Str<'a>>>(&mut map)?.map(|s| s.0),
+                        _ => { let _: serde::de::IgnoredAny = serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(value)
+            }
+        }
+
+        deserializer.deserialize_struct("ObjectMetaRef", FIELDS, Visitor(Default::default()))
+    }
+}
+
+impl<'de: 'a, 'a, T> serde::Deserialize<'de> for ListRef<'a, T> where T: serde::Deserialize<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        const FIELDS: &[&str] = &[
+            "apiVersion",
+            "kind",
+            "metadata",
+            "items",
+        ];
+
+        struct Visitor<'a, T>(std::marker::PhantomData<(&'a (), T)>);
+
+        impl<'de: 'a, 'a, T> serde::de::Visitor<'de> for Visitor<'a, T> where T: serde::Deserialize<'de> {
+            type Value = ListRef<'a, T>;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a list")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de> {
+                let mut api_version = Cow::Borrowed("");
+                let mut kind = Cow::Borrowed("");
+                let mut metadata = ListMetaRef::default();
+                let mut items = None;
+
+                while let Some(key) = serde::de::MapAccess::next_key_seed(&mut map, FieldIndex(FIELDS))? {
+                    match key {
+                        Some(0) => api_version = serde::de::MapAccess::next_value::<Str<'a>>(&mut map)?.0,
+                        Some(1) => kind = serde::de::MapAccess::next_value::<Str<'a>>(&mut map)?.0,
+                        Some(2) => metadata = serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => items = serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: serde::de::IgnoredAny = serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(ListRef {
+                    api_version,
+                    kind,
+                    metadata,
+                    items: items.ok_or_else(|| serde::de::Error::missing_field("items"))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct("ListRef", FIELDS, Visitor(Default::default()))
+    }
+}
+
+impl<'de: 'a, 'a> serde::Deserialize<'de> for ListMetaRef<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        const FIELDS: &[&str] = &[
+            "continue",
+            "remainingItemCount",
+            "resourceVersion",
+        ];
+
+        struct Visitor<'a>(std::marker::PhantomData<&'a ()>);
+
+        impl<'de: 'a, 'a> serde::de::Visitor<'de> for Visitor<'a> {
+            type Value = ListMetaRef<'a>;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ListMeta")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de> {
+                let mut value = ListMetaRef::default();
+
+                while let Some(key) = serde::de::MapAccess::next_key_seed(&mut map, FieldIndex(FIELDS))? {
+                    match key {
+                        Some(0) => value.continue_ = serde::de::MapAccess::next_value::<Option<Str<'a>>>(&mut map)?.map(|s| s.0),
+                        Some(1) => value.remaining_item_count = serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value.resource_version = serde::de::MapAccess::next_value::<Option<Str<'a>>>(&mut map)?.map(|s| s.0),
+                        _ => { let _: serde::de::IgnoredAny = serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(value)
+            }
+        }
+
+        deserializer.deserialize_struct("ListMetaRef", FIELDS, Visitor(Default::default()))
+    }
+}
+
+/// Like [`crate::serde2::FieldIndex`], but without reporting unknown fields to the unknown field handler,
+/// since the views skip most of the fields of the objects by design.
+struct FieldIndex(&'static [&'static str]);
+
+impl<'de> serde::de::DeserializeSeed<'de> for FieldIndex {
+    type Value = Option<usize>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: serde::Deserializer<'de> {
+        struct Visitor(&'static [&'static str]);
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = Option<usize>;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("field identifier")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: serde::de::Error {
+                Ok(self.0.iter().position(|field| *field == v))
+            }
+        }
+
+        deserializer.deserialize_identifier(Visitor(self.0))
+    }
+}
+
+/// A string that is borrowed from the input if the deserializer allows it.
+struct Str<'a>(Cow<'a, str>);
+
+impl<'de: 'a, 'a> serde::Deserialize<'de> for Str<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        struct Visitor<'a>(std::marker::PhantomData<&'a ()>);
+
+        impl<'de: 'a, 'a> serde::de::Visitor<'de> for Visitor<'a> {
+            type Value = Str<'a>;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a string")
+            }
+
+            fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E> where E: serde::de::Error {
+                Ok(Str(Cow::Borrowed(v)))
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: serde::de::Error {
+                Ok(Str(Cow::Owned(v.to_owned())))
+            }
+
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E> where E: serde::de::Error {
+                Ok(Str(Cow::Owned(v)))
+            }
+        }
+
+        deserializer.deserialize_str(Visitor(Default::default()))
+    }
+}
+
+/// A map of [`Str`]s, like the labels and annotations of an object.
+struct StrMap<'a>(BTreeMap<Cow<'a, str>, Cow<'a, str>>);
+
+impl<'de: 'a, 'a> serde::Deserialize<'de> for StrMap<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        struct Visitor<'a>(std::marker::PhantomData<&'a ()>);
+
+        impl<'de: 'a, 'a> serde::de::Visitor<'de> for Visitor<'a> {
+            type Value = StrMap<'a>;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a map of strings")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de> {
+                let mut result = BTreeMap::new();
+                while let Some((Str(key), Str(value))) = serde::de::MapAccess::next_entry(&mut map)? {
+                    result.insert(key, value);
+                }
+                Ok(StrMap(result))
+            }
+        }
+
+        deserializer.deserialize_map(Visitor(Default::default()))
+    }
+}
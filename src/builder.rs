@@ -0,0 +1,33 @@
+/// The error returned by the `build` functions of the builders of the generated types, like [`crate::api::core::v1::PodSpecBuilder::build`],
+/// when a required field of the type has not been set.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MissingFieldError {
+    type_name: &'static str,
+    field: &'static str,
+}
+
+impl MissingFieldError {
+    /// Creates an error for the given required field of the given type, such as for the builders of types that are generated
+    /// by other code generators.
+    pub fn new(type_name: &'static str, field: &'static str) -> Self {
+        MissingFieldError { type_name, field }
+    }
+
+    /// The name of the type that was being built, like `PodSpec`.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The name of the required field that was not set, as it is serialized, like `containers`.
+    pub fn field(&self) -> &'static str {
+        self.field
+    }
+}
+
+impl std::fmt::Display for MissingFieldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{} is required but was not set", self.type_name, self.field)
+    }
+}
+
+impl std::error::Error for MissingFieldError {}
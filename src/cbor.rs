@@ -0,0 +1,129 @@
+//! The CBOR encoding of objects in the Kubernetes API, the `application/cbor` content type that Kubernetes 1.32 and later support.
+//!
+//! The API server encodes objects in CBOR with the same structure as in JSON, so objects are serialized in their human-readable form,
+//! like `apiVersion` and `kind` being included and `IntOrString` being either an integer or a string. However CBOR libraries like `ciborium`
+//! are not human-readable formats as far as serde is concerned, and serializing with them directly would produce the compact form
+//! that this crate uses for formats like bincode instead. So the functions of this module should be used to encode and decode objects.
+//!
+//! The only difference from the JSON form is that [`ByteString`](crate::ByteString)s are encoded as CBOR byte strings instead of base64-encoded strings.
+//! Encoded objects are preceded by the self-described CBOR tag, like the API server does.
+//!
+//! ```rust
+//! use k8s_openapi::api::core::v1::Secret;
+//!
+//! let secret = Secret {
+//!     metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+//!         name: Some("foo".to_owned()),
+//!         ..Default::default()
+//!     },
+//!     data: Some(std::iter::once(("password".to_owned(), k8s_openapi::ByteString(b"hunter2".to_vec()))).collect()),
+//!     ..Default::default()
+//! };
+//!
+//! let buf = k8s_openapi::cbor::to_vec(&secret).unwrap();
+//! assert!(buf.starts_with(&k8s_openapi::cbor::SELF_DESCRIBED_TAG));
+//!
+//! let decoded: Secret = k8s_openapi::cbor::from_slice(&buf).unwrap();
+//! assert_eq!(decoded, secret);
+//! ```
+
+/// The content type of the CBOR encoding, for the `Accept` and `Content-Type` headers of requests.
+pub const CONTENT_TYPE: &str = "application/cbor";
+
+/// The bytes of the self-described CBOR tag (55799) that precedes every encoded object.
+pub const SELF_DESCRIBED_TAG: [u8; 3] = [0xd9, 0xd9, 0xf7];
+
+thread_local! {
+    static ENCODING: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+/// Whether a value is being serialized as part of [`to_vec`], in which case [`ByteString`](crate::ByteString)s serialize their bytes as they are.
+pub(crate) fn is_encoding() -> bool {
+    ENCODING.with(std::cell::Cell::get)
+}
+
+/// Encodes the object in CBOR, preceded by [`SELF_DESCRIBED_TAG`].
+pub fn to_vec<T>(value: &T) -> Result<Vec<u8>, EncodeError> where T: serde::Serialize + ?Sized {
+    struct Guard(bool);
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            ENCODING.with(|encoding| encoding.set(self.0));
+        }
+    }
+
+    // `serde_value` is a human-readable format, so this captures the human-readable form of the object for `ciborium` to encode.
+    let value = {
+        let _guard = Guard(ENCODING.with(|encoding| encoding.replace(true)));
+        serde_value::to_value(value).map_err(EncodeError::Serialize)?
+    };
+
+    let mut buf = SELF_DESCRIBED_TAG.to_vec();
+    ciborium::ser::into_writer(&value, &mut buf).map_err(EncodeError::Encode)?;
+    Ok(buf)
+}
+
+/// Decodes an object from CBOR, like the body of a response of the API server to a request that accepted [`CONTENT_TYPE`].
+///
+/// The buffer may or may not start with [`SELF_DESCRIBED_TAG`].
+pub fn from_slice<T>(buf: &[u8]) -> Result<T, DecodeError> where T: serde::de::DeserializeOwned {
+    let buf = buf.strip_prefix(&SELF_DESCRIBED_TAG[..]).unwrap_or(buf);
+    let value: serde_value::Value = ciborium::de::from_reader(buf).map_err(DecodeError::Decode)?;
+    value.deserialize_into().map_err(DecodeError::Deserialize)
+}
+
+/// The error returned by [`to_vec`].
+#[derive(Debug)]
+pub enum EncodeError {
+    /// The object failed to serialize.
+    Serialize(serde_value::SerializerError),
+
+    /// The serialized object could not be encoded in CBOR.
+    Encode(ciborium::ser::Error<std::io::Error>),
+}
+
+impl std::fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EncodeError::Serialize(err) => write!(f, "could not serialize object: {err}"),
+            EncodeError::Encode(err) => write!(f, "could not encode object: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for EncodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EncodeError::Serialize(err) => Some(err),
+            EncodeError::Encode(err) => Some(err),
+        }
+    }
+}
+
+/// The error returned by [`from_slice`].
+#[derive(Debug)]
+pub enum DecodeError {
+    /// The buffer is not valid CBOR.
+    Decode(ciborium::de::Error<std::io::Error>),
+
+    /// The decoded value could not be deserialized as the object, such as because it is missing a required field.
+    Deserialize(serde_value::DeserializerError),
+}
+
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecodeError::Decode(err) => write!(f, "could not decode CBOR: {err}"),
+            DecodeError::Deserialize(err) => write!(f, "could not deserialize object: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DecodeError::Decode(err) => Some(err),
+            DecodeError::Deserialize(err) => Some(err),
+        }
+    }
+}
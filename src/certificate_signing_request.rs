@@ -0,0 +1,94 @@
+// Helpers for `certificates.k8s.io/v1` `CertificateSigningRequest`, for clients that request certificates and for controllers that approve or sign them.
+
+use crate::api::certificates::v1::{CertificateSigningRequest, CertificateSigningRequestCondition, CertificateSigningRequestSpec};
+
+const PEM_CERTIFICATE_BEGIN: &str = "-----BEGIN CERTIFICATE-----";
+const PEM_CERTIFICATE_END: &str = "-----END CERTIFICATE-----";
+
+impl CertificateSigningRequest {
+    /// Constructs a CSR object with the given name from a PEM-encoded PKCS#10 certificate request.
+    ///
+    /// `signer_name` is the signer that is requested to sign the certificate, such as `kubernetes.io/kube-apiserver-client`,
+    /// and `usages` are the requested key usages, such as `client auth` or `digital signature`.
+    pub fn from_pem<I>(name: impl Into<String>, request_pem: impl Into<Vec<u8>>, signer_name: impl Into<String>, usages: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        CertificateSigningRequest {
+            metadata: crate::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+                name: Some(name.into()),
+                ..Default::default()
+            },
+            spec: CertificateSigningRequestSpec {
+                request: crate::ByteString(request_pem.into()),
+                signer_name: signer_name.into(),
+                usages: Some(usages.into_iter().map(Into::into).collect()),
+                ..Default::default()
+            },
+            status: None,
+        }
+    }
+
+    /// Gets the condition of the given type, such as `Approved`, `Denied` or `Failed`, if the CSR has one.
+    pub fn condition(&self, type_: &str) -> Option<&CertificateSigningRequestCondition> {
+        self.status.as_ref()?
+            .conditions.as_ref()?
+            .iter()
+            .find(|condition| condition.type_ == type_)
+    }
+
+    /// Returns `true` if the CSR has an `Approved` condition with status `True`.
+    pub fn is_approved(&self) -> bool {
+        self.has_true_condition("Approved")
+    }
+
+    /// Returns `true` if the CSR has a `Denied` condition with status `True`.
+    pub fn is_denied(&self) -> bool {
+        self.has_true_condition("Denied")
+    }
+
+    /// Returns `true` if the CSR has a `Failed` condition with status `True`, ie the signer failed to issue the certificate.
+    pub fn is_failed(&self) -> bool {
+        self.has_true_condition("Failed")
+    }
+
+    /// The PEM-encoded certificate chain issued by the signer, if any.
+    ///
+    /// The signer sets this once the CSR is approved. It is a concatenation of PEM `CERTIFICATE` blocks,
+    /// starting with the issued certificate and followed by any intermediate certificates.
+    pub fn issued_certificate(&self) -> Option<&[u8]> {
+        let certificate = &self.status.as_ref()?.certificate.as_ref()?.0;
+        if certificate.is_empty() {
+            None
+        }
+        else {
+            Some(certificate)
+        }
+    }
+
+    /// Splits the issued certificate chain into its individual PEM `CERTIFICATE` blocks, in order.
+    ///
+    /// Each returned string is a complete PEM block including its `BEGIN` and `END` lines. Any other PEM blocks and any text between blocks are ignored.
+    /// Returns an empty `Vec` if no certificate has been issued or if it is not valid UTF-8.
+    pub fn issued_certificate_chain(&self) -> Vec<&str> {
+        let mut result = vec![];
+        let mut rest = self.issued_certificate().and_then(|certificate| std::str::from_utf8(certificate).ok()).unwrap_or_default();
+        while let Some(begin) = rest.find(PEM_CERTIFICATE_BEGIN) {
+            let block = &rest[begin..];
+            if let Some(end) = block.find(PEM_CERTIFICATE_END) {
+                let end = end + PEM_CERTIFICATE_END.len();
+                result.push(&block[..end]);
+                rest = &block[end..];
+            }
+            else {
+                break;
+            }
+        }
+        result
+    }
+
+    fn has_true_condition(&self, type_: &str) -> bool {
+        matches!(self.condition(type_), Some(condition) if condition.status == "True")
+    }
+}
@@ -0,0 +1,151 @@
+//! Helpers for the `conditions` of the status of resources, in the unified form of [`Condition`], matching the `meta` package of apimachinery.
+//!
+//! The conditions of most built-in resources predate `meta/v1` `Condition` and have their own types, like [`PodCondition`](crate::api::core::v1::PodCondition)
+//! and [`DeploymentCondition`](crate::api::apps::v1::DeploymentCondition). These types can be converted to and from `Condition` with `From`,
+//! so that code that reports or inspects conditions can be written once for all resources.
+//!
+//! ```rust
+//! use k8s_openapi::api::apps::v1::DeploymentCondition;
+//! use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+//!
+//! let deployment_conditions = vec![DeploymentCondition {
+//!     type_: "Available".to_owned(),
+//!     status: "True".to_owned(),
+//!     ..Default::default()
+//! }];
+//!
+//! let conditions: Vec<Condition> = deployment_conditions.into_iter().map(Into::into).collect();
+//! assert!(k8s_openapi::conditions::is_true(&conditions, "Available"));
+//! ```
+//!
+//! Ref: <https://github.com/kubernetes/apimachinery/blob/master/pkg/api/meta/conditions.go>
+
+use crate::apimachinery::pkg::apis::meta::v1::{Condition, Time};
+
+/// Finds the condition of the given type.
+pub fn find<'a>(conditions: &'a [Condition], type_: &str) -> Option<&'a Condition> {
+    conditions.iter().find(|condition| condition.type_ == type_)
+}
+
+/// Returns `true` if there is a condition of the given type with the status `True`.
+pub fn is_true(conditions: &[Condition], type_: &str) -> bool {
+    is_present_and_equal(conditions, type_, "True")
+}
+
+/// Returns `true` if there is a condition of the given type with the status `False`.
+///
+/// This is not the same as `!is_true(..)`, since a condition can also be absent or have the status `Unknown`.
+pub fn is_false(conditions: &[Condition], type_: &str) -> bool {
+    is_present_and_equal(conditions, type_, "False")
+}
+
+/// Returns `true` if there is a condition of the given type with the given status.
+pub fn is_present_and_equal(conditions: &[Condition], type_: &str, status: &str) -> bool {
+    find(conditions, type_).is_some_and(|condition| condition.status == status)
+}
+
+/// Adds the given condition, or updates the existing condition of the same type to it.
+///
+/// The `lastTransitionTime` of an existing condition is only updated if its status changes, so the given condition should have the current time
+/// as its `lastTransitionTime`. Returns `true` if the conditions were changed.
+pub fn set(conditions: &mut Vec<Condition>, new_condition: Condition) -> bool {
+    let Some(existing) = conditions.iter_mut().find(|condition| condition.type_ == new_condition.type_) else {
+        conditions.push(new_condition);
+        return true;
+    };
+
+    let mut changed = false;
+
+    if existing.status != new_condition.status {
+        existing.status = new_condition.status;
+        existing.last_transition_time = new_condition.last_transition_time;
+        changed = true;
+    }
+
+    if existing.reason != new_condition.reason {
+        existing.reason = new_condition.reason;
+        changed = true;
+    }
+
+    if existing.message != new_condition.message {
+        existing.message = new_condition.message;
+        changed = true;
+    }
+
+    if existing.observed_generation != new_condition.observed_generation {
+        existing.observed_generation = new_condition.observed_generation;
+        changed = true;
+    }
+
+    changed
+}
+
+/// Removes the condition of the given type, if any. Returns `true` if a condition was removed.
+pub fn remove(conditions: &mut Vec<Condition>, type_: &str) -> bool {
+    let original_len = conditions.len();
+    conditions.retain(|condition| condition.type_ != type_);
+    conditions.len() != original_len
+}
+
+#[cfg(feature = "chrono")]
+fn unix_epoch() -> Time {
+    Time(std::time::UNIX_EPOCH.into())
+}
+
+#[cfg(not(feature = "chrono"))]
+fn unix_epoch() -> Time {
+    Time("1970-01-01T00:00:00Z".to_owned())
+}
+
+macro_rules! condition_conversions {
+    ($($condition:ty,)*) => {
+        $(
+            /// Conditions without a `lastTransitionTime` are converted with the Unix epoch as their `lastTransitionTime`,
+            /// since it is required by `Condition`. Missing reasons and messages are converted to empty strings.
+            impl From<$condition> for Condition {
+                fn from(condition: $condition) -> Self {
+                    Condition {
+                        last_transition_time: condition.last_transition_time.unwrap_or_else(unix_epoch),
+                        message: condition.message.unwrap_or_default(),
+                        observed_generation: None,
+                        reason: condition.reason.unwrap_or_default(),
+                        status: condition.status,
+                        type_: condition.type_,
+                    }
+                }
+            }
+
+            /// The `observedGeneration` of the condition is discarded. Empty reasons and messages are converted to `None`.
+            impl From<Condition> for $condition {
+                #[allow(clippy::needless_update)] // Some of the condition types have additional timestamps, but not all of them
+                fn from(condition: Condition) -> Self {
+                    Self {
+                        last_transition_time: Some(condition.last_transition_time),
+                        message: Some(condition.message).filter(|message| !message.is_empty()),
+                        reason: Some(condition.reason).filter(|reason| !reason.is_empty()),
+                        status: condition.status,
+                        type_: condition.type_,
+                        ..Default::default()
+                    }
+                }
+            }
+        )*
+    };
+}
+
+condition_conversions! {
+    crate::api::apps::v1::DaemonSetCondition,
+    crate::api::apps::v1::DeploymentCondition,
+    crate::api::apps::v1::ReplicaSetCondition,
+    crate::api::apps::v1::StatefulSetCondition,
+    crate::api::autoscaling::v2beta2::HorizontalPodAutoscalerCondition,
+    crate::api::batch::v1::JobCondition,
+    crate::api::certificates::v1::CertificateSigningRequestCondition,
+    crate::api::core::v1::NamespaceCondition,
+    crate::api::core::v1::NodeCondition,
+    crate::api::core::v1::PersistentVolumeClaimCondition,
+    crate::api::core::v1::PodCondition,
+    crate::api::core::v1::ReplicationControllerCondition,
+    crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionCondition,
+    crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceCondition,
+}
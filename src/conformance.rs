@@ -0,0 +1,266 @@
+//! Round-trip conformance checks for types that implement `serde::Deserialize` and `serde::Serialize`,
+//! such as the resource types of this crate or custom resource types generated with `k8s-openapi-derive`.
+//!
+//! A fixture is a JSON or YAML document of a value of the type, like a manifest of a resource. A fixture conforms if:
+//!
+//! - it can be deserialized as the type,
+//! - serializing the deserialized value produces the same JSON as the fixture, modulo formatting and the order of object keys, and
+//! - deserializing and serializing the serialized value again produces the same bytes.
+//!
+//! For example, a test that checks all the pod manifests in a crate's `tests/fixtures/pods` directory:
+//!
+//! ```rust,no_run
+//! k8s_openapi::conformance::assert_dir_roundtrips::<k8s_openapi::api::core::v1::Pod>(
+//!     concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/pods"));
+//! ```
+
+use std::fmt::Write;
+
+/// Asserts that every fixture in the given directory conforms as a value of type `T`.
+///
+/// Files with the extension `.json` are parsed as a single JSON document. Files with the extension `.yaml` or `.yml` may contain multiple YAML documents,
+/// each of which is checked separately. Other files and subdirectories are ignored.
+///
+/// # Panics
+///
+/// Panics if the directory cannot be read, or if any fixture in it does not conform. The panic message lists all the fixtures that do not conform.
+pub fn assert_dir_roundtrips<T>(dir: impl AsRef<std::path::Path>)
+where
+    T: serde::de::DeserializeOwned + serde::Serialize,
+{
+    let dir = dir.as_ref();
+
+    let mut failures = String::new();
+    let mut num_files = 0_usize;
+
+    let entries = std::fs::read_dir(dir).unwrap_or_else(|err| panic!("could not read fixtures directory {}: {err}", dir.display()));
+    let mut paths: Vec<_> =
+        entries
+        .map(|entry| entry.map_or_else(|err| panic!("could not read fixtures directory {}: {err}", dir.display()), |entry| entry.path()))
+        .filter(|path| path.is_file() && Format::of(path).is_some())
+        .collect();
+    paths.sort();
+
+    for path in paths {
+        num_files += 1;
+        if let Err(err) = check_file::<T>(&path) {
+            let _ = write!(failures, "\n{}: {err}", path.display());
+        }
+    }
+
+    assert!(num_files > 0, "fixtures directory {} does not contain any JSON or YAML files", dir.display());
+    assert!(failures.is_empty(), "some fixtures in {} do not roundtrip as {}:{failures}", dir.display(), std::any::type_name::<T>());
+}
+
+/// Checks that the given fixture file conforms as a value of type `T`.
+///
+/// The file is parsed as JSON or YAML according to its extension, like with [`assert_dir_roundtrips`].
+pub fn check_file<T>(path: impl AsRef<std::path::Path>) -> Result<(), RoundtripError>
+where
+    T: serde::de::DeserializeOwned + serde::Serialize,
+{
+    let path = path.as_ref();
+    let format = Format::of(path).ok_or(RoundtripError::UnknownExtension)?;
+    let contents = std::fs::read_to_string(path).map_err(RoundtripError::Io)?;
+    match format {
+        Format::Json => check_json::<T>(&contents),
+        Format::Yaml => check_yaml::<T>(&contents),
+    }
+}
+
+/// Checks that the given JSON document conforms as a value of type `T`.
+pub fn check_json<T>(json: &str) -> Result<(), RoundtripError>
+where
+    T: serde::de::DeserializeOwned + serde::Serialize,
+{
+    let value = serde_json::from_str(json).map_err(RoundtripError::Parse)?;
+    check_value::<T>(&value)
+}
+
+/// Checks that every document in the given YAML stream conforms as a value of type `T`.
+pub fn check_yaml<T>(yaml: &str) -> Result<(), RoundtripError>
+where
+    T: serde::de::DeserializeOwned + serde::Serialize,
+{
+    for (i, document) in serde_yaml::Deserializer::from_str(yaml).enumerate() {
+        let value = serde::Deserialize::deserialize(document).map_err(RoundtripError::ParseYaml)?;
+        check_value::<T>(&value).map_err(|err| RoundtripError::Document(i, Box::new(err)))?;
+    }
+
+    Ok(())
+}
+
+/// Checks that the given JSON value conforms as a value of type `T`.
+pub fn check_value<T>(value: &serde_json::Value) -> Result<(), RoundtripError>
+where
+    T: serde::de::DeserializeOwned + serde::Serialize,
+{
+    let deserialized: T = crate::deserialize_with_path(value).map_err(RoundtripError::Deserialize)?;
+
+    let serialized = serde_json::to_value(&deserialized).map_err(RoundtripError::Serialize)?;
+    let mut path = String::new();
+    if let Some((expected, actual)) = first_difference(value, &serialized, &mut path) {
+        if path.is_empty() {
+            path.push('.');
+        }
+        return Err(RoundtripError::Mismatch { path, expected, actual });
+    }
+
+    let serialized = serde_json::to_vec(&deserialized).map_err(RoundtripError::Serialize)?;
+    let deserialized: T = serde_json::from_slice(&serialized).map_err(RoundtripError::Parse)?;
+    let reserialized = serde_json::to_vec(&deserialized).map_err(RoundtripError::Serialize)?;
+    if serialized != reserialized {
+        return Err(RoundtripError::NotStable);
+    }
+
+    Ok(())
+}
+
+/// The type of errors returned by the conformance checks.
+#[derive(Debug)]
+pub enum RoundtripError {
+    /// The fixture could not be deserialized as the type. The error contains the path of the field that could not be deserialized.
+    Deserialize(crate::DeserializeError<serde_json::Error>),
+
+    /// An error in the YAML document at the given index of a fixture containing multiple YAML documents.
+    Document(usize, Box<RoundtripError>),
+
+    /// The fixture file could not be read.
+    Io(std::io::Error),
+
+    /// The value serialized from the fixture is different from the fixture.
+    Mismatch {
+        /// The path of the first field that is different, like `.spec.containers[0].image`
+        path: String,
+
+        /// The value of the field in the fixture, or `None` if the fixture does not have the field.
+        expected: Option<serde_json::Value>,
+
+        /// The value of the field in the value serialized from the fixture, or `None` if it does not have the field.
+        actual: Option<serde_json::Value>,
+    },
+
+    /// Serializing the value deserialized from its own serialization produced different bytes.
+    NotStable,
+
+    /// The fixture is not valid JSON.
+    Parse(serde_json::Error),
+
+    /// The fixture is not valid YAML.
+    ParseYaml(serde_yaml::Error),
+
+    /// The deserialized value could not be serialized.
+    Serialize(serde_json::Error),
+
+    /// The fixture file does not have a `.json`, `.yaml` or `.yml` extension.
+    UnknownExtension,
+}
+
+impl std::fmt::Display for RoundtripError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RoundtripError::Deserialize(err) => write!(f, "could not deserialize fixture: {err}"),
+            RoundtripError::Document(i, err) => write!(f, "document #{}: {err}", i + 1),
+            RoundtripError::Io(err) => write!(f, "could not read fixture: {err}"),
+            RoundtripError::Mismatch { path, expected, actual } => {
+                let field = |value: &Option<serde_json::Value>| value.as_ref().map_or_else(|| "no value".to_owned(), ToString::to_string);
+                write!(f, "serialized value differs from fixture at {path}: expected {}, got {}", field(expected), field(actual))
+            },
+            RoundtripError::NotStable => f.write_str("serialized value is not stable across another roundtrip"),
+            RoundtripError::Parse(err) => write!(f, "could not parse fixture as JSON: {err}"),
+            RoundtripError::ParseYaml(err) => write!(f, "could not parse fixture as YAML: {err}"),
+            RoundtripError::Serialize(err) => write!(f, "could not serialize value: {err}"),
+            RoundtripError::UnknownExtension => f.write_str("fixture is neither a JSON nor a YAML file"),
+        }
+    }
+}
+
+impl std::error::Error for RoundtripError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RoundtripError::Deserialize(err) => Some(err),
+            RoundtripError::Document(_, err) => Some(&**err),
+            RoundtripError::Io(err) => Some(err),
+            RoundtripError::Parse(err) |
+            RoundtripError::Serialize(err) => Some(err),
+            RoundtripError::ParseYaml(err) => Some(err),
+            RoundtripError::Mismatch { .. } |
+            RoundtripError::NotStable |
+            RoundtripError::UnknownExtension => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Format {
+    Json,
+    Yaml,
+}
+
+impl Format {
+    fn of(path: &std::path::Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "json" => Some(Format::Json),
+            "yaml" | "yml" => Some(Format::Yaml),
+            _ => None,
+        }
+    }
+}
+
+/// Finds the first difference between the two values, and returns its path along with the differing values.
+///
+/// A value is `None` if the corresponding object does not have the field at all.
+fn first_difference(
+    expected: &serde_json::Value,
+    actual: &serde_json::Value,
+    path: &mut String,
+) -> Option<(Option<serde_json::Value>, Option<serde_json::Value>)> {
+    match (expected, actual) {
+        (serde_json::Value::Object(expected), serde_json::Value::Object(actual)) => {
+            let mut keys: Vec<_> = expected.keys().chain(actual.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                path.push('.');
+                path.push_str(key);
+                match (expected.get(key), actual.get(key)) {
+                    (Some(expected), Some(actual)) => {
+                        let len = path.len() - key.len() - 1;
+                        if let Some(difference) = first_difference(expected, actual, path) {
+                            return Some(difference);
+                        }
+                        path.truncate(len);
+                    },
+
+                    (expected, actual) => return Some((expected.cloned(), actual.cloned())),
+                }
+            }
+            None
+        },
+
+        (serde_json::Value::Array(expected_items), serde_json::Value::Array(actual_items)) => {
+            for (i, (expected, actual)) in expected_items.iter().zip(actual_items).enumerate() {
+                let len = path.len();
+                let _ = write!(path, "[{i}]");
+                if let Some(difference) = first_difference(expected, actual, path) {
+                    return Some(difference);
+                }
+                path.truncate(len);
+            }
+
+            if expected_items.len() == actual_items.len() {
+                None
+            }
+            else {
+                Some((Some(expected.clone()), Some(actual.clone())))
+            }
+        },
+
+        // Compare numbers by value, so that eg `1.0` in the fixture matches `1` in the serialized value
+        (serde_json::Value::Number(expected_number), serde_json::Value::Number(actual_number)) if expected_number.as_f64() == actual_number.as_f64() => None,
+
+        (expected, actual) if expected == actual => None,
+
+        (expected, actual) => Some((Some(expected.clone()), Some(actual.clone()))),
+    }
+}
@@ -0,0 +1,161 @@
+// Content hashes of `ConfigMap`s and `Secret`s, matching the name suffixes that kustomize's generators and `kubectl create --append-hash` add to them.
+//
+// Naming a config map or secret after its contents makes pods that refer to it by name roll out whenever its contents change.
+//
+// Ref: https://github.com/kubernetes/kubectl/blob/master/pkg/util/hash/hash.go
+
+use crate::api::core::v1::{ConfigMap, Secret};
+
+impl ConfigMap {
+    /// The hash of the name and contents of this config map that kustomize and `kubectl create configmap --append-hash` append to its name,
+    /// like `9g67k2htb6`.
+    ///
+    /// The hash is computed from the name before the suffix is appended, so it must be computed before [`ConfigMap::append_content_hash_to_name`].
+    pub fn content_hash(&self) -> String {
+        let mut fields = vec![
+            ("data", self.data.as_ref().map_or_else(|| "null".to_owned(), |data| json_object(data.iter().map(|(key, value)| (&**key, json_string(value)))))),
+            ("kind", json_string("ConfigMap")),
+            ("name", json_string(self.metadata.name.as_deref().unwrap_or_default())),
+        ];
+
+        if let Some(binary_data) = self.binary_data.as_ref().filter(|binary_data| !binary_data.is_empty()) {
+            fields.push(("binaryData", json_object(binary_data.iter().map(|(key, value)| (&**key, json_string(&base64::encode(&value.0)))))));
+        }
+
+        k8s_if_ge_1_18! {
+            if let Some(immutable) = self.immutable {
+                fields.push(("immutable", immutable.to_string()));
+            }
+        }
+
+        fields.sort_by_key(|&(key, _)| key);
+        content_hash(&json_object(fields))
+    }
+
+    /// Appends the [content hash](ConfigMap::content_hash) of this config map to its name.
+    ///
+    /// ```rust
+    /// use k8s_openapi::api::core::v1::ConfigMap;
+    /// use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+    ///
+    /// let mut config_map = ConfigMap {
+    ///     data: Some(std::iter::once(("index.html".to_owned(), "<html>\n".to_owned())).collect()),
+    ///     metadata: ObjectMeta {
+    ///         name: Some("app-config".to_owned()),
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    /// config_map.append_content_hash_to_name();
+    /// assert_eq!(config_map.metadata.name.as_deref(), Some("app-config-5577gm4k2k"));
+    /// ```
+    pub fn append_content_hash_to_name(&mut self) {
+        let content_hash = self.content_hash();
+        self.metadata.name = Some(format!("{}-{content_hash}", self.metadata.name.as_deref().unwrap_or_default()));
+    }
+}
+
+impl Secret {
+    /// The hash of the name, type and contents of this secret that kustomize and `kubectl create secret --append-hash` append to its name.
+    ///
+    /// Only the `data` of the secret is hashed, not its `stringData`, since the generators of kustomize and kubectl only set the former.
+    ///
+    /// The hash is computed from the name before the suffix is appended, so it must be computed before [`Secret::append_content_hash_to_name`].
+    pub fn content_hash(&self) -> String {
+        let mut fields = vec![
+            ("data", self.data.as_ref().map_or_else(|| "null".to_owned(), |data| json_object(data.iter().map(|(key, value)| (&**key, json_string(&base64::encode(&value.0))))))),
+            ("kind", json_string("Secret")),
+            ("name", json_string(self.metadata.name.as_deref().unwrap_or_default())),
+            ("type", json_string(self.type_.as_deref().unwrap_or_default())),
+        ];
+
+        k8s_if_ge_1_18! {
+            if let Some(immutable) = self.immutable {
+                fields.push(("immutable", immutable.to_string()));
+            }
+        }
+
+        fields.sort_by_key(|&(key, _)| key);
+        content_hash(&json_object(fields))
+    }
+
+    /// Appends the [content hash](Secret::content_hash) of this secret to its name.
+    ///
+    /// ```rust
+    /// use k8s_openapi::api::core::v1::Secret;
+    /// use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+    /// use k8s_openapi::ByteString;
+    ///
+    /// let mut secret = Secret {
+    ///     data: Some(std::iter::once(("password".to_owned(), ByteString(b"hunter2".to_vec()))).collect()),
+    ///     metadata: ObjectMeta {
+    ///         name: Some("db-credentials".to_owned()),
+    ///         ..Default::default()
+    ///     },
+    ///     type_: Some("Opaque".to_owned()),
+    ///     ..Default::default()
+    /// };
+    /// secret.append_content_hash_to_name();
+    /// assert_eq!(secret.metadata.name.as_deref(), Some("db-credentials-kkk92bdc2d"));
+    /// ```
+    pub fn append_content_hash_to_name(&mut self) {
+        let content_hash = self.content_hash();
+        self.metadata.name = Some(format!("{}-{content_hash}", self.metadata.name.as_deref().unwrap_or_default()));
+    }
+}
+
+/// Encodes the first 10 hex digits of the SHA-256 of the given JSON, with the digits and vowels that could form words replaced by consonants.
+fn content_hash(json: &str) -> String {
+    use sha2::Digest;
+
+    sha2::Sha256::digest(json)[..5].iter()
+        .flat_map(|b| [b >> 4, b & 0x0f])
+        .map(|nibble| match nibble {
+            0x0 => 'g',
+            0x1 => 'h',
+            0x3 => 'k',
+            0xa => 'm',
+            0xe => 't',
+            nibble => std::char::from_digit(nibble.into(), 16).unwrap(),
+        })
+        .collect()
+}
+
+/// Formats the given keys and JSON values as a JSON object, in the given order.
+fn json_object<'a>(fields: impl IntoIterator<Item = (&'a str, String)>) -> String {
+    let mut result = String::from("{");
+    for (i, (key, value)) in fields.into_iter().enumerate() {
+        if i > 0 {
+            result.push(',');
+        }
+        result.push_str(&json_string(key));
+        result.push(':');
+        result.push_str(&value);
+    }
+    result.push('}');
+    result
+}
+
+/// Formats the given string as a JSON string the same way as Go's `json.Marshal`, which also escapes HTML characters and line separators.
+fn json_string(s: &str) -> String {
+    let mut result = String::with_capacity(s.len() + 2);
+    result.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            '\u{8}' => result.push_str("\\b"),
+            '\u{c}' => result.push_str("\\f"),
+            c if c < ' ' || c == '<' || c == '>' || c == '&' || c == '\u{2028}' || c == '\u{2029}' => {
+                use std::fmt::Write;
+                write!(result, "\\u{:04x}", u32::from(c)).unwrap();
+            },
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
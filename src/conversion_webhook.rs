@@ -0,0 +1,231 @@
+//! Helpers for implementing custom resource conversion webhooks.
+//!
+//! The API server sends a [`ConversionReview`] with a [`ConversionRequest`] to the webhook, and expects a [`ConversionReview`]
+//! with a [`ConversionResponse`] in return.
+//!
+//! ```rust,ignore
+//! let review: ConversionReview = serde_json::from_slice(&request_body)?;
+//!
+//! let mut conversions =
+//!     k8s_openapi::conversion_webhook::Conversions::new()
+//!     .register(|foo: v1::Foo| -> Result<v2::Foo, String> { ... })
+//!     .register(|foo: v2::Foo| -> Result<v1::Foo, String> { ... });
+//!
+//! let response_review = review.respond(&mut conversions);
+//! let response_body = serde_json::to_vec(&response_review)?;
+//! ```
+
+use crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::{ConversionRequest, ConversionResponse, ConversionReview};
+use crate::apimachinery::pkg::apis::meta::v1::Status;
+use crate::apimachinery::pkg::runtime::RawExtension;
+
+/// A set of typed conversion functions between versions of a custom resource.
+///
+/// Each function is registered for a pair of API versions, taken from the [`crate::Resource::API_VERSION`] of its input and output types.
+/// Objects whose `apiVersion` is already the desired API version are passed through unchanged.
+#[derive(Default)]
+pub struct Conversions<'a> {
+    conversions: Vec<Conversion<'a>>,
+}
+
+struct Conversion<'a> {
+    from: &'static str,
+    to: &'static str,
+    f: Box<dyn FnMut(serde_json::Value) -> Result<serde_json::Value, ConversionError> + 'a>,
+}
+
+impl<'a> Conversions<'a> {
+    /// Constructs an empty set of conversions.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Registers a function that converts a resource of type `T` into a resource of type `U`.
+    ///
+    /// If a function was already registered for the same pair of API versions, it is replaced.
+    #[must_use]
+    pub fn register<T, U, F, E>(mut self, mut f: F) -> Self
+    where
+        T: crate::Resource + serde::de::DeserializeOwned,
+        U: crate::Resource + serde::Serialize,
+        F: FnMut(T) -> Result<U, E> + 'a,
+        E: std::fmt::Display,
+    {
+        self.conversions.retain(|conversion| conversion.from != T::API_VERSION || conversion.to != U::API_VERSION);
+        self.conversions.push(Conversion {
+            from: T::API_VERSION,
+            to: U::API_VERSION,
+            f: Box::new(move |object| {
+                let object: T = serde_json::from_value(object).map_err(ConversionError::Deserialize)?;
+                let object = f(object).map_err(|err| ConversionError::Convert(err.to_string()))?;
+                let object = serde_json::to_value(object).map_err(ConversionError::Serialize)?;
+                Ok(object)
+            }),
+        });
+        self
+    }
+
+    /// Converts a single object to the given API version.
+    pub fn convert(&mut self, object: serde_json::Value, desired_api_version: &str) -> Result<serde_json::Value, ConversionError> {
+        let api_version = object.get("apiVersion").and_then(serde_json::Value::as_str).ok_or(ConversionError::MissingApiVersion)?;
+
+        if api_version == desired_api_version {
+            return Ok(object);
+        }
+
+        match self.conversions.iter_mut().find(|conversion| conversion.from == api_version && conversion.to == desired_api_version) {
+            Some(conversion) => (conversion.f)(object),
+            None => Err(ConversionError::Unsupported {
+                from: api_version.to_owned(),
+                to: desired_api_version.to_owned(),
+            }),
+        }
+    }
+}
+
+impl std::fmt::Debug for Conversions<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.conversions.iter().map(|conversion| (conversion.from, conversion.to))).finish()
+    }
+}
+
+/// The type of errors from converting an object in a [`ConversionRequest`].
+#[derive(Debug)]
+pub enum ConversionError {
+    /// The conversion function returned an error.
+    Convert(String),
+
+    /// The object could not be deserialized into the input type of the conversion function.
+    Deserialize(serde_json::Error),
+
+    /// The object does not have an `apiVersion` field.
+    MissingApiVersion,
+
+    /// The output of the conversion function could not be serialized.
+    Serialize(serde_json::Error),
+
+    /// No conversion function was registered for the object's API version and the desired API version.
+    Unsupported {
+        from: String,
+        to: String,
+    },
+}
+
+impl std::fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConversionError::Convert(message) => f.write_str(message),
+            ConversionError::Deserialize(err) => write!(f, "could not deserialize object: {err}"),
+            ConversionError::MissingApiVersion => f.write_str("object does not have an apiVersion"),
+            ConversionError::Serialize(err) => write!(f, "could not serialize converted object: {err}"),
+            ConversionError::Unsupported { from, to } => write!(f, "conversion from {from} to {to} is not supported"),
+        }
+    }
+}
+
+impl std::error::Error for ConversionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConversionError::Deserialize(err) |
+            ConversionError::Serialize(err) => Some(err),
+            ConversionError::Convert(_) |
+            ConversionError::MissingApiVersion |
+            ConversionError::Unsupported { .. } => None,
+        }
+    }
+}
+
+impl ConversionReview {
+    /// Constructs a review that carries the given response, to be sent back to the API server.
+    ///
+    /// The API server requires the `apiVersion` and `kind` of the review, so they are set to `apiextensions.k8s.io/v1` and `ConversionReview`.
+    pub fn from_response(response: ConversionResponse) -> Self {
+        ConversionReview {
+            api_version: Some("apiextensions.k8s.io/v1".to_owned()),
+            kind: Some("ConversionReview".to_owned()),
+            request: None,
+            response: Some(response),
+        }
+    }
+
+    /// Constructs the review to send back to the API server for this review's request, converting each object with the given conversions.
+    ///
+    /// See [`ConversionRequest::respond_with`] for details.
+    #[must_use]
+    pub fn respond(&self, conversions: &mut Conversions<'_>) -> Self {
+        self.respond_with(|object, desired_api_version| conversions.convert(object.0.clone(), desired_api_version).map(RawExtension))
+    }
+
+    /// Constructs the review to send back to the API server for this review's request, converting each object with the given function.
+    ///
+    /// If this review does not have a request, the response is a failure with an empty UID.
+    #[must_use]
+    pub fn respond_with<F, E>(&self, f: F) -> Self
+    where
+        F: FnMut(&RawExtension, &str) -> Result<RawExtension, E>,
+        E: std::fmt::Display,
+    {
+        let response = match &self.request {
+            Some(request) => request.respond_with(f),
+            None => ConversionResponse::failure(String::new(), "ConversionReview does not have a request"),
+        };
+        ConversionReview::from_response(response)
+    }
+}
+
+impl ConversionRequest {
+    /// Iterates over the objects to be converted, along with their `apiVersion` if they have one.
+    pub fn objects_with_api_version(&self) -> impl Iterator<Item = (Option<&str>, &RawExtension)> {
+        self.objects.iter().map(|object| (object.0.get("apiVersion").and_then(serde_json::Value::as_str), object))
+    }
+
+    /// Constructs the response for this request, converting each object to the desired API version with the given function.
+    ///
+    /// The function is called with each object and the desired API version. The response copies the UID of this request.
+    ///
+    /// The API server requires that either all objects are converted or none are, so the response is a failure
+    /// with the message of the first error if the function fails for any object.
+    pub fn respond_with<F, E>(&self, mut f: F) -> ConversionResponse
+    where
+        F: FnMut(&RawExtension, &str) -> Result<RawExtension, E>,
+        E: std::fmt::Display,
+    {
+        let converted_objects: Result<Vec<_>, _> = self.objects.iter().map(|object| f(object, &self.desired_api_version)).collect();
+        match converted_objects {
+            Ok(converted_objects) => ConversionResponse::success(self.uid.clone(), converted_objects),
+            Err(err) => ConversionResponse::failure(self.uid.clone(), err),
+        }
+    }
+}
+
+impl ConversionResponse {
+    /// Constructs a successful response with the given converted objects.
+    pub fn success(uid: String, converted_objects: Vec<RawExtension>) -> Self {
+        ConversionResponse {
+            converted_objects,
+            result: Status {
+                status: Some("Success".to_owned()),
+                ..Default::default()
+            },
+            uid,
+        }
+    }
+
+    /// Constructs a failed response with the given error message.
+    pub fn failure(uid: String, message: impl std::fmt::Display) -> Self {
+        ConversionResponse {
+            converted_objects: vec![],
+            result: Status {
+                message: Some(message.to_string()),
+                status: Some("Failure".to_owned()),
+                ..Default::default()
+            },
+            uid,
+        }
+    }
+
+    /// Returns `true` if the response indicates that the conversion succeeded.
+    pub fn is_success(&self) -> bool {
+        self.result.status.as_deref() == Some("Success")
+    }
+}
@@ -0,0 +1,62 @@
+// `std::str::FromStr` and `From` impls for small value types, so that they can be parsed from command-line arguments and config files,
+// and constructed from literals.
+
+use crate::apimachinery::pkg::api::resource::Quantity;
+use crate::apimachinery::pkg::util::intstr::IntOrString;
+
+/// Parses the string as an `IntOrString::Int` if it is a valid `i32`, like `8080`, and as an `IntOrString::String` otherwise, like `http` or `25%`.
+impl std::str::FromStr for IntOrString {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.parse().map_or_else(|_| IntOrString::String(s.to_owned()), IntOrString::Int))
+    }
+}
+
+/// Wraps the string as a quantity. The string is not validated, the same as when a quantity is deserialized.
+impl std::str::FromStr for Quantity {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Quantity(s.to_owned()))
+    }
+}
+
+/// Wraps the string as an `IntOrString::String`, even if it is a valid integer. Use `str::parse` to parse integers as `IntOrString::Int` instead.
+impl From<&str> for IntOrString {
+    fn from(s: &str) -> Self {
+        IntOrString::String(s.to_owned())
+    }
+}
+
+/// Wraps the string as an `IntOrString::String`, even if it is a valid integer. Use `str::parse` to parse integers as `IntOrString::Int` instead.
+impl From<String> for IntOrString {
+    fn from(s: String) -> Self {
+        IntOrString::String(s)
+    }
+}
+
+impl From<i32> for IntOrString {
+    fn from(i: i32) -> Self {
+        IntOrString::Int(i)
+    }
+}
+
+impl From<&str> for Quantity {
+    fn from(s: &str) -> Self {
+        Quantity(s.to_owned())
+    }
+}
+
+impl From<String> for Quantity {
+    fn from(s: String) -> Self {
+        Quantity(s)
+    }
+}
+
+/// A quantity of the given number of whole units, like `3` CPUs.
+impl From<i32> for Quantity {
+    fn from(i: i32) -> Self {
+        Quantity(i.to_string())
+    }
+}
@@ -0,0 +1,386 @@
+// Schedules of `CronJob`s, matching the standard parser of github.com/robfig/cron/v3 that the CronJob controller uses.
+//
+// Ref: https://pkg.go.dev/github.com/robfig/cron/v3
+
+use chrono::{Datelike, Timelike};
+
+/// A parsed cron schedule, like the `schedule` of a `CronJob`.
+///
+/// This accepts the same schedules as the API server:
+///
+/// - Five fields for the minute, hour, day of the month, month and day of the week, like `*/15 9-17 * * MON-FRI`.
+///   Each field is `*` or `?`, or a comma-separated list of values, ranges like `1-5`, and steps like `*/15`, `1-30/2` or `5/10`.
+///   Months and days of the week can also be given as their case-insensitive three-letter names, like `JAN` or `sun`.
+///
+/// - The descriptors `@yearly`, `@annually`, `@monthly`, `@weekly`, `@daily`, `@midnight` and `@hourly`,
+///   and `@every <duration>` with a Go duration like `1h30m`.
+///
+/// Like the CronJob controller, a schedule matches a day if its day of the month *or* its day of the week matches,
+/// unless either of those fields starts with `*` or `?`, in which case both must match.
+///
+/// The schedule may be prefixed with `CRON_TZ=<zone>` or `TZ=<zone>` to evaluate it in a time zone other than the controller's.
+/// Since this crate does not bundle a time zone database, only the `UTC`, `Etc/UTC` and `Local` zones are supported.
+///
+/// ```rust
+/// let schedule: k8s_openapi::CronSchedule = "30 9 * * MON-FRI".parse().unwrap();
+///
+/// // Saturday
+/// let now: k8s_openapi::chrono::DateTime<k8s_openapi::chrono::Utc> = "2021-08-07T12:00:00Z".parse().unwrap();
+/// assert_eq!(schedule.next_after(&now), Some("2021-08-09T09:30:00Z".parse().unwrap()));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CronSchedule {
+    schedule: String,
+    kind: Kind,
+    utc: bool,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Kind {
+    Fields {
+        minute: u64,
+        hour: u64,
+        day_of_month: u64,
+        month: u64,
+        day_of_week: u64,
+
+        /// Whether the day of the month field started with `*` or `?`.
+        day_of_month_star: bool,
+
+        /// Whether the day of the week field started with `*` or `?`.
+        day_of_week_star: bool,
+    },
+
+    Every(chrono::Duration),
+}
+
+impl CronSchedule {
+    /// The first time after the given time that matches this schedule, evaluated in the time zone of the given time
+    /// unless the schedule is prefixed with a time zone.
+    ///
+    /// Returns `None` if the schedule does not match any time in the next five years, such as `0 0 30 2 *`.
+    ///
+    /// Local times that are skipped by a daylight saving time transition never match. Local times that occur twice match the first time they occur.
+    pub fn next_after<Tz>(&self, time: &chrono::DateTime<Tz>) -> Option<chrono::DateTime<Tz>> where Tz: chrono::TimeZone {
+        if self.utc {
+            let next = self.next_after_in_time_zone(&time.with_timezone(&chrono::Utc))?;
+            return Some(next.with_timezone(&time.timezone()));
+        }
+
+        self.next_after_in_time_zone(time)
+    }
+
+    fn next_after_in_time_zone<Tz>(&self, time: &chrono::DateTime<Tz>) -> Option<chrono::DateTime<Tz>> where Tz: chrono::TimeZone {
+        let (minute, hour, day_of_month, month, day_of_week, day_of_month_star, day_of_week_star) = match self.kind {
+            Kind::Fields { minute, hour, day_of_month, month, day_of_week, day_of_month_star, day_of_week_star } =>
+                (minute, hour, day_of_month, month, day_of_week, day_of_month_star, day_of_week_star),
+
+            // Same as robfig's ConstantDelaySchedule, which ignores the sub-second part of the time.
+            Kind::Every(delay) => return Some(time.with_nanosecond(0)? + delay),
+        };
+
+        let time_zone = time.timezone();
+        let local = time.naive_local();
+        let mut next = local.date().and_hms_opt(local.hour(), local.minute(), 0)? + chrono::Duration::minutes(1);
+        let year_limit = next.year() + 5;
+
+        while next.year() <= year_limit {
+            if !has_bit(month, next.month()) {
+                let (year, month) = if next.month() == 12 { (next.year() + 1, 1) } else { (next.year(), next.month() + 1) };
+                next = chrono::NaiveDate::from_ymd_opt(year, month, 1)?.and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+
+            let day_of_month_matches = has_bit(day_of_month, next.day());
+            let day_of_week_matches = has_bit(day_of_week, next.weekday().num_days_from_sunday());
+            let day_matches =
+                if day_of_month_star || day_of_week_star { day_of_month_matches && day_of_week_matches }
+                else { day_of_month_matches || day_of_week_matches };
+            if !day_matches {
+                next = next.date().succ_opt()?.and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+
+            if !has_bit(hour, next.hour()) {
+                next = next.date().and_hms_opt(next.hour(), 0, 0)? + chrono::Duration::hours(1);
+                continue;
+            }
+
+            if has_bit(minute, next.minute()) {
+                match time_zone.from_local_datetime(&next) {
+                    chrono::LocalResult::Single(result) => return Some(result),
+                    chrono::LocalResult::Ambiguous(earliest, latest) => {
+                        if earliest > *time {
+                            return Some(earliest);
+                        }
+                        if latest > *time {
+                            return Some(latest);
+                        }
+                    },
+                    chrono::LocalResult::None => (),
+                }
+            }
+
+            next += chrono::Duration::minutes(1);
+        }
+
+        None
+    }
+}
+
+impl std::fmt::Display for CronSchedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.schedule)
+    }
+}
+
+impl std::str::FromStr for CronSchedule {
+    type Err = ParseCronScheduleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = |message: String| ParseCronScheduleError { schedule: s.to_owned(), message };
+
+        let mut spec = s.trim();
+        let mut utc = false;
+        if spec.starts_with("TZ=") || spec.starts_with("CRON_TZ=") {
+            let end = spec.find(char::is_whitespace).unwrap_or(spec.len());
+            let (_, time_zone) = spec[..end].split_at(spec.find('=').unwrap_or_default() + 1);
+            match time_zone {
+                "UTC" | "Etc/UTC" => utc = true,
+                "Local" => (),
+                time_zone => return Err(err(format!("time zone {time_zone:?} is not supported, only UTC is"))),
+            }
+            spec = spec[end..].trim_start();
+        }
+
+        let spec = match spec {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            spec => {
+                if let Some(duration) = spec.strip_prefix("@every ") {
+                    let delay = parse_go_duration(duration.trim()).ok_or_else(|| err(format!("invalid duration {duration:?}")))?;
+                    let delay = std::cmp::max(chrono::Duration::seconds(delay.num_seconds()), chrono::Duration::seconds(1));
+                    return Ok(CronSchedule { schedule: s.to_owned(), kind: Kind::Every(delay), utc });
+                }
+
+                if spec.starts_with('@') {
+                    return Err(err(format!("unrecognized descriptor {spec:?}")));
+                }
+
+                spec
+            },
+        };
+
+        let fields: Vec<_> = spec.split_whitespace().collect();
+        let [minute, hour, day_of_month, month, day_of_week] = fields[..] else {
+            return Err(err(format!("expected exactly 5 fields, found {}", fields.len())));
+        };
+
+        let (minute, _) = parse_field(minute, &MINUTE).map_err(err)?;
+        let (hour, _) = parse_field(hour, &HOUR).map_err(err)?;
+        let (day_of_month, day_of_month_star) = parse_field(day_of_month, &DAY_OF_MONTH).map_err(err)?;
+        let (month, _) = parse_field(month, &MONTH).map_err(err)?;
+        let (day_of_week, day_of_week_star) = parse_field(day_of_week, &DAY_OF_WEEK).map_err(err)?;
+
+        Ok(CronSchedule {
+            schedule: s.to_owned(),
+            kind: Kind::Fields { minute, hour, day_of_month, month, day_of_week, day_of_month_star, day_of_week_star },
+            utc,
+        })
+    }
+}
+
+/// The error returned when parsing an invalid [`CronSchedule`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseCronScheduleError {
+    schedule: String,
+    message: String,
+}
+
+impl std::fmt::Display for ParseCronScheduleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid cron schedule {:?}: {}", self.schedule, self.message)
+    }
+}
+
+impl std::error::Error for ParseCronScheduleError {}
+
+struct Bounds {
+    name: &'static str,
+    min: u32,
+    max: u32,
+    names: &'static [&'static str],
+}
+
+const MINUTE: Bounds = Bounds { name: "minute", min: 0, max: 59, names: &[] };
+const HOUR: Bounds = Bounds { name: "hour", min: 0, max: 23, names: &[] };
+const DAY_OF_MONTH: Bounds = Bounds { name: "day of month", min: 1, max: 31, names: &[] };
+const MONTH: Bounds = Bounds {
+    name: "month",
+    min: 1,
+    max: 12,
+    names: &["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"],
+};
+const DAY_OF_WEEK: Bounds = Bounds { name: "day of week", min: 0, max: 6, names: &["sun", "mon", "tue", "wed", "thu", "fri", "sat"] };
+
+fn has_bit(bits: u64, value: u32) -> bool {
+    bits & (1 << value) != 0
+}
+
+/// Parses a comma-separated field into the bitset of the values it matches, and whether it matches all values because it starts with `*` or `?`.
+fn parse_field(field: &str, bounds: &Bounds) -> Result<(u64, bool), String> {
+    let mut bits = 0;
+    let mut star = false;
+
+    for range in field.split(',') {
+        let (range_bits, range_star) = parse_range(range, bounds)?;
+        bits |= range_bits;
+        star |= range_star;
+    }
+
+    Ok((bits, star))
+}
+
+fn parse_range(range: &str, bounds: &Bounds) -> Result<(u64, bool), String> {
+    let (low_and_high, step) = match range.find('/') {
+        Some(pos) => (&range[..pos], Some(&range[(pos + 1)..])),
+        None => (range, None),
+    };
+
+    let (mut start, mut end, mut star) =
+        if low_and_high == "*" || low_and_high == "?" {
+            (bounds.min, bounds.max, true)
+        }
+        else {
+            match low_and_high.find('-') {
+                Some(pos) => (parse_value(&low_and_high[..pos], bounds)?, parse_value(&low_and_high[(pos + 1)..], bounds)?, false),
+                None => {
+                    let value = parse_value(low_and_high, bounds)?;
+                    (value, value, false)
+                },
+            }
+        };
+
+    let step = match step {
+        Some(step) => {
+            let step: u32 = step.parse().map_err(|_| format!("invalid step {step:?} of {} field", bounds.name))?;
+
+            // "N/step" means "N-max/step"
+            if !star && !low_and_high.contains('-') {
+                end = bounds.max;
+            }
+
+            if step > 1 {
+                star = false;
+            }
+
+            step
+        },
+        None => 1,
+    };
+
+    if start < bounds.min {
+        return Err(format!("{} field value {start} is below the minimum {}", bounds.name, bounds.min));
+    }
+    if end > bounds.max {
+        return Err(format!("{} field value {end} is above the maximum {}", bounds.name, bounds.max));
+    }
+    if start > end {
+        return Err(format!("{} field range {range:?} is empty", bounds.name));
+    }
+    if step == 0 {
+        return Err(format!("{} field step must be positive", bounds.name));
+    }
+
+    let mut bits = 0;
+    while start <= end {
+        bits |= 1 << start;
+        start += step;
+    }
+
+    Ok((bits, star))
+}
+
+fn parse_value(value: &str, bounds: &Bounds) -> Result<u32, String> {
+    if let Some(pos) = bounds.names.iter().position(|name| name.eq_ignore_ascii_case(value)) {
+        #[allow(clippy::cast_possible_truncation)]
+        return Ok(bounds.min + pos as u32);
+    }
+
+    value.parse().map_err(|_| format!("invalid {} field value {value:?}", bounds.name))
+}
+
+/// Parses a duration in the format of Go's `time.ParseDuration`, like `1h30m` or `1.5s`. Negative durations are not supported.
+fn parse_go_duration(mut s: &str) -> Option<chrono::Duration> {
+    if s == "0" {
+        return Some(chrono::Duration::zero());
+    }
+
+    if s.is_empty() {
+        return None;
+    }
+
+    let mut nanoseconds = 0_f64;
+
+    while !s.is_empty() {
+        let number_len = s.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(s.len());
+        let number: f64 = s[..number_len].parse().ok()?;
+        s = &s[number_len..];
+
+        let unit_len = s.find(|c: char| c.is_ascii_digit() || c == '.').unwrap_or(s.len());
+        let unit = match &s[..unit_len] {
+            "ns" => 1.,
+            "us" | "µs" | "μs" => 1_000.,
+            "ms" => 1_000_000.,
+            "s" => 1_000_000_000.,
+            "m" => 60_000_000_000.,
+            "h" => 3_600_000_000_000.,
+            _ => return None,
+        };
+        s = &s[unit_len..];
+
+        nanoseconds += number * unit;
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    Some(chrono::Duration::nanoseconds(nanoseconds as i64))
+}
+
+macro_rules! cron_job_impl {
+    ($cron_job:ty) => {
+        impl $cron_job {
+            /// Parses the schedule of this cron job.
+            ///
+            /// Returns `None` if the cron job does not have a spec.
+            pub fn parse_schedule(&self) -> Option<Result<CronSchedule, ParseCronScheduleError>> {
+                Some(self.spec.as_ref()?.schedule.parse())
+            }
+
+            /// The first time after the given time at which the CronJob controller will create a job for this cron job.
+            /// See [`CronSchedule::next_after`] for details.
+            ///
+            /// Returns `Ok(None)` if the cron job does not have a spec, if it is suspended, or if its schedule does not match any time in the next five years.
+            pub fn next_schedule_after<Tz>(&self, time: &chrono::DateTime<Tz>) -> Result<Option<chrono::DateTime<Tz>>, ParseCronScheduleError>
+            where
+                Tz: chrono::TimeZone,
+            {
+                let spec = match &self.spec {
+                    Some(spec) if spec.suspend != Some(true) => spec,
+                    _ => return Ok(None),
+                };
+
+                let schedule: CronSchedule = spec.schedule.parse()?;
+                Ok(schedule.next_after(time))
+            }
+        }
+    };
+}
+
+cron_job_impl!(crate::api::batch::v1beta1::CronJob);
+
+k8s_if_ge_1_21! {
+    cron_job_impl!(crate::api::batch::v1::CronJob);
+}
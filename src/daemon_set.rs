@@ -0,0 +1,90 @@
+// Rollout status of `apps/v1` `DaemonSet`, matching the logic of `kubectl rollout status`.
+//
+// Ref: https://github.com/kubernetes/kubectl/blob/master/pkg/polymorphichelpers/rollout_status.go
+
+use crate::api::apps::v1::DaemonSet;
+
+impl DaemonSet {
+    /// The status of the rollout of the daemon set's current spec, the same as `kubectl rollout status` reports it.
+    ///
+    /// The rollout is complete once a pod of the current spec is scheduled and available on every node that should run one.
+    pub fn rollout_status(&self) -> DaemonSetRolloutStatus {
+        let status = self.status.as_ref();
+
+        let update_strategy_type =
+            self.spec.as_ref()
+            .and_then(|spec| spec.update_strategy.as_ref())
+            .and_then(|update_strategy| update_strategy.type_.as_ref().map(AsRef::as_ref));
+        if !matches!(update_strategy_type, None | Some("RollingUpdate")) {
+            return DaemonSetRolloutStatus::NotRollingUpdate;
+        }
+
+        let observed_generation = status.and_then(|status| status.observed_generation).unwrap_or_default();
+        if self.metadata.generation.unwrap_or_default() > observed_generation {
+            return DaemonSetRolloutStatus::SpecUpdateNotObserved;
+        }
+
+        let desired_number_scheduled = status.map_or(0, |status| status.desired_number_scheduled);
+        let updated_number_scheduled = status.and_then(|status| status.updated_number_scheduled).unwrap_or_default();
+        let number_available = status.and_then(|status| status.number_available).unwrap_or_default();
+
+        if updated_number_scheduled < desired_number_scheduled {
+            return DaemonSetRolloutStatus::UpdatingPods { updated_number_scheduled, desired_number_scheduled };
+        }
+
+        if number_available < desired_number_scheduled {
+            return DaemonSetRolloutStatus::WaitingForAvailablePods { number_available, desired_number_scheduled };
+        }
+
+        DaemonSetRolloutStatus::Complete
+    }
+
+    /// Returns `true` if the rollout of the daemon set's current spec is complete. See [`DaemonSet::rollout_status`] for details.
+    pub fn is_rollout_complete(&self) -> bool {
+        self.rollout_status() == DaemonSetRolloutStatus::Complete
+    }
+}
+
+/// The status of the rollout of a [`DaemonSet`]. Returned by [`DaemonSet::rollout_status`].
+///
+/// The `Display` impl formats it the same way as the message of `kubectl rollout status`, without the name of the daemon set.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DaemonSetRolloutStatus {
+    /// The daemon set's update strategy is not `RollingUpdate`, such as `OnDelete`, so it does not have a rollout to wait for.
+    NotRollingUpdate,
+
+    /// The daemon set controller has not observed the daemon set's current spec yet, so its status is out of date.
+    SpecUpdateNotObserved,
+
+    /// Not all the nodes that should run a pod of the daemon set are running a pod of the current spec yet.
+    UpdatingPods {
+        updated_number_scheduled: i32,
+        desired_number_scheduled: i32,
+    },
+
+    /// All the nodes that should run a pod of the daemon set are running a pod of the current spec, but not all of them are available yet.
+    WaitingForAvailablePods {
+        number_available: i32,
+        desired_number_scheduled: i32,
+    },
+
+    /// The rollout is complete.
+    Complete,
+}
+
+impl std::fmt::Display for DaemonSetRolloutStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DaemonSetRolloutStatus::NotRollingUpdate =>
+                f.write_str("rollout status is only available for RollingUpdate strategy type"),
+            DaemonSetRolloutStatus::SpecUpdateNotObserved =>
+                f.write_str("Waiting for daemon set spec update to be observed..."),
+            DaemonSetRolloutStatus::UpdatingPods { updated_number_scheduled, desired_number_scheduled } =>
+                write!(f, "Waiting for daemon set rollout to finish: {updated_number_scheduled} out of {desired_number_scheduled} new pods have been updated..."),
+            DaemonSetRolloutStatus::WaitingForAvailablePods { number_available, desired_number_scheduled } =>
+                write!(f, "Waiting for daemon set rollout to finish: {number_available} of {desired_number_scheduled} updated pods are available..."),
+            DaemonSetRolloutStatus::Complete =>
+                f.write_str("daemon set successfully rolled out"),
+        }
+    }
+}
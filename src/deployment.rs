@@ -0,0 +1,115 @@
+// Rollout status of `apps/v1` `Deployment`, matching the logic of `kubectl rollout status`.
+//
+// Ref: https://github.com/kubernetes/kubectl/blob/master/pkg/polymorphichelpers/rollout_status.go
+
+use crate::api::apps::v1::{Deployment, DeploymentCondition};
+
+impl Deployment {
+    /// Gets the condition of the given type, such as `Available` or `Progressing`, if the deployment has one.
+    pub fn condition(&self, type_: &str) -> Option<&DeploymentCondition> {
+        self.status.as_ref()?
+            .conditions.as_ref()?
+            .iter()
+            .find(|condition| condition.type_ == type_)
+    }
+
+    /// Returns `true` if the deployment controller has given up on the current rollout because it did not make progress
+    /// within the deployment's `progressDeadlineSeconds`, ie the deployment's `Progressing` condition has the reason `ProgressDeadlineExceeded`.
+    pub fn is_progress_deadline_exceeded(&self) -> bool {
+        matches!(self.condition("Progressing"), Some(condition) if condition.reason.as_deref() == Some("ProgressDeadlineExceeded"))
+    }
+
+    /// The status of the rollout of the deployment's current spec, the same as `kubectl rollout status` reports it.
+    pub fn rollout_status(&self) -> DeploymentRolloutStatus {
+        let status = self.status.as_ref();
+
+        let observed_generation = status.and_then(|status| status.observed_generation).unwrap_or_default();
+        if self.metadata.generation.unwrap_or_default() > observed_generation {
+            return DeploymentRolloutStatus::SpecUpdateNotObserved;
+        }
+
+        if self.is_progress_deadline_exceeded() {
+            return DeploymentRolloutStatus::ProgressDeadlineExceeded;
+        }
+
+        let replicas = status.and_then(|status| status.replicas).unwrap_or_default();
+        let updated_replicas = status.and_then(|status| status.updated_replicas).unwrap_or_default();
+        let available_replicas = status.and_then(|status| status.available_replicas).unwrap_or_default();
+
+        // Like kubectl, this only waits for replicas to be updated if the spec has a number of replicas.
+        // The API server always sets it, to 1 by default.
+        if let Some(desired_replicas) = self.spec.as_ref().and_then(|spec| spec.replicas) {
+            if updated_replicas < desired_replicas {
+                return DeploymentRolloutStatus::UpdatingReplicas { updated_replicas, desired_replicas };
+            }
+        }
+
+        if replicas > updated_replicas {
+            return DeploymentRolloutStatus::TerminatingOldReplicas { old_replicas: replicas - updated_replicas };
+        }
+
+        if available_replicas < updated_replicas {
+            return DeploymentRolloutStatus::WaitingForAvailableReplicas { available_replicas, updated_replicas };
+        }
+
+        DeploymentRolloutStatus::Complete
+    }
+
+    /// Returns `true` if the rollout of the deployment's current spec is complete, ie the deployment controller has observed the current spec,
+    /// and all the desired replicas have been updated to it and are available, and no old replicas are left.
+    pub fn is_rollout_complete(&self) -> bool {
+        self.rollout_status() == DeploymentRolloutStatus::Complete
+    }
+}
+
+/// The status of the rollout of a [`Deployment`]. Returned by [`Deployment::rollout_status`].
+///
+/// The `Display` impl formats it the same way as the message of `kubectl rollout status`, without the name of the deployment.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DeploymentRolloutStatus {
+    /// The deployment controller has not observed the deployment's current spec yet, so its status is out of date.
+    SpecUpdateNotObserved,
+
+    /// The rollout did not make progress within the deployment's `progressDeadlineSeconds`.
+    /// The deployment controller does not retry the rollout until the deployment's spec is changed again.
+    ProgressDeadlineExceeded,
+
+    /// Not all the desired replicas have been updated to the current spec yet.
+    UpdatingReplicas {
+        updated_replicas: i32,
+        desired_replicas: i32,
+    },
+
+    /// All the desired replicas have been updated, but some replicas of the old spec are still being terminated.
+    TerminatingOldReplicas {
+        old_replicas: i32,
+    },
+
+    /// All the desired replicas have been updated, but not all of them are available yet.
+    WaitingForAvailableReplicas {
+        available_replicas: i32,
+        updated_replicas: i32,
+    },
+
+    /// The rollout is complete.
+    Complete,
+}
+
+impl std::fmt::Display for DeploymentRolloutStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeploymentRolloutStatus::SpecUpdateNotObserved =>
+                f.write_str("Waiting for deployment spec update to be observed..."),
+            DeploymentRolloutStatus::ProgressDeadlineExceeded =>
+                f.write_str("deployment exceeded its progress deadline"),
+            DeploymentRolloutStatus::UpdatingReplicas { updated_replicas, desired_replicas } =>
+                write!(f, "Waiting for deployment rollout to finish: {updated_replicas} out of {desired_replicas} new replicas have been updated..."),
+            DeploymentRolloutStatus::TerminatingOldReplicas { old_replicas } =>
+                write!(f, "Waiting for deployment rollout to finish: {old_replicas} old replicas are pending termination..."),
+            DeploymentRolloutStatus::WaitingForAvailableReplicas { available_replicas, updated_replicas } =>
+                write!(f, "Waiting for deployment rollout to finish: {available_replicas} of {updated_replicas} updated replicas are available..."),
+            DeploymentRolloutStatus::Complete =>
+                f.write_str("deployment successfully rolled out"),
+        }
+    }
+}
@@ -0,0 +1,415 @@
+//! Human-readable descriptions of objects in the style of `kubectl describe`, for command-line tools.
+//!
+//! ```rust
+//! use k8s_openapi::api::core::v1::{Pod, PodSpec, Container};
+//! use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+//! use k8s_openapi::describe::Describe;
+//!
+//! let pod = Pod {
+//!     metadata: ObjectMeta::builder().name("web-0").namespace("default").label("app", "web").build(),
+//!     spec: Some(PodSpec {
+//!         containers: vec![Container { name: "nginx".to_owned(), image: Some("nginx:1.21".to_owned()), ..Default::default() }],
+//!         ..Default::default()
+//!     }),
+//!     ..Default::default()
+//! };
+//!
+//! assert_eq!(pod.describe().to_string(), "\
+//! Name:         web-0
+//! Namespace:    default
+//! Labels:       app=web
+//! Annotations:  <none>
+//! Containers:
+//!   nginx:
+//!     Image:  nginx:1.21
+//! ");
+//! ```
+//!
+//! The descriptions are built only from the fields of the object itself. Unlike `kubectl describe`, they do not include related objects like events.
+
+use crate::api::apps::v1::Deployment;
+use crate::api::core::v1::{Container, ContainerStatus, Node, Pod, Service};
+use crate::apimachinery::pkg::apis::meta::v1::{LabelSelector, ObjectMeta, Time};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// A type whose values can be described in the style of `kubectl describe`.
+pub trait Describe {
+    /// Describes this value. The description is multi-line and ends with a newline.
+    fn describe(&self) -> Description;
+}
+
+/// The description of a value, returned by [`Describe::describe`]. Use its `std::fmt::Display` impl to print it.
+#[derive(Clone, Debug)]
+pub struct Description(Vec<Line>);
+
+#[derive(Clone, Debug)]
+enum Line {
+    /// A line like `Key:  value`. Lines with the same indentation have their values aligned.
+    /// A line with an empty key continues the value of the previous line, like the second label of `Labels:`.
+    ///
+    /// A nested line is indented further under the previous line, like the `Started:` of a container's `State:`,
+    /// but its value is still aligned with the lines of the given indentation.
+    Field { indent: usize, nested: bool, key: String, value: String },
+
+    /// A line that is printed as-is, like a heading or a row of a table.
+    Raw { indent: usize, text: String },
+}
+
+impl Description {
+    fn field(&mut self, indent: usize, key: &str, value: impl std::fmt::Display) {
+        self.0.push(Line::Field { indent, nested: false, key: key.to_owned(), value: value.to_string() });
+    }
+
+    fn nested_field(&mut self, indent: usize, key: &str, value: Option<impl std::fmt::Display>) {
+        if let Some(value) = value {
+            self.0.push(Line::Field { indent, nested: true, key: key.to_owned(), value: value.to_string() });
+        }
+    }
+
+    fn optional_field(&mut self, indent: usize, key: &str, value: Option<impl std::fmt::Display>) {
+        if let Some(value) = value {
+            self.field(indent, key, value);
+        }
+    }
+
+    fn heading(&mut self, indent: usize, key: &str) {
+        self.0.push(Line::Raw { indent, text: format!("{key}:") });
+    }
+
+    /// A field with one value per line, or `<none>` if there are no values.
+    fn list(&mut self, indent: usize, key: &str, values: impl IntoIterator<Item = String>) {
+        let mut values = values.into_iter();
+        self.field(indent, key, values.next().as_deref().unwrap_or("<none>"));
+        for value in values {
+            self.field(indent, "", value);
+        }
+    }
+
+    fn map(&mut self, indent: usize, key: &str, map: Option<&BTreeMap<String, String>>) {
+        self.list(indent, key, map.into_iter().flatten().map(|(key, value)| format!("{key}={value}")));
+    }
+
+    /// A table with the given column headers, underlined like `kubectl describe` does.
+    fn table(&mut self, indent: usize, headers: &[&str], rows: impl IntoIterator<Item = Vec<String>>) {
+        let underlines = headers.iter().map(|header| "-".repeat(header.len())).collect();
+        let rows: Vec<Vec<String>> =
+            std::iter::once(headers.iter().map(|&header| header.to_owned()).collect())
+            .chain(std::iter::once(underlines))
+            .chain(rows)
+            .collect();
+
+        let mut widths = vec![0; headers.len()];
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = std::cmp::max(*width, cell.len());
+            }
+        }
+
+        for row in rows {
+            let mut text = String::new();
+            for (i, (width, cell)) in widths.iter().zip(&row).enumerate() {
+                if i + 1 == row.len() {
+                    text.push_str(cell);
+                }
+                else {
+                    let _ = write!(text, "{cell:width$}  ");
+                }
+            }
+            self.0.push(Line::Raw { indent, text });
+        }
+    }
+
+    fn metadata(&mut self, metadata: &ObjectMeta) {
+        self.optional_field(0, "Name", metadata.name.as_ref());
+        self.optional_field(0, "Namespace", metadata.namespace.as_ref());
+    }
+
+    fn labels_and_annotations(&mut self, indent: usize, metadata: &ObjectMeta) {
+        self.map(indent, "Labels", metadata.labels.as_ref());
+        self.map(indent, "Annotations", metadata.annotations.as_ref());
+    }
+
+    fn containers(&mut self, indent: usize, containers: &[Container], statuses: Option<&[ContainerStatus]>) {
+        self.heading(indent, "Containers");
+        let indent = indent + 2;
+        for container in containers {
+            self.heading(indent, &container.name);
+            let indent = indent + 2;
+            self.optional_field(indent, "Image", container.image.as_ref());
+
+            let ports: Vec<_> =
+                container.ports.iter().flatten()
+                .map(|port| format!("{}/{}", port.container_port, port.protocol.as_ref().map_or("TCP", AsRef::as_ref)))
+                .collect();
+            if !ports.is_empty() {
+                self.field(indent, if ports.len() == 1 { "Port" } else { "Ports" }, ports.join(", "));
+            }
+
+            let status = statuses.into_iter().flatten().find(|status| status.name == container.name);
+            if let Some(status) = status {
+                let state = status.state.as_ref();
+                if let Some(running) = state.and_then(|state| state.running.as_ref()) {
+                    self.field(indent, "State", "Running");
+                    self.nested_field(indent, "Started", running.started_at.as_ref().map(format_time));
+                }
+                else if let Some(waiting) = state.and_then(|state| state.waiting.as_ref()) {
+                    self.field(indent, "State", "Waiting");
+                    self.nested_field(indent, "Reason", waiting.reason.as_ref());
+                }
+                else if let Some(terminated) = state.and_then(|state| state.terminated.as_ref()) {
+                    self.field(indent, "State", "Terminated");
+                    self.nested_field(indent, "Reason", terminated.reason.as_ref());
+                    self.nested_field(indent, "Exit Code", Some(terminated.exit_code));
+                    self.nested_field(indent, "Started", terminated.started_at.as_ref().map(format_time));
+                    self.nested_field(indent, "Finished", terminated.finished_at.as_ref().map(format_time));
+                }
+                self.field(indent, "Ready", if status.ready { "True" } else { "False" });
+                self.field(indent, "Restart Count", status.restart_count);
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Description {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The values of all fields with the same indentation are aligned two spaces after the longest of their keys.
+        let mut columns = BTreeMap::new();
+        for line in &self.0 {
+            if let Line::Field { indent, nested, key, .. } = line {
+                let column = columns.entry(*indent).or_insert(0);
+                *column = std::cmp::max(*column, nested_indent(*indent, *nested) + key.len() + 1 + 2);
+            }
+        }
+
+        for line in &self.0 {
+            match line {
+                Line::Field { indent, nested, key, value } => {
+                    let key = if key.is_empty() { String::new() } else { format!("{key}:") };
+                    let indent_ = nested_indent(*indent, *nested);
+                    writeln!(f, "{:indent_$}{key:width$}{value}", "", width = columns[indent] - indent_)?;
+                },
+                Line::Raw { indent, text } => writeln!(f, "{:indent$}{text}", "")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Describe for Pod {
+    fn describe(&self) -> Description {
+        let mut description = Description(vec![]);
+        let spec = self.spec.as_ref();
+        let status = self.status.as_ref();
+
+        description.metadata(&self.metadata);
+        if let Some(node_name) = spec.and_then(|spec| spec.node_name.as_ref()) {
+            match status.and_then(|status| status.host_ip.as_ref()) {
+                Some(host_ip) => description.field(0, "Node", format_args!("{node_name}/{host_ip}")),
+                None => description.field(0, "Node", node_name),
+            }
+        }
+        description.optional_field(0, "Start Time", status.and_then(|status| status.start_time.as_ref()).map(format_time));
+        description.labels_and_annotations(0, &self.metadata);
+        if self.metadata.deletion_timestamp.is_some() {
+            description.field(0, "Status", "Terminating");
+        }
+        else {
+            description.optional_field(0, "Status", status.and_then(|status| status.phase.as_ref()));
+        }
+        description.optional_field(0, "IP", status.and_then(|status| status.pod_ip.as_ref()));
+        description.optional_field(0, "Controlled By", controller(&self.metadata));
+
+        if let Some(spec) = spec {
+            description.containers(0, &spec.containers, status.and_then(|status| status.container_statuses.as_deref()));
+        }
+
+        if let Some(conditions) = status.and_then(|status| status.conditions.as_ref()) {
+            description.heading(0, "Conditions");
+            description.table(2, &["Type", "Status"], conditions.iter().map(|condition| vec![condition.type_.clone(), condition.status.clone()]));
+        }
+
+        description.optional_field(0, "QoS Class", status.and_then(|status| status.qos_class.as_ref()));
+
+        description
+    }
+}
+
+impl Describe for Deployment {
+    fn describe(&self) -> Description {
+        let mut description = Description(vec![]);
+        let spec = self.spec.as_ref();
+        let status = self.status.as_ref();
+
+        description.metadata(&self.metadata);
+        description.optional_field(0, "CreationTimestamp", self.metadata.creation_timestamp.as_ref().map(format_time));
+        description.labels_and_annotations(0, &self.metadata);
+
+        if let Some(spec) = spec {
+            description.field(0, "Selector", format_selector(&spec.selector));
+
+            let replicas = |replicas: Option<i32>| replicas.unwrap_or_default();
+            description.field(0, "Replicas", format_args!(
+                "{} desired | {} updated | {} total | {} available | {} unavailable",
+                replicas(spec.replicas.or(Some(1))),
+                replicas(status.and_then(|status| status.updated_replicas)),
+                replicas(status.and_then(|status| status.replicas)),
+                replicas(status.and_then(|status| status.available_replicas)),
+                replicas(status.and_then(|status| status.unavailable_replicas)),
+            ));
+            description.optional_field(0, "StrategyType", spec.strategy.as_ref().and_then(|strategy| strategy.type_.as_ref()));
+
+            description.heading(0, "Pod Template");
+            if let Some(metadata) = &spec.template.metadata {
+                description.labels_and_annotations(2, metadata);
+            }
+            if let Some(template_spec) = &spec.template.spec {
+                description.containers(2, &template_spec.containers, None);
+            }
+        }
+
+        if let Some(conditions) = status.and_then(|status| status.conditions.as_ref()) {
+            description.heading(0, "Conditions");
+            description.table(2, &["Type", "Status", "Reason"], conditions.iter().map(|condition| vec![
+                condition.type_.clone(),
+                condition.status.clone(),
+                condition.reason.clone().unwrap_or_default(),
+            ]));
+        }
+
+        description
+    }
+}
+
+impl Describe for Service {
+    fn describe(&self) -> Description {
+        let mut description = Description(vec![]);
+
+        description.metadata(&self.metadata);
+        description.labels_and_annotations(0, &self.metadata);
+
+        if let Some(spec) = &self.spec {
+            description.map(0, "Selector", spec.selector.as_ref());
+            description.optional_field(0, "Type", spec.type_.as_ref());
+            description.optional_field(0, "IP", spec.cluster_ip.as_ref());
+            description.optional_field(0, "External Name", spec.external_name.as_ref());
+            for port in spec.ports.iter().flatten() {
+                let protocol = port.protocol.as_ref().map_or("TCP", AsRef::as_ref);
+                description.field(0, "Port", format_args!("{}  {}/{protocol}", port.name.as_deref().unwrap_or("<unset>"), port.port));
+                match &port.target_port {
+                    Some(target_port) => description.field(0, "TargetPort", format_args!("{target_port}/{protocol}")),
+                    None => description.field(0, "TargetPort", format_args!("{}/{protocol}", port.port)),
+                }
+                description.optional_field(0, "NodePort", port.node_port.map(|node_port| format!("{node_port}/{protocol}")));
+            }
+            description.optional_field(0, "Session Affinity", spec.session_affinity.as_ref());
+        }
+
+        description
+    }
+}
+
+impl Describe for Node {
+    fn describe(&self) -> Description {
+        let mut description = Description(vec![]);
+        let spec = self.spec.as_ref();
+        let status = self.status.as_ref();
+
+        description.metadata(&self.metadata);
+
+        let roles: Vec<_> =
+            self.metadata.labels.iter().flatten()
+            .filter_map(|(key, _)| key.strip_prefix("node-role.kubernetes.io/"))
+            .collect();
+        description.field(0, "Roles", if roles.is_empty() { "<none>".to_owned() } else { roles.join(",") });
+
+        description.labels_and_annotations(0, &self.metadata);
+        description.optional_field(0, "CreationTimestamp", self.metadata.creation_timestamp.as_ref().map(format_time));
+        description.list(0, "Taints", spec.into_iter().flat_map(|spec| spec.taints.iter().flatten()).map(ToString::to_string));
+        description.field(0, "Unschedulable", spec.and_then(|spec| spec.unschedulable).unwrap_or_default());
+
+        if let Some(status) = status {
+            if let Some(conditions) = &status.conditions {
+                description.heading(0, "Conditions");
+                description.table(2, &["Type", "Status", "Reason"], conditions.iter().map(|condition| vec![
+                    condition.type_.clone(),
+                    condition.status.clone(),
+                    condition.reason.clone().unwrap_or_default(),
+                ]));
+            }
+
+            if let Some(addresses) = &status.addresses {
+                description.heading(0, "Addresses");
+                for address in addresses {
+                    description.field(2, &address.type_, &address.address);
+                }
+            }
+
+            for (key, resources) in &[("Capacity", &status.capacity), ("Allocatable", &status.allocatable)] {
+                if let Some(resources) = resources {
+                    description.heading(0, key);
+                    for (resource, quantity) in resources {
+                        description.field(2, resource, quantity);
+                    }
+                }
+            }
+
+            if let Some(node_info) = &status.node_info {
+                description.heading(0, "System Info");
+                description.field(2, "Kernel Version", &node_info.kernel_version);
+                description.field(2, "OS Image", &node_info.os_image);
+                description.field(2, "Operating System", &node_info.operating_system);
+                description.field(2, "Architecture", &node_info.architecture);
+                description.field(2, "Container Runtime Version", &node_info.container_runtime_version);
+                description.field(2, "Kubelet Version", &node_info.kubelet_version);
+            }
+        }
+
+        description.optional_field(0, "PodCIDR", spec.and_then(|spec| spec.pod_cidr.as_ref()));
+
+        description
+    }
+}
+
+/// The controller of the object in the form `kind/name`, like `ReplicaSet/web-5d4f8b9c7`.
+fn controller(metadata: &ObjectMeta) -> Option<String> {
+    metadata.owner_references.iter().flatten()
+        .find(|owner_reference| owner_reference.controller == Some(true))
+        .map(ToString::to_string)
+}
+
+/// Formats the selector like `kubectl` does, like `app=web,tier in (backend,frontend)`.
+fn format_selector(selector: &LabelSelector) -> String {
+    let match_labels = selector.match_labels.iter().flatten().map(|(key, value)| format!("{key}={value}"));
+    let match_expressions = selector.match_expressions.iter().flatten().map(|requirement| {
+        let key = &requirement.key;
+        let values = requirement.values.as_deref().unwrap_or_default().join(",");
+        match requirement.operator.as_str() {
+            "In" => format!("{key} in ({values})"),
+            "NotIn" => format!("{key} notin ({values})"),
+            "Exists" => key.clone(),
+            "DoesNotExist" => format!("!{key}"),
+            operator => format!("{key} {operator} ({values})"),
+        }
+    });
+
+    let selector: Vec<_> = match_labels.chain(match_expressions).collect();
+    if selector.is_empty() { "<none>".to_owned() } else { selector.join(",") }
+}
+
+/// Formats the time like `kubectl` does, like `Fri, 01 Jan 2021 00:00:00 +0000`.
+#[cfg(feature = "chrono")]
+fn format_time(time: &Time) -> String {
+    time.0.format("%a, %d %b %Y %H:%M:%S %z").to_string()
+}
+
+/// Formats the time as its RFC 3339 timestamp, since it cannot be parsed without `chrono`.
+#[cfg(not(feature = "chrono"))]
+fn format_time(time: &Time) -> String {
+    time.0.clone()
+}
+
+fn nested_indent(indent: usize, nested: bool) -> usize {
+    if nested { indent + 2 } else { indent }
+}
@@ -0,0 +1,42 @@
+/// A [`serde::de::DeserializeSeed`] that deserializes a value into an existing one, reusing its allocations.
+///
+/// The `Vec`s and `String`s of the existing value are cleared and refilled in place, and the elements of its `Vec`s and the values of its `Option`s
+/// are deserialized in place in turn. This is useful for code that deserializes many objects of the same type one after another,
+/// like the objects of the events of a watch, and is done with each object before deserializing the next one.
+///
+/// All the generated types, [`List`](crate::List) and [`WatchEvent`](crate::apimachinery::pkg::apis::meta::v1::WatchEvent) support this
+/// if the `deserialize-in-place` feature is enabled. Otherwise, and for other types, it falls back to deserializing a new value and replacing the existing one.
+///
+/// If deserialization fails, the existing value is left in a valid but unspecified state.
+///
+/// ```rust
+/// use k8s_openapi::serde::de::DeserializeSeed;
+///
+/// let events = br#"
+///     { "type": "ADDED", "object": { "apiVersion": "v1", "kind": "Pod", "metadata": { "name": "foo" } } }
+///     { "type": "MODIFIED", "object": { "apiVersion": "v1", "kind": "Pod", "metadata": { "name": "bar" } } }
+/// "#;
+///
+/// let mut event = k8s_openapi::apimachinery::pkg::apis::meta::v1::WatchEvent::<k8s_openapi::api::core::v1::Pod>::ErrorOther(Default::default());
+/// let mut names = vec![];
+///
+/// let mut deserializer = k8s_openapi::serde_json::Deserializer::from_slice(events);
+/// for _ in 0..2 {
+///     k8s_openapi::DeserializeInPlace(&mut event).deserialize(&mut deserializer).unwrap();
+///     if let k8s_openapi::apimachinery::pkg::apis::meta::v1::WatchEvent::Added(pod) | k8s_openapi::apimachinery::pkg::apis::meta::v1::WatchEvent::Modified(pod) = &event {
+///         names.push(pod.metadata.name.clone().unwrap());
+///     }
+/// }
+/// deserializer.end().unwrap();
+///
+/// assert_eq!(names, ["foo", "bar"]);
+/// ```
+pub struct DeserializeInPlace<'a, T>(pub &'a mut T);
+
+impl<'de, T> serde::de::DeserializeSeed<'de> for DeserializeInPlace<'_, T> where T: serde::Deserialize<'de> {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: serde::Deserializer<'de> {
+        T::deserialize_in_place(deserializer, self.0)
+    }
+}
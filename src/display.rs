@@ -0,0 +1,57 @@
+// `std::fmt::Display` impls for common value types, for use in logs and error messages.
+
+use crate::api::core::v1::ObjectReference;
+use crate::apimachinery::pkg::api::resource::Quantity;
+use crate::apimachinery::pkg::apis::meta::v1::{OwnerReference, Status};
+use crate::apimachinery::pkg::util::intstr::IntOrString;
+
+/// Formats the quantity as its string form, like `100m` or `1Gi`.
+impl std::fmt::Display for Quantity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Formats the value as the integer or the string, like `8080` or `http`.
+impl std::fmt::Display for IntOrString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IntOrString::Int(i) => write!(f, "{i}"),
+            IntOrString::String(s) => f.write_str(s),
+        }
+    }
+}
+
+/// Formats the status as its message followed by its reason in parentheses, like `pods "foo" not found (NotFound)`.
+///
+/// If the status does not have a message or reason, it is formatted as its `status` field instead, like `Failure`.
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.message, &self.reason) {
+            (Some(message), Some(reason)) => write!(f, "{message} ({reason})"),
+            (Some(message), None) => f.write_str(message),
+            (None, Some(reason)) => f.write_str(reason),
+            (None, None) => f.write_str(self.status.as_deref().unwrap_or("Unknown")),
+        }
+    }
+}
+
+/// Formats the reference as `kind/name`, like `ReplicaSet/foo-5d4f8b9c7`. The owner is always in the same namespace as the owned object.
+impl std::fmt::Display for OwnerReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.kind, self.name)
+    }
+}
+
+/// Formats the reference as `kind/namespace/name`, like `Pod/default/foo`. Any of the three fields that are not set are left out,
+/// so a reference to a cluster-scoped object is formatted as `kind/name`, like `Node/node-0`.
+impl std::fmt::Display for ObjectReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut separator = "";
+        for part in [&self.kind, &self.namespace, &self.name].iter().copied().flatten() {
+            write!(f, "{separator}{part}")?;
+            separator = "/";
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+/// A wrapper that serializes a value with explicit `null`s for the timestamp fields that `kubectl` writes out as `null` when they're not set,
+/// like the `creationTimestamp: null` of objects that haven't been created yet.
+///
+/// The Go types of these fields in client-go are not pointers, so they can't be omitted and are serialized as `null` instead.
+/// The generated types omit them when they're `None`, like any other optional field. This wrapper is for when the serialized value needs to
+/// byte-match manifests written by `kubectl` or other client-go-based tools, such as `kubectl create --dry-run=client -o yaml`,
+/// that a diff pipeline compares against.
+///
+/// ```rust
+/// let config_map = k8s_openapi::api::core::v1::ConfigMap {
+///     metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+///         name: Some("foo".to_owned()),
+///         ..Default::default()
+///     },
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(
+///     k8s_openapi::serde_json::to_string(&k8s_openapi::WithExplicitNulls(&config_map)).unwrap(),
+///     r#"{"apiVersion":"v1","kind":"ConfigMap","metadata":{"creationTimestamp":null,"name":"foo"}}"#,
+/// );
+/// ```
+///
+/// The fields are:
+///
+/// - `ObjectMeta`: `creationTimestamp`
+/// - `ContainerStateRunning`: `startedAt`
+/// - `ContainerStateTerminated`: `finishedAt`, `startedAt`
+/// - The `lastHeartbeatTime`, `lastProbeTime`, `lastTransitionTime` and `lastUpdateTime` fields of the conditions of
+///   `DaemonSet`s, `Deployment`s, `Job`s, `Namespace`s, `Node`s, `PersistentVolumeClaim`s, `Pod`s, `ReplicaSet`s, `ReplicationController`s and `StatefulSet`s
+///   that are not pointers in client-go.
+///
+/// Formats that are not self-describing, like bincode, serialize all the fields of structs in order, so nothing is added for them.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct WithExplicitNulls<T>(pub T);
+
+impl<T> serde::Serialize for WithExplicitNulls<T> where T: serde::Serialize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_with_explicit_nulls(&self.0, serializer)
+    }
+}
+
+/// Serializes a value with explicit `null`s for the timestamp fields that `kubectl` writes out as `null` when they're not set.
+/// See [`WithExplicitNulls`] for details.
+///
+/// This can be used with `#[serde(serialize_with)]` on fields of your own types.
+pub fn serialize_with_explicit_nulls<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error> where
+    T: serde::Serialize + ?Sized,
+    S: serde::Serializer,
+{
+    crate::serializer_wrapper::serialize(value, serializer, crate::serializer_wrapper::Options {
+        explicit_nulls: true,
+        ..Default::default()
+    })
+}
@@ -0,0 +1,91 @@
+//! The fields that the API server accepts in the field selectors of list and watch requests of each resource type.
+//!
+//! Every resource type can be selected by [`METADATA_NAME`], and namespace-scoped ones also by [`METADATA_NAMESPACE`].
+//! The other fields are specific to a few resource types, and are in the submodule named after the type.
+//!
+//! ```rust
+//! use k8s_openapi::field_selectors::pod;
+//!
+//! let field_selector = format!("{}=node-1,{}!=Succeeded", pod::SPEC_NODE_NAME, pod::STATUS_PHASE);
+//! assert_eq!(field_selector, "spec.nodeName=node-1,status.phase!=Succeeded");
+//! assert!(pod::ALL.contains(&"metadata.namespace"));
+//! ```
+//!
+//! The spec does not describe field selectors, so these are maintained by hand from the API server's registry of each resource type.
+//! Fields that only some of the supported versions of Kubernetes accept are not included.
+
+/// The name of the object. All resource types accept this field.
+pub const METADATA_NAME: &str = "metadata.name";
+
+/// The namespace of the object. All namespace-scoped resource types accept this field.
+pub const METADATA_NAMESPACE: &str = "metadata.namespace";
+
+macro_rules! field_selectors {
+    ($($module:ident ($ty:path, $namespaced:tt) {
+        $($name:ident = $value:literal,)*
+    })*) => {
+        $(
+            #[doc = concat!("The fields that [`", stringify!($ty), "`] can be selected by.")]
+            pub mod $module {
+                $(
+                    #[doc = concat!("`", $value, "`")]
+                    pub const $name: &str = $value;
+                )*
+
+                /// All the fields that this resource type can be selected by, including the ones of its metadata.
+                pub const ALL: &[&str] = field_selectors!(@all $namespaced $($value,)*);
+            }
+        )*
+    };
+
+    (@all namespaced $($value:literal,)*) => { &[super::METADATA_NAME, super::METADATA_NAMESPACE, $($value,)*] };
+    (@all cluster $($value:literal,)*) => { &[super::METADATA_NAME, $($value,)*] };
+}
+
+field_selectors! {
+    event (crate::api::core::v1::Event, namespaced) {
+        INVOLVED_OBJECT_API_VERSION = "involvedObject.apiVersion",
+        INVOLVED_OBJECT_FIELD_PATH = "involvedObject.fieldPath",
+        INVOLVED_OBJECT_KIND = "involvedObject.kind",
+        INVOLVED_OBJECT_NAME = "involvedObject.name",
+        INVOLVED_OBJECT_NAMESPACE = "involvedObject.namespace",
+        INVOLVED_OBJECT_RESOURCE_VERSION = "involvedObject.resourceVersion",
+        INVOLVED_OBJECT_UID = "involvedObject.uid",
+        REASON = "reason",
+        SOURCE = "source",
+        TYPE = "type",
+    }
+
+    job (crate::api::batch::v1::Job, namespaced) {
+        STATUS_SUCCESSFUL = "status.successful",
+    }
+
+    namespace (crate::api::core::v1::Namespace, cluster) {
+        STATUS_PHASE = "status.phase",
+    }
+
+    node (crate::api::core::v1::Node, cluster) {
+        SPEC_UNSCHEDULABLE = "spec.unschedulable",
+    }
+
+    pod (crate::api::core::v1::Pod, namespaced) {
+        SPEC_NODE_NAME = "spec.nodeName",
+        SPEC_RESTART_POLICY = "spec.restartPolicy",
+        SPEC_SCHEDULER_NAME = "spec.schedulerName",
+        SPEC_SERVICE_ACCOUNT_NAME = "spec.serviceAccountName",
+        STATUS_PHASE = "status.phase",
+        STATUS_POD_IP = "status.podIP",
+    }
+
+    replica_set (crate::api::apps::v1::ReplicaSet, namespaced) {
+        STATUS_REPLICAS = "status.replicas",
+    }
+
+    replication_controller (crate::api::core::v1::ReplicationController, namespaced) {
+        STATUS_REPLICAS = "status.replicas",
+    }
+
+    secret (crate::api::core::v1::Secret, namespaced) {
+        TYPE = "type",
+    }
+}
@@ -0,0 +1,449 @@
+//! Builders of realistic resources for use in tests, such as tests of controllers that consume these resources.
+//!
+//! The builders fill in the fields that the API server and the relevant controllers would have set, like `metadata.uid`,
+//! `metadata.resourceVersion` and `status`. All values are deterministic, so the same builder calls always produce the same resource,
+//! and a fixture that refers to another resource (like [`PodFixture::owned_by`]) agrees with that resource's own fixture.
+//!
+//! ```rust
+//! use k8s_openapi::fixtures;
+//!
+//! let deployment = fixtures::deployment("web").replicas(3).available().build();
+//! let pod = fixtures::pod("web-0").owned_by(&deployment).running().build();
+//!
+//! assert_eq!(
+//!     pod.metadata.owner_references.as_ref().unwrap()[0].uid,
+//!     *deployment.metadata.uid.as_ref().unwrap(),
+//! );
+//! assert_eq!(pod.status.unwrap().phase.as_deref(), Some("Running"));
+//! ```
+
+use crate::api::apps::v1::{Deployment, DeploymentCondition, DeploymentSpec, DeploymentStatus};
+use crate::api::core::v1::{
+    Container, ContainerState, ContainerStateRunning, ContainerStateTerminated, ContainerStateWaiting, ContainerStatus, DNSPolicy,
+    Pod, PodCondition, PodSpec, PodStatus, PodTemplateSpec, PullPolicy, RestartPolicy, TerminationMessagePolicy,
+};
+use crate::apimachinery::pkg::apis::meta::v1::{LabelSelector, ObjectMeta, OwnerReference, Time};
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+/// The namespace of fixtures whose namespace is not set explicitly.
+pub const DEFAULT_NAMESPACE: &str = "default";
+
+/// The container image of fixtures whose image is not set explicitly.
+pub const DEFAULT_IMAGE: &str = "nginx:1.21";
+
+/// The node that pods are scheduled to if their node is not set explicitly.
+pub const DEFAULT_NODE: &str = "node-0";
+
+/// The UID that fixtures use for the resource of the given kind, namespace and name.
+///
+/// The UID is derived from its arguments, so it is the same in every test run.
+/// Use the empty string as the namespace of cluster-scoped resources.
+pub fn uid(kind: &str, namespace: &str, name: &str) -> String {
+    let hi = fnv1a(0xcbf2_9ce4_8422_2325, kind, namespace, name);
+    let lo = fnv1a(hi, kind, namespace, name);
+
+    // Formatted as a version 4 UUID like the ones the API server generates.
+    format!(
+        "{:08x}-{:04x}-4{:03x}-{:04x}-{:012x}",
+        hi >> 32,
+        (hi >> 16) & 0xffff,
+        hi & 0x0fff,
+        0x8000 | ((lo >> 48) & 0x3fff),
+        lo & 0xffff_ffff_ffff,
+    )
+}
+
+/// Starts building a pod with the given name.
+///
+/// The pod is in the [`DEFAULT_NAMESPACE`], has the label `app=<name>` and a single container named `<name>` running the [`DEFAULT_IMAGE`].
+/// It is `Pending` and not yet scheduled to a node until one of the phase methods like [`PodFixture::running`] is called.
+pub fn pod(name: &str) -> PodFixture {
+    PodFixture {
+        name: name.to_owned(),
+        namespace: DEFAULT_NAMESPACE.to_owned(),
+        labels: std::iter::once(("app".to_owned(), name.to_owned())).collect(),
+        image: DEFAULT_IMAGE.to_owned(),
+        node_name: None,
+        owner: None,
+        phase: PodPhase::Pending,
+        resource_version: 1,
+    }
+}
+
+/// Starts building a deployment with the given name.
+///
+/// The deployment is in the [`DEFAULT_NAMESPACE`] and has one replica. Its selector and pod template labels are `app=<name>`,
+/// and its pod template has a single container named `<name>` running the [`DEFAULT_IMAGE`].
+/// Its status reports that its pods are still being rolled out until [`DeploymentFixture::available`] is called.
+pub fn deployment(name: &str) -> DeploymentFixture {
+    DeploymentFixture {
+        name: name.to_owned(),
+        namespace: DEFAULT_NAMESPACE.to_owned(),
+        labels: std::iter::once(("app".to_owned(), name.to_owned())).collect(),
+        image: DEFAULT_IMAGE.to_owned(),
+        replicas: 1,
+        available: false,
+        resource_version: 1,
+    }
+}
+
+/// A builder of a [`Pod`]. Created with [`pod`].
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct PodFixture {
+    name: String,
+    namespace: String,
+    labels: BTreeMap<String, String>,
+    image: String,
+    node_name: Option<String>,
+    owner: Option<OwnerReference>,
+    phase: PodPhase,
+    resource_version: u64,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum PodPhase {
+    Pending,
+    Running,
+    Succeeded,
+    Failed,
+}
+
+impl PodFixture {
+    /// Sets the namespace of the pod.
+    pub fn namespace(mut self, namespace: &str) -> Self {
+        namespace.clone_into(&mut self.namespace);
+        self
+    }
+
+    /// Adds a label to the pod, replacing any existing label with the same key.
+    pub fn label(mut self, key: &str, value: &str) -> Self {
+        self.labels.insert(key.to_owned(), value.to_owned());
+        self
+    }
+
+    /// Sets the image of the pod's container.
+    pub fn image(mut self, image: &str) -> Self {
+        image.clone_into(&mut self.image);
+        self
+    }
+
+    /// Schedules the pod to the given node. Pods that are not pending are scheduled to the [`DEFAULT_NODE`] unless this is called.
+    pub fn node(mut self, node_name: &str) -> Self {
+        self.node_name = Some(node_name.to_owned());
+        self
+    }
+
+    /// Makes the given resource the controller of the pod.
+    ///
+    /// The owner reference uses the owner's `metadata.uid` if it is set, and otherwise the [`uid`] that fixtures use for the owner.
+    pub fn owned_by<K>(mut self, owner: &K) -> Self where K: crate::Resource + crate::Metadata<Ty = ObjectMeta> {
+        let metadata = owner.metadata();
+        let name = metadata.name.clone().unwrap_or_default();
+        let uid = metadata.uid.clone().unwrap_or_else(|| uid(K::KIND, metadata.namespace.as_deref().unwrap_or_default(), &name));
+
+        self.owner = Some(OwnerReference {
+            api_version: K::API_VERSION.to_owned(),
+            block_owner_deletion: Some(true),
+            controller: Some(true),
+            kind: K::KIND.to_owned(),
+            name,
+            uid,
+        });
+        self
+    }
+
+    /// Marks the pod as pending, ie scheduled but with its container not started yet.
+    pub fn pending(self) -> Self {
+        self.with_phase(PodPhase::Pending)
+    }
+
+    /// Marks the pod as running, with its container started and ready.
+    pub fn running(self) -> Self {
+        self.with_phase(PodPhase::Running)
+    }
+
+    /// Marks the pod as succeeded, with its container having exited with exit code 0.
+    pub fn succeeded(self) -> Self {
+        self.with_phase(PodPhase::Succeeded)
+    }
+
+    /// Marks the pod as failed, with its container having exited with exit code 1.
+    pub fn failed(self) -> Self {
+        self.with_phase(PodPhase::Failed)
+    }
+
+    fn with_phase(mut self, phase: PodPhase) -> Self {
+        // Every status update made by the kubelet bumps the resource version.
+        self.resource_version += 1;
+        self.phase = phase;
+        if self.node_name.is_none() {
+            self.node_name = Some(DEFAULT_NODE.to_owned());
+        }
+        self
+    }
+
+    /// Builds the pod.
+    pub fn build(self) -> Pod {
+        let uid = uid(<Pod as crate::Resource>::KIND, &self.namespace, &self.name);
+
+        let container_id = format!("containerd://{}", uid.replace('-', ""));
+        let container_status = |ready: bool, state: ContainerState| ContainerStatus {
+            container_id: if self.phase == PodPhase::Pending { None } else { Some(container_id.clone()) },
+            image: self.image.clone(),
+            image_id: if self.phase == PodPhase::Pending { String::new() } else { self.image.clone() },
+            name: self.name.clone(),
+            ready,
+            state: Some(state),
+            ..Default::default()
+        };
+        let condition = |type_: &str, status: bool, offset_secs: u64| PodCondition {
+            last_transition_time: Some(time(offset_secs)),
+            status: if status { "True" } else { "False" }.to_owned(),
+            type_: type_.to_owned(),
+            ..Default::default()
+        };
+
+        let (phase, conditions, container_status) = match (self.phase, &self.node_name) {
+            (PodPhase::Pending, None) => ("Pending", vec![], None),
+
+            (PodPhase::Pending, Some(_)) => ("Pending", vec![
+                condition("Initialized", true, 1),
+                condition("Ready", false, 1),
+                condition("ContainersReady", false, 1),
+                condition("PodScheduled", true, 1),
+            ], Some(container_status(false, ContainerState {
+                waiting: Some(ContainerStateWaiting { reason: Some("ContainerCreating".to_owned()), ..Default::default() }),
+                ..Default::default()
+            }))),
+
+            (PodPhase::Running, _) => ("Running", vec![
+                condition("Initialized", true, 1),
+                condition("Ready", true, 3),
+                condition("ContainersReady", true, 3),
+                condition("PodScheduled", true, 1),
+            ], Some(container_status(true, ContainerState {
+                running: Some(ContainerStateRunning { started_at: Some(time(2)) }),
+                ..Default::default()
+            }))),
+
+            (PodPhase::Succeeded | PodPhase::Failed, _) => {
+                let (phase, exit_code, reason) =
+                    if self.phase == PodPhase::Succeeded { ("Succeeded", 0, "Completed") } else { ("Failed", 1, "Error") };
+                (phase, vec![
+                    condition("Initialized", true, 1),
+                    condition("Ready", false, 4),
+                    condition("ContainersReady", false, 4),
+                    condition("PodScheduled", true, 1),
+                ], Some(container_status(false, ContainerState {
+                    terminated: Some(ContainerStateTerminated {
+                        container_id: Some(container_id.clone()),
+                        exit_code,
+                        finished_at: Some(time(4)),
+                        reason: Some(reason.to_owned()),
+                        started_at: Some(time(2)),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                })))
+            },
+        };
+
+        let ip_seed = fnv1a(0xcbf2_9ce4_8422_2325, "", &self.namespace, &self.name);
+        let scheduled = self.node_name.is_some();
+
+        Pod {
+            metadata: ObjectMeta {
+                creation_timestamp: Some(time(0)),
+                labels: Some(self.labels),
+                name: Some(self.name.clone()),
+                namespace: Some(self.namespace),
+                owner_references: self.owner.map(|owner| vec![owner]),
+                resource_version: Some(self.resource_version.to_string()),
+                uid: Some(uid),
+                ..Default::default()
+            },
+            spec: Some(PodSpec {
+                containers: vec![Container {
+                    image: Some(self.image),
+                    image_pull_policy: Some(PullPolicy::IfNotPresent),
+                    name: self.name,
+                    termination_message_path: Some("/dev/termination-log".to_owned()),
+                    termination_message_policy: Some(TerminationMessagePolicy::File),
+                    ..Default::default()
+                }],
+                dns_policy: Some(DNSPolicy::ClusterFirst),
+                node_name: self.node_name,
+                restart_policy: Some(RestartPolicy::Always),
+                scheduler_name: Some("default-scheduler".to_owned()),
+                termination_grace_period_seconds: Some(30),
+                ..Default::default()
+            }),
+            status: Some(PodStatus {
+                conditions: if conditions.is_empty() { None } else { Some(conditions) },
+                container_statuses: container_status.map(|container_status| vec![container_status]),
+                host_ip: if scheduled { Some("172.18.0.2".to_owned()) } else { None },
+                phase: Some(phase.to_owned()),
+                pod_ip: if scheduled { Some(format!("10.244.{}.{}", (ip_seed >> 8) & 0xff, 2 + (ip_seed & 0xff) % 250)) } else { None },
+                qos_class: Some("BestEffort".to_owned()),
+                start_time: if scheduled { Some(time(1)) } else { None },
+                ..Default::default()
+            }),
+        }
+    }
+}
+
+impl From<PodFixture> for Pod {
+    fn from(fixture: PodFixture) -> Self {
+        fixture.build()
+    }
+}
+
+/// A builder of a [`Deployment`]. Created with [`deployment`].
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct DeploymentFixture {
+    name: String,
+    namespace: String,
+    labels: BTreeMap<String, String>,
+    image: String,
+    replicas: i32,
+    available: bool,
+    resource_version: u64,
+}
+
+impl DeploymentFixture {
+    /// Sets the namespace of the deployment.
+    pub fn namespace(mut self, namespace: &str) -> Self {
+        namespace.clone_into(&mut self.namespace);
+        self
+    }
+
+    /// Adds a label to the deployment, replacing any existing label with the same key.
+    ///
+    /// This does not change the deployment's selector or the labels of its pod template.
+    pub fn label(mut self, key: &str, value: &str) -> Self {
+        self.labels.insert(key.to_owned(), value.to_owned());
+        self
+    }
+
+    /// Sets the image of the container of the deployment's pod template.
+    pub fn image(mut self, image: &str) -> Self {
+        image.clone_into(&mut self.image);
+        self
+    }
+
+    /// Sets the number of replicas of the deployment.
+    pub fn replicas(mut self, replicas: i32) -> Self {
+        self.replicas = replicas;
+        self
+    }
+
+    /// Marks the deployment as available, with all of its replicas updated and ready.
+    pub fn available(mut self) -> Self {
+        // The deployment controller updates the status once per replica that becomes ready, and once more when the deployment becomes available.
+        self.resource_version += u64::try_from(self.replicas.max(0)).unwrap_or_default() + 1;
+        self.available = true;
+        self
+    }
+
+    /// Builds the deployment.
+    pub fn build(self) -> Deployment {
+        let uid = uid(<Deployment as crate::Resource>::KIND, &self.namespace, &self.name);
+
+        let selector_labels: BTreeMap<_, _> = std::iter::once(("app".to_owned(), self.name.clone())).collect();
+
+        let condition = |type_: &str, reason: &str, message: String, offset_secs: u64| DeploymentCondition {
+            last_transition_time: Some(time(offset_secs)),
+            last_update_time: Some(time(offset_secs)),
+            message: Some(message),
+            reason: Some(reason.to_owned()),
+            status: "True".to_owned(),
+            type_: type_.to_owned(),
+        };
+
+        let status =
+            if self.available {
+                DeploymentStatus {
+                    available_replicas: Some(self.replicas),
+                    conditions: Some(vec![
+                        condition("Available", "MinimumReplicasAvailable", "Deployment has minimum availability.".to_owned(), 3),
+                        condition("Progressing", "NewReplicaSetAvailable", format!(r#"ReplicaSet "{}" has successfully progressed."#, self.name), 3),
+                    ]),
+                    observed_generation: Some(1),
+                    ready_replicas: Some(self.replicas),
+                    replicas: Some(self.replicas),
+                    updated_replicas: Some(self.replicas),
+                    ..Default::default()
+                }
+            }
+            else {
+                DeploymentStatus {
+                    conditions: Some(vec![
+                        condition("Progressing", "ReplicaSetUpdated", format!(r#"ReplicaSet "{}" is progressing."#, self.name), 1),
+                    ]),
+                    observed_generation: Some(1),
+                    replicas: Some(self.replicas),
+                    unavailable_replicas: Some(self.replicas),
+                    updated_replicas: Some(self.replicas),
+                    ..Default::default()
+                }
+            };
+
+        Deployment {
+            metadata: ObjectMeta {
+                creation_timestamp: Some(time(0)),
+                generation: Some(1),
+                labels: Some(self.labels),
+                name: Some(self.name.clone()),
+                namespace: Some(self.namespace),
+                resource_version: Some(self.resource_version.to_string()),
+                uid: Some(uid),
+                ..Default::default()
+            },
+            spec: Some(DeploymentSpec {
+                replicas: Some(self.replicas),
+                selector: LabelSelector {
+                    match_labels: Some(selector_labels.clone()),
+                    ..Default::default()
+                },
+                template: PodTemplateSpec {
+                    metadata: Some(ObjectMeta {
+                        labels: Some(selector_labels),
+                        ..Default::default()
+                    }),
+                    spec: Some(PodSpec {
+                        containers: vec![Container {
+                            image: Some(self.image),
+                            image_pull_policy: Some(PullPolicy::IfNotPresent),
+                            name: self.name,
+                            ..Default::default()
+                        }],
+                        ..Default::default()
+                    }),
+                },
+                ..Default::default()
+            }),
+            status: Some(status),
+        }
+    }
+}
+
+impl From<DeploymentFixture> for Deployment {
+    fn from(fixture: DeploymentFixture) -> Self {
+        fixture.build()
+    }
+}
+
+/// The time that is the given number of seconds after the creation of all fixtures, 2021-01-01T00:00:00Z.
+fn time(offset_secs: u64) -> Time {
+    Time((std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_609_459_200 + offset_secs)).into())
+}
+
+/// The 64-bit FNV-1a hash of the given strings, starting from the given state.
+fn fnv1a(state: u64, kind: &str, namespace: &str, name: &str) -> u64 {
+    [kind, namespace, name].iter()
+        .flat_map(|s| s.bytes().chain(std::iter::once(b'/')))
+        .fold(state, |hash, b| (hash ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3))
+}
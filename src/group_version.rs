@@ -0,0 +1,78 @@
+/// The API group and version of a resource type, like `apps/v1`.
+///
+/// This is parsed from and formatted as the `apiVersion` of a resource, which is only the version for the legacy core group, like `v1`.
+/// It is serialized as the same string, so it can be used in config files.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct GroupVersion {
+    /// The API group, like `apps`. The legacy core group is the empty string.
+    pub group: String,
+
+    /// The API version within the group, like `v1`.
+    pub version: String,
+}
+
+impl GroupVersion {
+    /// The group and version of the given resource type.
+    pub fn of<T>() -> Self where T: crate::Resource {
+        GroupVersion {
+            group: T::GROUP.to_owned(),
+            version: T::VERSION.to_owned(),
+        }
+    }
+}
+
+impl std::fmt::Display for GroupVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.group.is_empty() {
+            f.write_str(&self.version)
+        }
+        else {
+            write!(f, "{}/{}", self.group, self.version)
+        }
+    }
+}
+
+impl std::str::FromStr for GroupVersion {
+    type Err = ParseGroupVersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (group, version) = match s.find('/') {
+            Some(pos) => (&s[..pos], &s[(pos + 1)..]),
+            None => ("", s),
+        };
+
+        if version.is_empty() || version.contains('/') || (group.is_empty() && s.contains('/')) {
+            return Err(ParseGroupVersionError(s.to_owned()));
+        }
+
+        Ok(GroupVersion {
+            group: group.to_owned(),
+            version: version.to_owned(),
+        })
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for GroupVersion {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let s: String = serde::Deserialize::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl serde::Serialize for GroupVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.collect_str(self)
+    }
+}
+
+/// The error returned when parsing a [`GroupVersion`] from a string that is not of the form `group/version` or `version`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseGroupVersionError(String);
+
+impl std::fmt::Display for ParseGroupVersionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} is not of the form `group/version` or `version`", self.0)
+    }
+}
+
+impl std::error::Error for ParseGroupVersionError {}
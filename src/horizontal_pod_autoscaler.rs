@@ -0,0 +1,228 @@
+// Constructors of the metrics and scaling behavior of `autoscaling/v2beta2` `HorizontalPodAutoscaler`,
+// so that each kind of metric can be built without spelling out the nested source, identifier and target structs.
+//
+// Ref: https://kubernetes.io/docs/tasks/run-application/horizontal-pod-autoscale/#support-for-metrics-apis
+
+use crate::api::autoscaling::v2beta2::{
+    CrossVersionObjectReference, ExternalMetricSource, MetricIdentifier, MetricSpec, MetricTarget, ObjectMetricSource, PodsMetricSource,
+    ResourceMetricSource,
+};
+use crate::apimachinery::pkg::api::resource::Quantity;
+use crate::apimachinery::pkg::apis::meta::v1::{LabelSelector, ObjectMeta};
+
+impl MetricSpec {
+    /// A metric of the average utilization of a resource like `cpu` or `memory` across the pods of the scale target,
+    /// as a percentage of the resource requests of those pods.
+    ///
+    /// ```rust
+    /// use k8s_openapi::api::autoscaling::v2beta2::MetricSpec;
+    ///
+    /// let metric = MetricSpec::resource_utilization("cpu", 80);
+    /// assert_eq!(metric.type_, "Resource");
+    /// assert_eq!(metric.resource.unwrap().target.average_utilization, Some(80));
+    /// ```
+    pub fn resource_utilization(name: impl Into<String>, average_utilization: i32) -> Self {
+        MetricSpec::resource(name, MetricTarget::utilization(average_utilization))
+    }
+
+    /// A metric of a resource like `cpu` or `memory` of the pods of the scale target, with the given target.
+    pub fn resource(name: impl Into<String>, target: MetricTarget) -> Self {
+        MetricSpec {
+            type_: "Resource".to_owned(),
+            resource: Some(ResourceMetricSource {
+                name: name.into(),
+                target,
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// A custom metric that is reported by each pod of the scale target, like `packets-per-second`, averaged across the pods.
+    ///
+    /// Pods metrics only support average value targets.
+    pub fn pods(metric_name: impl Into<String>, average_value: impl Into<Quantity>) -> Self {
+        MetricSpec {
+            type_: "Pods".to_owned(),
+            pods: Some(PodsMetricSource {
+                metric: MetricIdentifier { name: metric_name.into(), selector: None },
+                target: MetricTarget::average_value(average_value),
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// A custom metric that describes a single object in the namespace of the autoscaler, like the `requests-per-second` of an `Ingress`.
+    pub fn object(described_object: CrossVersionObjectReference, metric_name: impl Into<String>, target: MetricTarget) -> Self {
+        MetricSpec {
+            type_: "Object".to_owned(),
+            object: Some(ObjectMetricSource {
+                described_object,
+                metric: MetricIdentifier { name: metric_name.into(), selector: None },
+                target,
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// A metric that is not associated with any Kubernetes object, like the length of a queue of a cloud messaging service.
+    pub fn external(metric_name: impl Into<String>, target: MetricTarget) -> Self {
+        MetricSpec {
+            type_: "External".to_owned(),
+            external: Some(ExternalMetricSource {
+                metric: MetricIdentifier { name: metric_name.into(), selector: None },
+                target,
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the label selector of the metric of a pods, object or external metric, which is passed to the metrics server
+    /// to select a more specific series of the metric.
+    ///
+    /// Resource metrics do not have a selector, so they are returned unchanged.
+    #[must_use]
+    pub fn with_metric_selector(mut self, selector: LabelSelector) -> Self {
+        let metric =
+            if let Some(pods) = &mut self.pods { &mut pods.metric }
+            else if let Some(object) = &mut self.object { &mut object.metric }
+            else if let Some(external) = &mut self.external { &mut external.metric }
+            else { return self; };
+        metric.selector = Some(selector);
+        self
+    }
+}
+
+k8s_if_ge_1_20! {
+    impl MetricSpec {
+        /// A metric of the average utilization of a resource like `cpu` or `memory` of a single container in each pod of the scale target,
+        /// as a percentage of the resource requests of that container.
+        ///
+        /// This ignores the resource usage of the pods' other containers, like sidecars.
+        pub fn container_resource_utilization(name: impl Into<String>, container: impl Into<String>, average_utilization: i32) -> Self {
+            MetricSpec {
+                type_: "ContainerResource".to_owned(),
+                container_resource: Some(crate::api::autoscaling::v2beta2::ContainerResourceMetricSource {
+                    container: container.into(),
+                    name: name.into(),
+                    target: MetricTarget::utilization(average_utilization),
+                }),
+                ..Default::default()
+            }
+        }
+    }
+}
+
+impl MetricTarget {
+    /// A target of the given average utilization across all pods, as a percentage of their resource requests. Only valid for resource metrics.
+    pub fn utilization(average_utilization: i32) -> Self {
+        MetricTarget {
+            type_: "Utilization".to_owned(),
+            average_utilization: Some(average_utilization),
+            ..Default::default()
+        }
+    }
+
+    /// A target of the given value of the metric divided by the number of pods.
+    pub fn average_value(average_value: impl Into<Quantity>) -> Self {
+        MetricTarget {
+            type_: "AverageValue".to_owned(),
+            average_value: Some(average_value.into()),
+            ..Default::default()
+        }
+    }
+
+    /// A target of the given value of the metric. Only valid for object and external metrics.
+    pub fn value(value: impl Into<Quantity>) -> Self {
+        MetricTarget {
+            type_: "Value".to_owned(),
+            value: Some(value.into()),
+            ..Default::default()
+        }
+    }
+}
+
+impl CrossVersionObjectReference {
+    /// A reference to the given object, such as the `Deployment` that is the scale target of an autoscaler.
+    pub fn to<K>(object: &K) -> Self where K: crate::Resource + crate::Metadata<Ty = ObjectMeta> {
+        CrossVersionObjectReference {
+            api_version: Some(K::API_VERSION.to_owned()),
+            kind: K::KIND.to_owned(),
+            name: object.metadata().name.clone().unwrap_or_default(),
+        }
+    }
+}
+
+k8s_if_ge_1_18! {
+    use crate::api::autoscaling::v2beta2::{HPAScalingPolicy, HPAScalingRules};
+
+    impl HPAScalingRules {
+        /// Scaling rules that allow the largest change of all of the given policies.
+        ///
+        /// ```rust
+        /// use k8s_openapi::api::autoscaling::v2beta2::{HorizontalPodAutoscalerBehavior, HPAScalingPolicy, HPAScalingRules};
+        ///
+        /// // Scale up without limits, and scale down by at most 10% or 2 pods per minute, whichever is more,
+        /// // once the desired number of replicas has been lower for 5 minutes.
+        /// let behavior = HorizontalPodAutoscalerBehavior {
+        ///     scale_down: Some(
+        ///         HPAScalingRules::select_max(vec![HPAScalingPolicy::percent(10, 60), HPAScalingPolicy::pods(2, 60)])
+        ///         .stabilization_window(300),
+        ///     ),
+        ///     scale_up: None,
+        /// };
+        /// ```
+        pub fn select_max(policies: impl IntoIterator<Item = HPAScalingPolicy>) -> Self {
+            HPAScalingRules {
+                policies: Some(policies.into_iter().collect()),
+                select_policy: Some("Max".to_owned()),
+                stabilization_window_seconds: None,
+            }
+        }
+
+        /// Scaling rules that allow the smallest change of all of the given policies.
+        pub fn select_min(policies: impl IntoIterator<Item = HPAScalingPolicy>) -> Self {
+            HPAScalingRules {
+                policies: Some(policies.into_iter().collect()),
+                select_policy: Some("Min".to_owned()),
+                stabilization_window_seconds: None,
+            }
+        }
+
+        /// Scaling rules that disable scaling in their direction, such as to never scale down.
+        pub fn disabled() -> Self {
+            HPAScalingRules {
+                policies: None,
+                select_policy: Some("Disabled".to_owned()),
+                stabilization_window_seconds: None,
+            }
+        }
+
+        /// Sets the number of seconds for which past recommendations are considered when scaling, between 0 and 3600,
+        /// which prevents the number of replicas from flapping when the metrics fluctuate.
+        #[must_use]
+        pub fn stabilization_window(mut self, seconds: i32) -> Self {
+            self.stabilization_window_seconds = Some(seconds);
+            self
+        }
+    }
+
+    impl HPAScalingPolicy {
+        /// A policy that allows changing the number of replicas by at most the given number of pods within the given period.
+        pub fn pods(pods: i32, period_seconds: i32) -> Self {
+            HPAScalingPolicy {
+                type_: "Pods".to_owned(),
+                value: pods,
+                period_seconds,
+            }
+        }
+
+        /// A policy that allows changing the number of replicas by at most the given percentage of the current replicas within the given period.
+        pub fn percent(percent: i32, period_seconds: i32) -> Self {
+            HPAScalingPolicy {
+                type_: "Percent".to_owned(),
+                value: percent,
+                period_seconds,
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+// Completion status of `batch/v1` `Job`, matching how the job controller and `kubectl wait --for=condition=complete` report it.
+
+use crate::api::batch::v1::{Job, JobCondition};
+
+impl Job {
+    /// Gets the condition of the given type, such as `Complete` or `Failed`, if the job has one.
+    pub fn condition(&self, type_: &str) -> Option<&JobCondition> {
+        self.status.as_ref()?
+            .conditions.as_ref()?
+            .iter()
+            .find(|condition| condition.type_ == type_)
+    }
+
+    /// Returns `true` if the job has completed successfully, ie it has a `Complete` condition with status `True`.
+    pub fn is_complete(&self) -> bool {
+        matches!(self.condition("Complete"), Some(condition) if condition.status == "True")
+    }
+
+    /// Returns `true` if the job has failed, ie it has a `Failed` condition with status `True`.
+    ///
+    /// A job fails once it exceeds its `backoffLimit` or `activeDeadlineSeconds`, and is not retried after that.
+    pub fn is_failed(&self) -> bool {
+        matches!(self.condition("Failed"), Some(condition) if condition.status == "True")
+    }
+
+    /// Returns `true` if the job has either [completed](Self::is_complete) or [failed](Self::is_failed).
+    pub fn is_finished(&self) -> bool {
+        self.is_complete() || self.is_failed()
+    }
+
+    /// The number of pods of the job that are currently running.
+    pub fn active_pods(&self) -> i32 {
+        self.status.as_ref().and_then(|status| status.active).unwrap_or_default()
+    }
+
+    /// The number of pods of the job that have succeeded.
+    pub fn succeeded_pods(&self) -> i32 {
+        self.status.as_ref().and_then(|status| status.succeeded).unwrap_or_default()
+    }
+
+    /// The number of pods of the job that have failed.
+    pub fn failed_pods(&self) -> i32 {
+        self.status.as_ref().and_then(|status| status.failed).unwrap_or_default()
+    }
+}
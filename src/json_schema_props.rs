@@ -0,0 +1,108 @@
+// Pruning of custom resources according to their structural schemas, and helpers to build schemas that use `x-kubernetes-preserve-unknown-fields`.
+//
+// Ref: https://kubernetes.io/docs/tasks/extend-kubernetes/custom-resources/custom-resource-definitions/#field-pruning
+
+use std::fmt::Write;
+
+use crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::{JSONSchemaProps, JSONSchemaPropsOrArray, JSONSchemaPropsOrBool};
+
+/// The fields that are never pruned from the root of a custom resource or from an embedded resource.
+const TYPE_META_AND_METADATA: &[&str] = &["apiVersion", "kind", "metadata"];
+
+impl JSONSchemaProps {
+    /// A schema of an object whose fields are not specified by the schema, and which the API server must therefore not prune.
+    ///
+    /// Use this for fields of custom resources that hold arbitrary user-provided objects, like Helm values.
+    pub fn preserve_unknown_fields() -> Self {
+        JSONSchemaProps {
+            type_: Some("object".to_owned()),
+            x_kubernetes_preserve_unknown_fields: Some(true),
+            ..Default::default()
+        }
+    }
+
+    /// Whether the API server preserves the fields of objects validated by this schema that are not specified by it.
+    pub fn preserves_unknown_fields(&self) -> bool {
+        self.x_kubernetes_preserve_unknown_fields == Some(true)
+    }
+
+    /// Removes all the fields from the given custom resource that the API server would prune when the resource is created or updated,
+    /// if this schema was the `openAPIV3Schema` of the custom resource's version.
+    ///
+    /// A field is pruned if it is not specified by the `properties` or `additionalProperties` of the schema of its object, unless that schema
+    /// preserves unknown fields. The `apiVersion`, `kind` and `metadata` fields of the resource and of embedded resources are never pruned.
+    ///
+    /// Returns the paths of the pruned fields, like `.spec.replicas`.
+    pub fn prune(&self, value: &mut serde_json::Value) -> Vec<String> {
+        let mut pruned = vec![];
+        prune(self, value, true, &mut String::new(), &mut pruned);
+        pruned
+    }
+
+    /// Returns the paths of the fields of the given custom resource that the API server would prune, like [`JSONSchemaProps::prune`],
+    /// without removing them.
+    ///
+    /// This can be used to reject custom resources with unknown fields, such as ones with misspelled field names.
+    pub fn unknown_fields(&self, value: &serde_json::Value) -> Vec<String> {
+        self.prune(&mut value.clone())
+    }
+}
+
+fn prune(schema: &JSONSchemaProps, value: &mut serde_json::Value, is_resource: bool, path: &mut String, pruned: &mut Vec<String>) {
+    match value {
+        serde_json::Value::Object(fields) => {
+            let is_resource = is_resource || schema.x_kubernetes_embedded_resource == Some(true);
+
+            fields.retain(|name, value| {
+                if is_resource && TYPE_META_AND_METADATA.contains(&&**name) {
+                    return true;
+                }
+
+                let len = path.len();
+                path.push('.');
+                path.push_str(name);
+
+                let field_schema =
+                    schema.properties.as_ref().and_then(|properties| properties.get(name))
+                    .or(match &schema.additional_properties {
+                        Some(JSONSchemaPropsOrBool::Schema(field_schema)) => Some(&**field_schema),
+                        _ => None,
+                    });
+                let retain = match (field_schema, &schema.additional_properties) {
+                    (Some(field_schema), _) => {
+                        prune(field_schema, value, false, path, pruned);
+                        true
+                    },
+
+                    (None, Some(JSONSchemaPropsOrBool::Bool(true))) => true,
+
+                    (None, _) => schema.preserves_unknown_fields(),
+                };
+                if !retain {
+                    pruned.push(path.clone());
+                }
+
+                path.truncate(len);
+                retain
+            });
+        },
+
+        serde_json::Value::Array(items) => {
+            for (i, item) in items.iter_mut().enumerate() {
+                let item_schema = match &schema.items {
+                    Some(JSONSchemaPropsOrArray::Schema(item_schema)) => Some(&**item_schema),
+                    Some(JSONSchemaPropsOrArray::Schemas(item_schemas)) => item_schemas.get(i),
+                    None => None,
+                };
+                if let Some(item_schema) = item_schema {
+                    let len = path.len();
+                    let _ = write!(path, "[{i}]");
+                    prune(item_schema, item, false, path, pruned);
+                    path.truncate(len);
+                }
+            }
+        },
+
+        _ => (),
+    }
+}
@@ -0,0 +1,113 @@
+use crate::AnyObject;
+
+type DeserializeAnyObject = fn(serde_json::Value) -> Result<Box<dyn AnyObject>, serde_json::Error>;
+
+/// A registry of resource types by their API version and kind, for deserializing objects whose type is only known at runtime
+/// as [`AnyObject`]s.
+///
+/// This is useful for the output of `kubectl get -o json` and similar documents, which are `kind: List` objects whose items can be of any kind.
+///
+/// ```rust
+/// # #[cfg(feature = "reflection")] {
+/// let list = k8s_openapi::serde_json::json!({
+///     "apiVersion": "v1",
+///     "kind": "List",
+///     "items": [
+///         { "apiVersion": "v1", "kind": "Service", "metadata": { "name": "web" } },
+///         { "apiVersion": "apps/v1", "kind": "Deployment", "metadata": { "name": "web" } },
+///     ],
+/// });
+///
+/// let objects = k8s_openapi::KindRegistry::builtin().deserialize_list(list).unwrap();
+/// assert_eq!(objects.iter().map(|object| object.kind()).collect::<Vec<_>>(), ["Service", "Deployment"]);
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct KindRegistry {
+    kinds: std::collections::BTreeMap<(&'static str, &'static str), DeserializeAnyObject>,
+}
+
+impl KindRegistry {
+    /// A registry without any types. Register types with [`KindRegistry::register`].
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// A registry of all the resource types of this crate whose metadata is an `ObjectMeta`, ie everything except lists and the like.
+    ///
+    /// Types of custom resources can be added to it with [`KindRegistry::register`].
+    #[cfg(feature = "reflection")]
+    pub fn builtin() -> Self {
+        let kinds =
+            crate::reflection::types().iter()
+            .filter_map(|type_info| {
+                let resource = type_info.resource.as_ref()?;
+                Some(((resource.api_version, resource.kind), resource.deserialize_any_object?))
+            })
+            .collect();
+        KindRegistry { kinds }
+    }
+
+    /// Registers the resource type `T`, such as a custom resource type. It replaces any type with the same API version and kind.
+    pub fn register<T>(&mut self) where T: AnyObject + serde::de::DeserializeOwned + crate::Resource + 'static {
+        self.kinds.insert((T::API_VERSION, T::KIND), deserialize_any_object::<T>);
+    }
+
+    /// Returns `true` if a type with the given API version and kind is registered.
+    pub fn contains(&self, api_version: &str, kind: &str) -> bool {
+        self.deserialize_fn(api_version, kind).is_some()
+    }
+
+    /// Deserializes the given object as the registered type of its `apiVersion` and `kind`.
+    ///
+    /// Fails if the object does not have an `apiVersion` and `kind`, or if there is no type registered for them.
+    pub fn deserialize_object(&self, value: serde_json::Value) -> Result<Box<dyn AnyObject>, serde_json::Error> {
+        let api_version = value.get("apiVersion").and_then(serde_json::Value::as_str);
+        let kind = value.get("kind").and_then(serde_json::Value::as_str);
+        let (Some(api_version), Some(kind)) = (api_version, kind) else {
+            return Err(serde::de::Error::custom("object does not have an apiVersion and kind"));
+        };
+
+        let Some(deserialize) = self.deserialize_fn(api_version, kind) else {
+            return Err(serde::de::Error::custom(format_args!("no type is registered for {api_version} {kind}")));
+        };
+
+        deserialize(value)
+    }
+
+    /// Deserializes the items of the given list, like a `kind: List` document, as the registered types of their `apiVersion`s and `kind`s.
+    ///
+    /// Fails if the list does not have `items`, or if any of the items cannot be [deserialized](KindRegistry::deserialize_object).
+    /// The error of an item includes its index, like `items[3]: no type is registered for example.com/v1 Foo`.
+    pub fn deserialize_list(&self, value: serde_json::Value) -> Result<Vec<Box<dyn AnyObject>>, serde_json::Error> {
+        let items = match value {
+            serde_json::Value::Object(mut list) => list.remove("items"),
+            _ => None,
+        };
+        let items = match items {
+            Some(serde_json::Value::Array(items)) => items,
+            Some(serde_json::Value::Null) => vec![],
+            _ => return Err(serde::de::Error::custom("list does not have items")),
+        };
+
+        items.into_iter()
+            .enumerate()
+            .map(|(i, item)| self.deserialize_object(item).map_err(|err| serde::de::Error::custom(format_args!("items[{i}]: {err}"))))
+            .collect()
+    }
+
+    fn deserialize_fn(&self, api_version: &str, kind: &str) -> Option<DeserializeAnyObject> {
+        let kinds: &std::collections::BTreeMap<(&str, &str), DeserializeAnyObject> = &self.kinds;
+        kinds.get(&(api_version, kind)).copied()
+    }
+}
+
+/// Deserializes a value of the resource type `T` as an [`AnyObject`]. The type registry of the generated types refers to this function
+/// for each resource type.
+pub(crate) fn deserialize_any_object<T>(value: serde_json::Value) -> Result<Box<dyn AnyObject>, serde_json::Error>
+where
+    T: AnyObject + serde::de::DeserializeOwned + 'static,
+{
+    let value: T = serde_json::from_value(value)?;
+    Ok(Box::new(value))
+}
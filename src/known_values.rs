@@ -0,0 +1,197 @@
+// `set_*_checked` setters for fields that only accept a fixed set of values, like `Container::image_pull_policy`.
+//
+// The types of these fields are generated string enums, and the values that Kubernetes accepts for them are the `VALUES` of those enums.
+// The generated enums accept any string through their `Other` variant, since a value that a newer version of Kubernetes accepts
+// may not have been added yet. These setters are for callers that want such values to be rejected instead.
+
+use crate::api::apps::v1::{
+    DaemonSetUpdateStrategy, DaemonSetUpdateStrategyType, DeploymentStrategy, DeploymentStrategyType, PodManagementPolicyType, StatefulSetSpec,
+    StatefulSetUpdateStrategy, StatefulSetUpdateStrategyType,
+};
+use crate::api::core::v1::{
+    Container, ContainerPort, DNSPolicy, NodeSelectorOperator, NodeSelectorRequirement, PersistentVolumeReclaimPolicy, PersistentVolumeSpec, PodSpec,
+    Protocol, PullPolicy, RestartPolicy, ServiceAffinity, ServiceExternalTrafficPolicyType, ServicePort, ServiceSpec, ServiceType, Taint, TaintEffect,
+    TerminationMessagePolicy, Toleration, TolerationOperator,
+};
+use crate::apimachinery::pkg::apis::meta::v1::{LabelSelectorOperator, LabelSelectorRequirement};
+
+macro_rules! checked_setters {
+    ($($ty:ty {
+        $($setter:ident => $field:ident ($name:literal): $enum:ident,)*
+    })*) => {
+        $(
+            impl $ty {
+                $(
+                    #[doc = concat!("Sets `", $name, "` to the given value, if it is one of the values that Kubernetes accepts for it.")]
+                    pub fn $setter(&mut self, value: impl Into<String>) -> Result<(), InvalidValue> {
+                        let value = check($name, $enum::VALUES, value.into())?;
+                        SetValue::set_value(&mut self.$field, value);
+                        Ok(())
+                    }
+                )*
+            }
+        )*
+    };
+}
+
+checked_setters! {
+    Container {
+        set_image_pull_policy_checked => image_pull_policy ("imagePullPolicy"): PullPolicy,
+        set_termination_message_policy_checked => termination_message_policy ("terminationMessagePolicy"): TerminationMessagePolicy,
+    }
+
+    ContainerPort {
+        set_protocol_checked => protocol ("protocol"): Protocol,
+    }
+
+    DaemonSetUpdateStrategy {
+        set_type_checked => type_ ("type"): DaemonSetUpdateStrategyType,
+    }
+
+    DeploymentStrategy {
+        set_type_checked => type_ ("type"): DeploymentStrategyType,
+    }
+
+    LabelSelectorRequirement {
+        set_operator_checked => operator ("operator"): LabelSelectorOperator,
+    }
+
+    NodeSelectorRequirement {
+        set_operator_checked => operator ("operator"): NodeSelectorOperator,
+    }
+
+    PersistentVolumeSpec {
+        set_persistent_volume_reclaim_policy_checked => persistent_volume_reclaim_policy ("persistentVolumeReclaimPolicy"): PersistentVolumeReclaimPolicy,
+    }
+
+    PodSpec {
+        set_dns_policy_checked => dns_policy ("dnsPolicy"): DNSPolicy,
+        set_restart_policy_checked => restart_policy ("restartPolicy"): RestartPolicy,
+    }
+
+    ServicePort {
+        set_protocol_checked => protocol ("protocol"): Protocol,
+    }
+
+    ServiceSpec {
+        set_external_traffic_policy_checked => external_traffic_policy ("externalTrafficPolicy"): ServiceExternalTrafficPolicyType,
+        set_session_affinity_checked => session_affinity ("sessionAffinity"): ServiceAffinity,
+        set_type_checked => type_ ("type"): ServiceType,
+    }
+
+    StatefulSetSpec {
+        set_pod_management_policy_checked => pod_management_policy ("podManagementPolicy"): PodManagementPolicyType,
+    }
+
+    StatefulSetUpdateStrategy {
+        set_type_checked => type_ ("type"): StatefulSetUpdateStrategyType,
+    }
+
+    Taint {
+        set_effect_checked => effect ("effect"): TaintEffect,
+    }
+
+    Toleration {
+        set_effect_checked => effect ("effect"): TaintEffect,
+        set_operator_checked => operator ("operator"): TolerationOperator,
+    }
+}
+
+k8s_if_ge_1_16! {
+    checked_setters! {
+        crate::api::core::v1::EphemeralContainer {
+            set_image_pull_policy_checked => image_pull_policy ("imagePullPolicy"): PullPolicy,
+            set_termination_message_policy_checked => termination_message_policy ("terminationMessagePolicy"): TerminationMessagePolicy,
+        }
+    }
+}
+
+/// The definition paths of the string enum types and the values that Kubernetes accepts for them, for [`crate::Validate`].
+#[cfg(feature = "reflection")]
+pub(crate) const KNOWN_VALUES: &[(&str, &[&str])] = &[
+    ("io.k8s.api.apps.v1.DaemonSetUpdateStrategyType", DaemonSetUpdateStrategyType::VALUES),
+    ("io.k8s.api.apps.v1.DeploymentStrategyType", DeploymentStrategyType::VALUES),
+    ("io.k8s.api.apps.v1.PodManagementPolicyType", PodManagementPolicyType::VALUES),
+    ("io.k8s.api.apps.v1.StatefulSetUpdateStrategyType", StatefulSetUpdateStrategyType::VALUES),
+    ("io.k8s.api.core.v1.DNSPolicy", DNSPolicy::VALUES),
+    ("io.k8s.api.core.v1.NodeSelectorOperator", NodeSelectorOperator::VALUES),
+    ("io.k8s.api.core.v1.PersistentVolumeReclaimPolicy", PersistentVolumeReclaimPolicy::VALUES),
+    ("io.k8s.api.core.v1.Protocol", Protocol::VALUES),
+    ("io.k8s.api.core.v1.PullPolicy", PullPolicy::VALUES),
+    ("io.k8s.api.core.v1.RestartPolicy", RestartPolicy::VALUES),
+    ("io.k8s.api.core.v1.ServiceAffinity", ServiceAffinity::VALUES),
+    ("io.k8s.api.core.v1.ServiceExternalTrafficPolicyType", ServiceExternalTrafficPolicyType::VALUES),
+    ("io.k8s.api.core.v1.ServiceType", ServiceType::VALUES),
+    ("io.k8s.api.core.v1.TaintEffect", TaintEffect::VALUES),
+    ("io.k8s.api.core.v1.TerminationMessagePolicy", TerminationMessagePolicy::VALUES),
+    ("io.k8s.api.core.v1.TolerationOperator", TolerationOperator::VALUES),
+    ("io.k8s.apimachinery.pkg.apis.meta.v1.LabelSelectorOperator", LabelSelectorOperator::VALUES),
+];
+
+fn check(field: &'static str, known_values: &'static [&'static str], value: String) -> Result<String, InvalidValue> {
+    if known_values.contains(&&*value) {
+        Ok(value)
+    }
+    else {
+        Err(InvalidValue { field, value, known_values })
+    }
+}
+
+/// Sets both required and optional fields.
+trait SetValue {
+    fn set_value(&mut self, value: String);
+}
+
+impl<T> SetValue for Option<T> where T: From<String> {
+    fn set_value(&mut self, value: String) {
+        *self = Some(value.into());
+    }
+}
+
+// The types of the required fields. A blanket impl for `T: From<String>` would conflict with the one for `Option<T>`.
+macro_rules! set_value_required {
+    ($($ty:ty,)*) => {
+        $(
+            impl SetValue for $ty {
+                fn set_value(&mut self, value: String) {
+                    *self = value.into();
+                }
+            }
+        )*
+    };
+}
+
+set_value_required! {
+    LabelSelectorOperator,
+    NodeSelectorOperator,
+    TaintEffect,
+}
+
+/// The error returned by the `set_*_checked` setters, like [`Container::set_image_pull_policy_checked`],
+/// when the value is not one of the values that Kubernetes accepts for the field.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvalidValue {
+    field: &'static str,
+    value: String,
+    known_values: &'static [&'static str],
+}
+
+impl InvalidValue {
+    /// The values that Kubernetes accepts for the field.
+    pub fn known_values(&self) -> &'static [&'static str] {
+        self.known_values
+    }
+
+    /// The rejected value.
+    pub fn into_value(self) -> String {
+        self.value
+    }
+}
+
+impl std::fmt::Display for InvalidValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid {} {:?}, expected one of {}", self.field, self.value, self.known_values.join(", "))
+    }
+}
+
+impl std::error::Error for InvalidValue {}
@@ -0,0 +1,32 @@
+// Lookups of the named entries of a kubeconfig file.
+
+use crate::client_go::tools::clientcmd::api::v1::{AuthInfo, Cluster, Config, Context};
+
+impl Config {
+    /// Gets the cluster with the given name.
+    ///
+    /// If there are multiple clusters with the same name, the first one is used. client-go rejects such files instead.
+    pub fn cluster(&self, name: &str) -> Option<&Cluster> {
+        self.clusters.iter().flatten().find(|named| named.name == name).map(|named| &named.cluster)
+    }
+
+    /// Gets the context with the given name.
+    ///
+    /// If there are multiple contexts with the same name, the first one is used. client-go rejects such files instead.
+    pub fn context(&self, name: &str) -> Option<&Context> {
+        self.contexts.iter().flatten().find(|named| named.name == name).map(|named| &named.context)
+    }
+
+    /// Gets the user with the given name.
+    ///
+    /// If there are multiple users with the same name, the first one is used. client-go rejects such files instead.
+    pub fn auth_info(&self, name: &str) -> Option<&AuthInfo> {
+        self.users.iter().flatten().find(|named| named.name == name).map(|named| &named.user)
+    }
+
+    /// Gets the context named by `current-context`, if it is set and such a context exists.
+    pub fn active_context(&self) -> Option<&Context> {
+        let name = self.current_context.as_deref().filter(|name| !name.is_empty())?;
+        self.context(name)
+    }
+}
@@ -0,0 +1,32 @@
+use crate::apimachinery::pkg::apis::meta::v1::{LabelSelector, LabelSelectorOperator, LabelSelectorRequirement};
+
+impl LabelSelector {
+    /// Returns `true` if the given set of labels satisfies this selector.
+    ///
+    /// All `matchLabels` and `matchExpressions` must be satisfied. An empty selector matches all sets of labels.
+    /// A requirement with an unknown operator does not match anything.
+    pub fn matches(&self, labels: &std::collections::BTreeMap<String, String>) -> bool {
+        let match_labels_satisfied =
+            self.match_labels.iter().flatten()
+            .all(|(key, value)| labels.get(key) == Some(value));
+
+        match_labels_satisfied &&
+            self.match_expressions.iter().flatten().all(|requirement| requirement.matches(labels))
+    }
+}
+
+impl LabelSelectorRequirement {
+    /// Returns `true` if the given set of labels satisfies this requirement.
+    pub fn matches(&self, labels: &std::collections::BTreeMap<String, String>) -> bool {
+        let value = labels.get(&self.key);
+        let values = self.values.as_deref().unwrap_or_default();
+
+        match self.operator {
+            LabelSelectorOperator::In => matches!(value, Some(value) if values.contains(value)),
+            LabelSelectorOperator::NotIn => !matches!(value, Some(value) if values.contains(value)),
+            LabelSelectorOperator::Exists => value.is_some(),
+            LabelSelectorOperator::DoesNotExist => value.is_none(),
+            LabelSelectorOperator::Other(_) => false,
+        }
+    }
+}
@@ -0,0 +1,120 @@
+// Leader-election record semantics for `coordination.k8s.io/v1` `Lease`, matching the `LeaseLock` of client-go's leaderelection package.
+//
+// These helpers are pure functions of the lease and the given time. Callers are responsible for reading and replacing the lease via the API.
+
+use crate::api::coordination::v1::{Lease, LeaseSpec};
+use crate::apimachinery::pkg::apis::meta::v1::MicroTime;
+use std::convert::TryFrom;
+
+impl Lease {
+    /// The identity of the current holder of this lease, if any.
+    ///
+    /// An empty holder identity is treated the same as no holder, as it is by client-go when a leader releases the lease.
+    pub fn holder_identity(&self) -> Option<&str> {
+        self.spec.as_ref()
+            .and_then(|spec| spec.holder_identity.as_deref())
+            .filter(|holder_identity| !holder_identity.is_empty())
+    }
+
+    /// Returns `true` if this lease is currently held by the given identity. This does not check whether the lease has expired.
+    pub fn is_held_by(&self, identity: &str) -> bool {
+        self.holder_identity() == Some(identity)
+    }
+
+    /// The time at which this lease expires, ie its renew time plus its duration.
+    ///
+    /// Returns `None` if the lease does not have a renew time or a duration.
+    pub fn expiry_time(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        let spec = self.spec.as_ref()?;
+        let MicroTime(renew_time) = spec.renew_time.as_ref()?;
+        let lease_duration_seconds = spec.lease_duration_seconds?;
+        Some(*renew_time + chrono::Duration::seconds(lease_duration_seconds.into()))
+    }
+
+    /// Returns `true` if this lease is free to be acquired at the given time, either because it has no holder or because the holder has not renewed it in time.
+    ///
+    /// A lease with a holder but no renew time or duration is considered expired.
+    ///
+    /// Note that this compares the lease's renew time, which was set by the holder's clock, against `now`. Callers that need to be robust against clock skew
+    /// should instead track the time at which they themselves last observed a change to the lease, like client-go does.
+    pub fn is_expired(&self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        if self.holder_identity().is_none() {
+            return true;
+        }
+
+        match self.expiry_time() {
+            Some(expiry_time) => expiry_time <= now,
+            None => true,
+        }
+    }
+
+    /// Computes the lease that `identity` should write to acquire this lease, or to renew it if it already holds it.
+    ///
+    /// Returns `None` if the lease is held by a different identity and has not expired yet.
+    ///
+    /// The returned lease keeps this lease's metadata, including its `resourceVersion`, so replacing the lease with it will fail
+    /// if another candidate updated the lease in the meantime. When the holder changes, `acquireTime` is reset and `leaseTransitions` is incremented.
+    pub fn acquire_or_renew(&self, identity: &str, lease_duration: chrono::Duration, now: chrono::DateTime<chrono::Utc>) -> Option<Self> {
+        let is_held_by_identity = self.is_held_by(identity);
+        if !is_held_by_identity && !self.is_expired(now) {
+            return None;
+        }
+
+        let old_spec = self.spec.clone().unwrap_or_default();
+        let lease_duration_seconds = i32::try_from(lease_duration.num_seconds()).unwrap_or(i32::MAX);
+
+        let spec =
+            if is_held_by_identity {
+                LeaseSpec {
+                    lease_duration_seconds: Some(lease_duration_seconds),
+                    renew_time: Some(MicroTime(now)),
+                    ..old_spec
+                }
+            }
+            else {
+                LeaseSpec {
+                    acquire_time: Some(MicroTime(now)),
+                    holder_identity: Some(identity.to_owned()),
+                    lease_duration_seconds: Some(lease_duration_seconds),
+                    lease_transitions: Some(match (&old_spec.holder_identity, old_spec.lease_transitions) {
+                        // The lease has never been held before
+                        (None, None) => 0,
+                        (_, lease_transitions) => lease_transitions.unwrap_or_default() + 1,
+                    }),
+                    renew_time: Some(MicroTime(now)),
+                }
+            };
+
+        Some(Lease {
+            metadata: self.metadata.clone(),
+            spec: Some(spec),
+        })
+    }
+
+    /// Computes the lease that `identity` should write to release this lease, so that other candidates can acquire it without waiting for it to expire.
+    ///
+    /// Returns `None` if the lease is not held by `identity`.
+    pub fn release(&self, identity: &str, now: chrono::DateTime<chrono::Utc>) -> Option<Self> {
+        if !self.is_held_by(identity) {
+            return None;
+        }
+
+        let old_spec = self.spec.clone().unwrap_or_default();
+
+        Some(Lease {
+            metadata: self.metadata.clone(),
+            spec: Some(LeaseSpec {
+                acquire_time: Some(MicroTime(now)),
+                holder_identity: None,
+                lease_duration_seconds: Some(1),
+                lease_transitions: old_spec.lease_transitions,
+                renew_time: Some(MicroTime(now)),
+            }),
+        })
+    }
+
+    /// Returns `true` if the holder of this lease is different from the holder of the given previously observed lease.
+    pub fn holder_changed(&self, previous: &Lease) -> bool {
+        self.holder_identity() != previous.holder_identity()
+    }
+}
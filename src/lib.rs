@@ -9,9 +9,13 @@
     clippy::missing_errors_doc,
     clippy::module_name_repetitions,
     clippy::must_use_candidate,
+    clippy::should_implement_trait,
     clippy::single_match_else,
+    clippy::struct_field_names,
+    clippy::too_many_arguments,
     clippy::too_many_lines,
     clippy::type_complexity,
+    clippy::unnecessary_map_or, // Option::is_none_or needs Rust 1.82
     clippy::upper_case_acronyms,
     clippy::use_self,
 )]
@@ -53,6 +57,10 @@
 //! }
 //! ```
 //!
+//! The resource types implement `serde::Deserialize` and `serde::Serialize` according to their JSON representation in the Kubernetes API.
+//! They can also be serialized with binary formats that are not self-describing, like `bincode` and `postcard`, such as to persist a cache of resources.
+//! Such formats use their own representation that includes all fields of the type, and is not compatible across different versions of this crate.
+//!
 //! ## Client API
 //!
 //! (This requires the `api` feature to be enabled. The feature is enabled by default. See ["Crate features"](#crate-features) below for more details.)
@@ -227,10 +235,78 @@
 //!
 //! - The crate also contains a feature named `api`. If this feature is disabled, the library will only contain the resource types like [`api::core::v1::Pod`],
 //!   and not the associated operation functions like [`api::core::v1::Pod::read_namespaced_pod`]. The `Response` and `Optional` types for the operation functions
-//!   will also not be accessible.
+//!   will also not be accessible. The URL paths of resources, like [`Resource::object_url_path`], are still available
+//!   for clients that construct their own requests.
 //!
 //!   This feature is enabled by default, but can be disabled if your crate does not need the operation functions to save on compile time and resources.
 //!
+//! - The `gateway-api` feature adds the `gateway_api` module, containing the types of the [Gateway API](https://gateway-api.sigs.k8s.io/)
+//!   (`gateway.networking.k8s.io`). The Gateway API is installed into clusters as CRDs rather than being part of Kubernetes itself,
+//!   so this feature is disabled by default. The module is only available with the `v1_20` feature or later.
+//!
+//! - The `arbitrary` feature implements [`arbitrary::Arbitrary`](https://docs.rs/arbitrary/1/arbitrary/trait.Arbitrary.html) for the resource types,
+//!   so that fuzzers can generate structurally valid objects. Generated lists and maps have at most [`arbitrary2::MAX_COLLECTION_LEN`] elements,
+//!   objects nested deeper than [`arbitrary2::MAX_DEPTH`] levels like those of `JSONSchemaProps` have no optional fields,
+//!   and generated `Quantity` values are well-formed quantities like `250m` or `1.5Gi`.
+//!
+//! - The `borrowed` feature adds the `borrowed` module, containing views of objects and lists like `ListRef<'_, ObjectRef<'_>>` that only contain the identifying
+//!   metadata of the objects, and whose strings borrow from the input instead of being allocated. This is useful for deserializing large lists,
+//!   like the initial list of an informer, when only the names, labels and resource versions of the objects are needed.
+//!
+//! - The `builder` feature adds a builder type for each struct, like `api::core::v1::PodSpecBuilder` for `PodSpec`, that is created with `PodSpec::builder()`.
+//!   Builders have a chainable setter for each field, and a `build` function that returns a `MissingFieldError` if a required field has not been set,
+//!   like the `containers` of a `PodSpec`. `ObjectMeta`, `Affinity` and `ResourceRequirements` have hand-written builders instead, which are always available.
+//!
+//! - The `schemars` feature implements [`schemars::JsonSchema`](https://docs.rs/schemars/0.8/schemars/trait.JsonSchema.html) for the resource types.
+//!   Their schemas are named after their OpenAPI definition paths, like `io.k8s.api.core.v1.PodSpec`. Enabling the `schemars-rust-names` feature
+//!   names them after their paths in this crate instead, like `api::core::v1::PodSpec`, for schema registries that do not allow dots in definition names.
+//!   The `schemars` feature also adds the `OpenApiFragment` type, which emits the definitions of a chosen set of types as a fragment of an OpenAPI document.
+//!
+//! - The `content-hash` feature adds `content_hash` and `append_content_hash_to_name` methods to `ConfigMap` and `Secret`, which compute the hash
+//!   of their contents that kustomize's generators and `kubectl create --append-hash` append to their names. It enables the `sha2` dependency.
+//!
+//! - The `cron` feature adds the `CronSchedule` type, a parser of the schedules of `CronJob`s, and `next_schedule_after` methods to `CronJob`
+//!   that compute when the CronJob controller will next create a job.
+//!
+//! - The `deserialize-in-place` feature implements `serde::Deserialize::deserialize_in_place` for the structs, [`List`] and `WatchEvent`,
+//!   so that [`DeserializeInPlace`] reuses the allocations of the existing value. Without it, [`DeserializeInPlace`] deserializes a new value
+//!   and replaces the existing one with it.
+//!
+//! - The `deserialize-with-path` feature adds the `deserialize_with_path` function, which deserializes a value like `T::deserialize` does,
+//!   but whose errors also contain the path of the field that could not be deserialized, like `.spec.containers[2].resources.limits.cpu`.
+//!   It enables the `serde_path_to_error` dependency. The `test_util` feature enables it too.
+//!
+//! - The `describe` feature adds the `describe` module, containing human-readable descriptions of pods, deployments, services and nodes
+//!   in the style of `kubectl describe`, for use in command-line tools.
+//!
+//! - The `erased-serde` feature adds the `AnyObject` trait, an object-safe view of resource values that can be serialized as trait objects,
+//!   so that values of different resource types can be stored and serialized together, such as in a `Vec<Box<dyn AnyObject>>`.
+//!   It also adds the `KindRegistry` type, which deserializes objects of different resource types, like the items of a `kind: List` document,
+//!   as `AnyObject`s by their `apiVersion` and `kind`, and the `apply_order` module, which sorts such objects into the order
+//!   in which they can be applied to or deleted from a cluster.
+//!
+//! - The `manifests` feature adds the `manifests` module, which loads the objects of multi-document YAML manifests, including the items of `kind: List` documents,
+//!   as the types that a `KindRegistry` has registered for their `apiVersion` and `kind`. It enables the `erased-serde` feature.
+//!
+//! - The `protobuf-envelope` feature adds the `protobuf_envelope` module, which encodes and decodes the `runtime.Unknown` envelope of objects in the protobuf encoding
+//!   of the Kubernetes API. It does not encode the objects inside the envelope themselves. They must be encoded with a separate protobuf library.
+//!
+//! - The `cbor` feature adds the `cbor` module, which encodes and decodes objects in the CBOR encoding of the Kubernetes API
+//!   that Kubernetes 1.32 and later support. CBOR libraries must not be used with the types of this crate directly, since serde considers them
+//!   to not be human-readable, so objects would be serialized in the compact form that this crate uses for formats like bincode.
+//!
+//! - The `reflection` feature adds the `reflection` module, containing descriptions of all the types of this crate that are available at runtime,
+//!   such as their group, version and kind, and the names, types and docs of their fields.
+//!   It also adds the `Validate` trait, which checks resource values against these descriptions, such as for missing required fields
+//!   and unsupported values of fields like `Container::image_pull_policy`, without sending them to an API server.
+//!
+//! - The `shallow` feature adds the `Shallow` wrapper of resources, which deserializes their metadata as usual but keeps their other fields,
+//!   like their `spec` and `status`, as raw JSON that is only deserialized on demand. It enables the `raw_value` feature of `serde_json`.
+//!
+//! - The `test_util` feature adds the `fixtures` module, containing builders of realistic resources like pods and deployments for use in tests,
+//!   and the `conformance` module, containing checks that JSON and YAML fixtures round-trip through a type.
+//!   It is meant to be enabled only in `[dev-dependencies]`.
+//!
 //! One and only one of the `v1_*` features must be enabled at the same time, otherwise the crate will not compile. This ensures that all crates in the crate graph
 //! use the same types. If it was possible for one library crate to use `api::core::v1::Pod` corresponding to v1.15 and another to use the type
 //! corresponding to v1.16, an application would not be able to use the same `Pod` value with both.
@@ -460,10 +536,16 @@
 //! The [`k8s-openapi-derive` crate](https://crates.io/crates/k8s-openapi-derive) provides a custom derive for generating clientsets
 //! for custom resources. See that crate's docs for more information.
 
+#[cfg(feature = "arbitrary")]
+pub use arbitrary;
+#[cfg(feature = "chrono")]
 pub use chrono;
+#[cfg(feature = "cbor")]
+pub use ciborium;
+#[cfg(feature = "erased-serde")]
+pub use erased_serde;
 #[cfg(feature = "api")]
 pub use http;
-#[cfg(feature = "api")]
 pub use percent_encoding;
 #[cfg(feature = "schemars")]
 pub use schemars;
@@ -476,7 +558,8 @@ pub use url;
 /// A wrapper around a list of bytes.
 ///
 /// Used in Kubernetes types whose JSON representation uses a base64-encoded string for a list of bytes.
-#[derive(Clone, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+/// Formats that are not human-readable, like bincode, store the bytes directly instead.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct ByteString(pub Vec<u8>);
 
 impl<'de> serde::Deserialize<'de> for ByteString {
@@ -493,15 +576,47 @@ impl<'de> serde::Deserialize<'de> for ByteString {
             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: serde::de::Error {
                 Ok(ByteString(base64::decode_config(v, base64::STANDARD).map_err(serde::de::Error::custom)?))
             }
+
+            fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E> where E: serde::de::Error {
+                Ok(ByteString(v.to_owned()))
+            }
+
+            fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E> where E: serde::de::Error {
+                Ok(ByteString(v))
+            }
         }
 
-        deserializer.deserialize_str(Visitor)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(Visitor)
+        }
+        else {
+            deserializer.deserialize_byte_buf(Visitor)
+        }
     }
 }
 
 impl serde::Serialize for ByteString {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
-        base64::encode_config(&self.0, base64::STANDARD).serialize(serializer)
+        // Formats that are not human-readable, like bincode, can store the bytes as they are.
+        // So can CBOR, even though the `cbor` module serializes objects in their human-readable form.
+        #[cfg(feature = "cbor")]
+        let is_human_readable = serializer.is_human_readable() && !cbor::is_encoding();
+        #[cfg(not(feature = "cbor"))]
+        let is_human_readable = serializer.is_human_readable();
+
+        if is_human_readable {
+            base64::encode_config(&self.0, base64::STANDARD).serialize(serializer)
+        }
+        else {
+            serializer.serialize_bytes(&self.0)
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ByteString {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(ByteString(u.arbitrary()?))
     }
 }
 
@@ -533,11 +648,198 @@ pub trait Resource {
     /// For example, [`api::authentication::v1::TokenRequest`]'s value is `"token"`, and its URLs look like `.../serviceaccounts/{name}/token`.
     const URL_PATH_SEGMENT: &'static str;
 
+    /// The verbs that the API server supports for the resource, like `"get"` and `"list"`, in alphabetical order.
+    ///
+    /// These are the verbs that the API server lists for the resource in its discovery documents. For subresources,
+    /// they are the verbs supported for the subresource, like `"create"` for [`api::authentication::v1::TokenRequest`].
+    const VERBS: &'static [&'static str] = &[];
+
+    /// The plural name of the resource, like `"pods"`, which is the name that the API server's discovery documents list it by.
+    ///
+    /// This is the same as [`Resource::URL_PATH_SEGMENT`], including for subresources.
+    const PLURAL: &'static str = Self::URL_PATH_SEGMENT;
+
+    /// The singular name of the resource, like `"pod"`, which is the lowercase [`Resource::KIND`].
+    ///
+    /// This is empty for types that are not resources of the API server themselves, like [`List`].
+    const SINGULAR: &'static str = "";
+
+    /// The short names of the resource, like `"po"` for pods, that `kubectl` accepts in place of its plural name.
+    ///
+    /// These are not part of the OpenAPI spec, so they're maintained by hand for the built-in resources of Kubernetes.
+    const SHORT_NAMES: &'static [&'static str] = &[];
+
     /// Indicates whether the resource is namespace-scoped or cluster-scoped or a subresource.
     ///
     /// If you need to restrict some generic code to resources of a specific scope, use this associated type to create a bound on the generic.
     /// For example, `fn foo<T: k8s_openapi::Resource<Scope = k8s_openapi::ClusterResourceScope>>() { }` can only be called with cluster-scoped resources.
     type Scope: ResourceScope;
+
+    /// Deserializes a value of this resource type from the given JSON value.
+    ///
+    /// Unlike `serde_json::from_value`, which only verifies the `apiVersion` and `kind` fields if they are present,
+    /// this requires both fields to be present and to match [`Resource::API_VERSION`] and [`Resource::KIND`].
+    /// So a value of a different resource type is rejected even if it happens to have the fields required by this type.
+    fn try_from_value(value: serde_json::Value) -> Result<Self, TryFromValueError> where Self: Sized + serde::de::DeserializeOwned {
+        let type_meta_field = |name| value.get(name).and_then(serde_json::Value::as_str);
+        let api_version = type_meta_field("apiVersion");
+        let kind = type_meta_field("kind");
+        if api_version != Some(Self::API_VERSION) || kind != Some(Self::KIND) {
+            return Err(TryFromValueError::TypeMismatch {
+                expected_api_version: Self::API_VERSION,
+                expected_kind: Self::KIND,
+                api_version: api_version.map(ToOwned::to_owned),
+                kind: kind.map(ToOwned::to_owned),
+            });
+        }
+
+        serde_json::from_value(value).map_err(TryFromValueError::Json)
+    }
+
+    /// Serializes this resource value into a JSON value.
+    ///
+    /// The value always has the `apiVersion` and `kind` fields set to [`Resource::API_VERSION`] and [`Resource::KIND`],
+    /// so it can be deserialized again with [`Resource::try_from_value`].
+    fn to_value(&self) -> Result<serde_json::Value, serde_json::Error> where Self: serde::Serialize {
+        let mut value = serde_json::to_value(self)?;
+        if let serde_json::Value::Object(fields) = &mut value {
+            fields.insert("apiVersion".to_owned(), Self::API_VERSION.into());
+            fields.insert("kind".to_owned(), Self::KIND.into());
+        }
+        Ok(value)
+    }
+
+    /// An [`APIResource`](apimachinery::pkg::apis::meta::v1::APIResource) that describes this resource type
+    /// the way the API server describes it in its discovery documents, such as for a mock of the discovery API.
+    ///
+    /// Its `group` and `version` are `None`, like those of the resources in the discovery document of their own group and version.
+    /// The API server only sets them for subresources whose kind is from a different group or version, like the `autoscaling/v1` `Scale` of a `Deployment`.
+    ///
+    /// For subresources, the name of the `APIResource` is only the subresource's [`Resource::URL_PATH_SEGMENT`] like `token`,
+    /// while the API server qualifies it with the name of the parent resource like `serviceaccounts/token`.
+    fn api_resource() -> apimachinery::pkg::apis::meta::v1::APIResource where Self::Scope: 'static {
+        apimachinery::pkg::apis::meta::v1::APIResource {
+            group: None,
+            kind: Self::KIND.to_owned(),
+            name: Self::PLURAL.to_owned(),
+            namespaced: std::any::TypeId::of::<Self::Scope>() == std::any::TypeId::of::<NamespaceResourceScope>(),
+            short_names: if Self::SHORT_NAMES.is_empty() { None } else { Some(Self::SHORT_NAMES.iter().map(|&short_name| short_name.to_owned()).collect()) },
+            singular_name: if Self::SINGULAR.is_empty() { Self::KIND.to_lowercase() } else { Self::SINGULAR.to_owned() },
+            verbs: Self::VERBS.iter().map(|&verb| verb.to_owned()).collect(),
+            version: None,
+            ..Default::default()
+        }
+    }
+
+    /// The URL path of the collection of resources of this type, like `/apis/apps/v1/namespaces/default/deployments`.
+    ///
+    /// If `namespace` is `None`, this is the path of the resources in all namespaces, like `/apis/apps/v1/deployments`.
+    /// It must be `None` for cluster-scoped resources. This is not meaningful for subresources.
+    ///
+    /// This and the other `*_url_path` functions are available without the `api` feature, for clients that construct their own requests.
+    /// The namespace and name are percent-encoded.
+    fn url_path(namespace: Option<&str>) -> String {
+        let mut url_path =
+            if Self::GROUP.is_empty() {
+                format!("/api/{}", Self::VERSION)
+            }
+            else {
+                format!("/apis/{}/{}", Self::GROUP, Self::VERSION)
+            };
+        if let Some(namespace) = namespace {
+            url_path.push_str("/namespaces/");
+            url_path.extend(percent_encoding::percent_encode(namespace.as_bytes(), percent_encoding2::PATH_SEGMENT_ENCODE_SET));
+        }
+        url_path.push('/');
+        url_path.push_str(Self::URL_PATH_SEGMENT);
+        url_path
+    }
+
+    /// The URL path of the resource of this type with the given name, like `/api/v1/namespaces/default/pods/web-0`.
+    ///
+    /// `namespace` must be `Some` for namespace-scoped resources and `None` for cluster-scoped resources.
+    fn object_url_path(namespace: Option<&str>, name: &str) -> String {
+        let mut url_path = Self::url_path(namespace);
+        url_path.push('/');
+        url_path.extend(percent_encoding::percent_encode(name.as_bytes(), percent_encoding2::PATH_SEGMENT_ENCODE_SET));
+        url_path
+    }
+
+    /// The URL path of a subresource of the resource of this type with the given name,
+    /// like `/api/v1/namespaces/default/pods/web-0/log` for the `log` subresource of a pod.
+    ///
+    /// `namespace` must be `Some` for namespace-scoped resources and `None` for cluster-scoped resources.
+    fn subresource_url_path(namespace: Option<&str>, name: &str, subresource: &str) -> String {
+        let mut url_path = Self::object_url_path(namespace, name);
+        url_path.push('/');
+        url_path.push_str(subresource);
+        url_path
+    }
+}
+
+/// The type of errors returned by [`Resource::try_from_value`].
+#[derive(Debug)]
+pub enum TryFromValueError {
+    /// The `apiVersion` or `kind` of the value do not match the resource type, or are missing.
+    TypeMismatch {
+        /// The API version of the resource type.
+        expected_api_version: &'static str,
+
+        /// The kind of the resource type.
+        expected_kind: &'static str,
+
+        /// The `apiVersion` of the value, or `None` if it is missing or not a string.
+        api_version: Option<String>,
+
+        /// The `kind` of the value, or `None` if it is missing or not a string.
+        kind: Option<String>,
+    },
+
+    /// An error while deserializing the value as the resource type.
+    Json(serde_json::Error),
+}
+
+impl std::fmt::Display for TryFromValueError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TryFromValueError::TypeMismatch { expected_api_version, expected_kind, api_version, kind } => {
+                let api_version = api_version.as_deref().unwrap_or("<missing apiVersion>");
+                let kind = kind.as_deref().unwrap_or("<missing kind>");
+                write!(f, "expected {expected_api_version} {expected_kind} but got {api_version} {kind}")
+            },
+            TryFromValueError::Json(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for TryFromValueError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TryFromValueError::TypeMismatch { .. } => None,
+            TryFromValueError::Json(err) => Some(err),
+        }
+    }
+}
+
+/// Converts a resource value into another version of the same resource kind, through their shared JSON representation.
+///
+/// This is used by the `std::convert::TryFrom` impls between the adjacent versions of each resource kind,
+/// like the one from `api::networking::v1beta1::Ingress` to `api::networking::v1::Ingress`.
+/// The value is deserialized as a [`Strict`], so the conversion fails instead of silently dropping the fields that the target version does not have.
+/// Fields that were renamed or restructured between the versions are not mapped, so the conversion also fails if the value sets them.
+#[doc(hidden)]
+pub fn convert_resource_version<T, U>(value: T) -> Result<U, TryFromValueError>
+where
+    T: Resource + serde::Serialize,
+    U: Resource + serde::de::DeserializeOwned,
+{
+    let mut value = serde_json::to_value(value).map_err(TryFromValueError::Json)?;
+    if let serde_json::Value::Object(fields) = &mut value {
+        fields.insert("apiVersion".to_owned(), U::API_VERSION.into());
+        fields.insert("kind".to_owned(), U::KIND.into());
+    }
+    let Strict(value) = serde_json::from_value(value).map_err(TryFromValueError::Json)?;
+    Ok(value)
 }
 
 /// A trait applied to all Kubernetes resources that can be part of a corresponding list.
@@ -558,6 +860,367 @@ pub trait Metadata: Resource {
 
     /// Gets a mutable reference to the metadata of this resource value.
     fn metadata_mut(&mut self) -> &mut<Self as Metadata>::Ty;
+
+    /// Gets the name of this resource value, if it has one.
+    fn name(&self) -> Option<&str> where Self: Metadata<Ty = apimachinery::pkg::apis::meta::v1::ObjectMeta> {
+        self.metadata().name.as_deref()
+    }
+
+    /// Gets the namespace of this resource value, if it has one.
+    fn namespace(&self) -> Option<&str> where Self: Metadata<Ty = apimachinery::pkg::apis::meta::v1::ObjectMeta> {
+        self.metadata().namespace.as_deref()
+    }
+
+    /// Gets a mutable reference to the labels of this resource value. If the value does not have any labels, an empty map is set first.
+    fn labels_mut(&mut self) -> &mut std::collections::BTreeMap<String, String>
+    where
+        Self: Metadata<Ty = apimachinery::pkg::apis::meta::v1::ObjectMeta>,
+    {
+        self.metadata_mut().labels.get_or_insert_with(Default::default)
+    }
+
+    /// Gets a mutable reference to the annotations of this resource value. If the value does not have any annotations, an empty map is set first.
+    fn annotations_mut(&mut self) -> &mut std::collections::BTreeMap<String, String>
+    where
+        Self: Metadata<Ty = apimachinery::pkg::apis::meta::v1::ObjectMeta>,
+    {
+        self.metadata_mut().annotations.get_or_insert_with(Default::default)
+    }
+
+    /// Gets a mutable reference to the owner references of this resource value. If the value does not have any owner references, an empty list is set first.
+    fn owner_references_mut(&mut self) -> &mut Vec<apimachinery::pkg::apis::meta::v1::OwnerReference>
+    where
+        Self: Metadata<Ty = apimachinery::pkg::apis::meta::v1::ObjectMeta>,
+    {
+        self.metadata_mut().owner_references.get_or_insert_with(Default::default)
+    }
+}
+
+// References and smart pointers to resource values are resources too, so that generic code that takes a resource by value
+// can be given a borrowed or shared one without cloning it.
+
+macro_rules! resource_pointer_impls {
+    ($(impl[$($generics:tt)*] $ty:ty;)*) => {
+        $(
+            impl<$($generics)*> Resource for $ty where T: Resource {
+                const API_VERSION: &'static str = T::API_VERSION;
+                const GROUP: &'static str = T::GROUP;
+                const KIND: &'static str = T::KIND;
+                const VERSION: &'static str = T::VERSION;
+                const URL_PATH_SEGMENT: &'static str = T::URL_PATH_SEGMENT;
+                const VERBS: &'static [&'static str] = T::VERBS;
+                const PLURAL: &'static str = T::PLURAL;
+                const SINGULAR: &'static str = T::SINGULAR;
+                const SHORT_NAMES: &'static [&'static str] = T::SHORT_NAMES;
+                type Scope = T::Scope;
+            }
+
+            impl<$($generics)*> ListableResource for $ty where T: ListableResource {
+                const LIST_KIND: &'static str = T::LIST_KIND;
+            }
+        )*
+    };
+}
+
+resource_pointer_impls! {
+    impl['a, T] &'a T;
+    impl['a, T] &'a mut T;
+    impl[T] Box<T>;
+    impl[T] std::sync::Arc<T>;
+}
+
+// `&T` does not implement `Metadata`, since its metadata cannot be mutated.
+
+impl<T> Metadata for &'_ mut T where T: Metadata {
+    type Ty = T::Ty;
+
+    fn metadata(&self) -> &Self::Ty {
+        (**self).metadata()
+    }
+
+    fn metadata_mut(&mut self) -> &mut Self::Ty {
+        (**self).metadata_mut()
+    }
+}
+
+impl<T> Metadata for Box<T> where T: Metadata {
+    type Ty = T::Ty;
+
+    fn metadata(&self) -> &Self::Ty {
+        (**self).metadata()
+    }
+
+    fn metadata_mut(&mut self) -> &mut Self::Ty {
+        (**self).metadata_mut()
+    }
+}
+
+/// [`Metadata::metadata_mut`] clones the value first if the `Arc` is shared, like [`Arc::make_mut`](std::sync::Arc::make_mut).
+impl<T> Metadata for std::sync::Arc<T> where T: Metadata + Clone {
+    type Ty = T::Ty;
+
+    fn metadata(&self) -> &Self::Ty {
+        (**self).metadata()
+    }
+
+    fn metadata_mut(&mut self) -> &mut Self::Ty {
+        std::sync::Arc::make_mut(self).metadata_mut()
+    }
+}
+
+/// A trait applied to all Kubernetes resources that have a `status` subresource, like [`api::apps::v1::Deployment`].
+///
+/// The API server ignores changes to the `status` field of such resources in regular update and patch requests,
+/// so controllers must use the functions of this trait to update it instead. Bounding generic code on this trait
+/// prevents it from sending status updates for resources that don't have a `status` subresource.
+pub trait HasStatusSubresource: Resource {
+    /// The URL path of the `status` subresource of the resource of this type with the given name,
+    /// like `/apis/apps/v1/namespaces/default/deployments/web/status`.
+    ///
+    /// `namespace` must be `Some` for namespace-scoped resources and `None` for cluster-scoped resources.
+    fn status_url_path(namespace: Option<&str>, name: &str) -> String {
+        Self::subresource_url_path(namespace, name, "status")
+    }
+
+    /// Replaces the `status` subresource of the resource of this type with the given name.
+    ///
+    /// `namespace` must be `Some` for namespace-scoped resources and `None` for cluster-scoped resources. An empty `name` is an [`RequestError::InvalidParameter`].
+    ///
+    /// Use the returned [`ResponseBody`]`<`[`ReplaceResponse`]`<Self>>` constructor, or [`ReplaceResponse`]`<Self>` directly,
+    /// to parse the HTTP response.
+    #[cfg(feature = "api")]
+    fn replace_status(
+        namespace: Option<&str>,
+        name: &str,
+        body: &Self,
+        optional: ReplaceOptional<'_>,
+    ) -> Result<(http::Request<Vec<u8>>, fn(http::StatusCode) -> ResponseBody<ReplaceResponse<Self>>), RequestError>
+    where
+        Self: Sized + serde::de::DeserializeOwned + serde::Serialize,
+    {
+        validate_path_parameter("name", name)?;
+
+        let mut url = Self::status_url_path(namespace, name);
+        url.push('?');
+        let mut query_pairs = url::form_urlencoded::Serializer::new(url);
+        optional.__serialize(&mut query_pairs);
+        let url = query_pairs.finish();
+
+        let request = http::Request::put(url);
+        let body_bytes = serde_json::to_vec(body).map_err(RequestError::Json)?;
+        let request = request.header(http::header::CONTENT_TYPE, http::header::HeaderValue::from_static("application/json"));
+        match request.body(body_bytes) {
+            Ok(request) => Ok((request, ResponseBody::new)),
+            Err(err) => Err(RequestError::Http(err)),
+        }
+    }
+
+    /// Patches the `status` subresource of the resource of this type with the given name.
+    ///
+    /// `namespace` must be `Some` for namespace-scoped resources and `None` for cluster-scoped resources. An empty `name` is an [`RequestError::InvalidParameter`].
+    ///
+    /// Use the returned [`ResponseBody`]`<`[`PatchResponse`]`<Self>>` constructor, or [`PatchResponse`]`<Self>` directly,
+    /// to parse the HTTP response.
+    #[cfg(feature = "api")]
+    fn patch_status(
+        namespace: Option<&str>,
+        name: &str,
+        body: &apimachinery::pkg::apis::meta::v1::Patch,
+        optional: PatchOptional<'_>,
+    ) -> Result<(http::Request<Vec<u8>>, fn(http::StatusCode) -> ResponseBody<PatchResponse<Self>>), RequestError>
+    where
+        Self: Sized + serde::de::DeserializeOwned,
+    {
+        validate_path_parameter("name", name)?;
+        patch_request(Self::status_url_path(namespace, name), body, optional)
+    }
+}
+
+/// A trait applied to all the struct types of this crate, that describes how a strategic merge patch of a value of the type is applied to its fields.
+///
+/// This is the `x-kubernetes-patch-strategy` and `x-kubernetes-patch-merge-key` annotations of the fields in the OpenAPI spec,
+/// for tools that compute or apply strategic merge patches generically.
+///
+/// ```rust
+/// use k8s_openapi::api::core::v1::PodSpec;
+/// use k8s_openapi::MergeStrategyInfo;
+///
+/// let containers = PodSpec::field_patch_strategy("containers").unwrap();
+/// assert!(containers.merge);
+/// assert_eq!(containers.merge_key, Some("name"));
+///
+/// // Fields without an annotation are replaced by the patch.
+/// assert!(PodSpec::field_patch_strategy("nodeSelector").is_none());
+/// ```
+pub trait MergeStrategyInfo {
+    /// The patch strategies of the fields of this type that have one, sorted by the names of the fields.
+    const FIELD_PATCH_STRATEGIES: &'static [FieldPatchStrategy] = &[];
+
+    /// The patch strategy of the field with the given JSON name, like `containers`, or `None` if it does not have one.
+    fn field_patch_strategy(name: &str) -> Option<&'static FieldPatchStrategy> {
+        Self::FIELD_PATCH_STRATEGIES.iter().find(|field_patch_strategy| field_patch_strategy.field == name)
+    }
+}
+
+/// The patch strategy of a field. See [`MergeStrategyInfo`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FieldPatchStrategy {
+    /// The name of the field in JSON, like `containers`.
+    pub field: &'static str,
+
+    /// Whether the items of the list are merged with the items of the patch, instead of the list being replaced by the patch.
+    pub merge: bool,
+
+    /// Whether the fields of the object that are not set in the patch are cleared, like for `DeploymentSpec.strategy`.
+    pub retain_keys: bool,
+
+    /// The field of the items of the list that identifies them when merging, like `name` for `PodSpec.containers`.
+    ///
+    /// This is `None` for lists of primitive values like `ObjectMeta.finalizers`, that are merged as sets.
+    pub merge_key: Option<&'static str>,
+}
+
+/// A trait applied to all Kubernetes resources that have a `scale` subresource, like [`api::apps::v1::Deployment`].
+///
+/// Custom resources whose CRD enables the `scale` subresource can implement this trait too.
+/// For example, `impl k8s_openapi::Scalable for FooBar { type Scale = k8s_openapi::api::autoscaling::v1::Scale; }`
+pub trait Scalable: Resource {
+    /// The type of the `scale` subresource, like [`api::autoscaling::v1::Scale`].
+    type Scale: Resource + serde::de::DeserializeOwned;
+
+    /// The URL path of the `scale` subresource of the resource of this type with the given name,
+    /// like `/apis/apps/v1/namespaces/default/deployments/web/scale`.
+    ///
+    /// `namespace` must be `Some` for namespace-scoped resources and `None` for cluster-scoped resources.
+    fn scale_url_path(namespace: Option<&str>, name: &str) -> String {
+        Self::subresource_url_path(namespace, name, "scale")
+    }
+
+    /// Reads the `scale` subresource of the resource of this type with the given name.
+    ///
+    /// `namespace` must be `Some` for namespace-scoped resources and `None` for cluster-scoped resources. An empty `name` is an [`RequestError::InvalidParameter`].
+    ///
+    /// Use the returned [`ResponseBody`]`<`[`ReadScaleResponse`]`<Self::Scale>>` constructor, or [`ReadScaleResponse`]`<Self::Scale>` directly,
+    /// to parse the HTTP response.
+    #[cfg(feature = "api")]
+    fn read_scale(
+        namespace: Option<&str>,
+        name: &str,
+    ) -> Result<(http::Request<Vec<u8>>, fn(http::StatusCode) -> ResponseBody<ReadScaleResponse<Self::Scale>>), RequestError> {
+        validate_path_parameter("name", name)?;
+
+        let url = Self::scale_url_path(namespace, name);
+
+        let request = http::Request::get(url);
+        match request.body(vec![]) {
+            Ok(request) => Ok((request, ResponseBody::new)),
+            Err(err) => Err(RequestError::Http(err)),
+        }
+    }
+
+    /// Patches the `scale` subresource of the resource of this type with the given name.
+    ///
+    /// `namespace` must be `Some` for namespace-scoped resources and `None` for cluster-scoped resources. An empty `name` is an [`RequestError::InvalidParameter`].
+    ///
+    /// Use the returned [`ResponseBody`]`<`[`PatchResponse`]`<Self::Scale>>` constructor, or [`PatchResponse`]`<Self::Scale>` directly,
+    /// to parse the HTTP response.
+    #[cfg(feature = "api")]
+    fn patch_scale(
+        namespace: Option<&str>,
+        name: &str,
+        body: &apimachinery::pkg::apis::meta::v1::Patch,
+        optional: PatchOptional<'_>,
+    ) -> Result<(http::Request<Vec<u8>>, fn(http::StatusCode) -> ResponseBody<PatchResponse<Self::Scale>>), RequestError> {
+        validate_path_parameter("name", name)?;
+        patch_request(Self::scale_url_path(namespace, name), body, optional)
+    }
+}
+
+/// A trait applied to all Kubernetes resources that have an `eviction` subresource, like [`api::core::v1::Pod`].
+///
+/// Unlike deleting the resource, creating an eviction for it respects the `PodDisruptionBudget`s that select it.
+/// Bounding generic code on this trait prevents it from trying to evict resources that can only be deleted.
+pub trait Evictable: Resource {
+    /// The type of the `eviction` subresource, like the `Eviction` type of the `policy` API group.
+    type Eviction: Resource + serde::Serialize + serde::de::DeserializeOwned;
+
+    /// The URL path of the `eviction` subresource of the resource of this type with the given name,
+    /// like `/api/v1/namespaces/default/pods/web-0/eviction`.
+    ///
+    /// `namespace` must be `Some` for namespace-scoped resources and `None` for cluster-scoped resources.
+    fn eviction_url_path(namespace: Option<&str>, name: &str) -> String {
+        Self::subresource_url_path(namespace, name, "eviction")
+    }
+
+    /// Creates an eviction of the resource of this type with the given name.
+    ///
+    /// `namespace` must be `Some` for namespace-scoped resources and `None` for cluster-scoped resources. An empty `name` is an [`RequestError::InvalidParameter`].
+    ///
+    /// Use the returned [`ResponseBody`]`<`[`CreateResponse`]`<Self::Eviction>>` constructor, or [`CreateResponse`]`<Self::Eviction>` directly,
+    /// to parse the HTTP response.
+    #[cfg(feature = "api")]
+    fn create_eviction(
+        namespace: Option<&str>,
+        name: &str,
+        body: &Self::Eviction,
+        optional: CreateOptional<'_>,
+    ) -> Result<(http::Request<Vec<u8>>, fn(http::StatusCode) -> ResponseBody<CreateResponse<Self::Eviction>>), RequestError> {
+        validate_path_parameter("name", name)?;
+
+        let mut url = Self::eviction_url_path(namespace, name);
+        url.push('?');
+        let mut query_pairs = url::form_urlencoded::Serializer::new(url);
+        optional.__serialize(&mut query_pairs);
+        let url = query_pairs.finish();
+
+        let request = http::Request::post(url);
+        let body_bytes = serde_json::to_vec(body).map_err(RequestError::Json)?;
+        let request = request.header(http::header::CONTENT_TYPE, http::header::HeaderValue::from_static("application/json"));
+        match request.body(body_bytes) {
+            Ok(request) => Ok((request, ResponseBody::new)),
+            Err(err) => Err(RequestError::Http(err)),
+        }
+    }
+}
+
+/// A trait applied to all Kubernetes resources that have a `log` subresource, like [`api::core::v1::Pod`].
+///
+/// The operation that reads the log, like [`api::core::v1::Pod::read_namespaced_pod_log`], is generated on the resource type itself,
+/// since its optional parameters are specific to the resource. This trait lets generic code require that the log can be read at all.
+pub trait Loggable: Resource {
+    /// The URL path of the `log` subresource of the resource of this type with the given name,
+    /// like `/api/v1/namespaces/default/pods/web-0/log`.
+    ///
+    /// `namespace` must be `Some` for namespace-scoped resources and `None` for cluster-scoped resources.
+    fn log_url_path(namespace: Option<&str>, name: &str) -> String {
+        Self::subresource_url_path(namespace, name, "log")
+    }
+}
+
+/// Creates a request that patches the object at the given URL path, like the generated patch API operations do.
+#[cfg(feature = "api")]
+fn patch_request<T>(
+    url_path: String,
+    body: &apimachinery::pkg::apis::meta::v1::Patch,
+    optional: PatchOptional<'_>,
+) -> Result<(http::Request<Vec<u8>>, fn(http::StatusCode) -> ResponseBody<PatchResponse<T>>), RequestError> where T: serde::de::DeserializeOwned {
+    let mut url = url_path;
+    url.push('?');
+    let mut query_pairs = url::form_urlencoded::Serializer::new(url);
+    optional.__serialize(&mut query_pairs);
+    let url = query_pairs.finish();
+
+    let request = http::Request::patch(url);
+    let body_bytes = serde_json::to_vec(body).map_err(RequestError::Json)?;
+    let request = request.header(http::header::CONTENT_TYPE, http::header::HeaderValue::from_static(match body {
+        apimachinery::pkg::apis::meta::v1::Patch::Json(_) => "application/json-patch+json",
+        apimachinery::pkg::apis::meta::v1::Patch::Merge(_) => "application/merge-patch+json",
+        apimachinery::pkg::apis::meta::v1::Patch::StrategicMerge(_) => "application/strategic-merge-patch+json",
+    }));
+    match request.body(body_bytes) {
+        Ok(request) => Ok((request, ResponseBody::new)),
+        Err(err) => Err(RequestError::Http(err)),
+    }
 }
 
 /// Extracts the API version of the given resource value.
@@ -610,20 +1273,31 @@ impl ResourceScope for SubResourceScope {}
 /// The type of errors returned by the Kubernetes API functions that prepare the HTTP request.
 #[cfg(feature = "api")]
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum RequestError {
-    /// An error from preparing the HTTP request.
+    /// An error from preparing the HTTP request, such as a header value that contains invalid characters.
     Http(http::Error),
 
     /// An error while serializing a value into the JSON body of the HTTP request.
     Json(serde_json::Error),
+
+    /// A parameter of the API function has a value that would not identify the intended object, such as an empty name.
+    InvalidParameter {
+        /// The name of the parameter.
+        name: &'static str,
+
+        /// Why the value is invalid.
+        message: &'static str,
+    },
 }
 
 #[cfg(feature = "api")]
 impl std::fmt::Display for RequestError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            RequestError::Http(err) => write!(f, "{}", err),
-            RequestError::Json(err) => write!(f, "{}", err),
+            RequestError::Http(err) => write!(f, "could not build HTTP request: {err}"),
+            RequestError::Json(err) => write!(f, "could not serialize request body: {err}"),
+            RequestError::InvalidParameter { name, message } => write!(f, "invalid parameter {name}: {message}"),
         }
     }
 }
@@ -634,10 +1308,25 @@ impl std::error::Error for RequestError {
         match self {
             RequestError::Http(err) => Some(err),
             RequestError::Json(err) => Some(err),
+            RequestError::InvalidParameter { .. } => None,
         }
     }
 }
 
+/// Returns an error for an empty path parameter, like an empty object name, which would make the request URL refer to a different path
+/// than the object's.
+///
+/// This is used by the API functions, like `api::core::v1::ConfigMap::replace_namespaced_config_map`, for each of their path parameters.
+#[cfg(feature = "api")]
+#[doc(hidden)]
+pub fn validate_path_parameter(name: &'static str, value: &str) -> Result<(), RequestError> {
+    if value.is_empty() {
+        return Err(RequestError::InvalidParameter { name, message: "must not be empty" });
+    }
+
+    Ok(())
+}
+
 /// A trait implemented by all response types corresponding to Kubernetes API functions.
 #[cfg(feature = "api")]
 pub trait Response: Sized {
@@ -729,6 +1418,7 @@ impl<T> std::ops::Deref for ResponseBody<T> {
 /// The type of errors from parsing an HTTP response as one of the Kubernetes API functions' response types.
 #[cfg(feature = "api")]
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum ResponseError {
     /// An error from deserializing the HTTP response, indicating more data is needed to complete deserialization.
     NeedMoreData,
@@ -738,15 +1428,59 @@ pub enum ResponseError {
 
     /// An error while deserializing the HTTP response as a string, indicating that the response data is not UTF-8.
     Utf8(std::str::Utf8Error),
+
+    /// The response has a status code that the API operation does not define a response for.
+    ///
+    /// The response types parse such responses into their `Other` variant instead of returning this error.
+    /// Use [`ResponseError::unexpected_status`] to convert the value of an `Other` variant into this error,
+    /// so that it can be reported along with the other errors of the response.
+    UnexpectedStatus {
+        /// The status code of the response.
+        status_code: http::StatusCode,
+
+        /// The `message` of the [`Status`](apimachinery::pkg::apis::meta::v1::Status) object in the response body, if it has one.
+        message: Option<String>,
+    },
+}
+
+#[cfg(feature = "api")]
+impl ResponseError {
+    /// Creates a [`ResponseError::UnexpectedStatus`] error from the status code of a response and the value of the `Other` variant
+    /// that it was parsed into.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "v1_22")] {
+    /// use k8s_openapi::api::core::v1::ReadNamespacedPodResponse;
+    ///
+    /// let status_code = k8s_openapi::http::StatusCode::NOT_FOUND;
+    /// let body = br#"{"kind":"Status","apiVersion":"v1","status":"Failure","message":"pods \"web-0\" not found","reason":"NotFound","code":404}"#;
+    ///
+    /// let (response, _) = <ReadNamespacedPodResponse as k8s_openapi::Response>::try_from_parts(status_code, body).unwrap();
+    /// let err = match response {
+    ///     ReadNamespacedPodResponse::Ok(_) => unreachable!(),
+    ///     ReadNamespacedPodResponse::Other(result) => k8s_openapi::ResponseError::unexpected_status(status_code, &result),
+    /// };
+    /// assert_eq!(err.to_string(), r#"unexpected HTTP status 404 Not Found: pods "web-0" not found"#);
+    /// # }
+    /// ```
+    pub fn unexpected_status(status_code: http::StatusCode, body: &Result<Option<serde_json::Value>, serde_json::Error>) -> Self {
+        let message =
+            body.as_ref().ok()
+            .and_then(|value| value.as_ref()?.get("message")?.as_str())
+            .map(ToOwned::to_owned);
+        ResponseError::UnexpectedStatus { status_code, message }
+    }
 }
 
 #[cfg(feature = "api")]
 impl std::fmt::Display for ResponseError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            ResponseError::NeedMoreData => write!(f, "need more response data"),
-            ResponseError::Json(err) => write!(f, "{}", err),
-            ResponseError::Utf8(err) => write!(f, "{}", err),
+            ResponseError::NeedMoreData => f.write_str("need more response data"),
+            ResponseError::Json(err) => write!(f, "could not deserialize response body: {err}"),
+            ResponseError::Utf8(err) => write!(f, "response body is not valid UTF-8: {err}"),
+            ResponseError::UnexpectedStatus { status_code, message: Some(message) } => write!(f, "unexpected HTTP status {status_code}: {message}"),
+            ResponseError::UnexpectedStatus { status_code, message: None } => write!(f, "unexpected HTTP status {status_code}"),
         }
     }
 }
@@ -755,15 +1489,91 @@ impl std::fmt::Display for ResponseError {
 impl std::error::Error for ResponseError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
-            ResponseError::NeedMoreData => None,
+            ResponseError::NeedMoreData |
+            ResponseError::UnexpectedStatus { .. } => None,
             ResponseError::Json(err) => Some(err),
             ResponseError::Utf8(err) => Some(err),
         }
     }
 }
 
+/// Deserializes a value of type `T` from the given deserializer, like `T::deserialize(deserializer)`.
+///
+/// The difference is that if deserialization fails, the error also contains the path of the field that could not be deserialized,
+/// like `.spec.containers[2].resources.limits.cpu`. This is useful for reporting errors in manifests written by hand.
+///
+/// ```rust
+/// # #[cfg(feature = "v1_22")] {
+/// let manifest = r#"{ "metadata": { "name": "foo" }, "spec": { "containers": [{ "name": "bar", "ports": [{ "containerPort": "80" }] }] } }"#;
+///
+/// let err =
+///     k8s_openapi::deserialize_with_path::<_, k8s_openapi::api::core::v1::Pod>(&mut k8s_openapi::serde_json::Deserializer::from_str(manifest))
+///     .unwrap_err();
+/// assert_eq!(err.path(), ".spec.containers[0].ports[0].containerPort");
+/// # }
+/// ```
+#[cfg(feature = "deserialize-with-path")]
+pub fn deserialize_with_path<'de, D, T>(deserializer: D) -> Result<T, DeserializeError<D::Error>>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::Deserialize<'de>,
+{
+    serde_path_to_error::deserialize(deserializer).map_err(|err| {
+        let mut path = err.path().to_string();
+        // The path of the top-level value is already formatted as "."
+        if path != "." {
+            path.insert(0, '.');
+        }
+        DeserializeError {
+            path,
+            inner: err.into_inner(),
+        }
+    })
+}
+
+/// The type of errors returned by [`deserialize_with_path`].
+#[derive(Debug)]
+#[cfg(feature = "deserialize-with-path")]
+pub struct DeserializeError<E> {
+    path: String,
+    inner: E,
+}
+
+#[cfg(feature = "deserialize-with-path")]
+impl<E> DeserializeError<E> {
+    /// The path of the field that could not be deserialized, like `.spec.containers[2].resources.limits.cpu`.
+    ///
+    /// Fields are separated by `.` and array elements are indexed with `[]`. The path of the top-level value is `.`
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The error returned by the deserializer.
+    pub fn inner(&self) -> &E {
+        &self.inner
+    }
+
+    /// Returns the error returned by the deserializer.
+    pub fn into_inner(self) -> E {
+        self.inner
+    }
+}
+
+#[cfg(feature = "deserialize-with-path")]
+impl<E> std::fmt::Display for DeserializeError<E> where E: std::fmt::Display {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at {}", self.inner, self.path)
+    }
+}
+
+#[cfg(feature = "deserialize-with-path")]
+impl<E> std::error::Error for DeserializeError<E> where E: std::error::Error + 'static {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.inner)
+    }
+}
+
 /// Extensions to the percent-encoding crate
-#[cfg(feature = "api")]
 pub mod percent_encoding2 {
     /// Ref <https://url.spec.whatwg.org/#path-percent-encode-set>
     pub const PATH_SEGMENT_ENCODE_SET: &percent_encoding::AsciiSet =
@@ -772,40 +1582,520 @@ pub mod percent_encoding2 {
         .add(b'#').add(b'?').add(b'{').add(b'}'); // path percent-encode set
 }
 
-#[cfg(feature = "v1_11")] mod v1_11;
+/// Extensions to the serde crate, used by the `serde::Deserialize` impls of the generated types
+pub mod serde2 {
+    /// The error for a required field that is missing from a value of the type named `type_name`.
+    ///
+    /// Unlike [`serde::de::Error::missing_field`], the error message names the type and all its required fields, like
+    /// ``QuobyteVolumeSource requires `registry` (all required fields: `registry`, `volume`)``,
+    /// so that the error can be located even when the value is nested deep inside another value.
+    pub fn missing_field<E>(type_name: &str, field: &str, required_fields: &[&str]) -> E where E: serde::de::Error {
+        if required_fields.len() <= 1 {
+            E::custom(format_args!("{type_name} requires `{field}`"))
+        }
+        else {
+            let mut all_required_fields = String::new();
+            for (i, required_field) in required_fields.iter().enumerate() {
+                if i > 0 {
+                    all_required_fields.push_str(", ");
+                }
+                all_required_fields.push('`');
+                all_required_fields.push_str(required_field);
+                all_required_fields.push('`');
+            }
+            E::custom(format_args!("{type_name} requires `{field}` (all required fields: {all_required_fields})"))
+        }
+    }
+
+    /// Deserializes a field identifier of the struct named by the first field as the index of the field in the struct's table of field names,
+    /// or `None` if it isn't one of them. Unknown fields are reported to the handler set with [`crate::set_unknown_field_handler`],
+    /// or fail deserialization if the value is being deserialized as part of a [`crate::Strict`].
+    ///
+    /// The generated types look up their fields with this instead of each having their own visitor for their field identifiers,
+    /// so the code to deserialize field identifiers only exists once per deserializer instead of once per type.
+    pub struct FieldIndex(pub &'static str, pub &'static [&'static str]);
+
+    impl<'de> serde::de::DeserializeSeed<'de> for FieldIndex {
+        type Value = Option<usize>;
+
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: serde::Deserializer<'de> {
+            struct Visitor(&'static str, &'static [&'static str]);
+
+            impl serde::de::Visitor<'_> for Visitor {
+                type Value = Option<usize>;
+
+                fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.write_str("field identifier")
+                }
+
+                fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: serde::de::Error {
+                    let index = self.1.iter().position(|field| *field == v);
+                    if index.is_none() {
+                        crate::unknown_fields::unknown_field(self.0, v, self.1)?;
+                    }
+                    Ok(index)
+                }
+            }
+
+            deserializer.deserialize_identifier(Visitor(self.0, self.1))
+        }
+    }
+
+    /// Like [`FieldIndex`], but for structs with a flattened field. Deserializes a field identifier of the struct
+    /// as the index of the field in the struct's table of field names, or the name itself if it isn't one of them.
+    pub struct FieldIndexOrName(pub &'static [&'static str]);
+
+    impl<'de> serde::de::DeserializeSeed<'de> for FieldIndexOrName {
+        type Value = Result<usize, String>;
+
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: serde::Deserializer<'de> {
+            struct Visitor(&'static [&'static str]);
+
+            impl serde::de::Visitor<'_> for Visitor {
+                type Value = Result<usize, String>;
+
+                fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.write_str("field identifier")
+                }
+
+                fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: serde::de::Error {
+                    Ok(self.0.iter().position(|field| *field == v).ok_or_else(|| v.to_owned()))
+                }
+
+                fn visit_string<E>(self, v: String) -> Result<Self::Value, E> where E: serde::de::Error {
+                    Ok(self.0.iter().position(|field| *field == v).ok_or(v))
+                }
+            }
+
+            deserializer.deserialize_identifier(Visitor(self.0))
+        }
+    }
+
+    /// Deserializes an optional field in place. If the field is `null`, it's set to `None`.
+    /// Otherwise, its value is deserialized in place if it was already set, like with [`crate::DeserializeInPlace`].
+    #[cfg(feature = "deserialize-in-place")]
+    pub struct OptionInPlace<'a, T>(pub &'a mut Option<T>);
+
+    #[cfg(feature = "deserialize-in-place")]
+    impl<'de, T> serde::de::DeserializeSeed<'de> for OptionInPlace<'_, T> where T: serde::Deserialize<'de> {
+        type Value = ();
+
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: serde::Deserializer<'de> {
+            struct Visitor<'a, T>(&'a mut Option<T>);
+
+            impl<'de, T> serde::de::Visitor<'de> for Visitor<'_, T> where T: serde::Deserialize<'de> {
+                type Value = ();
+
+                fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.write_str("option")
+                }
+
+                fn visit_none<E>(self) -> Result<Self::Value, E> where E: serde::de::Error {
+                    *self.0 = None;
+                    Ok(())
+                }
+
+                fn visit_unit<E>(self) -> Result<Self::Value, E> where E: serde::de::Error {
+                    *self.0 = None;
+                    Ok(())
+                }
+
+                fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: serde::Deserializer<'de> {
+                    match self.0 {
+                        Some(value) => T::deserialize_in_place(deserializer, value),
+                        None => {
+                            *self.0 = Some(T::deserialize(deserializer)?);
+                            Ok(())
+                        },
+                    }
+                }
+            }
+
+            deserializer.deserialize_option(Visitor(self.0))
+        }
+    }
+
+    /// Deserializes an optional field that defaults to its type's default value in place. If the field is `null`, it's set to the default value.
+    /// Otherwise, its value is deserialized in place, like with [`crate::DeserializeInPlace`].
+    #[cfg(feature = "deserialize-in-place")]
+    pub struct OptionalDefaultInPlace<'a, T>(pub &'a mut T);
+
+    #[cfg(feature = "deserialize-in-place")]
+    impl<'de, T> serde::de::DeserializeSeed<'de> for OptionalDefaultInPlace<'_, T> where T: serde::Deserialize<'de> + Default {
+        type Value = ();
+
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: serde::Deserializer<'de> {
+            struct Visitor<'a, T>(&'a mut T);
+
+            impl<'de, T> serde::de::Visitor<'de> for Visitor<'_, T> where T: serde::Deserialize<'de> + Default {
+                type Value = ();
+
+                fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.write_str("option")
+                }
+
+                fn visit_none<E>(self) -> Result<Self::Value, E> where E: serde::de::Error {
+                    *self.0 = Default::default();
+                    Ok(())
+                }
+
+                fn visit_unit<E>(self) -> Result<Self::Value, E> where E: serde::de::Error {
+                    *self.0 = Default::default();
+                    Ok(())
+                }
+
+                fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: serde::Deserializer<'de> {
+                    T::deserialize_in_place(deserializer, self.0)
+                }
+            }
+
+            deserializer.deserialize_option(Visitor(self.0))
+        }
+    }
+
+    /// Serializes a `serde_json::Value` as itself in human-readable formats like JSON, and as a string containing its JSON in other formats like bincode.
+    ///
+    /// This is because formats that are not self-describing cannot deserialize a `serde_json::Value`, since the type of the value is only known from the data.
+    pub struct JsonValue<'a>(pub &'a serde_json::Value);
+
+    impl serde::Serialize for JsonValue<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+            if serializer.is_human_readable() {
+                self.0.serialize(serializer)
+            }
+            else {
+                serializer.collect_str(self.0)
+            }
+        }
+    }
+
+    /// Deserializes a `serde_json::Value` that was serialized with [`JsonValue`].
+    pub fn deserialize_json_value<'de, D>(deserializer: D) -> Result<serde_json::Value, D::Error> where D: serde::Deserializer<'de> {
+        if deserializer.is_human_readable() {
+            serde::Deserialize::deserialize(deserializer)
+        }
+        else {
+            let json: String = serde::Deserialize::deserialize(deserializer)?;
+            serde_json::from_str(&json).map_err(serde::de::Error::custom)
+        }
+    }
+}
+
+/// Extensions to the arbitrary crate, used by the `arbitrary::Arbitrary` impls of the generated types
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary2 {
+    /// The maximum number of elements in generated lists and maps.
+    ///
+    /// Most lists and maps in Kubernetes objects only have a few elements, and bounding them keeps objects with deeply nested lists
+    /// like `JSONSchemaProps` reasonably small.
+    pub const MAX_COLLECTION_LEN: usize = 4;
+
+    /// The maximum nesting depth of generated objects. Optional fields of objects nested deeper than this are not set,
+    /// and their lists and maps are empty.
+    ///
+    /// This bounds the size of recursive types like `JSONSchemaProps`, which would otherwise only be bounded by the amount of input data.
+    pub const MAX_DEPTH: usize = 16;
+
+    /// The maximum depth of generated `serde_json::Value`s.
+    pub const MAX_JSON_DEPTH: usize = 3;
+
+    thread_local! {
+        static DEPTH: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+    }
+
+    /// Generates an object using the given function, one level of nesting deeper than the current object.
+    pub fn nested<'a, T>(
+        u: &mut arbitrary::Unstructured<'a>,
+        f: impl FnOnce(&mut arbitrary::Unstructured<'a>) -> arbitrary::Result<T>,
+    ) -> arbitrary::Result<T> {
+        struct Guard;
+
+        impl Drop for Guard {
+            fn drop(&mut self) {
+                DEPTH.with(|depth| depth.set(depth.get() - 1));
+            }
+        }
+
+        DEPTH.with(|depth| depth.set(depth.get() + 1));
+        let _guard = Guard;
+        f(u)
+    }
+
+    fn is_too_deep() -> bool {
+        DEPTH.with(|depth| depth.get() > MAX_DEPTH)
+    }
+
+    /// Generates a list of up to [`MAX_COLLECTION_LEN`] elements using the given function.
+    pub fn vec<'a, T>(
+        u: &mut arbitrary::Unstructured<'a>,
+        mut f: impl FnMut(&mut arbitrary::Unstructured<'a>) -> arbitrary::Result<T>,
+    ) -> arbitrary::Result<Vec<T>> {
+        let len = if is_too_deep() { 0 } else { u.int_in_range(0..=MAX_COLLECTION_LEN)? };
+        (0..len).map(|_| f(u)).collect()
+    }
+
+    /// Generates a map of up to [`MAX_COLLECTION_LEN`] entries, with values generated using the given function.
+    pub fn map<'a, T>(
+        u: &mut arbitrary::Unstructured<'a>,
+        mut f: impl FnMut(&mut arbitrary::Unstructured<'a>) -> arbitrary::Result<T>,
+    ) -> arbitrary::Result<std::collections::BTreeMap<String, T>> {
+        let len = if is_too_deep() { 0 } else { u.int_in_range(0..=MAX_COLLECTION_LEN)? };
+        (0..len).map(|_| Ok((u.arbitrary()?, f(u)?))).collect()
+    }
+
+    /// Generates an optional value, using the given function if it's present.
+    pub fn option<'a, T>(
+        u: &mut arbitrary::Unstructured<'a>,
+        f: impl FnOnce(&mut arbitrary::Unstructured<'a>) -> arbitrary::Result<T>,
+    ) -> arbitrary::Result<Option<T>> {
+        Ok(if !is_too_deep() && u.arbitrary()? { Some(f(u)?) } else { None })
+    }
+
+    /// Generates a datetime with a whole number of seconds between the years 1970 and 9999,
+    /// so that it survives being serialized in the formats of both `Time` and `MicroTime`.
+    #[cfg(feature = "chrono")]
+    pub fn date_time(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<chrono::DateTime<chrono::Utc>> {
+        use chrono::TimeZone;
+
+        let secs = u.int_in_range(0..=253_402_300_799)?;
+        chrono::Utc.timestamp_opt(secs, 0).single().ok_or(arbitrary::Error::IncorrectFormat)
+    }
+
+    /// Generates an `f64` that survives a roundtrip through JSON.
+    ///
+    /// NaN and the infinities cannot be represented in JSON, and serde_json does not parse all other values exactly by default,
+    /// so this only generates integral values.
+    pub fn json_f64(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<f64> {
+        Ok(u.arbitrary::<i32>()?.into())
+    }
+
+    /// Generates a JSON value of up to [`MAX_JSON_DEPTH`] levels of nesting.
+    ///
+    /// The value itself is never `null`, since a field containing `null` would be indistinguishable from a missing field.
+    pub fn json_value(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<serde_json::Value> {
+        json_value_inner(u, MAX_JSON_DEPTH, false)
+    }
+
+    fn json_value_inner(u: &mut arbitrary::Unstructured<'_>, depth: usize, allow_null: bool) -> arbitrary::Result<serde_json::Value> {
+        let max_variant = if depth == 0 { 2 } else { 4 };
+        Ok(match u.int_in_range(0..=max_variant)? {
+            0 => serde_json::Value::Bool(u.arbitrary()?),
+            1 => serde_json::Value::Number(u.arbitrary::<i64>()?.into()),
+            2 => serde_json::Value::String(u.arbitrary()?),
+            3 => serde_json::Value::Array(vec(u, |u| json_value_inner(u, depth - 1, true))?),
+            _ if allow_null && u.arbitrary()? => serde_json::Value::Null,
+            _ => serde_json::Value::Object(map(u, |u| json_value_inner(u, depth - 1, true))?.into_iter().collect()),
+        })
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/conditional_compilation_macros.rs"));
+
+// The generated code uses the fields and types that it marks as deprecated, such as to (de)serialize them.
+
+#[cfg(feature = "v1_11")] #[allow(deprecated)] mod v1_11;
 #[cfg(feature = "v1_11")] pub use self::v1_11::*;
 
-#[cfg(feature = "v1_12")] mod v1_12;
+#[cfg(feature = "v1_12")] #[allow(deprecated)] mod v1_12;
 #[cfg(feature = "v1_12")] pub use self::v1_12::*;
 
-#[cfg(feature = "v1_13")] mod v1_13;
+#[cfg(feature = "v1_13")] #[allow(deprecated)] mod v1_13;
 #[cfg(feature = "v1_13")] pub use self::v1_13::*;
 
-#[cfg(feature = "v1_14")] mod v1_14;
+#[cfg(feature = "v1_14")] #[allow(deprecated)] mod v1_14;
 #[cfg(feature = "v1_14")] pub use self::v1_14::*;
 
-#[cfg(feature = "v1_15")] mod v1_15;
+#[cfg(feature = "v1_15")] #[allow(deprecated)] mod v1_15;
 #[cfg(feature = "v1_15")] pub use self::v1_15::*;
 
-#[cfg(feature = "v1_16")] mod v1_16;
+#[cfg(feature = "v1_16")] #[allow(deprecated)] mod v1_16;
 #[cfg(feature = "v1_16")] pub use self::v1_16::*;
 
-#[cfg(feature = "v1_17")] mod v1_17;
+#[cfg(feature = "v1_17")] #[allow(deprecated)] mod v1_17;
 #[cfg(feature = "v1_17")] pub use self::v1_17::*;
 
-#[cfg(feature = "v1_18")] mod v1_18;
+#[cfg(feature = "v1_18")] #[allow(deprecated)] mod v1_18;
 #[cfg(feature = "v1_18")] pub use self::v1_18::*;
 
-#[cfg(feature = "v1_19")] mod v1_19;
+#[cfg(feature = "v1_19")] #[allow(deprecated)] mod v1_19;
 #[cfg(feature = "v1_19")] pub use self::v1_19::*;
 
-#[cfg(feature = "v1_20")] mod v1_20;
+#[cfg(feature = "v1_20")] #[allow(deprecated)] mod v1_20;
 #[cfg(feature = "v1_20")] pub use self::v1_20::*;
 
-#[cfg(feature = "v1_21")] mod v1_21;
+#[cfg(feature = "v1_21")] #[allow(deprecated)] mod v1_21;
 #[cfg(feature = "v1_21")] pub use self::v1_21::*;
 
-#[cfg(feature = "v1_22")] mod v1_22;
+#[cfg(feature = "v1_22")] #[allow(deprecated)] mod v1_22;
 #[cfg(feature = "v1_22")] pub use self::v1_22::*;
 
-include!(concat!(env!("OUT_DIR"), "/conditional_compilation_macros.rs"));
+mod affinity;
+pub use self::affinity::AffinityBuilder;
+
+#[cfg(feature = "erased-serde")]
+mod any_object;
+#[cfg(feature = "erased-serde")]
+pub use self::any_object::AnyObject;
+
+#[cfg(feature = "erased-serde")]
+pub mod apply_order;
+
+mod authentication;
+
+#[cfg(feature = "borrowed")]
+pub mod borrowed;
+
+#[cfg(feature = "builder")]
+mod builder;
+#[cfg(feature = "builder")]
+pub use self::builder::MissingFieldError;
+
+#[cfg(feature = "cbor")]
+pub mod cbor;
+
+k8s_if_ge_1_19! {
+    mod certificate_signing_request;
+}
+
+k8s_if_ge_1_20! {
+    pub mod conditions;
+}
+
+#[cfg(feature = "test_util")]
+pub mod conformance;
+
+#[cfg(feature = "content-hash")]
+mod content_hash;
+
+k8s_if_ge_1_16! {
+    pub mod conversion_webhook;
+}
+
+mod conversions;
+
+#[cfg(feature = "cron")]
+mod cron;
+#[cfg(feature = "cron")]
+pub use self::cron::{CronSchedule, ParseCronScheduleError};
+
+mod daemon_set;
+pub use self::daemon_set::DaemonSetRolloutStatus;
+
+mod deployment;
+pub use self::deployment::DeploymentRolloutStatus;
+
+#[cfg(feature = "describe")]
+pub mod describe;
+
+mod deserialize;
+pub use self::deserialize::DeserializeInPlace;
+
+mod display;
+
+mod explicit_nulls;
+pub use self::explicit_nulls::{serialize_with_explicit_nulls, WithExplicitNulls};
+
+pub mod field_selectors;
+
+#[cfg(feature = "test_util")]
+pub mod fixtures;
+
+mod group_version;
+pub use self::group_version::{GroupVersion, ParseGroupVersionError};
+
+k8s_if_ge_1_12! {
+    mod horizontal_pod_autoscaler;
+}
+
+mod job;
+
+k8s_if_ge_1_16! {
+    mod json_schema_props;
+}
+
+#[cfg(feature = "erased-serde")]
+mod kind_registry;
+#[cfg(feature = "erased-serde")]
+pub use self::kind_registry::KindRegistry;
+
+mod known_values;
+pub use self::known_values::InvalidValue;
+
+mod kubeconfig;
+
+mod label_selector;
+
+k8s_if_ge_1_14! {
+    #[cfg(feature = "chrono")]
+    mod lease;
+}
+
+mod managed_fields;
+pub use self::managed_fields::{deserialize_without_managed_fields, WithoutManagedFields};
+
+#[cfg(feature = "manifests")]
+pub mod manifests;
+
+pub mod network_policy;
+
+mod object_key;
+pub use self::object_key::{ObjectKey, ParseObjectKeyError};
+
+mod object_meta;
+pub use self::object_meta::ObjectMetaBuilder;
+
+mod omit_empty_collections;
+pub use self::omit_empty_collections::{serialize_omitting_empty_collections, OmitEmptyCollections};
+
+#[cfg(feature = "schemars")]
+mod openapi_fragment;
+#[cfg(feature = "schemars")]
+pub use self::openapi_fragment::{OpenApiFragment, OpenApiVersion};
+
+mod persistent_volume_claim;
+
+#[cfg(feature = "protobuf-envelope")]
+pub mod protobuf_envelope;
+
+mod quantity;
+
+mod raw_extension;
+
+mod replica_set;
+
+mod resource_requirements;
+pub use self::resource_requirements::ResourceRequirementsBuilder;
+
+#[cfg(feature = "reflection")]
+pub mod reflection;
+
+#[cfg(feature = "api")]
+mod scale;
+#[cfg(feature = "api")]
+pub use self::scale::ReadScaleResponse;
+
+mod serialize;
+pub use self::serialize::{SerializeBuffer, SerializeInto};
+
+mod serializer_wrapper;
+
+#[cfg(feature = "shallow")]
+mod shallow;
+#[cfg(feature = "shallow")]
+pub use self::shallow::Shallow;
+
+mod stateful_set;
+pub use self::stateful_set::StatefulSetRolloutStatus;
+
+mod streaming_list;
+pub use self::streaming_list::StreamingList;
+
+pub mod taint;
+
+mod unknown_fields;
+pub use self::unknown_fields::{set_unknown_field_handler, Strict};
+
+#[cfg(feature = "reflection")]
+mod validation;
+#[cfg(feature = "reflection")]
+pub use self::validation::{SchemaViolation, Validate};
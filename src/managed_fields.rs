@@ -0,0 +1,355 @@
+// Deserialization that skips `metadata.managedFields`.
+//
+// The field is skipped by a `Deserializer` that wraps the original one, and everything it hands out to the `Deserialize` impls of the value,
+// until it reaches the deserialization of an `ObjectMeta`. So it works for any type that contains `ObjectMeta`s, like `List<T>`,
+// and the skipped field is never deserialized into a `Vec<ManagedFieldsEntry>` in the first place.
+//
+// Types that buffer their contents and deserialize them again with a different `Deserializer`, like `WatchEvent<T>` does with its object,
+// escape the wrapper. They need the wrapper around the buffered type instead.
+
+/// A wrapper that deserializes a value without the `managedFields` of any `ObjectMeta` in it,
+/// such as the `metadata.managedFields` of a resource.
+///
+/// The managed fields are usually the largest part of a resource, and most code that reads resources doesn't need them.
+/// So skipping them can save a lot of memory when a lot of resources are held in memory, like in the cache of a controller.
+///
+/// ```rust
+/// use k8s_openapi::WithoutManagedFields;
+///
+/// let json = r#"{
+///     "apiVersion": "v1",
+///     "kind": "ConfigMap",
+///     "metadata": {
+///         "name": "foo",
+///         "managedFields": [{ "manager": "kubectl", "operation": "Apply" }]
+///     }
+/// }"#;
+/// let WithoutManagedFields(config_map): WithoutManagedFields<k8s_openapi::api::core::v1::ConfigMap> = serde_json::from_str(json).unwrap();
+/// assert_eq!(config_map.metadata.name.as_deref(), Some("foo"));
+/// # k8s_openapi::k8s_if_ge_1_14! {
+/// assert_eq!(config_map.metadata.managed_fields, None);
+/// # }
+/// ```
+///
+/// `WatchEvent<T>` buffers its object before deserializing it, so use `WatchEvent<WithoutManagedFields<T>>` instead of
+/// `WithoutManagedFields<WatchEvent<T>>` to skip the managed fields of the objects of watch events.
+///
+/// Formats that are not self-describing, like bincode, don't deserialize structs from maps of field names, so the managed fields are deserialized as usual.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct WithoutManagedFields<T>(pub T);
+
+impl<'de, T> serde::Deserialize<'de> for WithoutManagedFields<T> where T: serde::Deserialize<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        deserialize_without_managed_fields(deserializer).map(WithoutManagedFields)
+    }
+}
+
+/// Deserializes a value without the `managedFields` of any `ObjectMeta` in it. See [`WithoutManagedFields`] for details.
+///
+/// This can be used with `#[serde(deserialize_with)]` on fields of your own types.
+pub fn deserialize_without_managed_fields<'de, D, T>(deserializer: D) -> Result<T, D::Error> where
+    D: serde::Deserializer<'de>,
+    T: serde::Deserialize<'de>,
+{
+    T::deserialize(Deserializer(deserializer))
+}
+
+struct Deserializer<D>(D);
+
+macro_rules! forward_deserialize {
+    ($($method:ident ($($arg:ident: $arg_ty:ty),*),)*) => {
+        $(
+            fn $method<V>(self, $($arg: $arg_ty,)* visitor: V) -> Result<V::Value, Self::Error> where V: serde::de::Visitor<'de> {
+                self.0.$method($($arg,)* Visitor(visitor))
+            }
+        )*
+    };
+}
+
+impl<'de, D> serde::Deserializer<'de> for Deserializer<D> where D: serde::Deserializer<'de> {
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any(),
+        deserialize_bool(),
+        deserialize_i8(),
+        deserialize_i16(),
+        deserialize_i32(),
+        deserialize_i64(),
+        deserialize_i128(),
+        deserialize_u8(),
+        deserialize_u16(),
+        deserialize_u32(),
+        deserialize_u64(),
+        deserialize_u128(),
+        deserialize_f32(),
+        deserialize_f64(),
+        deserialize_char(),
+        deserialize_str(),
+        deserialize_string(),
+        deserialize_bytes(),
+        deserialize_byte_buf(),
+        deserialize_option(),
+        deserialize_unit(),
+        deserialize_unit_struct(name: &'static str),
+        deserialize_newtype_struct(name: &'static str),
+        deserialize_seq(),
+        deserialize_tuple(len: usize),
+        deserialize_tuple_struct(name: &'static str, len: usize),
+        deserialize_map(),
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]),
+        deserialize_identifier(),
+        deserialize_ignored_any(),
+    }
+
+    fn deserialize_struct<V>(self, name: &'static str, fields: &'static [&'static str], visitor: V) -> Result<V::Value, Self::Error> where
+        V: serde::de::Visitor<'de>,
+    {
+        if name == "ObjectMeta" {
+            self.0.deserialize_struct(name, fields, ObjectMetaVisitor(visitor))
+        }
+        else {
+            self.0.deserialize_struct(name, fields, Visitor(visitor))
+        }
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.0.is_human_readable()
+    }
+}
+
+struct Visitor<V>(V);
+
+macro_rules! forward_visit {
+    ($($method:ident ($ty:ty),)*) => {
+        $(
+            fn $method<E>(self, v: $ty) -> Result<Self::Value, E> where E: serde::de::Error {
+                self.0.$method(v)
+            }
+        )*
+    };
+}
+
+impl<'de, V> serde::de::Visitor<'de> for Visitor<V> where V: serde::de::Visitor<'de> {
+    type Value = V::Value;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.expecting(f)
+    }
+
+    forward_visit! {
+        visit_bool(bool),
+        visit_i8(i8),
+        visit_i16(i16),
+        visit_i32(i32),
+        visit_i64(i64),
+        visit_i128(i128),
+        visit_u8(u8),
+        visit_u16(u16),
+        visit_u32(u32),
+        visit_u64(u64),
+        visit_u128(u128),
+        visit_f32(f32),
+        visit_f64(f64),
+        visit_char(char),
+        visit_str(&str),
+        visit_borrowed_str(&'de str),
+        visit_string(String),
+        visit_bytes(&[u8]),
+        visit_borrowed_bytes(&'de [u8]),
+        visit_byte_buf(Vec<u8>),
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E> where E: serde::de::Error {
+        self.0.visit_none()
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: serde::Deserializer<'de> {
+        self.0.visit_some(Deserializer(deserializer))
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> where E: serde::de::Error {
+        self.0.visit_unit()
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: serde::Deserializer<'de> {
+        self.0.visit_newtype_struct(Deserializer(deserializer))
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error> where A: serde::de::SeqAccess<'de> {
+        self.0.visit_seq(SeqAccess(seq))
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de> {
+        self.0.visit_map(MapAccess(map))
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error> where A: serde::de::EnumAccess<'de> {
+        self.0.visit_enum(EnumAccess(data))
+    }
+}
+
+struct DeserializeSeed<T>(T);
+
+impl<'de, T> serde::de::DeserializeSeed<'de> for DeserializeSeed<T> where T: serde::de::DeserializeSeed<'de> {
+    type Value = T::Value;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: serde::Deserializer<'de> {
+        self.0.deserialize(Deserializer(deserializer))
+    }
+}
+
+struct SeqAccess<A>(A);
+
+impl<'de, A> serde::de::SeqAccess<'de> for SeqAccess<A> where A: serde::de::SeqAccess<'de> {
+    type Error = A::Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error> where T: serde::de::DeserializeSeed<'de> {
+        self.0.next_element_seed(DeserializeSeed(seed))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.0.size_hint()
+    }
+}
+
+struct MapAccess<A>(A);
+
+impl<'de, A> serde::de::MapAccess<'de> for MapAccess<A> where A: serde::de::MapAccess<'de> {
+    type Error = A::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error> where K: serde::de::DeserializeSeed<'de> {
+        self.0.next_key_seed(seed)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error> where V: serde::de::DeserializeSeed<'de> {
+        self.0.next_value_seed(DeserializeSeed(seed))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.0.size_hint()
+    }
+}
+
+struct EnumAccess<A>(A);
+
+impl<'de, A> serde::de::EnumAccess<'de> for EnumAccess<A> where A: serde::de::EnumAccess<'de> {
+    type Error = A::Error;
+    type Variant = VariantAccess<A::Variant>;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error> where V: serde::de::DeserializeSeed<'de> {
+        let (value, variant) = self.0.variant_seed(seed)?;
+        Ok((value, VariantAccess(variant)))
+    }
+}
+
+struct VariantAccess<A>(A);
+
+impl<'de, A> serde::de::VariantAccess<'de> for VariantAccess<A> where A: serde::de::VariantAccess<'de> {
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        self.0.unit_variant()
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Self::Error> where T: serde::de::DeserializeSeed<'de> {
+        self.0.newtype_variant_seed(DeserializeSeed(seed))
+    }
+
+    fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error> where V: serde::de::Visitor<'de> {
+        self.0.tuple_variant(len, Visitor(visitor))
+    }
+
+    fn struct_variant<V>(self, fields: &'static [&'static str], visitor: V) -> Result<V::Value, Self::Error> where V: serde::de::Visitor<'de> {
+        self.0.struct_variant(fields, Visitor(visitor))
+    }
+}
+
+/// The visitor of an `ObjectMeta`, which skips the `managedFields` field if the `ObjectMeta` is deserialized from a map.
+///
+/// `ObjectMeta` doesn't contain any other `ObjectMeta`s, so its fields are deserialized with the original deserializer.
+struct ObjectMetaVisitor<V>(V);
+
+impl<'de, V> serde::de::Visitor<'de> for ObjectMetaVisitor<V> where V: serde::de::Visitor<'de> {
+    type Value = V::Value;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.expecting(f)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error> where A: serde::de::SeqAccess<'de> {
+        self.0.visit_seq(seq)
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de> {
+        self.0.visit_map(ObjectMetaMapAccess(map))
+    }
+}
+
+struct ObjectMetaMapAccess<A>(A);
+
+impl<'de, A> serde::de::MapAccess<'de> for ObjectMetaMapAccess<A> where A: serde::de::MapAccess<'de> {
+    type Error = A::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error> where K: serde::de::DeserializeSeed<'de> {
+        let mut seed = Some(seed);
+        loop {
+            match self.0.next_key_seed(ObjectMetaKeySeed(&mut seed))? {
+                Some(Some(key)) => return Ok(Some(key)),
+                Some(None) => {
+                    let _: serde::de::IgnoredAny = self.0.next_value()?;
+                },
+                None => return Ok(None),
+            }
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error> where V: serde::de::DeserializeSeed<'de> {
+        self.0.next_value_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.0.size_hint()
+    }
+}
+
+/// Deserializes a key of an `ObjectMeta` with the original seed, or returns `None` without consuming the seed if the key is `managedFields`.
+struct ObjectMetaKeySeed<'a, K>(&'a mut Option<K>);
+
+impl<'de, K> serde::de::DeserializeSeed<'de> for ObjectMetaKeySeed<'_, K> where K: serde::de::DeserializeSeed<'de> {
+    type Value = Option<K::Value>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: serde::Deserializer<'de> {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de, K> serde::de::Visitor<'de> for ObjectMetaKeySeed<'_, K> where K: serde::de::DeserializeSeed<'de> {
+    type Value = Option<K::Value>;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("field identifier")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: serde::de::Error {
+        self.deserialize_key(v, serde::de::value::StrDeserializer::new)
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E> where E: serde::de::Error {
+        self.deserialize_key(v, serde::de::value::BorrowedStrDeserializer::new)
+    }
+}
+
+impl<'de, K> ObjectMetaKeySeed<'_, K> where K: serde::de::DeserializeSeed<'de> {
+    fn deserialize_key<'v, D, E>(self, v: &'v str, deserializer: impl FnOnce(&'v str) -> D) -> Result<Option<K::Value>, E> where
+        D: serde::Deserializer<'de, Error = E>,
+        E: serde::de::Error,
+    {
+        if v == "managedFields" {
+            return Ok(None);
+        }
+
+        let seed = self.0.take().expect("seed is only taken for a key that is not skipped");
+        seed.deserialize(deserializer(v)).map(Some)
+    }
+}
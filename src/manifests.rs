@@ -0,0 +1,164 @@
+//! Loading the objects of multi-document YAML manifests, like the ones that are applied with `kubectl apply -f`.
+//!
+//! The documents of a manifest are separated by `---` lines. Empty documents are skipped, and the items of lists like `kind: List` documents
+//! are loaded as separate objects. Each object is deserialized as the type that the given [`KindRegistry`] has registered for its `apiVersion` and `kind`,
+//! or kept as an unstructured JSON value if there is none, such as for custom resources whose types are not registered.
+//!
+//! ```rust
+//! # #[cfg(feature = "reflection")] {
+//! use k8s_openapi::manifests::Manifest;
+//!
+//! let yaml = r#"
+//! apiVersion: v1
+//! kind: Service
+//! metadata:
+//!   name: web
+//! ---
+//! apiVersion: example.com/v1
+//! kind: Foo
+//! metadata:
+//!   name: foo
+//! "#;
+//!
+//! let manifests = k8s_openapi::manifests::load(yaml, &k8s_openapi::KindRegistry::builtin()).unwrap();
+//! assert!(matches!(&manifests[0], Manifest::Typed(object) if object.kind() == "Service"));
+//! assert!(matches!(&manifests[1], Manifest::Unstructured(value) if value["kind"] == "Foo"));
+//! # }
+//! ```
+
+use crate::{AnyObject, KindRegistry};
+
+/// An object loaded from a manifest.
+pub enum Manifest {
+    /// An object whose `apiVersion` and `kind` have a type registered in the [`KindRegistry`], deserialized as that type.
+    Typed(Box<dyn AnyObject>),
+
+    /// An object whose `apiVersion` and `kind` do not have a type registered in the [`KindRegistry`].
+    Unstructured(serde_json::Value),
+}
+
+impl Manifest {
+    /// The API version of the object, like `apps/v1`.
+    pub fn api_version(&self) -> &str {
+        match self {
+            Manifest::Typed(object) => object.api_version(),
+            Manifest::Unstructured(value) => value.get("apiVersion").and_then(serde_json::Value::as_str).unwrap_or_default(),
+        }
+    }
+
+    /// The kind of the object, like `Deployment`.
+    pub fn kind(&self) -> &str {
+        match self {
+            Manifest::Typed(object) => object.kind(),
+            Manifest::Unstructured(value) => value.get("kind").and_then(serde_json::Value::as_str).unwrap_or_default(),
+        }
+    }
+}
+
+impl std::fmt::Debug for Manifest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Manifest::Typed(object) => {
+                let value = serde_json::to_value(&**object).map_err(|_| std::fmt::Error)?;
+                f.debug_tuple("Typed").field(&value).finish()
+            },
+            Manifest::Unstructured(value) => f.debug_tuple("Unstructured").field(value).finish(),
+        }
+    }
+}
+
+impl serde::Serialize for Manifest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        match self {
+            Manifest::Typed(object) => object.serialize(serializer),
+            Manifest::Unstructured(value) => value.serialize(serializer),
+        }
+    }
+}
+
+/// Loads the objects of the documents of the given YAML stream, in order.
+///
+/// Fails if the YAML is not valid, if an object does not have an `apiVersion` and `kind`,
+/// or if an object cannot be deserialized as the type that is registered for them.
+pub fn load(yaml: &str, registry: &KindRegistry) -> Result<Vec<Manifest>, LoadError> {
+    let mut result = vec![];
+
+    for (document, deserializer) in serde_yaml::Deserializer::from_str(yaml).enumerate() {
+        let value = serde::Deserialize::deserialize(deserializer).map_err(LoadError::ParseYaml)?;
+        match value {
+            // Empty documents, like the one before a leading `---`
+            serde_json::Value::Null => (),
+
+            serde_json::Value::Object(mut object) if is_list(&object) => {
+                let items = match object.remove("items") {
+                    Some(serde_json::Value::Array(items)) => items,
+                    _ => vec![],
+                };
+                for (item, value) in items.into_iter().enumerate() {
+                    let manifest = load_object(value, registry).map_err(|source| LoadError::Object { document, item: Some(item), source })?;
+                    result.push(manifest);
+                }
+            },
+
+            value => {
+                let manifest = load_object(value, registry).map_err(|source| LoadError::Object { document, item: None, source })?;
+                result.push(manifest);
+            },
+        }
+    }
+
+    Ok(result)
+}
+
+/// Lists are recognized the same way as `kubectl` does, by their `kind` ending in `List` and them having `items`.
+fn is_list(object: &serde_json::Map<String, serde_json::Value>) -> bool {
+    object.get("kind").and_then(serde_json::Value::as_str).is_some_and(|kind| kind.ends_with("List")) &&
+        matches!(object.get("items"), Some(serde_json::Value::Array(_) | serde_json::Value::Null))
+}
+
+fn load_object(value: serde_json::Value, registry: &KindRegistry) -> Result<Manifest, serde_json::Error> {
+    let api_version = value.get("apiVersion").and_then(serde_json::Value::as_str);
+    let kind = value.get("kind").and_then(serde_json::Value::as_str);
+    match (api_version, kind) {
+        (Some(api_version), Some(kind)) if registry.contains(api_version, kind) => registry.deserialize_object(value).map(Manifest::Typed),
+        (Some(_), Some(_)) => Ok(Manifest::Unstructured(value)),
+        _ => Err(serde::de::Error::custom("object does not have an apiVersion and kind")),
+    }
+}
+
+/// The error returned by [`load`].
+#[derive(Debug)]
+pub enum LoadError {
+    /// The stream is not valid YAML. The error contains the location of the invalid YAML.
+    ParseYaml(serde_yaml::Error),
+
+    /// The object of the document at the given index could not be loaded.
+    Object {
+        /// The index of the document in the stream.
+        document: usize,
+
+        /// The index of the object in the `items` of the document if the document is a list, or `None` otherwise.
+        item: Option<usize>,
+
+        source: serde_json::Error,
+    },
+}
+
+impl std::fmt::Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadError::ParseYaml(err) => write!(f, "could not parse YAML: {err}"),
+            LoadError::Object { document, item: Some(item), source } => write!(f, "document #{} items[{item}]: {source}", document + 1),
+            LoadError::Object { document, item: None, source } => write!(f, "document #{}: {source}", document + 1),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LoadError::ParseYaml(err) => Some(err),
+            LoadError::Object { source, .. } => Some(source),
+        }
+    }
+}
@@ -0,0 +1,192 @@
+//! Helpers for evaluating `networking.k8s.io/v1` [`NetworkPolicy`] rules against pods and peers, without a cluster.
+//!
+//! Network policies are additive. Traffic to or from a pod that is not selected by any policy of the corresponding type (`Ingress` or `Egress`)
+//! is not restricted at all. Otherwise it is allowed if at least one of the policies that select the pod allows it.
+//! [`NetworkPolicy::selects_pod`], [`NetworkPolicy::allows_ingress`] and [`NetworkPolicy::allows_egress`] evaluate a single policy,
+//! so combining the results across all the policies in a namespace is up to the caller.
+
+use crate::api::networking::v1::{IPBlock, NetworkPolicy, NetworkPolicyPeer, NetworkPolicyPort};
+
+/// The other side of a connection to or from a pod selected by a [`NetworkPolicy`].
+#[derive(Clone, Copy, Debug)]
+pub enum Peer<'a> {
+    /// A pod in the cluster.
+    Pod {
+        /// The namespace of the pod.
+        namespace: &'a str,
+
+        /// The labels of the namespace of the pod.
+        namespace_labels: &'a std::collections::BTreeMap<String, String>,
+
+        /// The labels of the pod.
+        labels: &'a std::collections::BTreeMap<String, String>,
+
+        /// The IP of the pod, if known. This is only used to match `ipBlock` peers.
+        /// Whether `ipBlock` peers apply to pod IPs at all depends on the network plugin.
+        ip: Option<std::net::IpAddr>,
+    },
+
+    /// An endpoint outside the cluster, identified only by its IP.
+    External(std::net::IpAddr),
+}
+
+/// The destination port of a connection, as seen by the pod receiving it.
+#[derive(Clone, Copy, Debug)]
+pub struct Port<'a> {
+    /// The protocol of the connection, such as `TCP`, `UDP` or `SCTP`.
+    pub protocol: &'a str,
+
+    /// The port number.
+    pub number: i32,
+
+    /// The name of the container port that the connection targets, if any. This is needed to match rules that specify a named port.
+    pub name: Option<&'a str>,
+}
+
+impl NetworkPolicy {
+    /// The policy types that this policy applies to, ie `Ingress` and/or `Egress`.
+    ///
+    /// If the policy does not specify `policyTypes`, it always applies to `Ingress`, and to `Egress` if it has any egress rules.
+    pub fn effective_policy_types(&self) -> Vec<&str> {
+        let Some(spec) = &self.spec else { return vec!["Ingress"]; };
+
+        match &spec.policy_types {
+            Some(policy_types) => policy_types.iter().map(String::as_str).collect(),
+            None if spec.egress.as_ref().is_some_and(|egress| !egress.is_empty()) => vec!["Ingress", "Egress"],
+            None => vec!["Ingress"],
+        }
+    }
+
+    /// Returns `true` if this policy selects the pod with the given namespace and labels.
+    ///
+    /// A policy only selects pods in its own namespace.
+    pub fn selects_pod(&self, namespace: &str, labels: &std::collections::BTreeMap<String, String>) -> bool {
+        self.metadata.namespace.as_deref() == Some(namespace) &&
+            self.spec.as_ref().map_or(true, |spec| spec.pod_selector.matches(labels))
+    }
+
+    /// Returns `true` if this policy allows connections from the given peer to the given port of a pod that it selects.
+    ///
+    /// Returns `false` if the policy does not apply to ingress traffic. This does not check whether the policy selects the target pod;
+    /// use [`NetworkPolicy::selects_pod`] for that.
+    pub fn allows_ingress(&self, peer: &Peer<'_>, port: &Port<'_>) -> bool {
+        if !self.effective_policy_types().contains(&"Ingress") {
+            return false;
+        }
+
+        let namespace = self.metadata.namespace.as_deref().unwrap_or_default();
+        self.spec.iter()
+            .flat_map(|spec| spec.ingress.iter().flatten())
+            .any(|rule| rule_allows(namespace, rule.from.as_deref(), rule.ports.as_deref(), peer, port))
+    }
+
+    /// Returns `true` if this policy allows connections from a pod that it selects to the given port of the given peer.
+    ///
+    /// Returns `false` if the policy does not apply to egress traffic. This does not check whether the policy selects the source pod;
+    /// use [`NetworkPolicy::selects_pod`] for that.
+    pub fn allows_egress(&self, peer: &Peer<'_>, port: &Port<'_>) -> bool {
+        if !self.effective_policy_types().contains(&"Egress") {
+            return false;
+        }
+
+        let namespace = self.metadata.namespace.as_deref().unwrap_or_default();
+        self.spec.iter()
+            .flat_map(|spec| spec.egress.iter().flatten())
+            .any(|rule| rule_allows(namespace, rule.to.as_deref(), rule.ports.as_deref(), peer, port))
+    }
+}
+
+impl NetworkPolicyPeer {
+    /// Returns `true` if this peer selector matches the given peer, for a policy in the given namespace.
+    pub fn matches(&self, policy_namespace: &str, peer: &Peer<'_>) -> bool {
+        if let Some(ip_block) = &self.ip_block {
+            return match peer {
+                Peer::Pod { ip: Some(ip), .. } | Peer::External(ip) => ip_block.contains(*ip),
+                Peer::Pod { ip: None, .. } => false,
+            };
+        }
+
+        let (namespace, namespace_labels, labels) = match peer {
+            Peer::Pod { namespace, namespace_labels, labels, .. } => (*namespace, *namespace_labels, *labels),
+            Peer::External(_) => return false,
+        };
+
+        match (&self.namespace_selector, &self.pod_selector) {
+            (Some(namespace_selector), pod_selector) =>
+                namespace_selector.matches(namespace_labels) && pod_selector.as_ref().map_or(true, |pod_selector| pod_selector.matches(labels)),
+            (None, Some(pod_selector)) => namespace == policy_namespace && pod_selector.matches(labels),
+            (None, None) => false,
+        }
+    }
+}
+
+impl IPBlock {
+    /// Returns `true` if the given IP is in this block's CIDR and not in any of its excepted CIDRs.
+    ///
+    /// Malformed CIDRs do not contain any IPs.
+    pub fn contains(&self, ip: std::net::IpAddr) -> bool {
+        cidr_contains(&self.cidr, ip) &&
+            !self.except.iter().flatten().any(|except| cidr_contains(except, ip))
+    }
+}
+
+impl NetworkPolicyPort {
+    /// Returns `true` if this port selector matches the given port.
+    ///
+    /// The protocol defaults to `TCP` if unset. A selector without a port matches all ports of its protocol.
+    pub fn matches(&self, port: &Port<'_>) -> bool {
+        if self.protocol.as_deref().unwrap_or("TCP") != port.protocol {
+            return false;
+        }
+
+        match &self.port {
+            None => true,
+            Some(crate::apimachinery::pkg::util::intstr::IntOrString::Int(number)) => {
+                k8s_if_ge_1_21! {
+                    if let Some(end_port) = self.end_port {
+                        return *number <= port.number && port.number <= end_port;
+                    }
+                }
+
+                *number == port.number
+            },
+            Some(crate::apimachinery::pkg::util::intstr::IntOrString::String(name)) => port.name == Some(&**name),
+        }
+    }
+}
+
+fn rule_allows(
+    policy_namespace: &str,
+    peers: Option<&[NetworkPolicyPeer]>,
+    ports: Option<&[NetworkPolicyPort]>,
+    peer: &Peer<'_>,
+    port: &Port<'_>,
+) -> bool {
+    // An empty or missing list of peers or ports matches all peers or ports respectively.
+    let peer_allowed = match peers {
+        Some(peers) if !peers.is_empty() => peers.iter().any(|p| p.matches(policy_namespace, peer)),
+        _ => true,
+    };
+    let port_allowed = match ports {
+        Some(ports) if !ports.is_empty() => ports.iter().any(|p| p.matches(port)),
+        _ => true,
+    };
+    peer_allowed && port_allowed
+}
+
+fn cidr_contains(cidr: &str, ip: std::net::IpAddr) -> bool {
+    let Some((network, prefix_len)) = cidr.split_once('/') else { return false; };
+    let Ok(prefix_len) = prefix_len.parse::<u32>() else { return false; };
+
+    match (network.parse(), ip) {
+        (Ok(std::net::IpAddr::V4(network)), std::net::IpAddr::V4(ip)) if prefix_len <= 32 => {
+            let mask = u32::MAX.checked_shl(32 - prefix_len).unwrap_or(0);
+            u32::from(network) & mask == u32::from(ip) & mask
+        },
+        (Ok(std::net::IpAddr::V6(network)), std::net::IpAddr::V6(ip)) if prefix_len <= 128 => {
+            let mask = u128::MAX.checked_shl(128 - prefix_len).unwrap_or(0);
+            u128::from(network) & mask == u128::from(ip) & mask
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,66 @@
+use crate::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+/// The namespace and name of an object, which identify it among all objects of its type. This is the usual key of a cache of objects.
+///
+/// This is parsed from and formatted as `namespace/name`, or just `name` for cluster-scoped objects, the same as the keys of client-go's caches.
+/// Keys are ordered by namespace and then by name, with the keys of cluster-scoped objects first.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ObjectKey {
+    /// The namespace of the object, or `None` for cluster-scoped objects.
+    pub namespace: Option<String>,
+
+    /// The name of the object.
+    pub name: String,
+}
+
+/// The key of the given object. If the object does not have a name yet, such as an object to be created with a `generateName`, the name is empty.
+impl<T> From<&T> for ObjectKey where T: crate::Metadata<Ty = ObjectMeta> {
+    fn from(object: &T) -> Self {
+        let metadata = object.metadata();
+        ObjectKey {
+            namespace: metadata.namespace.clone(),
+            name: metadata.name.clone().unwrap_or_default(),
+        }
+    }
+}
+
+impl std::fmt::Display for ObjectKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(namespace) = &self.namespace {
+            write!(f, "{namespace}/")?;
+        }
+        f.write_str(&self.name)
+    }
+}
+
+impl std::str::FromStr for ObjectKey {
+    type Err = ParseObjectKeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (namespace, name) = match s.find('/') {
+            Some(pos) => (Some(&s[..pos]), &s[(pos + 1)..]),
+            None => (None, s),
+        };
+
+        if name.is_empty() || name.contains('/') || namespace == Some("") {
+            return Err(ParseObjectKeyError(s.to_owned()));
+        }
+
+        Ok(ObjectKey {
+            namespace: namespace.map(ToOwned::to_owned),
+            name: name.to_owned(),
+        })
+    }
+}
+
+/// The error returned when parsing an [`ObjectKey`] from a string that is not of the form `namespace/name` or `name`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseObjectKeyError(String);
+
+impl std::fmt::Display for ParseObjectKeyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} is not of the form `namespace/name` or `name`", self.0)
+    }
+}
+
+impl std::error::Error for ParseObjectKeyError {}
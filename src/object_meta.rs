@@ -0,0 +1,117 @@
+use crate::apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference};
+
+impl ObjectMeta {
+    /// Starts building the metadata of an object, such as a child object created by a controller.
+    ///
+    /// ```rust
+    /// use k8s_openapi::api::apps::v1::Deployment;
+    /// use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+    ///
+    /// let deployment = Deployment {
+    ///     metadata: ObjectMeta::builder().name("web").namespace("default").label("app", "web").build(),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let metadata =
+    ///     ObjectMeta::builder()
+    ///     .name("web-config")
+    ///     .inherit_labels(&deployment)
+    ///     .label("component", "config")
+    ///     .owner(&deployment)
+    ///     .build();
+    ///
+    /// assert_eq!(metadata.namespace, deployment.metadata.namespace);
+    /// assert_eq!(metadata.owner_references.unwrap()[0].name, "web");
+    /// ```
+    pub fn builder() -> ObjectMetaBuilder {
+        ObjectMetaBuilder(Default::default())
+    }
+}
+
+/// A builder of an [`ObjectMeta`]. Created with [`ObjectMeta::builder`].
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct ObjectMetaBuilder(ObjectMeta);
+
+impl ObjectMetaBuilder {
+    /// Sets the name of the object.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = Some(name.into());
+        self
+    }
+
+    /// Sets the prefix that the API server uses to generate a unique name for the object, if it does not have a name.
+    pub fn generate_name(mut self, generate_name: impl Into<String>) -> Self {
+        self.0.generate_name = Some(generate_name.into());
+        self
+    }
+
+    /// Sets the namespace of the object.
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.0.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Adds a label to the object.
+    pub fn label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.0.labels.get_or_insert_with(Default::default).insert(key.into(), value.into());
+        self
+    }
+
+    /// Adds all of the given labels to the object.
+    pub fn labels<I, K, V>(mut self, labels: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.0.labels.get_or_insert_with(Default::default).extend(labels.into_iter().map(|(key, value)| (key.into(), value.into())));
+        self
+    }
+
+    /// Adds all the labels of the given parent object to the object, so that selectors that select the parent also select its children.
+    ///
+    /// Like other labels, these override any labels with the same keys that were added before, and are overridden by any that are added after.
+    pub fn inherit_labels<K>(self, parent: &K) -> Self where K: crate::Metadata<Ty = ObjectMeta> {
+        match &parent.metadata().labels {
+            Some(labels) => self.labels(labels.clone()),
+            None => self,
+        }
+    }
+
+    /// Adds an annotation to the object.
+    pub fn annotation(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.0.annotations.get_or_insert_with(Default::default).insert(key.into(), value.into());
+        self
+    }
+
+    /// Makes the given object the controller of the object, so that the object is garbage-collected when the owner is deleted.
+    ///
+    /// If the namespace of the object has not been set, it is set to the namespace of the owner, since an object can only be owned by
+    /// objects in the same namespace or by cluster-scoped objects.
+    ///
+    /// The owner must have been read from the API server, so that it has a UID. Otherwise the reference has an empty UID,
+    /// which the API server rejects.
+    pub fn owner<K>(mut self, owner: &K) -> Self where K: crate::Metadata<Ty = ObjectMeta> {
+        let metadata = owner.metadata();
+
+        if self.0.namespace.is_none() {
+            self.0.namespace.clone_from(&metadata.namespace);
+        }
+
+        self.0.owner_references.get_or_insert_with(Default::default).push(OwnerReference {
+            api_version: K::API_VERSION.to_owned(),
+            block_owner_deletion: Some(true),
+            controller: Some(true),
+            kind: K::KIND.to_owned(),
+            name: metadata.name.clone().unwrap_or_default(),
+            uid: metadata.uid.clone().unwrap_or_default(),
+        });
+        self
+    }
+
+    /// Builds the metadata.
+    pub fn build(self) -> ObjectMeta {
+        self.0
+    }
+}
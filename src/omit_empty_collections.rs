@@ -0,0 +1,54 @@
+/// A wrapper that serializes a value without the fields of any struct in it whose values are empty lists or maps,
+/// like `"finalizers": []` or `"labels": {}`.
+///
+/// The generated types serialize an optional list or map field that is set to an empty list or map as `[]` or `{}`, since that's how
+/// the API server distinguishes an explicitly empty value from an unset one. This is what consumers like server-side apply need,
+/// since a field that is explicitly set to `[]` is owned by the field manager that set it, and a field that is omitted is not.
+/// Other consumers, like diff tools comparing objects with those created by `kubectl`, want these fields to be omitted instead.
+///
+/// ```rust
+/// let pod = k8s_openapi::api::core::v1::Pod {
+///     metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+///         name: Some("foo".to_owned()),
+///         finalizers: Some(vec![]),
+///         labels: Some(Default::default()),
+///         ..Default::default()
+///     },
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(
+///     k8s_openapi::serde_json::to_string(&pod).unwrap(),
+///     r#"{"apiVersion":"v1","kind":"Pod","metadata":{"finalizers":[],"labels":{},"name":"foo"}}"#,
+/// );
+///
+/// assert_eq!(
+///     k8s_openapi::serde_json::to_string(&k8s_openapi::OmitEmptyCollections(&pod)).unwrap(),
+///     r#"{"apiVersion":"v1","kind":"Pod","metadata":{"name":"foo"}}"#,
+/// );
+/// ```
+///
+/// Only fields of structs are omitted. Empty lists and maps that are elements of other lists or values of other maps are kept.
+///
+/// Formats that are not self-describing, like bincode, serialize all the fields of structs in order, so nothing is omitted for them.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct OmitEmptyCollections<T>(pub T);
+
+impl<T> serde::Serialize for OmitEmptyCollections<T> where T: serde::Serialize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_omitting_empty_collections(&self.0, serializer)
+    }
+}
+
+/// Serializes a value without the fields of any struct in it whose values are empty lists or maps. See [`OmitEmptyCollections`] for details.
+///
+/// This can be used with `#[serde(serialize_with)]` on fields of your own types.
+pub fn serialize_omitting_empty_collections<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error> where
+    T: serde::Serialize + ?Sized,
+    S: serde::Serializer,
+{
+    crate::serializer_wrapper::serialize(value, serializer, crate::serializer_wrapper::Options {
+        omit_empty_collections: true,
+        ..Default::default()
+    })
+}
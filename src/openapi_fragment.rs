@@ -0,0 +1,72 @@
+/// The definitions of a chosen set of types, emitted as a fragment of an OpenAPI document.
+///
+/// The definitions are generated from the [`schemars::JsonSchema`] impls of the types, so they include the definitions of all the types they refer to.
+/// This can be used by an aggregated API server to serve the definitions of the resource types it serves from its `/openapi/v2` endpoint.
+///
+/// ```rust
+/// let fragment =
+///     k8s_openapi::OpenApiFragment::new(k8s_openapi::OpenApiVersion::Swagger2)
+///     .add::<k8s_openapi::api::core::v1::Pod>()
+///     .add::<k8s_openapi::api::apps::v1::Deployment>()
+///     .into_json();
+/// assert!(fragment["definitions"].get("io.k8s.api.core.v1.PodSpec").is_some());
+/// ```
+#[derive(Clone, Debug)]
+pub struct OpenApiFragment {
+    generator: schemars::gen::SchemaGenerator,
+    version: OpenApiVersion,
+}
+
+/// The version of the OpenAPI specification that an [`OpenApiFragment`] conforms to.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OpenApiVersion {
+    /// Swagger 2.0, served by the API server at `/openapi/v2`. The definitions are emitted under `definitions`.
+    Swagger2,
+
+    /// OpenAPI 3.0, served by the API server at `/openapi/v3`. The definitions are emitted under `components.schemas`.
+    OpenApi3,
+}
+
+impl OpenApiFragment {
+    /// Creates a fragment without any definitions, that conforms to the given version of the OpenAPI specification.
+    pub fn new(version: OpenApiVersion) -> Self {
+        let settings = match version {
+            OpenApiVersion::Swagger2 => schemars::gen::SchemaSettings::draft07().with(|settings| settings.meta_schema = None),
+            OpenApiVersion::OpenApi3 => schemars::gen::SchemaSettings::openapi3(),
+        };
+
+        OpenApiFragment {
+            generator: settings.into_generator(),
+            version,
+        }
+    }
+
+    /// Adds the definition of the given type, and of all the types it refers to.
+    #[must_use]
+    pub fn add<T>(mut self) -> Self where T: schemars::JsonSchema {
+        let _ = self.generator.subschema_for::<T>();
+        self
+    }
+
+    /// Emits the fragment as JSON.
+    pub fn into_json(mut self) -> serde_json::Value {
+        let mut definitions = self.generator.take_definitions();
+        for visitor in self.generator.visitors_mut() {
+            for schema in definitions.values_mut() {
+                schemars::visit::Visitor::visit_schema(visitor, schema);
+            }
+        }
+
+        match self.version {
+            OpenApiVersion::Swagger2 => serde_json::json!({
+                "definitions": definitions,
+            }),
+
+            OpenApiVersion::OpenApi3 => serde_json::json!({
+                "components": {
+                    "schemas": definitions,
+                },
+            }),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+// Accessors for the binding and capacity of a `PersistentVolumeClaim`, matching how `kubectl get pvc` reports them.
+
+use crate::api::core::v1::PersistentVolumeClaim;
+use crate::apimachinery::pkg::api::resource::Quantity;
+
+impl PersistentVolumeClaim {
+    /// Returns `true` if this claim is bound to a persistent volume, ie its phase is `Bound`.
+    pub fn is_bound(&self) -> bool {
+        self.status.as_ref().and_then(|status| status.phase.as_deref()) == Some("Bound")
+    }
+
+    /// The name of the persistent volume that this claim is bound to, if any.
+    ///
+    /// This is the volume that was requested in the claim's spec, or the one the claim was bound to by the persistent volume controller.
+    /// A claim that is not [bound](Self::is_bound) yet may already have a volume name if it requested a specific volume.
+    pub fn volume_name(&self) -> Option<&str> {
+        self.spec.as_ref()
+            .and_then(|spec| spec.volume_name.as_deref())
+            .filter(|volume_name| !volume_name.is_empty())
+    }
+
+    /// The amount of storage that this claim requests, ie its `spec.resources.requests.storage`.
+    pub fn requested_storage(&self) -> Option<&Quantity> {
+        self.spec.as_ref()?.resources.as_ref()?.requests.as_ref()?.get("storage")
+    }
+
+    /// The actual amount of storage of the volume that this claim is bound to, ie its `status.capacity.storage`.
+    ///
+    /// This is only set once the claim is bound. It can be larger than the [requested storage](Self::requested_storage) if the volume is larger
+    /// than what was requested, and smaller while an expansion of the volume is still in progress.
+    pub fn storage_capacity(&self) -> Option<&Quantity> {
+        self.status.as_ref()?.capacity.as_ref()?.get("storage")
+    }
+
+    /// Returns `true` if this claim is bound to a volume that has at least as much storage as the claim requests.
+    ///
+    /// Returns `false` if the claim is not bound, if an expansion of its volume is still in progress,
+    /// or if either amount is missing or not a well-formed quantity.
+    pub fn has_requested_storage(&self) -> bool {
+        if !self.is_bound() {
+            return false;
+        }
+
+        match (self.storage_capacity(), self.requested_storage()) {
+            (Some(storage_capacity), Some(requested_storage)) => storage_capacity >= requested_storage,
+            _ => false,
+        }
+    }
+}
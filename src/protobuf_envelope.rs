@@ -0,0 +1,227 @@
+//! The envelope of objects in the protobuf encoding of the Kubernetes API, the `application/vnd.kubernetes.protobuf` content type.
+//!
+//! The API server encodes each object as the four bytes `k8s\0` followed by a protobuf-encoded `runtime.Unknown` message,
+//! which holds the `apiVersion` and `kind` of the object and the protobuf encoding of the object itself in its `raw` field.
+//! This module encodes and decodes that envelope. The contents of `raw` are encoded with the `.proto` IDL of the resource types,
+//! which the OpenAPI spec that this crate is generated from does not describe, so they have to be encoded and decoded
+//! with a separate protobuf library, such as types generated by `prost` from the `generated.proto` files of Kubernetes.
+//!
+//! This is only part of protobuf support. This crate does not generate protobuf encoding and decoding of the resource types themselves,
+//! so it cannot produce or read the contents of `raw` on its own.
+//!
+//! ```rust
+//! use k8s_openapi::protobuf_envelope::Unknown;
+//!
+//! let unknown = Unknown::for_resource::<k8s_openapi::api::core::v1::Pod>(b"...".to_vec());
+//! let buf = unknown.encode();
+//! assert!(buf.starts_with(&k8s_openapi::protobuf_envelope::MAGIC));
+//!
+//! let decoded = Unknown::decode(&buf).unwrap();
+//! assert_eq!(decoded.api_version, "v1");
+//! assert_eq!(decoded.kind, "Pod");
+//! assert_eq!(decoded.raw, b"...");
+//! ```
+
+use std::convert::TryFrom;
+
+/// The content type of the protobuf encoding, for the `Accept` and `Content-Type` headers of requests.
+pub const CONTENT_TYPE: &str = "application/vnd.kubernetes.protobuf";
+
+/// The bytes that precede the `runtime.Unknown` envelope of every object in the protobuf encoding.
+pub const MAGIC: [u8; 4] = *b"k8s\0";
+
+/// The `runtime.Unknown` envelope of an object in the protobuf encoding.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Unknown {
+    /// The `apiVersion` of the object, like `apps/v1`.
+    pub api_version: String,
+
+    /// The `kind` of the object, like `Deployment`.
+    pub kind: String,
+
+    /// The protobuf encoding of the object.
+    pub raw: Vec<u8>,
+
+    /// The encoding of `raw`, like `gzip`, or empty if it is not encoded.
+    pub content_encoding: String,
+
+    /// The content type of `raw`, or empty if it is the protobuf encoding.
+    pub content_type: String,
+}
+
+impl Unknown {
+    /// Constructs the envelope of an object of the given resource type, from its protobuf encoding.
+    pub fn for_resource<T>(raw: Vec<u8>) -> Self where T: crate::Resource {
+        Unknown {
+            api_version: T::API_VERSION.to_owned(),
+            kind: T::KIND.to_owned(),
+            raw,
+            content_encoding: String::new(),
+            content_type: String::new(),
+        }
+    }
+
+    /// Whether this is the envelope of an object of the given resource type.
+    pub fn is_resource<T>(&self) -> bool where T: crate::Resource {
+        self.api_version == T::API_VERSION && self.kind == T::KIND
+    }
+
+    /// The API group and version of the object, parsed from its `apiVersion`. Together with [`Unknown::kind`],
+    /// this identifies the type that [`Unknown::raw`] should be decoded as.
+    pub fn group_version(&self) -> Result<crate::GroupVersion, crate::ParseGroupVersionError> {
+        self.api_version.parse()
+    }
+
+    /// Encodes this envelope, preceded by [`MAGIC`].
+    ///
+    /// All fields are encoded even if they are empty, like the API server does.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut type_meta = vec![];
+        write_bytes_field(&mut type_meta, 1, self.api_version.as_bytes());
+        write_bytes_field(&mut type_meta, 2, self.kind.as_bytes());
+
+        let mut buf = MAGIC.to_vec();
+        write_bytes_field(&mut buf, 1, &type_meta);
+        write_bytes_field(&mut buf, 2, &self.raw);
+        write_bytes_field(&mut buf, 3, self.content_encoding.as_bytes());
+        write_bytes_field(&mut buf, 4, self.content_type.as_bytes());
+        buf
+    }
+
+    /// Decodes an envelope that is preceded by [`MAGIC`], like the body of a response of the API server to a request
+    /// that accepted [`CONTENT_TYPE`].
+    pub fn decode(buf: &[u8]) -> Result<Self, DecodeError> {
+        let buf = buf.strip_prefix(&MAGIC[..]).ok_or(DecodeError::MissingMagic)?;
+
+        let mut result: Unknown = Default::default();
+
+        let mut fields = Fields(buf);
+        while let Some((field_number, value)) = fields.next_field()? {
+            match (field_number, value) {
+                (1, FieldValue::Bytes(type_meta)) => {
+                    let mut type_meta = Fields(type_meta);
+                    while let Some((field_number, value)) = type_meta.next_field()? {
+                        match (field_number, value) {
+                            (1, FieldValue::Bytes(value)) => result.api_version = to_string(value, "apiVersion")?,
+                            (2, FieldValue::Bytes(value)) => result.kind = to_string(value, "kind")?,
+                            _ => (),
+                        }
+                    }
+                },
+                (2, FieldValue::Bytes(value)) => value.clone_into(&mut result.raw),
+                (3, FieldValue::Bytes(value)) => result.content_encoding = to_string(value, "contentEncoding")?,
+                (4, FieldValue::Bytes(value)) => result.content_type = to_string(value, "contentType")?,
+                _ => (),
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// The error returned by [`Unknown::decode`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DecodeError {
+    /// The buffer does not start with [`MAGIC`], so it is not in the protobuf encoding.
+    MissingMagic,
+
+    /// The buffer ends in the middle of a field.
+    Truncated,
+
+    /// A field has a wire type that is not supported, like the deprecated groups.
+    UnsupportedWireType(u64),
+
+    /// A string field of the envelope is not valid UTF-8.
+    InvalidUtf8 {
+        /// The name of the field, like `apiVersion`.
+        field: &'static str,
+    },
+}
+
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecodeError::MissingMagic => f.write_str("buffer does not start with the k8s protobuf magic"),
+            DecodeError::Truncated => f.write_str("buffer is truncated"),
+            DecodeError::UnsupportedWireType(wire_type) => write!(f, "unsupported wire type {wire_type}"),
+            DecodeError::InvalidUtf8 { field } => write!(f, "{field} is not valid UTF-8"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        #[allow(clippy::cast_possible_truncation)] // Truncation intended
+        buf.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    #[allow(clippy::cast_possible_truncation)] // Truncation intended
+    buf.push(value as u8);
+}
+
+fn write_bytes_field(buf: &mut Vec<u8>, field_number: u64, value: &[u8]) {
+    write_varint(buf, field_number << 3 | 2);
+    write_varint(buf, value.len() as u64);
+    buf.extend_from_slice(value);
+}
+
+fn to_string(value: &[u8], field: &'static str) -> Result<String, DecodeError> {
+    std::str::from_utf8(value).map(ToOwned::to_owned).map_err(|_| DecodeError::InvalidUtf8 { field })
+}
+
+enum FieldValue<'a> {
+    Bytes(&'a [u8]),
+    Other,
+}
+
+/// The fields of a protobuf message, in the order they were encoded.
+struct Fields<'a>(&'a [u8]);
+
+impl<'a> Fields<'a> {
+    fn next_field(&mut self) -> Result<Option<(u64, FieldValue<'a>)>, DecodeError> {
+        if self.0.is_empty() {
+            return Ok(None);
+        }
+
+        let key = self.read_varint()?;
+        let value = match key & 0x07 {
+            0 => { self.read_varint()?; FieldValue::Other },
+            1 => { self.read_bytes(8)?; FieldValue::Other },
+            2 => {
+                let len = self.read_varint()?;
+                let len = usize::try_from(len).map_err(|_| DecodeError::Truncated)?;
+                FieldValue::Bytes(self.read_bytes(len)?)
+            },
+            5 => { self.read_bytes(4)?; FieldValue::Other },
+            wire_type => return Err(DecodeError::UnsupportedWireType(wire_type)),
+        };
+
+        Ok(Some((key >> 3, value)))
+    }
+
+    fn read_varint(&mut self) -> Result<u64, DecodeError> {
+        let mut result = 0_u64;
+        for shift in (0..64).step_by(7) {
+            let (&b, rest) = self.0.split_first().ok_or(DecodeError::Truncated)?;
+            self.0 = rest;
+            result |= u64::from(b & 0x7f) << shift;
+            if b & 0x80 == 0 {
+                return Ok(result);
+            }
+        }
+
+        Err(DecodeError::Truncated)
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], DecodeError> {
+        if self.0.len() < len {
+            return Err(DecodeError::Truncated);
+        }
+
+        let (value, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(value)
+    }
+}
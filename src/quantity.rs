@@ -0,0 +1,196 @@
+// `PartialEq`, `Eq`, `Hash` and `PartialOrd` impls for `Quantity` that compare quantities by the numbers they represent, the same as the API server does.
+// So `1Gi` equals `1024Mi`, `0.5` equals `500m`, and `1G` is less than `1Gi`.
+//
+// Also an `arbitrary::Arbitrary` impl that generates well-formed quantities.
+//
+// Ref: https://github.com/kubernetes/apimachinery/blob/master/pkg/api/resource/quantity.go
+
+use std::convert::TryFrom;
+
+use crate::apimachinery::pkg::api::resource::Quantity;
+
+/// Quantities are equal if they represent the same number, regardless of how they're written, like `1Gi` and `1024Mi`.
+///
+/// Quantities that are not well-formed are only equal to quantities with the same string.
+impl PartialEq for Quantity {
+    fn eq(&self, other: &Self) -> bool {
+        Normalized::new(&self.0) == Normalized::new(&other.0)
+    }
+}
+
+impl Eq for Quantity {}
+
+/// Quantities that represent the same number have the same hash, consistent with their `PartialEq` impl.
+impl std::hash::Hash for Quantity {
+    fn hash<H>(&self, state: &mut H) where H: std::hash::Hasher {
+        Normalized::new(&self.0).hash(state);
+    }
+}
+
+/// Quantities are ordered by the numbers they represent, regardless of how they're written, like `1G` < `1Gi`.
+///
+/// Quantities that are not well-formed can't be ordered, except that they're equal to quantities with the same string.
+impl PartialOrd for Quantity {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        match (Normalized::new(&self.0), Normalized::new(&other.0)) {
+            (
+                Normalized::Number { negative: self_negative, digits: self_digits, exponent: self_exponent },
+                Normalized::Number { negative: other_negative, digits: other_digits, exponent: other_exponent },
+            ) => {
+                let self_magnitude = Magnitude { digits: &self_digits, exponent: self_exponent };
+                let other_magnitude = Magnitude { digits: &other_digits, exponent: other_exponent };
+                Some(match (self_negative, other_negative) {
+                    (false, false) => self_magnitude.cmp(&other_magnitude),
+                    (true, true) => other_magnitude.cmp(&self_magnitude),
+                    (false, true) => std::cmp::Ordering::Greater,
+                    (true, false) => std::cmp::Ordering::Less,
+                })
+            },
+
+            (Normalized::Invalid(self_s), Normalized::Invalid(other_s)) if self_s == other_s => Some(std::cmp::Ordering::Equal),
+
+            _ => None,
+        }
+    }
+}
+
+/// Generates well-formed quantities with a binary or decimal suffix or an exponent, like `250m`, `1.5Gi` or `-3e2`,
+/// so that fuzzers exercise the code that handles quantities instead of only generating malformed strings.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Quantity {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        const SUFFIXES: &[&str] = &["", "Ki", "Mi", "Gi", "Ti", "Pi", "Ei", "n", "u", "m", "k", "M", "G", "T", "P", "E"];
+
+        let sign = if u.ratio(1, 8)? { "-" } else { "" };
+        let integer: u16 = u.int_in_range(0..=9999)?;
+        let fraction = if u.ratio(1, 4)? { format!(".{}", u.int_in_range(0_u16..=999)?) } else { String::new() };
+        let suffix =
+            if u.ratio(1, 8)? {
+                format!("e{}", u.int_in_range(-3_i8..=3)?)
+            }
+            else {
+                (*u.choose(SUFFIXES)?).to_owned()
+            };
+
+        Ok(Quantity(format!("{sign}{integer}{fraction}{suffix}")))
+    }
+}
+
+/// The absolute value of a [`Normalized::Number`]
+struct Magnitude<'a> {
+    digits: &'a [u8],
+    exponent: i64,
+}
+
+impl Magnitude<'_> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self.digits.is_empty(), other.digits.is_empty()) {
+            (true, true) => return std::cmp::Ordering::Equal,
+            (true, false) => return std::cmp::Ordering::Less,
+            (false, true) => return std::cmp::Ordering::Greater,
+            (false, false) => (),
+        }
+
+        // Since the digits have no leading zeros, the number with the most significant digit in the higher place is larger.
+        // Numbers whose most significant digits are in the same place are ordered by their digits.
+        let self_place = i128::from(self.exponent) + self.digits.len() as i128;
+        let other_place = i128::from(other.exponent) + other.digits.len() as i128;
+        self_place.cmp(&other_place).then_with(|| self.digits.cmp(other.digits))
+    }
+}
+
+/// A form of a quantity that is the same for all the ways of writing the same number.
+#[derive(Debug, Eq, Hash, PartialEq)]
+enum Normalized<'a> {
+    /// The number `digits * 10^exponent`, where `digits` are the decimal digits of an integer without leading or trailing zeros.
+    ///
+    /// Zero is represented with no digits, a zero exponent, and `negative` set to `false`.
+    Number {
+        negative: bool,
+        digits: Vec<u8>,
+        exponent: i64,
+    },
+
+    /// A quantity that is not well-formed.
+    Invalid(&'a str),
+}
+
+impl<'a> Normalized<'a> {
+    fn new(s: &'a str) -> Self {
+        parse(s).unwrap_or(Normalized::Invalid(s))
+    }
+}
+
+/// Whether the given string is a well-formed quantity, for [`crate::Validate`].
+#[cfg(feature = "reflection")]
+pub(crate) fn is_well_formed(s: &str) -> bool {
+    parse(s).is_some()
+}
+
+fn parse(s: &str) -> Option<Normalized<'static>> {
+    let (negative, s) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+
+    let (number, suffix) = s.split_at(s.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(s.len()));
+    let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+    if (integer.is_empty() && fraction.is_empty()) || fraction.contains('.') {
+        return None;
+    }
+
+    let (decimal_exponent, binary_exponent) = match suffix {
+        "Ki" => (0, 10),
+        "Mi" => (0, 20),
+        "Gi" => (0, 30),
+        "Ti" => (0, 40),
+        "Pi" => (0, 50),
+        "Ei" => (0, 60),
+
+        "n" => (-9, 0),
+        "u" => (-6, 0),
+        "m" => (-3, 0),
+        "" => (0, 0),
+        "k" => (3, 0),
+        "M" => (6, 0),
+        "G" => (9, 0),
+        "T" => (12, 0),
+        "P" => (15, 0),
+        "E" => (18, 0),
+
+        suffix => {
+            let exponent = suffix.strip_prefix(|c| c == 'e' || c == 'E')?;
+            (exponent.parse().ok()?, 0)
+        },
+    };
+
+    let mut digits: Vec<u8> = integer.bytes().chain(fraction.bytes()).map(|digit| digit - b'0').collect();
+    let mut exponent = i64::try_from(fraction.len()).ok()?.checked_neg()?.checked_add(decimal_exponent)?;
+
+    for _ in 0..binary_exponent {
+        let mut carry = 0;
+        for digit in digits.iter_mut().rev() {
+            let doubled = *digit * 2 + carry;
+            *digit = doubled % 10;
+            carry = doubled / 10;
+        }
+        if carry > 0 {
+            digits.insert(0, carry);
+        }
+    }
+
+    let leading_zeros = digits.iter().take_while(|&&digit| digit == 0).count();
+    digits.drain(..leading_zeros);
+
+    while digits.last() == Some(&0) {
+        digits.pop();
+        exponent = exponent.checked_add(1)?;
+    }
+
+    if digits.is_empty() {
+        return Some(Normalized::Number { negative: false, digits, exponent: 0 });
+    }
+
+    Some(Normalized::Number { negative, digits, exponent })
+}
@@ -0,0 +1,17 @@
+use crate::apimachinery::pkg::runtime::RawExtension;
+
+impl RawExtension {
+    /// Serializes the given object into a `RawExtension`, such as to embed it in an `AdmissionReview` response or another object.
+    pub fn from_object<T>(object: &T) -> Result<Self, serde_json::Error> where T: serde::Serialize {
+        Ok(RawExtension(serde_json::to_value(object)?))
+    }
+
+    /// Deserializes the embedded object as a value of type `T`, such as the `object` and `oldObject` of an `AdmissionReview` request.
+    ///
+    /// This borrows the embedded object rather than consuming it, so the `RawExtension` still holds the object exactly as it was received,
+    /// including any fields that `T` does not know about. Serializing it again produces the original object rather than
+    /// the possibly lossy serialization of the parsed `T`.
+    pub fn parse<T>(&self) -> Result<T, serde_json::Error> where T: serde::de::DeserializeOwned {
+        T::deserialize(&self.0)
+    }
+}
@@ -0,0 +1,119 @@
+//! Descriptions of the types of this crate that are available at runtime, such as for building generic UIs and linters.
+//!
+//! The descriptions are generated from the same OpenAPI spec as the types themselves, so they always match the enabled `v1_*` feature.
+//!
+//! ```rust
+//! let pod = k8s_openapi::reflection::find("io.k8s.api.core.v1.Pod").unwrap();
+//! assert_eq!(pod.rust_path, "api::core::v1::Pod");
+//! assert_eq!(pod.resource.as_ref().unwrap().kind, "Pod");
+//!
+//! let spec = pod.field("spec").unwrap();
+//! assert!(!spec.required);
+//! assert_eq!(spec.referenced_type, Some("io.k8s.api.core.v1.PodSpec"));
+//! ```
+
+/// A description of a type of this crate.
+#[derive(Clone, Copy, Debug)]
+pub struct TypeInfo {
+    /// The path of the type's definition in the OpenAPI spec, like `io.k8s.api.core.v1.Pod`
+    pub definition_path: &'static str,
+
+    /// The path of the type relative to the root of this crate, like `api::core::v1::Pod`
+    pub rust_path: &'static str,
+
+    /// The documentation of the type.
+    pub description: Option<&'static str>,
+
+    /// If the type is a resource, its group, version and kind.
+    pub resource: Option<ResourceInfo>,
+
+    /// The fields of the type, sorted by their names. This is empty if the type is not a struct.
+    ///
+    /// For resources, this does not include the `apiVersion` and `kind` fields. See [`TypeInfo::resource`] for those instead.
+    pub fields: &'static [FieldInfo],
+}
+
+impl TypeInfo {
+    /// The field with the given name, like `spec`.
+    pub fn field(&self, name: &str) -> Option<&'static FieldInfo> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// The names of the fields that are required.
+    pub fn required_fields(&self) -> impl Iterator<Item = &'static str> {
+        self.fields.iter().filter(|field| field.required).map(|field| field.name)
+    }
+}
+
+/// The group, version and kind of a resource type. These are the same as the corresponding constants of its [`crate::Resource`] impl.
+///
+/// This is `#[non_exhaustive]` since some of its fields only exist with some features of this crate enabled.
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct ResourceInfo {
+    /// The API version of the resource, like `apps/v1`
+    pub api_version: &'static str,
+
+    /// The group of the resource, or the empty string if the resource doesn't have a group.
+    pub group: &'static str,
+
+    /// The kind of the resource, like `Deployment`
+    pub kind: &'static str,
+
+    /// The version of the resource, like `v1`
+    pub version: &'static str,
+
+    /// The [`crate::Resource::api_resource`] function of the resource type, which describes its plural name, scope and verbs.
+    pub api_resource: fn() -> crate::apimachinery::pkg::apis::meta::v1::APIResource,
+
+    /// Deserializes a value of this resource type as an [`AnyObject`](crate::AnyObject), such as for a [`KindRegistry`](crate::KindRegistry).
+    ///
+    /// This is `None` for resources whose metadata is not an `ObjectMeta`, like lists.
+    #[cfg(feature = "erased-serde")]
+    pub deserialize_any_object: Option<fn(serde_json::Value) -> Result<Box<dyn crate::AnyObject>, serde_json::Error>>,
+}
+
+/// A description of a field of a struct.
+#[derive(Clone, Copy, Debug)]
+pub struct FieldInfo {
+    /// The name of the field in JSON, like `apiVersion`
+    pub name: &'static str,
+
+    /// The name of the field in the struct, like `api_version`
+    pub field_name: &'static str,
+
+    /// The type of the field, with paths relative to the root of this crate, like `Vec<api::core::v1::Container>`.
+    ///
+    /// This is not wrapped in an `Option` for optional fields. See [`FieldInfo::required`] for that.
+    pub type_name: &'static str,
+
+    /// The definition path of the type of the field, or of its items or values if it is an array or a map, like `io.k8s.api.core.v1.Container`.
+    ///
+    /// This is `None` if that type is a primitive type like a string.
+    pub referenced_type: Option<&'static str>,
+
+    /// Whether the field is required.
+    pub required: bool,
+
+    /// The documentation of the field.
+    pub description: Option<&'static str>,
+}
+
+/// Descriptions of all the types of this crate, sorted by their definition paths.
+///
+/// This does not include the types of the optional parameters and responses of the API operation functions.
+pub fn types() -> &'static [TypeInfo] {
+    crate::TYPES
+}
+
+/// The description of the type with the given definition path, like `io.k8s.api.core.v1.Pod`
+pub fn find(definition_path: &str) -> Option<&'static TypeInfo> {
+    let types = types();
+    let index = types.binary_search_by(|type_info| type_info.definition_path.cmp(definition_path)).ok()?;
+    Some(&types[index])
+}
+
+/// The description of the resource type with the given API version and kind, like `apps/v1` and `Deployment`.
+pub fn find_resource(api_version: &str, kind: &str) -> Option<&'static TypeInfo> {
+    types().iter().find(|type_info| type_info.resource.as_ref().is_some_and(|resource| resource.api_version == api_version && resource.kind == kind))
+}
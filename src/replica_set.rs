@@ -0,0 +1,112 @@
+// Revisions of `apps/v1` `ReplicaSet`s and the `Deployment`s that own them, matching the bookkeeping of the deployment controller.
+//
+// Ref: https://github.com/kubernetes/kubernetes/blob/master/pkg/controller/deployment/util/deployment_util.go
+
+use crate::api::apps::v1::{Deployment, ReplicaSet};
+use crate::api::core::v1::PodTemplateSpec;
+
+/// The annotation that the deployment controller sets on a deployment and its replica sets to the revision of the replica set.
+const REVISION_ANNOTATION: &str = "deployment.kubernetes.io/revision";
+
+/// The label that the deployment controller adds to the pod template, selector and metadata of each of its replica sets.
+const POD_TEMPLATE_HASH_LABEL: &str = "pod-template-hash";
+
+impl ReplicaSet {
+    /// The revision of this replica set within its deployment, from its `deployment.kubernetes.io/revision` annotation.
+    ///
+    /// Returns `None` if the replica set does not have the annotation, such as if it is not owned by a deployment, or if it is not a valid number.
+    pub fn revision(&self) -> Option<i64> {
+        revision(&self.metadata)
+    }
+
+    /// The value of the `pod-template-hash` label that the deployment controller adds to the replica sets of a deployment,
+    /// which distinguishes the pods of each revision.
+    pub fn pod_template_hash(&self) -> Option<&str> {
+        self.metadata.labels.as_ref()?.get(POD_TEMPLATE_HASH_LABEL).map(String::as_str)
+    }
+
+    /// Returns `true` if the given deployment is the controller of this replica set, ie the replica set has a controller owner reference
+    /// with the UID of the deployment.
+    ///
+    /// The deployment must have been read from the API server, so that it has a UID.
+    pub fn is_controlled_by(&self, deployment: &Deployment) -> bool {
+        let uid = match deployment.metadata.uid.as_deref() {
+            Some(uid) if !uid.is_empty() => uid,
+            _ => return false,
+        };
+
+        self.metadata.owner_references.iter().flatten()
+            .any(|owner_reference| owner_reference.controller == Some(true) && owner_reference.uid == uid)
+    }
+
+    /// Returns `true` if this replica set's pod template is the same as the given deployment's, ignoring the `pod-template-hash` label
+    /// that the deployment controller adds to it. This is how the deployment controller decides which replica set is the deployment's new one.
+    ///
+    /// The hash itself cannot be recomputed outside the deployment controller, since it is a hash of the Go representation of the template.
+    pub fn has_pod_template_of(&self, deployment: &Deployment) -> bool {
+        let Some(template) = self.spec.as_ref().and_then(|spec| spec.template.as_ref()) else { return false; };
+        let Some(deployment_spec) = &deployment.spec else { return false; };
+        equal_ignoring_pod_template_hash(template, &deployment_spec.template)
+    }
+}
+
+impl Deployment {
+    /// The revision of the deployment's current pod template, from its `deployment.kubernetes.io/revision` annotation.
+    /// This is the same as the [revision](ReplicaSet::revision) of its [new replica set](Self::new_replica_set).
+    pub fn revision(&self) -> Option<i64> {
+        revision(&self.metadata)
+    }
+
+    /// Finds the replica set of the deployment's current pod template among the given replica sets, if the deployment controller has created it.
+    ///
+    /// If more than one replica set that the deployment controls has the same pod template, the oldest one is used, like the deployment controller does.
+    pub fn new_replica_set<'a, I>(&self, replica_sets: I) -> Option<&'a ReplicaSet> where I: IntoIterator<Item = &'a ReplicaSet> {
+        replica_sets.into_iter()
+            .filter(|replica_set| replica_set.is_controlled_by(self) && replica_set.has_pod_template_of(self))
+            .min_by(|a, b| {
+                let a_key = (a.metadata.creation_timestamp.as_ref().map(|time| &time.0), &a.metadata.name);
+                let b_key = (b.metadata.creation_timestamp.as_ref().map(|time| &time.0), &b.metadata.name);
+                a_key.cmp(&b_key)
+            })
+    }
+
+    /// Finds the replica sets that the deployment controls among the given replica sets, other than its [new replica set](Self::new_replica_set).
+    /// These are the replica sets of previous revisions, which are scaled down by a rollout and kept for rollbacks.
+    pub fn old_replica_sets<'a, I>(&self, replica_sets: I) -> Vec<&'a ReplicaSet> where I: IntoIterator<Item = &'a ReplicaSet> + Clone {
+        let new_replica_set = self.new_replica_set(replica_sets.clone());
+
+        replica_sets.into_iter()
+            .filter(|replica_set| replica_set.is_controlled_by(self))
+            .filter(|replica_set| !new_replica_set.is_some_and(|new_replica_set| std::ptr::eq(*replica_set, new_replica_set)))
+            .collect()
+    }
+
+    /// Finds the replica set of the given revision of the deployment among the given replica sets,
+    /// such as the revision to roll back to with `kubectl rollout undo --to-revision`.
+    pub fn replica_set_of_revision<'a, I>(&self, replica_sets: I, revision: i64) -> Option<&'a ReplicaSet> where I: IntoIterator<Item = &'a ReplicaSet> {
+        replica_sets.into_iter()
+            .find(|replica_set| replica_set.is_controlled_by(self) && replica_set.revision() == Some(revision))
+    }
+}
+
+fn revision(metadata: &crate::apimachinery::pkg::apis::meta::v1::ObjectMeta) -> Option<i64> {
+    metadata.annotations.as_ref()?.get(REVISION_ANNOTATION)?.parse().ok()
+}
+
+/// Compares two pod templates, ignoring the `pod-template-hash` label in either of them. Ref: `EqualIgnoreHash` of the deployment controller.
+fn equal_ignoring_pod_template_hash(a: &PodTemplateSpec, b: &PodTemplateSpec) -> bool {
+    fn without_pod_template_hash(template: &PodTemplateSpec) -> std::borrow::Cow<'_, PodTemplateSpec> {
+        if template.metadata.as_ref().and_then(|metadata| metadata.labels.as_ref()).is_some_and(|labels| labels.contains_key(POD_TEMPLATE_HASH_LABEL)) {
+            let mut template = template.clone();
+            if let Some(labels) = template.metadata.as_mut().and_then(|metadata| metadata.labels.as_mut()) {
+                labels.remove(POD_TEMPLATE_HASH_LABEL);
+            }
+            std::borrow::Cow::Owned(template)
+        }
+        else {
+            std::borrow::Cow::Borrowed(template)
+        }
+    }
+
+    without_pod_template_hash(a) == without_pod_template_hash(b)
+}
@@ -0,0 +1,82 @@
+use crate::api::core::v1::ResourceRequirements;
+use crate::apimachinery::pkg::api::resource::Quantity;
+
+impl ResourceRequirements {
+    /// Starts building the resource requirements of a container.
+    ///
+    /// ```rust
+    /// use k8s_openapi::api::core::v1::ResourceRequirements;
+    ///
+    /// let resources =
+    ///     ResourceRequirements::builder()
+    ///     .request_cpu("250m")
+    ///     .request_memory("512Mi")
+    ///     .limit_memory("1Gi")
+    ///     .build();
+    ///
+    /// assert_eq!(resources.requests.unwrap()["cpu"], "0.25".into());
+    /// assert_eq!(resources.limits.unwrap().len(), 1);
+    /// ```
+    pub fn builder() -> ResourceRequirementsBuilder {
+        ResourceRequirementsBuilder(Default::default())
+    }
+}
+
+/// A builder of a [`ResourceRequirements`]. Created with [`ResourceRequirements::builder`].
+///
+/// Requests and limits that are never set are left as `None` rather than empty maps.
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct ResourceRequirementsBuilder(ResourceRequirements);
+
+impl ResourceRequirementsBuilder {
+    /// Sets the amount of the given resource that the container requests, like `nvidia.com/gpu`.
+    /// The scheduler only schedules the container's pod to a node that has this amount of the resource available.
+    pub fn request(mut self, resource: impl Into<String>, quantity: impl Into<Quantity>) -> Self {
+        self.0.requests.get_or_insert_with(Default::default).insert(resource.into(), quantity.into());
+        self
+    }
+
+    /// Sets the amount of the given resource that the container is limited to, like `nvidia.com/gpu`.
+    ///
+    /// If the container does not request the resource, the API server defaults its request to this limit.
+    pub fn limit(mut self, resource: impl Into<String>, quantity: impl Into<Quantity>) -> Self {
+        self.0.limits.get_or_insert_with(Default::default).insert(resource.into(), quantity.into());
+        self
+    }
+
+    /// Sets the number of CPUs that the container requests, like `250m` or `2`.
+    pub fn request_cpu(self, quantity: impl Into<Quantity>) -> Self {
+        self.request("cpu", quantity)
+    }
+
+    /// Sets the number of bytes of memory that the container requests, like `512Mi`.
+    pub fn request_memory(self, quantity: impl Into<Quantity>) -> Self {
+        self.request("memory", quantity)
+    }
+
+    /// Sets the number of bytes of local ephemeral storage that the container requests, like `2Gi`.
+    pub fn request_ephemeral_storage(self, quantity: impl Into<Quantity>) -> Self {
+        self.request("ephemeral-storage", quantity)
+    }
+
+    /// Sets the number of CPUs that the container is limited to. The container is throttled when it uses more.
+    pub fn limit_cpu(self, quantity: impl Into<Quantity>) -> Self {
+        self.limit("cpu", quantity)
+    }
+
+    /// Sets the number of bytes of memory that the container is limited to. The container is killed when it uses more.
+    pub fn limit_memory(self, quantity: impl Into<Quantity>) -> Self {
+        self.limit("memory", quantity)
+    }
+
+    /// Sets the number of bytes of local ephemeral storage that the container is limited to. The pod is evicted when the container uses more.
+    pub fn limit_ephemeral_storage(self, quantity: impl Into<Quantity>) -> Self {
+        self.limit("ephemeral-storage", quantity)
+    }
+
+    /// Builds the resource requirements.
+    pub fn build(self) -> ResourceRequirements {
+        self.0
+    }
+}
@@ -0,0 +1,35 @@
+/// The response type of [`crate::Scalable::read_scale`].
+#[derive(Debug)]
+pub enum ReadScaleResponse<T> where T: serde::de::DeserializeOwned {
+    Ok(T),
+    Other(Result<Option<serde_json::Value>, serde_json::Error>),
+}
+
+impl<T> crate::Response for ReadScaleResponse<T> where T: serde::de::DeserializeOwned {
+    fn try_from_parts(status_code: http::StatusCode, buf: &[u8]) -> Result<(Self, usize), crate::ResponseError> {
+        match status_code {
+            http::StatusCode::OK => {
+                let result = match serde_json::from_slice(buf) {
+                    Ok(value) => value,
+                    Err(ref err) if err.is_eof() => return Err(crate::ResponseError::NeedMoreData),
+                    Err(err) => return Err(crate::ResponseError::Json(err)),
+                };
+                Ok((ReadScaleResponse::Ok(result), buf.len()))
+            },
+            _ => {
+                let (result, read) =
+                    if buf.is_empty() {
+                        (Ok(None), 0)
+                    }
+                    else {
+                        match serde_json::from_slice(buf) {
+                            Ok(value) => (Ok(Some(value)), buf.len()),
+                            Err(ref err) if err.is_eof() => return Err(crate::ResponseError::NeedMoreData),
+                            Err(err) => (Err(err), 0),
+                        }
+                    };
+                Ok((ReadScaleResponse::Other(result), read))
+            },
+        }
+    }
+}
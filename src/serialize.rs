@@ -0,0 +1,100 @@
+/// Serializes values as JSON into existing buffers, for code that serializes many values and wants to reuse
+/// its output buffers instead of allocating a new one for each value with `serde_json::to_vec`.
+///
+/// This is implemented for every type that implements `serde::Serialize`, including all the resource types of this crate.
+///
+/// ```rust
+/// use k8s_openapi::SerializeInto;
+///
+/// let mut buf = vec![];
+/// for name in ["foo", "bar"] {
+///     let config_map = k8s_openapi::api::core::v1::ConfigMap {
+///         metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+///             name: Some(name.to_owned()),
+///             ..Default::default()
+///         },
+///         ..Default::default()
+///     };
+///
+///     buf.clear();
+///     config_map.serialize_into(&mut buf).unwrap();
+///     assert!(buf.starts_with(br#"{"apiVersion":"v1","kind":"ConfigMap""#));
+/// }
+/// ```
+pub trait SerializeInto: serde::Serialize {
+    /// Appends the JSON serialization of this value to the given buffer.
+    ///
+    /// If serialization fails, the buffer may contain a partial serialization of the value after its original contents.
+    fn serialize_into(&self, buf: &mut Vec<u8>) -> Result<(), serde_json::Error> {
+        serde_json::to_writer(buf, self)
+    }
+
+    /// Appends the pretty-printed JSON serialization of this value to the given buffer.
+    ///
+    /// If serialization fails, the buffer may contain a partial serialization of the value after its original contents.
+    fn serialize_into_pretty(&self, buf: &mut Vec<u8>) -> Result<(), serde_json::Error> {
+        serde_json::to_writer_pretty(buf, self)
+    }
+}
+
+impl<T> SerializeInto for T where T: serde::Serialize + ?Sized {}
+
+/// A buffer that values are serialized into one at a time, which reuses its allocation across values.
+///
+/// This is useful when each serialized value is consumed before the next one is serialized, like when writing responses to a socket.
+///
+/// ```rust
+/// let mut serializer = k8s_openapi::SerializeBuffer::new();
+///
+/// let pod = k8s_openapi::api::core::v1::Pod::default();
+/// let serialized = serializer.serialize(&pod).unwrap();
+/// assert_eq!(serialized, br#"{"apiVersion":"v1","kind":"Pod","metadata":{}}"#);
+///
+/// let service = k8s_openapi::api::core::v1::Service::default();
+/// let serialized = serializer.serialize(&service).unwrap();
+/// assert_eq!(serialized, br#"{"apiVersion":"v1","kind":"Service","metadata":{}}"#);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SerializeBuffer {
+    buf: Vec<u8>,
+}
+
+impl SerializeBuffer {
+    /// Creates an empty buffer. It allocates when the first value is serialized into it.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates an empty buffer that can hold a serialized value of the given length without reallocating.
+    pub fn with_capacity(capacity: usize) -> Self {
+        SerializeBuffer {
+            buf: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Serializes the given value as JSON, replacing the previously serialized value, and returns the serialization.
+    pub fn serialize<T>(&mut self, value: &T) -> Result<&[u8], serde_json::Error> where T: serde::Serialize + ?Sized {
+        self.buf.clear();
+        value.serialize_into(&mut self.buf)?;
+        Ok(&self.buf)
+    }
+
+    /// Serializes the given value as pretty-printed JSON, replacing the previously serialized value, and returns the serialization.
+    pub fn serialize_pretty<T>(&mut self, value: &T) -> Result<&[u8], serde_json::Error> where T: serde::Serialize + ?Sized {
+        self.buf.clear();
+        value.serialize_into_pretty(&mut self.buf)?;
+        Ok(&self.buf)
+    }
+
+    /// Returns the underlying buffer, which contains the last serialized value.
+    pub fn into_inner(self) -> Vec<u8> {
+        self.buf
+    }
+}
+
+impl From<Vec<u8>> for SerializeBuffer {
+    /// Reuses the allocation of the given `Vec` for the buffer. Its contents are discarded when the first value is serialized.
+    fn from(buf: Vec<u8>) -> Self {
+        SerializeBuffer { buf }
+    }
+}
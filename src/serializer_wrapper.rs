@@ -0,0 +1,460 @@
+// A `Serializer` that wraps another one, and everything it hands out to the `Serialize` impls of the value,
+// to change how the fields of structs are serialized. Used by `OmitEmptyCollections` and `WithExplicitNulls`.
+//
+// Empty collections are detected by serializing each field into `EmptyCollectionProbe` first, which stops at the first thing the value
+// serializes and reports if that is a sequence or map with a length of zero.
+//
+// Explicit nulls are inserted for the fields listed in `EXPLICIT_NULL_FIELDS` that the value doesn't serialize. The generated types serialize
+// their fields in sorted order, so each null is inserted before the first field that sorts after it, or at the end of the struct.
+
+/// The changes that the wrapper makes to the serialization of the fields of structs.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Options {
+    pub(crate) omit_empty_collections: bool,
+    pub(crate) explicit_nulls: bool,
+}
+
+/// Serializes the value with the given options.
+///
+/// Formats that are not self-describing, like bincode, serialize all the fields of structs in order, so the value is serialized as-is for them.
+pub(crate) fn serialize<T, S>(value: &T, serializer: S, options: Options) -> Result<S::Ok, S::Error> where
+    T: serde::Serialize + ?Sized,
+    S: serde::Serializer,
+{
+    if serializer.is_human_readable() {
+        value.serialize(Serializer { inner: serializer, options })
+    }
+    else {
+        value.serialize(serializer)
+    }
+}
+
+/// The fields that client-go serializes as `null` when they're not set, because they're non-pointer `metav1.Time` fields in the Go types.
+/// So `kubectl` writes them out as `null`, like `creationTimestamp: null`.
+///
+/// Keyed by the name that the generated types pass to `serialize_struct`. The field names of each struct are sorted.
+const EXPLICIT_NULL_FIELDS: &[(&str, &[&str])] = &[
+    ("ContainerStateRunning", &["startedAt"]),
+    ("ContainerStateTerminated", &["finishedAt", "startedAt"]),
+    ("DaemonSetCondition", &["lastTransitionTime"]),
+    ("DeploymentCondition", &["lastTransitionTime", "lastUpdateTime"]),
+    ("JobCondition", &["lastProbeTime", "lastTransitionTime"]),
+    ("NamespaceCondition", &["lastTransitionTime"]),
+    ("NodeCondition", &["lastHeartbeatTime", "lastTransitionTime"]),
+    ("ObjectMeta", &["creationTimestamp"]),
+    ("PersistentVolumeClaimCondition", &["lastProbeTime", "lastTransitionTime"]),
+    ("PodCondition", &["lastProbeTime", "lastTransitionTime"]),
+    ("ReplicaSetCondition", &["lastTransitionTime"]),
+    ("ReplicationControllerCondition", &["lastTransitionTime"]),
+    ("StatefulSetCondition", &["lastTransitionTime"]),
+];
+
+fn explicit_null_fields(name: &str, options: Options) -> &'static [&'static str] {
+    if !options.explicit_nulls {
+        return &[];
+    }
+
+    EXPLICIT_NULL_FIELDS.binary_search_by_key(&name, |&(struct_name, _)| struct_name)
+        .map_or(&[], |i| EXPLICIT_NULL_FIELDS[i].1)
+}
+
+struct Serializer<S> {
+    inner: S,
+    options: Options,
+}
+
+struct Value<'a, T> where T: ?Sized {
+    value: &'a T,
+    options: Options,
+}
+
+impl<T> serde::Serialize for Value<'_, T> where T: serde::Serialize + ?Sized {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        self.value.serialize(Serializer { inner: serializer, options: self.options })
+    }
+}
+
+macro_rules! forward_serialize {
+    ($($method:ident ($($arg:ident: $arg_ty:ty),*),)*) => {
+        $(
+            fn $method(self, $($arg: $arg_ty),*) -> Result<Self::Ok, Self::Error> {
+                self.inner.$method($($arg),*)
+            }
+        )*
+    };
+}
+
+impl<S> serde::Serializer for Serializer<S> where S: serde::Serializer {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    type SerializeSeq = Compound<S::SerializeSeq>;
+    type SerializeTuple = Compound<S::SerializeTuple>;
+    type SerializeTupleStruct = Compound<S::SerializeTupleStruct>;
+    type SerializeTupleVariant = Compound<S::SerializeTupleVariant>;
+    type SerializeMap = Compound<S::SerializeMap>;
+    type SerializeStruct = Compound<S::SerializeStruct>;
+    type SerializeStructVariant = Compound<S::SerializeStructVariant>;
+
+    forward_serialize! {
+        serialize_bool(v: bool),
+        serialize_i8(v: i8),
+        serialize_i16(v: i16),
+        serialize_i32(v: i32),
+        serialize_i64(v: i64),
+        serialize_i128(v: i128),
+        serialize_u8(v: u8),
+        serialize_u16(v: u16),
+        serialize_u32(v: u32),
+        serialize_u64(v: u64),
+        serialize_u128(v: u128),
+        serialize_f32(v: f32),
+        serialize_f64(v: f64),
+        serialize_char(v: char),
+        serialize_str(v: &str),
+        serialize_bytes(v: &[u8]),
+        serialize_none(),
+        serialize_unit(),
+        serialize_unit_struct(name: &'static str),
+        serialize_unit_variant(name: &'static str, variant_index: u32, variant: &'static str),
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, Self::Error> where T: serde::Serialize + ?Sized {
+        self.inner.serialize_some(&Value { value, options: self.options })
+    }
+
+    fn serialize_newtype_struct<T>(self, name: &'static str, value: &T) -> Result<Self::Ok, Self::Error> where T: serde::Serialize + ?Sized {
+        self.inner.serialize_newtype_struct(name, &Value { value, options: self.options })
+    }
+
+    fn serialize_newtype_variant<T>(self, name: &'static str, variant_index: u32, variant: &'static str, value: &T) -> Result<Self::Ok, Self::Error> where
+        T: serde::Serialize + ?Sized,
+    {
+        self.inner.serialize_newtype_variant(name, variant_index, variant, &Value { value, options: self.options })
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        let inner = self.inner.serialize_seq(len)?;
+        Ok(Compound { inner, options: self.options, null_fields: &[] })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        let inner = self.inner.serialize_tuple(len)?;
+        Ok(Compound { inner, options: self.options, null_fields: &[] })
+    }
+
+    fn serialize_tuple_struct(self, name: &'static str, len: usize) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        let inner = self.inner.serialize_tuple_struct(name, len)?;
+        Ok(Compound { inner, options: self.options, null_fields: &[] })
+    }
+
+    fn serialize_tuple_variant(self, name: &'static str, variant_index: u32, variant: &'static str, len: usize) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        let inner = self.inner.serialize_tuple_variant(name, variant_index, variant, len)?;
+        Ok(Compound { inner, options: self.options, null_fields: &[] })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        let inner = self.inner.serialize_map(len)?;
+        Ok(Compound { inner, options: self.options, null_fields: &[] })
+    }
+
+    fn serialize_struct(self, name: &'static str, len: usize) -> Result<Self::SerializeStruct, Self::Error> {
+        // Some serializers, like serde_json's, close the struct right away if it has no fields, so count the null fields too.
+        let null_fields = explicit_null_fields(name, self.options);
+        let inner = self.inner.serialize_struct(name, len + null_fields.len())?;
+        Ok(Compound { inner, options: self.options, null_fields })
+    }
+
+    fn serialize_struct_variant(self, name: &'static str, variant_index: u32, variant: &'static str, len: usize) -> Result<Self::SerializeStructVariant, Self::Error> {
+        let inner = self.inner.serialize_struct_variant(name, variant_index, variant, len)?;
+        Ok(Compound { inner, options: self.options, null_fields: &[] })
+    }
+
+    fn collect_str<T>(self, value: &T) -> Result<Self::Ok, Self::Error> where T: std::fmt::Display + ?Sized {
+        self.inner.collect_str(value)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+struct Compound<S> {
+    inner: S,
+    options: Options,
+
+    /// The remaining fields of this struct to serialize as `null` if the value doesn't serialize them.
+    null_fields: &'static [&'static str],
+}
+
+impl<S> Compound<S> {
+    fn value<'a, T>(&self, value: &'a T) -> Value<'a, T> where T: ?Sized {
+        Value { value, options: self.options }
+    }
+}
+
+impl<S> serde::ser::SerializeSeq for Compound<S> where S: serde::ser::SerializeSeq {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error> where T: serde::Serialize + ?Sized {
+        let value = self.value(value);
+        self.inner.serialize_element(&value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+}
+
+impl<S> serde::ser::SerializeTuple for Compound<S> where S: serde::ser::SerializeTuple {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error> where T: serde::Serialize + ?Sized {
+        let value = self.value(value);
+        self.inner.serialize_element(&value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+}
+
+impl<S> serde::ser::SerializeTupleStruct for Compound<S> where S: serde::ser::SerializeTupleStruct {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error> where T: serde::Serialize + ?Sized {
+        let value = self.value(value);
+        self.inner.serialize_field(&value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+}
+
+impl<S> serde::ser::SerializeTupleVariant for Compound<S> where S: serde::ser::SerializeTupleVariant {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error> where T: serde::Serialize + ?Sized {
+        let value = self.value(value);
+        self.inner.serialize_field(&value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+}
+
+impl<S> serde::ser::SerializeMap for Compound<S> where S: serde::ser::SerializeMap {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Self::Error> where T: serde::Serialize + ?Sized {
+        let key = self.value(key);
+        self.inner.serialize_key(&key)
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error> where T: serde::Serialize + ?Sized {
+        let value = self.value(value);
+        self.inner.serialize_value(&value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+}
+
+impl<S> serde::ser::SerializeStruct for Compound<S> where S: serde::ser::SerializeStruct {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error> where T: serde::Serialize + ?Sized {
+        self.serialize_nulls_before(key)?;
+
+        if self.options.omit_empty_collections && is_empty_collection(value) {
+            self.inner.skip_field(key)
+        }
+        else {
+            let value = self.value(value);
+            self.inner.serialize_field(key, &value)
+        }
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), Self::Error> {
+        self.inner.skip_field(key)
+    }
+
+    fn end(mut self) -> Result<Self::Ok, Self::Error> {
+        for key in std::mem::take(&mut self.null_fields) {
+            self.inner.serialize_field(key, &None::<()>)?;
+        }
+        self.inner.end()
+    }
+}
+
+impl<S> Compound<S> where S: serde::ser::SerializeStruct {
+    /// Serializes the remaining null fields that sort before the given field, and skips the given field itself if it's a null field.
+    fn serialize_nulls_before(&mut self, key: &'static str) -> Result<(), S::Error> {
+        while let Some((&null_field, rest)) = self.null_fields.split_first() {
+            if null_field > key {
+                break;
+            }
+
+            self.null_fields = rest;
+            if null_field < key {
+                self.inner.serialize_field(null_field, &None::<()>)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<S> serde::ser::SerializeStructVariant for Compound<S> where S: serde::ser::SerializeStructVariant {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error> where T: serde::Serialize + ?Sized {
+        if self.options.omit_empty_collections && is_empty_collection(value) {
+            self.inner.skip_field(key)
+        }
+        else {
+            let value = self.value(value);
+            self.inner.serialize_field(key, &value)
+        }
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), Self::Error> {
+        self.inner.skip_field(key)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+}
+
+fn is_empty_collection<T>(value: &T) -> bool where T: serde::Serialize + ?Sized {
+    matches!(value.serialize(EmptyCollectionProbe), Err(ProbeResult { is_empty_collection: true }))
+}
+
+/// A `Serializer` that fails as soon as the value starts serializing, with an error that says if the value is an empty sequence or map.
+///
+/// Sequences and maps of unknown length are not considered empty.
+struct EmptyCollectionProbe;
+
+#[derive(Debug)]
+struct ProbeResult {
+    is_empty_collection: bool,
+}
+
+impl std::fmt::Display for ProbeResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("probed value")
+    }
+}
+
+impl std::error::Error for ProbeResult {}
+
+impl serde::ser::Error for ProbeResult {
+    fn custom<T>(_: T) -> Self where T: std::fmt::Display {
+        ProbeResult { is_empty_collection: false }
+    }
+}
+
+macro_rules! probe_not_empty {
+    ($($method:ident ($($arg_ty:ty),*),)*) => {
+        $(
+            fn $method(self, $(_: $arg_ty),*) -> Result<Self::Ok, Self::Error> {
+                Err(ProbeResult { is_empty_collection: false })
+            }
+        )*
+    };
+}
+
+impl serde::Serializer for EmptyCollectionProbe {
+    type Ok = ();
+    type Error = ProbeResult;
+
+    type SerializeSeq = serde::ser::Impossible<Self::Ok, Self::Error>;
+    type SerializeTuple = serde::ser::Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleStruct = serde::ser::Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleVariant = serde::ser::Impossible<Self::Ok, Self::Error>;
+    type SerializeMap = serde::ser::Impossible<Self::Ok, Self::Error>;
+    type SerializeStruct = serde::ser::Impossible<Self::Ok, Self::Error>;
+    type SerializeStructVariant = serde::ser::Impossible<Self::Ok, Self::Error>;
+
+    probe_not_empty! {
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+        serialize_none(),
+        serialize_unit(),
+        serialize_unit_struct(&'static str),
+        serialize_unit_variant(&'static str, u32, &'static str),
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, Self::Error> where T: serde::Serialize + ?Sized {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_struct<T>(self, _: &'static str, value: &T) -> Result<Self::Ok, Self::Error> where T: serde::Serialize + ?Sized {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(self, _: &'static str, _: u32, _: &'static str, _: &T) -> Result<Self::Ok, Self::Error> where T: serde::Serialize + ?Sized {
+        Err(ProbeResult { is_empty_collection: false })
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(ProbeResult { is_empty_collection: len == Some(0) })
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(ProbeResult { is_empty_collection: false })
+    }
+
+    fn serialize_tuple_struct(self, _: &'static str, _: usize) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(ProbeResult { is_empty_collection: false })
+    }
+
+    fn serialize_tuple_variant(self, _: &'static str, _: u32, _: &'static str, _: usize) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(ProbeResult { is_empty_collection: false })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(ProbeResult { is_empty_collection: len == Some(0) })
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Self::SerializeStruct, Self::Error> {
+        Err(ProbeResult { is_empty_collection: false })
+    }
+
+    fn serialize_struct_variant(self, _: &'static str, _: u32, _: &'static str, _: usize) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(ProbeResult { is_empty_collection: false })
+    }
+
+    fn collect_str<T>(self, _: &T) -> Result<Self::Ok, Self::Error> where T: std::fmt::Display + ?Sized {
+        Err(ProbeResult { is_empty_collection: false })
+    }
+
+    fn is_human_readable(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,198 @@
+/// A resource whose `metadata` is deserialized as usual, but whose other fields, like its `spec` and `status`,
+/// are kept as raw JSON and only deserialized on demand.
+///
+/// Controllers that only look at the metadata of most of the objects they receive, like to filter them by their labels or to
+/// enqueue their names, can skip most of the cost of deserializing them this way. The objects that need to be looked at fully
+/// can then be deserialized with [`Shallow::decode`], or only their spec or status with [`Shallow::decode_spec`] or [`Shallow::decode_status`].
+///
+/// `Shallow<K>` implements [`Resource`](crate::Resource), [`ListableResource`](crate::ListableResource) and [`Metadata`](crate::Metadata) like `K` does,
+/// so it can be used as the items of a [`List`](crate::List). It serializes back to the same JSON as the object it was deserialized from.
+///
+/// ```rust
+/// use k8s_openapi::api::apps::v1::{Deployment, DeploymentSpec};
+/// use k8s_openapi::Shallow;
+///
+/// let json = r#"{
+///     "apiVersion": "apps/v1",
+///     "kind": "Deployment",
+///     "metadata": { "name": "web", "labels": { "app": "web" } },
+///     "spec": { "replicas": 3, "selector": {}, "template": {} }
+/// }"#;
+///
+/// let deployment: Shallow<Deployment> = k8s_openapi::serde_json::from_str(json).unwrap();
+/// assert_eq!(deployment.metadata.name.as_deref(), Some("web"));
+/// assert_eq!(deployment.spec.as_ref().unwrap().get(), r#"{ "replicas": 3, "selector": {}, "template": {} }"#);
+///
+/// let spec: DeploymentSpec = deployment.decode_spec().unwrap().unwrap();
+/// assert_eq!(spec.replicas, Some(3));
+///
+/// let deployment: Deployment = deployment.decode().unwrap();
+/// assert_eq!(deployment.spec.unwrap().replicas, Some(3));
+/// ```
+///
+/// The raw JSON can only be captured by `serde_json`'s own `Deserializer`s, so `Shallow<K>` can't be deserialized from other formats,
+/// or from values that have been buffered by a different deserializer first. In particular, [`WatchEvent`](crate::apimachinery::pkg::apis::meta::v1::WatchEvent)
+/// buffers its object before deserializing it, so `WatchEvent<Shallow<K>>` fails to deserialize.
+/// Deserialize each line of a watch into your own struct with `type` and `object: Box<serde_json::value::RawValue>` fields instead,
+/// and then deserialize the `object` as `Shallow<K>`.
+pub struct Shallow<K> {
+    /// The metadata of the object.
+    pub metadata: crate::apimachinery::pkg::apis::meta::v1::ObjectMeta,
+
+    /// The raw JSON of the `spec` of the object, if it has one.
+    pub spec: Option<Box<serde_json::value::RawValue>>,
+
+    /// The raw JSON of the `status` of the object, if it has one.
+    pub status: Option<Box<serde_json::value::RawValue>>,
+
+    /// The raw JSON of the other fields of the object, like the `data` of a `ConfigMap`, other than its `apiVersion` and `kind`.
+    pub other_fields: std::collections::BTreeMap<String, Box<serde_json::value::RawValue>>,
+
+    resource: std::marker::PhantomData<fn() -> K>,
+}
+
+impl<K> Shallow<K> {
+    /// Creates a value with the given metadata and no other fields.
+    pub fn new(metadata: crate::apimachinery::pkg::apis::meta::v1::ObjectMeta) -> Self {
+        Shallow {
+            metadata,
+            spec: None,
+            status: None,
+            other_fields: Default::default(),
+            resource: Default::default(),
+        }
+    }
+
+    /// Deserializes the `spec` of the object, if it has one.
+    pub fn decode_spec<'a, T>(&'a self) -> Result<Option<T>, serde_json::Error> where T: serde::Deserialize<'a> {
+        self.spec.as_ref().map(|spec| serde_json::from_str(spec.get())).transpose()
+    }
+
+    /// Deserializes the `status` of the object, if it has one.
+    pub fn decode_status<'a, T>(&'a self) -> Result<Option<T>, serde_json::Error> where T: serde::Deserialize<'a> {
+        self.status.as_ref().map(|status| serde_json::from_str(status.get())).transpose()
+    }
+
+    /// Deserializes the whole object.
+    pub fn decode(&self) -> Result<K, serde_json::Error> where K: crate::Resource + serde::de::DeserializeOwned {
+        let json = serde_json::to_string(self)?;
+        serde_json::from_str(&json)
+    }
+}
+
+impl<K> Clone for Shallow<K> {
+    fn clone(&self) -> Self {
+        Shallow {
+            metadata: self.metadata.clone(),
+            spec: self.spec.clone(),
+            status: self.status.clone(),
+            other_fields: self.other_fields.clone(),
+            resource: Default::default(),
+        }
+    }
+}
+
+impl<K> std::fmt::Debug for Shallow<K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Shallow")
+            .field("metadata", &self.metadata)
+            .field("spec", &self.spec)
+            .field("status", &self.status)
+            .field("other_fields", &self.other_fields)
+            .finish()
+    }
+}
+
+impl<K> crate::Resource for Shallow<K> where K: crate::Resource {
+    const API_VERSION: &'static str = K::API_VERSION;
+    const GROUP: &'static str = K::GROUP;
+    const KIND: &'static str = K::KIND;
+    const VERSION: &'static str = K::VERSION;
+    const URL_PATH_SEGMENT: &'static str = K::URL_PATH_SEGMENT;
+    const VERBS: &'static [&'static str] = K::VERBS;
+    const PLURAL: &'static str = K::PLURAL;
+    const SINGULAR: &'static str = K::SINGULAR;
+    const SHORT_NAMES: &'static [&'static str] = K::SHORT_NAMES;
+    type Scope = K::Scope;
+}
+
+impl<K> crate::ListableResource for Shallow<K> where K: crate::ListableResource {
+    const LIST_KIND: &'static str = K::LIST_KIND;
+}
+
+impl<K> crate::Metadata for Shallow<K> where K: crate::Resource {
+    type Ty = crate::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+    fn metadata(&self) -> &<Self as crate::Metadata>::Ty {
+        &self.metadata
+    }
+
+    fn metadata_mut(&mut self) -> &mut<Self as crate::Metadata>::Ty {
+        &mut self.metadata
+    }
+}
+
+impl<'de, K> serde::Deserialize<'de> for Shallow<K> where K: crate::Resource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        struct Visitor<K>(std::marker::PhantomData<fn() -> K>);
+
+        impl<'de, K> serde::de::Visitor<'de> for Visitor<K> where K: crate::Resource {
+            type Value = Shallow<K>;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(K::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de> {
+                let mut metadata = None;
+                let mut value = Shallow::new(Default::default());
+
+                while let Some(key) = serde::de::MapAccess::next_key::<String>(&mut map)? {
+                    match &*key {
+                        "apiVersion" => {
+                            let api_version: String = serde::de::MapAccess::next_value(&mut map)?;
+                            if api_version != K::API_VERSION {
+                                return Err(serde::de::Error::invalid_value(serde::de::Unexpected::Str(&api_version), &K::API_VERSION));
+                            }
+                        },
+                        "kind" => {
+                            let kind: String = serde::de::MapAccess::next_value(&mut map)?;
+                            if kind != K::KIND {
+                                return Err(serde::de::Error::invalid_value(serde::de::Unexpected::Str(&kind), &K::KIND));
+                            }
+                        },
+                        "metadata" => metadata = Some(serde::de::MapAccess::next_value(&mut map)?),
+                        "spec" => value.spec = serde::de::MapAccess::next_value(&mut map)?,
+                        "status" => value.status = serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { value.other_fields.insert(key, serde::de::MapAccess::next_value(&mut map)?); },
+                    }
+                }
+
+                value.metadata = metadata.ok_or_else(|| serde::de::Error::missing_field("metadata"))?;
+                Ok(value)
+            }
+        }
+
+        deserializer.deserialize_map(Visitor(Default::default()))
+    }
+}
+
+impl<K> serde::Serialize for Shallow<K> where K: crate::Resource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        let len = 3 + usize::from(self.spec.is_some()) + usize::from(self.status.is_some()) + self.other_fields.len();
+        let mut state = serializer.serialize_map(Some(len))?;
+        serde::ser::SerializeMap::serialize_entry(&mut state, "apiVersion", K::API_VERSION)?;
+        serde::ser::SerializeMap::serialize_entry(&mut state, "kind", K::KIND)?;
+        serde::ser::SerializeMap::serialize_entry(&mut state, "metadata", &self.metadata)?;
+        if let Some(spec) = &self.spec {
+            serde::ser::SerializeMap::serialize_entry(&mut state, "spec", spec)?;
+        }
+        if let Some(status) = &self.status {
+            serde::ser::SerializeMap::serialize_entry(&mut state, "status", status)?;
+        }
+        for (name, value) in &self.other_fields {
+            serde::ser::SerializeMap::serialize_entry(&mut state, name, value)?;
+        }
+        serde::ser::SerializeMap::end(state)
+    }
+}
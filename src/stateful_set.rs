@@ -0,0 +1,130 @@
+// Rollout status of `apps/v1` `StatefulSet`, matching the logic of `kubectl rollout status`.
+//
+// Ref: https://github.com/kubernetes/kubectl/blob/master/pkg/polymorphichelpers/rollout_status.go
+
+use crate::api::apps::v1::StatefulSet;
+
+impl StatefulSet {
+    /// The status of the rollout of the stateful set's current spec, the same as `kubectl rollout status` reports it.
+    ///
+    /// If the stateful set's rolling update has a `partition`, the rollout is complete once the pods with ordinals at or above the partition
+    /// have been updated, since the pods below it are not updated until the partition is lowered.
+    pub fn rollout_status(&self) -> StatefulSetRolloutStatus {
+        let spec = self.spec.as_ref();
+        let status = self.status.as_ref();
+
+        let update_strategy = spec.and_then(|spec| spec.update_strategy.as_ref());
+        if !matches!(update_strategy.and_then(|update_strategy| update_strategy.type_.as_ref().map(AsRef::as_ref)), None | Some("RollingUpdate")) {
+            return StatefulSetRolloutStatus::NotRollingUpdate;
+        }
+
+        let observed_generation = status.and_then(|status| status.observed_generation).unwrap_or_default();
+        if observed_generation == 0 || self.metadata.generation.unwrap_or_default() > observed_generation {
+            return StatefulSetRolloutStatus::SpecUpdateNotObserved;
+        }
+
+        let ready_replicas = status.and_then(|status| status.ready_replicas).unwrap_or_default();
+        let updated_replicas = status.and_then(|status| status.updated_replicas).unwrap_or_default();
+
+        let desired_replicas = spec.and_then(|spec| spec.replicas);
+        if let Some(desired_replicas) = desired_replicas {
+            if ready_replicas < desired_replicas {
+                return StatefulSetRolloutStatus::WaitingForReadyReplicas { not_ready_replicas: desired_replicas - ready_replicas };
+            }
+        }
+
+        // The API server always sets the rolling update of a stateful set with the `RollingUpdate` strategy, with a partition of 0 by default.
+        if let Some(rolling_update) = update_strategy.and_then(|update_strategy| update_strategy.rolling_update.as_ref()) {
+            if let (Some(desired_replicas), Some(partition)) = (desired_replicas, rolling_update.partition) {
+                let target_replicas = desired_replicas - partition;
+                if updated_replicas < target_replicas {
+                    return StatefulSetRolloutStatus::UpdatingPartition { updated_replicas, target_replicas };
+                }
+            }
+
+            return StatefulSetRolloutStatus::PartitionComplete { updated_replicas };
+        }
+
+        let current_revision = status.and_then(|status| status.current_revision.as_deref()).unwrap_or_default();
+        let update_revision = status.and_then(|status| status.update_revision.as_deref()).unwrap_or_default();
+        if update_revision != current_revision {
+            return StatefulSetRolloutStatus::UpdatingReplicas { updated_replicas, update_revision: update_revision.to_owned() };
+        }
+
+        let current_replicas = status.and_then(|status| status.current_replicas).unwrap_or_default();
+        StatefulSetRolloutStatus::Complete { current_replicas, current_revision: current_revision.to_owned() }
+    }
+
+    /// Returns `true` if the rollout of the stateful set's current spec is complete. See [`StatefulSet::rollout_status`] for details.
+    pub fn is_rollout_complete(&self) -> bool {
+        self.rollout_status().is_complete()
+    }
+}
+
+/// The status of the rollout of a [`StatefulSet`]. Returned by [`StatefulSet::rollout_status`].
+///
+/// The `Display` impl formats it the same way as the message of `kubectl rollout status`, without the name of the stateful set.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StatefulSetRolloutStatus {
+    /// The stateful set's update strategy is not `RollingUpdate`, such as `OnDelete`, so it does not have a rollout to wait for.
+    NotRollingUpdate,
+
+    /// The stateful set controller has not observed the stateful set's current spec yet, so its status is out of date.
+    SpecUpdateNotObserved,
+
+    /// Not all the desired replicas are ready yet.
+    WaitingForReadyReplicas {
+        not_ready_replicas: i32,
+    },
+
+    /// Not all the replicas at or above the partition of the stateful set's rolling update have been updated to the current spec yet.
+    UpdatingPartition {
+        updated_replicas: i32,
+        target_replicas: i32,
+    },
+
+    /// All the replicas at or above the partition of the stateful set's rolling update have been updated to the current spec.
+    PartitionComplete {
+        updated_replicas: i32,
+    },
+
+    /// Not all the replicas have been updated to the current revision yet.
+    UpdatingReplicas {
+        updated_replicas: i32,
+        update_revision: String,
+    },
+
+    /// The rollout is complete.
+    Complete {
+        current_replicas: i32,
+        current_revision: String,
+    },
+}
+
+impl StatefulSetRolloutStatus {
+    /// Returns `true` if the rollout is complete, either fully or up to the partition of the stateful set's rolling update.
+    pub fn is_complete(&self) -> bool {
+        matches!(self, StatefulSetRolloutStatus::PartitionComplete { .. } | StatefulSetRolloutStatus::Complete { .. })
+    }
+}
+
+impl std::fmt::Display for StatefulSetRolloutStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StatefulSetRolloutStatus::NotRollingUpdate =>
+                f.write_str("rollout status is only available for RollingUpdate strategy type"),
+            StatefulSetRolloutStatus::SpecUpdateNotObserved =>
+                f.write_str("Waiting for statefulset spec update to be observed..."),
+            StatefulSetRolloutStatus::WaitingForReadyReplicas { not_ready_replicas } =>
+                write!(f, "Waiting for {not_ready_replicas} pods to be ready..."),
+            StatefulSetRolloutStatus::UpdatingPartition { updated_replicas, target_replicas } =>
+                write!(f, "Waiting for partitioned roll out to finish: {updated_replicas} out of {target_replicas} new pods have been updated..."),
+            StatefulSetRolloutStatus::PartitionComplete { updated_replicas } =>
+                write!(f, "partitioned roll out complete: {updated_replicas} new pods have been updated..."),
+            StatefulSetRolloutStatus::UpdatingReplicas { updated_replicas, update_revision } =>
+                write!(f, "waiting for statefulset rolling update to complete {updated_replicas} pods at revision {update_revision}..."),
+            StatefulSetRolloutStatus::Complete { current_replicas, current_revision } =>
+                write!(f, "statefulset rolling update complete {current_replicas} pods at revision {current_revision}..."),
+        }
+    }
+}
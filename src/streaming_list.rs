@@ -0,0 +1,101 @@
+/// A list of resources that serializes its items from an iterator as they're produced, instead of collecting them into a [`List`](crate::List) first.
+///
+/// It serializes the same way as a `List<T>` of the same items, so a mock API server or an exporter can write out arbitrarily large lists
+/// while only holding one item in memory at a time.
+///
+/// Since the iterator is consumed by serialization, a `StreamingList` can only be serialized once. Serializing it again is an error.
+///
+/// ```rust
+/// use k8s_openapi::api::core::v1 as api;
+///
+/// let config_maps = (0..3).map(|i| api::ConfigMap {
+///     metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+///         name: Some(format!("config-map-{i}")),
+///         ..Default::default()
+///     },
+///     ..Default::default()
+/// });
+/// let list = k8s_openapi::StreamingList::new(Default::default(), config_maps);
+///
+/// let mut buf = vec![];
+/// k8s_openapi::serde_json::to_writer(&mut buf, &list).unwrap();
+/// assert!(buf.starts_with(br#"{"apiVersion":"v1","kind":"ConfigMapList","items":[{"apiVersion":"v1","kind":"ConfigMap","metadata":{"name":"config-map-0"}},"#));
+/// ```
+pub struct StreamingList<I> {
+    api_version: &'static str,
+    kind: &'static str,
+    metadata: crate::apimachinery::pkg::apis::meta::v1::ListMeta,
+    items: std::cell::Cell<Option<I>>,
+}
+
+impl<I> StreamingList<I> where I: IntoIterator, I::Item: crate::ListableResource {
+    /// Creates a list of the given items, with the API version and list kind of their resource type.
+    pub fn new(metadata: crate::apimachinery::pkg::apis::meta::v1::ListMeta, items: I) -> Self {
+        StreamingList::with_kind(
+            <I::Item as crate::Resource>::API_VERSION,
+            <I::Item as crate::ListableResource>::LIST_KIND,
+            metadata,
+            items,
+        )
+    }
+}
+
+impl<I> StreamingList<I> {
+    /// Creates a list of the given items with the given API version and kind.
+    ///
+    /// This can be used for synthetic lists whose items don't implement [`ListableResource`](crate::ListableResource),
+    /// like a list of objects of different kinds with `"kind": "List"`.
+    pub fn with_kind(api_version: &'static str, kind: &'static str, metadata: crate::apimachinery::pkg::apis::meta::v1::ListMeta, items: I) -> Self {
+        StreamingList {
+            api_version,
+            kind,
+            metadata,
+            items: std::cell::Cell::new(Some(items)),
+        }
+    }
+}
+
+impl<I> std::fmt::Debug for StreamingList<I> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StreamingList")
+            .field("api_version", &self.api_version)
+            .field("kind", &self.kind)
+            .field("metadata", &self.metadata)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I> serde::Serialize for StreamingList<I> where I: IntoIterator, I::Item: serde::Serialize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        let items = self.items.take().ok_or_else(|| serde::ser::Error::custom("StreamingList can only be serialized once"))?;
+        let items = Items(std::cell::Cell::new(Some(items)));
+
+        // Same as the `Serialize` impl of `List<T>`
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct(self.kind, 2)?;
+            serde::ser::SerializeStruct::serialize_field(&mut state, "items", &items)?;
+            serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", &self.metadata)?;
+            return serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(self.kind, 4)?;
+        serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", self.api_version)?;
+        serde::ser::SerializeStruct::serialize_field(&mut state, "kind", self.kind)?;
+        serde::ser::SerializeStruct::serialize_field(&mut state, "items", &items)?;
+        serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", &self.metadata)?;
+        serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// Serializes the items of a [`StreamingList`] as a sequence.
+///
+/// The length of the sequence is only known up-front if the iterator's size hint is exact,
+/// which formats that are not self-describing, like bincode, require.
+struct Items<I>(std::cell::Cell<Option<I>>);
+
+impl<I> serde::Serialize for Items<I> where I: IntoIterator, I::Item: serde::Serialize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        let items = self.0.take().ok_or_else(|| serde::ser::Error::custom("StreamingList can only be serialized once"))?;
+        serializer.collect_seq(items)
+    }
+}
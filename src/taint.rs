@@ -0,0 +1,122 @@
+//! Parsing and formatting of [`Taint`]s and [`Toleration`]s in the string syntax of `kubectl taint`, like `dedicated=gpu:NoSchedule`.
+//!
+//! A taint is formatted as `key=value:effect`, or `key:effect` if it does not have a value. The effect must be one of
+//! `NoSchedule`, `PreferNoSchedule` or `NoExecute`.
+//!
+//! A toleration uses the same syntax, where the effect is optional and a toleration without an effect tolerates taints with any effect.
+//! `key=value` tolerates taints with that key and value (the `Equal` operator), and `key` tolerates taints with that key and any value
+//! (the `Exists` operator). The empty string tolerates all taints, and `=` tolerates all taints with an empty value, like a toleration
+//! that has neither a key nor an operator. The `tolerationSeconds` of a toleration cannot be expressed in this syntax.
+
+use crate::api::core::v1::{Taint, TaintEffect, Toleration, TolerationOperator};
+
+impl std::fmt::Display for Taint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.key)?;
+        if let Some(value) = &self.value {
+            write!(f, "={value}")?;
+        }
+        write!(f, ":{}", self.effect)
+    }
+}
+
+impl std::str::FromStr for Taint {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = |reason| ParseError { kind: "taint", input: s.to_owned(), reason };
+
+        let (key_value, effect) = split_effect(s).map_err(err)?;
+        let effect = effect.ok_or_else(|| err("a taint must have an effect"))?;
+        let (key, value) = split_key_value(key_value).map_err(err)?;
+        if key.is_empty() {
+            return Err(err("a taint must have a key"));
+        }
+
+        Ok(Taint {
+            effect: effect.into(),
+            key: key.to_owned(),
+            value: value.map(ToOwned::to_owned),
+            ..Default::default()
+        })
+    }
+}
+
+impl std::fmt::Display for Toleration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(key) = &self.key {
+            f.write_str(key)?;
+        }
+        if !matches!(self.operator, Some(TolerationOperator::Exists)) {
+            write!(f, "={}", self.value.as_deref().unwrap_or_default())?;
+        }
+        if let Some(effect) = self.effect.as_ref().map(TaintEffect::as_str).filter(|effect| !effect.is_empty()) {
+            write!(f, ":{effect}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for Toleration {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = |reason| ParseError { kind: "toleration", input: s.to_owned(), reason };
+
+        let (key_value, effect) = split_effect(s).map_err(err)?;
+        let (key, value) = split_key_value(key_value).map_err(err)?;
+        if key.is_empty() && value.is_some_and(|value| !value.is_empty()) {
+            return Err(err("a toleration with a value must have a key"));
+        }
+
+        Ok(Toleration {
+            effect: effect.map(Into::into),
+            key: if key.is_empty() { None } else { Some(key.to_owned()) },
+            operator: Some(if value.is_some() { TolerationOperator::Equal } else { TolerationOperator::Exists }),
+            value: value.map(ToOwned::to_owned),
+            ..Default::default()
+        })
+    }
+}
+
+/// Splits `key=value:effect` into `key=value` and the effect, if any.
+fn split_effect(s: &str) -> Result<(&str, Option<&str>), &'static str> {
+    match s.find(':') {
+        Some(pos) => {
+            let effect = &s[(pos + 1)..];
+            if !TaintEffect::VALUES.contains(&effect) {
+                return Err("the effect must be one of `NoSchedule`, `PreferNoSchedule` or `NoExecute`");
+            }
+            Ok((&s[..pos], Some(effect)))
+        },
+        None => Ok((s, None)),
+    }
+}
+
+/// Splits `key=value` into the key and the value, if any.
+fn split_key_value(key_value: &str) -> Result<(&str, Option<&str>), &'static str> {
+    let (key, value) = match key_value.find('=') {
+        Some(pos) => (&key_value[..pos], Some(&key_value[(pos + 1)..])),
+        None => (key_value, None),
+    };
+    if value.is_some_and(|value| value.contains('=')) {
+        return Err("the key and value must not contain `=`");
+    }
+    Ok((key, value))
+}
+
+/// The error returned when parsing a [`Taint`] or [`Toleration`] from a string that does not follow the syntax described in the [module docs](self).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    kind: &'static str,
+    input: String,
+    reason: &'static str,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid {} {:?}: {}", self.kind, self.input, self.reason)
+    }
+}
+
+impl std::error::Error for ParseError {}
@@ -0,0 +1,96 @@
+type UnknownFieldHandler = fn(type_name: &'static str, field: &str);
+
+static UNKNOWN_FIELD_HANDLER: std::sync::RwLock<Option<UnknownFieldHandler>> = std::sync::RwLock::new(None);
+
+/// Sets the function that is called when a value of one of the types of this crate is deserialized from an object with a field
+/// that the type does not have, with the name of the type and the name of the field. `None` removes the current function.
+///
+/// Such fields are ignored by deserialization, so they are lost when the value is serialized again, like when a controller
+/// replaces an object that it read. They are usually fields that were added in a newer version of Kubernetes than the one selected
+/// by the `v1_*` feature, so the handler can be used to log or count them to find out that the cluster is newer than the crate.
+///
+/// The handler applies to all threads. It is not called for the fields of types that keep unknown fields in a flattened field.
+///
+/// ```rust
+/// fn log_unknown_field(type_name: &'static str, field: &str) {
+///     eprintln!("{type_name} has unknown field {field:?} which will be dropped");
+/// }
+///
+/// k8s_openapi::set_unknown_field_handler(Some(log_unknown_field));
+///
+/// // Logs "ConfigMap has unknown field \"newField\" which will be dropped"
+/// let _: k8s_openapi::api::core::v1::ConfigMap = k8s_openapi::serde_json::from_value(k8s_openapi::serde_json::json!({
+///     "apiVersion": "v1",
+///     "kind": "ConfigMap",
+///     "metadata": { "name": "app-config" },
+///     "newField": true,
+/// })).unwrap();
+///
+/// k8s_openapi::set_unknown_field_handler(None);
+/// ```
+pub fn set_unknown_field_handler(handler: Option<UnknownFieldHandler>) {
+    *UNKNOWN_FIELD_HANDLER.write().unwrap_or_else(std::sync::PoisonError::into_inner) = handler;
+}
+
+/// Calls the handler set with [`set_unknown_field_handler`], if any, for the given unknown field of the type named `type_name`.
+///
+/// Returns an error instead if the field is being deserialized as part of a [`Strict`].
+pub(crate) fn unknown_field<E>(type_name: &'static str, field: &str, expected: &'static [&'static str]) -> Result<(), E> where E: serde::de::Error {
+    if STRICT.with(std::cell::Cell::get) {
+        return Err(E::unknown_field(field, expected));
+    }
+
+    let handler = *UNKNOWN_FIELD_HANDLER.read().unwrap_or_else(std::sync::PoisonError::into_inner);
+    if let Some(handler) = handler {
+        handler(type_name, field);
+    }
+
+    Ok(())
+}
+
+thread_local! {
+    static STRICT: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+/// Deserializes a value of one of the types of this crate, but fails if the value or any of its fields has a field that its type does not have,
+/// instead of ignoring it.
+///
+/// This is useful to validate manifests written by users against the version of Kubernetes selected by the `v1_*` feature,
+/// since it catches typos in field names as well as fields that only exist in newer versions. The error names the unknown field.
+///
+/// As with [`set_unknown_field_handler`], fields of types that keep unknown fields in a flattened field are not rejected.
+///
+/// ```rust
+/// use k8s_openapi::api::core::v1::ConfigMap;
+///
+/// let manifest = k8s_openapi::serde_json::json!({
+///     "apiVersion": "v1",
+///     "kind": "ConfigMap",
+///     "metadata": { "name": "app-config", "lables": { "app": "foo" } },
+/// });
+///
+/// // Deserializing the type by itself ignores the misspelled field.
+/// let config_map: ConfigMap = k8s_openapi::serde_json::from_value(manifest.clone()).unwrap();
+/// assert_eq!(config_map.metadata.labels, None);
+///
+/// // Deserializing it as `Strict` fails.
+/// let err = k8s_openapi::serde_json::from_value::<k8s_openapi::Strict<ConfigMap>>(manifest).unwrap_err();
+/// assert!(err.to_string().starts_with("unknown field `lables`"));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Strict<T>(pub T);
+
+impl<'de, T> serde::Deserialize<'de> for Strict<T> where T: serde::Deserialize<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        struct Guard(bool);
+
+        impl Drop for Guard {
+            fn drop(&mut self) {
+                STRICT.with(|strict| strict.set(self.0));
+            }
+        }
+
+        let _guard = Guard(STRICT.with(|strict| strict.replace(true)));
+        T::deserialize(deserializer).map(Strict)
+    }
+}
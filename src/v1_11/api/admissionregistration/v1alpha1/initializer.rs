@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.admissionregistration.v1alpha1.Initializer
 
 /// Initializer describes the name and the failure policy of an initializer, and what resources it applies to.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct Initializer {
     /// Name is the identifier of the initializer. It will be added to the object that needs to be initialized. Name should be fully qualified, e.g., alwayspullimages.kubernetes.io, where "alwayspullimages" is the name of the webhook, and kubernetes.io is the name of the organization. Required
     pub name: String,
@@ -10,39 +10,43 @@ pub struct Initializer {
     pub rules: Option<Vec<crate::api::admissionregistration::v1alpha1::Rule>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for Initializer {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_name,
-            Key_rules,
-            Other,
+impl Initializer {
+    /// Constructs a `Initializer` from its required fields. Its optional fields are unset.
+    pub fn new(name: impl Into<String>) -> Self {
+        Initializer {
+            name: name.into(),
+            rules: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl Initializer {
+    /// Returns this value with [`Initializer::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`Initializer::rules`] set to the given value.
+    #[must_use]
+    pub fn with_rules(mut self, value: impl Into<Vec<crate::api::admissionregistration::v1alpha1::Rule>>) -> Self {
+        self.rules = Some(value.into());
+        self
+    }
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for Initializer {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "name" => Field::Key_name,
-                            "rules" => Field::Key_rules,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl Initializer {
+    const FIELDS: &'static [&'static str] = &[
+        "name",
+        "rules",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for Initializer {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +60,91 @@ impl<'de> crate::serde::Deserialize<'de> for Initializer {
                 let mut value_name: Option<String> = None;
                 let mut value_rules: Option<Vec<crate::api::admissionregistration::v1alpha1::Rule>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Initializer", Initializer::FIELDS))? {
                     match key {
-                        Field::Key_name => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_rules => value_rules = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_rules = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(Initializer {
-                    name: value_name.ok_or_else(|| crate::serde::de::Error::missing_field("name"))?,
+                    name: value_name.ok_or_else(|| crate::serde2::missing_field("Initializer", "name", &["name"]))?,
                     rules: value_rules,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(Initializer {
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    rules: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "Initializer",
-            &[
-                "name",
-                "rules",
-            ],
+            Initializer::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, Initializer> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("Initializer")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_name = false;
+                let mut has_rules = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Initializer", Initializer::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.name))?; has_name = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.rules))?; has_rules = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_name {
+                    return Err(crate::serde2::missing_field("Initializer", "name", &["name"]));
+                }
+                if !has_rules {
+                    self.0.rules = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.rules))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "Initializer",
+            Initializer::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for Initializer {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("Initializer", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rules", &self.rules)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "Initializer",
             1 +
@@ -97,10 +158,84 @@ impl crate::serde::Serialize for Initializer {
     }
 }
 
+/// A builder of [`Initializer`]. Created with [`Initializer::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct InitializerBuilder {
+    name: Option<String>,
+    rules: Option<Vec<crate::api::admissionregistration::v1alpha1::Rule>>,
+}
+
+#[cfg(feature = "builder")]
+impl Initializer {
+    /// Starts building a `Initializer`. Its required fields must be set before [`InitializerBuilder::build`] is called.
+    pub fn builder() -> InitializerBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl InitializerBuilder {
+    /// Sets [`Initializer::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`Initializer::rules`].
+    pub fn rules(mut self, value: impl Into<Vec<crate::api::admissionregistration::v1alpha1::Rule>>) -> Self {
+        self.rules = Some(value.into());
+        self
+    }
+
+    /// Builds the `Initializer`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<Initializer, crate::MissingFieldError> {
+        Ok(Initializer {
+            name: self.name.ok_or_else(|| crate::MissingFieldError::new("Initializer", "name"))?,
+            rules: self.rules,
+        })
+    }
+}
+
+/// The apply configuration of [`Initializer`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InitializerApplyConfiguration {
+    /// See [`Initializer::name`].
+    pub name: Option<String>,
+
+    /// See [`Initializer::rules`].
+    pub rules: Option<Vec<crate::api::admissionregistration::v1alpha1::RuleApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for InitializerApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "InitializerApplyConfiguration",
+            self.name.as_ref().map_or(0, |_| 1) +
+            self.rules.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        if let Some(value) = &self.rules {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rules", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Initializer {
     fn schema_name() -> String {
-        "io.k8s.api.admissionregistration.v1alpha1.Initializer".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::admissionregistration::v1alpha1::Initializer".to_owned()
+        }
+        else {
+            "io.k8s.api.admissionregistration.v1alpha1.Initializer".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -111,8 +246,9 @@ impl crate::schemars::JsonSchema for Initializer {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -122,8 +258,8 @@ impl crate::schemars::JsonSchema for Initializer {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "rules".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -137,8 +273,9 @@ impl crate::schemars::JsonSchema for Initializer {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "name",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -148,3 +285,13 @@ impl crate::schemars::JsonSchema for Initializer {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for Initializer {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(Initializer {
+            name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            rules: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.admissionregistration.v1alpha1.InitializerConfiguration
 
 /// InitializerConfiguration describes the configuration of initializers.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct InitializerConfiguration {
     /// Initializers is a list of resources and their default initializers Order-sensitive. When merging multiple InitializerConfigurations, we sort the initializers from different InitializerConfigurations by the name of the InitializerConfigurations; the order of the initializers from the same InitializerConfiguration is preserved.
     pub initializers: Option<Vec<crate::api::admissionregistration::v1alpha1::Initializer>>,
@@ -10,6 +10,32 @@ pub struct InitializerConfiguration {
     pub metadata: crate::apimachinery::pkg::apis::meta::v1::ObjectMeta,
 }
 
+impl InitializerConfiguration {
+    /// Constructs a `InitializerConfiguration` from its required fields. Its optional fields are unset.
+    pub fn new(metadata: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        InitializerConfiguration {
+            initializers: None,
+            metadata: metadata.into(),
+        }
+    }
+}
+
+impl InitializerConfiguration {
+    /// Returns this value with [`InitializerConfiguration::initializers`] set to the given value.
+    #[must_use]
+    pub fn with_initializers(mut self, value: impl Into<Vec<crate::api::admissionregistration::v1alpha1::Initializer>>) -> Self {
+        self.initializers = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`InitializerConfiguration::metadata`] set to the given value.
+    #[must_use]
+    pub fn with_metadata(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        self.metadata = value.into();
+        self
+    }
+}
+
 // Begin admissionregistration.k8s.io/v1alpha1/InitializerConfiguration
 
 // Generated from operation createAdmissionregistrationV1alpha1InitializerConfiguration
@@ -103,6 +129,7 @@ impl InitializerConfiguration {
         name: &str,
         optional: crate::DeleteOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::DeleteResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
         let __url = format!("/apis/admissionregistration.k8s.io/v1alpha1/initializerconfigurations/{name}",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -173,6 +200,7 @@ impl InitializerConfiguration {
         body: &crate::apimachinery::pkg::apis::meta::v1::Patch,
         optional: crate::PatchOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::PatchResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
         let __url = format!("/apis/admissionregistration.k8s.io/v1alpha1/initializerconfigurations/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -220,6 +248,7 @@ impl InitializerConfiguration {
             export,
             pretty,
         } = optional;
+        crate::validate_path_parameter("name", name)?;
         let __url = format!("/apis/admissionregistration.k8s.io/v1alpha1/initializerconfigurations/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -318,6 +347,7 @@ impl InitializerConfiguration {
         body: &crate::api::admissionregistration::v1alpha1::InitializerConfiguration,
         optional: crate::ReplaceOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::ReplaceResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
         let __url = format!("/apis/admissionregistration.k8s.io/v1alpha1/initializerconfigurations/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -375,6 +405,9 @@ impl crate::Resource for InitializerConfiguration {
     const KIND: &'static str = "InitializerConfiguration";
     const VERSION: &'static str = "v1alpha1";
     const URL_PATH_SEGMENT: &'static str = "initializerconfigurations";
+    const VERBS: &'static [&'static str] = &["create", "delete", "deletecollection", "get", "list", "patch", "update", "watch"];
+    const SINGULAR: &'static str = "initializerconfiguration";
+    const SHORT_NAMES: &'static [&'static str] = &[];
     type Scope = crate::ClusterResourceScope;
 }
 
@@ -394,43 +427,29 @@ impl crate::Metadata for InitializerConfiguration {
     }
 }
 
-impl<'de> crate::serde::Deserialize<'de> for InitializerConfiguration {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_api_version,
-            Key_kind,
-            Key_initializers,
-            Key_metadata,
-            Other,
+impl std::fmt::Display for InitializerConfiguration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(<Self as crate::Resource>::URL_PATH_SEGMENT)?;
+        for part in [&self.metadata.namespace, &self.metadata.name].iter().copied().flatten() {
+            write!(f, "/{part}")?;
         }
+        Ok(())
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
-
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "apiVersion" => Field::Key_api_version,
-                            "kind" => Field::Key_kind,
-                            "initializers" => Field::Key_initializers,
-                            "metadata" => Field::Key_metadata,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for InitializerConfiguration {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl InitializerConfiguration {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "kind",
+        "initializers",
+        "metadata",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for InitializerConfiguration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -444,48 +463,115 @@ impl<'de> crate::serde::Deserialize<'de> for InitializerConfiguration {
                 let mut value_initializers: Option<Vec<crate::api::admissionregistration::v1alpha1::Initializer>> = None;
                 let mut value_metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("InitializerConfiguration", InitializerConfiguration::FIELDS))? {
                     match key {
-                        Field::Key_api_version => {
+                        Some(0) => {
                             let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_api_version != <Self::Value as crate::Resource>::API_VERSION {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<Self::Value as crate::Resource>::API_VERSION));
                             }
                         },
-                        Field::Key_kind => {
+                        Some(1) => {
                             let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_kind != <Self::Value as crate::Resource>::KIND {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<Self::Value as crate::Resource>::KIND));
                             }
                         },
-                        Field::Key_initializers => value_initializers = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_metadata => value_metadata = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(2) => value_initializers = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_metadata = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(InitializerConfiguration {
                     initializers: value_initializers,
-                    metadata: value_metadata.ok_or_else(|| crate::serde::de::Error::missing_field("metadata"))?,
+                    metadata: value_metadata.ok_or_else(|| crate::serde2::missing_field("InitializerConfiguration", "metadata", &["metadata"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(InitializerConfiguration {
+                    initializers: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    metadata: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             <Self as crate::Resource>::KIND,
-            &[
-                "apiVersion",
-                "kind",
-                "initializers",
-                "metadata",
-            ],
+            InitializerConfiguration::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, InitializerConfiguration> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<InitializerConfiguration as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_initializers = false;
+                let mut has_metadata = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("InitializerConfiguration", InitializerConfiguration::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <InitializerConfiguration as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<InitializerConfiguration as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <InitializerConfiguration as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<InitializerConfiguration as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.initializers))?; has_initializers = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metadata))?; has_metadata = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_initializers {
+                    self.0.initializers = None;
+                }
+                if !has_metadata {
+                    return Err(crate::serde2::missing_field("InitializerConfiguration", "metadata", &["metadata"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.initializers))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metadata))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            InitializerConfiguration::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for InitializerConfiguration {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct(<Self as crate::Resource>::KIND, 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "initializers", &self.initializers)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", &self.metadata)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             <Self as crate::Resource>::KIND,
             3 +
@@ -501,10 +587,87 @@ impl crate::serde::Serialize for InitializerConfiguration {
     }
 }
 
+/// A builder of [`InitializerConfiguration`]. Created with [`InitializerConfiguration::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct InitializerConfigurationBuilder {
+    initializers: Option<Vec<crate::api::admissionregistration::v1alpha1::Initializer>>,
+    metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>,
+}
+
+#[cfg(feature = "builder")]
+impl InitializerConfiguration {
+    /// Starts building a `InitializerConfiguration`. Its required fields must be set before [`InitializerConfigurationBuilder::build`] is called.
+    pub fn builder() -> InitializerConfigurationBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl InitializerConfigurationBuilder {
+    /// Sets [`InitializerConfiguration::initializers`].
+    pub fn initializers(mut self, value: impl Into<Vec<crate::api::admissionregistration::v1alpha1::Initializer>>) -> Self {
+        self.initializers = Some(value.into());
+        self
+    }
+
+    /// Sets [`InitializerConfiguration::metadata`].
+    pub fn metadata(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        self.metadata = Some(value.into());
+        self
+    }
+
+    /// Builds the `InitializerConfiguration`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<InitializerConfiguration, crate::MissingFieldError> {
+        Ok(InitializerConfiguration {
+            initializers: self.initializers,
+            metadata: self.metadata.ok_or_else(|| crate::MissingFieldError::new("InitializerConfiguration", "metadata"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`InitializerConfiguration`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InitializerConfigurationApplyConfiguration {
+    /// See [`InitializerConfiguration::initializers`].
+    pub initializers: Option<Vec<crate::api::admissionregistration::v1alpha1::InitializerApplyConfiguration>>,
+
+    /// See [`InitializerConfiguration::metadata`].
+    pub metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMetaApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for InitializerConfigurationApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            <InitializerConfiguration as crate::Resource>::KIND,
+            2 +
+            self.initializers.as_ref().map_or(0, |_| 1) +
+            self.metadata.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <InitializerConfiguration as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <InitializerConfiguration as crate::Resource>::KIND)?;
+        if let Some(value) = &self.initializers {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "initializers", value)?;
+        }
+        if let Some(value) = &self.metadata {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for InitializerConfiguration {
     fn schema_name() -> String {
-        "io.k8s.api.admissionregistration.v1alpha1.InitializerConfiguration".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::admissionregistration::v1alpha1::InitializerConfiguration".to_owned()
+        }
+        else {
+            "io.k8s.api.admissionregistration.v1alpha1.InitializerConfiguration".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -515,8 +678,9 @@ impl crate::schemars::JsonSchema for InitializerConfiguration {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "apiVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -524,10 +688,11 @@ impl crate::schemars::JsonSchema for InitializerConfiguration {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("admissionregistration.k8s.io/v1alpha1".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "initializers".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -541,8 +706,8 @@ impl crate::schemars::JsonSchema for InitializerConfiguration {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "kind".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -550,10 +715,11 @@ impl crate::schemars::JsonSchema for InitializerConfiguration {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("InitializerConfiguration".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "metadata".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>().into_object();
@@ -563,9 +729,12 @@ impl crate::schemars::JsonSchema for InitializerConfiguration {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
+                    "apiVersion",
+                    "kind",
                     "metadata",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
                 ..Default::default()
@@ -574,3 +743,13 @@ impl crate::schemars::JsonSchema for InitializerConfiguration {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for InitializerConfiguration {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(InitializerConfiguration {
+            initializers: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            metadata: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.admissionregistration.v1alpha1.Rule
 
 /// Rule is a tuple of APIGroups, APIVersion, and Resources.It is recommended to make sure that all the tuple expansions are valid.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct Rule {
     /// APIGroups is the API groups the resources belong to. '*' is all groups. If '*' is present, the length of the slice must be one. Required.
     pub api_groups: Option<Vec<String>>,
@@ -19,41 +19,41 @@ pub struct Rule {
     pub resources: Option<Vec<String>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for Rule {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_api_groups,
-            Key_api_versions,
-            Key_resources,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl Rule {
+    /// Returns this value with [`Rule::api_groups`] set to the given value.
+    #[must_use]
+    pub fn with_api_groups(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.api_groups = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`Rule::api_versions`] set to the given value.
+    #[must_use]
+    pub fn with_api_versions(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.api_versions = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`Rule::resources`] set to the given value.
+    #[must_use]
+    pub fn with_resources(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.resources = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "apiGroups" => Field::Key_api_groups,
-                            "apiVersions" => Field::Key_api_versions,
-                            "resources" => Field::Key_resources,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for Rule {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl Rule {
+    const FIELDS: &'static [&'static str] = &[
+        "apiGroups",
+        "apiVersions",
+        "resources",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for Rule {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -68,12 +68,12 @@ impl<'de> crate::serde::Deserialize<'de> for Rule {
                 let mut value_api_versions: Option<Vec<String>> = None;
                 let mut value_resources: Option<Vec<String>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Rule", Rule::FIELDS))? {
                     match key {
-                        Field::Key_api_groups => value_api_groups = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_api_versions => value_api_versions = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_resources => value_resources = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_api_groups = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_api_versions = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_resources = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -83,22 +83,86 @@ impl<'de> crate::serde::Deserialize<'de> for Rule {
                     resources: value_resources,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(Rule {
+                    api_groups: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    api_versions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    resources: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "Rule",
-            &[
-                "apiGroups",
-                "apiVersions",
-                "resources",
-            ],
+            Rule::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, Rule> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("Rule")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_api_groups = false;
+                let mut has_api_versions = false;
+                let mut has_resources = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Rule", Rule::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.api_groups))?; has_api_groups = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.api_versions))?; has_api_versions = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.resources))?; has_resources = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_api_groups {
+                    self.0.api_groups = None;
+                }
+                if !has_api_versions {
+                    self.0.api_versions = None;
+                }
+                if !has_resources {
+                    self.0.resources = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.api_groups))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.api_versions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.resources))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "Rule",
+            Rule::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for Rule {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("Rule", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiGroups", &self.api_groups)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersions", &self.api_versions)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resources", &self.resources)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "Rule",
             self.api_groups.as_ref().map_or(0, |_| 1) +
@@ -118,10 +182,99 @@ impl crate::serde::Serialize for Rule {
     }
 }
 
+/// A builder of [`Rule`]. Created with [`Rule::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct RuleBuilder {
+    api_groups: Option<Vec<String>>,
+    api_versions: Option<Vec<String>>,
+    resources: Option<Vec<String>>,
+}
+
+#[cfg(feature = "builder")]
+impl Rule {
+    /// Starts building a `Rule`. Its required fields must be set before [`RuleBuilder::build`] is called.
+    pub fn builder() -> RuleBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl RuleBuilder {
+    /// Sets [`Rule::api_groups`].
+    pub fn api_groups(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.api_groups = Some(value.into());
+        self
+    }
+
+    /// Sets [`Rule::api_versions`].
+    pub fn api_versions(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.api_versions = Some(value.into());
+        self
+    }
+
+    /// Sets [`Rule::resources`].
+    pub fn resources(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.resources = Some(value.into());
+        self
+    }
+
+    /// Builds the `Rule`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<Rule, crate::MissingFieldError> {
+        Ok(Rule {
+            api_groups: self.api_groups,
+            api_versions: self.api_versions,
+            resources: self.resources,
+        })
+    }
+}
+
+/// The apply configuration of [`Rule`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RuleApplyConfiguration {
+    /// See [`Rule::api_groups`].
+    pub api_groups: Option<Vec<String>>,
+
+    /// See [`Rule::api_versions`].
+    pub api_versions: Option<Vec<String>>,
+
+    /// See [`Rule::resources`].
+    pub resources: Option<Vec<String>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for RuleApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "RuleApplyConfiguration",
+            self.api_groups.as_ref().map_or(0, |_| 1) +
+            self.api_versions.as_ref().map_or(0, |_| 1) +
+            self.resources.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.api_groups {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiGroups", value)?;
+        }
+        if let Some(value) = &self.api_versions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersions", value)?;
+        }
+        if let Some(value) = &self.resources {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resources", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Rule {
     fn schema_name() -> String {
-        "io.k8s.api.admissionregistration.v1alpha1.Rule".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::admissionregistration::v1alpha1::Rule".to_owned()
+        }
+        else {
+            "io.k8s.api.admissionregistration.v1alpha1.Rule".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -132,8 +285,9 @@ impl crate::schemars::JsonSchema for Rule {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "apiGroups".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -152,8 +306,8 @@ impl crate::schemars::JsonSchema for Rule {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "apiVersions".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -172,8 +326,8 @@ impl crate::schemars::JsonSchema for Rule {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "resources".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -192,11 +346,23 @@ impl crate::schemars::JsonSchema for Rule {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for Rule {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(Rule {
+            api_groups: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            api_versions: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            resources: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
@@ -1,20 +1,44 @@
 
 mod mutating_webhook_configuration;
 pub use self::mutating_webhook_configuration::MutatingWebhookConfiguration;
+#[cfg(feature = "builder")]
+pub use self::mutating_webhook_configuration::MutatingWebhookConfigurationBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::mutating_webhook_configuration::MutatingWebhookConfigurationApplyConfiguration;
 #[cfg(feature = "api")] pub use self::mutating_webhook_configuration::{ReadMutatingWebhookConfigurationOptional, ReadMutatingWebhookConfigurationResponse};
 
 mod rule_with_operations;
 pub use self::rule_with_operations::RuleWithOperations;
+#[cfg(feature = "builder")]
+pub use self::rule_with_operations::RuleWithOperationsBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::rule_with_operations::RuleWithOperationsApplyConfiguration;
 
 mod service_reference;
 pub use self::service_reference::ServiceReference;
+#[cfg(feature = "builder")]
+pub use self::service_reference::ServiceReferenceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::service_reference::ServiceReferenceApplyConfiguration;
 
 mod validating_webhook_configuration;
 pub use self::validating_webhook_configuration::ValidatingWebhookConfiguration;
+#[cfg(feature = "builder")]
+pub use self::validating_webhook_configuration::ValidatingWebhookConfigurationBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::validating_webhook_configuration::ValidatingWebhookConfigurationApplyConfiguration;
 #[cfg(feature = "api")] pub use self::validating_webhook_configuration::{ReadValidatingWebhookConfigurationOptional, ReadValidatingWebhookConfigurationResponse};
 
 mod webhook;
 pub use self::webhook::Webhook;
+#[cfg(feature = "builder")]
+pub use self::webhook::WebhookBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::webhook::WebhookApplyConfiguration;
 
 mod webhook_client_config;
 pub use self::webhook_client_config::WebhookClientConfig;
+#[cfg(feature = "builder")]
+pub use self::webhook_client_config::WebhookClientConfigBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::webhook_client_config::WebhookClientConfigApplyConfiguration;
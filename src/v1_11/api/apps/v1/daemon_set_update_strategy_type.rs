@@ -0,0 +1,151 @@
+// Generated from definition io.k8s.api.apps.v1.DaemonSetUpdateStrategyType
+
+/// DaemonSetUpdateStrategyType is the type of a daemon set's update strategy.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum DaemonSetUpdateStrategyType {
+    /// `OnDelete`
+    OnDelete,
+
+    /// `RollingUpdate`
+    RollingUpdate,
+
+    /// A value that is not one of the values known to this version of the crate, such as one that was added in a newer version of Kubernetes.
+    Other(String),
+}
+
+impl DaemonSetUpdateStrategyType {
+    /// The values known to this version of the crate, in the order of the variants.
+    pub const VALUES: &'static [&'static str] = &["OnDelete", "RollingUpdate"];
+
+    /// The value as it is serialized.
+    pub fn as_str(&self) -> &str {
+        match self {
+            DaemonSetUpdateStrategyType::OnDelete => "OnDelete",
+            DaemonSetUpdateStrategyType::RollingUpdate => "RollingUpdate",
+            DaemonSetUpdateStrategyType::Other(value) => value,
+        }
+    }
+}
+
+impl AsRef<str> for DaemonSetUpdateStrategyType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Default for DaemonSetUpdateStrategyType {
+    fn default() -> Self {
+        DaemonSetUpdateStrategyType::Other(String::new())
+    }
+}
+
+impl std::fmt::Display for DaemonSetUpdateStrategyType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for DaemonSetUpdateStrategyType {
+    fn from(value: &str) -> Self {
+        match value {
+            "OnDelete" => DaemonSetUpdateStrategyType::OnDelete,
+            "RollingUpdate" => DaemonSetUpdateStrategyType::RollingUpdate,
+            value => DaemonSetUpdateStrategyType::Other(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DaemonSetUpdateStrategyType {
+    fn from(value: String) -> Self {
+        match &*value {
+            "OnDelete" => DaemonSetUpdateStrategyType::OnDelete,
+            "RollingUpdate" => DaemonSetUpdateStrategyType::RollingUpdate,
+            _ => DaemonSetUpdateStrategyType::Other(value),
+        }
+    }
+}
+
+impl From<DaemonSetUpdateStrategyType> for String {
+    fn from(value: DaemonSetUpdateStrategyType) -> Self {
+        match value {
+            DaemonSetUpdateStrategyType::Other(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl PartialEq<str> for DaemonSetUpdateStrategyType {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for DaemonSetUpdateStrategyType {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<'de> crate::serde::Deserialize<'de> for DaemonSetUpdateStrategyType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl crate::serde::de::Visitor<'_> for Visitor {
+            type Value = DaemonSetUpdateStrategyType;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("DaemonSetUpdateStrategyType")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
+                Ok(v.into())
+            }
+
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E> where E: crate::serde::de::Error {
+                Ok(v.into())
+            }
+        }
+
+        deserializer.deserialize_string(Visitor)
+    }
+}
+
+impl crate::serde::Serialize for DaemonSetUpdateStrategyType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for DaemonSetUpdateStrategyType {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "api::apps::v1::DaemonSetUpdateStrategyType".to_owned()
+        }
+        else {
+            "io.k8s.api.apps.v1.DaemonSetUpdateStrategyType".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("DaemonSetUpdateStrategyType is the type of a daemon set's update strategy.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for DaemonSetUpdateStrategyType {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        Ok(match u.int_in_range(0..=2_u8)? {
+            0 => DaemonSetUpdateStrategyType::OnDelete,
+            1 => DaemonSetUpdateStrategyType::RollingUpdate,
+            _ => DaemonSetUpdateStrategyType::from(u.arbitrary::<String>()?),
+        })
+    }
+}
@@ -1,48 +1,42 @@
 // Generated from definition io.k8s.api.apps.v1.DeploymentStrategy
 
 /// DeploymentStrategy describes how to replace existing pods with new ones.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct DeploymentStrategy {
     /// Rolling update config params. Present only if DeploymentStrategyType = RollingUpdate.
     pub rolling_update: Option<crate::api::apps::v1::RollingUpdateDeployment>,
 
     /// Type of deployment. Can be "Recreate" or "RollingUpdate". Default is RollingUpdate.
-    pub type_: Option<String>,
+    pub type_: Option<crate::api::apps::v1::DeploymentStrategyType>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for DeploymentStrategy {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_rolling_update,
-            Key_type_,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl DeploymentStrategy {
+    /// Returns this value with [`DeploymentStrategy::rolling_update`] set to the given value.
+    #[must_use]
+    pub fn with_rolling_update(mut self, value: impl Into<crate::api::apps::v1::RollingUpdateDeployment>) -> Self {
+        self.rolling_update = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`DeploymentStrategy::type_`] set to the given value.
+    #[must_use]
+    pub fn with_type(mut self, value: impl Into<crate::api::apps::v1::DeploymentStrategyType>) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "rollingUpdate" => Field::Key_rolling_update,
-                            "type" => Field::Key_type_,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for DeploymentStrategy {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl DeploymentStrategy {
+    const FIELDS: &'static [&'static str] = &[
+        "rollingUpdate",
+        "type",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for DeploymentStrategy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -54,13 +48,13 @@ impl<'de> crate::serde::Deserialize<'de> for DeploymentStrategy {
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
                 let mut value_rolling_update: Option<crate::api::apps::v1::RollingUpdateDeployment> = None;
-                let mut value_type_: Option<String> = None;
+                let mut value_type_: Option<crate::api::apps::v1::DeploymentStrategyType> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("DeploymentStrategy", DeploymentStrategy::FIELDS))? {
                     match key {
-                        Field::Key_rolling_update => value_rolling_update = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_type_ => value_type_ = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_rolling_update = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_type_ = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -69,21 +63,78 @@ impl<'de> crate::serde::Deserialize<'de> for DeploymentStrategy {
                     type_: value_type_,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(DeploymentStrategy {
+                    rolling_update: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    type_: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "DeploymentStrategy",
-            &[
-                "rollingUpdate",
-                "type",
-            ],
+            DeploymentStrategy::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, DeploymentStrategy> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("DeploymentStrategy")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_rolling_update = false;
+                let mut has_type_ = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("DeploymentStrategy", DeploymentStrategy::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.rolling_update))?; has_rolling_update = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.type_))?; has_type_ = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_rolling_update {
+                    self.0.rolling_update = None;
+                }
+                if !has_type_ {
+                    self.0.type_ = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.rolling_update))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.type_))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "DeploymentStrategy",
+            DeploymentStrategy::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for DeploymentStrategy {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("DeploymentStrategy", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rollingUpdate", &self.rolling_update)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "type", &self.type_)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "DeploymentStrategy",
             self.rolling_update.as_ref().map_or(0, |_| 1) +
@@ -99,10 +150,84 @@ impl crate::serde::Serialize for DeploymentStrategy {
     }
 }
 
+/// A builder of [`DeploymentStrategy`]. Created with [`DeploymentStrategy::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct DeploymentStrategyBuilder {
+    rolling_update: Option<crate::api::apps::v1::RollingUpdateDeployment>,
+    type_: Option<crate::api::apps::v1::DeploymentStrategyType>,
+}
+
+#[cfg(feature = "builder")]
+impl DeploymentStrategy {
+    /// Starts building a `DeploymentStrategy`. Its required fields must be set before [`DeploymentStrategyBuilder::build`] is called.
+    pub fn builder() -> DeploymentStrategyBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl DeploymentStrategyBuilder {
+    /// Sets [`DeploymentStrategy::rolling_update`].
+    pub fn rolling_update(mut self, value: impl Into<crate::api::apps::v1::RollingUpdateDeployment>) -> Self {
+        self.rolling_update = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeploymentStrategy::type_`].
+    pub fn type_(mut self, value: impl Into<crate::api::apps::v1::DeploymentStrategyType>) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeploymentStrategy`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<DeploymentStrategy, crate::MissingFieldError> {
+        Ok(DeploymentStrategy {
+            rolling_update: self.rolling_update,
+            type_: self.type_,
+        })
+    }
+}
+
+/// The apply configuration of [`DeploymentStrategy`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DeploymentStrategyApplyConfiguration {
+    /// See [`DeploymentStrategy::rolling_update`].
+    pub rolling_update: Option<crate::api::apps::v1::RollingUpdateDeploymentApplyConfiguration>,
+
+    /// See [`DeploymentStrategy::type_`].
+    pub type_: Option<crate::api::apps::v1::DeploymentStrategyType>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for DeploymentStrategyApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "DeploymentStrategyApplyConfiguration",
+            self.rolling_update.as_ref().map_or(0, |_| 1) +
+            self.type_.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.rolling_update {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rollingUpdate", value)?;
+        }
+        if let Some(value) = &self.type_ {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "type", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DeploymentStrategy {
     fn schema_name() -> String {
-        "io.k8s.api.apps.v1.DeploymentStrategy".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::apps::v1::DeploymentStrategy".to_owned()
+        }
+        else {
+            "io.k8s.api.apps.v1.DeploymentStrategy".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -113,8 +238,9 @@ impl crate::schemars::JsonSchema for DeploymentStrategy {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "rollingUpdate".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::apps::v1::RollingUpdateDeployment>().into_object();
@@ -124,22 +250,33 @@ impl crate::schemars::JsonSchema for DeploymentStrategy {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "type".to_owned(),
-                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
-                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::api::apps::v1::DeploymentStrategyType>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
                                 description: Some("Type of deployment. Can be \"Recreate\" or \"RollingUpdate\". Default is RollingUpdate.".to_owned()),
                                 ..Default::default()
-                            })),
-                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
-                            ..Default::default()
-                        }),
-                    ),
-                ]).collect(),
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for DeploymentStrategy {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(DeploymentStrategy {
+            rolling_update: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            type_: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -0,0 +1,151 @@
+// Generated from definition io.k8s.api.apps.v1.DeploymentStrategyType
+
+/// DeploymentStrategyType is the type of a deployment's strategy.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum DeploymentStrategyType {
+    /// `Recreate`
+    Recreate,
+
+    /// `RollingUpdate`
+    RollingUpdate,
+
+    /// A value that is not one of the values known to this version of the crate, such as one that was added in a newer version of Kubernetes.
+    Other(String),
+}
+
+impl DeploymentStrategyType {
+    /// The values known to this version of the crate, in the order of the variants.
+    pub const VALUES: &'static [&'static str] = &["Recreate", "RollingUpdate"];
+
+    /// The value as it is serialized.
+    pub fn as_str(&self) -> &str {
+        match self {
+            DeploymentStrategyType::Recreate => "Recreate",
+            DeploymentStrategyType::RollingUpdate => "RollingUpdate",
+            DeploymentStrategyType::Other(value) => value,
+        }
+    }
+}
+
+impl AsRef<str> for DeploymentStrategyType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Default for DeploymentStrategyType {
+    fn default() -> Self {
+        DeploymentStrategyType::Other(String::new())
+    }
+}
+
+impl std::fmt::Display for DeploymentStrategyType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for DeploymentStrategyType {
+    fn from(value: &str) -> Self {
+        match value {
+            "Recreate" => DeploymentStrategyType::Recreate,
+            "RollingUpdate" => DeploymentStrategyType::RollingUpdate,
+            value => DeploymentStrategyType::Other(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentStrategyType {
+    fn from(value: String) -> Self {
+        match &*value {
+            "Recreate" => DeploymentStrategyType::Recreate,
+            "RollingUpdate" => DeploymentStrategyType::RollingUpdate,
+            _ => DeploymentStrategyType::Other(value),
+        }
+    }
+}
+
+impl From<DeploymentStrategyType> for String {
+    fn from(value: DeploymentStrategyType) -> Self {
+        match value {
+            DeploymentStrategyType::Other(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl PartialEq<str> for DeploymentStrategyType {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for DeploymentStrategyType {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<'de> crate::serde::Deserialize<'de> for DeploymentStrategyType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl crate::serde::de::Visitor<'_> for Visitor {
+            type Value = DeploymentStrategyType;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("DeploymentStrategyType")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
+                Ok(v.into())
+            }
+
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E> where E: crate::serde::de::Error {
+                Ok(v.into())
+            }
+        }
+
+        deserializer.deserialize_string(Visitor)
+    }
+}
+
+impl crate::serde::Serialize for DeploymentStrategyType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for DeploymentStrategyType {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "api::apps::v1::DeploymentStrategyType".to_owned()
+        }
+        else {
+            "io.k8s.api.apps.v1.DeploymentStrategyType".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("DeploymentStrategyType is the type of a deployment's strategy.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for DeploymentStrategyType {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        Ok(match u.int_in_range(0..=2_u8)? {
+            0 => DeploymentStrategyType::Recreate,
+            1 => DeploymentStrategyType::RollingUpdate,
+            _ => DeploymentStrategyType::from(u.arbitrary::<String>()?),
+        })
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.apps.v1.ReplicaSetStatus
 
 /// ReplicaSetStatus represents the current status of a ReplicaSet.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ReplicaSetStatus {
     /// The number of available replicas (ready for at least minReadySeconds) for this replica set.
     pub available_replicas: Option<i32>,
@@ -22,47 +22,83 @@ pub struct ReplicaSetStatus {
     pub replicas: i32,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ReplicaSetStatus {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_available_replicas,
-            Key_conditions,
-            Key_fully_labeled_replicas,
-            Key_observed_generation,
-            Key_ready_replicas,
-            Key_replicas,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl ReplicaSetStatus {
+    /// Constructs a `ReplicaSetStatus` from its required fields. Its optional fields are unset.
+    pub fn new(replicas: impl Into<i32>) -> Self {
+        ReplicaSetStatus {
+            available_replicas: None,
+            conditions: None,
+            fully_labeled_replicas: None,
+            observed_generation: None,
+            ready_replicas: None,
+            replicas: replicas.into(),
+        }
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "availableReplicas" => Field::Key_available_replicas,
-                            "conditions" => Field::Key_conditions,
-                            "fullyLabeledReplicas" => Field::Key_fully_labeled_replicas,
-                            "observedGeneration" => Field::Key_observed_generation,
-                            "readyReplicas" => Field::Key_ready_replicas,
-                            "replicas" => Field::Key_replicas,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl ReplicaSetStatus {
+    /// Returns this value with [`ReplicaSetStatus::available_replicas`] set to the given value.
+    #[must_use]
+    pub fn with_available_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.available_replicas = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`ReplicaSetStatus::conditions`] set to the given value.
+    #[must_use]
+    pub fn with_conditions(mut self, value: impl Into<Vec<crate::api::apps::v1::ReplicaSetCondition>>) -> Self {
+        self.conditions = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ReplicaSetStatus::fully_labeled_replicas`] set to the given value.
+    #[must_use]
+    pub fn with_fully_labeled_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.fully_labeled_replicas = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ReplicaSetStatus::observed_generation`] set to the given value.
+    #[must_use]
+    pub fn with_observed_generation(mut self, value: impl Into<i64>) -> Self {
+        self.observed_generation = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ReplicaSetStatus::ready_replicas`] set to the given value.
+    #[must_use]
+    pub fn with_ready_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.ready_replicas = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ReplicaSetStatus::replicas`] set to the given value.
+    #[must_use]
+    pub fn with_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.replicas = value.into();
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for ReplicaSetStatus {
+    const FIELD_PATCH_STRATEGIES: &'static [crate::FieldPatchStrategy] = &[
+        crate::FieldPatchStrategy { field: "conditions", merge: true, retain_keys: false, merge_key: Some("type") },
+    ];
+}
 
+impl ReplicaSetStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "availableReplicas",
+        "conditions",
+        "fullyLabeledReplicas",
+        "observedGeneration",
+        "readyReplicas",
+        "replicas",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for ReplicaSetStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -80,15 +116,15 @@ impl<'de> crate::serde::Deserialize<'de> for ReplicaSetStatus {
                 let mut value_ready_replicas: Option<i32> = None;
                 let mut value_replicas: Option<i32> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ReplicaSetStatus", ReplicaSetStatus::FIELDS))? {
                     match key {
-                        Field::Key_available_replicas => value_available_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_conditions => value_conditions = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_fully_labeled_replicas => value_fully_labeled_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_observed_generation => value_observed_generation = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_ready_replicas => value_ready_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_replicas => value_replicas = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_available_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_conditions = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_fully_labeled_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_observed_generation = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_ready_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_replicas = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -98,28 +134,113 @@ impl<'de> crate::serde::Deserialize<'de> for ReplicaSetStatus {
                     fully_labeled_replicas: value_fully_labeled_replicas,
                     observed_generation: value_observed_generation,
                     ready_replicas: value_ready_replicas,
-                    replicas: value_replicas.ok_or_else(|| crate::serde::de::Error::missing_field("replicas"))?,
+                    replicas: value_replicas.ok_or_else(|| crate::serde2::missing_field("ReplicaSetStatus", "replicas", &["replicas"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ReplicaSetStatus {
+                    available_replicas: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    conditions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    fully_labeled_replicas: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    observed_generation: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    ready_replicas: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    replicas: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "ReplicaSetStatus",
-            &[
-                "availableReplicas",
-                "conditions",
-                "fullyLabeledReplicas",
-                "observedGeneration",
-                "readyReplicas",
-                "replicas",
-            ],
+            ReplicaSetStatus::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ReplicaSetStatus> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ReplicaSetStatus")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_available_replicas = false;
+                let mut has_conditions = false;
+                let mut has_fully_labeled_replicas = false;
+                let mut has_observed_generation = false;
+                let mut has_ready_replicas = false;
+                let mut has_replicas = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ReplicaSetStatus", ReplicaSetStatus::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.available_replicas))?; has_available_replicas = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.conditions))?; has_conditions = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.fully_labeled_replicas))?; has_fully_labeled_replicas = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.observed_generation))?; has_observed_generation = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.ready_replicas))?; has_ready_replicas = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.replicas))?; has_replicas = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_available_replicas {
+                    self.0.available_replicas = None;
+                }
+                if !has_conditions {
+                    self.0.conditions = None;
+                }
+                if !has_fully_labeled_replicas {
+                    self.0.fully_labeled_replicas = None;
+                }
+                if !has_observed_generation {
+                    self.0.observed_generation = None;
+                }
+                if !has_ready_replicas {
+                    self.0.ready_replicas = None;
+                }
+                if !has_replicas {
+                    return Err(crate::serde2::missing_field("ReplicaSetStatus", "replicas", &["replicas"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.available_replicas))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.conditions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.fully_labeled_replicas))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.observed_generation))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.ready_replicas))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.replicas))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ReplicaSetStatus",
+            ReplicaSetStatus::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ReplicaSetStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ReplicaSetStatus", 6)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "availableReplicas", &self.available_replicas)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conditions", &self.conditions)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fullyLabeledReplicas", &self.fully_labeled_replicas)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "observedGeneration", &self.observed_generation)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readyReplicas", &self.ready_replicas)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "replicas", &self.replicas)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ReplicaSetStatus",
             1 +
@@ -149,10 +270,144 @@ impl crate::serde::Serialize for ReplicaSetStatus {
     }
 }
 
+/// A builder of [`ReplicaSetStatus`]. Created with [`ReplicaSetStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ReplicaSetStatusBuilder {
+    available_replicas: Option<i32>,
+    conditions: Option<Vec<crate::api::apps::v1::ReplicaSetCondition>>,
+    fully_labeled_replicas: Option<i32>,
+    observed_generation: Option<i64>,
+    ready_replicas: Option<i32>,
+    replicas: Option<i32>,
+}
+
+#[cfg(feature = "builder")]
+impl ReplicaSetStatus {
+    /// Starts building a `ReplicaSetStatus`. Its required fields must be set before [`ReplicaSetStatusBuilder::build`] is called.
+    pub fn builder() -> ReplicaSetStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ReplicaSetStatusBuilder {
+    /// Sets [`ReplicaSetStatus::available_replicas`].
+    pub fn available_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.available_replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`ReplicaSetStatus::conditions`].
+    pub fn conditions(mut self, value: impl Into<Vec<crate::api::apps::v1::ReplicaSetCondition>>) -> Self {
+        self.conditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`ReplicaSetStatus::fully_labeled_replicas`].
+    pub fn fully_labeled_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.fully_labeled_replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`ReplicaSetStatus::observed_generation`].
+    pub fn observed_generation(mut self, value: impl Into<i64>) -> Self {
+        self.observed_generation = Some(value.into());
+        self
+    }
+
+    /// Sets [`ReplicaSetStatus::ready_replicas`].
+    pub fn ready_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.ready_replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`ReplicaSetStatus::replicas`].
+    pub fn replicas(mut self, value: impl Into<i32>) -> Self {
+        self.replicas = Some(value.into());
+        self
+    }
+
+    /// Builds the `ReplicaSetStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ReplicaSetStatus, crate::MissingFieldError> {
+        Ok(ReplicaSetStatus {
+            available_replicas: self.available_replicas,
+            conditions: self.conditions,
+            fully_labeled_replicas: self.fully_labeled_replicas,
+            observed_generation: self.observed_generation,
+            ready_replicas: self.ready_replicas,
+            replicas: self.replicas.ok_or_else(|| crate::MissingFieldError::new("ReplicaSetStatus", "replicas"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`ReplicaSetStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ReplicaSetStatusApplyConfiguration {
+    /// See [`ReplicaSetStatus::available_replicas`].
+    pub available_replicas: Option<i32>,
+
+    /// See [`ReplicaSetStatus::conditions`].
+    pub conditions: Option<Vec<crate::api::apps::v1::ReplicaSetConditionApplyConfiguration>>,
+
+    /// See [`ReplicaSetStatus::fully_labeled_replicas`].
+    pub fully_labeled_replicas: Option<i32>,
+
+    /// See [`ReplicaSetStatus::observed_generation`].
+    pub observed_generation: Option<i64>,
+
+    /// See [`ReplicaSetStatus::ready_replicas`].
+    pub ready_replicas: Option<i32>,
+
+    /// See [`ReplicaSetStatus::replicas`].
+    pub replicas: Option<i32>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ReplicaSetStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ReplicaSetStatusApplyConfiguration",
+            self.available_replicas.as_ref().map_or(0, |_| 1) +
+            self.conditions.as_ref().map_or(0, |_| 1) +
+            self.fully_labeled_replicas.as_ref().map_or(0, |_| 1) +
+            self.observed_generation.as_ref().map_or(0, |_| 1) +
+            self.ready_replicas.as_ref().map_or(0, |_| 1) +
+            self.replicas.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.available_replicas {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "availableReplicas", value)?;
+        }
+        if let Some(value) = &self.conditions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conditions", value)?;
+        }
+        if let Some(value) = &self.fully_labeled_replicas {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fullyLabeledReplicas", value)?;
+        }
+        if let Some(value) = &self.observed_generation {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "observedGeneration", value)?;
+        }
+        if let Some(value) = &self.ready_replicas {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readyReplicas", value)?;
+        }
+        if let Some(value) = &self.replicas {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "replicas", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ReplicaSetStatus {
     fn schema_name() -> String {
-        "io.k8s.api.apps.v1.ReplicaSetStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::apps::v1::ReplicaSetStatus".to_owned()
+        }
+        else {
+            "io.k8s.api.apps.v1.ReplicaSetStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -163,8 +418,9 @@ impl crate::schemars::JsonSchema for ReplicaSetStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "availableReplicas".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -175,8 +431,8 @@ impl crate::schemars::JsonSchema for ReplicaSetStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "conditions".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -190,8 +446,8 @@ impl crate::schemars::JsonSchema for ReplicaSetStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "fullyLabeledReplicas".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -202,8 +458,8 @@ impl crate::schemars::JsonSchema for ReplicaSetStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "observedGeneration".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -214,8 +470,8 @@ impl crate::schemars::JsonSchema for ReplicaSetStatus {
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "readyReplicas".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -226,8 +482,8 @@ impl crate::schemars::JsonSchema for ReplicaSetStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "replicas".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -238,8 +494,9 @@ impl crate::schemars::JsonSchema for ReplicaSetStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "replicas",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -249,3 +506,17 @@ impl crate::schemars::JsonSchema for ReplicaSetStatus {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ReplicaSetStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ReplicaSetStatus {
+            available_replicas: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            conditions: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            fully_labeled_replicas: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            observed_generation: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            ready_replicas: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            replicas: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.apps.v1.StatefulSetStatus
 
 /// StatefulSetStatus represents the current state of a StatefulSet.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct StatefulSetStatus {
     /// collisionCount is the count of hash collisions for the StatefulSet. The StatefulSet controller uses this field as a collision avoidance mechanism when it needs to create the name for the newest ControllerRevision.
     pub collision_count: Option<i32>,
@@ -31,53 +31,110 @@ pub struct StatefulSetStatus {
     pub updated_replicas: Option<i32>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for StatefulSetStatus {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_collision_count,
-            Key_conditions,
-            Key_current_replicas,
-            Key_current_revision,
-            Key_observed_generation,
-            Key_ready_replicas,
-            Key_replicas,
-            Key_update_revision,
-            Key_updated_replicas,
-            Other,
+impl StatefulSetStatus {
+    /// Constructs a `StatefulSetStatus` from its required fields. Its optional fields are unset.
+    pub fn new(replicas: impl Into<i32>) -> Self {
+        StatefulSetStatus {
+            collision_count: None,
+            conditions: None,
+            current_replicas: None,
+            current_revision: None,
+            observed_generation: None,
+            ready_replicas: None,
+            replicas: replicas.into(),
+            update_revision: None,
+            updated_replicas: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl StatefulSetStatus {
+    /// Returns this value with [`StatefulSetStatus::collision_count`] set to the given value.
+    #[must_use]
+    pub fn with_collision_count(mut self, value: impl Into<i32>) -> Self {
+        self.collision_count = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`StatefulSetStatus::conditions`] set to the given value.
+    #[must_use]
+    pub fn with_conditions(mut self, value: impl Into<Vec<crate::api::apps::v1::StatefulSetCondition>>) -> Self {
+        self.conditions = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`StatefulSetStatus::current_replicas`] set to the given value.
+    #[must_use]
+    pub fn with_current_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.current_replicas = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "collisionCount" => Field::Key_collision_count,
-                            "conditions" => Field::Key_conditions,
-                            "currentReplicas" => Field::Key_current_replicas,
-                            "currentRevision" => Field::Key_current_revision,
-                            "observedGeneration" => Field::Key_observed_generation,
-                            "readyReplicas" => Field::Key_ready_replicas,
-                            "replicas" => Field::Key_replicas,
-                            "updateRevision" => Field::Key_update_revision,
-                            "updatedReplicas" => Field::Key_updated_replicas,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`StatefulSetStatus::current_revision`] set to the given value.
+    #[must_use]
+    pub fn with_current_revision(mut self, value: impl Into<String>) -> Self {
+        self.current_revision = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`StatefulSetStatus::observed_generation`] set to the given value.
+    #[must_use]
+    pub fn with_observed_generation(mut self, value: impl Into<i64>) -> Self {
+        self.observed_generation = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`StatefulSetStatus::ready_replicas`] set to the given value.
+    #[must_use]
+    pub fn with_ready_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.ready_replicas = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`StatefulSetStatus::replicas`] set to the given value.
+    #[must_use]
+    pub fn with_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.replicas = value.into();
+        self
+    }
+
+    /// Returns this value with [`StatefulSetStatus::update_revision`] set to the given value.
+    #[must_use]
+    pub fn with_update_revision(mut self, value: impl Into<String>) -> Self {
+        self.update_revision = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`StatefulSetStatus::updated_replicas`] set to the given value.
+    #[must_use]
+    pub fn with_updated_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.updated_replicas = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for StatefulSetStatus {
+    const FIELD_PATCH_STRATEGIES: &'static [crate::FieldPatchStrategy] = &[
+        crate::FieldPatchStrategy { field: "conditions", merge: true, retain_keys: false, merge_key: Some("type") },
+    ];
+}
+
+impl StatefulSetStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "collisionCount",
+        "conditions",
+        "currentReplicas",
+        "currentRevision",
+        "observedGeneration",
+        "readyReplicas",
+        "replicas",
+        "updateRevision",
+        "updatedReplicas",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for StatefulSetStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -98,18 +155,18 @@ impl<'de> crate::serde::Deserialize<'de> for StatefulSetStatus {
                 let mut value_update_revision: Option<String> = None;
                 let mut value_updated_replicas: Option<i32> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("StatefulSetStatus", StatefulSetStatus::FIELDS))? {
                     match key {
-                        Field::Key_collision_count => value_collision_count = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_conditions => value_conditions = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_current_replicas => value_current_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_current_revision => value_current_revision = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_observed_generation => value_observed_generation = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_ready_replicas => value_ready_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_replicas => value_replicas = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_update_revision => value_update_revision = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_updated_replicas => value_updated_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_collision_count = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_conditions = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_current_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_current_revision = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_observed_generation = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_ready_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_replicas = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(7) => value_update_revision = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(8) => value_updated_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -120,33 +177,139 @@ impl<'de> crate::serde::Deserialize<'de> for StatefulSetStatus {
                     current_revision: value_current_revision,
                     observed_generation: value_observed_generation,
                     ready_replicas: value_ready_replicas,
-                    replicas: value_replicas.ok_or_else(|| crate::serde::de::Error::missing_field("replicas"))?,
+                    replicas: value_replicas.ok_or_else(|| crate::serde2::missing_field("StatefulSetStatus", "replicas", &["replicas"]))?,
                     update_revision: value_update_revision,
                     updated_replicas: value_updated_replicas,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(StatefulSetStatus {
+                    collision_count: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    conditions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    current_replicas: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    current_revision: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    observed_generation: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    ready_replicas: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    replicas: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    update_revision: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                    updated_replicas: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "StatefulSetStatus",
-            &[
-                "collisionCount",
-                "conditions",
-                "currentReplicas",
-                "currentRevision",
-                "observedGeneration",
-                "readyReplicas",
-                "replicas",
-                "updateRevision",
-                "updatedReplicas",
-            ],
+            StatefulSetStatus::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, StatefulSetStatus> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("StatefulSetStatus")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_collision_count = false;
+                let mut has_conditions = false;
+                let mut has_current_replicas = false;
+                let mut has_current_revision = false;
+                let mut has_observed_generation = false;
+                let mut has_ready_replicas = false;
+                let mut has_replicas = false;
+                let mut has_update_revision = false;
+                let mut has_updated_replicas = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("StatefulSetStatus", StatefulSetStatus::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.collision_count))?; has_collision_count = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.conditions))?; has_conditions = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.current_replicas))?; has_current_replicas = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.current_revision))?; has_current_revision = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.observed_generation))?; has_observed_generation = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.ready_replicas))?; has_ready_replicas = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.replicas))?; has_replicas = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.update_revision))?; has_update_revision = true; },
+                        Some(8) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.updated_replicas))?; has_updated_replicas = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_collision_count {
+                    self.0.collision_count = None;
+                }
+                if !has_conditions {
+                    self.0.conditions = None;
+                }
+                if !has_current_replicas {
+                    self.0.current_replicas = None;
+                }
+                if !has_current_revision {
+                    self.0.current_revision = None;
+                }
+                if !has_observed_generation {
+                    self.0.observed_generation = None;
+                }
+                if !has_ready_replicas {
+                    self.0.ready_replicas = None;
+                }
+                if !has_replicas {
+                    return Err(crate::serde2::missing_field("StatefulSetStatus", "replicas", &["replicas"]));
+                }
+                if !has_update_revision {
+                    self.0.update_revision = None;
+                }
+                if !has_updated_replicas {
+                    self.0.updated_replicas = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.collision_count))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.conditions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.current_replicas))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.current_revision))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.observed_generation))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.ready_replicas))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.replicas))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.update_revision))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.updated_replicas))?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "StatefulSetStatus",
+            StatefulSetStatus::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for StatefulSetStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("StatefulSetStatus", 9)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "collisionCount", &self.collision_count)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conditions", &self.conditions)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "currentReplicas", &self.current_replicas)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "currentRevision", &self.current_revision)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "observedGeneration", &self.observed_generation)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readyReplicas", &self.ready_replicas)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "replicas", &self.replicas)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "updateRevision", &self.update_revision)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "updatedReplicas", &self.updated_replicas)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "StatefulSetStatus",
             1 +
@@ -188,10 +351,189 @@ impl crate::serde::Serialize for StatefulSetStatus {
     }
 }
 
+/// A builder of [`StatefulSetStatus`]. Created with [`StatefulSetStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct StatefulSetStatusBuilder {
+    collision_count: Option<i32>,
+    conditions: Option<Vec<crate::api::apps::v1::StatefulSetCondition>>,
+    current_replicas: Option<i32>,
+    current_revision: Option<String>,
+    observed_generation: Option<i64>,
+    ready_replicas: Option<i32>,
+    replicas: Option<i32>,
+    update_revision: Option<String>,
+    updated_replicas: Option<i32>,
+}
+
+#[cfg(feature = "builder")]
+impl StatefulSetStatus {
+    /// Starts building a `StatefulSetStatus`. Its required fields must be set before [`StatefulSetStatusBuilder::build`] is called.
+    pub fn builder() -> StatefulSetStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl StatefulSetStatusBuilder {
+    /// Sets [`StatefulSetStatus::collision_count`].
+    pub fn collision_count(mut self, value: impl Into<i32>) -> Self {
+        self.collision_count = Some(value.into());
+        self
+    }
+
+    /// Sets [`StatefulSetStatus::conditions`].
+    pub fn conditions(mut self, value: impl Into<Vec<crate::api::apps::v1::StatefulSetCondition>>) -> Self {
+        self.conditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`StatefulSetStatus::current_replicas`].
+    pub fn current_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.current_replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`StatefulSetStatus::current_revision`].
+    pub fn current_revision(mut self, value: impl Into<String>) -> Self {
+        self.current_revision = Some(value.into());
+        self
+    }
+
+    /// Sets [`StatefulSetStatus::observed_generation`].
+    pub fn observed_generation(mut self, value: impl Into<i64>) -> Self {
+        self.observed_generation = Some(value.into());
+        self
+    }
+
+    /// Sets [`StatefulSetStatus::ready_replicas`].
+    pub fn ready_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.ready_replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`StatefulSetStatus::replicas`].
+    pub fn replicas(mut self, value: impl Into<i32>) -> Self {
+        self.replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`StatefulSetStatus::update_revision`].
+    pub fn update_revision(mut self, value: impl Into<String>) -> Self {
+        self.update_revision = Some(value.into());
+        self
+    }
+
+    /// Sets [`StatefulSetStatus::updated_replicas`].
+    pub fn updated_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.updated_replicas = Some(value.into());
+        self
+    }
+
+    /// Builds the `StatefulSetStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<StatefulSetStatus, crate::MissingFieldError> {
+        Ok(StatefulSetStatus {
+            collision_count: self.collision_count,
+            conditions: self.conditions,
+            current_replicas: self.current_replicas,
+            current_revision: self.current_revision,
+            observed_generation: self.observed_generation,
+            ready_replicas: self.ready_replicas,
+            replicas: self.replicas.ok_or_else(|| crate::MissingFieldError::new("StatefulSetStatus", "replicas"))?,
+            update_revision: self.update_revision,
+            updated_replicas: self.updated_replicas,
+        })
+    }
+}
+
+/// The apply configuration of [`StatefulSetStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StatefulSetStatusApplyConfiguration {
+    /// See [`StatefulSetStatus::collision_count`].
+    pub collision_count: Option<i32>,
+
+    /// See [`StatefulSetStatus::conditions`].
+    pub conditions: Option<Vec<crate::api::apps::v1::StatefulSetConditionApplyConfiguration>>,
+
+    /// See [`StatefulSetStatus::current_replicas`].
+    pub current_replicas: Option<i32>,
+
+    /// See [`StatefulSetStatus::current_revision`].
+    pub current_revision: Option<String>,
+
+    /// See [`StatefulSetStatus::observed_generation`].
+    pub observed_generation: Option<i64>,
+
+    /// See [`StatefulSetStatus::ready_replicas`].
+    pub ready_replicas: Option<i32>,
+
+    /// See [`StatefulSetStatus::replicas`].
+    pub replicas: Option<i32>,
+
+    /// See [`StatefulSetStatus::update_revision`].
+    pub update_revision: Option<String>,
+
+    /// See [`StatefulSetStatus::updated_replicas`].
+    pub updated_replicas: Option<i32>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for StatefulSetStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "StatefulSetStatusApplyConfiguration",
+            self.collision_count.as_ref().map_or(0, |_| 1) +
+            self.conditions.as_ref().map_or(0, |_| 1) +
+            self.current_replicas.as_ref().map_or(0, |_| 1) +
+            self.current_revision.as_ref().map_or(0, |_| 1) +
+            self.observed_generation.as_ref().map_or(0, |_| 1) +
+            self.ready_replicas.as_ref().map_or(0, |_| 1) +
+            self.replicas.as_ref().map_or(0, |_| 1) +
+            self.update_revision.as_ref().map_or(0, |_| 1) +
+            self.updated_replicas.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.collision_count {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "collisionCount", value)?;
+        }
+        if let Some(value) = &self.conditions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conditions", value)?;
+        }
+        if let Some(value) = &self.current_replicas {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "currentReplicas", value)?;
+        }
+        if let Some(value) = &self.current_revision {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "currentRevision", value)?;
+        }
+        if let Some(value) = &self.observed_generation {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "observedGeneration", value)?;
+        }
+        if let Some(value) = &self.ready_replicas {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readyReplicas", value)?;
+        }
+        if let Some(value) = &self.replicas {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "replicas", value)?;
+        }
+        if let Some(value) = &self.update_revision {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "updateRevision", value)?;
+        }
+        if let Some(value) = &self.updated_replicas {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "updatedReplicas", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for StatefulSetStatus {
     fn schema_name() -> String {
-        "io.k8s.api.apps.v1.StatefulSetStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::apps::v1::StatefulSetStatus".to_owned()
+        }
+        else {
+            "io.k8s.api.apps.v1.StatefulSetStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -202,8 +544,9 @@ impl crate::schemars::JsonSchema for StatefulSetStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "collisionCount".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -214,8 +557,8 @@ impl crate::schemars::JsonSchema for StatefulSetStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "conditions".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -229,8 +572,8 @@ impl crate::schemars::JsonSchema for StatefulSetStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "currentReplicas".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -241,8 +584,8 @@ impl crate::schemars::JsonSchema for StatefulSetStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "currentRevision".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -252,8 +595,8 @@ impl crate::schemars::JsonSchema for StatefulSetStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "observedGeneration".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -264,8 +607,8 @@ impl crate::schemars::JsonSchema for StatefulSetStatus {
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "readyReplicas".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -276,8 +619,8 @@ impl crate::schemars::JsonSchema for StatefulSetStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "replicas".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -288,8 +631,8 @@ impl crate::schemars::JsonSchema for StatefulSetStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "updateRevision".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -299,8 +642,8 @@ impl crate::schemars::JsonSchema for StatefulSetStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "updatedReplicas".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -311,8 +654,9 @@ impl crate::schemars::JsonSchema for StatefulSetStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "replicas",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -322,3 +666,20 @@ impl crate::schemars::JsonSchema for StatefulSetStatus {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for StatefulSetStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(StatefulSetStatus {
+            collision_count: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            conditions: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            current_replicas: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            current_revision: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            observed_generation: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            ready_replicas: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            replicas: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            update_revision: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            updated_replicas: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
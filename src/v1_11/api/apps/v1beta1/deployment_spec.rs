@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.apps.v1beta1.DeploymentSpec
 
 /// DeploymentSpec is the specification of the desired behavior of the Deployment.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct DeploymentSpec {
     /// Minimum number of seconds for which a newly created pod should be ready without any of its container crashing, for it to be considered available. Defaults to 0 (pod will be considered available as soon as it is ready)
     pub min_ready_seconds: Option<i32>,
@@ -19,6 +19,7 @@ pub struct DeploymentSpec {
     pub revision_history_limit: Option<i32>,
 
     /// DEPRECATED. The config this deployment is rolling back to. Will be cleared after rollback is done.
+    #[deprecated]
     pub rollback_to: Option<crate::api::apps::v1beta1::RollbackConfig>,
 
     /// Label selector for pods. Existing ReplicaSets whose pods are selected by this will be the ones affected by this deployment.
@@ -31,53 +32,110 @@ pub struct DeploymentSpec {
     pub template: crate::api::core::v1::PodTemplateSpec,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for DeploymentSpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_min_ready_seconds,
-            Key_paused,
-            Key_progress_deadline_seconds,
-            Key_replicas,
-            Key_revision_history_limit,
-            Key_rollback_to,
-            Key_selector,
-            Key_strategy,
-            Key_template,
-            Other,
+impl DeploymentSpec {
+    /// Constructs a `DeploymentSpec` from its required fields. Its optional fields are unset.
+    pub fn new(template: impl Into<crate::api::core::v1::PodTemplateSpec>) -> Self {
+        DeploymentSpec {
+            min_ready_seconds: None,
+            paused: None,
+            progress_deadline_seconds: None,
+            replicas: None,
+            revision_history_limit: None,
+            rollback_to: None,
+            selector: None,
+            strategy: None,
+            template: template.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl DeploymentSpec {
+    /// Returns this value with [`DeploymentSpec::min_ready_seconds`] set to the given value.
+    #[must_use]
+    pub fn with_min_ready_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.min_ready_seconds = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`DeploymentSpec::paused`] set to the given value.
+    #[must_use]
+    pub fn with_paused(mut self, value: impl Into<bool>) -> Self {
+        self.paused = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`DeploymentSpec::progress_deadline_seconds`] set to the given value.
+    #[must_use]
+    pub fn with_progress_deadline_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.progress_deadline_seconds = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "minReadySeconds" => Field::Key_min_ready_seconds,
-                            "paused" => Field::Key_paused,
-                            "progressDeadlineSeconds" => Field::Key_progress_deadline_seconds,
-                            "replicas" => Field::Key_replicas,
-                            "revisionHistoryLimit" => Field::Key_revision_history_limit,
-                            "rollbackTo" => Field::Key_rollback_to,
-                            "selector" => Field::Key_selector,
-                            "strategy" => Field::Key_strategy,
-                            "template" => Field::Key_template,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`DeploymentSpec::replicas`] set to the given value.
+    #[must_use]
+    pub fn with_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.replicas = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`DeploymentSpec::revision_history_limit`] set to the given value.
+    #[must_use]
+    pub fn with_revision_history_limit(mut self, value: impl Into<i32>) -> Self {
+        self.revision_history_limit = Some(value.into());
+        self
+    }
 
+    /// Returns this value with [`DeploymentSpec::rollback_to`] set to the given value.
+    #[must_use]
+    pub fn with_rollback_to(mut self, value: impl Into<crate::api::apps::v1beta1::RollbackConfig>) -> Self {
+        self.rollback_to = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`DeploymentSpec::selector`] set to the given value.
+    #[must_use]
+    pub fn with_selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.selector = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`DeploymentSpec::strategy`] set to the given value.
+    #[must_use]
+    pub fn with_strategy(mut self, value: impl Into<crate::api::apps::v1beta1::DeploymentStrategy>) -> Self {
+        self.strategy = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`DeploymentSpec::template`] set to the given value.
+    #[must_use]
+    pub fn with_template(mut self, value: impl Into<crate::api::core::v1::PodTemplateSpec>) -> Self {
+        self.template = value.into();
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for DeploymentSpec {
+    const FIELD_PATCH_STRATEGIES: &'static [crate::FieldPatchStrategy] = &[
+        crate::FieldPatchStrategy { field: "strategy", merge: false, retain_keys: true, merge_key: None },
+    ];
+}
+
+impl DeploymentSpec {
+    const FIELDS: &'static [&'static str] = &[
+        "minReadySeconds",
+        "paused",
+        "progressDeadlineSeconds",
+        "replicas",
+        "revisionHistoryLimit",
+        "rollbackTo",
+        "selector",
+        "strategy",
+        "template",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for DeploymentSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -98,18 +156,18 @@ impl<'de> crate::serde::Deserialize<'de> for DeploymentSpec {
                 let mut value_strategy: Option<crate::api::apps::v1beta1::DeploymentStrategy> = None;
                 let mut value_template: Option<crate::api::core::v1::PodTemplateSpec> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("DeploymentSpec", DeploymentSpec::FIELDS))? {
                     match key {
-                        Field::Key_min_ready_seconds => value_min_ready_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_paused => value_paused = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_progress_deadline_seconds => value_progress_deadline_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_replicas => value_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_revision_history_limit => value_revision_history_limit = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_rollback_to => value_rollback_to = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_selector => value_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_strategy => value_strategy = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_template => value_template = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_min_ready_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_paused = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_progress_deadline_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_revision_history_limit = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_rollback_to = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(7) => value_strategy = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(8) => value_template = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -122,31 +180,137 @@ impl<'de> crate::serde::Deserialize<'de> for DeploymentSpec {
                     rollback_to: value_rollback_to,
                     selector: value_selector,
                     strategy: value_strategy,
-                    template: value_template.ok_or_else(|| crate::serde::de::Error::missing_field("template"))?,
+                    template: value_template.ok_or_else(|| crate::serde2::missing_field("DeploymentSpec", "template", &["template"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(DeploymentSpec {
+                    min_ready_seconds: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    paused: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    progress_deadline_seconds: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    replicas: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    revision_history_limit: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    rollback_to: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    selector: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    strategy: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                    template: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "DeploymentSpec",
-            &[
-                "minReadySeconds",
-                "paused",
-                "progressDeadlineSeconds",
-                "replicas",
-                "revisionHistoryLimit",
-                "rollbackTo",
-                "selector",
-                "strategy",
-                "template",
-            ],
+            DeploymentSpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, DeploymentSpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("DeploymentSpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_min_ready_seconds = false;
+                let mut has_paused = false;
+                let mut has_progress_deadline_seconds = false;
+                let mut has_replicas = false;
+                let mut has_revision_history_limit = false;
+                let mut has_rollback_to = false;
+                let mut has_selector = false;
+                let mut has_strategy = false;
+                let mut has_template = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("DeploymentSpec", DeploymentSpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.min_ready_seconds))?; has_min_ready_seconds = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.paused))?; has_paused = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.progress_deadline_seconds))?; has_progress_deadline_seconds = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.replicas))?; has_replicas = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.revision_history_limit))?; has_revision_history_limit = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.rollback_to))?; has_rollback_to = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.selector))?; has_selector = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.strategy))?; has_strategy = true; },
+                        Some(8) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.template))?; has_template = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_min_ready_seconds {
+                    self.0.min_ready_seconds = None;
+                }
+                if !has_paused {
+                    self.0.paused = None;
+                }
+                if !has_progress_deadline_seconds {
+                    self.0.progress_deadline_seconds = None;
+                }
+                if !has_replicas {
+                    self.0.replicas = None;
+                }
+                if !has_revision_history_limit {
+                    self.0.revision_history_limit = None;
+                }
+                if !has_rollback_to {
+                    self.0.rollback_to = None;
+                }
+                if !has_selector {
+                    self.0.selector = None;
+                }
+                if !has_strategy {
+                    self.0.strategy = None;
+                }
+                if !has_template {
+                    return Err(crate::serde2::missing_field("DeploymentSpec", "template", &["template"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.min_ready_seconds))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.paused))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.progress_deadline_seconds))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.replicas))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.revision_history_limit))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.rollback_to))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.selector))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.strategy))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.template))?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "DeploymentSpec",
+            DeploymentSpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for DeploymentSpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("DeploymentSpec", 9)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "minReadySeconds", &self.min_ready_seconds)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "paused", &self.paused)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "progressDeadlineSeconds", &self.progress_deadline_seconds)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "replicas", &self.replicas)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "revisionHistoryLimit", &self.revision_history_limit)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rollbackTo", &self.rollback_to)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selector", &self.selector)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "strategy", &self.strategy)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "template", &self.template)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "DeploymentSpec",
             1 +
@@ -188,10 +352,189 @@ impl crate::serde::Serialize for DeploymentSpec {
     }
 }
 
+/// A builder of [`DeploymentSpec`]. Created with [`DeploymentSpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct DeploymentSpecBuilder {
+    min_ready_seconds: Option<i32>,
+    paused: Option<bool>,
+    progress_deadline_seconds: Option<i32>,
+    replicas: Option<i32>,
+    revision_history_limit: Option<i32>,
+    rollback_to: Option<crate::api::apps::v1beta1::RollbackConfig>,
+    selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>,
+    strategy: Option<crate::api::apps::v1beta1::DeploymentStrategy>,
+    template: Option<crate::api::core::v1::PodTemplateSpec>,
+}
+
+#[cfg(feature = "builder")]
+impl DeploymentSpec {
+    /// Starts building a `DeploymentSpec`. Its required fields must be set before [`DeploymentSpecBuilder::build`] is called.
+    pub fn builder() -> DeploymentSpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl DeploymentSpecBuilder {
+    /// Sets [`DeploymentSpec::min_ready_seconds`].
+    pub fn min_ready_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.min_ready_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeploymentSpec::paused`].
+    pub fn paused(mut self, value: impl Into<bool>) -> Self {
+        self.paused = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeploymentSpec::progress_deadline_seconds`].
+    pub fn progress_deadline_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.progress_deadline_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeploymentSpec::replicas`].
+    pub fn replicas(mut self, value: impl Into<i32>) -> Self {
+        self.replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeploymentSpec::revision_history_limit`].
+    pub fn revision_history_limit(mut self, value: impl Into<i32>) -> Self {
+        self.revision_history_limit = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeploymentSpec::rollback_to`].
+    pub fn rollback_to(mut self, value: impl Into<crate::api::apps::v1beta1::RollbackConfig>) -> Self {
+        self.rollback_to = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeploymentSpec::selector`].
+    pub fn selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeploymentSpec::strategy`].
+    pub fn strategy(mut self, value: impl Into<crate::api::apps::v1beta1::DeploymentStrategy>) -> Self {
+        self.strategy = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeploymentSpec::template`].
+    pub fn template(mut self, value: impl Into<crate::api::core::v1::PodTemplateSpec>) -> Self {
+        self.template = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeploymentSpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<DeploymentSpec, crate::MissingFieldError> {
+        Ok(DeploymentSpec {
+            min_ready_seconds: self.min_ready_seconds,
+            paused: self.paused,
+            progress_deadline_seconds: self.progress_deadline_seconds,
+            replicas: self.replicas,
+            revision_history_limit: self.revision_history_limit,
+            rollback_to: self.rollback_to,
+            selector: self.selector,
+            strategy: self.strategy,
+            template: self.template.ok_or_else(|| crate::MissingFieldError::new("DeploymentSpec", "template"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`DeploymentSpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DeploymentSpecApplyConfiguration {
+    /// See [`DeploymentSpec::min_ready_seconds`].
+    pub min_ready_seconds: Option<i32>,
+
+    /// See [`DeploymentSpec::paused`].
+    pub paused: Option<bool>,
+
+    /// See [`DeploymentSpec::progress_deadline_seconds`].
+    pub progress_deadline_seconds: Option<i32>,
+
+    /// See [`DeploymentSpec::replicas`].
+    pub replicas: Option<i32>,
+
+    /// See [`DeploymentSpec::revision_history_limit`].
+    pub revision_history_limit: Option<i32>,
+
+    /// See [`DeploymentSpec::rollback_to`].
+    pub rollback_to: Option<crate::api::apps::v1beta1::RollbackConfigApplyConfiguration>,
+
+    /// See [`DeploymentSpec::selector`].
+    pub selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorApplyConfiguration>,
+
+    /// See [`DeploymentSpec::strategy`].
+    pub strategy: Option<crate::api::apps::v1beta1::DeploymentStrategyApplyConfiguration>,
+
+    /// See [`DeploymentSpec::template`].
+    pub template: Option<crate::api::core::v1::PodTemplateSpecApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for DeploymentSpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "DeploymentSpecApplyConfiguration",
+            self.min_ready_seconds.as_ref().map_or(0, |_| 1) +
+            self.paused.as_ref().map_or(0, |_| 1) +
+            self.progress_deadline_seconds.as_ref().map_or(0, |_| 1) +
+            self.replicas.as_ref().map_or(0, |_| 1) +
+            self.revision_history_limit.as_ref().map_or(0, |_| 1) +
+            self.rollback_to.as_ref().map_or(0, |_| 1) +
+            self.selector.as_ref().map_or(0, |_| 1) +
+            self.strategy.as_ref().map_or(0, |_| 1) +
+            self.template.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.min_ready_seconds {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "minReadySeconds", value)?;
+        }
+        if let Some(value) = &self.paused {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "paused", value)?;
+        }
+        if let Some(value) = &self.progress_deadline_seconds {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "progressDeadlineSeconds", value)?;
+        }
+        if let Some(value) = &self.replicas {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "replicas", value)?;
+        }
+        if let Some(value) = &self.revision_history_limit {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "revisionHistoryLimit", value)?;
+        }
+        if let Some(value) = &self.rollback_to {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rollbackTo", value)?;
+        }
+        if let Some(value) = &self.selector {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selector", value)?;
+        }
+        if let Some(value) = &self.strategy {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "strategy", value)?;
+        }
+        if let Some(value) = &self.template {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "template", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DeploymentSpec {
     fn schema_name() -> String {
-        "io.k8s.api.apps.v1beta1.DeploymentSpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::apps::v1beta1::DeploymentSpec".to_owned()
+        }
+        else {
+            "io.k8s.api.apps.v1beta1.DeploymentSpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -202,8 +545,9 @@ impl crate::schemars::JsonSchema for DeploymentSpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "minReadySeconds".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -214,8 +558,8 @@ impl crate::schemars::JsonSchema for DeploymentSpec {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "paused".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -225,8 +569,8 @@ impl crate::schemars::JsonSchema for DeploymentSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "progressDeadlineSeconds".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -237,8 +581,8 @@ impl crate::schemars::JsonSchema for DeploymentSpec {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "replicas".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -249,8 +593,8 @@ impl crate::schemars::JsonSchema for DeploymentSpec {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "revisionHistoryLimit".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -261,8 +605,8 @@ impl crate::schemars::JsonSchema for DeploymentSpec {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "rollbackTo".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::apps::v1beta1::RollbackConfig>().into_object();
@@ -272,8 +616,8 @@ impl crate::schemars::JsonSchema for DeploymentSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "selector".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>().into_object();
@@ -283,8 +627,8 @@ impl crate::schemars::JsonSchema for DeploymentSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "strategy".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::apps::v1beta1::DeploymentStrategy>().into_object();
@@ -294,8 +638,8 @@ impl crate::schemars::JsonSchema for DeploymentSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "template".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::PodTemplateSpec>().into_object();
@@ -305,8 +649,9 @@ impl crate::schemars::JsonSchema for DeploymentSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "template",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -316,3 +661,20 @@ impl crate::schemars::JsonSchema for DeploymentSpec {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for DeploymentSpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(DeploymentSpec {
+            min_ready_seconds: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            paused: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            progress_deadline_seconds: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            replicas: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            revision_history_limit: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            rollback_to: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            selector: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            strategy: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            template: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.apps.v1beta1.DeploymentStatus
 
 /// DeploymentStatus is the most recently observed status of the Deployment.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct DeploymentStatus {
     /// Total number of available pods (ready for at least minReadySeconds) targeted by this deployment.
     pub available_replicas: Option<i32>,
@@ -28,51 +28,85 @@ pub struct DeploymentStatus {
     pub updated_replicas: Option<i32>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for DeploymentStatus {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_available_replicas,
-            Key_collision_count,
-            Key_conditions,
-            Key_observed_generation,
-            Key_ready_replicas,
-            Key_replicas,
-            Key_unavailable_replicas,
-            Key_updated_replicas,
-            Other,
-        }
+impl DeploymentStatus {
+    /// Returns this value with [`DeploymentStatus::available_replicas`] set to the given value.
+    #[must_use]
+    pub fn with_available_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.available_replicas = Some(value.into());
+        self
+    }
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+    /// Returns this value with [`DeploymentStatus::collision_count`] set to the given value.
+    #[must_use]
+    pub fn with_collision_count(mut self, value: impl Into<i32>) -> Self {
+        self.collision_count = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`DeploymentStatus::conditions`] set to the given value.
+    #[must_use]
+    pub fn with_conditions(mut self, value: impl Into<Vec<crate::api::apps::v1beta1::DeploymentCondition>>) -> Self {
+        self.conditions = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`DeploymentStatus::observed_generation`] set to the given value.
+    #[must_use]
+    pub fn with_observed_generation(mut self, value: impl Into<i64>) -> Self {
+        self.observed_generation = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "availableReplicas" => Field::Key_available_replicas,
-                            "collisionCount" => Field::Key_collision_count,
-                            "conditions" => Field::Key_conditions,
-                            "observedGeneration" => Field::Key_observed_generation,
-                            "readyReplicas" => Field::Key_ready_replicas,
-                            "replicas" => Field::Key_replicas,
-                            "unavailableReplicas" => Field::Key_unavailable_replicas,
-                            "updatedReplicas" => Field::Key_updated_replicas,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`DeploymentStatus::ready_replicas`] set to the given value.
+    #[must_use]
+    pub fn with_ready_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.ready_replicas = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`DeploymentStatus::replicas`] set to the given value.
+    #[must_use]
+    pub fn with_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.replicas = Some(value.into());
+        self
+    }
 
+    /// Returns this value with [`DeploymentStatus::unavailable_replicas`] set to the given value.
+    #[must_use]
+    pub fn with_unavailable_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.unavailable_replicas = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`DeploymentStatus::updated_replicas`] set to the given value.
+    #[must_use]
+    pub fn with_updated_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.updated_replicas = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for DeploymentStatus {
+    const FIELD_PATCH_STRATEGIES: &'static [crate::FieldPatchStrategy] = &[
+        crate::FieldPatchStrategy { field: "conditions", merge: true, retain_keys: false, merge_key: Some("type") },
+    ];
+}
+
+impl DeploymentStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "availableReplicas",
+        "collisionCount",
+        "conditions",
+        "observedGeneration",
+        "readyReplicas",
+        "replicas",
+        "unavailableReplicas",
+        "updatedReplicas",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for DeploymentStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -92,17 +126,17 @@ impl<'de> crate::serde::Deserialize<'de> for DeploymentStatus {
                 let mut value_unavailable_replicas: Option<i32> = None;
                 let mut value_updated_replicas: Option<i32> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("DeploymentStatus", DeploymentStatus::FIELDS))? {
                     match key {
-                        Field::Key_available_replicas => value_available_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_collision_count => value_collision_count = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_conditions => value_conditions = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_observed_generation => value_observed_generation = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_ready_replicas => value_ready_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_replicas => value_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_unavailable_replicas => value_unavailable_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_updated_replicas => value_updated_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_available_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_collision_count = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_conditions = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_observed_generation = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_ready_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_unavailable_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(7) => value_updated_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -117,27 +151,126 @@ impl<'de> crate::serde::Deserialize<'de> for DeploymentStatus {
                     updated_replicas: value_updated_replicas,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(DeploymentStatus {
+                    available_replicas: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    collision_count: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    conditions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    observed_generation: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    ready_replicas: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    replicas: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    unavailable_replicas: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    updated_replicas: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "DeploymentStatus",
-            &[
-                "availableReplicas",
-                "collisionCount",
-                "conditions",
-                "observedGeneration",
-                "readyReplicas",
-                "replicas",
-                "unavailableReplicas",
-                "updatedReplicas",
-            ],
+            DeploymentStatus::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, DeploymentStatus> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("DeploymentStatus")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_available_replicas = false;
+                let mut has_collision_count = false;
+                let mut has_conditions = false;
+                let mut has_observed_generation = false;
+                let mut has_ready_replicas = false;
+                let mut has_replicas = false;
+                let mut has_unavailable_replicas = false;
+                let mut has_updated_replicas = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("DeploymentStatus", DeploymentStatus::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.available_replicas))?; has_available_replicas = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.collision_count))?; has_collision_count = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.conditions))?; has_conditions = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.observed_generation))?; has_observed_generation = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.ready_replicas))?; has_ready_replicas = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.replicas))?; has_replicas = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.unavailable_replicas))?; has_unavailable_replicas = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.updated_replicas))?; has_updated_replicas = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_available_replicas {
+                    self.0.available_replicas = None;
+                }
+                if !has_collision_count {
+                    self.0.collision_count = None;
+                }
+                if !has_conditions {
+                    self.0.conditions = None;
+                }
+                if !has_observed_generation {
+                    self.0.observed_generation = None;
+                }
+                if !has_ready_replicas {
+                    self.0.ready_replicas = None;
+                }
+                if !has_replicas {
+                    self.0.replicas = None;
+                }
+                if !has_unavailable_replicas {
+                    self.0.unavailable_replicas = None;
+                }
+                if !has_updated_replicas {
+                    self.0.updated_replicas = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.available_replicas))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.collision_count))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.conditions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.observed_generation))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.ready_replicas))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.replicas))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.unavailable_replicas))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.updated_replicas))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "DeploymentStatus",
+            DeploymentStatus::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for DeploymentStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("DeploymentStatus", 8)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "availableReplicas", &self.available_replicas)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "collisionCount", &self.collision_count)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conditions", &self.conditions)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "observedGeneration", &self.observed_generation)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readyReplicas", &self.ready_replicas)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "replicas", &self.replicas)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "unavailableReplicas", &self.unavailable_replicas)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "updatedReplicas", &self.updated_replicas)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "DeploymentStatus",
             self.available_replicas.as_ref().map_or(0, |_| 1) +
@@ -177,10 +310,174 @@ impl crate::serde::Serialize for DeploymentStatus {
     }
 }
 
+/// A builder of [`DeploymentStatus`]. Created with [`DeploymentStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct DeploymentStatusBuilder {
+    available_replicas: Option<i32>,
+    collision_count: Option<i32>,
+    conditions: Option<Vec<crate::api::apps::v1beta1::DeploymentCondition>>,
+    observed_generation: Option<i64>,
+    ready_replicas: Option<i32>,
+    replicas: Option<i32>,
+    unavailable_replicas: Option<i32>,
+    updated_replicas: Option<i32>,
+}
+
+#[cfg(feature = "builder")]
+impl DeploymentStatus {
+    /// Starts building a `DeploymentStatus`. Its required fields must be set before [`DeploymentStatusBuilder::build`] is called.
+    pub fn builder() -> DeploymentStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl DeploymentStatusBuilder {
+    /// Sets [`DeploymentStatus::available_replicas`].
+    pub fn available_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.available_replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeploymentStatus::collision_count`].
+    pub fn collision_count(mut self, value: impl Into<i32>) -> Self {
+        self.collision_count = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeploymentStatus::conditions`].
+    pub fn conditions(mut self, value: impl Into<Vec<crate::api::apps::v1beta1::DeploymentCondition>>) -> Self {
+        self.conditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeploymentStatus::observed_generation`].
+    pub fn observed_generation(mut self, value: impl Into<i64>) -> Self {
+        self.observed_generation = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeploymentStatus::ready_replicas`].
+    pub fn ready_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.ready_replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeploymentStatus::replicas`].
+    pub fn replicas(mut self, value: impl Into<i32>) -> Self {
+        self.replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeploymentStatus::unavailable_replicas`].
+    pub fn unavailable_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.unavailable_replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeploymentStatus::updated_replicas`].
+    pub fn updated_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.updated_replicas = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeploymentStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<DeploymentStatus, crate::MissingFieldError> {
+        Ok(DeploymentStatus {
+            available_replicas: self.available_replicas,
+            collision_count: self.collision_count,
+            conditions: self.conditions,
+            observed_generation: self.observed_generation,
+            ready_replicas: self.ready_replicas,
+            replicas: self.replicas,
+            unavailable_replicas: self.unavailable_replicas,
+            updated_replicas: self.updated_replicas,
+        })
+    }
+}
+
+/// The apply configuration of [`DeploymentStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DeploymentStatusApplyConfiguration {
+    /// See [`DeploymentStatus::available_replicas`].
+    pub available_replicas: Option<i32>,
+
+    /// See [`DeploymentStatus::collision_count`].
+    pub collision_count: Option<i32>,
+
+    /// See [`DeploymentStatus::conditions`].
+    pub conditions: Option<Vec<crate::api::apps::v1beta1::DeploymentConditionApplyConfiguration>>,
+
+    /// See [`DeploymentStatus::observed_generation`].
+    pub observed_generation: Option<i64>,
+
+    /// See [`DeploymentStatus::ready_replicas`].
+    pub ready_replicas: Option<i32>,
+
+    /// See [`DeploymentStatus::replicas`].
+    pub replicas: Option<i32>,
+
+    /// See [`DeploymentStatus::unavailable_replicas`].
+    pub unavailable_replicas: Option<i32>,
+
+    /// See [`DeploymentStatus::updated_replicas`].
+    pub updated_replicas: Option<i32>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for DeploymentStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "DeploymentStatusApplyConfiguration",
+            self.available_replicas.as_ref().map_or(0, |_| 1) +
+            self.collision_count.as_ref().map_or(0, |_| 1) +
+            self.conditions.as_ref().map_or(0, |_| 1) +
+            self.observed_generation.as_ref().map_or(0, |_| 1) +
+            self.ready_replicas.as_ref().map_or(0, |_| 1) +
+            self.replicas.as_ref().map_or(0, |_| 1) +
+            self.unavailable_replicas.as_ref().map_or(0, |_| 1) +
+            self.updated_replicas.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.available_replicas {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "availableReplicas", value)?;
+        }
+        if let Some(value) = &self.collision_count {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "collisionCount", value)?;
+        }
+        if let Some(value) = &self.conditions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conditions", value)?;
+        }
+        if let Some(value) = &self.observed_generation {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "observedGeneration", value)?;
+        }
+        if let Some(value) = &self.ready_replicas {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readyReplicas", value)?;
+        }
+        if let Some(value) = &self.replicas {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "replicas", value)?;
+        }
+        if let Some(value) = &self.unavailable_replicas {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "unavailableReplicas", value)?;
+        }
+        if let Some(value) = &self.updated_replicas {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "updatedReplicas", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DeploymentStatus {
     fn schema_name() -> String {
-        "io.k8s.api.apps.v1beta1.DeploymentStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::apps::v1beta1::DeploymentStatus".to_owned()
+        }
+        else {
+            "io.k8s.api.apps.v1beta1.DeploymentStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -191,8 +488,9 @@ impl crate::schemars::JsonSchema for DeploymentStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "availableReplicas".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -203,8 +501,8 @@ impl crate::schemars::JsonSchema for DeploymentStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "collisionCount".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -215,8 +513,8 @@ impl crate::schemars::JsonSchema for DeploymentStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "conditions".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -230,8 +528,8 @@ impl crate::schemars::JsonSchema for DeploymentStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "observedGeneration".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -242,8 +540,8 @@ impl crate::schemars::JsonSchema for DeploymentStatus {
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "readyReplicas".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -254,8 +552,8 @@ impl crate::schemars::JsonSchema for DeploymentStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "replicas".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -266,8 +564,8 @@ impl crate::schemars::JsonSchema for DeploymentStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "unavailableReplicas".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -278,8 +576,8 @@ impl crate::schemars::JsonSchema for DeploymentStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "updatedReplicas".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -290,11 +588,28 @@ impl crate::schemars::JsonSchema for DeploymentStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for DeploymentStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(DeploymentStatus {
+            available_replicas: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            collision_count: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            conditions: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            observed_generation: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            ready_replicas: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            replicas: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            unavailable_replicas: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            updated_replicas: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,62 +1,134 @@
 
 mod controller_revision;
 pub use self::controller_revision::ControllerRevision;
+#[cfg(feature = "builder")]
+pub use self::controller_revision::ControllerRevisionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::controller_revision::ControllerRevisionApplyConfiguration;
 #[cfg(feature = "api")] pub use self::controller_revision::{ReadNamespacedControllerRevisionOptional, ReadNamespacedControllerRevisionResponse};
 
 mod deployment;
 pub use self::deployment::Deployment;
+#[cfg(feature = "builder")]
+pub use self::deployment::DeploymentBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::deployment::DeploymentApplyConfiguration;
 #[cfg(feature = "api")] pub use self::deployment::{ReadNamespacedDeploymentOptional, ReadNamespacedDeploymentResponse};
 #[cfg(feature = "api")] pub use self::deployment::{ReadNamespacedDeploymentStatusOptional, ReadNamespacedDeploymentStatusResponse};
 
 mod deployment_condition;
 pub use self::deployment_condition::DeploymentCondition;
+#[cfg(feature = "builder")]
+pub use self::deployment_condition::DeploymentConditionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::deployment_condition::DeploymentConditionApplyConfiguration;
 
 mod deployment_rollback;
 pub use self::deployment_rollback::DeploymentRollback;
+#[cfg(feature = "builder")]
+pub use self::deployment_rollback::DeploymentRollbackBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::deployment_rollback::DeploymentRollbackApplyConfiguration;
 #[cfg(feature = "api")] pub use self::deployment_rollback::CreateNamespacedDeploymentRollbackResponse;
 
 mod deployment_spec;
 pub use self::deployment_spec::DeploymentSpec;
+#[cfg(feature = "builder")]
+pub use self::deployment_spec::DeploymentSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::deployment_spec::DeploymentSpecApplyConfiguration;
 
 mod deployment_status;
 pub use self::deployment_status::DeploymentStatus;
+#[cfg(feature = "builder")]
+pub use self::deployment_status::DeploymentStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::deployment_status::DeploymentStatusApplyConfiguration;
 
 mod deployment_strategy;
 pub use self::deployment_strategy::DeploymentStrategy;
+#[cfg(feature = "builder")]
+pub use self::deployment_strategy::DeploymentStrategyBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::deployment_strategy::DeploymentStrategyApplyConfiguration;
 
 mod rollback_config;
 pub use self::rollback_config::RollbackConfig;
+#[cfg(feature = "builder")]
+pub use self::rollback_config::RollbackConfigBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::rollback_config::RollbackConfigApplyConfiguration;
 
 mod rolling_update_deployment;
 pub use self::rolling_update_deployment::RollingUpdateDeployment;
+#[cfg(feature = "builder")]
+pub use self::rolling_update_deployment::RollingUpdateDeploymentBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::rolling_update_deployment::RollingUpdateDeploymentApplyConfiguration;
 
 mod rolling_update_stateful_set_strategy;
 pub use self::rolling_update_stateful_set_strategy::RollingUpdateStatefulSetStrategy;
+#[cfg(feature = "builder")]
+pub use self::rolling_update_stateful_set_strategy::RollingUpdateStatefulSetStrategyBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::rolling_update_stateful_set_strategy::RollingUpdateStatefulSetStrategyApplyConfiguration;
 
 mod scale;
 pub use self::scale::Scale;
+#[cfg(feature = "builder")]
+pub use self::scale::ScaleBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::scale::ScaleApplyConfiguration;
 #[cfg(feature = "api")] pub use self::scale::{ReadNamespacedDeploymentScaleOptional, ReadNamespacedDeploymentScaleResponse};
 #[cfg(feature = "api")] pub use self::scale::{ReadNamespacedStatefulSetScaleOptional, ReadNamespacedStatefulSetScaleResponse};
 
 mod scale_spec;
 pub use self::scale_spec::ScaleSpec;
+#[cfg(feature = "builder")]
+pub use self::scale_spec::ScaleSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::scale_spec::ScaleSpecApplyConfiguration;
 
 mod scale_status;
 pub use self::scale_status::ScaleStatus;
+#[cfg(feature = "builder")]
+pub use self::scale_status::ScaleStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::scale_status::ScaleStatusApplyConfiguration;
 
 mod stateful_set;
 pub use self::stateful_set::StatefulSet;
+#[cfg(feature = "builder")]
+pub use self::stateful_set::StatefulSetBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::stateful_set::StatefulSetApplyConfiguration;
 #[cfg(feature = "api")] pub use self::stateful_set::{ReadNamespacedStatefulSetOptional, ReadNamespacedStatefulSetResponse};
 #[cfg(feature = "api")] pub use self::stateful_set::{ReadNamespacedStatefulSetStatusOptional, ReadNamespacedStatefulSetStatusResponse};
 
 mod stateful_set_condition;
 pub use self::stateful_set_condition::StatefulSetCondition;
+#[cfg(feature = "builder")]
+pub use self::stateful_set_condition::StatefulSetConditionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::stateful_set_condition::StatefulSetConditionApplyConfiguration;
 
 mod stateful_set_spec;
 pub use self::stateful_set_spec::StatefulSetSpec;
+#[cfg(feature = "builder")]
+pub use self::stateful_set_spec::StatefulSetSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::stateful_set_spec::StatefulSetSpecApplyConfiguration;
 
 mod stateful_set_status;
 pub use self::stateful_set_status::StatefulSetStatus;
+#[cfg(feature = "builder")]
+pub use self::stateful_set_status::StatefulSetStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::stateful_set_status::StatefulSetStatusApplyConfiguration;
 
 mod stateful_set_update_strategy;
 pub use self::stateful_set_update_strategy::StatefulSetUpdateStrategy;
+#[cfg(feature = "builder")]
+pub use self::stateful_set_update_strategy::StatefulSetUpdateStrategyBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::stateful_set_update_strategy::StatefulSetUpdateStrategyApplyConfiguration;
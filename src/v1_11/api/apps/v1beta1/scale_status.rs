@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.apps.v1beta1.ScaleStatus
 
 /// ScaleStatus represents the current status of a scale subresource.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ScaleStatus {
     /// actual number of observed instances of the scaled object.
     pub replicas: i32,
@@ -13,41 +13,52 @@ pub struct ScaleStatus {
     pub target_selector: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ScaleStatus {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_replicas,
-            Key_selector,
-            Key_target_selector,
-            Other,
+impl ScaleStatus {
+    /// Constructs a `ScaleStatus` from its required fields. Its optional fields are unset.
+    pub fn new(replicas: impl Into<i32>) -> Self {
+        ScaleStatus {
+            replicas: replicas.into(),
+            selector: None,
+            target_selector: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl ScaleStatus {
+    /// Returns this value with [`ScaleStatus::replicas`] set to the given value.
+    #[must_use]
+    pub fn with_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.replicas = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ScaleStatus::selector`] set to the given value.
+    #[must_use]
+    pub fn with_selector(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.selector = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ScaleStatus::target_selector`] set to the given value.
+    #[must_use]
+    pub fn with_target_selector(mut self, value: impl Into<String>) -> Self {
+        self.target_selector = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "replicas" => Field::Key_replicas,
-                            "selector" => Field::Key_selector,
-                            "targetSelector" => Field::Key_target_selector,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for ScaleStatus {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl ScaleStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "replicas",
+        "selector",
+        "targetSelector",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ScaleStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,37 +73,101 @@ impl<'de> crate::serde::Deserialize<'de> for ScaleStatus {
                 let mut value_selector: Option<std::collections::BTreeMap<String, String>> = None;
                 let mut value_target_selector: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ScaleStatus", ScaleStatus::FIELDS))? {
                     match key {
-                        Field::Key_replicas => value_replicas = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_selector => value_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_target_selector => value_target_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_replicas = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_target_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ScaleStatus {
-                    replicas: value_replicas.ok_or_else(|| crate::serde::de::Error::missing_field("replicas"))?,
+                    replicas: value_replicas.ok_or_else(|| crate::serde2::missing_field("ScaleStatus", "replicas", &["replicas"]))?,
                     selector: value_selector,
                     target_selector: value_target_selector,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ScaleStatus {
+                    replicas: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    selector: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    target_selector: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "ScaleStatus",
-            &[
-                "replicas",
-                "selector",
-                "targetSelector",
-            ],
+            ScaleStatus::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ScaleStatus> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ScaleStatus")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_replicas = false;
+                let mut has_selector = false;
+                let mut has_target_selector = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ScaleStatus", ScaleStatus::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.replicas))?; has_replicas = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.selector))?; has_selector = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.target_selector))?; has_target_selector = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_replicas {
+                    return Err(crate::serde2::missing_field("ScaleStatus", "replicas", &["replicas"]));
+                }
+                if !has_selector {
+                    self.0.selector = None;
+                }
+                if !has_target_selector {
+                    self.0.target_selector = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.replicas))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.selector))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.target_selector))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ScaleStatus",
+            ScaleStatus::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ScaleStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ScaleStatus", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "replicas", &self.replicas)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selector", &self.selector)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "targetSelector", &self.target_selector)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ScaleStatus",
             1 +
@@ -110,10 +185,99 @@ impl crate::serde::Serialize for ScaleStatus {
     }
 }
 
+/// A builder of [`ScaleStatus`]. Created with [`ScaleStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ScaleStatusBuilder {
+    replicas: Option<i32>,
+    selector: Option<std::collections::BTreeMap<String, String>>,
+    target_selector: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl ScaleStatus {
+    /// Starts building a `ScaleStatus`. Its required fields must be set before [`ScaleStatusBuilder::build`] is called.
+    pub fn builder() -> ScaleStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ScaleStatusBuilder {
+    /// Sets [`ScaleStatus::replicas`].
+    pub fn replicas(mut self, value: impl Into<i32>) -> Self {
+        self.replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`ScaleStatus::selector`].
+    pub fn selector(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`ScaleStatus::target_selector`].
+    pub fn target_selector(mut self, value: impl Into<String>) -> Self {
+        self.target_selector = Some(value.into());
+        self
+    }
+
+    /// Builds the `ScaleStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ScaleStatus, crate::MissingFieldError> {
+        Ok(ScaleStatus {
+            replicas: self.replicas.ok_or_else(|| crate::MissingFieldError::new("ScaleStatus", "replicas"))?,
+            selector: self.selector,
+            target_selector: self.target_selector,
+        })
+    }
+}
+
+/// The apply configuration of [`ScaleStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ScaleStatusApplyConfiguration {
+    /// See [`ScaleStatus::replicas`].
+    pub replicas: Option<i32>,
+
+    /// See [`ScaleStatus::selector`].
+    pub selector: Option<std::collections::BTreeMap<String, String>>,
+
+    /// See [`ScaleStatus::target_selector`].
+    pub target_selector: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ScaleStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ScaleStatusApplyConfiguration",
+            self.replicas.as_ref().map_or(0, |_| 1) +
+            self.selector.as_ref().map_or(0, |_| 1) +
+            self.target_selector.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.replicas {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "replicas", value)?;
+        }
+        if let Some(value) = &self.selector {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selector", value)?;
+        }
+        if let Some(value) = &self.target_selector {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "targetSelector", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ScaleStatus {
     fn schema_name() -> String {
-        "io.k8s.api.apps.v1beta1.ScaleStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::apps::v1beta1::ScaleStatus".to_owned()
+        }
+        else {
+            "io.k8s.api.apps.v1beta1.ScaleStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -124,8 +288,9 @@ impl crate::schemars::JsonSchema for ScaleStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "replicas".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -136,8 +301,8 @@ impl crate::schemars::JsonSchema for ScaleStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "selector".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -156,8 +321,8 @@ impl crate::schemars::JsonSchema for ScaleStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "targetSelector".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -167,8 +332,9 @@ impl crate::schemars::JsonSchema for ScaleStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "replicas",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -178,3 +344,14 @@ impl crate::schemars::JsonSchema for ScaleStatus {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ScaleStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ScaleStatus {
+            replicas: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            selector: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            target_selector: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.apps.v1beta2.DaemonSetSpec
 
 /// DaemonSetSpec is the specification of a daemon set.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct DaemonSetSpec {
     /// The minimum number of seconds for which a newly created DaemonSet pod should be ready without any of its container crashing, for it to be considered available. Defaults to 0 (pod will be considered available as soon as it is ready).
     pub min_ready_seconds: Option<i32>,
@@ -19,45 +19,70 @@ pub struct DaemonSetSpec {
     pub update_strategy: Option<crate::api::apps::v1beta2::DaemonSetUpdateStrategy>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for DaemonSetSpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_min_ready_seconds,
-            Key_revision_history_limit,
-            Key_selector,
-            Key_template,
-            Key_update_strategy,
-            Other,
+impl DaemonSetSpec {
+    /// Constructs a `DaemonSetSpec` from its required fields. Its optional fields are unset.
+    pub fn new(selector: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>, template: impl Into<crate::api::core::v1::PodTemplateSpec>) -> Self {
+        DaemonSetSpec {
+            min_ready_seconds: None,
+            revision_history_limit: None,
+            selector: selector.into(),
+            template: template.into(),
+            update_strategy: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl DaemonSetSpec {
+    /// Returns this value with [`DaemonSetSpec::min_ready_seconds`] set to the given value.
+    #[must_use]
+    pub fn with_min_ready_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.min_ready_seconds = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`DaemonSetSpec::revision_history_limit`] set to the given value.
+    #[must_use]
+    pub fn with_revision_history_limit(mut self, value: impl Into<i32>) -> Self {
+        self.revision_history_limit = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`DaemonSetSpec::selector`] set to the given value.
+    #[must_use]
+    pub fn with_selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.selector = value.into();
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "minReadySeconds" => Field::Key_min_ready_seconds,
-                            "revisionHistoryLimit" => Field::Key_revision_history_limit,
-                            "selector" => Field::Key_selector,
-                            "template" => Field::Key_template,
-                            "updateStrategy" => Field::Key_update_strategy,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`DaemonSetSpec::template`] set to the given value.
+    #[must_use]
+    pub fn with_template(mut self, value: impl Into<crate::api::core::v1::PodTemplateSpec>) -> Self {
+        self.template = value.into();
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`DaemonSetSpec::update_strategy`] set to the given value.
+    #[must_use]
+    pub fn with_update_strategy(mut self, value: impl Into<crate::api::apps::v1beta2::DaemonSetUpdateStrategy>) -> Self {
+        self.update_strategy = Some(value.into());
+        self
+    }
+}
 
+impl crate::MergeStrategyInfo for DaemonSetSpec {}
+
+impl DaemonSetSpec {
+    const FIELDS: &'static [&'static str] = &[
+        "minReadySeconds",
+        "revisionHistoryLimit",
+        "selector",
+        "template",
+        "updateStrategy",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for DaemonSetSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -74,43 +99,121 @@ impl<'de> crate::serde::Deserialize<'de> for DaemonSetSpec {
                 let mut value_template: Option<crate::api::core::v1::PodTemplateSpec> = None;
                 let mut value_update_strategy: Option<crate::api::apps::v1beta2::DaemonSetUpdateStrategy> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("DaemonSetSpec", DaemonSetSpec::FIELDS))? {
                     match key {
-                        Field::Key_min_ready_seconds => value_min_ready_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_revision_history_limit => value_revision_history_limit = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_selector => value_selector = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_template => value_template = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_update_strategy => value_update_strategy = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_min_ready_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_revision_history_limit = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_selector = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_template = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(4) => value_update_strategy = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(DaemonSetSpec {
                     min_ready_seconds: value_min_ready_seconds,
                     revision_history_limit: value_revision_history_limit,
-                    selector: value_selector.ok_or_else(|| crate::serde::de::Error::missing_field("selector"))?,
-                    template: value_template.ok_or_else(|| crate::serde::de::Error::missing_field("template"))?,
+                    selector: value_selector.ok_or_else(|| crate::serde2::missing_field("DaemonSetSpec", "selector", &["selector", "template"]))?,
+                    template: value_template.ok_or_else(|| crate::serde2::missing_field("DaemonSetSpec", "template", &["selector", "template"]))?,
                     update_strategy: value_update_strategy,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(DaemonSetSpec {
+                    min_ready_seconds: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    revision_history_limit: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    selector: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    template: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    update_strategy: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "DaemonSetSpec",
-            &[
-                "minReadySeconds",
-                "revisionHistoryLimit",
-                "selector",
-                "template",
-                "updateStrategy",
-            ],
+            DaemonSetSpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, DaemonSetSpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("DaemonSetSpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_min_ready_seconds = false;
+                let mut has_revision_history_limit = false;
+                let mut has_selector = false;
+                let mut has_template = false;
+                let mut has_update_strategy = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("DaemonSetSpec", DaemonSetSpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.min_ready_seconds))?; has_min_ready_seconds = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.revision_history_limit))?; has_revision_history_limit = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.selector))?; has_selector = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.template))?; has_template = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.update_strategy))?; has_update_strategy = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_min_ready_seconds {
+                    self.0.min_ready_seconds = None;
+                }
+                if !has_revision_history_limit {
+                    self.0.revision_history_limit = None;
+                }
+                if !has_selector {
+                    return Err(crate::serde2::missing_field("DaemonSetSpec", "selector", &["selector", "template"]));
+                }
+                if !has_template {
+                    return Err(crate::serde2::missing_field("DaemonSetSpec", "template", &["selector", "template"]));
+                }
+                if !has_update_strategy {
+                    self.0.update_strategy = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.min_ready_seconds))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.revision_history_limit))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.selector))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.template))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.update_strategy))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "DaemonSetSpec",
+            DaemonSetSpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for DaemonSetSpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("DaemonSetSpec", 5)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "minReadySeconds", &self.min_ready_seconds)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "revisionHistoryLimit", &self.revision_history_limit)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selector", &self.selector)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "template", &self.template)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "updateStrategy", &self.update_strategy)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "DaemonSetSpec",
             2 +
@@ -133,10 +236,129 @@ impl crate::serde::Serialize for DaemonSetSpec {
     }
 }
 
+/// A builder of [`DaemonSetSpec`]. Created with [`DaemonSetSpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct DaemonSetSpecBuilder {
+    min_ready_seconds: Option<i32>,
+    revision_history_limit: Option<i32>,
+    selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>,
+    template: Option<crate::api::core::v1::PodTemplateSpec>,
+    update_strategy: Option<crate::api::apps::v1beta2::DaemonSetUpdateStrategy>,
+}
+
+#[cfg(feature = "builder")]
+impl DaemonSetSpec {
+    /// Starts building a `DaemonSetSpec`. Its required fields must be set before [`DaemonSetSpecBuilder::build`] is called.
+    pub fn builder() -> DaemonSetSpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl DaemonSetSpecBuilder {
+    /// Sets [`DaemonSetSpec::min_ready_seconds`].
+    pub fn min_ready_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.min_ready_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`DaemonSetSpec::revision_history_limit`].
+    pub fn revision_history_limit(mut self, value: impl Into<i32>) -> Self {
+        self.revision_history_limit = Some(value.into());
+        self
+    }
+
+    /// Sets [`DaemonSetSpec::selector`].
+    pub fn selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`DaemonSetSpec::template`].
+    pub fn template(mut self, value: impl Into<crate::api::core::v1::PodTemplateSpec>) -> Self {
+        self.template = Some(value.into());
+        self
+    }
+
+    /// Sets [`DaemonSetSpec::update_strategy`].
+    pub fn update_strategy(mut self, value: impl Into<crate::api::apps::v1beta2::DaemonSetUpdateStrategy>) -> Self {
+        self.update_strategy = Some(value.into());
+        self
+    }
+
+    /// Builds the `DaemonSetSpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<DaemonSetSpec, crate::MissingFieldError> {
+        Ok(DaemonSetSpec {
+            min_ready_seconds: self.min_ready_seconds,
+            revision_history_limit: self.revision_history_limit,
+            selector: self.selector.ok_or_else(|| crate::MissingFieldError::new("DaemonSetSpec", "selector"))?,
+            template: self.template.ok_or_else(|| crate::MissingFieldError::new("DaemonSetSpec", "template"))?,
+            update_strategy: self.update_strategy,
+        })
+    }
+}
+
+/// The apply configuration of [`DaemonSetSpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DaemonSetSpecApplyConfiguration {
+    /// See [`DaemonSetSpec::min_ready_seconds`].
+    pub min_ready_seconds: Option<i32>,
+
+    /// See [`DaemonSetSpec::revision_history_limit`].
+    pub revision_history_limit: Option<i32>,
+
+    /// See [`DaemonSetSpec::selector`].
+    pub selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorApplyConfiguration>,
+
+    /// See [`DaemonSetSpec::template`].
+    pub template: Option<crate::api::core::v1::PodTemplateSpecApplyConfiguration>,
+
+    /// See [`DaemonSetSpec::update_strategy`].
+    pub update_strategy: Option<crate::api::apps::v1beta2::DaemonSetUpdateStrategyApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for DaemonSetSpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "DaemonSetSpecApplyConfiguration",
+            self.min_ready_seconds.as_ref().map_or(0, |_| 1) +
+            self.revision_history_limit.as_ref().map_or(0, |_| 1) +
+            self.selector.as_ref().map_or(0, |_| 1) +
+            self.template.as_ref().map_or(0, |_| 1) +
+            self.update_strategy.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.min_ready_seconds {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "minReadySeconds", value)?;
+        }
+        if let Some(value) = &self.revision_history_limit {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "revisionHistoryLimit", value)?;
+        }
+        if let Some(value) = &self.selector {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selector", value)?;
+        }
+        if let Some(value) = &self.template {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "template", value)?;
+        }
+        if let Some(value) = &self.update_strategy {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "updateStrategy", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DaemonSetSpec {
     fn schema_name() -> String {
-        "io.k8s.api.apps.v1beta2.DaemonSetSpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::apps::v1beta2::DaemonSetSpec".to_owned()
+        }
+        else {
+            "io.k8s.api.apps.v1beta2.DaemonSetSpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -147,8 +369,9 @@ impl crate::schemars::JsonSchema for DaemonSetSpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "minReadySeconds".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -159,8 +382,8 @@ impl crate::schemars::JsonSchema for DaemonSetSpec {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "revisionHistoryLimit".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -171,8 +394,8 @@ impl crate::schemars::JsonSchema for DaemonSetSpec {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "selector".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>().into_object();
@@ -182,8 +405,8 @@ impl crate::schemars::JsonSchema for DaemonSetSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "template".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::PodTemplateSpec>().into_object();
@@ -193,8 +416,8 @@ impl crate::schemars::JsonSchema for DaemonSetSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "updateStrategy".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::apps::v1beta2::DaemonSetUpdateStrategy>().into_object();
@@ -204,8 +427,9 @@ impl crate::schemars::JsonSchema for DaemonSetSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "selector",
                     "template",
@@ -216,3 +440,16 @@ impl crate::schemars::JsonSchema for DaemonSetSpec {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for DaemonSetSpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(DaemonSetSpec {
+            min_ready_seconds: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            revision_history_limit: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            selector: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            template: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            update_strategy: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.apps.v1beta2.DaemonSetUpdateStrategy
 
 /// DaemonSetUpdateStrategy is a struct used to control the update strategy for a DaemonSet.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct DaemonSetUpdateStrategy {
     /// Rolling update config params. Present only if type = "RollingUpdate".
     pub rolling_update: Option<crate::api::apps::v1beta2::RollingUpdateDaemonSet>,
@@ -10,39 +10,33 @@ pub struct DaemonSetUpdateStrategy {
     pub type_: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for DaemonSetUpdateStrategy {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_rolling_update,
-            Key_type_,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl DaemonSetUpdateStrategy {
+    /// Returns this value with [`DaemonSetUpdateStrategy::rolling_update`] set to the given value.
+    #[must_use]
+    pub fn with_rolling_update(mut self, value: impl Into<crate::api::apps::v1beta2::RollingUpdateDaemonSet>) -> Self {
+        self.rolling_update = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`DaemonSetUpdateStrategy::type_`] set to the given value.
+    #[must_use]
+    pub fn with_type(mut self, value: impl Into<String>) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "rollingUpdate" => Field::Key_rolling_update,
-                            "type" => Field::Key_type_,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for DaemonSetUpdateStrategy {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl DaemonSetUpdateStrategy {
+    const FIELDS: &'static [&'static str] = &[
+        "rollingUpdate",
+        "type",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for DaemonSetUpdateStrategy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,11 +50,11 @@ impl<'de> crate::serde::Deserialize<'de> for DaemonSetUpdateStrategy {
                 let mut value_rolling_update: Option<crate::api::apps::v1beta2::RollingUpdateDaemonSet> = None;
                 let mut value_type_: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("DaemonSetUpdateStrategy", DaemonSetUpdateStrategy::FIELDS))? {
                     match key {
-                        Field::Key_rolling_update => value_rolling_update = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_type_ => value_type_ = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_rolling_update = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_type_ = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -69,21 +63,78 @@ impl<'de> crate::serde::Deserialize<'de> for DaemonSetUpdateStrategy {
                     type_: value_type_,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(DaemonSetUpdateStrategy {
+                    rolling_update: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    type_: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "DaemonSetUpdateStrategy",
-            &[
-                "rollingUpdate",
-                "type",
-            ],
+            DaemonSetUpdateStrategy::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, DaemonSetUpdateStrategy> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("DaemonSetUpdateStrategy")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_rolling_update = false;
+                let mut has_type_ = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("DaemonSetUpdateStrategy", DaemonSetUpdateStrategy::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.rolling_update))?; has_rolling_update = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.type_))?; has_type_ = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_rolling_update {
+                    self.0.rolling_update = None;
+                }
+                if !has_type_ {
+                    self.0.type_ = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.rolling_update))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.type_))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "DaemonSetUpdateStrategy",
+            DaemonSetUpdateStrategy::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for DaemonSetUpdateStrategy {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("DaemonSetUpdateStrategy", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rollingUpdate", &self.rolling_update)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "type", &self.type_)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "DaemonSetUpdateStrategy",
             self.rolling_update.as_ref().map_or(0, |_| 1) +
@@ -99,10 +150,84 @@ impl crate::serde::Serialize for DaemonSetUpdateStrategy {
     }
 }
 
+/// A builder of [`DaemonSetUpdateStrategy`]. Created with [`DaemonSetUpdateStrategy::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct DaemonSetUpdateStrategyBuilder {
+    rolling_update: Option<crate::api::apps::v1beta2::RollingUpdateDaemonSet>,
+    type_: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl DaemonSetUpdateStrategy {
+    /// Starts building a `DaemonSetUpdateStrategy`. Its required fields must be set before [`DaemonSetUpdateStrategyBuilder::build`] is called.
+    pub fn builder() -> DaemonSetUpdateStrategyBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl DaemonSetUpdateStrategyBuilder {
+    /// Sets [`DaemonSetUpdateStrategy::rolling_update`].
+    pub fn rolling_update(mut self, value: impl Into<crate::api::apps::v1beta2::RollingUpdateDaemonSet>) -> Self {
+        self.rolling_update = Some(value.into());
+        self
+    }
+
+    /// Sets [`DaemonSetUpdateStrategy::type_`].
+    pub fn type_(mut self, value: impl Into<String>) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `DaemonSetUpdateStrategy`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<DaemonSetUpdateStrategy, crate::MissingFieldError> {
+        Ok(DaemonSetUpdateStrategy {
+            rolling_update: self.rolling_update,
+            type_: self.type_,
+        })
+    }
+}
+
+/// The apply configuration of [`DaemonSetUpdateStrategy`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DaemonSetUpdateStrategyApplyConfiguration {
+    /// See [`DaemonSetUpdateStrategy::rolling_update`].
+    pub rolling_update: Option<crate::api::apps::v1beta2::RollingUpdateDaemonSetApplyConfiguration>,
+
+    /// See [`DaemonSetUpdateStrategy::type_`].
+    pub type_: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for DaemonSetUpdateStrategyApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "DaemonSetUpdateStrategyApplyConfiguration",
+            self.rolling_update.as_ref().map_or(0, |_| 1) +
+            self.type_.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.rolling_update {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rollingUpdate", value)?;
+        }
+        if let Some(value) = &self.type_ {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "type", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DaemonSetUpdateStrategy {
     fn schema_name() -> String {
-        "io.k8s.api.apps.v1beta2.DaemonSetUpdateStrategy".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::apps::v1beta2::DaemonSetUpdateStrategy".to_owned()
+        }
+        else {
+            "io.k8s.api.apps.v1beta2.DaemonSetUpdateStrategy".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -113,8 +238,9 @@ impl crate::schemars::JsonSchema for DaemonSetUpdateStrategy {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "rollingUpdate".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::apps::v1beta2::RollingUpdateDaemonSet>().into_object();
@@ -124,8 +250,8 @@ impl crate::schemars::JsonSchema for DaemonSetUpdateStrategy {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "type".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -135,11 +261,22 @@ impl crate::schemars::JsonSchema for DaemonSetUpdateStrategy {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for DaemonSetUpdateStrategy {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(DaemonSetUpdateStrategy {
+            rolling_update: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            type_: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.apps.v1beta2.DeploymentCondition
 
 /// DeploymentCondition describes the state of a deployment at a certain point.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct DeploymentCondition {
     /// Last time the condition transitioned from one status to another.
     pub last_transition_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
@@ -22,47 +22,79 @@ pub struct DeploymentCondition {
     pub type_: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for DeploymentCondition {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_last_transition_time,
-            Key_last_update_time,
-            Key_message,
-            Key_reason,
-            Key_status,
-            Key_type_,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl DeploymentCondition {
+    /// Constructs a `DeploymentCondition` from its required fields. Its optional fields are unset.
+    pub fn new(status: impl Into<String>, type_: impl Into<String>) -> Self {
+        DeploymentCondition {
+            last_transition_time: None,
+            last_update_time: None,
+            message: None,
+            reason: None,
+            status: status.into(),
+            type_: type_.into(),
+        }
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "lastTransitionTime" => Field::Key_last_transition_time,
-                            "lastUpdateTime" => Field::Key_last_update_time,
-                            "message" => Field::Key_message,
-                            "reason" => Field::Key_reason,
-                            "status" => Field::Key_status,
-                            "type" => Field::Key_type_,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl DeploymentCondition {
+    /// Returns this value with [`DeploymentCondition::last_transition_time`] set to the given value.
+    #[must_use]
+    pub fn with_last_transition_time(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.last_transition_time = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`DeploymentCondition::last_update_time`] set to the given value.
+    #[must_use]
+    pub fn with_last_update_time(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.last_update_time = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`DeploymentCondition::message`] set to the given value.
+    #[must_use]
+    pub fn with_message(mut self, value: impl Into<String>) -> Self {
+        self.message = Some(value.into());
+        self
+    }
 
+    /// Returns this value with [`DeploymentCondition::reason`] set to the given value.
+    #[must_use]
+    pub fn with_reason(mut self, value: impl Into<String>) -> Self {
+        self.reason = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`DeploymentCondition::status`] set to the given value.
+    #[must_use]
+    pub fn with_status(mut self, value: impl Into<String>) -> Self {
+        self.status = value.into();
+        self
+    }
+
+    /// Returns this value with [`DeploymentCondition::type_`] set to the given value.
+    #[must_use]
+    pub fn with_type(mut self, value: impl Into<String>) -> Self {
+        self.type_ = value.into();
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for DeploymentCondition {}
+
+impl DeploymentCondition {
+    const FIELDS: &'static [&'static str] = &[
+        "lastTransitionTime",
+        "lastUpdateTime",
+        "message",
+        "reason",
+        "status",
+        "type",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for DeploymentCondition {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -80,15 +112,15 @@ impl<'de> crate::serde::Deserialize<'de> for DeploymentCondition {
                 let mut value_status: Option<String> = None;
                 let mut value_type_: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("DeploymentCondition", DeploymentCondition::FIELDS))? {
                     match key {
-                        Field::Key_last_transition_time => value_last_transition_time = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_last_update_time => value_last_update_time = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_message => value_message = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_reason => value_reason = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_status => value_status = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_type_ => value_type_ = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_last_transition_time = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_last_update_time = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_message = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_reason = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_status = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(5) => value_type_ = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -97,29 +129,114 @@ impl<'de> crate::serde::Deserialize<'de> for DeploymentCondition {
                     last_update_time: value_last_update_time,
                     message: value_message,
                     reason: value_reason,
-                    status: value_status.ok_or_else(|| crate::serde::de::Error::missing_field("status"))?,
-                    type_: value_type_.ok_or_else(|| crate::serde::de::Error::missing_field("type"))?,
+                    status: value_status.ok_or_else(|| crate::serde2::missing_field("DeploymentCondition", "status", &["status", "type"]))?,
+                    type_: value_type_.ok_or_else(|| crate::serde2::missing_field("DeploymentCondition", "type", &["status", "type"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(DeploymentCondition {
+                    last_transition_time: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    last_update_time: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    message: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    reason: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    status: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    type_: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "DeploymentCondition",
-            &[
-                "lastTransitionTime",
-                "lastUpdateTime",
-                "message",
-                "reason",
-                "status",
-                "type",
-            ],
+            DeploymentCondition::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, DeploymentCondition> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("DeploymentCondition")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_last_transition_time = false;
+                let mut has_last_update_time = false;
+                let mut has_message = false;
+                let mut has_reason = false;
+                let mut has_status = false;
+                let mut has_type_ = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("DeploymentCondition", DeploymentCondition::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.last_transition_time))?; has_last_transition_time = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.last_update_time))?; has_last_update_time = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.message))?; has_message = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.reason))?; has_reason = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.status))?; has_status = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.type_))?; has_type_ = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_last_transition_time {
+                    self.0.last_transition_time = None;
+                }
+                if !has_last_update_time {
+                    self.0.last_update_time = None;
+                }
+                if !has_message {
+                    self.0.message = None;
+                }
+                if !has_reason {
+                    self.0.reason = None;
+                }
+                if !has_status {
+                    return Err(crate::serde2::missing_field("DeploymentCondition", "status", &["status", "type"]));
+                }
+                if !has_type_ {
+                    return Err(crate::serde2::missing_field("DeploymentCondition", "type", &["status", "type"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.last_transition_time))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.last_update_time))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.message))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.reason))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.status))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.type_))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "DeploymentCondition",
+            DeploymentCondition::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for DeploymentCondition {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("DeploymentCondition", 6)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "lastTransitionTime", &self.last_transition_time)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "lastUpdateTime", &self.last_update_time)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "message", &self.message)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reason", &self.reason)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "status", &self.status)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "type", &self.type_)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "DeploymentCondition",
             2 +
@@ -146,10 +263,144 @@ impl crate::serde::Serialize for DeploymentCondition {
     }
 }
 
+/// A builder of [`DeploymentCondition`]. Created with [`DeploymentCondition::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct DeploymentConditionBuilder {
+    last_transition_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+    last_update_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+    message: Option<String>,
+    reason: Option<String>,
+    status: Option<String>,
+    type_: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl DeploymentCondition {
+    /// Starts building a `DeploymentCondition`. Its required fields must be set before [`DeploymentConditionBuilder::build`] is called.
+    pub fn builder() -> DeploymentConditionBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl DeploymentConditionBuilder {
+    /// Sets [`DeploymentCondition::last_transition_time`].
+    pub fn last_transition_time(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.last_transition_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeploymentCondition::last_update_time`].
+    pub fn last_update_time(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.last_update_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeploymentCondition::message`].
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.message = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeploymentCondition::reason`].
+    pub fn reason(mut self, value: impl Into<String>) -> Self {
+        self.reason = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeploymentCondition::status`].
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeploymentCondition::type_`].
+    pub fn type_(mut self, value: impl Into<String>) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeploymentCondition`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<DeploymentCondition, crate::MissingFieldError> {
+        Ok(DeploymentCondition {
+            last_transition_time: self.last_transition_time,
+            last_update_time: self.last_update_time,
+            message: self.message,
+            reason: self.reason,
+            status: self.status.ok_or_else(|| crate::MissingFieldError::new("DeploymentCondition", "status"))?,
+            type_: self.type_.ok_or_else(|| crate::MissingFieldError::new("DeploymentCondition", "type"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`DeploymentCondition`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DeploymentConditionApplyConfiguration {
+    /// See [`DeploymentCondition::last_transition_time`].
+    pub last_transition_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+
+    /// See [`DeploymentCondition::last_update_time`].
+    pub last_update_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+
+    /// See [`DeploymentCondition::message`].
+    pub message: Option<String>,
+
+    /// See [`DeploymentCondition::reason`].
+    pub reason: Option<String>,
+
+    /// See [`DeploymentCondition::status`].
+    pub status: Option<String>,
+
+    /// See [`DeploymentCondition::type_`].
+    pub type_: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for DeploymentConditionApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "DeploymentConditionApplyConfiguration",
+            self.last_transition_time.as_ref().map_or(0, |_| 1) +
+            self.last_update_time.as_ref().map_or(0, |_| 1) +
+            self.message.as_ref().map_or(0, |_| 1) +
+            self.reason.as_ref().map_or(0, |_| 1) +
+            self.status.as_ref().map_or(0, |_| 1) +
+            self.type_.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.last_transition_time {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "lastTransitionTime", value)?;
+        }
+        if let Some(value) = &self.last_update_time {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "lastUpdateTime", value)?;
+        }
+        if let Some(value) = &self.message {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "message", value)?;
+        }
+        if let Some(value) = &self.reason {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reason", value)?;
+        }
+        if let Some(value) = &self.status {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "status", value)?;
+        }
+        if let Some(value) = &self.type_ {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "type", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DeploymentCondition {
     fn schema_name() -> String {
-        "io.k8s.api.apps.v1beta2.DeploymentCondition".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::apps::v1beta2::DeploymentCondition".to_owned()
+        }
+        else {
+            "io.k8s.api.apps.v1beta2.DeploymentCondition".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -160,8 +411,9 @@ impl crate::schemars::JsonSchema for DeploymentCondition {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "lastTransitionTime".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::Time>().into_object();
@@ -171,8 +423,8 @@ impl crate::schemars::JsonSchema for DeploymentCondition {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "lastUpdateTime".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::Time>().into_object();
@@ -182,8 +434,8 @@ impl crate::schemars::JsonSchema for DeploymentCondition {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "message".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -193,8 +445,8 @@ impl crate::schemars::JsonSchema for DeploymentCondition {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "reason".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -204,8 +456,8 @@ impl crate::schemars::JsonSchema for DeploymentCondition {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "status".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -215,8 +467,8 @@ impl crate::schemars::JsonSchema for DeploymentCondition {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "type".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -226,8 +478,9 @@ impl crate::schemars::JsonSchema for DeploymentCondition {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "status",
                     "type",
@@ -238,3 +491,17 @@ impl crate::schemars::JsonSchema for DeploymentCondition {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for DeploymentCondition {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(DeploymentCondition {
+            last_transition_time: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            last_update_time: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            message: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            reason: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            status: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            type_: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -1,90 +1,194 @@
 
 mod controller_revision;
 pub use self::controller_revision::ControllerRevision;
+#[cfg(feature = "builder")]
+pub use self::controller_revision::ControllerRevisionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::controller_revision::ControllerRevisionApplyConfiguration;
 #[cfg(feature = "api")] pub use self::controller_revision::{ReadNamespacedControllerRevisionOptional, ReadNamespacedControllerRevisionResponse};
 
 mod daemon_set;
 pub use self::daemon_set::DaemonSet;
+#[cfg(feature = "builder")]
+pub use self::daemon_set::DaemonSetBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::daemon_set::DaemonSetApplyConfiguration;
 #[cfg(feature = "api")] pub use self::daemon_set::{ReadNamespacedDaemonSetOptional, ReadNamespacedDaemonSetResponse};
 #[cfg(feature = "api")] pub use self::daemon_set::{ReadNamespacedDaemonSetStatusOptional, ReadNamespacedDaemonSetStatusResponse};
 
 mod daemon_set_condition;
 pub use self::daemon_set_condition::DaemonSetCondition;
+#[cfg(feature = "builder")]
+pub use self::daemon_set_condition::DaemonSetConditionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::daemon_set_condition::DaemonSetConditionApplyConfiguration;
 
 mod daemon_set_spec;
 pub use self::daemon_set_spec::DaemonSetSpec;
+#[cfg(feature = "builder")]
+pub use self::daemon_set_spec::DaemonSetSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::daemon_set_spec::DaemonSetSpecApplyConfiguration;
 
 mod daemon_set_status;
 pub use self::daemon_set_status::DaemonSetStatus;
+#[cfg(feature = "builder")]
+pub use self::daemon_set_status::DaemonSetStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::daemon_set_status::DaemonSetStatusApplyConfiguration;
 
 mod daemon_set_update_strategy;
 pub use self::daemon_set_update_strategy::DaemonSetUpdateStrategy;
+#[cfg(feature = "builder")]
+pub use self::daemon_set_update_strategy::DaemonSetUpdateStrategyBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::daemon_set_update_strategy::DaemonSetUpdateStrategyApplyConfiguration;
 
 mod deployment;
 pub use self::deployment::Deployment;
+#[cfg(feature = "builder")]
+pub use self::deployment::DeploymentBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::deployment::DeploymentApplyConfiguration;
 #[cfg(feature = "api")] pub use self::deployment::{ReadNamespacedDeploymentOptional, ReadNamespacedDeploymentResponse};
 #[cfg(feature = "api")] pub use self::deployment::{ReadNamespacedDeploymentStatusOptional, ReadNamespacedDeploymentStatusResponse};
 
 mod deployment_condition;
 pub use self::deployment_condition::DeploymentCondition;
+#[cfg(feature = "builder")]
+pub use self::deployment_condition::DeploymentConditionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::deployment_condition::DeploymentConditionApplyConfiguration;
 
 mod deployment_spec;
 pub use self::deployment_spec::DeploymentSpec;
+#[cfg(feature = "builder")]
+pub use self::deployment_spec::DeploymentSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::deployment_spec::DeploymentSpecApplyConfiguration;
 
 mod deployment_status;
 pub use self::deployment_status::DeploymentStatus;
+#[cfg(feature = "builder")]
+pub use self::deployment_status::DeploymentStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::deployment_status::DeploymentStatusApplyConfiguration;
 
 mod deployment_strategy;
 pub use self::deployment_strategy::DeploymentStrategy;
+#[cfg(feature = "builder")]
+pub use self::deployment_strategy::DeploymentStrategyBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::deployment_strategy::DeploymentStrategyApplyConfiguration;
 
 mod replica_set;
 pub use self::replica_set::ReplicaSet;
+#[cfg(feature = "builder")]
+pub use self::replica_set::ReplicaSetBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::replica_set::ReplicaSetApplyConfiguration;
 #[cfg(feature = "api")] pub use self::replica_set::{ReadNamespacedReplicaSetOptional, ReadNamespacedReplicaSetResponse};
 #[cfg(feature = "api")] pub use self::replica_set::{ReadNamespacedReplicaSetStatusOptional, ReadNamespacedReplicaSetStatusResponse};
 
 mod replica_set_condition;
 pub use self::replica_set_condition::ReplicaSetCondition;
+#[cfg(feature = "builder")]
+pub use self::replica_set_condition::ReplicaSetConditionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::replica_set_condition::ReplicaSetConditionApplyConfiguration;
 
 mod replica_set_spec;
 pub use self::replica_set_spec::ReplicaSetSpec;
+#[cfg(feature = "builder")]
+pub use self::replica_set_spec::ReplicaSetSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::replica_set_spec::ReplicaSetSpecApplyConfiguration;
 
 mod replica_set_status;
 pub use self::replica_set_status::ReplicaSetStatus;
+#[cfg(feature = "builder")]
+pub use self::replica_set_status::ReplicaSetStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::replica_set_status::ReplicaSetStatusApplyConfiguration;
 
 mod rolling_update_daemon_set;
 pub use self::rolling_update_daemon_set::RollingUpdateDaemonSet;
+#[cfg(feature = "builder")]
+pub use self::rolling_update_daemon_set::RollingUpdateDaemonSetBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::rolling_update_daemon_set::RollingUpdateDaemonSetApplyConfiguration;
 
 mod rolling_update_deployment;
 pub use self::rolling_update_deployment::RollingUpdateDeployment;
+#[cfg(feature = "builder")]
+pub use self::rolling_update_deployment::RollingUpdateDeploymentBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::rolling_update_deployment::RollingUpdateDeploymentApplyConfiguration;
 
 mod rolling_update_stateful_set_strategy;
 pub use self::rolling_update_stateful_set_strategy::RollingUpdateStatefulSetStrategy;
+#[cfg(feature = "builder")]
+pub use self::rolling_update_stateful_set_strategy::RollingUpdateStatefulSetStrategyBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::rolling_update_stateful_set_strategy::RollingUpdateStatefulSetStrategyApplyConfiguration;
 
 mod scale;
 pub use self::scale::Scale;
+#[cfg(feature = "builder")]
+pub use self::scale::ScaleBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::scale::ScaleApplyConfiguration;
 #[cfg(feature = "api")] pub use self::scale::{ReadNamespacedDeploymentScaleOptional, ReadNamespacedDeploymentScaleResponse};
 #[cfg(feature = "api")] pub use self::scale::{ReadNamespacedReplicaSetScaleOptional, ReadNamespacedReplicaSetScaleResponse};
 #[cfg(feature = "api")] pub use self::scale::{ReadNamespacedStatefulSetScaleOptional, ReadNamespacedStatefulSetScaleResponse};
 
 mod scale_spec;
 pub use self::scale_spec::ScaleSpec;
+#[cfg(feature = "builder")]
+pub use self::scale_spec::ScaleSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::scale_spec::ScaleSpecApplyConfiguration;
 
 mod scale_status;
 pub use self::scale_status::ScaleStatus;
+#[cfg(feature = "builder")]
+pub use self::scale_status::ScaleStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::scale_status::ScaleStatusApplyConfiguration;
 
 mod stateful_set;
 pub use self::stateful_set::StatefulSet;
+#[cfg(feature = "builder")]
+pub use self::stateful_set::StatefulSetBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::stateful_set::StatefulSetApplyConfiguration;
 #[cfg(feature = "api")] pub use self::stateful_set::{ReadNamespacedStatefulSetOptional, ReadNamespacedStatefulSetResponse};
 #[cfg(feature = "api")] pub use self::stateful_set::{ReadNamespacedStatefulSetStatusOptional, ReadNamespacedStatefulSetStatusResponse};
 
 mod stateful_set_condition;
 pub use self::stateful_set_condition::StatefulSetCondition;
+#[cfg(feature = "builder")]
+pub use self::stateful_set_condition::StatefulSetConditionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::stateful_set_condition::StatefulSetConditionApplyConfiguration;
 
 mod stateful_set_spec;
 pub use self::stateful_set_spec::StatefulSetSpec;
+#[cfg(feature = "builder")]
+pub use self::stateful_set_spec::StatefulSetSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::stateful_set_spec::StatefulSetSpecApplyConfiguration;
 
 mod stateful_set_status;
 pub use self::stateful_set_status::StatefulSetStatus;
+#[cfg(feature = "builder")]
+pub use self::stateful_set_status::StatefulSetStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::stateful_set_status::StatefulSetStatusApplyConfiguration;
 
 mod stateful_set_update_strategy;
 pub use self::stateful_set_update_strategy::StatefulSetUpdateStrategy;
+#[cfg(feature = "builder")]
+pub use self::stateful_set_update_strategy::StatefulSetUpdateStrategyBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::stateful_set_update_strategy::StatefulSetUpdateStrategyApplyConfiguration;
@@ -1,7 +1,8 @@
 // Generated from definition io.k8s.api.apps.v1beta2.ReplicaSet
 
 /// DEPRECATED - This group version of ReplicaSet is deprecated by apps/v1/ReplicaSet. See the release notes for more information. ReplicaSet ensures that a specified number of pod replicas are running at any given time.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[deprecated(note = "This group version of ReplicaSet is deprecated by apps/v1/ReplicaSet.")]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ReplicaSet {
     /// If the Labels of a ReplicaSet are empty, they are defaulted to be the same as the Pod(s) that the ReplicaSet manages. Standard object's metadata. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata
     pub metadata: crate::apimachinery::pkg::apis::meta::v1::ObjectMeta,
@@ -13,6 +14,40 @@ pub struct ReplicaSet {
     pub status: Option<crate::api::apps::v1beta2::ReplicaSetStatus>,
 }
 
+impl ReplicaSet {
+    /// Constructs a `ReplicaSet` from its required fields. Its optional fields are unset.
+    pub fn new(metadata: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        ReplicaSet {
+            metadata: metadata.into(),
+            spec: None,
+            status: None,
+        }
+    }
+}
+
+impl ReplicaSet {
+    /// Returns this value with [`ReplicaSet::metadata`] set to the given value.
+    #[must_use]
+    pub fn with_metadata(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        self.metadata = value.into();
+        self
+    }
+
+    /// Returns this value with [`ReplicaSet::spec`] set to the given value.
+    #[must_use]
+    pub fn with_spec(mut self, value: impl Into<crate::api::apps::v1beta2::ReplicaSetSpec>) -> Self {
+        self.spec = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ReplicaSet::status`] set to the given value.
+    #[must_use]
+    pub fn with_status(mut self, value: impl Into<crate::api::apps::v1beta2::ReplicaSetStatus>) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+}
+
 // Begin apps/v1beta2/ReplicaSet
 
 // Generated from operation createAppsV1beta2NamespacedReplicaSet
@@ -39,6 +74,7 @@ impl ReplicaSet {
         body: &crate::api::apps::v1beta2::ReplicaSet,
         optional: crate::CreateOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::CreateResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/apps/v1beta2/namespaces/{namespace}/replicasets?",
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -82,6 +118,7 @@ impl ReplicaSet {
         delete_optional: crate::DeleteOptional<'_>,
         list_optional: crate::ListOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::DeleteResponse<crate::List<Self>>>), crate::RequestError> {
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/apps/v1beta2/namespaces/{namespace}/replicasets?",
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -125,6 +162,8 @@ impl ReplicaSet {
         namespace: &str,
         optional: crate::DeleteOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::DeleteResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/apps/v1beta2/namespaces/{namespace}/replicasets/{name}",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -163,6 +202,7 @@ impl ReplicaSet {
         namespace: &str,
         optional: crate::ListOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::ListResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/apps/v1beta2/namespaces/{namespace}/replicasets?",
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -240,6 +280,8 @@ impl ReplicaSet {
         body: &crate::apimachinery::pkg::apis::meta::v1::Patch,
         optional: crate::PatchOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::PatchResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/apps/v1beta2/namespaces/{namespace}/replicasets/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -291,6 +333,8 @@ impl ReplicaSet {
         body: &crate::apimachinery::pkg::apis::meta::v1::Patch,
         optional: crate::PatchOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::PatchResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/apps/v1beta2/namespaces/{namespace}/replicasets/{name}/status?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -344,6 +388,8 @@ impl ReplicaSet {
             export,
             pretty,
         } = optional;
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/apps/v1beta2/namespaces/{namespace}/replicasets/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -448,6 +494,8 @@ impl ReplicaSet {
         let ReadNamespacedReplicaSetStatusOptional {
             pretty,
         } = optional;
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/apps/v1beta2/namespaces/{namespace}/replicasets/{name}/status?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -542,6 +590,8 @@ impl ReplicaSet {
         body: &crate::api::apps::v1beta2::ReplicaSet,
         optional: crate::ReplaceOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::ReplaceResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/apps/v1beta2/namespaces/{namespace}/replicasets/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -589,6 +639,8 @@ impl ReplicaSet {
         body: &crate::api::apps::v1beta2::ReplicaSet,
         optional: crate::ReplaceOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::ReplaceResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/apps/v1beta2/namespaces/{namespace}/replicasets/{name}/status?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -630,6 +682,7 @@ impl ReplicaSet {
         namespace: &str,
         optional: crate::WatchOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::WatchResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/apps/v1beta2/namespaces/{namespace}/replicasets?",
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -686,6 +739,9 @@ impl crate::Resource for ReplicaSet {
     const KIND: &'static str = "ReplicaSet";
     const VERSION: &'static str = "v1beta2";
     const URL_PATH_SEGMENT: &'static str = "replicasets";
+    const VERBS: &'static [&'static str] = &["create", "delete", "deletecollection", "get", "list", "patch", "update", "watch"];
+    const SINGULAR: &'static str = "replicaset";
+    const SHORT_NAMES: &'static [&'static str] = &["rs"];
     type Scope = crate::NamespaceResourceScope;
 }
 
@@ -705,45 +761,36 @@ impl crate::Metadata for ReplicaSet {
     }
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ReplicaSet {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_api_version,
-            Key_kind,
-            Key_metadata,
-            Key_spec,
-            Key_status,
-            Other,
+impl std::fmt::Display for ReplicaSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(<Self as crate::Resource>::URL_PATH_SEGMENT)?;
+        for part in [&self.metadata.namespace, &self.metadata.name].iter().copied().flatten() {
+            write!(f, "/{part}")?;
         }
+        Ok(())
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl crate::HasStatusSubresource for ReplicaSet {}
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl crate::Scalable for ReplicaSet {
+    type Scale = crate::api::apps::v1beta2::Scale;
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for ReplicaSet {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "apiVersion" => Field::Key_api_version,
-                            "kind" => Field::Key_kind,
-                            "metadata" => Field::Key_metadata,
-                            "spec" => Field::Key_spec,
-                            "status" => Field::Key_status,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl ReplicaSet {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "kind",
+        "metadata",
+        "spec",
+        "status",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ReplicaSet {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -758,51 +805,125 @@ impl<'de> crate::serde::Deserialize<'de> for ReplicaSet {
                 let mut value_spec: Option<crate::api::apps::v1beta2::ReplicaSetSpec> = None;
                 let mut value_status: Option<crate::api::apps::v1beta2::ReplicaSetStatus> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ReplicaSet", ReplicaSet::FIELDS))? {
                     match key {
-                        Field::Key_api_version => {
+                        Some(0) => {
                             let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_api_version != <Self::Value as crate::Resource>::API_VERSION {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<Self::Value as crate::Resource>::API_VERSION));
                             }
                         },
-                        Field::Key_kind => {
+                        Some(1) => {
                             let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_kind != <Self::Value as crate::Resource>::KIND {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<Self::Value as crate::Resource>::KIND));
                             }
                         },
-                        Field::Key_metadata => value_metadata = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_spec => value_spec = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_status => value_status = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(2) => value_metadata = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_spec = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_status = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ReplicaSet {
-                    metadata: value_metadata.ok_or_else(|| crate::serde::de::Error::missing_field("metadata"))?,
+                    metadata: value_metadata.ok_or_else(|| crate::serde2::missing_field("ReplicaSet", "metadata", &["metadata"]))?,
                     spec: value_spec,
                     status: value_status,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ReplicaSet {
+                    metadata: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    spec: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    status: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             <Self as crate::Resource>::KIND,
-            &[
-                "apiVersion",
-                "kind",
-                "metadata",
-                "spec",
-                "status",
-            ],
+            ReplicaSet::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ReplicaSet> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<ReplicaSet as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_metadata = false;
+                let mut has_spec = false;
+                let mut has_status = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ReplicaSet", ReplicaSet::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <ReplicaSet as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<ReplicaSet as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <ReplicaSet as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<ReplicaSet as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metadata))?; has_metadata = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.spec))?; has_spec = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.status))?; has_status = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_metadata {
+                    return Err(crate::serde2::missing_field("ReplicaSet", "metadata", &["metadata"]));
+                }
+                if !has_spec {
+                    self.0.spec = None;
+                }
+                if !has_status {
+                    self.0.status = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metadata))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.spec))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.status))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            ReplicaSet::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ReplicaSet {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct(<Self as crate::Resource>::KIND, 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", &self.metadata)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "spec", &self.spec)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "status", &self.status)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             <Self as crate::Resource>::KIND,
             3 +
@@ -822,10 +943,102 @@ impl crate::serde::Serialize for ReplicaSet {
     }
 }
 
+/// A builder of [`ReplicaSet`]. Created with [`ReplicaSet::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ReplicaSetBuilder {
+    metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>,
+    spec: Option<crate::api::apps::v1beta2::ReplicaSetSpec>,
+    status: Option<crate::api::apps::v1beta2::ReplicaSetStatus>,
+}
+
+#[cfg(feature = "builder")]
+impl ReplicaSet {
+    /// Starts building a `ReplicaSet`. Its required fields must be set before [`ReplicaSetBuilder::build`] is called.
+    pub fn builder() -> ReplicaSetBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ReplicaSetBuilder {
+    /// Sets [`ReplicaSet::metadata`].
+    pub fn metadata(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        self.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`ReplicaSet::spec`].
+    pub fn spec(mut self, value: impl Into<crate::api::apps::v1beta2::ReplicaSetSpec>) -> Self {
+        self.spec = Some(value.into());
+        self
+    }
+
+    /// Sets [`ReplicaSet::status`].
+    pub fn status(mut self, value: impl Into<crate::api::apps::v1beta2::ReplicaSetStatus>) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Builds the `ReplicaSet`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ReplicaSet, crate::MissingFieldError> {
+        Ok(ReplicaSet {
+            metadata: self.metadata.ok_or_else(|| crate::MissingFieldError::new("ReplicaSet", "metadata"))?,
+            spec: self.spec,
+            status: self.status,
+        })
+    }
+}
+
+/// The apply configuration of [`ReplicaSet`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ReplicaSetApplyConfiguration {
+    /// See [`ReplicaSet::metadata`].
+    pub metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMetaApplyConfiguration>,
+
+    /// See [`ReplicaSet::spec`].
+    pub spec: Option<crate::api::apps::v1beta2::ReplicaSetSpecApplyConfiguration>,
+
+    /// See [`ReplicaSet::status`].
+    pub status: Option<crate::api::apps::v1beta2::ReplicaSetStatusApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ReplicaSetApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            <ReplicaSet as crate::Resource>::KIND,
+            2 +
+            self.metadata.as_ref().map_or(0, |_| 1) +
+            self.spec.as_ref().map_or(0, |_| 1) +
+            self.status.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <ReplicaSet as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <ReplicaSet as crate::Resource>::KIND)?;
+        if let Some(value) = &self.metadata {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", value)?;
+        }
+        if let Some(value) = &self.spec {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "spec", value)?;
+        }
+        if let Some(value) = &self.status {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "status", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ReplicaSet {
     fn schema_name() -> String {
-        "io.k8s.api.apps.v1beta2.ReplicaSet".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::apps::v1beta2::ReplicaSet".to_owned()
+        }
+        else {
+            "io.k8s.api.apps.v1beta2.ReplicaSet".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -836,8 +1049,9 @@ impl crate::schemars::JsonSchema for ReplicaSet {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "apiVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -845,10 +1059,11 @@ impl crate::schemars::JsonSchema for ReplicaSet {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("apps/v1beta2".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "kind".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -856,10 +1071,11 @@ impl crate::schemars::JsonSchema for ReplicaSet {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("ReplicaSet".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "metadata".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>().into_object();
@@ -869,8 +1085,8 @@ impl crate::schemars::JsonSchema for ReplicaSet {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "spec".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::apps::v1beta2::ReplicaSetSpec>().into_object();
@@ -880,8 +1096,8 @@ impl crate::schemars::JsonSchema for ReplicaSet {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "status".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::apps::v1beta2::ReplicaSetStatus>().into_object();
@@ -891,9 +1107,12 @@ impl crate::schemars::JsonSchema for ReplicaSet {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
+                    "apiVersion",
+                    "kind",
                     "metadata",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
                 ..Default::default()
@@ -902,3 +1121,14 @@ impl crate::schemars::JsonSchema for ReplicaSet {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ReplicaSet {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ReplicaSet {
+            metadata: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            spec: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            status: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.apps.v1beta2.ReplicaSetSpec
 
 /// ReplicaSetSpec is the specification of a ReplicaSet.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ReplicaSetSpec {
     /// Minimum number of seconds for which a newly created pod should be ready without any of its container crashing, for it to be considered available. Defaults to 0 (pod will be considered available as soon as it is ready)
     pub min_ready_seconds: Option<i32>,
@@ -16,43 +16,61 @@ pub struct ReplicaSetSpec {
     pub template: Option<crate::api::core::v1::PodTemplateSpec>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ReplicaSetSpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_min_ready_seconds,
-            Key_replicas,
-            Key_selector,
-            Key_template,
-            Other,
+impl ReplicaSetSpec {
+    /// Constructs a `ReplicaSetSpec` from its required fields. Its optional fields are unset.
+    pub fn new(selector: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        ReplicaSetSpec {
+            min_ready_seconds: None,
+            replicas: None,
+            selector: selector.into(),
+            template: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl ReplicaSetSpec {
+    /// Returns this value with [`ReplicaSetSpec::min_ready_seconds`] set to the given value.
+    #[must_use]
+    pub fn with_min_ready_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.min_ready_seconds = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ReplicaSetSpec::replicas`] set to the given value.
+    #[must_use]
+    pub fn with_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.replicas = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ReplicaSetSpec::selector`] set to the given value.
+    #[must_use]
+    pub fn with_selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.selector = value.into();
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "minReadySeconds" => Field::Key_min_ready_seconds,
-                            "replicas" => Field::Key_replicas,
-                            "selector" => Field::Key_selector,
-                            "template" => Field::Key_template,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`ReplicaSetSpec::template`] set to the given value.
+    #[must_use]
+    pub fn with_template(mut self, value: impl Into<crate::api::core::v1::PodTemplateSpec>) -> Self {
+        self.template = Some(value.into());
+        self
+    }
+}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl crate::MergeStrategyInfo for ReplicaSetSpec {}
+
+impl ReplicaSetSpec {
+    const FIELDS: &'static [&'static str] = &[
+        "minReadySeconds",
+        "replicas",
+        "selector",
+        "template",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ReplicaSetSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -68,40 +86,111 @@ impl<'de> crate::serde::Deserialize<'de> for ReplicaSetSpec {
                 let mut value_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> = None;
                 let mut value_template: Option<crate::api::core::v1::PodTemplateSpec> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ReplicaSetSpec", ReplicaSetSpec::FIELDS))? {
                     match key {
-                        Field::Key_min_ready_seconds => value_min_ready_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_replicas => value_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_selector => value_selector = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_template => value_template = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_min_ready_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_selector = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_template = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ReplicaSetSpec {
                     min_ready_seconds: value_min_ready_seconds,
                     replicas: value_replicas,
-                    selector: value_selector.ok_or_else(|| crate::serde::de::Error::missing_field("selector"))?,
+                    selector: value_selector.ok_or_else(|| crate::serde2::missing_field("ReplicaSetSpec", "selector", &["selector"]))?,
                     template: value_template,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ReplicaSetSpec {
+                    min_ready_seconds: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    replicas: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    selector: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    template: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "ReplicaSetSpec",
-            &[
-                "minReadySeconds",
-                "replicas",
-                "selector",
-                "template",
-            ],
+            ReplicaSetSpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ReplicaSetSpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ReplicaSetSpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_min_ready_seconds = false;
+                let mut has_replicas = false;
+                let mut has_selector = false;
+                let mut has_template = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ReplicaSetSpec", ReplicaSetSpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.min_ready_seconds))?; has_min_ready_seconds = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.replicas))?; has_replicas = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.selector))?; has_selector = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.template))?; has_template = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_min_ready_seconds {
+                    self.0.min_ready_seconds = None;
+                }
+                if !has_replicas {
+                    self.0.replicas = None;
+                }
+                if !has_selector {
+                    return Err(crate::serde2::missing_field("ReplicaSetSpec", "selector", &["selector"]));
+                }
+                if !has_template {
+                    self.0.template = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.min_ready_seconds))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.replicas))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.selector))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.template))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ReplicaSetSpec",
+            ReplicaSetSpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ReplicaSetSpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ReplicaSetSpec", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "minReadySeconds", &self.min_ready_seconds)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "replicas", &self.replicas)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selector", &self.selector)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "template", &self.template)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ReplicaSetSpec",
             1 +
@@ -123,10 +212,114 @@ impl crate::serde::Serialize for ReplicaSetSpec {
     }
 }
 
+/// A builder of [`ReplicaSetSpec`]. Created with [`ReplicaSetSpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ReplicaSetSpecBuilder {
+    min_ready_seconds: Option<i32>,
+    replicas: Option<i32>,
+    selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>,
+    template: Option<crate::api::core::v1::PodTemplateSpec>,
+}
+
+#[cfg(feature = "builder")]
+impl ReplicaSetSpec {
+    /// Starts building a `ReplicaSetSpec`. Its required fields must be set before [`ReplicaSetSpecBuilder::build`] is called.
+    pub fn builder() -> ReplicaSetSpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ReplicaSetSpecBuilder {
+    /// Sets [`ReplicaSetSpec::min_ready_seconds`].
+    pub fn min_ready_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.min_ready_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`ReplicaSetSpec::replicas`].
+    pub fn replicas(mut self, value: impl Into<i32>) -> Self {
+        self.replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`ReplicaSetSpec::selector`].
+    pub fn selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`ReplicaSetSpec::template`].
+    pub fn template(mut self, value: impl Into<crate::api::core::v1::PodTemplateSpec>) -> Self {
+        self.template = Some(value.into());
+        self
+    }
+
+    /// Builds the `ReplicaSetSpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ReplicaSetSpec, crate::MissingFieldError> {
+        Ok(ReplicaSetSpec {
+            min_ready_seconds: self.min_ready_seconds,
+            replicas: self.replicas,
+            selector: self.selector.ok_or_else(|| crate::MissingFieldError::new("ReplicaSetSpec", "selector"))?,
+            template: self.template,
+        })
+    }
+}
+
+/// The apply configuration of [`ReplicaSetSpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ReplicaSetSpecApplyConfiguration {
+    /// See [`ReplicaSetSpec::min_ready_seconds`].
+    pub min_ready_seconds: Option<i32>,
+
+    /// See [`ReplicaSetSpec::replicas`].
+    pub replicas: Option<i32>,
+
+    /// See [`ReplicaSetSpec::selector`].
+    pub selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorApplyConfiguration>,
+
+    /// See [`ReplicaSetSpec::template`].
+    pub template: Option<crate::api::core::v1::PodTemplateSpecApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ReplicaSetSpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ReplicaSetSpecApplyConfiguration",
+            self.min_ready_seconds.as_ref().map_or(0, |_| 1) +
+            self.replicas.as_ref().map_or(0, |_| 1) +
+            self.selector.as_ref().map_or(0, |_| 1) +
+            self.template.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.min_ready_seconds {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "minReadySeconds", value)?;
+        }
+        if let Some(value) = &self.replicas {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "replicas", value)?;
+        }
+        if let Some(value) = &self.selector {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selector", value)?;
+        }
+        if let Some(value) = &self.template {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "template", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ReplicaSetSpec {
     fn schema_name() -> String {
-        "io.k8s.api.apps.v1beta2.ReplicaSetSpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::apps::v1beta2::ReplicaSetSpec".to_owned()
+        }
+        else {
+            "io.k8s.api.apps.v1beta2.ReplicaSetSpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -137,8 +330,9 @@ impl crate::schemars::JsonSchema for ReplicaSetSpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "minReadySeconds".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -149,8 +343,8 @@ impl crate::schemars::JsonSchema for ReplicaSetSpec {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "replicas".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -161,8 +355,8 @@ impl crate::schemars::JsonSchema for ReplicaSetSpec {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "selector".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>().into_object();
@@ -172,8 +366,8 @@ impl crate::schemars::JsonSchema for ReplicaSetSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "template".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::PodTemplateSpec>().into_object();
@@ -183,8 +377,9 @@ impl crate::schemars::JsonSchema for ReplicaSetSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "selector",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -194,3 +389,15 @@ impl crate::schemars::JsonSchema for ReplicaSetSpec {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ReplicaSetSpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ReplicaSetSpec {
+            min_ready_seconds: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            replicas: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            selector: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            template: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
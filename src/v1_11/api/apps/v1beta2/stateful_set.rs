@@ -4,7 +4,8 @@
 ///  - Network: A single stable DNS and hostname.
 ///  - Storage: As many VolumeClaims as requested.
 /// The StatefulSet guarantees that a given network identity will always map to the same storage identity.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[deprecated(note = "This group version of StatefulSet is deprecated by apps/v1/StatefulSet.")]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct StatefulSet {
     pub metadata: crate::apimachinery::pkg::apis::meta::v1::ObjectMeta,
 
@@ -15,6 +16,40 @@ pub struct StatefulSet {
     pub status: Option<crate::api::apps::v1beta2::StatefulSetStatus>,
 }
 
+impl StatefulSet {
+    /// Constructs a `StatefulSet` from its required fields. Its optional fields are unset.
+    pub fn new(metadata: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        StatefulSet {
+            metadata: metadata.into(),
+            spec: None,
+            status: None,
+        }
+    }
+}
+
+impl StatefulSet {
+    /// Returns this value with [`StatefulSet::metadata`] set to the given value.
+    #[must_use]
+    pub fn with_metadata(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        self.metadata = value.into();
+        self
+    }
+
+    /// Returns this value with [`StatefulSet::spec`] set to the given value.
+    #[must_use]
+    pub fn with_spec(mut self, value: impl Into<crate::api::apps::v1beta2::StatefulSetSpec>) -> Self {
+        self.spec = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`StatefulSet::status`] set to the given value.
+    #[must_use]
+    pub fn with_status(mut self, value: impl Into<crate::api::apps::v1beta2::StatefulSetStatus>) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+}
+
 // Begin apps/v1beta2/StatefulSet
 
 // Generated from operation createAppsV1beta2NamespacedStatefulSet
@@ -41,6 +76,7 @@ impl StatefulSet {
         body: &crate::api::apps::v1beta2::StatefulSet,
         optional: crate::CreateOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::CreateResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/apps/v1beta2/namespaces/{namespace}/statefulsets?",
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -84,6 +120,7 @@ impl StatefulSet {
         delete_optional: crate::DeleteOptional<'_>,
         list_optional: crate::ListOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::DeleteResponse<crate::List<Self>>>), crate::RequestError> {
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/apps/v1beta2/namespaces/{namespace}/statefulsets?",
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -127,6 +164,8 @@ impl StatefulSet {
         namespace: &str,
         optional: crate::DeleteOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::DeleteResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/apps/v1beta2/namespaces/{namespace}/statefulsets/{name}",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -165,6 +204,7 @@ impl StatefulSet {
         namespace: &str,
         optional: crate::ListOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::ListResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/apps/v1beta2/namespaces/{namespace}/statefulsets?",
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -242,6 +282,8 @@ impl StatefulSet {
         body: &crate::apimachinery::pkg::apis::meta::v1::Patch,
         optional: crate::PatchOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::PatchResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/apps/v1beta2/namespaces/{namespace}/statefulsets/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -293,6 +335,8 @@ impl StatefulSet {
         body: &crate::apimachinery::pkg::apis::meta::v1::Patch,
         optional: crate::PatchOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::PatchResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/apps/v1beta2/namespaces/{namespace}/statefulsets/{name}/status?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -346,6 +390,8 @@ impl StatefulSet {
             export,
             pretty,
         } = optional;
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/apps/v1beta2/namespaces/{namespace}/statefulsets/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -450,6 +496,8 @@ impl StatefulSet {
         let ReadNamespacedStatefulSetStatusOptional {
             pretty,
         } = optional;
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/apps/v1beta2/namespaces/{namespace}/statefulsets/{name}/status?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -544,6 +592,8 @@ impl StatefulSet {
         body: &crate::api::apps::v1beta2::StatefulSet,
         optional: crate::ReplaceOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::ReplaceResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/apps/v1beta2/namespaces/{namespace}/statefulsets/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -591,6 +641,8 @@ impl StatefulSet {
         body: &crate::api::apps::v1beta2::StatefulSet,
         optional: crate::ReplaceOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::ReplaceResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/apps/v1beta2/namespaces/{namespace}/statefulsets/{name}/status?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -632,6 +684,7 @@ impl StatefulSet {
         namespace: &str,
         optional: crate::WatchOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::WatchResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/apps/v1beta2/namespaces/{namespace}/statefulsets?",
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -688,6 +741,9 @@ impl crate::Resource for StatefulSet {
     const KIND: &'static str = "StatefulSet";
     const VERSION: &'static str = "v1beta2";
     const URL_PATH_SEGMENT: &'static str = "statefulsets";
+    const VERBS: &'static [&'static str] = &["create", "delete", "deletecollection", "get", "list", "patch", "update", "watch"];
+    const SINGULAR: &'static str = "statefulset";
+    const SHORT_NAMES: &'static [&'static str] = &["sts"];
     type Scope = crate::NamespaceResourceScope;
 }
 
@@ -707,45 +763,36 @@ impl crate::Metadata for StatefulSet {
     }
 }
 
-impl<'de> crate::serde::Deserialize<'de> for StatefulSet {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_api_version,
-            Key_kind,
-            Key_metadata,
-            Key_spec,
-            Key_status,
-            Other,
+impl std::fmt::Display for StatefulSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(<Self as crate::Resource>::URL_PATH_SEGMENT)?;
+        for part in [&self.metadata.namespace, &self.metadata.name].iter().copied().flatten() {
+            write!(f, "/{part}")?;
         }
+        Ok(())
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl crate::HasStatusSubresource for StatefulSet {}
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl crate::Scalable for StatefulSet {
+    type Scale = crate::api::apps::v1beta2::Scale;
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for StatefulSet {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "apiVersion" => Field::Key_api_version,
-                            "kind" => Field::Key_kind,
-                            "metadata" => Field::Key_metadata,
-                            "spec" => Field::Key_spec,
-                            "status" => Field::Key_status,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl StatefulSet {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "kind",
+        "metadata",
+        "spec",
+        "status",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for StatefulSet {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -760,51 +807,125 @@ impl<'de> crate::serde::Deserialize<'de> for StatefulSet {
                 let mut value_spec: Option<crate::api::apps::v1beta2::StatefulSetSpec> = None;
                 let mut value_status: Option<crate::api::apps::v1beta2::StatefulSetStatus> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("StatefulSet", StatefulSet::FIELDS))? {
                     match key {
-                        Field::Key_api_version => {
+                        Some(0) => {
                             let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_api_version != <Self::Value as crate::Resource>::API_VERSION {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<Self::Value as crate::Resource>::API_VERSION));
                             }
                         },
-                        Field::Key_kind => {
+                        Some(1) => {
                             let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_kind != <Self::Value as crate::Resource>::KIND {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<Self::Value as crate::Resource>::KIND));
                             }
                         },
-                        Field::Key_metadata => value_metadata = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_spec => value_spec = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_status => value_status = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(2) => value_metadata = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_spec = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_status = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(StatefulSet {
-                    metadata: value_metadata.ok_or_else(|| crate::serde::de::Error::missing_field("metadata"))?,
+                    metadata: value_metadata.ok_or_else(|| crate::serde2::missing_field("StatefulSet", "metadata", &["metadata"]))?,
                     spec: value_spec,
                     status: value_status,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(StatefulSet {
+                    metadata: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    spec: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    status: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             <Self as crate::Resource>::KIND,
-            &[
-                "apiVersion",
-                "kind",
-                "metadata",
-                "spec",
-                "status",
-            ],
+            StatefulSet::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, StatefulSet> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<StatefulSet as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_metadata = false;
+                let mut has_spec = false;
+                let mut has_status = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("StatefulSet", StatefulSet::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <StatefulSet as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<StatefulSet as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <StatefulSet as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<StatefulSet as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metadata))?; has_metadata = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.spec))?; has_spec = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.status))?; has_status = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_metadata {
+                    return Err(crate::serde2::missing_field("StatefulSet", "metadata", &["metadata"]));
+                }
+                if !has_spec {
+                    self.0.spec = None;
+                }
+                if !has_status {
+                    self.0.status = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metadata))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.spec))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.status))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            StatefulSet::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for StatefulSet {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct(<Self as crate::Resource>::KIND, 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", &self.metadata)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "spec", &self.spec)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "status", &self.status)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             <Self as crate::Resource>::KIND,
             3 +
@@ -824,10 +945,102 @@ impl crate::serde::Serialize for StatefulSet {
     }
 }
 
+/// A builder of [`StatefulSet`]. Created with [`StatefulSet::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct StatefulSetBuilder {
+    metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>,
+    spec: Option<crate::api::apps::v1beta2::StatefulSetSpec>,
+    status: Option<crate::api::apps::v1beta2::StatefulSetStatus>,
+}
+
+#[cfg(feature = "builder")]
+impl StatefulSet {
+    /// Starts building a `StatefulSet`. Its required fields must be set before [`StatefulSetBuilder::build`] is called.
+    pub fn builder() -> StatefulSetBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl StatefulSetBuilder {
+    /// Sets [`StatefulSet::metadata`].
+    pub fn metadata(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        self.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`StatefulSet::spec`].
+    pub fn spec(mut self, value: impl Into<crate::api::apps::v1beta2::StatefulSetSpec>) -> Self {
+        self.spec = Some(value.into());
+        self
+    }
+
+    /// Sets [`StatefulSet::status`].
+    pub fn status(mut self, value: impl Into<crate::api::apps::v1beta2::StatefulSetStatus>) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Builds the `StatefulSet`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<StatefulSet, crate::MissingFieldError> {
+        Ok(StatefulSet {
+            metadata: self.metadata.ok_or_else(|| crate::MissingFieldError::new("StatefulSet", "metadata"))?,
+            spec: self.spec,
+            status: self.status,
+        })
+    }
+}
+
+/// The apply configuration of [`StatefulSet`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StatefulSetApplyConfiguration {
+    /// See [`StatefulSet::metadata`].
+    pub metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMetaApplyConfiguration>,
+
+    /// See [`StatefulSet::spec`].
+    pub spec: Option<crate::api::apps::v1beta2::StatefulSetSpecApplyConfiguration>,
+
+    /// See [`StatefulSet::status`].
+    pub status: Option<crate::api::apps::v1beta2::StatefulSetStatusApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for StatefulSetApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            <StatefulSet as crate::Resource>::KIND,
+            2 +
+            self.metadata.as_ref().map_or(0, |_| 1) +
+            self.spec.as_ref().map_or(0, |_| 1) +
+            self.status.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <StatefulSet as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <StatefulSet as crate::Resource>::KIND)?;
+        if let Some(value) = &self.metadata {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", value)?;
+        }
+        if let Some(value) = &self.spec {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "spec", value)?;
+        }
+        if let Some(value) = &self.status {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "status", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for StatefulSet {
     fn schema_name() -> String {
-        "io.k8s.api.apps.v1beta2.StatefulSet".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::apps::v1beta2::StatefulSet".to_owned()
+        }
+        else {
+            "io.k8s.api.apps.v1beta2.StatefulSet".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -838,8 +1051,9 @@ impl crate::schemars::JsonSchema for StatefulSet {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "apiVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -847,10 +1061,11 @@ impl crate::schemars::JsonSchema for StatefulSet {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("apps/v1beta2".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "kind".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -858,14 +1073,15 @@ impl crate::schemars::JsonSchema for StatefulSet {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("StatefulSet".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "metadata".to_owned(),
                         __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>(),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "spec".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::apps::v1beta2::StatefulSetSpec>().into_object();
@@ -875,8 +1091,8 @@ impl crate::schemars::JsonSchema for StatefulSet {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "status".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::apps::v1beta2::StatefulSetStatus>().into_object();
@@ -886,9 +1102,12 @@ impl crate::schemars::JsonSchema for StatefulSet {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
+                    "apiVersion",
+                    "kind",
                     "metadata",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
                 ..Default::default()
@@ -897,3 +1116,14 @@ impl crate::schemars::JsonSchema for StatefulSet {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for StatefulSet {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(StatefulSet {
+            metadata: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            spec: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            status: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.apps.v1beta2.StatefulSetCondition
 
 /// StatefulSetCondition describes the state of a statefulset at a certain point.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct StatefulSetCondition {
     /// Last time the condition transitioned from one status to another.
     pub last_transition_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
@@ -19,45 +19,70 @@ pub struct StatefulSetCondition {
     pub type_: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for StatefulSetCondition {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_last_transition_time,
-            Key_message,
-            Key_reason,
-            Key_status,
-            Key_type_,
-            Other,
+impl StatefulSetCondition {
+    /// Constructs a `StatefulSetCondition` from its required fields. Its optional fields are unset.
+    pub fn new(status: impl Into<String>, type_: impl Into<String>) -> Self {
+        StatefulSetCondition {
+            last_transition_time: None,
+            message: None,
+            reason: None,
+            status: status.into(),
+            type_: type_.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl StatefulSetCondition {
+    /// Returns this value with [`StatefulSetCondition::last_transition_time`] set to the given value.
+    #[must_use]
+    pub fn with_last_transition_time(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.last_transition_time = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`StatefulSetCondition::message`] set to the given value.
+    #[must_use]
+    pub fn with_message(mut self, value: impl Into<String>) -> Self {
+        self.message = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`StatefulSetCondition::reason`] set to the given value.
+    #[must_use]
+    pub fn with_reason(mut self, value: impl Into<String>) -> Self {
+        self.reason = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "lastTransitionTime" => Field::Key_last_transition_time,
-                            "message" => Field::Key_message,
-                            "reason" => Field::Key_reason,
-                            "status" => Field::Key_status,
-                            "type" => Field::Key_type_,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`StatefulSetCondition::status`] set to the given value.
+    #[must_use]
+    pub fn with_status(mut self, value: impl Into<String>) -> Self {
+        self.status = value.into();
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`StatefulSetCondition::type_`] set to the given value.
+    #[must_use]
+    pub fn with_type(mut self, value: impl Into<String>) -> Self {
+        self.type_ = value.into();
+        self
+    }
+}
 
+impl crate::MergeStrategyInfo for StatefulSetCondition {}
+
+impl StatefulSetCondition {
+    const FIELDS: &'static [&'static str] = &[
+        "lastTransitionTime",
+        "message",
+        "reason",
+        "status",
+        "type",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for StatefulSetCondition {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -74,14 +99,14 @@ impl<'de> crate::serde::Deserialize<'de> for StatefulSetCondition {
                 let mut value_status: Option<String> = None;
                 let mut value_type_: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("StatefulSetCondition", StatefulSetCondition::FIELDS))? {
                     match key {
-                        Field::Key_last_transition_time => value_last_transition_time = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_message => value_message = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_reason => value_reason = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_status => value_status = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_type_ => value_type_ = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_last_transition_time = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_message = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_reason = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_status = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(4) => value_type_ = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -89,28 +114,106 @@ impl<'de> crate::serde::Deserialize<'de> for StatefulSetCondition {
                     last_transition_time: value_last_transition_time,
                     message: value_message,
                     reason: value_reason,
-                    status: value_status.ok_or_else(|| crate::serde::de::Error::missing_field("status"))?,
-                    type_: value_type_.ok_or_else(|| crate::serde::de::Error::missing_field("type"))?,
+                    status: value_status.ok_or_else(|| crate::serde2::missing_field("StatefulSetCondition", "status", &["status", "type"]))?,
+                    type_: value_type_.ok_or_else(|| crate::serde2::missing_field("StatefulSetCondition", "type", &["status", "type"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(StatefulSetCondition {
+                    last_transition_time: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    message: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    reason: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    status: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    type_: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "StatefulSetCondition",
-            &[
-                "lastTransitionTime",
-                "message",
-                "reason",
-                "status",
-                "type",
-            ],
+            StatefulSetCondition::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, StatefulSetCondition> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("StatefulSetCondition")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_last_transition_time = false;
+                let mut has_message = false;
+                let mut has_reason = false;
+                let mut has_status = false;
+                let mut has_type_ = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("StatefulSetCondition", StatefulSetCondition::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.last_transition_time))?; has_last_transition_time = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.message))?; has_message = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.reason))?; has_reason = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.status))?; has_status = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.type_))?; has_type_ = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_last_transition_time {
+                    self.0.last_transition_time = None;
+                }
+                if !has_message {
+                    self.0.message = None;
+                }
+                if !has_reason {
+                    self.0.reason = None;
+                }
+                if !has_status {
+                    return Err(crate::serde2::missing_field("StatefulSetCondition", "status", &["status", "type"]));
+                }
+                if !has_type_ {
+                    return Err(crate::serde2::missing_field("StatefulSetCondition", "type", &["status", "type"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.last_transition_time))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.message))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.reason))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.status))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.type_))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "StatefulSetCondition",
+            StatefulSetCondition::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for StatefulSetCondition {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("StatefulSetCondition", 5)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "lastTransitionTime", &self.last_transition_time)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "message", &self.message)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reason", &self.reason)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "status", &self.status)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "type", &self.type_)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "StatefulSetCondition",
             2 +
@@ -133,10 +236,129 @@ impl crate::serde::Serialize for StatefulSetCondition {
     }
 }
 
+/// A builder of [`StatefulSetCondition`]. Created with [`StatefulSetCondition::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct StatefulSetConditionBuilder {
+    last_transition_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+    message: Option<String>,
+    reason: Option<String>,
+    status: Option<String>,
+    type_: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl StatefulSetCondition {
+    /// Starts building a `StatefulSetCondition`. Its required fields must be set before [`StatefulSetConditionBuilder::build`] is called.
+    pub fn builder() -> StatefulSetConditionBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl StatefulSetConditionBuilder {
+    /// Sets [`StatefulSetCondition::last_transition_time`].
+    pub fn last_transition_time(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.last_transition_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`StatefulSetCondition::message`].
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.message = Some(value.into());
+        self
+    }
+
+    /// Sets [`StatefulSetCondition::reason`].
+    pub fn reason(mut self, value: impl Into<String>) -> Self {
+        self.reason = Some(value.into());
+        self
+    }
+
+    /// Sets [`StatefulSetCondition::status`].
+    pub fn status(mut self, value: impl Into<String>) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Sets [`StatefulSetCondition::type_`].
+    pub fn type_(mut self, value: impl Into<String>) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `StatefulSetCondition`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<StatefulSetCondition, crate::MissingFieldError> {
+        Ok(StatefulSetCondition {
+            last_transition_time: self.last_transition_time,
+            message: self.message,
+            reason: self.reason,
+            status: self.status.ok_or_else(|| crate::MissingFieldError::new("StatefulSetCondition", "status"))?,
+            type_: self.type_.ok_or_else(|| crate::MissingFieldError::new("StatefulSetCondition", "type"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`StatefulSetCondition`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StatefulSetConditionApplyConfiguration {
+    /// See [`StatefulSetCondition::last_transition_time`].
+    pub last_transition_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+
+    /// See [`StatefulSetCondition::message`].
+    pub message: Option<String>,
+
+    /// See [`StatefulSetCondition::reason`].
+    pub reason: Option<String>,
+
+    /// See [`StatefulSetCondition::status`].
+    pub status: Option<String>,
+
+    /// See [`StatefulSetCondition::type_`].
+    pub type_: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for StatefulSetConditionApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "StatefulSetConditionApplyConfiguration",
+            self.last_transition_time.as_ref().map_or(0, |_| 1) +
+            self.message.as_ref().map_or(0, |_| 1) +
+            self.reason.as_ref().map_or(0, |_| 1) +
+            self.status.as_ref().map_or(0, |_| 1) +
+            self.type_.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.last_transition_time {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "lastTransitionTime", value)?;
+        }
+        if let Some(value) = &self.message {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "message", value)?;
+        }
+        if let Some(value) = &self.reason {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reason", value)?;
+        }
+        if let Some(value) = &self.status {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "status", value)?;
+        }
+        if let Some(value) = &self.type_ {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "type", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for StatefulSetCondition {
     fn schema_name() -> String {
-        "io.k8s.api.apps.v1beta2.StatefulSetCondition".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::apps::v1beta2::StatefulSetCondition".to_owned()
+        }
+        else {
+            "io.k8s.api.apps.v1beta2.StatefulSetCondition".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -147,8 +369,9 @@ impl crate::schemars::JsonSchema for StatefulSetCondition {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "lastTransitionTime".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::Time>().into_object();
@@ -158,8 +381,8 @@ impl crate::schemars::JsonSchema for StatefulSetCondition {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "message".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -169,8 +392,8 @@ impl crate::schemars::JsonSchema for StatefulSetCondition {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "reason".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -180,8 +403,8 @@ impl crate::schemars::JsonSchema for StatefulSetCondition {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "status".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -191,8 +414,8 @@ impl crate::schemars::JsonSchema for StatefulSetCondition {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "type".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -202,8 +425,9 @@ impl crate::schemars::JsonSchema for StatefulSetCondition {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "status",
                     "type",
@@ -214,3 +438,16 @@ impl crate::schemars::JsonSchema for StatefulSetCondition {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for StatefulSetCondition {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(StatefulSetCondition {
+            last_transition_time: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            message: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            reason: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            status: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            type_: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
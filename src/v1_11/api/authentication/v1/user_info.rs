@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.authentication.v1.UserInfo
 
 /// UserInfo holds the information about the user needed to implement the user.Info interface.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct UserInfo {
     /// Any additional information provided by the authenticator.
     pub extra: Option<std::collections::BTreeMap<String, Vec<String>>>,
@@ -16,43 +16,49 @@ pub struct UserInfo {
     pub username: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for UserInfo {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_extra,
-            Key_groups,
-            Key_uid,
-            Key_username,
-            Other,
-        }
+impl UserInfo {
+    /// Returns this value with [`UserInfo::extra`] set to the given value.
+    #[must_use]
+    pub fn with_extra(mut self, value: impl Into<std::collections::BTreeMap<String, Vec<String>>>) -> Self {
+        self.extra = Some(value.into());
+        self
+    }
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+    /// Returns this value with [`UserInfo::groups`] set to the given value.
+    #[must_use]
+    pub fn with_groups(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.groups = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`UserInfo::uid`] set to the given value.
+    #[must_use]
+    pub fn with_uid(mut self, value: impl Into<String>) -> Self {
+        self.uid = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`UserInfo::username`] set to the given value.
+    #[must_use]
+    pub fn with_username(mut self, value: impl Into<String>) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "extra" => Field::Key_extra,
-                            "groups" => Field::Key_groups,
-                            "uid" => Field::Key_uid,
-                            "username" => Field::Key_username,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for UserInfo {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl UserInfo {
+    const FIELDS: &'static [&'static str] = &[
+        "extra",
+        "groups",
+        "uid",
+        "username",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for UserInfo {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -68,13 +74,13 @@ impl<'de> crate::serde::Deserialize<'de> for UserInfo {
                 let mut value_uid: Option<String> = None;
                 let mut value_username: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("UserInfo", UserInfo::FIELDS))? {
                     match key {
-                        Field::Key_extra => value_extra = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_groups => value_groups = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_uid => value_uid = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_username => value_username = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_extra = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_groups = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_uid = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_username = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -85,23 +91,94 @@ impl<'de> crate::serde::Deserialize<'de> for UserInfo {
                     username: value_username,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(UserInfo {
+                    extra: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    groups: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    uid: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    username: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "UserInfo",
-            &[
-                "extra",
-                "groups",
-                "uid",
-                "username",
-            ],
+            UserInfo::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, UserInfo> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("UserInfo")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_extra = false;
+                let mut has_groups = false;
+                let mut has_uid = false;
+                let mut has_username = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("UserInfo", UserInfo::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.extra))?; has_extra = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.groups))?; has_groups = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.uid))?; has_uid = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.username))?; has_username = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_extra {
+                    self.0.extra = None;
+                }
+                if !has_groups {
+                    self.0.groups = None;
+                }
+                if !has_uid {
+                    self.0.uid = None;
+                }
+                if !has_username {
+                    self.0.username = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.extra))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.groups))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.uid))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.username))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "UserInfo",
+            UserInfo::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for UserInfo {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("UserInfo", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "extra", &self.extra)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "groups", &self.groups)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "uid", &self.uid)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "username", &self.username)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "UserInfo",
             self.extra.as_ref().map_or(0, |_| 1) +
@@ -125,10 +202,114 @@ impl crate::serde::Serialize for UserInfo {
     }
 }
 
+/// A builder of [`UserInfo`]. Created with [`UserInfo::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct UserInfoBuilder {
+    extra: Option<std::collections::BTreeMap<String, Vec<String>>>,
+    groups: Option<Vec<String>>,
+    uid: Option<String>,
+    username: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl UserInfo {
+    /// Starts building a `UserInfo`. Its required fields must be set before [`UserInfoBuilder::build`] is called.
+    pub fn builder() -> UserInfoBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl UserInfoBuilder {
+    /// Sets [`UserInfo::extra`].
+    pub fn extra(mut self, value: impl Into<std::collections::BTreeMap<String, Vec<String>>>) -> Self {
+        self.extra = Some(value.into());
+        self
+    }
+
+    /// Sets [`UserInfo::groups`].
+    pub fn groups(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.groups = Some(value.into());
+        self
+    }
+
+    /// Sets [`UserInfo::uid`].
+    pub fn uid(mut self, value: impl Into<String>) -> Self {
+        self.uid = Some(value.into());
+        self
+    }
+
+    /// Sets [`UserInfo::username`].
+    pub fn username(mut self, value: impl Into<String>) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `UserInfo`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<UserInfo, crate::MissingFieldError> {
+        Ok(UserInfo {
+            extra: self.extra,
+            groups: self.groups,
+            uid: self.uid,
+            username: self.username,
+        })
+    }
+}
+
+/// The apply configuration of [`UserInfo`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UserInfoApplyConfiguration {
+    /// See [`UserInfo::extra`].
+    pub extra: Option<std::collections::BTreeMap<String, Vec<String>>>,
+
+    /// See [`UserInfo::groups`].
+    pub groups: Option<Vec<String>>,
+
+    /// See [`UserInfo::uid`].
+    pub uid: Option<String>,
+
+    /// See [`UserInfo::username`].
+    pub username: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for UserInfoApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "UserInfoApplyConfiguration",
+            self.extra.as_ref().map_or(0, |_| 1) +
+            self.groups.as_ref().map_or(0, |_| 1) +
+            self.uid.as_ref().map_or(0, |_| 1) +
+            self.username.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.extra {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "extra", value)?;
+        }
+        if let Some(value) = &self.groups {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "groups", value)?;
+        }
+        if let Some(value) = &self.uid {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "uid", value)?;
+        }
+        if let Some(value) = &self.username {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "username", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for UserInfo {
     fn schema_name() -> String {
-        "io.k8s.api.authentication.v1.UserInfo".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::authentication::v1::UserInfo".to_owned()
+        }
+        else {
+            "io.k8s.api.authentication.v1.UserInfo".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -139,8 +320,9 @@ impl crate::schemars::JsonSchema for UserInfo {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "extra".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -168,8 +350,8 @@ impl crate::schemars::JsonSchema for UserInfo {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "groups".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -188,8 +370,8 @@ impl crate::schemars::JsonSchema for UserInfo {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "uid".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -199,8 +381,8 @@ impl crate::schemars::JsonSchema for UserInfo {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "username".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -210,11 +392,24 @@ impl crate::schemars::JsonSchema for UserInfo {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for UserInfo {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(UserInfo {
+            extra: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)))?,
+            groups: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            uid: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            username: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.authentication.v1beta1.TokenReviewStatus
 
 /// TokenReviewStatus is the result of the token authentication request.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct TokenReviewStatus {
     /// Authenticated indicates that the token was associated with a known user.
     pub authenticated: Option<bool>,
@@ -13,41 +13,41 @@ pub struct TokenReviewStatus {
     pub user: Option<crate::api::authentication::v1beta1::UserInfo>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for TokenReviewStatus {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_authenticated,
-            Key_error,
-            Key_user,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl TokenReviewStatus {
+    /// Returns this value with [`TokenReviewStatus::authenticated`] set to the given value.
+    #[must_use]
+    pub fn with_authenticated(mut self, value: impl Into<bool>) -> Self {
+        self.authenticated = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`TokenReviewStatus::error`] set to the given value.
+    #[must_use]
+    pub fn with_error(mut self, value: impl Into<String>) -> Self {
+        self.error = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`TokenReviewStatus::user`] set to the given value.
+    #[must_use]
+    pub fn with_user(mut self, value: impl Into<crate::api::authentication::v1beta1::UserInfo>) -> Self {
+        self.user = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "authenticated" => Field::Key_authenticated,
-                            "error" => Field::Key_error,
-                            "user" => Field::Key_user,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for TokenReviewStatus {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl TokenReviewStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "authenticated",
+        "error",
+        "user",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for TokenReviewStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,12 +62,12 @@ impl<'de> crate::serde::Deserialize<'de> for TokenReviewStatus {
                 let mut value_error: Option<String> = None;
                 let mut value_user: Option<crate::api::authentication::v1beta1::UserInfo> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("TokenReviewStatus", TokenReviewStatus::FIELDS))? {
                     match key {
-                        Field::Key_authenticated => value_authenticated = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_error => value_error = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_user => value_user = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_authenticated = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_error = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_user = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -77,22 +77,86 @@ impl<'de> crate::serde::Deserialize<'de> for TokenReviewStatus {
                     user: value_user,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(TokenReviewStatus {
+                    authenticated: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    error: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    user: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "TokenReviewStatus",
-            &[
-                "authenticated",
-                "error",
-                "user",
-            ],
+            TokenReviewStatus::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, TokenReviewStatus> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("TokenReviewStatus")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_authenticated = false;
+                let mut has_error = false;
+                let mut has_user = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("TokenReviewStatus", TokenReviewStatus::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.authenticated))?; has_authenticated = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.error))?; has_error = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.user))?; has_user = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_authenticated {
+                    self.0.authenticated = None;
+                }
+                if !has_error {
+                    self.0.error = None;
+                }
+                if !has_user {
+                    self.0.user = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.authenticated))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.error))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.user))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "TokenReviewStatus",
+            TokenReviewStatus::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for TokenReviewStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("TokenReviewStatus", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "authenticated", &self.authenticated)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "error", &self.error)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "user", &self.user)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "TokenReviewStatus",
             self.authenticated.as_ref().map_or(0, |_| 1) +
@@ -112,10 +176,99 @@ impl crate::serde::Serialize for TokenReviewStatus {
     }
 }
 
+/// A builder of [`TokenReviewStatus`]. Created with [`TokenReviewStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct TokenReviewStatusBuilder {
+    authenticated: Option<bool>,
+    error: Option<String>,
+    user: Option<crate::api::authentication::v1beta1::UserInfo>,
+}
+
+#[cfg(feature = "builder")]
+impl TokenReviewStatus {
+    /// Starts building a `TokenReviewStatus`. Its required fields must be set before [`TokenReviewStatusBuilder::build`] is called.
+    pub fn builder() -> TokenReviewStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl TokenReviewStatusBuilder {
+    /// Sets [`TokenReviewStatus::authenticated`].
+    pub fn authenticated(mut self, value: impl Into<bool>) -> Self {
+        self.authenticated = Some(value.into());
+        self
+    }
+
+    /// Sets [`TokenReviewStatus::error`].
+    pub fn error(mut self, value: impl Into<String>) -> Self {
+        self.error = Some(value.into());
+        self
+    }
+
+    /// Sets [`TokenReviewStatus::user`].
+    pub fn user(mut self, value: impl Into<crate::api::authentication::v1beta1::UserInfo>) -> Self {
+        self.user = Some(value.into());
+        self
+    }
+
+    /// Builds the `TokenReviewStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<TokenReviewStatus, crate::MissingFieldError> {
+        Ok(TokenReviewStatus {
+            authenticated: self.authenticated,
+            error: self.error,
+            user: self.user,
+        })
+    }
+}
+
+/// The apply configuration of [`TokenReviewStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TokenReviewStatusApplyConfiguration {
+    /// See [`TokenReviewStatus::authenticated`].
+    pub authenticated: Option<bool>,
+
+    /// See [`TokenReviewStatus::error`].
+    pub error: Option<String>,
+
+    /// See [`TokenReviewStatus::user`].
+    pub user: Option<crate::api::authentication::v1beta1::UserInfoApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for TokenReviewStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "TokenReviewStatusApplyConfiguration",
+            self.authenticated.as_ref().map_or(0, |_| 1) +
+            self.error.as_ref().map_or(0, |_| 1) +
+            self.user.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.authenticated {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "authenticated", value)?;
+        }
+        if let Some(value) = &self.error {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "error", value)?;
+        }
+        if let Some(value) = &self.user {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "user", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for TokenReviewStatus {
     fn schema_name() -> String {
-        "io.k8s.api.authentication.v1beta1.TokenReviewStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::authentication::v1beta1::TokenReviewStatus".to_owned()
+        }
+        else {
+            "io.k8s.api.authentication.v1beta1.TokenReviewStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -126,8 +279,9 @@ impl crate::schemars::JsonSchema for TokenReviewStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "authenticated".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -137,8 +291,8 @@ impl crate::schemars::JsonSchema for TokenReviewStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "error".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -148,8 +302,8 @@ impl crate::schemars::JsonSchema for TokenReviewStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "user".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::authentication::v1beta1::UserInfo>().into_object();
@@ -159,11 +313,23 @@ impl crate::schemars::JsonSchema for TokenReviewStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for TokenReviewStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(TokenReviewStatus {
+            authenticated: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            error: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            user: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
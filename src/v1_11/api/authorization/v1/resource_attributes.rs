@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.authorization.v1.ResourceAttributes
 
 /// ResourceAttributes includes the authorization attributes available for resource requests to the Authorizer interface
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ResourceAttributes {
     /// Group is the API Group of the Resource.  "*" means all.
     pub group: Option<String>,
@@ -25,49 +25,73 @@ pub struct ResourceAttributes {
     pub version: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ResourceAttributes {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_group,
-            Key_name,
-            Key_namespace,
-            Key_resource,
-            Key_subresource,
-            Key_verb,
-            Key_version,
-            Other,
-        }
+impl ResourceAttributes {
+    /// Returns this value with [`ResourceAttributes::group`] set to the given value.
+    #[must_use]
+    pub fn with_group(mut self, value: impl Into<String>) -> Self {
+        self.group = Some(value.into());
+        self
+    }
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+    /// Returns this value with [`ResourceAttributes::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ResourceAttributes::namespace`] set to the given value.
+    #[must_use]
+    pub fn with_namespace(mut self, value: impl Into<String>) -> Self {
+        self.namespace = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ResourceAttributes::resource`] set to the given value.
+    #[must_use]
+    pub fn with_resource(mut self, value: impl Into<String>) -> Self {
+        self.resource = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "group" => Field::Key_group,
-                            "name" => Field::Key_name,
-                            "namespace" => Field::Key_namespace,
-                            "resource" => Field::Key_resource,
-                            "subresource" => Field::Key_subresource,
-                            "verb" => Field::Key_verb,
-                            "version" => Field::Key_version,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`ResourceAttributes::subresource`] set to the given value.
+    #[must_use]
+    pub fn with_subresource(mut self, value: impl Into<String>) -> Self {
+        self.subresource = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`ResourceAttributes::verb`] set to the given value.
+    #[must_use]
+    pub fn with_verb(mut self, value: impl Into<String>) -> Self {
+        self.verb = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ResourceAttributes::version`] set to the given value.
+    #[must_use]
+    pub fn with_version(mut self, value: impl Into<String>) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+}
 
+impl crate::MergeStrategyInfo for ResourceAttributes {}
+
+impl ResourceAttributes {
+    const FIELDS: &'static [&'static str] = &[
+        "group",
+        "name",
+        "namespace",
+        "resource",
+        "subresource",
+        "verb",
+        "version",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for ResourceAttributes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -86,16 +110,16 @@ impl<'de> crate::serde::Deserialize<'de> for ResourceAttributes {
                 let mut value_verb: Option<String> = None;
                 let mut value_version: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ResourceAttributes", ResourceAttributes::FIELDS))? {
                     match key {
-                        Field::Key_group => value_group = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_name => value_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_namespace => value_namespace = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_resource => value_resource = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_subresource => value_subresource = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_verb => value_verb = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_version => value_version = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_group = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_namespace = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_resource = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_subresource = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_verb = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_version = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -109,26 +133,118 @@ impl<'de> crate::serde::Deserialize<'de> for ResourceAttributes {
                     version: value_version,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ResourceAttributes {
+                    group: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    namespace: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    resource: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    subresource: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    verb: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    version: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "ResourceAttributes",
-            &[
-                "group",
-                "name",
-                "namespace",
-                "resource",
-                "subresource",
-                "verb",
-                "version",
-            ],
+            ResourceAttributes::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ResourceAttributes> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ResourceAttributes")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_group = false;
+                let mut has_name = false;
+                let mut has_namespace = false;
+                let mut has_resource = false;
+                let mut has_subresource = false;
+                let mut has_verb = false;
+                let mut has_version = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ResourceAttributes", ResourceAttributes::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.group))?; has_group = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.name))?; has_name = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.namespace))?; has_namespace = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.resource))?; has_resource = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.subresource))?; has_subresource = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.verb))?; has_verb = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.version))?; has_version = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_group {
+                    self.0.group = None;
+                }
+                if !has_name {
+                    self.0.name = None;
+                }
+                if !has_namespace {
+                    self.0.namespace = None;
+                }
+                if !has_resource {
+                    self.0.resource = None;
+                }
+                if !has_subresource {
+                    self.0.subresource = None;
+                }
+                if !has_verb {
+                    self.0.verb = None;
+                }
+                if !has_version {
+                    self.0.version = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.group))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.namespace))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.resource))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.subresource))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.verb))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.version))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ResourceAttributes",
+            ResourceAttributes::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ResourceAttributes {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ResourceAttributes", 7)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "group", &self.group)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "namespace", &self.namespace)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resource", &self.resource)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "subresource", &self.subresource)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "verb", &self.verb)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "version", &self.version)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ResourceAttributes",
             self.group.as_ref().map_or(0, |_| 1) +
@@ -164,10 +280,159 @@ impl crate::serde::Serialize for ResourceAttributes {
     }
 }
 
+/// A builder of [`ResourceAttributes`]. Created with [`ResourceAttributes::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ResourceAttributesBuilder {
+    group: Option<String>,
+    name: Option<String>,
+    namespace: Option<String>,
+    resource: Option<String>,
+    subresource: Option<String>,
+    verb: Option<String>,
+    version: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl ResourceAttributes {
+    /// Starts building a `ResourceAttributes`. Its required fields must be set before [`ResourceAttributesBuilder::build`] is called.
+    pub fn builder() -> ResourceAttributesBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ResourceAttributesBuilder {
+    /// Sets [`ResourceAttributes::group`].
+    pub fn group(mut self, value: impl Into<String>) -> Self {
+        self.group = Some(value.into());
+        self
+    }
+
+    /// Sets [`ResourceAttributes::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`ResourceAttributes::namespace`].
+    pub fn namespace(mut self, value: impl Into<String>) -> Self {
+        self.namespace = Some(value.into());
+        self
+    }
+
+    /// Sets [`ResourceAttributes::resource`].
+    pub fn resource(mut self, value: impl Into<String>) -> Self {
+        self.resource = Some(value.into());
+        self
+    }
+
+    /// Sets [`ResourceAttributes::subresource`].
+    pub fn subresource(mut self, value: impl Into<String>) -> Self {
+        self.subresource = Some(value.into());
+        self
+    }
+
+    /// Sets [`ResourceAttributes::verb`].
+    pub fn verb(mut self, value: impl Into<String>) -> Self {
+        self.verb = Some(value.into());
+        self
+    }
+
+    /// Sets [`ResourceAttributes::version`].
+    pub fn version(mut self, value: impl Into<String>) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Builds the `ResourceAttributes`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ResourceAttributes, crate::MissingFieldError> {
+        Ok(ResourceAttributes {
+            group: self.group,
+            name: self.name,
+            namespace: self.namespace,
+            resource: self.resource,
+            subresource: self.subresource,
+            verb: self.verb,
+            version: self.version,
+        })
+    }
+}
+
+/// The apply configuration of [`ResourceAttributes`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ResourceAttributesApplyConfiguration {
+    /// See [`ResourceAttributes::group`].
+    pub group: Option<String>,
+
+    /// See [`ResourceAttributes::name`].
+    pub name: Option<String>,
+
+    /// See [`ResourceAttributes::namespace`].
+    pub namespace: Option<String>,
+
+    /// See [`ResourceAttributes::resource`].
+    pub resource: Option<String>,
+
+    /// See [`ResourceAttributes::subresource`].
+    pub subresource: Option<String>,
+
+    /// See [`ResourceAttributes::verb`].
+    pub verb: Option<String>,
+
+    /// See [`ResourceAttributes::version`].
+    pub version: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ResourceAttributesApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ResourceAttributesApplyConfiguration",
+            self.group.as_ref().map_or(0, |_| 1) +
+            self.name.as_ref().map_or(0, |_| 1) +
+            self.namespace.as_ref().map_or(0, |_| 1) +
+            self.resource.as_ref().map_or(0, |_| 1) +
+            self.subresource.as_ref().map_or(0, |_| 1) +
+            self.verb.as_ref().map_or(0, |_| 1) +
+            self.version.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.group {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "group", value)?;
+        }
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        if let Some(value) = &self.namespace {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "namespace", value)?;
+        }
+        if let Some(value) = &self.resource {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resource", value)?;
+        }
+        if let Some(value) = &self.subresource {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "subresource", value)?;
+        }
+        if let Some(value) = &self.verb {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "verb", value)?;
+        }
+        if let Some(value) = &self.version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "version", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ResourceAttributes {
     fn schema_name() -> String {
-        "io.k8s.api.authorization.v1.ResourceAttributes".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::authorization::v1::ResourceAttributes".to_owned()
+        }
+        else {
+            "io.k8s.api.authorization.v1.ResourceAttributes".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -178,8 +443,9 @@ impl crate::schemars::JsonSchema for ResourceAttributes {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "group".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -189,8 +455,8 @@ impl crate::schemars::JsonSchema for ResourceAttributes {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -200,8 +466,8 @@ impl crate::schemars::JsonSchema for ResourceAttributes {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "namespace".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -211,8 +477,8 @@ impl crate::schemars::JsonSchema for ResourceAttributes {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "resource".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -222,8 +488,8 @@ impl crate::schemars::JsonSchema for ResourceAttributes {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "subresource".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -233,8 +499,8 @@ impl crate::schemars::JsonSchema for ResourceAttributes {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "verb".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -244,8 +510,8 @@ impl crate::schemars::JsonSchema for ResourceAttributes {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "version".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -255,11 +521,27 @@ impl crate::schemars::JsonSchema for ResourceAttributes {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ResourceAttributes {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ResourceAttributes {
+            group: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            namespace: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            resource: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            subresource: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            verb: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            version: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.authorization.v1.SelfSubjectAccessReview
 
 /// SelfSubjectAccessReview checks whether or the current user can perform an action.  Not filling in a spec.namespace means "in all namespaces".  Self is a special case, because users should always be able to check whether they can perform an action
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct SelfSubjectAccessReview {
     pub metadata: crate::apimachinery::pkg::apis::meta::v1::ObjectMeta,
 
@@ -12,6 +12,40 @@ pub struct SelfSubjectAccessReview {
     pub status: Option<crate::api::authorization::v1::SubjectAccessReviewStatus>,
 }
 
+impl SelfSubjectAccessReview {
+    /// Constructs a `SelfSubjectAccessReview` from its required fields. Its optional fields are unset.
+    pub fn new(metadata: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>, spec: impl Into<crate::api::authorization::v1::SelfSubjectAccessReviewSpec>) -> Self {
+        SelfSubjectAccessReview {
+            metadata: metadata.into(),
+            spec: spec.into(),
+            status: None,
+        }
+    }
+}
+
+impl SelfSubjectAccessReview {
+    /// Returns this value with [`SelfSubjectAccessReview::metadata`] set to the given value.
+    #[must_use]
+    pub fn with_metadata(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        self.metadata = value.into();
+        self
+    }
+
+    /// Returns this value with [`SelfSubjectAccessReview::spec`] set to the given value.
+    #[must_use]
+    pub fn with_spec(mut self, value: impl Into<crate::api::authorization::v1::SelfSubjectAccessReviewSpec>) -> Self {
+        self.spec = value.into();
+        self
+    }
+
+    /// Returns this value with [`SelfSubjectAccessReview::status`] set to the given value.
+    #[must_use]
+    pub fn with_status(mut self, value: impl Into<crate::api::authorization::v1::SubjectAccessReviewStatus>) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+}
+
 // Begin authorization.k8s.io/v1/SelfSubjectAccessReview
 
 // Generated from operation createAuthorizationV1SelfSubjectAccessReview
@@ -56,6 +90,9 @@ impl crate::Resource for SelfSubjectAccessReview {
     const KIND: &'static str = "SelfSubjectAccessReview";
     const VERSION: &'static str = "v1";
     const URL_PATH_SEGMENT: &'static str = "selfsubjectaccessreviews";
+    const VERBS: &'static [&'static str] = &["create"];
+    const SINGULAR: &'static str = "selfsubjectaccessreview";
+    const SHORT_NAMES: &'static [&'static str] = &[];
     type Scope = crate::ClusterResourceScope;
 }
 
@@ -71,45 +108,30 @@ impl crate::Metadata for SelfSubjectAccessReview {
     }
 }
 
-impl<'de> crate::serde::Deserialize<'de> for SelfSubjectAccessReview {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_api_version,
-            Key_kind,
-            Key_metadata,
-            Key_spec,
-            Key_status,
-            Other,
+impl std::fmt::Display for SelfSubjectAccessReview {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(<Self as crate::Resource>::URL_PATH_SEGMENT)?;
+        for part in [&self.metadata.namespace, &self.metadata.name].iter().copied().flatten() {
+            write!(f, "/{part}")?;
         }
+        Ok(())
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
-
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "apiVersion" => Field::Key_api_version,
-                            "kind" => Field::Key_kind,
-                            "metadata" => Field::Key_metadata,
-                            "spec" => Field::Key_spec,
-                            "status" => Field::Key_status,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for SelfSubjectAccessReview {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl SelfSubjectAccessReview {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "kind",
+        "metadata",
+        "spec",
+        "status",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for SelfSubjectAccessReview {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -124,51 +146,125 @@ impl<'de> crate::serde::Deserialize<'de> for SelfSubjectAccessReview {
                 let mut value_spec: Option<crate::api::authorization::v1::SelfSubjectAccessReviewSpec> = None;
                 let mut value_status: Option<crate::api::authorization::v1::SubjectAccessReviewStatus> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("SelfSubjectAccessReview", SelfSubjectAccessReview::FIELDS))? {
                     match key {
-                        Field::Key_api_version => {
+                        Some(0) => {
                             let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_api_version != <Self::Value as crate::Resource>::API_VERSION {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<Self::Value as crate::Resource>::API_VERSION));
                             }
                         },
-                        Field::Key_kind => {
+                        Some(1) => {
                             let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_kind != <Self::Value as crate::Resource>::KIND {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<Self::Value as crate::Resource>::KIND));
                             }
                         },
-                        Field::Key_metadata => value_metadata = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_spec => value_spec = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_status => value_status = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(2) => value_metadata = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_spec = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(4) => value_status = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(SelfSubjectAccessReview {
-                    metadata: value_metadata.ok_or_else(|| crate::serde::de::Error::missing_field("metadata"))?,
-                    spec: value_spec.ok_or_else(|| crate::serde::de::Error::missing_field("spec"))?,
+                    metadata: value_metadata.ok_or_else(|| crate::serde2::missing_field("SelfSubjectAccessReview", "metadata", &["metadata", "spec"]))?,
+                    spec: value_spec.ok_or_else(|| crate::serde2::missing_field("SelfSubjectAccessReview", "spec", &["metadata", "spec"]))?,
                     status: value_status,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(SelfSubjectAccessReview {
+                    metadata: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    spec: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    status: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             <Self as crate::Resource>::KIND,
-            &[
-                "apiVersion",
-                "kind",
-                "metadata",
-                "spec",
-                "status",
-            ],
+            SelfSubjectAccessReview::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, SelfSubjectAccessReview> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<SelfSubjectAccessReview as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_metadata = false;
+                let mut has_spec = false;
+                let mut has_status = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("SelfSubjectAccessReview", SelfSubjectAccessReview::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <SelfSubjectAccessReview as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<SelfSubjectAccessReview as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <SelfSubjectAccessReview as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<SelfSubjectAccessReview as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metadata))?; has_metadata = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.spec))?; has_spec = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.status))?; has_status = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_metadata {
+                    return Err(crate::serde2::missing_field("SelfSubjectAccessReview", "metadata", &["metadata", "spec"]));
+                }
+                if !has_spec {
+                    return Err(crate::serde2::missing_field("SelfSubjectAccessReview", "spec", &["metadata", "spec"]));
+                }
+                if !has_status {
+                    self.0.status = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metadata))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.spec))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.status))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            SelfSubjectAccessReview::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for SelfSubjectAccessReview {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct(<Self as crate::Resource>::KIND, 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", &self.metadata)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "spec", &self.spec)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "status", &self.status)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             <Self as crate::Resource>::KIND,
             4 +
@@ -185,10 +281,102 @@ impl crate::serde::Serialize for SelfSubjectAccessReview {
     }
 }
 
+/// A builder of [`SelfSubjectAccessReview`]. Created with [`SelfSubjectAccessReview::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct SelfSubjectAccessReviewBuilder {
+    metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>,
+    spec: Option<crate::api::authorization::v1::SelfSubjectAccessReviewSpec>,
+    status: Option<crate::api::authorization::v1::SubjectAccessReviewStatus>,
+}
+
+#[cfg(feature = "builder")]
+impl SelfSubjectAccessReview {
+    /// Starts building a `SelfSubjectAccessReview`. Its required fields must be set before [`SelfSubjectAccessReviewBuilder::build`] is called.
+    pub fn builder() -> SelfSubjectAccessReviewBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl SelfSubjectAccessReviewBuilder {
+    /// Sets [`SelfSubjectAccessReview::metadata`].
+    pub fn metadata(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        self.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`SelfSubjectAccessReview::spec`].
+    pub fn spec(mut self, value: impl Into<crate::api::authorization::v1::SelfSubjectAccessReviewSpec>) -> Self {
+        self.spec = Some(value.into());
+        self
+    }
+
+    /// Sets [`SelfSubjectAccessReview::status`].
+    pub fn status(mut self, value: impl Into<crate::api::authorization::v1::SubjectAccessReviewStatus>) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Builds the `SelfSubjectAccessReview`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<SelfSubjectAccessReview, crate::MissingFieldError> {
+        Ok(SelfSubjectAccessReview {
+            metadata: self.metadata.ok_or_else(|| crate::MissingFieldError::new("SelfSubjectAccessReview", "metadata"))?,
+            spec: self.spec.ok_or_else(|| crate::MissingFieldError::new("SelfSubjectAccessReview", "spec"))?,
+            status: self.status,
+        })
+    }
+}
+
+/// The apply configuration of [`SelfSubjectAccessReview`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SelfSubjectAccessReviewApplyConfiguration {
+    /// See [`SelfSubjectAccessReview::metadata`].
+    pub metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMetaApplyConfiguration>,
+
+    /// See [`SelfSubjectAccessReview::spec`].
+    pub spec: Option<crate::api::authorization::v1::SelfSubjectAccessReviewSpecApplyConfiguration>,
+
+    /// See [`SelfSubjectAccessReview::status`].
+    pub status: Option<crate::api::authorization::v1::SubjectAccessReviewStatusApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for SelfSubjectAccessReviewApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            <SelfSubjectAccessReview as crate::Resource>::KIND,
+            2 +
+            self.metadata.as_ref().map_or(0, |_| 1) +
+            self.spec.as_ref().map_or(0, |_| 1) +
+            self.status.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <SelfSubjectAccessReview as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <SelfSubjectAccessReview as crate::Resource>::KIND)?;
+        if let Some(value) = &self.metadata {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", value)?;
+        }
+        if let Some(value) = &self.spec {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "spec", value)?;
+        }
+        if let Some(value) = &self.status {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "status", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for SelfSubjectAccessReview {
     fn schema_name() -> String {
-        "io.k8s.api.authorization.v1.SelfSubjectAccessReview".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::authorization::v1::SelfSubjectAccessReview".to_owned()
+        }
+        else {
+            "io.k8s.api.authorization.v1.SelfSubjectAccessReview".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -199,8 +387,9 @@ impl crate::schemars::JsonSchema for SelfSubjectAccessReview {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "apiVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -208,10 +397,11 @@ impl crate::schemars::JsonSchema for SelfSubjectAccessReview {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("authorization.k8s.io/v1".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "kind".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -219,14 +409,15 @@ impl crate::schemars::JsonSchema for SelfSubjectAccessReview {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("SelfSubjectAccessReview".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "metadata".to_owned(),
                         __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>(),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "spec".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::authorization::v1::SelfSubjectAccessReviewSpec>().into_object();
@@ -236,8 +427,8 @@ impl crate::schemars::JsonSchema for SelfSubjectAccessReview {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "status".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::authorization::v1::SubjectAccessReviewStatus>().into_object();
@@ -247,9 +438,12 @@ impl crate::schemars::JsonSchema for SelfSubjectAccessReview {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
+                    "apiVersion",
+                    "kind",
                     "metadata",
                     "spec",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -259,3 +453,14 @@ impl crate::schemars::JsonSchema for SelfSubjectAccessReview {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for SelfSubjectAccessReview {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(SelfSubjectAccessReview {
+            metadata: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            spec: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            status: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.authorization.v1.SubjectRulesReviewStatus
 
 /// SubjectRulesReviewStatus contains the result of a rules check. This check can be incomplete depending on the set of authorizers the server is configured with and any errors experienced during evaluation. Because authorization rules are additive, if a rule appears in a list it's safe to assume the subject has that permission, even if that list is incomplete.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct SubjectRulesReviewStatus {
     /// EvaluationError can appear in combination with Rules. It indicates an error occurred during rule evaluation, such as an authorizer that doesn't support rule evaluation, and that ResourceRules and/or NonResourceRules may be incomplete.
     pub evaluation_error: Option<String>,
@@ -16,43 +16,61 @@ pub struct SubjectRulesReviewStatus {
     pub resource_rules: Vec<crate::api::authorization::v1::ResourceRule>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for SubjectRulesReviewStatus {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_evaluation_error,
-            Key_incomplete,
-            Key_non_resource_rules,
-            Key_resource_rules,
-            Other,
+impl SubjectRulesReviewStatus {
+    /// Constructs a `SubjectRulesReviewStatus` from its required fields. Its optional fields are unset.
+    pub fn new(incomplete: impl Into<bool>, non_resource_rules: impl Into<Vec<crate::api::authorization::v1::NonResourceRule>>, resource_rules: impl Into<Vec<crate::api::authorization::v1::ResourceRule>>) -> Self {
+        SubjectRulesReviewStatus {
+            evaluation_error: None,
+            incomplete: incomplete.into(),
+            non_resource_rules: non_resource_rules.into(),
+            resource_rules: resource_rules.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl SubjectRulesReviewStatus {
+    /// Returns this value with [`SubjectRulesReviewStatus::evaluation_error`] set to the given value.
+    #[must_use]
+    pub fn with_evaluation_error(mut self, value: impl Into<String>) -> Self {
+        self.evaluation_error = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`SubjectRulesReviewStatus::incomplete`] set to the given value.
+    #[must_use]
+    pub fn with_incomplete(mut self, value: impl Into<bool>) -> Self {
+        self.incomplete = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`SubjectRulesReviewStatus::non_resource_rules`] set to the given value.
+    #[must_use]
+    pub fn with_non_resource_rules(mut self, value: impl Into<Vec<crate::api::authorization::v1::NonResourceRule>>) -> Self {
+        self.non_resource_rules = value.into();
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "evaluationError" => Field::Key_evaluation_error,
-                            "incomplete" => Field::Key_incomplete,
-                            "nonResourceRules" => Field::Key_non_resource_rules,
-                            "resourceRules" => Field::Key_resource_rules,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`SubjectRulesReviewStatus::resource_rules`] set to the given value.
+    #[must_use]
+    pub fn with_resource_rules(mut self, value: impl Into<Vec<crate::api::authorization::v1::ResourceRule>>) -> Self {
+        self.resource_rules = value.into();
+        self
+    }
+}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl crate::MergeStrategyInfo for SubjectRulesReviewStatus {}
 
+impl SubjectRulesReviewStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "evaluationError",
+        "incomplete",
+        "nonResourceRules",
+        "resourceRules",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for SubjectRulesReviewStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -68,40 +86,111 @@ impl<'de> crate::serde::Deserialize<'de> for SubjectRulesReviewStatus {
                 let mut value_non_resource_rules: Option<Vec<crate::api::authorization::v1::NonResourceRule>> = None;
                 let mut value_resource_rules: Option<Vec<crate::api::authorization::v1::ResourceRule>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("SubjectRulesReviewStatus", SubjectRulesReviewStatus::FIELDS))? {
                     match key {
-                        Field::Key_evaluation_error => value_evaluation_error = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_incomplete => value_incomplete = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_non_resource_rules => value_non_resource_rules = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_resource_rules => value_resource_rules = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_evaluation_error = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_incomplete = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_non_resource_rules = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_resource_rules = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(SubjectRulesReviewStatus {
                     evaluation_error: value_evaluation_error,
-                    incomplete: value_incomplete.ok_or_else(|| crate::serde::de::Error::missing_field("incomplete"))?,
-                    non_resource_rules: value_non_resource_rules.ok_or_else(|| crate::serde::de::Error::missing_field("nonResourceRules"))?,
-                    resource_rules: value_resource_rules.ok_or_else(|| crate::serde::de::Error::missing_field("resourceRules"))?,
+                    incomplete: value_incomplete.ok_or_else(|| crate::serde2::missing_field("SubjectRulesReviewStatus", "incomplete", &["incomplete", "nonResourceRules", "resourceRules"]))?,
+                    non_resource_rules: value_non_resource_rules.ok_or_else(|| crate::serde2::missing_field("SubjectRulesReviewStatus", "nonResourceRules", &["incomplete", "nonResourceRules", "resourceRules"]))?,
+                    resource_rules: value_resource_rules.ok_or_else(|| crate::serde2::missing_field("SubjectRulesReviewStatus", "resourceRules", &["incomplete", "nonResourceRules", "resourceRules"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(SubjectRulesReviewStatus {
+                    evaluation_error: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    incomplete: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    non_resource_rules: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    resource_rules: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "SubjectRulesReviewStatus",
-            &[
-                "evaluationError",
-                "incomplete",
-                "nonResourceRules",
-                "resourceRules",
-            ],
+            SubjectRulesReviewStatus::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, SubjectRulesReviewStatus> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("SubjectRulesReviewStatus")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_evaluation_error = false;
+                let mut has_incomplete = false;
+                let mut has_non_resource_rules = false;
+                let mut has_resource_rules = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("SubjectRulesReviewStatus", SubjectRulesReviewStatus::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.evaluation_error))?; has_evaluation_error = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.incomplete))?; has_incomplete = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.non_resource_rules))?; has_non_resource_rules = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.resource_rules))?; has_resource_rules = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_evaluation_error {
+                    self.0.evaluation_error = None;
+                }
+                if !has_incomplete {
+                    return Err(crate::serde2::missing_field("SubjectRulesReviewStatus", "incomplete", &["incomplete", "nonResourceRules", "resourceRules"]));
+                }
+                if !has_non_resource_rules {
+                    return Err(crate::serde2::missing_field("SubjectRulesReviewStatus", "nonResourceRules", &["incomplete", "nonResourceRules", "resourceRules"]));
+                }
+                if !has_resource_rules {
+                    return Err(crate::serde2::missing_field("SubjectRulesReviewStatus", "resourceRules", &["incomplete", "nonResourceRules", "resourceRules"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.evaluation_error))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.incomplete))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.non_resource_rules))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.resource_rules))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "SubjectRulesReviewStatus",
+            SubjectRulesReviewStatus::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for SubjectRulesReviewStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("SubjectRulesReviewStatus", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "evaluationError", &self.evaluation_error)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "incomplete", &self.incomplete)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nonResourceRules", &self.non_resource_rules)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resourceRules", &self.resource_rules)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "SubjectRulesReviewStatus",
             3 +
@@ -117,10 +206,114 @@ impl crate::serde::Serialize for SubjectRulesReviewStatus {
     }
 }
 
+/// A builder of [`SubjectRulesReviewStatus`]. Created with [`SubjectRulesReviewStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct SubjectRulesReviewStatusBuilder {
+    evaluation_error: Option<String>,
+    incomplete: Option<bool>,
+    non_resource_rules: Option<Vec<crate::api::authorization::v1::NonResourceRule>>,
+    resource_rules: Option<Vec<crate::api::authorization::v1::ResourceRule>>,
+}
+
+#[cfg(feature = "builder")]
+impl SubjectRulesReviewStatus {
+    /// Starts building a `SubjectRulesReviewStatus`. Its required fields must be set before [`SubjectRulesReviewStatusBuilder::build`] is called.
+    pub fn builder() -> SubjectRulesReviewStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl SubjectRulesReviewStatusBuilder {
+    /// Sets [`SubjectRulesReviewStatus::evaluation_error`].
+    pub fn evaluation_error(mut self, value: impl Into<String>) -> Self {
+        self.evaluation_error = Some(value.into());
+        self
+    }
+
+    /// Sets [`SubjectRulesReviewStatus::incomplete`].
+    pub fn incomplete(mut self, value: impl Into<bool>) -> Self {
+        self.incomplete = Some(value.into());
+        self
+    }
+
+    /// Sets [`SubjectRulesReviewStatus::non_resource_rules`].
+    pub fn non_resource_rules(mut self, value: impl Into<Vec<crate::api::authorization::v1::NonResourceRule>>) -> Self {
+        self.non_resource_rules = Some(value.into());
+        self
+    }
+
+    /// Sets [`SubjectRulesReviewStatus::resource_rules`].
+    pub fn resource_rules(mut self, value: impl Into<Vec<crate::api::authorization::v1::ResourceRule>>) -> Self {
+        self.resource_rules = Some(value.into());
+        self
+    }
+
+    /// Builds the `SubjectRulesReviewStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<SubjectRulesReviewStatus, crate::MissingFieldError> {
+        Ok(SubjectRulesReviewStatus {
+            evaluation_error: self.evaluation_error,
+            incomplete: self.incomplete.ok_or_else(|| crate::MissingFieldError::new("SubjectRulesReviewStatus", "incomplete"))?,
+            non_resource_rules: self.non_resource_rules.ok_or_else(|| crate::MissingFieldError::new("SubjectRulesReviewStatus", "nonResourceRules"))?,
+            resource_rules: self.resource_rules.ok_or_else(|| crate::MissingFieldError::new("SubjectRulesReviewStatus", "resourceRules"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`SubjectRulesReviewStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SubjectRulesReviewStatusApplyConfiguration {
+    /// See [`SubjectRulesReviewStatus::evaluation_error`].
+    pub evaluation_error: Option<String>,
+
+    /// See [`SubjectRulesReviewStatus::incomplete`].
+    pub incomplete: Option<bool>,
+
+    /// See [`SubjectRulesReviewStatus::non_resource_rules`].
+    pub non_resource_rules: Option<Vec<crate::api::authorization::v1::NonResourceRuleApplyConfiguration>>,
+
+    /// See [`SubjectRulesReviewStatus::resource_rules`].
+    pub resource_rules: Option<Vec<crate::api::authorization::v1::ResourceRuleApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for SubjectRulesReviewStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "SubjectRulesReviewStatusApplyConfiguration",
+            self.evaluation_error.as_ref().map_or(0, |_| 1) +
+            self.incomplete.as_ref().map_or(0, |_| 1) +
+            self.non_resource_rules.as_ref().map_or(0, |_| 1) +
+            self.resource_rules.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.evaluation_error {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "evaluationError", value)?;
+        }
+        if let Some(value) = &self.incomplete {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "incomplete", value)?;
+        }
+        if let Some(value) = &self.non_resource_rules {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nonResourceRules", value)?;
+        }
+        if let Some(value) = &self.resource_rules {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resourceRules", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for SubjectRulesReviewStatus {
     fn schema_name() -> String {
-        "io.k8s.api.authorization.v1.SubjectRulesReviewStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::authorization::v1::SubjectRulesReviewStatus".to_owned()
+        }
+        else {
+            "io.k8s.api.authorization.v1.SubjectRulesReviewStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -131,8 +324,9 @@ impl crate::schemars::JsonSchema for SubjectRulesReviewStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "evaluationError".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -142,8 +336,8 @@ impl crate::schemars::JsonSchema for SubjectRulesReviewStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "incomplete".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -153,8 +347,8 @@ impl crate::schemars::JsonSchema for SubjectRulesReviewStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "nonResourceRules".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -168,8 +362,8 @@ impl crate::schemars::JsonSchema for SubjectRulesReviewStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "resourceRules".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -183,8 +377,9 @@ impl crate::schemars::JsonSchema for SubjectRulesReviewStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "incomplete",
                     "nonResourceRules",
@@ -196,3 +391,15 @@ impl crate::schemars::JsonSchema for SubjectRulesReviewStatus {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for SubjectRulesReviewStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(SubjectRulesReviewStatus {
+            evaluation_error: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            incomplete: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            non_resource_rules: crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            resource_rules: crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,39 +1,91 @@
 
 mod local_subject_access_review;
 pub use self::local_subject_access_review::LocalSubjectAccessReview;
+#[cfg(feature = "builder")]
+pub use self::local_subject_access_review::LocalSubjectAccessReviewBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::local_subject_access_review::LocalSubjectAccessReviewApplyConfiguration;
 
 mod non_resource_attributes;
 pub use self::non_resource_attributes::NonResourceAttributes;
+#[cfg(feature = "builder")]
+pub use self::non_resource_attributes::NonResourceAttributesBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::non_resource_attributes::NonResourceAttributesApplyConfiguration;
 
 mod non_resource_rule;
 pub use self::non_resource_rule::NonResourceRule;
+#[cfg(feature = "builder")]
+pub use self::non_resource_rule::NonResourceRuleBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::non_resource_rule::NonResourceRuleApplyConfiguration;
 
 mod resource_attributes;
 pub use self::resource_attributes::ResourceAttributes;
+#[cfg(feature = "builder")]
+pub use self::resource_attributes::ResourceAttributesBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::resource_attributes::ResourceAttributesApplyConfiguration;
 
 mod resource_rule;
 pub use self::resource_rule::ResourceRule;
+#[cfg(feature = "builder")]
+pub use self::resource_rule::ResourceRuleBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::resource_rule::ResourceRuleApplyConfiguration;
 
 mod self_subject_access_review;
 pub use self::self_subject_access_review::SelfSubjectAccessReview;
+#[cfg(feature = "builder")]
+pub use self::self_subject_access_review::SelfSubjectAccessReviewBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::self_subject_access_review::SelfSubjectAccessReviewApplyConfiguration;
 
 mod self_subject_access_review_spec;
 pub use self::self_subject_access_review_spec::SelfSubjectAccessReviewSpec;
+#[cfg(feature = "builder")]
+pub use self::self_subject_access_review_spec::SelfSubjectAccessReviewSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::self_subject_access_review_spec::SelfSubjectAccessReviewSpecApplyConfiguration;
 
 mod self_subject_rules_review;
 pub use self::self_subject_rules_review::SelfSubjectRulesReview;
+#[cfg(feature = "builder")]
+pub use self::self_subject_rules_review::SelfSubjectRulesReviewBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::self_subject_rules_review::SelfSubjectRulesReviewApplyConfiguration;
 
 mod self_subject_rules_review_spec;
 pub use self::self_subject_rules_review_spec::SelfSubjectRulesReviewSpec;
+#[cfg(feature = "builder")]
+pub use self::self_subject_rules_review_spec::SelfSubjectRulesReviewSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::self_subject_rules_review_spec::SelfSubjectRulesReviewSpecApplyConfiguration;
 
 mod subject_access_review;
 pub use self::subject_access_review::SubjectAccessReview;
+#[cfg(feature = "builder")]
+pub use self::subject_access_review::SubjectAccessReviewBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::subject_access_review::SubjectAccessReviewApplyConfiguration;
 
 mod subject_access_review_spec;
 pub use self::subject_access_review_spec::SubjectAccessReviewSpec;
+#[cfg(feature = "builder")]
+pub use self::subject_access_review_spec::SubjectAccessReviewSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::subject_access_review_spec::SubjectAccessReviewSpecApplyConfiguration;
 
 mod subject_access_review_status;
 pub use self::subject_access_review_status::SubjectAccessReviewStatus;
+#[cfg(feature = "builder")]
+pub use self::subject_access_review_status::SubjectAccessReviewStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::subject_access_review_status::SubjectAccessReviewStatusApplyConfiguration;
 
 mod subject_rules_review_status;
 pub use self::subject_rules_review_status::SubjectRulesReviewStatus;
+#[cfg(feature = "builder")]
+pub use self::subject_rules_review_status::SubjectRulesReviewStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::subject_rules_review_status::SubjectRulesReviewStatusApplyConfiguration;
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.autoscaling.v2beta1.CrossVersionObjectReference
 
 /// CrossVersionObjectReference contains enough information to let you identify the referred resource.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct CrossVersionObjectReference {
     /// API version of the referent
     pub api_version: Option<String>,
@@ -13,41 +13,52 @@ pub struct CrossVersionObjectReference {
     pub name: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for CrossVersionObjectReference {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_api_version,
-            Key_kind,
-            Key_name,
-            Other,
+impl CrossVersionObjectReference {
+    /// Constructs a `CrossVersionObjectReference` from its required fields. Its optional fields are unset.
+    pub fn new(kind: impl Into<String>, name: impl Into<String>) -> Self {
+        CrossVersionObjectReference {
+            api_version: None,
+            kind: kind.into(),
+            name: name.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl CrossVersionObjectReference {
+    /// Returns this value with [`CrossVersionObjectReference::api_version`] set to the given value.
+    #[must_use]
+    pub fn with_api_version(mut self, value: impl Into<String>) -> Self {
+        self.api_version = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`CrossVersionObjectReference::kind`] set to the given value.
+    #[must_use]
+    pub fn with_kind(mut self, value: impl Into<String>) -> Self {
+        self.kind = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`CrossVersionObjectReference::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "apiVersion" => Field::Key_api_version,
-                            "kind" => Field::Key_kind,
-                            "name" => Field::Key_name,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for CrossVersionObjectReference {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl CrossVersionObjectReference {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "kind",
+        "name",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for CrossVersionObjectReference {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,37 +73,101 @@ impl<'de> crate::serde::Deserialize<'de> for CrossVersionObjectReference {
                 let mut value_kind: Option<String> = None;
                 let mut value_name: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CrossVersionObjectReference", CrossVersionObjectReference::FIELDS))? {
                     match key {
-                        Field::Key_api_version => value_api_version = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_kind => value_kind = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_name => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_api_version = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_kind = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(CrossVersionObjectReference {
                     api_version: value_api_version,
-                    kind: value_kind.ok_or_else(|| crate::serde::de::Error::missing_field("kind"))?,
-                    name: value_name.ok_or_else(|| crate::serde::de::Error::missing_field("name"))?,
+                    kind: value_kind.ok_or_else(|| crate::serde2::missing_field("CrossVersionObjectReference", "kind", &["kind", "name"]))?,
+                    name: value_name.ok_or_else(|| crate::serde2::missing_field("CrossVersionObjectReference", "name", &["kind", "name"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(CrossVersionObjectReference {
+                    api_version: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    kind: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "CrossVersionObjectReference",
-            &[
-                "apiVersion",
-                "kind",
-                "name",
-            ],
+            CrossVersionObjectReference::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, CrossVersionObjectReference> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("CrossVersionObjectReference")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_api_version = false;
+                let mut has_kind = false;
+                let mut has_name = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CrossVersionObjectReference", CrossVersionObjectReference::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.api_version))?; has_api_version = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.kind))?; has_kind = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.name))?; has_name = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_api_version {
+                    self.0.api_version = None;
+                }
+                if !has_kind {
+                    return Err(crate::serde2::missing_field("CrossVersionObjectReference", "kind", &["kind", "name"]));
+                }
+                if !has_name {
+                    return Err(crate::serde2::missing_field("CrossVersionObjectReference", "name", &["kind", "name"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.api_version))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.kind))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "CrossVersionObjectReference",
+            CrossVersionObjectReference::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for CrossVersionObjectReference {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("CrossVersionObjectReference", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", &self.api_version)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", &self.kind)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "CrossVersionObjectReference",
             2 +
@@ -107,10 +182,99 @@ impl crate::serde::Serialize for CrossVersionObjectReference {
     }
 }
 
+/// A builder of [`CrossVersionObjectReference`]. Created with [`CrossVersionObjectReference::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct CrossVersionObjectReferenceBuilder {
+    api_version: Option<String>,
+    kind: Option<String>,
+    name: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl CrossVersionObjectReference {
+    /// Starts building a `CrossVersionObjectReference`. Its required fields must be set before [`CrossVersionObjectReferenceBuilder::build`] is called.
+    pub fn builder() -> CrossVersionObjectReferenceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl CrossVersionObjectReferenceBuilder {
+    /// Sets [`CrossVersionObjectReference::api_version`].
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`CrossVersionObjectReference::kind`].
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`CrossVersionObjectReference::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CrossVersionObjectReference`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<CrossVersionObjectReference, crate::MissingFieldError> {
+        Ok(CrossVersionObjectReference {
+            api_version: self.api_version,
+            kind: self.kind.ok_or_else(|| crate::MissingFieldError::new("CrossVersionObjectReference", "kind"))?,
+            name: self.name.ok_or_else(|| crate::MissingFieldError::new("CrossVersionObjectReference", "name"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`CrossVersionObjectReference`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CrossVersionObjectReferenceApplyConfiguration {
+    /// See [`CrossVersionObjectReference::api_version`].
+    pub api_version: Option<String>,
+
+    /// See [`CrossVersionObjectReference::kind`].
+    pub kind: Option<String>,
+
+    /// See [`CrossVersionObjectReference::name`].
+    pub name: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for CrossVersionObjectReferenceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "CrossVersionObjectReferenceApplyConfiguration",
+            self.api_version.as_ref().map_or(0, |_| 1) +
+            self.kind.as_ref().map_or(0, |_| 1) +
+            self.name.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.api_version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", value)?;
+        }
+        if let Some(value) = &self.kind {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", value)?;
+        }
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CrossVersionObjectReference {
     fn schema_name() -> String {
-        "io.k8s.api.autoscaling.v2beta1.CrossVersionObjectReference".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::autoscaling::v2beta1::CrossVersionObjectReference".to_owned()
+        }
+        else {
+            "io.k8s.api.autoscaling.v2beta1.CrossVersionObjectReference".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -121,8 +285,9 @@ impl crate::schemars::JsonSchema for CrossVersionObjectReference {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "apiVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -132,8 +297,8 @@ impl crate::schemars::JsonSchema for CrossVersionObjectReference {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "kind".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -143,8 +308,8 @@ impl crate::schemars::JsonSchema for CrossVersionObjectReference {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -154,8 +319,9 @@ impl crate::schemars::JsonSchema for CrossVersionObjectReference {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "kind",
                     "name",
@@ -166,3 +332,14 @@ impl crate::schemars::JsonSchema for CrossVersionObjectReference {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for CrossVersionObjectReference {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(CrossVersionObjectReference {
+            api_version: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            kind: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.autoscaling.v2beta1.ObjectMetricSource
 
 /// ObjectMetricSource indicates how to scale on a metric describing a kubernetes object (for example, hits-per-second on an Ingress object).
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ObjectMetricSource {
     /// metricName is the name of the metric in question.
     pub metric_name: String,
@@ -13,41 +13,52 @@ pub struct ObjectMetricSource {
     pub target_value: crate::apimachinery::pkg::api::resource::Quantity,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ObjectMetricSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_metric_name,
-            Key_target,
-            Key_target_value,
-            Other,
+impl ObjectMetricSource {
+    /// Constructs a `ObjectMetricSource` from its required fields. Its optional fields are unset.
+    pub fn new(metric_name: impl Into<String>, target: impl Into<crate::api::autoscaling::v2beta1::CrossVersionObjectReference>, target_value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        ObjectMetricSource {
+            metric_name: metric_name.into(),
+            target: target.into(),
+            target_value: target_value.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl ObjectMetricSource {
+    /// Returns this value with [`ObjectMetricSource::metric_name`] set to the given value.
+    #[must_use]
+    pub fn with_metric_name(mut self, value: impl Into<String>) -> Self {
+        self.metric_name = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ObjectMetricSource::target`] set to the given value.
+    #[must_use]
+    pub fn with_target(mut self, value: impl Into<crate::api::autoscaling::v2beta1::CrossVersionObjectReference>) -> Self {
+        self.target = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ObjectMetricSource::target_value`] set to the given value.
+    #[must_use]
+    pub fn with_target_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.target_value = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "metricName" => Field::Key_metric_name,
-                            "target" => Field::Key_target,
-                            "targetValue" => Field::Key_target_value,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for ObjectMetricSource {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl ObjectMetricSource {
+    const FIELDS: &'static [&'static str] = &[
+        "metricName",
+        "target",
+        "targetValue",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ObjectMetricSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,37 +73,101 @@ impl<'de> crate::serde::Deserialize<'de> for ObjectMetricSource {
                 let mut value_target: Option<crate::api::autoscaling::v2beta1::CrossVersionObjectReference> = None;
                 let mut value_target_value: Option<crate::apimachinery::pkg::api::resource::Quantity> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ObjectMetricSource", ObjectMetricSource::FIELDS))? {
                     match key {
-                        Field::Key_metric_name => value_metric_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_target => value_target = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_target_value => value_target_value = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_metric_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_target = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_target_value = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ObjectMetricSource {
-                    metric_name: value_metric_name.ok_or_else(|| crate::serde::de::Error::missing_field("metricName"))?,
-                    target: value_target.ok_or_else(|| crate::serde::de::Error::missing_field("target"))?,
-                    target_value: value_target_value.ok_or_else(|| crate::serde::de::Error::missing_field("targetValue"))?,
+                    metric_name: value_metric_name.ok_or_else(|| crate::serde2::missing_field("ObjectMetricSource", "metricName", &["metricName", "target", "targetValue"]))?,
+                    target: value_target.ok_or_else(|| crate::serde2::missing_field("ObjectMetricSource", "target", &["metricName", "target", "targetValue"]))?,
+                    target_value: value_target_value.ok_or_else(|| crate::serde2::missing_field("ObjectMetricSource", "targetValue", &["metricName", "target", "targetValue"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ObjectMetricSource {
+                    metric_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    target: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    target_value: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "ObjectMetricSource",
-            &[
-                "metricName",
-                "target",
-                "targetValue",
-            ],
+            ObjectMetricSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ObjectMetricSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ObjectMetricSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_metric_name = false;
+                let mut has_target = false;
+                let mut has_target_value = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ObjectMetricSource", ObjectMetricSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metric_name))?; has_metric_name = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.target))?; has_target = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.target_value))?; has_target_value = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_metric_name {
+                    return Err(crate::serde2::missing_field("ObjectMetricSource", "metricName", &["metricName", "target", "targetValue"]));
+                }
+                if !has_target {
+                    return Err(crate::serde2::missing_field("ObjectMetricSource", "target", &["metricName", "target", "targetValue"]));
+                }
+                if !has_target_value {
+                    return Err(crate::serde2::missing_field("ObjectMetricSource", "targetValue", &["metricName", "target", "targetValue"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metric_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.target))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.target_value))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ObjectMetricSource",
+            ObjectMetricSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ObjectMetricSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ObjectMetricSource", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricName", &self.metric_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "target", &self.target)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "targetValue", &self.target_value)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ObjectMetricSource",
             3,
@@ -104,10 +179,99 @@ impl crate::serde::Serialize for ObjectMetricSource {
     }
 }
 
+/// A builder of [`ObjectMetricSource`]. Created with [`ObjectMetricSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ObjectMetricSourceBuilder {
+    metric_name: Option<String>,
+    target: Option<crate::api::autoscaling::v2beta1::CrossVersionObjectReference>,
+    target_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+}
+
+#[cfg(feature = "builder")]
+impl ObjectMetricSource {
+    /// Starts building a `ObjectMetricSource`. Its required fields must be set before [`ObjectMetricSourceBuilder::build`] is called.
+    pub fn builder() -> ObjectMetricSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ObjectMetricSourceBuilder {
+    /// Sets [`ObjectMetricSource::metric_name`].
+    pub fn metric_name(mut self, value: impl Into<String>) -> Self {
+        self.metric_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`ObjectMetricSource::target`].
+    pub fn target(mut self, value: impl Into<crate::api::autoscaling::v2beta1::CrossVersionObjectReference>) -> Self {
+        self.target = Some(value.into());
+        self
+    }
+
+    /// Sets [`ObjectMetricSource::target_value`].
+    pub fn target_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.target_value = Some(value.into());
+        self
+    }
+
+    /// Builds the `ObjectMetricSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ObjectMetricSource, crate::MissingFieldError> {
+        Ok(ObjectMetricSource {
+            metric_name: self.metric_name.ok_or_else(|| crate::MissingFieldError::new("ObjectMetricSource", "metricName"))?,
+            target: self.target.ok_or_else(|| crate::MissingFieldError::new("ObjectMetricSource", "target"))?,
+            target_value: self.target_value.ok_or_else(|| crate::MissingFieldError::new("ObjectMetricSource", "targetValue"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`ObjectMetricSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ObjectMetricSourceApplyConfiguration {
+    /// See [`ObjectMetricSource::metric_name`].
+    pub metric_name: Option<String>,
+
+    /// See [`ObjectMetricSource::target`].
+    pub target: Option<crate::api::autoscaling::v2beta1::CrossVersionObjectReferenceApplyConfiguration>,
+
+    /// See [`ObjectMetricSource::target_value`].
+    pub target_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ObjectMetricSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ObjectMetricSourceApplyConfiguration",
+            self.metric_name.as_ref().map_or(0, |_| 1) +
+            self.target.as_ref().map_or(0, |_| 1) +
+            self.target_value.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.metric_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricName", value)?;
+        }
+        if let Some(value) = &self.target {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "target", value)?;
+        }
+        if let Some(value) = &self.target_value {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "targetValue", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ObjectMetricSource {
     fn schema_name() -> String {
-        "io.k8s.api.autoscaling.v2beta1.ObjectMetricSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::autoscaling::v2beta1::ObjectMetricSource".to_owned()
+        }
+        else {
+            "io.k8s.api.autoscaling.v2beta1.ObjectMetricSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -118,8 +282,9 @@ impl crate::schemars::JsonSchema for ObjectMetricSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "metricName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -129,8 +294,8 @@ impl crate::schemars::JsonSchema for ObjectMetricSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "target".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::autoscaling::v2beta1::CrossVersionObjectReference>().into_object();
@@ -140,8 +305,8 @@ impl crate::schemars::JsonSchema for ObjectMetricSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "targetValue".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::api::resource::Quantity>().into_object();
@@ -151,8 +316,9 @@ impl crate::schemars::JsonSchema for ObjectMetricSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "metricName",
                     "target",
@@ -164,3 +330,14 @@ impl crate::schemars::JsonSchema for ObjectMetricSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ObjectMetricSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ObjectMetricSource {
+            metric_name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            target: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            target_value: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
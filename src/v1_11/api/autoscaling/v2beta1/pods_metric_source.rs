@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.autoscaling.v2beta1.PodsMetricSource
 
 /// PodsMetricSource indicates how to scale on a metric describing each pod in the current scale target (for example, transactions-processed-per-second). The values will be averaged together before being compared to the target value.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct PodsMetricSource {
     /// metricName is the name of the metric in question
     pub metric_name: String,
@@ -10,39 +10,43 @@ pub struct PodsMetricSource {
     pub target_average_value: crate::apimachinery::pkg::api::resource::Quantity,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for PodsMetricSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_metric_name,
-            Key_target_average_value,
-            Other,
+impl PodsMetricSource {
+    /// Constructs a `PodsMetricSource` from its required fields. Its optional fields are unset.
+    pub fn new(metric_name: impl Into<String>, target_average_value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        PodsMetricSource {
+            metric_name: metric_name.into(),
+            target_average_value: target_average_value.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl PodsMetricSource {
+    /// Returns this value with [`PodsMetricSource::metric_name`] set to the given value.
+    #[must_use]
+    pub fn with_metric_name(mut self, value: impl Into<String>) -> Self {
+        self.metric_name = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`PodsMetricSource::target_average_value`] set to the given value.
+    #[must_use]
+    pub fn with_target_average_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.target_average_value = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "metricName" => Field::Key_metric_name,
-                            "targetAverageValue" => Field::Key_target_average_value,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for PodsMetricSource {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl PodsMetricSource {
+    const FIELDS: &'static [&'static str] = &[
+        "metricName",
+        "targetAverageValue",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for PodsMetricSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +60,91 @@ impl<'de> crate::serde::Deserialize<'de> for PodsMetricSource {
                 let mut value_metric_name: Option<String> = None;
                 let mut value_target_average_value: Option<crate::apimachinery::pkg::api::resource::Quantity> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodsMetricSource", PodsMetricSource::FIELDS))? {
                     match key {
-                        Field::Key_metric_name => value_metric_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_target_average_value => value_target_average_value = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_metric_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_target_average_value = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(PodsMetricSource {
-                    metric_name: value_metric_name.ok_or_else(|| crate::serde::de::Error::missing_field("metricName"))?,
-                    target_average_value: value_target_average_value.ok_or_else(|| crate::serde::de::Error::missing_field("targetAverageValue"))?,
+                    metric_name: value_metric_name.ok_or_else(|| crate::serde2::missing_field("PodsMetricSource", "metricName", &["metricName", "targetAverageValue"]))?,
+                    target_average_value: value_target_average_value.ok_or_else(|| crate::serde2::missing_field("PodsMetricSource", "targetAverageValue", &["metricName", "targetAverageValue"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(PodsMetricSource {
+                    metric_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    target_average_value: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "PodsMetricSource",
-            &[
-                "metricName",
-                "targetAverageValue",
-            ],
+            PodsMetricSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, PodsMetricSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("PodsMetricSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_metric_name = false;
+                let mut has_target_average_value = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodsMetricSource", PodsMetricSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metric_name))?; has_metric_name = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.target_average_value))?; has_target_average_value = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_metric_name {
+                    return Err(crate::serde2::missing_field("PodsMetricSource", "metricName", &["metricName", "targetAverageValue"]));
+                }
+                if !has_target_average_value {
+                    return Err(crate::serde2::missing_field("PodsMetricSource", "targetAverageValue", &["metricName", "targetAverageValue"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metric_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.target_average_value))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "PodsMetricSource",
+            PodsMetricSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for PodsMetricSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("PodsMetricSource", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricName", &self.metric_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "targetAverageValue", &self.target_average_value)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "PodsMetricSource",
             2,
@@ -94,10 +155,84 @@ impl crate::serde::Serialize for PodsMetricSource {
     }
 }
 
+/// A builder of [`PodsMetricSource`]. Created with [`PodsMetricSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct PodsMetricSourceBuilder {
+    metric_name: Option<String>,
+    target_average_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+}
+
+#[cfg(feature = "builder")]
+impl PodsMetricSource {
+    /// Starts building a `PodsMetricSource`. Its required fields must be set before [`PodsMetricSourceBuilder::build`] is called.
+    pub fn builder() -> PodsMetricSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl PodsMetricSourceBuilder {
+    /// Sets [`PodsMetricSource::metric_name`].
+    pub fn metric_name(mut self, value: impl Into<String>) -> Self {
+        self.metric_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodsMetricSource::target_average_value`].
+    pub fn target_average_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.target_average_value = Some(value.into());
+        self
+    }
+
+    /// Builds the `PodsMetricSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<PodsMetricSource, crate::MissingFieldError> {
+        Ok(PodsMetricSource {
+            metric_name: self.metric_name.ok_or_else(|| crate::MissingFieldError::new("PodsMetricSource", "metricName"))?,
+            target_average_value: self.target_average_value.ok_or_else(|| crate::MissingFieldError::new("PodsMetricSource", "targetAverageValue"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`PodsMetricSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodsMetricSourceApplyConfiguration {
+    /// See [`PodsMetricSource::metric_name`].
+    pub metric_name: Option<String>,
+
+    /// See [`PodsMetricSource::target_average_value`].
+    pub target_average_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for PodsMetricSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "PodsMetricSourceApplyConfiguration",
+            self.metric_name.as_ref().map_or(0, |_| 1) +
+            self.target_average_value.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.metric_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricName", value)?;
+        }
+        if let Some(value) = &self.target_average_value {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "targetAverageValue", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodsMetricSource {
     fn schema_name() -> String {
-        "io.k8s.api.autoscaling.v2beta1.PodsMetricSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::autoscaling::v2beta1::PodsMetricSource".to_owned()
+        }
+        else {
+            "io.k8s.api.autoscaling.v2beta1.PodsMetricSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -108,8 +243,9 @@ impl crate::schemars::JsonSchema for PodsMetricSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "metricName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -119,8 +255,8 @@ impl crate::schemars::JsonSchema for PodsMetricSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "targetAverageValue".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::api::resource::Quantity>().into_object();
@@ -130,8 +266,9 @@ impl crate::schemars::JsonSchema for PodsMetricSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "metricName",
                     "targetAverageValue",
@@ -142,3 +279,13 @@ impl crate::schemars::JsonSchema for PodsMetricSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for PodsMetricSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(PodsMetricSource {
+            metric_name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            target_average_value: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.autoscaling.v2beta1.PodsMetricStatus
 
 /// PodsMetricStatus indicates the current value of a metric describing each pod in the current scale target (for example, transactions-processed-per-second).
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct PodsMetricStatus {
     /// currentAverageValue is the current value of the average of the metric across all relevant pods (as a quantity)
     pub current_average_value: crate::apimachinery::pkg::api::resource::Quantity,
@@ -10,39 +10,43 @@ pub struct PodsMetricStatus {
     pub metric_name: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for PodsMetricStatus {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_current_average_value,
-            Key_metric_name,
-            Other,
+impl PodsMetricStatus {
+    /// Constructs a `PodsMetricStatus` from its required fields. Its optional fields are unset.
+    pub fn new(current_average_value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>, metric_name: impl Into<String>) -> Self {
+        PodsMetricStatus {
+            current_average_value: current_average_value.into(),
+            metric_name: metric_name.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl PodsMetricStatus {
+    /// Returns this value with [`PodsMetricStatus::current_average_value`] set to the given value.
+    #[must_use]
+    pub fn with_current_average_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.current_average_value = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`PodsMetricStatus::metric_name`] set to the given value.
+    #[must_use]
+    pub fn with_metric_name(mut self, value: impl Into<String>) -> Self {
+        self.metric_name = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "currentAverageValue" => Field::Key_current_average_value,
-                            "metricName" => Field::Key_metric_name,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for PodsMetricStatus {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl PodsMetricStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "currentAverageValue",
+        "metricName",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for PodsMetricStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +60,91 @@ impl<'de> crate::serde::Deserialize<'de> for PodsMetricStatus {
                 let mut value_current_average_value: Option<crate::apimachinery::pkg::api::resource::Quantity> = None;
                 let mut value_metric_name: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodsMetricStatus", PodsMetricStatus::FIELDS))? {
                     match key {
-                        Field::Key_current_average_value => value_current_average_value = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_metric_name => value_metric_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_current_average_value = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_metric_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(PodsMetricStatus {
-                    current_average_value: value_current_average_value.ok_or_else(|| crate::serde::de::Error::missing_field("currentAverageValue"))?,
-                    metric_name: value_metric_name.ok_or_else(|| crate::serde::de::Error::missing_field("metricName"))?,
+                    current_average_value: value_current_average_value.ok_or_else(|| crate::serde2::missing_field("PodsMetricStatus", "currentAverageValue", &["currentAverageValue", "metricName"]))?,
+                    metric_name: value_metric_name.ok_or_else(|| crate::serde2::missing_field("PodsMetricStatus", "metricName", &["currentAverageValue", "metricName"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(PodsMetricStatus {
+                    current_average_value: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    metric_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "PodsMetricStatus",
-            &[
-                "currentAverageValue",
-                "metricName",
-            ],
+            PodsMetricStatus::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, PodsMetricStatus> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("PodsMetricStatus")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_current_average_value = false;
+                let mut has_metric_name = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodsMetricStatus", PodsMetricStatus::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.current_average_value))?; has_current_average_value = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metric_name))?; has_metric_name = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_current_average_value {
+                    return Err(crate::serde2::missing_field("PodsMetricStatus", "currentAverageValue", &["currentAverageValue", "metricName"]));
+                }
+                if !has_metric_name {
+                    return Err(crate::serde2::missing_field("PodsMetricStatus", "metricName", &["currentAverageValue", "metricName"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.current_average_value))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metric_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "PodsMetricStatus",
+            PodsMetricStatus::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for PodsMetricStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("PodsMetricStatus", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "currentAverageValue", &self.current_average_value)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricName", &self.metric_name)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "PodsMetricStatus",
             2,
@@ -94,10 +155,84 @@ impl crate::serde::Serialize for PodsMetricStatus {
     }
 }
 
+/// A builder of [`PodsMetricStatus`]. Created with [`PodsMetricStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct PodsMetricStatusBuilder {
+    current_average_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+    metric_name: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl PodsMetricStatus {
+    /// Starts building a `PodsMetricStatus`. Its required fields must be set before [`PodsMetricStatusBuilder::build`] is called.
+    pub fn builder() -> PodsMetricStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl PodsMetricStatusBuilder {
+    /// Sets [`PodsMetricStatus::current_average_value`].
+    pub fn current_average_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.current_average_value = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodsMetricStatus::metric_name`].
+    pub fn metric_name(mut self, value: impl Into<String>) -> Self {
+        self.metric_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `PodsMetricStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<PodsMetricStatus, crate::MissingFieldError> {
+        Ok(PodsMetricStatus {
+            current_average_value: self.current_average_value.ok_or_else(|| crate::MissingFieldError::new("PodsMetricStatus", "currentAverageValue"))?,
+            metric_name: self.metric_name.ok_or_else(|| crate::MissingFieldError::new("PodsMetricStatus", "metricName"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`PodsMetricStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodsMetricStatusApplyConfiguration {
+    /// See [`PodsMetricStatus::current_average_value`].
+    pub current_average_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+
+    /// See [`PodsMetricStatus::metric_name`].
+    pub metric_name: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for PodsMetricStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "PodsMetricStatusApplyConfiguration",
+            self.current_average_value.as_ref().map_or(0, |_| 1) +
+            self.metric_name.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.current_average_value {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "currentAverageValue", value)?;
+        }
+        if let Some(value) = &self.metric_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricName", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodsMetricStatus {
     fn schema_name() -> String {
-        "io.k8s.api.autoscaling.v2beta1.PodsMetricStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::autoscaling::v2beta1::PodsMetricStatus".to_owned()
+        }
+        else {
+            "io.k8s.api.autoscaling.v2beta1.PodsMetricStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -108,8 +243,9 @@ impl crate::schemars::JsonSchema for PodsMetricStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "currentAverageValue".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::api::resource::Quantity>().into_object();
@@ -119,8 +255,8 @@ impl crate::schemars::JsonSchema for PodsMetricStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "metricName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -130,8 +266,9 @@ impl crate::schemars::JsonSchema for PodsMetricStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "currentAverageValue",
                     "metricName",
@@ -142,3 +279,13 @@ impl crate::schemars::JsonSchema for PodsMetricStatus {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for PodsMetricStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(PodsMetricStatus {
+            current_average_value: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            metric_name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.autoscaling.v2beta1.ResourceMetricSource
 
 /// ResourceMetricSource indicates how to scale on a resource metric known to Kubernetes, as specified in requests and limits, describing each pod in the current scale target (e.g. CPU or memory).  The values will be averaged together before being compared to the target.  Such metrics are built in to Kubernetes, and have special scaling options on top of those available to normal per-pod metrics using the "pods" source.  Only one "target" type should be set.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ResourceMetricSource {
     /// name is the name of the resource in question.
     pub name: String,
@@ -13,41 +13,52 @@ pub struct ResourceMetricSource {
     pub target_average_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ResourceMetricSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_name,
-            Key_target_average_utilization,
-            Key_target_average_value,
-            Other,
+impl ResourceMetricSource {
+    /// Constructs a `ResourceMetricSource` from its required fields. Its optional fields are unset.
+    pub fn new(name: impl Into<String>) -> Self {
+        ResourceMetricSource {
+            name: name.into(),
+            target_average_utilization: None,
+            target_average_value: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl ResourceMetricSource {
+    /// Returns this value with [`ResourceMetricSource::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ResourceMetricSource::target_average_utilization`] set to the given value.
+    #[must_use]
+    pub fn with_target_average_utilization(mut self, value: impl Into<i32>) -> Self {
+        self.target_average_utilization = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ResourceMetricSource::target_average_value`] set to the given value.
+    #[must_use]
+    pub fn with_target_average_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.target_average_value = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "name" => Field::Key_name,
-                            "targetAverageUtilization" => Field::Key_target_average_utilization,
-                            "targetAverageValue" => Field::Key_target_average_value,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for ResourceMetricSource {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl ResourceMetricSource {
+    const FIELDS: &'static [&'static str] = &[
+        "name",
+        "targetAverageUtilization",
+        "targetAverageValue",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ResourceMetricSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,37 +73,101 @@ impl<'de> crate::serde::Deserialize<'de> for ResourceMetricSource {
                 let mut value_target_average_utilization: Option<i32> = None;
                 let mut value_target_average_value: Option<crate::apimachinery::pkg::api::resource::Quantity> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ResourceMetricSource", ResourceMetricSource::FIELDS))? {
                     match key {
-                        Field::Key_name => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_target_average_utilization => value_target_average_utilization = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_target_average_value => value_target_average_value = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_target_average_utilization = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_target_average_value = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ResourceMetricSource {
-                    name: value_name.ok_or_else(|| crate::serde::de::Error::missing_field("name"))?,
+                    name: value_name.ok_or_else(|| crate::serde2::missing_field("ResourceMetricSource", "name", &["name"]))?,
                     target_average_utilization: value_target_average_utilization,
                     target_average_value: value_target_average_value,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ResourceMetricSource {
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    target_average_utilization: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    target_average_value: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "ResourceMetricSource",
-            &[
-                "name",
-                "targetAverageUtilization",
-                "targetAverageValue",
-            ],
+            ResourceMetricSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ResourceMetricSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ResourceMetricSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_name = false;
+                let mut has_target_average_utilization = false;
+                let mut has_target_average_value = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ResourceMetricSource", ResourceMetricSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.name))?; has_name = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.target_average_utilization))?; has_target_average_utilization = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.target_average_value))?; has_target_average_value = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_name {
+                    return Err(crate::serde2::missing_field("ResourceMetricSource", "name", &["name"]));
+                }
+                if !has_target_average_utilization {
+                    self.0.target_average_utilization = None;
+                }
+                if !has_target_average_value {
+                    self.0.target_average_value = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.target_average_utilization))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.target_average_value))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ResourceMetricSource",
+            ResourceMetricSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ResourceMetricSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ResourceMetricSource", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "targetAverageUtilization", &self.target_average_utilization)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "targetAverageValue", &self.target_average_value)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ResourceMetricSource",
             1 +
@@ -110,10 +185,99 @@ impl crate::serde::Serialize for ResourceMetricSource {
     }
 }
 
+/// A builder of [`ResourceMetricSource`]. Created with [`ResourceMetricSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ResourceMetricSourceBuilder {
+    name: Option<String>,
+    target_average_utilization: Option<i32>,
+    target_average_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+}
+
+#[cfg(feature = "builder")]
+impl ResourceMetricSource {
+    /// Starts building a `ResourceMetricSource`. Its required fields must be set before [`ResourceMetricSourceBuilder::build`] is called.
+    pub fn builder() -> ResourceMetricSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ResourceMetricSourceBuilder {
+    /// Sets [`ResourceMetricSource::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`ResourceMetricSource::target_average_utilization`].
+    pub fn target_average_utilization(mut self, value: impl Into<i32>) -> Self {
+        self.target_average_utilization = Some(value.into());
+        self
+    }
+
+    /// Sets [`ResourceMetricSource::target_average_value`].
+    pub fn target_average_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.target_average_value = Some(value.into());
+        self
+    }
+
+    /// Builds the `ResourceMetricSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ResourceMetricSource, crate::MissingFieldError> {
+        Ok(ResourceMetricSource {
+            name: self.name.ok_or_else(|| crate::MissingFieldError::new("ResourceMetricSource", "name"))?,
+            target_average_utilization: self.target_average_utilization,
+            target_average_value: self.target_average_value,
+        })
+    }
+}
+
+/// The apply configuration of [`ResourceMetricSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ResourceMetricSourceApplyConfiguration {
+    /// See [`ResourceMetricSource::name`].
+    pub name: Option<String>,
+
+    /// See [`ResourceMetricSource::target_average_utilization`].
+    pub target_average_utilization: Option<i32>,
+
+    /// See [`ResourceMetricSource::target_average_value`].
+    pub target_average_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ResourceMetricSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ResourceMetricSourceApplyConfiguration",
+            self.name.as_ref().map_or(0, |_| 1) +
+            self.target_average_utilization.as_ref().map_or(0, |_| 1) +
+            self.target_average_value.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        if let Some(value) = &self.target_average_utilization {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "targetAverageUtilization", value)?;
+        }
+        if let Some(value) = &self.target_average_value {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "targetAverageValue", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ResourceMetricSource {
     fn schema_name() -> String {
-        "io.k8s.api.autoscaling.v2beta1.ResourceMetricSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::autoscaling::v2beta1::ResourceMetricSource".to_owned()
+        }
+        else {
+            "io.k8s.api.autoscaling.v2beta1.ResourceMetricSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -124,8 +288,9 @@ impl crate::schemars::JsonSchema for ResourceMetricSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -135,8 +300,8 @@ impl crate::schemars::JsonSchema for ResourceMetricSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "targetAverageUtilization".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -147,8 +312,8 @@ impl crate::schemars::JsonSchema for ResourceMetricSource {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "targetAverageValue".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::api::resource::Quantity>().into_object();
@@ -158,8 +323,9 @@ impl crate::schemars::JsonSchema for ResourceMetricSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "name",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -169,3 +335,14 @@ impl crate::schemars::JsonSchema for ResourceMetricSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ResourceMetricSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ResourceMetricSource {
+            name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            target_average_utilization: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            target_average_value: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
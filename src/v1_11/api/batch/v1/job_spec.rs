@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.batch.v1.JobSpec
 
 /// JobSpec describes how the job execution will look like.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct JobSpec {
     /// Specifies the duration in seconds relative to the startTime that the job may be active before the system tries to terminate it; value must be positive integer
     pub active_deadline_seconds: Option<i64>,
@@ -25,49 +25,88 @@ pub struct JobSpec {
     pub template: crate::api::core::v1::PodTemplateSpec,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for JobSpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_active_deadline_seconds,
-            Key_backoff_limit,
-            Key_completions,
-            Key_manual_selector,
-            Key_parallelism,
-            Key_selector,
-            Key_template,
-            Other,
+impl JobSpec {
+    /// Constructs a `JobSpec` from its required fields. Its optional fields are unset.
+    pub fn new(template: impl Into<crate::api::core::v1::PodTemplateSpec>) -> Self {
+        JobSpec {
+            active_deadline_seconds: None,
+            backoff_limit: None,
+            completions: None,
+            manual_selector: None,
+            parallelism: None,
+            selector: None,
+            template: template.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl JobSpec {
+    /// Returns this value with [`JobSpec::active_deadline_seconds`] set to the given value.
+    #[must_use]
+    pub fn with_active_deadline_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.active_deadline_seconds = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`JobSpec::backoff_limit`] set to the given value.
+    #[must_use]
+    pub fn with_backoff_limit(mut self, value: impl Into<i32>) -> Self {
+        self.backoff_limit = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`JobSpec::completions`] set to the given value.
+    #[must_use]
+    pub fn with_completions(mut self, value: impl Into<i32>) -> Self {
+        self.completions = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "activeDeadlineSeconds" => Field::Key_active_deadline_seconds,
-                            "backoffLimit" => Field::Key_backoff_limit,
-                            "completions" => Field::Key_completions,
-                            "manualSelector" => Field::Key_manual_selector,
-                            "parallelism" => Field::Key_parallelism,
-                            "selector" => Field::Key_selector,
-                            "template" => Field::Key_template,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`JobSpec::manual_selector`] set to the given value.
+    #[must_use]
+    pub fn with_manual_selector(mut self, value: impl Into<bool>) -> Self {
+        self.manual_selector = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`JobSpec::parallelism`] set to the given value.
+    #[must_use]
+    pub fn with_parallelism(mut self, value: impl Into<i32>) -> Self {
+        self.parallelism = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JobSpec::selector`] set to the given value.
+    #[must_use]
+    pub fn with_selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.selector = Some(value.into());
+        self
+    }
 
+    /// Returns this value with [`JobSpec::template`] set to the given value.
+    #[must_use]
+    pub fn with_template(mut self, value: impl Into<crate::api::core::v1::PodTemplateSpec>) -> Self {
+        self.template = value.into();
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for JobSpec {}
+
+impl JobSpec {
+    const FIELDS: &'static [&'static str] = &[
+        "activeDeadlineSeconds",
+        "backoffLimit",
+        "completions",
+        "manualSelector",
+        "parallelism",
+        "selector",
+        "template",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for JobSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -86,16 +125,16 @@ impl<'de> crate::serde::Deserialize<'de> for JobSpec {
                 let mut value_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> = None;
                 let mut value_template: Option<crate::api::core::v1::PodTemplateSpec> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("JobSpec", JobSpec::FIELDS))? {
                     match key {
-                        Field::Key_active_deadline_seconds => value_active_deadline_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_backoff_limit => value_backoff_limit = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_completions => value_completions = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_manual_selector => value_manual_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_parallelism => value_parallelism = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_selector => value_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_template => value_template = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_active_deadline_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_backoff_limit = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_completions = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_manual_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_parallelism = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_template = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -106,29 +145,121 @@ impl<'de> crate::serde::Deserialize<'de> for JobSpec {
                     manual_selector: value_manual_selector,
                     parallelism: value_parallelism,
                     selector: value_selector,
-                    template: value_template.ok_or_else(|| crate::serde::de::Error::missing_field("template"))?,
+                    template: value_template.ok_or_else(|| crate::serde2::missing_field("JobSpec", "template", &["template"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(JobSpec {
+                    active_deadline_seconds: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    backoff_limit: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    completions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    manual_selector: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    parallelism: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    selector: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    template: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "JobSpec",
-            &[
-                "activeDeadlineSeconds",
-                "backoffLimit",
-                "completions",
-                "manualSelector",
-                "parallelism",
-                "selector",
-                "template",
-            ],
+            JobSpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, JobSpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("JobSpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_active_deadline_seconds = false;
+                let mut has_backoff_limit = false;
+                let mut has_completions = false;
+                let mut has_manual_selector = false;
+                let mut has_parallelism = false;
+                let mut has_selector = false;
+                let mut has_template = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("JobSpec", JobSpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.active_deadline_seconds))?; has_active_deadline_seconds = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.backoff_limit))?; has_backoff_limit = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.completions))?; has_completions = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.manual_selector))?; has_manual_selector = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.parallelism))?; has_parallelism = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.selector))?; has_selector = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.template))?; has_template = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_active_deadline_seconds {
+                    self.0.active_deadline_seconds = None;
+                }
+                if !has_backoff_limit {
+                    self.0.backoff_limit = None;
+                }
+                if !has_completions {
+                    self.0.completions = None;
+                }
+                if !has_manual_selector {
+                    self.0.manual_selector = None;
+                }
+                if !has_parallelism {
+                    self.0.parallelism = None;
+                }
+                if !has_selector {
+                    self.0.selector = None;
+                }
+                if !has_template {
+                    return Err(crate::serde2::missing_field("JobSpec", "template", &["template"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.active_deadline_seconds))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.backoff_limit))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.completions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.manual_selector))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.parallelism))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.selector))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.template))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "JobSpec",
+            JobSpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for JobSpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("JobSpec", 7)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "activeDeadlineSeconds", &self.active_deadline_seconds)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "backoffLimit", &self.backoff_limit)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "completions", &self.completions)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "manualSelector", &self.manual_selector)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "parallelism", &self.parallelism)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selector", &self.selector)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "template", &self.template)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "JobSpec",
             1 +
@@ -162,10 +293,159 @@ impl crate::serde::Serialize for JobSpec {
     }
 }
 
+/// A builder of [`JobSpec`]. Created with [`JobSpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct JobSpecBuilder {
+    active_deadline_seconds: Option<i64>,
+    backoff_limit: Option<i32>,
+    completions: Option<i32>,
+    manual_selector: Option<bool>,
+    parallelism: Option<i32>,
+    selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>,
+    template: Option<crate::api::core::v1::PodTemplateSpec>,
+}
+
+#[cfg(feature = "builder")]
+impl JobSpec {
+    /// Starts building a `JobSpec`. Its required fields must be set before [`JobSpecBuilder::build`] is called.
+    pub fn builder() -> JobSpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl JobSpecBuilder {
+    /// Sets [`JobSpec::active_deadline_seconds`].
+    pub fn active_deadline_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.active_deadline_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`JobSpec::backoff_limit`].
+    pub fn backoff_limit(mut self, value: impl Into<i32>) -> Self {
+        self.backoff_limit = Some(value.into());
+        self
+    }
+
+    /// Sets [`JobSpec::completions`].
+    pub fn completions(mut self, value: impl Into<i32>) -> Self {
+        self.completions = Some(value.into());
+        self
+    }
+
+    /// Sets [`JobSpec::manual_selector`].
+    pub fn manual_selector(mut self, value: impl Into<bool>) -> Self {
+        self.manual_selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`JobSpec::parallelism`].
+    pub fn parallelism(mut self, value: impl Into<i32>) -> Self {
+        self.parallelism = Some(value.into());
+        self
+    }
+
+    /// Sets [`JobSpec::selector`].
+    pub fn selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`JobSpec::template`].
+    pub fn template(mut self, value: impl Into<crate::api::core::v1::PodTemplateSpec>) -> Self {
+        self.template = Some(value.into());
+        self
+    }
+
+    /// Builds the `JobSpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<JobSpec, crate::MissingFieldError> {
+        Ok(JobSpec {
+            active_deadline_seconds: self.active_deadline_seconds,
+            backoff_limit: self.backoff_limit,
+            completions: self.completions,
+            manual_selector: self.manual_selector,
+            parallelism: self.parallelism,
+            selector: self.selector,
+            template: self.template.ok_or_else(|| crate::MissingFieldError::new("JobSpec", "template"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`JobSpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct JobSpecApplyConfiguration {
+    /// See [`JobSpec::active_deadline_seconds`].
+    pub active_deadline_seconds: Option<i64>,
+
+    /// See [`JobSpec::backoff_limit`].
+    pub backoff_limit: Option<i32>,
+
+    /// See [`JobSpec::completions`].
+    pub completions: Option<i32>,
+
+    /// See [`JobSpec::manual_selector`].
+    pub manual_selector: Option<bool>,
+
+    /// See [`JobSpec::parallelism`].
+    pub parallelism: Option<i32>,
+
+    /// See [`JobSpec::selector`].
+    pub selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorApplyConfiguration>,
+
+    /// See [`JobSpec::template`].
+    pub template: Option<crate::api::core::v1::PodTemplateSpecApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for JobSpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "JobSpecApplyConfiguration",
+            self.active_deadline_seconds.as_ref().map_or(0, |_| 1) +
+            self.backoff_limit.as_ref().map_or(0, |_| 1) +
+            self.completions.as_ref().map_or(0, |_| 1) +
+            self.manual_selector.as_ref().map_or(0, |_| 1) +
+            self.parallelism.as_ref().map_or(0, |_| 1) +
+            self.selector.as_ref().map_or(0, |_| 1) +
+            self.template.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.active_deadline_seconds {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "activeDeadlineSeconds", value)?;
+        }
+        if let Some(value) = &self.backoff_limit {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "backoffLimit", value)?;
+        }
+        if let Some(value) = &self.completions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "completions", value)?;
+        }
+        if let Some(value) = &self.manual_selector {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "manualSelector", value)?;
+        }
+        if let Some(value) = &self.parallelism {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "parallelism", value)?;
+        }
+        if let Some(value) = &self.selector {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selector", value)?;
+        }
+        if let Some(value) = &self.template {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "template", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for JobSpec {
     fn schema_name() -> String {
-        "io.k8s.api.batch.v1.JobSpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::batch::v1::JobSpec".to_owned()
+        }
+        else {
+            "io.k8s.api.batch.v1.JobSpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -176,8 +456,9 @@ impl crate::schemars::JsonSchema for JobSpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "activeDeadlineSeconds".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -188,8 +469,8 @@ impl crate::schemars::JsonSchema for JobSpec {
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "backoffLimit".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -200,8 +481,8 @@ impl crate::schemars::JsonSchema for JobSpec {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "completions".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -212,8 +493,8 @@ impl crate::schemars::JsonSchema for JobSpec {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "manualSelector".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -223,8 +504,8 @@ impl crate::schemars::JsonSchema for JobSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "parallelism".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -235,8 +516,8 @@ impl crate::schemars::JsonSchema for JobSpec {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "selector".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>().into_object();
@@ -246,8 +527,8 @@ impl crate::schemars::JsonSchema for JobSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "template".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::PodTemplateSpec>().into_object();
@@ -257,8 +538,9 @@ impl crate::schemars::JsonSchema for JobSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "template",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -268,3 +550,18 @@ impl crate::schemars::JsonSchema for JobSpec {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for JobSpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(JobSpec {
+            active_deadline_seconds: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            backoff_limit: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            completions: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            manual_selector: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            parallelism: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            selector: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            template: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.batch.v1.JobStatus
 
 /// JobStatus represents the current state of a Job.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct JobStatus {
     /// The number of actively running pods.
     pub active: Option<i32>,
@@ -22,47 +22,69 @@ pub struct JobStatus {
     pub succeeded: Option<i32>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for JobStatus {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_active,
-            Key_completion_time,
-            Key_conditions,
-            Key_failed,
-            Key_start_time,
-            Key_succeeded,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl JobStatus {
+    /// Returns this value with [`JobStatus::active`] set to the given value.
+    #[must_use]
+    pub fn with_active(mut self, value: impl Into<i32>) -> Self {
+        self.active = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "active" => Field::Key_active,
-                            "completionTime" => Field::Key_completion_time,
-                            "conditions" => Field::Key_conditions,
-                            "failed" => Field::Key_failed,
-                            "startTime" => Field::Key_start_time,
-                            "succeeded" => Field::Key_succeeded,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`JobStatus::completion_time`] set to the given value.
+    #[must_use]
+    pub fn with_completion_time(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.completion_time = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`JobStatus::conditions`] set to the given value.
+    #[must_use]
+    pub fn with_conditions(mut self, value: impl Into<Vec<crate::api::batch::v1::JobCondition>>) -> Self {
+        self.conditions = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JobStatus::failed`] set to the given value.
+    #[must_use]
+    pub fn with_failed(mut self, value: impl Into<i32>) -> Self {
+        self.failed = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JobStatus::start_time`] set to the given value.
+    #[must_use]
+    pub fn with_start_time(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JobStatus::succeeded`] set to the given value.
+    #[must_use]
+    pub fn with_succeeded(mut self, value: impl Into<i32>) -> Self {
+        self.succeeded = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for JobStatus {
+    const FIELD_PATCH_STRATEGIES: &'static [crate::FieldPatchStrategy] = &[
+        crate::FieldPatchStrategy { field: "conditions", merge: true, retain_keys: false, merge_key: Some("type") },
+    ];
+}
 
+impl JobStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "active",
+        "completionTime",
+        "conditions",
+        "failed",
+        "startTime",
+        "succeeded",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for JobStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -80,15 +102,15 @@ impl<'de> crate::serde::Deserialize<'de> for JobStatus {
                 let mut value_start_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
                 let mut value_succeeded: Option<i32> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("JobStatus", JobStatus::FIELDS))? {
                     match key {
-                        Field::Key_active => value_active = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_completion_time => value_completion_time = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_conditions => value_conditions = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_failed => value_failed = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_start_time => value_start_time = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_succeeded => value_succeeded = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_active = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_completion_time = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_conditions = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_failed = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_start_time = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_succeeded = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -101,25 +123,110 @@ impl<'de> crate::serde::Deserialize<'de> for JobStatus {
                     succeeded: value_succeeded,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(JobStatus {
+                    active: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    completion_time: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    conditions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    failed: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    start_time: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    succeeded: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "JobStatus",
-            &[
-                "active",
-                "completionTime",
-                "conditions",
-                "failed",
-                "startTime",
-                "succeeded",
-            ],
+            JobStatus::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, JobStatus> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("JobStatus")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_active = false;
+                let mut has_completion_time = false;
+                let mut has_conditions = false;
+                let mut has_failed = false;
+                let mut has_start_time = false;
+                let mut has_succeeded = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("JobStatus", JobStatus::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.active))?; has_active = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.completion_time))?; has_completion_time = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.conditions))?; has_conditions = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.failed))?; has_failed = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.start_time))?; has_start_time = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.succeeded))?; has_succeeded = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_active {
+                    self.0.active = None;
+                }
+                if !has_completion_time {
+                    self.0.completion_time = None;
+                }
+                if !has_conditions {
+                    self.0.conditions = None;
+                }
+                if !has_failed {
+                    self.0.failed = None;
+                }
+                if !has_start_time {
+                    self.0.start_time = None;
+                }
+                if !has_succeeded {
+                    self.0.succeeded = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.active))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.completion_time))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.conditions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.failed))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.start_time))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.succeeded))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "JobStatus",
+            JobStatus::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for JobStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("JobStatus", 6)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "active", &self.active)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "completionTime", &self.completion_time)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conditions", &self.conditions)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "failed", &self.failed)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "startTime", &self.start_time)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "succeeded", &self.succeeded)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "JobStatus",
             self.active.as_ref().map_or(0, |_| 1) +
@@ -151,10 +258,144 @@ impl crate::serde::Serialize for JobStatus {
     }
 }
 
+/// A builder of [`JobStatus`]. Created with [`JobStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct JobStatusBuilder {
+    active: Option<i32>,
+    completion_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+    conditions: Option<Vec<crate::api::batch::v1::JobCondition>>,
+    failed: Option<i32>,
+    start_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+    succeeded: Option<i32>,
+}
+
+#[cfg(feature = "builder")]
+impl JobStatus {
+    /// Starts building a `JobStatus`. Its required fields must be set before [`JobStatusBuilder::build`] is called.
+    pub fn builder() -> JobStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl JobStatusBuilder {
+    /// Sets [`JobStatus::active`].
+    pub fn active(mut self, value: impl Into<i32>) -> Self {
+        self.active = Some(value.into());
+        self
+    }
+
+    /// Sets [`JobStatus::completion_time`].
+    pub fn completion_time(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.completion_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`JobStatus::conditions`].
+    pub fn conditions(mut self, value: impl Into<Vec<crate::api::batch::v1::JobCondition>>) -> Self {
+        self.conditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`JobStatus::failed`].
+    pub fn failed(mut self, value: impl Into<i32>) -> Self {
+        self.failed = Some(value.into());
+        self
+    }
+
+    /// Sets [`JobStatus::start_time`].
+    pub fn start_time(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`JobStatus::succeeded`].
+    pub fn succeeded(mut self, value: impl Into<i32>) -> Self {
+        self.succeeded = Some(value.into());
+        self
+    }
+
+    /// Builds the `JobStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<JobStatus, crate::MissingFieldError> {
+        Ok(JobStatus {
+            active: self.active,
+            completion_time: self.completion_time,
+            conditions: self.conditions,
+            failed: self.failed,
+            start_time: self.start_time,
+            succeeded: self.succeeded,
+        })
+    }
+}
+
+/// The apply configuration of [`JobStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct JobStatusApplyConfiguration {
+    /// See [`JobStatus::active`].
+    pub active: Option<i32>,
+
+    /// See [`JobStatus::completion_time`].
+    pub completion_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+
+    /// See [`JobStatus::conditions`].
+    pub conditions: Option<Vec<crate::api::batch::v1::JobConditionApplyConfiguration>>,
+
+    /// See [`JobStatus::failed`].
+    pub failed: Option<i32>,
+
+    /// See [`JobStatus::start_time`].
+    pub start_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+
+    /// See [`JobStatus::succeeded`].
+    pub succeeded: Option<i32>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for JobStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "JobStatusApplyConfiguration",
+            self.active.as_ref().map_or(0, |_| 1) +
+            self.completion_time.as_ref().map_or(0, |_| 1) +
+            self.conditions.as_ref().map_or(0, |_| 1) +
+            self.failed.as_ref().map_or(0, |_| 1) +
+            self.start_time.as_ref().map_or(0, |_| 1) +
+            self.succeeded.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.active {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "active", value)?;
+        }
+        if let Some(value) = &self.completion_time {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "completionTime", value)?;
+        }
+        if let Some(value) = &self.conditions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conditions", value)?;
+        }
+        if let Some(value) = &self.failed {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "failed", value)?;
+        }
+        if let Some(value) = &self.start_time {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "startTime", value)?;
+        }
+        if let Some(value) = &self.succeeded {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "succeeded", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for JobStatus {
     fn schema_name() -> String {
-        "io.k8s.api.batch.v1.JobStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::batch::v1::JobStatus".to_owned()
+        }
+        else {
+            "io.k8s.api.batch.v1.JobStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -165,8 +406,9 @@ impl crate::schemars::JsonSchema for JobStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "active".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -177,8 +419,8 @@ impl crate::schemars::JsonSchema for JobStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "completionTime".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::Time>().into_object();
@@ -188,8 +430,8 @@ impl crate::schemars::JsonSchema for JobStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "conditions".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -203,8 +445,8 @@ impl crate::schemars::JsonSchema for JobStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "failed".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -215,8 +457,8 @@ impl crate::schemars::JsonSchema for JobStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "startTime".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::Time>().into_object();
@@ -226,8 +468,8 @@ impl crate::schemars::JsonSchema for JobStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "succeeded".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -238,11 +480,26 @@ impl crate::schemars::JsonSchema for JobStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for JobStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(JobStatus {
+            active: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            completion_time: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            conditions: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            failed: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            start_time: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            succeeded: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
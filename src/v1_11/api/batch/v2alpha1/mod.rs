@@ -1,14 +1,30 @@
 
 mod cron_job;
 pub use self::cron_job::CronJob;
+#[cfg(feature = "builder")]
+pub use self::cron_job::CronJobBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::cron_job::CronJobApplyConfiguration;
 #[cfg(feature = "api")] pub use self::cron_job::{ReadNamespacedCronJobOptional, ReadNamespacedCronJobResponse};
 #[cfg(feature = "api")] pub use self::cron_job::{ReadNamespacedCronJobStatusOptional, ReadNamespacedCronJobStatusResponse};
 
 mod cron_job_spec;
 pub use self::cron_job_spec::CronJobSpec;
+#[cfg(feature = "builder")]
+pub use self::cron_job_spec::CronJobSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::cron_job_spec::CronJobSpecApplyConfiguration;
 
 mod cron_job_status;
 pub use self::cron_job_status::CronJobStatus;
+#[cfg(feature = "builder")]
+pub use self::cron_job_status::CronJobStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::cron_job_status::CronJobStatusApplyConfiguration;
 
 mod job_template_spec;
 pub use self::job_template_spec::JobTemplateSpec;
+#[cfg(feature = "builder")]
+pub use self::job_template_spec::JobTemplateSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::job_template_spec::JobTemplateSpecApplyConfiguration;
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.certificates.v1beta1.CertificateSigningRequestSpec
 
 /// This information is immutable after the request is created. Only the Request and Usages fields can be set on creation, other fields are derived by Kubernetes and cannot be modified by users.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct CertificateSigningRequestSpec {
     /// Extra information about the requesting user. See user.Info interface for details.
     pub extra: Option<std::collections::BTreeMap<String, Vec<String>>>,
@@ -23,47 +23,79 @@ pub struct CertificateSigningRequestSpec {
     pub username: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for CertificateSigningRequestSpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_extra,
-            Key_groups,
-            Key_request,
-            Key_uid,
-            Key_usages,
-            Key_username,
-            Other,
+impl CertificateSigningRequestSpec {
+    /// Constructs a `CertificateSigningRequestSpec` from its required fields. Its optional fields are unset.
+    pub fn new(request: impl Into<crate::ByteString>) -> Self {
+        CertificateSigningRequestSpec {
+            extra: None,
+            groups: None,
+            request: request.into(),
+            uid: None,
+            usages: None,
+            username: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl CertificateSigningRequestSpec {
+    /// Returns this value with [`CertificateSigningRequestSpec::extra`] set to the given value.
+    #[must_use]
+    pub fn with_extra(mut self, value: impl Into<std::collections::BTreeMap<String, Vec<String>>>) -> Self {
+        self.extra = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`CertificateSigningRequestSpec::groups`] set to the given value.
+    #[must_use]
+    pub fn with_groups(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.groups = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`CertificateSigningRequestSpec::request`] set to the given value.
+    #[must_use]
+    pub fn with_request(mut self, value: impl Into<crate::ByteString>) -> Self {
+        self.request = value.into();
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "extra" => Field::Key_extra,
-                            "groups" => Field::Key_groups,
-                            "request" => Field::Key_request,
-                            "uid" => Field::Key_uid,
-                            "usages" => Field::Key_usages,
-                            "username" => Field::Key_username,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`CertificateSigningRequestSpec::uid`] set to the given value.
+    #[must_use]
+    pub fn with_uid(mut self, value: impl Into<String>) -> Self {
+        self.uid = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`CertificateSigningRequestSpec::usages`] set to the given value.
+    #[must_use]
+    pub fn with_usages(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.usages = Some(value.into());
+        self
+    }
 
+    /// Returns this value with [`CertificateSigningRequestSpec::username`] set to the given value.
+    #[must_use]
+    pub fn with_username(mut self, value: impl Into<String>) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for CertificateSigningRequestSpec {}
+
+impl CertificateSigningRequestSpec {
+    const FIELDS: &'static [&'static str] = &[
+        "extra",
+        "groups",
+        "request",
+        "uid",
+        "usages",
+        "username",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for CertificateSigningRequestSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -81,46 +113,131 @@ impl<'de> crate::serde::Deserialize<'de> for CertificateSigningRequestSpec {
                 let mut value_usages: Option<Vec<String>> = None;
                 let mut value_username: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CertificateSigningRequestSpec", CertificateSigningRequestSpec::FIELDS))? {
                     match key {
-                        Field::Key_extra => value_extra = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_groups => value_groups = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_request => value_request = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_uid => value_uid = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_usages => value_usages = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_username => value_username = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_extra = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_groups = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_request = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_uid = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_usages = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_username = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(CertificateSigningRequestSpec {
                     extra: value_extra,
                     groups: value_groups,
-                    request: value_request.ok_or_else(|| crate::serde::de::Error::missing_field("request"))?,
+                    request: value_request.ok_or_else(|| crate::serde2::missing_field("CertificateSigningRequestSpec", "request", &["request"]))?,
                     uid: value_uid,
                     usages: value_usages,
                     username: value_username,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(CertificateSigningRequestSpec {
+                    extra: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    groups: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    request: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    uid: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    usages: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    username: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "CertificateSigningRequestSpec",
-            &[
-                "extra",
-                "groups",
-                "request",
-                "uid",
-                "usages",
-                "username",
-            ],
+            CertificateSigningRequestSpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, CertificateSigningRequestSpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("CertificateSigningRequestSpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_extra = false;
+                let mut has_groups = false;
+                let mut has_request = false;
+                let mut has_uid = false;
+                let mut has_usages = false;
+                let mut has_username = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CertificateSigningRequestSpec", CertificateSigningRequestSpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.extra))?; has_extra = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.groups))?; has_groups = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.request))?; has_request = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.uid))?; has_uid = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.usages))?; has_usages = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.username))?; has_username = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_extra {
+                    self.0.extra = None;
+                }
+                if !has_groups {
+                    self.0.groups = None;
+                }
+                if !has_request {
+                    return Err(crate::serde2::missing_field("CertificateSigningRequestSpec", "request", &["request"]));
+                }
+                if !has_uid {
+                    self.0.uid = None;
+                }
+                if !has_usages {
+                    self.0.usages = None;
+                }
+                if !has_username {
+                    self.0.username = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.extra))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.groups))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.request))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.uid))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.usages))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.username))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "CertificateSigningRequestSpec",
+            CertificateSigningRequestSpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for CertificateSigningRequestSpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("CertificateSigningRequestSpec", 6)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "extra", &self.extra)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "groups", &self.groups)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "request", &self.request)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "uid", &self.uid)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "usages", &self.usages)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "username", &self.username)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "CertificateSigningRequestSpec",
             1 +
@@ -150,10 +267,144 @@ impl crate::serde::Serialize for CertificateSigningRequestSpec {
     }
 }
 
+/// A builder of [`CertificateSigningRequestSpec`]. Created with [`CertificateSigningRequestSpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct CertificateSigningRequestSpecBuilder {
+    extra: Option<std::collections::BTreeMap<String, Vec<String>>>,
+    groups: Option<Vec<String>>,
+    request: Option<crate::ByteString>,
+    uid: Option<String>,
+    usages: Option<Vec<String>>,
+    username: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl CertificateSigningRequestSpec {
+    /// Starts building a `CertificateSigningRequestSpec`. Its required fields must be set before [`CertificateSigningRequestSpecBuilder::build`] is called.
+    pub fn builder() -> CertificateSigningRequestSpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl CertificateSigningRequestSpecBuilder {
+    /// Sets [`CertificateSigningRequestSpec::extra`].
+    pub fn extra(mut self, value: impl Into<std::collections::BTreeMap<String, Vec<String>>>) -> Self {
+        self.extra = Some(value.into());
+        self
+    }
+
+    /// Sets [`CertificateSigningRequestSpec::groups`].
+    pub fn groups(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.groups = Some(value.into());
+        self
+    }
+
+    /// Sets [`CertificateSigningRequestSpec::request`].
+    pub fn request(mut self, value: impl Into<crate::ByteString>) -> Self {
+        self.request = Some(value.into());
+        self
+    }
+
+    /// Sets [`CertificateSigningRequestSpec::uid`].
+    pub fn uid(mut self, value: impl Into<String>) -> Self {
+        self.uid = Some(value.into());
+        self
+    }
+
+    /// Sets [`CertificateSigningRequestSpec::usages`].
+    pub fn usages(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.usages = Some(value.into());
+        self
+    }
+
+    /// Sets [`CertificateSigningRequestSpec::username`].
+    pub fn username(mut self, value: impl Into<String>) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `CertificateSigningRequestSpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<CertificateSigningRequestSpec, crate::MissingFieldError> {
+        Ok(CertificateSigningRequestSpec {
+            extra: self.extra,
+            groups: self.groups,
+            request: self.request.ok_or_else(|| crate::MissingFieldError::new("CertificateSigningRequestSpec", "request"))?,
+            uid: self.uid,
+            usages: self.usages,
+            username: self.username,
+        })
+    }
+}
+
+/// The apply configuration of [`CertificateSigningRequestSpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CertificateSigningRequestSpecApplyConfiguration {
+    /// See [`CertificateSigningRequestSpec::extra`].
+    pub extra: Option<std::collections::BTreeMap<String, Vec<String>>>,
+
+    /// See [`CertificateSigningRequestSpec::groups`].
+    pub groups: Option<Vec<String>>,
+
+    /// See [`CertificateSigningRequestSpec::request`].
+    pub request: Option<crate::ByteString>,
+
+    /// See [`CertificateSigningRequestSpec::uid`].
+    pub uid: Option<String>,
+
+    /// See [`CertificateSigningRequestSpec::usages`].
+    pub usages: Option<Vec<String>>,
+
+    /// See [`CertificateSigningRequestSpec::username`].
+    pub username: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for CertificateSigningRequestSpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "CertificateSigningRequestSpecApplyConfiguration",
+            self.extra.as_ref().map_or(0, |_| 1) +
+            self.groups.as_ref().map_or(0, |_| 1) +
+            self.request.as_ref().map_or(0, |_| 1) +
+            self.uid.as_ref().map_or(0, |_| 1) +
+            self.usages.as_ref().map_or(0, |_| 1) +
+            self.username.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.extra {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "extra", value)?;
+        }
+        if let Some(value) = &self.groups {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "groups", value)?;
+        }
+        if let Some(value) = &self.request {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "request", value)?;
+        }
+        if let Some(value) = &self.uid {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "uid", value)?;
+        }
+        if let Some(value) = &self.usages {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "usages", value)?;
+        }
+        if let Some(value) = &self.username {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "username", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CertificateSigningRequestSpec {
     fn schema_name() -> String {
-        "io.k8s.api.certificates.v1beta1.CertificateSigningRequestSpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::certificates::v1beta1::CertificateSigningRequestSpec".to_owned()
+        }
+        else {
+            "io.k8s.api.certificates.v1beta1.CertificateSigningRequestSpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -164,8 +415,9 @@ impl crate::schemars::JsonSchema for CertificateSigningRequestSpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "extra".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -193,8 +445,8 @@ impl crate::schemars::JsonSchema for CertificateSigningRequestSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "groups".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -213,8 +465,8 @@ impl crate::schemars::JsonSchema for CertificateSigningRequestSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "request".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -225,8 +477,8 @@ impl crate::schemars::JsonSchema for CertificateSigningRequestSpec {
                             format: Some("byte".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "uid".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -236,8 +488,8 @@ impl crate::schemars::JsonSchema for CertificateSigningRequestSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "usages".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -256,8 +508,8 @@ impl crate::schemars::JsonSchema for CertificateSigningRequestSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "username".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -267,8 +519,9 @@ impl crate::schemars::JsonSchema for CertificateSigningRequestSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "request",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -278,3 +531,17 @@ impl crate::schemars::JsonSchema for CertificateSigningRequestSpec {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for CertificateSigningRequestSpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(CertificateSigningRequestSpec {
+            extra: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)))?,
+            groups: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            request: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            uid: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            usages: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            username: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,14 +1,30 @@
 
 mod certificate_signing_request;
 pub use self::certificate_signing_request::CertificateSigningRequest;
+#[cfg(feature = "builder")]
+pub use self::certificate_signing_request::CertificateSigningRequestBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::certificate_signing_request::CertificateSigningRequestApplyConfiguration;
 #[cfg(feature = "api")] pub use self::certificate_signing_request::{ReadCertificateSigningRequestOptional, ReadCertificateSigningRequestResponse};
 #[cfg(feature = "api")] pub use self::certificate_signing_request::{ReadCertificateSigningRequestStatusOptional, ReadCertificateSigningRequestStatusResponse};
 
 mod certificate_signing_request_condition;
 pub use self::certificate_signing_request_condition::CertificateSigningRequestCondition;
+#[cfg(feature = "builder")]
+pub use self::certificate_signing_request_condition::CertificateSigningRequestConditionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::certificate_signing_request_condition::CertificateSigningRequestConditionApplyConfiguration;
 
 mod certificate_signing_request_spec;
 pub use self::certificate_signing_request_spec::CertificateSigningRequestSpec;
+#[cfg(feature = "builder")]
+pub use self::certificate_signing_request_spec::CertificateSigningRequestSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::certificate_signing_request_spec::CertificateSigningRequestSpecApplyConfiguration;
 
 mod certificate_signing_request_status;
 pub use self::certificate_signing_request_status::CertificateSigningRequestStatus;
+#[cfg(feature = "builder")]
+pub use self::certificate_signing_request_status::CertificateSigningRequestStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::certificate_signing_request_status::CertificateSigningRequestStatusApplyConfiguration;
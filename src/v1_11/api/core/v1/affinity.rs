@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.Affinity
 
 /// Affinity is a group of affinity scheduling rules.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct Affinity {
     /// Describes node affinity scheduling rules for the pod.
     pub node_affinity: Option<crate::api::core::v1::NodeAffinity>,
@@ -13,41 +13,41 @@ pub struct Affinity {
     pub pod_anti_affinity: Option<crate::api::core::v1::PodAntiAffinity>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for Affinity {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_node_affinity,
-            Key_pod_affinity,
-            Key_pod_anti_affinity,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl Affinity {
+    /// Returns this value with [`Affinity::node_affinity`] set to the given value.
+    #[must_use]
+    pub fn with_node_affinity(mut self, value: impl Into<crate::api::core::v1::NodeAffinity>) -> Self {
+        self.node_affinity = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`Affinity::pod_affinity`] set to the given value.
+    #[must_use]
+    pub fn with_pod_affinity(mut self, value: impl Into<crate::api::core::v1::PodAffinity>) -> Self {
+        self.pod_affinity = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`Affinity::pod_anti_affinity`] set to the given value.
+    #[must_use]
+    pub fn with_pod_anti_affinity(mut self, value: impl Into<crate::api::core::v1::PodAntiAffinity>) -> Self {
+        self.pod_anti_affinity = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "nodeAffinity" => Field::Key_node_affinity,
-                            "podAffinity" => Field::Key_pod_affinity,
-                            "podAntiAffinity" => Field::Key_pod_anti_affinity,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for Affinity {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl Affinity {
+    const FIELDS: &'static [&'static str] = &[
+        "nodeAffinity",
+        "podAffinity",
+        "podAntiAffinity",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for Affinity {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,12 +62,12 @@ impl<'de> crate::serde::Deserialize<'de> for Affinity {
                 let mut value_pod_affinity: Option<crate::api::core::v1::PodAffinity> = None;
                 let mut value_pod_anti_affinity: Option<crate::api::core::v1::PodAntiAffinity> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Affinity", Affinity::FIELDS))? {
                     match key {
-                        Field::Key_node_affinity => value_node_affinity = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_pod_affinity => value_pod_affinity = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_pod_anti_affinity => value_pod_anti_affinity = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_node_affinity = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_pod_affinity = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_pod_anti_affinity = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -77,22 +77,86 @@ impl<'de> crate::serde::Deserialize<'de> for Affinity {
                     pod_anti_affinity: value_pod_anti_affinity,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(Affinity {
+                    node_affinity: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    pod_affinity: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    pod_anti_affinity: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "Affinity",
-            &[
-                "nodeAffinity",
-                "podAffinity",
-                "podAntiAffinity",
-            ],
+            Affinity::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, Affinity> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("Affinity")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_node_affinity = false;
+                let mut has_pod_affinity = false;
+                let mut has_pod_anti_affinity = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Affinity", Affinity::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.node_affinity))?; has_node_affinity = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.pod_affinity))?; has_pod_affinity = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.pod_anti_affinity))?; has_pod_anti_affinity = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_node_affinity {
+                    self.0.node_affinity = None;
+                }
+                if !has_pod_affinity {
+                    self.0.pod_affinity = None;
+                }
+                if !has_pod_anti_affinity {
+                    self.0.pod_anti_affinity = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.node_affinity))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.pod_affinity))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.pod_anti_affinity))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "Affinity",
+            Affinity::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for Affinity {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("Affinity", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeAffinity", &self.node_affinity)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "podAffinity", &self.pod_affinity)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "podAntiAffinity", &self.pod_anti_affinity)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "Affinity",
             self.node_affinity.as_ref().map_or(0, |_| 1) +
@@ -112,10 +176,51 @@ impl crate::serde::Serialize for Affinity {
     }
 }
 
+/// The apply configuration of [`Affinity`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AffinityApplyConfiguration {
+    /// See [`Affinity::node_affinity`].
+    pub node_affinity: Option<crate::api::core::v1::NodeAffinityApplyConfiguration>,
+
+    /// See [`Affinity::pod_affinity`].
+    pub pod_affinity: Option<crate::api::core::v1::PodAffinityApplyConfiguration>,
+
+    /// See [`Affinity::pod_anti_affinity`].
+    pub pod_anti_affinity: Option<crate::api::core::v1::PodAntiAffinityApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for AffinityApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "AffinityApplyConfiguration",
+            self.node_affinity.as_ref().map_or(0, |_| 1) +
+            self.pod_affinity.as_ref().map_or(0, |_| 1) +
+            self.pod_anti_affinity.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.node_affinity {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeAffinity", value)?;
+        }
+        if let Some(value) = &self.pod_affinity {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "podAffinity", value)?;
+        }
+        if let Some(value) = &self.pod_anti_affinity {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "podAntiAffinity", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Affinity {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.Affinity".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::Affinity".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.Affinity".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -126,8 +231,9 @@ impl crate::schemars::JsonSchema for Affinity {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "nodeAffinity".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::NodeAffinity>().into_object();
@@ -137,8 +243,8 @@ impl crate::schemars::JsonSchema for Affinity {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "podAffinity".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::PodAffinity>().into_object();
@@ -148,8 +254,8 @@ impl crate::schemars::JsonSchema for Affinity {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "podAntiAffinity".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::PodAntiAffinity>().into_object();
@@ -159,11 +265,23 @@ impl crate::schemars::JsonSchema for Affinity {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for Affinity {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(Affinity {
+            node_affinity: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            pod_affinity: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            pod_anti_affinity: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
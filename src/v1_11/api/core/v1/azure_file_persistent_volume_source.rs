@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.AzureFilePersistentVolumeSource
 
 /// AzureFile represents an Azure File Service mount on the host and bind mount to the pod.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct AzureFilePersistentVolumeSource {
     /// Defaults to false (read/write). ReadOnly here will force the ReadOnly setting in VolumeMounts.
     pub read_only: Option<bool>,
@@ -16,43 +16,61 @@ pub struct AzureFilePersistentVolumeSource {
     pub share_name: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for AzureFilePersistentVolumeSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_read_only,
-            Key_secret_name,
-            Key_secret_namespace,
-            Key_share_name,
-            Other,
+impl AzureFilePersistentVolumeSource {
+    /// Constructs a `AzureFilePersistentVolumeSource` from its required fields. Its optional fields are unset.
+    pub fn new(secret_name: impl Into<String>, share_name: impl Into<String>) -> Self {
+        AzureFilePersistentVolumeSource {
+            read_only: None,
+            secret_name: secret_name.into(),
+            secret_namespace: None,
+            share_name: share_name.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl AzureFilePersistentVolumeSource {
+    /// Returns this value with [`AzureFilePersistentVolumeSource::read_only`] set to the given value.
+    #[must_use]
+    pub fn with_read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`AzureFilePersistentVolumeSource::secret_name`] set to the given value.
+    #[must_use]
+    pub fn with_secret_name(mut self, value: impl Into<String>) -> Self {
+        self.secret_name = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`AzureFilePersistentVolumeSource::secret_namespace`] set to the given value.
+    #[must_use]
+    pub fn with_secret_namespace(mut self, value: impl Into<String>) -> Self {
+        self.secret_namespace = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "readOnly" => Field::Key_read_only,
-                            "secretName" => Field::Key_secret_name,
-                            "secretNamespace" => Field::Key_secret_namespace,
-                            "shareName" => Field::Key_share_name,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`AzureFilePersistentVolumeSource::share_name`] set to the given value.
+    #[must_use]
+    pub fn with_share_name(mut self, value: impl Into<String>) -> Self {
+        self.share_name = value.into();
+        self
+    }
+}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl crate::MergeStrategyInfo for AzureFilePersistentVolumeSource {}
+
+impl AzureFilePersistentVolumeSource {
+    const FIELDS: &'static [&'static str] = &[
+        "readOnly",
+        "secretName",
+        "secretNamespace",
+        "shareName",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for AzureFilePersistentVolumeSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -68,40 +86,111 @@ impl<'de> crate::serde::Deserialize<'de> for AzureFilePersistentVolumeSource {
                 let mut value_secret_namespace: Option<String> = None;
                 let mut value_share_name: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("AzureFilePersistentVolumeSource", AzureFilePersistentVolumeSource::FIELDS))? {
                     match key {
-                        Field::Key_read_only => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_secret_name => value_secret_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_secret_namespace => value_secret_namespace = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_share_name => value_share_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_secret_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_secret_namespace = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_share_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(AzureFilePersistentVolumeSource {
                     read_only: value_read_only,
-                    secret_name: value_secret_name.ok_or_else(|| crate::serde::de::Error::missing_field("secretName"))?,
+                    secret_name: value_secret_name.ok_or_else(|| crate::serde2::missing_field("AzureFilePersistentVolumeSource", "secretName", &["secretName", "shareName"]))?,
                     secret_namespace: value_secret_namespace,
-                    share_name: value_share_name.ok_or_else(|| crate::serde::de::Error::missing_field("shareName"))?,
+                    share_name: value_share_name.ok_or_else(|| crate::serde2::missing_field("AzureFilePersistentVolumeSource", "shareName", &["secretName", "shareName"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(AzureFilePersistentVolumeSource {
+                    read_only: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    secret_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    secret_namespace: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    share_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "AzureFilePersistentVolumeSource",
-            &[
-                "readOnly",
-                "secretName",
-                "secretNamespace",
-                "shareName",
-            ],
+            AzureFilePersistentVolumeSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, AzureFilePersistentVolumeSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("AzureFilePersistentVolumeSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_read_only = false;
+                let mut has_secret_name = false;
+                let mut has_secret_namespace = false;
+                let mut has_share_name = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("AzureFilePersistentVolumeSource", AzureFilePersistentVolumeSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.read_only))?; has_read_only = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.secret_name))?; has_secret_name = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.secret_namespace))?; has_secret_namespace = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.share_name))?; has_share_name = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_read_only {
+                    self.0.read_only = None;
+                }
+                if !has_secret_name {
+                    return Err(crate::serde2::missing_field("AzureFilePersistentVolumeSource", "secretName", &["secretName", "shareName"]));
+                }
+                if !has_secret_namespace {
+                    self.0.secret_namespace = None;
+                }
+                if !has_share_name {
+                    return Err(crate::serde2::missing_field("AzureFilePersistentVolumeSource", "shareName", &["secretName", "shareName"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.read_only))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.secret_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.secret_namespace))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.share_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "AzureFilePersistentVolumeSource",
+            AzureFilePersistentVolumeSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for AzureFilePersistentVolumeSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("AzureFilePersistentVolumeSource", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", &self.read_only)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretName", &self.secret_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretNamespace", &self.secret_namespace)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "shareName", &self.share_name)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "AzureFilePersistentVolumeSource",
             2 +
@@ -120,10 +209,114 @@ impl crate::serde::Serialize for AzureFilePersistentVolumeSource {
     }
 }
 
+/// A builder of [`AzureFilePersistentVolumeSource`]. Created with [`AzureFilePersistentVolumeSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct AzureFilePersistentVolumeSourceBuilder {
+    read_only: Option<bool>,
+    secret_name: Option<String>,
+    secret_namespace: Option<String>,
+    share_name: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl AzureFilePersistentVolumeSource {
+    /// Starts building a `AzureFilePersistentVolumeSource`. Its required fields must be set before [`AzureFilePersistentVolumeSourceBuilder::build`] is called.
+    pub fn builder() -> AzureFilePersistentVolumeSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl AzureFilePersistentVolumeSourceBuilder {
+    /// Sets [`AzureFilePersistentVolumeSource::read_only`].
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`AzureFilePersistentVolumeSource::secret_name`].
+    pub fn secret_name(mut self, value: impl Into<String>) -> Self {
+        self.secret_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`AzureFilePersistentVolumeSource::secret_namespace`].
+    pub fn secret_namespace(mut self, value: impl Into<String>) -> Self {
+        self.secret_namespace = Some(value.into());
+        self
+    }
+
+    /// Sets [`AzureFilePersistentVolumeSource::share_name`].
+    pub fn share_name(mut self, value: impl Into<String>) -> Self {
+        self.share_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `AzureFilePersistentVolumeSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<AzureFilePersistentVolumeSource, crate::MissingFieldError> {
+        Ok(AzureFilePersistentVolumeSource {
+            read_only: self.read_only,
+            secret_name: self.secret_name.ok_or_else(|| crate::MissingFieldError::new("AzureFilePersistentVolumeSource", "secretName"))?,
+            secret_namespace: self.secret_namespace,
+            share_name: self.share_name.ok_or_else(|| crate::MissingFieldError::new("AzureFilePersistentVolumeSource", "shareName"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`AzureFilePersistentVolumeSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AzureFilePersistentVolumeSourceApplyConfiguration {
+    /// See [`AzureFilePersistentVolumeSource::read_only`].
+    pub read_only: Option<bool>,
+
+    /// See [`AzureFilePersistentVolumeSource::secret_name`].
+    pub secret_name: Option<String>,
+
+    /// See [`AzureFilePersistentVolumeSource::secret_namespace`].
+    pub secret_namespace: Option<String>,
+
+    /// See [`AzureFilePersistentVolumeSource::share_name`].
+    pub share_name: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for AzureFilePersistentVolumeSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "AzureFilePersistentVolumeSourceApplyConfiguration",
+            self.read_only.as_ref().map_or(0, |_| 1) +
+            self.secret_name.as_ref().map_or(0, |_| 1) +
+            self.secret_namespace.as_ref().map_or(0, |_| 1) +
+            self.share_name.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.read_only {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", value)?;
+        }
+        if let Some(value) = &self.secret_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretName", value)?;
+        }
+        if let Some(value) = &self.secret_namespace {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretNamespace", value)?;
+        }
+        if let Some(value) = &self.share_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "shareName", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for AzureFilePersistentVolumeSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.AzureFilePersistentVolumeSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::AzureFilePersistentVolumeSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.AzureFilePersistentVolumeSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -134,8 +327,9 @@ impl crate::schemars::JsonSchema for AzureFilePersistentVolumeSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "readOnly".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -145,8 +339,8 @@ impl crate::schemars::JsonSchema for AzureFilePersistentVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "secretName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -156,8 +350,8 @@ impl crate::schemars::JsonSchema for AzureFilePersistentVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "secretNamespace".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -167,8 +361,8 @@ impl crate::schemars::JsonSchema for AzureFilePersistentVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "shareName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -178,8 +372,9 @@ impl crate::schemars::JsonSchema for AzureFilePersistentVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "secretName",
                     "shareName",
@@ -190,3 +385,15 @@ impl crate::schemars::JsonSchema for AzureFilePersistentVolumeSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for AzureFilePersistentVolumeSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(AzureFilePersistentVolumeSource {
+            read_only: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            secret_name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            secret_namespace: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            share_name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.CephFSPersistentVolumeSource
 
 /// Represents a Ceph Filesystem mount that lasts the lifetime of a pod Cephfs volumes do not support ownership management or SELinux relabeling.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct CephFSPersistentVolumeSource {
     /// Required: Monitors is a collection of Ceph monitors More info: https://releases.k8s.io/HEAD/examples/volumes/cephfs/README.md#how-to-use-it
     pub monitors: Vec<String>,
@@ -22,47 +22,79 @@ pub struct CephFSPersistentVolumeSource {
     pub user: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for CephFSPersistentVolumeSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_monitors,
-            Key_path,
-            Key_read_only,
-            Key_secret_file,
-            Key_secret_ref,
-            Key_user,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl CephFSPersistentVolumeSource {
+    /// Constructs a `CephFSPersistentVolumeSource` from its required fields. Its optional fields are unset.
+    pub fn new(monitors: impl Into<Vec<String>>) -> Self {
+        CephFSPersistentVolumeSource {
+            monitors: monitors.into(),
+            path: None,
+            read_only: None,
+            secret_file: None,
+            secret_ref: None,
+            user: None,
+        }
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "monitors" => Field::Key_monitors,
-                            "path" => Field::Key_path,
-                            "readOnly" => Field::Key_read_only,
-                            "secretFile" => Field::Key_secret_file,
-                            "secretRef" => Field::Key_secret_ref,
-                            "user" => Field::Key_user,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl CephFSPersistentVolumeSource {
+    /// Returns this value with [`CephFSPersistentVolumeSource::monitors`] set to the given value.
+    #[must_use]
+    pub fn with_monitors(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.monitors = value.into();
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`CephFSPersistentVolumeSource::path`] set to the given value.
+    #[must_use]
+    pub fn with_path(mut self, value: impl Into<String>) -> Self {
+        self.path = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`CephFSPersistentVolumeSource::read_only`] set to the given value.
+    #[must_use]
+    pub fn with_read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`CephFSPersistentVolumeSource::secret_file`] set to the given value.
+    #[must_use]
+    pub fn with_secret_file(mut self, value: impl Into<String>) -> Self {
+        self.secret_file = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`CephFSPersistentVolumeSource::secret_ref`] set to the given value.
+    #[must_use]
+    pub fn with_secret_ref(mut self, value: impl Into<crate::api::core::v1::SecretReference>) -> Self {
+        self.secret_ref = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`CephFSPersistentVolumeSource::user`] set to the given value.
+    #[must_use]
+    pub fn with_user(mut self, value: impl Into<String>) -> Self {
+        self.user = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for CephFSPersistentVolumeSource {}
+
+impl CephFSPersistentVolumeSource {
+    const FIELDS: &'static [&'static str] = &[
+        "monitors",
+        "path",
+        "readOnly",
+        "secretFile",
+        "secretRef",
+        "user",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for CephFSPersistentVolumeSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -80,20 +112,20 @@ impl<'de> crate::serde::Deserialize<'de> for CephFSPersistentVolumeSource {
                 let mut value_secret_ref: Option<crate::api::core::v1::SecretReference> = None;
                 let mut value_user: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CephFSPersistentVolumeSource", CephFSPersistentVolumeSource::FIELDS))? {
                     match key {
-                        Field::Key_monitors => value_monitors = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_path => value_path = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_read_only => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_secret_file => value_secret_file = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_secret_ref => value_secret_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_user => value_user = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_monitors = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_path = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_secret_file = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_secret_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_user = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(CephFSPersistentVolumeSource {
-                    monitors: value_monitors.ok_or_else(|| crate::serde::de::Error::missing_field("monitors"))?,
+                    monitors: value_monitors.ok_or_else(|| crate::serde2::missing_field("CephFSPersistentVolumeSource", "monitors", &["monitors"]))?,
                     path: value_path,
                     read_only: value_read_only,
                     secret_file: value_secret_file,
@@ -101,25 +133,110 @@ impl<'de> crate::serde::Deserialize<'de> for CephFSPersistentVolumeSource {
                     user: value_user,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(CephFSPersistentVolumeSource {
+                    monitors: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    path: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    read_only: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    secret_file: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    secret_ref: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    user: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "CephFSPersistentVolumeSource",
-            &[
-                "monitors",
-                "path",
-                "readOnly",
-                "secretFile",
-                "secretRef",
-                "user",
-            ],
+            CephFSPersistentVolumeSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, CephFSPersistentVolumeSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("CephFSPersistentVolumeSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_monitors = false;
+                let mut has_path = false;
+                let mut has_read_only = false;
+                let mut has_secret_file = false;
+                let mut has_secret_ref = false;
+                let mut has_user = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CephFSPersistentVolumeSource", CephFSPersistentVolumeSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.monitors))?; has_monitors = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.path))?; has_path = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.read_only))?; has_read_only = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.secret_file))?; has_secret_file = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.secret_ref))?; has_secret_ref = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.user))?; has_user = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_monitors {
+                    return Err(crate::serde2::missing_field("CephFSPersistentVolumeSource", "monitors", &["monitors"]));
+                }
+                if !has_path {
+                    self.0.path = None;
+                }
+                if !has_read_only {
+                    self.0.read_only = None;
+                }
+                if !has_secret_file {
+                    self.0.secret_file = None;
+                }
+                if !has_secret_ref {
+                    self.0.secret_ref = None;
+                }
+                if !has_user {
+                    self.0.user = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.monitors))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.path))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.read_only))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.secret_file))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.secret_ref))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.user))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "CephFSPersistentVolumeSource",
+            CephFSPersistentVolumeSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for CephFSPersistentVolumeSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("CephFSPersistentVolumeSource", 6)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "monitors", &self.monitors)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "path", &self.path)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", &self.read_only)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretFile", &self.secret_file)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretRef", &self.secret_ref)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "user", &self.user)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "CephFSPersistentVolumeSource",
             1 +
@@ -149,10 +266,144 @@ impl crate::serde::Serialize for CephFSPersistentVolumeSource {
     }
 }
 
+/// A builder of [`CephFSPersistentVolumeSource`]. Created with [`CephFSPersistentVolumeSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct CephFSPersistentVolumeSourceBuilder {
+    monitors: Option<Vec<String>>,
+    path: Option<String>,
+    read_only: Option<bool>,
+    secret_file: Option<String>,
+    secret_ref: Option<crate::api::core::v1::SecretReference>,
+    user: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl CephFSPersistentVolumeSource {
+    /// Starts building a `CephFSPersistentVolumeSource`. Its required fields must be set before [`CephFSPersistentVolumeSourceBuilder::build`] is called.
+    pub fn builder() -> CephFSPersistentVolumeSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl CephFSPersistentVolumeSourceBuilder {
+    /// Sets [`CephFSPersistentVolumeSource::monitors`].
+    pub fn monitors(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.monitors = Some(value.into());
+        self
+    }
+
+    /// Sets [`CephFSPersistentVolumeSource::path`].
+    pub fn path(mut self, value: impl Into<String>) -> Self {
+        self.path = Some(value.into());
+        self
+    }
+
+    /// Sets [`CephFSPersistentVolumeSource::read_only`].
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`CephFSPersistentVolumeSource::secret_file`].
+    pub fn secret_file(mut self, value: impl Into<String>) -> Self {
+        self.secret_file = Some(value.into());
+        self
+    }
+
+    /// Sets [`CephFSPersistentVolumeSource::secret_ref`].
+    pub fn secret_ref(mut self, value: impl Into<crate::api::core::v1::SecretReference>) -> Self {
+        self.secret_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`CephFSPersistentVolumeSource::user`].
+    pub fn user(mut self, value: impl Into<String>) -> Self {
+        self.user = Some(value.into());
+        self
+    }
+
+    /// Builds the `CephFSPersistentVolumeSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<CephFSPersistentVolumeSource, crate::MissingFieldError> {
+        Ok(CephFSPersistentVolumeSource {
+            monitors: self.monitors.ok_or_else(|| crate::MissingFieldError::new("CephFSPersistentVolumeSource", "monitors"))?,
+            path: self.path,
+            read_only: self.read_only,
+            secret_file: self.secret_file,
+            secret_ref: self.secret_ref,
+            user: self.user,
+        })
+    }
+}
+
+/// The apply configuration of [`CephFSPersistentVolumeSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CephFSPersistentVolumeSourceApplyConfiguration {
+    /// See [`CephFSPersistentVolumeSource::monitors`].
+    pub monitors: Option<Vec<String>>,
+
+    /// See [`CephFSPersistentVolumeSource::path`].
+    pub path: Option<String>,
+
+    /// See [`CephFSPersistentVolumeSource::read_only`].
+    pub read_only: Option<bool>,
+
+    /// See [`CephFSPersistentVolumeSource::secret_file`].
+    pub secret_file: Option<String>,
+
+    /// See [`CephFSPersistentVolumeSource::secret_ref`].
+    pub secret_ref: Option<crate::api::core::v1::SecretReferenceApplyConfiguration>,
+
+    /// See [`CephFSPersistentVolumeSource::user`].
+    pub user: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for CephFSPersistentVolumeSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "CephFSPersistentVolumeSourceApplyConfiguration",
+            self.monitors.as_ref().map_or(0, |_| 1) +
+            self.path.as_ref().map_or(0, |_| 1) +
+            self.read_only.as_ref().map_or(0, |_| 1) +
+            self.secret_file.as_ref().map_or(0, |_| 1) +
+            self.secret_ref.as_ref().map_or(0, |_| 1) +
+            self.user.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.monitors {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "monitors", value)?;
+        }
+        if let Some(value) = &self.path {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "path", value)?;
+        }
+        if let Some(value) = &self.read_only {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", value)?;
+        }
+        if let Some(value) = &self.secret_file {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretFile", value)?;
+        }
+        if let Some(value) = &self.secret_ref {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretRef", value)?;
+        }
+        if let Some(value) = &self.user {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "user", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CephFSPersistentVolumeSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.CephFSPersistentVolumeSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::CephFSPersistentVolumeSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.CephFSPersistentVolumeSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -163,8 +414,9 @@ impl crate::schemars::JsonSchema for CephFSPersistentVolumeSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "monitors".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -183,8 +435,8 @@ impl crate::schemars::JsonSchema for CephFSPersistentVolumeSource {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "path".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -194,8 +446,8 @@ impl crate::schemars::JsonSchema for CephFSPersistentVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "readOnly".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -205,8 +457,8 @@ impl crate::schemars::JsonSchema for CephFSPersistentVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "secretFile".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -216,8 +468,8 @@ impl crate::schemars::JsonSchema for CephFSPersistentVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "secretRef".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::SecretReference>().into_object();
@@ -227,8 +479,8 @@ impl crate::schemars::JsonSchema for CephFSPersistentVolumeSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "user".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -238,8 +490,9 @@ impl crate::schemars::JsonSchema for CephFSPersistentVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "monitors",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -249,3 +502,17 @@ impl crate::schemars::JsonSchema for CephFSPersistentVolumeSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for CephFSPersistentVolumeSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(CephFSPersistentVolumeSource {
+            monitors: crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            path: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            read_only: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            secret_file: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            secret_ref: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            user: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
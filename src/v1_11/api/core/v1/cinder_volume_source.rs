@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.CinderVolumeSource
 
 /// Represents a cinder volume resource in Openstack. A Cinder volume must exist before mounting to a container. The volume must also be in the same region as the kubelet. Cinder volumes support ownership management and SELinux relabeling.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct CinderVolumeSource {
     /// Filesystem type to mount. Must be a filesystem type supported by the host operating system. Examples: "ext4", "xfs", "ntfs". Implicitly inferred to be "ext4" if unspecified. More info: https://releases.k8s.io/HEAD/examples/mysql-cinder-pd/README.md
     pub fs_type: Option<String>,
@@ -16,43 +16,61 @@ pub struct CinderVolumeSource {
     pub volume_id: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for CinderVolumeSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_fs_type,
-            Key_read_only,
-            Key_secret_ref,
-            Key_volume_id,
-            Other,
+impl CinderVolumeSource {
+    /// Constructs a `CinderVolumeSource` from its required fields. Its optional fields are unset.
+    pub fn new(volume_id: impl Into<String>) -> Self {
+        CinderVolumeSource {
+            fs_type: None,
+            read_only: None,
+            secret_ref: None,
+            volume_id: volume_id.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl CinderVolumeSource {
+    /// Returns this value with [`CinderVolumeSource::fs_type`] set to the given value.
+    #[must_use]
+    pub fn with_fs_type(mut self, value: impl Into<String>) -> Self {
+        self.fs_type = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`CinderVolumeSource::read_only`] set to the given value.
+    #[must_use]
+    pub fn with_read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`CinderVolumeSource::secret_ref`] set to the given value.
+    #[must_use]
+    pub fn with_secret_ref(mut self, value: impl Into<crate::api::core::v1::LocalObjectReference>) -> Self {
+        self.secret_ref = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "fsType" => Field::Key_fs_type,
-                            "readOnly" => Field::Key_read_only,
-                            "secretRef" => Field::Key_secret_ref,
-                            "volumeID" => Field::Key_volume_id,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`CinderVolumeSource::volume_id`] set to the given value.
+    #[must_use]
+    pub fn with_volume_id(mut self, value: impl Into<String>) -> Self {
+        self.volume_id = value.into();
+        self
+    }
+}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl crate::MergeStrategyInfo for CinderVolumeSource {}
+
+impl CinderVolumeSource {
+    const FIELDS: &'static [&'static str] = &[
+        "fsType",
+        "readOnly",
+        "secretRef",
+        "volumeID",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for CinderVolumeSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -68,13 +86,13 @@ impl<'de> crate::serde::Deserialize<'de> for CinderVolumeSource {
                 let mut value_secret_ref: Option<crate::api::core::v1::LocalObjectReference> = None;
                 let mut value_volume_id: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CinderVolumeSource", CinderVolumeSource::FIELDS))? {
                     match key {
-                        Field::Key_fs_type => value_fs_type = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_read_only => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_secret_ref => value_secret_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_volume_id => value_volume_id = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_fs_type = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_secret_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_volume_id = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -82,26 +100,97 @@ impl<'de> crate::serde::Deserialize<'de> for CinderVolumeSource {
                     fs_type: value_fs_type,
                     read_only: value_read_only,
                     secret_ref: value_secret_ref,
-                    volume_id: value_volume_id.ok_or_else(|| crate::serde::de::Error::missing_field("volumeID"))?,
+                    volume_id: value_volume_id.ok_or_else(|| crate::serde2::missing_field("CinderVolumeSource", "volumeID", &["volumeID"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(CinderVolumeSource {
+                    fs_type: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    read_only: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    secret_ref: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    volume_id: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "CinderVolumeSource",
-            &[
-                "fsType",
-                "readOnly",
-                "secretRef",
-                "volumeID",
-            ],
+            CinderVolumeSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, CinderVolumeSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("CinderVolumeSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_fs_type = false;
+                let mut has_read_only = false;
+                let mut has_secret_ref = false;
+                let mut has_volume_id = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CinderVolumeSource", CinderVolumeSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.fs_type))?; has_fs_type = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.read_only))?; has_read_only = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.secret_ref))?; has_secret_ref = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.volume_id))?; has_volume_id = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_fs_type {
+                    self.0.fs_type = None;
+                }
+                if !has_read_only {
+                    self.0.read_only = None;
+                }
+                if !has_secret_ref {
+                    self.0.secret_ref = None;
+                }
+                if !has_volume_id {
+                    return Err(crate::serde2::missing_field("CinderVolumeSource", "volumeID", &["volumeID"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.fs_type))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.read_only))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.secret_ref))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.volume_id))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "CinderVolumeSource",
+            CinderVolumeSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for CinderVolumeSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("CinderVolumeSource", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsType", &self.fs_type)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", &self.read_only)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretRef", &self.secret_ref)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeID", &self.volume_id)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "CinderVolumeSource",
             1 +
@@ -123,10 +212,114 @@ impl crate::serde::Serialize for CinderVolumeSource {
     }
 }
 
+/// A builder of [`CinderVolumeSource`]. Created with [`CinderVolumeSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct CinderVolumeSourceBuilder {
+    fs_type: Option<String>,
+    read_only: Option<bool>,
+    secret_ref: Option<crate::api::core::v1::LocalObjectReference>,
+    volume_id: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl CinderVolumeSource {
+    /// Starts building a `CinderVolumeSource`. Its required fields must be set before [`CinderVolumeSourceBuilder::build`] is called.
+    pub fn builder() -> CinderVolumeSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl CinderVolumeSourceBuilder {
+    /// Sets [`CinderVolumeSource::fs_type`].
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`CinderVolumeSource::read_only`].
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`CinderVolumeSource::secret_ref`].
+    pub fn secret_ref(mut self, value: impl Into<crate::api::core::v1::LocalObjectReference>) -> Self {
+        self.secret_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`CinderVolumeSource::volume_id`].
+    pub fn volume_id(mut self, value: impl Into<String>) -> Self {
+        self.volume_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CinderVolumeSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<CinderVolumeSource, crate::MissingFieldError> {
+        Ok(CinderVolumeSource {
+            fs_type: self.fs_type,
+            read_only: self.read_only,
+            secret_ref: self.secret_ref,
+            volume_id: self.volume_id.ok_or_else(|| crate::MissingFieldError::new("CinderVolumeSource", "volumeID"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`CinderVolumeSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CinderVolumeSourceApplyConfiguration {
+    /// See [`CinderVolumeSource::fs_type`].
+    pub fs_type: Option<String>,
+
+    /// See [`CinderVolumeSource::read_only`].
+    pub read_only: Option<bool>,
+
+    /// See [`CinderVolumeSource::secret_ref`].
+    pub secret_ref: Option<crate::api::core::v1::LocalObjectReferenceApplyConfiguration>,
+
+    /// See [`CinderVolumeSource::volume_id`].
+    pub volume_id: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for CinderVolumeSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "CinderVolumeSourceApplyConfiguration",
+            self.fs_type.as_ref().map_or(0, |_| 1) +
+            self.read_only.as_ref().map_or(0, |_| 1) +
+            self.secret_ref.as_ref().map_or(0, |_| 1) +
+            self.volume_id.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.fs_type {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsType", value)?;
+        }
+        if let Some(value) = &self.read_only {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", value)?;
+        }
+        if let Some(value) = &self.secret_ref {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretRef", value)?;
+        }
+        if let Some(value) = &self.volume_id {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeID", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CinderVolumeSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.CinderVolumeSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::CinderVolumeSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.CinderVolumeSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -137,8 +330,9 @@ impl crate::schemars::JsonSchema for CinderVolumeSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "fsType".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -148,8 +342,8 @@ impl crate::schemars::JsonSchema for CinderVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "readOnly".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -159,8 +353,8 @@ impl crate::schemars::JsonSchema for CinderVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "secretRef".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::LocalObjectReference>().into_object();
@@ -170,8 +364,8 @@ impl crate::schemars::JsonSchema for CinderVolumeSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "volumeID".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -181,8 +375,9 @@ impl crate::schemars::JsonSchema for CinderVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "volumeID",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -192,3 +387,15 @@ impl crate::schemars::JsonSchema for CinderVolumeSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for CinderVolumeSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(CinderVolumeSource {
+            fs_type: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            read_only: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            secret_ref: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            volume_id: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.ConfigMap
 
 /// ConfigMap holds configuration data for pods to consume.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ConfigMap {
     /// BinaryData contains the binary data. Each key must consist of alphanumeric characters, '-', '_' or '.'. BinaryData can contain byte sequences that are not in the UTF-8 range. The keys stored in BinaryData must not overlap with the ones in the Data field, this is enforced during validation process. Using this field will require 1.10+ apiserver and kubelet.
     pub binary_data: Option<std::collections::BTreeMap<String, crate::ByteString>>,
@@ -13,6 +13,40 @@ pub struct ConfigMap {
     pub metadata: crate::apimachinery::pkg::apis::meta::v1::ObjectMeta,
 }
 
+impl ConfigMap {
+    /// Constructs a `ConfigMap` from its required fields. Its optional fields are unset.
+    pub fn new(metadata: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        ConfigMap {
+            binary_data: None,
+            data: None,
+            metadata: metadata.into(),
+        }
+    }
+}
+
+impl ConfigMap {
+    /// Returns this value with [`ConfigMap::binary_data`] set to the given value.
+    #[must_use]
+    pub fn with_binary_data(mut self, value: impl Into<std::collections::BTreeMap<String, crate::ByteString>>) -> Self {
+        self.binary_data = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ConfigMap::data`] set to the given value.
+    #[must_use]
+    pub fn with_data(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.data = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ConfigMap::metadata`] set to the given value.
+    #[must_use]
+    pub fn with_metadata(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        self.metadata = value.into();
+        self
+    }
+}
+
 // Begin /v1/ConfigMap
 
 // Generated from operation createCoreV1NamespacedConfigMap
@@ -39,6 +73,7 @@ impl ConfigMap {
         body: &crate::api::core::v1::ConfigMap,
         optional: crate::CreateOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::CreateResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/api/v1/namespaces/{namespace}/configmaps?",
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -82,6 +117,7 @@ impl ConfigMap {
         delete_optional: crate::DeleteOptional<'_>,
         list_optional: crate::ListOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::DeleteResponse<crate::List<Self>>>), crate::RequestError> {
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/api/v1/namespaces/{namespace}/configmaps?",
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -125,6 +161,8 @@ impl ConfigMap {
         namespace: &str,
         optional: crate::DeleteOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::DeleteResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/api/v1/namespaces/{namespace}/configmaps/{name}",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -195,6 +233,7 @@ impl ConfigMap {
         namespace: &str,
         optional: crate::ListOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::ListResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/api/v1/namespaces/{namespace}/configmaps?",
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -240,6 +279,8 @@ impl ConfigMap {
         body: &crate::apimachinery::pkg::apis::meta::v1::Patch,
         optional: crate::PatchOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::PatchResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/api/v1/namespaces/{namespace}/configmaps/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -293,6 +334,8 @@ impl ConfigMap {
             export,
             pretty,
         } = optional;
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/api/v1/namespaces/{namespace}/configmaps/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -397,6 +440,8 @@ impl ConfigMap {
         body: &crate::api::core::v1::ConfigMap,
         optional: crate::ReplaceOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::ReplaceResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/api/v1/namespaces/{namespace}/configmaps/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -470,6 +515,7 @@ impl ConfigMap {
         namespace: &str,
         optional: crate::WatchOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::WatchResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/api/v1/namespaces/{namespace}/configmaps?",
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -494,6 +540,9 @@ impl crate::Resource for ConfigMap {
     const KIND: &'static str = "ConfigMap";
     const VERSION: &'static str = "v1";
     const URL_PATH_SEGMENT: &'static str = "configmaps";
+    const VERBS: &'static [&'static str] = &["create", "delete", "deletecollection", "get", "list", "patch", "update", "watch"];
+    const SINGULAR: &'static str = "configmap";
+    const SHORT_NAMES: &'static [&'static str] = &["cm"];
     type Scope = crate::NamespaceResourceScope;
 }
 
@@ -513,45 +562,30 @@ impl crate::Metadata for ConfigMap {
     }
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ConfigMap {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_api_version,
-            Key_kind,
-            Key_binary_data,
-            Key_data,
-            Key_metadata,
-            Other,
+impl std::fmt::Display for ConfigMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(<Self as crate::Resource>::URL_PATH_SEGMENT)?;
+        for part in [&self.metadata.namespace, &self.metadata.name].iter().copied().flatten() {
+            write!(f, "/{part}")?;
         }
+        Ok(())
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
-
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "apiVersion" => Field::Key_api_version,
-                            "kind" => Field::Key_kind,
-                            "binaryData" => Field::Key_binary_data,
-                            "data" => Field::Key_data,
-                            "metadata" => Field::Key_metadata,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for ConfigMap {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl ConfigMap {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "kind",
+        "binaryData",
+        "data",
+        "metadata",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ConfigMap {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -566,51 +600,125 @@ impl<'de> crate::serde::Deserialize<'de> for ConfigMap {
                 let mut value_data: Option<std::collections::BTreeMap<String, String>> = None;
                 let mut value_metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ConfigMap", ConfigMap::FIELDS))? {
                     match key {
-                        Field::Key_api_version => {
+                        Some(0) => {
                             let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_api_version != <Self::Value as crate::Resource>::API_VERSION {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<Self::Value as crate::Resource>::API_VERSION));
                             }
                         },
-                        Field::Key_kind => {
+                        Some(1) => {
                             let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_kind != <Self::Value as crate::Resource>::KIND {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<Self::Value as crate::Resource>::KIND));
                             }
                         },
-                        Field::Key_binary_data => value_binary_data = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_data => value_data = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_metadata => value_metadata = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(2) => value_binary_data = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_data = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_metadata = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ConfigMap {
                     binary_data: value_binary_data,
                     data: value_data,
-                    metadata: value_metadata.ok_or_else(|| crate::serde::de::Error::missing_field("metadata"))?,
+                    metadata: value_metadata.ok_or_else(|| crate::serde2::missing_field("ConfigMap", "metadata", &["metadata"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ConfigMap {
+                    binary_data: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    data: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    metadata: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             <Self as crate::Resource>::KIND,
-            &[
-                "apiVersion",
-                "kind",
-                "binaryData",
-                "data",
-                "metadata",
-            ],
+            ConfigMap::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ConfigMap> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<ConfigMap as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_binary_data = false;
+                let mut has_data = false;
+                let mut has_metadata = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ConfigMap", ConfigMap::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <ConfigMap as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<ConfigMap as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <ConfigMap as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<ConfigMap as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.binary_data))?; has_binary_data = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.data))?; has_data = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metadata))?; has_metadata = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_binary_data {
+                    self.0.binary_data = None;
+                }
+                if !has_data {
+                    self.0.data = None;
+                }
+                if !has_metadata {
+                    return Err(crate::serde2::missing_field("ConfigMap", "metadata", &["metadata"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.binary_data))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.data))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metadata))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            ConfigMap::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ConfigMap {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct(<Self as crate::Resource>::KIND, 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "binaryData", &self.binary_data)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "data", &self.data)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", &self.metadata)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             <Self as crate::Resource>::KIND,
             3 +
@@ -630,10 +738,102 @@ impl crate::serde::Serialize for ConfigMap {
     }
 }
 
+/// A builder of [`ConfigMap`]. Created with [`ConfigMap::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ConfigMapBuilder {
+    binary_data: Option<std::collections::BTreeMap<String, crate::ByteString>>,
+    data: Option<std::collections::BTreeMap<String, String>>,
+    metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>,
+}
+
+#[cfg(feature = "builder")]
+impl ConfigMap {
+    /// Starts building a `ConfigMap`. Its required fields must be set before [`ConfigMapBuilder::build`] is called.
+    pub fn builder() -> ConfigMapBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ConfigMapBuilder {
+    /// Sets [`ConfigMap::binary_data`].
+    pub fn binary_data(mut self, value: impl Into<std::collections::BTreeMap<String, crate::ByteString>>) -> Self {
+        self.binary_data = Some(value.into());
+        self
+    }
+
+    /// Sets [`ConfigMap::data`].
+    pub fn data(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.data = Some(value.into());
+        self
+    }
+
+    /// Sets [`ConfigMap::metadata`].
+    pub fn metadata(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        self.metadata = Some(value.into());
+        self
+    }
+
+    /// Builds the `ConfigMap`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ConfigMap, crate::MissingFieldError> {
+        Ok(ConfigMap {
+            binary_data: self.binary_data,
+            data: self.data,
+            metadata: self.metadata.ok_or_else(|| crate::MissingFieldError::new("ConfigMap", "metadata"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`ConfigMap`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConfigMapApplyConfiguration {
+    /// See [`ConfigMap::binary_data`].
+    pub binary_data: Option<std::collections::BTreeMap<String, crate::ByteString>>,
+
+    /// See [`ConfigMap::data`].
+    pub data: Option<std::collections::BTreeMap<String, String>>,
+
+    /// See [`ConfigMap::metadata`].
+    pub metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMetaApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ConfigMapApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            <ConfigMap as crate::Resource>::KIND,
+            2 +
+            self.binary_data.as_ref().map_or(0, |_| 1) +
+            self.data.as_ref().map_or(0, |_| 1) +
+            self.metadata.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <ConfigMap as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <ConfigMap as crate::Resource>::KIND)?;
+        if let Some(value) = &self.binary_data {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "binaryData", value)?;
+        }
+        if let Some(value) = &self.data {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "data", value)?;
+        }
+        if let Some(value) = &self.metadata {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ConfigMap {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.ConfigMap".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::ConfigMap".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.ConfigMap".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -644,8 +844,9 @@ impl crate::schemars::JsonSchema for ConfigMap {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "apiVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -653,10 +854,11 @@ impl crate::schemars::JsonSchema for ConfigMap {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("v1".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "binaryData".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -676,8 +878,8 @@ impl crate::schemars::JsonSchema for ConfigMap {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "data".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -696,8 +898,8 @@ impl crate::schemars::JsonSchema for ConfigMap {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "kind".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -705,10 +907,11 @@ impl crate::schemars::JsonSchema for ConfigMap {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("ConfigMap".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "metadata".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>().into_object();
@@ -718,9 +921,12 @@ impl crate::schemars::JsonSchema for ConfigMap {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
+                    "apiVersion",
+                    "kind",
                     "metadata",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
                 ..Default::default()
@@ -729,3 +935,14 @@ impl crate::schemars::JsonSchema for ConfigMap {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ConfigMap {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ConfigMap {
+            binary_data: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            data: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            metadata: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
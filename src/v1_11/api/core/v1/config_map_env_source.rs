@@ -3,7 +3,7 @@
 /// ConfigMapEnvSource selects a ConfigMap to populate the environment variables with.
 ///
 /// The contents of the target ConfigMap's Data field will represent the key-value pairs as environment variables.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ConfigMapEnvSource {
     /// Name of the referent. More info: https://kubernetes.io/docs/concepts/overview/working-with-objects/names/#names
     pub name: Option<String>,
@@ -12,39 +12,33 @@ pub struct ConfigMapEnvSource {
     pub optional: Option<bool>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ConfigMapEnvSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_name,
-            Key_optional,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl ConfigMapEnvSource {
+    /// Returns this value with [`ConfigMapEnvSource::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ConfigMapEnvSource::optional`] set to the given value.
+    #[must_use]
+    pub fn with_optional(mut self, value: impl Into<bool>) -> Self {
+        self.optional = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "name" => Field::Key_name,
-                            "optional" => Field::Key_optional,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for ConfigMapEnvSource {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl ConfigMapEnvSource {
+    const FIELDS: &'static [&'static str] = &[
+        "name",
+        "optional",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ConfigMapEnvSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -58,11 +52,11 @@ impl<'de> crate::serde::Deserialize<'de> for ConfigMapEnvSource {
                 let mut value_name: Option<String> = None;
                 let mut value_optional: Option<bool> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ConfigMapEnvSource", ConfigMapEnvSource::FIELDS))? {
                     match key {
-                        Field::Key_name => value_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_optional => value_optional = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_optional = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -71,21 +65,78 @@ impl<'de> crate::serde::Deserialize<'de> for ConfigMapEnvSource {
                     optional: value_optional,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ConfigMapEnvSource {
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    optional: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "ConfigMapEnvSource",
-            &[
-                "name",
-                "optional",
-            ],
+            ConfigMapEnvSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ConfigMapEnvSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ConfigMapEnvSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_name = false;
+                let mut has_optional = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ConfigMapEnvSource", ConfigMapEnvSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.name))?; has_name = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.optional))?; has_optional = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_name {
+                    self.0.name = None;
+                }
+                if !has_optional {
+                    self.0.optional = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.optional))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ConfigMapEnvSource",
+            ConfigMapEnvSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ConfigMapEnvSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ConfigMapEnvSource", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "optional", &self.optional)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ConfigMapEnvSource",
             self.name.as_ref().map_or(0, |_| 1) +
@@ -101,10 +152,84 @@ impl crate::serde::Serialize for ConfigMapEnvSource {
     }
 }
 
+/// A builder of [`ConfigMapEnvSource`]. Created with [`ConfigMapEnvSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ConfigMapEnvSourceBuilder {
+    name: Option<String>,
+    optional: Option<bool>,
+}
+
+#[cfg(feature = "builder")]
+impl ConfigMapEnvSource {
+    /// Starts building a `ConfigMapEnvSource`. Its required fields must be set before [`ConfigMapEnvSourceBuilder::build`] is called.
+    pub fn builder() -> ConfigMapEnvSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ConfigMapEnvSourceBuilder {
+    /// Sets [`ConfigMapEnvSource::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`ConfigMapEnvSource::optional`].
+    pub fn optional(mut self, value: impl Into<bool>) -> Self {
+        self.optional = Some(value.into());
+        self
+    }
+
+    /// Builds the `ConfigMapEnvSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ConfigMapEnvSource, crate::MissingFieldError> {
+        Ok(ConfigMapEnvSource {
+            name: self.name,
+            optional: self.optional,
+        })
+    }
+}
+
+/// The apply configuration of [`ConfigMapEnvSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConfigMapEnvSourceApplyConfiguration {
+    /// See [`ConfigMapEnvSource::name`].
+    pub name: Option<String>,
+
+    /// See [`ConfigMapEnvSource::optional`].
+    pub optional: Option<bool>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ConfigMapEnvSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ConfigMapEnvSourceApplyConfiguration",
+            self.name.as_ref().map_or(0, |_| 1) +
+            self.optional.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        if let Some(value) = &self.optional {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "optional", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ConfigMapEnvSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.ConfigMapEnvSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::ConfigMapEnvSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.ConfigMapEnvSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -115,8 +240,9 @@ impl crate::schemars::JsonSchema for ConfigMapEnvSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -126,8 +252,8 @@ impl crate::schemars::JsonSchema for ConfigMapEnvSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "optional".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -137,11 +263,22 @@ impl crate::schemars::JsonSchema for ConfigMapEnvSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ConfigMapEnvSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ConfigMapEnvSource {
+            name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            optional: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
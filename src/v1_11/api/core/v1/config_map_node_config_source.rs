@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.ConfigMapNodeConfigSource
 
 /// ConfigMapNodeConfigSource contains the information to reference a ConfigMap as a config source for the Node.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ConfigMapNodeConfigSource {
     /// KubeletConfigKey declares which key of the referenced ConfigMap corresponds to the KubeletConfiguration structure This field is required in all cases.
     pub kubelet_config_key: String,
@@ -19,45 +19,70 @@ pub struct ConfigMapNodeConfigSource {
     pub uid: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ConfigMapNodeConfigSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_kubelet_config_key,
-            Key_name,
-            Key_namespace,
-            Key_resource_version,
-            Key_uid,
-            Other,
+impl ConfigMapNodeConfigSource {
+    /// Constructs a `ConfigMapNodeConfigSource` from its required fields. Its optional fields are unset.
+    pub fn new(kubelet_config_key: impl Into<String>, name: impl Into<String>, namespace: impl Into<String>) -> Self {
+        ConfigMapNodeConfigSource {
+            kubelet_config_key: kubelet_config_key.into(),
+            name: name.into(),
+            namespace: namespace.into(),
+            resource_version: None,
+            uid: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl ConfigMapNodeConfigSource {
+    /// Returns this value with [`ConfigMapNodeConfigSource::kubelet_config_key`] set to the given value.
+    #[must_use]
+    pub fn with_kubelet_config_key(mut self, value: impl Into<String>) -> Self {
+        self.kubelet_config_key = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ConfigMapNodeConfigSource::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ConfigMapNodeConfigSource::namespace`] set to the given value.
+    #[must_use]
+    pub fn with_namespace(mut self, value: impl Into<String>) -> Self {
+        self.namespace = value.into();
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "kubeletConfigKey" => Field::Key_kubelet_config_key,
-                            "name" => Field::Key_name,
-                            "namespace" => Field::Key_namespace,
-                            "resourceVersion" => Field::Key_resource_version,
-                            "uid" => Field::Key_uid,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`ConfigMapNodeConfigSource::resource_version`] set to the given value.
+    #[must_use]
+    pub fn with_resource_version(mut self, value: impl Into<String>) -> Self {
+        self.resource_version = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`ConfigMapNodeConfigSource::uid`] set to the given value.
+    #[must_use]
+    pub fn with_uid(mut self, value: impl Into<String>) -> Self {
+        self.uid = Some(value.into());
+        self
+    }
+}
 
+impl crate::MergeStrategyInfo for ConfigMapNodeConfigSource {}
+
+impl ConfigMapNodeConfigSource {
+    const FIELDS: &'static [&'static str] = &[
+        "kubeletConfigKey",
+        "name",
+        "namespace",
+        "resourceVersion",
+        "uid",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for ConfigMapNodeConfigSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -74,43 +99,121 @@ impl<'de> crate::serde::Deserialize<'de> for ConfigMapNodeConfigSource {
                 let mut value_resource_version: Option<String> = None;
                 let mut value_uid: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ConfigMapNodeConfigSource", ConfigMapNodeConfigSource::FIELDS))? {
                     match key {
-                        Field::Key_kubelet_config_key => value_kubelet_config_key = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_name => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_namespace => value_namespace = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_resource_version => value_resource_version = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_uid => value_uid = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_kubelet_config_key = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_namespace = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_resource_version = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_uid = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ConfigMapNodeConfigSource {
-                    kubelet_config_key: value_kubelet_config_key.ok_or_else(|| crate::serde::de::Error::missing_field("kubeletConfigKey"))?,
-                    name: value_name.ok_or_else(|| crate::serde::de::Error::missing_field("name"))?,
-                    namespace: value_namespace.ok_or_else(|| crate::serde::de::Error::missing_field("namespace"))?,
+                    kubelet_config_key: value_kubelet_config_key.ok_or_else(|| crate::serde2::missing_field("ConfigMapNodeConfigSource", "kubeletConfigKey", &["kubeletConfigKey", "name", "namespace"]))?,
+                    name: value_name.ok_or_else(|| crate::serde2::missing_field("ConfigMapNodeConfigSource", "name", &["kubeletConfigKey", "name", "namespace"]))?,
+                    namespace: value_namespace.ok_or_else(|| crate::serde2::missing_field("ConfigMapNodeConfigSource", "namespace", &["kubeletConfigKey", "name", "namespace"]))?,
                     resource_version: value_resource_version,
                     uid: value_uid,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ConfigMapNodeConfigSource {
+                    kubelet_config_key: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    namespace: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    resource_version: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    uid: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "ConfigMapNodeConfigSource",
-            &[
-                "kubeletConfigKey",
-                "name",
-                "namespace",
-                "resourceVersion",
-                "uid",
-            ],
+            ConfigMapNodeConfigSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ConfigMapNodeConfigSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ConfigMapNodeConfigSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_kubelet_config_key = false;
+                let mut has_name = false;
+                let mut has_namespace = false;
+                let mut has_resource_version = false;
+                let mut has_uid = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ConfigMapNodeConfigSource", ConfigMapNodeConfigSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.kubelet_config_key))?; has_kubelet_config_key = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.name))?; has_name = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.namespace))?; has_namespace = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.resource_version))?; has_resource_version = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.uid))?; has_uid = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_kubelet_config_key {
+                    return Err(crate::serde2::missing_field("ConfigMapNodeConfigSource", "kubeletConfigKey", &["kubeletConfigKey", "name", "namespace"]));
+                }
+                if !has_name {
+                    return Err(crate::serde2::missing_field("ConfigMapNodeConfigSource", "name", &["kubeletConfigKey", "name", "namespace"]));
+                }
+                if !has_namespace {
+                    return Err(crate::serde2::missing_field("ConfigMapNodeConfigSource", "namespace", &["kubeletConfigKey", "name", "namespace"]));
+                }
+                if !has_resource_version {
+                    self.0.resource_version = None;
+                }
+                if !has_uid {
+                    self.0.uid = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.kubelet_config_key))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.namespace))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.resource_version))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.uid))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ConfigMapNodeConfigSource",
+            ConfigMapNodeConfigSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ConfigMapNodeConfigSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ConfigMapNodeConfigSource", 5)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kubeletConfigKey", &self.kubelet_config_key)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "namespace", &self.namespace)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resourceVersion", &self.resource_version)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "uid", &self.uid)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ConfigMapNodeConfigSource",
             3 +
@@ -130,10 +233,129 @@ impl crate::serde::Serialize for ConfigMapNodeConfigSource {
     }
 }
 
+/// A builder of [`ConfigMapNodeConfigSource`]. Created with [`ConfigMapNodeConfigSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ConfigMapNodeConfigSourceBuilder {
+    kubelet_config_key: Option<String>,
+    name: Option<String>,
+    namespace: Option<String>,
+    resource_version: Option<String>,
+    uid: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl ConfigMapNodeConfigSource {
+    /// Starts building a `ConfigMapNodeConfigSource`. Its required fields must be set before [`ConfigMapNodeConfigSourceBuilder::build`] is called.
+    pub fn builder() -> ConfigMapNodeConfigSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ConfigMapNodeConfigSourceBuilder {
+    /// Sets [`ConfigMapNodeConfigSource::kubelet_config_key`].
+    pub fn kubelet_config_key(mut self, value: impl Into<String>) -> Self {
+        self.kubelet_config_key = Some(value.into());
+        self
+    }
+
+    /// Sets [`ConfigMapNodeConfigSource::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`ConfigMapNodeConfigSource::namespace`].
+    pub fn namespace(mut self, value: impl Into<String>) -> Self {
+        self.namespace = Some(value.into());
+        self
+    }
+
+    /// Sets [`ConfigMapNodeConfigSource::resource_version`].
+    pub fn resource_version(mut self, value: impl Into<String>) -> Self {
+        self.resource_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`ConfigMapNodeConfigSource::uid`].
+    pub fn uid(mut self, value: impl Into<String>) -> Self {
+        self.uid = Some(value.into());
+        self
+    }
+
+    /// Builds the `ConfigMapNodeConfigSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ConfigMapNodeConfigSource, crate::MissingFieldError> {
+        Ok(ConfigMapNodeConfigSource {
+            kubelet_config_key: self.kubelet_config_key.ok_or_else(|| crate::MissingFieldError::new("ConfigMapNodeConfigSource", "kubeletConfigKey"))?,
+            name: self.name.ok_or_else(|| crate::MissingFieldError::new("ConfigMapNodeConfigSource", "name"))?,
+            namespace: self.namespace.ok_or_else(|| crate::MissingFieldError::new("ConfigMapNodeConfigSource", "namespace"))?,
+            resource_version: self.resource_version,
+            uid: self.uid,
+        })
+    }
+}
+
+/// The apply configuration of [`ConfigMapNodeConfigSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConfigMapNodeConfigSourceApplyConfiguration {
+    /// See [`ConfigMapNodeConfigSource::kubelet_config_key`].
+    pub kubelet_config_key: Option<String>,
+
+    /// See [`ConfigMapNodeConfigSource::name`].
+    pub name: Option<String>,
+
+    /// See [`ConfigMapNodeConfigSource::namespace`].
+    pub namespace: Option<String>,
+
+    /// See [`ConfigMapNodeConfigSource::resource_version`].
+    pub resource_version: Option<String>,
+
+    /// See [`ConfigMapNodeConfigSource::uid`].
+    pub uid: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ConfigMapNodeConfigSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ConfigMapNodeConfigSourceApplyConfiguration",
+            self.kubelet_config_key.as_ref().map_or(0, |_| 1) +
+            self.name.as_ref().map_or(0, |_| 1) +
+            self.namespace.as_ref().map_or(0, |_| 1) +
+            self.resource_version.as_ref().map_or(0, |_| 1) +
+            self.uid.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.kubelet_config_key {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kubeletConfigKey", value)?;
+        }
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        if let Some(value) = &self.namespace {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "namespace", value)?;
+        }
+        if let Some(value) = &self.resource_version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resourceVersion", value)?;
+        }
+        if let Some(value) = &self.uid {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "uid", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ConfigMapNodeConfigSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.ConfigMapNodeConfigSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::ConfigMapNodeConfigSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.ConfigMapNodeConfigSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -144,8 +366,9 @@ impl crate::schemars::JsonSchema for ConfigMapNodeConfigSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "kubeletConfigKey".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -155,8 +378,8 @@ impl crate::schemars::JsonSchema for ConfigMapNodeConfigSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -166,8 +389,8 @@ impl crate::schemars::JsonSchema for ConfigMapNodeConfigSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "namespace".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -177,8 +400,8 @@ impl crate::schemars::JsonSchema for ConfigMapNodeConfigSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "resourceVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -188,8 +411,8 @@ impl crate::schemars::JsonSchema for ConfigMapNodeConfigSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "uid".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -199,8 +422,9 @@ impl crate::schemars::JsonSchema for ConfigMapNodeConfigSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "kubeletConfigKey",
                     "name",
@@ -212,3 +436,16 @@ impl crate::schemars::JsonSchema for ConfigMapNodeConfigSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ConfigMapNodeConfigSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ConfigMapNodeConfigSource {
+            kubelet_config_key: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            namespace: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            resource_version: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            uid: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
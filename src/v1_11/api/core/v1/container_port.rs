@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.ContainerPort
 
 /// ContainerPort represents a network port in a single container.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ContainerPort {
     /// Number of port to expose on the pod's IP address. This must be a valid port number, 0 \< x \< 65536.
     pub container_port: i32,
@@ -16,48 +16,73 @@ pub struct ContainerPort {
     pub name: Option<String>,
 
     /// Protocol for port. Must be UDP or TCP. Defaults to "TCP".
-    pub protocol: Option<String>,
+    pub protocol: Option<crate::api::core::v1::Protocol>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ContainerPort {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_container_port,
-            Key_host_ip,
-            Key_host_port,
-            Key_name,
-            Key_protocol,
-            Other,
+impl ContainerPort {
+    /// Constructs a `ContainerPort` from its required fields. Its optional fields are unset.
+    pub fn new(container_port: impl Into<i32>) -> Self {
+        ContainerPort {
+            container_port: container_port.into(),
+            host_ip: None,
+            host_port: None,
+            name: None,
+            protocol: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl ContainerPort {
+    /// Returns this value with [`ContainerPort::container_port`] set to the given value.
+    #[must_use]
+    pub fn with_container_port(mut self, value: impl Into<i32>) -> Self {
+        self.container_port = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ContainerPort::host_ip`] set to the given value.
+    #[must_use]
+    pub fn with_host_ip(mut self, value: impl Into<String>) -> Self {
+        self.host_ip = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ContainerPort::host_port`] set to the given value.
+    #[must_use]
+    pub fn with_host_port(mut self, value: impl Into<i32>) -> Self {
+        self.host_port = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "containerPort" => Field::Key_container_port,
-                            "hostIP" => Field::Key_host_ip,
-                            "hostPort" => Field::Key_host_port,
-                            "name" => Field::Key_name,
-                            "protocol" => Field::Key_protocol,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`ContainerPort::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`ContainerPort::protocol`] set to the given value.
+    #[must_use]
+    pub fn with_protocol(mut self, value: impl Into<crate::api::core::v1::Protocol>) -> Self {
+        self.protocol = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for ContainerPort {}
+
+impl ContainerPort {
+    const FIELDS: &'static [&'static str] = &[
+        "containerPort",
+        "hostIP",
+        "hostPort",
+        "name",
+        "protocol",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ContainerPort {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -72,45 +97,123 @@ impl<'de> crate::serde::Deserialize<'de> for ContainerPort {
                 let mut value_host_ip: Option<String> = None;
                 let mut value_host_port: Option<i32> = None;
                 let mut value_name: Option<String> = None;
-                let mut value_protocol: Option<String> = None;
+                let mut value_protocol: Option<crate::api::core::v1::Protocol> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ContainerPort", ContainerPort::FIELDS))? {
                     match key {
-                        Field::Key_container_port => value_container_port = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_host_ip => value_host_ip = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_host_port => value_host_port = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_name => value_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_protocol => value_protocol = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_container_port = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_host_ip = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_host_port = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_protocol = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ContainerPort {
-                    container_port: value_container_port.ok_or_else(|| crate::serde::de::Error::missing_field("containerPort"))?,
+                    container_port: value_container_port.ok_or_else(|| crate::serde2::missing_field("ContainerPort", "containerPort", &["containerPort"]))?,
                     host_ip: value_host_ip,
                     host_port: value_host_port,
                     name: value_name,
                     protocol: value_protocol,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ContainerPort {
+                    container_port: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    host_ip: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    host_port: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    protocol: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "ContainerPort",
-            &[
-                "containerPort",
-                "hostIP",
-                "hostPort",
-                "name",
-                "protocol",
-            ],
+            ContainerPort::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ContainerPort> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ContainerPort")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_container_port = false;
+                let mut has_host_ip = false;
+                let mut has_host_port = false;
+                let mut has_name = false;
+                let mut has_protocol = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ContainerPort", ContainerPort::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.container_port))?; has_container_port = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.host_ip))?; has_host_ip = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.host_port))?; has_host_port = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.name))?; has_name = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.protocol))?; has_protocol = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_container_port {
+                    return Err(crate::serde2::missing_field("ContainerPort", "containerPort", &["containerPort"]));
+                }
+                if !has_host_ip {
+                    self.0.host_ip = None;
+                }
+                if !has_host_port {
+                    self.0.host_port = None;
+                }
+                if !has_name {
+                    self.0.name = None;
+                }
+                if !has_protocol {
+                    self.0.protocol = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.container_port))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.host_ip))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.host_port))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.protocol))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ContainerPort",
+            ContainerPort::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ContainerPort {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ContainerPort", 5)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "containerPort", &self.container_port)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostIP", &self.host_ip)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostPort", &self.host_port)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "protocol", &self.protocol)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ContainerPort",
             1 +
@@ -136,10 +239,129 @@ impl crate::serde::Serialize for ContainerPort {
     }
 }
 
+/// A builder of [`ContainerPort`]. Created with [`ContainerPort::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ContainerPortBuilder {
+    container_port: Option<i32>,
+    host_ip: Option<String>,
+    host_port: Option<i32>,
+    name: Option<String>,
+    protocol: Option<crate::api::core::v1::Protocol>,
+}
+
+#[cfg(feature = "builder")]
+impl ContainerPort {
+    /// Starts building a `ContainerPort`. Its required fields must be set before [`ContainerPortBuilder::build`] is called.
+    pub fn builder() -> ContainerPortBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ContainerPortBuilder {
+    /// Sets [`ContainerPort::container_port`].
+    pub fn container_port(mut self, value: impl Into<i32>) -> Self {
+        self.container_port = Some(value.into());
+        self
+    }
+
+    /// Sets [`ContainerPort::host_ip`].
+    pub fn host_ip(mut self, value: impl Into<String>) -> Self {
+        self.host_ip = Some(value.into());
+        self
+    }
+
+    /// Sets [`ContainerPort::host_port`].
+    pub fn host_port(mut self, value: impl Into<i32>) -> Self {
+        self.host_port = Some(value.into());
+        self
+    }
+
+    /// Sets [`ContainerPort::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`ContainerPort::protocol`].
+    pub fn protocol(mut self, value: impl Into<crate::api::core::v1::Protocol>) -> Self {
+        self.protocol = Some(value.into());
+        self
+    }
+
+    /// Builds the `ContainerPort`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ContainerPort, crate::MissingFieldError> {
+        Ok(ContainerPort {
+            container_port: self.container_port.ok_or_else(|| crate::MissingFieldError::new("ContainerPort", "containerPort"))?,
+            host_ip: self.host_ip,
+            host_port: self.host_port,
+            name: self.name,
+            protocol: self.protocol,
+        })
+    }
+}
+
+/// The apply configuration of [`ContainerPort`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ContainerPortApplyConfiguration {
+    /// See [`ContainerPort::container_port`].
+    pub container_port: Option<i32>,
+
+    /// See [`ContainerPort::host_ip`].
+    pub host_ip: Option<String>,
+
+    /// See [`ContainerPort::host_port`].
+    pub host_port: Option<i32>,
+
+    /// See [`ContainerPort::name`].
+    pub name: Option<String>,
+
+    /// See [`ContainerPort::protocol`].
+    pub protocol: Option<crate::api::core::v1::Protocol>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ContainerPortApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ContainerPortApplyConfiguration",
+            self.container_port.as_ref().map_or(0, |_| 1) +
+            self.host_ip.as_ref().map_or(0, |_| 1) +
+            self.host_port.as_ref().map_or(0, |_| 1) +
+            self.name.as_ref().map_or(0, |_| 1) +
+            self.protocol.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.container_port {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "containerPort", value)?;
+        }
+        if let Some(value) = &self.host_ip {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostIP", value)?;
+        }
+        if let Some(value) = &self.host_port {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostPort", value)?;
+        }
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        if let Some(value) = &self.protocol {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "protocol", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ContainerPort {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.ContainerPort".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::ContainerPort".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.ContainerPort".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -150,8 +372,9 @@ impl crate::schemars::JsonSchema for ContainerPort {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "containerPort".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -162,8 +385,8 @@ impl crate::schemars::JsonSchema for ContainerPort {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "hostIP".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -173,8 +396,8 @@ impl crate::schemars::JsonSchema for ContainerPort {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "hostPort".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -185,8 +408,8 @@ impl crate::schemars::JsonSchema for ContainerPort {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -196,19 +419,20 @@ impl crate::schemars::JsonSchema for ContainerPort {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "protocol".to_owned(),
-                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
-                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::Protocol>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
                                 description: Some("Protocol for port. Must be UDP or TCP. Defaults to \"TCP\".".to_owned()),
                                 ..Default::default()
-                            })),
-                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
-                            ..Default::default()
-                        }),
-                    ),
-                ]).collect(),
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "containerPort",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -218,3 +442,16 @@ impl crate::schemars::JsonSchema for ContainerPort {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ContainerPort {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ContainerPort {
+            container_port: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            host_ip: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            host_port: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            protocol: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.ContainerStateTerminated
 
 /// ContainerStateTerminated is a terminated state of a container.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ContainerStateTerminated {
     /// Container's ID in the format 'docker://\<container_id\>'
     pub container_id: Option<String>,
@@ -25,49 +25,88 @@ pub struct ContainerStateTerminated {
     pub started_at: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ContainerStateTerminated {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_container_id,
-            Key_exit_code,
-            Key_finished_at,
-            Key_message,
-            Key_reason,
-            Key_signal,
-            Key_started_at,
-            Other,
+impl ContainerStateTerminated {
+    /// Constructs a `ContainerStateTerminated` from its required fields. Its optional fields are unset.
+    pub fn new(exit_code: impl Into<i32>) -> Self {
+        ContainerStateTerminated {
+            container_id: None,
+            exit_code: exit_code.into(),
+            finished_at: None,
+            message: None,
+            reason: None,
+            signal: None,
+            started_at: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl ContainerStateTerminated {
+    /// Returns this value with [`ContainerStateTerminated::container_id`] set to the given value.
+    #[must_use]
+    pub fn with_container_id(mut self, value: impl Into<String>) -> Self {
+        self.container_id = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ContainerStateTerminated::exit_code`] set to the given value.
+    #[must_use]
+    pub fn with_exit_code(mut self, value: impl Into<i32>) -> Self {
+        self.exit_code = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ContainerStateTerminated::finished_at`] set to the given value.
+    #[must_use]
+    pub fn with_finished_at(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.finished_at = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "containerID" => Field::Key_container_id,
-                            "exitCode" => Field::Key_exit_code,
-                            "finishedAt" => Field::Key_finished_at,
-                            "message" => Field::Key_message,
-                            "reason" => Field::Key_reason,
-                            "signal" => Field::Key_signal,
-                            "startedAt" => Field::Key_started_at,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`ContainerStateTerminated::message`] set to the given value.
+    #[must_use]
+    pub fn with_message(mut self, value: impl Into<String>) -> Self {
+        self.message = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`ContainerStateTerminated::reason`] set to the given value.
+    #[must_use]
+    pub fn with_reason(mut self, value: impl Into<String>) -> Self {
+        self.reason = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ContainerStateTerminated::signal`] set to the given value.
+    #[must_use]
+    pub fn with_signal(mut self, value: impl Into<i32>) -> Self {
+        self.signal = Some(value.into());
+        self
+    }
 
+    /// Returns this value with [`ContainerStateTerminated::started_at`] set to the given value.
+    #[must_use]
+    pub fn with_started_at(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.started_at = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for ContainerStateTerminated {}
+
+impl ContainerStateTerminated {
+    const FIELDS: &'static [&'static str] = &[
+        "containerID",
+        "exitCode",
+        "finishedAt",
+        "message",
+        "reason",
+        "signal",
+        "startedAt",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for ContainerStateTerminated {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -86,22 +125,22 @@ impl<'de> crate::serde::Deserialize<'de> for ContainerStateTerminated {
                 let mut value_signal: Option<i32> = None;
                 let mut value_started_at: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ContainerStateTerminated", ContainerStateTerminated::FIELDS))? {
                     match key {
-                        Field::Key_container_id => value_container_id = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_exit_code => value_exit_code = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_finished_at => value_finished_at = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_message => value_message = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_reason => value_reason = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_signal => value_signal = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_started_at => value_started_at = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_container_id = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_exit_code = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_finished_at = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_message = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_reason = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_signal = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_started_at = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ContainerStateTerminated {
                     container_id: value_container_id,
-                    exit_code: value_exit_code.ok_or_else(|| crate::serde::de::Error::missing_field("exitCode"))?,
+                    exit_code: value_exit_code.ok_or_else(|| crate::serde2::missing_field("ContainerStateTerminated", "exitCode", &["exitCode"]))?,
                     finished_at: value_finished_at,
                     message: value_message,
                     reason: value_reason,
@@ -109,26 +148,118 @@ impl<'de> crate::serde::Deserialize<'de> for ContainerStateTerminated {
                     started_at: value_started_at,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ContainerStateTerminated {
+                    container_id: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    exit_code: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    finished_at: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    message: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    reason: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    signal: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    started_at: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "ContainerStateTerminated",
-            &[
-                "containerID",
-                "exitCode",
-                "finishedAt",
-                "message",
-                "reason",
-                "signal",
-                "startedAt",
-            ],
+            ContainerStateTerminated::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ContainerStateTerminated> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ContainerStateTerminated")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_container_id = false;
+                let mut has_exit_code = false;
+                let mut has_finished_at = false;
+                let mut has_message = false;
+                let mut has_reason = false;
+                let mut has_signal = false;
+                let mut has_started_at = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ContainerStateTerminated", ContainerStateTerminated::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.container_id))?; has_container_id = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.exit_code))?; has_exit_code = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.finished_at))?; has_finished_at = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.message))?; has_message = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.reason))?; has_reason = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.signal))?; has_signal = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.started_at))?; has_started_at = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_container_id {
+                    self.0.container_id = None;
+                }
+                if !has_exit_code {
+                    return Err(crate::serde2::missing_field("ContainerStateTerminated", "exitCode", &["exitCode"]));
+                }
+                if !has_finished_at {
+                    self.0.finished_at = None;
+                }
+                if !has_message {
+                    self.0.message = None;
+                }
+                if !has_reason {
+                    self.0.reason = None;
+                }
+                if !has_signal {
+                    self.0.signal = None;
+                }
+                if !has_started_at {
+                    self.0.started_at = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.container_id))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.exit_code))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.finished_at))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.message))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.reason))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.signal))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.started_at))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ContainerStateTerminated",
+            ContainerStateTerminated::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ContainerStateTerminated {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ContainerStateTerminated", 7)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "containerID", &self.container_id)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "exitCode", &self.exit_code)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "finishedAt", &self.finished_at)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "message", &self.message)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reason", &self.reason)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "signal", &self.signal)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "startedAt", &self.started_at)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ContainerStateTerminated",
             1 +
@@ -162,10 +293,159 @@ impl crate::serde::Serialize for ContainerStateTerminated {
     }
 }
 
+/// A builder of [`ContainerStateTerminated`]. Created with [`ContainerStateTerminated::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ContainerStateTerminatedBuilder {
+    container_id: Option<String>,
+    exit_code: Option<i32>,
+    finished_at: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+    message: Option<String>,
+    reason: Option<String>,
+    signal: Option<i32>,
+    started_at: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+}
+
+#[cfg(feature = "builder")]
+impl ContainerStateTerminated {
+    /// Starts building a `ContainerStateTerminated`. Its required fields must be set before [`ContainerStateTerminatedBuilder::build`] is called.
+    pub fn builder() -> ContainerStateTerminatedBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ContainerStateTerminatedBuilder {
+    /// Sets [`ContainerStateTerminated::container_id`].
+    pub fn container_id(mut self, value: impl Into<String>) -> Self {
+        self.container_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`ContainerStateTerminated::exit_code`].
+    pub fn exit_code(mut self, value: impl Into<i32>) -> Self {
+        self.exit_code = Some(value.into());
+        self
+    }
+
+    /// Sets [`ContainerStateTerminated::finished_at`].
+    pub fn finished_at(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.finished_at = Some(value.into());
+        self
+    }
+
+    /// Sets [`ContainerStateTerminated::message`].
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.message = Some(value.into());
+        self
+    }
+
+    /// Sets [`ContainerStateTerminated::reason`].
+    pub fn reason(mut self, value: impl Into<String>) -> Self {
+        self.reason = Some(value.into());
+        self
+    }
+
+    /// Sets [`ContainerStateTerminated::signal`].
+    pub fn signal(mut self, value: impl Into<i32>) -> Self {
+        self.signal = Some(value.into());
+        self
+    }
+
+    /// Sets [`ContainerStateTerminated::started_at`].
+    pub fn started_at(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.started_at = Some(value.into());
+        self
+    }
+
+    /// Builds the `ContainerStateTerminated`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ContainerStateTerminated, crate::MissingFieldError> {
+        Ok(ContainerStateTerminated {
+            container_id: self.container_id,
+            exit_code: self.exit_code.ok_or_else(|| crate::MissingFieldError::new("ContainerStateTerminated", "exitCode"))?,
+            finished_at: self.finished_at,
+            message: self.message,
+            reason: self.reason,
+            signal: self.signal,
+            started_at: self.started_at,
+        })
+    }
+}
+
+/// The apply configuration of [`ContainerStateTerminated`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ContainerStateTerminatedApplyConfiguration {
+    /// See [`ContainerStateTerminated::container_id`].
+    pub container_id: Option<String>,
+
+    /// See [`ContainerStateTerminated::exit_code`].
+    pub exit_code: Option<i32>,
+
+    /// See [`ContainerStateTerminated::finished_at`].
+    pub finished_at: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+
+    /// See [`ContainerStateTerminated::message`].
+    pub message: Option<String>,
+
+    /// See [`ContainerStateTerminated::reason`].
+    pub reason: Option<String>,
+
+    /// See [`ContainerStateTerminated::signal`].
+    pub signal: Option<i32>,
+
+    /// See [`ContainerStateTerminated::started_at`].
+    pub started_at: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ContainerStateTerminatedApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ContainerStateTerminatedApplyConfiguration",
+            self.container_id.as_ref().map_or(0, |_| 1) +
+            self.exit_code.as_ref().map_or(0, |_| 1) +
+            self.finished_at.as_ref().map_or(0, |_| 1) +
+            self.message.as_ref().map_or(0, |_| 1) +
+            self.reason.as_ref().map_or(0, |_| 1) +
+            self.signal.as_ref().map_or(0, |_| 1) +
+            self.started_at.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.container_id {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "containerID", value)?;
+        }
+        if let Some(value) = &self.exit_code {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "exitCode", value)?;
+        }
+        if let Some(value) = &self.finished_at {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "finishedAt", value)?;
+        }
+        if let Some(value) = &self.message {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "message", value)?;
+        }
+        if let Some(value) = &self.reason {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reason", value)?;
+        }
+        if let Some(value) = &self.signal {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "signal", value)?;
+        }
+        if let Some(value) = &self.started_at {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "startedAt", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ContainerStateTerminated {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.ContainerStateTerminated".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::ContainerStateTerminated".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.ContainerStateTerminated".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -176,8 +456,9 @@ impl crate::schemars::JsonSchema for ContainerStateTerminated {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "containerID".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -187,8 +468,8 @@ impl crate::schemars::JsonSchema for ContainerStateTerminated {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "exitCode".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -199,8 +480,8 @@ impl crate::schemars::JsonSchema for ContainerStateTerminated {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "finishedAt".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::Time>().into_object();
@@ -210,8 +491,8 @@ impl crate::schemars::JsonSchema for ContainerStateTerminated {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "message".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -221,8 +502,8 @@ impl crate::schemars::JsonSchema for ContainerStateTerminated {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "reason".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -232,8 +513,8 @@ impl crate::schemars::JsonSchema for ContainerStateTerminated {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "signal".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -244,8 +525,8 @@ impl crate::schemars::JsonSchema for ContainerStateTerminated {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "startedAt".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::Time>().into_object();
@@ -255,8 +536,9 @@ impl crate::schemars::JsonSchema for ContainerStateTerminated {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "exitCode",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -266,3 +548,18 @@ impl crate::schemars::JsonSchema for ContainerStateTerminated {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ContainerStateTerminated {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ContainerStateTerminated {
+            container_id: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            exit_code: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            finished_at: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            message: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            reason: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            signal: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            started_at: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,43 +1,40 @@
 // Generated from definition io.k8s.api.core.v1.DaemonEndpoint
 
 /// DaemonEndpoint contains information about a single Daemon endpoint.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 pub struct DaemonEndpoint {
     /// Port number of the given endpoint.
     pub port: i32,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for DaemonEndpoint {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_port,
-            Other,
+impl DaemonEndpoint {
+    /// Constructs a `DaemonEndpoint` from its required fields. Its optional fields are unset.
+    pub fn new(port: impl Into<i32>) -> Self {
+        DaemonEndpoint {
+            port: port.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl DaemonEndpoint {
+    /// Returns this value with [`DaemonEndpoint::port`] set to the given value.
+    #[must_use]
+    pub fn with_port(mut self, value: impl Into<i32>) -> Self {
+        self.port = value.into();
+        self
+    }
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for DaemonEndpoint {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "Port" => Field::Key_port,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl DaemonEndpoint {
+    const FIELDS: &'static [&'static str] = &[
+        "Port",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for DaemonEndpoint {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -50,31 +47,81 @@ impl<'de> crate::serde::Deserialize<'de> for DaemonEndpoint {
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
                 let mut value_port: Option<i32> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("DaemonEndpoint", DaemonEndpoint::FIELDS))? {
                     match key {
-                        Field::Key_port => value_port = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_port = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(DaemonEndpoint {
-                    port: value_port.ok_or_else(|| crate::serde::de::Error::missing_field("Port"))?,
+                    port: value_port.ok_or_else(|| crate::serde2::missing_field("DaemonEndpoint", "Port", &["Port"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(DaemonEndpoint {
+                    port: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "DaemonEndpoint",
-            &[
-                "Port",
-            ],
+            DaemonEndpoint::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, DaemonEndpoint> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("DaemonEndpoint")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_port = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("DaemonEndpoint", DaemonEndpoint::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.port))?; has_port = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_port {
+                    return Err(crate::serde2::missing_field("DaemonEndpoint", "Port", &["Port"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.port))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "DaemonEndpoint",
+            DaemonEndpoint::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for DaemonEndpoint {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("DaemonEndpoint", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "Port", &self.port)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "DaemonEndpoint",
             1,
@@ -84,10 +131,69 @@ impl crate::serde::Serialize for DaemonEndpoint {
     }
 }
 
+/// A builder of [`DaemonEndpoint`]. Created with [`DaemonEndpoint::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct DaemonEndpointBuilder {
+    port: Option<i32>,
+}
+
+#[cfg(feature = "builder")]
+impl DaemonEndpoint {
+    /// Starts building a `DaemonEndpoint`. Its required fields must be set before [`DaemonEndpointBuilder::build`] is called.
+    pub fn builder() -> DaemonEndpointBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl DaemonEndpointBuilder {
+    /// Sets [`DaemonEndpoint::port`].
+    pub fn port(mut self, value: impl Into<i32>) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Builds the `DaemonEndpoint`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<DaemonEndpoint, crate::MissingFieldError> {
+        Ok(DaemonEndpoint {
+            port: self.port.ok_or_else(|| crate::MissingFieldError::new("DaemonEndpoint", "Port"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`DaemonEndpoint`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DaemonEndpointApplyConfiguration {
+    /// See [`DaemonEndpoint::port`].
+    pub port: Option<i32>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for DaemonEndpointApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "DaemonEndpointApplyConfiguration",
+            self.port.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.port {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "Port", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DaemonEndpoint {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.DaemonEndpoint".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::DaemonEndpoint".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.DaemonEndpoint".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -98,8 +204,9 @@ impl crate::schemars::JsonSchema for DaemonEndpoint {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "Port".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -110,8 +217,9 @@ impl crate::schemars::JsonSchema for DaemonEndpoint {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "Port",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -121,3 +229,12 @@ impl crate::schemars::JsonSchema for DaemonEndpoint {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for DaemonEndpoint {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(DaemonEndpoint {
+            port: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.EndpointAddress
 
 /// EndpointAddress is a tuple that describes single IP address.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct EndpointAddress {
     /// The Hostname of this endpoint
     pub hostname: Option<String>,
@@ -16,43 +16,61 @@ pub struct EndpointAddress {
     pub target_ref: Option<crate::api::core::v1::ObjectReference>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for EndpointAddress {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_hostname,
-            Key_ip,
-            Key_node_name,
-            Key_target_ref,
-            Other,
+impl EndpointAddress {
+    /// Constructs a `EndpointAddress` from its required fields. Its optional fields are unset.
+    pub fn new(ip: impl Into<String>) -> Self {
+        EndpointAddress {
+            hostname: None,
+            ip: ip.into(),
+            node_name: None,
+            target_ref: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl EndpointAddress {
+    /// Returns this value with [`EndpointAddress::hostname`] set to the given value.
+    #[must_use]
+    pub fn with_hostname(mut self, value: impl Into<String>) -> Self {
+        self.hostname = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`EndpointAddress::ip`] set to the given value.
+    #[must_use]
+    pub fn with_ip(mut self, value: impl Into<String>) -> Self {
+        self.ip = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`EndpointAddress::node_name`] set to the given value.
+    #[must_use]
+    pub fn with_node_name(mut self, value: impl Into<String>) -> Self {
+        self.node_name = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "hostname" => Field::Key_hostname,
-                            "ip" => Field::Key_ip,
-                            "nodeName" => Field::Key_node_name,
-                            "targetRef" => Field::Key_target_ref,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`EndpointAddress::target_ref`] set to the given value.
+    #[must_use]
+    pub fn with_target_ref(mut self, value: impl Into<crate::api::core::v1::ObjectReference>) -> Self {
+        self.target_ref = Some(value.into());
+        self
+    }
+}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl crate::MergeStrategyInfo for EndpointAddress {}
+
+impl EndpointAddress {
+    const FIELDS: &'static [&'static str] = &[
+        "hostname",
+        "ip",
+        "nodeName",
+        "targetRef",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for EndpointAddress {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -68,40 +86,111 @@ impl<'de> crate::serde::Deserialize<'de> for EndpointAddress {
                 let mut value_node_name: Option<String> = None;
                 let mut value_target_ref: Option<crate::api::core::v1::ObjectReference> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("EndpointAddress", EndpointAddress::FIELDS))? {
                     match key {
-                        Field::Key_hostname => value_hostname = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_ip => value_ip = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_node_name => value_node_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_target_ref => value_target_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_hostname = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_ip = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_node_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_target_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(EndpointAddress {
                     hostname: value_hostname,
-                    ip: value_ip.ok_or_else(|| crate::serde::de::Error::missing_field("ip"))?,
+                    ip: value_ip.ok_or_else(|| crate::serde2::missing_field("EndpointAddress", "ip", &["ip"]))?,
                     node_name: value_node_name,
                     target_ref: value_target_ref,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(EndpointAddress {
+                    hostname: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    ip: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    node_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    target_ref: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "EndpointAddress",
-            &[
-                "hostname",
-                "ip",
-                "nodeName",
-                "targetRef",
-            ],
+            EndpointAddress::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, EndpointAddress> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("EndpointAddress")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_hostname = false;
+                let mut has_ip = false;
+                let mut has_node_name = false;
+                let mut has_target_ref = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("EndpointAddress", EndpointAddress::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.hostname))?; has_hostname = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.ip))?; has_ip = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.node_name))?; has_node_name = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.target_ref))?; has_target_ref = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_hostname {
+                    self.0.hostname = None;
+                }
+                if !has_ip {
+                    return Err(crate::serde2::missing_field("EndpointAddress", "ip", &["ip"]));
+                }
+                if !has_node_name {
+                    self.0.node_name = None;
+                }
+                if !has_target_ref {
+                    self.0.target_ref = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.hostname))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.ip))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.node_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.target_ref))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "EndpointAddress",
+            EndpointAddress::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for EndpointAddress {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("EndpointAddress", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostname", &self.hostname)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ip", &self.ip)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeName", &self.node_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "targetRef", &self.target_ref)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "EndpointAddress",
             1 +
@@ -123,10 +212,114 @@ impl crate::serde::Serialize for EndpointAddress {
     }
 }
 
+/// A builder of [`EndpointAddress`]. Created with [`EndpointAddress::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct EndpointAddressBuilder {
+    hostname: Option<String>,
+    ip: Option<String>,
+    node_name: Option<String>,
+    target_ref: Option<crate::api::core::v1::ObjectReference>,
+}
+
+#[cfg(feature = "builder")]
+impl EndpointAddress {
+    /// Starts building a `EndpointAddress`. Its required fields must be set before [`EndpointAddressBuilder::build`] is called.
+    pub fn builder() -> EndpointAddressBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl EndpointAddressBuilder {
+    /// Sets [`EndpointAddress::hostname`].
+    pub fn hostname(mut self, value: impl Into<String>) -> Self {
+        self.hostname = Some(value.into());
+        self
+    }
+
+    /// Sets [`EndpointAddress::ip`].
+    pub fn ip(mut self, value: impl Into<String>) -> Self {
+        self.ip = Some(value.into());
+        self
+    }
+
+    /// Sets [`EndpointAddress::node_name`].
+    pub fn node_name(mut self, value: impl Into<String>) -> Self {
+        self.node_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`EndpointAddress::target_ref`].
+    pub fn target_ref(mut self, value: impl Into<crate::api::core::v1::ObjectReference>) -> Self {
+        self.target_ref = Some(value.into());
+        self
+    }
+
+    /// Builds the `EndpointAddress`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<EndpointAddress, crate::MissingFieldError> {
+        Ok(EndpointAddress {
+            hostname: self.hostname,
+            ip: self.ip.ok_or_else(|| crate::MissingFieldError::new("EndpointAddress", "ip"))?,
+            node_name: self.node_name,
+            target_ref: self.target_ref,
+        })
+    }
+}
+
+/// The apply configuration of [`EndpointAddress`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EndpointAddressApplyConfiguration {
+    /// See [`EndpointAddress::hostname`].
+    pub hostname: Option<String>,
+
+    /// See [`EndpointAddress::ip`].
+    pub ip: Option<String>,
+
+    /// See [`EndpointAddress::node_name`].
+    pub node_name: Option<String>,
+
+    /// See [`EndpointAddress::target_ref`].
+    pub target_ref: Option<crate::api::core::v1::ObjectReferenceApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for EndpointAddressApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "EndpointAddressApplyConfiguration",
+            self.hostname.as_ref().map_or(0, |_| 1) +
+            self.ip.as_ref().map_or(0, |_| 1) +
+            self.node_name.as_ref().map_or(0, |_| 1) +
+            self.target_ref.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.hostname {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostname", value)?;
+        }
+        if let Some(value) = &self.ip {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ip", value)?;
+        }
+        if let Some(value) = &self.node_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeName", value)?;
+        }
+        if let Some(value) = &self.target_ref {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "targetRef", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for EndpointAddress {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.EndpointAddress".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::EndpointAddress".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.EndpointAddress".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -137,8 +330,9 @@ impl crate::schemars::JsonSchema for EndpointAddress {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "hostname".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -148,8 +342,8 @@ impl crate::schemars::JsonSchema for EndpointAddress {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "ip".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -159,8 +353,8 @@ impl crate::schemars::JsonSchema for EndpointAddress {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "nodeName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -170,8 +364,8 @@ impl crate::schemars::JsonSchema for EndpointAddress {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "targetRef".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ObjectReference>().into_object();
@@ -181,8 +375,9 @@ impl crate::schemars::JsonSchema for EndpointAddress {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "ip",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -192,3 +387,15 @@ impl crate::schemars::JsonSchema for EndpointAddress {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for EndpointAddress {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(EndpointAddress {
+            hostname: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            ip: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            node_name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            target_ref: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.EnvFromSource
 
 /// EnvFromSource represents the source of a set of ConfigMaps
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct EnvFromSource {
     /// The ConfigMap to select from
     pub config_map_ref: Option<crate::api::core::v1::ConfigMapEnvSource>,
@@ -13,41 +13,41 @@ pub struct EnvFromSource {
     pub secret_ref: Option<crate::api::core::v1::SecretEnvSource>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for EnvFromSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_config_map_ref,
-            Key_prefix,
-            Key_secret_ref,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl EnvFromSource {
+    /// Returns this value with [`EnvFromSource::config_map_ref`] set to the given value.
+    #[must_use]
+    pub fn with_config_map_ref(mut self, value: impl Into<crate::api::core::v1::ConfigMapEnvSource>) -> Self {
+        self.config_map_ref = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`EnvFromSource::prefix`] set to the given value.
+    #[must_use]
+    pub fn with_prefix(mut self, value: impl Into<String>) -> Self {
+        self.prefix = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`EnvFromSource::secret_ref`] set to the given value.
+    #[must_use]
+    pub fn with_secret_ref(mut self, value: impl Into<crate::api::core::v1::SecretEnvSource>) -> Self {
+        self.secret_ref = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "configMapRef" => Field::Key_config_map_ref,
-                            "prefix" => Field::Key_prefix,
-                            "secretRef" => Field::Key_secret_ref,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for EnvFromSource {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl EnvFromSource {
+    const FIELDS: &'static [&'static str] = &[
+        "configMapRef",
+        "prefix",
+        "secretRef",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for EnvFromSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,12 +62,12 @@ impl<'de> crate::serde::Deserialize<'de> for EnvFromSource {
                 let mut value_prefix: Option<String> = None;
                 let mut value_secret_ref: Option<crate::api::core::v1::SecretEnvSource> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("EnvFromSource", EnvFromSource::FIELDS))? {
                     match key {
-                        Field::Key_config_map_ref => value_config_map_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_prefix => value_prefix = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_secret_ref => value_secret_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_config_map_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_prefix = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_secret_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -77,22 +77,86 @@ impl<'de> crate::serde::Deserialize<'de> for EnvFromSource {
                     secret_ref: value_secret_ref,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(EnvFromSource {
+                    config_map_ref: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    prefix: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    secret_ref: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "EnvFromSource",
-            &[
-                "configMapRef",
-                "prefix",
-                "secretRef",
-            ],
+            EnvFromSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, EnvFromSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("EnvFromSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_config_map_ref = false;
+                let mut has_prefix = false;
+                let mut has_secret_ref = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("EnvFromSource", EnvFromSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.config_map_ref))?; has_config_map_ref = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.prefix))?; has_prefix = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.secret_ref))?; has_secret_ref = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_config_map_ref {
+                    self.0.config_map_ref = None;
+                }
+                if !has_prefix {
+                    self.0.prefix = None;
+                }
+                if !has_secret_ref {
+                    self.0.secret_ref = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.config_map_ref))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.prefix))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.secret_ref))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "EnvFromSource",
+            EnvFromSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for EnvFromSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("EnvFromSource", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "configMapRef", &self.config_map_ref)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "prefix", &self.prefix)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretRef", &self.secret_ref)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "EnvFromSource",
             self.config_map_ref.as_ref().map_or(0, |_| 1) +
@@ -112,10 +176,99 @@ impl crate::serde::Serialize for EnvFromSource {
     }
 }
 
+/// A builder of [`EnvFromSource`]. Created with [`EnvFromSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct EnvFromSourceBuilder {
+    config_map_ref: Option<crate::api::core::v1::ConfigMapEnvSource>,
+    prefix: Option<String>,
+    secret_ref: Option<crate::api::core::v1::SecretEnvSource>,
+}
+
+#[cfg(feature = "builder")]
+impl EnvFromSource {
+    /// Starts building a `EnvFromSource`. Its required fields must be set before [`EnvFromSourceBuilder::build`] is called.
+    pub fn builder() -> EnvFromSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl EnvFromSourceBuilder {
+    /// Sets [`EnvFromSource::config_map_ref`].
+    pub fn config_map_ref(mut self, value: impl Into<crate::api::core::v1::ConfigMapEnvSource>) -> Self {
+        self.config_map_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`EnvFromSource::prefix`].
+    pub fn prefix(mut self, value: impl Into<String>) -> Self {
+        self.prefix = Some(value.into());
+        self
+    }
+
+    /// Sets [`EnvFromSource::secret_ref`].
+    pub fn secret_ref(mut self, value: impl Into<crate::api::core::v1::SecretEnvSource>) -> Self {
+        self.secret_ref = Some(value.into());
+        self
+    }
+
+    /// Builds the `EnvFromSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<EnvFromSource, crate::MissingFieldError> {
+        Ok(EnvFromSource {
+            config_map_ref: self.config_map_ref,
+            prefix: self.prefix,
+            secret_ref: self.secret_ref,
+        })
+    }
+}
+
+/// The apply configuration of [`EnvFromSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EnvFromSourceApplyConfiguration {
+    /// See [`EnvFromSource::config_map_ref`].
+    pub config_map_ref: Option<crate::api::core::v1::ConfigMapEnvSourceApplyConfiguration>,
+
+    /// See [`EnvFromSource::prefix`].
+    pub prefix: Option<String>,
+
+    /// See [`EnvFromSource::secret_ref`].
+    pub secret_ref: Option<crate::api::core::v1::SecretEnvSourceApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for EnvFromSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "EnvFromSourceApplyConfiguration",
+            self.config_map_ref.as_ref().map_or(0, |_| 1) +
+            self.prefix.as_ref().map_or(0, |_| 1) +
+            self.secret_ref.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.config_map_ref {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "configMapRef", value)?;
+        }
+        if let Some(value) = &self.prefix {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "prefix", value)?;
+        }
+        if let Some(value) = &self.secret_ref {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretRef", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for EnvFromSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.EnvFromSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::EnvFromSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.EnvFromSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -126,8 +279,9 @@ impl crate::schemars::JsonSchema for EnvFromSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "configMapRef".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ConfigMapEnvSource>().into_object();
@@ -137,8 +291,8 @@ impl crate::schemars::JsonSchema for EnvFromSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "prefix".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -148,8 +302,8 @@ impl crate::schemars::JsonSchema for EnvFromSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "secretRef".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::SecretEnvSource>().into_object();
@@ -159,11 +313,23 @@ impl crate::schemars::JsonSchema for EnvFromSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for EnvFromSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(EnvFromSource {
+            config_map_ref: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            prefix: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            secret_ref: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.EventSource
 
 /// EventSource contains information for an event.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct EventSource {
     /// Component from which the event is generated.
     pub component: Option<String>,
@@ -10,39 +10,33 @@ pub struct EventSource {
     pub host: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for EventSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_component,
-            Key_host,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl EventSource {
+    /// Returns this value with [`EventSource::component`] set to the given value.
+    #[must_use]
+    pub fn with_component(mut self, value: impl Into<String>) -> Self {
+        self.component = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`EventSource::host`] set to the given value.
+    #[must_use]
+    pub fn with_host(mut self, value: impl Into<String>) -> Self {
+        self.host = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "component" => Field::Key_component,
-                            "host" => Field::Key_host,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for EventSource {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl EventSource {
+    const FIELDS: &'static [&'static str] = &[
+        "component",
+        "host",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for EventSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,11 +50,11 @@ impl<'de> crate::serde::Deserialize<'de> for EventSource {
                 let mut value_component: Option<String> = None;
                 let mut value_host: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("EventSource", EventSource::FIELDS))? {
                     match key {
-                        Field::Key_component => value_component = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_host => value_host = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_component = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_host = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -69,21 +63,78 @@ impl<'de> crate::serde::Deserialize<'de> for EventSource {
                     host: value_host,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(EventSource {
+                    component: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    host: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "EventSource",
-            &[
-                "component",
-                "host",
-            ],
+            EventSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, EventSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("EventSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_component = false;
+                let mut has_host = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("EventSource", EventSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.component))?; has_component = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.host))?; has_host = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_component {
+                    self.0.component = None;
+                }
+                if !has_host {
+                    self.0.host = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.component))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.host))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "EventSource",
+            EventSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for EventSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("EventSource", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "component", &self.component)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "host", &self.host)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "EventSource",
             self.component.as_ref().map_or(0, |_| 1) +
@@ -99,10 +150,84 @@ impl crate::serde::Serialize for EventSource {
     }
 }
 
+/// A builder of [`EventSource`]. Created with [`EventSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct EventSourceBuilder {
+    component: Option<String>,
+    host: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl EventSource {
+    /// Starts building a `EventSource`. Its required fields must be set before [`EventSourceBuilder::build`] is called.
+    pub fn builder() -> EventSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl EventSourceBuilder {
+    /// Sets [`EventSource::component`].
+    pub fn component(mut self, value: impl Into<String>) -> Self {
+        self.component = Some(value.into());
+        self
+    }
+
+    /// Sets [`EventSource::host`].
+    pub fn host(mut self, value: impl Into<String>) -> Self {
+        self.host = Some(value.into());
+        self
+    }
+
+    /// Builds the `EventSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<EventSource, crate::MissingFieldError> {
+        Ok(EventSource {
+            component: self.component,
+            host: self.host,
+        })
+    }
+}
+
+/// The apply configuration of [`EventSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EventSourceApplyConfiguration {
+    /// See [`EventSource::component`].
+    pub component: Option<String>,
+
+    /// See [`EventSource::host`].
+    pub host: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for EventSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "EventSourceApplyConfiguration",
+            self.component.as_ref().map_or(0, |_| 1) +
+            self.host.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.component {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "component", value)?;
+        }
+        if let Some(value) = &self.host {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "host", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for EventSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.EventSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::EventSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.EventSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -113,8 +238,9 @@ impl crate::schemars::JsonSchema for EventSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "component".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -124,8 +250,8 @@ impl crate::schemars::JsonSchema for EventSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "host".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -135,11 +261,22 @@ impl crate::schemars::JsonSchema for EventSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for EventSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(EventSource {
+            component: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            host: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.HostPathVolumeSource
 
 /// Represents a host path mapped into a pod. Host path volumes do not support ownership management or SELinux relabeling.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct HostPathVolumeSource {
     /// Path of the directory on the host. If the path is a symlink, it will follow the link to the real path. More info: https://kubernetes.io/docs/concepts/storage/volumes#hostpath
     pub path: String,
@@ -10,39 +10,43 @@ pub struct HostPathVolumeSource {
     pub type_: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for HostPathVolumeSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_path,
-            Key_type_,
-            Other,
+impl HostPathVolumeSource {
+    /// Constructs a `HostPathVolumeSource` from its required fields. Its optional fields are unset.
+    pub fn new(path: impl Into<String>) -> Self {
+        HostPathVolumeSource {
+            path: path.into(),
+            type_: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl HostPathVolumeSource {
+    /// Returns this value with [`HostPathVolumeSource::path`] set to the given value.
+    #[must_use]
+    pub fn with_path(mut self, value: impl Into<String>) -> Self {
+        self.path = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`HostPathVolumeSource::type_`] set to the given value.
+    #[must_use]
+    pub fn with_type(mut self, value: impl Into<String>) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for HostPathVolumeSource {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "path" => Field::Key_path,
-                            "type" => Field::Key_type_,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl HostPathVolumeSource {
+    const FIELDS: &'static [&'static str] = &[
+        "path",
+        "type",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for HostPathVolumeSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +60,91 @@ impl<'de> crate::serde::Deserialize<'de> for HostPathVolumeSource {
                 let mut value_path: Option<String> = None;
                 let mut value_type_: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("HostPathVolumeSource", HostPathVolumeSource::FIELDS))? {
                     match key {
-                        Field::Key_path => value_path = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_type_ => value_type_ = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_path = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_type_ = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(HostPathVolumeSource {
-                    path: value_path.ok_or_else(|| crate::serde::de::Error::missing_field("path"))?,
+                    path: value_path.ok_or_else(|| crate::serde2::missing_field("HostPathVolumeSource", "path", &["path"]))?,
                     type_: value_type_,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(HostPathVolumeSource {
+                    path: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    type_: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "HostPathVolumeSource",
-            &[
-                "path",
-                "type",
-            ],
+            HostPathVolumeSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, HostPathVolumeSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("HostPathVolumeSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_path = false;
+                let mut has_type_ = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("HostPathVolumeSource", HostPathVolumeSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.path))?; has_path = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.type_))?; has_type_ = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_path {
+                    return Err(crate::serde2::missing_field("HostPathVolumeSource", "path", &["path"]));
+                }
+                if !has_type_ {
+                    self.0.type_ = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.path))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.type_))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "HostPathVolumeSource",
+            HostPathVolumeSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for HostPathVolumeSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("HostPathVolumeSource", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "path", &self.path)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "type", &self.type_)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "HostPathVolumeSource",
             1 +
@@ -97,10 +158,84 @@ impl crate::serde::Serialize for HostPathVolumeSource {
     }
 }
 
+/// A builder of [`HostPathVolumeSource`]. Created with [`HostPathVolumeSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct HostPathVolumeSourceBuilder {
+    path: Option<String>,
+    type_: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl HostPathVolumeSource {
+    /// Starts building a `HostPathVolumeSource`. Its required fields must be set before [`HostPathVolumeSourceBuilder::build`] is called.
+    pub fn builder() -> HostPathVolumeSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl HostPathVolumeSourceBuilder {
+    /// Sets [`HostPathVolumeSource::path`].
+    pub fn path(mut self, value: impl Into<String>) -> Self {
+        self.path = Some(value.into());
+        self
+    }
+
+    /// Sets [`HostPathVolumeSource::type_`].
+    pub fn type_(mut self, value: impl Into<String>) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `HostPathVolumeSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<HostPathVolumeSource, crate::MissingFieldError> {
+        Ok(HostPathVolumeSource {
+            path: self.path.ok_or_else(|| crate::MissingFieldError::new("HostPathVolumeSource", "path"))?,
+            type_: self.type_,
+        })
+    }
+}
+
+/// The apply configuration of [`HostPathVolumeSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HostPathVolumeSourceApplyConfiguration {
+    /// See [`HostPathVolumeSource::path`].
+    pub path: Option<String>,
+
+    /// See [`HostPathVolumeSource::type_`].
+    pub type_: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for HostPathVolumeSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "HostPathVolumeSourceApplyConfiguration",
+            self.path.as_ref().map_or(0, |_| 1) +
+            self.type_.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.path {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "path", value)?;
+        }
+        if let Some(value) = &self.type_ {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "type", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for HostPathVolumeSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.HostPathVolumeSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::HostPathVolumeSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.HostPathVolumeSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -111,8 +246,9 @@ impl crate::schemars::JsonSchema for HostPathVolumeSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "path".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -122,8 +258,8 @@ impl crate::schemars::JsonSchema for HostPathVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "type".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -133,8 +269,9 @@ impl crate::schemars::JsonSchema for HostPathVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "path",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -144,3 +281,13 @@ impl crate::schemars::JsonSchema for HostPathVolumeSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for HostPathVolumeSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(HostPathVolumeSource {
+            path: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            type_: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
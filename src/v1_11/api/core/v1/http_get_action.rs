@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.HTTPGetAction
 
 /// HTTPGetAction describes an action based on HTTP Get requests.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct HTTPGetAction {
     /// Host name to connect to, defaults to the pod IP. You probably want to set "Host" in httpHeaders instead.
     pub host: Option<String>,
@@ -19,45 +19,70 @@ pub struct HTTPGetAction {
     pub scheme: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for HTTPGetAction {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_host,
-            Key_http_headers,
-            Key_path,
-            Key_port,
-            Key_scheme,
-            Other,
+impl HTTPGetAction {
+    /// Constructs a `HTTPGetAction` from its required fields. Its optional fields are unset.
+    pub fn new(port: impl Into<crate::apimachinery::pkg::util::intstr::IntOrString>) -> Self {
+        HTTPGetAction {
+            host: None,
+            http_headers: None,
+            path: None,
+            port: port.into(),
+            scheme: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl HTTPGetAction {
+    /// Returns this value with [`HTTPGetAction::host`] set to the given value.
+    #[must_use]
+    pub fn with_host(mut self, value: impl Into<String>) -> Self {
+        self.host = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`HTTPGetAction::http_headers`] set to the given value.
+    #[must_use]
+    pub fn with_http_headers(mut self, value: impl Into<Vec<crate::api::core::v1::HTTPHeader>>) -> Self {
+        self.http_headers = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`HTTPGetAction::path`] set to the given value.
+    #[must_use]
+    pub fn with_path(mut self, value: impl Into<String>) -> Self {
+        self.path = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "host" => Field::Key_host,
-                            "httpHeaders" => Field::Key_http_headers,
-                            "path" => Field::Key_path,
-                            "port" => Field::Key_port,
-                            "scheme" => Field::Key_scheme,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`HTTPGetAction::port`] set to the given value.
+    #[must_use]
+    pub fn with_port(mut self, value: impl Into<crate::apimachinery::pkg::util::intstr::IntOrString>) -> Self {
+        self.port = value.into();
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`HTTPGetAction::scheme`] set to the given value.
+    #[must_use]
+    pub fn with_scheme(mut self, value: impl Into<String>) -> Self {
+        self.scheme = Some(value.into());
+        self
+    }
+}
 
+impl crate::MergeStrategyInfo for HTTPGetAction {}
+
+impl HTTPGetAction {
+    const FIELDS: &'static [&'static str] = &[
+        "host",
+        "httpHeaders",
+        "path",
+        "port",
+        "scheme",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for HTTPGetAction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -74,14 +99,14 @@ impl<'de> crate::serde::Deserialize<'de> for HTTPGetAction {
                 let mut value_port: Option<crate::apimachinery::pkg::util::intstr::IntOrString> = None;
                 let mut value_scheme: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("HTTPGetAction", HTTPGetAction::FIELDS))? {
                     match key {
-                        Field::Key_host => value_host = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_http_headers => value_http_headers = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_path => value_path = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_port => value_port = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_scheme => value_scheme = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_host = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_http_headers = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_path = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_port = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(4) => value_scheme = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -89,28 +114,106 @@ impl<'de> crate::serde::Deserialize<'de> for HTTPGetAction {
                     host: value_host,
                     http_headers: value_http_headers,
                     path: value_path,
-                    port: value_port.ok_or_else(|| crate::serde::de::Error::missing_field("port"))?,
+                    port: value_port.ok_or_else(|| crate::serde2::missing_field("HTTPGetAction", "port", &["port"]))?,
                     scheme: value_scheme,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(HTTPGetAction {
+                    host: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    http_headers: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    path: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    port: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    scheme: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "HTTPGetAction",
-            &[
-                "host",
-                "httpHeaders",
-                "path",
-                "port",
-                "scheme",
-            ],
+            HTTPGetAction::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, HTTPGetAction> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("HTTPGetAction")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_host = false;
+                let mut has_http_headers = false;
+                let mut has_path = false;
+                let mut has_port = false;
+                let mut has_scheme = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("HTTPGetAction", HTTPGetAction::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.host))?; has_host = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.http_headers))?; has_http_headers = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.path))?; has_path = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.port))?; has_port = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.scheme))?; has_scheme = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_host {
+                    self.0.host = None;
+                }
+                if !has_http_headers {
+                    self.0.http_headers = None;
+                }
+                if !has_path {
+                    self.0.path = None;
+                }
+                if !has_port {
+                    return Err(crate::serde2::missing_field("HTTPGetAction", "port", &["port"]));
+                }
+                if !has_scheme {
+                    self.0.scheme = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.host))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.http_headers))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.path))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.port))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.scheme))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "HTTPGetAction",
+            HTTPGetAction::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for HTTPGetAction {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("HTTPGetAction", 5)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "host", &self.host)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "httpHeaders", &self.http_headers)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "path", &self.path)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "port", &self.port)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "scheme", &self.scheme)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "HTTPGetAction",
             1 +
@@ -136,10 +239,129 @@ impl crate::serde::Serialize for HTTPGetAction {
     }
 }
 
+/// A builder of [`HTTPGetAction`]. Created with [`HTTPGetAction::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct HTTPGetActionBuilder {
+    host: Option<String>,
+    http_headers: Option<Vec<crate::api::core::v1::HTTPHeader>>,
+    path: Option<String>,
+    port: Option<crate::apimachinery::pkg::util::intstr::IntOrString>,
+    scheme: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl HTTPGetAction {
+    /// Starts building a `HTTPGetAction`. Its required fields must be set before [`HTTPGetActionBuilder::build`] is called.
+    pub fn builder() -> HTTPGetActionBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl HTTPGetActionBuilder {
+    /// Sets [`HTTPGetAction::host`].
+    pub fn host(mut self, value: impl Into<String>) -> Self {
+        self.host = Some(value.into());
+        self
+    }
+
+    /// Sets [`HTTPGetAction::http_headers`].
+    pub fn http_headers(mut self, value: impl Into<Vec<crate::api::core::v1::HTTPHeader>>) -> Self {
+        self.http_headers = Some(value.into());
+        self
+    }
+
+    /// Sets [`HTTPGetAction::path`].
+    pub fn path(mut self, value: impl Into<String>) -> Self {
+        self.path = Some(value.into());
+        self
+    }
+
+    /// Sets [`HTTPGetAction::port`].
+    pub fn port(mut self, value: impl Into<crate::apimachinery::pkg::util::intstr::IntOrString>) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets [`HTTPGetAction::scheme`].
+    pub fn scheme(mut self, value: impl Into<String>) -> Self {
+        self.scheme = Some(value.into());
+        self
+    }
+
+    /// Builds the `HTTPGetAction`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<HTTPGetAction, crate::MissingFieldError> {
+        Ok(HTTPGetAction {
+            host: self.host,
+            http_headers: self.http_headers,
+            path: self.path,
+            port: self.port.ok_or_else(|| crate::MissingFieldError::new("HTTPGetAction", "port"))?,
+            scheme: self.scheme,
+        })
+    }
+}
+
+/// The apply configuration of [`HTTPGetAction`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HTTPGetActionApplyConfiguration {
+    /// See [`HTTPGetAction::host`].
+    pub host: Option<String>,
+
+    /// See [`HTTPGetAction::http_headers`].
+    pub http_headers: Option<Vec<crate::api::core::v1::HTTPHeaderApplyConfiguration>>,
+
+    /// See [`HTTPGetAction::path`].
+    pub path: Option<String>,
+
+    /// See [`HTTPGetAction::port`].
+    pub port: Option<crate::apimachinery::pkg::util::intstr::IntOrString>,
+
+    /// See [`HTTPGetAction::scheme`].
+    pub scheme: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for HTTPGetActionApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "HTTPGetActionApplyConfiguration",
+            self.host.as_ref().map_or(0, |_| 1) +
+            self.http_headers.as_ref().map_or(0, |_| 1) +
+            self.path.as_ref().map_or(0, |_| 1) +
+            self.port.as_ref().map_or(0, |_| 1) +
+            self.scheme.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.host {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "host", value)?;
+        }
+        if let Some(value) = &self.http_headers {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "httpHeaders", value)?;
+        }
+        if let Some(value) = &self.path {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "path", value)?;
+        }
+        if let Some(value) = &self.port {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "port", value)?;
+        }
+        if let Some(value) = &self.scheme {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "scheme", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for HTTPGetAction {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.HTTPGetAction".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::HTTPGetAction".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.HTTPGetAction".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -150,8 +372,9 @@ impl crate::schemars::JsonSchema for HTTPGetAction {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "host".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -161,8 +384,8 @@ impl crate::schemars::JsonSchema for HTTPGetAction {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "httpHeaders".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -176,8 +399,8 @@ impl crate::schemars::JsonSchema for HTTPGetAction {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "path".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -187,8 +410,8 @@ impl crate::schemars::JsonSchema for HTTPGetAction {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "port".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::util::intstr::IntOrString>().into_object();
@@ -198,8 +421,8 @@ impl crate::schemars::JsonSchema for HTTPGetAction {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "scheme".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -209,8 +432,9 @@ impl crate::schemars::JsonSchema for HTTPGetAction {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "port",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -220,3 +444,16 @@ impl crate::schemars::JsonSchema for HTTPGetAction {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for HTTPGetAction {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(HTTPGetAction {
+            host: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            http_headers: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            path: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            port: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            scheme: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
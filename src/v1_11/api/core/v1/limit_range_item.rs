@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.LimitRangeItem
 
 /// LimitRangeItem defines a min/max usage limit for any resource that matches on kind.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct LimitRangeItem {
     /// Default resource requirement limit value by resource name if resource limit is omitted.
     pub default: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>,
@@ -22,47 +22,65 @@ pub struct LimitRangeItem {
     pub type_: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for LimitRangeItem {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_default,
-            Key_default_request,
-            Key_max,
-            Key_max_limit_request_ratio,
-            Key_min,
-            Key_type_,
-            Other,
-        }
+impl LimitRangeItem {
+    /// Returns this value with [`LimitRangeItem::default`] set to the given value.
+    #[must_use]
+    pub fn with_default(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>) -> Self {
+        self.default = Some(value.into());
+        self
+    }
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+    /// Returns this value with [`LimitRangeItem::default_request`] set to the given value.
+    #[must_use]
+    pub fn with_default_request(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>) -> Self {
+        self.default_request = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`LimitRangeItem::max`] set to the given value.
+    #[must_use]
+    pub fn with_max(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>) -> Self {
+        self.max = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`LimitRangeItem::max_limit_request_ratio`] set to the given value.
+    #[must_use]
+    pub fn with_max_limit_request_ratio(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>) -> Self {
+        self.max_limit_request_ratio = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "default" => Field::Key_default,
-                            "defaultRequest" => Field::Key_default_request,
-                            "max" => Field::Key_max,
-                            "maxLimitRequestRatio" => Field::Key_max_limit_request_ratio,
-                            "min" => Field::Key_min,
-                            "type" => Field::Key_type_,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`LimitRangeItem::min`] set to the given value.
+    #[must_use]
+    pub fn with_min(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>) -> Self {
+        self.min = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`LimitRangeItem::type_`] set to the given value.
+    #[must_use]
+    pub fn with_type(mut self, value: impl Into<String>) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+}
 
+impl crate::MergeStrategyInfo for LimitRangeItem {}
+
+impl LimitRangeItem {
+    const FIELDS: &'static [&'static str] = &[
+        "default",
+        "defaultRequest",
+        "max",
+        "maxLimitRequestRatio",
+        "min",
+        "type",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for LimitRangeItem {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -80,15 +98,15 @@ impl<'de> crate::serde::Deserialize<'de> for LimitRangeItem {
                 let mut value_min: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>> = None;
                 let mut value_type_: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("LimitRangeItem", LimitRangeItem::FIELDS))? {
                     match key {
-                        Field::Key_default => value_default = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_default_request => value_default_request = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_max => value_max = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_max_limit_request_ratio => value_max_limit_request_ratio = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_min => value_min = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_type_ => value_type_ = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_default = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_default_request = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_max = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_max_limit_request_ratio = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_min = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_type_ = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -101,25 +119,110 @@ impl<'de> crate::serde::Deserialize<'de> for LimitRangeItem {
                     type_: value_type_,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(LimitRangeItem {
+                    default: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    default_request: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    max: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    max_limit_request_ratio: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    min: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    type_: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "LimitRangeItem",
-            &[
-                "default",
-                "defaultRequest",
-                "max",
-                "maxLimitRequestRatio",
-                "min",
-                "type",
-            ],
+            LimitRangeItem::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, LimitRangeItem> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("LimitRangeItem")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_default = false;
+                let mut has_default_request = false;
+                let mut has_max = false;
+                let mut has_max_limit_request_ratio = false;
+                let mut has_min = false;
+                let mut has_type_ = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("LimitRangeItem", LimitRangeItem::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.default))?; has_default = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.default_request))?; has_default_request = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.max))?; has_max = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.max_limit_request_ratio))?; has_max_limit_request_ratio = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.min))?; has_min = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.type_))?; has_type_ = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_default {
+                    self.0.default = None;
+                }
+                if !has_default_request {
+                    self.0.default_request = None;
+                }
+                if !has_max {
+                    self.0.max = None;
+                }
+                if !has_max_limit_request_ratio {
+                    self.0.max_limit_request_ratio = None;
+                }
+                if !has_min {
+                    self.0.min = None;
+                }
+                if !has_type_ {
+                    self.0.type_ = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.default))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.default_request))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.max))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.max_limit_request_ratio))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.min))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.type_))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "LimitRangeItem",
+            LimitRangeItem::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for LimitRangeItem {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("LimitRangeItem", 6)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "default", &self.default)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "defaultRequest", &self.default_request)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "max", &self.max)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maxLimitRequestRatio", &self.max_limit_request_ratio)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "min", &self.min)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "type", &self.type_)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "LimitRangeItem",
             self.default.as_ref().map_or(0, |_| 1) +
@@ -151,10 +254,144 @@ impl crate::serde::Serialize for LimitRangeItem {
     }
 }
 
+/// A builder of [`LimitRangeItem`]. Created with [`LimitRangeItem::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct LimitRangeItemBuilder {
+    default: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>,
+    default_request: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>,
+    max: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>,
+    max_limit_request_ratio: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>,
+    min: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>,
+    type_: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl LimitRangeItem {
+    /// Starts building a `LimitRangeItem`. Its required fields must be set before [`LimitRangeItemBuilder::build`] is called.
+    pub fn builder() -> LimitRangeItemBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl LimitRangeItemBuilder {
+    /// Sets [`LimitRangeItem::default`].
+    pub fn default(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>) -> Self {
+        self.default = Some(value.into());
+        self
+    }
+
+    /// Sets [`LimitRangeItem::default_request`].
+    pub fn default_request(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>) -> Self {
+        self.default_request = Some(value.into());
+        self
+    }
+
+    /// Sets [`LimitRangeItem::max`].
+    pub fn max(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>) -> Self {
+        self.max = Some(value.into());
+        self
+    }
+
+    /// Sets [`LimitRangeItem::max_limit_request_ratio`].
+    pub fn max_limit_request_ratio(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>) -> Self {
+        self.max_limit_request_ratio = Some(value.into());
+        self
+    }
+
+    /// Sets [`LimitRangeItem::min`].
+    pub fn min(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>) -> Self {
+        self.min = Some(value.into());
+        self
+    }
+
+    /// Sets [`LimitRangeItem::type_`].
+    pub fn type_(mut self, value: impl Into<String>) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `LimitRangeItem`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<LimitRangeItem, crate::MissingFieldError> {
+        Ok(LimitRangeItem {
+            default: self.default,
+            default_request: self.default_request,
+            max: self.max,
+            max_limit_request_ratio: self.max_limit_request_ratio,
+            min: self.min,
+            type_: self.type_,
+        })
+    }
+}
+
+/// The apply configuration of [`LimitRangeItem`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LimitRangeItemApplyConfiguration {
+    /// See [`LimitRangeItem::default`].
+    pub default: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>,
+
+    /// See [`LimitRangeItem::default_request`].
+    pub default_request: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>,
+
+    /// See [`LimitRangeItem::max`].
+    pub max: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>,
+
+    /// See [`LimitRangeItem::max_limit_request_ratio`].
+    pub max_limit_request_ratio: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>,
+
+    /// See [`LimitRangeItem::min`].
+    pub min: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>,
+
+    /// See [`LimitRangeItem::type_`].
+    pub type_: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for LimitRangeItemApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "LimitRangeItemApplyConfiguration",
+            self.default.as_ref().map_or(0, |_| 1) +
+            self.default_request.as_ref().map_or(0, |_| 1) +
+            self.max.as_ref().map_or(0, |_| 1) +
+            self.max_limit_request_ratio.as_ref().map_or(0, |_| 1) +
+            self.min.as_ref().map_or(0, |_| 1) +
+            self.type_.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.default {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "default", value)?;
+        }
+        if let Some(value) = &self.default_request {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "defaultRequest", value)?;
+        }
+        if let Some(value) = &self.max {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "max", value)?;
+        }
+        if let Some(value) = &self.max_limit_request_ratio {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maxLimitRequestRatio", value)?;
+        }
+        if let Some(value) = &self.min {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "min", value)?;
+        }
+        if let Some(value) = &self.type_ {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "type", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for LimitRangeItem {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.LimitRangeItem".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::LimitRangeItem".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.LimitRangeItem".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -165,8 +402,9 @@ impl crate::schemars::JsonSchema for LimitRangeItem {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "default".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -180,8 +418,8 @@ impl crate::schemars::JsonSchema for LimitRangeItem {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "defaultRequest".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -195,8 +433,8 @@ impl crate::schemars::JsonSchema for LimitRangeItem {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "max".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -210,8 +448,8 @@ impl crate::schemars::JsonSchema for LimitRangeItem {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "maxLimitRequestRatio".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -225,8 +463,8 @@ impl crate::schemars::JsonSchema for LimitRangeItem {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "min".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -240,8 +478,8 @@ impl crate::schemars::JsonSchema for LimitRangeItem {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "type".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -251,11 +489,26 @@ impl crate::schemars::JsonSchema for LimitRangeItem {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for LimitRangeItem {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(LimitRangeItem {
+            default: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            default_request: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            max: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            max_limit_request_ratio: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            min: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            type_: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,43 +1,40 @@
 // Generated from definition io.k8s.api.core.v1.LimitRangeSpec
 
 /// LimitRangeSpec defines a min/max usage limit for resources that match on kind.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct LimitRangeSpec {
     /// Limits is the list of LimitRangeItem objects that are enforced.
     pub limits: Vec<crate::api::core::v1::LimitRangeItem>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for LimitRangeSpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_limits,
-            Other,
+impl LimitRangeSpec {
+    /// Constructs a `LimitRangeSpec` from its required fields. Its optional fields are unset.
+    pub fn new(limits: impl Into<Vec<crate::api::core::v1::LimitRangeItem>>) -> Self {
+        LimitRangeSpec {
+            limits: limits.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl LimitRangeSpec {
+    /// Returns this value with [`LimitRangeSpec::limits`] set to the given value.
+    #[must_use]
+    pub fn with_limits(mut self, value: impl Into<Vec<crate::api::core::v1::LimitRangeItem>>) -> Self {
+        self.limits = value.into();
+        self
+    }
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for LimitRangeSpec {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "limits" => Field::Key_limits,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl LimitRangeSpec {
+    const FIELDS: &'static [&'static str] = &[
+        "limits",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for LimitRangeSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -50,31 +47,81 @@ impl<'de> crate::serde::Deserialize<'de> for LimitRangeSpec {
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
                 let mut value_limits: Option<Vec<crate::api::core::v1::LimitRangeItem>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("LimitRangeSpec", LimitRangeSpec::FIELDS))? {
                     match key {
-                        Field::Key_limits => value_limits = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_limits = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(LimitRangeSpec {
-                    limits: value_limits.ok_or_else(|| crate::serde::de::Error::missing_field("limits"))?,
+                    limits: value_limits.ok_or_else(|| crate::serde2::missing_field("LimitRangeSpec", "limits", &["limits"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(LimitRangeSpec {
+                    limits: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "LimitRangeSpec",
-            &[
-                "limits",
-            ],
+            LimitRangeSpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, LimitRangeSpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("LimitRangeSpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_limits = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("LimitRangeSpec", LimitRangeSpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.limits))?; has_limits = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_limits {
+                    return Err(crate::serde2::missing_field("LimitRangeSpec", "limits", &["limits"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.limits))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "LimitRangeSpec",
+            LimitRangeSpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for LimitRangeSpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("LimitRangeSpec", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "limits", &self.limits)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "LimitRangeSpec",
             1,
@@ -84,10 +131,69 @@ impl crate::serde::Serialize for LimitRangeSpec {
     }
 }
 
+/// A builder of [`LimitRangeSpec`]. Created with [`LimitRangeSpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct LimitRangeSpecBuilder {
+    limits: Option<Vec<crate::api::core::v1::LimitRangeItem>>,
+}
+
+#[cfg(feature = "builder")]
+impl LimitRangeSpec {
+    /// Starts building a `LimitRangeSpec`. Its required fields must be set before [`LimitRangeSpecBuilder::build`] is called.
+    pub fn builder() -> LimitRangeSpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl LimitRangeSpecBuilder {
+    /// Sets [`LimitRangeSpec::limits`].
+    pub fn limits(mut self, value: impl Into<Vec<crate::api::core::v1::LimitRangeItem>>) -> Self {
+        self.limits = Some(value.into());
+        self
+    }
+
+    /// Builds the `LimitRangeSpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<LimitRangeSpec, crate::MissingFieldError> {
+        Ok(LimitRangeSpec {
+            limits: self.limits.ok_or_else(|| crate::MissingFieldError::new("LimitRangeSpec", "limits"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`LimitRangeSpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LimitRangeSpecApplyConfiguration {
+    /// See [`LimitRangeSpec::limits`].
+    pub limits: Option<Vec<crate::api::core::v1::LimitRangeItemApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for LimitRangeSpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "LimitRangeSpecApplyConfiguration",
+            self.limits.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.limits {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "limits", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for LimitRangeSpec {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.LimitRangeSpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::LimitRangeSpec".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.LimitRangeSpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -98,8 +204,9 @@ impl crate::schemars::JsonSchema for LimitRangeSpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "limits".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -113,8 +220,9 @@ impl crate::schemars::JsonSchema for LimitRangeSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "limits",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -124,3 +232,12 @@ impl crate::schemars::JsonSchema for LimitRangeSpec {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for LimitRangeSpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(LimitRangeSpec {
+            limits: crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,80 +1,118 @@
 // Generated from definition io.k8s.api.core.v1.LocalObjectReference
 
 /// LocalObjectReference contains enough information to let you locate the referenced object inside the same namespace.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct LocalObjectReference {
     /// Name of the referent. More info: https://kubernetes.io/docs/concepts/overview/working-with-objects/names/#names
     pub name: Option<String>,
 }
 
+impl LocalObjectReference {
+    /// Returns this value with [`LocalObjectReference::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for LocalObjectReference {}
+
+impl LocalObjectReference {
+    const FIELDS: &'static [&'static str] = &[
+        "name",
+    ];
+}
+
 impl<'de> crate::serde::Deserialize<'de> for LocalObjectReference {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_name,
-            Other,
-        }
+        struct Visitor;
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = LocalObjectReference;
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("LocalObjectReference")
+            }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_name: Option<String> = None;
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "name" => Field::Key_name,
-                            _ => Field::Other,
-                        })
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("LocalObjectReference", LocalObjectReference::FIELDS))? {
+                    match key {
+                        Some(0) => value_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
-                deserializer.deserialize_identifier(Visitor)
+                Ok(LocalObjectReference {
+                    name: value_name,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(LocalObjectReference {
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                })
             }
         }
 
-        struct Visitor;
+        deserializer.deserialize_struct(
+            "LocalObjectReference",
+            LocalObjectReference::FIELDS,
+            Visitor,
+        )
+    }
 
-        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-            type Value = LocalObjectReference;
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, LocalObjectReference> {
+            type Value = ();
 
             fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 f.write_str("LocalObjectReference")
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
-                let mut value_name: Option<String> = None;
+                let mut has_name = false;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("LocalObjectReference", LocalObjectReference::FIELDS))? {
                     match key {
-                        Field::Key_name => value_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.name))?; has_name = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
+                if !has_name {
+                    self.0.name = None;
+                }
 
-                Ok(LocalObjectReference {
-                    name: value_name,
-                })
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
             }
         }
 
         deserializer.deserialize_struct(
             "LocalObjectReference",
-            &[
-                "name",
-            ],
-            Visitor,
+            LocalObjectReference::FIELDS,
+            InPlaceVisitor(place),
         )
     }
 }
 
 impl crate::serde::Serialize for LocalObjectReference {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("LocalObjectReference", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "LocalObjectReference",
             self.name.as_ref().map_or(0, |_| 1),
@@ -86,10 +124,69 @@ impl crate::serde::Serialize for LocalObjectReference {
     }
 }
 
+/// A builder of [`LocalObjectReference`]. Created with [`LocalObjectReference::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct LocalObjectReferenceBuilder {
+    name: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl LocalObjectReference {
+    /// Starts building a `LocalObjectReference`. Its required fields must be set before [`LocalObjectReferenceBuilder::build`] is called.
+    pub fn builder() -> LocalObjectReferenceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl LocalObjectReferenceBuilder {
+    /// Sets [`LocalObjectReference::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `LocalObjectReference`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<LocalObjectReference, crate::MissingFieldError> {
+        Ok(LocalObjectReference {
+            name: self.name,
+        })
+    }
+}
+
+/// The apply configuration of [`LocalObjectReference`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LocalObjectReferenceApplyConfiguration {
+    /// See [`LocalObjectReference::name`].
+    pub name: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for LocalObjectReferenceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "LocalObjectReferenceApplyConfiguration",
+            self.name.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for LocalObjectReference {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.LocalObjectReference".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::LocalObjectReference".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.LocalObjectReference".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -100,8 +197,9 @@ impl crate::schemars::JsonSchema for LocalObjectReference {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -111,11 +209,21 @@ impl crate::schemars::JsonSchema for LocalObjectReference {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for LocalObjectReference {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(LocalObjectReference {
+            name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
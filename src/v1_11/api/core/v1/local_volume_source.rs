@@ -1,43 +1,40 @@
 // Generated from definition io.k8s.api.core.v1.LocalVolumeSource
 
 /// Local represents directly-attached storage with node affinity (Beta feature)
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct LocalVolumeSource {
     /// The full path to the volume on the node. It can be either a directory or block device (disk, partition, ...). Directories can be represented only by PersistentVolume with VolumeMode=Filesystem. Block devices can be represented only by VolumeMode=Block, which also requires the BlockVolume alpha feature gate to be enabled.
     pub path: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for LocalVolumeSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_path,
-            Other,
+impl LocalVolumeSource {
+    /// Constructs a `LocalVolumeSource` from its required fields. Its optional fields are unset.
+    pub fn new(path: impl Into<String>) -> Self {
+        LocalVolumeSource {
+            path: path.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl LocalVolumeSource {
+    /// Returns this value with [`LocalVolumeSource::path`] set to the given value.
+    #[must_use]
+    pub fn with_path(mut self, value: impl Into<String>) -> Self {
+        self.path = value.into();
+        self
+    }
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for LocalVolumeSource {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "path" => Field::Key_path,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl LocalVolumeSource {
+    const FIELDS: &'static [&'static str] = &[
+        "path",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for LocalVolumeSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -50,31 +47,81 @@ impl<'de> crate::serde::Deserialize<'de> for LocalVolumeSource {
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
                 let mut value_path: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("LocalVolumeSource", LocalVolumeSource::FIELDS))? {
                     match key {
-                        Field::Key_path => value_path = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_path = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(LocalVolumeSource {
-                    path: value_path.ok_or_else(|| crate::serde::de::Error::missing_field("path"))?,
+                    path: value_path.ok_or_else(|| crate::serde2::missing_field("LocalVolumeSource", "path", &["path"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(LocalVolumeSource {
+                    path: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "LocalVolumeSource",
-            &[
-                "path",
-            ],
+            LocalVolumeSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, LocalVolumeSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("LocalVolumeSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_path = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("LocalVolumeSource", LocalVolumeSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.path))?; has_path = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_path {
+                    return Err(crate::serde2::missing_field("LocalVolumeSource", "path", &["path"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.path))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "LocalVolumeSource",
+            LocalVolumeSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for LocalVolumeSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("LocalVolumeSource", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "path", &self.path)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "LocalVolumeSource",
             1,
@@ -84,10 +131,69 @@ impl crate::serde::Serialize for LocalVolumeSource {
     }
 }
 
+/// A builder of [`LocalVolumeSource`]. Created with [`LocalVolumeSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct LocalVolumeSourceBuilder {
+    path: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl LocalVolumeSource {
+    /// Starts building a `LocalVolumeSource`. Its required fields must be set before [`LocalVolumeSourceBuilder::build`] is called.
+    pub fn builder() -> LocalVolumeSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl LocalVolumeSourceBuilder {
+    /// Sets [`LocalVolumeSource::path`].
+    pub fn path(mut self, value: impl Into<String>) -> Self {
+        self.path = Some(value.into());
+        self
+    }
+
+    /// Builds the `LocalVolumeSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<LocalVolumeSource, crate::MissingFieldError> {
+        Ok(LocalVolumeSource {
+            path: self.path.ok_or_else(|| crate::MissingFieldError::new("LocalVolumeSource", "path"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`LocalVolumeSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LocalVolumeSourceApplyConfiguration {
+    /// See [`LocalVolumeSource::path`].
+    pub path: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for LocalVolumeSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "LocalVolumeSourceApplyConfiguration",
+            self.path.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.path {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "path", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for LocalVolumeSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.LocalVolumeSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::LocalVolumeSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.LocalVolumeSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -98,8 +204,9 @@ impl crate::schemars::JsonSchema for LocalVolumeSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "path".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -109,8 +216,9 @@ impl crate::schemars::JsonSchema for LocalVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "path",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -120,3 +228,12 @@ impl crate::schemars::JsonSchema for LocalVolumeSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for LocalVolumeSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(LocalVolumeSource {
+            path: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -1,232 +1,530 @@
 
 mod aws_elastic_block_store_volume_source;
 pub use self::aws_elastic_block_store_volume_source::AWSElasticBlockStoreVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::aws_elastic_block_store_volume_source::AWSElasticBlockStoreVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::aws_elastic_block_store_volume_source::AWSElasticBlockStoreVolumeSourceApplyConfiguration;
 
 mod affinity;
 pub use self::affinity::Affinity;
+#[cfg(feature = "apply-configurations")]
+pub use self::affinity::AffinityApplyConfiguration;
 
 mod attached_volume;
 pub use self::attached_volume::AttachedVolume;
+#[cfg(feature = "builder")]
+pub use self::attached_volume::AttachedVolumeBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::attached_volume::AttachedVolumeApplyConfiguration;
 
 mod azure_disk_volume_source;
 pub use self::azure_disk_volume_source::AzureDiskVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::azure_disk_volume_source::AzureDiskVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::azure_disk_volume_source::AzureDiskVolumeSourceApplyConfiguration;
 
 mod azure_file_persistent_volume_source;
 pub use self::azure_file_persistent_volume_source::AzureFilePersistentVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::azure_file_persistent_volume_source::AzureFilePersistentVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::azure_file_persistent_volume_source::AzureFilePersistentVolumeSourceApplyConfiguration;
 
 mod azure_file_volume_source;
 pub use self::azure_file_volume_source::AzureFileVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::azure_file_volume_source::AzureFileVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::azure_file_volume_source::AzureFileVolumeSourceApplyConfiguration;
 
 mod binding;
 pub use self::binding::Binding;
+#[cfg(feature = "builder")]
+pub use self::binding::BindingBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::binding::BindingApplyConfiguration;
 
 mod csi_persistent_volume_source;
 pub use self::csi_persistent_volume_source::CSIPersistentVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::csi_persistent_volume_source::CSIPersistentVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::csi_persistent_volume_source::CSIPersistentVolumeSourceApplyConfiguration;
 
 mod capabilities;
 pub use self::capabilities::Capabilities;
+#[cfg(feature = "builder")]
+pub use self::capabilities::CapabilitiesBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::capabilities::CapabilitiesApplyConfiguration;
 
 mod ceph_fs_persistent_volume_source;
 pub use self::ceph_fs_persistent_volume_source::CephFSPersistentVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::ceph_fs_persistent_volume_source::CephFSPersistentVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::ceph_fs_persistent_volume_source::CephFSPersistentVolumeSourceApplyConfiguration;
 
 mod ceph_fs_volume_source;
 pub use self::ceph_fs_volume_source::CephFSVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::ceph_fs_volume_source::CephFSVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::ceph_fs_volume_source::CephFSVolumeSourceApplyConfiguration;
 
 mod cinder_persistent_volume_source;
 pub use self::cinder_persistent_volume_source::CinderPersistentVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::cinder_persistent_volume_source::CinderPersistentVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::cinder_persistent_volume_source::CinderPersistentVolumeSourceApplyConfiguration;
 
 mod cinder_volume_source;
 pub use self::cinder_volume_source::CinderVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::cinder_volume_source::CinderVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::cinder_volume_source::CinderVolumeSourceApplyConfiguration;
 
 mod client_ip_config;
 pub use self::client_ip_config::ClientIPConfig;
+#[cfg(feature = "builder")]
+pub use self::client_ip_config::ClientIPConfigBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::client_ip_config::ClientIPConfigApplyConfiguration;
 
 mod component_condition;
 pub use self::component_condition::ComponentCondition;
+#[cfg(feature = "builder")]
+pub use self::component_condition::ComponentConditionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::component_condition::ComponentConditionApplyConfiguration;
 
 mod component_status;
 pub use self::component_status::ComponentStatus;
+#[cfg(feature = "builder")]
+pub use self::component_status::ComponentStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::component_status::ComponentStatusApplyConfiguration;
 #[cfg(feature = "api")] pub use self::component_status::{ReadComponentStatusOptional, ReadComponentStatusResponse};
 
 mod config_map;
 pub use self::config_map::ConfigMap;
+#[cfg(feature = "builder")]
+pub use self::config_map::ConfigMapBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::config_map::ConfigMapApplyConfiguration;
 #[cfg(feature = "api")] pub use self::config_map::{ReadNamespacedConfigMapOptional, ReadNamespacedConfigMapResponse};
 
 mod config_map_env_source;
 pub use self::config_map_env_source::ConfigMapEnvSource;
+#[cfg(feature = "builder")]
+pub use self::config_map_env_source::ConfigMapEnvSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::config_map_env_source::ConfigMapEnvSourceApplyConfiguration;
 
 mod config_map_key_selector;
 pub use self::config_map_key_selector::ConfigMapKeySelector;
+#[cfg(feature = "builder")]
+pub use self::config_map_key_selector::ConfigMapKeySelectorBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::config_map_key_selector::ConfigMapKeySelectorApplyConfiguration;
 
 mod config_map_node_config_source;
 pub use self::config_map_node_config_source::ConfigMapNodeConfigSource;
+#[cfg(feature = "builder")]
+pub use self::config_map_node_config_source::ConfigMapNodeConfigSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::config_map_node_config_source::ConfigMapNodeConfigSourceApplyConfiguration;
 
 mod config_map_projection;
 pub use self::config_map_projection::ConfigMapProjection;
+#[cfg(feature = "builder")]
+pub use self::config_map_projection::ConfigMapProjectionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::config_map_projection::ConfigMapProjectionApplyConfiguration;
 
 mod config_map_volume_source;
 pub use self::config_map_volume_source::ConfigMapVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::config_map_volume_source::ConfigMapVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::config_map_volume_source::ConfigMapVolumeSourceApplyConfiguration;
 
 mod container;
 pub use self::container::Container;
+#[cfg(feature = "builder")]
+pub use self::container::ContainerBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::container::ContainerApplyConfiguration;
 
 mod container_image;
 pub use self::container_image::ContainerImage;
+#[cfg(feature = "builder")]
+pub use self::container_image::ContainerImageBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::container_image::ContainerImageApplyConfiguration;
 
 mod container_port;
 pub use self::container_port::ContainerPort;
+#[cfg(feature = "builder")]
+pub use self::container_port::ContainerPortBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::container_port::ContainerPortApplyConfiguration;
 
 mod container_state;
 pub use self::container_state::ContainerState;
+#[cfg(feature = "builder")]
+pub use self::container_state::ContainerStateBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::container_state::ContainerStateApplyConfiguration;
 
 mod container_state_running;
 pub use self::container_state_running::ContainerStateRunning;
+#[cfg(feature = "builder")]
+pub use self::container_state_running::ContainerStateRunningBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::container_state_running::ContainerStateRunningApplyConfiguration;
 
 mod container_state_terminated;
 pub use self::container_state_terminated::ContainerStateTerminated;
+#[cfg(feature = "builder")]
+pub use self::container_state_terminated::ContainerStateTerminatedBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::container_state_terminated::ContainerStateTerminatedApplyConfiguration;
 
 mod container_state_waiting;
 pub use self::container_state_waiting::ContainerStateWaiting;
+#[cfg(feature = "builder")]
+pub use self::container_state_waiting::ContainerStateWaitingBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::container_state_waiting::ContainerStateWaitingApplyConfiguration;
 
 mod container_status;
 pub use self::container_status::ContainerStatus;
+#[cfg(feature = "builder")]
+pub use self::container_status::ContainerStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::container_status::ContainerStatusApplyConfiguration;
+
+mod dns_policy;
+pub use self::dns_policy::DNSPolicy;
 
 mod daemon_endpoint;
 pub use self::daemon_endpoint::DaemonEndpoint;
+#[cfg(feature = "builder")]
+pub use self::daemon_endpoint::DaemonEndpointBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::daemon_endpoint::DaemonEndpointApplyConfiguration;
 
 mod downward_api_projection;
 pub use self::downward_api_projection::DownwardAPIProjection;
+#[cfg(feature = "builder")]
+pub use self::downward_api_projection::DownwardAPIProjectionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::downward_api_projection::DownwardAPIProjectionApplyConfiguration;
 
 mod downward_api_volume_file;
 pub use self::downward_api_volume_file::DownwardAPIVolumeFile;
+#[cfg(feature = "builder")]
+pub use self::downward_api_volume_file::DownwardAPIVolumeFileBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::downward_api_volume_file::DownwardAPIVolumeFileApplyConfiguration;
 
 mod downward_api_volume_source;
 pub use self::downward_api_volume_source::DownwardAPIVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::downward_api_volume_source::DownwardAPIVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::downward_api_volume_source::DownwardAPIVolumeSourceApplyConfiguration;
 
 mod empty_dir_volume_source;
 pub use self::empty_dir_volume_source::EmptyDirVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::empty_dir_volume_source::EmptyDirVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::empty_dir_volume_source::EmptyDirVolumeSourceApplyConfiguration;
 
 mod endpoint_address;
 pub use self::endpoint_address::EndpointAddress;
+#[cfg(feature = "builder")]
+pub use self::endpoint_address::EndpointAddressBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::endpoint_address::EndpointAddressApplyConfiguration;
 
 mod endpoint_port;
 pub use self::endpoint_port::EndpointPort;
+#[cfg(feature = "builder")]
+pub use self::endpoint_port::EndpointPortBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::endpoint_port::EndpointPortApplyConfiguration;
 
 mod endpoint_subset;
 pub use self::endpoint_subset::EndpointSubset;
+#[cfg(feature = "builder")]
+pub use self::endpoint_subset::EndpointSubsetBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::endpoint_subset::EndpointSubsetApplyConfiguration;
 
 mod endpoints;
 pub use self::endpoints::Endpoints;
+#[cfg(feature = "builder")]
+pub use self::endpoints::EndpointsBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::endpoints::EndpointsApplyConfiguration;
 #[cfg(feature = "api")] pub use self::endpoints::{ReadNamespacedEndpointsOptional, ReadNamespacedEndpointsResponse};
 
 mod env_from_source;
 pub use self::env_from_source::EnvFromSource;
+#[cfg(feature = "builder")]
+pub use self::env_from_source::EnvFromSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::env_from_source::EnvFromSourceApplyConfiguration;
 
 mod env_var;
 pub use self::env_var::EnvVar;
+#[cfg(feature = "builder")]
+pub use self::env_var::EnvVarBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::env_var::EnvVarApplyConfiguration;
 
 mod env_var_source;
 pub use self::env_var_source::EnvVarSource;
+#[cfg(feature = "builder")]
+pub use self::env_var_source::EnvVarSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::env_var_source::EnvVarSourceApplyConfiguration;
 
 mod event;
 pub use self::event::Event;
+#[cfg(feature = "builder")]
+pub use self::event::EventBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::event::EventApplyConfiguration;
 #[cfg(feature = "api")] pub use self::event::{ReadNamespacedEventOptional, ReadNamespacedEventResponse};
 
 mod event_series;
 pub use self::event_series::EventSeries;
+#[cfg(feature = "builder")]
+pub use self::event_series::EventSeriesBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::event_series::EventSeriesApplyConfiguration;
 
 mod event_source;
 pub use self::event_source::EventSource;
+#[cfg(feature = "builder")]
+pub use self::event_source::EventSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::event_source::EventSourceApplyConfiguration;
 
 mod exec_action;
 pub use self::exec_action::ExecAction;
+#[cfg(feature = "builder")]
+pub use self::exec_action::ExecActionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::exec_action::ExecActionApplyConfiguration;
 
 mod fc_volume_source;
 pub use self::fc_volume_source::FCVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::fc_volume_source::FCVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::fc_volume_source::FCVolumeSourceApplyConfiguration;
 
 mod flex_persistent_volume_source;
 pub use self::flex_persistent_volume_source::FlexPersistentVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::flex_persistent_volume_source::FlexPersistentVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::flex_persistent_volume_source::FlexPersistentVolumeSourceApplyConfiguration;
 
 mod flex_volume_source;
 pub use self::flex_volume_source::FlexVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::flex_volume_source::FlexVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::flex_volume_source::FlexVolumeSourceApplyConfiguration;
 
 mod flocker_volume_source;
 pub use self::flocker_volume_source::FlockerVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::flocker_volume_source::FlockerVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::flocker_volume_source::FlockerVolumeSourceApplyConfiguration;
 
 mod gce_persistent_disk_volume_source;
 pub use self::gce_persistent_disk_volume_source::GCEPersistentDiskVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::gce_persistent_disk_volume_source::GCEPersistentDiskVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::gce_persistent_disk_volume_source::GCEPersistentDiskVolumeSourceApplyConfiguration;
 
 mod git_repo_volume_source;
 pub use self::git_repo_volume_source::GitRepoVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::git_repo_volume_source::GitRepoVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::git_repo_volume_source::GitRepoVolumeSourceApplyConfiguration;
 
 mod glusterfs_volume_source;
 pub use self::glusterfs_volume_source::GlusterfsVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::glusterfs_volume_source::GlusterfsVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::glusterfs_volume_source::GlusterfsVolumeSourceApplyConfiguration;
 
 mod http_get_action;
 pub use self::http_get_action::HTTPGetAction;
+#[cfg(feature = "builder")]
+pub use self::http_get_action::HTTPGetActionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::http_get_action::HTTPGetActionApplyConfiguration;
 
 mod http_header;
 pub use self::http_header::HTTPHeader;
+#[cfg(feature = "builder")]
+pub use self::http_header::HTTPHeaderBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::http_header::HTTPHeaderApplyConfiguration;
 
 mod handler;
 pub use self::handler::Handler;
+pub use self::handler::HandlerAction;
+#[cfg(feature = "builder")]
+pub use self::handler::HandlerBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::handler::HandlerApplyConfiguration;
 
 mod host_alias;
 pub use self::host_alias::HostAlias;
+#[cfg(feature = "builder")]
+pub use self::host_alias::HostAliasBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::host_alias::HostAliasApplyConfiguration;
 
 mod host_path_volume_source;
 pub use self::host_path_volume_source::HostPathVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::host_path_volume_source::HostPathVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::host_path_volume_source::HostPathVolumeSourceApplyConfiguration;
 
 mod iscsi_persistent_volume_source;
 pub use self::iscsi_persistent_volume_source::ISCSIPersistentVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::iscsi_persistent_volume_source::ISCSIPersistentVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::iscsi_persistent_volume_source::ISCSIPersistentVolumeSourceApplyConfiguration;
 
 mod iscsi_volume_source;
 pub use self::iscsi_volume_source::ISCSIVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::iscsi_volume_source::ISCSIVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::iscsi_volume_source::ISCSIVolumeSourceApplyConfiguration;
 
 mod key_to_path;
 pub use self::key_to_path::KeyToPath;
+#[cfg(feature = "builder")]
+pub use self::key_to_path::KeyToPathBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::key_to_path::KeyToPathApplyConfiguration;
 
 mod lifecycle;
 pub use self::lifecycle::Lifecycle;
+#[cfg(feature = "builder")]
+pub use self::lifecycle::LifecycleBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::lifecycle::LifecycleApplyConfiguration;
 
 mod limit_range;
 pub use self::limit_range::LimitRange;
+#[cfg(feature = "builder")]
+pub use self::limit_range::LimitRangeBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::limit_range::LimitRangeApplyConfiguration;
 #[cfg(feature = "api")] pub use self::limit_range::{ReadNamespacedLimitRangeOptional, ReadNamespacedLimitRangeResponse};
 
 mod limit_range_item;
 pub use self::limit_range_item::LimitRangeItem;
+#[cfg(feature = "builder")]
+pub use self::limit_range_item::LimitRangeItemBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::limit_range_item::LimitRangeItemApplyConfiguration;
 
 mod limit_range_spec;
 pub use self::limit_range_spec::LimitRangeSpec;
+#[cfg(feature = "builder")]
+pub use self::limit_range_spec::LimitRangeSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::limit_range_spec::LimitRangeSpecApplyConfiguration;
 
 mod load_balancer_ingress;
 pub use self::load_balancer_ingress::LoadBalancerIngress;
+#[cfg(feature = "builder")]
+pub use self::load_balancer_ingress::LoadBalancerIngressBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::load_balancer_ingress::LoadBalancerIngressApplyConfiguration;
 
 mod load_balancer_status;
 pub use self::load_balancer_status::LoadBalancerStatus;
+#[cfg(feature = "builder")]
+pub use self::load_balancer_status::LoadBalancerStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::load_balancer_status::LoadBalancerStatusApplyConfiguration;
 
 mod local_object_reference;
 pub use self::local_object_reference::LocalObjectReference;
+#[cfg(feature = "builder")]
+pub use self::local_object_reference::LocalObjectReferenceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::local_object_reference::LocalObjectReferenceApplyConfiguration;
 
 mod local_volume_source;
 pub use self::local_volume_source::LocalVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::local_volume_source::LocalVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::local_volume_source::LocalVolumeSourceApplyConfiguration;
 
 mod nfs_volume_source;
 pub use self::nfs_volume_source::NFSVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::nfs_volume_source::NFSVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::nfs_volume_source::NFSVolumeSourceApplyConfiguration;
 
 mod namespace;
 pub use self::namespace::Namespace;
+#[cfg(feature = "builder")]
+pub use self::namespace::NamespaceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::namespace::NamespaceApplyConfiguration;
 #[cfg(feature = "api")] pub use self::namespace::{ReadNamespaceOptional, ReadNamespaceResponse};
 #[cfg(feature = "api")] pub use self::namespace::{ReadNamespaceStatusOptional, ReadNamespaceStatusResponse};
 
 mod namespace_spec;
 pub use self::namespace_spec::NamespaceSpec;
+#[cfg(feature = "builder")]
+pub use self::namespace_spec::NamespaceSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::namespace_spec::NamespaceSpecApplyConfiguration;
 
 mod namespace_status;
 pub use self::namespace_status::NamespaceStatus;
+#[cfg(feature = "builder")]
+pub use self::namespace_status::NamespaceStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::namespace_status::NamespaceStatusApplyConfiguration;
 
 mod node;
 pub use self::node::Node;
+#[cfg(feature = "builder")]
+pub use self::node::NodeBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::node::NodeApplyConfiguration;
 #[cfg(feature = "api")] pub use self::node::ConnectDeleteNodeProxyOptional;
 #[cfg(feature = "api")] pub use self::node::ConnectDeleteNodeProxyWithPathOptional;
 #[cfg(feature = "api")] pub use self::node::ConnectGetNodeProxyOptional;
@@ -242,79 +540,182 @@ pub use self::node::Node;
 
 mod node_address;
 pub use self::node_address::NodeAddress;
+#[cfg(feature = "builder")]
+pub use self::node_address::NodeAddressBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::node_address::NodeAddressApplyConfiguration;
 
 mod node_affinity;
 pub use self::node_affinity::NodeAffinity;
+#[cfg(feature = "builder")]
+pub use self::node_affinity::NodeAffinityBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::node_affinity::NodeAffinityApplyConfiguration;
 
 mod node_condition;
 pub use self::node_condition::NodeCondition;
+#[cfg(feature = "builder")]
+pub use self::node_condition::NodeConditionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::node_condition::NodeConditionApplyConfiguration;
 
 mod node_config_source;
 pub use self::node_config_source::NodeConfigSource;
+#[cfg(feature = "builder")]
+pub use self::node_config_source::NodeConfigSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::node_config_source::NodeConfigSourceApplyConfiguration;
 
 mod node_config_status;
 pub use self::node_config_status::NodeConfigStatus;
+#[cfg(feature = "builder")]
+pub use self::node_config_status::NodeConfigStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::node_config_status::NodeConfigStatusApplyConfiguration;
 
 mod node_daemon_endpoints;
 pub use self::node_daemon_endpoints::NodeDaemonEndpoints;
+#[cfg(feature = "builder")]
+pub use self::node_daemon_endpoints::NodeDaemonEndpointsBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::node_daemon_endpoints::NodeDaemonEndpointsApplyConfiguration;
 
 mod node_selector;
 pub use self::node_selector::NodeSelector;
+#[cfg(feature = "builder")]
+pub use self::node_selector::NodeSelectorBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::node_selector::NodeSelectorApplyConfiguration;
+
+mod node_selector_operator;
+pub use self::node_selector_operator::NodeSelectorOperator;
 
 mod node_selector_requirement;
 pub use self::node_selector_requirement::NodeSelectorRequirement;
+#[cfg(feature = "builder")]
+pub use self::node_selector_requirement::NodeSelectorRequirementBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::node_selector_requirement::NodeSelectorRequirementApplyConfiguration;
 
 mod node_selector_term;
 pub use self::node_selector_term::NodeSelectorTerm;
+#[cfg(feature = "builder")]
+pub use self::node_selector_term::NodeSelectorTermBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::node_selector_term::NodeSelectorTermApplyConfiguration;
 
 mod node_spec;
 pub use self::node_spec::NodeSpec;
+#[cfg(feature = "builder")]
+pub use self::node_spec::NodeSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::node_spec::NodeSpecApplyConfiguration;
 
 mod node_status;
 pub use self::node_status::NodeStatus;
+#[cfg(feature = "builder")]
+pub use self::node_status::NodeStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::node_status::NodeStatusApplyConfiguration;
 
 mod node_system_info;
 pub use self::node_system_info::NodeSystemInfo;
+#[cfg(feature = "builder")]
+pub use self::node_system_info::NodeSystemInfoBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::node_system_info::NodeSystemInfoApplyConfiguration;
 
 mod object_field_selector;
 pub use self::object_field_selector::ObjectFieldSelector;
+#[cfg(feature = "builder")]
+pub use self::object_field_selector::ObjectFieldSelectorBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::object_field_selector::ObjectFieldSelectorApplyConfiguration;
 
 mod object_reference;
 pub use self::object_reference::ObjectReference;
+#[cfg(feature = "builder")]
+pub use self::object_reference::ObjectReferenceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::object_reference::ObjectReferenceApplyConfiguration;
 
 mod persistent_volume;
 pub use self::persistent_volume::PersistentVolume;
+#[cfg(feature = "builder")]
+pub use self::persistent_volume::PersistentVolumeBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::persistent_volume::PersistentVolumeApplyConfiguration;
 #[cfg(feature = "api")] pub use self::persistent_volume::{ReadPersistentVolumeOptional, ReadPersistentVolumeResponse};
 #[cfg(feature = "api")] pub use self::persistent_volume::{ReadPersistentVolumeStatusOptional, ReadPersistentVolumeStatusResponse};
 
 mod persistent_volume_claim;
 pub use self::persistent_volume_claim::PersistentVolumeClaim;
+#[cfg(feature = "builder")]
+pub use self::persistent_volume_claim::PersistentVolumeClaimBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::persistent_volume_claim::PersistentVolumeClaimApplyConfiguration;
 #[cfg(feature = "api")] pub use self::persistent_volume_claim::{ReadNamespacedPersistentVolumeClaimOptional, ReadNamespacedPersistentVolumeClaimResponse};
 #[cfg(feature = "api")] pub use self::persistent_volume_claim::{ReadNamespacedPersistentVolumeClaimStatusOptional, ReadNamespacedPersistentVolumeClaimStatusResponse};
 
 mod persistent_volume_claim_condition;
 pub use self::persistent_volume_claim_condition::PersistentVolumeClaimCondition;
+#[cfg(feature = "builder")]
+pub use self::persistent_volume_claim_condition::PersistentVolumeClaimConditionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::persistent_volume_claim_condition::PersistentVolumeClaimConditionApplyConfiguration;
 
 mod persistent_volume_claim_spec;
 pub use self::persistent_volume_claim_spec::PersistentVolumeClaimSpec;
+#[cfg(feature = "builder")]
+pub use self::persistent_volume_claim_spec::PersistentVolumeClaimSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::persistent_volume_claim_spec::PersistentVolumeClaimSpecApplyConfiguration;
 
 mod persistent_volume_claim_status;
 pub use self::persistent_volume_claim_status::PersistentVolumeClaimStatus;
+#[cfg(feature = "builder")]
+pub use self::persistent_volume_claim_status::PersistentVolumeClaimStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::persistent_volume_claim_status::PersistentVolumeClaimStatusApplyConfiguration;
 
 mod persistent_volume_claim_volume_source;
 pub use self::persistent_volume_claim_volume_source::PersistentVolumeClaimVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::persistent_volume_claim_volume_source::PersistentVolumeClaimVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::persistent_volume_claim_volume_source::PersistentVolumeClaimVolumeSourceApplyConfiguration;
+
+mod persistent_volume_reclaim_policy;
+pub use self::persistent_volume_reclaim_policy::PersistentVolumeReclaimPolicy;
 
 mod persistent_volume_spec;
 pub use self::persistent_volume_spec::PersistentVolumeSpec;
+pub use self::persistent_volume_spec::PersistentVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::persistent_volume_spec::PersistentVolumeSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::persistent_volume_spec::PersistentVolumeSpecApplyConfiguration;
 
 mod persistent_volume_status;
 pub use self::persistent_volume_status::PersistentVolumeStatus;
+#[cfg(feature = "builder")]
+pub use self::persistent_volume_status::PersistentVolumeStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::persistent_volume_status::PersistentVolumeStatusApplyConfiguration;
 
 mod photon_persistent_disk_volume_source;
 pub use self::photon_persistent_disk_volume_source::PhotonPersistentDiskVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::photon_persistent_disk_volume_source::PhotonPersistentDiskVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::photon_persistent_disk_volume_source::PhotonPersistentDiskVolumeSourceApplyConfiguration;
 
 mod pod;
 pub use self::pod::Pod;
+#[cfg(feature = "builder")]
+pub use self::pod::PodBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::pod::PodApplyConfiguration;
 #[cfg(feature = "api")] pub use self::pod::ConnectDeleteNamespacedPodProxyOptional;
 #[cfg(feature = "api")] pub use self::pod::ConnectDeleteNamespacedPodProxyWithPathOptional;
 #[cfg(feature = "api")] pub use self::pod::ConnectGetNamespacedPodAttachOptional;
@@ -337,132 +738,304 @@ pub use self::pod::Pod;
 
 mod pod_affinity;
 pub use self::pod_affinity::PodAffinity;
+#[cfg(feature = "builder")]
+pub use self::pod_affinity::PodAffinityBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::pod_affinity::PodAffinityApplyConfiguration;
 
 mod pod_affinity_term;
 pub use self::pod_affinity_term::PodAffinityTerm;
+#[cfg(feature = "builder")]
+pub use self::pod_affinity_term::PodAffinityTermBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::pod_affinity_term::PodAffinityTermApplyConfiguration;
 
 mod pod_anti_affinity;
 pub use self::pod_anti_affinity::PodAntiAffinity;
+#[cfg(feature = "builder")]
+pub use self::pod_anti_affinity::PodAntiAffinityBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::pod_anti_affinity::PodAntiAffinityApplyConfiguration;
 
 mod pod_condition;
 pub use self::pod_condition::PodCondition;
+#[cfg(feature = "builder")]
+pub use self::pod_condition::PodConditionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::pod_condition::PodConditionApplyConfiguration;
 
 mod pod_dns_config;
 pub use self::pod_dns_config::PodDNSConfig;
+#[cfg(feature = "builder")]
+pub use self::pod_dns_config::PodDNSConfigBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::pod_dns_config::PodDNSConfigApplyConfiguration;
 
 mod pod_dns_config_option;
 pub use self::pod_dns_config_option::PodDNSConfigOption;
+#[cfg(feature = "builder")]
+pub use self::pod_dns_config_option::PodDNSConfigOptionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::pod_dns_config_option::PodDNSConfigOptionApplyConfiguration;
 
 mod pod_readiness_gate;
 pub use self::pod_readiness_gate::PodReadinessGate;
+#[cfg(feature = "builder")]
+pub use self::pod_readiness_gate::PodReadinessGateBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::pod_readiness_gate::PodReadinessGateApplyConfiguration;
 
 mod pod_security_context;
 pub use self::pod_security_context::PodSecurityContext;
+#[cfg(feature = "builder")]
+pub use self::pod_security_context::PodSecurityContextBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::pod_security_context::PodSecurityContextApplyConfiguration;
 
 mod pod_spec;
 pub use self::pod_spec::PodSpec;
+#[cfg(feature = "builder")]
+pub use self::pod_spec::PodSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::pod_spec::PodSpecApplyConfiguration;
 
 mod pod_status;
 pub use self::pod_status::PodStatus;
+#[cfg(feature = "builder")]
+pub use self::pod_status::PodStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::pod_status::PodStatusApplyConfiguration;
 
 mod pod_template;
 pub use self::pod_template::PodTemplate;
+#[cfg(feature = "builder")]
+pub use self::pod_template::PodTemplateBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::pod_template::PodTemplateApplyConfiguration;
 #[cfg(feature = "api")] pub use self::pod_template::{ReadNamespacedPodTemplateOptional, ReadNamespacedPodTemplateResponse};
 
 mod pod_template_spec;
 pub use self::pod_template_spec::PodTemplateSpec;
+#[cfg(feature = "builder")]
+pub use self::pod_template_spec::PodTemplateSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::pod_template_spec::PodTemplateSpecApplyConfiguration;
 
 mod portworx_volume_source;
 pub use self::portworx_volume_source::PortworxVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::portworx_volume_source::PortworxVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::portworx_volume_source::PortworxVolumeSourceApplyConfiguration;
 
 mod preferred_scheduling_term;
 pub use self::preferred_scheduling_term::PreferredSchedulingTerm;
+#[cfg(feature = "builder")]
+pub use self::preferred_scheduling_term::PreferredSchedulingTermBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::preferred_scheduling_term::PreferredSchedulingTermApplyConfiguration;
 
 mod probe;
 pub use self::probe::Probe;
+pub use self::probe::ProbeAction;
+#[cfg(feature = "builder")]
+pub use self::probe::ProbeBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::probe::ProbeApplyConfiguration;
 
 mod projected_volume_source;
 pub use self::projected_volume_source::ProjectedVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::projected_volume_source::ProjectedVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::projected_volume_source::ProjectedVolumeSourceApplyConfiguration;
+
+mod protocol;
+pub use self::protocol::Protocol;
+
+mod pull_policy;
+pub use self::pull_policy::PullPolicy;
 
 mod quobyte_volume_source;
 pub use self::quobyte_volume_source::QuobyteVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::quobyte_volume_source::QuobyteVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::quobyte_volume_source::QuobyteVolumeSourceApplyConfiguration;
 
 mod rbd_persistent_volume_source;
 pub use self::rbd_persistent_volume_source::RBDPersistentVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::rbd_persistent_volume_source::RBDPersistentVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::rbd_persistent_volume_source::RBDPersistentVolumeSourceApplyConfiguration;
 
 mod rbd_volume_source;
 pub use self::rbd_volume_source::RBDVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::rbd_volume_source::RBDVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::rbd_volume_source::RBDVolumeSourceApplyConfiguration;
 
 mod replication_controller;
 pub use self::replication_controller::ReplicationController;
+#[cfg(feature = "builder")]
+pub use self::replication_controller::ReplicationControllerBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::replication_controller::ReplicationControllerApplyConfiguration;
 #[cfg(feature = "api")] pub use self::replication_controller::{ReadNamespacedReplicationControllerOptional, ReadNamespacedReplicationControllerResponse};
 #[cfg(feature = "api")] pub use self::replication_controller::{ReadNamespacedReplicationControllerStatusOptional, ReadNamespacedReplicationControllerStatusResponse};
 
 mod replication_controller_condition;
 pub use self::replication_controller_condition::ReplicationControllerCondition;
+#[cfg(feature = "builder")]
+pub use self::replication_controller_condition::ReplicationControllerConditionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::replication_controller_condition::ReplicationControllerConditionApplyConfiguration;
 
 mod replication_controller_spec;
 pub use self::replication_controller_spec::ReplicationControllerSpec;
+#[cfg(feature = "builder")]
+pub use self::replication_controller_spec::ReplicationControllerSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::replication_controller_spec::ReplicationControllerSpecApplyConfiguration;
 
 mod replication_controller_status;
 pub use self::replication_controller_status::ReplicationControllerStatus;
+#[cfg(feature = "builder")]
+pub use self::replication_controller_status::ReplicationControllerStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::replication_controller_status::ReplicationControllerStatusApplyConfiguration;
 
 mod resource_field_selector;
 pub use self::resource_field_selector::ResourceFieldSelector;
+#[cfg(feature = "builder")]
+pub use self::resource_field_selector::ResourceFieldSelectorBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::resource_field_selector::ResourceFieldSelectorApplyConfiguration;
 
 mod resource_quota;
 pub use self::resource_quota::ResourceQuota;
+#[cfg(feature = "builder")]
+pub use self::resource_quota::ResourceQuotaBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::resource_quota::ResourceQuotaApplyConfiguration;
 #[cfg(feature = "api")] pub use self::resource_quota::{ReadNamespacedResourceQuotaOptional, ReadNamespacedResourceQuotaResponse};
 #[cfg(feature = "api")] pub use self::resource_quota::{ReadNamespacedResourceQuotaStatusOptional, ReadNamespacedResourceQuotaStatusResponse};
 
 mod resource_quota_spec;
 pub use self::resource_quota_spec::ResourceQuotaSpec;
+#[cfg(feature = "builder")]
+pub use self::resource_quota_spec::ResourceQuotaSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::resource_quota_spec::ResourceQuotaSpecApplyConfiguration;
 
 mod resource_quota_status;
 pub use self::resource_quota_status::ResourceQuotaStatus;
+#[cfg(feature = "builder")]
+pub use self::resource_quota_status::ResourceQuotaStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::resource_quota_status::ResourceQuotaStatusApplyConfiguration;
 
 mod resource_requirements;
 pub use self::resource_requirements::ResourceRequirements;
+#[cfg(feature = "apply-configurations")]
+pub use self::resource_requirements::ResourceRequirementsApplyConfiguration;
+
+mod restart_policy;
+pub use self::restart_policy::RestartPolicy;
 
 mod se_linux_options;
 pub use self::se_linux_options::SELinuxOptions;
+#[cfg(feature = "builder")]
+pub use self::se_linux_options::SELinuxOptionsBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::se_linux_options::SELinuxOptionsApplyConfiguration;
 
 mod scale_io_persistent_volume_source;
 pub use self::scale_io_persistent_volume_source::ScaleIOPersistentVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::scale_io_persistent_volume_source::ScaleIOPersistentVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::scale_io_persistent_volume_source::ScaleIOPersistentVolumeSourceApplyConfiguration;
 
 mod scale_io_volume_source;
 pub use self::scale_io_volume_source::ScaleIOVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::scale_io_volume_source::ScaleIOVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::scale_io_volume_source::ScaleIOVolumeSourceApplyConfiguration;
 
 mod scope_selector;
 pub use self::scope_selector::ScopeSelector;
+#[cfg(feature = "builder")]
+pub use self::scope_selector::ScopeSelectorBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::scope_selector::ScopeSelectorApplyConfiguration;
 
 mod scoped_resource_selector_requirement;
 pub use self::scoped_resource_selector_requirement::ScopedResourceSelectorRequirement;
+#[cfg(feature = "builder")]
+pub use self::scoped_resource_selector_requirement::ScopedResourceSelectorRequirementBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::scoped_resource_selector_requirement::ScopedResourceSelectorRequirementApplyConfiguration;
 
 mod secret;
 pub use self::secret::Secret;
+#[cfg(feature = "builder")]
+pub use self::secret::SecretBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::secret::SecretApplyConfiguration;
 #[cfg(feature = "api")] pub use self::secret::{ReadNamespacedSecretOptional, ReadNamespacedSecretResponse};
 
 mod secret_env_source;
 pub use self::secret_env_source::SecretEnvSource;
+#[cfg(feature = "builder")]
+pub use self::secret_env_source::SecretEnvSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::secret_env_source::SecretEnvSourceApplyConfiguration;
 
 mod secret_key_selector;
 pub use self::secret_key_selector::SecretKeySelector;
+#[cfg(feature = "builder")]
+pub use self::secret_key_selector::SecretKeySelectorBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::secret_key_selector::SecretKeySelectorApplyConfiguration;
 
 mod secret_projection;
 pub use self::secret_projection::SecretProjection;
+#[cfg(feature = "builder")]
+pub use self::secret_projection::SecretProjectionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::secret_projection::SecretProjectionApplyConfiguration;
 
 mod secret_reference;
 pub use self::secret_reference::SecretReference;
+#[cfg(feature = "builder")]
+pub use self::secret_reference::SecretReferenceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::secret_reference::SecretReferenceApplyConfiguration;
 
 mod secret_volume_source;
 pub use self::secret_volume_source::SecretVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::secret_volume_source::SecretVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::secret_volume_source::SecretVolumeSourceApplyConfiguration;
 
 mod security_context;
 pub use self::security_context::SecurityContext;
+#[cfg(feature = "builder")]
+pub use self::security_context::SecurityContextBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::security_context::SecurityContextApplyConfiguration;
 
 mod service;
 pub use self::service::Service;
+#[cfg(feature = "builder")]
+pub use self::service::ServiceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::service::ServiceApplyConfiguration;
 #[cfg(feature = "api")] pub use self::service::ConnectDeleteNamespacedServiceProxyOptional;
 #[cfg(feature = "api")] pub use self::service::ConnectDeleteNamespacedServiceProxyWithPathOptional;
 #[cfg(feature = "api")] pub use self::service::ConnectGetNamespacedServiceProxyOptional;
@@ -478,64 +1051,168 @@ pub use self::service::Service;
 
 mod service_account;
 pub use self::service_account::ServiceAccount;
+#[cfg(feature = "builder")]
+pub use self::service_account::ServiceAccountBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::service_account::ServiceAccountApplyConfiguration;
 #[cfg(feature = "api")] pub use self::service_account::{ReadNamespacedServiceAccountOptional, ReadNamespacedServiceAccountResponse};
 
 mod service_account_token_projection;
 pub use self::service_account_token_projection::ServiceAccountTokenProjection;
+#[cfg(feature = "builder")]
+pub use self::service_account_token_projection::ServiceAccountTokenProjectionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::service_account_token_projection::ServiceAccountTokenProjectionApplyConfiguration;
+
+mod service_affinity;
+pub use self::service_affinity::ServiceAffinity;
+
+mod service_external_traffic_policy_type;
+pub use self::service_external_traffic_policy_type::ServiceExternalTrafficPolicyType;
 
 mod service_port;
 pub use self::service_port::ServicePort;
+#[cfg(feature = "builder")]
+pub use self::service_port::ServicePortBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::service_port::ServicePortApplyConfiguration;
 
 mod service_spec;
 pub use self::service_spec::ServiceSpec;
+#[cfg(feature = "builder")]
+pub use self::service_spec::ServiceSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::service_spec::ServiceSpecApplyConfiguration;
 
 mod service_status;
 pub use self::service_status::ServiceStatus;
+#[cfg(feature = "builder")]
+pub use self::service_status::ServiceStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::service_status::ServiceStatusApplyConfiguration;
+
+mod service_type;
+pub use self::service_type::ServiceType;
 
 mod session_affinity_config;
 pub use self::session_affinity_config::SessionAffinityConfig;
+#[cfg(feature = "builder")]
+pub use self::session_affinity_config::SessionAffinityConfigBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::session_affinity_config::SessionAffinityConfigApplyConfiguration;
 
 mod storage_os_persistent_volume_source;
 pub use self::storage_os_persistent_volume_source::StorageOSPersistentVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::storage_os_persistent_volume_source::StorageOSPersistentVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::storage_os_persistent_volume_source::StorageOSPersistentVolumeSourceApplyConfiguration;
 
 mod storage_os_volume_source;
 pub use self::storage_os_volume_source::StorageOSVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::storage_os_volume_source::StorageOSVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::storage_os_volume_source::StorageOSVolumeSourceApplyConfiguration;
 
 mod sysctl;
 pub use self::sysctl::Sysctl;
+#[cfg(feature = "builder")]
+pub use self::sysctl::SysctlBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::sysctl::SysctlApplyConfiguration;
 
 mod tcp_socket_action;
 pub use self::tcp_socket_action::TCPSocketAction;
+#[cfg(feature = "builder")]
+pub use self::tcp_socket_action::TCPSocketActionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::tcp_socket_action::TCPSocketActionApplyConfiguration;
 
 mod taint;
 pub use self::taint::Taint;
+#[cfg(feature = "builder")]
+pub use self::taint::TaintBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::taint::TaintApplyConfiguration;
+
+mod taint_effect;
+pub use self::taint_effect::TaintEffect;
+
+mod termination_message_policy;
+pub use self::termination_message_policy::TerminationMessagePolicy;
 
 mod toleration;
 pub use self::toleration::Toleration;
+#[cfg(feature = "builder")]
+pub use self::toleration::TolerationBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::toleration::TolerationApplyConfiguration;
+
+mod toleration_operator;
+pub use self::toleration_operator::TolerationOperator;
 
 mod topology_selector_label_requirement;
 pub use self::topology_selector_label_requirement::TopologySelectorLabelRequirement;
+#[cfg(feature = "builder")]
+pub use self::topology_selector_label_requirement::TopologySelectorLabelRequirementBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::topology_selector_label_requirement::TopologySelectorLabelRequirementApplyConfiguration;
 
 mod topology_selector_term;
 pub use self::topology_selector_term::TopologySelectorTerm;
+#[cfg(feature = "builder")]
+pub use self::topology_selector_term::TopologySelectorTermBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::topology_selector_term::TopologySelectorTermApplyConfiguration;
 
 mod volume;
 pub use self::volume::Volume;
+pub use self::volume::VolumeSource;
+#[cfg(feature = "builder")]
+pub use self::volume::VolumeBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::volume::VolumeApplyConfiguration;
 
 mod volume_device;
 pub use self::volume_device::VolumeDevice;
+#[cfg(feature = "builder")]
+pub use self::volume_device::VolumeDeviceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::volume_device::VolumeDeviceApplyConfiguration;
 
 mod volume_mount;
 pub use self::volume_mount::VolumeMount;
+#[cfg(feature = "builder")]
+pub use self::volume_mount::VolumeMountBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::volume_mount::VolumeMountApplyConfiguration;
 
 mod volume_node_affinity;
 pub use self::volume_node_affinity::VolumeNodeAffinity;
+#[cfg(feature = "builder")]
+pub use self::volume_node_affinity::VolumeNodeAffinityBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::volume_node_affinity::VolumeNodeAffinityApplyConfiguration;
 
 mod volume_projection;
 pub use self::volume_projection::VolumeProjection;
+pub use self::volume_projection::VolumeProjectionSource;
+#[cfg(feature = "builder")]
+pub use self::volume_projection::VolumeProjectionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::volume_projection::VolumeProjectionApplyConfiguration;
 
 mod vsphere_virtual_disk_volume_source;
 pub use self::vsphere_virtual_disk_volume_source::VsphereVirtualDiskVolumeSource;
+#[cfg(feature = "builder")]
+pub use self::vsphere_virtual_disk_volume_source::VsphereVirtualDiskVolumeSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::vsphere_virtual_disk_volume_source::VsphereVirtualDiskVolumeSourceApplyConfiguration;
 
 mod weighted_pod_affinity_term;
 pub use self::weighted_pod_affinity_term::WeightedPodAffinityTerm;
+#[cfg(feature = "builder")]
+pub use self::weighted_pod_affinity_term::WeightedPodAffinityTermBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::weighted_pod_affinity_term::WeightedPodAffinityTermApplyConfiguration;
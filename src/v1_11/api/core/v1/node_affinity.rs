@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.NodeAffinity
 
 /// Node affinity is a group of node affinity scheduling rules.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct NodeAffinity {
     /// The scheduler will prefer to schedule pods to nodes that satisfy the affinity expressions specified by this field, but it may choose a node that violates one or more of the expressions. The node that is most preferred is the one with the greatest sum of weights, i.e. for each node that meets all of the scheduling requirements (resource request, requiredDuringScheduling affinity expressions, etc.), compute a sum by iterating through the elements of this field and adding "weight" to the sum if the node matches the corresponding matchExpressions; the node(s) with the highest sum are the most preferred.
     pub preferred_during_scheduling_ignored_during_execution: Option<Vec<crate::api::core::v1::PreferredSchedulingTerm>>,
@@ -10,39 +10,33 @@ pub struct NodeAffinity {
     pub required_during_scheduling_ignored_during_execution: Option<crate::api::core::v1::NodeSelector>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for NodeAffinity {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_preferred_during_scheduling_ignored_during_execution,
-            Key_required_during_scheduling_ignored_during_execution,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl NodeAffinity {
+    /// Returns this value with [`NodeAffinity::preferred_during_scheduling_ignored_during_execution`] set to the given value.
+    #[must_use]
+    pub fn with_preferred_during_scheduling_ignored_during_execution(mut self, value: impl Into<Vec<crate::api::core::v1::PreferredSchedulingTerm>>) -> Self {
+        self.preferred_during_scheduling_ignored_during_execution = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`NodeAffinity::required_during_scheduling_ignored_during_execution`] set to the given value.
+    #[must_use]
+    pub fn with_required_during_scheduling_ignored_during_execution(mut self, value: impl Into<crate::api::core::v1::NodeSelector>) -> Self {
+        self.required_during_scheduling_ignored_during_execution = Some(value.into());
+        self
+    }
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for NodeAffinity {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "preferredDuringSchedulingIgnoredDuringExecution" => Field::Key_preferred_during_scheduling_ignored_during_execution,
-                            "requiredDuringSchedulingIgnoredDuringExecution" => Field::Key_required_during_scheduling_ignored_during_execution,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl NodeAffinity {
+    const FIELDS: &'static [&'static str] = &[
+        "preferredDuringSchedulingIgnoredDuringExecution",
+        "requiredDuringSchedulingIgnoredDuringExecution",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for NodeAffinity {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,11 +50,11 @@ impl<'de> crate::serde::Deserialize<'de> for NodeAffinity {
                 let mut value_preferred_during_scheduling_ignored_during_execution: Option<Vec<crate::api::core::v1::PreferredSchedulingTerm>> = None;
                 let mut value_required_during_scheduling_ignored_during_execution: Option<crate::api::core::v1::NodeSelector> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NodeAffinity", NodeAffinity::FIELDS))? {
                     match key {
-                        Field::Key_preferred_during_scheduling_ignored_during_execution => value_preferred_during_scheduling_ignored_during_execution = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_required_during_scheduling_ignored_during_execution => value_required_during_scheduling_ignored_during_execution = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_preferred_during_scheduling_ignored_during_execution = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_required_during_scheduling_ignored_during_execution = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -69,21 +63,78 @@ impl<'de> crate::serde::Deserialize<'de> for NodeAffinity {
                     required_during_scheduling_ignored_during_execution: value_required_during_scheduling_ignored_during_execution,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(NodeAffinity {
+                    preferred_during_scheduling_ignored_during_execution: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    required_during_scheduling_ignored_during_execution: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "NodeAffinity",
-            &[
-                "preferredDuringSchedulingIgnoredDuringExecution",
-                "requiredDuringSchedulingIgnoredDuringExecution",
-            ],
+            NodeAffinity::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, NodeAffinity> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("NodeAffinity")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_preferred_during_scheduling_ignored_during_execution = false;
+                let mut has_required_during_scheduling_ignored_during_execution = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NodeAffinity", NodeAffinity::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.preferred_during_scheduling_ignored_during_execution))?; has_preferred_during_scheduling_ignored_during_execution = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.required_during_scheduling_ignored_during_execution))?; has_required_during_scheduling_ignored_during_execution = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_preferred_during_scheduling_ignored_during_execution {
+                    self.0.preferred_during_scheduling_ignored_during_execution = None;
+                }
+                if !has_required_during_scheduling_ignored_during_execution {
+                    self.0.required_during_scheduling_ignored_during_execution = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.preferred_during_scheduling_ignored_during_execution))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.required_during_scheduling_ignored_during_execution))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "NodeAffinity",
+            NodeAffinity::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for NodeAffinity {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("NodeAffinity", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "preferredDuringSchedulingIgnoredDuringExecution", &self.preferred_during_scheduling_ignored_during_execution)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "requiredDuringSchedulingIgnoredDuringExecution", &self.required_during_scheduling_ignored_during_execution)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "NodeAffinity",
             self.preferred_during_scheduling_ignored_during_execution.as_ref().map_or(0, |_| 1) +
@@ -99,10 +150,84 @@ impl crate::serde::Serialize for NodeAffinity {
     }
 }
 
+/// A builder of [`NodeAffinity`]. Created with [`NodeAffinity::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct NodeAffinityBuilder {
+    preferred_during_scheduling_ignored_during_execution: Option<Vec<crate::api::core::v1::PreferredSchedulingTerm>>,
+    required_during_scheduling_ignored_during_execution: Option<crate::api::core::v1::NodeSelector>,
+}
+
+#[cfg(feature = "builder")]
+impl NodeAffinity {
+    /// Starts building a `NodeAffinity`. Its required fields must be set before [`NodeAffinityBuilder::build`] is called.
+    pub fn builder() -> NodeAffinityBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl NodeAffinityBuilder {
+    /// Sets [`NodeAffinity::preferred_during_scheduling_ignored_during_execution`].
+    pub fn preferred_during_scheduling_ignored_during_execution(mut self, value: impl Into<Vec<crate::api::core::v1::PreferredSchedulingTerm>>) -> Self {
+        self.preferred_during_scheduling_ignored_during_execution = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeAffinity::required_during_scheduling_ignored_during_execution`].
+    pub fn required_during_scheduling_ignored_during_execution(mut self, value: impl Into<crate::api::core::v1::NodeSelector>) -> Self {
+        self.required_during_scheduling_ignored_during_execution = Some(value.into());
+        self
+    }
+
+    /// Builds the `NodeAffinity`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<NodeAffinity, crate::MissingFieldError> {
+        Ok(NodeAffinity {
+            preferred_during_scheduling_ignored_during_execution: self.preferred_during_scheduling_ignored_during_execution,
+            required_during_scheduling_ignored_during_execution: self.required_during_scheduling_ignored_during_execution,
+        })
+    }
+}
+
+/// The apply configuration of [`NodeAffinity`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NodeAffinityApplyConfiguration {
+    /// See [`NodeAffinity::preferred_during_scheduling_ignored_during_execution`].
+    pub preferred_during_scheduling_ignored_during_execution: Option<Vec<crate::api::core::v1::PreferredSchedulingTermApplyConfiguration>>,
+
+    /// See [`NodeAffinity::required_during_scheduling_ignored_during_execution`].
+    pub required_during_scheduling_ignored_during_execution: Option<crate::api::core::v1::NodeSelectorApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for NodeAffinityApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "NodeAffinityApplyConfiguration",
+            self.preferred_during_scheduling_ignored_during_execution.as_ref().map_or(0, |_| 1) +
+            self.required_during_scheduling_ignored_during_execution.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.preferred_during_scheduling_ignored_during_execution {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "preferredDuringSchedulingIgnoredDuringExecution", value)?;
+        }
+        if let Some(value) = &self.required_during_scheduling_ignored_during_execution {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "requiredDuringSchedulingIgnoredDuringExecution", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NodeAffinity {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.NodeAffinity".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::NodeAffinity".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.NodeAffinity".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -113,8 +238,9 @@ impl crate::schemars::JsonSchema for NodeAffinity {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "preferredDuringSchedulingIgnoredDuringExecution".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -128,8 +254,8 @@ impl crate::schemars::JsonSchema for NodeAffinity {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "requiredDuringSchedulingIgnoredDuringExecution".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::NodeSelector>().into_object();
@@ -139,11 +265,22 @@ impl crate::schemars::JsonSchema for NodeAffinity {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for NodeAffinity {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(NodeAffinity {
+            preferred_during_scheduling_ignored_during_execution: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            required_during_scheduling_ignored_during_execution: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.NodeConfigStatus
 
 /// NodeConfigStatus describes the status of the config assigned by Node.Spec.ConfigSource.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct NodeConfigStatus {
     /// Active reports the checkpointed config the node is actively using. Active will represent either the current version of the Assigned config, or the current LastKnownGood config, depending on whether attempting to use the Assigned config results in an error.
     pub active: Option<crate::api::core::v1::NodeConfigSource>,
@@ -16,43 +16,49 @@ pub struct NodeConfigStatus {
     pub last_known_good: Option<crate::api::core::v1::NodeConfigSource>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for NodeConfigStatus {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_active,
-            Key_assigned,
-            Key_error,
-            Key_last_known_good,
-            Other,
-        }
+impl NodeConfigStatus {
+    /// Returns this value with [`NodeConfigStatus::active`] set to the given value.
+    #[must_use]
+    pub fn with_active(mut self, value: impl Into<crate::api::core::v1::NodeConfigSource>) -> Self {
+        self.active = Some(value.into());
+        self
+    }
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+    /// Returns this value with [`NodeConfigStatus::assigned`] set to the given value.
+    #[must_use]
+    pub fn with_assigned(mut self, value: impl Into<crate::api::core::v1::NodeConfigSource>) -> Self {
+        self.assigned = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`NodeConfigStatus::error`] set to the given value.
+    #[must_use]
+    pub fn with_error(mut self, value: impl Into<String>) -> Self {
+        self.error = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`NodeConfigStatus::last_known_good`] set to the given value.
+    #[must_use]
+    pub fn with_last_known_good(mut self, value: impl Into<crate::api::core::v1::NodeConfigSource>) -> Self {
+        self.last_known_good = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "active" => Field::Key_active,
-                            "assigned" => Field::Key_assigned,
-                            "error" => Field::Key_error,
-                            "lastKnownGood" => Field::Key_last_known_good,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for NodeConfigStatus {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl NodeConfigStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "active",
+        "assigned",
+        "error",
+        "lastKnownGood",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for NodeConfigStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -68,13 +74,13 @@ impl<'de> crate::serde::Deserialize<'de> for NodeConfigStatus {
                 let mut value_error: Option<String> = None;
                 let mut value_last_known_good: Option<crate::api::core::v1::NodeConfigSource> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NodeConfigStatus", NodeConfigStatus::FIELDS))? {
                     match key {
-                        Field::Key_active => value_active = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_assigned => value_assigned = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_error => value_error = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_last_known_good => value_last_known_good = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_active = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_assigned = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_error = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_last_known_good = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -85,23 +91,94 @@ impl<'de> crate::serde::Deserialize<'de> for NodeConfigStatus {
                     last_known_good: value_last_known_good,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(NodeConfigStatus {
+                    active: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    assigned: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    error: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    last_known_good: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "NodeConfigStatus",
-            &[
-                "active",
-                "assigned",
-                "error",
-                "lastKnownGood",
-            ],
+            NodeConfigStatus::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, NodeConfigStatus> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("NodeConfigStatus")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_active = false;
+                let mut has_assigned = false;
+                let mut has_error = false;
+                let mut has_last_known_good = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NodeConfigStatus", NodeConfigStatus::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.active))?; has_active = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.assigned))?; has_assigned = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.error))?; has_error = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.last_known_good))?; has_last_known_good = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_active {
+                    self.0.active = None;
+                }
+                if !has_assigned {
+                    self.0.assigned = None;
+                }
+                if !has_error {
+                    self.0.error = None;
+                }
+                if !has_last_known_good {
+                    self.0.last_known_good = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.active))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.assigned))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.error))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.last_known_good))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "NodeConfigStatus",
+            NodeConfigStatus::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for NodeConfigStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("NodeConfigStatus", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "active", &self.active)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "assigned", &self.assigned)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "error", &self.error)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "lastKnownGood", &self.last_known_good)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "NodeConfigStatus",
             self.active.as_ref().map_or(0, |_| 1) +
@@ -125,10 +202,114 @@ impl crate::serde::Serialize for NodeConfigStatus {
     }
 }
 
+/// A builder of [`NodeConfigStatus`]. Created with [`NodeConfigStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct NodeConfigStatusBuilder {
+    active: Option<crate::api::core::v1::NodeConfigSource>,
+    assigned: Option<crate::api::core::v1::NodeConfigSource>,
+    error: Option<String>,
+    last_known_good: Option<crate::api::core::v1::NodeConfigSource>,
+}
+
+#[cfg(feature = "builder")]
+impl NodeConfigStatus {
+    /// Starts building a `NodeConfigStatus`. Its required fields must be set before [`NodeConfigStatusBuilder::build`] is called.
+    pub fn builder() -> NodeConfigStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl NodeConfigStatusBuilder {
+    /// Sets [`NodeConfigStatus::active`].
+    pub fn active(mut self, value: impl Into<crate::api::core::v1::NodeConfigSource>) -> Self {
+        self.active = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeConfigStatus::assigned`].
+    pub fn assigned(mut self, value: impl Into<crate::api::core::v1::NodeConfigSource>) -> Self {
+        self.assigned = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeConfigStatus::error`].
+    pub fn error(mut self, value: impl Into<String>) -> Self {
+        self.error = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeConfigStatus::last_known_good`].
+    pub fn last_known_good(mut self, value: impl Into<crate::api::core::v1::NodeConfigSource>) -> Self {
+        self.last_known_good = Some(value.into());
+        self
+    }
+
+    /// Builds the `NodeConfigStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<NodeConfigStatus, crate::MissingFieldError> {
+        Ok(NodeConfigStatus {
+            active: self.active,
+            assigned: self.assigned,
+            error: self.error,
+            last_known_good: self.last_known_good,
+        })
+    }
+}
+
+/// The apply configuration of [`NodeConfigStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NodeConfigStatusApplyConfiguration {
+    /// See [`NodeConfigStatus::active`].
+    pub active: Option<crate::api::core::v1::NodeConfigSourceApplyConfiguration>,
+
+    /// See [`NodeConfigStatus::assigned`].
+    pub assigned: Option<crate::api::core::v1::NodeConfigSourceApplyConfiguration>,
+
+    /// See [`NodeConfigStatus::error`].
+    pub error: Option<String>,
+
+    /// See [`NodeConfigStatus::last_known_good`].
+    pub last_known_good: Option<crate::api::core::v1::NodeConfigSourceApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for NodeConfigStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "NodeConfigStatusApplyConfiguration",
+            self.active.as_ref().map_or(0, |_| 1) +
+            self.assigned.as_ref().map_or(0, |_| 1) +
+            self.error.as_ref().map_or(0, |_| 1) +
+            self.last_known_good.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.active {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "active", value)?;
+        }
+        if let Some(value) = &self.assigned {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "assigned", value)?;
+        }
+        if let Some(value) = &self.error {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "error", value)?;
+        }
+        if let Some(value) = &self.last_known_good {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "lastKnownGood", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NodeConfigStatus {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.NodeConfigStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::NodeConfigStatus".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.NodeConfigStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -139,8 +320,9 @@ impl crate::schemars::JsonSchema for NodeConfigStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "active".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::NodeConfigSource>().into_object();
@@ -150,8 +332,8 @@ impl crate::schemars::JsonSchema for NodeConfigStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "assigned".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::NodeConfigSource>().into_object();
@@ -161,8 +343,8 @@ impl crate::schemars::JsonSchema for NodeConfigStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "error".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -172,8 +354,8 @@ impl crate::schemars::JsonSchema for NodeConfigStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "lastKnownGood".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::NodeConfigSource>().into_object();
@@ -183,11 +365,24 @@ impl crate::schemars::JsonSchema for NodeConfigStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for NodeConfigStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(NodeConfigStatus {
+            active: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            assigned: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            error: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            last_known_good: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.PodAffinityTerm
 
 /// Defines a set of pods (namely those matching the labelSelector relative to the given namespace(s)) that this pod should be co-located (affinity) or not co-located (anti-affinity) with, where co-located is defined as running on a node whose value of the label with key \<topologyKey\> matches that of any node on which a pod of the set of pods is running
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct PodAffinityTerm {
     /// A label query over a set of resources, in this case pods.
     pub label_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>,
@@ -13,41 +13,52 @@ pub struct PodAffinityTerm {
     pub topology_key: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for PodAffinityTerm {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_label_selector,
-            Key_namespaces,
-            Key_topology_key,
-            Other,
+impl PodAffinityTerm {
+    /// Constructs a `PodAffinityTerm` from its required fields. Its optional fields are unset.
+    pub fn new(topology_key: impl Into<String>) -> Self {
+        PodAffinityTerm {
+            label_selector: None,
+            namespaces: None,
+            topology_key: topology_key.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl PodAffinityTerm {
+    /// Returns this value with [`PodAffinityTerm::label_selector`] set to the given value.
+    #[must_use]
+    pub fn with_label_selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.label_selector = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`PodAffinityTerm::namespaces`] set to the given value.
+    #[must_use]
+    pub fn with_namespaces(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.namespaces = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`PodAffinityTerm::topology_key`] set to the given value.
+    #[must_use]
+    pub fn with_topology_key(mut self, value: impl Into<String>) -> Self {
+        self.topology_key = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "labelSelector" => Field::Key_label_selector,
-                            "namespaces" => Field::Key_namespaces,
-                            "topologyKey" => Field::Key_topology_key,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for PodAffinityTerm {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl PodAffinityTerm {
+    const FIELDS: &'static [&'static str] = &[
+        "labelSelector",
+        "namespaces",
+        "topologyKey",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for PodAffinityTerm {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,37 +73,101 @@ impl<'de> crate::serde::Deserialize<'de> for PodAffinityTerm {
                 let mut value_namespaces: Option<Vec<String>> = None;
                 let mut value_topology_key: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodAffinityTerm", PodAffinityTerm::FIELDS))? {
                     match key {
-                        Field::Key_label_selector => value_label_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_namespaces => value_namespaces = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_topology_key => value_topology_key = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_label_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_namespaces = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_topology_key = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(PodAffinityTerm {
                     label_selector: value_label_selector,
                     namespaces: value_namespaces,
-                    topology_key: value_topology_key.ok_or_else(|| crate::serde::de::Error::missing_field("topologyKey"))?,
+                    topology_key: value_topology_key.ok_or_else(|| crate::serde2::missing_field("PodAffinityTerm", "topologyKey", &["topologyKey"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(PodAffinityTerm {
+                    label_selector: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    namespaces: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    topology_key: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "PodAffinityTerm",
-            &[
-                "labelSelector",
-                "namespaces",
-                "topologyKey",
-            ],
+            PodAffinityTerm::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, PodAffinityTerm> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("PodAffinityTerm")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_label_selector = false;
+                let mut has_namespaces = false;
+                let mut has_topology_key = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodAffinityTerm", PodAffinityTerm::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.label_selector))?; has_label_selector = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.namespaces))?; has_namespaces = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.topology_key))?; has_topology_key = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_label_selector {
+                    self.0.label_selector = None;
+                }
+                if !has_namespaces {
+                    self.0.namespaces = None;
+                }
+                if !has_topology_key {
+                    return Err(crate::serde2::missing_field("PodAffinityTerm", "topologyKey", &["topologyKey"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.label_selector))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.namespaces))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.topology_key))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "PodAffinityTerm",
+            PodAffinityTerm::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for PodAffinityTerm {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("PodAffinityTerm", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "labelSelector", &self.label_selector)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "namespaces", &self.namespaces)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "topologyKey", &self.topology_key)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "PodAffinityTerm",
             1 +
@@ -110,10 +185,99 @@ impl crate::serde::Serialize for PodAffinityTerm {
     }
 }
 
+/// A builder of [`PodAffinityTerm`]. Created with [`PodAffinityTerm::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct PodAffinityTermBuilder {
+    label_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>,
+    namespaces: Option<Vec<String>>,
+    topology_key: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl PodAffinityTerm {
+    /// Starts building a `PodAffinityTerm`. Its required fields must be set before [`PodAffinityTermBuilder::build`] is called.
+    pub fn builder() -> PodAffinityTermBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl PodAffinityTermBuilder {
+    /// Sets [`PodAffinityTerm::label_selector`].
+    pub fn label_selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.label_selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodAffinityTerm::namespaces`].
+    pub fn namespaces(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.namespaces = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodAffinityTerm::topology_key`].
+    pub fn topology_key(mut self, value: impl Into<String>) -> Self {
+        self.topology_key = Some(value.into());
+        self
+    }
+
+    /// Builds the `PodAffinityTerm`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<PodAffinityTerm, crate::MissingFieldError> {
+        Ok(PodAffinityTerm {
+            label_selector: self.label_selector,
+            namespaces: self.namespaces,
+            topology_key: self.topology_key.ok_or_else(|| crate::MissingFieldError::new("PodAffinityTerm", "topologyKey"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`PodAffinityTerm`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodAffinityTermApplyConfiguration {
+    /// See [`PodAffinityTerm::label_selector`].
+    pub label_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorApplyConfiguration>,
+
+    /// See [`PodAffinityTerm::namespaces`].
+    pub namespaces: Option<Vec<String>>,
+
+    /// See [`PodAffinityTerm::topology_key`].
+    pub topology_key: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for PodAffinityTermApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "PodAffinityTermApplyConfiguration",
+            self.label_selector.as_ref().map_or(0, |_| 1) +
+            self.namespaces.as_ref().map_or(0, |_| 1) +
+            self.topology_key.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.label_selector {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "labelSelector", value)?;
+        }
+        if let Some(value) = &self.namespaces {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "namespaces", value)?;
+        }
+        if let Some(value) = &self.topology_key {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "topologyKey", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodAffinityTerm {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.PodAffinityTerm".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::PodAffinityTerm".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.PodAffinityTerm".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -124,8 +288,9 @@ impl crate::schemars::JsonSchema for PodAffinityTerm {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "labelSelector".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>().into_object();
@@ -135,8 +300,8 @@ impl crate::schemars::JsonSchema for PodAffinityTerm {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "namespaces".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -155,8 +320,8 @@ impl crate::schemars::JsonSchema for PodAffinityTerm {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "topologyKey".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -166,8 +331,9 @@ impl crate::schemars::JsonSchema for PodAffinityTerm {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "topologyKey",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -177,3 +343,14 @@ impl crate::schemars::JsonSchema for PodAffinityTerm {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for PodAffinityTerm {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(PodAffinityTerm {
+            label_selector: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            namespaces: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            topology_key: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
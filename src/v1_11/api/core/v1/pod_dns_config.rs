@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.PodDNSConfig
 
 /// PodDNSConfig defines the DNS parameters of a pod in addition to those generated from DNSPolicy.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct PodDNSConfig {
     /// A list of DNS name server IP addresses. This will be appended to the base nameservers generated from DNSPolicy. Duplicated nameservers will be removed.
     pub nameservers: Option<Vec<String>>,
@@ -13,41 +13,41 @@ pub struct PodDNSConfig {
     pub searches: Option<Vec<String>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for PodDNSConfig {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_nameservers,
-            Key_options,
-            Key_searches,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl PodDNSConfig {
+    /// Returns this value with [`PodDNSConfig::nameservers`] set to the given value.
+    #[must_use]
+    pub fn with_nameservers(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.nameservers = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`PodDNSConfig::options`] set to the given value.
+    #[must_use]
+    pub fn with_options(mut self, value: impl Into<Vec<crate::api::core::v1::PodDNSConfigOption>>) -> Self {
+        self.options = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`PodDNSConfig::searches`] set to the given value.
+    #[must_use]
+    pub fn with_searches(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.searches = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "nameservers" => Field::Key_nameservers,
-                            "options" => Field::Key_options,
-                            "searches" => Field::Key_searches,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for PodDNSConfig {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl PodDNSConfig {
+    const FIELDS: &'static [&'static str] = &[
+        "nameservers",
+        "options",
+        "searches",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for PodDNSConfig {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,12 +62,12 @@ impl<'de> crate::serde::Deserialize<'de> for PodDNSConfig {
                 let mut value_options: Option<Vec<crate::api::core::v1::PodDNSConfigOption>> = None;
                 let mut value_searches: Option<Vec<String>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodDNSConfig", PodDNSConfig::FIELDS))? {
                     match key {
-                        Field::Key_nameservers => value_nameservers = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_options => value_options = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_searches => value_searches = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_nameservers = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_options = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_searches = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -77,22 +77,86 @@ impl<'de> crate::serde::Deserialize<'de> for PodDNSConfig {
                     searches: value_searches,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(PodDNSConfig {
+                    nameservers: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    options: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    searches: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "PodDNSConfig",
-            &[
-                "nameservers",
-                "options",
-                "searches",
-            ],
+            PodDNSConfig::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, PodDNSConfig> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("PodDNSConfig")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_nameservers = false;
+                let mut has_options = false;
+                let mut has_searches = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodDNSConfig", PodDNSConfig::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.nameservers))?; has_nameservers = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.options))?; has_options = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.searches))?; has_searches = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_nameservers {
+                    self.0.nameservers = None;
+                }
+                if !has_options {
+                    self.0.options = None;
+                }
+                if !has_searches {
+                    self.0.searches = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.nameservers))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.options))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.searches))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "PodDNSConfig",
+            PodDNSConfig::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for PodDNSConfig {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("PodDNSConfig", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nameservers", &self.nameservers)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "options", &self.options)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "searches", &self.searches)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "PodDNSConfig",
             self.nameservers.as_ref().map_or(0, |_| 1) +
@@ -112,10 +176,99 @@ impl crate::serde::Serialize for PodDNSConfig {
     }
 }
 
+/// A builder of [`PodDNSConfig`]. Created with [`PodDNSConfig::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct PodDNSConfigBuilder {
+    nameservers: Option<Vec<String>>,
+    options: Option<Vec<crate::api::core::v1::PodDNSConfigOption>>,
+    searches: Option<Vec<String>>,
+}
+
+#[cfg(feature = "builder")]
+impl PodDNSConfig {
+    /// Starts building a `PodDNSConfig`. Its required fields must be set before [`PodDNSConfigBuilder::build`] is called.
+    pub fn builder() -> PodDNSConfigBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl PodDNSConfigBuilder {
+    /// Sets [`PodDNSConfig::nameservers`].
+    pub fn nameservers(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.nameservers = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodDNSConfig::options`].
+    pub fn options(mut self, value: impl Into<Vec<crate::api::core::v1::PodDNSConfigOption>>) -> Self {
+        self.options = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodDNSConfig::searches`].
+    pub fn searches(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.searches = Some(value.into());
+        self
+    }
+
+    /// Builds the `PodDNSConfig`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<PodDNSConfig, crate::MissingFieldError> {
+        Ok(PodDNSConfig {
+            nameservers: self.nameservers,
+            options: self.options,
+            searches: self.searches,
+        })
+    }
+}
+
+/// The apply configuration of [`PodDNSConfig`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodDNSConfigApplyConfiguration {
+    /// See [`PodDNSConfig::nameservers`].
+    pub nameservers: Option<Vec<String>>,
+
+    /// See [`PodDNSConfig::options`].
+    pub options: Option<Vec<crate::api::core::v1::PodDNSConfigOptionApplyConfiguration>>,
+
+    /// See [`PodDNSConfig::searches`].
+    pub searches: Option<Vec<String>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for PodDNSConfigApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "PodDNSConfigApplyConfiguration",
+            self.nameservers.as_ref().map_or(0, |_| 1) +
+            self.options.as_ref().map_or(0, |_| 1) +
+            self.searches.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.nameservers {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nameservers", value)?;
+        }
+        if let Some(value) = &self.options {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "options", value)?;
+        }
+        if let Some(value) = &self.searches {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "searches", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodDNSConfig {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.PodDNSConfig".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::PodDNSConfig".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.PodDNSConfig".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -126,8 +279,9 @@ impl crate::schemars::JsonSchema for PodDNSConfig {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "nameservers".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -146,8 +300,8 @@ impl crate::schemars::JsonSchema for PodDNSConfig {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "options".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -161,8 +315,8 @@ impl crate::schemars::JsonSchema for PodDNSConfig {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "searches".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -181,11 +335,23 @@ impl crate::schemars::JsonSchema for PodDNSConfig {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for PodDNSConfig {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(PodDNSConfig {
+            nameservers: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            options: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            searches: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
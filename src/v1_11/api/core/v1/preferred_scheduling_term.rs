@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.PreferredSchedulingTerm
 
 /// An empty preferred scheduling term matches all objects with implicit weight 0 (i.e. it's a no-op). A null preferred scheduling term matches no objects (i.e. is also a no-op).
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct PreferredSchedulingTerm {
     /// A node selector term, associated with the corresponding weight.
     pub preference: crate::api::core::v1::NodeSelectorTerm,
@@ -10,39 +10,43 @@ pub struct PreferredSchedulingTerm {
     pub weight: i32,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for PreferredSchedulingTerm {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_preference,
-            Key_weight,
-            Other,
+impl PreferredSchedulingTerm {
+    /// Constructs a `PreferredSchedulingTerm` from its required fields. Its optional fields are unset.
+    pub fn new(preference: impl Into<crate::api::core::v1::NodeSelectorTerm>, weight: impl Into<i32>) -> Self {
+        PreferredSchedulingTerm {
+            preference: preference.into(),
+            weight: weight.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl PreferredSchedulingTerm {
+    /// Returns this value with [`PreferredSchedulingTerm::preference`] set to the given value.
+    #[must_use]
+    pub fn with_preference(mut self, value: impl Into<crate::api::core::v1::NodeSelectorTerm>) -> Self {
+        self.preference = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`PreferredSchedulingTerm::weight`] set to the given value.
+    #[must_use]
+    pub fn with_weight(mut self, value: impl Into<i32>) -> Self {
+        self.weight = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "preference" => Field::Key_preference,
-                            "weight" => Field::Key_weight,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for PreferredSchedulingTerm {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl PreferredSchedulingTerm {
+    const FIELDS: &'static [&'static str] = &[
+        "preference",
+        "weight",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for PreferredSchedulingTerm {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +60,91 @@ impl<'de> crate::serde::Deserialize<'de> for PreferredSchedulingTerm {
                 let mut value_preference: Option<crate::api::core::v1::NodeSelectorTerm> = None;
                 let mut value_weight: Option<i32> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PreferredSchedulingTerm", PreferredSchedulingTerm::FIELDS))? {
                     match key {
-                        Field::Key_preference => value_preference = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_weight => value_weight = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_preference = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_weight = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(PreferredSchedulingTerm {
-                    preference: value_preference.ok_or_else(|| crate::serde::de::Error::missing_field("preference"))?,
-                    weight: value_weight.ok_or_else(|| crate::serde::de::Error::missing_field("weight"))?,
+                    preference: value_preference.ok_or_else(|| crate::serde2::missing_field("PreferredSchedulingTerm", "preference", &["preference", "weight"]))?,
+                    weight: value_weight.ok_or_else(|| crate::serde2::missing_field("PreferredSchedulingTerm", "weight", &["preference", "weight"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(PreferredSchedulingTerm {
+                    preference: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    weight: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "PreferredSchedulingTerm",
-            &[
-                "preference",
-                "weight",
-            ],
+            PreferredSchedulingTerm::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, PreferredSchedulingTerm> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("PreferredSchedulingTerm")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_preference = false;
+                let mut has_weight = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PreferredSchedulingTerm", PreferredSchedulingTerm::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.preference))?; has_preference = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.weight))?; has_weight = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_preference {
+                    return Err(crate::serde2::missing_field("PreferredSchedulingTerm", "preference", &["preference", "weight"]));
+                }
+                if !has_weight {
+                    return Err(crate::serde2::missing_field("PreferredSchedulingTerm", "weight", &["preference", "weight"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.preference))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.weight))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "PreferredSchedulingTerm",
+            PreferredSchedulingTerm::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for PreferredSchedulingTerm {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("PreferredSchedulingTerm", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "preference", &self.preference)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "weight", &self.weight)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "PreferredSchedulingTerm",
             2,
@@ -94,10 +155,84 @@ impl crate::serde::Serialize for PreferredSchedulingTerm {
     }
 }
 
+/// A builder of [`PreferredSchedulingTerm`]. Created with [`PreferredSchedulingTerm::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct PreferredSchedulingTermBuilder {
+    preference: Option<crate::api::core::v1::NodeSelectorTerm>,
+    weight: Option<i32>,
+}
+
+#[cfg(feature = "builder")]
+impl PreferredSchedulingTerm {
+    /// Starts building a `PreferredSchedulingTerm`. Its required fields must be set before [`PreferredSchedulingTermBuilder::build`] is called.
+    pub fn builder() -> PreferredSchedulingTermBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl PreferredSchedulingTermBuilder {
+    /// Sets [`PreferredSchedulingTerm::preference`].
+    pub fn preference(mut self, value: impl Into<crate::api::core::v1::NodeSelectorTerm>) -> Self {
+        self.preference = Some(value.into());
+        self
+    }
+
+    /// Sets [`PreferredSchedulingTerm::weight`].
+    pub fn weight(mut self, value: impl Into<i32>) -> Self {
+        self.weight = Some(value.into());
+        self
+    }
+
+    /// Builds the `PreferredSchedulingTerm`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<PreferredSchedulingTerm, crate::MissingFieldError> {
+        Ok(PreferredSchedulingTerm {
+            preference: self.preference.ok_or_else(|| crate::MissingFieldError::new("PreferredSchedulingTerm", "preference"))?,
+            weight: self.weight.ok_or_else(|| crate::MissingFieldError::new("PreferredSchedulingTerm", "weight"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`PreferredSchedulingTerm`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PreferredSchedulingTermApplyConfiguration {
+    /// See [`PreferredSchedulingTerm::preference`].
+    pub preference: Option<crate::api::core::v1::NodeSelectorTermApplyConfiguration>,
+
+    /// See [`PreferredSchedulingTerm::weight`].
+    pub weight: Option<i32>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for PreferredSchedulingTermApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "PreferredSchedulingTermApplyConfiguration",
+            self.preference.as_ref().map_or(0, |_| 1) +
+            self.weight.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.preference {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "preference", value)?;
+        }
+        if let Some(value) = &self.weight {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "weight", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PreferredSchedulingTerm {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.PreferredSchedulingTerm".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::PreferredSchedulingTerm".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.PreferredSchedulingTerm".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -108,8 +243,9 @@ impl crate::schemars::JsonSchema for PreferredSchedulingTerm {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "preference".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::NodeSelectorTerm>().into_object();
@@ -119,8 +255,8 @@ impl crate::schemars::JsonSchema for PreferredSchedulingTerm {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "weight".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -131,8 +267,9 @@ impl crate::schemars::JsonSchema for PreferredSchedulingTerm {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "preference",
                     "weight",
@@ -143,3 +280,13 @@ impl crate::schemars::JsonSchema for PreferredSchedulingTerm {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for PreferredSchedulingTerm {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(PreferredSchedulingTerm {
+            preference: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            weight: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.QuobyteVolumeSource
 
 /// Represents a Quobyte mount that lasts the lifetime of a pod. Quobyte volumes do not support ownership management or SELinux relabeling.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct QuobyteVolumeSource {
     /// Group to map volume access to Default is no group
     pub group: Option<String>,
@@ -19,45 +19,70 @@ pub struct QuobyteVolumeSource {
     pub volume: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for QuobyteVolumeSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_group,
-            Key_read_only,
-            Key_registry,
-            Key_user,
-            Key_volume,
-            Other,
+impl QuobyteVolumeSource {
+    /// Constructs a `QuobyteVolumeSource` from its required fields. Its optional fields are unset.
+    pub fn new(registry: impl Into<String>, volume: impl Into<String>) -> Self {
+        QuobyteVolumeSource {
+            group: None,
+            read_only: None,
+            registry: registry.into(),
+            user: None,
+            volume: volume.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl QuobyteVolumeSource {
+    /// Returns this value with [`QuobyteVolumeSource::group`] set to the given value.
+    #[must_use]
+    pub fn with_group(mut self, value: impl Into<String>) -> Self {
+        self.group = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`QuobyteVolumeSource::read_only`] set to the given value.
+    #[must_use]
+    pub fn with_read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`QuobyteVolumeSource::registry`] set to the given value.
+    #[must_use]
+    pub fn with_registry(mut self, value: impl Into<String>) -> Self {
+        self.registry = value.into();
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "group" => Field::Key_group,
-                            "readOnly" => Field::Key_read_only,
-                            "registry" => Field::Key_registry,
-                            "user" => Field::Key_user,
-                            "volume" => Field::Key_volume,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`QuobyteVolumeSource::user`] set to the given value.
+    #[must_use]
+    pub fn with_user(mut self, value: impl Into<String>) -> Self {
+        self.user = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`QuobyteVolumeSource::volume`] set to the given value.
+    #[must_use]
+    pub fn with_volume(mut self, value: impl Into<String>) -> Self {
+        self.volume = value.into();
+        self
+    }
+}
 
+impl crate::MergeStrategyInfo for QuobyteVolumeSource {}
+
+impl QuobyteVolumeSource {
+    const FIELDS: &'static [&'static str] = &[
+        "group",
+        "readOnly",
+        "registry",
+        "user",
+        "volume",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for QuobyteVolumeSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -74,43 +99,121 @@ impl<'de> crate::serde::Deserialize<'de> for QuobyteVolumeSource {
                 let mut value_user: Option<String> = None;
                 let mut value_volume: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("QuobyteVolumeSource", QuobyteVolumeSource::FIELDS))? {
                     match key {
-                        Field::Key_group => value_group = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_read_only => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_registry => value_registry = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_user => value_user = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_volume => value_volume = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_group = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_registry = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_user = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_volume = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(QuobyteVolumeSource {
                     group: value_group,
                     read_only: value_read_only,
-                    registry: value_registry.ok_or_else(|| crate::serde::de::Error::missing_field("registry"))?,
+                    registry: value_registry.ok_or_else(|| crate::serde2::missing_field("QuobyteVolumeSource", "registry", &["registry", "volume"]))?,
                     user: value_user,
-                    volume: value_volume.ok_or_else(|| crate::serde::de::Error::missing_field("volume"))?,
+                    volume: value_volume.ok_or_else(|| crate::serde2::missing_field("QuobyteVolumeSource", "volume", &["registry", "volume"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(QuobyteVolumeSource {
+                    group: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    read_only: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    registry: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    user: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    volume: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "QuobyteVolumeSource",
-            &[
-                "group",
-                "readOnly",
-                "registry",
-                "user",
-                "volume",
-            ],
+            QuobyteVolumeSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, QuobyteVolumeSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("QuobyteVolumeSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_group = false;
+                let mut has_read_only = false;
+                let mut has_registry = false;
+                let mut has_user = false;
+                let mut has_volume = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("QuobyteVolumeSource", QuobyteVolumeSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.group))?; has_group = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.read_only))?; has_read_only = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.registry))?; has_registry = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.user))?; has_user = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.volume))?; has_volume = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_group {
+                    self.0.group = None;
+                }
+                if !has_read_only {
+                    self.0.read_only = None;
+                }
+                if !has_registry {
+                    return Err(crate::serde2::missing_field("QuobyteVolumeSource", "registry", &["registry", "volume"]));
+                }
+                if !has_user {
+                    self.0.user = None;
+                }
+                if !has_volume {
+                    return Err(crate::serde2::missing_field("QuobyteVolumeSource", "volume", &["registry", "volume"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.group))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.read_only))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.registry))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.user))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.volume))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "QuobyteVolumeSource",
+            QuobyteVolumeSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for QuobyteVolumeSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("QuobyteVolumeSource", 5)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "group", &self.group)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", &self.read_only)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "registry", &self.registry)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "user", &self.user)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volume", &self.volume)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "QuobyteVolumeSource",
             2 +
@@ -133,10 +236,129 @@ impl crate::serde::Serialize for QuobyteVolumeSource {
     }
 }
 
+/// A builder of [`QuobyteVolumeSource`]. Created with [`QuobyteVolumeSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct QuobyteVolumeSourceBuilder {
+    group: Option<String>,
+    read_only: Option<bool>,
+    registry: Option<String>,
+    user: Option<String>,
+    volume: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl QuobyteVolumeSource {
+    /// Starts building a `QuobyteVolumeSource`. Its required fields must be set before [`QuobyteVolumeSourceBuilder::build`] is called.
+    pub fn builder() -> QuobyteVolumeSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl QuobyteVolumeSourceBuilder {
+    /// Sets [`QuobyteVolumeSource::group`].
+    pub fn group(mut self, value: impl Into<String>) -> Self {
+        self.group = Some(value.into());
+        self
+    }
+
+    /// Sets [`QuobyteVolumeSource::read_only`].
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`QuobyteVolumeSource::registry`].
+    pub fn registry(mut self, value: impl Into<String>) -> Self {
+        self.registry = Some(value.into());
+        self
+    }
+
+    /// Sets [`QuobyteVolumeSource::user`].
+    pub fn user(mut self, value: impl Into<String>) -> Self {
+        self.user = Some(value.into());
+        self
+    }
+
+    /// Sets [`QuobyteVolumeSource::volume`].
+    pub fn volume(mut self, value: impl Into<String>) -> Self {
+        self.volume = Some(value.into());
+        self
+    }
+
+    /// Builds the `QuobyteVolumeSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<QuobyteVolumeSource, crate::MissingFieldError> {
+        Ok(QuobyteVolumeSource {
+            group: self.group,
+            read_only: self.read_only,
+            registry: self.registry.ok_or_else(|| crate::MissingFieldError::new("QuobyteVolumeSource", "registry"))?,
+            user: self.user,
+            volume: self.volume.ok_or_else(|| crate::MissingFieldError::new("QuobyteVolumeSource", "volume"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`QuobyteVolumeSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct QuobyteVolumeSourceApplyConfiguration {
+    /// See [`QuobyteVolumeSource::group`].
+    pub group: Option<String>,
+
+    /// See [`QuobyteVolumeSource::read_only`].
+    pub read_only: Option<bool>,
+
+    /// See [`QuobyteVolumeSource::registry`].
+    pub registry: Option<String>,
+
+    /// See [`QuobyteVolumeSource::user`].
+    pub user: Option<String>,
+
+    /// See [`QuobyteVolumeSource::volume`].
+    pub volume: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for QuobyteVolumeSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "QuobyteVolumeSourceApplyConfiguration",
+            self.group.as_ref().map_or(0, |_| 1) +
+            self.read_only.as_ref().map_or(0, |_| 1) +
+            self.registry.as_ref().map_or(0, |_| 1) +
+            self.user.as_ref().map_or(0, |_| 1) +
+            self.volume.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.group {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "group", value)?;
+        }
+        if let Some(value) = &self.read_only {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", value)?;
+        }
+        if let Some(value) = &self.registry {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "registry", value)?;
+        }
+        if let Some(value) = &self.user {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "user", value)?;
+        }
+        if let Some(value) = &self.volume {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volume", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for QuobyteVolumeSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.QuobyteVolumeSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::QuobyteVolumeSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.QuobyteVolumeSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -147,8 +369,9 @@ impl crate::schemars::JsonSchema for QuobyteVolumeSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "group".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -158,8 +381,8 @@ impl crate::schemars::JsonSchema for QuobyteVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "readOnly".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -169,8 +392,8 @@ impl crate::schemars::JsonSchema for QuobyteVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "registry".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -180,8 +403,8 @@ impl crate::schemars::JsonSchema for QuobyteVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "user".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -191,8 +414,8 @@ impl crate::schemars::JsonSchema for QuobyteVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "volume".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -202,8 +425,9 @@ impl crate::schemars::JsonSchema for QuobyteVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "registry",
                     "volume",
@@ -214,3 +438,16 @@ impl crate::schemars::JsonSchema for QuobyteVolumeSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for QuobyteVolumeSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(QuobyteVolumeSource {
+            group: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            read_only: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            registry: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            user: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            volume: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
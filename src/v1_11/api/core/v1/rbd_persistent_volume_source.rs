@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.RBDPersistentVolumeSource
 
 /// Represents a Rados Block Device mount that lasts the lifetime of a pod. RBD volumes support ownership management and SELinux relabeling.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct RBDPersistentVolumeSource {
     /// Filesystem type of the volume that you want to mount. Tip: Ensure that the filesystem type is supported by the host operating system. Examples: "ext4", "xfs", "ntfs". Implicitly inferred to be "ext4" if unspecified. More info: https://kubernetes.io/docs/concepts/storage/volumes#rbd
     pub fs_type: Option<String>,
@@ -28,51 +28,97 @@ pub struct RBDPersistentVolumeSource {
     pub user: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for RBDPersistentVolumeSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_fs_type,
-            Key_image,
-            Key_keyring,
-            Key_monitors,
-            Key_pool,
-            Key_read_only,
-            Key_secret_ref,
-            Key_user,
-            Other,
+impl RBDPersistentVolumeSource {
+    /// Constructs a `RBDPersistentVolumeSource` from its required fields. Its optional fields are unset.
+    pub fn new(image: impl Into<String>, monitors: impl Into<Vec<String>>) -> Self {
+        RBDPersistentVolumeSource {
+            fs_type: None,
+            image: image.into(),
+            keyring: None,
+            monitors: monitors.into(),
+            pool: None,
+            read_only: None,
+            secret_ref: None,
+            user: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl RBDPersistentVolumeSource {
+    /// Returns this value with [`RBDPersistentVolumeSource::fs_type`] set to the given value.
+    #[must_use]
+    pub fn with_fs_type(mut self, value: impl Into<String>) -> Self {
+        self.fs_type = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`RBDPersistentVolumeSource::image`] set to the given value.
+    #[must_use]
+    pub fn with_image(mut self, value: impl Into<String>) -> Self {
+        self.image = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`RBDPersistentVolumeSource::keyring`] set to the given value.
+    #[must_use]
+    pub fn with_keyring(mut self, value: impl Into<String>) -> Self {
+        self.keyring = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "fsType" => Field::Key_fs_type,
-                            "image" => Field::Key_image,
-                            "keyring" => Field::Key_keyring,
-                            "monitors" => Field::Key_monitors,
-                            "pool" => Field::Key_pool,
-                            "readOnly" => Field::Key_read_only,
-                            "secretRef" => Field::Key_secret_ref,
-                            "user" => Field::Key_user,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`RBDPersistentVolumeSource::monitors`] set to the given value.
+    #[must_use]
+    pub fn with_monitors(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.monitors = value.into();
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`RBDPersistentVolumeSource::pool`] set to the given value.
+    #[must_use]
+    pub fn with_pool(mut self, value: impl Into<String>) -> Self {
+        self.pool = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`RBDPersistentVolumeSource::read_only`] set to the given value.
+    #[must_use]
+    pub fn with_read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`RBDPersistentVolumeSource::secret_ref`] set to the given value.
+    #[must_use]
+    pub fn with_secret_ref(mut self, value: impl Into<crate::api::core::v1::SecretReference>) -> Self {
+        self.secret_ref = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`RBDPersistentVolumeSource::user`] set to the given value.
+    #[must_use]
+    pub fn with_user(mut self, value: impl Into<String>) -> Self {
+        self.user = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for RBDPersistentVolumeSource {}
 
+impl RBDPersistentVolumeSource {
+    const FIELDS: &'static [&'static str] = &[
+        "fsType",
+        "image",
+        "keyring",
+        "monitors",
+        "pool",
+        "readOnly",
+        "secretRef",
+        "user",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for RBDPersistentVolumeSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -92,52 +138,151 @@ impl<'de> crate::serde::Deserialize<'de> for RBDPersistentVolumeSource {
                 let mut value_secret_ref: Option<crate::api::core::v1::SecretReference> = None;
                 let mut value_user: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("RBDPersistentVolumeSource", RBDPersistentVolumeSource::FIELDS))? {
                     match key {
-                        Field::Key_fs_type => value_fs_type = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_image => value_image = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_keyring => value_keyring = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_monitors => value_monitors = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_pool => value_pool = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_read_only => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_secret_ref => value_secret_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_user => value_user = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_fs_type = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_image = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_keyring = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_monitors = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(4) => value_pool = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_secret_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(7) => value_user = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(RBDPersistentVolumeSource {
                     fs_type: value_fs_type,
-                    image: value_image.ok_or_else(|| crate::serde::de::Error::missing_field("image"))?,
+                    image: value_image.ok_or_else(|| crate::serde2::missing_field("RBDPersistentVolumeSource", "image", &["image", "monitors"]))?,
                     keyring: value_keyring,
-                    monitors: value_monitors.ok_or_else(|| crate::serde::de::Error::missing_field("monitors"))?,
+                    monitors: value_monitors.ok_or_else(|| crate::serde2::missing_field("RBDPersistentVolumeSource", "monitors", &["image", "monitors"]))?,
                     pool: value_pool,
                     read_only: value_read_only,
                     secret_ref: value_secret_ref,
                     user: value_user,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(RBDPersistentVolumeSource {
+                    fs_type: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    image: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    keyring: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    monitors: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    pool: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    read_only: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    secret_ref: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    user: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "RBDPersistentVolumeSource",
-            &[
-                "fsType",
-                "image",
-                "keyring",
-                "monitors",
-                "pool",
-                "readOnly",
-                "secretRef",
-                "user",
-            ],
+            RBDPersistentVolumeSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, RBDPersistentVolumeSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("RBDPersistentVolumeSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_fs_type = false;
+                let mut has_image = false;
+                let mut has_keyring = false;
+                let mut has_monitors = false;
+                let mut has_pool = false;
+                let mut has_read_only = false;
+                let mut has_secret_ref = false;
+                let mut has_user = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("RBDPersistentVolumeSource", RBDPersistentVolumeSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.fs_type))?; has_fs_type = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.image))?; has_image = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.keyring))?; has_keyring = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.monitors))?; has_monitors = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.pool))?; has_pool = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.read_only))?; has_read_only = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.secret_ref))?; has_secret_ref = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.user))?; has_user = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_fs_type {
+                    self.0.fs_type = None;
+                }
+                if !has_image {
+                    return Err(crate::serde2::missing_field("RBDPersistentVolumeSource", "image", &["image", "monitors"]));
+                }
+                if !has_keyring {
+                    self.0.keyring = None;
+                }
+                if !has_monitors {
+                    return Err(crate::serde2::missing_field("RBDPersistentVolumeSource", "monitors", &["image", "monitors"]));
+                }
+                if !has_pool {
+                    self.0.pool = None;
+                }
+                if !has_read_only {
+                    self.0.read_only = None;
+                }
+                if !has_secret_ref {
+                    self.0.secret_ref = None;
+                }
+                if !has_user {
+                    self.0.user = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.fs_type))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.image))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.keyring))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.monitors))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.pool))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.read_only))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.secret_ref))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.user))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "RBDPersistentVolumeSource",
+            RBDPersistentVolumeSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for RBDPersistentVolumeSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("RBDPersistentVolumeSource", 8)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsType", &self.fs_type)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "image", &self.image)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "keyring", &self.keyring)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "monitors", &self.monitors)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "pool", &self.pool)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", &self.read_only)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretRef", &self.secret_ref)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "user", &self.user)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "RBDPersistentVolumeSource",
             2 +
@@ -172,10 +317,174 @@ impl crate::serde::Serialize for RBDPersistentVolumeSource {
     }
 }
 
+/// A builder of [`RBDPersistentVolumeSource`]. Created with [`RBDPersistentVolumeSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct RBDPersistentVolumeSourceBuilder {
+    fs_type: Option<String>,
+    image: Option<String>,
+    keyring: Option<String>,
+    monitors: Option<Vec<String>>,
+    pool: Option<String>,
+    read_only: Option<bool>,
+    secret_ref: Option<crate::api::core::v1::SecretReference>,
+    user: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl RBDPersistentVolumeSource {
+    /// Starts building a `RBDPersistentVolumeSource`. Its required fields must be set before [`RBDPersistentVolumeSourceBuilder::build`] is called.
+    pub fn builder() -> RBDPersistentVolumeSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl RBDPersistentVolumeSourceBuilder {
+    /// Sets [`RBDPersistentVolumeSource::fs_type`].
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`RBDPersistentVolumeSource::image`].
+    pub fn image(mut self, value: impl Into<String>) -> Self {
+        self.image = Some(value.into());
+        self
+    }
+
+    /// Sets [`RBDPersistentVolumeSource::keyring`].
+    pub fn keyring(mut self, value: impl Into<String>) -> Self {
+        self.keyring = Some(value.into());
+        self
+    }
+
+    /// Sets [`RBDPersistentVolumeSource::monitors`].
+    pub fn monitors(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.monitors = Some(value.into());
+        self
+    }
+
+    /// Sets [`RBDPersistentVolumeSource::pool`].
+    pub fn pool(mut self, value: impl Into<String>) -> Self {
+        self.pool = Some(value.into());
+        self
+    }
+
+    /// Sets [`RBDPersistentVolumeSource::read_only`].
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`RBDPersistentVolumeSource::secret_ref`].
+    pub fn secret_ref(mut self, value: impl Into<crate::api::core::v1::SecretReference>) -> Self {
+        self.secret_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`RBDPersistentVolumeSource::user`].
+    pub fn user(mut self, value: impl Into<String>) -> Self {
+        self.user = Some(value.into());
+        self
+    }
+
+    /// Builds the `RBDPersistentVolumeSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<RBDPersistentVolumeSource, crate::MissingFieldError> {
+        Ok(RBDPersistentVolumeSource {
+            fs_type: self.fs_type,
+            image: self.image.ok_or_else(|| crate::MissingFieldError::new("RBDPersistentVolumeSource", "image"))?,
+            keyring: self.keyring,
+            monitors: self.monitors.ok_or_else(|| crate::MissingFieldError::new("RBDPersistentVolumeSource", "monitors"))?,
+            pool: self.pool,
+            read_only: self.read_only,
+            secret_ref: self.secret_ref,
+            user: self.user,
+        })
+    }
+}
+
+/// The apply configuration of [`RBDPersistentVolumeSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RBDPersistentVolumeSourceApplyConfiguration {
+    /// See [`RBDPersistentVolumeSource::fs_type`].
+    pub fs_type: Option<String>,
+
+    /// See [`RBDPersistentVolumeSource::image`].
+    pub image: Option<String>,
+
+    /// See [`RBDPersistentVolumeSource::keyring`].
+    pub keyring: Option<String>,
+
+    /// See [`RBDPersistentVolumeSource::monitors`].
+    pub monitors: Option<Vec<String>>,
+
+    /// See [`RBDPersistentVolumeSource::pool`].
+    pub pool: Option<String>,
+
+    /// See [`RBDPersistentVolumeSource::read_only`].
+    pub read_only: Option<bool>,
+
+    /// See [`RBDPersistentVolumeSource::secret_ref`].
+    pub secret_ref: Option<crate::api::core::v1::SecretReferenceApplyConfiguration>,
+
+    /// See [`RBDPersistentVolumeSource::user`].
+    pub user: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for RBDPersistentVolumeSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "RBDPersistentVolumeSourceApplyConfiguration",
+            self.fs_type.as_ref().map_or(0, |_| 1) +
+            self.image.as_ref().map_or(0, |_| 1) +
+            self.keyring.as_ref().map_or(0, |_| 1) +
+            self.monitors.as_ref().map_or(0, |_| 1) +
+            self.pool.as_ref().map_or(0, |_| 1) +
+            self.read_only.as_ref().map_or(0, |_| 1) +
+            self.secret_ref.as_ref().map_or(0, |_| 1) +
+            self.user.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.fs_type {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsType", value)?;
+        }
+        if let Some(value) = &self.image {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "image", value)?;
+        }
+        if let Some(value) = &self.keyring {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "keyring", value)?;
+        }
+        if let Some(value) = &self.monitors {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "monitors", value)?;
+        }
+        if let Some(value) = &self.pool {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "pool", value)?;
+        }
+        if let Some(value) = &self.read_only {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", value)?;
+        }
+        if let Some(value) = &self.secret_ref {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretRef", value)?;
+        }
+        if let Some(value) = &self.user {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "user", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for RBDPersistentVolumeSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.RBDPersistentVolumeSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::RBDPersistentVolumeSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.RBDPersistentVolumeSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -186,8 +495,9 @@ impl crate::schemars::JsonSchema for RBDPersistentVolumeSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "fsType".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -197,8 +507,8 @@ impl crate::schemars::JsonSchema for RBDPersistentVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "image".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -208,8 +518,8 @@ impl crate::schemars::JsonSchema for RBDPersistentVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "keyring".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -219,8 +529,8 @@ impl crate::schemars::JsonSchema for RBDPersistentVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "monitors".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -239,8 +549,8 @@ impl crate::schemars::JsonSchema for RBDPersistentVolumeSource {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "pool".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -250,8 +560,8 @@ impl crate::schemars::JsonSchema for RBDPersistentVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "readOnly".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -261,8 +571,8 @@ impl crate::schemars::JsonSchema for RBDPersistentVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "secretRef".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::SecretReference>().into_object();
@@ -272,8 +582,8 @@ impl crate::schemars::JsonSchema for RBDPersistentVolumeSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "user".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -283,8 +593,9 @@ impl crate::schemars::JsonSchema for RBDPersistentVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "image",
                     "monitors",
@@ -295,3 +606,19 @@ impl crate::schemars::JsonSchema for RBDPersistentVolumeSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for RBDPersistentVolumeSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(RBDPersistentVolumeSource {
+            fs_type: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            image: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            keyring: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            monitors: crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            pool: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            read_only: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            secret_ref: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            user: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
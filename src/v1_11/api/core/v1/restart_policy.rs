@@ -0,0 +1,158 @@
+// Generated from definition io.k8s.api.core.v1.RestartPolicy
+
+/// RestartPolicy describes how the containers of a pod should be restarted.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum RestartPolicy {
+    /// `Always`
+    Always,
+
+    /// `OnFailure`
+    OnFailure,
+
+    /// `Never`
+    Never,
+
+    /// A value that is not one of the values known to this version of the crate, such as one that was added in a newer version of Kubernetes.
+    Other(String),
+}
+
+impl RestartPolicy {
+    /// The values known to this version of the crate, in the order of the variants.
+    pub const VALUES: &'static [&'static str] = &["Always", "OnFailure", "Never"];
+
+    /// The value as it is serialized.
+    pub fn as_str(&self) -> &str {
+        match self {
+            RestartPolicy::Always => "Always",
+            RestartPolicy::OnFailure => "OnFailure",
+            RestartPolicy::Never => "Never",
+            RestartPolicy::Other(value) => value,
+        }
+    }
+}
+
+impl AsRef<str> for RestartPolicy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        RestartPolicy::Other(String::new())
+    }
+}
+
+impl std::fmt::Display for RestartPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for RestartPolicy {
+    fn from(value: &str) -> Self {
+        match value {
+            "Always" => RestartPolicy::Always,
+            "OnFailure" => RestartPolicy::OnFailure,
+            "Never" => RestartPolicy::Never,
+            value => RestartPolicy::Other(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RestartPolicy {
+    fn from(value: String) -> Self {
+        match &*value {
+            "Always" => RestartPolicy::Always,
+            "OnFailure" => RestartPolicy::OnFailure,
+            "Never" => RestartPolicy::Never,
+            _ => RestartPolicy::Other(value),
+        }
+    }
+}
+
+impl From<RestartPolicy> for String {
+    fn from(value: RestartPolicy) -> Self {
+        match value {
+            RestartPolicy::Other(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl PartialEq<str> for RestartPolicy {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for RestartPolicy {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<'de> crate::serde::Deserialize<'de> for RestartPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl crate::serde::de::Visitor<'_> for Visitor {
+            type Value = RestartPolicy;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("RestartPolicy")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
+                Ok(v.into())
+            }
+
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E> where E: crate::serde::de::Error {
+                Ok(v.into())
+            }
+        }
+
+        deserializer.deserialize_string(Visitor)
+    }
+}
+
+impl crate::serde::Serialize for RestartPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for RestartPolicy {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::RestartPolicy".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.RestartPolicy".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("RestartPolicy describes how the containers of a pod should be restarted.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for RestartPolicy {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        Ok(match u.int_in_range(0..=3_u8)? {
+            0 => RestartPolicy::Always,
+            1 => RestartPolicy::OnFailure,
+            2 => RestartPolicy::Never,
+            _ => RestartPolicy::from(u.arbitrary::<String>()?),
+        })
+    }
+}
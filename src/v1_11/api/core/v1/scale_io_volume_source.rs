@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.ScaleIOVolumeSource
 
 /// ScaleIOVolumeSource represents a persistent ScaleIO volume
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ScaleIOVolumeSource {
     /// Filesystem type to mount. Must be a filesystem type supported by the host operating system. Ex. "ext4", "xfs", "ntfs". Implicitly inferred to be "ext4" if unspecified.
     pub fs_type: Option<String>,
@@ -34,55 +34,115 @@ pub struct ScaleIOVolumeSource {
     pub volume_name: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ScaleIOVolumeSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_fs_type,
-            Key_gateway,
-            Key_protection_domain,
-            Key_read_only,
-            Key_secret_ref,
-            Key_ssl_enabled,
-            Key_storage_mode,
-            Key_storage_pool,
-            Key_system,
-            Key_volume_name,
-            Other,
+impl ScaleIOVolumeSource {
+    /// Constructs a `ScaleIOVolumeSource` from its required fields. Its optional fields are unset.
+    pub fn new(gateway: impl Into<String>, secret_ref: impl Into<crate::api::core::v1::LocalObjectReference>, system: impl Into<String>) -> Self {
+        ScaleIOVolumeSource {
+            fs_type: None,
+            gateway: gateway.into(),
+            protection_domain: None,
+            read_only: None,
+            secret_ref: secret_ref.into(),
+            ssl_enabled: None,
+            storage_mode: None,
+            storage_pool: None,
+            system: system.into(),
+            volume_name: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl ScaleIOVolumeSource {
+    /// Returns this value with [`ScaleIOVolumeSource::fs_type`] set to the given value.
+    #[must_use]
+    pub fn with_fs_type(mut self, value: impl Into<String>) -> Self {
+        self.fs_type = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ScaleIOVolumeSource::gateway`] set to the given value.
+    #[must_use]
+    pub fn with_gateway(mut self, value: impl Into<String>) -> Self {
+        self.gateway = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ScaleIOVolumeSource::protection_domain`] set to the given value.
+    #[must_use]
+    pub fn with_protection_domain(mut self, value: impl Into<String>) -> Self {
+        self.protection_domain = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "fsType" => Field::Key_fs_type,
-                            "gateway" => Field::Key_gateway,
-                            "protectionDomain" => Field::Key_protection_domain,
-                            "readOnly" => Field::Key_read_only,
-                            "secretRef" => Field::Key_secret_ref,
-                            "sslEnabled" => Field::Key_ssl_enabled,
-                            "storageMode" => Field::Key_storage_mode,
-                            "storagePool" => Field::Key_storage_pool,
-                            "system" => Field::Key_system,
-                            "volumeName" => Field::Key_volume_name,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`ScaleIOVolumeSource::read_only`] set to the given value.
+    #[must_use]
+    pub fn with_read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`ScaleIOVolumeSource::secret_ref`] set to the given value.
+    #[must_use]
+    pub fn with_secret_ref(mut self, value: impl Into<crate::api::core::v1::LocalObjectReference>) -> Self {
+        self.secret_ref = value.into();
+        self
+    }
+
+    /// Returns this value with [`ScaleIOVolumeSource::ssl_enabled`] set to the given value.
+    #[must_use]
+    pub fn with_ssl_enabled(mut self, value: impl Into<bool>) -> Self {
+        self.ssl_enabled = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ScaleIOVolumeSource::storage_mode`] set to the given value.
+    #[must_use]
+    pub fn with_storage_mode(mut self, value: impl Into<String>) -> Self {
+        self.storage_mode = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ScaleIOVolumeSource::storage_pool`] set to the given value.
+    #[must_use]
+    pub fn with_storage_pool(mut self, value: impl Into<String>) -> Self {
+        self.storage_pool = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ScaleIOVolumeSource::system`] set to the given value.
+    #[must_use]
+    pub fn with_system(mut self, value: impl Into<String>) -> Self {
+        self.system = value.into();
+        self
+    }
+
+    /// Returns this value with [`ScaleIOVolumeSource::volume_name`] set to the given value.
+    #[must_use]
+    pub fn with_volume_name(mut self, value: impl Into<String>) -> Self {
+        self.volume_name = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for ScaleIOVolumeSource {}
 
+impl ScaleIOVolumeSource {
+    const FIELDS: &'static [&'static str] = &[
+        "fsType",
+        "gateway",
+        "protectionDomain",
+        "readOnly",
+        "secretRef",
+        "sslEnabled",
+        "storageMode",
+        "storagePool",
+        "system",
+        "volumeName",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for ScaleIOVolumeSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -104,58 +164,171 @@ impl<'de> crate::serde::Deserialize<'de> for ScaleIOVolumeSource {
                 let mut value_system: Option<String> = None;
                 let mut value_volume_name: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ScaleIOVolumeSource", ScaleIOVolumeSource::FIELDS))? {
                     match key {
-                        Field::Key_fs_type => value_fs_type = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_gateway => value_gateway = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_protection_domain => value_protection_domain = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_read_only => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_secret_ref => value_secret_ref = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_ssl_enabled => value_ssl_enabled = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_storage_mode => value_storage_mode = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_storage_pool => value_storage_pool = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_system => value_system = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_volume_name => value_volume_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_fs_type = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_gateway = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_protection_domain = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_secret_ref = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(5) => value_ssl_enabled = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_storage_mode = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(7) => value_storage_pool = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(8) => value_system = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(9) => value_volume_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ScaleIOVolumeSource {
                     fs_type: value_fs_type,
-                    gateway: value_gateway.ok_or_else(|| crate::serde::de::Error::missing_field("gateway"))?,
+                    gateway: value_gateway.ok_or_else(|| crate::serde2::missing_field("ScaleIOVolumeSource", "gateway", &["gateway", "secretRef", "system"]))?,
                     protection_domain: value_protection_domain,
                     read_only: value_read_only,
-                    secret_ref: value_secret_ref.ok_or_else(|| crate::serde::de::Error::missing_field("secretRef"))?,
+                    secret_ref: value_secret_ref.ok_or_else(|| crate::serde2::missing_field("ScaleIOVolumeSource", "secretRef", &["gateway", "secretRef", "system"]))?,
                     ssl_enabled: value_ssl_enabled,
                     storage_mode: value_storage_mode,
                     storage_pool: value_storage_pool,
-                    system: value_system.ok_or_else(|| crate::serde::de::Error::missing_field("system"))?,
+                    system: value_system.ok_or_else(|| crate::serde2::missing_field("ScaleIOVolumeSource", "system", &["gateway", "secretRef", "system"]))?,
                     volume_name: value_volume_name,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ScaleIOVolumeSource {
+                    fs_type: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    gateway: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    protection_domain: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    read_only: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    secret_ref: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    ssl_enabled: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    storage_mode: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    storage_pool: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                    system: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?,
+                    volume_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "ScaleIOVolumeSource",
-            &[
-                "fsType",
-                "gateway",
-                "protectionDomain",
-                "readOnly",
-                "secretRef",
-                "sslEnabled",
-                "storageMode",
-                "storagePool",
-                "system",
-                "volumeName",
-            ],
+            ScaleIOVolumeSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ScaleIOVolumeSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ScaleIOVolumeSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_fs_type = false;
+                let mut has_gateway = false;
+                let mut has_protection_domain = false;
+                let mut has_read_only = false;
+                let mut has_secret_ref = false;
+                let mut has_ssl_enabled = false;
+                let mut has_storage_mode = false;
+                let mut has_storage_pool = false;
+                let mut has_system = false;
+                let mut has_volume_name = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ScaleIOVolumeSource", ScaleIOVolumeSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.fs_type))?; has_fs_type = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.gateway))?; has_gateway = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.protection_domain))?; has_protection_domain = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.read_only))?; has_read_only = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.secret_ref))?; has_secret_ref = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.ssl_enabled))?; has_ssl_enabled = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.storage_mode))?; has_storage_mode = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.storage_pool))?; has_storage_pool = true; },
+                        Some(8) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.system))?; has_system = true; },
+                        Some(9) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.volume_name))?; has_volume_name = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_fs_type {
+                    self.0.fs_type = None;
+                }
+                if !has_gateway {
+                    return Err(crate::serde2::missing_field("ScaleIOVolumeSource", "gateway", &["gateway", "secretRef", "system"]));
+                }
+                if !has_protection_domain {
+                    self.0.protection_domain = None;
+                }
+                if !has_read_only {
+                    self.0.read_only = None;
+                }
+                if !has_secret_ref {
+                    return Err(crate::serde2::missing_field("ScaleIOVolumeSource", "secretRef", &["gateway", "secretRef", "system"]));
+                }
+                if !has_ssl_enabled {
+                    self.0.ssl_enabled = None;
+                }
+                if !has_storage_mode {
+                    self.0.storage_mode = None;
+                }
+                if !has_storage_pool {
+                    self.0.storage_pool = None;
+                }
+                if !has_system {
+                    return Err(crate::serde2::missing_field("ScaleIOVolumeSource", "system", &["gateway", "secretRef", "system"]));
+                }
+                if !has_volume_name {
+                    self.0.volume_name = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.fs_type))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.gateway))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.protection_domain))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.read_only))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.secret_ref))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.ssl_enabled))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.storage_mode))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.storage_pool))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.system))?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.volume_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ScaleIOVolumeSource",
+            ScaleIOVolumeSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ScaleIOVolumeSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ScaleIOVolumeSource", 10)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsType", &self.fs_type)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "gateway", &self.gateway)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "protectionDomain", &self.protection_domain)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", &self.read_only)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretRef", &self.secret_ref)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "sslEnabled", &self.ssl_enabled)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "storageMode", &self.storage_mode)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "storagePool", &self.storage_pool)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "system", &self.system)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeName", &self.volume_name)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ScaleIOVolumeSource",
             3 +
@@ -195,10 +368,204 @@ impl crate::serde::Serialize for ScaleIOVolumeSource {
     }
 }
 
+/// A builder of [`ScaleIOVolumeSource`]. Created with [`ScaleIOVolumeSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ScaleIOVolumeSourceBuilder {
+    fs_type: Option<String>,
+    gateway: Option<String>,
+    protection_domain: Option<String>,
+    read_only: Option<bool>,
+    secret_ref: Option<crate::api::core::v1::LocalObjectReference>,
+    ssl_enabled: Option<bool>,
+    storage_mode: Option<String>,
+    storage_pool: Option<String>,
+    system: Option<String>,
+    volume_name: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl ScaleIOVolumeSource {
+    /// Starts building a `ScaleIOVolumeSource`. Its required fields must be set before [`ScaleIOVolumeSourceBuilder::build`] is called.
+    pub fn builder() -> ScaleIOVolumeSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ScaleIOVolumeSourceBuilder {
+    /// Sets [`ScaleIOVolumeSource::fs_type`].
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`ScaleIOVolumeSource::gateway`].
+    pub fn gateway(mut self, value: impl Into<String>) -> Self {
+        self.gateway = Some(value.into());
+        self
+    }
+
+    /// Sets [`ScaleIOVolumeSource::protection_domain`].
+    pub fn protection_domain(mut self, value: impl Into<String>) -> Self {
+        self.protection_domain = Some(value.into());
+        self
+    }
+
+    /// Sets [`ScaleIOVolumeSource::read_only`].
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`ScaleIOVolumeSource::secret_ref`].
+    pub fn secret_ref(mut self, value: impl Into<crate::api::core::v1::LocalObjectReference>) -> Self {
+        self.secret_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`ScaleIOVolumeSource::ssl_enabled`].
+    pub fn ssl_enabled(mut self, value: impl Into<bool>) -> Self {
+        self.ssl_enabled = Some(value.into());
+        self
+    }
+
+    /// Sets [`ScaleIOVolumeSource::storage_mode`].
+    pub fn storage_mode(mut self, value: impl Into<String>) -> Self {
+        self.storage_mode = Some(value.into());
+        self
+    }
+
+    /// Sets [`ScaleIOVolumeSource::storage_pool`].
+    pub fn storage_pool(mut self, value: impl Into<String>) -> Self {
+        self.storage_pool = Some(value.into());
+        self
+    }
+
+    /// Sets [`ScaleIOVolumeSource::system`].
+    pub fn system(mut self, value: impl Into<String>) -> Self {
+        self.system = Some(value.into());
+        self
+    }
+
+    /// Sets [`ScaleIOVolumeSource::volume_name`].
+    pub fn volume_name(mut self, value: impl Into<String>) -> Self {
+        self.volume_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ScaleIOVolumeSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ScaleIOVolumeSource, crate::MissingFieldError> {
+        Ok(ScaleIOVolumeSource {
+            fs_type: self.fs_type,
+            gateway: self.gateway.ok_or_else(|| crate::MissingFieldError::new("ScaleIOVolumeSource", "gateway"))?,
+            protection_domain: self.protection_domain,
+            read_only: self.read_only,
+            secret_ref: self.secret_ref.ok_or_else(|| crate::MissingFieldError::new("ScaleIOVolumeSource", "secretRef"))?,
+            ssl_enabled: self.ssl_enabled,
+            storage_mode: self.storage_mode,
+            storage_pool: self.storage_pool,
+            system: self.system.ok_or_else(|| crate::MissingFieldError::new("ScaleIOVolumeSource", "system"))?,
+            volume_name: self.volume_name,
+        })
+    }
+}
+
+/// The apply configuration of [`ScaleIOVolumeSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ScaleIOVolumeSourceApplyConfiguration {
+    /// See [`ScaleIOVolumeSource::fs_type`].
+    pub fs_type: Option<String>,
+
+    /// See [`ScaleIOVolumeSource::gateway`].
+    pub gateway: Option<String>,
+
+    /// See [`ScaleIOVolumeSource::protection_domain`].
+    pub protection_domain: Option<String>,
+
+    /// See [`ScaleIOVolumeSource::read_only`].
+    pub read_only: Option<bool>,
+
+    /// See [`ScaleIOVolumeSource::secret_ref`].
+    pub secret_ref: Option<crate::api::core::v1::LocalObjectReferenceApplyConfiguration>,
+
+    /// See [`ScaleIOVolumeSource::ssl_enabled`].
+    pub ssl_enabled: Option<bool>,
+
+    /// See [`ScaleIOVolumeSource::storage_mode`].
+    pub storage_mode: Option<String>,
+
+    /// See [`ScaleIOVolumeSource::storage_pool`].
+    pub storage_pool: Option<String>,
+
+    /// See [`ScaleIOVolumeSource::system`].
+    pub system: Option<String>,
+
+    /// See [`ScaleIOVolumeSource::volume_name`].
+    pub volume_name: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ScaleIOVolumeSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ScaleIOVolumeSourceApplyConfiguration",
+            self.fs_type.as_ref().map_or(0, |_| 1) +
+            self.gateway.as_ref().map_or(0, |_| 1) +
+            self.protection_domain.as_ref().map_or(0, |_| 1) +
+            self.read_only.as_ref().map_or(0, |_| 1) +
+            self.secret_ref.as_ref().map_or(0, |_| 1) +
+            self.ssl_enabled.as_ref().map_or(0, |_| 1) +
+            self.storage_mode.as_ref().map_or(0, |_| 1) +
+            self.storage_pool.as_ref().map_or(0, |_| 1) +
+            self.system.as_ref().map_or(0, |_| 1) +
+            self.volume_name.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.fs_type {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsType", value)?;
+        }
+        if let Some(value) = &self.gateway {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "gateway", value)?;
+        }
+        if let Some(value) = &self.protection_domain {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "protectionDomain", value)?;
+        }
+        if let Some(value) = &self.read_only {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", value)?;
+        }
+        if let Some(value) = &self.secret_ref {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretRef", value)?;
+        }
+        if let Some(value) = &self.ssl_enabled {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "sslEnabled", value)?;
+        }
+        if let Some(value) = &self.storage_mode {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "storageMode", value)?;
+        }
+        if let Some(value) = &self.storage_pool {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "storagePool", value)?;
+        }
+        if let Some(value) = &self.system {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "system", value)?;
+        }
+        if let Some(value) = &self.volume_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeName", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ScaleIOVolumeSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.ScaleIOVolumeSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::ScaleIOVolumeSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.ScaleIOVolumeSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -209,8 +576,9 @@ impl crate::schemars::JsonSchema for ScaleIOVolumeSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "fsType".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -220,8 +588,8 @@ impl crate::schemars::JsonSchema for ScaleIOVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "gateway".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -231,8 +599,8 @@ impl crate::schemars::JsonSchema for ScaleIOVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "protectionDomain".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -242,8 +610,8 @@ impl crate::schemars::JsonSchema for ScaleIOVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "readOnly".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -253,8 +621,8 @@ impl crate::schemars::JsonSchema for ScaleIOVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "secretRef".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::LocalObjectReference>().into_object();
@@ -264,8 +632,8 @@ impl crate::schemars::JsonSchema for ScaleIOVolumeSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "sslEnabled".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -275,8 +643,8 @@ impl crate::schemars::JsonSchema for ScaleIOVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "storageMode".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -286,8 +654,8 @@ impl crate::schemars::JsonSchema for ScaleIOVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "storagePool".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -297,8 +665,8 @@ impl crate::schemars::JsonSchema for ScaleIOVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "system".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -308,8 +676,8 @@ impl crate::schemars::JsonSchema for ScaleIOVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "volumeName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -319,8 +687,9 @@ impl crate::schemars::JsonSchema for ScaleIOVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "gateway",
                     "secretRef",
@@ -332,3 +701,21 @@ impl crate::schemars::JsonSchema for ScaleIOVolumeSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ScaleIOVolumeSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ScaleIOVolumeSource {
+            fs_type: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            gateway: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            protection_domain: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            read_only: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            secret_ref: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            ssl_enabled: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            storage_mode: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            storage_pool: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            system: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            volume_name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,80 +1,118 @@
 // Generated from definition io.k8s.api.core.v1.ScopeSelector
 
 /// A scope selector represents the AND of the selectors represented by the scoped-resource selector requirements.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ScopeSelector {
     /// A list of scope selector requirements by scope of the resources.
     pub match_expressions: Option<Vec<crate::api::core::v1::ScopedResourceSelectorRequirement>>,
 }
 
+impl ScopeSelector {
+    /// Returns this value with [`ScopeSelector::match_expressions`] set to the given value.
+    #[must_use]
+    pub fn with_match_expressions(mut self, value: impl Into<Vec<crate::api::core::v1::ScopedResourceSelectorRequirement>>) -> Self {
+        self.match_expressions = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for ScopeSelector {}
+
+impl ScopeSelector {
+    const FIELDS: &'static [&'static str] = &[
+        "matchExpressions",
+    ];
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ScopeSelector {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_match_expressions,
-            Other,
-        }
+        struct Visitor;
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = ScopeSelector;
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ScopeSelector")
+            }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_match_expressions: Option<Vec<crate::api::core::v1::ScopedResourceSelectorRequirement>> = None;
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "matchExpressions" => Field::Key_match_expressions,
-                            _ => Field::Other,
-                        })
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ScopeSelector", ScopeSelector::FIELDS))? {
+                    match key {
+                        Some(0) => value_match_expressions = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
-                deserializer.deserialize_identifier(Visitor)
+                Ok(ScopeSelector {
+                    match_expressions: value_match_expressions,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ScopeSelector {
+                    match_expressions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                })
             }
         }
 
-        struct Visitor;
+        deserializer.deserialize_struct(
+            "ScopeSelector",
+            ScopeSelector::FIELDS,
+            Visitor,
+        )
+    }
 
-        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-            type Value = ScopeSelector;
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ScopeSelector> {
+            type Value = ();
 
             fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 f.write_str("ScopeSelector")
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
-                let mut value_match_expressions: Option<Vec<crate::api::core::v1::ScopedResourceSelectorRequirement>> = None;
+                let mut has_match_expressions = false;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ScopeSelector", ScopeSelector::FIELDS))? {
                     match key {
-                        Field::Key_match_expressions => value_match_expressions = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.match_expressions))?; has_match_expressions = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
+                if !has_match_expressions {
+                    self.0.match_expressions = None;
+                }
 
-                Ok(ScopeSelector {
-                    match_expressions: value_match_expressions,
-                })
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.match_expressions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
             }
         }
 
         deserializer.deserialize_struct(
             "ScopeSelector",
-            &[
-                "matchExpressions",
-            ],
-            Visitor,
+            ScopeSelector::FIELDS,
+            InPlaceVisitor(place),
         )
     }
 }
 
 impl crate::serde::Serialize for ScopeSelector {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ScopeSelector", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "matchExpressions", &self.match_expressions)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ScopeSelector",
             self.match_expressions.as_ref().map_or(0, |_| 1),
@@ -86,10 +124,69 @@ impl crate::serde::Serialize for ScopeSelector {
     }
 }
 
+/// A builder of [`ScopeSelector`]. Created with [`ScopeSelector::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ScopeSelectorBuilder {
+    match_expressions: Option<Vec<crate::api::core::v1::ScopedResourceSelectorRequirement>>,
+}
+
+#[cfg(feature = "builder")]
+impl ScopeSelector {
+    /// Starts building a `ScopeSelector`. Its required fields must be set before [`ScopeSelectorBuilder::build`] is called.
+    pub fn builder() -> ScopeSelectorBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ScopeSelectorBuilder {
+    /// Sets [`ScopeSelector::match_expressions`].
+    pub fn match_expressions(mut self, value: impl Into<Vec<crate::api::core::v1::ScopedResourceSelectorRequirement>>) -> Self {
+        self.match_expressions = Some(value.into());
+        self
+    }
+
+    /// Builds the `ScopeSelector`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ScopeSelector, crate::MissingFieldError> {
+        Ok(ScopeSelector {
+            match_expressions: self.match_expressions,
+        })
+    }
+}
+
+/// The apply configuration of [`ScopeSelector`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ScopeSelectorApplyConfiguration {
+    /// See [`ScopeSelector::match_expressions`].
+    pub match_expressions: Option<Vec<crate::api::core::v1::ScopedResourceSelectorRequirementApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ScopeSelectorApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ScopeSelectorApplyConfiguration",
+            self.match_expressions.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.match_expressions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "matchExpressions", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ScopeSelector {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.ScopeSelector".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::ScopeSelector".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.ScopeSelector".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -100,8 +197,9 @@ impl crate::schemars::JsonSchema for ScopeSelector {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "matchExpressions".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -115,11 +213,21 @@ impl crate::schemars::JsonSchema for ScopeSelector {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ScopeSelector {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ScopeSelector {
+            match_expressions: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
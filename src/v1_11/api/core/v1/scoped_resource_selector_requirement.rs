@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.ScopedResourceSelectorRequirement
 
 /// A scoped-resource selector requirement is a selector that contains values, a scope name, and an operator that relates the scope name and values.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ScopedResourceSelectorRequirement {
     /// Represents a scope's relationship to a set of values. Valid operators are In, NotIn, Exists, DoesNotExist.
     pub operator: String,
@@ -13,41 +13,52 @@ pub struct ScopedResourceSelectorRequirement {
     pub values: Option<Vec<String>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ScopedResourceSelectorRequirement {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_operator,
-            Key_scope_name,
-            Key_values,
-            Other,
+impl ScopedResourceSelectorRequirement {
+    /// Constructs a `ScopedResourceSelectorRequirement` from its required fields. Its optional fields are unset.
+    pub fn new(operator: impl Into<String>, scope_name: impl Into<String>) -> Self {
+        ScopedResourceSelectorRequirement {
+            operator: operator.into(),
+            scope_name: scope_name.into(),
+            values: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl ScopedResourceSelectorRequirement {
+    /// Returns this value with [`ScopedResourceSelectorRequirement::operator`] set to the given value.
+    #[must_use]
+    pub fn with_operator(mut self, value: impl Into<String>) -> Self {
+        self.operator = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ScopedResourceSelectorRequirement::scope_name`] set to the given value.
+    #[must_use]
+    pub fn with_scope_name(mut self, value: impl Into<String>) -> Self {
+        self.scope_name = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ScopedResourceSelectorRequirement::values`] set to the given value.
+    #[must_use]
+    pub fn with_values(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.values = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "operator" => Field::Key_operator,
-                            "scopeName" => Field::Key_scope_name,
-                            "values" => Field::Key_values,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for ScopedResourceSelectorRequirement {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl ScopedResourceSelectorRequirement {
+    const FIELDS: &'static [&'static str] = &[
+        "operator",
+        "scopeName",
+        "values",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ScopedResourceSelectorRequirement {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,37 +73,101 @@ impl<'de> crate::serde::Deserialize<'de> for ScopedResourceSelectorRequirement {
                 let mut value_scope_name: Option<String> = None;
                 let mut value_values: Option<Vec<String>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ScopedResourceSelectorRequirement", ScopedResourceSelectorRequirement::FIELDS))? {
                     match key {
-                        Field::Key_operator => value_operator = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_scope_name => value_scope_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_values => value_values = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_operator = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_scope_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_values = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ScopedResourceSelectorRequirement {
-                    operator: value_operator.ok_or_else(|| crate::serde::de::Error::missing_field("operator"))?,
-                    scope_name: value_scope_name.ok_or_else(|| crate::serde::de::Error::missing_field("scopeName"))?,
+                    operator: value_operator.ok_or_else(|| crate::serde2::missing_field("ScopedResourceSelectorRequirement", "operator", &["operator", "scopeName"]))?,
+                    scope_name: value_scope_name.ok_or_else(|| crate::serde2::missing_field("ScopedResourceSelectorRequirement", "scopeName", &["operator", "scopeName"]))?,
                     values: value_values,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ScopedResourceSelectorRequirement {
+                    operator: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    scope_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    values: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "ScopedResourceSelectorRequirement",
-            &[
-                "operator",
-                "scopeName",
-                "values",
-            ],
+            ScopedResourceSelectorRequirement::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ScopedResourceSelectorRequirement> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ScopedResourceSelectorRequirement")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_operator = false;
+                let mut has_scope_name = false;
+                let mut has_values = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ScopedResourceSelectorRequirement", ScopedResourceSelectorRequirement::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.operator))?; has_operator = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.scope_name))?; has_scope_name = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.values))?; has_values = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_operator {
+                    return Err(crate::serde2::missing_field("ScopedResourceSelectorRequirement", "operator", &["operator", "scopeName"]));
+                }
+                if !has_scope_name {
+                    return Err(crate::serde2::missing_field("ScopedResourceSelectorRequirement", "scopeName", &["operator", "scopeName"]));
+                }
+                if !has_values {
+                    self.0.values = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.operator))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.scope_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.values))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ScopedResourceSelectorRequirement",
+            ScopedResourceSelectorRequirement::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ScopedResourceSelectorRequirement {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ScopedResourceSelectorRequirement", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "operator", &self.operator)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "scopeName", &self.scope_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "values", &self.values)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ScopedResourceSelectorRequirement",
             2 +
@@ -107,10 +182,99 @@ impl crate::serde::Serialize for ScopedResourceSelectorRequirement {
     }
 }
 
+/// A builder of [`ScopedResourceSelectorRequirement`]. Created with [`ScopedResourceSelectorRequirement::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ScopedResourceSelectorRequirementBuilder {
+    operator: Option<String>,
+    scope_name: Option<String>,
+    values: Option<Vec<String>>,
+}
+
+#[cfg(feature = "builder")]
+impl ScopedResourceSelectorRequirement {
+    /// Starts building a `ScopedResourceSelectorRequirement`. Its required fields must be set before [`ScopedResourceSelectorRequirementBuilder::build`] is called.
+    pub fn builder() -> ScopedResourceSelectorRequirementBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ScopedResourceSelectorRequirementBuilder {
+    /// Sets [`ScopedResourceSelectorRequirement::operator`].
+    pub fn operator(mut self, value: impl Into<String>) -> Self {
+        self.operator = Some(value.into());
+        self
+    }
+
+    /// Sets [`ScopedResourceSelectorRequirement::scope_name`].
+    pub fn scope_name(mut self, value: impl Into<String>) -> Self {
+        self.scope_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`ScopedResourceSelectorRequirement::values`].
+    pub fn values(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.values = Some(value.into());
+        self
+    }
+
+    /// Builds the `ScopedResourceSelectorRequirement`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ScopedResourceSelectorRequirement, crate::MissingFieldError> {
+        Ok(ScopedResourceSelectorRequirement {
+            operator: self.operator.ok_or_else(|| crate::MissingFieldError::new("ScopedResourceSelectorRequirement", "operator"))?,
+            scope_name: self.scope_name.ok_or_else(|| crate::MissingFieldError::new("ScopedResourceSelectorRequirement", "scopeName"))?,
+            values: self.values,
+        })
+    }
+}
+
+/// The apply configuration of [`ScopedResourceSelectorRequirement`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ScopedResourceSelectorRequirementApplyConfiguration {
+    /// See [`ScopedResourceSelectorRequirement::operator`].
+    pub operator: Option<String>,
+
+    /// See [`ScopedResourceSelectorRequirement::scope_name`].
+    pub scope_name: Option<String>,
+
+    /// See [`ScopedResourceSelectorRequirement::values`].
+    pub values: Option<Vec<String>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ScopedResourceSelectorRequirementApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ScopedResourceSelectorRequirementApplyConfiguration",
+            self.operator.as_ref().map_or(0, |_| 1) +
+            self.scope_name.as_ref().map_or(0, |_| 1) +
+            self.values.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.operator {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "operator", value)?;
+        }
+        if let Some(value) = &self.scope_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "scopeName", value)?;
+        }
+        if let Some(value) = &self.values {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "values", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ScopedResourceSelectorRequirement {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.ScopedResourceSelectorRequirement".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::ScopedResourceSelectorRequirement".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.ScopedResourceSelectorRequirement".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -121,8 +285,9 @@ impl crate::schemars::JsonSchema for ScopedResourceSelectorRequirement {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "operator".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -132,8 +297,8 @@ impl crate::schemars::JsonSchema for ScopedResourceSelectorRequirement {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "scopeName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -143,8 +308,8 @@ impl crate::schemars::JsonSchema for ScopedResourceSelectorRequirement {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "values".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -163,8 +328,9 @@ impl crate::schemars::JsonSchema for ScopedResourceSelectorRequirement {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "operator",
                     "scopeName",
@@ -175,3 +341,14 @@ impl crate::schemars::JsonSchema for ScopedResourceSelectorRequirement {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ScopedResourceSelectorRequirement {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ScopedResourceSelectorRequirement {
+            operator: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            scope_name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            values: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
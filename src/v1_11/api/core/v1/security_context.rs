@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.SecurityContext
 
 /// SecurityContext holds security configuration that will be applied to a container. Some fields are present in both SecurityContext and PodSecurityContext.  When both are set, the values in SecurityContext take precedence.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct SecurityContext {
     /// AllowPrivilegeEscalation controls whether a process can gain more privileges than its parent process. This bool directly controls if the no_new_privs flag will be set on the container process. AllowPrivilegeEscalation is true always when the container is: 1) run as Privileged 2) has CAP_SYS_ADMIN
     pub allow_privilege_escalation: Option<bool>,
@@ -28,51 +28,81 @@ pub struct SecurityContext {
     pub se_linux_options: Option<crate::api::core::v1::SELinuxOptions>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for SecurityContext {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_allow_privilege_escalation,
-            Key_capabilities,
-            Key_privileged,
-            Key_read_only_root_filesystem,
-            Key_run_as_group,
-            Key_run_as_non_root,
-            Key_run_as_user,
-            Key_se_linux_options,
-            Other,
-        }
+impl SecurityContext {
+    /// Returns this value with [`SecurityContext::allow_privilege_escalation`] set to the given value.
+    #[must_use]
+    pub fn with_allow_privilege_escalation(mut self, value: impl Into<bool>) -> Self {
+        self.allow_privilege_escalation = Some(value.into());
+        self
+    }
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+    /// Returns this value with [`SecurityContext::capabilities`] set to the given value.
+    #[must_use]
+    pub fn with_capabilities(mut self, value: impl Into<crate::api::core::v1::Capabilities>) -> Self {
+        self.capabilities = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`SecurityContext::privileged`] set to the given value.
+    #[must_use]
+    pub fn with_privileged(mut self, value: impl Into<bool>) -> Self {
+        self.privileged = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`SecurityContext::read_only_root_filesystem`] set to the given value.
+    #[must_use]
+    pub fn with_read_only_root_filesystem(mut self, value: impl Into<bool>) -> Self {
+        self.read_only_root_filesystem = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "allowPrivilegeEscalation" => Field::Key_allow_privilege_escalation,
-                            "capabilities" => Field::Key_capabilities,
-                            "privileged" => Field::Key_privileged,
-                            "readOnlyRootFilesystem" => Field::Key_read_only_root_filesystem,
-                            "runAsGroup" => Field::Key_run_as_group,
-                            "runAsNonRoot" => Field::Key_run_as_non_root,
-                            "runAsUser" => Field::Key_run_as_user,
-                            "seLinuxOptions" => Field::Key_se_linux_options,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`SecurityContext::run_as_group`] set to the given value.
+    #[must_use]
+    pub fn with_run_as_group(mut self, value: impl Into<i64>) -> Self {
+        self.run_as_group = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`SecurityContext::run_as_non_root`] set to the given value.
+    #[must_use]
+    pub fn with_run_as_non_root(mut self, value: impl Into<bool>) -> Self {
+        self.run_as_non_root = Some(value.into());
+        self
+    }
 
+    /// Returns this value with [`SecurityContext::run_as_user`] set to the given value.
+    #[must_use]
+    pub fn with_run_as_user(mut self, value: impl Into<i64>) -> Self {
+        self.run_as_user = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`SecurityContext::se_linux_options`] set to the given value.
+    #[must_use]
+    pub fn with_se_linux_options(mut self, value: impl Into<crate::api::core::v1::SELinuxOptions>) -> Self {
+        self.se_linux_options = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for SecurityContext {}
+
+impl SecurityContext {
+    const FIELDS: &'static [&'static str] = &[
+        "allowPrivilegeEscalation",
+        "capabilities",
+        "privileged",
+        "readOnlyRootFilesystem",
+        "runAsGroup",
+        "runAsNonRoot",
+        "runAsUser",
+        "seLinuxOptions",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for SecurityContext {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -92,17 +122,17 @@ impl<'de> crate::serde::Deserialize<'de> for SecurityContext {
                 let mut value_run_as_user: Option<i64> = None;
                 let mut value_se_linux_options: Option<crate::api::core::v1::SELinuxOptions> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("SecurityContext", SecurityContext::FIELDS))? {
                     match key {
-                        Field::Key_allow_privilege_escalation => value_allow_privilege_escalation = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_capabilities => value_capabilities = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_privileged => value_privileged = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_read_only_root_filesystem => value_read_only_root_filesystem = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_run_as_group => value_run_as_group = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_run_as_non_root => value_run_as_non_root = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_run_as_user => value_run_as_user = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_se_linux_options => value_se_linux_options = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_allow_privilege_escalation = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_capabilities = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_privileged = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_read_only_root_filesystem = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_run_as_group = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_run_as_non_root = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_run_as_user = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(7) => value_se_linux_options = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -117,27 +147,126 @@ impl<'de> crate::serde::Deserialize<'de> for SecurityContext {
                     se_linux_options: value_se_linux_options,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(SecurityContext {
+                    allow_privilege_escalation: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    capabilities: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    privileged: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    read_only_root_filesystem: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    run_as_group: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    run_as_non_root: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    run_as_user: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    se_linux_options: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "SecurityContext",
-            &[
-                "allowPrivilegeEscalation",
-                "capabilities",
-                "privileged",
-                "readOnlyRootFilesystem",
-                "runAsGroup",
-                "runAsNonRoot",
-                "runAsUser",
-                "seLinuxOptions",
-            ],
+            SecurityContext::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, SecurityContext> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("SecurityContext")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_allow_privilege_escalation = false;
+                let mut has_capabilities = false;
+                let mut has_privileged = false;
+                let mut has_read_only_root_filesystem = false;
+                let mut has_run_as_group = false;
+                let mut has_run_as_non_root = false;
+                let mut has_run_as_user = false;
+                let mut has_se_linux_options = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("SecurityContext", SecurityContext::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.allow_privilege_escalation))?; has_allow_privilege_escalation = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.capabilities))?; has_capabilities = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.privileged))?; has_privileged = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.read_only_root_filesystem))?; has_read_only_root_filesystem = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.run_as_group))?; has_run_as_group = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.run_as_non_root))?; has_run_as_non_root = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.run_as_user))?; has_run_as_user = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.se_linux_options))?; has_se_linux_options = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_allow_privilege_escalation {
+                    self.0.allow_privilege_escalation = None;
+                }
+                if !has_capabilities {
+                    self.0.capabilities = None;
+                }
+                if !has_privileged {
+                    self.0.privileged = None;
+                }
+                if !has_read_only_root_filesystem {
+                    self.0.read_only_root_filesystem = None;
+                }
+                if !has_run_as_group {
+                    self.0.run_as_group = None;
+                }
+                if !has_run_as_non_root {
+                    self.0.run_as_non_root = None;
+                }
+                if !has_run_as_user {
+                    self.0.run_as_user = None;
+                }
+                if !has_se_linux_options {
+                    self.0.se_linux_options = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.allow_privilege_escalation))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.capabilities))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.privileged))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.read_only_root_filesystem))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.run_as_group))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.run_as_non_root))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.run_as_user))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.se_linux_options))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "SecurityContext",
+            SecurityContext::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for SecurityContext {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("SecurityContext", 8)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "allowPrivilegeEscalation", &self.allow_privilege_escalation)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "capabilities", &self.capabilities)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "privileged", &self.privileged)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnlyRootFilesystem", &self.read_only_root_filesystem)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "runAsGroup", &self.run_as_group)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "runAsNonRoot", &self.run_as_non_root)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "runAsUser", &self.run_as_user)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "seLinuxOptions", &self.se_linux_options)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "SecurityContext",
             self.allow_privilege_escalation.as_ref().map_or(0, |_| 1) +
@@ -177,10 +306,174 @@ impl crate::serde::Serialize for SecurityContext {
     }
 }
 
+/// A builder of [`SecurityContext`]. Created with [`SecurityContext::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct SecurityContextBuilder {
+    allow_privilege_escalation: Option<bool>,
+    capabilities: Option<crate::api::core::v1::Capabilities>,
+    privileged: Option<bool>,
+    read_only_root_filesystem: Option<bool>,
+    run_as_group: Option<i64>,
+    run_as_non_root: Option<bool>,
+    run_as_user: Option<i64>,
+    se_linux_options: Option<crate::api::core::v1::SELinuxOptions>,
+}
+
+#[cfg(feature = "builder")]
+impl SecurityContext {
+    /// Starts building a `SecurityContext`. Its required fields must be set before [`SecurityContextBuilder::build`] is called.
+    pub fn builder() -> SecurityContextBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl SecurityContextBuilder {
+    /// Sets [`SecurityContext::allow_privilege_escalation`].
+    pub fn allow_privilege_escalation(mut self, value: impl Into<bool>) -> Self {
+        self.allow_privilege_escalation = Some(value.into());
+        self
+    }
+
+    /// Sets [`SecurityContext::capabilities`].
+    pub fn capabilities(mut self, value: impl Into<crate::api::core::v1::Capabilities>) -> Self {
+        self.capabilities = Some(value.into());
+        self
+    }
+
+    /// Sets [`SecurityContext::privileged`].
+    pub fn privileged(mut self, value: impl Into<bool>) -> Self {
+        self.privileged = Some(value.into());
+        self
+    }
+
+    /// Sets [`SecurityContext::read_only_root_filesystem`].
+    pub fn read_only_root_filesystem(mut self, value: impl Into<bool>) -> Self {
+        self.read_only_root_filesystem = Some(value.into());
+        self
+    }
+
+    /// Sets [`SecurityContext::run_as_group`].
+    pub fn run_as_group(mut self, value: impl Into<i64>) -> Self {
+        self.run_as_group = Some(value.into());
+        self
+    }
+
+    /// Sets [`SecurityContext::run_as_non_root`].
+    pub fn run_as_non_root(mut self, value: impl Into<bool>) -> Self {
+        self.run_as_non_root = Some(value.into());
+        self
+    }
+
+    /// Sets [`SecurityContext::run_as_user`].
+    pub fn run_as_user(mut self, value: impl Into<i64>) -> Self {
+        self.run_as_user = Some(value.into());
+        self
+    }
+
+    /// Sets [`SecurityContext::se_linux_options`].
+    pub fn se_linux_options(mut self, value: impl Into<crate::api::core::v1::SELinuxOptions>) -> Self {
+        self.se_linux_options = Some(value.into());
+        self
+    }
+
+    /// Builds the `SecurityContext`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<SecurityContext, crate::MissingFieldError> {
+        Ok(SecurityContext {
+            allow_privilege_escalation: self.allow_privilege_escalation,
+            capabilities: self.capabilities,
+            privileged: self.privileged,
+            read_only_root_filesystem: self.read_only_root_filesystem,
+            run_as_group: self.run_as_group,
+            run_as_non_root: self.run_as_non_root,
+            run_as_user: self.run_as_user,
+            se_linux_options: self.se_linux_options,
+        })
+    }
+}
+
+/// The apply configuration of [`SecurityContext`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SecurityContextApplyConfiguration {
+    /// See [`SecurityContext::allow_privilege_escalation`].
+    pub allow_privilege_escalation: Option<bool>,
+
+    /// See [`SecurityContext::capabilities`].
+    pub capabilities: Option<crate::api::core::v1::CapabilitiesApplyConfiguration>,
+
+    /// See [`SecurityContext::privileged`].
+    pub privileged: Option<bool>,
+
+    /// See [`SecurityContext::read_only_root_filesystem`].
+    pub read_only_root_filesystem: Option<bool>,
+
+    /// See [`SecurityContext::run_as_group`].
+    pub run_as_group: Option<i64>,
+
+    /// See [`SecurityContext::run_as_non_root`].
+    pub run_as_non_root: Option<bool>,
+
+    /// See [`SecurityContext::run_as_user`].
+    pub run_as_user: Option<i64>,
+
+    /// See [`SecurityContext::se_linux_options`].
+    pub se_linux_options: Option<crate::api::core::v1::SELinuxOptionsApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for SecurityContextApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "SecurityContextApplyConfiguration",
+            self.allow_privilege_escalation.as_ref().map_or(0, |_| 1) +
+            self.capabilities.as_ref().map_or(0, |_| 1) +
+            self.privileged.as_ref().map_or(0, |_| 1) +
+            self.read_only_root_filesystem.as_ref().map_or(0, |_| 1) +
+            self.run_as_group.as_ref().map_or(0, |_| 1) +
+            self.run_as_non_root.as_ref().map_or(0, |_| 1) +
+            self.run_as_user.as_ref().map_or(0, |_| 1) +
+            self.se_linux_options.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.allow_privilege_escalation {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "allowPrivilegeEscalation", value)?;
+        }
+        if let Some(value) = &self.capabilities {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "capabilities", value)?;
+        }
+        if let Some(value) = &self.privileged {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "privileged", value)?;
+        }
+        if let Some(value) = &self.read_only_root_filesystem {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnlyRootFilesystem", value)?;
+        }
+        if let Some(value) = &self.run_as_group {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "runAsGroup", value)?;
+        }
+        if let Some(value) = &self.run_as_non_root {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "runAsNonRoot", value)?;
+        }
+        if let Some(value) = &self.run_as_user {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "runAsUser", value)?;
+        }
+        if let Some(value) = &self.se_linux_options {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "seLinuxOptions", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for SecurityContext {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.SecurityContext".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::SecurityContext".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.SecurityContext".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -191,8 +484,9 @@ impl crate::schemars::JsonSchema for SecurityContext {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "allowPrivilegeEscalation".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -202,8 +496,8 @@ impl crate::schemars::JsonSchema for SecurityContext {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "capabilities".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::Capabilities>().into_object();
@@ -213,8 +507,8 @@ impl crate::schemars::JsonSchema for SecurityContext {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "privileged".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -224,8 +518,8 @@ impl crate::schemars::JsonSchema for SecurityContext {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "readOnlyRootFilesystem".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -235,8 +529,8 @@ impl crate::schemars::JsonSchema for SecurityContext {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "runAsGroup".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -247,8 +541,8 @@ impl crate::schemars::JsonSchema for SecurityContext {
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "runAsNonRoot".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -258,8 +552,8 @@ impl crate::schemars::JsonSchema for SecurityContext {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "runAsUser".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -270,8 +564,8 @@ impl crate::schemars::JsonSchema for SecurityContext {
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "seLinuxOptions".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::SELinuxOptions>().into_object();
@@ -281,11 +575,28 @@ impl crate::schemars::JsonSchema for SecurityContext {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for SecurityContext {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(SecurityContext {
+            allow_privilege_escalation: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            capabilities: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            privileged: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            read_only_root_filesystem: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            run_as_group: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            run_as_non_root: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            run_as_user: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            se_linux_options: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
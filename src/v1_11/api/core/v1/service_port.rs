@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.ServicePort
 
 /// ServicePort contains information on service's port.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ServicePort {
     /// The name of this port within the service. This must be a DNS_LABEL. All ports within a ServiceSpec must have unique names. This maps to the 'Name' field in EndpointPort objects. Optional if only one ServicePort is defined on this service.
     pub name: Option<String>,
@@ -13,51 +13,76 @@ pub struct ServicePort {
     pub port: i32,
 
     /// The IP protocol for this port. Supports "TCP" and "UDP". Default is TCP.
-    pub protocol: Option<String>,
+    pub protocol: Option<crate::api::core::v1::Protocol>,
 
     /// Number or name of the port to access on the pods targeted by the service. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME. If this is a string, it will be looked up as a named port in the target Pod's container ports. If this is not specified, the value of the 'port' field is used (an identity map). This field is ignored for services with clusterIP=None, and should be omitted or set equal to the 'port' field. More info: https://kubernetes.io/docs/concepts/services-networking/service/#defining-a-service
     pub target_port: Option<crate::apimachinery::pkg::util::intstr::IntOrString>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ServicePort {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_name,
-            Key_node_port,
-            Key_port,
-            Key_protocol,
-            Key_target_port,
-            Other,
+impl ServicePort {
+    /// Constructs a `ServicePort` from its required fields. Its optional fields are unset.
+    pub fn new(port: impl Into<i32>) -> Self {
+        ServicePort {
+            name: None,
+            node_port: None,
+            port: port.into(),
+            protocol: None,
+            target_port: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl ServicePort {
+    /// Returns this value with [`ServicePort::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ServicePort::node_port`] set to the given value.
+    #[must_use]
+    pub fn with_node_port(mut self, value: impl Into<i32>) -> Self {
+        self.node_port = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ServicePort::port`] set to the given value.
+    #[must_use]
+    pub fn with_port(mut self, value: impl Into<i32>) -> Self {
+        self.port = value.into();
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "name" => Field::Key_name,
-                            "nodePort" => Field::Key_node_port,
-                            "port" => Field::Key_port,
-                            "protocol" => Field::Key_protocol,
-                            "targetPort" => Field::Key_target_port,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`ServicePort::protocol`] set to the given value.
+    #[must_use]
+    pub fn with_protocol(mut self, value: impl Into<crate::api::core::v1::Protocol>) -> Self {
+        self.protocol = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`ServicePort::target_port`] set to the given value.
+    #[must_use]
+    pub fn with_target_port(mut self, value: impl Into<crate::apimachinery::pkg::util::intstr::IntOrString>) -> Self {
+        self.target_port = Some(value.into());
+        self
+    }
+}
 
+impl crate::MergeStrategyInfo for ServicePort {}
+
+impl ServicePort {
+    const FIELDS: &'static [&'static str] = &[
+        "name",
+        "nodePort",
+        "port",
+        "protocol",
+        "targetPort",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for ServicePort {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -71,46 +96,124 @@ impl<'de> crate::serde::Deserialize<'de> for ServicePort {
                 let mut value_name: Option<String> = None;
                 let mut value_node_port: Option<i32> = None;
                 let mut value_port: Option<i32> = None;
-                let mut value_protocol: Option<String> = None;
+                let mut value_protocol: Option<crate::api::core::v1::Protocol> = None;
                 let mut value_target_port: Option<crate::apimachinery::pkg::util::intstr::IntOrString> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ServicePort", ServicePort::FIELDS))? {
                     match key {
-                        Field::Key_name => value_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_node_port => value_node_port = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_port => value_port = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_protocol => value_protocol = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_target_port => value_target_port = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_node_port = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_port = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_protocol = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_target_port = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ServicePort {
                     name: value_name,
                     node_port: value_node_port,
-                    port: value_port.ok_or_else(|| crate::serde::de::Error::missing_field("port"))?,
+                    port: value_port.ok_or_else(|| crate::serde2::missing_field("ServicePort", "port", &["port"]))?,
                     protocol: value_protocol,
                     target_port: value_target_port,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ServicePort {
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    node_port: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    port: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    protocol: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    target_port: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "ServicePort",
-            &[
-                "name",
-                "nodePort",
-                "port",
-                "protocol",
-                "targetPort",
-            ],
+            ServicePort::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ServicePort> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ServicePort")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_name = false;
+                let mut has_node_port = false;
+                let mut has_port = false;
+                let mut has_protocol = false;
+                let mut has_target_port = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ServicePort", ServicePort::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.name))?; has_name = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.node_port))?; has_node_port = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.port))?; has_port = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.protocol))?; has_protocol = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.target_port))?; has_target_port = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_name {
+                    self.0.name = None;
+                }
+                if !has_node_port {
+                    self.0.node_port = None;
+                }
+                if !has_port {
+                    return Err(crate::serde2::missing_field("ServicePort", "port", &["port"]));
+                }
+                if !has_protocol {
+                    self.0.protocol = None;
+                }
+                if !has_target_port {
+                    self.0.target_port = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.node_port))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.port))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.protocol))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.target_port))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ServicePort",
+            ServicePort::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ServicePort {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ServicePort", 5)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodePort", &self.node_port)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "port", &self.port)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "protocol", &self.protocol)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "targetPort", &self.target_port)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ServicePort",
             1 +
@@ -136,10 +239,129 @@ impl crate::serde::Serialize for ServicePort {
     }
 }
 
+/// A builder of [`ServicePort`]. Created with [`ServicePort::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ServicePortBuilder {
+    name: Option<String>,
+    node_port: Option<i32>,
+    port: Option<i32>,
+    protocol: Option<crate::api::core::v1::Protocol>,
+    target_port: Option<crate::apimachinery::pkg::util::intstr::IntOrString>,
+}
+
+#[cfg(feature = "builder")]
+impl ServicePort {
+    /// Starts building a `ServicePort`. Its required fields must be set before [`ServicePortBuilder::build`] is called.
+    pub fn builder() -> ServicePortBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ServicePortBuilder {
+    /// Sets [`ServicePort::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`ServicePort::node_port`].
+    pub fn node_port(mut self, value: impl Into<i32>) -> Self {
+        self.node_port = Some(value.into());
+        self
+    }
+
+    /// Sets [`ServicePort::port`].
+    pub fn port(mut self, value: impl Into<i32>) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets [`ServicePort::protocol`].
+    pub fn protocol(mut self, value: impl Into<crate::api::core::v1::Protocol>) -> Self {
+        self.protocol = Some(value.into());
+        self
+    }
+
+    /// Sets [`ServicePort::target_port`].
+    pub fn target_port(mut self, value: impl Into<crate::apimachinery::pkg::util::intstr::IntOrString>) -> Self {
+        self.target_port = Some(value.into());
+        self
+    }
+
+    /// Builds the `ServicePort`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ServicePort, crate::MissingFieldError> {
+        Ok(ServicePort {
+            name: self.name,
+            node_port: self.node_port,
+            port: self.port.ok_or_else(|| crate::MissingFieldError::new("ServicePort", "port"))?,
+            protocol: self.protocol,
+            target_port: self.target_port,
+        })
+    }
+}
+
+/// The apply configuration of [`ServicePort`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ServicePortApplyConfiguration {
+    /// See [`ServicePort::name`].
+    pub name: Option<String>,
+
+    /// See [`ServicePort::node_port`].
+    pub node_port: Option<i32>,
+
+    /// See [`ServicePort::port`].
+    pub port: Option<i32>,
+
+    /// See [`ServicePort::protocol`].
+    pub protocol: Option<crate::api::core::v1::Protocol>,
+
+    /// See [`ServicePort::target_port`].
+    pub target_port: Option<crate::apimachinery::pkg::util::intstr::IntOrString>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ServicePortApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ServicePortApplyConfiguration",
+            self.name.as_ref().map_or(0, |_| 1) +
+            self.node_port.as_ref().map_or(0, |_| 1) +
+            self.port.as_ref().map_or(0, |_| 1) +
+            self.protocol.as_ref().map_or(0, |_| 1) +
+            self.target_port.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        if let Some(value) = &self.node_port {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodePort", value)?;
+        }
+        if let Some(value) = &self.port {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "port", value)?;
+        }
+        if let Some(value) = &self.protocol {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "protocol", value)?;
+        }
+        if let Some(value) = &self.target_port {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "targetPort", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ServicePort {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.ServicePort".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::ServicePort".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.ServicePort".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -150,8 +372,9 @@ impl crate::schemars::JsonSchema for ServicePort {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -161,8 +384,8 @@ impl crate::schemars::JsonSchema for ServicePort {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "nodePort".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -173,8 +396,8 @@ impl crate::schemars::JsonSchema for ServicePort {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "port".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -185,19 +408,19 @@ impl crate::schemars::JsonSchema for ServicePort {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "protocol".to_owned(),
-                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
-                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::Protocol>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
                                 description: Some("The IP protocol for this port. Supports \"TCP\" and \"UDP\". Default is TCP.".to_owned()),
                                 ..Default::default()
-                            })),
-                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
-                            ..Default::default()
-                        }),
-                    ),
-                    (
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
                         "targetPort".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::util::intstr::IntOrString>().into_object();
@@ -207,8 +430,9 @@ impl crate::schemars::JsonSchema for ServicePort {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "port",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -218,3 +442,16 @@ impl crate::schemars::JsonSchema for ServicePort {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ServicePort {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ServicePort {
+            name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            node_port: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            port: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            protocol: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            target_port: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.ServiceSpec
 
 /// ServiceSpec describes the attributes that a user creates on a service.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ServiceSpec {
     /// clusterIP is the IP address of the service and is usually assigned randomly by the master. If an address is specified manually and is not in use by others, it will be allocated to the service; otherwise, creation of the service will fail. This field can not be changed through updates. Valid values are "None", empty string (""), or a valid IP address. "None" can be specified for headless services when proxying is not required. Only applies to types ClusterIP, NodePort, and LoadBalancer. Ignored if type is ExternalName. More info: https://kubernetes.io/docs/concepts/services-networking/service/#virtual-ips-and-service-proxies
     pub cluster_ip: Option<String>,
@@ -13,7 +13,7 @@ pub struct ServiceSpec {
     pub external_name: Option<String>,
 
     /// externalTrafficPolicy denotes if this Service desires to route external traffic to node-local or cluster-wide endpoints. "Local" preserves the client source IP and avoids a second hop for LoadBalancer and Nodeport type services, but risks potentially imbalanced traffic spreading. "Cluster" obscures the client source IP and may cause a second hop to another node, but should have good overall load-spreading.
-    pub external_traffic_policy: Option<String>,
+    pub external_traffic_policy: Option<crate::api::core::v1::ServiceExternalTrafficPolicyType>,
 
     /// healthCheckNodePort specifies the healthcheck nodePort for the service. If not specified, HealthCheckNodePort is created by the service api backend with the allocated nodePort. Will use user-specified nodePort value if specified by the client. Only effects when Type is set to LoadBalancer and ExternalTrafficPolicy is set to Local.
     pub health_check_node_port: Option<i32>,
@@ -34,70 +34,134 @@ pub struct ServiceSpec {
     pub selector: Option<std::collections::BTreeMap<String, String>>,
 
     /// Supports "ClientIP" and "None". Used to maintain session affinity. Enable client IP based session affinity. Must be ClientIP or None. Defaults to None. More info: https://kubernetes.io/docs/concepts/services-networking/service/#virtual-ips-and-service-proxies
-    pub session_affinity: Option<String>,
+    pub session_affinity: Option<crate::api::core::v1::ServiceAffinity>,
 
     /// sessionAffinityConfig contains the configurations of session affinity.
     pub session_affinity_config: Option<crate::api::core::v1::SessionAffinityConfig>,
 
     /// type determines how the Service is exposed. Defaults to ClusterIP. Valid options are ExternalName, ClusterIP, NodePort, and LoadBalancer. "ExternalName" maps to the specified externalName. "ClusterIP" allocates a cluster-internal IP address for load-balancing to endpoints. Endpoints are determined by the selector or if that is not specified, by manual construction of an Endpoints object. If clusterIP is "None", no virtual IP is allocated and the endpoints are published as a set of endpoints rather than a stable IP. "NodePort" builds on ClusterIP and allocates a port on every node which routes to the clusterIP. "LoadBalancer" builds on NodePort and creates an external load-balancer (if supported in the current cloud) which routes to the clusterIP. More info: https://kubernetes.io/docs/concepts/services-networking/service/#publishing-services---service-types
-    pub type_: Option<String>,
+    pub type_: Option<crate::api::core::v1::ServiceType>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ServiceSpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_cluster_ip,
-            Key_external_ips,
-            Key_external_name,
-            Key_external_traffic_policy,
-            Key_health_check_node_port,
-            Key_load_balancer_ip,
-            Key_load_balancer_source_ranges,
-            Key_ports,
-            Key_publish_not_ready_addresses,
-            Key_selector,
-            Key_session_affinity,
-            Key_session_affinity_config,
-            Key_type_,
-            Other,
-        }
+impl ServiceSpec {
+    /// Returns this value with [`ServiceSpec::cluster_ip`] set to the given value.
+    #[must_use]
+    pub fn with_cluster_ip(mut self, value: impl Into<String>) -> Self {
+        self.cluster_ip = Some(value.into());
+        self
+    }
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+    /// Returns this value with [`ServiceSpec::external_ips`] set to the given value.
+    #[must_use]
+    pub fn with_external_ips(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.external_ips = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ServiceSpec::external_name`] set to the given value.
+    #[must_use]
+    pub fn with_external_name(mut self, value: impl Into<String>) -> Self {
+        self.external_name = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ServiceSpec::external_traffic_policy`] set to the given value.
+    #[must_use]
+    pub fn with_external_traffic_policy(mut self, value: impl Into<crate::api::core::v1::ServiceExternalTrafficPolicyType>) -> Self {
+        self.external_traffic_policy = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "clusterIP" => Field::Key_cluster_ip,
-                            "externalIPs" => Field::Key_external_ips,
-                            "externalName" => Field::Key_external_name,
-                            "externalTrafficPolicy" => Field::Key_external_traffic_policy,
-                            "healthCheckNodePort" => Field::Key_health_check_node_port,
-                            "loadBalancerIP" => Field::Key_load_balancer_ip,
-                            "loadBalancerSourceRanges" => Field::Key_load_balancer_source_ranges,
-                            "ports" => Field::Key_ports,
-                            "publishNotReadyAddresses" => Field::Key_publish_not_ready_addresses,
-                            "selector" => Field::Key_selector,
-                            "sessionAffinity" => Field::Key_session_affinity,
-                            "sessionAffinityConfig" => Field::Key_session_affinity_config,
-                            "type" => Field::Key_type_,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`ServiceSpec::health_check_node_port`] set to the given value.
+    #[must_use]
+    pub fn with_health_check_node_port(mut self, value: impl Into<i32>) -> Self {
+        self.health_check_node_port = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`ServiceSpec::load_balancer_ip`] set to the given value.
+    #[must_use]
+    pub fn with_load_balancer_ip(mut self, value: impl Into<String>) -> Self {
+        self.load_balancer_ip = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ServiceSpec::load_balancer_source_ranges`] set to the given value.
+    #[must_use]
+    pub fn with_load_balancer_source_ranges(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.load_balancer_source_ranges = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ServiceSpec::ports`] set to the given value.
+    #[must_use]
+    pub fn with_ports(mut self, value: impl Into<Vec<crate::api::core::v1::ServicePort>>) -> Self {
+        self.ports = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ServiceSpec::publish_not_ready_addresses`] set to the given value.
+    #[must_use]
+    pub fn with_publish_not_ready_addresses(mut self, value: impl Into<bool>) -> Self {
+        self.publish_not_ready_addresses = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ServiceSpec::selector`] set to the given value.
+    #[must_use]
+    pub fn with_selector(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.selector = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ServiceSpec::session_affinity`] set to the given value.
+    #[must_use]
+    pub fn with_session_affinity(mut self, value: impl Into<crate::api::core::v1::ServiceAffinity>) -> Self {
+        self.session_affinity = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ServiceSpec::session_affinity_config`] set to the given value.
+    #[must_use]
+    pub fn with_session_affinity_config(mut self, value: impl Into<crate::api::core::v1::SessionAffinityConfig>) -> Self {
+        self.session_affinity_config = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ServiceSpec::type_`] set to the given value.
+    #[must_use]
+    pub fn with_type(mut self, value: impl Into<crate::api::core::v1::ServiceType>) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for ServiceSpec {
+    const FIELD_PATCH_STRATEGIES: &'static [crate::FieldPatchStrategy] = &[
+        crate::FieldPatchStrategy { field: "ports", merge: true, retain_keys: false, merge_key: Some("port") },
+    ];
+}
+
+impl ServiceSpec {
+    const FIELDS: &'static [&'static str] = &[
+        "clusterIP",
+        "externalIPs",
+        "externalName",
+        "externalTrafficPolicy",
+        "healthCheckNodePort",
+        "loadBalancerIP",
+        "loadBalancerSourceRanges",
+        "ports",
+        "publishNotReadyAddresses",
+        "selector",
+        "sessionAffinity",
+        "sessionAffinityConfig",
+        "type",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ServiceSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -111,33 +175,33 @@ impl<'de> crate::serde::Deserialize<'de> for ServiceSpec {
                 let mut value_cluster_ip: Option<String> = None;
                 let mut value_external_ips: Option<Vec<String>> = None;
                 let mut value_external_name: Option<String> = None;
-                let mut value_external_traffic_policy: Option<String> = None;
+                let mut value_external_traffic_policy: Option<crate::api::core::v1::ServiceExternalTrafficPolicyType> = None;
                 let mut value_health_check_node_port: Option<i32> = None;
                 let mut value_load_balancer_ip: Option<String> = None;
                 let mut value_load_balancer_source_ranges: Option<Vec<String>> = None;
                 let mut value_ports: Option<Vec<crate::api::core::v1::ServicePort>> = None;
                 let mut value_publish_not_ready_addresses: Option<bool> = None;
                 let mut value_selector: Option<std::collections::BTreeMap<String, String>> = None;
-                let mut value_session_affinity: Option<String> = None;
+                let mut value_session_affinity: Option<crate::api::core::v1::ServiceAffinity> = None;
                 let mut value_session_affinity_config: Option<crate::api::core::v1::SessionAffinityConfig> = None;
-                let mut value_type_: Option<String> = None;
+                let mut value_type_: Option<crate::api::core::v1::ServiceType> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ServiceSpec", ServiceSpec::FIELDS))? {
                     match key {
-                        Field::Key_cluster_ip => value_cluster_ip = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_external_ips => value_external_ips = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_external_name => value_external_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_external_traffic_policy => value_external_traffic_policy = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_health_check_node_port => value_health_check_node_port = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_load_balancer_ip => value_load_balancer_ip = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_load_balancer_source_ranges => value_load_balancer_source_ranges = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_ports => value_ports = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_publish_not_ready_addresses => value_publish_not_ready_addresses = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_selector => value_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_session_affinity => value_session_affinity = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_session_affinity_config => value_session_affinity_config = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_type_ => value_type_ = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_cluster_ip = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_external_ips = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_external_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_external_traffic_policy = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_health_check_node_port = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_load_balancer_ip = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_load_balancer_source_ranges = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(7) => value_ports = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(8) => value_publish_not_ready_addresses = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(9) => value_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(10) => value_session_affinity = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(11) => value_session_affinity_config = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(12) => value_type_ = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -157,32 +221,166 @@ impl<'de> crate::serde::Deserialize<'de> for ServiceSpec {
                     type_: value_type_,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ServiceSpec {
+                    cluster_ip: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    external_ips: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    external_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    external_traffic_policy: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    health_check_node_port: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    load_balancer_ip: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    load_balancer_source_ranges: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    ports: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                    publish_not_ready_addresses: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?,
+                    selector: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?,
+                    session_affinity: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?,
+                    session_affinity_config: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(11, &self))?,
+                    type_: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(12, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "ServiceSpec",
-            &[
-                "clusterIP",
-                "externalIPs",
-                "externalName",
-                "externalTrafficPolicy",
-                "healthCheckNodePort",
-                "loadBalancerIP",
-                "loadBalancerSourceRanges",
-                "ports",
-                "publishNotReadyAddresses",
-                "selector",
-                "sessionAffinity",
-                "sessionAffinityConfig",
-                "type",
-            ],
+            ServiceSpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ServiceSpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ServiceSpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_cluster_ip = false;
+                let mut has_external_ips = false;
+                let mut has_external_name = false;
+                let mut has_external_traffic_policy = false;
+                let mut has_health_check_node_port = false;
+                let mut has_load_balancer_ip = false;
+                let mut has_load_balancer_source_ranges = false;
+                let mut has_ports = false;
+                let mut has_publish_not_ready_addresses = false;
+                let mut has_selector = false;
+                let mut has_session_affinity = false;
+                let mut has_session_affinity_config = false;
+                let mut has_type_ = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ServiceSpec", ServiceSpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.cluster_ip))?; has_cluster_ip = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.external_ips))?; has_external_ips = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.external_name))?; has_external_name = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.external_traffic_policy))?; has_external_traffic_policy = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.health_check_node_port))?; has_health_check_node_port = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.load_balancer_ip))?; has_load_balancer_ip = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.load_balancer_source_ranges))?; has_load_balancer_source_ranges = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.ports))?; has_ports = true; },
+                        Some(8) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.publish_not_ready_addresses))?; has_publish_not_ready_addresses = true; },
+                        Some(9) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.selector))?; has_selector = true; },
+                        Some(10) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.session_affinity))?; has_session_affinity = true; },
+                        Some(11) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.session_affinity_config))?; has_session_affinity_config = true; },
+                        Some(12) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.type_))?; has_type_ = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_cluster_ip {
+                    self.0.cluster_ip = None;
+                }
+                if !has_external_ips {
+                    self.0.external_ips = None;
+                }
+                if !has_external_name {
+                    self.0.external_name = None;
+                }
+                if !has_external_traffic_policy {
+                    self.0.external_traffic_policy = None;
+                }
+                if !has_health_check_node_port {
+                    self.0.health_check_node_port = None;
+                }
+                if !has_load_balancer_ip {
+                    self.0.load_balancer_ip = None;
+                }
+                if !has_load_balancer_source_ranges {
+                    self.0.load_balancer_source_ranges = None;
+                }
+                if !has_ports {
+                    self.0.ports = None;
+                }
+                if !has_publish_not_ready_addresses {
+                    self.0.publish_not_ready_addresses = None;
+                }
+                if !has_selector {
+                    self.0.selector = None;
+                }
+                if !has_session_affinity {
+                    self.0.session_affinity = None;
+                }
+                if !has_session_affinity_config {
+                    self.0.session_affinity_config = None;
+                }
+                if !has_type_ {
+                    self.0.type_ = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.cluster_ip))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.external_ips))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.external_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.external_traffic_policy))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.health_check_node_port))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.load_balancer_ip))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.load_balancer_source_ranges))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.ports))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.publish_not_ready_addresses))?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.selector))?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.session_affinity))?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.session_affinity_config))?.ok_or_else(|| crate::serde::de::Error::invalid_length(11, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.type_))?.ok_or_else(|| crate::serde::de::Error::invalid_length(12, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ServiceSpec",
+            ServiceSpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ServiceSpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ServiceSpec", 13)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clusterIP", &self.cluster_ip)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "externalIPs", &self.external_ips)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "externalName", &self.external_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "externalTrafficPolicy", &self.external_traffic_policy)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "healthCheckNodePort", &self.health_check_node_port)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "loadBalancerIP", &self.load_balancer_ip)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "loadBalancerSourceRanges", &self.load_balancer_source_ranges)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ports", &self.ports)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "publishNotReadyAddresses", &self.publish_not_ready_addresses)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selector", &self.selector)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "sessionAffinity", &self.session_affinity)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "sessionAffinityConfig", &self.session_affinity_config)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "type", &self.type_)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ServiceSpec",
             self.cluster_ip.as_ref().map_or(0, |_| 1) +
@@ -242,10 +440,249 @@ impl crate::serde::Serialize for ServiceSpec {
     }
 }
 
+/// A builder of [`ServiceSpec`]. Created with [`ServiceSpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ServiceSpecBuilder {
+    cluster_ip: Option<String>,
+    external_ips: Option<Vec<String>>,
+    external_name: Option<String>,
+    external_traffic_policy: Option<crate::api::core::v1::ServiceExternalTrafficPolicyType>,
+    health_check_node_port: Option<i32>,
+    load_balancer_ip: Option<String>,
+    load_balancer_source_ranges: Option<Vec<String>>,
+    ports: Option<Vec<crate::api::core::v1::ServicePort>>,
+    publish_not_ready_addresses: Option<bool>,
+    selector: Option<std::collections::BTreeMap<String, String>>,
+    session_affinity: Option<crate::api::core::v1::ServiceAffinity>,
+    session_affinity_config: Option<crate::api::core::v1::SessionAffinityConfig>,
+    type_: Option<crate::api::core::v1::ServiceType>,
+}
+
+#[cfg(feature = "builder")]
+impl ServiceSpec {
+    /// Starts building a `ServiceSpec`. Its required fields must be set before [`ServiceSpecBuilder::build`] is called.
+    pub fn builder() -> ServiceSpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ServiceSpecBuilder {
+    /// Sets [`ServiceSpec::cluster_ip`].
+    pub fn cluster_ip(mut self, value: impl Into<String>) -> Self {
+        self.cluster_ip = Some(value.into());
+        self
+    }
+
+    /// Sets [`ServiceSpec::external_ips`].
+    pub fn external_ips(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.external_ips = Some(value.into());
+        self
+    }
+
+    /// Sets [`ServiceSpec::external_name`].
+    pub fn external_name(mut self, value: impl Into<String>) -> Self {
+        self.external_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`ServiceSpec::external_traffic_policy`].
+    pub fn external_traffic_policy(mut self, value: impl Into<crate::api::core::v1::ServiceExternalTrafficPolicyType>) -> Self {
+        self.external_traffic_policy = Some(value.into());
+        self
+    }
+
+    /// Sets [`ServiceSpec::health_check_node_port`].
+    pub fn health_check_node_port(mut self, value: impl Into<i32>) -> Self {
+        self.health_check_node_port = Some(value.into());
+        self
+    }
+
+    /// Sets [`ServiceSpec::load_balancer_ip`].
+    pub fn load_balancer_ip(mut self, value: impl Into<String>) -> Self {
+        self.load_balancer_ip = Some(value.into());
+        self
+    }
+
+    /// Sets [`ServiceSpec::load_balancer_source_ranges`].
+    pub fn load_balancer_source_ranges(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.load_balancer_source_ranges = Some(value.into());
+        self
+    }
+
+    /// Sets [`ServiceSpec::ports`].
+    pub fn ports(mut self, value: impl Into<Vec<crate::api::core::v1::ServicePort>>) -> Self {
+        self.ports = Some(value.into());
+        self
+    }
+
+    /// Sets [`ServiceSpec::publish_not_ready_addresses`].
+    pub fn publish_not_ready_addresses(mut self, value: impl Into<bool>) -> Self {
+        self.publish_not_ready_addresses = Some(value.into());
+        self
+    }
+
+    /// Sets [`ServiceSpec::selector`].
+    pub fn selector(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`ServiceSpec::session_affinity`].
+    pub fn session_affinity(mut self, value: impl Into<crate::api::core::v1::ServiceAffinity>) -> Self {
+        self.session_affinity = Some(value.into());
+        self
+    }
+
+    /// Sets [`ServiceSpec::session_affinity_config`].
+    pub fn session_affinity_config(mut self, value: impl Into<crate::api::core::v1::SessionAffinityConfig>) -> Self {
+        self.session_affinity_config = Some(value.into());
+        self
+    }
+
+    /// Sets [`ServiceSpec::type_`].
+    pub fn type_(mut self, value: impl Into<crate::api::core::v1::ServiceType>) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `ServiceSpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ServiceSpec, crate::MissingFieldError> {
+        Ok(ServiceSpec {
+            cluster_ip: self.cluster_ip,
+            external_ips: self.external_ips,
+            external_name: self.external_name,
+            external_traffic_policy: self.external_traffic_policy,
+            health_check_node_port: self.health_check_node_port,
+            load_balancer_ip: self.load_balancer_ip,
+            load_balancer_source_ranges: self.load_balancer_source_ranges,
+            ports: self.ports,
+            publish_not_ready_addresses: self.publish_not_ready_addresses,
+            selector: self.selector,
+            session_affinity: self.session_affinity,
+            session_affinity_config: self.session_affinity_config,
+            type_: self.type_,
+        })
+    }
+}
+
+/// The apply configuration of [`ServiceSpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ServiceSpecApplyConfiguration {
+    /// See [`ServiceSpec::cluster_ip`].
+    pub cluster_ip: Option<String>,
+
+    /// See [`ServiceSpec::external_ips`].
+    pub external_ips: Option<Vec<String>>,
+
+    /// See [`ServiceSpec::external_name`].
+    pub external_name: Option<String>,
+
+    /// See [`ServiceSpec::external_traffic_policy`].
+    pub external_traffic_policy: Option<crate::api::core::v1::ServiceExternalTrafficPolicyType>,
+
+    /// See [`ServiceSpec::health_check_node_port`].
+    pub health_check_node_port: Option<i32>,
+
+    /// See [`ServiceSpec::load_balancer_ip`].
+    pub load_balancer_ip: Option<String>,
+
+    /// See [`ServiceSpec::load_balancer_source_ranges`].
+    pub load_balancer_source_ranges: Option<Vec<String>>,
+
+    /// See [`ServiceSpec::ports`].
+    pub ports: Option<Vec<crate::api::core::v1::ServicePortApplyConfiguration>>,
+
+    /// See [`ServiceSpec::publish_not_ready_addresses`].
+    pub publish_not_ready_addresses: Option<bool>,
+
+    /// See [`ServiceSpec::selector`].
+    pub selector: Option<std::collections::BTreeMap<String, String>>,
+
+    /// See [`ServiceSpec::session_affinity`].
+    pub session_affinity: Option<crate::api::core::v1::ServiceAffinity>,
+
+    /// See [`ServiceSpec::session_affinity_config`].
+    pub session_affinity_config: Option<crate::api::core::v1::SessionAffinityConfigApplyConfiguration>,
+
+    /// See [`ServiceSpec::type_`].
+    pub type_: Option<crate::api::core::v1::ServiceType>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ServiceSpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ServiceSpecApplyConfiguration",
+            self.cluster_ip.as_ref().map_or(0, |_| 1) +
+            self.external_ips.as_ref().map_or(0, |_| 1) +
+            self.external_name.as_ref().map_or(0, |_| 1) +
+            self.external_traffic_policy.as_ref().map_or(0, |_| 1) +
+            self.health_check_node_port.as_ref().map_or(0, |_| 1) +
+            self.load_balancer_ip.as_ref().map_or(0, |_| 1) +
+            self.load_balancer_source_ranges.as_ref().map_or(0, |_| 1) +
+            self.ports.as_ref().map_or(0, |_| 1) +
+            self.publish_not_ready_addresses.as_ref().map_or(0, |_| 1) +
+            self.selector.as_ref().map_or(0, |_| 1) +
+            self.session_affinity.as_ref().map_or(0, |_| 1) +
+            self.session_affinity_config.as_ref().map_or(0, |_| 1) +
+            self.type_.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.cluster_ip {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clusterIP", value)?;
+        }
+        if let Some(value) = &self.external_ips {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "externalIPs", value)?;
+        }
+        if let Some(value) = &self.external_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "externalName", value)?;
+        }
+        if let Some(value) = &self.external_traffic_policy {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "externalTrafficPolicy", value)?;
+        }
+        if let Some(value) = &self.health_check_node_port {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "healthCheckNodePort", value)?;
+        }
+        if let Some(value) = &self.load_balancer_ip {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "loadBalancerIP", value)?;
+        }
+        if let Some(value) = &self.load_balancer_source_ranges {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "loadBalancerSourceRanges", value)?;
+        }
+        if let Some(value) = &self.ports {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ports", value)?;
+        }
+        if let Some(value) = &self.publish_not_ready_addresses {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "publishNotReadyAddresses", value)?;
+        }
+        if let Some(value) = &self.selector {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selector", value)?;
+        }
+        if let Some(value) = &self.session_affinity {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "sessionAffinity", value)?;
+        }
+        if let Some(value) = &self.session_affinity_config {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "sessionAffinityConfig", value)?;
+        }
+        if let Some(value) = &self.type_ {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "type", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ServiceSpec {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.ServiceSpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::ServiceSpec".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.ServiceSpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -256,8 +693,9 @@ impl crate::schemars::JsonSchema for ServiceSpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "clusterIP".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -267,8 +705,8 @@ impl crate::schemars::JsonSchema for ServiceSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "externalIPs".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -287,8 +725,8 @@ impl crate::schemars::JsonSchema for ServiceSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "externalName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -298,19 +736,19 @@ impl crate::schemars::JsonSchema for ServiceSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "externalTrafficPolicy".to_owned(),
-                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
-                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ServiceExternalTrafficPolicyType>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
                                 description: Some("externalTrafficPolicy denotes if this Service desires to route external traffic to node-local or cluster-wide endpoints. \"Local\" preserves the client source IP and avoids a second hop for LoadBalancer and Nodeport type services, but risks potentially imbalanced traffic spreading. \"Cluster\" obscures the client source IP and may cause a second hop to another node, but should have good overall load-spreading.".to_owned()),
                                 ..Default::default()
-                            })),
-                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
-                            ..Default::default()
-                        }),
-                    ),
-                    (
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
                         "healthCheckNodePort".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -321,8 +759,8 @@ impl crate::schemars::JsonSchema for ServiceSpec {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "loadBalancerIP".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -332,8 +770,8 @@ impl crate::schemars::JsonSchema for ServiceSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "loadBalancerSourceRanges".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -352,8 +790,8 @@ impl crate::schemars::JsonSchema for ServiceSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "ports".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -367,8 +805,8 @@ impl crate::schemars::JsonSchema for ServiceSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "publishNotReadyAddresses".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -378,8 +816,8 @@ impl crate::schemars::JsonSchema for ServiceSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "selector".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -398,19 +836,19 @@ impl crate::schemars::JsonSchema for ServiceSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "sessionAffinity".to_owned(),
-                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
-                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ServiceAffinity>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
                                 description: Some("Supports \"ClientIP\" and \"None\". Used to maintain session affinity. Enable client IP based session affinity. Must be ClientIP or None. Defaults to None. More info: https://kubernetes.io/docs/concepts/services-networking/service/#virtual-ips-and-service-proxies".to_owned()),
                                 ..Default::default()
-                            })),
-                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
-                            ..Default::default()
-                        }),
-                    ),
-                    (
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
                         "sessionAffinityConfig".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::SessionAffinityConfig>().into_object();
@@ -420,22 +858,44 @@ impl crate::schemars::JsonSchema for ServiceSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "type".to_owned(),
-                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
-                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ServiceType>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
                                 description: Some("type determines how the Service is exposed. Defaults to ClusterIP. Valid options are ExternalName, ClusterIP, NodePort, and LoadBalancer. \"ExternalName\" maps to the specified externalName. \"ClusterIP\" allocates a cluster-internal IP address for load-balancing to endpoints. Endpoints are determined by the selector or if that is not specified, by manual construction of an Endpoints object. If clusterIP is \"None\", no virtual IP is allocated and the endpoints are published as a set of endpoints rather than a stable IP. \"NodePort\" builds on ClusterIP and allocates a port on every node which routes to the clusterIP. \"LoadBalancer\" builds on NodePort and creates an external load-balancer (if supported in the current cloud) which routes to the clusterIP. More info: https://kubernetes.io/docs/concepts/services-networking/service/#publishing-services---service-types".to_owned()),
                                 ..Default::default()
-                            })),
-                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
-                            ..Default::default()
-                        }),
-                    ),
-                ]).collect(),
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ServiceSpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ServiceSpec {
+            cluster_ip: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            external_ips: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            external_name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            external_traffic_policy: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            health_check_node_port: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            load_balancer_ip: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            load_balancer_source_ranges: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            ports: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            publish_not_ready_addresses: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            selector: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            session_affinity: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            session_affinity_config: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            type_: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,80 +1,118 @@
 // Generated from definition io.k8s.api.core.v1.SessionAffinityConfig
 
 /// SessionAffinityConfig represents the configurations of session affinity.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 pub struct SessionAffinityConfig {
     /// clientIP contains the configurations of Client IP based session affinity.
     pub client_ip: Option<crate::api::core::v1::ClientIPConfig>,
 }
 
+impl SessionAffinityConfig {
+    /// Returns this value with [`SessionAffinityConfig::client_ip`] set to the given value.
+    #[must_use]
+    pub fn with_client_ip(mut self, value: impl Into<crate::api::core::v1::ClientIPConfig>) -> Self {
+        self.client_ip = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for SessionAffinityConfig {}
+
+impl SessionAffinityConfig {
+    const FIELDS: &'static [&'static str] = &[
+        "clientIP",
+    ];
+}
+
 impl<'de> crate::serde::Deserialize<'de> for SessionAffinityConfig {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_client_ip,
-            Other,
-        }
+        struct Visitor;
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = SessionAffinityConfig;
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("SessionAffinityConfig")
+            }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_client_ip: Option<crate::api::core::v1::ClientIPConfig> = None;
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "clientIP" => Field::Key_client_ip,
-                            _ => Field::Other,
-                        })
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("SessionAffinityConfig", SessionAffinityConfig::FIELDS))? {
+                    match key {
+                        Some(0) => value_client_ip = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
-                deserializer.deserialize_identifier(Visitor)
+                Ok(SessionAffinityConfig {
+                    client_ip: value_client_ip,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(SessionAffinityConfig {
+                    client_ip: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                })
             }
         }
 
-        struct Visitor;
+        deserializer.deserialize_struct(
+            "SessionAffinityConfig",
+            SessionAffinityConfig::FIELDS,
+            Visitor,
+        )
+    }
 
-        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-            type Value = SessionAffinityConfig;
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, SessionAffinityConfig> {
+            type Value = ();
 
             fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 f.write_str("SessionAffinityConfig")
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
-                let mut value_client_ip: Option<crate::api::core::v1::ClientIPConfig> = None;
+                let mut has_client_ip = false;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("SessionAffinityConfig", SessionAffinityConfig::FIELDS))? {
                     match key {
-                        Field::Key_client_ip => value_client_ip = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.client_ip))?; has_client_ip = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
+                if !has_client_ip {
+                    self.0.client_ip = None;
+                }
 
-                Ok(SessionAffinityConfig {
-                    client_ip: value_client_ip,
-                })
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.client_ip))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
             }
         }
 
         deserializer.deserialize_struct(
             "SessionAffinityConfig",
-            &[
-                "clientIP",
-            ],
-            Visitor,
+            SessionAffinityConfig::FIELDS,
+            InPlaceVisitor(place),
         )
     }
 }
 
 impl crate::serde::Serialize for SessionAffinityConfig {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("SessionAffinityConfig", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clientIP", &self.client_ip)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "SessionAffinityConfig",
             self.client_ip.as_ref().map_or(0, |_| 1),
@@ -86,10 +124,69 @@ impl crate::serde::Serialize for SessionAffinityConfig {
     }
 }
 
+/// A builder of [`SessionAffinityConfig`]. Created with [`SessionAffinityConfig::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct SessionAffinityConfigBuilder {
+    client_ip: Option<crate::api::core::v1::ClientIPConfig>,
+}
+
+#[cfg(feature = "builder")]
+impl SessionAffinityConfig {
+    /// Starts building a `SessionAffinityConfig`. Its required fields must be set before [`SessionAffinityConfigBuilder::build`] is called.
+    pub fn builder() -> SessionAffinityConfigBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl SessionAffinityConfigBuilder {
+    /// Sets [`SessionAffinityConfig::client_ip`].
+    pub fn client_ip(mut self, value: impl Into<crate::api::core::v1::ClientIPConfig>) -> Self {
+        self.client_ip = Some(value.into());
+        self
+    }
+
+    /// Builds the `SessionAffinityConfig`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<SessionAffinityConfig, crate::MissingFieldError> {
+        Ok(SessionAffinityConfig {
+            client_ip: self.client_ip,
+        })
+    }
+}
+
+/// The apply configuration of [`SessionAffinityConfig`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SessionAffinityConfigApplyConfiguration {
+    /// See [`SessionAffinityConfig::client_ip`].
+    pub client_ip: Option<crate::api::core::v1::ClientIPConfigApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for SessionAffinityConfigApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "SessionAffinityConfigApplyConfiguration",
+            self.client_ip.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.client_ip {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clientIP", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for SessionAffinityConfig {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.SessionAffinityConfig".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::SessionAffinityConfig".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.SessionAffinityConfig".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -100,8 +197,9 @@ impl crate::schemars::JsonSchema for SessionAffinityConfig {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "clientIP".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ClientIPConfig>().into_object();
@@ -111,11 +209,21 @@ impl crate::schemars::JsonSchema for SessionAffinityConfig {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for SessionAffinityConfig {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(SessionAffinityConfig {
+            client_ip: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
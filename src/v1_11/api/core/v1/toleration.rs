@@ -1,16 +1,16 @@
 // Generated from definition io.k8s.api.core.v1.Toleration
 
 /// The pod this Toleration is attached to tolerates any taint that matches the triple \<key,value,effect\> using the matching operator \<operator\>.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct Toleration {
     /// Effect indicates the taint effect to match. Empty means match all taint effects. When specified, allowed values are NoSchedule, PreferNoSchedule and NoExecute.
-    pub effect: Option<String>,
+    pub effect: Option<crate::api::core::v1::TaintEffect>,
 
     /// Key is the taint key that the toleration applies to. Empty means match all taint keys. If the key is empty, operator must be Exists; this combination means to match all values and all keys.
     pub key: Option<String>,
 
     /// Operator represents a key's relationship to the value. Valid operators are Exists and Equal. Defaults to Equal. Exists is equivalent to wildcard for value, so that a pod can tolerate all taints of a particular category.
-    pub operator: Option<String>,
+    pub operator: Option<crate::api::core::v1::TolerationOperator>,
 
     /// TolerationSeconds represents the period of time the toleration (which must be of effect NoExecute, otherwise this field is ignored) tolerates the taint. By default, it is not set, which means tolerate the taint forever (do not evict). Zero and negative values will be treated as 0 (evict immediately) by the system.
     pub toleration_seconds: Option<i64>,
@@ -19,45 +19,57 @@ pub struct Toleration {
     pub value: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for Toleration {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_effect,
-            Key_key,
-            Key_operator,
-            Key_toleration_seconds,
-            Key_value,
-            Other,
-        }
+impl Toleration {
+    /// Returns this value with [`Toleration::effect`] set to the given value.
+    #[must_use]
+    pub fn with_effect(mut self, value: impl Into<crate::api::core::v1::TaintEffect>) -> Self {
+        self.effect = Some(value.into());
+        self
+    }
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+    /// Returns this value with [`Toleration::key`] set to the given value.
+    #[must_use]
+    pub fn with_key(mut self, value: impl Into<String>) -> Self {
+        self.key = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`Toleration::operator`] set to the given value.
+    #[must_use]
+    pub fn with_operator(mut self, value: impl Into<crate::api::core::v1::TolerationOperator>) -> Self {
+        self.operator = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`Toleration::toleration_seconds`] set to the given value.
+    #[must_use]
+    pub fn with_toleration_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.toleration_seconds = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "effect" => Field::Key_effect,
-                            "key" => Field::Key_key,
-                            "operator" => Field::Key_operator,
-                            "tolerationSeconds" => Field::Key_toleration_seconds,
-                            "value" => Field::Key_value,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`Toleration::value`] set to the given value.
+    #[must_use]
+    pub fn with_value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl crate::MergeStrategyInfo for Toleration {}
+
+impl Toleration {
+    const FIELDS: &'static [&'static str] = &[
+        "effect",
+        "key",
+        "operator",
+        "tolerationSeconds",
+        "value",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for Toleration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -68,20 +80,20 @@ impl<'de> crate::serde::Deserialize<'de> for Toleration {
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
-                let mut value_effect: Option<String> = None;
+                let mut value_effect: Option<crate::api::core::v1::TaintEffect> = None;
                 let mut value_key: Option<String> = None;
-                let mut value_operator: Option<String> = None;
+                let mut value_operator: Option<crate::api::core::v1::TolerationOperator> = None;
                 let mut value_toleration_seconds: Option<i64> = None;
                 let mut value_value: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Toleration", Toleration::FIELDS))? {
                     match key {
-                        Field::Key_effect => value_effect = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_key => value_key = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_operator => value_operator = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_toleration_seconds => value_toleration_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_value => value_value = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_effect = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_key = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_operator = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_toleration_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_value = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -93,24 +105,102 @@ impl<'de> crate::serde::Deserialize<'de> for Toleration {
                     value: value_value,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(Toleration {
+                    effect: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    key: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    operator: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    toleration_seconds: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    value: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "Toleration",
-            &[
-                "effect",
-                "key",
-                "operator",
-                "tolerationSeconds",
-                "value",
-            ],
+            Toleration::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, Toleration> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("Toleration")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_effect = false;
+                let mut has_key = false;
+                let mut has_operator = false;
+                let mut has_toleration_seconds = false;
+                let mut has_value = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Toleration", Toleration::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.effect))?; has_effect = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.key))?; has_key = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.operator))?; has_operator = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.toleration_seconds))?; has_toleration_seconds = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.value))?; has_value = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_effect {
+                    self.0.effect = None;
+                }
+                if !has_key {
+                    self.0.key = None;
+                }
+                if !has_operator {
+                    self.0.operator = None;
+                }
+                if !has_toleration_seconds {
+                    self.0.toleration_seconds = None;
+                }
+                if !has_value {
+                    self.0.value = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.effect))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.key))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.operator))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.toleration_seconds))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.value))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "Toleration",
+            Toleration::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for Toleration {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("Toleration", 5)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "effect", &self.effect)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "key", &self.key)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "operator", &self.operator)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tolerationSeconds", &self.toleration_seconds)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "value", &self.value)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "Toleration",
             self.effect.as_ref().map_or(0, |_| 1) +
@@ -138,10 +228,129 @@ impl crate::serde::Serialize for Toleration {
     }
 }
 
+/// A builder of [`Toleration`]. Created with [`Toleration::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct TolerationBuilder {
+    effect: Option<crate::api::core::v1::TaintEffect>,
+    key: Option<String>,
+    operator: Option<crate::api::core::v1::TolerationOperator>,
+    toleration_seconds: Option<i64>,
+    value: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl Toleration {
+    /// Starts building a `Toleration`. Its required fields must be set before [`TolerationBuilder::build`] is called.
+    pub fn builder() -> TolerationBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl TolerationBuilder {
+    /// Sets [`Toleration::effect`].
+    pub fn effect(mut self, value: impl Into<crate::api::core::v1::TaintEffect>) -> Self {
+        self.effect = Some(value.into());
+        self
+    }
+
+    /// Sets [`Toleration::key`].
+    pub fn key(mut self, value: impl Into<String>) -> Self {
+        self.key = Some(value.into());
+        self
+    }
+
+    /// Sets [`Toleration::operator`].
+    pub fn operator(mut self, value: impl Into<crate::api::core::v1::TolerationOperator>) -> Self {
+        self.operator = Some(value.into());
+        self
+    }
+
+    /// Sets [`Toleration::toleration_seconds`].
+    pub fn toleration_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.toleration_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`Toleration::value`].
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Builds the `Toleration`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<Toleration, crate::MissingFieldError> {
+        Ok(Toleration {
+            effect: self.effect,
+            key: self.key,
+            operator: self.operator,
+            toleration_seconds: self.toleration_seconds,
+            value: self.value,
+        })
+    }
+}
+
+/// The apply configuration of [`Toleration`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TolerationApplyConfiguration {
+    /// See [`Toleration::effect`].
+    pub effect: Option<crate::api::core::v1::TaintEffect>,
+
+    /// See [`Toleration::key`].
+    pub key: Option<String>,
+
+    /// See [`Toleration::operator`].
+    pub operator: Option<crate::api::core::v1::TolerationOperator>,
+
+    /// See [`Toleration::toleration_seconds`].
+    pub toleration_seconds: Option<i64>,
+
+    /// See [`Toleration::value`].
+    pub value: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for TolerationApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "TolerationApplyConfiguration",
+            self.effect.as_ref().map_or(0, |_| 1) +
+            self.key.as_ref().map_or(0, |_| 1) +
+            self.operator.as_ref().map_or(0, |_| 1) +
+            self.toleration_seconds.as_ref().map_or(0, |_| 1) +
+            self.value.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.effect {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "effect", value)?;
+        }
+        if let Some(value) = &self.key {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "key", value)?;
+        }
+        if let Some(value) = &self.operator {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "operator", value)?;
+        }
+        if let Some(value) = &self.toleration_seconds {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tolerationSeconds", value)?;
+        }
+        if let Some(value) = &self.value {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "value", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Toleration {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.Toleration".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::Toleration".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.Toleration".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -152,19 +361,20 @@ impl crate::schemars::JsonSchema for Toleration {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "effect".to_owned(),
-                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
-                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::TaintEffect>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
                                 description: Some("Effect indicates the taint effect to match. Empty means match all taint effects. When specified, allowed values are NoSchedule, PreferNoSchedule and NoExecute.".to_owned()),
                                 ..Default::default()
-                            })),
-                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
-                            ..Default::default()
-                        }),
-                    ),
-                    (
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
                         "key".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -174,19 +384,19 @@ impl crate::schemars::JsonSchema for Toleration {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "operator".to_owned(),
-                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
-                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::TolerationOperator>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
                                 description: Some("Operator represents a key's relationship to the value. Valid operators are Exists and Equal. Defaults to Equal. Exists is equivalent to wildcard for value, so that a pod can tolerate all taints of a particular category.".to_owned()),
                                 ..Default::default()
-                            })),
-                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
-                            ..Default::default()
-                        }),
-                    ),
-                    (
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
                         "tolerationSeconds".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -197,8 +407,8 @@ impl crate::schemars::JsonSchema for Toleration {
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "value".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -208,11 +418,25 @@ impl crate::schemars::JsonSchema for Toleration {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for Toleration {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(Toleration {
+            effect: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            key: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            operator: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            toleration_seconds: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            value: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
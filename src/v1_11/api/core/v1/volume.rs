@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.Volume
 
 /// Volume represents a named volume in a pod that may be accessed by any container in the pod.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct Volume {
     /// AWSElasticBlockStore represents an AWS Disk resource that is attached to a kubelet's host machine and then exposed to the pod. More info: https://kubernetes.io/docs/concepts/storage/volumes#awselasticblockstore
     pub aws_elastic_block_store: Option<crate::api::core::v1::AWSElasticBlockStoreVolumeSource>,
@@ -40,6 +40,7 @@ pub struct Volume {
     pub gce_persistent_disk: Option<crate::api::core::v1::GCEPersistentDiskVolumeSource>,
 
     /// GitRepo represents a git repository at a particular revision. DEPRECATED: GitRepo is deprecated. To provision a container with a git repo, mount an EmptyDir into an InitContainer that clones the repo using git, then mount the EmptyDir into the Pod's container.
+    #[deprecated(note = "GitRepo is deprecated.")]
     pub git_repo: Option<crate::api::core::v1::GitRepoVolumeSource>,
 
     /// Glusterfs represents a Glusterfs mount on the host that shares a pod's lifetime. More info: https://releases.k8s.io/HEAD/examples/volumes/glusterfs/README.md
@@ -88,91 +89,277 @@ pub struct Volume {
     pub vsphere_volume: Option<crate::api::core::v1::VsphereVirtualDiskVolumeSource>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for Volume {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_aws_elastic_block_store,
-            Key_azure_disk,
-            Key_azure_file,
-            Key_cephfs,
-            Key_cinder,
-            Key_config_map,
-            Key_downward_api,
-            Key_empty_dir,
-            Key_fc,
-            Key_flex_volume,
-            Key_flocker,
-            Key_gce_persistent_disk,
-            Key_git_repo,
-            Key_glusterfs,
-            Key_host_path,
-            Key_iscsi,
-            Key_name,
-            Key_nfs,
-            Key_persistent_volume_claim,
-            Key_photon_persistent_disk,
-            Key_portworx_volume,
-            Key_projected,
-            Key_quobyte,
-            Key_rbd,
-            Key_scale_io,
-            Key_secret,
-            Key_storageos,
-            Key_vsphere_volume,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl Volume {
+    /// Constructs a `Volume` from its required fields. Its optional fields are unset.
+    pub fn new(name: impl Into<String>) -> Self {
+        Volume {
+            aws_elastic_block_store: None,
+            azure_disk: None,
+            azure_file: None,
+            cephfs: None,
+            cinder: None,
+            config_map: None,
+            downward_api: None,
+            empty_dir: None,
+            fc: None,
+            flex_volume: None,
+            flocker: None,
+            gce_persistent_disk: None,
+            git_repo: None,
+            glusterfs: None,
+            host_path: None,
+            iscsi: None,
+            name: name.into(),
+            nfs: None,
+            persistent_volume_claim: None,
+            photon_persistent_disk: None,
+            portworx_volume: None,
+            projected: None,
+            quobyte: None,
+            rbd: None,
+            scale_io: None,
+            secret: None,
+            storageos: None,
+            vsphere_volume: None,
+        }
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "awsElasticBlockStore" => Field::Key_aws_elastic_block_store,
-                            "azureDisk" => Field::Key_azure_disk,
-                            "azureFile" => Field::Key_azure_file,
-                            "cephfs" => Field::Key_cephfs,
-                            "cinder" => Field::Key_cinder,
-                            "configMap" => Field::Key_config_map,
-                            "downwardAPI" => Field::Key_downward_api,
-                            "emptyDir" => Field::Key_empty_dir,
-                            "fc" => Field::Key_fc,
-                            "flexVolume" => Field::Key_flex_volume,
-                            "flocker" => Field::Key_flocker,
-                            "gcePersistentDisk" => Field::Key_gce_persistent_disk,
-                            "gitRepo" => Field::Key_git_repo,
-                            "glusterfs" => Field::Key_glusterfs,
-                            "hostPath" => Field::Key_host_path,
-                            "iscsi" => Field::Key_iscsi,
-                            "name" => Field::Key_name,
-                            "nfs" => Field::Key_nfs,
-                            "persistentVolumeClaim" => Field::Key_persistent_volume_claim,
-                            "photonPersistentDisk" => Field::Key_photon_persistent_disk,
-                            "portworxVolume" => Field::Key_portworx_volume,
-                            "projected" => Field::Key_projected,
-                            "quobyte" => Field::Key_quobyte,
-                            "rbd" => Field::Key_rbd,
-                            "scaleIO" => Field::Key_scale_io,
-                            "secret" => Field::Key_secret,
-                            "storageos" => Field::Key_storageos,
-                            "vsphereVolume" => Field::Key_vsphere_volume,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl Volume {
+    /// Returns this value with [`Volume::aws_elastic_block_store`] set to the given value.
+    #[must_use]
+    pub fn with_aws_elastic_block_store(mut self, value: impl Into<crate::api::core::v1::AWSElasticBlockStoreVolumeSource>) -> Self {
+        self.aws_elastic_block_store = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`Volume::azure_disk`] set to the given value.
+    #[must_use]
+    pub fn with_azure_disk(mut self, value: impl Into<crate::api::core::v1::AzureDiskVolumeSource>) -> Self {
+        self.azure_disk = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Volume::azure_file`] set to the given value.
+    #[must_use]
+    pub fn with_azure_file(mut self, value: impl Into<crate::api::core::v1::AzureFileVolumeSource>) -> Self {
+        self.azure_file = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Volume::cephfs`] set to the given value.
+    #[must_use]
+    pub fn with_cephfs(mut self, value: impl Into<crate::api::core::v1::CephFSVolumeSource>) -> Self {
+        self.cephfs = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Volume::cinder`] set to the given value.
+    #[must_use]
+    pub fn with_cinder(mut self, value: impl Into<crate::api::core::v1::CinderVolumeSource>) -> Self {
+        self.cinder = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Volume::config_map`] set to the given value.
+    #[must_use]
+    pub fn with_config_map(mut self, value: impl Into<crate::api::core::v1::ConfigMapVolumeSource>) -> Self {
+        self.config_map = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Volume::downward_api`] set to the given value.
+    #[must_use]
+    pub fn with_downward_api(mut self, value: impl Into<crate::api::core::v1::DownwardAPIVolumeSource>) -> Self {
+        self.downward_api = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Volume::empty_dir`] set to the given value.
+    #[must_use]
+    pub fn with_empty_dir(mut self, value: impl Into<crate::api::core::v1::EmptyDirVolumeSource>) -> Self {
+        self.empty_dir = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Volume::fc`] set to the given value.
+    #[must_use]
+    pub fn with_fc(mut self, value: impl Into<crate::api::core::v1::FCVolumeSource>) -> Self {
+        self.fc = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Volume::flex_volume`] set to the given value.
+    #[must_use]
+    pub fn with_flex_volume(mut self, value: impl Into<crate::api::core::v1::FlexVolumeSource>) -> Self {
+        self.flex_volume = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Volume::flocker`] set to the given value.
+    #[must_use]
+    pub fn with_flocker(mut self, value: impl Into<crate::api::core::v1::FlockerVolumeSource>) -> Self {
+        self.flocker = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Volume::gce_persistent_disk`] set to the given value.
+    #[must_use]
+    pub fn with_gce_persistent_disk(mut self, value: impl Into<crate::api::core::v1::GCEPersistentDiskVolumeSource>) -> Self {
+        self.gce_persistent_disk = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Volume::git_repo`] set to the given value.
+    #[must_use]
+    pub fn with_git_repo(mut self, value: impl Into<crate::api::core::v1::GitRepoVolumeSource>) -> Self {
+        self.git_repo = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Volume::glusterfs`] set to the given value.
+    #[must_use]
+    pub fn with_glusterfs(mut self, value: impl Into<crate::api::core::v1::GlusterfsVolumeSource>) -> Self {
+        self.glusterfs = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Volume::host_path`] set to the given value.
+    #[must_use]
+    pub fn with_host_path(mut self, value: impl Into<crate::api::core::v1::HostPathVolumeSource>) -> Self {
+        self.host_path = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Volume::iscsi`] set to the given value.
+    #[must_use]
+    pub fn with_iscsi(mut self, value: impl Into<crate::api::core::v1::ISCSIVolumeSource>) -> Self {
+        self.iscsi = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Volume::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
+
+    /// Returns this value with [`Volume::nfs`] set to the given value.
+    #[must_use]
+    pub fn with_nfs(mut self, value: impl Into<crate::api::core::v1::NFSVolumeSource>) -> Self {
+        self.nfs = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Volume::persistent_volume_claim`] set to the given value.
+    #[must_use]
+    pub fn with_persistent_volume_claim(mut self, value: impl Into<crate::api::core::v1::PersistentVolumeClaimVolumeSource>) -> Self {
+        self.persistent_volume_claim = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Volume::photon_persistent_disk`] set to the given value.
+    #[must_use]
+    pub fn with_photon_persistent_disk(mut self, value: impl Into<crate::api::core::v1::PhotonPersistentDiskVolumeSource>) -> Self {
+        self.photon_persistent_disk = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Volume::portworx_volume`] set to the given value.
+    #[must_use]
+    pub fn with_portworx_volume(mut self, value: impl Into<crate::api::core::v1::PortworxVolumeSource>) -> Self {
+        self.portworx_volume = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Volume::projected`] set to the given value.
+    #[must_use]
+    pub fn with_projected(mut self, value: impl Into<crate::api::core::v1::ProjectedVolumeSource>) -> Self {
+        self.projected = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Volume::quobyte`] set to the given value.
+    #[must_use]
+    pub fn with_quobyte(mut self, value: impl Into<crate::api::core::v1::QuobyteVolumeSource>) -> Self {
+        self.quobyte = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Volume::rbd`] set to the given value.
+    #[must_use]
+    pub fn with_rbd(mut self, value: impl Into<crate::api::core::v1::RBDVolumeSource>) -> Self {
+        self.rbd = Some(value.into());
+        self
+    }
 
+    /// Returns this value with [`Volume::scale_io`] set to the given value.
+    #[must_use]
+    pub fn with_scale_io(mut self, value: impl Into<crate::api::core::v1::ScaleIOVolumeSource>) -> Self {
+        self.scale_io = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Volume::secret`] set to the given value.
+    #[must_use]
+    pub fn with_secret(mut self, value: impl Into<crate::api::core::v1::SecretVolumeSource>) -> Self {
+        self.secret = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Volume::storageos`] set to the given value.
+    #[must_use]
+    pub fn with_storageos(mut self, value: impl Into<crate::api::core::v1::StorageOSVolumeSource>) -> Self {
+        self.storageos = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Volume::vsphere_volume`] set to the given value.
+    #[must_use]
+    pub fn with_vsphere_volume(mut self, value: impl Into<crate::api::core::v1::VsphereVirtualDiskVolumeSource>) -> Self {
+        self.vsphere_volume = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for Volume {}
+
+impl Volume {
+    const FIELDS: &'static [&'static str] = &[
+        "awsElasticBlockStore",
+        "azureDisk",
+        "azureFile",
+        "cephfs",
+        "cinder",
+        "configMap",
+        "downwardAPI",
+        "emptyDir",
+        "fc",
+        "flexVolume",
+        "flocker",
+        "gcePersistentDisk",
+        "gitRepo",
+        "glusterfs",
+        "hostPath",
+        "iscsi",
+        "name",
+        "nfs",
+        "persistentVolumeClaim",
+        "photonPersistentDisk",
+        "portworxVolume",
+        "projected",
+        "quobyte",
+        "rbd",
+        "scaleIO",
+        "secret",
+        "storageos",
+        "vsphereVolume",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for Volume {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -212,37 +399,37 @@ impl<'de> crate::serde::Deserialize<'de> for Volume {
                 let mut value_storageos: Option<crate::api::core::v1::StorageOSVolumeSource> = None;
                 let mut value_vsphere_volume: Option<crate::api::core::v1::VsphereVirtualDiskVolumeSource> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Volume", Volume::FIELDS))? {
                     match key {
-                        Field::Key_aws_elastic_block_store => value_aws_elastic_block_store = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_azure_disk => value_azure_disk = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_azure_file => value_azure_file = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_cephfs => value_cephfs = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_cinder => value_cinder = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_config_map => value_config_map = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_downward_api => value_downward_api = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_empty_dir => value_empty_dir = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_fc => value_fc = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_flex_volume => value_flex_volume = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_flocker => value_flocker = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_gce_persistent_disk => value_gce_persistent_disk = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_git_repo => value_git_repo = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_glusterfs => value_glusterfs = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_host_path => value_host_path = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_iscsi => value_iscsi = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_name => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_nfs => value_nfs = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_persistent_volume_claim => value_persistent_volume_claim = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_photon_persistent_disk => value_photon_persistent_disk = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_portworx_volume => value_portworx_volume = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_projected => value_projected = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_quobyte => value_quobyte = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_rbd => value_rbd = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_scale_io => value_scale_io = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_secret => value_secret = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_storageos => value_storageos = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_vsphere_volume => value_vsphere_volume = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_aws_elastic_block_store = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_azure_disk = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_azure_file = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_cephfs = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_cinder = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_config_map = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_downward_api = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(7) => value_empty_dir = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(8) => value_fc = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(9) => value_flex_volume = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(10) => value_flocker = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(11) => value_gce_persistent_disk = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(12) => value_git_repo = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(13) => value_glusterfs = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(14) => value_host_path = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(15) => value_iscsi = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(16) => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(17) => value_nfs = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(18) => value_persistent_volume_claim = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(19) => value_photon_persistent_disk = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(20) => value_portworx_volume = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(21) => value_projected = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(22) => value_quobyte = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(23) => value_rbd = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(24) => value_scale_io = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(25) => value_secret = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(26) => value_storageos = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(27) => value_vsphere_volume = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -263,7 +450,7 @@ impl<'de> crate::serde::Deserialize<'de> for Volume {
                     glusterfs: value_glusterfs,
                     host_path: value_host_path,
                     iscsi: value_iscsi,
-                    name: value_name.ok_or_else(|| crate::serde::de::Error::missing_field("name"))?,
+                    name: value_name.ok_or_else(|| crate::serde2::missing_field("Volume", "name", &["name"]))?,
                     nfs: value_nfs,
                     persistent_volume_claim: value_persistent_volume_claim,
                     photon_persistent_disk: value_photon_persistent_disk,
@@ -277,47 +464,286 @@ impl<'de> crate::serde::Deserialize<'de> for Volume {
                     vsphere_volume: value_vsphere_volume,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(Volume {
+                    aws_elastic_block_store: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    azure_disk: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    azure_file: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    cephfs: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    cinder: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    config_map: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    downward_api: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    empty_dir: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                    fc: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?,
+                    flex_volume: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?,
+                    flocker: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?,
+                    gce_persistent_disk: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(11, &self))?,
+                    git_repo: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(12, &self))?,
+                    glusterfs: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(13, &self))?,
+                    host_path: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(14, &self))?,
+                    iscsi: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(15, &self))?,
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(16, &self))?,
+                    nfs: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(17, &self))?,
+                    persistent_volume_claim: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(18, &self))?,
+                    photon_persistent_disk: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(19, &self))?,
+                    portworx_volume: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(20, &self))?,
+                    projected: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(21, &self))?,
+                    quobyte: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(22, &self))?,
+                    rbd: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(23, &self))?,
+                    scale_io: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(24, &self))?,
+                    secret: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(25, &self))?,
+                    storageos: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(26, &self))?,
+                    vsphere_volume: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(27, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "Volume",
-            &[
-                "awsElasticBlockStore",
-                "azureDisk",
-                "azureFile",
-                "cephfs",
-                "cinder",
-                "configMap",
-                "downwardAPI",
-                "emptyDir",
-                "fc",
-                "flexVolume",
-                "flocker",
-                "gcePersistentDisk",
-                "gitRepo",
-                "glusterfs",
-                "hostPath",
-                "iscsi",
-                "name",
-                "nfs",
-                "persistentVolumeClaim",
-                "photonPersistentDisk",
-                "portworxVolume",
-                "projected",
-                "quobyte",
-                "rbd",
-                "scaleIO",
-                "secret",
-                "storageos",
-                "vsphereVolume",
-            ],
+            Volume::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, Volume> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("Volume")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_aws_elastic_block_store = false;
+                let mut has_azure_disk = false;
+                let mut has_azure_file = false;
+                let mut has_cephfs = false;
+                let mut has_cinder = false;
+                let mut has_config_map = false;
+                let mut has_downward_api = false;
+                let mut has_empty_dir = false;
+                let mut has_fc = false;
+                let mut has_flex_volume = false;
+                let mut has_flocker = false;
+                let mut has_gce_persistent_disk = false;
+                let mut has_git_repo = false;
+                let mut has_glusterfs = false;
+                let mut has_host_path = false;
+                let mut has_iscsi = false;
+                let mut has_name = false;
+                let mut has_nfs = false;
+                let mut has_persistent_volume_claim = false;
+                let mut has_photon_persistent_disk = false;
+                let mut has_portworx_volume = false;
+                let mut has_projected = false;
+                let mut has_quobyte = false;
+                let mut has_rbd = false;
+                let mut has_scale_io = false;
+                let mut has_secret = false;
+                let mut has_storageos = false;
+                let mut has_vsphere_volume = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Volume", Volume::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.aws_elastic_block_store))?; has_aws_elastic_block_store = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.azure_disk))?; has_azure_disk = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.azure_file))?; has_azure_file = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.cephfs))?; has_cephfs = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.cinder))?; has_cinder = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.config_map))?; has_config_map = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.downward_api))?; has_downward_api = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.empty_dir))?; has_empty_dir = true; },
+                        Some(8) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.fc))?; has_fc = true; },
+                        Some(9) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.flex_volume))?; has_flex_volume = true; },
+                        Some(10) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.flocker))?; has_flocker = true; },
+                        Some(11) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.gce_persistent_disk))?; has_gce_persistent_disk = true; },
+                        Some(12) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.git_repo))?; has_git_repo = true; },
+                        Some(13) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.glusterfs))?; has_glusterfs = true; },
+                        Some(14) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.host_path))?; has_host_path = true; },
+                        Some(15) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.iscsi))?; has_iscsi = true; },
+                        Some(16) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.name))?; has_name = true; },
+                        Some(17) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.nfs))?; has_nfs = true; },
+                        Some(18) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.persistent_volume_claim))?; has_persistent_volume_claim = true; },
+                        Some(19) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.photon_persistent_disk))?; has_photon_persistent_disk = true; },
+                        Some(20) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.portworx_volume))?; has_portworx_volume = true; },
+                        Some(21) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.projected))?; has_projected = true; },
+                        Some(22) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.quobyte))?; has_quobyte = true; },
+                        Some(23) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.rbd))?; has_rbd = true; },
+                        Some(24) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.scale_io))?; has_scale_io = true; },
+                        Some(25) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.secret))?; has_secret = true; },
+                        Some(26) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.storageos))?; has_storageos = true; },
+                        Some(27) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.vsphere_volume))?; has_vsphere_volume = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_aws_elastic_block_store {
+                    self.0.aws_elastic_block_store = None;
+                }
+                if !has_azure_disk {
+                    self.0.azure_disk = None;
+                }
+                if !has_azure_file {
+                    self.0.azure_file = None;
+                }
+                if !has_cephfs {
+                    self.0.cephfs = None;
+                }
+                if !has_cinder {
+                    self.0.cinder = None;
+                }
+                if !has_config_map {
+                    self.0.config_map = None;
+                }
+                if !has_downward_api {
+                    self.0.downward_api = None;
+                }
+                if !has_empty_dir {
+                    self.0.empty_dir = None;
+                }
+                if !has_fc {
+                    self.0.fc = None;
+                }
+                if !has_flex_volume {
+                    self.0.flex_volume = None;
+                }
+                if !has_flocker {
+                    self.0.flocker = None;
+                }
+                if !has_gce_persistent_disk {
+                    self.0.gce_persistent_disk = None;
+                }
+                if !has_git_repo {
+                    self.0.git_repo = None;
+                }
+                if !has_glusterfs {
+                    self.0.glusterfs = None;
+                }
+                if !has_host_path {
+                    self.0.host_path = None;
+                }
+                if !has_iscsi {
+                    self.0.iscsi = None;
+                }
+                if !has_name {
+                    return Err(crate::serde2::missing_field("Volume", "name", &["name"]));
+                }
+                if !has_nfs {
+                    self.0.nfs = None;
+                }
+                if !has_persistent_volume_claim {
+                    self.0.persistent_volume_claim = None;
+                }
+                if !has_photon_persistent_disk {
+                    self.0.photon_persistent_disk = None;
+                }
+                if !has_portworx_volume {
+                    self.0.portworx_volume = None;
+                }
+                if !has_projected {
+                    self.0.projected = None;
+                }
+                if !has_quobyte {
+                    self.0.quobyte = None;
+                }
+                if !has_rbd {
+                    self.0.rbd = None;
+                }
+                if !has_scale_io {
+                    self.0.scale_io = None;
+                }
+                if !has_secret {
+                    self.0.secret = None;
+                }
+                if !has_storageos {
+                    self.0.storageos = None;
+                }
+                if !has_vsphere_volume {
+                    self.0.vsphere_volume = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.aws_elastic_block_store))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.azure_disk))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.azure_file))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.cephfs))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.cinder))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.config_map))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.downward_api))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.empty_dir))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.fc))?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.flex_volume))?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.flocker))?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.gce_persistent_disk))?.ok_or_else(|| crate::serde::de::Error::invalid_length(11, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.git_repo))?.ok_or_else(|| crate::serde::de::Error::invalid_length(12, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.glusterfs))?.ok_or_else(|| crate::serde::de::Error::invalid_length(13, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.host_path))?.ok_or_else(|| crate::serde::de::Error::invalid_length(14, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.iscsi))?.ok_or_else(|| crate::serde::de::Error::invalid_length(15, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(16, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.nfs))?.ok_or_else(|| crate::serde::de::Error::invalid_length(17, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.persistent_volume_claim))?.ok_or_else(|| crate::serde::de::Error::invalid_length(18, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.photon_persistent_disk))?.ok_or_else(|| crate::serde::de::Error::invalid_length(19, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.portworx_volume))?.ok_or_else(|| crate::serde::de::Error::invalid_length(20, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.projected))?.ok_or_else(|| crate::serde::de::Error::invalid_length(21, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.quobyte))?.ok_or_else(|| crate::serde::de::Error::invalid_length(22, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.rbd))?.ok_or_else(|| crate::serde::de::Error::invalid_length(23, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.scale_io))?.ok_or_else(|| crate::serde::de::Error::invalid_length(24, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.secret))?.ok_or_else(|| crate::serde::de::Error::invalid_length(25, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.storageos))?.ok_or_else(|| crate::serde::de::Error::invalid_length(26, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.vsphere_volume))?.ok_or_else(|| crate::serde::de::Error::invalid_length(27, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "Volume",
+            Volume::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for Volume {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("Volume", 28)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "awsElasticBlockStore", &self.aws_elastic_block_store)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "azureDisk", &self.azure_disk)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "azureFile", &self.azure_file)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cephfs", &self.cephfs)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cinder", &self.cinder)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "configMap", &self.config_map)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "downwardAPI", &self.downward_api)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "emptyDir", &self.empty_dir)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fc", &self.fc)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "flexVolume", &self.flex_volume)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "flocker", &self.flocker)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "gcePersistentDisk", &self.gce_persistent_disk)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "gitRepo", &self.git_repo)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "glusterfs", &self.glusterfs)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostPath", &self.host_path)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "iscsi", &self.iscsi)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nfs", &self.nfs)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "persistentVolumeClaim", &self.persistent_volume_claim)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "photonPersistentDisk", &self.photon_persistent_disk)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "portworxVolume", &self.portworx_volume)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "projected", &self.projected)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "quobyte", &self.quobyte)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rbd", &self.rbd)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "scaleIO", &self.scale_io)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secret", &self.secret)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "storageos", &self.storageos)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "vsphereVolume", &self.vsphere_volume)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "Volume",
             1 +
@@ -435,10 +861,474 @@ impl crate::serde::Serialize for Volume {
     }
 }
 
+/// A builder of [`Volume`]. Created with [`Volume::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct VolumeBuilder {
+    aws_elastic_block_store: Option<crate::api::core::v1::AWSElasticBlockStoreVolumeSource>,
+    azure_disk: Option<crate::api::core::v1::AzureDiskVolumeSource>,
+    azure_file: Option<crate::api::core::v1::AzureFileVolumeSource>,
+    cephfs: Option<crate::api::core::v1::CephFSVolumeSource>,
+    cinder: Option<crate::api::core::v1::CinderVolumeSource>,
+    config_map: Option<crate::api::core::v1::ConfigMapVolumeSource>,
+    downward_api: Option<crate::api::core::v1::DownwardAPIVolumeSource>,
+    empty_dir: Option<crate::api::core::v1::EmptyDirVolumeSource>,
+    fc: Option<crate::api::core::v1::FCVolumeSource>,
+    flex_volume: Option<crate::api::core::v1::FlexVolumeSource>,
+    flocker: Option<crate::api::core::v1::FlockerVolumeSource>,
+    gce_persistent_disk: Option<crate::api::core::v1::GCEPersistentDiskVolumeSource>,
+    git_repo: Option<crate::api::core::v1::GitRepoVolumeSource>,
+    glusterfs: Option<crate::api::core::v1::GlusterfsVolumeSource>,
+    host_path: Option<crate::api::core::v1::HostPathVolumeSource>,
+    iscsi: Option<crate::api::core::v1::ISCSIVolumeSource>,
+    name: Option<String>,
+    nfs: Option<crate::api::core::v1::NFSVolumeSource>,
+    persistent_volume_claim: Option<crate::api::core::v1::PersistentVolumeClaimVolumeSource>,
+    photon_persistent_disk: Option<crate::api::core::v1::PhotonPersistentDiskVolumeSource>,
+    portworx_volume: Option<crate::api::core::v1::PortworxVolumeSource>,
+    projected: Option<crate::api::core::v1::ProjectedVolumeSource>,
+    quobyte: Option<crate::api::core::v1::QuobyteVolumeSource>,
+    rbd: Option<crate::api::core::v1::RBDVolumeSource>,
+    scale_io: Option<crate::api::core::v1::ScaleIOVolumeSource>,
+    secret: Option<crate::api::core::v1::SecretVolumeSource>,
+    storageos: Option<crate::api::core::v1::StorageOSVolumeSource>,
+    vsphere_volume: Option<crate::api::core::v1::VsphereVirtualDiskVolumeSource>,
+}
+
+#[cfg(feature = "builder")]
+impl Volume {
+    /// Starts building a `Volume`. Its required fields must be set before [`VolumeBuilder::build`] is called.
+    pub fn builder() -> VolumeBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl VolumeBuilder {
+    /// Sets [`Volume::aws_elastic_block_store`].
+    pub fn aws_elastic_block_store(mut self, value: impl Into<crate::api::core::v1::AWSElasticBlockStoreVolumeSource>) -> Self {
+        self.aws_elastic_block_store = Some(value.into());
+        self
+    }
+
+    /// Sets [`Volume::azure_disk`].
+    pub fn azure_disk(mut self, value: impl Into<crate::api::core::v1::AzureDiskVolumeSource>) -> Self {
+        self.azure_disk = Some(value.into());
+        self
+    }
+
+    /// Sets [`Volume::azure_file`].
+    pub fn azure_file(mut self, value: impl Into<crate::api::core::v1::AzureFileVolumeSource>) -> Self {
+        self.azure_file = Some(value.into());
+        self
+    }
+
+    /// Sets [`Volume::cephfs`].
+    pub fn cephfs(mut self, value: impl Into<crate::api::core::v1::CephFSVolumeSource>) -> Self {
+        self.cephfs = Some(value.into());
+        self
+    }
+
+    /// Sets [`Volume::cinder`].
+    pub fn cinder(mut self, value: impl Into<crate::api::core::v1::CinderVolumeSource>) -> Self {
+        self.cinder = Some(value.into());
+        self
+    }
+
+    /// Sets [`Volume::config_map`].
+    pub fn config_map(mut self, value: impl Into<crate::api::core::v1::ConfigMapVolumeSource>) -> Self {
+        self.config_map = Some(value.into());
+        self
+    }
+
+    /// Sets [`Volume::downward_api`].
+    pub fn downward_api(mut self, value: impl Into<crate::api::core::v1::DownwardAPIVolumeSource>) -> Self {
+        self.downward_api = Some(value.into());
+        self
+    }
+
+    /// Sets [`Volume::empty_dir`].
+    pub fn empty_dir(mut self, value: impl Into<crate::api::core::v1::EmptyDirVolumeSource>) -> Self {
+        self.empty_dir = Some(value.into());
+        self
+    }
+
+    /// Sets [`Volume::fc`].
+    pub fn fc(mut self, value: impl Into<crate::api::core::v1::FCVolumeSource>) -> Self {
+        self.fc = Some(value.into());
+        self
+    }
+
+    /// Sets [`Volume::flex_volume`].
+    pub fn flex_volume(mut self, value: impl Into<crate::api::core::v1::FlexVolumeSource>) -> Self {
+        self.flex_volume = Some(value.into());
+        self
+    }
+
+    /// Sets [`Volume::flocker`].
+    pub fn flocker(mut self, value: impl Into<crate::api::core::v1::FlockerVolumeSource>) -> Self {
+        self.flocker = Some(value.into());
+        self
+    }
+
+    /// Sets [`Volume::gce_persistent_disk`].
+    pub fn gce_persistent_disk(mut self, value: impl Into<crate::api::core::v1::GCEPersistentDiskVolumeSource>) -> Self {
+        self.gce_persistent_disk = Some(value.into());
+        self
+    }
+
+    /// Sets [`Volume::git_repo`].
+    pub fn git_repo(mut self, value: impl Into<crate::api::core::v1::GitRepoVolumeSource>) -> Self {
+        self.git_repo = Some(value.into());
+        self
+    }
+
+    /// Sets [`Volume::glusterfs`].
+    pub fn glusterfs(mut self, value: impl Into<crate::api::core::v1::GlusterfsVolumeSource>) -> Self {
+        self.glusterfs = Some(value.into());
+        self
+    }
+
+    /// Sets [`Volume::host_path`].
+    pub fn host_path(mut self, value: impl Into<crate::api::core::v1::HostPathVolumeSource>) -> Self {
+        self.host_path = Some(value.into());
+        self
+    }
+
+    /// Sets [`Volume::iscsi`].
+    pub fn iscsi(mut self, value: impl Into<crate::api::core::v1::ISCSIVolumeSource>) -> Self {
+        self.iscsi = Some(value.into());
+        self
+    }
+
+    /// Sets [`Volume::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`Volume::nfs`].
+    pub fn nfs(mut self, value: impl Into<crate::api::core::v1::NFSVolumeSource>) -> Self {
+        self.nfs = Some(value.into());
+        self
+    }
+
+    /// Sets [`Volume::persistent_volume_claim`].
+    pub fn persistent_volume_claim(mut self, value: impl Into<crate::api::core::v1::PersistentVolumeClaimVolumeSource>) -> Self {
+        self.persistent_volume_claim = Some(value.into());
+        self
+    }
+
+    /// Sets [`Volume::photon_persistent_disk`].
+    pub fn photon_persistent_disk(mut self, value: impl Into<crate::api::core::v1::PhotonPersistentDiskVolumeSource>) -> Self {
+        self.photon_persistent_disk = Some(value.into());
+        self
+    }
+
+    /// Sets [`Volume::portworx_volume`].
+    pub fn portworx_volume(mut self, value: impl Into<crate::api::core::v1::PortworxVolumeSource>) -> Self {
+        self.portworx_volume = Some(value.into());
+        self
+    }
+
+    /// Sets [`Volume::projected`].
+    pub fn projected(mut self, value: impl Into<crate::api::core::v1::ProjectedVolumeSource>) -> Self {
+        self.projected = Some(value.into());
+        self
+    }
+
+    /// Sets [`Volume::quobyte`].
+    pub fn quobyte(mut self, value: impl Into<crate::api::core::v1::QuobyteVolumeSource>) -> Self {
+        self.quobyte = Some(value.into());
+        self
+    }
+
+    /// Sets [`Volume::rbd`].
+    pub fn rbd(mut self, value: impl Into<crate::api::core::v1::RBDVolumeSource>) -> Self {
+        self.rbd = Some(value.into());
+        self
+    }
+
+    /// Sets [`Volume::scale_io`].
+    pub fn scale_io(mut self, value: impl Into<crate::api::core::v1::ScaleIOVolumeSource>) -> Self {
+        self.scale_io = Some(value.into());
+        self
+    }
+
+    /// Sets [`Volume::secret`].
+    pub fn secret(mut self, value: impl Into<crate::api::core::v1::SecretVolumeSource>) -> Self {
+        self.secret = Some(value.into());
+        self
+    }
+
+    /// Sets [`Volume::storageos`].
+    pub fn storageos(mut self, value: impl Into<crate::api::core::v1::StorageOSVolumeSource>) -> Self {
+        self.storageos = Some(value.into());
+        self
+    }
+
+    /// Sets [`Volume::vsphere_volume`].
+    pub fn vsphere_volume(mut self, value: impl Into<crate::api::core::v1::VsphereVirtualDiskVolumeSource>) -> Self {
+        self.vsphere_volume = Some(value.into());
+        self
+    }
+
+    /// Builds the `Volume`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<Volume, crate::MissingFieldError> {
+        Ok(Volume {
+            aws_elastic_block_store: self.aws_elastic_block_store,
+            azure_disk: self.azure_disk,
+            azure_file: self.azure_file,
+            cephfs: self.cephfs,
+            cinder: self.cinder,
+            config_map: self.config_map,
+            downward_api: self.downward_api,
+            empty_dir: self.empty_dir,
+            fc: self.fc,
+            flex_volume: self.flex_volume,
+            flocker: self.flocker,
+            gce_persistent_disk: self.gce_persistent_disk,
+            git_repo: self.git_repo,
+            glusterfs: self.glusterfs,
+            host_path: self.host_path,
+            iscsi: self.iscsi,
+            name: self.name.ok_or_else(|| crate::MissingFieldError::new("Volume", "name"))?,
+            nfs: self.nfs,
+            persistent_volume_claim: self.persistent_volume_claim,
+            photon_persistent_disk: self.photon_persistent_disk,
+            portworx_volume: self.portworx_volume,
+            projected: self.projected,
+            quobyte: self.quobyte,
+            rbd: self.rbd,
+            scale_io: self.scale_io,
+            secret: self.secret,
+            storageos: self.storageos,
+            vsphere_volume: self.vsphere_volume,
+        })
+    }
+}
+
+/// The apply configuration of [`Volume`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VolumeApplyConfiguration {
+    /// See [`Volume::aws_elastic_block_store`].
+    pub aws_elastic_block_store: Option<crate::api::core::v1::AWSElasticBlockStoreVolumeSourceApplyConfiguration>,
+
+    /// See [`Volume::azure_disk`].
+    pub azure_disk: Option<crate::api::core::v1::AzureDiskVolumeSourceApplyConfiguration>,
+
+    /// See [`Volume::azure_file`].
+    pub azure_file: Option<crate::api::core::v1::AzureFileVolumeSourceApplyConfiguration>,
+
+    /// See [`Volume::cephfs`].
+    pub cephfs: Option<crate::api::core::v1::CephFSVolumeSourceApplyConfiguration>,
+
+    /// See [`Volume::cinder`].
+    pub cinder: Option<crate::api::core::v1::CinderVolumeSourceApplyConfiguration>,
+
+    /// See [`Volume::config_map`].
+    pub config_map: Option<crate::api::core::v1::ConfigMapVolumeSourceApplyConfiguration>,
+
+    /// See [`Volume::downward_api`].
+    pub downward_api: Option<crate::api::core::v1::DownwardAPIVolumeSourceApplyConfiguration>,
+
+    /// See [`Volume::empty_dir`].
+    pub empty_dir: Option<crate::api::core::v1::EmptyDirVolumeSourceApplyConfiguration>,
+
+    /// See [`Volume::fc`].
+    pub fc: Option<crate::api::core::v1::FCVolumeSourceApplyConfiguration>,
+
+    /// See [`Volume::flex_volume`].
+    pub flex_volume: Option<crate::api::core::v1::FlexVolumeSourceApplyConfiguration>,
+
+    /// See [`Volume::flocker`].
+    pub flocker: Option<crate::api::core::v1::FlockerVolumeSourceApplyConfiguration>,
+
+    /// See [`Volume::gce_persistent_disk`].
+    pub gce_persistent_disk: Option<crate::api::core::v1::GCEPersistentDiskVolumeSourceApplyConfiguration>,
+
+    /// See [`Volume::git_repo`].
+    pub git_repo: Option<crate::api::core::v1::GitRepoVolumeSourceApplyConfiguration>,
+
+    /// See [`Volume::glusterfs`].
+    pub glusterfs: Option<crate::api::core::v1::GlusterfsVolumeSourceApplyConfiguration>,
+
+    /// See [`Volume::host_path`].
+    pub host_path: Option<crate::api::core::v1::HostPathVolumeSourceApplyConfiguration>,
+
+    /// See [`Volume::iscsi`].
+    pub iscsi: Option<crate::api::core::v1::ISCSIVolumeSourceApplyConfiguration>,
+
+    /// See [`Volume::name`].
+    pub name: Option<String>,
+
+    /// See [`Volume::nfs`].
+    pub nfs: Option<crate::api::core::v1::NFSVolumeSourceApplyConfiguration>,
+
+    /// See [`Volume::persistent_volume_claim`].
+    pub persistent_volume_claim: Option<crate::api::core::v1::PersistentVolumeClaimVolumeSourceApplyConfiguration>,
+
+    /// See [`Volume::photon_persistent_disk`].
+    pub photon_persistent_disk: Option<crate::api::core::v1::PhotonPersistentDiskVolumeSourceApplyConfiguration>,
+
+    /// See [`Volume::portworx_volume`].
+    pub portworx_volume: Option<crate::api::core::v1::PortworxVolumeSourceApplyConfiguration>,
+
+    /// See [`Volume::projected`].
+    pub projected: Option<crate::api::core::v1::ProjectedVolumeSourceApplyConfiguration>,
+
+    /// See [`Volume::quobyte`].
+    pub quobyte: Option<crate::api::core::v1::QuobyteVolumeSourceApplyConfiguration>,
+
+    /// See [`Volume::rbd`].
+    pub rbd: Option<crate::api::core::v1::RBDVolumeSourceApplyConfiguration>,
+
+    /// See [`Volume::scale_io`].
+    pub scale_io: Option<crate::api::core::v1::ScaleIOVolumeSourceApplyConfiguration>,
+
+    /// See [`Volume::secret`].
+    pub secret: Option<crate::api::core::v1::SecretVolumeSourceApplyConfiguration>,
+
+    /// See [`Volume::storageos`].
+    pub storageos: Option<crate::api::core::v1::StorageOSVolumeSourceApplyConfiguration>,
+
+    /// See [`Volume::vsphere_volume`].
+    pub vsphere_volume: Option<crate::api::core::v1::VsphereVirtualDiskVolumeSourceApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for VolumeApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "VolumeApplyConfiguration",
+            self.aws_elastic_block_store.as_ref().map_or(0, |_| 1) +
+            self.azure_disk.as_ref().map_or(0, |_| 1) +
+            self.azure_file.as_ref().map_or(0, |_| 1) +
+            self.cephfs.as_ref().map_or(0, |_| 1) +
+            self.cinder.as_ref().map_or(0, |_| 1) +
+            self.config_map.as_ref().map_or(0, |_| 1) +
+            self.downward_api.as_ref().map_or(0, |_| 1) +
+            self.empty_dir.as_ref().map_or(0, |_| 1) +
+            self.fc.as_ref().map_or(0, |_| 1) +
+            self.flex_volume.as_ref().map_or(0, |_| 1) +
+            self.flocker.as_ref().map_or(0, |_| 1) +
+            self.gce_persistent_disk.as_ref().map_or(0, |_| 1) +
+            self.git_repo.as_ref().map_or(0, |_| 1) +
+            self.glusterfs.as_ref().map_or(0, |_| 1) +
+            self.host_path.as_ref().map_or(0, |_| 1) +
+            self.iscsi.as_ref().map_or(0, |_| 1) +
+            self.name.as_ref().map_or(0, |_| 1) +
+            self.nfs.as_ref().map_or(0, |_| 1) +
+            self.persistent_volume_claim.as_ref().map_or(0, |_| 1) +
+            self.photon_persistent_disk.as_ref().map_or(0, |_| 1) +
+            self.portworx_volume.as_ref().map_or(0, |_| 1) +
+            self.projected.as_ref().map_or(0, |_| 1) +
+            self.quobyte.as_ref().map_or(0, |_| 1) +
+            self.rbd.as_ref().map_or(0, |_| 1) +
+            self.scale_io.as_ref().map_or(0, |_| 1) +
+            self.secret.as_ref().map_or(0, |_| 1) +
+            self.storageos.as_ref().map_or(0, |_| 1) +
+            self.vsphere_volume.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.aws_elastic_block_store {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "awsElasticBlockStore", value)?;
+        }
+        if let Some(value) = &self.azure_disk {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "azureDisk", value)?;
+        }
+        if let Some(value) = &self.azure_file {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "azureFile", value)?;
+        }
+        if let Some(value) = &self.cephfs {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cephfs", value)?;
+        }
+        if let Some(value) = &self.cinder {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cinder", value)?;
+        }
+        if let Some(value) = &self.config_map {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "configMap", value)?;
+        }
+        if let Some(value) = &self.downward_api {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "downwardAPI", value)?;
+        }
+        if let Some(value) = &self.empty_dir {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "emptyDir", value)?;
+        }
+        if let Some(value) = &self.fc {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fc", value)?;
+        }
+        if let Some(value) = &self.flex_volume {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "flexVolume", value)?;
+        }
+        if let Some(value) = &self.flocker {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "flocker", value)?;
+        }
+        if let Some(value) = &self.gce_persistent_disk {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "gcePersistentDisk", value)?;
+        }
+        if let Some(value) = &self.git_repo {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "gitRepo", value)?;
+        }
+        if let Some(value) = &self.glusterfs {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "glusterfs", value)?;
+        }
+        if let Some(value) = &self.host_path {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostPath", value)?;
+        }
+        if let Some(value) = &self.iscsi {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "iscsi", value)?;
+        }
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        if let Some(value) = &self.nfs {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nfs", value)?;
+        }
+        if let Some(value) = &self.persistent_volume_claim {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "persistentVolumeClaim", value)?;
+        }
+        if let Some(value) = &self.photon_persistent_disk {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "photonPersistentDisk", value)?;
+        }
+        if let Some(value) = &self.portworx_volume {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "portworxVolume", value)?;
+        }
+        if let Some(value) = &self.projected {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "projected", value)?;
+        }
+        if let Some(value) = &self.quobyte {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "quobyte", value)?;
+        }
+        if let Some(value) = &self.rbd {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rbd", value)?;
+        }
+        if let Some(value) = &self.scale_io {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "scaleIO", value)?;
+        }
+        if let Some(value) = &self.secret {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secret", value)?;
+        }
+        if let Some(value) = &self.storageos {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "storageos", value)?;
+        }
+        if let Some(value) = &self.vsphere_volume {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "vsphereVolume", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Volume {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.Volume".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::Volume".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.Volume".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -449,8 +1339,9 @@ impl crate::schemars::JsonSchema for Volume {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "awsElasticBlockStore".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::AWSElasticBlockStoreVolumeSource>().into_object();
@@ -460,8 +1351,8 @@ impl crate::schemars::JsonSchema for Volume {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "azureDisk".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::AzureDiskVolumeSource>().into_object();
@@ -471,8 +1362,8 @@ impl crate::schemars::JsonSchema for Volume {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "azureFile".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::AzureFileVolumeSource>().into_object();
@@ -482,8 +1373,8 @@ impl crate::schemars::JsonSchema for Volume {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "cephfs".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::CephFSVolumeSource>().into_object();
@@ -493,8 +1384,8 @@ impl crate::schemars::JsonSchema for Volume {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "cinder".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::CinderVolumeSource>().into_object();
@@ -504,8 +1395,8 @@ impl crate::schemars::JsonSchema for Volume {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "configMap".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ConfigMapVolumeSource>().into_object();
@@ -515,8 +1406,8 @@ impl crate::schemars::JsonSchema for Volume {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "downwardAPI".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::DownwardAPIVolumeSource>().into_object();
@@ -526,8 +1417,8 @@ impl crate::schemars::JsonSchema for Volume {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "emptyDir".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::EmptyDirVolumeSource>().into_object();
@@ -537,8 +1428,8 @@ impl crate::schemars::JsonSchema for Volume {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "fc".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::FCVolumeSource>().into_object();
@@ -548,8 +1439,8 @@ impl crate::schemars::JsonSchema for Volume {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "flexVolume".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::FlexVolumeSource>().into_object();
@@ -559,8 +1450,8 @@ impl crate::schemars::JsonSchema for Volume {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "flocker".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::FlockerVolumeSource>().into_object();
@@ -570,8 +1461,8 @@ impl crate::schemars::JsonSchema for Volume {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "gcePersistentDisk".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::GCEPersistentDiskVolumeSource>().into_object();
@@ -581,8 +1472,8 @@ impl crate::schemars::JsonSchema for Volume {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "gitRepo".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::GitRepoVolumeSource>().into_object();
@@ -592,8 +1483,8 @@ impl crate::schemars::JsonSchema for Volume {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "glusterfs".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::GlusterfsVolumeSource>().into_object();
@@ -603,8 +1494,8 @@ impl crate::schemars::JsonSchema for Volume {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "hostPath".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::HostPathVolumeSource>().into_object();
@@ -614,8 +1505,8 @@ impl crate::schemars::JsonSchema for Volume {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "iscsi".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ISCSIVolumeSource>().into_object();
@@ -625,8 +1516,8 @@ impl crate::schemars::JsonSchema for Volume {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -636,8 +1527,8 @@ impl crate::schemars::JsonSchema for Volume {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "nfs".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::NFSVolumeSource>().into_object();
@@ -647,8 +1538,8 @@ impl crate::schemars::JsonSchema for Volume {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "persistentVolumeClaim".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::PersistentVolumeClaimVolumeSource>().into_object();
@@ -658,8 +1549,8 @@ impl crate::schemars::JsonSchema for Volume {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "photonPersistentDisk".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::PhotonPersistentDiskVolumeSource>().into_object();
@@ -669,8 +1560,8 @@ impl crate::schemars::JsonSchema for Volume {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "portworxVolume".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::PortworxVolumeSource>().into_object();
@@ -680,8 +1571,8 @@ impl crate::schemars::JsonSchema for Volume {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "projected".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ProjectedVolumeSource>().into_object();
@@ -691,8 +1582,8 @@ impl crate::schemars::JsonSchema for Volume {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "quobyte".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::QuobyteVolumeSource>().into_object();
@@ -702,8 +1593,8 @@ impl crate::schemars::JsonSchema for Volume {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "rbd".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::RBDVolumeSource>().into_object();
@@ -713,8 +1604,8 @@ impl crate::schemars::JsonSchema for Volume {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "scaleIO".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ScaleIOVolumeSource>().into_object();
@@ -724,8 +1615,8 @@ impl crate::schemars::JsonSchema for Volume {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "secret".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::SecretVolumeSource>().into_object();
@@ -735,8 +1626,8 @@ impl crate::schemars::JsonSchema for Volume {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "storageos".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::StorageOSVolumeSource>().into_object();
@@ -746,8 +1637,8 @@ impl crate::schemars::JsonSchema for Volume {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "vsphereVolume".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::VsphereVirtualDiskVolumeSource>().into_object();
@@ -757,8 +1648,9 @@ impl crate::schemars::JsonSchema for Volume {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "name",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -768,3 +1660,242 @@ impl crate::schemars::JsonSchema for Volume {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for Volume {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(Volume {
+            aws_elastic_block_store: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            azure_disk: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            azure_file: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            cephfs: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            cinder: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            config_map: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            downward_api: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            empty_dir: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            fc: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            flex_volume: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            flocker: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            gce_persistent_disk: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            git_repo: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            glusterfs: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            host_path: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            iscsi: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            nfs: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            persistent_volume_claim: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            photon_persistent_disk: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            portworx_volume: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            projected: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            quobyte: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            rbd: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            scale_io: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            secret: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            storageos: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            vsphere_volume: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
+
+/// The property of a [`Volume`] that is set, out of the properties that are mutually exclusive. Returned by [`Volume::source`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VolumeSource<'a> {
+    /// [`Volume::aws_elastic_block_store`]
+    AwsElasticBlockStore(&'a crate::api::core::v1::AWSElasticBlockStoreVolumeSource),
+
+    /// [`Volume::azure_disk`]
+    AzureDisk(&'a crate::api::core::v1::AzureDiskVolumeSource),
+
+    /// [`Volume::azure_file`]
+    AzureFile(&'a crate::api::core::v1::AzureFileVolumeSource),
+
+    /// [`Volume::cephfs`]
+    Cephfs(&'a crate::api::core::v1::CephFSVolumeSource),
+
+    /// [`Volume::cinder`]
+    Cinder(&'a crate::api::core::v1::CinderVolumeSource),
+
+    /// [`Volume::config_map`]
+    ConfigMap(&'a crate::api::core::v1::ConfigMapVolumeSource),
+
+    /// [`Volume::downward_api`]
+    DownwardAPI(&'a crate::api::core::v1::DownwardAPIVolumeSource),
+
+    /// [`Volume::empty_dir`]
+    EmptyDir(&'a crate::api::core::v1::EmptyDirVolumeSource),
+
+    /// [`Volume::fc`]
+    Fc(&'a crate::api::core::v1::FCVolumeSource),
+
+    /// [`Volume::flex_volume`]
+    FlexVolume(&'a crate::api::core::v1::FlexVolumeSource),
+
+    /// [`Volume::flocker`]
+    Flocker(&'a crate::api::core::v1::FlockerVolumeSource),
+
+    /// [`Volume::gce_persistent_disk`]
+    GcePersistentDisk(&'a crate::api::core::v1::GCEPersistentDiskVolumeSource),
+
+    /// [`Volume::git_repo`]
+    GitRepo(&'a crate::api::core::v1::GitRepoVolumeSource),
+
+    /// [`Volume::glusterfs`]
+    Glusterfs(&'a crate::api::core::v1::GlusterfsVolumeSource),
+
+    /// [`Volume::host_path`]
+    HostPath(&'a crate::api::core::v1::HostPathVolumeSource),
+
+    /// [`Volume::iscsi`]
+    Iscsi(&'a crate::api::core::v1::ISCSIVolumeSource),
+
+    /// [`Volume::nfs`]
+    Nfs(&'a crate::api::core::v1::NFSVolumeSource),
+
+    /// [`Volume::persistent_volume_claim`]
+    PersistentVolumeClaim(&'a crate::api::core::v1::PersistentVolumeClaimVolumeSource),
+
+    /// [`Volume::photon_persistent_disk`]
+    PhotonPersistentDisk(&'a crate::api::core::v1::PhotonPersistentDiskVolumeSource),
+
+    /// [`Volume::portworx_volume`]
+    PortworxVolume(&'a crate::api::core::v1::PortworxVolumeSource),
+
+    /// [`Volume::projected`]
+    Projected(&'a crate::api::core::v1::ProjectedVolumeSource),
+
+    /// [`Volume::quobyte`]
+    Quobyte(&'a crate::api::core::v1::QuobyteVolumeSource),
+
+    /// [`Volume::rbd`]
+    Rbd(&'a crate::api::core::v1::RBDVolumeSource),
+
+    /// [`Volume::scale_io`]
+    ScaleIO(&'a crate::api::core::v1::ScaleIOVolumeSource),
+
+    /// [`Volume::secret`]
+    Secret(&'a crate::api::core::v1::SecretVolumeSource),
+
+    /// [`Volume::storageos`]
+    Storageos(&'a crate::api::core::v1::StorageOSVolumeSource),
+
+    /// [`Volume::vsphere_volume`]
+    VsphereVolume(&'a crate::api::core::v1::VsphereVirtualDiskVolumeSource),
+}
+
+impl Volume {
+    /// Returns the property of this `Volume` that is set, out of the properties that are mutually exclusive.
+    ///
+    /// Returns `None` if none of them is set. If more than one of them is set, which the API server rejects,
+    /// the one that comes first in the order of the variants of [`VolumeSource`] is returned.
+    pub fn source(&self) -> Option<VolumeSource<'_>> {
+        if let Some(value) = &self.aws_elastic_block_store {
+            return Some(VolumeSource::AwsElasticBlockStore(value));
+        }
+
+        if let Some(value) = &self.azure_disk {
+            return Some(VolumeSource::AzureDisk(value));
+        }
+
+        if let Some(value) = &self.azure_file {
+            return Some(VolumeSource::AzureFile(value));
+        }
+
+        if let Some(value) = &self.cephfs {
+            return Some(VolumeSource::Cephfs(value));
+        }
+
+        if let Some(value) = &self.cinder {
+            return Some(VolumeSource::Cinder(value));
+        }
+
+        if let Some(value) = &self.config_map {
+            return Some(VolumeSource::ConfigMap(value));
+        }
+
+        if let Some(value) = &self.downward_api {
+            return Some(VolumeSource::DownwardAPI(value));
+        }
+
+        if let Some(value) = &self.empty_dir {
+            return Some(VolumeSource::EmptyDir(value));
+        }
+
+        if let Some(value) = &self.fc {
+            return Some(VolumeSource::Fc(value));
+        }
+
+        if let Some(value) = &self.flex_volume {
+            return Some(VolumeSource::FlexVolume(value));
+        }
+
+        if let Some(value) = &self.flocker {
+            return Some(VolumeSource::Flocker(value));
+        }
+
+        if let Some(value) = &self.gce_persistent_disk {
+            return Some(VolumeSource::GcePersistentDisk(value));
+        }
+
+        if let Some(value) = &self.git_repo {
+            return Some(VolumeSource::GitRepo(value));
+        }
+
+        if let Some(value) = &self.glusterfs {
+            return Some(VolumeSource::Glusterfs(value));
+        }
+
+        if let Some(value) = &self.host_path {
+            return Some(VolumeSource::HostPath(value));
+        }
+
+        if let Some(value) = &self.iscsi {
+            return Some(VolumeSource::Iscsi(value));
+        }
+
+        if let Some(value) = &self.nfs {
+            return Some(VolumeSource::Nfs(value));
+        }
+
+        if let Some(value) = &self.persistent_volume_claim {
+            return Some(VolumeSource::PersistentVolumeClaim(value));
+        }
+
+        if let Some(value) = &self.photon_persistent_disk {
+            return Some(VolumeSource::PhotonPersistentDisk(value));
+        }
+
+        if let Some(value) = &self.portworx_volume {
+            return Some(VolumeSource::PortworxVolume(value));
+        }
+
+        if let Some(value) = &self.projected {
+            return Some(VolumeSource::Projected(value));
+        }
+
+        if let Some(value) = &self.quobyte {
+            return Some(VolumeSource::Quobyte(value));
+        }
+
+        if let Some(value) = &self.rbd {
+            return Some(VolumeSource::Rbd(value));
+        }
+
+        if let Some(value) = &self.scale_io {
+            return Some(VolumeSource::ScaleIO(value));
+        }
+
+        if let Some(value) = &self.secret {
+            return Some(VolumeSource::Secret(value));
+        }
+
+        if let Some(value) = &self.storageos {
+            return Some(VolumeSource::Storageos(value));
+        }
+
+        if let Some(value) = &self.vsphere_volume {
+            return Some(VolumeSource::VsphereVolume(value));
+        }
+
+        None
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.VolumeProjection
 
 /// Projection that may be projected along with other supported volume types
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct VolumeProjection {
     /// information about the configMap data to project
     pub config_map: Option<crate::api::core::v1::ConfigMapProjection>,
@@ -16,43 +16,49 @@ pub struct VolumeProjection {
     pub service_account_token: Option<crate::api::core::v1::ServiceAccountTokenProjection>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for VolumeProjection {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_config_map,
-            Key_downward_api,
-            Key_secret,
-            Key_service_account_token,
-            Other,
-        }
+impl VolumeProjection {
+    /// Returns this value with [`VolumeProjection::config_map`] set to the given value.
+    #[must_use]
+    pub fn with_config_map(mut self, value: impl Into<crate::api::core::v1::ConfigMapProjection>) -> Self {
+        self.config_map = Some(value.into());
+        self
+    }
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+    /// Returns this value with [`VolumeProjection::downward_api`] set to the given value.
+    #[must_use]
+    pub fn with_downward_api(mut self, value: impl Into<crate::api::core::v1::DownwardAPIProjection>) -> Self {
+        self.downward_api = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`VolumeProjection::secret`] set to the given value.
+    #[must_use]
+    pub fn with_secret(mut self, value: impl Into<crate::api::core::v1::SecretProjection>) -> Self {
+        self.secret = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`VolumeProjection::service_account_token`] set to the given value.
+    #[must_use]
+    pub fn with_service_account_token(mut self, value: impl Into<crate::api::core::v1::ServiceAccountTokenProjection>) -> Self {
+        self.service_account_token = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "configMap" => Field::Key_config_map,
-                            "downwardAPI" => Field::Key_downward_api,
-                            "secret" => Field::Key_secret,
-                            "serviceAccountToken" => Field::Key_service_account_token,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for VolumeProjection {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl VolumeProjection {
+    const FIELDS: &'static [&'static str] = &[
+        "configMap",
+        "downwardAPI",
+        "secret",
+        "serviceAccountToken",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for VolumeProjection {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -68,13 +74,13 @@ impl<'de> crate::serde::Deserialize<'de> for VolumeProjection {
                 let mut value_secret: Option<crate::api::core::v1::SecretProjection> = None;
                 let mut value_service_account_token: Option<crate::api::core::v1::ServiceAccountTokenProjection> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("VolumeProjection", VolumeProjection::FIELDS))? {
                     match key {
-                        Field::Key_config_map => value_config_map = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_downward_api => value_downward_api = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_secret => value_secret = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_service_account_token => value_service_account_token = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_config_map = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_downward_api = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_secret = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_service_account_token = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -85,23 +91,94 @@ impl<'de> crate::serde::Deserialize<'de> for VolumeProjection {
                     service_account_token: value_service_account_token,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(VolumeProjection {
+                    config_map: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    downward_api: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    secret: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    service_account_token: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "VolumeProjection",
-            &[
-                "configMap",
-                "downwardAPI",
-                "secret",
-                "serviceAccountToken",
-            ],
+            VolumeProjection::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, VolumeProjection> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("VolumeProjection")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_config_map = false;
+                let mut has_downward_api = false;
+                let mut has_secret = false;
+                let mut has_service_account_token = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("VolumeProjection", VolumeProjection::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.config_map))?; has_config_map = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.downward_api))?; has_downward_api = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.secret))?; has_secret = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.service_account_token))?; has_service_account_token = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_config_map {
+                    self.0.config_map = None;
+                }
+                if !has_downward_api {
+                    self.0.downward_api = None;
+                }
+                if !has_secret {
+                    self.0.secret = None;
+                }
+                if !has_service_account_token {
+                    self.0.service_account_token = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.config_map))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.downward_api))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.secret))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.service_account_token))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "VolumeProjection",
+            VolumeProjection::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for VolumeProjection {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("VolumeProjection", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "configMap", &self.config_map)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "downwardAPI", &self.downward_api)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secret", &self.secret)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "serviceAccountToken", &self.service_account_token)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "VolumeProjection",
             self.config_map.as_ref().map_or(0, |_| 1) +
@@ -125,10 +202,114 @@ impl crate::serde::Serialize for VolumeProjection {
     }
 }
 
+/// A builder of [`VolumeProjection`]. Created with [`VolumeProjection::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct VolumeProjectionBuilder {
+    config_map: Option<crate::api::core::v1::ConfigMapProjection>,
+    downward_api: Option<crate::api::core::v1::DownwardAPIProjection>,
+    secret: Option<crate::api::core::v1::SecretProjection>,
+    service_account_token: Option<crate::api::core::v1::ServiceAccountTokenProjection>,
+}
+
+#[cfg(feature = "builder")]
+impl VolumeProjection {
+    /// Starts building a `VolumeProjection`. Its required fields must be set before [`VolumeProjectionBuilder::build`] is called.
+    pub fn builder() -> VolumeProjectionBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl VolumeProjectionBuilder {
+    /// Sets [`VolumeProjection::config_map`].
+    pub fn config_map(mut self, value: impl Into<crate::api::core::v1::ConfigMapProjection>) -> Self {
+        self.config_map = Some(value.into());
+        self
+    }
+
+    /// Sets [`VolumeProjection::downward_api`].
+    pub fn downward_api(mut self, value: impl Into<crate::api::core::v1::DownwardAPIProjection>) -> Self {
+        self.downward_api = Some(value.into());
+        self
+    }
+
+    /// Sets [`VolumeProjection::secret`].
+    pub fn secret(mut self, value: impl Into<crate::api::core::v1::SecretProjection>) -> Self {
+        self.secret = Some(value.into());
+        self
+    }
+
+    /// Sets [`VolumeProjection::service_account_token`].
+    pub fn service_account_token(mut self, value: impl Into<crate::api::core::v1::ServiceAccountTokenProjection>) -> Self {
+        self.service_account_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `VolumeProjection`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<VolumeProjection, crate::MissingFieldError> {
+        Ok(VolumeProjection {
+            config_map: self.config_map,
+            downward_api: self.downward_api,
+            secret: self.secret,
+            service_account_token: self.service_account_token,
+        })
+    }
+}
+
+/// The apply configuration of [`VolumeProjection`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VolumeProjectionApplyConfiguration {
+    /// See [`VolumeProjection::config_map`].
+    pub config_map: Option<crate::api::core::v1::ConfigMapProjectionApplyConfiguration>,
+
+    /// See [`VolumeProjection::downward_api`].
+    pub downward_api: Option<crate::api::core::v1::DownwardAPIProjectionApplyConfiguration>,
+
+    /// See [`VolumeProjection::secret`].
+    pub secret: Option<crate::api::core::v1::SecretProjectionApplyConfiguration>,
+
+    /// See [`VolumeProjection::service_account_token`].
+    pub service_account_token: Option<crate::api::core::v1::ServiceAccountTokenProjectionApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for VolumeProjectionApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "VolumeProjectionApplyConfiguration",
+            self.config_map.as_ref().map_or(0, |_| 1) +
+            self.downward_api.as_ref().map_or(0, |_| 1) +
+            self.secret.as_ref().map_or(0, |_| 1) +
+            self.service_account_token.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.config_map {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "configMap", value)?;
+        }
+        if let Some(value) = &self.downward_api {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "downwardAPI", value)?;
+        }
+        if let Some(value) = &self.secret {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secret", value)?;
+        }
+        if let Some(value) = &self.service_account_token {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "serviceAccountToken", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for VolumeProjection {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.VolumeProjection".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::VolumeProjection".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.VolumeProjection".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -139,8 +320,9 @@ impl crate::schemars::JsonSchema for VolumeProjection {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "configMap".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ConfigMapProjection>().into_object();
@@ -150,8 +332,8 @@ impl crate::schemars::JsonSchema for VolumeProjection {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "downwardAPI".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::DownwardAPIProjection>().into_object();
@@ -161,8 +343,8 @@ impl crate::schemars::JsonSchema for VolumeProjection {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "secret".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::SecretProjection>().into_object();
@@ -172,8 +354,8 @@ impl crate::schemars::JsonSchema for VolumeProjection {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "serviceAccountToken".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ServiceAccountTokenProjection>().into_object();
@@ -183,11 +365,66 @@ impl crate::schemars::JsonSchema for VolumeProjection {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for VolumeProjection {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(VolumeProjection {
+            config_map: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            downward_api: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            secret: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            service_account_token: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
+
+/// The property of a [`VolumeProjection`] that is set, out of the properties that are mutually exclusive. Returned by [`VolumeProjection::source`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VolumeProjectionSource<'a> {
+    /// [`VolumeProjection::config_map`]
+    ConfigMap(&'a crate::api::core::v1::ConfigMapProjection),
+
+    /// [`VolumeProjection::downward_api`]
+    DownwardAPI(&'a crate::api::core::v1::DownwardAPIProjection),
+
+    /// [`VolumeProjection::secret`]
+    Secret(&'a crate::api::core::v1::SecretProjection),
+
+    /// [`VolumeProjection::service_account_token`]
+    ServiceAccountToken(&'a crate::api::core::v1::ServiceAccountTokenProjection),
+}
+
+impl VolumeProjection {
+    /// Returns the property of this `VolumeProjection` that is set, out of the properties that are mutually exclusive.
+    ///
+    /// Returns `None` if none of them is set. If more than one of them is set, which the API server rejects,
+    /// the one that comes first in the order of the variants of [`VolumeProjectionSource`] is returned.
+    pub fn source(&self) -> Option<VolumeProjectionSource<'_>> {
+        if let Some(value) = &self.config_map {
+            return Some(VolumeProjectionSource::ConfigMap(value));
+        }
+
+        if let Some(value) = &self.downward_api {
+            return Some(VolumeProjectionSource::DownwardAPI(value));
+        }
+
+        if let Some(value) = &self.secret {
+            return Some(VolumeProjectionSource::Secret(value));
+        }
+
+        if let Some(value) = &self.service_account_token {
+            return Some(VolumeProjectionSource::ServiceAccountToken(value));
+        }
+
+        None
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.VsphereVirtualDiskVolumeSource
 
 /// Represents a vSphere volume resource.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct VsphereVirtualDiskVolumeSource {
     /// Filesystem type to mount. Must be a filesystem type supported by the host operating system. Ex. "ext4", "xfs", "ntfs". Implicitly inferred to be "ext4" if unspecified.
     pub fs_type: Option<String>,
@@ -16,43 +16,61 @@ pub struct VsphereVirtualDiskVolumeSource {
     pub volume_path: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for VsphereVirtualDiskVolumeSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_fs_type,
-            Key_storage_policy_id,
-            Key_storage_policy_name,
-            Key_volume_path,
-            Other,
+impl VsphereVirtualDiskVolumeSource {
+    /// Constructs a `VsphereVirtualDiskVolumeSource` from its required fields. Its optional fields are unset.
+    pub fn new(volume_path: impl Into<String>) -> Self {
+        VsphereVirtualDiskVolumeSource {
+            fs_type: None,
+            storage_policy_id: None,
+            storage_policy_name: None,
+            volume_path: volume_path.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl VsphereVirtualDiskVolumeSource {
+    /// Returns this value with [`VsphereVirtualDiskVolumeSource::fs_type`] set to the given value.
+    #[must_use]
+    pub fn with_fs_type(mut self, value: impl Into<String>) -> Self {
+        self.fs_type = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`VsphereVirtualDiskVolumeSource::storage_policy_id`] set to the given value.
+    #[must_use]
+    pub fn with_storage_policy_id(mut self, value: impl Into<String>) -> Self {
+        self.storage_policy_id = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`VsphereVirtualDiskVolumeSource::storage_policy_name`] set to the given value.
+    #[must_use]
+    pub fn with_storage_policy_name(mut self, value: impl Into<String>) -> Self {
+        self.storage_policy_name = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "fsType" => Field::Key_fs_type,
-                            "storagePolicyID" => Field::Key_storage_policy_id,
-                            "storagePolicyName" => Field::Key_storage_policy_name,
-                            "volumePath" => Field::Key_volume_path,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`VsphereVirtualDiskVolumeSource::volume_path`] set to the given value.
+    #[must_use]
+    pub fn with_volume_path(mut self, value: impl Into<String>) -> Self {
+        self.volume_path = value.into();
+        self
+    }
+}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl crate::MergeStrategyInfo for VsphereVirtualDiskVolumeSource {}
+
+impl VsphereVirtualDiskVolumeSource {
+    const FIELDS: &'static [&'static str] = &[
+        "fsType",
+        "storagePolicyID",
+        "storagePolicyName",
+        "volumePath",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for VsphereVirtualDiskVolumeSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -68,13 +86,13 @@ impl<'de> crate::serde::Deserialize<'de> for VsphereVirtualDiskVolumeSource {
                 let mut value_storage_policy_name: Option<String> = None;
                 let mut value_volume_path: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("VsphereVirtualDiskVolumeSource", VsphereVirtualDiskVolumeSource::FIELDS))? {
                     match key {
-                        Field::Key_fs_type => value_fs_type = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_storage_policy_id => value_storage_policy_id = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_storage_policy_name => value_storage_policy_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_volume_path => value_volume_path = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_fs_type = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_storage_policy_id = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_storage_policy_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_volume_path = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -82,26 +100,97 @@ impl<'de> crate::serde::Deserialize<'de> for VsphereVirtualDiskVolumeSource {
                     fs_type: value_fs_type,
                     storage_policy_id: value_storage_policy_id,
                     storage_policy_name: value_storage_policy_name,
-                    volume_path: value_volume_path.ok_or_else(|| crate::serde::de::Error::missing_field("volumePath"))?,
+                    volume_path: value_volume_path.ok_or_else(|| crate::serde2::missing_field("VsphereVirtualDiskVolumeSource", "volumePath", &["volumePath"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(VsphereVirtualDiskVolumeSource {
+                    fs_type: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    storage_policy_id: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    storage_policy_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    volume_path: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "VsphereVirtualDiskVolumeSource",
-            &[
-                "fsType",
-                "storagePolicyID",
-                "storagePolicyName",
-                "volumePath",
-            ],
+            VsphereVirtualDiskVolumeSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, VsphereVirtualDiskVolumeSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("VsphereVirtualDiskVolumeSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_fs_type = false;
+                let mut has_storage_policy_id = false;
+                let mut has_storage_policy_name = false;
+                let mut has_volume_path = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("VsphereVirtualDiskVolumeSource", VsphereVirtualDiskVolumeSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.fs_type))?; has_fs_type = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.storage_policy_id))?; has_storage_policy_id = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.storage_policy_name))?; has_storage_policy_name = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.volume_path))?; has_volume_path = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_fs_type {
+                    self.0.fs_type = None;
+                }
+                if !has_storage_policy_id {
+                    self.0.storage_policy_id = None;
+                }
+                if !has_storage_policy_name {
+                    self.0.storage_policy_name = None;
+                }
+                if !has_volume_path {
+                    return Err(crate::serde2::missing_field("VsphereVirtualDiskVolumeSource", "volumePath", &["volumePath"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.fs_type))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.storage_policy_id))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.storage_policy_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.volume_path))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "VsphereVirtualDiskVolumeSource",
+            VsphereVirtualDiskVolumeSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for VsphereVirtualDiskVolumeSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("VsphereVirtualDiskVolumeSource", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsType", &self.fs_type)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "storagePolicyID", &self.storage_policy_id)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "storagePolicyName", &self.storage_policy_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumePath", &self.volume_path)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "VsphereVirtualDiskVolumeSource",
             1 +
@@ -123,10 +212,114 @@ impl crate::serde::Serialize for VsphereVirtualDiskVolumeSource {
     }
 }
 
+/// A builder of [`VsphereVirtualDiskVolumeSource`]. Created with [`VsphereVirtualDiskVolumeSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct VsphereVirtualDiskVolumeSourceBuilder {
+    fs_type: Option<String>,
+    storage_policy_id: Option<String>,
+    storage_policy_name: Option<String>,
+    volume_path: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl VsphereVirtualDiskVolumeSource {
+    /// Starts building a `VsphereVirtualDiskVolumeSource`. Its required fields must be set before [`VsphereVirtualDiskVolumeSourceBuilder::build`] is called.
+    pub fn builder() -> VsphereVirtualDiskVolumeSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl VsphereVirtualDiskVolumeSourceBuilder {
+    /// Sets [`VsphereVirtualDiskVolumeSource::fs_type`].
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`VsphereVirtualDiskVolumeSource::storage_policy_id`].
+    pub fn storage_policy_id(mut self, value: impl Into<String>) -> Self {
+        self.storage_policy_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`VsphereVirtualDiskVolumeSource::storage_policy_name`].
+    pub fn storage_policy_name(mut self, value: impl Into<String>) -> Self {
+        self.storage_policy_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`VsphereVirtualDiskVolumeSource::volume_path`].
+    pub fn volume_path(mut self, value: impl Into<String>) -> Self {
+        self.volume_path = Some(value.into());
+        self
+    }
+
+    /// Builds the `VsphereVirtualDiskVolumeSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<VsphereVirtualDiskVolumeSource, crate::MissingFieldError> {
+        Ok(VsphereVirtualDiskVolumeSource {
+            fs_type: self.fs_type,
+            storage_policy_id: self.storage_policy_id,
+            storage_policy_name: self.storage_policy_name,
+            volume_path: self.volume_path.ok_or_else(|| crate::MissingFieldError::new("VsphereVirtualDiskVolumeSource", "volumePath"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`VsphereVirtualDiskVolumeSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VsphereVirtualDiskVolumeSourceApplyConfiguration {
+    /// See [`VsphereVirtualDiskVolumeSource::fs_type`].
+    pub fs_type: Option<String>,
+
+    /// See [`VsphereVirtualDiskVolumeSource::storage_policy_id`].
+    pub storage_policy_id: Option<String>,
+
+    /// See [`VsphereVirtualDiskVolumeSource::storage_policy_name`].
+    pub storage_policy_name: Option<String>,
+
+    /// See [`VsphereVirtualDiskVolumeSource::volume_path`].
+    pub volume_path: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for VsphereVirtualDiskVolumeSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "VsphereVirtualDiskVolumeSourceApplyConfiguration",
+            self.fs_type.as_ref().map_or(0, |_| 1) +
+            self.storage_policy_id.as_ref().map_or(0, |_| 1) +
+            self.storage_policy_name.as_ref().map_or(0, |_| 1) +
+            self.volume_path.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.fs_type {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsType", value)?;
+        }
+        if let Some(value) = &self.storage_policy_id {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "storagePolicyID", value)?;
+        }
+        if let Some(value) = &self.storage_policy_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "storagePolicyName", value)?;
+        }
+        if let Some(value) = &self.volume_path {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumePath", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for VsphereVirtualDiskVolumeSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.VsphereVirtualDiskVolumeSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::VsphereVirtualDiskVolumeSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.VsphereVirtualDiskVolumeSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -137,8 +330,9 @@ impl crate::schemars::JsonSchema for VsphereVirtualDiskVolumeSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "fsType".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -148,8 +342,8 @@ impl crate::schemars::JsonSchema for VsphereVirtualDiskVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "storagePolicyID".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -159,8 +353,8 @@ impl crate::schemars::JsonSchema for VsphereVirtualDiskVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "storagePolicyName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -170,8 +364,8 @@ impl crate::schemars::JsonSchema for VsphereVirtualDiskVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "volumePath".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -181,8 +375,9 @@ impl crate::schemars::JsonSchema for VsphereVirtualDiskVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "volumePath",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -192,3 +387,15 @@ impl crate::schemars::JsonSchema for VsphereVirtualDiskVolumeSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for VsphereVirtualDiskVolumeSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(VsphereVirtualDiskVolumeSource {
+            fs_type: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            storage_policy_id: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            storage_policy_name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            volume_path: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.WeightedPodAffinityTerm
 
 /// The weights of all of the matched WeightedPodAffinityTerm fields are added per-node to find the most preferred node(s)
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct WeightedPodAffinityTerm {
     /// Required. A pod affinity term, associated with the corresponding weight.
     pub pod_affinity_term: crate::api::core::v1::PodAffinityTerm,
@@ -10,39 +10,43 @@ pub struct WeightedPodAffinityTerm {
     pub weight: i32,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for WeightedPodAffinityTerm {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_pod_affinity_term,
-            Key_weight,
-            Other,
+impl WeightedPodAffinityTerm {
+    /// Constructs a `WeightedPodAffinityTerm` from its required fields. Its optional fields are unset.
+    pub fn new(pod_affinity_term: impl Into<crate::api::core::v1::PodAffinityTerm>, weight: impl Into<i32>) -> Self {
+        WeightedPodAffinityTerm {
+            pod_affinity_term: pod_affinity_term.into(),
+            weight: weight.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl WeightedPodAffinityTerm {
+    /// Returns this value with [`WeightedPodAffinityTerm::pod_affinity_term`] set to the given value.
+    #[must_use]
+    pub fn with_pod_affinity_term(mut self, value: impl Into<crate::api::core::v1::PodAffinityTerm>) -> Self {
+        self.pod_affinity_term = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`WeightedPodAffinityTerm::weight`] set to the given value.
+    #[must_use]
+    pub fn with_weight(mut self, value: impl Into<i32>) -> Self {
+        self.weight = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "podAffinityTerm" => Field::Key_pod_affinity_term,
-                            "weight" => Field::Key_weight,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for WeightedPodAffinityTerm {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl WeightedPodAffinityTerm {
+    const FIELDS: &'static [&'static str] = &[
+        "podAffinityTerm",
+        "weight",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for WeightedPodAffinityTerm {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +60,91 @@ impl<'de> crate::serde::Deserialize<'de> for WeightedPodAffinityTerm {
                 let mut value_pod_affinity_term: Option<crate::api::core::v1::PodAffinityTerm> = None;
                 let mut value_weight: Option<i32> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("WeightedPodAffinityTerm", WeightedPodAffinityTerm::FIELDS))? {
                     match key {
-                        Field::Key_pod_affinity_term => value_pod_affinity_term = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_weight => value_weight = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_pod_affinity_term = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_weight = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(WeightedPodAffinityTerm {
-                    pod_affinity_term: value_pod_affinity_term.ok_or_else(|| crate::serde::de::Error::missing_field("podAffinityTerm"))?,
-                    weight: value_weight.ok_or_else(|| crate::serde::de::Error::missing_field("weight"))?,
+                    pod_affinity_term: value_pod_affinity_term.ok_or_else(|| crate::serde2::missing_field("WeightedPodAffinityTerm", "podAffinityTerm", &["podAffinityTerm", "weight"]))?,
+                    weight: value_weight.ok_or_else(|| crate::serde2::missing_field("WeightedPodAffinityTerm", "weight", &["podAffinityTerm", "weight"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(WeightedPodAffinityTerm {
+                    pod_affinity_term: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    weight: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "WeightedPodAffinityTerm",
-            &[
-                "podAffinityTerm",
-                "weight",
-            ],
+            WeightedPodAffinityTerm::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, WeightedPodAffinityTerm> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("WeightedPodAffinityTerm")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_pod_affinity_term = false;
+                let mut has_weight = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("WeightedPodAffinityTerm", WeightedPodAffinityTerm::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.pod_affinity_term))?; has_pod_affinity_term = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.weight))?; has_weight = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_pod_affinity_term {
+                    return Err(crate::serde2::missing_field("WeightedPodAffinityTerm", "podAffinityTerm", &["podAffinityTerm", "weight"]));
+                }
+                if !has_weight {
+                    return Err(crate::serde2::missing_field("WeightedPodAffinityTerm", "weight", &["podAffinityTerm", "weight"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.pod_affinity_term))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.weight))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "WeightedPodAffinityTerm",
+            WeightedPodAffinityTerm::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for WeightedPodAffinityTerm {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("WeightedPodAffinityTerm", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "podAffinityTerm", &self.pod_affinity_term)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "weight", &self.weight)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "WeightedPodAffinityTerm",
             2,
@@ -94,10 +155,84 @@ impl crate::serde::Serialize for WeightedPodAffinityTerm {
     }
 }
 
+/// A builder of [`WeightedPodAffinityTerm`]. Created with [`WeightedPodAffinityTerm::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct WeightedPodAffinityTermBuilder {
+    pod_affinity_term: Option<crate::api::core::v1::PodAffinityTerm>,
+    weight: Option<i32>,
+}
+
+#[cfg(feature = "builder")]
+impl WeightedPodAffinityTerm {
+    /// Starts building a `WeightedPodAffinityTerm`. Its required fields must be set before [`WeightedPodAffinityTermBuilder::build`] is called.
+    pub fn builder() -> WeightedPodAffinityTermBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl WeightedPodAffinityTermBuilder {
+    /// Sets [`WeightedPodAffinityTerm::pod_affinity_term`].
+    pub fn pod_affinity_term(mut self, value: impl Into<crate::api::core::v1::PodAffinityTerm>) -> Self {
+        self.pod_affinity_term = Some(value.into());
+        self
+    }
+
+    /// Sets [`WeightedPodAffinityTerm::weight`].
+    pub fn weight(mut self, value: impl Into<i32>) -> Self {
+        self.weight = Some(value.into());
+        self
+    }
+
+    /// Builds the `WeightedPodAffinityTerm`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<WeightedPodAffinityTerm, crate::MissingFieldError> {
+        Ok(WeightedPodAffinityTerm {
+            pod_affinity_term: self.pod_affinity_term.ok_or_else(|| crate::MissingFieldError::new("WeightedPodAffinityTerm", "podAffinityTerm"))?,
+            weight: self.weight.ok_or_else(|| crate::MissingFieldError::new("WeightedPodAffinityTerm", "weight"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`WeightedPodAffinityTerm`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WeightedPodAffinityTermApplyConfiguration {
+    /// See [`WeightedPodAffinityTerm::pod_affinity_term`].
+    pub pod_affinity_term: Option<crate::api::core::v1::PodAffinityTermApplyConfiguration>,
+
+    /// See [`WeightedPodAffinityTerm::weight`].
+    pub weight: Option<i32>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for WeightedPodAffinityTermApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "WeightedPodAffinityTermApplyConfiguration",
+            self.pod_affinity_term.as_ref().map_or(0, |_| 1) +
+            self.weight.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.pod_affinity_term {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "podAffinityTerm", value)?;
+        }
+        if let Some(value) = &self.weight {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "weight", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for WeightedPodAffinityTerm {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.WeightedPodAffinityTerm".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::WeightedPodAffinityTerm".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.WeightedPodAffinityTerm".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -108,8 +243,9 @@ impl crate::schemars::JsonSchema for WeightedPodAffinityTerm {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "podAffinityTerm".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::PodAffinityTerm>().into_object();
@@ -119,8 +255,8 @@ impl crate::schemars::JsonSchema for WeightedPodAffinityTerm {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "weight".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -131,8 +267,9 @@ impl crate::schemars::JsonSchema for WeightedPodAffinityTerm {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "podAffinityTerm",
                     "weight",
@@ -143,3 +280,13 @@ impl crate::schemars::JsonSchema for WeightedPodAffinityTerm {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for WeightedPodAffinityTerm {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(WeightedPodAffinityTerm {
+            pod_affinity_term: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            weight: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -1,21 +1,25 @@
 // Generated from definition io.k8s.api.events.v1beta1.Event
 
 /// Event is a report of an event somewhere in the cluster. It generally denotes some state change in the system.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct Event {
     /// What action was taken/failed regarding to the regarding object.
     pub action: Option<String>,
 
     /// Deprecated field assuring backward compatibility with core.v1 Event type
+    #[deprecated(note = "Deprecated field assuring backward compatibility with core.v1 Event type")]
     pub deprecated_count: Option<i32>,
 
     /// Deprecated field assuring backward compatibility with core.v1 Event type
+    #[deprecated(note = "Deprecated field assuring backward compatibility with core.v1 Event type")]
     pub deprecated_first_timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
 
     /// Deprecated field assuring backward compatibility with core.v1 Event type
+    #[deprecated(note = "Deprecated field assuring backward compatibility with core.v1 Event type")]
     pub deprecated_last_timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
 
     /// Deprecated field assuring backward compatibility with core.v1 Event type
+    #[deprecated(note = "Deprecated field assuring backward compatibility with core.v1 Event type")]
     pub deprecated_source: Option<crate::api::core::v1::EventSource>,
 
     /// Required. Time when this Event was first observed.
@@ -48,6 +52,136 @@ pub struct Event {
     pub type_: Option<String>,
 }
 
+impl Event {
+    /// Constructs a `Event` from its required fields. Its optional fields are unset.
+    pub fn new(event_time: impl Into<crate::apimachinery::pkg::apis::meta::v1::MicroTime>, metadata: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        Event {
+            action: None,
+            deprecated_count: None,
+            deprecated_first_timestamp: None,
+            deprecated_last_timestamp: None,
+            deprecated_source: None,
+            event_time: event_time.into(),
+            metadata: metadata.into(),
+            note: None,
+            reason: None,
+            regarding: None,
+            related: None,
+            reporting_controller: None,
+            reporting_instance: None,
+            series: None,
+            type_: None,
+        }
+    }
+}
+
+impl Event {
+    /// Returns this value with [`Event::action`] set to the given value.
+    #[must_use]
+    pub fn with_action(mut self, value: impl Into<String>) -> Self {
+        self.action = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Event::deprecated_count`] set to the given value.
+    #[must_use]
+    pub fn with_deprecated_count(mut self, value: impl Into<i32>) -> Self {
+        self.deprecated_count = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Event::deprecated_first_timestamp`] set to the given value.
+    #[must_use]
+    pub fn with_deprecated_first_timestamp(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.deprecated_first_timestamp = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Event::deprecated_last_timestamp`] set to the given value.
+    #[must_use]
+    pub fn with_deprecated_last_timestamp(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.deprecated_last_timestamp = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Event::deprecated_source`] set to the given value.
+    #[must_use]
+    pub fn with_deprecated_source(mut self, value: impl Into<crate::api::core::v1::EventSource>) -> Self {
+        self.deprecated_source = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Event::event_time`] set to the given value.
+    #[must_use]
+    pub fn with_event_time(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::MicroTime>) -> Self {
+        self.event_time = value.into();
+        self
+    }
+
+    /// Returns this value with [`Event::metadata`] set to the given value.
+    #[must_use]
+    pub fn with_metadata(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        self.metadata = value.into();
+        self
+    }
+
+    /// Returns this value with [`Event::note`] set to the given value.
+    #[must_use]
+    pub fn with_note(mut self, value: impl Into<String>) -> Self {
+        self.note = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Event::reason`] set to the given value.
+    #[must_use]
+    pub fn with_reason(mut self, value: impl Into<String>) -> Self {
+        self.reason = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Event::regarding`] set to the given value.
+    #[must_use]
+    pub fn with_regarding(mut self, value: impl Into<crate::api::core::v1::ObjectReference>) -> Self {
+        self.regarding = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Event::related`] set to the given value.
+    #[must_use]
+    pub fn with_related(mut self, value: impl Into<crate::api::core::v1::ObjectReference>) -> Self {
+        self.related = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Event::reporting_controller`] set to the given value.
+    #[must_use]
+    pub fn with_reporting_controller(mut self, value: impl Into<String>) -> Self {
+        self.reporting_controller = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Event::reporting_instance`] set to the given value.
+    #[must_use]
+    pub fn with_reporting_instance(mut self, value: impl Into<String>) -> Self {
+        self.reporting_instance = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Event::series`] set to the given value.
+    #[must_use]
+    pub fn with_series(mut self, value: impl Into<crate::api::events::v1beta1::EventSeries>) -> Self {
+        self.series = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Event::type_`] set to the given value.
+    #[must_use]
+    pub fn with_type(mut self, value: impl Into<String>) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+}
+
 // Begin events.k8s.io/v1beta1/Event
 
 // Generated from operation createEventsV1beta1NamespacedEvent
@@ -74,6 +208,7 @@ impl Event {
         body: &crate::api::events::v1beta1::Event,
         optional: crate::CreateOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::CreateResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/events.k8s.io/v1beta1/namespaces/{namespace}/events?",
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -117,6 +252,7 @@ impl Event {
         delete_optional: crate::DeleteOptional<'_>,
         list_optional: crate::ListOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::DeleteResponse<crate::List<Self>>>), crate::RequestError> {
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/events.k8s.io/v1beta1/namespaces/{namespace}/events?",
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -160,6 +296,8 @@ impl Event {
         namespace: &str,
         optional: crate::DeleteOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::DeleteResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/events.k8s.io/v1beta1/namespaces/{namespace}/events/{name}",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -230,6 +368,7 @@ impl Event {
         namespace: &str,
         optional: crate::ListOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::ListResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/events.k8s.io/v1beta1/namespaces/{namespace}/events?",
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -275,6 +414,8 @@ impl Event {
         body: &crate::apimachinery::pkg::apis::meta::v1::Patch,
         optional: crate::PatchOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::PatchResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/events.k8s.io/v1beta1/namespaces/{namespace}/events/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -328,6 +469,8 @@ impl Event {
             export,
             pretty,
         } = optional;
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/events.k8s.io/v1beta1/namespaces/{namespace}/events/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -432,6 +575,8 @@ impl Event {
         body: &crate::api::events::v1beta1::Event,
         optional: crate::ReplaceOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::ReplaceResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/events.k8s.io/v1beta1/namespaces/{namespace}/events/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -505,6 +650,7 @@ impl Event {
         namespace: &str,
         optional: crate::WatchOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::WatchResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/events.k8s.io/v1beta1/namespaces/{namespace}/events?",
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -529,6 +675,9 @@ impl crate::Resource for Event {
     const KIND: &'static str = "Event";
     const VERSION: &'static str = "v1beta1";
     const URL_PATH_SEGMENT: &'static str = "events";
+    const VERBS: &'static [&'static str] = &["create", "delete", "deletecollection", "get", "list", "patch", "update", "watch"];
+    const SINGULAR: &'static str = "event";
+    const SHORT_NAMES: &'static [&'static str] = &["ev"];
     type Scope = crate::NamespaceResourceScope;
 }
 
@@ -548,69 +697,42 @@ impl crate::Metadata for Event {
     }
 }
 
-impl<'de> crate::serde::Deserialize<'de> for Event {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_api_version,
-            Key_kind,
-            Key_action,
-            Key_deprecated_count,
-            Key_deprecated_first_timestamp,
-            Key_deprecated_last_timestamp,
-            Key_deprecated_source,
-            Key_event_time,
-            Key_metadata,
-            Key_note,
-            Key_reason,
-            Key_regarding,
-            Key_related,
-            Key_reporting_controller,
-            Key_reporting_instance,
-            Key_series,
-            Key_type_,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl std::fmt::Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(<Self as crate::Resource>::URL_PATH_SEGMENT)?;
+        for part in [&self.metadata.namespace, &self.metadata.name].iter().copied().flatten() {
+            write!(f, "/{part}")?;
+        }
+        Ok(())
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "apiVersion" => Field::Key_api_version,
-                            "kind" => Field::Key_kind,
-                            "action" => Field::Key_action,
-                            "deprecatedCount" => Field::Key_deprecated_count,
-                            "deprecatedFirstTimestamp" => Field::Key_deprecated_first_timestamp,
-                            "deprecatedLastTimestamp" => Field::Key_deprecated_last_timestamp,
-                            "deprecatedSource" => Field::Key_deprecated_source,
-                            "eventTime" => Field::Key_event_time,
-                            "metadata" => Field::Key_metadata,
-                            "note" => Field::Key_note,
-                            "reason" => Field::Key_reason,
-                            "regarding" => Field::Key_regarding,
-                            "related" => Field::Key_related,
-                            "reportingController" => Field::Key_reporting_controller,
-                            "reportingInstance" => Field::Key_reporting_instance,
-                            "series" => Field::Key_series,
-                            "type" => Field::Key_type_,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for Event {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl Event {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "kind",
+        "action",
+        "deprecatedCount",
+        "deprecatedFirstTimestamp",
+        "deprecatedLastTimestamp",
+        "deprecatedSource",
+        "eventTime",
+        "metadata",
+        "note",
+        "reason",
+        "regarding",
+        "related",
+        "reportingController",
+        "reportingInstance",
+        "series",
+        "type",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for Event {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -637,36 +759,36 @@ impl<'de> crate::serde::Deserialize<'de> for Event {
                 let mut value_series: Option<crate::api::events::v1beta1::EventSeries> = None;
                 let mut value_type_: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Event", Event::FIELDS))? {
                     match key {
-                        Field::Key_api_version => {
+                        Some(0) => {
                             let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_api_version != <Self::Value as crate::Resource>::API_VERSION {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<Self::Value as crate::Resource>::API_VERSION));
                             }
                         },
-                        Field::Key_kind => {
+                        Some(1) => {
                             let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_kind != <Self::Value as crate::Resource>::KIND {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<Self::Value as crate::Resource>::KIND));
                             }
                         },
-                        Field::Key_action => value_action = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_deprecated_count => value_deprecated_count = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_deprecated_first_timestamp => value_deprecated_first_timestamp = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_deprecated_last_timestamp => value_deprecated_last_timestamp = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_deprecated_source => value_deprecated_source = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_event_time => value_event_time = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_metadata => value_metadata = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_note => value_note = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_reason => value_reason = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_regarding => value_regarding = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_related => value_related = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_reporting_controller => value_reporting_controller = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_reporting_instance => value_reporting_instance = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_series => value_series = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_type_ => value_type_ = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(2) => value_action = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_deprecated_count = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_deprecated_first_timestamp = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_deprecated_last_timestamp = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_deprecated_source = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(7) => value_event_time = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(8) => value_metadata = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(9) => value_note = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(10) => value_reason = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(11) => value_regarding = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(12) => value_related = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(13) => value_reporting_controller = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(14) => value_reporting_instance = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(15) => value_series = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(16) => value_type_ = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -676,8 +798,8 @@ impl<'de> crate::serde::Deserialize<'de> for Event {
                     deprecated_first_timestamp: value_deprecated_first_timestamp,
                     deprecated_last_timestamp: value_deprecated_last_timestamp,
                     deprecated_source: value_deprecated_source,
-                    event_time: value_event_time.ok_or_else(|| crate::serde::de::Error::missing_field("eventTime"))?,
-                    metadata: value_metadata.ok_or_else(|| crate::serde::de::Error::missing_field("metadata"))?,
+                    event_time: value_event_time.ok_or_else(|| crate::serde2::missing_field("Event", "eventTime", &["eventTime", "metadata"]))?,
+                    metadata: value_metadata.ok_or_else(|| crate::serde2::missing_field("Event", "metadata", &["eventTime", "metadata"]))?,
                     note: value_note,
                     reason: value_reason,
                     regarding: value_regarding,
@@ -688,36 +810,194 @@ impl<'de> crate::serde::Deserialize<'de> for Event {
                     type_: value_type_,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(Event {
+                    action: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    deprecated_count: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    deprecated_first_timestamp: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    deprecated_last_timestamp: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    deprecated_source: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    event_time: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    metadata: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    note: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                    reason: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?,
+                    regarding: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?,
+                    related: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?,
+                    reporting_controller: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(11, &self))?,
+                    reporting_instance: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(12, &self))?,
+                    series: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(13, &self))?,
+                    type_: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(14, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             <Self as crate::Resource>::KIND,
-            &[
-                "apiVersion",
-                "kind",
-                "action",
-                "deprecatedCount",
-                "deprecatedFirstTimestamp",
-                "deprecatedLastTimestamp",
-                "deprecatedSource",
-                "eventTime",
-                "metadata",
-                "note",
-                "reason",
-                "regarding",
-                "related",
-                "reportingController",
-                "reportingInstance",
-                "series",
-                "type",
-            ],
+            Event::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, Event> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<Event as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_action = false;
+                let mut has_deprecated_count = false;
+                let mut has_deprecated_first_timestamp = false;
+                let mut has_deprecated_last_timestamp = false;
+                let mut has_deprecated_source = false;
+                let mut has_event_time = false;
+                let mut has_metadata = false;
+                let mut has_note = false;
+                let mut has_reason = false;
+                let mut has_regarding = false;
+                let mut has_related = false;
+                let mut has_reporting_controller = false;
+                let mut has_reporting_instance = false;
+                let mut has_series = false;
+                let mut has_type_ = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Event", Event::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <Event as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<Event as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <Event as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<Event as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.action))?; has_action = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.deprecated_count))?; has_deprecated_count = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.deprecated_first_timestamp))?; has_deprecated_first_timestamp = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.deprecated_last_timestamp))?; has_deprecated_last_timestamp = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.deprecated_source))?; has_deprecated_source = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.event_time))?; has_event_time = true; },
+                        Some(8) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metadata))?; has_metadata = true; },
+                        Some(9) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.note))?; has_note = true; },
+                        Some(10) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.reason))?; has_reason = true; },
+                        Some(11) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.regarding))?; has_regarding = true; },
+                        Some(12) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.related))?; has_related = true; },
+                        Some(13) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.reporting_controller))?; has_reporting_controller = true; },
+                        Some(14) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.reporting_instance))?; has_reporting_instance = true; },
+                        Some(15) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.series))?; has_series = true; },
+                        Some(16) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.type_))?; has_type_ = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_action {
+                    self.0.action = None;
+                }
+                if !has_deprecated_count {
+                    self.0.deprecated_count = None;
+                }
+                if !has_deprecated_first_timestamp {
+                    self.0.deprecated_first_timestamp = None;
+                }
+                if !has_deprecated_last_timestamp {
+                    self.0.deprecated_last_timestamp = None;
+                }
+                if !has_deprecated_source {
+                    self.0.deprecated_source = None;
+                }
+                if !has_event_time {
+                    return Err(crate::serde2::missing_field("Event", "eventTime", &["eventTime", "metadata"]));
+                }
+                if !has_metadata {
+                    return Err(crate::serde2::missing_field("Event", "metadata", &["eventTime", "metadata"]));
+                }
+                if !has_note {
+                    self.0.note = None;
+                }
+                if !has_reason {
+                    self.0.reason = None;
+                }
+                if !has_regarding {
+                    self.0.regarding = None;
+                }
+                if !has_related {
+                    self.0.related = None;
+                }
+                if !has_reporting_controller {
+                    self.0.reporting_controller = None;
+                }
+                if !has_reporting_instance {
+                    self.0.reporting_instance = None;
+                }
+                if !has_series {
+                    self.0.series = None;
+                }
+                if !has_type_ {
+                    self.0.type_ = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.action))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.deprecated_count))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.deprecated_first_timestamp))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.deprecated_last_timestamp))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.deprecated_source))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.event_time))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metadata))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.note))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.reason))?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.regarding))?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.related))?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.reporting_controller))?.ok_or_else(|| crate::serde::de::Error::invalid_length(11, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.reporting_instance))?.ok_or_else(|| crate::serde::de::Error::invalid_length(12, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.series))?.ok_or_else(|| crate::serde::de::Error::invalid_length(13, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.type_))?.ok_or_else(|| crate::serde::de::Error::invalid_length(14, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            Event::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for Event {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct(<Self as crate::Resource>::KIND, 15)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "action", &self.action)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "deprecatedCount", &self.deprecated_count)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "deprecatedFirstTimestamp", &self.deprecated_first_timestamp)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "deprecatedLastTimestamp", &self.deprecated_last_timestamp)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "deprecatedSource", &self.deprecated_source)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "eventTime", &self.event_time)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", &self.metadata)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "note", &self.note)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reason", &self.reason)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "regarding", &self.regarding)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "related", &self.related)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reportingController", &self.reporting_controller)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reportingInstance", &self.reporting_instance)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "series", &self.series)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "type", &self.type_)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             <Self as crate::Resource>::KIND,
             4 +
@@ -782,10 +1062,282 @@ impl crate::serde::Serialize for Event {
     }
 }
 
+/// A builder of [`Event`]. Created with [`Event::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct EventBuilder {
+    action: Option<String>,
+    deprecated_count: Option<i32>,
+    deprecated_first_timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+    deprecated_last_timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+    deprecated_source: Option<crate::api::core::v1::EventSource>,
+    event_time: Option<crate::apimachinery::pkg::apis::meta::v1::MicroTime>,
+    metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>,
+    note: Option<String>,
+    reason: Option<String>,
+    regarding: Option<crate::api::core::v1::ObjectReference>,
+    related: Option<crate::api::core::v1::ObjectReference>,
+    reporting_controller: Option<String>,
+    reporting_instance: Option<String>,
+    series: Option<crate::api::events::v1beta1::EventSeries>,
+    type_: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl Event {
+    /// Starts building a `Event`. Its required fields must be set before [`EventBuilder::build`] is called.
+    pub fn builder() -> EventBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl EventBuilder {
+    /// Sets [`Event::action`].
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.action = Some(value.into());
+        self
+    }
+
+    /// Sets [`Event::deprecated_count`].
+    pub fn deprecated_count(mut self, value: impl Into<i32>) -> Self {
+        self.deprecated_count = Some(value.into());
+        self
+    }
+
+    /// Sets [`Event::deprecated_first_timestamp`].
+    pub fn deprecated_first_timestamp(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.deprecated_first_timestamp = Some(value.into());
+        self
+    }
+
+    /// Sets [`Event::deprecated_last_timestamp`].
+    pub fn deprecated_last_timestamp(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.deprecated_last_timestamp = Some(value.into());
+        self
+    }
+
+    /// Sets [`Event::deprecated_source`].
+    pub fn deprecated_source(mut self, value: impl Into<crate::api::core::v1::EventSource>) -> Self {
+        self.deprecated_source = Some(value.into());
+        self
+    }
+
+    /// Sets [`Event::event_time`].
+    pub fn event_time(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::MicroTime>) -> Self {
+        self.event_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`Event::metadata`].
+    pub fn metadata(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        self.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`Event::note`].
+    pub fn note(mut self, value: impl Into<String>) -> Self {
+        self.note = Some(value.into());
+        self
+    }
+
+    /// Sets [`Event::reason`].
+    pub fn reason(mut self, value: impl Into<String>) -> Self {
+        self.reason = Some(value.into());
+        self
+    }
+
+    /// Sets [`Event::regarding`].
+    pub fn regarding(mut self, value: impl Into<crate::api::core::v1::ObjectReference>) -> Self {
+        self.regarding = Some(value.into());
+        self
+    }
+
+    /// Sets [`Event::related`].
+    pub fn related(mut self, value: impl Into<crate::api::core::v1::ObjectReference>) -> Self {
+        self.related = Some(value.into());
+        self
+    }
+
+    /// Sets [`Event::reporting_controller`].
+    pub fn reporting_controller(mut self, value: impl Into<String>) -> Self {
+        self.reporting_controller = Some(value.into());
+        self
+    }
+
+    /// Sets [`Event::reporting_instance`].
+    pub fn reporting_instance(mut self, value: impl Into<String>) -> Self {
+        self.reporting_instance = Some(value.into());
+        self
+    }
+
+    /// Sets [`Event::series`].
+    pub fn series(mut self, value: impl Into<crate::api::events::v1beta1::EventSeries>) -> Self {
+        self.series = Some(value.into());
+        self
+    }
+
+    /// Sets [`Event::type_`].
+    pub fn type_(mut self, value: impl Into<String>) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `Event`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<Event, crate::MissingFieldError> {
+        Ok(Event {
+            action: self.action,
+            deprecated_count: self.deprecated_count,
+            deprecated_first_timestamp: self.deprecated_first_timestamp,
+            deprecated_last_timestamp: self.deprecated_last_timestamp,
+            deprecated_source: self.deprecated_source,
+            event_time: self.event_time.ok_or_else(|| crate::MissingFieldError::new("Event", "eventTime"))?,
+            metadata: self.metadata.ok_or_else(|| crate::MissingFieldError::new("Event", "metadata"))?,
+            note: self.note,
+            reason: self.reason,
+            regarding: self.regarding,
+            related: self.related,
+            reporting_controller: self.reporting_controller,
+            reporting_instance: self.reporting_instance,
+            series: self.series,
+            type_: self.type_,
+        })
+    }
+}
+
+/// The apply configuration of [`Event`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EventApplyConfiguration {
+    /// See [`Event::action`].
+    pub action: Option<String>,
+
+    /// See [`Event::deprecated_count`].
+    pub deprecated_count: Option<i32>,
+
+    /// See [`Event::deprecated_first_timestamp`].
+    pub deprecated_first_timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+
+    /// See [`Event::deprecated_last_timestamp`].
+    pub deprecated_last_timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+
+    /// See [`Event::deprecated_source`].
+    pub deprecated_source: Option<crate::api::core::v1::EventSourceApplyConfiguration>,
+
+    /// See [`Event::event_time`].
+    pub event_time: Option<crate::apimachinery::pkg::apis::meta::v1::MicroTime>,
+
+    /// See [`Event::metadata`].
+    pub metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMetaApplyConfiguration>,
+
+    /// See [`Event::note`].
+    pub note: Option<String>,
+
+    /// See [`Event::reason`].
+    pub reason: Option<String>,
+
+    /// See [`Event::regarding`].
+    pub regarding: Option<crate::api::core::v1::ObjectReferenceApplyConfiguration>,
+
+    /// See [`Event::related`].
+    pub related: Option<crate::api::core::v1::ObjectReferenceApplyConfiguration>,
+
+    /// See [`Event::reporting_controller`].
+    pub reporting_controller: Option<String>,
+
+    /// See [`Event::reporting_instance`].
+    pub reporting_instance: Option<String>,
+
+    /// See [`Event::series`].
+    pub series: Option<crate::api::events::v1beta1::EventSeriesApplyConfiguration>,
+
+    /// See [`Event::type_`].
+    pub type_: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for EventApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            <Event as crate::Resource>::KIND,
+            2 +
+            self.action.as_ref().map_or(0, |_| 1) +
+            self.deprecated_count.as_ref().map_or(0, |_| 1) +
+            self.deprecated_first_timestamp.as_ref().map_or(0, |_| 1) +
+            self.deprecated_last_timestamp.as_ref().map_or(0, |_| 1) +
+            self.deprecated_source.as_ref().map_or(0, |_| 1) +
+            self.event_time.as_ref().map_or(0, |_| 1) +
+            self.metadata.as_ref().map_or(0, |_| 1) +
+            self.note.as_ref().map_or(0, |_| 1) +
+            self.reason.as_ref().map_or(0, |_| 1) +
+            self.regarding.as_ref().map_or(0, |_| 1) +
+            self.related.as_ref().map_or(0, |_| 1) +
+            self.reporting_controller.as_ref().map_or(0, |_| 1) +
+            self.reporting_instance.as_ref().map_or(0, |_| 1) +
+            self.series.as_ref().map_or(0, |_| 1) +
+            self.type_.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <Event as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <Event as crate::Resource>::KIND)?;
+        if let Some(value) = &self.action {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "action", value)?;
+        }
+        if let Some(value) = &self.deprecated_count {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "deprecatedCount", value)?;
+        }
+        if let Some(value) = &self.deprecated_first_timestamp {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "deprecatedFirstTimestamp", value)?;
+        }
+        if let Some(value) = &self.deprecated_last_timestamp {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "deprecatedLastTimestamp", value)?;
+        }
+        if let Some(value) = &self.deprecated_source {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "deprecatedSource", value)?;
+        }
+        if let Some(value) = &self.event_time {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "eventTime", value)?;
+        }
+        if let Some(value) = &self.metadata {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", value)?;
+        }
+        if let Some(value) = &self.note {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "note", value)?;
+        }
+        if let Some(value) = &self.reason {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reason", value)?;
+        }
+        if let Some(value) = &self.regarding {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "regarding", value)?;
+        }
+        if let Some(value) = &self.related {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "related", value)?;
+        }
+        if let Some(value) = &self.reporting_controller {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reportingController", value)?;
+        }
+        if let Some(value) = &self.reporting_instance {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reportingInstance", value)?;
+        }
+        if let Some(value) = &self.series {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "series", value)?;
+        }
+        if let Some(value) = &self.type_ {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "type", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Event {
     fn schema_name() -> String {
-        "io.k8s.api.events.v1beta1.Event".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::events::v1beta1::Event".to_owned()
+        }
+        else {
+            "io.k8s.api.events.v1beta1.Event".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -796,8 +1348,9 @@ impl crate::schemars::JsonSchema for Event {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "action".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -807,8 +1360,8 @@ impl crate::schemars::JsonSchema for Event {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "apiVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -816,10 +1369,11 @@ impl crate::schemars::JsonSchema for Event {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("events.k8s.io/v1beta1".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "deprecatedCount".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -830,8 +1384,8 @@ impl crate::schemars::JsonSchema for Event {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "deprecatedFirstTimestamp".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::Time>().into_object();
@@ -841,8 +1395,8 @@ impl crate::schemars::JsonSchema for Event {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "deprecatedLastTimestamp".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::Time>().into_object();
@@ -852,8 +1406,8 @@ impl crate::schemars::JsonSchema for Event {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "deprecatedSource".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::EventSource>().into_object();
@@ -863,8 +1417,8 @@ impl crate::schemars::JsonSchema for Event {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "eventTime".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::MicroTime>().into_object();
@@ -874,8 +1428,8 @@ impl crate::schemars::JsonSchema for Event {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "kind".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -883,14 +1437,15 @@ impl crate::schemars::JsonSchema for Event {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("Event".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "metadata".to_owned(),
                         __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>(),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "note".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -900,8 +1455,8 @@ impl crate::schemars::JsonSchema for Event {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "reason".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -911,8 +1466,8 @@ impl crate::schemars::JsonSchema for Event {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "regarding".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ObjectReference>().into_object();
@@ -922,8 +1477,8 @@ impl crate::schemars::JsonSchema for Event {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "related".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ObjectReference>().into_object();
@@ -933,8 +1488,8 @@ impl crate::schemars::JsonSchema for Event {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "reportingController".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -944,8 +1499,8 @@ impl crate::schemars::JsonSchema for Event {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "reportingInstance".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -955,8 +1510,8 @@ impl crate::schemars::JsonSchema for Event {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "series".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::events::v1beta1::EventSeries>().into_object();
@@ -966,8 +1521,8 @@ impl crate::schemars::JsonSchema for Event {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "type".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -977,10 +1532,13 @@ impl crate::schemars::JsonSchema for Event {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
+                    "apiVersion",
                     "eventTime",
+                    "kind",
                     "metadata",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
                 ..Default::default()
@@ -989,3 +1547,26 @@ impl crate::schemars::JsonSchema for Event {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for Event {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(Event {
+            action: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            deprecated_count: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            deprecated_first_timestamp: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            deprecated_last_timestamp: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            deprecated_source: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            event_time: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            metadata: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            note: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            reason: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            regarding: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            related: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            reporting_controller: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            reporting_instance: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            series: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            type_: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.extensions.v1beta1.IngressTLS
 
 /// IngressTLS describes the transport layer security associated with an Ingress.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct IngressTLS {
     /// Hosts are a list of hosts included in the TLS certificate. The values in this list must match the name/s used in the tlsSecret. Defaults to the wildcard host setting for the loadbalancer controller fulfilling this Ingress, if left unspecified.
     pub hosts: Option<Vec<String>>,
@@ -10,39 +10,33 @@ pub struct IngressTLS {
     pub secret_name: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for IngressTLS {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_hosts,
-            Key_secret_name,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl IngressTLS {
+    /// Returns this value with [`IngressTLS::hosts`] set to the given value.
+    #[must_use]
+    pub fn with_hosts(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.hosts = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`IngressTLS::secret_name`] set to the given value.
+    #[must_use]
+    pub fn with_secret_name(mut self, value: impl Into<String>) -> Self {
+        self.secret_name = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "hosts" => Field::Key_hosts,
-                            "secretName" => Field::Key_secret_name,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for IngressTLS {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl IngressTLS {
+    const FIELDS: &'static [&'static str] = &[
+        "hosts",
+        "secretName",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for IngressTLS {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,11 +50,11 @@ impl<'de> crate::serde::Deserialize<'de> for IngressTLS {
                 let mut value_hosts: Option<Vec<String>> = None;
                 let mut value_secret_name: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("IngressTLS", IngressTLS::FIELDS))? {
                     match key {
-                        Field::Key_hosts => value_hosts = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_secret_name => value_secret_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_hosts = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_secret_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -69,21 +63,78 @@ impl<'de> crate::serde::Deserialize<'de> for IngressTLS {
                     secret_name: value_secret_name,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(IngressTLS {
+                    hosts: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    secret_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "IngressTLS",
-            &[
-                "hosts",
-                "secretName",
-            ],
+            IngressTLS::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, IngressTLS> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("IngressTLS")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_hosts = false;
+                let mut has_secret_name = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("IngressTLS", IngressTLS::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.hosts))?; has_hosts = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.secret_name))?; has_secret_name = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_hosts {
+                    self.0.hosts = None;
+                }
+                if !has_secret_name {
+                    self.0.secret_name = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.hosts))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.secret_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "IngressTLS",
+            IngressTLS::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for IngressTLS {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("IngressTLS", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hosts", &self.hosts)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretName", &self.secret_name)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "IngressTLS",
             self.hosts.as_ref().map_or(0, |_| 1) +
@@ -99,10 +150,84 @@ impl crate::serde::Serialize for IngressTLS {
     }
 }
 
+/// A builder of [`IngressTLS`]. Created with [`IngressTLS::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct IngressTLSBuilder {
+    hosts: Option<Vec<String>>,
+    secret_name: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl IngressTLS {
+    /// Starts building a `IngressTLS`. Its required fields must be set before [`IngressTLSBuilder::build`] is called.
+    pub fn builder() -> IngressTLSBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl IngressTLSBuilder {
+    /// Sets [`IngressTLS::hosts`].
+    pub fn hosts(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.hosts = Some(value.into());
+        self
+    }
+
+    /// Sets [`IngressTLS::secret_name`].
+    pub fn secret_name(mut self, value: impl Into<String>) -> Self {
+        self.secret_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `IngressTLS`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<IngressTLS, crate::MissingFieldError> {
+        Ok(IngressTLS {
+            hosts: self.hosts,
+            secret_name: self.secret_name,
+        })
+    }
+}
+
+/// The apply configuration of [`IngressTLS`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IngressTLSApplyConfiguration {
+    /// See [`IngressTLS::hosts`].
+    pub hosts: Option<Vec<String>>,
+
+    /// See [`IngressTLS::secret_name`].
+    pub secret_name: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for IngressTLSApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "IngressTLSApplyConfiguration",
+            self.hosts.as_ref().map_or(0, |_| 1) +
+            self.secret_name.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.hosts {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hosts", value)?;
+        }
+        if let Some(value) = &self.secret_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretName", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for IngressTLS {
     fn schema_name() -> String {
-        "io.k8s.api.extensions.v1beta1.IngressTLS".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::extensions::v1beta1::IngressTLS".to_owned()
+        }
+        else {
+            "io.k8s.api.extensions.v1beta1.IngressTLS".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -113,8 +238,9 @@ impl crate::schemars::JsonSchema for IngressTLS {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "hosts".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -133,8 +259,8 @@ impl crate::schemars::JsonSchema for IngressTLS {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "secretName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -144,11 +270,22 @@ impl crate::schemars::JsonSchema for IngressTLS {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for IngressTLS {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(IngressTLS {
+            hosts: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            secret_name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
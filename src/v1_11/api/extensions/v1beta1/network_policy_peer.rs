@@ -1,7 +1,8 @@
 // Generated from definition io.k8s.api.extensions.v1beta1.NetworkPolicyPeer
 
 /// DEPRECATED 1.9 - This group version of NetworkPolicyPeer is deprecated by networking/v1/NetworkPolicyPeer.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[deprecated(note = "DEPRECATED 1.9 - This group version of NetworkPolicyPeer is deprecated by networking/v1/NetworkPolicyPeer.")]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct NetworkPolicyPeer {
     /// IPBlock defines policy on a particular IPBlock. If this field is set then neither of the other fields can be.
     pub ip_block: Option<crate::api::extensions::v1beta1::IPBlock>,
@@ -17,41 +18,41 @@ pub struct NetworkPolicyPeer {
     pub pod_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for NetworkPolicyPeer {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_ip_block,
-            Key_namespace_selector,
-            Key_pod_selector,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl NetworkPolicyPeer {
+    /// Returns this value with [`NetworkPolicyPeer::ip_block`] set to the given value.
+    #[must_use]
+    pub fn with_ip_block(mut self, value: impl Into<crate::api::extensions::v1beta1::IPBlock>) -> Self {
+        self.ip_block = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`NetworkPolicyPeer::namespace_selector`] set to the given value.
+    #[must_use]
+    pub fn with_namespace_selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.namespace_selector = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`NetworkPolicyPeer::pod_selector`] set to the given value.
+    #[must_use]
+    pub fn with_pod_selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.pod_selector = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "ipBlock" => Field::Key_ip_block,
-                            "namespaceSelector" => Field::Key_namespace_selector,
-                            "podSelector" => Field::Key_pod_selector,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for NetworkPolicyPeer {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl NetworkPolicyPeer {
+    const FIELDS: &'static [&'static str] = &[
+        "ipBlock",
+        "namespaceSelector",
+        "podSelector",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for NetworkPolicyPeer {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -66,12 +67,12 @@ impl<'de> crate::serde::Deserialize<'de> for NetworkPolicyPeer {
                 let mut value_namespace_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> = None;
                 let mut value_pod_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NetworkPolicyPeer", NetworkPolicyPeer::FIELDS))? {
                     match key {
-                        Field::Key_ip_block => value_ip_block = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_namespace_selector => value_namespace_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_pod_selector => value_pod_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_ip_block = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_namespace_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_pod_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -81,22 +82,86 @@ impl<'de> crate::serde::Deserialize<'de> for NetworkPolicyPeer {
                     pod_selector: value_pod_selector,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(NetworkPolicyPeer {
+                    ip_block: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    namespace_selector: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    pod_selector: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "NetworkPolicyPeer",
-            &[
-                "ipBlock",
-                "namespaceSelector",
-                "podSelector",
-            ],
+            NetworkPolicyPeer::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, NetworkPolicyPeer> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("NetworkPolicyPeer")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_ip_block = false;
+                let mut has_namespace_selector = false;
+                let mut has_pod_selector = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NetworkPolicyPeer", NetworkPolicyPeer::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.ip_block))?; has_ip_block = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.namespace_selector))?; has_namespace_selector = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.pod_selector))?; has_pod_selector = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_ip_block {
+                    self.0.ip_block = None;
+                }
+                if !has_namespace_selector {
+                    self.0.namespace_selector = None;
+                }
+                if !has_pod_selector {
+                    self.0.pod_selector = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.ip_block))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.namespace_selector))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.pod_selector))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "NetworkPolicyPeer",
+            NetworkPolicyPeer::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for NetworkPolicyPeer {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("NetworkPolicyPeer", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ipBlock", &self.ip_block)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "namespaceSelector", &self.namespace_selector)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "podSelector", &self.pod_selector)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "NetworkPolicyPeer",
             self.ip_block.as_ref().map_or(0, |_| 1) +
@@ -116,10 +181,99 @@ impl crate::serde::Serialize for NetworkPolicyPeer {
     }
 }
 
+/// A builder of [`NetworkPolicyPeer`]. Created with [`NetworkPolicyPeer::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct NetworkPolicyPeerBuilder {
+    ip_block: Option<crate::api::extensions::v1beta1::IPBlock>,
+    namespace_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>,
+    pod_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>,
+}
+
+#[cfg(feature = "builder")]
+impl NetworkPolicyPeer {
+    /// Starts building a `NetworkPolicyPeer`. Its required fields must be set before [`NetworkPolicyPeerBuilder::build`] is called.
+    pub fn builder() -> NetworkPolicyPeerBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl NetworkPolicyPeerBuilder {
+    /// Sets [`NetworkPolicyPeer::ip_block`].
+    pub fn ip_block(mut self, value: impl Into<crate::api::extensions::v1beta1::IPBlock>) -> Self {
+        self.ip_block = Some(value.into());
+        self
+    }
+
+    /// Sets [`NetworkPolicyPeer::namespace_selector`].
+    pub fn namespace_selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.namespace_selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`NetworkPolicyPeer::pod_selector`].
+    pub fn pod_selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.pod_selector = Some(value.into());
+        self
+    }
+
+    /// Builds the `NetworkPolicyPeer`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<NetworkPolicyPeer, crate::MissingFieldError> {
+        Ok(NetworkPolicyPeer {
+            ip_block: self.ip_block,
+            namespace_selector: self.namespace_selector,
+            pod_selector: self.pod_selector,
+        })
+    }
+}
+
+/// The apply configuration of [`NetworkPolicyPeer`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NetworkPolicyPeerApplyConfiguration {
+    /// See [`NetworkPolicyPeer::ip_block`].
+    pub ip_block: Option<crate::api::extensions::v1beta1::IPBlockApplyConfiguration>,
+
+    /// See [`NetworkPolicyPeer::namespace_selector`].
+    pub namespace_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorApplyConfiguration>,
+
+    /// See [`NetworkPolicyPeer::pod_selector`].
+    pub pod_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for NetworkPolicyPeerApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "NetworkPolicyPeerApplyConfiguration",
+            self.ip_block.as_ref().map_or(0, |_| 1) +
+            self.namespace_selector.as_ref().map_or(0, |_| 1) +
+            self.pod_selector.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.ip_block {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ipBlock", value)?;
+        }
+        if let Some(value) = &self.namespace_selector {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "namespaceSelector", value)?;
+        }
+        if let Some(value) = &self.pod_selector {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "podSelector", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NetworkPolicyPeer {
     fn schema_name() -> String {
-        "io.k8s.api.extensions.v1beta1.NetworkPolicyPeer".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::extensions::v1beta1::NetworkPolicyPeer".to_owned()
+        }
+        else {
+            "io.k8s.api.extensions.v1beta1.NetworkPolicyPeer".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -130,8 +284,9 @@ impl crate::schemars::JsonSchema for NetworkPolicyPeer {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "ipBlock".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::extensions::v1beta1::IPBlock>().into_object();
@@ -141,8 +296,8 @@ impl crate::schemars::JsonSchema for NetworkPolicyPeer {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "namespaceSelector".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>().into_object();
@@ -152,8 +307,8 @@ impl crate::schemars::JsonSchema for NetworkPolicyPeer {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "podSelector".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>().into_object();
@@ -163,11 +318,23 @@ impl crate::schemars::JsonSchema for NetworkPolicyPeer {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for NetworkPolicyPeer {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(NetworkPolicyPeer {
+            ip_block: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            namespace_selector: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            pod_selector: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
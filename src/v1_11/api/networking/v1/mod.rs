@@ -1,22 +1,50 @@
 
 mod ip_block;
 pub use self::ip_block::IPBlock;
+#[cfg(feature = "builder")]
+pub use self::ip_block::IPBlockBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::ip_block::IPBlockApplyConfiguration;
 
 mod network_policy;
 pub use self::network_policy::NetworkPolicy;
+#[cfg(feature = "builder")]
+pub use self::network_policy::NetworkPolicyBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::network_policy::NetworkPolicyApplyConfiguration;
 #[cfg(feature = "api")] pub use self::network_policy::{ReadNamespacedNetworkPolicyOptional, ReadNamespacedNetworkPolicyResponse};
 
 mod network_policy_egress_rule;
 pub use self::network_policy_egress_rule::NetworkPolicyEgressRule;
+#[cfg(feature = "builder")]
+pub use self::network_policy_egress_rule::NetworkPolicyEgressRuleBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::network_policy_egress_rule::NetworkPolicyEgressRuleApplyConfiguration;
 
 mod network_policy_ingress_rule;
 pub use self::network_policy_ingress_rule::NetworkPolicyIngressRule;
+#[cfg(feature = "builder")]
+pub use self::network_policy_ingress_rule::NetworkPolicyIngressRuleBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::network_policy_ingress_rule::NetworkPolicyIngressRuleApplyConfiguration;
 
 mod network_policy_peer;
 pub use self::network_policy_peer::NetworkPolicyPeer;
+#[cfg(feature = "builder")]
+pub use self::network_policy_peer::NetworkPolicyPeerBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::network_policy_peer::NetworkPolicyPeerApplyConfiguration;
 
 mod network_policy_port;
 pub use self::network_policy_port::NetworkPolicyPort;
+#[cfg(feature = "builder")]
+pub use self::network_policy_port::NetworkPolicyPortBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::network_policy_port::NetworkPolicyPortApplyConfiguration;
 
 mod network_policy_spec;
 pub use self::network_policy_spec::NetworkPolicySpec;
+#[cfg(feature = "builder")]
+pub use self::network_policy_spec::NetworkPolicySpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::network_policy_spec::NetworkPolicySpecApplyConfiguration;
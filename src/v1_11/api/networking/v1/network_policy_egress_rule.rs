@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.networking.v1.NetworkPolicyEgressRule
 
 /// NetworkPolicyEgressRule describes a particular set of traffic that is allowed out of pods matched by a NetworkPolicySpec's podSelector. The traffic must match both ports and to. This type is beta-level in 1.8
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct NetworkPolicyEgressRule {
     /// List of destination ports for outgoing traffic. Each item in this list is combined using a logical OR. If this field is empty or missing, this rule matches all ports (traffic not restricted by port). If this field is present and contains at least one item, then this rule allows traffic only if the traffic matches at least one port in the list.
     pub ports: Option<Vec<crate::api::networking::v1::NetworkPolicyPort>>,
@@ -10,39 +10,33 @@ pub struct NetworkPolicyEgressRule {
     pub to: Option<Vec<crate::api::networking::v1::NetworkPolicyPeer>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for NetworkPolicyEgressRule {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_ports,
-            Key_to,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl NetworkPolicyEgressRule {
+    /// Returns this value with [`NetworkPolicyEgressRule::ports`] set to the given value.
+    #[must_use]
+    pub fn with_ports(mut self, value: impl Into<Vec<crate::api::networking::v1::NetworkPolicyPort>>) -> Self {
+        self.ports = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`NetworkPolicyEgressRule::to`] set to the given value.
+    #[must_use]
+    pub fn with_to(mut self, value: impl Into<Vec<crate::api::networking::v1::NetworkPolicyPeer>>) -> Self {
+        self.to = Some(value.into());
+        self
+    }
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for NetworkPolicyEgressRule {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "ports" => Field::Key_ports,
-                            "to" => Field::Key_to,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl NetworkPolicyEgressRule {
+    const FIELDS: &'static [&'static str] = &[
+        "ports",
+        "to",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for NetworkPolicyEgressRule {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,11 +50,11 @@ impl<'de> crate::serde::Deserialize<'de> for NetworkPolicyEgressRule {
                 let mut value_ports: Option<Vec<crate::api::networking::v1::NetworkPolicyPort>> = None;
                 let mut value_to: Option<Vec<crate::api::networking::v1::NetworkPolicyPeer>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NetworkPolicyEgressRule", NetworkPolicyEgressRule::FIELDS))? {
                     match key {
-                        Field::Key_ports => value_ports = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_to => value_to = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_ports = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_to = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -69,21 +63,78 @@ impl<'de> crate::serde::Deserialize<'de> for NetworkPolicyEgressRule {
                     to: value_to,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(NetworkPolicyEgressRule {
+                    ports: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    to: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "NetworkPolicyEgressRule",
-            &[
-                "ports",
-                "to",
-            ],
+            NetworkPolicyEgressRule::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, NetworkPolicyEgressRule> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("NetworkPolicyEgressRule")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_ports = false;
+                let mut has_to = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NetworkPolicyEgressRule", NetworkPolicyEgressRule::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.ports))?; has_ports = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.to))?; has_to = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_ports {
+                    self.0.ports = None;
+                }
+                if !has_to {
+                    self.0.to = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.ports))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.to))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "NetworkPolicyEgressRule",
+            NetworkPolicyEgressRule::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for NetworkPolicyEgressRule {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("NetworkPolicyEgressRule", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ports", &self.ports)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "to", &self.to)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "NetworkPolicyEgressRule",
             self.ports.as_ref().map_or(0, |_| 1) +
@@ -99,10 +150,84 @@ impl crate::serde::Serialize for NetworkPolicyEgressRule {
     }
 }
 
+/// A builder of [`NetworkPolicyEgressRule`]. Created with [`NetworkPolicyEgressRule::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct NetworkPolicyEgressRuleBuilder {
+    ports: Option<Vec<crate::api::networking::v1::NetworkPolicyPort>>,
+    to: Option<Vec<crate::api::networking::v1::NetworkPolicyPeer>>,
+}
+
+#[cfg(feature = "builder")]
+impl NetworkPolicyEgressRule {
+    /// Starts building a `NetworkPolicyEgressRule`. Its required fields must be set before [`NetworkPolicyEgressRuleBuilder::build`] is called.
+    pub fn builder() -> NetworkPolicyEgressRuleBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl NetworkPolicyEgressRuleBuilder {
+    /// Sets [`NetworkPolicyEgressRule::ports`].
+    pub fn ports(mut self, value: impl Into<Vec<crate::api::networking::v1::NetworkPolicyPort>>) -> Self {
+        self.ports = Some(value.into());
+        self
+    }
+
+    /// Sets [`NetworkPolicyEgressRule::to`].
+    pub fn to(mut self, value: impl Into<Vec<crate::api::networking::v1::NetworkPolicyPeer>>) -> Self {
+        self.to = Some(value.into());
+        self
+    }
+
+    /// Builds the `NetworkPolicyEgressRule`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<NetworkPolicyEgressRule, crate::MissingFieldError> {
+        Ok(NetworkPolicyEgressRule {
+            ports: self.ports,
+            to: self.to,
+        })
+    }
+}
+
+/// The apply configuration of [`NetworkPolicyEgressRule`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NetworkPolicyEgressRuleApplyConfiguration {
+    /// See [`NetworkPolicyEgressRule::ports`].
+    pub ports: Option<Vec<crate::api::networking::v1::NetworkPolicyPortApplyConfiguration>>,
+
+    /// See [`NetworkPolicyEgressRule::to`].
+    pub to: Option<Vec<crate::api::networking::v1::NetworkPolicyPeerApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for NetworkPolicyEgressRuleApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "NetworkPolicyEgressRuleApplyConfiguration",
+            self.ports.as_ref().map_or(0, |_| 1) +
+            self.to.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.ports {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ports", value)?;
+        }
+        if let Some(value) = &self.to {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "to", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NetworkPolicyEgressRule {
     fn schema_name() -> String {
-        "io.k8s.api.networking.v1.NetworkPolicyEgressRule".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::networking::v1::NetworkPolicyEgressRule".to_owned()
+        }
+        else {
+            "io.k8s.api.networking.v1.NetworkPolicyEgressRule".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -113,8 +238,9 @@ impl crate::schemars::JsonSchema for NetworkPolicyEgressRule {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "ports".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -128,8 +254,8 @@ impl crate::schemars::JsonSchema for NetworkPolicyEgressRule {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "to".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -143,11 +269,22 @@ impl crate::schemars::JsonSchema for NetworkPolicyEgressRule {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for NetworkPolicyEgressRule {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(NetworkPolicyEgressRule {
+            ports: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            to: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
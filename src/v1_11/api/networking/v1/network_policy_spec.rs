@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.networking.v1.NetworkPolicySpec
 
 /// NetworkPolicySpec provides the specification of a NetworkPolicy
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct NetworkPolicySpec {
     /// List of egress rules to be applied to the selected pods. Outgoing traffic is allowed if there are no NetworkPolicies selecting the pod (and cluster policy otherwise allows the traffic), OR if the traffic matches at least one egress rule across all of the NetworkPolicy objects whose podSelector matches the pod. If this field is empty then this NetworkPolicy limits all outgoing traffic (and serves solely to ensure that the pods it selects are isolated by default). This field is beta-level in 1.8
     pub egress: Option<Vec<crate::api::networking::v1::NetworkPolicyEgressRule>>,
@@ -16,43 +16,61 @@ pub struct NetworkPolicySpec {
     pub policy_types: Option<Vec<String>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for NetworkPolicySpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_egress,
-            Key_ingress,
-            Key_pod_selector,
-            Key_policy_types,
-            Other,
+impl NetworkPolicySpec {
+    /// Constructs a `NetworkPolicySpec` from its required fields. Its optional fields are unset.
+    pub fn new(pod_selector: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        NetworkPolicySpec {
+            egress: None,
+            ingress: None,
+            pod_selector: pod_selector.into(),
+            policy_types: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl NetworkPolicySpec {
+    /// Returns this value with [`NetworkPolicySpec::egress`] set to the given value.
+    #[must_use]
+    pub fn with_egress(mut self, value: impl Into<Vec<crate::api::networking::v1::NetworkPolicyEgressRule>>) -> Self {
+        self.egress = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`NetworkPolicySpec::ingress`] set to the given value.
+    #[must_use]
+    pub fn with_ingress(mut self, value: impl Into<Vec<crate::api::networking::v1::NetworkPolicyIngressRule>>) -> Self {
+        self.ingress = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`NetworkPolicySpec::pod_selector`] set to the given value.
+    #[must_use]
+    pub fn with_pod_selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.pod_selector = value.into();
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "egress" => Field::Key_egress,
-                            "ingress" => Field::Key_ingress,
-                            "podSelector" => Field::Key_pod_selector,
-                            "policyTypes" => Field::Key_policy_types,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`NetworkPolicySpec::policy_types`] set to the given value.
+    #[must_use]
+    pub fn with_policy_types(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.policy_types = Some(value.into());
+        self
+    }
+}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl crate::MergeStrategyInfo for NetworkPolicySpec {}
+
+impl NetworkPolicySpec {
+    const FIELDS: &'static [&'static str] = &[
+        "egress",
+        "ingress",
+        "podSelector",
+        "policyTypes",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for NetworkPolicySpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -68,40 +86,111 @@ impl<'de> crate::serde::Deserialize<'de> for NetworkPolicySpec {
                 let mut value_pod_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> = None;
                 let mut value_policy_types: Option<Vec<String>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NetworkPolicySpec", NetworkPolicySpec::FIELDS))? {
                     match key {
-                        Field::Key_egress => value_egress = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_ingress => value_ingress = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_pod_selector => value_pod_selector = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_policy_types => value_policy_types = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_egress = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_ingress = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_pod_selector = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_policy_types = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(NetworkPolicySpec {
                     egress: value_egress,
                     ingress: value_ingress,
-                    pod_selector: value_pod_selector.ok_or_else(|| crate::serde::de::Error::missing_field("podSelector"))?,
+                    pod_selector: value_pod_selector.ok_or_else(|| crate::serde2::missing_field("NetworkPolicySpec", "podSelector", &["podSelector"]))?,
                     policy_types: value_policy_types,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(NetworkPolicySpec {
+                    egress: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    ingress: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    pod_selector: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    policy_types: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "NetworkPolicySpec",
-            &[
-                "egress",
-                "ingress",
-                "podSelector",
-                "policyTypes",
-            ],
+            NetworkPolicySpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, NetworkPolicySpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("NetworkPolicySpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_egress = false;
+                let mut has_ingress = false;
+                let mut has_pod_selector = false;
+                let mut has_policy_types = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NetworkPolicySpec", NetworkPolicySpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.egress))?; has_egress = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.ingress))?; has_ingress = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.pod_selector))?; has_pod_selector = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.policy_types))?; has_policy_types = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_egress {
+                    self.0.egress = None;
+                }
+                if !has_ingress {
+                    self.0.ingress = None;
+                }
+                if !has_pod_selector {
+                    return Err(crate::serde2::missing_field("NetworkPolicySpec", "podSelector", &["podSelector"]));
+                }
+                if !has_policy_types {
+                    self.0.policy_types = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.egress))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.ingress))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.pod_selector))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.policy_types))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "NetworkPolicySpec",
+            NetworkPolicySpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for NetworkPolicySpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("NetworkPolicySpec", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "egress", &self.egress)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ingress", &self.ingress)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "podSelector", &self.pod_selector)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "policyTypes", &self.policy_types)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "NetworkPolicySpec",
             1 +
@@ -123,10 +212,114 @@ impl crate::serde::Serialize for NetworkPolicySpec {
     }
 }
 
+/// A builder of [`NetworkPolicySpec`]. Created with [`NetworkPolicySpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct NetworkPolicySpecBuilder {
+    egress: Option<Vec<crate::api::networking::v1::NetworkPolicyEgressRule>>,
+    ingress: Option<Vec<crate::api::networking::v1::NetworkPolicyIngressRule>>,
+    pod_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>,
+    policy_types: Option<Vec<String>>,
+}
+
+#[cfg(feature = "builder")]
+impl NetworkPolicySpec {
+    /// Starts building a `NetworkPolicySpec`. Its required fields must be set before [`NetworkPolicySpecBuilder::build`] is called.
+    pub fn builder() -> NetworkPolicySpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl NetworkPolicySpecBuilder {
+    /// Sets [`NetworkPolicySpec::egress`].
+    pub fn egress(mut self, value: impl Into<Vec<crate::api::networking::v1::NetworkPolicyEgressRule>>) -> Self {
+        self.egress = Some(value.into());
+        self
+    }
+
+    /// Sets [`NetworkPolicySpec::ingress`].
+    pub fn ingress(mut self, value: impl Into<Vec<crate::api::networking::v1::NetworkPolicyIngressRule>>) -> Self {
+        self.ingress = Some(value.into());
+        self
+    }
+
+    /// Sets [`NetworkPolicySpec::pod_selector`].
+    pub fn pod_selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.pod_selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`NetworkPolicySpec::policy_types`].
+    pub fn policy_types(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.policy_types = Some(value.into());
+        self
+    }
+
+    /// Builds the `NetworkPolicySpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<NetworkPolicySpec, crate::MissingFieldError> {
+        Ok(NetworkPolicySpec {
+            egress: self.egress,
+            ingress: self.ingress,
+            pod_selector: self.pod_selector.ok_or_else(|| crate::MissingFieldError::new("NetworkPolicySpec", "podSelector"))?,
+            policy_types: self.policy_types,
+        })
+    }
+}
+
+/// The apply configuration of [`NetworkPolicySpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NetworkPolicySpecApplyConfiguration {
+    /// See [`NetworkPolicySpec::egress`].
+    pub egress: Option<Vec<crate::api::networking::v1::NetworkPolicyEgressRuleApplyConfiguration>>,
+
+    /// See [`NetworkPolicySpec::ingress`].
+    pub ingress: Option<Vec<crate::api::networking::v1::NetworkPolicyIngressRuleApplyConfiguration>>,
+
+    /// See [`NetworkPolicySpec::pod_selector`].
+    pub pod_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorApplyConfiguration>,
+
+    /// See [`NetworkPolicySpec::policy_types`].
+    pub policy_types: Option<Vec<String>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for NetworkPolicySpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "NetworkPolicySpecApplyConfiguration",
+            self.egress.as_ref().map_or(0, |_| 1) +
+            self.ingress.as_ref().map_or(0, |_| 1) +
+            self.pod_selector.as_ref().map_or(0, |_| 1) +
+            self.policy_types.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.egress {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "egress", value)?;
+        }
+        if let Some(value) = &self.ingress {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ingress", value)?;
+        }
+        if let Some(value) = &self.pod_selector {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "podSelector", value)?;
+        }
+        if let Some(value) = &self.policy_types {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "policyTypes", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NetworkPolicySpec {
     fn schema_name() -> String {
-        "io.k8s.api.networking.v1.NetworkPolicySpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::networking::v1::NetworkPolicySpec".to_owned()
+        }
+        else {
+            "io.k8s.api.networking.v1.NetworkPolicySpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -137,8 +330,9 @@ impl crate::schemars::JsonSchema for NetworkPolicySpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "egress".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -152,8 +346,8 @@ impl crate::schemars::JsonSchema for NetworkPolicySpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "ingress".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -167,8 +361,8 @@ impl crate::schemars::JsonSchema for NetworkPolicySpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "podSelector".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>().into_object();
@@ -178,8 +372,8 @@ impl crate::schemars::JsonSchema for NetworkPolicySpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "policyTypes".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -198,8 +392,9 @@ impl crate::schemars::JsonSchema for NetworkPolicySpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "podSelector",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -209,3 +404,15 @@ impl crate::schemars::JsonSchema for NetworkPolicySpec {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for NetworkPolicySpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(NetworkPolicySpec {
+            egress: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            ingress: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            pod_selector: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            policy_types: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
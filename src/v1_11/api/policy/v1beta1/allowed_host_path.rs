@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.policy.v1beta1.AllowedHostPath
 
 /// AllowedHostPath defines the host volume conditions that will be enabled by a policy for pods to use. It requires the path prefix to be defined.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct AllowedHostPath {
     /// pathPrefix is the path prefix that the host volume must match. It does not support `*`. Trailing slashes are trimmed when validating the path prefix with a host path.
     ///
@@ -12,39 +12,33 @@ pub struct AllowedHostPath {
     pub read_only: Option<bool>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for AllowedHostPath {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_path_prefix,
-            Key_read_only,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl AllowedHostPath {
+    /// Returns this value with [`AllowedHostPath::path_prefix`] set to the given value.
+    #[must_use]
+    pub fn with_path_prefix(mut self, value: impl Into<String>) -> Self {
+        self.path_prefix = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`AllowedHostPath::read_only`] set to the given value.
+    #[must_use]
+    pub fn with_read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "pathPrefix" => Field::Key_path_prefix,
-                            "readOnly" => Field::Key_read_only,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for AllowedHostPath {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl AllowedHostPath {
+    const FIELDS: &'static [&'static str] = &[
+        "pathPrefix",
+        "readOnly",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for AllowedHostPath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -58,11 +52,11 @@ impl<'de> crate::serde::Deserialize<'de> for AllowedHostPath {
                 let mut value_path_prefix: Option<String> = None;
                 let mut value_read_only: Option<bool> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("AllowedHostPath", AllowedHostPath::FIELDS))? {
                     match key {
-                        Field::Key_path_prefix => value_path_prefix = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_read_only => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_path_prefix = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -71,21 +65,78 @@ impl<'de> crate::serde::Deserialize<'de> for AllowedHostPath {
                     read_only: value_read_only,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(AllowedHostPath {
+                    path_prefix: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    read_only: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "AllowedHostPath",
-            &[
-                "pathPrefix",
-                "readOnly",
-            ],
+            AllowedHostPath::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, AllowedHostPath> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("AllowedHostPath")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_path_prefix = false;
+                let mut has_read_only = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("AllowedHostPath", AllowedHostPath::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.path_prefix))?; has_path_prefix = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.read_only))?; has_read_only = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_path_prefix {
+                    self.0.path_prefix = None;
+                }
+                if !has_read_only {
+                    self.0.read_only = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.path_prefix))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.read_only))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "AllowedHostPath",
+            AllowedHostPath::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for AllowedHostPath {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("AllowedHostPath", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "pathPrefix", &self.path_prefix)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", &self.read_only)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "AllowedHostPath",
             self.path_prefix.as_ref().map_or(0, |_| 1) +
@@ -101,10 +152,84 @@ impl crate::serde::Serialize for AllowedHostPath {
     }
 }
 
+/// A builder of [`AllowedHostPath`]. Created with [`AllowedHostPath::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct AllowedHostPathBuilder {
+    path_prefix: Option<String>,
+    read_only: Option<bool>,
+}
+
+#[cfg(feature = "builder")]
+impl AllowedHostPath {
+    /// Starts building a `AllowedHostPath`. Its required fields must be set before [`AllowedHostPathBuilder::build`] is called.
+    pub fn builder() -> AllowedHostPathBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl AllowedHostPathBuilder {
+    /// Sets [`AllowedHostPath::path_prefix`].
+    pub fn path_prefix(mut self, value: impl Into<String>) -> Self {
+        self.path_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets [`AllowedHostPath::read_only`].
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+
+    /// Builds the `AllowedHostPath`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<AllowedHostPath, crate::MissingFieldError> {
+        Ok(AllowedHostPath {
+            path_prefix: self.path_prefix,
+            read_only: self.read_only,
+        })
+    }
+}
+
+/// The apply configuration of [`AllowedHostPath`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AllowedHostPathApplyConfiguration {
+    /// See [`AllowedHostPath::path_prefix`].
+    pub path_prefix: Option<String>,
+
+    /// See [`AllowedHostPath::read_only`].
+    pub read_only: Option<bool>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for AllowedHostPathApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "AllowedHostPathApplyConfiguration",
+            self.path_prefix.as_ref().map_or(0, |_| 1) +
+            self.read_only.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.path_prefix {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "pathPrefix", value)?;
+        }
+        if let Some(value) = &self.read_only {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for AllowedHostPath {
     fn schema_name() -> String {
-        "io.k8s.api.policy.v1beta1.AllowedHostPath".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::policy::v1beta1::AllowedHostPath".to_owned()
+        }
+        else {
+            "io.k8s.api.policy.v1beta1.AllowedHostPath".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -115,8 +240,9 @@ impl crate::schemars::JsonSchema for AllowedHostPath {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "pathPrefix".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -126,8 +252,8 @@ impl crate::schemars::JsonSchema for AllowedHostPath {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "readOnly".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -137,11 +263,22 @@ impl crate::schemars::JsonSchema for AllowedHostPath {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for AllowedHostPath {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(AllowedHostPath {
+            path_prefix: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            read_only: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
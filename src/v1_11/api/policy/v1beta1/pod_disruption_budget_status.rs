@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.policy.v1beta1.PodDisruptionBudgetStatus
 
 /// PodDisruptionBudgetStatus represents information about the status of a PodDisruptionBudget. Status may trail the actual state of a system.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct PodDisruptionBudgetStatus {
     /// current number of healthy pods
     pub current_healthy: i32,
@@ -22,47 +22,79 @@ pub struct PodDisruptionBudgetStatus {
     pub observed_generation: Option<i64>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for PodDisruptionBudgetStatus {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_current_healthy,
-            Key_desired_healthy,
-            Key_disrupted_pods,
-            Key_disruptions_allowed,
-            Key_expected_pods,
-            Key_observed_generation,
-            Other,
+impl PodDisruptionBudgetStatus {
+    /// Constructs a `PodDisruptionBudgetStatus` from its required fields. Its optional fields are unset.
+    pub fn new(current_healthy: impl Into<i32>, desired_healthy: impl Into<i32>, disruptions_allowed: impl Into<i32>, expected_pods: impl Into<i32>) -> Self {
+        PodDisruptionBudgetStatus {
+            current_healthy: current_healthy.into(),
+            desired_healthy: desired_healthy.into(),
+            disrupted_pods: None,
+            disruptions_allowed: disruptions_allowed.into(),
+            expected_pods: expected_pods.into(),
+            observed_generation: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl PodDisruptionBudgetStatus {
+    /// Returns this value with [`PodDisruptionBudgetStatus::current_healthy`] set to the given value.
+    #[must_use]
+    pub fn with_current_healthy(mut self, value: impl Into<i32>) -> Self {
+        self.current_healthy = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`PodDisruptionBudgetStatus::desired_healthy`] set to the given value.
+    #[must_use]
+    pub fn with_desired_healthy(mut self, value: impl Into<i32>) -> Self {
+        self.desired_healthy = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`PodDisruptionBudgetStatus::disrupted_pods`] set to the given value.
+    #[must_use]
+    pub fn with_disrupted_pods(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::apis::meta::v1::Time>>) -> Self {
+        self.disrupted_pods = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "currentHealthy" => Field::Key_current_healthy,
-                            "desiredHealthy" => Field::Key_desired_healthy,
-                            "disruptedPods" => Field::Key_disrupted_pods,
-                            "disruptionsAllowed" => Field::Key_disruptions_allowed,
-                            "expectedPods" => Field::Key_expected_pods,
-                            "observedGeneration" => Field::Key_observed_generation,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`PodDisruptionBudgetStatus::disruptions_allowed`] set to the given value.
+    #[must_use]
+    pub fn with_disruptions_allowed(mut self, value: impl Into<i32>) -> Self {
+        self.disruptions_allowed = value.into();
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`PodDisruptionBudgetStatus::expected_pods`] set to the given value.
+    #[must_use]
+    pub fn with_expected_pods(mut self, value: impl Into<i32>) -> Self {
+        self.expected_pods = value.into();
+        self
+    }
+
+    /// Returns this value with [`PodDisruptionBudgetStatus::observed_generation`] set to the given value.
+    #[must_use]
+    pub fn with_observed_generation(mut self, value: impl Into<i64>) -> Self {
+        self.observed_generation = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for PodDisruptionBudgetStatus {}
+
+impl PodDisruptionBudgetStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "currentHealthy",
+        "desiredHealthy",
+        "disruptedPods",
+        "disruptionsAllowed",
+        "expectedPods",
+        "observedGeneration",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for PodDisruptionBudgetStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -80,46 +112,131 @@ impl<'de> crate::serde::Deserialize<'de> for PodDisruptionBudgetStatus {
                 let mut value_expected_pods: Option<i32> = None;
                 let mut value_observed_generation: Option<i64> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodDisruptionBudgetStatus", PodDisruptionBudgetStatus::FIELDS))? {
                     match key {
-                        Field::Key_current_healthy => value_current_healthy = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_desired_healthy => value_desired_healthy = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_disrupted_pods => value_disrupted_pods = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_disruptions_allowed => value_disruptions_allowed = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_expected_pods => value_expected_pods = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_observed_generation => value_observed_generation = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_current_healthy = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_desired_healthy = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_disrupted_pods = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_disruptions_allowed = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(4) => value_expected_pods = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(5) => value_observed_generation = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(PodDisruptionBudgetStatus {
-                    current_healthy: value_current_healthy.ok_or_else(|| crate::serde::de::Error::missing_field("currentHealthy"))?,
-                    desired_healthy: value_desired_healthy.ok_or_else(|| crate::serde::de::Error::missing_field("desiredHealthy"))?,
+                    current_healthy: value_current_healthy.ok_or_else(|| crate::serde2::missing_field("PodDisruptionBudgetStatus", "currentHealthy", &["currentHealthy", "desiredHealthy", "disruptionsAllowed", "expectedPods"]))?,
+                    desired_healthy: value_desired_healthy.ok_or_else(|| crate::serde2::missing_field("PodDisruptionBudgetStatus", "desiredHealthy", &["currentHealthy", "desiredHealthy", "disruptionsAllowed", "expectedPods"]))?,
                     disrupted_pods: value_disrupted_pods,
-                    disruptions_allowed: value_disruptions_allowed.ok_or_else(|| crate::serde::de::Error::missing_field("disruptionsAllowed"))?,
-                    expected_pods: value_expected_pods.ok_or_else(|| crate::serde::de::Error::missing_field("expectedPods"))?,
+                    disruptions_allowed: value_disruptions_allowed.ok_or_else(|| crate::serde2::missing_field("PodDisruptionBudgetStatus", "disruptionsAllowed", &["currentHealthy", "desiredHealthy", "disruptionsAllowed", "expectedPods"]))?,
+                    expected_pods: value_expected_pods.ok_or_else(|| crate::serde2::missing_field("PodDisruptionBudgetStatus", "expectedPods", &["currentHealthy", "desiredHealthy", "disruptionsAllowed", "expectedPods"]))?,
                     observed_generation: value_observed_generation,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(PodDisruptionBudgetStatus {
+                    current_healthy: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    desired_healthy: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    disrupted_pods: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    disruptions_allowed: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    expected_pods: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    observed_generation: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "PodDisruptionBudgetStatus",
-            &[
-                "currentHealthy",
-                "desiredHealthy",
-                "disruptedPods",
-                "disruptionsAllowed",
-                "expectedPods",
-                "observedGeneration",
-            ],
+            PodDisruptionBudgetStatus::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, PodDisruptionBudgetStatus> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("PodDisruptionBudgetStatus")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_current_healthy = false;
+                let mut has_desired_healthy = false;
+                let mut has_disrupted_pods = false;
+                let mut has_disruptions_allowed = false;
+                let mut has_expected_pods = false;
+                let mut has_observed_generation = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodDisruptionBudgetStatus", PodDisruptionBudgetStatus::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.current_healthy))?; has_current_healthy = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.desired_healthy))?; has_desired_healthy = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.disrupted_pods))?; has_disrupted_pods = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.disruptions_allowed))?; has_disruptions_allowed = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.expected_pods))?; has_expected_pods = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.observed_generation))?; has_observed_generation = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_current_healthy {
+                    return Err(crate::serde2::missing_field("PodDisruptionBudgetStatus", "currentHealthy", &["currentHealthy", "desiredHealthy", "disruptionsAllowed", "expectedPods"]));
+                }
+                if !has_desired_healthy {
+                    return Err(crate::serde2::missing_field("PodDisruptionBudgetStatus", "desiredHealthy", &["currentHealthy", "desiredHealthy", "disruptionsAllowed", "expectedPods"]));
+                }
+                if !has_disrupted_pods {
+                    self.0.disrupted_pods = None;
+                }
+                if !has_disruptions_allowed {
+                    return Err(crate::serde2::missing_field("PodDisruptionBudgetStatus", "disruptionsAllowed", &["currentHealthy", "desiredHealthy", "disruptionsAllowed", "expectedPods"]));
+                }
+                if !has_expected_pods {
+                    return Err(crate::serde2::missing_field("PodDisruptionBudgetStatus", "expectedPods", &["currentHealthy", "desiredHealthy", "disruptionsAllowed", "expectedPods"]));
+                }
+                if !has_observed_generation {
+                    self.0.observed_generation = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.current_healthy))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.desired_healthy))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.disrupted_pods))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.disruptions_allowed))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.expected_pods))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.observed_generation))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "PodDisruptionBudgetStatus",
+            PodDisruptionBudgetStatus::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for PodDisruptionBudgetStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("PodDisruptionBudgetStatus", 6)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "currentHealthy", &self.current_healthy)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "desiredHealthy", &self.desired_healthy)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "disruptedPods", &self.disrupted_pods)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "disruptionsAllowed", &self.disruptions_allowed)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "expectedPods", &self.expected_pods)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "observedGeneration", &self.observed_generation)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "PodDisruptionBudgetStatus",
             4 +
@@ -140,10 +257,144 @@ impl crate::serde::Serialize for PodDisruptionBudgetStatus {
     }
 }
 
+/// A builder of [`PodDisruptionBudgetStatus`]. Created with [`PodDisruptionBudgetStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct PodDisruptionBudgetStatusBuilder {
+    current_healthy: Option<i32>,
+    desired_healthy: Option<i32>,
+    disrupted_pods: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::apis::meta::v1::Time>>,
+    disruptions_allowed: Option<i32>,
+    expected_pods: Option<i32>,
+    observed_generation: Option<i64>,
+}
+
+#[cfg(feature = "builder")]
+impl PodDisruptionBudgetStatus {
+    /// Starts building a `PodDisruptionBudgetStatus`. Its required fields must be set before [`PodDisruptionBudgetStatusBuilder::build`] is called.
+    pub fn builder() -> PodDisruptionBudgetStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl PodDisruptionBudgetStatusBuilder {
+    /// Sets [`PodDisruptionBudgetStatus::current_healthy`].
+    pub fn current_healthy(mut self, value: impl Into<i32>) -> Self {
+        self.current_healthy = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodDisruptionBudgetStatus::desired_healthy`].
+    pub fn desired_healthy(mut self, value: impl Into<i32>) -> Self {
+        self.desired_healthy = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodDisruptionBudgetStatus::disrupted_pods`].
+    pub fn disrupted_pods(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::apis::meta::v1::Time>>) -> Self {
+        self.disrupted_pods = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodDisruptionBudgetStatus::disruptions_allowed`].
+    pub fn disruptions_allowed(mut self, value: impl Into<i32>) -> Self {
+        self.disruptions_allowed = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodDisruptionBudgetStatus::expected_pods`].
+    pub fn expected_pods(mut self, value: impl Into<i32>) -> Self {
+        self.expected_pods = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodDisruptionBudgetStatus::observed_generation`].
+    pub fn observed_generation(mut self, value: impl Into<i64>) -> Self {
+        self.observed_generation = Some(value.into());
+        self
+    }
+
+    /// Builds the `PodDisruptionBudgetStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<PodDisruptionBudgetStatus, crate::MissingFieldError> {
+        Ok(PodDisruptionBudgetStatus {
+            current_healthy: self.current_healthy.ok_or_else(|| crate::MissingFieldError::new("PodDisruptionBudgetStatus", "currentHealthy"))?,
+            desired_healthy: self.desired_healthy.ok_or_else(|| crate::MissingFieldError::new("PodDisruptionBudgetStatus", "desiredHealthy"))?,
+            disrupted_pods: self.disrupted_pods,
+            disruptions_allowed: self.disruptions_allowed.ok_or_else(|| crate::MissingFieldError::new("PodDisruptionBudgetStatus", "disruptionsAllowed"))?,
+            expected_pods: self.expected_pods.ok_or_else(|| crate::MissingFieldError::new("PodDisruptionBudgetStatus", "expectedPods"))?,
+            observed_generation: self.observed_generation,
+        })
+    }
+}
+
+/// The apply configuration of [`PodDisruptionBudgetStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodDisruptionBudgetStatusApplyConfiguration {
+    /// See [`PodDisruptionBudgetStatus::current_healthy`].
+    pub current_healthy: Option<i32>,
+
+    /// See [`PodDisruptionBudgetStatus::desired_healthy`].
+    pub desired_healthy: Option<i32>,
+
+    /// See [`PodDisruptionBudgetStatus::disrupted_pods`].
+    pub disrupted_pods: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::apis::meta::v1::Time>>,
+
+    /// See [`PodDisruptionBudgetStatus::disruptions_allowed`].
+    pub disruptions_allowed: Option<i32>,
+
+    /// See [`PodDisruptionBudgetStatus::expected_pods`].
+    pub expected_pods: Option<i32>,
+
+    /// See [`PodDisruptionBudgetStatus::observed_generation`].
+    pub observed_generation: Option<i64>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for PodDisruptionBudgetStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "PodDisruptionBudgetStatusApplyConfiguration",
+            self.current_healthy.as_ref().map_or(0, |_| 1) +
+            self.desired_healthy.as_ref().map_or(0, |_| 1) +
+            self.disrupted_pods.as_ref().map_or(0, |_| 1) +
+            self.disruptions_allowed.as_ref().map_or(0, |_| 1) +
+            self.expected_pods.as_ref().map_or(0, |_| 1) +
+            self.observed_generation.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.current_healthy {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "currentHealthy", value)?;
+        }
+        if let Some(value) = &self.desired_healthy {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "desiredHealthy", value)?;
+        }
+        if let Some(value) = &self.disrupted_pods {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "disruptedPods", value)?;
+        }
+        if let Some(value) = &self.disruptions_allowed {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "disruptionsAllowed", value)?;
+        }
+        if let Some(value) = &self.expected_pods {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "expectedPods", value)?;
+        }
+        if let Some(value) = &self.observed_generation {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "observedGeneration", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodDisruptionBudgetStatus {
     fn schema_name() -> String {
-        "io.k8s.api.policy.v1beta1.PodDisruptionBudgetStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::policy::v1beta1::PodDisruptionBudgetStatus".to_owned()
+        }
+        else {
+            "io.k8s.api.policy.v1beta1.PodDisruptionBudgetStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -154,8 +405,9 @@ impl crate::schemars::JsonSchema for PodDisruptionBudgetStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "currentHealthy".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -166,8 +418,8 @@ impl crate::schemars::JsonSchema for PodDisruptionBudgetStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "desiredHealthy".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -178,8 +430,8 @@ impl crate::schemars::JsonSchema for PodDisruptionBudgetStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "disruptedPods".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -193,8 +445,8 @@ impl crate::schemars::JsonSchema for PodDisruptionBudgetStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "disruptionsAllowed".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -205,8 +457,8 @@ impl crate::schemars::JsonSchema for PodDisruptionBudgetStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "expectedPods".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -217,8 +469,8 @@ impl crate::schemars::JsonSchema for PodDisruptionBudgetStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "observedGeneration".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -229,8 +481,9 @@ impl crate::schemars::JsonSchema for PodDisruptionBudgetStatus {
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "currentHealthy",
                     "desiredHealthy",
@@ -243,3 +496,17 @@ impl crate::schemars::JsonSchema for PodDisruptionBudgetStatus {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for PodDisruptionBudgetStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(PodDisruptionBudgetStatus {
+            current_healthy: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            desired_healthy: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            disrupted_pods: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            disruptions_allowed: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            expected_pods: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            observed_generation: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.policy.v1beta1.SELinuxStrategyOptions
 
 /// SELinuxStrategyOptions defines the strategy type and any options used to create the strategy.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct SELinuxStrategyOptions {
     /// rule is the strategy that will dictate the allowable labels that may be set.
     pub rule: String,
@@ -10,39 +10,43 @@ pub struct SELinuxStrategyOptions {
     pub se_linux_options: Option<crate::api::core::v1::SELinuxOptions>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for SELinuxStrategyOptions {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_rule,
-            Key_se_linux_options,
-            Other,
+impl SELinuxStrategyOptions {
+    /// Constructs a `SELinuxStrategyOptions` from its required fields. Its optional fields are unset.
+    pub fn new(rule: impl Into<String>) -> Self {
+        SELinuxStrategyOptions {
+            rule: rule.into(),
+            se_linux_options: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl SELinuxStrategyOptions {
+    /// Returns this value with [`SELinuxStrategyOptions::rule`] set to the given value.
+    #[must_use]
+    pub fn with_rule(mut self, value: impl Into<String>) -> Self {
+        self.rule = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`SELinuxStrategyOptions::se_linux_options`] set to the given value.
+    #[must_use]
+    pub fn with_se_linux_options(mut self, value: impl Into<crate::api::core::v1::SELinuxOptions>) -> Self {
+        self.se_linux_options = Some(value.into());
+        self
+    }
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for SELinuxStrategyOptions {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "rule" => Field::Key_rule,
-                            "seLinuxOptions" => Field::Key_se_linux_options,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl SELinuxStrategyOptions {
+    const FIELDS: &'static [&'static str] = &[
+        "rule",
+        "seLinuxOptions",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for SELinuxStrategyOptions {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +60,91 @@ impl<'de> crate::serde::Deserialize<'de> for SELinuxStrategyOptions {
                 let mut value_rule: Option<String> = None;
                 let mut value_se_linux_options: Option<crate::api::core::v1::SELinuxOptions> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("SELinuxStrategyOptions", SELinuxStrategyOptions::FIELDS))? {
                     match key {
-                        Field::Key_rule => value_rule = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_se_linux_options => value_se_linux_options = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_rule = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_se_linux_options = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(SELinuxStrategyOptions {
-                    rule: value_rule.ok_or_else(|| crate::serde::de::Error::missing_field("rule"))?,
+                    rule: value_rule.ok_or_else(|| crate::serde2::missing_field("SELinuxStrategyOptions", "rule", &["rule"]))?,
                     se_linux_options: value_se_linux_options,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(SELinuxStrategyOptions {
+                    rule: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    se_linux_options: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "SELinuxStrategyOptions",
-            &[
-                "rule",
-                "seLinuxOptions",
-            ],
+            SELinuxStrategyOptions::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, SELinuxStrategyOptions> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("SELinuxStrategyOptions")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_rule = false;
+                let mut has_se_linux_options = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("SELinuxStrategyOptions", SELinuxStrategyOptions::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.rule))?; has_rule = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.se_linux_options))?; has_se_linux_options = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_rule {
+                    return Err(crate::serde2::missing_field("SELinuxStrategyOptions", "rule", &["rule"]));
+                }
+                if !has_se_linux_options {
+                    self.0.se_linux_options = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.rule))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.se_linux_options))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "SELinuxStrategyOptions",
+            SELinuxStrategyOptions::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for SELinuxStrategyOptions {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("SELinuxStrategyOptions", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rule", &self.rule)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "seLinuxOptions", &self.se_linux_options)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "SELinuxStrategyOptions",
             1 +
@@ -97,10 +158,84 @@ impl crate::serde::Serialize for SELinuxStrategyOptions {
     }
 }
 
+/// A builder of [`SELinuxStrategyOptions`]. Created with [`SELinuxStrategyOptions::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct SELinuxStrategyOptionsBuilder {
+    rule: Option<String>,
+    se_linux_options: Option<crate::api::core::v1::SELinuxOptions>,
+}
+
+#[cfg(feature = "builder")]
+impl SELinuxStrategyOptions {
+    /// Starts building a `SELinuxStrategyOptions`. Its required fields must be set before [`SELinuxStrategyOptionsBuilder::build`] is called.
+    pub fn builder() -> SELinuxStrategyOptionsBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl SELinuxStrategyOptionsBuilder {
+    /// Sets [`SELinuxStrategyOptions::rule`].
+    pub fn rule(mut self, value: impl Into<String>) -> Self {
+        self.rule = Some(value.into());
+        self
+    }
+
+    /// Sets [`SELinuxStrategyOptions::se_linux_options`].
+    pub fn se_linux_options(mut self, value: impl Into<crate::api::core::v1::SELinuxOptions>) -> Self {
+        self.se_linux_options = Some(value.into());
+        self
+    }
+
+    /// Builds the `SELinuxStrategyOptions`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<SELinuxStrategyOptions, crate::MissingFieldError> {
+        Ok(SELinuxStrategyOptions {
+            rule: self.rule.ok_or_else(|| crate::MissingFieldError::new("SELinuxStrategyOptions", "rule"))?,
+            se_linux_options: self.se_linux_options,
+        })
+    }
+}
+
+/// The apply configuration of [`SELinuxStrategyOptions`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SELinuxStrategyOptionsApplyConfiguration {
+    /// See [`SELinuxStrategyOptions::rule`].
+    pub rule: Option<String>,
+
+    /// See [`SELinuxStrategyOptions::se_linux_options`].
+    pub se_linux_options: Option<crate::api::core::v1::SELinuxOptionsApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for SELinuxStrategyOptionsApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "SELinuxStrategyOptionsApplyConfiguration",
+            self.rule.as_ref().map_or(0, |_| 1) +
+            self.se_linux_options.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.rule {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rule", value)?;
+        }
+        if let Some(value) = &self.se_linux_options {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "seLinuxOptions", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for SELinuxStrategyOptions {
     fn schema_name() -> String {
-        "io.k8s.api.policy.v1beta1.SELinuxStrategyOptions".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::policy::v1beta1::SELinuxStrategyOptions".to_owned()
+        }
+        else {
+            "io.k8s.api.policy.v1beta1.SELinuxStrategyOptions".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -111,8 +246,9 @@ impl crate::schemars::JsonSchema for SELinuxStrategyOptions {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "rule".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -122,8 +258,8 @@ impl crate::schemars::JsonSchema for SELinuxStrategyOptions {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "seLinuxOptions".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::SELinuxOptions>().into_object();
@@ -133,8 +269,9 @@ impl crate::schemars::JsonSchema for SELinuxStrategyOptions {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "rule",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -144,3 +281,13 @@ impl crate::schemars::JsonSchema for SELinuxStrategyOptions {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for SELinuxStrategyOptions {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(SELinuxStrategyOptions {
+            rule: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            se_linux_options: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
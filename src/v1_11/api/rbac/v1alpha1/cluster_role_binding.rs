@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.rbac.v1alpha1.ClusterRoleBinding
 
 /// ClusterRoleBinding references a ClusterRole, but not contain it.  It can reference a ClusterRole in the global namespace, and adds who information via Subject.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ClusterRoleBinding {
     /// Standard object's metadata.
     pub metadata: crate::apimachinery::pkg::apis::meta::v1::ObjectMeta,
@@ -13,6 +13,40 @@ pub struct ClusterRoleBinding {
     pub subjects: Option<Vec<crate::api::rbac::v1alpha1::Subject>>,
 }
 
+impl ClusterRoleBinding {
+    /// Constructs a `ClusterRoleBinding` from its required fields. Its optional fields are unset.
+    pub fn new(metadata: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>, role_ref: impl Into<crate::api::rbac::v1alpha1::RoleRef>) -> Self {
+        ClusterRoleBinding {
+            metadata: metadata.into(),
+            role_ref: role_ref.into(),
+            subjects: None,
+        }
+    }
+}
+
+impl ClusterRoleBinding {
+    /// Returns this value with [`ClusterRoleBinding::metadata`] set to the given value.
+    #[must_use]
+    pub fn with_metadata(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        self.metadata = value.into();
+        self
+    }
+
+    /// Returns this value with [`ClusterRoleBinding::role_ref`] set to the given value.
+    #[must_use]
+    pub fn with_role_ref(mut self, value: impl Into<crate::api::rbac::v1alpha1::RoleRef>) -> Self {
+        self.role_ref = value.into();
+        self
+    }
+
+    /// Returns this value with [`ClusterRoleBinding::subjects`] set to the given value.
+    #[must_use]
+    pub fn with_subjects(mut self, value: impl Into<Vec<crate::api::rbac::v1alpha1::Subject>>) -> Self {
+        self.subjects = Some(value.into());
+        self
+    }
+}
+
 // Begin rbac.authorization.k8s.io/v1alpha1/ClusterRoleBinding
 
 // Generated from operation createRbacAuthorizationV1alpha1ClusterRoleBinding
@@ -70,6 +104,7 @@ impl ClusterRoleBinding {
         name: &str,
         optional: crate::DeleteOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::DeleteResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
         let __url = format!("/apis/rbac.authorization.k8s.io/v1alpha1/clusterrolebindings/{name}",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -176,6 +211,7 @@ impl ClusterRoleBinding {
         body: &crate::apimachinery::pkg::apis::meta::v1::Patch,
         optional: crate::PatchOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::PatchResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
         let __url = format!("/apis/rbac.authorization.k8s.io/v1alpha1/clusterrolebindings/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -221,6 +257,7 @@ impl ClusterRoleBinding {
         let ReadClusterRoleBindingOptional {
             pretty,
         } = optional;
+        crate::validate_path_parameter("name", name)?;
         let __url = format!("/apis/rbac.authorization.k8s.io/v1alpha1/clusterrolebindings/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -309,6 +346,7 @@ impl ClusterRoleBinding {
         body: &crate::api::rbac::v1alpha1::ClusterRoleBinding,
         optional: crate::ReplaceOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::ReplaceResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
         let __url = format!("/apis/rbac.authorization.k8s.io/v1alpha1/clusterrolebindings/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -366,6 +404,9 @@ impl crate::Resource for ClusterRoleBinding {
     const KIND: &'static str = "ClusterRoleBinding";
     const VERSION: &'static str = "v1alpha1";
     const URL_PATH_SEGMENT: &'static str = "clusterrolebindings";
+    const VERBS: &'static [&'static str] = &["create", "delete", "deletecollection", "get", "list", "patch", "update", "watch"];
+    const SINGULAR: &'static str = "clusterrolebinding";
+    const SHORT_NAMES: &'static [&'static str] = &[];
     type Scope = crate::ClusterResourceScope;
 }
 
@@ -385,45 +426,30 @@ impl crate::Metadata for ClusterRoleBinding {
     }
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ClusterRoleBinding {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_api_version,
-            Key_kind,
-            Key_metadata,
-            Key_role_ref,
-            Key_subjects,
-            Other,
+impl std::fmt::Display for ClusterRoleBinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(<Self as crate::Resource>::URL_PATH_SEGMENT)?;
+        for part in [&self.metadata.namespace, &self.metadata.name].iter().copied().flatten() {
+            write!(f, "/{part}")?;
         }
+        Ok(())
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
-
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "apiVersion" => Field::Key_api_version,
-                            "kind" => Field::Key_kind,
-                            "metadata" => Field::Key_metadata,
-                            "roleRef" => Field::Key_role_ref,
-                            "subjects" => Field::Key_subjects,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for ClusterRoleBinding {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl ClusterRoleBinding {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "kind",
+        "metadata",
+        "roleRef",
+        "subjects",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ClusterRoleBinding {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -438,51 +464,125 @@ impl<'de> crate::serde::Deserialize<'de> for ClusterRoleBinding {
                 let mut value_role_ref: Option<crate::api::rbac::v1alpha1::RoleRef> = None;
                 let mut value_subjects: Option<Vec<crate::api::rbac::v1alpha1::Subject>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ClusterRoleBinding", ClusterRoleBinding::FIELDS))? {
                     match key {
-                        Field::Key_api_version => {
+                        Some(0) => {
                             let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_api_version != <Self::Value as crate::Resource>::API_VERSION {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<Self::Value as crate::Resource>::API_VERSION));
                             }
                         },
-                        Field::Key_kind => {
+                        Some(1) => {
                             let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_kind != <Self::Value as crate::Resource>::KIND {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<Self::Value as crate::Resource>::KIND));
                             }
                         },
-                        Field::Key_metadata => value_metadata = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_role_ref => value_role_ref = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_subjects => value_subjects = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(2) => value_metadata = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_role_ref = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(4) => value_subjects = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ClusterRoleBinding {
-                    metadata: value_metadata.ok_or_else(|| crate::serde::de::Error::missing_field("metadata"))?,
-                    role_ref: value_role_ref.ok_or_else(|| crate::serde::de::Error::missing_field("roleRef"))?,
+                    metadata: value_metadata.ok_or_else(|| crate::serde2::missing_field("ClusterRoleBinding", "metadata", &["metadata", "roleRef"]))?,
+                    role_ref: value_role_ref.ok_or_else(|| crate::serde2::missing_field("ClusterRoleBinding", "roleRef", &["metadata", "roleRef"]))?,
                     subjects: value_subjects,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ClusterRoleBinding {
+                    metadata: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    role_ref: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    subjects: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             <Self as crate::Resource>::KIND,
-            &[
-                "apiVersion",
-                "kind",
-                "metadata",
-                "roleRef",
-                "subjects",
-            ],
+            ClusterRoleBinding::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ClusterRoleBinding> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<ClusterRoleBinding as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_metadata = false;
+                let mut has_role_ref = false;
+                let mut has_subjects = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ClusterRoleBinding", ClusterRoleBinding::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <ClusterRoleBinding as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<ClusterRoleBinding as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <ClusterRoleBinding as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<ClusterRoleBinding as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metadata))?; has_metadata = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.role_ref))?; has_role_ref = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.subjects))?; has_subjects = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_metadata {
+                    return Err(crate::serde2::missing_field("ClusterRoleBinding", "metadata", &["metadata", "roleRef"]));
+                }
+                if !has_role_ref {
+                    return Err(crate::serde2::missing_field("ClusterRoleBinding", "roleRef", &["metadata", "roleRef"]));
+                }
+                if !has_subjects {
+                    self.0.subjects = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metadata))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.role_ref))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.subjects))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            ClusterRoleBinding::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ClusterRoleBinding {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct(<Self as crate::Resource>::KIND, 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", &self.metadata)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "roleRef", &self.role_ref)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "subjects", &self.subjects)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             <Self as crate::Resource>::KIND,
             4 +
@@ -499,10 +599,102 @@ impl crate::serde::Serialize for ClusterRoleBinding {
     }
 }
 
+/// A builder of [`ClusterRoleBinding`]. Created with [`ClusterRoleBinding::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ClusterRoleBindingBuilder {
+    metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>,
+    role_ref: Option<crate::api::rbac::v1alpha1::RoleRef>,
+    subjects: Option<Vec<crate::api::rbac::v1alpha1::Subject>>,
+}
+
+#[cfg(feature = "builder")]
+impl ClusterRoleBinding {
+    /// Starts building a `ClusterRoleBinding`. Its required fields must be set before [`ClusterRoleBindingBuilder::build`] is called.
+    pub fn builder() -> ClusterRoleBindingBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ClusterRoleBindingBuilder {
+    /// Sets [`ClusterRoleBinding::metadata`].
+    pub fn metadata(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        self.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`ClusterRoleBinding::role_ref`].
+    pub fn role_ref(mut self, value: impl Into<crate::api::rbac::v1alpha1::RoleRef>) -> Self {
+        self.role_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`ClusterRoleBinding::subjects`].
+    pub fn subjects(mut self, value: impl Into<Vec<crate::api::rbac::v1alpha1::Subject>>) -> Self {
+        self.subjects = Some(value.into());
+        self
+    }
+
+    /// Builds the `ClusterRoleBinding`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ClusterRoleBinding, crate::MissingFieldError> {
+        Ok(ClusterRoleBinding {
+            metadata: self.metadata.ok_or_else(|| crate::MissingFieldError::new("ClusterRoleBinding", "metadata"))?,
+            role_ref: self.role_ref.ok_or_else(|| crate::MissingFieldError::new("ClusterRoleBinding", "roleRef"))?,
+            subjects: self.subjects,
+        })
+    }
+}
+
+/// The apply configuration of [`ClusterRoleBinding`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ClusterRoleBindingApplyConfiguration {
+    /// See [`ClusterRoleBinding::metadata`].
+    pub metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMetaApplyConfiguration>,
+
+    /// See [`ClusterRoleBinding::role_ref`].
+    pub role_ref: Option<crate::api::rbac::v1alpha1::RoleRefApplyConfiguration>,
+
+    /// See [`ClusterRoleBinding::subjects`].
+    pub subjects: Option<Vec<crate::api::rbac::v1alpha1::SubjectApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ClusterRoleBindingApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            <ClusterRoleBinding as crate::Resource>::KIND,
+            2 +
+            self.metadata.as_ref().map_or(0, |_| 1) +
+            self.role_ref.as_ref().map_or(0, |_| 1) +
+            self.subjects.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <ClusterRoleBinding as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <ClusterRoleBinding as crate::Resource>::KIND)?;
+        if let Some(value) = &self.metadata {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", value)?;
+        }
+        if let Some(value) = &self.role_ref {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "roleRef", value)?;
+        }
+        if let Some(value) = &self.subjects {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "subjects", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ClusterRoleBinding {
     fn schema_name() -> String {
-        "io.k8s.api.rbac.v1alpha1.ClusterRoleBinding".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::rbac::v1alpha1::ClusterRoleBinding".to_owned()
+        }
+        else {
+            "io.k8s.api.rbac.v1alpha1.ClusterRoleBinding".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -513,8 +705,9 @@ impl crate::schemars::JsonSchema for ClusterRoleBinding {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "apiVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -522,10 +715,11 @@ impl crate::schemars::JsonSchema for ClusterRoleBinding {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("rbac.authorization.k8s.io/v1alpha1".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "kind".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -533,10 +727,11 @@ impl crate::schemars::JsonSchema for ClusterRoleBinding {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("ClusterRoleBinding".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "metadata".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>().into_object();
@@ -546,8 +741,8 @@ impl crate::schemars::JsonSchema for ClusterRoleBinding {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "roleRef".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::rbac::v1alpha1::RoleRef>().into_object();
@@ -557,8 +752,8 @@ impl crate::schemars::JsonSchema for ClusterRoleBinding {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "subjects".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -572,9 +767,12 @@ impl crate::schemars::JsonSchema for ClusterRoleBinding {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
+                    "apiVersion",
+                    "kind",
                     "metadata",
                     "roleRef",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -584,3 +782,14 @@ impl crate::schemars::JsonSchema for ClusterRoleBinding {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ClusterRoleBinding {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ClusterRoleBinding {
+            metadata: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            role_ref: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            subjects: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
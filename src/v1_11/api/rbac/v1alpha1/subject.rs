@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.rbac.v1alpha1.Subject
 
 /// Subject contains a reference to the object or user identities a role binding applies to.  This can either hold a direct API object reference, or a value for non-objects such as user and group names.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct Subject {
     /// APIVersion holds the API group and version of the referenced subject. Defaults to "v1" for ServiceAccount subjects. Defaults to "rbac.authorization.k8s.io/v1alpha1" for User and Group subjects.
     pub api_version: Option<String>,
@@ -16,43 +16,61 @@ pub struct Subject {
     pub namespace: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for Subject {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_api_version,
-            Key_kind,
-            Key_name,
-            Key_namespace,
-            Other,
+impl Subject {
+    /// Constructs a `Subject` from its required fields. Its optional fields are unset.
+    pub fn new(kind: impl Into<String>, name: impl Into<String>) -> Self {
+        Subject {
+            api_version: None,
+            kind: kind.into(),
+            name: name.into(),
+            namespace: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl Subject {
+    /// Returns this value with [`Subject::api_version`] set to the given value.
+    #[must_use]
+    pub fn with_api_version(mut self, value: impl Into<String>) -> Self {
+        self.api_version = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`Subject::kind`] set to the given value.
+    #[must_use]
+    pub fn with_kind(mut self, value: impl Into<String>) -> Self {
+        self.kind = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`Subject::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "apiVersion" => Field::Key_api_version,
-                            "kind" => Field::Key_kind,
-                            "name" => Field::Key_name,
-                            "namespace" => Field::Key_namespace,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`Subject::namespace`] set to the given value.
+    #[must_use]
+    pub fn with_namespace(mut self, value: impl Into<String>) -> Self {
+        self.namespace = Some(value.into());
+        self
+    }
+}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl crate::MergeStrategyInfo for Subject {}
+
+impl Subject {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "kind",
+        "name",
+        "namespace",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for Subject {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -68,40 +86,111 @@ impl<'de> crate::serde::Deserialize<'de> for Subject {
                 let mut value_name: Option<String> = None;
                 let mut value_namespace: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Subject", Subject::FIELDS))? {
                     match key {
-                        Field::Key_api_version => value_api_version = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_kind => value_kind = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_name => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_namespace => value_namespace = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_api_version = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_kind = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_namespace = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(Subject {
                     api_version: value_api_version,
-                    kind: value_kind.ok_or_else(|| crate::serde::de::Error::missing_field("kind"))?,
-                    name: value_name.ok_or_else(|| crate::serde::de::Error::missing_field("name"))?,
+                    kind: value_kind.ok_or_else(|| crate::serde2::missing_field("Subject", "kind", &["kind", "name"]))?,
+                    name: value_name.ok_or_else(|| crate::serde2::missing_field("Subject", "name", &["kind", "name"]))?,
                     namespace: value_namespace,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(Subject {
+                    api_version: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    kind: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    namespace: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "Subject",
-            &[
-                "apiVersion",
-                "kind",
-                "name",
-                "namespace",
-            ],
+            Subject::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, Subject> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("Subject")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_api_version = false;
+                let mut has_kind = false;
+                let mut has_name = false;
+                let mut has_namespace = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Subject", Subject::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.api_version))?; has_api_version = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.kind))?; has_kind = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.name))?; has_name = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.namespace))?; has_namespace = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_api_version {
+                    self.0.api_version = None;
+                }
+                if !has_kind {
+                    return Err(crate::serde2::missing_field("Subject", "kind", &["kind", "name"]));
+                }
+                if !has_name {
+                    return Err(crate::serde2::missing_field("Subject", "name", &["kind", "name"]));
+                }
+                if !has_namespace {
+                    self.0.namespace = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.api_version))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.kind))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.namespace))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "Subject",
+            Subject::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for Subject {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("Subject", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", &self.api_version)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", &self.kind)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "namespace", &self.namespace)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "Subject",
             2 +
@@ -120,10 +209,114 @@ impl crate::serde::Serialize for Subject {
     }
 }
 
+/// A builder of [`Subject`]. Created with [`Subject::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct SubjectBuilder {
+    api_version: Option<String>,
+    kind: Option<String>,
+    name: Option<String>,
+    namespace: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl Subject {
+    /// Starts building a `Subject`. Its required fields must be set before [`SubjectBuilder::build`] is called.
+    pub fn builder() -> SubjectBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl SubjectBuilder {
+    /// Sets [`Subject::api_version`].
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`Subject::kind`].
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`Subject::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`Subject::namespace`].
+    pub fn namespace(mut self, value: impl Into<String>) -> Self {
+        self.namespace = Some(value.into());
+        self
+    }
+
+    /// Builds the `Subject`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<Subject, crate::MissingFieldError> {
+        Ok(Subject {
+            api_version: self.api_version,
+            kind: self.kind.ok_or_else(|| crate::MissingFieldError::new("Subject", "kind"))?,
+            name: self.name.ok_or_else(|| crate::MissingFieldError::new("Subject", "name"))?,
+            namespace: self.namespace,
+        })
+    }
+}
+
+/// The apply configuration of [`Subject`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SubjectApplyConfiguration {
+    /// See [`Subject::api_version`].
+    pub api_version: Option<String>,
+
+    /// See [`Subject::kind`].
+    pub kind: Option<String>,
+
+    /// See [`Subject::name`].
+    pub name: Option<String>,
+
+    /// See [`Subject::namespace`].
+    pub namespace: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for SubjectApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "SubjectApplyConfiguration",
+            self.api_version.as_ref().map_or(0, |_| 1) +
+            self.kind.as_ref().map_or(0, |_| 1) +
+            self.name.as_ref().map_or(0, |_| 1) +
+            self.namespace.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.api_version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", value)?;
+        }
+        if let Some(value) = &self.kind {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", value)?;
+        }
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        if let Some(value) = &self.namespace {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "namespace", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Subject {
     fn schema_name() -> String {
-        "io.k8s.api.rbac.v1alpha1.Subject".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::rbac::v1alpha1::Subject".to_owned()
+        }
+        else {
+            "io.k8s.api.rbac.v1alpha1.Subject".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -134,8 +327,9 @@ impl crate::schemars::JsonSchema for Subject {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "apiVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -145,8 +339,8 @@ impl crate::schemars::JsonSchema for Subject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "kind".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -156,8 +350,8 @@ impl crate::schemars::JsonSchema for Subject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -167,8 +361,8 @@ impl crate::schemars::JsonSchema for Subject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "namespace".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -178,8 +372,9 @@ impl crate::schemars::JsonSchema for Subject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "kind",
                     "name",
@@ -190,3 +385,15 @@ impl crate::schemars::JsonSchema for Subject {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for Subject {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(Subject {
+            api_version: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            kind: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            namespace: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
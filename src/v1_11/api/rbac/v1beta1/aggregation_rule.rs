@@ -1,80 +1,118 @@
 // Generated from definition io.k8s.api.rbac.v1beta1.AggregationRule
 
 /// AggregationRule describes how to locate ClusterRoles to aggregate into the ClusterRole
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct AggregationRule {
     /// ClusterRoleSelectors holds a list of selectors which will be used to find ClusterRoles and create the rules. If any of the selectors match, then the ClusterRole's permissions will be added
     pub cluster_role_selectors: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>>,
 }
 
+impl AggregationRule {
+    /// Returns this value with [`AggregationRule::cluster_role_selectors`] set to the given value.
+    #[must_use]
+    pub fn with_cluster_role_selectors(mut self, value: impl Into<Vec<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>>) -> Self {
+        self.cluster_role_selectors = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for AggregationRule {}
+
+impl AggregationRule {
+    const FIELDS: &'static [&'static str] = &[
+        "clusterRoleSelectors",
+    ];
+}
+
 impl<'de> crate::serde::Deserialize<'de> for AggregationRule {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_cluster_role_selectors,
-            Other,
-        }
+        struct Visitor;
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = AggregationRule;
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("AggregationRule")
+            }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_cluster_role_selectors: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>> = None;
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "clusterRoleSelectors" => Field::Key_cluster_role_selectors,
-                            _ => Field::Other,
-                        })
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("AggregationRule", AggregationRule::FIELDS))? {
+                    match key {
+                        Some(0) => value_cluster_role_selectors = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
-                deserializer.deserialize_identifier(Visitor)
+                Ok(AggregationRule {
+                    cluster_role_selectors: value_cluster_role_selectors,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(AggregationRule {
+                    cluster_role_selectors: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                })
             }
         }
 
-        struct Visitor;
+        deserializer.deserialize_struct(
+            "AggregationRule",
+            AggregationRule::FIELDS,
+            Visitor,
+        )
+    }
 
-        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-            type Value = AggregationRule;
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, AggregationRule> {
+            type Value = ();
 
             fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 f.write_str("AggregationRule")
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
-                let mut value_cluster_role_selectors: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>> = None;
+                let mut has_cluster_role_selectors = false;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("AggregationRule", AggregationRule::FIELDS))? {
                     match key {
-                        Field::Key_cluster_role_selectors => value_cluster_role_selectors = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.cluster_role_selectors))?; has_cluster_role_selectors = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
+                if !has_cluster_role_selectors {
+                    self.0.cluster_role_selectors = None;
+                }
 
-                Ok(AggregationRule {
-                    cluster_role_selectors: value_cluster_role_selectors,
-                })
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.cluster_role_selectors))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
             }
         }
 
         deserializer.deserialize_struct(
             "AggregationRule",
-            &[
-                "clusterRoleSelectors",
-            ],
-            Visitor,
+            AggregationRule::FIELDS,
+            InPlaceVisitor(place),
         )
     }
 }
 
 impl crate::serde::Serialize for AggregationRule {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("AggregationRule", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clusterRoleSelectors", &self.cluster_role_selectors)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "AggregationRule",
             self.cluster_role_selectors.as_ref().map_or(0, |_| 1),
@@ -86,10 +124,69 @@ impl crate::serde::Serialize for AggregationRule {
     }
 }
 
+/// A builder of [`AggregationRule`]. Created with [`AggregationRule::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct AggregationRuleBuilder {
+    cluster_role_selectors: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>>,
+}
+
+#[cfg(feature = "builder")]
+impl AggregationRule {
+    /// Starts building a `AggregationRule`. Its required fields must be set before [`AggregationRuleBuilder::build`] is called.
+    pub fn builder() -> AggregationRuleBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl AggregationRuleBuilder {
+    /// Sets [`AggregationRule::cluster_role_selectors`].
+    pub fn cluster_role_selectors(mut self, value: impl Into<Vec<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>>) -> Self {
+        self.cluster_role_selectors = Some(value.into());
+        self
+    }
+
+    /// Builds the `AggregationRule`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<AggregationRule, crate::MissingFieldError> {
+        Ok(AggregationRule {
+            cluster_role_selectors: self.cluster_role_selectors,
+        })
+    }
+}
+
+/// The apply configuration of [`AggregationRule`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AggregationRuleApplyConfiguration {
+    /// See [`AggregationRule::cluster_role_selectors`].
+    pub cluster_role_selectors: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for AggregationRuleApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "AggregationRuleApplyConfiguration",
+            self.cluster_role_selectors.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.cluster_role_selectors {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clusterRoleSelectors", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for AggregationRule {
     fn schema_name() -> String {
-        "io.k8s.api.rbac.v1beta1.AggregationRule".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::rbac::v1beta1::AggregationRule".to_owned()
+        }
+        else {
+            "io.k8s.api.rbac.v1beta1.AggregationRule".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -100,8 +197,9 @@ impl crate::schemars::JsonSchema for AggregationRule {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "clusterRoleSelectors".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -115,11 +213,21 @@ impl crate::schemars::JsonSchema for AggregationRule {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for AggregationRule {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(AggregationRule {
+            cluster_role_selectors: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
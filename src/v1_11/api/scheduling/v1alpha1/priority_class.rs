@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.scheduling.v1alpha1.PriorityClass
 
 /// PriorityClass defines mapping from a priority class name to the priority integer value. The value can be any valid integer.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct PriorityClass {
     /// description is an arbitrary string that usually provides guidelines on when this priority class should be used.
     pub description: Option<String>,
@@ -16,6 +16,48 @@ pub struct PriorityClass {
     pub value: i32,
 }
 
+impl PriorityClass {
+    /// Constructs a `PriorityClass` from its required fields. Its optional fields are unset.
+    pub fn new(metadata: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>, value: impl Into<i32>) -> Self {
+        PriorityClass {
+            description: None,
+            global_default: None,
+            metadata: metadata.into(),
+            value: value.into(),
+        }
+    }
+}
+
+impl PriorityClass {
+    /// Returns this value with [`PriorityClass::description`] set to the given value.
+    #[must_use]
+    pub fn with_description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PriorityClass::global_default`] set to the given value.
+    #[must_use]
+    pub fn with_global_default(mut self, value: impl Into<bool>) -> Self {
+        self.global_default = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PriorityClass::metadata`] set to the given value.
+    #[must_use]
+    pub fn with_metadata(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        self.metadata = value.into();
+        self
+    }
+
+    /// Returns this value with [`PriorityClass::value`] set to the given value.
+    #[must_use]
+    pub fn with_value(mut self, value: impl Into<i32>) -> Self {
+        self.value = value.into();
+        self
+    }
+}
+
 // Begin scheduling.k8s.io/v1alpha1/PriorityClass
 
 // Generated from operation createSchedulingV1alpha1PriorityClass
@@ -109,6 +151,7 @@ impl PriorityClass {
         name: &str,
         optional: crate::DeleteOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::DeleteResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
         let __url = format!("/apis/scheduling.k8s.io/v1alpha1/priorityclasses/{name}",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -179,6 +222,7 @@ impl PriorityClass {
         body: &crate::apimachinery::pkg::apis::meta::v1::Patch,
         optional: crate::PatchOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::PatchResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
         let __url = format!("/apis/scheduling.k8s.io/v1alpha1/priorityclasses/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -226,6 +270,7 @@ impl PriorityClass {
             export,
             pretty,
         } = optional;
+        crate::validate_path_parameter("name", name)?;
         let __url = format!("/apis/scheduling.k8s.io/v1alpha1/priorityclasses/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -324,6 +369,7 @@ impl PriorityClass {
         body: &crate::api::scheduling::v1alpha1::PriorityClass,
         optional: crate::ReplaceOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::ReplaceResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
         let __url = format!("/apis/scheduling.k8s.io/v1alpha1/priorityclasses/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -381,6 +427,9 @@ impl crate::Resource for PriorityClass {
     const KIND: &'static str = "PriorityClass";
     const VERSION: &'static str = "v1alpha1";
     const URL_PATH_SEGMENT: &'static str = "priorityclasses";
+    const VERBS: &'static [&'static str] = &["create", "delete", "deletecollection", "get", "list", "patch", "update", "watch"];
+    const SINGULAR: &'static str = "priorityclass";
+    const SHORT_NAMES: &'static [&'static str] = &["pc"];
     type Scope = crate::ClusterResourceScope;
 }
 
@@ -400,47 +449,31 @@ impl crate::Metadata for PriorityClass {
     }
 }
 
-impl<'de> crate::serde::Deserialize<'de> for PriorityClass {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_api_version,
-            Key_kind,
-            Key_description,
-            Key_global_default,
-            Key_metadata,
-            Key_value,
-            Other,
+impl std::fmt::Display for PriorityClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(<Self as crate::Resource>::URL_PATH_SEGMENT)?;
+        for part in [&self.metadata.namespace, &self.metadata.name].iter().copied().flatten() {
+            write!(f, "/{part}")?;
         }
+        Ok(())
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
-
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "apiVersion" => Field::Key_api_version,
-                            "kind" => Field::Key_kind,
-                            "description" => Field::Key_description,
-                            "globalDefault" => Field::Key_global_default,
-                            "metadata" => Field::Key_metadata,
-                            "value" => Field::Key_value,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for PriorityClass {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl PriorityClass {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "kind",
+        "description",
+        "globalDefault",
+        "metadata",
+        "value",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for PriorityClass {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -456,54 +489,135 @@ impl<'de> crate::serde::Deserialize<'de> for PriorityClass {
                 let mut value_metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> = None;
                 let mut value_value: Option<i32> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PriorityClass", PriorityClass::FIELDS))? {
                     match key {
-                        Field::Key_api_version => {
+                        Some(0) => {
                             let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_api_version != <Self::Value as crate::Resource>::API_VERSION {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<Self::Value as crate::Resource>::API_VERSION));
                             }
                         },
-                        Field::Key_kind => {
+                        Some(1) => {
                             let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_kind != <Self::Value as crate::Resource>::KIND {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<Self::Value as crate::Resource>::KIND));
                             }
                         },
-                        Field::Key_description => value_description = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_global_default => value_global_default = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_metadata => value_metadata = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_value => value_value = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(2) => value_description = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_global_default = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_metadata = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(5) => value_value = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(PriorityClass {
                     description: value_description,
                     global_default: value_global_default,
-                    metadata: value_metadata.ok_or_else(|| crate::serde::de::Error::missing_field("metadata"))?,
-                    value: value_value.ok_or_else(|| crate::serde::de::Error::missing_field("value"))?,
+                    metadata: value_metadata.ok_or_else(|| crate::serde2::missing_field("PriorityClass", "metadata", &["metadata", "value"]))?,
+                    value: value_value.ok_or_else(|| crate::serde2::missing_field("PriorityClass", "value", &["metadata", "value"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(PriorityClass {
+                    description: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    global_default: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    metadata: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    value: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             <Self as crate::Resource>::KIND,
-            &[
-                "apiVersion",
-                "kind",
-                "description",
-                "globalDefault",
-                "metadata",
-                "value",
-            ],
+            PriorityClass::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, PriorityClass> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<PriorityClass as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_description = false;
+                let mut has_global_default = false;
+                let mut has_metadata = false;
+                let mut has_value = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PriorityClass", PriorityClass::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <PriorityClass as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<PriorityClass as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <PriorityClass as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<PriorityClass as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.description))?; has_description = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.global_default))?; has_global_default = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metadata))?; has_metadata = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.value))?; has_value = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_description {
+                    self.0.description = None;
+                }
+                if !has_global_default {
+                    self.0.global_default = None;
+                }
+                if !has_metadata {
+                    return Err(crate::serde2::missing_field("PriorityClass", "metadata", &["metadata", "value"]));
+                }
+                if !has_value {
+                    return Err(crate::serde2::missing_field("PriorityClass", "value", &["metadata", "value"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.description))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.global_default))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metadata))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.value))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            PriorityClass::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for PriorityClass {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct(<Self as crate::Resource>::KIND, 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "description", &self.description)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "globalDefault", &self.global_default)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", &self.metadata)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "value", &self.value)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             <Self as crate::Resource>::KIND,
             4 +
@@ -524,10 +638,117 @@ impl crate::serde::Serialize for PriorityClass {
     }
 }
 
+/// A builder of [`PriorityClass`]. Created with [`PriorityClass::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct PriorityClassBuilder {
+    description: Option<String>,
+    global_default: Option<bool>,
+    metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>,
+    value: Option<i32>,
+}
+
+#[cfg(feature = "builder")]
+impl PriorityClass {
+    /// Starts building a `PriorityClass`. Its required fields must be set before [`PriorityClassBuilder::build`] is called.
+    pub fn builder() -> PriorityClassBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl PriorityClassBuilder {
+    /// Sets [`PriorityClass::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets [`PriorityClass::global_default`].
+    pub fn global_default(mut self, value: impl Into<bool>) -> Self {
+        self.global_default = Some(value.into());
+        self
+    }
+
+    /// Sets [`PriorityClass::metadata`].
+    pub fn metadata(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        self.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`PriorityClass::value`].
+    pub fn value(mut self, value: impl Into<i32>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Builds the `PriorityClass`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<PriorityClass, crate::MissingFieldError> {
+        Ok(PriorityClass {
+            description: self.description,
+            global_default: self.global_default,
+            metadata: self.metadata.ok_or_else(|| crate::MissingFieldError::new("PriorityClass", "metadata"))?,
+            value: self.value.ok_or_else(|| crate::MissingFieldError::new("PriorityClass", "value"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`PriorityClass`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PriorityClassApplyConfiguration {
+    /// See [`PriorityClass::description`].
+    pub description: Option<String>,
+
+    /// See [`PriorityClass::global_default`].
+    pub global_default: Option<bool>,
+
+    /// See [`PriorityClass::metadata`].
+    pub metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMetaApplyConfiguration>,
+
+    /// See [`PriorityClass::value`].
+    pub value: Option<i32>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for PriorityClassApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            <PriorityClass as crate::Resource>::KIND,
+            2 +
+            self.description.as_ref().map_or(0, |_| 1) +
+            self.global_default.as_ref().map_or(0, |_| 1) +
+            self.metadata.as_ref().map_or(0, |_| 1) +
+            self.value.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <PriorityClass as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <PriorityClass as crate::Resource>::KIND)?;
+        if let Some(value) = &self.description {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "description", value)?;
+        }
+        if let Some(value) = &self.global_default {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "globalDefault", value)?;
+        }
+        if let Some(value) = &self.metadata {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", value)?;
+        }
+        if let Some(value) = &self.value {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "value", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PriorityClass {
     fn schema_name() -> String {
-        "io.k8s.api.scheduling.v1alpha1.PriorityClass".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::scheduling::v1alpha1::PriorityClass".to_owned()
+        }
+        else {
+            "io.k8s.api.scheduling.v1alpha1.PriorityClass".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -538,8 +759,9 @@ impl crate::schemars::JsonSchema for PriorityClass {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "apiVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -547,10 +769,11 @@ impl crate::schemars::JsonSchema for PriorityClass {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("scheduling.k8s.io/v1alpha1".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "description".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -560,8 +783,8 @@ impl crate::schemars::JsonSchema for PriorityClass {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "globalDefault".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -571,8 +794,8 @@ impl crate::schemars::JsonSchema for PriorityClass {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "kind".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -580,10 +803,11 @@ impl crate::schemars::JsonSchema for PriorityClass {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("PriorityClass".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "metadata".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>().into_object();
@@ -593,8 +817,8 @@ impl crate::schemars::JsonSchema for PriorityClass {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "value".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -605,9 +829,12 @@ impl crate::schemars::JsonSchema for PriorityClass {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
+                    "apiVersion",
+                    "kind",
                     "metadata",
                     "value",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -617,3 +844,15 @@ impl crate::schemars::JsonSchema for PriorityClass {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for PriorityClass {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(PriorityClass {
+            description: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            global_default: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            metadata: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            value: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
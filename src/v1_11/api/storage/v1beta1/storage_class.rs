@@ -3,7 +3,7 @@
 /// StorageClass describes the parameters for a class of storage for which PersistentVolumes can be dynamically provisioned.
 ///
 /// StorageClasses are non-namespaced; the name of the storage class according to etcd is in ObjectMeta.Name.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct StorageClass {
     /// AllowVolumeExpansion shows whether the storage class allow volume expand
     pub allow_volume_expansion: Option<bool>,
@@ -30,6 +30,80 @@ pub struct StorageClass {
     pub volume_binding_mode: Option<String>,
 }
 
+impl StorageClass {
+    /// Constructs a `StorageClass` from its required fields. Its optional fields are unset.
+    pub fn new(metadata: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>, provisioner: impl Into<String>) -> Self {
+        StorageClass {
+            allow_volume_expansion: None,
+            allowed_topologies: None,
+            metadata: metadata.into(),
+            mount_options: None,
+            parameters: None,
+            provisioner: provisioner.into(),
+            reclaim_policy: None,
+            volume_binding_mode: None,
+        }
+    }
+}
+
+impl StorageClass {
+    /// Returns this value with [`StorageClass::allow_volume_expansion`] set to the given value.
+    #[must_use]
+    pub fn with_allow_volume_expansion(mut self, value: impl Into<bool>) -> Self {
+        self.allow_volume_expansion = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`StorageClass::allowed_topologies`] set to the given value.
+    #[must_use]
+    pub fn with_allowed_topologies(mut self, value: impl Into<Vec<crate::api::core::v1::TopologySelectorTerm>>) -> Self {
+        self.allowed_topologies = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`StorageClass::metadata`] set to the given value.
+    #[must_use]
+    pub fn with_metadata(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        self.metadata = value.into();
+        self
+    }
+
+    /// Returns this value with [`StorageClass::mount_options`] set to the given value.
+    #[must_use]
+    pub fn with_mount_options(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.mount_options = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`StorageClass::parameters`] set to the given value.
+    #[must_use]
+    pub fn with_parameters(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`StorageClass::provisioner`] set to the given value.
+    #[must_use]
+    pub fn with_provisioner(mut self, value: impl Into<String>) -> Self {
+        self.provisioner = value.into();
+        self
+    }
+
+    /// Returns this value with [`StorageClass::reclaim_policy`] set to the given value.
+    #[must_use]
+    pub fn with_reclaim_policy(mut self, value: impl Into<String>) -> Self {
+        self.reclaim_policy = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`StorageClass::volume_binding_mode`] set to the given value.
+    #[must_use]
+    pub fn with_volume_binding_mode(mut self, value: impl Into<String>) -> Self {
+        self.volume_binding_mode = Some(value.into());
+        self
+    }
+}
+
 // Begin storage.k8s.io/v1beta1/StorageClass
 
 // Generated from operation createStorageV1beta1StorageClass
@@ -123,6 +197,7 @@ impl StorageClass {
         name: &str,
         optional: crate::DeleteOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::DeleteResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
         let __url = format!("/apis/storage.k8s.io/v1beta1/storageclasses/{name}",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -193,6 +268,7 @@ impl StorageClass {
         body: &crate::apimachinery::pkg::apis::meta::v1::Patch,
         optional: crate::PatchOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::PatchResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
         let __url = format!("/apis/storage.k8s.io/v1beta1/storageclasses/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -240,6 +316,7 @@ impl StorageClass {
             export,
             pretty,
         } = optional;
+        crate::validate_path_parameter("name", name)?;
         let __url = format!("/apis/storage.k8s.io/v1beta1/storageclasses/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -338,6 +415,7 @@ impl StorageClass {
         body: &crate::api::storage::v1beta1::StorageClass,
         optional: crate::ReplaceOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::ReplaceResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
         let __url = format!("/apis/storage.k8s.io/v1beta1/storageclasses/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -395,6 +473,9 @@ impl crate::Resource for StorageClass {
     const KIND: &'static str = "StorageClass";
     const VERSION: &'static str = "v1beta1";
     const URL_PATH_SEGMENT: &'static str = "storageclasses";
+    const VERBS: &'static [&'static str] = &["create", "delete", "deletecollection", "get", "list", "patch", "update", "watch"];
+    const SINGULAR: &'static str = "storageclass";
+    const SHORT_NAMES: &'static [&'static str] = &["sc"];
     type Scope = crate::ClusterResourceScope;
 }
 
@@ -414,55 +495,35 @@ impl crate::Metadata for StorageClass {
     }
 }
 
-impl<'de> crate::serde::Deserialize<'de> for StorageClass {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_api_version,
-            Key_kind,
-            Key_allow_volume_expansion,
-            Key_allowed_topologies,
-            Key_metadata,
-            Key_mount_options,
-            Key_parameters,
-            Key_provisioner,
-            Key_reclaim_policy,
-            Key_volume_binding_mode,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl std::fmt::Display for StorageClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(<Self as crate::Resource>::URL_PATH_SEGMENT)?;
+        for part in [&self.metadata.namespace, &self.metadata.name].iter().copied().flatten() {
+            write!(f, "/{part}")?;
+        }
+        Ok(())
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "apiVersion" => Field::Key_api_version,
-                            "kind" => Field::Key_kind,
-                            "allowVolumeExpansion" => Field::Key_allow_volume_expansion,
-                            "allowedTopologies" => Field::Key_allowed_topologies,
-                            "metadata" => Field::Key_metadata,
-                            "mountOptions" => Field::Key_mount_options,
-                            "parameters" => Field::Key_parameters,
-                            "provisioner" => Field::Key_provisioner,
-                            "reclaimPolicy" => Field::Key_reclaim_policy,
-                            "volumeBindingMode" => Field::Key_volume_binding_mode,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for StorageClass {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl StorageClass {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "kind",
+        "allowVolumeExpansion",
+        "allowedTopologies",
+        "metadata",
+        "mountOptions",
+        "parameters",
+        "provisioner",
+        "reclaimPolicy",
+        "volumeBindingMode",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for StorageClass {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -482,66 +543,175 @@ impl<'de> crate::serde::Deserialize<'de> for StorageClass {
                 let mut value_reclaim_policy: Option<String> = None;
                 let mut value_volume_binding_mode: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("StorageClass", StorageClass::FIELDS))? {
                     match key {
-                        Field::Key_api_version => {
+                        Some(0) => {
                             let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_api_version != <Self::Value as crate::Resource>::API_VERSION {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<Self::Value as crate::Resource>::API_VERSION));
                             }
                         },
-                        Field::Key_kind => {
+                        Some(1) => {
                             let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_kind != <Self::Value as crate::Resource>::KIND {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<Self::Value as crate::Resource>::KIND));
                             }
                         },
-                        Field::Key_allow_volume_expansion => value_allow_volume_expansion = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_allowed_topologies => value_allowed_topologies = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_metadata => value_metadata = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_mount_options => value_mount_options = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_parameters => value_parameters = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_provisioner => value_provisioner = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_reclaim_policy => value_reclaim_policy = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_volume_binding_mode => value_volume_binding_mode = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(2) => value_allow_volume_expansion = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_allowed_topologies = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_metadata = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(5) => value_mount_options = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_parameters = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(7) => value_provisioner = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(8) => value_reclaim_policy = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(9) => value_volume_binding_mode = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(StorageClass {
                     allow_volume_expansion: value_allow_volume_expansion,
                     allowed_topologies: value_allowed_topologies,
-                    metadata: value_metadata.ok_or_else(|| crate::serde::de::Error::missing_field("metadata"))?,
+                    metadata: value_metadata.ok_or_else(|| crate::serde2::missing_field("StorageClass", "metadata", &["metadata", "provisioner"]))?,
                     mount_options: value_mount_options,
                     parameters: value_parameters,
-                    provisioner: value_provisioner.ok_or_else(|| crate::serde::de::Error::missing_field("provisioner"))?,
+                    provisioner: value_provisioner.ok_or_else(|| crate::serde2::missing_field("StorageClass", "provisioner", &["metadata", "provisioner"]))?,
                     reclaim_policy: value_reclaim_policy,
                     volume_binding_mode: value_volume_binding_mode,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(StorageClass {
+                    allow_volume_expansion: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    allowed_topologies: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    metadata: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    mount_options: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    parameters: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    provisioner: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    reclaim_policy: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    volume_binding_mode: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             <Self as crate::Resource>::KIND,
-            &[
-                "apiVersion",
-                "kind",
-                "allowVolumeExpansion",
-                "allowedTopologies",
-                "metadata",
-                "mountOptions",
-                "parameters",
-                "provisioner",
-                "reclaimPolicy",
-                "volumeBindingMode",
-            ],
+            StorageClass::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, StorageClass> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<StorageClass as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_allow_volume_expansion = false;
+                let mut has_allowed_topologies = false;
+                let mut has_metadata = false;
+                let mut has_mount_options = false;
+                let mut has_parameters = false;
+                let mut has_provisioner = false;
+                let mut has_reclaim_policy = false;
+                let mut has_volume_binding_mode = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("StorageClass", StorageClass::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <StorageClass as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<StorageClass as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <StorageClass as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<StorageClass as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.allow_volume_expansion))?; has_allow_volume_expansion = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.allowed_topologies))?; has_allowed_topologies = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metadata))?; has_metadata = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.mount_options))?; has_mount_options = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.parameters))?; has_parameters = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.provisioner))?; has_provisioner = true; },
+                        Some(8) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.reclaim_policy))?; has_reclaim_policy = true; },
+                        Some(9) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.volume_binding_mode))?; has_volume_binding_mode = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_allow_volume_expansion {
+                    self.0.allow_volume_expansion = None;
+                }
+                if !has_allowed_topologies {
+                    self.0.allowed_topologies = None;
+                }
+                if !has_metadata {
+                    return Err(crate::serde2::missing_field("StorageClass", "metadata", &["metadata", "provisioner"]));
+                }
+                if !has_mount_options {
+                    self.0.mount_options = None;
+                }
+                if !has_parameters {
+                    self.0.parameters = None;
+                }
+                if !has_provisioner {
+                    return Err(crate::serde2::missing_field("StorageClass", "provisioner", &["metadata", "provisioner"]));
+                }
+                if !has_reclaim_policy {
+                    self.0.reclaim_policy = None;
+                }
+                if !has_volume_binding_mode {
+                    self.0.volume_binding_mode = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.allow_volume_expansion))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.allowed_topologies))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metadata))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.mount_options))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.parameters))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.provisioner))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.reclaim_policy))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.volume_binding_mode))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            StorageClass::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for StorageClass {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct(<Self as crate::Resource>::KIND, 8)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "allowVolumeExpansion", &self.allow_volume_expansion)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "allowedTopologies", &self.allowed_topologies)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", &self.metadata)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "mountOptions", &self.mount_options)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "parameters", &self.parameters)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "provisioner", &self.provisioner)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reclaimPolicy", &self.reclaim_policy)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeBindingMode", &self.volume_binding_mode)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             <Self as crate::Resource>::KIND,
             4 +
@@ -578,10 +748,177 @@ impl crate::serde::Serialize for StorageClass {
     }
 }
 
+/// A builder of [`StorageClass`]. Created with [`StorageClass::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct StorageClassBuilder {
+    allow_volume_expansion: Option<bool>,
+    allowed_topologies: Option<Vec<crate::api::core::v1::TopologySelectorTerm>>,
+    metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>,
+    mount_options: Option<Vec<String>>,
+    parameters: Option<std::collections::BTreeMap<String, String>>,
+    provisioner: Option<String>,
+    reclaim_policy: Option<String>,
+    volume_binding_mode: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl StorageClass {
+    /// Starts building a `StorageClass`. Its required fields must be set before [`StorageClassBuilder::build`] is called.
+    pub fn builder() -> StorageClassBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl StorageClassBuilder {
+    /// Sets [`StorageClass::allow_volume_expansion`].
+    pub fn allow_volume_expansion(mut self, value: impl Into<bool>) -> Self {
+        self.allow_volume_expansion = Some(value.into());
+        self
+    }
+
+    /// Sets [`StorageClass::allowed_topologies`].
+    pub fn allowed_topologies(mut self, value: impl Into<Vec<crate::api::core::v1::TopologySelectorTerm>>) -> Self {
+        self.allowed_topologies = Some(value.into());
+        self
+    }
+
+    /// Sets [`StorageClass::metadata`].
+    pub fn metadata(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        self.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`StorageClass::mount_options`].
+    pub fn mount_options(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.mount_options = Some(value.into());
+        self
+    }
+
+    /// Sets [`StorageClass::parameters`].
+    pub fn parameters(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Sets [`StorageClass::provisioner`].
+    pub fn provisioner(mut self, value: impl Into<String>) -> Self {
+        self.provisioner = Some(value.into());
+        self
+    }
+
+    /// Sets [`StorageClass::reclaim_policy`].
+    pub fn reclaim_policy(mut self, value: impl Into<String>) -> Self {
+        self.reclaim_policy = Some(value.into());
+        self
+    }
+
+    /// Sets [`StorageClass::volume_binding_mode`].
+    pub fn volume_binding_mode(mut self, value: impl Into<String>) -> Self {
+        self.volume_binding_mode = Some(value.into());
+        self
+    }
+
+    /// Builds the `StorageClass`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<StorageClass, crate::MissingFieldError> {
+        Ok(StorageClass {
+            allow_volume_expansion: self.allow_volume_expansion,
+            allowed_topologies: self.allowed_topologies,
+            metadata: self.metadata.ok_or_else(|| crate::MissingFieldError::new("StorageClass", "metadata"))?,
+            mount_options: self.mount_options,
+            parameters: self.parameters,
+            provisioner: self.provisioner.ok_or_else(|| crate::MissingFieldError::new("StorageClass", "provisioner"))?,
+            reclaim_policy: self.reclaim_policy,
+            volume_binding_mode: self.volume_binding_mode,
+        })
+    }
+}
+
+/// The apply configuration of [`StorageClass`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StorageClassApplyConfiguration {
+    /// See [`StorageClass::allow_volume_expansion`].
+    pub allow_volume_expansion: Option<bool>,
+
+    /// See [`StorageClass::allowed_topologies`].
+    pub allowed_topologies: Option<Vec<crate::api::core::v1::TopologySelectorTermApplyConfiguration>>,
+
+    /// See [`StorageClass::metadata`].
+    pub metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMetaApplyConfiguration>,
+
+    /// See [`StorageClass::mount_options`].
+    pub mount_options: Option<Vec<String>>,
+
+    /// See [`StorageClass::parameters`].
+    pub parameters: Option<std::collections::BTreeMap<String, String>>,
+
+    /// See [`StorageClass::provisioner`].
+    pub provisioner: Option<String>,
+
+    /// See [`StorageClass::reclaim_policy`].
+    pub reclaim_policy: Option<String>,
+
+    /// See [`StorageClass::volume_binding_mode`].
+    pub volume_binding_mode: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for StorageClassApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            <StorageClass as crate::Resource>::KIND,
+            2 +
+            self.allow_volume_expansion.as_ref().map_or(0, |_| 1) +
+            self.allowed_topologies.as_ref().map_or(0, |_| 1) +
+            self.metadata.as_ref().map_or(0, |_| 1) +
+            self.mount_options.as_ref().map_or(0, |_| 1) +
+            self.parameters.as_ref().map_or(0, |_| 1) +
+            self.provisioner.as_ref().map_or(0, |_| 1) +
+            self.reclaim_policy.as_ref().map_or(0, |_| 1) +
+            self.volume_binding_mode.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <StorageClass as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <StorageClass as crate::Resource>::KIND)?;
+        if let Some(value) = &self.allow_volume_expansion {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "allowVolumeExpansion", value)?;
+        }
+        if let Some(value) = &self.allowed_topologies {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "allowedTopologies", value)?;
+        }
+        if let Some(value) = &self.metadata {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", value)?;
+        }
+        if let Some(value) = &self.mount_options {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "mountOptions", value)?;
+        }
+        if let Some(value) = &self.parameters {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "parameters", value)?;
+        }
+        if let Some(value) = &self.provisioner {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "provisioner", value)?;
+        }
+        if let Some(value) = &self.reclaim_policy {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reclaimPolicy", value)?;
+        }
+        if let Some(value) = &self.volume_binding_mode {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeBindingMode", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for StorageClass {
     fn schema_name() -> String {
-        "io.k8s.api.storage.v1beta1.StorageClass".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::storage::v1beta1::StorageClass".to_owned()
+        }
+        else {
+            "io.k8s.api.storage.v1beta1.StorageClass".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -592,8 +929,9 @@ impl crate::schemars::JsonSchema for StorageClass {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "allowVolumeExpansion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -603,8 +941,8 @@ impl crate::schemars::JsonSchema for StorageClass {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "allowedTopologies".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -618,8 +956,8 @@ impl crate::schemars::JsonSchema for StorageClass {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "apiVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -627,10 +965,11 @@ impl crate::schemars::JsonSchema for StorageClass {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("storage.k8s.io/v1beta1".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "kind".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -638,10 +977,11 @@ impl crate::schemars::JsonSchema for StorageClass {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("StorageClass".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "metadata".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>().into_object();
@@ -651,8 +991,8 @@ impl crate::schemars::JsonSchema for StorageClass {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "mountOptions".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -671,8 +1011,8 @@ impl crate::schemars::JsonSchema for StorageClass {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "parameters".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -691,8 +1031,8 @@ impl crate::schemars::JsonSchema for StorageClass {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "provisioner".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -702,8 +1042,8 @@ impl crate::schemars::JsonSchema for StorageClass {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "reclaimPolicy".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -713,8 +1053,8 @@ impl crate::schemars::JsonSchema for StorageClass {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "volumeBindingMode".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -724,9 +1064,12 @@ impl crate::schemars::JsonSchema for StorageClass {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
+                    "apiVersion",
+                    "kind",
                     "metadata",
                     "provisioner",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -736,3 +1079,19 @@ impl crate::schemars::JsonSchema for StorageClass {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for StorageClass {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(StorageClass {
+            allow_volume_expansion: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            allowed_topologies: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            metadata: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            mount_options: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            parameters: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            provisioner: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            reclaim_policy: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            volume_binding_mode: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,6 +1,6 @@
 // Generated from definition io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.CustomResourceDefinitionVersion
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct CustomResourceDefinitionVersion {
     /// Name is the version name, e.g. “v1”, “v2beta1”, etc.
     pub name: String,
@@ -12,41 +12,52 @@ pub struct CustomResourceDefinitionVersion {
     pub storage: bool,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for CustomResourceDefinitionVersion {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_name,
-            Key_served,
-            Key_storage,
-            Other,
+impl CustomResourceDefinitionVersion {
+    /// Constructs a `CustomResourceDefinitionVersion` from its required fields. Its optional fields are unset.
+    pub fn new(name: impl Into<String>, served: impl Into<bool>, storage: impl Into<bool>) -> Self {
+        CustomResourceDefinitionVersion {
+            name: name.into(),
+            served: served.into(),
+            storage: storage.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl CustomResourceDefinitionVersion {
+    /// Returns this value with [`CustomResourceDefinitionVersion::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`CustomResourceDefinitionVersion::served`] set to the given value.
+    #[must_use]
+    pub fn with_served(mut self, value: impl Into<bool>) -> Self {
+        self.served = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`CustomResourceDefinitionVersion::storage`] set to the given value.
+    #[must_use]
+    pub fn with_storage(mut self, value: impl Into<bool>) -> Self {
+        self.storage = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "name" => Field::Key_name,
-                            "served" => Field::Key_served,
-                            "storage" => Field::Key_storage,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for CustomResourceDefinitionVersion {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl CustomResourceDefinitionVersion {
+    const FIELDS: &'static [&'static str] = &[
+        "name",
+        "served",
+        "storage",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for CustomResourceDefinitionVersion {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -61,37 +72,101 @@ impl<'de> crate::serde::Deserialize<'de> for CustomResourceDefinitionVersion {
                 let mut value_served: Option<bool> = None;
                 let mut value_storage: Option<bool> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CustomResourceDefinitionVersion", CustomResourceDefinitionVersion::FIELDS))? {
                     match key {
-                        Field::Key_name => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_served => value_served = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_storage => value_storage = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_served = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_storage = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(CustomResourceDefinitionVersion {
-                    name: value_name.ok_or_else(|| crate::serde::de::Error::missing_field("name"))?,
-                    served: value_served.ok_or_else(|| crate::serde::de::Error::missing_field("served"))?,
-                    storage: value_storage.ok_or_else(|| crate::serde::de::Error::missing_field("storage"))?,
+                    name: value_name.ok_or_else(|| crate::serde2::missing_field("CustomResourceDefinitionVersion", "name", &["name", "served", "storage"]))?,
+                    served: value_served.ok_or_else(|| crate::serde2::missing_field("CustomResourceDefinitionVersion", "served", &["name", "served", "storage"]))?,
+                    storage: value_storage.ok_or_else(|| crate::serde2::missing_field("CustomResourceDefinitionVersion", "storage", &["name", "served", "storage"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(CustomResourceDefinitionVersion {
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    served: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    storage: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "CustomResourceDefinitionVersion",
-            &[
-                "name",
-                "served",
-                "storage",
-            ],
+            CustomResourceDefinitionVersion::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, CustomResourceDefinitionVersion> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("CustomResourceDefinitionVersion")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_name = false;
+                let mut has_served = false;
+                let mut has_storage = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CustomResourceDefinitionVersion", CustomResourceDefinitionVersion::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.name))?; has_name = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.served))?; has_served = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.storage))?; has_storage = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_name {
+                    return Err(crate::serde2::missing_field("CustomResourceDefinitionVersion", "name", &["name", "served", "storage"]));
+                }
+                if !has_served {
+                    return Err(crate::serde2::missing_field("CustomResourceDefinitionVersion", "served", &["name", "served", "storage"]));
+                }
+                if !has_storage {
+                    return Err(crate::serde2::missing_field("CustomResourceDefinitionVersion", "storage", &["name", "served", "storage"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.served))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.storage))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "CustomResourceDefinitionVersion",
+            CustomResourceDefinitionVersion::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for CustomResourceDefinitionVersion {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("CustomResourceDefinitionVersion", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "served", &self.served)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "storage", &self.storage)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "CustomResourceDefinitionVersion",
             3,
@@ -103,18 +178,108 @@ impl crate::serde::Serialize for CustomResourceDefinitionVersion {
     }
 }
 
+/// A builder of [`CustomResourceDefinitionVersion`]. Created with [`CustomResourceDefinitionVersion::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct CustomResourceDefinitionVersionBuilder {
+    name: Option<String>,
+    served: Option<bool>,
+    storage: Option<bool>,
+}
+
+#[cfg(feature = "builder")]
+impl CustomResourceDefinitionVersion {
+    /// Starts building a `CustomResourceDefinitionVersion`. Its required fields must be set before [`CustomResourceDefinitionVersionBuilder::build`] is called.
+    pub fn builder() -> CustomResourceDefinitionVersionBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl CustomResourceDefinitionVersionBuilder {
+    /// Sets [`CustomResourceDefinitionVersion::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceDefinitionVersion::served`].
+    pub fn served(mut self, value: impl Into<bool>) -> Self {
+        self.served = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceDefinitionVersion::storage`].
+    pub fn storage(mut self, value: impl Into<bool>) -> Self {
+        self.storage = Some(value.into());
+        self
+    }
+
+    /// Builds the `CustomResourceDefinitionVersion`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<CustomResourceDefinitionVersion, crate::MissingFieldError> {
+        Ok(CustomResourceDefinitionVersion {
+            name: self.name.ok_or_else(|| crate::MissingFieldError::new("CustomResourceDefinitionVersion", "name"))?,
+            served: self.served.ok_or_else(|| crate::MissingFieldError::new("CustomResourceDefinitionVersion", "served"))?,
+            storage: self.storage.ok_or_else(|| crate::MissingFieldError::new("CustomResourceDefinitionVersion", "storage"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`CustomResourceDefinitionVersion`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CustomResourceDefinitionVersionApplyConfiguration {
+    /// See [`CustomResourceDefinitionVersion::name`].
+    pub name: Option<String>,
+
+    /// See [`CustomResourceDefinitionVersion::served`].
+    pub served: Option<bool>,
+
+    /// See [`CustomResourceDefinitionVersion::storage`].
+    pub storage: Option<bool>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for CustomResourceDefinitionVersionApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "CustomResourceDefinitionVersionApplyConfiguration",
+            self.name.as_ref().map_or(0, |_| 1) +
+            self.served.as_ref().map_or(0, |_| 1) +
+            self.storage.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        if let Some(value) = &self.served {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "served", value)?;
+        }
+        if let Some(value) = &self.storage {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "storage", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CustomResourceDefinitionVersion {
     fn schema_name() -> String {
-        "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.CustomResourceDefinitionVersion".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionVersion".to_owned()
+        }
+        else {
+            "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.CustomResourceDefinitionVersion".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -124,8 +289,8 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionVersion {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "served".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -135,8 +300,8 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionVersion {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "storage".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -146,8 +311,9 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionVersion {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "name",
                     "served",
@@ -159,3 +325,14 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionVersion {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for CustomResourceDefinitionVersion {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(CustomResourceDefinitionVersion {
+            name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            served: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            storage: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -7,74 +7,112 @@ pub struct CustomResourceValidation {
     pub open_api_v3_schema: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps>,
 }
 
+impl CustomResourceValidation {
+    /// Returns this value with [`CustomResourceValidation::open_api_v3_schema`] set to the given value.
+    #[must_use]
+    pub fn with_open_api_v3_schema(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps>) -> Self {
+        self.open_api_v3_schema = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for CustomResourceValidation {}
+
+impl CustomResourceValidation {
+    const FIELDS: &'static [&'static str] = &[
+        "openAPIV3Schema",
+    ];
+}
+
 impl<'de> crate::serde::Deserialize<'de> for CustomResourceValidation {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_open_api_v3_schema,
-            Other,
-        }
+        struct Visitor;
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = CustomResourceValidation;
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("CustomResourceValidation")
+            }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_open_api_v3_schema: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps> = None;
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "openAPIV3Schema" => Field::Key_open_api_v3_schema,
-                            _ => Field::Other,
-                        })
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CustomResourceValidation", CustomResourceValidation::FIELDS))? {
+                    match key {
+                        Some(0) => value_open_api_v3_schema = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
-                deserializer.deserialize_identifier(Visitor)
+                Ok(CustomResourceValidation {
+                    open_api_v3_schema: value_open_api_v3_schema,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(CustomResourceValidation {
+                    open_api_v3_schema: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                })
             }
         }
 
-        struct Visitor;
+        deserializer.deserialize_struct(
+            "CustomResourceValidation",
+            CustomResourceValidation::FIELDS,
+            Visitor,
+        )
+    }
 
-        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-            type Value = CustomResourceValidation;
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, CustomResourceValidation> {
+            type Value = ();
 
             fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 f.write_str("CustomResourceValidation")
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
-                let mut value_open_api_v3_schema: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps> = None;
+                let mut has_open_api_v3_schema = false;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CustomResourceValidation", CustomResourceValidation::FIELDS))? {
                     match key {
-                        Field::Key_open_api_v3_schema => value_open_api_v3_schema = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.open_api_v3_schema))?; has_open_api_v3_schema = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
+                if !has_open_api_v3_schema {
+                    self.0.open_api_v3_schema = None;
+                }
 
-                Ok(CustomResourceValidation {
-                    open_api_v3_schema: value_open_api_v3_schema,
-                })
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.open_api_v3_schema))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
             }
         }
 
         deserializer.deserialize_struct(
             "CustomResourceValidation",
-            &[
-                "openAPIV3Schema",
-            ],
-            Visitor,
+            CustomResourceValidation::FIELDS,
+            InPlaceVisitor(place),
         )
     }
 }
 
 impl crate::serde::Serialize for CustomResourceValidation {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("CustomResourceValidation", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "openAPIV3Schema", &self.open_api_v3_schema)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "CustomResourceValidation",
             self.open_api_v3_schema.as_ref().map_or(0, |_| 1),
@@ -86,10 +124,69 @@ impl crate::serde::Serialize for CustomResourceValidation {
     }
 }
 
+/// A builder of [`CustomResourceValidation`]. Created with [`CustomResourceValidation::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct CustomResourceValidationBuilder {
+    open_api_v3_schema: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps>,
+}
+
+#[cfg(feature = "builder")]
+impl CustomResourceValidation {
+    /// Starts building a `CustomResourceValidation`. Its required fields must be set before [`CustomResourceValidationBuilder::build`] is called.
+    pub fn builder() -> CustomResourceValidationBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl CustomResourceValidationBuilder {
+    /// Sets [`CustomResourceValidation::open_api_v3_schema`].
+    pub fn open_api_v3_schema(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps>) -> Self {
+        self.open_api_v3_schema = Some(value.into());
+        self
+    }
+
+    /// Builds the `CustomResourceValidation`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<CustomResourceValidation, crate::MissingFieldError> {
+        Ok(CustomResourceValidation {
+            open_api_v3_schema: self.open_api_v3_schema,
+        })
+    }
+}
+
+/// The apply configuration of [`CustomResourceValidation`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CustomResourceValidationApplyConfiguration {
+    /// See [`CustomResourceValidation::open_api_v3_schema`].
+    pub open_api_v3_schema: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for CustomResourceValidationApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "CustomResourceValidationApplyConfiguration",
+            self.open_api_v3_schema.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.open_api_v3_schema {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "openAPIV3Schema", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CustomResourceValidation {
     fn schema_name() -> String {
-        "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.CustomResourceValidation".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceValidation".to_owned()
+        }
+        else {
+            "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.CustomResourceValidation".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -100,8 +197,9 @@ impl crate::schemars::JsonSchema for CustomResourceValidation {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "openAPIV3Schema".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps>().into_object();
@@ -111,11 +209,21 @@ impl crate::schemars::JsonSchema for CustomResourceValidation {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for CustomResourceValidation {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(CustomResourceValidation {
+            open_api_v3_schema: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
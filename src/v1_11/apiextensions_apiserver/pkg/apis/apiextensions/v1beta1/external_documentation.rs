@@ -1,46 +1,40 @@
 // Generated from definition io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.ExternalDocumentation
 
 /// ExternalDocumentation allows referencing an external resource for extended documentation.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ExternalDocumentation {
     pub description: Option<String>,
 
     pub url: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ExternalDocumentation {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_description,
-            Key_url,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl ExternalDocumentation {
+    /// Returns this value with [`ExternalDocumentation::description`] set to the given value.
+    #[must_use]
+    pub fn with_description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ExternalDocumentation::url`] set to the given value.
+    #[must_use]
+    pub fn with_url(mut self, value: impl Into<String>) -> Self {
+        self.url = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "description" => Field::Key_description,
-                            "url" => Field::Key_url,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for ExternalDocumentation {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl ExternalDocumentation {
+    const FIELDS: &'static [&'static str] = &[
+        "description",
+        "url",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ExternalDocumentation {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -54,11 +48,11 @@ impl<'de> crate::serde::Deserialize<'de> for ExternalDocumentation {
                 let mut value_description: Option<String> = None;
                 let mut value_url: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ExternalDocumentation", ExternalDocumentation::FIELDS))? {
                     match key {
-                        Field::Key_description => value_description = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_url => value_url = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_description = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_url = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -67,21 +61,78 @@ impl<'de> crate::serde::Deserialize<'de> for ExternalDocumentation {
                     url: value_url,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ExternalDocumentation {
+                    description: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    url: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "ExternalDocumentation",
-            &[
-                "description",
-                "url",
-            ],
+            ExternalDocumentation::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ExternalDocumentation> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ExternalDocumentation")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_description = false;
+                let mut has_url = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ExternalDocumentation", ExternalDocumentation::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.description))?; has_description = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.url))?; has_url = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_description {
+                    self.0.description = None;
+                }
+                if !has_url {
+                    self.0.url = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.description))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.url))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ExternalDocumentation",
+            ExternalDocumentation::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ExternalDocumentation {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ExternalDocumentation", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "description", &self.description)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "url", &self.url)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ExternalDocumentation",
             self.description.as_ref().map_or(0, |_| 1) +
@@ -97,10 +148,84 @@ impl crate::serde::Serialize for ExternalDocumentation {
     }
 }
 
+/// A builder of [`ExternalDocumentation`]. Created with [`ExternalDocumentation::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ExternalDocumentationBuilder {
+    description: Option<String>,
+    url: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl ExternalDocumentation {
+    /// Starts building a `ExternalDocumentation`. Its required fields must be set before [`ExternalDocumentationBuilder::build`] is called.
+    pub fn builder() -> ExternalDocumentationBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ExternalDocumentationBuilder {
+    /// Sets [`ExternalDocumentation::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets [`ExternalDocumentation::url`].
+    pub fn url(mut self, value: impl Into<String>) -> Self {
+        self.url = Some(value.into());
+        self
+    }
+
+    /// Builds the `ExternalDocumentation`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ExternalDocumentation, crate::MissingFieldError> {
+        Ok(ExternalDocumentation {
+            description: self.description,
+            url: self.url,
+        })
+    }
+}
+
+/// The apply configuration of [`ExternalDocumentation`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ExternalDocumentationApplyConfiguration {
+    /// See [`ExternalDocumentation::description`].
+    pub description: Option<String>,
+
+    /// See [`ExternalDocumentation::url`].
+    pub url: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ExternalDocumentationApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ExternalDocumentationApplyConfiguration",
+            self.description.as_ref().map_or(0, |_| 1) +
+            self.url.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.description {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "description", value)?;
+        }
+        if let Some(value) = &self.url {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "url", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ExternalDocumentation {
     fn schema_name() -> String {
-        "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.ExternalDocumentation".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::ExternalDocumentation".to_owned()
+        }
+        else {
+            "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.ExternalDocumentation".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -111,25 +236,37 @@ impl crate::schemars::JsonSchema for ExternalDocumentation {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "description".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "url".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ExternalDocumentation {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ExternalDocumentation {
+            description: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            url: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
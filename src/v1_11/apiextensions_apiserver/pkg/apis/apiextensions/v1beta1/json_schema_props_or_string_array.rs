@@ -25,14 +25,36 @@ impl<'de> crate::serde::Deserialize<'de> for JSONSchemaPropsOrStringArray {
             fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
                 Ok(JSONSchemaPropsOrStringArray::Strings(crate::serde::de::Deserialize::deserialize(crate::serde::de::value::SeqAccessDeserializer::new(seq))?))
             }
+
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::EnumAccess<'de> {
+                let (variant, value) = crate::serde::de::EnumAccess::variant::<u32>(data)?;
+                match variant {
+                    0 => Ok(JSONSchemaPropsOrStringArray::Schema(crate::serde::de::VariantAccess::newtype_variant(value)?)),
+                    1 => Ok(JSONSchemaPropsOrStringArray::Strings(crate::serde::de::VariantAccess::newtype_variant(value)?)),
+                    _ => Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Unsigned(variant.into()), &"variant index 0 <= i < 2")),
+                }
+            }
         }
 
-        deserializer.deserialize_any(Visitor)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(Visitor)
+        }
+        else {
+            // Formats that are not self-describing cannot deserialize untagged values, so the variant is serialized explicitly.
+            deserializer.deserialize_enum("JSONSchemaPropsOrStringArray", &["Schema", "Strings"], Visitor)
+        }
     }
 }
 
 impl crate::serde::Serialize for JSONSchemaPropsOrStringArray {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            return match self {
+                JSONSchemaPropsOrStringArray::Schema(value) => serializer.serialize_newtype_variant("JSONSchemaPropsOrStringArray", 0, "Schema", value),
+                JSONSchemaPropsOrStringArray::Strings(value) => serializer.serialize_newtype_variant("JSONSchemaPropsOrStringArray", 1, "Strings", value),
+            };
+        }
+
         match self {
             JSONSchemaPropsOrStringArray::Schema(value) => value.serialize(serializer),
             JSONSchemaPropsOrStringArray::Strings(value) => value.serialize(serializer),
@@ -43,7 +65,12 @@ impl crate::serde::Serialize for JSONSchemaPropsOrStringArray {
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for JSONSchemaPropsOrStringArray {
     fn schema_name() -> String {
-        "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.JSONSchemaPropsOrStringArray".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsOrStringArray".to_owned()
+        }
+        else {
+            "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.JSONSchemaPropsOrStringArray".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -75,3 +102,10 @@ impl crate::schemars::JsonSchema for JSONSchemaPropsOrStringArray {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for JSONSchemaPropsOrStringArray {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        Ok(match crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)? { Some(schema) => JSONSchemaPropsOrStringArray::Schema(schema), None => JSONSchemaPropsOrStringArray::Strings(crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)?) })
+    }
+}
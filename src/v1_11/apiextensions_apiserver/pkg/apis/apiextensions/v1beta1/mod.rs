@@ -1,47 +1,95 @@
 
 mod custom_resource_column_definition;
 pub use self::custom_resource_column_definition::CustomResourceColumnDefinition;
+#[cfg(feature = "builder")]
+pub use self::custom_resource_column_definition::CustomResourceColumnDefinitionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::custom_resource_column_definition::CustomResourceColumnDefinitionApplyConfiguration;
 
 mod custom_resource_definition;
 pub use self::custom_resource_definition::CustomResourceDefinition;
+#[cfg(feature = "builder")]
+pub use self::custom_resource_definition::CustomResourceDefinitionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::custom_resource_definition::CustomResourceDefinitionApplyConfiguration;
 #[cfg(feature = "api")] pub use self::custom_resource_definition::{ReadCustomResourceDefinitionOptional, ReadCustomResourceDefinitionResponse};
 #[cfg(feature = "api")] pub use self::custom_resource_definition::{ReadCustomResourceDefinitionStatusOptional, ReadCustomResourceDefinitionStatusResponse};
 
 mod custom_resource_definition_condition;
 pub use self::custom_resource_definition_condition::CustomResourceDefinitionCondition;
+#[cfg(feature = "builder")]
+pub use self::custom_resource_definition_condition::CustomResourceDefinitionConditionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::custom_resource_definition_condition::CustomResourceDefinitionConditionApplyConfiguration;
 
 mod custom_resource_definition_names;
 pub use self::custom_resource_definition_names::CustomResourceDefinitionNames;
+#[cfg(feature = "builder")]
+pub use self::custom_resource_definition_names::CustomResourceDefinitionNamesBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::custom_resource_definition_names::CustomResourceDefinitionNamesApplyConfiguration;
 
 mod custom_resource_definition_spec;
 pub use self::custom_resource_definition_spec::CustomResourceDefinitionSpec;
+#[cfg(feature = "builder")]
+pub use self::custom_resource_definition_spec::CustomResourceDefinitionSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::custom_resource_definition_spec::CustomResourceDefinitionSpecApplyConfiguration;
 
 mod custom_resource_definition_status;
 pub use self::custom_resource_definition_status::CustomResourceDefinitionStatus;
+#[cfg(feature = "builder")]
+pub use self::custom_resource_definition_status::CustomResourceDefinitionStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::custom_resource_definition_status::CustomResourceDefinitionStatusApplyConfiguration;
 
 mod custom_resource_definition_version;
 pub use self::custom_resource_definition_version::CustomResourceDefinitionVersion;
+#[cfg(feature = "builder")]
+pub use self::custom_resource_definition_version::CustomResourceDefinitionVersionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::custom_resource_definition_version::CustomResourceDefinitionVersionApplyConfiguration;
 
 mod custom_resource_subresource_scale;
 pub use self::custom_resource_subresource_scale::CustomResourceSubresourceScale;
+#[cfg(feature = "builder")]
+pub use self::custom_resource_subresource_scale::CustomResourceSubresourceScaleBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::custom_resource_subresource_scale::CustomResourceSubresourceScaleApplyConfiguration;
 
 mod custom_resource_subresource_status;
 pub use self::custom_resource_subresource_status::CustomResourceSubresourceStatus;
 
 mod custom_resource_subresources;
 pub use self::custom_resource_subresources::CustomResourceSubresources;
+#[cfg(feature = "builder")]
+pub use self::custom_resource_subresources::CustomResourceSubresourcesBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::custom_resource_subresources::CustomResourceSubresourcesApplyConfiguration;
 
 mod custom_resource_validation;
 pub use self::custom_resource_validation::CustomResourceValidation;
+#[cfg(feature = "builder")]
+pub use self::custom_resource_validation::CustomResourceValidationBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::custom_resource_validation::CustomResourceValidationApplyConfiguration;
 
 mod external_documentation;
 pub use self::external_documentation::ExternalDocumentation;
+#[cfg(feature = "builder")]
+pub use self::external_documentation::ExternalDocumentationBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::external_documentation::ExternalDocumentationApplyConfiguration;
 
 mod json;
 pub use self::json::JSON;
 
 mod json_schema_props;
 pub use self::json_schema_props::JSONSchemaProps;
+#[cfg(feature = "builder")]
+pub use self::json_schema_props::JSONSchemaPropsBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::json_schema_props::JSONSchemaPropsApplyConfiguration;
 
 mod json_schema_props_or_array;
 pub use self::json_schema_props_or_array::JSONSchemaPropsOrArray;
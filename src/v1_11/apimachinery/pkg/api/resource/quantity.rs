@@ -35,7 +35,7 @@
 /// Non-canonical values will still parse as long as they are well formed, but will be re-emitted in their canonical form. (So always use canonical form, or don't diff.)
 ///
 /// This format is intended to make it difficult to use these numbers without writing some sort of special handling code in the hopes that that will cause implementors to also use a fixed point implementation.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default)]
 pub struct Quantity(pub String);
 
 impl<'de> crate::serde::Deserialize<'de> for Quantity {
@@ -67,7 +67,12 @@ impl crate::serde::Serialize for Quantity {
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Quantity {
     fn schema_name() -> String {
-        "io.k8s.apimachinery.pkg.api.resource.Quantity".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apimachinery::pkg::api::resource::Quantity".to_owned()
+        }
+        else {
+            "io.k8s.apimachinery.pkg.api.resource.Quantity".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
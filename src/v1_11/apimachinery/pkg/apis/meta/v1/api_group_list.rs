@@ -1,56 +1,54 @@
 // Generated from definition io.k8s.apimachinery.pkg.apis.meta.v1.APIGroupList
 
 /// APIGroupList is a list of APIGroup, to allow clients to discover the API at /apis.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct APIGroupList {
     /// groups is a list of APIGroup.
     pub groups: Vec<crate::apimachinery::pkg::apis::meta::v1::APIGroup>,
 }
 
+impl APIGroupList {
+    /// Constructs a `APIGroupList` from its required fields. Its optional fields are unset.
+    pub fn new(groups: impl Into<Vec<crate::apimachinery::pkg::apis::meta::v1::APIGroup>>) -> Self {
+        APIGroupList {
+            groups: groups.into(),
+        }
+    }
+}
+
+impl APIGroupList {
+    /// Returns this value with [`APIGroupList::groups`] set to the given value.
+    #[must_use]
+    pub fn with_groups(mut self, value: impl Into<Vec<crate::apimachinery::pkg::apis::meta::v1::APIGroup>>) -> Self {
+        self.groups = value.into();
+        self
+    }
+}
+
 impl crate::Resource for APIGroupList {
     const API_VERSION: &'static str = "v1";
     const GROUP: &'static str = "";
     const KIND: &'static str = "APIGroupList";
     const VERSION: &'static str = "v1";
     const URL_PATH_SEGMENT: &'static str = "";
+    const VERBS: &'static [&'static str] = &[];
+    const SINGULAR: &'static str = "apigrouplist";
+    const SHORT_NAMES: &'static [&'static str] = &[];
     type Scope = crate::ClusterResourceScope;
 }
 
-impl<'de> crate::serde::Deserialize<'de> for APIGroupList {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_api_version,
-            Key_kind,
-            Key_groups,
-            Other,
-        }
+impl crate::MergeStrategyInfo for APIGroupList {}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
-
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "apiVersion" => Field::Key_api_version,
-                            "kind" => Field::Key_kind,
-                            "groups" => Field::Key_groups,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl APIGroupList {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "kind",
+        "groups",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for APIGroupList {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -63,45 +61,105 @@ impl<'de> crate::serde::Deserialize<'de> for APIGroupList {
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
                 let mut value_groups: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::APIGroup>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("APIGroupList", APIGroupList::FIELDS))? {
                     match key {
-                        Field::Key_api_version => {
+                        Some(0) => {
                             let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_api_version != <Self::Value as crate::Resource>::API_VERSION {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<Self::Value as crate::Resource>::API_VERSION));
                             }
                         },
-                        Field::Key_kind => {
+                        Some(1) => {
                             let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_kind != <Self::Value as crate::Resource>::KIND {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<Self::Value as crate::Resource>::KIND));
                             }
                         },
-                        Field::Key_groups => value_groups = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(2) => value_groups = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(APIGroupList {
-                    groups: value_groups.ok_or_else(|| crate::serde::de::Error::missing_field("groups"))?,
+                    groups: value_groups.ok_or_else(|| crate::serde2::missing_field("APIGroupList", "groups", &["groups"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(APIGroupList {
+                    groups: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             <Self as crate::Resource>::KIND,
-            &[
-                "apiVersion",
-                "kind",
-                "groups",
-            ],
+            APIGroupList::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, APIGroupList> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<APIGroupList as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_groups = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("APIGroupList", APIGroupList::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <APIGroupList as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<APIGroupList as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <APIGroupList as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<APIGroupList as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.groups))?; has_groups = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_groups {
+                    return Err(crate::serde2::missing_field("APIGroupList", "groups", &["groups"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.groups))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            APIGroupList::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for APIGroupList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct(<Self as crate::Resource>::KIND, 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "groups", &self.groups)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             <Self as crate::Resource>::KIND,
             3,
@@ -113,10 +171,72 @@ impl crate::serde::Serialize for APIGroupList {
     }
 }
 
+/// A builder of [`APIGroupList`]. Created with [`APIGroupList::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct APIGroupListBuilder {
+    groups: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::APIGroup>>,
+}
+
+#[cfg(feature = "builder")]
+impl APIGroupList {
+    /// Starts building a `APIGroupList`. Its required fields must be set before [`APIGroupListBuilder::build`] is called.
+    pub fn builder() -> APIGroupListBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl APIGroupListBuilder {
+    /// Sets [`APIGroupList::groups`].
+    pub fn groups(mut self, value: impl Into<Vec<crate::apimachinery::pkg::apis::meta::v1::APIGroup>>) -> Self {
+        self.groups = Some(value.into());
+        self
+    }
+
+    /// Builds the `APIGroupList`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<APIGroupList, crate::MissingFieldError> {
+        Ok(APIGroupList {
+            groups: self.groups.ok_or_else(|| crate::MissingFieldError::new("APIGroupList", "groups"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`APIGroupList`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct APIGroupListApplyConfiguration {
+    /// See [`APIGroupList::groups`].
+    pub groups: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::APIGroupApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for APIGroupListApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            <APIGroupList as crate::Resource>::KIND,
+            2 +
+            self.groups.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <APIGroupList as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <APIGroupList as crate::Resource>::KIND)?;
+        if let Some(value) = &self.groups {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "groups", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for APIGroupList {
     fn schema_name() -> String {
-        "io.k8s.apimachinery.pkg.apis.meta.v1.APIGroupList".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apimachinery::pkg::apis::meta::v1::APIGroupList".to_owned()
+        }
+        else {
+            "io.k8s.apimachinery.pkg.apis.meta.v1.APIGroupList".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -127,8 +247,9 @@ impl crate::schemars::JsonSchema for APIGroupList {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "apiVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -136,10 +257,11 @@ impl crate::schemars::JsonSchema for APIGroupList {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("v1".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "groups".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -153,8 +275,8 @@ impl crate::schemars::JsonSchema for APIGroupList {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "kind".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -162,12 +284,16 @@ impl crate::schemars::JsonSchema for APIGroupList {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("APIGroupList".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
+                    "apiVersion",
                     "groups",
+                    "kind",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
                 ..Default::default()
             })),
@@ -175,3 +301,12 @@ impl crate::schemars::JsonSchema for APIGroupList {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for APIGroupList {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(APIGroupList {
+            groups: crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
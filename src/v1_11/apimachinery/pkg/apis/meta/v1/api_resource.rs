@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.apimachinery.pkg.apis.meta.v1.APIResource
 
 /// APIResource specifies the name of a resource and whether it is namespaced.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct APIResource {
     /// categories is a list of the grouped resources this resource belongs to (e.g. 'all')
     pub categories: Option<Vec<String>>,
@@ -31,53 +31,106 @@ pub struct APIResource {
     pub version: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for APIResource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_categories,
-            Key_group,
-            Key_kind,
-            Key_name,
-            Key_namespaced,
-            Key_short_names,
-            Key_singular_name,
-            Key_verbs,
-            Key_version,
-            Other,
+impl APIResource {
+    /// Constructs a `APIResource` from its required fields. Its optional fields are unset.
+    pub fn new(kind: impl Into<String>, name: impl Into<String>, namespaced: impl Into<bool>, singular_name: impl Into<String>, verbs: impl Into<Vec<String>>) -> Self {
+        APIResource {
+            categories: None,
+            group: None,
+            kind: kind.into(),
+            name: name.into(),
+            namespaced: namespaced.into(),
+            short_names: None,
+            singular_name: singular_name.into(),
+            verbs: verbs.into(),
+            version: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl APIResource {
+    /// Returns this value with [`APIResource::categories`] set to the given value.
+    #[must_use]
+    pub fn with_categories(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.categories = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`APIResource::group`] set to the given value.
+    #[must_use]
+    pub fn with_group(mut self, value: impl Into<String>) -> Self {
+        self.group = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`APIResource::kind`] set to the given value.
+    #[must_use]
+    pub fn with_kind(mut self, value: impl Into<String>) -> Self {
+        self.kind = value.into();
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "categories" => Field::Key_categories,
-                            "group" => Field::Key_group,
-                            "kind" => Field::Key_kind,
-                            "name" => Field::Key_name,
-                            "namespaced" => Field::Key_namespaced,
-                            "shortNames" => Field::Key_short_names,
-                            "singularName" => Field::Key_singular_name,
-                            "verbs" => Field::Key_verbs,
-                            "version" => Field::Key_version,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`APIResource::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`APIResource::namespaced`] set to the given value.
+    #[must_use]
+    pub fn with_namespaced(mut self, value: impl Into<bool>) -> Self {
+        self.namespaced = value.into();
+        self
+    }
+
+    /// Returns this value with [`APIResource::short_names`] set to the given value.
+    #[must_use]
+    pub fn with_short_names(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.short_names = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`APIResource::singular_name`] set to the given value.
+    #[must_use]
+    pub fn with_singular_name(mut self, value: impl Into<String>) -> Self {
+        self.singular_name = value.into();
+        self
+    }
+
+    /// Returns this value with [`APIResource::verbs`] set to the given value.
+    #[must_use]
+    pub fn with_verbs(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.verbs = value.into();
+        self
+    }
+
+    /// Returns this value with [`APIResource::version`] set to the given value.
+    #[must_use]
+    pub fn with_version(mut self, value: impl Into<String>) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for APIResource {}
+
+impl APIResource {
+    const FIELDS: &'static [&'static str] = &[
+        "categories",
+        "group",
+        "kind",
+        "name",
+        "namespaced",
+        "shortNames",
+        "singularName",
+        "verbs",
+        "version",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for APIResource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -98,55 +151,161 @@ impl<'de> crate::serde::Deserialize<'de> for APIResource {
                 let mut value_verbs: Option<Vec<String>> = None;
                 let mut value_version: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("APIResource", APIResource::FIELDS))? {
                     match key {
-                        Field::Key_categories => value_categories = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_group => value_group = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_kind => value_kind = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_name => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_namespaced => value_namespaced = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_short_names => value_short_names = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_singular_name => value_singular_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_verbs => value_verbs = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_version => value_version = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_categories = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_group = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_kind = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(4) => value_namespaced = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(5) => value_short_names = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_singular_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(7) => value_verbs = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(8) => value_version = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(APIResource {
                     categories: value_categories,
                     group: value_group,
-                    kind: value_kind.ok_or_else(|| crate::serde::de::Error::missing_field("kind"))?,
-                    name: value_name.ok_or_else(|| crate::serde::de::Error::missing_field("name"))?,
-                    namespaced: value_namespaced.ok_or_else(|| crate::serde::de::Error::missing_field("namespaced"))?,
+                    kind: value_kind.ok_or_else(|| crate::serde2::missing_field("APIResource", "kind", &["kind", "name", "namespaced", "singularName", "verbs"]))?,
+                    name: value_name.ok_or_else(|| crate::serde2::missing_field("APIResource", "name", &["kind", "name", "namespaced", "singularName", "verbs"]))?,
+                    namespaced: value_namespaced.ok_or_else(|| crate::serde2::missing_field("APIResource", "namespaced", &["kind", "name", "namespaced", "singularName", "verbs"]))?,
                     short_names: value_short_names,
-                    singular_name: value_singular_name.ok_or_else(|| crate::serde::de::Error::missing_field("singularName"))?,
-                    verbs: value_verbs.ok_or_else(|| crate::serde::de::Error::missing_field("verbs"))?,
+                    singular_name: value_singular_name.ok_or_else(|| crate::serde2::missing_field("APIResource", "singularName", &["kind", "name", "namespaced", "singularName", "verbs"]))?,
+                    verbs: value_verbs.ok_or_else(|| crate::serde2::missing_field("APIResource", "verbs", &["kind", "name", "namespaced", "singularName", "verbs"]))?,
                     version: value_version,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(APIResource {
+                    categories: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    group: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    kind: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    namespaced: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    short_names: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    singular_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    verbs: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                    version: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "APIResource",
-            &[
-                "categories",
-                "group",
-                "kind",
-                "name",
-                "namespaced",
-                "shortNames",
-                "singularName",
-                "verbs",
-                "version",
-            ],
+            APIResource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, APIResource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("APIResource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_categories = false;
+                let mut has_group = false;
+                let mut has_kind = false;
+                let mut has_name = false;
+                let mut has_namespaced = false;
+                let mut has_short_names = false;
+                let mut has_singular_name = false;
+                let mut has_verbs = false;
+                let mut has_version = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("APIResource", APIResource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.categories))?; has_categories = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.group))?; has_group = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.kind))?; has_kind = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.name))?; has_name = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.namespaced))?; has_namespaced = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.short_names))?; has_short_names = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.singular_name))?; has_singular_name = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.verbs))?; has_verbs = true; },
+                        Some(8) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.version))?; has_version = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_categories {
+                    self.0.categories = None;
+                }
+                if !has_group {
+                    self.0.group = None;
+                }
+                if !has_kind {
+                    return Err(crate::serde2::missing_field("APIResource", "kind", &["kind", "name", "namespaced", "singularName", "verbs"]));
+                }
+                if !has_name {
+                    return Err(crate::serde2::missing_field("APIResource", "name", &["kind", "name", "namespaced", "singularName", "verbs"]));
+                }
+                if !has_namespaced {
+                    return Err(crate::serde2::missing_field("APIResource", "namespaced", &["kind", "name", "namespaced", "singularName", "verbs"]));
+                }
+                if !has_short_names {
+                    self.0.short_names = None;
+                }
+                if !has_singular_name {
+                    return Err(crate::serde2::missing_field("APIResource", "singularName", &["kind", "name", "namespaced", "singularName", "verbs"]));
+                }
+                if !has_verbs {
+                    return Err(crate::serde2::missing_field("APIResource", "verbs", &["kind", "name", "namespaced", "singularName", "verbs"]));
+                }
+                if !has_version {
+                    self.0.version = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.categories))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.group))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.kind))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.namespaced))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.short_names))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.singular_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.verbs))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.version))?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "APIResource",
+            APIResource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for APIResource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("APIResource", 9)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "categories", &self.categories)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "group", &self.group)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", &self.kind)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "namespaced", &self.namespaced)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "shortNames", &self.short_names)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "singularName", &self.singular_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "verbs", &self.verbs)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "version", &self.version)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "APIResource",
             5 +
@@ -176,10 +335,189 @@ impl crate::serde::Serialize for APIResource {
     }
 }
 
+/// A builder of [`APIResource`]. Created with [`APIResource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct APIResourceBuilder {
+    categories: Option<Vec<String>>,
+    group: Option<String>,
+    kind: Option<String>,
+    name: Option<String>,
+    namespaced: Option<bool>,
+    short_names: Option<Vec<String>>,
+    singular_name: Option<String>,
+    verbs: Option<Vec<String>>,
+    version: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl APIResource {
+    /// Starts building a `APIResource`. Its required fields must be set before [`APIResourceBuilder::build`] is called.
+    pub fn builder() -> APIResourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl APIResourceBuilder {
+    /// Sets [`APIResource::categories`].
+    pub fn categories(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.categories = Some(value.into());
+        self
+    }
+
+    /// Sets [`APIResource::group`].
+    pub fn group(mut self, value: impl Into<String>) -> Self {
+        self.group = Some(value.into());
+        self
+    }
+
+    /// Sets [`APIResource::kind`].
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`APIResource::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`APIResource::namespaced`].
+    pub fn namespaced(mut self, value: impl Into<bool>) -> Self {
+        self.namespaced = Some(value.into());
+        self
+    }
+
+    /// Sets [`APIResource::short_names`].
+    pub fn short_names(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.short_names = Some(value.into());
+        self
+    }
+
+    /// Sets [`APIResource::singular_name`].
+    pub fn singular_name(mut self, value: impl Into<String>) -> Self {
+        self.singular_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`APIResource::verbs`].
+    pub fn verbs(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.verbs = Some(value.into());
+        self
+    }
+
+    /// Sets [`APIResource::version`].
+    pub fn version(mut self, value: impl Into<String>) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Builds the `APIResource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<APIResource, crate::MissingFieldError> {
+        Ok(APIResource {
+            categories: self.categories,
+            group: self.group,
+            kind: self.kind.ok_or_else(|| crate::MissingFieldError::new("APIResource", "kind"))?,
+            name: self.name.ok_or_else(|| crate::MissingFieldError::new("APIResource", "name"))?,
+            namespaced: self.namespaced.ok_or_else(|| crate::MissingFieldError::new("APIResource", "namespaced"))?,
+            short_names: self.short_names,
+            singular_name: self.singular_name.ok_or_else(|| crate::MissingFieldError::new("APIResource", "singularName"))?,
+            verbs: self.verbs.ok_or_else(|| crate::MissingFieldError::new("APIResource", "verbs"))?,
+            version: self.version,
+        })
+    }
+}
+
+/// The apply configuration of [`APIResource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct APIResourceApplyConfiguration {
+    /// See [`APIResource::categories`].
+    pub categories: Option<Vec<String>>,
+
+    /// See [`APIResource::group`].
+    pub group: Option<String>,
+
+    /// See [`APIResource::kind`].
+    pub kind: Option<String>,
+
+    /// See [`APIResource::name`].
+    pub name: Option<String>,
+
+    /// See [`APIResource::namespaced`].
+    pub namespaced: Option<bool>,
+
+    /// See [`APIResource::short_names`].
+    pub short_names: Option<Vec<String>>,
+
+    /// See [`APIResource::singular_name`].
+    pub singular_name: Option<String>,
+
+    /// See [`APIResource::verbs`].
+    pub verbs: Option<Vec<String>>,
+
+    /// See [`APIResource::version`].
+    pub version: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for APIResourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "APIResourceApplyConfiguration",
+            self.categories.as_ref().map_or(0, |_| 1) +
+            self.group.as_ref().map_or(0, |_| 1) +
+            self.kind.as_ref().map_or(0, |_| 1) +
+            self.name.as_ref().map_or(0, |_| 1) +
+            self.namespaced.as_ref().map_or(0, |_| 1) +
+            self.short_names.as_ref().map_or(0, |_| 1) +
+            self.singular_name.as_ref().map_or(0, |_| 1) +
+            self.verbs.as_ref().map_or(0, |_| 1) +
+            self.version.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.categories {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "categories", value)?;
+        }
+        if let Some(value) = &self.group {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "group", value)?;
+        }
+        if let Some(value) = &self.kind {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", value)?;
+        }
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        if let Some(value) = &self.namespaced {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "namespaced", value)?;
+        }
+        if let Some(value) = &self.short_names {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "shortNames", value)?;
+        }
+        if let Some(value) = &self.singular_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "singularName", value)?;
+        }
+        if let Some(value) = &self.verbs {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "verbs", value)?;
+        }
+        if let Some(value) = &self.version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "version", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for APIResource {
     fn schema_name() -> String {
-        "io.k8s.apimachinery.pkg.apis.meta.v1.APIResource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apimachinery::pkg::apis::meta::v1::APIResource".to_owned()
+        }
+        else {
+            "io.k8s.apimachinery.pkg.apis.meta.v1.APIResource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -190,8 +528,9 @@ impl crate::schemars::JsonSchema for APIResource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "categories".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -210,8 +549,8 @@ impl crate::schemars::JsonSchema for APIResource {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "group".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -221,8 +560,8 @@ impl crate::schemars::JsonSchema for APIResource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "kind".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -232,8 +571,8 @@ impl crate::schemars::JsonSchema for APIResource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -243,8 +582,8 @@ impl crate::schemars::JsonSchema for APIResource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "namespaced".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -254,8 +593,8 @@ impl crate::schemars::JsonSchema for APIResource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "shortNames".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -274,8 +613,8 @@ impl crate::schemars::JsonSchema for APIResource {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "singularName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -285,8 +624,8 @@ impl crate::schemars::JsonSchema for APIResource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "verbs".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -305,8 +644,8 @@ impl crate::schemars::JsonSchema for APIResource {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "version".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -316,8 +655,9 @@ impl crate::schemars::JsonSchema for APIResource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "kind",
                     "name",
@@ -331,3 +671,20 @@ impl crate::schemars::JsonSchema for APIResource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for APIResource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(APIResource {
+            categories: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            group: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            kind: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            namespaced: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            short_names: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            singular_name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            verbs: crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            version: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.apimachinery.pkg.apis.meta.v1.APIResourceList
 
 /// APIResourceList is a list of APIResource, it is used to expose the name of the resources supported in a specific group and version, and if the resource is namespaced.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct APIResourceList {
     /// groupVersion is the group and version this APIResourceList is for.
     pub group_version: String,
@@ -10,52 +10,57 @@ pub struct APIResourceList {
     pub resources: Vec<crate::apimachinery::pkg::apis::meta::v1::APIResource>,
 }
 
+impl APIResourceList {
+    /// Constructs a `APIResourceList` from its required fields. Its optional fields are unset.
+    pub fn new(group_version: impl Into<String>, resources: impl Into<Vec<crate::apimachinery::pkg::apis::meta::v1::APIResource>>) -> Self {
+        APIResourceList {
+            group_version: group_version.into(),
+            resources: resources.into(),
+        }
+    }
+}
+
+impl APIResourceList {
+    /// Returns this value with [`APIResourceList::group_version`] set to the given value.
+    #[must_use]
+    pub fn with_group_version(mut self, value: impl Into<String>) -> Self {
+        self.group_version = value.into();
+        self
+    }
+
+    /// Returns this value with [`APIResourceList::resources`] set to the given value.
+    #[must_use]
+    pub fn with_resources(mut self, value: impl Into<Vec<crate::apimachinery::pkg::apis::meta::v1::APIResource>>) -> Self {
+        self.resources = value.into();
+        self
+    }
+}
+
 impl crate::Resource for APIResourceList {
     const API_VERSION: &'static str = "v1";
     const GROUP: &'static str = "";
     const KIND: &'static str = "APIResourceList";
     const VERSION: &'static str = "v1";
     const URL_PATH_SEGMENT: &'static str = "";
+    const VERBS: &'static [&'static str] = &[];
+    const SINGULAR: &'static str = "apiresourcelist";
+    const SHORT_NAMES: &'static [&'static str] = &[];
     type Scope = crate::ClusterResourceScope;
 }
 
-impl<'de> crate::serde::Deserialize<'de> for APIResourceList {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_api_version,
-            Key_kind,
-            Key_group_version,
-            Key_resources,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl crate::MergeStrategyInfo for APIResourceList {}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
-
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "apiVersion" => Field::Key_api_version,
-                            "kind" => Field::Key_kind,
-                            "groupVersion" => Field::Key_group_version,
-                            "resources" => Field::Key_resources,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl APIResourceList {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "kind",
+        "groupVersion",
+        "resources",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for APIResourceList {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -69,48 +74,115 @@ impl<'de> crate::serde::Deserialize<'de> for APIResourceList {
                 let mut value_group_version: Option<String> = None;
                 let mut value_resources: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::APIResource>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("APIResourceList", APIResourceList::FIELDS))? {
                     match key {
-                        Field::Key_api_version => {
+                        Some(0) => {
                             let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_api_version != <Self::Value as crate::Resource>::API_VERSION {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<Self::Value as crate::Resource>::API_VERSION));
                             }
                         },
-                        Field::Key_kind => {
+                        Some(1) => {
                             let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_kind != <Self::Value as crate::Resource>::KIND {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<Self::Value as crate::Resource>::KIND));
                             }
                         },
-                        Field::Key_group_version => value_group_version = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_resources => value_resources = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(2) => value_group_version = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_resources = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(APIResourceList {
-                    group_version: value_group_version.ok_or_else(|| crate::serde::de::Error::missing_field("groupVersion"))?,
-                    resources: value_resources.ok_or_else(|| crate::serde::de::Error::missing_field("resources"))?,
+                    group_version: value_group_version.ok_or_else(|| crate::serde2::missing_field("APIResourceList", "groupVersion", &["groupVersion", "resources"]))?,
+                    resources: value_resources.ok_or_else(|| crate::serde2::missing_field("APIResourceList", "resources", &["groupVersion", "resources"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(APIResourceList {
+                    group_version: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    resources: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             <Self as crate::Resource>::KIND,
-            &[
-                "apiVersion",
-                "kind",
-                "groupVersion",
-                "resources",
-            ],
+            APIResourceList::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, APIResourceList> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<APIResourceList as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_group_version = false;
+                let mut has_resources = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("APIResourceList", APIResourceList::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <APIResourceList as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<APIResourceList as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <APIResourceList as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<APIResourceList as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.group_version))?; has_group_version = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.resources))?; has_resources = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_group_version {
+                    return Err(crate::serde2::missing_field("APIResourceList", "groupVersion", &["groupVersion", "resources"]));
+                }
+                if !has_resources {
+                    return Err(crate::serde2::missing_field("APIResourceList", "resources", &["groupVersion", "resources"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.group_version))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.resources))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            APIResourceList::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for APIResourceList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct(<Self as crate::Resource>::KIND, 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "groupVersion", &self.group_version)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resources", &self.resources)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             <Self as crate::Resource>::KIND,
             4,
@@ -123,10 +195,87 @@ impl crate::serde::Serialize for APIResourceList {
     }
 }
 
+/// A builder of [`APIResourceList`]. Created with [`APIResourceList::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct APIResourceListBuilder {
+    group_version: Option<String>,
+    resources: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::APIResource>>,
+}
+
+#[cfg(feature = "builder")]
+impl APIResourceList {
+    /// Starts building a `APIResourceList`. Its required fields must be set before [`APIResourceListBuilder::build`] is called.
+    pub fn builder() -> APIResourceListBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl APIResourceListBuilder {
+    /// Sets [`APIResourceList::group_version`].
+    pub fn group_version(mut self, value: impl Into<String>) -> Self {
+        self.group_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`APIResourceList::resources`].
+    pub fn resources(mut self, value: impl Into<Vec<crate::apimachinery::pkg::apis::meta::v1::APIResource>>) -> Self {
+        self.resources = Some(value.into());
+        self
+    }
+
+    /// Builds the `APIResourceList`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<APIResourceList, crate::MissingFieldError> {
+        Ok(APIResourceList {
+            group_version: self.group_version.ok_or_else(|| crate::MissingFieldError::new("APIResourceList", "groupVersion"))?,
+            resources: self.resources.ok_or_else(|| crate::MissingFieldError::new("APIResourceList", "resources"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`APIResourceList`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct APIResourceListApplyConfiguration {
+    /// See [`APIResourceList::group_version`].
+    pub group_version: Option<String>,
+
+    /// See [`APIResourceList::resources`].
+    pub resources: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::APIResourceApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for APIResourceListApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            <APIResourceList as crate::Resource>::KIND,
+            2 +
+            self.group_version.as_ref().map_or(0, |_| 1) +
+            self.resources.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <APIResourceList as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <APIResourceList as crate::Resource>::KIND)?;
+        if let Some(value) = &self.group_version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "groupVersion", value)?;
+        }
+        if let Some(value) = &self.resources {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resources", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for APIResourceList {
     fn schema_name() -> String {
-        "io.k8s.apimachinery.pkg.apis.meta.v1.APIResourceList".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apimachinery::pkg::apis::meta::v1::APIResourceList".to_owned()
+        }
+        else {
+            "io.k8s.apimachinery.pkg.apis.meta.v1.APIResourceList".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -137,8 +286,9 @@ impl crate::schemars::JsonSchema for APIResourceList {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "apiVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -146,10 +296,11 @@ impl crate::schemars::JsonSchema for APIResourceList {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("v1".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "groupVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -159,8 +310,8 @@ impl crate::schemars::JsonSchema for APIResourceList {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "kind".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -168,10 +319,11 @@ impl crate::schemars::JsonSchema for APIResourceList {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("APIResourceList".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "resources".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -185,10 +337,13 @@ impl crate::schemars::JsonSchema for APIResourceList {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
+                    "apiVersion",
                     "groupVersion",
+                    "kind",
                     "resources",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
                 ..Default::default()
@@ -197,3 +352,13 @@ impl crate::schemars::JsonSchema for APIResourceList {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for APIResourceList {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(APIResourceList {
+            group_version: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            resources: crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
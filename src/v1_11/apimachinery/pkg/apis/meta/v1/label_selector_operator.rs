@@ -0,0 +1,165 @@
+// Generated from definition io.k8s.apimachinery.pkg.apis.meta.v1.LabelSelectorOperator
+
+/// LabelSelectorOperator is the set of operators that can be used in a label selector requirement.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum LabelSelectorOperator {
+    /// `In`
+    In,
+
+    /// `NotIn`
+    NotIn,
+
+    /// `Exists`
+    Exists,
+
+    /// `DoesNotExist`
+    DoesNotExist,
+
+    /// A value that is not one of the values known to this version of the crate, such as one that was added in a newer version of Kubernetes.
+    Other(String),
+}
+
+impl LabelSelectorOperator {
+    /// The values known to this version of the crate, in the order of the variants.
+    pub const VALUES: &'static [&'static str] = &["In", "NotIn", "Exists", "DoesNotExist"];
+
+    /// The value as it is serialized.
+    pub fn as_str(&self) -> &str {
+        match self {
+            LabelSelectorOperator::In => "In",
+            LabelSelectorOperator::NotIn => "NotIn",
+            LabelSelectorOperator::Exists => "Exists",
+            LabelSelectorOperator::DoesNotExist => "DoesNotExist",
+            LabelSelectorOperator::Other(value) => value,
+        }
+    }
+}
+
+impl AsRef<str> for LabelSelectorOperator {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Default for LabelSelectorOperator {
+    fn default() -> Self {
+        LabelSelectorOperator::Other(String::new())
+    }
+}
+
+impl std::fmt::Display for LabelSelectorOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for LabelSelectorOperator {
+    fn from(value: &str) -> Self {
+        match value {
+            "In" => LabelSelectorOperator::In,
+            "NotIn" => LabelSelectorOperator::NotIn,
+            "Exists" => LabelSelectorOperator::Exists,
+            "DoesNotExist" => LabelSelectorOperator::DoesNotExist,
+            value => LabelSelectorOperator::Other(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LabelSelectorOperator {
+    fn from(value: String) -> Self {
+        match &*value {
+            "In" => LabelSelectorOperator::In,
+            "NotIn" => LabelSelectorOperator::NotIn,
+            "Exists" => LabelSelectorOperator::Exists,
+            "DoesNotExist" => LabelSelectorOperator::DoesNotExist,
+            _ => LabelSelectorOperator::Other(value),
+        }
+    }
+}
+
+impl From<LabelSelectorOperator> for String {
+    fn from(value: LabelSelectorOperator) -> Self {
+        match value {
+            LabelSelectorOperator::Other(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl PartialEq<str> for LabelSelectorOperator {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for LabelSelectorOperator {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<'de> crate::serde::Deserialize<'de> for LabelSelectorOperator {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl crate::serde::de::Visitor<'_> for Visitor {
+            type Value = LabelSelectorOperator;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("LabelSelectorOperator")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
+                Ok(v.into())
+            }
+
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E> where E: crate::serde::de::Error {
+                Ok(v.into())
+            }
+        }
+
+        deserializer.deserialize_string(Visitor)
+    }
+}
+
+impl crate::serde::Serialize for LabelSelectorOperator {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for LabelSelectorOperator {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "apimachinery::pkg::apis::meta::v1::LabelSelectorOperator".to_owned()
+        }
+        else {
+            "io.k8s.apimachinery.pkg.apis.meta.v1.LabelSelectorOperator".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("LabelSelectorOperator is the set of operators that can be used in a label selector requirement.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for LabelSelectorOperator {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        Ok(match u.int_in_range(0..=4_u8)? {
+            0 => LabelSelectorOperator::In,
+            1 => LabelSelectorOperator::NotIn,
+            2 => LabelSelectorOperator::Exists,
+            3 => LabelSelectorOperator::DoesNotExist,
+            _ => LabelSelectorOperator::from(u.arbitrary::<String>()?),
+        })
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.apimachinery.pkg.apis.meta.v1.ListMeta
 
 /// ListMeta describes metadata that synthetic resources must have, including lists and various status objects. A resource may have only one of {ObjectMeta, ListMeta}.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ListMeta {
     /// continue may be set if the user set a limit on the number of items returned, and indicates that the server has more data available. The value is opaque and may be used to issue another request to the endpoint that served this list to retrieve the next set of available objects. Continuing a list may not be possible if the server configuration has changed or more than a few minutes have passed. The resourceVersion field returned when using this continue value will be identical to the value in the first response.
     pub continue_: Option<String>,
@@ -13,41 +13,41 @@ pub struct ListMeta {
     pub self_link: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ListMeta {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_continue_,
-            Key_resource_version,
-            Key_self_link,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl ListMeta {
+    /// Returns this value with [`ListMeta::continue_`] set to the given value.
+    #[must_use]
+    pub fn with_continue(mut self, value: impl Into<String>) -> Self {
+        self.continue_ = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ListMeta::resource_version`] set to the given value.
+    #[must_use]
+    pub fn with_resource_version(mut self, value: impl Into<String>) -> Self {
+        self.resource_version = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ListMeta::self_link`] set to the given value.
+    #[must_use]
+    pub fn with_self_link(mut self, value: impl Into<String>) -> Self {
+        self.self_link = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "continue" => Field::Key_continue_,
-                            "resourceVersion" => Field::Key_resource_version,
-                            "selfLink" => Field::Key_self_link,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for ListMeta {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl ListMeta {
+    const FIELDS: &'static [&'static str] = &[
+        "continue",
+        "resourceVersion",
+        "selfLink",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ListMeta {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,12 +62,12 @@ impl<'de> crate::serde::Deserialize<'de> for ListMeta {
                 let mut value_resource_version: Option<String> = None;
                 let mut value_self_link: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ListMeta", ListMeta::FIELDS))? {
                     match key {
-                        Field::Key_continue_ => value_continue_ = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_resource_version => value_resource_version = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_self_link => value_self_link = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_continue_ = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_resource_version = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_self_link = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -77,22 +77,86 @@ impl<'de> crate::serde::Deserialize<'de> for ListMeta {
                     self_link: value_self_link,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ListMeta {
+                    continue_: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    resource_version: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    self_link: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "ListMeta",
-            &[
-                "continue",
-                "resourceVersion",
-                "selfLink",
-            ],
+            ListMeta::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ListMeta> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ListMeta")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_continue_ = false;
+                let mut has_resource_version = false;
+                let mut has_self_link = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ListMeta", ListMeta::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.continue_))?; has_continue_ = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.resource_version))?; has_resource_version = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.self_link))?; has_self_link = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_continue_ {
+                    self.0.continue_ = None;
+                }
+                if !has_resource_version {
+                    self.0.resource_version = None;
+                }
+                if !has_self_link {
+                    self.0.self_link = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.continue_))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.resource_version))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.self_link))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ListMeta",
+            ListMeta::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ListMeta {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ListMeta", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "continue", &self.continue_)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resourceVersion", &self.resource_version)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selfLink", &self.self_link)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ListMeta",
             self.continue_.as_ref().map_or(0, |_| 1) +
@@ -112,10 +176,99 @@ impl crate::serde::Serialize for ListMeta {
     }
 }
 
+/// A builder of [`ListMeta`]. Created with [`ListMeta::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ListMetaBuilder {
+    continue_: Option<String>,
+    resource_version: Option<String>,
+    self_link: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl ListMeta {
+    /// Starts building a `ListMeta`. Its required fields must be set before [`ListMetaBuilder::build`] is called.
+    pub fn builder() -> ListMetaBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ListMetaBuilder {
+    /// Sets [`ListMeta::continue_`].
+    pub fn continue_(mut self, value: impl Into<String>) -> Self {
+        self.continue_ = Some(value.into());
+        self
+    }
+
+    /// Sets [`ListMeta::resource_version`].
+    pub fn resource_version(mut self, value: impl Into<String>) -> Self {
+        self.resource_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`ListMeta::self_link`].
+    pub fn self_link(mut self, value: impl Into<String>) -> Self {
+        self.self_link = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListMeta`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ListMeta, crate::MissingFieldError> {
+        Ok(ListMeta {
+            continue_: self.continue_,
+            resource_version: self.resource_version,
+            self_link: self.self_link,
+        })
+    }
+}
+
+/// The apply configuration of [`ListMeta`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ListMetaApplyConfiguration {
+    /// See [`ListMeta::continue_`].
+    pub continue_: Option<String>,
+
+    /// See [`ListMeta::resource_version`].
+    pub resource_version: Option<String>,
+
+    /// See [`ListMeta::self_link`].
+    pub self_link: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ListMetaApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ListMetaApplyConfiguration",
+            self.continue_.as_ref().map_or(0, |_| 1) +
+            self.resource_version.as_ref().map_or(0, |_| 1) +
+            self.self_link.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.continue_ {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "continue", value)?;
+        }
+        if let Some(value) = &self.resource_version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resourceVersion", value)?;
+        }
+        if let Some(value) = &self.self_link {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selfLink", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ListMeta {
     fn schema_name() -> String {
-        "io.k8s.apimachinery.pkg.apis.meta.v1.ListMeta".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apimachinery::pkg::apis::meta::v1::ListMeta".to_owned()
+        }
+        else {
+            "io.k8s.apimachinery.pkg.apis.meta.v1.ListMeta".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -126,8 +279,9 @@ impl crate::schemars::JsonSchema for ListMeta {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "continue".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -137,8 +291,8 @@ impl crate::schemars::JsonSchema for ListMeta {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "resourceVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -148,8 +302,8 @@ impl crate::schemars::JsonSchema for ListMeta {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "selfLink".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -159,11 +313,23 @@ impl crate::schemars::JsonSchema for ListMeta {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ListMeta {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ListMeta {
+            continue_: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            resource_version: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            self_link: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
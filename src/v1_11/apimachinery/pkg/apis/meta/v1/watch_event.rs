@@ -18,77 +18,50 @@ pub enum WatchEvent<T> {
 
 impl<'de, T> crate::serde::Deserialize<'de> for WatchEvent<T> where T: crate::serde::Deserialize<'de> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_type,
-            Key_object,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+        struct Visitor<T>(std::marker::PhantomData<T>);
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "type" => Field::Key_type,
-                            "object" => Field::Key_object,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+        impl<'de, T> crate::serde::de::Visitor<'de> for Visitor<T> where T: crate::serde::Deserialize<'de> {
+            type Value = WatchEvent<T>;
 
-                deserializer.deserialize_identifier(Visitor)
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("WatchEvent")
             }
-        }
 
-        enum WatchEventType {
-            Added,
-            Deleted,
-            Modified,
-            Error,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for WatchEventType {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = WatchEventType;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("watch event type")
-                    }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_type: Option<WatchEventType> = None;
+                let mut value_object: Option<crate::serde_value::Value> = None;
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "ADDED" => WatchEventType::Added,
-                            "DELETED" => WatchEventType::Deleted,
-                            "MODIFIED" => WatchEventType::Modified,
-                            "ERROR" => WatchEventType::Error,
-                            _ => return Err(crate::serde::de::Error::unknown_variant(
-                                v,
-                                &["ADDED", "DELETED", "MODIFIED", "ERROR"],
-                            )),
-                        })
+                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                    match key {
+                        Field::Key_type => value_type = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Field::Key_object => value_object = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
-                deserializer.deserialize_identifier(Visitor)
+                let value_type = value_type.ok_or_else(|| crate::serde::de::Error::missing_field("type"))?;
+                let value_object = value_object.ok_or_else(|| crate::serde::de::Error::missing_field("object"))?;
+
+                value_type.watch_event(value_object)
             }
         }
 
-        struct Visitor<T>(std::marker::PhantomData<T>);
+        deserializer.deserialize_struct(
+            "WatchEvent",
+            &[
+                "type",
+                "object",
+            ],
+            Visitor(Default::default()),
+        )
+    }
 
-        impl<'de, T> crate::serde::de::Visitor<'de> for Visitor<T> where T: crate::serde::Deserialize<'de> {
-            type Value = WatchEvent<T>;
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, T>(&'a mut WatchEvent<T>);
+
+        impl<'de, T> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, T> where T: crate::serde::Deserialize<'de> {
+            type Value = ();
 
             fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 f.write_str("WatchEvent")
@@ -98,48 +71,57 @@ impl<'de, T> crate::serde::Deserialize<'de> for WatchEvent<T> where T: crate::se
                 let mut value_type: Option<WatchEventType> = None;
                 let mut value_object: Option<crate::serde_value::Value> = None;
 
+                // The object can only be deserialized in place if the type of the event is already known when the object is encountered,
+                // and the existing event has an object. The API server always writes the type first.
+                let mut has_object_in_place = false;
+
                 while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
                     match key {
-                        Field::Key_type => value_type = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_object => value_object = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Field::Key_type => {
+                            if has_object_in_place {
+                                return Err(crate::serde::de::Error::duplicate_field("type"));
+                            }
+                            value_type = crate::serde::de::MapAccess::next_value(&mut map)?;
+                        },
+                        Field::Key_object => match (&value_type, &mut *self.0) {
+                            (
+                                Some(WatchEventType::Added | WatchEventType::Deleted | WatchEventType::Modified),
+                                WatchEvent::Added(object) | WatchEvent::Deleted(object) | WatchEvent::Modified(object),
+                            ) => {
+                                crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(object))?;
+                                value_object = None;
+                                has_object_in_place = true;
+                            },
+                            _ => {
+                                value_object = crate::serde::de::MapAccess::next_value(&mut map)?;
+                                has_object_in_place = false;
+                            },
+                        },
                         Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 let value_type = value_type.ok_or_else(|| crate::serde::de::Error::missing_field("type"))?;
-                let value_object = value_object.ok_or_else(|| crate::serde::de::Error::missing_field("object"))?;
 
-                Ok(match value_type {
-                    WatchEventType::Added => {
-                        let value_object = crate::serde_value::ValueDeserializer::new(value_object);
-                        WatchEvent::Added(crate::serde::Deserialize::deserialize(value_object)?)
-                    },
-                    WatchEventType::Deleted => {
-                        let value_object = crate::serde_value::ValueDeserializer::new(value_object);
-                        WatchEvent::Deleted(crate::serde::Deserialize::deserialize(value_object)?)
-                    },
-                    WatchEventType::Modified => {
-                        let value_object = crate::serde_value::ValueDeserializer::new(value_object);
-                        WatchEvent::Modified(crate::serde::Deserialize::deserialize(value_object)?)
-                    },
-
-                    WatchEventType::Error => {
-                        let is_status =
-                            if let crate::serde_value::Value::Map(map) = &value_object {
-                                matches!(map.get(&crate::serde_value::Value::String("kind".to_owned())), Some(crate::serde_value::Value::String(s)) if s == "Status")
-                            }
-                            else {
-                                false
-                            };
-                        let value_object = crate::serde_value::ValueDeserializer::new(value_object);
-                        if is_status {
-                            WatchEvent::ErrorStatus(crate::serde::Deserialize::deserialize(value_object)?)
-                        }
-                        else {
-                            WatchEvent::ErrorOther(crate::serde::Deserialize::deserialize(value_object)?)
-                        }
-                    },
-                })
+                if has_object_in_place {
+                    // The object was deserialized into the existing event, so move it to the variant of the new event's type.
+                    let object = match std::mem::replace(self.0, WatchEvent::ErrorOther(Default::default())) {
+                        WatchEvent::Added(object) | WatchEvent::Deleted(object) | WatchEvent::Modified(object) => object,
+                        _ => unreachable!("object was deserialized in place into an event without an object"),
+                    };
+                    *self.0 = match value_type {
+                        WatchEventType::Added => WatchEvent::Added(object),
+                        WatchEventType::Deleted => WatchEvent::Deleted(object),
+                        WatchEventType::Modified => WatchEvent::Modified(object),
+                        _ => unreachable!("object was deserialized in place for an event type without an object"),
+                    };
+                }
+                else {
+                    let value_object = value_object.ok_or_else(|| crate::serde::de::Error::missing_field("object"))?;
+                    *self.0 = value_type.watch_event(value_object)?;
+                }
+
+                Ok(())
             }
         }
 
@@ -149,11 +131,115 @@ impl<'de, T> crate::serde::Deserialize<'de> for WatchEvent<T> where T: crate::se
                 "type",
                 "object",
             ],
-            Visitor(Default::default()),
+            InPlaceVisitor(place),
         )
     }
 }
 
+#[allow(non_camel_case_types)]
+enum Field {
+    Key_type,
+    Key_object,
+    Other,
+}
+
+impl<'de> crate::serde::Deserialize<'de> for Field {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = Field;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("field identifier")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
+                Ok(match v {
+                    "type" => Field::Key_type,
+                    "object" => Field::Key_object,
+                    _ => Field::Other,
+                })
+            }
+        }
+
+        deserializer.deserialize_identifier(Visitor)
+    }
+}
+
+enum WatchEventType {
+    Added,
+    Deleted,
+    Modified,
+    Error,
+}
+
+impl<'de> crate::serde::Deserialize<'de> for WatchEventType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = WatchEventType;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("watch event type")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
+                Ok(match v {
+                    "ADDED" => WatchEventType::Added,
+                    "DELETED" => WatchEventType::Deleted,
+                    "MODIFIED" => WatchEventType::Modified,
+                    "ERROR" => WatchEventType::Error,
+                    _ => return Err(crate::serde::de::Error::unknown_variant(
+                        v,
+                        &["ADDED", "DELETED", "MODIFIED", "ERROR"],
+                    )),
+                })
+            }
+        }
+
+        deserializer.deserialize_identifier(Visitor)
+    }
+}
+
+impl WatchEventType {
+    /// Deserializes the buffered object of an event of this type.
+    fn watch_event<'de, T, E>(self, value_object: crate::serde_value::Value) -> Result<WatchEvent<T>, E> where T: crate::serde::Deserialize<'de>, E: crate::serde::de::Error {
+        Ok(match self {
+            WatchEventType::Added => {
+                let value_object = crate::serde_value::ValueDeserializer::new(value_object);
+                WatchEvent::Added(crate::serde::Deserialize::deserialize(value_object)?)
+            },
+            WatchEventType::Deleted => {
+                let value_object = crate::serde_value::ValueDeserializer::new(value_object);
+                WatchEvent::Deleted(crate::serde::Deserialize::deserialize(value_object)?)
+            },
+            WatchEventType::Modified => {
+                let value_object = crate::serde_value::ValueDeserializer::new(value_object);
+                WatchEvent::Modified(crate::serde::Deserialize::deserialize(value_object)?)
+            },
+
+            WatchEventType::Error => {
+                let is_status =
+                    if let crate::serde_value::Value::Map(map) = &value_object {
+                        matches!(map.get(&crate::serde_value::Value::String("kind".to_owned())), Some(crate::serde_value::Value::String(s)) if s == "Status")
+                    }
+                    else {
+                        false
+                    };
+                let value_object = crate::serde_value::ValueDeserializer::new(value_object);
+                if is_status {
+                    WatchEvent::ErrorStatus(crate::serde::Deserialize::deserialize(value_object)?)
+                }
+                else {
+                    WatchEvent::ErrorOther(crate::serde::Deserialize::deserialize(value_object)?)
+                }
+            },
+        })
+    }
+}
+
 impl<T> crate::serde::Serialize for WatchEvent<T> where T: crate::serde::Serialize {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
         let mut state = serializer.serialize_struct(
@@ -187,9 +273,14 @@ impl<T> crate::serde::Serialize for WatchEvent<T> where T: crate::serde::Seriali
 }
 
 #[cfg(feature = "schemars")]
-impl<T> crate::schemars::JsonSchema for WatchEvent<T> {
+impl<T> crate::schemars::JsonSchema for WatchEvent<T> where T: crate::schemars::JsonSchema {
     fn schema_name() -> String {
-        "io.k8s.apimachinery.pkg.apis.meta.v1.WatchEvent".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            format!("{}_for_{}", "apimachinery::pkg::apis::meta::v1::WatchEvent", <T as crate::schemars::JsonSchema>::schema_name())
+        }
+        else {
+            format!("{}_for_{}", "io.k8s.apimachinery.pkg.apis.meta.v1.WatchEvent", <T as crate::schemars::JsonSchema>::schema_name())
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -200,19 +291,21 @@ impl<T> crate::schemars::JsonSchema for WatchEvent<T> {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "object".to_owned(),
-                        __gen.subschema_for::<crate::apimachinery::pkg::runtime::RawExtension>(),
-                    ),
-                    (
+                        __gen.subschema_for::<T>(),
+                    );
+                    properties.insert(
                         "type".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "object",
                     "type",
@@ -43,7 +43,7 @@ impl<'de> crate::serde::Deserialize<'de> for RawExtension {
             }
 
             fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: crate::serde::Deserializer<'de> {
-                Ok(RawExtension(crate::serde::Deserialize::deserialize(deserializer)?))
+                Ok(RawExtension(crate::serde2::deserialize_json_value(deserializer)?))
             }
         }
 
@@ -53,14 +53,19 @@ impl<'de> crate::serde::Deserialize<'de> for RawExtension {
 
 impl crate::serde::Serialize for RawExtension {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
-        serializer.serialize_newtype_struct("RawExtension", &self.0)
+        serializer.serialize_newtype_struct("RawExtension", &crate::serde2::JsonValue(&self.0))
     }
 }
 
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for RawExtension {
     fn schema_name() -> String {
-        "io.k8s.apimachinery.pkg.runtime.RawExtension".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apimachinery::pkg::runtime::RawExtension".to_owned()
+        }
+        else {
+            "io.k8s.apimachinery.pkg.runtime.RawExtension".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -70,7 +75,17 @@ impl crate::schemars::JsonSchema for RawExtension {
                 ..Default::default()
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            extensions: std::array::IntoIter::new([
+                ("x-kubernetes-preserve-unknown-fields".to_owned(), crate::serde_json::Value::Bool(true)),
+            ]).collect(),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for RawExtension {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        Ok(RawExtension(crate::arbitrary2::json_value(u)?))
+    }
+}
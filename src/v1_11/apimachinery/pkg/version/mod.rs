@@ -1,3 +1,7 @@
 
 mod info;
 pub use self::info::Info;
+#[cfg(feature = "builder")]
+pub use self::info::InfoBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::info::InfoApplyConfiguration;
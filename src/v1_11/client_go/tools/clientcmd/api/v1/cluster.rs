@@ -0,0 +1,568 @@
+// Generated from definition io.k8s.client-go.tools.clientcmd.api.v1.Cluster
+
+/// Cluster contains information about how to communicate with a kubernetes cluster
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Cluster {
+    /// CertificateAuthority is the path to a cert file for the certificate authority.
+    pub certificate_authority: Option<String>,
+
+    /// CertificateAuthorityData contains PEM-encoded certificate authority certificates. Overrides CertificateAuthority
+    pub certificate_authority_data: Option<crate::ByteString>,
+
+    /// Extensions holds additional information. This is useful for extenders so that reads and writes don't clobber unknown fields
+    pub extensions: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtension>>,
+
+    /// InsecureSkipTLSVerify skips the validity check for the server's certificate. This will make your HTTPS connections insecure.
+    pub insecure_skip_tls_verify: Option<bool>,
+
+    /// ProxyURL is the URL to the proxy to be used for all requests made by this client. URLs with "http", "https", and "socks5" schemes are supported.  If this configuration is not provided or the empty string, the client attempts to construct a proxy configuration from http_proxy and https_proxy environment variables. If these environment variables are not set, the client does not attempt to proxy requests.
+    pub proxy_url: Option<String>,
+
+    /// Server is the address of the kubernetes cluster (https://hostname:port).
+    pub server: String,
+
+    /// TLSServerName is used to check server certificate. If TLSServerName is empty, the hostname used to contact the server is used.
+    pub tls_server_name: Option<String>,
+}
+
+impl Cluster {
+    /// Constructs a `Cluster` from its required fields. Its optional fields are unset.
+    pub fn new(server: impl Into<String>) -> Self {
+        Cluster {
+            certificate_authority: None,
+            certificate_authority_data: None,
+            extensions: None,
+            insecure_skip_tls_verify: None,
+            proxy_url: None,
+            server: server.into(),
+            tls_server_name: None,
+        }
+    }
+}
+
+impl Cluster {
+    /// Returns this value with [`Cluster::certificate_authority`] set to the given value.
+    #[must_use]
+    pub fn with_certificate_authority(mut self, value: impl Into<String>) -> Self {
+        self.certificate_authority = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Cluster::certificate_authority_data`] set to the given value.
+    #[must_use]
+    pub fn with_certificate_authority_data(mut self, value: impl Into<crate::ByteString>) -> Self {
+        self.certificate_authority_data = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Cluster::extensions`] set to the given value.
+    #[must_use]
+    pub fn with_extensions(mut self, value: impl Into<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtension>>) -> Self {
+        self.extensions = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Cluster::insecure_skip_tls_verify`] set to the given value.
+    #[must_use]
+    pub fn with_insecure_skip_tls_verify(mut self, value: impl Into<bool>) -> Self {
+        self.insecure_skip_tls_verify = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Cluster::proxy_url`] set to the given value.
+    #[must_use]
+    pub fn with_proxy_url(mut self, value: impl Into<String>) -> Self {
+        self.proxy_url = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Cluster::server`] set to the given value.
+    #[must_use]
+    pub fn with_server(mut self, value: impl Into<String>) -> Self {
+        self.server = value.into();
+        self
+    }
+
+    /// Returns this value with [`Cluster::tls_server_name`] set to the given value.
+    #[must_use]
+    pub fn with_tls_server_name(mut self, value: impl Into<String>) -> Self {
+        self.tls_server_name = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for Cluster {}
+
+impl Cluster {
+    const FIELDS: &'static [&'static str] = &[
+        "certificate-authority",
+        "certificate-authority-data",
+        "extensions",
+        "insecure-skip-tls-verify",
+        "proxy-url",
+        "server",
+        "tls-server-name",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for Cluster {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = Cluster;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("Cluster")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_certificate_authority: Option<String> = None;
+                let mut value_certificate_authority_data: Option<crate::ByteString> = None;
+                let mut value_extensions: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtension>> = None;
+                let mut value_insecure_skip_tls_verify: Option<bool> = None;
+                let mut value_proxy_url: Option<String> = None;
+                let mut value_server: Option<String> = None;
+                let mut value_tls_server_name: Option<String> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Cluster", Cluster::FIELDS))? {
+                    match key {
+                        Some(0) => value_certificate_authority = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_certificate_authority_data = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_extensions = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_insecure_skip_tls_verify = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_proxy_url = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_server = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(6) => value_tls_server_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(Cluster {
+                    certificate_authority: value_certificate_authority,
+                    certificate_authority_data: value_certificate_authority_data,
+                    extensions: value_extensions,
+                    insecure_skip_tls_verify: value_insecure_skip_tls_verify,
+                    proxy_url: value_proxy_url,
+                    server: value_server.ok_or_else(|| crate::serde2::missing_field("Cluster", "server", &["server"]))?,
+                    tls_server_name: value_tls_server_name,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(Cluster {
+                    certificate_authority: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    certificate_authority_data: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    extensions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    insecure_skip_tls_verify: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    proxy_url: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    server: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    tls_server_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "Cluster",
+            Cluster::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, Cluster> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("Cluster")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_certificate_authority = false;
+                let mut has_certificate_authority_data = false;
+                let mut has_extensions = false;
+                let mut has_insecure_skip_tls_verify = false;
+                let mut has_proxy_url = false;
+                let mut has_server = false;
+                let mut has_tls_server_name = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Cluster", Cluster::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.certificate_authority))?; has_certificate_authority = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.certificate_authority_data))?; has_certificate_authority_data = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.extensions))?; has_extensions = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.insecure_skip_tls_verify))?; has_insecure_skip_tls_verify = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.proxy_url))?; has_proxy_url = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.server))?; has_server = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.tls_server_name))?; has_tls_server_name = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_certificate_authority {
+                    self.0.certificate_authority = None;
+                }
+                if !has_certificate_authority_data {
+                    self.0.certificate_authority_data = None;
+                }
+                if !has_extensions {
+                    self.0.extensions = None;
+                }
+                if !has_insecure_skip_tls_verify {
+                    self.0.insecure_skip_tls_verify = None;
+                }
+                if !has_proxy_url {
+                    self.0.proxy_url = None;
+                }
+                if !has_server {
+                    return Err(crate::serde2::missing_field("Cluster", "server", &["server"]));
+                }
+                if !has_tls_server_name {
+                    self.0.tls_server_name = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.certificate_authority))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.certificate_authority_data))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.extensions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.insecure_skip_tls_verify))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.proxy_url))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.server))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.tls_server_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "Cluster",
+            Cluster::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for Cluster {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("Cluster", 7)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "certificate-authority", &self.certificate_authority)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "certificate-authority-data", &self.certificate_authority_data)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "extensions", &self.extensions)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "insecure-skip-tls-verify", &self.insecure_skip_tls_verify)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "proxy-url", &self.proxy_url)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "server", &self.server)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tls-server-name", &self.tls_server_name)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            "Cluster",
+            1 +
+            self.certificate_authority.as_ref().map_or(0, |_| 1) +
+            self.certificate_authority_data.as_ref().map_or(0, |_| 1) +
+            self.extensions.as_ref().map_or(0, |_| 1) +
+            self.insecure_skip_tls_verify.as_ref().map_or(0, |_| 1) +
+            self.proxy_url.as_ref().map_or(0, |_| 1) +
+            self.tls_server_name.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.certificate_authority {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "certificate-authority", value)?;
+        }
+        if let Some(value) = &self.certificate_authority_data {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "certificate-authority-data", value)?;
+        }
+        if let Some(value) = &self.extensions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "extensions", value)?;
+        }
+        if let Some(value) = &self.insecure_skip_tls_verify {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "insecure-skip-tls-verify", value)?;
+        }
+        if let Some(value) = &self.proxy_url {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "proxy-url", value)?;
+        }
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "server", &self.server)?;
+        if let Some(value) = &self.tls_server_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tls-server-name", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`Cluster`]. Created with [`Cluster::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ClusterBuilder {
+    certificate_authority: Option<String>,
+    certificate_authority_data: Option<crate::ByteString>,
+    extensions: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtension>>,
+    insecure_skip_tls_verify: Option<bool>,
+    proxy_url: Option<String>,
+    server: Option<String>,
+    tls_server_name: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl Cluster {
+    /// Starts building a `Cluster`. Its required fields must be set before [`ClusterBuilder::build`] is called.
+    pub fn builder() -> ClusterBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ClusterBuilder {
+    /// Sets [`Cluster::certificate_authority`].
+    pub fn certificate_authority(mut self, value: impl Into<String>) -> Self {
+        self.certificate_authority = Some(value.into());
+        self
+    }
+
+    /// Sets [`Cluster::certificate_authority_data`].
+    pub fn certificate_authority_data(mut self, value: impl Into<crate::ByteString>) -> Self {
+        self.certificate_authority_data = Some(value.into());
+        self
+    }
+
+    /// Sets [`Cluster::extensions`].
+    pub fn extensions(mut self, value: impl Into<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtension>>) -> Self {
+        self.extensions = Some(value.into());
+        self
+    }
+
+    /// Sets [`Cluster::insecure_skip_tls_verify`].
+    pub fn insecure_skip_tls_verify(mut self, value: impl Into<bool>) -> Self {
+        self.insecure_skip_tls_verify = Some(value.into());
+        self
+    }
+
+    /// Sets [`Cluster::proxy_url`].
+    pub fn proxy_url(mut self, value: impl Into<String>) -> Self {
+        self.proxy_url = Some(value.into());
+        self
+    }
+
+    /// Sets [`Cluster::server`].
+    pub fn server(mut self, value: impl Into<String>) -> Self {
+        self.server = Some(value.into());
+        self
+    }
+
+    /// Sets [`Cluster::tls_server_name`].
+    pub fn tls_server_name(mut self, value: impl Into<String>) -> Self {
+        self.tls_server_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `Cluster`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<Cluster, crate::MissingFieldError> {
+        Ok(Cluster {
+            certificate_authority: self.certificate_authority,
+            certificate_authority_data: self.certificate_authority_data,
+            extensions: self.extensions,
+            insecure_skip_tls_verify: self.insecure_skip_tls_verify,
+            proxy_url: self.proxy_url,
+            server: self.server.ok_or_else(|| crate::MissingFieldError::new("Cluster", "server"))?,
+            tls_server_name: self.tls_server_name,
+        })
+    }
+}
+
+/// The apply configuration of [`Cluster`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ClusterApplyConfiguration {
+    /// See [`Cluster::certificate_authority`].
+    pub certificate_authority: Option<String>,
+
+    /// See [`Cluster::certificate_authority_data`].
+    pub certificate_authority_data: Option<crate::ByteString>,
+
+    /// See [`Cluster::extensions`].
+    pub extensions: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtensionApplyConfiguration>>,
+
+    /// See [`Cluster::insecure_skip_tls_verify`].
+    pub insecure_skip_tls_verify: Option<bool>,
+
+    /// See [`Cluster::proxy_url`].
+    pub proxy_url: Option<String>,
+
+    /// See [`Cluster::server`].
+    pub server: Option<String>,
+
+    /// See [`Cluster::tls_server_name`].
+    pub tls_server_name: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ClusterApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ClusterApplyConfiguration",
+            self.certificate_authority.as_ref().map_or(0, |_| 1) +
+            self.certificate_authority_data.as_ref().map_or(0, |_| 1) +
+            self.extensions.as_ref().map_or(0, |_| 1) +
+            self.insecure_skip_tls_verify.as_ref().map_or(0, |_| 1) +
+            self.proxy_url.as_ref().map_or(0, |_| 1) +
+            self.server.as_ref().map_or(0, |_| 1) +
+            self.tls_server_name.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.certificate_authority {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "certificate-authority", value)?;
+        }
+        if let Some(value) = &self.certificate_authority_data {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "certificate-authority-data", value)?;
+        }
+        if let Some(value) = &self.extensions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "extensions", value)?;
+        }
+        if let Some(value) = &self.insecure_skip_tls_verify {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "insecure-skip-tls-verify", value)?;
+        }
+        if let Some(value) = &self.proxy_url {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "proxy-url", value)?;
+        }
+        if let Some(value) = &self.server {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "server", value)?;
+        }
+        if let Some(value) = &self.tls_server_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tls-server-name", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for Cluster {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "client_go::tools::clientcmd::api::v1::Cluster".to_owned()
+        }
+        else {
+            "io.k8s.client-go.tools.clientcmd.api.v1.Cluster".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("Cluster contains information about how to communicate with a kubernetes cluster".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "certificate-authority".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("CertificateAuthority is the path to a cert file for the certificate authority.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "certificate-authority-data".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("CertificateAuthorityData contains PEM-encoded certificate authority certificates. Overrides CertificateAuthority".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            format: Some("byte".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "extensions".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Extensions holds additional information. This is useful for extenders so that reads and writes don't clobber unknown fields".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Array))),
+                            array: Some(Box::new(crate::schemars::schema::ArrayValidation {
+                                items: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(__gen.subschema_for::<crate::client_go::tools::clientcmd::api::v1::NamedExtension>()))),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "insecure-skip-tls-verify".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("InsecureSkipTLSVerify skips the validity check for the server's certificate. This will make your HTTPS connections insecure.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "proxy-url".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("ProxyURL is the URL to the proxy to be used for all requests made by this client. URLs with \"http\", \"https\", and \"socks5\" schemes are supported.  If this configuration is not provided or the empty string, the client attempts to construct a proxy configuration from http_proxy and https_proxy environment variables. If these environment variables are not set, the client does not attempt to proxy requests.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "server".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Server is the address of the kubernetes cluster (https://hostname:port).".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "tls-server-name".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("TLSServerName is used to check server certificate. If TLSServerName is empty, the hostname used to contact the server is used.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                required: std::array::IntoIter::new([
+                    "server",
+                ]).map(std::borrow::ToOwned::to_owned).collect(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for Cluster {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(Cluster {
+            certificate_authority: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            certificate_authority_data: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            extensions: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            insecure_skip_tls_verify: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            proxy_url: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            server: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            tls_server_name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -0,0 +1,524 @@
+// Generated from definition io.k8s.client-go.tools.clientcmd.api.v1.ExecConfig
+
+/// ExecConfig specifies a command to provide client credentials. The command is exec'd and outputs structured stdout holding credentials.
+///
+/// See the client.authentication.k8s.io API group for specifications of the exact input and output format
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ExecConfig {
+    /// Preferred input version of the ExecInfo. The returned ExecCredentials MUST use the same encoding version as the input.
+    pub api_version: Option<String>,
+
+    /// Arguments to pass to the command when executing it.
+    pub args: Option<Vec<String>>,
+
+    /// Command to execute.
+    pub command: String,
+
+    /// Env defines additional environment variables to expose to the process. These are unioned with the host's environment, as well as variables client-go uses to pass argument to the plugin.
+    pub env: Option<Vec<crate::client_go::tools::clientcmd::api::v1::ExecEnvVar>>,
+
+    /// This text is shown to the user when the executable doesn't seem to be present. For example, `brew install foo-cli` might be a good InstallHint for foo-cli on Mac OS systems.
+    pub install_hint: Option<String>,
+
+    /// ProvideClusterInfo determines whether or not to provide cluster information, which could potentially contain very large CA data, to this exec plugin as a part of the KUBERNETES_EXEC_INFO environment variable. By default, it is set to false. Package k8s.io/client-go/tools/auth/exec provides helper methods for reading this environment variable.
+    pub provide_cluster_info: Option<bool>,
+}
+
+impl ExecConfig {
+    /// Constructs a `ExecConfig` from its required fields. Its optional fields are unset.
+    pub fn new(command: impl Into<String>) -> Self {
+        ExecConfig {
+            api_version: None,
+            args: None,
+            command: command.into(),
+            env: None,
+            install_hint: None,
+            provide_cluster_info: None,
+        }
+    }
+}
+
+impl ExecConfig {
+    /// Returns this value with [`ExecConfig::api_version`] set to the given value.
+    #[must_use]
+    pub fn with_api_version(mut self, value: impl Into<String>) -> Self {
+        self.api_version = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ExecConfig::args`] set to the given value.
+    #[must_use]
+    pub fn with_args(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.args = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ExecConfig::command`] set to the given value.
+    #[must_use]
+    pub fn with_command(mut self, value: impl Into<String>) -> Self {
+        self.command = value.into();
+        self
+    }
+
+    /// Returns this value with [`ExecConfig::env`] set to the given value.
+    #[must_use]
+    pub fn with_env(mut self, value: impl Into<Vec<crate::client_go::tools::clientcmd::api::v1::ExecEnvVar>>) -> Self {
+        self.env = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ExecConfig::install_hint`] set to the given value.
+    #[must_use]
+    pub fn with_install_hint(mut self, value: impl Into<String>) -> Self {
+        self.install_hint = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ExecConfig::provide_cluster_info`] set to the given value.
+    #[must_use]
+    pub fn with_provide_cluster_info(mut self, value: impl Into<bool>) -> Self {
+        self.provide_cluster_info = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for ExecConfig {}
+
+impl ExecConfig {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "args",
+        "command",
+        "env",
+        "installHint",
+        "provideClusterInfo",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for ExecConfig {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = ExecConfig;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ExecConfig")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_api_version: Option<String> = None;
+                let mut value_args: Option<Vec<String>> = None;
+                let mut value_command: Option<String> = None;
+                let mut value_env: Option<Vec<crate::client_go::tools::clientcmd::api::v1::ExecEnvVar>> = None;
+                let mut value_install_hint: Option<String> = None;
+                let mut value_provide_cluster_info: Option<bool> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ExecConfig", ExecConfig::FIELDS))? {
+                    match key {
+                        Some(0) => value_api_version = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_args = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_command = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_env = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_install_hint = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_provide_cluster_info = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(ExecConfig {
+                    api_version: value_api_version,
+                    args: value_args,
+                    command: value_command.ok_or_else(|| crate::serde2::missing_field("ExecConfig", "command", &["command"]))?,
+                    env: value_env,
+                    install_hint: value_install_hint,
+                    provide_cluster_info: value_provide_cluster_info,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ExecConfig {
+                    api_version: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    args: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    command: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    env: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    install_hint: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    provide_cluster_info: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ExecConfig",
+            ExecConfig::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ExecConfig> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ExecConfig")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_api_version = false;
+                let mut has_args = false;
+                let mut has_command = false;
+                let mut has_env = false;
+                let mut has_install_hint = false;
+                let mut has_provide_cluster_info = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ExecConfig", ExecConfig::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.api_version))?; has_api_version = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.args))?; has_args = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.command))?; has_command = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.env))?; has_env = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.install_hint))?; has_install_hint = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.provide_cluster_info))?; has_provide_cluster_info = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_api_version {
+                    self.0.api_version = None;
+                }
+                if !has_args {
+                    self.0.args = None;
+                }
+                if !has_command {
+                    return Err(crate::serde2::missing_field("ExecConfig", "command", &["command"]));
+                }
+                if !has_env {
+                    self.0.env = None;
+                }
+                if !has_install_hint {
+                    self.0.install_hint = None;
+                }
+                if !has_provide_cluster_info {
+                    self.0.provide_cluster_info = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.api_version))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.args))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.command))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.env))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.install_hint))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.provide_cluster_info))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ExecConfig",
+            ExecConfig::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for ExecConfig {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ExecConfig", 6)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", &self.api_version)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "args", &self.args)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "command", &self.command)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "env", &self.env)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "installHint", &self.install_hint)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "provideClusterInfo", &self.provide_cluster_info)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            "ExecConfig",
+            1 +
+            self.api_version.as_ref().map_or(0, |_| 1) +
+            self.args.as_ref().map_or(0, |_| 1) +
+            self.env.as_ref().map_or(0, |_| 1) +
+            self.install_hint.as_ref().map_or(0, |_| 1) +
+            self.provide_cluster_info.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.api_version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", value)?;
+        }
+        if let Some(value) = &self.args {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "args", value)?;
+        }
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "command", &self.command)?;
+        if let Some(value) = &self.env {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "env", value)?;
+        }
+        if let Some(value) = &self.install_hint {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "installHint", value)?;
+        }
+        if let Some(value) = &self.provide_cluster_info {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "provideClusterInfo", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`ExecConfig`]. Created with [`ExecConfig::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ExecConfigBuilder {
+    api_version: Option<String>,
+    args: Option<Vec<String>>,
+    command: Option<String>,
+    env: Option<Vec<crate::client_go::tools::clientcmd::api::v1::ExecEnvVar>>,
+    install_hint: Option<String>,
+    provide_cluster_info: Option<bool>,
+}
+
+#[cfg(feature = "builder")]
+impl ExecConfig {
+    /// Starts building a `ExecConfig`. Its required fields must be set before [`ExecConfigBuilder::build`] is called.
+    pub fn builder() -> ExecConfigBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ExecConfigBuilder {
+    /// Sets [`ExecConfig::api_version`].
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`ExecConfig::args`].
+    pub fn args(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.args = Some(value.into());
+        self
+    }
+
+    /// Sets [`ExecConfig::command`].
+    pub fn command(mut self, value: impl Into<String>) -> Self {
+        self.command = Some(value.into());
+        self
+    }
+
+    /// Sets [`ExecConfig::env`].
+    pub fn env(mut self, value: impl Into<Vec<crate::client_go::tools::clientcmd::api::v1::ExecEnvVar>>) -> Self {
+        self.env = Some(value.into());
+        self
+    }
+
+    /// Sets [`ExecConfig::install_hint`].
+    pub fn install_hint(mut self, value: impl Into<String>) -> Self {
+        self.install_hint = Some(value.into());
+        self
+    }
+
+    /// Sets [`ExecConfig::provide_cluster_info`].
+    pub fn provide_cluster_info(mut self, value: impl Into<bool>) -> Self {
+        self.provide_cluster_info = Some(value.into());
+        self
+    }
+
+    /// Builds the `ExecConfig`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ExecConfig, crate::MissingFieldError> {
+        Ok(ExecConfig {
+            api_version: self.api_version,
+            args: self.args,
+            command: self.command.ok_or_else(|| crate::MissingFieldError::new("ExecConfig", "command"))?,
+            env: self.env,
+            install_hint: self.install_hint,
+            provide_cluster_info: self.provide_cluster_info,
+        })
+    }
+}
+
+/// The apply configuration of [`ExecConfig`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ExecConfigApplyConfiguration {
+    /// See [`ExecConfig::api_version`].
+    pub api_version: Option<String>,
+
+    /// See [`ExecConfig::args`].
+    pub args: Option<Vec<String>>,
+
+    /// See [`ExecConfig::command`].
+    pub command: Option<String>,
+
+    /// See [`ExecConfig::env`].
+    pub env: Option<Vec<crate::client_go::tools::clientcmd::api::v1::ExecEnvVarApplyConfiguration>>,
+
+    /// See [`ExecConfig::install_hint`].
+    pub install_hint: Option<String>,
+
+    /// See [`ExecConfig::provide_cluster_info`].
+    pub provide_cluster_info: Option<bool>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ExecConfigApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ExecConfigApplyConfiguration",
+            self.api_version.as_ref().map_or(0, |_| 1) +
+            self.args.as_ref().map_or(0, |_| 1) +
+            self.command.as_ref().map_or(0, |_| 1) +
+            self.env.as_ref().map_or(0, |_| 1) +
+            self.install_hint.as_ref().map_or(0, |_| 1) +
+            self.provide_cluster_info.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.api_version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", value)?;
+        }
+        if let Some(value) = &self.args {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "args", value)?;
+        }
+        if let Some(value) = &self.command {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "command", value)?;
+        }
+        if let Some(value) = &self.env {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "env", value)?;
+        }
+        if let Some(value) = &self.install_hint {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "installHint", value)?;
+        }
+        if let Some(value) = &self.provide_cluster_info {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "provideClusterInfo", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for ExecConfig {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "client_go::tools::clientcmd::api::v1::ExecConfig".to_owned()
+        }
+        else {
+            "io.k8s.client-go.tools.clientcmd.api.v1.ExecConfig".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("ExecConfig specifies a command to provide client credentials. The command is exec'd and outputs structured stdout holding credentials.\n\nSee the client.authentication.k8s.io API group for specifications of the exact input and output format".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "apiVersion".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Preferred input version of the ExecInfo. The returned ExecCredentials MUST use the same encoding version as the input.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "args".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Arguments to pass to the command when executing it.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Array))),
+                            array: Some(Box::new(crate::schemars::schema::ArrayValidation {
+                                items: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(
+                                    crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                                        instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                                        ..Default::default()
+                                    })
+                                ))),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "command".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Command to execute.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "env".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Env defines additional environment variables to expose to the process. These are unioned with the host's environment, as well as variables client-go uses to pass argument to the plugin.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Array))),
+                            array: Some(Box::new(crate::schemars::schema::ArrayValidation {
+                                items: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(__gen.subschema_for::<crate::client_go::tools::clientcmd::api::v1::ExecEnvVar>()))),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "installHint".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("This text is shown to the user when the executable doesn't seem to be present. For example, `brew install foo-cli` might be a good InstallHint for foo-cli on Mac OS systems.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "provideClusterInfo".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("ProvideClusterInfo determines whether or not to provide cluster information, which could potentially contain very large CA data, to this exec plugin as a part of the KUBERNETES_EXEC_INFO environment variable. By default, it is set to false. Package k8s.io/client-go/tools/auth/exec provides helper methods for reading this environment variable.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                required: std::array::IntoIter::new([
+                    "command",
+                ]).map(std::borrow::ToOwned::to_owned).collect(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ExecConfig {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ExecConfig {
+            api_version: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            args: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            command: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            env: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            install_hint: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            provide_cluster_info: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
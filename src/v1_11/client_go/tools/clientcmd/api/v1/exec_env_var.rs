@@ -0,0 +1,281 @@
+// Generated from definition io.k8s.client-go.tools.clientcmd.api.v1.ExecEnvVar
+
+/// ExecEnvVar is used for setting environment variables when executing an exec-based credential plugin.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ExecEnvVar {
+    pub name: String,
+
+    pub value: String,
+}
+
+impl ExecEnvVar {
+    /// Constructs a `ExecEnvVar` from its required fields. Its optional fields are unset.
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        ExecEnvVar {
+            name: name.into(),
+            value: value.into(),
+        }
+    }
+}
+
+impl ExecEnvVar {
+    /// Returns this value with [`ExecEnvVar::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
+
+    /// Returns this value with [`ExecEnvVar::value`] set to the given value.
+    #[must_use]
+    pub fn with_value(mut self, value: impl Into<String>) -> Self {
+        self.value = value.into();
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for ExecEnvVar {}
+
+impl ExecEnvVar {
+    const FIELDS: &'static [&'static str] = &[
+        "name",
+        "value",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for ExecEnvVar {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = ExecEnvVar;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ExecEnvVar")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_name: Option<String> = None;
+                let mut value_value: Option<String> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ExecEnvVar", ExecEnvVar::FIELDS))? {
+                    match key {
+                        Some(0) => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_value = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(ExecEnvVar {
+                    name: value_name.ok_or_else(|| crate::serde2::missing_field("ExecEnvVar", "name", &["name", "value"]))?,
+                    value: value_value.ok_or_else(|| crate::serde2::missing_field("ExecEnvVar", "value", &["name", "value"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ExecEnvVar {
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    value: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ExecEnvVar",
+            ExecEnvVar::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ExecEnvVar> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ExecEnvVar")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_name = false;
+                let mut has_value = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ExecEnvVar", ExecEnvVar::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.name))?; has_name = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.value))?; has_value = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_name {
+                    return Err(crate::serde2::missing_field("ExecEnvVar", "name", &["name", "value"]));
+                }
+                if !has_value {
+                    return Err(crate::serde2::missing_field("ExecEnvVar", "value", &["name", "value"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.value))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ExecEnvVar",
+            ExecEnvVar::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for ExecEnvVar {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ExecEnvVar", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "value", &self.value)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            "ExecEnvVar",
+            2,
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "value", &self.value)?;
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`ExecEnvVar`]. Created with [`ExecEnvVar::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ExecEnvVarBuilder {
+    name: Option<String>,
+    value: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl ExecEnvVar {
+    /// Starts building a `ExecEnvVar`. Its required fields must be set before [`ExecEnvVarBuilder::build`] is called.
+    pub fn builder() -> ExecEnvVarBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ExecEnvVarBuilder {
+    /// Sets [`ExecEnvVar::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`ExecEnvVar::value`].
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Builds the `ExecEnvVar`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ExecEnvVar, crate::MissingFieldError> {
+        Ok(ExecEnvVar {
+            name: self.name.ok_or_else(|| crate::MissingFieldError::new("ExecEnvVar", "name"))?,
+            value: self.value.ok_or_else(|| crate::MissingFieldError::new("ExecEnvVar", "value"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`ExecEnvVar`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ExecEnvVarApplyConfiguration {
+    /// See [`ExecEnvVar::name`].
+    pub name: Option<String>,
+
+    /// See [`ExecEnvVar::value`].
+    pub value: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ExecEnvVarApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ExecEnvVarApplyConfiguration",
+            self.name.as_ref().map_or(0, |_| 1) +
+            self.value.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        if let Some(value) = &self.value {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "value", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for ExecEnvVar {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "client_go::tools::clientcmd::api::v1::ExecEnvVar".to_owned()
+        }
+        else {
+            "io.k8s.client-go.tools.clientcmd.api.v1.ExecEnvVar".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("ExecEnvVar is used for setting environment variables when executing an exec-based credential plugin.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "name".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "value".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                required: std::array::IntoIter::new([
+                    "name",
+                    "value",
+                ]).map(std::borrow::ToOwned::to_owned).collect(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ExecEnvVar {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ExecEnvVar {
+            name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            value: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
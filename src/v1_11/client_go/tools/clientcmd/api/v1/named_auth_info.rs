@@ -0,0 +1,291 @@
+// Generated from definition io.k8s.client-go.tools.clientcmd.api.v1.NamedAuthInfo
+
+/// NamedAuthInfo relates nicknames to auth information
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NamedAuthInfo {
+    /// Name is the nickname for this AuthInfo
+    pub name: String,
+
+    /// AuthInfo holds the auth information
+    pub user: crate::client_go::tools::clientcmd::api::v1::AuthInfo,
+}
+
+impl NamedAuthInfo {
+    /// Constructs a `NamedAuthInfo` from its required fields. Its optional fields are unset.
+    pub fn new(name: impl Into<String>, user: impl Into<crate::client_go::tools::clientcmd::api::v1::AuthInfo>) -> Self {
+        NamedAuthInfo {
+            name: name.into(),
+            user: user.into(),
+        }
+    }
+}
+
+impl NamedAuthInfo {
+    /// Returns this value with [`NamedAuthInfo::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
+
+    /// Returns this value with [`NamedAuthInfo::user`] set to the given value.
+    #[must_use]
+    pub fn with_user(mut self, value: impl Into<crate::client_go::tools::clientcmd::api::v1::AuthInfo>) -> Self {
+        self.user = value.into();
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for NamedAuthInfo {}
+
+impl NamedAuthInfo {
+    const FIELDS: &'static [&'static str] = &[
+        "name",
+        "user",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for NamedAuthInfo {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = NamedAuthInfo;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("NamedAuthInfo")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_name: Option<String> = None;
+                let mut value_user: Option<crate::client_go::tools::clientcmd::api::v1::AuthInfo> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NamedAuthInfo", NamedAuthInfo::FIELDS))? {
+                    match key {
+                        Some(0) => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_user = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(NamedAuthInfo {
+                    name: value_name.ok_or_else(|| crate::serde2::missing_field("NamedAuthInfo", "name", &["name", "user"]))?,
+                    user: value_user.ok_or_else(|| crate::serde2::missing_field("NamedAuthInfo", "user", &["name", "user"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(NamedAuthInfo {
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    user: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "NamedAuthInfo",
+            NamedAuthInfo::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, NamedAuthInfo> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("NamedAuthInfo")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_name = false;
+                let mut has_user = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NamedAuthInfo", NamedAuthInfo::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.name))?; has_name = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.user))?; has_user = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_name {
+                    return Err(crate::serde2::missing_field("NamedAuthInfo", "name", &["name", "user"]));
+                }
+                if !has_user {
+                    return Err(crate::serde2::missing_field("NamedAuthInfo", "user", &["name", "user"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.user))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "NamedAuthInfo",
+            NamedAuthInfo::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for NamedAuthInfo {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("NamedAuthInfo", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "user", &self.user)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            "NamedAuthInfo",
+            2,
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "user", &self.user)?;
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`NamedAuthInfo`]. Created with [`NamedAuthInfo::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct NamedAuthInfoBuilder {
+    name: Option<String>,
+    user: Option<crate::client_go::tools::clientcmd::api::v1::AuthInfo>,
+}
+
+#[cfg(feature = "builder")]
+impl NamedAuthInfo {
+    /// Starts building a `NamedAuthInfo`. Its required fields must be set before [`NamedAuthInfoBuilder::build`] is called.
+    pub fn builder() -> NamedAuthInfoBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl NamedAuthInfoBuilder {
+    /// Sets [`NamedAuthInfo::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`NamedAuthInfo::user`].
+    pub fn user(mut self, value: impl Into<crate::client_go::tools::clientcmd::api::v1::AuthInfo>) -> Self {
+        self.user = Some(value.into());
+        self
+    }
+
+    /// Builds the `NamedAuthInfo`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<NamedAuthInfo, crate::MissingFieldError> {
+        Ok(NamedAuthInfo {
+            name: self.name.ok_or_else(|| crate::MissingFieldError::new("NamedAuthInfo", "name"))?,
+            user: self.user.ok_or_else(|| crate::MissingFieldError::new("NamedAuthInfo", "user"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`NamedAuthInfo`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NamedAuthInfoApplyConfiguration {
+    /// See [`NamedAuthInfo::name`].
+    pub name: Option<String>,
+
+    /// See [`NamedAuthInfo::user`].
+    pub user: Option<crate::client_go::tools::clientcmd::api::v1::AuthInfoApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for NamedAuthInfoApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "NamedAuthInfoApplyConfiguration",
+            self.name.as_ref().map_or(0, |_| 1) +
+            self.user.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        if let Some(value) = &self.user {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "user", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for NamedAuthInfo {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "client_go::tools::clientcmd::api::v1::NamedAuthInfo".to_owned()
+        }
+        else {
+            "io.k8s.client-go.tools.clientcmd.api.v1.NamedAuthInfo".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("NamedAuthInfo relates nicknames to auth information".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "name".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Name is the nickname for this AuthInfo".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "user".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::client_go::tools::clientcmd::api::v1::AuthInfo>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("AuthInfo holds the auth information".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties
+                },
+                required: std::array::IntoIter::new([
+                    "name",
+                    "user",
+                ]).map(std::borrow::ToOwned::to_owned).collect(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for NamedAuthInfo {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(NamedAuthInfo {
+            name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            user: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -0,0 +1,291 @@
+// Generated from definition io.k8s.client-go.tools.clientcmd.api.v1.NamedCluster
+
+/// NamedCluster relates nicknames to cluster information
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NamedCluster {
+    /// Cluster holds the cluster information
+    pub cluster: crate::client_go::tools::clientcmd::api::v1::Cluster,
+
+    /// Name is the nickname for this Cluster
+    pub name: String,
+}
+
+impl NamedCluster {
+    /// Constructs a `NamedCluster` from its required fields. Its optional fields are unset.
+    pub fn new(cluster: impl Into<crate::client_go::tools::clientcmd::api::v1::Cluster>, name: impl Into<String>) -> Self {
+        NamedCluster {
+            cluster: cluster.into(),
+            name: name.into(),
+        }
+    }
+}
+
+impl NamedCluster {
+    /// Returns this value with [`NamedCluster::cluster`] set to the given value.
+    #[must_use]
+    pub fn with_cluster(mut self, value: impl Into<crate::client_go::tools::clientcmd::api::v1::Cluster>) -> Self {
+        self.cluster = value.into();
+        self
+    }
+
+    /// Returns this value with [`NamedCluster::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for NamedCluster {}
+
+impl NamedCluster {
+    const FIELDS: &'static [&'static str] = &[
+        "cluster",
+        "name",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for NamedCluster {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = NamedCluster;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("NamedCluster")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_cluster: Option<crate::client_go::tools::clientcmd::api::v1::Cluster> = None;
+                let mut value_name: Option<String> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NamedCluster", NamedCluster::FIELDS))? {
+                    match key {
+                        Some(0) => value_cluster = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(NamedCluster {
+                    cluster: value_cluster.ok_or_else(|| crate::serde2::missing_field("NamedCluster", "cluster", &["cluster", "name"]))?,
+                    name: value_name.ok_or_else(|| crate::serde2::missing_field("NamedCluster", "name", &["cluster", "name"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(NamedCluster {
+                    cluster: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "NamedCluster",
+            NamedCluster::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, NamedCluster> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("NamedCluster")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_cluster = false;
+                let mut has_name = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NamedCluster", NamedCluster::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.cluster))?; has_cluster = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.name))?; has_name = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_cluster {
+                    return Err(crate::serde2::missing_field("NamedCluster", "cluster", &["cluster", "name"]));
+                }
+                if !has_name {
+                    return Err(crate::serde2::missing_field("NamedCluster", "name", &["cluster", "name"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.cluster))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "NamedCluster",
+            NamedCluster::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for NamedCluster {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("NamedCluster", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cluster", &self.cluster)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            "NamedCluster",
+            2,
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cluster", &self.cluster)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`NamedCluster`]. Created with [`NamedCluster::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct NamedClusterBuilder {
+    cluster: Option<crate::client_go::tools::clientcmd::api::v1::Cluster>,
+    name: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl NamedCluster {
+    /// Starts building a `NamedCluster`. Its required fields must be set before [`NamedClusterBuilder::build`] is called.
+    pub fn builder() -> NamedClusterBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl NamedClusterBuilder {
+    /// Sets [`NamedCluster::cluster`].
+    pub fn cluster(mut self, value: impl Into<crate::client_go::tools::clientcmd::api::v1::Cluster>) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Sets [`NamedCluster::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `NamedCluster`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<NamedCluster, crate::MissingFieldError> {
+        Ok(NamedCluster {
+            cluster: self.cluster.ok_or_else(|| crate::MissingFieldError::new("NamedCluster", "cluster"))?,
+            name: self.name.ok_or_else(|| crate::MissingFieldError::new("NamedCluster", "name"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`NamedCluster`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NamedClusterApplyConfiguration {
+    /// See [`NamedCluster::cluster`].
+    pub cluster: Option<crate::client_go::tools::clientcmd::api::v1::ClusterApplyConfiguration>,
+
+    /// See [`NamedCluster::name`].
+    pub name: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for NamedClusterApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "NamedClusterApplyConfiguration",
+            self.cluster.as_ref().map_or(0, |_| 1) +
+            self.name.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.cluster {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cluster", value)?;
+        }
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for NamedCluster {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "client_go::tools::clientcmd::api::v1::NamedCluster".to_owned()
+        }
+        else {
+            "io.k8s.client-go.tools.clientcmd.api.v1.NamedCluster".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("NamedCluster relates nicknames to cluster information".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "cluster".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::client_go::tools::clientcmd::api::v1::Cluster>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Cluster holds the cluster information".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
+                        "name".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Name is the nickname for this Cluster".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                required: std::array::IntoIter::new([
+                    "cluster",
+                    "name",
+                ]).map(std::borrow::ToOwned::to_owned).collect(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for NamedCluster {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(NamedCluster {
+            cluster: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
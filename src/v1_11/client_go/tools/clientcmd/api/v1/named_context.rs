@@ -0,0 +1,291 @@
+// Generated from definition io.k8s.client-go.tools.clientcmd.api.v1.NamedContext
+
+/// NamedContext relates nicknames to context information
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NamedContext {
+    /// Context holds the context information
+    pub context: crate::client_go::tools::clientcmd::api::v1::Context,
+
+    /// Name is the nickname for this Context
+    pub name: String,
+}
+
+impl NamedContext {
+    /// Constructs a `NamedContext` from its required fields. Its optional fields are unset.
+    pub fn new(context: impl Into<crate::client_go::tools::clientcmd::api::v1::Context>, name: impl Into<String>) -> Self {
+        NamedContext {
+            context: context.into(),
+            name: name.into(),
+        }
+    }
+}
+
+impl NamedContext {
+    /// Returns this value with [`NamedContext::context`] set to the given value.
+    #[must_use]
+    pub fn with_context(mut self, value: impl Into<crate::client_go::tools::clientcmd::api::v1::Context>) -> Self {
+        self.context = value.into();
+        self
+    }
+
+    /// Returns this value with [`NamedContext::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for NamedContext {}
+
+impl NamedContext {
+    const FIELDS: &'static [&'static str] = &[
+        "context",
+        "name",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for NamedContext {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = NamedContext;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("NamedContext")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_context: Option<crate::client_go::tools::clientcmd::api::v1::Context> = None;
+                let mut value_name: Option<String> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NamedContext", NamedContext::FIELDS))? {
+                    match key {
+                        Some(0) => value_context = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(NamedContext {
+                    context: value_context.ok_or_else(|| crate::serde2::missing_field("NamedContext", "context", &["context", "name"]))?,
+                    name: value_name.ok_or_else(|| crate::serde2::missing_field("NamedContext", "name", &["context", "name"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(NamedContext {
+                    context: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "NamedContext",
+            NamedContext::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, NamedContext> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("NamedContext")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_context = false;
+                let mut has_name = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NamedContext", NamedContext::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.context))?; has_context = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.name))?; has_name = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_context {
+                    return Err(crate::serde2::missing_field("NamedContext", "context", &["context", "name"]));
+                }
+                if !has_name {
+                    return Err(crate::serde2::missing_field("NamedContext", "name", &["context", "name"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.context))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "NamedContext",
+            NamedContext::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for NamedContext {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("NamedContext", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "context", &self.context)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            "NamedContext",
+            2,
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "context", &self.context)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`NamedContext`]. Created with [`NamedContext::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct NamedContextBuilder {
+    context: Option<crate::client_go::tools::clientcmd::api::v1::Context>,
+    name: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl NamedContext {
+    /// Starts building a `NamedContext`. Its required fields must be set before [`NamedContextBuilder::build`] is called.
+    pub fn builder() -> NamedContextBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl NamedContextBuilder {
+    /// Sets [`NamedContext::context`].
+    pub fn context(mut self, value: impl Into<crate::client_go::tools::clientcmd::api::v1::Context>) -> Self {
+        self.context = Some(value.into());
+        self
+    }
+
+    /// Sets [`NamedContext::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `NamedContext`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<NamedContext, crate::MissingFieldError> {
+        Ok(NamedContext {
+            context: self.context.ok_or_else(|| crate::MissingFieldError::new("NamedContext", "context"))?,
+            name: self.name.ok_or_else(|| crate::MissingFieldError::new("NamedContext", "name"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`NamedContext`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NamedContextApplyConfiguration {
+    /// See [`NamedContext::context`].
+    pub context: Option<crate::client_go::tools::clientcmd::api::v1::ContextApplyConfiguration>,
+
+    /// See [`NamedContext::name`].
+    pub name: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for NamedContextApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "NamedContextApplyConfiguration",
+            self.context.as_ref().map_or(0, |_| 1) +
+            self.name.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.context {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "context", value)?;
+        }
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for NamedContext {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "client_go::tools::clientcmd::api::v1::NamedContext".to_owned()
+        }
+        else {
+            "io.k8s.client-go.tools.clientcmd.api.v1.NamedContext".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("NamedContext relates nicknames to context information".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "context".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::client_go::tools::clientcmd::api::v1::Context>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Context holds the context information".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
+                        "name".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Name is the nickname for this Context".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                required: std::array::IntoIter::new([
+                    "context",
+                    "name",
+                ]).map(std::borrow::ToOwned::to_owned).collect(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for NamedContext {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(NamedContext {
+            context: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
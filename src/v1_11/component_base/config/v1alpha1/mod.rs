@@ -0,0 +1,14 @@
+
+mod client_connection_configuration;
+pub use self::client_connection_configuration::ClientConnectionConfiguration;
+#[cfg(feature = "builder")]
+pub use self::client_connection_configuration::ClientConnectionConfigurationBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::client_connection_configuration::ClientConnectionConfigurationApplyConfiguration;
+
+mod leader_election_configuration;
+pub use self::leader_election_configuration::LeaderElectionConfiguration;
+#[cfg(feature = "builder")]
+pub use self::leader_election_configuration::LeaderElectionConfigurationBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::leader_election_configuration::LeaderElectionConfigurationApplyConfiguration;
@@ -1,17 +1,37 @@
 
 mod api_service;
 pub use self::api_service::APIService;
+#[cfg(feature = "builder")]
+pub use self::api_service::APIServiceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::api_service::APIServiceApplyConfiguration;
 #[cfg(feature = "api")] pub use self::api_service::{ReadAPIServiceOptional, ReadAPIServiceResponse};
 #[cfg(feature = "api")] pub use self::api_service::{ReadAPIServiceStatusOptional, ReadAPIServiceStatusResponse};
 
 mod api_service_condition;
 pub use self::api_service_condition::APIServiceCondition;
+#[cfg(feature = "builder")]
+pub use self::api_service_condition::APIServiceConditionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::api_service_condition::APIServiceConditionApplyConfiguration;
 
 mod api_service_spec;
 pub use self::api_service_spec::APIServiceSpec;
+#[cfg(feature = "builder")]
+pub use self::api_service_spec::APIServiceSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::api_service_spec::APIServiceSpecApplyConfiguration;
 
 mod api_service_status;
 pub use self::api_service_status::APIServiceStatus;
+#[cfg(feature = "builder")]
+pub use self::api_service_status::APIServiceStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::api_service_status::APIServiceStatusApplyConfiguration;
 
 mod service_reference;
 pub use self::service_reference::ServiceReference;
+#[cfg(feature = "builder")]
+pub use self::service_reference::ServiceReferenceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::service_reference::ServiceReferenceApplyConfiguration;
@@ -1,80 +1,118 @@
 // Generated from definition io.k8s.kube-aggregator.pkg.apis.apiregistration.v1beta1.APIServiceStatus
 
 /// APIServiceStatus contains derived information about an API server
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct APIServiceStatus {
     /// Current service state of apiService.
     pub conditions: Option<Vec<crate::kube_aggregator::pkg::apis::apiregistration::v1beta1::APIServiceCondition>>,
 }
 
+impl APIServiceStatus {
+    /// Returns this value with [`APIServiceStatus::conditions`] set to the given value.
+    #[must_use]
+    pub fn with_conditions(mut self, value: impl Into<Vec<crate::kube_aggregator::pkg::apis::apiregistration::v1beta1::APIServiceCondition>>) -> Self {
+        self.conditions = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for APIServiceStatus {}
+
+impl APIServiceStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "conditions",
+    ];
+}
+
 impl<'de> crate::serde::Deserialize<'de> for APIServiceStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_conditions,
-            Other,
-        }
+        struct Visitor;
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = APIServiceStatus;
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("APIServiceStatus")
+            }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_conditions: Option<Vec<crate::kube_aggregator::pkg::apis::apiregistration::v1beta1::APIServiceCondition>> = None;
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "conditions" => Field::Key_conditions,
-                            _ => Field::Other,
-                        })
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("APIServiceStatus", APIServiceStatus::FIELDS))? {
+                    match key {
+                        Some(0) => value_conditions = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
-                deserializer.deserialize_identifier(Visitor)
+                Ok(APIServiceStatus {
+                    conditions: value_conditions,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(APIServiceStatus {
+                    conditions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                })
             }
         }
 
-        struct Visitor;
+        deserializer.deserialize_struct(
+            "APIServiceStatus",
+            APIServiceStatus::FIELDS,
+            Visitor,
+        )
+    }
 
-        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-            type Value = APIServiceStatus;
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, APIServiceStatus> {
+            type Value = ();
 
             fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 f.write_str("APIServiceStatus")
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
-                let mut value_conditions: Option<Vec<crate::kube_aggregator::pkg::apis::apiregistration::v1beta1::APIServiceCondition>> = None;
+                let mut has_conditions = false;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("APIServiceStatus", APIServiceStatus::FIELDS))? {
                     match key {
-                        Field::Key_conditions => value_conditions = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.conditions))?; has_conditions = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
+                if !has_conditions {
+                    self.0.conditions = None;
+                }
 
-                Ok(APIServiceStatus {
-                    conditions: value_conditions,
-                })
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.conditions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
             }
         }
 
         deserializer.deserialize_struct(
             "APIServiceStatus",
-            &[
-                "conditions",
-            ],
-            Visitor,
+            APIServiceStatus::FIELDS,
+            InPlaceVisitor(place),
         )
     }
 }
 
 impl crate::serde::Serialize for APIServiceStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("APIServiceStatus", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conditions", &self.conditions)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "APIServiceStatus",
             self.conditions.as_ref().map_or(0, |_| 1),
@@ -86,10 +124,69 @@ impl crate::serde::Serialize for APIServiceStatus {
     }
 }
 
+/// A builder of [`APIServiceStatus`]. Created with [`APIServiceStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct APIServiceStatusBuilder {
+    conditions: Option<Vec<crate::kube_aggregator::pkg::apis::apiregistration::v1beta1::APIServiceCondition>>,
+}
+
+#[cfg(feature = "builder")]
+impl APIServiceStatus {
+    /// Starts building a `APIServiceStatus`. Its required fields must be set before [`APIServiceStatusBuilder::build`] is called.
+    pub fn builder() -> APIServiceStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl APIServiceStatusBuilder {
+    /// Sets [`APIServiceStatus::conditions`].
+    pub fn conditions(mut self, value: impl Into<Vec<crate::kube_aggregator::pkg::apis::apiregistration::v1beta1::APIServiceCondition>>) -> Self {
+        self.conditions = Some(value.into());
+        self
+    }
+
+    /// Builds the `APIServiceStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<APIServiceStatus, crate::MissingFieldError> {
+        Ok(APIServiceStatus {
+            conditions: self.conditions,
+        })
+    }
+}
+
+/// The apply configuration of [`APIServiceStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct APIServiceStatusApplyConfiguration {
+    /// See [`APIServiceStatus::conditions`].
+    pub conditions: Option<Vec<crate::kube_aggregator::pkg::apis::apiregistration::v1beta1::APIServiceConditionApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for APIServiceStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "APIServiceStatusApplyConfiguration",
+            self.conditions.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.conditions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conditions", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for APIServiceStatus {
     fn schema_name() -> String {
-        "io.k8s.kube-aggregator.pkg.apis.apiregistration.v1beta1.APIServiceStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "kube_aggregator::pkg::apis::apiregistration::v1beta1::APIServiceStatus".to_owned()
+        }
+        else {
+            "io.k8s.kube-aggregator.pkg.apis.apiregistration.v1beta1.APIServiceStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -100,8 +197,9 @@ impl crate::schemars::JsonSchema for APIServiceStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "conditions".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -115,11 +213,21 @@ impl crate::schemars::JsonSchema for APIServiceStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for APIServiceStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(APIServiceStatus {
+            conditions: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
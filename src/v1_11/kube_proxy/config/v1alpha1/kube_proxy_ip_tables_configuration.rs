@@ -0,0 +1,393 @@
+// Generated from definition io.k8s.kube-proxy.config.v1alpha1.KubeProxyIPTablesConfiguration
+
+/// KubeProxyIPTablesConfiguration contains iptables-related configuration details for the Kubernetes proxy server.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct KubeProxyIPTablesConfiguration {
+    /// masqueradeAll tells kube-proxy to SNAT everything if using the pure iptables proxy mode.
+    pub masquerade_all: Option<bool>,
+
+    /// masqueradeBit is the bit of the iptables fwmark space to use for SNAT if using the pure iptables proxy mode. Values must be within the range \[0, 31\].
+    pub masquerade_bit: Option<i32>,
+
+    /// minSyncPeriod is the minimum period that iptables rules are refreshed (e.g. '5s', '1m', '2h22m').
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub min_sync_period: Option<String>,
+
+    /// syncPeriod is the period that iptables rules are refreshed (e.g. '5s', '1m', '2h22m').  Must be greater than 0.
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub sync_period: Option<String>,
+}
+
+impl KubeProxyIPTablesConfiguration {
+    /// Returns this value with [`KubeProxyIPTablesConfiguration::masquerade_all`] set to the given value.
+    #[must_use]
+    pub fn with_masquerade_all(mut self, value: impl Into<bool>) -> Self {
+        self.masquerade_all = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyIPTablesConfiguration::masquerade_bit`] set to the given value.
+    #[must_use]
+    pub fn with_masquerade_bit(mut self, value: impl Into<i32>) -> Self {
+        self.masquerade_bit = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyIPTablesConfiguration::min_sync_period`] set to the given value.
+    #[must_use]
+    pub fn with_min_sync_period(mut self, value: impl Into<String>) -> Self {
+        self.min_sync_period = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyIPTablesConfiguration::sync_period`] set to the given value.
+    #[must_use]
+    pub fn with_sync_period(mut self, value: impl Into<String>) -> Self {
+        self.sync_period = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for KubeProxyIPTablesConfiguration {}
+
+impl KubeProxyIPTablesConfiguration {
+    const FIELDS: &'static [&'static str] = &[
+        "masqueradeAll",
+        "masqueradeBit",
+        "minSyncPeriod",
+        "syncPeriod",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for KubeProxyIPTablesConfiguration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = KubeProxyIPTablesConfiguration;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("KubeProxyIPTablesConfiguration")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_masquerade_all: Option<bool> = None;
+                let mut value_masquerade_bit: Option<i32> = None;
+                let mut value_min_sync_period: Option<String> = None;
+                let mut value_sync_period: Option<String> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("KubeProxyIPTablesConfiguration", KubeProxyIPTablesConfiguration::FIELDS))? {
+                    match key {
+                        Some(0) => value_masquerade_all = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_masquerade_bit = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_min_sync_period = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_sync_period = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(KubeProxyIPTablesConfiguration {
+                    masquerade_all: value_masquerade_all,
+                    masquerade_bit: value_masquerade_bit,
+                    min_sync_period: value_min_sync_period,
+                    sync_period: value_sync_period,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(KubeProxyIPTablesConfiguration {
+                    masquerade_all: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    masquerade_bit: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    min_sync_period: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    sync_period: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "KubeProxyIPTablesConfiguration",
+            KubeProxyIPTablesConfiguration::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, KubeProxyIPTablesConfiguration> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("KubeProxyIPTablesConfiguration")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_masquerade_all = false;
+                let mut has_masquerade_bit = false;
+                let mut has_min_sync_period = false;
+                let mut has_sync_period = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("KubeProxyIPTablesConfiguration", KubeProxyIPTablesConfiguration::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.masquerade_all))?; has_masquerade_all = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.masquerade_bit))?; has_masquerade_bit = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.min_sync_period))?; has_min_sync_period = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.sync_period))?; has_sync_period = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_masquerade_all {
+                    self.0.masquerade_all = None;
+                }
+                if !has_masquerade_bit {
+                    self.0.masquerade_bit = None;
+                }
+                if !has_min_sync_period {
+                    self.0.min_sync_period = None;
+                }
+                if !has_sync_period {
+                    self.0.sync_period = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.masquerade_all))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.masquerade_bit))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.min_sync_period))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.sync_period))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "KubeProxyIPTablesConfiguration",
+            KubeProxyIPTablesConfiguration::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for KubeProxyIPTablesConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("KubeProxyIPTablesConfiguration", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "masqueradeAll", &self.masquerade_all)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "masqueradeBit", &self.masquerade_bit)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "minSyncPeriod", &self.min_sync_period)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "syncPeriod", &self.sync_period)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            "KubeProxyIPTablesConfiguration",
+            self.masquerade_all.as_ref().map_or(0, |_| 1) +
+            self.masquerade_bit.as_ref().map_or(0, |_| 1) +
+            self.min_sync_period.as_ref().map_or(0, |_| 1) +
+            self.sync_period.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.masquerade_all {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "masqueradeAll", value)?;
+        }
+        if let Some(value) = &self.masquerade_bit {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "masqueradeBit", value)?;
+        }
+        if let Some(value) = &self.min_sync_period {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "minSyncPeriod", value)?;
+        }
+        if let Some(value) = &self.sync_period {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "syncPeriod", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`KubeProxyIPTablesConfiguration`]. Created with [`KubeProxyIPTablesConfiguration::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct KubeProxyIPTablesConfigurationBuilder {
+    masquerade_all: Option<bool>,
+    masquerade_bit: Option<i32>,
+    min_sync_period: Option<String>,
+    sync_period: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl KubeProxyIPTablesConfiguration {
+    /// Starts building a `KubeProxyIPTablesConfiguration`. Its required fields must be set before [`KubeProxyIPTablesConfigurationBuilder::build`] is called.
+    pub fn builder() -> KubeProxyIPTablesConfigurationBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl KubeProxyIPTablesConfigurationBuilder {
+    /// Sets [`KubeProxyIPTablesConfiguration::masquerade_all`].
+    pub fn masquerade_all(mut self, value: impl Into<bool>) -> Self {
+        self.masquerade_all = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyIPTablesConfiguration::masquerade_bit`].
+    pub fn masquerade_bit(mut self, value: impl Into<i32>) -> Self {
+        self.masquerade_bit = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyIPTablesConfiguration::min_sync_period`].
+    pub fn min_sync_period(mut self, value: impl Into<String>) -> Self {
+        self.min_sync_period = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyIPTablesConfiguration::sync_period`].
+    pub fn sync_period(mut self, value: impl Into<String>) -> Self {
+        self.sync_period = Some(value.into());
+        self
+    }
+
+    /// Builds the `KubeProxyIPTablesConfiguration`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<KubeProxyIPTablesConfiguration, crate::MissingFieldError> {
+        Ok(KubeProxyIPTablesConfiguration {
+            masquerade_all: self.masquerade_all,
+            masquerade_bit: self.masquerade_bit,
+            min_sync_period: self.min_sync_period,
+            sync_period: self.sync_period,
+        })
+    }
+}
+
+/// The apply configuration of [`KubeProxyIPTablesConfiguration`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KubeProxyIPTablesConfigurationApplyConfiguration {
+    /// See [`KubeProxyIPTablesConfiguration::masquerade_all`].
+    pub masquerade_all: Option<bool>,
+
+    /// See [`KubeProxyIPTablesConfiguration::masquerade_bit`].
+    pub masquerade_bit: Option<i32>,
+
+    /// See [`KubeProxyIPTablesConfiguration::min_sync_period`].
+    pub min_sync_period: Option<String>,
+
+    /// See [`KubeProxyIPTablesConfiguration::sync_period`].
+    pub sync_period: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for KubeProxyIPTablesConfigurationApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "KubeProxyIPTablesConfigurationApplyConfiguration",
+            self.masquerade_all.as_ref().map_or(0, |_| 1) +
+            self.masquerade_bit.as_ref().map_or(0, |_| 1) +
+            self.min_sync_period.as_ref().map_or(0, |_| 1) +
+            self.sync_period.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.masquerade_all {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "masqueradeAll", value)?;
+        }
+        if let Some(value) = &self.masquerade_bit {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "masqueradeBit", value)?;
+        }
+        if let Some(value) = &self.min_sync_period {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "minSyncPeriod", value)?;
+        }
+        if let Some(value) = &self.sync_period {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "syncPeriod", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for KubeProxyIPTablesConfiguration {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "kube_proxy::config::v1alpha1::KubeProxyIPTablesConfiguration".to_owned()
+        }
+        else {
+            "io.k8s.kube-proxy.config.v1alpha1.KubeProxyIPTablesConfiguration".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("KubeProxyIPTablesConfiguration contains iptables-related configuration details for the Kubernetes proxy server.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "masqueradeAll".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("masqueradeAll tells kube-proxy to SNAT everything if using the pure iptables proxy mode.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "masqueradeBit".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("masqueradeBit is the bit of the iptables fwmark space to use for SNAT if using the pure iptables proxy mode. Values must be within the range [0, 31].".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int32".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "minSyncPeriod".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("minSyncPeriod is the minimum period that iptables rules are refreshed (e.g. '5s', '1m', '2h22m').\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "syncPeriod".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("syncPeriod is the period that iptables rules are refreshed (e.g. '5s', '1m', '2h22m').  Must be greater than 0.\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for KubeProxyIPTablesConfiguration {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(KubeProxyIPTablesConfiguration {
+            masquerade_all: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            masquerade_bit: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            min_sync_period: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            sync_period: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -0,0 +1,35 @@
+
+mod kube_proxy_configuration;
+pub use self::kube_proxy_configuration::KubeProxyConfiguration;
+#[cfg(feature = "builder")]
+pub use self::kube_proxy_configuration::KubeProxyConfigurationBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::kube_proxy_configuration::KubeProxyConfigurationApplyConfiguration;
+
+mod kube_proxy_conntrack_configuration;
+pub use self::kube_proxy_conntrack_configuration::KubeProxyConntrackConfiguration;
+#[cfg(feature = "builder")]
+pub use self::kube_proxy_conntrack_configuration::KubeProxyConntrackConfigurationBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::kube_proxy_conntrack_configuration::KubeProxyConntrackConfigurationApplyConfiguration;
+
+mod kube_proxy_ip_tables_configuration;
+pub use self::kube_proxy_ip_tables_configuration::KubeProxyIPTablesConfiguration;
+#[cfg(feature = "builder")]
+pub use self::kube_proxy_ip_tables_configuration::KubeProxyIPTablesConfigurationBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::kube_proxy_ip_tables_configuration::KubeProxyIPTablesConfigurationApplyConfiguration;
+
+mod kube_proxy_ipvs_configuration;
+pub use self::kube_proxy_ipvs_configuration::KubeProxyIPVSConfiguration;
+#[cfg(feature = "builder")]
+pub use self::kube_proxy_ipvs_configuration::KubeProxyIPVSConfigurationBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::kube_proxy_ipvs_configuration::KubeProxyIPVSConfigurationApplyConfiguration;
+
+mod kube_proxy_winkernel_configuration;
+pub use self::kube_proxy_winkernel_configuration::KubeProxyWinkernelConfiguration;
+#[cfg(feature = "builder")]
+pub use self::kube_proxy_winkernel_configuration::KubeProxyWinkernelConfigurationBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::kube_proxy_winkernel_configuration::KubeProxyWinkernelConfigurationApplyConfiguration;
@@ -0,0 +1,229 @@
+// Generated from definition io.k8s.kubelet.config.v1beta1.KubeletAnonymousAuthentication
+
+/// KubeletAnonymousAuthentication enables anonymous requests to the kubelet server.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct KubeletAnonymousAuthentication {
+    /// enabled allows anonymous requests to the kubelet server. Requests that are not rejected by another authentication method are treated as anonymous requests. Anonymous requests have a username of `system:anonymous`, and a group name of `system:unauthenticated`.
+    pub enabled: Option<bool>,
+}
+
+impl KubeletAnonymousAuthentication {
+    /// Returns this value with [`KubeletAnonymousAuthentication::enabled`] set to the given value.
+    #[must_use]
+    pub fn with_enabled(mut self, value: impl Into<bool>) -> Self {
+        self.enabled = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for KubeletAnonymousAuthentication {}
+
+impl KubeletAnonymousAuthentication {
+    const FIELDS: &'static [&'static str] = &[
+        "enabled",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for KubeletAnonymousAuthentication {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = KubeletAnonymousAuthentication;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("KubeletAnonymousAuthentication")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_enabled: Option<bool> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("KubeletAnonymousAuthentication", KubeletAnonymousAuthentication::FIELDS))? {
+                    match key {
+                        Some(0) => value_enabled = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(KubeletAnonymousAuthentication {
+                    enabled: value_enabled,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(KubeletAnonymousAuthentication {
+                    enabled: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "KubeletAnonymousAuthentication",
+            KubeletAnonymousAuthentication::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, KubeletAnonymousAuthentication> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("KubeletAnonymousAuthentication")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_enabled = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("KubeletAnonymousAuthentication", KubeletAnonymousAuthentication::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.enabled))?; has_enabled = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_enabled {
+                    self.0.enabled = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.enabled))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "KubeletAnonymousAuthentication",
+            KubeletAnonymousAuthentication::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for KubeletAnonymousAuthentication {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("KubeletAnonymousAuthentication", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enabled", &self.enabled)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            "KubeletAnonymousAuthentication",
+            self.enabled.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.enabled {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enabled", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`KubeletAnonymousAuthentication`]. Created with [`KubeletAnonymousAuthentication::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct KubeletAnonymousAuthenticationBuilder {
+    enabled: Option<bool>,
+}
+
+#[cfg(feature = "builder")]
+impl KubeletAnonymousAuthentication {
+    /// Starts building a `KubeletAnonymousAuthentication`. Its required fields must be set before [`KubeletAnonymousAuthenticationBuilder::build`] is called.
+    pub fn builder() -> KubeletAnonymousAuthenticationBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl KubeletAnonymousAuthenticationBuilder {
+    /// Sets [`KubeletAnonymousAuthentication::enabled`].
+    pub fn enabled(mut self, value: impl Into<bool>) -> Self {
+        self.enabled = Some(value.into());
+        self
+    }
+
+    /// Builds the `KubeletAnonymousAuthentication`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<KubeletAnonymousAuthentication, crate::MissingFieldError> {
+        Ok(KubeletAnonymousAuthentication {
+            enabled: self.enabled,
+        })
+    }
+}
+
+/// The apply configuration of [`KubeletAnonymousAuthentication`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KubeletAnonymousAuthenticationApplyConfiguration {
+    /// See [`KubeletAnonymousAuthentication::enabled`].
+    pub enabled: Option<bool>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for KubeletAnonymousAuthenticationApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "KubeletAnonymousAuthenticationApplyConfiguration",
+            self.enabled.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.enabled {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enabled", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for KubeletAnonymousAuthentication {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "kubelet::config::v1beta1::KubeletAnonymousAuthentication".to_owned()
+        }
+        else {
+            "io.k8s.kubelet.config.v1beta1.KubeletAnonymousAuthentication".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("KubeletAnonymousAuthentication enables anonymous requests to the kubelet server.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "enabled".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("enabled allows anonymous requests to the kubelet server. Requests that are not rejected by another authentication method are treated as anonymous requests. Anonymous requests have a username of `system:anonymous`, and a group name of `system:unauthenticated`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for KubeletAnonymousAuthentication {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(KubeletAnonymousAuthentication {
+            enabled: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
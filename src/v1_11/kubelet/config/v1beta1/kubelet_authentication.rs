@@ -0,0 +1,335 @@
+// Generated from definition io.k8s.kubelet.config.v1beta1.KubeletAuthentication
+
+/// KubeletAuthentication configures how requests to the kubelet server are authenticated.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct KubeletAuthentication {
+    /// anonymous contains settings related to anonymous authentication.
+    pub anonymous: Option<crate::kubelet::config::v1beta1::KubeletAnonymousAuthentication>,
+
+    /// webhook contains settings related to webhook bearer token authentication.
+    pub webhook: Option<crate::kubelet::config::v1beta1::KubeletWebhookAuthentication>,
+
+    /// x509 contains settings related to x509 client certificate authentication.
+    pub x509: Option<crate::kubelet::config::v1beta1::KubeletX509Authentication>,
+}
+
+impl KubeletAuthentication {
+    /// Returns this value with [`KubeletAuthentication::anonymous`] set to the given value.
+    #[must_use]
+    pub fn with_anonymous(mut self, value: impl Into<crate::kubelet::config::v1beta1::KubeletAnonymousAuthentication>) -> Self {
+        self.anonymous = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletAuthentication::webhook`] set to the given value.
+    #[must_use]
+    pub fn with_webhook(mut self, value: impl Into<crate::kubelet::config::v1beta1::KubeletWebhookAuthentication>) -> Self {
+        self.webhook = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletAuthentication::x509`] set to the given value.
+    #[must_use]
+    pub fn with_x509(mut self, value: impl Into<crate::kubelet::config::v1beta1::KubeletX509Authentication>) -> Self {
+        self.x509 = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for KubeletAuthentication {}
+
+impl KubeletAuthentication {
+    const FIELDS: &'static [&'static str] = &[
+        "anonymous",
+        "webhook",
+        "x509",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for KubeletAuthentication {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = KubeletAuthentication;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("KubeletAuthentication")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_anonymous: Option<crate::kubelet::config::v1beta1::KubeletAnonymousAuthentication> = None;
+                let mut value_webhook: Option<crate::kubelet::config::v1beta1::KubeletWebhookAuthentication> = None;
+                let mut value_x509: Option<crate::kubelet::config::v1beta1::KubeletX509Authentication> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("KubeletAuthentication", KubeletAuthentication::FIELDS))? {
+                    match key {
+                        Some(0) => value_anonymous = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_webhook = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_x509 = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(KubeletAuthentication {
+                    anonymous: value_anonymous,
+                    webhook: value_webhook,
+                    x509: value_x509,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(KubeletAuthentication {
+                    anonymous: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    webhook: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    x509: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "KubeletAuthentication",
+            KubeletAuthentication::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, KubeletAuthentication> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("KubeletAuthentication")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_anonymous = false;
+                let mut has_webhook = false;
+                let mut has_x509 = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("KubeletAuthentication", KubeletAuthentication::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.anonymous))?; has_anonymous = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.webhook))?; has_webhook = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.x509))?; has_x509 = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_anonymous {
+                    self.0.anonymous = None;
+                }
+                if !has_webhook {
+                    self.0.webhook = None;
+                }
+                if !has_x509 {
+                    self.0.x509 = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.anonymous))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.webhook))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.x509))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "KubeletAuthentication",
+            KubeletAuthentication::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for KubeletAuthentication {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("KubeletAuthentication", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "anonymous", &self.anonymous)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "webhook", &self.webhook)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "x509", &self.x509)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            "KubeletAuthentication",
+            self.anonymous.as_ref().map_or(0, |_| 1) +
+            self.webhook.as_ref().map_or(0, |_| 1) +
+            self.x509.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.anonymous {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "anonymous", value)?;
+        }
+        if let Some(value) = &self.webhook {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "webhook", value)?;
+        }
+        if let Some(value) = &self.x509 {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "x509", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`KubeletAuthentication`]. Created with [`KubeletAuthentication::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct KubeletAuthenticationBuilder {
+    anonymous: Option<crate::kubelet::config::v1beta1::KubeletAnonymousAuthentication>,
+    webhook: Option<crate::kubelet::config::v1beta1::KubeletWebhookAuthentication>,
+    x509: Option<crate::kubelet::config::v1beta1::KubeletX509Authentication>,
+}
+
+#[cfg(feature = "builder")]
+impl KubeletAuthentication {
+    /// Starts building a `KubeletAuthentication`. Its required fields must be set before [`KubeletAuthenticationBuilder::build`] is called.
+    pub fn builder() -> KubeletAuthenticationBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl KubeletAuthenticationBuilder {
+    /// Sets [`KubeletAuthentication::anonymous`].
+    pub fn anonymous(mut self, value: impl Into<crate::kubelet::config::v1beta1::KubeletAnonymousAuthentication>) -> Self {
+        self.anonymous = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletAuthentication::webhook`].
+    pub fn webhook(mut self, value: impl Into<crate::kubelet::config::v1beta1::KubeletWebhookAuthentication>) -> Self {
+        self.webhook = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletAuthentication::x509`].
+    pub fn x509(mut self, value: impl Into<crate::kubelet::config::v1beta1::KubeletX509Authentication>) -> Self {
+        self.x509 = Some(value.into());
+        self
+    }
+
+    /// Builds the `KubeletAuthentication`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<KubeletAuthentication, crate::MissingFieldError> {
+        Ok(KubeletAuthentication {
+            anonymous: self.anonymous,
+            webhook: self.webhook,
+            x509: self.x509,
+        })
+    }
+}
+
+/// The apply configuration of [`KubeletAuthentication`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KubeletAuthenticationApplyConfiguration {
+    /// See [`KubeletAuthentication::anonymous`].
+    pub anonymous: Option<crate::kubelet::config::v1beta1::KubeletAnonymousAuthenticationApplyConfiguration>,
+
+    /// See [`KubeletAuthentication::webhook`].
+    pub webhook: Option<crate::kubelet::config::v1beta1::KubeletWebhookAuthenticationApplyConfiguration>,
+
+    /// See [`KubeletAuthentication::x509`].
+    pub x509: Option<crate::kubelet::config::v1beta1::KubeletX509AuthenticationApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for KubeletAuthenticationApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "KubeletAuthenticationApplyConfiguration",
+            self.anonymous.as_ref().map_or(0, |_| 1) +
+            self.webhook.as_ref().map_or(0, |_| 1) +
+            self.x509.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.anonymous {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "anonymous", value)?;
+        }
+        if let Some(value) = &self.webhook {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "webhook", value)?;
+        }
+        if let Some(value) = &self.x509 {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "x509", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for KubeletAuthentication {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "kubelet::config::v1beta1::KubeletAuthentication".to_owned()
+        }
+        else {
+            "io.k8s.kubelet.config.v1beta1.KubeletAuthentication".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("KubeletAuthentication configures how requests to the kubelet server are authenticated.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "anonymous".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::kubelet::config::v1beta1::KubeletAnonymousAuthentication>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("anonymous contains settings related to anonymous authentication.".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
+                        "webhook".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::kubelet::config::v1beta1::KubeletWebhookAuthentication>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("webhook contains settings related to webhook bearer token authentication.".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
+                        "x509".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::kubelet::config::v1beta1::KubeletX509Authentication>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("x509 contains settings related to x509 client certificate authentication.".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties
+                },
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for KubeletAuthentication {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(KubeletAuthentication {
+            anonymous: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            webhook: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            x509: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
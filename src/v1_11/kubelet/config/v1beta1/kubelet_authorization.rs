@@ -0,0 +1,282 @@
+// Generated from definition io.k8s.kubelet.config.v1beta1.KubeletAuthorization
+
+/// KubeletAuthorization configures how requests to the kubelet server are authorized.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct KubeletAuthorization {
+    /// mode is the authorization mode to apply to requests to the kubelet server. Valid values are `AlwaysAllow` and `Webhook`. Webhook mode uses the SubjectAccessReview API to determine authorization.
+    pub mode: Option<String>,
+
+    /// webhook contains settings related to Webhook authorization.
+    pub webhook: Option<crate::kubelet::config::v1beta1::KubeletWebhookAuthorization>,
+}
+
+impl KubeletAuthorization {
+    /// Returns this value with [`KubeletAuthorization::mode`] set to the given value.
+    #[must_use]
+    pub fn with_mode(mut self, value: impl Into<String>) -> Self {
+        self.mode = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletAuthorization::webhook`] set to the given value.
+    #[must_use]
+    pub fn with_webhook(mut self, value: impl Into<crate::kubelet::config::v1beta1::KubeletWebhookAuthorization>) -> Self {
+        self.webhook = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for KubeletAuthorization {}
+
+impl KubeletAuthorization {
+    const FIELDS: &'static [&'static str] = &[
+        "mode",
+        "webhook",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for KubeletAuthorization {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = KubeletAuthorization;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("KubeletAuthorization")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_mode: Option<String> = None;
+                let mut value_webhook: Option<crate::kubelet::config::v1beta1::KubeletWebhookAuthorization> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("KubeletAuthorization", KubeletAuthorization::FIELDS))? {
+                    match key {
+                        Some(0) => value_mode = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_webhook = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(KubeletAuthorization {
+                    mode: value_mode,
+                    webhook: value_webhook,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(KubeletAuthorization {
+                    mode: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    webhook: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "KubeletAuthorization",
+            KubeletAuthorization::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, KubeletAuthorization> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("KubeletAuthorization")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_mode = false;
+                let mut has_webhook = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("KubeletAuthorization", KubeletAuthorization::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.mode))?; has_mode = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.webhook))?; has_webhook = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_mode {
+                    self.0.mode = None;
+                }
+                if !has_webhook {
+                    self.0.webhook = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.mode))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.webhook))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "KubeletAuthorization",
+            KubeletAuthorization::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for KubeletAuthorization {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("KubeletAuthorization", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "mode", &self.mode)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "webhook", &self.webhook)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            "KubeletAuthorization",
+            self.mode.as_ref().map_or(0, |_| 1) +
+            self.webhook.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.mode {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "mode", value)?;
+        }
+        if let Some(value) = &self.webhook {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "webhook", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`KubeletAuthorization`]. Created with [`KubeletAuthorization::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct KubeletAuthorizationBuilder {
+    mode: Option<String>,
+    webhook: Option<crate::kubelet::config::v1beta1::KubeletWebhookAuthorization>,
+}
+
+#[cfg(feature = "builder")]
+impl KubeletAuthorization {
+    /// Starts building a `KubeletAuthorization`. Its required fields must be set before [`KubeletAuthorizationBuilder::build`] is called.
+    pub fn builder() -> KubeletAuthorizationBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl KubeletAuthorizationBuilder {
+    /// Sets [`KubeletAuthorization::mode`].
+    pub fn mode(mut self, value: impl Into<String>) -> Self {
+        self.mode = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletAuthorization::webhook`].
+    pub fn webhook(mut self, value: impl Into<crate::kubelet::config::v1beta1::KubeletWebhookAuthorization>) -> Self {
+        self.webhook = Some(value.into());
+        self
+    }
+
+    /// Builds the `KubeletAuthorization`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<KubeletAuthorization, crate::MissingFieldError> {
+        Ok(KubeletAuthorization {
+            mode: self.mode,
+            webhook: self.webhook,
+        })
+    }
+}
+
+/// The apply configuration of [`KubeletAuthorization`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KubeletAuthorizationApplyConfiguration {
+    /// See [`KubeletAuthorization::mode`].
+    pub mode: Option<String>,
+
+    /// See [`KubeletAuthorization::webhook`].
+    pub webhook: Option<crate::kubelet::config::v1beta1::KubeletWebhookAuthorizationApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for KubeletAuthorizationApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "KubeletAuthorizationApplyConfiguration",
+            self.mode.as_ref().map_or(0, |_| 1) +
+            self.webhook.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.mode {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "mode", value)?;
+        }
+        if let Some(value) = &self.webhook {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "webhook", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for KubeletAuthorization {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "kubelet::config::v1beta1::KubeletAuthorization".to_owned()
+        }
+        else {
+            "io.k8s.kubelet.config.v1beta1.KubeletAuthorization".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("KubeletAuthorization configures how requests to the kubelet server are authorized.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "mode".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("mode is the authorization mode to apply to requests to the kubelet server. Valid values are `AlwaysAllow` and `Webhook`. Webhook mode uses the SubjectAccessReview API to determine authorization.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "webhook".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::kubelet::config::v1beta1::KubeletWebhookAuthorization>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("webhook contains settings related to Webhook authorization.".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties
+                },
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for KubeletAuthorization {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(KubeletAuthorization {
+            mode: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            webhook: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
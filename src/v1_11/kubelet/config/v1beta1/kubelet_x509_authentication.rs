@@ -0,0 +1,229 @@
+// Generated from definition io.k8s.kubelet.config.v1beta1.KubeletX509Authentication
+
+/// KubeletX509Authentication configures client certificate authentication of requests to the kubelet server.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct KubeletX509Authentication {
+    /// clientCAFile is the path to a PEM-encoded certificate bundle. If set, any request presenting a client certificate signed by one of the authorities in the bundle is authenticated with a username corresponding to the CommonName, and groups corresponding to the Organization in the client certificate.
+    pub client_ca_file: Option<String>,
+}
+
+impl KubeletX509Authentication {
+    /// Returns this value with [`KubeletX509Authentication::client_ca_file`] set to the given value.
+    #[must_use]
+    pub fn with_client_ca_file(mut self, value: impl Into<String>) -> Self {
+        self.client_ca_file = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for KubeletX509Authentication {}
+
+impl KubeletX509Authentication {
+    const FIELDS: &'static [&'static str] = &[
+        "clientCAFile",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for KubeletX509Authentication {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = KubeletX509Authentication;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("KubeletX509Authentication")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_client_ca_file: Option<String> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("KubeletX509Authentication", KubeletX509Authentication::FIELDS))? {
+                    match key {
+                        Some(0) => value_client_ca_file = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(KubeletX509Authentication {
+                    client_ca_file: value_client_ca_file,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(KubeletX509Authentication {
+                    client_ca_file: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "KubeletX509Authentication",
+            KubeletX509Authentication::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, KubeletX509Authentication> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("KubeletX509Authentication")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_client_ca_file = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("KubeletX509Authentication", KubeletX509Authentication::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.client_ca_file))?; has_client_ca_file = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_client_ca_file {
+                    self.0.client_ca_file = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.client_ca_file))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "KubeletX509Authentication",
+            KubeletX509Authentication::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for KubeletX509Authentication {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("KubeletX509Authentication", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clientCAFile", &self.client_ca_file)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            "KubeletX509Authentication",
+            self.client_ca_file.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.client_ca_file {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clientCAFile", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`KubeletX509Authentication`]. Created with [`KubeletX509Authentication::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct KubeletX509AuthenticationBuilder {
+    client_ca_file: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl KubeletX509Authentication {
+    /// Starts building a `KubeletX509Authentication`. Its required fields must be set before [`KubeletX509AuthenticationBuilder::build`] is called.
+    pub fn builder() -> KubeletX509AuthenticationBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl KubeletX509AuthenticationBuilder {
+    /// Sets [`KubeletX509Authentication::client_ca_file`].
+    pub fn client_ca_file(mut self, value: impl Into<String>) -> Self {
+        self.client_ca_file = Some(value.into());
+        self
+    }
+
+    /// Builds the `KubeletX509Authentication`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<KubeletX509Authentication, crate::MissingFieldError> {
+        Ok(KubeletX509Authentication {
+            client_ca_file: self.client_ca_file,
+        })
+    }
+}
+
+/// The apply configuration of [`KubeletX509Authentication`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KubeletX509AuthenticationApplyConfiguration {
+    /// See [`KubeletX509Authentication::client_ca_file`].
+    pub client_ca_file: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for KubeletX509AuthenticationApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "KubeletX509AuthenticationApplyConfiguration",
+            self.client_ca_file.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.client_ca_file {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clientCAFile", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for KubeletX509Authentication {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "kubelet::config::v1beta1::KubeletX509Authentication".to_owned()
+        }
+        else {
+            "io.k8s.kubelet.config.v1beta1.KubeletX509Authentication".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("KubeletX509Authentication configures client certificate authentication of requests to the kubelet server.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "clientCAFile".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("clientCAFile is the path to a PEM-encoded certificate bundle. If set, any request presenting a client certificate signed by one of the authorities in the bundle is authenticated with a username corresponding to the CommonName, and groups corresponding to the Organization in the client certificate.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for KubeletX509Authentication {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(KubeletX509Authentication {
+            client_ca_file: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
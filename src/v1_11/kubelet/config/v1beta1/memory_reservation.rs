@@ -0,0 +1,296 @@
+// Generated from definition io.k8s.kubelet.config.v1beta1.MemoryReservation
+
+/// MemoryReservation specifies the memory reservation of different types for each NUMA node.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct MemoryReservation {
+    /// limits are the amounts of each resource type reserved on the NUMA node.
+    pub limits: std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>,
+
+    /// numaNode is the NUMA node that the reservation applies to.
+    pub numa_node: i32,
+}
+
+impl MemoryReservation {
+    /// Constructs a `MemoryReservation` from its required fields. Its optional fields are unset.
+    pub fn new(limits: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>, numa_node: impl Into<i32>) -> Self {
+        MemoryReservation {
+            limits: limits.into(),
+            numa_node: numa_node.into(),
+        }
+    }
+}
+
+impl MemoryReservation {
+    /// Returns this value with [`MemoryReservation::limits`] set to the given value.
+    #[must_use]
+    pub fn with_limits(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>) -> Self {
+        self.limits = value.into();
+        self
+    }
+
+    /// Returns this value with [`MemoryReservation::numa_node`] set to the given value.
+    #[must_use]
+    pub fn with_numa_node(mut self, value: impl Into<i32>) -> Self {
+        self.numa_node = value.into();
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for MemoryReservation {}
+
+impl MemoryReservation {
+    const FIELDS: &'static [&'static str] = &[
+        "limits",
+        "numaNode",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for MemoryReservation {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = MemoryReservation;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("MemoryReservation")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_limits: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>> = None;
+                let mut value_numa_node: Option<i32> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("MemoryReservation", MemoryReservation::FIELDS))? {
+                    match key {
+                        Some(0) => value_limits = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_numa_node = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(MemoryReservation {
+                    limits: value_limits.ok_or_else(|| crate::serde2::missing_field("MemoryReservation", "limits", &["limits", "numaNode"]))?,
+                    numa_node: value_numa_node.ok_or_else(|| crate::serde2::missing_field("MemoryReservation", "numaNode", &["limits", "numaNode"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(MemoryReservation {
+                    limits: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    numa_node: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "MemoryReservation",
+            MemoryReservation::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, MemoryReservation> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("MemoryReservation")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_limits = false;
+                let mut has_numa_node = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("MemoryReservation", MemoryReservation::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.limits))?; has_limits = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.numa_node))?; has_numa_node = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_limits {
+                    return Err(crate::serde2::missing_field("MemoryReservation", "limits", &["limits", "numaNode"]));
+                }
+                if !has_numa_node {
+                    return Err(crate::serde2::missing_field("MemoryReservation", "numaNode", &["limits", "numaNode"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.limits))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.numa_node))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "MemoryReservation",
+            MemoryReservation::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for MemoryReservation {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("MemoryReservation", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "limits", &self.limits)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "numaNode", &self.numa_node)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            "MemoryReservation",
+            2,
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "limits", &self.limits)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "numaNode", &self.numa_node)?;
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`MemoryReservation`]. Created with [`MemoryReservation::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct MemoryReservationBuilder {
+    limits: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>,
+    numa_node: Option<i32>,
+}
+
+#[cfg(feature = "builder")]
+impl MemoryReservation {
+    /// Starts building a `MemoryReservation`. Its required fields must be set before [`MemoryReservationBuilder::build`] is called.
+    pub fn builder() -> MemoryReservationBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl MemoryReservationBuilder {
+    /// Sets [`MemoryReservation::limits`].
+    pub fn limits(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>) -> Self {
+        self.limits = Some(value.into());
+        self
+    }
+
+    /// Sets [`MemoryReservation::numa_node`].
+    pub fn numa_node(mut self, value: impl Into<i32>) -> Self {
+        self.numa_node = Some(value.into());
+        self
+    }
+
+    /// Builds the `MemoryReservation`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<MemoryReservation, crate::MissingFieldError> {
+        Ok(MemoryReservation {
+            limits: self.limits.ok_or_else(|| crate::MissingFieldError::new("MemoryReservation", "limits"))?,
+            numa_node: self.numa_node.ok_or_else(|| crate::MissingFieldError::new("MemoryReservation", "numaNode"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`MemoryReservation`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MemoryReservationApplyConfiguration {
+    /// See [`MemoryReservation::limits`].
+    pub limits: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>,
+
+    /// See [`MemoryReservation::numa_node`].
+    pub numa_node: Option<i32>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for MemoryReservationApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "MemoryReservationApplyConfiguration",
+            self.limits.as_ref().map_or(0, |_| 1) +
+            self.numa_node.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.limits {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "limits", value)?;
+        }
+        if let Some(value) = &self.numa_node {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "numaNode", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for MemoryReservation {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "kubelet::config::v1beta1::MemoryReservation".to_owned()
+        }
+        else {
+            "io.k8s.kubelet.config.v1beta1.MemoryReservation".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("MemoryReservation specifies the memory reservation of different types for each NUMA node.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "limits".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("limits are the amounts of each resource type reserved on the NUMA node.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+                            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                                additional_properties: Some(Box::new(__gen.subschema_for::<crate::apimachinery::pkg::api::resource::Quantity>())),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "numaNode".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("numaNode is the NUMA node that the reservation applies to.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int32".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                required: std::array::IntoIter::new([
+                    "limits",
+                    "numaNode",
+                ]).map(std::borrow::ToOwned::to_owned).collect(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for MemoryReservation {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(MemoryReservation {
+            limits: crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            numa_node: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
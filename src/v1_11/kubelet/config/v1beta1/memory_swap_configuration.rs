@@ -0,0 +1,229 @@
+// Generated from definition io.k8s.kubelet.config.v1beta1.MemorySwapConfiguration
+
+/// MemorySwapConfiguration configures how workloads on the node may use swap memory.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct MemorySwapConfiguration {
+    /// swapBehavior configures swap memory available to container workloads. May be one of `""`, `LimitedSwap` or `UnlimitedSwap`.
+    pub swap_behavior: Option<String>,
+}
+
+impl MemorySwapConfiguration {
+    /// Returns this value with [`MemorySwapConfiguration::swap_behavior`] set to the given value.
+    #[must_use]
+    pub fn with_swap_behavior(mut self, value: impl Into<String>) -> Self {
+        self.swap_behavior = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for MemorySwapConfiguration {}
+
+impl MemorySwapConfiguration {
+    const FIELDS: &'static [&'static str] = &[
+        "swapBehavior",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for MemorySwapConfiguration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = MemorySwapConfiguration;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("MemorySwapConfiguration")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_swap_behavior: Option<String> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("MemorySwapConfiguration", MemorySwapConfiguration::FIELDS))? {
+                    match key {
+                        Some(0) => value_swap_behavior = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(MemorySwapConfiguration {
+                    swap_behavior: value_swap_behavior,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(MemorySwapConfiguration {
+                    swap_behavior: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "MemorySwapConfiguration",
+            MemorySwapConfiguration::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, MemorySwapConfiguration> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("MemorySwapConfiguration")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_swap_behavior = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("MemorySwapConfiguration", MemorySwapConfiguration::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.swap_behavior))?; has_swap_behavior = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_swap_behavior {
+                    self.0.swap_behavior = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.swap_behavior))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "MemorySwapConfiguration",
+            MemorySwapConfiguration::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for MemorySwapConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("MemorySwapConfiguration", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "swapBehavior", &self.swap_behavior)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            "MemorySwapConfiguration",
+            self.swap_behavior.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.swap_behavior {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "swapBehavior", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`MemorySwapConfiguration`]. Created with [`MemorySwapConfiguration::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct MemorySwapConfigurationBuilder {
+    swap_behavior: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl MemorySwapConfiguration {
+    /// Starts building a `MemorySwapConfiguration`. Its required fields must be set before [`MemorySwapConfigurationBuilder::build`] is called.
+    pub fn builder() -> MemorySwapConfigurationBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl MemorySwapConfigurationBuilder {
+    /// Sets [`MemorySwapConfiguration::swap_behavior`].
+    pub fn swap_behavior(mut self, value: impl Into<String>) -> Self {
+        self.swap_behavior = Some(value.into());
+        self
+    }
+
+    /// Builds the `MemorySwapConfiguration`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<MemorySwapConfiguration, crate::MissingFieldError> {
+        Ok(MemorySwapConfiguration {
+            swap_behavior: self.swap_behavior,
+        })
+    }
+}
+
+/// The apply configuration of [`MemorySwapConfiguration`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MemorySwapConfigurationApplyConfiguration {
+    /// See [`MemorySwapConfiguration::swap_behavior`].
+    pub swap_behavior: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for MemorySwapConfigurationApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "MemorySwapConfigurationApplyConfiguration",
+            self.swap_behavior.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.swap_behavior {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "swapBehavior", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for MemorySwapConfiguration {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "kubelet::config::v1beta1::MemorySwapConfiguration".to_owned()
+        }
+        else {
+            "io.k8s.kubelet.config.v1beta1.MemorySwapConfiguration".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("MemorySwapConfiguration configures how workloads on the node may use swap memory.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "swapBehavior".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("swapBehavior configures swap memory available to container workloads. May be one of `\"\"`, `LimitedSwap` or `UnlimitedSwap`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for MemorySwapConfiguration {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(MemorySwapConfiguration {
+            swap_behavior: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -0,0 +1,7 @@
+
+mod metric_value;
+pub use self::metric_value::MetricValue;
+#[cfg(feature = "builder")]
+pub use self::metric_value::MetricValueBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::metric_value::MetricValueApplyConfiguration;
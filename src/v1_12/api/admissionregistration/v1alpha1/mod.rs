@@ -1,10 +1,22 @@
 
 mod initializer;
 pub use self::initializer::Initializer;
+#[cfg(feature = "builder")]
+pub use self::initializer::InitializerBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::initializer::InitializerApplyConfiguration;
 
 mod initializer_configuration;
 pub use self::initializer_configuration::InitializerConfiguration;
+#[cfg(feature = "builder")]
+pub use self::initializer_configuration::InitializerConfigurationBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::initializer_configuration::InitializerConfigurationApplyConfiguration;
 #[cfg(feature = "api")] pub use self::initializer_configuration::{ReadInitializerConfigurationOptional, ReadInitializerConfigurationResponse};
 
 mod rule;
 pub use self::rule::Rule;
+#[cfg(feature = "builder")]
+pub use self::rule::RuleBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::rule::RuleApplyConfiguration;
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.admissionregistration.v1beta1.WebhookClientConfig
 
 /// WebhookClientConfig contains the information to make a TLS connection with the webhook
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct WebhookClientConfig {
     /// `caBundle` is a PEM encoded CA bundle which will be used to validate the webhook's server certificate. Required.
     pub ca_bundle: crate::ByteString,
@@ -27,41 +27,52 @@ pub struct WebhookClientConfig {
     pub url: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for WebhookClientConfig {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_ca_bundle,
-            Key_service,
-            Key_url,
-            Other,
+impl WebhookClientConfig {
+    /// Constructs a `WebhookClientConfig` from its required fields. Its optional fields are unset.
+    pub fn new(ca_bundle: impl Into<crate::ByteString>) -> Self {
+        WebhookClientConfig {
+            ca_bundle: ca_bundle.into(),
+            service: None,
+            url: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl WebhookClientConfig {
+    /// Returns this value with [`WebhookClientConfig::ca_bundle`] set to the given value.
+    #[must_use]
+    pub fn with_ca_bundle(mut self, value: impl Into<crate::ByteString>) -> Self {
+        self.ca_bundle = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`WebhookClientConfig::service`] set to the given value.
+    #[must_use]
+    pub fn with_service(mut self, value: impl Into<crate::api::admissionregistration::v1beta1::ServiceReference>) -> Self {
+        self.service = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`WebhookClientConfig::url`] set to the given value.
+    #[must_use]
+    pub fn with_url(mut self, value: impl Into<String>) -> Self {
+        self.url = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "caBundle" => Field::Key_ca_bundle,
-                            "service" => Field::Key_service,
-                            "url" => Field::Key_url,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for WebhookClientConfig {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl WebhookClientConfig {
+    const FIELDS: &'static [&'static str] = &[
+        "caBundle",
+        "service",
+        "url",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for WebhookClientConfig {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -76,37 +87,101 @@ impl<'de> crate::serde::Deserialize<'de> for WebhookClientConfig {
                 let mut value_service: Option<crate::api::admissionregistration::v1beta1::ServiceReference> = None;
                 let mut value_url: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("WebhookClientConfig", WebhookClientConfig::FIELDS))? {
                     match key {
-                        Field::Key_ca_bundle => value_ca_bundle = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_service => value_service = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_url => value_url = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_ca_bundle = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_service = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_url = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(WebhookClientConfig {
-                    ca_bundle: value_ca_bundle.ok_or_else(|| crate::serde::de::Error::missing_field("caBundle"))?,
+                    ca_bundle: value_ca_bundle.ok_or_else(|| crate::serde2::missing_field("WebhookClientConfig", "caBundle", &["caBundle"]))?,
                     service: value_service,
                     url: value_url,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(WebhookClientConfig {
+                    ca_bundle: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    service: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    url: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "WebhookClientConfig",
-            &[
-                "caBundle",
-                "service",
-                "url",
-            ],
+            WebhookClientConfig::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, WebhookClientConfig> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("WebhookClientConfig")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_ca_bundle = false;
+                let mut has_service = false;
+                let mut has_url = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("WebhookClientConfig", WebhookClientConfig::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.ca_bundle))?; has_ca_bundle = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.service))?; has_service = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.url))?; has_url = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_ca_bundle {
+                    return Err(crate::serde2::missing_field("WebhookClientConfig", "caBundle", &["caBundle"]));
+                }
+                if !has_service {
+                    self.0.service = None;
+                }
+                if !has_url {
+                    self.0.url = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.ca_bundle))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.service))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.url))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "WebhookClientConfig",
+            WebhookClientConfig::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for WebhookClientConfig {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("WebhookClientConfig", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "caBundle", &self.ca_bundle)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "service", &self.service)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "url", &self.url)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "WebhookClientConfig",
             1 +
@@ -124,10 +199,99 @@ impl crate::serde::Serialize for WebhookClientConfig {
     }
 }
 
+/// A builder of [`WebhookClientConfig`]. Created with [`WebhookClientConfig::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct WebhookClientConfigBuilder {
+    ca_bundle: Option<crate::ByteString>,
+    service: Option<crate::api::admissionregistration::v1beta1::ServiceReference>,
+    url: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl WebhookClientConfig {
+    /// Starts building a `WebhookClientConfig`. Its required fields must be set before [`WebhookClientConfigBuilder::build`] is called.
+    pub fn builder() -> WebhookClientConfigBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl WebhookClientConfigBuilder {
+    /// Sets [`WebhookClientConfig::ca_bundle`].
+    pub fn ca_bundle(mut self, value: impl Into<crate::ByteString>) -> Self {
+        self.ca_bundle = Some(value.into());
+        self
+    }
+
+    /// Sets [`WebhookClientConfig::service`].
+    pub fn service(mut self, value: impl Into<crate::api::admissionregistration::v1beta1::ServiceReference>) -> Self {
+        self.service = Some(value.into());
+        self
+    }
+
+    /// Sets [`WebhookClientConfig::url`].
+    pub fn url(mut self, value: impl Into<String>) -> Self {
+        self.url = Some(value.into());
+        self
+    }
+
+    /// Builds the `WebhookClientConfig`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<WebhookClientConfig, crate::MissingFieldError> {
+        Ok(WebhookClientConfig {
+            ca_bundle: self.ca_bundle.ok_or_else(|| crate::MissingFieldError::new("WebhookClientConfig", "caBundle"))?,
+            service: self.service,
+            url: self.url,
+        })
+    }
+}
+
+/// The apply configuration of [`WebhookClientConfig`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WebhookClientConfigApplyConfiguration {
+    /// See [`WebhookClientConfig::ca_bundle`].
+    pub ca_bundle: Option<crate::ByteString>,
+
+    /// See [`WebhookClientConfig::service`].
+    pub service: Option<crate::api::admissionregistration::v1beta1::ServiceReferenceApplyConfiguration>,
+
+    /// See [`WebhookClientConfig::url`].
+    pub url: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for WebhookClientConfigApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "WebhookClientConfigApplyConfiguration",
+            self.ca_bundle.as_ref().map_or(0, |_| 1) +
+            self.service.as_ref().map_or(0, |_| 1) +
+            self.url.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.ca_bundle {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "caBundle", value)?;
+        }
+        if let Some(value) = &self.service {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "service", value)?;
+        }
+        if let Some(value) = &self.url {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "url", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for WebhookClientConfig {
     fn schema_name() -> String {
-        "io.k8s.api.admissionregistration.v1beta1.WebhookClientConfig".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::admissionregistration::v1beta1::WebhookClientConfig".to_owned()
+        }
+        else {
+            "io.k8s.api.admissionregistration.v1beta1.WebhookClientConfig".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -138,8 +302,9 @@ impl crate::schemars::JsonSchema for WebhookClientConfig {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "caBundle".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -150,8 +315,8 @@ impl crate::schemars::JsonSchema for WebhookClientConfig {
                             format: Some("byte".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "service".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::admissionregistration::v1beta1::ServiceReference>().into_object();
@@ -161,8 +326,8 @@ impl crate::schemars::JsonSchema for WebhookClientConfig {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "url".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -172,8 +337,9 @@ impl crate::schemars::JsonSchema for WebhookClientConfig {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "caBundle",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -183,3 +349,14 @@ impl crate::schemars::JsonSchema for WebhookClientConfig {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for WebhookClientConfig {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(WebhookClientConfig {
+            ca_bundle: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            service: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            url: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
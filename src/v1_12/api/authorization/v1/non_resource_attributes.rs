@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.authorization.v1.NonResourceAttributes
 
 /// NonResourceAttributes includes the authorization attributes available for non-resource requests to the Authorizer interface
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct NonResourceAttributes {
     /// Path is the URL path of the request
     pub path: Option<String>,
@@ -10,39 +10,33 @@ pub struct NonResourceAttributes {
     pub verb: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for NonResourceAttributes {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_path,
-            Key_verb,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl NonResourceAttributes {
+    /// Returns this value with [`NonResourceAttributes::path`] set to the given value.
+    #[must_use]
+    pub fn with_path(mut self, value: impl Into<String>) -> Self {
+        self.path = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`NonResourceAttributes::verb`] set to the given value.
+    #[must_use]
+    pub fn with_verb(mut self, value: impl Into<String>) -> Self {
+        self.verb = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "path" => Field::Key_path,
-                            "verb" => Field::Key_verb,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for NonResourceAttributes {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl NonResourceAttributes {
+    const FIELDS: &'static [&'static str] = &[
+        "path",
+        "verb",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for NonResourceAttributes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,11 +50,11 @@ impl<'de> crate::serde::Deserialize<'de> for NonResourceAttributes {
                 let mut value_path: Option<String> = None;
                 let mut value_verb: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NonResourceAttributes", NonResourceAttributes::FIELDS))? {
                     match key {
-                        Field::Key_path => value_path = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_verb => value_verb = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_path = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_verb = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -69,21 +63,78 @@ impl<'de> crate::serde::Deserialize<'de> for NonResourceAttributes {
                     verb: value_verb,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(NonResourceAttributes {
+                    path: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    verb: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "NonResourceAttributes",
-            &[
-                "path",
-                "verb",
-            ],
+            NonResourceAttributes::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, NonResourceAttributes> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("NonResourceAttributes")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_path = false;
+                let mut has_verb = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NonResourceAttributes", NonResourceAttributes::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.path))?; has_path = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.verb))?; has_verb = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_path {
+                    self.0.path = None;
+                }
+                if !has_verb {
+                    self.0.verb = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.path))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.verb))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "NonResourceAttributes",
+            NonResourceAttributes::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for NonResourceAttributes {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("NonResourceAttributes", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "path", &self.path)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "verb", &self.verb)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "NonResourceAttributes",
             self.path.as_ref().map_or(0, |_| 1) +
@@ -99,10 +150,84 @@ impl crate::serde::Serialize for NonResourceAttributes {
     }
 }
 
+/// A builder of [`NonResourceAttributes`]. Created with [`NonResourceAttributes::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct NonResourceAttributesBuilder {
+    path: Option<String>,
+    verb: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl NonResourceAttributes {
+    /// Starts building a `NonResourceAttributes`. Its required fields must be set before [`NonResourceAttributesBuilder::build`] is called.
+    pub fn builder() -> NonResourceAttributesBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl NonResourceAttributesBuilder {
+    /// Sets [`NonResourceAttributes::path`].
+    pub fn path(mut self, value: impl Into<String>) -> Self {
+        self.path = Some(value.into());
+        self
+    }
+
+    /// Sets [`NonResourceAttributes::verb`].
+    pub fn verb(mut self, value: impl Into<String>) -> Self {
+        self.verb = Some(value.into());
+        self
+    }
+
+    /// Builds the `NonResourceAttributes`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<NonResourceAttributes, crate::MissingFieldError> {
+        Ok(NonResourceAttributes {
+            path: self.path,
+            verb: self.verb,
+        })
+    }
+}
+
+/// The apply configuration of [`NonResourceAttributes`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NonResourceAttributesApplyConfiguration {
+    /// See [`NonResourceAttributes::path`].
+    pub path: Option<String>,
+
+    /// See [`NonResourceAttributes::verb`].
+    pub verb: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for NonResourceAttributesApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "NonResourceAttributesApplyConfiguration",
+            self.path.as_ref().map_or(0, |_| 1) +
+            self.verb.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.path {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "path", value)?;
+        }
+        if let Some(value) = &self.verb {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "verb", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NonResourceAttributes {
     fn schema_name() -> String {
-        "io.k8s.api.authorization.v1.NonResourceAttributes".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::authorization::v1::NonResourceAttributes".to_owned()
+        }
+        else {
+            "io.k8s.api.authorization.v1.NonResourceAttributes".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -113,8 +238,9 @@ impl crate::schemars::JsonSchema for NonResourceAttributes {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "path".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -124,8 +250,8 @@ impl crate::schemars::JsonSchema for NonResourceAttributes {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "verb".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -135,11 +261,22 @@ impl crate::schemars::JsonSchema for NonResourceAttributes {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for NonResourceAttributes {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(NonResourceAttributes {
+            path: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            verb: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
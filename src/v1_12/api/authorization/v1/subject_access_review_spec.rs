@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.authorization.v1.SubjectAccessReviewSpec
 
 /// SubjectAccessReviewSpec is a description of the access request.  Exactly one of ResourceAuthorizationAttributes and NonResourceAuthorizationAttributes must be set
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct SubjectAccessReviewSpec {
     /// Extra corresponds to the user.Info.GetExtra() method from the authenticator.  Since that is input to the authorizer it needs a reflection here.
     pub extra: Option<std::collections::BTreeMap<String, Vec<String>>>,
@@ -22,47 +22,65 @@ pub struct SubjectAccessReviewSpec {
     pub user: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for SubjectAccessReviewSpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_extra,
-            Key_groups,
-            Key_non_resource_attributes,
-            Key_resource_attributes,
-            Key_uid,
-            Key_user,
-            Other,
-        }
+impl SubjectAccessReviewSpec {
+    /// Returns this value with [`SubjectAccessReviewSpec::extra`] set to the given value.
+    #[must_use]
+    pub fn with_extra(mut self, value: impl Into<std::collections::BTreeMap<String, Vec<String>>>) -> Self {
+        self.extra = Some(value.into());
+        self
+    }
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+    /// Returns this value with [`SubjectAccessReviewSpec::groups`] set to the given value.
+    #[must_use]
+    pub fn with_groups(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.groups = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`SubjectAccessReviewSpec::non_resource_attributes`] set to the given value.
+    #[must_use]
+    pub fn with_non_resource_attributes(mut self, value: impl Into<crate::api::authorization::v1::NonResourceAttributes>) -> Self {
+        self.non_resource_attributes = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`SubjectAccessReviewSpec::resource_attributes`] set to the given value.
+    #[must_use]
+    pub fn with_resource_attributes(mut self, value: impl Into<crate::api::authorization::v1::ResourceAttributes>) -> Self {
+        self.resource_attributes = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "extra" => Field::Key_extra,
-                            "groups" => Field::Key_groups,
-                            "nonResourceAttributes" => Field::Key_non_resource_attributes,
-                            "resourceAttributes" => Field::Key_resource_attributes,
-                            "uid" => Field::Key_uid,
-                            "user" => Field::Key_user,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`SubjectAccessReviewSpec::uid`] set to the given value.
+    #[must_use]
+    pub fn with_uid(mut self, value: impl Into<String>) -> Self {
+        self.uid = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`SubjectAccessReviewSpec::user`] set to the given value.
+    #[must_use]
+    pub fn with_user(mut self, value: impl Into<String>) -> Self {
+        self.user = Some(value.into());
+        self
+    }
+}
 
+impl crate::MergeStrategyInfo for SubjectAccessReviewSpec {}
+
+impl SubjectAccessReviewSpec {
+    const FIELDS: &'static [&'static str] = &[
+        "extra",
+        "groups",
+        "nonResourceAttributes",
+        "resourceAttributes",
+        "uid",
+        "user",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for SubjectAccessReviewSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -80,15 +98,15 @@ impl<'de> crate::serde::Deserialize<'de> for SubjectAccessReviewSpec {
                 let mut value_uid: Option<String> = None;
                 let mut value_user: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("SubjectAccessReviewSpec", SubjectAccessReviewSpec::FIELDS))? {
                     match key {
-                        Field::Key_extra => value_extra = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_groups => value_groups = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_non_resource_attributes => value_non_resource_attributes = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_resource_attributes => value_resource_attributes = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_uid => value_uid = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_user => value_user = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_extra = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_groups = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_non_resource_attributes = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_resource_attributes = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_uid = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_user = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -101,25 +119,110 @@ impl<'de> crate::serde::Deserialize<'de> for SubjectAccessReviewSpec {
                     user: value_user,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(SubjectAccessReviewSpec {
+                    extra: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    groups: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    non_resource_attributes: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    resource_attributes: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    uid: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    user: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "SubjectAccessReviewSpec",
-            &[
-                "extra",
-                "groups",
-                "nonResourceAttributes",
-                "resourceAttributes",
-                "uid",
-                "user",
-            ],
+            SubjectAccessReviewSpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, SubjectAccessReviewSpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("SubjectAccessReviewSpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_extra = false;
+                let mut has_groups = false;
+                let mut has_non_resource_attributes = false;
+                let mut has_resource_attributes = false;
+                let mut has_uid = false;
+                let mut has_user = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("SubjectAccessReviewSpec", SubjectAccessReviewSpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.extra))?; has_extra = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.groups))?; has_groups = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.non_resource_attributes))?; has_non_resource_attributes = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.resource_attributes))?; has_resource_attributes = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.uid))?; has_uid = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.user))?; has_user = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_extra {
+                    self.0.extra = None;
+                }
+                if !has_groups {
+                    self.0.groups = None;
+                }
+                if !has_non_resource_attributes {
+                    self.0.non_resource_attributes = None;
+                }
+                if !has_resource_attributes {
+                    self.0.resource_attributes = None;
+                }
+                if !has_uid {
+                    self.0.uid = None;
+                }
+                if !has_user {
+                    self.0.user = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.extra))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.groups))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.non_resource_attributes))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.resource_attributes))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.uid))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.user))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "SubjectAccessReviewSpec",
+            SubjectAccessReviewSpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for SubjectAccessReviewSpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("SubjectAccessReviewSpec", 6)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "extra", &self.extra)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "groups", &self.groups)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nonResourceAttributes", &self.non_resource_attributes)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resourceAttributes", &self.resource_attributes)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "uid", &self.uid)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "user", &self.user)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "SubjectAccessReviewSpec",
             self.extra.as_ref().map_or(0, |_| 1) +
@@ -151,10 +254,144 @@ impl crate::serde::Serialize for SubjectAccessReviewSpec {
     }
 }
 
+/// A builder of [`SubjectAccessReviewSpec`]. Created with [`SubjectAccessReviewSpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct SubjectAccessReviewSpecBuilder {
+    extra: Option<std::collections::BTreeMap<String, Vec<String>>>,
+    groups: Option<Vec<String>>,
+    non_resource_attributes: Option<crate::api::authorization::v1::NonResourceAttributes>,
+    resource_attributes: Option<crate::api::authorization::v1::ResourceAttributes>,
+    uid: Option<String>,
+    user: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl SubjectAccessReviewSpec {
+    /// Starts building a `SubjectAccessReviewSpec`. Its required fields must be set before [`SubjectAccessReviewSpecBuilder::build`] is called.
+    pub fn builder() -> SubjectAccessReviewSpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl SubjectAccessReviewSpecBuilder {
+    /// Sets [`SubjectAccessReviewSpec::extra`].
+    pub fn extra(mut self, value: impl Into<std::collections::BTreeMap<String, Vec<String>>>) -> Self {
+        self.extra = Some(value.into());
+        self
+    }
+
+    /// Sets [`SubjectAccessReviewSpec::groups`].
+    pub fn groups(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.groups = Some(value.into());
+        self
+    }
+
+    /// Sets [`SubjectAccessReviewSpec::non_resource_attributes`].
+    pub fn non_resource_attributes(mut self, value: impl Into<crate::api::authorization::v1::NonResourceAttributes>) -> Self {
+        self.non_resource_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets [`SubjectAccessReviewSpec::resource_attributes`].
+    pub fn resource_attributes(mut self, value: impl Into<crate::api::authorization::v1::ResourceAttributes>) -> Self {
+        self.resource_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets [`SubjectAccessReviewSpec::uid`].
+    pub fn uid(mut self, value: impl Into<String>) -> Self {
+        self.uid = Some(value.into());
+        self
+    }
+
+    /// Sets [`SubjectAccessReviewSpec::user`].
+    pub fn user(mut self, value: impl Into<String>) -> Self {
+        self.user = Some(value.into());
+        self
+    }
+
+    /// Builds the `SubjectAccessReviewSpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<SubjectAccessReviewSpec, crate::MissingFieldError> {
+        Ok(SubjectAccessReviewSpec {
+            extra: self.extra,
+            groups: self.groups,
+            non_resource_attributes: self.non_resource_attributes,
+            resource_attributes: self.resource_attributes,
+            uid: self.uid,
+            user: self.user,
+        })
+    }
+}
+
+/// The apply configuration of [`SubjectAccessReviewSpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SubjectAccessReviewSpecApplyConfiguration {
+    /// See [`SubjectAccessReviewSpec::extra`].
+    pub extra: Option<std::collections::BTreeMap<String, Vec<String>>>,
+
+    /// See [`SubjectAccessReviewSpec::groups`].
+    pub groups: Option<Vec<String>>,
+
+    /// See [`SubjectAccessReviewSpec::non_resource_attributes`].
+    pub non_resource_attributes: Option<crate::api::authorization::v1::NonResourceAttributesApplyConfiguration>,
+
+    /// See [`SubjectAccessReviewSpec::resource_attributes`].
+    pub resource_attributes: Option<crate::api::authorization::v1::ResourceAttributesApplyConfiguration>,
+
+    /// See [`SubjectAccessReviewSpec::uid`].
+    pub uid: Option<String>,
+
+    /// See [`SubjectAccessReviewSpec::user`].
+    pub user: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for SubjectAccessReviewSpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "SubjectAccessReviewSpecApplyConfiguration",
+            self.extra.as_ref().map_or(0, |_| 1) +
+            self.groups.as_ref().map_or(0, |_| 1) +
+            self.non_resource_attributes.as_ref().map_or(0, |_| 1) +
+            self.resource_attributes.as_ref().map_or(0, |_| 1) +
+            self.uid.as_ref().map_or(0, |_| 1) +
+            self.user.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.extra {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "extra", value)?;
+        }
+        if let Some(value) = &self.groups {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "groups", value)?;
+        }
+        if let Some(value) = &self.non_resource_attributes {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nonResourceAttributes", value)?;
+        }
+        if let Some(value) = &self.resource_attributes {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resourceAttributes", value)?;
+        }
+        if let Some(value) = &self.uid {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "uid", value)?;
+        }
+        if let Some(value) = &self.user {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "user", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for SubjectAccessReviewSpec {
     fn schema_name() -> String {
-        "io.k8s.api.authorization.v1.SubjectAccessReviewSpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::authorization::v1::SubjectAccessReviewSpec".to_owned()
+        }
+        else {
+            "io.k8s.api.authorization.v1.SubjectAccessReviewSpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -165,8 +402,9 @@ impl crate::schemars::JsonSchema for SubjectAccessReviewSpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "extra".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -194,8 +432,8 @@ impl crate::schemars::JsonSchema for SubjectAccessReviewSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "groups".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -214,8 +452,8 @@ impl crate::schemars::JsonSchema for SubjectAccessReviewSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "nonResourceAttributes".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::authorization::v1::NonResourceAttributes>().into_object();
@@ -225,8 +463,8 @@ impl crate::schemars::JsonSchema for SubjectAccessReviewSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "resourceAttributes".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::authorization::v1::ResourceAttributes>().into_object();
@@ -236,8 +474,8 @@ impl crate::schemars::JsonSchema for SubjectAccessReviewSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "uid".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -247,8 +485,8 @@ impl crate::schemars::JsonSchema for SubjectAccessReviewSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "user".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -258,11 +496,26 @@ impl crate::schemars::JsonSchema for SubjectAccessReviewSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for SubjectAccessReviewSpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(SubjectAccessReviewSpec {
+            extra: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)))?,
+            groups: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            non_resource_attributes: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            resource_attributes: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            uid: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            user: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
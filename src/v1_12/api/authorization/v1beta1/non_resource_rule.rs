@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.authorization.v1beta1.NonResourceRule
 
 /// NonResourceRule holds information that describes a rule for the non-resource
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct NonResourceRule {
     /// NonResourceURLs is a set of partial urls that a user should have access to.  *s are allowed, but only as the full, final step in the path.  "*" means all.
     pub non_resource_urls: Option<Vec<String>>,
@@ -10,39 +10,43 @@ pub struct NonResourceRule {
     pub verbs: Vec<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for NonResourceRule {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_non_resource_urls,
-            Key_verbs,
-            Other,
+impl NonResourceRule {
+    /// Constructs a `NonResourceRule` from its required fields. Its optional fields are unset.
+    pub fn new(verbs: impl Into<Vec<String>>) -> Self {
+        NonResourceRule {
+            non_resource_urls: None,
+            verbs: verbs.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl NonResourceRule {
+    /// Returns this value with [`NonResourceRule::non_resource_urls`] set to the given value.
+    #[must_use]
+    pub fn with_non_resource_urls(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.non_resource_urls = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`NonResourceRule::verbs`] set to the given value.
+    #[must_use]
+    pub fn with_verbs(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.verbs = value.into();
+        self
+    }
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for NonResourceRule {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "nonResourceURLs" => Field::Key_non_resource_urls,
-                            "verbs" => Field::Key_verbs,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl NonResourceRule {
+    const FIELDS: &'static [&'static str] = &[
+        "nonResourceURLs",
+        "verbs",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for NonResourceRule {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +60,91 @@ impl<'de> crate::serde::Deserialize<'de> for NonResourceRule {
                 let mut value_non_resource_urls: Option<Vec<String>> = None;
                 let mut value_verbs: Option<Vec<String>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NonResourceRule", NonResourceRule::FIELDS))? {
                     match key {
-                        Field::Key_non_resource_urls => value_non_resource_urls = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_verbs => value_verbs = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_non_resource_urls = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_verbs = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(NonResourceRule {
                     non_resource_urls: value_non_resource_urls,
-                    verbs: value_verbs.ok_or_else(|| crate::serde::de::Error::missing_field("verbs"))?,
+                    verbs: value_verbs.ok_or_else(|| crate::serde2::missing_field("NonResourceRule", "verbs", &["verbs"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(NonResourceRule {
+                    non_resource_urls: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    verbs: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "NonResourceRule",
-            &[
-                "nonResourceURLs",
-                "verbs",
-            ],
+            NonResourceRule::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, NonResourceRule> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("NonResourceRule")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_non_resource_urls = false;
+                let mut has_verbs = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NonResourceRule", NonResourceRule::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.non_resource_urls))?; has_non_resource_urls = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.verbs))?; has_verbs = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_non_resource_urls {
+                    self.0.non_resource_urls = None;
+                }
+                if !has_verbs {
+                    return Err(crate::serde2::missing_field("NonResourceRule", "verbs", &["verbs"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.non_resource_urls))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.verbs))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "NonResourceRule",
+            NonResourceRule::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for NonResourceRule {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("NonResourceRule", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nonResourceURLs", &self.non_resource_urls)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "verbs", &self.verbs)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "NonResourceRule",
             1 +
@@ -97,10 +158,84 @@ impl crate::serde::Serialize for NonResourceRule {
     }
 }
 
+/// A builder of [`NonResourceRule`]. Created with [`NonResourceRule::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct NonResourceRuleBuilder {
+    non_resource_urls: Option<Vec<String>>,
+    verbs: Option<Vec<String>>,
+}
+
+#[cfg(feature = "builder")]
+impl NonResourceRule {
+    /// Starts building a `NonResourceRule`. Its required fields must be set before [`NonResourceRuleBuilder::build`] is called.
+    pub fn builder() -> NonResourceRuleBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl NonResourceRuleBuilder {
+    /// Sets [`NonResourceRule::non_resource_urls`].
+    pub fn non_resource_urls(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.non_resource_urls = Some(value.into());
+        self
+    }
+
+    /// Sets [`NonResourceRule::verbs`].
+    pub fn verbs(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.verbs = Some(value.into());
+        self
+    }
+
+    /// Builds the `NonResourceRule`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<NonResourceRule, crate::MissingFieldError> {
+        Ok(NonResourceRule {
+            non_resource_urls: self.non_resource_urls,
+            verbs: self.verbs.ok_or_else(|| crate::MissingFieldError::new("NonResourceRule", "verbs"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`NonResourceRule`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NonResourceRuleApplyConfiguration {
+    /// See [`NonResourceRule::non_resource_urls`].
+    pub non_resource_urls: Option<Vec<String>>,
+
+    /// See [`NonResourceRule::verbs`].
+    pub verbs: Option<Vec<String>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for NonResourceRuleApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "NonResourceRuleApplyConfiguration",
+            self.non_resource_urls.as_ref().map_or(0, |_| 1) +
+            self.verbs.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.non_resource_urls {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nonResourceURLs", value)?;
+        }
+        if let Some(value) = &self.verbs {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "verbs", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NonResourceRule {
     fn schema_name() -> String {
-        "io.k8s.api.authorization.v1beta1.NonResourceRule".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::authorization::v1beta1::NonResourceRule".to_owned()
+        }
+        else {
+            "io.k8s.api.authorization.v1beta1.NonResourceRule".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -111,8 +246,9 @@ impl crate::schemars::JsonSchema for NonResourceRule {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "nonResourceURLs".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -131,8 +267,8 @@ impl crate::schemars::JsonSchema for NonResourceRule {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "verbs".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -151,8 +287,9 @@ impl crate::schemars::JsonSchema for NonResourceRule {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "verbs",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -162,3 +299,13 @@ impl crate::schemars::JsonSchema for NonResourceRule {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for NonResourceRule {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(NonResourceRule {
+            non_resource_urls: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            verbs: crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.authorization.v1beta1.ResourceRule
 
 /// ResourceRule is the list of actions the subject is allowed to perform on resources. The list ordering isn't significant, may contain duplicates, and possibly be incomplete.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ResourceRule {
     /// APIGroups is the name of the APIGroup that contains the resources.  If multiple API groups are specified, any action requested against one of the enumerated resources in any API group will be allowed.  "*" means all.
     pub api_groups: Option<Vec<String>>,
@@ -17,43 +17,61 @@ pub struct ResourceRule {
     pub verbs: Vec<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ResourceRule {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_api_groups,
-            Key_resource_names,
-            Key_resources,
-            Key_verbs,
-            Other,
+impl ResourceRule {
+    /// Constructs a `ResourceRule` from its required fields. Its optional fields are unset.
+    pub fn new(verbs: impl Into<Vec<String>>) -> Self {
+        ResourceRule {
+            api_groups: None,
+            resource_names: None,
+            resources: None,
+            verbs: verbs.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl ResourceRule {
+    /// Returns this value with [`ResourceRule::api_groups`] set to the given value.
+    #[must_use]
+    pub fn with_api_groups(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.api_groups = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ResourceRule::resource_names`] set to the given value.
+    #[must_use]
+    pub fn with_resource_names(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.resource_names = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ResourceRule::resources`] set to the given value.
+    #[must_use]
+    pub fn with_resources(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.resources = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "apiGroups" => Field::Key_api_groups,
-                            "resourceNames" => Field::Key_resource_names,
-                            "resources" => Field::Key_resources,
-                            "verbs" => Field::Key_verbs,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`ResourceRule::verbs`] set to the given value.
+    #[must_use]
+    pub fn with_verbs(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.verbs = value.into();
+        self
+    }
+}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl crate::MergeStrategyInfo for ResourceRule {}
+
+impl ResourceRule {
+    const FIELDS: &'static [&'static str] = &[
+        "apiGroups",
+        "resourceNames",
+        "resources",
+        "verbs",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ResourceRule {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -69,13 +87,13 @@ impl<'de> crate::serde::Deserialize<'de> for ResourceRule {
                 let mut value_resources: Option<Vec<String>> = None;
                 let mut value_verbs: Option<Vec<String>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ResourceRule", ResourceRule::FIELDS))? {
                     match key {
-                        Field::Key_api_groups => value_api_groups = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_resource_names => value_resource_names = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_resources => value_resources = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_verbs => value_verbs = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_api_groups = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_resource_names = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_resources = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_verbs = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -83,26 +101,97 @@ impl<'de> crate::serde::Deserialize<'de> for ResourceRule {
                     api_groups: value_api_groups,
                     resource_names: value_resource_names,
                     resources: value_resources,
-                    verbs: value_verbs.ok_or_else(|| crate::serde::de::Error::missing_field("verbs"))?,
+                    verbs: value_verbs.ok_or_else(|| crate::serde2::missing_field("ResourceRule", "verbs", &["verbs"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ResourceRule {
+                    api_groups: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    resource_names: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    resources: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    verbs: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "ResourceRule",
-            &[
-                "apiGroups",
-                "resourceNames",
-                "resources",
-                "verbs",
-            ],
+            ResourceRule::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ResourceRule> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ResourceRule")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_api_groups = false;
+                let mut has_resource_names = false;
+                let mut has_resources = false;
+                let mut has_verbs = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ResourceRule", ResourceRule::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.api_groups))?; has_api_groups = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.resource_names))?; has_resource_names = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.resources))?; has_resources = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.verbs))?; has_verbs = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_api_groups {
+                    self.0.api_groups = None;
+                }
+                if !has_resource_names {
+                    self.0.resource_names = None;
+                }
+                if !has_resources {
+                    self.0.resources = None;
+                }
+                if !has_verbs {
+                    return Err(crate::serde2::missing_field("ResourceRule", "verbs", &["verbs"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.api_groups))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.resource_names))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.resources))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.verbs))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ResourceRule",
+            ResourceRule::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ResourceRule {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ResourceRule", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiGroups", &self.api_groups)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resourceNames", &self.resource_names)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resources", &self.resources)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "verbs", &self.verbs)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ResourceRule",
             1 +
@@ -124,10 +213,114 @@ impl crate::serde::Serialize for ResourceRule {
     }
 }
 
+/// A builder of [`ResourceRule`]. Created with [`ResourceRule::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ResourceRuleBuilder {
+    api_groups: Option<Vec<String>>,
+    resource_names: Option<Vec<String>>,
+    resources: Option<Vec<String>>,
+    verbs: Option<Vec<String>>,
+}
+
+#[cfg(feature = "builder")]
+impl ResourceRule {
+    /// Starts building a `ResourceRule`. Its required fields must be set before [`ResourceRuleBuilder::build`] is called.
+    pub fn builder() -> ResourceRuleBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ResourceRuleBuilder {
+    /// Sets [`ResourceRule::api_groups`].
+    pub fn api_groups(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.api_groups = Some(value.into());
+        self
+    }
+
+    /// Sets [`ResourceRule::resource_names`].
+    pub fn resource_names(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.resource_names = Some(value.into());
+        self
+    }
+
+    /// Sets [`ResourceRule::resources`].
+    pub fn resources(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.resources = Some(value.into());
+        self
+    }
+
+    /// Sets [`ResourceRule::verbs`].
+    pub fn verbs(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.verbs = Some(value.into());
+        self
+    }
+
+    /// Builds the `ResourceRule`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ResourceRule, crate::MissingFieldError> {
+        Ok(ResourceRule {
+            api_groups: self.api_groups,
+            resource_names: self.resource_names,
+            resources: self.resources,
+            verbs: self.verbs.ok_or_else(|| crate::MissingFieldError::new("ResourceRule", "verbs"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`ResourceRule`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ResourceRuleApplyConfiguration {
+    /// See [`ResourceRule::api_groups`].
+    pub api_groups: Option<Vec<String>>,
+
+    /// See [`ResourceRule::resource_names`].
+    pub resource_names: Option<Vec<String>>,
+
+    /// See [`ResourceRule::resources`].
+    pub resources: Option<Vec<String>>,
+
+    /// See [`ResourceRule::verbs`].
+    pub verbs: Option<Vec<String>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ResourceRuleApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ResourceRuleApplyConfiguration",
+            self.api_groups.as_ref().map_or(0, |_| 1) +
+            self.resource_names.as_ref().map_or(0, |_| 1) +
+            self.resources.as_ref().map_or(0, |_| 1) +
+            self.verbs.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.api_groups {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiGroups", value)?;
+        }
+        if let Some(value) = &self.resource_names {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resourceNames", value)?;
+        }
+        if let Some(value) = &self.resources {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resources", value)?;
+        }
+        if let Some(value) = &self.verbs {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "verbs", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ResourceRule {
     fn schema_name() -> String {
-        "io.k8s.api.authorization.v1beta1.ResourceRule".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::authorization::v1beta1::ResourceRule".to_owned()
+        }
+        else {
+            "io.k8s.api.authorization.v1beta1.ResourceRule".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -138,8 +331,9 @@ impl crate::schemars::JsonSchema for ResourceRule {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "apiGroups".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -158,8 +352,8 @@ impl crate::schemars::JsonSchema for ResourceRule {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "resourceNames".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -178,8 +372,8 @@ impl crate::schemars::JsonSchema for ResourceRule {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "resources".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -198,8 +392,8 @@ impl crate::schemars::JsonSchema for ResourceRule {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "verbs".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -218,8 +412,9 @@ impl crate::schemars::JsonSchema for ResourceRule {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "verbs",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -229,3 +424,15 @@ impl crate::schemars::JsonSchema for ResourceRule {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ResourceRule {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ResourceRule {
+            api_groups: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            resource_names: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            resources: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            verbs: crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
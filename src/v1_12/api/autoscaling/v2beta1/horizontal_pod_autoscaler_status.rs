@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.autoscaling.v2beta1.HorizontalPodAutoscalerStatus
 
 /// HorizontalPodAutoscalerStatus describes the current status of a horizontal pod autoscaler.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct HorizontalPodAutoscalerStatus {
     /// conditions is the set of conditions required for this autoscaler to scale its target, and indicates whether or not those conditions are met.
     pub conditions: Vec<crate::api::autoscaling::v2beta1::HorizontalPodAutoscalerCondition>,
@@ -22,47 +22,79 @@ pub struct HorizontalPodAutoscalerStatus {
     pub observed_generation: Option<i64>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for HorizontalPodAutoscalerStatus {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_conditions,
-            Key_current_metrics,
-            Key_current_replicas,
-            Key_desired_replicas,
-            Key_last_scale_time,
-            Key_observed_generation,
-            Other,
+impl HorizontalPodAutoscalerStatus {
+    /// Constructs a `HorizontalPodAutoscalerStatus` from its required fields. Its optional fields are unset.
+    pub fn new(conditions: impl Into<Vec<crate::api::autoscaling::v2beta1::HorizontalPodAutoscalerCondition>>, current_replicas: impl Into<i32>, desired_replicas: impl Into<i32>) -> Self {
+        HorizontalPodAutoscalerStatus {
+            conditions: conditions.into(),
+            current_metrics: None,
+            current_replicas: current_replicas.into(),
+            desired_replicas: desired_replicas.into(),
+            last_scale_time: None,
+            observed_generation: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl HorizontalPodAutoscalerStatus {
+    /// Returns this value with [`HorizontalPodAutoscalerStatus::conditions`] set to the given value.
+    #[must_use]
+    pub fn with_conditions(mut self, value: impl Into<Vec<crate::api::autoscaling::v2beta1::HorizontalPodAutoscalerCondition>>) -> Self {
+        self.conditions = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`HorizontalPodAutoscalerStatus::current_metrics`] set to the given value.
+    #[must_use]
+    pub fn with_current_metrics(mut self, value: impl Into<Vec<crate::api::autoscaling::v2beta1::MetricStatus>>) -> Self {
+        self.current_metrics = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`HorizontalPodAutoscalerStatus::current_replicas`] set to the given value.
+    #[must_use]
+    pub fn with_current_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.current_replicas = value.into();
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "conditions" => Field::Key_conditions,
-                            "currentMetrics" => Field::Key_current_metrics,
-                            "currentReplicas" => Field::Key_current_replicas,
-                            "desiredReplicas" => Field::Key_desired_replicas,
-                            "lastScaleTime" => Field::Key_last_scale_time,
-                            "observedGeneration" => Field::Key_observed_generation,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`HorizontalPodAutoscalerStatus::desired_replicas`] set to the given value.
+    #[must_use]
+    pub fn with_desired_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.desired_replicas = value.into();
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`HorizontalPodAutoscalerStatus::last_scale_time`] set to the given value.
+    #[must_use]
+    pub fn with_last_scale_time(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.last_scale_time = Some(value.into());
+        self
+    }
 
+    /// Returns this value with [`HorizontalPodAutoscalerStatus::observed_generation`] set to the given value.
+    #[must_use]
+    pub fn with_observed_generation(mut self, value: impl Into<i64>) -> Self {
+        self.observed_generation = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for HorizontalPodAutoscalerStatus {}
+
+impl HorizontalPodAutoscalerStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "conditions",
+        "currentMetrics",
+        "currentReplicas",
+        "desiredReplicas",
+        "lastScaleTime",
+        "observedGeneration",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for HorizontalPodAutoscalerStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -80,46 +112,131 @@ impl<'de> crate::serde::Deserialize<'de> for HorizontalPodAutoscalerStatus {
                 let mut value_last_scale_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
                 let mut value_observed_generation: Option<i64> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("HorizontalPodAutoscalerStatus", HorizontalPodAutoscalerStatus::FIELDS))? {
                     match key {
-                        Field::Key_conditions => value_conditions = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_current_metrics => value_current_metrics = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_current_replicas => value_current_replicas = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_desired_replicas => value_desired_replicas = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_last_scale_time => value_last_scale_time = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_observed_generation => value_observed_generation = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_conditions = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_current_metrics = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_current_replicas = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_desired_replicas = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(4) => value_last_scale_time = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_observed_generation = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(HorizontalPodAutoscalerStatus {
-                    conditions: value_conditions.ok_or_else(|| crate::serde::de::Error::missing_field("conditions"))?,
+                    conditions: value_conditions.ok_or_else(|| crate::serde2::missing_field("HorizontalPodAutoscalerStatus", "conditions", &["conditions", "currentReplicas", "desiredReplicas"]))?,
                     current_metrics: value_current_metrics,
-                    current_replicas: value_current_replicas.ok_or_else(|| crate::serde::de::Error::missing_field("currentReplicas"))?,
-                    desired_replicas: value_desired_replicas.ok_or_else(|| crate::serde::de::Error::missing_field("desiredReplicas"))?,
+                    current_replicas: value_current_replicas.ok_or_else(|| crate::serde2::missing_field("HorizontalPodAutoscalerStatus", "currentReplicas", &["conditions", "currentReplicas", "desiredReplicas"]))?,
+                    desired_replicas: value_desired_replicas.ok_or_else(|| crate::serde2::missing_field("HorizontalPodAutoscalerStatus", "desiredReplicas", &["conditions", "currentReplicas", "desiredReplicas"]))?,
                     last_scale_time: value_last_scale_time,
                     observed_generation: value_observed_generation,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(HorizontalPodAutoscalerStatus {
+                    conditions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    current_metrics: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    current_replicas: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    desired_replicas: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    last_scale_time: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    observed_generation: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "HorizontalPodAutoscalerStatus",
-            &[
-                "conditions",
-                "currentMetrics",
-                "currentReplicas",
-                "desiredReplicas",
-                "lastScaleTime",
-                "observedGeneration",
-            ],
+            HorizontalPodAutoscalerStatus::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, HorizontalPodAutoscalerStatus> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("HorizontalPodAutoscalerStatus")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_conditions = false;
+                let mut has_current_metrics = false;
+                let mut has_current_replicas = false;
+                let mut has_desired_replicas = false;
+                let mut has_last_scale_time = false;
+                let mut has_observed_generation = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("HorizontalPodAutoscalerStatus", HorizontalPodAutoscalerStatus::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.conditions))?; has_conditions = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.current_metrics))?; has_current_metrics = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.current_replicas))?; has_current_replicas = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.desired_replicas))?; has_desired_replicas = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.last_scale_time))?; has_last_scale_time = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.observed_generation))?; has_observed_generation = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_conditions {
+                    return Err(crate::serde2::missing_field("HorizontalPodAutoscalerStatus", "conditions", &["conditions", "currentReplicas", "desiredReplicas"]));
+                }
+                if !has_current_metrics {
+                    self.0.current_metrics = None;
+                }
+                if !has_current_replicas {
+                    return Err(crate::serde2::missing_field("HorizontalPodAutoscalerStatus", "currentReplicas", &["conditions", "currentReplicas", "desiredReplicas"]));
+                }
+                if !has_desired_replicas {
+                    return Err(crate::serde2::missing_field("HorizontalPodAutoscalerStatus", "desiredReplicas", &["conditions", "currentReplicas", "desiredReplicas"]));
+                }
+                if !has_last_scale_time {
+                    self.0.last_scale_time = None;
+                }
+                if !has_observed_generation {
+                    self.0.observed_generation = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.conditions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.current_metrics))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.current_replicas))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.desired_replicas))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.last_scale_time))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.observed_generation))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "HorizontalPodAutoscalerStatus",
+            HorizontalPodAutoscalerStatus::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for HorizontalPodAutoscalerStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("HorizontalPodAutoscalerStatus", 6)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conditions", &self.conditions)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "currentMetrics", &self.current_metrics)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "currentReplicas", &self.current_replicas)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "desiredReplicas", &self.desired_replicas)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "lastScaleTime", &self.last_scale_time)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "observedGeneration", &self.observed_generation)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "HorizontalPodAutoscalerStatus",
             3 +
@@ -143,10 +260,144 @@ impl crate::serde::Serialize for HorizontalPodAutoscalerStatus {
     }
 }
 
+/// A builder of [`HorizontalPodAutoscalerStatus`]. Created with [`HorizontalPodAutoscalerStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct HorizontalPodAutoscalerStatusBuilder {
+    conditions: Option<Vec<crate::api::autoscaling::v2beta1::HorizontalPodAutoscalerCondition>>,
+    current_metrics: Option<Vec<crate::api::autoscaling::v2beta1::MetricStatus>>,
+    current_replicas: Option<i32>,
+    desired_replicas: Option<i32>,
+    last_scale_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+    observed_generation: Option<i64>,
+}
+
+#[cfg(feature = "builder")]
+impl HorizontalPodAutoscalerStatus {
+    /// Starts building a `HorizontalPodAutoscalerStatus`. Its required fields must be set before [`HorizontalPodAutoscalerStatusBuilder::build`] is called.
+    pub fn builder() -> HorizontalPodAutoscalerStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl HorizontalPodAutoscalerStatusBuilder {
+    /// Sets [`HorizontalPodAutoscalerStatus::conditions`].
+    pub fn conditions(mut self, value: impl Into<Vec<crate::api::autoscaling::v2beta1::HorizontalPodAutoscalerCondition>>) -> Self {
+        self.conditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`HorizontalPodAutoscalerStatus::current_metrics`].
+    pub fn current_metrics(mut self, value: impl Into<Vec<crate::api::autoscaling::v2beta1::MetricStatus>>) -> Self {
+        self.current_metrics = Some(value.into());
+        self
+    }
+
+    /// Sets [`HorizontalPodAutoscalerStatus::current_replicas`].
+    pub fn current_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.current_replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`HorizontalPodAutoscalerStatus::desired_replicas`].
+    pub fn desired_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.desired_replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`HorizontalPodAutoscalerStatus::last_scale_time`].
+    pub fn last_scale_time(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.last_scale_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`HorizontalPodAutoscalerStatus::observed_generation`].
+    pub fn observed_generation(mut self, value: impl Into<i64>) -> Self {
+        self.observed_generation = Some(value.into());
+        self
+    }
+
+    /// Builds the `HorizontalPodAutoscalerStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<HorizontalPodAutoscalerStatus, crate::MissingFieldError> {
+        Ok(HorizontalPodAutoscalerStatus {
+            conditions: self.conditions.ok_or_else(|| crate::MissingFieldError::new("HorizontalPodAutoscalerStatus", "conditions"))?,
+            current_metrics: self.current_metrics,
+            current_replicas: self.current_replicas.ok_or_else(|| crate::MissingFieldError::new("HorizontalPodAutoscalerStatus", "currentReplicas"))?,
+            desired_replicas: self.desired_replicas.ok_or_else(|| crate::MissingFieldError::new("HorizontalPodAutoscalerStatus", "desiredReplicas"))?,
+            last_scale_time: self.last_scale_time,
+            observed_generation: self.observed_generation,
+        })
+    }
+}
+
+/// The apply configuration of [`HorizontalPodAutoscalerStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HorizontalPodAutoscalerStatusApplyConfiguration {
+    /// See [`HorizontalPodAutoscalerStatus::conditions`].
+    pub conditions: Option<Vec<crate::api::autoscaling::v2beta1::HorizontalPodAutoscalerConditionApplyConfiguration>>,
+
+    /// See [`HorizontalPodAutoscalerStatus::current_metrics`].
+    pub current_metrics: Option<Vec<crate::api::autoscaling::v2beta1::MetricStatusApplyConfiguration>>,
+
+    /// See [`HorizontalPodAutoscalerStatus::current_replicas`].
+    pub current_replicas: Option<i32>,
+
+    /// See [`HorizontalPodAutoscalerStatus::desired_replicas`].
+    pub desired_replicas: Option<i32>,
+
+    /// See [`HorizontalPodAutoscalerStatus::last_scale_time`].
+    pub last_scale_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+
+    /// See [`HorizontalPodAutoscalerStatus::observed_generation`].
+    pub observed_generation: Option<i64>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for HorizontalPodAutoscalerStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "HorizontalPodAutoscalerStatusApplyConfiguration",
+            self.conditions.as_ref().map_or(0, |_| 1) +
+            self.current_metrics.as_ref().map_or(0, |_| 1) +
+            self.current_replicas.as_ref().map_or(0, |_| 1) +
+            self.desired_replicas.as_ref().map_or(0, |_| 1) +
+            self.last_scale_time.as_ref().map_or(0, |_| 1) +
+            self.observed_generation.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.conditions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conditions", value)?;
+        }
+        if let Some(value) = &self.current_metrics {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "currentMetrics", value)?;
+        }
+        if let Some(value) = &self.current_replicas {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "currentReplicas", value)?;
+        }
+        if let Some(value) = &self.desired_replicas {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "desiredReplicas", value)?;
+        }
+        if let Some(value) = &self.last_scale_time {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "lastScaleTime", value)?;
+        }
+        if let Some(value) = &self.observed_generation {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "observedGeneration", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for HorizontalPodAutoscalerStatus {
     fn schema_name() -> String {
-        "io.k8s.api.autoscaling.v2beta1.HorizontalPodAutoscalerStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::autoscaling::v2beta1::HorizontalPodAutoscalerStatus".to_owned()
+        }
+        else {
+            "io.k8s.api.autoscaling.v2beta1.HorizontalPodAutoscalerStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -157,8 +408,9 @@ impl crate::schemars::JsonSchema for HorizontalPodAutoscalerStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "conditions".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -172,8 +424,8 @@ impl crate::schemars::JsonSchema for HorizontalPodAutoscalerStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "currentMetrics".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -187,8 +439,8 @@ impl crate::schemars::JsonSchema for HorizontalPodAutoscalerStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "currentReplicas".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -199,8 +451,8 @@ impl crate::schemars::JsonSchema for HorizontalPodAutoscalerStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "desiredReplicas".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -211,8 +463,8 @@ impl crate::schemars::JsonSchema for HorizontalPodAutoscalerStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "lastScaleTime".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::Time>().into_object();
@@ -222,8 +474,8 @@ impl crate::schemars::JsonSchema for HorizontalPodAutoscalerStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "observedGeneration".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -234,8 +486,9 @@ impl crate::schemars::JsonSchema for HorizontalPodAutoscalerStatus {
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "conditions",
                     "currentReplicas",
@@ -247,3 +500,17 @@ impl crate::schemars::JsonSchema for HorizontalPodAutoscalerStatus {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for HorizontalPodAutoscalerStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(HorizontalPodAutoscalerStatus {
+            conditions: crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            current_metrics: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            current_replicas: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            desired_replicas: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            last_scale_time: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            observed_generation: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
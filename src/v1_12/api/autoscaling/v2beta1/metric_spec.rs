@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.autoscaling.v2beta1.MetricSpec
 
 /// MetricSpec specifies how to scale based on a single metric (only `type` and one other matching field should be set at once).
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct MetricSpec {
     /// external refers to a global metric that is not associated with any Kubernetes object. It allows autoscaling based on information coming from components running outside of cluster (for example length of queue in cloud messaging service, or QPS from loadbalancer running outside of cluster).
     pub external: Option<crate::api::autoscaling::v2beta1::ExternalMetricSource>,
@@ -19,45 +19,70 @@ pub struct MetricSpec {
     pub type_: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for MetricSpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_external,
-            Key_object,
-            Key_pods,
-            Key_resource,
-            Key_type_,
-            Other,
+impl MetricSpec {
+    /// Constructs a `MetricSpec` from its required fields. Its optional fields are unset.
+    pub fn new(type_: impl Into<String>) -> Self {
+        MetricSpec {
+            external: None,
+            object: None,
+            pods: None,
+            resource: None,
+            type_: type_.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl MetricSpec {
+    /// Returns this value with [`MetricSpec::external`] set to the given value.
+    #[must_use]
+    pub fn with_external(mut self, value: impl Into<crate::api::autoscaling::v2beta1::ExternalMetricSource>) -> Self {
+        self.external = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`MetricSpec::object`] set to the given value.
+    #[must_use]
+    pub fn with_object(mut self, value: impl Into<crate::api::autoscaling::v2beta1::ObjectMetricSource>) -> Self {
+        self.object = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`MetricSpec::pods`] set to the given value.
+    #[must_use]
+    pub fn with_pods(mut self, value: impl Into<crate::api::autoscaling::v2beta1::PodsMetricSource>) -> Self {
+        self.pods = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "external" => Field::Key_external,
-                            "object" => Field::Key_object,
-                            "pods" => Field::Key_pods,
-                            "resource" => Field::Key_resource,
-                            "type" => Field::Key_type_,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`MetricSpec::resource`] set to the given value.
+    #[must_use]
+    pub fn with_resource(mut self, value: impl Into<crate::api::autoscaling::v2beta1::ResourceMetricSource>) -> Self {
+        self.resource = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`MetricSpec::type_`] set to the given value.
+    #[must_use]
+    pub fn with_type(mut self, value: impl Into<String>) -> Self {
+        self.type_ = value.into();
+        self
+    }
+}
 
+impl crate::MergeStrategyInfo for MetricSpec {}
+
+impl MetricSpec {
+    const FIELDS: &'static [&'static str] = &[
+        "external",
+        "object",
+        "pods",
+        "resource",
+        "type",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for MetricSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -74,14 +99,14 @@ impl<'de> crate::serde::Deserialize<'de> for MetricSpec {
                 let mut value_resource: Option<crate::api::autoscaling::v2beta1::ResourceMetricSource> = None;
                 let mut value_type_: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("MetricSpec", MetricSpec::FIELDS))? {
                     match key {
-                        Field::Key_external => value_external = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_object => value_object = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_pods => value_pods = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_resource => value_resource = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_type_ => value_type_ = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_external = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_object = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_pods = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_resource = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_type_ = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -90,27 +115,105 @@ impl<'de> crate::serde::Deserialize<'de> for MetricSpec {
                     object: value_object,
                     pods: value_pods,
                     resource: value_resource,
-                    type_: value_type_.ok_or_else(|| crate::serde::de::Error::missing_field("type"))?,
+                    type_: value_type_.ok_or_else(|| crate::serde2::missing_field("MetricSpec", "type", &["type"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(MetricSpec {
+                    external: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    object: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    pods: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    resource: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    type_: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "MetricSpec",
-            &[
-                "external",
-                "object",
-                "pods",
-                "resource",
-                "type",
-            ],
+            MetricSpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, MetricSpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("MetricSpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_external = false;
+                let mut has_object = false;
+                let mut has_pods = false;
+                let mut has_resource = false;
+                let mut has_type_ = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("MetricSpec", MetricSpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.external))?; has_external = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.object))?; has_object = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.pods))?; has_pods = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.resource))?; has_resource = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.type_))?; has_type_ = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_external {
+                    self.0.external = None;
+                }
+                if !has_object {
+                    self.0.object = None;
+                }
+                if !has_pods {
+                    self.0.pods = None;
+                }
+                if !has_resource {
+                    self.0.resource = None;
+                }
+                if !has_type_ {
+                    return Err(crate::serde2::missing_field("MetricSpec", "type", &["type"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.external))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.object))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.pods))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.resource))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.type_))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "MetricSpec",
+            MetricSpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for MetricSpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("MetricSpec", 5)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "external", &self.external)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "object", &self.object)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "pods", &self.pods)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resource", &self.resource)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "type", &self.type_)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "MetricSpec",
             1 +
@@ -136,10 +239,129 @@ impl crate::serde::Serialize for MetricSpec {
     }
 }
 
+/// A builder of [`MetricSpec`]. Created with [`MetricSpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct MetricSpecBuilder {
+    external: Option<crate::api::autoscaling::v2beta1::ExternalMetricSource>,
+    object: Option<crate::api::autoscaling::v2beta1::ObjectMetricSource>,
+    pods: Option<crate::api::autoscaling::v2beta1::PodsMetricSource>,
+    resource: Option<crate::api::autoscaling::v2beta1::ResourceMetricSource>,
+    type_: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl MetricSpec {
+    /// Starts building a `MetricSpec`. Its required fields must be set before [`MetricSpecBuilder::build`] is called.
+    pub fn builder() -> MetricSpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl MetricSpecBuilder {
+    /// Sets [`MetricSpec::external`].
+    pub fn external(mut self, value: impl Into<crate::api::autoscaling::v2beta1::ExternalMetricSource>) -> Self {
+        self.external = Some(value.into());
+        self
+    }
+
+    /// Sets [`MetricSpec::object`].
+    pub fn object(mut self, value: impl Into<crate::api::autoscaling::v2beta1::ObjectMetricSource>) -> Self {
+        self.object = Some(value.into());
+        self
+    }
+
+    /// Sets [`MetricSpec::pods`].
+    pub fn pods(mut self, value: impl Into<crate::api::autoscaling::v2beta1::PodsMetricSource>) -> Self {
+        self.pods = Some(value.into());
+        self
+    }
+
+    /// Sets [`MetricSpec::resource`].
+    pub fn resource(mut self, value: impl Into<crate::api::autoscaling::v2beta1::ResourceMetricSource>) -> Self {
+        self.resource = Some(value.into());
+        self
+    }
+
+    /// Sets [`MetricSpec::type_`].
+    pub fn type_(mut self, value: impl Into<String>) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `MetricSpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<MetricSpec, crate::MissingFieldError> {
+        Ok(MetricSpec {
+            external: self.external,
+            object: self.object,
+            pods: self.pods,
+            resource: self.resource,
+            type_: self.type_.ok_or_else(|| crate::MissingFieldError::new("MetricSpec", "type"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`MetricSpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MetricSpecApplyConfiguration {
+    /// See [`MetricSpec::external`].
+    pub external: Option<crate::api::autoscaling::v2beta1::ExternalMetricSourceApplyConfiguration>,
+
+    /// See [`MetricSpec::object`].
+    pub object: Option<crate::api::autoscaling::v2beta1::ObjectMetricSourceApplyConfiguration>,
+
+    /// See [`MetricSpec::pods`].
+    pub pods: Option<crate::api::autoscaling::v2beta1::PodsMetricSourceApplyConfiguration>,
+
+    /// See [`MetricSpec::resource`].
+    pub resource: Option<crate::api::autoscaling::v2beta1::ResourceMetricSourceApplyConfiguration>,
+
+    /// See [`MetricSpec::type_`].
+    pub type_: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for MetricSpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "MetricSpecApplyConfiguration",
+            self.external.as_ref().map_or(0, |_| 1) +
+            self.object.as_ref().map_or(0, |_| 1) +
+            self.pods.as_ref().map_or(0, |_| 1) +
+            self.resource.as_ref().map_or(0, |_| 1) +
+            self.type_.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.external {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "external", value)?;
+        }
+        if let Some(value) = &self.object {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "object", value)?;
+        }
+        if let Some(value) = &self.pods {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "pods", value)?;
+        }
+        if let Some(value) = &self.resource {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resource", value)?;
+        }
+        if let Some(value) = &self.type_ {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "type", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for MetricSpec {
     fn schema_name() -> String {
-        "io.k8s.api.autoscaling.v2beta1.MetricSpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::autoscaling::v2beta1::MetricSpec".to_owned()
+        }
+        else {
+            "io.k8s.api.autoscaling.v2beta1.MetricSpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -150,8 +372,9 @@ impl crate::schemars::JsonSchema for MetricSpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "external".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::autoscaling::v2beta1::ExternalMetricSource>().into_object();
@@ -161,8 +384,8 @@ impl crate::schemars::JsonSchema for MetricSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "object".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::autoscaling::v2beta1::ObjectMetricSource>().into_object();
@@ -172,8 +395,8 @@ impl crate::schemars::JsonSchema for MetricSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "pods".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::autoscaling::v2beta1::PodsMetricSource>().into_object();
@@ -183,8 +406,8 @@ impl crate::schemars::JsonSchema for MetricSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "resource".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::autoscaling::v2beta1::ResourceMetricSource>().into_object();
@@ -194,8 +417,8 @@ impl crate::schemars::JsonSchema for MetricSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "type".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -205,8 +428,9 @@ impl crate::schemars::JsonSchema for MetricSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "type",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -216,3 +440,58 @@ impl crate::schemars::JsonSchema for MetricSpec {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for MetricSpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(MetricSpec {
+            external: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            object: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            pods: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            resource: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            type_: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
+
+/// The property of a [`MetricSpec`] that is set, out of the properties that are mutually exclusive. Returned by [`MetricSpec::source`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MetricSource<'a> {
+    /// [`MetricSpec::external`]
+    External(&'a crate::api::autoscaling::v2beta1::ExternalMetricSource),
+
+    /// [`MetricSpec::object`]
+    Object(&'a crate::api::autoscaling::v2beta1::ObjectMetricSource),
+
+    /// [`MetricSpec::pods`]
+    Pods(&'a crate::api::autoscaling::v2beta1::PodsMetricSource),
+
+    /// [`MetricSpec::resource`]
+    Resource(&'a crate::api::autoscaling::v2beta1::ResourceMetricSource),
+}
+
+impl MetricSpec {
+    /// Returns the property of this `MetricSpec` that is set, out of the properties that are mutually exclusive.
+    ///
+    /// Returns `None` if none of them is set. If more than one of them is set, which the API server rejects,
+    /// the one that comes first in the order of the variants of [`MetricSource`] is returned.
+    pub fn source(&self) -> Option<MetricSource<'_>> {
+        if let Some(value) = &self.external {
+            return Some(MetricSource::External(value));
+        }
+
+        if let Some(value) = &self.object {
+            return Some(MetricSource::Object(value));
+        }
+
+        if let Some(value) = &self.pods {
+            return Some(MetricSource::Pods(value));
+        }
+
+        if let Some(value) = &self.resource {
+            return Some(MetricSource::Resource(value));
+        }
+
+        None
+    }
+}
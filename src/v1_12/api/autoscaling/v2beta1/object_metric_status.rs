@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.autoscaling.v2beta1.ObjectMetricStatus
 
 /// ObjectMetricStatus indicates the current value of a metric describing a kubernetes object (for example, hits-per-second on an Ingress object).
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ObjectMetricStatus {
     /// averageValue is the current value of the average of the metric across all relevant pods (as a quantity)
     pub average_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
@@ -19,45 +19,70 @@ pub struct ObjectMetricStatus {
     pub target: crate::api::autoscaling::v2beta1::CrossVersionObjectReference,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ObjectMetricStatus {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_average_value,
-            Key_current_value,
-            Key_metric_name,
-            Key_selector,
-            Key_target,
-            Other,
+impl ObjectMetricStatus {
+    /// Constructs a `ObjectMetricStatus` from its required fields. Its optional fields are unset.
+    pub fn new(current_value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>, metric_name: impl Into<String>, target: impl Into<crate::api::autoscaling::v2beta1::CrossVersionObjectReference>) -> Self {
+        ObjectMetricStatus {
+            average_value: None,
+            current_value: current_value.into(),
+            metric_name: metric_name.into(),
+            selector: None,
+            target: target.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl ObjectMetricStatus {
+    /// Returns this value with [`ObjectMetricStatus::average_value`] set to the given value.
+    #[must_use]
+    pub fn with_average_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.average_value = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ObjectMetricStatus::current_value`] set to the given value.
+    #[must_use]
+    pub fn with_current_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.current_value = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ObjectMetricStatus::metric_name`] set to the given value.
+    #[must_use]
+    pub fn with_metric_name(mut self, value: impl Into<String>) -> Self {
+        self.metric_name = value.into();
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "averageValue" => Field::Key_average_value,
-                            "currentValue" => Field::Key_current_value,
-                            "metricName" => Field::Key_metric_name,
-                            "selector" => Field::Key_selector,
-                            "target" => Field::Key_target,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`ObjectMetricStatus::selector`] set to the given value.
+    #[must_use]
+    pub fn with_selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.selector = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`ObjectMetricStatus::target`] set to the given value.
+    #[must_use]
+    pub fn with_target(mut self, value: impl Into<crate::api::autoscaling::v2beta1::CrossVersionObjectReference>) -> Self {
+        self.target = value.into();
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for ObjectMetricStatus {}
 
+impl ObjectMetricStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "averageValue",
+        "currentValue",
+        "metricName",
+        "selector",
+        "target",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for ObjectMetricStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -74,43 +99,121 @@ impl<'de> crate::serde::Deserialize<'de> for ObjectMetricStatus {
                 let mut value_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> = None;
                 let mut value_target: Option<crate::api::autoscaling::v2beta1::CrossVersionObjectReference> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ObjectMetricStatus", ObjectMetricStatus::FIELDS))? {
                     match key {
-                        Field::Key_average_value => value_average_value = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_current_value => value_current_value = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_metric_name => value_metric_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_selector => value_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_target => value_target = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_average_value = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_current_value = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_metric_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_target = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ObjectMetricStatus {
                     average_value: value_average_value,
-                    current_value: value_current_value.ok_or_else(|| crate::serde::de::Error::missing_field("currentValue"))?,
-                    metric_name: value_metric_name.ok_or_else(|| crate::serde::de::Error::missing_field("metricName"))?,
+                    current_value: value_current_value.ok_or_else(|| crate::serde2::missing_field("ObjectMetricStatus", "currentValue", &["currentValue", "metricName", "target"]))?,
+                    metric_name: value_metric_name.ok_or_else(|| crate::serde2::missing_field("ObjectMetricStatus", "metricName", &["currentValue", "metricName", "target"]))?,
                     selector: value_selector,
-                    target: value_target.ok_or_else(|| crate::serde::de::Error::missing_field("target"))?,
+                    target: value_target.ok_or_else(|| crate::serde2::missing_field("ObjectMetricStatus", "target", &["currentValue", "metricName", "target"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ObjectMetricStatus {
+                    average_value: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    current_value: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    metric_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    selector: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    target: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "ObjectMetricStatus",
-            &[
-                "averageValue",
-                "currentValue",
-                "metricName",
-                "selector",
-                "target",
-            ],
+            ObjectMetricStatus::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ObjectMetricStatus> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ObjectMetricStatus")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_average_value = false;
+                let mut has_current_value = false;
+                let mut has_metric_name = false;
+                let mut has_selector = false;
+                let mut has_target = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ObjectMetricStatus", ObjectMetricStatus::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.average_value))?; has_average_value = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.current_value))?; has_current_value = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metric_name))?; has_metric_name = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.selector))?; has_selector = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.target))?; has_target = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_average_value {
+                    self.0.average_value = None;
+                }
+                if !has_current_value {
+                    return Err(crate::serde2::missing_field("ObjectMetricStatus", "currentValue", &["currentValue", "metricName", "target"]));
+                }
+                if !has_metric_name {
+                    return Err(crate::serde2::missing_field("ObjectMetricStatus", "metricName", &["currentValue", "metricName", "target"]));
+                }
+                if !has_selector {
+                    self.0.selector = None;
+                }
+                if !has_target {
+                    return Err(crate::serde2::missing_field("ObjectMetricStatus", "target", &["currentValue", "metricName", "target"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.average_value))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.current_value))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metric_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.selector))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.target))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ObjectMetricStatus",
+            ObjectMetricStatus::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ObjectMetricStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ObjectMetricStatus", 5)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "averageValue", &self.average_value)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "currentValue", &self.current_value)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricName", &self.metric_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selector", &self.selector)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "target", &self.target)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ObjectMetricStatus",
             3 +
@@ -130,10 +233,129 @@ impl crate::serde::Serialize for ObjectMetricStatus {
     }
 }
 
+/// A builder of [`ObjectMetricStatus`]. Created with [`ObjectMetricStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ObjectMetricStatusBuilder {
+    average_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+    current_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+    metric_name: Option<String>,
+    selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>,
+    target: Option<crate::api::autoscaling::v2beta1::CrossVersionObjectReference>,
+}
+
+#[cfg(feature = "builder")]
+impl ObjectMetricStatus {
+    /// Starts building a `ObjectMetricStatus`. Its required fields must be set before [`ObjectMetricStatusBuilder::build`] is called.
+    pub fn builder() -> ObjectMetricStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ObjectMetricStatusBuilder {
+    /// Sets [`ObjectMetricStatus::average_value`].
+    pub fn average_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.average_value = Some(value.into());
+        self
+    }
+
+    /// Sets [`ObjectMetricStatus::current_value`].
+    pub fn current_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.current_value = Some(value.into());
+        self
+    }
+
+    /// Sets [`ObjectMetricStatus::metric_name`].
+    pub fn metric_name(mut self, value: impl Into<String>) -> Self {
+        self.metric_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`ObjectMetricStatus::selector`].
+    pub fn selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`ObjectMetricStatus::target`].
+    pub fn target(mut self, value: impl Into<crate::api::autoscaling::v2beta1::CrossVersionObjectReference>) -> Self {
+        self.target = Some(value.into());
+        self
+    }
+
+    /// Builds the `ObjectMetricStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ObjectMetricStatus, crate::MissingFieldError> {
+        Ok(ObjectMetricStatus {
+            average_value: self.average_value,
+            current_value: self.current_value.ok_or_else(|| crate::MissingFieldError::new("ObjectMetricStatus", "currentValue"))?,
+            metric_name: self.metric_name.ok_or_else(|| crate::MissingFieldError::new("ObjectMetricStatus", "metricName"))?,
+            selector: self.selector,
+            target: self.target.ok_or_else(|| crate::MissingFieldError::new("ObjectMetricStatus", "target"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`ObjectMetricStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ObjectMetricStatusApplyConfiguration {
+    /// See [`ObjectMetricStatus::average_value`].
+    pub average_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+
+    /// See [`ObjectMetricStatus::current_value`].
+    pub current_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+
+    /// See [`ObjectMetricStatus::metric_name`].
+    pub metric_name: Option<String>,
+
+    /// See [`ObjectMetricStatus::selector`].
+    pub selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorApplyConfiguration>,
+
+    /// See [`ObjectMetricStatus::target`].
+    pub target: Option<crate::api::autoscaling::v2beta1::CrossVersionObjectReferenceApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ObjectMetricStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ObjectMetricStatusApplyConfiguration",
+            self.average_value.as_ref().map_or(0, |_| 1) +
+            self.current_value.as_ref().map_or(0, |_| 1) +
+            self.metric_name.as_ref().map_or(0, |_| 1) +
+            self.selector.as_ref().map_or(0, |_| 1) +
+            self.target.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.average_value {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "averageValue", value)?;
+        }
+        if let Some(value) = &self.current_value {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "currentValue", value)?;
+        }
+        if let Some(value) = &self.metric_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricName", value)?;
+        }
+        if let Some(value) = &self.selector {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selector", value)?;
+        }
+        if let Some(value) = &self.target {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "target", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ObjectMetricStatus {
     fn schema_name() -> String {
-        "io.k8s.api.autoscaling.v2beta1.ObjectMetricStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::autoscaling::v2beta1::ObjectMetricStatus".to_owned()
+        }
+        else {
+            "io.k8s.api.autoscaling.v2beta1.ObjectMetricStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -144,8 +366,9 @@ impl crate::schemars::JsonSchema for ObjectMetricStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "averageValue".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::api::resource::Quantity>().into_object();
@@ -155,8 +378,8 @@ impl crate::schemars::JsonSchema for ObjectMetricStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "currentValue".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::api::resource::Quantity>().into_object();
@@ -166,8 +389,8 @@ impl crate::schemars::JsonSchema for ObjectMetricStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "metricName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -177,8 +400,8 @@ impl crate::schemars::JsonSchema for ObjectMetricStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "selector".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>().into_object();
@@ -188,8 +411,8 @@ impl crate::schemars::JsonSchema for ObjectMetricStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "target".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::autoscaling::v2beta1::CrossVersionObjectReference>().into_object();
@@ -199,8 +422,9 @@ impl crate::schemars::JsonSchema for ObjectMetricStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "currentValue",
                     "metricName",
@@ -212,3 +436,16 @@ impl crate::schemars::JsonSchema for ObjectMetricStatus {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ObjectMetricStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ObjectMetricStatus {
+            average_value: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            current_value: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            metric_name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            selector: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            target: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.autoscaling.v2beta1.ResourceMetricStatus
 
 /// ResourceMetricStatus indicates the current value of a resource metric known to Kubernetes, as specified in requests and limits, describing each pod in the current scale target (e.g. CPU or memory).  Such metrics are built in to Kubernetes, and have special scaling options on top of those available to normal per-pod metrics using the "pods" source.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ResourceMetricStatus {
     /// currentAverageUtilization is the current value of the average of the resource metric across all relevant pods, represented as a percentage of the requested value of the resource for the pods.  It will only be present if `targetAverageValue` was set in the corresponding metric specification.
     pub current_average_utilization: Option<i32>,
@@ -13,41 +13,52 @@ pub struct ResourceMetricStatus {
     pub name: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ResourceMetricStatus {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_current_average_utilization,
-            Key_current_average_value,
-            Key_name,
-            Other,
+impl ResourceMetricStatus {
+    /// Constructs a `ResourceMetricStatus` from its required fields. Its optional fields are unset.
+    pub fn new(current_average_value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>, name: impl Into<String>) -> Self {
+        ResourceMetricStatus {
+            current_average_utilization: None,
+            current_average_value: current_average_value.into(),
+            name: name.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl ResourceMetricStatus {
+    /// Returns this value with [`ResourceMetricStatus::current_average_utilization`] set to the given value.
+    #[must_use]
+    pub fn with_current_average_utilization(mut self, value: impl Into<i32>) -> Self {
+        self.current_average_utilization = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ResourceMetricStatus::current_average_value`] set to the given value.
+    #[must_use]
+    pub fn with_current_average_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.current_average_value = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ResourceMetricStatus::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "currentAverageUtilization" => Field::Key_current_average_utilization,
-                            "currentAverageValue" => Field::Key_current_average_value,
-                            "name" => Field::Key_name,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for ResourceMetricStatus {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl ResourceMetricStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "currentAverageUtilization",
+        "currentAverageValue",
+        "name",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ResourceMetricStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,37 +73,101 @@ impl<'de> crate::serde::Deserialize<'de> for ResourceMetricStatus {
                 let mut value_current_average_value: Option<crate::apimachinery::pkg::api::resource::Quantity> = None;
                 let mut value_name: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ResourceMetricStatus", ResourceMetricStatus::FIELDS))? {
                     match key {
-                        Field::Key_current_average_utilization => value_current_average_utilization = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_current_average_value => value_current_average_value = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_name => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_current_average_utilization = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_current_average_value = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ResourceMetricStatus {
                     current_average_utilization: value_current_average_utilization,
-                    current_average_value: value_current_average_value.ok_or_else(|| crate::serde::de::Error::missing_field("currentAverageValue"))?,
-                    name: value_name.ok_or_else(|| crate::serde::de::Error::missing_field("name"))?,
+                    current_average_value: value_current_average_value.ok_or_else(|| crate::serde2::missing_field("ResourceMetricStatus", "currentAverageValue", &["currentAverageValue", "name"]))?,
+                    name: value_name.ok_or_else(|| crate::serde2::missing_field("ResourceMetricStatus", "name", &["currentAverageValue", "name"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ResourceMetricStatus {
+                    current_average_utilization: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    current_average_value: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "ResourceMetricStatus",
-            &[
-                "currentAverageUtilization",
-                "currentAverageValue",
-                "name",
-            ],
+            ResourceMetricStatus::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ResourceMetricStatus> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ResourceMetricStatus")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_current_average_utilization = false;
+                let mut has_current_average_value = false;
+                let mut has_name = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ResourceMetricStatus", ResourceMetricStatus::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.current_average_utilization))?; has_current_average_utilization = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.current_average_value))?; has_current_average_value = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.name))?; has_name = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_current_average_utilization {
+                    self.0.current_average_utilization = None;
+                }
+                if !has_current_average_value {
+                    return Err(crate::serde2::missing_field("ResourceMetricStatus", "currentAverageValue", &["currentAverageValue", "name"]));
+                }
+                if !has_name {
+                    return Err(crate::serde2::missing_field("ResourceMetricStatus", "name", &["currentAverageValue", "name"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.current_average_utilization))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.current_average_value))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ResourceMetricStatus",
+            ResourceMetricStatus::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ResourceMetricStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ResourceMetricStatus", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "currentAverageUtilization", &self.current_average_utilization)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "currentAverageValue", &self.current_average_value)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ResourceMetricStatus",
             2 +
@@ -107,10 +182,99 @@ impl crate::serde::Serialize for ResourceMetricStatus {
     }
 }
 
+/// A builder of [`ResourceMetricStatus`]. Created with [`ResourceMetricStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ResourceMetricStatusBuilder {
+    current_average_utilization: Option<i32>,
+    current_average_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+    name: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl ResourceMetricStatus {
+    /// Starts building a `ResourceMetricStatus`. Its required fields must be set before [`ResourceMetricStatusBuilder::build`] is called.
+    pub fn builder() -> ResourceMetricStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ResourceMetricStatusBuilder {
+    /// Sets [`ResourceMetricStatus::current_average_utilization`].
+    pub fn current_average_utilization(mut self, value: impl Into<i32>) -> Self {
+        self.current_average_utilization = Some(value.into());
+        self
+    }
+
+    /// Sets [`ResourceMetricStatus::current_average_value`].
+    pub fn current_average_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.current_average_value = Some(value.into());
+        self
+    }
+
+    /// Sets [`ResourceMetricStatus::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ResourceMetricStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ResourceMetricStatus, crate::MissingFieldError> {
+        Ok(ResourceMetricStatus {
+            current_average_utilization: self.current_average_utilization,
+            current_average_value: self.current_average_value.ok_or_else(|| crate::MissingFieldError::new("ResourceMetricStatus", "currentAverageValue"))?,
+            name: self.name.ok_or_else(|| crate::MissingFieldError::new("ResourceMetricStatus", "name"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`ResourceMetricStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ResourceMetricStatusApplyConfiguration {
+    /// See [`ResourceMetricStatus::current_average_utilization`].
+    pub current_average_utilization: Option<i32>,
+
+    /// See [`ResourceMetricStatus::current_average_value`].
+    pub current_average_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+
+    /// See [`ResourceMetricStatus::name`].
+    pub name: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ResourceMetricStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ResourceMetricStatusApplyConfiguration",
+            self.current_average_utilization.as_ref().map_or(0, |_| 1) +
+            self.current_average_value.as_ref().map_or(0, |_| 1) +
+            self.name.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.current_average_utilization {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "currentAverageUtilization", value)?;
+        }
+        if let Some(value) = &self.current_average_value {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "currentAverageValue", value)?;
+        }
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ResourceMetricStatus {
     fn schema_name() -> String {
-        "io.k8s.api.autoscaling.v2beta1.ResourceMetricStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::autoscaling::v2beta1::ResourceMetricStatus".to_owned()
+        }
+        else {
+            "io.k8s.api.autoscaling.v2beta1.ResourceMetricStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -121,8 +285,9 @@ impl crate::schemars::JsonSchema for ResourceMetricStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "currentAverageUtilization".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -133,8 +298,8 @@ impl crate::schemars::JsonSchema for ResourceMetricStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "currentAverageValue".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::api::resource::Quantity>().into_object();
@@ -144,8 +309,8 @@ impl crate::schemars::JsonSchema for ResourceMetricStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -155,8 +320,9 @@ impl crate::schemars::JsonSchema for ResourceMetricStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "currentAverageValue",
                     "name",
@@ -167,3 +333,14 @@ impl crate::schemars::JsonSchema for ResourceMetricStatus {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ResourceMetricStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ResourceMetricStatus {
+            current_average_utilization: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            current_average_value: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
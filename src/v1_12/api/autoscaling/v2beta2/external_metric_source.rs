@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.autoscaling.v2beta2.ExternalMetricSource
 
 /// ExternalMetricSource indicates how to scale on a metric not associated with any Kubernetes object (for example length of queue in cloud messaging service, or QPS from loadbalancer running outside of cluster).
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ExternalMetricSource {
     /// metric identifies the target metric by name and selector
     pub metric: crate::api::autoscaling::v2beta2::MetricIdentifier,
@@ -10,39 +10,43 @@ pub struct ExternalMetricSource {
     pub target: crate::api::autoscaling::v2beta2::MetricTarget,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ExternalMetricSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_metric,
-            Key_target,
-            Other,
+impl ExternalMetricSource {
+    /// Constructs a `ExternalMetricSource` from its required fields. Its optional fields are unset.
+    pub fn new(metric: impl Into<crate::api::autoscaling::v2beta2::MetricIdentifier>, target: impl Into<crate::api::autoscaling::v2beta2::MetricTarget>) -> Self {
+        ExternalMetricSource {
+            metric: metric.into(),
+            target: target.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl ExternalMetricSource {
+    /// Returns this value with [`ExternalMetricSource::metric`] set to the given value.
+    #[must_use]
+    pub fn with_metric(mut self, value: impl Into<crate::api::autoscaling::v2beta2::MetricIdentifier>) -> Self {
+        self.metric = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ExternalMetricSource::target`] set to the given value.
+    #[must_use]
+    pub fn with_target(mut self, value: impl Into<crate::api::autoscaling::v2beta2::MetricTarget>) -> Self {
+        self.target = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "metric" => Field::Key_metric,
-                            "target" => Field::Key_target,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for ExternalMetricSource {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl ExternalMetricSource {
+    const FIELDS: &'static [&'static str] = &[
+        "metric",
+        "target",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ExternalMetricSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +60,91 @@ impl<'de> crate::serde::Deserialize<'de> for ExternalMetricSource {
                 let mut value_metric: Option<crate::api::autoscaling::v2beta2::MetricIdentifier> = None;
                 let mut value_target: Option<crate::api::autoscaling::v2beta2::MetricTarget> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ExternalMetricSource", ExternalMetricSource::FIELDS))? {
                     match key {
-                        Field::Key_metric => value_metric = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_target => value_target = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_metric = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_target = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ExternalMetricSource {
-                    metric: value_metric.ok_or_else(|| crate::serde::de::Error::missing_field("metric"))?,
-                    target: value_target.ok_or_else(|| crate::serde::de::Error::missing_field("target"))?,
+                    metric: value_metric.ok_or_else(|| crate::serde2::missing_field("ExternalMetricSource", "metric", &["metric", "target"]))?,
+                    target: value_target.ok_or_else(|| crate::serde2::missing_field("ExternalMetricSource", "target", &["metric", "target"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ExternalMetricSource {
+                    metric: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    target: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "ExternalMetricSource",
-            &[
-                "metric",
-                "target",
-            ],
+            ExternalMetricSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ExternalMetricSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ExternalMetricSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_metric = false;
+                let mut has_target = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ExternalMetricSource", ExternalMetricSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metric))?; has_metric = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.target))?; has_target = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_metric {
+                    return Err(crate::serde2::missing_field("ExternalMetricSource", "metric", &["metric", "target"]));
+                }
+                if !has_target {
+                    return Err(crate::serde2::missing_field("ExternalMetricSource", "target", &["metric", "target"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metric))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.target))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ExternalMetricSource",
+            ExternalMetricSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ExternalMetricSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ExternalMetricSource", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metric", &self.metric)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "target", &self.target)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ExternalMetricSource",
             2,
@@ -94,10 +155,84 @@ impl crate::serde::Serialize for ExternalMetricSource {
     }
 }
 
+/// A builder of [`ExternalMetricSource`]. Created with [`ExternalMetricSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ExternalMetricSourceBuilder {
+    metric: Option<crate::api::autoscaling::v2beta2::MetricIdentifier>,
+    target: Option<crate::api::autoscaling::v2beta2::MetricTarget>,
+}
+
+#[cfg(feature = "builder")]
+impl ExternalMetricSource {
+    /// Starts building a `ExternalMetricSource`. Its required fields must be set before [`ExternalMetricSourceBuilder::build`] is called.
+    pub fn builder() -> ExternalMetricSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ExternalMetricSourceBuilder {
+    /// Sets [`ExternalMetricSource::metric`].
+    pub fn metric(mut self, value: impl Into<crate::api::autoscaling::v2beta2::MetricIdentifier>) -> Self {
+        self.metric = Some(value.into());
+        self
+    }
+
+    /// Sets [`ExternalMetricSource::target`].
+    pub fn target(mut self, value: impl Into<crate::api::autoscaling::v2beta2::MetricTarget>) -> Self {
+        self.target = Some(value.into());
+        self
+    }
+
+    /// Builds the `ExternalMetricSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ExternalMetricSource, crate::MissingFieldError> {
+        Ok(ExternalMetricSource {
+            metric: self.metric.ok_or_else(|| crate::MissingFieldError::new("ExternalMetricSource", "metric"))?,
+            target: self.target.ok_or_else(|| crate::MissingFieldError::new("ExternalMetricSource", "target"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`ExternalMetricSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ExternalMetricSourceApplyConfiguration {
+    /// See [`ExternalMetricSource::metric`].
+    pub metric: Option<crate::api::autoscaling::v2beta2::MetricIdentifierApplyConfiguration>,
+
+    /// See [`ExternalMetricSource::target`].
+    pub target: Option<crate::api::autoscaling::v2beta2::MetricTargetApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ExternalMetricSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ExternalMetricSourceApplyConfiguration",
+            self.metric.as_ref().map_or(0, |_| 1) +
+            self.target.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.metric {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metric", value)?;
+        }
+        if let Some(value) = &self.target {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "target", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ExternalMetricSource {
     fn schema_name() -> String {
-        "io.k8s.api.autoscaling.v2beta2.ExternalMetricSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::autoscaling::v2beta2::ExternalMetricSource".to_owned()
+        }
+        else {
+            "io.k8s.api.autoscaling.v2beta2.ExternalMetricSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -108,8 +243,9 @@ impl crate::schemars::JsonSchema for ExternalMetricSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "metric".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::autoscaling::v2beta2::MetricIdentifier>().into_object();
@@ -119,8 +255,8 @@ impl crate::schemars::JsonSchema for ExternalMetricSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "target".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::autoscaling::v2beta2::MetricTarget>().into_object();
@@ -130,8 +266,9 @@ impl crate::schemars::JsonSchema for ExternalMetricSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "metric",
                     "target",
@@ -142,3 +279,13 @@ impl crate::schemars::JsonSchema for ExternalMetricSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ExternalMetricSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ExternalMetricSource {
+            metric: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            target: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.autoscaling.v2beta2.HorizontalPodAutoscalerSpec
 
 /// HorizontalPodAutoscalerSpec describes the desired functionality of the HorizontalPodAutoscaler.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct HorizontalPodAutoscalerSpec {
     /// maxReplicas is the upper limit for the number of replicas to which the autoscaler can scale up. It cannot be less that minReplicas.
     pub max_replicas: i32,
@@ -16,43 +16,61 @@ pub struct HorizontalPodAutoscalerSpec {
     pub scale_target_ref: crate::api::autoscaling::v2beta2::CrossVersionObjectReference,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for HorizontalPodAutoscalerSpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_max_replicas,
-            Key_metrics,
-            Key_min_replicas,
-            Key_scale_target_ref,
-            Other,
+impl HorizontalPodAutoscalerSpec {
+    /// Constructs a `HorizontalPodAutoscalerSpec` from its required fields. Its optional fields are unset.
+    pub fn new(max_replicas: impl Into<i32>, scale_target_ref: impl Into<crate::api::autoscaling::v2beta2::CrossVersionObjectReference>) -> Self {
+        HorizontalPodAutoscalerSpec {
+            max_replicas: max_replicas.into(),
+            metrics: None,
+            min_replicas: None,
+            scale_target_ref: scale_target_ref.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl HorizontalPodAutoscalerSpec {
+    /// Returns this value with [`HorizontalPodAutoscalerSpec::max_replicas`] set to the given value.
+    #[must_use]
+    pub fn with_max_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.max_replicas = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`HorizontalPodAutoscalerSpec::metrics`] set to the given value.
+    #[must_use]
+    pub fn with_metrics(mut self, value: impl Into<Vec<crate::api::autoscaling::v2beta2::MetricSpec>>) -> Self {
+        self.metrics = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`HorizontalPodAutoscalerSpec::min_replicas`] set to the given value.
+    #[must_use]
+    pub fn with_min_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.min_replicas = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "maxReplicas" => Field::Key_max_replicas,
-                            "metrics" => Field::Key_metrics,
-                            "minReplicas" => Field::Key_min_replicas,
-                            "scaleTargetRef" => Field::Key_scale_target_ref,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`HorizontalPodAutoscalerSpec::scale_target_ref`] set to the given value.
+    #[must_use]
+    pub fn with_scale_target_ref(mut self, value: impl Into<crate::api::autoscaling::v2beta2::CrossVersionObjectReference>) -> Self {
+        self.scale_target_ref = value.into();
+        self
+    }
+}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl crate::MergeStrategyInfo for HorizontalPodAutoscalerSpec {}
 
+impl HorizontalPodAutoscalerSpec {
+    const FIELDS: &'static [&'static str] = &[
+        "maxReplicas",
+        "metrics",
+        "minReplicas",
+        "scaleTargetRef",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for HorizontalPodAutoscalerSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -68,40 +86,111 @@ impl<'de> crate::serde::Deserialize<'de> for HorizontalPodAutoscalerSpec {
                 let mut value_min_replicas: Option<i32> = None;
                 let mut value_scale_target_ref: Option<crate::api::autoscaling::v2beta2::CrossVersionObjectReference> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("HorizontalPodAutoscalerSpec", HorizontalPodAutoscalerSpec::FIELDS))? {
                     match key {
-                        Field::Key_max_replicas => value_max_replicas = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_metrics => value_metrics = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_min_replicas => value_min_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_scale_target_ref => value_scale_target_ref = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_max_replicas = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_metrics = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_min_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_scale_target_ref = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(HorizontalPodAutoscalerSpec {
-                    max_replicas: value_max_replicas.ok_or_else(|| crate::serde::de::Error::missing_field("maxReplicas"))?,
+                    max_replicas: value_max_replicas.ok_or_else(|| crate::serde2::missing_field("HorizontalPodAutoscalerSpec", "maxReplicas", &["maxReplicas", "scaleTargetRef"]))?,
                     metrics: value_metrics,
                     min_replicas: value_min_replicas,
-                    scale_target_ref: value_scale_target_ref.ok_or_else(|| crate::serde::de::Error::missing_field("scaleTargetRef"))?,
+                    scale_target_ref: value_scale_target_ref.ok_or_else(|| crate::serde2::missing_field("HorizontalPodAutoscalerSpec", "scaleTargetRef", &["maxReplicas", "scaleTargetRef"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(HorizontalPodAutoscalerSpec {
+                    max_replicas: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    metrics: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    min_replicas: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    scale_target_ref: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "HorizontalPodAutoscalerSpec",
-            &[
-                "maxReplicas",
-                "metrics",
-                "minReplicas",
-                "scaleTargetRef",
-            ],
+            HorizontalPodAutoscalerSpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, HorizontalPodAutoscalerSpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("HorizontalPodAutoscalerSpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_max_replicas = false;
+                let mut has_metrics = false;
+                let mut has_min_replicas = false;
+                let mut has_scale_target_ref = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("HorizontalPodAutoscalerSpec", HorizontalPodAutoscalerSpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.max_replicas))?; has_max_replicas = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.metrics))?; has_metrics = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.min_replicas))?; has_min_replicas = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.scale_target_ref))?; has_scale_target_ref = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_max_replicas {
+                    return Err(crate::serde2::missing_field("HorizontalPodAutoscalerSpec", "maxReplicas", &["maxReplicas", "scaleTargetRef"]));
+                }
+                if !has_metrics {
+                    self.0.metrics = None;
+                }
+                if !has_min_replicas {
+                    self.0.min_replicas = None;
+                }
+                if !has_scale_target_ref {
+                    return Err(crate::serde2::missing_field("HorizontalPodAutoscalerSpec", "scaleTargetRef", &["maxReplicas", "scaleTargetRef"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.max_replicas))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.metrics))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.min_replicas))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.scale_target_ref))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "HorizontalPodAutoscalerSpec",
+            HorizontalPodAutoscalerSpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for HorizontalPodAutoscalerSpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("HorizontalPodAutoscalerSpec", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maxReplicas", &self.max_replicas)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metrics", &self.metrics)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "minReplicas", &self.min_replicas)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "scaleTargetRef", &self.scale_target_ref)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "HorizontalPodAutoscalerSpec",
             2 +
@@ -120,10 +209,114 @@ impl crate::serde::Serialize for HorizontalPodAutoscalerSpec {
     }
 }
 
+/// A builder of [`HorizontalPodAutoscalerSpec`]. Created with [`HorizontalPodAutoscalerSpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct HorizontalPodAutoscalerSpecBuilder {
+    max_replicas: Option<i32>,
+    metrics: Option<Vec<crate::api::autoscaling::v2beta2::MetricSpec>>,
+    min_replicas: Option<i32>,
+    scale_target_ref: Option<crate::api::autoscaling::v2beta2::CrossVersionObjectReference>,
+}
+
+#[cfg(feature = "builder")]
+impl HorizontalPodAutoscalerSpec {
+    /// Starts building a `HorizontalPodAutoscalerSpec`. Its required fields must be set before [`HorizontalPodAutoscalerSpecBuilder::build`] is called.
+    pub fn builder() -> HorizontalPodAutoscalerSpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl HorizontalPodAutoscalerSpecBuilder {
+    /// Sets [`HorizontalPodAutoscalerSpec::max_replicas`].
+    pub fn max_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.max_replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`HorizontalPodAutoscalerSpec::metrics`].
+    pub fn metrics(mut self, value: impl Into<Vec<crate::api::autoscaling::v2beta2::MetricSpec>>) -> Self {
+        self.metrics = Some(value.into());
+        self
+    }
+
+    /// Sets [`HorizontalPodAutoscalerSpec::min_replicas`].
+    pub fn min_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.min_replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`HorizontalPodAutoscalerSpec::scale_target_ref`].
+    pub fn scale_target_ref(mut self, value: impl Into<crate::api::autoscaling::v2beta2::CrossVersionObjectReference>) -> Self {
+        self.scale_target_ref = Some(value.into());
+        self
+    }
+
+    /// Builds the `HorizontalPodAutoscalerSpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<HorizontalPodAutoscalerSpec, crate::MissingFieldError> {
+        Ok(HorizontalPodAutoscalerSpec {
+            max_replicas: self.max_replicas.ok_or_else(|| crate::MissingFieldError::new("HorizontalPodAutoscalerSpec", "maxReplicas"))?,
+            metrics: self.metrics,
+            min_replicas: self.min_replicas,
+            scale_target_ref: self.scale_target_ref.ok_or_else(|| crate::MissingFieldError::new("HorizontalPodAutoscalerSpec", "scaleTargetRef"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`HorizontalPodAutoscalerSpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HorizontalPodAutoscalerSpecApplyConfiguration {
+    /// See [`HorizontalPodAutoscalerSpec::max_replicas`].
+    pub max_replicas: Option<i32>,
+
+    /// See [`HorizontalPodAutoscalerSpec::metrics`].
+    pub metrics: Option<Vec<crate::api::autoscaling::v2beta2::MetricSpecApplyConfiguration>>,
+
+    /// See [`HorizontalPodAutoscalerSpec::min_replicas`].
+    pub min_replicas: Option<i32>,
+
+    /// See [`HorizontalPodAutoscalerSpec::scale_target_ref`].
+    pub scale_target_ref: Option<crate::api::autoscaling::v2beta2::CrossVersionObjectReferenceApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for HorizontalPodAutoscalerSpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "HorizontalPodAutoscalerSpecApplyConfiguration",
+            self.max_replicas.as_ref().map_or(0, |_| 1) +
+            self.metrics.as_ref().map_or(0, |_| 1) +
+            self.min_replicas.as_ref().map_or(0, |_| 1) +
+            self.scale_target_ref.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.max_replicas {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maxReplicas", value)?;
+        }
+        if let Some(value) = &self.metrics {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metrics", value)?;
+        }
+        if let Some(value) = &self.min_replicas {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "minReplicas", value)?;
+        }
+        if let Some(value) = &self.scale_target_ref {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "scaleTargetRef", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for HorizontalPodAutoscalerSpec {
     fn schema_name() -> String {
-        "io.k8s.api.autoscaling.v2beta2.HorizontalPodAutoscalerSpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::autoscaling::v2beta2::HorizontalPodAutoscalerSpec".to_owned()
+        }
+        else {
+            "io.k8s.api.autoscaling.v2beta2.HorizontalPodAutoscalerSpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -134,8 +327,9 @@ impl crate::schemars::JsonSchema for HorizontalPodAutoscalerSpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "maxReplicas".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -146,8 +340,8 @@ impl crate::schemars::JsonSchema for HorizontalPodAutoscalerSpec {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "metrics".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -161,8 +355,8 @@ impl crate::schemars::JsonSchema for HorizontalPodAutoscalerSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "minReplicas".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -173,8 +367,8 @@ impl crate::schemars::JsonSchema for HorizontalPodAutoscalerSpec {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "scaleTargetRef".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::autoscaling::v2beta2::CrossVersionObjectReference>().into_object();
@@ -184,8 +378,9 @@ impl crate::schemars::JsonSchema for HorizontalPodAutoscalerSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "maxReplicas",
                     "scaleTargetRef",
@@ -196,3 +391,15 @@ impl crate::schemars::JsonSchema for HorizontalPodAutoscalerSpec {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for HorizontalPodAutoscalerSpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(HorizontalPodAutoscalerSpec {
+            max_replicas: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            metrics: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            min_replicas: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            scale_target_ref: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
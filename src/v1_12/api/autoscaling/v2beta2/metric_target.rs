@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.autoscaling.v2beta2.MetricTarget
 
 /// MetricTarget defines the target value, average value, or average utilization of a specific metric
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct MetricTarget {
     /// averageUtilization is the target value of the average of the resource metric across all relevant pods, represented as a percentage of the requested value of the resource for the pods. Currently only valid for Resource metric source type
     pub average_utilization: Option<i32>,
@@ -16,43 +16,61 @@ pub struct MetricTarget {
     pub value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for MetricTarget {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_average_utilization,
-            Key_average_value,
-            Key_type_,
-            Key_value,
-            Other,
+impl MetricTarget {
+    /// Constructs a `MetricTarget` from its required fields. Its optional fields are unset.
+    pub fn new(type_: impl Into<String>) -> Self {
+        MetricTarget {
+            average_utilization: None,
+            average_value: None,
+            type_: type_.into(),
+            value: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl MetricTarget {
+    /// Returns this value with [`MetricTarget::average_utilization`] set to the given value.
+    #[must_use]
+    pub fn with_average_utilization(mut self, value: impl Into<i32>) -> Self {
+        self.average_utilization = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`MetricTarget::average_value`] set to the given value.
+    #[must_use]
+    pub fn with_average_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.average_value = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`MetricTarget::type_`] set to the given value.
+    #[must_use]
+    pub fn with_type(mut self, value: impl Into<String>) -> Self {
+        self.type_ = value.into();
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "averageUtilization" => Field::Key_average_utilization,
-                            "averageValue" => Field::Key_average_value,
-                            "type" => Field::Key_type_,
-                            "value" => Field::Key_value,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`MetricTarget::value`] set to the given value.
+    #[must_use]
+    pub fn with_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl crate::MergeStrategyInfo for MetricTarget {}
+
+impl MetricTarget {
+    const FIELDS: &'static [&'static str] = &[
+        "averageUtilization",
+        "averageValue",
+        "type",
+        "value",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for MetricTarget {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -68,40 +86,111 @@ impl<'de> crate::serde::Deserialize<'de> for MetricTarget {
                 let mut value_type_: Option<String> = None;
                 let mut value_value: Option<crate::apimachinery::pkg::api::resource::Quantity> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("MetricTarget", MetricTarget::FIELDS))? {
                     match key {
-                        Field::Key_average_utilization => value_average_utilization = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_average_value => value_average_value = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_type_ => value_type_ = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_value => value_value = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_average_utilization = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_average_value = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_type_ = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_value = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(MetricTarget {
                     average_utilization: value_average_utilization,
                     average_value: value_average_value,
-                    type_: value_type_.ok_or_else(|| crate::serde::de::Error::missing_field("type"))?,
+                    type_: value_type_.ok_or_else(|| crate::serde2::missing_field("MetricTarget", "type", &["type"]))?,
                     value: value_value,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(MetricTarget {
+                    average_utilization: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    average_value: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    type_: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    value: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "MetricTarget",
-            &[
-                "averageUtilization",
-                "averageValue",
-                "type",
-                "value",
-            ],
+            MetricTarget::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, MetricTarget> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("MetricTarget")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_average_utilization = false;
+                let mut has_average_value = false;
+                let mut has_type_ = false;
+                let mut has_value = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("MetricTarget", MetricTarget::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.average_utilization))?; has_average_utilization = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.average_value))?; has_average_value = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.type_))?; has_type_ = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.value))?; has_value = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_average_utilization {
+                    self.0.average_utilization = None;
+                }
+                if !has_average_value {
+                    self.0.average_value = None;
+                }
+                if !has_type_ {
+                    return Err(crate::serde2::missing_field("MetricTarget", "type", &["type"]));
+                }
+                if !has_value {
+                    self.0.value = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.average_utilization))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.average_value))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.type_))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.value))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "MetricTarget",
+            MetricTarget::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for MetricTarget {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("MetricTarget", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "averageUtilization", &self.average_utilization)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "averageValue", &self.average_value)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "type", &self.type_)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "value", &self.value)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "MetricTarget",
             1 +
@@ -123,10 +212,114 @@ impl crate::serde::Serialize for MetricTarget {
     }
 }
 
+/// A builder of [`MetricTarget`]. Created with [`MetricTarget::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct MetricTargetBuilder {
+    average_utilization: Option<i32>,
+    average_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+    type_: Option<String>,
+    value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+}
+
+#[cfg(feature = "builder")]
+impl MetricTarget {
+    /// Starts building a `MetricTarget`. Its required fields must be set before [`MetricTargetBuilder::build`] is called.
+    pub fn builder() -> MetricTargetBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl MetricTargetBuilder {
+    /// Sets [`MetricTarget::average_utilization`].
+    pub fn average_utilization(mut self, value: impl Into<i32>) -> Self {
+        self.average_utilization = Some(value.into());
+        self
+    }
+
+    /// Sets [`MetricTarget::average_value`].
+    pub fn average_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.average_value = Some(value.into());
+        self
+    }
+
+    /// Sets [`MetricTarget::type_`].
+    pub fn type_(mut self, value: impl Into<String>) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Sets [`MetricTarget::value`].
+    pub fn value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Builds the `MetricTarget`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<MetricTarget, crate::MissingFieldError> {
+        Ok(MetricTarget {
+            average_utilization: self.average_utilization,
+            average_value: self.average_value,
+            type_: self.type_.ok_or_else(|| crate::MissingFieldError::new("MetricTarget", "type"))?,
+            value: self.value,
+        })
+    }
+}
+
+/// The apply configuration of [`MetricTarget`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MetricTargetApplyConfiguration {
+    /// See [`MetricTarget::average_utilization`].
+    pub average_utilization: Option<i32>,
+
+    /// See [`MetricTarget::average_value`].
+    pub average_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+
+    /// See [`MetricTarget::type_`].
+    pub type_: Option<String>,
+
+    /// See [`MetricTarget::value`].
+    pub value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for MetricTargetApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "MetricTargetApplyConfiguration",
+            self.average_utilization.as_ref().map_or(0, |_| 1) +
+            self.average_value.as_ref().map_or(0, |_| 1) +
+            self.type_.as_ref().map_or(0, |_| 1) +
+            self.value.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.average_utilization {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "averageUtilization", value)?;
+        }
+        if let Some(value) = &self.average_value {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "averageValue", value)?;
+        }
+        if let Some(value) = &self.type_ {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "type", value)?;
+        }
+        if let Some(value) = &self.value {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "value", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for MetricTarget {
     fn schema_name() -> String {
-        "io.k8s.api.autoscaling.v2beta2.MetricTarget".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::autoscaling::v2beta2::MetricTarget".to_owned()
+        }
+        else {
+            "io.k8s.api.autoscaling.v2beta2.MetricTarget".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -137,8 +330,9 @@ impl crate::schemars::JsonSchema for MetricTarget {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "averageUtilization".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -149,8 +343,8 @@ impl crate::schemars::JsonSchema for MetricTarget {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "averageValue".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::api::resource::Quantity>().into_object();
@@ -160,8 +354,8 @@ impl crate::schemars::JsonSchema for MetricTarget {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "type".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -171,8 +365,8 @@ impl crate::schemars::JsonSchema for MetricTarget {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "value".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::api::resource::Quantity>().into_object();
@@ -182,8 +376,9 @@ impl crate::schemars::JsonSchema for MetricTarget {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "type",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -193,3 +388,15 @@ impl crate::schemars::JsonSchema for MetricTarget {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for MetricTarget {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(MetricTarget {
+            average_utilization: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            average_value: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            type_: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            value: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.autoscaling.v2beta2.MetricValueStatus
 
 /// MetricValueStatus holds the current value for a metric
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct MetricValueStatus {
     /// currentAverageUtilization is the current value of the average of the resource metric across all relevant pods, represented as a percentage of the requested value of the resource for the pods.
     pub average_utilization: Option<i32>,
@@ -13,41 +13,41 @@ pub struct MetricValueStatus {
     pub value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for MetricValueStatus {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_average_utilization,
-            Key_average_value,
-            Key_value,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl MetricValueStatus {
+    /// Returns this value with [`MetricValueStatus::average_utilization`] set to the given value.
+    #[must_use]
+    pub fn with_average_utilization(mut self, value: impl Into<i32>) -> Self {
+        self.average_utilization = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`MetricValueStatus::average_value`] set to the given value.
+    #[must_use]
+    pub fn with_average_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.average_value = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`MetricValueStatus::value`] set to the given value.
+    #[must_use]
+    pub fn with_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "averageUtilization" => Field::Key_average_utilization,
-                            "averageValue" => Field::Key_average_value,
-                            "value" => Field::Key_value,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for MetricValueStatus {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl MetricValueStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "averageUtilization",
+        "averageValue",
+        "value",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for MetricValueStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,12 +62,12 @@ impl<'de> crate::serde::Deserialize<'de> for MetricValueStatus {
                 let mut value_average_value: Option<crate::apimachinery::pkg::api::resource::Quantity> = None;
                 let mut value_value: Option<crate::apimachinery::pkg::api::resource::Quantity> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("MetricValueStatus", MetricValueStatus::FIELDS))? {
                     match key {
-                        Field::Key_average_utilization => value_average_utilization = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_average_value => value_average_value = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_value => value_value = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_average_utilization = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_average_value = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_value = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -77,22 +77,86 @@ impl<'de> crate::serde::Deserialize<'de> for MetricValueStatus {
                     value: value_value,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(MetricValueStatus {
+                    average_utilization: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    average_value: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    value: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "MetricValueStatus",
-            &[
-                "averageUtilization",
-                "averageValue",
-                "value",
-            ],
+            MetricValueStatus::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, MetricValueStatus> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("MetricValueStatus")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_average_utilization = false;
+                let mut has_average_value = false;
+                let mut has_value = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("MetricValueStatus", MetricValueStatus::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.average_utilization))?; has_average_utilization = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.average_value))?; has_average_value = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.value))?; has_value = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_average_utilization {
+                    self.0.average_utilization = None;
+                }
+                if !has_average_value {
+                    self.0.average_value = None;
+                }
+                if !has_value {
+                    self.0.value = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.average_utilization))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.average_value))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.value))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "MetricValueStatus",
+            MetricValueStatus::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for MetricValueStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("MetricValueStatus", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "averageUtilization", &self.average_utilization)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "averageValue", &self.average_value)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "value", &self.value)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "MetricValueStatus",
             self.average_utilization.as_ref().map_or(0, |_| 1) +
@@ -112,10 +176,99 @@ impl crate::serde::Serialize for MetricValueStatus {
     }
 }
 
+/// A builder of [`MetricValueStatus`]. Created with [`MetricValueStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct MetricValueStatusBuilder {
+    average_utilization: Option<i32>,
+    average_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+    value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+}
+
+#[cfg(feature = "builder")]
+impl MetricValueStatus {
+    /// Starts building a `MetricValueStatus`. Its required fields must be set before [`MetricValueStatusBuilder::build`] is called.
+    pub fn builder() -> MetricValueStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl MetricValueStatusBuilder {
+    /// Sets [`MetricValueStatus::average_utilization`].
+    pub fn average_utilization(mut self, value: impl Into<i32>) -> Self {
+        self.average_utilization = Some(value.into());
+        self
+    }
+
+    /// Sets [`MetricValueStatus::average_value`].
+    pub fn average_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.average_value = Some(value.into());
+        self
+    }
+
+    /// Sets [`MetricValueStatus::value`].
+    pub fn value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Builds the `MetricValueStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<MetricValueStatus, crate::MissingFieldError> {
+        Ok(MetricValueStatus {
+            average_utilization: self.average_utilization,
+            average_value: self.average_value,
+            value: self.value,
+        })
+    }
+}
+
+/// The apply configuration of [`MetricValueStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MetricValueStatusApplyConfiguration {
+    /// See [`MetricValueStatus::average_utilization`].
+    pub average_utilization: Option<i32>,
+
+    /// See [`MetricValueStatus::average_value`].
+    pub average_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+
+    /// See [`MetricValueStatus::value`].
+    pub value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for MetricValueStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "MetricValueStatusApplyConfiguration",
+            self.average_utilization.as_ref().map_or(0, |_| 1) +
+            self.average_value.as_ref().map_or(0, |_| 1) +
+            self.value.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.average_utilization {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "averageUtilization", value)?;
+        }
+        if let Some(value) = &self.average_value {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "averageValue", value)?;
+        }
+        if let Some(value) = &self.value {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "value", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for MetricValueStatus {
     fn schema_name() -> String {
-        "io.k8s.api.autoscaling.v2beta2.MetricValueStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::autoscaling::v2beta2::MetricValueStatus".to_owned()
+        }
+        else {
+            "io.k8s.api.autoscaling.v2beta2.MetricValueStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -126,8 +279,9 @@ impl crate::schemars::JsonSchema for MetricValueStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "averageUtilization".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -138,8 +292,8 @@ impl crate::schemars::JsonSchema for MetricValueStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "averageValue".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::api::resource::Quantity>().into_object();
@@ -149,8 +303,8 @@ impl crate::schemars::JsonSchema for MetricValueStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "value".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::api::resource::Quantity>().into_object();
@@ -160,11 +314,23 @@ impl crate::schemars::JsonSchema for MetricValueStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for MetricValueStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(MetricValueStatus {
+            average_utilization: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            average_value: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            value: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,56 +1,130 @@
 
 mod cross_version_object_reference;
 pub use self::cross_version_object_reference::CrossVersionObjectReference;
+#[cfg(feature = "builder")]
+pub use self::cross_version_object_reference::CrossVersionObjectReferenceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::cross_version_object_reference::CrossVersionObjectReferenceApplyConfiguration;
 
 mod external_metric_source;
 pub use self::external_metric_source::ExternalMetricSource;
+#[cfg(feature = "builder")]
+pub use self::external_metric_source::ExternalMetricSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::external_metric_source::ExternalMetricSourceApplyConfiguration;
 
 mod external_metric_status;
 pub use self::external_metric_status::ExternalMetricStatus;
+#[cfg(feature = "builder")]
+pub use self::external_metric_status::ExternalMetricStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::external_metric_status::ExternalMetricStatusApplyConfiguration;
 
 mod horizontal_pod_autoscaler;
 pub use self::horizontal_pod_autoscaler::HorizontalPodAutoscaler;
+#[cfg(feature = "builder")]
+pub use self::horizontal_pod_autoscaler::HorizontalPodAutoscalerBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::horizontal_pod_autoscaler::HorizontalPodAutoscalerApplyConfiguration;
 #[cfg(feature = "api")] pub use self::horizontal_pod_autoscaler::{ReadNamespacedHorizontalPodAutoscalerOptional, ReadNamespacedHorizontalPodAutoscalerResponse};
 #[cfg(feature = "api")] pub use self::horizontal_pod_autoscaler::{ReadNamespacedHorizontalPodAutoscalerStatusOptional, ReadNamespacedHorizontalPodAutoscalerStatusResponse};
 
 mod horizontal_pod_autoscaler_condition;
 pub use self::horizontal_pod_autoscaler_condition::HorizontalPodAutoscalerCondition;
+#[cfg(feature = "builder")]
+pub use self::horizontal_pod_autoscaler_condition::HorizontalPodAutoscalerConditionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::horizontal_pod_autoscaler_condition::HorizontalPodAutoscalerConditionApplyConfiguration;
 
 mod horizontal_pod_autoscaler_spec;
 pub use self::horizontal_pod_autoscaler_spec::HorizontalPodAutoscalerSpec;
+#[cfg(feature = "builder")]
+pub use self::horizontal_pod_autoscaler_spec::HorizontalPodAutoscalerSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::horizontal_pod_autoscaler_spec::HorizontalPodAutoscalerSpecApplyConfiguration;
 
 mod horizontal_pod_autoscaler_status;
 pub use self::horizontal_pod_autoscaler_status::HorizontalPodAutoscalerStatus;
+#[cfg(feature = "builder")]
+pub use self::horizontal_pod_autoscaler_status::HorizontalPodAutoscalerStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::horizontal_pod_autoscaler_status::HorizontalPodAutoscalerStatusApplyConfiguration;
 
 mod metric_identifier;
 pub use self::metric_identifier::MetricIdentifier;
+#[cfg(feature = "builder")]
+pub use self::metric_identifier::MetricIdentifierBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::metric_identifier::MetricIdentifierApplyConfiguration;
 
 mod metric_spec;
 pub use self::metric_spec::MetricSpec;
+pub use self::metric_spec::MetricSource;
+#[cfg(feature = "builder")]
+pub use self::metric_spec::MetricSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::metric_spec::MetricSpecApplyConfiguration;
 
 mod metric_status;
 pub use self::metric_status::MetricStatus;
+pub use self::metric_status::MetricStatusSource;
+#[cfg(feature = "builder")]
+pub use self::metric_status::MetricStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::metric_status::MetricStatusApplyConfiguration;
 
 mod metric_target;
 pub use self::metric_target::MetricTarget;
+#[cfg(feature = "builder")]
+pub use self::metric_target::MetricTargetBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::metric_target::MetricTargetApplyConfiguration;
 
 mod metric_value_status;
 pub use self::metric_value_status::MetricValueStatus;
+#[cfg(feature = "builder")]
+pub use self::metric_value_status::MetricValueStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::metric_value_status::MetricValueStatusApplyConfiguration;
 
 mod object_metric_source;
 pub use self::object_metric_source::ObjectMetricSource;
+#[cfg(feature = "builder")]
+pub use self::object_metric_source::ObjectMetricSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::object_metric_source::ObjectMetricSourceApplyConfiguration;
 
 mod object_metric_status;
 pub use self::object_metric_status::ObjectMetricStatus;
+#[cfg(feature = "builder")]
+pub use self::object_metric_status::ObjectMetricStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::object_metric_status::ObjectMetricStatusApplyConfiguration;
 
 mod pods_metric_source;
 pub use self::pods_metric_source::PodsMetricSource;
+#[cfg(feature = "builder")]
+pub use self::pods_metric_source::PodsMetricSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::pods_metric_source::PodsMetricSourceApplyConfiguration;
 
 mod pods_metric_status;
 pub use self::pods_metric_status::PodsMetricStatus;
+#[cfg(feature = "builder")]
+pub use self::pods_metric_status::PodsMetricStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::pods_metric_status::PodsMetricStatusApplyConfiguration;
 
 mod resource_metric_source;
 pub use self::resource_metric_source::ResourceMetricSource;
+#[cfg(feature = "builder")]
+pub use self::resource_metric_source::ResourceMetricSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::resource_metric_source::ResourceMetricSourceApplyConfiguration;
 
 mod resource_metric_status;
 pub use self::resource_metric_status::ResourceMetricStatus;
+#[cfg(feature = "builder")]
+pub use self::resource_metric_status::ResourceMetricStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::resource_metric_status::ResourceMetricStatusApplyConfiguration;
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.autoscaling.v2beta2.ObjectMetricStatus
 
 /// ObjectMetricStatus indicates the current value of a metric describing a kubernetes object (for example, hits-per-second on an Ingress object).
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ObjectMetricStatus {
     /// current contains the current value for the given metric
     pub current: crate::api::autoscaling::v2beta2::MetricValueStatus,
@@ -12,41 +12,52 @@ pub struct ObjectMetricStatus {
     pub metric: crate::api::autoscaling::v2beta2::MetricIdentifier,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ObjectMetricStatus {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_current,
-            Key_described_object,
-            Key_metric,
-            Other,
+impl ObjectMetricStatus {
+    /// Constructs a `ObjectMetricStatus` from its required fields. Its optional fields are unset.
+    pub fn new(current: impl Into<crate::api::autoscaling::v2beta2::MetricValueStatus>, described_object: impl Into<crate::api::autoscaling::v2beta2::CrossVersionObjectReference>, metric: impl Into<crate::api::autoscaling::v2beta2::MetricIdentifier>) -> Self {
+        ObjectMetricStatus {
+            current: current.into(),
+            described_object: described_object.into(),
+            metric: metric.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl ObjectMetricStatus {
+    /// Returns this value with [`ObjectMetricStatus::current`] set to the given value.
+    #[must_use]
+    pub fn with_current(mut self, value: impl Into<crate::api::autoscaling::v2beta2::MetricValueStatus>) -> Self {
+        self.current = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ObjectMetricStatus::described_object`] set to the given value.
+    #[must_use]
+    pub fn with_described_object(mut self, value: impl Into<crate::api::autoscaling::v2beta2::CrossVersionObjectReference>) -> Self {
+        self.described_object = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ObjectMetricStatus::metric`] set to the given value.
+    #[must_use]
+    pub fn with_metric(mut self, value: impl Into<crate::api::autoscaling::v2beta2::MetricIdentifier>) -> Self {
+        self.metric = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "current" => Field::Key_current,
-                            "describedObject" => Field::Key_described_object,
-                            "metric" => Field::Key_metric,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for ObjectMetricStatus {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl ObjectMetricStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "current",
+        "describedObject",
+        "metric",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ObjectMetricStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -61,37 +72,101 @@ impl<'de> crate::serde::Deserialize<'de> for ObjectMetricStatus {
                 let mut value_described_object: Option<crate::api::autoscaling::v2beta2::CrossVersionObjectReference> = None;
                 let mut value_metric: Option<crate::api::autoscaling::v2beta2::MetricIdentifier> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ObjectMetricStatus", ObjectMetricStatus::FIELDS))? {
                     match key {
-                        Field::Key_current => value_current = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_described_object => value_described_object = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_metric => value_metric = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_current = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_described_object = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_metric = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ObjectMetricStatus {
-                    current: value_current.ok_or_else(|| crate::serde::de::Error::missing_field("current"))?,
-                    described_object: value_described_object.ok_or_else(|| crate::serde::de::Error::missing_field("describedObject"))?,
-                    metric: value_metric.ok_or_else(|| crate::serde::de::Error::missing_field("metric"))?,
+                    current: value_current.ok_or_else(|| crate::serde2::missing_field("ObjectMetricStatus", "current", &["current", "describedObject", "metric"]))?,
+                    described_object: value_described_object.ok_or_else(|| crate::serde2::missing_field("ObjectMetricStatus", "describedObject", &["current", "describedObject", "metric"]))?,
+                    metric: value_metric.ok_or_else(|| crate::serde2::missing_field("ObjectMetricStatus", "metric", &["current", "describedObject", "metric"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ObjectMetricStatus {
+                    current: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    described_object: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    metric: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "ObjectMetricStatus",
-            &[
-                "current",
-                "describedObject",
-                "metric",
-            ],
+            ObjectMetricStatus::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ObjectMetricStatus> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ObjectMetricStatus")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_current = false;
+                let mut has_described_object = false;
+                let mut has_metric = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ObjectMetricStatus", ObjectMetricStatus::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.current))?; has_current = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.described_object))?; has_described_object = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metric))?; has_metric = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_current {
+                    return Err(crate::serde2::missing_field("ObjectMetricStatus", "current", &["current", "describedObject", "metric"]));
+                }
+                if !has_described_object {
+                    return Err(crate::serde2::missing_field("ObjectMetricStatus", "describedObject", &["current", "describedObject", "metric"]));
+                }
+                if !has_metric {
+                    return Err(crate::serde2::missing_field("ObjectMetricStatus", "metric", &["current", "describedObject", "metric"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.current))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.described_object))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metric))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ObjectMetricStatus",
+            ObjectMetricStatus::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ObjectMetricStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ObjectMetricStatus", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "current", &self.current)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "describedObject", &self.described_object)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metric", &self.metric)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ObjectMetricStatus",
             3,
@@ -103,10 +178,99 @@ impl crate::serde::Serialize for ObjectMetricStatus {
     }
 }
 
+/// A builder of [`ObjectMetricStatus`]. Created with [`ObjectMetricStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ObjectMetricStatusBuilder {
+    current: Option<crate::api::autoscaling::v2beta2::MetricValueStatus>,
+    described_object: Option<crate::api::autoscaling::v2beta2::CrossVersionObjectReference>,
+    metric: Option<crate::api::autoscaling::v2beta2::MetricIdentifier>,
+}
+
+#[cfg(feature = "builder")]
+impl ObjectMetricStatus {
+    /// Starts building a `ObjectMetricStatus`. Its required fields must be set before [`ObjectMetricStatusBuilder::build`] is called.
+    pub fn builder() -> ObjectMetricStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ObjectMetricStatusBuilder {
+    /// Sets [`ObjectMetricStatus::current`].
+    pub fn current(mut self, value: impl Into<crate::api::autoscaling::v2beta2::MetricValueStatus>) -> Self {
+        self.current = Some(value.into());
+        self
+    }
+
+    /// Sets [`ObjectMetricStatus::described_object`].
+    pub fn described_object(mut self, value: impl Into<crate::api::autoscaling::v2beta2::CrossVersionObjectReference>) -> Self {
+        self.described_object = Some(value.into());
+        self
+    }
+
+    /// Sets [`ObjectMetricStatus::metric`].
+    pub fn metric(mut self, value: impl Into<crate::api::autoscaling::v2beta2::MetricIdentifier>) -> Self {
+        self.metric = Some(value.into());
+        self
+    }
+
+    /// Builds the `ObjectMetricStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ObjectMetricStatus, crate::MissingFieldError> {
+        Ok(ObjectMetricStatus {
+            current: self.current.ok_or_else(|| crate::MissingFieldError::new("ObjectMetricStatus", "current"))?,
+            described_object: self.described_object.ok_or_else(|| crate::MissingFieldError::new("ObjectMetricStatus", "describedObject"))?,
+            metric: self.metric.ok_or_else(|| crate::MissingFieldError::new("ObjectMetricStatus", "metric"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`ObjectMetricStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ObjectMetricStatusApplyConfiguration {
+    /// See [`ObjectMetricStatus::current`].
+    pub current: Option<crate::api::autoscaling::v2beta2::MetricValueStatusApplyConfiguration>,
+
+    /// See [`ObjectMetricStatus::described_object`].
+    pub described_object: Option<crate::api::autoscaling::v2beta2::CrossVersionObjectReferenceApplyConfiguration>,
+
+    /// See [`ObjectMetricStatus::metric`].
+    pub metric: Option<crate::api::autoscaling::v2beta2::MetricIdentifierApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ObjectMetricStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ObjectMetricStatusApplyConfiguration",
+            self.current.as_ref().map_or(0, |_| 1) +
+            self.described_object.as_ref().map_or(0, |_| 1) +
+            self.metric.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.current {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "current", value)?;
+        }
+        if let Some(value) = &self.described_object {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "describedObject", value)?;
+        }
+        if let Some(value) = &self.metric {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metric", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ObjectMetricStatus {
     fn schema_name() -> String {
-        "io.k8s.api.autoscaling.v2beta2.ObjectMetricStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::autoscaling::v2beta2::ObjectMetricStatus".to_owned()
+        }
+        else {
+            "io.k8s.api.autoscaling.v2beta2.ObjectMetricStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -117,8 +281,9 @@ impl crate::schemars::JsonSchema for ObjectMetricStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "current".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::autoscaling::v2beta2::MetricValueStatus>().into_object();
@@ -128,12 +293,12 @@ impl crate::schemars::JsonSchema for ObjectMetricStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "describedObject".to_owned(),
                         __gen.subschema_for::<crate::api::autoscaling::v2beta2::CrossVersionObjectReference>(),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "metric".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::autoscaling::v2beta2::MetricIdentifier>().into_object();
@@ -143,8 +308,9 @@ impl crate::schemars::JsonSchema for ObjectMetricStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "current",
                     "describedObject",
@@ -156,3 +322,14 @@ impl crate::schemars::JsonSchema for ObjectMetricStatus {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ObjectMetricStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ObjectMetricStatus {
+            current: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            described_object: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            metric: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
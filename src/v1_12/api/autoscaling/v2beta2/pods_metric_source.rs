@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.autoscaling.v2beta2.PodsMetricSource
 
 /// PodsMetricSource indicates how to scale on a metric describing each pod in the current scale target (for example, transactions-processed-per-second). The values will be averaged together before being compared to the target value.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct PodsMetricSource {
     /// metric identifies the target metric by name and selector
     pub metric: crate::api::autoscaling::v2beta2::MetricIdentifier,
@@ -10,39 +10,43 @@ pub struct PodsMetricSource {
     pub target: crate::api::autoscaling::v2beta2::MetricTarget,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for PodsMetricSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_metric,
-            Key_target,
-            Other,
+impl PodsMetricSource {
+    /// Constructs a `PodsMetricSource` from its required fields. Its optional fields are unset.
+    pub fn new(metric: impl Into<crate::api::autoscaling::v2beta2::MetricIdentifier>, target: impl Into<crate::api::autoscaling::v2beta2::MetricTarget>) -> Self {
+        PodsMetricSource {
+            metric: metric.into(),
+            target: target.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl PodsMetricSource {
+    /// Returns this value with [`PodsMetricSource::metric`] set to the given value.
+    #[must_use]
+    pub fn with_metric(mut self, value: impl Into<crate::api::autoscaling::v2beta2::MetricIdentifier>) -> Self {
+        self.metric = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`PodsMetricSource::target`] set to the given value.
+    #[must_use]
+    pub fn with_target(mut self, value: impl Into<crate::api::autoscaling::v2beta2::MetricTarget>) -> Self {
+        self.target = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "metric" => Field::Key_metric,
-                            "target" => Field::Key_target,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for PodsMetricSource {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl PodsMetricSource {
+    const FIELDS: &'static [&'static str] = &[
+        "metric",
+        "target",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for PodsMetricSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +60,91 @@ impl<'de> crate::serde::Deserialize<'de> for PodsMetricSource {
                 let mut value_metric: Option<crate::api::autoscaling::v2beta2::MetricIdentifier> = None;
                 let mut value_target: Option<crate::api::autoscaling::v2beta2::MetricTarget> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodsMetricSource", PodsMetricSource::FIELDS))? {
                     match key {
-                        Field::Key_metric => value_metric = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_target => value_target = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_metric = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_target = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(PodsMetricSource {
-                    metric: value_metric.ok_or_else(|| crate::serde::de::Error::missing_field("metric"))?,
-                    target: value_target.ok_or_else(|| crate::serde::de::Error::missing_field("target"))?,
+                    metric: value_metric.ok_or_else(|| crate::serde2::missing_field("PodsMetricSource", "metric", &["metric", "target"]))?,
+                    target: value_target.ok_or_else(|| crate::serde2::missing_field("PodsMetricSource", "target", &["metric", "target"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(PodsMetricSource {
+                    metric: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    target: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "PodsMetricSource",
-            &[
-                "metric",
-                "target",
-            ],
+            PodsMetricSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, PodsMetricSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("PodsMetricSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_metric = false;
+                let mut has_target = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodsMetricSource", PodsMetricSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metric))?; has_metric = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.target))?; has_target = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_metric {
+                    return Err(crate::serde2::missing_field("PodsMetricSource", "metric", &["metric", "target"]));
+                }
+                if !has_target {
+                    return Err(crate::serde2::missing_field("PodsMetricSource", "target", &["metric", "target"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metric))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.target))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "PodsMetricSource",
+            PodsMetricSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for PodsMetricSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("PodsMetricSource", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metric", &self.metric)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "target", &self.target)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "PodsMetricSource",
             2,
@@ -94,10 +155,84 @@ impl crate::serde::Serialize for PodsMetricSource {
     }
 }
 
+/// A builder of [`PodsMetricSource`]. Created with [`PodsMetricSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct PodsMetricSourceBuilder {
+    metric: Option<crate::api::autoscaling::v2beta2::MetricIdentifier>,
+    target: Option<crate::api::autoscaling::v2beta2::MetricTarget>,
+}
+
+#[cfg(feature = "builder")]
+impl PodsMetricSource {
+    /// Starts building a `PodsMetricSource`. Its required fields must be set before [`PodsMetricSourceBuilder::build`] is called.
+    pub fn builder() -> PodsMetricSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl PodsMetricSourceBuilder {
+    /// Sets [`PodsMetricSource::metric`].
+    pub fn metric(mut self, value: impl Into<crate::api::autoscaling::v2beta2::MetricIdentifier>) -> Self {
+        self.metric = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodsMetricSource::target`].
+    pub fn target(mut self, value: impl Into<crate::api::autoscaling::v2beta2::MetricTarget>) -> Self {
+        self.target = Some(value.into());
+        self
+    }
+
+    /// Builds the `PodsMetricSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<PodsMetricSource, crate::MissingFieldError> {
+        Ok(PodsMetricSource {
+            metric: self.metric.ok_or_else(|| crate::MissingFieldError::new("PodsMetricSource", "metric"))?,
+            target: self.target.ok_or_else(|| crate::MissingFieldError::new("PodsMetricSource", "target"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`PodsMetricSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodsMetricSourceApplyConfiguration {
+    /// See [`PodsMetricSource::metric`].
+    pub metric: Option<crate::api::autoscaling::v2beta2::MetricIdentifierApplyConfiguration>,
+
+    /// See [`PodsMetricSource::target`].
+    pub target: Option<crate::api::autoscaling::v2beta2::MetricTargetApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for PodsMetricSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "PodsMetricSourceApplyConfiguration",
+            self.metric.as_ref().map_or(0, |_| 1) +
+            self.target.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.metric {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metric", value)?;
+        }
+        if let Some(value) = &self.target {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "target", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodsMetricSource {
     fn schema_name() -> String {
-        "io.k8s.api.autoscaling.v2beta2.PodsMetricSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::autoscaling::v2beta2::PodsMetricSource".to_owned()
+        }
+        else {
+            "io.k8s.api.autoscaling.v2beta2.PodsMetricSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -108,8 +243,9 @@ impl crate::schemars::JsonSchema for PodsMetricSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "metric".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::autoscaling::v2beta2::MetricIdentifier>().into_object();
@@ -119,8 +255,8 @@ impl crate::schemars::JsonSchema for PodsMetricSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "target".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::autoscaling::v2beta2::MetricTarget>().into_object();
@@ -130,8 +266,9 @@ impl crate::schemars::JsonSchema for PodsMetricSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "metric",
                     "target",
@@ -142,3 +279,13 @@ impl crate::schemars::JsonSchema for PodsMetricSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for PodsMetricSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(PodsMetricSource {
+            metric: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            target: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -1,7 +1,15 @@
 
 mod lease;
 pub use self::lease::Lease;
+#[cfg(feature = "builder")]
+pub use self::lease::LeaseBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::lease::LeaseApplyConfiguration;
 #[cfg(feature = "api")] pub use self::lease::{ReadNamespacedLeaseOptional, ReadNamespacedLeaseResponse};
 
 mod lease_spec;
 pub use self::lease_spec::LeaseSpec;
+#[cfg(feature = "builder")]
+pub use self::lease_spec::LeaseSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::lease_spec::LeaseSpecApplyConfiguration;
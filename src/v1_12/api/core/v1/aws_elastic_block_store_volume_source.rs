@@ -3,7 +3,7 @@
 /// Represents a Persistent Disk resource in AWS.
 ///
 /// An AWS EBS disk must exist before mounting to a container. The disk must also be in the same AWS zone as the kubelet. An AWS EBS disk can only be mounted as read/write once. AWS EBS volumes support ownership management and SELinux relabeling.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct AWSElasticBlockStoreVolumeSource {
     /// Filesystem type of the volume that you want to mount. Tip: Ensure that the filesystem type is supported by the host operating system. Examples: "ext4", "xfs", "ntfs". Implicitly inferred to be "ext4" if unspecified. More info: https://kubernetes.io/docs/concepts/storage/volumes#awselasticblockstore
     pub fs_type: Option<String>,
@@ -18,43 +18,61 @@ pub struct AWSElasticBlockStoreVolumeSource {
     pub volume_id: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for AWSElasticBlockStoreVolumeSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_fs_type,
-            Key_partition,
-            Key_read_only,
-            Key_volume_id,
-            Other,
+impl AWSElasticBlockStoreVolumeSource {
+    /// Constructs a `AWSElasticBlockStoreVolumeSource` from its required fields. Its optional fields are unset.
+    pub fn new(volume_id: impl Into<String>) -> Self {
+        AWSElasticBlockStoreVolumeSource {
+            fs_type: None,
+            partition: None,
+            read_only: None,
+            volume_id: volume_id.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl AWSElasticBlockStoreVolumeSource {
+    /// Returns this value with [`AWSElasticBlockStoreVolumeSource::fs_type`] set to the given value.
+    #[must_use]
+    pub fn with_fs_type(mut self, value: impl Into<String>) -> Self {
+        self.fs_type = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`AWSElasticBlockStoreVolumeSource::partition`] set to the given value.
+    #[must_use]
+    pub fn with_partition(mut self, value: impl Into<i32>) -> Self {
+        self.partition = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`AWSElasticBlockStoreVolumeSource::read_only`] set to the given value.
+    #[must_use]
+    pub fn with_read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "fsType" => Field::Key_fs_type,
-                            "partition" => Field::Key_partition,
-                            "readOnly" => Field::Key_read_only,
-                            "volumeID" => Field::Key_volume_id,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`AWSElasticBlockStoreVolumeSource::volume_id`] set to the given value.
+    #[must_use]
+    pub fn with_volume_id(mut self, value: impl Into<String>) -> Self {
+        self.volume_id = value.into();
+        self
+    }
+}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl crate::MergeStrategyInfo for AWSElasticBlockStoreVolumeSource {}
+
+impl AWSElasticBlockStoreVolumeSource {
+    const FIELDS: &'static [&'static str] = &[
+        "fsType",
+        "partition",
+        "readOnly",
+        "volumeID",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for AWSElasticBlockStoreVolumeSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -70,13 +88,13 @@ impl<'de> crate::serde::Deserialize<'de> for AWSElasticBlockStoreVolumeSource {
                 let mut value_read_only: Option<bool> = None;
                 let mut value_volume_id: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("AWSElasticBlockStoreVolumeSource", AWSElasticBlockStoreVolumeSource::FIELDS))? {
                     match key {
-                        Field::Key_fs_type => value_fs_type = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_partition => value_partition = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_read_only => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_volume_id => value_volume_id = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_fs_type = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_partition = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_volume_id = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -84,26 +102,97 @@ impl<'de> crate::serde::Deserialize<'de> for AWSElasticBlockStoreVolumeSource {
                     fs_type: value_fs_type,
                     partition: value_partition,
                     read_only: value_read_only,
-                    volume_id: value_volume_id.ok_or_else(|| crate::serde::de::Error::missing_field("volumeID"))?,
+                    volume_id: value_volume_id.ok_or_else(|| crate::serde2::missing_field("AWSElasticBlockStoreVolumeSource", "volumeID", &["volumeID"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(AWSElasticBlockStoreVolumeSource {
+                    fs_type: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    partition: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    read_only: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    volume_id: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "AWSElasticBlockStoreVolumeSource",
-            &[
-                "fsType",
-                "partition",
-                "readOnly",
-                "volumeID",
-            ],
+            AWSElasticBlockStoreVolumeSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, AWSElasticBlockStoreVolumeSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("AWSElasticBlockStoreVolumeSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_fs_type = false;
+                let mut has_partition = false;
+                let mut has_read_only = false;
+                let mut has_volume_id = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("AWSElasticBlockStoreVolumeSource", AWSElasticBlockStoreVolumeSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.fs_type))?; has_fs_type = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.partition))?; has_partition = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.read_only))?; has_read_only = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.volume_id))?; has_volume_id = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_fs_type {
+                    self.0.fs_type = None;
+                }
+                if !has_partition {
+                    self.0.partition = None;
+                }
+                if !has_read_only {
+                    self.0.read_only = None;
+                }
+                if !has_volume_id {
+                    return Err(crate::serde2::missing_field("AWSElasticBlockStoreVolumeSource", "volumeID", &["volumeID"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.fs_type))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.partition))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.read_only))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.volume_id))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "AWSElasticBlockStoreVolumeSource",
+            AWSElasticBlockStoreVolumeSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for AWSElasticBlockStoreVolumeSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("AWSElasticBlockStoreVolumeSource", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsType", &self.fs_type)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "partition", &self.partition)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", &self.read_only)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeID", &self.volume_id)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "AWSElasticBlockStoreVolumeSource",
             1 +
@@ -125,10 +214,114 @@ impl crate::serde::Serialize for AWSElasticBlockStoreVolumeSource {
     }
 }
 
+/// A builder of [`AWSElasticBlockStoreVolumeSource`]. Created with [`AWSElasticBlockStoreVolumeSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct AWSElasticBlockStoreVolumeSourceBuilder {
+    fs_type: Option<String>,
+    partition: Option<i32>,
+    read_only: Option<bool>,
+    volume_id: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl AWSElasticBlockStoreVolumeSource {
+    /// Starts building a `AWSElasticBlockStoreVolumeSource`. Its required fields must be set before [`AWSElasticBlockStoreVolumeSourceBuilder::build`] is called.
+    pub fn builder() -> AWSElasticBlockStoreVolumeSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl AWSElasticBlockStoreVolumeSourceBuilder {
+    /// Sets [`AWSElasticBlockStoreVolumeSource::fs_type`].
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`AWSElasticBlockStoreVolumeSource::partition`].
+    pub fn partition(mut self, value: impl Into<i32>) -> Self {
+        self.partition = Some(value.into());
+        self
+    }
+
+    /// Sets [`AWSElasticBlockStoreVolumeSource::read_only`].
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`AWSElasticBlockStoreVolumeSource::volume_id`].
+    pub fn volume_id(mut self, value: impl Into<String>) -> Self {
+        self.volume_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AWSElasticBlockStoreVolumeSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<AWSElasticBlockStoreVolumeSource, crate::MissingFieldError> {
+        Ok(AWSElasticBlockStoreVolumeSource {
+            fs_type: self.fs_type,
+            partition: self.partition,
+            read_only: self.read_only,
+            volume_id: self.volume_id.ok_or_else(|| crate::MissingFieldError::new("AWSElasticBlockStoreVolumeSource", "volumeID"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`AWSElasticBlockStoreVolumeSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AWSElasticBlockStoreVolumeSourceApplyConfiguration {
+    /// See [`AWSElasticBlockStoreVolumeSource::fs_type`].
+    pub fs_type: Option<String>,
+
+    /// See [`AWSElasticBlockStoreVolumeSource::partition`].
+    pub partition: Option<i32>,
+
+    /// See [`AWSElasticBlockStoreVolumeSource::read_only`].
+    pub read_only: Option<bool>,
+
+    /// See [`AWSElasticBlockStoreVolumeSource::volume_id`].
+    pub volume_id: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for AWSElasticBlockStoreVolumeSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "AWSElasticBlockStoreVolumeSourceApplyConfiguration",
+            self.fs_type.as_ref().map_or(0, |_| 1) +
+            self.partition.as_ref().map_or(0, |_| 1) +
+            self.read_only.as_ref().map_or(0, |_| 1) +
+            self.volume_id.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.fs_type {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsType", value)?;
+        }
+        if let Some(value) = &self.partition {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "partition", value)?;
+        }
+        if let Some(value) = &self.read_only {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", value)?;
+        }
+        if let Some(value) = &self.volume_id {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeID", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for AWSElasticBlockStoreVolumeSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.AWSElasticBlockStoreVolumeSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::AWSElasticBlockStoreVolumeSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.AWSElasticBlockStoreVolumeSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -139,8 +332,9 @@ impl crate::schemars::JsonSchema for AWSElasticBlockStoreVolumeSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "fsType".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -150,8 +344,8 @@ impl crate::schemars::JsonSchema for AWSElasticBlockStoreVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "partition".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -162,8 +356,8 @@ impl crate::schemars::JsonSchema for AWSElasticBlockStoreVolumeSource {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "readOnly".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -173,8 +367,8 @@ impl crate::schemars::JsonSchema for AWSElasticBlockStoreVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "volumeID".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -184,8 +378,9 @@ impl crate::schemars::JsonSchema for AWSElasticBlockStoreVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "volumeID",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -195,3 +390,15 @@ impl crate::schemars::JsonSchema for AWSElasticBlockStoreVolumeSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for AWSElasticBlockStoreVolumeSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(AWSElasticBlockStoreVolumeSource {
+            fs_type: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            partition: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            read_only: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            volume_id: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
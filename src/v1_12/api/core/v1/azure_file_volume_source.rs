@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.AzureFileVolumeSource
 
 /// AzureFile represents an Azure File Service mount on the host and bind mount to the pod.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct AzureFileVolumeSource {
     /// Defaults to false (read/write). ReadOnly here will force the ReadOnly setting in VolumeMounts.
     pub read_only: Option<bool>,
@@ -13,41 +13,52 @@ pub struct AzureFileVolumeSource {
     pub share_name: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for AzureFileVolumeSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_read_only,
-            Key_secret_name,
-            Key_share_name,
-            Other,
+impl AzureFileVolumeSource {
+    /// Constructs a `AzureFileVolumeSource` from its required fields. Its optional fields are unset.
+    pub fn new(secret_name: impl Into<String>, share_name: impl Into<String>) -> Self {
+        AzureFileVolumeSource {
+            read_only: None,
+            secret_name: secret_name.into(),
+            share_name: share_name.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl AzureFileVolumeSource {
+    /// Returns this value with [`AzureFileVolumeSource::read_only`] set to the given value.
+    #[must_use]
+    pub fn with_read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`AzureFileVolumeSource::secret_name`] set to the given value.
+    #[must_use]
+    pub fn with_secret_name(mut self, value: impl Into<String>) -> Self {
+        self.secret_name = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`AzureFileVolumeSource::share_name`] set to the given value.
+    #[must_use]
+    pub fn with_share_name(mut self, value: impl Into<String>) -> Self {
+        self.share_name = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "readOnly" => Field::Key_read_only,
-                            "secretName" => Field::Key_secret_name,
-                            "shareName" => Field::Key_share_name,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for AzureFileVolumeSource {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl AzureFileVolumeSource {
+    const FIELDS: &'static [&'static str] = &[
+        "readOnly",
+        "secretName",
+        "shareName",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for AzureFileVolumeSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,37 +73,101 @@ impl<'de> crate::serde::Deserialize<'de> for AzureFileVolumeSource {
                 let mut value_secret_name: Option<String> = None;
                 let mut value_share_name: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("AzureFileVolumeSource", AzureFileVolumeSource::FIELDS))? {
                     match key {
-                        Field::Key_read_only => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_secret_name => value_secret_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_share_name => value_share_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_secret_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_share_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(AzureFileVolumeSource {
                     read_only: value_read_only,
-                    secret_name: value_secret_name.ok_or_else(|| crate::serde::de::Error::missing_field("secretName"))?,
-                    share_name: value_share_name.ok_or_else(|| crate::serde::de::Error::missing_field("shareName"))?,
+                    secret_name: value_secret_name.ok_or_else(|| crate::serde2::missing_field("AzureFileVolumeSource", "secretName", &["secretName", "shareName"]))?,
+                    share_name: value_share_name.ok_or_else(|| crate::serde2::missing_field("AzureFileVolumeSource", "shareName", &["secretName", "shareName"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(AzureFileVolumeSource {
+                    read_only: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    secret_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    share_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "AzureFileVolumeSource",
-            &[
-                "readOnly",
-                "secretName",
-                "shareName",
-            ],
+            AzureFileVolumeSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, AzureFileVolumeSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("AzureFileVolumeSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_read_only = false;
+                let mut has_secret_name = false;
+                let mut has_share_name = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("AzureFileVolumeSource", AzureFileVolumeSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.read_only))?; has_read_only = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.secret_name))?; has_secret_name = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.share_name))?; has_share_name = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_read_only {
+                    self.0.read_only = None;
+                }
+                if !has_secret_name {
+                    return Err(crate::serde2::missing_field("AzureFileVolumeSource", "secretName", &["secretName", "shareName"]));
+                }
+                if !has_share_name {
+                    return Err(crate::serde2::missing_field("AzureFileVolumeSource", "shareName", &["secretName", "shareName"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.read_only))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.secret_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.share_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "AzureFileVolumeSource",
+            AzureFileVolumeSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for AzureFileVolumeSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("AzureFileVolumeSource", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", &self.read_only)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretName", &self.secret_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "shareName", &self.share_name)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "AzureFileVolumeSource",
             2 +
@@ -107,10 +182,99 @@ impl crate::serde::Serialize for AzureFileVolumeSource {
     }
 }
 
+/// A builder of [`AzureFileVolumeSource`]. Created with [`AzureFileVolumeSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct AzureFileVolumeSourceBuilder {
+    read_only: Option<bool>,
+    secret_name: Option<String>,
+    share_name: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl AzureFileVolumeSource {
+    /// Starts building a `AzureFileVolumeSource`. Its required fields must be set before [`AzureFileVolumeSourceBuilder::build`] is called.
+    pub fn builder() -> AzureFileVolumeSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl AzureFileVolumeSourceBuilder {
+    /// Sets [`AzureFileVolumeSource::read_only`].
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`AzureFileVolumeSource::secret_name`].
+    pub fn secret_name(mut self, value: impl Into<String>) -> Self {
+        self.secret_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`AzureFileVolumeSource::share_name`].
+    pub fn share_name(mut self, value: impl Into<String>) -> Self {
+        self.share_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `AzureFileVolumeSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<AzureFileVolumeSource, crate::MissingFieldError> {
+        Ok(AzureFileVolumeSource {
+            read_only: self.read_only,
+            secret_name: self.secret_name.ok_or_else(|| crate::MissingFieldError::new("AzureFileVolumeSource", "secretName"))?,
+            share_name: self.share_name.ok_or_else(|| crate::MissingFieldError::new("AzureFileVolumeSource", "shareName"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`AzureFileVolumeSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AzureFileVolumeSourceApplyConfiguration {
+    /// See [`AzureFileVolumeSource::read_only`].
+    pub read_only: Option<bool>,
+
+    /// See [`AzureFileVolumeSource::secret_name`].
+    pub secret_name: Option<String>,
+
+    /// See [`AzureFileVolumeSource::share_name`].
+    pub share_name: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for AzureFileVolumeSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "AzureFileVolumeSourceApplyConfiguration",
+            self.read_only.as_ref().map_or(0, |_| 1) +
+            self.secret_name.as_ref().map_or(0, |_| 1) +
+            self.share_name.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.read_only {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", value)?;
+        }
+        if let Some(value) = &self.secret_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretName", value)?;
+        }
+        if let Some(value) = &self.share_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "shareName", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for AzureFileVolumeSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.AzureFileVolumeSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::AzureFileVolumeSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.AzureFileVolumeSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -121,8 +285,9 @@ impl crate::schemars::JsonSchema for AzureFileVolumeSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "readOnly".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -132,8 +297,8 @@ impl crate::schemars::JsonSchema for AzureFileVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "secretName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -143,8 +308,8 @@ impl crate::schemars::JsonSchema for AzureFileVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "shareName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -154,8 +319,9 @@ impl crate::schemars::JsonSchema for AzureFileVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "secretName",
                     "shareName",
@@ -166,3 +332,14 @@ impl crate::schemars::JsonSchema for AzureFileVolumeSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for AzureFileVolumeSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(AzureFileVolumeSource {
+            read_only: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            secret_name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            share_name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
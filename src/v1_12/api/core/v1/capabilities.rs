@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.Capabilities
 
 /// Adds and removes POSIX capabilities from running containers.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct Capabilities {
     /// Added capabilities
     pub add: Option<Vec<String>>,
@@ -10,39 +10,33 @@ pub struct Capabilities {
     pub drop: Option<Vec<String>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for Capabilities {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_add,
-            Key_drop,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl Capabilities {
+    /// Returns this value with [`Capabilities::add`] set to the given value.
+    #[must_use]
+    pub fn with_add(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.add = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`Capabilities::drop`] set to the given value.
+    #[must_use]
+    pub fn with_drop(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.drop = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "add" => Field::Key_add,
-                            "drop" => Field::Key_drop,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for Capabilities {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl Capabilities {
+    const FIELDS: &'static [&'static str] = &[
+        "add",
+        "drop",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for Capabilities {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,11 +50,11 @@ impl<'de> crate::serde::Deserialize<'de> for Capabilities {
                 let mut value_add: Option<Vec<String>> = None;
                 let mut value_drop: Option<Vec<String>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Capabilities", Capabilities::FIELDS))? {
                     match key {
-                        Field::Key_add => value_add = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_drop => value_drop = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_add = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_drop = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -69,21 +63,78 @@ impl<'de> crate::serde::Deserialize<'de> for Capabilities {
                     drop: value_drop,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(Capabilities {
+                    add: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    drop: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "Capabilities",
-            &[
-                "add",
-                "drop",
-            ],
+            Capabilities::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, Capabilities> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("Capabilities")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_add = false;
+                let mut has_drop = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Capabilities", Capabilities::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.add))?; has_add = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.drop))?; has_drop = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_add {
+                    self.0.add = None;
+                }
+                if !has_drop {
+                    self.0.drop = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.add))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.drop))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "Capabilities",
+            Capabilities::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for Capabilities {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("Capabilities", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "add", &self.add)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "drop", &self.drop)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "Capabilities",
             self.add.as_ref().map_or(0, |_| 1) +
@@ -99,10 +150,84 @@ impl crate::serde::Serialize for Capabilities {
     }
 }
 
+/// A builder of [`Capabilities`]. Created with [`Capabilities::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct CapabilitiesBuilder {
+    add: Option<Vec<String>>,
+    drop: Option<Vec<String>>,
+}
+
+#[cfg(feature = "builder")]
+impl Capabilities {
+    /// Starts building a `Capabilities`. Its required fields must be set before [`CapabilitiesBuilder::build`] is called.
+    pub fn builder() -> CapabilitiesBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl CapabilitiesBuilder {
+    /// Sets [`Capabilities::add`].
+    pub fn add(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.add = Some(value.into());
+        self
+    }
+
+    /// Sets [`Capabilities::drop`].
+    pub fn drop(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.drop = Some(value.into());
+        self
+    }
+
+    /// Builds the `Capabilities`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<Capabilities, crate::MissingFieldError> {
+        Ok(Capabilities {
+            add: self.add,
+            drop: self.drop,
+        })
+    }
+}
+
+/// The apply configuration of [`Capabilities`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CapabilitiesApplyConfiguration {
+    /// See [`Capabilities::add`].
+    pub add: Option<Vec<String>>,
+
+    /// See [`Capabilities::drop`].
+    pub drop: Option<Vec<String>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for CapabilitiesApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "CapabilitiesApplyConfiguration",
+            self.add.as_ref().map_or(0, |_| 1) +
+            self.drop.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.add {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "add", value)?;
+        }
+        if let Some(value) = &self.drop {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "drop", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Capabilities {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.Capabilities".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::Capabilities".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.Capabilities".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -113,8 +238,9 @@ impl crate::schemars::JsonSchema for Capabilities {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "add".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -133,8 +259,8 @@ impl crate::schemars::JsonSchema for Capabilities {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "drop".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -153,11 +279,22 @@ impl crate::schemars::JsonSchema for Capabilities {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for Capabilities {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(Capabilities {
+            add: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            drop: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
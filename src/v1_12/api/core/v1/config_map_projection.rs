@@ -3,7 +3,7 @@
 /// Adapts a ConfigMap into a projected volume.
 ///
 /// The contents of the target ConfigMap's Data field will be presented in a projected volume as files using the keys in the Data field as the file names, unless the items element is populated with specific mappings of keys to paths. Note that this is identical to a configmap volume source without the default mode.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ConfigMapProjection {
     /// If unspecified, each key-value pair in the Data field of the referenced ConfigMap will be projected into the volume as a file whose name is the key and content is the value. If specified, the listed keys will be projected into the specified paths, and unlisted keys will not be present. If a key is specified which is not present in the ConfigMap, the volume setup will error unless it is marked optional. Paths must be relative and may not contain the '..' path or start with '..'.
     pub items: Option<Vec<crate::api::core::v1::KeyToPath>>,
@@ -15,41 +15,41 @@ pub struct ConfigMapProjection {
     pub optional: Option<bool>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ConfigMapProjection {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_items,
-            Key_name,
-            Key_optional,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl ConfigMapProjection {
+    /// Returns this value with [`ConfigMapProjection::items`] set to the given value.
+    #[must_use]
+    pub fn with_items(mut self, value: impl Into<Vec<crate::api::core::v1::KeyToPath>>) -> Self {
+        self.items = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ConfigMapProjection::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ConfigMapProjection::optional`] set to the given value.
+    #[must_use]
+    pub fn with_optional(mut self, value: impl Into<bool>) -> Self {
+        self.optional = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "items" => Field::Key_items,
-                            "name" => Field::Key_name,
-                            "optional" => Field::Key_optional,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for ConfigMapProjection {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl ConfigMapProjection {
+    const FIELDS: &'static [&'static str] = &[
+        "items",
+        "name",
+        "optional",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ConfigMapProjection {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -64,12 +64,12 @@ impl<'de> crate::serde::Deserialize<'de> for ConfigMapProjection {
                 let mut value_name: Option<String> = None;
                 let mut value_optional: Option<bool> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ConfigMapProjection", ConfigMapProjection::FIELDS))? {
                     match key {
-                        Field::Key_items => value_items = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_name => value_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_optional => value_optional = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_items = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_optional = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -79,22 +79,86 @@ impl<'de> crate::serde::Deserialize<'de> for ConfigMapProjection {
                     optional: value_optional,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ConfigMapProjection {
+                    items: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    optional: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "ConfigMapProjection",
-            &[
-                "items",
-                "name",
-                "optional",
-            ],
+            ConfigMapProjection::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ConfigMapProjection> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ConfigMapProjection")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_items = false;
+                let mut has_name = false;
+                let mut has_optional = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ConfigMapProjection", ConfigMapProjection::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.items))?; has_items = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.name))?; has_name = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.optional))?; has_optional = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_items {
+                    self.0.items = None;
+                }
+                if !has_name {
+                    self.0.name = None;
+                }
+                if !has_optional {
+                    self.0.optional = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.items))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.optional))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ConfigMapProjection",
+            ConfigMapProjection::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ConfigMapProjection {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ConfigMapProjection", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "items", &self.items)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "optional", &self.optional)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ConfigMapProjection",
             self.items.as_ref().map_or(0, |_| 1) +
@@ -114,10 +178,99 @@ impl crate::serde::Serialize for ConfigMapProjection {
     }
 }
 
+/// A builder of [`ConfigMapProjection`]. Created with [`ConfigMapProjection::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ConfigMapProjectionBuilder {
+    items: Option<Vec<crate::api::core::v1::KeyToPath>>,
+    name: Option<String>,
+    optional: Option<bool>,
+}
+
+#[cfg(feature = "builder")]
+impl ConfigMapProjection {
+    /// Starts building a `ConfigMapProjection`. Its required fields must be set before [`ConfigMapProjectionBuilder::build`] is called.
+    pub fn builder() -> ConfigMapProjectionBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ConfigMapProjectionBuilder {
+    /// Sets [`ConfigMapProjection::items`].
+    pub fn items(mut self, value: impl Into<Vec<crate::api::core::v1::KeyToPath>>) -> Self {
+        self.items = Some(value.into());
+        self
+    }
+
+    /// Sets [`ConfigMapProjection::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`ConfigMapProjection::optional`].
+    pub fn optional(mut self, value: impl Into<bool>) -> Self {
+        self.optional = Some(value.into());
+        self
+    }
+
+    /// Builds the `ConfigMapProjection`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ConfigMapProjection, crate::MissingFieldError> {
+        Ok(ConfigMapProjection {
+            items: self.items,
+            name: self.name,
+            optional: self.optional,
+        })
+    }
+}
+
+/// The apply configuration of [`ConfigMapProjection`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConfigMapProjectionApplyConfiguration {
+    /// See [`ConfigMapProjection::items`].
+    pub items: Option<Vec<crate::api::core::v1::KeyToPathApplyConfiguration>>,
+
+    /// See [`ConfigMapProjection::name`].
+    pub name: Option<String>,
+
+    /// See [`ConfigMapProjection::optional`].
+    pub optional: Option<bool>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ConfigMapProjectionApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ConfigMapProjectionApplyConfiguration",
+            self.items.as_ref().map_or(0, |_| 1) +
+            self.name.as_ref().map_or(0, |_| 1) +
+            self.optional.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.items {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "items", value)?;
+        }
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        if let Some(value) = &self.optional {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "optional", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ConfigMapProjection {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.ConfigMapProjection".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::ConfigMapProjection".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.ConfigMapProjection".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -128,8 +281,9 @@ impl crate::schemars::JsonSchema for ConfigMapProjection {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "items".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -143,8 +297,8 @@ impl crate::schemars::JsonSchema for ConfigMapProjection {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -154,8 +308,8 @@ impl crate::schemars::JsonSchema for ConfigMapProjection {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "optional".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -165,11 +319,23 @@ impl crate::schemars::JsonSchema for ConfigMapProjection {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ConfigMapProjection {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ConfigMapProjection {
+            items: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            optional: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,80 +1,118 @@
 // Generated from definition io.k8s.api.core.v1.ContainerStateRunning
 
 /// ContainerStateRunning is a running state of a container.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ContainerStateRunning {
     /// Time at which the container was last (re-)started
     pub started_at: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
 }
 
+impl ContainerStateRunning {
+    /// Returns this value with [`ContainerStateRunning::started_at`] set to the given value.
+    #[must_use]
+    pub fn with_started_at(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.started_at = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for ContainerStateRunning {}
+
+impl ContainerStateRunning {
+    const FIELDS: &'static [&'static str] = &[
+        "startedAt",
+    ];
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ContainerStateRunning {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_started_at,
-            Other,
-        }
+        struct Visitor;
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = ContainerStateRunning;
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ContainerStateRunning")
+            }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_started_at: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "startedAt" => Field::Key_started_at,
-                            _ => Field::Other,
-                        })
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ContainerStateRunning", ContainerStateRunning::FIELDS))? {
+                    match key {
+                        Some(0) => value_started_at = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
-                deserializer.deserialize_identifier(Visitor)
+                Ok(ContainerStateRunning {
+                    started_at: value_started_at,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ContainerStateRunning {
+                    started_at: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                })
             }
         }
 
-        struct Visitor;
+        deserializer.deserialize_struct(
+            "ContainerStateRunning",
+            ContainerStateRunning::FIELDS,
+            Visitor,
+        )
+    }
 
-        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-            type Value = ContainerStateRunning;
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ContainerStateRunning> {
+            type Value = ();
 
             fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 f.write_str("ContainerStateRunning")
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
-                let mut value_started_at: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
+                let mut has_started_at = false;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ContainerStateRunning", ContainerStateRunning::FIELDS))? {
                     match key {
-                        Field::Key_started_at => value_started_at = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.started_at))?; has_started_at = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
+                if !has_started_at {
+                    self.0.started_at = None;
+                }
 
-                Ok(ContainerStateRunning {
-                    started_at: value_started_at,
-                })
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.started_at))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
             }
         }
 
         deserializer.deserialize_struct(
             "ContainerStateRunning",
-            &[
-                "startedAt",
-            ],
-            Visitor,
+            ContainerStateRunning::FIELDS,
+            InPlaceVisitor(place),
         )
     }
 }
 
 impl crate::serde::Serialize for ContainerStateRunning {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ContainerStateRunning", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "startedAt", &self.started_at)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ContainerStateRunning",
             self.started_at.as_ref().map_or(0, |_| 1),
@@ -86,10 +124,69 @@ impl crate::serde::Serialize for ContainerStateRunning {
     }
 }
 
+/// A builder of [`ContainerStateRunning`]. Created with [`ContainerStateRunning::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ContainerStateRunningBuilder {
+    started_at: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+}
+
+#[cfg(feature = "builder")]
+impl ContainerStateRunning {
+    /// Starts building a `ContainerStateRunning`. Its required fields must be set before [`ContainerStateRunningBuilder::build`] is called.
+    pub fn builder() -> ContainerStateRunningBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ContainerStateRunningBuilder {
+    /// Sets [`ContainerStateRunning::started_at`].
+    pub fn started_at(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.started_at = Some(value.into());
+        self
+    }
+
+    /// Builds the `ContainerStateRunning`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ContainerStateRunning, crate::MissingFieldError> {
+        Ok(ContainerStateRunning {
+            started_at: self.started_at,
+        })
+    }
+}
+
+/// The apply configuration of [`ContainerStateRunning`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ContainerStateRunningApplyConfiguration {
+    /// See [`ContainerStateRunning::started_at`].
+    pub started_at: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ContainerStateRunningApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ContainerStateRunningApplyConfiguration",
+            self.started_at.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.started_at {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "startedAt", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ContainerStateRunning {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.ContainerStateRunning".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::ContainerStateRunning".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.ContainerStateRunning".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -100,8 +197,9 @@ impl crate::schemars::JsonSchema for ContainerStateRunning {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "startedAt".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::Time>().into_object();
@@ -111,11 +209,21 @@ impl crate::schemars::JsonSchema for ContainerStateRunning {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ContainerStateRunning {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ContainerStateRunning {
+            started_at: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
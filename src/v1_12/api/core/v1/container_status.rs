@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.ContainerStatus
 
 /// ContainerStatus contains details for the current status of this container.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ContainerStatus {
     /// Container's ID in the format 'docker://\<container_id\>'.
     pub container_id: Option<String>,
@@ -28,51 +28,97 @@ pub struct ContainerStatus {
     pub state: Option<crate::api::core::v1::ContainerState>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ContainerStatus {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_container_id,
-            Key_image,
-            Key_image_id,
-            Key_last_state,
-            Key_name,
-            Key_ready,
-            Key_restart_count,
-            Key_state,
-            Other,
+impl ContainerStatus {
+    /// Constructs a `ContainerStatus` from its required fields. Its optional fields are unset.
+    pub fn new(image: impl Into<String>, image_id: impl Into<String>, name: impl Into<String>, ready: impl Into<bool>, restart_count: impl Into<i32>) -> Self {
+        ContainerStatus {
+            container_id: None,
+            image: image.into(),
+            image_id: image_id.into(),
+            last_state: None,
+            name: name.into(),
+            ready: ready.into(),
+            restart_count: restart_count.into(),
+            state: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl ContainerStatus {
+    /// Returns this value with [`ContainerStatus::container_id`] set to the given value.
+    #[must_use]
+    pub fn with_container_id(mut self, value: impl Into<String>) -> Self {
+        self.container_id = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ContainerStatus::image`] set to the given value.
+    #[must_use]
+    pub fn with_image(mut self, value: impl Into<String>) -> Self {
+        self.image = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ContainerStatus::image_id`] set to the given value.
+    #[must_use]
+    pub fn with_image_id(mut self, value: impl Into<String>) -> Self {
+        self.image_id = value.into();
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "containerID" => Field::Key_container_id,
-                            "image" => Field::Key_image,
-                            "imageID" => Field::Key_image_id,
-                            "lastState" => Field::Key_last_state,
-                            "name" => Field::Key_name,
-                            "ready" => Field::Key_ready,
-                            "restartCount" => Field::Key_restart_count,
-                            "state" => Field::Key_state,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`ContainerStatus::last_state`] set to the given value.
+    #[must_use]
+    pub fn with_last_state(mut self, value: impl Into<crate::api::core::v1::ContainerState>) -> Self {
+        self.last_state = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`ContainerStatus::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
+
+    /// Returns this value with [`ContainerStatus::ready`] set to the given value.
+    #[must_use]
+    pub fn with_ready(mut self, value: impl Into<bool>) -> Self {
+        self.ready = value.into();
+        self
+    }
+
+    /// Returns this value with [`ContainerStatus::restart_count`] set to the given value.
+    #[must_use]
+    pub fn with_restart_count(mut self, value: impl Into<i32>) -> Self {
+        self.restart_count = value.into();
+        self
+    }
+
+    /// Returns this value with [`ContainerStatus::state`] set to the given value.
+    #[must_use]
+    pub fn with_state(mut self, value: impl Into<crate::api::core::v1::ContainerState>) -> Self {
+        self.state = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for ContainerStatus {}
+
+impl ContainerStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "containerID",
+        "image",
+        "imageID",
+        "lastState",
+        "name",
+        "ready",
+        "restartCount",
+        "state",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ContainerStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -92,52 +138,151 @@ impl<'de> crate::serde::Deserialize<'de> for ContainerStatus {
                 let mut value_restart_count: Option<i32> = None;
                 let mut value_state: Option<crate::api::core::v1::ContainerState> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ContainerStatus", ContainerStatus::FIELDS))? {
                     match key {
-                        Field::Key_container_id => value_container_id = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_image => value_image = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_image_id => value_image_id = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_last_state => value_last_state = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_name => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_ready => value_ready = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_restart_count => value_restart_count = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_state => value_state = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_container_id = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_image = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_image_id = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_last_state = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(5) => value_ready = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(6) => value_restart_count = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(7) => value_state = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ContainerStatus {
                     container_id: value_container_id,
-                    image: value_image.ok_or_else(|| crate::serde::de::Error::missing_field("image"))?,
-                    image_id: value_image_id.ok_or_else(|| crate::serde::de::Error::missing_field("imageID"))?,
+                    image: value_image.ok_or_else(|| crate::serde2::missing_field("ContainerStatus", "image", &["image", "imageID", "name", "ready", "restartCount"]))?,
+                    image_id: value_image_id.ok_or_else(|| crate::serde2::missing_field("ContainerStatus", "imageID", &["image", "imageID", "name", "ready", "restartCount"]))?,
                     last_state: value_last_state,
-                    name: value_name.ok_or_else(|| crate::serde::de::Error::missing_field("name"))?,
-                    ready: value_ready.ok_or_else(|| crate::serde::de::Error::missing_field("ready"))?,
-                    restart_count: value_restart_count.ok_or_else(|| crate::serde::de::Error::missing_field("restartCount"))?,
+                    name: value_name.ok_or_else(|| crate::serde2::missing_field("ContainerStatus", "name", &["image", "imageID", "name", "ready", "restartCount"]))?,
+                    ready: value_ready.ok_or_else(|| crate::serde2::missing_field("ContainerStatus", "ready", &["image", "imageID", "name", "ready", "restartCount"]))?,
+                    restart_count: value_restart_count.ok_or_else(|| crate::serde2::missing_field("ContainerStatus", "restartCount", &["image", "imageID", "name", "ready", "restartCount"]))?,
                     state: value_state,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ContainerStatus {
+                    container_id: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    image: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    image_id: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    last_state: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    ready: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    restart_count: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    state: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "ContainerStatus",
-            &[
-                "containerID",
-                "image",
-                "imageID",
-                "lastState",
-                "name",
-                "ready",
-                "restartCount",
-                "state",
-            ],
+            ContainerStatus::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ContainerStatus> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ContainerStatus")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_container_id = false;
+                let mut has_image = false;
+                let mut has_image_id = false;
+                let mut has_last_state = false;
+                let mut has_name = false;
+                let mut has_ready = false;
+                let mut has_restart_count = false;
+                let mut has_state = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ContainerStatus", ContainerStatus::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.container_id))?; has_container_id = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.image))?; has_image = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.image_id))?; has_image_id = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.last_state))?; has_last_state = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.name))?; has_name = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.ready))?; has_ready = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.restart_count))?; has_restart_count = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.state))?; has_state = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_container_id {
+                    self.0.container_id = None;
+                }
+                if !has_image {
+                    return Err(crate::serde2::missing_field("ContainerStatus", "image", &["image", "imageID", "name", "ready", "restartCount"]));
+                }
+                if !has_image_id {
+                    return Err(crate::serde2::missing_field("ContainerStatus", "imageID", &["image", "imageID", "name", "ready", "restartCount"]));
+                }
+                if !has_last_state {
+                    self.0.last_state = None;
+                }
+                if !has_name {
+                    return Err(crate::serde2::missing_field("ContainerStatus", "name", &["image", "imageID", "name", "ready", "restartCount"]));
+                }
+                if !has_ready {
+                    return Err(crate::serde2::missing_field("ContainerStatus", "ready", &["image", "imageID", "name", "ready", "restartCount"]));
+                }
+                if !has_restart_count {
+                    return Err(crate::serde2::missing_field("ContainerStatus", "restartCount", &["image", "imageID", "name", "ready", "restartCount"]));
+                }
+                if !has_state {
+                    self.0.state = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.container_id))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.image))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.image_id))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.last_state))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.ready))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.restart_count))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.state))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ContainerStatus",
+            ContainerStatus::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ContainerStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ContainerStatus", 8)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "containerID", &self.container_id)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "image", &self.image)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "imageID", &self.image_id)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "lastState", &self.last_state)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ready", &self.ready)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "restartCount", &self.restart_count)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "state", &self.state)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ContainerStatus",
             5 +
@@ -163,10 +308,174 @@ impl crate::serde::Serialize for ContainerStatus {
     }
 }
 
+/// A builder of [`ContainerStatus`]. Created with [`ContainerStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ContainerStatusBuilder {
+    container_id: Option<String>,
+    image: Option<String>,
+    image_id: Option<String>,
+    last_state: Option<crate::api::core::v1::ContainerState>,
+    name: Option<String>,
+    ready: Option<bool>,
+    restart_count: Option<i32>,
+    state: Option<crate::api::core::v1::ContainerState>,
+}
+
+#[cfg(feature = "builder")]
+impl ContainerStatus {
+    /// Starts building a `ContainerStatus`. Its required fields must be set before [`ContainerStatusBuilder::build`] is called.
+    pub fn builder() -> ContainerStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ContainerStatusBuilder {
+    /// Sets [`ContainerStatus::container_id`].
+    pub fn container_id(mut self, value: impl Into<String>) -> Self {
+        self.container_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`ContainerStatus::image`].
+    pub fn image(mut self, value: impl Into<String>) -> Self {
+        self.image = Some(value.into());
+        self
+    }
+
+    /// Sets [`ContainerStatus::image_id`].
+    pub fn image_id(mut self, value: impl Into<String>) -> Self {
+        self.image_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`ContainerStatus::last_state`].
+    pub fn last_state(mut self, value: impl Into<crate::api::core::v1::ContainerState>) -> Self {
+        self.last_state = Some(value.into());
+        self
+    }
+
+    /// Sets [`ContainerStatus::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`ContainerStatus::ready`].
+    pub fn ready(mut self, value: impl Into<bool>) -> Self {
+        self.ready = Some(value.into());
+        self
+    }
+
+    /// Sets [`ContainerStatus::restart_count`].
+    pub fn restart_count(mut self, value: impl Into<i32>) -> Self {
+        self.restart_count = Some(value.into());
+        self
+    }
+
+    /// Sets [`ContainerStatus::state`].
+    pub fn state(mut self, value: impl Into<crate::api::core::v1::ContainerState>) -> Self {
+        self.state = Some(value.into());
+        self
+    }
+
+    /// Builds the `ContainerStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ContainerStatus, crate::MissingFieldError> {
+        Ok(ContainerStatus {
+            container_id: self.container_id,
+            image: self.image.ok_or_else(|| crate::MissingFieldError::new("ContainerStatus", "image"))?,
+            image_id: self.image_id.ok_or_else(|| crate::MissingFieldError::new("ContainerStatus", "imageID"))?,
+            last_state: self.last_state,
+            name: self.name.ok_or_else(|| crate::MissingFieldError::new("ContainerStatus", "name"))?,
+            ready: self.ready.ok_or_else(|| crate::MissingFieldError::new("ContainerStatus", "ready"))?,
+            restart_count: self.restart_count.ok_or_else(|| crate::MissingFieldError::new("ContainerStatus", "restartCount"))?,
+            state: self.state,
+        })
+    }
+}
+
+/// The apply configuration of [`ContainerStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ContainerStatusApplyConfiguration {
+    /// See [`ContainerStatus::container_id`].
+    pub container_id: Option<String>,
+
+    /// See [`ContainerStatus::image`].
+    pub image: Option<String>,
+
+    /// See [`ContainerStatus::image_id`].
+    pub image_id: Option<String>,
+
+    /// See [`ContainerStatus::last_state`].
+    pub last_state: Option<crate::api::core::v1::ContainerStateApplyConfiguration>,
+
+    /// See [`ContainerStatus::name`].
+    pub name: Option<String>,
+
+    /// See [`ContainerStatus::ready`].
+    pub ready: Option<bool>,
+
+    /// See [`ContainerStatus::restart_count`].
+    pub restart_count: Option<i32>,
+
+    /// See [`ContainerStatus::state`].
+    pub state: Option<crate::api::core::v1::ContainerStateApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ContainerStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ContainerStatusApplyConfiguration",
+            self.container_id.as_ref().map_or(0, |_| 1) +
+            self.image.as_ref().map_or(0, |_| 1) +
+            self.image_id.as_ref().map_or(0, |_| 1) +
+            self.last_state.as_ref().map_or(0, |_| 1) +
+            self.name.as_ref().map_or(0, |_| 1) +
+            self.ready.as_ref().map_or(0, |_| 1) +
+            self.restart_count.as_ref().map_or(0, |_| 1) +
+            self.state.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.container_id {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "containerID", value)?;
+        }
+        if let Some(value) = &self.image {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "image", value)?;
+        }
+        if let Some(value) = &self.image_id {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "imageID", value)?;
+        }
+        if let Some(value) = &self.last_state {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "lastState", value)?;
+        }
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        if let Some(value) = &self.ready {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ready", value)?;
+        }
+        if let Some(value) = &self.restart_count {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "restartCount", value)?;
+        }
+        if let Some(value) = &self.state {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "state", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ContainerStatus {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.ContainerStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::ContainerStatus".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.ContainerStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -177,8 +486,9 @@ impl crate::schemars::JsonSchema for ContainerStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "containerID".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -188,8 +498,8 @@ impl crate::schemars::JsonSchema for ContainerStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "image".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -199,8 +509,8 @@ impl crate::schemars::JsonSchema for ContainerStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "imageID".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -210,8 +520,8 @@ impl crate::schemars::JsonSchema for ContainerStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "lastState".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ContainerState>().into_object();
@@ -221,8 +531,8 @@ impl crate::schemars::JsonSchema for ContainerStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -232,8 +542,8 @@ impl crate::schemars::JsonSchema for ContainerStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "ready".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -243,8 +553,8 @@ impl crate::schemars::JsonSchema for ContainerStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "restartCount".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -255,8 +565,8 @@ impl crate::schemars::JsonSchema for ContainerStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "state".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ContainerState>().into_object();
@@ -266,8 +576,9 @@ impl crate::schemars::JsonSchema for ContainerStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "image",
                     "imageID",
@@ -281,3 +592,19 @@ impl crate::schemars::JsonSchema for ContainerStatus {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ContainerStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ContainerStatus {
+            container_id: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            image: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            image_id: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            last_state: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            ready: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            restart_count: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            state: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
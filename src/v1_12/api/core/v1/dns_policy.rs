@@ -0,0 +1,165 @@
+// Generated from definition io.k8s.api.core.v1.DNSPolicy
+
+/// DNSPolicy defines how a pod's DNS will be configured.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum DNSPolicy {
+    /// `ClusterFirst`
+    ClusterFirst,
+
+    /// `ClusterFirstWithHostNet`
+    ClusterFirstWithHostNet,
+
+    /// `Default`
+    Default,
+
+    /// `None`
+    None,
+
+    /// A value that is not one of the values known to this version of the crate, such as one that was added in a newer version of Kubernetes.
+    Other(String),
+}
+
+impl DNSPolicy {
+    /// The values known to this version of the crate, in the order of the variants.
+    pub const VALUES: &'static [&'static str] = &["ClusterFirst", "ClusterFirstWithHostNet", "Default", "None"];
+
+    /// The value as it is serialized.
+    pub fn as_str(&self) -> &str {
+        match self {
+            DNSPolicy::ClusterFirst => "ClusterFirst",
+            DNSPolicy::ClusterFirstWithHostNet => "ClusterFirstWithHostNet",
+            DNSPolicy::Default => "Default",
+            DNSPolicy::None => "None",
+            DNSPolicy::Other(value) => value,
+        }
+    }
+}
+
+impl AsRef<str> for DNSPolicy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Default for DNSPolicy {
+    fn default() -> Self {
+        DNSPolicy::Other(String::new())
+    }
+}
+
+impl std::fmt::Display for DNSPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for DNSPolicy {
+    fn from(value: &str) -> Self {
+        match value {
+            "ClusterFirst" => DNSPolicy::ClusterFirst,
+            "ClusterFirstWithHostNet" => DNSPolicy::ClusterFirstWithHostNet,
+            "Default" => DNSPolicy::Default,
+            "None" => DNSPolicy::None,
+            value => DNSPolicy::Other(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DNSPolicy {
+    fn from(value: String) -> Self {
+        match &*value {
+            "ClusterFirst" => DNSPolicy::ClusterFirst,
+            "ClusterFirstWithHostNet" => DNSPolicy::ClusterFirstWithHostNet,
+            "Default" => DNSPolicy::Default,
+            "None" => DNSPolicy::None,
+            _ => DNSPolicy::Other(value),
+        }
+    }
+}
+
+impl From<DNSPolicy> for String {
+    fn from(value: DNSPolicy) -> Self {
+        match value {
+            DNSPolicy::Other(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl PartialEq<str> for DNSPolicy {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for DNSPolicy {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<'de> crate::serde::Deserialize<'de> for DNSPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl crate::serde::de::Visitor<'_> for Visitor {
+            type Value = DNSPolicy;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("DNSPolicy")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
+                Ok(v.into())
+            }
+
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E> where E: crate::serde::de::Error {
+                Ok(v.into())
+            }
+        }
+
+        deserializer.deserialize_string(Visitor)
+    }
+}
+
+impl crate::serde::Serialize for DNSPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for DNSPolicy {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::DNSPolicy".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.DNSPolicy".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("DNSPolicy defines how a pod's DNS will be configured.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for DNSPolicy {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        Ok(match u.int_in_range(0..=4_u8)? {
+            0 => DNSPolicy::ClusterFirst,
+            1 => DNSPolicy::ClusterFirstWithHostNet,
+            2 => DNSPolicy::Default,
+            3 => DNSPolicy::None,
+            _ => DNSPolicy::from(u.arbitrary::<String>()?),
+        })
+    }
+}
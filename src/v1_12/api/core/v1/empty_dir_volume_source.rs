@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.EmptyDirVolumeSource
 
 /// Represents an empty directory for a pod. Empty directory volumes support ownership management and SELinux relabeling.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct EmptyDirVolumeSource {
     /// What type of storage medium should back this directory. The default is "" which means to use the node's default medium. Must be an empty string (default) or Memory. More info: https://kubernetes.io/docs/concepts/storage/volumes#emptydir
     pub medium: Option<String>,
@@ -10,39 +10,33 @@ pub struct EmptyDirVolumeSource {
     pub size_limit: Option<crate::apimachinery::pkg::api::resource::Quantity>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for EmptyDirVolumeSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_medium,
-            Key_size_limit,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl EmptyDirVolumeSource {
+    /// Returns this value with [`EmptyDirVolumeSource::medium`] set to the given value.
+    #[must_use]
+    pub fn with_medium(mut self, value: impl Into<String>) -> Self {
+        self.medium = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`EmptyDirVolumeSource::size_limit`] set to the given value.
+    #[must_use]
+    pub fn with_size_limit(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.size_limit = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "medium" => Field::Key_medium,
-                            "sizeLimit" => Field::Key_size_limit,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for EmptyDirVolumeSource {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl EmptyDirVolumeSource {
+    const FIELDS: &'static [&'static str] = &[
+        "medium",
+        "sizeLimit",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for EmptyDirVolumeSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,11 +50,11 @@ impl<'de> crate::serde::Deserialize<'de> for EmptyDirVolumeSource {
                 let mut value_medium: Option<String> = None;
                 let mut value_size_limit: Option<crate::apimachinery::pkg::api::resource::Quantity> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("EmptyDirVolumeSource", EmptyDirVolumeSource::FIELDS))? {
                     match key {
-                        Field::Key_medium => value_medium = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_size_limit => value_size_limit = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_medium = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_size_limit = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -69,21 +63,78 @@ impl<'de> crate::serde::Deserialize<'de> for EmptyDirVolumeSource {
                     size_limit: value_size_limit,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(EmptyDirVolumeSource {
+                    medium: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    size_limit: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "EmptyDirVolumeSource",
-            &[
-                "medium",
-                "sizeLimit",
-            ],
+            EmptyDirVolumeSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, EmptyDirVolumeSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("EmptyDirVolumeSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_medium = false;
+                let mut has_size_limit = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("EmptyDirVolumeSource", EmptyDirVolumeSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.medium))?; has_medium = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.size_limit))?; has_size_limit = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_medium {
+                    self.0.medium = None;
+                }
+                if !has_size_limit {
+                    self.0.size_limit = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.medium))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.size_limit))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "EmptyDirVolumeSource",
+            EmptyDirVolumeSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for EmptyDirVolumeSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("EmptyDirVolumeSource", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "medium", &self.medium)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "sizeLimit", &self.size_limit)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "EmptyDirVolumeSource",
             self.medium.as_ref().map_or(0, |_| 1) +
@@ -99,10 +150,84 @@ impl crate::serde::Serialize for EmptyDirVolumeSource {
     }
 }
 
+/// A builder of [`EmptyDirVolumeSource`]. Created with [`EmptyDirVolumeSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct EmptyDirVolumeSourceBuilder {
+    medium: Option<String>,
+    size_limit: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+}
+
+#[cfg(feature = "builder")]
+impl EmptyDirVolumeSource {
+    /// Starts building a `EmptyDirVolumeSource`. Its required fields must be set before [`EmptyDirVolumeSourceBuilder::build`] is called.
+    pub fn builder() -> EmptyDirVolumeSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl EmptyDirVolumeSourceBuilder {
+    /// Sets [`EmptyDirVolumeSource::medium`].
+    pub fn medium(mut self, value: impl Into<String>) -> Self {
+        self.medium = Some(value.into());
+        self
+    }
+
+    /// Sets [`EmptyDirVolumeSource::size_limit`].
+    pub fn size_limit(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.size_limit = Some(value.into());
+        self
+    }
+
+    /// Builds the `EmptyDirVolumeSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<EmptyDirVolumeSource, crate::MissingFieldError> {
+        Ok(EmptyDirVolumeSource {
+            medium: self.medium,
+            size_limit: self.size_limit,
+        })
+    }
+}
+
+/// The apply configuration of [`EmptyDirVolumeSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EmptyDirVolumeSourceApplyConfiguration {
+    /// See [`EmptyDirVolumeSource::medium`].
+    pub medium: Option<String>,
+
+    /// See [`EmptyDirVolumeSource::size_limit`].
+    pub size_limit: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for EmptyDirVolumeSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "EmptyDirVolumeSourceApplyConfiguration",
+            self.medium.as_ref().map_or(0, |_| 1) +
+            self.size_limit.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.medium {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "medium", value)?;
+        }
+        if let Some(value) = &self.size_limit {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "sizeLimit", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for EmptyDirVolumeSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.EmptyDirVolumeSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::EmptyDirVolumeSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.EmptyDirVolumeSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -113,8 +238,9 @@ impl crate::schemars::JsonSchema for EmptyDirVolumeSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "medium".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -124,8 +250,8 @@ impl crate::schemars::JsonSchema for EmptyDirVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "sizeLimit".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::api::resource::Quantity>().into_object();
@@ -135,11 +261,22 @@ impl crate::schemars::JsonSchema for EmptyDirVolumeSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for EmptyDirVolumeSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(EmptyDirVolumeSource {
+            medium: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            size_limit: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.EndpointPort
 
 /// EndpointPort is a tuple that describes a single port.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct EndpointPort {
     /// The name of this port (corresponds to ServicePort.Name). Must be a DNS_LABEL. Optional only if one port is defined.
     pub name: Option<String>,
@@ -13,41 +13,52 @@ pub struct EndpointPort {
     pub protocol: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for EndpointPort {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_name,
-            Key_port,
-            Key_protocol,
-            Other,
+impl EndpointPort {
+    /// Constructs a `EndpointPort` from its required fields. Its optional fields are unset.
+    pub fn new(port: impl Into<i32>) -> Self {
+        EndpointPort {
+            name: None,
+            port: port.into(),
+            protocol: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl EndpointPort {
+    /// Returns this value with [`EndpointPort::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`EndpointPort::port`] set to the given value.
+    #[must_use]
+    pub fn with_port(mut self, value: impl Into<i32>) -> Self {
+        self.port = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`EndpointPort::protocol`] set to the given value.
+    #[must_use]
+    pub fn with_protocol(mut self, value: impl Into<String>) -> Self {
+        self.protocol = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "name" => Field::Key_name,
-                            "port" => Field::Key_port,
-                            "protocol" => Field::Key_protocol,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for EndpointPort {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl EndpointPort {
+    const FIELDS: &'static [&'static str] = &[
+        "name",
+        "port",
+        "protocol",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for EndpointPort {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,37 +73,101 @@ impl<'de> crate::serde::Deserialize<'de> for EndpointPort {
                 let mut value_port: Option<i32> = None;
                 let mut value_protocol: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("EndpointPort", EndpointPort::FIELDS))? {
                     match key {
-                        Field::Key_name => value_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_port => value_port = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_protocol => value_protocol = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_port = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_protocol = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(EndpointPort {
                     name: value_name,
-                    port: value_port.ok_or_else(|| crate::serde::de::Error::missing_field("port"))?,
+                    port: value_port.ok_or_else(|| crate::serde2::missing_field("EndpointPort", "port", &["port"]))?,
                     protocol: value_protocol,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(EndpointPort {
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    port: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    protocol: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "EndpointPort",
-            &[
-                "name",
-                "port",
-                "protocol",
-            ],
+            EndpointPort::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, EndpointPort> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("EndpointPort")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_name = false;
+                let mut has_port = false;
+                let mut has_protocol = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("EndpointPort", EndpointPort::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.name))?; has_name = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.port))?; has_port = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.protocol))?; has_protocol = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_name {
+                    self.0.name = None;
+                }
+                if !has_port {
+                    return Err(crate::serde2::missing_field("EndpointPort", "port", &["port"]));
+                }
+                if !has_protocol {
+                    self.0.protocol = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.port))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.protocol))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "EndpointPort",
+            EndpointPort::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for EndpointPort {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("EndpointPort", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "port", &self.port)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "protocol", &self.protocol)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "EndpointPort",
             1 +
@@ -110,10 +185,99 @@ impl crate::serde::Serialize for EndpointPort {
     }
 }
 
+/// A builder of [`EndpointPort`]. Created with [`EndpointPort::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct EndpointPortBuilder {
+    name: Option<String>,
+    port: Option<i32>,
+    protocol: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl EndpointPort {
+    /// Starts building a `EndpointPort`. Its required fields must be set before [`EndpointPortBuilder::build`] is called.
+    pub fn builder() -> EndpointPortBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl EndpointPortBuilder {
+    /// Sets [`EndpointPort::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`EndpointPort::port`].
+    pub fn port(mut self, value: impl Into<i32>) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets [`EndpointPort::protocol`].
+    pub fn protocol(mut self, value: impl Into<String>) -> Self {
+        self.protocol = Some(value.into());
+        self
+    }
+
+    /// Builds the `EndpointPort`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<EndpointPort, crate::MissingFieldError> {
+        Ok(EndpointPort {
+            name: self.name,
+            port: self.port.ok_or_else(|| crate::MissingFieldError::new("EndpointPort", "port"))?,
+            protocol: self.protocol,
+        })
+    }
+}
+
+/// The apply configuration of [`EndpointPort`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EndpointPortApplyConfiguration {
+    /// See [`EndpointPort::name`].
+    pub name: Option<String>,
+
+    /// See [`EndpointPort::port`].
+    pub port: Option<i32>,
+
+    /// See [`EndpointPort::protocol`].
+    pub protocol: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for EndpointPortApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "EndpointPortApplyConfiguration",
+            self.name.as_ref().map_or(0, |_| 1) +
+            self.port.as_ref().map_or(0, |_| 1) +
+            self.protocol.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        if let Some(value) = &self.port {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "port", value)?;
+        }
+        if let Some(value) = &self.protocol {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "protocol", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for EndpointPort {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.EndpointPort".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::EndpointPort".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.EndpointPort".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -124,8 +288,9 @@ impl crate::schemars::JsonSchema for EndpointPort {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -135,8 +300,8 @@ impl crate::schemars::JsonSchema for EndpointPort {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "port".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -147,8 +312,8 @@ impl crate::schemars::JsonSchema for EndpointPort {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "protocol".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -158,8 +323,9 @@ impl crate::schemars::JsonSchema for EndpointPort {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "port",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -169,3 +335,14 @@ impl crate::schemars::JsonSchema for EndpointPort {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for EndpointPort {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(EndpointPort {
+            name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            port: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            protocol: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
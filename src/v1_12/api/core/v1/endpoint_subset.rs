@@ -8,7 +8,7 @@
 /// The resulting set of endpoints can be viewed as:
 ///     a: \[ 10.10.1.1:8675, 10.10.2.2:8675 \],
 ///     b: \[ 10.10.1.1:309, 10.10.2.2:309 \]
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct EndpointSubset {
     /// IP addresses which offer the related ports that are marked as ready. These endpoints should be considered safe for load balancers and clients to utilize.
     pub addresses: Option<Vec<crate::api::core::v1::EndpointAddress>>,
@@ -20,41 +20,41 @@ pub struct EndpointSubset {
     pub ports: Option<Vec<crate::api::core::v1::EndpointPort>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for EndpointSubset {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_addresses,
-            Key_not_ready_addresses,
-            Key_ports,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl EndpointSubset {
+    /// Returns this value with [`EndpointSubset::addresses`] set to the given value.
+    #[must_use]
+    pub fn with_addresses(mut self, value: impl Into<Vec<crate::api::core::v1::EndpointAddress>>) -> Self {
+        self.addresses = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`EndpointSubset::not_ready_addresses`] set to the given value.
+    #[must_use]
+    pub fn with_not_ready_addresses(mut self, value: impl Into<Vec<crate::api::core::v1::EndpointAddress>>) -> Self {
+        self.not_ready_addresses = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`EndpointSubset::ports`] set to the given value.
+    #[must_use]
+    pub fn with_ports(mut self, value: impl Into<Vec<crate::api::core::v1::EndpointPort>>) -> Self {
+        self.ports = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "addresses" => Field::Key_addresses,
-                            "notReadyAddresses" => Field::Key_not_ready_addresses,
-                            "ports" => Field::Key_ports,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for EndpointSubset {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl EndpointSubset {
+    const FIELDS: &'static [&'static str] = &[
+        "addresses",
+        "notReadyAddresses",
+        "ports",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for EndpointSubset {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -69,12 +69,12 @@ impl<'de> crate::serde::Deserialize<'de> for EndpointSubset {
                 let mut value_not_ready_addresses: Option<Vec<crate::api::core::v1::EndpointAddress>> = None;
                 let mut value_ports: Option<Vec<crate::api::core::v1::EndpointPort>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("EndpointSubset", EndpointSubset::FIELDS))? {
                     match key {
-                        Field::Key_addresses => value_addresses = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_not_ready_addresses => value_not_ready_addresses = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_ports => value_ports = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_addresses = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_not_ready_addresses = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_ports = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -84,22 +84,86 @@ impl<'de> crate::serde::Deserialize<'de> for EndpointSubset {
                     ports: value_ports,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(EndpointSubset {
+                    addresses: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    not_ready_addresses: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    ports: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "EndpointSubset",
-            &[
-                "addresses",
-                "notReadyAddresses",
-                "ports",
-            ],
+            EndpointSubset::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, EndpointSubset> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("EndpointSubset")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_addresses = false;
+                let mut has_not_ready_addresses = false;
+                let mut has_ports = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("EndpointSubset", EndpointSubset::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.addresses))?; has_addresses = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.not_ready_addresses))?; has_not_ready_addresses = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.ports))?; has_ports = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_addresses {
+                    self.0.addresses = None;
+                }
+                if !has_not_ready_addresses {
+                    self.0.not_ready_addresses = None;
+                }
+                if !has_ports {
+                    self.0.ports = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.addresses))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.not_ready_addresses))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.ports))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "EndpointSubset",
+            EndpointSubset::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for EndpointSubset {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("EndpointSubset", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "addresses", &self.addresses)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "notReadyAddresses", &self.not_ready_addresses)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ports", &self.ports)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "EndpointSubset",
             self.addresses.as_ref().map_or(0, |_| 1) +
@@ -119,10 +183,99 @@ impl crate::serde::Serialize for EndpointSubset {
     }
 }
 
+/// A builder of [`EndpointSubset`]. Created with [`EndpointSubset::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct EndpointSubsetBuilder {
+    addresses: Option<Vec<crate::api::core::v1::EndpointAddress>>,
+    not_ready_addresses: Option<Vec<crate::api::core::v1::EndpointAddress>>,
+    ports: Option<Vec<crate::api::core::v1::EndpointPort>>,
+}
+
+#[cfg(feature = "builder")]
+impl EndpointSubset {
+    /// Starts building a `EndpointSubset`. Its required fields must be set before [`EndpointSubsetBuilder::build`] is called.
+    pub fn builder() -> EndpointSubsetBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl EndpointSubsetBuilder {
+    /// Sets [`EndpointSubset::addresses`].
+    pub fn addresses(mut self, value: impl Into<Vec<crate::api::core::v1::EndpointAddress>>) -> Self {
+        self.addresses = Some(value.into());
+        self
+    }
+
+    /// Sets [`EndpointSubset::not_ready_addresses`].
+    pub fn not_ready_addresses(mut self, value: impl Into<Vec<crate::api::core::v1::EndpointAddress>>) -> Self {
+        self.not_ready_addresses = Some(value.into());
+        self
+    }
+
+    /// Sets [`EndpointSubset::ports`].
+    pub fn ports(mut self, value: impl Into<Vec<crate::api::core::v1::EndpointPort>>) -> Self {
+        self.ports = Some(value.into());
+        self
+    }
+
+    /// Builds the `EndpointSubset`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<EndpointSubset, crate::MissingFieldError> {
+        Ok(EndpointSubset {
+            addresses: self.addresses,
+            not_ready_addresses: self.not_ready_addresses,
+            ports: self.ports,
+        })
+    }
+}
+
+/// The apply configuration of [`EndpointSubset`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EndpointSubsetApplyConfiguration {
+    /// See [`EndpointSubset::addresses`].
+    pub addresses: Option<Vec<crate::api::core::v1::EndpointAddressApplyConfiguration>>,
+
+    /// See [`EndpointSubset::not_ready_addresses`].
+    pub not_ready_addresses: Option<Vec<crate::api::core::v1::EndpointAddressApplyConfiguration>>,
+
+    /// See [`EndpointSubset::ports`].
+    pub ports: Option<Vec<crate::api::core::v1::EndpointPortApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for EndpointSubsetApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "EndpointSubsetApplyConfiguration",
+            self.addresses.as_ref().map_or(0, |_| 1) +
+            self.not_ready_addresses.as_ref().map_or(0, |_| 1) +
+            self.ports.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.addresses {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "addresses", value)?;
+        }
+        if let Some(value) = &self.not_ready_addresses {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "notReadyAddresses", value)?;
+        }
+        if let Some(value) = &self.ports {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ports", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for EndpointSubset {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.EndpointSubset".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::EndpointSubset".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.EndpointSubset".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -133,8 +286,9 @@ impl crate::schemars::JsonSchema for EndpointSubset {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "addresses".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -148,8 +302,8 @@ impl crate::schemars::JsonSchema for EndpointSubset {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "notReadyAddresses".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -163,8 +317,8 @@ impl crate::schemars::JsonSchema for EndpointSubset {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "ports".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -178,11 +332,23 @@ impl crate::schemars::JsonSchema for EndpointSubset {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for EndpointSubset {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(EndpointSubset {
+            addresses: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            not_ready_addresses: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            ports: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
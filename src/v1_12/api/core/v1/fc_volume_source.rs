@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.FCVolumeSource
 
 /// Represents a Fibre Channel volume. Fibre Channel volumes can only be mounted as read/write once. Fibre Channel volumes support ownership management and SELinux relabeling.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct FCVolumeSource {
     /// Filesystem type to mount. Must be a filesystem type supported by the host operating system. Ex. "ext4", "xfs", "ntfs". Implicitly inferred to be "ext4" if unspecified.
     pub fs_type: Option<String>,
@@ -19,45 +19,57 @@ pub struct FCVolumeSource {
     pub wwids: Option<Vec<String>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for FCVolumeSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_fs_type,
-            Key_lun,
-            Key_read_only,
-            Key_target_wwns,
-            Key_wwids,
-            Other,
-        }
+impl FCVolumeSource {
+    /// Returns this value with [`FCVolumeSource::fs_type`] set to the given value.
+    #[must_use]
+    pub fn with_fs_type(mut self, value: impl Into<String>) -> Self {
+        self.fs_type = Some(value.into());
+        self
+    }
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+    /// Returns this value with [`FCVolumeSource::lun`] set to the given value.
+    #[must_use]
+    pub fn with_lun(mut self, value: impl Into<i32>) -> Self {
+        self.lun = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`FCVolumeSource::read_only`] set to the given value.
+    #[must_use]
+    pub fn with_read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`FCVolumeSource::target_wwns`] set to the given value.
+    #[must_use]
+    pub fn with_target_wwns(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.target_wwns = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "fsType" => Field::Key_fs_type,
-                            "lun" => Field::Key_lun,
-                            "readOnly" => Field::Key_read_only,
-                            "targetWWNs" => Field::Key_target_wwns,
-                            "wwids" => Field::Key_wwids,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`FCVolumeSource::wwids`] set to the given value.
+    #[must_use]
+    pub fn with_wwids(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.wwids = Some(value.into());
+        self
+    }
+}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl crate::MergeStrategyInfo for FCVolumeSource {}
 
+impl FCVolumeSource {
+    const FIELDS: &'static [&'static str] = &[
+        "fsType",
+        "lun",
+        "readOnly",
+        "targetWWNs",
+        "wwids",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for FCVolumeSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -74,14 +86,14 @@ impl<'de> crate::serde::Deserialize<'de> for FCVolumeSource {
                 let mut value_target_wwns: Option<Vec<String>> = None;
                 let mut value_wwids: Option<Vec<String>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("FCVolumeSource", FCVolumeSource::FIELDS))? {
                     match key {
-                        Field::Key_fs_type => value_fs_type = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_lun => value_lun = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_read_only => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_target_wwns => value_target_wwns = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_wwids => value_wwids = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_fs_type = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_lun = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_target_wwns = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_wwids = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -93,24 +105,102 @@ impl<'de> crate::serde::Deserialize<'de> for FCVolumeSource {
                     wwids: value_wwids,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(FCVolumeSource {
+                    fs_type: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    lun: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    read_only: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    target_wwns: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    wwids: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "FCVolumeSource",
-            &[
-                "fsType",
-                "lun",
-                "readOnly",
-                "targetWWNs",
-                "wwids",
-            ],
+            FCVolumeSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, FCVolumeSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("FCVolumeSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_fs_type = false;
+                let mut has_lun = false;
+                let mut has_read_only = false;
+                let mut has_target_wwns = false;
+                let mut has_wwids = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("FCVolumeSource", FCVolumeSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.fs_type))?; has_fs_type = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.lun))?; has_lun = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.read_only))?; has_read_only = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.target_wwns))?; has_target_wwns = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.wwids))?; has_wwids = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_fs_type {
+                    self.0.fs_type = None;
+                }
+                if !has_lun {
+                    self.0.lun = None;
+                }
+                if !has_read_only {
+                    self.0.read_only = None;
+                }
+                if !has_target_wwns {
+                    self.0.target_wwns = None;
+                }
+                if !has_wwids {
+                    self.0.wwids = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.fs_type))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.lun))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.read_only))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.target_wwns))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.wwids))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "FCVolumeSource",
+            FCVolumeSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for FCVolumeSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("FCVolumeSource", 5)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsType", &self.fs_type)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "lun", &self.lun)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", &self.read_only)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "targetWWNs", &self.target_wwns)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "wwids", &self.wwids)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "FCVolumeSource",
             self.fs_type.as_ref().map_or(0, |_| 1) +
@@ -138,10 +228,129 @@ impl crate::serde::Serialize for FCVolumeSource {
     }
 }
 
+/// A builder of [`FCVolumeSource`]. Created with [`FCVolumeSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct FCVolumeSourceBuilder {
+    fs_type: Option<String>,
+    lun: Option<i32>,
+    read_only: Option<bool>,
+    target_wwns: Option<Vec<String>>,
+    wwids: Option<Vec<String>>,
+}
+
+#[cfg(feature = "builder")]
+impl FCVolumeSource {
+    /// Starts building a `FCVolumeSource`. Its required fields must be set before [`FCVolumeSourceBuilder::build`] is called.
+    pub fn builder() -> FCVolumeSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl FCVolumeSourceBuilder {
+    /// Sets [`FCVolumeSource::fs_type`].
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`FCVolumeSource::lun`].
+    pub fn lun(mut self, value: impl Into<i32>) -> Self {
+        self.lun = Some(value.into());
+        self
+    }
+
+    /// Sets [`FCVolumeSource::read_only`].
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`FCVolumeSource::target_wwns`].
+    pub fn target_wwns(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.target_wwns = Some(value.into());
+        self
+    }
+
+    /// Sets [`FCVolumeSource::wwids`].
+    pub fn wwids(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.wwids = Some(value.into());
+        self
+    }
+
+    /// Builds the `FCVolumeSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<FCVolumeSource, crate::MissingFieldError> {
+        Ok(FCVolumeSource {
+            fs_type: self.fs_type,
+            lun: self.lun,
+            read_only: self.read_only,
+            target_wwns: self.target_wwns,
+            wwids: self.wwids,
+        })
+    }
+}
+
+/// The apply configuration of [`FCVolumeSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FCVolumeSourceApplyConfiguration {
+    /// See [`FCVolumeSource::fs_type`].
+    pub fs_type: Option<String>,
+
+    /// See [`FCVolumeSource::lun`].
+    pub lun: Option<i32>,
+
+    /// See [`FCVolumeSource::read_only`].
+    pub read_only: Option<bool>,
+
+    /// See [`FCVolumeSource::target_wwns`].
+    pub target_wwns: Option<Vec<String>>,
+
+    /// See [`FCVolumeSource::wwids`].
+    pub wwids: Option<Vec<String>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for FCVolumeSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "FCVolumeSourceApplyConfiguration",
+            self.fs_type.as_ref().map_or(0, |_| 1) +
+            self.lun.as_ref().map_or(0, |_| 1) +
+            self.read_only.as_ref().map_or(0, |_| 1) +
+            self.target_wwns.as_ref().map_or(0, |_| 1) +
+            self.wwids.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.fs_type {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsType", value)?;
+        }
+        if let Some(value) = &self.lun {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "lun", value)?;
+        }
+        if let Some(value) = &self.read_only {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", value)?;
+        }
+        if let Some(value) = &self.target_wwns {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "targetWWNs", value)?;
+        }
+        if let Some(value) = &self.wwids {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "wwids", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for FCVolumeSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.FCVolumeSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::FCVolumeSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.FCVolumeSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -152,8 +361,9 @@ impl crate::schemars::JsonSchema for FCVolumeSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "fsType".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -163,8 +373,8 @@ impl crate::schemars::JsonSchema for FCVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "lun".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -175,8 +385,8 @@ impl crate::schemars::JsonSchema for FCVolumeSource {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "readOnly".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -186,8 +396,8 @@ impl crate::schemars::JsonSchema for FCVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "targetWWNs".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -206,8 +416,8 @@ impl crate::schemars::JsonSchema for FCVolumeSource {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "wwids".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -226,11 +436,25 @@ impl crate::schemars::JsonSchema for FCVolumeSource {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for FCVolumeSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(FCVolumeSource {
+            fs_type: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            lun: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            read_only: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            target_wwns: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            wwids: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
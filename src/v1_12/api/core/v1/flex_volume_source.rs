@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.FlexVolumeSource
 
 /// FlexVolume represents a generic volume resource that is provisioned/attached using an exec based plugin.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct FlexVolumeSource {
     /// Driver is the name of the driver to use for this volume.
     pub driver: String,
@@ -19,45 +19,70 @@ pub struct FlexVolumeSource {
     pub secret_ref: Option<crate::api::core::v1::LocalObjectReference>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for FlexVolumeSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_driver,
-            Key_fs_type,
-            Key_options,
-            Key_read_only,
-            Key_secret_ref,
-            Other,
+impl FlexVolumeSource {
+    /// Constructs a `FlexVolumeSource` from its required fields. Its optional fields are unset.
+    pub fn new(driver: impl Into<String>) -> Self {
+        FlexVolumeSource {
+            driver: driver.into(),
+            fs_type: None,
+            options: None,
+            read_only: None,
+            secret_ref: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl FlexVolumeSource {
+    /// Returns this value with [`FlexVolumeSource::driver`] set to the given value.
+    #[must_use]
+    pub fn with_driver(mut self, value: impl Into<String>) -> Self {
+        self.driver = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`FlexVolumeSource::fs_type`] set to the given value.
+    #[must_use]
+    pub fn with_fs_type(mut self, value: impl Into<String>) -> Self {
+        self.fs_type = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`FlexVolumeSource::options`] set to the given value.
+    #[must_use]
+    pub fn with_options(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.options = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "driver" => Field::Key_driver,
-                            "fsType" => Field::Key_fs_type,
-                            "options" => Field::Key_options,
-                            "readOnly" => Field::Key_read_only,
-                            "secretRef" => Field::Key_secret_ref,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`FlexVolumeSource::read_only`] set to the given value.
+    #[must_use]
+    pub fn with_read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`FlexVolumeSource::secret_ref`] set to the given value.
+    #[must_use]
+    pub fn with_secret_ref(mut self, value: impl Into<crate::api::core::v1::LocalObjectReference>) -> Self {
+        self.secret_ref = Some(value.into());
+        self
+    }
+}
 
+impl crate::MergeStrategyInfo for FlexVolumeSource {}
+
+impl FlexVolumeSource {
+    const FIELDS: &'static [&'static str] = &[
+        "driver",
+        "fsType",
+        "options",
+        "readOnly",
+        "secretRef",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for FlexVolumeSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -74,43 +99,121 @@ impl<'de> crate::serde::Deserialize<'de> for FlexVolumeSource {
                 let mut value_read_only: Option<bool> = None;
                 let mut value_secret_ref: Option<crate::api::core::v1::LocalObjectReference> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("FlexVolumeSource", FlexVolumeSource::FIELDS))? {
                     match key {
-                        Field::Key_driver => value_driver = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_fs_type => value_fs_type = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_options => value_options = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_read_only => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_secret_ref => value_secret_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_driver = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_fs_type = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_options = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_secret_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(FlexVolumeSource {
-                    driver: value_driver.ok_or_else(|| crate::serde::de::Error::missing_field("driver"))?,
+                    driver: value_driver.ok_or_else(|| crate::serde2::missing_field("FlexVolumeSource", "driver", &["driver"]))?,
                     fs_type: value_fs_type,
                     options: value_options,
                     read_only: value_read_only,
                     secret_ref: value_secret_ref,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(FlexVolumeSource {
+                    driver: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    fs_type: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    options: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    read_only: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    secret_ref: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "FlexVolumeSource",
-            &[
-                "driver",
-                "fsType",
-                "options",
-                "readOnly",
-                "secretRef",
-            ],
+            FlexVolumeSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, FlexVolumeSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("FlexVolumeSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_driver = false;
+                let mut has_fs_type = false;
+                let mut has_options = false;
+                let mut has_read_only = false;
+                let mut has_secret_ref = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("FlexVolumeSource", FlexVolumeSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.driver))?; has_driver = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.fs_type))?; has_fs_type = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.options))?; has_options = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.read_only))?; has_read_only = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.secret_ref))?; has_secret_ref = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_driver {
+                    return Err(crate::serde2::missing_field("FlexVolumeSource", "driver", &["driver"]));
+                }
+                if !has_fs_type {
+                    self.0.fs_type = None;
+                }
+                if !has_options {
+                    self.0.options = None;
+                }
+                if !has_read_only {
+                    self.0.read_only = None;
+                }
+                if !has_secret_ref {
+                    self.0.secret_ref = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.driver))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.fs_type))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.options))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.read_only))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.secret_ref))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "FlexVolumeSource",
+            FlexVolumeSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for FlexVolumeSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("FlexVolumeSource", 5)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "driver", &self.driver)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsType", &self.fs_type)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "options", &self.options)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", &self.read_only)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretRef", &self.secret_ref)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "FlexVolumeSource",
             1 +
@@ -136,10 +239,129 @@ impl crate::serde::Serialize for FlexVolumeSource {
     }
 }
 
+/// A builder of [`FlexVolumeSource`]. Created with [`FlexVolumeSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct FlexVolumeSourceBuilder {
+    driver: Option<String>,
+    fs_type: Option<String>,
+    options: Option<std::collections::BTreeMap<String, String>>,
+    read_only: Option<bool>,
+    secret_ref: Option<crate::api::core::v1::LocalObjectReference>,
+}
+
+#[cfg(feature = "builder")]
+impl FlexVolumeSource {
+    /// Starts building a `FlexVolumeSource`. Its required fields must be set before [`FlexVolumeSourceBuilder::build`] is called.
+    pub fn builder() -> FlexVolumeSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl FlexVolumeSourceBuilder {
+    /// Sets [`FlexVolumeSource::driver`].
+    pub fn driver(mut self, value: impl Into<String>) -> Self {
+        self.driver = Some(value.into());
+        self
+    }
+
+    /// Sets [`FlexVolumeSource::fs_type`].
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`FlexVolumeSource::options`].
+    pub fn options(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.options = Some(value.into());
+        self
+    }
+
+    /// Sets [`FlexVolumeSource::read_only`].
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`FlexVolumeSource::secret_ref`].
+    pub fn secret_ref(mut self, value: impl Into<crate::api::core::v1::LocalObjectReference>) -> Self {
+        self.secret_ref = Some(value.into());
+        self
+    }
+
+    /// Builds the `FlexVolumeSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<FlexVolumeSource, crate::MissingFieldError> {
+        Ok(FlexVolumeSource {
+            driver: self.driver.ok_or_else(|| crate::MissingFieldError::new("FlexVolumeSource", "driver"))?,
+            fs_type: self.fs_type,
+            options: self.options,
+            read_only: self.read_only,
+            secret_ref: self.secret_ref,
+        })
+    }
+}
+
+/// The apply configuration of [`FlexVolumeSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FlexVolumeSourceApplyConfiguration {
+    /// See [`FlexVolumeSource::driver`].
+    pub driver: Option<String>,
+
+    /// See [`FlexVolumeSource::fs_type`].
+    pub fs_type: Option<String>,
+
+    /// See [`FlexVolumeSource::options`].
+    pub options: Option<std::collections::BTreeMap<String, String>>,
+
+    /// See [`FlexVolumeSource::read_only`].
+    pub read_only: Option<bool>,
+
+    /// See [`FlexVolumeSource::secret_ref`].
+    pub secret_ref: Option<crate::api::core::v1::LocalObjectReferenceApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for FlexVolumeSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "FlexVolumeSourceApplyConfiguration",
+            self.driver.as_ref().map_or(0, |_| 1) +
+            self.fs_type.as_ref().map_or(0, |_| 1) +
+            self.options.as_ref().map_or(0, |_| 1) +
+            self.read_only.as_ref().map_or(0, |_| 1) +
+            self.secret_ref.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.driver {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "driver", value)?;
+        }
+        if let Some(value) = &self.fs_type {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsType", value)?;
+        }
+        if let Some(value) = &self.options {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "options", value)?;
+        }
+        if let Some(value) = &self.read_only {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", value)?;
+        }
+        if let Some(value) = &self.secret_ref {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretRef", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for FlexVolumeSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.FlexVolumeSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::FlexVolumeSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.FlexVolumeSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -150,8 +372,9 @@ impl crate::schemars::JsonSchema for FlexVolumeSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "driver".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -161,8 +384,8 @@ impl crate::schemars::JsonSchema for FlexVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "fsType".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -172,8 +395,8 @@ impl crate::schemars::JsonSchema for FlexVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "options".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -192,8 +415,8 @@ impl crate::schemars::JsonSchema for FlexVolumeSource {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "readOnly".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -203,8 +426,8 @@ impl crate::schemars::JsonSchema for FlexVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "secretRef".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::LocalObjectReference>().into_object();
@@ -214,8 +437,9 @@ impl crate::schemars::JsonSchema for FlexVolumeSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "driver",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -225,3 +449,16 @@ impl crate::schemars::JsonSchema for FlexVolumeSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for FlexVolumeSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(FlexVolumeSource {
+            driver: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            fs_type: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            options: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            read_only: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            secret_ref: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.GlusterfsVolumeSource
 
 /// Represents a Glusterfs mount that lasts the lifetime of a pod. Glusterfs volumes do not support ownership management or SELinux relabeling.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct GlusterfsVolumeSource {
     /// EndpointsName is the endpoint name that details Glusterfs topology. More info: https://releases.k8s.io/HEAD/examples/volumes/glusterfs/README.md#create-a-pod
     pub endpoints: String,
@@ -13,41 +13,52 @@ pub struct GlusterfsVolumeSource {
     pub read_only: Option<bool>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for GlusterfsVolumeSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_endpoints,
-            Key_path,
-            Key_read_only,
-            Other,
+impl GlusterfsVolumeSource {
+    /// Constructs a `GlusterfsVolumeSource` from its required fields. Its optional fields are unset.
+    pub fn new(endpoints: impl Into<String>, path: impl Into<String>) -> Self {
+        GlusterfsVolumeSource {
+            endpoints: endpoints.into(),
+            path: path.into(),
+            read_only: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl GlusterfsVolumeSource {
+    /// Returns this value with [`GlusterfsVolumeSource::endpoints`] set to the given value.
+    #[must_use]
+    pub fn with_endpoints(mut self, value: impl Into<String>) -> Self {
+        self.endpoints = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`GlusterfsVolumeSource::path`] set to the given value.
+    #[must_use]
+    pub fn with_path(mut self, value: impl Into<String>) -> Self {
+        self.path = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`GlusterfsVolumeSource::read_only`] set to the given value.
+    #[must_use]
+    pub fn with_read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "endpoints" => Field::Key_endpoints,
-                            "path" => Field::Key_path,
-                            "readOnly" => Field::Key_read_only,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for GlusterfsVolumeSource {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl GlusterfsVolumeSource {
+    const FIELDS: &'static [&'static str] = &[
+        "endpoints",
+        "path",
+        "readOnly",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for GlusterfsVolumeSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,37 +73,101 @@ impl<'de> crate::serde::Deserialize<'de> for GlusterfsVolumeSource {
                 let mut value_path: Option<String> = None;
                 let mut value_read_only: Option<bool> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("GlusterfsVolumeSource", GlusterfsVolumeSource::FIELDS))? {
                     match key {
-                        Field::Key_endpoints => value_endpoints = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_path => value_path = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_read_only => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_endpoints = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_path = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(GlusterfsVolumeSource {
-                    endpoints: value_endpoints.ok_or_else(|| crate::serde::de::Error::missing_field("endpoints"))?,
-                    path: value_path.ok_or_else(|| crate::serde::de::Error::missing_field("path"))?,
+                    endpoints: value_endpoints.ok_or_else(|| crate::serde2::missing_field("GlusterfsVolumeSource", "endpoints", &["endpoints", "path"]))?,
+                    path: value_path.ok_or_else(|| crate::serde2::missing_field("GlusterfsVolumeSource", "path", &["endpoints", "path"]))?,
                     read_only: value_read_only,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(GlusterfsVolumeSource {
+                    endpoints: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    path: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    read_only: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "GlusterfsVolumeSource",
-            &[
-                "endpoints",
-                "path",
-                "readOnly",
-            ],
+            GlusterfsVolumeSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, GlusterfsVolumeSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("GlusterfsVolumeSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_endpoints = false;
+                let mut has_path = false;
+                let mut has_read_only = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("GlusterfsVolumeSource", GlusterfsVolumeSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.endpoints))?; has_endpoints = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.path))?; has_path = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.read_only))?; has_read_only = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_endpoints {
+                    return Err(crate::serde2::missing_field("GlusterfsVolumeSource", "endpoints", &["endpoints", "path"]));
+                }
+                if !has_path {
+                    return Err(crate::serde2::missing_field("GlusterfsVolumeSource", "path", &["endpoints", "path"]));
+                }
+                if !has_read_only {
+                    self.0.read_only = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.endpoints))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.path))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.read_only))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "GlusterfsVolumeSource",
+            GlusterfsVolumeSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for GlusterfsVolumeSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("GlusterfsVolumeSource", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "endpoints", &self.endpoints)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "path", &self.path)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", &self.read_only)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "GlusterfsVolumeSource",
             2 +
@@ -107,10 +182,99 @@ impl crate::serde::Serialize for GlusterfsVolumeSource {
     }
 }
 
+/// A builder of [`GlusterfsVolumeSource`]. Created with [`GlusterfsVolumeSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct GlusterfsVolumeSourceBuilder {
+    endpoints: Option<String>,
+    path: Option<String>,
+    read_only: Option<bool>,
+}
+
+#[cfg(feature = "builder")]
+impl GlusterfsVolumeSource {
+    /// Starts building a `GlusterfsVolumeSource`. Its required fields must be set before [`GlusterfsVolumeSourceBuilder::build`] is called.
+    pub fn builder() -> GlusterfsVolumeSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl GlusterfsVolumeSourceBuilder {
+    /// Sets [`GlusterfsVolumeSource::endpoints`].
+    pub fn endpoints(mut self, value: impl Into<String>) -> Self {
+        self.endpoints = Some(value.into());
+        self
+    }
+
+    /// Sets [`GlusterfsVolumeSource::path`].
+    pub fn path(mut self, value: impl Into<String>) -> Self {
+        self.path = Some(value.into());
+        self
+    }
+
+    /// Sets [`GlusterfsVolumeSource::read_only`].
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+
+    /// Builds the `GlusterfsVolumeSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<GlusterfsVolumeSource, crate::MissingFieldError> {
+        Ok(GlusterfsVolumeSource {
+            endpoints: self.endpoints.ok_or_else(|| crate::MissingFieldError::new("GlusterfsVolumeSource", "endpoints"))?,
+            path: self.path.ok_or_else(|| crate::MissingFieldError::new("GlusterfsVolumeSource", "path"))?,
+            read_only: self.read_only,
+        })
+    }
+}
+
+/// The apply configuration of [`GlusterfsVolumeSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GlusterfsVolumeSourceApplyConfiguration {
+    /// See [`GlusterfsVolumeSource::endpoints`].
+    pub endpoints: Option<String>,
+
+    /// See [`GlusterfsVolumeSource::path`].
+    pub path: Option<String>,
+
+    /// See [`GlusterfsVolumeSource::read_only`].
+    pub read_only: Option<bool>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for GlusterfsVolumeSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "GlusterfsVolumeSourceApplyConfiguration",
+            self.endpoints.as_ref().map_or(0, |_| 1) +
+            self.path.as_ref().map_or(0, |_| 1) +
+            self.read_only.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.endpoints {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "endpoints", value)?;
+        }
+        if let Some(value) = &self.path {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "path", value)?;
+        }
+        if let Some(value) = &self.read_only {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for GlusterfsVolumeSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.GlusterfsVolumeSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::GlusterfsVolumeSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.GlusterfsVolumeSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -121,8 +285,9 @@ impl crate::schemars::JsonSchema for GlusterfsVolumeSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "endpoints".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -132,8 +297,8 @@ impl crate::schemars::JsonSchema for GlusterfsVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "path".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -143,8 +308,8 @@ impl crate::schemars::JsonSchema for GlusterfsVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "readOnly".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -154,8 +319,9 @@ impl crate::schemars::JsonSchema for GlusterfsVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "endpoints",
                     "path",
@@ -166,3 +332,14 @@ impl crate::schemars::JsonSchema for GlusterfsVolumeSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for GlusterfsVolumeSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(GlusterfsVolumeSource {
+            endpoints: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            path: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            read_only: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.Handler
 
 /// Handler defines a specific action that should be taken
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct Handler {
     /// One and only one of the following should be specified. Exec specifies the action to take.
     pub exec: Option<crate::api::core::v1::ExecAction>,
@@ -13,41 +13,41 @@ pub struct Handler {
     pub tcp_socket: Option<crate::api::core::v1::TCPSocketAction>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for Handler {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_exec,
-            Key_http_get,
-            Key_tcp_socket,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl Handler {
+    /// Returns this value with [`Handler::exec`] set to the given value.
+    #[must_use]
+    pub fn with_exec(mut self, value: impl Into<crate::api::core::v1::ExecAction>) -> Self {
+        self.exec = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`Handler::http_get`] set to the given value.
+    #[must_use]
+    pub fn with_http_get(mut self, value: impl Into<crate::api::core::v1::HTTPGetAction>) -> Self {
+        self.http_get = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`Handler::tcp_socket`] set to the given value.
+    #[must_use]
+    pub fn with_tcp_socket(mut self, value: impl Into<crate::api::core::v1::TCPSocketAction>) -> Self {
+        self.tcp_socket = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "exec" => Field::Key_exec,
-                            "httpGet" => Field::Key_http_get,
-                            "tcpSocket" => Field::Key_tcp_socket,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for Handler {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl Handler {
+    const FIELDS: &'static [&'static str] = &[
+        "exec",
+        "httpGet",
+        "tcpSocket",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for Handler {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,12 +62,12 @@ impl<'de> crate::serde::Deserialize<'de> for Handler {
                 let mut value_http_get: Option<crate::api::core::v1::HTTPGetAction> = None;
                 let mut value_tcp_socket: Option<crate::api::core::v1::TCPSocketAction> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Handler", Handler::FIELDS))? {
                     match key {
-                        Field::Key_exec => value_exec = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_http_get => value_http_get = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_tcp_socket => value_tcp_socket = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_exec = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_http_get = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_tcp_socket = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -77,22 +77,86 @@ impl<'de> crate::serde::Deserialize<'de> for Handler {
                     tcp_socket: value_tcp_socket,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(Handler {
+                    exec: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    http_get: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    tcp_socket: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "Handler",
-            &[
-                "exec",
-                "httpGet",
-                "tcpSocket",
-            ],
+            Handler::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, Handler> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("Handler")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_exec = false;
+                let mut has_http_get = false;
+                let mut has_tcp_socket = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Handler", Handler::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.exec))?; has_exec = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.http_get))?; has_http_get = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.tcp_socket))?; has_tcp_socket = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_exec {
+                    self.0.exec = None;
+                }
+                if !has_http_get {
+                    self.0.http_get = None;
+                }
+                if !has_tcp_socket {
+                    self.0.tcp_socket = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.exec))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.http_get))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.tcp_socket))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "Handler",
+            Handler::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for Handler {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("Handler", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "exec", &self.exec)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "httpGet", &self.http_get)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tcpSocket", &self.tcp_socket)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "Handler",
             self.exec.as_ref().map_or(0, |_| 1) +
@@ -112,10 +176,99 @@ impl crate::serde::Serialize for Handler {
     }
 }
 
+/// A builder of [`Handler`]. Created with [`Handler::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct HandlerBuilder {
+    exec: Option<crate::api::core::v1::ExecAction>,
+    http_get: Option<crate::api::core::v1::HTTPGetAction>,
+    tcp_socket: Option<crate::api::core::v1::TCPSocketAction>,
+}
+
+#[cfg(feature = "builder")]
+impl Handler {
+    /// Starts building a `Handler`. Its required fields must be set before [`HandlerBuilder::build`] is called.
+    pub fn builder() -> HandlerBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl HandlerBuilder {
+    /// Sets [`Handler::exec`].
+    pub fn exec(mut self, value: impl Into<crate::api::core::v1::ExecAction>) -> Self {
+        self.exec = Some(value.into());
+        self
+    }
+
+    /// Sets [`Handler::http_get`].
+    pub fn http_get(mut self, value: impl Into<crate::api::core::v1::HTTPGetAction>) -> Self {
+        self.http_get = Some(value.into());
+        self
+    }
+
+    /// Sets [`Handler::tcp_socket`].
+    pub fn tcp_socket(mut self, value: impl Into<crate::api::core::v1::TCPSocketAction>) -> Self {
+        self.tcp_socket = Some(value.into());
+        self
+    }
+
+    /// Builds the `Handler`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<Handler, crate::MissingFieldError> {
+        Ok(Handler {
+            exec: self.exec,
+            http_get: self.http_get,
+            tcp_socket: self.tcp_socket,
+        })
+    }
+}
+
+/// The apply configuration of [`Handler`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HandlerApplyConfiguration {
+    /// See [`Handler::exec`].
+    pub exec: Option<crate::api::core::v1::ExecActionApplyConfiguration>,
+
+    /// See [`Handler::http_get`].
+    pub http_get: Option<crate::api::core::v1::HTTPGetActionApplyConfiguration>,
+
+    /// See [`Handler::tcp_socket`].
+    pub tcp_socket: Option<crate::api::core::v1::TCPSocketActionApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for HandlerApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "HandlerApplyConfiguration",
+            self.exec.as_ref().map_or(0, |_| 1) +
+            self.http_get.as_ref().map_or(0, |_| 1) +
+            self.tcp_socket.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.exec {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "exec", value)?;
+        }
+        if let Some(value) = &self.http_get {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "httpGet", value)?;
+        }
+        if let Some(value) = &self.tcp_socket {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tcpSocket", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Handler {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.Handler".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::Handler".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.Handler".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -126,8 +279,9 @@ impl crate::schemars::JsonSchema for Handler {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "exec".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ExecAction>().into_object();
@@ -137,8 +291,8 @@ impl crate::schemars::JsonSchema for Handler {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "httpGet".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::HTTPGetAction>().into_object();
@@ -148,8 +302,8 @@ impl crate::schemars::JsonSchema for Handler {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "tcpSocket".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::TCPSocketAction>().into_object();
@@ -159,11 +313,58 @@ impl crate::schemars::JsonSchema for Handler {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for Handler {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(Handler {
+            exec: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            http_get: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            tcp_socket: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
+
+/// The property of a [`Handler`] that is set, out of the properties that are mutually exclusive. Returned by [`Handler::action`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HandlerAction<'a> {
+    /// [`Handler::exec`]
+    Exec(&'a crate::api::core::v1::ExecAction),
+
+    /// [`Handler::http_get`]
+    HttpGet(&'a crate::api::core::v1::HTTPGetAction),
+
+    /// [`Handler::tcp_socket`]
+    TcpSocket(&'a crate::api::core::v1::TCPSocketAction),
+}
+
+impl Handler {
+    /// Returns the property of this `Handler` that is set, out of the properties that are mutually exclusive.
+    ///
+    /// Returns `None` if none of them is set. If more than one of them is set, which the API server rejects,
+    /// the one that comes first in the order of the variants of [`HandlerAction`] is returned.
+    pub fn action(&self) -> Option<HandlerAction<'_>> {
+        if let Some(value) = &self.exec {
+            return Some(HandlerAction::Exec(value));
+        }
+
+        if let Some(value) = &self.http_get {
+            return Some(HandlerAction::HttpGet(value));
+        }
+
+        if let Some(value) = &self.tcp_socket {
+            return Some(HandlerAction::TcpSocket(value));
+        }
+
+        None
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.HostAlias
 
 /// HostAlias holds the mapping between IP and hostnames that will be injected as an entry in the pod's hosts file.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct HostAlias {
     /// Hostnames for the above IP address.
     pub hostnames: Option<Vec<String>>,
@@ -10,39 +10,33 @@ pub struct HostAlias {
     pub ip: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for HostAlias {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_hostnames,
-            Key_ip,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl HostAlias {
+    /// Returns this value with [`HostAlias::hostnames`] set to the given value.
+    #[must_use]
+    pub fn with_hostnames(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.hostnames = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`HostAlias::ip`] set to the given value.
+    #[must_use]
+    pub fn with_ip(mut self, value: impl Into<String>) -> Self {
+        self.ip = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "hostnames" => Field::Key_hostnames,
-                            "ip" => Field::Key_ip,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for HostAlias {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl HostAlias {
+    const FIELDS: &'static [&'static str] = &[
+        "hostnames",
+        "ip",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for HostAlias {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,11 +50,11 @@ impl<'de> crate::serde::Deserialize<'de> for HostAlias {
                 let mut value_hostnames: Option<Vec<String>> = None;
                 let mut value_ip: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("HostAlias", HostAlias::FIELDS))? {
                     match key {
-                        Field::Key_hostnames => value_hostnames = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_ip => value_ip = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_hostnames = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_ip = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -69,21 +63,78 @@ impl<'de> crate::serde::Deserialize<'de> for HostAlias {
                     ip: value_ip,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(HostAlias {
+                    hostnames: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    ip: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "HostAlias",
-            &[
-                "hostnames",
-                "ip",
-            ],
+            HostAlias::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, HostAlias> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("HostAlias")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_hostnames = false;
+                let mut has_ip = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("HostAlias", HostAlias::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.hostnames))?; has_hostnames = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.ip))?; has_ip = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_hostnames {
+                    self.0.hostnames = None;
+                }
+                if !has_ip {
+                    self.0.ip = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.hostnames))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.ip))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "HostAlias",
+            HostAlias::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for HostAlias {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("HostAlias", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostnames", &self.hostnames)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ip", &self.ip)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "HostAlias",
             self.hostnames.as_ref().map_or(0, |_| 1) +
@@ -99,10 +150,84 @@ impl crate::serde::Serialize for HostAlias {
     }
 }
 
+/// A builder of [`HostAlias`]. Created with [`HostAlias::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct HostAliasBuilder {
+    hostnames: Option<Vec<String>>,
+    ip: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl HostAlias {
+    /// Starts building a `HostAlias`. Its required fields must be set before [`HostAliasBuilder::build`] is called.
+    pub fn builder() -> HostAliasBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl HostAliasBuilder {
+    /// Sets [`HostAlias::hostnames`].
+    pub fn hostnames(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.hostnames = Some(value.into());
+        self
+    }
+
+    /// Sets [`HostAlias::ip`].
+    pub fn ip(mut self, value: impl Into<String>) -> Self {
+        self.ip = Some(value.into());
+        self
+    }
+
+    /// Builds the `HostAlias`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<HostAlias, crate::MissingFieldError> {
+        Ok(HostAlias {
+            hostnames: self.hostnames,
+            ip: self.ip,
+        })
+    }
+}
+
+/// The apply configuration of [`HostAlias`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HostAliasApplyConfiguration {
+    /// See [`HostAlias::hostnames`].
+    pub hostnames: Option<Vec<String>>,
+
+    /// See [`HostAlias::ip`].
+    pub ip: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for HostAliasApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "HostAliasApplyConfiguration",
+            self.hostnames.as_ref().map_or(0, |_| 1) +
+            self.ip.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.hostnames {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostnames", value)?;
+        }
+        if let Some(value) = &self.ip {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ip", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for HostAlias {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.HostAlias".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::HostAlias".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.HostAlias".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -113,8 +238,9 @@ impl crate::schemars::JsonSchema for HostAlias {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "hostnames".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -133,8 +259,8 @@ impl crate::schemars::JsonSchema for HostAlias {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "ip".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -144,11 +270,22 @@ impl crate::schemars::JsonSchema for HostAlias {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for HostAlias {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(HostAlias {
+            hostnames: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            ip: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.ISCSIVolumeSource
 
 /// Represents an ISCSI disk. ISCSI volumes can only be mounted as read/write once. ISCSI volumes support ownership management and SELinux relabeling.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ISCSIVolumeSource {
     /// whether support iSCSI Discovery CHAP authentication
     pub chap_auth_discovery: Option<bool>,
@@ -37,57 +37,124 @@ pub struct ISCSIVolumeSource {
     pub target_portal: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ISCSIVolumeSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_chap_auth_discovery,
-            Key_chap_auth_session,
-            Key_fs_type,
-            Key_initiator_name,
-            Key_iqn,
-            Key_iscsi_interface,
-            Key_lun,
-            Key_portals,
-            Key_read_only,
-            Key_secret_ref,
-            Key_target_portal,
-            Other,
+impl ISCSIVolumeSource {
+    /// Constructs a `ISCSIVolumeSource` from its required fields. Its optional fields are unset.
+    pub fn new(iqn: impl Into<String>, lun: impl Into<i32>, target_portal: impl Into<String>) -> Self {
+        ISCSIVolumeSource {
+            chap_auth_discovery: None,
+            chap_auth_session: None,
+            fs_type: None,
+            initiator_name: None,
+            iqn: iqn.into(),
+            iscsi_interface: None,
+            lun: lun.into(),
+            portals: None,
+            read_only: None,
+            secret_ref: None,
+            target_portal: target_portal.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl ISCSIVolumeSource {
+    /// Returns this value with [`ISCSIVolumeSource::chap_auth_discovery`] set to the given value.
+    #[must_use]
+    pub fn with_chap_auth_discovery(mut self, value: impl Into<bool>) -> Self {
+        self.chap_auth_discovery = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ISCSIVolumeSource::chap_auth_session`] set to the given value.
+    #[must_use]
+    pub fn with_chap_auth_session(mut self, value: impl Into<bool>) -> Self {
+        self.chap_auth_session = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ISCSIVolumeSource::fs_type`] set to the given value.
+    #[must_use]
+    pub fn with_fs_type(mut self, value: impl Into<String>) -> Self {
+        self.fs_type = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "chapAuthDiscovery" => Field::Key_chap_auth_discovery,
-                            "chapAuthSession" => Field::Key_chap_auth_session,
-                            "fsType" => Field::Key_fs_type,
-                            "initiatorName" => Field::Key_initiator_name,
-                            "iqn" => Field::Key_iqn,
-                            "iscsiInterface" => Field::Key_iscsi_interface,
-                            "lun" => Field::Key_lun,
-                            "portals" => Field::Key_portals,
-                            "readOnly" => Field::Key_read_only,
-                            "secretRef" => Field::Key_secret_ref,
-                            "targetPortal" => Field::Key_target_portal,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`ISCSIVolumeSource::initiator_name`] set to the given value.
+    #[must_use]
+    pub fn with_initiator_name(mut self, value: impl Into<String>) -> Self {
+        self.initiator_name = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`ISCSIVolumeSource::iqn`] set to the given value.
+    #[must_use]
+    pub fn with_iqn(mut self, value: impl Into<String>) -> Self {
+        self.iqn = value.into();
+        self
+    }
+
+    /// Returns this value with [`ISCSIVolumeSource::iscsi_interface`] set to the given value.
+    #[must_use]
+    pub fn with_iscsi_interface(mut self, value: impl Into<String>) -> Self {
+        self.iscsi_interface = Some(value.into());
+        self
+    }
 
+    /// Returns this value with [`ISCSIVolumeSource::lun`] set to the given value.
+    #[must_use]
+    pub fn with_lun(mut self, value: impl Into<i32>) -> Self {
+        self.lun = value.into();
+        self
+    }
+
+    /// Returns this value with [`ISCSIVolumeSource::portals`] set to the given value.
+    #[must_use]
+    pub fn with_portals(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.portals = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ISCSIVolumeSource::read_only`] set to the given value.
+    #[must_use]
+    pub fn with_read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ISCSIVolumeSource::secret_ref`] set to the given value.
+    #[must_use]
+    pub fn with_secret_ref(mut self, value: impl Into<crate::api::core::v1::LocalObjectReference>) -> Self {
+        self.secret_ref = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ISCSIVolumeSource::target_portal`] set to the given value.
+    #[must_use]
+    pub fn with_target_portal(mut self, value: impl Into<String>) -> Self {
+        self.target_portal = value.into();
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for ISCSIVolumeSource {}
+
+impl ISCSIVolumeSource {
+    const FIELDS: &'static [&'static str] = &[
+        "chapAuthDiscovery",
+        "chapAuthSession",
+        "fsType",
+        "initiatorName",
+        "iqn",
+        "iscsiInterface",
+        "lun",
+        "portals",
+        "readOnly",
+        "secretRef",
+        "targetPortal",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for ISCSIVolumeSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -110,20 +177,20 @@ impl<'de> crate::serde::Deserialize<'de> for ISCSIVolumeSource {
                 let mut value_secret_ref: Option<crate::api::core::v1::LocalObjectReference> = None;
                 let mut value_target_portal: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ISCSIVolumeSource", ISCSIVolumeSource::FIELDS))? {
                     match key {
-                        Field::Key_chap_auth_discovery => value_chap_auth_discovery = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_chap_auth_session => value_chap_auth_session = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_fs_type => value_fs_type = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_initiator_name => value_initiator_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_iqn => value_iqn = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_iscsi_interface => value_iscsi_interface = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_lun => value_lun = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_portals => value_portals = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_read_only => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_secret_ref => value_secret_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_target_portal => value_target_portal = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_chap_auth_discovery = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_chap_auth_session = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_fs_type = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_initiator_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_iqn = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(5) => value_iscsi_interface = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_lun = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(7) => value_portals = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(8) => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(9) => value_secret_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(10) => value_target_portal = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -132,39 +199,159 @@ impl<'de> crate::serde::Deserialize<'de> for ISCSIVolumeSource {
                     chap_auth_session: value_chap_auth_session,
                     fs_type: value_fs_type,
                     initiator_name: value_initiator_name,
-                    iqn: value_iqn.ok_or_else(|| crate::serde::de::Error::missing_field("iqn"))?,
+                    iqn: value_iqn.ok_or_else(|| crate::serde2::missing_field("ISCSIVolumeSource", "iqn", &["iqn", "lun", "targetPortal"]))?,
                     iscsi_interface: value_iscsi_interface,
-                    lun: value_lun.ok_or_else(|| crate::serde::de::Error::missing_field("lun"))?,
+                    lun: value_lun.ok_or_else(|| crate::serde2::missing_field("ISCSIVolumeSource", "lun", &["iqn", "lun", "targetPortal"]))?,
                     portals: value_portals,
                     read_only: value_read_only,
                     secret_ref: value_secret_ref,
-                    target_portal: value_target_portal.ok_or_else(|| crate::serde::de::Error::missing_field("targetPortal"))?,
+                    target_portal: value_target_portal.ok_or_else(|| crate::serde2::missing_field("ISCSIVolumeSource", "targetPortal", &["iqn", "lun", "targetPortal"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ISCSIVolumeSource {
+                    chap_auth_discovery: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    chap_auth_session: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    fs_type: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    initiator_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    iqn: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    iscsi_interface: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    lun: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    portals: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                    read_only: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?,
+                    secret_ref: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?,
+                    target_portal: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "ISCSIVolumeSource",
-            &[
-                "chapAuthDiscovery",
-                "chapAuthSession",
-                "fsType",
-                "initiatorName",
-                "iqn",
-                "iscsiInterface",
-                "lun",
-                "portals",
-                "readOnly",
-                "secretRef",
-                "targetPortal",
-            ],
+            ISCSIVolumeSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ISCSIVolumeSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ISCSIVolumeSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_chap_auth_discovery = false;
+                let mut has_chap_auth_session = false;
+                let mut has_fs_type = false;
+                let mut has_initiator_name = false;
+                let mut has_iqn = false;
+                let mut has_iscsi_interface = false;
+                let mut has_lun = false;
+                let mut has_portals = false;
+                let mut has_read_only = false;
+                let mut has_secret_ref = false;
+                let mut has_target_portal = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ISCSIVolumeSource", ISCSIVolumeSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.chap_auth_discovery))?; has_chap_auth_discovery = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.chap_auth_session))?; has_chap_auth_session = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.fs_type))?; has_fs_type = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.initiator_name))?; has_initiator_name = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.iqn))?; has_iqn = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.iscsi_interface))?; has_iscsi_interface = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.lun))?; has_lun = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.portals))?; has_portals = true; },
+                        Some(8) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.read_only))?; has_read_only = true; },
+                        Some(9) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.secret_ref))?; has_secret_ref = true; },
+                        Some(10) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.target_portal))?; has_target_portal = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_chap_auth_discovery {
+                    self.0.chap_auth_discovery = None;
+                }
+                if !has_chap_auth_session {
+                    self.0.chap_auth_session = None;
+                }
+                if !has_fs_type {
+                    self.0.fs_type = None;
+                }
+                if !has_initiator_name {
+                    self.0.initiator_name = None;
+                }
+                if !has_iqn {
+                    return Err(crate::serde2::missing_field("ISCSIVolumeSource", "iqn", &["iqn", "lun", "targetPortal"]));
+                }
+                if !has_iscsi_interface {
+                    self.0.iscsi_interface = None;
+                }
+                if !has_lun {
+                    return Err(crate::serde2::missing_field("ISCSIVolumeSource", "lun", &["iqn", "lun", "targetPortal"]));
+                }
+                if !has_portals {
+                    self.0.portals = None;
+                }
+                if !has_read_only {
+                    self.0.read_only = None;
+                }
+                if !has_secret_ref {
+                    self.0.secret_ref = None;
+                }
+                if !has_target_portal {
+                    return Err(crate::serde2::missing_field("ISCSIVolumeSource", "targetPortal", &["iqn", "lun", "targetPortal"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.chap_auth_discovery))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.chap_auth_session))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.fs_type))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.initiator_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.iqn))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.iscsi_interface))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.lun))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.portals))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.read_only))?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.secret_ref))?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.target_portal))?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ISCSIVolumeSource",
+            ISCSIVolumeSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ISCSIVolumeSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ISCSIVolumeSource", 11)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "chapAuthDiscovery", &self.chap_auth_discovery)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "chapAuthSession", &self.chap_auth_session)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsType", &self.fs_type)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "initiatorName", &self.initiator_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "iqn", &self.iqn)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "iscsiInterface", &self.iscsi_interface)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "lun", &self.lun)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "portals", &self.portals)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", &self.read_only)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretRef", &self.secret_ref)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "targetPortal", &self.target_portal)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ISCSIVolumeSource",
             3 +
@@ -208,10 +395,219 @@ impl crate::serde::Serialize for ISCSIVolumeSource {
     }
 }
 
+/// A builder of [`ISCSIVolumeSource`]. Created with [`ISCSIVolumeSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ISCSIVolumeSourceBuilder {
+    chap_auth_discovery: Option<bool>,
+    chap_auth_session: Option<bool>,
+    fs_type: Option<String>,
+    initiator_name: Option<String>,
+    iqn: Option<String>,
+    iscsi_interface: Option<String>,
+    lun: Option<i32>,
+    portals: Option<Vec<String>>,
+    read_only: Option<bool>,
+    secret_ref: Option<crate::api::core::v1::LocalObjectReference>,
+    target_portal: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl ISCSIVolumeSource {
+    /// Starts building a `ISCSIVolumeSource`. Its required fields must be set before [`ISCSIVolumeSourceBuilder::build`] is called.
+    pub fn builder() -> ISCSIVolumeSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ISCSIVolumeSourceBuilder {
+    /// Sets [`ISCSIVolumeSource::chap_auth_discovery`].
+    pub fn chap_auth_discovery(mut self, value: impl Into<bool>) -> Self {
+        self.chap_auth_discovery = Some(value.into());
+        self
+    }
+
+    /// Sets [`ISCSIVolumeSource::chap_auth_session`].
+    pub fn chap_auth_session(mut self, value: impl Into<bool>) -> Self {
+        self.chap_auth_session = Some(value.into());
+        self
+    }
+
+    /// Sets [`ISCSIVolumeSource::fs_type`].
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`ISCSIVolumeSource::initiator_name`].
+    pub fn initiator_name(mut self, value: impl Into<String>) -> Self {
+        self.initiator_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`ISCSIVolumeSource::iqn`].
+    pub fn iqn(mut self, value: impl Into<String>) -> Self {
+        self.iqn = Some(value.into());
+        self
+    }
+
+    /// Sets [`ISCSIVolumeSource::iscsi_interface`].
+    pub fn iscsi_interface(mut self, value: impl Into<String>) -> Self {
+        self.iscsi_interface = Some(value.into());
+        self
+    }
+
+    /// Sets [`ISCSIVolumeSource::lun`].
+    pub fn lun(mut self, value: impl Into<i32>) -> Self {
+        self.lun = Some(value.into());
+        self
+    }
+
+    /// Sets [`ISCSIVolumeSource::portals`].
+    pub fn portals(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.portals = Some(value.into());
+        self
+    }
+
+    /// Sets [`ISCSIVolumeSource::read_only`].
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`ISCSIVolumeSource::secret_ref`].
+    pub fn secret_ref(mut self, value: impl Into<crate::api::core::v1::LocalObjectReference>) -> Self {
+        self.secret_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`ISCSIVolumeSource::target_portal`].
+    pub fn target_portal(mut self, value: impl Into<String>) -> Self {
+        self.target_portal = Some(value.into());
+        self
+    }
+
+    /// Builds the `ISCSIVolumeSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ISCSIVolumeSource, crate::MissingFieldError> {
+        Ok(ISCSIVolumeSource {
+            chap_auth_discovery: self.chap_auth_discovery,
+            chap_auth_session: self.chap_auth_session,
+            fs_type: self.fs_type,
+            initiator_name: self.initiator_name,
+            iqn: self.iqn.ok_or_else(|| crate::MissingFieldError::new("ISCSIVolumeSource", "iqn"))?,
+            iscsi_interface: self.iscsi_interface,
+            lun: self.lun.ok_or_else(|| crate::MissingFieldError::new("ISCSIVolumeSource", "lun"))?,
+            portals: self.portals,
+            read_only: self.read_only,
+            secret_ref: self.secret_ref,
+            target_portal: self.target_portal.ok_or_else(|| crate::MissingFieldError::new("ISCSIVolumeSource", "targetPortal"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`ISCSIVolumeSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ISCSIVolumeSourceApplyConfiguration {
+    /// See [`ISCSIVolumeSource::chap_auth_discovery`].
+    pub chap_auth_discovery: Option<bool>,
+
+    /// See [`ISCSIVolumeSource::chap_auth_session`].
+    pub chap_auth_session: Option<bool>,
+
+    /// See [`ISCSIVolumeSource::fs_type`].
+    pub fs_type: Option<String>,
+
+    /// See [`ISCSIVolumeSource::initiator_name`].
+    pub initiator_name: Option<String>,
+
+    /// See [`ISCSIVolumeSource::iqn`].
+    pub iqn: Option<String>,
+
+    /// See [`ISCSIVolumeSource::iscsi_interface`].
+    pub iscsi_interface: Option<String>,
+
+    /// See [`ISCSIVolumeSource::lun`].
+    pub lun: Option<i32>,
+
+    /// See [`ISCSIVolumeSource::portals`].
+    pub portals: Option<Vec<String>>,
+
+    /// See [`ISCSIVolumeSource::read_only`].
+    pub read_only: Option<bool>,
+
+    /// See [`ISCSIVolumeSource::secret_ref`].
+    pub secret_ref: Option<crate::api::core::v1::LocalObjectReferenceApplyConfiguration>,
+
+    /// See [`ISCSIVolumeSource::target_portal`].
+    pub target_portal: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ISCSIVolumeSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ISCSIVolumeSourceApplyConfiguration",
+            self.chap_auth_discovery.as_ref().map_or(0, |_| 1) +
+            self.chap_auth_session.as_ref().map_or(0, |_| 1) +
+            self.fs_type.as_ref().map_or(0, |_| 1) +
+            self.initiator_name.as_ref().map_or(0, |_| 1) +
+            self.iqn.as_ref().map_or(0, |_| 1) +
+            self.iscsi_interface.as_ref().map_or(0, |_| 1) +
+            self.lun.as_ref().map_or(0, |_| 1) +
+            self.portals.as_ref().map_or(0, |_| 1) +
+            self.read_only.as_ref().map_or(0, |_| 1) +
+            self.secret_ref.as_ref().map_or(0, |_| 1) +
+            self.target_portal.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.chap_auth_discovery {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "chapAuthDiscovery", value)?;
+        }
+        if let Some(value) = &self.chap_auth_session {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "chapAuthSession", value)?;
+        }
+        if let Some(value) = &self.fs_type {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsType", value)?;
+        }
+        if let Some(value) = &self.initiator_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "initiatorName", value)?;
+        }
+        if let Some(value) = &self.iqn {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "iqn", value)?;
+        }
+        if let Some(value) = &self.iscsi_interface {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "iscsiInterface", value)?;
+        }
+        if let Some(value) = &self.lun {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "lun", value)?;
+        }
+        if let Some(value) = &self.portals {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "portals", value)?;
+        }
+        if let Some(value) = &self.read_only {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", value)?;
+        }
+        if let Some(value) = &self.secret_ref {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretRef", value)?;
+        }
+        if let Some(value) = &self.target_portal {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "targetPortal", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ISCSIVolumeSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.ISCSIVolumeSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::ISCSIVolumeSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.ISCSIVolumeSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -222,8 +618,9 @@ impl crate::schemars::JsonSchema for ISCSIVolumeSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "chapAuthDiscovery".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -233,8 +630,8 @@ impl crate::schemars::JsonSchema for ISCSIVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "chapAuthSession".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -244,8 +641,8 @@ impl crate::schemars::JsonSchema for ISCSIVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "fsType".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -255,8 +652,8 @@ impl crate::schemars::JsonSchema for ISCSIVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "initiatorName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -266,8 +663,8 @@ impl crate::schemars::JsonSchema for ISCSIVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "iqn".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -277,8 +674,8 @@ impl crate::schemars::JsonSchema for ISCSIVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "iscsiInterface".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -288,8 +685,8 @@ impl crate::schemars::JsonSchema for ISCSIVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "lun".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -300,8 +697,8 @@ impl crate::schemars::JsonSchema for ISCSIVolumeSource {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "portals".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -320,8 +717,8 @@ impl crate::schemars::JsonSchema for ISCSIVolumeSource {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "readOnly".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -331,8 +728,8 @@ impl crate::schemars::JsonSchema for ISCSIVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "secretRef".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::LocalObjectReference>().into_object();
@@ -342,8 +739,8 @@ impl crate::schemars::JsonSchema for ISCSIVolumeSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "targetPortal".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -353,8 +750,9 @@ impl crate::schemars::JsonSchema for ISCSIVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "iqn",
                     "lun",
@@ -366,3 +764,22 @@ impl crate::schemars::JsonSchema for ISCSIVolumeSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ISCSIVolumeSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ISCSIVolumeSource {
+            chap_auth_discovery: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            chap_auth_session: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            fs_type: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            initiator_name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            iqn: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            iscsi_interface: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            lun: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            portals: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            read_only: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            secret_ref: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            target_portal: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
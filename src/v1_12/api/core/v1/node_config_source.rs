@@ -1,80 +1,118 @@
 // Generated from definition io.k8s.api.core.v1.NodeConfigSource
 
 /// NodeConfigSource specifies a source of node configuration. Exactly one subfield (excluding metadata) must be non-nil.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct NodeConfigSource {
     /// ConfigMap is a reference to a Node's ConfigMap
     pub config_map: Option<crate::api::core::v1::ConfigMapNodeConfigSource>,
 }
 
+impl NodeConfigSource {
+    /// Returns this value with [`NodeConfigSource::config_map`] set to the given value.
+    #[must_use]
+    pub fn with_config_map(mut self, value: impl Into<crate::api::core::v1::ConfigMapNodeConfigSource>) -> Self {
+        self.config_map = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for NodeConfigSource {}
+
+impl NodeConfigSource {
+    const FIELDS: &'static [&'static str] = &[
+        "configMap",
+    ];
+}
+
 impl<'de> crate::serde::Deserialize<'de> for NodeConfigSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_config_map,
-            Other,
-        }
+        struct Visitor;
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = NodeConfigSource;
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("NodeConfigSource")
+            }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_config_map: Option<crate::api::core::v1::ConfigMapNodeConfigSource> = None;
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "configMap" => Field::Key_config_map,
-                            _ => Field::Other,
-                        })
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NodeConfigSource", NodeConfigSource::FIELDS))? {
+                    match key {
+                        Some(0) => value_config_map = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
-                deserializer.deserialize_identifier(Visitor)
+                Ok(NodeConfigSource {
+                    config_map: value_config_map,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(NodeConfigSource {
+                    config_map: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                })
             }
         }
 
-        struct Visitor;
+        deserializer.deserialize_struct(
+            "NodeConfigSource",
+            NodeConfigSource::FIELDS,
+            Visitor,
+        )
+    }
 
-        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-            type Value = NodeConfigSource;
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, NodeConfigSource> {
+            type Value = ();
 
             fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 f.write_str("NodeConfigSource")
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
-                let mut value_config_map: Option<crate::api::core::v1::ConfigMapNodeConfigSource> = None;
+                let mut has_config_map = false;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NodeConfigSource", NodeConfigSource::FIELDS))? {
                     match key {
-                        Field::Key_config_map => value_config_map = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.config_map))?; has_config_map = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
+                if !has_config_map {
+                    self.0.config_map = None;
+                }
 
-                Ok(NodeConfigSource {
-                    config_map: value_config_map,
-                })
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.config_map))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
             }
         }
 
         deserializer.deserialize_struct(
             "NodeConfigSource",
-            &[
-                "configMap",
-            ],
-            Visitor,
+            NodeConfigSource::FIELDS,
+            InPlaceVisitor(place),
         )
     }
 }
 
 impl crate::serde::Serialize for NodeConfigSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("NodeConfigSource", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "configMap", &self.config_map)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "NodeConfigSource",
             self.config_map.as_ref().map_or(0, |_| 1),
@@ -86,10 +124,69 @@ impl crate::serde::Serialize for NodeConfigSource {
     }
 }
 
+/// A builder of [`NodeConfigSource`]. Created with [`NodeConfigSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct NodeConfigSourceBuilder {
+    config_map: Option<crate::api::core::v1::ConfigMapNodeConfigSource>,
+}
+
+#[cfg(feature = "builder")]
+impl NodeConfigSource {
+    /// Starts building a `NodeConfigSource`. Its required fields must be set before [`NodeConfigSourceBuilder::build`] is called.
+    pub fn builder() -> NodeConfigSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl NodeConfigSourceBuilder {
+    /// Sets [`NodeConfigSource::config_map`].
+    pub fn config_map(mut self, value: impl Into<crate::api::core::v1::ConfigMapNodeConfigSource>) -> Self {
+        self.config_map = Some(value.into());
+        self
+    }
+
+    /// Builds the `NodeConfigSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<NodeConfigSource, crate::MissingFieldError> {
+        Ok(NodeConfigSource {
+            config_map: self.config_map,
+        })
+    }
+}
+
+/// The apply configuration of [`NodeConfigSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NodeConfigSourceApplyConfiguration {
+    /// See [`NodeConfigSource::config_map`].
+    pub config_map: Option<crate::api::core::v1::ConfigMapNodeConfigSourceApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for NodeConfigSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "NodeConfigSourceApplyConfiguration",
+            self.config_map.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.config_map {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "configMap", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NodeConfigSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.NodeConfigSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::NodeConfigSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.NodeConfigSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -100,8 +197,9 @@ impl crate::schemars::JsonSchema for NodeConfigSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "configMap".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ConfigMapNodeConfigSource>().into_object();
@@ -111,11 +209,21 @@ impl crate::schemars::JsonSchema for NodeConfigSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for NodeConfigSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(NodeConfigSource {
+            config_map: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
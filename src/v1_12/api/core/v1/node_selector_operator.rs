@@ -0,0 +1,179 @@
+// Generated from definition io.k8s.api.core.v1.NodeSelectorOperator
+
+/// NodeSelectorOperator is the set of operators that can be used in a node selector requirement.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum NodeSelectorOperator {
+    /// `In`
+    In,
+
+    /// `NotIn`
+    NotIn,
+
+    /// `Exists`
+    Exists,
+
+    /// `DoesNotExist`
+    DoesNotExist,
+
+    /// `Gt`
+    Gt,
+
+    /// `Lt`
+    Lt,
+
+    /// A value that is not one of the values known to this version of the crate, such as one that was added in a newer version of Kubernetes.
+    Other(String),
+}
+
+impl NodeSelectorOperator {
+    /// The values known to this version of the crate, in the order of the variants.
+    pub const VALUES: &'static [&'static str] = &["In", "NotIn", "Exists", "DoesNotExist", "Gt", "Lt"];
+
+    /// The value as it is serialized.
+    pub fn as_str(&self) -> &str {
+        match self {
+            NodeSelectorOperator::In => "In",
+            NodeSelectorOperator::NotIn => "NotIn",
+            NodeSelectorOperator::Exists => "Exists",
+            NodeSelectorOperator::DoesNotExist => "DoesNotExist",
+            NodeSelectorOperator::Gt => "Gt",
+            NodeSelectorOperator::Lt => "Lt",
+            NodeSelectorOperator::Other(value) => value,
+        }
+    }
+}
+
+impl AsRef<str> for NodeSelectorOperator {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Default for NodeSelectorOperator {
+    fn default() -> Self {
+        NodeSelectorOperator::Other(String::new())
+    }
+}
+
+impl std::fmt::Display for NodeSelectorOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for NodeSelectorOperator {
+    fn from(value: &str) -> Self {
+        match value {
+            "In" => NodeSelectorOperator::In,
+            "NotIn" => NodeSelectorOperator::NotIn,
+            "Exists" => NodeSelectorOperator::Exists,
+            "DoesNotExist" => NodeSelectorOperator::DoesNotExist,
+            "Gt" => NodeSelectorOperator::Gt,
+            "Lt" => NodeSelectorOperator::Lt,
+            value => NodeSelectorOperator::Other(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NodeSelectorOperator {
+    fn from(value: String) -> Self {
+        match &*value {
+            "In" => NodeSelectorOperator::In,
+            "NotIn" => NodeSelectorOperator::NotIn,
+            "Exists" => NodeSelectorOperator::Exists,
+            "DoesNotExist" => NodeSelectorOperator::DoesNotExist,
+            "Gt" => NodeSelectorOperator::Gt,
+            "Lt" => NodeSelectorOperator::Lt,
+            _ => NodeSelectorOperator::Other(value),
+        }
+    }
+}
+
+impl From<NodeSelectorOperator> for String {
+    fn from(value: NodeSelectorOperator) -> Self {
+        match value {
+            NodeSelectorOperator::Other(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl PartialEq<str> for NodeSelectorOperator {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for NodeSelectorOperator {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<'de> crate::serde::Deserialize<'de> for NodeSelectorOperator {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl crate::serde::de::Visitor<'_> for Visitor {
+            type Value = NodeSelectorOperator;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("NodeSelectorOperator")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
+                Ok(v.into())
+            }
+
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E> where E: crate::serde::de::Error {
+                Ok(v.into())
+            }
+        }
+
+        deserializer.deserialize_string(Visitor)
+    }
+}
+
+impl crate::serde::Serialize for NodeSelectorOperator {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for NodeSelectorOperator {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::NodeSelectorOperator".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.NodeSelectorOperator".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("NodeSelectorOperator is the set of operators that can be used in a node selector requirement.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for NodeSelectorOperator {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        Ok(match u.int_in_range(0..=6_u8)? {
+            0 => NodeSelectorOperator::In,
+            1 => NodeSelectorOperator::NotIn,
+            2 => NodeSelectorOperator::Exists,
+            3 => NodeSelectorOperator::DoesNotExist,
+            4 => NodeSelectorOperator::Gt,
+            5 => NodeSelectorOperator::Lt,
+            _ => NodeSelectorOperator::from(u.arbitrary::<String>()?),
+        })
+    }
+}
@@ -1,53 +1,64 @@
 // Generated from definition io.k8s.api.core.v1.NodeSelectorRequirement
 
 /// A node selector requirement is a selector that contains values, a key, and an operator that relates the key and values.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct NodeSelectorRequirement {
     /// The label key that the selector applies to.
     pub key: String,
 
     /// Represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists, DoesNotExist. Gt, and Lt.
-    pub operator: String,
+    pub operator: crate::api::core::v1::NodeSelectorOperator,
 
     /// An array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. If the operator is Gt or Lt, the values array must have a single element, which will be interpreted as an integer. This array is replaced during a strategic merge patch.
     pub values: Option<Vec<String>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for NodeSelectorRequirement {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_key,
-            Key_operator,
-            Key_values,
-            Other,
+impl NodeSelectorRequirement {
+    /// Constructs a `NodeSelectorRequirement` from its required fields. Its optional fields are unset.
+    pub fn new(key: impl Into<String>, operator: impl Into<crate::api::core::v1::NodeSelectorOperator>) -> Self {
+        NodeSelectorRequirement {
+            key: key.into(),
+            operator: operator.into(),
+            values: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl NodeSelectorRequirement {
+    /// Returns this value with [`NodeSelectorRequirement::key`] set to the given value.
+    #[must_use]
+    pub fn with_key(mut self, value: impl Into<String>) -> Self {
+        self.key = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`NodeSelectorRequirement::operator`] set to the given value.
+    #[must_use]
+    pub fn with_operator(mut self, value: impl Into<crate::api::core::v1::NodeSelectorOperator>) -> Self {
+        self.operator = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`NodeSelectorRequirement::values`] set to the given value.
+    #[must_use]
+    pub fn with_values(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.values = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "key" => Field::Key_key,
-                            "operator" => Field::Key_operator,
-                            "values" => Field::Key_values,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for NodeSelectorRequirement {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl NodeSelectorRequirement {
+    const FIELDS: &'static [&'static str] = &[
+        "key",
+        "operator",
+        "values",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for NodeSelectorRequirement {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -59,40 +70,104 @@ impl<'de> crate::serde::Deserialize<'de> for NodeSelectorRequirement {
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
                 let mut value_key: Option<String> = None;
-                let mut value_operator: Option<String> = None;
+                let mut value_operator: Option<crate::api::core::v1::NodeSelectorOperator> = None;
                 let mut value_values: Option<Vec<String>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NodeSelectorRequirement", NodeSelectorRequirement::FIELDS))? {
                     match key {
-                        Field::Key_key => value_key = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_operator => value_operator = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_values => value_values = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_key = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_operator = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_values = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(NodeSelectorRequirement {
-                    key: value_key.ok_or_else(|| crate::serde::de::Error::missing_field("key"))?,
-                    operator: value_operator.ok_or_else(|| crate::serde::de::Error::missing_field("operator"))?,
+                    key: value_key.ok_or_else(|| crate::serde2::missing_field("NodeSelectorRequirement", "key", &["key", "operator"]))?,
+                    operator: value_operator.ok_or_else(|| crate::serde2::missing_field("NodeSelectorRequirement", "operator", &["key", "operator"]))?,
                     values: value_values,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(NodeSelectorRequirement {
+                    key: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    operator: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    values: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "NodeSelectorRequirement",
-            &[
-                "key",
-                "operator",
-                "values",
-            ],
+            NodeSelectorRequirement::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, NodeSelectorRequirement> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("NodeSelectorRequirement")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_key = false;
+                let mut has_operator = false;
+                let mut has_values = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NodeSelectorRequirement", NodeSelectorRequirement::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.key))?; has_key = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.operator))?; has_operator = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.values))?; has_values = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_key {
+                    return Err(crate::serde2::missing_field("NodeSelectorRequirement", "key", &["key", "operator"]));
+                }
+                if !has_operator {
+                    return Err(crate::serde2::missing_field("NodeSelectorRequirement", "operator", &["key", "operator"]));
+                }
+                if !has_values {
+                    self.0.values = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.key))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.operator))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.values))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "NodeSelectorRequirement",
+            NodeSelectorRequirement::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for NodeSelectorRequirement {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("NodeSelectorRequirement", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "key", &self.key)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "operator", &self.operator)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "values", &self.values)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "NodeSelectorRequirement",
             2 +
@@ -107,10 +182,99 @@ impl crate::serde::Serialize for NodeSelectorRequirement {
     }
 }
 
+/// A builder of [`NodeSelectorRequirement`]. Created with [`NodeSelectorRequirement::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct NodeSelectorRequirementBuilder {
+    key: Option<String>,
+    operator: Option<crate::api::core::v1::NodeSelectorOperator>,
+    values: Option<Vec<String>>,
+}
+
+#[cfg(feature = "builder")]
+impl NodeSelectorRequirement {
+    /// Starts building a `NodeSelectorRequirement`. Its required fields must be set before [`NodeSelectorRequirementBuilder::build`] is called.
+    pub fn builder() -> NodeSelectorRequirementBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl NodeSelectorRequirementBuilder {
+    /// Sets [`NodeSelectorRequirement::key`].
+    pub fn key(mut self, value: impl Into<String>) -> Self {
+        self.key = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeSelectorRequirement::operator`].
+    pub fn operator(mut self, value: impl Into<crate::api::core::v1::NodeSelectorOperator>) -> Self {
+        self.operator = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeSelectorRequirement::values`].
+    pub fn values(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.values = Some(value.into());
+        self
+    }
+
+    /// Builds the `NodeSelectorRequirement`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<NodeSelectorRequirement, crate::MissingFieldError> {
+        Ok(NodeSelectorRequirement {
+            key: self.key.ok_or_else(|| crate::MissingFieldError::new("NodeSelectorRequirement", "key"))?,
+            operator: self.operator.ok_or_else(|| crate::MissingFieldError::new("NodeSelectorRequirement", "operator"))?,
+            values: self.values,
+        })
+    }
+}
+
+/// The apply configuration of [`NodeSelectorRequirement`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NodeSelectorRequirementApplyConfiguration {
+    /// See [`NodeSelectorRequirement::key`].
+    pub key: Option<String>,
+
+    /// See [`NodeSelectorRequirement::operator`].
+    pub operator: Option<crate::api::core::v1::NodeSelectorOperator>,
+
+    /// See [`NodeSelectorRequirement::values`].
+    pub values: Option<Vec<String>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for NodeSelectorRequirementApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "NodeSelectorRequirementApplyConfiguration",
+            self.key.as_ref().map_or(0, |_| 1) +
+            self.operator.as_ref().map_or(0, |_| 1) +
+            self.values.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.key {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "key", value)?;
+        }
+        if let Some(value) = &self.operator {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "operator", value)?;
+        }
+        if let Some(value) = &self.values {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "values", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NodeSelectorRequirement {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.NodeSelectorRequirement".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::NodeSelectorRequirement".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.NodeSelectorRequirement".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -121,8 +285,9 @@ impl crate::schemars::JsonSchema for NodeSelectorRequirement {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "key".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -132,19 +297,19 @@ impl crate::schemars::JsonSchema for NodeSelectorRequirement {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "operator".to_owned(),
-                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
-                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::NodeSelectorOperator>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
                                 description: Some("Represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists, DoesNotExist. Gt, and Lt.".to_owned()),
                                 ..Default::default()
-                            })),
-                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
-                            ..Default::default()
-                        }),
-                    ),
-                    (
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
                         "values".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -163,8 +328,9 @@ impl crate::schemars::JsonSchema for NodeSelectorRequirement {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "key",
                     "operator",
@@ -175,3 +341,14 @@ impl crate::schemars::JsonSchema for NodeSelectorRequirement {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for NodeSelectorRequirement {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(NodeSelectorRequirement {
+            key: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            operator: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            values: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
@@ -1,12 +1,13 @@
 // Generated from definition io.k8s.api.core.v1.NodeSpec
 
 /// NodeSpec describes the attributes that a node is created with.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct NodeSpec {
     /// If specified, the source to get node configuration from The DynamicKubeletConfig feature gate must be enabled for the Kubelet to use this field
     pub config_source: Option<crate::api::core::v1::NodeConfigSource>,
 
     /// Deprecated. Not all kubelets will set this field. Remove field after 1.13. see: https://issues.k8s.io/61966
+    #[deprecated]
     pub external_id: Option<String>,
 
     /// PodCIDR represents the pod IP range assigned to the node.
@@ -22,47 +23,65 @@ pub struct NodeSpec {
     pub unschedulable: Option<bool>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for NodeSpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_config_source,
-            Key_external_id,
-            Key_pod_cidr,
-            Key_provider_id,
-            Key_taints,
-            Key_unschedulable,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl NodeSpec {
+    /// Returns this value with [`NodeSpec::config_source`] set to the given value.
+    #[must_use]
+    pub fn with_config_source(mut self, value: impl Into<crate::api::core::v1::NodeConfigSource>) -> Self {
+        self.config_source = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "configSource" => Field::Key_config_source,
-                            "externalID" => Field::Key_external_id,
-                            "podCIDR" => Field::Key_pod_cidr,
-                            "providerID" => Field::Key_provider_id,
-                            "taints" => Field::Key_taints,
-                            "unschedulable" => Field::Key_unschedulable,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`NodeSpec::external_id`] set to the given value.
+    #[must_use]
+    pub fn with_external_id(mut self, value: impl Into<String>) -> Self {
+        self.external_id = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`NodeSpec::pod_cidr`] set to the given value.
+    #[must_use]
+    pub fn with_pod_cidr(mut self, value: impl Into<String>) -> Self {
+        self.pod_cidr = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`NodeSpec::provider_id`] set to the given value.
+    #[must_use]
+    pub fn with_provider_id(mut self, value: impl Into<String>) -> Self {
+        self.provider_id = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`NodeSpec::taints`] set to the given value.
+    #[must_use]
+    pub fn with_taints(mut self, value: impl Into<Vec<crate::api::core::v1::Taint>>) -> Self {
+        self.taints = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`NodeSpec::unschedulable`] set to the given value.
+    #[must_use]
+    pub fn with_unschedulable(mut self, value: impl Into<bool>) -> Self {
+        self.unschedulable = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for NodeSpec {}
+
+impl NodeSpec {
+    const FIELDS: &'static [&'static str] = &[
+        "configSource",
+        "externalID",
+        "podCIDR",
+        "providerID",
+        "taints",
+        "unschedulable",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for NodeSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -80,15 +99,15 @@ impl<'de> crate::serde::Deserialize<'de> for NodeSpec {
                 let mut value_taints: Option<Vec<crate::api::core::v1::Taint>> = None;
                 let mut value_unschedulable: Option<bool> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NodeSpec", NodeSpec::FIELDS))? {
                     match key {
-                        Field::Key_config_source => value_config_source = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_external_id => value_external_id = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_pod_cidr => value_pod_cidr = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_provider_id => value_provider_id = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_taints => value_taints = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_unschedulable => value_unschedulable = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_config_source = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_external_id = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_pod_cidr = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_provider_id = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_taints = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_unschedulable = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -101,25 +120,110 @@ impl<'de> crate::serde::Deserialize<'de> for NodeSpec {
                     unschedulable: value_unschedulable,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(NodeSpec {
+                    config_source: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    external_id: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    pod_cidr: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    provider_id: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    taints: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    unschedulable: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "NodeSpec",
-            &[
-                "configSource",
-                "externalID",
-                "podCIDR",
-                "providerID",
-                "taints",
-                "unschedulable",
-            ],
+            NodeSpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, NodeSpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("NodeSpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_config_source = false;
+                let mut has_external_id = false;
+                let mut has_pod_cidr = false;
+                let mut has_provider_id = false;
+                let mut has_taints = false;
+                let mut has_unschedulable = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NodeSpec", NodeSpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.config_source))?; has_config_source = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.external_id))?; has_external_id = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.pod_cidr))?; has_pod_cidr = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.provider_id))?; has_provider_id = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.taints))?; has_taints = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.unschedulable))?; has_unschedulable = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_config_source {
+                    self.0.config_source = None;
+                }
+                if !has_external_id {
+                    self.0.external_id = None;
+                }
+                if !has_pod_cidr {
+                    self.0.pod_cidr = None;
+                }
+                if !has_provider_id {
+                    self.0.provider_id = None;
+                }
+                if !has_taints {
+                    self.0.taints = None;
+                }
+                if !has_unschedulable {
+                    self.0.unschedulable = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.config_source))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.external_id))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.pod_cidr))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.provider_id))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.taints))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.unschedulable))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "NodeSpec",
+            NodeSpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for NodeSpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("NodeSpec", 6)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "configSource", &self.config_source)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "externalID", &self.external_id)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "podCIDR", &self.pod_cidr)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "providerID", &self.provider_id)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "taints", &self.taints)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "unschedulable", &self.unschedulable)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "NodeSpec",
             self.config_source.as_ref().map_or(0, |_| 1) +
@@ -151,10 +255,144 @@ impl crate::serde::Serialize for NodeSpec {
     }
 }
 
+/// A builder of [`NodeSpec`]. Created with [`NodeSpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct NodeSpecBuilder {
+    config_source: Option<crate::api::core::v1::NodeConfigSource>,
+    external_id: Option<String>,
+    pod_cidr: Option<String>,
+    provider_id: Option<String>,
+    taints: Option<Vec<crate::api::core::v1::Taint>>,
+    unschedulable: Option<bool>,
+}
+
+#[cfg(feature = "builder")]
+impl NodeSpec {
+    /// Starts building a `NodeSpec`. Its required fields must be set before [`NodeSpecBuilder::build`] is called.
+    pub fn builder() -> NodeSpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl NodeSpecBuilder {
+    /// Sets [`NodeSpec::config_source`].
+    pub fn config_source(mut self, value: impl Into<crate::api::core::v1::NodeConfigSource>) -> Self {
+        self.config_source = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeSpec::external_id`].
+    pub fn external_id(mut self, value: impl Into<String>) -> Self {
+        self.external_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeSpec::pod_cidr`].
+    pub fn pod_cidr(mut self, value: impl Into<String>) -> Self {
+        self.pod_cidr = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeSpec::provider_id`].
+    pub fn provider_id(mut self, value: impl Into<String>) -> Self {
+        self.provider_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeSpec::taints`].
+    pub fn taints(mut self, value: impl Into<Vec<crate::api::core::v1::Taint>>) -> Self {
+        self.taints = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeSpec::unschedulable`].
+    pub fn unschedulable(mut self, value: impl Into<bool>) -> Self {
+        self.unschedulable = Some(value.into());
+        self
+    }
+
+    /// Builds the `NodeSpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<NodeSpec, crate::MissingFieldError> {
+        Ok(NodeSpec {
+            config_source: self.config_source,
+            external_id: self.external_id,
+            pod_cidr: self.pod_cidr,
+            provider_id: self.provider_id,
+            taints: self.taints,
+            unschedulable: self.unschedulable,
+        })
+    }
+}
+
+/// The apply configuration of [`NodeSpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NodeSpecApplyConfiguration {
+    /// See [`NodeSpec::config_source`].
+    pub config_source: Option<crate::api::core::v1::NodeConfigSourceApplyConfiguration>,
+
+    /// See [`NodeSpec::external_id`].
+    pub external_id: Option<String>,
+
+    /// See [`NodeSpec::pod_cidr`].
+    pub pod_cidr: Option<String>,
+
+    /// See [`NodeSpec::provider_id`].
+    pub provider_id: Option<String>,
+
+    /// See [`NodeSpec::taints`].
+    pub taints: Option<Vec<crate::api::core::v1::TaintApplyConfiguration>>,
+
+    /// See [`NodeSpec::unschedulable`].
+    pub unschedulable: Option<bool>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for NodeSpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "NodeSpecApplyConfiguration",
+            self.config_source.as_ref().map_or(0, |_| 1) +
+            self.external_id.as_ref().map_or(0, |_| 1) +
+            self.pod_cidr.as_ref().map_or(0, |_| 1) +
+            self.provider_id.as_ref().map_or(0, |_| 1) +
+            self.taints.as_ref().map_or(0, |_| 1) +
+            self.unschedulable.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.config_source {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "configSource", value)?;
+        }
+        if let Some(value) = &self.external_id {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "externalID", value)?;
+        }
+        if let Some(value) = &self.pod_cidr {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "podCIDR", value)?;
+        }
+        if let Some(value) = &self.provider_id {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "providerID", value)?;
+        }
+        if let Some(value) = &self.taints {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "taints", value)?;
+        }
+        if let Some(value) = &self.unschedulable {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "unschedulable", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NodeSpec {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.NodeSpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::NodeSpec".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.NodeSpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -165,8 +403,9 @@ impl crate::schemars::JsonSchema for NodeSpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "configSource".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::NodeConfigSource>().into_object();
@@ -176,8 +415,8 @@ impl crate::schemars::JsonSchema for NodeSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "externalID".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -187,8 +426,8 @@ impl crate::schemars::JsonSchema for NodeSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "podCIDR".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -198,8 +437,8 @@ impl crate::schemars::JsonSchema for NodeSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "providerID".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -209,8 +448,8 @@ impl crate::schemars::JsonSchema for NodeSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "taints".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -224,8 +463,8 @@ impl crate::schemars::JsonSchema for NodeSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "unschedulable".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -235,11 +474,26 @@ impl crate::schemars::JsonSchema for NodeSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for NodeSpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(NodeSpec {
+            config_source: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            external_id: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            pod_cidr: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            provider_id: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            taints: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            unschedulable: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.NodeStatus
 
 /// NodeStatus is information about the current status of a node.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct NodeStatus {
     /// List of addresses reachable to the node. Queried from cloud provider, if available. More info: https://kubernetes.io/docs/concepts/nodes/node/#addresses
     pub addresses: Option<Vec<crate::api::core::v1::NodeAddress>>,
@@ -37,57 +37,110 @@ pub struct NodeStatus {
     pub volumes_in_use: Option<Vec<String>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for NodeStatus {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_addresses,
-            Key_allocatable,
-            Key_capacity,
-            Key_conditions,
-            Key_config,
-            Key_daemon_endpoints,
-            Key_images,
-            Key_node_info,
-            Key_phase,
-            Key_volumes_attached,
-            Key_volumes_in_use,
-            Other,
-        }
+impl NodeStatus {
+    /// Returns this value with [`NodeStatus::addresses`] set to the given value.
+    #[must_use]
+    pub fn with_addresses(mut self, value: impl Into<Vec<crate::api::core::v1::NodeAddress>>) -> Self {
+        self.addresses = Some(value.into());
+        self
+    }
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+    /// Returns this value with [`NodeStatus::allocatable`] set to the given value.
+    #[must_use]
+    pub fn with_allocatable(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>) -> Self {
+        self.allocatable = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`NodeStatus::capacity`] set to the given value.
+    #[must_use]
+    pub fn with_capacity(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>) -> Self {
+        self.capacity = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`NodeStatus::conditions`] set to the given value.
+    #[must_use]
+    pub fn with_conditions(mut self, value: impl Into<Vec<crate::api::core::v1::NodeCondition>>) -> Self {
+        self.conditions = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "addresses" => Field::Key_addresses,
-                            "allocatable" => Field::Key_allocatable,
-                            "capacity" => Field::Key_capacity,
-                            "conditions" => Field::Key_conditions,
-                            "config" => Field::Key_config,
-                            "daemonEndpoints" => Field::Key_daemon_endpoints,
-                            "images" => Field::Key_images,
-                            "nodeInfo" => Field::Key_node_info,
-                            "phase" => Field::Key_phase,
-                            "volumesAttached" => Field::Key_volumes_attached,
-                            "volumesInUse" => Field::Key_volumes_in_use,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`NodeStatus::config`] set to the given value.
+    #[must_use]
+    pub fn with_config(mut self, value: impl Into<crate::api::core::v1::NodeConfigStatus>) -> Self {
+        self.config = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`NodeStatus::daemon_endpoints`] set to the given value.
+    #[must_use]
+    pub fn with_daemon_endpoints(mut self, value: impl Into<crate::api::core::v1::NodeDaemonEndpoints>) -> Self {
+        self.daemon_endpoints = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`NodeStatus::images`] set to the given value.
+    #[must_use]
+    pub fn with_images(mut self, value: impl Into<Vec<crate::api::core::v1::ContainerImage>>) -> Self {
+        self.images = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`NodeStatus::node_info`] set to the given value.
+    #[must_use]
+    pub fn with_node_info(mut self, value: impl Into<crate::api::core::v1::NodeSystemInfo>) -> Self {
+        self.node_info = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`NodeStatus::phase`] set to the given value.
+    #[must_use]
+    pub fn with_phase(mut self, value: impl Into<String>) -> Self {
+        self.phase = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`NodeStatus::volumes_attached`] set to the given value.
+    #[must_use]
+    pub fn with_volumes_attached(mut self, value: impl Into<Vec<crate::api::core::v1::AttachedVolume>>) -> Self {
+        self.volumes_attached = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`NodeStatus::volumes_in_use`] set to the given value.
+    #[must_use]
+    pub fn with_volumes_in_use(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.volumes_in_use = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for NodeStatus {
+    const FIELD_PATCH_STRATEGIES: &'static [crate::FieldPatchStrategy] = &[
+        crate::FieldPatchStrategy { field: "addresses", merge: true, retain_keys: false, merge_key: Some("type") },
+        crate::FieldPatchStrategy { field: "conditions", merge: true, retain_keys: false, merge_key: Some("type") },
+    ];
+}
 
+impl NodeStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "addresses",
+        "allocatable",
+        "capacity",
+        "conditions",
+        "config",
+        "daemonEndpoints",
+        "images",
+        "nodeInfo",
+        "phase",
+        "volumesAttached",
+        "volumesInUse",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for NodeStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -110,20 +163,20 @@ impl<'de> crate::serde::Deserialize<'de> for NodeStatus {
                 let mut value_volumes_attached: Option<Vec<crate::api::core::v1::AttachedVolume>> = None;
                 let mut value_volumes_in_use: Option<Vec<String>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NodeStatus", NodeStatus::FIELDS))? {
                     match key {
-                        Field::Key_addresses => value_addresses = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_allocatable => value_allocatable = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_capacity => value_capacity = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_conditions => value_conditions = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_config => value_config = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_daemon_endpoints => value_daemon_endpoints = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_images => value_images = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_node_info => value_node_info = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_phase => value_phase = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_volumes_attached => value_volumes_attached = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_volumes_in_use => value_volumes_in_use = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_addresses = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_allocatable = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_capacity = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_conditions = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_config = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_daemon_endpoints = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_images = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(7) => value_node_info = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(8) => value_phase = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(9) => value_volumes_attached = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(10) => value_volumes_in_use = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -141,30 +194,150 @@ impl<'de> crate::serde::Deserialize<'de> for NodeStatus {
                     volumes_in_use: value_volumes_in_use,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(NodeStatus {
+                    addresses: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    allocatable: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    capacity: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    conditions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    config: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    daemon_endpoints: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    images: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    node_info: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                    phase: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?,
+                    volumes_attached: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?,
+                    volumes_in_use: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "NodeStatus",
-            &[
-                "addresses",
-                "allocatable",
-                "capacity",
-                "conditions",
-                "config",
-                "daemonEndpoints",
-                "images",
-                "nodeInfo",
-                "phase",
-                "volumesAttached",
-                "volumesInUse",
-            ],
+            NodeStatus::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, NodeStatus> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("NodeStatus")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_addresses = false;
+                let mut has_allocatable = false;
+                let mut has_capacity = false;
+                let mut has_conditions = false;
+                let mut has_config = false;
+                let mut has_daemon_endpoints = false;
+                let mut has_images = false;
+                let mut has_node_info = false;
+                let mut has_phase = false;
+                let mut has_volumes_attached = false;
+                let mut has_volumes_in_use = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NodeStatus", NodeStatus::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.addresses))?; has_addresses = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.allocatable))?; has_allocatable = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.capacity))?; has_capacity = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.conditions))?; has_conditions = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.config))?; has_config = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.daemon_endpoints))?; has_daemon_endpoints = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.images))?; has_images = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.node_info))?; has_node_info = true; },
+                        Some(8) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.phase))?; has_phase = true; },
+                        Some(9) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.volumes_attached))?; has_volumes_attached = true; },
+                        Some(10) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.volumes_in_use))?; has_volumes_in_use = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_addresses {
+                    self.0.addresses = None;
+                }
+                if !has_allocatable {
+                    self.0.allocatable = None;
+                }
+                if !has_capacity {
+                    self.0.capacity = None;
+                }
+                if !has_conditions {
+                    self.0.conditions = None;
+                }
+                if !has_config {
+                    self.0.config = None;
+                }
+                if !has_daemon_endpoints {
+                    self.0.daemon_endpoints = None;
+                }
+                if !has_images {
+                    self.0.images = None;
+                }
+                if !has_node_info {
+                    self.0.node_info = None;
+                }
+                if !has_phase {
+                    self.0.phase = None;
+                }
+                if !has_volumes_attached {
+                    self.0.volumes_attached = None;
+                }
+                if !has_volumes_in_use {
+                    self.0.volumes_in_use = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.addresses))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.allocatable))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.capacity))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.conditions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.config))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.daemon_endpoints))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.images))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.node_info))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.phase))?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.volumes_attached))?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.volumes_in_use))?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "NodeStatus",
+            NodeStatus::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for NodeStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("NodeStatus", 11)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "addresses", &self.addresses)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "allocatable", &self.allocatable)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "capacity", &self.capacity)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conditions", &self.conditions)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "config", &self.config)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "daemonEndpoints", &self.daemon_endpoints)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "images", &self.images)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeInfo", &self.node_info)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "phase", &self.phase)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumesAttached", &self.volumes_attached)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumesInUse", &self.volumes_in_use)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "NodeStatus",
             self.addresses.as_ref().map_or(0, |_| 1) +
@@ -216,10 +389,219 @@ impl crate::serde::Serialize for NodeStatus {
     }
 }
 
+/// A builder of [`NodeStatus`]. Created with [`NodeStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct NodeStatusBuilder {
+    addresses: Option<Vec<crate::api::core::v1::NodeAddress>>,
+    allocatable: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>,
+    capacity: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>,
+    conditions: Option<Vec<crate::api::core::v1::NodeCondition>>,
+    config: Option<crate::api::core::v1::NodeConfigStatus>,
+    daemon_endpoints: Option<crate::api::core::v1::NodeDaemonEndpoints>,
+    images: Option<Vec<crate::api::core::v1::ContainerImage>>,
+    node_info: Option<crate::api::core::v1::NodeSystemInfo>,
+    phase: Option<String>,
+    volumes_attached: Option<Vec<crate::api::core::v1::AttachedVolume>>,
+    volumes_in_use: Option<Vec<String>>,
+}
+
+#[cfg(feature = "builder")]
+impl NodeStatus {
+    /// Starts building a `NodeStatus`. Its required fields must be set before [`NodeStatusBuilder::build`] is called.
+    pub fn builder() -> NodeStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl NodeStatusBuilder {
+    /// Sets [`NodeStatus::addresses`].
+    pub fn addresses(mut self, value: impl Into<Vec<crate::api::core::v1::NodeAddress>>) -> Self {
+        self.addresses = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeStatus::allocatable`].
+    pub fn allocatable(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>) -> Self {
+        self.allocatable = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeStatus::capacity`].
+    pub fn capacity(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>) -> Self {
+        self.capacity = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeStatus::conditions`].
+    pub fn conditions(mut self, value: impl Into<Vec<crate::api::core::v1::NodeCondition>>) -> Self {
+        self.conditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeStatus::config`].
+    pub fn config(mut self, value: impl Into<crate::api::core::v1::NodeConfigStatus>) -> Self {
+        self.config = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeStatus::daemon_endpoints`].
+    pub fn daemon_endpoints(mut self, value: impl Into<crate::api::core::v1::NodeDaemonEndpoints>) -> Self {
+        self.daemon_endpoints = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeStatus::images`].
+    pub fn images(mut self, value: impl Into<Vec<crate::api::core::v1::ContainerImage>>) -> Self {
+        self.images = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeStatus::node_info`].
+    pub fn node_info(mut self, value: impl Into<crate::api::core::v1::NodeSystemInfo>) -> Self {
+        self.node_info = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeStatus::phase`].
+    pub fn phase(mut self, value: impl Into<String>) -> Self {
+        self.phase = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeStatus::volumes_attached`].
+    pub fn volumes_attached(mut self, value: impl Into<Vec<crate::api::core::v1::AttachedVolume>>) -> Self {
+        self.volumes_attached = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeStatus::volumes_in_use`].
+    pub fn volumes_in_use(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.volumes_in_use = Some(value.into());
+        self
+    }
+
+    /// Builds the `NodeStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<NodeStatus, crate::MissingFieldError> {
+        Ok(NodeStatus {
+            addresses: self.addresses,
+            allocatable: self.allocatable,
+            capacity: self.capacity,
+            conditions: self.conditions,
+            config: self.config,
+            daemon_endpoints: self.daemon_endpoints,
+            images: self.images,
+            node_info: self.node_info,
+            phase: self.phase,
+            volumes_attached: self.volumes_attached,
+            volumes_in_use: self.volumes_in_use,
+        })
+    }
+}
+
+/// The apply configuration of [`NodeStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NodeStatusApplyConfiguration {
+    /// See [`NodeStatus::addresses`].
+    pub addresses: Option<Vec<crate::api::core::v1::NodeAddressApplyConfiguration>>,
+
+    /// See [`NodeStatus::allocatable`].
+    pub allocatable: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>,
+
+    /// See [`NodeStatus::capacity`].
+    pub capacity: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>,
+
+    /// See [`NodeStatus::conditions`].
+    pub conditions: Option<Vec<crate::api::core::v1::NodeConditionApplyConfiguration>>,
+
+    /// See [`NodeStatus::config`].
+    pub config: Option<crate::api::core::v1::NodeConfigStatusApplyConfiguration>,
+
+    /// See [`NodeStatus::daemon_endpoints`].
+    pub daemon_endpoints: Option<crate::api::core::v1::NodeDaemonEndpointsApplyConfiguration>,
+
+    /// See [`NodeStatus::images`].
+    pub images: Option<Vec<crate::api::core::v1::ContainerImageApplyConfiguration>>,
+
+    /// See [`NodeStatus::node_info`].
+    pub node_info: Option<crate::api::core::v1::NodeSystemInfoApplyConfiguration>,
+
+    /// See [`NodeStatus::phase`].
+    pub phase: Option<String>,
+
+    /// See [`NodeStatus::volumes_attached`].
+    pub volumes_attached: Option<Vec<crate::api::core::v1::AttachedVolumeApplyConfiguration>>,
+
+    /// See [`NodeStatus::volumes_in_use`].
+    pub volumes_in_use: Option<Vec<String>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for NodeStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "NodeStatusApplyConfiguration",
+            self.addresses.as_ref().map_or(0, |_| 1) +
+            self.allocatable.as_ref().map_or(0, |_| 1) +
+            self.capacity.as_ref().map_or(0, |_| 1) +
+            self.conditions.as_ref().map_or(0, |_| 1) +
+            self.config.as_ref().map_or(0, |_| 1) +
+            self.daemon_endpoints.as_ref().map_or(0, |_| 1) +
+            self.images.as_ref().map_or(0, |_| 1) +
+            self.node_info.as_ref().map_or(0, |_| 1) +
+            self.phase.as_ref().map_or(0, |_| 1) +
+            self.volumes_attached.as_ref().map_or(0, |_| 1) +
+            self.volumes_in_use.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.addresses {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "addresses", value)?;
+        }
+        if let Some(value) = &self.allocatable {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "allocatable", value)?;
+        }
+        if let Some(value) = &self.capacity {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "capacity", value)?;
+        }
+        if let Some(value) = &self.conditions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conditions", value)?;
+        }
+        if let Some(value) = &self.config {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "config", value)?;
+        }
+        if let Some(value) = &self.daemon_endpoints {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "daemonEndpoints", value)?;
+        }
+        if let Some(value) = &self.images {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "images", value)?;
+        }
+        if let Some(value) = &self.node_info {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeInfo", value)?;
+        }
+        if let Some(value) = &self.phase {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "phase", value)?;
+        }
+        if let Some(value) = &self.volumes_attached {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumesAttached", value)?;
+        }
+        if let Some(value) = &self.volumes_in_use {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumesInUse", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NodeStatus {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.NodeStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::NodeStatus".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.NodeStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -230,8 +612,9 @@ impl crate::schemars::JsonSchema for NodeStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "addresses".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -245,8 +628,8 @@ impl crate::schemars::JsonSchema for NodeStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "allocatable".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -260,8 +643,8 @@ impl crate::schemars::JsonSchema for NodeStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "capacity".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -275,8 +658,8 @@ impl crate::schemars::JsonSchema for NodeStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "conditions".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -290,8 +673,8 @@ impl crate::schemars::JsonSchema for NodeStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "config".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::NodeConfigStatus>().into_object();
@@ -301,8 +684,8 @@ impl crate::schemars::JsonSchema for NodeStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "daemonEndpoints".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::NodeDaemonEndpoints>().into_object();
@@ -312,8 +695,8 @@ impl crate::schemars::JsonSchema for NodeStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "images".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -327,8 +710,8 @@ impl crate::schemars::JsonSchema for NodeStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "nodeInfo".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::NodeSystemInfo>().into_object();
@@ -338,8 +721,8 @@ impl crate::schemars::JsonSchema for NodeStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "phase".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -349,8 +732,8 @@ impl crate::schemars::JsonSchema for NodeStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "volumesAttached".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -364,8 +747,8 @@ impl crate::schemars::JsonSchema for NodeStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "volumesInUse".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -384,11 +767,31 @@ impl crate::schemars::JsonSchema for NodeStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for NodeStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(NodeStatus {
+            addresses: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            allocatable: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            capacity: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            conditions: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            config: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            daemon_endpoints: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            images: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            node_info: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            phase: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            volumes_attached: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            volumes_in_use: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.NodeSystemInfo
 
 /// NodeSystemInfo is a set of ids/uuids to uniquely identify the node.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct NodeSystemInfo {
     /// The Architecture reported by the node
     pub architecture: String,
@@ -34,55 +34,115 @@ pub struct NodeSystemInfo {
     pub system_uuid: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for NodeSystemInfo {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_architecture,
-            Key_boot_id,
-            Key_container_runtime_version,
-            Key_kernel_version,
-            Key_kube_proxy_version,
-            Key_kubelet_version,
-            Key_machine_id,
-            Key_operating_system,
-            Key_os_image,
-            Key_system_uuid,
-            Other,
+impl NodeSystemInfo {
+    /// Constructs a `NodeSystemInfo` from its required fields. Its optional fields are unset.
+    pub fn new(architecture: impl Into<String>, boot_id: impl Into<String>, container_runtime_version: impl Into<String>, kernel_version: impl Into<String>, kube_proxy_version: impl Into<String>, kubelet_version: impl Into<String>, machine_id: impl Into<String>, operating_system: impl Into<String>, os_image: impl Into<String>, system_uuid: impl Into<String>) -> Self {
+        NodeSystemInfo {
+            architecture: architecture.into(),
+            boot_id: boot_id.into(),
+            container_runtime_version: container_runtime_version.into(),
+            kernel_version: kernel_version.into(),
+            kube_proxy_version: kube_proxy_version.into(),
+            kubelet_version: kubelet_version.into(),
+            machine_id: machine_id.into(),
+            operating_system: operating_system.into(),
+            os_image: os_image.into(),
+            system_uuid: system_uuid.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl NodeSystemInfo {
+    /// Returns this value with [`NodeSystemInfo::architecture`] set to the given value.
+    #[must_use]
+    pub fn with_architecture(mut self, value: impl Into<String>) -> Self {
+        self.architecture = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`NodeSystemInfo::boot_id`] set to the given value.
+    #[must_use]
+    pub fn with_boot_id(mut self, value: impl Into<String>) -> Self {
+        self.boot_id = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`NodeSystemInfo::container_runtime_version`] set to the given value.
+    #[must_use]
+    pub fn with_container_runtime_version(mut self, value: impl Into<String>) -> Self {
+        self.container_runtime_version = value.into();
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "architecture" => Field::Key_architecture,
-                            "bootID" => Field::Key_boot_id,
-                            "containerRuntimeVersion" => Field::Key_container_runtime_version,
-                            "kernelVersion" => Field::Key_kernel_version,
-                            "kubeProxyVersion" => Field::Key_kube_proxy_version,
-                            "kubeletVersion" => Field::Key_kubelet_version,
-                            "machineID" => Field::Key_machine_id,
-                            "operatingSystem" => Field::Key_operating_system,
-                            "osImage" => Field::Key_os_image,
-                            "systemUUID" => Field::Key_system_uuid,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`NodeSystemInfo::kernel_version`] set to the given value.
+    #[must_use]
+    pub fn with_kernel_version(mut self, value: impl Into<String>) -> Self {
+        self.kernel_version = value.into();
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`NodeSystemInfo::kube_proxy_version`] set to the given value.
+    #[must_use]
+    pub fn with_kube_proxy_version(mut self, value: impl Into<String>) -> Self {
+        self.kube_proxy_version = value.into();
+        self
+    }
+
+    /// Returns this value with [`NodeSystemInfo::kubelet_version`] set to the given value.
+    #[must_use]
+    pub fn with_kubelet_version(mut self, value: impl Into<String>) -> Self {
+        self.kubelet_version = value.into();
+        self
+    }
 
+    /// Returns this value with [`NodeSystemInfo::machine_id`] set to the given value.
+    #[must_use]
+    pub fn with_machine_id(mut self, value: impl Into<String>) -> Self {
+        self.machine_id = value.into();
+        self
+    }
+
+    /// Returns this value with [`NodeSystemInfo::operating_system`] set to the given value.
+    #[must_use]
+    pub fn with_operating_system(mut self, value: impl Into<String>) -> Self {
+        self.operating_system = value.into();
+        self
+    }
+
+    /// Returns this value with [`NodeSystemInfo::os_image`] set to the given value.
+    #[must_use]
+    pub fn with_os_image(mut self, value: impl Into<String>) -> Self {
+        self.os_image = value.into();
+        self
+    }
+
+    /// Returns this value with [`NodeSystemInfo::system_uuid`] set to the given value.
+    #[must_use]
+    pub fn with_system_uuid(mut self, value: impl Into<String>) -> Self {
+        self.system_uuid = value.into();
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for NodeSystemInfo {}
+
+impl NodeSystemInfo {
+    const FIELDS: &'static [&'static str] = &[
+        "architecture",
+        "bootID",
+        "containerRuntimeVersion",
+        "kernelVersion",
+        "kubeProxyVersion",
+        "kubeletVersion",
+        "machineID",
+        "operatingSystem",
+        "osImage",
+        "systemUUID",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for NodeSystemInfo {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -104,58 +164,171 @@ impl<'de> crate::serde::Deserialize<'de> for NodeSystemInfo {
                 let mut value_os_image: Option<String> = None;
                 let mut value_system_uuid: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NodeSystemInfo", NodeSystemInfo::FIELDS))? {
                     match key {
-                        Field::Key_architecture => value_architecture = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_boot_id => value_boot_id = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_container_runtime_version => value_container_runtime_version = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_kernel_version => value_kernel_version = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_kube_proxy_version => value_kube_proxy_version = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_kubelet_version => value_kubelet_version = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_machine_id => value_machine_id = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_operating_system => value_operating_system = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_os_image => value_os_image = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_system_uuid => value_system_uuid = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_architecture = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_boot_id = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_container_runtime_version = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_kernel_version = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(4) => value_kube_proxy_version = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(5) => value_kubelet_version = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(6) => value_machine_id = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(7) => value_operating_system = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(8) => value_os_image = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(9) => value_system_uuid = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(NodeSystemInfo {
-                    architecture: value_architecture.ok_or_else(|| crate::serde::de::Error::missing_field("architecture"))?,
-                    boot_id: value_boot_id.ok_or_else(|| crate::serde::de::Error::missing_field("bootID"))?,
-                    container_runtime_version: value_container_runtime_version.ok_or_else(|| crate::serde::de::Error::missing_field("containerRuntimeVersion"))?,
-                    kernel_version: value_kernel_version.ok_or_else(|| crate::serde::de::Error::missing_field("kernelVersion"))?,
-                    kube_proxy_version: value_kube_proxy_version.ok_or_else(|| crate::serde::de::Error::missing_field("kubeProxyVersion"))?,
-                    kubelet_version: value_kubelet_version.ok_or_else(|| crate::serde::de::Error::missing_field("kubeletVersion"))?,
-                    machine_id: value_machine_id.ok_or_else(|| crate::serde::de::Error::missing_field("machineID"))?,
-                    operating_system: value_operating_system.ok_or_else(|| crate::serde::de::Error::missing_field("operatingSystem"))?,
-                    os_image: value_os_image.ok_or_else(|| crate::serde::de::Error::missing_field("osImage"))?,
-                    system_uuid: value_system_uuid.ok_or_else(|| crate::serde::de::Error::missing_field("systemUUID"))?,
+                    architecture: value_architecture.ok_or_else(|| crate::serde2::missing_field("NodeSystemInfo", "architecture", &["architecture", "bootID", "containerRuntimeVersion", "kernelVersion", "kubeProxyVersion", "kubeletVersion", "machineID", "operatingSystem", "osImage", "systemUUID"]))?,
+                    boot_id: value_boot_id.ok_or_else(|| crate::serde2::missing_field("NodeSystemInfo", "bootID", &["architecture", "bootID", "containerRuntimeVersion", "kernelVersion", "kubeProxyVersion", "kubeletVersion", "machineID", "operatingSystem", "osImage", "systemUUID"]))?,
+                    container_runtime_version: value_container_runtime_version.ok_or_else(|| crate::serde2::missing_field("NodeSystemInfo", "containerRuntimeVersion", &["architecture", "bootID", "containerRuntimeVersion", "kernelVersion", "kubeProxyVersion", "kubeletVersion", "machineID", "operatingSystem", "osImage", "systemUUID"]))?,
+                    kernel_version: value_kernel_version.ok_or_else(|| crate::serde2::missing_field("NodeSystemInfo", "kernelVersion", &["architecture", "bootID", "containerRuntimeVersion", "kernelVersion", "kubeProxyVersion", "kubeletVersion", "machineID", "operatingSystem", "osImage", "systemUUID"]))?,
+                    kube_proxy_version: value_kube_proxy_version.ok_or_else(|| crate::serde2::missing_field("NodeSystemInfo", "kubeProxyVersion", &["architecture", "bootID", "containerRuntimeVersion", "kernelVersion", "kubeProxyVersion", "kubeletVersion", "machineID", "operatingSystem", "osImage", "systemUUID"]))?,
+                    kubelet_version: value_kubelet_version.ok_or_else(|| crate::serde2::missing_field("NodeSystemInfo", "kubeletVersion", &["architecture", "bootID", "containerRuntimeVersion", "kernelVersion", "kubeProxyVersion", "kubeletVersion", "machineID", "operatingSystem", "osImage", "systemUUID"]))?,
+                    machine_id: value_machine_id.ok_or_else(|| crate::serde2::missing_field("NodeSystemInfo", "machineID", &["architecture", "bootID", "containerRuntimeVersion", "kernelVersion", "kubeProxyVersion", "kubeletVersion", "machineID", "operatingSystem", "osImage", "systemUUID"]))?,
+                    operating_system: value_operating_system.ok_or_else(|| crate::serde2::missing_field("NodeSystemInfo", "operatingSystem", &["architecture", "bootID", "containerRuntimeVersion", "kernelVersion", "kubeProxyVersion", "kubeletVersion", "machineID", "operatingSystem", "osImage", "systemUUID"]))?,
+                    os_image: value_os_image.ok_or_else(|| crate::serde2::missing_field("NodeSystemInfo", "osImage", &["architecture", "bootID", "containerRuntimeVersion", "kernelVersion", "kubeProxyVersion", "kubeletVersion", "machineID", "operatingSystem", "osImage", "systemUUID"]))?,
+                    system_uuid: value_system_uuid.ok_or_else(|| crate::serde2::missing_field("NodeSystemInfo", "systemUUID", &["architecture", "bootID", "containerRuntimeVersion", "kernelVersion", "kubeProxyVersion", "kubeletVersion", "machineID", "operatingSystem", "osImage", "systemUUID"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(NodeSystemInfo {
+                    architecture: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    boot_id: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    container_runtime_version: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    kernel_version: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    kube_proxy_version: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    kubelet_version: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    machine_id: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    operating_system: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                    os_image: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?,
+                    system_uuid: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "NodeSystemInfo",
-            &[
-                "architecture",
-                "bootID",
-                "containerRuntimeVersion",
-                "kernelVersion",
-                "kubeProxyVersion",
-                "kubeletVersion",
-                "machineID",
-                "operatingSystem",
-                "osImage",
-                "systemUUID",
-            ],
+            NodeSystemInfo::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, NodeSystemInfo> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("NodeSystemInfo")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_architecture = false;
+                let mut has_boot_id = false;
+                let mut has_container_runtime_version = false;
+                let mut has_kernel_version = false;
+                let mut has_kube_proxy_version = false;
+                let mut has_kubelet_version = false;
+                let mut has_machine_id = false;
+                let mut has_operating_system = false;
+                let mut has_os_image = false;
+                let mut has_system_uuid = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NodeSystemInfo", NodeSystemInfo::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.architecture))?; has_architecture = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.boot_id))?; has_boot_id = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.container_runtime_version))?; has_container_runtime_version = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.kernel_version))?; has_kernel_version = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.kube_proxy_version))?; has_kube_proxy_version = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.kubelet_version))?; has_kubelet_version = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.machine_id))?; has_machine_id = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.operating_system))?; has_operating_system = true; },
+                        Some(8) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.os_image))?; has_os_image = true; },
+                        Some(9) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.system_uuid))?; has_system_uuid = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_architecture {
+                    return Err(crate::serde2::missing_field("NodeSystemInfo", "architecture", &["architecture", "bootID", "containerRuntimeVersion", "kernelVersion", "kubeProxyVersion", "kubeletVersion", "machineID", "operatingSystem", "osImage", "systemUUID"]));
+                }
+                if !has_boot_id {
+                    return Err(crate::serde2::missing_field("NodeSystemInfo", "bootID", &["architecture", "bootID", "containerRuntimeVersion", "kernelVersion", "kubeProxyVersion", "kubeletVersion", "machineID", "operatingSystem", "osImage", "systemUUID"]));
+                }
+                if !has_container_runtime_version {
+                    return Err(crate::serde2::missing_field("NodeSystemInfo", "containerRuntimeVersion", &["architecture", "bootID", "containerRuntimeVersion", "kernelVersion", "kubeProxyVersion", "kubeletVersion", "machineID", "operatingSystem", "osImage", "systemUUID"]));
+                }
+                if !has_kernel_version {
+                    return Err(crate::serde2::missing_field("NodeSystemInfo", "kernelVersion", &["architecture", "bootID", "containerRuntimeVersion", "kernelVersion", "kubeProxyVersion", "kubeletVersion", "machineID", "operatingSystem", "osImage", "systemUUID"]));
+                }
+                if !has_kube_proxy_version {
+                    return Err(crate::serde2::missing_field("NodeSystemInfo", "kubeProxyVersion", &["architecture", "bootID", "containerRuntimeVersion", "kernelVersion", "kubeProxyVersion", "kubeletVersion", "machineID", "operatingSystem", "osImage", "systemUUID"]));
+                }
+                if !has_kubelet_version {
+                    return Err(crate::serde2::missing_field("NodeSystemInfo", "kubeletVersion", &["architecture", "bootID", "containerRuntimeVersion", "kernelVersion", "kubeProxyVersion", "kubeletVersion", "machineID", "operatingSystem", "osImage", "systemUUID"]));
+                }
+                if !has_machine_id {
+                    return Err(crate::serde2::missing_field("NodeSystemInfo", "machineID", &["architecture", "bootID", "containerRuntimeVersion", "kernelVersion", "kubeProxyVersion", "kubeletVersion", "machineID", "operatingSystem", "osImage", "systemUUID"]));
+                }
+                if !has_operating_system {
+                    return Err(crate::serde2::missing_field("NodeSystemInfo", "operatingSystem", &["architecture", "bootID", "containerRuntimeVersion", "kernelVersion", "kubeProxyVersion", "kubeletVersion", "machineID", "operatingSystem", "osImage", "systemUUID"]));
+                }
+                if !has_os_image {
+                    return Err(crate::serde2::missing_field("NodeSystemInfo", "osImage", &["architecture", "bootID", "containerRuntimeVersion", "kernelVersion", "kubeProxyVersion", "kubeletVersion", "machineID", "operatingSystem", "osImage", "systemUUID"]));
+                }
+                if !has_system_uuid {
+                    return Err(crate::serde2::missing_field("NodeSystemInfo", "systemUUID", &["architecture", "bootID", "containerRuntimeVersion", "kernelVersion", "kubeProxyVersion", "kubeletVersion", "machineID", "operatingSystem", "osImage", "systemUUID"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.architecture))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.boot_id))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.container_runtime_version))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.kernel_version))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.kube_proxy_version))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.kubelet_version))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.machine_id))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.operating_system))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.os_image))?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.system_uuid))?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "NodeSystemInfo",
+            NodeSystemInfo::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for NodeSystemInfo {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("NodeSystemInfo", 10)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "architecture", &self.architecture)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "bootID", &self.boot_id)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "containerRuntimeVersion", &self.container_runtime_version)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kernelVersion", &self.kernel_version)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kubeProxyVersion", &self.kube_proxy_version)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kubeletVersion", &self.kubelet_version)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "machineID", &self.machine_id)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "operatingSystem", &self.operating_system)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "osImage", &self.os_image)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "systemUUID", &self.system_uuid)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "NodeSystemInfo",
             10,
@@ -174,10 +347,204 @@ impl crate::serde::Serialize for NodeSystemInfo {
     }
 }
 
+/// A builder of [`NodeSystemInfo`]. Created with [`NodeSystemInfo::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct NodeSystemInfoBuilder {
+    architecture: Option<String>,
+    boot_id: Option<String>,
+    container_runtime_version: Option<String>,
+    kernel_version: Option<String>,
+    kube_proxy_version: Option<String>,
+    kubelet_version: Option<String>,
+    machine_id: Option<String>,
+    operating_system: Option<String>,
+    os_image: Option<String>,
+    system_uuid: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl NodeSystemInfo {
+    /// Starts building a `NodeSystemInfo`. Its required fields must be set before [`NodeSystemInfoBuilder::build`] is called.
+    pub fn builder() -> NodeSystemInfoBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl NodeSystemInfoBuilder {
+    /// Sets [`NodeSystemInfo::architecture`].
+    pub fn architecture(mut self, value: impl Into<String>) -> Self {
+        self.architecture = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeSystemInfo::boot_id`].
+    pub fn boot_id(mut self, value: impl Into<String>) -> Self {
+        self.boot_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeSystemInfo::container_runtime_version`].
+    pub fn container_runtime_version(mut self, value: impl Into<String>) -> Self {
+        self.container_runtime_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeSystemInfo::kernel_version`].
+    pub fn kernel_version(mut self, value: impl Into<String>) -> Self {
+        self.kernel_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeSystemInfo::kube_proxy_version`].
+    pub fn kube_proxy_version(mut self, value: impl Into<String>) -> Self {
+        self.kube_proxy_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeSystemInfo::kubelet_version`].
+    pub fn kubelet_version(mut self, value: impl Into<String>) -> Self {
+        self.kubelet_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeSystemInfo::machine_id`].
+    pub fn machine_id(mut self, value: impl Into<String>) -> Self {
+        self.machine_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeSystemInfo::operating_system`].
+    pub fn operating_system(mut self, value: impl Into<String>) -> Self {
+        self.operating_system = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeSystemInfo::os_image`].
+    pub fn os_image(mut self, value: impl Into<String>) -> Self {
+        self.os_image = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeSystemInfo::system_uuid`].
+    pub fn system_uuid(mut self, value: impl Into<String>) -> Self {
+        self.system_uuid = Some(value.into());
+        self
+    }
+
+    /// Builds the `NodeSystemInfo`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<NodeSystemInfo, crate::MissingFieldError> {
+        Ok(NodeSystemInfo {
+            architecture: self.architecture.ok_or_else(|| crate::MissingFieldError::new("NodeSystemInfo", "architecture"))?,
+            boot_id: self.boot_id.ok_or_else(|| crate::MissingFieldError::new("NodeSystemInfo", "bootID"))?,
+            container_runtime_version: self.container_runtime_version.ok_or_else(|| crate::MissingFieldError::new("NodeSystemInfo", "containerRuntimeVersion"))?,
+            kernel_version: self.kernel_version.ok_or_else(|| crate::MissingFieldError::new("NodeSystemInfo", "kernelVersion"))?,
+            kube_proxy_version: self.kube_proxy_version.ok_or_else(|| crate::MissingFieldError::new("NodeSystemInfo", "kubeProxyVersion"))?,
+            kubelet_version: self.kubelet_version.ok_or_else(|| crate::MissingFieldError::new("NodeSystemInfo", "kubeletVersion"))?,
+            machine_id: self.machine_id.ok_or_else(|| crate::MissingFieldError::new("NodeSystemInfo", "machineID"))?,
+            operating_system: self.operating_system.ok_or_else(|| crate::MissingFieldError::new("NodeSystemInfo", "operatingSystem"))?,
+            os_image: self.os_image.ok_or_else(|| crate::MissingFieldError::new("NodeSystemInfo", "osImage"))?,
+            system_uuid: self.system_uuid.ok_or_else(|| crate::MissingFieldError::new("NodeSystemInfo", "systemUUID"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`NodeSystemInfo`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NodeSystemInfoApplyConfiguration {
+    /// See [`NodeSystemInfo::architecture`].
+    pub architecture: Option<String>,
+
+    /// See [`NodeSystemInfo::boot_id`].
+    pub boot_id: Option<String>,
+
+    /// See [`NodeSystemInfo::container_runtime_version`].
+    pub container_runtime_version: Option<String>,
+
+    /// See [`NodeSystemInfo::kernel_version`].
+    pub kernel_version: Option<String>,
+
+    /// See [`NodeSystemInfo::kube_proxy_version`].
+    pub kube_proxy_version: Option<String>,
+
+    /// See [`NodeSystemInfo::kubelet_version`].
+    pub kubelet_version: Option<String>,
+
+    /// See [`NodeSystemInfo::machine_id`].
+    pub machine_id: Option<String>,
+
+    /// See [`NodeSystemInfo::operating_system`].
+    pub operating_system: Option<String>,
+
+    /// See [`NodeSystemInfo::os_image`].
+    pub os_image: Option<String>,
+
+    /// See [`NodeSystemInfo::system_uuid`].
+    pub system_uuid: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for NodeSystemInfoApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "NodeSystemInfoApplyConfiguration",
+            self.architecture.as_ref().map_or(0, |_| 1) +
+            self.boot_id.as_ref().map_or(0, |_| 1) +
+            self.container_runtime_version.as_ref().map_or(0, |_| 1) +
+            self.kernel_version.as_ref().map_or(0, |_| 1) +
+            self.kube_proxy_version.as_ref().map_or(0, |_| 1) +
+            self.kubelet_version.as_ref().map_or(0, |_| 1) +
+            self.machine_id.as_ref().map_or(0, |_| 1) +
+            self.operating_system.as_ref().map_or(0, |_| 1) +
+            self.os_image.as_ref().map_or(0, |_| 1) +
+            self.system_uuid.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.architecture {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "architecture", value)?;
+        }
+        if let Some(value) = &self.boot_id {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "bootID", value)?;
+        }
+        if let Some(value) = &self.container_runtime_version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "containerRuntimeVersion", value)?;
+        }
+        if let Some(value) = &self.kernel_version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kernelVersion", value)?;
+        }
+        if let Some(value) = &self.kube_proxy_version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kubeProxyVersion", value)?;
+        }
+        if let Some(value) = &self.kubelet_version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kubeletVersion", value)?;
+        }
+        if let Some(value) = &self.machine_id {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "machineID", value)?;
+        }
+        if let Some(value) = &self.operating_system {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "operatingSystem", value)?;
+        }
+        if let Some(value) = &self.os_image {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "osImage", value)?;
+        }
+        if let Some(value) = &self.system_uuid {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "systemUUID", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NodeSystemInfo {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.NodeSystemInfo".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::NodeSystemInfo".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.NodeSystemInfo".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -188,8 +555,9 @@ impl crate::schemars::JsonSchema for NodeSystemInfo {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "architecture".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -199,8 +567,8 @@ impl crate::schemars::JsonSchema for NodeSystemInfo {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "bootID".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -210,8 +578,8 @@ impl crate::schemars::JsonSchema for NodeSystemInfo {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "containerRuntimeVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -221,8 +589,8 @@ impl crate::schemars::JsonSchema for NodeSystemInfo {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "kernelVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -232,8 +600,8 @@ impl crate::schemars::JsonSchema for NodeSystemInfo {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "kubeProxyVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -243,8 +611,8 @@ impl crate::schemars::JsonSchema for NodeSystemInfo {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "kubeletVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -254,8 +622,8 @@ impl crate::schemars::JsonSchema for NodeSystemInfo {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "machineID".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -265,8 +633,8 @@ impl crate::schemars::JsonSchema for NodeSystemInfo {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "operatingSystem".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -276,8 +644,8 @@ impl crate::schemars::JsonSchema for NodeSystemInfo {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "osImage".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -287,8 +655,8 @@ impl crate::schemars::JsonSchema for NodeSystemInfo {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "systemUUID".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -298,8 +666,9 @@ impl crate::schemars::JsonSchema for NodeSystemInfo {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "architecture",
                     "bootID",
@@ -318,3 +687,21 @@ impl crate::schemars::JsonSchema for NodeSystemInfo {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for NodeSystemInfo {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(NodeSystemInfo {
+            architecture: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            boot_id: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            container_runtime_version: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            kernel_version: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            kube_proxy_version: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            kubelet_version: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            machine_id: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            operating_system: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            os_image: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            system_uuid: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.PersistentVolumeClaimStatus
 
 /// PersistentVolumeClaimStatus is the current status of a persistent volume claim.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct PersistentVolumeClaimStatus {
     /// AccessModes contains the actual access modes the volume backing the PVC has. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#access-modes-1
     pub access_modes: Option<Vec<String>>,
@@ -16,43 +16,53 @@ pub struct PersistentVolumeClaimStatus {
     pub phase: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for PersistentVolumeClaimStatus {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_access_modes,
-            Key_capacity,
-            Key_conditions,
-            Key_phase,
-            Other,
-        }
+impl PersistentVolumeClaimStatus {
+    /// Returns this value with [`PersistentVolumeClaimStatus::access_modes`] set to the given value.
+    #[must_use]
+    pub fn with_access_modes(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.access_modes = Some(value.into());
+        self
+    }
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+    /// Returns this value with [`PersistentVolumeClaimStatus::capacity`] set to the given value.
+    #[must_use]
+    pub fn with_capacity(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>) -> Self {
+        self.capacity = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`PersistentVolumeClaimStatus::conditions`] set to the given value.
+    #[must_use]
+    pub fn with_conditions(mut self, value: impl Into<Vec<crate::api::core::v1::PersistentVolumeClaimCondition>>) -> Self {
+        self.conditions = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`PersistentVolumeClaimStatus::phase`] set to the given value.
+    #[must_use]
+    pub fn with_phase(mut self, value: impl Into<String>) -> Self {
+        self.phase = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "accessModes" => Field::Key_access_modes,
-                            "capacity" => Field::Key_capacity,
-                            "conditions" => Field::Key_conditions,
-                            "phase" => Field::Key_phase,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for PersistentVolumeClaimStatus {
+    const FIELD_PATCH_STRATEGIES: &'static [crate::FieldPatchStrategy] = &[
+        crate::FieldPatchStrategy { field: "conditions", merge: true, retain_keys: false, merge_key: Some("type") },
+    ];
+}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl PersistentVolumeClaimStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "accessModes",
+        "capacity",
+        "conditions",
+        "phase",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for PersistentVolumeClaimStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -68,13 +78,13 @@ impl<'de> crate::serde::Deserialize<'de> for PersistentVolumeClaimStatus {
                 let mut value_conditions: Option<Vec<crate::api::core::v1::PersistentVolumeClaimCondition>> = None;
                 let mut value_phase: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PersistentVolumeClaimStatus", PersistentVolumeClaimStatus::FIELDS))? {
                     match key {
-                        Field::Key_access_modes => value_access_modes = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_capacity => value_capacity = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_conditions => value_conditions = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_phase => value_phase = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_access_modes = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_capacity = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_conditions = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_phase = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -85,23 +95,94 @@ impl<'de> crate::serde::Deserialize<'de> for PersistentVolumeClaimStatus {
                     phase: value_phase,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(PersistentVolumeClaimStatus {
+                    access_modes: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    capacity: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    conditions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    phase: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "PersistentVolumeClaimStatus",
-            &[
-                "accessModes",
-                "capacity",
-                "conditions",
-                "phase",
-            ],
+            PersistentVolumeClaimStatus::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, PersistentVolumeClaimStatus> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("PersistentVolumeClaimStatus")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_access_modes = false;
+                let mut has_capacity = false;
+                let mut has_conditions = false;
+                let mut has_phase = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PersistentVolumeClaimStatus", PersistentVolumeClaimStatus::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.access_modes))?; has_access_modes = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.capacity))?; has_capacity = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.conditions))?; has_conditions = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.phase))?; has_phase = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_access_modes {
+                    self.0.access_modes = None;
+                }
+                if !has_capacity {
+                    self.0.capacity = None;
+                }
+                if !has_conditions {
+                    self.0.conditions = None;
+                }
+                if !has_phase {
+                    self.0.phase = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.access_modes))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.capacity))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.conditions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.phase))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "PersistentVolumeClaimStatus",
+            PersistentVolumeClaimStatus::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for PersistentVolumeClaimStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("PersistentVolumeClaimStatus", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "accessModes", &self.access_modes)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "capacity", &self.capacity)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conditions", &self.conditions)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "phase", &self.phase)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "PersistentVolumeClaimStatus",
             self.access_modes.as_ref().map_or(0, |_| 1) +
@@ -125,10 +206,114 @@ impl crate::serde::Serialize for PersistentVolumeClaimStatus {
     }
 }
 
+/// A builder of [`PersistentVolumeClaimStatus`]. Created with [`PersistentVolumeClaimStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct PersistentVolumeClaimStatusBuilder {
+    access_modes: Option<Vec<String>>,
+    capacity: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>,
+    conditions: Option<Vec<crate::api::core::v1::PersistentVolumeClaimCondition>>,
+    phase: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl PersistentVolumeClaimStatus {
+    /// Starts building a `PersistentVolumeClaimStatus`. Its required fields must be set before [`PersistentVolumeClaimStatusBuilder::build`] is called.
+    pub fn builder() -> PersistentVolumeClaimStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl PersistentVolumeClaimStatusBuilder {
+    /// Sets [`PersistentVolumeClaimStatus::access_modes`].
+    pub fn access_modes(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.access_modes = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeClaimStatus::capacity`].
+    pub fn capacity(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>) -> Self {
+        self.capacity = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeClaimStatus::conditions`].
+    pub fn conditions(mut self, value: impl Into<Vec<crate::api::core::v1::PersistentVolumeClaimCondition>>) -> Self {
+        self.conditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeClaimStatus::phase`].
+    pub fn phase(mut self, value: impl Into<String>) -> Self {
+        self.phase = Some(value.into());
+        self
+    }
+
+    /// Builds the `PersistentVolumeClaimStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<PersistentVolumeClaimStatus, crate::MissingFieldError> {
+        Ok(PersistentVolumeClaimStatus {
+            access_modes: self.access_modes,
+            capacity: self.capacity,
+            conditions: self.conditions,
+            phase: self.phase,
+        })
+    }
+}
+
+/// The apply configuration of [`PersistentVolumeClaimStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PersistentVolumeClaimStatusApplyConfiguration {
+    /// See [`PersistentVolumeClaimStatus::access_modes`].
+    pub access_modes: Option<Vec<String>>,
+
+    /// See [`PersistentVolumeClaimStatus::capacity`].
+    pub capacity: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>,
+
+    /// See [`PersistentVolumeClaimStatus::conditions`].
+    pub conditions: Option<Vec<crate::api::core::v1::PersistentVolumeClaimConditionApplyConfiguration>>,
+
+    /// See [`PersistentVolumeClaimStatus::phase`].
+    pub phase: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for PersistentVolumeClaimStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "PersistentVolumeClaimStatusApplyConfiguration",
+            self.access_modes.as_ref().map_or(0, |_| 1) +
+            self.capacity.as_ref().map_or(0, |_| 1) +
+            self.conditions.as_ref().map_or(0, |_| 1) +
+            self.phase.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.access_modes {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "accessModes", value)?;
+        }
+        if let Some(value) = &self.capacity {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "capacity", value)?;
+        }
+        if let Some(value) = &self.conditions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conditions", value)?;
+        }
+        if let Some(value) = &self.phase {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "phase", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PersistentVolumeClaimStatus {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.PersistentVolumeClaimStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::PersistentVolumeClaimStatus".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.PersistentVolumeClaimStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -139,8 +324,9 @@ impl crate::schemars::JsonSchema for PersistentVolumeClaimStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "accessModes".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -159,8 +345,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeClaimStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "capacity".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -174,8 +360,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeClaimStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "conditions".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -189,8 +375,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeClaimStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "phase".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -200,11 +386,24 @@ impl crate::schemars::JsonSchema for PersistentVolumeClaimStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for PersistentVolumeClaimStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(PersistentVolumeClaimStatus {
+            access_modes: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            capacity: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            conditions: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            phase: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.PersistentVolumeClaimVolumeSource
 
 /// PersistentVolumeClaimVolumeSource references the user's PVC in the same namespace. This volume finds the bound PV and mounts that volume for the pod. A PersistentVolumeClaimVolumeSource is, essentially, a wrapper around another type of volume that is owned by someone else (the system).
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct PersistentVolumeClaimVolumeSource {
     /// ClaimName is the name of a PersistentVolumeClaim in the same namespace as the pod using this volume. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#persistentvolumeclaims
     pub claim_name: String,
@@ -10,39 +10,43 @@ pub struct PersistentVolumeClaimVolumeSource {
     pub read_only: Option<bool>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for PersistentVolumeClaimVolumeSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_claim_name,
-            Key_read_only,
-            Other,
+impl PersistentVolumeClaimVolumeSource {
+    /// Constructs a `PersistentVolumeClaimVolumeSource` from its required fields. Its optional fields are unset.
+    pub fn new(claim_name: impl Into<String>) -> Self {
+        PersistentVolumeClaimVolumeSource {
+            claim_name: claim_name.into(),
+            read_only: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl PersistentVolumeClaimVolumeSource {
+    /// Returns this value with [`PersistentVolumeClaimVolumeSource::claim_name`] set to the given value.
+    #[must_use]
+    pub fn with_claim_name(mut self, value: impl Into<String>) -> Self {
+        self.claim_name = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`PersistentVolumeClaimVolumeSource::read_only`] set to the given value.
+    #[must_use]
+    pub fn with_read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for PersistentVolumeClaimVolumeSource {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "claimName" => Field::Key_claim_name,
-                            "readOnly" => Field::Key_read_only,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl PersistentVolumeClaimVolumeSource {
+    const FIELDS: &'static [&'static str] = &[
+        "claimName",
+        "readOnly",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for PersistentVolumeClaimVolumeSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +60,91 @@ impl<'de> crate::serde::Deserialize<'de> for PersistentVolumeClaimVolumeSource {
                 let mut value_claim_name: Option<String> = None;
                 let mut value_read_only: Option<bool> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PersistentVolumeClaimVolumeSource", PersistentVolumeClaimVolumeSource::FIELDS))? {
                     match key {
-                        Field::Key_claim_name => value_claim_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_read_only => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_claim_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(PersistentVolumeClaimVolumeSource {
-                    claim_name: value_claim_name.ok_or_else(|| crate::serde::de::Error::missing_field("claimName"))?,
+                    claim_name: value_claim_name.ok_or_else(|| crate::serde2::missing_field("PersistentVolumeClaimVolumeSource", "claimName", &["claimName"]))?,
                     read_only: value_read_only,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(PersistentVolumeClaimVolumeSource {
+                    claim_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    read_only: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "PersistentVolumeClaimVolumeSource",
-            &[
-                "claimName",
-                "readOnly",
-            ],
+            PersistentVolumeClaimVolumeSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, PersistentVolumeClaimVolumeSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("PersistentVolumeClaimVolumeSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_claim_name = false;
+                let mut has_read_only = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PersistentVolumeClaimVolumeSource", PersistentVolumeClaimVolumeSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.claim_name))?; has_claim_name = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.read_only))?; has_read_only = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_claim_name {
+                    return Err(crate::serde2::missing_field("PersistentVolumeClaimVolumeSource", "claimName", &["claimName"]));
+                }
+                if !has_read_only {
+                    self.0.read_only = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.claim_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.read_only))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "PersistentVolumeClaimVolumeSource",
+            PersistentVolumeClaimVolumeSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for PersistentVolumeClaimVolumeSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("PersistentVolumeClaimVolumeSource", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "claimName", &self.claim_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", &self.read_only)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "PersistentVolumeClaimVolumeSource",
             1 +
@@ -97,10 +158,84 @@ impl crate::serde::Serialize for PersistentVolumeClaimVolumeSource {
     }
 }
 
+/// A builder of [`PersistentVolumeClaimVolumeSource`]. Created with [`PersistentVolumeClaimVolumeSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct PersistentVolumeClaimVolumeSourceBuilder {
+    claim_name: Option<String>,
+    read_only: Option<bool>,
+}
+
+#[cfg(feature = "builder")]
+impl PersistentVolumeClaimVolumeSource {
+    /// Starts building a `PersistentVolumeClaimVolumeSource`. Its required fields must be set before [`PersistentVolumeClaimVolumeSourceBuilder::build`] is called.
+    pub fn builder() -> PersistentVolumeClaimVolumeSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl PersistentVolumeClaimVolumeSourceBuilder {
+    /// Sets [`PersistentVolumeClaimVolumeSource::claim_name`].
+    pub fn claim_name(mut self, value: impl Into<String>) -> Self {
+        self.claim_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeClaimVolumeSource::read_only`].
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+
+    /// Builds the `PersistentVolumeClaimVolumeSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<PersistentVolumeClaimVolumeSource, crate::MissingFieldError> {
+        Ok(PersistentVolumeClaimVolumeSource {
+            claim_name: self.claim_name.ok_or_else(|| crate::MissingFieldError::new("PersistentVolumeClaimVolumeSource", "claimName"))?,
+            read_only: self.read_only,
+        })
+    }
+}
+
+/// The apply configuration of [`PersistentVolumeClaimVolumeSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PersistentVolumeClaimVolumeSourceApplyConfiguration {
+    /// See [`PersistentVolumeClaimVolumeSource::claim_name`].
+    pub claim_name: Option<String>,
+
+    /// See [`PersistentVolumeClaimVolumeSource::read_only`].
+    pub read_only: Option<bool>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for PersistentVolumeClaimVolumeSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "PersistentVolumeClaimVolumeSourceApplyConfiguration",
+            self.claim_name.as_ref().map_or(0, |_| 1) +
+            self.read_only.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.claim_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "claimName", value)?;
+        }
+        if let Some(value) = &self.read_only {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PersistentVolumeClaimVolumeSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.PersistentVolumeClaimVolumeSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::PersistentVolumeClaimVolumeSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.PersistentVolumeClaimVolumeSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -111,8 +246,9 @@ impl crate::schemars::JsonSchema for PersistentVolumeClaimVolumeSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "claimName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -122,8 +258,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeClaimVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "readOnly".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -133,8 +269,9 @@ impl crate::schemars::JsonSchema for PersistentVolumeClaimVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "claimName",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -144,3 +281,13 @@ impl crate::schemars::JsonSchema for PersistentVolumeClaimVolumeSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for PersistentVolumeClaimVolumeSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(PersistentVolumeClaimVolumeSource {
+            claim_name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            read_only: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
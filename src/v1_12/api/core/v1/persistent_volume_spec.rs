@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.PersistentVolumeSpec
 
 /// PersistentVolumeSpec is the specification of a persistent volume.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct PersistentVolumeSpec {
     /// AccessModes contains all ways the volume can be mounted. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#access-modes
     pub access_modes: Option<Vec<String>>,
@@ -64,7 +64,7 @@ pub struct PersistentVolumeSpec {
     pub node_affinity: Option<crate::api::core::v1::VolumeNodeAffinity>,
 
     /// What happens to a persistent volume when released from its claim. Valid options are Retain (default for manually created PersistentVolumes), Delete (default for dynamically provisioned PersistentVolumes), and Recycle (deprecated). Recycle must be supported by the volume plugin underlying this PersistentVolume. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#reclaiming
-    pub persistent_volume_reclaim_policy: Option<String>,
+    pub persistent_volume_reclaim_policy: Option<crate::api::core::v1::PersistentVolumeReclaimPolicy>,
 
     /// PhotonPersistentDisk represents a PhotonController persistent disk attached and mounted on kubelets host machine
     pub photon_persistent_disk: Option<crate::api::core::v1::PhotonPersistentDiskVolumeSource>,
@@ -94,95 +94,257 @@ pub struct PersistentVolumeSpec {
     pub vsphere_volume: Option<crate::api::core::v1::VsphereVirtualDiskVolumeSource>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for PersistentVolumeSpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_access_modes,
-            Key_aws_elastic_block_store,
-            Key_azure_disk,
-            Key_azure_file,
-            Key_capacity,
-            Key_cephfs,
-            Key_cinder,
-            Key_claim_ref,
-            Key_csi,
-            Key_fc,
-            Key_flex_volume,
-            Key_flocker,
-            Key_gce_persistent_disk,
-            Key_glusterfs,
-            Key_host_path,
-            Key_iscsi,
-            Key_local,
-            Key_mount_options,
-            Key_nfs,
-            Key_node_affinity,
-            Key_persistent_volume_reclaim_policy,
-            Key_photon_persistent_disk,
-            Key_portworx_volume,
-            Key_quobyte,
-            Key_rbd,
-            Key_scale_io,
-            Key_storage_class_name,
-            Key_storageos,
-            Key_volume_mode,
-            Key_vsphere_volume,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl PersistentVolumeSpec {
+    /// Returns this value with [`PersistentVolumeSpec::access_modes`] set to the given value.
+    #[must_use]
+    pub fn with_access_modes(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.access_modes = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "accessModes" => Field::Key_access_modes,
-                            "awsElasticBlockStore" => Field::Key_aws_elastic_block_store,
-                            "azureDisk" => Field::Key_azure_disk,
-                            "azureFile" => Field::Key_azure_file,
-                            "capacity" => Field::Key_capacity,
-                            "cephfs" => Field::Key_cephfs,
-                            "cinder" => Field::Key_cinder,
-                            "claimRef" => Field::Key_claim_ref,
-                            "csi" => Field::Key_csi,
-                            "fc" => Field::Key_fc,
-                            "flexVolume" => Field::Key_flex_volume,
-                            "flocker" => Field::Key_flocker,
-                            "gcePersistentDisk" => Field::Key_gce_persistent_disk,
-                            "glusterfs" => Field::Key_glusterfs,
-                            "hostPath" => Field::Key_host_path,
-                            "iscsi" => Field::Key_iscsi,
-                            "local" => Field::Key_local,
-                            "mountOptions" => Field::Key_mount_options,
-                            "nfs" => Field::Key_nfs,
-                            "nodeAffinity" => Field::Key_node_affinity,
-                            "persistentVolumeReclaimPolicy" => Field::Key_persistent_volume_reclaim_policy,
-                            "photonPersistentDisk" => Field::Key_photon_persistent_disk,
-                            "portworxVolume" => Field::Key_portworx_volume,
-                            "quobyte" => Field::Key_quobyte,
-                            "rbd" => Field::Key_rbd,
-                            "scaleIO" => Field::Key_scale_io,
-                            "storageClassName" => Field::Key_storage_class_name,
-                            "storageos" => Field::Key_storageos,
-                            "volumeMode" => Field::Key_volume_mode,
-                            "vsphereVolume" => Field::Key_vsphere_volume,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`PersistentVolumeSpec::aws_elastic_block_store`] set to the given value.
+    #[must_use]
+    pub fn with_aws_elastic_block_store(mut self, value: impl Into<crate::api::core::v1::AWSElasticBlockStoreVolumeSource>) -> Self {
+        self.aws_elastic_block_store = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`PersistentVolumeSpec::azure_disk`] set to the given value.
+    #[must_use]
+    pub fn with_azure_disk(mut self, value: impl Into<crate::api::core::v1::AzureDiskVolumeSource>) -> Self {
+        self.azure_disk = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PersistentVolumeSpec::azure_file`] set to the given value.
+    #[must_use]
+    pub fn with_azure_file(mut self, value: impl Into<crate::api::core::v1::AzureFilePersistentVolumeSource>) -> Self {
+        self.azure_file = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PersistentVolumeSpec::capacity`] set to the given value.
+    #[must_use]
+    pub fn with_capacity(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>) -> Self {
+        self.capacity = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PersistentVolumeSpec::cephfs`] set to the given value.
+    #[must_use]
+    pub fn with_cephfs(mut self, value: impl Into<crate::api::core::v1::CephFSPersistentVolumeSource>) -> Self {
+        self.cephfs = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PersistentVolumeSpec::cinder`] set to the given value.
+    #[must_use]
+    pub fn with_cinder(mut self, value: impl Into<crate::api::core::v1::CinderPersistentVolumeSource>) -> Self {
+        self.cinder = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PersistentVolumeSpec::claim_ref`] set to the given value.
+    #[must_use]
+    pub fn with_claim_ref(mut self, value: impl Into<crate::api::core::v1::ObjectReference>) -> Self {
+        self.claim_ref = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PersistentVolumeSpec::csi`] set to the given value.
+    #[must_use]
+    pub fn with_csi(mut self, value: impl Into<crate::api::core::v1::CSIPersistentVolumeSource>) -> Self {
+        self.csi = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PersistentVolumeSpec::fc`] set to the given value.
+    #[must_use]
+    pub fn with_fc(mut self, value: impl Into<crate::api::core::v1::FCVolumeSource>) -> Self {
+        self.fc = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PersistentVolumeSpec::flex_volume`] set to the given value.
+    #[must_use]
+    pub fn with_flex_volume(mut self, value: impl Into<crate::api::core::v1::FlexPersistentVolumeSource>) -> Self {
+        self.flex_volume = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PersistentVolumeSpec::flocker`] set to the given value.
+    #[must_use]
+    pub fn with_flocker(mut self, value: impl Into<crate::api::core::v1::FlockerVolumeSource>) -> Self {
+        self.flocker = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PersistentVolumeSpec::gce_persistent_disk`] set to the given value.
+    #[must_use]
+    pub fn with_gce_persistent_disk(mut self, value: impl Into<crate::api::core::v1::GCEPersistentDiskVolumeSource>) -> Self {
+        self.gce_persistent_disk = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PersistentVolumeSpec::glusterfs`] set to the given value.
+    #[must_use]
+    pub fn with_glusterfs(mut self, value: impl Into<crate::api::core::v1::GlusterfsVolumeSource>) -> Self {
+        self.glusterfs = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PersistentVolumeSpec::host_path`] set to the given value.
+    #[must_use]
+    pub fn with_host_path(mut self, value: impl Into<crate::api::core::v1::HostPathVolumeSource>) -> Self {
+        self.host_path = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PersistentVolumeSpec::iscsi`] set to the given value.
+    #[must_use]
+    pub fn with_iscsi(mut self, value: impl Into<crate::api::core::v1::ISCSIPersistentVolumeSource>) -> Self {
+        self.iscsi = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PersistentVolumeSpec::local`] set to the given value.
+    #[must_use]
+    pub fn with_local(mut self, value: impl Into<crate::api::core::v1::LocalVolumeSource>) -> Self {
+        self.local = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PersistentVolumeSpec::mount_options`] set to the given value.
+    #[must_use]
+    pub fn with_mount_options(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.mount_options = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PersistentVolumeSpec::nfs`] set to the given value.
+    #[must_use]
+    pub fn with_nfs(mut self, value: impl Into<crate::api::core::v1::NFSVolumeSource>) -> Self {
+        self.nfs = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PersistentVolumeSpec::node_affinity`] set to the given value.
+    #[must_use]
+    pub fn with_node_affinity(mut self, value: impl Into<crate::api::core::v1::VolumeNodeAffinity>) -> Self {
+        self.node_affinity = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PersistentVolumeSpec::persistent_volume_reclaim_policy`] set to the given value.
+    #[must_use]
+    pub fn with_persistent_volume_reclaim_policy(mut self, value: impl Into<crate::api::core::v1::PersistentVolumeReclaimPolicy>) -> Self {
+        self.persistent_volume_reclaim_policy = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PersistentVolumeSpec::photon_persistent_disk`] set to the given value.
+    #[must_use]
+    pub fn with_photon_persistent_disk(mut self, value: impl Into<crate::api::core::v1::PhotonPersistentDiskVolumeSource>) -> Self {
+        self.photon_persistent_disk = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PersistentVolumeSpec::portworx_volume`] set to the given value.
+    #[must_use]
+    pub fn with_portworx_volume(mut self, value: impl Into<crate::api::core::v1::PortworxVolumeSource>) -> Self {
+        self.portworx_volume = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PersistentVolumeSpec::quobyte`] set to the given value.
+    #[must_use]
+    pub fn with_quobyte(mut self, value: impl Into<crate::api::core::v1::QuobyteVolumeSource>) -> Self {
+        self.quobyte = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PersistentVolumeSpec::rbd`] set to the given value.
+    #[must_use]
+    pub fn with_rbd(mut self, value: impl Into<crate::api::core::v1::RBDPersistentVolumeSource>) -> Self {
+        self.rbd = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PersistentVolumeSpec::scale_io`] set to the given value.
+    #[must_use]
+    pub fn with_scale_io(mut self, value: impl Into<crate::api::core::v1::ScaleIOPersistentVolumeSource>) -> Self {
+        self.scale_io = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PersistentVolumeSpec::storage_class_name`] set to the given value.
+    #[must_use]
+    pub fn with_storage_class_name(mut self, value: impl Into<String>) -> Self {
+        self.storage_class_name = Some(value.into());
+        self
+    }
 
+    /// Returns this value with [`PersistentVolumeSpec::storageos`] set to the given value.
+    #[must_use]
+    pub fn with_storageos(mut self, value: impl Into<crate::api::core::v1::StorageOSPersistentVolumeSource>) -> Self {
+        self.storageos = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PersistentVolumeSpec::volume_mode`] set to the given value.
+    #[must_use]
+    pub fn with_volume_mode(mut self, value: impl Into<String>) -> Self {
+        self.volume_mode = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PersistentVolumeSpec::vsphere_volume`] set to the given value.
+    #[must_use]
+    pub fn with_vsphere_volume(mut self, value: impl Into<crate::api::core::v1::VsphereVirtualDiskVolumeSource>) -> Self {
+        self.vsphere_volume = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for PersistentVolumeSpec {}
+
+impl PersistentVolumeSpec {
+    const FIELDS: &'static [&'static str] = &[
+        "accessModes",
+        "awsElasticBlockStore",
+        "azureDisk",
+        "azureFile",
+        "capacity",
+        "cephfs",
+        "cinder",
+        "claimRef",
+        "csi",
+        "fc",
+        "flexVolume",
+        "flocker",
+        "gcePersistentDisk",
+        "glusterfs",
+        "hostPath",
+        "iscsi",
+        "local",
+        "mountOptions",
+        "nfs",
+        "nodeAffinity",
+        "persistentVolumeReclaimPolicy",
+        "photonPersistentDisk",
+        "portworxVolume",
+        "quobyte",
+        "rbd",
+        "scaleIO",
+        "storageClassName",
+        "storageos",
+        "volumeMode",
+        "vsphereVolume",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for PersistentVolumeSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -213,7 +375,7 @@ impl<'de> crate::serde::Deserialize<'de> for PersistentVolumeSpec {
                 let mut value_mount_options: Option<Vec<String>> = None;
                 let mut value_nfs: Option<crate::api::core::v1::NFSVolumeSource> = None;
                 let mut value_node_affinity: Option<crate::api::core::v1::VolumeNodeAffinity> = None;
-                let mut value_persistent_volume_reclaim_policy: Option<String> = None;
+                let mut value_persistent_volume_reclaim_policy: Option<crate::api::core::v1::PersistentVolumeReclaimPolicy> = None;
                 let mut value_photon_persistent_disk: Option<crate::api::core::v1::PhotonPersistentDiskVolumeSource> = None;
                 let mut value_portworx_volume: Option<crate::api::core::v1::PortworxVolumeSource> = None;
                 let mut value_quobyte: Option<crate::api::core::v1::QuobyteVolumeSource> = None;
@@ -224,39 +386,39 @@ impl<'de> crate::serde::Deserialize<'de> for PersistentVolumeSpec {
                 let mut value_volume_mode: Option<String> = None;
                 let mut value_vsphere_volume: Option<crate::api::core::v1::VsphereVirtualDiskVolumeSource> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PersistentVolumeSpec", PersistentVolumeSpec::FIELDS))? {
                     match key {
-                        Field::Key_access_modes => value_access_modes = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_aws_elastic_block_store => value_aws_elastic_block_store = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_azure_disk => value_azure_disk = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_azure_file => value_azure_file = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_capacity => value_capacity = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_cephfs => value_cephfs = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_cinder => value_cinder = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_claim_ref => value_claim_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_csi => value_csi = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_fc => value_fc = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_flex_volume => value_flex_volume = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_flocker => value_flocker = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_gce_persistent_disk => value_gce_persistent_disk = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_glusterfs => value_glusterfs = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_host_path => value_host_path = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_iscsi => value_iscsi = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_local => value_local = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_mount_options => value_mount_options = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_nfs => value_nfs = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_node_affinity => value_node_affinity = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_persistent_volume_reclaim_policy => value_persistent_volume_reclaim_policy = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_photon_persistent_disk => value_photon_persistent_disk = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_portworx_volume => value_portworx_volume = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_quobyte => value_quobyte = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_rbd => value_rbd = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_scale_io => value_scale_io = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_storage_class_name => value_storage_class_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_storageos => value_storageos = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_volume_mode => value_volume_mode = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_vsphere_volume => value_vsphere_volume = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_access_modes = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_aws_elastic_block_store = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_azure_disk = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_azure_file = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_capacity = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_cephfs = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_cinder = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(7) => value_claim_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(8) => value_csi = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(9) => value_fc = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(10) => value_flex_volume = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(11) => value_flocker = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(12) => value_gce_persistent_disk = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(13) => value_glusterfs = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(14) => value_host_path = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(15) => value_iscsi = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(16) => value_local = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(17) => value_mount_options = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(18) => value_nfs = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(19) => value_node_affinity = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(20) => value_persistent_volume_reclaim_policy = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(21) => value_photon_persistent_disk = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(22) => value_portworx_volume = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(23) => value_quobyte = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(24) => value_rbd = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(25) => value_scale_io = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(26) => value_storage_class_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(27) => value_storageos = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(28) => value_volume_mode = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(29) => value_vsphere_volume = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -293,49 +455,302 @@ impl<'de> crate::serde::Deserialize<'de> for PersistentVolumeSpec {
                     vsphere_volume: value_vsphere_volume,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(PersistentVolumeSpec {
+                    access_modes: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    aws_elastic_block_store: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    azure_disk: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    azure_file: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    capacity: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    cephfs: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    cinder: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    claim_ref: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                    csi: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?,
+                    fc: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?,
+                    flex_volume: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?,
+                    flocker: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(11, &self))?,
+                    gce_persistent_disk: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(12, &self))?,
+                    glusterfs: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(13, &self))?,
+                    host_path: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(14, &self))?,
+                    iscsi: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(15, &self))?,
+                    local: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(16, &self))?,
+                    mount_options: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(17, &self))?,
+                    nfs: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(18, &self))?,
+                    node_affinity: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(19, &self))?,
+                    persistent_volume_reclaim_policy: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(20, &self))?,
+                    photon_persistent_disk: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(21, &self))?,
+                    portworx_volume: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(22, &self))?,
+                    quobyte: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(23, &self))?,
+                    rbd: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(24, &self))?,
+                    scale_io: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(25, &self))?,
+                    storage_class_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(26, &self))?,
+                    storageos: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(27, &self))?,
+                    volume_mode: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(28, &self))?,
+                    vsphere_volume: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(29, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "PersistentVolumeSpec",
-            &[
-                "accessModes",
-                "awsElasticBlockStore",
-                "azureDisk",
-                "azureFile",
-                "capacity",
-                "cephfs",
-                "cinder",
-                "claimRef",
-                "csi",
-                "fc",
-                "flexVolume",
-                "flocker",
-                "gcePersistentDisk",
-                "glusterfs",
-                "hostPath",
-                "iscsi",
-                "local",
-                "mountOptions",
-                "nfs",
-                "nodeAffinity",
-                "persistentVolumeReclaimPolicy",
-                "photonPersistentDisk",
-                "portworxVolume",
-                "quobyte",
-                "rbd",
-                "scaleIO",
-                "storageClassName",
-                "storageos",
-                "volumeMode",
-                "vsphereVolume",
-            ],
+            PersistentVolumeSpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, PersistentVolumeSpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("PersistentVolumeSpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_access_modes = false;
+                let mut has_aws_elastic_block_store = false;
+                let mut has_azure_disk = false;
+                let mut has_azure_file = false;
+                let mut has_capacity = false;
+                let mut has_cephfs = false;
+                let mut has_cinder = false;
+                let mut has_claim_ref = false;
+                let mut has_csi = false;
+                let mut has_fc = false;
+                let mut has_flex_volume = false;
+                let mut has_flocker = false;
+                let mut has_gce_persistent_disk = false;
+                let mut has_glusterfs = false;
+                let mut has_host_path = false;
+                let mut has_iscsi = false;
+                let mut has_local = false;
+                let mut has_mount_options = false;
+                let mut has_nfs = false;
+                let mut has_node_affinity = false;
+                let mut has_persistent_volume_reclaim_policy = false;
+                let mut has_photon_persistent_disk = false;
+                let mut has_portworx_volume = false;
+                let mut has_quobyte = false;
+                let mut has_rbd = false;
+                let mut has_scale_io = false;
+                let mut has_storage_class_name = false;
+                let mut has_storageos = false;
+                let mut has_volume_mode = false;
+                let mut has_vsphere_volume = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PersistentVolumeSpec", PersistentVolumeSpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.access_modes))?; has_access_modes = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.aws_elastic_block_store))?; has_aws_elastic_block_store = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.azure_disk))?; has_azure_disk = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.azure_file))?; has_azure_file = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.capacity))?; has_capacity = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.cephfs))?; has_cephfs = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.cinder))?; has_cinder = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.claim_ref))?; has_claim_ref = true; },
+                        Some(8) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.csi))?; has_csi = true; },
+                        Some(9) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.fc))?; has_fc = true; },
+                        Some(10) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.flex_volume))?; has_flex_volume = true; },
+                        Some(11) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.flocker))?; has_flocker = true; },
+                        Some(12) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.gce_persistent_disk))?; has_gce_persistent_disk = true; },
+                        Some(13) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.glusterfs))?; has_glusterfs = true; },
+                        Some(14) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.host_path))?; has_host_path = true; },
+                        Some(15) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.iscsi))?; has_iscsi = true; },
+                        Some(16) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.local))?; has_local = true; },
+                        Some(17) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.mount_options))?; has_mount_options = true; },
+                        Some(18) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.nfs))?; has_nfs = true; },
+                        Some(19) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.node_affinity))?; has_node_affinity = true; },
+                        Some(20) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.persistent_volume_reclaim_policy))?; has_persistent_volume_reclaim_policy = true; },
+                        Some(21) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.photon_persistent_disk))?; has_photon_persistent_disk = true; },
+                        Some(22) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.portworx_volume))?; has_portworx_volume = true; },
+                        Some(23) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.quobyte))?; has_quobyte = true; },
+                        Some(24) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.rbd))?; has_rbd = true; },
+                        Some(25) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.scale_io))?; has_scale_io = true; },
+                        Some(26) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.storage_class_name))?; has_storage_class_name = true; },
+                        Some(27) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.storageos))?; has_storageos = true; },
+                        Some(28) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.volume_mode))?; has_volume_mode = true; },
+                        Some(29) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.vsphere_volume))?; has_vsphere_volume = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_access_modes {
+                    self.0.access_modes = None;
+                }
+                if !has_aws_elastic_block_store {
+                    self.0.aws_elastic_block_store = None;
+                }
+                if !has_azure_disk {
+                    self.0.azure_disk = None;
+                }
+                if !has_azure_file {
+                    self.0.azure_file = None;
+                }
+                if !has_capacity {
+                    self.0.capacity = None;
+                }
+                if !has_cephfs {
+                    self.0.cephfs = None;
+                }
+                if !has_cinder {
+                    self.0.cinder = None;
+                }
+                if !has_claim_ref {
+                    self.0.claim_ref = None;
+                }
+                if !has_csi {
+                    self.0.csi = None;
+                }
+                if !has_fc {
+                    self.0.fc = None;
+                }
+                if !has_flex_volume {
+                    self.0.flex_volume = None;
+                }
+                if !has_flocker {
+                    self.0.flocker = None;
+                }
+                if !has_gce_persistent_disk {
+                    self.0.gce_persistent_disk = None;
+                }
+                if !has_glusterfs {
+                    self.0.glusterfs = None;
+                }
+                if !has_host_path {
+                    self.0.host_path = None;
+                }
+                if !has_iscsi {
+                    self.0.iscsi = None;
+                }
+                if !has_local {
+                    self.0.local = None;
+                }
+                if !has_mount_options {
+                    self.0.mount_options = None;
+                }
+                if !has_nfs {
+                    self.0.nfs = None;
+                }
+                if !has_node_affinity {
+                    self.0.node_affinity = None;
+                }
+                if !has_persistent_volume_reclaim_policy {
+                    self.0.persistent_volume_reclaim_policy = None;
+                }
+                if !has_photon_persistent_disk {
+                    self.0.photon_persistent_disk = None;
+                }
+                if !has_portworx_volume {
+                    self.0.portworx_volume = None;
+                }
+                if !has_quobyte {
+                    self.0.quobyte = None;
+                }
+                if !has_rbd {
+                    self.0.rbd = None;
+                }
+                if !has_scale_io {
+                    self.0.scale_io = None;
+                }
+                if !has_storage_class_name {
+                    self.0.storage_class_name = None;
+                }
+                if !has_storageos {
+                    self.0.storageos = None;
+                }
+                if !has_volume_mode {
+                    self.0.volume_mode = None;
+                }
+                if !has_vsphere_volume {
+                    self.0.vsphere_volume = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.access_modes))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.aws_elastic_block_store))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.azure_disk))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.azure_file))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.capacity))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.cephfs))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.cinder))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.claim_ref))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.csi))?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.fc))?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.flex_volume))?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.flocker))?.ok_or_else(|| crate::serde::de::Error::invalid_length(11, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.gce_persistent_disk))?.ok_or_else(|| crate::serde::de::Error::invalid_length(12, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.glusterfs))?.ok_or_else(|| crate::serde::de::Error::invalid_length(13, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.host_path))?.ok_or_else(|| crate::serde::de::Error::invalid_length(14, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.iscsi))?.ok_or_else(|| crate::serde::de::Error::invalid_length(15, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.local))?.ok_or_else(|| crate::serde::de::Error::invalid_length(16, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.mount_options))?.ok_or_else(|| crate::serde::de::Error::invalid_length(17, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.nfs))?.ok_or_else(|| crate::serde::de::Error::invalid_length(18, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.node_affinity))?.ok_or_else(|| crate::serde::de::Error::invalid_length(19, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.persistent_volume_reclaim_policy))?.ok_or_else(|| crate::serde::de::Error::invalid_length(20, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.photon_persistent_disk))?.ok_or_else(|| crate::serde::de::Error::invalid_length(21, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.portworx_volume))?.ok_or_else(|| crate::serde::de::Error::invalid_length(22, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.quobyte))?.ok_or_else(|| crate::serde::de::Error::invalid_length(23, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.rbd))?.ok_or_else(|| crate::serde::de::Error::invalid_length(24, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.scale_io))?.ok_or_else(|| crate::serde::de::Error::invalid_length(25, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.storage_class_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(26, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.storageos))?.ok_or_else(|| crate::serde::de::Error::invalid_length(27, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.volume_mode))?.ok_or_else(|| crate::serde::de::Error::invalid_length(28, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.vsphere_volume))?.ok_or_else(|| crate::serde::de::Error::invalid_length(29, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "PersistentVolumeSpec",
+            PersistentVolumeSpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for PersistentVolumeSpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("PersistentVolumeSpec", 30)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "accessModes", &self.access_modes)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "awsElasticBlockStore", &self.aws_elastic_block_store)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "azureDisk", &self.azure_disk)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "azureFile", &self.azure_file)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "capacity", &self.capacity)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cephfs", &self.cephfs)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cinder", &self.cinder)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "claimRef", &self.claim_ref)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "csi", &self.csi)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fc", &self.fc)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "flexVolume", &self.flex_volume)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "flocker", &self.flocker)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "gcePersistentDisk", &self.gce_persistent_disk)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "glusterfs", &self.glusterfs)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostPath", &self.host_path)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "iscsi", &self.iscsi)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "local", &self.local)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "mountOptions", &self.mount_options)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nfs", &self.nfs)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeAffinity", &self.node_affinity)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "persistentVolumeReclaimPolicy", &self.persistent_volume_reclaim_policy)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "photonPersistentDisk", &self.photon_persistent_disk)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "portworxVolume", &self.portworx_volume)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "quobyte", &self.quobyte)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rbd", &self.rbd)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "scaleIO", &self.scale_io)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "storageClassName", &self.storage_class_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "storageos", &self.storageos)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeMode", &self.volume_mode)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "vsphereVolume", &self.vsphere_volume)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "PersistentVolumeSpec",
             self.access_modes.as_ref().map_or(0, |_| 1) +
@@ -463,10 +878,504 @@ impl crate::serde::Serialize for PersistentVolumeSpec {
     }
 }
 
+/// A builder of [`PersistentVolumeSpec`]. Created with [`PersistentVolumeSpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct PersistentVolumeSpecBuilder {
+    access_modes: Option<Vec<String>>,
+    aws_elastic_block_store: Option<crate::api::core::v1::AWSElasticBlockStoreVolumeSource>,
+    azure_disk: Option<crate::api::core::v1::AzureDiskVolumeSource>,
+    azure_file: Option<crate::api::core::v1::AzureFilePersistentVolumeSource>,
+    capacity: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>,
+    cephfs: Option<crate::api::core::v1::CephFSPersistentVolumeSource>,
+    cinder: Option<crate::api::core::v1::CinderPersistentVolumeSource>,
+    claim_ref: Option<crate::api::core::v1::ObjectReference>,
+    csi: Option<crate::api::core::v1::CSIPersistentVolumeSource>,
+    fc: Option<crate::api::core::v1::FCVolumeSource>,
+    flex_volume: Option<crate::api::core::v1::FlexPersistentVolumeSource>,
+    flocker: Option<crate::api::core::v1::FlockerVolumeSource>,
+    gce_persistent_disk: Option<crate::api::core::v1::GCEPersistentDiskVolumeSource>,
+    glusterfs: Option<crate::api::core::v1::GlusterfsVolumeSource>,
+    host_path: Option<crate::api::core::v1::HostPathVolumeSource>,
+    iscsi: Option<crate::api::core::v1::ISCSIPersistentVolumeSource>,
+    local: Option<crate::api::core::v1::LocalVolumeSource>,
+    mount_options: Option<Vec<String>>,
+    nfs: Option<crate::api::core::v1::NFSVolumeSource>,
+    node_affinity: Option<crate::api::core::v1::VolumeNodeAffinity>,
+    persistent_volume_reclaim_policy: Option<crate::api::core::v1::PersistentVolumeReclaimPolicy>,
+    photon_persistent_disk: Option<crate::api::core::v1::PhotonPersistentDiskVolumeSource>,
+    portworx_volume: Option<crate::api::core::v1::PortworxVolumeSource>,
+    quobyte: Option<crate::api::core::v1::QuobyteVolumeSource>,
+    rbd: Option<crate::api::core::v1::RBDPersistentVolumeSource>,
+    scale_io: Option<crate::api::core::v1::ScaleIOPersistentVolumeSource>,
+    storage_class_name: Option<String>,
+    storageos: Option<crate::api::core::v1::StorageOSPersistentVolumeSource>,
+    volume_mode: Option<String>,
+    vsphere_volume: Option<crate::api::core::v1::VsphereVirtualDiskVolumeSource>,
+}
+
+#[cfg(feature = "builder")]
+impl PersistentVolumeSpec {
+    /// Starts building a `PersistentVolumeSpec`. Its required fields must be set before [`PersistentVolumeSpecBuilder::build`] is called.
+    pub fn builder() -> PersistentVolumeSpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl PersistentVolumeSpecBuilder {
+    /// Sets [`PersistentVolumeSpec::access_modes`].
+    pub fn access_modes(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.access_modes = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::aws_elastic_block_store`].
+    pub fn aws_elastic_block_store(mut self, value: impl Into<crate::api::core::v1::AWSElasticBlockStoreVolumeSource>) -> Self {
+        self.aws_elastic_block_store = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::azure_disk`].
+    pub fn azure_disk(mut self, value: impl Into<crate::api::core::v1::AzureDiskVolumeSource>) -> Self {
+        self.azure_disk = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::azure_file`].
+    pub fn azure_file(mut self, value: impl Into<crate::api::core::v1::AzureFilePersistentVolumeSource>) -> Self {
+        self.azure_file = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::capacity`].
+    pub fn capacity(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>) -> Self {
+        self.capacity = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::cephfs`].
+    pub fn cephfs(mut self, value: impl Into<crate::api::core::v1::CephFSPersistentVolumeSource>) -> Self {
+        self.cephfs = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::cinder`].
+    pub fn cinder(mut self, value: impl Into<crate::api::core::v1::CinderPersistentVolumeSource>) -> Self {
+        self.cinder = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::claim_ref`].
+    pub fn claim_ref(mut self, value: impl Into<crate::api::core::v1::ObjectReference>) -> Self {
+        self.claim_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::csi`].
+    pub fn csi(mut self, value: impl Into<crate::api::core::v1::CSIPersistentVolumeSource>) -> Self {
+        self.csi = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::fc`].
+    pub fn fc(mut self, value: impl Into<crate::api::core::v1::FCVolumeSource>) -> Self {
+        self.fc = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::flex_volume`].
+    pub fn flex_volume(mut self, value: impl Into<crate::api::core::v1::FlexPersistentVolumeSource>) -> Self {
+        self.flex_volume = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::flocker`].
+    pub fn flocker(mut self, value: impl Into<crate::api::core::v1::FlockerVolumeSource>) -> Self {
+        self.flocker = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::gce_persistent_disk`].
+    pub fn gce_persistent_disk(mut self, value: impl Into<crate::api::core::v1::GCEPersistentDiskVolumeSource>) -> Self {
+        self.gce_persistent_disk = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::glusterfs`].
+    pub fn glusterfs(mut self, value: impl Into<crate::api::core::v1::GlusterfsVolumeSource>) -> Self {
+        self.glusterfs = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::host_path`].
+    pub fn host_path(mut self, value: impl Into<crate::api::core::v1::HostPathVolumeSource>) -> Self {
+        self.host_path = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::iscsi`].
+    pub fn iscsi(mut self, value: impl Into<crate::api::core::v1::ISCSIPersistentVolumeSource>) -> Self {
+        self.iscsi = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::local`].
+    pub fn local(mut self, value: impl Into<crate::api::core::v1::LocalVolumeSource>) -> Self {
+        self.local = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::mount_options`].
+    pub fn mount_options(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.mount_options = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::nfs`].
+    pub fn nfs(mut self, value: impl Into<crate::api::core::v1::NFSVolumeSource>) -> Self {
+        self.nfs = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::node_affinity`].
+    pub fn node_affinity(mut self, value: impl Into<crate::api::core::v1::VolumeNodeAffinity>) -> Self {
+        self.node_affinity = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::persistent_volume_reclaim_policy`].
+    pub fn persistent_volume_reclaim_policy(mut self, value: impl Into<crate::api::core::v1::PersistentVolumeReclaimPolicy>) -> Self {
+        self.persistent_volume_reclaim_policy = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::photon_persistent_disk`].
+    pub fn photon_persistent_disk(mut self, value: impl Into<crate::api::core::v1::PhotonPersistentDiskVolumeSource>) -> Self {
+        self.photon_persistent_disk = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::portworx_volume`].
+    pub fn portworx_volume(mut self, value: impl Into<crate::api::core::v1::PortworxVolumeSource>) -> Self {
+        self.portworx_volume = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::quobyte`].
+    pub fn quobyte(mut self, value: impl Into<crate::api::core::v1::QuobyteVolumeSource>) -> Self {
+        self.quobyte = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::rbd`].
+    pub fn rbd(mut self, value: impl Into<crate::api::core::v1::RBDPersistentVolumeSource>) -> Self {
+        self.rbd = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::scale_io`].
+    pub fn scale_io(mut self, value: impl Into<crate::api::core::v1::ScaleIOPersistentVolumeSource>) -> Self {
+        self.scale_io = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::storage_class_name`].
+    pub fn storage_class_name(mut self, value: impl Into<String>) -> Self {
+        self.storage_class_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::storageos`].
+    pub fn storageos(mut self, value: impl Into<crate::api::core::v1::StorageOSPersistentVolumeSource>) -> Self {
+        self.storageos = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::volume_mode`].
+    pub fn volume_mode(mut self, value: impl Into<String>) -> Self {
+        self.volume_mode = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeSpec::vsphere_volume`].
+    pub fn vsphere_volume(mut self, value: impl Into<crate::api::core::v1::VsphereVirtualDiskVolumeSource>) -> Self {
+        self.vsphere_volume = Some(value.into());
+        self
+    }
+
+    /// Builds the `PersistentVolumeSpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<PersistentVolumeSpec, crate::MissingFieldError> {
+        Ok(PersistentVolumeSpec {
+            access_modes: self.access_modes,
+            aws_elastic_block_store: self.aws_elastic_block_store,
+            azure_disk: self.azure_disk,
+            azure_file: self.azure_file,
+            capacity: self.capacity,
+            cephfs: self.cephfs,
+            cinder: self.cinder,
+            claim_ref: self.claim_ref,
+            csi: self.csi,
+            fc: self.fc,
+            flex_volume: self.flex_volume,
+            flocker: self.flocker,
+            gce_persistent_disk: self.gce_persistent_disk,
+            glusterfs: self.glusterfs,
+            host_path: self.host_path,
+            iscsi: self.iscsi,
+            local: self.local,
+            mount_options: self.mount_options,
+            nfs: self.nfs,
+            node_affinity: self.node_affinity,
+            persistent_volume_reclaim_policy: self.persistent_volume_reclaim_policy,
+            photon_persistent_disk: self.photon_persistent_disk,
+            portworx_volume: self.portworx_volume,
+            quobyte: self.quobyte,
+            rbd: self.rbd,
+            scale_io: self.scale_io,
+            storage_class_name: self.storage_class_name,
+            storageos: self.storageos,
+            volume_mode: self.volume_mode,
+            vsphere_volume: self.vsphere_volume,
+        })
+    }
+}
+
+/// The apply configuration of [`PersistentVolumeSpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PersistentVolumeSpecApplyConfiguration {
+    /// See [`PersistentVolumeSpec::access_modes`].
+    pub access_modes: Option<Vec<String>>,
+
+    /// See [`PersistentVolumeSpec::aws_elastic_block_store`].
+    pub aws_elastic_block_store: Option<crate::api::core::v1::AWSElasticBlockStoreVolumeSourceApplyConfiguration>,
+
+    /// See [`PersistentVolumeSpec::azure_disk`].
+    pub azure_disk: Option<crate::api::core::v1::AzureDiskVolumeSourceApplyConfiguration>,
+
+    /// See [`PersistentVolumeSpec::azure_file`].
+    pub azure_file: Option<crate::api::core::v1::AzureFilePersistentVolumeSourceApplyConfiguration>,
+
+    /// See [`PersistentVolumeSpec::capacity`].
+    pub capacity: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>,
+
+    /// See [`PersistentVolumeSpec::cephfs`].
+    pub cephfs: Option<crate::api::core::v1::CephFSPersistentVolumeSourceApplyConfiguration>,
+
+    /// See [`PersistentVolumeSpec::cinder`].
+    pub cinder: Option<crate::api::core::v1::CinderPersistentVolumeSourceApplyConfiguration>,
+
+    /// See [`PersistentVolumeSpec::claim_ref`].
+    pub claim_ref: Option<crate::api::core::v1::ObjectReferenceApplyConfiguration>,
+
+    /// See [`PersistentVolumeSpec::csi`].
+    pub csi: Option<crate::api::core::v1::CSIPersistentVolumeSourceApplyConfiguration>,
+
+    /// See [`PersistentVolumeSpec::fc`].
+    pub fc: Option<crate::api::core::v1::FCVolumeSourceApplyConfiguration>,
+
+    /// See [`PersistentVolumeSpec::flex_volume`].
+    pub flex_volume: Option<crate::api::core::v1::FlexPersistentVolumeSourceApplyConfiguration>,
+
+    /// See [`PersistentVolumeSpec::flocker`].
+    pub flocker: Option<crate::api::core::v1::FlockerVolumeSourceApplyConfiguration>,
+
+    /// See [`PersistentVolumeSpec::gce_persistent_disk`].
+    pub gce_persistent_disk: Option<crate::api::core::v1::GCEPersistentDiskVolumeSourceApplyConfiguration>,
+
+    /// See [`PersistentVolumeSpec::glusterfs`].
+    pub glusterfs: Option<crate::api::core::v1::GlusterfsVolumeSourceApplyConfiguration>,
+
+    /// See [`PersistentVolumeSpec::host_path`].
+    pub host_path: Option<crate::api::core::v1::HostPathVolumeSourceApplyConfiguration>,
+
+    /// See [`PersistentVolumeSpec::iscsi`].
+    pub iscsi: Option<crate::api::core::v1::ISCSIPersistentVolumeSourceApplyConfiguration>,
+
+    /// See [`PersistentVolumeSpec::local`].
+    pub local: Option<crate::api::core::v1::LocalVolumeSourceApplyConfiguration>,
+
+    /// See [`PersistentVolumeSpec::mount_options`].
+    pub mount_options: Option<Vec<String>>,
+
+    /// See [`PersistentVolumeSpec::nfs`].
+    pub nfs: Option<crate::api::core::v1::NFSVolumeSourceApplyConfiguration>,
+
+    /// See [`PersistentVolumeSpec::node_affinity`].
+    pub node_affinity: Option<crate::api::core::v1::VolumeNodeAffinityApplyConfiguration>,
+
+    /// See [`PersistentVolumeSpec::persistent_volume_reclaim_policy`].
+    pub persistent_volume_reclaim_policy: Option<crate::api::core::v1::PersistentVolumeReclaimPolicy>,
+
+    /// See [`PersistentVolumeSpec::photon_persistent_disk`].
+    pub photon_persistent_disk: Option<crate::api::core::v1::PhotonPersistentDiskVolumeSourceApplyConfiguration>,
+
+    /// See [`PersistentVolumeSpec::portworx_volume`].
+    pub portworx_volume: Option<crate::api::core::v1::PortworxVolumeSourceApplyConfiguration>,
+
+    /// See [`PersistentVolumeSpec::quobyte`].
+    pub quobyte: Option<crate::api::core::v1::QuobyteVolumeSourceApplyConfiguration>,
+
+    /// See [`PersistentVolumeSpec::rbd`].
+    pub rbd: Option<crate::api::core::v1::RBDPersistentVolumeSourceApplyConfiguration>,
+
+    /// See [`PersistentVolumeSpec::scale_io`].
+    pub scale_io: Option<crate::api::core::v1::ScaleIOPersistentVolumeSourceApplyConfiguration>,
+
+    /// See [`PersistentVolumeSpec::storage_class_name`].
+    pub storage_class_name: Option<String>,
+
+    /// See [`PersistentVolumeSpec::storageos`].
+    pub storageos: Option<crate::api::core::v1::StorageOSPersistentVolumeSourceApplyConfiguration>,
+
+    /// See [`PersistentVolumeSpec::volume_mode`].
+    pub volume_mode: Option<String>,
+
+    /// See [`PersistentVolumeSpec::vsphere_volume`].
+    pub vsphere_volume: Option<crate::api::core::v1::VsphereVirtualDiskVolumeSourceApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for PersistentVolumeSpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "PersistentVolumeSpecApplyConfiguration",
+            self.access_modes.as_ref().map_or(0, |_| 1) +
+            self.aws_elastic_block_store.as_ref().map_or(0, |_| 1) +
+            self.azure_disk.as_ref().map_or(0, |_| 1) +
+            self.azure_file.as_ref().map_or(0, |_| 1) +
+            self.capacity.as_ref().map_or(0, |_| 1) +
+            self.cephfs.as_ref().map_or(0, |_| 1) +
+            self.cinder.as_ref().map_or(0, |_| 1) +
+            self.claim_ref.as_ref().map_or(0, |_| 1) +
+            self.csi.as_ref().map_or(0, |_| 1) +
+            self.fc.as_ref().map_or(0, |_| 1) +
+            self.flex_volume.as_ref().map_or(0, |_| 1) +
+            self.flocker.as_ref().map_or(0, |_| 1) +
+            self.gce_persistent_disk.as_ref().map_or(0, |_| 1) +
+            self.glusterfs.as_ref().map_or(0, |_| 1) +
+            self.host_path.as_ref().map_or(0, |_| 1) +
+            self.iscsi.as_ref().map_or(0, |_| 1) +
+            self.local.as_ref().map_or(0, |_| 1) +
+            self.mount_options.as_ref().map_or(0, |_| 1) +
+            self.nfs.as_ref().map_or(0, |_| 1) +
+            self.node_affinity.as_ref().map_or(0, |_| 1) +
+            self.persistent_volume_reclaim_policy.as_ref().map_or(0, |_| 1) +
+            self.photon_persistent_disk.as_ref().map_or(0, |_| 1) +
+            self.portworx_volume.as_ref().map_or(0, |_| 1) +
+            self.quobyte.as_ref().map_or(0, |_| 1) +
+            self.rbd.as_ref().map_or(0, |_| 1) +
+            self.scale_io.as_ref().map_or(0, |_| 1) +
+            self.storage_class_name.as_ref().map_or(0, |_| 1) +
+            self.storageos.as_ref().map_or(0, |_| 1) +
+            self.volume_mode.as_ref().map_or(0, |_| 1) +
+            self.vsphere_volume.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.access_modes {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "accessModes", value)?;
+        }
+        if let Some(value) = &self.aws_elastic_block_store {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "awsElasticBlockStore", value)?;
+        }
+        if let Some(value) = &self.azure_disk {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "azureDisk", value)?;
+        }
+        if let Some(value) = &self.azure_file {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "azureFile", value)?;
+        }
+        if let Some(value) = &self.capacity {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "capacity", value)?;
+        }
+        if let Some(value) = &self.cephfs {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cephfs", value)?;
+        }
+        if let Some(value) = &self.cinder {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cinder", value)?;
+        }
+        if let Some(value) = &self.claim_ref {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "claimRef", value)?;
+        }
+        if let Some(value) = &self.csi {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "csi", value)?;
+        }
+        if let Some(value) = &self.fc {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fc", value)?;
+        }
+        if let Some(value) = &self.flex_volume {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "flexVolume", value)?;
+        }
+        if let Some(value) = &self.flocker {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "flocker", value)?;
+        }
+        if let Some(value) = &self.gce_persistent_disk {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "gcePersistentDisk", value)?;
+        }
+        if let Some(value) = &self.glusterfs {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "glusterfs", value)?;
+        }
+        if let Some(value) = &self.host_path {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostPath", value)?;
+        }
+        if let Some(value) = &self.iscsi {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "iscsi", value)?;
+        }
+        if let Some(value) = &self.local {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "local", value)?;
+        }
+        if let Some(value) = &self.mount_options {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "mountOptions", value)?;
+        }
+        if let Some(value) = &self.nfs {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nfs", value)?;
+        }
+        if let Some(value) = &self.node_affinity {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeAffinity", value)?;
+        }
+        if let Some(value) = &self.persistent_volume_reclaim_policy {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "persistentVolumeReclaimPolicy", value)?;
+        }
+        if let Some(value) = &self.photon_persistent_disk {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "photonPersistentDisk", value)?;
+        }
+        if let Some(value) = &self.portworx_volume {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "portworxVolume", value)?;
+        }
+        if let Some(value) = &self.quobyte {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "quobyte", value)?;
+        }
+        if let Some(value) = &self.rbd {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rbd", value)?;
+        }
+        if let Some(value) = &self.scale_io {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "scaleIO", value)?;
+        }
+        if let Some(value) = &self.storage_class_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "storageClassName", value)?;
+        }
+        if let Some(value) = &self.storageos {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "storageos", value)?;
+        }
+        if let Some(value) = &self.volume_mode {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeMode", value)?;
+        }
+        if let Some(value) = &self.vsphere_volume {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "vsphereVolume", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PersistentVolumeSpec {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.PersistentVolumeSpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::PersistentVolumeSpec".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.PersistentVolumeSpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -477,8 +1386,9 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "accessModes".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -497,8 +1407,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "awsElasticBlockStore".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::AWSElasticBlockStoreVolumeSource>().into_object();
@@ -508,8 +1418,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "azureDisk".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::AzureDiskVolumeSource>().into_object();
@@ -519,8 +1429,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "azureFile".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::AzureFilePersistentVolumeSource>().into_object();
@@ -530,8 +1440,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "capacity".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -545,8 +1455,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "cephfs".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::CephFSPersistentVolumeSource>().into_object();
@@ -556,8 +1466,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "cinder".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::CinderPersistentVolumeSource>().into_object();
@@ -567,8 +1477,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "claimRef".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ObjectReference>().into_object();
@@ -578,8 +1488,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "csi".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::CSIPersistentVolumeSource>().into_object();
@@ -589,8 +1499,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "fc".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::FCVolumeSource>().into_object();
@@ -600,8 +1510,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "flexVolume".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::FlexPersistentVolumeSource>().into_object();
@@ -611,8 +1521,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "flocker".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::FlockerVolumeSource>().into_object();
@@ -622,8 +1532,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "gcePersistentDisk".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::GCEPersistentDiskVolumeSource>().into_object();
@@ -633,8 +1543,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "glusterfs".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::GlusterfsVolumeSource>().into_object();
@@ -644,8 +1554,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "hostPath".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::HostPathVolumeSource>().into_object();
@@ -655,8 +1565,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "iscsi".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ISCSIPersistentVolumeSource>().into_object();
@@ -666,8 +1576,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "local".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::LocalVolumeSource>().into_object();
@@ -677,8 +1587,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "mountOptions".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -697,8 +1607,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "nfs".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::NFSVolumeSource>().into_object();
@@ -708,8 +1618,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "nodeAffinity".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::VolumeNodeAffinity>().into_object();
@@ -719,19 +1629,19 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "persistentVolumeReclaimPolicy".to_owned(),
-                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
-                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::PersistentVolumeReclaimPolicy>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
                                 description: Some("What happens to a persistent volume when released from its claim. Valid options are Retain (default for manually created PersistentVolumes), Delete (default for dynamically provisioned PersistentVolumes), and Recycle (deprecated). Recycle must be supported by the volume plugin underlying this PersistentVolume. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#reclaiming".to_owned()),
                                 ..Default::default()
-                            })),
-                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
-                            ..Default::default()
-                        }),
-                    ),
-                    (
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
                         "photonPersistentDisk".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::PhotonPersistentDiskVolumeSource>().into_object();
@@ -741,8 +1651,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "portworxVolume".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::PortworxVolumeSource>().into_object();
@@ -752,8 +1662,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "quobyte".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::QuobyteVolumeSource>().into_object();
@@ -763,8 +1673,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "rbd".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::RBDPersistentVolumeSource>().into_object();
@@ -774,8 +1684,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "scaleIO".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ScaleIOPersistentVolumeSource>().into_object();
@@ -785,8 +1695,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "storageClassName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -796,8 +1706,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "storageos".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::StorageOSPersistentVolumeSource>().into_object();
@@ -807,8 +1717,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "volumeMode".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -818,8 +1728,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "vsphereVolume".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::VsphereVirtualDiskVolumeSource>().into_object();
@@ -829,11 +1739,218 @@ impl crate::schemars::JsonSchema for PersistentVolumeSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for PersistentVolumeSpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(PersistentVolumeSpec {
+            access_modes: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            aws_elastic_block_store: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            azure_disk: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            azure_file: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            capacity: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            cephfs: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            cinder: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            claim_ref: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            csi: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            fc: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            flex_volume: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            flocker: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            gce_persistent_disk: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            glusterfs: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            host_path: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            iscsi: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            local: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            mount_options: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            nfs: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            node_affinity: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            persistent_volume_reclaim_policy: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            photon_persistent_disk: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            portworx_volume: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            quobyte: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            rbd: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            scale_io: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            storage_class_name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            storageos: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            volume_mode: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            vsphere_volume: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
+
+/// The property of a [`PersistentVolumeSpec`] that is set, out of the properties that are mutually exclusive. Returned by [`PersistentVolumeSpec::source`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PersistentVolumeSource<'a> {
+    /// [`PersistentVolumeSpec::aws_elastic_block_store`]
+    AwsElasticBlockStore(&'a crate::api::core::v1::AWSElasticBlockStoreVolumeSource),
+
+    /// [`PersistentVolumeSpec::azure_disk`]
+    AzureDisk(&'a crate::api::core::v1::AzureDiskVolumeSource),
+
+    /// [`PersistentVolumeSpec::azure_file`]
+    AzureFile(&'a crate::api::core::v1::AzureFilePersistentVolumeSource),
+
+    /// [`PersistentVolumeSpec::cephfs`]
+    Cephfs(&'a crate::api::core::v1::CephFSPersistentVolumeSource),
+
+    /// [`PersistentVolumeSpec::cinder`]
+    Cinder(&'a crate::api::core::v1::CinderPersistentVolumeSource),
+
+    /// [`PersistentVolumeSpec::csi`]
+    Csi(&'a crate::api::core::v1::CSIPersistentVolumeSource),
+
+    /// [`PersistentVolumeSpec::fc`]
+    Fc(&'a crate::api::core::v1::FCVolumeSource),
+
+    /// [`PersistentVolumeSpec::flex_volume`]
+    FlexVolume(&'a crate::api::core::v1::FlexPersistentVolumeSource),
+
+    /// [`PersistentVolumeSpec::flocker`]
+    Flocker(&'a crate::api::core::v1::FlockerVolumeSource),
+
+    /// [`PersistentVolumeSpec::gce_persistent_disk`]
+    GcePersistentDisk(&'a crate::api::core::v1::GCEPersistentDiskVolumeSource),
+
+    /// [`PersistentVolumeSpec::glusterfs`]
+    Glusterfs(&'a crate::api::core::v1::GlusterfsVolumeSource),
+
+    /// [`PersistentVolumeSpec::host_path`]
+    HostPath(&'a crate::api::core::v1::HostPathVolumeSource),
+
+    /// [`PersistentVolumeSpec::iscsi`]
+    Iscsi(&'a crate::api::core::v1::ISCSIPersistentVolumeSource),
+
+    /// [`PersistentVolumeSpec::local`]
+    Local(&'a crate::api::core::v1::LocalVolumeSource),
+
+    /// [`PersistentVolumeSpec::nfs`]
+    Nfs(&'a crate::api::core::v1::NFSVolumeSource),
+
+    /// [`PersistentVolumeSpec::photon_persistent_disk`]
+    PhotonPersistentDisk(&'a crate::api::core::v1::PhotonPersistentDiskVolumeSource),
+
+    /// [`PersistentVolumeSpec::portworx_volume`]
+    PortworxVolume(&'a crate::api::core::v1::PortworxVolumeSource),
+
+    /// [`PersistentVolumeSpec::quobyte`]
+    Quobyte(&'a crate::api::core::v1::QuobyteVolumeSource),
+
+    /// [`PersistentVolumeSpec::rbd`]
+    Rbd(&'a crate::api::core::v1::RBDPersistentVolumeSource),
+
+    /// [`PersistentVolumeSpec::scale_io`]
+    ScaleIO(&'a crate::api::core::v1::ScaleIOPersistentVolumeSource),
+
+    /// [`PersistentVolumeSpec::storageos`]
+    Storageos(&'a crate::api::core::v1::StorageOSPersistentVolumeSource),
+
+    /// [`PersistentVolumeSpec::vsphere_volume`]
+    VsphereVolume(&'a crate::api::core::v1::VsphereVirtualDiskVolumeSource),
+}
+
+impl PersistentVolumeSpec {
+    /// Returns the property of this `PersistentVolumeSpec` that is set, out of the properties that are mutually exclusive.
+    ///
+    /// Returns `None` if none of them is set. If more than one of them is set, which the API server rejects,
+    /// the one that comes first in the order of the variants of [`PersistentVolumeSource`] is returned.
+    pub fn source(&self) -> Option<PersistentVolumeSource<'_>> {
+        if let Some(value) = &self.aws_elastic_block_store {
+            return Some(PersistentVolumeSource::AwsElasticBlockStore(value));
+        }
+
+        if let Some(value) = &self.azure_disk {
+            return Some(PersistentVolumeSource::AzureDisk(value));
+        }
+
+        if let Some(value) = &self.azure_file {
+            return Some(PersistentVolumeSource::AzureFile(value));
+        }
+
+        if let Some(value) = &self.cephfs {
+            return Some(PersistentVolumeSource::Cephfs(value));
+        }
+
+        if let Some(value) = &self.cinder {
+            return Some(PersistentVolumeSource::Cinder(value));
+        }
+
+        if let Some(value) = &self.csi {
+            return Some(PersistentVolumeSource::Csi(value));
+        }
+
+        if let Some(value) = &self.fc {
+            return Some(PersistentVolumeSource::Fc(value));
+        }
+
+        if let Some(value) = &self.flex_volume {
+            return Some(PersistentVolumeSource::FlexVolume(value));
+        }
+
+        if let Some(value) = &self.flocker {
+            return Some(PersistentVolumeSource::Flocker(value));
+        }
+
+        if let Some(value) = &self.gce_persistent_disk {
+            return Some(PersistentVolumeSource::GcePersistentDisk(value));
+        }
+
+        if let Some(value) = &self.glusterfs {
+            return Some(PersistentVolumeSource::Glusterfs(value));
+        }
+
+        if let Some(value) = &self.host_path {
+            return Some(PersistentVolumeSource::HostPath(value));
+        }
+
+        if let Some(value) = &self.iscsi {
+            return Some(PersistentVolumeSource::Iscsi(value));
+        }
+
+        if let Some(value) = &self.local {
+            return Some(PersistentVolumeSource::Local(value));
+        }
+
+        if let Some(value) = &self.nfs {
+            return Some(PersistentVolumeSource::Nfs(value));
+        }
+
+        if let Some(value) = &self.photon_persistent_disk {
+            return Some(PersistentVolumeSource::PhotonPersistentDisk(value));
+        }
+
+        if let Some(value) = &self.portworx_volume {
+            return Some(PersistentVolumeSource::PortworxVolume(value));
+        }
+
+        if let Some(value) = &self.quobyte {
+            return Some(PersistentVolumeSource::Quobyte(value));
+        }
+
+        if let Some(value) = &self.rbd {
+            return Some(PersistentVolumeSource::Rbd(value));
+        }
+
+        if let Some(value) = &self.scale_io {
+            return Some(PersistentVolumeSource::ScaleIO(value));
+        }
+
+        if let Some(value) = &self.storageos {
+            return Some(PersistentVolumeSource::Storageos(value));
+        }
+
+        if let Some(value) = &self.vsphere_volume {
+            return Some(PersistentVolumeSource::VsphereVolume(value));
+        }
+
+        None
+    }
+}
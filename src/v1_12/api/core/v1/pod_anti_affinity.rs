@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.PodAntiAffinity
 
 /// Pod anti affinity is a group of inter pod anti affinity scheduling rules.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct PodAntiAffinity {
     /// The scheduler will prefer to schedule pods to nodes that satisfy the anti-affinity expressions specified by this field, but it may choose a node that violates one or more of the expressions. The node that is most preferred is the one with the greatest sum of weights, i.e. for each node that meets all of the scheduling requirements (resource request, requiredDuringScheduling anti-affinity expressions, etc.), compute a sum by iterating through the elements of this field and adding "weight" to the sum if the node has pods which matches the corresponding podAffinityTerm; the node(s) with the highest sum are the most preferred.
     pub preferred_during_scheduling_ignored_during_execution: Option<Vec<crate::api::core::v1::WeightedPodAffinityTerm>>,
@@ -10,39 +10,33 @@ pub struct PodAntiAffinity {
     pub required_during_scheduling_ignored_during_execution: Option<Vec<crate::api::core::v1::PodAffinityTerm>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for PodAntiAffinity {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_preferred_during_scheduling_ignored_during_execution,
-            Key_required_during_scheduling_ignored_during_execution,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl PodAntiAffinity {
+    /// Returns this value with [`PodAntiAffinity::preferred_during_scheduling_ignored_during_execution`] set to the given value.
+    #[must_use]
+    pub fn with_preferred_during_scheduling_ignored_during_execution(mut self, value: impl Into<Vec<crate::api::core::v1::WeightedPodAffinityTerm>>) -> Self {
+        self.preferred_during_scheduling_ignored_during_execution = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`PodAntiAffinity::required_during_scheduling_ignored_during_execution`] set to the given value.
+    #[must_use]
+    pub fn with_required_during_scheduling_ignored_during_execution(mut self, value: impl Into<Vec<crate::api::core::v1::PodAffinityTerm>>) -> Self {
+        self.required_during_scheduling_ignored_during_execution = Some(value.into());
+        self
+    }
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for PodAntiAffinity {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "preferredDuringSchedulingIgnoredDuringExecution" => Field::Key_preferred_during_scheduling_ignored_during_execution,
-                            "requiredDuringSchedulingIgnoredDuringExecution" => Field::Key_required_during_scheduling_ignored_during_execution,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl PodAntiAffinity {
+    const FIELDS: &'static [&'static str] = &[
+        "preferredDuringSchedulingIgnoredDuringExecution",
+        "requiredDuringSchedulingIgnoredDuringExecution",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for PodAntiAffinity {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,11 +50,11 @@ impl<'de> crate::serde::Deserialize<'de> for PodAntiAffinity {
                 let mut value_preferred_during_scheduling_ignored_during_execution: Option<Vec<crate::api::core::v1::WeightedPodAffinityTerm>> = None;
                 let mut value_required_during_scheduling_ignored_during_execution: Option<Vec<crate::api::core::v1::PodAffinityTerm>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodAntiAffinity", PodAntiAffinity::FIELDS))? {
                     match key {
-                        Field::Key_preferred_during_scheduling_ignored_during_execution => value_preferred_during_scheduling_ignored_during_execution = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_required_during_scheduling_ignored_during_execution => value_required_during_scheduling_ignored_during_execution = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_preferred_during_scheduling_ignored_during_execution = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_required_during_scheduling_ignored_during_execution = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -69,21 +63,78 @@ impl<'de> crate::serde::Deserialize<'de> for PodAntiAffinity {
                     required_during_scheduling_ignored_during_execution: value_required_during_scheduling_ignored_during_execution,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(PodAntiAffinity {
+                    preferred_during_scheduling_ignored_during_execution: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    required_during_scheduling_ignored_during_execution: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "PodAntiAffinity",
-            &[
-                "preferredDuringSchedulingIgnoredDuringExecution",
-                "requiredDuringSchedulingIgnoredDuringExecution",
-            ],
+            PodAntiAffinity::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, PodAntiAffinity> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("PodAntiAffinity")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_preferred_during_scheduling_ignored_during_execution = false;
+                let mut has_required_during_scheduling_ignored_during_execution = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodAntiAffinity", PodAntiAffinity::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.preferred_during_scheduling_ignored_during_execution))?; has_preferred_during_scheduling_ignored_during_execution = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.required_during_scheduling_ignored_during_execution))?; has_required_during_scheduling_ignored_during_execution = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_preferred_during_scheduling_ignored_during_execution {
+                    self.0.preferred_during_scheduling_ignored_during_execution = None;
+                }
+                if !has_required_during_scheduling_ignored_during_execution {
+                    self.0.required_during_scheduling_ignored_during_execution = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.preferred_during_scheduling_ignored_during_execution))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.required_during_scheduling_ignored_during_execution))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "PodAntiAffinity",
+            PodAntiAffinity::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for PodAntiAffinity {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("PodAntiAffinity", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "preferredDuringSchedulingIgnoredDuringExecution", &self.preferred_during_scheduling_ignored_during_execution)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "requiredDuringSchedulingIgnoredDuringExecution", &self.required_during_scheduling_ignored_during_execution)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "PodAntiAffinity",
             self.preferred_during_scheduling_ignored_during_execution.as_ref().map_or(0, |_| 1) +
@@ -99,10 +150,84 @@ impl crate::serde::Serialize for PodAntiAffinity {
     }
 }
 
+/// A builder of [`PodAntiAffinity`]. Created with [`PodAntiAffinity::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct PodAntiAffinityBuilder {
+    preferred_during_scheduling_ignored_during_execution: Option<Vec<crate::api::core::v1::WeightedPodAffinityTerm>>,
+    required_during_scheduling_ignored_during_execution: Option<Vec<crate::api::core::v1::PodAffinityTerm>>,
+}
+
+#[cfg(feature = "builder")]
+impl PodAntiAffinity {
+    /// Starts building a `PodAntiAffinity`. Its required fields must be set before [`PodAntiAffinityBuilder::build`] is called.
+    pub fn builder() -> PodAntiAffinityBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl PodAntiAffinityBuilder {
+    /// Sets [`PodAntiAffinity::preferred_during_scheduling_ignored_during_execution`].
+    pub fn preferred_during_scheduling_ignored_during_execution(mut self, value: impl Into<Vec<crate::api::core::v1::WeightedPodAffinityTerm>>) -> Self {
+        self.preferred_during_scheduling_ignored_during_execution = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodAntiAffinity::required_during_scheduling_ignored_during_execution`].
+    pub fn required_during_scheduling_ignored_during_execution(mut self, value: impl Into<Vec<crate::api::core::v1::PodAffinityTerm>>) -> Self {
+        self.required_during_scheduling_ignored_during_execution = Some(value.into());
+        self
+    }
+
+    /// Builds the `PodAntiAffinity`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<PodAntiAffinity, crate::MissingFieldError> {
+        Ok(PodAntiAffinity {
+            preferred_during_scheduling_ignored_during_execution: self.preferred_during_scheduling_ignored_during_execution,
+            required_during_scheduling_ignored_during_execution: self.required_during_scheduling_ignored_during_execution,
+        })
+    }
+}
+
+/// The apply configuration of [`PodAntiAffinity`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodAntiAffinityApplyConfiguration {
+    /// See [`PodAntiAffinity::preferred_during_scheduling_ignored_during_execution`].
+    pub preferred_during_scheduling_ignored_during_execution: Option<Vec<crate::api::core::v1::WeightedPodAffinityTermApplyConfiguration>>,
+
+    /// See [`PodAntiAffinity::required_during_scheduling_ignored_during_execution`].
+    pub required_during_scheduling_ignored_during_execution: Option<Vec<crate::api::core::v1::PodAffinityTermApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for PodAntiAffinityApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "PodAntiAffinityApplyConfiguration",
+            self.preferred_during_scheduling_ignored_during_execution.as_ref().map_or(0, |_| 1) +
+            self.required_during_scheduling_ignored_during_execution.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.preferred_during_scheduling_ignored_during_execution {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "preferredDuringSchedulingIgnoredDuringExecution", value)?;
+        }
+        if let Some(value) = &self.required_during_scheduling_ignored_during_execution {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "requiredDuringSchedulingIgnoredDuringExecution", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodAntiAffinity {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.PodAntiAffinity".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::PodAntiAffinity".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.PodAntiAffinity".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -113,8 +238,9 @@ impl crate::schemars::JsonSchema for PodAntiAffinity {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "preferredDuringSchedulingIgnoredDuringExecution".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -128,8 +254,8 @@ impl crate::schemars::JsonSchema for PodAntiAffinity {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "requiredDuringSchedulingIgnoredDuringExecution".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -143,11 +269,22 @@ impl crate::schemars::JsonSchema for PodAntiAffinity {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for PodAntiAffinity {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(PodAntiAffinity {
+            preferred_during_scheduling_ignored_during_execution: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            required_during_scheduling_ignored_during_execution: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
@@ -1,43 +1,40 @@
 // Generated from definition io.k8s.api.core.v1.PodReadinessGate
 
 /// PodReadinessGate contains the reference to a pod condition
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct PodReadinessGate {
     /// ConditionType refers to a condition in the pod's condition list with matching type.
     pub condition_type: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for PodReadinessGate {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_condition_type,
-            Other,
+impl PodReadinessGate {
+    /// Constructs a `PodReadinessGate` from its required fields. Its optional fields are unset.
+    pub fn new(condition_type: impl Into<String>) -> Self {
+        PodReadinessGate {
+            condition_type: condition_type.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl PodReadinessGate {
+    /// Returns this value with [`PodReadinessGate::condition_type`] set to the given value.
+    #[must_use]
+    pub fn with_condition_type(mut self, value: impl Into<String>) -> Self {
+        self.condition_type = value.into();
+        self
+    }
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for PodReadinessGate {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "conditionType" => Field::Key_condition_type,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl PodReadinessGate {
+    const FIELDS: &'static [&'static str] = &[
+        "conditionType",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for PodReadinessGate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -50,31 +47,81 @@ impl<'de> crate::serde::Deserialize<'de> for PodReadinessGate {
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
                 let mut value_condition_type: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodReadinessGate", PodReadinessGate::FIELDS))? {
                     match key {
-                        Field::Key_condition_type => value_condition_type = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_condition_type = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(PodReadinessGate {
-                    condition_type: value_condition_type.ok_or_else(|| crate::serde::de::Error::missing_field("conditionType"))?,
+                    condition_type: value_condition_type.ok_or_else(|| crate::serde2::missing_field("PodReadinessGate", "conditionType", &["conditionType"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(PodReadinessGate {
+                    condition_type: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "PodReadinessGate",
-            &[
-                "conditionType",
-            ],
+            PodReadinessGate::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, PodReadinessGate> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("PodReadinessGate")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_condition_type = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodReadinessGate", PodReadinessGate::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.condition_type))?; has_condition_type = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_condition_type {
+                    return Err(crate::serde2::missing_field("PodReadinessGate", "conditionType", &["conditionType"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.condition_type))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "PodReadinessGate",
+            PodReadinessGate::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for PodReadinessGate {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("PodReadinessGate", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conditionType", &self.condition_type)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "PodReadinessGate",
             1,
@@ -84,10 +131,69 @@ impl crate::serde::Serialize for PodReadinessGate {
     }
 }
 
+/// A builder of [`PodReadinessGate`]. Created with [`PodReadinessGate::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct PodReadinessGateBuilder {
+    condition_type: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl PodReadinessGate {
+    /// Starts building a `PodReadinessGate`. Its required fields must be set before [`PodReadinessGateBuilder::build`] is called.
+    pub fn builder() -> PodReadinessGateBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl PodReadinessGateBuilder {
+    /// Sets [`PodReadinessGate::condition_type`].
+    pub fn condition_type(mut self, value: impl Into<String>) -> Self {
+        self.condition_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `PodReadinessGate`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<PodReadinessGate, crate::MissingFieldError> {
+        Ok(PodReadinessGate {
+            condition_type: self.condition_type.ok_or_else(|| crate::MissingFieldError::new("PodReadinessGate", "conditionType"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`PodReadinessGate`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodReadinessGateApplyConfiguration {
+    /// See [`PodReadinessGate::condition_type`].
+    pub condition_type: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for PodReadinessGateApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "PodReadinessGateApplyConfiguration",
+            self.condition_type.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.condition_type {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conditionType", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodReadinessGate {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.PodReadinessGate".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::PodReadinessGate".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.PodReadinessGate".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -98,8 +204,9 @@ impl crate::schemars::JsonSchema for PodReadinessGate {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "conditionType".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -109,8 +216,9 @@ impl crate::schemars::JsonSchema for PodReadinessGate {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "conditionType",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -120,3 +228,12 @@ impl crate::schemars::JsonSchema for PodReadinessGate {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for PodReadinessGate {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(PodReadinessGate {
+            condition_type: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
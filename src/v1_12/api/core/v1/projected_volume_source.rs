@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.ProjectedVolumeSource
 
 /// Represents a projected volume source
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ProjectedVolumeSource {
     /// Mode bits to use on created files by default. Must be a value between 0 and 0777. Directories within the path are not affected by this setting. This might be in conflict with other options that affect the file mode, like fsGroup, and the result can be other mode bits set.
     pub default_mode: Option<i32>,
@@ -10,39 +10,43 @@ pub struct ProjectedVolumeSource {
     pub sources: Vec<crate::api::core::v1::VolumeProjection>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ProjectedVolumeSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_default_mode,
-            Key_sources,
-            Other,
+impl ProjectedVolumeSource {
+    /// Constructs a `ProjectedVolumeSource` from its required fields. Its optional fields are unset.
+    pub fn new(sources: impl Into<Vec<crate::api::core::v1::VolumeProjection>>) -> Self {
+        ProjectedVolumeSource {
+            default_mode: None,
+            sources: sources.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl ProjectedVolumeSource {
+    /// Returns this value with [`ProjectedVolumeSource::default_mode`] set to the given value.
+    #[must_use]
+    pub fn with_default_mode(mut self, value: impl Into<i32>) -> Self {
+        self.default_mode = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ProjectedVolumeSource::sources`] set to the given value.
+    #[must_use]
+    pub fn with_sources(mut self, value: impl Into<Vec<crate::api::core::v1::VolumeProjection>>) -> Self {
+        self.sources = value.into();
+        self
+    }
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for ProjectedVolumeSource {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "defaultMode" => Field::Key_default_mode,
-                            "sources" => Field::Key_sources,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl ProjectedVolumeSource {
+    const FIELDS: &'static [&'static str] = &[
+        "defaultMode",
+        "sources",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ProjectedVolumeSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +60,91 @@ impl<'de> crate::serde::Deserialize<'de> for ProjectedVolumeSource {
                 let mut value_default_mode: Option<i32> = None;
                 let mut value_sources: Option<Vec<crate::api::core::v1::VolumeProjection>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ProjectedVolumeSource", ProjectedVolumeSource::FIELDS))? {
                     match key {
-                        Field::Key_default_mode => value_default_mode = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_sources => value_sources = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_default_mode = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_sources = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ProjectedVolumeSource {
                     default_mode: value_default_mode,
-                    sources: value_sources.ok_or_else(|| crate::serde::de::Error::missing_field("sources"))?,
+                    sources: value_sources.ok_or_else(|| crate::serde2::missing_field("ProjectedVolumeSource", "sources", &["sources"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ProjectedVolumeSource {
+                    default_mode: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    sources: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "ProjectedVolumeSource",
-            &[
-                "defaultMode",
-                "sources",
-            ],
+            ProjectedVolumeSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ProjectedVolumeSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ProjectedVolumeSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_default_mode = false;
+                let mut has_sources = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ProjectedVolumeSource", ProjectedVolumeSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.default_mode))?; has_default_mode = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.sources))?; has_sources = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_default_mode {
+                    self.0.default_mode = None;
+                }
+                if !has_sources {
+                    return Err(crate::serde2::missing_field("ProjectedVolumeSource", "sources", &["sources"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.default_mode))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.sources))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ProjectedVolumeSource",
+            ProjectedVolumeSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ProjectedVolumeSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ProjectedVolumeSource", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "defaultMode", &self.default_mode)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "sources", &self.sources)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ProjectedVolumeSource",
             1 +
@@ -97,10 +158,84 @@ impl crate::serde::Serialize for ProjectedVolumeSource {
     }
 }
 
+/// A builder of [`ProjectedVolumeSource`]. Created with [`ProjectedVolumeSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ProjectedVolumeSourceBuilder {
+    default_mode: Option<i32>,
+    sources: Option<Vec<crate::api::core::v1::VolumeProjection>>,
+}
+
+#[cfg(feature = "builder")]
+impl ProjectedVolumeSource {
+    /// Starts building a `ProjectedVolumeSource`. Its required fields must be set before [`ProjectedVolumeSourceBuilder::build`] is called.
+    pub fn builder() -> ProjectedVolumeSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ProjectedVolumeSourceBuilder {
+    /// Sets [`ProjectedVolumeSource::default_mode`].
+    pub fn default_mode(mut self, value: impl Into<i32>) -> Self {
+        self.default_mode = Some(value.into());
+        self
+    }
+
+    /// Sets [`ProjectedVolumeSource::sources`].
+    pub fn sources(mut self, value: impl Into<Vec<crate::api::core::v1::VolumeProjection>>) -> Self {
+        self.sources = Some(value.into());
+        self
+    }
+
+    /// Builds the `ProjectedVolumeSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ProjectedVolumeSource, crate::MissingFieldError> {
+        Ok(ProjectedVolumeSource {
+            default_mode: self.default_mode,
+            sources: self.sources.ok_or_else(|| crate::MissingFieldError::new("ProjectedVolumeSource", "sources"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`ProjectedVolumeSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProjectedVolumeSourceApplyConfiguration {
+    /// See [`ProjectedVolumeSource::default_mode`].
+    pub default_mode: Option<i32>,
+
+    /// See [`ProjectedVolumeSource::sources`].
+    pub sources: Option<Vec<crate::api::core::v1::VolumeProjectionApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ProjectedVolumeSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ProjectedVolumeSourceApplyConfiguration",
+            self.default_mode.as_ref().map_or(0, |_| 1) +
+            self.sources.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.default_mode {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "defaultMode", value)?;
+        }
+        if let Some(value) = &self.sources {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "sources", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ProjectedVolumeSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.ProjectedVolumeSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::ProjectedVolumeSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.ProjectedVolumeSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -111,8 +246,9 @@ impl crate::schemars::JsonSchema for ProjectedVolumeSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "defaultMode".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -123,8 +259,8 @@ impl crate::schemars::JsonSchema for ProjectedVolumeSource {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "sources".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -138,8 +274,9 @@ impl crate::schemars::JsonSchema for ProjectedVolumeSource {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "sources",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -149,3 +286,13 @@ impl crate::schemars::JsonSchema for ProjectedVolumeSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ProjectedVolumeSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ProjectedVolumeSource {
+            default_mode: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            sources: crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
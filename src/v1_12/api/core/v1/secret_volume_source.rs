@@ -3,7 +3,7 @@
 /// Adapts a Secret into a volume.
 ///
 /// The contents of the target Secret's Data field will be presented in a volume as files using the keys in the Data field as the file names. Secret volumes support ownership management and SELinux relabeling.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct SecretVolumeSource {
     /// Optional: mode bits to use on created files by default. Must be a value between 0 and 0777. Defaults to 0644. Directories within the path are not affected by this setting. This might be in conflict with other options that affect the file mode, like fsGroup, and the result can be other mode bits set.
     pub default_mode: Option<i32>,
@@ -18,43 +18,49 @@ pub struct SecretVolumeSource {
     pub secret_name: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for SecretVolumeSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_default_mode,
-            Key_items,
-            Key_optional,
-            Key_secret_name,
-            Other,
-        }
+impl SecretVolumeSource {
+    /// Returns this value with [`SecretVolumeSource::default_mode`] set to the given value.
+    #[must_use]
+    pub fn with_default_mode(mut self, value: impl Into<i32>) -> Self {
+        self.default_mode = Some(value.into());
+        self
+    }
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+    /// Returns this value with [`SecretVolumeSource::items`] set to the given value.
+    #[must_use]
+    pub fn with_items(mut self, value: impl Into<Vec<crate::api::core::v1::KeyToPath>>) -> Self {
+        self.items = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`SecretVolumeSource::optional`] set to the given value.
+    #[must_use]
+    pub fn with_optional(mut self, value: impl Into<bool>) -> Self {
+        self.optional = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`SecretVolumeSource::secret_name`] set to the given value.
+    #[must_use]
+    pub fn with_secret_name(mut self, value: impl Into<String>) -> Self {
+        self.secret_name = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "defaultMode" => Field::Key_default_mode,
-                            "items" => Field::Key_items,
-                            "optional" => Field::Key_optional,
-                            "secretName" => Field::Key_secret_name,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for SecretVolumeSource {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl SecretVolumeSource {
+    const FIELDS: &'static [&'static str] = &[
+        "defaultMode",
+        "items",
+        "optional",
+        "secretName",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for SecretVolumeSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -70,13 +76,13 @@ impl<'de> crate::serde::Deserialize<'de> for SecretVolumeSource {
                 let mut value_optional: Option<bool> = None;
                 let mut value_secret_name: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("SecretVolumeSource", SecretVolumeSource::FIELDS))? {
                     match key {
-                        Field::Key_default_mode => value_default_mode = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_items => value_items = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_optional => value_optional = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_secret_name => value_secret_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_default_mode = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_items = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_optional = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_secret_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -87,23 +93,94 @@ impl<'de> crate::serde::Deserialize<'de> for SecretVolumeSource {
                     secret_name: value_secret_name,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(SecretVolumeSource {
+                    default_mode: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    items: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    optional: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    secret_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "SecretVolumeSource",
-            &[
-                "defaultMode",
-                "items",
-                "optional",
-                "secretName",
-            ],
+            SecretVolumeSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, SecretVolumeSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("SecretVolumeSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_default_mode = false;
+                let mut has_items = false;
+                let mut has_optional = false;
+                let mut has_secret_name = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("SecretVolumeSource", SecretVolumeSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.default_mode))?; has_default_mode = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.items))?; has_items = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.optional))?; has_optional = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.secret_name))?; has_secret_name = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_default_mode {
+                    self.0.default_mode = None;
+                }
+                if !has_items {
+                    self.0.items = None;
+                }
+                if !has_optional {
+                    self.0.optional = None;
+                }
+                if !has_secret_name {
+                    self.0.secret_name = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.default_mode))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.items))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.optional))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.secret_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "SecretVolumeSource",
+            SecretVolumeSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for SecretVolumeSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("SecretVolumeSource", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "defaultMode", &self.default_mode)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "items", &self.items)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "optional", &self.optional)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretName", &self.secret_name)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "SecretVolumeSource",
             self.default_mode.as_ref().map_or(0, |_| 1) +
@@ -127,10 +204,114 @@ impl crate::serde::Serialize for SecretVolumeSource {
     }
 }
 
+/// A builder of [`SecretVolumeSource`]. Created with [`SecretVolumeSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct SecretVolumeSourceBuilder {
+    default_mode: Option<i32>,
+    items: Option<Vec<crate::api::core::v1::KeyToPath>>,
+    optional: Option<bool>,
+    secret_name: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl SecretVolumeSource {
+    /// Starts building a `SecretVolumeSource`. Its required fields must be set before [`SecretVolumeSourceBuilder::build`] is called.
+    pub fn builder() -> SecretVolumeSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl SecretVolumeSourceBuilder {
+    /// Sets [`SecretVolumeSource::default_mode`].
+    pub fn default_mode(mut self, value: impl Into<i32>) -> Self {
+        self.default_mode = Some(value.into());
+        self
+    }
+
+    /// Sets [`SecretVolumeSource::items`].
+    pub fn items(mut self, value: impl Into<Vec<crate::api::core::v1::KeyToPath>>) -> Self {
+        self.items = Some(value.into());
+        self
+    }
+
+    /// Sets [`SecretVolumeSource::optional`].
+    pub fn optional(mut self, value: impl Into<bool>) -> Self {
+        self.optional = Some(value.into());
+        self
+    }
+
+    /// Sets [`SecretVolumeSource::secret_name`].
+    pub fn secret_name(mut self, value: impl Into<String>) -> Self {
+        self.secret_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `SecretVolumeSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<SecretVolumeSource, crate::MissingFieldError> {
+        Ok(SecretVolumeSource {
+            default_mode: self.default_mode,
+            items: self.items,
+            optional: self.optional,
+            secret_name: self.secret_name,
+        })
+    }
+}
+
+/// The apply configuration of [`SecretVolumeSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SecretVolumeSourceApplyConfiguration {
+    /// See [`SecretVolumeSource::default_mode`].
+    pub default_mode: Option<i32>,
+
+    /// See [`SecretVolumeSource::items`].
+    pub items: Option<Vec<crate::api::core::v1::KeyToPathApplyConfiguration>>,
+
+    /// See [`SecretVolumeSource::optional`].
+    pub optional: Option<bool>,
+
+    /// See [`SecretVolumeSource::secret_name`].
+    pub secret_name: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for SecretVolumeSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "SecretVolumeSourceApplyConfiguration",
+            self.default_mode.as_ref().map_or(0, |_| 1) +
+            self.items.as_ref().map_or(0, |_| 1) +
+            self.optional.as_ref().map_or(0, |_| 1) +
+            self.secret_name.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.default_mode {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "defaultMode", value)?;
+        }
+        if let Some(value) = &self.items {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "items", value)?;
+        }
+        if let Some(value) = &self.optional {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "optional", value)?;
+        }
+        if let Some(value) = &self.secret_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretName", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for SecretVolumeSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.SecretVolumeSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::SecretVolumeSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.SecretVolumeSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -141,8 +322,9 @@ impl crate::schemars::JsonSchema for SecretVolumeSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "defaultMode".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -153,8 +335,8 @@ impl crate::schemars::JsonSchema for SecretVolumeSource {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "items".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -168,8 +350,8 @@ impl crate::schemars::JsonSchema for SecretVolumeSource {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "optional".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -179,8 +361,8 @@ impl crate::schemars::JsonSchema for SecretVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "secretName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -190,11 +372,24 @@ impl crate::schemars::JsonSchema for SecretVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for SecretVolumeSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(SecretVolumeSource {
+            default_mode: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            items: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            optional: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            secret_name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
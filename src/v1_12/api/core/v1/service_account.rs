@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.ServiceAccount
 
 /// ServiceAccount binds together: * a name, understood by users, and perhaps by peripheral systems, for an identity * a principal that can be authenticated and authorized * a set of secrets
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ServiceAccount {
     /// AutomountServiceAccountToken indicates whether pods running as this service account should have an API token automatically mounted. Can be overridden at the pod level.
     pub automount_service_account_token: Option<bool>,
@@ -16,6 +16,48 @@ pub struct ServiceAccount {
     pub secrets: Option<Vec<crate::api::core::v1::ObjectReference>>,
 }
 
+impl ServiceAccount {
+    /// Constructs a `ServiceAccount` from its required fields. Its optional fields are unset.
+    pub fn new(metadata: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        ServiceAccount {
+            automount_service_account_token: None,
+            image_pull_secrets: None,
+            metadata: metadata.into(),
+            secrets: None,
+        }
+    }
+}
+
+impl ServiceAccount {
+    /// Returns this value with [`ServiceAccount::automount_service_account_token`] set to the given value.
+    #[must_use]
+    pub fn with_automount_service_account_token(mut self, value: impl Into<bool>) -> Self {
+        self.automount_service_account_token = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ServiceAccount::image_pull_secrets`] set to the given value.
+    #[must_use]
+    pub fn with_image_pull_secrets(mut self, value: impl Into<Vec<crate::api::core::v1::LocalObjectReference>>) -> Self {
+        self.image_pull_secrets = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ServiceAccount::metadata`] set to the given value.
+    #[must_use]
+    pub fn with_metadata(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        self.metadata = value.into();
+        self
+    }
+
+    /// Returns this value with [`ServiceAccount::secrets`] set to the given value.
+    #[must_use]
+    pub fn with_secrets(mut self, value: impl Into<Vec<crate::api::core::v1::ObjectReference>>) -> Self {
+        self.secrets = Some(value.into());
+        self
+    }
+}
+
 // Begin /v1/ServiceAccount
 
 // Generated from operation createCoreV1NamespacedServiceAccount
@@ -42,6 +84,7 @@ impl ServiceAccount {
         body: &crate::api::core::v1::ServiceAccount,
         optional: crate::CreateOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::CreateResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/api/v1/namespaces/{namespace}/serviceaccounts?",
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -85,6 +128,7 @@ impl ServiceAccount {
         delete_optional: crate::DeleteOptional<'_>,
         list_optional: crate::ListOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::DeleteResponse<crate::List<Self>>>), crate::RequestError> {
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/api/v1/namespaces/{namespace}/serviceaccounts?",
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -128,6 +172,8 @@ impl ServiceAccount {
         namespace: &str,
         optional: crate::DeleteOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::DeleteResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/api/v1/namespaces/{namespace}/serviceaccounts/{name}",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -166,6 +212,7 @@ impl ServiceAccount {
         namespace: &str,
         optional: crate::ListOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::ListResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/api/v1/namespaces/{namespace}/serviceaccounts?",
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -243,6 +290,8 @@ impl ServiceAccount {
         body: &crate::apimachinery::pkg::apis::meta::v1::Patch,
         optional: crate::PatchOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::PatchResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/api/v1/namespaces/{namespace}/serviceaccounts/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -296,6 +345,8 @@ impl ServiceAccount {
             export,
             pretty,
         } = optional;
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/api/v1/namespaces/{namespace}/serviceaccounts/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -400,6 +451,8 @@ impl ServiceAccount {
         body: &crate::api::core::v1::ServiceAccount,
         optional: crate::ReplaceOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::ReplaceResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/api/v1/namespaces/{namespace}/serviceaccounts/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -441,6 +494,7 @@ impl ServiceAccount {
         namespace: &str,
         optional: crate::WatchOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::WatchResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/api/v1/namespaces/{namespace}/serviceaccounts?",
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -497,6 +551,9 @@ impl crate::Resource for ServiceAccount {
     const KIND: &'static str = "ServiceAccount";
     const VERSION: &'static str = "v1";
     const URL_PATH_SEGMENT: &'static str = "serviceaccounts";
+    const VERBS: &'static [&'static str] = &["create", "delete", "deletecollection", "get", "list", "patch", "update", "watch"];
+    const SINGULAR: &'static str = "serviceaccount";
+    const SHORT_NAMES: &'static [&'static str] = &["sa"];
     type Scope = crate::NamespaceResourceScope;
 }
 
@@ -516,47 +573,35 @@ impl crate::Metadata for ServiceAccount {
     }
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ServiceAccount {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_api_version,
-            Key_kind,
-            Key_automount_service_account_token,
-            Key_image_pull_secrets,
-            Key_metadata,
-            Key_secrets,
-            Other,
+impl std::fmt::Display for ServiceAccount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(<Self as crate::Resource>::URL_PATH_SEGMENT)?;
+        for part in [&self.metadata.namespace, &self.metadata.name].iter().copied().flatten() {
+            write!(f, "/{part}")?;
         }
+        Ok(())
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
-
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "apiVersion" => Field::Key_api_version,
-                            "kind" => Field::Key_kind,
-                            "automountServiceAccountToken" => Field::Key_automount_service_account_token,
-                            "imagePullSecrets" => Field::Key_image_pull_secrets,
-                            "metadata" => Field::Key_metadata,
-                            "secrets" => Field::Key_secrets,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for ServiceAccount {
+    const FIELD_PATCH_STRATEGIES: &'static [crate::FieldPatchStrategy] = &[
+        crate::FieldPatchStrategy { field: "secrets", merge: true, retain_keys: false, merge_key: Some("name") },
+    ];
+}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl ServiceAccount {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "kind",
+        "automountServiceAccountToken",
+        "imagePullSecrets",
+        "metadata",
+        "secrets",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ServiceAccount {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -572,54 +617,135 @@ impl<'de> crate::serde::Deserialize<'de> for ServiceAccount {
                 let mut value_metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> = None;
                 let mut value_secrets: Option<Vec<crate::api::core::v1::ObjectReference>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ServiceAccount", ServiceAccount::FIELDS))? {
                     match key {
-                        Field::Key_api_version => {
+                        Some(0) => {
                             let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_api_version != <Self::Value as crate::Resource>::API_VERSION {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<Self::Value as crate::Resource>::API_VERSION));
                             }
                         },
-                        Field::Key_kind => {
+                        Some(1) => {
                             let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_kind != <Self::Value as crate::Resource>::KIND {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<Self::Value as crate::Resource>::KIND));
                             }
                         },
-                        Field::Key_automount_service_account_token => value_automount_service_account_token = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_image_pull_secrets => value_image_pull_secrets = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_metadata => value_metadata = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_secrets => value_secrets = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(2) => value_automount_service_account_token = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_image_pull_secrets = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_metadata = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(5) => value_secrets = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ServiceAccount {
                     automount_service_account_token: value_automount_service_account_token,
                     image_pull_secrets: value_image_pull_secrets,
-                    metadata: value_metadata.ok_or_else(|| crate::serde::de::Error::missing_field("metadata"))?,
+                    metadata: value_metadata.ok_or_else(|| crate::serde2::missing_field("ServiceAccount", "metadata", &["metadata"]))?,
                     secrets: value_secrets,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ServiceAccount {
+                    automount_service_account_token: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    image_pull_secrets: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    metadata: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    secrets: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             <Self as crate::Resource>::KIND,
-            &[
-                "apiVersion",
-                "kind",
-                "automountServiceAccountToken",
-                "imagePullSecrets",
-                "metadata",
-                "secrets",
-            ],
+            ServiceAccount::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ServiceAccount> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<ServiceAccount as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_automount_service_account_token = false;
+                let mut has_image_pull_secrets = false;
+                let mut has_metadata = false;
+                let mut has_secrets = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ServiceAccount", ServiceAccount::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <ServiceAccount as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<ServiceAccount as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <ServiceAccount as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<ServiceAccount as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.automount_service_account_token))?; has_automount_service_account_token = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.image_pull_secrets))?; has_image_pull_secrets = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metadata))?; has_metadata = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.secrets))?; has_secrets = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_automount_service_account_token {
+                    self.0.automount_service_account_token = None;
+                }
+                if !has_image_pull_secrets {
+                    self.0.image_pull_secrets = None;
+                }
+                if !has_metadata {
+                    return Err(crate::serde2::missing_field("ServiceAccount", "metadata", &["metadata"]));
+                }
+                if !has_secrets {
+                    self.0.secrets = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.automount_service_account_token))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.image_pull_secrets))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metadata))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.secrets))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            ServiceAccount::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ServiceAccount {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct(<Self as crate::Resource>::KIND, 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "automountServiceAccountToken", &self.automount_service_account_token)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "imagePullSecrets", &self.image_pull_secrets)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", &self.metadata)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secrets", &self.secrets)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             <Self as crate::Resource>::KIND,
             3 +
@@ -643,10 +769,117 @@ impl crate::serde::Serialize for ServiceAccount {
     }
 }
 
+/// A builder of [`ServiceAccount`]. Created with [`ServiceAccount::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ServiceAccountBuilder {
+    automount_service_account_token: Option<bool>,
+    image_pull_secrets: Option<Vec<crate::api::core::v1::LocalObjectReference>>,
+    metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>,
+    secrets: Option<Vec<crate::api::core::v1::ObjectReference>>,
+}
+
+#[cfg(feature = "builder")]
+impl ServiceAccount {
+    /// Starts building a `ServiceAccount`. Its required fields must be set before [`ServiceAccountBuilder::build`] is called.
+    pub fn builder() -> ServiceAccountBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ServiceAccountBuilder {
+    /// Sets [`ServiceAccount::automount_service_account_token`].
+    pub fn automount_service_account_token(mut self, value: impl Into<bool>) -> Self {
+        self.automount_service_account_token = Some(value.into());
+        self
+    }
+
+    /// Sets [`ServiceAccount::image_pull_secrets`].
+    pub fn image_pull_secrets(mut self, value: impl Into<Vec<crate::api::core::v1::LocalObjectReference>>) -> Self {
+        self.image_pull_secrets = Some(value.into());
+        self
+    }
+
+    /// Sets [`ServiceAccount::metadata`].
+    pub fn metadata(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        self.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`ServiceAccount::secrets`].
+    pub fn secrets(mut self, value: impl Into<Vec<crate::api::core::v1::ObjectReference>>) -> Self {
+        self.secrets = Some(value.into());
+        self
+    }
+
+    /// Builds the `ServiceAccount`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ServiceAccount, crate::MissingFieldError> {
+        Ok(ServiceAccount {
+            automount_service_account_token: self.automount_service_account_token,
+            image_pull_secrets: self.image_pull_secrets,
+            metadata: self.metadata.ok_or_else(|| crate::MissingFieldError::new("ServiceAccount", "metadata"))?,
+            secrets: self.secrets,
+        })
+    }
+}
+
+/// The apply configuration of [`ServiceAccount`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ServiceAccountApplyConfiguration {
+    /// See [`ServiceAccount::automount_service_account_token`].
+    pub automount_service_account_token: Option<bool>,
+
+    /// See [`ServiceAccount::image_pull_secrets`].
+    pub image_pull_secrets: Option<Vec<crate::api::core::v1::LocalObjectReferenceApplyConfiguration>>,
+
+    /// See [`ServiceAccount::metadata`].
+    pub metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMetaApplyConfiguration>,
+
+    /// See [`ServiceAccount::secrets`].
+    pub secrets: Option<Vec<crate::api::core::v1::ObjectReferenceApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ServiceAccountApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            <ServiceAccount as crate::Resource>::KIND,
+            2 +
+            self.automount_service_account_token.as_ref().map_or(0, |_| 1) +
+            self.image_pull_secrets.as_ref().map_or(0, |_| 1) +
+            self.metadata.as_ref().map_or(0, |_| 1) +
+            self.secrets.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <ServiceAccount as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <ServiceAccount as crate::Resource>::KIND)?;
+        if let Some(value) = &self.automount_service_account_token {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "automountServiceAccountToken", value)?;
+        }
+        if let Some(value) = &self.image_pull_secrets {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "imagePullSecrets", value)?;
+        }
+        if let Some(value) = &self.metadata {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", value)?;
+        }
+        if let Some(value) = &self.secrets {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secrets", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ServiceAccount {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.ServiceAccount".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::ServiceAccount".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.ServiceAccount".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -657,8 +890,9 @@ impl crate::schemars::JsonSchema for ServiceAccount {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "apiVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -666,10 +900,11 @@ impl crate::schemars::JsonSchema for ServiceAccount {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("v1".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "automountServiceAccountToken".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -679,8 +914,8 @@ impl crate::schemars::JsonSchema for ServiceAccount {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "imagePullSecrets".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -694,8 +929,8 @@ impl crate::schemars::JsonSchema for ServiceAccount {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "kind".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -703,10 +938,11 @@ impl crate::schemars::JsonSchema for ServiceAccount {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("ServiceAccount".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "metadata".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>().into_object();
@@ -716,8 +952,8 @@ impl crate::schemars::JsonSchema for ServiceAccount {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "secrets".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -731,9 +967,12 @@ impl crate::schemars::JsonSchema for ServiceAccount {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
+                    "apiVersion",
+                    "kind",
                     "metadata",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
                 ..Default::default()
@@ -742,3 +981,15 @@ impl crate::schemars::JsonSchema for ServiceAccount {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ServiceAccount {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ServiceAccount {
+            automount_service_account_token: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            image_pull_secrets: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            metadata: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            secrets: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
@@ -1,80 +1,118 @@
 // Generated from definition io.k8s.api.core.v1.ServiceStatus
 
 /// ServiceStatus represents the current status of a service.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ServiceStatus {
     /// LoadBalancer contains the current status of the load-balancer, if one is present.
     pub load_balancer: Option<crate::api::core::v1::LoadBalancerStatus>,
 }
 
+impl ServiceStatus {
+    /// Returns this value with [`ServiceStatus::load_balancer`] set to the given value.
+    #[must_use]
+    pub fn with_load_balancer(mut self, value: impl Into<crate::api::core::v1::LoadBalancerStatus>) -> Self {
+        self.load_balancer = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for ServiceStatus {}
+
+impl ServiceStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "loadBalancer",
+    ];
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ServiceStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_load_balancer,
-            Other,
-        }
+        struct Visitor;
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = ServiceStatus;
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ServiceStatus")
+            }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_load_balancer: Option<crate::api::core::v1::LoadBalancerStatus> = None;
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "loadBalancer" => Field::Key_load_balancer,
-                            _ => Field::Other,
-                        })
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ServiceStatus", ServiceStatus::FIELDS))? {
+                    match key {
+                        Some(0) => value_load_balancer = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
-                deserializer.deserialize_identifier(Visitor)
+                Ok(ServiceStatus {
+                    load_balancer: value_load_balancer,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ServiceStatus {
+                    load_balancer: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                })
             }
         }
 
-        struct Visitor;
+        deserializer.deserialize_struct(
+            "ServiceStatus",
+            ServiceStatus::FIELDS,
+            Visitor,
+        )
+    }
 
-        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-            type Value = ServiceStatus;
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ServiceStatus> {
+            type Value = ();
 
             fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 f.write_str("ServiceStatus")
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
-                let mut value_load_balancer: Option<crate::api::core::v1::LoadBalancerStatus> = None;
+                let mut has_load_balancer = false;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ServiceStatus", ServiceStatus::FIELDS))? {
                     match key {
-                        Field::Key_load_balancer => value_load_balancer = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.load_balancer))?; has_load_balancer = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
+                if !has_load_balancer {
+                    self.0.load_balancer = None;
+                }
 
-                Ok(ServiceStatus {
-                    load_balancer: value_load_balancer,
-                })
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.load_balancer))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
             }
         }
 
         deserializer.deserialize_struct(
             "ServiceStatus",
-            &[
-                "loadBalancer",
-            ],
-            Visitor,
+            ServiceStatus::FIELDS,
+            InPlaceVisitor(place),
         )
     }
 }
 
 impl crate::serde::Serialize for ServiceStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ServiceStatus", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "loadBalancer", &self.load_balancer)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ServiceStatus",
             self.load_balancer.as_ref().map_or(0, |_| 1),
@@ -86,10 +124,69 @@ impl crate::serde::Serialize for ServiceStatus {
     }
 }
 
+/// A builder of [`ServiceStatus`]. Created with [`ServiceStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ServiceStatusBuilder {
+    load_balancer: Option<crate::api::core::v1::LoadBalancerStatus>,
+}
+
+#[cfg(feature = "builder")]
+impl ServiceStatus {
+    /// Starts building a `ServiceStatus`. Its required fields must be set before [`ServiceStatusBuilder::build`] is called.
+    pub fn builder() -> ServiceStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ServiceStatusBuilder {
+    /// Sets [`ServiceStatus::load_balancer`].
+    pub fn load_balancer(mut self, value: impl Into<crate::api::core::v1::LoadBalancerStatus>) -> Self {
+        self.load_balancer = Some(value.into());
+        self
+    }
+
+    /// Builds the `ServiceStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ServiceStatus, crate::MissingFieldError> {
+        Ok(ServiceStatus {
+            load_balancer: self.load_balancer,
+        })
+    }
+}
+
+/// The apply configuration of [`ServiceStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ServiceStatusApplyConfiguration {
+    /// See [`ServiceStatus::load_balancer`].
+    pub load_balancer: Option<crate::api::core::v1::LoadBalancerStatusApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ServiceStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ServiceStatusApplyConfiguration",
+            self.load_balancer.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.load_balancer {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "loadBalancer", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ServiceStatus {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.ServiceStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::ServiceStatus".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.ServiceStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -100,8 +197,9 @@ impl crate::schemars::JsonSchema for ServiceStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "loadBalancer".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::LoadBalancerStatus>().into_object();
@@ -111,11 +209,21 @@ impl crate::schemars::JsonSchema for ServiceStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ServiceStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ServiceStatus {
+            load_balancer: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
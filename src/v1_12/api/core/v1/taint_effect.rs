@@ -0,0 +1,158 @@
+// Generated from definition io.k8s.api.core.v1.TaintEffect
+
+/// TaintEffect is the effect of a taint on pods that do not tolerate the taint.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum TaintEffect {
+    /// `NoSchedule`
+    NoSchedule,
+
+    /// `PreferNoSchedule`
+    PreferNoSchedule,
+
+    /// `NoExecute`
+    NoExecute,
+
+    /// A value that is not one of the values known to this version of the crate, such as one that was added in a newer version of Kubernetes.
+    Other(String),
+}
+
+impl TaintEffect {
+    /// The values known to this version of the crate, in the order of the variants.
+    pub const VALUES: &'static [&'static str] = &["NoSchedule", "PreferNoSchedule", "NoExecute"];
+
+    /// The value as it is serialized.
+    pub fn as_str(&self) -> &str {
+        match self {
+            TaintEffect::NoSchedule => "NoSchedule",
+            TaintEffect::PreferNoSchedule => "PreferNoSchedule",
+            TaintEffect::NoExecute => "NoExecute",
+            TaintEffect::Other(value) => value,
+        }
+    }
+}
+
+impl AsRef<str> for TaintEffect {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Default for TaintEffect {
+    fn default() -> Self {
+        TaintEffect::Other(String::new())
+    }
+}
+
+impl std::fmt::Display for TaintEffect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for TaintEffect {
+    fn from(value: &str) -> Self {
+        match value {
+            "NoSchedule" => TaintEffect::NoSchedule,
+            "PreferNoSchedule" => TaintEffect::PreferNoSchedule,
+            "NoExecute" => TaintEffect::NoExecute,
+            value => TaintEffect::Other(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaintEffect {
+    fn from(value: String) -> Self {
+        match &*value {
+            "NoSchedule" => TaintEffect::NoSchedule,
+            "PreferNoSchedule" => TaintEffect::PreferNoSchedule,
+            "NoExecute" => TaintEffect::NoExecute,
+            _ => TaintEffect::Other(value),
+        }
+    }
+}
+
+impl From<TaintEffect> for String {
+    fn from(value: TaintEffect) -> Self {
+        match value {
+            TaintEffect::Other(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl PartialEq<str> for TaintEffect {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for TaintEffect {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<'de> crate::serde::Deserialize<'de> for TaintEffect {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl crate::serde::de::Visitor<'_> for Visitor {
+            type Value = TaintEffect;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("TaintEffect")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
+                Ok(v.into())
+            }
+
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E> where E: crate::serde::de::Error {
+                Ok(v.into())
+            }
+        }
+
+        deserializer.deserialize_string(Visitor)
+    }
+}
+
+impl crate::serde::Serialize for TaintEffect {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for TaintEffect {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::TaintEffect".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.TaintEffect".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("TaintEffect is the effect of a taint on pods that do not tolerate the taint.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for TaintEffect {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        Ok(match u.int_in_range(0..=3_u8)? {
+            0 => TaintEffect::NoSchedule,
+            1 => TaintEffect::PreferNoSchedule,
+            2 => TaintEffect::NoExecute,
+            _ => TaintEffect::from(u.arbitrary::<String>()?),
+        })
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.VolumeDevice
 
 /// volumeDevice describes a mapping of a raw block device within a container.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct VolumeDevice {
     /// devicePath is the path inside of the container that the device will be mapped to.
     pub device_path: String,
@@ -10,39 +10,43 @@ pub struct VolumeDevice {
     pub name: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for VolumeDevice {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_device_path,
-            Key_name,
-            Other,
+impl VolumeDevice {
+    /// Constructs a `VolumeDevice` from its required fields. Its optional fields are unset.
+    pub fn new(device_path: impl Into<String>, name: impl Into<String>) -> Self {
+        VolumeDevice {
+            device_path: device_path.into(),
+            name: name.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl VolumeDevice {
+    /// Returns this value with [`VolumeDevice::device_path`] set to the given value.
+    #[must_use]
+    pub fn with_device_path(mut self, value: impl Into<String>) -> Self {
+        self.device_path = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`VolumeDevice::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "devicePath" => Field::Key_device_path,
-                            "name" => Field::Key_name,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for VolumeDevice {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl VolumeDevice {
+    const FIELDS: &'static [&'static str] = &[
+        "devicePath",
+        "name",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for VolumeDevice {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +60,91 @@ impl<'de> crate::serde::Deserialize<'de> for VolumeDevice {
                 let mut value_device_path: Option<String> = None;
                 let mut value_name: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("VolumeDevice", VolumeDevice::FIELDS))? {
                     match key {
-                        Field::Key_device_path => value_device_path = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_name => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_device_path = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(VolumeDevice {
-                    device_path: value_device_path.ok_or_else(|| crate::serde::de::Error::missing_field("devicePath"))?,
-                    name: value_name.ok_or_else(|| crate::serde::de::Error::missing_field("name"))?,
+                    device_path: value_device_path.ok_or_else(|| crate::serde2::missing_field("VolumeDevice", "devicePath", &["devicePath", "name"]))?,
+                    name: value_name.ok_or_else(|| crate::serde2::missing_field("VolumeDevice", "name", &["devicePath", "name"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(VolumeDevice {
+                    device_path: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "VolumeDevice",
-            &[
-                "devicePath",
-                "name",
-            ],
+            VolumeDevice::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, VolumeDevice> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("VolumeDevice")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_device_path = false;
+                let mut has_name = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("VolumeDevice", VolumeDevice::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.device_path))?; has_device_path = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.name))?; has_name = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_device_path {
+                    return Err(crate::serde2::missing_field("VolumeDevice", "devicePath", &["devicePath", "name"]));
+                }
+                if !has_name {
+                    return Err(crate::serde2::missing_field("VolumeDevice", "name", &["devicePath", "name"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.device_path))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "VolumeDevice",
+            VolumeDevice::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for VolumeDevice {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("VolumeDevice", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "devicePath", &self.device_path)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "VolumeDevice",
             2,
@@ -94,10 +155,84 @@ impl crate::serde::Serialize for VolumeDevice {
     }
 }
 
+/// A builder of [`VolumeDevice`]. Created with [`VolumeDevice::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct VolumeDeviceBuilder {
+    device_path: Option<String>,
+    name: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl VolumeDevice {
+    /// Starts building a `VolumeDevice`. Its required fields must be set before [`VolumeDeviceBuilder::build`] is called.
+    pub fn builder() -> VolumeDeviceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl VolumeDeviceBuilder {
+    /// Sets [`VolumeDevice::device_path`].
+    pub fn device_path(mut self, value: impl Into<String>) -> Self {
+        self.device_path = Some(value.into());
+        self
+    }
+
+    /// Sets [`VolumeDevice::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `VolumeDevice`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<VolumeDevice, crate::MissingFieldError> {
+        Ok(VolumeDevice {
+            device_path: self.device_path.ok_or_else(|| crate::MissingFieldError::new("VolumeDevice", "devicePath"))?,
+            name: self.name.ok_or_else(|| crate::MissingFieldError::new("VolumeDevice", "name"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`VolumeDevice`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VolumeDeviceApplyConfiguration {
+    /// See [`VolumeDevice::device_path`].
+    pub device_path: Option<String>,
+
+    /// See [`VolumeDevice::name`].
+    pub name: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for VolumeDeviceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "VolumeDeviceApplyConfiguration",
+            self.device_path.as_ref().map_or(0, |_| 1) +
+            self.name.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.device_path {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "devicePath", value)?;
+        }
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for VolumeDevice {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.VolumeDevice".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::VolumeDevice".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.VolumeDevice".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -108,8 +243,9 @@ impl crate::schemars::JsonSchema for VolumeDevice {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "devicePath".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -119,8 +255,8 @@ impl crate::schemars::JsonSchema for VolumeDevice {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -130,8 +266,9 @@ impl crate::schemars::JsonSchema for VolumeDevice {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "devicePath",
                     "name",
@@ -142,3 +279,13 @@ impl crate::schemars::JsonSchema for VolumeDevice {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for VolumeDevice {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(VolumeDevice {
+            device_path: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.VolumeMount
 
 /// VolumeMount describes a mounting of a Volume within a container.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct VolumeMount {
     /// Path within the container at which the volume should be mounted.  Must not contain ':'.
     pub mount_path: String,
@@ -19,45 +19,70 @@ pub struct VolumeMount {
     pub sub_path: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for VolumeMount {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_mount_path,
-            Key_mount_propagation,
-            Key_name,
-            Key_read_only,
-            Key_sub_path,
-            Other,
+impl VolumeMount {
+    /// Constructs a `VolumeMount` from its required fields. Its optional fields are unset.
+    pub fn new(mount_path: impl Into<String>, name: impl Into<String>) -> Self {
+        VolumeMount {
+            mount_path: mount_path.into(),
+            mount_propagation: None,
+            name: name.into(),
+            read_only: None,
+            sub_path: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl VolumeMount {
+    /// Returns this value with [`VolumeMount::mount_path`] set to the given value.
+    #[must_use]
+    pub fn with_mount_path(mut self, value: impl Into<String>) -> Self {
+        self.mount_path = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`VolumeMount::mount_propagation`] set to the given value.
+    #[must_use]
+    pub fn with_mount_propagation(mut self, value: impl Into<String>) -> Self {
+        self.mount_propagation = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`VolumeMount::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "mountPath" => Field::Key_mount_path,
-                            "mountPropagation" => Field::Key_mount_propagation,
-                            "name" => Field::Key_name,
-                            "readOnly" => Field::Key_read_only,
-                            "subPath" => Field::Key_sub_path,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`VolumeMount::read_only`] set to the given value.
+    #[must_use]
+    pub fn with_read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`VolumeMount::sub_path`] set to the given value.
+    #[must_use]
+    pub fn with_sub_path(mut self, value: impl Into<String>) -> Self {
+        self.sub_path = Some(value.into());
+        self
+    }
+}
 
+impl crate::MergeStrategyInfo for VolumeMount {}
+
+impl VolumeMount {
+    const FIELDS: &'static [&'static str] = &[
+        "mountPath",
+        "mountPropagation",
+        "name",
+        "readOnly",
+        "subPath",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for VolumeMount {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -74,43 +99,121 @@ impl<'de> crate::serde::Deserialize<'de> for VolumeMount {
                 let mut value_read_only: Option<bool> = None;
                 let mut value_sub_path: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("VolumeMount", VolumeMount::FIELDS))? {
                     match key {
-                        Field::Key_mount_path => value_mount_path = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_mount_propagation => value_mount_propagation = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_name => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_read_only => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_sub_path => value_sub_path = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_mount_path = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_mount_propagation = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_sub_path = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(VolumeMount {
-                    mount_path: value_mount_path.ok_or_else(|| crate::serde::de::Error::missing_field("mountPath"))?,
+                    mount_path: value_mount_path.ok_or_else(|| crate::serde2::missing_field("VolumeMount", "mountPath", &["mountPath", "name"]))?,
                     mount_propagation: value_mount_propagation,
-                    name: value_name.ok_or_else(|| crate::serde::de::Error::missing_field("name"))?,
+                    name: value_name.ok_or_else(|| crate::serde2::missing_field("VolumeMount", "name", &["mountPath", "name"]))?,
                     read_only: value_read_only,
                     sub_path: value_sub_path,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(VolumeMount {
+                    mount_path: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    mount_propagation: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    read_only: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    sub_path: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "VolumeMount",
-            &[
-                "mountPath",
-                "mountPropagation",
-                "name",
-                "readOnly",
-                "subPath",
-            ],
+            VolumeMount::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, VolumeMount> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("VolumeMount")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_mount_path = false;
+                let mut has_mount_propagation = false;
+                let mut has_name = false;
+                let mut has_read_only = false;
+                let mut has_sub_path = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("VolumeMount", VolumeMount::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.mount_path))?; has_mount_path = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.mount_propagation))?; has_mount_propagation = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.name))?; has_name = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.read_only))?; has_read_only = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.sub_path))?; has_sub_path = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_mount_path {
+                    return Err(crate::serde2::missing_field("VolumeMount", "mountPath", &["mountPath", "name"]));
+                }
+                if !has_mount_propagation {
+                    self.0.mount_propagation = None;
+                }
+                if !has_name {
+                    return Err(crate::serde2::missing_field("VolumeMount", "name", &["mountPath", "name"]));
+                }
+                if !has_read_only {
+                    self.0.read_only = None;
+                }
+                if !has_sub_path {
+                    self.0.sub_path = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.mount_path))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.mount_propagation))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.read_only))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.sub_path))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "VolumeMount",
+            VolumeMount::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for VolumeMount {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("VolumeMount", 5)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "mountPath", &self.mount_path)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "mountPropagation", &self.mount_propagation)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", &self.read_only)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "subPath", &self.sub_path)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "VolumeMount",
             2 +
@@ -133,10 +236,129 @@ impl crate::serde::Serialize for VolumeMount {
     }
 }
 
+/// A builder of [`VolumeMount`]. Created with [`VolumeMount::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct VolumeMountBuilder {
+    mount_path: Option<String>,
+    mount_propagation: Option<String>,
+    name: Option<String>,
+    read_only: Option<bool>,
+    sub_path: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl VolumeMount {
+    /// Starts building a `VolumeMount`. Its required fields must be set before [`VolumeMountBuilder::build`] is called.
+    pub fn builder() -> VolumeMountBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl VolumeMountBuilder {
+    /// Sets [`VolumeMount::mount_path`].
+    pub fn mount_path(mut self, value: impl Into<String>) -> Self {
+        self.mount_path = Some(value.into());
+        self
+    }
+
+    /// Sets [`VolumeMount::mount_propagation`].
+    pub fn mount_propagation(mut self, value: impl Into<String>) -> Self {
+        self.mount_propagation = Some(value.into());
+        self
+    }
+
+    /// Sets [`VolumeMount::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`VolumeMount::read_only`].
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`VolumeMount::sub_path`].
+    pub fn sub_path(mut self, value: impl Into<String>) -> Self {
+        self.sub_path = Some(value.into());
+        self
+    }
+
+    /// Builds the `VolumeMount`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<VolumeMount, crate::MissingFieldError> {
+        Ok(VolumeMount {
+            mount_path: self.mount_path.ok_or_else(|| crate::MissingFieldError::new("VolumeMount", "mountPath"))?,
+            mount_propagation: self.mount_propagation,
+            name: self.name.ok_or_else(|| crate::MissingFieldError::new("VolumeMount", "name"))?,
+            read_only: self.read_only,
+            sub_path: self.sub_path,
+        })
+    }
+}
+
+/// The apply configuration of [`VolumeMount`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VolumeMountApplyConfiguration {
+    /// See [`VolumeMount::mount_path`].
+    pub mount_path: Option<String>,
+
+    /// See [`VolumeMount::mount_propagation`].
+    pub mount_propagation: Option<String>,
+
+    /// See [`VolumeMount::name`].
+    pub name: Option<String>,
+
+    /// See [`VolumeMount::read_only`].
+    pub read_only: Option<bool>,
+
+    /// See [`VolumeMount::sub_path`].
+    pub sub_path: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for VolumeMountApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "VolumeMountApplyConfiguration",
+            self.mount_path.as_ref().map_or(0, |_| 1) +
+            self.mount_propagation.as_ref().map_or(0, |_| 1) +
+            self.name.as_ref().map_or(0, |_| 1) +
+            self.read_only.as_ref().map_or(0, |_| 1) +
+            self.sub_path.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.mount_path {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "mountPath", value)?;
+        }
+        if let Some(value) = &self.mount_propagation {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "mountPropagation", value)?;
+        }
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        if let Some(value) = &self.read_only {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", value)?;
+        }
+        if let Some(value) = &self.sub_path {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "subPath", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for VolumeMount {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.VolumeMount".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::VolumeMount".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.VolumeMount".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -147,8 +369,9 @@ impl crate::schemars::JsonSchema for VolumeMount {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "mountPath".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -158,8 +381,8 @@ impl crate::schemars::JsonSchema for VolumeMount {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "mountPropagation".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -169,8 +392,8 @@ impl crate::schemars::JsonSchema for VolumeMount {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -180,8 +403,8 @@ impl crate::schemars::JsonSchema for VolumeMount {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "readOnly".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -191,8 +414,8 @@ impl crate::schemars::JsonSchema for VolumeMount {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "subPath".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -202,8 +425,9 @@ impl crate::schemars::JsonSchema for VolumeMount {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "mountPath",
                     "name",
@@ -214,3 +438,16 @@ impl crate::schemars::JsonSchema for VolumeMount {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for VolumeMount {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(VolumeMount {
+            mount_path: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            mount_propagation: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            read_only: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            sub_path: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.events.v1beta1.EventSeries
 
 /// EventSeries contain information on series of events, i.e. thing that was/is happening continuously for some time.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct EventSeries {
     /// Number of occurrences in this series up to the last heartbeat time
     pub count: i32,
@@ -13,41 +13,52 @@ pub struct EventSeries {
     pub state: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for EventSeries {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_count,
-            Key_last_observed_time,
-            Key_state,
-            Other,
+impl EventSeries {
+    /// Constructs a `EventSeries` from its required fields. Its optional fields are unset.
+    pub fn new(count: impl Into<i32>, last_observed_time: impl Into<crate::apimachinery::pkg::apis::meta::v1::MicroTime>, state: impl Into<String>) -> Self {
+        EventSeries {
+            count: count.into(),
+            last_observed_time: last_observed_time.into(),
+            state: state.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl EventSeries {
+    /// Returns this value with [`EventSeries::count`] set to the given value.
+    #[must_use]
+    pub fn with_count(mut self, value: impl Into<i32>) -> Self {
+        self.count = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`EventSeries::last_observed_time`] set to the given value.
+    #[must_use]
+    pub fn with_last_observed_time(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::MicroTime>) -> Self {
+        self.last_observed_time = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`EventSeries::state`] set to the given value.
+    #[must_use]
+    pub fn with_state(mut self, value: impl Into<String>) -> Self {
+        self.state = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "count" => Field::Key_count,
-                            "lastObservedTime" => Field::Key_last_observed_time,
-                            "state" => Field::Key_state,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for EventSeries {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl EventSeries {
+    const FIELDS: &'static [&'static str] = &[
+        "count",
+        "lastObservedTime",
+        "state",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for EventSeries {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,37 +73,101 @@ impl<'de> crate::serde::Deserialize<'de> for EventSeries {
                 let mut value_last_observed_time: Option<crate::apimachinery::pkg::apis::meta::v1::MicroTime> = None;
                 let mut value_state: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("EventSeries", EventSeries::FIELDS))? {
                     match key {
-                        Field::Key_count => value_count = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_last_observed_time => value_last_observed_time = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_state => value_state = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_count = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_last_observed_time = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_state = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(EventSeries {
-                    count: value_count.ok_or_else(|| crate::serde::de::Error::missing_field("count"))?,
-                    last_observed_time: value_last_observed_time.ok_or_else(|| crate::serde::de::Error::missing_field("lastObservedTime"))?,
-                    state: value_state.ok_or_else(|| crate::serde::de::Error::missing_field("state"))?,
+                    count: value_count.ok_or_else(|| crate::serde2::missing_field("EventSeries", "count", &["count", "lastObservedTime", "state"]))?,
+                    last_observed_time: value_last_observed_time.ok_or_else(|| crate::serde2::missing_field("EventSeries", "lastObservedTime", &["count", "lastObservedTime", "state"]))?,
+                    state: value_state.ok_or_else(|| crate::serde2::missing_field("EventSeries", "state", &["count", "lastObservedTime", "state"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(EventSeries {
+                    count: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    last_observed_time: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    state: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "EventSeries",
-            &[
-                "count",
-                "lastObservedTime",
-                "state",
-            ],
+            EventSeries::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, EventSeries> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("EventSeries")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_count = false;
+                let mut has_last_observed_time = false;
+                let mut has_state = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("EventSeries", EventSeries::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.count))?; has_count = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.last_observed_time))?; has_last_observed_time = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.state))?; has_state = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_count {
+                    return Err(crate::serde2::missing_field("EventSeries", "count", &["count", "lastObservedTime", "state"]));
+                }
+                if !has_last_observed_time {
+                    return Err(crate::serde2::missing_field("EventSeries", "lastObservedTime", &["count", "lastObservedTime", "state"]));
+                }
+                if !has_state {
+                    return Err(crate::serde2::missing_field("EventSeries", "state", &["count", "lastObservedTime", "state"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.count))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.last_observed_time))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.state))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "EventSeries",
+            EventSeries::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for EventSeries {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("EventSeries", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "count", &self.count)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "lastObservedTime", &self.last_observed_time)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "state", &self.state)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "EventSeries",
             3,
@@ -104,10 +179,99 @@ impl crate::serde::Serialize for EventSeries {
     }
 }
 
+/// A builder of [`EventSeries`]. Created with [`EventSeries::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct EventSeriesBuilder {
+    count: Option<i32>,
+    last_observed_time: Option<crate::apimachinery::pkg::apis::meta::v1::MicroTime>,
+    state: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl EventSeries {
+    /// Starts building a `EventSeries`. Its required fields must be set before [`EventSeriesBuilder::build`] is called.
+    pub fn builder() -> EventSeriesBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl EventSeriesBuilder {
+    /// Sets [`EventSeries::count`].
+    pub fn count(mut self, value: impl Into<i32>) -> Self {
+        self.count = Some(value.into());
+        self
+    }
+
+    /// Sets [`EventSeries::last_observed_time`].
+    pub fn last_observed_time(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::MicroTime>) -> Self {
+        self.last_observed_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`EventSeries::state`].
+    pub fn state(mut self, value: impl Into<String>) -> Self {
+        self.state = Some(value.into());
+        self
+    }
+
+    /// Builds the `EventSeries`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<EventSeries, crate::MissingFieldError> {
+        Ok(EventSeries {
+            count: self.count.ok_or_else(|| crate::MissingFieldError::new("EventSeries", "count"))?,
+            last_observed_time: self.last_observed_time.ok_or_else(|| crate::MissingFieldError::new("EventSeries", "lastObservedTime"))?,
+            state: self.state.ok_or_else(|| crate::MissingFieldError::new("EventSeries", "state"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`EventSeries`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EventSeriesApplyConfiguration {
+    /// See [`EventSeries::count`].
+    pub count: Option<i32>,
+
+    /// See [`EventSeries::last_observed_time`].
+    pub last_observed_time: Option<crate::apimachinery::pkg::apis::meta::v1::MicroTime>,
+
+    /// See [`EventSeries::state`].
+    pub state: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for EventSeriesApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "EventSeriesApplyConfiguration",
+            self.count.as_ref().map_or(0, |_| 1) +
+            self.last_observed_time.as_ref().map_or(0, |_| 1) +
+            self.state.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.count {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "count", value)?;
+        }
+        if let Some(value) = &self.last_observed_time {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "lastObservedTime", value)?;
+        }
+        if let Some(value) = &self.state {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "state", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for EventSeries {
     fn schema_name() -> String {
-        "io.k8s.api.events.v1beta1.EventSeries".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::events::v1beta1::EventSeries".to_owned()
+        }
+        else {
+            "io.k8s.api.events.v1beta1.EventSeries".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -118,8 +282,9 @@ impl crate::schemars::JsonSchema for EventSeries {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "count".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -130,8 +295,8 @@ impl crate::schemars::JsonSchema for EventSeries {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "lastObservedTime".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::MicroTime>().into_object();
@@ -141,8 +306,8 @@ impl crate::schemars::JsonSchema for EventSeries {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "state".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -152,8 +317,9 @@ impl crate::schemars::JsonSchema for EventSeries {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "count",
                     "lastObservedTime",
@@ -165,3 +331,14 @@ impl crate::schemars::JsonSchema for EventSeries {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for EventSeries {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(EventSeries {
+            count: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            last_observed_time: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            state: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
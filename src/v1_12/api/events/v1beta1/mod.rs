@@ -1,7 +1,15 @@
 
 mod event;
 pub use self::event::Event;
+#[cfg(feature = "builder")]
+pub use self::event::EventBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::event::EventApplyConfiguration;
 #[cfg(feature = "api")] pub use self::event::{ReadNamespacedEventOptional, ReadNamespacedEventResponse};
 
 mod event_series;
 pub use self::event_series::EventSeries;
+#[cfg(feature = "builder")]
+pub use self::event_series::EventSeriesBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::event_series::EventSeriesApplyConfiguration;
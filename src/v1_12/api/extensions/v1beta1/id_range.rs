@@ -1,7 +1,8 @@
 // Generated from definition io.k8s.api.extensions.v1beta1.IDRange
 
 /// IDRange provides a min/max of an allowed range of IDs. Deprecated: use IDRange from policy API Group instead.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[deprecated(note = "use IDRange from policy API Group instead.")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 pub struct IDRange {
     /// max is the end of the range, inclusive.
     pub max: i64,
@@ -10,39 +11,43 @@ pub struct IDRange {
     pub min: i64,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for IDRange {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_max,
-            Key_min,
-            Other,
+impl IDRange {
+    /// Constructs a `IDRange` from its required fields. Its optional fields are unset.
+    pub fn new(max: impl Into<i64>, min: impl Into<i64>) -> Self {
+        IDRange {
+            max: max.into(),
+            min: min.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl IDRange {
+    /// Returns this value with [`IDRange::max`] set to the given value.
+    #[must_use]
+    pub fn with_max(mut self, value: impl Into<i64>) -> Self {
+        self.max = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`IDRange::min`] set to the given value.
+    #[must_use]
+    pub fn with_min(mut self, value: impl Into<i64>) -> Self {
+        self.min = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "max" => Field::Key_max,
-                            "min" => Field::Key_min,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for IDRange {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl IDRange {
+    const FIELDS: &'static [&'static str] = &[
+        "max",
+        "min",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for IDRange {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +61,91 @@ impl<'de> crate::serde::Deserialize<'de> for IDRange {
                 let mut value_max: Option<i64> = None;
                 let mut value_min: Option<i64> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("IDRange", IDRange::FIELDS))? {
                     match key {
-                        Field::Key_max => value_max = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_min => value_min = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_max = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_min = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(IDRange {
-                    max: value_max.ok_or_else(|| crate::serde::de::Error::missing_field("max"))?,
-                    min: value_min.ok_or_else(|| crate::serde::de::Error::missing_field("min"))?,
+                    max: value_max.ok_or_else(|| crate::serde2::missing_field("IDRange", "max", &["max", "min"]))?,
+                    min: value_min.ok_or_else(|| crate::serde2::missing_field("IDRange", "min", &["max", "min"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(IDRange {
+                    max: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    min: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "IDRange",
-            &[
-                "max",
-                "min",
-            ],
+            IDRange::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, IDRange> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("IDRange")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_max = false;
+                let mut has_min = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("IDRange", IDRange::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.max))?; has_max = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.min))?; has_min = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_max {
+                    return Err(crate::serde2::missing_field("IDRange", "max", &["max", "min"]));
+                }
+                if !has_min {
+                    return Err(crate::serde2::missing_field("IDRange", "min", &["max", "min"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.max))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.min))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "IDRange",
+            IDRange::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for IDRange {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("IDRange", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "max", &self.max)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "min", &self.min)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "IDRange",
             2,
@@ -94,10 +156,84 @@ impl crate::serde::Serialize for IDRange {
     }
 }
 
+/// A builder of [`IDRange`]. Created with [`IDRange::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct IDRangeBuilder {
+    max: Option<i64>,
+    min: Option<i64>,
+}
+
+#[cfg(feature = "builder")]
+impl IDRange {
+    /// Starts building a `IDRange`. Its required fields must be set before [`IDRangeBuilder::build`] is called.
+    pub fn builder() -> IDRangeBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl IDRangeBuilder {
+    /// Sets [`IDRange::max`].
+    pub fn max(mut self, value: impl Into<i64>) -> Self {
+        self.max = Some(value.into());
+        self
+    }
+
+    /// Sets [`IDRange::min`].
+    pub fn min(mut self, value: impl Into<i64>) -> Self {
+        self.min = Some(value.into());
+        self
+    }
+
+    /// Builds the `IDRange`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<IDRange, crate::MissingFieldError> {
+        Ok(IDRange {
+            max: self.max.ok_or_else(|| crate::MissingFieldError::new("IDRange", "max"))?,
+            min: self.min.ok_or_else(|| crate::MissingFieldError::new("IDRange", "min"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`IDRange`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IDRangeApplyConfiguration {
+    /// See [`IDRange::max`].
+    pub max: Option<i64>,
+
+    /// See [`IDRange::min`].
+    pub min: Option<i64>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for IDRangeApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "IDRangeApplyConfiguration",
+            self.max.as_ref().map_or(0, |_| 1) +
+            self.min.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.max {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "max", value)?;
+        }
+        if let Some(value) = &self.min {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "min", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for IDRange {
     fn schema_name() -> String {
-        "io.k8s.api.extensions.v1beta1.IDRange".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::extensions::v1beta1::IDRange".to_owned()
+        }
+        else {
+            "io.k8s.api.extensions.v1beta1.IDRange".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -108,8 +244,9 @@ impl crate::schemars::JsonSchema for IDRange {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "max".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -120,8 +257,8 @@ impl crate::schemars::JsonSchema for IDRange {
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "min".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -132,8 +269,9 @@ impl crate::schemars::JsonSchema for IDRange {
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "max",
                     "min",
@@ -144,3 +282,13 @@ impl crate::schemars::JsonSchema for IDRange {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for IDRange {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(IDRange {
+            max: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            min: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
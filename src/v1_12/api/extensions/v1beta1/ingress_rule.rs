@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.extensions.v1beta1.IngressRule
 
 /// IngressRule represents the rules mapping the paths under a specified host to the related backend services. Incoming requests are first evaluated for a host match, then routed to the backend associated with the matching IngressRuleValue.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct IngressRule {
     /// Host is the fully qualified domain name of a network host, as defined by RFC 3986. Note the following deviations from the "host" part of the URI as defined in the RFC: 1. IPs are not allowed. Currently an IngressRuleValue can only apply to the
     ///       IP in the Spec of the parent Ingress.
@@ -14,39 +14,33 @@ pub struct IngressRule {
     pub http: Option<crate::api::extensions::v1beta1::HTTPIngressRuleValue>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for IngressRule {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_host,
-            Key_http,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl IngressRule {
+    /// Returns this value with [`IngressRule::host`] set to the given value.
+    #[must_use]
+    pub fn with_host(mut self, value: impl Into<String>) -> Self {
+        self.host = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`IngressRule::http`] set to the given value.
+    #[must_use]
+    pub fn with_http(mut self, value: impl Into<crate::api::extensions::v1beta1::HTTPIngressRuleValue>) -> Self {
+        self.http = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "host" => Field::Key_host,
-                            "http" => Field::Key_http,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for IngressRule {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl IngressRule {
+    const FIELDS: &'static [&'static str] = &[
+        "host",
+        "http",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for IngressRule {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -60,11 +54,11 @@ impl<'de> crate::serde::Deserialize<'de> for IngressRule {
                 let mut value_host: Option<String> = None;
                 let mut value_http: Option<crate::api::extensions::v1beta1::HTTPIngressRuleValue> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("IngressRule", IngressRule::FIELDS))? {
                     match key {
-                        Field::Key_host => value_host = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_http => value_http = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_host = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_http = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -73,21 +67,78 @@ impl<'de> crate::serde::Deserialize<'de> for IngressRule {
                     http: value_http,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(IngressRule {
+                    host: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    http: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "IngressRule",
-            &[
-                "host",
-                "http",
-            ],
+            IngressRule::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, IngressRule> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("IngressRule")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_host = false;
+                let mut has_http = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("IngressRule", IngressRule::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.host))?; has_host = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.http))?; has_http = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_host {
+                    self.0.host = None;
+                }
+                if !has_http {
+                    self.0.http = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.host))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.http))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "IngressRule",
+            IngressRule::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for IngressRule {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("IngressRule", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "host", &self.host)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "http", &self.http)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "IngressRule",
             self.host.as_ref().map_or(0, |_| 1) +
@@ -103,10 +154,84 @@ impl crate::serde::Serialize for IngressRule {
     }
 }
 
+/// A builder of [`IngressRule`]. Created with [`IngressRule::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct IngressRuleBuilder {
+    host: Option<String>,
+    http: Option<crate::api::extensions::v1beta1::HTTPIngressRuleValue>,
+}
+
+#[cfg(feature = "builder")]
+impl IngressRule {
+    /// Starts building a `IngressRule`. Its required fields must be set before [`IngressRuleBuilder::build`] is called.
+    pub fn builder() -> IngressRuleBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl IngressRuleBuilder {
+    /// Sets [`IngressRule::host`].
+    pub fn host(mut self, value: impl Into<String>) -> Self {
+        self.host = Some(value.into());
+        self
+    }
+
+    /// Sets [`IngressRule::http`].
+    pub fn http(mut self, value: impl Into<crate::api::extensions::v1beta1::HTTPIngressRuleValue>) -> Self {
+        self.http = Some(value.into());
+        self
+    }
+
+    /// Builds the `IngressRule`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<IngressRule, crate::MissingFieldError> {
+        Ok(IngressRule {
+            host: self.host,
+            http: self.http,
+        })
+    }
+}
+
+/// The apply configuration of [`IngressRule`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IngressRuleApplyConfiguration {
+    /// See [`IngressRule::host`].
+    pub host: Option<String>,
+
+    /// See [`IngressRule::http`].
+    pub http: Option<crate::api::extensions::v1beta1::HTTPIngressRuleValueApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for IngressRuleApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "IngressRuleApplyConfiguration",
+            self.host.as_ref().map_or(0, |_| 1) +
+            self.http.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.host {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "host", value)?;
+        }
+        if let Some(value) = &self.http {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "http", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for IngressRule {
     fn schema_name() -> String {
-        "io.k8s.api.extensions.v1beta1.IngressRule".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::extensions::v1beta1::IngressRule".to_owned()
+        }
+        else {
+            "io.k8s.api.extensions.v1beta1.IngressRule".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -117,8 +242,9 @@ impl crate::schemars::JsonSchema for IngressRule {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "host".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -128,15 +254,26 @@ impl crate::schemars::JsonSchema for IngressRule {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "http".to_owned(),
                         __gen.subschema_for::<crate::api::extensions::v1beta1::HTTPIngressRuleValue>(),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for IngressRule {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(IngressRule {
+            host: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            http: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,152 +1,336 @@
 
 mod allowed_flex_volume;
 pub use self::allowed_flex_volume::AllowedFlexVolume;
+#[cfg(feature = "builder")]
+pub use self::allowed_flex_volume::AllowedFlexVolumeBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::allowed_flex_volume::AllowedFlexVolumeApplyConfiguration;
 
 mod allowed_host_path;
 pub use self::allowed_host_path::AllowedHostPath;
+#[cfg(feature = "builder")]
+pub use self::allowed_host_path::AllowedHostPathBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::allowed_host_path::AllowedHostPathApplyConfiguration;
 
 mod daemon_set;
 pub use self::daemon_set::DaemonSet;
+#[cfg(feature = "builder")]
+pub use self::daemon_set::DaemonSetBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::daemon_set::DaemonSetApplyConfiguration;
 #[cfg(feature = "api")] pub use self::daemon_set::{ReadNamespacedDaemonSetOptional, ReadNamespacedDaemonSetResponse};
 #[cfg(feature = "api")] pub use self::daemon_set::{ReadNamespacedDaemonSetStatusOptional, ReadNamespacedDaemonSetStatusResponse};
 
 mod daemon_set_condition;
 pub use self::daemon_set_condition::DaemonSetCondition;
+#[cfg(feature = "builder")]
+pub use self::daemon_set_condition::DaemonSetConditionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::daemon_set_condition::DaemonSetConditionApplyConfiguration;
 
 mod daemon_set_spec;
 pub use self::daemon_set_spec::DaemonSetSpec;
+#[cfg(feature = "builder")]
+pub use self::daemon_set_spec::DaemonSetSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::daemon_set_spec::DaemonSetSpecApplyConfiguration;
 
 mod daemon_set_status;
 pub use self::daemon_set_status::DaemonSetStatus;
+#[cfg(feature = "builder")]
+pub use self::daemon_set_status::DaemonSetStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::daemon_set_status::DaemonSetStatusApplyConfiguration;
 
 mod daemon_set_update_strategy;
 pub use self::daemon_set_update_strategy::DaemonSetUpdateStrategy;
+#[cfg(feature = "builder")]
+pub use self::daemon_set_update_strategy::DaemonSetUpdateStrategyBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::daemon_set_update_strategy::DaemonSetUpdateStrategyApplyConfiguration;
 
 mod deployment;
 pub use self::deployment::Deployment;
+#[cfg(feature = "builder")]
+pub use self::deployment::DeploymentBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::deployment::DeploymentApplyConfiguration;
 #[cfg(feature = "api")] pub use self::deployment::{ReadNamespacedDeploymentOptional, ReadNamespacedDeploymentResponse};
 #[cfg(feature = "api")] pub use self::deployment::{ReadNamespacedDeploymentStatusOptional, ReadNamespacedDeploymentStatusResponse};
 
 mod deployment_condition;
 pub use self::deployment_condition::DeploymentCondition;
+#[cfg(feature = "builder")]
+pub use self::deployment_condition::DeploymentConditionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::deployment_condition::DeploymentConditionApplyConfiguration;
 
 mod deployment_rollback;
 pub use self::deployment_rollback::DeploymentRollback;
+#[cfg(feature = "builder")]
+pub use self::deployment_rollback::DeploymentRollbackBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::deployment_rollback::DeploymentRollbackApplyConfiguration;
 #[cfg(feature = "api")] pub use self::deployment_rollback::CreateNamespacedDeploymentRollbackResponse;
 
 mod deployment_spec;
 pub use self::deployment_spec::DeploymentSpec;
+#[cfg(feature = "builder")]
+pub use self::deployment_spec::DeploymentSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::deployment_spec::DeploymentSpecApplyConfiguration;
 
 mod deployment_status;
 pub use self::deployment_status::DeploymentStatus;
+#[cfg(feature = "builder")]
+pub use self::deployment_status::DeploymentStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::deployment_status::DeploymentStatusApplyConfiguration;
 
 mod deployment_strategy;
 pub use self::deployment_strategy::DeploymentStrategy;
+#[cfg(feature = "builder")]
+pub use self::deployment_strategy::DeploymentStrategyBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::deployment_strategy::DeploymentStrategyApplyConfiguration;
 
 mod fs_group_strategy_options;
 pub use self::fs_group_strategy_options::FSGroupStrategyOptions;
+#[cfg(feature = "builder")]
+pub use self::fs_group_strategy_options::FSGroupStrategyOptionsBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::fs_group_strategy_options::FSGroupStrategyOptionsApplyConfiguration;
 
 mod http_ingress_path;
 pub use self::http_ingress_path::HTTPIngressPath;
+#[cfg(feature = "builder")]
+pub use self::http_ingress_path::HTTPIngressPathBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::http_ingress_path::HTTPIngressPathApplyConfiguration;
 
 mod http_ingress_rule_value;
 pub use self::http_ingress_rule_value::HTTPIngressRuleValue;
+#[cfg(feature = "builder")]
+pub use self::http_ingress_rule_value::HTTPIngressRuleValueBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::http_ingress_rule_value::HTTPIngressRuleValueApplyConfiguration;
 
 mod host_port_range;
 pub use self::host_port_range::HostPortRange;
+#[cfg(feature = "builder")]
+pub use self::host_port_range::HostPortRangeBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::host_port_range::HostPortRangeApplyConfiguration;
 
 mod id_range;
 pub use self::id_range::IDRange;
+#[cfg(feature = "builder")]
+pub use self::id_range::IDRangeBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::id_range::IDRangeApplyConfiguration;
 
 mod ip_block;
 pub use self::ip_block::IPBlock;
+#[cfg(feature = "builder")]
+pub use self::ip_block::IPBlockBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::ip_block::IPBlockApplyConfiguration;
 
 mod ingress;
 pub use self::ingress::Ingress;
+#[cfg(feature = "builder")]
+pub use self::ingress::IngressBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::ingress::IngressApplyConfiguration;
 #[cfg(feature = "api")] pub use self::ingress::{ReadNamespacedIngressOptional, ReadNamespacedIngressResponse};
 #[cfg(feature = "api")] pub use self::ingress::{ReadNamespacedIngressStatusOptional, ReadNamespacedIngressStatusResponse};
 
 mod ingress_backend;
 pub use self::ingress_backend::IngressBackend;
+#[cfg(feature = "builder")]
+pub use self::ingress_backend::IngressBackendBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::ingress_backend::IngressBackendApplyConfiguration;
 
 mod ingress_rule;
 pub use self::ingress_rule::IngressRule;
+#[cfg(feature = "builder")]
+pub use self::ingress_rule::IngressRuleBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::ingress_rule::IngressRuleApplyConfiguration;
 
 mod ingress_spec;
 pub use self::ingress_spec::IngressSpec;
+#[cfg(feature = "builder")]
+pub use self::ingress_spec::IngressSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::ingress_spec::IngressSpecApplyConfiguration;
 
 mod ingress_status;
 pub use self::ingress_status::IngressStatus;
+#[cfg(feature = "builder")]
+pub use self::ingress_status::IngressStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::ingress_status::IngressStatusApplyConfiguration;
 
 mod ingress_tls;
 pub use self::ingress_tls::IngressTLS;
+#[cfg(feature = "builder")]
+pub use self::ingress_tls::IngressTLSBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::ingress_tls::IngressTLSApplyConfiguration;
 
 mod network_policy;
 pub use self::network_policy::NetworkPolicy;
+#[cfg(feature = "builder")]
+pub use self::network_policy::NetworkPolicyBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::network_policy::NetworkPolicyApplyConfiguration;
 #[cfg(feature = "api")] pub use self::network_policy::{ReadNamespacedNetworkPolicyOptional, ReadNamespacedNetworkPolicyResponse};
 
 mod network_policy_egress_rule;
 pub use self::network_policy_egress_rule::NetworkPolicyEgressRule;
+#[cfg(feature = "builder")]
+pub use self::network_policy_egress_rule::NetworkPolicyEgressRuleBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::network_policy_egress_rule::NetworkPolicyEgressRuleApplyConfiguration;
 
 mod network_policy_ingress_rule;
 pub use self::network_policy_ingress_rule::NetworkPolicyIngressRule;
+#[cfg(feature = "builder")]
+pub use self::network_policy_ingress_rule::NetworkPolicyIngressRuleBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::network_policy_ingress_rule::NetworkPolicyIngressRuleApplyConfiguration;
 
 mod network_policy_peer;
 pub use self::network_policy_peer::NetworkPolicyPeer;
+#[cfg(feature = "builder")]
+pub use self::network_policy_peer::NetworkPolicyPeerBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::network_policy_peer::NetworkPolicyPeerApplyConfiguration;
 
 mod network_policy_port;
 pub use self::network_policy_port::NetworkPolicyPort;
+#[cfg(feature = "builder")]
+pub use self::network_policy_port::NetworkPolicyPortBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::network_policy_port::NetworkPolicyPortApplyConfiguration;
 
 mod network_policy_spec;
 pub use self::network_policy_spec::NetworkPolicySpec;
+#[cfg(feature = "builder")]
+pub use self::network_policy_spec::NetworkPolicySpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::network_policy_spec::NetworkPolicySpecApplyConfiguration;
 
 mod pod_security_policy;
 pub use self::pod_security_policy::PodSecurityPolicy;
+#[cfg(feature = "builder")]
+pub use self::pod_security_policy::PodSecurityPolicyBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::pod_security_policy::PodSecurityPolicyApplyConfiguration;
 #[cfg(feature = "api")] pub use self::pod_security_policy::{ReadPodSecurityPolicyOptional, ReadPodSecurityPolicyResponse};
 
 mod pod_security_policy_spec;
 pub use self::pod_security_policy_spec::PodSecurityPolicySpec;
+#[cfg(feature = "builder")]
+pub use self::pod_security_policy_spec::PodSecurityPolicySpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::pod_security_policy_spec::PodSecurityPolicySpecApplyConfiguration;
 
 mod replica_set;
 pub use self::replica_set::ReplicaSet;
+#[cfg(feature = "builder")]
+pub use self::replica_set::ReplicaSetBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::replica_set::ReplicaSetApplyConfiguration;
 #[cfg(feature = "api")] pub use self::replica_set::{ReadNamespacedReplicaSetOptional, ReadNamespacedReplicaSetResponse};
 #[cfg(feature = "api")] pub use self::replica_set::{ReadNamespacedReplicaSetStatusOptional, ReadNamespacedReplicaSetStatusResponse};
 
 mod replica_set_condition;
 pub use self::replica_set_condition::ReplicaSetCondition;
+#[cfg(feature = "builder")]
+pub use self::replica_set_condition::ReplicaSetConditionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::replica_set_condition::ReplicaSetConditionApplyConfiguration;
 
 mod replica_set_spec;
 pub use self::replica_set_spec::ReplicaSetSpec;
+#[cfg(feature = "builder")]
+pub use self::replica_set_spec::ReplicaSetSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::replica_set_spec::ReplicaSetSpecApplyConfiguration;
 
 mod replica_set_status;
 pub use self::replica_set_status::ReplicaSetStatus;
+#[cfg(feature = "builder")]
+pub use self::replica_set_status::ReplicaSetStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::replica_set_status::ReplicaSetStatusApplyConfiguration;
 
 mod rollback_config;
 pub use self::rollback_config::RollbackConfig;
+#[cfg(feature = "builder")]
+pub use self::rollback_config::RollbackConfigBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::rollback_config::RollbackConfigApplyConfiguration;
 
 mod rolling_update_daemon_set;
 pub use self::rolling_update_daemon_set::RollingUpdateDaemonSet;
+#[cfg(feature = "builder")]
+pub use self::rolling_update_daemon_set::RollingUpdateDaemonSetBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::rolling_update_daemon_set::RollingUpdateDaemonSetApplyConfiguration;
 
 mod rolling_update_deployment;
 pub use self::rolling_update_deployment::RollingUpdateDeployment;
+#[cfg(feature = "builder")]
+pub use self::rolling_update_deployment::RollingUpdateDeploymentBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::rolling_update_deployment::RollingUpdateDeploymentApplyConfiguration;
 
 mod run_as_user_strategy_options;
 pub use self::run_as_user_strategy_options::RunAsUserStrategyOptions;
+#[cfg(feature = "builder")]
+pub use self::run_as_user_strategy_options::RunAsUserStrategyOptionsBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::run_as_user_strategy_options::RunAsUserStrategyOptionsApplyConfiguration;
 
 mod se_linux_strategy_options;
 pub use self::se_linux_strategy_options::SELinuxStrategyOptions;
+#[cfg(feature = "builder")]
+pub use self::se_linux_strategy_options::SELinuxStrategyOptionsBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::se_linux_strategy_options::SELinuxStrategyOptionsApplyConfiguration;
 
 mod scale;
 pub use self::scale::Scale;
+#[cfg(feature = "builder")]
+pub use self::scale::ScaleBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::scale::ScaleApplyConfiguration;
 #[cfg(feature = "api")] pub use self::scale::{ReadNamespacedDeploymentScaleOptional, ReadNamespacedDeploymentScaleResponse};
 #[cfg(feature = "api")] pub use self::scale::{ReadNamespacedReplicaSetScaleOptional, ReadNamespacedReplicaSetScaleResponse};
 #[cfg(feature = "api")] pub use self::scale::{ReadNamespacedReplicationControllerDummyScaleOptional, ReadNamespacedReplicationControllerDummyScaleResponse};
 
 mod scale_spec;
 pub use self::scale_spec::ScaleSpec;
+#[cfg(feature = "builder")]
+pub use self::scale_spec::ScaleSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::scale_spec::ScaleSpecApplyConfiguration;
 
 mod scale_status;
 pub use self::scale_status::ScaleStatus;
+#[cfg(feature = "builder")]
+pub use self::scale_status::ScaleStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::scale_status::ScaleStatusApplyConfiguration;
 
 mod supplemental_groups_strategy_options;
 pub use self::supplemental_groups_strategy_options::SupplementalGroupsStrategyOptions;
+#[cfg(feature = "builder")]
+pub use self::supplemental_groups_strategy_options::SupplementalGroupsStrategyOptionsBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::supplemental_groups_strategy_options::SupplementalGroupsStrategyOptionsApplyConfiguration;
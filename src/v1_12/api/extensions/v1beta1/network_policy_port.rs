@@ -1,7 +1,8 @@
 // Generated from definition io.k8s.api.extensions.v1beta1.NetworkPolicyPort
 
 /// DEPRECATED 1.9 - This group version of NetworkPolicyPort is deprecated by networking/v1/NetworkPolicyPort.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[deprecated(note = "DEPRECATED 1.9 - This group version of NetworkPolicyPort is deprecated by networking/v1/NetworkPolicyPort.")]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct NetworkPolicyPort {
     /// If specified, the port on the given protocol.  This can either be a numerical or named port on a pod.  If this field is not provided, this matches all port names and numbers. If present, only traffic on the specified protocol AND port will be matched.
     pub port: Option<crate::apimachinery::pkg::util::intstr::IntOrString>,
@@ -10,39 +11,33 @@ pub struct NetworkPolicyPort {
     pub protocol: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for NetworkPolicyPort {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_port,
-            Key_protocol,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl NetworkPolicyPort {
+    /// Returns this value with [`NetworkPolicyPort::port`] set to the given value.
+    #[must_use]
+    pub fn with_port(mut self, value: impl Into<crate::apimachinery::pkg::util::intstr::IntOrString>) -> Self {
+        self.port = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`NetworkPolicyPort::protocol`] set to the given value.
+    #[must_use]
+    pub fn with_protocol(mut self, value: impl Into<String>) -> Self {
+        self.protocol = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "port" => Field::Key_port,
-                            "protocol" => Field::Key_protocol,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for NetworkPolicyPort {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl NetworkPolicyPort {
+    const FIELDS: &'static [&'static str] = &[
+        "port",
+        "protocol",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for NetworkPolicyPort {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,11 +51,11 @@ impl<'de> crate::serde::Deserialize<'de> for NetworkPolicyPort {
                 let mut value_port: Option<crate::apimachinery::pkg::util::intstr::IntOrString> = None;
                 let mut value_protocol: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NetworkPolicyPort", NetworkPolicyPort::FIELDS))? {
                     match key {
-                        Field::Key_port => value_port = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_protocol => value_protocol = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_port = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_protocol = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -69,21 +64,78 @@ impl<'de> crate::serde::Deserialize<'de> for NetworkPolicyPort {
                     protocol: value_protocol,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(NetworkPolicyPort {
+                    port: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    protocol: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "NetworkPolicyPort",
-            &[
-                "port",
-                "protocol",
-            ],
+            NetworkPolicyPort::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, NetworkPolicyPort> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("NetworkPolicyPort")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_port = false;
+                let mut has_protocol = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NetworkPolicyPort", NetworkPolicyPort::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.port))?; has_port = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.protocol))?; has_protocol = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_port {
+                    self.0.port = None;
+                }
+                if !has_protocol {
+                    self.0.protocol = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.port))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.protocol))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "NetworkPolicyPort",
+            NetworkPolicyPort::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for NetworkPolicyPort {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("NetworkPolicyPort", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "port", &self.port)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "protocol", &self.protocol)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "NetworkPolicyPort",
             self.port.as_ref().map_or(0, |_| 1) +
@@ -99,10 +151,84 @@ impl crate::serde::Serialize for NetworkPolicyPort {
     }
 }
 
+/// A builder of [`NetworkPolicyPort`]. Created with [`NetworkPolicyPort::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct NetworkPolicyPortBuilder {
+    port: Option<crate::apimachinery::pkg::util::intstr::IntOrString>,
+    protocol: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl NetworkPolicyPort {
+    /// Starts building a `NetworkPolicyPort`. Its required fields must be set before [`NetworkPolicyPortBuilder::build`] is called.
+    pub fn builder() -> NetworkPolicyPortBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl NetworkPolicyPortBuilder {
+    /// Sets [`NetworkPolicyPort::port`].
+    pub fn port(mut self, value: impl Into<crate::apimachinery::pkg::util::intstr::IntOrString>) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets [`NetworkPolicyPort::protocol`].
+    pub fn protocol(mut self, value: impl Into<String>) -> Self {
+        self.protocol = Some(value.into());
+        self
+    }
+
+    /// Builds the `NetworkPolicyPort`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<NetworkPolicyPort, crate::MissingFieldError> {
+        Ok(NetworkPolicyPort {
+            port: self.port,
+            protocol: self.protocol,
+        })
+    }
+}
+
+/// The apply configuration of [`NetworkPolicyPort`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NetworkPolicyPortApplyConfiguration {
+    /// See [`NetworkPolicyPort::port`].
+    pub port: Option<crate::apimachinery::pkg::util::intstr::IntOrString>,
+
+    /// See [`NetworkPolicyPort::protocol`].
+    pub protocol: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for NetworkPolicyPortApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "NetworkPolicyPortApplyConfiguration",
+            self.port.as_ref().map_or(0, |_| 1) +
+            self.protocol.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.port {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "port", value)?;
+        }
+        if let Some(value) = &self.protocol {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "protocol", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NetworkPolicyPort {
     fn schema_name() -> String {
-        "io.k8s.api.extensions.v1beta1.NetworkPolicyPort".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::extensions::v1beta1::NetworkPolicyPort".to_owned()
+        }
+        else {
+            "io.k8s.api.extensions.v1beta1.NetworkPolicyPort".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -113,8 +239,9 @@ impl crate::schemars::JsonSchema for NetworkPolicyPort {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "port".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::util::intstr::IntOrString>().into_object();
@@ -124,8 +251,8 @@ impl crate::schemars::JsonSchema for NetworkPolicyPort {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "protocol".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -135,11 +262,22 @@ impl crate::schemars::JsonSchema for NetworkPolicyPort {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for NetworkPolicyPort {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(NetworkPolicyPort {
+            port: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            protocol: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,80 +1,119 @@
 // Generated from definition io.k8s.api.extensions.v1beta1.RollbackConfig
 
 /// DEPRECATED.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[deprecated]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 pub struct RollbackConfig {
     /// The revision to rollback to. If set to 0, rollback to the last revision.
     pub revision: Option<i64>,
 }
 
+impl RollbackConfig {
+    /// Returns this value with [`RollbackConfig::revision`] set to the given value.
+    #[must_use]
+    pub fn with_revision(mut self, value: impl Into<i64>) -> Self {
+        self.revision = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for RollbackConfig {}
+
+impl RollbackConfig {
+    const FIELDS: &'static [&'static str] = &[
+        "revision",
+    ];
+}
+
 impl<'de> crate::serde::Deserialize<'de> for RollbackConfig {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_revision,
-            Other,
-        }
+        struct Visitor;
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = RollbackConfig;
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("RollbackConfig")
+            }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_revision: Option<i64> = None;
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "revision" => Field::Key_revision,
-                            _ => Field::Other,
-                        })
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("RollbackConfig", RollbackConfig::FIELDS))? {
+                    match key {
+                        Some(0) => value_revision = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
-                deserializer.deserialize_identifier(Visitor)
+                Ok(RollbackConfig {
+                    revision: value_revision,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(RollbackConfig {
+                    revision: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                })
             }
         }
 
-        struct Visitor;
+        deserializer.deserialize_struct(
+            "RollbackConfig",
+            RollbackConfig::FIELDS,
+            Visitor,
+        )
+    }
 
-        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-            type Value = RollbackConfig;
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, RollbackConfig> {
+            type Value = ();
 
             fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 f.write_str("RollbackConfig")
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
-                let mut value_revision: Option<i64> = None;
+                let mut has_revision = false;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("RollbackConfig", RollbackConfig::FIELDS))? {
                     match key {
-                        Field::Key_revision => value_revision = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.revision))?; has_revision = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
+                if !has_revision {
+                    self.0.revision = None;
+                }
 
-                Ok(RollbackConfig {
-                    revision: value_revision,
-                })
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.revision))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
             }
         }
 
         deserializer.deserialize_struct(
             "RollbackConfig",
-            &[
-                "revision",
-            ],
-            Visitor,
+            RollbackConfig::FIELDS,
+            InPlaceVisitor(place),
         )
     }
 }
 
 impl crate::serde::Serialize for RollbackConfig {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("RollbackConfig", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "revision", &self.revision)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "RollbackConfig",
             self.revision.as_ref().map_or(0, |_| 1),
@@ -86,10 +125,69 @@ impl crate::serde::Serialize for RollbackConfig {
     }
 }
 
+/// A builder of [`RollbackConfig`]. Created with [`RollbackConfig::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct RollbackConfigBuilder {
+    revision: Option<i64>,
+}
+
+#[cfg(feature = "builder")]
+impl RollbackConfig {
+    /// Starts building a `RollbackConfig`. Its required fields must be set before [`RollbackConfigBuilder::build`] is called.
+    pub fn builder() -> RollbackConfigBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl RollbackConfigBuilder {
+    /// Sets [`RollbackConfig::revision`].
+    pub fn revision(mut self, value: impl Into<i64>) -> Self {
+        self.revision = Some(value.into());
+        self
+    }
+
+    /// Builds the `RollbackConfig`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<RollbackConfig, crate::MissingFieldError> {
+        Ok(RollbackConfig {
+            revision: self.revision,
+        })
+    }
+}
+
+/// The apply configuration of [`RollbackConfig`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RollbackConfigApplyConfiguration {
+    /// See [`RollbackConfig::revision`].
+    pub revision: Option<i64>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for RollbackConfigApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "RollbackConfigApplyConfiguration",
+            self.revision.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.revision {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "revision", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for RollbackConfig {
     fn schema_name() -> String {
-        "io.k8s.api.extensions.v1beta1.RollbackConfig".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::extensions::v1beta1::RollbackConfig".to_owned()
+        }
+        else {
+            "io.k8s.api.extensions.v1beta1.RollbackConfig".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -100,8 +198,9 @@ impl crate::schemars::JsonSchema for RollbackConfig {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "revision".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -112,11 +211,21 @@ impl crate::schemars::JsonSchema for RollbackConfig {
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for RollbackConfig {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(RollbackConfig {
+            revision: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,80 +1,118 @@
 // Generated from definition io.k8s.api.extensions.v1beta1.RollingUpdateDaemonSet
 
 /// Spec to control the desired behavior of daemon set rolling update.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct RollingUpdateDaemonSet {
     /// The maximum number of DaemonSet pods that can be unavailable during the update. Value can be an absolute number (ex: 5) or a percentage of total number of DaemonSet pods at the start of the update (ex: 10%). Absolute number is calculated from percentage by rounding up. This cannot be 0. Default value is 1. Example: when this is set to 30%, at most 30% of the total number of nodes that should be running the daemon pod (i.e. status.desiredNumberScheduled) can have their pods stopped for an update at any given time. The update starts by stopping at most 30% of those DaemonSet pods and then brings up new DaemonSet pods in their place. Once the new pods are available, it then proceeds onto other DaemonSet pods, thus ensuring that at least 70% of original number of DaemonSet pods are available at all times during the update.
     pub max_unavailable: Option<crate::apimachinery::pkg::util::intstr::IntOrString>,
 }
 
+impl RollingUpdateDaemonSet {
+    /// Returns this value with [`RollingUpdateDaemonSet::max_unavailable`] set to the given value.
+    #[must_use]
+    pub fn with_max_unavailable(mut self, value: impl Into<crate::apimachinery::pkg::util::intstr::IntOrString>) -> Self {
+        self.max_unavailable = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for RollingUpdateDaemonSet {}
+
+impl RollingUpdateDaemonSet {
+    const FIELDS: &'static [&'static str] = &[
+        "maxUnavailable",
+    ];
+}
+
 impl<'de> crate::serde::Deserialize<'de> for RollingUpdateDaemonSet {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_max_unavailable,
-            Other,
-        }
+        struct Visitor;
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = RollingUpdateDaemonSet;
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("RollingUpdateDaemonSet")
+            }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_max_unavailable: Option<crate::apimachinery::pkg::util::intstr::IntOrString> = None;
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "maxUnavailable" => Field::Key_max_unavailable,
-                            _ => Field::Other,
-                        })
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("RollingUpdateDaemonSet", RollingUpdateDaemonSet::FIELDS))? {
+                    match key {
+                        Some(0) => value_max_unavailable = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
-                deserializer.deserialize_identifier(Visitor)
+                Ok(RollingUpdateDaemonSet {
+                    max_unavailable: value_max_unavailable,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(RollingUpdateDaemonSet {
+                    max_unavailable: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                })
             }
         }
 
-        struct Visitor;
+        deserializer.deserialize_struct(
+            "RollingUpdateDaemonSet",
+            RollingUpdateDaemonSet::FIELDS,
+            Visitor,
+        )
+    }
 
-        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-            type Value = RollingUpdateDaemonSet;
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, RollingUpdateDaemonSet> {
+            type Value = ();
 
             fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 f.write_str("RollingUpdateDaemonSet")
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
-                let mut value_max_unavailable: Option<crate::apimachinery::pkg::util::intstr::IntOrString> = None;
+                let mut has_max_unavailable = false;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("RollingUpdateDaemonSet", RollingUpdateDaemonSet::FIELDS))? {
                     match key {
-                        Field::Key_max_unavailable => value_max_unavailable = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.max_unavailable))?; has_max_unavailable = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
+                if !has_max_unavailable {
+                    self.0.max_unavailable = None;
+                }
 
-                Ok(RollingUpdateDaemonSet {
-                    max_unavailable: value_max_unavailable,
-                })
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.max_unavailable))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
             }
         }
 
         deserializer.deserialize_struct(
             "RollingUpdateDaemonSet",
-            &[
-                "maxUnavailable",
-            ],
-            Visitor,
+            RollingUpdateDaemonSet::FIELDS,
+            InPlaceVisitor(place),
         )
     }
 }
 
 impl crate::serde::Serialize for RollingUpdateDaemonSet {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("RollingUpdateDaemonSet", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maxUnavailable", &self.max_unavailable)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "RollingUpdateDaemonSet",
             self.max_unavailable.as_ref().map_or(0, |_| 1),
@@ -86,10 +124,69 @@ impl crate::serde::Serialize for RollingUpdateDaemonSet {
     }
 }
 
+/// A builder of [`RollingUpdateDaemonSet`]. Created with [`RollingUpdateDaemonSet::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct RollingUpdateDaemonSetBuilder {
+    max_unavailable: Option<crate::apimachinery::pkg::util::intstr::IntOrString>,
+}
+
+#[cfg(feature = "builder")]
+impl RollingUpdateDaemonSet {
+    /// Starts building a `RollingUpdateDaemonSet`. Its required fields must be set before [`RollingUpdateDaemonSetBuilder::build`] is called.
+    pub fn builder() -> RollingUpdateDaemonSetBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl RollingUpdateDaemonSetBuilder {
+    /// Sets [`RollingUpdateDaemonSet::max_unavailable`].
+    pub fn max_unavailable(mut self, value: impl Into<crate::apimachinery::pkg::util::intstr::IntOrString>) -> Self {
+        self.max_unavailable = Some(value.into());
+        self
+    }
+
+    /// Builds the `RollingUpdateDaemonSet`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<RollingUpdateDaemonSet, crate::MissingFieldError> {
+        Ok(RollingUpdateDaemonSet {
+            max_unavailable: self.max_unavailable,
+        })
+    }
+}
+
+/// The apply configuration of [`RollingUpdateDaemonSet`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RollingUpdateDaemonSetApplyConfiguration {
+    /// See [`RollingUpdateDaemonSet::max_unavailable`].
+    pub max_unavailable: Option<crate::apimachinery::pkg::util::intstr::IntOrString>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for RollingUpdateDaemonSetApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "RollingUpdateDaemonSetApplyConfiguration",
+            self.max_unavailable.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.max_unavailable {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maxUnavailable", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for RollingUpdateDaemonSet {
     fn schema_name() -> String {
-        "io.k8s.api.extensions.v1beta1.RollingUpdateDaemonSet".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::extensions::v1beta1::RollingUpdateDaemonSet".to_owned()
+        }
+        else {
+            "io.k8s.api.extensions.v1beta1.RollingUpdateDaemonSet".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -100,8 +197,9 @@ impl crate::schemars::JsonSchema for RollingUpdateDaemonSet {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "maxUnavailable".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::util::intstr::IntOrString>().into_object();
@@ -111,11 +209,21 @@ impl crate::schemars::JsonSchema for RollingUpdateDaemonSet {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for RollingUpdateDaemonSet {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(RollingUpdateDaemonSet {
+            max_unavailable: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
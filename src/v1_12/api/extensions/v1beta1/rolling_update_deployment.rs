@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.extensions.v1beta1.RollingUpdateDeployment
 
 /// Spec to control the desired behavior of rolling update.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct RollingUpdateDeployment {
     /// The maximum number of pods that can be scheduled above the desired number of pods. Value can be an absolute number (ex: 5) or a percentage of desired pods (ex: 10%). This can not be 0 if MaxUnavailable is 0. Absolute number is calculated from percentage by rounding up. By default, a value of 1 is used. Example: when this is set to 30%, the new RC can be scaled up immediately when the rolling update starts, such that the total number of old and new pods do not exceed 130% of desired pods. Once old pods have been killed, new RC can be scaled up further, ensuring that total number of pods running at any time during the update is atmost 130% of desired pods.
     pub max_surge: Option<crate::apimachinery::pkg::util::intstr::IntOrString>,
@@ -10,39 +10,33 @@ pub struct RollingUpdateDeployment {
     pub max_unavailable: Option<crate::apimachinery::pkg::util::intstr::IntOrString>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for RollingUpdateDeployment {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_max_surge,
-            Key_max_unavailable,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl RollingUpdateDeployment {
+    /// Returns this value with [`RollingUpdateDeployment::max_surge`] set to the given value.
+    #[must_use]
+    pub fn with_max_surge(mut self, value: impl Into<crate::apimachinery::pkg::util::intstr::IntOrString>) -> Self {
+        self.max_surge = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`RollingUpdateDeployment::max_unavailable`] set to the given value.
+    #[must_use]
+    pub fn with_max_unavailable(mut self, value: impl Into<crate::apimachinery::pkg::util::intstr::IntOrString>) -> Self {
+        self.max_unavailable = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "maxSurge" => Field::Key_max_surge,
-                            "maxUnavailable" => Field::Key_max_unavailable,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for RollingUpdateDeployment {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl RollingUpdateDeployment {
+    const FIELDS: &'static [&'static str] = &[
+        "maxSurge",
+        "maxUnavailable",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for RollingUpdateDeployment {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,11 +50,11 @@ impl<'de> crate::serde::Deserialize<'de> for RollingUpdateDeployment {
                 let mut value_max_surge: Option<crate::apimachinery::pkg::util::intstr::IntOrString> = None;
                 let mut value_max_unavailable: Option<crate::apimachinery::pkg::util::intstr::IntOrString> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("RollingUpdateDeployment", RollingUpdateDeployment::FIELDS))? {
                     match key {
-                        Field::Key_max_surge => value_max_surge = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_max_unavailable => value_max_unavailable = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_max_surge = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_max_unavailable = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -69,21 +63,78 @@ impl<'de> crate::serde::Deserialize<'de> for RollingUpdateDeployment {
                     max_unavailable: value_max_unavailable,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(RollingUpdateDeployment {
+                    max_surge: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    max_unavailable: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "RollingUpdateDeployment",
-            &[
-                "maxSurge",
-                "maxUnavailable",
-            ],
+            RollingUpdateDeployment::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, RollingUpdateDeployment> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("RollingUpdateDeployment")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_max_surge = false;
+                let mut has_max_unavailable = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("RollingUpdateDeployment", RollingUpdateDeployment::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.max_surge))?; has_max_surge = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.max_unavailable))?; has_max_unavailable = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_max_surge {
+                    self.0.max_surge = None;
+                }
+                if !has_max_unavailable {
+                    self.0.max_unavailable = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.max_surge))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.max_unavailable))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "RollingUpdateDeployment",
+            RollingUpdateDeployment::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for RollingUpdateDeployment {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("RollingUpdateDeployment", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maxSurge", &self.max_surge)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maxUnavailable", &self.max_unavailable)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "RollingUpdateDeployment",
             self.max_surge.as_ref().map_or(0, |_| 1) +
@@ -99,10 +150,84 @@ impl crate::serde::Serialize for RollingUpdateDeployment {
     }
 }
 
+/// A builder of [`RollingUpdateDeployment`]. Created with [`RollingUpdateDeployment::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct RollingUpdateDeploymentBuilder {
+    max_surge: Option<crate::apimachinery::pkg::util::intstr::IntOrString>,
+    max_unavailable: Option<crate::apimachinery::pkg::util::intstr::IntOrString>,
+}
+
+#[cfg(feature = "builder")]
+impl RollingUpdateDeployment {
+    /// Starts building a `RollingUpdateDeployment`. Its required fields must be set before [`RollingUpdateDeploymentBuilder::build`] is called.
+    pub fn builder() -> RollingUpdateDeploymentBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl RollingUpdateDeploymentBuilder {
+    /// Sets [`RollingUpdateDeployment::max_surge`].
+    pub fn max_surge(mut self, value: impl Into<crate::apimachinery::pkg::util::intstr::IntOrString>) -> Self {
+        self.max_surge = Some(value.into());
+        self
+    }
+
+    /// Sets [`RollingUpdateDeployment::max_unavailable`].
+    pub fn max_unavailable(mut self, value: impl Into<crate::apimachinery::pkg::util::intstr::IntOrString>) -> Self {
+        self.max_unavailable = Some(value.into());
+        self
+    }
+
+    /// Builds the `RollingUpdateDeployment`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<RollingUpdateDeployment, crate::MissingFieldError> {
+        Ok(RollingUpdateDeployment {
+            max_surge: self.max_surge,
+            max_unavailable: self.max_unavailable,
+        })
+    }
+}
+
+/// The apply configuration of [`RollingUpdateDeployment`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RollingUpdateDeploymentApplyConfiguration {
+    /// See [`RollingUpdateDeployment::max_surge`].
+    pub max_surge: Option<crate::apimachinery::pkg::util::intstr::IntOrString>,
+
+    /// See [`RollingUpdateDeployment::max_unavailable`].
+    pub max_unavailable: Option<crate::apimachinery::pkg::util::intstr::IntOrString>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for RollingUpdateDeploymentApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "RollingUpdateDeploymentApplyConfiguration",
+            self.max_surge.as_ref().map_or(0, |_| 1) +
+            self.max_unavailable.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.max_surge {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maxSurge", value)?;
+        }
+        if let Some(value) = &self.max_unavailable {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maxUnavailable", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for RollingUpdateDeployment {
     fn schema_name() -> String {
-        "io.k8s.api.extensions.v1beta1.RollingUpdateDeployment".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::extensions::v1beta1::RollingUpdateDeployment".to_owned()
+        }
+        else {
+            "io.k8s.api.extensions.v1beta1.RollingUpdateDeployment".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -113,8 +238,9 @@ impl crate::schemars::JsonSchema for RollingUpdateDeployment {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "maxSurge".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::util::intstr::IntOrString>().into_object();
@@ -124,8 +250,8 @@ impl crate::schemars::JsonSchema for RollingUpdateDeployment {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "maxUnavailable".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::util::intstr::IntOrString>().into_object();
@@ -135,11 +261,22 @@ impl crate::schemars::JsonSchema for RollingUpdateDeployment {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for RollingUpdateDeployment {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(RollingUpdateDeployment {
+            max_surge: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            max_unavailable: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,8 @@
 // Generated from definition io.k8s.api.extensions.v1beta1.RunAsUserStrategyOptions
 
 /// RunAsUserStrategyOptions defines the strategy type and any options used to create the strategy. Deprecated: use RunAsUserStrategyOptions from policy API Group instead.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[deprecated(note = "use RunAsUserStrategyOptions from policy API Group instead.")]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct RunAsUserStrategyOptions {
     /// ranges are the allowed ranges of uids that may be used. If you would like to force a single uid then supply a single range with the same start and end. Required for MustRunAs.
     pub ranges: Option<Vec<crate::api::extensions::v1beta1::IDRange>>,
@@ -10,39 +11,43 @@ pub struct RunAsUserStrategyOptions {
     pub rule: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for RunAsUserStrategyOptions {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_ranges,
-            Key_rule,
-            Other,
+impl RunAsUserStrategyOptions {
+    /// Constructs a `RunAsUserStrategyOptions` from its required fields. Its optional fields are unset.
+    pub fn new(rule: impl Into<String>) -> Self {
+        RunAsUserStrategyOptions {
+            ranges: None,
+            rule: rule.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl RunAsUserStrategyOptions {
+    /// Returns this value with [`RunAsUserStrategyOptions::ranges`] set to the given value.
+    #[must_use]
+    pub fn with_ranges(mut self, value: impl Into<Vec<crate::api::extensions::v1beta1::IDRange>>) -> Self {
+        self.ranges = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`RunAsUserStrategyOptions::rule`] set to the given value.
+    #[must_use]
+    pub fn with_rule(mut self, value: impl Into<String>) -> Self {
+        self.rule = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "ranges" => Field::Key_ranges,
-                            "rule" => Field::Key_rule,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for RunAsUserStrategyOptions {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl RunAsUserStrategyOptions {
+    const FIELDS: &'static [&'static str] = &[
+        "ranges",
+        "rule",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for RunAsUserStrategyOptions {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +61,91 @@ impl<'de> crate::serde::Deserialize<'de> for RunAsUserStrategyOptions {
                 let mut value_ranges: Option<Vec<crate::api::extensions::v1beta1::IDRange>> = None;
                 let mut value_rule: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("RunAsUserStrategyOptions", RunAsUserStrategyOptions::FIELDS))? {
                     match key {
-                        Field::Key_ranges => value_ranges = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_rule => value_rule = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_ranges = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_rule = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(RunAsUserStrategyOptions {
                     ranges: value_ranges,
-                    rule: value_rule.ok_or_else(|| crate::serde::de::Error::missing_field("rule"))?,
+                    rule: value_rule.ok_or_else(|| crate::serde2::missing_field("RunAsUserStrategyOptions", "rule", &["rule"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(RunAsUserStrategyOptions {
+                    ranges: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    rule: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "RunAsUserStrategyOptions",
-            &[
-                "ranges",
-                "rule",
-            ],
+            RunAsUserStrategyOptions::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, RunAsUserStrategyOptions> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("RunAsUserStrategyOptions")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_ranges = false;
+                let mut has_rule = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("RunAsUserStrategyOptions", RunAsUserStrategyOptions::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.ranges))?; has_ranges = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.rule))?; has_rule = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_ranges {
+                    self.0.ranges = None;
+                }
+                if !has_rule {
+                    return Err(crate::serde2::missing_field("RunAsUserStrategyOptions", "rule", &["rule"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.ranges))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.rule))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "RunAsUserStrategyOptions",
+            RunAsUserStrategyOptions::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for RunAsUserStrategyOptions {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("RunAsUserStrategyOptions", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ranges", &self.ranges)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rule", &self.rule)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "RunAsUserStrategyOptions",
             1 +
@@ -97,10 +159,84 @@ impl crate::serde::Serialize for RunAsUserStrategyOptions {
     }
 }
 
+/// A builder of [`RunAsUserStrategyOptions`]. Created with [`RunAsUserStrategyOptions::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct RunAsUserStrategyOptionsBuilder {
+    ranges: Option<Vec<crate::api::extensions::v1beta1::IDRange>>,
+    rule: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl RunAsUserStrategyOptions {
+    /// Starts building a `RunAsUserStrategyOptions`. Its required fields must be set before [`RunAsUserStrategyOptionsBuilder::build`] is called.
+    pub fn builder() -> RunAsUserStrategyOptionsBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl RunAsUserStrategyOptionsBuilder {
+    /// Sets [`RunAsUserStrategyOptions::ranges`].
+    pub fn ranges(mut self, value: impl Into<Vec<crate::api::extensions::v1beta1::IDRange>>) -> Self {
+        self.ranges = Some(value.into());
+        self
+    }
+
+    /// Sets [`RunAsUserStrategyOptions::rule`].
+    pub fn rule(mut self, value: impl Into<String>) -> Self {
+        self.rule = Some(value.into());
+        self
+    }
+
+    /// Builds the `RunAsUserStrategyOptions`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<RunAsUserStrategyOptions, crate::MissingFieldError> {
+        Ok(RunAsUserStrategyOptions {
+            ranges: self.ranges,
+            rule: self.rule.ok_or_else(|| crate::MissingFieldError::new("RunAsUserStrategyOptions", "rule"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`RunAsUserStrategyOptions`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RunAsUserStrategyOptionsApplyConfiguration {
+    /// See [`RunAsUserStrategyOptions::ranges`].
+    pub ranges: Option<Vec<crate::api::extensions::v1beta1::IDRangeApplyConfiguration>>,
+
+    /// See [`RunAsUserStrategyOptions::rule`].
+    pub rule: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for RunAsUserStrategyOptionsApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "RunAsUserStrategyOptionsApplyConfiguration",
+            self.ranges.as_ref().map_or(0, |_| 1) +
+            self.rule.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.ranges {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ranges", value)?;
+        }
+        if let Some(value) = &self.rule {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rule", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for RunAsUserStrategyOptions {
     fn schema_name() -> String {
-        "io.k8s.api.extensions.v1beta1.RunAsUserStrategyOptions".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::extensions::v1beta1::RunAsUserStrategyOptions".to_owned()
+        }
+        else {
+            "io.k8s.api.extensions.v1beta1.RunAsUserStrategyOptions".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -111,8 +247,9 @@ impl crate::schemars::JsonSchema for RunAsUserStrategyOptions {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "ranges".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -126,8 +263,8 @@ impl crate::schemars::JsonSchema for RunAsUserStrategyOptions {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "rule".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -137,8 +274,9 @@ impl crate::schemars::JsonSchema for RunAsUserStrategyOptions {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "rule",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -148,3 +286,13 @@ impl crate::schemars::JsonSchema for RunAsUserStrategyOptions {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for RunAsUserStrategyOptions {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(RunAsUserStrategyOptions {
+            ranges: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            rule: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.policy.v1beta1.FSGroupStrategyOptions
 
 /// FSGroupStrategyOptions defines the strategy type and options used to create the strategy.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct FSGroupStrategyOptions {
     /// ranges are the allowed ranges of fs groups.  If you would like to force a single fs group then supply a single range with the same start and end. Required for MustRunAs.
     pub ranges: Option<Vec<crate::api::policy::v1beta1::IDRange>>,
@@ -10,39 +10,33 @@ pub struct FSGroupStrategyOptions {
     pub rule: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for FSGroupStrategyOptions {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_ranges,
-            Key_rule,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl FSGroupStrategyOptions {
+    /// Returns this value with [`FSGroupStrategyOptions::ranges`] set to the given value.
+    #[must_use]
+    pub fn with_ranges(mut self, value: impl Into<Vec<crate::api::policy::v1beta1::IDRange>>) -> Self {
+        self.ranges = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`FSGroupStrategyOptions::rule`] set to the given value.
+    #[must_use]
+    pub fn with_rule(mut self, value: impl Into<String>) -> Self {
+        self.rule = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "ranges" => Field::Key_ranges,
-                            "rule" => Field::Key_rule,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for FSGroupStrategyOptions {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl FSGroupStrategyOptions {
+    const FIELDS: &'static [&'static str] = &[
+        "ranges",
+        "rule",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for FSGroupStrategyOptions {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,11 +50,11 @@ impl<'de> crate::serde::Deserialize<'de> for FSGroupStrategyOptions {
                 let mut value_ranges: Option<Vec<crate::api::policy::v1beta1::IDRange>> = None;
                 let mut value_rule: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("FSGroupStrategyOptions", FSGroupStrategyOptions::FIELDS))? {
                     match key {
-                        Field::Key_ranges => value_ranges = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_rule => value_rule = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_ranges = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_rule = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -69,21 +63,78 @@ impl<'de> crate::serde::Deserialize<'de> for FSGroupStrategyOptions {
                     rule: value_rule,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(FSGroupStrategyOptions {
+                    ranges: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    rule: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "FSGroupStrategyOptions",
-            &[
-                "ranges",
-                "rule",
-            ],
+            FSGroupStrategyOptions::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, FSGroupStrategyOptions> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("FSGroupStrategyOptions")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_ranges = false;
+                let mut has_rule = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("FSGroupStrategyOptions", FSGroupStrategyOptions::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.ranges))?; has_ranges = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.rule))?; has_rule = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_ranges {
+                    self.0.ranges = None;
+                }
+                if !has_rule {
+                    self.0.rule = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.ranges))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.rule))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "FSGroupStrategyOptions",
+            FSGroupStrategyOptions::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for FSGroupStrategyOptions {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("FSGroupStrategyOptions", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ranges", &self.ranges)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rule", &self.rule)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "FSGroupStrategyOptions",
             self.ranges.as_ref().map_or(0, |_| 1) +
@@ -99,10 +150,84 @@ impl crate::serde::Serialize for FSGroupStrategyOptions {
     }
 }
 
+/// A builder of [`FSGroupStrategyOptions`]. Created with [`FSGroupStrategyOptions::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct FSGroupStrategyOptionsBuilder {
+    ranges: Option<Vec<crate::api::policy::v1beta1::IDRange>>,
+    rule: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl FSGroupStrategyOptions {
+    /// Starts building a `FSGroupStrategyOptions`. Its required fields must be set before [`FSGroupStrategyOptionsBuilder::build`] is called.
+    pub fn builder() -> FSGroupStrategyOptionsBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl FSGroupStrategyOptionsBuilder {
+    /// Sets [`FSGroupStrategyOptions::ranges`].
+    pub fn ranges(mut self, value: impl Into<Vec<crate::api::policy::v1beta1::IDRange>>) -> Self {
+        self.ranges = Some(value.into());
+        self
+    }
+
+    /// Sets [`FSGroupStrategyOptions::rule`].
+    pub fn rule(mut self, value: impl Into<String>) -> Self {
+        self.rule = Some(value.into());
+        self
+    }
+
+    /// Builds the `FSGroupStrategyOptions`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<FSGroupStrategyOptions, crate::MissingFieldError> {
+        Ok(FSGroupStrategyOptions {
+            ranges: self.ranges,
+            rule: self.rule,
+        })
+    }
+}
+
+/// The apply configuration of [`FSGroupStrategyOptions`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FSGroupStrategyOptionsApplyConfiguration {
+    /// See [`FSGroupStrategyOptions::ranges`].
+    pub ranges: Option<Vec<crate::api::policy::v1beta1::IDRangeApplyConfiguration>>,
+
+    /// See [`FSGroupStrategyOptions::rule`].
+    pub rule: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for FSGroupStrategyOptionsApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "FSGroupStrategyOptionsApplyConfiguration",
+            self.ranges.as_ref().map_or(0, |_| 1) +
+            self.rule.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.ranges {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ranges", value)?;
+        }
+        if let Some(value) = &self.rule {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rule", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for FSGroupStrategyOptions {
     fn schema_name() -> String {
-        "io.k8s.api.policy.v1beta1.FSGroupStrategyOptions".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::policy::v1beta1::FSGroupStrategyOptions".to_owned()
+        }
+        else {
+            "io.k8s.api.policy.v1beta1.FSGroupStrategyOptions".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -113,8 +238,9 @@ impl crate::schemars::JsonSchema for FSGroupStrategyOptions {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "ranges".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -128,8 +254,8 @@ impl crate::schemars::JsonSchema for FSGroupStrategyOptions {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "rule".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -139,11 +265,22 @@ impl crate::schemars::JsonSchema for FSGroupStrategyOptions {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for FSGroupStrategyOptions {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(FSGroupStrategyOptions {
+            ranges: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            rule: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.policy.v1beta1.PodDisruptionBudgetSpec
 
 /// PodDisruptionBudgetSpec is a description of a PodDisruptionBudget.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct PodDisruptionBudgetSpec {
     /// An eviction is allowed if at most "maxUnavailable" pods selected by "selector" are unavailable after the eviction, i.e. even in absence of the evicted pod. For example, one can prevent all voluntary evictions by specifying 0. This is a mutually exclusive setting with "minAvailable".
     pub max_unavailable: Option<crate::apimachinery::pkg::util::intstr::IntOrString>,
@@ -13,41 +13,41 @@ pub struct PodDisruptionBudgetSpec {
     pub selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for PodDisruptionBudgetSpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_max_unavailable,
-            Key_min_available,
-            Key_selector,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl PodDisruptionBudgetSpec {
+    /// Returns this value with [`PodDisruptionBudgetSpec::max_unavailable`] set to the given value.
+    #[must_use]
+    pub fn with_max_unavailable(mut self, value: impl Into<crate::apimachinery::pkg::util::intstr::IntOrString>) -> Self {
+        self.max_unavailable = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`PodDisruptionBudgetSpec::min_available`] set to the given value.
+    #[must_use]
+    pub fn with_min_available(mut self, value: impl Into<crate::apimachinery::pkg::util::intstr::IntOrString>) -> Self {
+        self.min_available = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`PodDisruptionBudgetSpec::selector`] set to the given value.
+    #[must_use]
+    pub fn with_selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.selector = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "maxUnavailable" => Field::Key_max_unavailable,
-                            "minAvailable" => Field::Key_min_available,
-                            "selector" => Field::Key_selector,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for PodDisruptionBudgetSpec {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl PodDisruptionBudgetSpec {
+    const FIELDS: &'static [&'static str] = &[
+        "maxUnavailable",
+        "minAvailable",
+        "selector",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for PodDisruptionBudgetSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,12 +62,12 @@ impl<'de> crate::serde::Deserialize<'de> for PodDisruptionBudgetSpec {
                 let mut value_min_available: Option<crate::apimachinery::pkg::util::intstr::IntOrString> = None;
                 let mut value_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodDisruptionBudgetSpec", PodDisruptionBudgetSpec::FIELDS))? {
                     match key {
-                        Field::Key_max_unavailable => value_max_unavailable = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_min_available => value_min_available = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_selector => value_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_max_unavailable = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_min_available = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -77,22 +77,86 @@ impl<'de> crate::serde::Deserialize<'de> for PodDisruptionBudgetSpec {
                     selector: value_selector,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(PodDisruptionBudgetSpec {
+                    max_unavailable: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    min_available: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    selector: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "PodDisruptionBudgetSpec",
-            &[
-                "maxUnavailable",
-                "minAvailable",
-                "selector",
-            ],
+            PodDisruptionBudgetSpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, PodDisruptionBudgetSpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("PodDisruptionBudgetSpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_max_unavailable = false;
+                let mut has_min_available = false;
+                let mut has_selector = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodDisruptionBudgetSpec", PodDisruptionBudgetSpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.max_unavailable))?; has_max_unavailable = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.min_available))?; has_min_available = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.selector))?; has_selector = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_max_unavailable {
+                    self.0.max_unavailable = None;
+                }
+                if !has_min_available {
+                    self.0.min_available = None;
+                }
+                if !has_selector {
+                    self.0.selector = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.max_unavailable))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.min_available))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.selector))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "PodDisruptionBudgetSpec",
+            PodDisruptionBudgetSpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for PodDisruptionBudgetSpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("PodDisruptionBudgetSpec", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maxUnavailable", &self.max_unavailable)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "minAvailable", &self.min_available)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selector", &self.selector)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "PodDisruptionBudgetSpec",
             self.max_unavailable.as_ref().map_or(0, |_| 1) +
@@ -112,10 +176,99 @@ impl crate::serde::Serialize for PodDisruptionBudgetSpec {
     }
 }
 
+/// A builder of [`PodDisruptionBudgetSpec`]. Created with [`PodDisruptionBudgetSpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct PodDisruptionBudgetSpecBuilder {
+    max_unavailable: Option<crate::apimachinery::pkg::util::intstr::IntOrString>,
+    min_available: Option<crate::apimachinery::pkg::util::intstr::IntOrString>,
+    selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>,
+}
+
+#[cfg(feature = "builder")]
+impl PodDisruptionBudgetSpec {
+    /// Starts building a `PodDisruptionBudgetSpec`. Its required fields must be set before [`PodDisruptionBudgetSpecBuilder::build`] is called.
+    pub fn builder() -> PodDisruptionBudgetSpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl PodDisruptionBudgetSpecBuilder {
+    /// Sets [`PodDisruptionBudgetSpec::max_unavailable`].
+    pub fn max_unavailable(mut self, value: impl Into<crate::apimachinery::pkg::util::intstr::IntOrString>) -> Self {
+        self.max_unavailable = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodDisruptionBudgetSpec::min_available`].
+    pub fn min_available(mut self, value: impl Into<crate::apimachinery::pkg::util::intstr::IntOrString>) -> Self {
+        self.min_available = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodDisruptionBudgetSpec::selector`].
+    pub fn selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.selector = Some(value.into());
+        self
+    }
+
+    /// Builds the `PodDisruptionBudgetSpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<PodDisruptionBudgetSpec, crate::MissingFieldError> {
+        Ok(PodDisruptionBudgetSpec {
+            max_unavailable: self.max_unavailable,
+            min_available: self.min_available,
+            selector: self.selector,
+        })
+    }
+}
+
+/// The apply configuration of [`PodDisruptionBudgetSpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodDisruptionBudgetSpecApplyConfiguration {
+    /// See [`PodDisruptionBudgetSpec::max_unavailable`].
+    pub max_unavailable: Option<crate::apimachinery::pkg::util::intstr::IntOrString>,
+
+    /// See [`PodDisruptionBudgetSpec::min_available`].
+    pub min_available: Option<crate::apimachinery::pkg::util::intstr::IntOrString>,
+
+    /// See [`PodDisruptionBudgetSpec::selector`].
+    pub selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for PodDisruptionBudgetSpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "PodDisruptionBudgetSpecApplyConfiguration",
+            self.max_unavailable.as_ref().map_or(0, |_| 1) +
+            self.min_available.as_ref().map_or(0, |_| 1) +
+            self.selector.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.max_unavailable {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maxUnavailable", value)?;
+        }
+        if let Some(value) = &self.min_available {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "minAvailable", value)?;
+        }
+        if let Some(value) = &self.selector {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selector", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodDisruptionBudgetSpec {
     fn schema_name() -> String {
-        "io.k8s.api.policy.v1beta1.PodDisruptionBudgetSpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::policy::v1beta1::PodDisruptionBudgetSpec".to_owned()
+        }
+        else {
+            "io.k8s.api.policy.v1beta1.PodDisruptionBudgetSpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -126,8 +279,9 @@ impl crate::schemars::JsonSchema for PodDisruptionBudgetSpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "maxUnavailable".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::util::intstr::IntOrString>().into_object();
@@ -137,8 +291,8 @@ impl crate::schemars::JsonSchema for PodDisruptionBudgetSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "minAvailable".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::util::intstr::IntOrString>().into_object();
@@ -148,8 +302,8 @@ impl crate::schemars::JsonSchema for PodDisruptionBudgetSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "selector".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>().into_object();
@@ -159,11 +313,23 @@ impl crate::schemars::JsonSchema for PodDisruptionBudgetSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for PodDisruptionBudgetSpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(PodDisruptionBudgetSpec {
+            max_unavailable: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            min_available: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            selector: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.policy.v1beta1.PodSecurityPolicySpec
 
 /// PodSecurityPolicySpec defines the policy enforced.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct PodSecurityPolicySpec {
     /// allowPrivilegeEscalation determines if a pod can request to allow privilege escalation. If unspecified, defaults to true.
     pub allow_privilege_escalation: Option<bool>,
@@ -71,77 +71,214 @@ pub struct PodSecurityPolicySpec {
     pub volumes: Option<Vec<String>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for PodSecurityPolicySpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_allow_privilege_escalation,
-            Key_allowed_capabilities,
-            Key_allowed_flex_volumes,
-            Key_allowed_host_paths,
-            Key_allowed_proc_mount_types,
-            Key_allowed_unsafe_sysctls,
-            Key_default_add_capabilities,
-            Key_default_allow_privilege_escalation,
-            Key_forbidden_sysctls,
-            Key_fs_group,
-            Key_host_ipc,
-            Key_host_network,
-            Key_host_pid,
-            Key_host_ports,
-            Key_privileged,
-            Key_read_only_root_filesystem,
-            Key_required_drop_capabilities,
-            Key_run_as_user,
-            Key_se_linux,
-            Key_supplemental_groups,
-            Key_volumes,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl PodSecurityPolicySpec {
+    /// Constructs a `PodSecurityPolicySpec` from its required fields. Its optional fields are unset.
+    pub fn new(fs_group: impl Into<crate::api::policy::v1beta1::FSGroupStrategyOptions>, run_as_user: impl Into<crate::api::policy::v1beta1::RunAsUserStrategyOptions>, se_linux: impl Into<crate::api::policy::v1beta1::SELinuxStrategyOptions>, supplemental_groups: impl Into<crate::api::policy::v1beta1::SupplementalGroupsStrategyOptions>) -> Self {
+        PodSecurityPolicySpec {
+            allow_privilege_escalation: None,
+            allowed_capabilities: None,
+            allowed_flex_volumes: None,
+            allowed_host_paths: None,
+            allowed_proc_mount_types: None,
+            allowed_unsafe_sysctls: None,
+            default_add_capabilities: None,
+            default_allow_privilege_escalation: None,
+            forbidden_sysctls: None,
+            fs_group: fs_group.into(),
+            host_ipc: None,
+            host_network: None,
+            host_pid: None,
+            host_ports: None,
+            privileged: None,
+            read_only_root_filesystem: None,
+            required_drop_capabilities: None,
+            run_as_user: run_as_user.into(),
+            se_linux: se_linux.into(),
+            supplemental_groups: supplemental_groups.into(),
+            volumes: None,
+        }
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "allowPrivilegeEscalation" => Field::Key_allow_privilege_escalation,
-                            "allowedCapabilities" => Field::Key_allowed_capabilities,
-                            "allowedFlexVolumes" => Field::Key_allowed_flex_volumes,
-                            "allowedHostPaths" => Field::Key_allowed_host_paths,
-                            "allowedProcMountTypes" => Field::Key_allowed_proc_mount_types,
-                            "allowedUnsafeSysctls" => Field::Key_allowed_unsafe_sysctls,
-                            "defaultAddCapabilities" => Field::Key_default_add_capabilities,
-                            "defaultAllowPrivilegeEscalation" => Field::Key_default_allow_privilege_escalation,
-                            "forbiddenSysctls" => Field::Key_forbidden_sysctls,
-                            "fsGroup" => Field::Key_fs_group,
-                            "hostIPC" => Field::Key_host_ipc,
-                            "hostNetwork" => Field::Key_host_network,
-                            "hostPID" => Field::Key_host_pid,
-                            "hostPorts" => Field::Key_host_ports,
-                            "privileged" => Field::Key_privileged,
-                            "readOnlyRootFilesystem" => Field::Key_read_only_root_filesystem,
-                            "requiredDropCapabilities" => Field::Key_required_drop_capabilities,
-                            "runAsUser" => Field::Key_run_as_user,
-                            "seLinux" => Field::Key_se_linux,
-                            "supplementalGroups" => Field::Key_supplemental_groups,
-                            "volumes" => Field::Key_volumes,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl PodSecurityPolicySpec {
+    /// Returns this value with [`PodSecurityPolicySpec::allow_privilege_escalation`] set to the given value.
+    #[must_use]
+    pub fn with_allow_privilege_escalation(mut self, value: impl Into<bool>) -> Self {
+        self.allow_privilege_escalation = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`PodSecurityPolicySpec::allowed_capabilities`] set to the given value.
+    #[must_use]
+    pub fn with_allowed_capabilities(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.allowed_capabilities = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSecurityPolicySpec::allowed_flex_volumes`] set to the given value.
+    #[must_use]
+    pub fn with_allowed_flex_volumes(mut self, value: impl Into<Vec<crate::api::policy::v1beta1::AllowedFlexVolume>>) -> Self {
+        self.allowed_flex_volumes = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSecurityPolicySpec::allowed_host_paths`] set to the given value.
+    #[must_use]
+    pub fn with_allowed_host_paths(mut self, value: impl Into<Vec<crate::api::policy::v1beta1::AllowedHostPath>>) -> Self {
+        self.allowed_host_paths = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSecurityPolicySpec::allowed_proc_mount_types`] set to the given value.
+    #[must_use]
+    pub fn with_allowed_proc_mount_types(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.allowed_proc_mount_types = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSecurityPolicySpec::allowed_unsafe_sysctls`] set to the given value.
+    #[must_use]
+    pub fn with_allowed_unsafe_sysctls(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.allowed_unsafe_sysctls = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSecurityPolicySpec::default_add_capabilities`] set to the given value.
+    #[must_use]
+    pub fn with_default_add_capabilities(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.default_add_capabilities = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSecurityPolicySpec::default_allow_privilege_escalation`] set to the given value.
+    #[must_use]
+    pub fn with_default_allow_privilege_escalation(mut self, value: impl Into<bool>) -> Self {
+        self.default_allow_privilege_escalation = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSecurityPolicySpec::forbidden_sysctls`] set to the given value.
+    #[must_use]
+    pub fn with_forbidden_sysctls(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.forbidden_sysctls = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSecurityPolicySpec::fs_group`] set to the given value.
+    #[must_use]
+    pub fn with_fs_group(mut self, value: impl Into<crate::api::policy::v1beta1::FSGroupStrategyOptions>) -> Self {
+        self.fs_group = value.into();
+        self
+    }
+
+    /// Returns this value with [`PodSecurityPolicySpec::host_ipc`] set to the given value.
+    #[must_use]
+    pub fn with_host_ipc(mut self, value: impl Into<bool>) -> Self {
+        self.host_ipc = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSecurityPolicySpec::host_network`] set to the given value.
+    #[must_use]
+    pub fn with_host_network(mut self, value: impl Into<bool>) -> Self {
+        self.host_network = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSecurityPolicySpec::host_pid`] set to the given value.
+    #[must_use]
+    pub fn with_host_pid(mut self, value: impl Into<bool>) -> Self {
+        self.host_pid = Some(value.into());
+        self
+    }
 
+    /// Returns this value with [`PodSecurityPolicySpec::host_ports`] set to the given value.
+    #[must_use]
+    pub fn with_host_ports(mut self, value: impl Into<Vec<crate::api::policy::v1beta1::HostPortRange>>) -> Self {
+        self.host_ports = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSecurityPolicySpec::privileged`] set to the given value.
+    #[must_use]
+    pub fn with_privileged(mut self, value: impl Into<bool>) -> Self {
+        self.privileged = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSecurityPolicySpec::read_only_root_filesystem`] set to the given value.
+    #[must_use]
+    pub fn with_read_only_root_filesystem(mut self, value: impl Into<bool>) -> Self {
+        self.read_only_root_filesystem = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSecurityPolicySpec::required_drop_capabilities`] set to the given value.
+    #[must_use]
+    pub fn with_required_drop_capabilities(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.required_drop_capabilities = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSecurityPolicySpec::run_as_user`] set to the given value.
+    #[must_use]
+    pub fn with_run_as_user(mut self, value: impl Into<crate::api::policy::v1beta1::RunAsUserStrategyOptions>) -> Self {
+        self.run_as_user = value.into();
+        self
+    }
+
+    /// Returns this value with [`PodSecurityPolicySpec::se_linux`] set to the given value.
+    #[must_use]
+    pub fn with_se_linux(mut self, value: impl Into<crate::api::policy::v1beta1::SELinuxStrategyOptions>) -> Self {
+        self.se_linux = value.into();
+        self
+    }
+
+    /// Returns this value with [`PodSecurityPolicySpec::supplemental_groups`] set to the given value.
+    #[must_use]
+    pub fn with_supplemental_groups(mut self, value: impl Into<crate::api::policy::v1beta1::SupplementalGroupsStrategyOptions>) -> Self {
+        self.supplemental_groups = value.into();
+        self
+    }
+
+    /// Returns this value with [`PodSecurityPolicySpec::volumes`] set to the given value.
+    #[must_use]
+    pub fn with_volumes(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.volumes = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for PodSecurityPolicySpec {}
+
+impl PodSecurityPolicySpec {
+    const FIELDS: &'static [&'static str] = &[
+        "allowPrivilegeEscalation",
+        "allowedCapabilities",
+        "allowedFlexVolumes",
+        "allowedHostPaths",
+        "allowedProcMountTypes",
+        "allowedUnsafeSysctls",
+        "defaultAddCapabilities",
+        "defaultAllowPrivilegeEscalation",
+        "forbiddenSysctls",
+        "fsGroup",
+        "hostIPC",
+        "hostNetwork",
+        "hostPID",
+        "hostPorts",
+        "privileged",
+        "readOnlyRootFilesystem",
+        "requiredDropCapabilities",
+        "runAsUser",
+        "seLinux",
+        "supplementalGroups",
+        "volumes",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for PodSecurityPolicySpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -174,30 +311,30 @@ impl<'de> crate::serde::Deserialize<'de> for PodSecurityPolicySpec {
                 let mut value_supplemental_groups: Option<crate::api::policy::v1beta1::SupplementalGroupsStrategyOptions> = None;
                 let mut value_volumes: Option<Vec<String>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodSecurityPolicySpec", PodSecurityPolicySpec::FIELDS))? {
                     match key {
-                        Field::Key_allow_privilege_escalation => value_allow_privilege_escalation = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_allowed_capabilities => value_allowed_capabilities = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_allowed_flex_volumes => value_allowed_flex_volumes = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_allowed_host_paths => value_allowed_host_paths = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_allowed_proc_mount_types => value_allowed_proc_mount_types = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_allowed_unsafe_sysctls => value_allowed_unsafe_sysctls = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_default_add_capabilities => value_default_add_capabilities = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_default_allow_privilege_escalation => value_default_allow_privilege_escalation = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_forbidden_sysctls => value_forbidden_sysctls = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_fs_group => value_fs_group = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_host_ipc => value_host_ipc = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_host_network => value_host_network = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_host_pid => value_host_pid = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_host_ports => value_host_ports = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_privileged => value_privileged = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_read_only_root_filesystem => value_read_only_root_filesystem = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_required_drop_capabilities => value_required_drop_capabilities = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_run_as_user => value_run_as_user = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_se_linux => value_se_linux = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_supplemental_groups => value_supplemental_groups = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_volumes => value_volumes = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_allow_privilege_escalation = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_allowed_capabilities = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_allowed_flex_volumes = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_allowed_host_paths = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_allowed_proc_mount_types = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_allowed_unsafe_sysctls = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_default_add_capabilities = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(7) => value_default_allow_privilege_escalation = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(8) => value_forbidden_sysctls = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(9) => value_fs_group = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(10) => value_host_ipc = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(11) => value_host_network = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(12) => value_host_pid = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(13) => value_host_ports = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(14) => value_privileged = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(15) => value_read_only_root_filesystem = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(16) => value_required_drop_capabilities = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(17) => value_run_as_user = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(18) => value_se_linux = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(19) => value_supplemental_groups = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(20) => value_volumes = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -211,7 +348,7 @@ impl<'de> crate::serde::Deserialize<'de> for PodSecurityPolicySpec {
                     default_add_capabilities: value_default_add_capabilities,
                     default_allow_privilege_escalation: value_default_allow_privilege_escalation,
                     forbidden_sysctls: value_forbidden_sysctls,
-                    fs_group: value_fs_group.ok_or_else(|| crate::serde::de::Error::missing_field("fsGroup"))?,
+                    fs_group: value_fs_group.ok_or_else(|| crate::serde2::missing_field("PodSecurityPolicySpec", "fsGroup", &["fsGroup", "runAsUser", "seLinux", "supplementalGroups"]))?,
                     host_ipc: value_host_ipc,
                     host_network: value_host_network,
                     host_pid: value_host_pid,
@@ -219,46 +356,236 @@ impl<'de> crate::serde::Deserialize<'de> for PodSecurityPolicySpec {
                     privileged: value_privileged,
                     read_only_root_filesystem: value_read_only_root_filesystem,
                     required_drop_capabilities: value_required_drop_capabilities,
-                    run_as_user: value_run_as_user.ok_or_else(|| crate::serde::de::Error::missing_field("runAsUser"))?,
-                    se_linux: value_se_linux.ok_or_else(|| crate::serde::de::Error::missing_field("seLinux"))?,
-                    supplemental_groups: value_supplemental_groups.ok_or_else(|| crate::serde::de::Error::missing_field("supplementalGroups"))?,
+                    run_as_user: value_run_as_user.ok_or_else(|| crate::serde2::missing_field("PodSecurityPolicySpec", "runAsUser", &["fsGroup", "runAsUser", "seLinux", "supplementalGroups"]))?,
+                    se_linux: value_se_linux.ok_or_else(|| crate::serde2::missing_field("PodSecurityPolicySpec", "seLinux", &["fsGroup", "runAsUser", "seLinux", "supplementalGroups"]))?,
+                    supplemental_groups: value_supplemental_groups.ok_or_else(|| crate::serde2::missing_field("PodSecurityPolicySpec", "supplementalGroups", &["fsGroup", "runAsUser", "seLinux", "supplementalGroups"]))?,
                     volumes: value_volumes,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(PodSecurityPolicySpec {
+                    allow_privilege_escalation: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    allowed_capabilities: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    allowed_flex_volumes: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    allowed_host_paths: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    allowed_proc_mount_types: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    allowed_unsafe_sysctls: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    default_add_capabilities: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    default_allow_privilege_escalation: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                    forbidden_sysctls: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?,
+                    fs_group: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?,
+                    host_ipc: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?,
+                    host_network: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(11, &self))?,
+                    host_pid: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(12, &self))?,
+                    host_ports: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(13, &self))?,
+                    privileged: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(14, &self))?,
+                    read_only_root_filesystem: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(15, &self))?,
+                    required_drop_capabilities: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(16, &self))?,
+                    run_as_user: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(17, &self))?,
+                    se_linux: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(18, &self))?,
+                    supplemental_groups: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(19, &self))?,
+                    volumes: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(20, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "PodSecurityPolicySpec",
-            &[
-                "allowPrivilegeEscalation",
-                "allowedCapabilities",
-                "allowedFlexVolumes",
-                "allowedHostPaths",
-                "allowedProcMountTypes",
-                "allowedUnsafeSysctls",
-                "defaultAddCapabilities",
-                "defaultAllowPrivilegeEscalation",
-                "forbiddenSysctls",
-                "fsGroup",
-                "hostIPC",
-                "hostNetwork",
-                "hostPID",
-                "hostPorts",
-                "privileged",
-                "readOnlyRootFilesystem",
-                "requiredDropCapabilities",
-                "runAsUser",
-                "seLinux",
-                "supplementalGroups",
-                "volumes",
-            ],
+            PodSecurityPolicySpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, PodSecurityPolicySpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("PodSecurityPolicySpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_allow_privilege_escalation = false;
+                let mut has_allowed_capabilities = false;
+                let mut has_allowed_flex_volumes = false;
+                let mut has_allowed_host_paths = false;
+                let mut has_allowed_proc_mount_types = false;
+                let mut has_allowed_unsafe_sysctls = false;
+                let mut has_default_add_capabilities = false;
+                let mut has_default_allow_privilege_escalation = false;
+                let mut has_forbidden_sysctls = false;
+                let mut has_fs_group = false;
+                let mut has_host_ipc = false;
+                let mut has_host_network = false;
+                let mut has_host_pid = false;
+                let mut has_host_ports = false;
+                let mut has_privileged = false;
+                let mut has_read_only_root_filesystem = false;
+                let mut has_required_drop_capabilities = false;
+                let mut has_run_as_user = false;
+                let mut has_se_linux = false;
+                let mut has_supplemental_groups = false;
+                let mut has_volumes = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodSecurityPolicySpec", PodSecurityPolicySpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.allow_privilege_escalation))?; has_allow_privilege_escalation = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.allowed_capabilities))?; has_allowed_capabilities = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.allowed_flex_volumes))?; has_allowed_flex_volumes = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.allowed_host_paths))?; has_allowed_host_paths = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.allowed_proc_mount_types))?; has_allowed_proc_mount_types = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.allowed_unsafe_sysctls))?; has_allowed_unsafe_sysctls = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.default_add_capabilities))?; has_default_add_capabilities = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.default_allow_privilege_escalation))?; has_default_allow_privilege_escalation = true; },
+                        Some(8) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.forbidden_sysctls))?; has_forbidden_sysctls = true; },
+                        Some(9) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.fs_group))?; has_fs_group = true; },
+                        Some(10) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.host_ipc))?; has_host_ipc = true; },
+                        Some(11) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.host_network))?; has_host_network = true; },
+                        Some(12) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.host_pid))?; has_host_pid = true; },
+                        Some(13) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.host_ports))?; has_host_ports = true; },
+                        Some(14) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.privileged))?; has_privileged = true; },
+                        Some(15) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.read_only_root_filesystem))?; has_read_only_root_filesystem = true; },
+                        Some(16) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.required_drop_capabilities))?; has_required_drop_capabilities = true; },
+                        Some(17) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.run_as_user))?; has_run_as_user = true; },
+                        Some(18) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.se_linux))?; has_se_linux = true; },
+                        Some(19) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.supplemental_groups))?; has_supplemental_groups = true; },
+                        Some(20) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.volumes))?; has_volumes = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_allow_privilege_escalation {
+                    self.0.allow_privilege_escalation = None;
+                }
+                if !has_allowed_capabilities {
+                    self.0.allowed_capabilities = None;
+                }
+                if !has_allowed_flex_volumes {
+                    self.0.allowed_flex_volumes = None;
+                }
+                if !has_allowed_host_paths {
+                    self.0.allowed_host_paths = None;
+                }
+                if !has_allowed_proc_mount_types {
+                    self.0.allowed_proc_mount_types = None;
+                }
+                if !has_allowed_unsafe_sysctls {
+                    self.0.allowed_unsafe_sysctls = None;
+                }
+                if !has_default_add_capabilities {
+                    self.0.default_add_capabilities = None;
+                }
+                if !has_default_allow_privilege_escalation {
+                    self.0.default_allow_privilege_escalation = None;
+                }
+                if !has_forbidden_sysctls {
+                    self.0.forbidden_sysctls = None;
+                }
+                if !has_fs_group {
+                    return Err(crate::serde2::missing_field("PodSecurityPolicySpec", "fsGroup", &["fsGroup", "runAsUser", "seLinux", "supplementalGroups"]));
+                }
+                if !has_host_ipc {
+                    self.0.host_ipc = None;
+                }
+                if !has_host_network {
+                    self.0.host_network = None;
+                }
+                if !has_host_pid {
+                    self.0.host_pid = None;
+                }
+                if !has_host_ports {
+                    self.0.host_ports = None;
+                }
+                if !has_privileged {
+                    self.0.privileged = None;
+                }
+                if !has_read_only_root_filesystem {
+                    self.0.read_only_root_filesystem = None;
+                }
+                if !has_required_drop_capabilities {
+                    self.0.required_drop_capabilities = None;
+                }
+                if !has_run_as_user {
+                    return Err(crate::serde2::missing_field("PodSecurityPolicySpec", "runAsUser", &["fsGroup", "runAsUser", "seLinux", "supplementalGroups"]));
+                }
+                if !has_se_linux {
+                    return Err(crate::serde2::missing_field("PodSecurityPolicySpec", "seLinux", &["fsGroup", "runAsUser", "seLinux", "supplementalGroups"]));
+                }
+                if !has_supplemental_groups {
+                    return Err(crate::serde2::missing_field("PodSecurityPolicySpec", "supplementalGroups", &["fsGroup", "runAsUser", "seLinux", "supplementalGroups"]));
+                }
+                if !has_volumes {
+                    self.0.volumes = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.allow_privilege_escalation))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.allowed_capabilities))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.allowed_flex_volumes))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.allowed_host_paths))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.allowed_proc_mount_types))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.allowed_unsafe_sysctls))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.default_add_capabilities))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.default_allow_privilege_escalation))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.forbidden_sysctls))?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.fs_group))?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.host_ipc))?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.host_network))?.ok_or_else(|| crate::serde::de::Error::invalid_length(11, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.host_pid))?.ok_or_else(|| crate::serde::de::Error::invalid_length(12, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.host_ports))?.ok_or_else(|| crate::serde::de::Error::invalid_length(13, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.privileged))?.ok_or_else(|| crate::serde::de::Error::invalid_length(14, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.read_only_root_filesystem))?.ok_or_else(|| crate::serde::de::Error::invalid_length(15, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.required_drop_capabilities))?.ok_or_else(|| crate::serde::de::Error::invalid_length(16, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.run_as_user))?.ok_or_else(|| crate::serde::de::Error::invalid_length(17, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.se_linux))?.ok_or_else(|| crate::serde::de::Error::invalid_length(18, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.supplemental_groups))?.ok_or_else(|| crate::serde::de::Error::invalid_length(19, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.volumes))?.ok_or_else(|| crate::serde::de::Error::invalid_length(20, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "PodSecurityPolicySpec",
+            PodSecurityPolicySpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for PodSecurityPolicySpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("PodSecurityPolicySpec", 21)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "allowPrivilegeEscalation", &self.allow_privilege_escalation)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "allowedCapabilities", &self.allowed_capabilities)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "allowedFlexVolumes", &self.allowed_flex_volumes)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "allowedHostPaths", &self.allowed_host_paths)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "allowedProcMountTypes", &self.allowed_proc_mount_types)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "allowedUnsafeSysctls", &self.allowed_unsafe_sysctls)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "defaultAddCapabilities", &self.default_add_capabilities)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "defaultAllowPrivilegeEscalation", &self.default_allow_privilege_escalation)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "forbiddenSysctls", &self.forbidden_sysctls)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsGroup", &self.fs_group)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostIPC", &self.host_ipc)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostNetwork", &self.host_network)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostPID", &self.host_pid)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostPorts", &self.host_ports)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "privileged", &self.privileged)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnlyRootFilesystem", &self.read_only_root_filesystem)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "requiredDropCapabilities", &self.required_drop_capabilities)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "runAsUser", &self.run_as_user)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "seLinux", &self.se_linux)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "supplementalGroups", &self.supplemental_groups)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumes", &self.volumes)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "PodSecurityPolicySpec",
             4 +
@@ -339,10 +666,369 @@ impl crate::serde::Serialize for PodSecurityPolicySpec {
     }
 }
 
+/// A builder of [`PodSecurityPolicySpec`]. Created with [`PodSecurityPolicySpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct PodSecurityPolicySpecBuilder {
+    allow_privilege_escalation: Option<bool>,
+    allowed_capabilities: Option<Vec<String>>,
+    allowed_flex_volumes: Option<Vec<crate::api::policy::v1beta1::AllowedFlexVolume>>,
+    allowed_host_paths: Option<Vec<crate::api::policy::v1beta1::AllowedHostPath>>,
+    allowed_proc_mount_types: Option<Vec<String>>,
+    allowed_unsafe_sysctls: Option<Vec<String>>,
+    default_add_capabilities: Option<Vec<String>>,
+    default_allow_privilege_escalation: Option<bool>,
+    forbidden_sysctls: Option<Vec<String>>,
+    fs_group: Option<crate::api::policy::v1beta1::FSGroupStrategyOptions>,
+    host_ipc: Option<bool>,
+    host_network: Option<bool>,
+    host_pid: Option<bool>,
+    host_ports: Option<Vec<crate::api::policy::v1beta1::HostPortRange>>,
+    privileged: Option<bool>,
+    read_only_root_filesystem: Option<bool>,
+    required_drop_capabilities: Option<Vec<String>>,
+    run_as_user: Option<crate::api::policy::v1beta1::RunAsUserStrategyOptions>,
+    se_linux: Option<crate::api::policy::v1beta1::SELinuxStrategyOptions>,
+    supplemental_groups: Option<crate::api::policy::v1beta1::SupplementalGroupsStrategyOptions>,
+    volumes: Option<Vec<String>>,
+}
+
+#[cfg(feature = "builder")]
+impl PodSecurityPolicySpec {
+    /// Starts building a `PodSecurityPolicySpec`. Its required fields must be set before [`PodSecurityPolicySpecBuilder::build`] is called.
+    pub fn builder() -> PodSecurityPolicySpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl PodSecurityPolicySpecBuilder {
+    /// Sets [`PodSecurityPolicySpec::allow_privilege_escalation`].
+    pub fn allow_privilege_escalation(mut self, value: impl Into<bool>) -> Self {
+        self.allow_privilege_escalation = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSecurityPolicySpec::allowed_capabilities`].
+    pub fn allowed_capabilities(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.allowed_capabilities = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSecurityPolicySpec::allowed_flex_volumes`].
+    pub fn allowed_flex_volumes(mut self, value: impl Into<Vec<crate::api::policy::v1beta1::AllowedFlexVolume>>) -> Self {
+        self.allowed_flex_volumes = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSecurityPolicySpec::allowed_host_paths`].
+    pub fn allowed_host_paths(mut self, value: impl Into<Vec<crate::api::policy::v1beta1::AllowedHostPath>>) -> Self {
+        self.allowed_host_paths = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSecurityPolicySpec::allowed_proc_mount_types`].
+    pub fn allowed_proc_mount_types(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.allowed_proc_mount_types = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSecurityPolicySpec::allowed_unsafe_sysctls`].
+    pub fn allowed_unsafe_sysctls(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.allowed_unsafe_sysctls = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSecurityPolicySpec::default_add_capabilities`].
+    pub fn default_add_capabilities(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.default_add_capabilities = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSecurityPolicySpec::default_allow_privilege_escalation`].
+    pub fn default_allow_privilege_escalation(mut self, value: impl Into<bool>) -> Self {
+        self.default_allow_privilege_escalation = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSecurityPolicySpec::forbidden_sysctls`].
+    pub fn forbidden_sysctls(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.forbidden_sysctls = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSecurityPolicySpec::fs_group`].
+    pub fn fs_group(mut self, value: impl Into<crate::api::policy::v1beta1::FSGroupStrategyOptions>) -> Self {
+        self.fs_group = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSecurityPolicySpec::host_ipc`].
+    pub fn host_ipc(mut self, value: impl Into<bool>) -> Self {
+        self.host_ipc = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSecurityPolicySpec::host_network`].
+    pub fn host_network(mut self, value: impl Into<bool>) -> Self {
+        self.host_network = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSecurityPolicySpec::host_pid`].
+    pub fn host_pid(mut self, value: impl Into<bool>) -> Self {
+        self.host_pid = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSecurityPolicySpec::host_ports`].
+    pub fn host_ports(mut self, value: impl Into<Vec<crate::api::policy::v1beta1::HostPortRange>>) -> Self {
+        self.host_ports = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSecurityPolicySpec::privileged`].
+    pub fn privileged(mut self, value: impl Into<bool>) -> Self {
+        self.privileged = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSecurityPolicySpec::read_only_root_filesystem`].
+    pub fn read_only_root_filesystem(mut self, value: impl Into<bool>) -> Self {
+        self.read_only_root_filesystem = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSecurityPolicySpec::required_drop_capabilities`].
+    pub fn required_drop_capabilities(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.required_drop_capabilities = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSecurityPolicySpec::run_as_user`].
+    pub fn run_as_user(mut self, value: impl Into<crate::api::policy::v1beta1::RunAsUserStrategyOptions>) -> Self {
+        self.run_as_user = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSecurityPolicySpec::se_linux`].
+    pub fn se_linux(mut self, value: impl Into<crate::api::policy::v1beta1::SELinuxStrategyOptions>) -> Self {
+        self.se_linux = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSecurityPolicySpec::supplemental_groups`].
+    pub fn supplemental_groups(mut self, value: impl Into<crate::api::policy::v1beta1::SupplementalGroupsStrategyOptions>) -> Self {
+        self.supplemental_groups = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSecurityPolicySpec::volumes`].
+    pub fn volumes(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.volumes = Some(value.into());
+        self
+    }
+
+    /// Builds the `PodSecurityPolicySpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<PodSecurityPolicySpec, crate::MissingFieldError> {
+        Ok(PodSecurityPolicySpec {
+            allow_privilege_escalation: self.allow_privilege_escalation,
+            allowed_capabilities: self.allowed_capabilities,
+            allowed_flex_volumes: self.allowed_flex_volumes,
+            allowed_host_paths: self.allowed_host_paths,
+            allowed_proc_mount_types: self.allowed_proc_mount_types,
+            allowed_unsafe_sysctls: self.allowed_unsafe_sysctls,
+            default_add_capabilities: self.default_add_capabilities,
+            default_allow_privilege_escalation: self.default_allow_privilege_escalation,
+            forbidden_sysctls: self.forbidden_sysctls,
+            fs_group: self.fs_group.ok_or_else(|| crate::MissingFieldError::new("PodSecurityPolicySpec", "fsGroup"))?,
+            host_ipc: self.host_ipc,
+            host_network: self.host_network,
+            host_pid: self.host_pid,
+            host_ports: self.host_ports,
+            privileged: self.privileged,
+            read_only_root_filesystem: self.read_only_root_filesystem,
+            required_drop_capabilities: self.required_drop_capabilities,
+            run_as_user: self.run_as_user.ok_or_else(|| crate::MissingFieldError::new("PodSecurityPolicySpec", "runAsUser"))?,
+            se_linux: self.se_linux.ok_or_else(|| crate::MissingFieldError::new("PodSecurityPolicySpec", "seLinux"))?,
+            supplemental_groups: self.supplemental_groups.ok_or_else(|| crate::MissingFieldError::new("PodSecurityPolicySpec", "supplementalGroups"))?,
+            volumes: self.volumes,
+        })
+    }
+}
+
+/// The apply configuration of [`PodSecurityPolicySpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodSecurityPolicySpecApplyConfiguration {
+    /// See [`PodSecurityPolicySpec::allow_privilege_escalation`].
+    pub allow_privilege_escalation: Option<bool>,
+
+    /// See [`PodSecurityPolicySpec::allowed_capabilities`].
+    pub allowed_capabilities: Option<Vec<String>>,
+
+    /// See [`PodSecurityPolicySpec::allowed_flex_volumes`].
+    pub allowed_flex_volumes: Option<Vec<crate::api::policy::v1beta1::AllowedFlexVolumeApplyConfiguration>>,
+
+    /// See [`PodSecurityPolicySpec::allowed_host_paths`].
+    pub allowed_host_paths: Option<Vec<crate::api::policy::v1beta1::AllowedHostPathApplyConfiguration>>,
+
+    /// See [`PodSecurityPolicySpec::allowed_proc_mount_types`].
+    pub allowed_proc_mount_types: Option<Vec<String>>,
+
+    /// See [`PodSecurityPolicySpec::allowed_unsafe_sysctls`].
+    pub allowed_unsafe_sysctls: Option<Vec<String>>,
+
+    /// See [`PodSecurityPolicySpec::default_add_capabilities`].
+    pub default_add_capabilities: Option<Vec<String>>,
+
+    /// See [`PodSecurityPolicySpec::default_allow_privilege_escalation`].
+    pub default_allow_privilege_escalation: Option<bool>,
+
+    /// See [`PodSecurityPolicySpec::forbidden_sysctls`].
+    pub forbidden_sysctls: Option<Vec<String>>,
+
+    /// See [`PodSecurityPolicySpec::fs_group`].
+    pub fs_group: Option<crate::api::policy::v1beta1::FSGroupStrategyOptionsApplyConfiguration>,
+
+    /// See [`PodSecurityPolicySpec::host_ipc`].
+    pub host_ipc: Option<bool>,
+
+    /// See [`PodSecurityPolicySpec::host_network`].
+    pub host_network: Option<bool>,
+
+    /// See [`PodSecurityPolicySpec::host_pid`].
+    pub host_pid: Option<bool>,
+
+    /// See [`PodSecurityPolicySpec::host_ports`].
+    pub host_ports: Option<Vec<crate::api::policy::v1beta1::HostPortRangeApplyConfiguration>>,
+
+    /// See [`PodSecurityPolicySpec::privileged`].
+    pub privileged: Option<bool>,
+
+    /// See [`PodSecurityPolicySpec::read_only_root_filesystem`].
+    pub read_only_root_filesystem: Option<bool>,
+
+    /// See [`PodSecurityPolicySpec::required_drop_capabilities`].
+    pub required_drop_capabilities: Option<Vec<String>>,
+
+    /// See [`PodSecurityPolicySpec::run_as_user`].
+    pub run_as_user: Option<crate::api::policy::v1beta1::RunAsUserStrategyOptionsApplyConfiguration>,
+
+    /// See [`PodSecurityPolicySpec::se_linux`].
+    pub se_linux: Option<crate::api::policy::v1beta1::SELinuxStrategyOptionsApplyConfiguration>,
+
+    /// See [`PodSecurityPolicySpec::supplemental_groups`].
+    pub supplemental_groups: Option<crate::api::policy::v1beta1::SupplementalGroupsStrategyOptionsApplyConfiguration>,
+
+    /// See [`PodSecurityPolicySpec::volumes`].
+    pub volumes: Option<Vec<String>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for PodSecurityPolicySpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "PodSecurityPolicySpecApplyConfiguration",
+            self.allow_privilege_escalation.as_ref().map_or(0, |_| 1) +
+            self.allowed_capabilities.as_ref().map_or(0, |_| 1) +
+            self.allowed_flex_volumes.as_ref().map_or(0, |_| 1) +
+            self.allowed_host_paths.as_ref().map_or(0, |_| 1) +
+            self.allowed_proc_mount_types.as_ref().map_or(0, |_| 1) +
+            self.allowed_unsafe_sysctls.as_ref().map_or(0, |_| 1) +
+            self.default_add_capabilities.as_ref().map_or(0, |_| 1) +
+            self.default_allow_privilege_escalation.as_ref().map_or(0, |_| 1) +
+            self.forbidden_sysctls.as_ref().map_or(0, |_| 1) +
+            self.fs_group.as_ref().map_or(0, |_| 1) +
+            self.host_ipc.as_ref().map_or(0, |_| 1) +
+            self.host_network.as_ref().map_or(0, |_| 1) +
+            self.host_pid.as_ref().map_or(0, |_| 1) +
+            self.host_ports.as_ref().map_or(0, |_| 1) +
+            self.privileged.as_ref().map_or(0, |_| 1) +
+            self.read_only_root_filesystem.as_ref().map_or(0, |_| 1) +
+            self.required_drop_capabilities.as_ref().map_or(0, |_| 1) +
+            self.run_as_user.as_ref().map_or(0, |_| 1) +
+            self.se_linux.as_ref().map_or(0, |_| 1) +
+            self.supplemental_groups.as_ref().map_or(0, |_| 1) +
+            self.volumes.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.allow_privilege_escalation {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "allowPrivilegeEscalation", value)?;
+        }
+        if let Some(value) = &self.allowed_capabilities {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "allowedCapabilities", value)?;
+        }
+        if let Some(value) = &self.allowed_flex_volumes {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "allowedFlexVolumes", value)?;
+        }
+        if let Some(value) = &self.allowed_host_paths {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "allowedHostPaths", value)?;
+        }
+        if let Some(value) = &self.allowed_proc_mount_types {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "allowedProcMountTypes", value)?;
+        }
+        if let Some(value) = &self.allowed_unsafe_sysctls {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "allowedUnsafeSysctls", value)?;
+        }
+        if let Some(value) = &self.default_add_capabilities {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "defaultAddCapabilities", value)?;
+        }
+        if let Some(value) = &self.default_allow_privilege_escalation {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "defaultAllowPrivilegeEscalation", value)?;
+        }
+        if let Some(value) = &self.forbidden_sysctls {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "forbiddenSysctls", value)?;
+        }
+        if let Some(value) = &self.fs_group {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsGroup", value)?;
+        }
+        if let Some(value) = &self.host_ipc {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostIPC", value)?;
+        }
+        if let Some(value) = &self.host_network {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostNetwork", value)?;
+        }
+        if let Some(value) = &self.host_pid {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostPID", value)?;
+        }
+        if let Some(value) = &self.host_ports {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostPorts", value)?;
+        }
+        if let Some(value) = &self.privileged {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "privileged", value)?;
+        }
+        if let Some(value) = &self.read_only_root_filesystem {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnlyRootFilesystem", value)?;
+        }
+        if let Some(value) = &self.required_drop_capabilities {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "requiredDropCapabilities", value)?;
+        }
+        if let Some(value) = &self.run_as_user {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "runAsUser", value)?;
+        }
+        if let Some(value) = &self.se_linux {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "seLinux", value)?;
+        }
+        if let Some(value) = &self.supplemental_groups {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "supplementalGroups", value)?;
+        }
+        if let Some(value) = &self.volumes {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumes", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodSecurityPolicySpec {
     fn schema_name() -> String {
-        "io.k8s.api.policy.v1beta1.PodSecurityPolicySpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::policy::v1beta1::PodSecurityPolicySpec".to_owned()
+        }
+        else {
+            "io.k8s.api.policy.v1beta1.PodSecurityPolicySpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -353,8 +1039,9 @@ impl crate::schemars::JsonSchema for PodSecurityPolicySpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "allowPrivilegeEscalation".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -364,8 +1051,8 @@ impl crate::schemars::JsonSchema for PodSecurityPolicySpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "allowedCapabilities".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -384,8 +1071,8 @@ impl crate::schemars::JsonSchema for PodSecurityPolicySpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "allowedFlexVolumes".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -399,8 +1086,8 @@ impl crate::schemars::JsonSchema for PodSecurityPolicySpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "allowedHostPaths".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -414,8 +1101,8 @@ impl crate::schemars::JsonSchema for PodSecurityPolicySpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "allowedProcMountTypes".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -434,8 +1121,8 @@ impl crate::schemars::JsonSchema for PodSecurityPolicySpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "allowedUnsafeSysctls".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -454,8 +1141,8 @@ impl crate::schemars::JsonSchema for PodSecurityPolicySpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "defaultAddCapabilities".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -474,8 +1161,8 @@ impl crate::schemars::JsonSchema for PodSecurityPolicySpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "defaultAllowPrivilegeEscalation".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -485,8 +1172,8 @@ impl crate::schemars::JsonSchema for PodSecurityPolicySpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "forbiddenSysctls".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -505,8 +1192,8 @@ impl crate::schemars::JsonSchema for PodSecurityPolicySpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "fsGroup".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::policy::v1beta1::FSGroupStrategyOptions>().into_object();
@@ -516,8 +1203,8 @@ impl crate::schemars::JsonSchema for PodSecurityPolicySpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "hostIPC".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -527,8 +1214,8 @@ impl crate::schemars::JsonSchema for PodSecurityPolicySpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "hostNetwork".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -538,8 +1225,8 @@ impl crate::schemars::JsonSchema for PodSecurityPolicySpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "hostPID".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -549,8 +1236,8 @@ impl crate::schemars::JsonSchema for PodSecurityPolicySpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "hostPorts".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -564,8 +1251,8 @@ impl crate::schemars::JsonSchema for PodSecurityPolicySpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "privileged".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -575,8 +1262,8 @@ impl crate::schemars::JsonSchema for PodSecurityPolicySpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "readOnlyRootFilesystem".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -586,8 +1273,8 @@ impl crate::schemars::JsonSchema for PodSecurityPolicySpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "requiredDropCapabilities".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -606,8 +1293,8 @@ impl crate::schemars::JsonSchema for PodSecurityPolicySpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "runAsUser".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::policy::v1beta1::RunAsUserStrategyOptions>().into_object();
@@ -617,8 +1304,8 @@ impl crate::schemars::JsonSchema for PodSecurityPolicySpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "seLinux".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::policy::v1beta1::SELinuxStrategyOptions>().into_object();
@@ -628,8 +1315,8 @@ impl crate::schemars::JsonSchema for PodSecurityPolicySpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "supplementalGroups".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::policy::v1beta1::SupplementalGroupsStrategyOptions>().into_object();
@@ -639,8 +1326,8 @@ impl crate::schemars::JsonSchema for PodSecurityPolicySpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "volumes".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -659,8 +1346,9 @@ impl crate::schemars::JsonSchema for PodSecurityPolicySpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "fsGroup",
                     "runAsUser",
@@ -673,3 +1361,32 @@ impl crate::schemars::JsonSchema for PodSecurityPolicySpec {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for PodSecurityPolicySpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(PodSecurityPolicySpec {
+            allow_privilege_escalation: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            allowed_capabilities: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            allowed_flex_volumes: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            allowed_host_paths: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            allowed_proc_mount_types: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            allowed_unsafe_sysctls: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            default_add_capabilities: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            default_allow_privilege_escalation: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            forbidden_sysctls: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            fs_group: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            host_ipc: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            host_network: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            host_pid: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            host_ports: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            privileged: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            read_only_root_filesystem: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            required_drop_capabilities: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            run_as_user: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            se_linux: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            supplemental_groups: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            volumes: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.rbac.v1.ClusterRole
 
 /// ClusterRole is a cluster level, logical grouping of PolicyRules that can be referenced as a unit by a RoleBinding or ClusterRoleBinding.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ClusterRole {
     /// AggregationRule is an optional field that describes how to build the Rules for this ClusterRole. If AggregationRule is set, then the Rules are controller managed and direct changes to Rules will be stomped by the controller.
     pub aggregation_rule: Option<crate::api::rbac::v1::AggregationRule>,
@@ -13,6 +13,40 @@ pub struct ClusterRole {
     pub rules: Vec<crate::api::rbac::v1::PolicyRule>,
 }
 
+impl ClusterRole {
+    /// Constructs a `ClusterRole` from its required fields. Its optional fields are unset.
+    pub fn new(metadata: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>, rules: impl Into<Vec<crate::api::rbac::v1::PolicyRule>>) -> Self {
+        ClusterRole {
+            aggregation_rule: None,
+            metadata: metadata.into(),
+            rules: rules.into(),
+        }
+    }
+}
+
+impl ClusterRole {
+    /// Returns this value with [`ClusterRole::aggregation_rule`] set to the given value.
+    #[must_use]
+    pub fn with_aggregation_rule(mut self, value: impl Into<crate::api::rbac::v1::AggregationRule>) -> Self {
+        self.aggregation_rule = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ClusterRole::metadata`] set to the given value.
+    #[must_use]
+    pub fn with_metadata(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        self.metadata = value.into();
+        self
+    }
+
+    /// Returns this value with [`ClusterRole::rules`] set to the given value.
+    #[must_use]
+    pub fn with_rules(mut self, value: impl Into<Vec<crate::api::rbac::v1::PolicyRule>>) -> Self {
+        self.rules = value.into();
+        self
+    }
+}
+
 // Begin rbac.authorization.k8s.io/v1/ClusterRole
 
 // Generated from operation createRbacAuthorizationV1ClusterRole
@@ -70,6 +104,7 @@ impl ClusterRole {
         name: &str,
         optional: crate::DeleteOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::DeleteResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
         let __url = format!("/apis/rbac.authorization.k8s.io/v1/clusterroles/{name}",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -176,6 +211,7 @@ impl ClusterRole {
         body: &crate::apimachinery::pkg::apis::meta::v1::Patch,
         optional: crate::PatchOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::PatchResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
         let __url = format!("/apis/rbac.authorization.k8s.io/v1/clusterroles/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -221,6 +257,7 @@ impl ClusterRole {
         let ReadClusterRoleOptional {
             pretty,
         } = optional;
+        crate::validate_path_parameter("name", name)?;
         let __url = format!("/apis/rbac.authorization.k8s.io/v1/clusterroles/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -309,6 +346,7 @@ impl ClusterRole {
         body: &crate::api::rbac::v1::ClusterRole,
         optional: crate::ReplaceOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::ReplaceResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
         let __url = format!("/apis/rbac.authorization.k8s.io/v1/clusterroles/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -366,6 +404,9 @@ impl crate::Resource for ClusterRole {
     const KIND: &'static str = "ClusterRole";
     const VERSION: &'static str = "v1";
     const URL_PATH_SEGMENT: &'static str = "clusterroles";
+    const VERBS: &'static [&'static str] = &["create", "delete", "deletecollection", "get", "list", "patch", "update", "watch"];
+    const SINGULAR: &'static str = "clusterrole";
+    const SHORT_NAMES: &'static [&'static str] = &[];
     type Scope = crate::ClusterResourceScope;
 }
 
@@ -385,45 +426,30 @@ impl crate::Metadata for ClusterRole {
     }
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ClusterRole {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_api_version,
-            Key_kind,
-            Key_aggregation_rule,
-            Key_metadata,
-            Key_rules,
-            Other,
+impl std::fmt::Display for ClusterRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(<Self as crate::Resource>::URL_PATH_SEGMENT)?;
+        for part in [&self.metadata.namespace, &self.metadata.name].iter().copied().flatten() {
+            write!(f, "/{part}")?;
         }
+        Ok(())
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
-
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "apiVersion" => Field::Key_api_version,
-                            "kind" => Field::Key_kind,
-                            "aggregationRule" => Field::Key_aggregation_rule,
-                            "metadata" => Field::Key_metadata,
-                            "rules" => Field::Key_rules,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for ClusterRole {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl ClusterRole {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "kind",
+        "aggregationRule",
+        "metadata",
+        "rules",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ClusterRole {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -438,51 +464,125 @@ impl<'de> crate::serde::Deserialize<'de> for ClusterRole {
                 let mut value_metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> = None;
                 let mut value_rules: Option<Vec<crate::api::rbac::v1::PolicyRule>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ClusterRole", ClusterRole::FIELDS))? {
                     match key {
-                        Field::Key_api_version => {
+                        Some(0) => {
                             let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_api_version != <Self::Value as crate::Resource>::API_VERSION {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<Self::Value as crate::Resource>::API_VERSION));
                             }
                         },
-                        Field::Key_kind => {
+                        Some(1) => {
                             let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_kind != <Self::Value as crate::Resource>::KIND {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<Self::Value as crate::Resource>::KIND));
                             }
                         },
-                        Field::Key_aggregation_rule => value_aggregation_rule = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_metadata => value_metadata = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_rules => value_rules = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(2) => value_aggregation_rule = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_metadata = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(4) => value_rules = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ClusterRole {
                     aggregation_rule: value_aggregation_rule,
-                    metadata: value_metadata.ok_or_else(|| crate::serde::de::Error::missing_field("metadata"))?,
-                    rules: value_rules.ok_or_else(|| crate::serde::de::Error::missing_field("rules"))?,
+                    metadata: value_metadata.ok_or_else(|| crate::serde2::missing_field("ClusterRole", "metadata", &["metadata", "rules"]))?,
+                    rules: value_rules.ok_or_else(|| crate::serde2::missing_field("ClusterRole", "rules", &["metadata", "rules"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ClusterRole {
+                    aggregation_rule: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    metadata: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    rules: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             <Self as crate::Resource>::KIND,
-            &[
-                "apiVersion",
-                "kind",
-                "aggregationRule",
-                "metadata",
-                "rules",
-            ],
+            ClusterRole::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ClusterRole> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<ClusterRole as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_aggregation_rule = false;
+                let mut has_metadata = false;
+                let mut has_rules = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ClusterRole", ClusterRole::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <ClusterRole as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<ClusterRole as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <ClusterRole as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<ClusterRole as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.aggregation_rule))?; has_aggregation_rule = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metadata))?; has_metadata = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.rules))?; has_rules = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_aggregation_rule {
+                    self.0.aggregation_rule = None;
+                }
+                if !has_metadata {
+                    return Err(crate::serde2::missing_field("ClusterRole", "metadata", &["metadata", "rules"]));
+                }
+                if !has_rules {
+                    return Err(crate::serde2::missing_field("ClusterRole", "rules", &["metadata", "rules"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.aggregation_rule))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metadata))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.rules))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            ClusterRole::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ClusterRole {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct(<Self as crate::Resource>::KIND, 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "aggregationRule", &self.aggregation_rule)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", &self.metadata)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rules", &self.rules)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             <Self as crate::Resource>::KIND,
             4 +
@@ -499,10 +599,102 @@ impl crate::serde::Serialize for ClusterRole {
     }
 }
 
+/// A builder of [`ClusterRole`]. Created with [`ClusterRole::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ClusterRoleBuilder {
+    aggregation_rule: Option<crate::api::rbac::v1::AggregationRule>,
+    metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>,
+    rules: Option<Vec<crate::api::rbac::v1::PolicyRule>>,
+}
+
+#[cfg(feature = "builder")]
+impl ClusterRole {
+    /// Starts building a `ClusterRole`. Its required fields must be set before [`ClusterRoleBuilder::build`] is called.
+    pub fn builder() -> ClusterRoleBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ClusterRoleBuilder {
+    /// Sets [`ClusterRole::aggregation_rule`].
+    pub fn aggregation_rule(mut self, value: impl Into<crate::api::rbac::v1::AggregationRule>) -> Self {
+        self.aggregation_rule = Some(value.into());
+        self
+    }
+
+    /// Sets [`ClusterRole::metadata`].
+    pub fn metadata(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        self.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`ClusterRole::rules`].
+    pub fn rules(mut self, value: impl Into<Vec<crate::api::rbac::v1::PolicyRule>>) -> Self {
+        self.rules = Some(value.into());
+        self
+    }
+
+    /// Builds the `ClusterRole`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ClusterRole, crate::MissingFieldError> {
+        Ok(ClusterRole {
+            aggregation_rule: self.aggregation_rule,
+            metadata: self.metadata.ok_or_else(|| crate::MissingFieldError::new("ClusterRole", "metadata"))?,
+            rules: self.rules.ok_or_else(|| crate::MissingFieldError::new("ClusterRole", "rules"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`ClusterRole`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ClusterRoleApplyConfiguration {
+    /// See [`ClusterRole::aggregation_rule`].
+    pub aggregation_rule: Option<crate::api::rbac::v1::AggregationRuleApplyConfiguration>,
+
+    /// See [`ClusterRole::metadata`].
+    pub metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMetaApplyConfiguration>,
+
+    /// See [`ClusterRole::rules`].
+    pub rules: Option<Vec<crate::api::rbac::v1::PolicyRuleApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ClusterRoleApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            <ClusterRole as crate::Resource>::KIND,
+            2 +
+            self.aggregation_rule.as_ref().map_or(0, |_| 1) +
+            self.metadata.as_ref().map_or(0, |_| 1) +
+            self.rules.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <ClusterRole as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <ClusterRole as crate::Resource>::KIND)?;
+        if let Some(value) = &self.aggregation_rule {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "aggregationRule", value)?;
+        }
+        if let Some(value) = &self.metadata {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", value)?;
+        }
+        if let Some(value) = &self.rules {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rules", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ClusterRole {
     fn schema_name() -> String {
-        "io.k8s.api.rbac.v1.ClusterRole".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::rbac::v1::ClusterRole".to_owned()
+        }
+        else {
+            "io.k8s.api.rbac.v1.ClusterRole".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -513,8 +705,9 @@ impl crate::schemars::JsonSchema for ClusterRole {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "aggregationRule".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::rbac::v1::AggregationRule>().into_object();
@@ -524,8 +717,8 @@ impl crate::schemars::JsonSchema for ClusterRole {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "apiVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -533,10 +726,11 @@ impl crate::schemars::JsonSchema for ClusterRole {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("rbac.authorization.k8s.io/v1".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "kind".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -544,10 +738,11 @@ impl crate::schemars::JsonSchema for ClusterRole {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("ClusterRole".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "metadata".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>().into_object();
@@ -557,8 +752,8 @@ impl crate::schemars::JsonSchema for ClusterRole {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "rules".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -572,9 +767,12 @@ impl crate::schemars::JsonSchema for ClusterRole {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
+                    "apiVersion",
+                    "kind",
                     "metadata",
                     "rules",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -584,3 +782,14 @@ impl crate::schemars::JsonSchema for ClusterRole {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ClusterRole {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ClusterRole {
+            aggregation_rule: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            metadata: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            rules: crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.rbac.v1.RoleRef
 
 /// RoleRef contains information that points to the role being used
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct RoleRef {
     /// APIGroup is the group for the resource being referenced
     pub api_group: String,
@@ -13,41 +13,52 @@ pub struct RoleRef {
     pub name: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for RoleRef {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_api_group,
-            Key_kind,
-            Key_name,
-            Other,
+impl RoleRef {
+    /// Constructs a `RoleRef` from its required fields. Its optional fields are unset.
+    pub fn new(api_group: impl Into<String>, kind: impl Into<String>, name: impl Into<String>) -> Self {
+        RoleRef {
+            api_group: api_group.into(),
+            kind: kind.into(),
+            name: name.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl RoleRef {
+    /// Returns this value with [`RoleRef::api_group`] set to the given value.
+    #[must_use]
+    pub fn with_api_group(mut self, value: impl Into<String>) -> Self {
+        self.api_group = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`RoleRef::kind`] set to the given value.
+    #[must_use]
+    pub fn with_kind(mut self, value: impl Into<String>) -> Self {
+        self.kind = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`RoleRef::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "apiGroup" => Field::Key_api_group,
-                            "kind" => Field::Key_kind,
-                            "name" => Field::Key_name,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for RoleRef {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl RoleRef {
+    const FIELDS: &'static [&'static str] = &[
+        "apiGroup",
+        "kind",
+        "name",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for RoleRef {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,37 +73,101 @@ impl<'de> crate::serde::Deserialize<'de> for RoleRef {
                 let mut value_kind: Option<String> = None;
                 let mut value_name: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("RoleRef", RoleRef::FIELDS))? {
                     match key {
-                        Field::Key_api_group => value_api_group = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_kind => value_kind = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_name => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_api_group = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_kind = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(RoleRef {
-                    api_group: value_api_group.ok_or_else(|| crate::serde::de::Error::missing_field("apiGroup"))?,
-                    kind: value_kind.ok_or_else(|| crate::serde::de::Error::missing_field("kind"))?,
-                    name: value_name.ok_or_else(|| crate::serde::de::Error::missing_field("name"))?,
+                    api_group: value_api_group.ok_or_else(|| crate::serde2::missing_field("RoleRef", "apiGroup", &["apiGroup", "kind", "name"]))?,
+                    kind: value_kind.ok_or_else(|| crate::serde2::missing_field("RoleRef", "kind", &["apiGroup", "kind", "name"]))?,
+                    name: value_name.ok_or_else(|| crate::serde2::missing_field("RoleRef", "name", &["apiGroup", "kind", "name"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(RoleRef {
+                    api_group: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    kind: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "RoleRef",
-            &[
-                "apiGroup",
-                "kind",
-                "name",
-            ],
+            RoleRef::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, RoleRef> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("RoleRef")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_api_group = false;
+                let mut has_kind = false;
+                let mut has_name = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("RoleRef", RoleRef::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.api_group))?; has_api_group = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.kind))?; has_kind = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.name))?; has_name = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_api_group {
+                    return Err(crate::serde2::missing_field("RoleRef", "apiGroup", &["apiGroup", "kind", "name"]));
+                }
+                if !has_kind {
+                    return Err(crate::serde2::missing_field("RoleRef", "kind", &["apiGroup", "kind", "name"]));
+                }
+                if !has_name {
+                    return Err(crate::serde2::missing_field("RoleRef", "name", &["apiGroup", "kind", "name"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.api_group))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.kind))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "RoleRef",
+            RoleRef::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for RoleRef {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("RoleRef", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiGroup", &self.api_group)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", &self.kind)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "RoleRef",
             3,
@@ -104,10 +179,99 @@ impl crate::serde::Serialize for RoleRef {
     }
 }
 
+/// A builder of [`RoleRef`]. Created with [`RoleRef::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct RoleRefBuilder {
+    api_group: Option<String>,
+    kind: Option<String>,
+    name: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl RoleRef {
+    /// Starts building a `RoleRef`. Its required fields must be set before [`RoleRefBuilder::build`] is called.
+    pub fn builder() -> RoleRefBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl RoleRefBuilder {
+    /// Sets [`RoleRef::api_group`].
+    pub fn api_group(mut self, value: impl Into<String>) -> Self {
+        self.api_group = Some(value.into());
+        self
+    }
+
+    /// Sets [`RoleRef::kind`].
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`RoleRef::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `RoleRef`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<RoleRef, crate::MissingFieldError> {
+        Ok(RoleRef {
+            api_group: self.api_group.ok_or_else(|| crate::MissingFieldError::new("RoleRef", "apiGroup"))?,
+            kind: self.kind.ok_or_else(|| crate::MissingFieldError::new("RoleRef", "kind"))?,
+            name: self.name.ok_or_else(|| crate::MissingFieldError::new("RoleRef", "name"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`RoleRef`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RoleRefApplyConfiguration {
+    /// See [`RoleRef::api_group`].
+    pub api_group: Option<String>,
+
+    /// See [`RoleRef::kind`].
+    pub kind: Option<String>,
+
+    /// See [`RoleRef::name`].
+    pub name: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for RoleRefApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "RoleRefApplyConfiguration",
+            self.api_group.as_ref().map_or(0, |_| 1) +
+            self.kind.as_ref().map_or(0, |_| 1) +
+            self.name.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.api_group {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiGroup", value)?;
+        }
+        if let Some(value) = &self.kind {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", value)?;
+        }
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for RoleRef {
     fn schema_name() -> String {
-        "io.k8s.api.rbac.v1.RoleRef".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::rbac::v1::RoleRef".to_owned()
+        }
+        else {
+            "io.k8s.api.rbac.v1.RoleRef".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -118,8 +282,9 @@ impl crate::schemars::JsonSchema for RoleRef {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "apiGroup".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -129,8 +294,8 @@ impl crate::schemars::JsonSchema for RoleRef {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "kind".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -140,8 +305,8 @@ impl crate::schemars::JsonSchema for RoleRef {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -151,8 +316,9 @@ impl crate::schemars::JsonSchema for RoleRef {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "apiGroup",
                     "kind",
@@ -164,3 +330,14 @@ impl crate::schemars::JsonSchema for RoleRef {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for RoleRef {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(RoleRef {
+            api_group: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            kind: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -1,4 +1,8 @@
 
 mod priority_class;
 pub use self::priority_class::PriorityClass;
+#[cfg(feature = "builder")]
+pub use self::priority_class::PriorityClassBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::priority_class::PriorityClassApplyConfiguration;
 #[cfg(feature = "api")] pub use self::priority_class::{ReadPriorityClassOptional, ReadPriorityClassResponse};
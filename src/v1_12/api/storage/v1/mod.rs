@@ -1,4 +1,8 @@
 
 mod storage_class;
 pub use self::storage_class::StorageClass;
+#[cfg(feature = "builder")]
+pub use self::storage_class::StorageClassBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::storage_class::StorageClassApplyConfiguration;
 #[cfg(feature = "api")] pub use self::storage_class::{ReadStorageClassOptional, ReadStorageClassResponse};
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.CustomResourceSubresourceScale
 
 /// CustomResourceSubresourceScale defines how to serve the scale subresource for CustomResources.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct CustomResourceSubresourceScale {
     /// LabelSelectorPath defines the JSON path inside of a CustomResource that corresponds to Scale.Status.Selector. Only JSON paths without the array notation are allowed. Must be a JSON Path under .status. Must be set to work with HPA. If there is no value under the given path in the CustomResource, the status label selector value in the /scale subresource will default to the empty string.
     pub label_selector_path: Option<String>,
@@ -13,41 +13,52 @@ pub struct CustomResourceSubresourceScale {
     pub status_replicas_path: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for CustomResourceSubresourceScale {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_label_selector_path,
-            Key_spec_replicas_path,
-            Key_status_replicas_path,
-            Other,
+impl CustomResourceSubresourceScale {
+    /// Constructs a `CustomResourceSubresourceScale` from its required fields. Its optional fields are unset.
+    pub fn new(spec_replicas_path: impl Into<String>, status_replicas_path: impl Into<String>) -> Self {
+        CustomResourceSubresourceScale {
+            label_selector_path: None,
+            spec_replicas_path: spec_replicas_path.into(),
+            status_replicas_path: status_replicas_path.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl CustomResourceSubresourceScale {
+    /// Returns this value with [`CustomResourceSubresourceScale::label_selector_path`] set to the given value.
+    #[must_use]
+    pub fn with_label_selector_path(mut self, value: impl Into<String>) -> Self {
+        self.label_selector_path = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`CustomResourceSubresourceScale::spec_replicas_path`] set to the given value.
+    #[must_use]
+    pub fn with_spec_replicas_path(mut self, value: impl Into<String>) -> Self {
+        self.spec_replicas_path = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`CustomResourceSubresourceScale::status_replicas_path`] set to the given value.
+    #[must_use]
+    pub fn with_status_replicas_path(mut self, value: impl Into<String>) -> Self {
+        self.status_replicas_path = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "labelSelectorPath" => Field::Key_label_selector_path,
-                            "specReplicasPath" => Field::Key_spec_replicas_path,
-                            "statusReplicasPath" => Field::Key_status_replicas_path,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for CustomResourceSubresourceScale {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl CustomResourceSubresourceScale {
+    const FIELDS: &'static [&'static str] = &[
+        "labelSelectorPath",
+        "specReplicasPath",
+        "statusReplicasPath",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for CustomResourceSubresourceScale {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,37 +73,101 @@ impl<'de> crate::serde::Deserialize<'de> for CustomResourceSubresourceScale {
                 let mut value_spec_replicas_path: Option<String> = None;
                 let mut value_status_replicas_path: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CustomResourceSubresourceScale", CustomResourceSubresourceScale::FIELDS))? {
                     match key {
-                        Field::Key_label_selector_path => value_label_selector_path = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_spec_replicas_path => value_spec_replicas_path = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_status_replicas_path => value_status_replicas_path = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_label_selector_path = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_spec_replicas_path = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_status_replicas_path = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(CustomResourceSubresourceScale {
                     label_selector_path: value_label_selector_path,
-                    spec_replicas_path: value_spec_replicas_path.ok_or_else(|| crate::serde::de::Error::missing_field("specReplicasPath"))?,
-                    status_replicas_path: value_status_replicas_path.ok_or_else(|| crate::serde::de::Error::missing_field("statusReplicasPath"))?,
+                    spec_replicas_path: value_spec_replicas_path.ok_or_else(|| crate::serde2::missing_field("CustomResourceSubresourceScale", "specReplicasPath", &["specReplicasPath", "statusReplicasPath"]))?,
+                    status_replicas_path: value_status_replicas_path.ok_or_else(|| crate::serde2::missing_field("CustomResourceSubresourceScale", "statusReplicasPath", &["specReplicasPath", "statusReplicasPath"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(CustomResourceSubresourceScale {
+                    label_selector_path: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    spec_replicas_path: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    status_replicas_path: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "CustomResourceSubresourceScale",
-            &[
-                "labelSelectorPath",
-                "specReplicasPath",
-                "statusReplicasPath",
-            ],
+            CustomResourceSubresourceScale::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, CustomResourceSubresourceScale> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("CustomResourceSubresourceScale")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_label_selector_path = false;
+                let mut has_spec_replicas_path = false;
+                let mut has_status_replicas_path = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CustomResourceSubresourceScale", CustomResourceSubresourceScale::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.label_selector_path))?; has_label_selector_path = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.spec_replicas_path))?; has_spec_replicas_path = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.status_replicas_path))?; has_status_replicas_path = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_label_selector_path {
+                    self.0.label_selector_path = None;
+                }
+                if !has_spec_replicas_path {
+                    return Err(crate::serde2::missing_field("CustomResourceSubresourceScale", "specReplicasPath", &["specReplicasPath", "statusReplicasPath"]));
+                }
+                if !has_status_replicas_path {
+                    return Err(crate::serde2::missing_field("CustomResourceSubresourceScale", "statusReplicasPath", &["specReplicasPath", "statusReplicasPath"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.label_selector_path))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.spec_replicas_path))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.status_replicas_path))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "CustomResourceSubresourceScale",
+            CustomResourceSubresourceScale::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for CustomResourceSubresourceScale {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("CustomResourceSubresourceScale", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "labelSelectorPath", &self.label_selector_path)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "specReplicasPath", &self.spec_replicas_path)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "statusReplicasPath", &self.status_replicas_path)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "CustomResourceSubresourceScale",
             2 +
@@ -107,10 +182,99 @@ impl crate::serde::Serialize for CustomResourceSubresourceScale {
     }
 }
 
+/// A builder of [`CustomResourceSubresourceScale`]. Created with [`CustomResourceSubresourceScale::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct CustomResourceSubresourceScaleBuilder {
+    label_selector_path: Option<String>,
+    spec_replicas_path: Option<String>,
+    status_replicas_path: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl CustomResourceSubresourceScale {
+    /// Starts building a `CustomResourceSubresourceScale`. Its required fields must be set before [`CustomResourceSubresourceScaleBuilder::build`] is called.
+    pub fn builder() -> CustomResourceSubresourceScaleBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl CustomResourceSubresourceScaleBuilder {
+    /// Sets [`CustomResourceSubresourceScale::label_selector_path`].
+    pub fn label_selector_path(mut self, value: impl Into<String>) -> Self {
+        self.label_selector_path = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceSubresourceScale::spec_replicas_path`].
+    pub fn spec_replicas_path(mut self, value: impl Into<String>) -> Self {
+        self.spec_replicas_path = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceSubresourceScale::status_replicas_path`].
+    pub fn status_replicas_path(mut self, value: impl Into<String>) -> Self {
+        self.status_replicas_path = Some(value.into());
+        self
+    }
+
+    /// Builds the `CustomResourceSubresourceScale`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<CustomResourceSubresourceScale, crate::MissingFieldError> {
+        Ok(CustomResourceSubresourceScale {
+            label_selector_path: self.label_selector_path,
+            spec_replicas_path: self.spec_replicas_path.ok_or_else(|| crate::MissingFieldError::new("CustomResourceSubresourceScale", "specReplicasPath"))?,
+            status_replicas_path: self.status_replicas_path.ok_or_else(|| crate::MissingFieldError::new("CustomResourceSubresourceScale", "statusReplicasPath"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`CustomResourceSubresourceScale`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CustomResourceSubresourceScaleApplyConfiguration {
+    /// See [`CustomResourceSubresourceScale::label_selector_path`].
+    pub label_selector_path: Option<String>,
+
+    /// See [`CustomResourceSubresourceScale::spec_replicas_path`].
+    pub spec_replicas_path: Option<String>,
+
+    /// See [`CustomResourceSubresourceScale::status_replicas_path`].
+    pub status_replicas_path: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for CustomResourceSubresourceScaleApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "CustomResourceSubresourceScaleApplyConfiguration",
+            self.label_selector_path.as_ref().map_or(0, |_| 1) +
+            self.spec_replicas_path.as_ref().map_or(0, |_| 1) +
+            self.status_replicas_path.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.label_selector_path {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "labelSelectorPath", value)?;
+        }
+        if let Some(value) = &self.spec_replicas_path {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "specReplicasPath", value)?;
+        }
+        if let Some(value) = &self.status_replicas_path {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "statusReplicasPath", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CustomResourceSubresourceScale {
     fn schema_name() -> String {
-        "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.CustomResourceSubresourceScale".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceSubresourceScale".to_owned()
+        }
+        else {
+            "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.CustomResourceSubresourceScale".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -121,8 +285,9 @@ impl crate::schemars::JsonSchema for CustomResourceSubresourceScale {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "labelSelectorPath".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -132,8 +297,8 @@ impl crate::schemars::JsonSchema for CustomResourceSubresourceScale {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "specReplicasPath".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -143,8 +308,8 @@ impl crate::schemars::JsonSchema for CustomResourceSubresourceScale {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "statusReplicasPath".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -154,8 +319,9 @@ impl crate::schemars::JsonSchema for CustomResourceSubresourceScale {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "specReplicasPath",
                     "statusReplicasPath",
@@ -166,3 +332,14 @@ impl crate::schemars::JsonSchema for CustomResourceSubresourceScale {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for CustomResourceSubresourceScale {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(CustomResourceSubresourceScale {
+            label_selector_path: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            spec_replicas_path: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            status_replicas_path: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -76,107 +76,305 @@ pub struct JSONSchemaProps {
     pub unique_items: Option<bool>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for JSONSchemaProps {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_ref_path,
-            Key_schema,
-            Key_additional_items,
-            Key_additional_properties,
-            Key_all_of,
-            Key_any_of,
-            Key_default,
-            Key_definitions,
-            Key_dependencies,
-            Key_description,
-            Key_enum_,
-            Key_example,
-            Key_exclusive_maximum,
-            Key_exclusive_minimum,
-            Key_external_docs,
-            Key_format,
-            Key_id,
-            Key_items,
-            Key_max_items,
-            Key_max_length,
-            Key_max_properties,
-            Key_maximum,
-            Key_min_items,
-            Key_min_length,
-            Key_min_properties,
-            Key_minimum,
-            Key_multiple_of,
-            Key_not,
-            Key_one_of,
-            Key_pattern,
-            Key_pattern_properties,
-            Key_properties,
-            Key_required,
-            Key_title,
-            Key_type_,
-            Key_unique_items,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl JSONSchemaProps {
+    /// Returns this value with [`JSONSchemaProps::ref_path`] set to the given value.
+    #[must_use]
+    pub fn with_ref_path(mut self, value: impl Into<String>) -> Self {
+        self.ref_path = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "$ref" => Field::Key_ref_path,
-                            "$schema" => Field::Key_schema,
-                            "additionalItems" => Field::Key_additional_items,
-                            "additionalProperties" => Field::Key_additional_properties,
-                            "allOf" => Field::Key_all_of,
-                            "anyOf" => Field::Key_any_of,
-                            "default" => Field::Key_default,
-                            "definitions" => Field::Key_definitions,
-                            "dependencies" => Field::Key_dependencies,
-                            "description" => Field::Key_description,
-                            "enum" => Field::Key_enum_,
-                            "example" => Field::Key_example,
-                            "exclusiveMaximum" => Field::Key_exclusive_maximum,
-                            "exclusiveMinimum" => Field::Key_exclusive_minimum,
-                            "externalDocs" => Field::Key_external_docs,
-                            "format" => Field::Key_format,
-                            "id" => Field::Key_id,
-                            "items" => Field::Key_items,
-                            "maxItems" => Field::Key_max_items,
-                            "maxLength" => Field::Key_max_length,
-                            "maxProperties" => Field::Key_max_properties,
-                            "maximum" => Field::Key_maximum,
-                            "minItems" => Field::Key_min_items,
-                            "minLength" => Field::Key_min_length,
-                            "minProperties" => Field::Key_min_properties,
-                            "minimum" => Field::Key_minimum,
-                            "multipleOf" => Field::Key_multiple_of,
-                            "not" => Field::Key_not,
-                            "oneOf" => Field::Key_one_of,
-                            "pattern" => Field::Key_pattern,
-                            "patternProperties" => Field::Key_pattern_properties,
-                            "properties" => Field::Key_properties,
-                            "required" => Field::Key_required,
-                            "title" => Field::Key_title,
-                            "type" => Field::Key_type_,
-                            "uniqueItems" => Field::Key_unique_items,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`JSONSchemaProps::schema`] set to the given value.
+    #[must_use]
+    pub fn with_schema(mut self, value: impl Into<String>) -> Self {
+        self.schema = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`JSONSchemaProps::additional_items`] set to the given value.
+    #[must_use]
+    pub fn with_additional_items(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsOrBool>) -> Self {
+        self.additional_items = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::additional_properties`] set to the given value.
+    #[must_use]
+    pub fn with_additional_properties(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsOrBool>) -> Self {
+        self.additional_properties = Some(value.into());
+        self
+    }
 
+    /// Returns this value with [`JSONSchemaProps::all_of`] set to the given value.
+    #[must_use]
+    pub fn with_all_of(mut self, value: impl Into<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps>>) -> Self {
+        self.all_of = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::any_of`] set to the given value.
+    #[must_use]
+    pub fn with_any_of(mut self, value: impl Into<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps>>) -> Self {
+        self.any_of = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::default`] set to the given value.
+    #[must_use]
+    pub fn with_default(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSON>) -> Self {
+        self.default = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::definitions`] set to the given value.
+    #[must_use]
+    pub fn with_definitions(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps>>) -> Self {
+        self.definitions = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::dependencies`] set to the given value.
+    #[must_use]
+    pub fn with_dependencies(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsOrStringArray>>) -> Self {
+        self.dependencies = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::description`] set to the given value.
+    #[must_use]
+    pub fn with_description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::enum_`] set to the given value.
+    #[must_use]
+    pub fn with_enum(mut self, value: impl Into<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSON>>) -> Self {
+        self.enum_ = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::example`] set to the given value.
+    #[must_use]
+    pub fn with_example(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSON>) -> Self {
+        self.example = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::exclusive_maximum`] set to the given value.
+    #[must_use]
+    pub fn with_exclusive_maximum(mut self, value: impl Into<bool>) -> Self {
+        self.exclusive_maximum = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::exclusive_minimum`] set to the given value.
+    #[must_use]
+    pub fn with_exclusive_minimum(mut self, value: impl Into<bool>) -> Self {
+        self.exclusive_minimum = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::external_docs`] set to the given value.
+    #[must_use]
+    pub fn with_external_docs(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::ExternalDocumentation>) -> Self {
+        self.external_docs = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::format`] set to the given value.
+    #[must_use]
+    pub fn with_format(mut self, value: impl Into<String>) -> Self {
+        self.format = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::id`] set to the given value.
+    #[must_use]
+    pub fn with_id(mut self, value: impl Into<String>) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::items`] set to the given value.
+    #[must_use]
+    pub fn with_items(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsOrArray>) -> Self {
+        self.items = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::max_items`] set to the given value.
+    #[must_use]
+    pub fn with_max_items(mut self, value: impl Into<i64>) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::max_length`] set to the given value.
+    #[must_use]
+    pub fn with_max_length(mut self, value: impl Into<i64>) -> Self {
+        self.max_length = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::max_properties`] set to the given value.
+    #[must_use]
+    pub fn with_max_properties(mut self, value: impl Into<i64>) -> Self {
+        self.max_properties = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::maximum`] set to the given value.
+    #[must_use]
+    pub fn with_maximum(mut self, value: impl Into<f64>) -> Self {
+        self.maximum = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::min_items`] set to the given value.
+    #[must_use]
+    pub fn with_min_items(mut self, value: impl Into<i64>) -> Self {
+        self.min_items = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::min_length`] set to the given value.
+    #[must_use]
+    pub fn with_min_length(mut self, value: impl Into<i64>) -> Self {
+        self.min_length = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::min_properties`] set to the given value.
+    #[must_use]
+    pub fn with_min_properties(mut self, value: impl Into<i64>) -> Self {
+        self.min_properties = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::minimum`] set to the given value.
+    #[must_use]
+    pub fn with_minimum(mut self, value: impl Into<f64>) -> Self {
+        self.minimum = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::multiple_of`] set to the given value.
+    #[must_use]
+    pub fn with_multiple_of(mut self, value: impl Into<f64>) -> Self {
+        self.multiple_of = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::not`] set to the given value.
+    #[must_use]
+    pub fn with_not(mut self, value: impl Into<Box<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps>>) -> Self {
+        self.not = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::one_of`] set to the given value.
+    #[must_use]
+    pub fn with_one_of(mut self, value: impl Into<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps>>) -> Self {
+        self.one_of = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::pattern`] set to the given value.
+    #[must_use]
+    pub fn with_pattern(mut self, value: impl Into<String>) -> Self {
+        self.pattern = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::pattern_properties`] set to the given value.
+    #[must_use]
+    pub fn with_pattern_properties(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps>>) -> Self {
+        self.pattern_properties = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::properties`] set to the given value.
+    #[must_use]
+    pub fn with_properties(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps>>) -> Self {
+        self.properties = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::required`] set to the given value.
+    #[must_use]
+    pub fn with_required(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.required = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::title`] set to the given value.
+    #[must_use]
+    pub fn with_title(mut self, value: impl Into<String>) -> Self {
+        self.title = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::type_`] set to the given value.
+    #[must_use]
+    pub fn with_type(mut self, value: impl Into<String>) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`JSONSchemaProps::unique_items`] set to the given value.
+    #[must_use]
+    pub fn with_unique_items(mut self, value: impl Into<bool>) -> Self {
+        self.unique_items = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for JSONSchemaProps {}
+
+impl JSONSchemaProps {
+    const FIELDS: &'static [&'static str] = &[
+        "$ref",
+        "$schema",
+        "additionalItems",
+        "additionalProperties",
+        "allOf",
+        "anyOf",
+        "default",
+        "definitions",
+        "dependencies",
+        "description",
+        "enum",
+        "example",
+        "exclusiveMaximum",
+        "exclusiveMinimum",
+        "externalDocs",
+        "format",
+        "id",
+        "items",
+        "maxItems",
+        "maxLength",
+        "maxProperties",
+        "maximum",
+        "minItems",
+        "minLength",
+        "minProperties",
+        "minimum",
+        "multipleOf",
+        "not",
+        "oneOf",
+        "pattern",
+        "patternProperties",
+        "properties",
+        "required",
+        "title",
+        "type",
+        "uniqueItems",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for JSONSchemaProps {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -224,45 +422,45 @@ impl<'de> crate::serde::Deserialize<'de> for JSONSchemaProps {
                 let mut value_type_: Option<String> = None;
                 let mut value_unique_items: Option<bool> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("JSONSchemaProps", JSONSchemaProps::FIELDS))? {
                     match key {
-                        Field::Key_ref_path => value_ref_path = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_schema => value_schema = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_additional_items => value_additional_items = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_additional_properties => value_additional_properties = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_all_of => value_all_of = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_any_of => value_any_of = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_default => value_default = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_definitions => value_definitions = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_dependencies => value_dependencies = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_description => value_description = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_enum_ => value_enum_ = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_example => value_example = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_exclusive_maximum => value_exclusive_maximum = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_exclusive_minimum => value_exclusive_minimum = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_external_docs => value_external_docs = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_format => value_format = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_id => value_id = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_items => value_items = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_max_items => value_max_items = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_max_length => value_max_length = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_max_properties => value_max_properties = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_maximum => value_maximum = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_min_items => value_min_items = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_min_length => value_min_length = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_min_properties => value_min_properties = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_minimum => value_minimum = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_multiple_of => value_multiple_of = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_not => value_not = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_one_of => value_one_of = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_pattern => value_pattern = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_pattern_properties => value_pattern_properties = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_properties => value_properties = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_required => value_required = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_title => value_title = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_type_ => value_type_ = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_unique_items => value_unique_items = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_ref_path = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_schema = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_additional_items = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_additional_properties = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_all_of = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_any_of = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_default = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(7) => value_definitions = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(8) => value_dependencies = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(9) => value_description = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(10) => value_enum_ = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(11) => value_example = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(12) => value_exclusive_maximum = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(13) => value_exclusive_minimum = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(14) => value_external_docs = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(15) => value_format = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(16) => value_id = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(17) => value_items = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(18) => value_max_items = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(19) => value_max_length = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(20) => value_max_properties = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(21) => value_maximum = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(22) => value_min_items = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(23) => value_min_length = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(24) => value_min_properties = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(25) => value_minimum = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(26) => value_multiple_of = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(27) => value_not = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(28) => value_one_of = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(29) => value_pattern = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(30) => value_pattern_properties = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(31) => value_properties = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(32) => value_required = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(33) => value_title = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(34) => value_type_ = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(35) => value_unique_items = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -305,55 +503,350 @@ impl<'de> crate::serde::Deserialize<'de> for JSONSchemaProps {
                     unique_items: value_unique_items,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(JSONSchemaProps {
+                    ref_path: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    schema: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    additional_items: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    additional_properties: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    all_of: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    any_of: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    default: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    definitions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                    dependencies: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?,
+                    description: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?,
+                    enum_: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?,
+                    example: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(11, &self))?,
+                    exclusive_maximum: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(12, &self))?,
+                    exclusive_minimum: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(13, &self))?,
+                    external_docs: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(14, &self))?,
+                    format: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(15, &self))?,
+                    id: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(16, &self))?,
+                    items: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(17, &self))?,
+                    max_items: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(18, &self))?,
+                    max_length: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(19, &self))?,
+                    max_properties: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(20, &self))?,
+                    maximum: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(21, &self))?,
+                    min_items: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(22, &self))?,
+                    min_length: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(23, &self))?,
+                    min_properties: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(24, &self))?,
+                    minimum: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(25, &self))?,
+                    multiple_of: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(26, &self))?,
+                    not: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(27, &self))?,
+                    one_of: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(28, &self))?,
+                    pattern: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(29, &self))?,
+                    pattern_properties: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(30, &self))?,
+                    properties: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(31, &self))?,
+                    required: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(32, &self))?,
+                    title: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(33, &self))?,
+                    type_: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(34, &self))?,
+                    unique_items: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(35, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "JSONSchemaProps",
-            &[
-                "$ref",
-                "$schema",
-                "additionalItems",
-                "additionalProperties",
-                "allOf",
-                "anyOf",
-                "default",
-                "definitions",
-                "dependencies",
-                "description",
-                "enum",
-                "example",
-                "exclusiveMaximum",
-                "exclusiveMinimum",
-                "externalDocs",
-                "format",
-                "id",
-                "items",
-                "maxItems",
-                "maxLength",
-                "maxProperties",
-                "maximum",
-                "minItems",
-                "minLength",
-                "minProperties",
-                "minimum",
-                "multipleOf",
-                "not",
-                "oneOf",
-                "pattern",
-                "patternProperties",
-                "properties",
-                "required",
-                "title",
-                "type",
-                "uniqueItems",
-            ],
+            JSONSchemaProps::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, JSONSchemaProps> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("JSONSchemaProps")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_ref_path = false;
+                let mut has_schema = false;
+                let mut has_additional_items = false;
+                let mut has_additional_properties = false;
+                let mut has_all_of = false;
+                let mut has_any_of = false;
+                let mut has_default = false;
+                let mut has_definitions = false;
+                let mut has_dependencies = false;
+                let mut has_description = false;
+                let mut has_enum_ = false;
+                let mut has_example = false;
+                let mut has_exclusive_maximum = false;
+                let mut has_exclusive_minimum = false;
+                let mut has_external_docs = false;
+                let mut has_format = false;
+                let mut has_id = false;
+                let mut has_items = false;
+                let mut has_max_items = false;
+                let mut has_max_length = false;
+                let mut has_max_properties = false;
+                let mut has_maximum = false;
+                let mut has_min_items = false;
+                let mut has_min_length = false;
+                let mut has_min_properties = false;
+                let mut has_minimum = false;
+                let mut has_multiple_of = false;
+                let mut has_not = false;
+                let mut has_one_of = false;
+                let mut has_pattern = false;
+                let mut has_pattern_properties = false;
+                let mut has_properties = false;
+                let mut has_required = false;
+                let mut has_title = false;
+                let mut has_type_ = false;
+                let mut has_unique_items = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("JSONSchemaProps", JSONSchemaProps::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.ref_path))?; has_ref_path = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.schema))?; has_schema = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.additional_items))?; has_additional_items = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.additional_properties))?; has_additional_properties = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.all_of))?; has_all_of = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.any_of))?; has_any_of = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.default))?; has_default = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.definitions))?; has_definitions = true; },
+                        Some(8) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.dependencies))?; has_dependencies = true; },
+                        Some(9) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.description))?; has_description = true; },
+                        Some(10) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.enum_))?; has_enum_ = true; },
+                        Some(11) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.example))?; has_example = true; },
+                        Some(12) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.exclusive_maximum))?; has_exclusive_maximum = true; },
+                        Some(13) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.exclusive_minimum))?; has_exclusive_minimum = true; },
+                        Some(14) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.external_docs))?; has_external_docs = true; },
+                        Some(15) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.format))?; has_format = true; },
+                        Some(16) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.id))?; has_id = true; },
+                        Some(17) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.items))?; has_items = true; },
+                        Some(18) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.max_items))?; has_max_items = true; },
+                        Some(19) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.max_length))?; has_max_length = true; },
+                        Some(20) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.max_properties))?; has_max_properties = true; },
+                        Some(21) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.maximum))?; has_maximum = true; },
+                        Some(22) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.min_items))?; has_min_items = true; },
+                        Some(23) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.min_length))?; has_min_length = true; },
+                        Some(24) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.min_properties))?; has_min_properties = true; },
+                        Some(25) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.minimum))?; has_minimum = true; },
+                        Some(26) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.multiple_of))?; has_multiple_of = true; },
+                        Some(27) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.not))?; has_not = true; },
+                        Some(28) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.one_of))?; has_one_of = true; },
+                        Some(29) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.pattern))?; has_pattern = true; },
+                        Some(30) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.pattern_properties))?; has_pattern_properties = true; },
+                        Some(31) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.properties))?; has_properties = true; },
+                        Some(32) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.required))?; has_required = true; },
+                        Some(33) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.title))?; has_title = true; },
+                        Some(34) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.type_))?; has_type_ = true; },
+                        Some(35) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.unique_items))?; has_unique_items = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_ref_path {
+                    self.0.ref_path = None;
+                }
+                if !has_schema {
+                    self.0.schema = None;
+                }
+                if !has_additional_items {
+                    self.0.additional_items = None;
+                }
+                if !has_additional_properties {
+                    self.0.additional_properties = None;
+                }
+                if !has_all_of {
+                    self.0.all_of = None;
+                }
+                if !has_any_of {
+                    self.0.any_of = None;
+                }
+                if !has_default {
+                    self.0.default = None;
+                }
+                if !has_definitions {
+                    self.0.definitions = None;
+                }
+                if !has_dependencies {
+                    self.0.dependencies = None;
+                }
+                if !has_description {
+                    self.0.description = None;
+                }
+                if !has_enum_ {
+                    self.0.enum_ = None;
+                }
+                if !has_example {
+                    self.0.example = None;
+                }
+                if !has_exclusive_maximum {
+                    self.0.exclusive_maximum = None;
+                }
+                if !has_exclusive_minimum {
+                    self.0.exclusive_minimum = None;
+                }
+                if !has_external_docs {
+                    self.0.external_docs = None;
+                }
+                if !has_format {
+                    self.0.format = None;
+                }
+                if !has_id {
+                    self.0.id = None;
+                }
+                if !has_items {
+                    self.0.items = None;
+                }
+                if !has_max_items {
+                    self.0.max_items = None;
+                }
+                if !has_max_length {
+                    self.0.max_length = None;
+                }
+                if !has_max_properties {
+                    self.0.max_properties = None;
+                }
+                if !has_maximum {
+                    self.0.maximum = None;
+                }
+                if !has_min_items {
+                    self.0.min_items = None;
+                }
+                if !has_min_length {
+                    self.0.min_length = None;
+                }
+                if !has_min_properties {
+                    self.0.min_properties = None;
+                }
+                if !has_minimum {
+                    self.0.minimum = None;
+                }
+                if !has_multiple_of {
+                    self.0.multiple_of = None;
+                }
+                if !has_not {
+                    self.0.not = None;
+                }
+                if !has_one_of {
+                    self.0.one_of = None;
+                }
+                if !has_pattern {
+                    self.0.pattern = None;
+                }
+                if !has_pattern_properties {
+                    self.0.pattern_properties = None;
+                }
+                if !has_properties {
+                    self.0.properties = None;
+                }
+                if !has_required {
+                    self.0.required = None;
+                }
+                if !has_title {
+                    self.0.title = None;
+                }
+                if !has_type_ {
+                    self.0.type_ = None;
+                }
+                if !has_unique_items {
+                    self.0.unique_items = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.ref_path))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.schema))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.additional_items))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.additional_properties))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.all_of))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.any_of))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.default))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.definitions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.dependencies))?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.description))?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.enum_))?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.example))?.ok_or_else(|| crate::serde::de::Error::invalid_length(11, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.exclusive_maximum))?.ok_or_else(|| crate::serde::de::Error::invalid_length(12, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.exclusive_minimum))?.ok_or_else(|| crate::serde::de::Error::invalid_length(13, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.external_docs))?.ok_or_else(|| crate::serde::de::Error::invalid_length(14, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.format))?.ok_or_else(|| crate::serde::de::Error::invalid_length(15, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.id))?.ok_or_else(|| crate::serde::de::Error::invalid_length(16, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.items))?.ok_or_else(|| crate::serde::de::Error::invalid_length(17, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.max_items))?.ok_or_else(|| crate::serde::de::Error::invalid_length(18, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.max_length))?.ok_or_else(|| crate::serde::de::Error::invalid_length(19, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.max_properties))?.ok_or_else(|| crate::serde::de::Error::invalid_length(20, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.maximum))?.ok_or_else(|| crate::serde::de::Error::invalid_length(21, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.min_items))?.ok_or_else(|| crate::serde::de::Error::invalid_length(22, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.min_length))?.ok_or_else(|| crate::serde::de::Error::invalid_length(23, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.min_properties))?.ok_or_else(|| crate::serde::de::Error::invalid_length(24, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.minimum))?.ok_or_else(|| crate::serde::de::Error::invalid_length(25, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.multiple_of))?.ok_or_else(|| crate::serde::de::Error::invalid_length(26, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.not))?.ok_or_else(|| crate::serde::de::Error::invalid_length(27, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.one_of))?.ok_or_else(|| crate::serde::de::Error::invalid_length(28, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.pattern))?.ok_or_else(|| crate::serde::de::Error::invalid_length(29, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.pattern_properties))?.ok_or_else(|| crate::serde::de::Error::invalid_length(30, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.properties))?.ok_or_else(|| crate::serde::de::Error::invalid_length(31, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.required))?.ok_or_else(|| crate::serde::de::Error::invalid_length(32, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.title))?.ok_or_else(|| crate::serde::de::Error::invalid_length(33, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.type_))?.ok_or_else(|| crate::serde::de::Error::invalid_length(34, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.unique_items))?.ok_or_else(|| crate::serde::de::Error::invalid_length(35, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "JSONSchemaProps",
+            JSONSchemaProps::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for JSONSchemaProps {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("JSONSchemaProps", 36)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "$ref", &self.ref_path)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "$schema", &self.schema)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "additionalItems", &self.additional_items)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "additionalProperties", &self.additional_properties)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "allOf", &self.all_of)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "anyOf", &self.any_of)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "default", &self.default)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "definitions", &self.definitions)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "dependencies", &self.dependencies)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "description", &self.description)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enum", &self.enum_)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "example", &self.example)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "exclusiveMaximum", &self.exclusive_maximum)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "exclusiveMinimum", &self.exclusive_minimum)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "externalDocs", &self.external_docs)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "format", &self.format)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "id", &self.id)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "items", &self.items)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maxItems", &self.max_items)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maxLength", &self.max_length)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maxProperties", &self.max_properties)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maximum", &self.maximum)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "minItems", &self.min_items)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "minLength", &self.min_length)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "minProperties", &self.min_properties)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "minimum", &self.minimum)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "multipleOf", &self.multiple_of)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "not", &self.not)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "oneOf", &self.one_of)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "pattern", &self.pattern)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "patternProperties", &self.pattern_properties)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "properties", &self.properties)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "required", &self.required)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "title", &self.title)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "type", &self.type_)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "uniqueItems", &self.unique_items)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "JSONSchemaProps",
             self.ref_path.as_ref().map_or(0, |_| 1) +
@@ -505,10 +998,594 @@ impl crate::serde::Serialize for JSONSchemaProps {
     }
 }
 
+/// A builder of [`JSONSchemaProps`]. Created with [`JSONSchemaProps::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct JSONSchemaPropsBuilder {
+    ref_path: Option<String>,
+    schema: Option<String>,
+    additional_items: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsOrBool>,
+    additional_properties: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsOrBool>,
+    all_of: Option<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps>>,
+    any_of: Option<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps>>,
+    default: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSON>,
+    definitions: Option<std::collections::BTreeMap<String, crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps>>,
+    dependencies: Option<std::collections::BTreeMap<String, crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsOrStringArray>>,
+    description: Option<String>,
+    enum_: Option<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSON>>,
+    example: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSON>,
+    exclusive_maximum: Option<bool>,
+    exclusive_minimum: Option<bool>,
+    external_docs: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::ExternalDocumentation>,
+    format: Option<String>,
+    id: Option<String>,
+    items: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsOrArray>,
+    max_items: Option<i64>,
+    max_length: Option<i64>,
+    max_properties: Option<i64>,
+    maximum: Option<f64>,
+    min_items: Option<i64>,
+    min_length: Option<i64>,
+    min_properties: Option<i64>,
+    minimum: Option<f64>,
+    multiple_of: Option<f64>,
+    not: Option<Box<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps>>,
+    one_of: Option<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps>>,
+    pattern: Option<String>,
+    pattern_properties: Option<std::collections::BTreeMap<String, crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps>>,
+    properties: Option<std::collections::BTreeMap<String, crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps>>,
+    required: Option<Vec<String>>,
+    title: Option<String>,
+    type_: Option<String>,
+    unique_items: Option<bool>,
+}
+
+#[cfg(feature = "builder")]
+impl JSONSchemaProps {
+    /// Starts building a `JSONSchemaProps`. Its required fields must be set before [`JSONSchemaPropsBuilder::build`] is called.
+    pub fn builder() -> JSONSchemaPropsBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl JSONSchemaPropsBuilder {
+    /// Sets [`JSONSchemaProps::ref_path`].
+    pub fn ref_path(mut self, value: impl Into<String>) -> Self {
+        self.ref_path = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::schema`].
+    pub fn schema(mut self, value: impl Into<String>) -> Self {
+        self.schema = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::additional_items`].
+    pub fn additional_items(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsOrBool>) -> Self {
+        self.additional_items = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::additional_properties`].
+    pub fn additional_properties(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsOrBool>) -> Self {
+        self.additional_properties = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::all_of`].
+    pub fn all_of(mut self, value: impl Into<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps>>) -> Self {
+        self.all_of = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::any_of`].
+    pub fn any_of(mut self, value: impl Into<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps>>) -> Self {
+        self.any_of = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::default`].
+    pub fn default(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSON>) -> Self {
+        self.default = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::definitions`].
+    pub fn definitions(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps>>) -> Self {
+        self.definitions = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::dependencies`].
+    pub fn dependencies(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsOrStringArray>>) -> Self {
+        self.dependencies = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::enum_`].
+    pub fn enum_(mut self, value: impl Into<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSON>>) -> Self {
+        self.enum_ = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::example`].
+    pub fn example(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSON>) -> Self {
+        self.example = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::exclusive_maximum`].
+    pub fn exclusive_maximum(mut self, value: impl Into<bool>) -> Self {
+        self.exclusive_maximum = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::exclusive_minimum`].
+    pub fn exclusive_minimum(mut self, value: impl Into<bool>) -> Self {
+        self.exclusive_minimum = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::external_docs`].
+    pub fn external_docs(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::ExternalDocumentation>) -> Self {
+        self.external_docs = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::format`].
+    pub fn format(mut self, value: impl Into<String>) -> Self {
+        self.format = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::id`].
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::items`].
+    pub fn items(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsOrArray>) -> Self {
+        self.items = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::max_items`].
+    pub fn max_items(mut self, value: impl Into<i64>) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::max_length`].
+    pub fn max_length(mut self, value: impl Into<i64>) -> Self {
+        self.max_length = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::max_properties`].
+    pub fn max_properties(mut self, value: impl Into<i64>) -> Self {
+        self.max_properties = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::maximum`].
+    pub fn maximum(mut self, value: impl Into<f64>) -> Self {
+        self.maximum = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::min_items`].
+    pub fn min_items(mut self, value: impl Into<i64>) -> Self {
+        self.min_items = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::min_length`].
+    pub fn min_length(mut self, value: impl Into<i64>) -> Self {
+        self.min_length = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::min_properties`].
+    pub fn min_properties(mut self, value: impl Into<i64>) -> Self {
+        self.min_properties = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::minimum`].
+    pub fn minimum(mut self, value: impl Into<f64>) -> Self {
+        self.minimum = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::multiple_of`].
+    pub fn multiple_of(mut self, value: impl Into<f64>) -> Self {
+        self.multiple_of = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::not`].
+    pub fn not(mut self, value: impl Into<Box<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps>>) -> Self {
+        self.not = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::one_of`].
+    pub fn one_of(mut self, value: impl Into<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps>>) -> Self {
+        self.one_of = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::pattern`].
+    pub fn pattern(mut self, value: impl Into<String>) -> Self {
+        self.pattern = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::pattern_properties`].
+    pub fn pattern_properties(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps>>) -> Self {
+        self.pattern_properties = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::properties`].
+    pub fn properties(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps>>) -> Self {
+        self.properties = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::required`].
+    pub fn required(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.required = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::title`].
+    pub fn title(mut self, value: impl Into<String>) -> Self {
+        self.title = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::type_`].
+    pub fn type_(mut self, value: impl Into<String>) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Sets [`JSONSchemaProps::unique_items`].
+    pub fn unique_items(mut self, value: impl Into<bool>) -> Self {
+        self.unique_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `JSONSchemaProps`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<JSONSchemaProps, crate::MissingFieldError> {
+        Ok(JSONSchemaProps {
+            ref_path: self.ref_path,
+            schema: self.schema,
+            additional_items: self.additional_items,
+            additional_properties: self.additional_properties,
+            all_of: self.all_of,
+            any_of: self.any_of,
+            default: self.default,
+            definitions: self.definitions,
+            dependencies: self.dependencies,
+            description: self.description,
+            enum_: self.enum_,
+            example: self.example,
+            exclusive_maximum: self.exclusive_maximum,
+            exclusive_minimum: self.exclusive_minimum,
+            external_docs: self.external_docs,
+            format: self.format,
+            id: self.id,
+            items: self.items,
+            max_items: self.max_items,
+            max_length: self.max_length,
+            max_properties: self.max_properties,
+            maximum: self.maximum,
+            min_items: self.min_items,
+            min_length: self.min_length,
+            min_properties: self.min_properties,
+            minimum: self.minimum,
+            multiple_of: self.multiple_of,
+            not: self.not,
+            one_of: self.one_of,
+            pattern: self.pattern,
+            pattern_properties: self.pattern_properties,
+            properties: self.properties,
+            required: self.required,
+            title: self.title,
+            type_: self.type_,
+            unique_items: self.unique_items,
+        })
+    }
+}
+
+/// The apply configuration of [`JSONSchemaProps`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct JSONSchemaPropsApplyConfiguration {
+    /// See [`JSONSchemaProps::ref_path`].
+    pub ref_path: Option<String>,
+
+    /// See [`JSONSchemaProps::schema`].
+    pub schema: Option<String>,
+
+    /// See [`JSONSchemaProps::additional_items`].
+    pub additional_items: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsOrBool>,
+
+    /// See [`JSONSchemaProps::additional_properties`].
+    pub additional_properties: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsOrBool>,
+
+    /// See [`JSONSchemaProps::all_of`].
+    pub all_of: Option<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsApplyConfiguration>>,
+
+    /// See [`JSONSchemaProps::any_of`].
+    pub any_of: Option<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsApplyConfiguration>>,
+
+    /// See [`JSONSchemaProps::default`].
+    pub default: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSON>,
+
+    /// See [`JSONSchemaProps::definitions`].
+    pub definitions: Option<std::collections::BTreeMap<String, crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsApplyConfiguration>>,
+
+    /// See [`JSONSchemaProps::dependencies`].
+    pub dependencies: Option<std::collections::BTreeMap<String, crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsOrStringArray>>,
+
+    /// See [`JSONSchemaProps::description`].
+    pub description: Option<String>,
+
+    /// See [`JSONSchemaProps::enum_`].
+    pub enum_: Option<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSON>>,
+
+    /// See [`JSONSchemaProps::example`].
+    pub example: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSON>,
+
+    /// See [`JSONSchemaProps::exclusive_maximum`].
+    pub exclusive_maximum: Option<bool>,
+
+    /// See [`JSONSchemaProps::exclusive_minimum`].
+    pub exclusive_minimum: Option<bool>,
+
+    /// See [`JSONSchemaProps::external_docs`].
+    pub external_docs: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::ExternalDocumentationApplyConfiguration>,
+
+    /// See [`JSONSchemaProps::format`].
+    pub format: Option<String>,
+
+    /// See [`JSONSchemaProps::id`].
+    pub id: Option<String>,
+
+    /// See [`JSONSchemaProps::items`].
+    pub items: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsOrArray>,
+
+    /// See [`JSONSchemaProps::max_items`].
+    pub max_items: Option<i64>,
+
+    /// See [`JSONSchemaProps::max_length`].
+    pub max_length: Option<i64>,
+
+    /// See [`JSONSchemaProps::max_properties`].
+    pub max_properties: Option<i64>,
+
+    /// See [`JSONSchemaProps::maximum`].
+    pub maximum: Option<f64>,
+
+    /// See [`JSONSchemaProps::min_items`].
+    pub min_items: Option<i64>,
+
+    /// See [`JSONSchemaProps::min_length`].
+    pub min_length: Option<i64>,
+
+    /// See [`JSONSchemaProps::min_properties`].
+    pub min_properties: Option<i64>,
+
+    /// See [`JSONSchemaProps::minimum`].
+    pub minimum: Option<f64>,
+
+    /// See [`JSONSchemaProps::multiple_of`].
+    pub multiple_of: Option<f64>,
+
+    /// See [`JSONSchemaProps::not`].
+    pub not: Option<Box<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsApplyConfiguration>>,
+
+    /// See [`JSONSchemaProps::one_of`].
+    pub one_of: Option<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsApplyConfiguration>>,
+
+    /// See [`JSONSchemaProps::pattern`].
+    pub pattern: Option<String>,
+
+    /// See [`JSONSchemaProps::pattern_properties`].
+    pub pattern_properties: Option<std::collections::BTreeMap<String, crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsApplyConfiguration>>,
+
+    /// See [`JSONSchemaProps::properties`].
+    pub properties: Option<std::collections::BTreeMap<String, crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsApplyConfiguration>>,
+
+    /// See [`JSONSchemaProps::required`].
+    pub required: Option<Vec<String>>,
+
+    /// See [`JSONSchemaProps::title`].
+    pub title: Option<String>,
+
+    /// See [`JSONSchemaProps::type_`].
+    pub type_: Option<String>,
+
+    /// See [`JSONSchemaProps::unique_items`].
+    pub unique_items: Option<bool>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for JSONSchemaPropsApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "JSONSchemaPropsApplyConfiguration",
+            self.ref_path.as_ref().map_or(0, |_| 1) +
+            self.schema.as_ref().map_or(0, |_| 1) +
+            self.additional_items.as_ref().map_or(0, |_| 1) +
+            self.additional_properties.as_ref().map_or(0, |_| 1) +
+            self.all_of.as_ref().map_or(0, |_| 1) +
+            self.any_of.as_ref().map_or(0, |_| 1) +
+            self.default.as_ref().map_or(0, |_| 1) +
+            self.definitions.as_ref().map_or(0, |_| 1) +
+            self.dependencies.as_ref().map_or(0, |_| 1) +
+            self.description.as_ref().map_or(0, |_| 1) +
+            self.enum_.as_ref().map_or(0, |_| 1) +
+            self.example.as_ref().map_or(0, |_| 1) +
+            self.exclusive_maximum.as_ref().map_or(0, |_| 1) +
+            self.exclusive_minimum.as_ref().map_or(0, |_| 1) +
+            self.external_docs.as_ref().map_or(0, |_| 1) +
+            self.format.as_ref().map_or(0, |_| 1) +
+            self.id.as_ref().map_or(0, |_| 1) +
+            self.items.as_ref().map_or(0, |_| 1) +
+            self.max_items.as_ref().map_or(0, |_| 1) +
+            self.max_length.as_ref().map_or(0, |_| 1) +
+            self.max_properties.as_ref().map_or(0, |_| 1) +
+            self.maximum.as_ref().map_or(0, |_| 1) +
+            self.min_items.as_ref().map_or(0, |_| 1) +
+            self.min_length.as_ref().map_or(0, |_| 1) +
+            self.min_properties.as_ref().map_or(0, |_| 1) +
+            self.minimum.as_ref().map_or(0, |_| 1) +
+            self.multiple_of.as_ref().map_or(0, |_| 1) +
+            self.not.as_ref().map_or(0, |_| 1) +
+            self.one_of.as_ref().map_or(0, |_| 1) +
+            self.pattern.as_ref().map_or(0, |_| 1) +
+            self.pattern_properties.as_ref().map_or(0, |_| 1) +
+            self.properties.as_ref().map_or(0, |_| 1) +
+            self.required.as_ref().map_or(0, |_| 1) +
+            self.title.as_ref().map_or(0, |_| 1) +
+            self.type_.as_ref().map_or(0, |_| 1) +
+            self.unique_items.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.ref_path {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "$ref", value)?;
+        }
+        if let Some(value) = &self.schema {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "$schema", value)?;
+        }
+        if let Some(value) = &self.additional_items {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "additionalItems", value)?;
+        }
+        if let Some(value) = &self.additional_properties {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "additionalProperties", value)?;
+        }
+        if let Some(value) = &self.all_of {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "allOf", value)?;
+        }
+        if let Some(value) = &self.any_of {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "anyOf", value)?;
+        }
+        if let Some(value) = &self.default {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "default", value)?;
+        }
+        if let Some(value) = &self.definitions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "definitions", value)?;
+        }
+        if let Some(value) = &self.dependencies {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "dependencies", value)?;
+        }
+        if let Some(value) = &self.description {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "description", value)?;
+        }
+        if let Some(value) = &self.enum_ {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enum", value)?;
+        }
+        if let Some(value) = &self.example {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "example", value)?;
+        }
+        if let Some(value) = &self.exclusive_maximum {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "exclusiveMaximum", value)?;
+        }
+        if let Some(value) = &self.exclusive_minimum {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "exclusiveMinimum", value)?;
+        }
+        if let Some(value) = &self.external_docs {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "externalDocs", value)?;
+        }
+        if let Some(value) = &self.format {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "format", value)?;
+        }
+        if let Some(value) = &self.id {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "id", value)?;
+        }
+        if let Some(value) = &self.items {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "items", value)?;
+        }
+        if let Some(value) = &self.max_items {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maxItems", value)?;
+        }
+        if let Some(value) = &self.max_length {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maxLength", value)?;
+        }
+        if let Some(value) = &self.max_properties {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maxProperties", value)?;
+        }
+        if let Some(value) = &self.maximum {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maximum", value)?;
+        }
+        if let Some(value) = &self.min_items {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "minItems", value)?;
+        }
+        if let Some(value) = &self.min_length {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "minLength", value)?;
+        }
+        if let Some(value) = &self.min_properties {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "minProperties", value)?;
+        }
+        if let Some(value) = &self.minimum {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "minimum", value)?;
+        }
+        if let Some(value) = &self.multiple_of {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "multipleOf", value)?;
+        }
+        if let Some(value) = &self.not {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "not", value)?;
+        }
+        if let Some(value) = &self.one_of {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "oneOf", value)?;
+        }
+        if let Some(value) = &self.pattern {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "pattern", value)?;
+        }
+        if let Some(value) = &self.pattern_properties {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "patternProperties", value)?;
+        }
+        if let Some(value) = &self.properties {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "properties", value)?;
+        }
+        if let Some(value) = &self.required {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "required", value)?;
+        }
+        if let Some(value) = &self.title {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "title", value)?;
+        }
+        if let Some(value) = &self.type_ {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "type", value)?;
+        }
+        if let Some(value) = &self.unique_items {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "uniqueItems", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for JSONSchemaProps {
     fn schema_name() -> String {
-        "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.JSONSchemaProps".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps".to_owned()
+        }
+        else {
+            "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.JSONSchemaProps".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -519,30 +1596,31 @@ impl crate::schemars::JsonSchema for JSONSchemaProps {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "$ref".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "$schema".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "additionalItems".to_owned(),
                         __gen.subschema_for::<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsOrBool>(),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "additionalProperties".to_owned(),
                         __gen.subschema_for::<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsOrBool>(),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "allOf".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Array))),
@@ -552,8 +1630,8 @@ impl crate::schemars::JsonSchema for JSONSchemaProps {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "anyOf".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Array))),
@@ -563,12 +1641,12 @@ impl crate::schemars::JsonSchema for JSONSchemaProps {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "default".to_owned(),
                         __gen.subschema_for::<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSON>(),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "definitions".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
@@ -578,8 +1656,8 @@ impl crate::schemars::JsonSchema for JSONSchemaProps {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "dependencies".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
@@ -589,15 +1667,15 @@ impl crate::schemars::JsonSchema for JSONSchemaProps {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "description".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "enum".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Array))),
@@ -607,124 +1685,124 @@ impl crate::schemars::JsonSchema for JSONSchemaProps {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "example".to_owned(),
                         __gen.subschema_for::<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSON>(),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "exclusiveMaximum".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "exclusiveMinimum".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "externalDocs".to_owned(),
                         __gen.subschema_for::<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::ExternalDocumentation>(),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "format".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "id".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "items".to_owned(),
                         __gen.subschema_for::<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsOrArray>(),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "maxItems".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "maxLength".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "maxProperties".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "maximum".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Number))),
                             format: Some("double".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "minItems".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "minLength".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "minProperties".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "minimum".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Number))),
                             format: Some("double".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "multipleOf".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Number))),
                             format: Some("double".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "not".to_owned(),
                         __gen.subschema_for::<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps>(),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "oneOf".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Array))),
@@ -734,15 +1812,15 @@ impl crate::schemars::JsonSchema for JSONSchemaProps {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "pattern".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "patternProperties".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
@@ -752,8 +1830,8 @@ impl crate::schemars::JsonSchema for JSONSchemaProps {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "properties".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
@@ -763,8 +1841,8 @@ impl crate::schemars::JsonSchema for JSONSchemaProps {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "required".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Array))),
@@ -779,32 +1857,77 @@ impl crate::schemars::JsonSchema for JSONSchemaProps {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "title".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "type".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "uniqueItems".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for JSONSchemaProps {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(JSONSchemaProps {
+            ref_path: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            schema: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            additional_items: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            additional_properties: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            all_of: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            any_of: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            default: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            definitions: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            dependencies: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            description: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            enum_: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            example: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            exclusive_maximum: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            exclusive_minimum: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            external_docs: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            format: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            id: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            items: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            max_items: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            max_length: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            max_properties: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            maximum: crate::arbitrary2::option(u, crate::arbitrary2::json_f64)?,
+            min_items: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            min_length: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            min_properties: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            minimum: crate::arbitrary2::option(u, crate::arbitrary2::json_f64)?,
+            multiple_of: crate::arbitrary2::option(u, crate::arbitrary2::json_f64)?,
+            not: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            one_of: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            pattern: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            pattern_properties: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            properties: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            required: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            title: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            type_: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            unique_items: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
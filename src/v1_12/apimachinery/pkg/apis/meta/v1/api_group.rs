@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.apimachinery.pkg.apis.meta.v1.APIGroup
 
 /// APIGroup contains the name, the supported versions, and the preferred version of a group.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct APIGroup {
     /// name is the name of the group.
     pub name: String,
@@ -16,56 +16,75 @@ pub struct APIGroup {
     pub versions: Vec<crate::apimachinery::pkg::apis::meta::v1::GroupVersionForDiscovery>,
 }
 
+impl APIGroup {
+    /// Constructs a `APIGroup` from its required fields. Its optional fields are unset.
+    pub fn new(name: impl Into<String>, versions: impl Into<Vec<crate::apimachinery::pkg::apis::meta::v1::GroupVersionForDiscovery>>) -> Self {
+        APIGroup {
+            name: name.into(),
+            preferred_version: None,
+            server_address_by_client_cidrs: None,
+            versions: versions.into(),
+        }
+    }
+}
+
+impl APIGroup {
+    /// Returns this value with [`APIGroup::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
+
+    /// Returns this value with [`APIGroup::preferred_version`] set to the given value.
+    #[must_use]
+    pub fn with_preferred_version(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::GroupVersionForDiscovery>) -> Self {
+        self.preferred_version = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`APIGroup::server_address_by_client_cidrs`] set to the given value.
+    #[must_use]
+    pub fn with_server_address_by_client_cidrs(mut self, value: impl Into<Vec<crate::apimachinery::pkg::apis::meta::v1::ServerAddressByClientCIDR>>) -> Self {
+        self.server_address_by_client_cidrs = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`APIGroup::versions`] set to the given value.
+    #[must_use]
+    pub fn with_versions(mut self, value: impl Into<Vec<crate::apimachinery::pkg::apis::meta::v1::GroupVersionForDiscovery>>) -> Self {
+        self.versions = value.into();
+        self
+    }
+}
+
 impl crate::Resource for APIGroup {
     const API_VERSION: &'static str = "v1";
     const GROUP: &'static str = "";
     const KIND: &'static str = "APIGroup";
     const VERSION: &'static str = "v1";
     const URL_PATH_SEGMENT: &'static str = "";
+    const VERBS: &'static [&'static str] = &[];
+    const SINGULAR: &'static str = "apigroup";
+    const SHORT_NAMES: &'static [&'static str] = &[];
     type Scope = crate::ClusterResourceScope;
 }
 
-impl<'de> crate::serde::Deserialize<'de> for APIGroup {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_api_version,
-            Key_kind,
-            Key_name,
-            Key_preferred_version,
-            Key_server_address_by_client_cidrs,
-            Key_versions,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for APIGroup {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "apiVersion" => Field::Key_api_version,
-                            "kind" => Field::Key_kind,
-                            "name" => Field::Key_name,
-                            "preferredVersion" => Field::Key_preferred_version,
-                            "serverAddressByClientCIDRs" => Field::Key_server_address_by_client_cidrs,
-                            "versions" => Field::Key_versions,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl APIGroup {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "kind",
+        "name",
+        "preferredVersion",
+        "serverAddressByClientCIDRs",
+        "versions",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for APIGroup {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -81,54 +100,135 @@ impl<'de> crate::serde::Deserialize<'de> for APIGroup {
                 let mut value_server_address_by_client_cidrs: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::ServerAddressByClientCIDR>> = None;
                 let mut value_versions: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::GroupVersionForDiscovery>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("APIGroup", APIGroup::FIELDS))? {
                     match key {
-                        Field::Key_api_version => {
+                        Some(0) => {
                             let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_api_version != <Self::Value as crate::Resource>::API_VERSION {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<Self::Value as crate::Resource>::API_VERSION));
                             }
                         },
-                        Field::Key_kind => {
+                        Some(1) => {
                             let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_kind != <Self::Value as crate::Resource>::KIND {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<Self::Value as crate::Resource>::KIND));
                             }
                         },
-                        Field::Key_name => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_preferred_version => value_preferred_version = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_server_address_by_client_cidrs => value_server_address_by_client_cidrs = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_versions => value_versions = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(2) => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_preferred_version = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_server_address_by_client_cidrs = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_versions = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(APIGroup {
-                    name: value_name.ok_or_else(|| crate::serde::de::Error::missing_field("name"))?,
+                    name: value_name.ok_or_else(|| crate::serde2::missing_field("APIGroup", "name", &["name", "versions"]))?,
                     preferred_version: value_preferred_version,
                     server_address_by_client_cidrs: value_server_address_by_client_cidrs,
-                    versions: value_versions.ok_or_else(|| crate::serde::de::Error::missing_field("versions"))?,
+                    versions: value_versions.ok_or_else(|| crate::serde2::missing_field("APIGroup", "versions", &["name", "versions"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(APIGroup {
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    preferred_version: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    server_address_by_client_cidrs: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    versions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             <Self as crate::Resource>::KIND,
-            &[
-                "apiVersion",
-                "kind",
-                "name",
-                "preferredVersion",
-                "serverAddressByClientCIDRs",
-                "versions",
-            ],
+            APIGroup::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, APIGroup> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<APIGroup as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_name = false;
+                let mut has_preferred_version = false;
+                let mut has_server_address_by_client_cidrs = false;
+                let mut has_versions = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("APIGroup", APIGroup::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <APIGroup as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<APIGroup as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <APIGroup as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<APIGroup as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.name))?; has_name = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.preferred_version))?; has_preferred_version = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.server_address_by_client_cidrs))?; has_server_address_by_client_cidrs = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.versions))?; has_versions = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_name {
+                    return Err(crate::serde2::missing_field("APIGroup", "name", &["name", "versions"]));
+                }
+                if !has_preferred_version {
+                    self.0.preferred_version = None;
+                }
+                if !has_server_address_by_client_cidrs {
+                    self.0.server_address_by_client_cidrs = None;
+                }
+                if !has_versions {
+                    return Err(crate::serde2::missing_field("APIGroup", "versions", &["name", "versions"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.preferred_version))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.server_address_by_client_cidrs))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.versions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            APIGroup::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for APIGroup {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct(<Self as crate::Resource>::KIND, 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "preferredVersion", &self.preferred_version)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "serverAddressByClientCIDRs", &self.server_address_by_client_cidrs)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "versions", &self.versions)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             <Self as crate::Resource>::KIND,
             4 +
@@ -149,10 +249,117 @@ impl crate::serde::Serialize for APIGroup {
     }
 }
 
+/// A builder of [`APIGroup`]. Created with [`APIGroup::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct APIGroupBuilder {
+    name: Option<String>,
+    preferred_version: Option<crate::apimachinery::pkg::apis::meta::v1::GroupVersionForDiscovery>,
+    server_address_by_client_cidrs: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::ServerAddressByClientCIDR>>,
+    versions: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::GroupVersionForDiscovery>>,
+}
+
+#[cfg(feature = "builder")]
+impl APIGroup {
+    /// Starts building a `APIGroup`. Its required fields must be set before [`APIGroupBuilder::build`] is called.
+    pub fn builder() -> APIGroupBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl APIGroupBuilder {
+    /// Sets [`APIGroup::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`APIGroup::preferred_version`].
+    pub fn preferred_version(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::GroupVersionForDiscovery>) -> Self {
+        self.preferred_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`APIGroup::server_address_by_client_cidrs`].
+    pub fn server_address_by_client_cidrs(mut self, value: impl Into<Vec<crate::apimachinery::pkg::apis::meta::v1::ServerAddressByClientCIDR>>) -> Self {
+        self.server_address_by_client_cidrs = Some(value.into());
+        self
+    }
+
+    /// Sets [`APIGroup::versions`].
+    pub fn versions(mut self, value: impl Into<Vec<crate::apimachinery::pkg::apis::meta::v1::GroupVersionForDiscovery>>) -> Self {
+        self.versions = Some(value.into());
+        self
+    }
+
+    /// Builds the `APIGroup`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<APIGroup, crate::MissingFieldError> {
+        Ok(APIGroup {
+            name: self.name.ok_or_else(|| crate::MissingFieldError::new("APIGroup", "name"))?,
+            preferred_version: self.preferred_version,
+            server_address_by_client_cidrs: self.server_address_by_client_cidrs,
+            versions: self.versions.ok_or_else(|| crate::MissingFieldError::new("APIGroup", "versions"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`APIGroup`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct APIGroupApplyConfiguration {
+    /// See [`APIGroup::name`].
+    pub name: Option<String>,
+
+    /// See [`APIGroup::preferred_version`].
+    pub preferred_version: Option<crate::apimachinery::pkg::apis::meta::v1::GroupVersionForDiscoveryApplyConfiguration>,
+
+    /// See [`APIGroup::server_address_by_client_cidrs`].
+    pub server_address_by_client_cidrs: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::ServerAddressByClientCIDRApplyConfiguration>>,
+
+    /// See [`APIGroup::versions`].
+    pub versions: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::GroupVersionForDiscoveryApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for APIGroupApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            <APIGroup as crate::Resource>::KIND,
+            2 +
+            self.name.as_ref().map_or(0, |_| 1) +
+            self.preferred_version.as_ref().map_or(0, |_| 1) +
+            self.server_address_by_client_cidrs.as_ref().map_or(0, |_| 1) +
+            self.versions.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <APIGroup as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <APIGroup as crate::Resource>::KIND)?;
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        if let Some(value) = &self.preferred_version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "preferredVersion", value)?;
+        }
+        if let Some(value) = &self.server_address_by_client_cidrs {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "serverAddressByClientCIDRs", value)?;
+        }
+        if let Some(value) = &self.versions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "versions", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for APIGroup {
     fn schema_name() -> String {
-        "io.k8s.apimachinery.pkg.apis.meta.v1.APIGroup".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apimachinery::pkg::apis::meta::v1::APIGroup".to_owned()
+        }
+        else {
+            "io.k8s.apimachinery.pkg.apis.meta.v1.APIGroup".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -163,8 +370,9 @@ impl crate::schemars::JsonSchema for APIGroup {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "apiVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -172,10 +380,11 @@ impl crate::schemars::JsonSchema for APIGroup {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("v1".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "kind".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -183,10 +392,11 @@ impl crate::schemars::JsonSchema for APIGroup {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("APIGroup".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -196,8 +406,8 @@ impl crate::schemars::JsonSchema for APIGroup {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "preferredVersion".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::GroupVersionForDiscovery>().into_object();
@@ -207,8 +417,8 @@ impl crate::schemars::JsonSchema for APIGroup {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "serverAddressByClientCIDRs".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -222,8 +432,8 @@ impl crate::schemars::JsonSchema for APIGroup {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "versions".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -237,9 +447,12 @@ impl crate::schemars::JsonSchema for APIGroup {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
+                    "apiVersion",
+                    "kind",
                     "name",
                     "versions",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -249,3 +462,15 @@ impl crate::schemars::JsonSchema for APIGroup {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for APIGroup {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(APIGroup {
+            name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            preferred_version: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            server_address_by_client_cidrs: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            versions: crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
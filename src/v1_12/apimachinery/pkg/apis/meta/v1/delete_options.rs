@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.apimachinery.pkg.apis.meta.v1.DeleteOptions
 
 /// DeleteOptions may be provided when deleting an API object.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct DeleteOptions {
     /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
     pub api_version: Option<String>,
@@ -16,6 +16,7 @@ pub struct DeleteOptions {
     pub kind: Option<String>,
 
     /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[deprecated(note = "please use the PropagationPolicy, this field will be deprecated in 1.7.")]
     pub orphan_dependents: Option<bool>,
 
     /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
@@ -25,49 +26,73 @@ pub struct DeleteOptions {
     pub propagation_policy: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for DeleteOptions {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_api_version,
-            Key_dry_run,
-            Key_grace_period_seconds,
-            Key_kind,
-            Key_orphan_dependents,
-            Key_preconditions,
-            Key_propagation_policy,
-            Other,
-        }
+impl DeleteOptions {
+    /// Returns this value with [`DeleteOptions::api_version`] set to the given value.
+    #[must_use]
+    pub fn with_api_version(mut self, value: impl Into<String>) -> Self {
+        self.api_version = Some(value.into());
+        self
+    }
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+    /// Returns this value with [`DeleteOptions::dry_run`] set to the given value.
+    #[must_use]
+    pub fn with_dry_run(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.dry_run = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`DeleteOptions::grace_period_seconds`] set to the given value.
+    #[must_use]
+    pub fn with_grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.grace_period_seconds = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`DeleteOptions::kind`] set to the given value.
+    #[must_use]
+    pub fn with_kind(mut self, value: impl Into<String>) -> Self {
+        self.kind = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "apiVersion" => Field::Key_api_version,
-                            "dryRun" => Field::Key_dry_run,
-                            "gracePeriodSeconds" => Field::Key_grace_period_seconds,
-                            "kind" => Field::Key_kind,
-                            "orphanDependents" => Field::Key_orphan_dependents,
-                            "preconditions" => Field::Key_preconditions,
-                            "propagationPolicy" => Field::Key_propagation_policy,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`DeleteOptions::orphan_dependents`] set to the given value.
+    #[must_use]
+    pub fn with_orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.orphan_dependents = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`DeleteOptions::preconditions`] set to the given value.
+    #[must_use]
+    pub fn with_preconditions(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Preconditions>) -> Self {
+        self.preconditions = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`DeleteOptions::propagation_policy`] set to the given value.
+    #[must_use]
+    pub fn with_propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.propagation_policy = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for DeleteOptions {}
 
+impl DeleteOptions {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "dryRun",
+        "gracePeriodSeconds",
+        "kind",
+        "orphanDependents",
+        "preconditions",
+        "propagationPolicy",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for DeleteOptions {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -86,16 +111,16 @@ impl<'de> crate::serde::Deserialize<'de> for DeleteOptions {
                 let mut value_preconditions: Option<crate::apimachinery::pkg::apis::meta::v1::Preconditions> = None;
                 let mut value_propagation_policy: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("DeleteOptions", DeleteOptions::FIELDS))? {
                     match key {
-                        Field::Key_api_version => value_api_version = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_dry_run => value_dry_run = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_grace_period_seconds => value_grace_period_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_kind => value_kind = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_orphan_dependents => value_orphan_dependents = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_preconditions => value_preconditions = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_propagation_policy => value_propagation_policy = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_api_version = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_dry_run = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_grace_period_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_kind = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_orphan_dependents = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_preconditions = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_propagation_policy = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -109,26 +134,118 @@ impl<'de> crate::serde::Deserialize<'de> for DeleteOptions {
                     propagation_policy: value_propagation_policy,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(DeleteOptions {
+                    api_version: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    dry_run: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    grace_period_seconds: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    kind: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    orphan_dependents: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    preconditions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    propagation_policy: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "DeleteOptions",
-            &[
-                "apiVersion",
-                "dryRun",
-                "gracePeriodSeconds",
-                "kind",
-                "orphanDependents",
-                "preconditions",
-                "propagationPolicy",
-            ],
+            DeleteOptions::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, DeleteOptions> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("DeleteOptions")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_api_version = false;
+                let mut has_dry_run = false;
+                let mut has_grace_period_seconds = false;
+                let mut has_kind = false;
+                let mut has_orphan_dependents = false;
+                let mut has_preconditions = false;
+                let mut has_propagation_policy = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("DeleteOptions", DeleteOptions::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.api_version))?; has_api_version = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.dry_run))?; has_dry_run = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.grace_period_seconds))?; has_grace_period_seconds = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.kind))?; has_kind = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.orphan_dependents))?; has_orphan_dependents = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.preconditions))?; has_preconditions = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.propagation_policy))?; has_propagation_policy = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_api_version {
+                    self.0.api_version = None;
+                }
+                if !has_dry_run {
+                    self.0.dry_run = None;
+                }
+                if !has_grace_period_seconds {
+                    self.0.grace_period_seconds = None;
+                }
+                if !has_kind {
+                    self.0.kind = None;
+                }
+                if !has_orphan_dependents {
+                    self.0.orphan_dependents = None;
+                }
+                if !has_preconditions {
+                    self.0.preconditions = None;
+                }
+                if !has_propagation_policy {
+                    self.0.propagation_policy = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.api_version))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.dry_run))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.grace_period_seconds))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.kind))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.orphan_dependents))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.preconditions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.propagation_policy))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "DeleteOptions",
+            DeleteOptions::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for DeleteOptions {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("DeleteOptions", 7)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", &self.api_version)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "dryRun", &self.dry_run)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "gracePeriodSeconds", &self.grace_period_seconds)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", &self.kind)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "orphanDependents", &self.orphan_dependents)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "preconditions", &self.preconditions)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "propagationPolicy", &self.propagation_policy)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "DeleteOptions",
             self.api_version.as_ref().map_or(0, |_| 1) +
@@ -164,10 +281,159 @@ impl crate::serde::Serialize for DeleteOptions {
     }
 }
 
+/// A builder of [`DeleteOptions`]. Created with [`DeleteOptions::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct DeleteOptionsBuilder {
+    api_version: Option<String>,
+    dry_run: Option<Vec<String>>,
+    grace_period_seconds: Option<i64>,
+    kind: Option<String>,
+    orphan_dependents: Option<bool>,
+    preconditions: Option<crate::apimachinery::pkg::apis::meta::v1::Preconditions>,
+    propagation_policy: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl DeleteOptions {
+    /// Starts building a `DeleteOptions`. Its required fields must be set before [`DeleteOptionsBuilder::build`] is called.
+    pub fn builder() -> DeleteOptionsBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl DeleteOptionsBuilder {
+    /// Sets [`DeleteOptions::api_version`].
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeleteOptions::dry_run`].
+    pub fn dry_run(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeleteOptions::grace_period_seconds`].
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.grace_period_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeleteOptions::kind`].
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeleteOptions::orphan_dependents`].
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.orphan_dependents = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeleteOptions::preconditions`].
+    pub fn preconditions(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Preconditions>) -> Self {
+        self.preconditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeleteOptions::propagation_policy`].
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.propagation_policy = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteOptions`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<DeleteOptions, crate::MissingFieldError> {
+        Ok(DeleteOptions {
+            api_version: self.api_version,
+            dry_run: self.dry_run,
+            grace_period_seconds: self.grace_period_seconds,
+            kind: self.kind,
+            orphan_dependents: self.orphan_dependents,
+            preconditions: self.preconditions,
+            propagation_policy: self.propagation_policy,
+        })
+    }
+}
+
+/// The apply configuration of [`DeleteOptions`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DeleteOptionsApplyConfiguration {
+    /// See [`DeleteOptions::api_version`].
+    pub api_version: Option<String>,
+
+    /// See [`DeleteOptions::dry_run`].
+    pub dry_run: Option<Vec<String>>,
+
+    /// See [`DeleteOptions::grace_period_seconds`].
+    pub grace_period_seconds: Option<i64>,
+
+    /// See [`DeleteOptions::kind`].
+    pub kind: Option<String>,
+
+    /// See [`DeleteOptions::orphan_dependents`].
+    pub orphan_dependents: Option<bool>,
+
+    /// See [`DeleteOptions::preconditions`].
+    pub preconditions: Option<crate::apimachinery::pkg::apis::meta::v1::PreconditionsApplyConfiguration>,
+
+    /// See [`DeleteOptions::propagation_policy`].
+    pub propagation_policy: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for DeleteOptionsApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "DeleteOptionsApplyConfiguration",
+            self.api_version.as_ref().map_or(0, |_| 1) +
+            self.dry_run.as_ref().map_or(0, |_| 1) +
+            self.grace_period_seconds.as_ref().map_or(0, |_| 1) +
+            self.kind.as_ref().map_or(0, |_| 1) +
+            self.orphan_dependents.as_ref().map_or(0, |_| 1) +
+            self.preconditions.as_ref().map_or(0, |_| 1) +
+            self.propagation_policy.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.api_version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", value)?;
+        }
+        if let Some(value) = &self.dry_run {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "dryRun", value)?;
+        }
+        if let Some(value) = &self.grace_period_seconds {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "gracePeriodSeconds", value)?;
+        }
+        if let Some(value) = &self.kind {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", value)?;
+        }
+        if let Some(value) = &self.orphan_dependents {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "orphanDependents", value)?;
+        }
+        if let Some(value) = &self.preconditions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "preconditions", value)?;
+        }
+        if let Some(value) = &self.propagation_policy {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "propagationPolicy", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DeleteOptions {
     fn schema_name() -> String {
-        "io.k8s.apimachinery.pkg.apis.meta.v1.DeleteOptions".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apimachinery::pkg::apis::meta::v1::DeleteOptions".to_owned()
+        }
+        else {
+            "io.k8s.apimachinery.pkg.apis.meta.v1.DeleteOptions".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -178,8 +444,9 @@ impl crate::schemars::JsonSchema for DeleteOptions {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "apiVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -189,8 +456,8 @@ impl crate::schemars::JsonSchema for DeleteOptions {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "dryRun".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -209,8 +476,8 @@ impl crate::schemars::JsonSchema for DeleteOptions {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "gracePeriodSeconds".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -221,8 +488,8 @@ impl crate::schemars::JsonSchema for DeleteOptions {
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "kind".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -232,8 +499,8 @@ impl crate::schemars::JsonSchema for DeleteOptions {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "orphanDependents".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -243,8 +510,8 @@ impl crate::schemars::JsonSchema for DeleteOptions {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "preconditions".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::Preconditions>().into_object();
@@ -254,8 +521,8 @@ impl crate::schemars::JsonSchema for DeleteOptions {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "propagationPolicy".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -265,11 +532,27 @@ impl crate::schemars::JsonSchema for DeleteOptions {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for DeleteOptions {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(DeleteOptions {
+            api_version: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            dry_run: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            grace_period_seconds: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            kind: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            orphan_dependents: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            preconditions: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            propagation_policy: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.apimachinery.pkg.apis.meta.v1.GroupVersionForDiscovery
 
 /// GroupVersion contains the "group/version" and "version" string of a version. It is made a struct to keep extensibility.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct GroupVersionForDiscovery {
     /// groupVersion specifies the API group and version in the form "group/version"
     pub group_version: String,
@@ -10,39 +10,43 @@ pub struct GroupVersionForDiscovery {
     pub version: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for GroupVersionForDiscovery {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_group_version,
-            Key_version,
-            Other,
+impl GroupVersionForDiscovery {
+    /// Constructs a `GroupVersionForDiscovery` from its required fields. Its optional fields are unset.
+    pub fn new(group_version: impl Into<String>, version: impl Into<String>) -> Self {
+        GroupVersionForDiscovery {
+            group_version: group_version.into(),
+            version: version.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl GroupVersionForDiscovery {
+    /// Returns this value with [`GroupVersionForDiscovery::group_version`] set to the given value.
+    #[must_use]
+    pub fn with_group_version(mut self, value: impl Into<String>) -> Self {
+        self.group_version = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`GroupVersionForDiscovery::version`] set to the given value.
+    #[must_use]
+    pub fn with_version(mut self, value: impl Into<String>) -> Self {
+        self.version = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "groupVersion" => Field::Key_group_version,
-                            "version" => Field::Key_version,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for GroupVersionForDiscovery {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl GroupVersionForDiscovery {
+    const FIELDS: &'static [&'static str] = &[
+        "groupVersion",
+        "version",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for GroupVersionForDiscovery {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +60,91 @@ impl<'de> crate::serde::Deserialize<'de> for GroupVersionForDiscovery {
                 let mut value_group_version: Option<String> = None;
                 let mut value_version: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("GroupVersionForDiscovery", GroupVersionForDiscovery::FIELDS))? {
                     match key {
-                        Field::Key_group_version => value_group_version = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_version => value_version = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_group_version = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_version = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(GroupVersionForDiscovery {
-                    group_version: value_group_version.ok_or_else(|| crate::serde::de::Error::missing_field("groupVersion"))?,
-                    version: value_version.ok_or_else(|| crate::serde::de::Error::missing_field("version"))?,
+                    group_version: value_group_version.ok_or_else(|| crate::serde2::missing_field("GroupVersionForDiscovery", "groupVersion", &["groupVersion", "version"]))?,
+                    version: value_version.ok_or_else(|| crate::serde2::missing_field("GroupVersionForDiscovery", "version", &["groupVersion", "version"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(GroupVersionForDiscovery {
+                    group_version: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    version: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "GroupVersionForDiscovery",
-            &[
-                "groupVersion",
-                "version",
-            ],
+            GroupVersionForDiscovery::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, GroupVersionForDiscovery> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("GroupVersionForDiscovery")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_group_version = false;
+                let mut has_version = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("GroupVersionForDiscovery", GroupVersionForDiscovery::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.group_version))?; has_group_version = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.version))?; has_version = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_group_version {
+                    return Err(crate::serde2::missing_field("GroupVersionForDiscovery", "groupVersion", &["groupVersion", "version"]));
+                }
+                if !has_version {
+                    return Err(crate::serde2::missing_field("GroupVersionForDiscovery", "version", &["groupVersion", "version"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.group_version))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.version))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "GroupVersionForDiscovery",
+            GroupVersionForDiscovery::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for GroupVersionForDiscovery {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("GroupVersionForDiscovery", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "groupVersion", &self.group_version)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "version", &self.version)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "GroupVersionForDiscovery",
             2,
@@ -94,10 +155,84 @@ impl crate::serde::Serialize for GroupVersionForDiscovery {
     }
 }
 
+/// A builder of [`GroupVersionForDiscovery`]. Created with [`GroupVersionForDiscovery::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct GroupVersionForDiscoveryBuilder {
+    group_version: Option<String>,
+    version: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl GroupVersionForDiscovery {
+    /// Starts building a `GroupVersionForDiscovery`. Its required fields must be set before [`GroupVersionForDiscoveryBuilder::build`] is called.
+    pub fn builder() -> GroupVersionForDiscoveryBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl GroupVersionForDiscoveryBuilder {
+    /// Sets [`GroupVersionForDiscovery::group_version`].
+    pub fn group_version(mut self, value: impl Into<String>) -> Self {
+        self.group_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`GroupVersionForDiscovery::version`].
+    pub fn version(mut self, value: impl Into<String>) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Builds the `GroupVersionForDiscovery`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<GroupVersionForDiscovery, crate::MissingFieldError> {
+        Ok(GroupVersionForDiscovery {
+            group_version: self.group_version.ok_or_else(|| crate::MissingFieldError::new("GroupVersionForDiscovery", "groupVersion"))?,
+            version: self.version.ok_or_else(|| crate::MissingFieldError::new("GroupVersionForDiscovery", "version"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`GroupVersionForDiscovery`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GroupVersionForDiscoveryApplyConfiguration {
+    /// See [`GroupVersionForDiscovery::group_version`].
+    pub group_version: Option<String>,
+
+    /// See [`GroupVersionForDiscovery::version`].
+    pub version: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for GroupVersionForDiscoveryApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "GroupVersionForDiscoveryApplyConfiguration",
+            self.group_version.as_ref().map_or(0, |_| 1) +
+            self.version.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.group_version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "groupVersion", value)?;
+        }
+        if let Some(value) = &self.version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "version", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for GroupVersionForDiscovery {
     fn schema_name() -> String {
-        "io.k8s.apimachinery.pkg.apis.meta.v1.GroupVersionForDiscovery".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apimachinery::pkg::apis::meta::v1::GroupVersionForDiscovery".to_owned()
+        }
+        else {
+            "io.k8s.apimachinery.pkg.apis.meta.v1.GroupVersionForDiscovery".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -108,8 +243,9 @@ impl crate::schemars::JsonSchema for GroupVersionForDiscovery {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "groupVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -119,8 +255,8 @@ impl crate::schemars::JsonSchema for GroupVersionForDiscovery {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "version".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -130,8 +266,9 @@ impl crate::schemars::JsonSchema for GroupVersionForDiscovery {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "groupVersion",
                     "version",
@@ -142,3 +279,13 @@ impl crate::schemars::JsonSchema for GroupVersionForDiscovery {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for GroupVersionForDiscovery {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(GroupVersionForDiscovery {
+            group_version: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            version: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
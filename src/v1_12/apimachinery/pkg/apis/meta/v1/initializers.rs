@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.apimachinery.pkg.apis.meta.v1.Initializers
 
 /// Initializers tracks the progress of initialization.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct Initializers {
     /// Pending is a list of initializers that must execute in order before this object is visible. When the last pending initializer is removed, and no failing result is set, the initializers struct will be set to nil and the object is considered as initialized and visible to all clients.
     pub pending: Vec<crate::apimachinery::pkg::apis::meta::v1::Initializer>,
@@ -10,39 +10,43 @@ pub struct Initializers {
     pub result: Option<crate::apimachinery::pkg::apis::meta::v1::Status>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for Initializers {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_pending,
-            Key_result,
-            Other,
+impl Initializers {
+    /// Constructs a `Initializers` from its required fields. Its optional fields are unset.
+    pub fn new(pending: impl Into<Vec<crate::apimachinery::pkg::apis::meta::v1::Initializer>>) -> Self {
+        Initializers {
+            pending: pending.into(),
+            result: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl Initializers {
+    /// Returns this value with [`Initializers::pending`] set to the given value.
+    #[must_use]
+    pub fn with_pending(mut self, value: impl Into<Vec<crate::apimachinery::pkg::apis::meta::v1::Initializer>>) -> Self {
+        self.pending = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`Initializers::result`] set to the given value.
+    #[must_use]
+    pub fn with_result(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Status>) -> Self {
+        self.result = Some(value.into());
+        self
+    }
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for Initializers {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "pending" => Field::Key_pending,
-                            "result" => Field::Key_result,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl Initializers {
+    const FIELDS: &'static [&'static str] = &[
+        "pending",
+        "result",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for Initializers {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +60,91 @@ impl<'de> crate::serde::Deserialize<'de> for Initializers {
                 let mut value_pending: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::Initializer>> = None;
                 let mut value_result: Option<crate::apimachinery::pkg::apis::meta::v1::Status> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Initializers", Initializers::FIELDS))? {
                     match key {
-                        Field::Key_pending => value_pending = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_result => value_result = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_pending = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_result = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(Initializers {
-                    pending: value_pending.ok_or_else(|| crate::serde::de::Error::missing_field("pending"))?,
+                    pending: value_pending.ok_or_else(|| crate::serde2::missing_field("Initializers", "pending", &["pending"]))?,
                     result: value_result,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(Initializers {
+                    pending: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    result: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "Initializers",
-            &[
-                "pending",
-                "result",
-            ],
+            Initializers::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, Initializers> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("Initializers")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_pending = false;
+                let mut has_result = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Initializers", Initializers::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.pending))?; has_pending = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.result))?; has_result = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_pending {
+                    return Err(crate::serde2::missing_field("Initializers", "pending", &["pending"]));
+                }
+                if !has_result {
+                    self.0.result = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.pending))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.result))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "Initializers",
+            Initializers::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for Initializers {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("Initializers", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "pending", &self.pending)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "result", &self.result)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "Initializers",
             1 +
@@ -97,10 +158,84 @@ impl crate::serde::Serialize for Initializers {
     }
 }
 
+/// A builder of [`Initializers`]. Created with [`Initializers::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct InitializersBuilder {
+    pending: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::Initializer>>,
+    result: Option<crate::apimachinery::pkg::apis::meta::v1::Status>,
+}
+
+#[cfg(feature = "builder")]
+impl Initializers {
+    /// Starts building a `Initializers`. Its required fields must be set before [`InitializersBuilder::build`] is called.
+    pub fn builder() -> InitializersBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl InitializersBuilder {
+    /// Sets [`Initializers::pending`].
+    pub fn pending(mut self, value: impl Into<Vec<crate::apimachinery::pkg::apis::meta::v1::Initializer>>) -> Self {
+        self.pending = Some(value.into());
+        self
+    }
+
+    /// Sets [`Initializers::result`].
+    pub fn result(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Status>) -> Self {
+        self.result = Some(value.into());
+        self
+    }
+
+    /// Builds the `Initializers`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<Initializers, crate::MissingFieldError> {
+        Ok(Initializers {
+            pending: self.pending.ok_or_else(|| crate::MissingFieldError::new("Initializers", "pending"))?,
+            result: self.result,
+        })
+    }
+}
+
+/// The apply configuration of [`Initializers`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InitializersApplyConfiguration {
+    /// See [`Initializers::pending`].
+    pub pending: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::InitializerApplyConfiguration>>,
+
+    /// See [`Initializers::result`].
+    pub result: Option<crate::apimachinery::pkg::apis::meta::v1::StatusApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for InitializersApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "InitializersApplyConfiguration",
+            self.pending.as_ref().map_or(0, |_| 1) +
+            self.result.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.pending {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "pending", value)?;
+        }
+        if let Some(value) = &self.result {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "result", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Initializers {
     fn schema_name() -> String {
-        "io.k8s.apimachinery.pkg.apis.meta.v1.Initializers".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apimachinery::pkg::apis::meta::v1::Initializers".to_owned()
+        }
+        else {
+            "io.k8s.apimachinery.pkg.apis.meta.v1.Initializers".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -111,8 +246,9 @@ impl crate::schemars::JsonSchema for Initializers {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "pending".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -126,8 +262,8 @@ impl crate::schemars::JsonSchema for Initializers {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "result".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::Status>().into_object();
@@ -137,8 +273,9 @@ impl crate::schemars::JsonSchema for Initializers {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "pending",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -148,3 +285,13 @@ impl crate::schemars::JsonSchema for Initializers {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for Initializers {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(Initializers {
+            pending: crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            result: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.apimachinery.pkg.apis.meta.v1.LabelSelector
 
 /// A label selector is a label query over a set of resources. The result of matchLabels and matchExpressions are ANDed. An empty label selector matches all objects. A null label selector matches no objects.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct LabelSelector {
     /// matchExpressions is a list of label selector requirements. The requirements are ANDed.
     pub match_expressions: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorRequirement>>,
@@ -10,39 +10,33 @@ pub struct LabelSelector {
     pub match_labels: Option<std::collections::BTreeMap<String, String>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for LabelSelector {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_match_expressions,
-            Key_match_labels,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl LabelSelector {
+    /// Returns this value with [`LabelSelector::match_expressions`] set to the given value.
+    #[must_use]
+    pub fn with_match_expressions(mut self, value: impl Into<Vec<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorRequirement>>) -> Self {
+        self.match_expressions = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`LabelSelector::match_labels`] set to the given value.
+    #[must_use]
+    pub fn with_match_labels(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.match_labels = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "matchExpressions" => Field::Key_match_expressions,
-                            "matchLabels" => Field::Key_match_labels,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for LabelSelector {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl LabelSelector {
+    const FIELDS: &'static [&'static str] = &[
+        "matchExpressions",
+        "matchLabels",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for LabelSelector {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,11 +50,11 @@ impl<'de> crate::serde::Deserialize<'de> for LabelSelector {
                 let mut value_match_expressions: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorRequirement>> = None;
                 let mut value_match_labels: Option<std::collections::BTreeMap<String, String>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("LabelSelector", LabelSelector::FIELDS))? {
                     match key {
-                        Field::Key_match_expressions => value_match_expressions = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_match_labels => value_match_labels = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_match_expressions = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_match_labels = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -69,21 +63,78 @@ impl<'de> crate::serde::Deserialize<'de> for LabelSelector {
                     match_labels: value_match_labels,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(LabelSelector {
+                    match_expressions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    match_labels: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "LabelSelector",
-            &[
-                "matchExpressions",
-                "matchLabels",
-            ],
+            LabelSelector::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, LabelSelector> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("LabelSelector")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_match_expressions = false;
+                let mut has_match_labels = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("LabelSelector", LabelSelector::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.match_expressions))?; has_match_expressions = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.match_labels))?; has_match_labels = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_match_expressions {
+                    self.0.match_expressions = None;
+                }
+                if !has_match_labels {
+                    self.0.match_labels = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.match_expressions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.match_labels))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "LabelSelector",
+            LabelSelector::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for LabelSelector {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("LabelSelector", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "matchExpressions", &self.match_expressions)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "matchLabels", &self.match_labels)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "LabelSelector",
             self.match_expressions.as_ref().map_or(0, |_| 1) +
@@ -99,10 +150,84 @@ impl crate::serde::Serialize for LabelSelector {
     }
 }
 
+/// A builder of [`LabelSelector`]. Created with [`LabelSelector::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct LabelSelectorBuilder {
+    match_expressions: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorRequirement>>,
+    match_labels: Option<std::collections::BTreeMap<String, String>>,
+}
+
+#[cfg(feature = "builder")]
+impl LabelSelector {
+    /// Starts building a `LabelSelector`. Its required fields must be set before [`LabelSelectorBuilder::build`] is called.
+    pub fn builder() -> LabelSelectorBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl LabelSelectorBuilder {
+    /// Sets [`LabelSelector::match_expressions`].
+    pub fn match_expressions(mut self, value: impl Into<Vec<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorRequirement>>) -> Self {
+        self.match_expressions = Some(value.into());
+        self
+    }
+
+    /// Sets [`LabelSelector::match_labels`].
+    pub fn match_labels(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.match_labels = Some(value.into());
+        self
+    }
+
+    /// Builds the `LabelSelector`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<LabelSelector, crate::MissingFieldError> {
+        Ok(LabelSelector {
+            match_expressions: self.match_expressions,
+            match_labels: self.match_labels,
+        })
+    }
+}
+
+/// The apply configuration of [`LabelSelector`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LabelSelectorApplyConfiguration {
+    /// See [`LabelSelector::match_expressions`].
+    pub match_expressions: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorRequirementApplyConfiguration>>,
+
+    /// See [`LabelSelector::match_labels`].
+    pub match_labels: Option<std::collections::BTreeMap<String, String>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for LabelSelectorApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "LabelSelectorApplyConfiguration",
+            self.match_expressions.as_ref().map_or(0, |_| 1) +
+            self.match_labels.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.match_expressions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "matchExpressions", value)?;
+        }
+        if let Some(value) = &self.match_labels {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "matchLabels", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for LabelSelector {
     fn schema_name() -> String {
-        "io.k8s.apimachinery.pkg.apis.meta.v1.LabelSelector".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apimachinery::pkg::apis::meta::v1::LabelSelector".to_owned()
+        }
+        else {
+            "io.k8s.apimachinery.pkg.apis.meta.v1.LabelSelector".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -113,8 +238,9 @@ impl crate::schemars::JsonSchema for LabelSelector {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "matchExpressions".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -128,8 +254,8 @@ impl crate::schemars::JsonSchema for LabelSelector {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "matchLabels".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -148,11 +274,22 @@ impl crate::schemars::JsonSchema for LabelSelector {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for LabelSelector {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(LabelSelector {
+            match_expressions: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            match_labels: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
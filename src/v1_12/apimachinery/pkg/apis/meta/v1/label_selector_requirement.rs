@@ -1,53 +1,64 @@
 // Generated from definition io.k8s.apimachinery.pkg.apis.meta.v1.LabelSelectorRequirement
 
 /// A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct LabelSelectorRequirement {
     /// key is the label key that the selector applies to.
     pub key: String,
 
     /// operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist.
-    pub operator: String,
+    pub operator: crate::apimachinery::pkg::apis::meta::v1::LabelSelectorOperator,
 
     /// values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch.
     pub values: Option<Vec<String>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for LabelSelectorRequirement {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_key,
-            Key_operator,
-            Key_values,
-            Other,
+impl LabelSelectorRequirement {
+    /// Constructs a `LabelSelectorRequirement` from its required fields. Its optional fields are unset.
+    pub fn new(key: impl Into<String>, operator: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorOperator>) -> Self {
+        LabelSelectorRequirement {
+            key: key.into(),
+            operator: operator.into(),
+            values: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl LabelSelectorRequirement {
+    /// Returns this value with [`LabelSelectorRequirement::key`] set to the given value.
+    #[must_use]
+    pub fn with_key(mut self, value: impl Into<String>) -> Self {
+        self.key = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`LabelSelectorRequirement::operator`] set to the given value.
+    #[must_use]
+    pub fn with_operator(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorOperator>) -> Self {
+        self.operator = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`LabelSelectorRequirement::values`] set to the given value.
+    #[must_use]
+    pub fn with_values(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.values = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "key" => Field::Key_key,
-                            "operator" => Field::Key_operator,
-                            "values" => Field::Key_values,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for LabelSelectorRequirement {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl LabelSelectorRequirement {
+    const FIELDS: &'static [&'static str] = &[
+        "key",
+        "operator",
+        "values",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for LabelSelectorRequirement {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -59,40 +70,104 @@ impl<'de> crate::serde::Deserialize<'de> for LabelSelectorRequirement {
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
                 let mut value_key: Option<String> = None;
-                let mut value_operator: Option<String> = None;
+                let mut value_operator: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorOperator> = None;
                 let mut value_values: Option<Vec<String>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("LabelSelectorRequirement", LabelSelectorRequirement::FIELDS))? {
                     match key {
-                        Field::Key_key => value_key = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_operator => value_operator = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_values => value_values = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_key = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_operator = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_values = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(LabelSelectorRequirement {
-                    key: value_key.ok_or_else(|| crate::serde::de::Error::missing_field("key"))?,
-                    operator: value_operator.ok_or_else(|| crate::serde::de::Error::missing_field("operator"))?,
+                    key: value_key.ok_or_else(|| crate::serde2::missing_field("LabelSelectorRequirement", "key", &["key", "operator"]))?,
+                    operator: value_operator.ok_or_else(|| crate::serde2::missing_field("LabelSelectorRequirement", "operator", &["key", "operator"]))?,
                     values: value_values,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(LabelSelectorRequirement {
+                    key: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    operator: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    values: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "LabelSelectorRequirement",
-            &[
-                "key",
-                "operator",
-                "values",
-            ],
+            LabelSelectorRequirement::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, LabelSelectorRequirement> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("LabelSelectorRequirement")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_key = false;
+                let mut has_operator = false;
+                let mut has_values = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("LabelSelectorRequirement", LabelSelectorRequirement::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.key))?; has_key = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.operator))?; has_operator = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.values))?; has_values = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_key {
+                    return Err(crate::serde2::missing_field("LabelSelectorRequirement", "key", &["key", "operator"]));
+                }
+                if !has_operator {
+                    return Err(crate::serde2::missing_field("LabelSelectorRequirement", "operator", &["key", "operator"]));
+                }
+                if !has_values {
+                    self.0.values = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.key))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.operator))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.values))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "LabelSelectorRequirement",
+            LabelSelectorRequirement::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for LabelSelectorRequirement {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("LabelSelectorRequirement", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "key", &self.key)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "operator", &self.operator)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "values", &self.values)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "LabelSelectorRequirement",
             2 +
@@ -107,10 +182,99 @@ impl crate::serde::Serialize for LabelSelectorRequirement {
     }
 }
 
+/// A builder of [`LabelSelectorRequirement`]. Created with [`LabelSelectorRequirement::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct LabelSelectorRequirementBuilder {
+    key: Option<String>,
+    operator: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorOperator>,
+    values: Option<Vec<String>>,
+}
+
+#[cfg(feature = "builder")]
+impl LabelSelectorRequirement {
+    /// Starts building a `LabelSelectorRequirement`. Its required fields must be set before [`LabelSelectorRequirementBuilder::build`] is called.
+    pub fn builder() -> LabelSelectorRequirementBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl LabelSelectorRequirementBuilder {
+    /// Sets [`LabelSelectorRequirement::key`].
+    pub fn key(mut self, value: impl Into<String>) -> Self {
+        self.key = Some(value.into());
+        self
+    }
+
+    /// Sets [`LabelSelectorRequirement::operator`].
+    pub fn operator(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorOperator>) -> Self {
+        self.operator = Some(value.into());
+        self
+    }
+
+    /// Sets [`LabelSelectorRequirement::values`].
+    pub fn values(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.values = Some(value.into());
+        self
+    }
+
+    /// Builds the `LabelSelectorRequirement`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<LabelSelectorRequirement, crate::MissingFieldError> {
+        Ok(LabelSelectorRequirement {
+            key: self.key.ok_or_else(|| crate::MissingFieldError::new("LabelSelectorRequirement", "key"))?,
+            operator: self.operator.ok_or_else(|| crate::MissingFieldError::new("LabelSelectorRequirement", "operator"))?,
+            values: self.values,
+        })
+    }
+}
+
+/// The apply configuration of [`LabelSelectorRequirement`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LabelSelectorRequirementApplyConfiguration {
+    /// See [`LabelSelectorRequirement::key`].
+    pub key: Option<String>,
+
+    /// See [`LabelSelectorRequirement::operator`].
+    pub operator: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorOperator>,
+
+    /// See [`LabelSelectorRequirement::values`].
+    pub values: Option<Vec<String>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for LabelSelectorRequirementApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "LabelSelectorRequirementApplyConfiguration",
+            self.key.as_ref().map_or(0, |_| 1) +
+            self.operator.as_ref().map_or(0, |_| 1) +
+            self.values.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.key {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "key", value)?;
+        }
+        if let Some(value) = &self.operator {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "operator", value)?;
+        }
+        if let Some(value) = &self.values {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "values", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for LabelSelectorRequirement {
     fn schema_name() -> String {
-        "io.k8s.apimachinery.pkg.apis.meta.v1.LabelSelectorRequirement".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apimachinery::pkg::apis::meta::v1::LabelSelectorRequirement".to_owned()
+        }
+        else {
+            "io.k8s.apimachinery.pkg.apis.meta.v1.LabelSelectorRequirement".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -121,8 +285,9 @@ impl crate::schemars::JsonSchema for LabelSelectorRequirement {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "key".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -132,19 +297,19 @@ impl crate::schemars::JsonSchema for LabelSelectorRequirement {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "operator".to_owned(),
-                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
-                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorOperator>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
                                 description: Some("operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist.".to_owned()),
                                 ..Default::default()
-                            })),
-                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
-                            ..Default::default()
-                        }),
-                    ),
-                    (
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
                         "values".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -163,8 +328,9 @@ impl crate::schemars::JsonSchema for LabelSelectorRequirement {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "key",
                     "operator",
@@ -175,3 +341,14 @@ impl crate::schemars::JsonSchema for LabelSelectorRequirement {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for LabelSelectorRequirement {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(LabelSelectorRequirement {
+            key: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            operator: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            values: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
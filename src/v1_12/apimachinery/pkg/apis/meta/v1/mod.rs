@@ -1,66 +1,143 @@
 
 mod api_group;
 pub use self::api_group::APIGroup;
+#[cfg(feature = "builder")]
+pub use self::api_group::APIGroupBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::api_group::APIGroupApplyConfiguration;
 
 mod api_group_list;
 pub use self::api_group_list::APIGroupList;
+#[cfg(feature = "builder")]
+pub use self::api_group_list::APIGroupListBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::api_group_list::APIGroupListApplyConfiguration;
 
 mod api_resource;
 pub use self::api_resource::APIResource;
+#[cfg(feature = "builder")]
+pub use self::api_resource::APIResourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::api_resource::APIResourceApplyConfiguration;
 
 mod api_resource_list;
 pub use self::api_resource_list::APIResourceList;
+#[cfg(feature = "builder")]
+pub use self::api_resource_list::APIResourceListBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::api_resource_list::APIResourceListApplyConfiguration;
 
 mod api_versions;
 pub use self::api_versions::APIVersions;
+#[cfg(feature = "builder")]
+pub use self::api_versions::APIVersionsBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::api_versions::APIVersionsApplyConfiguration;
 
 mod delete_options;
 pub use self::delete_options::DeleteOptions;
+#[cfg(feature = "builder")]
+pub use self::delete_options::DeleteOptionsBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::delete_options::DeleteOptionsApplyConfiguration;
 
 mod group_version_for_discovery;
 pub use self::group_version_for_discovery::GroupVersionForDiscovery;
+#[cfg(feature = "builder")]
+pub use self::group_version_for_discovery::GroupVersionForDiscoveryBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::group_version_for_discovery::GroupVersionForDiscoveryApplyConfiguration;
 
 mod initializer;
 pub use self::initializer::Initializer;
+#[cfg(feature = "builder")]
+pub use self::initializer::InitializerBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::initializer::InitializerApplyConfiguration;
 
 mod initializers;
 pub use self::initializers::Initializers;
+#[cfg(feature = "builder")]
+pub use self::initializers::InitializersBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::initializers::InitializersApplyConfiguration;
 
 mod label_selector;
 pub use self::label_selector::LabelSelector;
+#[cfg(feature = "builder")]
+pub use self::label_selector::LabelSelectorBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::label_selector::LabelSelectorApplyConfiguration;
+
+mod label_selector_operator;
+pub use self::label_selector_operator::LabelSelectorOperator;
 
 mod label_selector_requirement;
 pub use self::label_selector_requirement::LabelSelectorRequirement;
+#[cfg(feature = "builder")]
+pub use self::label_selector_requirement::LabelSelectorRequirementBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::label_selector_requirement::LabelSelectorRequirementApplyConfiguration;
 
 mod list_meta;
 pub use self::list_meta::ListMeta;
+#[cfg(feature = "builder")]
+pub use self::list_meta::ListMetaBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::list_meta::ListMetaApplyConfiguration;
 
 mod micro_time;
 pub use self::micro_time::MicroTime;
 
 mod object_meta;
 pub use self::object_meta::ObjectMeta;
+#[cfg(feature = "apply-configurations")]
+pub use self::object_meta::ObjectMetaApplyConfiguration;
 
 mod owner_reference;
 pub use self::owner_reference::OwnerReference;
+#[cfg(feature = "builder")]
+pub use self::owner_reference::OwnerReferenceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::owner_reference::OwnerReferenceApplyConfiguration;
 
 mod patch;
 pub use self::patch::Patch;
 
 mod preconditions;
 pub use self::preconditions::Preconditions;
+#[cfg(feature = "builder")]
+pub use self::preconditions::PreconditionsBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::preconditions::PreconditionsApplyConfiguration;
 
 mod server_address_by_client_cidr;
 pub use self::server_address_by_client_cidr::ServerAddressByClientCIDR;
+#[cfg(feature = "builder")]
+pub use self::server_address_by_client_cidr::ServerAddressByClientCIDRBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::server_address_by_client_cidr::ServerAddressByClientCIDRApplyConfiguration;
 
 mod status;
 pub use self::status::Status;
+#[cfg(feature = "builder")]
+pub use self::status::StatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::status::StatusApplyConfiguration;
 
 mod status_cause;
 pub use self::status_cause::StatusCause;
+#[cfg(feature = "builder")]
+pub use self::status_cause::StatusCauseBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::status_cause::StatusCauseApplyConfiguration;
 
 mod status_details;
 pub use self::status_details::StatusDetails;
+#[cfg(feature = "builder")]
+pub use self::status_details::StatusDetailsBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::status_details::StatusDetailsApplyConfiguration;
 
 mod time;
 pub use self::time::Time;
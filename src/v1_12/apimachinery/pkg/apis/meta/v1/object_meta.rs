@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.apimachinery.pkg.apis.meta.v1.ObjectMeta
 
 /// ObjectMeta is metadata that all persisted resources must have, which includes all objects users must create.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ObjectMeta {
     /// Annotations is an unstructured key value map stored with a resource that may be set by external tools to store and retrieve arbitrary metadata. They are not queryable and should be preserved when modifying objects. More info: http://kubernetes.io/docs/user-guide/annotations
     pub annotations: Option<std::collections::BTreeMap<String, String>>,
@@ -68,67 +68,150 @@ pub struct ObjectMeta {
     pub uid: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ObjectMeta {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_annotations,
-            Key_cluster_name,
-            Key_creation_timestamp,
-            Key_deletion_grace_period_seconds,
-            Key_deletion_timestamp,
-            Key_finalizers,
-            Key_generate_name,
-            Key_generation,
-            Key_initializers,
-            Key_labels,
-            Key_name,
-            Key_namespace,
-            Key_owner_references,
-            Key_resource_version,
-            Key_self_link,
-            Key_uid,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl ObjectMeta {
+    /// Returns this value with [`ObjectMeta::annotations`] set to the given value.
+    #[must_use]
+    pub fn with_annotations(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.annotations = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "annotations" => Field::Key_annotations,
-                            "clusterName" => Field::Key_cluster_name,
-                            "creationTimestamp" => Field::Key_creation_timestamp,
-                            "deletionGracePeriodSeconds" => Field::Key_deletion_grace_period_seconds,
-                            "deletionTimestamp" => Field::Key_deletion_timestamp,
-                            "finalizers" => Field::Key_finalizers,
-                            "generateName" => Field::Key_generate_name,
-                            "generation" => Field::Key_generation,
-                            "initializers" => Field::Key_initializers,
-                            "labels" => Field::Key_labels,
-                            "name" => Field::Key_name,
-                            "namespace" => Field::Key_namespace,
-                            "ownerReferences" => Field::Key_owner_references,
-                            "resourceVersion" => Field::Key_resource_version,
-                            "selfLink" => Field::Key_self_link,
-                            "uid" => Field::Key_uid,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`ObjectMeta::cluster_name`] set to the given value.
+    #[must_use]
+    pub fn with_cluster_name(mut self, value: impl Into<String>) -> Self {
+        self.cluster_name = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`ObjectMeta::creation_timestamp`] set to the given value.
+    #[must_use]
+    pub fn with_creation_timestamp(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.creation_timestamp = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ObjectMeta::deletion_grace_period_seconds`] set to the given value.
+    #[must_use]
+    pub fn with_deletion_grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.deletion_grace_period_seconds = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ObjectMeta::deletion_timestamp`] set to the given value.
+    #[must_use]
+    pub fn with_deletion_timestamp(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.deletion_timestamp = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ObjectMeta::finalizers`] set to the given value.
+    #[must_use]
+    pub fn with_finalizers(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.finalizers = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ObjectMeta::generate_name`] set to the given value.
+    #[must_use]
+    pub fn with_generate_name(mut self, value: impl Into<String>) -> Self {
+        self.generate_name = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ObjectMeta::generation`] set to the given value.
+    #[must_use]
+    pub fn with_generation(mut self, value: impl Into<i64>) -> Self {
+        self.generation = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ObjectMeta::initializers`] set to the given value.
+    #[must_use]
+    pub fn with_initializers(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Initializers>) -> Self {
+        self.initializers = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ObjectMeta::labels`] set to the given value.
+    #[must_use]
+    pub fn with_labels(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.labels = Some(value.into());
+        self
+    }
 
+    /// Returns this value with [`ObjectMeta::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ObjectMeta::namespace`] set to the given value.
+    #[must_use]
+    pub fn with_namespace(mut self, value: impl Into<String>) -> Self {
+        self.namespace = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ObjectMeta::owner_references`] set to the given value.
+    #[must_use]
+    pub fn with_owner_references(mut self, value: impl Into<Vec<crate::apimachinery::pkg::apis::meta::v1::OwnerReference>>) -> Self {
+        self.owner_references = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ObjectMeta::resource_version`] set to the given value.
+    #[must_use]
+    pub fn with_resource_version(mut self, value: impl Into<String>) -> Self {
+        self.resource_version = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ObjectMeta::self_link`] set to the given value.
+    #[must_use]
+    pub fn with_self_link(mut self, value: impl Into<String>) -> Self {
+        self.self_link = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ObjectMeta::uid`] set to the given value.
+    #[must_use]
+    pub fn with_uid(mut self, value: impl Into<String>) -> Self {
+        self.uid = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for ObjectMeta {
+    const FIELD_PATCH_STRATEGIES: &'static [crate::FieldPatchStrategy] = &[
+        crate::FieldPatchStrategy { field: "finalizers", merge: true, retain_keys: false, merge_key: None },
+        crate::FieldPatchStrategy { field: "ownerReferences", merge: true, retain_keys: false, merge_key: Some("uid") },
+    ];
+}
+
+impl ObjectMeta {
+    const FIELDS: &'static [&'static str] = &[
+        "annotations",
+        "clusterName",
+        "creationTimestamp",
+        "deletionGracePeriodSeconds",
+        "deletionTimestamp",
+        "finalizers",
+        "generateName",
+        "generation",
+        "initializers",
+        "labels",
+        "name",
+        "namespace",
+        "ownerReferences",
+        "resourceVersion",
+        "selfLink",
+        "uid",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for ObjectMeta {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -156,25 +239,25 @@ impl<'de> crate::serde::Deserialize<'de> for ObjectMeta {
                 let mut value_self_link: Option<String> = None;
                 let mut value_uid: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ObjectMeta", ObjectMeta::FIELDS))? {
                     match key {
-                        Field::Key_annotations => value_annotations = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_cluster_name => value_cluster_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_creation_timestamp => value_creation_timestamp = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_deletion_grace_period_seconds => value_deletion_grace_period_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_deletion_timestamp => value_deletion_timestamp = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_finalizers => value_finalizers = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_generate_name => value_generate_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_generation => value_generation = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_initializers => value_initializers = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_labels => value_labels = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_name => value_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_namespace => value_namespace = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_owner_references => value_owner_references = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_resource_version => value_resource_version = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_self_link => value_self_link = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_uid => value_uid = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_annotations = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_cluster_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_creation_timestamp = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_deletion_grace_period_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_deletion_timestamp = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_finalizers = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_generate_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(7) => value_generation = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(8) => value_initializers = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(9) => value_labels = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(10) => value_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(11) => value_namespace = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(12) => value_owner_references = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(13) => value_resource_version = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(14) => value_self_link = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(15) => value_uid = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -197,35 +280,190 @@ impl<'de> crate::serde::Deserialize<'de> for ObjectMeta {
                     uid: value_uid,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ObjectMeta {
+                    annotations: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    cluster_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    creation_timestamp: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    deletion_grace_period_seconds: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    deletion_timestamp: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    finalizers: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    generate_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    generation: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                    initializers: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?,
+                    labels: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?,
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?,
+                    namespace: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(11, &self))?,
+                    owner_references: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(12, &self))?,
+                    resource_version: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(13, &self))?,
+                    self_link: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(14, &self))?,
+                    uid: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(15, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "ObjectMeta",
-            &[
-                "annotations",
-                "clusterName",
-                "creationTimestamp",
-                "deletionGracePeriodSeconds",
-                "deletionTimestamp",
-                "finalizers",
-                "generateName",
-                "generation",
-                "initializers",
-                "labels",
-                "name",
-                "namespace",
-                "ownerReferences",
-                "resourceVersion",
-                "selfLink",
-                "uid",
-            ],
+            ObjectMeta::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ObjectMeta> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ObjectMeta")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_annotations = false;
+                let mut has_cluster_name = false;
+                let mut has_creation_timestamp = false;
+                let mut has_deletion_grace_period_seconds = false;
+                let mut has_deletion_timestamp = false;
+                let mut has_finalizers = false;
+                let mut has_generate_name = false;
+                let mut has_generation = false;
+                let mut has_initializers = false;
+                let mut has_labels = false;
+                let mut has_name = false;
+                let mut has_namespace = false;
+                let mut has_owner_references = false;
+                let mut has_resource_version = false;
+                let mut has_self_link = false;
+                let mut has_uid = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ObjectMeta", ObjectMeta::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.annotations))?; has_annotations = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.cluster_name))?; has_cluster_name = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.creation_timestamp))?; has_creation_timestamp = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.deletion_grace_period_seconds))?; has_deletion_grace_period_seconds = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.deletion_timestamp))?; has_deletion_timestamp = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.finalizers))?; has_finalizers = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.generate_name))?; has_generate_name = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.generation))?; has_generation = true; },
+                        Some(8) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.initializers))?; has_initializers = true; },
+                        Some(9) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.labels))?; has_labels = true; },
+                        Some(10) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.name))?; has_name = true; },
+                        Some(11) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.namespace))?; has_namespace = true; },
+                        Some(12) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.owner_references))?; has_owner_references = true; },
+                        Some(13) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.resource_version))?; has_resource_version = true; },
+                        Some(14) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.self_link))?; has_self_link = true; },
+                        Some(15) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.uid))?; has_uid = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_annotations {
+                    self.0.annotations = None;
+                }
+                if !has_cluster_name {
+                    self.0.cluster_name = None;
+                }
+                if !has_creation_timestamp {
+                    self.0.creation_timestamp = None;
+                }
+                if !has_deletion_grace_period_seconds {
+                    self.0.deletion_grace_period_seconds = None;
+                }
+                if !has_deletion_timestamp {
+                    self.0.deletion_timestamp = None;
+                }
+                if !has_finalizers {
+                    self.0.finalizers = None;
+                }
+                if !has_generate_name {
+                    self.0.generate_name = None;
+                }
+                if !has_generation {
+                    self.0.generation = None;
+                }
+                if !has_initializers {
+                    self.0.initializers = None;
+                }
+                if !has_labels {
+                    self.0.labels = None;
+                }
+                if !has_name {
+                    self.0.name = None;
+                }
+                if !has_namespace {
+                    self.0.namespace = None;
+                }
+                if !has_owner_references {
+                    self.0.owner_references = None;
+                }
+                if !has_resource_version {
+                    self.0.resource_version = None;
+                }
+                if !has_self_link {
+                    self.0.self_link = None;
+                }
+                if !has_uid {
+                    self.0.uid = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.annotations))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.cluster_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.creation_timestamp))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.deletion_grace_period_seconds))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.deletion_timestamp))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.finalizers))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.generate_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.generation))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.initializers))?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.labels))?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.namespace))?.ok_or_else(|| crate::serde::de::Error::invalid_length(11, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.owner_references))?.ok_or_else(|| crate::serde::de::Error::invalid_length(12, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.resource_version))?.ok_or_else(|| crate::serde::de::Error::invalid_length(13, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.self_link))?.ok_or_else(|| crate::serde::de::Error::invalid_length(14, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.uid))?.ok_or_else(|| crate::serde::de::Error::invalid_length(15, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ObjectMeta",
+            ObjectMeta::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ObjectMeta {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ObjectMeta", 16)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "annotations", &self.annotations)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clusterName", &self.cluster_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "creationTimestamp", &self.creation_timestamp)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "deletionGracePeriodSeconds", &self.deletion_grace_period_seconds)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "deletionTimestamp", &self.deletion_timestamp)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "finalizers", &self.finalizers)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "generateName", &self.generate_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "generation", &self.generation)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "initializers", &self.initializers)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "labels", &self.labels)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "namespace", &self.namespace)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ownerReferences", &self.owner_references)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resourceVersion", &self.resource_version)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selfLink", &self.self_link)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "uid", &self.uid)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ObjectMeta",
             self.annotations.as_ref().map_or(0, |_| 1) +
@@ -297,10 +535,142 @@ impl crate::serde::Serialize for ObjectMeta {
     }
 }
 
+/// The apply configuration of [`ObjectMeta`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ObjectMetaApplyConfiguration {
+    /// See [`ObjectMeta::annotations`].
+    pub annotations: Option<std::collections::BTreeMap<String, String>>,
+
+    /// See [`ObjectMeta::cluster_name`].
+    pub cluster_name: Option<String>,
+
+    /// See [`ObjectMeta::creation_timestamp`].
+    pub creation_timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+
+    /// See [`ObjectMeta::deletion_grace_period_seconds`].
+    pub deletion_grace_period_seconds: Option<i64>,
+
+    /// See [`ObjectMeta::deletion_timestamp`].
+    pub deletion_timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+
+    /// See [`ObjectMeta::finalizers`].
+    pub finalizers: Option<Vec<String>>,
+
+    /// See [`ObjectMeta::generate_name`].
+    pub generate_name: Option<String>,
+
+    /// See [`ObjectMeta::generation`].
+    pub generation: Option<i64>,
+
+    /// See [`ObjectMeta::initializers`].
+    pub initializers: Option<crate::apimachinery::pkg::apis::meta::v1::InitializersApplyConfiguration>,
+
+    /// See [`ObjectMeta::labels`].
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+
+    /// See [`ObjectMeta::name`].
+    pub name: Option<String>,
+
+    /// See [`ObjectMeta::namespace`].
+    pub namespace: Option<String>,
+
+    /// See [`ObjectMeta::owner_references`].
+    pub owner_references: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::OwnerReferenceApplyConfiguration>>,
+
+    /// See [`ObjectMeta::resource_version`].
+    pub resource_version: Option<String>,
+
+    /// See [`ObjectMeta::self_link`].
+    pub self_link: Option<String>,
+
+    /// See [`ObjectMeta::uid`].
+    pub uid: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ObjectMetaApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ObjectMetaApplyConfiguration",
+            self.annotations.as_ref().map_or(0, |_| 1) +
+            self.cluster_name.as_ref().map_or(0, |_| 1) +
+            self.creation_timestamp.as_ref().map_or(0, |_| 1) +
+            self.deletion_grace_period_seconds.as_ref().map_or(0, |_| 1) +
+            self.deletion_timestamp.as_ref().map_or(0, |_| 1) +
+            self.finalizers.as_ref().map_or(0, |_| 1) +
+            self.generate_name.as_ref().map_or(0, |_| 1) +
+            self.generation.as_ref().map_or(0, |_| 1) +
+            self.initializers.as_ref().map_or(0, |_| 1) +
+            self.labels.as_ref().map_or(0, |_| 1) +
+            self.name.as_ref().map_or(0, |_| 1) +
+            self.namespace.as_ref().map_or(0, |_| 1) +
+            self.owner_references.as_ref().map_or(0, |_| 1) +
+            self.resource_version.as_ref().map_or(0, |_| 1) +
+            self.self_link.as_ref().map_or(0, |_| 1) +
+            self.uid.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.annotations {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "annotations", value)?;
+        }
+        if let Some(value) = &self.cluster_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clusterName", value)?;
+        }
+        if let Some(value) = &self.creation_timestamp {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "creationTimestamp", value)?;
+        }
+        if let Some(value) = &self.deletion_grace_period_seconds {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "deletionGracePeriodSeconds", value)?;
+        }
+        if let Some(value) = &self.deletion_timestamp {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "deletionTimestamp", value)?;
+        }
+        if let Some(value) = &self.finalizers {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "finalizers", value)?;
+        }
+        if let Some(value) = &self.generate_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "generateName", value)?;
+        }
+        if let Some(value) = &self.generation {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "generation", value)?;
+        }
+        if let Some(value) = &self.initializers {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "initializers", value)?;
+        }
+        if let Some(value) = &self.labels {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "labels", value)?;
+        }
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        if let Some(value) = &self.namespace {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "namespace", value)?;
+        }
+        if let Some(value) = &self.owner_references {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ownerReferences", value)?;
+        }
+        if let Some(value) = &self.resource_version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resourceVersion", value)?;
+        }
+        if let Some(value) = &self.self_link {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selfLink", value)?;
+        }
+        if let Some(value) = &self.uid {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "uid", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ObjectMeta {
     fn schema_name() -> String {
-        "io.k8s.apimachinery.pkg.apis.meta.v1.ObjectMeta".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apimachinery::pkg::apis::meta::v1::ObjectMeta".to_owned()
+        }
+        else {
+            "io.k8s.apimachinery.pkg.apis.meta.v1.ObjectMeta".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -311,8 +681,9 @@ impl crate::schemars::JsonSchema for ObjectMeta {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "annotations".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -331,8 +702,8 @@ impl crate::schemars::JsonSchema for ObjectMeta {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "clusterName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -342,8 +713,8 @@ impl crate::schemars::JsonSchema for ObjectMeta {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "creationTimestamp".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::Time>().into_object();
@@ -353,8 +724,8 @@ impl crate::schemars::JsonSchema for ObjectMeta {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "deletionGracePeriodSeconds".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -365,8 +736,8 @@ impl crate::schemars::JsonSchema for ObjectMeta {
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "deletionTimestamp".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::Time>().into_object();
@@ -376,8 +747,8 @@ impl crate::schemars::JsonSchema for ObjectMeta {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "finalizers".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -396,8 +767,8 @@ impl crate::schemars::JsonSchema for ObjectMeta {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "generateName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -407,8 +778,8 @@ impl crate::schemars::JsonSchema for ObjectMeta {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "generation".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -419,8 +790,8 @@ impl crate::schemars::JsonSchema for ObjectMeta {
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "initializers".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::Initializers>().into_object();
@@ -430,8 +801,8 @@ impl crate::schemars::JsonSchema for ObjectMeta {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "labels".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -450,8 +821,8 @@ impl crate::schemars::JsonSchema for ObjectMeta {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -461,8 +832,8 @@ impl crate::schemars::JsonSchema for ObjectMeta {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "namespace".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -472,8 +843,8 @@ impl crate::schemars::JsonSchema for ObjectMeta {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "ownerReferences".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -487,8 +858,8 @@ impl crate::schemars::JsonSchema for ObjectMeta {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "resourceVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -498,8 +869,8 @@ impl crate::schemars::JsonSchema for ObjectMeta {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "selfLink".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -509,8 +880,8 @@ impl crate::schemars::JsonSchema for ObjectMeta {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "uid".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -520,11 +891,36 @@ impl crate::schemars::JsonSchema for ObjectMeta {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ObjectMeta {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ObjectMeta {
+            annotations: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            cluster_name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            creation_timestamp: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            deletion_grace_period_seconds: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            deletion_timestamp: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            finalizers: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            generate_name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            generation: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            initializers: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            labels: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            namespace: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            owner_references: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            resource_version: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            self_link: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            uid: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.apimachinery.pkg.apis.meta.v1.OwnerReference
 
 /// OwnerReference contains enough information to let you identify an owning object. Currently, an owning object must be in the same namespace, so there is no namespace field.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct OwnerReference {
     /// API version of the referent.
     pub api_version: String,
@@ -22,47 +22,79 @@ pub struct OwnerReference {
     pub uid: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for OwnerReference {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_api_version,
-            Key_block_owner_deletion,
-            Key_controller,
-            Key_kind,
-            Key_name,
-            Key_uid,
-            Other,
+impl OwnerReference {
+    /// Constructs a `OwnerReference` from its required fields. Its optional fields are unset.
+    pub fn new(api_version: impl Into<String>, kind: impl Into<String>, name: impl Into<String>, uid: impl Into<String>) -> Self {
+        OwnerReference {
+            api_version: api_version.into(),
+            block_owner_deletion: None,
+            controller: None,
+            kind: kind.into(),
+            name: name.into(),
+            uid: uid.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl OwnerReference {
+    /// Returns this value with [`OwnerReference::api_version`] set to the given value.
+    #[must_use]
+    pub fn with_api_version(mut self, value: impl Into<String>) -> Self {
+        self.api_version = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`OwnerReference::block_owner_deletion`] set to the given value.
+    #[must_use]
+    pub fn with_block_owner_deletion(mut self, value: impl Into<bool>) -> Self {
+        self.block_owner_deletion = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`OwnerReference::controller`] set to the given value.
+    #[must_use]
+    pub fn with_controller(mut self, value: impl Into<bool>) -> Self {
+        self.controller = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "apiVersion" => Field::Key_api_version,
-                            "blockOwnerDeletion" => Field::Key_block_owner_deletion,
-                            "controller" => Field::Key_controller,
-                            "kind" => Field::Key_kind,
-                            "name" => Field::Key_name,
-                            "uid" => Field::Key_uid,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`OwnerReference::kind`] set to the given value.
+    #[must_use]
+    pub fn with_kind(mut self, value: impl Into<String>) -> Self {
+        self.kind = value.into();
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`OwnerReference::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
+
+    /// Returns this value with [`OwnerReference::uid`] set to the given value.
+    #[must_use]
+    pub fn with_uid(mut self, value: impl Into<String>) -> Self {
+        self.uid = value.into();
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for OwnerReference {}
+
+impl OwnerReference {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "blockOwnerDeletion",
+        "controller",
+        "kind",
+        "name",
+        "uid",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for OwnerReference {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -80,46 +112,131 @@ impl<'de> crate::serde::Deserialize<'de> for OwnerReference {
                 let mut value_name: Option<String> = None;
                 let mut value_uid: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("OwnerReference", OwnerReference::FIELDS))? {
                     match key {
-                        Field::Key_api_version => value_api_version = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_block_owner_deletion => value_block_owner_deletion = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_controller => value_controller = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_kind => value_kind = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_name => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_uid => value_uid = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_api_version = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_block_owner_deletion = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_controller = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_kind = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(4) => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(5) => value_uid = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(OwnerReference {
-                    api_version: value_api_version.ok_or_else(|| crate::serde::de::Error::missing_field("apiVersion"))?,
+                    api_version: value_api_version.ok_or_else(|| crate::serde2::missing_field("OwnerReference", "apiVersion", &["apiVersion", "kind", "name", "uid"]))?,
                     block_owner_deletion: value_block_owner_deletion,
                     controller: value_controller,
-                    kind: value_kind.ok_or_else(|| crate::serde::de::Error::missing_field("kind"))?,
-                    name: value_name.ok_or_else(|| crate::serde::de::Error::missing_field("name"))?,
-                    uid: value_uid.ok_or_else(|| crate::serde::de::Error::missing_field("uid"))?,
+                    kind: value_kind.ok_or_else(|| crate::serde2::missing_field("OwnerReference", "kind", &["apiVersion", "kind", "name", "uid"]))?,
+                    name: value_name.ok_or_else(|| crate::serde2::missing_field("OwnerReference", "name", &["apiVersion", "kind", "name", "uid"]))?,
+                    uid: value_uid.ok_or_else(|| crate::serde2::missing_field("OwnerReference", "uid", &["apiVersion", "kind", "name", "uid"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(OwnerReference {
+                    api_version: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    block_owner_deletion: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    controller: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    kind: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    uid: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "OwnerReference",
-            &[
-                "apiVersion",
-                "blockOwnerDeletion",
-                "controller",
-                "kind",
-                "name",
-                "uid",
-            ],
+            OwnerReference::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, OwnerReference> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("OwnerReference")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_api_version = false;
+                let mut has_block_owner_deletion = false;
+                let mut has_controller = false;
+                let mut has_kind = false;
+                let mut has_name = false;
+                let mut has_uid = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("OwnerReference", OwnerReference::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.api_version))?; has_api_version = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.block_owner_deletion))?; has_block_owner_deletion = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.controller))?; has_controller = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.kind))?; has_kind = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.name))?; has_name = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.uid))?; has_uid = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_api_version {
+                    return Err(crate::serde2::missing_field("OwnerReference", "apiVersion", &["apiVersion", "kind", "name", "uid"]));
+                }
+                if !has_block_owner_deletion {
+                    self.0.block_owner_deletion = None;
+                }
+                if !has_controller {
+                    self.0.controller = None;
+                }
+                if !has_kind {
+                    return Err(crate::serde2::missing_field("OwnerReference", "kind", &["apiVersion", "kind", "name", "uid"]));
+                }
+                if !has_name {
+                    return Err(crate::serde2::missing_field("OwnerReference", "name", &["apiVersion", "kind", "name", "uid"]));
+                }
+                if !has_uid {
+                    return Err(crate::serde2::missing_field("OwnerReference", "uid", &["apiVersion", "kind", "name", "uid"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.api_version))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.block_owner_deletion))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.controller))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.kind))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.uid))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "OwnerReference",
+            OwnerReference::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for OwnerReference {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("OwnerReference", 6)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", &self.api_version)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "blockOwnerDeletion", &self.block_owner_deletion)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "controller", &self.controller)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", &self.kind)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "uid", &self.uid)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "OwnerReference",
             4 +
@@ -140,10 +257,144 @@ impl crate::serde::Serialize for OwnerReference {
     }
 }
 
+/// A builder of [`OwnerReference`]. Created with [`OwnerReference::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct OwnerReferenceBuilder {
+    api_version: Option<String>,
+    block_owner_deletion: Option<bool>,
+    controller: Option<bool>,
+    kind: Option<String>,
+    name: Option<String>,
+    uid: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl OwnerReference {
+    /// Starts building a `OwnerReference`. Its required fields must be set before [`OwnerReferenceBuilder::build`] is called.
+    pub fn builder() -> OwnerReferenceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl OwnerReferenceBuilder {
+    /// Sets [`OwnerReference::api_version`].
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`OwnerReference::block_owner_deletion`].
+    pub fn block_owner_deletion(mut self, value: impl Into<bool>) -> Self {
+        self.block_owner_deletion = Some(value.into());
+        self
+    }
+
+    /// Sets [`OwnerReference::controller`].
+    pub fn controller(mut self, value: impl Into<bool>) -> Self {
+        self.controller = Some(value.into());
+        self
+    }
+
+    /// Sets [`OwnerReference::kind`].
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`OwnerReference::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`OwnerReference::uid`].
+    pub fn uid(mut self, value: impl Into<String>) -> Self {
+        self.uid = Some(value.into());
+        self
+    }
+
+    /// Builds the `OwnerReference`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<OwnerReference, crate::MissingFieldError> {
+        Ok(OwnerReference {
+            api_version: self.api_version.ok_or_else(|| crate::MissingFieldError::new("OwnerReference", "apiVersion"))?,
+            block_owner_deletion: self.block_owner_deletion,
+            controller: self.controller,
+            kind: self.kind.ok_or_else(|| crate::MissingFieldError::new("OwnerReference", "kind"))?,
+            name: self.name.ok_or_else(|| crate::MissingFieldError::new("OwnerReference", "name"))?,
+            uid: self.uid.ok_or_else(|| crate::MissingFieldError::new("OwnerReference", "uid"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`OwnerReference`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OwnerReferenceApplyConfiguration {
+    /// See [`OwnerReference::api_version`].
+    pub api_version: Option<String>,
+
+    /// See [`OwnerReference::block_owner_deletion`].
+    pub block_owner_deletion: Option<bool>,
+
+    /// See [`OwnerReference::controller`].
+    pub controller: Option<bool>,
+
+    /// See [`OwnerReference::kind`].
+    pub kind: Option<String>,
+
+    /// See [`OwnerReference::name`].
+    pub name: Option<String>,
+
+    /// See [`OwnerReference::uid`].
+    pub uid: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for OwnerReferenceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "OwnerReferenceApplyConfiguration",
+            self.api_version.as_ref().map_or(0, |_| 1) +
+            self.block_owner_deletion.as_ref().map_or(0, |_| 1) +
+            self.controller.as_ref().map_or(0, |_| 1) +
+            self.kind.as_ref().map_or(0, |_| 1) +
+            self.name.as_ref().map_or(0, |_| 1) +
+            self.uid.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.api_version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", value)?;
+        }
+        if let Some(value) = &self.block_owner_deletion {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "blockOwnerDeletion", value)?;
+        }
+        if let Some(value) = &self.controller {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "controller", value)?;
+        }
+        if let Some(value) = &self.kind {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", value)?;
+        }
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        if let Some(value) = &self.uid {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "uid", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for OwnerReference {
     fn schema_name() -> String {
-        "io.k8s.apimachinery.pkg.apis.meta.v1.OwnerReference".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apimachinery::pkg::apis::meta::v1::OwnerReference".to_owned()
+        }
+        else {
+            "io.k8s.apimachinery.pkg.apis.meta.v1.OwnerReference".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -154,8 +405,9 @@ impl crate::schemars::JsonSchema for OwnerReference {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "apiVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -165,8 +417,8 @@ impl crate::schemars::JsonSchema for OwnerReference {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "blockOwnerDeletion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -176,8 +428,8 @@ impl crate::schemars::JsonSchema for OwnerReference {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "controller".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -187,8 +439,8 @@ impl crate::schemars::JsonSchema for OwnerReference {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "kind".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -198,8 +450,8 @@ impl crate::schemars::JsonSchema for OwnerReference {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -209,8 +461,8 @@ impl crate::schemars::JsonSchema for OwnerReference {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "uid".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -220,8 +472,9 @@ impl crate::schemars::JsonSchema for OwnerReference {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "apiVersion",
                     "kind",
@@ -234,3 +487,17 @@ impl crate::schemars::JsonSchema for OwnerReference {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for OwnerReference {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(OwnerReference {
+            api_version: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            block_owner_deletion: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            controller: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            kind: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            uid: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
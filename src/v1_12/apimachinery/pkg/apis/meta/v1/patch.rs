@@ -21,7 +21,12 @@ impl crate::serde::Serialize for Patch {
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Patch {
     fn schema_name() -> String {
-        "io.k8s.apimachinery.pkg.apis.meta.v1.Patch".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apimachinery::pkg::apis::meta::v1::Patch".to_owned()
+        }
+        else {
+            "io.k8s.apimachinery.pkg.apis.meta.v1.Patch".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -35,3 +40,14 @@ impl crate::schemars::JsonSchema for Patch {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for Patch {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        Ok(match u.int_in_range(0..=2_u8)? {
+            0 => Patch::Json(crate::arbitrary2::vec(u, crate::arbitrary2::json_value)?),
+            1 => Patch::Merge(crate::arbitrary2::json_value(u)?),
+            _ => Patch::StrategicMerge(crate::arbitrary2::json_value(u)?),
+        })
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.apimachinery.pkg.util.intstr.IntOrString
 
 /// IntOrString is a type that can hold an int32 or a string.  When used in JSON or YAML marshalling and unmarshalling, it produces or consumes the inner type.  This allows you to have, for example, a JSON field that can accept a name or number.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum IntOrString {
     Int(i32),
     String(String),
@@ -56,14 +56,36 @@ impl<'de> crate::serde::Deserialize<'de> for IntOrString {
             fn visit_string<E>(self, v: String) -> Result<Self::Value, E> where E: crate::serde::de::Error {
                 Ok(IntOrString::String(v))
             }
+
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::EnumAccess<'de> {
+                let (variant, value) = crate::serde::de::EnumAccess::variant::<u32>(data)?;
+                match variant {
+                    0 => Ok(IntOrString::Int(crate::serde::de::VariantAccess::newtype_variant(value)?)),
+                    1 => Ok(IntOrString::String(crate::serde::de::VariantAccess::newtype_variant(value)?)),
+                    _ => Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Unsigned(variant.into()), &"variant index 0 <= i < 2")),
+                }
+            }
         }
 
-        deserializer.deserialize_any(Visitor)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(Visitor)
+        }
+        else {
+            // Formats that are not self-describing cannot deserialize untagged values, so the variant is serialized explicitly.
+            deserializer.deserialize_enum("IntOrString", &["Int", "String"], Visitor)
+        }
     }
 }
 
 impl crate::serde::Serialize for IntOrString {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            return match self {
+                IntOrString::Int(i) => serializer.serialize_newtype_variant("IntOrString", 0, "Int", i),
+                IntOrString::String(s) => serializer.serialize_newtype_variant("IntOrString", 1, "String", s),
+            };
+        }
+
         match self {
             IntOrString::Int(i) => i.serialize(serializer),
             IntOrString::String(s) => s.serialize(serializer),
@@ -74,7 +96,12 @@ impl crate::serde::Serialize for IntOrString {
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for IntOrString {
     fn schema_name() -> String {
-        "io.k8s.apimachinery.pkg.util.intstr.IntOrString".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apimachinery::pkg::util::intstr::IntOrString".to_owned()
+        }
+        else {
+            "io.k8s.apimachinery.pkg.util.intstr.IntOrString".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -89,3 +116,10 @@ impl crate::schemars::JsonSchema for IntOrString {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for IntOrString {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        Ok(if u.arbitrary()? { IntOrString::Int(u.arbitrary()?) } else { IntOrString::String(u.arbitrary()?) })
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.apimachinery.pkg.version.Info
 
 /// Info contains versioning information. how we'll want to distribute that information.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct Info {
     pub build_date: String,
 
@@ -22,53 +22,106 @@ pub struct Info {
     pub platform: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for Info {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_build_date,
-            Key_compiler,
-            Key_git_commit,
-            Key_git_tree_state,
-            Key_git_version,
-            Key_go_version,
-            Key_major,
-            Key_minor,
-            Key_platform,
-            Other,
+impl Info {
+    /// Constructs a `Info` from its required fields. Its optional fields are unset.
+    pub fn new(build_date: impl Into<String>, compiler: impl Into<String>, git_commit: impl Into<String>, git_tree_state: impl Into<String>, git_version: impl Into<String>, go_version: impl Into<String>, major: impl Into<String>, minor: impl Into<String>, platform: impl Into<String>) -> Self {
+        Info {
+            build_date: build_date.into(),
+            compiler: compiler.into(),
+            git_commit: git_commit.into(),
+            git_tree_state: git_tree_state.into(),
+            git_version: git_version.into(),
+            go_version: go_version.into(),
+            major: major.into(),
+            minor: minor.into(),
+            platform: platform.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl Info {
+    /// Returns this value with [`Info::build_date`] set to the given value.
+    #[must_use]
+    pub fn with_build_date(mut self, value: impl Into<String>) -> Self {
+        self.build_date = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`Info::compiler`] set to the given value.
+    #[must_use]
+    pub fn with_compiler(mut self, value: impl Into<String>) -> Self {
+        self.compiler = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`Info::git_commit`] set to the given value.
+    #[must_use]
+    pub fn with_git_commit(mut self, value: impl Into<String>) -> Self {
+        self.git_commit = value.into();
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "buildDate" => Field::Key_build_date,
-                            "compiler" => Field::Key_compiler,
-                            "gitCommit" => Field::Key_git_commit,
-                            "gitTreeState" => Field::Key_git_tree_state,
-                            "gitVersion" => Field::Key_git_version,
-                            "goVersion" => Field::Key_go_version,
-                            "major" => Field::Key_major,
-                            "minor" => Field::Key_minor,
-                            "platform" => Field::Key_platform,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`Info::git_tree_state`] set to the given value.
+    #[must_use]
+    pub fn with_git_tree_state(mut self, value: impl Into<String>) -> Self {
+        self.git_tree_state = value.into();
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`Info::git_version`] set to the given value.
+    #[must_use]
+    pub fn with_git_version(mut self, value: impl Into<String>) -> Self {
+        self.git_version = value.into();
+        self
+    }
+
+    /// Returns this value with [`Info::go_version`] set to the given value.
+    #[must_use]
+    pub fn with_go_version(mut self, value: impl Into<String>) -> Self {
+        self.go_version = value.into();
+        self
+    }
+
+    /// Returns this value with [`Info::major`] set to the given value.
+    #[must_use]
+    pub fn with_major(mut self, value: impl Into<String>) -> Self {
+        self.major = value.into();
+        self
+    }
+
+    /// Returns this value with [`Info::minor`] set to the given value.
+    #[must_use]
+    pub fn with_minor(mut self, value: impl Into<String>) -> Self {
+        self.minor = value.into();
+        self
+    }
+
+    /// Returns this value with [`Info::platform`] set to the given value.
+    #[must_use]
+    pub fn with_platform(mut self, value: impl Into<String>) -> Self {
+        self.platform = value.into();
+        self
+    }
+}
 
+impl crate::MergeStrategyInfo for Info {}
+
+impl Info {
+    const FIELDS: &'static [&'static str] = &[
+        "buildDate",
+        "compiler",
+        "gitCommit",
+        "gitTreeState",
+        "gitVersion",
+        "goVersion",
+        "major",
+        "minor",
+        "platform",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for Info {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -89,55 +142,161 @@ impl<'de> crate::serde::Deserialize<'de> for Info {
                 let mut value_minor: Option<String> = None;
                 let mut value_platform: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Info", Info::FIELDS))? {
                     match key {
-                        Field::Key_build_date => value_build_date = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_compiler => value_compiler = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_git_commit => value_git_commit = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_git_tree_state => value_git_tree_state = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_git_version => value_git_version = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_go_version => value_go_version = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_major => value_major = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_minor => value_minor = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_platform => value_platform = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_build_date = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_compiler = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_git_commit = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_git_tree_state = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(4) => value_git_version = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(5) => value_go_version = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(6) => value_major = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(7) => value_minor = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(8) => value_platform = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(Info {
-                    build_date: value_build_date.ok_or_else(|| crate::serde::de::Error::missing_field("buildDate"))?,
-                    compiler: value_compiler.ok_or_else(|| crate::serde::de::Error::missing_field("compiler"))?,
-                    git_commit: value_git_commit.ok_or_else(|| crate::serde::de::Error::missing_field("gitCommit"))?,
-                    git_tree_state: value_git_tree_state.ok_or_else(|| crate::serde::de::Error::missing_field("gitTreeState"))?,
-                    git_version: value_git_version.ok_or_else(|| crate::serde::de::Error::missing_field("gitVersion"))?,
-                    go_version: value_go_version.ok_or_else(|| crate::serde::de::Error::missing_field("goVersion"))?,
-                    major: value_major.ok_or_else(|| crate::serde::de::Error::missing_field("major"))?,
-                    minor: value_minor.ok_or_else(|| crate::serde::de::Error::missing_field("minor"))?,
-                    platform: value_platform.ok_or_else(|| crate::serde::de::Error::missing_field("platform"))?,
+                    build_date: value_build_date.ok_or_else(|| crate::serde2::missing_field("Info", "buildDate", &["buildDate", "compiler", "gitCommit", "gitTreeState", "gitVersion", "goVersion", "major", "minor", "platform"]))?,
+                    compiler: value_compiler.ok_or_else(|| crate::serde2::missing_field("Info", "compiler", &["buildDate", "compiler", "gitCommit", "gitTreeState", "gitVersion", "goVersion", "major", "minor", "platform"]))?,
+                    git_commit: value_git_commit.ok_or_else(|| crate::serde2::missing_field("Info", "gitCommit", &["buildDate", "compiler", "gitCommit", "gitTreeState", "gitVersion", "goVersion", "major", "minor", "platform"]))?,
+                    git_tree_state: value_git_tree_state.ok_or_else(|| crate::serde2::missing_field("Info", "gitTreeState", &["buildDate", "compiler", "gitCommit", "gitTreeState", "gitVersion", "goVersion", "major", "minor", "platform"]))?,
+                    git_version: value_git_version.ok_or_else(|| crate::serde2::missing_field("Info", "gitVersion", &["buildDate", "compiler", "gitCommit", "gitTreeState", "gitVersion", "goVersion", "major", "minor", "platform"]))?,
+                    go_version: value_go_version.ok_or_else(|| crate::serde2::missing_field("Info", "goVersion", &["buildDate", "compiler", "gitCommit", "gitTreeState", "gitVersion", "goVersion", "major", "minor", "platform"]))?,
+                    major: value_major.ok_or_else(|| crate::serde2::missing_field("Info", "major", &["buildDate", "compiler", "gitCommit", "gitTreeState", "gitVersion", "goVersion", "major", "minor", "platform"]))?,
+                    minor: value_minor.ok_or_else(|| crate::serde2::missing_field("Info", "minor", &["buildDate", "compiler", "gitCommit", "gitTreeState", "gitVersion", "goVersion", "major", "minor", "platform"]))?,
+                    platform: value_platform.ok_or_else(|| crate::serde2::missing_field("Info", "platform", &["buildDate", "compiler", "gitCommit", "gitTreeState", "gitVersion", "goVersion", "major", "minor", "platform"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(Info {
+                    build_date: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    compiler: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    git_commit: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    git_tree_state: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    git_version: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    go_version: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    major: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    minor: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                    platform: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "Info",
-            &[
-                "buildDate",
-                "compiler",
-                "gitCommit",
-                "gitTreeState",
-                "gitVersion",
-                "goVersion",
-                "major",
-                "minor",
-                "platform",
-            ],
+            Info::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, Info> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("Info")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_build_date = false;
+                let mut has_compiler = false;
+                let mut has_git_commit = false;
+                let mut has_git_tree_state = false;
+                let mut has_git_version = false;
+                let mut has_go_version = false;
+                let mut has_major = false;
+                let mut has_minor = false;
+                let mut has_platform = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Info", Info::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.build_date))?; has_build_date = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.compiler))?; has_compiler = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.git_commit))?; has_git_commit = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.git_tree_state))?; has_git_tree_state = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.git_version))?; has_git_version = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.go_version))?; has_go_version = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.major))?; has_major = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.minor))?; has_minor = true; },
+                        Some(8) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.platform))?; has_platform = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_build_date {
+                    return Err(crate::serde2::missing_field("Info", "buildDate", &["buildDate", "compiler", "gitCommit", "gitTreeState", "gitVersion", "goVersion", "major", "minor", "platform"]));
+                }
+                if !has_compiler {
+                    return Err(crate::serde2::missing_field("Info", "compiler", &["buildDate", "compiler", "gitCommit", "gitTreeState", "gitVersion", "goVersion", "major", "minor", "platform"]));
+                }
+                if !has_git_commit {
+                    return Err(crate::serde2::missing_field("Info", "gitCommit", &["buildDate", "compiler", "gitCommit", "gitTreeState", "gitVersion", "goVersion", "major", "minor", "platform"]));
+                }
+                if !has_git_tree_state {
+                    return Err(crate::serde2::missing_field("Info", "gitTreeState", &["buildDate", "compiler", "gitCommit", "gitTreeState", "gitVersion", "goVersion", "major", "minor", "platform"]));
+                }
+                if !has_git_version {
+                    return Err(crate::serde2::missing_field("Info", "gitVersion", &["buildDate", "compiler", "gitCommit", "gitTreeState", "gitVersion", "goVersion", "major", "minor", "platform"]));
+                }
+                if !has_go_version {
+                    return Err(crate::serde2::missing_field("Info", "goVersion", &["buildDate", "compiler", "gitCommit", "gitTreeState", "gitVersion", "goVersion", "major", "minor", "platform"]));
+                }
+                if !has_major {
+                    return Err(crate::serde2::missing_field("Info", "major", &["buildDate", "compiler", "gitCommit", "gitTreeState", "gitVersion", "goVersion", "major", "minor", "platform"]));
+                }
+                if !has_minor {
+                    return Err(crate::serde2::missing_field("Info", "minor", &["buildDate", "compiler", "gitCommit", "gitTreeState", "gitVersion", "goVersion", "major", "minor", "platform"]));
+                }
+                if !has_platform {
+                    return Err(crate::serde2::missing_field("Info", "platform", &["buildDate", "compiler", "gitCommit", "gitTreeState", "gitVersion", "goVersion", "major", "minor", "platform"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.build_date))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.compiler))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.git_commit))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.git_tree_state))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.git_version))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.go_version))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.major))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.minor))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.platform))?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "Info",
+            Info::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for Info {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("Info", 9)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "buildDate", &self.build_date)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "compiler", &self.compiler)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "gitCommit", &self.git_commit)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "gitTreeState", &self.git_tree_state)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "gitVersion", &self.git_version)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "goVersion", &self.go_version)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "major", &self.major)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "minor", &self.minor)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "platform", &self.platform)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "Info",
             9,
@@ -155,10 +314,189 @@ impl crate::serde::Serialize for Info {
     }
 }
 
+/// A builder of [`Info`]. Created with [`Info::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct InfoBuilder {
+    build_date: Option<String>,
+    compiler: Option<String>,
+    git_commit: Option<String>,
+    git_tree_state: Option<String>,
+    git_version: Option<String>,
+    go_version: Option<String>,
+    major: Option<String>,
+    minor: Option<String>,
+    platform: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl Info {
+    /// Starts building a `Info`. Its required fields must be set before [`InfoBuilder::build`] is called.
+    pub fn builder() -> InfoBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl InfoBuilder {
+    /// Sets [`Info::build_date`].
+    pub fn build_date(mut self, value: impl Into<String>) -> Self {
+        self.build_date = Some(value.into());
+        self
+    }
+
+    /// Sets [`Info::compiler`].
+    pub fn compiler(mut self, value: impl Into<String>) -> Self {
+        self.compiler = Some(value.into());
+        self
+    }
+
+    /// Sets [`Info::git_commit`].
+    pub fn git_commit(mut self, value: impl Into<String>) -> Self {
+        self.git_commit = Some(value.into());
+        self
+    }
+
+    /// Sets [`Info::git_tree_state`].
+    pub fn git_tree_state(mut self, value: impl Into<String>) -> Self {
+        self.git_tree_state = Some(value.into());
+        self
+    }
+
+    /// Sets [`Info::git_version`].
+    pub fn git_version(mut self, value: impl Into<String>) -> Self {
+        self.git_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`Info::go_version`].
+    pub fn go_version(mut self, value: impl Into<String>) -> Self {
+        self.go_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`Info::major`].
+    pub fn major(mut self, value: impl Into<String>) -> Self {
+        self.major = Some(value.into());
+        self
+    }
+
+    /// Sets [`Info::minor`].
+    pub fn minor(mut self, value: impl Into<String>) -> Self {
+        self.minor = Some(value.into());
+        self
+    }
+
+    /// Sets [`Info::platform`].
+    pub fn platform(mut self, value: impl Into<String>) -> Self {
+        self.platform = Some(value.into());
+        self
+    }
+
+    /// Builds the `Info`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<Info, crate::MissingFieldError> {
+        Ok(Info {
+            build_date: self.build_date.ok_or_else(|| crate::MissingFieldError::new("Info", "buildDate"))?,
+            compiler: self.compiler.ok_or_else(|| crate::MissingFieldError::new("Info", "compiler"))?,
+            git_commit: self.git_commit.ok_or_else(|| crate::MissingFieldError::new("Info", "gitCommit"))?,
+            git_tree_state: self.git_tree_state.ok_or_else(|| crate::MissingFieldError::new("Info", "gitTreeState"))?,
+            git_version: self.git_version.ok_or_else(|| crate::MissingFieldError::new("Info", "gitVersion"))?,
+            go_version: self.go_version.ok_or_else(|| crate::MissingFieldError::new("Info", "goVersion"))?,
+            major: self.major.ok_or_else(|| crate::MissingFieldError::new("Info", "major"))?,
+            minor: self.minor.ok_or_else(|| crate::MissingFieldError::new("Info", "minor"))?,
+            platform: self.platform.ok_or_else(|| crate::MissingFieldError::new("Info", "platform"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`Info`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InfoApplyConfiguration {
+    /// See [`Info::build_date`].
+    pub build_date: Option<String>,
+
+    /// See [`Info::compiler`].
+    pub compiler: Option<String>,
+
+    /// See [`Info::git_commit`].
+    pub git_commit: Option<String>,
+
+    /// See [`Info::git_tree_state`].
+    pub git_tree_state: Option<String>,
+
+    /// See [`Info::git_version`].
+    pub git_version: Option<String>,
+
+    /// See [`Info::go_version`].
+    pub go_version: Option<String>,
+
+    /// See [`Info::major`].
+    pub major: Option<String>,
+
+    /// See [`Info::minor`].
+    pub minor: Option<String>,
+
+    /// See [`Info::platform`].
+    pub platform: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for InfoApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "InfoApplyConfiguration",
+            self.build_date.as_ref().map_or(0, |_| 1) +
+            self.compiler.as_ref().map_or(0, |_| 1) +
+            self.git_commit.as_ref().map_or(0, |_| 1) +
+            self.git_tree_state.as_ref().map_or(0, |_| 1) +
+            self.git_version.as_ref().map_or(0, |_| 1) +
+            self.go_version.as_ref().map_or(0, |_| 1) +
+            self.major.as_ref().map_or(0, |_| 1) +
+            self.minor.as_ref().map_or(0, |_| 1) +
+            self.platform.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.build_date {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "buildDate", value)?;
+        }
+        if let Some(value) = &self.compiler {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "compiler", value)?;
+        }
+        if let Some(value) = &self.git_commit {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "gitCommit", value)?;
+        }
+        if let Some(value) = &self.git_tree_state {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "gitTreeState", value)?;
+        }
+        if let Some(value) = &self.git_version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "gitVersion", value)?;
+        }
+        if let Some(value) = &self.go_version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "goVersion", value)?;
+        }
+        if let Some(value) = &self.major {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "major", value)?;
+        }
+        if let Some(value) = &self.minor {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "minor", value)?;
+        }
+        if let Some(value) = &self.platform {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "platform", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Info {
     fn schema_name() -> String {
-        "io.k8s.apimachinery.pkg.version.Info".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apimachinery::pkg::version::Info".to_owned()
+        }
+        else {
+            "io.k8s.apimachinery.pkg.version.Info".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -169,71 +507,73 @@ impl crate::schemars::JsonSchema for Info {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "buildDate".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "compiler".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "gitCommit".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "gitTreeState".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "gitVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "goVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "major".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "minor".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "platform".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "buildDate",
                     "compiler",
@@ -251,3 +591,20 @@ impl crate::schemars::JsonSchema for Info {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for Info {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(Info {
+            build_date: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            compiler: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            git_commit: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            git_tree_state: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            git_version: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            go_version: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            major: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            minor: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            platform: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
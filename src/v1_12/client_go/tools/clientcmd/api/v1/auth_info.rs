@@ -0,0 +1,951 @@
+// Generated from definition io.k8s.client-go.tools.clientcmd.api.v1.AuthInfo
+
+/// AuthInfo contains information that describes identity information.  This is use to tell the kubernetes cluster who you are.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AuthInfo {
+    /// Impersonate is the username to impersonate.  The name matches the flag.
+    pub as_: Option<String>,
+
+    /// ImpersonateGroups is the groups to impersonate.
+    pub as_groups: Option<Vec<String>>,
+
+    /// ImpersonateUserExtra contains additional information for impersonated user.
+    pub as_user_extra: Option<std::collections::BTreeMap<String, Vec<String>>>,
+
+    /// AuthProvider specifies a custom authentication plugin for the kubernetes cluster.
+    pub auth_provider: Option<crate::client_go::tools::clientcmd::api::v1::AuthProviderConfig>,
+
+    /// ClientCertificate is the path to a client cert file for TLS.
+    pub client_certificate: Option<String>,
+
+    /// ClientCertificateData contains PEM-encoded data from a client cert file for TLS. Overrides ClientCertificate
+    pub client_certificate_data: Option<crate::ByteString>,
+
+    /// ClientKey is the path to a client key file for TLS.
+    pub client_key: Option<String>,
+
+    /// ClientKeyData contains PEM-encoded data from a client key file for TLS. Overrides ClientKey
+    pub client_key_data: Option<crate::ByteString>,
+
+    /// Exec specifies a custom exec-based authentication plugin for the kubernetes cluster.
+    pub exec: Option<crate::client_go::tools::clientcmd::api::v1::ExecConfig>,
+
+    /// Extensions holds additional information. This is useful for extenders so that reads and writes don't clobber unknown fields
+    pub extensions: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtension>>,
+
+    /// Password is the password for basic authentication to the kubernetes cluster.
+    pub password: Option<String>,
+
+    /// Token is the bearer token for authentication to the kubernetes cluster.
+    pub token: Option<String>,
+
+    /// TokenFile is a pointer to a file that contains a bearer token (as described above).  If both Token and TokenFile are present, Token takes precedence.
+    pub token_file: Option<String>,
+
+    /// Username is the username for basic authentication to the kubernetes cluster.
+    pub username: Option<String>,
+}
+
+impl AuthInfo {
+    /// Returns this value with [`AuthInfo::as_`] set to the given value.
+    #[must_use]
+    pub fn with_as(mut self, value: impl Into<String>) -> Self {
+        self.as_ = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`AuthInfo::as_groups`] set to the given value.
+    #[must_use]
+    pub fn with_as_groups(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.as_groups = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`AuthInfo::as_user_extra`] set to the given value.
+    #[must_use]
+    pub fn with_as_user_extra(mut self, value: impl Into<std::collections::BTreeMap<String, Vec<String>>>) -> Self {
+        self.as_user_extra = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`AuthInfo::auth_provider`] set to the given value.
+    #[must_use]
+    pub fn with_auth_provider(mut self, value: impl Into<crate::client_go::tools::clientcmd::api::v1::AuthProviderConfig>) -> Self {
+        self.auth_provider = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`AuthInfo::client_certificate`] set to the given value.
+    #[must_use]
+    pub fn with_client_certificate(mut self, value: impl Into<String>) -> Self {
+        self.client_certificate = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`AuthInfo::client_certificate_data`] set to the given value.
+    #[must_use]
+    pub fn with_client_certificate_data(mut self, value: impl Into<crate::ByteString>) -> Self {
+        self.client_certificate_data = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`AuthInfo::client_key`] set to the given value.
+    #[must_use]
+    pub fn with_client_key(mut self, value: impl Into<String>) -> Self {
+        self.client_key = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`AuthInfo::client_key_data`] set to the given value.
+    #[must_use]
+    pub fn with_client_key_data(mut self, value: impl Into<crate::ByteString>) -> Self {
+        self.client_key_data = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`AuthInfo::exec`] set to the given value.
+    #[must_use]
+    pub fn with_exec(mut self, value: impl Into<crate::client_go::tools::clientcmd::api::v1::ExecConfig>) -> Self {
+        self.exec = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`AuthInfo::extensions`] set to the given value.
+    #[must_use]
+    pub fn with_extensions(mut self, value: impl Into<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtension>>) -> Self {
+        self.extensions = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`AuthInfo::password`] set to the given value.
+    #[must_use]
+    pub fn with_password(mut self, value: impl Into<String>) -> Self {
+        self.password = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`AuthInfo::token`] set to the given value.
+    #[must_use]
+    pub fn with_token(mut self, value: impl Into<String>) -> Self {
+        self.token = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`AuthInfo::token_file`] set to the given value.
+    #[must_use]
+    pub fn with_token_file(mut self, value: impl Into<String>) -> Self {
+        self.token_file = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`AuthInfo::username`] set to the given value.
+    #[must_use]
+    pub fn with_username(mut self, value: impl Into<String>) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for AuthInfo {}
+
+impl AuthInfo {
+    const FIELDS: &'static [&'static str] = &[
+        "as",
+        "as-groups",
+        "as-user-extra",
+        "auth-provider",
+        "client-certificate",
+        "client-certificate-data",
+        "client-key",
+        "client-key-data",
+        "exec",
+        "extensions",
+        "password",
+        "token",
+        "tokenFile",
+        "username",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for AuthInfo {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = AuthInfo;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("AuthInfo")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_as_: Option<String> = None;
+                let mut value_as_groups: Option<Vec<String>> = None;
+                let mut value_as_user_extra: Option<std::collections::BTreeMap<String, Vec<String>>> = None;
+                let mut value_auth_provider: Option<crate::client_go::tools::clientcmd::api::v1::AuthProviderConfig> = None;
+                let mut value_client_certificate: Option<String> = None;
+                let mut value_client_certificate_data: Option<crate::ByteString> = None;
+                let mut value_client_key: Option<String> = None;
+                let mut value_client_key_data: Option<crate::ByteString> = None;
+                let mut value_exec: Option<crate::client_go::tools::clientcmd::api::v1::ExecConfig> = None;
+                let mut value_extensions: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtension>> = None;
+                let mut value_password: Option<String> = None;
+                let mut value_token: Option<String> = None;
+                let mut value_token_file: Option<String> = None;
+                let mut value_username: Option<String> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("AuthInfo", AuthInfo::FIELDS))? {
+                    match key {
+                        Some(0) => value_as_ = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_as_groups = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_as_user_extra = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_auth_provider = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_client_certificate = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_client_certificate_data = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_client_key = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(7) => value_client_key_data = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(8) => value_exec = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(9) => value_extensions = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(10) => value_password = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(11) => value_token = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(12) => value_token_file = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(13) => value_username = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(AuthInfo {
+                    as_: value_as_,
+                    as_groups: value_as_groups,
+                    as_user_extra: value_as_user_extra,
+                    auth_provider: value_auth_provider,
+                    client_certificate: value_client_certificate,
+                    client_certificate_data: value_client_certificate_data,
+                    client_key: value_client_key,
+                    client_key_data: value_client_key_data,
+                    exec: value_exec,
+                    extensions: value_extensions,
+                    password: value_password,
+                    token: value_token,
+                    token_file: value_token_file,
+                    username: value_username,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(AuthInfo {
+                    as_: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    as_groups: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    as_user_extra: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    auth_provider: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    client_certificate: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    client_certificate_data: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    client_key: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    client_key_data: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                    exec: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?,
+                    extensions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?,
+                    password: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?,
+                    token: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(11, &self))?,
+                    token_file: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(12, &self))?,
+                    username: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(13, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "AuthInfo",
+            AuthInfo::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, AuthInfo> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("AuthInfo")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_as_ = false;
+                let mut has_as_groups = false;
+                let mut has_as_user_extra = false;
+                let mut has_auth_provider = false;
+                let mut has_client_certificate = false;
+                let mut has_client_certificate_data = false;
+                let mut has_client_key = false;
+                let mut has_client_key_data = false;
+                let mut has_exec = false;
+                let mut has_extensions = false;
+                let mut has_password = false;
+                let mut has_token = false;
+                let mut has_token_file = false;
+                let mut has_username = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("AuthInfo", AuthInfo::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.as_))?; has_as_ = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.as_groups))?; has_as_groups = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.as_user_extra))?; has_as_user_extra = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.auth_provider))?; has_auth_provider = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.client_certificate))?; has_client_certificate = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.client_certificate_data))?; has_client_certificate_data = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.client_key))?; has_client_key = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.client_key_data))?; has_client_key_data = true; },
+                        Some(8) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.exec))?; has_exec = true; },
+                        Some(9) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.extensions))?; has_extensions = true; },
+                        Some(10) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.password))?; has_password = true; },
+                        Some(11) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.token))?; has_token = true; },
+                        Some(12) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.token_file))?; has_token_file = true; },
+                        Some(13) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.username))?; has_username = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_as_ {
+                    self.0.as_ = None;
+                }
+                if !has_as_groups {
+                    self.0.as_groups = None;
+                }
+                if !has_as_user_extra {
+                    self.0.as_user_extra = None;
+                }
+                if !has_auth_provider {
+                    self.0.auth_provider = None;
+                }
+                if !has_client_certificate {
+                    self.0.client_certificate = None;
+                }
+                if !has_client_certificate_data {
+                    self.0.client_certificate_data = None;
+                }
+                if !has_client_key {
+                    self.0.client_key = None;
+                }
+                if !has_client_key_data {
+                    self.0.client_key_data = None;
+                }
+                if !has_exec {
+                    self.0.exec = None;
+                }
+                if !has_extensions {
+                    self.0.extensions = None;
+                }
+                if !has_password {
+                    self.0.password = None;
+                }
+                if !has_token {
+                    self.0.token = None;
+                }
+                if !has_token_file {
+                    self.0.token_file = None;
+                }
+                if !has_username {
+                    self.0.username = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.as_))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.as_groups))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.as_user_extra))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.auth_provider))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.client_certificate))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.client_certificate_data))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.client_key))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.client_key_data))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.exec))?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.extensions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.password))?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.token))?.ok_or_else(|| crate::serde::de::Error::invalid_length(11, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.token_file))?.ok_or_else(|| crate::serde::de::Error::invalid_length(12, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.username))?.ok_or_else(|| crate::serde::de::Error::invalid_length(13, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "AuthInfo",
+            AuthInfo::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for AuthInfo {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("AuthInfo", 14)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "as", &self.as_)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "as-groups", &self.as_groups)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "as-user-extra", &self.as_user_extra)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "auth-provider", &self.auth_provider)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "client-certificate", &self.client_certificate)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "client-certificate-data", &self.client_certificate_data)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "client-key", &self.client_key)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "client-key-data", &self.client_key_data)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "exec", &self.exec)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "extensions", &self.extensions)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "password", &self.password)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "token", &self.token)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tokenFile", &self.token_file)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "username", &self.username)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            "AuthInfo",
+            self.as_.as_ref().map_or(0, |_| 1) +
+            self.as_groups.as_ref().map_or(0, |_| 1) +
+            self.as_user_extra.as_ref().map_or(0, |_| 1) +
+            self.auth_provider.as_ref().map_or(0, |_| 1) +
+            self.client_certificate.as_ref().map_or(0, |_| 1) +
+            self.client_certificate_data.as_ref().map_or(0, |_| 1) +
+            self.client_key.as_ref().map_or(0, |_| 1) +
+            self.client_key_data.as_ref().map_or(0, |_| 1) +
+            self.exec.as_ref().map_or(0, |_| 1) +
+            self.extensions.as_ref().map_or(0, |_| 1) +
+            self.password.as_ref().map_or(0, |_| 1) +
+            self.token.as_ref().map_or(0, |_| 1) +
+            self.token_file.as_ref().map_or(0, |_| 1) +
+            self.username.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.as_ {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "as", value)?;
+        }
+        if let Some(value) = &self.as_groups {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "as-groups", value)?;
+        }
+        if let Some(value) = &self.as_user_extra {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "as-user-extra", value)?;
+        }
+        if let Some(value) = &self.auth_provider {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "auth-provider", value)?;
+        }
+        if let Some(value) = &self.client_certificate {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "client-certificate", value)?;
+        }
+        if let Some(value) = &self.client_certificate_data {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "client-certificate-data", value)?;
+        }
+        if let Some(value) = &self.client_key {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "client-key", value)?;
+        }
+        if let Some(value) = &self.client_key_data {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "client-key-data", value)?;
+        }
+        if let Some(value) = &self.exec {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "exec", value)?;
+        }
+        if let Some(value) = &self.extensions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "extensions", value)?;
+        }
+        if let Some(value) = &self.password {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "password", value)?;
+        }
+        if let Some(value) = &self.token {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "token", value)?;
+        }
+        if let Some(value) = &self.token_file {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tokenFile", value)?;
+        }
+        if let Some(value) = &self.username {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "username", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`AuthInfo`]. Created with [`AuthInfo::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct AuthInfoBuilder {
+    as_: Option<String>,
+    as_groups: Option<Vec<String>>,
+    as_user_extra: Option<std::collections::BTreeMap<String, Vec<String>>>,
+    auth_provider: Option<crate::client_go::tools::clientcmd::api::v1::AuthProviderConfig>,
+    client_certificate: Option<String>,
+    client_certificate_data: Option<crate::ByteString>,
+    client_key: Option<String>,
+    client_key_data: Option<crate::ByteString>,
+    exec: Option<crate::client_go::tools::clientcmd::api::v1::ExecConfig>,
+    extensions: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtension>>,
+    password: Option<String>,
+    token: Option<String>,
+    token_file: Option<String>,
+    username: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl AuthInfo {
+    /// Starts building a `AuthInfo`. Its required fields must be set before [`AuthInfoBuilder::build`] is called.
+    pub fn builder() -> AuthInfoBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl AuthInfoBuilder {
+    /// Sets [`AuthInfo::as_`].
+    pub fn as_(mut self, value: impl Into<String>) -> Self {
+        self.as_ = Some(value.into());
+        self
+    }
+
+    /// Sets [`AuthInfo::as_groups`].
+    pub fn as_groups(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.as_groups = Some(value.into());
+        self
+    }
+
+    /// Sets [`AuthInfo::as_user_extra`].
+    pub fn as_user_extra(mut self, value: impl Into<std::collections::BTreeMap<String, Vec<String>>>) -> Self {
+        self.as_user_extra = Some(value.into());
+        self
+    }
+
+    /// Sets [`AuthInfo::auth_provider`].
+    pub fn auth_provider(mut self, value: impl Into<crate::client_go::tools::clientcmd::api::v1::AuthProviderConfig>) -> Self {
+        self.auth_provider = Some(value.into());
+        self
+    }
+
+    /// Sets [`AuthInfo::client_certificate`].
+    pub fn client_certificate(mut self, value: impl Into<String>) -> Self {
+        self.client_certificate = Some(value.into());
+        self
+    }
+
+    /// Sets [`AuthInfo::client_certificate_data`].
+    pub fn client_certificate_data(mut self, value: impl Into<crate::ByteString>) -> Self {
+        self.client_certificate_data = Some(value.into());
+        self
+    }
+
+    /// Sets [`AuthInfo::client_key`].
+    pub fn client_key(mut self, value: impl Into<String>) -> Self {
+        self.client_key = Some(value.into());
+        self
+    }
+
+    /// Sets [`AuthInfo::client_key_data`].
+    pub fn client_key_data(mut self, value: impl Into<crate::ByteString>) -> Self {
+        self.client_key_data = Some(value.into());
+        self
+    }
+
+    /// Sets [`AuthInfo::exec`].
+    pub fn exec(mut self, value: impl Into<crate::client_go::tools::clientcmd::api::v1::ExecConfig>) -> Self {
+        self.exec = Some(value.into());
+        self
+    }
+
+    /// Sets [`AuthInfo::extensions`].
+    pub fn extensions(mut self, value: impl Into<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtension>>) -> Self {
+        self.extensions = Some(value.into());
+        self
+    }
+
+    /// Sets [`AuthInfo::password`].
+    pub fn password(mut self, value: impl Into<String>) -> Self {
+        self.password = Some(value.into());
+        self
+    }
+
+    /// Sets [`AuthInfo::token`].
+    pub fn token(mut self, value: impl Into<String>) -> Self {
+        self.token = Some(value.into());
+        self
+    }
+
+    /// Sets [`AuthInfo::token_file`].
+    pub fn token_file(mut self, value: impl Into<String>) -> Self {
+        self.token_file = Some(value.into());
+        self
+    }
+
+    /// Sets [`AuthInfo::username`].
+    pub fn username(mut self, value: impl Into<String>) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `AuthInfo`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<AuthInfo, crate::MissingFieldError> {
+        Ok(AuthInfo {
+            as_: self.as_,
+            as_groups: self.as_groups,
+            as_user_extra: self.as_user_extra,
+            auth_provider: self.auth_provider,
+            client_certificate: self.client_certificate,
+            client_certificate_data: self.client_certificate_data,
+            client_key: self.client_key,
+            client_key_data: self.client_key_data,
+            exec: self.exec,
+            extensions: self.extensions,
+            password: self.password,
+            token: self.token,
+            token_file: self.token_file,
+            username: self.username,
+        })
+    }
+}
+
+/// The apply configuration of [`AuthInfo`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AuthInfoApplyConfiguration {
+    /// See [`AuthInfo::as_`].
+    pub as_: Option<String>,
+
+    /// See [`AuthInfo::as_groups`].
+    pub as_groups: Option<Vec<String>>,
+
+    /// See [`AuthInfo::as_user_extra`].
+    pub as_user_extra: Option<std::collections::BTreeMap<String, Vec<String>>>,
+
+    /// See [`AuthInfo::auth_provider`].
+    pub auth_provider: Option<crate::client_go::tools::clientcmd::api::v1::AuthProviderConfigApplyConfiguration>,
+
+    /// See [`AuthInfo::client_certificate`].
+    pub client_certificate: Option<String>,
+
+    /// See [`AuthInfo::client_certificate_data`].
+    pub client_certificate_data: Option<crate::ByteString>,
+
+    /// See [`AuthInfo::client_key`].
+    pub client_key: Option<String>,
+
+    /// See [`AuthInfo::client_key_data`].
+    pub client_key_data: Option<crate::ByteString>,
+
+    /// See [`AuthInfo::exec`].
+    pub exec: Option<crate::client_go::tools::clientcmd::api::v1::ExecConfigApplyConfiguration>,
+
+    /// See [`AuthInfo::extensions`].
+    pub extensions: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtensionApplyConfiguration>>,
+
+    /// See [`AuthInfo::password`].
+    pub password: Option<String>,
+
+    /// See [`AuthInfo::token`].
+    pub token: Option<String>,
+
+    /// See [`AuthInfo::token_file`].
+    pub token_file: Option<String>,
+
+    /// See [`AuthInfo::username`].
+    pub username: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for AuthInfoApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "AuthInfoApplyConfiguration",
+            self.as_.as_ref().map_or(0, |_| 1) +
+            self.as_groups.as_ref().map_or(0, |_| 1) +
+            self.as_user_extra.as_ref().map_or(0, |_| 1) +
+            self.auth_provider.as_ref().map_or(0, |_| 1) +
+            self.client_certificate.as_ref().map_or(0, |_| 1) +
+            self.client_certificate_data.as_ref().map_or(0, |_| 1) +
+            self.client_key.as_ref().map_or(0, |_| 1) +
+            self.client_key_data.as_ref().map_or(0, |_| 1) +
+            self.exec.as_ref().map_or(0, |_| 1) +
+            self.extensions.as_ref().map_or(0, |_| 1) +
+            self.password.as_ref().map_or(0, |_| 1) +
+            self.token.as_ref().map_or(0, |_| 1) +
+            self.token_file.as_ref().map_or(0, |_| 1) +
+            self.username.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.as_ {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "as", value)?;
+        }
+        if let Some(value) = &self.as_groups {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "as-groups", value)?;
+        }
+        if let Some(value) = &self.as_user_extra {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "as-user-extra", value)?;
+        }
+        if let Some(value) = &self.auth_provider {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "auth-provider", value)?;
+        }
+        if let Some(value) = &self.client_certificate {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "client-certificate", value)?;
+        }
+        if let Some(value) = &self.client_certificate_data {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "client-certificate-data", value)?;
+        }
+        if let Some(value) = &self.client_key {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "client-key", value)?;
+        }
+        if let Some(value) = &self.client_key_data {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "client-key-data", value)?;
+        }
+        if let Some(value) = &self.exec {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "exec", value)?;
+        }
+        if let Some(value) = &self.extensions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "extensions", value)?;
+        }
+        if let Some(value) = &self.password {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "password", value)?;
+        }
+        if let Some(value) = &self.token {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "token", value)?;
+        }
+        if let Some(value) = &self.token_file {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tokenFile", value)?;
+        }
+        if let Some(value) = &self.username {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "username", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for AuthInfo {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "client_go::tools::clientcmd::api::v1::AuthInfo".to_owned()
+        }
+        else {
+            "io.k8s.client-go.tools.clientcmd.api.v1.AuthInfo".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("AuthInfo contains information that describes identity information.  This is use to tell the kubernetes cluster who you are.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "as".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Impersonate is the username to impersonate.  The name matches the flag.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "as-groups".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("ImpersonateGroups is the groups to impersonate.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Array))),
+                            array: Some(Box::new(crate::schemars::schema::ArrayValidation {
+                                items: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(
+                                    crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                                        instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                                        ..Default::default()
+                                    })
+                                ))),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "as-user-extra".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("ImpersonateUserExtra contains additional information for impersonated user.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+                            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                                additional_properties: Some(Box::new(
+                                    crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                                        instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Array))),
+                                        array: Some(Box::new(crate::schemars::schema::ArrayValidation {
+                                            items: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(
+                                                crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                                                    instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                                                    ..Default::default()
+                                                })
+                                            ))),
+                                            ..Default::default()
+                                        })),
+                                        ..Default::default()
+                                    })
+                                )),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "auth-provider".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::client_go::tools::clientcmd::api::v1::AuthProviderConfig>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("AuthProvider specifies a custom authentication plugin for the kubernetes cluster.".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
+                        "client-certificate".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("ClientCertificate is the path to a client cert file for TLS.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "client-certificate-data".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("ClientCertificateData contains PEM-encoded data from a client cert file for TLS. Overrides ClientCertificate".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            format: Some("byte".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "client-key".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("ClientKey is the path to a client key file for TLS.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "client-key-data".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("ClientKeyData contains PEM-encoded data from a client key file for TLS. Overrides ClientKey".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            format: Some("byte".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "exec".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::client_go::tools::clientcmd::api::v1::ExecConfig>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Exec specifies a custom exec-based authentication plugin for the kubernetes cluster.".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
+                        "extensions".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Extensions holds additional information. This is useful for extenders so that reads and writes don't clobber unknown fields".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Array))),
+                            array: Some(Box::new(crate::schemars::schema::ArrayValidation {
+                                items: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(__gen.subschema_for::<crate::client_go::tools::clientcmd::api::v1::NamedExtension>()))),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "password".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Password is the password for basic authentication to the kubernetes cluster.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "token".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Token is the bearer token for authentication to the kubernetes cluster.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "tokenFile".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("TokenFile is a pointer to a file that contains a bearer token (as described above).  If both Token and TokenFile are present, Token takes precedence.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "username".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Username is the username for basic authentication to the kubernetes cluster.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for AuthInfo {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(AuthInfo {
+            as_: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            as_groups: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            as_user_extra: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)))?,
+            auth_provider: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            client_certificate: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            client_certificate_data: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            client_key: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            client_key_data: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            exec: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            extensions: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            password: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            token: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            token_file: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            username: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -0,0 +1,292 @@
+// Generated from definition io.k8s.client-go.tools.clientcmd.api.v1.AuthProviderConfig
+
+/// AuthProviderConfig holds the configuration for a specified auth provider.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct AuthProviderConfig {
+    pub config: Option<std::collections::BTreeMap<String, String>>,
+
+    pub name: String,
+}
+
+impl AuthProviderConfig {
+    /// Constructs a `AuthProviderConfig` from its required fields. Its optional fields are unset.
+    pub fn new(name: impl Into<String>) -> Self {
+        AuthProviderConfig {
+            config: None,
+            name: name.into(),
+        }
+    }
+}
+
+impl AuthProviderConfig {
+    /// Returns this value with [`AuthProviderConfig::config`] set to the given value.
+    #[must_use]
+    pub fn with_config(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.config = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`AuthProviderConfig::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for AuthProviderConfig {}
+
+impl AuthProviderConfig {
+    const FIELDS: &'static [&'static str] = &[
+        "config",
+        "name",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for AuthProviderConfig {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = AuthProviderConfig;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("AuthProviderConfig")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_config: Option<std::collections::BTreeMap<String, String>> = None;
+                let mut value_name: Option<String> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("AuthProviderConfig", AuthProviderConfig::FIELDS))? {
+                    match key {
+                        Some(0) => value_config = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(AuthProviderConfig {
+                    config: value_config,
+                    name: value_name.ok_or_else(|| crate::serde2::missing_field("AuthProviderConfig", "name", &["name"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(AuthProviderConfig {
+                    config: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "AuthProviderConfig",
+            AuthProviderConfig::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, AuthProviderConfig> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("AuthProviderConfig")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_config = false;
+                let mut has_name = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("AuthProviderConfig", AuthProviderConfig::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.config))?; has_config = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.name))?; has_name = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_config {
+                    self.0.config = None;
+                }
+                if !has_name {
+                    return Err(crate::serde2::missing_field("AuthProviderConfig", "name", &["name"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.config))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "AuthProviderConfig",
+            AuthProviderConfig::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for AuthProviderConfig {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("AuthProviderConfig", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "config", &self.config)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            "AuthProviderConfig",
+            1 +
+            self.config.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.config {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "config", value)?;
+        }
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`AuthProviderConfig`]. Created with [`AuthProviderConfig::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct AuthProviderConfigBuilder {
+    config: Option<std::collections::BTreeMap<String, String>>,
+    name: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl AuthProviderConfig {
+    /// Starts building a `AuthProviderConfig`. Its required fields must be set before [`AuthProviderConfigBuilder::build`] is called.
+    pub fn builder() -> AuthProviderConfigBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl AuthProviderConfigBuilder {
+    /// Sets [`AuthProviderConfig::config`].
+    pub fn config(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.config = Some(value.into());
+        self
+    }
+
+    /// Sets [`AuthProviderConfig::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `AuthProviderConfig`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<AuthProviderConfig, crate::MissingFieldError> {
+        Ok(AuthProviderConfig {
+            config: self.config,
+            name: self.name.ok_or_else(|| crate::MissingFieldError::new("AuthProviderConfig", "name"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`AuthProviderConfig`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AuthProviderConfigApplyConfiguration {
+    /// See [`AuthProviderConfig::config`].
+    pub config: Option<std::collections::BTreeMap<String, String>>,
+
+    /// See [`AuthProviderConfig::name`].
+    pub name: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for AuthProviderConfigApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "AuthProviderConfigApplyConfiguration",
+            self.config.as_ref().map_or(0, |_| 1) +
+            self.name.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.config {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "config", value)?;
+        }
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for AuthProviderConfig {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "client_go::tools::clientcmd::api::v1::AuthProviderConfig".to_owned()
+        }
+        else {
+            "io.k8s.client-go.tools.clientcmd.api.v1.AuthProviderConfig".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("AuthProviderConfig holds the configuration for a specified auth provider.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "config".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+                            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                                additional_properties: Some(Box::new(
+                                    crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                                        instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                                        ..Default::default()
+                                    })
+                                )),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "name".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                required: std::array::IntoIter::new([
+                    "name",
+                ]).map(std::borrow::ToOwned::to_owned).collect(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for AuthProviderConfig {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(AuthProviderConfig {
+            config: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -0,0 +1,84 @@
+
+mod auth_info;
+pub use self::auth_info::AuthInfo;
+#[cfg(feature = "builder")]
+pub use self::auth_info::AuthInfoBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::auth_info::AuthInfoApplyConfiguration;
+
+mod auth_provider_config;
+pub use self::auth_provider_config::AuthProviderConfig;
+#[cfg(feature = "builder")]
+pub use self::auth_provider_config::AuthProviderConfigBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::auth_provider_config::AuthProviderConfigApplyConfiguration;
+
+mod cluster;
+pub use self::cluster::Cluster;
+#[cfg(feature = "builder")]
+pub use self::cluster::ClusterBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::cluster::ClusterApplyConfiguration;
+
+mod config;
+pub use self::config::Config;
+#[cfg(feature = "builder")]
+pub use self::config::ConfigBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::config::ConfigApplyConfiguration;
+
+mod context;
+pub use self::context::Context;
+#[cfg(feature = "builder")]
+pub use self::context::ContextBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::context::ContextApplyConfiguration;
+
+mod exec_config;
+pub use self::exec_config::ExecConfig;
+#[cfg(feature = "builder")]
+pub use self::exec_config::ExecConfigBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::exec_config::ExecConfigApplyConfiguration;
+
+mod exec_env_var;
+pub use self::exec_env_var::ExecEnvVar;
+#[cfg(feature = "builder")]
+pub use self::exec_env_var::ExecEnvVarBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::exec_env_var::ExecEnvVarApplyConfiguration;
+
+mod named_auth_info;
+pub use self::named_auth_info::NamedAuthInfo;
+#[cfg(feature = "builder")]
+pub use self::named_auth_info::NamedAuthInfoBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::named_auth_info::NamedAuthInfoApplyConfiguration;
+
+mod named_cluster;
+pub use self::named_cluster::NamedCluster;
+#[cfg(feature = "builder")]
+pub use self::named_cluster::NamedClusterBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::named_cluster::NamedClusterApplyConfiguration;
+
+mod named_context;
+pub use self::named_context::NamedContext;
+#[cfg(feature = "builder")]
+pub use self::named_context::NamedContextBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::named_context::NamedContextApplyConfiguration;
+
+mod named_extension;
+pub use self::named_extension::NamedExtension;
+#[cfg(feature = "builder")]
+pub use self::named_extension::NamedExtensionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::named_extension::NamedExtensionApplyConfiguration;
+
+mod preferences;
+pub use self::preferences::Preferences;
+#[cfg(feature = "builder")]
+pub use self::preferences::PreferencesBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::preferences::PreferencesApplyConfiguration;
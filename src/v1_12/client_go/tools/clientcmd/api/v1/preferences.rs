@@ -0,0 +1,281 @@
+// Generated from definition io.k8s.client-go.tools.clientcmd.api.v1.Preferences
+
+/// Preferences holds general information to be use for cli interactions
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Preferences {
+    pub colors: Option<bool>,
+
+    /// Extensions holds additional information. This is useful for extenders so that reads and writes don't clobber unknown fields
+    pub extensions: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtension>>,
+}
+
+impl Preferences {
+    /// Returns this value with [`Preferences::colors`] set to the given value.
+    #[must_use]
+    pub fn with_colors(mut self, value: impl Into<bool>) -> Self {
+        self.colors = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Preferences::extensions`] set to the given value.
+    #[must_use]
+    pub fn with_extensions(mut self, value: impl Into<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtension>>) -> Self {
+        self.extensions = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for Preferences {}
+
+impl Preferences {
+    const FIELDS: &'static [&'static str] = &[
+        "colors",
+        "extensions",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for Preferences {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = Preferences;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("Preferences")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_colors: Option<bool> = None;
+                let mut value_extensions: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtension>> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Preferences", Preferences::FIELDS))? {
+                    match key {
+                        Some(0) => value_colors = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_extensions = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(Preferences {
+                    colors: value_colors,
+                    extensions: value_extensions,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(Preferences {
+                    colors: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    extensions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "Preferences",
+            Preferences::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, Preferences> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("Preferences")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_colors = false;
+                let mut has_extensions = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Preferences", Preferences::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.colors))?; has_colors = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.extensions))?; has_extensions = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_colors {
+                    self.0.colors = None;
+                }
+                if !has_extensions {
+                    self.0.extensions = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.colors))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.extensions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "Preferences",
+            Preferences::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for Preferences {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("Preferences", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "colors", &self.colors)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "extensions", &self.extensions)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            "Preferences",
+            self.colors.as_ref().map_or(0, |_| 1) +
+            self.extensions.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.colors {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "colors", value)?;
+        }
+        if let Some(value) = &self.extensions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "extensions", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`Preferences`]. Created with [`Preferences::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct PreferencesBuilder {
+    colors: Option<bool>,
+    extensions: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtension>>,
+}
+
+#[cfg(feature = "builder")]
+impl Preferences {
+    /// Starts building a `Preferences`. Its required fields must be set before [`PreferencesBuilder::build`] is called.
+    pub fn builder() -> PreferencesBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl PreferencesBuilder {
+    /// Sets [`Preferences::colors`].
+    pub fn colors(mut self, value: impl Into<bool>) -> Self {
+        self.colors = Some(value.into());
+        self
+    }
+
+    /// Sets [`Preferences::extensions`].
+    pub fn extensions(mut self, value: impl Into<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtension>>) -> Self {
+        self.extensions = Some(value.into());
+        self
+    }
+
+    /// Builds the `Preferences`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<Preferences, crate::MissingFieldError> {
+        Ok(Preferences {
+            colors: self.colors,
+            extensions: self.extensions,
+        })
+    }
+}
+
+/// The apply configuration of [`Preferences`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PreferencesApplyConfiguration {
+    /// See [`Preferences::colors`].
+    pub colors: Option<bool>,
+
+    /// See [`Preferences::extensions`].
+    pub extensions: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtensionApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for PreferencesApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "PreferencesApplyConfiguration",
+            self.colors.as_ref().map_or(0, |_| 1) +
+            self.extensions.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.colors {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "colors", value)?;
+        }
+        if let Some(value) = &self.extensions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "extensions", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for Preferences {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "client_go::tools::clientcmd::api::v1::Preferences".to_owned()
+        }
+        else {
+            "io.k8s.client-go.tools.clientcmd.api.v1.Preferences".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("Preferences holds general information to be use for cli interactions".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "colors".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "extensions".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Extensions holds additional information. This is useful for extenders so that reads and writes don't clobber unknown fields".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Array))),
+                            array: Some(Box::new(crate::schemars::schema::ArrayValidation {
+                                items: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(__gen.subschema_for::<crate::client_go::tools::clientcmd::api::v1::NamedExtension>()))),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for Preferences {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(Preferences {
+            colors: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            extensions: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
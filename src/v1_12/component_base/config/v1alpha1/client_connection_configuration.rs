@@ -0,0 +1,443 @@
+// Generated from definition io.k8s.component-base.config.v1alpha1.ClientConnectionConfiguration
+
+/// ClientConnectionConfiguration contains details for constructing a client.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ClientConnectionConfiguration {
+    /// acceptContentTypes defines the Accept header sent by clients when connecting to a server, overriding the default value of 'application/json'. This field will control all connections to the server used by a particular client.
+    pub accept_content_types: Option<String>,
+
+    /// burst allows extra queries to accumulate when a client is exceeding its rate.
+    pub burst: Option<i32>,
+
+    /// contentType is the content type used when sending data to the server from this client.
+    pub content_type: Option<String>,
+
+    /// kubeconfig is the path to a KubeConfig file.
+    pub kubeconfig: Option<String>,
+
+    /// qps controls the number of queries per second allowed for this connection.
+    pub qps: Option<f64>,
+}
+
+impl ClientConnectionConfiguration {
+    /// Returns this value with [`ClientConnectionConfiguration::accept_content_types`] set to the given value.
+    #[must_use]
+    pub fn with_accept_content_types(mut self, value: impl Into<String>) -> Self {
+        self.accept_content_types = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ClientConnectionConfiguration::burst`] set to the given value.
+    #[must_use]
+    pub fn with_burst(mut self, value: impl Into<i32>) -> Self {
+        self.burst = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ClientConnectionConfiguration::content_type`] set to the given value.
+    #[must_use]
+    pub fn with_content_type(mut self, value: impl Into<String>) -> Self {
+        self.content_type = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ClientConnectionConfiguration::kubeconfig`] set to the given value.
+    #[must_use]
+    pub fn with_kubeconfig(mut self, value: impl Into<String>) -> Self {
+        self.kubeconfig = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`ClientConnectionConfiguration::qps`] set to the given value.
+    #[must_use]
+    pub fn with_qps(mut self, value: impl Into<f64>) -> Self {
+        self.qps = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for ClientConnectionConfiguration {}
+
+impl ClientConnectionConfiguration {
+    const FIELDS: &'static [&'static str] = &[
+        "acceptContentTypes",
+        "burst",
+        "contentType",
+        "kubeconfig",
+        "qps",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for ClientConnectionConfiguration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = ClientConnectionConfiguration;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ClientConnectionConfiguration")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_accept_content_types: Option<String> = None;
+                let mut value_burst: Option<i32> = None;
+                let mut value_content_type: Option<String> = None;
+                let mut value_kubeconfig: Option<String> = None;
+                let mut value_qps: Option<f64> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ClientConnectionConfiguration", ClientConnectionConfiguration::FIELDS))? {
+                    match key {
+                        Some(0) => value_accept_content_types = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_burst = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_content_type = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_kubeconfig = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_qps = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(ClientConnectionConfiguration {
+                    accept_content_types: value_accept_content_types,
+                    burst: value_burst,
+                    content_type: value_content_type,
+                    kubeconfig: value_kubeconfig,
+                    qps: value_qps,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ClientConnectionConfiguration {
+                    accept_content_types: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    burst: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    content_type: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    kubeconfig: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    qps: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ClientConnectionConfiguration",
+            ClientConnectionConfiguration::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ClientConnectionConfiguration> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ClientConnectionConfiguration")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_accept_content_types = false;
+                let mut has_burst = false;
+                let mut has_content_type = false;
+                let mut has_kubeconfig = false;
+                let mut has_qps = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ClientConnectionConfiguration", ClientConnectionConfiguration::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.accept_content_types))?; has_accept_content_types = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.burst))?; has_burst = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.content_type))?; has_content_type = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.kubeconfig))?; has_kubeconfig = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.qps))?; has_qps = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_accept_content_types {
+                    self.0.accept_content_types = None;
+                }
+                if !has_burst {
+                    self.0.burst = None;
+                }
+                if !has_content_type {
+                    self.0.content_type = None;
+                }
+                if !has_kubeconfig {
+                    self.0.kubeconfig = None;
+                }
+                if !has_qps {
+                    self.0.qps = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.accept_content_types))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.burst))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.content_type))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.kubeconfig))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.qps))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ClientConnectionConfiguration",
+            ClientConnectionConfiguration::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for ClientConnectionConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ClientConnectionConfiguration", 5)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "acceptContentTypes", &self.accept_content_types)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "burst", &self.burst)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "contentType", &self.content_type)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kubeconfig", &self.kubeconfig)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "qps", &self.qps)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            "ClientConnectionConfiguration",
+            self.accept_content_types.as_ref().map_or(0, |_| 1) +
+            self.burst.as_ref().map_or(0, |_| 1) +
+            self.content_type.as_ref().map_or(0, |_| 1) +
+            self.kubeconfig.as_ref().map_or(0, |_| 1) +
+            self.qps.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.accept_content_types {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "acceptContentTypes", value)?;
+        }
+        if let Some(value) = &self.burst {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "burst", value)?;
+        }
+        if let Some(value) = &self.content_type {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "contentType", value)?;
+        }
+        if let Some(value) = &self.kubeconfig {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kubeconfig", value)?;
+        }
+        if let Some(value) = &self.qps {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "qps", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`ClientConnectionConfiguration`]. Created with [`ClientConnectionConfiguration::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ClientConnectionConfigurationBuilder {
+    accept_content_types: Option<String>,
+    burst: Option<i32>,
+    content_type: Option<String>,
+    kubeconfig: Option<String>,
+    qps: Option<f64>,
+}
+
+#[cfg(feature = "builder")]
+impl ClientConnectionConfiguration {
+    /// Starts building a `ClientConnectionConfiguration`. Its required fields must be set before [`ClientConnectionConfigurationBuilder::build`] is called.
+    pub fn builder() -> ClientConnectionConfigurationBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ClientConnectionConfigurationBuilder {
+    /// Sets [`ClientConnectionConfiguration::accept_content_types`].
+    pub fn accept_content_types(mut self, value: impl Into<String>) -> Self {
+        self.accept_content_types = Some(value.into());
+        self
+    }
+
+    /// Sets [`ClientConnectionConfiguration::burst`].
+    pub fn burst(mut self, value: impl Into<i32>) -> Self {
+        self.burst = Some(value.into());
+        self
+    }
+
+    /// Sets [`ClientConnectionConfiguration::content_type`].
+    pub fn content_type(mut self, value: impl Into<String>) -> Self {
+        self.content_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`ClientConnectionConfiguration::kubeconfig`].
+    pub fn kubeconfig(mut self, value: impl Into<String>) -> Self {
+        self.kubeconfig = Some(value.into());
+        self
+    }
+
+    /// Sets [`ClientConnectionConfiguration::qps`].
+    pub fn qps(mut self, value: impl Into<f64>) -> Self {
+        self.qps = Some(value.into());
+        self
+    }
+
+    /// Builds the `ClientConnectionConfiguration`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ClientConnectionConfiguration, crate::MissingFieldError> {
+        Ok(ClientConnectionConfiguration {
+            accept_content_types: self.accept_content_types,
+            burst: self.burst,
+            content_type: self.content_type,
+            kubeconfig: self.kubeconfig,
+            qps: self.qps,
+        })
+    }
+}
+
+/// The apply configuration of [`ClientConnectionConfiguration`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ClientConnectionConfigurationApplyConfiguration {
+    /// See [`ClientConnectionConfiguration::accept_content_types`].
+    pub accept_content_types: Option<String>,
+
+    /// See [`ClientConnectionConfiguration::burst`].
+    pub burst: Option<i32>,
+
+    /// See [`ClientConnectionConfiguration::content_type`].
+    pub content_type: Option<String>,
+
+    /// See [`ClientConnectionConfiguration::kubeconfig`].
+    pub kubeconfig: Option<String>,
+
+    /// See [`ClientConnectionConfiguration::qps`].
+    pub qps: Option<f64>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ClientConnectionConfigurationApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ClientConnectionConfigurationApplyConfiguration",
+            self.accept_content_types.as_ref().map_or(0, |_| 1) +
+            self.burst.as_ref().map_or(0, |_| 1) +
+            self.content_type.as_ref().map_or(0, |_| 1) +
+            self.kubeconfig.as_ref().map_or(0, |_| 1) +
+            self.qps.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.accept_content_types {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "acceptContentTypes", value)?;
+        }
+        if let Some(value) = &self.burst {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "burst", value)?;
+        }
+        if let Some(value) = &self.content_type {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "contentType", value)?;
+        }
+        if let Some(value) = &self.kubeconfig {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kubeconfig", value)?;
+        }
+        if let Some(value) = &self.qps {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "qps", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for ClientConnectionConfiguration {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "component_base::config::v1alpha1::ClientConnectionConfiguration".to_owned()
+        }
+        else {
+            "io.k8s.component-base.config.v1alpha1.ClientConnectionConfiguration".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("ClientConnectionConfiguration contains details for constructing a client.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "acceptContentTypes".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("acceptContentTypes defines the Accept header sent by clients when connecting to a server, overriding the default value of 'application/json'. This field will control all connections to the server used by a particular client.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "burst".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("burst allows extra queries to accumulate when a client is exceeding its rate.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int32".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "contentType".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("contentType is the content type used when sending data to the server from this client.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "kubeconfig".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("kubeconfig is the path to a KubeConfig file.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "qps".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("qps controls the number of queries per second allowed for this connection.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Number))),
+                            format: Some("double".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ClientConnectionConfiguration {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ClientConnectionConfiguration {
+            accept_content_types: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            burst: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            content_type: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            kubeconfig: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            qps: crate::arbitrary2::option(u, crate::arbitrary2::json_f64)?,
+        }))
+    }
+}
@@ -17,6 +17,7 @@ pub struct DeleteOptional<'a> {
     pub kind: Option<&'a str>,
 
     /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the "orphan" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[deprecated(note = "please use the PropagationPolicy, this field will be deprecated in 1.7.")]
     pub orphan_dependents: Option<bool>,
 
     /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
@@ -28,6 +29,18 @@ pub struct DeleteOptional<'a> {
 
 impl<'a> crate::serde::Serialize for DeleteOptional<'a> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("DeleteOptional", 7)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", &self.api_version)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "dryRun", &self.dry_run)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "gracePeriodSeconds", &self.grace_period_seconds)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", &self.kind)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "orphanDependents", &self.orphan_dependents)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "preconditions", &self.preconditions)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "propagationPolicy", &self.propagation_policy)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "DeleteOptional",
             self.api_version.as_ref().map_or(0, |_| 1) +
@@ -0,0 +1,394 @@
+// Generated from definition io.k8s.kube-proxy.config.v1alpha1.KubeProxyConntrackConfiguration
+
+/// KubeProxyConntrackConfiguration contains conntrack settings for the Kubernetes proxy server.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct KubeProxyConntrackConfiguration {
+    /// maxPerCore is the maximum number of NAT connections to track per CPU core (0 to leave the limit as-is and ignore min).
+    pub max_per_core: Option<i32>,
+
+    /// min is the minimum value of connect-tracking records to allocate, regardless of conntrackMaxPerCore (set maxPerCore=0 to leave the limit as-is).
+    pub min: Option<i32>,
+
+    /// tcpCloseWaitTimeout is how long an idle conntrack entry in CLOSE_WAIT state will remain in the conntrack table. (e.g. '60s'). Must be greater than 0 to set.
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub tcp_close_wait_timeout: Option<String>,
+
+    /// tcpEstablishedTimeout is how long an idle TCP connection will be kept open (e.g. '2s').  Must be greater than 0 to set.
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub tcp_established_timeout: Option<String>,
+}
+
+impl KubeProxyConntrackConfiguration {
+    /// Returns this value with [`KubeProxyConntrackConfiguration::max_per_core`] set to the given value.
+    #[must_use]
+    pub fn with_max_per_core(mut self, value: impl Into<i32>) -> Self {
+        self.max_per_core = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyConntrackConfiguration::min`] set to the given value.
+    #[must_use]
+    pub fn with_min(mut self, value: impl Into<i32>) -> Self {
+        self.min = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyConntrackConfiguration::tcp_close_wait_timeout`] set to the given value.
+    #[must_use]
+    pub fn with_tcp_close_wait_timeout(mut self, value: impl Into<String>) -> Self {
+        self.tcp_close_wait_timeout = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyConntrackConfiguration::tcp_established_timeout`] set to the given value.
+    #[must_use]
+    pub fn with_tcp_established_timeout(mut self, value: impl Into<String>) -> Self {
+        self.tcp_established_timeout = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for KubeProxyConntrackConfiguration {}
+
+impl KubeProxyConntrackConfiguration {
+    const FIELDS: &'static [&'static str] = &[
+        "maxPerCore",
+        "min",
+        "tcpCloseWaitTimeout",
+        "tcpEstablishedTimeout",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for KubeProxyConntrackConfiguration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = KubeProxyConntrackConfiguration;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("KubeProxyConntrackConfiguration")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_max_per_core: Option<i32> = None;
+                let mut value_min: Option<i32> = None;
+                let mut value_tcp_close_wait_timeout: Option<String> = None;
+                let mut value_tcp_established_timeout: Option<String> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("KubeProxyConntrackConfiguration", KubeProxyConntrackConfiguration::FIELDS))? {
+                    match key {
+                        Some(0) => value_max_per_core = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_min = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_tcp_close_wait_timeout = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_tcp_established_timeout = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(KubeProxyConntrackConfiguration {
+                    max_per_core: value_max_per_core,
+                    min: value_min,
+                    tcp_close_wait_timeout: value_tcp_close_wait_timeout,
+                    tcp_established_timeout: value_tcp_established_timeout,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(KubeProxyConntrackConfiguration {
+                    max_per_core: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    min: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    tcp_close_wait_timeout: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    tcp_established_timeout: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "KubeProxyConntrackConfiguration",
+            KubeProxyConntrackConfiguration::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, KubeProxyConntrackConfiguration> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("KubeProxyConntrackConfiguration")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_max_per_core = false;
+                let mut has_min = false;
+                let mut has_tcp_close_wait_timeout = false;
+                let mut has_tcp_established_timeout = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("KubeProxyConntrackConfiguration", KubeProxyConntrackConfiguration::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.max_per_core))?; has_max_per_core = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.min))?; has_min = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.tcp_close_wait_timeout))?; has_tcp_close_wait_timeout = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.tcp_established_timeout))?; has_tcp_established_timeout = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_max_per_core {
+                    self.0.max_per_core = None;
+                }
+                if !has_min {
+                    self.0.min = None;
+                }
+                if !has_tcp_close_wait_timeout {
+                    self.0.tcp_close_wait_timeout = None;
+                }
+                if !has_tcp_established_timeout {
+                    self.0.tcp_established_timeout = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.max_per_core))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.min))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.tcp_close_wait_timeout))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.tcp_established_timeout))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "KubeProxyConntrackConfiguration",
+            KubeProxyConntrackConfiguration::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for KubeProxyConntrackConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("KubeProxyConntrackConfiguration", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maxPerCore", &self.max_per_core)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "min", &self.min)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tcpCloseWaitTimeout", &self.tcp_close_wait_timeout)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tcpEstablishedTimeout", &self.tcp_established_timeout)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            "KubeProxyConntrackConfiguration",
+            self.max_per_core.as_ref().map_or(0, |_| 1) +
+            self.min.as_ref().map_or(0, |_| 1) +
+            self.tcp_close_wait_timeout.as_ref().map_or(0, |_| 1) +
+            self.tcp_established_timeout.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.max_per_core {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maxPerCore", value)?;
+        }
+        if let Some(value) = &self.min {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "min", value)?;
+        }
+        if let Some(value) = &self.tcp_close_wait_timeout {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tcpCloseWaitTimeout", value)?;
+        }
+        if let Some(value) = &self.tcp_established_timeout {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tcpEstablishedTimeout", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`KubeProxyConntrackConfiguration`]. Created with [`KubeProxyConntrackConfiguration::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct KubeProxyConntrackConfigurationBuilder {
+    max_per_core: Option<i32>,
+    min: Option<i32>,
+    tcp_close_wait_timeout: Option<String>,
+    tcp_established_timeout: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl KubeProxyConntrackConfiguration {
+    /// Starts building a `KubeProxyConntrackConfiguration`. Its required fields must be set before [`KubeProxyConntrackConfigurationBuilder::build`] is called.
+    pub fn builder() -> KubeProxyConntrackConfigurationBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl KubeProxyConntrackConfigurationBuilder {
+    /// Sets [`KubeProxyConntrackConfiguration::max_per_core`].
+    pub fn max_per_core(mut self, value: impl Into<i32>) -> Self {
+        self.max_per_core = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyConntrackConfiguration::min`].
+    pub fn min(mut self, value: impl Into<i32>) -> Self {
+        self.min = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyConntrackConfiguration::tcp_close_wait_timeout`].
+    pub fn tcp_close_wait_timeout(mut self, value: impl Into<String>) -> Self {
+        self.tcp_close_wait_timeout = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyConntrackConfiguration::tcp_established_timeout`].
+    pub fn tcp_established_timeout(mut self, value: impl Into<String>) -> Self {
+        self.tcp_established_timeout = Some(value.into());
+        self
+    }
+
+    /// Builds the `KubeProxyConntrackConfiguration`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<KubeProxyConntrackConfiguration, crate::MissingFieldError> {
+        Ok(KubeProxyConntrackConfiguration {
+            max_per_core: self.max_per_core,
+            min: self.min,
+            tcp_close_wait_timeout: self.tcp_close_wait_timeout,
+            tcp_established_timeout: self.tcp_established_timeout,
+        })
+    }
+}
+
+/// The apply configuration of [`KubeProxyConntrackConfiguration`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KubeProxyConntrackConfigurationApplyConfiguration {
+    /// See [`KubeProxyConntrackConfiguration::max_per_core`].
+    pub max_per_core: Option<i32>,
+
+    /// See [`KubeProxyConntrackConfiguration::min`].
+    pub min: Option<i32>,
+
+    /// See [`KubeProxyConntrackConfiguration::tcp_close_wait_timeout`].
+    pub tcp_close_wait_timeout: Option<String>,
+
+    /// See [`KubeProxyConntrackConfiguration::tcp_established_timeout`].
+    pub tcp_established_timeout: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for KubeProxyConntrackConfigurationApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "KubeProxyConntrackConfigurationApplyConfiguration",
+            self.max_per_core.as_ref().map_or(0, |_| 1) +
+            self.min.as_ref().map_or(0, |_| 1) +
+            self.tcp_close_wait_timeout.as_ref().map_or(0, |_| 1) +
+            self.tcp_established_timeout.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.max_per_core {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maxPerCore", value)?;
+        }
+        if let Some(value) = &self.min {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "min", value)?;
+        }
+        if let Some(value) = &self.tcp_close_wait_timeout {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tcpCloseWaitTimeout", value)?;
+        }
+        if let Some(value) = &self.tcp_established_timeout {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tcpEstablishedTimeout", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for KubeProxyConntrackConfiguration {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "kube_proxy::config::v1alpha1::KubeProxyConntrackConfiguration".to_owned()
+        }
+        else {
+            "io.k8s.kube-proxy.config.v1alpha1.KubeProxyConntrackConfiguration".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("KubeProxyConntrackConfiguration contains conntrack settings for the Kubernetes proxy server.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "maxPerCore".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("maxPerCore is the maximum number of NAT connections to track per CPU core (0 to leave the limit as-is and ignore min).".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int32".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "min".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("min is the minimum value of connect-tracking records to allocate, regardless of conntrackMaxPerCore (set maxPerCore=0 to leave the limit as-is).".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int32".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "tcpCloseWaitTimeout".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("tcpCloseWaitTimeout is how long an idle conntrack entry in CLOSE_WAIT state will remain in the conntrack table. (e.g. '60s'). Must be greater than 0 to set.\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "tcpEstablishedTimeout".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("tcpEstablishedTimeout is how long an idle TCP connection will be kept open (e.g. '2s').  Must be greater than 0 to set.\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for KubeProxyConntrackConfiguration {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(KubeProxyConntrackConfiguration {
+            max_per_core: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            min: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            tcp_close_wait_timeout: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            tcp_established_timeout: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -0,0 +1,619 @@
+// Generated from definition io.k8s.kube-proxy.config.v1alpha1.KubeProxyIPVSConfiguration
+
+/// KubeProxyIPVSConfiguration contains ipvs-related configuration details for the Kubernetes proxy server.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct KubeProxyIPVSConfiguration {
+    /// excludeCIDRs is a list of CIDR's which the ipvs proxier should not touch when cleaning up ipvs services.
+    pub exclude_cidrs: Option<Vec<String>>,
+
+    /// minSyncPeriod is the minimum period that ipvs rules are refreshed (e.g. '5s', '1m', '2h22m').
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub min_sync_period: Option<String>,
+
+    /// ipvs scheduler
+    pub scheduler: Option<String>,
+
+    /// strict ARP configure arp_ignore and arp_announce to avoid answering ARP queries from kube-ipvs0 interface
+    pub strict_arp: Option<bool>,
+
+    /// syncPeriod is the period that ipvs rules are refreshed (e.g. '5s', '1m', '2h22m').  Must be greater than 0.
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub sync_period: Option<String>,
+
+    /// tcpFinTimeout is the timeout value used for IPVS TCP sessions after receiving a FIN. The default value is 0, which preserves the current timeout value on the system.
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub tcp_fin_timeout: Option<String>,
+
+    /// tcpTimeout is the timeout value used for idle IPVS TCP sessions. The default value is 0, which preserves the current timeout value on the system.
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub tcp_timeout: Option<String>,
+
+    /// udpTimeout is the timeout value used for IPVS UDP packets. The default value is 0, which preserves the current timeout value on the system.
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub udp_timeout: Option<String>,
+}
+
+impl KubeProxyIPVSConfiguration {
+    /// Returns this value with [`KubeProxyIPVSConfiguration::exclude_cidrs`] set to the given value.
+    #[must_use]
+    pub fn with_exclude_cidrs(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.exclude_cidrs = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyIPVSConfiguration::min_sync_period`] set to the given value.
+    #[must_use]
+    pub fn with_min_sync_period(mut self, value: impl Into<String>) -> Self {
+        self.min_sync_period = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyIPVSConfiguration::scheduler`] set to the given value.
+    #[must_use]
+    pub fn with_scheduler(mut self, value: impl Into<String>) -> Self {
+        self.scheduler = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyIPVSConfiguration::strict_arp`] set to the given value.
+    #[must_use]
+    pub fn with_strict_arp(mut self, value: impl Into<bool>) -> Self {
+        self.strict_arp = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyIPVSConfiguration::sync_period`] set to the given value.
+    #[must_use]
+    pub fn with_sync_period(mut self, value: impl Into<String>) -> Self {
+        self.sync_period = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyIPVSConfiguration::tcp_fin_timeout`] set to the given value.
+    #[must_use]
+    pub fn with_tcp_fin_timeout(mut self, value: impl Into<String>) -> Self {
+        self.tcp_fin_timeout = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyIPVSConfiguration::tcp_timeout`] set to the given value.
+    #[must_use]
+    pub fn with_tcp_timeout(mut self, value: impl Into<String>) -> Self {
+        self.tcp_timeout = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyIPVSConfiguration::udp_timeout`] set to the given value.
+    #[must_use]
+    pub fn with_udp_timeout(mut self, value: impl Into<String>) -> Self {
+        self.udp_timeout = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for KubeProxyIPVSConfiguration {}
+
+impl KubeProxyIPVSConfiguration {
+    const FIELDS: &'static [&'static str] = &[
+        "excludeCIDRs",
+        "minSyncPeriod",
+        "scheduler",
+        "strictARP",
+        "syncPeriod",
+        "tcpFinTimeout",
+        "tcpTimeout",
+        "udpTimeout",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for KubeProxyIPVSConfiguration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = KubeProxyIPVSConfiguration;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("KubeProxyIPVSConfiguration")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_exclude_cidrs: Option<Vec<String>> = None;
+                let mut value_min_sync_period: Option<String> = None;
+                let mut value_scheduler: Option<String> = None;
+                let mut value_strict_arp: Option<bool> = None;
+                let mut value_sync_period: Option<String> = None;
+                let mut value_tcp_fin_timeout: Option<String> = None;
+                let mut value_tcp_timeout: Option<String> = None;
+                let mut value_udp_timeout: Option<String> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("KubeProxyIPVSConfiguration", KubeProxyIPVSConfiguration::FIELDS))? {
+                    match key {
+                        Some(0) => value_exclude_cidrs = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_min_sync_period = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_scheduler = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_strict_arp = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_sync_period = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_tcp_fin_timeout = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_tcp_timeout = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(7) => value_udp_timeout = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(KubeProxyIPVSConfiguration {
+                    exclude_cidrs: value_exclude_cidrs,
+                    min_sync_period: value_min_sync_period,
+                    scheduler: value_scheduler,
+                    strict_arp: value_strict_arp,
+                    sync_period: value_sync_period,
+                    tcp_fin_timeout: value_tcp_fin_timeout,
+                    tcp_timeout: value_tcp_timeout,
+                    udp_timeout: value_udp_timeout,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(KubeProxyIPVSConfiguration {
+                    exclude_cidrs: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    min_sync_period: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    scheduler: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    strict_arp: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    sync_period: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    tcp_fin_timeout: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    tcp_timeout: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    udp_timeout: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "KubeProxyIPVSConfiguration",
+            KubeProxyIPVSConfiguration::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, KubeProxyIPVSConfiguration> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("KubeProxyIPVSConfiguration")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_exclude_cidrs = false;
+                let mut has_min_sync_period = false;
+                let mut has_scheduler = false;
+                let mut has_strict_arp = false;
+                let mut has_sync_period = false;
+                let mut has_tcp_fin_timeout = false;
+                let mut has_tcp_timeout = false;
+                let mut has_udp_timeout = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("KubeProxyIPVSConfiguration", KubeProxyIPVSConfiguration::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.exclude_cidrs))?; has_exclude_cidrs = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.min_sync_period))?; has_min_sync_period = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.scheduler))?; has_scheduler = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.strict_arp))?; has_strict_arp = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.sync_period))?; has_sync_period = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.tcp_fin_timeout))?; has_tcp_fin_timeout = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.tcp_timeout))?; has_tcp_timeout = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.udp_timeout))?; has_udp_timeout = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_exclude_cidrs {
+                    self.0.exclude_cidrs = None;
+                }
+                if !has_min_sync_period {
+                    self.0.min_sync_period = None;
+                }
+                if !has_scheduler {
+                    self.0.scheduler = None;
+                }
+                if !has_strict_arp {
+                    self.0.strict_arp = None;
+                }
+                if !has_sync_period {
+                    self.0.sync_period = None;
+                }
+                if !has_tcp_fin_timeout {
+                    self.0.tcp_fin_timeout = None;
+                }
+                if !has_tcp_timeout {
+                    self.0.tcp_timeout = None;
+                }
+                if !has_udp_timeout {
+                    self.0.udp_timeout = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.exclude_cidrs))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.min_sync_period))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.scheduler))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.strict_arp))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.sync_period))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.tcp_fin_timeout))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.tcp_timeout))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.udp_timeout))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "KubeProxyIPVSConfiguration",
+            KubeProxyIPVSConfiguration::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for KubeProxyIPVSConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("KubeProxyIPVSConfiguration", 8)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "excludeCIDRs", &self.exclude_cidrs)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "minSyncPeriod", &self.min_sync_period)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "scheduler", &self.scheduler)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "strictARP", &self.strict_arp)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "syncPeriod", &self.sync_period)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tcpFinTimeout", &self.tcp_fin_timeout)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tcpTimeout", &self.tcp_timeout)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "udpTimeout", &self.udp_timeout)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            "KubeProxyIPVSConfiguration",
+            self.exclude_cidrs.as_ref().map_or(0, |_| 1) +
+            self.min_sync_period.as_ref().map_or(0, |_| 1) +
+            self.scheduler.as_ref().map_or(0, |_| 1) +
+            self.strict_arp.as_ref().map_or(0, |_| 1) +
+            self.sync_period.as_ref().map_or(0, |_| 1) +
+            self.tcp_fin_timeout.as_ref().map_or(0, |_| 1) +
+            self.tcp_timeout.as_ref().map_or(0, |_| 1) +
+            self.udp_timeout.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.exclude_cidrs {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "excludeCIDRs", value)?;
+        }
+        if let Some(value) = &self.min_sync_period {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "minSyncPeriod", value)?;
+        }
+        if let Some(value) = &self.scheduler {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "scheduler", value)?;
+        }
+        if let Some(value) = &self.strict_arp {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "strictARP", value)?;
+        }
+        if let Some(value) = &self.sync_period {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "syncPeriod", value)?;
+        }
+        if let Some(value) = &self.tcp_fin_timeout {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tcpFinTimeout", value)?;
+        }
+        if let Some(value) = &self.tcp_timeout {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tcpTimeout", value)?;
+        }
+        if let Some(value) = &self.udp_timeout {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "udpTimeout", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`KubeProxyIPVSConfiguration`]. Created with [`KubeProxyIPVSConfiguration::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct KubeProxyIPVSConfigurationBuilder {
+    exclude_cidrs: Option<Vec<String>>,
+    min_sync_period: Option<String>,
+    scheduler: Option<String>,
+    strict_arp: Option<bool>,
+    sync_period: Option<String>,
+    tcp_fin_timeout: Option<String>,
+    tcp_timeout: Option<String>,
+    udp_timeout: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl KubeProxyIPVSConfiguration {
+    /// Starts building a `KubeProxyIPVSConfiguration`. Its required fields must be set before [`KubeProxyIPVSConfigurationBuilder::build`] is called.
+    pub fn builder() -> KubeProxyIPVSConfigurationBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl KubeProxyIPVSConfigurationBuilder {
+    /// Sets [`KubeProxyIPVSConfiguration::exclude_cidrs`].
+    pub fn exclude_cidrs(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.exclude_cidrs = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyIPVSConfiguration::min_sync_period`].
+    pub fn min_sync_period(mut self, value: impl Into<String>) -> Self {
+        self.min_sync_period = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyIPVSConfiguration::scheduler`].
+    pub fn scheduler(mut self, value: impl Into<String>) -> Self {
+        self.scheduler = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyIPVSConfiguration::strict_arp`].
+    pub fn strict_arp(mut self, value: impl Into<bool>) -> Self {
+        self.strict_arp = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyIPVSConfiguration::sync_period`].
+    pub fn sync_period(mut self, value: impl Into<String>) -> Self {
+        self.sync_period = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyIPVSConfiguration::tcp_fin_timeout`].
+    pub fn tcp_fin_timeout(mut self, value: impl Into<String>) -> Self {
+        self.tcp_fin_timeout = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyIPVSConfiguration::tcp_timeout`].
+    pub fn tcp_timeout(mut self, value: impl Into<String>) -> Self {
+        self.tcp_timeout = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyIPVSConfiguration::udp_timeout`].
+    pub fn udp_timeout(mut self, value: impl Into<String>) -> Self {
+        self.udp_timeout = Some(value.into());
+        self
+    }
+
+    /// Builds the `KubeProxyIPVSConfiguration`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<KubeProxyIPVSConfiguration, crate::MissingFieldError> {
+        Ok(KubeProxyIPVSConfiguration {
+            exclude_cidrs: self.exclude_cidrs,
+            min_sync_period: self.min_sync_period,
+            scheduler: self.scheduler,
+            strict_arp: self.strict_arp,
+            sync_period: self.sync_period,
+            tcp_fin_timeout: self.tcp_fin_timeout,
+            tcp_timeout: self.tcp_timeout,
+            udp_timeout: self.udp_timeout,
+        })
+    }
+}
+
+/// The apply configuration of [`KubeProxyIPVSConfiguration`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KubeProxyIPVSConfigurationApplyConfiguration {
+    /// See [`KubeProxyIPVSConfiguration::exclude_cidrs`].
+    pub exclude_cidrs: Option<Vec<String>>,
+
+    /// See [`KubeProxyIPVSConfiguration::min_sync_period`].
+    pub min_sync_period: Option<String>,
+
+    /// See [`KubeProxyIPVSConfiguration::scheduler`].
+    pub scheduler: Option<String>,
+
+    /// See [`KubeProxyIPVSConfiguration::strict_arp`].
+    pub strict_arp: Option<bool>,
+
+    /// See [`KubeProxyIPVSConfiguration::sync_period`].
+    pub sync_period: Option<String>,
+
+    /// See [`KubeProxyIPVSConfiguration::tcp_fin_timeout`].
+    pub tcp_fin_timeout: Option<String>,
+
+    /// See [`KubeProxyIPVSConfiguration::tcp_timeout`].
+    pub tcp_timeout: Option<String>,
+
+    /// See [`KubeProxyIPVSConfiguration::udp_timeout`].
+    pub udp_timeout: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for KubeProxyIPVSConfigurationApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "KubeProxyIPVSConfigurationApplyConfiguration",
+            self.exclude_cidrs.as_ref().map_or(0, |_| 1) +
+            self.min_sync_period.as_ref().map_or(0, |_| 1) +
+            self.scheduler.as_ref().map_or(0, |_| 1) +
+            self.strict_arp.as_ref().map_or(0, |_| 1) +
+            self.sync_period.as_ref().map_or(0, |_| 1) +
+            self.tcp_fin_timeout.as_ref().map_or(0, |_| 1) +
+            self.tcp_timeout.as_ref().map_or(0, |_| 1) +
+            self.udp_timeout.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.exclude_cidrs {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "excludeCIDRs", value)?;
+        }
+        if let Some(value) = &self.min_sync_period {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "minSyncPeriod", value)?;
+        }
+        if let Some(value) = &self.scheduler {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "scheduler", value)?;
+        }
+        if let Some(value) = &self.strict_arp {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "strictARP", value)?;
+        }
+        if let Some(value) = &self.sync_period {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "syncPeriod", value)?;
+        }
+        if let Some(value) = &self.tcp_fin_timeout {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tcpFinTimeout", value)?;
+        }
+        if let Some(value) = &self.tcp_timeout {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tcpTimeout", value)?;
+        }
+        if let Some(value) = &self.udp_timeout {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "udpTimeout", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for KubeProxyIPVSConfiguration {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "kube_proxy::config::v1alpha1::KubeProxyIPVSConfiguration".to_owned()
+        }
+        else {
+            "io.k8s.kube-proxy.config.v1alpha1.KubeProxyIPVSConfiguration".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("KubeProxyIPVSConfiguration contains ipvs-related configuration details for the Kubernetes proxy server.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "excludeCIDRs".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("excludeCIDRs is a list of CIDR's which the ipvs proxier should not touch when cleaning up ipvs services.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Array))),
+                            array: Some(Box::new(crate::schemars::schema::ArrayValidation {
+                                items: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(
+                                    crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                                        instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                                        ..Default::default()
+                                    })
+                                ))),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "minSyncPeriod".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("minSyncPeriod is the minimum period that ipvs rules are refreshed (e.g. '5s', '1m', '2h22m').\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "scheduler".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("ipvs scheduler".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "strictARP".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("strict ARP configure arp_ignore and arp_announce to avoid answering ARP queries from kube-ipvs0 interface".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "syncPeriod".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("syncPeriod is the period that ipvs rules are refreshed (e.g. '5s', '1m', '2h22m').  Must be greater than 0.\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "tcpFinTimeout".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("tcpFinTimeout is the timeout value used for IPVS TCP sessions after receiving a FIN. The default value is 0, which preserves the current timeout value on the system.\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "tcpTimeout".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("tcpTimeout is the timeout value used for idle IPVS TCP sessions. The default value is 0, which preserves the current timeout value on the system.\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "udpTimeout".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("udpTimeout is the timeout value used for IPVS UDP packets. The default value is 0, which preserves the current timeout value on the system.\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for KubeProxyIPVSConfiguration {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(KubeProxyIPVSConfiguration {
+            exclude_cidrs: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            min_sync_period: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            scheduler: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            strict_arp: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            sync_period: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            tcp_fin_timeout: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            tcp_timeout: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            udp_timeout: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
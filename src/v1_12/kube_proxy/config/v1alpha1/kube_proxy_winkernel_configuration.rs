@@ -0,0 +1,335 @@
+// Generated from definition io.k8s.kube-proxy.config.v1alpha1.KubeProxyWinkernelConfiguration
+
+/// KubeProxyWinkernelConfiguration contains Windows/HNS settings for the Kubernetes proxy server.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct KubeProxyWinkernelConfiguration {
+    /// enableDSR tells kube-proxy whether HNS policies should be created with DSR
+    pub enable_dsr: Option<bool>,
+
+    /// networkName is the name of the network kube-proxy will use to create endpoints and policies
+    pub network_name: Option<String>,
+
+    /// sourceVip is the IP address of the source VIP endoint used for NAT when loadbalancing
+    pub source_vip: Option<String>,
+}
+
+impl KubeProxyWinkernelConfiguration {
+    /// Returns this value with [`KubeProxyWinkernelConfiguration::enable_dsr`] set to the given value.
+    #[must_use]
+    pub fn with_enable_dsr(mut self, value: impl Into<bool>) -> Self {
+        self.enable_dsr = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyWinkernelConfiguration::network_name`] set to the given value.
+    #[must_use]
+    pub fn with_network_name(mut self, value: impl Into<String>) -> Self {
+        self.network_name = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyWinkernelConfiguration::source_vip`] set to the given value.
+    #[must_use]
+    pub fn with_source_vip(mut self, value: impl Into<String>) -> Self {
+        self.source_vip = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for KubeProxyWinkernelConfiguration {}
+
+impl KubeProxyWinkernelConfiguration {
+    const FIELDS: &'static [&'static str] = &[
+        "enableDSR",
+        "networkName",
+        "sourceVip",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for KubeProxyWinkernelConfiguration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = KubeProxyWinkernelConfiguration;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("KubeProxyWinkernelConfiguration")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_enable_dsr: Option<bool> = None;
+                let mut value_network_name: Option<String> = None;
+                let mut value_source_vip: Option<String> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("KubeProxyWinkernelConfiguration", KubeProxyWinkernelConfiguration::FIELDS))? {
+                    match key {
+                        Some(0) => value_enable_dsr = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_network_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_source_vip = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(KubeProxyWinkernelConfiguration {
+                    enable_dsr: value_enable_dsr,
+                    network_name: value_network_name,
+                    source_vip: value_source_vip,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(KubeProxyWinkernelConfiguration {
+                    enable_dsr: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    network_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    source_vip: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "KubeProxyWinkernelConfiguration",
+            KubeProxyWinkernelConfiguration::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, KubeProxyWinkernelConfiguration> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("KubeProxyWinkernelConfiguration")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_enable_dsr = false;
+                let mut has_network_name = false;
+                let mut has_source_vip = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("KubeProxyWinkernelConfiguration", KubeProxyWinkernelConfiguration::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.enable_dsr))?; has_enable_dsr = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.network_name))?; has_network_name = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.source_vip))?; has_source_vip = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_enable_dsr {
+                    self.0.enable_dsr = None;
+                }
+                if !has_network_name {
+                    self.0.network_name = None;
+                }
+                if !has_source_vip {
+                    self.0.source_vip = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.enable_dsr))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.network_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.source_vip))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "KubeProxyWinkernelConfiguration",
+            KubeProxyWinkernelConfiguration::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for KubeProxyWinkernelConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("KubeProxyWinkernelConfiguration", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableDSR", &self.enable_dsr)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "networkName", &self.network_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "sourceVip", &self.source_vip)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            "KubeProxyWinkernelConfiguration",
+            self.enable_dsr.as_ref().map_or(0, |_| 1) +
+            self.network_name.as_ref().map_or(0, |_| 1) +
+            self.source_vip.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.enable_dsr {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableDSR", value)?;
+        }
+        if let Some(value) = &self.network_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "networkName", value)?;
+        }
+        if let Some(value) = &self.source_vip {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "sourceVip", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`KubeProxyWinkernelConfiguration`]. Created with [`KubeProxyWinkernelConfiguration::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct KubeProxyWinkernelConfigurationBuilder {
+    enable_dsr: Option<bool>,
+    network_name: Option<String>,
+    source_vip: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl KubeProxyWinkernelConfiguration {
+    /// Starts building a `KubeProxyWinkernelConfiguration`. Its required fields must be set before [`KubeProxyWinkernelConfigurationBuilder::build`] is called.
+    pub fn builder() -> KubeProxyWinkernelConfigurationBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl KubeProxyWinkernelConfigurationBuilder {
+    /// Sets [`KubeProxyWinkernelConfiguration::enable_dsr`].
+    pub fn enable_dsr(mut self, value: impl Into<bool>) -> Self {
+        self.enable_dsr = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyWinkernelConfiguration::network_name`].
+    pub fn network_name(mut self, value: impl Into<String>) -> Self {
+        self.network_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyWinkernelConfiguration::source_vip`].
+    pub fn source_vip(mut self, value: impl Into<String>) -> Self {
+        self.source_vip = Some(value.into());
+        self
+    }
+
+    /// Builds the `KubeProxyWinkernelConfiguration`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<KubeProxyWinkernelConfiguration, crate::MissingFieldError> {
+        Ok(KubeProxyWinkernelConfiguration {
+            enable_dsr: self.enable_dsr,
+            network_name: self.network_name,
+            source_vip: self.source_vip,
+        })
+    }
+}
+
+/// The apply configuration of [`KubeProxyWinkernelConfiguration`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KubeProxyWinkernelConfigurationApplyConfiguration {
+    /// See [`KubeProxyWinkernelConfiguration::enable_dsr`].
+    pub enable_dsr: Option<bool>,
+
+    /// See [`KubeProxyWinkernelConfiguration::network_name`].
+    pub network_name: Option<String>,
+
+    /// See [`KubeProxyWinkernelConfiguration::source_vip`].
+    pub source_vip: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for KubeProxyWinkernelConfigurationApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "KubeProxyWinkernelConfigurationApplyConfiguration",
+            self.enable_dsr.as_ref().map_or(0, |_| 1) +
+            self.network_name.as_ref().map_or(0, |_| 1) +
+            self.source_vip.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.enable_dsr {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableDSR", value)?;
+        }
+        if let Some(value) = &self.network_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "networkName", value)?;
+        }
+        if let Some(value) = &self.source_vip {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "sourceVip", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for KubeProxyWinkernelConfiguration {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "kube_proxy::config::v1alpha1::KubeProxyWinkernelConfiguration".to_owned()
+        }
+        else {
+            "io.k8s.kube-proxy.config.v1alpha1.KubeProxyWinkernelConfiguration".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("KubeProxyWinkernelConfiguration contains Windows/HNS settings for the Kubernetes proxy server.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "enableDSR".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("enableDSR tells kube-proxy whether HNS policies should be created with DSR".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "networkName".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("networkName is the name of the network kube-proxy will use to create endpoints and policies".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "sourceVip".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("sourceVip is the IP address of the source VIP endoint used for NAT when loadbalancing".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for KubeProxyWinkernelConfiguration {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(KubeProxyWinkernelConfiguration {
+            enable_dsr: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            network_name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            source_vip: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -0,0 +1,284 @@
+// Generated from definition io.k8s.kubelet.config.v1beta1.KubeletWebhookAuthentication
+
+/// KubeletWebhookAuthentication configures bearer token authentication of requests to the kubelet server.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct KubeletWebhookAuthentication {
+    /// cacheTTL enables caching of authentication results
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub cache_ttl: Option<String>,
+
+    /// enabled allows bearer token authentication backed by the tokenreviews.authentication.k8s.io API.
+    pub enabled: Option<bool>,
+}
+
+impl KubeletWebhookAuthentication {
+    /// Returns this value with [`KubeletWebhookAuthentication::cache_ttl`] set to the given value.
+    #[must_use]
+    pub fn with_cache_ttl(mut self, value: impl Into<String>) -> Self {
+        self.cache_ttl = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletWebhookAuthentication::enabled`] set to the given value.
+    #[must_use]
+    pub fn with_enabled(mut self, value: impl Into<bool>) -> Self {
+        self.enabled = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for KubeletWebhookAuthentication {}
+
+impl KubeletWebhookAuthentication {
+    const FIELDS: &'static [&'static str] = &[
+        "cacheTTL",
+        "enabled",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for KubeletWebhookAuthentication {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = KubeletWebhookAuthentication;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("KubeletWebhookAuthentication")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_cache_ttl: Option<String> = None;
+                let mut value_enabled: Option<bool> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("KubeletWebhookAuthentication", KubeletWebhookAuthentication::FIELDS))? {
+                    match key {
+                        Some(0) => value_cache_ttl = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_enabled = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(KubeletWebhookAuthentication {
+                    cache_ttl: value_cache_ttl,
+                    enabled: value_enabled,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(KubeletWebhookAuthentication {
+                    cache_ttl: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    enabled: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "KubeletWebhookAuthentication",
+            KubeletWebhookAuthentication::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, KubeletWebhookAuthentication> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("KubeletWebhookAuthentication")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_cache_ttl = false;
+                let mut has_enabled = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("KubeletWebhookAuthentication", KubeletWebhookAuthentication::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.cache_ttl))?; has_cache_ttl = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.enabled))?; has_enabled = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_cache_ttl {
+                    self.0.cache_ttl = None;
+                }
+                if !has_enabled {
+                    self.0.enabled = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.cache_ttl))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.enabled))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "KubeletWebhookAuthentication",
+            KubeletWebhookAuthentication::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for KubeletWebhookAuthentication {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("KubeletWebhookAuthentication", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cacheTTL", &self.cache_ttl)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enabled", &self.enabled)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            "KubeletWebhookAuthentication",
+            self.cache_ttl.as_ref().map_or(0, |_| 1) +
+            self.enabled.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.cache_ttl {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cacheTTL", value)?;
+        }
+        if let Some(value) = &self.enabled {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enabled", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`KubeletWebhookAuthentication`]. Created with [`KubeletWebhookAuthentication::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct KubeletWebhookAuthenticationBuilder {
+    cache_ttl: Option<String>,
+    enabled: Option<bool>,
+}
+
+#[cfg(feature = "builder")]
+impl KubeletWebhookAuthentication {
+    /// Starts building a `KubeletWebhookAuthentication`. Its required fields must be set before [`KubeletWebhookAuthenticationBuilder::build`] is called.
+    pub fn builder() -> KubeletWebhookAuthenticationBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl KubeletWebhookAuthenticationBuilder {
+    /// Sets [`KubeletWebhookAuthentication::cache_ttl`].
+    pub fn cache_ttl(mut self, value: impl Into<String>) -> Self {
+        self.cache_ttl = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletWebhookAuthentication::enabled`].
+    pub fn enabled(mut self, value: impl Into<bool>) -> Self {
+        self.enabled = Some(value.into());
+        self
+    }
+
+    /// Builds the `KubeletWebhookAuthentication`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<KubeletWebhookAuthentication, crate::MissingFieldError> {
+        Ok(KubeletWebhookAuthentication {
+            cache_ttl: self.cache_ttl,
+            enabled: self.enabled,
+        })
+    }
+}
+
+/// The apply configuration of [`KubeletWebhookAuthentication`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KubeletWebhookAuthenticationApplyConfiguration {
+    /// See [`KubeletWebhookAuthentication::cache_ttl`].
+    pub cache_ttl: Option<String>,
+
+    /// See [`KubeletWebhookAuthentication::enabled`].
+    pub enabled: Option<bool>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for KubeletWebhookAuthenticationApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "KubeletWebhookAuthenticationApplyConfiguration",
+            self.cache_ttl.as_ref().map_or(0, |_| 1) +
+            self.enabled.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.cache_ttl {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cacheTTL", value)?;
+        }
+        if let Some(value) = &self.enabled {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enabled", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for KubeletWebhookAuthentication {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "kubelet::config::v1beta1::KubeletWebhookAuthentication".to_owned()
+        }
+        else {
+            "io.k8s.kubelet.config.v1beta1.KubeletWebhookAuthentication".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("KubeletWebhookAuthentication configures bearer token authentication of requests to the kubelet server.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "cacheTTL".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("cacheTTL enables caching of authentication results\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "enabled".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("enabled allows bearer token authentication backed by the tokenreviews.authentication.k8s.io API.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for KubeletWebhookAuthentication {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(KubeletWebhookAuthentication {
+            cache_ttl: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            enabled: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -0,0 +1,3 @@
+pub mod v1beta1;
+
+pub mod v1beta2;
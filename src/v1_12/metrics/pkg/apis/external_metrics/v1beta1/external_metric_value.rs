@@ -0,0 +1,532 @@
+// Generated from definition io.k8s.metrics.pkg.apis.external_metrics.v1beta1.ExternalMetricValue
+
+/// ExternalMetricValue is a metric value for external metric. A single metric value is identified by metric name and a set of string labels. For one metric there can be multiple values with different sets of labels.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ExternalMetricValue {
+    /// a set of labels that identify a single time series for the metric
+    pub metric_labels: std::collections::BTreeMap<String, String>,
+
+    /// the name of the metric
+    pub metric_name: String,
+
+    /// indicates the time at which the metrics were produced
+    pub timestamp: crate::apimachinery::pkg::apis::meta::v1::Time,
+
+    /// the value of the metric for this
+    pub value: crate::apimachinery::pkg::api::resource::Quantity,
+
+    /// indicates the window (\[Timestamp-Window, Timestamp\]) from which these metrics were calculated, when returning rate metrics calculated from cumulative metrics (or zero for non-calculated instantaneous metrics).
+    pub window: Option<i64>,
+}
+
+impl ExternalMetricValue {
+    /// Constructs a `ExternalMetricValue` from its required fields. Its optional fields are unset.
+    pub fn new(metric_labels: impl Into<std::collections::BTreeMap<String, String>>, metric_name: impl Into<String>, timestamp: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        ExternalMetricValue {
+            metric_labels: metric_labels.into(),
+            metric_name: metric_name.into(),
+            timestamp: timestamp.into(),
+            value: value.into(),
+            window: None,
+        }
+    }
+}
+
+impl ExternalMetricValue {
+    /// Returns this value with [`ExternalMetricValue::metric_labels`] set to the given value.
+    #[must_use]
+    pub fn with_metric_labels(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.metric_labels = value.into();
+        self
+    }
+
+    /// Returns this value with [`ExternalMetricValue::metric_name`] set to the given value.
+    #[must_use]
+    pub fn with_metric_name(mut self, value: impl Into<String>) -> Self {
+        self.metric_name = value.into();
+        self
+    }
+
+    /// Returns this value with [`ExternalMetricValue::timestamp`] set to the given value.
+    #[must_use]
+    pub fn with_timestamp(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.timestamp = value.into();
+        self
+    }
+
+    /// Returns this value with [`ExternalMetricValue::value`] set to the given value.
+    #[must_use]
+    pub fn with_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    /// Returns this value with [`ExternalMetricValue::window`] set to the given value.
+    #[must_use]
+    pub fn with_window(mut self, value: impl Into<i64>) -> Self {
+        self.window = Some(value.into());
+        self
+    }
+}
+
+impl crate::Resource for ExternalMetricValue {
+    const API_VERSION: &'static str = "external.metrics.k8s.io/v1beta1";
+    const GROUP: &'static str = "external.metrics.k8s.io";
+    const KIND: &'static str = "ExternalMetricValue";
+    const VERSION: &'static str = "v1beta1";
+    const URL_PATH_SEGMENT: &'static str = "";
+    const VERBS: &'static [&'static str] = &[];
+    const SINGULAR: &'static str = "externalmetricvalue";
+    const SHORT_NAMES: &'static [&'static str] = &[];
+    type Scope = crate::ClusterResourceScope;
+}
+
+impl crate::ListableResource for ExternalMetricValue {
+    const LIST_KIND: &'static str = "ExternalMetricValueList";
+}
+
+impl crate::MergeStrategyInfo for ExternalMetricValue {}
+
+impl ExternalMetricValue {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "kind",
+        "metricLabels",
+        "metricName",
+        "timestamp",
+        "value",
+        "window",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for ExternalMetricValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = ExternalMetricValue;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<Self::Value as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_metric_labels: Option<std::collections::BTreeMap<String, String>> = None;
+                let mut value_metric_name: Option<String> = None;
+                let mut value_timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
+                let mut value_value: Option<crate::apimachinery::pkg::api::resource::Quantity> = None;
+                let mut value_window: Option<i64> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ExternalMetricValue", ExternalMetricValue::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <Self::Value as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<Self::Value as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <Self::Value as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<Self::Value as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => value_metric_labels = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_metric_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(4) => value_timestamp = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(5) => value_value = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(6) => value_window = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(ExternalMetricValue {
+                    metric_labels: value_metric_labels.ok_or_else(|| crate::serde2::missing_field("ExternalMetricValue", "metricLabels", &["metricLabels", "metricName", "timestamp", "value"]))?,
+                    metric_name: value_metric_name.ok_or_else(|| crate::serde2::missing_field("ExternalMetricValue", "metricName", &["metricLabels", "metricName", "timestamp", "value"]))?,
+                    timestamp: value_timestamp.ok_or_else(|| crate::serde2::missing_field("ExternalMetricValue", "timestamp", &["metricLabels", "metricName", "timestamp", "value"]))?,
+                    value: value_value.ok_or_else(|| crate::serde2::missing_field("ExternalMetricValue", "value", &["metricLabels", "metricName", "timestamp", "value"]))?,
+                    window: value_window,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ExternalMetricValue {
+                    metric_labels: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    metric_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    timestamp: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    value: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    window: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            ExternalMetricValue::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ExternalMetricValue> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<ExternalMetricValue as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_metric_labels = false;
+                let mut has_metric_name = false;
+                let mut has_timestamp = false;
+                let mut has_value = false;
+                let mut has_window = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ExternalMetricValue", ExternalMetricValue::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <ExternalMetricValue as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<ExternalMetricValue as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <ExternalMetricValue as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<ExternalMetricValue as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metric_labels))?; has_metric_labels = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metric_name))?; has_metric_name = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.timestamp))?; has_timestamp = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.value))?; has_value = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.window))?; has_window = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_metric_labels {
+                    return Err(crate::serde2::missing_field("ExternalMetricValue", "metricLabels", &["metricLabels", "metricName", "timestamp", "value"]));
+                }
+                if !has_metric_name {
+                    return Err(crate::serde2::missing_field("ExternalMetricValue", "metricName", &["metricLabels", "metricName", "timestamp", "value"]));
+                }
+                if !has_timestamp {
+                    return Err(crate::serde2::missing_field("ExternalMetricValue", "timestamp", &["metricLabels", "metricName", "timestamp", "value"]));
+                }
+                if !has_value {
+                    return Err(crate::serde2::missing_field("ExternalMetricValue", "value", &["metricLabels", "metricName", "timestamp", "value"]));
+                }
+                if !has_window {
+                    self.0.window = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metric_labels))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metric_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.timestamp))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.value))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.window))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            ExternalMetricValue::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for ExternalMetricValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct(<Self as crate::Resource>::KIND, 5)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricLabels", &self.metric_labels)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricName", &self.metric_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "timestamp", &self.timestamp)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "value", &self.value)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "window", &self.window)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            <Self as crate::Resource>::KIND,
+            6 +
+            self.window.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <Self as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <Self as crate::Resource>::KIND)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricLabels", &self.metric_labels)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricName", &self.metric_name)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "timestamp", &self.timestamp)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "value", &self.value)?;
+        if let Some(value) = &self.window {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "window", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`ExternalMetricValue`]. Created with [`ExternalMetricValue::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ExternalMetricValueBuilder {
+    metric_labels: Option<std::collections::BTreeMap<String, String>>,
+    metric_name: Option<String>,
+    timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+    value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+    window: Option<i64>,
+}
+
+#[cfg(feature = "builder")]
+impl ExternalMetricValue {
+    /// Starts building a `ExternalMetricValue`. Its required fields must be set before [`ExternalMetricValueBuilder::build`] is called.
+    pub fn builder() -> ExternalMetricValueBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ExternalMetricValueBuilder {
+    /// Sets [`ExternalMetricValue::metric_labels`].
+    pub fn metric_labels(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.metric_labels = Some(value.into());
+        self
+    }
+
+    /// Sets [`ExternalMetricValue::metric_name`].
+    pub fn metric_name(mut self, value: impl Into<String>) -> Self {
+        self.metric_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`ExternalMetricValue::timestamp`].
+    pub fn timestamp(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.timestamp = Some(value.into());
+        self
+    }
+
+    /// Sets [`ExternalMetricValue::value`].
+    pub fn value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Sets [`ExternalMetricValue::window`].
+    pub fn window(mut self, value: impl Into<i64>) -> Self {
+        self.window = Some(value.into());
+        self
+    }
+
+    /// Builds the `ExternalMetricValue`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ExternalMetricValue, crate::MissingFieldError> {
+        Ok(ExternalMetricValue {
+            metric_labels: self.metric_labels.ok_or_else(|| crate::MissingFieldError::new("ExternalMetricValue", "metricLabels"))?,
+            metric_name: self.metric_name.ok_or_else(|| crate::MissingFieldError::new("ExternalMetricValue", "metricName"))?,
+            timestamp: self.timestamp.ok_or_else(|| crate::MissingFieldError::new("ExternalMetricValue", "timestamp"))?,
+            value: self.value.ok_or_else(|| crate::MissingFieldError::new("ExternalMetricValue", "value"))?,
+            window: self.window,
+        })
+    }
+}
+
+/// The apply configuration of [`ExternalMetricValue`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ExternalMetricValueApplyConfiguration {
+    /// See [`ExternalMetricValue::metric_labels`].
+    pub metric_labels: Option<std::collections::BTreeMap<String, String>>,
+
+    /// See [`ExternalMetricValue::metric_name`].
+    pub metric_name: Option<String>,
+
+    /// See [`ExternalMetricValue::timestamp`].
+    pub timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+
+    /// See [`ExternalMetricValue::value`].
+    pub value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+
+    /// See [`ExternalMetricValue::window`].
+    pub window: Option<i64>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ExternalMetricValueApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            <ExternalMetricValue as crate::Resource>::KIND,
+            2 +
+            self.metric_labels.as_ref().map_or(0, |_| 1) +
+            self.metric_name.as_ref().map_or(0, |_| 1) +
+            self.timestamp.as_ref().map_or(0, |_| 1) +
+            self.value.as_ref().map_or(0, |_| 1) +
+            self.window.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <ExternalMetricValue as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <ExternalMetricValue as crate::Resource>::KIND)?;
+        if let Some(value) = &self.metric_labels {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricLabels", value)?;
+        }
+        if let Some(value) = &self.metric_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricName", value)?;
+        }
+        if let Some(value) = &self.timestamp {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "timestamp", value)?;
+        }
+        if let Some(value) = &self.value {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "value", value)?;
+        }
+        if let Some(value) = &self.window {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "window", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for ExternalMetricValue {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "metrics::pkg::apis::external_metrics::v1beta1::ExternalMetricValue".to_owned()
+        }
+        else {
+            "io.k8s.metrics.pkg.apis.external_metrics.v1beta1.ExternalMetricValue".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("ExternalMetricValue is a metric value for external metric. A single metric value is identified by metric name and a set of string labels. For one metric there can be multiple values with different sets of labels.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "apiVersion".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#resources".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("external.metrics.k8s.io/v1beta1".to_owned())]),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "kind".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#types-kinds".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("ExternalMetricValue".to_owned())]),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "metricLabels".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("a set of labels that identify a single time series for the metric".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+                            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                                additional_properties: Some(Box::new(
+                                    crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                                        instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                                        ..Default::default()
+                                    })
+                                )),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "metricName".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("the name of the metric".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "timestamp".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::Time>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("indicates the time at which the metrics were produced".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
+                        "value".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::api::resource::Quantity>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("the value of the metric for this".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
+                        "window".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("indicates the window ([Timestamp-Window, Timestamp]) from which these metrics were calculated, when returning rate metrics calculated from cumulative metrics (or zero for non-calculated instantaneous metrics).".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int64".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                required: std::array::IntoIter::new([
+                    "apiVersion",
+                    "kind",
+                    "metricLabels",
+                    "metricName",
+                    "timestamp",
+                    "value",
+                ]).map(std::borrow::ToOwned::to_owned).collect(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ExternalMetricValue {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ExternalMetricValue {
+            metric_labels: crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            metric_name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            timestamp: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            value: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            window: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -0,0 +1,7 @@
+
+mod external_metric_value;
+pub use self::external_metric_value::ExternalMetricValue;
+#[cfg(feature = "builder")]
+pub use self::external_metric_value::ExternalMetricValueBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::external_metric_value::ExternalMetricValueApplyConfiguration;
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.admissionregistration.v1beta1.RuleWithOperations
 
 /// RuleWithOperations is a tuple of Operations and Resources. It is recommended to make sure that all the tuple expansions are valid.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct RuleWithOperations {
     /// APIGroups is the API groups the resources belong to. '*' is all groups. If '*' is present, the length of the slice must be one. Required.
     pub api_groups: Option<Vec<String>>,
@@ -22,43 +22,49 @@ pub struct RuleWithOperations {
     pub resources: Option<Vec<String>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for RuleWithOperations {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_api_groups,
-            Key_api_versions,
-            Key_operations,
-            Key_resources,
-            Other,
-        }
+impl RuleWithOperations {
+    /// Returns this value with [`RuleWithOperations::api_groups`] set to the given value.
+    #[must_use]
+    pub fn with_api_groups(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.api_groups = Some(value.into());
+        self
+    }
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+    /// Returns this value with [`RuleWithOperations::api_versions`] set to the given value.
+    #[must_use]
+    pub fn with_api_versions(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.api_versions = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`RuleWithOperations::operations`] set to the given value.
+    #[must_use]
+    pub fn with_operations(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.operations = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`RuleWithOperations::resources`] set to the given value.
+    #[must_use]
+    pub fn with_resources(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.resources = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "apiGroups" => Field::Key_api_groups,
-                            "apiVersions" => Field::Key_api_versions,
-                            "operations" => Field::Key_operations,
-                            "resources" => Field::Key_resources,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for RuleWithOperations {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl RuleWithOperations {
+    const FIELDS: &'static [&'static str] = &[
+        "apiGroups",
+        "apiVersions",
+        "operations",
+        "resources",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for RuleWithOperations {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -74,13 +80,13 @@ impl<'de> crate::serde::Deserialize<'de> for RuleWithOperations {
                 let mut value_operations: Option<Vec<String>> = None;
                 let mut value_resources: Option<Vec<String>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("RuleWithOperations", RuleWithOperations::FIELDS))? {
                     match key {
-                        Field::Key_api_groups => value_api_groups = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_api_versions => value_api_versions = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_operations => value_operations = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_resources => value_resources = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_api_groups = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_api_versions = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_operations = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_resources = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -91,23 +97,94 @@ impl<'de> crate::serde::Deserialize<'de> for RuleWithOperations {
                     resources: value_resources,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(RuleWithOperations {
+                    api_groups: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    api_versions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    operations: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    resources: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "RuleWithOperations",
-            &[
-                "apiGroups",
-                "apiVersions",
-                "operations",
-                "resources",
-            ],
+            RuleWithOperations::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, RuleWithOperations> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("RuleWithOperations")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_api_groups = false;
+                let mut has_api_versions = false;
+                let mut has_operations = false;
+                let mut has_resources = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("RuleWithOperations", RuleWithOperations::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.api_groups))?; has_api_groups = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.api_versions))?; has_api_versions = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.operations))?; has_operations = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.resources))?; has_resources = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_api_groups {
+                    self.0.api_groups = None;
+                }
+                if !has_api_versions {
+                    self.0.api_versions = None;
+                }
+                if !has_operations {
+                    self.0.operations = None;
+                }
+                if !has_resources {
+                    self.0.resources = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.api_groups))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.api_versions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.operations))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.resources))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "RuleWithOperations",
+            RuleWithOperations::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for RuleWithOperations {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("RuleWithOperations", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiGroups", &self.api_groups)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersions", &self.api_versions)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "operations", &self.operations)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resources", &self.resources)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "RuleWithOperations",
             self.api_groups.as_ref().map_or(0, |_| 1) +
@@ -131,10 +208,114 @@ impl crate::serde::Serialize for RuleWithOperations {
     }
 }
 
+/// A builder of [`RuleWithOperations`]. Created with [`RuleWithOperations::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct RuleWithOperationsBuilder {
+    api_groups: Option<Vec<String>>,
+    api_versions: Option<Vec<String>>,
+    operations: Option<Vec<String>>,
+    resources: Option<Vec<String>>,
+}
+
+#[cfg(feature = "builder")]
+impl RuleWithOperations {
+    /// Starts building a `RuleWithOperations`. Its required fields must be set before [`RuleWithOperationsBuilder::build`] is called.
+    pub fn builder() -> RuleWithOperationsBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl RuleWithOperationsBuilder {
+    /// Sets [`RuleWithOperations::api_groups`].
+    pub fn api_groups(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.api_groups = Some(value.into());
+        self
+    }
+
+    /// Sets [`RuleWithOperations::api_versions`].
+    pub fn api_versions(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.api_versions = Some(value.into());
+        self
+    }
+
+    /// Sets [`RuleWithOperations::operations`].
+    pub fn operations(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.operations = Some(value.into());
+        self
+    }
+
+    /// Sets [`RuleWithOperations::resources`].
+    pub fn resources(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.resources = Some(value.into());
+        self
+    }
+
+    /// Builds the `RuleWithOperations`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<RuleWithOperations, crate::MissingFieldError> {
+        Ok(RuleWithOperations {
+            api_groups: self.api_groups,
+            api_versions: self.api_versions,
+            operations: self.operations,
+            resources: self.resources,
+        })
+    }
+}
+
+/// The apply configuration of [`RuleWithOperations`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RuleWithOperationsApplyConfiguration {
+    /// See [`RuleWithOperations::api_groups`].
+    pub api_groups: Option<Vec<String>>,
+
+    /// See [`RuleWithOperations::api_versions`].
+    pub api_versions: Option<Vec<String>>,
+
+    /// See [`RuleWithOperations::operations`].
+    pub operations: Option<Vec<String>>,
+
+    /// See [`RuleWithOperations::resources`].
+    pub resources: Option<Vec<String>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for RuleWithOperationsApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "RuleWithOperationsApplyConfiguration",
+            self.api_groups.as_ref().map_or(0, |_| 1) +
+            self.api_versions.as_ref().map_or(0, |_| 1) +
+            self.operations.as_ref().map_or(0, |_| 1) +
+            self.resources.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.api_groups {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiGroups", value)?;
+        }
+        if let Some(value) = &self.api_versions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersions", value)?;
+        }
+        if let Some(value) = &self.operations {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "operations", value)?;
+        }
+        if let Some(value) = &self.resources {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resources", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for RuleWithOperations {
     fn schema_name() -> String {
-        "io.k8s.api.admissionregistration.v1beta1.RuleWithOperations".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::admissionregistration::v1beta1::RuleWithOperations".to_owned()
+        }
+        else {
+            "io.k8s.api.admissionregistration.v1beta1.RuleWithOperations".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -145,8 +326,9 @@ impl crate::schemars::JsonSchema for RuleWithOperations {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "apiGroups".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -165,8 +347,8 @@ impl crate::schemars::JsonSchema for RuleWithOperations {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "apiVersions".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -185,8 +367,8 @@ impl crate::schemars::JsonSchema for RuleWithOperations {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "operations".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -205,8 +387,8 @@ impl crate::schemars::JsonSchema for RuleWithOperations {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "resources".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -225,11 +407,24 @@ impl crate::schemars::JsonSchema for RuleWithOperations {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for RuleWithOperations {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(RuleWithOperations {
+            api_groups: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            api_versions: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            operations: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            resources: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
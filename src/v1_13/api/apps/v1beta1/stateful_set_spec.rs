@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.apps.v1beta1.StatefulSetSpec
 
 /// A StatefulSetSpec is the specification of a StatefulSet.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct StatefulSetSpec {
     /// podManagementPolicy controls how pods are created during initial scale up, when replacing pods on nodes, or when scaling down. The default policy is `OrderedReady`, where pods are created in increasing order (pod-0, then pod-1, etc) and the controller will wait until each pod is ready before continuing. When scaling down, the pods are removed in the opposite order. The alternative policy is `Parallel` which will create pods in parallel to match the desired scale without waiting, and on scale down will delete all pods at once.
     pub pod_management_policy: Option<String>,
@@ -28,51 +28,97 @@ pub struct StatefulSetSpec {
     pub volume_claim_templates: Option<Vec<crate::api::core::v1::PersistentVolumeClaim>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for StatefulSetSpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_pod_management_policy,
-            Key_replicas,
-            Key_revision_history_limit,
-            Key_selector,
-            Key_service_name,
-            Key_template,
-            Key_update_strategy,
-            Key_volume_claim_templates,
-            Other,
+impl StatefulSetSpec {
+    /// Constructs a `StatefulSetSpec` from its required fields. Its optional fields are unset.
+    pub fn new(service_name: impl Into<String>, template: impl Into<crate::api::core::v1::PodTemplateSpec>) -> Self {
+        StatefulSetSpec {
+            pod_management_policy: None,
+            replicas: None,
+            revision_history_limit: None,
+            selector: None,
+            service_name: service_name.into(),
+            template: template.into(),
+            update_strategy: None,
+            volume_claim_templates: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl StatefulSetSpec {
+    /// Returns this value with [`StatefulSetSpec::pod_management_policy`] set to the given value.
+    #[must_use]
+    pub fn with_pod_management_policy(mut self, value: impl Into<String>) -> Self {
+        self.pod_management_policy = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`StatefulSetSpec::replicas`] set to the given value.
+    #[must_use]
+    pub fn with_replicas(mut self, value: impl Into<i32>) -> Self {
+        self.replicas = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`StatefulSetSpec::revision_history_limit`] set to the given value.
+    #[must_use]
+    pub fn with_revision_history_limit(mut self, value: impl Into<i32>) -> Self {
+        self.revision_history_limit = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "podManagementPolicy" => Field::Key_pod_management_policy,
-                            "replicas" => Field::Key_replicas,
-                            "revisionHistoryLimit" => Field::Key_revision_history_limit,
-                            "selector" => Field::Key_selector,
-                            "serviceName" => Field::Key_service_name,
-                            "template" => Field::Key_template,
-                            "updateStrategy" => Field::Key_update_strategy,
-                            "volumeClaimTemplates" => Field::Key_volume_claim_templates,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`StatefulSetSpec::selector`] set to the given value.
+    #[must_use]
+    pub fn with_selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.selector = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`StatefulSetSpec::service_name`] set to the given value.
+    #[must_use]
+    pub fn with_service_name(mut self, value: impl Into<String>) -> Self {
+        self.service_name = value.into();
+        self
+    }
+
+    /// Returns this value with [`StatefulSetSpec::template`] set to the given value.
+    #[must_use]
+    pub fn with_template(mut self, value: impl Into<crate::api::core::v1::PodTemplateSpec>) -> Self {
+        self.template = value.into();
+        self
+    }
 
+    /// Returns this value with [`StatefulSetSpec::update_strategy`] set to the given value.
+    #[must_use]
+    pub fn with_update_strategy(mut self, value: impl Into<crate::api::apps::v1beta1::StatefulSetUpdateStrategy>) -> Self {
+        self.update_strategy = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`StatefulSetSpec::volume_claim_templates`] set to the given value.
+    #[must_use]
+    pub fn with_volume_claim_templates(mut self, value: impl Into<Vec<crate::api::core::v1::PersistentVolumeClaim>>) -> Self {
+        self.volume_claim_templates = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for StatefulSetSpec {}
+
+impl StatefulSetSpec {
+    const FIELDS: &'static [&'static str] = &[
+        "podManagementPolicy",
+        "replicas",
+        "revisionHistoryLimit",
+        "selector",
+        "serviceName",
+        "template",
+        "updateStrategy",
+        "volumeClaimTemplates",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for StatefulSetSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -92,17 +138,17 @@ impl<'de> crate::serde::Deserialize<'de> for StatefulSetSpec {
                 let mut value_update_strategy: Option<crate::api::apps::v1beta1::StatefulSetUpdateStrategy> = None;
                 let mut value_volume_claim_templates: Option<Vec<crate::api::core::v1::PersistentVolumeClaim>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("StatefulSetSpec", StatefulSetSpec::FIELDS))? {
                     match key {
-                        Field::Key_pod_management_policy => value_pod_management_policy = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_replicas => value_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_revision_history_limit => value_revision_history_limit = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_selector => value_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_service_name => value_service_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_template => value_template = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_update_strategy => value_update_strategy = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_volume_claim_templates => value_volume_claim_templates = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_pod_management_policy = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_replicas = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_revision_history_limit = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_service_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(5) => value_template = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(6) => value_update_strategy = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(7) => value_volume_claim_templates = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -111,33 +157,132 @@ impl<'de> crate::serde::Deserialize<'de> for StatefulSetSpec {
                     replicas: value_replicas,
                     revision_history_limit: value_revision_history_limit,
                     selector: value_selector,
-                    service_name: value_service_name.ok_or_else(|| crate::serde::de::Error::missing_field("serviceName"))?,
-                    template: value_template.ok_or_else(|| crate::serde::de::Error::missing_field("template"))?,
+                    service_name: value_service_name.ok_or_else(|| crate::serde2::missing_field("StatefulSetSpec", "serviceName", &["serviceName", "template"]))?,
+                    template: value_template.ok_or_else(|| crate::serde2::missing_field("StatefulSetSpec", "template", &["serviceName", "template"]))?,
                     update_strategy: value_update_strategy,
                     volume_claim_templates: value_volume_claim_templates,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(StatefulSetSpec {
+                    pod_management_policy: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    replicas: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    revision_history_limit: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    selector: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    service_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    template: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    update_strategy: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    volume_claim_templates: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "StatefulSetSpec",
-            &[
-                "podManagementPolicy",
-                "replicas",
-                "revisionHistoryLimit",
-                "selector",
-                "serviceName",
-                "template",
-                "updateStrategy",
-                "volumeClaimTemplates",
-            ],
+            StatefulSetSpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, StatefulSetSpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("StatefulSetSpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_pod_management_policy = false;
+                let mut has_replicas = false;
+                let mut has_revision_history_limit = false;
+                let mut has_selector = false;
+                let mut has_service_name = false;
+                let mut has_template = false;
+                let mut has_update_strategy = false;
+                let mut has_volume_claim_templates = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("StatefulSetSpec", StatefulSetSpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.pod_management_policy))?; has_pod_management_policy = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.replicas))?; has_replicas = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.revision_history_limit))?; has_revision_history_limit = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.selector))?; has_selector = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.service_name))?; has_service_name = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.template))?; has_template = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.update_strategy))?; has_update_strategy = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.volume_claim_templates))?; has_volume_claim_templates = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_pod_management_policy {
+                    self.0.pod_management_policy = None;
+                }
+                if !has_replicas {
+                    self.0.replicas = None;
+                }
+                if !has_revision_history_limit {
+                    self.0.revision_history_limit = None;
+                }
+                if !has_selector {
+                    self.0.selector = None;
+                }
+                if !has_service_name {
+                    return Err(crate::serde2::missing_field("StatefulSetSpec", "serviceName", &["serviceName", "template"]));
+                }
+                if !has_template {
+                    return Err(crate::serde2::missing_field("StatefulSetSpec", "template", &["serviceName", "template"]));
+                }
+                if !has_update_strategy {
+                    self.0.update_strategy = None;
+                }
+                if !has_volume_claim_templates {
+                    self.0.volume_claim_templates = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.pod_management_policy))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.replicas))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.revision_history_limit))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.selector))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.service_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.template))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.update_strategy))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.volume_claim_templates))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "StatefulSetSpec",
+            StatefulSetSpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for StatefulSetSpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("StatefulSetSpec", 8)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "podManagementPolicy", &self.pod_management_policy)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "replicas", &self.replicas)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "revisionHistoryLimit", &self.revision_history_limit)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selector", &self.selector)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "serviceName", &self.service_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "template", &self.template)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "updateStrategy", &self.update_strategy)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeClaimTemplates", &self.volume_claim_templates)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "StatefulSetSpec",
             2 +
@@ -172,10 +317,174 @@ impl crate::serde::Serialize for StatefulSetSpec {
     }
 }
 
+/// A builder of [`StatefulSetSpec`]. Created with [`StatefulSetSpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct StatefulSetSpecBuilder {
+    pod_management_policy: Option<String>,
+    replicas: Option<i32>,
+    revision_history_limit: Option<i32>,
+    selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>,
+    service_name: Option<String>,
+    template: Option<crate::api::core::v1::PodTemplateSpec>,
+    update_strategy: Option<crate::api::apps::v1beta1::StatefulSetUpdateStrategy>,
+    volume_claim_templates: Option<Vec<crate::api::core::v1::PersistentVolumeClaim>>,
+}
+
+#[cfg(feature = "builder")]
+impl StatefulSetSpec {
+    /// Starts building a `StatefulSetSpec`. Its required fields must be set before [`StatefulSetSpecBuilder::build`] is called.
+    pub fn builder() -> StatefulSetSpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl StatefulSetSpecBuilder {
+    /// Sets [`StatefulSetSpec::pod_management_policy`].
+    pub fn pod_management_policy(mut self, value: impl Into<String>) -> Self {
+        self.pod_management_policy = Some(value.into());
+        self
+    }
+
+    /// Sets [`StatefulSetSpec::replicas`].
+    pub fn replicas(mut self, value: impl Into<i32>) -> Self {
+        self.replicas = Some(value.into());
+        self
+    }
+
+    /// Sets [`StatefulSetSpec::revision_history_limit`].
+    pub fn revision_history_limit(mut self, value: impl Into<i32>) -> Self {
+        self.revision_history_limit = Some(value.into());
+        self
+    }
+
+    /// Sets [`StatefulSetSpec::selector`].
+    pub fn selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`StatefulSetSpec::service_name`].
+    pub fn service_name(mut self, value: impl Into<String>) -> Self {
+        self.service_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`StatefulSetSpec::template`].
+    pub fn template(mut self, value: impl Into<crate::api::core::v1::PodTemplateSpec>) -> Self {
+        self.template = Some(value.into());
+        self
+    }
+
+    /// Sets [`StatefulSetSpec::update_strategy`].
+    pub fn update_strategy(mut self, value: impl Into<crate::api::apps::v1beta1::StatefulSetUpdateStrategy>) -> Self {
+        self.update_strategy = Some(value.into());
+        self
+    }
+
+    /// Sets [`StatefulSetSpec::volume_claim_templates`].
+    pub fn volume_claim_templates(mut self, value: impl Into<Vec<crate::api::core::v1::PersistentVolumeClaim>>) -> Self {
+        self.volume_claim_templates = Some(value.into());
+        self
+    }
+
+    /// Builds the `StatefulSetSpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<StatefulSetSpec, crate::MissingFieldError> {
+        Ok(StatefulSetSpec {
+            pod_management_policy: self.pod_management_policy,
+            replicas: self.replicas,
+            revision_history_limit: self.revision_history_limit,
+            selector: self.selector,
+            service_name: self.service_name.ok_or_else(|| crate::MissingFieldError::new("StatefulSetSpec", "serviceName"))?,
+            template: self.template.ok_or_else(|| crate::MissingFieldError::new("StatefulSetSpec", "template"))?,
+            update_strategy: self.update_strategy,
+            volume_claim_templates: self.volume_claim_templates,
+        })
+    }
+}
+
+/// The apply configuration of [`StatefulSetSpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StatefulSetSpecApplyConfiguration {
+    /// See [`StatefulSetSpec::pod_management_policy`].
+    pub pod_management_policy: Option<String>,
+
+    /// See [`StatefulSetSpec::replicas`].
+    pub replicas: Option<i32>,
+
+    /// See [`StatefulSetSpec::revision_history_limit`].
+    pub revision_history_limit: Option<i32>,
+
+    /// See [`StatefulSetSpec::selector`].
+    pub selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorApplyConfiguration>,
+
+    /// See [`StatefulSetSpec::service_name`].
+    pub service_name: Option<String>,
+
+    /// See [`StatefulSetSpec::template`].
+    pub template: Option<crate::api::core::v1::PodTemplateSpecApplyConfiguration>,
+
+    /// See [`StatefulSetSpec::update_strategy`].
+    pub update_strategy: Option<crate::api::apps::v1beta1::StatefulSetUpdateStrategyApplyConfiguration>,
+
+    /// See [`StatefulSetSpec::volume_claim_templates`].
+    pub volume_claim_templates: Option<Vec<crate::api::core::v1::PersistentVolumeClaimApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for StatefulSetSpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "StatefulSetSpecApplyConfiguration",
+            self.pod_management_policy.as_ref().map_or(0, |_| 1) +
+            self.replicas.as_ref().map_or(0, |_| 1) +
+            self.revision_history_limit.as_ref().map_or(0, |_| 1) +
+            self.selector.as_ref().map_or(0, |_| 1) +
+            self.service_name.as_ref().map_or(0, |_| 1) +
+            self.template.as_ref().map_or(0, |_| 1) +
+            self.update_strategy.as_ref().map_or(0, |_| 1) +
+            self.volume_claim_templates.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.pod_management_policy {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "podManagementPolicy", value)?;
+        }
+        if let Some(value) = &self.replicas {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "replicas", value)?;
+        }
+        if let Some(value) = &self.revision_history_limit {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "revisionHistoryLimit", value)?;
+        }
+        if let Some(value) = &self.selector {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selector", value)?;
+        }
+        if let Some(value) = &self.service_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "serviceName", value)?;
+        }
+        if let Some(value) = &self.template {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "template", value)?;
+        }
+        if let Some(value) = &self.update_strategy {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "updateStrategy", value)?;
+        }
+        if let Some(value) = &self.volume_claim_templates {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeClaimTemplates", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for StatefulSetSpec {
     fn schema_name() -> String {
-        "io.k8s.api.apps.v1beta1.StatefulSetSpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::apps::v1beta1::StatefulSetSpec".to_owned()
+        }
+        else {
+            "io.k8s.api.apps.v1beta1.StatefulSetSpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -186,8 +495,9 @@ impl crate::schemars::JsonSchema for StatefulSetSpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "podManagementPolicy".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -197,8 +507,8 @@ impl crate::schemars::JsonSchema for StatefulSetSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "replicas".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -209,8 +519,8 @@ impl crate::schemars::JsonSchema for StatefulSetSpec {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "revisionHistoryLimit".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -221,8 +531,8 @@ impl crate::schemars::JsonSchema for StatefulSetSpec {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "selector".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>().into_object();
@@ -232,8 +542,8 @@ impl crate::schemars::JsonSchema for StatefulSetSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "serviceName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -243,8 +553,8 @@ impl crate::schemars::JsonSchema for StatefulSetSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "template".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::PodTemplateSpec>().into_object();
@@ -254,8 +564,8 @@ impl crate::schemars::JsonSchema for StatefulSetSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "updateStrategy".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::apps::v1beta1::StatefulSetUpdateStrategy>().into_object();
@@ -265,8 +575,8 @@ impl crate::schemars::JsonSchema for StatefulSetSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "volumeClaimTemplates".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -280,8 +590,9 @@ impl crate::schemars::JsonSchema for StatefulSetSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "serviceName",
                     "template",
@@ -292,3 +603,19 @@ impl crate::schemars::JsonSchema for StatefulSetSpec {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for StatefulSetSpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(StatefulSetSpec {
+            pod_management_policy: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            replicas: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            revision_history_limit: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            selector: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            service_name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            template: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            update_strategy: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            volume_claim_templates: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
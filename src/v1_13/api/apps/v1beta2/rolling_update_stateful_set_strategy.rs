@@ -1,80 +1,118 @@
 // Generated from definition io.k8s.api.apps.v1beta2.RollingUpdateStatefulSetStrategy
 
 /// RollingUpdateStatefulSetStrategy is used to communicate parameter for RollingUpdateStatefulSetStrategyType.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 pub struct RollingUpdateStatefulSetStrategy {
     /// Partition indicates the ordinal at which the StatefulSet should be partitioned. Default value is 0.
     pub partition: Option<i32>,
 }
 
+impl RollingUpdateStatefulSetStrategy {
+    /// Returns this value with [`RollingUpdateStatefulSetStrategy::partition`] set to the given value.
+    #[must_use]
+    pub fn with_partition(mut self, value: impl Into<i32>) -> Self {
+        self.partition = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for RollingUpdateStatefulSetStrategy {}
+
+impl RollingUpdateStatefulSetStrategy {
+    const FIELDS: &'static [&'static str] = &[
+        "partition",
+    ];
+}
+
 impl<'de> crate::serde::Deserialize<'de> for RollingUpdateStatefulSetStrategy {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_partition,
-            Other,
-        }
+        struct Visitor;
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = RollingUpdateStatefulSetStrategy;
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("RollingUpdateStatefulSetStrategy")
+            }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_partition: Option<i32> = None;
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "partition" => Field::Key_partition,
-                            _ => Field::Other,
-                        })
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("RollingUpdateStatefulSetStrategy", RollingUpdateStatefulSetStrategy::FIELDS))? {
+                    match key {
+                        Some(0) => value_partition = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
-                deserializer.deserialize_identifier(Visitor)
+                Ok(RollingUpdateStatefulSetStrategy {
+                    partition: value_partition,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(RollingUpdateStatefulSetStrategy {
+                    partition: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                })
             }
         }
 
-        struct Visitor;
+        deserializer.deserialize_struct(
+            "RollingUpdateStatefulSetStrategy",
+            RollingUpdateStatefulSetStrategy::FIELDS,
+            Visitor,
+        )
+    }
 
-        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-            type Value = RollingUpdateStatefulSetStrategy;
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, RollingUpdateStatefulSetStrategy> {
+            type Value = ();
 
             fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 f.write_str("RollingUpdateStatefulSetStrategy")
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
-                let mut value_partition: Option<i32> = None;
+                let mut has_partition = false;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("RollingUpdateStatefulSetStrategy", RollingUpdateStatefulSetStrategy::FIELDS))? {
                     match key {
-                        Field::Key_partition => value_partition = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.partition))?; has_partition = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
+                if !has_partition {
+                    self.0.partition = None;
+                }
 
-                Ok(RollingUpdateStatefulSetStrategy {
-                    partition: value_partition,
-                })
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.partition))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
             }
         }
 
         deserializer.deserialize_struct(
             "RollingUpdateStatefulSetStrategy",
-            &[
-                "partition",
-            ],
-            Visitor,
+            RollingUpdateStatefulSetStrategy::FIELDS,
+            InPlaceVisitor(place),
         )
     }
 }
 
 impl crate::serde::Serialize for RollingUpdateStatefulSetStrategy {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("RollingUpdateStatefulSetStrategy", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "partition", &self.partition)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "RollingUpdateStatefulSetStrategy",
             self.partition.as_ref().map_or(0, |_| 1),
@@ -86,10 +124,69 @@ impl crate::serde::Serialize for RollingUpdateStatefulSetStrategy {
     }
 }
 
+/// A builder of [`RollingUpdateStatefulSetStrategy`]. Created with [`RollingUpdateStatefulSetStrategy::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct RollingUpdateStatefulSetStrategyBuilder {
+    partition: Option<i32>,
+}
+
+#[cfg(feature = "builder")]
+impl RollingUpdateStatefulSetStrategy {
+    /// Starts building a `RollingUpdateStatefulSetStrategy`. Its required fields must be set before [`RollingUpdateStatefulSetStrategyBuilder::build`] is called.
+    pub fn builder() -> RollingUpdateStatefulSetStrategyBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl RollingUpdateStatefulSetStrategyBuilder {
+    /// Sets [`RollingUpdateStatefulSetStrategy::partition`].
+    pub fn partition(mut self, value: impl Into<i32>) -> Self {
+        self.partition = Some(value.into());
+        self
+    }
+
+    /// Builds the `RollingUpdateStatefulSetStrategy`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<RollingUpdateStatefulSetStrategy, crate::MissingFieldError> {
+        Ok(RollingUpdateStatefulSetStrategy {
+            partition: self.partition,
+        })
+    }
+}
+
+/// The apply configuration of [`RollingUpdateStatefulSetStrategy`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RollingUpdateStatefulSetStrategyApplyConfiguration {
+    /// See [`RollingUpdateStatefulSetStrategy::partition`].
+    pub partition: Option<i32>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for RollingUpdateStatefulSetStrategyApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "RollingUpdateStatefulSetStrategyApplyConfiguration",
+            self.partition.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.partition {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "partition", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for RollingUpdateStatefulSetStrategy {
     fn schema_name() -> String {
-        "io.k8s.api.apps.v1beta2.RollingUpdateStatefulSetStrategy".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::apps::v1beta2::RollingUpdateStatefulSetStrategy".to_owned()
+        }
+        else {
+            "io.k8s.api.apps.v1beta2.RollingUpdateStatefulSetStrategy".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -100,8 +197,9 @@ impl crate::schemars::JsonSchema for RollingUpdateStatefulSetStrategy {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "partition".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -112,11 +210,21 @@ impl crate::schemars::JsonSchema for RollingUpdateStatefulSetStrategy {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for RollingUpdateStatefulSetStrategy {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(RollingUpdateStatefulSetStrategy {
+            partition: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.auditregistration.v1alpha1.Policy
 
 /// Policy defines the configuration of how audit events are logged
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct Policy {
     /// The Level that all requests are recorded at. available options: None, Metadata, Request, RequestResponse required
     pub level: String,
@@ -10,39 +10,43 @@ pub struct Policy {
     pub stages: Option<Vec<String>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for Policy {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_level,
-            Key_stages,
-            Other,
+impl Policy {
+    /// Constructs a `Policy` from its required fields. Its optional fields are unset.
+    pub fn new(level: impl Into<String>) -> Self {
+        Policy {
+            level: level.into(),
+            stages: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl Policy {
+    /// Returns this value with [`Policy::level`] set to the given value.
+    #[must_use]
+    pub fn with_level(mut self, value: impl Into<String>) -> Self {
+        self.level = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`Policy::stages`] set to the given value.
+    #[must_use]
+    pub fn with_stages(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.stages = Some(value.into());
+        self
+    }
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for Policy {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "level" => Field::Key_level,
-                            "stages" => Field::Key_stages,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl Policy {
+    const FIELDS: &'static [&'static str] = &[
+        "level",
+        "stages",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for Policy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +60,91 @@ impl<'de> crate::serde::Deserialize<'de> for Policy {
                 let mut value_level: Option<String> = None;
                 let mut value_stages: Option<Vec<String>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Policy", Policy::FIELDS))? {
                     match key {
-                        Field::Key_level => value_level = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_stages => value_stages = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_level = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_stages = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(Policy {
-                    level: value_level.ok_or_else(|| crate::serde::de::Error::missing_field("level"))?,
+                    level: value_level.ok_or_else(|| crate::serde2::missing_field("Policy", "level", &["level"]))?,
                     stages: value_stages,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(Policy {
+                    level: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    stages: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "Policy",
-            &[
-                "level",
-                "stages",
-            ],
+            Policy::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, Policy> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("Policy")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_level = false;
+                let mut has_stages = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Policy", Policy::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.level))?; has_level = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.stages))?; has_stages = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_level {
+                    return Err(crate::serde2::missing_field("Policy", "level", &["level"]));
+                }
+                if !has_stages {
+                    self.0.stages = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.level))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.stages))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "Policy",
+            Policy::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for Policy {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("Policy", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "level", &self.level)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "stages", &self.stages)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "Policy",
             1 +
@@ -97,10 +158,84 @@ impl crate::serde::Serialize for Policy {
     }
 }
 
+/// A builder of [`Policy`]. Created with [`Policy::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct PolicyBuilder {
+    level: Option<String>,
+    stages: Option<Vec<String>>,
+}
+
+#[cfg(feature = "builder")]
+impl Policy {
+    /// Starts building a `Policy`. Its required fields must be set before [`PolicyBuilder::build`] is called.
+    pub fn builder() -> PolicyBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl PolicyBuilder {
+    /// Sets [`Policy::level`].
+    pub fn level(mut self, value: impl Into<String>) -> Self {
+        self.level = Some(value.into());
+        self
+    }
+
+    /// Sets [`Policy::stages`].
+    pub fn stages(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.stages = Some(value.into());
+        self
+    }
+
+    /// Builds the `Policy`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<Policy, crate::MissingFieldError> {
+        Ok(Policy {
+            level: self.level.ok_or_else(|| crate::MissingFieldError::new("Policy", "level"))?,
+            stages: self.stages,
+        })
+    }
+}
+
+/// The apply configuration of [`Policy`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PolicyApplyConfiguration {
+    /// See [`Policy::level`].
+    pub level: Option<String>,
+
+    /// See [`Policy::stages`].
+    pub stages: Option<Vec<String>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for PolicyApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "PolicyApplyConfiguration",
+            self.level.as_ref().map_or(0, |_| 1) +
+            self.stages.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.level {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "level", value)?;
+        }
+        if let Some(value) = &self.stages {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "stages", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Policy {
     fn schema_name() -> String {
-        "io.k8s.api.auditregistration.v1alpha1.Policy".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::auditregistration::v1alpha1::Policy".to_owned()
+        }
+        else {
+            "io.k8s.api.auditregistration.v1alpha1.Policy".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -111,8 +246,9 @@ impl crate::schemars::JsonSchema for Policy {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "level".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -122,8 +258,8 @@ impl crate::schemars::JsonSchema for Policy {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "stages".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -142,8 +278,9 @@ impl crate::schemars::JsonSchema for Policy {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "level",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -153,3 +290,13 @@ impl crate::schemars::JsonSchema for Policy {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for Policy {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(Policy {
+            level: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            stages: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.authentication.v1beta1.TokenReviewSpec
 
 /// TokenReviewSpec is a description of the token authentication request.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct TokenReviewSpec {
     /// Audiences is a list of the identifiers that the resource server presented with the token identifies as. Audience-aware token authenticators will verify that the token was intended for at least one of the audiences in this list. If no audiences are provided, the audience will default to the audience of the Kubernetes apiserver.
     pub audiences: Option<Vec<String>>,
@@ -10,39 +10,33 @@ pub struct TokenReviewSpec {
     pub token: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for TokenReviewSpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_audiences,
-            Key_token,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl TokenReviewSpec {
+    /// Returns this value with [`TokenReviewSpec::audiences`] set to the given value.
+    #[must_use]
+    pub fn with_audiences(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.audiences = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`TokenReviewSpec::token`] set to the given value.
+    #[must_use]
+    pub fn with_token(mut self, value: impl Into<String>) -> Self {
+        self.token = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "audiences" => Field::Key_audiences,
-                            "token" => Field::Key_token,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for TokenReviewSpec {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl TokenReviewSpec {
+    const FIELDS: &'static [&'static str] = &[
+        "audiences",
+        "token",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for TokenReviewSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,11 +50,11 @@ impl<'de> crate::serde::Deserialize<'de> for TokenReviewSpec {
                 let mut value_audiences: Option<Vec<String>> = None;
                 let mut value_token: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("TokenReviewSpec", TokenReviewSpec::FIELDS))? {
                     match key {
-                        Field::Key_audiences => value_audiences = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_token => value_token = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_audiences = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_token = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -69,21 +63,78 @@ impl<'de> crate::serde::Deserialize<'de> for TokenReviewSpec {
                     token: value_token,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(TokenReviewSpec {
+                    audiences: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    token: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "TokenReviewSpec",
-            &[
-                "audiences",
-                "token",
-            ],
+            TokenReviewSpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, TokenReviewSpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("TokenReviewSpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_audiences = false;
+                let mut has_token = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("TokenReviewSpec", TokenReviewSpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.audiences))?; has_audiences = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.token))?; has_token = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_audiences {
+                    self.0.audiences = None;
+                }
+                if !has_token {
+                    self.0.token = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.audiences))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.token))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "TokenReviewSpec",
+            TokenReviewSpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for TokenReviewSpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("TokenReviewSpec", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "audiences", &self.audiences)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "token", &self.token)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "TokenReviewSpec",
             self.audiences.as_ref().map_or(0, |_| 1) +
@@ -99,10 +150,84 @@ impl crate::serde::Serialize for TokenReviewSpec {
     }
 }
 
+/// A builder of [`TokenReviewSpec`]. Created with [`TokenReviewSpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct TokenReviewSpecBuilder {
+    audiences: Option<Vec<String>>,
+    token: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl TokenReviewSpec {
+    /// Starts building a `TokenReviewSpec`. Its required fields must be set before [`TokenReviewSpecBuilder::build`] is called.
+    pub fn builder() -> TokenReviewSpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl TokenReviewSpecBuilder {
+    /// Sets [`TokenReviewSpec::audiences`].
+    pub fn audiences(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.audiences = Some(value.into());
+        self
+    }
+
+    /// Sets [`TokenReviewSpec::token`].
+    pub fn token(mut self, value: impl Into<String>) -> Self {
+        self.token = Some(value.into());
+        self
+    }
+
+    /// Builds the `TokenReviewSpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<TokenReviewSpec, crate::MissingFieldError> {
+        Ok(TokenReviewSpec {
+            audiences: self.audiences,
+            token: self.token,
+        })
+    }
+}
+
+/// The apply configuration of [`TokenReviewSpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TokenReviewSpecApplyConfiguration {
+    /// See [`TokenReviewSpec::audiences`].
+    pub audiences: Option<Vec<String>>,
+
+    /// See [`TokenReviewSpec::token`].
+    pub token: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for TokenReviewSpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "TokenReviewSpecApplyConfiguration",
+            self.audiences.as_ref().map_or(0, |_| 1) +
+            self.token.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.audiences {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "audiences", value)?;
+        }
+        if let Some(value) = &self.token {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "token", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for TokenReviewSpec {
     fn schema_name() -> String {
-        "io.k8s.api.authentication.v1beta1.TokenReviewSpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::authentication::v1beta1::TokenReviewSpec".to_owned()
+        }
+        else {
+            "io.k8s.api.authentication.v1beta1.TokenReviewSpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -113,8 +238,9 @@ impl crate::schemars::JsonSchema for TokenReviewSpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "audiences".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -133,8 +259,8 @@ impl crate::schemars::JsonSchema for TokenReviewSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "token".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -144,11 +270,22 @@ impl crate::schemars::JsonSchema for TokenReviewSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for TokenReviewSpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(TokenReviewSpec {
+            audiences: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            token: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
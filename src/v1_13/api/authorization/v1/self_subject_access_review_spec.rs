@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.authorization.v1.SelfSubjectAccessReviewSpec
 
 /// SelfSubjectAccessReviewSpec is a description of the access request.  Exactly one of ResourceAuthorizationAttributes and NonResourceAuthorizationAttributes must be set
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct SelfSubjectAccessReviewSpec {
     /// NonResourceAttributes describes information for a non-resource access request
     pub non_resource_attributes: Option<crate::api::authorization::v1::NonResourceAttributes>,
@@ -10,39 +10,33 @@ pub struct SelfSubjectAccessReviewSpec {
     pub resource_attributes: Option<crate::api::authorization::v1::ResourceAttributes>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for SelfSubjectAccessReviewSpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_non_resource_attributes,
-            Key_resource_attributes,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl SelfSubjectAccessReviewSpec {
+    /// Returns this value with [`SelfSubjectAccessReviewSpec::non_resource_attributes`] set to the given value.
+    #[must_use]
+    pub fn with_non_resource_attributes(mut self, value: impl Into<crate::api::authorization::v1::NonResourceAttributes>) -> Self {
+        self.non_resource_attributes = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`SelfSubjectAccessReviewSpec::resource_attributes`] set to the given value.
+    #[must_use]
+    pub fn with_resource_attributes(mut self, value: impl Into<crate::api::authorization::v1::ResourceAttributes>) -> Self {
+        self.resource_attributes = Some(value.into());
+        self
+    }
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for SelfSubjectAccessReviewSpec {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "nonResourceAttributes" => Field::Key_non_resource_attributes,
-                            "resourceAttributes" => Field::Key_resource_attributes,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl SelfSubjectAccessReviewSpec {
+    const FIELDS: &'static [&'static str] = &[
+        "nonResourceAttributes",
+        "resourceAttributes",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for SelfSubjectAccessReviewSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,11 +50,11 @@ impl<'de> crate::serde::Deserialize<'de> for SelfSubjectAccessReviewSpec {
                 let mut value_non_resource_attributes: Option<crate::api::authorization::v1::NonResourceAttributes> = None;
                 let mut value_resource_attributes: Option<crate::api::authorization::v1::ResourceAttributes> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("SelfSubjectAccessReviewSpec", SelfSubjectAccessReviewSpec::FIELDS))? {
                     match key {
-                        Field::Key_non_resource_attributes => value_non_resource_attributes = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_resource_attributes => value_resource_attributes = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_non_resource_attributes = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_resource_attributes = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -69,21 +63,78 @@ impl<'de> crate::serde::Deserialize<'de> for SelfSubjectAccessReviewSpec {
                     resource_attributes: value_resource_attributes,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(SelfSubjectAccessReviewSpec {
+                    non_resource_attributes: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    resource_attributes: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "SelfSubjectAccessReviewSpec",
-            &[
-                "nonResourceAttributes",
-                "resourceAttributes",
-            ],
+            SelfSubjectAccessReviewSpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, SelfSubjectAccessReviewSpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("SelfSubjectAccessReviewSpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_non_resource_attributes = false;
+                let mut has_resource_attributes = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("SelfSubjectAccessReviewSpec", SelfSubjectAccessReviewSpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.non_resource_attributes))?; has_non_resource_attributes = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.resource_attributes))?; has_resource_attributes = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_non_resource_attributes {
+                    self.0.non_resource_attributes = None;
+                }
+                if !has_resource_attributes {
+                    self.0.resource_attributes = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.non_resource_attributes))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.resource_attributes))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "SelfSubjectAccessReviewSpec",
+            SelfSubjectAccessReviewSpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for SelfSubjectAccessReviewSpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("SelfSubjectAccessReviewSpec", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nonResourceAttributes", &self.non_resource_attributes)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resourceAttributes", &self.resource_attributes)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "SelfSubjectAccessReviewSpec",
             self.non_resource_attributes.as_ref().map_or(0, |_| 1) +
@@ -99,10 +150,84 @@ impl crate::serde::Serialize for SelfSubjectAccessReviewSpec {
     }
 }
 
+/// A builder of [`SelfSubjectAccessReviewSpec`]. Created with [`SelfSubjectAccessReviewSpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct SelfSubjectAccessReviewSpecBuilder {
+    non_resource_attributes: Option<crate::api::authorization::v1::NonResourceAttributes>,
+    resource_attributes: Option<crate::api::authorization::v1::ResourceAttributes>,
+}
+
+#[cfg(feature = "builder")]
+impl SelfSubjectAccessReviewSpec {
+    /// Starts building a `SelfSubjectAccessReviewSpec`. Its required fields must be set before [`SelfSubjectAccessReviewSpecBuilder::build`] is called.
+    pub fn builder() -> SelfSubjectAccessReviewSpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl SelfSubjectAccessReviewSpecBuilder {
+    /// Sets [`SelfSubjectAccessReviewSpec::non_resource_attributes`].
+    pub fn non_resource_attributes(mut self, value: impl Into<crate::api::authorization::v1::NonResourceAttributes>) -> Self {
+        self.non_resource_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets [`SelfSubjectAccessReviewSpec::resource_attributes`].
+    pub fn resource_attributes(mut self, value: impl Into<crate::api::authorization::v1::ResourceAttributes>) -> Self {
+        self.resource_attributes = Some(value.into());
+        self
+    }
+
+    /// Builds the `SelfSubjectAccessReviewSpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<SelfSubjectAccessReviewSpec, crate::MissingFieldError> {
+        Ok(SelfSubjectAccessReviewSpec {
+            non_resource_attributes: self.non_resource_attributes,
+            resource_attributes: self.resource_attributes,
+        })
+    }
+}
+
+/// The apply configuration of [`SelfSubjectAccessReviewSpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SelfSubjectAccessReviewSpecApplyConfiguration {
+    /// See [`SelfSubjectAccessReviewSpec::non_resource_attributes`].
+    pub non_resource_attributes: Option<crate::api::authorization::v1::NonResourceAttributesApplyConfiguration>,
+
+    /// See [`SelfSubjectAccessReviewSpec::resource_attributes`].
+    pub resource_attributes: Option<crate::api::authorization::v1::ResourceAttributesApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for SelfSubjectAccessReviewSpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "SelfSubjectAccessReviewSpecApplyConfiguration",
+            self.non_resource_attributes.as_ref().map_or(0, |_| 1) +
+            self.resource_attributes.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.non_resource_attributes {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nonResourceAttributes", value)?;
+        }
+        if let Some(value) = &self.resource_attributes {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resourceAttributes", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for SelfSubjectAccessReviewSpec {
     fn schema_name() -> String {
-        "io.k8s.api.authorization.v1.SelfSubjectAccessReviewSpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::authorization::v1::SelfSubjectAccessReviewSpec".to_owned()
+        }
+        else {
+            "io.k8s.api.authorization.v1.SelfSubjectAccessReviewSpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -113,8 +238,9 @@ impl crate::schemars::JsonSchema for SelfSubjectAccessReviewSpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "nonResourceAttributes".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::authorization::v1::NonResourceAttributes>().into_object();
@@ -124,8 +250,8 @@ impl crate::schemars::JsonSchema for SelfSubjectAccessReviewSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "resourceAttributes".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::authorization::v1::ResourceAttributes>().into_object();
@@ -135,11 +261,22 @@ impl crate::schemars::JsonSchema for SelfSubjectAccessReviewSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for SelfSubjectAccessReviewSpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(SelfSubjectAccessReviewSpec {
+            non_resource_attributes: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            resource_attributes: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.autoscaling.v2beta1.ExternalMetricSource
 
 /// ExternalMetricSource indicates how to scale on a metric not associated with any Kubernetes object (for example length of queue in cloud messaging service, or QPS from loadbalancer running outside of cluster). Exactly one "target" type should be set.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ExternalMetricSource {
     /// metricName is the name of the metric in question.
     pub metric_name: String,
@@ -16,43 +16,61 @@ pub struct ExternalMetricSource {
     pub target_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ExternalMetricSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_metric_name,
-            Key_metric_selector,
-            Key_target_average_value,
-            Key_target_value,
-            Other,
+impl ExternalMetricSource {
+    /// Constructs a `ExternalMetricSource` from its required fields. Its optional fields are unset.
+    pub fn new(metric_name: impl Into<String>) -> Self {
+        ExternalMetricSource {
+            metric_name: metric_name.into(),
+            metric_selector: None,
+            target_average_value: None,
+            target_value: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl ExternalMetricSource {
+    /// Returns this value with [`ExternalMetricSource::metric_name`] set to the given value.
+    #[must_use]
+    pub fn with_metric_name(mut self, value: impl Into<String>) -> Self {
+        self.metric_name = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ExternalMetricSource::metric_selector`] set to the given value.
+    #[must_use]
+    pub fn with_metric_selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.metric_selector = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ExternalMetricSource::target_average_value`] set to the given value.
+    #[must_use]
+    pub fn with_target_average_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.target_average_value = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "metricName" => Field::Key_metric_name,
-                            "metricSelector" => Field::Key_metric_selector,
-                            "targetAverageValue" => Field::Key_target_average_value,
-                            "targetValue" => Field::Key_target_value,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`ExternalMetricSource::target_value`] set to the given value.
+    #[must_use]
+    pub fn with_target_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.target_value = Some(value.into());
+        self
+    }
+}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl crate::MergeStrategyInfo for ExternalMetricSource {}
+
+impl ExternalMetricSource {
+    const FIELDS: &'static [&'static str] = &[
+        "metricName",
+        "metricSelector",
+        "targetAverageValue",
+        "targetValue",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ExternalMetricSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -68,40 +86,111 @@ impl<'de> crate::serde::Deserialize<'de> for ExternalMetricSource {
                 let mut value_target_average_value: Option<crate::apimachinery::pkg::api::resource::Quantity> = None;
                 let mut value_target_value: Option<crate::apimachinery::pkg::api::resource::Quantity> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ExternalMetricSource", ExternalMetricSource::FIELDS))? {
                     match key {
-                        Field::Key_metric_name => value_metric_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_metric_selector => value_metric_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_target_average_value => value_target_average_value = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_target_value => value_target_value = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_metric_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_metric_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_target_average_value = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_target_value = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ExternalMetricSource {
-                    metric_name: value_metric_name.ok_or_else(|| crate::serde::de::Error::missing_field("metricName"))?,
+                    metric_name: value_metric_name.ok_or_else(|| crate::serde2::missing_field("ExternalMetricSource", "metricName", &["metricName"]))?,
                     metric_selector: value_metric_selector,
                     target_average_value: value_target_average_value,
                     target_value: value_target_value,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ExternalMetricSource {
+                    metric_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    metric_selector: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    target_average_value: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    target_value: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "ExternalMetricSource",
-            &[
-                "metricName",
-                "metricSelector",
-                "targetAverageValue",
-                "targetValue",
-            ],
+            ExternalMetricSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ExternalMetricSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ExternalMetricSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_metric_name = false;
+                let mut has_metric_selector = false;
+                let mut has_target_average_value = false;
+                let mut has_target_value = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ExternalMetricSource", ExternalMetricSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metric_name))?; has_metric_name = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.metric_selector))?; has_metric_selector = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.target_average_value))?; has_target_average_value = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.target_value))?; has_target_value = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_metric_name {
+                    return Err(crate::serde2::missing_field("ExternalMetricSource", "metricName", &["metricName"]));
+                }
+                if !has_metric_selector {
+                    self.0.metric_selector = None;
+                }
+                if !has_target_average_value {
+                    self.0.target_average_value = None;
+                }
+                if !has_target_value {
+                    self.0.target_value = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metric_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.metric_selector))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.target_average_value))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.target_value))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ExternalMetricSource",
+            ExternalMetricSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ExternalMetricSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ExternalMetricSource", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricName", &self.metric_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricSelector", &self.metric_selector)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "targetAverageValue", &self.target_average_value)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "targetValue", &self.target_value)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ExternalMetricSource",
             1 +
@@ -123,10 +212,114 @@ impl crate::serde::Serialize for ExternalMetricSource {
     }
 }
 
+/// A builder of [`ExternalMetricSource`]. Created with [`ExternalMetricSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ExternalMetricSourceBuilder {
+    metric_name: Option<String>,
+    metric_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>,
+    target_average_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+    target_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+}
+
+#[cfg(feature = "builder")]
+impl ExternalMetricSource {
+    /// Starts building a `ExternalMetricSource`. Its required fields must be set before [`ExternalMetricSourceBuilder::build`] is called.
+    pub fn builder() -> ExternalMetricSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ExternalMetricSourceBuilder {
+    /// Sets [`ExternalMetricSource::metric_name`].
+    pub fn metric_name(mut self, value: impl Into<String>) -> Self {
+        self.metric_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`ExternalMetricSource::metric_selector`].
+    pub fn metric_selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.metric_selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`ExternalMetricSource::target_average_value`].
+    pub fn target_average_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.target_average_value = Some(value.into());
+        self
+    }
+
+    /// Sets [`ExternalMetricSource::target_value`].
+    pub fn target_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.target_value = Some(value.into());
+        self
+    }
+
+    /// Builds the `ExternalMetricSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ExternalMetricSource, crate::MissingFieldError> {
+        Ok(ExternalMetricSource {
+            metric_name: self.metric_name.ok_or_else(|| crate::MissingFieldError::new("ExternalMetricSource", "metricName"))?,
+            metric_selector: self.metric_selector,
+            target_average_value: self.target_average_value,
+            target_value: self.target_value,
+        })
+    }
+}
+
+/// The apply configuration of [`ExternalMetricSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ExternalMetricSourceApplyConfiguration {
+    /// See [`ExternalMetricSource::metric_name`].
+    pub metric_name: Option<String>,
+
+    /// See [`ExternalMetricSource::metric_selector`].
+    pub metric_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorApplyConfiguration>,
+
+    /// See [`ExternalMetricSource::target_average_value`].
+    pub target_average_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+
+    /// See [`ExternalMetricSource::target_value`].
+    pub target_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ExternalMetricSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ExternalMetricSourceApplyConfiguration",
+            self.metric_name.as_ref().map_or(0, |_| 1) +
+            self.metric_selector.as_ref().map_or(0, |_| 1) +
+            self.target_average_value.as_ref().map_or(0, |_| 1) +
+            self.target_value.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.metric_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricName", value)?;
+        }
+        if let Some(value) = &self.metric_selector {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricSelector", value)?;
+        }
+        if let Some(value) = &self.target_average_value {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "targetAverageValue", value)?;
+        }
+        if let Some(value) = &self.target_value {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "targetValue", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ExternalMetricSource {
     fn schema_name() -> String {
-        "io.k8s.api.autoscaling.v2beta1.ExternalMetricSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::autoscaling::v2beta1::ExternalMetricSource".to_owned()
+        }
+        else {
+            "io.k8s.api.autoscaling.v2beta1.ExternalMetricSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -137,8 +330,9 @@ impl crate::schemars::JsonSchema for ExternalMetricSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "metricName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -148,8 +342,8 @@ impl crate::schemars::JsonSchema for ExternalMetricSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "metricSelector".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>().into_object();
@@ -159,8 +353,8 @@ impl crate::schemars::JsonSchema for ExternalMetricSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "targetAverageValue".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::api::resource::Quantity>().into_object();
@@ -170,8 +364,8 @@ impl crate::schemars::JsonSchema for ExternalMetricSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "targetValue".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::api::resource::Quantity>().into_object();
@@ -181,8 +375,9 @@ impl crate::schemars::JsonSchema for ExternalMetricSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "metricName",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -192,3 +387,15 @@ impl crate::schemars::JsonSchema for ExternalMetricSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ExternalMetricSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ExternalMetricSource {
+            metric_name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            metric_selector: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            target_average_value: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            target_value: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.autoscaling.v2beta2.ObjectMetricSource
 
 /// ObjectMetricSource indicates how to scale on a metric describing a kubernetes object (for example, hits-per-second on an Ingress object).
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ObjectMetricSource {
     pub described_object: crate::api::autoscaling::v2beta2::CrossVersionObjectReference,
 
@@ -12,41 +12,52 @@ pub struct ObjectMetricSource {
     pub target: crate::api::autoscaling::v2beta2::MetricTarget,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ObjectMetricSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_described_object,
-            Key_metric,
-            Key_target,
-            Other,
+impl ObjectMetricSource {
+    /// Constructs a `ObjectMetricSource` from its required fields. Its optional fields are unset.
+    pub fn new(described_object: impl Into<crate::api::autoscaling::v2beta2::CrossVersionObjectReference>, metric: impl Into<crate::api::autoscaling::v2beta2::MetricIdentifier>, target: impl Into<crate::api::autoscaling::v2beta2::MetricTarget>) -> Self {
+        ObjectMetricSource {
+            described_object: described_object.into(),
+            metric: metric.into(),
+            target: target.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl ObjectMetricSource {
+    /// Returns this value with [`ObjectMetricSource::described_object`] set to the given value.
+    #[must_use]
+    pub fn with_described_object(mut self, value: impl Into<crate::api::autoscaling::v2beta2::CrossVersionObjectReference>) -> Self {
+        self.described_object = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ObjectMetricSource::metric`] set to the given value.
+    #[must_use]
+    pub fn with_metric(mut self, value: impl Into<crate::api::autoscaling::v2beta2::MetricIdentifier>) -> Self {
+        self.metric = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ObjectMetricSource::target`] set to the given value.
+    #[must_use]
+    pub fn with_target(mut self, value: impl Into<crate::api::autoscaling::v2beta2::MetricTarget>) -> Self {
+        self.target = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "describedObject" => Field::Key_described_object,
-                            "metric" => Field::Key_metric,
-                            "target" => Field::Key_target,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for ObjectMetricSource {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl ObjectMetricSource {
+    const FIELDS: &'static [&'static str] = &[
+        "describedObject",
+        "metric",
+        "target",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ObjectMetricSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -61,37 +72,101 @@ impl<'de> crate::serde::Deserialize<'de> for ObjectMetricSource {
                 let mut value_metric: Option<crate::api::autoscaling::v2beta2::MetricIdentifier> = None;
                 let mut value_target: Option<crate::api::autoscaling::v2beta2::MetricTarget> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ObjectMetricSource", ObjectMetricSource::FIELDS))? {
                     match key {
-                        Field::Key_described_object => value_described_object = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_metric => value_metric = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_target => value_target = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_described_object = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_metric = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_target = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ObjectMetricSource {
-                    described_object: value_described_object.ok_or_else(|| crate::serde::de::Error::missing_field("describedObject"))?,
-                    metric: value_metric.ok_or_else(|| crate::serde::de::Error::missing_field("metric"))?,
-                    target: value_target.ok_or_else(|| crate::serde::de::Error::missing_field("target"))?,
+                    described_object: value_described_object.ok_or_else(|| crate::serde2::missing_field("ObjectMetricSource", "describedObject", &["describedObject", "metric", "target"]))?,
+                    metric: value_metric.ok_or_else(|| crate::serde2::missing_field("ObjectMetricSource", "metric", &["describedObject", "metric", "target"]))?,
+                    target: value_target.ok_or_else(|| crate::serde2::missing_field("ObjectMetricSource", "target", &["describedObject", "metric", "target"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ObjectMetricSource {
+                    described_object: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    metric: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    target: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "ObjectMetricSource",
-            &[
-                "describedObject",
-                "metric",
-                "target",
-            ],
+            ObjectMetricSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ObjectMetricSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ObjectMetricSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_described_object = false;
+                let mut has_metric = false;
+                let mut has_target = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ObjectMetricSource", ObjectMetricSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.described_object))?; has_described_object = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metric))?; has_metric = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.target))?; has_target = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_described_object {
+                    return Err(crate::serde2::missing_field("ObjectMetricSource", "describedObject", &["describedObject", "metric", "target"]));
+                }
+                if !has_metric {
+                    return Err(crate::serde2::missing_field("ObjectMetricSource", "metric", &["describedObject", "metric", "target"]));
+                }
+                if !has_target {
+                    return Err(crate::serde2::missing_field("ObjectMetricSource", "target", &["describedObject", "metric", "target"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.described_object))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metric))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.target))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ObjectMetricSource",
+            ObjectMetricSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ObjectMetricSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ObjectMetricSource", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "describedObject", &self.described_object)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metric", &self.metric)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "target", &self.target)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ObjectMetricSource",
             3,
@@ -103,10 +178,99 @@ impl crate::serde::Serialize for ObjectMetricSource {
     }
 }
 
+/// A builder of [`ObjectMetricSource`]. Created with [`ObjectMetricSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ObjectMetricSourceBuilder {
+    described_object: Option<crate::api::autoscaling::v2beta2::CrossVersionObjectReference>,
+    metric: Option<crate::api::autoscaling::v2beta2::MetricIdentifier>,
+    target: Option<crate::api::autoscaling::v2beta2::MetricTarget>,
+}
+
+#[cfg(feature = "builder")]
+impl ObjectMetricSource {
+    /// Starts building a `ObjectMetricSource`. Its required fields must be set before [`ObjectMetricSourceBuilder::build`] is called.
+    pub fn builder() -> ObjectMetricSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ObjectMetricSourceBuilder {
+    /// Sets [`ObjectMetricSource::described_object`].
+    pub fn described_object(mut self, value: impl Into<crate::api::autoscaling::v2beta2::CrossVersionObjectReference>) -> Self {
+        self.described_object = Some(value.into());
+        self
+    }
+
+    /// Sets [`ObjectMetricSource::metric`].
+    pub fn metric(mut self, value: impl Into<crate::api::autoscaling::v2beta2::MetricIdentifier>) -> Self {
+        self.metric = Some(value.into());
+        self
+    }
+
+    /// Sets [`ObjectMetricSource::target`].
+    pub fn target(mut self, value: impl Into<crate::api::autoscaling::v2beta2::MetricTarget>) -> Self {
+        self.target = Some(value.into());
+        self
+    }
+
+    /// Builds the `ObjectMetricSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ObjectMetricSource, crate::MissingFieldError> {
+        Ok(ObjectMetricSource {
+            described_object: self.described_object.ok_or_else(|| crate::MissingFieldError::new("ObjectMetricSource", "describedObject"))?,
+            metric: self.metric.ok_or_else(|| crate::MissingFieldError::new("ObjectMetricSource", "metric"))?,
+            target: self.target.ok_or_else(|| crate::MissingFieldError::new("ObjectMetricSource", "target"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`ObjectMetricSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ObjectMetricSourceApplyConfiguration {
+    /// See [`ObjectMetricSource::described_object`].
+    pub described_object: Option<crate::api::autoscaling::v2beta2::CrossVersionObjectReferenceApplyConfiguration>,
+
+    /// See [`ObjectMetricSource::metric`].
+    pub metric: Option<crate::api::autoscaling::v2beta2::MetricIdentifierApplyConfiguration>,
+
+    /// See [`ObjectMetricSource::target`].
+    pub target: Option<crate::api::autoscaling::v2beta2::MetricTargetApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ObjectMetricSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ObjectMetricSourceApplyConfiguration",
+            self.described_object.as_ref().map_or(0, |_| 1) +
+            self.metric.as_ref().map_or(0, |_| 1) +
+            self.target.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.described_object {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "describedObject", value)?;
+        }
+        if let Some(value) = &self.metric {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metric", value)?;
+        }
+        if let Some(value) = &self.target {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "target", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ObjectMetricSource {
     fn schema_name() -> String {
-        "io.k8s.api.autoscaling.v2beta2.ObjectMetricSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::autoscaling::v2beta2::ObjectMetricSource".to_owned()
+        }
+        else {
+            "io.k8s.api.autoscaling.v2beta2.ObjectMetricSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -117,12 +281,13 @@ impl crate::schemars::JsonSchema for ObjectMetricSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "describedObject".to_owned(),
                         __gen.subschema_for::<crate::api::autoscaling::v2beta2::CrossVersionObjectReference>(),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "metric".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::autoscaling::v2beta2::MetricIdentifier>().into_object();
@@ -132,8 +297,8 @@ impl crate::schemars::JsonSchema for ObjectMetricSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "target".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::autoscaling::v2beta2::MetricTarget>().into_object();
@@ -143,8 +308,9 @@ impl crate::schemars::JsonSchema for ObjectMetricSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "describedObject",
                     "metric",
@@ -156,3 +322,14 @@ impl crate::schemars::JsonSchema for ObjectMetricSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ObjectMetricSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ObjectMetricSource {
+            described_object: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            metric: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            target: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
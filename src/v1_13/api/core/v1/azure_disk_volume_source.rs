@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.AzureDiskVolumeSource
 
 /// AzureDisk represents an Azure Data Disk mount on the host and bind mount to the pod.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct AzureDiskVolumeSource {
     /// Host Caching mode: None, Read Only, Read Write.
     pub caching_mode: Option<String>,
@@ -22,47 +22,79 @@ pub struct AzureDiskVolumeSource {
     pub read_only: Option<bool>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for AzureDiskVolumeSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_caching_mode,
-            Key_disk_name,
-            Key_disk_uri,
-            Key_fs_type,
-            Key_kind,
-            Key_read_only,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl AzureDiskVolumeSource {
+    /// Constructs a `AzureDiskVolumeSource` from its required fields. Its optional fields are unset.
+    pub fn new(disk_name: impl Into<String>, disk_uri: impl Into<String>) -> Self {
+        AzureDiskVolumeSource {
+            caching_mode: None,
+            disk_name: disk_name.into(),
+            disk_uri: disk_uri.into(),
+            fs_type: None,
+            kind: None,
+            read_only: None,
+        }
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "cachingMode" => Field::Key_caching_mode,
-                            "diskName" => Field::Key_disk_name,
-                            "diskURI" => Field::Key_disk_uri,
-                            "fsType" => Field::Key_fs_type,
-                            "kind" => Field::Key_kind,
-                            "readOnly" => Field::Key_read_only,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl AzureDiskVolumeSource {
+    /// Returns this value with [`AzureDiskVolumeSource::caching_mode`] set to the given value.
+    #[must_use]
+    pub fn with_caching_mode(mut self, value: impl Into<String>) -> Self {
+        self.caching_mode = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`AzureDiskVolumeSource::disk_name`] set to the given value.
+    #[must_use]
+    pub fn with_disk_name(mut self, value: impl Into<String>) -> Self {
+        self.disk_name = value.into();
+        self
+    }
+
+    /// Returns this value with [`AzureDiskVolumeSource::disk_uri`] set to the given value.
+    #[must_use]
+    pub fn with_disk_uri(mut self, value: impl Into<String>) -> Self {
+        self.disk_uri = value.into();
+        self
+    }
 
+    /// Returns this value with [`AzureDiskVolumeSource::fs_type`] set to the given value.
+    #[must_use]
+    pub fn with_fs_type(mut self, value: impl Into<String>) -> Self {
+        self.fs_type = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`AzureDiskVolumeSource::kind`] set to the given value.
+    #[must_use]
+    pub fn with_kind(mut self, value: impl Into<String>) -> Self {
+        self.kind = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`AzureDiskVolumeSource::read_only`] set to the given value.
+    #[must_use]
+    pub fn with_read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for AzureDiskVolumeSource {}
+
+impl AzureDiskVolumeSource {
+    const FIELDS: &'static [&'static str] = &[
+        "cachingMode",
+        "diskName",
+        "diskURI",
+        "fsType",
+        "kind",
+        "readOnly",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for AzureDiskVolumeSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -80,46 +112,131 @@ impl<'de> crate::serde::Deserialize<'de> for AzureDiskVolumeSource {
                 let mut value_kind: Option<String> = None;
                 let mut value_read_only: Option<bool> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("AzureDiskVolumeSource", AzureDiskVolumeSource::FIELDS))? {
                     match key {
-                        Field::Key_caching_mode => value_caching_mode = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_disk_name => value_disk_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_disk_uri => value_disk_uri = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_fs_type => value_fs_type = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_kind => value_kind = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_read_only => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_caching_mode = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_disk_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_disk_uri = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_fs_type = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_kind = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(AzureDiskVolumeSource {
                     caching_mode: value_caching_mode,
-                    disk_name: value_disk_name.ok_or_else(|| crate::serde::de::Error::missing_field("diskName"))?,
-                    disk_uri: value_disk_uri.ok_or_else(|| crate::serde::de::Error::missing_field("diskURI"))?,
+                    disk_name: value_disk_name.ok_or_else(|| crate::serde2::missing_field("AzureDiskVolumeSource", "diskName", &["diskName", "diskURI"]))?,
+                    disk_uri: value_disk_uri.ok_or_else(|| crate::serde2::missing_field("AzureDiskVolumeSource", "diskURI", &["diskName", "diskURI"]))?,
                     fs_type: value_fs_type,
                     kind: value_kind,
                     read_only: value_read_only,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(AzureDiskVolumeSource {
+                    caching_mode: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    disk_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    disk_uri: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    fs_type: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    kind: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    read_only: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "AzureDiskVolumeSource",
-            &[
-                "cachingMode",
-                "diskName",
-                "diskURI",
-                "fsType",
-                "kind",
-                "readOnly",
-            ],
+            AzureDiskVolumeSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, AzureDiskVolumeSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("AzureDiskVolumeSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_caching_mode = false;
+                let mut has_disk_name = false;
+                let mut has_disk_uri = false;
+                let mut has_fs_type = false;
+                let mut has_kind = false;
+                let mut has_read_only = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("AzureDiskVolumeSource", AzureDiskVolumeSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.caching_mode))?; has_caching_mode = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.disk_name))?; has_disk_name = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.disk_uri))?; has_disk_uri = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.fs_type))?; has_fs_type = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.kind))?; has_kind = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.read_only))?; has_read_only = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_caching_mode {
+                    self.0.caching_mode = None;
+                }
+                if !has_disk_name {
+                    return Err(crate::serde2::missing_field("AzureDiskVolumeSource", "diskName", &["diskName", "diskURI"]));
+                }
+                if !has_disk_uri {
+                    return Err(crate::serde2::missing_field("AzureDiskVolumeSource", "diskURI", &["diskName", "diskURI"]));
+                }
+                if !has_fs_type {
+                    self.0.fs_type = None;
+                }
+                if !has_kind {
+                    self.0.kind = None;
+                }
+                if !has_read_only {
+                    self.0.read_only = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.caching_mode))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.disk_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.disk_uri))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.fs_type))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.kind))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.read_only))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "AzureDiskVolumeSource",
+            AzureDiskVolumeSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for AzureDiskVolumeSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("AzureDiskVolumeSource", 6)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cachingMode", &self.caching_mode)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "diskName", &self.disk_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "diskURI", &self.disk_uri)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsType", &self.fs_type)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", &self.kind)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", &self.read_only)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "AzureDiskVolumeSource",
             2 +
@@ -146,10 +263,144 @@ impl crate::serde::Serialize for AzureDiskVolumeSource {
     }
 }
 
+/// A builder of [`AzureDiskVolumeSource`]. Created with [`AzureDiskVolumeSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct AzureDiskVolumeSourceBuilder {
+    caching_mode: Option<String>,
+    disk_name: Option<String>,
+    disk_uri: Option<String>,
+    fs_type: Option<String>,
+    kind: Option<String>,
+    read_only: Option<bool>,
+}
+
+#[cfg(feature = "builder")]
+impl AzureDiskVolumeSource {
+    /// Starts building a `AzureDiskVolumeSource`. Its required fields must be set before [`AzureDiskVolumeSourceBuilder::build`] is called.
+    pub fn builder() -> AzureDiskVolumeSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl AzureDiskVolumeSourceBuilder {
+    /// Sets [`AzureDiskVolumeSource::caching_mode`].
+    pub fn caching_mode(mut self, value: impl Into<String>) -> Self {
+        self.caching_mode = Some(value.into());
+        self
+    }
+
+    /// Sets [`AzureDiskVolumeSource::disk_name`].
+    pub fn disk_name(mut self, value: impl Into<String>) -> Self {
+        self.disk_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`AzureDiskVolumeSource::disk_uri`].
+    pub fn disk_uri(mut self, value: impl Into<String>) -> Self {
+        self.disk_uri = Some(value.into());
+        self
+    }
+
+    /// Sets [`AzureDiskVolumeSource::fs_type`].
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`AzureDiskVolumeSource::kind`].
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`AzureDiskVolumeSource::read_only`].
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+
+    /// Builds the `AzureDiskVolumeSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<AzureDiskVolumeSource, crate::MissingFieldError> {
+        Ok(AzureDiskVolumeSource {
+            caching_mode: self.caching_mode,
+            disk_name: self.disk_name.ok_or_else(|| crate::MissingFieldError::new("AzureDiskVolumeSource", "diskName"))?,
+            disk_uri: self.disk_uri.ok_or_else(|| crate::MissingFieldError::new("AzureDiskVolumeSource", "diskURI"))?,
+            fs_type: self.fs_type,
+            kind: self.kind,
+            read_only: self.read_only,
+        })
+    }
+}
+
+/// The apply configuration of [`AzureDiskVolumeSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AzureDiskVolumeSourceApplyConfiguration {
+    /// See [`AzureDiskVolumeSource::caching_mode`].
+    pub caching_mode: Option<String>,
+
+    /// See [`AzureDiskVolumeSource::disk_name`].
+    pub disk_name: Option<String>,
+
+    /// See [`AzureDiskVolumeSource::disk_uri`].
+    pub disk_uri: Option<String>,
+
+    /// See [`AzureDiskVolumeSource::fs_type`].
+    pub fs_type: Option<String>,
+
+    /// See [`AzureDiskVolumeSource::kind`].
+    pub kind: Option<String>,
+
+    /// See [`AzureDiskVolumeSource::read_only`].
+    pub read_only: Option<bool>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for AzureDiskVolumeSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "AzureDiskVolumeSourceApplyConfiguration",
+            self.caching_mode.as_ref().map_or(0, |_| 1) +
+            self.disk_name.as_ref().map_or(0, |_| 1) +
+            self.disk_uri.as_ref().map_or(0, |_| 1) +
+            self.fs_type.as_ref().map_or(0, |_| 1) +
+            self.kind.as_ref().map_or(0, |_| 1) +
+            self.read_only.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.caching_mode {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cachingMode", value)?;
+        }
+        if let Some(value) = &self.disk_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "diskName", value)?;
+        }
+        if let Some(value) = &self.disk_uri {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "diskURI", value)?;
+        }
+        if let Some(value) = &self.fs_type {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsType", value)?;
+        }
+        if let Some(value) = &self.kind {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", value)?;
+        }
+        if let Some(value) = &self.read_only {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for AzureDiskVolumeSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.AzureDiskVolumeSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::AzureDiskVolumeSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.AzureDiskVolumeSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -160,8 +411,9 @@ impl crate::schemars::JsonSchema for AzureDiskVolumeSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "cachingMode".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -171,8 +423,8 @@ impl crate::schemars::JsonSchema for AzureDiskVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "diskName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -182,8 +434,8 @@ impl crate::schemars::JsonSchema for AzureDiskVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "diskURI".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -193,8 +445,8 @@ impl crate::schemars::JsonSchema for AzureDiskVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "fsType".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -204,8 +456,8 @@ impl crate::schemars::JsonSchema for AzureDiskVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "kind".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -215,8 +467,8 @@ impl crate::schemars::JsonSchema for AzureDiskVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "readOnly".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -226,8 +478,9 @@ impl crate::schemars::JsonSchema for AzureDiskVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "diskName",
                     "diskURI",
@@ -238,3 +491,17 @@ impl crate::schemars::JsonSchema for AzureDiskVolumeSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for AzureDiskVolumeSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(AzureDiskVolumeSource {
+            caching_mode: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            disk_name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            disk_uri: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            fs_type: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            kind: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            read_only: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,80 +1,118 @@
 // Generated from definition io.k8s.api.core.v1.ClientIPConfig
 
 /// ClientIPConfig represents the configurations of Client IP based session affinity.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ClientIPConfig {
     /// timeoutSeconds specifies the seconds of ClientIP type session sticky time. The value must be \>0 && \<=86400(for 1 day) if ServiceAffinity == "ClientIP". Default value is 10800(for 3 hours).
     pub timeout_seconds: Option<i32>,
 }
 
+impl ClientIPConfig {
+    /// Returns this value with [`ClientIPConfig::timeout_seconds`] set to the given value.
+    #[must_use]
+    pub fn with_timeout_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.timeout_seconds = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for ClientIPConfig {}
+
+impl ClientIPConfig {
+    const FIELDS: &'static [&'static str] = &[
+        "timeoutSeconds",
+    ];
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ClientIPConfig {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_timeout_seconds,
-            Other,
-        }
+        struct Visitor;
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = ClientIPConfig;
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ClientIPConfig")
+            }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_timeout_seconds: Option<i32> = None;
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "timeoutSeconds" => Field::Key_timeout_seconds,
-                            _ => Field::Other,
-                        })
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ClientIPConfig", ClientIPConfig::FIELDS))? {
+                    match key {
+                        Some(0) => value_timeout_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
-                deserializer.deserialize_identifier(Visitor)
+                Ok(ClientIPConfig {
+                    timeout_seconds: value_timeout_seconds,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ClientIPConfig {
+                    timeout_seconds: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                })
             }
         }
 
-        struct Visitor;
+        deserializer.deserialize_struct(
+            "ClientIPConfig",
+            ClientIPConfig::FIELDS,
+            Visitor,
+        )
+    }
 
-        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-            type Value = ClientIPConfig;
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ClientIPConfig> {
+            type Value = ();
 
             fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 f.write_str("ClientIPConfig")
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
-                let mut value_timeout_seconds: Option<i32> = None;
+                let mut has_timeout_seconds = false;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ClientIPConfig", ClientIPConfig::FIELDS))? {
                     match key {
-                        Field::Key_timeout_seconds => value_timeout_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.timeout_seconds))?; has_timeout_seconds = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
+                if !has_timeout_seconds {
+                    self.0.timeout_seconds = None;
+                }
 
-                Ok(ClientIPConfig {
-                    timeout_seconds: value_timeout_seconds,
-                })
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.timeout_seconds))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
             }
         }
 
         deserializer.deserialize_struct(
             "ClientIPConfig",
-            &[
-                "timeoutSeconds",
-            ],
-            Visitor,
+            ClientIPConfig::FIELDS,
+            InPlaceVisitor(place),
         )
     }
 }
 
 impl crate::serde::Serialize for ClientIPConfig {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ClientIPConfig", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "timeoutSeconds", &self.timeout_seconds)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ClientIPConfig",
             self.timeout_seconds.as_ref().map_or(0, |_| 1),
@@ -86,10 +124,69 @@ impl crate::serde::Serialize for ClientIPConfig {
     }
 }
 
+/// A builder of [`ClientIPConfig`]. Created with [`ClientIPConfig::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ClientIPConfigBuilder {
+    timeout_seconds: Option<i32>,
+}
+
+#[cfg(feature = "builder")]
+impl ClientIPConfig {
+    /// Starts building a `ClientIPConfig`. Its required fields must be set before [`ClientIPConfigBuilder::build`] is called.
+    pub fn builder() -> ClientIPConfigBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ClientIPConfigBuilder {
+    /// Sets [`ClientIPConfig::timeout_seconds`].
+    pub fn timeout_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.timeout_seconds = Some(value.into());
+        self
+    }
+
+    /// Builds the `ClientIPConfig`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ClientIPConfig, crate::MissingFieldError> {
+        Ok(ClientIPConfig {
+            timeout_seconds: self.timeout_seconds,
+        })
+    }
+}
+
+/// The apply configuration of [`ClientIPConfig`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ClientIPConfigApplyConfiguration {
+    /// See [`ClientIPConfig::timeout_seconds`].
+    pub timeout_seconds: Option<i32>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ClientIPConfigApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ClientIPConfigApplyConfiguration",
+            self.timeout_seconds.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.timeout_seconds {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "timeoutSeconds", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ClientIPConfig {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.ClientIPConfig".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::ClientIPConfig".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.ClientIPConfig".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -100,8 +197,9 @@ impl crate::schemars::JsonSchema for ClientIPConfig {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "timeoutSeconds".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -112,11 +210,21 @@ impl crate::schemars::JsonSchema for ClientIPConfig {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ClientIPConfig {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ClientIPConfig {
+            timeout_seconds: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
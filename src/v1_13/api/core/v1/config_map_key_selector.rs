@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.ConfigMapKeySelector
 
 /// Selects a key from a ConfigMap.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ConfigMapKeySelector {
     /// The key to select.
     pub key: String,
@@ -13,41 +13,52 @@ pub struct ConfigMapKeySelector {
     pub optional: Option<bool>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ConfigMapKeySelector {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_key,
-            Key_name,
-            Key_optional,
-            Other,
+impl ConfigMapKeySelector {
+    /// Constructs a `ConfigMapKeySelector` from its required fields. Its optional fields are unset.
+    pub fn new(key: impl Into<String>) -> Self {
+        ConfigMapKeySelector {
+            key: key.into(),
+            name: None,
+            optional: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl ConfigMapKeySelector {
+    /// Returns this value with [`ConfigMapKeySelector::key`] set to the given value.
+    #[must_use]
+    pub fn with_key(mut self, value: impl Into<String>) -> Self {
+        self.key = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ConfigMapKeySelector::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ConfigMapKeySelector::optional`] set to the given value.
+    #[must_use]
+    pub fn with_optional(mut self, value: impl Into<bool>) -> Self {
+        self.optional = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "key" => Field::Key_key,
-                            "name" => Field::Key_name,
-                            "optional" => Field::Key_optional,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for ConfigMapKeySelector {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl ConfigMapKeySelector {
+    const FIELDS: &'static [&'static str] = &[
+        "key",
+        "name",
+        "optional",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ConfigMapKeySelector {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,37 +73,101 @@ impl<'de> crate::serde::Deserialize<'de> for ConfigMapKeySelector {
                 let mut value_name: Option<String> = None;
                 let mut value_optional: Option<bool> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ConfigMapKeySelector", ConfigMapKeySelector::FIELDS))? {
                     match key {
-                        Field::Key_key => value_key = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_name => value_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_optional => value_optional = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_key = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_optional = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ConfigMapKeySelector {
-                    key: value_key.ok_or_else(|| crate::serde::de::Error::missing_field("key"))?,
+                    key: value_key.ok_or_else(|| crate::serde2::missing_field("ConfigMapKeySelector", "key", &["key"]))?,
                     name: value_name,
                     optional: value_optional,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ConfigMapKeySelector {
+                    key: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    optional: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "ConfigMapKeySelector",
-            &[
-                "key",
-                "name",
-                "optional",
-            ],
+            ConfigMapKeySelector::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ConfigMapKeySelector> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ConfigMapKeySelector")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_key = false;
+                let mut has_name = false;
+                let mut has_optional = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ConfigMapKeySelector", ConfigMapKeySelector::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.key))?; has_key = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.name))?; has_name = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.optional))?; has_optional = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_key {
+                    return Err(crate::serde2::missing_field("ConfigMapKeySelector", "key", &["key"]));
+                }
+                if !has_name {
+                    self.0.name = None;
+                }
+                if !has_optional {
+                    self.0.optional = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.key))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.optional))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ConfigMapKeySelector",
+            ConfigMapKeySelector::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ConfigMapKeySelector {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ConfigMapKeySelector", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "key", &self.key)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "optional", &self.optional)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ConfigMapKeySelector",
             1 +
@@ -110,10 +185,99 @@ impl crate::serde::Serialize for ConfigMapKeySelector {
     }
 }
 
+/// A builder of [`ConfigMapKeySelector`]. Created with [`ConfigMapKeySelector::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ConfigMapKeySelectorBuilder {
+    key: Option<String>,
+    name: Option<String>,
+    optional: Option<bool>,
+}
+
+#[cfg(feature = "builder")]
+impl ConfigMapKeySelector {
+    /// Starts building a `ConfigMapKeySelector`. Its required fields must be set before [`ConfigMapKeySelectorBuilder::build`] is called.
+    pub fn builder() -> ConfigMapKeySelectorBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ConfigMapKeySelectorBuilder {
+    /// Sets [`ConfigMapKeySelector::key`].
+    pub fn key(mut self, value: impl Into<String>) -> Self {
+        self.key = Some(value.into());
+        self
+    }
+
+    /// Sets [`ConfigMapKeySelector::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`ConfigMapKeySelector::optional`].
+    pub fn optional(mut self, value: impl Into<bool>) -> Self {
+        self.optional = Some(value.into());
+        self
+    }
+
+    /// Builds the `ConfigMapKeySelector`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ConfigMapKeySelector, crate::MissingFieldError> {
+        Ok(ConfigMapKeySelector {
+            key: self.key.ok_or_else(|| crate::MissingFieldError::new("ConfigMapKeySelector", "key"))?,
+            name: self.name,
+            optional: self.optional,
+        })
+    }
+}
+
+/// The apply configuration of [`ConfigMapKeySelector`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConfigMapKeySelectorApplyConfiguration {
+    /// See [`ConfigMapKeySelector::key`].
+    pub key: Option<String>,
+
+    /// See [`ConfigMapKeySelector::name`].
+    pub name: Option<String>,
+
+    /// See [`ConfigMapKeySelector::optional`].
+    pub optional: Option<bool>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ConfigMapKeySelectorApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ConfigMapKeySelectorApplyConfiguration",
+            self.key.as_ref().map_or(0, |_| 1) +
+            self.name.as_ref().map_or(0, |_| 1) +
+            self.optional.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.key {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "key", value)?;
+        }
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        if let Some(value) = &self.optional {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "optional", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ConfigMapKeySelector {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.ConfigMapKeySelector".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::ConfigMapKeySelector".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.ConfigMapKeySelector".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -124,8 +288,9 @@ impl crate::schemars::JsonSchema for ConfigMapKeySelector {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "key".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -135,8 +300,8 @@ impl crate::schemars::JsonSchema for ConfigMapKeySelector {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -146,8 +311,8 @@ impl crate::schemars::JsonSchema for ConfigMapKeySelector {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "optional".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -157,8 +322,9 @@ impl crate::schemars::JsonSchema for ConfigMapKeySelector {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "key",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -168,3 +334,14 @@ impl crate::schemars::JsonSchema for ConfigMapKeySelector {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ConfigMapKeySelector {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ConfigMapKeySelector {
+            key: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            optional: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
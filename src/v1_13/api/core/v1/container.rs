@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.Container
 
 /// A single application container that you want to run within a pod.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct Container {
     /// Arguments to the entrypoint. The docker image's CMD is used if this is not provided. Variable references $(VAR_NAME) are expanded using the container's environment. If a variable cannot be resolved, the reference in the input string will be unchanged. The $(VAR_NAME) syntax can be escaped with a double $$, ie: $$(VAR_NAME). Escaped references will never be expanded, regardless of whether the variable exists or not. Cannot be updated. More info: https://kubernetes.io/docs/tasks/inject-data-application/define-command-argument-container/#running-a-command-in-a-shell
     pub args: Option<Vec<String>>,
@@ -19,7 +19,7 @@ pub struct Container {
     pub image: Option<String>,
 
     /// Image pull policy. One of Always, Never, IfNotPresent. Defaults to Always if :latest tag is specified, or IfNotPresent otherwise. Cannot be updated. More info: https://kubernetes.io/docs/concepts/containers/images#updating-images
-    pub image_pull_policy: Option<String>,
+    pub image_pull_policy: Option<crate::api::core::v1::PullPolicy>,
 
     /// Actions that the management system should take in response to container lifecycle events. Cannot be updated.
     pub lifecycle: Option<crate::api::core::v1::Lifecycle>,
@@ -52,7 +52,7 @@ pub struct Container {
     pub termination_message_path: Option<String>,
 
     /// Indicate how the termination message should be populated. File will use the contents of terminationMessagePath to populate the container status message on both success and failure. FallbackToLogsOnError will use the last chunk of container log output if the termination message file is empty and the container exited with an error. The log output is limited to 2048 bytes or 80 lines, whichever is smaller. Defaults to File. Cannot be updated.
-    pub termination_message_policy: Option<String>,
+    pub termination_message_policy: Option<crate::api::core::v1::TerminationMessagePolicy>,
 
     /// Whether this container should allocate a TTY for itself, also requires 'stdin' to be true. Default is false.
     pub tty: Option<bool>,
@@ -67,77 +67,221 @@ pub struct Container {
     pub working_dir: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for Container {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_args,
-            Key_command,
-            Key_env,
-            Key_env_from,
-            Key_image,
-            Key_image_pull_policy,
-            Key_lifecycle,
-            Key_liveness_probe,
-            Key_name,
-            Key_ports,
-            Key_readiness_probe,
-            Key_resources,
-            Key_security_context,
-            Key_stdin,
-            Key_stdin_once,
-            Key_termination_message_path,
-            Key_termination_message_policy,
-            Key_tty,
-            Key_volume_devices,
-            Key_volume_mounts,
-            Key_working_dir,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl Container {
+    /// Constructs a `Container` from its required fields. Its optional fields are unset.
+    pub fn new(name: impl Into<String>) -> Self {
+        Container {
+            args: None,
+            command: None,
+            env: None,
+            env_from: None,
+            image: None,
+            image_pull_policy: None,
+            lifecycle: None,
+            liveness_probe: None,
+            name: name.into(),
+            ports: None,
+            readiness_probe: None,
+            resources: None,
+            security_context: None,
+            stdin: None,
+            stdin_once: None,
+            termination_message_path: None,
+            termination_message_policy: None,
+            tty: None,
+            volume_devices: None,
+            volume_mounts: None,
+            working_dir: None,
+        }
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "args" => Field::Key_args,
-                            "command" => Field::Key_command,
-                            "env" => Field::Key_env,
-                            "envFrom" => Field::Key_env_from,
-                            "image" => Field::Key_image,
-                            "imagePullPolicy" => Field::Key_image_pull_policy,
-                            "lifecycle" => Field::Key_lifecycle,
-                            "livenessProbe" => Field::Key_liveness_probe,
-                            "name" => Field::Key_name,
-                            "ports" => Field::Key_ports,
-                            "readinessProbe" => Field::Key_readiness_probe,
-                            "resources" => Field::Key_resources,
-                            "securityContext" => Field::Key_security_context,
-                            "stdin" => Field::Key_stdin,
-                            "stdinOnce" => Field::Key_stdin_once,
-                            "terminationMessagePath" => Field::Key_termination_message_path,
-                            "terminationMessagePolicy" => Field::Key_termination_message_policy,
-                            "tty" => Field::Key_tty,
-                            "volumeDevices" => Field::Key_volume_devices,
-                            "volumeMounts" => Field::Key_volume_mounts,
-                            "workingDir" => Field::Key_working_dir,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl Container {
+    /// Returns this value with [`Container::args`] set to the given value.
+    #[must_use]
+    pub fn with_args(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.args = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`Container::command`] set to the given value.
+    #[must_use]
+    pub fn with_command(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.command = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Container::env`] set to the given value.
+    #[must_use]
+    pub fn with_env(mut self, value: impl Into<Vec<crate::api::core::v1::EnvVar>>) -> Self {
+        self.env = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Container::env_from`] set to the given value.
+    #[must_use]
+    pub fn with_env_from(mut self, value: impl Into<Vec<crate::api::core::v1::EnvFromSource>>) -> Self {
+        self.env_from = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Container::image`] set to the given value.
+    #[must_use]
+    pub fn with_image(mut self, value: impl Into<String>) -> Self {
+        self.image = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Container::image_pull_policy`] set to the given value.
+    #[must_use]
+    pub fn with_image_pull_policy(mut self, value: impl Into<crate::api::core::v1::PullPolicy>) -> Self {
+        self.image_pull_policy = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Container::lifecycle`] set to the given value.
+    #[must_use]
+    pub fn with_lifecycle(mut self, value: impl Into<crate::api::core::v1::Lifecycle>) -> Self {
+        self.lifecycle = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Container::liveness_probe`] set to the given value.
+    #[must_use]
+    pub fn with_liveness_probe(mut self, value: impl Into<crate::api::core::v1::Probe>) -> Self {
+        self.liveness_probe = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Container::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
+
+    /// Returns this value with [`Container::ports`] set to the given value.
+    #[must_use]
+    pub fn with_ports(mut self, value: impl Into<Vec<crate::api::core::v1::ContainerPort>>) -> Self {
+        self.ports = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Container::readiness_probe`] set to the given value.
+    #[must_use]
+    pub fn with_readiness_probe(mut self, value: impl Into<crate::api::core::v1::Probe>) -> Self {
+        self.readiness_probe = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Container::resources`] set to the given value.
+    #[must_use]
+    pub fn with_resources(mut self, value: impl Into<crate::api::core::v1::ResourceRequirements>) -> Self {
+        self.resources = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Container::security_context`] set to the given value.
+    #[must_use]
+    pub fn with_security_context(mut self, value: impl Into<crate::api::core::v1::SecurityContext>) -> Self {
+        self.security_context = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Container::stdin`] set to the given value.
+    #[must_use]
+    pub fn with_stdin(mut self, value: impl Into<bool>) -> Self {
+        self.stdin = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Container::stdin_once`] set to the given value.
+    #[must_use]
+    pub fn with_stdin_once(mut self, value: impl Into<bool>) -> Self {
+        self.stdin_once = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Container::termination_message_path`] set to the given value.
+    #[must_use]
+    pub fn with_termination_message_path(mut self, value: impl Into<String>) -> Self {
+        self.termination_message_path = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Container::termination_message_policy`] set to the given value.
+    #[must_use]
+    pub fn with_termination_message_policy(mut self, value: impl Into<crate::api::core::v1::TerminationMessagePolicy>) -> Self {
+        self.termination_message_policy = Some(value.into());
+        self
+    }
 
+    /// Returns this value with [`Container::tty`] set to the given value.
+    #[must_use]
+    pub fn with_tty(mut self, value: impl Into<bool>) -> Self {
+        self.tty = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Container::volume_devices`] set to the given value.
+    #[must_use]
+    pub fn with_volume_devices(mut self, value: impl Into<Vec<crate::api::core::v1::VolumeDevice>>) -> Self {
+        self.volume_devices = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Container::volume_mounts`] set to the given value.
+    #[must_use]
+    pub fn with_volume_mounts(mut self, value: impl Into<Vec<crate::api::core::v1::VolumeMount>>) -> Self {
+        self.volume_mounts = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Container::working_dir`] set to the given value.
+    #[must_use]
+    pub fn with_working_dir(mut self, value: impl Into<String>) -> Self {
+        self.working_dir = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for Container {
+    const FIELD_PATCH_STRATEGIES: &'static [crate::FieldPatchStrategy] = &[
+        crate::FieldPatchStrategy { field: "env", merge: true, retain_keys: false, merge_key: Some("name") },
+        crate::FieldPatchStrategy { field: "ports", merge: true, retain_keys: false, merge_key: Some("containerPort") },
+        crate::FieldPatchStrategy { field: "volumeDevices", merge: true, retain_keys: false, merge_key: Some("devicePath") },
+        crate::FieldPatchStrategy { field: "volumeMounts", merge: true, retain_keys: false, merge_key: Some("mountPath") },
+    ];
+}
+
+impl Container {
+    const FIELDS: &'static [&'static str] = &[
+        "args",
+        "command",
+        "env",
+        "envFrom",
+        "image",
+        "imagePullPolicy",
+        "lifecycle",
+        "livenessProbe",
+        "name",
+        "ports",
+        "readinessProbe",
+        "resources",
+        "securityContext",
+        "stdin",
+        "stdinOnce",
+        "terminationMessagePath",
+        "terminationMessagePolicy",
+        "tty",
+        "volumeDevices",
+        "volumeMounts",
+        "workingDir",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for Container {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -153,7 +297,7 @@ impl<'de> crate::serde::Deserialize<'de> for Container {
                 let mut value_env: Option<Vec<crate::api::core::v1::EnvVar>> = None;
                 let mut value_env_from: Option<Vec<crate::api::core::v1::EnvFromSource>> = None;
                 let mut value_image: Option<String> = None;
-                let mut value_image_pull_policy: Option<String> = None;
+                let mut value_image_pull_policy: Option<crate::api::core::v1::PullPolicy> = None;
                 let mut value_lifecycle: Option<crate::api::core::v1::Lifecycle> = None;
                 let mut value_liveness_probe: Option<crate::api::core::v1::Probe> = None;
                 let mut value_name: Option<String> = None;
@@ -164,36 +308,36 @@ impl<'de> crate::serde::Deserialize<'de> for Container {
                 let mut value_stdin: Option<bool> = None;
                 let mut value_stdin_once: Option<bool> = None;
                 let mut value_termination_message_path: Option<String> = None;
-                let mut value_termination_message_policy: Option<String> = None;
+                let mut value_termination_message_policy: Option<crate::api::core::v1::TerminationMessagePolicy> = None;
                 let mut value_tty: Option<bool> = None;
                 let mut value_volume_devices: Option<Vec<crate::api::core::v1::VolumeDevice>> = None;
                 let mut value_volume_mounts: Option<Vec<crate::api::core::v1::VolumeMount>> = None;
                 let mut value_working_dir: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Container", Container::FIELDS))? {
                     match key {
-                        Field::Key_args => value_args = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_command => value_command = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_env => value_env = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_env_from => value_env_from = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_image => value_image = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_image_pull_policy => value_image_pull_policy = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_lifecycle => value_lifecycle = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_liveness_probe => value_liveness_probe = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_name => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_ports => value_ports = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_readiness_probe => value_readiness_probe = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_resources => value_resources = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_security_context => value_security_context = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_stdin => value_stdin = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_stdin_once => value_stdin_once = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_termination_message_path => value_termination_message_path = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_termination_message_policy => value_termination_message_policy = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_tty => value_tty = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_volume_devices => value_volume_devices = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_volume_mounts => value_volume_mounts = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_working_dir => value_working_dir = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_args = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_command = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_env = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_env_from = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_image = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_image_pull_policy = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_lifecycle = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(7) => value_liveness_probe = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(8) => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(9) => value_ports = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(10) => value_readiness_probe = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(11) => value_resources = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(12) => value_security_context = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(13) => value_stdin = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(14) => value_stdin_once = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(15) => value_termination_message_path = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(16) => value_termination_message_policy = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(17) => value_tty = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(18) => value_volume_devices = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(19) => value_volume_mounts = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(20) => value_working_dir = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -206,7 +350,7 @@ impl<'de> crate::serde::Deserialize<'de> for Container {
                     image_pull_policy: value_image_pull_policy,
                     lifecycle: value_lifecycle,
                     liveness_probe: value_liveness_probe,
-                    name: value_name.ok_or_else(|| crate::serde::de::Error::missing_field("name"))?,
+                    name: value_name.ok_or_else(|| crate::serde2::missing_field("Container", "name", &["name"]))?,
                     ports: value_ports,
                     readiness_probe: value_readiness_probe,
                     resources: value_resources,
@@ -221,40 +365,230 @@ impl<'de> crate::serde::Deserialize<'de> for Container {
                     working_dir: value_working_dir,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(Container {
+                    args: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    command: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    env: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    env_from: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    image: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    image_pull_policy: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    lifecycle: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    liveness_probe: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?,
+                    ports: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?,
+                    readiness_probe: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?,
+                    resources: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(11, &self))?,
+                    security_context: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(12, &self))?,
+                    stdin: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(13, &self))?,
+                    stdin_once: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(14, &self))?,
+                    termination_message_path: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(15, &self))?,
+                    termination_message_policy: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(16, &self))?,
+                    tty: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(17, &self))?,
+                    volume_devices: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(18, &self))?,
+                    volume_mounts: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(19, &self))?,
+                    working_dir: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(20, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "Container",
-            &[
-                "args",
-                "command",
-                "env",
-                "envFrom",
-                "image",
-                "imagePullPolicy",
-                "lifecycle",
-                "livenessProbe",
-                "name",
-                "ports",
-                "readinessProbe",
-                "resources",
-                "securityContext",
-                "stdin",
-                "stdinOnce",
-                "terminationMessagePath",
-                "terminationMessagePolicy",
-                "tty",
-                "volumeDevices",
-                "volumeMounts",
-                "workingDir",
-            ],
+            Container::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, Container> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("Container")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_args = false;
+                let mut has_command = false;
+                let mut has_env = false;
+                let mut has_env_from = false;
+                let mut has_image = false;
+                let mut has_image_pull_policy = false;
+                let mut has_lifecycle = false;
+                let mut has_liveness_probe = false;
+                let mut has_name = false;
+                let mut has_ports = false;
+                let mut has_readiness_probe = false;
+                let mut has_resources = false;
+                let mut has_security_context = false;
+                let mut has_stdin = false;
+                let mut has_stdin_once = false;
+                let mut has_termination_message_path = false;
+                let mut has_termination_message_policy = false;
+                let mut has_tty = false;
+                let mut has_volume_devices = false;
+                let mut has_volume_mounts = false;
+                let mut has_working_dir = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Container", Container::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.args))?; has_args = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.command))?; has_command = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.env))?; has_env = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.env_from))?; has_env_from = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.image))?; has_image = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.image_pull_policy))?; has_image_pull_policy = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.lifecycle))?; has_lifecycle = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.liveness_probe))?; has_liveness_probe = true; },
+                        Some(8) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.name))?; has_name = true; },
+                        Some(9) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.ports))?; has_ports = true; },
+                        Some(10) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.readiness_probe))?; has_readiness_probe = true; },
+                        Some(11) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.resources))?; has_resources = true; },
+                        Some(12) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.security_context))?; has_security_context = true; },
+                        Some(13) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.stdin))?; has_stdin = true; },
+                        Some(14) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.stdin_once))?; has_stdin_once = true; },
+                        Some(15) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.termination_message_path))?; has_termination_message_path = true; },
+                        Some(16) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.termination_message_policy))?; has_termination_message_policy = true; },
+                        Some(17) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.tty))?; has_tty = true; },
+                        Some(18) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.volume_devices))?; has_volume_devices = true; },
+                        Some(19) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.volume_mounts))?; has_volume_mounts = true; },
+                        Some(20) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.working_dir))?; has_working_dir = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_args {
+                    self.0.args = None;
+                }
+                if !has_command {
+                    self.0.command = None;
+                }
+                if !has_env {
+                    self.0.env = None;
+                }
+                if !has_env_from {
+                    self.0.env_from = None;
+                }
+                if !has_image {
+                    self.0.image = None;
+                }
+                if !has_image_pull_policy {
+                    self.0.image_pull_policy = None;
+                }
+                if !has_lifecycle {
+                    self.0.lifecycle = None;
+                }
+                if !has_liveness_probe {
+                    self.0.liveness_probe = None;
+                }
+                if !has_name {
+                    return Err(crate::serde2::missing_field("Container", "name", &["name"]));
+                }
+                if !has_ports {
+                    self.0.ports = None;
+                }
+                if !has_readiness_probe {
+                    self.0.readiness_probe = None;
+                }
+                if !has_resources {
+                    self.0.resources = None;
+                }
+                if !has_security_context {
+                    self.0.security_context = None;
+                }
+                if !has_stdin {
+                    self.0.stdin = None;
+                }
+                if !has_stdin_once {
+                    self.0.stdin_once = None;
+                }
+                if !has_termination_message_path {
+                    self.0.termination_message_path = None;
+                }
+                if !has_termination_message_policy {
+                    self.0.termination_message_policy = None;
+                }
+                if !has_tty {
+                    self.0.tty = None;
+                }
+                if !has_volume_devices {
+                    self.0.volume_devices = None;
+                }
+                if !has_volume_mounts {
+                    self.0.volume_mounts = None;
+                }
+                if !has_working_dir {
+                    self.0.working_dir = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.args))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.command))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.env))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.env_from))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.image))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.image_pull_policy))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.lifecycle))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.liveness_probe))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.ports))?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.readiness_probe))?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.resources))?.ok_or_else(|| crate::serde::de::Error::invalid_length(11, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.security_context))?.ok_or_else(|| crate::serde::de::Error::invalid_length(12, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.stdin))?.ok_or_else(|| crate::serde::de::Error::invalid_length(13, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.stdin_once))?.ok_or_else(|| crate::serde::de::Error::invalid_length(14, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.termination_message_path))?.ok_or_else(|| crate::serde::de::Error::invalid_length(15, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.termination_message_policy))?.ok_or_else(|| crate::serde::de::Error::invalid_length(16, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.tty))?.ok_or_else(|| crate::serde::de::Error::invalid_length(17, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.volume_devices))?.ok_or_else(|| crate::serde::de::Error::invalid_length(18, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.volume_mounts))?.ok_or_else(|| crate::serde::de::Error::invalid_length(19, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.working_dir))?.ok_or_else(|| crate::serde::de::Error::invalid_length(20, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "Container",
+            Container::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for Container {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("Container", 21)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "args", &self.args)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "command", &self.command)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "env", &self.env)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "envFrom", &self.env_from)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "image", &self.image)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "imagePullPolicy", &self.image_pull_policy)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "lifecycle", &self.lifecycle)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "livenessProbe", &self.liveness_probe)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ports", &self.ports)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readinessProbe", &self.readiness_probe)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resources", &self.resources)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "securityContext", &self.security_context)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "stdin", &self.stdin)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "stdinOnce", &self.stdin_once)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "terminationMessagePath", &self.termination_message_path)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "terminationMessagePolicy", &self.termination_message_policy)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tty", &self.tty)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeDevices", &self.volume_devices)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeMounts", &self.volume_mounts)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "workingDir", &self.working_dir)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "Container",
             1 +
@@ -344,10 +678,369 @@ impl crate::serde::Serialize for Container {
     }
 }
 
+/// A builder of [`Container`]. Created with [`Container::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ContainerBuilder {
+    args: Option<Vec<String>>,
+    command: Option<Vec<String>>,
+    env: Option<Vec<crate::api::core::v1::EnvVar>>,
+    env_from: Option<Vec<crate::api::core::v1::EnvFromSource>>,
+    image: Option<String>,
+    image_pull_policy: Option<crate::api::core::v1::PullPolicy>,
+    lifecycle: Option<crate::api::core::v1::Lifecycle>,
+    liveness_probe: Option<crate::api::core::v1::Probe>,
+    name: Option<String>,
+    ports: Option<Vec<crate::api::core::v1::ContainerPort>>,
+    readiness_probe: Option<crate::api::core::v1::Probe>,
+    resources: Option<crate::api::core::v1::ResourceRequirements>,
+    security_context: Option<crate::api::core::v1::SecurityContext>,
+    stdin: Option<bool>,
+    stdin_once: Option<bool>,
+    termination_message_path: Option<String>,
+    termination_message_policy: Option<crate::api::core::v1::TerminationMessagePolicy>,
+    tty: Option<bool>,
+    volume_devices: Option<Vec<crate::api::core::v1::VolumeDevice>>,
+    volume_mounts: Option<Vec<crate::api::core::v1::VolumeMount>>,
+    working_dir: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl Container {
+    /// Starts building a `Container`. Its required fields must be set before [`ContainerBuilder::build`] is called.
+    pub fn builder() -> ContainerBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ContainerBuilder {
+    /// Sets [`Container::args`].
+    pub fn args(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.args = Some(value.into());
+        self
+    }
+
+    /// Sets [`Container::command`].
+    pub fn command(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.command = Some(value.into());
+        self
+    }
+
+    /// Sets [`Container::env`].
+    pub fn env(mut self, value: impl Into<Vec<crate::api::core::v1::EnvVar>>) -> Self {
+        self.env = Some(value.into());
+        self
+    }
+
+    /// Sets [`Container::env_from`].
+    pub fn env_from(mut self, value: impl Into<Vec<crate::api::core::v1::EnvFromSource>>) -> Self {
+        self.env_from = Some(value.into());
+        self
+    }
+
+    /// Sets [`Container::image`].
+    pub fn image(mut self, value: impl Into<String>) -> Self {
+        self.image = Some(value.into());
+        self
+    }
+
+    /// Sets [`Container::image_pull_policy`].
+    pub fn image_pull_policy(mut self, value: impl Into<crate::api::core::v1::PullPolicy>) -> Self {
+        self.image_pull_policy = Some(value.into());
+        self
+    }
+
+    /// Sets [`Container::lifecycle`].
+    pub fn lifecycle(mut self, value: impl Into<crate::api::core::v1::Lifecycle>) -> Self {
+        self.lifecycle = Some(value.into());
+        self
+    }
+
+    /// Sets [`Container::liveness_probe`].
+    pub fn liveness_probe(mut self, value: impl Into<crate::api::core::v1::Probe>) -> Self {
+        self.liveness_probe = Some(value.into());
+        self
+    }
+
+    /// Sets [`Container::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`Container::ports`].
+    pub fn ports(mut self, value: impl Into<Vec<crate::api::core::v1::ContainerPort>>) -> Self {
+        self.ports = Some(value.into());
+        self
+    }
+
+    /// Sets [`Container::readiness_probe`].
+    pub fn readiness_probe(mut self, value: impl Into<crate::api::core::v1::Probe>) -> Self {
+        self.readiness_probe = Some(value.into());
+        self
+    }
+
+    /// Sets [`Container::resources`].
+    pub fn resources(mut self, value: impl Into<crate::api::core::v1::ResourceRequirements>) -> Self {
+        self.resources = Some(value.into());
+        self
+    }
+
+    /// Sets [`Container::security_context`].
+    pub fn security_context(mut self, value: impl Into<crate::api::core::v1::SecurityContext>) -> Self {
+        self.security_context = Some(value.into());
+        self
+    }
+
+    /// Sets [`Container::stdin`].
+    pub fn stdin(mut self, value: impl Into<bool>) -> Self {
+        self.stdin = Some(value.into());
+        self
+    }
+
+    /// Sets [`Container::stdin_once`].
+    pub fn stdin_once(mut self, value: impl Into<bool>) -> Self {
+        self.stdin_once = Some(value.into());
+        self
+    }
+
+    /// Sets [`Container::termination_message_path`].
+    pub fn termination_message_path(mut self, value: impl Into<String>) -> Self {
+        self.termination_message_path = Some(value.into());
+        self
+    }
+
+    /// Sets [`Container::termination_message_policy`].
+    pub fn termination_message_policy(mut self, value: impl Into<crate::api::core::v1::TerminationMessagePolicy>) -> Self {
+        self.termination_message_policy = Some(value.into());
+        self
+    }
+
+    /// Sets [`Container::tty`].
+    pub fn tty(mut self, value: impl Into<bool>) -> Self {
+        self.tty = Some(value.into());
+        self
+    }
+
+    /// Sets [`Container::volume_devices`].
+    pub fn volume_devices(mut self, value: impl Into<Vec<crate::api::core::v1::VolumeDevice>>) -> Self {
+        self.volume_devices = Some(value.into());
+        self
+    }
+
+    /// Sets [`Container::volume_mounts`].
+    pub fn volume_mounts(mut self, value: impl Into<Vec<crate::api::core::v1::VolumeMount>>) -> Self {
+        self.volume_mounts = Some(value.into());
+        self
+    }
+
+    /// Sets [`Container::working_dir`].
+    pub fn working_dir(mut self, value: impl Into<String>) -> Self {
+        self.working_dir = Some(value.into());
+        self
+    }
+
+    /// Builds the `Container`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<Container, crate::MissingFieldError> {
+        Ok(Container {
+            args: self.args,
+            command: self.command,
+            env: self.env,
+            env_from: self.env_from,
+            image: self.image,
+            image_pull_policy: self.image_pull_policy,
+            lifecycle: self.lifecycle,
+            liveness_probe: self.liveness_probe,
+            name: self.name.ok_or_else(|| crate::MissingFieldError::new("Container", "name"))?,
+            ports: self.ports,
+            readiness_probe: self.readiness_probe,
+            resources: self.resources,
+            security_context: self.security_context,
+            stdin: self.stdin,
+            stdin_once: self.stdin_once,
+            termination_message_path: self.termination_message_path,
+            termination_message_policy: self.termination_message_policy,
+            tty: self.tty,
+            volume_devices: self.volume_devices,
+            volume_mounts: self.volume_mounts,
+            working_dir: self.working_dir,
+        })
+    }
+}
+
+/// The apply configuration of [`Container`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ContainerApplyConfiguration {
+    /// See [`Container::args`].
+    pub args: Option<Vec<String>>,
+
+    /// See [`Container::command`].
+    pub command: Option<Vec<String>>,
+
+    /// See [`Container::env`].
+    pub env: Option<Vec<crate::api::core::v1::EnvVarApplyConfiguration>>,
+
+    /// See [`Container::env_from`].
+    pub env_from: Option<Vec<crate::api::core::v1::EnvFromSourceApplyConfiguration>>,
+
+    /// See [`Container::image`].
+    pub image: Option<String>,
+
+    /// See [`Container::image_pull_policy`].
+    pub image_pull_policy: Option<crate::api::core::v1::PullPolicy>,
+
+    /// See [`Container::lifecycle`].
+    pub lifecycle: Option<crate::api::core::v1::LifecycleApplyConfiguration>,
+
+    /// See [`Container::liveness_probe`].
+    pub liveness_probe: Option<crate::api::core::v1::ProbeApplyConfiguration>,
+
+    /// See [`Container::name`].
+    pub name: Option<String>,
+
+    /// See [`Container::ports`].
+    pub ports: Option<Vec<crate::api::core::v1::ContainerPortApplyConfiguration>>,
+
+    /// See [`Container::readiness_probe`].
+    pub readiness_probe: Option<crate::api::core::v1::ProbeApplyConfiguration>,
+
+    /// See [`Container::resources`].
+    pub resources: Option<crate::api::core::v1::ResourceRequirementsApplyConfiguration>,
+
+    /// See [`Container::security_context`].
+    pub security_context: Option<crate::api::core::v1::SecurityContextApplyConfiguration>,
+
+    /// See [`Container::stdin`].
+    pub stdin: Option<bool>,
+
+    /// See [`Container::stdin_once`].
+    pub stdin_once: Option<bool>,
+
+    /// See [`Container::termination_message_path`].
+    pub termination_message_path: Option<String>,
+
+    /// See [`Container::termination_message_policy`].
+    pub termination_message_policy: Option<crate::api::core::v1::TerminationMessagePolicy>,
+
+    /// See [`Container::tty`].
+    pub tty: Option<bool>,
+
+    /// See [`Container::volume_devices`].
+    pub volume_devices: Option<Vec<crate::api::core::v1::VolumeDeviceApplyConfiguration>>,
+
+    /// See [`Container::volume_mounts`].
+    pub volume_mounts: Option<Vec<crate::api::core::v1::VolumeMountApplyConfiguration>>,
+
+    /// See [`Container::working_dir`].
+    pub working_dir: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ContainerApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ContainerApplyConfiguration",
+            self.args.as_ref().map_or(0, |_| 1) +
+            self.command.as_ref().map_or(0, |_| 1) +
+            self.env.as_ref().map_or(0, |_| 1) +
+            self.env_from.as_ref().map_or(0, |_| 1) +
+            self.image.as_ref().map_or(0, |_| 1) +
+            self.image_pull_policy.as_ref().map_or(0, |_| 1) +
+            self.lifecycle.as_ref().map_or(0, |_| 1) +
+            self.liveness_probe.as_ref().map_or(0, |_| 1) +
+            self.name.as_ref().map_or(0, |_| 1) +
+            self.ports.as_ref().map_or(0, |_| 1) +
+            self.readiness_probe.as_ref().map_or(0, |_| 1) +
+            self.resources.as_ref().map_or(0, |_| 1) +
+            self.security_context.as_ref().map_or(0, |_| 1) +
+            self.stdin.as_ref().map_or(0, |_| 1) +
+            self.stdin_once.as_ref().map_or(0, |_| 1) +
+            self.termination_message_path.as_ref().map_or(0, |_| 1) +
+            self.termination_message_policy.as_ref().map_or(0, |_| 1) +
+            self.tty.as_ref().map_or(0, |_| 1) +
+            self.volume_devices.as_ref().map_or(0, |_| 1) +
+            self.volume_mounts.as_ref().map_or(0, |_| 1) +
+            self.working_dir.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.args {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "args", value)?;
+        }
+        if let Some(value) = &self.command {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "command", value)?;
+        }
+        if let Some(value) = &self.env {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "env", value)?;
+        }
+        if let Some(value) = &self.env_from {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "envFrom", value)?;
+        }
+        if let Some(value) = &self.image {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "image", value)?;
+        }
+        if let Some(value) = &self.image_pull_policy {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "imagePullPolicy", value)?;
+        }
+        if let Some(value) = &self.lifecycle {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "lifecycle", value)?;
+        }
+        if let Some(value) = &self.liveness_probe {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "livenessProbe", value)?;
+        }
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        if let Some(value) = &self.ports {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ports", value)?;
+        }
+        if let Some(value) = &self.readiness_probe {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readinessProbe", value)?;
+        }
+        if let Some(value) = &self.resources {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resources", value)?;
+        }
+        if let Some(value) = &self.security_context {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "securityContext", value)?;
+        }
+        if let Some(value) = &self.stdin {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "stdin", value)?;
+        }
+        if let Some(value) = &self.stdin_once {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "stdinOnce", value)?;
+        }
+        if let Some(value) = &self.termination_message_path {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "terminationMessagePath", value)?;
+        }
+        if let Some(value) = &self.termination_message_policy {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "terminationMessagePolicy", value)?;
+        }
+        if let Some(value) = &self.tty {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tty", value)?;
+        }
+        if let Some(value) = &self.volume_devices {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeDevices", value)?;
+        }
+        if let Some(value) = &self.volume_mounts {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeMounts", value)?;
+        }
+        if let Some(value) = &self.working_dir {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "workingDir", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Container {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.Container".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::Container".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.Container".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -358,8 +1051,9 @@ impl crate::schemars::JsonSchema for Container {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "args".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -378,8 +1072,8 @@ impl crate::schemars::JsonSchema for Container {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "command".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -398,8 +1092,8 @@ impl crate::schemars::JsonSchema for Container {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "env".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -413,8 +1107,8 @@ impl crate::schemars::JsonSchema for Container {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "envFrom".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -428,8 +1122,8 @@ impl crate::schemars::JsonSchema for Container {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "image".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -439,19 +1133,19 @@ impl crate::schemars::JsonSchema for Container {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "imagePullPolicy".to_owned(),
-                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
-                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::PullPolicy>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
                                 description: Some("Image pull policy. One of Always, Never, IfNotPresent. Defaults to Always if :latest tag is specified, or IfNotPresent otherwise. Cannot be updated. More info: https://kubernetes.io/docs/concepts/containers/images#updating-images".to_owned()),
                                 ..Default::default()
-                            })),
-                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
-                            ..Default::default()
-                        }),
-                    ),
-                    (
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
                         "lifecycle".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::Lifecycle>().into_object();
@@ -461,8 +1155,8 @@ impl crate::schemars::JsonSchema for Container {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "livenessProbe".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::Probe>().into_object();
@@ -472,8 +1166,8 @@ impl crate::schemars::JsonSchema for Container {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -483,8 +1177,8 @@ impl crate::schemars::JsonSchema for Container {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "ports".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -498,8 +1192,8 @@ impl crate::schemars::JsonSchema for Container {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "readinessProbe".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::Probe>().into_object();
@@ -509,8 +1203,8 @@ impl crate::schemars::JsonSchema for Container {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "resources".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ResourceRequirements>().into_object();
@@ -520,8 +1214,8 @@ impl crate::schemars::JsonSchema for Container {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "securityContext".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::SecurityContext>().into_object();
@@ -531,8 +1225,8 @@ impl crate::schemars::JsonSchema for Container {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "stdin".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -542,8 +1236,8 @@ impl crate::schemars::JsonSchema for Container {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "stdinOnce".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -553,8 +1247,8 @@ impl crate::schemars::JsonSchema for Container {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "terminationMessagePath".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -564,19 +1258,19 @@ impl crate::schemars::JsonSchema for Container {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "terminationMessagePolicy".to_owned(),
-                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
-                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::TerminationMessagePolicy>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
                                 description: Some("Indicate how the termination message should be populated. File will use the contents of terminationMessagePath to populate the container status message on both success and failure. FallbackToLogsOnError will use the last chunk of container log output if the termination message file is empty and the container exited with an error. The log output is limited to 2048 bytes or 80 lines, whichever is smaller. Defaults to File. Cannot be updated.".to_owned()),
                                 ..Default::default()
-                            })),
-                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
-                            ..Default::default()
-                        }),
-                    ),
-                    (
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
                         "tty".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -586,8 +1280,8 @@ impl crate::schemars::JsonSchema for Container {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "volumeDevices".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -601,8 +1295,8 @@ impl crate::schemars::JsonSchema for Container {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "volumeMounts".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -616,8 +1310,8 @@ impl crate::schemars::JsonSchema for Container {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "workingDir".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -627,8 +1321,9 @@ impl crate::schemars::JsonSchema for Container {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "name",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -638,3 +1333,32 @@ impl crate::schemars::JsonSchema for Container {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for Container {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(Container {
+            args: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            command: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            env: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            env_from: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            image: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            image_pull_policy: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            lifecycle: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            liveness_probe: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            ports: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            readiness_probe: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            resources: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            security_context: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            stdin: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            stdin_once: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            termination_message_path: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            termination_message_policy: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            tty: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            volume_devices: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            volume_mounts: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            working_dir: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
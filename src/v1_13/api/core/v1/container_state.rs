@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.ContainerState
 
 /// ContainerState holds a possible state of container. Only one of its members may be specified. If none of them is specified, the default one is ContainerStateWaiting.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ContainerState {
     /// Details about a running container
     pub running: Option<crate::api::core::v1::ContainerStateRunning>,
@@ -13,41 +13,41 @@ pub struct ContainerState {
     pub waiting: Option<crate::api::core::v1::ContainerStateWaiting>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ContainerState {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_running,
-            Key_terminated,
-            Key_waiting,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl ContainerState {
+    /// Returns this value with [`ContainerState::running`] set to the given value.
+    #[must_use]
+    pub fn with_running(mut self, value: impl Into<crate::api::core::v1::ContainerStateRunning>) -> Self {
+        self.running = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ContainerState::terminated`] set to the given value.
+    #[must_use]
+    pub fn with_terminated(mut self, value: impl Into<crate::api::core::v1::ContainerStateTerminated>) -> Self {
+        self.terminated = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ContainerState::waiting`] set to the given value.
+    #[must_use]
+    pub fn with_waiting(mut self, value: impl Into<crate::api::core::v1::ContainerStateWaiting>) -> Self {
+        self.waiting = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "running" => Field::Key_running,
-                            "terminated" => Field::Key_terminated,
-                            "waiting" => Field::Key_waiting,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for ContainerState {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl ContainerState {
+    const FIELDS: &'static [&'static str] = &[
+        "running",
+        "terminated",
+        "waiting",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ContainerState {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,12 +62,12 @@ impl<'de> crate::serde::Deserialize<'de> for ContainerState {
                 let mut value_terminated: Option<crate::api::core::v1::ContainerStateTerminated> = None;
                 let mut value_waiting: Option<crate::api::core::v1::ContainerStateWaiting> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ContainerState", ContainerState::FIELDS))? {
                     match key {
-                        Field::Key_running => value_running = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_terminated => value_terminated = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_waiting => value_waiting = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_running = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_terminated = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_waiting = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -77,22 +77,86 @@ impl<'de> crate::serde::Deserialize<'de> for ContainerState {
                     waiting: value_waiting,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ContainerState {
+                    running: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    terminated: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    waiting: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "ContainerState",
-            &[
-                "running",
-                "terminated",
-                "waiting",
-            ],
+            ContainerState::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ContainerState> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ContainerState")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_running = false;
+                let mut has_terminated = false;
+                let mut has_waiting = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ContainerState", ContainerState::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.running))?; has_running = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.terminated))?; has_terminated = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.waiting))?; has_waiting = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_running {
+                    self.0.running = None;
+                }
+                if !has_terminated {
+                    self.0.terminated = None;
+                }
+                if !has_waiting {
+                    self.0.waiting = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.running))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.terminated))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.waiting))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ContainerState",
+            ContainerState::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ContainerState {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ContainerState", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "running", &self.running)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "terminated", &self.terminated)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "waiting", &self.waiting)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ContainerState",
             self.running.as_ref().map_or(0, |_| 1) +
@@ -112,10 +176,99 @@ impl crate::serde::Serialize for ContainerState {
     }
 }
 
+/// A builder of [`ContainerState`]. Created with [`ContainerState::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ContainerStateBuilder {
+    running: Option<crate::api::core::v1::ContainerStateRunning>,
+    terminated: Option<crate::api::core::v1::ContainerStateTerminated>,
+    waiting: Option<crate::api::core::v1::ContainerStateWaiting>,
+}
+
+#[cfg(feature = "builder")]
+impl ContainerState {
+    /// Starts building a `ContainerState`. Its required fields must be set before [`ContainerStateBuilder::build`] is called.
+    pub fn builder() -> ContainerStateBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ContainerStateBuilder {
+    /// Sets [`ContainerState::running`].
+    pub fn running(mut self, value: impl Into<crate::api::core::v1::ContainerStateRunning>) -> Self {
+        self.running = Some(value.into());
+        self
+    }
+
+    /// Sets [`ContainerState::terminated`].
+    pub fn terminated(mut self, value: impl Into<crate::api::core::v1::ContainerStateTerminated>) -> Self {
+        self.terminated = Some(value.into());
+        self
+    }
+
+    /// Sets [`ContainerState::waiting`].
+    pub fn waiting(mut self, value: impl Into<crate::api::core::v1::ContainerStateWaiting>) -> Self {
+        self.waiting = Some(value.into());
+        self
+    }
+
+    /// Builds the `ContainerState`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ContainerState, crate::MissingFieldError> {
+        Ok(ContainerState {
+            running: self.running,
+            terminated: self.terminated,
+            waiting: self.waiting,
+        })
+    }
+}
+
+/// The apply configuration of [`ContainerState`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ContainerStateApplyConfiguration {
+    /// See [`ContainerState::running`].
+    pub running: Option<crate::api::core::v1::ContainerStateRunningApplyConfiguration>,
+
+    /// See [`ContainerState::terminated`].
+    pub terminated: Option<crate::api::core::v1::ContainerStateTerminatedApplyConfiguration>,
+
+    /// See [`ContainerState::waiting`].
+    pub waiting: Option<crate::api::core::v1::ContainerStateWaitingApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ContainerStateApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ContainerStateApplyConfiguration",
+            self.running.as_ref().map_or(0, |_| 1) +
+            self.terminated.as_ref().map_or(0, |_| 1) +
+            self.waiting.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.running {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "running", value)?;
+        }
+        if let Some(value) = &self.terminated {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "terminated", value)?;
+        }
+        if let Some(value) = &self.waiting {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "waiting", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ContainerState {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.ContainerState".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::ContainerState".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.ContainerState".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -126,8 +279,9 @@ impl crate::schemars::JsonSchema for ContainerState {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "running".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ContainerStateRunning>().into_object();
@@ -137,8 +291,8 @@ impl crate::schemars::JsonSchema for ContainerState {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "terminated".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ContainerStateTerminated>().into_object();
@@ -148,8 +302,8 @@ impl crate::schemars::JsonSchema for ContainerState {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "waiting".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ContainerStateWaiting>().into_object();
@@ -159,11 +313,23 @@ impl crate::schemars::JsonSchema for ContainerState {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ContainerState {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ContainerState {
+            running: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            terminated: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            waiting: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
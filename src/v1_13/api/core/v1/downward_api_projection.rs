@@ -1,80 +1,118 @@
 // Generated from definition io.k8s.api.core.v1.DownwardAPIProjection
 
 /// Represents downward API info for projecting into a projected volume. Note that this is identical to a downwardAPI volume source without the default mode.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct DownwardAPIProjection {
     /// Items is a list of DownwardAPIVolume file
     pub items: Option<Vec<crate::api::core::v1::DownwardAPIVolumeFile>>,
 }
 
+impl DownwardAPIProjection {
+    /// Returns this value with [`DownwardAPIProjection::items`] set to the given value.
+    #[must_use]
+    pub fn with_items(mut self, value: impl Into<Vec<crate::api::core::v1::DownwardAPIVolumeFile>>) -> Self {
+        self.items = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for DownwardAPIProjection {}
+
+impl DownwardAPIProjection {
+    const FIELDS: &'static [&'static str] = &[
+        "items",
+    ];
+}
+
 impl<'de> crate::serde::Deserialize<'de> for DownwardAPIProjection {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_items,
-            Other,
-        }
+        struct Visitor;
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = DownwardAPIProjection;
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("DownwardAPIProjection")
+            }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_items: Option<Vec<crate::api::core::v1::DownwardAPIVolumeFile>> = None;
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "items" => Field::Key_items,
-                            _ => Field::Other,
-                        })
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("DownwardAPIProjection", DownwardAPIProjection::FIELDS))? {
+                    match key {
+                        Some(0) => value_items = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
-                deserializer.deserialize_identifier(Visitor)
+                Ok(DownwardAPIProjection {
+                    items: value_items,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(DownwardAPIProjection {
+                    items: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                })
             }
         }
 
-        struct Visitor;
+        deserializer.deserialize_struct(
+            "DownwardAPIProjection",
+            DownwardAPIProjection::FIELDS,
+            Visitor,
+        )
+    }
 
-        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-            type Value = DownwardAPIProjection;
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, DownwardAPIProjection> {
+            type Value = ();
 
             fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 f.write_str("DownwardAPIProjection")
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
-                let mut value_items: Option<Vec<crate::api::core::v1::DownwardAPIVolumeFile>> = None;
+                let mut has_items = false;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("DownwardAPIProjection", DownwardAPIProjection::FIELDS))? {
                     match key {
-                        Field::Key_items => value_items = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.items))?; has_items = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
+                if !has_items {
+                    self.0.items = None;
+                }
 
-                Ok(DownwardAPIProjection {
-                    items: value_items,
-                })
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.items))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
             }
         }
 
         deserializer.deserialize_struct(
             "DownwardAPIProjection",
-            &[
-                "items",
-            ],
-            Visitor,
+            DownwardAPIProjection::FIELDS,
+            InPlaceVisitor(place),
         )
     }
 }
 
 impl crate::serde::Serialize for DownwardAPIProjection {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("DownwardAPIProjection", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "items", &self.items)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "DownwardAPIProjection",
             self.items.as_ref().map_or(0, |_| 1),
@@ -86,10 +124,69 @@ impl crate::serde::Serialize for DownwardAPIProjection {
     }
 }
 
+/// A builder of [`DownwardAPIProjection`]. Created with [`DownwardAPIProjection::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct DownwardAPIProjectionBuilder {
+    items: Option<Vec<crate::api::core::v1::DownwardAPIVolumeFile>>,
+}
+
+#[cfg(feature = "builder")]
+impl DownwardAPIProjection {
+    /// Starts building a `DownwardAPIProjection`. Its required fields must be set before [`DownwardAPIProjectionBuilder::build`] is called.
+    pub fn builder() -> DownwardAPIProjectionBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl DownwardAPIProjectionBuilder {
+    /// Sets [`DownwardAPIProjection::items`].
+    pub fn items(mut self, value: impl Into<Vec<crate::api::core::v1::DownwardAPIVolumeFile>>) -> Self {
+        self.items = Some(value.into());
+        self
+    }
+
+    /// Builds the `DownwardAPIProjection`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<DownwardAPIProjection, crate::MissingFieldError> {
+        Ok(DownwardAPIProjection {
+            items: self.items,
+        })
+    }
+}
+
+/// The apply configuration of [`DownwardAPIProjection`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DownwardAPIProjectionApplyConfiguration {
+    /// See [`DownwardAPIProjection::items`].
+    pub items: Option<Vec<crate::api::core::v1::DownwardAPIVolumeFileApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for DownwardAPIProjectionApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "DownwardAPIProjectionApplyConfiguration",
+            self.items.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.items {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "items", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DownwardAPIProjection {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.DownwardAPIProjection".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::DownwardAPIProjection".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.DownwardAPIProjection".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -100,8 +197,9 @@ impl crate::schemars::JsonSchema for DownwardAPIProjection {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "items".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -115,11 +213,21 @@ impl crate::schemars::JsonSchema for DownwardAPIProjection {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for DownwardAPIProjection {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(DownwardAPIProjection {
+            items: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.DownwardAPIVolumeFile
 
 /// DownwardAPIVolumeFile represents information to create the file containing the pod field
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct DownwardAPIVolumeFile {
     /// Required: Selects a field of the pod: only annotations, labels, name and namespace are supported.
     pub field_ref: Option<crate::api::core::v1::ObjectFieldSelector>,
@@ -16,43 +16,61 @@ pub struct DownwardAPIVolumeFile {
     pub resource_field_ref: Option<crate::api::core::v1::ResourceFieldSelector>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for DownwardAPIVolumeFile {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_field_ref,
-            Key_mode,
-            Key_path,
-            Key_resource_field_ref,
-            Other,
+impl DownwardAPIVolumeFile {
+    /// Constructs a `DownwardAPIVolumeFile` from its required fields. Its optional fields are unset.
+    pub fn new(path: impl Into<String>) -> Self {
+        DownwardAPIVolumeFile {
+            field_ref: None,
+            mode: None,
+            path: path.into(),
+            resource_field_ref: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl DownwardAPIVolumeFile {
+    /// Returns this value with [`DownwardAPIVolumeFile::field_ref`] set to the given value.
+    #[must_use]
+    pub fn with_field_ref(mut self, value: impl Into<crate::api::core::v1::ObjectFieldSelector>) -> Self {
+        self.field_ref = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`DownwardAPIVolumeFile::mode`] set to the given value.
+    #[must_use]
+    pub fn with_mode(mut self, value: impl Into<i32>) -> Self {
+        self.mode = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`DownwardAPIVolumeFile::path`] set to the given value.
+    #[must_use]
+    pub fn with_path(mut self, value: impl Into<String>) -> Self {
+        self.path = value.into();
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "fieldRef" => Field::Key_field_ref,
-                            "mode" => Field::Key_mode,
-                            "path" => Field::Key_path,
-                            "resourceFieldRef" => Field::Key_resource_field_ref,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`DownwardAPIVolumeFile::resource_field_ref`] set to the given value.
+    #[must_use]
+    pub fn with_resource_field_ref(mut self, value: impl Into<crate::api::core::v1::ResourceFieldSelector>) -> Self {
+        self.resource_field_ref = Some(value.into());
+        self
+    }
+}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl crate::MergeStrategyInfo for DownwardAPIVolumeFile {}
+
+impl DownwardAPIVolumeFile {
+    const FIELDS: &'static [&'static str] = &[
+        "fieldRef",
+        "mode",
+        "path",
+        "resourceFieldRef",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for DownwardAPIVolumeFile {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -68,40 +86,111 @@ impl<'de> crate::serde::Deserialize<'de> for DownwardAPIVolumeFile {
                 let mut value_path: Option<String> = None;
                 let mut value_resource_field_ref: Option<crate::api::core::v1::ResourceFieldSelector> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("DownwardAPIVolumeFile", DownwardAPIVolumeFile::FIELDS))? {
                     match key {
-                        Field::Key_field_ref => value_field_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_mode => value_mode = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_path => value_path = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_resource_field_ref => value_resource_field_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_field_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_mode = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_path = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_resource_field_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(DownwardAPIVolumeFile {
                     field_ref: value_field_ref,
                     mode: value_mode,
-                    path: value_path.ok_or_else(|| crate::serde::de::Error::missing_field("path"))?,
+                    path: value_path.ok_or_else(|| crate::serde2::missing_field("DownwardAPIVolumeFile", "path", &["path"]))?,
                     resource_field_ref: value_resource_field_ref,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(DownwardAPIVolumeFile {
+                    field_ref: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    mode: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    path: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    resource_field_ref: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "DownwardAPIVolumeFile",
-            &[
-                "fieldRef",
-                "mode",
-                "path",
-                "resourceFieldRef",
-            ],
+            DownwardAPIVolumeFile::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, DownwardAPIVolumeFile> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("DownwardAPIVolumeFile")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_field_ref = false;
+                let mut has_mode = false;
+                let mut has_path = false;
+                let mut has_resource_field_ref = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("DownwardAPIVolumeFile", DownwardAPIVolumeFile::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.field_ref))?; has_field_ref = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.mode))?; has_mode = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.path))?; has_path = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.resource_field_ref))?; has_resource_field_ref = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_field_ref {
+                    self.0.field_ref = None;
+                }
+                if !has_mode {
+                    self.0.mode = None;
+                }
+                if !has_path {
+                    return Err(crate::serde2::missing_field("DownwardAPIVolumeFile", "path", &["path"]));
+                }
+                if !has_resource_field_ref {
+                    self.0.resource_field_ref = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.field_ref))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.mode))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.path))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.resource_field_ref))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "DownwardAPIVolumeFile",
+            DownwardAPIVolumeFile::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for DownwardAPIVolumeFile {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("DownwardAPIVolumeFile", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fieldRef", &self.field_ref)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "mode", &self.mode)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "path", &self.path)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resourceFieldRef", &self.resource_field_ref)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "DownwardAPIVolumeFile",
             1 +
@@ -123,10 +212,114 @@ impl crate::serde::Serialize for DownwardAPIVolumeFile {
     }
 }
 
+/// A builder of [`DownwardAPIVolumeFile`]. Created with [`DownwardAPIVolumeFile::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct DownwardAPIVolumeFileBuilder {
+    field_ref: Option<crate::api::core::v1::ObjectFieldSelector>,
+    mode: Option<i32>,
+    path: Option<String>,
+    resource_field_ref: Option<crate::api::core::v1::ResourceFieldSelector>,
+}
+
+#[cfg(feature = "builder")]
+impl DownwardAPIVolumeFile {
+    /// Starts building a `DownwardAPIVolumeFile`. Its required fields must be set before [`DownwardAPIVolumeFileBuilder::build`] is called.
+    pub fn builder() -> DownwardAPIVolumeFileBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl DownwardAPIVolumeFileBuilder {
+    /// Sets [`DownwardAPIVolumeFile::field_ref`].
+    pub fn field_ref(mut self, value: impl Into<crate::api::core::v1::ObjectFieldSelector>) -> Self {
+        self.field_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`DownwardAPIVolumeFile::mode`].
+    pub fn mode(mut self, value: impl Into<i32>) -> Self {
+        self.mode = Some(value.into());
+        self
+    }
+
+    /// Sets [`DownwardAPIVolumeFile::path`].
+    pub fn path(mut self, value: impl Into<String>) -> Self {
+        self.path = Some(value.into());
+        self
+    }
+
+    /// Sets [`DownwardAPIVolumeFile::resource_field_ref`].
+    pub fn resource_field_ref(mut self, value: impl Into<crate::api::core::v1::ResourceFieldSelector>) -> Self {
+        self.resource_field_ref = Some(value.into());
+        self
+    }
+
+    /// Builds the `DownwardAPIVolumeFile`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<DownwardAPIVolumeFile, crate::MissingFieldError> {
+        Ok(DownwardAPIVolumeFile {
+            field_ref: self.field_ref,
+            mode: self.mode,
+            path: self.path.ok_or_else(|| crate::MissingFieldError::new("DownwardAPIVolumeFile", "path"))?,
+            resource_field_ref: self.resource_field_ref,
+        })
+    }
+}
+
+/// The apply configuration of [`DownwardAPIVolumeFile`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DownwardAPIVolumeFileApplyConfiguration {
+    /// See [`DownwardAPIVolumeFile::field_ref`].
+    pub field_ref: Option<crate::api::core::v1::ObjectFieldSelectorApplyConfiguration>,
+
+    /// See [`DownwardAPIVolumeFile::mode`].
+    pub mode: Option<i32>,
+
+    /// See [`DownwardAPIVolumeFile::path`].
+    pub path: Option<String>,
+
+    /// See [`DownwardAPIVolumeFile::resource_field_ref`].
+    pub resource_field_ref: Option<crate::api::core::v1::ResourceFieldSelectorApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for DownwardAPIVolumeFileApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "DownwardAPIVolumeFileApplyConfiguration",
+            self.field_ref.as_ref().map_or(0, |_| 1) +
+            self.mode.as_ref().map_or(0, |_| 1) +
+            self.path.as_ref().map_or(0, |_| 1) +
+            self.resource_field_ref.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.field_ref {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fieldRef", value)?;
+        }
+        if let Some(value) = &self.mode {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "mode", value)?;
+        }
+        if let Some(value) = &self.path {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "path", value)?;
+        }
+        if let Some(value) = &self.resource_field_ref {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resourceFieldRef", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DownwardAPIVolumeFile {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.DownwardAPIVolumeFile".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::DownwardAPIVolumeFile".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.DownwardAPIVolumeFile".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -137,8 +330,9 @@ impl crate::schemars::JsonSchema for DownwardAPIVolumeFile {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "fieldRef".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ObjectFieldSelector>().into_object();
@@ -148,8 +342,8 @@ impl crate::schemars::JsonSchema for DownwardAPIVolumeFile {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "mode".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -160,8 +354,8 @@ impl crate::schemars::JsonSchema for DownwardAPIVolumeFile {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "path".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -171,8 +365,8 @@ impl crate::schemars::JsonSchema for DownwardAPIVolumeFile {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "resourceFieldRef".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ResourceFieldSelector>().into_object();
@@ -182,8 +376,9 @@ impl crate::schemars::JsonSchema for DownwardAPIVolumeFile {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "path",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -193,3 +388,15 @@ impl crate::schemars::JsonSchema for DownwardAPIVolumeFile {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for DownwardAPIVolumeFile {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(DownwardAPIVolumeFile {
+            field_ref: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            mode: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            path: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            resource_field_ref: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
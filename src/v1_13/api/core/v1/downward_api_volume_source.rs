@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.DownwardAPIVolumeSource
 
 /// DownwardAPIVolumeSource represents a volume containing downward API info. Downward API volumes support ownership management and SELinux relabeling.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct DownwardAPIVolumeSource {
     /// Optional: mode bits to use on created files by default. Must be a value between 0 and 0777. Defaults to 0644. Directories within the path are not affected by this setting. This might be in conflict with other options that affect the file mode, like fsGroup, and the result can be other mode bits set.
     pub default_mode: Option<i32>,
@@ -10,39 +10,33 @@ pub struct DownwardAPIVolumeSource {
     pub items: Option<Vec<crate::api::core::v1::DownwardAPIVolumeFile>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for DownwardAPIVolumeSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_default_mode,
-            Key_items,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl DownwardAPIVolumeSource {
+    /// Returns this value with [`DownwardAPIVolumeSource::default_mode`] set to the given value.
+    #[must_use]
+    pub fn with_default_mode(mut self, value: impl Into<i32>) -> Self {
+        self.default_mode = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`DownwardAPIVolumeSource::items`] set to the given value.
+    #[must_use]
+    pub fn with_items(mut self, value: impl Into<Vec<crate::api::core::v1::DownwardAPIVolumeFile>>) -> Self {
+        self.items = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "defaultMode" => Field::Key_default_mode,
-                            "items" => Field::Key_items,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for DownwardAPIVolumeSource {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl DownwardAPIVolumeSource {
+    const FIELDS: &'static [&'static str] = &[
+        "defaultMode",
+        "items",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for DownwardAPIVolumeSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,11 +50,11 @@ impl<'de> crate::serde::Deserialize<'de> for DownwardAPIVolumeSource {
                 let mut value_default_mode: Option<i32> = None;
                 let mut value_items: Option<Vec<crate::api::core::v1::DownwardAPIVolumeFile>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("DownwardAPIVolumeSource", DownwardAPIVolumeSource::FIELDS))? {
                     match key {
-                        Field::Key_default_mode => value_default_mode = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_items => value_items = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_default_mode = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_items = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -69,21 +63,78 @@ impl<'de> crate::serde::Deserialize<'de> for DownwardAPIVolumeSource {
                     items: value_items,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(DownwardAPIVolumeSource {
+                    default_mode: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    items: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "DownwardAPIVolumeSource",
-            &[
-                "defaultMode",
-                "items",
-            ],
+            DownwardAPIVolumeSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, DownwardAPIVolumeSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("DownwardAPIVolumeSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_default_mode = false;
+                let mut has_items = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("DownwardAPIVolumeSource", DownwardAPIVolumeSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.default_mode))?; has_default_mode = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.items))?; has_items = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_default_mode {
+                    self.0.default_mode = None;
+                }
+                if !has_items {
+                    self.0.items = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.default_mode))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.items))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "DownwardAPIVolumeSource",
+            DownwardAPIVolumeSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for DownwardAPIVolumeSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("DownwardAPIVolumeSource", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "defaultMode", &self.default_mode)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "items", &self.items)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "DownwardAPIVolumeSource",
             self.default_mode.as_ref().map_or(0, |_| 1) +
@@ -99,10 +150,84 @@ impl crate::serde::Serialize for DownwardAPIVolumeSource {
     }
 }
 
+/// A builder of [`DownwardAPIVolumeSource`]. Created with [`DownwardAPIVolumeSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct DownwardAPIVolumeSourceBuilder {
+    default_mode: Option<i32>,
+    items: Option<Vec<crate::api::core::v1::DownwardAPIVolumeFile>>,
+}
+
+#[cfg(feature = "builder")]
+impl DownwardAPIVolumeSource {
+    /// Starts building a `DownwardAPIVolumeSource`. Its required fields must be set before [`DownwardAPIVolumeSourceBuilder::build`] is called.
+    pub fn builder() -> DownwardAPIVolumeSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl DownwardAPIVolumeSourceBuilder {
+    /// Sets [`DownwardAPIVolumeSource::default_mode`].
+    pub fn default_mode(mut self, value: impl Into<i32>) -> Self {
+        self.default_mode = Some(value.into());
+        self
+    }
+
+    /// Sets [`DownwardAPIVolumeSource::items`].
+    pub fn items(mut self, value: impl Into<Vec<crate::api::core::v1::DownwardAPIVolumeFile>>) -> Self {
+        self.items = Some(value.into());
+        self
+    }
+
+    /// Builds the `DownwardAPIVolumeSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<DownwardAPIVolumeSource, crate::MissingFieldError> {
+        Ok(DownwardAPIVolumeSource {
+            default_mode: self.default_mode,
+            items: self.items,
+        })
+    }
+}
+
+/// The apply configuration of [`DownwardAPIVolumeSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DownwardAPIVolumeSourceApplyConfiguration {
+    /// See [`DownwardAPIVolumeSource::default_mode`].
+    pub default_mode: Option<i32>,
+
+    /// See [`DownwardAPIVolumeSource::items`].
+    pub items: Option<Vec<crate::api::core::v1::DownwardAPIVolumeFileApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for DownwardAPIVolumeSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "DownwardAPIVolumeSourceApplyConfiguration",
+            self.default_mode.as_ref().map_or(0, |_| 1) +
+            self.items.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.default_mode {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "defaultMode", value)?;
+        }
+        if let Some(value) = &self.items {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "items", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DownwardAPIVolumeSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.DownwardAPIVolumeSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::DownwardAPIVolumeSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.DownwardAPIVolumeSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -113,8 +238,9 @@ impl crate::schemars::JsonSchema for DownwardAPIVolumeSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "defaultMode".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -125,8 +251,8 @@ impl crate::schemars::JsonSchema for DownwardAPIVolumeSource {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "items".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -140,11 +266,22 @@ impl crate::schemars::JsonSchema for DownwardAPIVolumeSource {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for DownwardAPIVolumeSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(DownwardAPIVolumeSource {
+            default_mode: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            items: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
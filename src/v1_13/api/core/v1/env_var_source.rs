@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.EnvVarSource
 
 /// EnvVarSource represents a source for the value of an EnvVar.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct EnvVarSource {
     /// Selects a key of a ConfigMap.
     pub config_map_key_ref: Option<crate::api::core::v1::ConfigMapKeySelector>,
@@ -16,43 +16,49 @@ pub struct EnvVarSource {
     pub secret_key_ref: Option<crate::api::core::v1::SecretKeySelector>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for EnvVarSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_config_map_key_ref,
-            Key_field_ref,
-            Key_resource_field_ref,
-            Key_secret_key_ref,
-            Other,
-        }
+impl EnvVarSource {
+    /// Returns this value with [`EnvVarSource::config_map_key_ref`] set to the given value.
+    #[must_use]
+    pub fn with_config_map_key_ref(mut self, value: impl Into<crate::api::core::v1::ConfigMapKeySelector>) -> Self {
+        self.config_map_key_ref = Some(value.into());
+        self
+    }
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+    /// Returns this value with [`EnvVarSource::field_ref`] set to the given value.
+    #[must_use]
+    pub fn with_field_ref(mut self, value: impl Into<crate::api::core::v1::ObjectFieldSelector>) -> Self {
+        self.field_ref = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`EnvVarSource::resource_field_ref`] set to the given value.
+    #[must_use]
+    pub fn with_resource_field_ref(mut self, value: impl Into<crate::api::core::v1::ResourceFieldSelector>) -> Self {
+        self.resource_field_ref = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`EnvVarSource::secret_key_ref`] set to the given value.
+    #[must_use]
+    pub fn with_secret_key_ref(mut self, value: impl Into<crate::api::core::v1::SecretKeySelector>) -> Self {
+        self.secret_key_ref = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "configMapKeyRef" => Field::Key_config_map_key_ref,
-                            "fieldRef" => Field::Key_field_ref,
-                            "resourceFieldRef" => Field::Key_resource_field_ref,
-                            "secretKeyRef" => Field::Key_secret_key_ref,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for EnvVarSource {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl EnvVarSource {
+    const FIELDS: &'static [&'static str] = &[
+        "configMapKeyRef",
+        "fieldRef",
+        "resourceFieldRef",
+        "secretKeyRef",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for EnvVarSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -68,13 +74,13 @@ impl<'de> crate::serde::Deserialize<'de> for EnvVarSource {
                 let mut value_resource_field_ref: Option<crate::api::core::v1::ResourceFieldSelector> = None;
                 let mut value_secret_key_ref: Option<crate::api::core::v1::SecretKeySelector> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("EnvVarSource", EnvVarSource::FIELDS))? {
                     match key {
-                        Field::Key_config_map_key_ref => value_config_map_key_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_field_ref => value_field_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_resource_field_ref => value_resource_field_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_secret_key_ref => value_secret_key_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_config_map_key_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_field_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_resource_field_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_secret_key_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -85,23 +91,94 @@ impl<'de> crate::serde::Deserialize<'de> for EnvVarSource {
                     secret_key_ref: value_secret_key_ref,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(EnvVarSource {
+                    config_map_key_ref: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    field_ref: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    resource_field_ref: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    secret_key_ref: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "EnvVarSource",
-            &[
-                "configMapKeyRef",
-                "fieldRef",
-                "resourceFieldRef",
-                "secretKeyRef",
-            ],
+            EnvVarSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, EnvVarSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("EnvVarSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_config_map_key_ref = false;
+                let mut has_field_ref = false;
+                let mut has_resource_field_ref = false;
+                let mut has_secret_key_ref = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("EnvVarSource", EnvVarSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.config_map_key_ref))?; has_config_map_key_ref = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.field_ref))?; has_field_ref = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.resource_field_ref))?; has_resource_field_ref = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.secret_key_ref))?; has_secret_key_ref = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_config_map_key_ref {
+                    self.0.config_map_key_ref = None;
+                }
+                if !has_field_ref {
+                    self.0.field_ref = None;
+                }
+                if !has_resource_field_ref {
+                    self.0.resource_field_ref = None;
+                }
+                if !has_secret_key_ref {
+                    self.0.secret_key_ref = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.config_map_key_ref))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.field_ref))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.resource_field_ref))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.secret_key_ref))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "EnvVarSource",
+            EnvVarSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for EnvVarSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("EnvVarSource", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "configMapKeyRef", &self.config_map_key_ref)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fieldRef", &self.field_ref)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resourceFieldRef", &self.resource_field_ref)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretKeyRef", &self.secret_key_ref)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "EnvVarSource",
             self.config_map_key_ref.as_ref().map_or(0, |_| 1) +
@@ -125,10 +202,114 @@ impl crate::serde::Serialize for EnvVarSource {
     }
 }
 
+/// A builder of [`EnvVarSource`]. Created with [`EnvVarSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct EnvVarSourceBuilder {
+    config_map_key_ref: Option<crate::api::core::v1::ConfigMapKeySelector>,
+    field_ref: Option<crate::api::core::v1::ObjectFieldSelector>,
+    resource_field_ref: Option<crate::api::core::v1::ResourceFieldSelector>,
+    secret_key_ref: Option<crate::api::core::v1::SecretKeySelector>,
+}
+
+#[cfg(feature = "builder")]
+impl EnvVarSource {
+    /// Starts building a `EnvVarSource`. Its required fields must be set before [`EnvVarSourceBuilder::build`] is called.
+    pub fn builder() -> EnvVarSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl EnvVarSourceBuilder {
+    /// Sets [`EnvVarSource::config_map_key_ref`].
+    pub fn config_map_key_ref(mut self, value: impl Into<crate::api::core::v1::ConfigMapKeySelector>) -> Self {
+        self.config_map_key_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`EnvVarSource::field_ref`].
+    pub fn field_ref(mut self, value: impl Into<crate::api::core::v1::ObjectFieldSelector>) -> Self {
+        self.field_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`EnvVarSource::resource_field_ref`].
+    pub fn resource_field_ref(mut self, value: impl Into<crate::api::core::v1::ResourceFieldSelector>) -> Self {
+        self.resource_field_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`EnvVarSource::secret_key_ref`].
+    pub fn secret_key_ref(mut self, value: impl Into<crate::api::core::v1::SecretKeySelector>) -> Self {
+        self.secret_key_ref = Some(value.into());
+        self
+    }
+
+    /// Builds the `EnvVarSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<EnvVarSource, crate::MissingFieldError> {
+        Ok(EnvVarSource {
+            config_map_key_ref: self.config_map_key_ref,
+            field_ref: self.field_ref,
+            resource_field_ref: self.resource_field_ref,
+            secret_key_ref: self.secret_key_ref,
+        })
+    }
+}
+
+/// The apply configuration of [`EnvVarSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EnvVarSourceApplyConfiguration {
+    /// See [`EnvVarSource::config_map_key_ref`].
+    pub config_map_key_ref: Option<crate::api::core::v1::ConfigMapKeySelectorApplyConfiguration>,
+
+    /// See [`EnvVarSource::field_ref`].
+    pub field_ref: Option<crate::api::core::v1::ObjectFieldSelectorApplyConfiguration>,
+
+    /// See [`EnvVarSource::resource_field_ref`].
+    pub resource_field_ref: Option<crate::api::core::v1::ResourceFieldSelectorApplyConfiguration>,
+
+    /// See [`EnvVarSource::secret_key_ref`].
+    pub secret_key_ref: Option<crate::api::core::v1::SecretKeySelectorApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for EnvVarSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "EnvVarSourceApplyConfiguration",
+            self.config_map_key_ref.as_ref().map_or(0, |_| 1) +
+            self.field_ref.as_ref().map_or(0, |_| 1) +
+            self.resource_field_ref.as_ref().map_or(0, |_| 1) +
+            self.secret_key_ref.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.config_map_key_ref {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "configMapKeyRef", value)?;
+        }
+        if let Some(value) = &self.field_ref {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fieldRef", value)?;
+        }
+        if let Some(value) = &self.resource_field_ref {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resourceFieldRef", value)?;
+        }
+        if let Some(value) = &self.secret_key_ref {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretKeyRef", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for EnvVarSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.EnvVarSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::EnvVarSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.EnvVarSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -139,8 +320,9 @@ impl crate::schemars::JsonSchema for EnvVarSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "configMapKeyRef".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ConfigMapKeySelector>().into_object();
@@ -150,8 +332,8 @@ impl crate::schemars::JsonSchema for EnvVarSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "fieldRef".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ObjectFieldSelector>().into_object();
@@ -161,8 +343,8 @@ impl crate::schemars::JsonSchema for EnvVarSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "resourceFieldRef".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ResourceFieldSelector>().into_object();
@@ -172,8 +354,8 @@ impl crate::schemars::JsonSchema for EnvVarSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "secretKeyRef".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::SecretKeySelector>().into_object();
@@ -183,11 +365,24 @@ impl crate::schemars::JsonSchema for EnvVarSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for EnvVarSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(EnvVarSource {
+            config_map_key_ref: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            field_ref: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            resource_field_ref: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            secret_key_ref: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
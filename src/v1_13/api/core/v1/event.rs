@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.Event
 
 /// Event is a report of an event somewhere in the cluster.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct Event {
     /// What action was taken/failed regarding to the Regarding object.
     pub action: Option<String>,
@@ -49,6 +49,136 @@ pub struct Event {
     pub type_: Option<String>,
 }
 
+impl Event {
+    /// Constructs a `Event` from its required fields. Its optional fields are unset.
+    pub fn new(involved_object: impl Into<crate::api::core::v1::ObjectReference>, metadata: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        Event {
+            action: None,
+            count: None,
+            event_time: None,
+            first_timestamp: None,
+            involved_object: involved_object.into(),
+            last_timestamp: None,
+            message: None,
+            metadata: metadata.into(),
+            reason: None,
+            related: None,
+            reporting_component: None,
+            reporting_instance: None,
+            series: None,
+            source: None,
+            type_: None,
+        }
+    }
+}
+
+impl Event {
+    /// Returns this value with [`Event::action`] set to the given value.
+    #[must_use]
+    pub fn with_action(mut self, value: impl Into<String>) -> Self {
+        self.action = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Event::count`] set to the given value.
+    #[must_use]
+    pub fn with_count(mut self, value: impl Into<i32>) -> Self {
+        self.count = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Event::event_time`] set to the given value.
+    #[must_use]
+    pub fn with_event_time(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::MicroTime>) -> Self {
+        self.event_time = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Event::first_timestamp`] set to the given value.
+    #[must_use]
+    pub fn with_first_timestamp(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.first_timestamp = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Event::involved_object`] set to the given value.
+    #[must_use]
+    pub fn with_involved_object(mut self, value: impl Into<crate::api::core::v1::ObjectReference>) -> Self {
+        self.involved_object = value.into();
+        self
+    }
+
+    /// Returns this value with [`Event::last_timestamp`] set to the given value.
+    #[must_use]
+    pub fn with_last_timestamp(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.last_timestamp = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Event::message`] set to the given value.
+    #[must_use]
+    pub fn with_message(mut self, value: impl Into<String>) -> Self {
+        self.message = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Event::metadata`] set to the given value.
+    #[must_use]
+    pub fn with_metadata(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        self.metadata = value.into();
+        self
+    }
+
+    /// Returns this value with [`Event::reason`] set to the given value.
+    #[must_use]
+    pub fn with_reason(mut self, value: impl Into<String>) -> Self {
+        self.reason = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Event::related`] set to the given value.
+    #[must_use]
+    pub fn with_related(mut self, value: impl Into<crate::api::core::v1::ObjectReference>) -> Self {
+        self.related = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Event::reporting_component`] set to the given value.
+    #[must_use]
+    pub fn with_reporting_component(mut self, value: impl Into<String>) -> Self {
+        self.reporting_component = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Event::reporting_instance`] set to the given value.
+    #[must_use]
+    pub fn with_reporting_instance(mut self, value: impl Into<String>) -> Self {
+        self.reporting_instance = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Event::series`] set to the given value.
+    #[must_use]
+    pub fn with_series(mut self, value: impl Into<crate::api::core::v1::EventSeries>) -> Self {
+        self.series = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Event::source`] set to the given value.
+    #[must_use]
+    pub fn with_source(mut self, value: impl Into<crate::api::core::v1::EventSource>) -> Self {
+        self.source = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Event::type_`] set to the given value.
+    #[must_use]
+    pub fn with_type(mut self, value: impl Into<String>) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+}
+
 // Begin /v1/Event
 
 // Generated from operation createCoreV1NamespacedEvent
@@ -75,6 +205,7 @@ impl Event {
         body: &crate::api::core::v1::Event,
         optional: crate::CreateOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::CreateResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/api/v1/namespaces/{namespace}/events?",
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -118,6 +249,7 @@ impl Event {
         delete_optional: crate::DeleteOptional<'_>,
         list_optional: crate::ListOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::DeleteResponse<crate::List<Self>>>), crate::RequestError> {
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/api/v1/namespaces/{namespace}/events?",
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -161,6 +293,8 @@ impl Event {
         namespace: &str,
         optional: crate::DeleteOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::DeleteResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/api/v1/namespaces/{namespace}/events/{name}",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -231,6 +365,7 @@ impl Event {
         namespace: &str,
         optional: crate::ListOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::ListResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/api/v1/namespaces/{namespace}/events?",
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -276,6 +411,8 @@ impl Event {
         body: &crate::apimachinery::pkg::apis::meta::v1::Patch,
         optional: crate::PatchOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::PatchResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/api/v1/namespaces/{namespace}/events/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -329,6 +466,8 @@ impl Event {
             export,
             pretty,
         } = optional;
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/api/v1/namespaces/{namespace}/events/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -433,6 +572,8 @@ impl Event {
         body: &crate::api::core::v1::Event,
         optional: crate::ReplaceOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::ReplaceResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/api/v1/namespaces/{namespace}/events/{name}?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -506,6 +647,7 @@ impl Event {
         namespace: &str,
         optional: crate::WatchOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::WatchResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/api/v1/namespaces/{namespace}/events?",
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
         );
@@ -530,6 +672,9 @@ impl crate::Resource for Event {
     const KIND: &'static str = "Event";
     const VERSION: &'static str = "v1";
     const URL_PATH_SEGMENT: &'static str = "events";
+    const VERBS: &'static [&'static str] = &["create", "delete", "deletecollection", "get", "list", "patch", "update", "watch"];
+    const SINGULAR: &'static str = "event";
+    const SHORT_NAMES: &'static [&'static str] = &["ev"];
     type Scope = crate::NamespaceResourceScope;
 }
 
@@ -549,69 +694,42 @@ impl crate::Metadata for Event {
     }
 }
 
-impl<'de> crate::serde::Deserialize<'de> for Event {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_api_version,
-            Key_kind,
-            Key_action,
-            Key_count,
-            Key_event_time,
-            Key_first_timestamp,
-            Key_involved_object,
-            Key_last_timestamp,
-            Key_message,
-            Key_metadata,
-            Key_reason,
-            Key_related,
-            Key_reporting_component,
-            Key_reporting_instance,
-            Key_series,
-            Key_source,
-            Key_type_,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl std::fmt::Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(<Self as crate::Resource>::URL_PATH_SEGMENT)?;
+        for part in [&self.metadata.namespace, &self.metadata.name].iter().copied().flatten() {
+            write!(f, "/{part}")?;
+        }
+        Ok(())
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "apiVersion" => Field::Key_api_version,
-                            "kind" => Field::Key_kind,
-                            "action" => Field::Key_action,
-                            "count" => Field::Key_count,
-                            "eventTime" => Field::Key_event_time,
-                            "firstTimestamp" => Field::Key_first_timestamp,
-                            "involvedObject" => Field::Key_involved_object,
-                            "lastTimestamp" => Field::Key_last_timestamp,
-                            "message" => Field::Key_message,
-                            "metadata" => Field::Key_metadata,
-                            "reason" => Field::Key_reason,
-                            "related" => Field::Key_related,
-                            "reportingComponent" => Field::Key_reporting_component,
-                            "reportingInstance" => Field::Key_reporting_instance,
-                            "series" => Field::Key_series,
-                            "source" => Field::Key_source,
-                            "type" => Field::Key_type_,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for Event {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl Event {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "kind",
+        "action",
+        "count",
+        "eventTime",
+        "firstTimestamp",
+        "involvedObject",
+        "lastTimestamp",
+        "message",
+        "metadata",
+        "reason",
+        "related",
+        "reportingComponent",
+        "reportingInstance",
+        "series",
+        "source",
+        "type",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for Event {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -638,36 +756,36 @@ impl<'de> crate::serde::Deserialize<'de> for Event {
                 let mut value_source: Option<crate::api::core::v1::EventSource> = None;
                 let mut value_type_: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Event", Event::FIELDS))? {
                     match key {
-                        Field::Key_api_version => {
+                        Some(0) => {
                             let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_api_version != <Self::Value as crate::Resource>::API_VERSION {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<Self::Value as crate::Resource>::API_VERSION));
                             }
                         },
-                        Field::Key_kind => {
+                        Some(1) => {
                             let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_kind != <Self::Value as crate::Resource>::KIND {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<Self::Value as crate::Resource>::KIND));
                             }
                         },
-                        Field::Key_action => value_action = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_count => value_count = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_event_time => value_event_time = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_first_timestamp => value_first_timestamp = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_involved_object => value_involved_object = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_last_timestamp => value_last_timestamp = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_message => value_message = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_metadata => value_metadata = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_reason => value_reason = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_related => value_related = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_reporting_component => value_reporting_component = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_reporting_instance => value_reporting_instance = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_series => value_series = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_source => value_source = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_type_ => value_type_ = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(2) => value_action = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_count = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_event_time = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_first_timestamp = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_involved_object = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(7) => value_last_timestamp = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(8) => value_message = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(9) => value_metadata = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(10) => value_reason = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(11) => value_related = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(12) => value_reporting_component = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(13) => value_reporting_instance = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(14) => value_series = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(15) => value_source = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(16) => value_type_ = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -676,10 +794,10 @@ impl<'de> crate::serde::Deserialize<'de> for Event {
                     count: value_count,
                     event_time: value_event_time,
                     first_timestamp: value_first_timestamp,
-                    involved_object: value_involved_object.ok_or_else(|| crate::serde::de::Error::missing_field("involvedObject"))?,
+                    involved_object: value_involved_object.ok_or_else(|| crate::serde2::missing_field("Event", "involvedObject", &["involvedObject", "metadata"]))?,
                     last_timestamp: value_last_timestamp,
                     message: value_message,
-                    metadata: value_metadata.ok_or_else(|| crate::serde::de::Error::missing_field("metadata"))?,
+                    metadata: value_metadata.ok_or_else(|| crate::serde2::missing_field("Event", "metadata", &["involvedObject", "metadata"]))?,
                     reason: value_reason,
                     related: value_related,
                     reporting_component: value_reporting_component,
@@ -689,36 +807,194 @@ impl<'de> crate::serde::Deserialize<'de> for Event {
                     type_: value_type_,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(Event {
+                    action: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    count: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    event_time: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    first_timestamp: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    involved_object: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    last_timestamp: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    message: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    metadata: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                    reason: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?,
+                    related: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?,
+                    reporting_component: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?,
+                    reporting_instance: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(11, &self))?,
+                    series: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(12, &self))?,
+                    source: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(13, &self))?,
+                    type_: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(14, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             <Self as crate::Resource>::KIND,
-            &[
-                "apiVersion",
-                "kind",
-                "action",
-                "count",
-                "eventTime",
-                "firstTimestamp",
-                "involvedObject",
-                "lastTimestamp",
-                "message",
-                "metadata",
-                "reason",
-                "related",
-                "reportingComponent",
-                "reportingInstance",
-                "series",
-                "source",
-                "type",
-            ],
+            Event::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, Event> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<Event as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_action = false;
+                let mut has_count = false;
+                let mut has_event_time = false;
+                let mut has_first_timestamp = false;
+                let mut has_involved_object = false;
+                let mut has_last_timestamp = false;
+                let mut has_message = false;
+                let mut has_metadata = false;
+                let mut has_reason = false;
+                let mut has_related = false;
+                let mut has_reporting_component = false;
+                let mut has_reporting_instance = false;
+                let mut has_series = false;
+                let mut has_source = false;
+                let mut has_type_ = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Event", Event::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <Event as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<Event as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <Event as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<Event as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.action))?; has_action = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.count))?; has_count = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.event_time))?; has_event_time = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.first_timestamp))?; has_first_timestamp = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.involved_object))?; has_involved_object = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.last_timestamp))?; has_last_timestamp = true; },
+                        Some(8) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.message))?; has_message = true; },
+                        Some(9) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metadata))?; has_metadata = true; },
+                        Some(10) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.reason))?; has_reason = true; },
+                        Some(11) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.related))?; has_related = true; },
+                        Some(12) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.reporting_component))?; has_reporting_component = true; },
+                        Some(13) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.reporting_instance))?; has_reporting_instance = true; },
+                        Some(14) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.series))?; has_series = true; },
+                        Some(15) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.source))?; has_source = true; },
+                        Some(16) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.type_))?; has_type_ = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_action {
+                    self.0.action = None;
+                }
+                if !has_count {
+                    self.0.count = None;
+                }
+                if !has_event_time {
+                    self.0.event_time = None;
+                }
+                if !has_first_timestamp {
+                    self.0.first_timestamp = None;
+                }
+                if !has_involved_object {
+                    return Err(crate::serde2::missing_field("Event", "involvedObject", &["involvedObject", "metadata"]));
+                }
+                if !has_last_timestamp {
+                    self.0.last_timestamp = None;
+                }
+                if !has_message {
+                    self.0.message = None;
+                }
+                if !has_metadata {
+                    return Err(crate::serde2::missing_field("Event", "metadata", &["involvedObject", "metadata"]));
+                }
+                if !has_reason {
+                    self.0.reason = None;
+                }
+                if !has_related {
+                    self.0.related = None;
+                }
+                if !has_reporting_component {
+                    self.0.reporting_component = None;
+                }
+                if !has_reporting_instance {
+                    self.0.reporting_instance = None;
+                }
+                if !has_series {
+                    self.0.series = None;
+                }
+                if !has_source {
+                    self.0.source = None;
+                }
+                if !has_type_ {
+                    self.0.type_ = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.action))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.count))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.event_time))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.first_timestamp))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.involved_object))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.last_timestamp))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.message))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metadata))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.reason))?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.related))?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.reporting_component))?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.reporting_instance))?.ok_or_else(|| crate::serde::de::Error::invalid_length(11, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.series))?.ok_or_else(|| crate::serde::de::Error::invalid_length(12, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.source))?.ok_or_else(|| crate::serde::de::Error::invalid_length(13, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.type_))?.ok_or_else(|| crate::serde::de::Error::invalid_length(14, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            Event::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for Event {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct(<Self as crate::Resource>::KIND, 15)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "action", &self.action)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "count", &self.count)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "eventTime", &self.event_time)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "firstTimestamp", &self.first_timestamp)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "involvedObject", &self.involved_object)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "lastTimestamp", &self.last_timestamp)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "message", &self.message)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", &self.metadata)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reason", &self.reason)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "related", &self.related)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reportingComponent", &self.reporting_component)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reportingInstance", &self.reporting_instance)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "series", &self.series)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "source", &self.source)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "type", &self.type_)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             <Self as crate::Resource>::KIND,
             4 +
@@ -783,10 +1059,282 @@ impl crate::serde::Serialize for Event {
     }
 }
 
+/// A builder of [`Event`]. Created with [`Event::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct EventBuilder {
+    action: Option<String>,
+    count: Option<i32>,
+    event_time: Option<crate::apimachinery::pkg::apis::meta::v1::MicroTime>,
+    first_timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+    involved_object: Option<crate::api::core::v1::ObjectReference>,
+    last_timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+    message: Option<String>,
+    metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>,
+    reason: Option<String>,
+    related: Option<crate::api::core::v1::ObjectReference>,
+    reporting_component: Option<String>,
+    reporting_instance: Option<String>,
+    series: Option<crate::api::core::v1::EventSeries>,
+    source: Option<crate::api::core::v1::EventSource>,
+    type_: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl Event {
+    /// Starts building a `Event`. Its required fields must be set before [`EventBuilder::build`] is called.
+    pub fn builder() -> EventBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl EventBuilder {
+    /// Sets [`Event::action`].
+    pub fn action(mut self, value: impl Into<String>) -> Self {
+        self.action = Some(value.into());
+        self
+    }
+
+    /// Sets [`Event::count`].
+    pub fn count(mut self, value: impl Into<i32>) -> Self {
+        self.count = Some(value.into());
+        self
+    }
+
+    /// Sets [`Event::event_time`].
+    pub fn event_time(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::MicroTime>) -> Self {
+        self.event_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`Event::first_timestamp`].
+    pub fn first_timestamp(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.first_timestamp = Some(value.into());
+        self
+    }
+
+    /// Sets [`Event::involved_object`].
+    pub fn involved_object(mut self, value: impl Into<crate::api::core::v1::ObjectReference>) -> Self {
+        self.involved_object = Some(value.into());
+        self
+    }
+
+    /// Sets [`Event::last_timestamp`].
+    pub fn last_timestamp(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.last_timestamp = Some(value.into());
+        self
+    }
+
+    /// Sets [`Event::message`].
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.message = Some(value.into());
+        self
+    }
+
+    /// Sets [`Event::metadata`].
+    pub fn metadata(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        self.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`Event::reason`].
+    pub fn reason(mut self, value: impl Into<String>) -> Self {
+        self.reason = Some(value.into());
+        self
+    }
+
+    /// Sets [`Event::related`].
+    pub fn related(mut self, value: impl Into<crate::api::core::v1::ObjectReference>) -> Self {
+        self.related = Some(value.into());
+        self
+    }
+
+    /// Sets [`Event::reporting_component`].
+    pub fn reporting_component(mut self, value: impl Into<String>) -> Self {
+        self.reporting_component = Some(value.into());
+        self
+    }
+
+    /// Sets [`Event::reporting_instance`].
+    pub fn reporting_instance(mut self, value: impl Into<String>) -> Self {
+        self.reporting_instance = Some(value.into());
+        self
+    }
+
+    /// Sets [`Event::series`].
+    pub fn series(mut self, value: impl Into<crate::api::core::v1::EventSeries>) -> Self {
+        self.series = Some(value.into());
+        self
+    }
+
+    /// Sets [`Event::source`].
+    pub fn source(mut self, value: impl Into<crate::api::core::v1::EventSource>) -> Self {
+        self.source = Some(value.into());
+        self
+    }
+
+    /// Sets [`Event::type_`].
+    pub fn type_(mut self, value: impl Into<String>) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `Event`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<Event, crate::MissingFieldError> {
+        Ok(Event {
+            action: self.action,
+            count: self.count,
+            event_time: self.event_time,
+            first_timestamp: self.first_timestamp,
+            involved_object: self.involved_object.ok_or_else(|| crate::MissingFieldError::new("Event", "involvedObject"))?,
+            last_timestamp: self.last_timestamp,
+            message: self.message,
+            metadata: self.metadata.ok_or_else(|| crate::MissingFieldError::new("Event", "metadata"))?,
+            reason: self.reason,
+            related: self.related,
+            reporting_component: self.reporting_component,
+            reporting_instance: self.reporting_instance,
+            series: self.series,
+            source: self.source,
+            type_: self.type_,
+        })
+    }
+}
+
+/// The apply configuration of [`Event`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EventApplyConfiguration {
+    /// See [`Event::action`].
+    pub action: Option<String>,
+
+    /// See [`Event::count`].
+    pub count: Option<i32>,
+
+    /// See [`Event::event_time`].
+    pub event_time: Option<crate::apimachinery::pkg::apis::meta::v1::MicroTime>,
+
+    /// See [`Event::first_timestamp`].
+    pub first_timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+
+    /// See [`Event::involved_object`].
+    pub involved_object: Option<crate::api::core::v1::ObjectReferenceApplyConfiguration>,
+
+    /// See [`Event::last_timestamp`].
+    pub last_timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+
+    /// See [`Event::message`].
+    pub message: Option<String>,
+
+    /// See [`Event::metadata`].
+    pub metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMetaApplyConfiguration>,
+
+    /// See [`Event::reason`].
+    pub reason: Option<String>,
+
+    /// See [`Event::related`].
+    pub related: Option<crate::api::core::v1::ObjectReferenceApplyConfiguration>,
+
+    /// See [`Event::reporting_component`].
+    pub reporting_component: Option<String>,
+
+    /// See [`Event::reporting_instance`].
+    pub reporting_instance: Option<String>,
+
+    /// See [`Event::series`].
+    pub series: Option<crate::api::core::v1::EventSeriesApplyConfiguration>,
+
+    /// See [`Event::source`].
+    pub source: Option<crate::api::core::v1::EventSourceApplyConfiguration>,
+
+    /// See [`Event::type_`].
+    pub type_: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for EventApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            <Event as crate::Resource>::KIND,
+            2 +
+            self.action.as_ref().map_or(0, |_| 1) +
+            self.count.as_ref().map_or(0, |_| 1) +
+            self.event_time.as_ref().map_or(0, |_| 1) +
+            self.first_timestamp.as_ref().map_or(0, |_| 1) +
+            self.involved_object.as_ref().map_or(0, |_| 1) +
+            self.last_timestamp.as_ref().map_or(0, |_| 1) +
+            self.message.as_ref().map_or(0, |_| 1) +
+            self.metadata.as_ref().map_or(0, |_| 1) +
+            self.reason.as_ref().map_or(0, |_| 1) +
+            self.related.as_ref().map_or(0, |_| 1) +
+            self.reporting_component.as_ref().map_or(0, |_| 1) +
+            self.reporting_instance.as_ref().map_or(0, |_| 1) +
+            self.series.as_ref().map_or(0, |_| 1) +
+            self.source.as_ref().map_or(0, |_| 1) +
+            self.type_.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <Event as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <Event as crate::Resource>::KIND)?;
+        if let Some(value) = &self.action {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "action", value)?;
+        }
+        if let Some(value) = &self.count {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "count", value)?;
+        }
+        if let Some(value) = &self.event_time {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "eventTime", value)?;
+        }
+        if let Some(value) = &self.first_timestamp {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "firstTimestamp", value)?;
+        }
+        if let Some(value) = &self.involved_object {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "involvedObject", value)?;
+        }
+        if let Some(value) = &self.last_timestamp {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "lastTimestamp", value)?;
+        }
+        if let Some(value) = &self.message {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "message", value)?;
+        }
+        if let Some(value) = &self.metadata {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", value)?;
+        }
+        if let Some(value) = &self.reason {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reason", value)?;
+        }
+        if let Some(value) = &self.related {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "related", value)?;
+        }
+        if let Some(value) = &self.reporting_component {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reportingComponent", value)?;
+        }
+        if let Some(value) = &self.reporting_instance {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reportingInstance", value)?;
+        }
+        if let Some(value) = &self.series {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "series", value)?;
+        }
+        if let Some(value) = &self.source {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "source", value)?;
+        }
+        if let Some(value) = &self.type_ {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "type", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Event {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.Event".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::Event".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.Event".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -797,8 +1345,9 @@ impl crate::schemars::JsonSchema for Event {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "action".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -808,8 +1357,8 @@ impl crate::schemars::JsonSchema for Event {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "apiVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -817,10 +1366,11 @@ impl crate::schemars::JsonSchema for Event {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("v1".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "count".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -831,8 +1381,8 @@ impl crate::schemars::JsonSchema for Event {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "eventTime".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::MicroTime>().into_object();
@@ -842,8 +1392,8 @@ impl crate::schemars::JsonSchema for Event {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "firstTimestamp".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::Time>().into_object();
@@ -853,8 +1403,8 @@ impl crate::schemars::JsonSchema for Event {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "involvedObject".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ObjectReference>().into_object();
@@ -864,8 +1414,8 @@ impl crate::schemars::JsonSchema for Event {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "kind".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -873,10 +1423,11 @@ impl crate::schemars::JsonSchema for Event {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("Event".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "lastTimestamp".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::Time>().into_object();
@@ -886,8 +1437,8 @@ impl crate::schemars::JsonSchema for Event {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "message".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -897,8 +1448,8 @@ impl crate::schemars::JsonSchema for Event {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "metadata".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>().into_object();
@@ -908,8 +1459,8 @@ impl crate::schemars::JsonSchema for Event {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "reason".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -919,8 +1470,8 @@ impl crate::schemars::JsonSchema for Event {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "related".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ObjectReference>().into_object();
@@ -930,8 +1481,8 @@ impl crate::schemars::JsonSchema for Event {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "reportingComponent".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -941,8 +1492,8 @@ impl crate::schemars::JsonSchema for Event {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "reportingInstance".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -952,8 +1503,8 @@ impl crate::schemars::JsonSchema for Event {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "series".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::EventSeries>().into_object();
@@ -963,8 +1514,8 @@ impl crate::schemars::JsonSchema for Event {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "source".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::EventSource>().into_object();
@@ -974,8 +1525,8 @@ impl crate::schemars::JsonSchema for Event {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "type".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -985,10 +1536,13 @@ impl crate::schemars::JsonSchema for Event {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
+                    "apiVersion",
                     "involvedObject",
+                    "kind",
                     "metadata",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
                 ..Default::default()
@@ -997,3 +1551,26 @@ impl crate::schemars::JsonSchema for Event {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for Event {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(Event {
+            action: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            count: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            event_time: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            first_timestamp: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            involved_object: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            last_timestamp: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            message: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            metadata: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            reason: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            related: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            reporting_component: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            reporting_instance: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            series: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            source: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            type_: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
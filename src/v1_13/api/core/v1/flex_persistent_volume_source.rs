@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.FlexPersistentVolumeSource
 
 /// FlexPersistentVolumeSource represents a generic persistent volume resource that is provisioned/attached using an exec based plugin.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct FlexPersistentVolumeSource {
     /// Driver is the name of the driver to use for this volume.
     pub driver: String,
@@ -19,45 +19,70 @@ pub struct FlexPersistentVolumeSource {
     pub secret_ref: Option<crate::api::core::v1::SecretReference>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for FlexPersistentVolumeSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_driver,
-            Key_fs_type,
-            Key_options,
-            Key_read_only,
-            Key_secret_ref,
-            Other,
+impl FlexPersistentVolumeSource {
+    /// Constructs a `FlexPersistentVolumeSource` from its required fields. Its optional fields are unset.
+    pub fn new(driver: impl Into<String>) -> Self {
+        FlexPersistentVolumeSource {
+            driver: driver.into(),
+            fs_type: None,
+            options: None,
+            read_only: None,
+            secret_ref: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl FlexPersistentVolumeSource {
+    /// Returns this value with [`FlexPersistentVolumeSource::driver`] set to the given value.
+    #[must_use]
+    pub fn with_driver(mut self, value: impl Into<String>) -> Self {
+        self.driver = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`FlexPersistentVolumeSource::fs_type`] set to the given value.
+    #[must_use]
+    pub fn with_fs_type(mut self, value: impl Into<String>) -> Self {
+        self.fs_type = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`FlexPersistentVolumeSource::options`] set to the given value.
+    #[must_use]
+    pub fn with_options(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.options = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "driver" => Field::Key_driver,
-                            "fsType" => Field::Key_fs_type,
-                            "options" => Field::Key_options,
-                            "readOnly" => Field::Key_read_only,
-                            "secretRef" => Field::Key_secret_ref,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`FlexPersistentVolumeSource::read_only`] set to the given value.
+    #[must_use]
+    pub fn with_read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`FlexPersistentVolumeSource::secret_ref`] set to the given value.
+    #[must_use]
+    pub fn with_secret_ref(mut self, value: impl Into<crate::api::core::v1::SecretReference>) -> Self {
+        self.secret_ref = Some(value.into());
+        self
+    }
+}
 
+impl crate::MergeStrategyInfo for FlexPersistentVolumeSource {}
+
+impl FlexPersistentVolumeSource {
+    const FIELDS: &'static [&'static str] = &[
+        "driver",
+        "fsType",
+        "options",
+        "readOnly",
+        "secretRef",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for FlexPersistentVolumeSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -74,43 +99,121 @@ impl<'de> crate::serde::Deserialize<'de> for FlexPersistentVolumeSource {
                 let mut value_read_only: Option<bool> = None;
                 let mut value_secret_ref: Option<crate::api::core::v1::SecretReference> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("FlexPersistentVolumeSource", FlexPersistentVolumeSource::FIELDS))? {
                     match key {
-                        Field::Key_driver => value_driver = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_fs_type => value_fs_type = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_options => value_options = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_read_only => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_secret_ref => value_secret_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_driver = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_fs_type = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_options = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_secret_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(FlexPersistentVolumeSource {
-                    driver: value_driver.ok_or_else(|| crate::serde::de::Error::missing_field("driver"))?,
+                    driver: value_driver.ok_or_else(|| crate::serde2::missing_field("FlexPersistentVolumeSource", "driver", &["driver"]))?,
                     fs_type: value_fs_type,
                     options: value_options,
                     read_only: value_read_only,
                     secret_ref: value_secret_ref,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(FlexPersistentVolumeSource {
+                    driver: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    fs_type: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    options: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    read_only: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    secret_ref: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "FlexPersistentVolumeSource",
-            &[
-                "driver",
-                "fsType",
-                "options",
-                "readOnly",
-                "secretRef",
-            ],
+            FlexPersistentVolumeSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, FlexPersistentVolumeSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("FlexPersistentVolumeSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_driver = false;
+                let mut has_fs_type = false;
+                let mut has_options = false;
+                let mut has_read_only = false;
+                let mut has_secret_ref = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("FlexPersistentVolumeSource", FlexPersistentVolumeSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.driver))?; has_driver = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.fs_type))?; has_fs_type = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.options))?; has_options = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.read_only))?; has_read_only = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.secret_ref))?; has_secret_ref = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_driver {
+                    return Err(crate::serde2::missing_field("FlexPersistentVolumeSource", "driver", &["driver"]));
+                }
+                if !has_fs_type {
+                    self.0.fs_type = None;
+                }
+                if !has_options {
+                    self.0.options = None;
+                }
+                if !has_read_only {
+                    self.0.read_only = None;
+                }
+                if !has_secret_ref {
+                    self.0.secret_ref = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.driver))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.fs_type))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.options))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.read_only))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.secret_ref))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "FlexPersistentVolumeSource",
+            FlexPersistentVolumeSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for FlexPersistentVolumeSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("FlexPersistentVolumeSource", 5)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "driver", &self.driver)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsType", &self.fs_type)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "options", &self.options)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", &self.read_only)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretRef", &self.secret_ref)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "FlexPersistentVolumeSource",
             1 +
@@ -136,10 +239,129 @@ impl crate::serde::Serialize for FlexPersistentVolumeSource {
     }
 }
 
+/// A builder of [`FlexPersistentVolumeSource`]. Created with [`FlexPersistentVolumeSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct FlexPersistentVolumeSourceBuilder {
+    driver: Option<String>,
+    fs_type: Option<String>,
+    options: Option<std::collections::BTreeMap<String, String>>,
+    read_only: Option<bool>,
+    secret_ref: Option<crate::api::core::v1::SecretReference>,
+}
+
+#[cfg(feature = "builder")]
+impl FlexPersistentVolumeSource {
+    /// Starts building a `FlexPersistentVolumeSource`. Its required fields must be set before [`FlexPersistentVolumeSourceBuilder::build`] is called.
+    pub fn builder() -> FlexPersistentVolumeSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl FlexPersistentVolumeSourceBuilder {
+    /// Sets [`FlexPersistentVolumeSource::driver`].
+    pub fn driver(mut self, value: impl Into<String>) -> Self {
+        self.driver = Some(value.into());
+        self
+    }
+
+    /// Sets [`FlexPersistentVolumeSource::fs_type`].
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`FlexPersistentVolumeSource::options`].
+    pub fn options(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.options = Some(value.into());
+        self
+    }
+
+    /// Sets [`FlexPersistentVolumeSource::read_only`].
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`FlexPersistentVolumeSource::secret_ref`].
+    pub fn secret_ref(mut self, value: impl Into<crate::api::core::v1::SecretReference>) -> Self {
+        self.secret_ref = Some(value.into());
+        self
+    }
+
+    /// Builds the `FlexPersistentVolumeSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<FlexPersistentVolumeSource, crate::MissingFieldError> {
+        Ok(FlexPersistentVolumeSource {
+            driver: self.driver.ok_or_else(|| crate::MissingFieldError::new("FlexPersistentVolumeSource", "driver"))?,
+            fs_type: self.fs_type,
+            options: self.options,
+            read_only: self.read_only,
+            secret_ref: self.secret_ref,
+        })
+    }
+}
+
+/// The apply configuration of [`FlexPersistentVolumeSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FlexPersistentVolumeSourceApplyConfiguration {
+    /// See [`FlexPersistentVolumeSource::driver`].
+    pub driver: Option<String>,
+
+    /// See [`FlexPersistentVolumeSource::fs_type`].
+    pub fs_type: Option<String>,
+
+    /// See [`FlexPersistentVolumeSource::options`].
+    pub options: Option<std::collections::BTreeMap<String, String>>,
+
+    /// See [`FlexPersistentVolumeSource::read_only`].
+    pub read_only: Option<bool>,
+
+    /// See [`FlexPersistentVolumeSource::secret_ref`].
+    pub secret_ref: Option<crate::api::core::v1::SecretReferenceApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for FlexPersistentVolumeSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "FlexPersistentVolumeSourceApplyConfiguration",
+            self.driver.as_ref().map_or(0, |_| 1) +
+            self.fs_type.as_ref().map_or(0, |_| 1) +
+            self.options.as_ref().map_or(0, |_| 1) +
+            self.read_only.as_ref().map_or(0, |_| 1) +
+            self.secret_ref.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.driver {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "driver", value)?;
+        }
+        if let Some(value) = &self.fs_type {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsType", value)?;
+        }
+        if let Some(value) = &self.options {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "options", value)?;
+        }
+        if let Some(value) = &self.read_only {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", value)?;
+        }
+        if let Some(value) = &self.secret_ref {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "secretRef", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for FlexPersistentVolumeSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.FlexPersistentVolumeSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::FlexPersistentVolumeSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.FlexPersistentVolumeSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -150,8 +372,9 @@ impl crate::schemars::JsonSchema for FlexPersistentVolumeSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "driver".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -161,8 +384,8 @@ impl crate::schemars::JsonSchema for FlexPersistentVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "fsType".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -172,8 +395,8 @@ impl crate::schemars::JsonSchema for FlexPersistentVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "options".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -192,8 +415,8 @@ impl crate::schemars::JsonSchema for FlexPersistentVolumeSource {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "readOnly".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -203,8 +426,8 @@ impl crate::schemars::JsonSchema for FlexPersistentVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "secretRef".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::SecretReference>().into_object();
@@ -214,8 +437,9 @@ impl crate::schemars::JsonSchema for FlexPersistentVolumeSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "driver",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -225,3 +449,16 @@ impl crate::schemars::JsonSchema for FlexPersistentVolumeSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for FlexPersistentVolumeSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(FlexPersistentVolumeSource {
+            driver: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            fs_type: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            options: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            read_only: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            secret_ref: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
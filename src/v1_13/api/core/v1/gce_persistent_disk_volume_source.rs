@@ -3,7 +3,7 @@
 /// Represents a Persistent Disk resource in Google Compute Engine.
 ///
 /// A GCE PD must exist before mounting to a container. The disk must also be in the same GCE project and zone as the kubelet. A GCE PD can only be mounted as read/write once or read-only many times. GCE PDs support ownership management and SELinux relabeling.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct GCEPersistentDiskVolumeSource {
     /// Filesystem type of the volume that you want to mount. Tip: Ensure that the filesystem type is supported by the host operating system. Examples: "ext4", "xfs", "ntfs". Implicitly inferred to be "ext4" if unspecified. More info: https://kubernetes.io/docs/concepts/storage/volumes#gcepersistentdisk
     pub fs_type: Option<String>,
@@ -18,43 +18,61 @@ pub struct GCEPersistentDiskVolumeSource {
     pub read_only: Option<bool>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for GCEPersistentDiskVolumeSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_fs_type,
-            Key_partition,
-            Key_pd_name,
-            Key_read_only,
-            Other,
+impl GCEPersistentDiskVolumeSource {
+    /// Constructs a `GCEPersistentDiskVolumeSource` from its required fields. Its optional fields are unset.
+    pub fn new(pd_name: impl Into<String>) -> Self {
+        GCEPersistentDiskVolumeSource {
+            fs_type: None,
+            partition: None,
+            pd_name: pd_name.into(),
+            read_only: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl GCEPersistentDiskVolumeSource {
+    /// Returns this value with [`GCEPersistentDiskVolumeSource::fs_type`] set to the given value.
+    #[must_use]
+    pub fn with_fs_type(mut self, value: impl Into<String>) -> Self {
+        self.fs_type = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`GCEPersistentDiskVolumeSource::partition`] set to the given value.
+    #[must_use]
+    pub fn with_partition(mut self, value: impl Into<i32>) -> Self {
+        self.partition = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`GCEPersistentDiskVolumeSource::pd_name`] set to the given value.
+    #[must_use]
+    pub fn with_pd_name(mut self, value: impl Into<String>) -> Self {
+        self.pd_name = value.into();
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "fsType" => Field::Key_fs_type,
-                            "partition" => Field::Key_partition,
-                            "pdName" => Field::Key_pd_name,
-                            "readOnly" => Field::Key_read_only,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`GCEPersistentDiskVolumeSource::read_only`] set to the given value.
+    #[must_use]
+    pub fn with_read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl crate::MergeStrategyInfo for GCEPersistentDiskVolumeSource {}
+
+impl GCEPersistentDiskVolumeSource {
+    const FIELDS: &'static [&'static str] = &[
+        "fsType",
+        "partition",
+        "pdName",
+        "readOnly",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for GCEPersistentDiskVolumeSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -70,40 +88,111 @@ impl<'de> crate::serde::Deserialize<'de> for GCEPersistentDiskVolumeSource {
                 let mut value_pd_name: Option<String> = None;
                 let mut value_read_only: Option<bool> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("GCEPersistentDiskVolumeSource", GCEPersistentDiskVolumeSource::FIELDS))? {
                     match key {
-                        Field::Key_fs_type => value_fs_type = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_partition => value_partition = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_pd_name => value_pd_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_read_only => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_fs_type = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_partition = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_pd_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(GCEPersistentDiskVolumeSource {
                     fs_type: value_fs_type,
                     partition: value_partition,
-                    pd_name: value_pd_name.ok_or_else(|| crate::serde::de::Error::missing_field("pdName"))?,
+                    pd_name: value_pd_name.ok_or_else(|| crate::serde2::missing_field("GCEPersistentDiskVolumeSource", "pdName", &["pdName"]))?,
                     read_only: value_read_only,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(GCEPersistentDiskVolumeSource {
+                    fs_type: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    partition: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    pd_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    read_only: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "GCEPersistentDiskVolumeSource",
-            &[
-                "fsType",
-                "partition",
-                "pdName",
-                "readOnly",
-            ],
+            GCEPersistentDiskVolumeSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, GCEPersistentDiskVolumeSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("GCEPersistentDiskVolumeSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_fs_type = false;
+                let mut has_partition = false;
+                let mut has_pd_name = false;
+                let mut has_read_only = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("GCEPersistentDiskVolumeSource", GCEPersistentDiskVolumeSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.fs_type))?; has_fs_type = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.partition))?; has_partition = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.pd_name))?; has_pd_name = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.read_only))?; has_read_only = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_fs_type {
+                    self.0.fs_type = None;
+                }
+                if !has_partition {
+                    self.0.partition = None;
+                }
+                if !has_pd_name {
+                    return Err(crate::serde2::missing_field("GCEPersistentDiskVolumeSource", "pdName", &["pdName"]));
+                }
+                if !has_read_only {
+                    self.0.read_only = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.fs_type))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.partition))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.pd_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.read_only))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "GCEPersistentDiskVolumeSource",
+            GCEPersistentDiskVolumeSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for GCEPersistentDiskVolumeSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("GCEPersistentDiskVolumeSource", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsType", &self.fs_type)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "partition", &self.partition)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "pdName", &self.pd_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", &self.read_only)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "GCEPersistentDiskVolumeSource",
             1 +
@@ -125,10 +214,114 @@ impl crate::serde::Serialize for GCEPersistentDiskVolumeSource {
     }
 }
 
+/// A builder of [`GCEPersistentDiskVolumeSource`]. Created with [`GCEPersistentDiskVolumeSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct GCEPersistentDiskVolumeSourceBuilder {
+    fs_type: Option<String>,
+    partition: Option<i32>,
+    pd_name: Option<String>,
+    read_only: Option<bool>,
+}
+
+#[cfg(feature = "builder")]
+impl GCEPersistentDiskVolumeSource {
+    /// Starts building a `GCEPersistentDiskVolumeSource`. Its required fields must be set before [`GCEPersistentDiskVolumeSourceBuilder::build`] is called.
+    pub fn builder() -> GCEPersistentDiskVolumeSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl GCEPersistentDiskVolumeSourceBuilder {
+    /// Sets [`GCEPersistentDiskVolumeSource::fs_type`].
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`GCEPersistentDiskVolumeSource::partition`].
+    pub fn partition(mut self, value: impl Into<i32>) -> Self {
+        self.partition = Some(value.into());
+        self
+    }
+
+    /// Sets [`GCEPersistentDiskVolumeSource::pd_name`].
+    pub fn pd_name(mut self, value: impl Into<String>) -> Self {
+        self.pd_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`GCEPersistentDiskVolumeSource::read_only`].
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+
+    /// Builds the `GCEPersistentDiskVolumeSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<GCEPersistentDiskVolumeSource, crate::MissingFieldError> {
+        Ok(GCEPersistentDiskVolumeSource {
+            fs_type: self.fs_type,
+            partition: self.partition,
+            pd_name: self.pd_name.ok_or_else(|| crate::MissingFieldError::new("GCEPersistentDiskVolumeSource", "pdName"))?,
+            read_only: self.read_only,
+        })
+    }
+}
+
+/// The apply configuration of [`GCEPersistentDiskVolumeSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GCEPersistentDiskVolumeSourceApplyConfiguration {
+    /// See [`GCEPersistentDiskVolumeSource::fs_type`].
+    pub fs_type: Option<String>,
+
+    /// See [`GCEPersistentDiskVolumeSource::partition`].
+    pub partition: Option<i32>,
+
+    /// See [`GCEPersistentDiskVolumeSource::pd_name`].
+    pub pd_name: Option<String>,
+
+    /// See [`GCEPersistentDiskVolumeSource::read_only`].
+    pub read_only: Option<bool>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for GCEPersistentDiskVolumeSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "GCEPersistentDiskVolumeSourceApplyConfiguration",
+            self.fs_type.as_ref().map_or(0, |_| 1) +
+            self.partition.as_ref().map_or(0, |_| 1) +
+            self.pd_name.as_ref().map_or(0, |_| 1) +
+            self.read_only.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.fs_type {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsType", value)?;
+        }
+        if let Some(value) = &self.partition {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "partition", value)?;
+        }
+        if let Some(value) = &self.pd_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "pdName", value)?;
+        }
+        if let Some(value) = &self.read_only {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for GCEPersistentDiskVolumeSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.GCEPersistentDiskVolumeSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::GCEPersistentDiskVolumeSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.GCEPersistentDiskVolumeSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -139,8 +332,9 @@ impl crate::schemars::JsonSchema for GCEPersistentDiskVolumeSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "fsType".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -150,8 +344,8 @@ impl crate::schemars::JsonSchema for GCEPersistentDiskVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "partition".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -162,8 +356,8 @@ impl crate::schemars::JsonSchema for GCEPersistentDiskVolumeSource {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "pdName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -173,8 +367,8 @@ impl crate::schemars::JsonSchema for GCEPersistentDiskVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "readOnly".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -184,8 +378,9 @@ impl crate::schemars::JsonSchema for GCEPersistentDiskVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "pdName",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -195,3 +390,15 @@ impl crate::schemars::JsonSchema for GCEPersistentDiskVolumeSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for GCEPersistentDiskVolumeSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(GCEPersistentDiskVolumeSource {
+            fs_type: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            partition: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            pd_name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            read_only: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
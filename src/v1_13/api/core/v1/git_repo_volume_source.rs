@@ -3,7 +3,8 @@
 /// Represents a volume that is populated with the contents of a git repository. Git repo volumes do not support ownership management. Git repo volumes support SELinux relabeling.
 ///
 /// DEPRECATED: GitRepo is deprecated. To provision a container with a git repo, mount an EmptyDir into an InitContainer that clones the repo using git, then mount the EmptyDir into the Pod's container.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[deprecated(note = "GitRepo is deprecated.")]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct GitRepoVolumeSource {
     /// Target directory name. Must not contain or start with '..'.  If '.' is supplied, the volume directory will be the git repository.  Otherwise, if specified, the volume will contain the git repository in the subdirectory with the given name.
     pub directory: Option<String>,
@@ -15,41 +16,52 @@ pub struct GitRepoVolumeSource {
     pub revision: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for GitRepoVolumeSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_directory,
-            Key_repository,
-            Key_revision,
-            Other,
+impl GitRepoVolumeSource {
+    /// Constructs a `GitRepoVolumeSource` from its required fields. Its optional fields are unset.
+    pub fn new(repository: impl Into<String>) -> Self {
+        GitRepoVolumeSource {
+            directory: None,
+            repository: repository.into(),
+            revision: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl GitRepoVolumeSource {
+    /// Returns this value with [`GitRepoVolumeSource::directory`] set to the given value.
+    #[must_use]
+    pub fn with_directory(mut self, value: impl Into<String>) -> Self {
+        self.directory = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`GitRepoVolumeSource::repository`] set to the given value.
+    #[must_use]
+    pub fn with_repository(mut self, value: impl Into<String>) -> Self {
+        self.repository = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`GitRepoVolumeSource::revision`] set to the given value.
+    #[must_use]
+    pub fn with_revision(mut self, value: impl Into<String>) -> Self {
+        self.revision = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "directory" => Field::Key_directory,
-                            "repository" => Field::Key_repository,
-                            "revision" => Field::Key_revision,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for GitRepoVolumeSource {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl GitRepoVolumeSource {
+    const FIELDS: &'static [&'static str] = &[
+        "directory",
+        "repository",
+        "revision",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for GitRepoVolumeSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -64,37 +76,101 @@ impl<'de> crate::serde::Deserialize<'de> for GitRepoVolumeSource {
                 let mut value_repository: Option<String> = None;
                 let mut value_revision: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("GitRepoVolumeSource", GitRepoVolumeSource::FIELDS))? {
                     match key {
-                        Field::Key_directory => value_directory = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_repository => value_repository = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_revision => value_revision = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_directory = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_repository = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_revision = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(GitRepoVolumeSource {
                     directory: value_directory,
-                    repository: value_repository.ok_or_else(|| crate::serde::de::Error::missing_field("repository"))?,
+                    repository: value_repository.ok_or_else(|| crate::serde2::missing_field("GitRepoVolumeSource", "repository", &["repository"]))?,
                     revision: value_revision,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(GitRepoVolumeSource {
+                    directory: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    repository: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    revision: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "GitRepoVolumeSource",
-            &[
-                "directory",
-                "repository",
-                "revision",
-            ],
+            GitRepoVolumeSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, GitRepoVolumeSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("GitRepoVolumeSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_directory = false;
+                let mut has_repository = false;
+                let mut has_revision = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("GitRepoVolumeSource", GitRepoVolumeSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.directory))?; has_directory = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.repository))?; has_repository = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.revision))?; has_revision = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_directory {
+                    self.0.directory = None;
+                }
+                if !has_repository {
+                    return Err(crate::serde2::missing_field("GitRepoVolumeSource", "repository", &["repository"]));
+                }
+                if !has_revision {
+                    self.0.revision = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.directory))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.repository))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.revision))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "GitRepoVolumeSource",
+            GitRepoVolumeSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for GitRepoVolumeSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("GitRepoVolumeSource", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "directory", &self.directory)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "repository", &self.repository)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "revision", &self.revision)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "GitRepoVolumeSource",
             1 +
@@ -112,10 +188,99 @@ impl crate::serde::Serialize for GitRepoVolumeSource {
     }
 }
 
+/// A builder of [`GitRepoVolumeSource`]. Created with [`GitRepoVolumeSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct GitRepoVolumeSourceBuilder {
+    directory: Option<String>,
+    repository: Option<String>,
+    revision: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl GitRepoVolumeSource {
+    /// Starts building a `GitRepoVolumeSource`. Its required fields must be set before [`GitRepoVolumeSourceBuilder::build`] is called.
+    pub fn builder() -> GitRepoVolumeSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl GitRepoVolumeSourceBuilder {
+    /// Sets [`GitRepoVolumeSource::directory`].
+    pub fn directory(mut self, value: impl Into<String>) -> Self {
+        self.directory = Some(value.into());
+        self
+    }
+
+    /// Sets [`GitRepoVolumeSource::repository`].
+    pub fn repository(mut self, value: impl Into<String>) -> Self {
+        self.repository = Some(value.into());
+        self
+    }
+
+    /// Sets [`GitRepoVolumeSource::revision`].
+    pub fn revision(mut self, value: impl Into<String>) -> Self {
+        self.revision = Some(value.into());
+        self
+    }
+
+    /// Builds the `GitRepoVolumeSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<GitRepoVolumeSource, crate::MissingFieldError> {
+        Ok(GitRepoVolumeSource {
+            directory: self.directory,
+            repository: self.repository.ok_or_else(|| crate::MissingFieldError::new("GitRepoVolumeSource", "repository"))?,
+            revision: self.revision,
+        })
+    }
+}
+
+/// The apply configuration of [`GitRepoVolumeSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GitRepoVolumeSourceApplyConfiguration {
+    /// See [`GitRepoVolumeSource::directory`].
+    pub directory: Option<String>,
+
+    /// See [`GitRepoVolumeSource::repository`].
+    pub repository: Option<String>,
+
+    /// See [`GitRepoVolumeSource::revision`].
+    pub revision: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for GitRepoVolumeSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "GitRepoVolumeSourceApplyConfiguration",
+            self.directory.as_ref().map_or(0, |_| 1) +
+            self.repository.as_ref().map_or(0, |_| 1) +
+            self.revision.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.directory {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "directory", value)?;
+        }
+        if let Some(value) = &self.repository {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "repository", value)?;
+        }
+        if let Some(value) = &self.revision {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "revision", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for GitRepoVolumeSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.GitRepoVolumeSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::GitRepoVolumeSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.GitRepoVolumeSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -126,8 +291,9 @@ impl crate::schemars::JsonSchema for GitRepoVolumeSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "directory".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -137,8 +303,8 @@ impl crate::schemars::JsonSchema for GitRepoVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "repository".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -148,8 +314,8 @@ impl crate::schemars::JsonSchema for GitRepoVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "revision".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -159,8 +325,9 @@ impl crate::schemars::JsonSchema for GitRepoVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "repository",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -170,3 +337,14 @@ impl crate::schemars::JsonSchema for GitRepoVolumeSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for GitRepoVolumeSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(GitRepoVolumeSource {
+            directory: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            repository: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            revision: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
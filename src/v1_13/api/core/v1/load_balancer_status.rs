@@ -1,80 +1,118 @@
 // Generated from definition io.k8s.api.core.v1.LoadBalancerStatus
 
 /// LoadBalancerStatus represents the status of a load-balancer.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct LoadBalancerStatus {
     /// Ingress is a list containing ingress points for the load-balancer. Traffic intended for the service should be sent to these ingress points.
     pub ingress: Option<Vec<crate::api::core::v1::LoadBalancerIngress>>,
 }
 
+impl LoadBalancerStatus {
+    /// Returns this value with [`LoadBalancerStatus::ingress`] set to the given value.
+    #[must_use]
+    pub fn with_ingress(mut self, value: impl Into<Vec<crate::api::core::v1::LoadBalancerIngress>>) -> Self {
+        self.ingress = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for LoadBalancerStatus {}
+
+impl LoadBalancerStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "ingress",
+    ];
+}
+
 impl<'de> crate::serde::Deserialize<'de> for LoadBalancerStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_ingress,
-            Other,
-        }
+        struct Visitor;
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = LoadBalancerStatus;
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("LoadBalancerStatus")
+            }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_ingress: Option<Vec<crate::api::core::v1::LoadBalancerIngress>> = None;
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "ingress" => Field::Key_ingress,
-                            _ => Field::Other,
-                        })
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("LoadBalancerStatus", LoadBalancerStatus::FIELDS))? {
+                    match key {
+                        Some(0) => value_ingress = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
-                deserializer.deserialize_identifier(Visitor)
+                Ok(LoadBalancerStatus {
+                    ingress: value_ingress,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(LoadBalancerStatus {
+                    ingress: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                })
             }
         }
 
-        struct Visitor;
+        deserializer.deserialize_struct(
+            "LoadBalancerStatus",
+            LoadBalancerStatus::FIELDS,
+            Visitor,
+        )
+    }
 
-        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-            type Value = LoadBalancerStatus;
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, LoadBalancerStatus> {
+            type Value = ();
 
             fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 f.write_str("LoadBalancerStatus")
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
-                let mut value_ingress: Option<Vec<crate::api::core::v1::LoadBalancerIngress>> = None;
+                let mut has_ingress = false;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("LoadBalancerStatus", LoadBalancerStatus::FIELDS))? {
                     match key {
-                        Field::Key_ingress => value_ingress = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.ingress))?; has_ingress = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
+                if !has_ingress {
+                    self.0.ingress = None;
+                }
 
-                Ok(LoadBalancerStatus {
-                    ingress: value_ingress,
-                })
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.ingress))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
             }
         }
 
         deserializer.deserialize_struct(
             "LoadBalancerStatus",
-            &[
-                "ingress",
-            ],
-            Visitor,
+            LoadBalancerStatus::FIELDS,
+            InPlaceVisitor(place),
         )
     }
 }
 
 impl crate::serde::Serialize for LoadBalancerStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("LoadBalancerStatus", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ingress", &self.ingress)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "LoadBalancerStatus",
             self.ingress.as_ref().map_or(0, |_| 1),
@@ -86,10 +124,69 @@ impl crate::serde::Serialize for LoadBalancerStatus {
     }
 }
 
+/// A builder of [`LoadBalancerStatus`]. Created with [`LoadBalancerStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct LoadBalancerStatusBuilder {
+    ingress: Option<Vec<crate::api::core::v1::LoadBalancerIngress>>,
+}
+
+#[cfg(feature = "builder")]
+impl LoadBalancerStatus {
+    /// Starts building a `LoadBalancerStatus`. Its required fields must be set before [`LoadBalancerStatusBuilder::build`] is called.
+    pub fn builder() -> LoadBalancerStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl LoadBalancerStatusBuilder {
+    /// Sets [`LoadBalancerStatus::ingress`].
+    pub fn ingress(mut self, value: impl Into<Vec<crate::api::core::v1::LoadBalancerIngress>>) -> Self {
+        self.ingress = Some(value.into());
+        self
+    }
+
+    /// Builds the `LoadBalancerStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<LoadBalancerStatus, crate::MissingFieldError> {
+        Ok(LoadBalancerStatus {
+            ingress: self.ingress,
+        })
+    }
+}
+
+/// The apply configuration of [`LoadBalancerStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LoadBalancerStatusApplyConfiguration {
+    /// See [`LoadBalancerStatus::ingress`].
+    pub ingress: Option<Vec<crate::api::core::v1::LoadBalancerIngressApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for LoadBalancerStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "LoadBalancerStatusApplyConfiguration",
+            self.ingress.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.ingress {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ingress", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for LoadBalancerStatus {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.LoadBalancerStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::LoadBalancerStatus".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.LoadBalancerStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -100,8 +197,9 @@ impl crate::schemars::JsonSchema for LoadBalancerStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "ingress".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -115,11 +213,21 @@ impl crate::schemars::JsonSchema for LoadBalancerStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for LoadBalancerStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(LoadBalancerStatus {
+            ingress: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
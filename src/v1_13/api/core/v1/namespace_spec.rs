@@ -1,80 +1,118 @@
 // Generated from definition io.k8s.api.core.v1.NamespaceSpec
 
 /// NamespaceSpec describes the attributes on a Namespace.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct NamespaceSpec {
     /// Finalizers is an opaque list of values that must be empty to permanently remove object from storage. More info: https://kubernetes.io/docs/tasks/administer-cluster/namespaces/
     pub finalizers: Option<Vec<String>>,
 }
 
+impl NamespaceSpec {
+    /// Returns this value with [`NamespaceSpec::finalizers`] set to the given value.
+    #[must_use]
+    pub fn with_finalizers(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.finalizers = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for NamespaceSpec {}
+
+impl NamespaceSpec {
+    const FIELDS: &'static [&'static str] = &[
+        "finalizers",
+    ];
+}
+
 impl<'de> crate::serde::Deserialize<'de> for NamespaceSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_finalizers,
-            Other,
-        }
+        struct Visitor;
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = NamespaceSpec;
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("NamespaceSpec")
+            }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_finalizers: Option<Vec<String>> = None;
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "finalizers" => Field::Key_finalizers,
-                            _ => Field::Other,
-                        })
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NamespaceSpec", NamespaceSpec::FIELDS))? {
+                    match key {
+                        Some(0) => value_finalizers = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
-                deserializer.deserialize_identifier(Visitor)
+                Ok(NamespaceSpec {
+                    finalizers: value_finalizers,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(NamespaceSpec {
+                    finalizers: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                })
             }
         }
 
-        struct Visitor;
+        deserializer.deserialize_struct(
+            "NamespaceSpec",
+            NamespaceSpec::FIELDS,
+            Visitor,
+        )
+    }
 
-        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-            type Value = NamespaceSpec;
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, NamespaceSpec> {
+            type Value = ();
 
             fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 f.write_str("NamespaceSpec")
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
-                let mut value_finalizers: Option<Vec<String>> = None;
+                let mut has_finalizers = false;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NamespaceSpec", NamespaceSpec::FIELDS))? {
                     match key {
-                        Field::Key_finalizers => value_finalizers = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.finalizers))?; has_finalizers = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
+                if !has_finalizers {
+                    self.0.finalizers = None;
+                }
 
-                Ok(NamespaceSpec {
-                    finalizers: value_finalizers,
-                })
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.finalizers))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
             }
         }
 
         deserializer.deserialize_struct(
             "NamespaceSpec",
-            &[
-                "finalizers",
-            ],
-            Visitor,
+            NamespaceSpec::FIELDS,
+            InPlaceVisitor(place),
         )
     }
 }
 
 impl crate::serde::Serialize for NamespaceSpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("NamespaceSpec", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "finalizers", &self.finalizers)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "NamespaceSpec",
             self.finalizers.as_ref().map_or(0, |_| 1),
@@ -86,10 +124,69 @@ impl crate::serde::Serialize for NamespaceSpec {
     }
 }
 
+/// A builder of [`NamespaceSpec`]. Created with [`NamespaceSpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct NamespaceSpecBuilder {
+    finalizers: Option<Vec<String>>,
+}
+
+#[cfg(feature = "builder")]
+impl NamespaceSpec {
+    /// Starts building a `NamespaceSpec`. Its required fields must be set before [`NamespaceSpecBuilder::build`] is called.
+    pub fn builder() -> NamespaceSpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl NamespaceSpecBuilder {
+    /// Sets [`NamespaceSpec::finalizers`].
+    pub fn finalizers(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.finalizers = Some(value.into());
+        self
+    }
+
+    /// Builds the `NamespaceSpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<NamespaceSpec, crate::MissingFieldError> {
+        Ok(NamespaceSpec {
+            finalizers: self.finalizers,
+        })
+    }
+}
+
+/// The apply configuration of [`NamespaceSpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NamespaceSpecApplyConfiguration {
+    /// See [`NamespaceSpec::finalizers`].
+    pub finalizers: Option<Vec<String>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for NamespaceSpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "NamespaceSpecApplyConfiguration",
+            self.finalizers.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.finalizers {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "finalizers", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NamespaceSpec {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.NamespaceSpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::NamespaceSpec".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.NamespaceSpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -100,8 +197,9 @@ impl crate::schemars::JsonSchema for NamespaceSpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "finalizers".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -120,11 +218,21 @@ impl crate::schemars::JsonSchema for NamespaceSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for NamespaceSpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(NamespaceSpec {
+            finalizers: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
@@ -1,80 +1,118 @@
 // Generated from definition io.k8s.api.core.v1.NodeDaemonEndpoints
 
 /// NodeDaemonEndpoints lists ports opened by daemons running on the Node.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 pub struct NodeDaemonEndpoints {
     /// Endpoint on which Kubelet is listening.
     pub kubelet_endpoint: Option<crate::api::core::v1::DaemonEndpoint>,
 }
 
+impl NodeDaemonEndpoints {
+    /// Returns this value with [`NodeDaemonEndpoints::kubelet_endpoint`] set to the given value.
+    #[must_use]
+    pub fn with_kubelet_endpoint(mut self, value: impl Into<crate::api::core::v1::DaemonEndpoint>) -> Self {
+        self.kubelet_endpoint = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for NodeDaemonEndpoints {}
+
+impl NodeDaemonEndpoints {
+    const FIELDS: &'static [&'static str] = &[
+        "kubeletEndpoint",
+    ];
+}
+
 impl<'de> crate::serde::Deserialize<'de> for NodeDaemonEndpoints {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_kubelet_endpoint,
-            Other,
-        }
+        struct Visitor;
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = NodeDaemonEndpoints;
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("NodeDaemonEndpoints")
+            }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_kubelet_endpoint: Option<crate::api::core::v1::DaemonEndpoint> = None;
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "kubeletEndpoint" => Field::Key_kubelet_endpoint,
-                            _ => Field::Other,
-                        })
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NodeDaemonEndpoints", NodeDaemonEndpoints::FIELDS))? {
+                    match key {
+                        Some(0) => value_kubelet_endpoint = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
-                deserializer.deserialize_identifier(Visitor)
+                Ok(NodeDaemonEndpoints {
+                    kubelet_endpoint: value_kubelet_endpoint,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(NodeDaemonEndpoints {
+                    kubelet_endpoint: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                })
             }
         }
 
-        struct Visitor;
+        deserializer.deserialize_struct(
+            "NodeDaemonEndpoints",
+            NodeDaemonEndpoints::FIELDS,
+            Visitor,
+        )
+    }
 
-        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-            type Value = NodeDaemonEndpoints;
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, NodeDaemonEndpoints> {
+            type Value = ();
 
             fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 f.write_str("NodeDaemonEndpoints")
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
-                let mut value_kubelet_endpoint: Option<crate::api::core::v1::DaemonEndpoint> = None;
+                let mut has_kubelet_endpoint = false;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NodeDaemonEndpoints", NodeDaemonEndpoints::FIELDS))? {
                     match key {
-                        Field::Key_kubelet_endpoint => value_kubelet_endpoint = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.kubelet_endpoint))?; has_kubelet_endpoint = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
+                if !has_kubelet_endpoint {
+                    self.0.kubelet_endpoint = None;
+                }
 
-                Ok(NodeDaemonEndpoints {
-                    kubelet_endpoint: value_kubelet_endpoint,
-                })
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.kubelet_endpoint))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
             }
         }
 
         deserializer.deserialize_struct(
             "NodeDaemonEndpoints",
-            &[
-                "kubeletEndpoint",
-            ],
-            Visitor,
+            NodeDaemonEndpoints::FIELDS,
+            InPlaceVisitor(place),
         )
     }
 }
 
 impl crate::serde::Serialize for NodeDaemonEndpoints {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("NodeDaemonEndpoints", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kubeletEndpoint", &self.kubelet_endpoint)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "NodeDaemonEndpoints",
             self.kubelet_endpoint.as_ref().map_or(0, |_| 1),
@@ -86,10 +124,69 @@ impl crate::serde::Serialize for NodeDaemonEndpoints {
     }
 }
 
+/// A builder of [`NodeDaemonEndpoints`]. Created with [`NodeDaemonEndpoints::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct NodeDaemonEndpointsBuilder {
+    kubelet_endpoint: Option<crate::api::core::v1::DaemonEndpoint>,
+}
+
+#[cfg(feature = "builder")]
+impl NodeDaemonEndpoints {
+    /// Starts building a `NodeDaemonEndpoints`. Its required fields must be set before [`NodeDaemonEndpointsBuilder::build`] is called.
+    pub fn builder() -> NodeDaemonEndpointsBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl NodeDaemonEndpointsBuilder {
+    /// Sets [`NodeDaemonEndpoints::kubelet_endpoint`].
+    pub fn kubelet_endpoint(mut self, value: impl Into<crate::api::core::v1::DaemonEndpoint>) -> Self {
+        self.kubelet_endpoint = Some(value.into());
+        self
+    }
+
+    /// Builds the `NodeDaemonEndpoints`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<NodeDaemonEndpoints, crate::MissingFieldError> {
+        Ok(NodeDaemonEndpoints {
+            kubelet_endpoint: self.kubelet_endpoint,
+        })
+    }
+}
+
+/// The apply configuration of [`NodeDaemonEndpoints`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NodeDaemonEndpointsApplyConfiguration {
+    /// See [`NodeDaemonEndpoints::kubelet_endpoint`].
+    pub kubelet_endpoint: Option<crate::api::core::v1::DaemonEndpointApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for NodeDaemonEndpointsApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "NodeDaemonEndpointsApplyConfiguration",
+            self.kubelet_endpoint.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.kubelet_endpoint {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kubeletEndpoint", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NodeDaemonEndpoints {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.NodeDaemonEndpoints".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::NodeDaemonEndpoints".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.NodeDaemonEndpoints".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -100,8 +197,9 @@ impl crate::schemars::JsonSchema for NodeDaemonEndpoints {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "kubeletEndpoint".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::DaemonEndpoint>().into_object();
@@ -111,11 +209,21 @@ impl crate::schemars::JsonSchema for NodeDaemonEndpoints {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for NodeDaemonEndpoints {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(NodeDaemonEndpoints {
+            kubelet_endpoint: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
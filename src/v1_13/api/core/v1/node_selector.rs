@@ -1,43 +1,40 @@
 // Generated from definition io.k8s.api.core.v1.NodeSelector
 
 /// A node selector represents the union of the results of one or more label queries over a set of nodes; that is, it represents the OR of the selectors represented by the node selector terms.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct NodeSelector {
     /// Required. A list of node selector terms. The terms are ORed.
     pub node_selector_terms: Vec<crate::api::core::v1::NodeSelectorTerm>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for NodeSelector {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_node_selector_terms,
-            Other,
+impl NodeSelector {
+    /// Constructs a `NodeSelector` from its required fields. Its optional fields are unset.
+    pub fn new(node_selector_terms: impl Into<Vec<crate::api::core::v1::NodeSelectorTerm>>) -> Self {
+        NodeSelector {
+            node_selector_terms: node_selector_terms.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl NodeSelector {
+    /// Returns this value with [`NodeSelector::node_selector_terms`] set to the given value.
+    #[must_use]
+    pub fn with_node_selector_terms(mut self, value: impl Into<Vec<crate::api::core::v1::NodeSelectorTerm>>) -> Self {
+        self.node_selector_terms = value.into();
+        self
+    }
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for NodeSelector {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "nodeSelectorTerms" => Field::Key_node_selector_terms,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl NodeSelector {
+    const FIELDS: &'static [&'static str] = &[
+        "nodeSelectorTerms",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for NodeSelector {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -50,31 +47,81 @@ impl<'de> crate::serde::Deserialize<'de> for NodeSelector {
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
                 let mut value_node_selector_terms: Option<Vec<crate::api::core::v1::NodeSelectorTerm>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NodeSelector", NodeSelector::FIELDS))? {
                     match key {
-                        Field::Key_node_selector_terms => value_node_selector_terms = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_node_selector_terms = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(NodeSelector {
-                    node_selector_terms: value_node_selector_terms.ok_or_else(|| crate::serde::de::Error::missing_field("nodeSelectorTerms"))?,
+                    node_selector_terms: value_node_selector_terms.ok_or_else(|| crate::serde2::missing_field("NodeSelector", "nodeSelectorTerms", &["nodeSelectorTerms"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(NodeSelector {
+                    node_selector_terms: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "NodeSelector",
-            &[
-                "nodeSelectorTerms",
-            ],
+            NodeSelector::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, NodeSelector> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("NodeSelector")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_node_selector_terms = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NodeSelector", NodeSelector::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.node_selector_terms))?; has_node_selector_terms = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_node_selector_terms {
+                    return Err(crate::serde2::missing_field("NodeSelector", "nodeSelectorTerms", &["nodeSelectorTerms"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.node_selector_terms))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "NodeSelector",
+            NodeSelector::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for NodeSelector {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("NodeSelector", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeSelectorTerms", &self.node_selector_terms)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "NodeSelector",
             1,
@@ -84,10 +131,69 @@ impl crate::serde::Serialize for NodeSelector {
     }
 }
 
+/// A builder of [`NodeSelector`]. Created with [`NodeSelector::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct NodeSelectorBuilder {
+    node_selector_terms: Option<Vec<crate::api::core::v1::NodeSelectorTerm>>,
+}
+
+#[cfg(feature = "builder")]
+impl NodeSelector {
+    /// Starts building a `NodeSelector`. Its required fields must be set before [`NodeSelectorBuilder::build`] is called.
+    pub fn builder() -> NodeSelectorBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl NodeSelectorBuilder {
+    /// Sets [`NodeSelector::node_selector_terms`].
+    pub fn node_selector_terms(mut self, value: impl Into<Vec<crate::api::core::v1::NodeSelectorTerm>>) -> Self {
+        self.node_selector_terms = Some(value.into());
+        self
+    }
+
+    /// Builds the `NodeSelector`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<NodeSelector, crate::MissingFieldError> {
+        Ok(NodeSelector {
+            node_selector_terms: self.node_selector_terms.ok_or_else(|| crate::MissingFieldError::new("NodeSelector", "nodeSelectorTerms"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`NodeSelector`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NodeSelectorApplyConfiguration {
+    /// See [`NodeSelector::node_selector_terms`].
+    pub node_selector_terms: Option<Vec<crate::api::core::v1::NodeSelectorTermApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for NodeSelectorApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "NodeSelectorApplyConfiguration",
+            self.node_selector_terms.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.node_selector_terms {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeSelectorTerms", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NodeSelector {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.NodeSelector".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::NodeSelector".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.NodeSelector".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -98,8 +204,9 @@ impl crate::schemars::JsonSchema for NodeSelector {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "nodeSelectorTerms".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -113,8 +220,9 @@ impl crate::schemars::JsonSchema for NodeSelector {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "nodeSelectorTerms",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -124,3 +232,12 @@ impl crate::schemars::JsonSchema for NodeSelector {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for NodeSelector {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(NodeSelector {
+            node_selector_terms: crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.NodeSelectorTerm
 
 /// A null or empty node selector term matches no objects. The requirements of them are ANDed. The TopologySelectorTerm type implements a subset of the NodeSelectorTerm.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct NodeSelectorTerm {
     /// A list of node selector requirements by node's labels.
     pub match_expressions: Option<Vec<crate::api::core::v1::NodeSelectorRequirement>>,
@@ -10,39 +10,33 @@ pub struct NodeSelectorTerm {
     pub match_fields: Option<Vec<crate::api::core::v1::NodeSelectorRequirement>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for NodeSelectorTerm {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_match_expressions,
-            Key_match_fields,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl NodeSelectorTerm {
+    /// Returns this value with [`NodeSelectorTerm::match_expressions`] set to the given value.
+    #[must_use]
+    pub fn with_match_expressions(mut self, value: impl Into<Vec<crate::api::core::v1::NodeSelectorRequirement>>) -> Self {
+        self.match_expressions = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`NodeSelectorTerm::match_fields`] set to the given value.
+    #[must_use]
+    pub fn with_match_fields(mut self, value: impl Into<Vec<crate::api::core::v1::NodeSelectorRequirement>>) -> Self {
+        self.match_fields = Some(value.into());
+        self
+    }
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for NodeSelectorTerm {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "matchExpressions" => Field::Key_match_expressions,
-                            "matchFields" => Field::Key_match_fields,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl NodeSelectorTerm {
+    const FIELDS: &'static [&'static str] = &[
+        "matchExpressions",
+        "matchFields",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for NodeSelectorTerm {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,11 +50,11 @@ impl<'de> crate::serde::Deserialize<'de> for NodeSelectorTerm {
                 let mut value_match_expressions: Option<Vec<crate::api::core::v1::NodeSelectorRequirement>> = None;
                 let mut value_match_fields: Option<Vec<crate::api::core::v1::NodeSelectorRequirement>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NodeSelectorTerm", NodeSelectorTerm::FIELDS))? {
                     match key {
-                        Field::Key_match_expressions => value_match_expressions = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_match_fields => value_match_fields = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_match_expressions = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_match_fields = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -69,21 +63,78 @@ impl<'de> crate::serde::Deserialize<'de> for NodeSelectorTerm {
                     match_fields: value_match_fields,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(NodeSelectorTerm {
+                    match_expressions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    match_fields: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "NodeSelectorTerm",
-            &[
-                "matchExpressions",
-                "matchFields",
-            ],
+            NodeSelectorTerm::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, NodeSelectorTerm> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("NodeSelectorTerm")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_match_expressions = false;
+                let mut has_match_fields = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NodeSelectorTerm", NodeSelectorTerm::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.match_expressions))?; has_match_expressions = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.match_fields))?; has_match_fields = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_match_expressions {
+                    self.0.match_expressions = None;
+                }
+                if !has_match_fields {
+                    self.0.match_fields = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.match_expressions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.match_fields))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "NodeSelectorTerm",
+            NodeSelectorTerm::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for NodeSelectorTerm {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("NodeSelectorTerm", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "matchExpressions", &self.match_expressions)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "matchFields", &self.match_fields)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "NodeSelectorTerm",
             self.match_expressions.as_ref().map_or(0, |_| 1) +
@@ -99,10 +150,84 @@ impl crate::serde::Serialize for NodeSelectorTerm {
     }
 }
 
+/// A builder of [`NodeSelectorTerm`]. Created with [`NodeSelectorTerm::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct NodeSelectorTermBuilder {
+    match_expressions: Option<Vec<crate::api::core::v1::NodeSelectorRequirement>>,
+    match_fields: Option<Vec<crate::api::core::v1::NodeSelectorRequirement>>,
+}
+
+#[cfg(feature = "builder")]
+impl NodeSelectorTerm {
+    /// Starts building a `NodeSelectorTerm`. Its required fields must be set before [`NodeSelectorTermBuilder::build`] is called.
+    pub fn builder() -> NodeSelectorTermBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl NodeSelectorTermBuilder {
+    /// Sets [`NodeSelectorTerm::match_expressions`].
+    pub fn match_expressions(mut self, value: impl Into<Vec<crate::api::core::v1::NodeSelectorRequirement>>) -> Self {
+        self.match_expressions = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeSelectorTerm::match_fields`].
+    pub fn match_fields(mut self, value: impl Into<Vec<crate::api::core::v1::NodeSelectorRequirement>>) -> Self {
+        self.match_fields = Some(value.into());
+        self
+    }
+
+    /// Builds the `NodeSelectorTerm`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<NodeSelectorTerm, crate::MissingFieldError> {
+        Ok(NodeSelectorTerm {
+            match_expressions: self.match_expressions,
+            match_fields: self.match_fields,
+        })
+    }
+}
+
+/// The apply configuration of [`NodeSelectorTerm`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NodeSelectorTermApplyConfiguration {
+    /// See [`NodeSelectorTerm::match_expressions`].
+    pub match_expressions: Option<Vec<crate::api::core::v1::NodeSelectorRequirementApplyConfiguration>>,
+
+    /// See [`NodeSelectorTerm::match_fields`].
+    pub match_fields: Option<Vec<crate::api::core::v1::NodeSelectorRequirementApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for NodeSelectorTermApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "NodeSelectorTermApplyConfiguration",
+            self.match_expressions.as_ref().map_or(0, |_| 1) +
+            self.match_fields.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.match_expressions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "matchExpressions", value)?;
+        }
+        if let Some(value) = &self.match_fields {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "matchFields", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NodeSelectorTerm {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.NodeSelectorTerm".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::NodeSelectorTerm".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.NodeSelectorTerm".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -113,8 +238,9 @@ impl crate::schemars::JsonSchema for NodeSelectorTerm {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "matchExpressions".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -128,8 +254,8 @@ impl crate::schemars::JsonSchema for NodeSelectorTerm {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "matchFields".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -143,11 +269,22 @@ impl crate::schemars::JsonSchema for NodeSelectorTerm {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for NodeSelectorTerm {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(NodeSelectorTerm {
+            match_expressions: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            match_fields: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
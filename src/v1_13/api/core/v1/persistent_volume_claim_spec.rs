@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.PersistentVolumeClaimSpec
 
 /// PersistentVolumeClaimSpec describes the common attributes of storage devices and allows a Source for provider-specific attributes
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct PersistentVolumeClaimSpec {
     /// AccessModes contains the desired access modes the volume should have. More info: https://kubernetes.io/docs/concepts/storage/persistent-volumes#access-modes-1
     pub access_modes: Option<Vec<String>>,
@@ -25,49 +25,73 @@ pub struct PersistentVolumeClaimSpec {
     pub volume_name: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for PersistentVolumeClaimSpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_access_modes,
-            Key_data_source,
-            Key_resources,
-            Key_selector,
-            Key_storage_class_name,
-            Key_volume_mode,
-            Key_volume_name,
-            Other,
-        }
+impl PersistentVolumeClaimSpec {
+    /// Returns this value with [`PersistentVolumeClaimSpec::access_modes`] set to the given value.
+    #[must_use]
+    pub fn with_access_modes(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.access_modes = Some(value.into());
+        self
+    }
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+    /// Returns this value with [`PersistentVolumeClaimSpec::data_source`] set to the given value.
+    #[must_use]
+    pub fn with_data_source(mut self, value: impl Into<crate::api::core::v1::TypedLocalObjectReference>) -> Self {
+        self.data_source = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`PersistentVolumeClaimSpec::resources`] set to the given value.
+    #[must_use]
+    pub fn with_resources(mut self, value: impl Into<crate::api::core::v1::ResourceRequirements>) -> Self {
+        self.resources = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`PersistentVolumeClaimSpec::selector`] set to the given value.
+    #[must_use]
+    pub fn with_selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.selector = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "accessModes" => Field::Key_access_modes,
-                            "dataSource" => Field::Key_data_source,
-                            "resources" => Field::Key_resources,
-                            "selector" => Field::Key_selector,
-                            "storageClassName" => Field::Key_storage_class_name,
-                            "volumeMode" => Field::Key_volume_mode,
-                            "volumeName" => Field::Key_volume_name,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`PersistentVolumeClaimSpec::storage_class_name`] set to the given value.
+    #[must_use]
+    pub fn with_storage_class_name(mut self, value: impl Into<String>) -> Self {
+        self.storage_class_name = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`PersistentVolumeClaimSpec::volume_mode`] set to the given value.
+    #[must_use]
+    pub fn with_volume_mode(mut self, value: impl Into<String>) -> Self {
+        self.volume_mode = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PersistentVolumeClaimSpec::volume_name`] set to the given value.
+    #[must_use]
+    pub fn with_volume_name(mut self, value: impl Into<String>) -> Self {
+        self.volume_name = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for PersistentVolumeClaimSpec {}
 
+impl PersistentVolumeClaimSpec {
+    const FIELDS: &'static [&'static str] = &[
+        "accessModes",
+        "dataSource",
+        "resources",
+        "selector",
+        "storageClassName",
+        "volumeMode",
+        "volumeName",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for PersistentVolumeClaimSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -86,16 +110,16 @@ impl<'de> crate::serde::Deserialize<'de> for PersistentVolumeClaimSpec {
                 let mut value_volume_mode: Option<String> = None;
                 let mut value_volume_name: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PersistentVolumeClaimSpec", PersistentVolumeClaimSpec::FIELDS))? {
                     match key {
-                        Field::Key_access_modes => value_access_modes = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_data_source => value_data_source = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_resources => value_resources = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_selector => value_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_storage_class_name => value_storage_class_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_volume_mode => value_volume_mode = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_volume_name => value_volume_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_access_modes = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_data_source = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_resources = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_storage_class_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_volume_mode = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_volume_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -109,26 +133,118 @@ impl<'de> crate::serde::Deserialize<'de> for PersistentVolumeClaimSpec {
                     volume_name: value_volume_name,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(PersistentVolumeClaimSpec {
+                    access_modes: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    data_source: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    resources: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    selector: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    storage_class_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    volume_mode: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    volume_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "PersistentVolumeClaimSpec",
-            &[
-                "accessModes",
-                "dataSource",
-                "resources",
-                "selector",
-                "storageClassName",
-                "volumeMode",
-                "volumeName",
-            ],
+            PersistentVolumeClaimSpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, PersistentVolumeClaimSpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("PersistentVolumeClaimSpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_access_modes = false;
+                let mut has_data_source = false;
+                let mut has_resources = false;
+                let mut has_selector = false;
+                let mut has_storage_class_name = false;
+                let mut has_volume_mode = false;
+                let mut has_volume_name = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PersistentVolumeClaimSpec", PersistentVolumeClaimSpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.access_modes))?; has_access_modes = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.data_source))?; has_data_source = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.resources))?; has_resources = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.selector))?; has_selector = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.storage_class_name))?; has_storage_class_name = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.volume_mode))?; has_volume_mode = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.volume_name))?; has_volume_name = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_access_modes {
+                    self.0.access_modes = None;
+                }
+                if !has_data_source {
+                    self.0.data_source = None;
+                }
+                if !has_resources {
+                    self.0.resources = None;
+                }
+                if !has_selector {
+                    self.0.selector = None;
+                }
+                if !has_storage_class_name {
+                    self.0.storage_class_name = None;
+                }
+                if !has_volume_mode {
+                    self.0.volume_mode = None;
+                }
+                if !has_volume_name {
+                    self.0.volume_name = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.access_modes))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.data_source))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.resources))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.selector))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.storage_class_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.volume_mode))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.volume_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "PersistentVolumeClaimSpec",
+            PersistentVolumeClaimSpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for PersistentVolumeClaimSpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("PersistentVolumeClaimSpec", 7)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "accessModes", &self.access_modes)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "dataSource", &self.data_source)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resources", &self.resources)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selector", &self.selector)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "storageClassName", &self.storage_class_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeMode", &self.volume_mode)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeName", &self.volume_name)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "PersistentVolumeClaimSpec",
             self.access_modes.as_ref().map_or(0, |_| 1) +
@@ -164,10 +280,159 @@ impl crate::serde::Serialize for PersistentVolumeClaimSpec {
     }
 }
 
+/// A builder of [`PersistentVolumeClaimSpec`]. Created with [`PersistentVolumeClaimSpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct PersistentVolumeClaimSpecBuilder {
+    access_modes: Option<Vec<String>>,
+    data_source: Option<crate::api::core::v1::TypedLocalObjectReference>,
+    resources: Option<crate::api::core::v1::ResourceRequirements>,
+    selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>,
+    storage_class_name: Option<String>,
+    volume_mode: Option<String>,
+    volume_name: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl PersistentVolumeClaimSpec {
+    /// Starts building a `PersistentVolumeClaimSpec`. Its required fields must be set before [`PersistentVolumeClaimSpecBuilder::build`] is called.
+    pub fn builder() -> PersistentVolumeClaimSpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl PersistentVolumeClaimSpecBuilder {
+    /// Sets [`PersistentVolumeClaimSpec::access_modes`].
+    pub fn access_modes(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.access_modes = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeClaimSpec::data_source`].
+    pub fn data_source(mut self, value: impl Into<crate::api::core::v1::TypedLocalObjectReference>) -> Self {
+        self.data_source = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeClaimSpec::resources`].
+    pub fn resources(mut self, value: impl Into<crate::api::core::v1::ResourceRequirements>) -> Self {
+        self.resources = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeClaimSpec::selector`].
+    pub fn selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeClaimSpec::storage_class_name`].
+    pub fn storage_class_name(mut self, value: impl Into<String>) -> Self {
+        self.storage_class_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeClaimSpec::volume_mode`].
+    pub fn volume_mode(mut self, value: impl Into<String>) -> Self {
+        self.volume_mode = Some(value.into());
+        self
+    }
+
+    /// Sets [`PersistentVolumeClaimSpec::volume_name`].
+    pub fn volume_name(mut self, value: impl Into<String>) -> Self {
+        self.volume_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `PersistentVolumeClaimSpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<PersistentVolumeClaimSpec, crate::MissingFieldError> {
+        Ok(PersistentVolumeClaimSpec {
+            access_modes: self.access_modes,
+            data_source: self.data_source,
+            resources: self.resources,
+            selector: self.selector,
+            storage_class_name: self.storage_class_name,
+            volume_mode: self.volume_mode,
+            volume_name: self.volume_name,
+        })
+    }
+}
+
+/// The apply configuration of [`PersistentVolumeClaimSpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PersistentVolumeClaimSpecApplyConfiguration {
+    /// See [`PersistentVolumeClaimSpec::access_modes`].
+    pub access_modes: Option<Vec<String>>,
+
+    /// See [`PersistentVolumeClaimSpec::data_source`].
+    pub data_source: Option<crate::api::core::v1::TypedLocalObjectReferenceApplyConfiguration>,
+
+    /// See [`PersistentVolumeClaimSpec::resources`].
+    pub resources: Option<crate::api::core::v1::ResourceRequirementsApplyConfiguration>,
+
+    /// See [`PersistentVolumeClaimSpec::selector`].
+    pub selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorApplyConfiguration>,
+
+    /// See [`PersistentVolumeClaimSpec::storage_class_name`].
+    pub storage_class_name: Option<String>,
+
+    /// See [`PersistentVolumeClaimSpec::volume_mode`].
+    pub volume_mode: Option<String>,
+
+    /// See [`PersistentVolumeClaimSpec::volume_name`].
+    pub volume_name: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for PersistentVolumeClaimSpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "PersistentVolumeClaimSpecApplyConfiguration",
+            self.access_modes.as_ref().map_or(0, |_| 1) +
+            self.data_source.as_ref().map_or(0, |_| 1) +
+            self.resources.as_ref().map_or(0, |_| 1) +
+            self.selector.as_ref().map_or(0, |_| 1) +
+            self.storage_class_name.as_ref().map_or(0, |_| 1) +
+            self.volume_mode.as_ref().map_or(0, |_| 1) +
+            self.volume_name.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.access_modes {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "accessModes", value)?;
+        }
+        if let Some(value) = &self.data_source {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "dataSource", value)?;
+        }
+        if let Some(value) = &self.resources {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resources", value)?;
+        }
+        if let Some(value) = &self.selector {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selector", value)?;
+        }
+        if let Some(value) = &self.storage_class_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "storageClassName", value)?;
+        }
+        if let Some(value) = &self.volume_mode {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeMode", value)?;
+        }
+        if let Some(value) = &self.volume_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeName", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PersistentVolumeClaimSpec {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.PersistentVolumeClaimSpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::PersistentVolumeClaimSpec".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.PersistentVolumeClaimSpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -178,8 +443,9 @@ impl crate::schemars::JsonSchema for PersistentVolumeClaimSpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "accessModes".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -198,8 +464,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeClaimSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "dataSource".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::TypedLocalObjectReference>().into_object();
@@ -209,8 +475,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeClaimSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "resources".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ResourceRequirements>().into_object();
@@ -220,8 +486,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeClaimSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "selector".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>().into_object();
@@ -231,8 +497,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeClaimSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "storageClassName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -242,8 +508,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeClaimSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "volumeMode".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -253,8 +519,8 @@ impl crate::schemars::JsonSchema for PersistentVolumeClaimSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "volumeName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -264,11 +530,27 @@ impl crate::schemars::JsonSchema for PersistentVolumeClaimSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for PersistentVolumeClaimSpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(PersistentVolumeClaimSpec {
+            access_modes: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            data_source: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            resources: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            selector: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            storage_class_name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            volume_mode: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            volume_name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -0,0 +1,158 @@
+// Generated from definition io.k8s.api.core.v1.PersistentVolumeReclaimPolicy
+
+/// PersistentVolumeReclaimPolicy describes a policy for end-of-life maintenance of persistent volumes.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum PersistentVolumeReclaimPolicy {
+    /// `Delete`
+    Delete,
+
+    /// `Recycle`
+    Recycle,
+
+    /// `Retain`
+    Retain,
+
+    /// A value that is not one of the values known to this version of the crate, such as one that was added in a newer version of Kubernetes.
+    Other(String),
+}
+
+impl PersistentVolumeReclaimPolicy {
+    /// The values known to this version of the crate, in the order of the variants.
+    pub const VALUES: &'static [&'static str] = &["Delete", "Recycle", "Retain"];
+
+    /// The value as it is serialized.
+    pub fn as_str(&self) -> &str {
+        match self {
+            PersistentVolumeReclaimPolicy::Delete => "Delete",
+            PersistentVolumeReclaimPolicy::Recycle => "Recycle",
+            PersistentVolumeReclaimPolicy::Retain => "Retain",
+            PersistentVolumeReclaimPolicy::Other(value) => value,
+        }
+    }
+}
+
+impl AsRef<str> for PersistentVolumeReclaimPolicy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Default for PersistentVolumeReclaimPolicy {
+    fn default() -> Self {
+        PersistentVolumeReclaimPolicy::Other(String::new())
+    }
+}
+
+impl std::fmt::Display for PersistentVolumeReclaimPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for PersistentVolumeReclaimPolicy {
+    fn from(value: &str) -> Self {
+        match value {
+            "Delete" => PersistentVolumeReclaimPolicy::Delete,
+            "Recycle" => PersistentVolumeReclaimPolicy::Recycle,
+            "Retain" => PersistentVolumeReclaimPolicy::Retain,
+            value => PersistentVolumeReclaimPolicy::Other(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PersistentVolumeReclaimPolicy {
+    fn from(value: String) -> Self {
+        match &*value {
+            "Delete" => PersistentVolumeReclaimPolicy::Delete,
+            "Recycle" => PersistentVolumeReclaimPolicy::Recycle,
+            "Retain" => PersistentVolumeReclaimPolicy::Retain,
+            _ => PersistentVolumeReclaimPolicy::Other(value),
+        }
+    }
+}
+
+impl From<PersistentVolumeReclaimPolicy> for String {
+    fn from(value: PersistentVolumeReclaimPolicy) -> Self {
+        match value {
+            PersistentVolumeReclaimPolicy::Other(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl PartialEq<str> for PersistentVolumeReclaimPolicy {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for PersistentVolumeReclaimPolicy {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<'de> crate::serde::Deserialize<'de> for PersistentVolumeReclaimPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl crate::serde::de::Visitor<'_> for Visitor {
+            type Value = PersistentVolumeReclaimPolicy;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("PersistentVolumeReclaimPolicy")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
+                Ok(v.into())
+            }
+
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E> where E: crate::serde::de::Error {
+                Ok(v.into())
+            }
+        }
+
+        deserializer.deserialize_string(Visitor)
+    }
+}
+
+impl crate::serde::Serialize for PersistentVolumeReclaimPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for PersistentVolumeReclaimPolicy {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::PersistentVolumeReclaimPolicy".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.PersistentVolumeReclaimPolicy".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("PersistentVolumeReclaimPolicy describes a policy for end-of-life maintenance of persistent volumes.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for PersistentVolumeReclaimPolicy {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        Ok(match u.int_in_range(0..=3_u8)? {
+            0 => PersistentVolumeReclaimPolicy::Delete,
+            1 => PersistentVolumeReclaimPolicy::Recycle,
+            2 => PersistentVolumeReclaimPolicy::Retain,
+            _ => PersistentVolumeReclaimPolicy::from(u.arbitrary::<String>()?),
+        })
+    }
+}
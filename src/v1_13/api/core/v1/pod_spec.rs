@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.PodSpec
 
 /// PodSpec is a description of a pod.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct PodSpec {
     /// Optional duration in seconds the pod may be active on the node relative to StartTime before the system will actively try to mark it failed and kill associated containers. Value must be a positive integer.
     pub active_deadline_seconds: Option<i64>,
@@ -19,7 +19,7 @@ pub struct PodSpec {
     pub dns_config: Option<crate::api::core::v1::PodDNSConfig>,
 
     /// Set DNS policy for the pod. Defaults to "ClusterFirst". Valid values are 'ClusterFirstWithHostNet', 'ClusterFirst', 'Default' or 'None'. DNS parameters given in DNSConfig will be merged with the policy selected with DNSPolicy. To have DNS options set along with hostNetwork, you have to specify DNS policy explicitly to 'ClusterFirstWithHostNet'.
-    pub dns_policy: Option<String>,
+    pub dns_policy: Option<crate::api::core::v1::DNSPolicy>,
 
     /// EnableServiceLinks indicates whether information about services should be injected into pod's environment variables, matching the syntax of Docker links. Optional: Defaults to true.
     pub enable_service_links: Option<bool>,
@@ -61,7 +61,7 @@ pub struct PodSpec {
     pub readiness_gates: Option<Vec<crate::api::core::v1::PodReadinessGate>>,
 
     /// Restart policy for all containers within the pod. One of Always, OnFailure, Never. Default to Always. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle/#restart-policy
-    pub restart_policy: Option<String>,
+    pub restart_policy: Option<crate::api::core::v1::RestartPolicy>,
 
     /// RuntimeClassName refers to a RuntimeClass object in the node.k8s.io group, which should be used to run this pod.  If no RuntimeClass resource matches the named class, the pod will not be run. If unset or empty, the "legacy" RuntimeClass will be used, which is an implicit class with an empty definition that uses the default runtime handler. More info: https://github.com/kubernetes/community/blob/master/keps/sig-node/0014-runtime-class.md This is an alpha feature and may change in the future.
     pub runtime_class_name: Option<String>,
@@ -73,6 +73,7 @@ pub struct PodSpec {
     pub security_context: Option<crate::api::core::v1::PodSecurityContext>,
 
     /// DeprecatedServiceAccount is a depreciated alias for ServiceAccountName. Deprecated: Use serviceAccountName instead.
+    #[deprecated(note = "Use serviceAccountName instead.")]
     pub service_account: Option<String>,
 
     /// ServiceAccountName is the name of the ServiceAccount to use to run this pod. More info: https://kubernetes.io/docs/tasks/configure-pod-container/configure-service-account/
@@ -94,95 +95,303 @@ pub struct PodSpec {
     pub volumes: Option<Vec<crate::api::core::v1::Volume>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for PodSpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_active_deadline_seconds,
-            Key_affinity,
-            Key_automount_service_account_token,
-            Key_containers,
-            Key_dns_config,
-            Key_dns_policy,
-            Key_enable_service_links,
-            Key_host_aliases,
-            Key_host_ipc,
-            Key_host_network,
-            Key_host_pid,
-            Key_hostname,
-            Key_image_pull_secrets,
-            Key_init_containers,
-            Key_node_name,
-            Key_node_selector,
-            Key_priority,
-            Key_priority_class_name,
-            Key_readiness_gates,
-            Key_restart_policy,
-            Key_runtime_class_name,
-            Key_scheduler_name,
-            Key_security_context,
-            Key_service_account,
-            Key_service_account_name,
-            Key_share_process_namespace,
-            Key_subdomain,
-            Key_termination_grace_period_seconds,
-            Key_tolerations,
-            Key_volumes,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl PodSpec {
+    /// Constructs a `PodSpec` from its required fields. Its optional fields are unset.
+    pub fn new(containers: impl Into<Vec<crate::api::core::v1::Container>>) -> Self {
+        PodSpec {
+            active_deadline_seconds: None,
+            affinity: None,
+            automount_service_account_token: None,
+            containers: containers.into(),
+            dns_config: None,
+            dns_policy: None,
+            enable_service_links: None,
+            host_aliases: None,
+            host_ipc: None,
+            host_network: None,
+            host_pid: None,
+            hostname: None,
+            image_pull_secrets: None,
+            init_containers: None,
+            node_name: None,
+            node_selector: None,
+            priority: None,
+            priority_class_name: None,
+            readiness_gates: None,
+            restart_policy: None,
+            runtime_class_name: None,
+            scheduler_name: None,
+            security_context: None,
+            service_account: None,
+            service_account_name: None,
+            share_process_namespace: None,
+            subdomain: None,
+            termination_grace_period_seconds: None,
+            tolerations: None,
+            volumes: None,
+        }
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "activeDeadlineSeconds" => Field::Key_active_deadline_seconds,
-                            "affinity" => Field::Key_affinity,
-                            "automountServiceAccountToken" => Field::Key_automount_service_account_token,
-                            "containers" => Field::Key_containers,
-                            "dnsConfig" => Field::Key_dns_config,
-                            "dnsPolicy" => Field::Key_dns_policy,
-                            "enableServiceLinks" => Field::Key_enable_service_links,
-                            "hostAliases" => Field::Key_host_aliases,
-                            "hostIPC" => Field::Key_host_ipc,
-                            "hostNetwork" => Field::Key_host_network,
-                            "hostPID" => Field::Key_host_pid,
-                            "hostname" => Field::Key_hostname,
-                            "imagePullSecrets" => Field::Key_image_pull_secrets,
-                            "initContainers" => Field::Key_init_containers,
-                            "nodeName" => Field::Key_node_name,
-                            "nodeSelector" => Field::Key_node_selector,
-                            "priority" => Field::Key_priority,
-                            "priorityClassName" => Field::Key_priority_class_name,
-                            "readinessGates" => Field::Key_readiness_gates,
-                            "restartPolicy" => Field::Key_restart_policy,
-                            "runtimeClassName" => Field::Key_runtime_class_name,
-                            "schedulerName" => Field::Key_scheduler_name,
-                            "securityContext" => Field::Key_security_context,
-                            "serviceAccount" => Field::Key_service_account,
-                            "serviceAccountName" => Field::Key_service_account_name,
-                            "shareProcessNamespace" => Field::Key_share_process_namespace,
-                            "subdomain" => Field::Key_subdomain,
-                            "terminationGracePeriodSeconds" => Field::Key_termination_grace_period_seconds,
-                            "tolerations" => Field::Key_tolerations,
-                            "volumes" => Field::Key_volumes,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl PodSpec {
+    /// Returns this value with [`PodSpec::active_deadline_seconds`] set to the given value.
+    #[must_use]
+    pub fn with_active_deadline_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.active_deadline_seconds = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`PodSpec::affinity`] set to the given value.
+    #[must_use]
+    pub fn with_affinity(mut self, value: impl Into<crate::api::core::v1::Affinity>) -> Self {
+        self.affinity = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSpec::automount_service_account_token`] set to the given value.
+    #[must_use]
+    pub fn with_automount_service_account_token(mut self, value: impl Into<bool>) -> Self {
+        self.automount_service_account_token = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSpec::containers`] set to the given value.
+    #[must_use]
+    pub fn with_containers(mut self, value: impl Into<Vec<crate::api::core::v1::Container>>) -> Self {
+        self.containers = value.into();
+        self
+    }
+
+    /// Returns this value with [`PodSpec::dns_config`] set to the given value.
+    #[must_use]
+    pub fn with_dns_config(mut self, value: impl Into<crate::api::core::v1::PodDNSConfig>) -> Self {
+        self.dns_config = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSpec::dns_policy`] set to the given value.
+    #[must_use]
+    pub fn with_dns_policy(mut self, value: impl Into<crate::api::core::v1::DNSPolicy>) -> Self {
+        self.dns_policy = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSpec::enable_service_links`] set to the given value.
+    #[must_use]
+    pub fn with_enable_service_links(mut self, value: impl Into<bool>) -> Self {
+        self.enable_service_links = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSpec::host_aliases`] set to the given value.
+    #[must_use]
+    pub fn with_host_aliases(mut self, value: impl Into<Vec<crate::api::core::v1::HostAlias>>) -> Self {
+        self.host_aliases = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSpec::host_ipc`] set to the given value.
+    #[must_use]
+    pub fn with_host_ipc(mut self, value: impl Into<bool>) -> Self {
+        self.host_ipc = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSpec::host_network`] set to the given value.
+    #[must_use]
+    pub fn with_host_network(mut self, value: impl Into<bool>) -> Self {
+        self.host_network = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSpec::host_pid`] set to the given value.
+    #[must_use]
+    pub fn with_host_pid(mut self, value: impl Into<bool>) -> Self {
+        self.host_pid = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSpec::hostname`] set to the given value.
+    #[must_use]
+    pub fn with_hostname(mut self, value: impl Into<String>) -> Self {
+        self.hostname = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSpec::image_pull_secrets`] set to the given value.
+    #[must_use]
+    pub fn with_image_pull_secrets(mut self, value: impl Into<Vec<crate::api::core::v1::LocalObjectReference>>) -> Self {
+        self.image_pull_secrets = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSpec::init_containers`] set to the given value.
+    #[must_use]
+    pub fn with_init_containers(mut self, value: impl Into<Vec<crate::api::core::v1::Container>>) -> Self {
+        self.init_containers = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSpec::node_name`] set to the given value.
+    #[must_use]
+    pub fn with_node_name(mut self, value: impl Into<String>) -> Self {
+        self.node_name = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSpec::node_selector`] set to the given value.
+    #[must_use]
+    pub fn with_node_selector(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.node_selector = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSpec::priority`] set to the given value.
+    #[must_use]
+    pub fn with_priority(mut self, value: impl Into<i32>) -> Self {
+        self.priority = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSpec::priority_class_name`] set to the given value.
+    #[must_use]
+    pub fn with_priority_class_name(mut self, value: impl Into<String>) -> Self {
+        self.priority_class_name = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSpec::readiness_gates`] set to the given value.
+    #[must_use]
+    pub fn with_readiness_gates(mut self, value: impl Into<Vec<crate::api::core::v1::PodReadinessGate>>) -> Self {
+        self.readiness_gates = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSpec::restart_policy`] set to the given value.
+    #[must_use]
+    pub fn with_restart_policy(mut self, value: impl Into<crate::api::core::v1::RestartPolicy>) -> Self {
+        self.restart_policy = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSpec::runtime_class_name`] set to the given value.
+    #[must_use]
+    pub fn with_runtime_class_name(mut self, value: impl Into<String>) -> Self {
+        self.runtime_class_name = Some(value.into());
+        self
+    }
 
+    /// Returns this value with [`PodSpec::scheduler_name`] set to the given value.
+    #[must_use]
+    pub fn with_scheduler_name(mut self, value: impl Into<String>) -> Self {
+        self.scheduler_name = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSpec::security_context`] set to the given value.
+    #[must_use]
+    pub fn with_security_context(mut self, value: impl Into<crate::api::core::v1::PodSecurityContext>) -> Self {
+        self.security_context = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSpec::service_account`] set to the given value.
+    #[must_use]
+    pub fn with_service_account(mut self, value: impl Into<String>) -> Self {
+        self.service_account = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSpec::service_account_name`] set to the given value.
+    #[must_use]
+    pub fn with_service_account_name(mut self, value: impl Into<String>) -> Self {
+        self.service_account_name = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSpec::share_process_namespace`] set to the given value.
+    #[must_use]
+    pub fn with_share_process_namespace(mut self, value: impl Into<bool>) -> Self {
+        self.share_process_namespace = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSpec::subdomain`] set to the given value.
+    #[must_use]
+    pub fn with_subdomain(mut self, value: impl Into<String>) -> Self {
+        self.subdomain = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSpec::termination_grace_period_seconds`] set to the given value.
+    #[must_use]
+    pub fn with_termination_grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.termination_grace_period_seconds = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSpec::tolerations`] set to the given value.
+    #[must_use]
+    pub fn with_tolerations(mut self, value: impl Into<Vec<crate::api::core::v1::Toleration>>) -> Self {
+        self.tolerations = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSpec::volumes`] set to the given value.
+    #[must_use]
+    pub fn with_volumes(mut self, value: impl Into<Vec<crate::api::core::v1::Volume>>) -> Self {
+        self.volumes = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for PodSpec {
+    const FIELD_PATCH_STRATEGIES: &'static [crate::FieldPatchStrategy] = &[
+        crate::FieldPatchStrategy { field: "containers", merge: true, retain_keys: false, merge_key: Some("name") },
+        crate::FieldPatchStrategy { field: "hostAliases", merge: true, retain_keys: false, merge_key: Some("ip") },
+        crate::FieldPatchStrategy { field: "imagePullSecrets", merge: true, retain_keys: false, merge_key: Some("name") },
+        crate::FieldPatchStrategy { field: "initContainers", merge: true, retain_keys: false, merge_key: Some("name") },
+        crate::FieldPatchStrategy { field: "volumes", merge: true, retain_keys: true, merge_key: Some("name") },
+    ];
+}
+
+impl PodSpec {
+    const FIELDS: &'static [&'static str] = &[
+        "activeDeadlineSeconds",
+        "affinity",
+        "automountServiceAccountToken",
+        "containers",
+        "dnsConfig",
+        "dnsPolicy",
+        "enableServiceLinks",
+        "hostAliases",
+        "hostIPC",
+        "hostNetwork",
+        "hostPID",
+        "hostname",
+        "imagePullSecrets",
+        "initContainers",
+        "nodeName",
+        "nodeSelector",
+        "priority",
+        "priorityClassName",
+        "readinessGates",
+        "restartPolicy",
+        "runtimeClassName",
+        "schedulerName",
+        "securityContext",
+        "serviceAccount",
+        "serviceAccountName",
+        "shareProcessNamespace",
+        "subdomain",
+        "terminationGracePeriodSeconds",
+        "tolerations",
+        "volumes",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for PodSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -198,7 +407,7 @@ impl<'de> crate::serde::Deserialize<'de> for PodSpec {
                 let mut value_automount_service_account_token: Option<bool> = None;
                 let mut value_containers: Option<Vec<crate::api::core::v1::Container>> = None;
                 let mut value_dns_config: Option<crate::api::core::v1::PodDNSConfig> = None;
-                let mut value_dns_policy: Option<String> = None;
+                let mut value_dns_policy: Option<crate::api::core::v1::DNSPolicy> = None;
                 let mut value_enable_service_links: Option<bool> = None;
                 let mut value_host_aliases: Option<Vec<crate::api::core::v1::HostAlias>> = None;
                 let mut value_host_ipc: Option<bool> = None;
@@ -212,7 +421,7 @@ impl<'de> crate::serde::Deserialize<'de> for PodSpec {
                 let mut value_priority: Option<i32> = None;
                 let mut value_priority_class_name: Option<String> = None;
                 let mut value_readiness_gates: Option<Vec<crate::api::core::v1::PodReadinessGate>> = None;
-                let mut value_restart_policy: Option<String> = None;
+                let mut value_restart_policy: Option<crate::api::core::v1::RestartPolicy> = None;
                 let mut value_runtime_class_name: Option<String> = None;
                 let mut value_scheduler_name: Option<String> = None;
                 let mut value_security_context: Option<crate::api::core::v1::PodSecurityContext> = None;
@@ -224,39 +433,39 @@ impl<'de> crate::serde::Deserialize<'de> for PodSpec {
                 let mut value_tolerations: Option<Vec<crate::api::core::v1::Toleration>> = None;
                 let mut value_volumes: Option<Vec<crate::api::core::v1::Volume>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodSpec", PodSpec::FIELDS))? {
                     match key {
-                        Field::Key_active_deadline_seconds => value_active_deadline_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_affinity => value_affinity = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_automount_service_account_token => value_automount_service_account_token = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_containers => value_containers = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_dns_config => value_dns_config = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_dns_policy => value_dns_policy = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_enable_service_links => value_enable_service_links = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_host_aliases => value_host_aliases = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_host_ipc => value_host_ipc = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_host_network => value_host_network = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_host_pid => value_host_pid = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_hostname => value_hostname = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_image_pull_secrets => value_image_pull_secrets = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_init_containers => value_init_containers = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_node_name => value_node_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_node_selector => value_node_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_priority => value_priority = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_priority_class_name => value_priority_class_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_readiness_gates => value_readiness_gates = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_restart_policy => value_restart_policy = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_runtime_class_name => value_runtime_class_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_scheduler_name => value_scheduler_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_security_context => value_security_context = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_service_account => value_service_account = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_service_account_name => value_service_account_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_share_process_namespace => value_share_process_namespace = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_subdomain => value_subdomain = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_termination_grace_period_seconds => value_termination_grace_period_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_tolerations => value_tolerations = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_volumes => value_volumes = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_active_deadline_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_affinity = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_automount_service_account_token = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_containers = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(4) => value_dns_config = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_dns_policy = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_enable_service_links = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(7) => value_host_aliases = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(8) => value_host_ipc = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(9) => value_host_network = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(10) => value_host_pid = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(11) => value_hostname = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(12) => value_image_pull_secrets = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(13) => value_init_containers = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(14) => value_node_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(15) => value_node_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(16) => value_priority = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(17) => value_priority_class_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(18) => value_readiness_gates = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(19) => value_restart_policy = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(20) => value_runtime_class_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(21) => value_scheduler_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(22) => value_security_context = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(23) => value_service_account = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(24) => value_service_account_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(25) => value_share_process_namespace = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(26) => value_subdomain = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(27) => value_termination_grace_period_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(28) => value_tolerations = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(29) => value_volumes = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -264,7 +473,7 @@ impl<'de> crate::serde::Deserialize<'de> for PodSpec {
                     active_deadline_seconds: value_active_deadline_seconds,
                     affinity: value_affinity,
                     automount_service_account_token: value_automount_service_account_token,
-                    containers: value_containers.ok_or_else(|| crate::serde::de::Error::missing_field("containers"))?,
+                    containers: value_containers.ok_or_else(|| crate::serde2::missing_field("PodSpec", "containers", &["containers"]))?,
                     dns_config: value_dns_config,
                     dns_policy: value_dns_policy,
                     enable_service_links: value_enable_service_links,
@@ -293,49 +502,302 @@ impl<'de> crate::serde::Deserialize<'de> for PodSpec {
                     volumes: value_volumes,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(PodSpec {
+                    active_deadline_seconds: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    affinity: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    automount_service_account_token: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    containers: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    dns_config: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    dns_policy: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    enable_service_links: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    host_aliases: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                    host_ipc: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?,
+                    host_network: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?,
+                    host_pid: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?,
+                    hostname: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(11, &self))?,
+                    image_pull_secrets: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(12, &self))?,
+                    init_containers: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(13, &self))?,
+                    node_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(14, &self))?,
+                    node_selector: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(15, &self))?,
+                    priority: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(16, &self))?,
+                    priority_class_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(17, &self))?,
+                    readiness_gates: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(18, &self))?,
+                    restart_policy: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(19, &self))?,
+                    runtime_class_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(20, &self))?,
+                    scheduler_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(21, &self))?,
+                    security_context: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(22, &self))?,
+                    service_account: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(23, &self))?,
+                    service_account_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(24, &self))?,
+                    share_process_namespace: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(25, &self))?,
+                    subdomain: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(26, &self))?,
+                    termination_grace_period_seconds: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(27, &self))?,
+                    tolerations: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(28, &self))?,
+                    volumes: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(29, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "PodSpec",
-            &[
-                "activeDeadlineSeconds",
-                "affinity",
-                "automountServiceAccountToken",
-                "containers",
-                "dnsConfig",
-                "dnsPolicy",
-                "enableServiceLinks",
-                "hostAliases",
-                "hostIPC",
-                "hostNetwork",
-                "hostPID",
-                "hostname",
-                "imagePullSecrets",
-                "initContainers",
-                "nodeName",
-                "nodeSelector",
-                "priority",
-                "priorityClassName",
-                "readinessGates",
-                "restartPolicy",
-                "runtimeClassName",
-                "schedulerName",
-                "securityContext",
-                "serviceAccount",
-                "serviceAccountName",
-                "shareProcessNamespace",
-                "subdomain",
-                "terminationGracePeriodSeconds",
-                "tolerations",
-                "volumes",
-            ],
+            PodSpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, PodSpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("PodSpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_active_deadline_seconds = false;
+                let mut has_affinity = false;
+                let mut has_automount_service_account_token = false;
+                let mut has_containers = false;
+                let mut has_dns_config = false;
+                let mut has_dns_policy = false;
+                let mut has_enable_service_links = false;
+                let mut has_host_aliases = false;
+                let mut has_host_ipc = false;
+                let mut has_host_network = false;
+                let mut has_host_pid = false;
+                let mut has_hostname = false;
+                let mut has_image_pull_secrets = false;
+                let mut has_init_containers = false;
+                let mut has_node_name = false;
+                let mut has_node_selector = false;
+                let mut has_priority = false;
+                let mut has_priority_class_name = false;
+                let mut has_readiness_gates = false;
+                let mut has_restart_policy = false;
+                let mut has_runtime_class_name = false;
+                let mut has_scheduler_name = false;
+                let mut has_security_context = false;
+                let mut has_service_account = false;
+                let mut has_service_account_name = false;
+                let mut has_share_process_namespace = false;
+                let mut has_subdomain = false;
+                let mut has_termination_grace_period_seconds = false;
+                let mut has_tolerations = false;
+                let mut has_volumes = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodSpec", PodSpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.active_deadline_seconds))?; has_active_deadline_seconds = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.affinity))?; has_affinity = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.automount_service_account_token))?; has_automount_service_account_token = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.containers))?; has_containers = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.dns_config))?; has_dns_config = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.dns_policy))?; has_dns_policy = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.enable_service_links))?; has_enable_service_links = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.host_aliases))?; has_host_aliases = true; },
+                        Some(8) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.host_ipc))?; has_host_ipc = true; },
+                        Some(9) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.host_network))?; has_host_network = true; },
+                        Some(10) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.host_pid))?; has_host_pid = true; },
+                        Some(11) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.hostname))?; has_hostname = true; },
+                        Some(12) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.image_pull_secrets))?; has_image_pull_secrets = true; },
+                        Some(13) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.init_containers))?; has_init_containers = true; },
+                        Some(14) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.node_name))?; has_node_name = true; },
+                        Some(15) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.node_selector))?; has_node_selector = true; },
+                        Some(16) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.priority))?; has_priority = true; },
+                        Some(17) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.priority_class_name))?; has_priority_class_name = true; },
+                        Some(18) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.readiness_gates))?; has_readiness_gates = true; },
+                        Some(19) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.restart_policy))?; has_restart_policy = true; },
+                        Some(20) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.runtime_class_name))?; has_runtime_class_name = true; },
+                        Some(21) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.scheduler_name))?; has_scheduler_name = true; },
+                        Some(22) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.security_context))?; has_security_context = true; },
+                        Some(23) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.service_account))?; has_service_account = true; },
+                        Some(24) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.service_account_name))?; has_service_account_name = true; },
+                        Some(25) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.share_process_namespace))?; has_share_process_namespace = true; },
+                        Some(26) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.subdomain))?; has_subdomain = true; },
+                        Some(27) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.termination_grace_period_seconds))?; has_termination_grace_period_seconds = true; },
+                        Some(28) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.tolerations))?; has_tolerations = true; },
+                        Some(29) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.volumes))?; has_volumes = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_active_deadline_seconds {
+                    self.0.active_deadline_seconds = None;
+                }
+                if !has_affinity {
+                    self.0.affinity = None;
+                }
+                if !has_automount_service_account_token {
+                    self.0.automount_service_account_token = None;
+                }
+                if !has_containers {
+                    return Err(crate::serde2::missing_field("PodSpec", "containers", &["containers"]));
+                }
+                if !has_dns_config {
+                    self.0.dns_config = None;
+                }
+                if !has_dns_policy {
+                    self.0.dns_policy = None;
+                }
+                if !has_enable_service_links {
+                    self.0.enable_service_links = None;
+                }
+                if !has_host_aliases {
+                    self.0.host_aliases = None;
+                }
+                if !has_host_ipc {
+                    self.0.host_ipc = None;
+                }
+                if !has_host_network {
+                    self.0.host_network = None;
+                }
+                if !has_host_pid {
+                    self.0.host_pid = None;
+                }
+                if !has_hostname {
+                    self.0.hostname = None;
+                }
+                if !has_image_pull_secrets {
+                    self.0.image_pull_secrets = None;
+                }
+                if !has_init_containers {
+                    self.0.init_containers = None;
+                }
+                if !has_node_name {
+                    self.0.node_name = None;
+                }
+                if !has_node_selector {
+                    self.0.node_selector = None;
+                }
+                if !has_priority {
+                    self.0.priority = None;
+                }
+                if !has_priority_class_name {
+                    self.0.priority_class_name = None;
+                }
+                if !has_readiness_gates {
+                    self.0.readiness_gates = None;
+                }
+                if !has_restart_policy {
+                    self.0.restart_policy = None;
+                }
+                if !has_runtime_class_name {
+                    self.0.runtime_class_name = None;
+                }
+                if !has_scheduler_name {
+                    self.0.scheduler_name = None;
+                }
+                if !has_security_context {
+                    self.0.security_context = None;
+                }
+                if !has_service_account {
+                    self.0.service_account = None;
+                }
+                if !has_service_account_name {
+                    self.0.service_account_name = None;
+                }
+                if !has_share_process_namespace {
+                    self.0.share_process_namespace = None;
+                }
+                if !has_subdomain {
+                    self.0.subdomain = None;
+                }
+                if !has_termination_grace_period_seconds {
+                    self.0.termination_grace_period_seconds = None;
+                }
+                if !has_tolerations {
+                    self.0.tolerations = None;
+                }
+                if !has_volumes {
+                    self.0.volumes = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.active_deadline_seconds))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.affinity))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.automount_service_account_token))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.containers))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.dns_config))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.dns_policy))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.enable_service_links))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.host_aliases))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.host_ipc))?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.host_network))?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.host_pid))?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.hostname))?.ok_or_else(|| crate::serde::de::Error::invalid_length(11, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.image_pull_secrets))?.ok_or_else(|| crate::serde::de::Error::invalid_length(12, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.init_containers))?.ok_or_else(|| crate::serde::de::Error::invalid_length(13, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.node_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(14, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.node_selector))?.ok_or_else(|| crate::serde::de::Error::invalid_length(15, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.priority))?.ok_or_else(|| crate::serde::de::Error::invalid_length(16, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.priority_class_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(17, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.readiness_gates))?.ok_or_else(|| crate::serde::de::Error::invalid_length(18, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.restart_policy))?.ok_or_else(|| crate::serde::de::Error::invalid_length(19, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.runtime_class_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(20, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.scheduler_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(21, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.security_context))?.ok_or_else(|| crate::serde::de::Error::invalid_length(22, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.service_account))?.ok_or_else(|| crate::serde::de::Error::invalid_length(23, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.service_account_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(24, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.share_process_namespace))?.ok_or_else(|| crate::serde::de::Error::invalid_length(25, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.subdomain))?.ok_or_else(|| crate::serde::de::Error::invalid_length(26, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.termination_grace_period_seconds))?.ok_or_else(|| crate::serde::de::Error::invalid_length(27, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.tolerations))?.ok_or_else(|| crate::serde::de::Error::invalid_length(28, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.volumes))?.ok_or_else(|| crate::serde::de::Error::invalid_length(29, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "PodSpec",
+            PodSpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for PodSpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("PodSpec", 30)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "activeDeadlineSeconds", &self.active_deadline_seconds)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "affinity", &self.affinity)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "automountServiceAccountToken", &self.automount_service_account_token)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "containers", &self.containers)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "dnsConfig", &self.dns_config)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "dnsPolicy", &self.dns_policy)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableServiceLinks", &self.enable_service_links)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostAliases", &self.host_aliases)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostIPC", &self.host_ipc)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostNetwork", &self.host_network)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostPID", &self.host_pid)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostname", &self.hostname)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "imagePullSecrets", &self.image_pull_secrets)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "initContainers", &self.init_containers)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeName", &self.node_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeSelector", &self.node_selector)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "priority", &self.priority)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "priorityClassName", &self.priority_class_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readinessGates", &self.readiness_gates)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "restartPolicy", &self.restart_policy)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "runtimeClassName", &self.runtime_class_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "schedulerName", &self.scheduler_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "securityContext", &self.security_context)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "serviceAccount", &self.service_account)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "serviceAccountName", &self.service_account_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "shareProcessNamespace", &self.share_process_namespace)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "subdomain", &self.subdomain)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "terminationGracePeriodSeconds", &self.termination_grace_period_seconds)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tolerations", &self.tolerations)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumes", &self.volumes)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "PodSpec",
             1 +
@@ -461,10 +923,504 @@ impl crate::serde::Serialize for PodSpec {
     }
 }
 
+/// A builder of [`PodSpec`]. Created with [`PodSpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct PodSpecBuilder {
+    active_deadline_seconds: Option<i64>,
+    affinity: Option<crate::api::core::v1::Affinity>,
+    automount_service_account_token: Option<bool>,
+    containers: Option<Vec<crate::api::core::v1::Container>>,
+    dns_config: Option<crate::api::core::v1::PodDNSConfig>,
+    dns_policy: Option<crate::api::core::v1::DNSPolicy>,
+    enable_service_links: Option<bool>,
+    host_aliases: Option<Vec<crate::api::core::v1::HostAlias>>,
+    host_ipc: Option<bool>,
+    host_network: Option<bool>,
+    host_pid: Option<bool>,
+    hostname: Option<String>,
+    image_pull_secrets: Option<Vec<crate::api::core::v1::LocalObjectReference>>,
+    init_containers: Option<Vec<crate::api::core::v1::Container>>,
+    node_name: Option<String>,
+    node_selector: Option<std::collections::BTreeMap<String, String>>,
+    priority: Option<i32>,
+    priority_class_name: Option<String>,
+    readiness_gates: Option<Vec<crate::api::core::v1::PodReadinessGate>>,
+    restart_policy: Option<crate::api::core::v1::RestartPolicy>,
+    runtime_class_name: Option<String>,
+    scheduler_name: Option<String>,
+    security_context: Option<crate::api::core::v1::PodSecurityContext>,
+    service_account: Option<String>,
+    service_account_name: Option<String>,
+    share_process_namespace: Option<bool>,
+    subdomain: Option<String>,
+    termination_grace_period_seconds: Option<i64>,
+    tolerations: Option<Vec<crate::api::core::v1::Toleration>>,
+    volumes: Option<Vec<crate::api::core::v1::Volume>>,
+}
+
+#[cfg(feature = "builder")]
+impl PodSpec {
+    /// Starts building a `PodSpec`. Its required fields must be set before [`PodSpecBuilder::build`] is called.
+    pub fn builder() -> PodSpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl PodSpecBuilder {
+    /// Sets [`PodSpec::active_deadline_seconds`].
+    pub fn active_deadline_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.active_deadline_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::affinity`].
+    pub fn affinity(mut self, value: impl Into<crate::api::core::v1::Affinity>) -> Self {
+        self.affinity = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::automount_service_account_token`].
+    pub fn automount_service_account_token(mut self, value: impl Into<bool>) -> Self {
+        self.automount_service_account_token = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::containers`].
+    pub fn containers(mut self, value: impl Into<Vec<crate::api::core::v1::Container>>) -> Self {
+        self.containers = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::dns_config`].
+    pub fn dns_config(mut self, value: impl Into<crate::api::core::v1::PodDNSConfig>) -> Self {
+        self.dns_config = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::dns_policy`].
+    pub fn dns_policy(mut self, value: impl Into<crate::api::core::v1::DNSPolicy>) -> Self {
+        self.dns_policy = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::enable_service_links`].
+    pub fn enable_service_links(mut self, value: impl Into<bool>) -> Self {
+        self.enable_service_links = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::host_aliases`].
+    pub fn host_aliases(mut self, value: impl Into<Vec<crate::api::core::v1::HostAlias>>) -> Self {
+        self.host_aliases = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::host_ipc`].
+    pub fn host_ipc(mut self, value: impl Into<bool>) -> Self {
+        self.host_ipc = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::host_network`].
+    pub fn host_network(mut self, value: impl Into<bool>) -> Self {
+        self.host_network = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::host_pid`].
+    pub fn host_pid(mut self, value: impl Into<bool>) -> Self {
+        self.host_pid = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::hostname`].
+    pub fn hostname(mut self, value: impl Into<String>) -> Self {
+        self.hostname = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::image_pull_secrets`].
+    pub fn image_pull_secrets(mut self, value: impl Into<Vec<crate::api::core::v1::LocalObjectReference>>) -> Self {
+        self.image_pull_secrets = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::init_containers`].
+    pub fn init_containers(mut self, value: impl Into<Vec<crate::api::core::v1::Container>>) -> Self {
+        self.init_containers = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::node_name`].
+    pub fn node_name(mut self, value: impl Into<String>) -> Self {
+        self.node_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::node_selector`].
+    pub fn node_selector(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.node_selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::priority`].
+    pub fn priority(mut self, value: impl Into<i32>) -> Self {
+        self.priority = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::priority_class_name`].
+    pub fn priority_class_name(mut self, value: impl Into<String>) -> Self {
+        self.priority_class_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::readiness_gates`].
+    pub fn readiness_gates(mut self, value: impl Into<Vec<crate::api::core::v1::PodReadinessGate>>) -> Self {
+        self.readiness_gates = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::restart_policy`].
+    pub fn restart_policy(mut self, value: impl Into<crate::api::core::v1::RestartPolicy>) -> Self {
+        self.restart_policy = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::runtime_class_name`].
+    pub fn runtime_class_name(mut self, value: impl Into<String>) -> Self {
+        self.runtime_class_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::scheduler_name`].
+    pub fn scheduler_name(mut self, value: impl Into<String>) -> Self {
+        self.scheduler_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::security_context`].
+    pub fn security_context(mut self, value: impl Into<crate::api::core::v1::PodSecurityContext>) -> Self {
+        self.security_context = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::service_account`].
+    pub fn service_account(mut self, value: impl Into<String>) -> Self {
+        self.service_account = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::service_account_name`].
+    pub fn service_account_name(mut self, value: impl Into<String>) -> Self {
+        self.service_account_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::share_process_namespace`].
+    pub fn share_process_namespace(mut self, value: impl Into<bool>) -> Self {
+        self.share_process_namespace = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::subdomain`].
+    pub fn subdomain(mut self, value: impl Into<String>) -> Self {
+        self.subdomain = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::termination_grace_period_seconds`].
+    pub fn termination_grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.termination_grace_period_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::tolerations`].
+    pub fn tolerations(mut self, value: impl Into<Vec<crate::api::core::v1::Toleration>>) -> Self {
+        self.tolerations = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSpec::volumes`].
+    pub fn volumes(mut self, value: impl Into<Vec<crate::api::core::v1::Volume>>) -> Self {
+        self.volumes = Some(value.into());
+        self
+    }
+
+    /// Builds the `PodSpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<PodSpec, crate::MissingFieldError> {
+        Ok(PodSpec {
+            active_deadline_seconds: self.active_deadline_seconds,
+            affinity: self.affinity,
+            automount_service_account_token: self.automount_service_account_token,
+            containers: self.containers.ok_or_else(|| crate::MissingFieldError::new("PodSpec", "containers"))?,
+            dns_config: self.dns_config,
+            dns_policy: self.dns_policy,
+            enable_service_links: self.enable_service_links,
+            host_aliases: self.host_aliases,
+            host_ipc: self.host_ipc,
+            host_network: self.host_network,
+            host_pid: self.host_pid,
+            hostname: self.hostname,
+            image_pull_secrets: self.image_pull_secrets,
+            init_containers: self.init_containers,
+            node_name: self.node_name,
+            node_selector: self.node_selector,
+            priority: self.priority,
+            priority_class_name: self.priority_class_name,
+            readiness_gates: self.readiness_gates,
+            restart_policy: self.restart_policy,
+            runtime_class_name: self.runtime_class_name,
+            scheduler_name: self.scheduler_name,
+            security_context: self.security_context,
+            service_account: self.service_account,
+            service_account_name: self.service_account_name,
+            share_process_namespace: self.share_process_namespace,
+            subdomain: self.subdomain,
+            termination_grace_period_seconds: self.termination_grace_period_seconds,
+            tolerations: self.tolerations,
+            volumes: self.volumes,
+        })
+    }
+}
+
+/// The apply configuration of [`PodSpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodSpecApplyConfiguration {
+    /// See [`PodSpec::active_deadline_seconds`].
+    pub active_deadline_seconds: Option<i64>,
+
+    /// See [`PodSpec::affinity`].
+    pub affinity: Option<crate::api::core::v1::AffinityApplyConfiguration>,
+
+    /// See [`PodSpec::automount_service_account_token`].
+    pub automount_service_account_token: Option<bool>,
+
+    /// See [`PodSpec::containers`].
+    pub containers: Option<Vec<crate::api::core::v1::ContainerApplyConfiguration>>,
+
+    /// See [`PodSpec::dns_config`].
+    pub dns_config: Option<crate::api::core::v1::PodDNSConfigApplyConfiguration>,
+
+    /// See [`PodSpec::dns_policy`].
+    pub dns_policy: Option<crate::api::core::v1::DNSPolicy>,
+
+    /// See [`PodSpec::enable_service_links`].
+    pub enable_service_links: Option<bool>,
+
+    /// See [`PodSpec::host_aliases`].
+    pub host_aliases: Option<Vec<crate::api::core::v1::HostAliasApplyConfiguration>>,
+
+    /// See [`PodSpec::host_ipc`].
+    pub host_ipc: Option<bool>,
+
+    /// See [`PodSpec::host_network`].
+    pub host_network: Option<bool>,
+
+    /// See [`PodSpec::host_pid`].
+    pub host_pid: Option<bool>,
+
+    /// See [`PodSpec::hostname`].
+    pub hostname: Option<String>,
+
+    /// See [`PodSpec::image_pull_secrets`].
+    pub image_pull_secrets: Option<Vec<crate::api::core::v1::LocalObjectReferenceApplyConfiguration>>,
+
+    /// See [`PodSpec::init_containers`].
+    pub init_containers: Option<Vec<crate::api::core::v1::ContainerApplyConfiguration>>,
+
+    /// See [`PodSpec::node_name`].
+    pub node_name: Option<String>,
+
+    /// See [`PodSpec::node_selector`].
+    pub node_selector: Option<std::collections::BTreeMap<String, String>>,
+
+    /// See [`PodSpec::priority`].
+    pub priority: Option<i32>,
+
+    /// See [`PodSpec::priority_class_name`].
+    pub priority_class_name: Option<String>,
+
+    /// See [`PodSpec::readiness_gates`].
+    pub readiness_gates: Option<Vec<crate::api::core::v1::PodReadinessGateApplyConfiguration>>,
+
+    /// See [`PodSpec::restart_policy`].
+    pub restart_policy: Option<crate::api::core::v1::RestartPolicy>,
+
+    /// See [`PodSpec::runtime_class_name`].
+    pub runtime_class_name: Option<String>,
+
+    /// See [`PodSpec::scheduler_name`].
+    pub scheduler_name: Option<String>,
+
+    /// See [`PodSpec::security_context`].
+    pub security_context: Option<crate::api::core::v1::PodSecurityContextApplyConfiguration>,
+
+    /// See [`PodSpec::service_account`].
+    pub service_account: Option<String>,
+
+    /// See [`PodSpec::service_account_name`].
+    pub service_account_name: Option<String>,
+
+    /// See [`PodSpec::share_process_namespace`].
+    pub share_process_namespace: Option<bool>,
+
+    /// See [`PodSpec::subdomain`].
+    pub subdomain: Option<String>,
+
+    /// See [`PodSpec::termination_grace_period_seconds`].
+    pub termination_grace_period_seconds: Option<i64>,
+
+    /// See [`PodSpec::tolerations`].
+    pub tolerations: Option<Vec<crate::api::core::v1::TolerationApplyConfiguration>>,
+
+    /// See [`PodSpec::volumes`].
+    pub volumes: Option<Vec<crate::api::core::v1::VolumeApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for PodSpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "PodSpecApplyConfiguration",
+            self.active_deadline_seconds.as_ref().map_or(0, |_| 1) +
+            self.affinity.as_ref().map_or(0, |_| 1) +
+            self.automount_service_account_token.as_ref().map_or(0, |_| 1) +
+            self.containers.as_ref().map_or(0, |_| 1) +
+            self.dns_config.as_ref().map_or(0, |_| 1) +
+            self.dns_policy.as_ref().map_or(0, |_| 1) +
+            self.enable_service_links.as_ref().map_or(0, |_| 1) +
+            self.host_aliases.as_ref().map_or(0, |_| 1) +
+            self.host_ipc.as_ref().map_or(0, |_| 1) +
+            self.host_network.as_ref().map_or(0, |_| 1) +
+            self.host_pid.as_ref().map_or(0, |_| 1) +
+            self.hostname.as_ref().map_or(0, |_| 1) +
+            self.image_pull_secrets.as_ref().map_or(0, |_| 1) +
+            self.init_containers.as_ref().map_or(0, |_| 1) +
+            self.node_name.as_ref().map_or(0, |_| 1) +
+            self.node_selector.as_ref().map_or(0, |_| 1) +
+            self.priority.as_ref().map_or(0, |_| 1) +
+            self.priority_class_name.as_ref().map_or(0, |_| 1) +
+            self.readiness_gates.as_ref().map_or(0, |_| 1) +
+            self.restart_policy.as_ref().map_or(0, |_| 1) +
+            self.runtime_class_name.as_ref().map_or(0, |_| 1) +
+            self.scheduler_name.as_ref().map_or(0, |_| 1) +
+            self.security_context.as_ref().map_or(0, |_| 1) +
+            self.service_account.as_ref().map_or(0, |_| 1) +
+            self.service_account_name.as_ref().map_or(0, |_| 1) +
+            self.share_process_namespace.as_ref().map_or(0, |_| 1) +
+            self.subdomain.as_ref().map_or(0, |_| 1) +
+            self.termination_grace_period_seconds.as_ref().map_or(0, |_| 1) +
+            self.tolerations.as_ref().map_or(0, |_| 1) +
+            self.volumes.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.active_deadline_seconds {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "activeDeadlineSeconds", value)?;
+        }
+        if let Some(value) = &self.affinity {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "affinity", value)?;
+        }
+        if let Some(value) = &self.automount_service_account_token {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "automountServiceAccountToken", value)?;
+        }
+        if let Some(value) = &self.containers {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "containers", value)?;
+        }
+        if let Some(value) = &self.dns_config {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "dnsConfig", value)?;
+        }
+        if let Some(value) = &self.dns_policy {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "dnsPolicy", value)?;
+        }
+        if let Some(value) = &self.enable_service_links {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableServiceLinks", value)?;
+        }
+        if let Some(value) = &self.host_aliases {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostAliases", value)?;
+        }
+        if let Some(value) = &self.host_ipc {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostIPC", value)?;
+        }
+        if let Some(value) = &self.host_network {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostNetwork", value)?;
+        }
+        if let Some(value) = &self.host_pid {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostPID", value)?;
+        }
+        if let Some(value) = &self.hostname {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostname", value)?;
+        }
+        if let Some(value) = &self.image_pull_secrets {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "imagePullSecrets", value)?;
+        }
+        if let Some(value) = &self.init_containers {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "initContainers", value)?;
+        }
+        if let Some(value) = &self.node_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeName", value)?;
+        }
+        if let Some(value) = &self.node_selector {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeSelector", value)?;
+        }
+        if let Some(value) = &self.priority {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "priority", value)?;
+        }
+        if let Some(value) = &self.priority_class_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "priorityClassName", value)?;
+        }
+        if let Some(value) = &self.readiness_gates {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readinessGates", value)?;
+        }
+        if let Some(value) = &self.restart_policy {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "restartPolicy", value)?;
+        }
+        if let Some(value) = &self.runtime_class_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "runtimeClassName", value)?;
+        }
+        if let Some(value) = &self.scheduler_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "schedulerName", value)?;
+        }
+        if let Some(value) = &self.security_context {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "securityContext", value)?;
+        }
+        if let Some(value) = &self.service_account {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "serviceAccount", value)?;
+        }
+        if let Some(value) = &self.service_account_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "serviceAccountName", value)?;
+        }
+        if let Some(value) = &self.share_process_namespace {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "shareProcessNamespace", value)?;
+        }
+        if let Some(value) = &self.subdomain {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "subdomain", value)?;
+        }
+        if let Some(value) = &self.termination_grace_period_seconds {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "terminationGracePeriodSeconds", value)?;
+        }
+        if let Some(value) = &self.tolerations {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tolerations", value)?;
+        }
+        if let Some(value) = &self.volumes {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumes", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodSpec {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.PodSpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::PodSpec".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.PodSpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -475,8 +1431,9 @@ impl crate::schemars::JsonSchema for PodSpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "activeDeadlineSeconds".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -487,8 +1444,8 @@ impl crate::schemars::JsonSchema for PodSpec {
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "affinity".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::Affinity>().into_object();
@@ -498,8 +1455,8 @@ impl crate::schemars::JsonSchema for PodSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "automountServiceAccountToken".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -509,8 +1466,8 @@ impl crate::schemars::JsonSchema for PodSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "containers".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -524,8 +1481,8 @@ impl crate::schemars::JsonSchema for PodSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "dnsConfig".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::PodDNSConfig>().into_object();
@@ -535,19 +1492,19 @@ impl crate::schemars::JsonSchema for PodSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "dnsPolicy".to_owned(),
-                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
-                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::DNSPolicy>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
                                 description: Some("Set DNS policy for the pod. Defaults to \"ClusterFirst\". Valid values are 'ClusterFirstWithHostNet', 'ClusterFirst', 'Default' or 'None'. DNS parameters given in DNSConfig will be merged with the policy selected with DNSPolicy. To have DNS options set along with hostNetwork, you have to specify DNS policy explicitly to 'ClusterFirstWithHostNet'.".to_owned()),
                                 ..Default::default()
-                            })),
-                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
-                            ..Default::default()
-                        }),
-                    ),
-                    (
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
                         "enableServiceLinks".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -557,8 +1514,8 @@ impl crate::schemars::JsonSchema for PodSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "hostAliases".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -572,8 +1529,8 @@ impl crate::schemars::JsonSchema for PodSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "hostIPC".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -583,8 +1540,8 @@ impl crate::schemars::JsonSchema for PodSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "hostNetwork".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -594,8 +1551,8 @@ impl crate::schemars::JsonSchema for PodSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "hostPID".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -605,8 +1562,8 @@ impl crate::schemars::JsonSchema for PodSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "hostname".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -616,8 +1573,8 @@ impl crate::schemars::JsonSchema for PodSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "imagePullSecrets".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -631,8 +1588,8 @@ impl crate::schemars::JsonSchema for PodSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "initContainers".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -646,8 +1603,8 @@ impl crate::schemars::JsonSchema for PodSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "nodeName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -657,8 +1614,8 @@ impl crate::schemars::JsonSchema for PodSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "nodeSelector".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -677,8 +1634,8 @@ impl crate::schemars::JsonSchema for PodSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "priority".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -689,8 +1646,8 @@ impl crate::schemars::JsonSchema for PodSpec {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "priorityClassName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -700,8 +1657,8 @@ impl crate::schemars::JsonSchema for PodSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "readinessGates".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -715,19 +1672,19 @@ impl crate::schemars::JsonSchema for PodSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "restartPolicy".to_owned(),
-                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
-                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::RestartPolicy>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
                                 description: Some("Restart policy for all containers within the pod. One of Always, OnFailure, Never. Default to Always. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle/#restart-policy".to_owned()),
                                 ..Default::default()
-                            })),
-                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
-                            ..Default::default()
-                        }),
-                    ),
-                    (
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
                         "runtimeClassName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -737,8 +1694,8 @@ impl crate::schemars::JsonSchema for PodSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "schedulerName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -748,8 +1705,8 @@ impl crate::schemars::JsonSchema for PodSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "securityContext".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::PodSecurityContext>().into_object();
@@ -759,8 +1716,8 @@ impl crate::schemars::JsonSchema for PodSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "serviceAccount".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -770,8 +1727,8 @@ impl crate::schemars::JsonSchema for PodSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "serviceAccountName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -781,8 +1738,8 @@ impl crate::schemars::JsonSchema for PodSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "shareProcessNamespace".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -792,8 +1749,8 @@ impl crate::schemars::JsonSchema for PodSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "subdomain".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -803,8 +1760,8 @@ impl crate::schemars::JsonSchema for PodSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "terminationGracePeriodSeconds".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -815,8 +1772,8 @@ impl crate::schemars::JsonSchema for PodSpec {
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "tolerations".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -830,8 +1787,8 @@ impl crate::schemars::JsonSchema for PodSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "volumes".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -845,8 +1802,9 @@ impl crate::schemars::JsonSchema for PodSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "containers",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -856,3 +1814,41 @@ impl crate::schemars::JsonSchema for PodSpec {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for PodSpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(PodSpec {
+            active_deadline_seconds: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            affinity: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            automount_service_account_token: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            containers: crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            dns_config: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            dns_policy: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            enable_service_links: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            host_aliases: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            host_ipc: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            host_network: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            host_pid: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            hostname: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            image_pull_secrets: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            init_containers: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            node_name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            node_selector: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            priority: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            priority_class_name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            readiness_gates: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            restart_policy: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            runtime_class_name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            scheduler_name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            security_context: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            service_account: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            service_account_name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            share_process_namespace: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            subdomain: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            termination_grace_period_seconds: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            tolerations: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            volumes: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.ResourceFieldSelector
 
 /// ResourceFieldSelector represents container resources (cpu, memory) and their output format
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ResourceFieldSelector {
     /// Container name: required for volumes, optional for env vars
     pub container_name: Option<String>,
@@ -13,41 +13,52 @@ pub struct ResourceFieldSelector {
     pub resource: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ResourceFieldSelector {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_container_name,
-            Key_divisor,
-            Key_resource,
-            Other,
+impl ResourceFieldSelector {
+    /// Constructs a `ResourceFieldSelector` from its required fields. Its optional fields are unset.
+    pub fn new(resource: impl Into<String>) -> Self {
+        ResourceFieldSelector {
+            container_name: None,
+            divisor: None,
+            resource: resource.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl ResourceFieldSelector {
+    /// Returns this value with [`ResourceFieldSelector::container_name`] set to the given value.
+    #[must_use]
+    pub fn with_container_name(mut self, value: impl Into<String>) -> Self {
+        self.container_name = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ResourceFieldSelector::divisor`] set to the given value.
+    #[must_use]
+    pub fn with_divisor(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.divisor = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ResourceFieldSelector::resource`] set to the given value.
+    #[must_use]
+    pub fn with_resource(mut self, value: impl Into<String>) -> Self {
+        self.resource = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "containerName" => Field::Key_container_name,
-                            "divisor" => Field::Key_divisor,
-                            "resource" => Field::Key_resource,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for ResourceFieldSelector {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl ResourceFieldSelector {
+    const FIELDS: &'static [&'static str] = &[
+        "containerName",
+        "divisor",
+        "resource",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ResourceFieldSelector {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,37 +73,101 @@ impl<'de> crate::serde::Deserialize<'de> for ResourceFieldSelector {
                 let mut value_divisor: Option<crate::apimachinery::pkg::api::resource::Quantity> = None;
                 let mut value_resource: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ResourceFieldSelector", ResourceFieldSelector::FIELDS))? {
                     match key {
-                        Field::Key_container_name => value_container_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_divisor => value_divisor = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_resource => value_resource = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_container_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_divisor = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_resource = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ResourceFieldSelector {
                     container_name: value_container_name,
                     divisor: value_divisor,
-                    resource: value_resource.ok_or_else(|| crate::serde::de::Error::missing_field("resource"))?,
+                    resource: value_resource.ok_or_else(|| crate::serde2::missing_field("ResourceFieldSelector", "resource", &["resource"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ResourceFieldSelector {
+                    container_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    divisor: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    resource: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "ResourceFieldSelector",
-            &[
-                "containerName",
-                "divisor",
-                "resource",
-            ],
+            ResourceFieldSelector::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ResourceFieldSelector> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ResourceFieldSelector")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_container_name = false;
+                let mut has_divisor = false;
+                let mut has_resource = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ResourceFieldSelector", ResourceFieldSelector::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.container_name))?; has_container_name = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.divisor))?; has_divisor = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.resource))?; has_resource = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_container_name {
+                    self.0.container_name = None;
+                }
+                if !has_divisor {
+                    self.0.divisor = None;
+                }
+                if !has_resource {
+                    return Err(crate::serde2::missing_field("ResourceFieldSelector", "resource", &["resource"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.container_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.divisor))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.resource))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ResourceFieldSelector",
+            ResourceFieldSelector::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ResourceFieldSelector {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ResourceFieldSelector", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "containerName", &self.container_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "divisor", &self.divisor)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resource", &self.resource)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ResourceFieldSelector",
             1 +
@@ -110,10 +185,99 @@ impl crate::serde::Serialize for ResourceFieldSelector {
     }
 }
 
+/// A builder of [`ResourceFieldSelector`]. Created with [`ResourceFieldSelector::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ResourceFieldSelectorBuilder {
+    container_name: Option<String>,
+    divisor: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+    resource: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl ResourceFieldSelector {
+    /// Starts building a `ResourceFieldSelector`. Its required fields must be set before [`ResourceFieldSelectorBuilder::build`] is called.
+    pub fn builder() -> ResourceFieldSelectorBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ResourceFieldSelectorBuilder {
+    /// Sets [`ResourceFieldSelector::container_name`].
+    pub fn container_name(mut self, value: impl Into<String>) -> Self {
+        self.container_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`ResourceFieldSelector::divisor`].
+    pub fn divisor(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.divisor = Some(value.into());
+        self
+    }
+
+    /// Sets [`ResourceFieldSelector::resource`].
+    pub fn resource(mut self, value: impl Into<String>) -> Self {
+        self.resource = Some(value.into());
+        self
+    }
+
+    /// Builds the `ResourceFieldSelector`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ResourceFieldSelector, crate::MissingFieldError> {
+        Ok(ResourceFieldSelector {
+            container_name: self.container_name,
+            divisor: self.divisor,
+            resource: self.resource.ok_or_else(|| crate::MissingFieldError::new("ResourceFieldSelector", "resource"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`ResourceFieldSelector`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ResourceFieldSelectorApplyConfiguration {
+    /// See [`ResourceFieldSelector::container_name`].
+    pub container_name: Option<String>,
+
+    /// See [`ResourceFieldSelector::divisor`].
+    pub divisor: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+
+    /// See [`ResourceFieldSelector::resource`].
+    pub resource: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ResourceFieldSelectorApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ResourceFieldSelectorApplyConfiguration",
+            self.container_name.as_ref().map_or(0, |_| 1) +
+            self.divisor.as_ref().map_or(0, |_| 1) +
+            self.resource.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.container_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "containerName", value)?;
+        }
+        if let Some(value) = &self.divisor {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "divisor", value)?;
+        }
+        if let Some(value) = &self.resource {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resource", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ResourceFieldSelector {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.ResourceFieldSelector".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::ResourceFieldSelector".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.ResourceFieldSelector".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -124,8 +288,9 @@ impl crate::schemars::JsonSchema for ResourceFieldSelector {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "containerName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -135,8 +300,8 @@ impl crate::schemars::JsonSchema for ResourceFieldSelector {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "divisor".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::api::resource::Quantity>().into_object();
@@ -146,8 +311,8 @@ impl crate::schemars::JsonSchema for ResourceFieldSelector {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "resource".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -157,8 +322,9 @@ impl crate::schemars::JsonSchema for ResourceFieldSelector {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "resource",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -168,3 +334,14 @@ impl crate::schemars::JsonSchema for ResourceFieldSelector {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ResourceFieldSelector {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ResourceFieldSelector {
+            container_name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            divisor: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            resource: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -0,0 +1,165 @@
+// Generated from definition io.k8s.api.core.v1.ServiceType
+
+/// ServiceType describes how a service is exposed.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ServiceType {
+    /// `ClusterIP`
+    ClusterIP,
+
+    /// `ExternalName`
+    ExternalName,
+
+    /// `LoadBalancer`
+    LoadBalancer,
+
+    /// `NodePort`
+    NodePort,
+
+    /// A value that is not one of the values known to this version of the crate, such as one that was added in a newer version of Kubernetes.
+    Other(String),
+}
+
+impl ServiceType {
+    /// The values known to this version of the crate, in the order of the variants.
+    pub const VALUES: &'static [&'static str] = &["ClusterIP", "ExternalName", "LoadBalancer", "NodePort"];
+
+    /// The value as it is serialized.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ServiceType::ClusterIP => "ClusterIP",
+            ServiceType::ExternalName => "ExternalName",
+            ServiceType::LoadBalancer => "LoadBalancer",
+            ServiceType::NodePort => "NodePort",
+            ServiceType::Other(value) => value,
+        }
+    }
+}
+
+impl AsRef<str> for ServiceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Default for ServiceType {
+    fn default() -> Self {
+        ServiceType::Other(String::new())
+    }
+}
+
+impl std::fmt::Display for ServiceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for ServiceType {
+    fn from(value: &str) -> Self {
+        match value {
+            "ClusterIP" => ServiceType::ClusterIP,
+            "ExternalName" => ServiceType::ExternalName,
+            "LoadBalancer" => ServiceType::LoadBalancer,
+            "NodePort" => ServiceType::NodePort,
+            value => ServiceType::Other(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServiceType {
+    fn from(value: String) -> Self {
+        match &*value {
+            "ClusterIP" => ServiceType::ClusterIP,
+            "ExternalName" => ServiceType::ExternalName,
+            "LoadBalancer" => ServiceType::LoadBalancer,
+            "NodePort" => ServiceType::NodePort,
+            _ => ServiceType::Other(value),
+        }
+    }
+}
+
+impl From<ServiceType> for String {
+    fn from(value: ServiceType) -> Self {
+        match value {
+            ServiceType::Other(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl PartialEq<str> for ServiceType {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for ServiceType {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<'de> crate::serde::Deserialize<'de> for ServiceType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl crate::serde::de::Visitor<'_> for Visitor {
+            type Value = ServiceType;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("ServiceType")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
+                Ok(v.into())
+            }
+
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E> where E: crate::serde::de::Error {
+                Ok(v.into())
+            }
+        }
+
+        deserializer.deserialize_string(Visitor)
+    }
+}
+
+impl crate::serde::Serialize for ServiceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for ServiceType {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::ServiceType".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.ServiceType".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("ServiceType describes how a service is exposed.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ServiceType {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        Ok(match u.int_in_range(0..=4_u8)? {
+            0 => ServiceType::ClusterIP,
+            1 => ServiceType::ExternalName,
+            2 => ServiceType::LoadBalancer,
+            3 => ServiceType::NodePort,
+            _ => ServiceType::from(u.arbitrary::<String>()?),
+        })
+    }
+}
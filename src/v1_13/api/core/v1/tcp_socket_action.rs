@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.TCPSocketAction
 
 /// TCPSocketAction describes an action based on opening a socket
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct TCPSocketAction {
     /// Optional: Host name to connect to, defaults to the pod IP.
     pub host: Option<String>,
@@ -10,39 +10,43 @@ pub struct TCPSocketAction {
     pub port: crate::apimachinery::pkg::util::intstr::IntOrString,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for TCPSocketAction {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_host,
-            Key_port,
-            Other,
+impl TCPSocketAction {
+    /// Constructs a `TCPSocketAction` from its required fields. Its optional fields are unset.
+    pub fn new(port: impl Into<crate::apimachinery::pkg::util::intstr::IntOrString>) -> Self {
+        TCPSocketAction {
+            host: None,
+            port: port.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl TCPSocketAction {
+    /// Returns this value with [`TCPSocketAction::host`] set to the given value.
+    #[must_use]
+    pub fn with_host(mut self, value: impl Into<String>) -> Self {
+        self.host = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`TCPSocketAction::port`] set to the given value.
+    #[must_use]
+    pub fn with_port(mut self, value: impl Into<crate::apimachinery::pkg::util::intstr::IntOrString>) -> Self {
+        self.port = value.into();
+        self
+    }
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for TCPSocketAction {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "host" => Field::Key_host,
-                            "port" => Field::Key_port,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl TCPSocketAction {
+    const FIELDS: &'static [&'static str] = &[
+        "host",
+        "port",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for TCPSocketAction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +60,91 @@ impl<'de> crate::serde::Deserialize<'de> for TCPSocketAction {
                 let mut value_host: Option<String> = None;
                 let mut value_port: Option<crate::apimachinery::pkg::util::intstr::IntOrString> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("TCPSocketAction", TCPSocketAction::FIELDS))? {
                     match key {
-                        Field::Key_host => value_host = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_port => value_port = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_host = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_port = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(TCPSocketAction {
                     host: value_host,
-                    port: value_port.ok_or_else(|| crate::serde::de::Error::missing_field("port"))?,
+                    port: value_port.ok_or_else(|| crate::serde2::missing_field("TCPSocketAction", "port", &["port"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(TCPSocketAction {
+                    host: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    port: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "TCPSocketAction",
-            &[
-                "host",
-                "port",
-            ],
+            TCPSocketAction::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, TCPSocketAction> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("TCPSocketAction")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_host = false;
+                let mut has_port = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("TCPSocketAction", TCPSocketAction::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.host))?; has_host = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.port))?; has_port = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_host {
+                    self.0.host = None;
+                }
+                if !has_port {
+                    return Err(crate::serde2::missing_field("TCPSocketAction", "port", &["port"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.host))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.port))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "TCPSocketAction",
+            TCPSocketAction::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for TCPSocketAction {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("TCPSocketAction", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "host", &self.host)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "port", &self.port)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "TCPSocketAction",
             1 +
@@ -97,10 +158,84 @@ impl crate::serde::Serialize for TCPSocketAction {
     }
 }
 
+/// A builder of [`TCPSocketAction`]. Created with [`TCPSocketAction::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct TCPSocketActionBuilder {
+    host: Option<String>,
+    port: Option<crate::apimachinery::pkg::util::intstr::IntOrString>,
+}
+
+#[cfg(feature = "builder")]
+impl TCPSocketAction {
+    /// Starts building a `TCPSocketAction`. Its required fields must be set before [`TCPSocketActionBuilder::build`] is called.
+    pub fn builder() -> TCPSocketActionBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl TCPSocketActionBuilder {
+    /// Sets [`TCPSocketAction::host`].
+    pub fn host(mut self, value: impl Into<String>) -> Self {
+        self.host = Some(value.into());
+        self
+    }
+
+    /// Sets [`TCPSocketAction::port`].
+    pub fn port(mut self, value: impl Into<crate::apimachinery::pkg::util::intstr::IntOrString>) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Builds the `TCPSocketAction`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<TCPSocketAction, crate::MissingFieldError> {
+        Ok(TCPSocketAction {
+            host: self.host,
+            port: self.port.ok_or_else(|| crate::MissingFieldError::new("TCPSocketAction", "port"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`TCPSocketAction`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TCPSocketActionApplyConfiguration {
+    /// See [`TCPSocketAction::host`].
+    pub host: Option<String>,
+
+    /// See [`TCPSocketAction::port`].
+    pub port: Option<crate::apimachinery::pkg::util::intstr::IntOrString>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for TCPSocketActionApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "TCPSocketActionApplyConfiguration",
+            self.host.as_ref().map_or(0, |_| 1) +
+            self.port.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.host {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "host", value)?;
+        }
+        if let Some(value) = &self.port {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "port", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for TCPSocketAction {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.TCPSocketAction".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::TCPSocketAction".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.TCPSocketAction".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -111,8 +246,9 @@ impl crate::schemars::JsonSchema for TCPSocketAction {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "host".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -122,8 +258,8 @@ impl crate::schemars::JsonSchema for TCPSocketAction {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "port".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::util::intstr::IntOrString>().into_object();
@@ -133,8 +269,9 @@ impl crate::schemars::JsonSchema for TCPSocketAction {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "port",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -144,3 +281,13 @@ impl crate::schemars::JsonSchema for TCPSocketAction {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for TCPSocketAction {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(TCPSocketAction {
+            host: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            port: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -0,0 +1,151 @@
+// Generated from definition io.k8s.api.core.v1.TerminationMessagePolicy
+
+/// TerminationMessagePolicy describes how termination messages are retrieved from a container.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum TerminationMessagePolicy {
+    /// `File`
+    File,
+
+    /// `FallbackToLogsOnError`
+    FallbackToLogsOnError,
+
+    /// A value that is not one of the values known to this version of the crate, such as one that was added in a newer version of Kubernetes.
+    Other(String),
+}
+
+impl TerminationMessagePolicy {
+    /// The values known to this version of the crate, in the order of the variants.
+    pub const VALUES: &'static [&'static str] = &["File", "FallbackToLogsOnError"];
+
+    /// The value as it is serialized.
+    pub fn as_str(&self) -> &str {
+        match self {
+            TerminationMessagePolicy::File => "File",
+            TerminationMessagePolicy::FallbackToLogsOnError => "FallbackToLogsOnError",
+            TerminationMessagePolicy::Other(value) => value,
+        }
+    }
+}
+
+impl AsRef<str> for TerminationMessagePolicy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Default for TerminationMessagePolicy {
+    fn default() -> Self {
+        TerminationMessagePolicy::Other(String::new())
+    }
+}
+
+impl std::fmt::Display for TerminationMessagePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for TerminationMessagePolicy {
+    fn from(value: &str) -> Self {
+        match value {
+            "File" => TerminationMessagePolicy::File,
+            "FallbackToLogsOnError" => TerminationMessagePolicy::FallbackToLogsOnError,
+            value => TerminationMessagePolicy::Other(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TerminationMessagePolicy {
+    fn from(value: String) -> Self {
+        match &*value {
+            "File" => TerminationMessagePolicy::File,
+            "FallbackToLogsOnError" => TerminationMessagePolicy::FallbackToLogsOnError,
+            _ => TerminationMessagePolicy::Other(value),
+        }
+    }
+}
+
+impl From<TerminationMessagePolicy> for String {
+    fn from(value: TerminationMessagePolicy) -> Self {
+        match value {
+            TerminationMessagePolicy::Other(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl PartialEq<str> for TerminationMessagePolicy {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for TerminationMessagePolicy {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<'de> crate::serde::Deserialize<'de> for TerminationMessagePolicy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl crate::serde::de::Visitor<'_> for Visitor {
+            type Value = TerminationMessagePolicy;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("TerminationMessagePolicy")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
+                Ok(v.into())
+            }
+
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E> where E: crate::serde::de::Error {
+                Ok(v.into())
+            }
+        }
+
+        deserializer.deserialize_string(Visitor)
+    }
+}
+
+impl crate::serde::Serialize for TerminationMessagePolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for TerminationMessagePolicy {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::TerminationMessagePolicy".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.TerminationMessagePolicy".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("TerminationMessagePolicy describes how termination messages are retrieved from a container.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for TerminationMessagePolicy {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        Ok(match u.int_in_range(0..=2_u8)? {
+            0 => TerminationMessagePolicy::File,
+            1 => TerminationMessagePolicy::FallbackToLogsOnError,
+            _ => TerminationMessagePolicy::from(u.arbitrary::<String>()?),
+        })
+    }
+}
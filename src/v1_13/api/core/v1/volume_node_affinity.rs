@@ -1,80 +1,118 @@
 // Generated from definition io.k8s.api.core.v1.VolumeNodeAffinity
 
 /// VolumeNodeAffinity defines constraints that limit what nodes this volume can be accessed from.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct VolumeNodeAffinity {
     /// Required specifies hard node constraints that must be met.
     pub required: Option<crate::api::core::v1::NodeSelector>,
 }
 
+impl VolumeNodeAffinity {
+    /// Returns this value with [`VolumeNodeAffinity::required`] set to the given value.
+    #[must_use]
+    pub fn with_required(mut self, value: impl Into<crate::api::core::v1::NodeSelector>) -> Self {
+        self.required = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for VolumeNodeAffinity {}
+
+impl VolumeNodeAffinity {
+    const FIELDS: &'static [&'static str] = &[
+        "required",
+    ];
+}
+
 impl<'de> crate::serde::Deserialize<'de> for VolumeNodeAffinity {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_required,
-            Other,
-        }
+        struct Visitor;
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = VolumeNodeAffinity;
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("VolumeNodeAffinity")
+            }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_required: Option<crate::api::core::v1::NodeSelector> = None;
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "required" => Field::Key_required,
-                            _ => Field::Other,
-                        })
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("VolumeNodeAffinity", VolumeNodeAffinity::FIELDS))? {
+                    match key {
+                        Some(0) => value_required = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
-                deserializer.deserialize_identifier(Visitor)
+                Ok(VolumeNodeAffinity {
+                    required: value_required,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(VolumeNodeAffinity {
+                    required: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                })
             }
         }
 
-        struct Visitor;
+        deserializer.deserialize_struct(
+            "VolumeNodeAffinity",
+            VolumeNodeAffinity::FIELDS,
+            Visitor,
+        )
+    }
 
-        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-            type Value = VolumeNodeAffinity;
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, VolumeNodeAffinity> {
+            type Value = ();
 
             fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 f.write_str("VolumeNodeAffinity")
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
-                let mut value_required: Option<crate::api::core::v1::NodeSelector> = None;
+                let mut has_required = false;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("VolumeNodeAffinity", VolumeNodeAffinity::FIELDS))? {
                     match key {
-                        Field::Key_required => value_required = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.required))?; has_required = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
+                if !has_required {
+                    self.0.required = None;
+                }
 
-                Ok(VolumeNodeAffinity {
-                    required: value_required,
-                })
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.required))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
             }
         }
 
         deserializer.deserialize_struct(
             "VolumeNodeAffinity",
-            &[
-                "required",
-            ],
-            Visitor,
+            VolumeNodeAffinity::FIELDS,
+            InPlaceVisitor(place),
         )
     }
 }
 
 impl crate::serde::Serialize for VolumeNodeAffinity {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("VolumeNodeAffinity", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "required", &self.required)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "VolumeNodeAffinity",
             self.required.as_ref().map_or(0, |_| 1),
@@ -86,10 +124,69 @@ impl crate::serde::Serialize for VolumeNodeAffinity {
     }
 }
 
+/// A builder of [`VolumeNodeAffinity`]. Created with [`VolumeNodeAffinity::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct VolumeNodeAffinityBuilder {
+    required: Option<crate::api::core::v1::NodeSelector>,
+}
+
+#[cfg(feature = "builder")]
+impl VolumeNodeAffinity {
+    /// Starts building a `VolumeNodeAffinity`. Its required fields must be set before [`VolumeNodeAffinityBuilder::build`] is called.
+    pub fn builder() -> VolumeNodeAffinityBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl VolumeNodeAffinityBuilder {
+    /// Sets [`VolumeNodeAffinity::required`].
+    pub fn required(mut self, value: impl Into<crate::api::core::v1::NodeSelector>) -> Self {
+        self.required = Some(value.into());
+        self
+    }
+
+    /// Builds the `VolumeNodeAffinity`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<VolumeNodeAffinity, crate::MissingFieldError> {
+        Ok(VolumeNodeAffinity {
+            required: self.required,
+        })
+    }
+}
+
+/// The apply configuration of [`VolumeNodeAffinity`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VolumeNodeAffinityApplyConfiguration {
+    /// See [`VolumeNodeAffinity::required`].
+    pub required: Option<crate::api::core::v1::NodeSelectorApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for VolumeNodeAffinityApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "VolumeNodeAffinityApplyConfiguration",
+            self.required.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.required {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "required", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for VolumeNodeAffinity {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.VolumeNodeAffinity".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::VolumeNodeAffinity".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.VolumeNodeAffinity".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -100,8 +197,9 @@ impl crate::schemars::JsonSchema for VolumeNodeAffinity {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "required".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::NodeSelector>().into_object();
@@ -111,11 +209,21 @@ impl crate::schemars::JsonSchema for VolumeNodeAffinity {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for VolumeNodeAffinity {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(VolumeNodeAffinity {
+            required: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
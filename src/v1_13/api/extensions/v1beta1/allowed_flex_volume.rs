@@ -1,43 +1,41 @@
 // Generated from definition io.k8s.api.extensions.v1beta1.AllowedFlexVolume
 
 /// AllowedFlexVolume represents a single Flexvolume that is allowed to be used. Deprecated: use AllowedFlexVolume from policy API Group instead.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[deprecated(note = "use AllowedFlexVolume from policy API Group instead.")]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct AllowedFlexVolume {
     /// driver is the name of the Flexvolume driver.
     pub driver: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for AllowedFlexVolume {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_driver,
-            Other,
+impl AllowedFlexVolume {
+    /// Constructs a `AllowedFlexVolume` from its required fields. Its optional fields are unset.
+    pub fn new(driver: impl Into<String>) -> Self {
+        AllowedFlexVolume {
+            driver: driver.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl AllowedFlexVolume {
+    /// Returns this value with [`AllowedFlexVolume::driver`] set to the given value.
+    #[must_use]
+    pub fn with_driver(mut self, value: impl Into<String>) -> Self {
+        self.driver = value.into();
+        self
+    }
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for AllowedFlexVolume {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "driver" => Field::Key_driver,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl AllowedFlexVolume {
+    const FIELDS: &'static [&'static str] = &[
+        "driver",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for AllowedFlexVolume {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -50,31 +48,81 @@ impl<'de> crate::serde::Deserialize<'de> for AllowedFlexVolume {
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
                 let mut value_driver: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("AllowedFlexVolume", AllowedFlexVolume::FIELDS))? {
                     match key {
-                        Field::Key_driver => value_driver = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_driver = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(AllowedFlexVolume {
-                    driver: value_driver.ok_or_else(|| crate::serde::de::Error::missing_field("driver"))?,
+                    driver: value_driver.ok_or_else(|| crate::serde2::missing_field("AllowedFlexVolume", "driver", &["driver"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(AllowedFlexVolume {
+                    driver: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "AllowedFlexVolume",
-            &[
-                "driver",
-            ],
+            AllowedFlexVolume::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, AllowedFlexVolume> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("AllowedFlexVolume")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_driver = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("AllowedFlexVolume", AllowedFlexVolume::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.driver))?; has_driver = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_driver {
+                    return Err(crate::serde2::missing_field("AllowedFlexVolume", "driver", &["driver"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.driver))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "AllowedFlexVolume",
+            AllowedFlexVolume::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for AllowedFlexVolume {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("AllowedFlexVolume", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "driver", &self.driver)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "AllowedFlexVolume",
             1,
@@ -84,10 +132,69 @@ impl crate::serde::Serialize for AllowedFlexVolume {
     }
 }
 
+/// A builder of [`AllowedFlexVolume`]. Created with [`AllowedFlexVolume::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct AllowedFlexVolumeBuilder {
+    driver: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl AllowedFlexVolume {
+    /// Starts building a `AllowedFlexVolume`. Its required fields must be set before [`AllowedFlexVolumeBuilder::build`] is called.
+    pub fn builder() -> AllowedFlexVolumeBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl AllowedFlexVolumeBuilder {
+    /// Sets [`AllowedFlexVolume::driver`].
+    pub fn driver(mut self, value: impl Into<String>) -> Self {
+        self.driver = Some(value.into());
+        self
+    }
+
+    /// Builds the `AllowedFlexVolume`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<AllowedFlexVolume, crate::MissingFieldError> {
+        Ok(AllowedFlexVolume {
+            driver: self.driver.ok_or_else(|| crate::MissingFieldError::new("AllowedFlexVolume", "driver"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`AllowedFlexVolume`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AllowedFlexVolumeApplyConfiguration {
+    /// See [`AllowedFlexVolume::driver`].
+    pub driver: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for AllowedFlexVolumeApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "AllowedFlexVolumeApplyConfiguration",
+            self.driver.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.driver {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "driver", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for AllowedFlexVolume {
     fn schema_name() -> String {
-        "io.k8s.api.extensions.v1beta1.AllowedFlexVolume".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::extensions::v1beta1::AllowedFlexVolume".to_owned()
+        }
+        else {
+            "io.k8s.api.extensions.v1beta1.AllowedFlexVolume".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -98,8 +205,9 @@ impl crate::schemars::JsonSchema for AllowedFlexVolume {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "driver".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -109,8 +217,9 @@ impl crate::schemars::JsonSchema for AllowedFlexVolume {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "driver",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -120,3 +229,12 @@ impl crate::schemars::JsonSchema for AllowedFlexVolume {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for AllowedFlexVolume {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(AllowedFlexVolume {
+            driver: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
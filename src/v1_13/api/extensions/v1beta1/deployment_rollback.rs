@@ -1,7 +1,8 @@
 // Generated from definition io.k8s.api.extensions.v1beta1.DeploymentRollback
 
 /// DEPRECATED. DeploymentRollback stores the information required to rollback a deployment.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[deprecated]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct DeploymentRollback {
     /// Required: This must match the Name of a deployment.
     pub name: String,
@@ -13,6 +14,40 @@ pub struct DeploymentRollback {
     pub updated_annotations: Option<std::collections::BTreeMap<String, String>>,
 }
 
+impl DeploymentRollback {
+    /// Constructs a `DeploymentRollback` from its required fields. Its optional fields are unset.
+    pub fn new(name: impl Into<String>, rollback_to: impl Into<crate::api::extensions::v1beta1::RollbackConfig>) -> Self {
+        DeploymentRollback {
+            name: name.into(),
+            rollback_to: rollback_to.into(),
+            updated_annotations: None,
+        }
+    }
+}
+
+impl DeploymentRollback {
+    /// Returns this value with [`DeploymentRollback::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
+
+    /// Returns this value with [`DeploymentRollback::rollback_to`] set to the given value.
+    #[must_use]
+    pub fn with_rollback_to(mut self, value: impl Into<crate::api::extensions::v1beta1::RollbackConfig>) -> Self {
+        self.rollback_to = value.into();
+        self
+    }
+
+    /// Returns this value with [`DeploymentRollback::updated_annotations`] set to the given value.
+    #[must_use]
+    pub fn with_updated_annotations(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.updated_annotations = Some(value.into());
+        self
+    }
+}
+
 // Begin extensions/v1beta1/DeploymentRollback
 
 // Generated from operation createExtensionsV1beta1NamespacedDeploymentRollback
@@ -44,6 +79,8 @@ impl DeploymentRollback {
         body: &crate::api::extensions::v1beta1::DeploymentRollback,
         optional: crate::CreateOptional<'_>,
     ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<CreateNamespacedDeploymentRollbackResponse>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
         let __url = format!("/apis/extensions/v1beta1/namespaces/{namespace}/deployments/{name}/rollback?",
             name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
             namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
@@ -126,48 +163,26 @@ impl crate::Resource for DeploymentRollback {
     const KIND: &'static str = "DeploymentRollback";
     const VERSION: &'static str = "v1beta1";
     const URL_PATH_SEGMENT: &'static str = "rollback";
+    const VERBS: &'static [&'static str] = &["create"];
+    const SINGULAR: &'static str = "deploymentrollback";
+    const SHORT_NAMES: &'static [&'static str] = &[];
     type Scope = crate::SubResourceScope;
 }
 
-impl<'de> crate::serde::Deserialize<'de> for DeploymentRollback {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_api_version,
-            Key_kind,
-            Key_name,
-            Key_rollback_to,
-            Key_updated_annotations,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
-
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for DeploymentRollback {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "apiVersion" => Field::Key_api_version,
-                            "kind" => Field::Key_kind,
-                            "name" => Field::Key_name,
-                            "rollbackTo" => Field::Key_rollback_to,
-                            "updatedAnnotations" => Field::Key_updated_annotations,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl DeploymentRollback {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "kind",
+        "name",
+        "rollbackTo",
+        "updatedAnnotations",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for DeploymentRollback {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -182,51 +197,125 @@ impl<'de> crate::serde::Deserialize<'de> for DeploymentRollback {
                 let mut value_rollback_to: Option<crate::api::extensions::v1beta1::RollbackConfig> = None;
                 let mut value_updated_annotations: Option<std::collections::BTreeMap<String, String>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("DeploymentRollback", DeploymentRollback::FIELDS))? {
                     match key {
-                        Field::Key_api_version => {
+                        Some(0) => {
                             let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_api_version != <Self::Value as crate::Resource>::API_VERSION {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<Self::Value as crate::Resource>::API_VERSION));
                             }
                         },
-                        Field::Key_kind => {
+                        Some(1) => {
                             let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_kind != <Self::Value as crate::Resource>::KIND {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<Self::Value as crate::Resource>::KIND));
                             }
                         },
-                        Field::Key_name => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_rollback_to => value_rollback_to = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_updated_annotations => value_updated_annotations = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(2) => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_rollback_to = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(4) => value_updated_annotations = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(DeploymentRollback {
-                    name: value_name.ok_or_else(|| crate::serde::de::Error::missing_field("name"))?,
-                    rollback_to: value_rollback_to.ok_or_else(|| crate::serde::de::Error::missing_field("rollbackTo"))?,
+                    name: value_name.ok_or_else(|| crate::serde2::missing_field("DeploymentRollback", "name", &["name", "rollbackTo"]))?,
+                    rollback_to: value_rollback_to.ok_or_else(|| crate::serde2::missing_field("DeploymentRollback", "rollbackTo", &["name", "rollbackTo"]))?,
                     updated_annotations: value_updated_annotations,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(DeploymentRollback {
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    rollback_to: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    updated_annotations: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             <Self as crate::Resource>::KIND,
-            &[
-                "apiVersion",
-                "kind",
-                "name",
-                "rollbackTo",
-                "updatedAnnotations",
-            ],
+            DeploymentRollback::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, DeploymentRollback> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<DeploymentRollback as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_name = false;
+                let mut has_rollback_to = false;
+                let mut has_updated_annotations = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("DeploymentRollback", DeploymentRollback::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <DeploymentRollback as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<DeploymentRollback as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <DeploymentRollback as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<DeploymentRollback as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.name))?; has_name = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.rollback_to))?; has_rollback_to = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.updated_annotations))?; has_updated_annotations = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_name {
+                    return Err(crate::serde2::missing_field("DeploymentRollback", "name", &["name", "rollbackTo"]));
+                }
+                if !has_rollback_to {
+                    return Err(crate::serde2::missing_field("DeploymentRollback", "rollbackTo", &["name", "rollbackTo"]));
+                }
+                if !has_updated_annotations {
+                    self.0.updated_annotations = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.rollback_to))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.updated_annotations))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            DeploymentRollback::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for DeploymentRollback {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct(<Self as crate::Resource>::KIND, 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rollbackTo", &self.rollback_to)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "updatedAnnotations", &self.updated_annotations)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             <Self as crate::Resource>::KIND,
             4 +
@@ -243,10 +332,102 @@ impl crate::serde::Serialize for DeploymentRollback {
     }
 }
 
+/// A builder of [`DeploymentRollback`]. Created with [`DeploymentRollback::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct DeploymentRollbackBuilder {
+    name: Option<String>,
+    rollback_to: Option<crate::api::extensions::v1beta1::RollbackConfig>,
+    updated_annotations: Option<std::collections::BTreeMap<String, String>>,
+}
+
+#[cfg(feature = "builder")]
+impl DeploymentRollback {
+    /// Starts building a `DeploymentRollback`. Its required fields must be set before [`DeploymentRollbackBuilder::build`] is called.
+    pub fn builder() -> DeploymentRollbackBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl DeploymentRollbackBuilder {
+    /// Sets [`DeploymentRollback::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeploymentRollback::rollback_to`].
+    pub fn rollback_to(mut self, value: impl Into<crate::api::extensions::v1beta1::RollbackConfig>) -> Self {
+        self.rollback_to = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeploymentRollback::updated_annotations`].
+    pub fn updated_annotations(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.updated_annotations = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeploymentRollback`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<DeploymentRollback, crate::MissingFieldError> {
+        Ok(DeploymentRollback {
+            name: self.name.ok_or_else(|| crate::MissingFieldError::new("DeploymentRollback", "name"))?,
+            rollback_to: self.rollback_to.ok_or_else(|| crate::MissingFieldError::new("DeploymentRollback", "rollbackTo"))?,
+            updated_annotations: self.updated_annotations,
+        })
+    }
+}
+
+/// The apply configuration of [`DeploymentRollback`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DeploymentRollbackApplyConfiguration {
+    /// See [`DeploymentRollback::name`].
+    pub name: Option<String>,
+
+    /// See [`DeploymentRollback::rollback_to`].
+    pub rollback_to: Option<crate::api::extensions::v1beta1::RollbackConfigApplyConfiguration>,
+
+    /// See [`DeploymentRollback::updated_annotations`].
+    pub updated_annotations: Option<std::collections::BTreeMap<String, String>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for DeploymentRollbackApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            <DeploymentRollback as crate::Resource>::KIND,
+            2 +
+            self.name.as_ref().map_or(0, |_| 1) +
+            self.rollback_to.as_ref().map_or(0, |_| 1) +
+            self.updated_annotations.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <DeploymentRollback as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <DeploymentRollback as crate::Resource>::KIND)?;
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        if let Some(value) = &self.rollback_to {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rollbackTo", value)?;
+        }
+        if let Some(value) = &self.updated_annotations {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "updatedAnnotations", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DeploymentRollback {
     fn schema_name() -> String {
-        "io.k8s.api.extensions.v1beta1.DeploymentRollback".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::extensions::v1beta1::DeploymentRollback".to_owned()
+        }
+        else {
+            "io.k8s.api.extensions.v1beta1.DeploymentRollback".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -257,8 +438,9 @@ impl crate::schemars::JsonSchema for DeploymentRollback {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "apiVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -266,10 +448,11 @@ impl crate::schemars::JsonSchema for DeploymentRollback {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("extensions/v1beta1".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "kind".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -277,10 +460,11 @@ impl crate::schemars::JsonSchema for DeploymentRollback {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("DeploymentRollback".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -290,8 +474,8 @@ impl crate::schemars::JsonSchema for DeploymentRollback {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "rollbackTo".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::extensions::v1beta1::RollbackConfig>().into_object();
@@ -301,8 +485,8 @@ impl crate::schemars::JsonSchema for DeploymentRollback {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "updatedAnnotations".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -321,9 +505,12 @@ impl crate::schemars::JsonSchema for DeploymentRollback {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
+                    "apiVersion",
+                    "kind",
                     "name",
                     "rollbackTo",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -333,3 +520,14 @@ impl crate::schemars::JsonSchema for DeploymentRollback {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for DeploymentRollback {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(DeploymentRollback {
+            name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            rollback_to: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            updated_annotations: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.extensions.v1beta1.HTTPIngressPath
 
 /// HTTPIngressPath associates a path regex with a backend. Incoming urls matching the path are forwarded to the backend.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct HTTPIngressPath {
     /// Backend defines the referenced service endpoint to which the traffic will be forwarded to.
     pub backend: crate::api::extensions::v1beta1::IngressBackend,
@@ -10,39 +10,43 @@ pub struct HTTPIngressPath {
     pub path: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for HTTPIngressPath {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_backend,
-            Key_path,
-            Other,
+impl HTTPIngressPath {
+    /// Constructs a `HTTPIngressPath` from its required fields. Its optional fields are unset.
+    pub fn new(backend: impl Into<crate::api::extensions::v1beta1::IngressBackend>) -> Self {
+        HTTPIngressPath {
+            backend: backend.into(),
+            path: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl HTTPIngressPath {
+    /// Returns this value with [`HTTPIngressPath::backend`] set to the given value.
+    #[must_use]
+    pub fn with_backend(mut self, value: impl Into<crate::api::extensions::v1beta1::IngressBackend>) -> Self {
+        self.backend = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`HTTPIngressPath::path`] set to the given value.
+    #[must_use]
+    pub fn with_path(mut self, value: impl Into<String>) -> Self {
+        self.path = Some(value.into());
+        self
+    }
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for HTTPIngressPath {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "backend" => Field::Key_backend,
-                            "path" => Field::Key_path,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl HTTPIngressPath {
+    const FIELDS: &'static [&'static str] = &[
+        "backend",
+        "path",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for HTTPIngressPath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +60,91 @@ impl<'de> crate::serde::Deserialize<'de> for HTTPIngressPath {
                 let mut value_backend: Option<crate::api::extensions::v1beta1::IngressBackend> = None;
                 let mut value_path: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("HTTPIngressPath", HTTPIngressPath::FIELDS))? {
                     match key {
-                        Field::Key_backend => value_backend = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_path => value_path = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_backend = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_path = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(HTTPIngressPath {
-                    backend: value_backend.ok_or_else(|| crate::serde::de::Error::missing_field("backend"))?,
+                    backend: value_backend.ok_or_else(|| crate::serde2::missing_field("HTTPIngressPath", "backend", &["backend"]))?,
                     path: value_path,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(HTTPIngressPath {
+                    backend: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    path: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "HTTPIngressPath",
-            &[
-                "backend",
-                "path",
-            ],
+            HTTPIngressPath::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, HTTPIngressPath> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("HTTPIngressPath")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_backend = false;
+                let mut has_path = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("HTTPIngressPath", HTTPIngressPath::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.backend))?; has_backend = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.path))?; has_path = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_backend {
+                    return Err(crate::serde2::missing_field("HTTPIngressPath", "backend", &["backend"]));
+                }
+                if !has_path {
+                    self.0.path = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.backend))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.path))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "HTTPIngressPath",
+            HTTPIngressPath::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for HTTPIngressPath {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("HTTPIngressPath", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "backend", &self.backend)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "path", &self.path)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "HTTPIngressPath",
             1 +
@@ -97,10 +158,84 @@ impl crate::serde::Serialize for HTTPIngressPath {
     }
 }
 
+/// A builder of [`HTTPIngressPath`]. Created with [`HTTPIngressPath::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct HTTPIngressPathBuilder {
+    backend: Option<crate::api::extensions::v1beta1::IngressBackend>,
+    path: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl HTTPIngressPath {
+    /// Starts building a `HTTPIngressPath`. Its required fields must be set before [`HTTPIngressPathBuilder::build`] is called.
+    pub fn builder() -> HTTPIngressPathBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl HTTPIngressPathBuilder {
+    /// Sets [`HTTPIngressPath::backend`].
+    pub fn backend(mut self, value: impl Into<crate::api::extensions::v1beta1::IngressBackend>) -> Self {
+        self.backend = Some(value.into());
+        self
+    }
+
+    /// Sets [`HTTPIngressPath::path`].
+    pub fn path(mut self, value: impl Into<String>) -> Self {
+        self.path = Some(value.into());
+        self
+    }
+
+    /// Builds the `HTTPIngressPath`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<HTTPIngressPath, crate::MissingFieldError> {
+        Ok(HTTPIngressPath {
+            backend: self.backend.ok_or_else(|| crate::MissingFieldError::new("HTTPIngressPath", "backend"))?,
+            path: self.path,
+        })
+    }
+}
+
+/// The apply configuration of [`HTTPIngressPath`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HTTPIngressPathApplyConfiguration {
+    /// See [`HTTPIngressPath::backend`].
+    pub backend: Option<crate::api::extensions::v1beta1::IngressBackendApplyConfiguration>,
+
+    /// See [`HTTPIngressPath::path`].
+    pub path: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for HTTPIngressPathApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "HTTPIngressPathApplyConfiguration",
+            self.backend.as_ref().map_or(0, |_| 1) +
+            self.path.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.backend {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "backend", value)?;
+        }
+        if let Some(value) = &self.path {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "path", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for HTTPIngressPath {
     fn schema_name() -> String {
-        "io.k8s.api.extensions.v1beta1.HTTPIngressPath".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::extensions::v1beta1::HTTPIngressPath".to_owned()
+        }
+        else {
+            "io.k8s.api.extensions.v1beta1.HTTPIngressPath".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -111,8 +246,9 @@ impl crate::schemars::JsonSchema for HTTPIngressPath {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "backend".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::extensions::v1beta1::IngressBackend>().into_object();
@@ -122,8 +258,8 @@ impl crate::schemars::JsonSchema for HTTPIngressPath {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "path".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -133,8 +269,9 @@ impl crate::schemars::JsonSchema for HTTPIngressPath {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "backend",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -144,3 +281,13 @@ impl crate::schemars::JsonSchema for HTTPIngressPath {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for HTTPIngressPath {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(HTTPIngressPath {
+            backend: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            path: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
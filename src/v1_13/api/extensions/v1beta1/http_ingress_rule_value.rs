@@ -1,43 +1,40 @@
 // Generated from definition io.k8s.api.extensions.v1beta1.HTTPIngressRuleValue
 
 /// HTTPIngressRuleValue is a list of http selectors pointing to backends. In the example: http://\<host\>/\<path\>?\<searchpart\> -\> backend where where parts of the url correspond to RFC 3986, this resource will be used to match against everything after the last '/' and before the first '?' or '#'.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct HTTPIngressRuleValue {
     /// A collection of paths that map requests to backends.
     pub paths: Vec<crate::api::extensions::v1beta1::HTTPIngressPath>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for HTTPIngressRuleValue {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_paths,
-            Other,
+impl HTTPIngressRuleValue {
+    /// Constructs a `HTTPIngressRuleValue` from its required fields. Its optional fields are unset.
+    pub fn new(paths: impl Into<Vec<crate::api::extensions::v1beta1::HTTPIngressPath>>) -> Self {
+        HTTPIngressRuleValue {
+            paths: paths.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl HTTPIngressRuleValue {
+    /// Returns this value with [`HTTPIngressRuleValue::paths`] set to the given value.
+    #[must_use]
+    pub fn with_paths(mut self, value: impl Into<Vec<crate::api::extensions::v1beta1::HTTPIngressPath>>) -> Self {
+        self.paths = value.into();
+        self
+    }
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for HTTPIngressRuleValue {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "paths" => Field::Key_paths,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl HTTPIngressRuleValue {
+    const FIELDS: &'static [&'static str] = &[
+        "paths",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for HTTPIngressRuleValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -50,31 +47,81 @@ impl<'de> crate::serde::Deserialize<'de> for HTTPIngressRuleValue {
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
                 let mut value_paths: Option<Vec<crate::api::extensions::v1beta1::HTTPIngressPath>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("HTTPIngressRuleValue", HTTPIngressRuleValue::FIELDS))? {
                     match key {
-                        Field::Key_paths => value_paths = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_paths = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(HTTPIngressRuleValue {
-                    paths: value_paths.ok_or_else(|| crate::serde::de::Error::missing_field("paths"))?,
+                    paths: value_paths.ok_or_else(|| crate::serde2::missing_field("HTTPIngressRuleValue", "paths", &["paths"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(HTTPIngressRuleValue {
+                    paths: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "HTTPIngressRuleValue",
-            &[
-                "paths",
-            ],
+            HTTPIngressRuleValue::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, HTTPIngressRuleValue> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("HTTPIngressRuleValue")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_paths = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("HTTPIngressRuleValue", HTTPIngressRuleValue::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.paths))?; has_paths = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_paths {
+                    return Err(crate::serde2::missing_field("HTTPIngressRuleValue", "paths", &["paths"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.paths))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "HTTPIngressRuleValue",
+            HTTPIngressRuleValue::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for HTTPIngressRuleValue {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("HTTPIngressRuleValue", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "paths", &self.paths)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "HTTPIngressRuleValue",
             1,
@@ -84,10 +131,69 @@ impl crate::serde::Serialize for HTTPIngressRuleValue {
     }
 }
 
+/// A builder of [`HTTPIngressRuleValue`]. Created with [`HTTPIngressRuleValue::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct HTTPIngressRuleValueBuilder {
+    paths: Option<Vec<crate::api::extensions::v1beta1::HTTPIngressPath>>,
+}
+
+#[cfg(feature = "builder")]
+impl HTTPIngressRuleValue {
+    /// Starts building a `HTTPIngressRuleValue`. Its required fields must be set before [`HTTPIngressRuleValueBuilder::build`] is called.
+    pub fn builder() -> HTTPIngressRuleValueBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl HTTPIngressRuleValueBuilder {
+    /// Sets [`HTTPIngressRuleValue::paths`].
+    pub fn paths(mut self, value: impl Into<Vec<crate::api::extensions::v1beta1::HTTPIngressPath>>) -> Self {
+        self.paths = Some(value.into());
+        self
+    }
+
+    /// Builds the `HTTPIngressRuleValue`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<HTTPIngressRuleValue, crate::MissingFieldError> {
+        Ok(HTTPIngressRuleValue {
+            paths: self.paths.ok_or_else(|| crate::MissingFieldError::new("HTTPIngressRuleValue", "paths"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`HTTPIngressRuleValue`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HTTPIngressRuleValueApplyConfiguration {
+    /// See [`HTTPIngressRuleValue::paths`].
+    pub paths: Option<Vec<crate::api::extensions::v1beta1::HTTPIngressPathApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for HTTPIngressRuleValueApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "HTTPIngressRuleValueApplyConfiguration",
+            self.paths.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.paths {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "paths", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for HTTPIngressRuleValue {
     fn schema_name() -> String {
-        "io.k8s.api.extensions.v1beta1.HTTPIngressRuleValue".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::extensions::v1beta1::HTTPIngressRuleValue".to_owned()
+        }
+        else {
+            "io.k8s.api.extensions.v1beta1.HTTPIngressRuleValue".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -98,8 +204,9 @@ impl crate::schemars::JsonSchema for HTTPIngressRuleValue {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "paths".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -113,8 +220,9 @@ impl crate::schemars::JsonSchema for HTTPIngressRuleValue {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "paths",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -124,3 +232,12 @@ impl crate::schemars::JsonSchema for HTTPIngressRuleValue {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for HTTPIngressRuleValue {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(HTTPIngressRuleValue {
+            paths: crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
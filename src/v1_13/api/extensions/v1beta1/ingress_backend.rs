@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.extensions.v1beta1.IngressBackend
 
 /// IngressBackend describes all endpoints for a given service and port.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct IngressBackend {
     /// Specifies the name of the referenced service.
     pub service_name: String,
@@ -10,39 +10,43 @@ pub struct IngressBackend {
     pub service_port: crate::apimachinery::pkg::util::intstr::IntOrString,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for IngressBackend {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_service_name,
-            Key_service_port,
-            Other,
+impl IngressBackend {
+    /// Constructs a `IngressBackend` from its required fields. Its optional fields are unset.
+    pub fn new(service_name: impl Into<String>, service_port: impl Into<crate::apimachinery::pkg::util::intstr::IntOrString>) -> Self {
+        IngressBackend {
+            service_name: service_name.into(),
+            service_port: service_port.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl IngressBackend {
+    /// Returns this value with [`IngressBackend::service_name`] set to the given value.
+    #[must_use]
+    pub fn with_service_name(mut self, value: impl Into<String>) -> Self {
+        self.service_name = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`IngressBackend::service_port`] set to the given value.
+    #[must_use]
+    pub fn with_service_port(mut self, value: impl Into<crate::apimachinery::pkg::util::intstr::IntOrString>) -> Self {
+        self.service_port = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "serviceName" => Field::Key_service_name,
-                            "servicePort" => Field::Key_service_port,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for IngressBackend {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl IngressBackend {
+    const FIELDS: &'static [&'static str] = &[
+        "serviceName",
+        "servicePort",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for IngressBackend {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +60,91 @@ impl<'de> crate::serde::Deserialize<'de> for IngressBackend {
                 let mut value_service_name: Option<String> = None;
                 let mut value_service_port: Option<crate::apimachinery::pkg::util::intstr::IntOrString> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("IngressBackend", IngressBackend::FIELDS))? {
                     match key {
-                        Field::Key_service_name => value_service_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_service_port => value_service_port = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_service_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_service_port = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(IngressBackend {
-                    service_name: value_service_name.ok_or_else(|| crate::serde::de::Error::missing_field("serviceName"))?,
-                    service_port: value_service_port.ok_or_else(|| crate::serde::de::Error::missing_field("servicePort"))?,
+                    service_name: value_service_name.ok_or_else(|| crate::serde2::missing_field("IngressBackend", "serviceName", &["serviceName", "servicePort"]))?,
+                    service_port: value_service_port.ok_or_else(|| crate::serde2::missing_field("IngressBackend", "servicePort", &["serviceName", "servicePort"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(IngressBackend {
+                    service_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    service_port: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "IngressBackend",
-            &[
-                "serviceName",
-                "servicePort",
-            ],
+            IngressBackend::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, IngressBackend> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("IngressBackend")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_service_name = false;
+                let mut has_service_port = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("IngressBackend", IngressBackend::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.service_name))?; has_service_name = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.service_port))?; has_service_port = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_service_name {
+                    return Err(crate::serde2::missing_field("IngressBackend", "serviceName", &["serviceName", "servicePort"]));
+                }
+                if !has_service_port {
+                    return Err(crate::serde2::missing_field("IngressBackend", "servicePort", &["serviceName", "servicePort"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.service_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.service_port))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "IngressBackend",
+            IngressBackend::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for IngressBackend {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("IngressBackend", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "serviceName", &self.service_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "servicePort", &self.service_port)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "IngressBackend",
             2,
@@ -94,10 +155,84 @@ impl crate::serde::Serialize for IngressBackend {
     }
 }
 
+/// A builder of [`IngressBackend`]. Created with [`IngressBackend::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct IngressBackendBuilder {
+    service_name: Option<String>,
+    service_port: Option<crate::apimachinery::pkg::util::intstr::IntOrString>,
+}
+
+#[cfg(feature = "builder")]
+impl IngressBackend {
+    /// Starts building a `IngressBackend`. Its required fields must be set before [`IngressBackendBuilder::build`] is called.
+    pub fn builder() -> IngressBackendBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl IngressBackendBuilder {
+    /// Sets [`IngressBackend::service_name`].
+    pub fn service_name(mut self, value: impl Into<String>) -> Self {
+        self.service_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`IngressBackend::service_port`].
+    pub fn service_port(mut self, value: impl Into<crate::apimachinery::pkg::util::intstr::IntOrString>) -> Self {
+        self.service_port = Some(value.into());
+        self
+    }
+
+    /// Builds the `IngressBackend`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<IngressBackend, crate::MissingFieldError> {
+        Ok(IngressBackend {
+            service_name: self.service_name.ok_or_else(|| crate::MissingFieldError::new("IngressBackend", "serviceName"))?,
+            service_port: self.service_port.ok_or_else(|| crate::MissingFieldError::new("IngressBackend", "servicePort"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`IngressBackend`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IngressBackendApplyConfiguration {
+    /// See [`IngressBackend::service_name`].
+    pub service_name: Option<String>,
+
+    /// See [`IngressBackend::service_port`].
+    pub service_port: Option<crate::apimachinery::pkg::util::intstr::IntOrString>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for IngressBackendApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "IngressBackendApplyConfiguration",
+            self.service_name.as_ref().map_or(0, |_| 1) +
+            self.service_port.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.service_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "serviceName", value)?;
+        }
+        if let Some(value) = &self.service_port {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "servicePort", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for IngressBackend {
     fn schema_name() -> String {
-        "io.k8s.api.extensions.v1beta1.IngressBackend".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::extensions::v1beta1::IngressBackend".to_owned()
+        }
+        else {
+            "io.k8s.api.extensions.v1beta1.IngressBackend".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -108,8 +243,9 @@ impl crate::schemars::JsonSchema for IngressBackend {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "serviceName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -119,8 +255,8 @@ impl crate::schemars::JsonSchema for IngressBackend {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "servicePort".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::util::intstr::IntOrString>().into_object();
@@ -130,8 +266,9 @@ impl crate::schemars::JsonSchema for IngressBackend {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "serviceName",
                     "servicePort",
@@ -142,3 +279,13 @@ impl crate::schemars::JsonSchema for IngressBackend {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for IngressBackend {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(IngressBackend {
+            service_name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            service_port: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
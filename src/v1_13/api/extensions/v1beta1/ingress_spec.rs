@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.extensions.v1beta1.IngressSpec
 
 /// IngressSpec describes the Ingress the user wishes to exist.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct IngressSpec {
     /// A default backend capable of servicing requests that don't match any rule. At least one of 'backend' or 'rules' must be specified. This field is optional to allow the loadbalancer controller or defaulting logic to specify a global default.
     pub backend: Option<crate::api::extensions::v1beta1::IngressBackend>,
@@ -13,41 +13,41 @@ pub struct IngressSpec {
     pub tls: Option<Vec<crate::api::extensions::v1beta1::IngressTLS>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for IngressSpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_backend,
-            Key_rules,
-            Key_tls,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl IngressSpec {
+    /// Returns this value with [`IngressSpec::backend`] set to the given value.
+    #[must_use]
+    pub fn with_backend(mut self, value: impl Into<crate::api::extensions::v1beta1::IngressBackend>) -> Self {
+        self.backend = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`IngressSpec::rules`] set to the given value.
+    #[must_use]
+    pub fn with_rules(mut self, value: impl Into<Vec<crate::api::extensions::v1beta1::IngressRule>>) -> Self {
+        self.rules = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`IngressSpec::tls`] set to the given value.
+    #[must_use]
+    pub fn with_tls(mut self, value: impl Into<Vec<crate::api::extensions::v1beta1::IngressTLS>>) -> Self {
+        self.tls = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "backend" => Field::Key_backend,
-                            "rules" => Field::Key_rules,
-                            "tls" => Field::Key_tls,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for IngressSpec {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl IngressSpec {
+    const FIELDS: &'static [&'static str] = &[
+        "backend",
+        "rules",
+        "tls",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for IngressSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,12 +62,12 @@ impl<'de> crate::serde::Deserialize<'de> for IngressSpec {
                 let mut value_rules: Option<Vec<crate::api::extensions::v1beta1::IngressRule>> = None;
                 let mut value_tls: Option<Vec<crate::api::extensions::v1beta1::IngressTLS>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("IngressSpec", IngressSpec::FIELDS))? {
                     match key {
-                        Field::Key_backend => value_backend = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_rules => value_rules = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_tls => value_tls = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_backend = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_rules = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_tls = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -77,22 +77,86 @@ impl<'de> crate::serde::Deserialize<'de> for IngressSpec {
                     tls: value_tls,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(IngressSpec {
+                    backend: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    rules: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    tls: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "IngressSpec",
-            &[
-                "backend",
-                "rules",
-                "tls",
-            ],
+            IngressSpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, IngressSpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("IngressSpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_backend = false;
+                let mut has_rules = false;
+                let mut has_tls = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("IngressSpec", IngressSpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.backend))?; has_backend = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.rules))?; has_rules = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.tls))?; has_tls = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_backend {
+                    self.0.backend = None;
+                }
+                if !has_rules {
+                    self.0.rules = None;
+                }
+                if !has_tls {
+                    self.0.tls = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.backend))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.rules))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.tls))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "IngressSpec",
+            IngressSpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for IngressSpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("IngressSpec", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "backend", &self.backend)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rules", &self.rules)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tls", &self.tls)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "IngressSpec",
             self.backend.as_ref().map_or(0, |_| 1) +
@@ -112,10 +176,99 @@ impl crate::serde::Serialize for IngressSpec {
     }
 }
 
+/// A builder of [`IngressSpec`]. Created with [`IngressSpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct IngressSpecBuilder {
+    backend: Option<crate::api::extensions::v1beta1::IngressBackend>,
+    rules: Option<Vec<crate::api::extensions::v1beta1::IngressRule>>,
+    tls: Option<Vec<crate::api::extensions::v1beta1::IngressTLS>>,
+}
+
+#[cfg(feature = "builder")]
+impl IngressSpec {
+    /// Starts building a `IngressSpec`. Its required fields must be set before [`IngressSpecBuilder::build`] is called.
+    pub fn builder() -> IngressSpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl IngressSpecBuilder {
+    /// Sets [`IngressSpec::backend`].
+    pub fn backend(mut self, value: impl Into<crate::api::extensions::v1beta1::IngressBackend>) -> Self {
+        self.backend = Some(value.into());
+        self
+    }
+
+    /// Sets [`IngressSpec::rules`].
+    pub fn rules(mut self, value: impl Into<Vec<crate::api::extensions::v1beta1::IngressRule>>) -> Self {
+        self.rules = Some(value.into());
+        self
+    }
+
+    /// Sets [`IngressSpec::tls`].
+    pub fn tls(mut self, value: impl Into<Vec<crate::api::extensions::v1beta1::IngressTLS>>) -> Self {
+        self.tls = Some(value.into());
+        self
+    }
+
+    /// Builds the `IngressSpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<IngressSpec, crate::MissingFieldError> {
+        Ok(IngressSpec {
+            backend: self.backend,
+            rules: self.rules,
+            tls: self.tls,
+        })
+    }
+}
+
+/// The apply configuration of [`IngressSpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IngressSpecApplyConfiguration {
+    /// See [`IngressSpec::backend`].
+    pub backend: Option<crate::api::extensions::v1beta1::IngressBackendApplyConfiguration>,
+
+    /// See [`IngressSpec::rules`].
+    pub rules: Option<Vec<crate::api::extensions::v1beta1::IngressRuleApplyConfiguration>>,
+
+    /// See [`IngressSpec::tls`].
+    pub tls: Option<Vec<crate::api::extensions::v1beta1::IngressTLSApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for IngressSpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "IngressSpecApplyConfiguration",
+            self.backend.as_ref().map_or(0, |_| 1) +
+            self.rules.as_ref().map_or(0, |_| 1) +
+            self.tls.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.backend {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "backend", value)?;
+        }
+        if let Some(value) = &self.rules {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rules", value)?;
+        }
+        if let Some(value) = &self.tls {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tls", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for IngressSpec {
     fn schema_name() -> String {
-        "io.k8s.api.extensions.v1beta1.IngressSpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::extensions::v1beta1::IngressSpec".to_owned()
+        }
+        else {
+            "io.k8s.api.extensions.v1beta1.IngressSpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -126,8 +279,9 @@ impl crate::schemars::JsonSchema for IngressSpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "backend".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::extensions::v1beta1::IngressBackend>().into_object();
@@ -137,8 +291,8 @@ impl crate::schemars::JsonSchema for IngressSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "rules".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -152,8 +306,8 @@ impl crate::schemars::JsonSchema for IngressSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "tls".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -167,11 +321,23 @@ impl crate::schemars::JsonSchema for IngressSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for IngressSpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(IngressSpec {
+            backend: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            rules: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            tls: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
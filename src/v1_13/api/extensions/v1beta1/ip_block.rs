@@ -1,7 +1,8 @@
 // Generated from definition io.k8s.api.extensions.v1beta1.IPBlock
 
 /// DEPRECATED 1.9 - This group version of IPBlock is deprecated by networking/v1/IPBlock. IPBlock describes a particular CIDR (Ex. "192.168.1.1/24") that is allowed to the pods matched by a NetworkPolicySpec's podSelector. The except entry describes CIDRs that should not be included within this rule.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[deprecated(note = "DEPRECATED 1.9 - This group version of IPBlock is deprecated by networking/v1/IPBlock.")]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct IPBlock {
     /// CIDR is a string representing the IP Block Valid examples are "192.168.1.1/24"
     pub cidr: String,
@@ -10,39 +11,43 @@ pub struct IPBlock {
     pub except: Option<Vec<String>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for IPBlock {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_cidr,
-            Key_except,
-            Other,
+impl IPBlock {
+    /// Constructs a `IPBlock` from its required fields. Its optional fields are unset.
+    pub fn new(cidr: impl Into<String>) -> Self {
+        IPBlock {
+            cidr: cidr.into(),
+            except: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl IPBlock {
+    /// Returns this value with [`IPBlock::cidr`] set to the given value.
+    #[must_use]
+    pub fn with_cidr(mut self, value: impl Into<String>) -> Self {
+        self.cidr = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`IPBlock::except`] set to the given value.
+    #[must_use]
+    pub fn with_except(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.except = Some(value.into());
+        self
+    }
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for IPBlock {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "cidr" => Field::Key_cidr,
-                            "except" => Field::Key_except,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl IPBlock {
+    const FIELDS: &'static [&'static str] = &[
+        "cidr",
+        "except",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for IPBlock {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +61,91 @@ impl<'de> crate::serde::Deserialize<'de> for IPBlock {
                 let mut value_cidr: Option<String> = None;
                 let mut value_except: Option<Vec<String>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("IPBlock", IPBlock::FIELDS))? {
                     match key {
-                        Field::Key_cidr => value_cidr = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_except => value_except = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_cidr = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_except = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(IPBlock {
-                    cidr: value_cidr.ok_or_else(|| crate::serde::de::Error::missing_field("cidr"))?,
+                    cidr: value_cidr.ok_or_else(|| crate::serde2::missing_field("IPBlock", "cidr", &["cidr"]))?,
                     except: value_except,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(IPBlock {
+                    cidr: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    except: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "IPBlock",
-            &[
-                "cidr",
-                "except",
-            ],
+            IPBlock::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, IPBlock> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("IPBlock")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_cidr = false;
+                let mut has_except = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("IPBlock", IPBlock::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.cidr))?; has_cidr = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.except))?; has_except = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_cidr {
+                    return Err(crate::serde2::missing_field("IPBlock", "cidr", &["cidr"]));
+                }
+                if !has_except {
+                    self.0.except = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.cidr))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.except))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "IPBlock",
+            IPBlock::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for IPBlock {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("IPBlock", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cidr", &self.cidr)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "except", &self.except)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "IPBlock",
             1 +
@@ -97,10 +159,84 @@ impl crate::serde::Serialize for IPBlock {
     }
 }
 
+/// A builder of [`IPBlock`]. Created with [`IPBlock::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct IPBlockBuilder {
+    cidr: Option<String>,
+    except: Option<Vec<String>>,
+}
+
+#[cfg(feature = "builder")]
+impl IPBlock {
+    /// Starts building a `IPBlock`. Its required fields must be set before [`IPBlockBuilder::build`] is called.
+    pub fn builder() -> IPBlockBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl IPBlockBuilder {
+    /// Sets [`IPBlock::cidr`].
+    pub fn cidr(mut self, value: impl Into<String>) -> Self {
+        self.cidr = Some(value.into());
+        self
+    }
+
+    /// Sets [`IPBlock::except`].
+    pub fn except(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.except = Some(value.into());
+        self
+    }
+
+    /// Builds the `IPBlock`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<IPBlock, crate::MissingFieldError> {
+        Ok(IPBlock {
+            cidr: self.cidr.ok_or_else(|| crate::MissingFieldError::new("IPBlock", "cidr"))?,
+            except: self.except,
+        })
+    }
+}
+
+/// The apply configuration of [`IPBlock`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IPBlockApplyConfiguration {
+    /// See [`IPBlock::cidr`].
+    pub cidr: Option<String>,
+
+    /// See [`IPBlock::except`].
+    pub except: Option<Vec<String>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for IPBlockApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "IPBlockApplyConfiguration",
+            self.cidr.as_ref().map_or(0, |_| 1) +
+            self.except.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.cidr {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cidr", value)?;
+        }
+        if let Some(value) = &self.except {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "except", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for IPBlock {
     fn schema_name() -> String {
-        "io.k8s.api.extensions.v1beta1.IPBlock".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::extensions::v1beta1::IPBlock".to_owned()
+        }
+        else {
+            "io.k8s.api.extensions.v1beta1.IPBlock".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -111,8 +247,9 @@ impl crate::schemars::JsonSchema for IPBlock {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "cidr".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -122,8 +259,8 @@ impl crate::schemars::JsonSchema for IPBlock {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "except".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -142,8 +279,9 @@ impl crate::schemars::JsonSchema for IPBlock {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "cidr",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -153,3 +291,13 @@ impl crate::schemars::JsonSchema for IPBlock {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for IPBlock {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(IPBlock {
+            cidr: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            except: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
@@ -1,7 +1,8 @@
 // Generated from definition io.k8s.api.extensions.v1beta1.NetworkPolicyIngressRule
 
 /// DEPRECATED 1.9 - This group version of NetworkPolicyIngressRule is deprecated by networking/v1/NetworkPolicyIngressRule. This NetworkPolicyIngressRule matches traffic if and only if the traffic matches both ports AND from.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[deprecated(note = "DEPRECATED 1.9 - This group version of NetworkPolicyIngressRule is deprecated by networking/v1/NetworkPolicyIngressRule.")]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct NetworkPolicyIngressRule {
     /// List of sources which should be able to access the pods selected for this rule. Items in this list are combined using a logical OR operation. If this field is empty or missing, this rule matches all sources (traffic not restricted by source). If this field is present and contains at least on item, this rule allows traffic only if the traffic matches at least one item in the from list.
     pub from: Option<Vec<crate::api::extensions::v1beta1::NetworkPolicyPeer>>,
@@ -10,39 +11,33 @@ pub struct NetworkPolicyIngressRule {
     pub ports: Option<Vec<crate::api::extensions::v1beta1::NetworkPolicyPort>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for NetworkPolicyIngressRule {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_from,
-            Key_ports,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl NetworkPolicyIngressRule {
+    /// Returns this value with [`NetworkPolicyIngressRule::from`] set to the given value.
+    #[must_use]
+    pub fn with_from(mut self, value: impl Into<Vec<crate::api::extensions::v1beta1::NetworkPolicyPeer>>) -> Self {
+        self.from = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`NetworkPolicyIngressRule::ports`] set to the given value.
+    #[must_use]
+    pub fn with_ports(mut self, value: impl Into<Vec<crate::api::extensions::v1beta1::NetworkPolicyPort>>) -> Self {
+        self.ports = Some(value.into());
+        self
+    }
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for NetworkPolicyIngressRule {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "from" => Field::Key_from,
-                            "ports" => Field::Key_ports,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl NetworkPolicyIngressRule {
+    const FIELDS: &'static [&'static str] = &[
+        "from",
+        "ports",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for NetworkPolicyIngressRule {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,11 +51,11 @@ impl<'de> crate::serde::Deserialize<'de> for NetworkPolicyIngressRule {
                 let mut value_from: Option<Vec<crate::api::extensions::v1beta1::NetworkPolicyPeer>> = None;
                 let mut value_ports: Option<Vec<crate::api::extensions::v1beta1::NetworkPolicyPort>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NetworkPolicyIngressRule", NetworkPolicyIngressRule::FIELDS))? {
                     match key {
-                        Field::Key_from => value_from = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_ports => value_ports = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_from = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_ports = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -69,21 +64,78 @@ impl<'de> crate::serde::Deserialize<'de> for NetworkPolicyIngressRule {
                     ports: value_ports,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(NetworkPolicyIngressRule {
+                    from: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    ports: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "NetworkPolicyIngressRule",
-            &[
-                "from",
-                "ports",
-            ],
+            NetworkPolicyIngressRule::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, NetworkPolicyIngressRule> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("NetworkPolicyIngressRule")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_from = false;
+                let mut has_ports = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NetworkPolicyIngressRule", NetworkPolicyIngressRule::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.from))?; has_from = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.ports))?; has_ports = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_from {
+                    self.0.from = None;
+                }
+                if !has_ports {
+                    self.0.ports = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.from))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.ports))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "NetworkPolicyIngressRule",
+            NetworkPolicyIngressRule::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for NetworkPolicyIngressRule {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("NetworkPolicyIngressRule", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "from", &self.from)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ports", &self.ports)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "NetworkPolicyIngressRule",
             self.from.as_ref().map_or(0, |_| 1) +
@@ -99,10 +151,84 @@ impl crate::serde::Serialize for NetworkPolicyIngressRule {
     }
 }
 
+/// A builder of [`NetworkPolicyIngressRule`]. Created with [`NetworkPolicyIngressRule::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct NetworkPolicyIngressRuleBuilder {
+    from: Option<Vec<crate::api::extensions::v1beta1::NetworkPolicyPeer>>,
+    ports: Option<Vec<crate::api::extensions::v1beta1::NetworkPolicyPort>>,
+}
+
+#[cfg(feature = "builder")]
+impl NetworkPolicyIngressRule {
+    /// Starts building a `NetworkPolicyIngressRule`. Its required fields must be set before [`NetworkPolicyIngressRuleBuilder::build`] is called.
+    pub fn builder() -> NetworkPolicyIngressRuleBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl NetworkPolicyIngressRuleBuilder {
+    /// Sets [`NetworkPolicyIngressRule::from`].
+    pub fn from(mut self, value: impl Into<Vec<crate::api::extensions::v1beta1::NetworkPolicyPeer>>) -> Self {
+        self.from = Some(value.into());
+        self
+    }
+
+    /// Sets [`NetworkPolicyIngressRule::ports`].
+    pub fn ports(mut self, value: impl Into<Vec<crate::api::extensions::v1beta1::NetworkPolicyPort>>) -> Self {
+        self.ports = Some(value.into());
+        self
+    }
+
+    /// Builds the `NetworkPolicyIngressRule`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<NetworkPolicyIngressRule, crate::MissingFieldError> {
+        Ok(NetworkPolicyIngressRule {
+            from: self.from,
+            ports: self.ports,
+        })
+    }
+}
+
+/// The apply configuration of [`NetworkPolicyIngressRule`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NetworkPolicyIngressRuleApplyConfiguration {
+    /// See [`NetworkPolicyIngressRule::from`].
+    pub from: Option<Vec<crate::api::extensions::v1beta1::NetworkPolicyPeerApplyConfiguration>>,
+
+    /// See [`NetworkPolicyIngressRule::ports`].
+    pub ports: Option<Vec<crate::api::extensions::v1beta1::NetworkPolicyPortApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for NetworkPolicyIngressRuleApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "NetworkPolicyIngressRuleApplyConfiguration",
+            self.from.as_ref().map_or(0, |_| 1) +
+            self.ports.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.from {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "from", value)?;
+        }
+        if let Some(value) = &self.ports {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ports", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NetworkPolicyIngressRule {
     fn schema_name() -> String {
-        "io.k8s.api.extensions.v1beta1.NetworkPolicyIngressRule".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::extensions::v1beta1::NetworkPolicyIngressRule".to_owned()
+        }
+        else {
+            "io.k8s.api.extensions.v1beta1.NetworkPolicyIngressRule".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -113,8 +239,9 @@ impl crate::schemars::JsonSchema for NetworkPolicyIngressRule {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "from".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -128,8 +255,8 @@ impl crate::schemars::JsonSchema for NetworkPolicyIngressRule {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "ports".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -143,11 +270,22 @@ impl crate::schemars::JsonSchema for NetworkPolicyIngressRule {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for NetworkPolicyIngressRule {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(NetworkPolicyIngressRule {
+            from: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            ports: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
@@ -1,48 +1,108 @@
 
 mod allowed_flex_volume;
 pub use self::allowed_flex_volume::AllowedFlexVolume;
+#[cfg(feature = "builder")]
+pub use self::allowed_flex_volume::AllowedFlexVolumeBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::allowed_flex_volume::AllowedFlexVolumeApplyConfiguration;
 
 mod allowed_host_path;
 pub use self::allowed_host_path::AllowedHostPath;
+#[cfg(feature = "builder")]
+pub use self::allowed_host_path::AllowedHostPathBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::allowed_host_path::AllowedHostPathApplyConfiguration;
 
 mod eviction;
 pub use self::eviction::Eviction;
+#[cfg(feature = "builder")]
+pub use self::eviction::EvictionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::eviction::EvictionApplyConfiguration;
 
 mod fs_group_strategy_options;
 pub use self::fs_group_strategy_options::FSGroupStrategyOptions;
+#[cfg(feature = "builder")]
+pub use self::fs_group_strategy_options::FSGroupStrategyOptionsBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::fs_group_strategy_options::FSGroupStrategyOptionsApplyConfiguration;
 
 mod host_port_range;
 pub use self::host_port_range::HostPortRange;
+#[cfg(feature = "builder")]
+pub use self::host_port_range::HostPortRangeBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::host_port_range::HostPortRangeApplyConfiguration;
 
 mod id_range;
 pub use self::id_range::IDRange;
+#[cfg(feature = "builder")]
+pub use self::id_range::IDRangeBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::id_range::IDRangeApplyConfiguration;
 
 mod pod_disruption_budget;
 pub use self::pod_disruption_budget::PodDisruptionBudget;
+#[cfg(feature = "builder")]
+pub use self::pod_disruption_budget::PodDisruptionBudgetBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::pod_disruption_budget::PodDisruptionBudgetApplyConfiguration;
 #[cfg(feature = "api")] pub use self::pod_disruption_budget::{ReadNamespacedPodDisruptionBudgetOptional, ReadNamespacedPodDisruptionBudgetResponse};
 #[cfg(feature = "api")] pub use self::pod_disruption_budget::{ReadNamespacedPodDisruptionBudgetStatusOptional, ReadNamespacedPodDisruptionBudgetStatusResponse};
 
 mod pod_disruption_budget_spec;
 pub use self::pod_disruption_budget_spec::PodDisruptionBudgetSpec;
+#[cfg(feature = "builder")]
+pub use self::pod_disruption_budget_spec::PodDisruptionBudgetSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::pod_disruption_budget_spec::PodDisruptionBudgetSpecApplyConfiguration;
 
 mod pod_disruption_budget_status;
 pub use self::pod_disruption_budget_status::PodDisruptionBudgetStatus;
+#[cfg(feature = "builder")]
+pub use self::pod_disruption_budget_status::PodDisruptionBudgetStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::pod_disruption_budget_status::PodDisruptionBudgetStatusApplyConfiguration;
 
 mod pod_security_policy;
 pub use self::pod_security_policy::PodSecurityPolicy;
+#[cfg(feature = "builder")]
+pub use self::pod_security_policy::PodSecurityPolicyBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::pod_security_policy::PodSecurityPolicyApplyConfiguration;
 #[cfg(feature = "api")] pub use self::pod_security_policy::{ReadPodSecurityPolicyOptional, ReadPodSecurityPolicyResponse};
 
 mod pod_security_policy_spec;
 pub use self::pod_security_policy_spec::PodSecurityPolicySpec;
+#[cfg(feature = "builder")]
+pub use self::pod_security_policy_spec::PodSecurityPolicySpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::pod_security_policy_spec::PodSecurityPolicySpecApplyConfiguration;
 
 mod run_as_group_strategy_options;
 pub use self::run_as_group_strategy_options::RunAsGroupStrategyOptions;
+#[cfg(feature = "builder")]
+pub use self::run_as_group_strategy_options::RunAsGroupStrategyOptionsBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::run_as_group_strategy_options::RunAsGroupStrategyOptionsApplyConfiguration;
 
 mod run_as_user_strategy_options;
 pub use self::run_as_user_strategy_options::RunAsUserStrategyOptions;
+#[cfg(feature = "builder")]
+pub use self::run_as_user_strategy_options::RunAsUserStrategyOptionsBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::run_as_user_strategy_options::RunAsUserStrategyOptionsApplyConfiguration;
 
 mod se_linux_strategy_options;
 pub use self::se_linux_strategy_options::SELinuxStrategyOptions;
+#[cfg(feature = "builder")]
+pub use self::se_linux_strategy_options::SELinuxStrategyOptionsBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::se_linux_strategy_options::SELinuxStrategyOptionsApplyConfiguration;
 
 mod supplemental_groups_strategy_options;
 pub use self::supplemental_groups_strategy_options::SupplementalGroupsStrategyOptions;
+#[cfg(feature = "builder")]
+pub use self::supplemental_groups_strategy_options::SupplementalGroupsStrategyOptionsBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::supplemental_groups_strategy_options::SupplementalGroupsStrategyOptionsApplyConfiguration;
@@ -1,28 +1,60 @@
 
 mod aggregation_rule;
 pub use self::aggregation_rule::AggregationRule;
+#[cfg(feature = "builder")]
+pub use self::aggregation_rule::AggregationRuleBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::aggregation_rule::AggregationRuleApplyConfiguration;
 
 mod cluster_role;
 pub use self::cluster_role::ClusterRole;
+#[cfg(feature = "builder")]
+pub use self::cluster_role::ClusterRoleBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::cluster_role::ClusterRoleApplyConfiguration;
 #[cfg(feature = "api")] pub use self::cluster_role::{ReadClusterRoleOptional, ReadClusterRoleResponse};
 
 mod cluster_role_binding;
 pub use self::cluster_role_binding::ClusterRoleBinding;
+#[cfg(feature = "builder")]
+pub use self::cluster_role_binding::ClusterRoleBindingBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::cluster_role_binding::ClusterRoleBindingApplyConfiguration;
 #[cfg(feature = "api")] pub use self::cluster_role_binding::{ReadClusterRoleBindingOptional, ReadClusterRoleBindingResponse};
 
 mod policy_rule;
 pub use self::policy_rule::PolicyRule;
+#[cfg(feature = "builder")]
+pub use self::policy_rule::PolicyRuleBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::policy_rule::PolicyRuleApplyConfiguration;
 
 mod role;
 pub use self::role::Role;
+#[cfg(feature = "builder")]
+pub use self::role::RoleBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::role::RoleApplyConfiguration;
 #[cfg(feature = "api")] pub use self::role::{ReadNamespacedRoleOptional, ReadNamespacedRoleResponse};
 
 mod role_binding;
 pub use self::role_binding::RoleBinding;
+#[cfg(feature = "builder")]
+pub use self::role_binding::RoleBindingBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::role_binding::RoleBindingApplyConfiguration;
 #[cfg(feature = "api")] pub use self::role_binding::{ReadNamespacedRoleBindingOptional, ReadNamespacedRoleBindingResponse};
 
 mod role_ref;
 pub use self::role_ref::RoleRef;
+#[cfg(feature = "builder")]
+pub use self::role_ref::RoleRefBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::role_ref::RoleRefApplyConfiguration;
 
 mod subject;
 pub use self::subject::Subject;
+#[cfg(feature = "builder")]
+pub use self::subject::SubjectBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::subject::SubjectApplyConfiguration;
@@ -1,7 +1,15 @@
 
 mod pod_preset;
 pub use self::pod_preset::PodPreset;
+#[cfg(feature = "builder")]
+pub use self::pod_preset::PodPresetBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::pod_preset::PodPresetApplyConfiguration;
 #[cfg(feature = "api")] pub use self::pod_preset::{ReadNamespacedPodPresetOptional, ReadNamespacedPodPresetResponse};
 
 mod pod_preset_spec;
 pub use self::pod_preset_spec::PodPresetSpec;
+#[cfg(feature = "builder")]
+pub use self::pod_preset_spec::PodPresetSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::pod_preset_spec::PodPresetSpecApplyConfiguration;
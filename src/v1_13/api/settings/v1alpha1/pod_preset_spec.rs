@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.settings.v1alpha1.PodPresetSpec
 
 /// PodPresetSpec is a description of a pod preset.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct PodPresetSpec {
     /// Env defines the collection of EnvVar to inject into containers.
     pub env: Option<Vec<crate::api::core::v1::EnvVar>>,
@@ -19,45 +19,57 @@ pub struct PodPresetSpec {
     pub volumes: Option<Vec<crate::api::core::v1::Volume>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for PodPresetSpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_env,
-            Key_env_from,
-            Key_selector,
-            Key_volume_mounts,
-            Key_volumes,
-            Other,
-        }
+impl PodPresetSpec {
+    /// Returns this value with [`PodPresetSpec::env`] set to the given value.
+    #[must_use]
+    pub fn with_env(mut self, value: impl Into<Vec<crate::api::core::v1::EnvVar>>) -> Self {
+        self.env = Some(value.into());
+        self
+    }
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+    /// Returns this value with [`PodPresetSpec::env_from`] set to the given value.
+    #[must_use]
+    pub fn with_env_from(mut self, value: impl Into<Vec<crate::api::core::v1::EnvFromSource>>) -> Self {
+        self.env_from = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`PodPresetSpec::selector`] set to the given value.
+    #[must_use]
+    pub fn with_selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.selector = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`PodPresetSpec::volume_mounts`] set to the given value.
+    #[must_use]
+    pub fn with_volume_mounts(mut self, value: impl Into<Vec<crate::api::core::v1::VolumeMount>>) -> Self {
+        self.volume_mounts = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "env" => Field::Key_env,
-                            "envFrom" => Field::Key_env_from,
-                            "selector" => Field::Key_selector,
-                            "volumeMounts" => Field::Key_volume_mounts,
-                            "volumes" => Field::Key_volumes,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`PodPresetSpec::volumes`] set to the given value.
+    #[must_use]
+    pub fn with_volumes(mut self, value: impl Into<Vec<crate::api::core::v1::Volume>>) -> Self {
+        self.volumes = Some(value.into());
+        self
+    }
+}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl crate::MergeStrategyInfo for PodPresetSpec {}
+
+impl PodPresetSpec {
+    const FIELDS: &'static [&'static str] = &[
+        "env",
+        "envFrom",
+        "selector",
+        "volumeMounts",
+        "volumes",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for PodPresetSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -74,14 +86,14 @@ impl<'de> crate::serde::Deserialize<'de> for PodPresetSpec {
                 let mut value_volume_mounts: Option<Vec<crate::api::core::v1::VolumeMount>> = None;
                 let mut value_volumes: Option<Vec<crate::api::core::v1::Volume>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodPresetSpec", PodPresetSpec::FIELDS))? {
                     match key {
-                        Field::Key_env => value_env = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_env_from => value_env_from = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_selector => value_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_volume_mounts => value_volume_mounts = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_volumes => value_volumes = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_env = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_env_from = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_volume_mounts = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_volumes = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -93,24 +105,102 @@ impl<'de> crate::serde::Deserialize<'de> for PodPresetSpec {
                     volumes: value_volumes,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(PodPresetSpec {
+                    env: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    env_from: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    selector: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    volume_mounts: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    volumes: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "PodPresetSpec",
-            &[
-                "env",
-                "envFrom",
-                "selector",
-                "volumeMounts",
-                "volumes",
-            ],
+            PodPresetSpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, PodPresetSpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("PodPresetSpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_env = false;
+                let mut has_env_from = false;
+                let mut has_selector = false;
+                let mut has_volume_mounts = false;
+                let mut has_volumes = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodPresetSpec", PodPresetSpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.env))?; has_env = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.env_from))?; has_env_from = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.selector))?; has_selector = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.volume_mounts))?; has_volume_mounts = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.volumes))?; has_volumes = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_env {
+                    self.0.env = None;
+                }
+                if !has_env_from {
+                    self.0.env_from = None;
+                }
+                if !has_selector {
+                    self.0.selector = None;
+                }
+                if !has_volume_mounts {
+                    self.0.volume_mounts = None;
+                }
+                if !has_volumes {
+                    self.0.volumes = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.env))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.env_from))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.selector))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.volume_mounts))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.volumes))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "PodPresetSpec",
+            PodPresetSpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for PodPresetSpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("PodPresetSpec", 5)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "env", &self.env)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "envFrom", &self.env_from)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selector", &self.selector)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeMounts", &self.volume_mounts)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumes", &self.volumes)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "PodPresetSpec",
             self.env.as_ref().map_or(0, |_| 1) +
@@ -138,10 +228,129 @@ impl crate::serde::Serialize for PodPresetSpec {
     }
 }
 
+/// A builder of [`PodPresetSpec`]. Created with [`PodPresetSpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct PodPresetSpecBuilder {
+    env: Option<Vec<crate::api::core::v1::EnvVar>>,
+    env_from: Option<Vec<crate::api::core::v1::EnvFromSource>>,
+    selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>,
+    volume_mounts: Option<Vec<crate::api::core::v1::VolumeMount>>,
+    volumes: Option<Vec<crate::api::core::v1::Volume>>,
+}
+
+#[cfg(feature = "builder")]
+impl PodPresetSpec {
+    /// Starts building a `PodPresetSpec`. Its required fields must be set before [`PodPresetSpecBuilder::build`] is called.
+    pub fn builder() -> PodPresetSpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl PodPresetSpecBuilder {
+    /// Sets [`PodPresetSpec::env`].
+    pub fn env(mut self, value: impl Into<Vec<crate::api::core::v1::EnvVar>>) -> Self {
+        self.env = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodPresetSpec::env_from`].
+    pub fn env_from(mut self, value: impl Into<Vec<crate::api::core::v1::EnvFromSource>>) -> Self {
+        self.env_from = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodPresetSpec::selector`].
+    pub fn selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodPresetSpec::volume_mounts`].
+    pub fn volume_mounts(mut self, value: impl Into<Vec<crate::api::core::v1::VolumeMount>>) -> Self {
+        self.volume_mounts = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodPresetSpec::volumes`].
+    pub fn volumes(mut self, value: impl Into<Vec<crate::api::core::v1::Volume>>) -> Self {
+        self.volumes = Some(value.into());
+        self
+    }
+
+    /// Builds the `PodPresetSpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<PodPresetSpec, crate::MissingFieldError> {
+        Ok(PodPresetSpec {
+            env: self.env,
+            env_from: self.env_from,
+            selector: self.selector,
+            volume_mounts: self.volume_mounts,
+            volumes: self.volumes,
+        })
+    }
+}
+
+/// The apply configuration of [`PodPresetSpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodPresetSpecApplyConfiguration {
+    /// See [`PodPresetSpec::env`].
+    pub env: Option<Vec<crate::api::core::v1::EnvVarApplyConfiguration>>,
+
+    /// See [`PodPresetSpec::env_from`].
+    pub env_from: Option<Vec<crate::api::core::v1::EnvFromSourceApplyConfiguration>>,
+
+    /// See [`PodPresetSpec::selector`].
+    pub selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorApplyConfiguration>,
+
+    /// See [`PodPresetSpec::volume_mounts`].
+    pub volume_mounts: Option<Vec<crate::api::core::v1::VolumeMountApplyConfiguration>>,
+
+    /// See [`PodPresetSpec::volumes`].
+    pub volumes: Option<Vec<crate::api::core::v1::VolumeApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for PodPresetSpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "PodPresetSpecApplyConfiguration",
+            self.env.as_ref().map_or(0, |_| 1) +
+            self.env_from.as_ref().map_or(0, |_| 1) +
+            self.selector.as_ref().map_or(0, |_| 1) +
+            self.volume_mounts.as_ref().map_or(0, |_| 1) +
+            self.volumes.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.env {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "env", value)?;
+        }
+        if let Some(value) = &self.env_from {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "envFrom", value)?;
+        }
+        if let Some(value) = &self.selector {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selector", value)?;
+        }
+        if let Some(value) = &self.volume_mounts {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeMounts", value)?;
+        }
+        if let Some(value) = &self.volumes {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumes", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodPresetSpec {
     fn schema_name() -> String {
-        "io.k8s.api.settings.v1alpha1.PodPresetSpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::settings::v1alpha1::PodPresetSpec".to_owned()
+        }
+        else {
+            "io.k8s.api.settings.v1alpha1.PodPresetSpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -152,8 +361,9 @@ impl crate::schemars::JsonSchema for PodPresetSpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "env".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -167,8 +377,8 @@ impl crate::schemars::JsonSchema for PodPresetSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "envFrom".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -182,8 +392,8 @@ impl crate::schemars::JsonSchema for PodPresetSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "selector".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>().into_object();
@@ -193,8 +403,8 @@ impl crate::schemars::JsonSchema for PodPresetSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "volumeMounts".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -208,8 +418,8 @@ impl crate::schemars::JsonSchema for PodPresetSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "volumes".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -223,11 +433,25 @@ impl crate::schemars::JsonSchema for PodPresetSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for PodPresetSpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(PodPresetSpec {
+            env: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            env_from: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            selector: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            volume_mounts: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            volumes: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.storage.v1.VolumeAttachmentStatus
 
 /// VolumeAttachmentStatus is the status of a VolumeAttachment request.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct VolumeAttachmentStatus {
     /// The last error encountered during attach operation, if any. This field must only be set by the entity completing the attach operation, i.e. the external-attacher.
     pub attach_error: Option<crate::api::storage::v1::VolumeError>,
@@ -16,43 +16,61 @@ pub struct VolumeAttachmentStatus {
     pub detach_error: Option<crate::api::storage::v1::VolumeError>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for VolumeAttachmentStatus {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_attach_error,
-            Key_attached,
-            Key_attachment_metadata,
-            Key_detach_error,
-            Other,
+impl VolumeAttachmentStatus {
+    /// Constructs a `VolumeAttachmentStatus` from its required fields. Its optional fields are unset.
+    pub fn new(attached: impl Into<bool>) -> Self {
+        VolumeAttachmentStatus {
+            attach_error: None,
+            attached: attached.into(),
+            attachment_metadata: None,
+            detach_error: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl VolumeAttachmentStatus {
+    /// Returns this value with [`VolumeAttachmentStatus::attach_error`] set to the given value.
+    #[must_use]
+    pub fn with_attach_error(mut self, value: impl Into<crate::api::storage::v1::VolumeError>) -> Self {
+        self.attach_error = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`VolumeAttachmentStatus::attached`] set to the given value.
+    #[must_use]
+    pub fn with_attached(mut self, value: impl Into<bool>) -> Self {
+        self.attached = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`VolumeAttachmentStatus::attachment_metadata`] set to the given value.
+    #[must_use]
+    pub fn with_attachment_metadata(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.attachment_metadata = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "attachError" => Field::Key_attach_error,
-                            "attached" => Field::Key_attached,
-                            "attachmentMetadata" => Field::Key_attachment_metadata,
-                            "detachError" => Field::Key_detach_error,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`VolumeAttachmentStatus::detach_error`] set to the given value.
+    #[must_use]
+    pub fn with_detach_error(mut self, value: impl Into<crate::api::storage::v1::VolumeError>) -> Self {
+        self.detach_error = Some(value.into());
+        self
+    }
+}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl crate::MergeStrategyInfo for VolumeAttachmentStatus {}
+
+impl VolumeAttachmentStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "attachError",
+        "attached",
+        "attachmentMetadata",
+        "detachError",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for VolumeAttachmentStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -68,40 +86,111 @@ impl<'de> crate::serde::Deserialize<'de> for VolumeAttachmentStatus {
                 let mut value_attachment_metadata: Option<std::collections::BTreeMap<String, String>> = None;
                 let mut value_detach_error: Option<crate::api::storage::v1::VolumeError> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("VolumeAttachmentStatus", VolumeAttachmentStatus::FIELDS))? {
                     match key {
-                        Field::Key_attach_error => value_attach_error = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_attached => value_attached = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_attachment_metadata => value_attachment_metadata = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_detach_error => value_detach_error = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_attach_error = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_attached = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_attachment_metadata = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_detach_error = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(VolumeAttachmentStatus {
                     attach_error: value_attach_error,
-                    attached: value_attached.ok_or_else(|| crate::serde::de::Error::missing_field("attached"))?,
+                    attached: value_attached.ok_or_else(|| crate::serde2::missing_field("VolumeAttachmentStatus", "attached", &["attached"]))?,
                     attachment_metadata: value_attachment_metadata,
                     detach_error: value_detach_error,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(VolumeAttachmentStatus {
+                    attach_error: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    attached: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    attachment_metadata: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    detach_error: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "VolumeAttachmentStatus",
-            &[
-                "attachError",
-                "attached",
-                "attachmentMetadata",
-                "detachError",
-            ],
+            VolumeAttachmentStatus::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, VolumeAttachmentStatus> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("VolumeAttachmentStatus")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_attach_error = false;
+                let mut has_attached = false;
+                let mut has_attachment_metadata = false;
+                let mut has_detach_error = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("VolumeAttachmentStatus", VolumeAttachmentStatus::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.attach_error))?; has_attach_error = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.attached))?; has_attached = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.attachment_metadata))?; has_attachment_metadata = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.detach_error))?; has_detach_error = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_attach_error {
+                    self.0.attach_error = None;
+                }
+                if !has_attached {
+                    return Err(crate::serde2::missing_field("VolumeAttachmentStatus", "attached", &["attached"]));
+                }
+                if !has_attachment_metadata {
+                    self.0.attachment_metadata = None;
+                }
+                if !has_detach_error {
+                    self.0.detach_error = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.attach_error))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.attached))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.attachment_metadata))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.detach_error))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "VolumeAttachmentStatus",
+            VolumeAttachmentStatus::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for VolumeAttachmentStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("VolumeAttachmentStatus", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "attachError", &self.attach_error)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "attached", &self.attached)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "attachmentMetadata", &self.attachment_metadata)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "detachError", &self.detach_error)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "VolumeAttachmentStatus",
             1 +
@@ -123,10 +212,114 @@ impl crate::serde::Serialize for VolumeAttachmentStatus {
     }
 }
 
+/// A builder of [`VolumeAttachmentStatus`]. Created with [`VolumeAttachmentStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct VolumeAttachmentStatusBuilder {
+    attach_error: Option<crate::api::storage::v1::VolumeError>,
+    attached: Option<bool>,
+    attachment_metadata: Option<std::collections::BTreeMap<String, String>>,
+    detach_error: Option<crate::api::storage::v1::VolumeError>,
+}
+
+#[cfg(feature = "builder")]
+impl VolumeAttachmentStatus {
+    /// Starts building a `VolumeAttachmentStatus`. Its required fields must be set before [`VolumeAttachmentStatusBuilder::build`] is called.
+    pub fn builder() -> VolumeAttachmentStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl VolumeAttachmentStatusBuilder {
+    /// Sets [`VolumeAttachmentStatus::attach_error`].
+    pub fn attach_error(mut self, value: impl Into<crate::api::storage::v1::VolumeError>) -> Self {
+        self.attach_error = Some(value.into());
+        self
+    }
+
+    /// Sets [`VolumeAttachmentStatus::attached`].
+    pub fn attached(mut self, value: impl Into<bool>) -> Self {
+        self.attached = Some(value.into());
+        self
+    }
+
+    /// Sets [`VolumeAttachmentStatus::attachment_metadata`].
+    pub fn attachment_metadata(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.attachment_metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`VolumeAttachmentStatus::detach_error`].
+    pub fn detach_error(mut self, value: impl Into<crate::api::storage::v1::VolumeError>) -> Self {
+        self.detach_error = Some(value.into());
+        self
+    }
+
+    /// Builds the `VolumeAttachmentStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<VolumeAttachmentStatus, crate::MissingFieldError> {
+        Ok(VolumeAttachmentStatus {
+            attach_error: self.attach_error,
+            attached: self.attached.ok_or_else(|| crate::MissingFieldError::new("VolumeAttachmentStatus", "attached"))?,
+            attachment_metadata: self.attachment_metadata,
+            detach_error: self.detach_error,
+        })
+    }
+}
+
+/// The apply configuration of [`VolumeAttachmentStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VolumeAttachmentStatusApplyConfiguration {
+    /// See [`VolumeAttachmentStatus::attach_error`].
+    pub attach_error: Option<crate::api::storage::v1::VolumeErrorApplyConfiguration>,
+
+    /// See [`VolumeAttachmentStatus::attached`].
+    pub attached: Option<bool>,
+
+    /// See [`VolumeAttachmentStatus::attachment_metadata`].
+    pub attachment_metadata: Option<std::collections::BTreeMap<String, String>>,
+
+    /// See [`VolumeAttachmentStatus::detach_error`].
+    pub detach_error: Option<crate::api::storage::v1::VolumeErrorApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for VolumeAttachmentStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "VolumeAttachmentStatusApplyConfiguration",
+            self.attach_error.as_ref().map_or(0, |_| 1) +
+            self.attached.as_ref().map_or(0, |_| 1) +
+            self.attachment_metadata.as_ref().map_or(0, |_| 1) +
+            self.detach_error.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.attach_error {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "attachError", value)?;
+        }
+        if let Some(value) = &self.attached {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "attached", value)?;
+        }
+        if let Some(value) = &self.attachment_metadata {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "attachmentMetadata", value)?;
+        }
+        if let Some(value) = &self.detach_error {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "detachError", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for VolumeAttachmentStatus {
     fn schema_name() -> String {
-        "io.k8s.api.storage.v1.VolumeAttachmentStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::storage::v1::VolumeAttachmentStatus".to_owned()
+        }
+        else {
+            "io.k8s.api.storage.v1.VolumeAttachmentStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -137,8 +330,9 @@ impl crate::schemars::JsonSchema for VolumeAttachmentStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "attachError".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::storage::v1::VolumeError>().into_object();
@@ -148,8 +342,8 @@ impl crate::schemars::JsonSchema for VolumeAttachmentStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "attached".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -159,8 +353,8 @@ impl crate::schemars::JsonSchema for VolumeAttachmentStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "attachmentMetadata".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -179,8 +373,8 @@ impl crate::schemars::JsonSchema for VolumeAttachmentStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "detachError".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::storage::v1::VolumeError>().into_object();
@@ -190,8 +384,9 @@ impl crate::schemars::JsonSchema for VolumeAttachmentStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "attached",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -201,3 +396,15 @@ impl crate::schemars::JsonSchema for VolumeAttachmentStatus {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for VolumeAttachmentStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(VolumeAttachmentStatus {
+            attach_error: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            attached: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            attachment_metadata: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            detach_error: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
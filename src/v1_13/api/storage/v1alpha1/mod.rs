@@ -1,16 +1,36 @@
 
 mod volume_attachment;
 pub use self::volume_attachment::VolumeAttachment;
+#[cfg(feature = "builder")]
+pub use self::volume_attachment::VolumeAttachmentBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::volume_attachment::VolumeAttachmentApplyConfiguration;
 #[cfg(feature = "api")] pub use self::volume_attachment::{ReadVolumeAttachmentOptional, ReadVolumeAttachmentResponse};
 
 mod volume_attachment_source;
 pub use self::volume_attachment_source::VolumeAttachmentSource;
+#[cfg(feature = "builder")]
+pub use self::volume_attachment_source::VolumeAttachmentSourceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::volume_attachment_source::VolumeAttachmentSourceApplyConfiguration;
 
 mod volume_attachment_spec;
 pub use self::volume_attachment_spec::VolumeAttachmentSpec;
+#[cfg(feature = "builder")]
+pub use self::volume_attachment_spec::VolumeAttachmentSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::volume_attachment_spec::VolumeAttachmentSpecApplyConfiguration;
 
 mod volume_attachment_status;
 pub use self::volume_attachment_status::VolumeAttachmentStatus;
+#[cfg(feature = "builder")]
+pub use self::volume_attachment_status::VolumeAttachmentStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::volume_attachment_status::VolumeAttachmentStatusApplyConfiguration;
 
 mod volume_error;
 pub use self::volume_error::VolumeError;
+#[cfg(feature = "builder")]
+pub use self::volume_error::VolumeErrorBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::volume_error::VolumeErrorApplyConfiguration;
@@ -1,80 +1,118 @@
 // Generated from definition io.k8s.api.storage.v1beta1.VolumeAttachmentSource
 
 /// VolumeAttachmentSource represents a volume that should be attached. Right now only PersistenVolumes can be attached via external attacher, in future we may allow also inline volumes in pods. Exactly one member can be set.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct VolumeAttachmentSource {
     /// Name of the persistent volume to attach.
     pub persistent_volume_name: Option<String>,
 }
 
+impl VolumeAttachmentSource {
+    /// Returns this value with [`VolumeAttachmentSource::persistent_volume_name`] set to the given value.
+    #[must_use]
+    pub fn with_persistent_volume_name(mut self, value: impl Into<String>) -> Self {
+        self.persistent_volume_name = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for VolumeAttachmentSource {}
+
+impl VolumeAttachmentSource {
+    const FIELDS: &'static [&'static str] = &[
+        "persistentVolumeName",
+    ];
+}
+
 impl<'de> crate::serde::Deserialize<'de> for VolumeAttachmentSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_persistent_volume_name,
-            Other,
-        }
+        struct Visitor;
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = VolumeAttachmentSource;
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("VolumeAttachmentSource")
+            }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_persistent_volume_name: Option<String> = None;
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "persistentVolumeName" => Field::Key_persistent_volume_name,
-                            _ => Field::Other,
-                        })
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("VolumeAttachmentSource", VolumeAttachmentSource::FIELDS))? {
+                    match key {
+                        Some(0) => value_persistent_volume_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
-                deserializer.deserialize_identifier(Visitor)
+                Ok(VolumeAttachmentSource {
+                    persistent_volume_name: value_persistent_volume_name,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(VolumeAttachmentSource {
+                    persistent_volume_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                })
             }
         }
 
-        struct Visitor;
+        deserializer.deserialize_struct(
+            "VolumeAttachmentSource",
+            VolumeAttachmentSource::FIELDS,
+            Visitor,
+        )
+    }
 
-        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-            type Value = VolumeAttachmentSource;
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, VolumeAttachmentSource> {
+            type Value = ();
 
             fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 f.write_str("VolumeAttachmentSource")
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
-                let mut value_persistent_volume_name: Option<String> = None;
+                let mut has_persistent_volume_name = false;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("VolumeAttachmentSource", VolumeAttachmentSource::FIELDS))? {
                     match key {
-                        Field::Key_persistent_volume_name => value_persistent_volume_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.persistent_volume_name))?; has_persistent_volume_name = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
+                if !has_persistent_volume_name {
+                    self.0.persistent_volume_name = None;
+                }
 
-                Ok(VolumeAttachmentSource {
-                    persistent_volume_name: value_persistent_volume_name,
-                })
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.persistent_volume_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
             }
         }
 
         deserializer.deserialize_struct(
             "VolumeAttachmentSource",
-            &[
-                "persistentVolumeName",
-            ],
-            Visitor,
+            VolumeAttachmentSource::FIELDS,
+            InPlaceVisitor(place),
         )
     }
 }
 
 impl crate::serde::Serialize for VolumeAttachmentSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("VolumeAttachmentSource", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "persistentVolumeName", &self.persistent_volume_name)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "VolumeAttachmentSource",
             self.persistent_volume_name.as_ref().map_or(0, |_| 1),
@@ -86,10 +124,69 @@ impl crate::serde::Serialize for VolumeAttachmentSource {
     }
 }
 
+/// A builder of [`VolumeAttachmentSource`]. Created with [`VolumeAttachmentSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct VolumeAttachmentSourceBuilder {
+    persistent_volume_name: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl VolumeAttachmentSource {
+    /// Starts building a `VolumeAttachmentSource`. Its required fields must be set before [`VolumeAttachmentSourceBuilder::build`] is called.
+    pub fn builder() -> VolumeAttachmentSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl VolumeAttachmentSourceBuilder {
+    /// Sets [`VolumeAttachmentSource::persistent_volume_name`].
+    pub fn persistent_volume_name(mut self, value: impl Into<String>) -> Self {
+        self.persistent_volume_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `VolumeAttachmentSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<VolumeAttachmentSource, crate::MissingFieldError> {
+        Ok(VolumeAttachmentSource {
+            persistent_volume_name: self.persistent_volume_name,
+        })
+    }
+}
+
+/// The apply configuration of [`VolumeAttachmentSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VolumeAttachmentSourceApplyConfiguration {
+    /// See [`VolumeAttachmentSource::persistent_volume_name`].
+    pub persistent_volume_name: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for VolumeAttachmentSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "VolumeAttachmentSourceApplyConfiguration",
+            self.persistent_volume_name.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.persistent_volume_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "persistentVolumeName", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for VolumeAttachmentSource {
     fn schema_name() -> String {
-        "io.k8s.api.storage.v1beta1.VolumeAttachmentSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::storage::v1beta1::VolumeAttachmentSource".to_owned()
+        }
+        else {
+            "io.k8s.api.storage.v1beta1.VolumeAttachmentSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -100,8 +197,9 @@ impl crate::schemars::JsonSchema for VolumeAttachmentSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "persistentVolumeName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -111,11 +209,21 @@ impl crate::schemars::JsonSchema for VolumeAttachmentSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for VolumeAttachmentSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(VolumeAttachmentSource {
+            persistent_volume_name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.storage.v1beta1.VolumeAttachmentSpec
 
 /// VolumeAttachmentSpec is the specification of a VolumeAttachment request.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct VolumeAttachmentSpec {
     /// Attacher indicates the name of the volume driver that MUST handle this request. This is the name returned by GetPluginName().
     pub attacher: String,
@@ -13,41 +13,52 @@ pub struct VolumeAttachmentSpec {
     pub source: crate::api::storage::v1beta1::VolumeAttachmentSource,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for VolumeAttachmentSpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_attacher,
-            Key_node_name,
-            Key_source,
-            Other,
+impl VolumeAttachmentSpec {
+    /// Constructs a `VolumeAttachmentSpec` from its required fields. Its optional fields are unset.
+    pub fn new(attacher: impl Into<String>, node_name: impl Into<String>, source: impl Into<crate::api::storage::v1beta1::VolumeAttachmentSource>) -> Self {
+        VolumeAttachmentSpec {
+            attacher: attacher.into(),
+            node_name: node_name.into(),
+            source: source.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl VolumeAttachmentSpec {
+    /// Returns this value with [`VolumeAttachmentSpec::attacher`] set to the given value.
+    #[must_use]
+    pub fn with_attacher(mut self, value: impl Into<String>) -> Self {
+        self.attacher = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`VolumeAttachmentSpec::node_name`] set to the given value.
+    #[must_use]
+    pub fn with_node_name(mut self, value: impl Into<String>) -> Self {
+        self.node_name = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`VolumeAttachmentSpec::source`] set to the given value.
+    #[must_use]
+    pub fn with_source(mut self, value: impl Into<crate::api::storage::v1beta1::VolumeAttachmentSource>) -> Self {
+        self.source = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "attacher" => Field::Key_attacher,
-                            "nodeName" => Field::Key_node_name,
-                            "source" => Field::Key_source,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for VolumeAttachmentSpec {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl VolumeAttachmentSpec {
+    const FIELDS: &'static [&'static str] = &[
+        "attacher",
+        "nodeName",
+        "source",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for VolumeAttachmentSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,37 +73,101 @@ impl<'de> crate::serde::Deserialize<'de> for VolumeAttachmentSpec {
                 let mut value_node_name: Option<String> = None;
                 let mut value_source: Option<crate::api::storage::v1beta1::VolumeAttachmentSource> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("VolumeAttachmentSpec", VolumeAttachmentSpec::FIELDS))? {
                     match key {
-                        Field::Key_attacher => value_attacher = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_node_name => value_node_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_source => value_source = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_attacher = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_node_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_source = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(VolumeAttachmentSpec {
-                    attacher: value_attacher.ok_or_else(|| crate::serde::de::Error::missing_field("attacher"))?,
-                    node_name: value_node_name.ok_or_else(|| crate::serde::de::Error::missing_field("nodeName"))?,
-                    source: value_source.ok_or_else(|| crate::serde::de::Error::missing_field("source"))?,
+                    attacher: value_attacher.ok_or_else(|| crate::serde2::missing_field("VolumeAttachmentSpec", "attacher", &["attacher", "nodeName", "source"]))?,
+                    node_name: value_node_name.ok_or_else(|| crate::serde2::missing_field("VolumeAttachmentSpec", "nodeName", &["attacher", "nodeName", "source"]))?,
+                    source: value_source.ok_or_else(|| crate::serde2::missing_field("VolumeAttachmentSpec", "source", &["attacher", "nodeName", "source"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(VolumeAttachmentSpec {
+                    attacher: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    node_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    source: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "VolumeAttachmentSpec",
-            &[
-                "attacher",
-                "nodeName",
-                "source",
-            ],
+            VolumeAttachmentSpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, VolumeAttachmentSpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("VolumeAttachmentSpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_attacher = false;
+                let mut has_node_name = false;
+                let mut has_source = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("VolumeAttachmentSpec", VolumeAttachmentSpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.attacher))?; has_attacher = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.node_name))?; has_node_name = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.source))?; has_source = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_attacher {
+                    return Err(crate::serde2::missing_field("VolumeAttachmentSpec", "attacher", &["attacher", "nodeName", "source"]));
+                }
+                if !has_node_name {
+                    return Err(crate::serde2::missing_field("VolumeAttachmentSpec", "nodeName", &["attacher", "nodeName", "source"]));
+                }
+                if !has_source {
+                    return Err(crate::serde2::missing_field("VolumeAttachmentSpec", "source", &["attacher", "nodeName", "source"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.attacher))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.node_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.source))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "VolumeAttachmentSpec",
+            VolumeAttachmentSpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for VolumeAttachmentSpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("VolumeAttachmentSpec", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "attacher", &self.attacher)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeName", &self.node_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "source", &self.source)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "VolumeAttachmentSpec",
             3,
@@ -104,10 +179,99 @@ impl crate::serde::Serialize for VolumeAttachmentSpec {
     }
 }
 
+/// A builder of [`VolumeAttachmentSpec`]. Created with [`VolumeAttachmentSpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct VolumeAttachmentSpecBuilder {
+    attacher: Option<String>,
+    node_name: Option<String>,
+    source: Option<crate::api::storage::v1beta1::VolumeAttachmentSource>,
+}
+
+#[cfg(feature = "builder")]
+impl VolumeAttachmentSpec {
+    /// Starts building a `VolumeAttachmentSpec`. Its required fields must be set before [`VolumeAttachmentSpecBuilder::build`] is called.
+    pub fn builder() -> VolumeAttachmentSpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl VolumeAttachmentSpecBuilder {
+    /// Sets [`VolumeAttachmentSpec::attacher`].
+    pub fn attacher(mut self, value: impl Into<String>) -> Self {
+        self.attacher = Some(value.into());
+        self
+    }
+
+    /// Sets [`VolumeAttachmentSpec::node_name`].
+    pub fn node_name(mut self, value: impl Into<String>) -> Self {
+        self.node_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`VolumeAttachmentSpec::source`].
+    pub fn source(mut self, value: impl Into<crate::api::storage::v1beta1::VolumeAttachmentSource>) -> Self {
+        self.source = Some(value.into());
+        self
+    }
+
+    /// Builds the `VolumeAttachmentSpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<VolumeAttachmentSpec, crate::MissingFieldError> {
+        Ok(VolumeAttachmentSpec {
+            attacher: self.attacher.ok_or_else(|| crate::MissingFieldError::new("VolumeAttachmentSpec", "attacher"))?,
+            node_name: self.node_name.ok_or_else(|| crate::MissingFieldError::new("VolumeAttachmentSpec", "nodeName"))?,
+            source: self.source.ok_or_else(|| crate::MissingFieldError::new("VolumeAttachmentSpec", "source"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`VolumeAttachmentSpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VolumeAttachmentSpecApplyConfiguration {
+    /// See [`VolumeAttachmentSpec::attacher`].
+    pub attacher: Option<String>,
+
+    /// See [`VolumeAttachmentSpec::node_name`].
+    pub node_name: Option<String>,
+
+    /// See [`VolumeAttachmentSpec::source`].
+    pub source: Option<crate::api::storage::v1beta1::VolumeAttachmentSourceApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for VolumeAttachmentSpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "VolumeAttachmentSpecApplyConfiguration",
+            self.attacher.as_ref().map_or(0, |_| 1) +
+            self.node_name.as_ref().map_or(0, |_| 1) +
+            self.source.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.attacher {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "attacher", value)?;
+        }
+        if let Some(value) = &self.node_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeName", value)?;
+        }
+        if let Some(value) = &self.source {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "source", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for VolumeAttachmentSpec {
     fn schema_name() -> String {
-        "io.k8s.api.storage.v1beta1.VolumeAttachmentSpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::storage::v1beta1::VolumeAttachmentSpec".to_owned()
+        }
+        else {
+            "io.k8s.api.storage.v1beta1.VolumeAttachmentSpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -118,8 +282,9 @@ impl crate::schemars::JsonSchema for VolumeAttachmentSpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "attacher".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -129,8 +294,8 @@ impl crate::schemars::JsonSchema for VolumeAttachmentSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "nodeName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -140,8 +305,8 @@ impl crate::schemars::JsonSchema for VolumeAttachmentSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "source".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::storage::v1beta1::VolumeAttachmentSource>().into_object();
@@ -151,8 +316,9 @@ impl crate::schemars::JsonSchema for VolumeAttachmentSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "attacher",
                     "nodeName",
@@ -164,3 +330,14 @@ impl crate::schemars::JsonSchema for VolumeAttachmentSpec {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for VolumeAttachmentSpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(VolumeAttachmentSpec {
+            attacher: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            node_name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            source: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
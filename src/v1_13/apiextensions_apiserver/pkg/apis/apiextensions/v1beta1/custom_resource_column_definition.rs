@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.CustomResourceColumnDefinition
 
 /// CustomResourceColumnDefinition specifies a column for server side printing.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct CustomResourceColumnDefinition {
     /// JSONPath is a simple JSON path, i.e. with array notation.
     pub json_path: String,
@@ -22,47 +22,79 @@ pub struct CustomResourceColumnDefinition {
     pub type_: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for CustomResourceColumnDefinition {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_json_path,
-            Key_description,
-            Key_format,
-            Key_name,
-            Key_priority,
-            Key_type_,
-            Other,
+impl CustomResourceColumnDefinition {
+    /// Constructs a `CustomResourceColumnDefinition` from its required fields. Its optional fields are unset.
+    pub fn new(json_path: impl Into<String>, name: impl Into<String>, type_: impl Into<String>) -> Self {
+        CustomResourceColumnDefinition {
+            json_path: json_path.into(),
+            description: None,
+            format: None,
+            name: name.into(),
+            priority: None,
+            type_: type_.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl CustomResourceColumnDefinition {
+    /// Returns this value with [`CustomResourceColumnDefinition::json_path`] set to the given value.
+    #[must_use]
+    pub fn with_json_path(mut self, value: impl Into<String>) -> Self {
+        self.json_path = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`CustomResourceColumnDefinition::description`] set to the given value.
+    #[must_use]
+    pub fn with_description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`CustomResourceColumnDefinition::format`] set to the given value.
+    #[must_use]
+    pub fn with_format(mut self, value: impl Into<String>) -> Self {
+        self.format = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "JSONPath" => Field::Key_json_path,
-                            "description" => Field::Key_description,
-                            "format" => Field::Key_format,
-                            "name" => Field::Key_name,
-                            "priority" => Field::Key_priority,
-                            "type" => Field::Key_type_,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`CustomResourceColumnDefinition::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`CustomResourceColumnDefinition::priority`] set to the given value.
+    #[must_use]
+    pub fn with_priority(mut self, value: impl Into<i32>) -> Self {
+        self.priority = Some(value.into());
+        self
+    }
 
+    /// Returns this value with [`CustomResourceColumnDefinition::type_`] set to the given value.
+    #[must_use]
+    pub fn with_type(mut self, value: impl Into<String>) -> Self {
+        self.type_ = value.into();
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for CustomResourceColumnDefinition {}
+
+impl CustomResourceColumnDefinition {
+    const FIELDS: &'static [&'static str] = &[
+        "JSONPath",
+        "description",
+        "format",
+        "name",
+        "priority",
+        "type",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for CustomResourceColumnDefinition {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -80,46 +112,131 @@ impl<'de> crate::serde::Deserialize<'de> for CustomResourceColumnDefinition {
                 let mut value_priority: Option<i32> = None;
                 let mut value_type_: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CustomResourceColumnDefinition", CustomResourceColumnDefinition::FIELDS))? {
                     match key {
-                        Field::Key_json_path => value_json_path = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_description => value_description = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_format => value_format = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_name => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_priority => value_priority = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_type_ => value_type_ = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_json_path = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_description = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_format = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(4) => value_priority = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_type_ = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(CustomResourceColumnDefinition {
-                    json_path: value_json_path.ok_or_else(|| crate::serde::de::Error::missing_field("JSONPath"))?,
+                    json_path: value_json_path.ok_or_else(|| crate::serde2::missing_field("CustomResourceColumnDefinition", "JSONPath", &["JSONPath", "name", "type"]))?,
                     description: value_description,
                     format: value_format,
-                    name: value_name.ok_or_else(|| crate::serde::de::Error::missing_field("name"))?,
+                    name: value_name.ok_or_else(|| crate::serde2::missing_field("CustomResourceColumnDefinition", "name", &["JSONPath", "name", "type"]))?,
                     priority: value_priority,
-                    type_: value_type_.ok_or_else(|| crate::serde::de::Error::missing_field("type"))?,
+                    type_: value_type_.ok_or_else(|| crate::serde2::missing_field("CustomResourceColumnDefinition", "type", &["JSONPath", "name", "type"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(CustomResourceColumnDefinition {
+                    json_path: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    description: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    format: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    priority: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    type_: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "CustomResourceColumnDefinition",
-            &[
-                "JSONPath",
-                "description",
-                "format",
-                "name",
-                "priority",
-                "type",
-            ],
+            CustomResourceColumnDefinition::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, CustomResourceColumnDefinition> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("CustomResourceColumnDefinition")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_json_path = false;
+                let mut has_description = false;
+                let mut has_format = false;
+                let mut has_name = false;
+                let mut has_priority = false;
+                let mut has_type_ = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CustomResourceColumnDefinition", CustomResourceColumnDefinition::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.json_path))?; has_json_path = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.description))?; has_description = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.format))?; has_format = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.name))?; has_name = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.priority))?; has_priority = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.type_))?; has_type_ = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_json_path {
+                    return Err(crate::serde2::missing_field("CustomResourceColumnDefinition", "JSONPath", &["JSONPath", "name", "type"]));
+                }
+                if !has_description {
+                    self.0.description = None;
+                }
+                if !has_format {
+                    self.0.format = None;
+                }
+                if !has_name {
+                    return Err(crate::serde2::missing_field("CustomResourceColumnDefinition", "name", &["JSONPath", "name", "type"]));
+                }
+                if !has_priority {
+                    self.0.priority = None;
+                }
+                if !has_type_ {
+                    return Err(crate::serde2::missing_field("CustomResourceColumnDefinition", "type", &["JSONPath", "name", "type"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.json_path))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.description))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.format))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.priority))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.type_))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "CustomResourceColumnDefinition",
+            CustomResourceColumnDefinition::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for CustomResourceColumnDefinition {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("CustomResourceColumnDefinition", 6)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "JSONPath", &self.json_path)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "description", &self.description)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "format", &self.format)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "priority", &self.priority)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "type", &self.type_)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "CustomResourceColumnDefinition",
             3 +
@@ -143,10 +260,144 @@ impl crate::serde::Serialize for CustomResourceColumnDefinition {
     }
 }
 
+/// A builder of [`CustomResourceColumnDefinition`]. Created with [`CustomResourceColumnDefinition::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct CustomResourceColumnDefinitionBuilder {
+    json_path: Option<String>,
+    description: Option<String>,
+    format: Option<String>,
+    name: Option<String>,
+    priority: Option<i32>,
+    type_: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl CustomResourceColumnDefinition {
+    /// Starts building a `CustomResourceColumnDefinition`. Its required fields must be set before [`CustomResourceColumnDefinitionBuilder::build`] is called.
+    pub fn builder() -> CustomResourceColumnDefinitionBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl CustomResourceColumnDefinitionBuilder {
+    /// Sets [`CustomResourceColumnDefinition::json_path`].
+    pub fn json_path(mut self, value: impl Into<String>) -> Self {
+        self.json_path = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceColumnDefinition::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceColumnDefinition::format`].
+    pub fn format(mut self, value: impl Into<String>) -> Self {
+        self.format = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceColumnDefinition::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceColumnDefinition::priority`].
+    pub fn priority(mut self, value: impl Into<i32>) -> Self {
+        self.priority = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceColumnDefinition::type_`].
+    pub fn type_(mut self, value: impl Into<String>) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `CustomResourceColumnDefinition`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<CustomResourceColumnDefinition, crate::MissingFieldError> {
+        Ok(CustomResourceColumnDefinition {
+            json_path: self.json_path.ok_or_else(|| crate::MissingFieldError::new("CustomResourceColumnDefinition", "JSONPath"))?,
+            description: self.description,
+            format: self.format,
+            name: self.name.ok_or_else(|| crate::MissingFieldError::new("CustomResourceColumnDefinition", "name"))?,
+            priority: self.priority,
+            type_: self.type_.ok_or_else(|| crate::MissingFieldError::new("CustomResourceColumnDefinition", "type"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`CustomResourceColumnDefinition`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CustomResourceColumnDefinitionApplyConfiguration {
+    /// See [`CustomResourceColumnDefinition::json_path`].
+    pub json_path: Option<String>,
+
+    /// See [`CustomResourceColumnDefinition::description`].
+    pub description: Option<String>,
+
+    /// See [`CustomResourceColumnDefinition::format`].
+    pub format: Option<String>,
+
+    /// See [`CustomResourceColumnDefinition::name`].
+    pub name: Option<String>,
+
+    /// See [`CustomResourceColumnDefinition::priority`].
+    pub priority: Option<i32>,
+
+    /// See [`CustomResourceColumnDefinition::type_`].
+    pub type_: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for CustomResourceColumnDefinitionApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "CustomResourceColumnDefinitionApplyConfiguration",
+            self.json_path.as_ref().map_or(0, |_| 1) +
+            self.description.as_ref().map_or(0, |_| 1) +
+            self.format.as_ref().map_or(0, |_| 1) +
+            self.name.as_ref().map_or(0, |_| 1) +
+            self.priority.as_ref().map_or(0, |_| 1) +
+            self.type_.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.json_path {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "JSONPath", value)?;
+        }
+        if let Some(value) = &self.description {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "description", value)?;
+        }
+        if let Some(value) = &self.format {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "format", value)?;
+        }
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        if let Some(value) = &self.priority {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "priority", value)?;
+        }
+        if let Some(value) = &self.type_ {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "type", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CustomResourceColumnDefinition {
     fn schema_name() -> String {
-        "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.CustomResourceColumnDefinition".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceColumnDefinition".to_owned()
+        }
+        else {
+            "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.CustomResourceColumnDefinition".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -157,8 +408,9 @@ impl crate::schemars::JsonSchema for CustomResourceColumnDefinition {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "JSONPath".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -168,8 +420,8 @@ impl crate::schemars::JsonSchema for CustomResourceColumnDefinition {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "description".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -179,8 +431,8 @@ impl crate::schemars::JsonSchema for CustomResourceColumnDefinition {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "format".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -190,8 +442,8 @@ impl crate::schemars::JsonSchema for CustomResourceColumnDefinition {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -201,8 +453,8 @@ impl crate::schemars::JsonSchema for CustomResourceColumnDefinition {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "priority".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -213,8 +465,8 @@ impl crate::schemars::JsonSchema for CustomResourceColumnDefinition {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "type".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -224,8 +476,9 @@ impl crate::schemars::JsonSchema for CustomResourceColumnDefinition {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "JSONPath",
                     "name",
@@ -237,3 +490,17 @@ impl crate::schemars::JsonSchema for CustomResourceColumnDefinition {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for CustomResourceColumnDefinition {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(CustomResourceColumnDefinition {
+            json_path: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            description: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            format: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            priority: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            type_: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
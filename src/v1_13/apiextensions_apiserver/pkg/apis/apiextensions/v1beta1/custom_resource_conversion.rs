@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.CustomResourceConversion
 
 /// CustomResourceConversion describes how to convert different versions of a CR.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct CustomResourceConversion {
     /// `strategy` specifies the conversion strategy. Allowed values are: - `None`: The converter only change the apiVersion and would not touch any other field in the CR. - `Webhook`: API Server will call to an external webhook to do the conversion. Additional information is needed for this option.
     pub strategy: String,
@@ -10,39 +10,43 @@ pub struct CustomResourceConversion {
     pub webhook_client_config: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::WebhookClientConfig>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for CustomResourceConversion {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_strategy,
-            Key_webhook_client_config,
-            Other,
+impl CustomResourceConversion {
+    /// Constructs a `CustomResourceConversion` from its required fields. Its optional fields are unset.
+    pub fn new(strategy: impl Into<String>) -> Self {
+        CustomResourceConversion {
+            strategy: strategy.into(),
+            webhook_client_config: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl CustomResourceConversion {
+    /// Returns this value with [`CustomResourceConversion::strategy`] set to the given value.
+    #[must_use]
+    pub fn with_strategy(mut self, value: impl Into<String>) -> Self {
+        self.strategy = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`CustomResourceConversion::webhook_client_config`] set to the given value.
+    #[must_use]
+    pub fn with_webhook_client_config(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::WebhookClientConfig>) -> Self {
+        self.webhook_client_config = Some(value.into());
+        self
+    }
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for CustomResourceConversion {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "strategy" => Field::Key_strategy,
-                            "webhookClientConfig" => Field::Key_webhook_client_config,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl CustomResourceConversion {
+    const FIELDS: &'static [&'static str] = &[
+        "strategy",
+        "webhookClientConfig",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for CustomResourceConversion {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +60,91 @@ impl<'de> crate::serde::Deserialize<'de> for CustomResourceConversion {
                 let mut value_strategy: Option<String> = None;
                 let mut value_webhook_client_config: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::WebhookClientConfig> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CustomResourceConversion", CustomResourceConversion::FIELDS))? {
                     match key {
-                        Field::Key_strategy => value_strategy = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_webhook_client_config => value_webhook_client_config = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_strategy = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_webhook_client_config = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(CustomResourceConversion {
-                    strategy: value_strategy.ok_or_else(|| crate::serde::de::Error::missing_field("strategy"))?,
+                    strategy: value_strategy.ok_or_else(|| crate::serde2::missing_field("CustomResourceConversion", "strategy", &["strategy"]))?,
                     webhook_client_config: value_webhook_client_config,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(CustomResourceConversion {
+                    strategy: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    webhook_client_config: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "CustomResourceConversion",
-            &[
-                "strategy",
-                "webhookClientConfig",
-            ],
+            CustomResourceConversion::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, CustomResourceConversion> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("CustomResourceConversion")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_strategy = false;
+                let mut has_webhook_client_config = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CustomResourceConversion", CustomResourceConversion::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.strategy))?; has_strategy = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.webhook_client_config))?; has_webhook_client_config = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_strategy {
+                    return Err(crate::serde2::missing_field("CustomResourceConversion", "strategy", &["strategy"]));
+                }
+                if !has_webhook_client_config {
+                    self.0.webhook_client_config = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.strategy))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.webhook_client_config))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "CustomResourceConversion",
+            CustomResourceConversion::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for CustomResourceConversion {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("CustomResourceConversion", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "strategy", &self.strategy)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "webhookClientConfig", &self.webhook_client_config)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "CustomResourceConversion",
             1 +
@@ -97,10 +158,84 @@ impl crate::serde::Serialize for CustomResourceConversion {
     }
 }
 
+/// A builder of [`CustomResourceConversion`]. Created with [`CustomResourceConversion::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct CustomResourceConversionBuilder {
+    strategy: Option<String>,
+    webhook_client_config: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::WebhookClientConfig>,
+}
+
+#[cfg(feature = "builder")]
+impl CustomResourceConversion {
+    /// Starts building a `CustomResourceConversion`. Its required fields must be set before [`CustomResourceConversionBuilder::build`] is called.
+    pub fn builder() -> CustomResourceConversionBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl CustomResourceConversionBuilder {
+    /// Sets [`CustomResourceConversion::strategy`].
+    pub fn strategy(mut self, value: impl Into<String>) -> Self {
+        self.strategy = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceConversion::webhook_client_config`].
+    pub fn webhook_client_config(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::WebhookClientConfig>) -> Self {
+        self.webhook_client_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `CustomResourceConversion`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<CustomResourceConversion, crate::MissingFieldError> {
+        Ok(CustomResourceConversion {
+            strategy: self.strategy.ok_or_else(|| crate::MissingFieldError::new("CustomResourceConversion", "strategy"))?,
+            webhook_client_config: self.webhook_client_config,
+        })
+    }
+}
+
+/// The apply configuration of [`CustomResourceConversion`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CustomResourceConversionApplyConfiguration {
+    /// See [`CustomResourceConversion::strategy`].
+    pub strategy: Option<String>,
+
+    /// See [`CustomResourceConversion::webhook_client_config`].
+    pub webhook_client_config: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::WebhookClientConfigApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for CustomResourceConversionApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "CustomResourceConversionApplyConfiguration",
+            self.strategy.as_ref().map_or(0, |_| 1) +
+            self.webhook_client_config.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.strategy {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "strategy", value)?;
+        }
+        if let Some(value) = &self.webhook_client_config {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "webhookClientConfig", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CustomResourceConversion {
     fn schema_name() -> String {
-        "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.CustomResourceConversion".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceConversion".to_owned()
+        }
+        else {
+            "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.CustomResourceConversion".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -111,8 +246,9 @@ impl crate::schemars::JsonSchema for CustomResourceConversion {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "strategy".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -122,8 +258,8 @@ impl crate::schemars::JsonSchema for CustomResourceConversion {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "webhookClientConfig".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::WebhookClientConfig>().into_object();
@@ -133,8 +269,9 @@ impl crate::schemars::JsonSchema for CustomResourceConversion {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "strategy",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -144,3 +281,13 @@ impl crate::schemars::JsonSchema for CustomResourceConversion {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for CustomResourceConversion {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(CustomResourceConversion {
+            strategy: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            webhook_client_config: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.CustomResourceDefinitionNames
 
 /// CustomResourceDefinitionNames indicates the names to serve this CustomResourceDefinition
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct CustomResourceDefinitionNames {
     /// Categories is a list of grouped resources custom resources belong to (e.g. 'all')
     pub categories: Option<Vec<String>>,
@@ -22,47 +22,79 @@ pub struct CustomResourceDefinitionNames {
     pub singular: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for CustomResourceDefinitionNames {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_categories,
-            Key_kind,
-            Key_list_kind,
-            Key_plural,
-            Key_short_names,
-            Key_singular,
-            Other,
+impl CustomResourceDefinitionNames {
+    /// Constructs a `CustomResourceDefinitionNames` from its required fields. Its optional fields are unset.
+    pub fn new(kind: impl Into<String>, plural: impl Into<String>) -> Self {
+        CustomResourceDefinitionNames {
+            categories: None,
+            kind: kind.into(),
+            list_kind: None,
+            plural: plural.into(),
+            short_names: None,
+            singular: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl CustomResourceDefinitionNames {
+    /// Returns this value with [`CustomResourceDefinitionNames::categories`] set to the given value.
+    #[must_use]
+    pub fn with_categories(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.categories = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`CustomResourceDefinitionNames::kind`] set to the given value.
+    #[must_use]
+    pub fn with_kind(mut self, value: impl Into<String>) -> Self {
+        self.kind = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`CustomResourceDefinitionNames::list_kind`] set to the given value.
+    #[must_use]
+    pub fn with_list_kind(mut self, value: impl Into<String>) -> Self {
+        self.list_kind = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "categories" => Field::Key_categories,
-                            "kind" => Field::Key_kind,
-                            "listKind" => Field::Key_list_kind,
-                            "plural" => Field::Key_plural,
-                            "shortNames" => Field::Key_short_names,
-                            "singular" => Field::Key_singular,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`CustomResourceDefinitionNames::plural`] set to the given value.
+    #[must_use]
+    pub fn with_plural(mut self, value: impl Into<String>) -> Self {
+        self.plural = value.into();
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`CustomResourceDefinitionNames::short_names`] set to the given value.
+    #[must_use]
+    pub fn with_short_names(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.short_names = Some(value.into());
+        self
+    }
 
+    /// Returns this value with [`CustomResourceDefinitionNames::singular`] set to the given value.
+    #[must_use]
+    pub fn with_singular(mut self, value: impl Into<String>) -> Self {
+        self.singular = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for CustomResourceDefinitionNames {}
+
+impl CustomResourceDefinitionNames {
+    const FIELDS: &'static [&'static str] = &[
+        "categories",
+        "kind",
+        "listKind",
+        "plural",
+        "shortNames",
+        "singular",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for CustomResourceDefinitionNames {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -80,46 +112,131 @@ impl<'de> crate::serde::Deserialize<'de> for CustomResourceDefinitionNames {
                 let mut value_short_names: Option<Vec<String>> = None;
                 let mut value_singular: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CustomResourceDefinitionNames", CustomResourceDefinitionNames::FIELDS))? {
                     match key {
-                        Field::Key_categories => value_categories = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_kind => value_kind = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_list_kind => value_list_kind = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_plural => value_plural = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_short_names => value_short_names = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_singular => value_singular = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_categories = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_kind = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_list_kind = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_plural = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(4) => value_short_names = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_singular = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(CustomResourceDefinitionNames {
                     categories: value_categories,
-                    kind: value_kind.ok_or_else(|| crate::serde::de::Error::missing_field("kind"))?,
+                    kind: value_kind.ok_or_else(|| crate::serde2::missing_field("CustomResourceDefinitionNames", "kind", &["kind", "plural"]))?,
                     list_kind: value_list_kind,
-                    plural: value_plural.ok_or_else(|| crate::serde::de::Error::missing_field("plural"))?,
+                    plural: value_plural.ok_or_else(|| crate::serde2::missing_field("CustomResourceDefinitionNames", "plural", &["kind", "plural"]))?,
                     short_names: value_short_names,
                     singular: value_singular,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(CustomResourceDefinitionNames {
+                    categories: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    kind: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    list_kind: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    plural: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    short_names: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    singular: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "CustomResourceDefinitionNames",
-            &[
-                "categories",
-                "kind",
-                "listKind",
-                "plural",
-                "shortNames",
-                "singular",
-            ],
+            CustomResourceDefinitionNames::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, CustomResourceDefinitionNames> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("CustomResourceDefinitionNames")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_categories = false;
+                let mut has_kind = false;
+                let mut has_list_kind = false;
+                let mut has_plural = false;
+                let mut has_short_names = false;
+                let mut has_singular = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CustomResourceDefinitionNames", CustomResourceDefinitionNames::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.categories))?; has_categories = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.kind))?; has_kind = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.list_kind))?; has_list_kind = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.plural))?; has_plural = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.short_names))?; has_short_names = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.singular))?; has_singular = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_categories {
+                    self.0.categories = None;
+                }
+                if !has_kind {
+                    return Err(crate::serde2::missing_field("CustomResourceDefinitionNames", "kind", &["kind", "plural"]));
+                }
+                if !has_list_kind {
+                    self.0.list_kind = None;
+                }
+                if !has_plural {
+                    return Err(crate::serde2::missing_field("CustomResourceDefinitionNames", "plural", &["kind", "plural"]));
+                }
+                if !has_short_names {
+                    self.0.short_names = None;
+                }
+                if !has_singular {
+                    self.0.singular = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.categories))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.kind))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.list_kind))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.plural))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.short_names))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.singular))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "CustomResourceDefinitionNames",
+            CustomResourceDefinitionNames::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for CustomResourceDefinitionNames {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("CustomResourceDefinitionNames", 6)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "categories", &self.categories)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", &self.kind)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "listKind", &self.list_kind)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "plural", &self.plural)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "shortNames", &self.short_names)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "singular", &self.singular)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "CustomResourceDefinitionNames",
             2 +
@@ -146,10 +263,144 @@ impl crate::serde::Serialize for CustomResourceDefinitionNames {
     }
 }
 
+/// A builder of [`CustomResourceDefinitionNames`]. Created with [`CustomResourceDefinitionNames::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct CustomResourceDefinitionNamesBuilder {
+    categories: Option<Vec<String>>,
+    kind: Option<String>,
+    list_kind: Option<String>,
+    plural: Option<String>,
+    short_names: Option<Vec<String>>,
+    singular: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl CustomResourceDefinitionNames {
+    /// Starts building a `CustomResourceDefinitionNames`. Its required fields must be set before [`CustomResourceDefinitionNamesBuilder::build`] is called.
+    pub fn builder() -> CustomResourceDefinitionNamesBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl CustomResourceDefinitionNamesBuilder {
+    /// Sets [`CustomResourceDefinitionNames::categories`].
+    pub fn categories(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.categories = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceDefinitionNames::kind`].
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceDefinitionNames::list_kind`].
+    pub fn list_kind(mut self, value: impl Into<String>) -> Self {
+        self.list_kind = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceDefinitionNames::plural`].
+    pub fn plural(mut self, value: impl Into<String>) -> Self {
+        self.plural = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceDefinitionNames::short_names`].
+    pub fn short_names(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.short_names = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceDefinitionNames::singular`].
+    pub fn singular(mut self, value: impl Into<String>) -> Self {
+        self.singular = Some(value.into());
+        self
+    }
+
+    /// Builds the `CustomResourceDefinitionNames`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<CustomResourceDefinitionNames, crate::MissingFieldError> {
+        Ok(CustomResourceDefinitionNames {
+            categories: self.categories,
+            kind: self.kind.ok_or_else(|| crate::MissingFieldError::new("CustomResourceDefinitionNames", "kind"))?,
+            list_kind: self.list_kind,
+            plural: self.plural.ok_or_else(|| crate::MissingFieldError::new("CustomResourceDefinitionNames", "plural"))?,
+            short_names: self.short_names,
+            singular: self.singular,
+        })
+    }
+}
+
+/// The apply configuration of [`CustomResourceDefinitionNames`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CustomResourceDefinitionNamesApplyConfiguration {
+    /// See [`CustomResourceDefinitionNames::categories`].
+    pub categories: Option<Vec<String>>,
+
+    /// See [`CustomResourceDefinitionNames::kind`].
+    pub kind: Option<String>,
+
+    /// See [`CustomResourceDefinitionNames::list_kind`].
+    pub list_kind: Option<String>,
+
+    /// See [`CustomResourceDefinitionNames::plural`].
+    pub plural: Option<String>,
+
+    /// See [`CustomResourceDefinitionNames::short_names`].
+    pub short_names: Option<Vec<String>>,
+
+    /// See [`CustomResourceDefinitionNames::singular`].
+    pub singular: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for CustomResourceDefinitionNamesApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "CustomResourceDefinitionNamesApplyConfiguration",
+            self.categories.as_ref().map_or(0, |_| 1) +
+            self.kind.as_ref().map_or(0, |_| 1) +
+            self.list_kind.as_ref().map_or(0, |_| 1) +
+            self.plural.as_ref().map_or(0, |_| 1) +
+            self.short_names.as_ref().map_or(0, |_| 1) +
+            self.singular.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.categories {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "categories", value)?;
+        }
+        if let Some(value) = &self.kind {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", value)?;
+        }
+        if let Some(value) = &self.list_kind {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "listKind", value)?;
+        }
+        if let Some(value) = &self.plural {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "plural", value)?;
+        }
+        if let Some(value) = &self.short_names {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "shortNames", value)?;
+        }
+        if let Some(value) = &self.singular {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "singular", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CustomResourceDefinitionNames {
     fn schema_name() -> String {
-        "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.CustomResourceDefinitionNames".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionNames".to_owned()
+        }
+        else {
+            "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.CustomResourceDefinitionNames".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -160,8 +411,9 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionNames {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "categories".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -180,8 +432,8 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionNames {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "kind".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -191,8 +443,8 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionNames {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "listKind".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -202,8 +454,8 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionNames {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "plural".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -213,8 +465,8 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionNames {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "shortNames".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -233,8 +485,8 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionNames {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "singular".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -244,8 +496,9 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionNames {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "kind",
                     "plural",
@@ -256,3 +509,17 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionNames {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for CustomResourceDefinitionNames {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(CustomResourceDefinitionNames {
+            categories: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            kind: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            list_kind: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            plural: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            short_names: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            singular: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
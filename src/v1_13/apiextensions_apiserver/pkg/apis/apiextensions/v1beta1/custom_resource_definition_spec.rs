@@ -25,59 +25,113 @@ pub struct CustomResourceDefinitionSpec {
     pub validation: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceValidation>,
 
     /// Version is the version this resource belongs in Should be always first item in Versions field if provided. Optional, but at least one of Version or Versions must be set. Deprecated: Please use `Versions`.
+    #[deprecated(note = "Please use `Versions`.")]
     pub version: Option<String>,
 
     /// Versions is the list of all supported versions for this resource. If Version field is provided, this field is optional. Validation: All versions must use the same validation schema for now. i.e., top level Validation field is applied to all of these versions. Order: The version name will be used to compute the order. If the version string is "kube-like", it will sort above non "kube-like" version strings, which are ordered lexicographically. "Kube-like" versions start with a "v", then are followed by a number (the major version), then optionally the string "alpha" or "beta" and another number (the minor version). These are sorted first by GA \> beta \> alpha (where GA is a version with no suffix such as beta or alpha), and then by comparing major version, then minor version. An example sorted list of versions: v10, v2, v1, v11beta2, v10beta3, v3beta1, v12alpha1, v11alpha2, foo1, foo10.
     pub versions: Option<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionVersion>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for CustomResourceDefinitionSpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_additional_printer_columns,
-            Key_conversion,
-            Key_group,
-            Key_names,
-            Key_scope,
-            Key_subresources,
-            Key_validation,
-            Key_version,
-            Key_versions,
-            Other,
+impl CustomResourceDefinitionSpec {
+    /// Constructs a `CustomResourceDefinitionSpec` from its required fields. Its optional fields are unset.
+    pub fn new(group: impl Into<String>, names: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionNames>, scope: impl Into<String>) -> Self {
+        CustomResourceDefinitionSpec {
+            additional_printer_columns: None,
+            conversion: None,
+            group: group.into(),
+            names: names.into(),
+            scope: scope.into(),
+            subresources: None,
+            validation: None,
+            version: None,
+            versions: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl CustomResourceDefinitionSpec {
+    /// Returns this value with [`CustomResourceDefinitionSpec::additional_printer_columns`] set to the given value.
+    #[must_use]
+    pub fn with_additional_printer_columns(mut self, value: impl Into<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceColumnDefinition>>) -> Self {
+        self.additional_printer_columns = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`CustomResourceDefinitionSpec::conversion`] set to the given value.
+    #[must_use]
+    pub fn with_conversion(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceConversion>) -> Self {
+        self.conversion = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`CustomResourceDefinitionSpec::group`] set to the given value.
+    #[must_use]
+    pub fn with_group(mut self, value: impl Into<String>) -> Self {
+        self.group = value.into();
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "additionalPrinterColumns" => Field::Key_additional_printer_columns,
-                            "conversion" => Field::Key_conversion,
-                            "group" => Field::Key_group,
-                            "names" => Field::Key_names,
-                            "scope" => Field::Key_scope,
-                            "subresources" => Field::Key_subresources,
-                            "validation" => Field::Key_validation,
-                            "version" => Field::Key_version,
-                            "versions" => Field::Key_versions,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`CustomResourceDefinitionSpec::names`] set to the given value.
+    #[must_use]
+    pub fn with_names(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionNames>) -> Self {
+        self.names = value.into();
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`CustomResourceDefinitionSpec::scope`] set to the given value.
+    #[must_use]
+    pub fn with_scope(mut self, value: impl Into<String>) -> Self {
+        self.scope = value.into();
+        self
+    }
+
+    /// Returns this value with [`CustomResourceDefinitionSpec::subresources`] set to the given value.
+    #[must_use]
+    pub fn with_subresources(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceSubresources>) -> Self {
+        self.subresources = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`CustomResourceDefinitionSpec::validation`] set to the given value.
+    #[must_use]
+    pub fn with_validation(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceValidation>) -> Self {
+        self.validation = Some(value.into());
+        self
+    }
 
+    /// Returns this value with [`CustomResourceDefinitionSpec::version`] set to the given value.
+    #[must_use]
+    pub fn with_version(mut self, value: impl Into<String>) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`CustomResourceDefinitionSpec::versions`] set to the given value.
+    #[must_use]
+    pub fn with_versions(mut self, value: impl Into<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionVersion>>) -> Self {
+        self.versions = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for CustomResourceDefinitionSpec {}
+
+impl CustomResourceDefinitionSpec {
+    const FIELDS: &'static [&'static str] = &[
+        "additionalPrinterColumns",
+        "conversion",
+        "group",
+        "names",
+        "scope",
+        "subresources",
+        "validation",
+        "version",
+        "versions",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for CustomResourceDefinitionSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -98,55 +152,161 @@ impl<'de> crate::serde::Deserialize<'de> for CustomResourceDefinitionSpec {
                 let mut value_version: Option<String> = None;
                 let mut value_versions: Option<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionVersion>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CustomResourceDefinitionSpec", CustomResourceDefinitionSpec::FIELDS))? {
                     match key {
-                        Field::Key_additional_printer_columns => value_additional_printer_columns = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_conversion => value_conversion = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_group => value_group = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_names => value_names = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_scope => value_scope = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_subresources => value_subresources = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_validation => value_validation = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_version => value_version = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_versions => value_versions = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_additional_printer_columns = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_conversion = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_group = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_names = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(4) => value_scope = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(5) => value_subresources = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_validation = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(7) => value_version = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(8) => value_versions = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(CustomResourceDefinitionSpec {
                     additional_printer_columns: value_additional_printer_columns,
                     conversion: value_conversion,
-                    group: value_group.ok_or_else(|| crate::serde::de::Error::missing_field("group"))?,
-                    names: value_names.ok_or_else(|| crate::serde::de::Error::missing_field("names"))?,
-                    scope: value_scope.ok_or_else(|| crate::serde::de::Error::missing_field("scope"))?,
+                    group: value_group.ok_or_else(|| crate::serde2::missing_field("CustomResourceDefinitionSpec", "group", &["group", "names", "scope"]))?,
+                    names: value_names.ok_or_else(|| crate::serde2::missing_field("CustomResourceDefinitionSpec", "names", &["group", "names", "scope"]))?,
+                    scope: value_scope.ok_or_else(|| crate::serde2::missing_field("CustomResourceDefinitionSpec", "scope", &["group", "names", "scope"]))?,
                     subresources: value_subresources,
                     validation: value_validation,
                     version: value_version,
                     versions: value_versions,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(CustomResourceDefinitionSpec {
+                    additional_printer_columns: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    conversion: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    group: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    names: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    scope: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    subresources: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    validation: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    version: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                    versions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "CustomResourceDefinitionSpec",
-            &[
-                "additionalPrinterColumns",
-                "conversion",
-                "group",
-                "names",
-                "scope",
-                "subresources",
-                "validation",
-                "version",
-                "versions",
-            ],
+            CustomResourceDefinitionSpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, CustomResourceDefinitionSpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("CustomResourceDefinitionSpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_additional_printer_columns = false;
+                let mut has_conversion = false;
+                let mut has_group = false;
+                let mut has_names = false;
+                let mut has_scope = false;
+                let mut has_subresources = false;
+                let mut has_validation = false;
+                let mut has_version = false;
+                let mut has_versions = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CustomResourceDefinitionSpec", CustomResourceDefinitionSpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.additional_printer_columns))?; has_additional_printer_columns = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.conversion))?; has_conversion = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.group))?; has_group = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.names))?; has_names = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.scope))?; has_scope = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.subresources))?; has_subresources = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.validation))?; has_validation = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.version))?; has_version = true; },
+                        Some(8) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.versions))?; has_versions = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_additional_printer_columns {
+                    self.0.additional_printer_columns = None;
+                }
+                if !has_conversion {
+                    self.0.conversion = None;
+                }
+                if !has_group {
+                    return Err(crate::serde2::missing_field("CustomResourceDefinitionSpec", "group", &["group", "names", "scope"]));
+                }
+                if !has_names {
+                    return Err(crate::serde2::missing_field("CustomResourceDefinitionSpec", "names", &["group", "names", "scope"]));
+                }
+                if !has_scope {
+                    return Err(crate::serde2::missing_field("CustomResourceDefinitionSpec", "scope", &["group", "names", "scope"]));
+                }
+                if !has_subresources {
+                    self.0.subresources = None;
+                }
+                if !has_validation {
+                    self.0.validation = None;
+                }
+                if !has_version {
+                    self.0.version = None;
+                }
+                if !has_versions {
+                    self.0.versions = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.additional_printer_columns))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.conversion))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.group))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.names))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.scope))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.subresources))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.validation))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.version))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.versions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "CustomResourceDefinitionSpec",
+            CustomResourceDefinitionSpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for CustomResourceDefinitionSpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("CustomResourceDefinitionSpec", 9)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "additionalPrinterColumns", &self.additional_printer_columns)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conversion", &self.conversion)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "group", &self.group)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "names", &self.names)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "scope", &self.scope)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "subresources", &self.subresources)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "validation", &self.validation)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "version", &self.version)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "versions", &self.versions)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "CustomResourceDefinitionSpec",
             3 +
@@ -182,10 +342,189 @@ impl crate::serde::Serialize for CustomResourceDefinitionSpec {
     }
 }
 
+/// A builder of [`CustomResourceDefinitionSpec`]. Created with [`CustomResourceDefinitionSpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct CustomResourceDefinitionSpecBuilder {
+    additional_printer_columns: Option<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceColumnDefinition>>,
+    conversion: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceConversion>,
+    group: Option<String>,
+    names: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionNames>,
+    scope: Option<String>,
+    subresources: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceSubresources>,
+    validation: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceValidation>,
+    version: Option<String>,
+    versions: Option<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionVersion>>,
+}
+
+#[cfg(feature = "builder")]
+impl CustomResourceDefinitionSpec {
+    /// Starts building a `CustomResourceDefinitionSpec`. Its required fields must be set before [`CustomResourceDefinitionSpecBuilder::build`] is called.
+    pub fn builder() -> CustomResourceDefinitionSpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl CustomResourceDefinitionSpecBuilder {
+    /// Sets [`CustomResourceDefinitionSpec::additional_printer_columns`].
+    pub fn additional_printer_columns(mut self, value: impl Into<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceColumnDefinition>>) -> Self {
+        self.additional_printer_columns = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceDefinitionSpec::conversion`].
+    pub fn conversion(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceConversion>) -> Self {
+        self.conversion = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceDefinitionSpec::group`].
+    pub fn group(mut self, value: impl Into<String>) -> Self {
+        self.group = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceDefinitionSpec::names`].
+    pub fn names(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionNames>) -> Self {
+        self.names = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceDefinitionSpec::scope`].
+    pub fn scope(mut self, value: impl Into<String>) -> Self {
+        self.scope = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceDefinitionSpec::subresources`].
+    pub fn subresources(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceSubresources>) -> Self {
+        self.subresources = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceDefinitionSpec::validation`].
+    pub fn validation(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceValidation>) -> Self {
+        self.validation = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceDefinitionSpec::version`].
+    pub fn version(mut self, value: impl Into<String>) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceDefinitionSpec::versions`].
+    pub fn versions(mut self, value: impl Into<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionVersion>>) -> Self {
+        self.versions = Some(value.into());
+        self
+    }
+
+    /// Builds the `CustomResourceDefinitionSpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<CustomResourceDefinitionSpec, crate::MissingFieldError> {
+        Ok(CustomResourceDefinitionSpec {
+            additional_printer_columns: self.additional_printer_columns,
+            conversion: self.conversion,
+            group: self.group.ok_or_else(|| crate::MissingFieldError::new("CustomResourceDefinitionSpec", "group"))?,
+            names: self.names.ok_or_else(|| crate::MissingFieldError::new("CustomResourceDefinitionSpec", "names"))?,
+            scope: self.scope.ok_or_else(|| crate::MissingFieldError::new("CustomResourceDefinitionSpec", "scope"))?,
+            subresources: self.subresources,
+            validation: self.validation,
+            version: self.version,
+            versions: self.versions,
+        })
+    }
+}
+
+/// The apply configuration of [`CustomResourceDefinitionSpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CustomResourceDefinitionSpecApplyConfiguration {
+    /// See [`CustomResourceDefinitionSpec::additional_printer_columns`].
+    pub additional_printer_columns: Option<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceColumnDefinitionApplyConfiguration>>,
+
+    /// See [`CustomResourceDefinitionSpec::conversion`].
+    pub conversion: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceConversionApplyConfiguration>,
+
+    /// See [`CustomResourceDefinitionSpec::group`].
+    pub group: Option<String>,
+
+    /// See [`CustomResourceDefinitionSpec::names`].
+    pub names: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionNamesApplyConfiguration>,
+
+    /// See [`CustomResourceDefinitionSpec::scope`].
+    pub scope: Option<String>,
+
+    /// See [`CustomResourceDefinitionSpec::subresources`].
+    pub subresources: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceSubresourcesApplyConfiguration>,
+
+    /// See [`CustomResourceDefinitionSpec::validation`].
+    pub validation: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceValidationApplyConfiguration>,
+
+    /// See [`CustomResourceDefinitionSpec::version`].
+    pub version: Option<String>,
+
+    /// See [`CustomResourceDefinitionSpec::versions`].
+    pub versions: Option<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionVersionApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for CustomResourceDefinitionSpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "CustomResourceDefinitionSpecApplyConfiguration",
+            self.additional_printer_columns.as_ref().map_or(0, |_| 1) +
+            self.conversion.as_ref().map_or(0, |_| 1) +
+            self.group.as_ref().map_or(0, |_| 1) +
+            self.names.as_ref().map_or(0, |_| 1) +
+            self.scope.as_ref().map_or(0, |_| 1) +
+            self.subresources.as_ref().map_or(0, |_| 1) +
+            self.validation.as_ref().map_or(0, |_| 1) +
+            self.version.as_ref().map_or(0, |_| 1) +
+            self.versions.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.additional_printer_columns {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "additionalPrinterColumns", value)?;
+        }
+        if let Some(value) = &self.conversion {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conversion", value)?;
+        }
+        if let Some(value) = &self.group {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "group", value)?;
+        }
+        if let Some(value) = &self.names {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "names", value)?;
+        }
+        if let Some(value) = &self.scope {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "scope", value)?;
+        }
+        if let Some(value) = &self.subresources {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "subresources", value)?;
+        }
+        if let Some(value) = &self.validation {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "validation", value)?;
+        }
+        if let Some(value) = &self.version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "version", value)?;
+        }
+        if let Some(value) = &self.versions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "versions", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CustomResourceDefinitionSpec {
     fn schema_name() -> String {
-        "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.CustomResourceDefinitionSpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionSpec".to_owned()
+        }
+        else {
+            "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.CustomResourceDefinitionSpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -196,8 +535,9 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionSpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "additionalPrinterColumns".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -211,8 +551,8 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "conversion".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceConversion>().into_object();
@@ -222,8 +562,8 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "group".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -233,8 +573,8 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "names".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionNames>().into_object();
@@ -244,8 +584,8 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "scope".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -255,8 +595,8 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "subresources".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceSubresources>().into_object();
@@ -266,8 +606,8 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "validation".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceValidation>().into_object();
@@ -277,8 +617,8 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "version".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -288,8 +628,8 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "versions".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -303,8 +643,9 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "group",
                     "names",
@@ -316,3 +657,20 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionSpec {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for CustomResourceDefinitionSpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(CustomResourceDefinitionSpec {
+            additional_printer_columns: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            conversion: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            group: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            names: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            scope: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            subresources: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            validation: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            version: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            versions: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
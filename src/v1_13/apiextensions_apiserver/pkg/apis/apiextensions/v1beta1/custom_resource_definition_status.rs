@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.CustomResourceDefinitionStatus
 
 /// CustomResourceDefinitionStatus indicates the state of the CustomResourceDefinition
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct CustomResourceDefinitionStatus {
     /// AcceptedNames are the names that are actually being used to serve discovery They may be different than the names in spec.
     pub accepted_names: crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionNames,
@@ -13,41 +13,52 @@ pub struct CustomResourceDefinitionStatus {
     pub stored_versions: Vec<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for CustomResourceDefinitionStatus {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_accepted_names,
-            Key_conditions,
-            Key_stored_versions,
-            Other,
+impl CustomResourceDefinitionStatus {
+    /// Constructs a `CustomResourceDefinitionStatus` from its required fields. Its optional fields are unset.
+    pub fn new(accepted_names: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionNames>, stored_versions: impl Into<Vec<String>>) -> Self {
+        CustomResourceDefinitionStatus {
+            accepted_names: accepted_names.into(),
+            conditions: None,
+            stored_versions: stored_versions.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl CustomResourceDefinitionStatus {
+    /// Returns this value with [`CustomResourceDefinitionStatus::accepted_names`] set to the given value.
+    #[must_use]
+    pub fn with_accepted_names(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionNames>) -> Self {
+        self.accepted_names = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`CustomResourceDefinitionStatus::conditions`] set to the given value.
+    #[must_use]
+    pub fn with_conditions(mut self, value: impl Into<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionCondition>>) -> Self {
+        self.conditions = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`CustomResourceDefinitionStatus::stored_versions`] set to the given value.
+    #[must_use]
+    pub fn with_stored_versions(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.stored_versions = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "acceptedNames" => Field::Key_accepted_names,
-                            "conditions" => Field::Key_conditions,
-                            "storedVersions" => Field::Key_stored_versions,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for CustomResourceDefinitionStatus {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl CustomResourceDefinitionStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "acceptedNames",
+        "conditions",
+        "storedVersions",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for CustomResourceDefinitionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,37 +73,101 @@ impl<'de> crate::serde::Deserialize<'de> for CustomResourceDefinitionStatus {
                 let mut value_conditions: Option<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionCondition>> = None;
                 let mut value_stored_versions: Option<Vec<String>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CustomResourceDefinitionStatus", CustomResourceDefinitionStatus::FIELDS))? {
                     match key {
-                        Field::Key_accepted_names => value_accepted_names = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_conditions => value_conditions = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_stored_versions => value_stored_versions = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_accepted_names = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_conditions = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_stored_versions = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(CustomResourceDefinitionStatus {
-                    accepted_names: value_accepted_names.ok_or_else(|| crate::serde::de::Error::missing_field("acceptedNames"))?,
+                    accepted_names: value_accepted_names.ok_or_else(|| crate::serde2::missing_field("CustomResourceDefinitionStatus", "acceptedNames", &["acceptedNames", "storedVersions"]))?,
                     conditions: value_conditions,
-                    stored_versions: value_stored_versions.ok_or_else(|| crate::serde::de::Error::missing_field("storedVersions"))?,
+                    stored_versions: value_stored_versions.ok_or_else(|| crate::serde2::missing_field("CustomResourceDefinitionStatus", "storedVersions", &["acceptedNames", "storedVersions"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(CustomResourceDefinitionStatus {
+                    accepted_names: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    conditions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    stored_versions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "CustomResourceDefinitionStatus",
-            &[
-                "acceptedNames",
-                "conditions",
-                "storedVersions",
-            ],
+            CustomResourceDefinitionStatus::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, CustomResourceDefinitionStatus> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("CustomResourceDefinitionStatus")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_accepted_names = false;
+                let mut has_conditions = false;
+                let mut has_stored_versions = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CustomResourceDefinitionStatus", CustomResourceDefinitionStatus::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.accepted_names))?; has_accepted_names = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.conditions))?; has_conditions = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.stored_versions))?; has_stored_versions = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_accepted_names {
+                    return Err(crate::serde2::missing_field("CustomResourceDefinitionStatus", "acceptedNames", &["acceptedNames", "storedVersions"]));
+                }
+                if !has_conditions {
+                    self.0.conditions = None;
+                }
+                if !has_stored_versions {
+                    return Err(crate::serde2::missing_field("CustomResourceDefinitionStatus", "storedVersions", &["acceptedNames", "storedVersions"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.accepted_names))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.conditions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.stored_versions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "CustomResourceDefinitionStatus",
+            CustomResourceDefinitionStatus::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for CustomResourceDefinitionStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("CustomResourceDefinitionStatus", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "acceptedNames", &self.accepted_names)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conditions", &self.conditions)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "storedVersions", &self.stored_versions)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "CustomResourceDefinitionStatus",
             2 +
@@ -107,10 +182,99 @@ impl crate::serde::Serialize for CustomResourceDefinitionStatus {
     }
 }
 
+/// A builder of [`CustomResourceDefinitionStatus`]. Created with [`CustomResourceDefinitionStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct CustomResourceDefinitionStatusBuilder {
+    accepted_names: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionNames>,
+    conditions: Option<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionCondition>>,
+    stored_versions: Option<Vec<String>>,
+}
+
+#[cfg(feature = "builder")]
+impl CustomResourceDefinitionStatus {
+    /// Starts building a `CustomResourceDefinitionStatus`. Its required fields must be set before [`CustomResourceDefinitionStatusBuilder::build`] is called.
+    pub fn builder() -> CustomResourceDefinitionStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl CustomResourceDefinitionStatusBuilder {
+    /// Sets [`CustomResourceDefinitionStatus::accepted_names`].
+    pub fn accepted_names(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionNames>) -> Self {
+        self.accepted_names = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceDefinitionStatus::conditions`].
+    pub fn conditions(mut self, value: impl Into<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionCondition>>) -> Self {
+        self.conditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceDefinitionStatus::stored_versions`].
+    pub fn stored_versions(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.stored_versions = Some(value.into());
+        self
+    }
+
+    /// Builds the `CustomResourceDefinitionStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<CustomResourceDefinitionStatus, crate::MissingFieldError> {
+        Ok(CustomResourceDefinitionStatus {
+            accepted_names: self.accepted_names.ok_or_else(|| crate::MissingFieldError::new("CustomResourceDefinitionStatus", "acceptedNames"))?,
+            conditions: self.conditions,
+            stored_versions: self.stored_versions.ok_or_else(|| crate::MissingFieldError::new("CustomResourceDefinitionStatus", "storedVersions"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`CustomResourceDefinitionStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CustomResourceDefinitionStatusApplyConfiguration {
+    /// See [`CustomResourceDefinitionStatus::accepted_names`].
+    pub accepted_names: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionNamesApplyConfiguration>,
+
+    /// See [`CustomResourceDefinitionStatus::conditions`].
+    pub conditions: Option<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionConditionApplyConfiguration>>,
+
+    /// See [`CustomResourceDefinitionStatus::stored_versions`].
+    pub stored_versions: Option<Vec<String>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for CustomResourceDefinitionStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "CustomResourceDefinitionStatusApplyConfiguration",
+            self.accepted_names.as_ref().map_or(0, |_| 1) +
+            self.conditions.as_ref().map_or(0, |_| 1) +
+            self.stored_versions.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.accepted_names {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "acceptedNames", value)?;
+        }
+        if let Some(value) = &self.conditions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conditions", value)?;
+        }
+        if let Some(value) = &self.stored_versions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "storedVersions", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CustomResourceDefinitionStatus {
     fn schema_name() -> String {
-        "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.CustomResourceDefinitionStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionStatus".to_owned()
+        }
+        else {
+            "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.CustomResourceDefinitionStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -121,8 +285,9 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "acceptedNames".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionNames>().into_object();
@@ -132,8 +297,8 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "conditions".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -147,8 +312,8 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "storedVersions".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -167,8 +332,9 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "acceptedNames",
                     "storedVersions",
@@ -179,3 +345,14 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionStatus {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for CustomResourceDefinitionStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(CustomResourceDefinitionStatus {
+            accepted_names: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            conditions: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            stored_versions: crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -22,47 +22,79 @@ pub struct CustomResourceDefinitionVersion {
     pub subresources: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceSubresources>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for CustomResourceDefinitionVersion {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_additional_printer_columns,
-            Key_name,
-            Key_schema,
-            Key_served,
-            Key_storage,
-            Key_subresources,
-            Other,
+impl CustomResourceDefinitionVersion {
+    /// Constructs a `CustomResourceDefinitionVersion` from its required fields. Its optional fields are unset.
+    pub fn new(name: impl Into<String>, served: impl Into<bool>, storage: impl Into<bool>) -> Self {
+        CustomResourceDefinitionVersion {
+            additional_printer_columns: None,
+            name: name.into(),
+            schema: None,
+            served: served.into(),
+            storage: storage.into(),
+            subresources: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl CustomResourceDefinitionVersion {
+    /// Returns this value with [`CustomResourceDefinitionVersion::additional_printer_columns`] set to the given value.
+    #[must_use]
+    pub fn with_additional_printer_columns(mut self, value: impl Into<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceColumnDefinition>>) -> Self {
+        self.additional_printer_columns = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`CustomResourceDefinitionVersion::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`CustomResourceDefinitionVersion::schema`] set to the given value.
+    #[must_use]
+    pub fn with_schema(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceValidation>) -> Self {
+        self.schema = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "additionalPrinterColumns" => Field::Key_additional_printer_columns,
-                            "name" => Field::Key_name,
-                            "schema" => Field::Key_schema,
-                            "served" => Field::Key_served,
-                            "storage" => Field::Key_storage,
-                            "subresources" => Field::Key_subresources,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`CustomResourceDefinitionVersion::served`] set to the given value.
+    #[must_use]
+    pub fn with_served(mut self, value: impl Into<bool>) -> Self {
+        self.served = value.into();
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`CustomResourceDefinitionVersion::storage`] set to the given value.
+    #[must_use]
+    pub fn with_storage(mut self, value: impl Into<bool>) -> Self {
+        self.storage = value.into();
+        self
+    }
 
+    /// Returns this value with [`CustomResourceDefinitionVersion::subresources`] set to the given value.
+    #[must_use]
+    pub fn with_subresources(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceSubresources>) -> Self {
+        self.subresources = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for CustomResourceDefinitionVersion {}
+
+impl CustomResourceDefinitionVersion {
+    const FIELDS: &'static [&'static str] = &[
+        "additionalPrinterColumns",
+        "name",
+        "schema",
+        "served",
+        "storage",
+        "subresources",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for CustomResourceDefinitionVersion {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -80,46 +112,131 @@ impl<'de> crate::serde::Deserialize<'de> for CustomResourceDefinitionVersion {
                 let mut value_storage: Option<bool> = None;
                 let mut value_subresources: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceSubresources> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CustomResourceDefinitionVersion", CustomResourceDefinitionVersion::FIELDS))? {
                     match key {
-                        Field::Key_additional_printer_columns => value_additional_printer_columns = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_name => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_schema => value_schema = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_served => value_served = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_storage => value_storage = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_subresources => value_subresources = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_additional_printer_columns = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_schema = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_served = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(4) => value_storage = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(5) => value_subresources = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(CustomResourceDefinitionVersion {
                     additional_printer_columns: value_additional_printer_columns,
-                    name: value_name.ok_or_else(|| crate::serde::de::Error::missing_field("name"))?,
+                    name: value_name.ok_or_else(|| crate::serde2::missing_field("CustomResourceDefinitionVersion", "name", &["name", "served", "storage"]))?,
                     schema: value_schema,
-                    served: value_served.ok_or_else(|| crate::serde::de::Error::missing_field("served"))?,
-                    storage: value_storage.ok_or_else(|| crate::serde::de::Error::missing_field("storage"))?,
+                    served: value_served.ok_or_else(|| crate::serde2::missing_field("CustomResourceDefinitionVersion", "served", &["name", "served", "storage"]))?,
+                    storage: value_storage.ok_or_else(|| crate::serde2::missing_field("CustomResourceDefinitionVersion", "storage", &["name", "served", "storage"]))?,
                     subresources: value_subresources,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(CustomResourceDefinitionVersion {
+                    additional_printer_columns: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    schema: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    served: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    storage: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    subresources: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "CustomResourceDefinitionVersion",
-            &[
-                "additionalPrinterColumns",
-                "name",
-                "schema",
-                "served",
-                "storage",
-                "subresources",
-            ],
+            CustomResourceDefinitionVersion::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, CustomResourceDefinitionVersion> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("CustomResourceDefinitionVersion")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_additional_printer_columns = false;
+                let mut has_name = false;
+                let mut has_schema = false;
+                let mut has_served = false;
+                let mut has_storage = false;
+                let mut has_subresources = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CustomResourceDefinitionVersion", CustomResourceDefinitionVersion::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.additional_printer_columns))?; has_additional_printer_columns = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.name))?; has_name = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.schema))?; has_schema = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.served))?; has_served = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.storage))?; has_storage = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.subresources))?; has_subresources = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_additional_printer_columns {
+                    self.0.additional_printer_columns = None;
+                }
+                if !has_name {
+                    return Err(crate::serde2::missing_field("CustomResourceDefinitionVersion", "name", &["name", "served", "storage"]));
+                }
+                if !has_schema {
+                    self.0.schema = None;
+                }
+                if !has_served {
+                    return Err(crate::serde2::missing_field("CustomResourceDefinitionVersion", "served", &["name", "served", "storage"]));
+                }
+                if !has_storage {
+                    return Err(crate::serde2::missing_field("CustomResourceDefinitionVersion", "storage", &["name", "served", "storage"]));
+                }
+                if !has_subresources {
+                    self.0.subresources = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.additional_printer_columns))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.schema))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.served))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.storage))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.subresources))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "CustomResourceDefinitionVersion",
+            CustomResourceDefinitionVersion::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for CustomResourceDefinitionVersion {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("CustomResourceDefinitionVersion", 6)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "additionalPrinterColumns", &self.additional_printer_columns)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "schema", &self.schema)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "served", &self.served)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "storage", &self.storage)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "subresources", &self.subresources)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "CustomResourceDefinitionVersion",
             3 +
@@ -143,10 +260,144 @@ impl crate::serde::Serialize for CustomResourceDefinitionVersion {
     }
 }
 
+/// A builder of [`CustomResourceDefinitionVersion`]. Created with [`CustomResourceDefinitionVersion::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct CustomResourceDefinitionVersionBuilder {
+    additional_printer_columns: Option<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceColumnDefinition>>,
+    name: Option<String>,
+    schema: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceValidation>,
+    served: Option<bool>,
+    storage: Option<bool>,
+    subresources: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceSubresources>,
+}
+
+#[cfg(feature = "builder")]
+impl CustomResourceDefinitionVersion {
+    /// Starts building a `CustomResourceDefinitionVersion`. Its required fields must be set before [`CustomResourceDefinitionVersionBuilder::build`] is called.
+    pub fn builder() -> CustomResourceDefinitionVersionBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl CustomResourceDefinitionVersionBuilder {
+    /// Sets [`CustomResourceDefinitionVersion::additional_printer_columns`].
+    pub fn additional_printer_columns(mut self, value: impl Into<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceColumnDefinition>>) -> Self {
+        self.additional_printer_columns = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceDefinitionVersion::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceDefinitionVersion::schema`].
+    pub fn schema(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceValidation>) -> Self {
+        self.schema = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceDefinitionVersion::served`].
+    pub fn served(mut self, value: impl Into<bool>) -> Self {
+        self.served = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceDefinitionVersion::storage`].
+    pub fn storage(mut self, value: impl Into<bool>) -> Self {
+        self.storage = Some(value.into());
+        self
+    }
+
+    /// Sets [`CustomResourceDefinitionVersion::subresources`].
+    pub fn subresources(mut self, value: impl Into<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceSubresources>) -> Self {
+        self.subresources = Some(value.into());
+        self
+    }
+
+    /// Builds the `CustomResourceDefinitionVersion`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<CustomResourceDefinitionVersion, crate::MissingFieldError> {
+        Ok(CustomResourceDefinitionVersion {
+            additional_printer_columns: self.additional_printer_columns,
+            name: self.name.ok_or_else(|| crate::MissingFieldError::new("CustomResourceDefinitionVersion", "name"))?,
+            schema: self.schema,
+            served: self.served.ok_or_else(|| crate::MissingFieldError::new("CustomResourceDefinitionVersion", "served"))?,
+            storage: self.storage.ok_or_else(|| crate::MissingFieldError::new("CustomResourceDefinitionVersion", "storage"))?,
+            subresources: self.subresources,
+        })
+    }
+}
+
+/// The apply configuration of [`CustomResourceDefinitionVersion`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CustomResourceDefinitionVersionApplyConfiguration {
+    /// See [`CustomResourceDefinitionVersion::additional_printer_columns`].
+    pub additional_printer_columns: Option<Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceColumnDefinitionApplyConfiguration>>,
+
+    /// See [`CustomResourceDefinitionVersion::name`].
+    pub name: Option<String>,
+
+    /// See [`CustomResourceDefinitionVersion::schema`].
+    pub schema: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceValidationApplyConfiguration>,
+
+    /// See [`CustomResourceDefinitionVersion::served`].
+    pub served: Option<bool>,
+
+    /// See [`CustomResourceDefinitionVersion::storage`].
+    pub storage: Option<bool>,
+
+    /// See [`CustomResourceDefinitionVersion::subresources`].
+    pub subresources: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceSubresourcesApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for CustomResourceDefinitionVersionApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "CustomResourceDefinitionVersionApplyConfiguration",
+            self.additional_printer_columns.as_ref().map_or(0, |_| 1) +
+            self.name.as_ref().map_or(0, |_| 1) +
+            self.schema.as_ref().map_or(0, |_| 1) +
+            self.served.as_ref().map_or(0, |_| 1) +
+            self.storage.as_ref().map_or(0, |_| 1) +
+            self.subresources.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.additional_printer_columns {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "additionalPrinterColumns", value)?;
+        }
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        if let Some(value) = &self.schema {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "schema", value)?;
+        }
+        if let Some(value) = &self.served {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "served", value)?;
+        }
+        if let Some(value) = &self.storage {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "storage", value)?;
+        }
+        if let Some(value) = &self.subresources {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "subresources", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CustomResourceDefinitionVersion {
     fn schema_name() -> String {
-        "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.CustomResourceDefinitionVersion".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionVersion".to_owned()
+        }
+        else {
+            "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.CustomResourceDefinitionVersion".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -157,8 +408,9 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionVersion {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "additionalPrinterColumns".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -172,8 +424,8 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionVersion {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -183,8 +435,8 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionVersion {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "schema".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceValidation>().into_object();
@@ -194,8 +446,8 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionVersion {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "served".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -205,8 +457,8 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionVersion {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "storage".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -216,8 +468,8 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionVersion {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "subresources".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceSubresources>().into_object();
@@ -227,8 +479,9 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionVersion {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "name",
                     "served",
@@ -240,3 +493,17 @@ impl crate::schemars::JsonSchema for CustomResourceDefinitionVersion {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for CustomResourceDefinitionVersion {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(CustomResourceDefinitionVersion {
+            additional_printer_columns: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            schema: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            served: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            storage: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            subresources: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
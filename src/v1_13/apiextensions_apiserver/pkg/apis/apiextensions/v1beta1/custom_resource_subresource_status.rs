@@ -16,7 +16,7 @@ impl<'de> crate::serde::Deserialize<'de> for CustomResourceSubresourceStatus {
             }
 
             fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: crate::serde::Deserializer<'de> {
-                Ok(CustomResourceSubresourceStatus(crate::serde::Deserialize::deserialize(deserializer)?))
+                Ok(CustomResourceSubresourceStatus(crate::serde2::deserialize_json_value(deserializer)?))
             }
         }
 
@@ -26,14 +26,19 @@ impl<'de> crate::serde::Deserialize<'de> for CustomResourceSubresourceStatus {
 
 impl crate::serde::Serialize for CustomResourceSubresourceStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
-        serializer.serialize_newtype_struct("CustomResourceSubresourceStatus", &self.0)
+        serializer.serialize_newtype_struct("CustomResourceSubresourceStatus", &crate::serde2::JsonValue(&self.0))
     }
 }
 
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CustomResourceSubresourceStatus {
     fn schema_name() -> String {
-        "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.CustomResourceSubresourceStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceSubresourceStatus".to_owned()
+        }
+        else {
+            "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.CustomResourceSubresourceStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -43,7 +48,17 @@ impl crate::schemars::JsonSchema for CustomResourceSubresourceStatus {
                 ..Default::default()
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            extensions: std::array::IntoIter::new([
+                ("x-kubernetes-preserve-unknown-fields".to_owned(), crate::serde_json::Value::Bool(true)),
+            ]).collect(),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for CustomResourceSubresourceStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        Ok(CustomResourceSubresourceStatus(crate::arbitrary2::json_value(u)?))
+    }
+}
@@ -25,14 +25,36 @@ impl<'de> crate::serde::Deserialize<'de> for JSONSchemaPropsOrBool {
             fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E> where E: crate::serde::de::Error {
                 Ok(JSONSchemaPropsOrBool::Bool(v))
             }
+
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::EnumAccess<'de> {
+                let (variant, value) = crate::serde::de::EnumAccess::variant::<u32>(data)?;
+                match variant {
+                    0 => Ok(JSONSchemaPropsOrBool::Schema(crate::serde::de::VariantAccess::newtype_variant(value)?)),
+                    1 => Ok(JSONSchemaPropsOrBool::Bool(crate::serde::de::VariantAccess::newtype_variant(value)?)),
+                    _ => Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Unsigned(variant.into()), &"variant index 0 <= i < 2")),
+                }
+            }
         }
 
-        deserializer.deserialize_any(Visitor)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(Visitor)
+        }
+        else {
+            // Formats that are not self-describing cannot deserialize untagged values, so the variant is serialized explicitly.
+            deserializer.deserialize_enum("JSONSchemaPropsOrBool", &["Schema", "Bool"], Visitor)
+        }
     }
 }
 
 impl crate::serde::Serialize for JSONSchemaPropsOrBool {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            return match self {
+                JSONSchemaPropsOrBool::Schema(value) => serializer.serialize_newtype_variant("JSONSchemaPropsOrBool", 0, "Schema", value),
+                JSONSchemaPropsOrBool::Bool(value) => serializer.serialize_newtype_variant("JSONSchemaPropsOrBool", 1, "Bool", value),
+            };
+        }
+
         match self {
             JSONSchemaPropsOrBool::Schema(value) => value.serialize(serializer),
             JSONSchemaPropsOrBool::Bool(value) => value.serialize(serializer),
@@ -43,7 +65,12 @@ impl crate::serde::Serialize for JSONSchemaPropsOrBool {
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for JSONSchemaPropsOrBool {
     fn schema_name() -> String {
-        "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.JSONSchemaPropsOrBool".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaPropsOrBool".to_owned()
+        }
+        else {
+            "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.JSONSchemaPropsOrBool".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -66,3 +93,10 @@ impl crate::schemars::JsonSchema for JSONSchemaPropsOrBool {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for JSONSchemaPropsOrBool {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        Ok(match crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)? { Some(schema) => JSONSchemaPropsOrBool::Schema(schema), None => JSONSchemaPropsOrBool::Bool(u.arbitrary()?) })
+    }
+}
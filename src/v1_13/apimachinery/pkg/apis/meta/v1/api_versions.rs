@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.apimachinery.pkg.apis.meta.v1.APIVersions
 
 /// APIVersions lists the versions that are available, to allow clients to discover the API at /api, which is the root path of the legacy v1 API.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct APIVersions {
     /// a map of client CIDR to server address that is serving this group. This is to help clients reach servers in the most network-efficient way possible. Clients can use the appropriate server address as per the CIDR that they match. In case of multiple matches, clients should use the longest matching CIDR. The server returns only those CIDRs that it thinks that the client can match. For example: the master will return an internal IP CIDR only, if the client reaches the server using an internal IP. Server looks at X-Forwarded-For header or X-Real-Ip header or request.RemoteAddr (in that order) to get the client IP.
     pub server_address_by_client_cidrs: Vec<crate::apimachinery::pkg::apis::meta::v1::ServerAddressByClientCIDR>,
@@ -10,52 +10,57 @@ pub struct APIVersions {
     pub versions: Vec<String>,
 }
 
+impl APIVersions {
+    /// Constructs a `APIVersions` from its required fields. Its optional fields are unset.
+    pub fn new(server_address_by_client_cidrs: impl Into<Vec<crate::apimachinery::pkg::apis::meta::v1::ServerAddressByClientCIDR>>, versions: impl Into<Vec<String>>) -> Self {
+        APIVersions {
+            server_address_by_client_cidrs: server_address_by_client_cidrs.into(),
+            versions: versions.into(),
+        }
+    }
+}
+
+impl APIVersions {
+    /// Returns this value with [`APIVersions::server_address_by_client_cidrs`] set to the given value.
+    #[must_use]
+    pub fn with_server_address_by_client_cidrs(mut self, value: impl Into<Vec<crate::apimachinery::pkg::apis::meta::v1::ServerAddressByClientCIDR>>) -> Self {
+        self.server_address_by_client_cidrs = value.into();
+        self
+    }
+
+    /// Returns this value with [`APIVersions::versions`] set to the given value.
+    #[must_use]
+    pub fn with_versions(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.versions = value.into();
+        self
+    }
+}
+
 impl crate::Resource for APIVersions {
     const API_VERSION: &'static str = "v1";
     const GROUP: &'static str = "";
     const KIND: &'static str = "APIVersions";
     const VERSION: &'static str = "v1";
     const URL_PATH_SEGMENT: &'static str = "";
+    const VERBS: &'static [&'static str] = &[];
+    const SINGULAR: &'static str = "apiversions";
+    const SHORT_NAMES: &'static [&'static str] = &[];
     type Scope = crate::ClusterResourceScope;
 }
 
-impl<'de> crate::serde::Deserialize<'de> for APIVersions {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_api_version,
-            Key_kind,
-            Key_server_address_by_client_cidrs,
-            Key_versions,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl crate::MergeStrategyInfo for APIVersions {}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
-
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "apiVersion" => Field::Key_api_version,
-                            "kind" => Field::Key_kind,
-                            "serverAddressByClientCIDRs" => Field::Key_server_address_by_client_cidrs,
-                            "versions" => Field::Key_versions,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl APIVersions {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "kind",
+        "serverAddressByClientCIDRs",
+        "versions",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for APIVersions {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -69,48 +74,115 @@ impl<'de> crate::serde::Deserialize<'de> for APIVersions {
                 let mut value_server_address_by_client_cidrs: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::ServerAddressByClientCIDR>> = None;
                 let mut value_versions: Option<Vec<String>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("APIVersions", APIVersions::FIELDS))? {
                     match key {
-                        Field::Key_api_version => {
+                        Some(0) => {
                             let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_api_version != <Self::Value as crate::Resource>::API_VERSION {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<Self::Value as crate::Resource>::API_VERSION));
                             }
                         },
-                        Field::Key_kind => {
+                        Some(1) => {
                             let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
                             if value_kind != <Self::Value as crate::Resource>::KIND {
                                 return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<Self::Value as crate::Resource>::KIND));
                             }
                         },
-                        Field::Key_server_address_by_client_cidrs => value_server_address_by_client_cidrs = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_versions => value_versions = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(2) => value_server_address_by_client_cidrs = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_versions = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(APIVersions {
-                    server_address_by_client_cidrs: value_server_address_by_client_cidrs.ok_or_else(|| crate::serde::de::Error::missing_field("serverAddressByClientCIDRs"))?,
-                    versions: value_versions.ok_or_else(|| crate::serde::de::Error::missing_field("versions"))?,
+                    server_address_by_client_cidrs: value_server_address_by_client_cidrs.ok_or_else(|| crate::serde2::missing_field("APIVersions", "serverAddressByClientCIDRs", &["serverAddressByClientCIDRs", "versions"]))?,
+                    versions: value_versions.ok_or_else(|| crate::serde2::missing_field("APIVersions", "versions", &["serverAddressByClientCIDRs", "versions"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(APIVersions {
+                    server_address_by_client_cidrs: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    versions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             <Self as crate::Resource>::KIND,
-            &[
-                "apiVersion",
-                "kind",
-                "serverAddressByClientCIDRs",
-                "versions",
-            ],
+            APIVersions::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, APIVersions> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<APIVersions as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_server_address_by_client_cidrs = false;
+                let mut has_versions = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("APIVersions", APIVersions::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <APIVersions as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<APIVersions as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <APIVersions as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<APIVersions as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.server_address_by_client_cidrs))?; has_server_address_by_client_cidrs = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.versions))?; has_versions = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_server_address_by_client_cidrs {
+                    return Err(crate::serde2::missing_field("APIVersions", "serverAddressByClientCIDRs", &["serverAddressByClientCIDRs", "versions"]));
+                }
+                if !has_versions {
+                    return Err(crate::serde2::missing_field("APIVersions", "versions", &["serverAddressByClientCIDRs", "versions"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.server_address_by_client_cidrs))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.versions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            APIVersions::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for APIVersions {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct(<Self as crate::Resource>::KIND, 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "serverAddressByClientCIDRs", &self.server_address_by_client_cidrs)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "versions", &self.versions)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             <Self as crate::Resource>::KIND,
             4,
@@ -123,10 +195,87 @@ impl crate::serde::Serialize for APIVersions {
     }
 }
 
+/// A builder of [`APIVersions`]. Created with [`APIVersions::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct APIVersionsBuilder {
+    server_address_by_client_cidrs: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::ServerAddressByClientCIDR>>,
+    versions: Option<Vec<String>>,
+}
+
+#[cfg(feature = "builder")]
+impl APIVersions {
+    /// Starts building a `APIVersions`. Its required fields must be set before [`APIVersionsBuilder::build`] is called.
+    pub fn builder() -> APIVersionsBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl APIVersionsBuilder {
+    /// Sets [`APIVersions::server_address_by_client_cidrs`].
+    pub fn server_address_by_client_cidrs(mut self, value: impl Into<Vec<crate::apimachinery::pkg::apis::meta::v1::ServerAddressByClientCIDR>>) -> Self {
+        self.server_address_by_client_cidrs = Some(value.into());
+        self
+    }
+
+    /// Sets [`APIVersions::versions`].
+    pub fn versions(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.versions = Some(value.into());
+        self
+    }
+
+    /// Builds the `APIVersions`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<APIVersions, crate::MissingFieldError> {
+        Ok(APIVersions {
+            server_address_by_client_cidrs: self.server_address_by_client_cidrs.ok_or_else(|| crate::MissingFieldError::new("APIVersions", "serverAddressByClientCIDRs"))?,
+            versions: self.versions.ok_or_else(|| crate::MissingFieldError::new("APIVersions", "versions"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`APIVersions`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct APIVersionsApplyConfiguration {
+    /// See [`APIVersions::server_address_by_client_cidrs`].
+    pub server_address_by_client_cidrs: Option<Vec<crate::apimachinery::pkg::apis::meta::v1::ServerAddressByClientCIDRApplyConfiguration>>,
+
+    /// See [`APIVersions::versions`].
+    pub versions: Option<Vec<String>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for APIVersionsApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            <APIVersions as crate::Resource>::KIND,
+            2 +
+            self.server_address_by_client_cidrs.as_ref().map_or(0, |_| 1) +
+            self.versions.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <APIVersions as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <APIVersions as crate::Resource>::KIND)?;
+        if let Some(value) = &self.server_address_by_client_cidrs {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "serverAddressByClientCIDRs", value)?;
+        }
+        if let Some(value) = &self.versions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "versions", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for APIVersions {
     fn schema_name() -> String {
-        "io.k8s.apimachinery.pkg.apis.meta.v1.APIVersions".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apimachinery::pkg::apis::meta::v1::APIVersions".to_owned()
+        }
+        else {
+            "io.k8s.apimachinery.pkg.apis.meta.v1.APIVersions".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -137,8 +286,9 @@ impl crate::schemars::JsonSchema for APIVersions {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "apiVersion".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -146,10 +296,11 @@ impl crate::schemars::JsonSchema for APIVersions {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("v1".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "kind".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -157,10 +308,11 @@ impl crate::schemars::JsonSchema for APIVersions {
                                 ..Default::default()
                             })),
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("APIVersions".to_owned())]),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "serverAddressByClientCIDRs".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -174,8 +326,8 @@ impl crate::schemars::JsonSchema for APIVersions {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "versions".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -194,9 +346,12 @@ impl crate::schemars::JsonSchema for APIVersions {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
+                    "apiVersion",
+                    "kind",
                     "serverAddressByClientCIDRs",
                     "versions",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -206,3 +361,13 @@ impl crate::schemars::JsonSchema for APIVersions {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for APIVersions {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(APIVersions {
+            server_address_by_client_cidrs: crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            versions: crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
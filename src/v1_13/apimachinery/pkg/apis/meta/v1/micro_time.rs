@@ -1,9 +1,15 @@
 // Generated from definition io.k8s.apimachinery.pkg.apis.meta.v1.MicroTime
 
 /// MicroTime is version of Time with microsecond level precision.
-#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg(feature = "chrono")]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct MicroTime(pub crate::chrono::DateTime<crate::chrono::Utc>);
 
+/// MicroTime is version of Time with microsecond level precision.
+#[cfg(not(feature = "chrono"))]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct MicroTime(pub String);
+
 impl<'de> crate::serde::Deserialize<'de> for MicroTime {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
@@ -24,16 +30,30 @@ impl<'de> crate::serde::Deserialize<'de> for MicroTime {
     }
 }
 
+#[cfg(feature = "chrono")]
 impl crate::serde::Serialize for MicroTime {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
-        serializer.serialize_newtype_struct("MicroTime", &self.0.to_rfc3339_opts(chrono::SecondsFormat::Micros, true))
+        serializer.serialize_newtype_struct("MicroTime", &self.0.to_rfc3339_opts(crate::chrono::SecondsFormat::Micros, true))
     }
 }
 
+#[cfg(not(feature = "chrono"))]
+impl crate::serde::Serialize for MicroTime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        serializer.serialize_newtype_struct("MicroTime", &self.0)
+    }
+}
+
+#[cfg(feature = "chrono")]
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for MicroTime {
     fn schema_name() -> String {
-        "io.k8s.apimachinery.pkg.apis.meta.v1.MicroTime".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apimachinery::pkg::apis::meta::v1::MicroTime".to_owned()
+        }
+        else {
+            "io.k8s.apimachinery.pkg.apis.meta.v1.MicroTime".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -48,3 +68,43 @@ impl crate::schemars::JsonSchema for MicroTime {
         })
     }
 }
+
+#[cfg(not(feature = "chrono"))]
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for MicroTime {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "apimachinery::pkg::apis::meta::v1::MicroTime".to_owned()
+        }
+        else {
+            "io.k8s.apimachinery.pkg.apis.meta.v1.MicroTime".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("MicroTime is version of Time with microsecond level precision.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for MicroTime {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        Ok(MicroTime(crate::arbitrary2::date_time(u)?))
+    }
+}
+
+#[cfg(not(feature = "chrono"))]
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for MicroTime {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        Ok(MicroTime(crate::arbitrary::Arbitrary::arbitrary(u)?))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.apimachinery.pkg.apis.meta.v1.ServerAddressByClientCIDR
 
 /// ServerAddressByClientCIDR helps the client to determine the server address that they should use, depending on the clientCIDR that they match.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ServerAddressByClientCIDR {
     /// The CIDR with which clients can match their IP to figure out the server address that they should use.
     pub client_cidr: String,
@@ -10,39 +10,43 @@ pub struct ServerAddressByClientCIDR {
     pub server_address: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ServerAddressByClientCIDR {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_client_cidr,
-            Key_server_address,
-            Other,
+impl ServerAddressByClientCIDR {
+    /// Constructs a `ServerAddressByClientCIDR` from its required fields. Its optional fields are unset.
+    pub fn new(client_cidr: impl Into<String>, server_address: impl Into<String>) -> Self {
+        ServerAddressByClientCIDR {
+            client_cidr: client_cidr.into(),
+            server_address: server_address.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl ServerAddressByClientCIDR {
+    /// Returns this value with [`ServerAddressByClientCIDR::client_cidr`] set to the given value.
+    #[must_use]
+    pub fn with_client_cidr(mut self, value: impl Into<String>) -> Self {
+        self.client_cidr = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ServerAddressByClientCIDR::server_address`] set to the given value.
+    #[must_use]
+    pub fn with_server_address(mut self, value: impl Into<String>) -> Self {
+        self.server_address = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "clientCIDR" => Field::Key_client_cidr,
-                            "serverAddress" => Field::Key_server_address,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for ServerAddressByClientCIDR {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl ServerAddressByClientCIDR {
+    const FIELDS: &'static [&'static str] = &[
+        "clientCIDR",
+        "serverAddress",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ServerAddressByClientCIDR {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +60,91 @@ impl<'de> crate::serde::Deserialize<'de> for ServerAddressByClientCIDR {
                 let mut value_client_cidr: Option<String> = None;
                 let mut value_server_address: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ServerAddressByClientCIDR", ServerAddressByClientCIDR::FIELDS))? {
                     match key {
-                        Field::Key_client_cidr => value_client_cidr = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_server_address => value_server_address = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_client_cidr = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_server_address = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ServerAddressByClientCIDR {
-                    client_cidr: value_client_cidr.ok_or_else(|| crate::serde::de::Error::missing_field("clientCIDR"))?,
-                    server_address: value_server_address.ok_or_else(|| crate::serde::de::Error::missing_field("serverAddress"))?,
+                    client_cidr: value_client_cidr.ok_or_else(|| crate::serde2::missing_field("ServerAddressByClientCIDR", "clientCIDR", &["clientCIDR", "serverAddress"]))?,
+                    server_address: value_server_address.ok_or_else(|| crate::serde2::missing_field("ServerAddressByClientCIDR", "serverAddress", &["clientCIDR", "serverAddress"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ServerAddressByClientCIDR {
+                    client_cidr: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    server_address: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "ServerAddressByClientCIDR",
-            &[
-                "clientCIDR",
-                "serverAddress",
-            ],
+            ServerAddressByClientCIDR::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ServerAddressByClientCIDR> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ServerAddressByClientCIDR")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_client_cidr = false;
+                let mut has_server_address = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ServerAddressByClientCIDR", ServerAddressByClientCIDR::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.client_cidr))?; has_client_cidr = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.server_address))?; has_server_address = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_client_cidr {
+                    return Err(crate::serde2::missing_field("ServerAddressByClientCIDR", "clientCIDR", &["clientCIDR", "serverAddress"]));
+                }
+                if !has_server_address {
+                    return Err(crate::serde2::missing_field("ServerAddressByClientCIDR", "serverAddress", &["clientCIDR", "serverAddress"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.client_cidr))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.server_address))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ServerAddressByClientCIDR",
+            ServerAddressByClientCIDR::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ServerAddressByClientCIDR {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ServerAddressByClientCIDR", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clientCIDR", &self.client_cidr)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "serverAddress", &self.server_address)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ServerAddressByClientCIDR",
             2,
@@ -94,10 +155,84 @@ impl crate::serde::Serialize for ServerAddressByClientCIDR {
     }
 }
 
+/// A builder of [`ServerAddressByClientCIDR`]. Created with [`ServerAddressByClientCIDR::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ServerAddressByClientCIDRBuilder {
+    client_cidr: Option<String>,
+    server_address: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl ServerAddressByClientCIDR {
+    /// Starts building a `ServerAddressByClientCIDR`. Its required fields must be set before [`ServerAddressByClientCIDRBuilder::build`] is called.
+    pub fn builder() -> ServerAddressByClientCIDRBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ServerAddressByClientCIDRBuilder {
+    /// Sets [`ServerAddressByClientCIDR::client_cidr`].
+    pub fn client_cidr(mut self, value: impl Into<String>) -> Self {
+        self.client_cidr = Some(value.into());
+        self
+    }
+
+    /// Sets [`ServerAddressByClientCIDR::server_address`].
+    pub fn server_address(mut self, value: impl Into<String>) -> Self {
+        self.server_address = Some(value.into());
+        self
+    }
+
+    /// Builds the `ServerAddressByClientCIDR`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ServerAddressByClientCIDR, crate::MissingFieldError> {
+        Ok(ServerAddressByClientCIDR {
+            client_cidr: self.client_cidr.ok_or_else(|| crate::MissingFieldError::new("ServerAddressByClientCIDR", "clientCIDR"))?,
+            server_address: self.server_address.ok_or_else(|| crate::MissingFieldError::new("ServerAddressByClientCIDR", "serverAddress"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`ServerAddressByClientCIDR`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ServerAddressByClientCIDRApplyConfiguration {
+    /// See [`ServerAddressByClientCIDR::client_cidr`].
+    pub client_cidr: Option<String>,
+
+    /// See [`ServerAddressByClientCIDR::server_address`].
+    pub server_address: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ServerAddressByClientCIDRApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ServerAddressByClientCIDRApplyConfiguration",
+            self.client_cidr.as_ref().map_or(0, |_| 1) +
+            self.server_address.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.client_cidr {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clientCIDR", value)?;
+        }
+        if let Some(value) = &self.server_address {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "serverAddress", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ServerAddressByClientCIDR {
     fn schema_name() -> String {
-        "io.k8s.apimachinery.pkg.apis.meta.v1.ServerAddressByClientCIDR".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "apimachinery::pkg::apis::meta::v1::ServerAddressByClientCIDR".to_owned()
+        }
+        else {
+            "io.k8s.apimachinery.pkg.apis.meta.v1.ServerAddressByClientCIDR".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -108,8 +243,9 @@ impl crate::schemars::JsonSchema for ServerAddressByClientCIDR {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "clientCIDR".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -119,8 +255,8 @@ impl crate::schemars::JsonSchema for ServerAddressByClientCIDR {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "serverAddress".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -130,8 +266,9 @@ impl crate::schemars::JsonSchema for ServerAddressByClientCIDR {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "clientCIDR",
                     "serverAddress",
@@ -142,3 +279,13 @@ impl crate::schemars::JsonSchema for ServerAddressByClientCIDR {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ServerAddressByClientCIDR {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ServerAddressByClientCIDR {
+            client_cidr: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            server_address: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
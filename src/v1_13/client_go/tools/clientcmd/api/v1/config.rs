@@ -0,0 +1,582 @@
+// Generated from definition io.k8s.client-go.tools.clientcmd.api.v1.Config
+
+/// Config holds the information needed to build connect to remote kubernetes clusters as a given user
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Config {
+    /// Clusters is a map of referencable names to cluster configs
+    pub clusters: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedCluster>>,
+
+    /// Contexts is a map of referencable names to context configs
+    pub contexts: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedContext>>,
+
+    /// CurrentContext is the name of the context that you would like to use by default
+    pub current_context: Option<String>,
+
+    /// Extensions holds additional information. This is useful for extenders so that reads and writes don't clobber unknown fields
+    pub extensions: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtension>>,
+
+    /// Preferences holds general information to be use for cli interactions
+    pub preferences: Option<crate::client_go::tools::clientcmd::api::v1::Preferences>,
+
+    /// AuthInfos is a map of referencable names to user configs
+    pub users: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedAuthInfo>>,
+}
+
+impl Config {
+    /// Returns this value with [`Config::clusters`] set to the given value.
+    #[must_use]
+    pub fn with_clusters(mut self, value: impl Into<Vec<crate::client_go::tools::clientcmd::api::v1::NamedCluster>>) -> Self {
+        self.clusters = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Config::contexts`] set to the given value.
+    #[must_use]
+    pub fn with_contexts(mut self, value: impl Into<Vec<crate::client_go::tools::clientcmd::api::v1::NamedContext>>) -> Self {
+        self.contexts = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Config::current_context`] set to the given value.
+    #[must_use]
+    pub fn with_current_context(mut self, value: impl Into<String>) -> Self {
+        self.current_context = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Config::extensions`] set to the given value.
+    #[must_use]
+    pub fn with_extensions(mut self, value: impl Into<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtension>>) -> Self {
+        self.extensions = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Config::preferences`] set to the given value.
+    #[must_use]
+    pub fn with_preferences(mut self, value: impl Into<crate::client_go::tools::clientcmd::api::v1::Preferences>) -> Self {
+        self.preferences = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Config::users`] set to the given value.
+    #[must_use]
+    pub fn with_users(mut self, value: impl Into<Vec<crate::client_go::tools::clientcmd::api::v1::NamedAuthInfo>>) -> Self {
+        self.users = Some(value.into());
+        self
+    }
+}
+
+impl crate::Resource for Config {
+    const API_VERSION: &'static str = "v1";
+    const GROUP: &'static str = "";
+    const KIND: &'static str = "Config";
+    const VERSION: &'static str = "v1";
+    const URL_PATH_SEGMENT: &'static str = "";
+    const VERBS: &'static [&'static str] = &[];
+    const SINGULAR: &'static str = "config";
+    const SHORT_NAMES: &'static [&'static str] = &[];
+    type Scope = crate::ClusterResourceScope;
+}
+
+impl crate::MergeStrategyInfo for Config {}
+
+impl Config {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "kind",
+        "clusters",
+        "contexts",
+        "current-context",
+        "extensions",
+        "preferences",
+        "users",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for Config {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = Config;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<Self::Value as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_clusters: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedCluster>> = None;
+                let mut value_contexts: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedContext>> = None;
+                let mut value_current_context: Option<String> = None;
+                let mut value_extensions: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtension>> = None;
+                let mut value_preferences: Option<crate::client_go::tools::clientcmd::api::v1::Preferences> = None;
+                let mut value_users: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedAuthInfo>> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Config", Config::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <Self::Value as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<Self::Value as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <Self::Value as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<Self::Value as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => value_clusters = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_contexts = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_current_context = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_extensions = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_preferences = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(7) => value_users = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(Config {
+                    clusters: value_clusters,
+                    contexts: value_contexts,
+                    current_context: value_current_context,
+                    extensions: value_extensions,
+                    preferences: value_preferences,
+                    users: value_users,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(Config {
+                    clusters: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    contexts: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    current_context: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    extensions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    preferences: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    users: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            Config::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, Config> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<Config as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_clusters = false;
+                let mut has_contexts = false;
+                let mut has_current_context = false;
+                let mut has_extensions = false;
+                let mut has_preferences = false;
+                let mut has_users = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Config", Config::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <Config as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<Config as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <Config as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<Config as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.clusters))?; has_clusters = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.contexts))?; has_contexts = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.current_context))?; has_current_context = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.extensions))?; has_extensions = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.preferences))?; has_preferences = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.users))?; has_users = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_clusters {
+                    self.0.clusters = None;
+                }
+                if !has_contexts {
+                    self.0.contexts = None;
+                }
+                if !has_current_context {
+                    self.0.current_context = None;
+                }
+                if !has_extensions {
+                    self.0.extensions = None;
+                }
+                if !has_preferences {
+                    self.0.preferences = None;
+                }
+                if !has_users {
+                    self.0.users = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.clusters))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.contexts))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.current_context))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.extensions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.preferences))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.users))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            Config::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for Config {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct(<Self as crate::Resource>::KIND, 6)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clusters", &self.clusters)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "contexts", &self.contexts)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "current-context", &self.current_context)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "extensions", &self.extensions)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "preferences", &self.preferences)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "users", &self.users)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            <Self as crate::Resource>::KIND,
+            2 +
+            self.clusters.as_ref().map_or(0, |_| 1) +
+            self.contexts.as_ref().map_or(0, |_| 1) +
+            self.current_context.as_ref().map_or(0, |_| 1) +
+            self.extensions.as_ref().map_or(0, |_| 1) +
+            self.preferences.as_ref().map_or(0, |_| 1) +
+            self.users.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <Self as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <Self as crate::Resource>::KIND)?;
+        if let Some(value) = &self.clusters {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clusters", value)?;
+        }
+        if let Some(value) = &self.contexts {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "contexts", value)?;
+        }
+        if let Some(value) = &self.current_context {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "current-context", value)?;
+        }
+        if let Some(value) = &self.extensions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "extensions", value)?;
+        }
+        if let Some(value) = &self.preferences {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "preferences", value)?;
+        }
+        if let Some(value) = &self.users {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "users", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`Config`]. Created with [`Config::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ConfigBuilder {
+    clusters: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedCluster>>,
+    contexts: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedContext>>,
+    current_context: Option<String>,
+    extensions: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtension>>,
+    preferences: Option<crate::client_go::tools::clientcmd::api::v1::Preferences>,
+    users: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedAuthInfo>>,
+}
+
+#[cfg(feature = "builder")]
+impl Config {
+    /// Starts building a `Config`. Its required fields must be set before [`ConfigBuilder::build`] is called.
+    pub fn builder() -> ConfigBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ConfigBuilder {
+    /// Sets [`Config::clusters`].
+    pub fn clusters(mut self, value: impl Into<Vec<crate::client_go::tools::clientcmd::api::v1::NamedCluster>>) -> Self {
+        self.clusters = Some(value.into());
+        self
+    }
+
+    /// Sets [`Config::contexts`].
+    pub fn contexts(mut self, value: impl Into<Vec<crate::client_go::tools::clientcmd::api::v1::NamedContext>>) -> Self {
+        self.contexts = Some(value.into());
+        self
+    }
+
+    /// Sets [`Config::current_context`].
+    pub fn current_context(mut self, value: impl Into<String>) -> Self {
+        self.current_context = Some(value.into());
+        self
+    }
+
+    /// Sets [`Config::extensions`].
+    pub fn extensions(mut self, value: impl Into<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtension>>) -> Self {
+        self.extensions = Some(value.into());
+        self
+    }
+
+    /// Sets [`Config::preferences`].
+    pub fn preferences(mut self, value: impl Into<crate::client_go::tools::clientcmd::api::v1::Preferences>) -> Self {
+        self.preferences = Some(value.into());
+        self
+    }
+
+    /// Sets [`Config::users`].
+    pub fn users(mut self, value: impl Into<Vec<crate::client_go::tools::clientcmd::api::v1::NamedAuthInfo>>) -> Self {
+        self.users = Some(value.into());
+        self
+    }
+
+    /// Builds the `Config`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<Config, crate::MissingFieldError> {
+        Ok(Config {
+            clusters: self.clusters,
+            contexts: self.contexts,
+            current_context: self.current_context,
+            extensions: self.extensions,
+            preferences: self.preferences,
+            users: self.users,
+        })
+    }
+}
+
+/// The apply configuration of [`Config`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConfigApplyConfiguration {
+    /// See [`Config::clusters`].
+    pub clusters: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedClusterApplyConfiguration>>,
+
+    /// See [`Config::contexts`].
+    pub contexts: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedContextApplyConfiguration>>,
+
+    /// See [`Config::current_context`].
+    pub current_context: Option<String>,
+
+    /// See [`Config::extensions`].
+    pub extensions: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtensionApplyConfiguration>>,
+
+    /// See [`Config::preferences`].
+    pub preferences: Option<crate::client_go::tools::clientcmd::api::v1::PreferencesApplyConfiguration>,
+
+    /// See [`Config::users`].
+    pub users: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedAuthInfoApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ConfigApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            <Config as crate::Resource>::KIND,
+            2 +
+            self.clusters.as_ref().map_or(0, |_| 1) +
+            self.contexts.as_ref().map_or(0, |_| 1) +
+            self.current_context.as_ref().map_or(0, |_| 1) +
+            self.extensions.as_ref().map_or(0, |_| 1) +
+            self.preferences.as_ref().map_or(0, |_| 1) +
+            self.users.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <Config as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <Config as crate::Resource>::KIND)?;
+        if let Some(value) = &self.clusters {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clusters", value)?;
+        }
+        if let Some(value) = &self.contexts {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "contexts", value)?;
+        }
+        if let Some(value) = &self.current_context {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "current-context", value)?;
+        }
+        if let Some(value) = &self.extensions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "extensions", value)?;
+        }
+        if let Some(value) = &self.preferences {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "preferences", value)?;
+        }
+        if let Some(value) = &self.users {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "users", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for Config {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "client_go::tools::clientcmd::api::v1::Config".to_owned()
+        }
+        else {
+            "io.k8s.client-go.tools.clientcmd.api.v1.Config".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("Config holds the information needed to build connect to remote kubernetes clusters as a given user".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "apiVersion".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#resources".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("v1".to_owned())]),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "clusters".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Clusters is a map of referencable names to cluster configs".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Array))),
+                            array: Some(Box::new(crate::schemars::schema::ArrayValidation {
+                                items: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(__gen.subschema_for::<crate::client_go::tools::clientcmd::api::v1::NamedCluster>()))),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "contexts".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Contexts is a map of referencable names to context configs".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Array))),
+                            array: Some(Box::new(crate::schemars::schema::ArrayValidation {
+                                items: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(__gen.subschema_for::<crate::client_go::tools::clientcmd::api::v1::NamedContext>()))),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "current-context".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("CurrentContext is the name of the context that you would like to use by default".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "extensions".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Extensions holds additional information. This is useful for extenders so that reads and writes don't clobber unknown fields".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Array))),
+                            array: Some(Box::new(crate::schemars::schema::ArrayValidation {
+                                items: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(__gen.subschema_for::<crate::client_go::tools::clientcmd::api::v1::NamedExtension>()))),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "kind".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#types-kinds".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("Config".to_owned())]),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "preferences".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::client_go::tools::clientcmd::api::v1::Preferences>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Preferences holds general information to be use for cli interactions".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
+                        "users".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("AuthInfos is a map of referencable names to user configs".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Array))),
+                            array: Some(Box::new(crate::schemars::schema::ArrayValidation {
+                                items: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(__gen.subschema_for::<crate::client_go::tools::clientcmd::api::v1::NamedAuthInfo>()))),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                required: std::array::IntoIter::new([
+                    "apiVersion",
+                    "kind",
+                ]).map(std::borrow::ToOwned::to_owned).collect(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for Config {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(Config {
+            clusters: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            contexts: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            current_context: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            extensions: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            preferences: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            users: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
@@ -0,0 +1,403 @@
+// Generated from definition io.k8s.client-go.tools.clientcmd.api.v1.Context
+
+/// Context is a tuple of references to a cluster (how do I communicate with a kubernetes cluster), a user (how do I identify myself), and a namespace (what subset of resources do I want to work with)
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Context {
+    /// Cluster is the name of the cluster for this context
+    pub cluster: String,
+
+    /// Extensions holds additional information. This is useful for extenders so that reads and writes don't clobber unknown fields
+    pub extensions: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtension>>,
+
+    /// Namespace is the default namespace to use on unspecified requests
+    pub namespace: Option<String>,
+
+    /// AuthInfo is the name of the authInfo for this context
+    pub user: String,
+}
+
+impl Context {
+    /// Constructs a `Context` from its required fields. Its optional fields are unset.
+    pub fn new(cluster: impl Into<String>, user: impl Into<String>) -> Self {
+        Context {
+            cluster: cluster.into(),
+            extensions: None,
+            namespace: None,
+            user: user.into(),
+        }
+    }
+}
+
+impl Context {
+    /// Returns this value with [`Context::cluster`] set to the given value.
+    #[must_use]
+    pub fn with_cluster(mut self, value: impl Into<String>) -> Self {
+        self.cluster = value.into();
+        self
+    }
+
+    /// Returns this value with [`Context::extensions`] set to the given value.
+    #[must_use]
+    pub fn with_extensions(mut self, value: impl Into<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtension>>) -> Self {
+        self.extensions = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Context::namespace`] set to the given value.
+    #[must_use]
+    pub fn with_namespace(mut self, value: impl Into<String>) -> Self {
+        self.namespace = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Context::user`] set to the given value.
+    #[must_use]
+    pub fn with_user(mut self, value: impl Into<String>) -> Self {
+        self.user = value.into();
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for Context {}
+
+impl Context {
+    const FIELDS: &'static [&'static str] = &[
+        "cluster",
+        "extensions",
+        "namespace",
+        "user",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for Context {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = Context;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("Context")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_cluster: Option<String> = None;
+                let mut value_extensions: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtension>> = None;
+                let mut value_namespace: Option<String> = None;
+                let mut value_user: Option<String> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Context", Context::FIELDS))? {
+                    match key {
+                        Some(0) => value_cluster = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_extensions = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_namespace = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_user = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(Context {
+                    cluster: value_cluster.ok_or_else(|| crate::serde2::missing_field("Context", "cluster", &["cluster", "user"]))?,
+                    extensions: value_extensions,
+                    namespace: value_namespace,
+                    user: value_user.ok_or_else(|| crate::serde2::missing_field("Context", "user", &["cluster", "user"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(Context {
+                    cluster: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    extensions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    namespace: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    user: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "Context",
+            Context::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, Context> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("Context")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_cluster = false;
+                let mut has_extensions = false;
+                let mut has_namespace = false;
+                let mut has_user = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Context", Context::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.cluster))?; has_cluster = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.extensions))?; has_extensions = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.namespace))?; has_namespace = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.user))?; has_user = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_cluster {
+                    return Err(crate::serde2::missing_field("Context", "cluster", &["cluster", "user"]));
+                }
+                if !has_extensions {
+                    self.0.extensions = None;
+                }
+                if !has_namespace {
+                    self.0.namespace = None;
+                }
+                if !has_user {
+                    return Err(crate::serde2::missing_field("Context", "user", &["cluster", "user"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.cluster))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.extensions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.namespace))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.user))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "Context",
+            Context::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for Context {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("Context", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cluster", &self.cluster)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "extensions", &self.extensions)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "namespace", &self.namespace)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "user", &self.user)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            "Context",
+            2 +
+            self.extensions.as_ref().map_or(0, |_| 1) +
+            self.namespace.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cluster", &self.cluster)?;
+        if let Some(value) = &self.extensions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "extensions", value)?;
+        }
+        if let Some(value) = &self.namespace {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "namespace", value)?;
+        }
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "user", &self.user)?;
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`Context`]. Created with [`Context::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ContextBuilder {
+    cluster: Option<String>,
+    extensions: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtension>>,
+    namespace: Option<String>,
+    user: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl Context {
+    /// Starts building a `Context`. Its required fields must be set before [`ContextBuilder::build`] is called.
+    pub fn builder() -> ContextBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ContextBuilder {
+    /// Sets [`Context::cluster`].
+    pub fn cluster(mut self, value: impl Into<String>) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Sets [`Context::extensions`].
+    pub fn extensions(mut self, value: impl Into<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtension>>) -> Self {
+        self.extensions = Some(value.into());
+        self
+    }
+
+    /// Sets [`Context::namespace`].
+    pub fn namespace(mut self, value: impl Into<String>) -> Self {
+        self.namespace = Some(value.into());
+        self
+    }
+
+    /// Sets [`Context::user`].
+    pub fn user(mut self, value: impl Into<String>) -> Self {
+        self.user = Some(value.into());
+        self
+    }
+
+    /// Builds the `Context`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<Context, crate::MissingFieldError> {
+        Ok(Context {
+            cluster: self.cluster.ok_or_else(|| crate::MissingFieldError::new("Context", "cluster"))?,
+            extensions: self.extensions,
+            namespace: self.namespace,
+            user: self.user.ok_or_else(|| crate::MissingFieldError::new("Context", "user"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`Context`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ContextApplyConfiguration {
+    /// See [`Context::cluster`].
+    pub cluster: Option<String>,
+
+    /// See [`Context::extensions`].
+    pub extensions: Option<Vec<crate::client_go::tools::clientcmd::api::v1::NamedExtensionApplyConfiguration>>,
+
+    /// See [`Context::namespace`].
+    pub namespace: Option<String>,
+
+    /// See [`Context::user`].
+    pub user: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ContextApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ContextApplyConfiguration",
+            self.cluster.as_ref().map_or(0, |_| 1) +
+            self.extensions.as_ref().map_or(0, |_| 1) +
+            self.namespace.as_ref().map_or(0, |_| 1) +
+            self.user.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.cluster {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cluster", value)?;
+        }
+        if let Some(value) = &self.extensions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "extensions", value)?;
+        }
+        if let Some(value) = &self.namespace {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "namespace", value)?;
+        }
+        if let Some(value) = &self.user {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "user", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for Context {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "client_go::tools::clientcmd::api::v1::Context".to_owned()
+        }
+        else {
+            "io.k8s.client-go.tools.clientcmd.api.v1.Context".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("Context is a tuple of references to a cluster (how do I communicate with a kubernetes cluster), a user (how do I identify myself), and a namespace (what subset of resources do I want to work with)".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "cluster".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Cluster is the name of the cluster for this context".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "extensions".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Extensions holds additional information. This is useful for extenders so that reads and writes don't clobber unknown fields".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Array))),
+                            array: Some(Box::new(crate::schemars::schema::ArrayValidation {
+                                items: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(__gen.subschema_for::<crate::client_go::tools::clientcmd::api::v1::NamedExtension>()))),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "namespace".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Namespace is the default namespace to use on unspecified requests".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "user".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("AuthInfo is the name of the authInfo for this context".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                required: std::array::IntoIter::new([
+                    "cluster",
+                    "user",
+                ]).map(std::borrow::ToOwned::to_owned).collect(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for Context {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(Context {
+            cluster: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            extensions: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            namespace: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            user: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
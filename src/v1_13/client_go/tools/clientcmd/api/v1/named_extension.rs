@@ -0,0 +1,291 @@
+// Generated from definition io.k8s.client-go.tools.clientcmd.api.v1.NamedExtension
+
+/// NamedExtension relates nicknames to extension information
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NamedExtension {
+    /// Extension holds the extension information
+    pub extension: crate::apimachinery::pkg::runtime::RawExtension,
+
+    /// Name is the nickname for this Extension
+    pub name: String,
+}
+
+impl NamedExtension {
+    /// Constructs a `NamedExtension` from its required fields. Its optional fields are unset.
+    pub fn new(extension: impl Into<crate::apimachinery::pkg::runtime::RawExtension>, name: impl Into<String>) -> Self {
+        NamedExtension {
+            extension: extension.into(),
+            name: name.into(),
+        }
+    }
+}
+
+impl NamedExtension {
+    /// Returns this value with [`NamedExtension::extension`] set to the given value.
+    #[must_use]
+    pub fn with_extension(mut self, value: impl Into<crate::apimachinery::pkg::runtime::RawExtension>) -> Self {
+        self.extension = value.into();
+        self
+    }
+
+    /// Returns this value with [`NamedExtension::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for NamedExtension {}
+
+impl NamedExtension {
+    const FIELDS: &'static [&'static str] = &[
+        "extension",
+        "name",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for NamedExtension {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = NamedExtension;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("NamedExtension")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_extension: Option<crate::apimachinery::pkg::runtime::RawExtension> = None;
+                let mut value_name: Option<String> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NamedExtension", NamedExtension::FIELDS))? {
+                    match key {
+                        Some(0) => value_extension = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(NamedExtension {
+                    extension: value_extension.ok_or_else(|| crate::serde2::missing_field("NamedExtension", "extension", &["extension", "name"]))?,
+                    name: value_name.ok_or_else(|| crate::serde2::missing_field("NamedExtension", "name", &["extension", "name"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(NamedExtension {
+                    extension: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "NamedExtension",
+            NamedExtension::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, NamedExtension> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("NamedExtension")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_extension = false;
+                let mut has_name = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NamedExtension", NamedExtension::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.extension))?; has_extension = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.name))?; has_name = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_extension {
+                    return Err(crate::serde2::missing_field("NamedExtension", "extension", &["extension", "name"]));
+                }
+                if !has_name {
+                    return Err(crate::serde2::missing_field("NamedExtension", "name", &["extension", "name"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.extension))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "NamedExtension",
+            NamedExtension::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for NamedExtension {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("NamedExtension", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "extension", &self.extension)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            "NamedExtension",
+            2,
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "extension", &self.extension)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`NamedExtension`]. Created with [`NamedExtension::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct NamedExtensionBuilder {
+    extension: Option<crate::apimachinery::pkg::runtime::RawExtension>,
+    name: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl NamedExtension {
+    /// Starts building a `NamedExtension`. Its required fields must be set before [`NamedExtensionBuilder::build`] is called.
+    pub fn builder() -> NamedExtensionBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl NamedExtensionBuilder {
+    /// Sets [`NamedExtension::extension`].
+    pub fn extension(mut self, value: impl Into<crate::apimachinery::pkg::runtime::RawExtension>) -> Self {
+        self.extension = Some(value.into());
+        self
+    }
+
+    /// Sets [`NamedExtension::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `NamedExtension`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<NamedExtension, crate::MissingFieldError> {
+        Ok(NamedExtension {
+            extension: self.extension.ok_or_else(|| crate::MissingFieldError::new("NamedExtension", "extension"))?,
+            name: self.name.ok_or_else(|| crate::MissingFieldError::new("NamedExtension", "name"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`NamedExtension`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NamedExtensionApplyConfiguration {
+    /// See [`NamedExtension::extension`].
+    pub extension: Option<crate::apimachinery::pkg::runtime::RawExtension>,
+
+    /// See [`NamedExtension::name`].
+    pub name: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for NamedExtensionApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "NamedExtensionApplyConfiguration",
+            self.extension.as_ref().map_or(0, |_| 1) +
+            self.name.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.extension {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "extension", value)?;
+        }
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for NamedExtension {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "client_go::tools::clientcmd::api::v1::NamedExtension".to_owned()
+        }
+        else {
+            "io.k8s.client-go.tools.clientcmd.api.v1.NamedExtension".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("NamedExtension relates nicknames to extension information".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "extension".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::runtime::RawExtension>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Extension holds the extension information".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
+                        "name".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Name is the nickname for this Extension".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                required: std::array::IntoIter::new([
+                    "extension",
+                    "name",
+                ]).map(std::borrow::ToOwned::to_owned).collect(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for NamedExtension {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(NamedExtension {
+            extension: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -0,0 +1,553 @@
+// Generated from definition io.k8s.component-base.config.v1alpha1.LeaderElectionConfiguration
+
+/// LeaderElectionConfiguration defines the configuration of leader election clients for components that can run with leader election enabled.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct LeaderElectionConfiguration {
+    /// leaderElect enables a leader election client to gain leadership before executing the main loop. Enable this when running replicated components for high availability.
+    pub leader_elect: Option<bool>,
+
+    /// leaseDuration is the duration that non-leader candidates will wait after observing a leadership renewal until attempting to acquire leadership of a led but unrenewed leader slot. This is effectively the maximum duration that a leader can be stopped before it is replaced by another candidate. This is only applicable if leader election is enabled.
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub lease_duration: Option<String>,
+
+    /// renewDeadline is the interval between attempts by the acting master to renew a leadership slot before it stops leading. This must be less than or equal to the lease duration. This is only applicable if leader election is enabled.
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub renew_deadline: Option<String>,
+
+    /// resourceLock indicates the resource object type that will be used to lock during leader election cycles.
+    pub resource_lock: Option<String>,
+
+    /// resourceName indicates the name of resource object that will be used to lock during leader election cycles.
+    pub resource_name: Option<String>,
+
+    /// resourceName indicates the namespace of resource object that will be used to lock during leader election cycles.
+    pub resource_namespace: Option<String>,
+
+    /// retryPeriod is the duration the clients should wait between attempting acquisition and renewal of a leadership. This is only applicable if leader election is enabled.
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub retry_period: Option<String>,
+}
+
+impl LeaderElectionConfiguration {
+    /// Returns this value with [`LeaderElectionConfiguration::leader_elect`] set to the given value.
+    #[must_use]
+    pub fn with_leader_elect(mut self, value: impl Into<bool>) -> Self {
+        self.leader_elect = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`LeaderElectionConfiguration::lease_duration`] set to the given value.
+    #[must_use]
+    pub fn with_lease_duration(mut self, value: impl Into<String>) -> Self {
+        self.lease_duration = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`LeaderElectionConfiguration::renew_deadline`] set to the given value.
+    #[must_use]
+    pub fn with_renew_deadline(mut self, value: impl Into<String>) -> Self {
+        self.renew_deadline = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`LeaderElectionConfiguration::resource_lock`] set to the given value.
+    #[must_use]
+    pub fn with_resource_lock(mut self, value: impl Into<String>) -> Self {
+        self.resource_lock = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`LeaderElectionConfiguration::resource_name`] set to the given value.
+    #[must_use]
+    pub fn with_resource_name(mut self, value: impl Into<String>) -> Self {
+        self.resource_name = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`LeaderElectionConfiguration::resource_namespace`] set to the given value.
+    #[must_use]
+    pub fn with_resource_namespace(mut self, value: impl Into<String>) -> Self {
+        self.resource_namespace = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`LeaderElectionConfiguration::retry_period`] set to the given value.
+    #[must_use]
+    pub fn with_retry_period(mut self, value: impl Into<String>) -> Self {
+        self.retry_period = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for LeaderElectionConfiguration {}
+
+impl LeaderElectionConfiguration {
+    const FIELDS: &'static [&'static str] = &[
+        "leaderElect",
+        "leaseDuration",
+        "renewDeadline",
+        "resourceLock",
+        "resourceName",
+        "resourceNamespace",
+        "retryPeriod",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for LeaderElectionConfiguration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = LeaderElectionConfiguration;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("LeaderElectionConfiguration")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_leader_elect: Option<bool> = None;
+                let mut value_lease_duration: Option<String> = None;
+                let mut value_renew_deadline: Option<String> = None;
+                let mut value_resource_lock: Option<String> = None;
+                let mut value_resource_name: Option<String> = None;
+                let mut value_resource_namespace: Option<String> = None;
+                let mut value_retry_period: Option<String> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("LeaderElectionConfiguration", LeaderElectionConfiguration::FIELDS))? {
+                    match key {
+                        Some(0) => value_leader_elect = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_lease_duration = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_renew_deadline = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_resource_lock = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_resource_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_resource_namespace = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_retry_period = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(LeaderElectionConfiguration {
+                    leader_elect: value_leader_elect,
+                    lease_duration: value_lease_duration,
+                    renew_deadline: value_renew_deadline,
+                    resource_lock: value_resource_lock,
+                    resource_name: value_resource_name,
+                    resource_namespace: value_resource_namespace,
+                    retry_period: value_retry_period,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(LeaderElectionConfiguration {
+                    leader_elect: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    lease_duration: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    renew_deadline: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    resource_lock: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    resource_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    resource_namespace: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    retry_period: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "LeaderElectionConfiguration",
+            LeaderElectionConfiguration::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, LeaderElectionConfiguration> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("LeaderElectionConfiguration")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_leader_elect = false;
+                let mut has_lease_duration = false;
+                let mut has_renew_deadline = false;
+                let mut has_resource_lock = false;
+                let mut has_resource_name = false;
+                let mut has_resource_namespace = false;
+                let mut has_retry_period = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("LeaderElectionConfiguration", LeaderElectionConfiguration::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.leader_elect))?; has_leader_elect = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.lease_duration))?; has_lease_duration = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.renew_deadline))?; has_renew_deadline = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.resource_lock))?; has_resource_lock = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.resource_name))?; has_resource_name = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.resource_namespace))?; has_resource_namespace = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.retry_period))?; has_retry_period = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_leader_elect {
+                    self.0.leader_elect = None;
+                }
+                if !has_lease_duration {
+                    self.0.lease_duration = None;
+                }
+                if !has_renew_deadline {
+                    self.0.renew_deadline = None;
+                }
+                if !has_resource_lock {
+                    self.0.resource_lock = None;
+                }
+                if !has_resource_name {
+                    self.0.resource_name = None;
+                }
+                if !has_resource_namespace {
+                    self.0.resource_namespace = None;
+                }
+                if !has_retry_period {
+                    self.0.retry_period = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.leader_elect))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.lease_duration))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.renew_deadline))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.resource_lock))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.resource_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.resource_namespace))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.retry_period))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "LeaderElectionConfiguration",
+            LeaderElectionConfiguration::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for LeaderElectionConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("LeaderElectionConfiguration", 7)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "leaderElect", &self.leader_elect)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "leaseDuration", &self.lease_duration)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "renewDeadline", &self.renew_deadline)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resourceLock", &self.resource_lock)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resourceName", &self.resource_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resourceNamespace", &self.resource_namespace)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "retryPeriod", &self.retry_period)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            "LeaderElectionConfiguration",
+            self.leader_elect.as_ref().map_or(0, |_| 1) +
+            self.lease_duration.as_ref().map_or(0, |_| 1) +
+            self.renew_deadline.as_ref().map_or(0, |_| 1) +
+            self.resource_lock.as_ref().map_or(0, |_| 1) +
+            self.resource_name.as_ref().map_or(0, |_| 1) +
+            self.resource_namespace.as_ref().map_or(0, |_| 1) +
+            self.retry_period.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.leader_elect {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "leaderElect", value)?;
+        }
+        if let Some(value) = &self.lease_duration {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "leaseDuration", value)?;
+        }
+        if let Some(value) = &self.renew_deadline {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "renewDeadline", value)?;
+        }
+        if let Some(value) = &self.resource_lock {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resourceLock", value)?;
+        }
+        if let Some(value) = &self.resource_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resourceName", value)?;
+        }
+        if let Some(value) = &self.resource_namespace {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resourceNamespace", value)?;
+        }
+        if let Some(value) = &self.retry_period {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "retryPeriod", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`LeaderElectionConfiguration`]. Created with [`LeaderElectionConfiguration::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct LeaderElectionConfigurationBuilder {
+    leader_elect: Option<bool>,
+    lease_duration: Option<String>,
+    renew_deadline: Option<String>,
+    resource_lock: Option<String>,
+    resource_name: Option<String>,
+    resource_namespace: Option<String>,
+    retry_period: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl LeaderElectionConfiguration {
+    /// Starts building a `LeaderElectionConfiguration`. Its required fields must be set before [`LeaderElectionConfigurationBuilder::build`] is called.
+    pub fn builder() -> LeaderElectionConfigurationBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl LeaderElectionConfigurationBuilder {
+    /// Sets [`LeaderElectionConfiguration::leader_elect`].
+    pub fn leader_elect(mut self, value: impl Into<bool>) -> Self {
+        self.leader_elect = Some(value.into());
+        self
+    }
+
+    /// Sets [`LeaderElectionConfiguration::lease_duration`].
+    pub fn lease_duration(mut self, value: impl Into<String>) -> Self {
+        self.lease_duration = Some(value.into());
+        self
+    }
+
+    /// Sets [`LeaderElectionConfiguration::renew_deadline`].
+    pub fn renew_deadline(mut self, value: impl Into<String>) -> Self {
+        self.renew_deadline = Some(value.into());
+        self
+    }
+
+    /// Sets [`LeaderElectionConfiguration::resource_lock`].
+    pub fn resource_lock(mut self, value: impl Into<String>) -> Self {
+        self.resource_lock = Some(value.into());
+        self
+    }
+
+    /// Sets [`LeaderElectionConfiguration::resource_name`].
+    pub fn resource_name(mut self, value: impl Into<String>) -> Self {
+        self.resource_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`LeaderElectionConfiguration::resource_namespace`].
+    pub fn resource_namespace(mut self, value: impl Into<String>) -> Self {
+        self.resource_namespace = Some(value.into());
+        self
+    }
+
+    /// Sets [`LeaderElectionConfiguration::retry_period`].
+    pub fn retry_period(mut self, value: impl Into<String>) -> Self {
+        self.retry_period = Some(value.into());
+        self
+    }
+
+    /// Builds the `LeaderElectionConfiguration`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<LeaderElectionConfiguration, crate::MissingFieldError> {
+        Ok(LeaderElectionConfiguration {
+            leader_elect: self.leader_elect,
+            lease_duration: self.lease_duration,
+            renew_deadline: self.renew_deadline,
+            resource_lock: self.resource_lock,
+            resource_name: self.resource_name,
+            resource_namespace: self.resource_namespace,
+            retry_period: self.retry_period,
+        })
+    }
+}
+
+/// The apply configuration of [`LeaderElectionConfiguration`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LeaderElectionConfigurationApplyConfiguration {
+    /// See [`LeaderElectionConfiguration::leader_elect`].
+    pub leader_elect: Option<bool>,
+
+    /// See [`LeaderElectionConfiguration::lease_duration`].
+    pub lease_duration: Option<String>,
+
+    /// See [`LeaderElectionConfiguration::renew_deadline`].
+    pub renew_deadline: Option<String>,
+
+    /// See [`LeaderElectionConfiguration::resource_lock`].
+    pub resource_lock: Option<String>,
+
+    /// See [`LeaderElectionConfiguration::resource_name`].
+    pub resource_name: Option<String>,
+
+    /// See [`LeaderElectionConfiguration::resource_namespace`].
+    pub resource_namespace: Option<String>,
+
+    /// See [`LeaderElectionConfiguration::retry_period`].
+    pub retry_period: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for LeaderElectionConfigurationApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "LeaderElectionConfigurationApplyConfiguration",
+            self.leader_elect.as_ref().map_or(0, |_| 1) +
+            self.lease_duration.as_ref().map_or(0, |_| 1) +
+            self.renew_deadline.as_ref().map_or(0, |_| 1) +
+            self.resource_lock.as_ref().map_or(0, |_| 1) +
+            self.resource_name.as_ref().map_or(0, |_| 1) +
+            self.resource_namespace.as_ref().map_or(0, |_| 1) +
+            self.retry_period.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.leader_elect {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "leaderElect", value)?;
+        }
+        if let Some(value) = &self.lease_duration {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "leaseDuration", value)?;
+        }
+        if let Some(value) = &self.renew_deadline {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "renewDeadline", value)?;
+        }
+        if let Some(value) = &self.resource_lock {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resourceLock", value)?;
+        }
+        if let Some(value) = &self.resource_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resourceName", value)?;
+        }
+        if let Some(value) = &self.resource_namespace {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resourceNamespace", value)?;
+        }
+        if let Some(value) = &self.retry_period {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "retryPeriod", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for LeaderElectionConfiguration {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "component_base::config::v1alpha1::LeaderElectionConfiguration".to_owned()
+        }
+        else {
+            "io.k8s.component-base.config.v1alpha1.LeaderElectionConfiguration".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("LeaderElectionConfiguration defines the configuration of leader election clients for components that can run with leader election enabled.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "leaderElect".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("leaderElect enables a leader election client to gain leadership before executing the main loop. Enable this when running replicated components for high availability.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "leaseDuration".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("leaseDuration is the duration that non-leader candidates will wait after observing a leadership renewal until attempting to acquire leadership of a led but unrenewed leader slot. This is effectively the maximum duration that a leader can be stopped before it is replaced by another candidate. This is only applicable if leader election is enabled.\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "renewDeadline".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("renewDeadline is the interval between attempts by the acting master to renew a leadership slot before it stops leading. This must be less than or equal to the lease duration. This is only applicable if leader election is enabled.\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "resourceLock".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("resourceLock indicates the resource object type that will be used to lock during leader election cycles.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "resourceName".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("resourceName indicates the name of resource object that will be used to lock during leader election cycles.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "resourceNamespace".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("resourceName indicates the namespace of resource object that will be used to lock during leader election cycles.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "retryPeriod".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("retryPeriod is the duration the clients should wait between attempting acquisition and renewal of a leadership. This is only applicable if leader election is enabled.\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for LeaderElectionConfiguration {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(LeaderElectionConfiguration {
+            leader_elect: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            lease_duration: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            renew_deadline: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            resource_lock: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            resource_name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            resource_namespace: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            retry_period: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
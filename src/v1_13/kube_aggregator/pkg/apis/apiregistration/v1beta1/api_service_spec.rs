@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.kube-aggregator.pkg.apis.apiregistration.v1beta1.APIServiceSpec
 
 /// APIServiceSpec contains information for locating and communicating with a server. Only https is supported, though you are able to disable certificate verification.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct APIServiceSpec {
     /// CABundle is a PEM encoded CA bundle which will be used to validate an API server's serving certificate. If unspecified, system trust roots on the apiserver are used.
     pub ca_bundle: Option<crate::ByteString>,
@@ -25,49 +25,88 @@ pub struct APIServiceSpec {
     pub version_priority: i32,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for APIServiceSpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_ca_bundle,
-            Key_group,
-            Key_group_priority_minimum,
-            Key_insecure_skip_tls_verify,
-            Key_service,
-            Key_version,
-            Key_version_priority,
-            Other,
+impl APIServiceSpec {
+    /// Constructs a `APIServiceSpec` from its required fields. Its optional fields are unset.
+    pub fn new(group_priority_minimum: impl Into<i32>, service: impl Into<crate::kube_aggregator::pkg::apis::apiregistration::v1beta1::ServiceReference>, version_priority: impl Into<i32>) -> Self {
+        APIServiceSpec {
+            ca_bundle: None,
+            group: None,
+            group_priority_minimum: group_priority_minimum.into(),
+            insecure_skip_tls_verify: None,
+            service: service.into(),
+            version: None,
+            version_priority: version_priority.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl APIServiceSpec {
+    /// Returns this value with [`APIServiceSpec::ca_bundle`] set to the given value.
+    #[must_use]
+    pub fn with_ca_bundle(mut self, value: impl Into<crate::ByteString>) -> Self {
+        self.ca_bundle = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`APIServiceSpec::group`] set to the given value.
+    #[must_use]
+    pub fn with_group(mut self, value: impl Into<String>) -> Self {
+        self.group = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`APIServiceSpec::group_priority_minimum`] set to the given value.
+    #[must_use]
+    pub fn with_group_priority_minimum(mut self, value: impl Into<i32>) -> Self {
+        self.group_priority_minimum = value.into();
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "caBundle" => Field::Key_ca_bundle,
-                            "group" => Field::Key_group,
-                            "groupPriorityMinimum" => Field::Key_group_priority_minimum,
-                            "insecureSkipTLSVerify" => Field::Key_insecure_skip_tls_verify,
-                            "service" => Field::Key_service,
-                            "version" => Field::Key_version,
-                            "versionPriority" => Field::Key_version_priority,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`APIServiceSpec::insecure_skip_tls_verify`] set to the given value.
+    #[must_use]
+    pub fn with_insecure_skip_tls_verify(mut self, value: impl Into<bool>) -> Self {
+        self.insecure_skip_tls_verify = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`APIServiceSpec::service`] set to the given value.
+    #[must_use]
+    pub fn with_service(mut self, value: impl Into<crate::kube_aggregator::pkg::apis::apiregistration::v1beta1::ServiceReference>) -> Self {
+        self.service = value.into();
+        self
+    }
+
+    /// Returns this value with [`APIServiceSpec::version`] set to the given value.
+    #[must_use]
+    pub fn with_version(mut self, value: impl Into<String>) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`APIServiceSpec::version_priority`] set to the given value.
+    #[must_use]
+    pub fn with_version_priority(mut self, value: impl Into<i32>) -> Self {
+        self.version_priority = value.into();
+        self
+    }
+}
 
+impl crate::MergeStrategyInfo for APIServiceSpec {}
+
+impl APIServiceSpec {
+    const FIELDS: &'static [&'static str] = &[
+        "caBundle",
+        "group",
+        "groupPriorityMinimum",
+        "insecureSkipTLSVerify",
+        "service",
+        "version",
+        "versionPriority",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for APIServiceSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -86,49 +125,141 @@ impl<'de> crate::serde::Deserialize<'de> for APIServiceSpec {
                 let mut value_version: Option<String> = None;
                 let mut value_version_priority: Option<i32> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("APIServiceSpec", APIServiceSpec::FIELDS))? {
                     match key {
-                        Field::Key_ca_bundle => value_ca_bundle = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_group => value_group = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_group_priority_minimum => value_group_priority_minimum = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_insecure_skip_tls_verify => value_insecure_skip_tls_verify = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_service => value_service = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_version => value_version = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_version_priority => value_version_priority = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_ca_bundle = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_group = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_group_priority_minimum = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_insecure_skip_tls_verify = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_service = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(5) => value_version = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_version_priority = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(APIServiceSpec {
                     ca_bundle: value_ca_bundle,
                     group: value_group,
-                    group_priority_minimum: value_group_priority_minimum.ok_or_else(|| crate::serde::de::Error::missing_field("groupPriorityMinimum"))?,
+                    group_priority_minimum: value_group_priority_minimum.ok_or_else(|| crate::serde2::missing_field("APIServiceSpec", "groupPriorityMinimum", &["groupPriorityMinimum", "service", "versionPriority"]))?,
                     insecure_skip_tls_verify: value_insecure_skip_tls_verify,
-                    service: value_service.ok_or_else(|| crate::serde::de::Error::missing_field("service"))?,
+                    service: value_service.ok_or_else(|| crate::serde2::missing_field("APIServiceSpec", "service", &["groupPriorityMinimum", "service", "versionPriority"]))?,
                     version: value_version,
-                    version_priority: value_version_priority.ok_or_else(|| crate::serde::de::Error::missing_field("versionPriority"))?,
+                    version_priority: value_version_priority.ok_or_else(|| crate::serde2::missing_field("APIServiceSpec", "versionPriority", &["groupPriorityMinimum", "service", "versionPriority"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(APIServiceSpec {
+                    ca_bundle: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    group: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    group_priority_minimum: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    insecure_skip_tls_verify: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    service: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    version: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    version_priority: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "APIServiceSpec",
-            &[
-                "caBundle",
-                "group",
-                "groupPriorityMinimum",
-                "insecureSkipTLSVerify",
-                "service",
-                "version",
-                "versionPriority",
-            ],
+            APIServiceSpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, APIServiceSpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("APIServiceSpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_ca_bundle = false;
+                let mut has_group = false;
+                let mut has_group_priority_minimum = false;
+                let mut has_insecure_skip_tls_verify = false;
+                let mut has_service = false;
+                let mut has_version = false;
+                let mut has_version_priority = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("APIServiceSpec", APIServiceSpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.ca_bundle))?; has_ca_bundle = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.group))?; has_group = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.group_priority_minimum))?; has_group_priority_minimum = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.insecure_skip_tls_verify))?; has_insecure_skip_tls_verify = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.service))?; has_service = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.version))?; has_version = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.version_priority))?; has_version_priority = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_ca_bundle {
+                    self.0.ca_bundle = None;
+                }
+                if !has_group {
+                    self.0.group = None;
+                }
+                if !has_group_priority_minimum {
+                    return Err(crate::serde2::missing_field("APIServiceSpec", "groupPriorityMinimum", &["groupPriorityMinimum", "service", "versionPriority"]));
+                }
+                if !has_insecure_skip_tls_verify {
+                    self.0.insecure_skip_tls_verify = None;
+                }
+                if !has_service {
+                    return Err(crate::serde2::missing_field("APIServiceSpec", "service", &["groupPriorityMinimum", "service", "versionPriority"]));
+                }
+                if !has_version {
+                    self.0.version = None;
+                }
+                if !has_version_priority {
+                    return Err(crate::serde2::missing_field("APIServiceSpec", "versionPriority", &["groupPriorityMinimum", "service", "versionPriority"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.ca_bundle))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.group))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.group_priority_minimum))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.insecure_skip_tls_verify))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.service))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.version))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.version_priority))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "APIServiceSpec",
+            APIServiceSpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for APIServiceSpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("APIServiceSpec", 7)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "caBundle", &self.ca_bundle)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "group", &self.group)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "groupPriorityMinimum", &self.group_priority_minimum)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "insecureSkipTLSVerify", &self.insecure_skip_tls_verify)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "service", &self.service)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "version", &self.version)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "versionPriority", &self.version_priority)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "APIServiceSpec",
             3 +
@@ -156,10 +287,159 @@ impl crate::serde::Serialize for APIServiceSpec {
     }
 }
 
+/// A builder of [`APIServiceSpec`]. Created with [`APIServiceSpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct APIServiceSpecBuilder {
+    ca_bundle: Option<crate::ByteString>,
+    group: Option<String>,
+    group_priority_minimum: Option<i32>,
+    insecure_skip_tls_verify: Option<bool>,
+    service: Option<crate::kube_aggregator::pkg::apis::apiregistration::v1beta1::ServiceReference>,
+    version: Option<String>,
+    version_priority: Option<i32>,
+}
+
+#[cfg(feature = "builder")]
+impl APIServiceSpec {
+    /// Starts building a `APIServiceSpec`. Its required fields must be set before [`APIServiceSpecBuilder::build`] is called.
+    pub fn builder() -> APIServiceSpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl APIServiceSpecBuilder {
+    /// Sets [`APIServiceSpec::ca_bundle`].
+    pub fn ca_bundle(mut self, value: impl Into<crate::ByteString>) -> Self {
+        self.ca_bundle = Some(value.into());
+        self
+    }
+
+    /// Sets [`APIServiceSpec::group`].
+    pub fn group(mut self, value: impl Into<String>) -> Self {
+        self.group = Some(value.into());
+        self
+    }
+
+    /// Sets [`APIServiceSpec::group_priority_minimum`].
+    pub fn group_priority_minimum(mut self, value: impl Into<i32>) -> Self {
+        self.group_priority_minimum = Some(value.into());
+        self
+    }
+
+    /// Sets [`APIServiceSpec::insecure_skip_tls_verify`].
+    pub fn insecure_skip_tls_verify(mut self, value: impl Into<bool>) -> Self {
+        self.insecure_skip_tls_verify = Some(value.into());
+        self
+    }
+
+    /// Sets [`APIServiceSpec::service`].
+    pub fn service(mut self, value: impl Into<crate::kube_aggregator::pkg::apis::apiregistration::v1beta1::ServiceReference>) -> Self {
+        self.service = Some(value.into());
+        self
+    }
+
+    /// Sets [`APIServiceSpec::version`].
+    pub fn version(mut self, value: impl Into<String>) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Sets [`APIServiceSpec::version_priority`].
+    pub fn version_priority(mut self, value: impl Into<i32>) -> Self {
+        self.version_priority = Some(value.into());
+        self
+    }
+
+    /// Builds the `APIServiceSpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<APIServiceSpec, crate::MissingFieldError> {
+        Ok(APIServiceSpec {
+            ca_bundle: self.ca_bundle,
+            group: self.group,
+            group_priority_minimum: self.group_priority_minimum.ok_or_else(|| crate::MissingFieldError::new("APIServiceSpec", "groupPriorityMinimum"))?,
+            insecure_skip_tls_verify: self.insecure_skip_tls_verify,
+            service: self.service.ok_or_else(|| crate::MissingFieldError::new("APIServiceSpec", "service"))?,
+            version: self.version,
+            version_priority: self.version_priority.ok_or_else(|| crate::MissingFieldError::new("APIServiceSpec", "versionPriority"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`APIServiceSpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct APIServiceSpecApplyConfiguration {
+    /// See [`APIServiceSpec::ca_bundle`].
+    pub ca_bundle: Option<crate::ByteString>,
+
+    /// See [`APIServiceSpec::group`].
+    pub group: Option<String>,
+
+    /// See [`APIServiceSpec::group_priority_minimum`].
+    pub group_priority_minimum: Option<i32>,
+
+    /// See [`APIServiceSpec::insecure_skip_tls_verify`].
+    pub insecure_skip_tls_verify: Option<bool>,
+
+    /// See [`APIServiceSpec::service`].
+    pub service: Option<crate::kube_aggregator::pkg::apis::apiregistration::v1beta1::ServiceReferenceApplyConfiguration>,
+
+    /// See [`APIServiceSpec::version`].
+    pub version: Option<String>,
+
+    /// See [`APIServiceSpec::version_priority`].
+    pub version_priority: Option<i32>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for APIServiceSpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "APIServiceSpecApplyConfiguration",
+            self.ca_bundle.as_ref().map_or(0, |_| 1) +
+            self.group.as_ref().map_or(0, |_| 1) +
+            self.group_priority_minimum.as_ref().map_or(0, |_| 1) +
+            self.insecure_skip_tls_verify.as_ref().map_or(0, |_| 1) +
+            self.service.as_ref().map_or(0, |_| 1) +
+            self.version.as_ref().map_or(0, |_| 1) +
+            self.version_priority.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.ca_bundle {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "caBundle", value)?;
+        }
+        if let Some(value) = &self.group {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "group", value)?;
+        }
+        if let Some(value) = &self.group_priority_minimum {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "groupPriorityMinimum", value)?;
+        }
+        if let Some(value) = &self.insecure_skip_tls_verify {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "insecureSkipTLSVerify", value)?;
+        }
+        if let Some(value) = &self.service {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "service", value)?;
+        }
+        if let Some(value) = &self.version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "version", value)?;
+        }
+        if let Some(value) = &self.version_priority {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "versionPriority", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for APIServiceSpec {
     fn schema_name() -> String {
-        "io.k8s.kube-aggregator.pkg.apis.apiregistration.v1beta1.APIServiceSpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "kube_aggregator::pkg::apis::apiregistration::v1beta1::APIServiceSpec".to_owned()
+        }
+        else {
+            "io.k8s.kube-aggregator.pkg.apis.apiregistration.v1beta1.APIServiceSpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -170,8 +450,9 @@ impl crate::schemars::JsonSchema for APIServiceSpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "caBundle".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -182,8 +463,8 @@ impl crate::schemars::JsonSchema for APIServiceSpec {
                             format: Some("byte".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "group".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -193,8 +474,8 @@ impl crate::schemars::JsonSchema for APIServiceSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "groupPriorityMinimum".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -205,8 +486,8 @@ impl crate::schemars::JsonSchema for APIServiceSpec {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "insecureSkipTLSVerify".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -216,8 +497,8 @@ impl crate::schemars::JsonSchema for APIServiceSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "service".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::kube_aggregator::pkg::apis::apiregistration::v1beta1::ServiceReference>().into_object();
@@ -227,8 +508,8 @@ impl crate::schemars::JsonSchema for APIServiceSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "version".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -238,8 +519,8 @@ impl crate::schemars::JsonSchema for APIServiceSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "versionPriority".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -250,8 +531,9 @@ impl crate::schemars::JsonSchema for APIServiceSpec {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "groupPriorityMinimum",
                     "service",
@@ -263,3 +545,18 @@ impl crate::schemars::JsonSchema for APIServiceSpec {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for APIServiceSpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(APIServiceSpec {
+            ca_bundle: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            group: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            group_priority_minimum: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            insecure_skip_tls_verify: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            service: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            version: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            version_priority: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
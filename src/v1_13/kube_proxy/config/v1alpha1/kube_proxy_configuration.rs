@@ -0,0 +1,1329 @@
+// Generated from definition io.k8s.kube-proxy.config.v1alpha1.KubeProxyConfiguration
+
+/// KubeProxyConfiguration contains everything necessary to configure the Kubernetes proxy server.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KubeProxyConfiguration {
+    /// bindAddress is the IP address for the proxy server to serve on (set to 0.0.0.0 for all interfaces)
+    pub bind_address: Option<String>,
+
+    /// bindAddressHardFail, if true, kube-proxy will treat failure to bind to a port as fatal and exit
+    pub bind_address_hard_fail: Option<bool>,
+
+    /// clientConnection specifies the kubeconfig file and client connection settings for the proxy server to use when communicating with the apiserver.
+    pub client_connection: Option<crate::component_base::config::v1alpha1::ClientConnectionConfiguration>,
+
+    /// clusterCIDR is the CIDR range of the pods in the cluster. It is used to bridge traffic coming from outside of the cluster. If not provided, no off-cluster bridging will be performed.
+    pub cluster_cidr: Option<String>,
+
+    /// configSyncPeriod is how often configuration from the apiserver is refreshed. Must be greater than 0.
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub config_sync_period: Option<String>,
+
+    /// conntrack contains conntrack-related configuration options.
+    pub conntrack: Option<crate::kube_proxy::config::v1alpha1::KubeProxyConntrackConfiguration>,
+
+    /// DetectLocalMode determines mode to use for detecting local traffic, defaults to LocalModeClusterCIDR
+    pub detect_local_mode: Option<String>,
+
+    /// enableProfiling enables profiling via web interface on /debug/pprof handler. Profiling handlers will be handled by metrics server.
+    pub enable_profiling: Option<bool>,
+
+    /// featureGates is a map of feature names to bools that enable or disable alpha/experimental features.
+    pub feature_gates: Option<std::collections::BTreeMap<String, bool>>,
+
+    /// healthzBindAddress is the IP address and port for the health check server to serve on, defaulting to 0.0.0.0:10256
+    pub healthz_bind_address: Option<String>,
+
+    /// hostnameOverride, if non-empty, will be used as the identity instead of the actual hostname.
+    pub hostname_override: Option<String>,
+
+    /// iptables contains iptables-related configuration options.
+    pub iptables: Option<crate::kube_proxy::config::v1alpha1::KubeProxyIPTablesConfiguration>,
+
+    /// ipvs contains ipvs-related configuration options.
+    pub ipvs: Option<crate::kube_proxy::config::v1alpha1::KubeProxyIPVSConfiguration>,
+
+    /// metricsBindAddress is the IP address and port for the metrics server to serve on, defaulting to 127.0.0.1:10249 (set to 0.0.0.0 for all interfaces)
+    pub metrics_bind_address: Option<String>,
+
+    /// mode specifies which proxy mode to use.
+    pub mode: Option<String>,
+
+    /// nodePortAddresses is the --nodeport-addresses value for kube-proxy process. Values must be valid IP blocks. These values are as a parameter to select the interfaces where nodeport works. In case someone would like to expose a service on localhost for local visit and some other interfaces for particular purpose, a list of IP blocks would do that. If set it to "127.0.0.0/8", kube-proxy will only select the loopback interface for NodePort. If set it to a non-zero IP block, kube-proxy will filter that down to just the IPs that applied to the node. An empty string slice is meant to select all network interfaces.
+    pub node_port_addresses: Option<Vec<String>>,
+
+    /// oomScoreAdj is the oom-score-adj value for kube-proxy process. Values must be within the range \[-1000, 1000\]
+    pub oom_score_adj: Option<i32>,
+
+    /// portRange is the range of host ports (beginPort-endPort, inclusive) that may be consumed in order to proxy service traffic. If unspecified (0-0) then ports will be randomly chosen.
+    pub port_range: Option<String>,
+
+    /// ShowHiddenMetricsForVersion is the version for which you want to show hidden metrics.
+    pub show_hidden_metrics_for_version: Option<String>,
+
+    /// winkernel contains winkernel-related configuration options.
+    pub winkernel: Option<crate::kube_proxy::config::v1alpha1::KubeProxyWinkernelConfiguration>,
+}
+
+impl KubeProxyConfiguration {
+    /// Returns this value with [`KubeProxyConfiguration::bind_address`] set to the given value.
+    #[must_use]
+    pub fn with_bind_address(mut self, value: impl Into<String>) -> Self {
+        self.bind_address = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyConfiguration::bind_address_hard_fail`] set to the given value.
+    #[must_use]
+    pub fn with_bind_address_hard_fail(mut self, value: impl Into<bool>) -> Self {
+        self.bind_address_hard_fail = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyConfiguration::client_connection`] set to the given value.
+    #[must_use]
+    pub fn with_client_connection(mut self, value: impl Into<crate::component_base::config::v1alpha1::ClientConnectionConfiguration>) -> Self {
+        self.client_connection = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyConfiguration::cluster_cidr`] set to the given value.
+    #[must_use]
+    pub fn with_cluster_cidr(mut self, value: impl Into<String>) -> Self {
+        self.cluster_cidr = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyConfiguration::config_sync_period`] set to the given value.
+    #[must_use]
+    pub fn with_config_sync_period(mut self, value: impl Into<String>) -> Self {
+        self.config_sync_period = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyConfiguration::conntrack`] set to the given value.
+    #[must_use]
+    pub fn with_conntrack(mut self, value: impl Into<crate::kube_proxy::config::v1alpha1::KubeProxyConntrackConfiguration>) -> Self {
+        self.conntrack = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyConfiguration::detect_local_mode`] set to the given value.
+    #[must_use]
+    pub fn with_detect_local_mode(mut self, value: impl Into<String>) -> Self {
+        self.detect_local_mode = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyConfiguration::enable_profiling`] set to the given value.
+    #[must_use]
+    pub fn with_enable_profiling(mut self, value: impl Into<bool>) -> Self {
+        self.enable_profiling = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyConfiguration::feature_gates`] set to the given value.
+    #[must_use]
+    pub fn with_feature_gates(mut self, value: impl Into<std::collections::BTreeMap<String, bool>>) -> Self {
+        self.feature_gates = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyConfiguration::healthz_bind_address`] set to the given value.
+    #[must_use]
+    pub fn with_healthz_bind_address(mut self, value: impl Into<String>) -> Self {
+        self.healthz_bind_address = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyConfiguration::hostname_override`] set to the given value.
+    #[must_use]
+    pub fn with_hostname_override(mut self, value: impl Into<String>) -> Self {
+        self.hostname_override = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyConfiguration::iptables`] set to the given value.
+    #[must_use]
+    pub fn with_iptables(mut self, value: impl Into<crate::kube_proxy::config::v1alpha1::KubeProxyIPTablesConfiguration>) -> Self {
+        self.iptables = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyConfiguration::ipvs`] set to the given value.
+    #[must_use]
+    pub fn with_ipvs(mut self, value: impl Into<crate::kube_proxy::config::v1alpha1::KubeProxyIPVSConfiguration>) -> Self {
+        self.ipvs = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyConfiguration::metrics_bind_address`] set to the given value.
+    #[must_use]
+    pub fn with_metrics_bind_address(mut self, value: impl Into<String>) -> Self {
+        self.metrics_bind_address = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyConfiguration::mode`] set to the given value.
+    #[must_use]
+    pub fn with_mode(mut self, value: impl Into<String>) -> Self {
+        self.mode = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyConfiguration::node_port_addresses`] set to the given value.
+    #[must_use]
+    pub fn with_node_port_addresses(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.node_port_addresses = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyConfiguration::oom_score_adj`] set to the given value.
+    #[must_use]
+    pub fn with_oom_score_adj(mut self, value: impl Into<i32>) -> Self {
+        self.oom_score_adj = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyConfiguration::port_range`] set to the given value.
+    #[must_use]
+    pub fn with_port_range(mut self, value: impl Into<String>) -> Self {
+        self.port_range = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyConfiguration::show_hidden_metrics_for_version`] set to the given value.
+    #[must_use]
+    pub fn with_show_hidden_metrics_for_version(mut self, value: impl Into<String>) -> Self {
+        self.show_hidden_metrics_for_version = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeProxyConfiguration::winkernel`] set to the given value.
+    #[must_use]
+    pub fn with_winkernel(mut self, value: impl Into<crate::kube_proxy::config::v1alpha1::KubeProxyWinkernelConfiguration>) -> Self {
+        self.winkernel = Some(value.into());
+        self
+    }
+}
+
+impl crate::Resource for KubeProxyConfiguration {
+    const API_VERSION: &'static str = "kubeproxy.config.k8s.io/v1alpha1";
+    const GROUP: &'static str = "kubeproxy.config.k8s.io";
+    const KIND: &'static str = "KubeProxyConfiguration";
+    const VERSION: &'static str = "v1alpha1";
+    const URL_PATH_SEGMENT: &'static str = "";
+    const VERBS: &'static [&'static str] = &[];
+    const SINGULAR: &'static str = "kubeproxyconfiguration";
+    const SHORT_NAMES: &'static [&'static str] = &[];
+    type Scope = crate::ClusterResourceScope;
+}
+
+impl crate::MergeStrategyInfo for KubeProxyConfiguration {}
+
+impl KubeProxyConfiguration {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "kind",
+        "bindAddress",
+        "bindAddressHardFail",
+        "clientConnection",
+        "clusterCIDR",
+        "configSyncPeriod",
+        "conntrack",
+        "detectLocalMode",
+        "enableProfiling",
+        "featureGates",
+        "healthzBindAddress",
+        "hostnameOverride",
+        "iptables",
+        "ipvs",
+        "metricsBindAddress",
+        "mode",
+        "nodePortAddresses",
+        "oomScoreAdj",
+        "portRange",
+        "showHiddenMetricsForVersion",
+        "winkernel",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for KubeProxyConfiguration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = KubeProxyConfiguration;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<Self::Value as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_bind_address: Option<String> = None;
+                let mut value_bind_address_hard_fail: Option<bool> = None;
+                let mut value_client_connection: Option<crate::component_base::config::v1alpha1::ClientConnectionConfiguration> = None;
+                let mut value_cluster_cidr: Option<String> = None;
+                let mut value_config_sync_period: Option<String> = None;
+                let mut value_conntrack: Option<crate::kube_proxy::config::v1alpha1::KubeProxyConntrackConfiguration> = None;
+                let mut value_detect_local_mode: Option<String> = None;
+                let mut value_enable_profiling: Option<bool> = None;
+                let mut value_feature_gates: Option<std::collections::BTreeMap<String, bool>> = None;
+                let mut value_healthz_bind_address: Option<String> = None;
+                let mut value_hostname_override: Option<String> = None;
+                let mut value_iptables: Option<crate::kube_proxy::config::v1alpha1::KubeProxyIPTablesConfiguration> = None;
+                let mut value_ipvs: Option<crate::kube_proxy::config::v1alpha1::KubeProxyIPVSConfiguration> = None;
+                let mut value_metrics_bind_address: Option<String> = None;
+                let mut value_mode: Option<String> = None;
+                let mut value_node_port_addresses: Option<Vec<String>> = None;
+                let mut value_oom_score_adj: Option<i32> = None;
+                let mut value_port_range: Option<String> = None;
+                let mut value_show_hidden_metrics_for_version: Option<String> = None;
+                let mut value_winkernel: Option<crate::kube_proxy::config::v1alpha1::KubeProxyWinkernelConfiguration> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("KubeProxyConfiguration", KubeProxyConfiguration::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <Self::Value as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<Self::Value as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <Self::Value as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<Self::Value as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => value_bind_address = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_bind_address_hard_fail = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_client_connection = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_cluster_cidr = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_config_sync_period = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(7) => value_conntrack = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(8) => value_detect_local_mode = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(9) => value_enable_profiling = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(10) => value_feature_gates = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(11) => value_healthz_bind_address = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(12) => value_hostname_override = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(13) => value_iptables = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(14) => value_ipvs = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(15) => value_metrics_bind_address = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(16) => value_mode = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(17) => value_node_port_addresses = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(18) => value_oom_score_adj = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(19) => value_port_range = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(20) => value_show_hidden_metrics_for_version = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(21) => value_winkernel = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(KubeProxyConfiguration {
+                    bind_address: value_bind_address,
+                    bind_address_hard_fail: value_bind_address_hard_fail,
+                    client_connection: value_client_connection,
+                    cluster_cidr: value_cluster_cidr,
+                    config_sync_period: value_config_sync_period,
+                    conntrack: value_conntrack,
+                    detect_local_mode: value_detect_local_mode,
+                    enable_profiling: value_enable_profiling,
+                    feature_gates: value_feature_gates,
+                    healthz_bind_address: value_healthz_bind_address,
+                    hostname_override: value_hostname_override,
+                    iptables: value_iptables,
+                    ipvs: value_ipvs,
+                    metrics_bind_address: value_metrics_bind_address,
+                    mode: value_mode,
+                    node_port_addresses: value_node_port_addresses,
+                    oom_score_adj: value_oom_score_adj,
+                    port_range: value_port_range,
+                    show_hidden_metrics_for_version: value_show_hidden_metrics_for_version,
+                    winkernel: value_winkernel,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(KubeProxyConfiguration {
+                    bind_address: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    bind_address_hard_fail: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    client_connection: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    cluster_cidr: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    config_sync_period: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    conntrack: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    detect_local_mode: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    enable_profiling: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                    feature_gates: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?,
+                    healthz_bind_address: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?,
+                    hostname_override: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?,
+                    iptables: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(11, &self))?,
+                    ipvs: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(12, &self))?,
+                    metrics_bind_address: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(13, &self))?,
+                    mode: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(14, &self))?,
+                    node_port_addresses: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(15, &self))?,
+                    oom_score_adj: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(16, &self))?,
+                    port_range: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(17, &self))?,
+                    show_hidden_metrics_for_version: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(18, &self))?,
+                    winkernel: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(19, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            KubeProxyConfiguration::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, KubeProxyConfiguration> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<KubeProxyConfiguration as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_bind_address = false;
+                let mut has_bind_address_hard_fail = false;
+                let mut has_client_connection = false;
+                let mut has_cluster_cidr = false;
+                let mut has_config_sync_period = false;
+                let mut has_conntrack = false;
+                let mut has_detect_local_mode = false;
+                let mut has_enable_profiling = false;
+                let mut has_feature_gates = false;
+                let mut has_healthz_bind_address = false;
+                let mut has_hostname_override = false;
+                let mut has_iptables = false;
+                let mut has_ipvs = false;
+                let mut has_metrics_bind_address = false;
+                let mut has_mode = false;
+                let mut has_node_port_addresses = false;
+                let mut has_oom_score_adj = false;
+                let mut has_port_range = false;
+                let mut has_show_hidden_metrics_for_version = false;
+                let mut has_winkernel = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("KubeProxyConfiguration", KubeProxyConfiguration::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <KubeProxyConfiguration as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<KubeProxyConfiguration as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <KubeProxyConfiguration as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<KubeProxyConfiguration as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.bind_address))?; has_bind_address = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.bind_address_hard_fail))?; has_bind_address_hard_fail = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.client_connection))?; has_client_connection = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.cluster_cidr))?; has_cluster_cidr = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.config_sync_period))?; has_config_sync_period = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.conntrack))?; has_conntrack = true; },
+                        Some(8) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.detect_local_mode))?; has_detect_local_mode = true; },
+                        Some(9) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.enable_profiling))?; has_enable_profiling = true; },
+                        Some(10) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.feature_gates))?; has_feature_gates = true; },
+                        Some(11) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.healthz_bind_address))?; has_healthz_bind_address = true; },
+                        Some(12) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.hostname_override))?; has_hostname_override = true; },
+                        Some(13) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.iptables))?; has_iptables = true; },
+                        Some(14) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.ipvs))?; has_ipvs = true; },
+                        Some(15) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.metrics_bind_address))?; has_metrics_bind_address = true; },
+                        Some(16) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.mode))?; has_mode = true; },
+                        Some(17) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.node_port_addresses))?; has_node_port_addresses = true; },
+                        Some(18) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.oom_score_adj))?; has_oom_score_adj = true; },
+                        Some(19) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.port_range))?; has_port_range = true; },
+                        Some(20) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.show_hidden_metrics_for_version))?; has_show_hidden_metrics_for_version = true; },
+                        Some(21) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.winkernel))?; has_winkernel = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_bind_address {
+                    self.0.bind_address = None;
+                }
+                if !has_bind_address_hard_fail {
+                    self.0.bind_address_hard_fail = None;
+                }
+                if !has_client_connection {
+                    self.0.client_connection = None;
+                }
+                if !has_cluster_cidr {
+                    self.0.cluster_cidr = None;
+                }
+                if !has_config_sync_period {
+                    self.0.config_sync_period = None;
+                }
+                if !has_conntrack {
+                    self.0.conntrack = None;
+                }
+                if !has_detect_local_mode {
+                    self.0.detect_local_mode = None;
+                }
+                if !has_enable_profiling {
+                    self.0.enable_profiling = None;
+                }
+                if !has_feature_gates {
+                    self.0.feature_gates = None;
+                }
+                if !has_healthz_bind_address {
+                    self.0.healthz_bind_address = None;
+                }
+                if !has_hostname_override {
+                    self.0.hostname_override = None;
+                }
+                if !has_iptables {
+                    self.0.iptables = None;
+                }
+                if !has_ipvs {
+                    self.0.ipvs = None;
+                }
+                if !has_metrics_bind_address {
+                    self.0.metrics_bind_address = None;
+                }
+                if !has_mode {
+                    self.0.mode = None;
+                }
+                if !has_node_port_addresses {
+                    self.0.node_port_addresses = None;
+                }
+                if !has_oom_score_adj {
+                    self.0.oom_score_adj = None;
+                }
+                if !has_port_range {
+                    self.0.port_range = None;
+                }
+                if !has_show_hidden_metrics_for_version {
+                    self.0.show_hidden_metrics_for_version = None;
+                }
+                if !has_winkernel {
+                    self.0.winkernel = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.bind_address))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.bind_address_hard_fail))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.client_connection))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.cluster_cidr))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.config_sync_period))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.conntrack))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.detect_local_mode))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.enable_profiling))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.feature_gates))?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.healthz_bind_address))?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.hostname_override))?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.iptables))?.ok_or_else(|| crate::serde::de::Error::invalid_length(11, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.ipvs))?.ok_or_else(|| crate::serde::de::Error::invalid_length(12, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.metrics_bind_address))?.ok_or_else(|| crate::serde::de::Error::invalid_length(13, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.mode))?.ok_or_else(|| crate::serde::de::Error::invalid_length(14, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.node_port_addresses))?.ok_or_else(|| crate::serde::de::Error::invalid_length(15, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.oom_score_adj))?.ok_or_else(|| crate::serde::de::Error::invalid_length(16, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.port_range))?.ok_or_else(|| crate::serde::de::Error::invalid_length(17, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.show_hidden_metrics_for_version))?.ok_or_else(|| crate::serde::de::Error::invalid_length(18, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.winkernel))?.ok_or_else(|| crate::serde::de::Error::invalid_length(19, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            KubeProxyConfiguration::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for KubeProxyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct(<Self as crate::Resource>::KIND, 20)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "bindAddress", &self.bind_address)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "bindAddressHardFail", &self.bind_address_hard_fail)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clientConnection", &self.client_connection)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clusterCIDR", &self.cluster_cidr)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "configSyncPeriod", &self.config_sync_period)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conntrack", &self.conntrack)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "detectLocalMode", &self.detect_local_mode)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableProfiling", &self.enable_profiling)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "featureGates", &self.feature_gates)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "healthzBindAddress", &self.healthz_bind_address)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostnameOverride", &self.hostname_override)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "iptables", &self.iptables)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ipvs", &self.ipvs)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricsBindAddress", &self.metrics_bind_address)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "mode", &self.mode)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodePortAddresses", &self.node_port_addresses)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "oomScoreAdj", &self.oom_score_adj)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "portRange", &self.port_range)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "showHiddenMetricsForVersion", &self.show_hidden_metrics_for_version)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "winkernel", &self.winkernel)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            <Self as crate::Resource>::KIND,
+            2 +
+            self.bind_address.as_ref().map_or(0, |_| 1) +
+            self.bind_address_hard_fail.as_ref().map_or(0, |_| 1) +
+            self.client_connection.as_ref().map_or(0, |_| 1) +
+            self.cluster_cidr.as_ref().map_or(0, |_| 1) +
+            self.config_sync_period.as_ref().map_or(0, |_| 1) +
+            self.conntrack.as_ref().map_or(0, |_| 1) +
+            self.detect_local_mode.as_ref().map_or(0, |_| 1) +
+            self.enable_profiling.as_ref().map_or(0, |_| 1) +
+            self.feature_gates.as_ref().map_or(0, |_| 1) +
+            self.healthz_bind_address.as_ref().map_or(0, |_| 1) +
+            self.hostname_override.as_ref().map_or(0, |_| 1) +
+            self.iptables.as_ref().map_or(0, |_| 1) +
+            self.ipvs.as_ref().map_or(0, |_| 1) +
+            self.metrics_bind_address.as_ref().map_or(0, |_| 1) +
+            self.mode.as_ref().map_or(0, |_| 1) +
+            self.node_port_addresses.as_ref().map_or(0, |_| 1) +
+            self.oom_score_adj.as_ref().map_or(0, |_| 1) +
+            self.port_range.as_ref().map_or(0, |_| 1) +
+            self.show_hidden_metrics_for_version.as_ref().map_or(0, |_| 1) +
+            self.winkernel.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <Self as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <Self as crate::Resource>::KIND)?;
+        if let Some(value) = &self.bind_address {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "bindAddress", value)?;
+        }
+        if let Some(value) = &self.bind_address_hard_fail {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "bindAddressHardFail", value)?;
+        }
+        if let Some(value) = &self.client_connection {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clientConnection", value)?;
+        }
+        if let Some(value) = &self.cluster_cidr {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clusterCIDR", value)?;
+        }
+        if let Some(value) = &self.config_sync_period {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "configSyncPeriod", value)?;
+        }
+        if let Some(value) = &self.conntrack {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conntrack", value)?;
+        }
+        if let Some(value) = &self.detect_local_mode {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "detectLocalMode", value)?;
+        }
+        if let Some(value) = &self.enable_profiling {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableProfiling", value)?;
+        }
+        if let Some(value) = &self.feature_gates {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "featureGates", value)?;
+        }
+        if let Some(value) = &self.healthz_bind_address {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "healthzBindAddress", value)?;
+        }
+        if let Some(value) = &self.hostname_override {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostnameOverride", value)?;
+        }
+        if let Some(value) = &self.iptables {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "iptables", value)?;
+        }
+        if let Some(value) = &self.ipvs {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ipvs", value)?;
+        }
+        if let Some(value) = &self.metrics_bind_address {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricsBindAddress", value)?;
+        }
+        if let Some(value) = &self.mode {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "mode", value)?;
+        }
+        if let Some(value) = &self.node_port_addresses {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodePortAddresses", value)?;
+        }
+        if let Some(value) = &self.oom_score_adj {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "oomScoreAdj", value)?;
+        }
+        if let Some(value) = &self.port_range {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "portRange", value)?;
+        }
+        if let Some(value) = &self.show_hidden_metrics_for_version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "showHiddenMetricsForVersion", value)?;
+        }
+        if let Some(value) = &self.winkernel {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "winkernel", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`KubeProxyConfiguration`]. Created with [`KubeProxyConfiguration::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct KubeProxyConfigurationBuilder {
+    bind_address: Option<String>,
+    bind_address_hard_fail: Option<bool>,
+    client_connection: Option<crate::component_base::config::v1alpha1::ClientConnectionConfiguration>,
+    cluster_cidr: Option<String>,
+    config_sync_period: Option<String>,
+    conntrack: Option<crate::kube_proxy::config::v1alpha1::KubeProxyConntrackConfiguration>,
+    detect_local_mode: Option<String>,
+    enable_profiling: Option<bool>,
+    feature_gates: Option<std::collections::BTreeMap<String, bool>>,
+    healthz_bind_address: Option<String>,
+    hostname_override: Option<String>,
+    iptables: Option<crate::kube_proxy::config::v1alpha1::KubeProxyIPTablesConfiguration>,
+    ipvs: Option<crate::kube_proxy::config::v1alpha1::KubeProxyIPVSConfiguration>,
+    metrics_bind_address: Option<String>,
+    mode: Option<String>,
+    node_port_addresses: Option<Vec<String>>,
+    oom_score_adj: Option<i32>,
+    port_range: Option<String>,
+    show_hidden_metrics_for_version: Option<String>,
+    winkernel: Option<crate::kube_proxy::config::v1alpha1::KubeProxyWinkernelConfiguration>,
+}
+
+#[cfg(feature = "builder")]
+impl KubeProxyConfiguration {
+    /// Starts building a `KubeProxyConfiguration`. Its required fields must be set before [`KubeProxyConfigurationBuilder::build`] is called.
+    pub fn builder() -> KubeProxyConfigurationBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl KubeProxyConfigurationBuilder {
+    /// Sets [`KubeProxyConfiguration::bind_address`].
+    pub fn bind_address(mut self, value: impl Into<String>) -> Self {
+        self.bind_address = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyConfiguration::bind_address_hard_fail`].
+    pub fn bind_address_hard_fail(mut self, value: impl Into<bool>) -> Self {
+        self.bind_address_hard_fail = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyConfiguration::client_connection`].
+    pub fn client_connection(mut self, value: impl Into<crate::component_base::config::v1alpha1::ClientConnectionConfiguration>) -> Self {
+        self.client_connection = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyConfiguration::cluster_cidr`].
+    pub fn cluster_cidr(mut self, value: impl Into<String>) -> Self {
+        self.cluster_cidr = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyConfiguration::config_sync_period`].
+    pub fn config_sync_period(mut self, value: impl Into<String>) -> Self {
+        self.config_sync_period = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyConfiguration::conntrack`].
+    pub fn conntrack(mut self, value: impl Into<crate::kube_proxy::config::v1alpha1::KubeProxyConntrackConfiguration>) -> Self {
+        self.conntrack = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyConfiguration::detect_local_mode`].
+    pub fn detect_local_mode(mut self, value: impl Into<String>) -> Self {
+        self.detect_local_mode = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyConfiguration::enable_profiling`].
+    pub fn enable_profiling(mut self, value: impl Into<bool>) -> Self {
+        self.enable_profiling = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyConfiguration::feature_gates`].
+    pub fn feature_gates(mut self, value: impl Into<std::collections::BTreeMap<String, bool>>) -> Self {
+        self.feature_gates = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyConfiguration::healthz_bind_address`].
+    pub fn healthz_bind_address(mut self, value: impl Into<String>) -> Self {
+        self.healthz_bind_address = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyConfiguration::hostname_override`].
+    pub fn hostname_override(mut self, value: impl Into<String>) -> Self {
+        self.hostname_override = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyConfiguration::iptables`].
+    pub fn iptables(mut self, value: impl Into<crate::kube_proxy::config::v1alpha1::KubeProxyIPTablesConfiguration>) -> Self {
+        self.iptables = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyConfiguration::ipvs`].
+    pub fn ipvs(mut self, value: impl Into<crate::kube_proxy::config::v1alpha1::KubeProxyIPVSConfiguration>) -> Self {
+        self.ipvs = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyConfiguration::metrics_bind_address`].
+    pub fn metrics_bind_address(mut self, value: impl Into<String>) -> Self {
+        self.metrics_bind_address = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyConfiguration::mode`].
+    pub fn mode(mut self, value: impl Into<String>) -> Self {
+        self.mode = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyConfiguration::node_port_addresses`].
+    pub fn node_port_addresses(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.node_port_addresses = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyConfiguration::oom_score_adj`].
+    pub fn oom_score_adj(mut self, value: impl Into<i32>) -> Self {
+        self.oom_score_adj = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyConfiguration::port_range`].
+    pub fn port_range(mut self, value: impl Into<String>) -> Self {
+        self.port_range = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyConfiguration::show_hidden_metrics_for_version`].
+    pub fn show_hidden_metrics_for_version(mut self, value: impl Into<String>) -> Self {
+        self.show_hidden_metrics_for_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeProxyConfiguration::winkernel`].
+    pub fn winkernel(mut self, value: impl Into<crate::kube_proxy::config::v1alpha1::KubeProxyWinkernelConfiguration>) -> Self {
+        self.winkernel = Some(value.into());
+        self
+    }
+
+    /// Builds the `KubeProxyConfiguration`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<KubeProxyConfiguration, crate::MissingFieldError> {
+        Ok(KubeProxyConfiguration {
+            bind_address: self.bind_address,
+            bind_address_hard_fail: self.bind_address_hard_fail,
+            client_connection: self.client_connection,
+            cluster_cidr: self.cluster_cidr,
+            config_sync_period: self.config_sync_period,
+            conntrack: self.conntrack,
+            detect_local_mode: self.detect_local_mode,
+            enable_profiling: self.enable_profiling,
+            feature_gates: self.feature_gates,
+            healthz_bind_address: self.healthz_bind_address,
+            hostname_override: self.hostname_override,
+            iptables: self.iptables,
+            ipvs: self.ipvs,
+            metrics_bind_address: self.metrics_bind_address,
+            mode: self.mode,
+            node_port_addresses: self.node_port_addresses,
+            oom_score_adj: self.oom_score_adj,
+            port_range: self.port_range,
+            show_hidden_metrics_for_version: self.show_hidden_metrics_for_version,
+            winkernel: self.winkernel,
+        })
+    }
+}
+
+/// The apply configuration of [`KubeProxyConfiguration`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KubeProxyConfigurationApplyConfiguration {
+    /// See [`KubeProxyConfiguration::bind_address`].
+    pub bind_address: Option<String>,
+
+    /// See [`KubeProxyConfiguration::bind_address_hard_fail`].
+    pub bind_address_hard_fail: Option<bool>,
+
+    /// See [`KubeProxyConfiguration::client_connection`].
+    pub client_connection: Option<crate::component_base::config::v1alpha1::ClientConnectionConfigurationApplyConfiguration>,
+
+    /// See [`KubeProxyConfiguration::cluster_cidr`].
+    pub cluster_cidr: Option<String>,
+
+    /// See [`KubeProxyConfiguration::config_sync_period`].
+    pub config_sync_period: Option<String>,
+
+    /// See [`KubeProxyConfiguration::conntrack`].
+    pub conntrack: Option<crate::kube_proxy::config::v1alpha1::KubeProxyConntrackConfigurationApplyConfiguration>,
+
+    /// See [`KubeProxyConfiguration::detect_local_mode`].
+    pub detect_local_mode: Option<String>,
+
+    /// See [`KubeProxyConfiguration::enable_profiling`].
+    pub enable_profiling: Option<bool>,
+
+    /// See [`KubeProxyConfiguration::feature_gates`].
+    pub feature_gates: Option<std::collections::BTreeMap<String, bool>>,
+
+    /// See [`KubeProxyConfiguration::healthz_bind_address`].
+    pub healthz_bind_address: Option<String>,
+
+    /// See [`KubeProxyConfiguration::hostname_override`].
+    pub hostname_override: Option<String>,
+
+    /// See [`KubeProxyConfiguration::iptables`].
+    pub iptables: Option<crate::kube_proxy::config::v1alpha1::KubeProxyIPTablesConfigurationApplyConfiguration>,
+
+    /// See [`KubeProxyConfiguration::ipvs`].
+    pub ipvs: Option<crate::kube_proxy::config::v1alpha1::KubeProxyIPVSConfigurationApplyConfiguration>,
+
+    /// See [`KubeProxyConfiguration::metrics_bind_address`].
+    pub metrics_bind_address: Option<String>,
+
+    /// See [`KubeProxyConfiguration::mode`].
+    pub mode: Option<String>,
+
+    /// See [`KubeProxyConfiguration::node_port_addresses`].
+    pub node_port_addresses: Option<Vec<String>>,
+
+    /// See [`KubeProxyConfiguration::oom_score_adj`].
+    pub oom_score_adj: Option<i32>,
+
+    /// See [`KubeProxyConfiguration::port_range`].
+    pub port_range: Option<String>,
+
+    /// See [`KubeProxyConfiguration::show_hidden_metrics_for_version`].
+    pub show_hidden_metrics_for_version: Option<String>,
+
+    /// See [`KubeProxyConfiguration::winkernel`].
+    pub winkernel: Option<crate::kube_proxy::config::v1alpha1::KubeProxyWinkernelConfigurationApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for KubeProxyConfigurationApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            <KubeProxyConfiguration as crate::Resource>::KIND,
+            2 +
+            self.bind_address.as_ref().map_or(0, |_| 1) +
+            self.bind_address_hard_fail.as_ref().map_or(0, |_| 1) +
+            self.client_connection.as_ref().map_or(0, |_| 1) +
+            self.cluster_cidr.as_ref().map_or(0, |_| 1) +
+            self.config_sync_period.as_ref().map_or(0, |_| 1) +
+            self.conntrack.as_ref().map_or(0, |_| 1) +
+            self.detect_local_mode.as_ref().map_or(0, |_| 1) +
+            self.enable_profiling.as_ref().map_or(0, |_| 1) +
+            self.feature_gates.as_ref().map_or(0, |_| 1) +
+            self.healthz_bind_address.as_ref().map_or(0, |_| 1) +
+            self.hostname_override.as_ref().map_or(0, |_| 1) +
+            self.iptables.as_ref().map_or(0, |_| 1) +
+            self.ipvs.as_ref().map_or(0, |_| 1) +
+            self.metrics_bind_address.as_ref().map_or(0, |_| 1) +
+            self.mode.as_ref().map_or(0, |_| 1) +
+            self.node_port_addresses.as_ref().map_or(0, |_| 1) +
+            self.oom_score_adj.as_ref().map_or(0, |_| 1) +
+            self.port_range.as_ref().map_or(0, |_| 1) +
+            self.show_hidden_metrics_for_version.as_ref().map_or(0, |_| 1) +
+            self.winkernel.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <KubeProxyConfiguration as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <KubeProxyConfiguration as crate::Resource>::KIND)?;
+        if let Some(value) = &self.bind_address {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "bindAddress", value)?;
+        }
+        if let Some(value) = &self.bind_address_hard_fail {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "bindAddressHardFail", value)?;
+        }
+        if let Some(value) = &self.client_connection {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clientConnection", value)?;
+        }
+        if let Some(value) = &self.cluster_cidr {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clusterCIDR", value)?;
+        }
+        if let Some(value) = &self.config_sync_period {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "configSyncPeriod", value)?;
+        }
+        if let Some(value) = &self.conntrack {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conntrack", value)?;
+        }
+        if let Some(value) = &self.detect_local_mode {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "detectLocalMode", value)?;
+        }
+        if let Some(value) = &self.enable_profiling {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableProfiling", value)?;
+        }
+        if let Some(value) = &self.feature_gates {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "featureGates", value)?;
+        }
+        if let Some(value) = &self.healthz_bind_address {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "healthzBindAddress", value)?;
+        }
+        if let Some(value) = &self.hostname_override {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostnameOverride", value)?;
+        }
+        if let Some(value) = &self.iptables {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "iptables", value)?;
+        }
+        if let Some(value) = &self.ipvs {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "ipvs", value)?;
+        }
+        if let Some(value) = &self.metrics_bind_address {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricsBindAddress", value)?;
+        }
+        if let Some(value) = &self.mode {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "mode", value)?;
+        }
+        if let Some(value) = &self.node_port_addresses {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodePortAddresses", value)?;
+        }
+        if let Some(value) = &self.oom_score_adj {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "oomScoreAdj", value)?;
+        }
+        if let Some(value) = &self.port_range {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "portRange", value)?;
+        }
+        if let Some(value) = &self.show_hidden_metrics_for_version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "showHiddenMetricsForVersion", value)?;
+        }
+        if let Some(value) = &self.winkernel {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "winkernel", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for KubeProxyConfiguration {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "kube_proxy::config::v1alpha1::KubeProxyConfiguration".to_owned()
+        }
+        else {
+            "io.k8s.kube-proxy.config.v1alpha1.KubeProxyConfiguration".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("KubeProxyConfiguration contains everything necessary to configure the Kubernetes proxy server.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "apiVersion".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#resources".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("kubeproxy.config.k8s.io/v1alpha1".to_owned())]),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "bindAddress".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("bindAddress is the IP address for the proxy server to serve on (set to 0.0.0.0 for all interfaces)".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "bindAddressHardFail".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("bindAddressHardFail, if true, kube-proxy will treat failure to bind to a port as fatal and exit".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "clientConnection".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::component_base::config::v1alpha1::ClientConnectionConfiguration>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("clientConnection specifies the kubeconfig file and client connection settings for the proxy server to use when communicating with the apiserver.".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
+                        "clusterCIDR".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("clusterCIDR is the CIDR range of the pods in the cluster. It is used to bridge traffic coming from outside of the cluster. If not provided, no off-cluster bridging will be performed.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "configSyncPeriod".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("configSyncPeriod is how often configuration from the apiserver is refreshed. Must be greater than 0.\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "conntrack".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::kube_proxy::config::v1alpha1::KubeProxyConntrackConfiguration>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("conntrack contains conntrack-related configuration options.".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
+                        "detectLocalMode".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("DetectLocalMode determines mode to use for detecting local traffic, defaults to LocalModeClusterCIDR".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "enableProfiling".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("enableProfiling enables profiling via web interface on /debug/pprof handler. Profiling handlers will be handled by metrics server.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "featureGates".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("featureGates is a map of feature names to bools that enable or disable alpha/experimental features.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+                            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                                additional_properties: Some(Box::new(
+                                    crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                                        instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                                        ..Default::default()
+                                    })
+                                )),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "healthzBindAddress".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("healthzBindAddress is the IP address and port for the health check server to serve on, defaulting to 0.0.0.0:10256".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "hostnameOverride".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("hostnameOverride, if non-empty, will be used as the identity instead of the actual hostname.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "iptables".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::kube_proxy::config::v1alpha1::KubeProxyIPTablesConfiguration>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("iptables contains iptables-related configuration options.".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
+                        "ipvs".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::kube_proxy::config::v1alpha1::KubeProxyIPVSConfiguration>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("ipvs contains ipvs-related configuration options.".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
+                        "kind".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#types-kinds".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("KubeProxyConfiguration".to_owned())]),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "metricsBindAddress".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("metricsBindAddress is the IP address and port for the metrics server to serve on, defaulting to 127.0.0.1:10249 (set to 0.0.0.0 for all interfaces)".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "mode".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("mode specifies which proxy mode to use.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "nodePortAddresses".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("nodePortAddresses is the --nodeport-addresses value for kube-proxy process. Values must be valid IP blocks. These values are as a parameter to select the interfaces where nodeport works. In case someone would like to expose a service on localhost for local visit and some other interfaces for particular purpose, a list of IP blocks would do that. If set it to \"127.0.0.0/8\", kube-proxy will only select the loopback interface for NodePort. If set it to a non-zero IP block, kube-proxy will filter that down to just the IPs that applied to the node. An empty string slice is meant to select all network interfaces.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Array))),
+                            array: Some(Box::new(crate::schemars::schema::ArrayValidation {
+                                items: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(
+                                    crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                                        instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                                        ..Default::default()
+                                    })
+                                ))),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "oomScoreAdj".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("oomScoreAdj is the oom-score-adj value for kube-proxy process. Values must be within the range [-1000, 1000]".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int32".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "portRange".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("portRange is the range of host ports (beginPort-endPort, inclusive) that may be consumed in order to proxy service traffic. If unspecified (0-0) then ports will be randomly chosen.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "showHiddenMetricsForVersion".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("ShowHiddenMetricsForVersion is the version for which you want to show hidden metrics.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "winkernel".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::kube_proxy::config::v1alpha1::KubeProxyWinkernelConfiguration>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("winkernel contains winkernel-related configuration options.".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties
+                },
+                required: std::array::IntoIter::new([
+                    "apiVersion",
+                    "kind",
+                ]).map(std::borrow::ToOwned::to_owned).collect(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for KubeProxyConfiguration {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(KubeProxyConfiguration {
+            bind_address: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            bind_address_hard_fail: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            client_connection: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            cluster_cidr: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            config_sync_period: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            conntrack: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            detect_local_mode: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            enable_profiling: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            feature_gates: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            healthz_bind_address: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            hostname_override: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            iptables: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            ipvs: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            metrics_bind_address: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            mode: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            node_port_addresses: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            oom_score_adj: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            port_range: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            show_hidden_metrics_for_version: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            winkernel: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
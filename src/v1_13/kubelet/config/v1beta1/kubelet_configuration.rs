@@ -0,0 +1,5844 @@
+// Generated from definition io.k8s.kubelet.config.v1beta1.KubeletConfiguration
+
+/// KubeletConfiguration contains the configuration for the Kubelet.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KubeletConfiguration {
+    /// address is the IP address for the Kubelet to serve on (set to 0.0.0.0 for all interfaces). Default: "0.0.0.0"
+    pub address: Option<String>,
+
+    /// A comma separated whitelist of unsafe sysctls or sysctl patterns (ending in `*`). Unsafe sysctl groups are `kernel.shm*`, `kernel.msg*`, `kernel.sem`, `fs.mqueue.*`, and `net.*`. For example: "`kernel.msg*,net.ipv4.route.min_pmtu`" Default: \[\]
+    pub allowed_unsafe_sysctls: Option<Vec<String>>,
+
+    /// authentication specifies how requests to the Kubelet's server are authenticated.
+    pub authentication: Option<crate::kubelet::config::v1beta1::KubeletAuthentication>,
+
+    /// authorization specifies how requests to the Kubelet's server are authorized.
+    pub authorization: Option<crate::kubelet::config::v1beta1::KubeletAuthorization>,
+
+    /// cgroupDriver is the driver kubelet uses to manipulate CGroups on the host (cgroupfs or systemd). Default: "cgroupfs"
+    pub cgroup_driver: Option<String>,
+
+    /// cgroupRoot is the root cgroup to use for pods. This is handled by the container runtime on a best effort basis.
+    pub cgroup_root: Option<String>,
+
+    /// cgroupsPerQOS enable QoS based CGroup hierarchy: top level CGroups for QoS classes and all Burstable and BestEffort Pods are brought up under their specific top level QoS CGroup. Default: true
+    pub cgroups_per_qos: Option<bool>,
+
+    /// clusterDNS is a list of IP addresses for the cluster DNS server. If set, kubelet will configure all containers to use this for DNS resolution instead of the host's DNS servers. Default: nil
+    pub cluster_dns: Option<Vec<String>>,
+
+    /// clusterDomain is the DNS domain for this cluster. If set, kubelet will configure all containers to search this domain in addition to the host's search domains. Default: ""
+    pub cluster_domain: Option<String>,
+
+    /// configMapAndSecretChangeDetectionStrategy is a mode in which ConfigMap and Secret managers are running. Valid values include: `Get`, `Cache` and `Watch`. Default: "Watch"
+    pub config_map_and_secret_change_detection_strategy: Option<String>,
+
+    /// containerLogMaxFiles specifies the maximum number of container log files that can be present for a container. Default: 5
+    pub container_log_max_files: Option<i32>,
+
+    /// containerLogMaxSize is a quantity defining the maximum size of the container log file before it is rotated. For example: "5Mi" or "256Ki". Default: "10Mi"
+    pub container_log_max_size: Option<String>,
+
+    /// contentType is contentType of requests sent to apiserver. Default: "application/vnd.kubernetes.protobuf"
+    pub content_type: Option<String>,
+
+    /// cpuCFSQuota enables CPU CFS quota enforcement for containers that specify CPU limits. Default: true
+    pub cpu_cfs_quota: Option<bool>,
+
+    /// cpuCFSQuotaPeriod is the CPU CFS quota period value, `cpu.cfs_period_us`. The value must be between 1 us and 1 second, inclusive. Requires the CustomCPUCFSQuotaPeriod feature gate to be enabled. Default: "100ms"
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub cpu_cfs_quota_period: Option<String>,
+
+    /// cpuManagerPolicy is the name of the policy to use. Requires the CPUManager feature gate to be enabled. Default: "None"
+    pub cpu_manager_policy: Option<String>,
+
+    /// cpuManagerPolicyOptions is a set of key=value which allows to set extra options to fine tune the behaviour of the cpu manager policies. Requires both the "CPUManager" and "CPUManagerPolicyOptions" feature gates to be enabled. Default: nil
+    pub cpu_manager_policy_options: Option<std::collections::BTreeMap<String, String>>,
+
+    /// cpuManagerReconcilePeriod is the reconciliation period for the CPU Manager. Requires the CPUManager feature gate to be enabled. Default: "10s"
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub cpu_manager_reconcile_period: Option<String>,
+
+    /// enableContentionProfiling enables lock contention profiling, if enableDebuggingHandlers is true. Default: false
+    pub enable_contention_profiling: Option<bool>,
+
+    /// enableControllerAttachDetach enables the Attach/Detach controller to manage attachment/detachment of volumes scheduled to this node, and disables kubelet from executing any attach/detach operations. Default: true
+    pub enable_controller_attach_detach: Option<bool>,
+
+    /// enableDebugFlagsHandler enables flags endpoint via web interface host:port/debug/flags/v Default: true
+    pub enable_debug_flags_handler: Option<bool>,
+
+    /// enableDebuggingHandlers enables server endpoints for log access and local running of containers and commands, including the exec, attach, logs, and portforward features. Default: true
+    pub enable_debugging_handlers: Option<bool>,
+
+    /// enableProfilingHandler enables profiling via web interface host:port/debug/pprof/ Default: true
+    pub enable_profiling_handler: Option<bool>,
+
+    /// enableServer enables the kubelet's secured server. Note: the kubelet's insecure port is controlled by the readOnlyPort option. Default: true
+    pub enable_server: Option<bool>,
+
+    /// enableSystemLogHandler enables system logs via web interface host:port/logs/ Default: true
+    pub enable_system_log_handler: Option<bool>,
+
+    /// This flag specifies the various Node Allocatable enforcements that Kubelet needs to perform. This flag accepts a list of options. Acceptable options are `none`, `pods`, `system-reserved` and `kube-reserved`. Default: \["pods"\]
+    pub enforce_node_allocatable: Option<Vec<String>>,
+
+    /// eventBurst is the maximum size of a burst of event creations, temporarily allows event creations to burst to this number, while still not exceeding eventRecordQPS. This field canot be a negative number and it is only used when eventRecordQPS \> 0. Default: 10
+    pub event_burst: Option<i32>,
+
+    /// eventRecordQPS is the maximum event creations per second. If 0, there is no limit enforced. The value cannot be a negative number. Default: 5
+    pub event_record_qps: Option<i32>,
+
+    /// evictionHard is a map of signal names to quantities that defines hard eviction thresholds. For example: `{"memory.available": "300Mi"}`. To explicitly disable, pass a 0% or 100% threshold on an arbitrary resource. Default: memory.available: "100Mi", nodefs.available: "10%", nodefs.inodesFree: "5%", imagefs.available: "15%"
+    pub eviction_hard: Option<std::collections::BTreeMap<String, String>>,
+
+    /// evictionMaxPodGracePeriod is the maximum allowed grace period (in seconds) to use when terminating pods in response to a soft eviction threshold being met. This value effectively caps the Pod's terminationGracePeriodSeconds value during soft evictions. Default: 0
+    pub eviction_max_pod_grace_period: Option<i32>,
+
+    /// evictionMinimumReclaim is a map of signal names to quantities that defines minimum reclaims, which describe the minimum amount of a given resource the kubelet will reclaim when performing a pod eviction while that resource is under pressure. For example: `{"imagefs.available": "2Gi"}`. Default: nil
+    pub eviction_minimum_reclaim: Option<std::collections::BTreeMap<String, String>>,
+
+    /// evictionPressureTransitionPeriod is the duration for which the kubelet has to wait before transitioning out of an eviction pressure condition. Default: "5m"
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub eviction_pressure_transition_period: Option<String>,
+
+    /// evictionSoft is a map of signal names to quantities that defines soft eviction thresholds. For example: `{"memory.available": "300Mi"}`. Default: nil
+    pub eviction_soft: Option<std::collections::BTreeMap<String, String>>,
+
+    /// evictionSoftGracePeriod is a map of signal names to quantities that defines grace periods for each soft eviction signal. For example: `{"memory.available": "30s"}`. Default: nil
+    pub eviction_soft_grace_period: Option<std::collections::BTreeMap<String, String>>,
+
+    /// failSwapOn tells the Kubelet to fail to start if swap is enabled on the node. Default: true
+    pub fail_swap_on: Option<bool>,
+
+    /// featureGates is a map of feature names to bools that enable or disable experimental features. This field modifies piecemeal the built-in default values from "k8s.io/kubernetes/pkg/features/kube_features.go". Default: nil
+    pub feature_gates: Option<std::collections::BTreeMap<String, bool>>,
+
+    /// fileCheckFrequency is the duration between checking config files for new data. Default: "20s"
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub file_check_frequency: Option<String>,
+
+    /// hairpinMode specifies how the Kubelet should configure the container bridge for hairpin packets. Setting this flag allows endpoints in a Service to loadbalance back to themselves if they should try to access their own Service. Values: `promiscuous-bridge`, `hairpin-veth` and `none`. Default: "promiscuous-bridge"
+    pub hairpin_mode: Option<String>,
+
+    /// healthzBindAddress is the IP address for the healthz server to serve on. Default: "127.0.0.1"
+    pub healthz_bind_address: Option<String>,
+
+    /// healthzPort is the port of the localhost healthz endpoint (set to 0 to disable). A valid number is between 1 and 65535. Default: 10248
+    pub healthz_port: Option<i32>,
+
+    /// httpCheckFrequency is the duration between checking http for new data. Default: "20s"
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub http_check_frequency: Option<String>,
+
+    /// imageGCHighThresholdPercent is the percent of disk usage after which image garbage collection is always run. The percent is calculated by dividing this field value by 100, so this field must be between 0 and 100, inclusive. Default: 85
+    pub image_gc_high_threshold_percent: Option<i32>,
+
+    /// imageGCLowThresholdPercent is the percent of disk usage before which image garbage collection is never run. Lowest disk usage to garbage collect to. The percent is calculated by dividing this field value by 100, so the field value must be between 0 and 100, inclusive. Default: 80
+    pub image_gc_low_threshold_percent: Option<i32>,
+
+    /// imageMinimumGCAge is the minimum age for an unused image before it is garbage collected. Default: "2m"
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub image_minimum_gc_age: Option<String>,
+
+    /// iptablesDropBit is the bit of the iptables fwmark space to mark for dropping packets. Values must be within the range \[0, 31\]. Must be different from other mark bits. Default: 15
+    pub iptables_drop_bit: Option<i32>,
+
+    /// iptablesMasqueradeBit is the bit of the iptables fwmark space to mark for SNAT. Values must be within the range \[0, 31\]. Must be different from other mark bits. Warning: Please match the value of the corresponding parameter in kube-proxy. Default: 14
+    pub iptables_masquerade_bit: Option<i32>,
+
+    /// kernelMemcgNotification, if set, instructs the the kubelet to integrate with the kernel memcg notification for determining if memory eviction thresholds are exceeded rather than polling. Default: false
+    pub kernel_memcg_notification: Option<bool>,
+
+    /// kubeAPIBurst is the burst to allow while talking with kubernetes API server. This field cannot be a negative number. Default: 10
+    pub kube_api_burst: Option<i32>,
+
+    /// kubeAPIQPS is the QPS to use while talking with kubernetes apiserver. Default: 5
+    pub kube_api_qps: Option<i32>,
+
+    /// kubeReserved is a set of ResourceName=ResourceQuantity (e.g. cpu=200m,memory=150G) pairs that describe resources reserved for kubernetes system components. Currently cpu, memory and local storage for root file system are supported. Default: nil
+    pub kube_reserved: Option<std::collections::BTreeMap<String, String>>,
+
+    /// kubeReservedCgroup helps the kubelet identify absolute name of top level CGroup used to enforce `KubeReserved` compute resource reservation for Kubernetes node system daemons. Default: ""
+    pub kube_reserved_cgroup: Option<String>,
+
+    /// kubeletCgroups is the absolute name of cgroups to isolate the kubelet in Default: ""
+    pub kubelet_cgroups: Option<String>,
+
+    /// logging specifies the options of logging.
+    pub logging: Option<crate::kubelet::config::v1beta1::LoggingConfiguration>,
+
+    /// makeIPTablesUtilChains, if true, causes the Kubelet ensures a set of iptables rules are present on host. These rules will serve as utility rules for various components, e.g. kube-proxy. The rules will be created based on iptablesMasqueradeBit and iptablesDropBit. Default: true
+    pub make_iptables_util_chains: Option<bool>,
+
+    /// maxOpenFiles is Number of files that can be opened by Kubelet process. The value must be a non-negative number. Default: 1000000
+    pub max_open_files: Option<i64>,
+
+    /// maxPods is the maximum number of Pods that can run on this Kubelet. The value must be a non-negative integer. Default: 110
+    pub max_pods: Option<i32>,
+
+    /// memoryManagerPolicy is the name of memory manager policy to use. Requires the MemoryManager feature gate to be enabled. Default: "none"
+    pub memory_manager_policy: Option<String>,
+
+    /// memorySwap configures swap memory available to container workloads.
+    pub memory_swap: Option<crate::kubelet::config::v1beta1::MemorySwapConfiguration>,
+
+    /// MemoryThrottlingFactor specifies the factor multiplied by the memory limit or node allocatable memory when setting the cgroupv2 memory.high value to enforce MemoryQoS. Decreasing this factor will set lower high limit for container cgroups and put heavier reclaim pressure while increasing will put less reclaim pressure. Default: 0.8
+    pub memory_throttling_factor: Option<f64>,
+
+    /// nodeLeaseDurationSeconds is the duration the Kubelet will set on its corresponding Lease. Default: 40
+    pub node_lease_duration_seconds: Option<i32>,
+
+    /// nodeStatusMaxImages caps the number of images reported in Node.status.images. The value must be greater than -2. Note: If -1 is specified, no cap will be applied. If 0 is specified, no image is returned. Default: 50
+    pub node_status_max_images: Option<i32>,
+
+    /// nodeStatusReportFrequency is the frequency that kubelet posts node status to master if node status does not change. Kubelet will ignore this frequency and post node status immediately if any change is detected. Default: "5m"
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub node_status_report_frequency: Option<String>,
+
+    /// nodeStatusUpdateFrequency is the frequency that kubelet computes node status. If node lease feature is not enabled, it is also the frequency that kubelet posts node status to master. Default: "10s"
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub node_status_update_frequency: Option<String>,
+
+    /// oomScoreAdj is The oom-score-adj value for kubelet process. Values must be within the range \[-1000, 1000\]. Default: -999
+    pub oom_score_adj: Option<i32>,
+
+    /// podCIDR is the CIDR to use for pod IP addresses, only used in standalone mode. In cluster mode, this is obtained from the control plane. Default: ""
+    pub pod_cidr: Option<String>,
+
+    /// podPidsLimit is the maximum number of PIDs in any pod. Default: -1
+    pub pod_pids_limit: Option<i64>,
+
+    /// podsPerCore is the maximum number of pods per core. Cannot exceed maxPods. The value must be a non-negative integer. If 0, there is no limit on the number of Pods. Default: 0
+    pub pods_per_core: Option<i32>,
+
+    /// port is the port for the Kubelet to serve on. Default: 10250
+    pub port: Option<i32>,
+
+    /// protectKernelDefaults, if true, causes the Kubelet to error if kernel flags are not as it expects. Otherwise the Kubelet will attempt to modify kernel flags to match its expectation. Default: false
+    pub protect_kernel_defaults: Option<bool>,
+
+    /// providerID, if set, sets the unique ID of the instance that an external provider (i.e. cloudprovider) can use to identify a specific node. Default: ""
+    pub provider_id: Option<String>,
+
+    /// qosReserved is a set of resource name to percentage pairs that specify the minimum percentage of a resource reserved for exclusive use by the guaranteed QoS tier. Currently supported resources: "memory". Requires the QOSReserved feature gate to be enabled. Default: nil
+    pub qos_reserved: Option<std::collections::BTreeMap<String, String>>,
+
+    /// readOnlyPort is the read-only port for the Kubelet to serve on with no authentication/authorization. The port number must be between 1 and 65535, inclusive. Setting this field to 0 disables the read-only service. Default: 0 (disabled)
+    pub read_only_port: Option<i32>,
+
+    /// registryBurst is the maximum size of bursty pulls, temporarily allows pulls to burst to this number, while still not exceeding registryPullQPS. The value must not be a negative number. Only used if registryPullQPS is greater than 0. Default: 10
+    pub registry_burst: Option<i32>,
+
+    /// registryPullQPS is the limit of registry pulls per second. The value must not be a negative number. Setting it to 0 means no limit. Default: 5
+    pub registry_pull_qps: Option<i32>,
+
+    /// reservedMemory specifies a comma-separated list of memory reservations for NUMA nodes. The parameter makes sense only in the context of the memory manager feature. Default: nil
+    pub reserved_memory: Option<Vec<crate::kubelet::config::v1beta1::MemoryReservation>>,
+
+    /// The reservedSystemCPUs option specifies the CPU list reserved for the host level system threads and kubernetes related threads. This provide a "static" CPU list rather than the "dynamic" list by systemReserved and kubeReserved. This option does not support systemReservedCgroup or kubeReservedCgroup.
+    pub reserved_system_cpus: Option<String>,
+
+    /// resolvConf is the resolver configuration file used as the basis for the container DNS resolution configuration. If set to the empty string, will override the default and effectively disable DNS lookups. Default: "/etc/resolv.conf"
+    pub resolv_conf: Option<String>,
+
+    /// rotateCertificates enables client certificate rotation. The Kubelet will request a new certificate from the certificates.k8s.io API. This requires an approver to approve the certificate signing requests. Default: false
+    pub rotate_certificates: Option<bool>,
+
+    /// runOnce causes the Kubelet to check the API server once for pods, run those in addition to the pods specified by static pod files, and exit. Default: false
+    pub run_once: Option<bool>,
+
+    /// runtimeRequestTimeout is the timeout for all runtime requests except long running requests - pull, logs, exec and attach. Default: "2m"
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub runtime_request_timeout: Option<String>,
+
+    /// SeccompDefault enables the use of `RuntimeDefault` as the default seccomp profile for all workloads. This requires the corresponding SeccompDefault feature gate to be enabled as well. Default: false
+    pub seccomp_default: Option<bool>,
+
+    /// serializeImagePulls when enabled, tells the Kubelet to pull images one at a time. We recommend *not* changing the default value on nodes that run docker daemon with version  \< 1.9 or an Aufs storage backend. Default: true
+    pub serialize_image_pulls: Option<bool>,
+
+    /// serverTLSBootstrap enables server certificate bootstrap. Instead of self signing a serving certificate, the Kubelet will request a certificate from the 'certificates.k8s.io' API. This requires an approver to approve the certificate signing requests (CSR). Default: false
+    pub server_tls_bootstrap: Option<bool>,
+
+    /// showHiddenMetricsForVersion is the previous version for which you want to show hidden metrics. Only the previous minor version is meaningful, other values will not be allowed. The format is `\<major\>.\<minor\>`, e.g.: `1.16`. Default: ""
+    pub show_hidden_metrics_for_version: Option<String>,
+
+    /// shutdownGracePeriod specifies the total duration that the node should delay the shutdown and total grace period for pod termination during a node shutdown. Default: "0s"
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub shutdown_grace_period: Option<String>,
+
+    /// shutdownGracePeriodCriticalPods specifies the duration used to terminate critical pods during a node shutdown. This should be less than shutdownGracePeriod. Default: "0s"
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub shutdown_grace_period_critical_pods: Option<String>,
+
+    /// staticPodPath is the path to the directory containing local (static) pods to run, or the path to a single static pod file. Default: ""
+    pub static_pod_path: Option<String>,
+
+    /// staticPodURL is the URL for accessing static pods to run. Default: ""
+    pub static_pod_url: Option<String>,
+
+    /// staticPodURLHeader is a map of slices with HTTP headers to use when accessing the podURL. Default: nil
+    pub static_pod_url_header: Option<std::collections::BTreeMap<String, Vec<String>>>,
+
+    /// streamingConnectionIdleTimeout is the maximum time a streaming connection can be idle before the connection is automatically closed. Default: "4h"
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub streaming_connection_idle_timeout: Option<String>,
+
+    /// syncFrequency is the max period between synchronizing running containers and config. Default: "1m"
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub sync_frequency: Option<String>,
+
+    /// systemCgroups is absolute name of cgroups in which to place all non-kernel processes that are not already in a container. Empty for no container. Rolling back the flag requires a reboot. Default: ""
+    pub system_cgroups: Option<String>,
+
+    /// systemReserved is a set of ResourceName=ResourceQuantity (e.g. cpu=200m,memory=150G) pairs that describe resources reserved for non-kubernetes components. Currently only cpu and memory are supported. Default: nil
+    pub system_reserved: Option<std::collections::BTreeMap<String, String>>,
+
+    /// systemReservedCgroup helps the kubelet identify absolute name of top level CGroup used to enforce `systemReserved` compute resource reservation for OS system daemons. Default: ""
+    pub system_reserved_cgroup: Option<String>,
+
+    /// tlsCertFile is the file containing x509 Certificate for HTTPS. If tlsCertFile and tlsPrivateKeyFile are not provided, a self-signed certificate and key are generated for the public address and saved to the directory passed to the Kubelet's --cert-dir flag. Default: ""
+    pub tls_cert_file: Option<String>,
+
+    /// TLSCipherSuites is the list of allowed cipher suites for the server. Values are from tls package constants (https://golang.org/pkg/crypto/tls/#pkg-constants). Default: nil
+    pub tls_cipher_suites: Option<Vec<String>>,
+
+    /// TLSMinVersion is the minimum TLS version supported. Values are from tls package constants (https://golang.org/pkg/crypto/tls/#pkg-constants). Default: ""
+    pub tls_min_version: Option<String>,
+
+    /// tlsPrivateKeyFile is the file containing x509 private key matching tlsCertFile. Default: ""
+    pub tls_private_key_file: Option<String>,
+
+    /// topologyManagerPolicy is the name of the topology manager policy to use. Valid values include: `restricted`, `best-effort`, `none` and `single-numa-node`. Default: "none"
+    pub topology_manager_policy: Option<String>,
+
+    /// topologyManagerScope represents the scope of topology hint generation that topology manager requests and hint providers generate. Valid values include: `container` and `pod`. Default: "container"
+    pub topology_manager_scope: Option<String>,
+
+    /// volumePluginDir is the full path of the directory in which to search for additional third party volume plugins. Default: "/usr/libexec/kubernetes/kubelet-plugins/volume/exec/"
+    pub volume_plugin_dir: Option<String>,
+
+    /// volumeStatsAggPeriod is the frequency for calculating and caching volume disk usage for all pods. Default: "1m"
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub volume_stats_agg_period: Option<String>,
+}
+
+impl KubeletConfiguration {
+    /// Returns this value with [`KubeletConfiguration::address`] set to the given value.
+    #[must_use]
+    pub fn with_address(mut self, value: impl Into<String>) -> Self {
+        self.address = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::allowed_unsafe_sysctls`] set to the given value.
+    #[must_use]
+    pub fn with_allowed_unsafe_sysctls(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.allowed_unsafe_sysctls = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::authentication`] set to the given value.
+    #[must_use]
+    pub fn with_authentication(mut self, value: impl Into<crate::kubelet::config::v1beta1::KubeletAuthentication>) -> Self {
+        self.authentication = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::authorization`] set to the given value.
+    #[must_use]
+    pub fn with_authorization(mut self, value: impl Into<crate::kubelet::config::v1beta1::KubeletAuthorization>) -> Self {
+        self.authorization = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::cgroup_driver`] set to the given value.
+    #[must_use]
+    pub fn with_cgroup_driver(mut self, value: impl Into<String>) -> Self {
+        self.cgroup_driver = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::cgroup_root`] set to the given value.
+    #[must_use]
+    pub fn with_cgroup_root(mut self, value: impl Into<String>) -> Self {
+        self.cgroup_root = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::cgroups_per_qos`] set to the given value.
+    #[must_use]
+    pub fn with_cgroups_per_qos(mut self, value: impl Into<bool>) -> Self {
+        self.cgroups_per_qos = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::cluster_dns`] set to the given value.
+    #[must_use]
+    pub fn with_cluster_dns(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.cluster_dns = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::cluster_domain`] set to the given value.
+    #[must_use]
+    pub fn with_cluster_domain(mut self, value: impl Into<String>) -> Self {
+        self.cluster_domain = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::config_map_and_secret_change_detection_strategy`] set to the given value.
+    #[must_use]
+    pub fn with_config_map_and_secret_change_detection_strategy(mut self, value: impl Into<String>) -> Self {
+        self.config_map_and_secret_change_detection_strategy = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::container_log_max_files`] set to the given value.
+    #[must_use]
+    pub fn with_container_log_max_files(mut self, value: impl Into<i32>) -> Self {
+        self.container_log_max_files = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::container_log_max_size`] set to the given value.
+    #[must_use]
+    pub fn with_container_log_max_size(mut self, value: impl Into<String>) -> Self {
+        self.container_log_max_size = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::content_type`] set to the given value.
+    #[must_use]
+    pub fn with_content_type(mut self, value: impl Into<String>) -> Self {
+        self.content_type = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::cpu_cfs_quota`] set to the given value.
+    #[must_use]
+    pub fn with_cpu_cfs_quota(mut self, value: impl Into<bool>) -> Self {
+        self.cpu_cfs_quota = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::cpu_cfs_quota_period`] set to the given value.
+    #[must_use]
+    pub fn with_cpu_cfs_quota_period(mut self, value: impl Into<String>) -> Self {
+        self.cpu_cfs_quota_period = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::cpu_manager_policy`] set to the given value.
+    #[must_use]
+    pub fn with_cpu_manager_policy(mut self, value: impl Into<String>) -> Self {
+        self.cpu_manager_policy = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::cpu_manager_policy_options`] set to the given value.
+    #[must_use]
+    pub fn with_cpu_manager_policy_options(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.cpu_manager_policy_options = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::cpu_manager_reconcile_period`] set to the given value.
+    #[must_use]
+    pub fn with_cpu_manager_reconcile_period(mut self, value: impl Into<String>) -> Self {
+        self.cpu_manager_reconcile_period = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::enable_contention_profiling`] set to the given value.
+    #[must_use]
+    pub fn with_enable_contention_profiling(mut self, value: impl Into<bool>) -> Self {
+        self.enable_contention_profiling = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::enable_controller_attach_detach`] set to the given value.
+    #[must_use]
+    pub fn with_enable_controller_attach_detach(mut self, value: impl Into<bool>) -> Self {
+        self.enable_controller_attach_detach = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::enable_debug_flags_handler`] set to the given value.
+    #[must_use]
+    pub fn with_enable_debug_flags_handler(mut self, value: impl Into<bool>) -> Self {
+        self.enable_debug_flags_handler = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::enable_debugging_handlers`] set to the given value.
+    #[must_use]
+    pub fn with_enable_debugging_handlers(mut self, value: impl Into<bool>) -> Self {
+        self.enable_debugging_handlers = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::enable_profiling_handler`] set to the given value.
+    #[must_use]
+    pub fn with_enable_profiling_handler(mut self, value: impl Into<bool>) -> Self {
+        self.enable_profiling_handler = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::enable_server`] set to the given value.
+    #[must_use]
+    pub fn with_enable_server(mut self, value: impl Into<bool>) -> Self {
+        self.enable_server = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::enable_system_log_handler`] set to the given value.
+    #[must_use]
+    pub fn with_enable_system_log_handler(mut self, value: impl Into<bool>) -> Self {
+        self.enable_system_log_handler = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::enforce_node_allocatable`] set to the given value.
+    #[must_use]
+    pub fn with_enforce_node_allocatable(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.enforce_node_allocatable = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::event_burst`] set to the given value.
+    #[must_use]
+    pub fn with_event_burst(mut self, value: impl Into<i32>) -> Self {
+        self.event_burst = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::event_record_qps`] set to the given value.
+    #[must_use]
+    pub fn with_event_record_qps(mut self, value: impl Into<i32>) -> Self {
+        self.event_record_qps = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::eviction_hard`] set to the given value.
+    #[must_use]
+    pub fn with_eviction_hard(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.eviction_hard = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::eviction_max_pod_grace_period`] set to the given value.
+    #[must_use]
+    pub fn with_eviction_max_pod_grace_period(mut self, value: impl Into<i32>) -> Self {
+        self.eviction_max_pod_grace_period = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::eviction_minimum_reclaim`] set to the given value.
+    #[must_use]
+    pub fn with_eviction_minimum_reclaim(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.eviction_minimum_reclaim = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::eviction_pressure_transition_period`] set to the given value.
+    #[must_use]
+    pub fn with_eviction_pressure_transition_period(mut self, value: impl Into<String>) -> Self {
+        self.eviction_pressure_transition_period = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::eviction_soft`] set to the given value.
+    #[must_use]
+    pub fn with_eviction_soft(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.eviction_soft = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::eviction_soft_grace_period`] set to the given value.
+    #[must_use]
+    pub fn with_eviction_soft_grace_period(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.eviction_soft_grace_period = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::fail_swap_on`] set to the given value.
+    #[must_use]
+    pub fn with_fail_swap_on(mut self, value: impl Into<bool>) -> Self {
+        self.fail_swap_on = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::feature_gates`] set to the given value.
+    #[must_use]
+    pub fn with_feature_gates(mut self, value: impl Into<std::collections::BTreeMap<String, bool>>) -> Self {
+        self.feature_gates = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::file_check_frequency`] set to the given value.
+    #[must_use]
+    pub fn with_file_check_frequency(mut self, value: impl Into<String>) -> Self {
+        self.file_check_frequency = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::hairpin_mode`] set to the given value.
+    #[must_use]
+    pub fn with_hairpin_mode(mut self, value: impl Into<String>) -> Self {
+        self.hairpin_mode = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::healthz_bind_address`] set to the given value.
+    #[must_use]
+    pub fn with_healthz_bind_address(mut self, value: impl Into<String>) -> Self {
+        self.healthz_bind_address = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::healthz_port`] set to the given value.
+    #[must_use]
+    pub fn with_healthz_port(mut self, value: impl Into<i32>) -> Self {
+        self.healthz_port = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::http_check_frequency`] set to the given value.
+    #[must_use]
+    pub fn with_http_check_frequency(mut self, value: impl Into<String>) -> Self {
+        self.http_check_frequency = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::image_gc_high_threshold_percent`] set to the given value.
+    #[must_use]
+    pub fn with_image_gc_high_threshold_percent(mut self, value: impl Into<i32>) -> Self {
+        self.image_gc_high_threshold_percent = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::image_gc_low_threshold_percent`] set to the given value.
+    #[must_use]
+    pub fn with_image_gc_low_threshold_percent(mut self, value: impl Into<i32>) -> Self {
+        self.image_gc_low_threshold_percent = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::image_minimum_gc_age`] set to the given value.
+    #[must_use]
+    pub fn with_image_minimum_gc_age(mut self, value: impl Into<String>) -> Self {
+        self.image_minimum_gc_age = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::iptables_drop_bit`] set to the given value.
+    #[must_use]
+    pub fn with_iptables_drop_bit(mut self, value: impl Into<i32>) -> Self {
+        self.iptables_drop_bit = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::iptables_masquerade_bit`] set to the given value.
+    #[must_use]
+    pub fn with_iptables_masquerade_bit(mut self, value: impl Into<i32>) -> Self {
+        self.iptables_masquerade_bit = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::kernel_memcg_notification`] set to the given value.
+    #[must_use]
+    pub fn with_kernel_memcg_notification(mut self, value: impl Into<bool>) -> Self {
+        self.kernel_memcg_notification = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::kube_api_burst`] set to the given value.
+    #[must_use]
+    pub fn with_kube_api_burst(mut self, value: impl Into<i32>) -> Self {
+        self.kube_api_burst = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::kube_api_qps`] set to the given value.
+    #[must_use]
+    pub fn with_kube_api_qps(mut self, value: impl Into<i32>) -> Self {
+        self.kube_api_qps = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::kube_reserved`] set to the given value.
+    #[must_use]
+    pub fn with_kube_reserved(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.kube_reserved = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::kube_reserved_cgroup`] set to the given value.
+    #[must_use]
+    pub fn with_kube_reserved_cgroup(mut self, value: impl Into<String>) -> Self {
+        self.kube_reserved_cgroup = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::kubelet_cgroups`] set to the given value.
+    #[must_use]
+    pub fn with_kubelet_cgroups(mut self, value: impl Into<String>) -> Self {
+        self.kubelet_cgroups = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::logging`] set to the given value.
+    #[must_use]
+    pub fn with_logging(mut self, value: impl Into<crate::kubelet::config::v1beta1::LoggingConfiguration>) -> Self {
+        self.logging = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::make_iptables_util_chains`] set to the given value.
+    #[must_use]
+    pub fn with_make_iptables_util_chains(mut self, value: impl Into<bool>) -> Self {
+        self.make_iptables_util_chains = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::max_open_files`] set to the given value.
+    #[must_use]
+    pub fn with_max_open_files(mut self, value: impl Into<i64>) -> Self {
+        self.max_open_files = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::max_pods`] set to the given value.
+    #[must_use]
+    pub fn with_max_pods(mut self, value: impl Into<i32>) -> Self {
+        self.max_pods = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::memory_manager_policy`] set to the given value.
+    #[must_use]
+    pub fn with_memory_manager_policy(mut self, value: impl Into<String>) -> Self {
+        self.memory_manager_policy = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::memory_swap`] set to the given value.
+    #[must_use]
+    pub fn with_memory_swap(mut self, value: impl Into<crate::kubelet::config::v1beta1::MemorySwapConfiguration>) -> Self {
+        self.memory_swap = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::memory_throttling_factor`] set to the given value.
+    #[must_use]
+    pub fn with_memory_throttling_factor(mut self, value: impl Into<f64>) -> Self {
+        self.memory_throttling_factor = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::node_lease_duration_seconds`] set to the given value.
+    #[must_use]
+    pub fn with_node_lease_duration_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.node_lease_duration_seconds = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::node_status_max_images`] set to the given value.
+    #[must_use]
+    pub fn with_node_status_max_images(mut self, value: impl Into<i32>) -> Self {
+        self.node_status_max_images = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::node_status_report_frequency`] set to the given value.
+    #[must_use]
+    pub fn with_node_status_report_frequency(mut self, value: impl Into<String>) -> Self {
+        self.node_status_report_frequency = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::node_status_update_frequency`] set to the given value.
+    #[must_use]
+    pub fn with_node_status_update_frequency(mut self, value: impl Into<String>) -> Self {
+        self.node_status_update_frequency = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::oom_score_adj`] set to the given value.
+    #[must_use]
+    pub fn with_oom_score_adj(mut self, value: impl Into<i32>) -> Self {
+        self.oom_score_adj = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::pod_cidr`] set to the given value.
+    #[must_use]
+    pub fn with_pod_cidr(mut self, value: impl Into<String>) -> Self {
+        self.pod_cidr = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::pod_pids_limit`] set to the given value.
+    #[must_use]
+    pub fn with_pod_pids_limit(mut self, value: impl Into<i64>) -> Self {
+        self.pod_pids_limit = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::pods_per_core`] set to the given value.
+    #[must_use]
+    pub fn with_pods_per_core(mut self, value: impl Into<i32>) -> Self {
+        self.pods_per_core = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::port`] set to the given value.
+    #[must_use]
+    pub fn with_port(mut self, value: impl Into<i32>) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::protect_kernel_defaults`] set to the given value.
+    #[must_use]
+    pub fn with_protect_kernel_defaults(mut self, value: impl Into<bool>) -> Self {
+        self.protect_kernel_defaults = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::provider_id`] set to the given value.
+    #[must_use]
+    pub fn with_provider_id(mut self, value: impl Into<String>) -> Self {
+        self.provider_id = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::qos_reserved`] set to the given value.
+    #[must_use]
+    pub fn with_qos_reserved(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.qos_reserved = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::read_only_port`] set to the given value.
+    #[must_use]
+    pub fn with_read_only_port(mut self, value: impl Into<i32>) -> Self {
+        self.read_only_port = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::registry_burst`] set to the given value.
+    #[must_use]
+    pub fn with_registry_burst(mut self, value: impl Into<i32>) -> Self {
+        self.registry_burst = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::registry_pull_qps`] set to the given value.
+    #[must_use]
+    pub fn with_registry_pull_qps(mut self, value: impl Into<i32>) -> Self {
+        self.registry_pull_qps = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::reserved_memory`] set to the given value.
+    #[must_use]
+    pub fn with_reserved_memory(mut self, value: impl Into<Vec<crate::kubelet::config::v1beta1::MemoryReservation>>) -> Self {
+        self.reserved_memory = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::reserved_system_cpus`] set to the given value.
+    #[must_use]
+    pub fn with_reserved_system_cpus(mut self, value: impl Into<String>) -> Self {
+        self.reserved_system_cpus = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::resolv_conf`] set to the given value.
+    #[must_use]
+    pub fn with_resolv_conf(mut self, value: impl Into<String>) -> Self {
+        self.resolv_conf = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::rotate_certificates`] set to the given value.
+    #[must_use]
+    pub fn with_rotate_certificates(mut self, value: impl Into<bool>) -> Self {
+        self.rotate_certificates = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::run_once`] set to the given value.
+    #[must_use]
+    pub fn with_run_once(mut self, value: impl Into<bool>) -> Self {
+        self.run_once = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::runtime_request_timeout`] set to the given value.
+    #[must_use]
+    pub fn with_runtime_request_timeout(mut self, value: impl Into<String>) -> Self {
+        self.runtime_request_timeout = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::seccomp_default`] set to the given value.
+    #[must_use]
+    pub fn with_seccomp_default(mut self, value: impl Into<bool>) -> Self {
+        self.seccomp_default = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::serialize_image_pulls`] set to the given value.
+    #[must_use]
+    pub fn with_serialize_image_pulls(mut self, value: impl Into<bool>) -> Self {
+        self.serialize_image_pulls = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::server_tls_bootstrap`] set to the given value.
+    #[must_use]
+    pub fn with_server_tls_bootstrap(mut self, value: impl Into<bool>) -> Self {
+        self.server_tls_bootstrap = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::show_hidden_metrics_for_version`] set to the given value.
+    #[must_use]
+    pub fn with_show_hidden_metrics_for_version(mut self, value: impl Into<String>) -> Self {
+        self.show_hidden_metrics_for_version = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::shutdown_grace_period`] set to the given value.
+    #[must_use]
+    pub fn with_shutdown_grace_period(mut self, value: impl Into<String>) -> Self {
+        self.shutdown_grace_period = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::shutdown_grace_period_critical_pods`] set to the given value.
+    #[must_use]
+    pub fn with_shutdown_grace_period_critical_pods(mut self, value: impl Into<String>) -> Self {
+        self.shutdown_grace_period_critical_pods = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::static_pod_path`] set to the given value.
+    #[must_use]
+    pub fn with_static_pod_path(mut self, value: impl Into<String>) -> Self {
+        self.static_pod_path = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::static_pod_url`] set to the given value.
+    #[must_use]
+    pub fn with_static_pod_url(mut self, value: impl Into<String>) -> Self {
+        self.static_pod_url = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::static_pod_url_header`] set to the given value.
+    #[must_use]
+    pub fn with_static_pod_url_header(mut self, value: impl Into<std::collections::BTreeMap<String, Vec<String>>>) -> Self {
+        self.static_pod_url_header = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::streaming_connection_idle_timeout`] set to the given value.
+    #[must_use]
+    pub fn with_streaming_connection_idle_timeout(mut self, value: impl Into<String>) -> Self {
+        self.streaming_connection_idle_timeout = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::sync_frequency`] set to the given value.
+    #[must_use]
+    pub fn with_sync_frequency(mut self, value: impl Into<String>) -> Self {
+        self.sync_frequency = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::system_cgroups`] set to the given value.
+    #[must_use]
+    pub fn with_system_cgroups(mut self, value: impl Into<String>) -> Self {
+        self.system_cgroups = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::system_reserved`] set to the given value.
+    #[must_use]
+    pub fn with_system_reserved(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.system_reserved = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::system_reserved_cgroup`] set to the given value.
+    #[must_use]
+    pub fn with_system_reserved_cgroup(mut self, value: impl Into<String>) -> Self {
+        self.system_reserved_cgroup = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::tls_cert_file`] set to the given value.
+    #[must_use]
+    pub fn with_tls_cert_file(mut self, value: impl Into<String>) -> Self {
+        self.tls_cert_file = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::tls_cipher_suites`] set to the given value.
+    #[must_use]
+    pub fn with_tls_cipher_suites(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.tls_cipher_suites = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::tls_min_version`] set to the given value.
+    #[must_use]
+    pub fn with_tls_min_version(mut self, value: impl Into<String>) -> Self {
+        self.tls_min_version = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::tls_private_key_file`] set to the given value.
+    #[must_use]
+    pub fn with_tls_private_key_file(mut self, value: impl Into<String>) -> Self {
+        self.tls_private_key_file = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::topology_manager_policy`] set to the given value.
+    #[must_use]
+    pub fn with_topology_manager_policy(mut self, value: impl Into<String>) -> Self {
+        self.topology_manager_policy = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::topology_manager_scope`] set to the given value.
+    #[must_use]
+    pub fn with_topology_manager_scope(mut self, value: impl Into<String>) -> Self {
+        self.topology_manager_scope = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::volume_plugin_dir`] set to the given value.
+    #[must_use]
+    pub fn with_volume_plugin_dir(mut self, value: impl Into<String>) -> Self {
+        self.volume_plugin_dir = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletConfiguration::volume_stats_agg_period`] set to the given value.
+    #[must_use]
+    pub fn with_volume_stats_agg_period(mut self, value: impl Into<String>) -> Self {
+        self.volume_stats_agg_period = Some(value.into());
+        self
+    }
+}
+
+impl crate::Resource for KubeletConfiguration {
+    const API_VERSION: &'static str = "kubelet.config.k8s.io/v1beta1";
+    const GROUP: &'static str = "kubelet.config.k8s.io";
+    const KIND: &'static str = "KubeletConfiguration";
+    const VERSION: &'static str = "v1beta1";
+    const URL_PATH_SEGMENT: &'static str = "";
+    const VERBS: &'static [&'static str] = &[];
+    const SINGULAR: &'static str = "kubeletconfiguration";
+    const SHORT_NAMES: &'static [&'static str] = &[];
+    type Scope = crate::ClusterResourceScope;
+}
+
+impl crate::MergeStrategyInfo for KubeletConfiguration {}
+
+impl KubeletConfiguration {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "kind",
+        "address",
+        "allowedUnsafeSysctls",
+        "authentication",
+        "authorization",
+        "cgroupDriver",
+        "cgroupRoot",
+        "cgroupsPerQOS",
+        "clusterDNS",
+        "clusterDomain",
+        "configMapAndSecretChangeDetectionStrategy",
+        "containerLogMaxFiles",
+        "containerLogMaxSize",
+        "contentType",
+        "cpuCFSQuota",
+        "cpuCFSQuotaPeriod",
+        "cpuManagerPolicy",
+        "cpuManagerPolicyOptions",
+        "cpuManagerReconcilePeriod",
+        "enableContentionProfiling",
+        "enableControllerAttachDetach",
+        "enableDebugFlagsHandler",
+        "enableDebuggingHandlers",
+        "enableProfilingHandler",
+        "enableServer",
+        "enableSystemLogHandler",
+        "enforceNodeAllocatable",
+        "eventBurst",
+        "eventRecordQPS",
+        "evictionHard",
+        "evictionMaxPodGracePeriod",
+        "evictionMinimumReclaim",
+        "evictionPressureTransitionPeriod",
+        "evictionSoft",
+        "evictionSoftGracePeriod",
+        "failSwapOn",
+        "featureGates",
+        "fileCheckFrequency",
+        "hairpinMode",
+        "healthzBindAddress",
+        "healthzPort",
+        "httpCheckFrequency",
+        "imageGCHighThresholdPercent",
+        "imageGCLowThresholdPercent",
+        "imageMinimumGCAge",
+        "iptablesDropBit",
+        "iptablesMasqueradeBit",
+        "kernelMemcgNotification",
+        "kubeAPIBurst",
+        "kubeAPIQPS",
+        "kubeReserved",
+        "kubeReservedCgroup",
+        "kubeletCgroups",
+        "logging",
+        "makeIPTablesUtilChains",
+        "maxOpenFiles",
+        "maxPods",
+        "memoryManagerPolicy",
+        "memorySwap",
+        "memoryThrottlingFactor",
+        "nodeLeaseDurationSeconds",
+        "nodeStatusMaxImages",
+        "nodeStatusReportFrequency",
+        "nodeStatusUpdateFrequency",
+        "oomScoreAdj",
+        "podCIDR",
+        "podPidsLimit",
+        "podsPerCore",
+        "port",
+        "protectKernelDefaults",
+        "providerID",
+        "qosReserved",
+        "readOnlyPort",
+        "registryBurst",
+        "registryPullQPS",
+        "reservedMemory",
+        "reservedSystemCPUs",
+        "resolvConf",
+        "rotateCertificates",
+        "runOnce",
+        "runtimeRequestTimeout",
+        "seccompDefault",
+        "serializeImagePulls",
+        "serverTLSBootstrap",
+        "showHiddenMetricsForVersion",
+        "shutdownGracePeriod",
+        "shutdownGracePeriodCriticalPods",
+        "staticPodPath",
+        "staticPodURL",
+        "staticPodURLHeader",
+        "streamingConnectionIdleTimeout",
+        "syncFrequency",
+        "systemCgroups",
+        "systemReserved",
+        "systemReservedCgroup",
+        "tlsCertFile",
+        "tlsCipherSuites",
+        "tlsMinVersion",
+        "tlsPrivateKeyFile",
+        "topologyManagerPolicy",
+        "topologyManagerScope",
+        "volumePluginDir",
+        "volumeStatsAggPeriod",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for KubeletConfiguration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = KubeletConfiguration;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<Self::Value as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_address: Option<String> = None;
+                let mut value_allowed_unsafe_sysctls: Option<Vec<String>> = None;
+                let mut value_authentication: Option<crate::kubelet::config::v1beta1::KubeletAuthentication> = None;
+                let mut value_authorization: Option<crate::kubelet::config::v1beta1::KubeletAuthorization> = None;
+                let mut value_cgroup_driver: Option<String> = None;
+                let mut value_cgroup_root: Option<String> = None;
+                let mut value_cgroups_per_qos: Option<bool> = None;
+                let mut value_cluster_dns: Option<Vec<String>> = None;
+                let mut value_cluster_domain: Option<String> = None;
+                let mut value_config_map_and_secret_change_detection_strategy: Option<String> = None;
+                let mut value_container_log_max_files: Option<i32> = None;
+                let mut value_container_log_max_size: Option<String> = None;
+                let mut value_content_type: Option<String> = None;
+                let mut value_cpu_cfs_quota: Option<bool> = None;
+                let mut value_cpu_cfs_quota_period: Option<String> = None;
+                let mut value_cpu_manager_policy: Option<String> = None;
+                let mut value_cpu_manager_policy_options: Option<std::collections::BTreeMap<String, String>> = None;
+                let mut value_cpu_manager_reconcile_period: Option<String> = None;
+                let mut value_enable_contention_profiling: Option<bool> = None;
+                let mut value_enable_controller_attach_detach: Option<bool> = None;
+                let mut value_enable_debug_flags_handler: Option<bool> = None;
+                let mut value_enable_debugging_handlers: Option<bool> = None;
+                let mut value_enable_profiling_handler: Option<bool> = None;
+                let mut value_enable_server: Option<bool> = None;
+                let mut value_enable_system_log_handler: Option<bool> = None;
+                let mut value_enforce_node_allocatable: Option<Vec<String>> = None;
+                let mut value_event_burst: Option<i32> = None;
+                let mut value_event_record_qps: Option<i32> = None;
+                let mut value_eviction_hard: Option<std::collections::BTreeMap<String, String>> = None;
+                let mut value_eviction_max_pod_grace_period: Option<i32> = None;
+                let mut value_eviction_minimum_reclaim: Option<std::collections::BTreeMap<String, String>> = None;
+                let mut value_eviction_pressure_transition_period: Option<String> = None;
+                let mut value_eviction_soft: Option<std::collections::BTreeMap<String, String>> = None;
+                let mut value_eviction_soft_grace_period: Option<std::collections::BTreeMap<String, String>> = None;
+                let mut value_fail_swap_on: Option<bool> = None;
+                let mut value_feature_gates: Option<std::collections::BTreeMap<String, bool>> = None;
+                let mut value_file_check_frequency: Option<String> = None;
+                let mut value_hairpin_mode: Option<String> = None;
+                let mut value_healthz_bind_address: Option<String> = None;
+                let mut value_healthz_port: Option<i32> = None;
+                let mut value_http_check_frequency: Option<String> = None;
+                let mut value_image_gc_high_threshold_percent: Option<i32> = None;
+                let mut value_image_gc_low_threshold_percent: Option<i32> = None;
+                let mut value_image_minimum_gc_age: Option<String> = None;
+                let mut value_iptables_drop_bit: Option<i32> = None;
+                let mut value_iptables_masquerade_bit: Option<i32> = None;
+                let mut value_kernel_memcg_notification: Option<bool> = None;
+                let mut value_kube_api_burst: Option<i32> = None;
+                let mut value_kube_api_qps: Option<i32> = None;
+                let mut value_kube_reserved: Option<std::collections::BTreeMap<String, String>> = None;
+                let mut value_kube_reserved_cgroup: Option<String> = None;
+                let mut value_kubelet_cgroups: Option<String> = None;
+                let mut value_logging: Option<crate::kubelet::config::v1beta1::LoggingConfiguration> = None;
+                let mut value_make_iptables_util_chains: Option<bool> = None;
+                let mut value_max_open_files: Option<i64> = None;
+                let mut value_max_pods: Option<i32> = None;
+                let mut value_memory_manager_policy: Option<String> = None;
+                let mut value_memory_swap: Option<crate::kubelet::config::v1beta1::MemorySwapConfiguration> = None;
+                let mut value_memory_throttling_factor: Option<f64> = None;
+                let mut value_node_lease_duration_seconds: Option<i32> = None;
+                let mut value_node_status_max_images: Option<i32> = None;
+                let mut value_node_status_report_frequency: Option<String> = None;
+                let mut value_node_status_update_frequency: Option<String> = None;
+                let mut value_oom_score_adj: Option<i32> = None;
+                let mut value_pod_cidr: Option<String> = None;
+                let mut value_pod_pids_limit: Option<i64> = None;
+                let mut value_pods_per_core: Option<i32> = None;
+                let mut value_port: Option<i32> = None;
+                let mut value_protect_kernel_defaults: Option<bool> = None;
+                let mut value_provider_id: Option<String> = None;
+                let mut value_qos_reserved: Option<std::collections::BTreeMap<String, String>> = None;
+                let mut value_read_only_port: Option<i32> = None;
+                let mut value_registry_burst: Option<i32> = None;
+                let mut value_registry_pull_qps: Option<i32> = None;
+                let mut value_reserved_memory: Option<Vec<crate::kubelet::config::v1beta1::MemoryReservation>> = None;
+                let mut value_reserved_system_cpus: Option<String> = None;
+                let mut value_resolv_conf: Option<String> = None;
+                let mut value_rotate_certificates: Option<bool> = None;
+                let mut value_run_once: Option<bool> = None;
+                let mut value_runtime_request_timeout: Option<String> = None;
+                let mut value_seccomp_default: Option<bool> = None;
+                let mut value_serialize_image_pulls: Option<bool> = None;
+                let mut value_server_tls_bootstrap: Option<bool> = None;
+                let mut value_show_hidden_metrics_for_version: Option<String> = None;
+                let mut value_shutdown_grace_period: Option<String> = None;
+                let mut value_shutdown_grace_period_critical_pods: Option<String> = None;
+                let mut value_static_pod_path: Option<String> = None;
+                let mut value_static_pod_url: Option<String> = None;
+                let mut value_static_pod_url_header: Option<std::collections::BTreeMap<String, Vec<String>>> = None;
+                let mut value_streaming_connection_idle_timeout: Option<String> = None;
+                let mut value_sync_frequency: Option<String> = None;
+                let mut value_system_cgroups: Option<String> = None;
+                let mut value_system_reserved: Option<std::collections::BTreeMap<String, String>> = None;
+                let mut value_system_reserved_cgroup: Option<String> = None;
+                let mut value_tls_cert_file: Option<String> = None;
+                let mut value_tls_cipher_suites: Option<Vec<String>> = None;
+                let mut value_tls_min_version: Option<String> = None;
+                let mut value_tls_private_key_file: Option<String> = None;
+                let mut value_topology_manager_policy: Option<String> = None;
+                let mut value_topology_manager_scope: Option<String> = None;
+                let mut value_volume_plugin_dir: Option<String> = None;
+                let mut value_volume_stats_agg_period: Option<String> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("KubeletConfiguration", KubeletConfiguration::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <Self::Value as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<Self::Value as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <Self::Value as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<Self::Value as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => value_address = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_allowed_unsafe_sysctls = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_authentication = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_authorization = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_cgroup_driver = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(7) => value_cgroup_root = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(8) => value_cgroups_per_qos = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(9) => value_cluster_dns = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(10) => value_cluster_domain = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(11) => value_config_map_and_secret_change_detection_strategy = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(12) => value_container_log_max_files = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(13) => value_container_log_max_size = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(14) => value_content_type = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(15) => value_cpu_cfs_quota = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(16) => value_cpu_cfs_quota_period = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(17) => value_cpu_manager_policy = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(18) => value_cpu_manager_policy_options = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(19) => value_cpu_manager_reconcile_period = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(20) => value_enable_contention_profiling = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(21) => value_enable_controller_attach_detach = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(22) => value_enable_debug_flags_handler = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(23) => value_enable_debugging_handlers = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(24) => value_enable_profiling_handler = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(25) => value_enable_server = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(26) => value_enable_system_log_handler = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(27) => value_enforce_node_allocatable = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(28) => value_event_burst = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(29) => value_event_record_qps = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(30) => value_eviction_hard = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(31) => value_eviction_max_pod_grace_period = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(32) => value_eviction_minimum_reclaim = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(33) => value_eviction_pressure_transition_period = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(34) => value_eviction_soft = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(35) => value_eviction_soft_grace_period = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(36) => value_fail_swap_on = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(37) => value_feature_gates = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(38) => value_file_check_frequency = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(39) => value_hairpin_mode = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(40) => value_healthz_bind_address = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(41) => value_healthz_port = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(42) => value_http_check_frequency = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(43) => value_image_gc_high_threshold_percent = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(44) => value_image_gc_low_threshold_percent = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(45) => value_image_minimum_gc_age = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(46) => value_iptables_drop_bit = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(47) => value_iptables_masquerade_bit = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(48) => value_kernel_memcg_notification = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(49) => value_kube_api_burst = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(50) => value_kube_api_qps = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(51) => value_kube_reserved = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(52) => value_kube_reserved_cgroup = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(53) => value_kubelet_cgroups = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(54) => value_logging = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(55) => value_make_iptables_util_chains = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(56) => value_max_open_files = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(57) => value_max_pods = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(58) => value_memory_manager_policy = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(59) => value_memory_swap = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(60) => value_memory_throttling_factor = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(61) => value_node_lease_duration_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(62) => value_node_status_max_images = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(63) => value_node_status_report_frequency = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(64) => value_node_status_update_frequency = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(65) => value_oom_score_adj = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(66) => value_pod_cidr = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(67) => value_pod_pids_limit = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(68) => value_pods_per_core = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(69) => value_port = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(70) => value_protect_kernel_defaults = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(71) => value_provider_id = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(72) => value_qos_reserved = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(73) => value_read_only_port = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(74) => value_registry_burst = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(75) => value_registry_pull_qps = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(76) => value_reserved_memory = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(77) => value_reserved_system_cpus = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(78) => value_resolv_conf = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(79) => value_rotate_certificates = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(80) => value_run_once = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(81) => value_runtime_request_timeout = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(82) => value_seccomp_default = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(83) => value_serialize_image_pulls = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(84) => value_server_tls_bootstrap = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(85) => value_show_hidden_metrics_for_version = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(86) => value_shutdown_grace_period = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(87) => value_shutdown_grace_period_critical_pods = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(88) => value_static_pod_path = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(89) => value_static_pod_url = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(90) => value_static_pod_url_header = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(91) => value_streaming_connection_idle_timeout = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(92) => value_sync_frequency = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(93) => value_system_cgroups = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(94) => value_system_reserved = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(95) => value_system_reserved_cgroup = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(96) => value_tls_cert_file = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(97) => value_tls_cipher_suites = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(98) => value_tls_min_version = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(99) => value_tls_private_key_file = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(100) => value_topology_manager_policy = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(101) => value_topology_manager_scope = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(102) => value_volume_plugin_dir = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(103) => value_volume_stats_agg_period = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(KubeletConfiguration {
+                    address: value_address,
+                    allowed_unsafe_sysctls: value_allowed_unsafe_sysctls,
+                    authentication: value_authentication,
+                    authorization: value_authorization,
+                    cgroup_driver: value_cgroup_driver,
+                    cgroup_root: value_cgroup_root,
+                    cgroups_per_qos: value_cgroups_per_qos,
+                    cluster_dns: value_cluster_dns,
+                    cluster_domain: value_cluster_domain,
+                    config_map_and_secret_change_detection_strategy: value_config_map_and_secret_change_detection_strategy,
+                    container_log_max_files: value_container_log_max_files,
+                    container_log_max_size: value_container_log_max_size,
+                    content_type: value_content_type,
+                    cpu_cfs_quota: value_cpu_cfs_quota,
+                    cpu_cfs_quota_period: value_cpu_cfs_quota_period,
+                    cpu_manager_policy: value_cpu_manager_policy,
+                    cpu_manager_policy_options: value_cpu_manager_policy_options,
+                    cpu_manager_reconcile_period: value_cpu_manager_reconcile_period,
+                    enable_contention_profiling: value_enable_contention_profiling,
+                    enable_controller_attach_detach: value_enable_controller_attach_detach,
+                    enable_debug_flags_handler: value_enable_debug_flags_handler,
+                    enable_debugging_handlers: value_enable_debugging_handlers,
+                    enable_profiling_handler: value_enable_profiling_handler,
+                    enable_server: value_enable_server,
+                    enable_system_log_handler: value_enable_system_log_handler,
+                    enforce_node_allocatable: value_enforce_node_allocatable,
+                    event_burst: value_event_burst,
+                    event_record_qps: value_event_record_qps,
+                    eviction_hard: value_eviction_hard,
+                    eviction_max_pod_grace_period: value_eviction_max_pod_grace_period,
+                    eviction_minimum_reclaim: value_eviction_minimum_reclaim,
+                    eviction_pressure_transition_period: value_eviction_pressure_transition_period,
+                    eviction_soft: value_eviction_soft,
+                    eviction_soft_grace_period: value_eviction_soft_grace_period,
+                    fail_swap_on: value_fail_swap_on,
+                    feature_gates: value_feature_gates,
+                    file_check_frequency: value_file_check_frequency,
+                    hairpin_mode: value_hairpin_mode,
+                    healthz_bind_address: value_healthz_bind_address,
+                    healthz_port: value_healthz_port,
+                    http_check_frequency: value_http_check_frequency,
+                    image_gc_high_threshold_percent: value_image_gc_high_threshold_percent,
+                    image_gc_low_threshold_percent: value_image_gc_low_threshold_percent,
+                    image_minimum_gc_age: value_image_minimum_gc_age,
+                    iptables_drop_bit: value_iptables_drop_bit,
+                    iptables_masquerade_bit: value_iptables_masquerade_bit,
+                    kernel_memcg_notification: value_kernel_memcg_notification,
+                    kube_api_burst: value_kube_api_burst,
+                    kube_api_qps: value_kube_api_qps,
+                    kube_reserved: value_kube_reserved,
+                    kube_reserved_cgroup: value_kube_reserved_cgroup,
+                    kubelet_cgroups: value_kubelet_cgroups,
+                    logging: value_logging,
+                    make_iptables_util_chains: value_make_iptables_util_chains,
+                    max_open_files: value_max_open_files,
+                    max_pods: value_max_pods,
+                    memory_manager_policy: value_memory_manager_policy,
+                    memory_swap: value_memory_swap,
+                    memory_throttling_factor: value_memory_throttling_factor,
+                    node_lease_duration_seconds: value_node_lease_duration_seconds,
+                    node_status_max_images: value_node_status_max_images,
+                    node_status_report_frequency: value_node_status_report_frequency,
+                    node_status_update_frequency: value_node_status_update_frequency,
+                    oom_score_adj: value_oom_score_adj,
+                    pod_cidr: value_pod_cidr,
+                    pod_pids_limit: value_pod_pids_limit,
+                    pods_per_core: value_pods_per_core,
+                    port: value_port,
+                    protect_kernel_defaults: value_protect_kernel_defaults,
+                    provider_id: value_provider_id,
+                    qos_reserved: value_qos_reserved,
+                    read_only_port: value_read_only_port,
+                    registry_burst: value_registry_burst,
+                    registry_pull_qps: value_registry_pull_qps,
+                    reserved_memory: value_reserved_memory,
+                    reserved_system_cpus: value_reserved_system_cpus,
+                    resolv_conf: value_resolv_conf,
+                    rotate_certificates: value_rotate_certificates,
+                    run_once: value_run_once,
+                    runtime_request_timeout: value_runtime_request_timeout,
+                    seccomp_default: value_seccomp_default,
+                    serialize_image_pulls: value_serialize_image_pulls,
+                    server_tls_bootstrap: value_server_tls_bootstrap,
+                    show_hidden_metrics_for_version: value_show_hidden_metrics_for_version,
+                    shutdown_grace_period: value_shutdown_grace_period,
+                    shutdown_grace_period_critical_pods: value_shutdown_grace_period_critical_pods,
+                    static_pod_path: value_static_pod_path,
+                    static_pod_url: value_static_pod_url,
+                    static_pod_url_header: value_static_pod_url_header,
+                    streaming_connection_idle_timeout: value_streaming_connection_idle_timeout,
+                    sync_frequency: value_sync_frequency,
+                    system_cgroups: value_system_cgroups,
+                    system_reserved: value_system_reserved,
+                    system_reserved_cgroup: value_system_reserved_cgroup,
+                    tls_cert_file: value_tls_cert_file,
+                    tls_cipher_suites: value_tls_cipher_suites,
+                    tls_min_version: value_tls_min_version,
+                    tls_private_key_file: value_tls_private_key_file,
+                    topology_manager_policy: value_topology_manager_policy,
+                    topology_manager_scope: value_topology_manager_scope,
+                    volume_plugin_dir: value_volume_plugin_dir,
+                    volume_stats_agg_period: value_volume_stats_agg_period,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(KubeletConfiguration {
+                    address: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    allowed_unsafe_sysctls: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    authentication: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    authorization: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    cgroup_driver: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    cgroup_root: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    cgroups_per_qos: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    cluster_dns: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                    cluster_domain: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?,
+                    config_map_and_secret_change_detection_strategy: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?,
+                    container_log_max_files: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?,
+                    container_log_max_size: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(11, &self))?,
+                    content_type: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(12, &self))?,
+                    cpu_cfs_quota: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(13, &self))?,
+                    cpu_cfs_quota_period: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(14, &self))?,
+                    cpu_manager_policy: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(15, &self))?,
+                    cpu_manager_policy_options: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(16, &self))?,
+                    cpu_manager_reconcile_period: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(17, &self))?,
+                    enable_contention_profiling: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(18, &self))?,
+                    enable_controller_attach_detach: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(19, &self))?,
+                    enable_debug_flags_handler: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(20, &self))?,
+                    enable_debugging_handlers: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(21, &self))?,
+                    enable_profiling_handler: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(22, &self))?,
+                    enable_server: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(23, &self))?,
+                    enable_system_log_handler: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(24, &self))?,
+                    enforce_node_allocatable: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(25, &self))?,
+                    event_burst: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(26, &self))?,
+                    event_record_qps: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(27, &self))?,
+                    eviction_hard: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(28, &self))?,
+                    eviction_max_pod_grace_period: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(29, &self))?,
+                    eviction_minimum_reclaim: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(30, &self))?,
+                    eviction_pressure_transition_period: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(31, &self))?,
+                    eviction_soft: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(32, &self))?,
+                    eviction_soft_grace_period: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(33, &self))?,
+                    fail_swap_on: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(34, &self))?,
+                    feature_gates: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(35, &self))?,
+                    file_check_frequency: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(36, &self))?,
+                    hairpin_mode: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(37, &self))?,
+                    healthz_bind_address: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(38, &self))?,
+                    healthz_port: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(39, &self))?,
+                    http_check_frequency: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(40, &self))?,
+                    image_gc_high_threshold_percent: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(41, &self))?,
+                    image_gc_low_threshold_percent: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(42, &self))?,
+                    image_minimum_gc_age: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(43, &self))?,
+                    iptables_drop_bit: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(44, &self))?,
+                    iptables_masquerade_bit: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(45, &self))?,
+                    kernel_memcg_notification: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(46, &self))?,
+                    kube_api_burst: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(47, &self))?,
+                    kube_api_qps: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(48, &self))?,
+                    kube_reserved: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(49, &self))?,
+                    kube_reserved_cgroup: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(50, &self))?,
+                    kubelet_cgroups: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(51, &self))?,
+                    logging: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(52, &self))?,
+                    make_iptables_util_chains: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(53, &self))?,
+                    max_open_files: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(54, &self))?,
+                    max_pods: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(55, &self))?,
+                    memory_manager_policy: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(56, &self))?,
+                    memory_swap: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(57, &self))?,
+                    memory_throttling_factor: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(58, &self))?,
+                    node_lease_duration_seconds: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(59, &self))?,
+                    node_status_max_images: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(60, &self))?,
+                    node_status_report_frequency: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(61, &self))?,
+                    node_status_update_frequency: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(62, &self))?,
+                    oom_score_adj: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(63, &self))?,
+                    pod_cidr: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(64, &self))?,
+                    pod_pids_limit: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(65, &self))?,
+                    pods_per_core: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(66, &self))?,
+                    port: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(67, &self))?,
+                    protect_kernel_defaults: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(68, &self))?,
+                    provider_id: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(69, &self))?,
+                    qos_reserved: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(70, &self))?,
+                    read_only_port: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(71, &self))?,
+                    registry_burst: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(72, &self))?,
+                    registry_pull_qps: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(73, &self))?,
+                    reserved_memory: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(74, &self))?,
+                    reserved_system_cpus: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(75, &self))?,
+                    resolv_conf: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(76, &self))?,
+                    rotate_certificates: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(77, &self))?,
+                    run_once: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(78, &self))?,
+                    runtime_request_timeout: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(79, &self))?,
+                    seccomp_default: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(80, &self))?,
+                    serialize_image_pulls: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(81, &self))?,
+                    server_tls_bootstrap: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(82, &self))?,
+                    show_hidden_metrics_for_version: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(83, &self))?,
+                    shutdown_grace_period: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(84, &self))?,
+                    shutdown_grace_period_critical_pods: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(85, &self))?,
+                    static_pod_path: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(86, &self))?,
+                    static_pod_url: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(87, &self))?,
+                    static_pod_url_header: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(88, &self))?,
+                    streaming_connection_idle_timeout: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(89, &self))?,
+                    sync_frequency: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(90, &self))?,
+                    system_cgroups: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(91, &self))?,
+                    system_reserved: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(92, &self))?,
+                    system_reserved_cgroup: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(93, &self))?,
+                    tls_cert_file: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(94, &self))?,
+                    tls_cipher_suites: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(95, &self))?,
+                    tls_min_version: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(96, &self))?,
+                    tls_private_key_file: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(97, &self))?,
+                    topology_manager_policy: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(98, &self))?,
+                    topology_manager_scope: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(99, &self))?,
+                    volume_plugin_dir: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(100, &self))?,
+                    volume_stats_agg_period: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(101, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            KubeletConfiguration::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, KubeletConfiguration> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<KubeletConfiguration as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_address = false;
+                let mut has_allowed_unsafe_sysctls = false;
+                let mut has_authentication = false;
+                let mut has_authorization = false;
+                let mut has_cgroup_driver = false;
+                let mut has_cgroup_root = false;
+                let mut has_cgroups_per_qos = false;
+                let mut has_cluster_dns = false;
+                let mut has_cluster_domain = false;
+                let mut has_config_map_and_secret_change_detection_strategy = false;
+                let mut has_container_log_max_files = false;
+                let mut has_container_log_max_size = false;
+                let mut has_content_type = false;
+                let mut has_cpu_cfs_quota = false;
+                let mut has_cpu_cfs_quota_period = false;
+                let mut has_cpu_manager_policy = false;
+                let mut has_cpu_manager_policy_options = false;
+                let mut has_cpu_manager_reconcile_period = false;
+                let mut has_enable_contention_profiling = false;
+                let mut has_enable_controller_attach_detach = false;
+                let mut has_enable_debug_flags_handler = false;
+                let mut has_enable_debugging_handlers = false;
+                let mut has_enable_profiling_handler = false;
+                let mut has_enable_server = false;
+                let mut has_enable_system_log_handler = false;
+                let mut has_enforce_node_allocatable = false;
+                let mut has_event_burst = false;
+                let mut has_event_record_qps = false;
+                let mut has_eviction_hard = false;
+                let mut has_eviction_max_pod_grace_period = false;
+                let mut has_eviction_minimum_reclaim = false;
+                let mut has_eviction_pressure_transition_period = false;
+                let mut has_eviction_soft = false;
+                let mut has_eviction_soft_grace_period = false;
+                let mut has_fail_swap_on = false;
+                let mut has_feature_gates = false;
+                let mut has_file_check_frequency = false;
+                let mut has_hairpin_mode = false;
+                let mut has_healthz_bind_address = false;
+                let mut has_healthz_port = false;
+                let mut has_http_check_frequency = false;
+                let mut has_image_gc_high_threshold_percent = false;
+                let mut has_image_gc_low_threshold_percent = false;
+                let mut has_image_minimum_gc_age = false;
+                let mut has_iptables_drop_bit = false;
+                let mut has_iptables_masquerade_bit = false;
+                let mut has_kernel_memcg_notification = false;
+                let mut has_kube_api_burst = false;
+                let mut has_kube_api_qps = false;
+                let mut has_kube_reserved = false;
+                let mut has_kube_reserved_cgroup = false;
+                let mut has_kubelet_cgroups = false;
+                let mut has_logging = false;
+                let mut has_make_iptables_util_chains = false;
+                let mut has_max_open_files = false;
+                let mut has_max_pods = false;
+                let mut has_memory_manager_policy = false;
+                let mut has_memory_swap = false;
+                let mut has_memory_throttling_factor = false;
+                let mut has_node_lease_duration_seconds = false;
+                let mut has_node_status_max_images = false;
+                let mut has_node_status_report_frequency = false;
+                let mut has_node_status_update_frequency = false;
+                let mut has_oom_score_adj = false;
+                let mut has_pod_cidr = false;
+                let mut has_pod_pids_limit = false;
+                let mut has_pods_per_core = false;
+                let mut has_port = false;
+                let mut has_protect_kernel_defaults = false;
+                let mut has_provider_id = false;
+                let mut has_qos_reserved = false;
+                let mut has_read_only_port = false;
+                let mut has_registry_burst = false;
+                let mut has_registry_pull_qps = false;
+                let mut has_reserved_memory = false;
+                let mut has_reserved_system_cpus = false;
+                let mut has_resolv_conf = false;
+                let mut has_rotate_certificates = false;
+                let mut has_run_once = false;
+                let mut has_runtime_request_timeout = false;
+                let mut has_seccomp_default = false;
+                let mut has_serialize_image_pulls = false;
+                let mut has_server_tls_bootstrap = false;
+                let mut has_show_hidden_metrics_for_version = false;
+                let mut has_shutdown_grace_period = false;
+                let mut has_shutdown_grace_period_critical_pods = false;
+                let mut has_static_pod_path = false;
+                let mut has_static_pod_url = false;
+                let mut has_static_pod_url_header = false;
+                let mut has_streaming_connection_idle_timeout = false;
+                let mut has_sync_frequency = false;
+                let mut has_system_cgroups = false;
+                let mut has_system_reserved = false;
+                let mut has_system_reserved_cgroup = false;
+                let mut has_tls_cert_file = false;
+                let mut has_tls_cipher_suites = false;
+                let mut has_tls_min_version = false;
+                let mut has_tls_private_key_file = false;
+                let mut has_topology_manager_policy = false;
+                let mut has_topology_manager_scope = false;
+                let mut has_volume_plugin_dir = false;
+                let mut has_volume_stats_agg_period = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("KubeletConfiguration", KubeletConfiguration::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <KubeletConfiguration as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<KubeletConfiguration as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <KubeletConfiguration as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<KubeletConfiguration as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.address))?; has_address = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.allowed_unsafe_sysctls))?; has_allowed_unsafe_sysctls = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.authentication))?; has_authentication = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.authorization))?; has_authorization = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.cgroup_driver))?; has_cgroup_driver = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.cgroup_root))?; has_cgroup_root = true; },
+                        Some(8) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.cgroups_per_qos))?; has_cgroups_per_qos = true; },
+                        Some(9) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.cluster_dns))?; has_cluster_dns = true; },
+                        Some(10) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.cluster_domain))?; has_cluster_domain = true; },
+                        Some(11) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.config_map_and_secret_change_detection_strategy))?; has_config_map_and_secret_change_detection_strategy = true; },
+                        Some(12) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.container_log_max_files))?; has_container_log_max_files = true; },
+                        Some(13) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.container_log_max_size))?; has_container_log_max_size = true; },
+                        Some(14) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.content_type))?; has_content_type = true; },
+                        Some(15) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.cpu_cfs_quota))?; has_cpu_cfs_quota = true; },
+                        Some(16) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.cpu_cfs_quota_period))?; has_cpu_cfs_quota_period = true; },
+                        Some(17) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.cpu_manager_policy))?; has_cpu_manager_policy = true; },
+                        Some(18) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.cpu_manager_policy_options))?; has_cpu_manager_policy_options = true; },
+                        Some(19) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.cpu_manager_reconcile_period))?; has_cpu_manager_reconcile_period = true; },
+                        Some(20) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.enable_contention_profiling))?; has_enable_contention_profiling = true; },
+                        Some(21) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.enable_controller_attach_detach))?; has_enable_controller_attach_detach = true; },
+                        Some(22) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.enable_debug_flags_handler))?; has_enable_debug_flags_handler = true; },
+                        Some(23) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.enable_debugging_handlers))?; has_enable_debugging_handlers = true; },
+                        Some(24) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.enable_profiling_handler))?; has_enable_profiling_handler = true; },
+                        Some(25) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.enable_server))?; has_enable_server = true; },
+                        Some(26) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.enable_system_log_handler))?; has_enable_system_log_handler = true; },
+                        Some(27) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.enforce_node_allocatable))?; has_enforce_node_allocatable = true; },
+                        Some(28) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.event_burst))?; has_event_burst = true; },
+                        Some(29) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.event_record_qps))?; has_event_record_qps = true; },
+                        Some(30) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.eviction_hard))?; has_eviction_hard = true; },
+                        Some(31) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.eviction_max_pod_grace_period))?; has_eviction_max_pod_grace_period = true; },
+                        Some(32) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.eviction_minimum_reclaim))?; has_eviction_minimum_reclaim = true; },
+                        Some(33) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.eviction_pressure_transition_period))?; has_eviction_pressure_transition_period = true; },
+                        Some(34) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.eviction_soft))?; has_eviction_soft = true; },
+                        Some(35) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.eviction_soft_grace_period))?; has_eviction_soft_grace_period = true; },
+                        Some(36) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.fail_swap_on))?; has_fail_swap_on = true; },
+                        Some(37) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.feature_gates))?; has_feature_gates = true; },
+                        Some(38) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.file_check_frequency))?; has_file_check_frequency = true; },
+                        Some(39) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.hairpin_mode))?; has_hairpin_mode = true; },
+                        Some(40) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.healthz_bind_address))?; has_healthz_bind_address = true; },
+                        Some(41) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.healthz_port))?; has_healthz_port = true; },
+                        Some(42) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.http_check_frequency))?; has_http_check_frequency = true; },
+                        Some(43) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.image_gc_high_threshold_percent))?; has_image_gc_high_threshold_percent = true; },
+                        Some(44) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.image_gc_low_threshold_percent))?; has_image_gc_low_threshold_percent = true; },
+                        Some(45) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.image_minimum_gc_age))?; has_image_minimum_gc_age = true; },
+                        Some(46) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.iptables_drop_bit))?; has_iptables_drop_bit = true; },
+                        Some(47) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.iptables_masquerade_bit))?; has_iptables_masquerade_bit = true; },
+                        Some(48) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.kernel_memcg_notification))?; has_kernel_memcg_notification = true; },
+                        Some(49) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.kube_api_burst))?; has_kube_api_burst = true; },
+                        Some(50) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.kube_api_qps))?; has_kube_api_qps = true; },
+                        Some(51) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.kube_reserved))?; has_kube_reserved = true; },
+                        Some(52) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.kube_reserved_cgroup))?; has_kube_reserved_cgroup = true; },
+                        Some(53) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.kubelet_cgroups))?; has_kubelet_cgroups = true; },
+                        Some(54) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.logging))?; has_logging = true; },
+                        Some(55) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.make_iptables_util_chains))?; has_make_iptables_util_chains = true; },
+                        Some(56) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.max_open_files))?; has_max_open_files = true; },
+                        Some(57) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.max_pods))?; has_max_pods = true; },
+                        Some(58) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.memory_manager_policy))?; has_memory_manager_policy = true; },
+                        Some(59) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.memory_swap))?; has_memory_swap = true; },
+                        Some(60) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.memory_throttling_factor))?; has_memory_throttling_factor = true; },
+                        Some(61) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.node_lease_duration_seconds))?; has_node_lease_duration_seconds = true; },
+                        Some(62) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.node_status_max_images))?; has_node_status_max_images = true; },
+                        Some(63) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.node_status_report_frequency))?; has_node_status_report_frequency = true; },
+                        Some(64) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.node_status_update_frequency))?; has_node_status_update_frequency = true; },
+                        Some(65) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.oom_score_adj))?; has_oom_score_adj = true; },
+                        Some(66) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.pod_cidr))?; has_pod_cidr = true; },
+                        Some(67) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.pod_pids_limit))?; has_pod_pids_limit = true; },
+                        Some(68) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.pods_per_core))?; has_pods_per_core = true; },
+                        Some(69) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.port))?; has_port = true; },
+                        Some(70) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.protect_kernel_defaults))?; has_protect_kernel_defaults = true; },
+                        Some(71) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.provider_id))?; has_provider_id = true; },
+                        Some(72) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.qos_reserved))?; has_qos_reserved = true; },
+                        Some(73) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.read_only_port))?; has_read_only_port = true; },
+                        Some(74) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.registry_burst))?; has_registry_burst = true; },
+                        Some(75) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.registry_pull_qps))?; has_registry_pull_qps = true; },
+                        Some(76) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.reserved_memory))?; has_reserved_memory = true; },
+                        Some(77) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.reserved_system_cpus))?; has_reserved_system_cpus = true; },
+                        Some(78) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.resolv_conf))?; has_resolv_conf = true; },
+                        Some(79) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.rotate_certificates))?; has_rotate_certificates = true; },
+                        Some(80) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.run_once))?; has_run_once = true; },
+                        Some(81) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.runtime_request_timeout))?; has_runtime_request_timeout = true; },
+                        Some(82) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.seccomp_default))?; has_seccomp_default = true; },
+                        Some(83) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.serialize_image_pulls))?; has_serialize_image_pulls = true; },
+                        Some(84) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.server_tls_bootstrap))?; has_server_tls_bootstrap = true; },
+                        Some(85) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.show_hidden_metrics_for_version))?; has_show_hidden_metrics_for_version = true; },
+                        Some(86) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.shutdown_grace_period))?; has_shutdown_grace_period = true; },
+                        Some(87) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.shutdown_grace_period_critical_pods))?; has_shutdown_grace_period_critical_pods = true; },
+                        Some(88) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.static_pod_path))?; has_static_pod_path = true; },
+                        Some(89) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.static_pod_url))?; has_static_pod_url = true; },
+                        Some(90) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.static_pod_url_header))?; has_static_pod_url_header = true; },
+                        Some(91) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.streaming_connection_idle_timeout))?; has_streaming_connection_idle_timeout = true; },
+                        Some(92) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.sync_frequency))?; has_sync_frequency = true; },
+                        Some(93) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.system_cgroups))?; has_system_cgroups = true; },
+                        Some(94) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.system_reserved))?; has_system_reserved = true; },
+                        Some(95) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.system_reserved_cgroup))?; has_system_reserved_cgroup = true; },
+                        Some(96) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.tls_cert_file))?; has_tls_cert_file = true; },
+                        Some(97) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.tls_cipher_suites))?; has_tls_cipher_suites = true; },
+                        Some(98) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.tls_min_version))?; has_tls_min_version = true; },
+                        Some(99) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.tls_private_key_file))?; has_tls_private_key_file = true; },
+                        Some(100) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.topology_manager_policy))?; has_topology_manager_policy = true; },
+                        Some(101) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.topology_manager_scope))?; has_topology_manager_scope = true; },
+                        Some(102) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.volume_plugin_dir))?; has_volume_plugin_dir = true; },
+                        Some(103) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.volume_stats_agg_period))?; has_volume_stats_agg_period = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_address {
+                    self.0.address = None;
+                }
+                if !has_allowed_unsafe_sysctls {
+                    self.0.allowed_unsafe_sysctls = None;
+                }
+                if !has_authentication {
+                    self.0.authentication = None;
+                }
+                if !has_authorization {
+                    self.0.authorization = None;
+                }
+                if !has_cgroup_driver {
+                    self.0.cgroup_driver = None;
+                }
+                if !has_cgroup_root {
+                    self.0.cgroup_root = None;
+                }
+                if !has_cgroups_per_qos {
+                    self.0.cgroups_per_qos = None;
+                }
+                if !has_cluster_dns {
+                    self.0.cluster_dns = None;
+                }
+                if !has_cluster_domain {
+                    self.0.cluster_domain = None;
+                }
+                if !has_config_map_and_secret_change_detection_strategy {
+                    self.0.config_map_and_secret_change_detection_strategy = None;
+                }
+                if !has_container_log_max_files {
+                    self.0.container_log_max_files = None;
+                }
+                if !has_container_log_max_size {
+                    self.0.container_log_max_size = None;
+                }
+                if !has_content_type {
+                    self.0.content_type = None;
+                }
+                if !has_cpu_cfs_quota {
+                    self.0.cpu_cfs_quota = None;
+                }
+                if !has_cpu_cfs_quota_period {
+                    self.0.cpu_cfs_quota_period = None;
+                }
+                if !has_cpu_manager_policy {
+                    self.0.cpu_manager_policy = None;
+                }
+                if !has_cpu_manager_policy_options {
+                    self.0.cpu_manager_policy_options = None;
+                }
+                if !has_cpu_manager_reconcile_period {
+                    self.0.cpu_manager_reconcile_period = None;
+                }
+                if !has_enable_contention_profiling {
+                    self.0.enable_contention_profiling = None;
+                }
+                if !has_enable_controller_attach_detach {
+                    self.0.enable_controller_attach_detach = None;
+                }
+                if !has_enable_debug_flags_handler {
+                    self.0.enable_debug_flags_handler = None;
+                }
+                if !has_enable_debugging_handlers {
+                    self.0.enable_debugging_handlers = None;
+                }
+                if !has_enable_profiling_handler {
+                    self.0.enable_profiling_handler = None;
+                }
+                if !has_enable_server {
+                    self.0.enable_server = None;
+                }
+                if !has_enable_system_log_handler {
+                    self.0.enable_system_log_handler = None;
+                }
+                if !has_enforce_node_allocatable {
+                    self.0.enforce_node_allocatable = None;
+                }
+                if !has_event_burst {
+                    self.0.event_burst = None;
+                }
+                if !has_event_record_qps {
+                    self.0.event_record_qps = None;
+                }
+                if !has_eviction_hard {
+                    self.0.eviction_hard = None;
+                }
+                if !has_eviction_max_pod_grace_period {
+                    self.0.eviction_max_pod_grace_period = None;
+                }
+                if !has_eviction_minimum_reclaim {
+                    self.0.eviction_minimum_reclaim = None;
+                }
+                if !has_eviction_pressure_transition_period {
+                    self.0.eviction_pressure_transition_period = None;
+                }
+                if !has_eviction_soft {
+                    self.0.eviction_soft = None;
+                }
+                if !has_eviction_soft_grace_period {
+                    self.0.eviction_soft_grace_period = None;
+                }
+                if !has_fail_swap_on {
+                    self.0.fail_swap_on = None;
+                }
+                if !has_feature_gates {
+                    self.0.feature_gates = None;
+                }
+                if !has_file_check_frequency {
+                    self.0.file_check_frequency = None;
+                }
+                if !has_hairpin_mode {
+                    self.0.hairpin_mode = None;
+                }
+                if !has_healthz_bind_address {
+                    self.0.healthz_bind_address = None;
+                }
+                if !has_healthz_port {
+                    self.0.healthz_port = None;
+                }
+                if !has_http_check_frequency {
+                    self.0.http_check_frequency = None;
+                }
+                if !has_image_gc_high_threshold_percent {
+                    self.0.image_gc_high_threshold_percent = None;
+                }
+                if !has_image_gc_low_threshold_percent {
+                    self.0.image_gc_low_threshold_percent = None;
+                }
+                if !has_image_minimum_gc_age {
+                    self.0.image_minimum_gc_age = None;
+                }
+                if !has_iptables_drop_bit {
+                    self.0.iptables_drop_bit = None;
+                }
+                if !has_iptables_masquerade_bit {
+                    self.0.iptables_masquerade_bit = None;
+                }
+                if !has_kernel_memcg_notification {
+                    self.0.kernel_memcg_notification = None;
+                }
+                if !has_kube_api_burst {
+                    self.0.kube_api_burst = None;
+                }
+                if !has_kube_api_qps {
+                    self.0.kube_api_qps = None;
+                }
+                if !has_kube_reserved {
+                    self.0.kube_reserved = None;
+                }
+                if !has_kube_reserved_cgroup {
+                    self.0.kube_reserved_cgroup = None;
+                }
+                if !has_kubelet_cgroups {
+                    self.0.kubelet_cgroups = None;
+                }
+                if !has_logging {
+                    self.0.logging = None;
+                }
+                if !has_make_iptables_util_chains {
+                    self.0.make_iptables_util_chains = None;
+                }
+                if !has_max_open_files {
+                    self.0.max_open_files = None;
+                }
+                if !has_max_pods {
+                    self.0.max_pods = None;
+                }
+                if !has_memory_manager_policy {
+                    self.0.memory_manager_policy = None;
+                }
+                if !has_memory_swap {
+                    self.0.memory_swap = None;
+                }
+                if !has_memory_throttling_factor {
+                    self.0.memory_throttling_factor = None;
+                }
+                if !has_node_lease_duration_seconds {
+                    self.0.node_lease_duration_seconds = None;
+                }
+                if !has_node_status_max_images {
+                    self.0.node_status_max_images = None;
+                }
+                if !has_node_status_report_frequency {
+                    self.0.node_status_report_frequency = None;
+                }
+                if !has_node_status_update_frequency {
+                    self.0.node_status_update_frequency = None;
+                }
+                if !has_oom_score_adj {
+                    self.0.oom_score_adj = None;
+                }
+                if !has_pod_cidr {
+                    self.0.pod_cidr = None;
+                }
+                if !has_pod_pids_limit {
+                    self.0.pod_pids_limit = None;
+                }
+                if !has_pods_per_core {
+                    self.0.pods_per_core = None;
+                }
+                if !has_port {
+                    self.0.port = None;
+                }
+                if !has_protect_kernel_defaults {
+                    self.0.protect_kernel_defaults = None;
+                }
+                if !has_provider_id {
+                    self.0.provider_id = None;
+                }
+                if !has_qos_reserved {
+                    self.0.qos_reserved = None;
+                }
+                if !has_read_only_port {
+                    self.0.read_only_port = None;
+                }
+                if !has_registry_burst {
+                    self.0.registry_burst = None;
+                }
+                if !has_registry_pull_qps {
+                    self.0.registry_pull_qps = None;
+                }
+                if !has_reserved_memory {
+                    self.0.reserved_memory = None;
+                }
+                if !has_reserved_system_cpus {
+                    self.0.reserved_system_cpus = None;
+                }
+                if !has_resolv_conf {
+                    self.0.resolv_conf = None;
+                }
+                if !has_rotate_certificates {
+                    self.0.rotate_certificates = None;
+                }
+                if !has_run_once {
+                    self.0.run_once = None;
+                }
+                if !has_runtime_request_timeout {
+                    self.0.runtime_request_timeout = None;
+                }
+                if !has_seccomp_default {
+                    self.0.seccomp_default = None;
+                }
+                if !has_serialize_image_pulls {
+                    self.0.serialize_image_pulls = None;
+                }
+                if !has_server_tls_bootstrap {
+                    self.0.server_tls_bootstrap = None;
+                }
+                if !has_show_hidden_metrics_for_version {
+                    self.0.show_hidden_metrics_for_version = None;
+                }
+                if !has_shutdown_grace_period {
+                    self.0.shutdown_grace_period = None;
+                }
+                if !has_shutdown_grace_period_critical_pods {
+                    self.0.shutdown_grace_period_critical_pods = None;
+                }
+                if !has_static_pod_path {
+                    self.0.static_pod_path = None;
+                }
+                if !has_static_pod_url {
+                    self.0.static_pod_url = None;
+                }
+                if !has_static_pod_url_header {
+                    self.0.static_pod_url_header = None;
+                }
+                if !has_streaming_connection_idle_timeout {
+                    self.0.streaming_connection_idle_timeout = None;
+                }
+                if !has_sync_frequency {
+                    self.0.sync_frequency = None;
+                }
+                if !has_system_cgroups {
+                    self.0.system_cgroups = None;
+                }
+                if !has_system_reserved {
+                    self.0.system_reserved = None;
+                }
+                if !has_system_reserved_cgroup {
+                    self.0.system_reserved_cgroup = None;
+                }
+                if !has_tls_cert_file {
+                    self.0.tls_cert_file = None;
+                }
+                if !has_tls_cipher_suites {
+                    self.0.tls_cipher_suites = None;
+                }
+                if !has_tls_min_version {
+                    self.0.tls_min_version = None;
+                }
+                if !has_tls_private_key_file {
+                    self.0.tls_private_key_file = None;
+                }
+                if !has_topology_manager_policy {
+                    self.0.topology_manager_policy = None;
+                }
+                if !has_topology_manager_scope {
+                    self.0.topology_manager_scope = None;
+                }
+                if !has_volume_plugin_dir {
+                    self.0.volume_plugin_dir = None;
+                }
+                if !has_volume_stats_agg_period {
+                    self.0.volume_stats_agg_period = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.address))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.allowed_unsafe_sysctls))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.authentication))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.authorization))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.cgroup_driver))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.cgroup_root))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.cgroups_per_qos))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.cluster_dns))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.cluster_domain))?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.config_map_and_secret_change_detection_strategy))?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.container_log_max_files))?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.container_log_max_size))?.ok_or_else(|| crate::serde::de::Error::invalid_length(11, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.content_type))?.ok_or_else(|| crate::serde::de::Error::invalid_length(12, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.cpu_cfs_quota))?.ok_or_else(|| crate::serde::de::Error::invalid_length(13, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.cpu_cfs_quota_period))?.ok_or_else(|| crate::serde::de::Error::invalid_length(14, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.cpu_manager_policy))?.ok_or_else(|| crate::serde::de::Error::invalid_length(15, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.cpu_manager_policy_options))?.ok_or_else(|| crate::serde::de::Error::invalid_length(16, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.cpu_manager_reconcile_period))?.ok_or_else(|| crate::serde::de::Error::invalid_length(17, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.enable_contention_profiling))?.ok_or_else(|| crate::serde::de::Error::invalid_length(18, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.enable_controller_attach_detach))?.ok_or_else(|| crate::serde::de::Error::invalid_length(19, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.enable_debug_flags_handler))?.ok_or_else(|| crate::serde::de::Error::invalid_length(20, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.enable_debugging_handlers))?.ok_or_else(|| crate::serde::de::Error::invalid_length(21, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.enable_profiling_handler))?.ok_or_else(|| crate::serde::de::Error::invalid_length(22, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.enable_server))?.ok_or_else(|| crate::serde::de::Error::invalid_length(23, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.enable_system_log_handler))?.ok_or_else(|| crate::serde::de::Error::invalid_length(24, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.enforce_node_allocatable))?.ok_or_else(|| crate::serde::de::Error::invalid_length(25, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.event_burst))?.ok_or_else(|| crate::serde::de::Error::invalid_length(26, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.event_record_qps))?.ok_or_else(|| crate::serde::de::Error::invalid_length(27, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.eviction_hard))?.ok_or_else(|| crate::serde::de::Error::invalid_length(28, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.eviction_max_pod_grace_period))?.ok_or_else(|| crate::serde::de::Error::invalid_length(29, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.eviction_minimum_reclaim))?.ok_or_else(|| crate::serde::de::Error::invalid_length(30, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.eviction_pressure_transition_period))?.ok_or_else(|| crate::serde::de::Error::invalid_length(31, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.eviction_soft))?.ok_or_else(|| crate::serde::de::Error::invalid_length(32, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.eviction_soft_grace_period))?.ok_or_else(|| crate::serde::de::Error::invalid_length(33, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.fail_swap_on))?.ok_or_else(|| crate::serde::de::Error::invalid_length(34, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.feature_gates))?.ok_or_else(|| crate::serde::de::Error::invalid_length(35, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.file_check_frequency))?.ok_or_else(|| crate::serde::de::Error::invalid_length(36, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.hairpin_mode))?.ok_or_else(|| crate::serde::de::Error::invalid_length(37, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.healthz_bind_address))?.ok_or_else(|| crate::serde::de::Error::invalid_length(38, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.healthz_port))?.ok_or_else(|| crate::serde::de::Error::invalid_length(39, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.http_check_frequency))?.ok_or_else(|| crate::serde::de::Error::invalid_length(40, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.image_gc_high_threshold_percent))?.ok_or_else(|| crate::serde::de::Error::invalid_length(41, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.image_gc_low_threshold_percent))?.ok_or_else(|| crate::serde::de::Error::invalid_length(42, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.image_minimum_gc_age))?.ok_or_else(|| crate::serde::de::Error::invalid_length(43, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.iptables_drop_bit))?.ok_or_else(|| crate::serde::de::Error::invalid_length(44, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.iptables_masquerade_bit))?.ok_or_else(|| crate::serde::de::Error::invalid_length(45, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.kernel_memcg_notification))?.ok_or_else(|| crate::serde::de::Error::invalid_length(46, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.kube_api_burst))?.ok_or_else(|| crate::serde::de::Error::invalid_length(47, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.kube_api_qps))?.ok_or_else(|| crate::serde::de::Error::invalid_length(48, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.kube_reserved))?.ok_or_else(|| crate::serde::de::Error::invalid_length(49, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.kube_reserved_cgroup))?.ok_or_else(|| crate::serde::de::Error::invalid_length(50, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.kubelet_cgroups))?.ok_or_else(|| crate::serde::de::Error::invalid_length(51, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.logging))?.ok_or_else(|| crate::serde::de::Error::invalid_length(52, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.make_iptables_util_chains))?.ok_or_else(|| crate::serde::de::Error::invalid_length(53, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.max_open_files))?.ok_or_else(|| crate::serde::de::Error::invalid_length(54, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.max_pods))?.ok_or_else(|| crate::serde::de::Error::invalid_length(55, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.memory_manager_policy))?.ok_or_else(|| crate::serde::de::Error::invalid_length(56, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.memory_swap))?.ok_or_else(|| crate::serde::de::Error::invalid_length(57, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.memory_throttling_factor))?.ok_or_else(|| crate::serde::de::Error::invalid_length(58, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.node_lease_duration_seconds))?.ok_or_else(|| crate::serde::de::Error::invalid_length(59, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.node_status_max_images))?.ok_or_else(|| crate::serde::de::Error::invalid_length(60, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.node_status_report_frequency))?.ok_or_else(|| crate::serde::de::Error::invalid_length(61, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.node_status_update_frequency))?.ok_or_else(|| crate::serde::de::Error::invalid_length(62, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.oom_score_adj))?.ok_or_else(|| crate::serde::de::Error::invalid_length(63, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.pod_cidr))?.ok_or_else(|| crate::serde::de::Error::invalid_length(64, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.pod_pids_limit))?.ok_or_else(|| crate::serde::de::Error::invalid_length(65, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.pods_per_core))?.ok_or_else(|| crate::serde::de::Error::invalid_length(66, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.port))?.ok_or_else(|| crate::serde::de::Error::invalid_length(67, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.protect_kernel_defaults))?.ok_or_else(|| crate::serde::de::Error::invalid_length(68, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.provider_id))?.ok_or_else(|| crate::serde::de::Error::invalid_length(69, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.qos_reserved))?.ok_or_else(|| crate::serde::de::Error::invalid_length(70, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.read_only_port))?.ok_or_else(|| crate::serde::de::Error::invalid_length(71, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.registry_burst))?.ok_or_else(|| crate::serde::de::Error::invalid_length(72, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.registry_pull_qps))?.ok_or_else(|| crate::serde::de::Error::invalid_length(73, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.reserved_memory))?.ok_or_else(|| crate::serde::de::Error::invalid_length(74, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.reserved_system_cpus))?.ok_or_else(|| crate::serde::de::Error::invalid_length(75, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.resolv_conf))?.ok_or_else(|| crate::serde::de::Error::invalid_length(76, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.rotate_certificates))?.ok_or_else(|| crate::serde::de::Error::invalid_length(77, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.run_once))?.ok_or_else(|| crate::serde::de::Error::invalid_length(78, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.runtime_request_timeout))?.ok_or_else(|| crate::serde::de::Error::invalid_length(79, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.seccomp_default))?.ok_or_else(|| crate::serde::de::Error::invalid_length(80, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.serialize_image_pulls))?.ok_or_else(|| crate::serde::de::Error::invalid_length(81, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.server_tls_bootstrap))?.ok_or_else(|| crate::serde::de::Error::invalid_length(82, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.show_hidden_metrics_for_version))?.ok_or_else(|| crate::serde::de::Error::invalid_length(83, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.shutdown_grace_period))?.ok_or_else(|| crate::serde::de::Error::invalid_length(84, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.shutdown_grace_period_critical_pods))?.ok_or_else(|| crate::serde::de::Error::invalid_length(85, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.static_pod_path))?.ok_or_else(|| crate::serde::de::Error::invalid_length(86, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.static_pod_url))?.ok_or_else(|| crate::serde::de::Error::invalid_length(87, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.static_pod_url_header))?.ok_or_else(|| crate::serde::de::Error::invalid_length(88, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.streaming_connection_idle_timeout))?.ok_or_else(|| crate::serde::de::Error::invalid_length(89, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.sync_frequency))?.ok_or_else(|| crate::serde::de::Error::invalid_length(90, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.system_cgroups))?.ok_or_else(|| crate::serde::de::Error::invalid_length(91, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.system_reserved))?.ok_or_else(|| crate::serde::de::Error::invalid_length(92, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.system_reserved_cgroup))?.ok_or_else(|| crate::serde::de::Error::invalid_length(93, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.tls_cert_file))?.ok_or_else(|| crate::serde::de::Error::invalid_length(94, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.tls_cipher_suites))?.ok_or_else(|| crate::serde::de::Error::invalid_length(95, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.tls_min_version))?.ok_or_else(|| crate::serde::de::Error::invalid_length(96, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.tls_private_key_file))?.ok_or_else(|| crate::serde::de::Error::invalid_length(97, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.topology_manager_policy))?.ok_or_else(|| crate::serde::de::Error::invalid_length(98, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.topology_manager_scope))?.ok_or_else(|| crate::serde::de::Error::invalid_length(99, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.volume_plugin_dir))?.ok_or_else(|| crate::serde::de::Error::invalid_length(100, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.volume_stats_agg_period))?.ok_or_else(|| crate::serde::de::Error::invalid_length(101, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            KubeletConfiguration::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for KubeletConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct(<Self as crate::Resource>::KIND, 102)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "address", &self.address)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "allowedUnsafeSysctls", &self.allowed_unsafe_sysctls)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "authentication", &self.authentication)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "authorization", &self.authorization)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cgroupDriver", &self.cgroup_driver)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cgroupRoot", &self.cgroup_root)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cgroupsPerQOS", &self.cgroups_per_qos)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clusterDNS", &self.cluster_dns)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clusterDomain", &self.cluster_domain)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "configMapAndSecretChangeDetectionStrategy", &self.config_map_and_secret_change_detection_strategy)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "containerLogMaxFiles", &self.container_log_max_files)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "containerLogMaxSize", &self.container_log_max_size)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "contentType", &self.content_type)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cpuCFSQuota", &self.cpu_cfs_quota)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cpuCFSQuotaPeriod", &self.cpu_cfs_quota_period)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cpuManagerPolicy", &self.cpu_manager_policy)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cpuManagerPolicyOptions", &self.cpu_manager_policy_options)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cpuManagerReconcilePeriod", &self.cpu_manager_reconcile_period)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableContentionProfiling", &self.enable_contention_profiling)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableControllerAttachDetach", &self.enable_controller_attach_detach)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableDebugFlagsHandler", &self.enable_debug_flags_handler)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableDebuggingHandlers", &self.enable_debugging_handlers)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableProfilingHandler", &self.enable_profiling_handler)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableServer", &self.enable_server)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableSystemLogHandler", &self.enable_system_log_handler)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enforceNodeAllocatable", &self.enforce_node_allocatable)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "eventBurst", &self.event_burst)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "eventRecordQPS", &self.event_record_qps)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "evictionHard", &self.eviction_hard)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "evictionMaxPodGracePeriod", &self.eviction_max_pod_grace_period)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "evictionMinimumReclaim", &self.eviction_minimum_reclaim)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "evictionPressureTransitionPeriod", &self.eviction_pressure_transition_period)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "evictionSoft", &self.eviction_soft)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "evictionSoftGracePeriod", &self.eviction_soft_grace_period)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "failSwapOn", &self.fail_swap_on)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "featureGates", &self.feature_gates)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fileCheckFrequency", &self.file_check_frequency)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hairpinMode", &self.hairpin_mode)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "healthzBindAddress", &self.healthz_bind_address)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "healthzPort", &self.healthz_port)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "httpCheckFrequency", &self.http_check_frequency)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "imageGCHighThresholdPercent", &self.image_gc_high_threshold_percent)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "imageGCLowThresholdPercent", &self.image_gc_low_threshold_percent)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "imageMinimumGCAge", &self.image_minimum_gc_age)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "iptablesDropBit", &self.iptables_drop_bit)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "iptablesMasqueradeBit", &self.iptables_masquerade_bit)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kernelMemcgNotification", &self.kernel_memcg_notification)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kubeAPIBurst", &self.kube_api_burst)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kubeAPIQPS", &self.kube_api_qps)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kubeReserved", &self.kube_reserved)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kubeReservedCgroup", &self.kube_reserved_cgroup)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kubeletCgroups", &self.kubelet_cgroups)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "logging", &self.logging)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "makeIPTablesUtilChains", &self.make_iptables_util_chains)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maxOpenFiles", &self.max_open_files)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maxPods", &self.max_pods)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "memoryManagerPolicy", &self.memory_manager_policy)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "memorySwap", &self.memory_swap)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "memoryThrottlingFactor", &self.memory_throttling_factor)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeLeaseDurationSeconds", &self.node_lease_duration_seconds)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeStatusMaxImages", &self.node_status_max_images)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeStatusReportFrequency", &self.node_status_report_frequency)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeStatusUpdateFrequency", &self.node_status_update_frequency)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "oomScoreAdj", &self.oom_score_adj)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "podCIDR", &self.pod_cidr)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "podPidsLimit", &self.pod_pids_limit)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "podsPerCore", &self.pods_per_core)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "port", &self.port)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "protectKernelDefaults", &self.protect_kernel_defaults)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "providerID", &self.provider_id)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "qosReserved", &self.qos_reserved)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnlyPort", &self.read_only_port)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "registryBurst", &self.registry_burst)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "registryPullQPS", &self.registry_pull_qps)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reservedMemory", &self.reserved_memory)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reservedSystemCPUs", &self.reserved_system_cpus)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resolvConf", &self.resolv_conf)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rotateCertificates", &self.rotate_certificates)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "runOnce", &self.run_once)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "runtimeRequestTimeout", &self.runtime_request_timeout)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "seccompDefault", &self.seccomp_default)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "serializeImagePulls", &self.serialize_image_pulls)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "serverTLSBootstrap", &self.server_tls_bootstrap)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "showHiddenMetricsForVersion", &self.show_hidden_metrics_for_version)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "shutdownGracePeriod", &self.shutdown_grace_period)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "shutdownGracePeriodCriticalPods", &self.shutdown_grace_period_critical_pods)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "staticPodPath", &self.static_pod_path)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "staticPodURL", &self.static_pod_url)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "staticPodURLHeader", &self.static_pod_url_header)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "streamingConnectionIdleTimeout", &self.streaming_connection_idle_timeout)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "syncFrequency", &self.sync_frequency)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "systemCgroups", &self.system_cgroups)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "systemReserved", &self.system_reserved)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "systemReservedCgroup", &self.system_reserved_cgroup)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tlsCertFile", &self.tls_cert_file)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tlsCipherSuites", &self.tls_cipher_suites)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tlsMinVersion", &self.tls_min_version)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tlsPrivateKeyFile", &self.tls_private_key_file)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "topologyManagerPolicy", &self.topology_manager_policy)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "topologyManagerScope", &self.topology_manager_scope)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumePluginDir", &self.volume_plugin_dir)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeStatsAggPeriod", &self.volume_stats_agg_period)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            <Self as crate::Resource>::KIND,
+            2 +
+            self.address.as_ref().map_or(0, |_| 1) +
+            self.allowed_unsafe_sysctls.as_ref().map_or(0, |_| 1) +
+            self.authentication.as_ref().map_or(0, |_| 1) +
+            self.authorization.as_ref().map_or(0, |_| 1) +
+            self.cgroup_driver.as_ref().map_or(0, |_| 1) +
+            self.cgroup_root.as_ref().map_or(0, |_| 1) +
+            self.cgroups_per_qos.as_ref().map_or(0, |_| 1) +
+            self.cluster_dns.as_ref().map_or(0, |_| 1) +
+            self.cluster_domain.as_ref().map_or(0, |_| 1) +
+            self.config_map_and_secret_change_detection_strategy.as_ref().map_or(0, |_| 1) +
+            self.container_log_max_files.as_ref().map_or(0, |_| 1) +
+            self.container_log_max_size.as_ref().map_or(0, |_| 1) +
+            self.content_type.as_ref().map_or(0, |_| 1) +
+            self.cpu_cfs_quota.as_ref().map_or(0, |_| 1) +
+            self.cpu_cfs_quota_period.as_ref().map_or(0, |_| 1) +
+            self.cpu_manager_policy.as_ref().map_or(0, |_| 1) +
+            self.cpu_manager_policy_options.as_ref().map_or(0, |_| 1) +
+            self.cpu_manager_reconcile_period.as_ref().map_or(0, |_| 1) +
+            self.enable_contention_profiling.as_ref().map_or(0, |_| 1) +
+            self.enable_controller_attach_detach.as_ref().map_or(0, |_| 1) +
+            self.enable_debug_flags_handler.as_ref().map_or(0, |_| 1) +
+            self.enable_debugging_handlers.as_ref().map_or(0, |_| 1) +
+            self.enable_profiling_handler.as_ref().map_or(0, |_| 1) +
+            self.enable_server.as_ref().map_or(0, |_| 1) +
+            self.enable_system_log_handler.as_ref().map_or(0, |_| 1) +
+            self.enforce_node_allocatable.as_ref().map_or(0, |_| 1) +
+            self.event_burst.as_ref().map_or(0, |_| 1) +
+            self.event_record_qps.as_ref().map_or(0, |_| 1) +
+            self.eviction_hard.as_ref().map_or(0, |_| 1) +
+            self.eviction_max_pod_grace_period.as_ref().map_or(0, |_| 1) +
+            self.eviction_minimum_reclaim.as_ref().map_or(0, |_| 1) +
+            self.eviction_pressure_transition_period.as_ref().map_or(0, |_| 1) +
+            self.eviction_soft.as_ref().map_or(0, |_| 1) +
+            self.eviction_soft_grace_period.as_ref().map_or(0, |_| 1) +
+            self.fail_swap_on.as_ref().map_or(0, |_| 1) +
+            self.feature_gates.as_ref().map_or(0, |_| 1) +
+            self.file_check_frequency.as_ref().map_or(0, |_| 1) +
+            self.hairpin_mode.as_ref().map_or(0, |_| 1) +
+            self.healthz_bind_address.as_ref().map_or(0, |_| 1) +
+            self.healthz_port.as_ref().map_or(0, |_| 1) +
+            self.http_check_frequency.as_ref().map_or(0, |_| 1) +
+            self.image_gc_high_threshold_percent.as_ref().map_or(0, |_| 1) +
+            self.image_gc_low_threshold_percent.as_ref().map_or(0, |_| 1) +
+            self.image_minimum_gc_age.as_ref().map_or(0, |_| 1) +
+            self.iptables_drop_bit.as_ref().map_or(0, |_| 1) +
+            self.iptables_masquerade_bit.as_ref().map_or(0, |_| 1) +
+            self.kernel_memcg_notification.as_ref().map_or(0, |_| 1) +
+            self.kube_api_burst.as_ref().map_or(0, |_| 1) +
+            self.kube_api_qps.as_ref().map_or(0, |_| 1) +
+            self.kube_reserved.as_ref().map_or(0, |_| 1) +
+            self.kube_reserved_cgroup.as_ref().map_or(0, |_| 1) +
+            self.kubelet_cgroups.as_ref().map_or(0, |_| 1) +
+            self.logging.as_ref().map_or(0, |_| 1) +
+            self.make_iptables_util_chains.as_ref().map_or(0, |_| 1) +
+            self.max_open_files.as_ref().map_or(0, |_| 1) +
+            self.max_pods.as_ref().map_or(0, |_| 1) +
+            self.memory_manager_policy.as_ref().map_or(0, |_| 1) +
+            self.memory_swap.as_ref().map_or(0, |_| 1) +
+            self.memory_throttling_factor.as_ref().map_or(0, |_| 1) +
+            self.node_lease_duration_seconds.as_ref().map_or(0, |_| 1) +
+            self.node_status_max_images.as_ref().map_or(0, |_| 1) +
+            self.node_status_report_frequency.as_ref().map_or(0, |_| 1) +
+            self.node_status_update_frequency.as_ref().map_or(0, |_| 1) +
+            self.oom_score_adj.as_ref().map_or(0, |_| 1) +
+            self.pod_cidr.as_ref().map_or(0, |_| 1) +
+            self.pod_pids_limit.as_ref().map_or(0, |_| 1) +
+            self.pods_per_core.as_ref().map_or(0, |_| 1) +
+            self.port.as_ref().map_or(0, |_| 1) +
+            self.protect_kernel_defaults.as_ref().map_or(0, |_| 1) +
+            self.provider_id.as_ref().map_or(0, |_| 1) +
+            self.qos_reserved.as_ref().map_or(0, |_| 1) +
+            self.read_only_port.as_ref().map_or(0, |_| 1) +
+            self.registry_burst.as_ref().map_or(0, |_| 1) +
+            self.registry_pull_qps.as_ref().map_or(0, |_| 1) +
+            self.reserved_memory.as_ref().map_or(0, |_| 1) +
+            self.reserved_system_cpus.as_ref().map_or(0, |_| 1) +
+            self.resolv_conf.as_ref().map_or(0, |_| 1) +
+            self.rotate_certificates.as_ref().map_or(0, |_| 1) +
+            self.run_once.as_ref().map_or(0, |_| 1) +
+            self.runtime_request_timeout.as_ref().map_or(0, |_| 1) +
+            self.seccomp_default.as_ref().map_or(0, |_| 1) +
+            self.serialize_image_pulls.as_ref().map_or(0, |_| 1) +
+            self.server_tls_bootstrap.as_ref().map_or(0, |_| 1) +
+            self.show_hidden_metrics_for_version.as_ref().map_or(0, |_| 1) +
+            self.shutdown_grace_period.as_ref().map_or(0, |_| 1) +
+            self.shutdown_grace_period_critical_pods.as_ref().map_or(0, |_| 1) +
+            self.static_pod_path.as_ref().map_or(0, |_| 1) +
+            self.static_pod_url.as_ref().map_or(0, |_| 1) +
+            self.static_pod_url_header.as_ref().map_or(0, |_| 1) +
+            self.streaming_connection_idle_timeout.as_ref().map_or(0, |_| 1) +
+            self.sync_frequency.as_ref().map_or(0, |_| 1) +
+            self.system_cgroups.as_ref().map_or(0, |_| 1) +
+            self.system_reserved.as_ref().map_or(0, |_| 1) +
+            self.system_reserved_cgroup.as_ref().map_or(0, |_| 1) +
+            self.tls_cert_file.as_ref().map_or(0, |_| 1) +
+            self.tls_cipher_suites.as_ref().map_or(0, |_| 1) +
+            self.tls_min_version.as_ref().map_or(0, |_| 1) +
+            self.tls_private_key_file.as_ref().map_or(0, |_| 1) +
+            self.topology_manager_policy.as_ref().map_or(0, |_| 1) +
+            self.topology_manager_scope.as_ref().map_or(0, |_| 1) +
+            self.volume_plugin_dir.as_ref().map_or(0, |_| 1) +
+            self.volume_stats_agg_period.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <Self as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <Self as crate::Resource>::KIND)?;
+        if let Some(value) = &self.address {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "address", value)?;
+        }
+        if let Some(value) = &self.allowed_unsafe_sysctls {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "allowedUnsafeSysctls", value)?;
+        }
+        if let Some(value) = &self.authentication {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "authentication", value)?;
+        }
+        if let Some(value) = &self.authorization {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "authorization", value)?;
+        }
+        if let Some(value) = &self.cgroup_driver {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cgroupDriver", value)?;
+        }
+        if let Some(value) = &self.cgroup_root {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cgroupRoot", value)?;
+        }
+        if let Some(value) = &self.cgroups_per_qos {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cgroupsPerQOS", value)?;
+        }
+        if let Some(value) = &self.cluster_dns {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clusterDNS", value)?;
+        }
+        if let Some(value) = &self.cluster_domain {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clusterDomain", value)?;
+        }
+        if let Some(value) = &self.config_map_and_secret_change_detection_strategy {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "configMapAndSecretChangeDetectionStrategy", value)?;
+        }
+        if let Some(value) = &self.container_log_max_files {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "containerLogMaxFiles", value)?;
+        }
+        if let Some(value) = &self.container_log_max_size {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "containerLogMaxSize", value)?;
+        }
+        if let Some(value) = &self.content_type {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "contentType", value)?;
+        }
+        if let Some(value) = &self.cpu_cfs_quota {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cpuCFSQuota", value)?;
+        }
+        if let Some(value) = &self.cpu_cfs_quota_period {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cpuCFSQuotaPeriod", value)?;
+        }
+        if let Some(value) = &self.cpu_manager_policy {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cpuManagerPolicy", value)?;
+        }
+        if let Some(value) = &self.cpu_manager_policy_options {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cpuManagerPolicyOptions", value)?;
+        }
+        if let Some(value) = &self.cpu_manager_reconcile_period {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cpuManagerReconcilePeriod", value)?;
+        }
+        if let Some(value) = &self.enable_contention_profiling {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableContentionProfiling", value)?;
+        }
+        if let Some(value) = &self.enable_controller_attach_detach {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableControllerAttachDetach", value)?;
+        }
+        if let Some(value) = &self.enable_debug_flags_handler {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableDebugFlagsHandler", value)?;
+        }
+        if let Some(value) = &self.enable_debugging_handlers {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableDebuggingHandlers", value)?;
+        }
+        if let Some(value) = &self.enable_profiling_handler {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableProfilingHandler", value)?;
+        }
+        if let Some(value) = &self.enable_server {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableServer", value)?;
+        }
+        if let Some(value) = &self.enable_system_log_handler {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableSystemLogHandler", value)?;
+        }
+        if let Some(value) = &self.enforce_node_allocatable {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enforceNodeAllocatable", value)?;
+        }
+        if let Some(value) = &self.event_burst {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "eventBurst", value)?;
+        }
+        if let Some(value) = &self.event_record_qps {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "eventRecordQPS", value)?;
+        }
+        if let Some(value) = &self.eviction_hard {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "evictionHard", value)?;
+        }
+        if let Some(value) = &self.eviction_max_pod_grace_period {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "evictionMaxPodGracePeriod", value)?;
+        }
+        if let Some(value) = &self.eviction_minimum_reclaim {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "evictionMinimumReclaim", value)?;
+        }
+        if let Some(value) = &self.eviction_pressure_transition_period {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "evictionPressureTransitionPeriod", value)?;
+        }
+        if let Some(value) = &self.eviction_soft {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "evictionSoft", value)?;
+        }
+        if let Some(value) = &self.eviction_soft_grace_period {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "evictionSoftGracePeriod", value)?;
+        }
+        if let Some(value) = &self.fail_swap_on {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "failSwapOn", value)?;
+        }
+        if let Some(value) = &self.feature_gates {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "featureGates", value)?;
+        }
+        if let Some(value) = &self.file_check_frequency {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fileCheckFrequency", value)?;
+        }
+        if let Some(value) = &self.hairpin_mode {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hairpinMode", value)?;
+        }
+        if let Some(value) = &self.healthz_bind_address {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "healthzBindAddress", value)?;
+        }
+        if let Some(value) = &self.healthz_port {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "healthzPort", value)?;
+        }
+        if let Some(value) = &self.http_check_frequency {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "httpCheckFrequency", value)?;
+        }
+        if let Some(value) = &self.image_gc_high_threshold_percent {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "imageGCHighThresholdPercent", value)?;
+        }
+        if let Some(value) = &self.image_gc_low_threshold_percent {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "imageGCLowThresholdPercent", value)?;
+        }
+        if let Some(value) = &self.image_minimum_gc_age {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "imageMinimumGCAge", value)?;
+        }
+        if let Some(value) = &self.iptables_drop_bit {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "iptablesDropBit", value)?;
+        }
+        if let Some(value) = &self.iptables_masquerade_bit {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "iptablesMasqueradeBit", value)?;
+        }
+        if let Some(value) = &self.kernel_memcg_notification {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kernelMemcgNotification", value)?;
+        }
+        if let Some(value) = &self.kube_api_burst {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kubeAPIBurst", value)?;
+        }
+        if let Some(value) = &self.kube_api_qps {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kubeAPIQPS", value)?;
+        }
+        if let Some(value) = &self.kube_reserved {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kubeReserved", value)?;
+        }
+        if let Some(value) = &self.kube_reserved_cgroup {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kubeReservedCgroup", value)?;
+        }
+        if let Some(value) = &self.kubelet_cgroups {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kubeletCgroups", value)?;
+        }
+        if let Some(value) = &self.logging {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "logging", value)?;
+        }
+        if let Some(value) = &self.make_iptables_util_chains {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "makeIPTablesUtilChains", value)?;
+        }
+        if let Some(value) = &self.max_open_files {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maxOpenFiles", value)?;
+        }
+        if let Some(value) = &self.max_pods {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maxPods", value)?;
+        }
+        if let Some(value) = &self.memory_manager_policy {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "memoryManagerPolicy", value)?;
+        }
+        if let Some(value) = &self.memory_swap {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "memorySwap", value)?;
+        }
+        if let Some(value) = &self.memory_throttling_factor {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "memoryThrottlingFactor", value)?;
+        }
+        if let Some(value) = &self.node_lease_duration_seconds {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeLeaseDurationSeconds", value)?;
+        }
+        if let Some(value) = &self.node_status_max_images {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeStatusMaxImages", value)?;
+        }
+        if let Some(value) = &self.node_status_report_frequency {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeStatusReportFrequency", value)?;
+        }
+        if let Some(value) = &self.node_status_update_frequency {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeStatusUpdateFrequency", value)?;
+        }
+        if let Some(value) = &self.oom_score_adj {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "oomScoreAdj", value)?;
+        }
+        if let Some(value) = &self.pod_cidr {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "podCIDR", value)?;
+        }
+        if let Some(value) = &self.pod_pids_limit {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "podPidsLimit", value)?;
+        }
+        if let Some(value) = &self.pods_per_core {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "podsPerCore", value)?;
+        }
+        if let Some(value) = &self.port {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "port", value)?;
+        }
+        if let Some(value) = &self.protect_kernel_defaults {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "protectKernelDefaults", value)?;
+        }
+        if let Some(value) = &self.provider_id {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "providerID", value)?;
+        }
+        if let Some(value) = &self.qos_reserved {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "qosReserved", value)?;
+        }
+        if let Some(value) = &self.read_only_port {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnlyPort", value)?;
+        }
+        if let Some(value) = &self.registry_burst {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "registryBurst", value)?;
+        }
+        if let Some(value) = &self.registry_pull_qps {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "registryPullQPS", value)?;
+        }
+        if let Some(value) = &self.reserved_memory {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reservedMemory", value)?;
+        }
+        if let Some(value) = &self.reserved_system_cpus {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reservedSystemCPUs", value)?;
+        }
+        if let Some(value) = &self.resolv_conf {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resolvConf", value)?;
+        }
+        if let Some(value) = &self.rotate_certificates {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rotateCertificates", value)?;
+        }
+        if let Some(value) = &self.run_once {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "runOnce", value)?;
+        }
+        if let Some(value) = &self.runtime_request_timeout {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "runtimeRequestTimeout", value)?;
+        }
+        if let Some(value) = &self.seccomp_default {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "seccompDefault", value)?;
+        }
+        if let Some(value) = &self.serialize_image_pulls {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "serializeImagePulls", value)?;
+        }
+        if let Some(value) = &self.server_tls_bootstrap {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "serverTLSBootstrap", value)?;
+        }
+        if let Some(value) = &self.show_hidden_metrics_for_version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "showHiddenMetricsForVersion", value)?;
+        }
+        if let Some(value) = &self.shutdown_grace_period {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "shutdownGracePeriod", value)?;
+        }
+        if let Some(value) = &self.shutdown_grace_period_critical_pods {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "shutdownGracePeriodCriticalPods", value)?;
+        }
+        if let Some(value) = &self.static_pod_path {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "staticPodPath", value)?;
+        }
+        if let Some(value) = &self.static_pod_url {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "staticPodURL", value)?;
+        }
+        if let Some(value) = &self.static_pod_url_header {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "staticPodURLHeader", value)?;
+        }
+        if let Some(value) = &self.streaming_connection_idle_timeout {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "streamingConnectionIdleTimeout", value)?;
+        }
+        if let Some(value) = &self.sync_frequency {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "syncFrequency", value)?;
+        }
+        if let Some(value) = &self.system_cgroups {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "systemCgroups", value)?;
+        }
+        if let Some(value) = &self.system_reserved {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "systemReserved", value)?;
+        }
+        if let Some(value) = &self.system_reserved_cgroup {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "systemReservedCgroup", value)?;
+        }
+        if let Some(value) = &self.tls_cert_file {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tlsCertFile", value)?;
+        }
+        if let Some(value) = &self.tls_cipher_suites {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tlsCipherSuites", value)?;
+        }
+        if let Some(value) = &self.tls_min_version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tlsMinVersion", value)?;
+        }
+        if let Some(value) = &self.tls_private_key_file {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tlsPrivateKeyFile", value)?;
+        }
+        if let Some(value) = &self.topology_manager_policy {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "topologyManagerPolicy", value)?;
+        }
+        if let Some(value) = &self.topology_manager_scope {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "topologyManagerScope", value)?;
+        }
+        if let Some(value) = &self.volume_plugin_dir {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumePluginDir", value)?;
+        }
+        if let Some(value) = &self.volume_stats_agg_period {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeStatsAggPeriod", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`KubeletConfiguration`]. Created with [`KubeletConfiguration::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct KubeletConfigurationBuilder {
+    address: Option<String>,
+    allowed_unsafe_sysctls: Option<Vec<String>>,
+    authentication: Option<crate::kubelet::config::v1beta1::KubeletAuthentication>,
+    authorization: Option<crate::kubelet::config::v1beta1::KubeletAuthorization>,
+    cgroup_driver: Option<String>,
+    cgroup_root: Option<String>,
+    cgroups_per_qos: Option<bool>,
+    cluster_dns: Option<Vec<String>>,
+    cluster_domain: Option<String>,
+    config_map_and_secret_change_detection_strategy: Option<String>,
+    container_log_max_files: Option<i32>,
+    container_log_max_size: Option<String>,
+    content_type: Option<String>,
+    cpu_cfs_quota: Option<bool>,
+    cpu_cfs_quota_period: Option<String>,
+    cpu_manager_policy: Option<String>,
+    cpu_manager_policy_options: Option<std::collections::BTreeMap<String, String>>,
+    cpu_manager_reconcile_period: Option<String>,
+    enable_contention_profiling: Option<bool>,
+    enable_controller_attach_detach: Option<bool>,
+    enable_debug_flags_handler: Option<bool>,
+    enable_debugging_handlers: Option<bool>,
+    enable_profiling_handler: Option<bool>,
+    enable_server: Option<bool>,
+    enable_system_log_handler: Option<bool>,
+    enforce_node_allocatable: Option<Vec<String>>,
+    event_burst: Option<i32>,
+    event_record_qps: Option<i32>,
+    eviction_hard: Option<std::collections::BTreeMap<String, String>>,
+    eviction_max_pod_grace_period: Option<i32>,
+    eviction_minimum_reclaim: Option<std::collections::BTreeMap<String, String>>,
+    eviction_pressure_transition_period: Option<String>,
+    eviction_soft: Option<std::collections::BTreeMap<String, String>>,
+    eviction_soft_grace_period: Option<std::collections::BTreeMap<String, String>>,
+    fail_swap_on: Option<bool>,
+    feature_gates: Option<std::collections::BTreeMap<String, bool>>,
+    file_check_frequency: Option<String>,
+    hairpin_mode: Option<String>,
+    healthz_bind_address: Option<String>,
+    healthz_port: Option<i32>,
+    http_check_frequency: Option<String>,
+    image_gc_high_threshold_percent: Option<i32>,
+    image_gc_low_threshold_percent: Option<i32>,
+    image_minimum_gc_age: Option<String>,
+    iptables_drop_bit: Option<i32>,
+    iptables_masquerade_bit: Option<i32>,
+    kernel_memcg_notification: Option<bool>,
+    kube_api_burst: Option<i32>,
+    kube_api_qps: Option<i32>,
+    kube_reserved: Option<std::collections::BTreeMap<String, String>>,
+    kube_reserved_cgroup: Option<String>,
+    kubelet_cgroups: Option<String>,
+    logging: Option<crate::kubelet::config::v1beta1::LoggingConfiguration>,
+    make_iptables_util_chains: Option<bool>,
+    max_open_files: Option<i64>,
+    max_pods: Option<i32>,
+    memory_manager_policy: Option<String>,
+    memory_swap: Option<crate::kubelet::config::v1beta1::MemorySwapConfiguration>,
+    memory_throttling_factor: Option<f64>,
+    node_lease_duration_seconds: Option<i32>,
+    node_status_max_images: Option<i32>,
+    node_status_report_frequency: Option<String>,
+    node_status_update_frequency: Option<String>,
+    oom_score_adj: Option<i32>,
+    pod_cidr: Option<String>,
+    pod_pids_limit: Option<i64>,
+    pods_per_core: Option<i32>,
+    port: Option<i32>,
+    protect_kernel_defaults: Option<bool>,
+    provider_id: Option<String>,
+    qos_reserved: Option<std::collections::BTreeMap<String, String>>,
+    read_only_port: Option<i32>,
+    registry_burst: Option<i32>,
+    registry_pull_qps: Option<i32>,
+    reserved_memory: Option<Vec<crate::kubelet::config::v1beta1::MemoryReservation>>,
+    reserved_system_cpus: Option<String>,
+    resolv_conf: Option<String>,
+    rotate_certificates: Option<bool>,
+    run_once: Option<bool>,
+    runtime_request_timeout: Option<String>,
+    seccomp_default: Option<bool>,
+    serialize_image_pulls: Option<bool>,
+    server_tls_bootstrap: Option<bool>,
+    show_hidden_metrics_for_version: Option<String>,
+    shutdown_grace_period: Option<String>,
+    shutdown_grace_period_critical_pods: Option<String>,
+    static_pod_path: Option<String>,
+    static_pod_url: Option<String>,
+    static_pod_url_header: Option<std::collections::BTreeMap<String, Vec<String>>>,
+    streaming_connection_idle_timeout: Option<String>,
+    sync_frequency: Option<String>,
+    system_cgroups: Option<String>,
+    system_reserved: Option<std::collections::BTreeMap<String, String>>,
+    system_reserved_cgroup: Option<String>,
+    tls_cert_file: Option<String>,
+    tls_cipher_suites: Option<Vec<String>>,
+    tls_min_version: Option<String>,
+    tls_private_key_file: Option<String>,
+    topology_manager_policy: Option<String>,
+    topology_manager_scope: Option<String>,
+    volume_plugin_dir: Option<String>,
+    volume_stats_agg_period: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl KubeletConfiguration {
+    /// Starts building a `KubeletConfiguration`. Its required fields must be set before [`KubeletConfigurationBuilder::build`] is called.
+    pub fn builder() -> KubeletConfigurationBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl KubeletConfigurationBuilder {
+    /// Sets [`KubeletConfiguration::address`].
+    pub fn address(mut self, value: impl Into<String>) -> Self {
+        self.address = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::allowed_unsafe_sysctls`].
+    pub fn allowed_unsafe_sysctls(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.allowed_unsafe_sysctls = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::authentication`].
+    pub fn authentication(mut self, value: impl Into<crate::kubelet::config::v1beta1::KubeletAuthentication>) -> Self {
+        self.authentication = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::authorization`].
+    pub fn authorization(mut self, value: impl Into<crate::kubelet::config::v1beta1::KubeletAuthorization>) -> Self {
+        self.authorization = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::cgroup_driver`].
+    pub fn cgroup_driver(mut self, value: impl Into<String>) -> Self {
+        self.cgroup_driver = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::cgroup_root`].
+    pub fn cgroup_root(mut self, value: impl Into<String>) -> Self {
+        self.cgroup_root = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::cgroups_per_qos`].
+    pub fn cgroups_per_qos(mut self, value: impl Into<bool>) -> Self {
+        self.cgroups_per_qos = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::cluster_dns`].
+    pub fn cluster_dns(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.cluster_dns = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::cluster_domain`].
+    pub fn cluster_domain(mut self, value: impl Into<String>) -> Self {
+        self.cluster_domain = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::config_map_and_secret_change_detection_strategy`].
+    pub fn config_map_and_secret_change_detection_strategy(mut self, value: impl Into<String>) -> Self {
+        self.config_map_and_secret_change_detection_strategy = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::container_log_max_files`].
+    pub fn container_log_max_files(mut self, value: impl Into<i32>) -> Self {
+        self.container_log_max_files = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::container_log_max_size`].
+    pub fn container_log_max_size(mut self, value: impl Into<String>) -> Self {
+        self.container_log_max_size = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::content_type`].
+    pub fn content_type(mut self, value: impl Into<String>) -> Self {
+        self.content_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::cpu_cfs_quota`].
+    pub fn cpu_cfs_quota(mut self, value: impl Into<bool>) -> Self {
+        self.cpu_cfs_quota = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::cpu_cfs_quota_period`].
+    pub fn cpu_cfs_quota_period(mut self, value: impl Into<String>) -> Self {
+        self.cpu_cfs_quota_period = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::cpu_manager_policy`].
+    pub fn cpu_manager_policy(mut self, value: impl Into<String>) -> Self {
+        self.cpu_manager_policy = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::cpu_manager_policy_options`].
+    pub fn cpu_manager_policy_options(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.cpu_manager_policy_options = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::cpu_manager_reconcile_period`].
+    pub fn cpu_manager_reconcile_period(mut self, value: impl Into<String>) -> Self {
+        self.cpu_manager_reconcile_period = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::enable_contention_profiling`].
+    pub fn enable_contention_profiling(mut self, value: impl Into<bool>) -> Self {
+        self.enable_contention_profiling = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::enable_controller_attach_detach`].
+    pub fn enable_controller_attach_detach(mut self, value: impl Into<bool>) -> Self {
+        self.enable_controller_attach_detach = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::enable_debug_flags_handler`].
+    pub fn enable_debug_flags_handler(mut self, value: impl Into<bool>) -> Self {
+        self.enable_debug_flags_handler = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::enable_debugging_handlers`].
+    pub fn enable_debugging_handlers(mut self, value: impl Into<bool>) -> Self {
+        self.enable_debugging_handlers = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::enable_profiling_handler`].
+    pub fn enable_profiling_handler(mut self, value: impl Into<bool>) -> Self {
+        self.enable_profiling_handler = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::enable_server`].
+    pub fn enable_server(mut self, value: impl Into<bool>) -> Self {
+        self.enable_server = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::enable_system_log_handler`].
+    pub fn enable_system_log_handler(mut self, value: impl Into<bool>) -> Self {
+        self.enable_system_log_handler = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::enforce_node_allocatable`].
+    pub fn enforce_node_allocatable(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.enforce_node_allocatable = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::event_burst`].
+    pub fn event_burst(mut self, value: impl Into<i32>) -> Self {
+        self.event_burst = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::event_record_qps`].
+    pub fn event_record_qps(mut self, value: impl Into<i32>) -> Self {
+        self.event_record_qps = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::eviction_hard`].
+    pub fn eviction_hard(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.eviction_hard = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::eviction_max_pod_grace_period`].
+    pub fn eviction_max_pod_grace_period(mut self, value: impl Into<i32>) -> Self {
+        self.eviction_max_pod_grace_period = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::eviction_minimum_reclaim`].
+    pub fn eviction_minimum_reclaim(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.eviction_minimum_reclaim = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::eviction_pressure_transition_period`].
+    pub fn eviction_pressure_transition_period(mut self, value: impl Into<String>) -> Self {
+        self.eviction_pressure_transition_period = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::eviction_soft`].
+    pub fn eviction_soft(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.eviction_soft = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::eviction_soft_grace_period`].
+    pub fn eviction_soft_grace_period(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.eviction_soft_grace_period = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::fail_swap_on`].
+    pub fn fail_swap_on(mut self, value: impl Into<bool>) -> Self {
+        self.fail_swap_on = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::feature_gates`].
+    pub fn feature_gates(mut self, value: impl Into<std::collections::BTreeMap<String, bool>>) -> Self {
+        self.feature_gates = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::file_check_frequency`].
+    pub fn file_check_frequency(mut self, value: impl Into<String>) -> Self {
+        self.file_check_frequency = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::hairpin_mode`].
+    pub fn hairpin_mode(mut self, value: impl Into<String>) -> Self {
+        self.hairpin_mode = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::healthz_bind_address`].
+    pub fn healthz_bind_address(mut self, value: impl Into<String>) -> Self {
+        self.healthz_bind_address = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::healthz_port`].
+    pub fn healthz_port(mut self, value: impl Into<i32>) -> Self {
+        self.healthz_port = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::http_check_frequency`].
+    pub fn http_check_frequency(mut self, value: impl Into<String>) -> Self {
+        self.http_check_frequency = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::image_gc_high_threshold_percent`].
+    pub fn image_gc_high_threshold_percent(mut self, value: impl Into<i32>) -> Self {
+        self.image_gc_high_threshold_percent = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::image_gc_low_threshold_percent`].
+    pub fn image_gc_low_threshold_percent(mut self, value: impl Into<i32>) -> Self {
+        self.image_gc_low_threshold_percent = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::image_minimum_gc_age`].
+    pub fn image_minimum_gc_age(mut self, value: impl Into<String>) -> Self {
+        self.image_minimum_gc_age = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::iptables_drop_bit`].
+    pub fn iptables_drop_bit(mut self, value: impl Into<i32>) -> Self {
+        self.iptables_drop_bit = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::iptables_masquerade_bit`].
+    pub fn iptables_masquerade_bit(mut self, value: impl Into<i32>) -> Self {
+        self.iptables_masquerade_bit = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::kernel_memcg_notification`].
+    pub fn kernel_memcg_notification(mut self, value: impl Into<bool>) -> Self {
+        self.kernel_memcg_notification = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::kube_api_burst`].
+    pub fn kube_api_burst(mut self, value: impl Into<i32>) -> Self {
+        self.kube_api_burst = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::kube_api_qps`].
+    pub fn kube_api_qps(mut self, value: impl Into<i32>) -> Self {
+        self.kube_api_qps = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::kube_reserved`].
+    pub fn kube_reserved(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.kube_reserved = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::kube_reserved_cgroup`].
+    pub fn kube_reserved_cgroup(mut self, value: impl Into<String>) -> Self {
+        self.kube_reserved_cgroup = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::kubelet_cgroups`].
+    pub fn kubelet_cgroups(mut self, value: impl Into<String>) -> Self {
+        self.kubelet_cgroups = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::logging`].
+    pub fn logging(mut self, value: impl Into<crate::kubelet::config::v1beta1::LoggingConfiguration>) -> Self {
+        self.logging = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::make_iptables_util_chains`].
+    pub fn make_iptables_util_chains(mut self, value: impl Into<bool>) -> Self {
+        self.make_iptables_util_chains = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::max_open_files`].
+    pub fn max_open_files(mut self, value: impl Into<i64>) -> Self {
+        self.max_open_files = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::max_pods`].
+    pub fn max_pods(mut self, value: impl Into<i32>) -> Self {
+        self.max_pods = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::memory_manager_policy`].
+    pub fn memory_manager_policy(mut self, value: impl Into<String>) -> Self {
+        self.memory_manager_policy = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::memory_swap`].
+    pub fn memory_swap(mut self, value: impl Into<crate::kubelet::config::v1beta1::MemorySwapConfiguration>) -> Self {
+        self.memory_swap = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::memory_throttling_factor`].
+    pub fn memory_throttling_factor(mut self, value: impl Into<f64>) -> Self {
+        self.memory_throttling_factor = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::node_lease_duration_seconds`].
+    pub fn node_lease_duration_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.node_lease_duration_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::node_status_max_images`].
+    pub fn node_status_max_images(mut self, value: impl Into<i32>) -> Self {
+        self.node_status_max_images = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::node_status_report_frequency`].
+    pub fn node_status_report_frequency(mut self, value: impl Into<String>) -> Self {
+        self.node_status_report_frequency = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::node_status_update_frequency`].
+    pub fn node_status_update_frequency(mut self, value: impl Into<String>) -> Self {
+        self.node_status_update_frequency = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::oom_score_adj`].
+    pub fn oom_score_adj(mut self, value: impl Into<i32>) -> Self {
+        self.oom_score_adj = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::pod_cidr`].
+    pub fn pod_cidr(mut self, value: impl Into<String>) -> Self {
+        self.pod_cidr = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::pod_pids_limit`].
+    pub fn pod_pids_limit(mut self, value: impl Into<i64>) -> Self {
+        self.pod_pids_limit = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::pods_per_core`].
+    pub fn pods_per_core(mut self, value: impl Into<i32>) -> Self {
+        self.pods_per_core = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::port`].
+    pub fn port(mut self, value: impl Into<i32>) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::protect_kernel_defaults`].
+    pub fn protect_kernel_defaults(mut self, value: impl Into<bool>) -> Self {
+        self.protect_kernel_defaults = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::provider_id`].
+    pub fn provider_id(mut self, value: impl Into<String>) -> Self {
+        self.provider_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::qos_reserved`].
+    pub fn qos_reserved(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.qos_reserved = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::read_only_port`].
+    pub fn read_only_port(mut self, value: impl Into<i32>) -> Self {
+        self.read_only_port = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::registry_burst`].
+    pub fn registry_burst(mut self, value: impl Into<i32>) -> Self {
+        self.registry_burst = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::registry_pull_qps`].
+    pub fn registry_pull_qps(mut self, value: impl Into<i32>) -> Self {
+        self.registry_pull_qps = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::reserved_memory`].
+    pub fn reserved_memory(mut self, value: impl Into<Vec<crate::kubelet::config::v1beta1::MemoryReservation>>) -> Self {
+        self.reserved_memory = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::reserved_system_cpus`].
+    pub fn reserved_system_cpus(mut self, value: impl Into<String>) -> Self {
+        self.reserved_system_cpus = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::resolv_conf`].
+    pub fn resolv_conf(mut self, value: impl Into<String>) -> Self {
+        self.resolv_conf = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::rotate_certificates`].
+    pub fn rotate_certificates(mut self, value: impl Into<bool>) -> Self {
+        self.rotate_certificates = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::run_once`].
+    pub fn run_once(mut self, value: impl Into<bool>) -> Self {
+        self.run_once = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::runtime_request_timeout`].
+    pub fn runtime_request_timeout(mut self, value: impl Into<String>) -> Self {
+        self.runtime_request_timeout = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::seccomp_default`].
+    pub fn seccomp_default(mut self, value: impl Into<bool>) -> Self {
+        self.seccomp_default = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::serialize_image_pulls`].
+    pub fn serialize_image_pulls(mut self, value: impl Into<bool>) -> Self {
+        self.serialize_image_pulls = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::server_tls_bootstrap`].
+    pub fn server_tls_bootstrap(mut self, value: impl Into<bool>) -> Self {
+        self.server_tls_bootstrap = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::show_hidden_metrics_for_version`].
+    pub fn show_hidden_metrics_for_version(mut self, value: impl Into<String>) -> Self {
+        self.show_hidden_metrics_for_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::shutdown_grace_period`].
+    pub fn shutdown_grace_period(mut self, value: impl Into<String>) -> Self {
+        self.shutdown_grace_period = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::shutdown_grace_period_critical_pods`].
+    pub fn shutdown_grace_period_critical_pods(mut self, value: impl Into<String>) -> Self {
+        self.shutdown_grace_period_critical_pods = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::static_pod_path`].
+    pub fn static_pod_path(mut self, value: impl Into<String>) -> Self {
+        self.static_pod_path = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::static_pod_url`].
+    pub fn static_pod_url(mut self, value: impl Into<String>) -> Self {
+        self.static_pod_url = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::static_pod_url_header`].
+    pub fn static_pod_url_header(mut self, value: impl Into<std::collections::BTreeMap<String, Vec<String>>>) -> Self {
+        self.static_pod_url_header = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::streaming_connection_idle_timeout`].
+    pub fn streaming_connection_idle_timeout(mut self, value: impl Into<String>) -> Self {
+        self.streaming_connection_idle_timeout = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::sync_frequency`].
+    pub fn sync_frequency(mut self, value: impl Into<String>) -> Self {
+        self.sync_frequency = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::system_cgroups`].
+    pub fn system_cgroups(mut self, value: impl Into<String>) -> Self {
+        self.system_cgroups = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::system_reserved`].
+    pub fn system_reserved(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.system_reserved = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::system_reserved_cgroup`].
+    pub fn system_reserved_cgroup(mut self, value: impl Into<String>) -> Self {
+        self.system_reserved_cgroup = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::tls_cert_file`].
+    pub fn tls_cert_file(mut self, value: impl Into<String>) -> Self {
+        self.tls_cert_file = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::tls_cipher_suites`].
+    pub fn tls_cipher_suites(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.tls_cipher_suites = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::tls_min_version`].
+    pub fn tls_min_version(mut self, value: impl Into<String>) -> Self {
+        self.tls_min_version = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::tls_private_key_file`].
+    pub fn tls_private_key_file(mut self, value: impl Into<String>) -> Self {
+        self.tls_private_key_file = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::topology_manager_policy`].
+    pub fn topology_manager_policy(mut self, value: impl Into<String>) -> Self {
+        self.topology_manager_policy = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::topology_manager_scope`].
+    pub fn topology_manager_scope(mut self, value: impl Into<String>) -> Self {
+        self.topology_manager_scope = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::volume_plugin_dir`].
+    pub fn volume_plugin_dir(mut self, value: impl Into<String>) -> Self {
+        self.volume_plugin_dir = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletConfiguration::volume_stats_agg_period`].
+    pub fn volume_stats_agg_period(mut self, value: impl Into<String>) -> Self {
+        self.volume_stats_agg_period = Some(value.into());
+        self
+    }
+
+    /// Builds the `KubeletConfiguration`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<KubeletConfiguration, crate::MissingFieldError> {
+        Ok(KubeletConfiguration {
+            address: self.address,
+            allowed_unsafe_sysctls: self.allowed_unsafe_sysctls,
+            authentication: self.authentication,
+            authorization: self.authorization,
+            cgroup_driver: self.cgroup_driver,
+            cgroup_root: self.cgroup_root,
+            cgroups_per_qos: self.cgroups_per_qos,
+            cluster_dns: self.cluster_dns,
+            cluster_domain: self.cluster_domain,
+            config_map_and_secret_change_detection_strategy: self.config_map_and_secret_change_detection_strategy,
+            container_log_max_files: self.container_log_max_files,
+            container_log_max_size: self.container_log_max_size,
+            content_type: self.content_type,
+            cpu_cfs_quota: self.cpu_cfs_quota,
+            cpu_cfs_quota_period: self.cpu_cfs_quota_period,
+            cpu_manager_policy: self.cpu_manager_policy,
+            cpu_manager_policy_options: self.cpu_manager_policy_options,
+            cpu_manager_reconcile_period: self.cpu_manager_reconcile_period,
+            enable_contention_profiling: self.enable_contention_profiling,
+            enable_controller_attach_detach: self.enable_controller_attach_detach,
+            enable_debug_flags_handler: self.enable_debug_flags_handler,
+            enable_debugging_handlers: self.enable_debugging_handlers,
+            enable_profiling_handler: self.enable_profiling_handler,
+            enable_server: self.enable_server,
+            enable_system_log_handler: self.enable_system_log_handler,
+            enforce_node_allocatable: self.enforce_node_allocatable,
+            event_burst: self.event_burst,
+            event_record_qps: self.event_record_qps,
+            eviction_hard: self.eviction_hard,
+            eviction_max_pod_grace_period: self.eviction_max_pod_grace_period,
+            eviction_minimum_reclaim: self.eviction_minimum_reclaim,
+            eviction_pressure_transition_period: self.eviction_pressure_transition_period,
+            eviction_soft: self.eviction_soft,
+            eviction_soft_grace_period: self.eviction_soft_grace_period,
+            fail_swap_on: self.fail_swap_on,
+            feature_gates: self.feature_gates,
+            file_check_frequency: self.file_check_frequency,
+            hairpin_mode: self.hairpin_mode,
+            healthz_bind_address: self.healthz_bind_address,
+            healthz_port: self.healthz_port,
+            http_check_frequency: self.http_check_frequency,
+            image_gc_high_threshold_percent: self.image_gc_high_threshold_percent,
+            image_gc_low_threshold_percent: self.image_gc_low_threshold_percent,
+            image_minimum_gc_age: self.image_minimum_gc_age,
+            iptables_drop_bit: self.iptables_drop_bit,
+            iptables_masquerade_bit: self.iptables_masquerade_bit,
+            kernel_memcg_notification: self.kernel_memcg_notification,
+            kube_api_burst: self.kube_api_burst,
+            kube_api_qps: self.kube_api_qps,
+            kube_reserved: self.kube_reserved,
+            kube_reserved_cgroup: self.kube_reserved_cgroup,
+            kubelet_cgroups: self.kubelet_cgroups,
+            logging: self.logging,
+            make_iptables_util_chains: self.make_iptables_util_chains,
+            max_open_files: self.max_open_files,
+            max_pods: self.max_pods,
+            memory_manager_policy: self.memory_manager_policy,
+            memory_swap: self.memory_swap,
+            memory_throttling_factor: self.memory_throttling_factor,
+            node_lease_duration_seconds: self.node_lease_duration_seconds,
+            node_status_max_images: self.node_status_max_images,
+            node_status_report_frequency: self.node_status_report_frequency,
+            node_status_update_frequency: self.node_status_update_frequency,
+            oom_score_adj: self.oom_score_adj,
+            pod_cidr: self.pod_cidr,
+            pod_pids_limit: self.pod_pids_limit,
+            pods_per_core: self.pods_per_core,
+            port: self.port,
+            protect_kernel_defaults: self.protect_kernel_defaults,
+            provider_id: self.provider_id,
+            qos_reserved: self.qos_reserved,
+            read_only_port: self.read_only_port,
+            registry_burst: self.registry_burst,
+            registry_pull_qps: self.registry_pull_qps,
+            reserved_memory: self.reserved_memory,
+            reserved_system_cpus: self.reserved_system_cpus,
+            resolv_conf: self.resolv_conf,
+            rotate_certificates: self.rotate_certificates,
+            run_once: self.run_once,
+            runtime_request_timeout: self.runtime_request_timeout,
+            seccomp_default: self.seccomp_default,
+            serialize_image_pulls: self.serialize_image_pulls,
+            server_tls_bootstrap: self.server_tls_bootstrap,
+            show_hidden_metrics_for_version: self.show_hidden_metrics_for_version,
+            shutdown_grace_period: self.shutdown_grace_period,
+            shutdown_grace_period_critical_pods: self.shutdown_grace_period_critical_pods,
+            static_pod_path: self.static_pod_path,
+            static_pod_url: self.static_pod_url,
+            static_pod_url_header: self.static_pod_url_header,
+            streaming_connection_idle_timeout: self.streaming_connection_idle_timeout,
+            sync_frequency: self.sync_frequency,
+            system_cgroups: self.system_cgroups,
+            system_reserved: self.system_reserved,
+            system_reserved_cgroup: self.system_reserved_cgroup,
+            tls_cert_file: self.tls_cert_file,
+            tls_cipher_suites: self.tls_cipher_suites,
+            tls_min_version: self.tls_min_version,
+            tls_private_key_file: self.tls_private_key_file,
+            topology_manager_policy: self.topology_manager_policy,
+            topology_manager_scope: self.topology_manager_scope,
+            volume_plugin_dir: self.volume_plugin_dir,
+            volume_stats_agg_period: self.volume_stats_agg_period,
+        })
+    }
+}
+
+/// The apply configuration of [`KubeletConfiguration`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KubeletConfigurationApplyConfiguration {
+    /// See [`KubeletConfiguration::address`].
+    pub address: Option<String>,
+
+    /// See [`KubeletConfiguration::allowed_unsafe_sysctls`].
+    pub allowed_unsafe_sysctls: Option<Vec<String>>,
+
+    /// See [`KubeletConfiguration::authentication`].
+    pub authentication: Option<crate::kubelet::config::v1beta1::KubeletAuthenticationApplyConfiguration>,
+
+    /// See [`KubeletConfiguration::authorization`].
+    pub authorization: Option<crate::kubelet::config::v1beta1::KubeletAuthorizationApplyConfiguration>,
+
+    /// See [`KubeletConfiguration::cgroup_driver`].
+    pub cgroup_driver: Option<String>,
+
+    /// See [`KubeletConfiguration::cgroup_root`].
+    pub cgroup_root: Option<String>,
+
+    /// See [`KubeletConfiguration::cgroups_per_qos`].
+    pub cgroups_per_qos: Option<bool>,
+
+    /// See [`KubeletConfiguration::cluster_dns`].
+    pub cluster_dns: Option<Vec<String>>,
+
+    /// See [`KubeletConfiguration::cluster_domain`].
+    pub cluster_domain: Option<String>,
+
+    /// See [`KubeletConfiguration::config_map_and_secret_change_detection_strategy`].
+    pub config_map_and_secret_change_detection_strategy: Option<String>,
+
+    /// See [`KubeletConfiguration::container_log_max_files`].
+    pub container_log_max_files: Option<i32>,
+
+    /// See [`KubeletConfiguration::container_log_max_size`].
+    pub container_log_max_size: Option<String>,
+
+    /// See [`KubeletConfiguration::content_type`].
+    pub content_type: Option<String>,
+
+    /// See [`KubeletConfiguration::cpu_cfs_quota`].
+    pub cpu_cfs_quota: Option<bool>,
+
+    /// See [`KubeletConfiguration::cpu_cfs_quota_period`].
+    pub cpu_cfs_quota_period: Option<String>,
+
+    /// See [`KubeletConfiguration::cpu_manager_policy`].
+    pub cpu_manager_policy: Option<String>,
+
+    /// See [`KubeletConfiguration::cpu_manager_policy_options`].
+    pub cpu_manager_policy_options: Option<std::collections::BTreeMap<String, String>>,
+
+    /// See [`KubeletConfiguration::cpu_manager_reconcile_period`].
+    pub cpu_manager_reconcile_period: Option<String>,
+
+    /// See [`KubeletConfiguration::enable_contention_profiling`].
+    pub enable_contention_profiling: Option<bool>,
+
+    /// See [`KubeletConfiguration::enable_controller_attach_detach`].
+    pub enable_controller_attach_detach: Option<bool>,
+
+    /// See [`KubeletConfiguration::enable_debug_flags_handler`].
+    pub enable_debug_flags_handler: Option<bool>,
+
+    /// See [`KubeletConfiguration::enable_debugging_handlers`].
+    pub enable_debugging_handlers: Option<bool>,
+
+    /// See [`KubeletConfiguration::enable_profiling_handler`].
+    pub enable_profiling_handler: Option<bool>,
+
+    /// See [`KubeletConfiguration::enable_server`].
+    pub enable_server: Option<bool>,
+
+    /// See [`KubeletConfiguration::enable_system_log_handler`].
+    pub enable_system_log_handler: Option<bool>,
+
+    /// See [`KubeletConfiguration::enforce_node_allocatable`].
+    pub enforce_node_allocatable: Option<Vec<String>>,
+
+    /// See [`KubeletConfiguration::event_burst`].
+    pub event_burst: Option<i32>,
+
+    /// See [`KubeletConfiguration::event_record_qps`].
+    pub event_record_qps: Option<i32>,
+
+    /// See [`KubeletConfiguration::eviction_hard`].
+    pub eviction_hard: Option<std::collections::BTreeMap<String, String>>,
+
+    /// See [`KubeletConfiguration::eviction_max_pod_grace_period`].
+    pub eviction_max_pod_grace_period: Option<i32>,
+
+    /// See [`KubeletConfiguration::eviction_minimum_reclaim`].
+    pub eviction_minimum_reclaim: Option<std::collections::BTreeMap<String, String>>,
+
+    /// See [`KubeletConfiguration::eviction_pressure_transition_period`].
+    pub eviction_pressure_transition_period: Option<String>,
+
+    /// See [`KubeletConfiguration::eviction_soft`].
+    pub eviction_soft: Option<std::collections::BTreeMap<String, String>>,
+
+    /// See [`KubeletConfiguration::eviction_soft_grace_period`].
+    pub eviction_soft_grace_period: Option<std::collections::BTreeMap<String, String>>,
+
+    /// See [`KubeletConfiguration::fail_swap_on`].
+    pub fail_swap_on: Option<bool>,
+
+    /// See [`KubeletConfiguration::feature_gates`].
+    pub feature_gates: Option<std::collections::BTreeMap<String, bool>>,
+
+    /// See [`KubeletConfiguration::file_check_frequency`].
+    pub file_check_frequency: Option<String>,
+
+    /// See [`KubeletConfiguration::hairpin_mode`].
+    pub hairpin_mode: Option<String>,
+
+    /// See [`KubeletConfiguration::healthz_bind_address`].
+    pub healthz_bind_address: Option<String>,
+
+    /// See [`KubeletConfiguration::healthz_port`].
+    pub healthz_port: Option<i32>,
+
+    /// See [`KubeletConfiguration::http_check_frequency`].
+    pub http_check_frequency: Option<String>,
+
+    /// See [`KubeletConfiguration::image_gc_high_threshold_percent`].
+    pub image_gc_high_threshold_percent: Option<i32>,
+
+    /// See [`KubeletConfiguration::image_gc_low_threshold_percent`].
+    pub image_gc_low_threshold_percent: Option<i32>,
+
+    /// See [`KubeletConfiguration::image_minimum_gc_age`].
+    pub image_minimum_gc_age: Option<String>,
+
+    /// See [`KubeletConfiguration::iptables_drop_bit`].
+    pub iptables_drop_bit: Option<i32>,
+
+    /// See [`KubeletConfiguration::iptables_masquerade_bit`].
+    pub iptables_masquerade_bit: Option<i32>,
+
+    /// See [`KubeletConfiguration::kernel_memcg_notification`].
+    pub kernel_memcg_notification: Option<bool>,
+
+    /// See [`KubeletConfiguration::kube_api_burst`].
+    pub kube_api_burst: Option<i32>,
+
+    /// See [`KubeletConfiguration::kube_api_qps`].
+    pub kube_api_qps: Option<i32>,
+
+    /// See [`KubeletConfiguration::kube_reserved`].
+    pub kube_reserved: Option<std::collections::BTreeMap<String, String>>,
+
+    /// See [`KubeletConfiguration::kube_reserved_cgroup`].
+    pub kube_reserved_cgroup: Option<String>,
+
+    /// See [`KubeletConfiguration::kubelet_cgroups`].
+    pub kubelet_cgroups: Option<String>,
+
+    /// See [`KubeletConfiguration::logging`].
+    pub logging: Option<crate::kubelet::config::v1beta1::LoggingConfigurationApplyConfiguration>,
+
+    /// See [`KubeletConfiguration::make_iptables_util_chains`].
+    pub make_iptables_util_chains: Option<bool>,
+
+    /// See [`KubeletConfiguration::max_open_files`].
+    pub max_open_files: Option<i64>,
+
+    /// See [`KubeletConfiguration::max_pods`].
+    pub max_pods: Option<i32>,
+
+    /// See [`KubeletConfiguration::memory_manager_policy`].
+    pub memory_manager_policy: Option<String>,
+
+    /// See [`KubeletConfiguration::memory_swap`].
+    pub memory_swap: Option<crate::kubelet::config::v1beta1::MemorySwapConfigurationApplyConfiguration>,
+
+    /// See [`KubeletConfiguration::memory_throttling_factor`].
+    pub memory_throttling_factor: Option<f64>,
+
+    /// See [`KubeletConfiguration::node_lease_duration_seconds`].
+    pub node_lease_duration_seconds: Option<i32>,
+
+    /// See [`KubeletConfiguration::node_status_max_images`].
+    pub node_status_max_images: Option<i32>,
+
+    /// See [`KubeletConfiguration::node_status_report_frequency`].
+    pub node_status_report_frequency: Option<String>,
+
+    /// See [`KubeletConfiguration::node_status_update_frequency`].
+    pub node_status_update_frequency: Option<String>,
+
+    /// See [`KubeletConfiguration::oom_score_adj`].
+    pub oom_score_adj: Option<i32>,
+
+    /// See [`KubeletConfiguration::pod_cidr`].
+    pub pod_cidr: Option<String>,
+
+    /// See [`KubeletConfiguration::pod_pids_limit`].
+    pub pod_pids_limit: Option<i64>,
+
+    /// See [`KubeletConfiguration::pods_per_core`].
+    pub pods_per_core: Option<i32>,
+
+    /// See [`KubeletConfiguration::port`].
+    pub port: Option<i32>,
+
+    /// See [`KubeletConfiguration::protect_kernel_defaults`].
+    pub protect_kernel_defaults: Option<bool>,
+
+    /// See [`KubeletConfiguration::provider_id`].
+    pub provider_id: Option<String>,
+
+    /// See [`KubeletConfiguration::qos_reserved`].
+    pub qos_reserved: Option<std::collections::BTreeMap<String, String>>,
+
+    /// See [`KubeletConfiguration::read_only_port`].
+    pub read_only_port: Option<i32>,
+
+    /// See [`KubeletConfiguration::registry_burst`].
+    pub registry_burst: Option<i32>,
+
+    /// See [`KubeletConfiguration::registry_pull_qps`].
+    pub registry_pull_qps: Option<i32>,
+
+    /// See [`KubeletConfiguration::reserved_memory`].
+    pub reserved_memory: Option<Vec<crate::kubelet::config::v1beta1::MemoryReservationApplyConfiguration>>,
+
+    /// See [`KubeletConfiguration::reserved_system_cpus`].
+    pub reserved_system_cpus: Option<String>,
+
+    /// See [`KubeletConfiguration::resolv_conf`].
+    pub resolv_conf: Option<String>,
+
+    /// See [`KubeletConfiguration::rotate_certificates`].
+    pub rotate_certificates: Option<bool>,
+
+    /// See [`KubeletConfiguration::run_once`].
+    pub run_once: Option<bool>,
+
+    /// See [`KubeletConfiguration::runtime_request_timeout`].
+    pub runtime_request_timeout: Option<String>,
+
+    /// See [`KubeletConfiguration::seccomp_default`].
+    pub seccomp_default: Option<bool>,
+
+    /// See [`KubeletConfiguration::serialize_image_pulls`].
+    pub serialize_image_pulls: Option<bool>,
+
+    /// See [`KubeletConfiguration::server_tls_bootstrap`].
+    pub server_tls_bootstrap: Option<bool>,
+
+    /// See [`KubeletConfiguration::show_hidden_metrics_for_version`].
+    pub show_hidden_metrics_for_version: Option<String>,
+
+    /// See [`KubeletConfiguration::shutdown_grace_period`].
+    pub shutdown_grace_period: Option<String>,
+
+    /// See [`KubeletConfiguration::shutdown_grace_period_critical_pods`].
+    pub shutdown_grace_period_critical_pods: Option<String>,
+
+    /// See [`KubeletConfiguration::static_pod_path`].
+    pub static_pod_path: Option<String>,
+
+    /// See [`KubeletConfiguration::static_pod_url`].
+    pub static_pod_url: Option<String>,
+
+    /// See [`KubeletConfiguration::static_pod_url_header`].
+    pub static_pod_url_header: Option<std::collections::BTreeMap<String, Vec<String>>>,
+
+    /// See [`KubeletConfiguration::streaming_connection_idle_timeout`].
+    pub streaming_connection_idle_timeout: Option<String>,
+
+    /// See [`KubeletConfiguration::sync_frequency`].
+    pub sync_frequency: Option<String>,
+
+    /// See [`KubeletConfiguration::system_cgroups`].
+    pub system_cgroups: Option<String>,
+
+    /// See [`KubeletConfiguration::system_reserved`].
+    pub system_reserved: Option<std::collections::BTreeMap<String, String>>,
+
+    /// See [`KubeletConfiguration::system_reserved_cgroup`].
+    pub system_reserved_cgroup: Option<String>,
+
+    /// See [`KubeletConfiguration::tls_cert_file`].
+    pub tls_cert_file: Option<String>,
+
+    /// See [`KubeletConfiguration::tls_cipher_suites`].
+    pub tls_cipher_suites: Option<Vec<String>>,
+
+    /// See [`KubeletConfiguration::tls_min_version`].
+    pub tls_min_version: Option<String>,
+
+    /// See [`KubeletConfiguration::tls_private_key_file`].
+    pub tls_private_key_file: Option<String>,
+
+    /// See [`KubeletConfiguration::topology_manager_policy`].
+    pub topology_manager_policy: Option<String>,
+
+    /// See [`KubeletConfiguration::topology_manager_scope`].
+    pub topology_manager_scope: Option<String>,
+
+    /// See [`KubeletConfiguration::volume_plugin_dir`].
+    pub volume_plugin_dir: Option<String>,
+
+    /// See [`KubeletConfiguration::volume_stats_agg_period`].
+    pub volume_stats_agg_period: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for KubeletConfigurationApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            <KubeletConfiguration as crate::Resource>::KIND,
+            2 +
+            self.address.as_ref().map_or(0, |_| 1) +
+            self.allowed_unsafe_sysctls.as_ref().map_or(0, |_| 1) +
+            self.authentication.as_ref().map_or(0, |_| 1) +
+            self.authorization.as_ref().map_or(0, |_| 1) +
+            self.cgroup_driver.as_ref().map_or(0, |_| 1) +
+            self.cgroup_root.as_ref().map_or(0, |_| 1) +
+            self.cgroups_per_qos.as_ref().map_or(0, |_| 1) +
+            self.cluster_dns.as_ref().map_or(0, |_| 1) +
+            self.cluster_domain.as_ref().map_or(0, |_| 1) +
+            self.config_map_and_secret_change_detection_strategy.as_ref().map_or(0, |_| 1) +
+            self.container_log_max_files.as_ref().map_or(0, |_| 1) +
+            self.container_log_max_size.as_ref().map_or(0, |_| 1) +
+            self.content_type.as_ref().map_or(0, |_| 1) +
+            self.cpu_cfs_quota.as_ref().map_or(0, |_| 1) +
+            self.cpu_cfs_quota_period.as_ref().map_or(0, |_| 1) +
+            self.cpu_manager_policy.as_ref().map_or(0, |_| 1) +
+            self.cpu_manager_policy_options.as_ref().map_or(0, |_| 1) +
+            self.cpu_manager_reconcile_period.as_ref().map_or(0, |_| 1) +
+            self.enable_contention_profiling.as_ref().map_or(0, |_| 1) +
+            self.enable_controller_attach_detach.as_ref().map_or(0, |_| 1) +
+            self.enable_debug_flags_handler.as_ref().map_or(0, |_| 1) +
+            self.enable_debugging_handlers.as_ref().map_or(0, |_| 1) +
+            self.enable_profiling_handler.as_ref().map_or(0, |_| 1) +
+            self.enable_server.as_ref().map_or(0, |_| 1) +
+            self.enable_system_log_handler.as_ref().map_or(0, |_| 1) +
+            self.enforce_node_allocatable.as_ref().map_or(0, |_| 1) +
+            self.event_burst.as_ref().map_or(0, |_| 1) +
+            self.event_record_qps.as_ref().map_or(0, |_| 1) +
+            self.eviction_hard.as_ref().map_or(0, |_| 1) +
+            self.eviction_max_pod_grace_period.as_ref().map_or(0, |_| 1) +
+            self.eviction_minimum_reclaim.as_ref().map_or(0, |_| 1) +
+            self.eviction_pressure_transition_period.as_ref().map_or(0, |_| 1) +
+            self.eviction_soft.as_ref().map_or(0, |_| 1) +
+            self.eviction_soft_grace_period.as_ref().map_or(0, |_| 1) +
+            self.fail_swap_on.as_ref().map_or(0, |_| 1) +
+            self.feature_gates.as_ref().map_or(0, |_| 1) +
+            self.file_check_frequency.as_ref().map_or(0, |_| 1) +
+            self.hairpin_mode.as_ref().map_or(0, |_| 1) +
+            self.healthz_bind_address.as_ref().map_or(0, |_| 1) +
+            self.healthz_port.as_ref().map_or(0, |_| 1) +
+            self.http_check_frequency.as_ref().map_or(0, |_| 1) +
+            self.image_gc_high_threshold_percent.as_ref().map_or(0, |_| 1) +
+            self.image_gc_low_threshold_percent.as_ref().map_or(0, |_| 1) +
+            self.image_minimum_gc_age.as_ref().map_or(0, |_| 1) +
+            self.iptables_drop_bit.as_ref().map_or(0, |_| 1) +
+            self.iptables_masquerade_bit.as_ref().map_or(0, |_| 1) +
+            self.kernel_memcg_notification.as_ref().map_or(0, |_| 1) +
+            self.kube_api_burst.as_ref().map_or(0, |_| 1) +
+            self.kube_api_qps.as_ref().map_or(0, |_| 1) +
+            self.kube_reserved.as_ref().map_or(0, |_| 1) +
+            self.kube_reserved_cgroup.as_ref().map_or(0, |_| 1) +
+            self.kubelet_cgroups.as_ref().map_or(0, |_| 1) +
+            self.logging.as_ref().map_or(0, |_| 1) +
+            self.make_iptables_util_chains.as_ref().map_or(0, |_| 1) +
+            self.max_open_files.as_ref().map_or(0, |_| 1) +
+            self.max_pods.as_ref().map_or(0, |_| 1) +
+            self.memory_manager_policy.as_ref().map_or(0, |_| 1) +
+            self.memory_swap.as_ref().map_or(0, |_| 1) +
+            self.memory_throttling_factor.as_ref().map_or(0, |_| 1) +
+            self.node_lease_duration_seconds.as_ref().map_or(0, |_| 1) +
+            self.node_status_max_images.as_ref().map_or(0, |_| 1) +
+            self.node_status_report_frequency.as_ref().map_or(0, |_| 1) +
+            self.node_status_update_frequency.as_ref().map_or(0, |_| 1) +
+            self.oom_score_adj.as_ref().map_or(0, |_| 1) +
+            self.pod_cidr.as_ref().map_or(0, |_| 1) +
+            self.pod_pids_limit.as_ref().map_or(0, |_| 1) +
+            self.pods_per_core.as_ref().map_or(0, |_| 1) +
+            self.port.as_ref().map_or(0, |_| 1) +
+            self.protect_kernel_defaults.as_ref().map_or(0, |_| 1) +
+            self.provider_id.as_ref().map_or(0, |_| 1) +
+            self.qos_reserved.as_ref().map_or(0, |_| 1) +
+            self.read_only_port.as_ref().map_or(0, |_| 1) +
+            self.registry_burst.as_ref().map_or(0, |_| 1) +
+            self.registry_pull_qps.as_ref().map_or(0, |_| 1) +
+            self.reserved_memory.as_ref().map_or(0, |_| 1) +
+            self.reserved_system_cpus.as_ref().map_or(0, |_| 1) +
+            self.resolv_conf.as_ref().map_or(0, |_| 1) +
+            self.rotate_certificates.as_ref().map_or(0, |_| 1) +
+            self.run_once.as_ref().map_or(0, |_| 1) +
+            self.runtime_request_timeout.as_ref().map_or(0, |_| 1) +
+            self.seccomp_default.as_ref().map_or(0, |_| 1) +
+            self.serialize_image_pulls.as_ref().map_or(0, |_| 1) +
+            self.server_tls_bootstrap.as_ref().map_or(0, |_| 1) +
+            self.show_hidden_metrics_for_version.as_ref().map_or(0, |_| 1) +
+            self.shutdown_grace_period.as_ref().map_or(0, |_| 1) +
+            self.shutdown_grace_period_critical_pods.as_ref().map_or(0, |_| 1) +
+            self.static_pod_path.as_ref().map_or(0, |_| 1) +
+            self.static_pod_url.as_ref().map_or(0, |_| 1) +
+            self.static_pod_url_header.as_ref().map_or(0, |_| 1) +
+            self.streaming_connection_idle_timeout.as_ref().map_or(0, |_| 1) +
+            self.sync_frequency.as_ref().map_or(0, |_| 1) +
+            self.system_cgroups.as_ref().map_or(0, |_| 1) +
+            self.system_reserved.as_ref().map_or(0, |_| 1) +
+            self.system_reserved_cgroup.as_ref().map_or(0, |_| 1) +
+            self.tls_cert_file.as_ref().map_or(0, |_| 1) +
+            self.tls_cipher_suites.as_ref().map_or(0, |_| 1) +
+            self.tls_min_version.as_ref().map_or(0, |_| 1) +
+            self.tls_private_key_file.as_ref().map_or(0, |_| 1) +
+            self.topology_manager_policy.as_ref().map_or(0, |_| 1) +
+            self.topology_manager_scope.as_ref().map_or(0, |_| 1) +
+            self.volume_plugin_dir.as_ref().map_or(0, |_| 1) +
+            self.volume_stats_agg_period.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <KubeletConfiguration as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <KubeletConfiguration as crate::Resource>::KIND)?;
+        if let Some(value) = &self.address {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "address", value)?;
+        }
+        if let Some(value) = &self.allowed_unsafe_sysctls {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "allowedUnsafeSysctls", value)?;
+        }
+        if let Some(value) = &self.authentication {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "authentication", value)?;
+        }
+        if let Some(value) = &self.authorization {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "authorization", value)?;
+        }
+        if let Some(value) = &self.cgroup_driver {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cgroupDriver", value)?;
+        }
+        if let Some(value) = &self.cgroup_root {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cgroupRoot", value)?;
+        }
+        if let Some(value) = &self.cgroups_per_qos {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cgroupsPerQOS", value)?;
+        }
+        if let Some(value) = &self.cluster_dns {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clusterDNS", value)?;
+        }
+        if let Some(value) = &self.cluster_domain {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clusterDomain", value)?;
+        }
+        if let Some(value) = &self.config_map_and_secret_change_detection_strategy {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "configMapAndSecretChangeDetectionStrategy", value)?;
+        }
+        if let Some(value) = &self.container_log_max_files {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "containerLogMaxFiles", value)?;
+        }
+        if let Some(value) = &self.container_log_max_size {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "containerLogMaxSize", value)?;
+        }
+        if let Some(value) = &self.content_type {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "contentType", value)?;
+        }
+        if let Some(value) = &self.cpu_cfs_quota {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cpuCFSQuota", value)?;
+        }
+        if let Some(value) = &self.cpu_cfs_quota_period {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cpuCFSQuotaPeriod", value)?;
+        }
+        if let Some(value) = &self.cpu_manager_policy {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cpuManagerPolicy", value)?;
+        }
+        if let Some(value) = &self.cpu_manager_policy_options {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cpuManagerPolicyOptions", value)?;
+        }
+        if let Some(value) = &self.cpu_manager_reconcile_period {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cpuManagerReconcilePeriod", value)?;
+        }
+        if let Some(value) = &self.enable_contention_profiling {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableContentionProfiling", value)?;
+        }
+        if let Some(value) = &self.enable_controller_attach_detach {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableControllerAttachDetach", value)?;
+        }
+        if let Some(value) = &self.enable_debug_flags_handler {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableDebugFlagsHandler", value)?;
+        }
+        if let Some(value) = &self.enable_debugging_handlers {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableDebuggingHandlers", value)?;
+        }
+        if let Some(value) = &self.enable_profiling_handler {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableProfilingHandler", value)?;
+        }
+        if let Some(value) = &self.enable_server {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableServer", value)?;
+        }
+        if let Some(value) = &self.enable_system_log_handler {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enableSystemLogHandler", value)?;
+        }
+        if let Some(value) = &self.enforce_node_allocatable {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "enforceNodeAllocatable", value)?;
+        }
+        if let Some(value) = &self.event_burst {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "eventBurst", value)?;
+        }
+        if let Some(value) = &self.event_record_qps {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "eventRecordQPS", value)?;
+        }
+        if let Some(value) = &self.eviction_hard {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "evictionHard", value)?;
+        }
+        if let Some(value) = &self.eviction_max_pod_grace_period {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "evictionMaxPodGracePeriod", value)?;
+        }
+        if let Some(value) = &self.eviction_minimum_reclaim {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "evictionMinimumReclaim", value)?;
+        }
+        if let Some(value) = &self.eviction_pressure_transition_period {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "evictionPressureTransitionPeriod", value)?;
+        }
+        if let Some(value) = &self.eviction_soft {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "evictionSoft", value)?;
+        }
+        if let Some(value) = &self.eviction_soft_grace_period {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "evictionSoftGracePeriod", value)?;
+        }
+        if let Some(value) = &self.fail_swap_on {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "failSwapOn", value)?;
+        }
+        if let Some(value) = &self.feature_gates {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "featureGates", value)?;
+        }
+        if let Some(value) = &self.file_check_frequency {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fileCheckFrequency", value)?;
+        }
+        if let Some(value) = &self.hairpin_mode {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hairpinMode", value)?;
+        }
+        if let Some(value) = &self.healthz_bind_address {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "healthzBindAddress", value)?;
+        }
+        if let Some(value) = &self.healthz_port {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "healthzPort", value)?;
+        }
+        if let Some(value) = &self.http_check_frequency {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "httpCheckFrequency", value)?;
+        }
+        if let Some(value) = &self.image_gc_high_threshold_percent {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "imageGCHighThresholdPercent", value)?;
+        }
+        if let Some(value) = &self.image_gc_low_threshold_percent {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "imageGCLowThresholdPercent", value)?;
+        }
+        if let Some(value) = &self.image_minimum_gc_age {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "imageMinimumGCAge", value)?;
+        }
+        if let Some(value) = &self.iptables_drop_bit {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "iptablesDropBit", value)?;
+        }
+        if let Some(value) = &self.iptables_masquerade_bit {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "iptablesMasqueradeBit", value)?;
+        }
+        if let Some(value) = &self.kernel_memcg_notification {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kernelMemcgNotification", value)?;
+        }
+        if let Some(value) = &self.kube_api_burst {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kubeAPIBurst", value)?;
+        }
+        if let Some(value) = &self.kube_api_qps {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kubeAPIQPS", value)?;
+        }
+        if let Some(value) = &self.kube_reserved {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kubeReserved", value)?;
+        }
+        if let Some(value) = &self.kube_reserved_cgroup {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kubeReservedCgroup", value)?;
+        }
+        if let Some(value) = &self.kubelet_cgroups {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kubeletCgroups", value)?;
+        }
+        if let Some(value) = &self.logging {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "logging", value)?;
+        }
+        if let Some(value) = &self.make_iptables_util_chains {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "makeIPTablesUtilChains", value)?;
+        }
+        if let Some(value) = &self.max_open_files {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maxOpenFiles", value)?;
+        }
+        if let Some(value) = &self.max_pods {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "maxPods", value)?;
+        }
+        if let Some(value) = &self.memory_manager_policy {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "memoryManagerPolicy", value)?;
+        }
+        if let Some(value) = &self.memory_swap {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "memorySwap", value)?;
+        }
+        if let Some(value) = &self.memory_throttling_factor {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "memoryThrottlingFactor", value)?;
+        }
+        if let Some(value) = &self.node_lease_duration_seconds {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeLeaseDurationSeconds", value)?;
+        }
+        if let Some(value) = &self.node_status_max_images {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeStatusMaxImages", value)?;
+        }
+        if let Some(value) = &self.node_status_report_frequency {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeStatusReportFrequency", value)?;
+        }
+        if let Some(value) = &self.node_status_update_frequency {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeStatusUpdateFrequency", value)?;
+        }
+        if let Some(value) = &self.oom_score_adj {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "oomScoreAdj", value)?;
+        }
+        if let Some(value) = &self.pod_cidr {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "podCIDR", value)?;
+        }
+        if let Some(value) = &self.pod_pids_limit {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "podPidsLimit", value)?;
+        }
+        if let Some(value) = &self.pods_per_core {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "podsPerCore", value)?;
+        }
+        if let Some(value) = &self.port {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "port", value)?;
+        }
+        if let Some(value) = &self.protect_kernel_defaults {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "protectKernelDefaults", value)?;
+        }
+        if let Some(value) = &self.provider_id {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "providerID", value)?;
+        }
+        if let Some(value) = &self.qos_reserved {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "qosReserved", value)?;
+        }
+        if let Some(value) = &self.read_only_port {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnlyPort", value)?;
+        }
+        if let Some(value) = &self.registry_burst {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "registryBurst", value)?;
+        }
+        if let Some(value) = &self.registry_pull_qps {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "registryPullQPS", value)?;
+        }
+        if let Some(value) = &self.reserved_memory {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reservedMemory", value)?;
+        }
+        if let Some(value) = &self.reserved_system_cpus {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reservedSystemCPUs", value)?;
+        }
+        if let Some(value) = &self.resolv_conf {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "resolvConf", value)?;
+        }
+        if let Some(value) = &self.rotate_certificates {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rotateCertificates", value)?;
+        }
+        if let Some(value) = &self.run_once {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "runOnce", value)?;
+        }
+        if let Some(value) = &self.runtime_request_timeout {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "runtimeRequestTimeout", value)?;
+        }
+        if let Some(value) = &self.seccomp_default {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "seccompDefault", value)?;
+        }
+        if let Some(value) = &self.serialize_image_pulls {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "serializeImagePulls", value)?;
+        }
+        if let Some(value) = &self.server_tls_bootstrap {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "serverTLSBootstrap", value)?;
+        }
+        if let Some(value) = &self.show_hidden_metrics_for_version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "showHiddenMetricsForVersion", value)?;
+        }
+        if let Some(value) = &self.shutdown_grace_period {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "shutdownGracePeriod", value)?;
+        }
+        if let Some(value) = &self.shutdown_grace_period_critical_pods {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "shutdownGracePeriodCriticalPods", value)?;
+        }
+        if let Some(value) = &self.static_pod_path {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "staticPodPath", value)?;
+        }
+        if let Some(value) = &self.static_pod_url {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "staticPodURL", value)?;
+        }
+        if let Some(value) = &self.static_pod_url_header {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "staticPodURLHeader", value)?;
+        }
+        if let Some(value) = &self.streaming_connection_idle_timeout {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "streamingConnectionIdleTimeout", value)?;
+        }
+        if let Some(value) = &self.sync_frequency {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "syncFrequency", value)?;
+        }
+        if let Some(value) = &self.system_cgroups {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "systemCgroups", value)?;
+        }
+        if let Some(value) = &self.system_reserved {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "systemReserved", value)?;
+        }
+        if let Some(value) = &self.system_reserved_cgroup {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "systemReservedCgroup", value)?;
+        }
+        if let Some(value) = &self.tls_cert_file {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tlsCertFile", value)?;
+        }
+        if let Some(value) = &self.tls_cipher_suites {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tlsCipherSuites", value)?;
+        }
+        if let Some(value) = &self.tls_min_version {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tlsMinVersion", value)?;
+        }
+        if let Some(value) = &self.tls_private_key_file {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tlsPrivateKeyFile", value)?;
+        }
+        if let Some(value) = &self.topology_manager_policy {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "topologyManagerPolicy", value)?;
+        }
+        if let Some(value) = &self.topology_manager_scope {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "topologyManagerScope", value)?;
+        }
+        if let Some(value) = &self.volume_plugin_dir {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumePluginDir", value)?;
+        }
+        if let Some(value) = &self.volume_stats_agg_period {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeStatsAggPeriod", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for KubeletConfiguration {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "kubelet::config::v1beta1::KubeletConfiguration".to_owned()
+        }
+        else {
+            "io.k8s.kubelet.config.v1beta1.KubeletConfiguration".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("KubeletConfiguration contains the configuration for the Kubelet.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "address".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("address is the IP address for the Kubelet to serve on (set to 0.0.0.0 for all interfaces). Default: \"0.0.0.0\"".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "allowedUnsafeSysctls".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("A comma separated whitelist of unsafe sysctls or sysctl patterns (ending in `*`). Unsafe sysctl groups are `kernel.shm*`, `kernel.msg*`, `kernel.sem`, `fs.mqueue.*`, and `net.*`. For example: \"`kernel.msg*,net.ipv4.route.min_pmtu`\" Default: []".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Array))),
+                            array: Some(Box::new(crate::schemars::schema::ArrayValidation {
+                                items: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(
+                                    crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                                        instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                                        ..Default::default()
+                                    })
+                                ))),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "apiVersion".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#resources".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("kubelet.config.k8s.io/v1beta1".to_owned())]),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "authentication".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::kubelet::config::v1beta1::KubeletAuthentication>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("authentication specifies how requests to the Kubelet's server are authenticated.".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
+                        "authorization".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::kubelet::config::v1beta1::KubeletAuthorization>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("authorization specifies how requests to the Kubelet's server are authorized.".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
+                        "cgroupDriver".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("cgroupDriver is the driver kubelet uses to manipulate CGroups on the host (cgroupfs or systemd). Default: \"cgroupfs\"".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "cgroupRoot".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("cgroupRoot is the root cgroup to use for pods. This is handled by the container runtime on a best effort basis.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "cgroupsPerQOS".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("cgroupsPerQOS enable QoS based CGroup hierarchy: top level CGroups for QoS classes and all Burstable and BestEffort Pods are brought up under their specific top level QoS CGroup. Default: true".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "clusterDNS".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("clusterDNS is a list of IP addresses for the cluster DNS server. If set, kubelet will configure all containers to use this for DNS resolution instead of the host's DNS servers. Default: nil".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Array))),
+                            array: Some(Box::new(crate::schemars::schema::ArrayValidation {
+                                items: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(
+                                    crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                                        instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                                        ..Default::default()
+                                    })
+                                ))),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "clusterDomain".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("clusterDomain is the DNS domain for this cluster. If set, kubelet will configure all containers to search this domain in addition to the host's search domains. Default: \"\"".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "configMapAndSecretChangeDetectionStrategy".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("configMapAndSecretChangeDetectionStrategy is a mode in which ConfigMap and Secret managers are running. Valid values include: `Get`, `Cache` and `Watch`. Default: \"Watch\"".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "containerLogMaxFiles".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("containerLogMaxFiles specifies the maximum number of container log files that can be present for a container. Default: 5".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int32".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "containerLogMaxSize".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("containerLogMaxSize is a quantity defining the maximum size of the container log file before it is rotated. For example: \"5Mi\" or \"256Ki\". Default: \"10Mi\"".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "contentType".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("contentType is contentType of requests sent to apiserver. Default: \"application/vnd.kubernetes.protobuf\"".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "cpuCFSQuota".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("cpuCFSQuota enables CPU CFS quota enforcement for containers that specify CPU limits. Default: true".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "cpuCFSQuotaPeriod".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("cpuCFSQuotaPeriod is the CPU CFS quota period value, `cpu.cfs_period_us`. The value must be between 1 us and 1 second, inclusive. Requires the CustomCPUCFSQuotaPeriod feature gate to be enabled. Default: \"100ms\"\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "cpuManagerPolicy".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("cpuManagerPolicy is the name of the policy to use. Requires the CPUManager feature gate to be enabled. Default: \"None\"".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "cpuManagerPolicyOptions".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("cpuManagerPolicyOptions is a set of key=value which allows to set extra options to fine tune the behaviour of the cpu manager policies. Requires both the \"CPUManager\" and \"CPUManagerPolicyOptions\" feature gates to be enabled. Default: nil".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+                            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                                additional_properties: Some(Box::new(
+                                    crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                                        instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                                        ..Default::default()
+                                    })
+                                )),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "cpuManagerReconcilePeriod".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("cpuManagerReconcilePeriod is the reconciliation period for the CPU Manager. Requires the CPUManager feature gate to be enabled. Default: \"10s\"\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "enableContentionProfiling".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("enableContentionProfiling enables lock contention profiling, if enableDebuggingHandlers is true. Default: false".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "enableControllerAttachDetach".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("enableControllerAttachDetach enables the Attach/Detach controller to manage attachment/detachment of volumes scheduled to this node, and disables kubelet from executing any attach/detach operations. Default: true".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "enableDebugFlagsHandler".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("enableDebugFlagsHandler enables flags endpoint via web interface host:port/debug/flags/v Default: true".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "enableDebuggingHandlers".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("enableDebuggingHandlers enables server endpoints for log access and local running of containers and commands, including the exec, attach, logs, and portforward features. Default: true".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "enableProfilingHandler".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("enableProfilingHandler enables profiling via web interface host:port/debug/pprof/ Default: true".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "enableServer".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("enableServer enables the kubelet's secured server. Note: the kubelet's insecure port is controlled by the readOnlyPort option. Default: true".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "enableSystemLogHandler".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("enableSystemLogHandler enables system logs via web interface host:port/logs/ Default: true".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "enforceNodeAllocatable".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("This flag specifies the various Node Allocatable enforcements that Kubelet needs to perform. This flag accepts a list of options. Acceptable options are `none`, `pods`, `system-reserved` and `kube-reserved`. Default: [\"pods\"]".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Array))),
+                            array: Some(Box::new(crate::schemars::schema::ArrayValidation {
+                                items: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(
+                                    crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                                        instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                                        ..Default::default()
+                                    })
+                                ))),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "eventBurst".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("eventBurst is the maximum size of a burst of event creations, temporarily allows event creations to burst to this number, while still not exceeding eventRecordQPS. This field canot be a negative number and it is only used when eventRecordQPS > 0. Default: 10".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int32".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "eventRecordQPS".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("eventRecordQPS is the maximum event creations per second. If 0, there is no limit enforced. The value cannot be a negative number. Default: 5".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int32".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "evictionHard".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("evictionHard is a map of signal names to quantities that defines hard eviction thresholds. For example: `{\"memory.available\": \"300Mi\"}`. To explicitly disable, pass a 0% or 100% threshold on an arbitrary resource. Default: memory.available: \"100Mi\", nodefs.available: \"10%\", nodefs.inodesFree: \"5%\", imagefs.available: \"15%\"".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+                            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                                additional_properties: Some(Box::new(
+                                    crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                                        instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                                        ..Default::default()
+                                    })
+                                )),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "evictionMaxPodGracePeriod".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("evictionMaxPodGracePeriod is the maximum allowed grace period (in seconds) to use when terminating pods in response to a soft eviction threshold being met. This value effectively caps the Pod's terminationGracePeriodSeconds value during soft evictions. Default: 0".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int32".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "evictionMinimumReclaim".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("evictionMinimumReclaim is a map of signal names to quantities that defines minimum reclaims, which describe the minimum amount of a given resource the kubelet will reclaim when performing a pod eviction while that resource is under pressure. For example: `{\"imagefs.available\": \"2Gi\"}`. Default: nil".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+                            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                                additional_properties: Some(Box::new(
+                                    crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                                        instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                                        ..Default::default()
+                                    })
+                                )),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "evictionPressureTransitionPeriod".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("evictionPressureTransitionPeriod is the duration for which the kubelet has to wait before transitioning out of an eviction pressure condition. Default: \"5m\"\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "evictionSoft".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("evictionSoft is a map of signal names to quantities that defines soft eviction thresholds. For example: `{\"memory.available\": \"300Mi\"}`. Default: nil".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+                            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                                additional_properties: Some(Box::new(
+                                    crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                                        instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                                        ..Default::default()
+                                    })
+                                )),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "evictionSoftGracePeriod".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("evictionSoftGracePeriod is a map of signal names to quantities that defines grace periods for each soft eviction signal. For example: `{\"memory.available\": \"30s\"}`. Default: nil".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+                            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                                additional_properties: Some(Box::new(
+                                    crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                                        instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                                        ..Default::default()
+                                    })
+                                )),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "failSwapOn".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("failSwapOn tells the Kubelet to fail to start if swap is enabled on the node. Default: true".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "featureGates".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("featureGates is a map of feature names to bools that enable or disable experimental features. This field modifies piecemeal the built-in default values from \"k8s.io/kubernetes/pkg/features/kube_features.go\". Default: nil".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+                            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                                additional_properties: Some(Box::new(
+                                    crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                                        instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                                        ..Default::default()
+                                    })
+                                )),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "fileCheckFrequency".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("fileCheckFrequency is the duration between checking config files for new data. Default: \"20s\"\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "hairpinMode".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("hairpinMode specifies how the Kubelet should configure the container bridge for hairpin packets. Setting this flag allows endpoints in a Service to loadbalance back to themselves if they should try to access their own Service. Values: `promiscuous-bridge`, `hairpin-veth` and `none`. Default: \"promiscuous-bridge\"".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "healthzBindAddress".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("healthzBindAddress is the IP address for the healthz server to serve on. Default: \"127.0.0.1\"".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "healthzPort".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("healthzPort is the port of the localhost healthz endpoint (set to 0 to disable). A valid number is between 1 and 65535. Default: 10248".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int32".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "httpCheckFrequency".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("httpCheckFrequency is the duration between checking http for new data. Default: \"20s\"\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "imageGCHighThresholdPercent".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("imageGCHighThresholdPercent is the percent of disk usage after which image garbage collection is always run. The percent is calculated by dividing this field value by 100, so this field must be between 0 and 100, inclusive. Default: 85".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int32".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "imageGCLowThresholdPercent".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("imageGCLowThresholdPercent is the percent of disk usage before which image garbage collection is never run. Lowest disk usage to garbage collect to. The percent is calculated by dividing this field value by 100, so the field value must be between 0 and 100, inclusive. Default: 80".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int32".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "imageMinimumGCAge".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("imageMinimumGCAge is the minimum age for an unused image before it is garbage collected. Default: \"2m\"\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "iptablesDropBit".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("iptablesDropBit is the bit of the iptables fwmark space to mark for dropping packets. Values must be within the range [0, 31]. Must be different from other mark bits. Default: 15".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int32".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "iptablesMasqueradeBit".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("iptablesMasqueradeBit is the bit of the iptables fwmark space to mark for SNAT. Values must be within the range [0, 31]. Must be different from other mark bits. Warning: Please match the value of the corresponding parameter in kube-proxy. Default: 14".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int32".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "kernelMemcgNotification".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("kernelMemcgNotification, if set, instructs the the kubelet to integrate with the kernel memcg notification for determining if memory eviction thresholds are exceeded rather than polling. Default: false".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "kind".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#types-kinds".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("KubeletConfiguration".to_owned())]),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "kubeAPIBurst".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("kubeAPIBurst is the burst to allow while talking with kubernetes API server. This field cannot be a negative number. Default: 10".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int32".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "kubeAPIQPS".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("kubeAPIQPS is the QPS to use while talking with kubernetes apiserver. Default: 5".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int32".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "kubeReserved".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("kubeReserved is a set of ResourceName=ResourceQuantity (e.g. cpu=200m,memory=150G) pairs that describe resources reserved for kubernetes system components. Currently cpu, memory and local storage for root file system are supported. Default: nil".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+                            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                                additional_properties: Some(Box::new(
+                                    crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                                        instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                                        ..Default::default()
+                                    })
+                                )),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "kubeReservedCgroup".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("kubeReservedCgroup helps the kubelet identify absolute name of top level CGroup used to enforce `KubeReserved` compute resource reservation for Kubernetes node system daemons. Default: \"\"".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "kubeletCgroups".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("kubeletCgroups is the absolute name of cgroups to isolate the kubelet in Default: \"\"".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "logging".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::kubelet::config::v1beta1::LoggingConfiguration>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("logging specifies the options of logging.".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
+                        "makeIPTablesUtilChains".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("makeIPTablesUtilChains, if true, causes the Kubelet ensures a set of iptables rules are present on host. These rules will serve as utility rules for various components, e.g. kube-proxy. The rules will be created based on iptablesMasqueradeBit and iptablesDropBit. Default: true".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "maxOpenFiles".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("maxOpenFiles is Number of files that can be opened by Kubelet process. The value must be a non-negative number. Default: 1000000".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int64".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "maxPods".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("maxPods is the maximum number of Pods that can run on this Kubelet. The value must be a non-negative integer. Default: 110".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int32".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "memoryManagerPolicy".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("memoryManagerPolicy is the name of memory manager policy to use. Requires the MemoryManager feature gate to be enabled. Default: \"none\"".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "memorySwap".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::kubelet::config::v1beta1::MemorySwapConfiguration>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("memorySwap configures swap memory available to container workloads.".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
+                        "memoryThrottlingFactor".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("MemoryThrottlingFactor specifies the factor multiplied by the memory limit or node allocatable memory when setting the cgroupv2 memory.high value to enforce MemoryQoS. Decreasing this factor will set lower high limit for container cgroups and put heavier reclaim pressure while increasing will put less reclaim pressure. Default: 0.8".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Number))),
+                            format: Some("double".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "nodeLeaseDurationSeconds".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("nodeLeaseDurationSeconds is the duration the Kubelet will set on its corresponding Lease. Default: 40".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int32".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "nodeStatusMaxImages".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("nodeStatusMaxImages caps the number of images reported in Node.status.images. The value must be greater than -2. Note: If -1 is specified, no cap will be applied. If 0 is specified, no image is returned. Default: 50".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int32".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "nodeStatusReportFrequency".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("nodeStatusReportFrequency is the frequency that kubelet posts node status to master if node status does not change. Kubelet will ignore this frequency and post node status immediately if any change is detected. Default: \"5m\"\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "nodeStatusUpdateFrequency".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("nodeStatusUpdateFrequency is the frequency that kubelet computes node status. If node lease feature is not enabled, it is also the frequency that kubelet posts node status to master. Default: \"10s\"\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "oomScoreAdj".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("oomScoreAdj is The oom-score-adj value for kubelet process. Values must be within the range [-1000, 1000]. Default: -999".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int32".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "podCIDR".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("podCIDR is the CIDR to use for pod IP addresses, only used in standalone mode. In cluster mode, this is obtained from the control plane. Default: \"\"".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "podPidsLimit".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("podPidsLimit is the maximum number of PIDs in any pod. Default: -1".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int64".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "podsPerCore".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("podsPerCore is the maximum number of pods per core. Cannot exceed maxPods. The value must be a non-negative integer. If 0, there is no limit on the number of Pods. Default: 0".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int32".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "port".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("port is the port for the Kubelet to serve on. Default: 10250".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int32".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "protectKernelDefaults".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("protectKernelDefaults, if true, causes the Kubelet to error if kernel flags are not as it expects. Otherwise the Kubelet will attempt to modify kernel flags to match its expectation. Default: false".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "providerID".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("providerID, if set, sets the unique ID of the instance that an external provider (i.e. cloudprovider) can use to identify a specific node. Default: \"\"".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "qosReserved".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("qosReserved is a set of resource name to percentage pairs that specify the minimum percentage of a resource reserved for exclusive use by the guaranteed QoS tier. Currently supported resources: \"memory\". Requires the QOSReserved feature gate to be enabled. Default: nil".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+                            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                                additional_properties: Some(Box::new(
+                                    crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                                        instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                                        ..Default::default()
+                                    })
+                                )),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "readOnlyPort".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("readOnlyPort is the read-only port for the Kubelet to serve on with no authentication/authorization. The port number must be between 1 and 65535, inclusive. Setting this field to 0 disables the read-only service. Default: 0 (disabled)".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int32".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "registryBurst".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("registryBurst is the maximum size of bursty pulls, temporarily allows pulls to burst to this number, while still not exceeding registryPullQPS. The value must not be a negative number. Only used if registryPullQPS is greater than 0. Default: 10".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int32".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "registryPullQPS".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("registryPullQPS is the limit of registry pulls per second. The value must not be a negative number. Setting it to 0 means no limit. Default: 5".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int32".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "reservedMemory".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("reservedMemory specifies a comma-separated list of memory reservations for NUMA nodes. The parameter makes sense only in the context of the memory manager feature. Default: nil".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Array))),
+                            array: Some(Box::new(crate::schemars::schema::ArrayValidation {
+                                items: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(__gen.subschema_for::<crate::kubelet::config::v1beta1::MemoryReservation>()))),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "reservedSystemCPUs".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("The reservedSystemCPUs option specifies the CPU list reserved for the host level system threads and kubernetes related threads. This provide a \"static\" CPU list rather than the \"dynamic\" list by systemReserved and kubeReserved. This option does not support systemReservedCgroup or kubeReservedCgroup.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "resolvConf".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("resolvConf is the resolver configuration file used as the basis for the container DNS resolution configuration. If set to the empty string, will override the default and effectively disable DNS lookups. Default: \"/etc/resolv.conf\"".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "rotateCertificates".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("rotateCertificates enables client certificate rotation. The Kubelet will request a new certificate from the certificates.k8s.io API. This requires an approver to approve the certificate signing requests. Default: false".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "runOnce".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("runOnce causes the Kubelet to check the API server once for pods, run those in addition to the pods specified by static pod files, and exit. Default: false".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "runtimeRequestTimeout".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("runtimeRequestTimeout is the timeout for all runtime requests except long running requests - pull, logs, exec and attach. Default: \"2m\"\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "seccompDefault".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("SeccompDefault enables the use of `RuntimeDefault` as the default seccomp profile for all workloads. This requires the corresponding SeccompDefault feature gate to be enabled as well. Default: false".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "serializeImagePulls".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("serializeImagePulls when enabled, tells the Kubelet to pull images one at a time. We recommend *not* changing the default value on nodes that run docker daemon with version  < 1.9 or an Aufs storage backend. Default: true".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "serverTLSBootstrap".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("serverTLSBootstrap enables server certificate bootstrap. Instead of self signing a serving certificate, the Kubelet will request a certificate from the 'certificates.k8s.io' API. This requires an approver to approve the certificate signing requests (CSR). Default: false".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "showHiddenMetricsForVersion".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("showHiddenMetricsForVersion is the previous version for which you want to show hidden metrics. Only the previous minor version is meaningful, other values will not be allowed. The format is `<major>.<minor>`, e.g.: `1.16`. Default: \"\"".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "shutdownGracePeriod".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("shutdownGracePeriod specifies the total duration that the node should delay the shutdown and total grace period for pod termination during a node shutdown. Default: \"0s\"\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "shutdownGracePeriodCriticalPods".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("shutdownGracePeriodCriticalPods specifies the duration used to terminate critical pods during a node shutdown. This should be less than shutdownGracePeriod. Default: \"0s\"\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "staticPodPath".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("staticPodPath is the path to the directory containing local (static) pods to run, or the path to a single static pod file. Default: \"\"".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "staticPodURL".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("staticPodURL is the URL for accessing static pods to run. Default: \"\"".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "staticPodURLHeader".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("staticPodURLHeader is a map of slices with HTTP headers to use when accessing the podURL. Default: nil".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+                            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                                additional_properties: Some(Box::new(
+                                    crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                                        instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Array))),
+                                        array: Some(Box::new(crate::schemars::schema::ArrayValidation {
+                                            items: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(
+                                                crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                                                    instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                                                    ..Default::default()
+                                                })
+                                            ))),
+                                            ..Default::default()
+                                        })),
+                                        ..Default::default()
+                                    })
+                                )),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "streamingConnectionIdleTimeout".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("streamingConnectionIdleTimeout is the maximum time a streaming connection can be idle before the connection is automatically closed. Default: \"4h\"\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "syncFrequency".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("syncFrequency is the max period between synchronizing running containers and config. Default: \"1m\"\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "systemCgroups".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("systemCgroups is absolute name of cgroups in which to place all non-kernel processes that are not already in a container. Empty for no container. Rolling back the flag requires a reboot. Default: \"\"".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "systemReserved".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("systemReserved is a set of ResourceName=ResourceQuantity (e.g. cpu=200m,memory=150G) pairs that describe resources reserved for non-kubernetes components. Currently only cpu and memory are supported. Default: nil".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+                            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                                additional_properties: Some(Box::new(
+                                    crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                                        instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                                        ..Default::default()
+                                    })
+                                )),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "systemReservedCgroup".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("systemReservedCgroup helps the kubelet identify absolute name of top level CGroup used to enforce `systemReserved` compute resource reservation for OS system daemons. Default: \"\"".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "tlsCertFile".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("tlsCertFile is the file containing x509 Certificate for HTTPS. If tlsCertFile and tlsPrivateKeyFile are not provided, a self-signed certificate and key are generated for the public address and saved to the directory passed to the Kubelet's --cert-dir flag. Default: \"\"".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "tlsCipherSuites".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("TLSCipherSuites is the list of allowed cipher suites for the server. Values are from tls package constants (https://golang.org/pkg/crypto/tls/#pkg-constants). Default: nil".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Array))),
+                            array: Some(Box::new(crate::schemars::schema::ArrayValidation {
+                                items: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(
+                                    crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                                        instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                                        ..Default::default()
+                                    })
+                                ))),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "tlsMinVersion".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("TLSMinVersion is the minimum TLS version supported. Values are from tls package constants (https://golang.org/pkg/crypto/tls/#pkg-constants). Default: \"\"".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "tlsPrivateKeyFile".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("tlsPrivateKeyFile is the file containing x509 private key matching tlsCertFile. Default: \"\"".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "topologyManagerPolicy".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("topologyManagerPolicy is the name of the topology manager policy to use. Valid values include: `restricted`, `best-effort`, `none` and `single-numa-node`. Default: \"none\"".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "topologyManagerScope".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("topologyManagerScope represents the scope of topology hint generation that topology manager requests and hint providers generate. Valid values include: `container` and `pod`. Default: \"container\"".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "volumePluginDir".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("volumePluginDir is the full path of the directory in which to search for additional third party volume plugins. Default: \"/usr/libexec/kubernetes/kubelet-plugins/volume/exec/\"".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "volumeStatsAggPeriod".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("volumeStatsAggPeriod is the frequency for calculating and caching volume disk usage for all pods. Default: \"1m\"\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                required: std::array::IntoIter::new([
+                    "apiVersion",
+                    "kind",
+                ]).map(std::borrow::ToOwned::to_owned).collect(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for KubeletConfiguration {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(KubeletConfiguration {
+            address: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            allowed_unsafe_sysctls: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            authentication: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            authorization: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            cgroup_driver: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            cgroup_root: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            cgroups_per_qos: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            cluster_dns: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            cluster_domain: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            config_map_and_secret_change_detection_strategy: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            container_log_max_files: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            container_log_max_size: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            content_type: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            cpu_cfs_quota: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            cpu_cfs_quota_period: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            cpu_manager_policy: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            cpu_manager_policy_options: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            cpu_manager_reconcile_period: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            enable_contention_profiling: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            enable_controller_attach_detach: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            enable_debug_flags_handler: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            enable_debugging_handlers: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            enable_profiling_handler: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            enable_server: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            enable_system_log_handler: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            enforce_node_allocatable: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            event_burst: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            event_record_qps: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            eviction_hard: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            eviction_max_pod_grace_period: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            eviction_minimum_reclaim: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            eviction_pressure_transition_period: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            eviction_soft: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            eviction_soft_grace_period: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            fail_swap_on: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            feature_gates: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            file_check_frequency: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            hairpin_mode: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            healthz_bind_address: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            healthz_port: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            http_check_frequency: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            image_gc_high_threshold_percent: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            image_gc_low_threshold_percent: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            image_minimum_gc_age: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            iptables_drop_bit: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            iptables_masquerade_bit: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            kernel_memcg_notification: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            kube_api_burst: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            kube_api_qps: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            kube_reserved: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            kube_reserved_cgroup: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            kubelet_cgroups: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            logging: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            make_iptables_util_chains: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            max_open_files: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            max_pods: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            memory_manager_policy: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            memory_swap: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            memory_throttling_factor: crate::arbitrary2::option(u, crate::arbitrary2::json_f64)?,
+            node_lease_duration_seconds: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            node_status_max_images: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            node_status_report_frequency: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            node_status_update_frequency: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            oom_score_adj: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            pod_cidr: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            pod_pids_limit: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            pods_per_core: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            port: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            protect_kernel_defaults: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            provider_id: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            qos_reserved: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            read_only_port: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            registry_burst: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            registry_pull_qps: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            reserved_memory: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            reserved_system_cpus: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            resolv_conf: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            rotate_certificates: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            run_once: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            runtime_request_timeout: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            seccomp_default: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            serialize_image_pulls: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            server_tls_bootstrap: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            show_hidden_metrics_for_version: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            shutdown_grace_period: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            shutdown_grace_period_critical_pods: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            static_pod_path: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            static_pod_url: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            static_pod_url_header: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)))?,
+            streaming_connection_idle_timeout: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            sync_frequency: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            system_cgroups: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            system_reserved: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            system_reserved_cgroup: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            tls_cert_file: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            tls_cipher_suites: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            tls_min_version: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            tls_private_key_file: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            topology_manager_policy: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            topology_manager_scope: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            volume_plugin_dir: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            volume_stats_agg_period: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
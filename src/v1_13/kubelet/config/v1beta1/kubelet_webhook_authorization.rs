@@ -0,0 +1,286 @@
+// Generated from definition io.k8s.kubelet.config.v1beta1.KubeletWebhookAuthorization
+
+/// KubeletWebhookAuthorization configures how the kubelet server caches the results of `Webhook` authorization.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct KubeletWebhookAuthorization {
+    /// cacheAuthorizedTTL is the duration to cache 'authorized' responses from the webhook authorizer.
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub cache_authorized_ttl: Option<String>,
+
+    /// cacheUnauthorizedTTL is the duration to cache 'unauthorized' responses from the webhook authorizer.
+    ///
+    /// This is a duration in Go's format, such as `1m30s`.
+    pub cache_unauthorized_ttl: Option<String>,
+}
+
+impl KubeletWebhookAuthorization {
+    /// Returns this value with [`KubeletWebhookAuthorization::cache_authorized_ttl`] set to the given value.
+    #[must_use]
+    pub fn with_cache_authorized_ttl(mut self, value: impl Into<String>) -> Self {
+        self.cache_authorized_ttl = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`KubeletWebhookAuthorization::cache_unauthorized_ttl`] set to the given value.
+    #[must_use]
+    pub fn with_cache_unauthorized_ttl(mut self, value: impl Into<String>) -> Self {
+        self.cache_unauthorized_ttl = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for KubeletWebhookAuthorization {}
+
+impl KubeletWebhookAuthorization {
+    const FIELDS: &'static [&'static str] = &[
+        "cacheAuthorizedTTL",
+        "cacheUnauthorizedTTL",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for KubeletWebhookAuthorization {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = KubeletWebhookAuthorization;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("KubeletWebhookAuthorization")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_cache_authorized_ttl: Option<String> = None;
+                let mut value_cache_unauthorized_ttl: Option<String> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("KubeletWebhookAuthorization", KubeletWebhookAuthorization::FIELDS))? {
+                    match key {
+                        Some(0) => value_cache_authorized_ttl = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_cache_unauthorized_ttl = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(KubeletWebhookAuthorization {
+                    cache_authorized_ttl: value_cache_authorized_ttl,
+                    cache_unauthorized_ttl: value_cache_unauthorized_ttl,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(KubeletWebhookAuthorization {
+                    cache_authorized_ttl: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    cache_unauthorized_ttl: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "KubeletWebhookAuthorization",
+            KubeletWebhookAuthorization::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, KubeletWebhookAuthorization> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("KubeletWebhookAuthorization")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_cache_authorized_ttl = false;
+                let mut has_cache_unauthorized_ttl = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("KubeletWebhookAuthorization", KubeletWebhookAuthorization::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.cache_authorized_ttl))?; has_cache_authorized_ttl = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.cache_unauthorized_ttl))?; has_cache_unauthorized_ttl = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_cache_authorized_ttl {
+                    self.0.cache_authorized_ttl = None;
+                }
+                if !has_cache_unauthorized_ttl {
+                    self.0.cache_unauthorized_ttl = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.cache_authorized_ttl))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.cache_unauthorized_ttl))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "KubeletWebhookAuthorization",
+            KubeletWebhookAuthorization::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for KubeletWebhookAuthorization {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("KubeletWebhookAuthorization", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cacheAuthorizedTTL", &self.cache_authorized_ttl)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cacheUnauthorizedTTL", &self.cache_unauthorized_ttl)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            "KubeletWebhookAuthorization",
+            self.cache_authorized_ttl.as_ref().map_or(0, |_| 1) +
+            self.cache_unauthorized_ttl.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.cache_authorized_ttl {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cacheAuthorizedTTL", value)?;
+        }
+        if let Some(value) = &self.cache_unauthorized_ttl {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cacheUnauthorizedTTL", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`KubeletWebhookAuthorization`]. Created with [`KubeletWebhookAuthorization::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct KubeletWebhookAuthorizationBuilder {
+    cache_authorized_ttl: Option<String>,
+    cache_unauthorized_ttl: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl KubeletWebhookAuthorization {
+    /// Starts building a `KubeletWebhookAuthorization`. Its required fields must be set before [`KubeletWebhookAuthorizationBuilder::build`] is called.
+    pub fn builder() -> KubeletWebhookAuthorizationBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl KubeletWebhookAuthorizationBuilder {
+    /// Sets [`KubeletWebhookAuthorization::cache_authorized_ttl`].
+    pub fn cache_authorized_ttl(mut self, value: impl Into<String>) -> Self {
+        self.cache_authorized_ttl = Some(value.into());
+        self
+    }
+
+    /// Sets [`KubeletWebhookAuthorization::cache_unauthorized_ttl`].
+    pub fn cache_unauthorized_ttl(mut self, value: impl Into<String>) -> Self {
+        self.cache_unauthorized_ttl = Some(value.into());
+        self
+    }
+
+    /// Builds the `KubeletWebhookAuthorization`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<KubeletWebhookAuthorization, crate::MissingFieldError> {
+        Ok(KubeletWebhookAuthorization {
+            cache_authorized_ttl: self.cache_authorized_ttl,
+            cache_unauthorized_ttl: self.cache_unauthorized_ttl,
+        })
+    }
+}
+
+/// The apply configuration of [`KubeletWebhookAuthorization`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KubeletWebhookAuthorizationApplyConfiguration {
+    /// See [`KubeletWebhookAuthorization::cache_authorized_ttl`].
+    pub cache_authorized_ttl: Option<String>,
+
+    /// See [`KubeletWebhookAuthorization::cache_unauthorized_ttl`].
+    pub cache_unauthorized_ttl: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for KubeletWebhookAuthorizationApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "KubeletWebhookAuthorizationApplyConfiguration",
+            self.cache_authorized_ttl.as_ref().map_or(0, |_| 1) +
+            self.cache_unauthorized_ttl.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.cache_authorized_ttl {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cacheAuthorizedTTL", value)?;
+        }
+        if let Some(value) = &self.cache_unauthorized_ttl {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "cacheUnauthorizedTTL", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for KubeletWebhookAuthorization {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "kubelet::config::v1beta1::KubeletWebhookAuthorization".to_owned()
+        }
+        else {
+            "io.k8s.kubelet.config.v1beta1.KubeletWebhookAuthorization".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("KubeletWebhookAuthorization configures how the kubelet server caches the results of `Webhook` authorization.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "cacheAuthorizedTTL".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("cacheAuthorizedTTL is the duration to cache 'authorized' responses from the webhook authorizer.\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "cacheUnauthorizedTTL".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("cacheUnauthorizedTTL is the duration to cache 'unauthorized' responses from the webhook authorizer.\n\nThis is a duration in Go's format, such as `1m30s`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for KubeletWebhookAuthorization {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(KubeletWebhookAuthorization {
+            cache_authorized_ttl: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            cache_unauthorized_ttl: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
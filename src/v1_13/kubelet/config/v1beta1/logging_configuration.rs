@@ -0,0 +1,282 @@
+// Generated from definition io.k8s.kubelet.config.v1beta1.LoggingConfiguration
+
+/// LoggingConfiguration contains logging options.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct LoggingConfiguration {
+    /// format flag specifies the structure of log messages. The default value of format is `text`.
+    pub format: Option<String>,
+
+    /// sanitization \[Experimental\] when enabled prevents logging of fields tagged as sensitive (passwords, keys, tokens). Runtime log sanitization may introduce significant computation overhead and therefore should be disabled in production.
+    pub sanitization: Option<bool>,
+}
+
+impl LoggingConfiguration {
+    /// Returns this value with [`LoggingConfiguration::format`] set to the given value.
+    #[must_use]
+    pub fn with_format(mut self, value: impl Into<String>) -> Self {
+        self.format = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`LoggingConfiguration::sanitization`] set to the given value.
+    #[must_use]
+    pub fn with_sanitization(mut self, value: impl Into<bool>) -> Self {
+        self.sanitization = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for LoggingConfiguration {}
+
+impl LoggingConfiguration {
+    const FIELDS: &'static [&'static str] = &[
+        "format",
+        "sanitization",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for LoggingConfiguration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = LoggingConfiguration;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("LoggingConfiguration")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_format: Option<String> = None;
+                let mut value_sanitization: Option<bool> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("LoggingConfiguration", LoggingConfiguration::FIELDS))? {
+                    match key {
+                        Some(0) => value_format = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_sanitization = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(LoggingConfiguration {
+                    format: value_format,
+                    sanitization: value_sanitization,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(LoggingConfiguration {
+                    format: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    sanitization: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "LoggingConfiguration",
+            LoggingConfiguration::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, LoggingConfiguration> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("LoggingConfiguration")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_format = false;
+                let mut has_sanitization = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("LoggingConfiguration", LoggingConfiguration::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.format))?; has_format = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.sanitization))?; has_sanitization = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_format {
+                    self.0.format = None;
+                }
+                if !has_sanitization {
+                    self.0.sanitization = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.format))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.sanitization))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "LoggingConfiguration",
+            LoggingConfiguration::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for LoggingConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("LoggingConfiguration", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "format", &self.format)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "sanitization", &self.sanitization)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            "LoggingConfiguration",
+            self.format.as_ref().map_or(0, |_| 1) +
+            self.sanitization.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.format {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "format", value)?;
+        }
+        if let Some(value) = &self.sanitization {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "sanitization", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`LoggingConfiguration`]. Created with [`LoggingConfiguration::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct LoggingConfigurationBuilder {
+    format: Option<String>,
+    sanitization: Option<bool>,
+}
+
+#[cfg(feature = "builder")]
+impl LoggingConfiguration {
+    /// Starts building a `LoggingConfiguration`. Its required fields must be set before [`LoggingConfigurationBuilder::build`] is called.
+    pub fn builder() -> LoggingConfigurationBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl LoggingConfigurationBuilder {
+    /// Sets [`LoggingConfiguration::format`].
+    pub fn format(mut self, value: impl Into<String>) -> Self {
+        self.format = Some(value.into());
+        self
+    }
+
+    /// Sets [`LoggingConfiguration::sanitization`].
+    pub fn sanitization(mut self, value: impl Into<bool>) -> Self {
+        self.sanitization = Some(value.into());
+        self
+    }
+
+    /// Builds the `LoggingConfiguration`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<LoggingConfiguration, crate::MissingFieldError> {
+        Ok(LoggingConfiguration {
+            format: self.format,
+            sanitization: self.sanitization,
+        })
+    }
+}
+
+/// The apply configuration of [`LoggingConfiguration`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LoggingConfigurationApplyConfiguration {
+    /// See [`LoggingConfiguration::format`].
+    pub format: Option<String>,
+
+    /// See [`LoggingConfiguration::sanitization`].
+    pub sanitization: Option<bool>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for LoggingConfigurationApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "LoggingConfigurationApplyConfiguration",
+            self.format.as_ref().map_or(0, |_| 1) +
+            self.sanitization.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.format {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "format", value)?;
+        }
+        if let Some(value) = &self.sanitization {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "sanitization", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for LoggingConfiguration {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "kubelet::config::v1beta1::LoggingConfiguration".to_owned()
+        }
+        else {
+            "io.k8s.kubelet.config.v1beta1.LoggingConfiguration".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("LoggingConfiguration contains logging options.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "format".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("format flag specifies the structure of log messages. The default value of format is `text`.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "sanitization".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("sanitization [Experimental] when enabled prevents logging of fields tagged as sensitive (passwords, keys, tokens). Runtime log sanitization may introduce significant computation overhead and therefore should be disabled in production.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for LoggingConfiguration {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(LoggingConfiguration {
+            format: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            sanitization: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
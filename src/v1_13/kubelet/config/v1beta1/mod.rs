@@ -0,0 +1,70 @@
+
+mod kubelet_anonymous_authentication;
+pub use self::kubelet_anonymous_authentication::KubeletAnonymousAuthentication;
+#[cfg(feature = "builder")]
+pub use self::kubelet_anonymous_authentication::KubeletAnonymousAuthenticationBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::kubelet_anonymous_authentication::KubeletAnonymousAuthenticationApplyConfiguration;
+
+mod kubelet_authentication;
+pub use self::kubelet_authentication::KubeletAuthentication;
+#[cfg(feature = "builder")]
+pub use self::kubelet_authentication::KubeletAuthenticationBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::kubelet_authentication::KubeletAuthenticationApplyConfiguration;
+
+mod kubelet_authorization;
+pub use self::kubelet_authorization::KubeletAuthorization;
+#[cfg(feature = "builder")]
+pub use self::kubelet_authorization::KubeletAuthorizationBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::kubelet_authorization::KubeletAuthorizationApplyConfiguration;
+
+mod kubelet_configuration;
+pub use self::kubelet_configuration::KubeletConfiguration;
+#[cfg(feature = "builder")]
+pub use self::kubelet_configuration::KubeletConfigurationBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::kubelet_configuration::KubeletConfigurationApplyConfiguration;
+
+mod kubelet_webhook_authentication;
+pub use self::kubelet_webhook_authentication::KubeletWebhookAuthentication;
+#[cfg(feature = "builder")]
+pub use self::kubelet_webhook_authentication::KubeletWebhookAuthenticationBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::kubelet_webhook_authentication::KubeletWebhookAuthenticationApplyConfiguration;
+
+mod kubelet_webhook_authorization;
+pub use self::kubelet_webhook_authorization::KubeletWebhookAuthorization;
+#[cfg(feature = "builder")]
+pub use self::kubelet_webhook_authorization::KubeletWebhookAuthorizationBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::kubelet_webhook_authorization::KubeletWebhookAuthorizationApplyConfiguration;
+
+mod kubelet_x509_authentication;
+pub use self::kubelet_x509_authentication::KubeletX509Authentication;
+#[cfg(feature = "builder")]
+pub use self::kubelet_x509_authentication::KubeletX509AuthenticationBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::kubelet_x509_authentication::KubeletX509AuthenticationApplyConfiguration;
+
+mod logging_configuration;
+pub use self::logging_configuration::LoggingConfiguration;
+#[cfg(feature = "builder")]
+pub use self::logging_configuration::LoggingConfigurationBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::logging_configuration::LoggingConfigurationApplyConfiguration;
+
+mod memory_reservation;
+pub use self::memory_reservation::MemoryReservation;
+#[cfg(feature = "builder")]
+pub use self::memory_reservation::MemoryReservationBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::memory_reservation::MemoryReservationApplyConfiguration;
+
+mod memory_swap_configuration;
+pub use self::memory_swap_configuration::MemorySwapConfiguration;
+#[cfg(feature = "builder")]
+pub use self::memory_swap_configuration::MemorySwapConfigurationBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::memory_swap_configuration::MemorySwapConfigurationApplyConfiguration;
@@ -0,0 +1,577 @@
+// Generated from definition io.k8s.metrics.pkg.apis.custom_metrics.v1beta1.MetricValue
+
+/// a metric value for some object
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct MetricValue {
+    /// a reference to the described object
+    pub described_object: crate::api::core::v1::ObjectReference,
+
+    /// the name of the metric
+    pub metric_name: String,
+
+    /// selector represents the label selector that could be used to select this metric, and will generally just be the selector passed in to the query. This is used to disambiguate between multiple metrics with the same name but different label sets.
+    pub selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>,
+
+    /// indicates the time at which the metrics were produced
+    pub timestamp: crate::apimachinery::pkg::apis::meta::v1::Time,
+
+    /// the value of the metric for this
+    pub value: crate::apimachinery::pkg::api::resource::Quantity,
+
+    /// indicates the window (\[Timestamp-Window, Timestamp\]) from which these metrics were calculated, when returning rate metrics calculated from cumulative metrics (or zero for non-calculated instantaneous metrics).
+    pub window: Option<i64>,
+}
+
+impl MetricValue {
+    /// Constructs a `MetricValue` from its required fields. Its optional fields are unset.
+    pub fn new(described_object: impl Into<crate::api::core::v1::ObjectReference>, metric_name: impl Into<String>, timestamp: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        MetricValue {
+            described_object: described_object.into(),
+            metric_name: metric_name.into(),
+            selector: None,
+            timestamp: timestamp.into(),
+            value: value.into(),
+            window: None,
+        }
+    }
+}
+
+impl MetricValue {
+    /// Returns this value with [`MetricValue::described_object`] set to the given value.
+    #[must_use]
+    pub fn with_described_object(mut self, value: impl Into<crate::api::core::v1::ObjectReference>) -> Self {
+        self.described_object = value.into();
+        self
+    }
+
+    /// Returns this value with [`MetricValue::metric_name`] set to the given value.
+    #[must_use]
+    pub fn with_metric_name(mut self, value: impl Into<String>) -> Self {
+        self.metric_name = value.into();
+        self
+    }
+
+    /// Returns this value with [`MetricValue::selector`] set to the given value.
+    #[must_use]
+    pub fn with_selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.selector = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`MetricValue::timestamp`] set to the given value.
+    #[must_use]
+    pub fn with_timestamp(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.timestamp = value.into();
+        self
+    }
+
+    /// Returns this value with [`MetricValue::value`] set to the given value.
+    #[must_use]
+    pub fn with_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    /// Returns this value with [`MetricValue::window`] set to the given value.
+    #[must_use]
+    pub fn with_window(mut self, value: impl Into<i64>) -> Self {
+        self.window = Some(value.into());
+        self
+    }
+}
+
+impl crate::Resource for MetricValue {
+    const API_VERSION: &'static str = "custom.metrics.k8s.io/v1beta1";
+    const GROUP: &'static str = "custom.metrics.k8s.io";
+    const KIND: &'static str = "MetricValue";
+    const VERSION: &'static str = "v1beta1";
+    const URL_PATH_SEGMENT: &'static str = "";
+    const VERBS: &'static [&'static str] = &[];
+    const SINGULAR: &'static str = "metricvalue";
+    const SHORT_NAMES: &'static [&'static str] = &[];
+    type Scope = crate::ClusterResourceScope;
+}
+
+impl crate::ListableResource for MetricValue {
+    const LIST_KIND: &'static str = "MetricValueList";
+}
+
+impl crate::MergeStrategyInfo for MetricValue {}
+
+impl MetricValue {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "kind",
+        "describedObject",
+        "metricName",
+        "selector",
+        "timestamp",
+        "value",
+        "window",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for MetricValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = MetricValue;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<Self::Value as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_described_object: Option<crate::api::core::v1::ObjectReference> = None;
+                let mut value_metric_name: Option<String> = None;
+                let mut value_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> = None;
+                let mut value_timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
+                let mut value_value: Option<crate::apimachinery::pkg::api::resource::Quantity> = None;
+                let mut value_window: Option<i64> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("MetricValue", MetricValue::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <Self::Value as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<Self::Value as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <Self::Value as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<Self::Value as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => value_described_object = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_metric_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(4) => value_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_timestamp = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(6) => value_value = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(7) => value_window = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(MetricValue {
+                    described_object: value_described_object.ok_or_else(|| crate::serde2::missing_field("MetricValue", "describedObject", &["describedObject", "metricName", "timestamp", "value"]))?,
+                    metric_name: value_metric_name.ok_or_else(|| crate::serde2::missing_field("MetricValue", "metricName", &["describedObject", "metricName", "timestamp", "value"]))?,
+                    selector: value_selector,
+                    timestamp: value_timestamp.ok_or_else(|| crate::serde2::missing_field("MetricValue", "timestamp", &["describedObject", "metricName", "timestamp", "value"]))?,
+                    value: value_value.ok_or_else(|| crate::serde2::missing_field("MetricValue", "value", &["describedObject", "metricName", "timestamp", "value"]))?,
+                    window: value_window,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(MetricValue {
+                    described_object: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    metric_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    selector: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    timestamp: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    value: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    window: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            MetricValue::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, MetricValue> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<MetricValue as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_described_object = false;
+                let mut has_metric_name = false;
+                let mut has_selector = false;
+                let mut has_timestamp = false;
+                let mut has_value = false;
+                let mut has_window = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("MetricValue", MetricValue::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <MetricValue as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<MetricValue as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <MetricValue as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<MetricValue as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.described_object))?; has_described_object = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metric_name))?; has_metric_name = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.selector))?; has_selector = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.timestamp))?; has_timestamp = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.value))?; has_value = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.window))?; has_window = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_described_object {
+                    return Err(crate::serde2::missing_field("MetricValue", "describedObject", &["describedObject", "metricName", "timestamp", "value"]));
+                }
+                if !has_metric_name {
+                    return Err(crate::serde2::missing_field("MetricValue", "metricName", &["describedObject", "metricName", "timestamp", "value"]));
+                }
+                if !has_selector {
+                    self.0.selector = None;
+                }
+                if !has_timestamp {
+                    return Err(crate::serde2::missing_field("MetricValue", "timestamp", &["describedObject", "metricName", "timestamp", "value"]));
+                }
+                if !has_value {
+                    return Err(crate::serde2::missing_field("MetricValue", "value", &["describedObject", "metricName", "timestamp", "value"]));
+                }
+                if !has_window {
+                    self.0.window = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.described_object))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metric_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.selector))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.timestamp))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.value))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.window))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            MetricValue::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for MetricValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct(<Self as crate::Resource>::KIND, 6)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "describedObject", &self.described_object)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricName", &self.metric_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selector", &self.selector)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "timestamp", &self.timestamp)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "value", &self.value)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "window", &self.window)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            <Self as crate::Resource>::KIND,
+            6 +
+            self.selector.as_ref().map_or(0, |_| 1) +
+            self.window.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <Self as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <Self as crate::Resource>::KIND)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "describedObject", &self.described_object)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricName", &self.metric_name)?;
+        if let Some(value) = &self.selector {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selector", value)?;
+        }
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "timestamp", &self.timestamp)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "value", &self.value)?;
+        if let Some(value) = &self.window {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "window", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`MetricValue`]. Created with [`MetricValue::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct MetricValueBuilder {
+    described_object: Option<crate::api::core::v1::ObjectReference>,
+    metric_name: Option<String>,
+    selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>,
+    timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+    value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+    window: Option<i64>,
+}
+
+#[cfg(feature = "builder")]
+impl MetricValue {
+    /// Starts building a `MetricValue`. Its required fields must be set before [`MetricValueBuilder::build`] is called.
+    pub fn builder() -> MetricValueBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl MetricValueBuilder {
+    /// Sets [`MetricValue::described_object`].
+    pub fn described_object(mut self, value: impl Into<crate::api::core::v1::ObjectReference>) -> Self {
+        self.described_object = Some(value.into());
+        self
+    }
+
+    /// Sets [`MetricValue::metric_name`].
+    pub fn metric_name(mut self, value: impl Into<String>) -> Self {
+        self.metric_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`MetricValue::selector`].
+    pub fn selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`MetricValue::timestamp`].
+    pub fn timestamp(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.timestamp = Some(value.into());
+        self
+    }
+
+    /// Sets [`MetricValue::value`].
+    pub fn value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Sets [`MetricValue::window`].
+    pub fn window(mut self, value: impl Into<i64>) -> Self {
+        self.window = Some(value.into());
+        self
+    }
+
+    /// Builds the `MetricValue`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<MetricValue, crate::MissingFieldError> {
+        Ok(MetricValue {
+            described_object: self.described_object.ok_or_else(|| crate::MissingFieldError::new("MetricValue", "describedObject"))?,
+            metric_name: self.metric_name.ok_or_else(|| crate::MissingFieldError::new("MetricValue", "metricName"))?,
+            selector: self.selector,
+            timestamp: self.timestamp.ok_or_else(|| crate::MissingFieldError::new("MetricValue", "timestamp"))?,
+            value: self.value.ok_or_else(|| crate::MissingFieldError::new("MetricValue", "value"))?,
+            window: self.window,
+        })
+    }
+}
+
+/// The apply configuration of [`MetricValue`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MetricValueApplyConfiguration {
+    /// See [`MetricValue::described_object`].
+    pub described_object: Option<crate::api::core::v1::ObjectReferenceApplyConfiguration>,
+
+    /// See [`MetricValue::metric_name`].
+    pub metric_name: Option<String>,
+
+    /// See [`MetricValue::selector`].
+    pub selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorApplyConfiguration>,
+
+    /// See [`MetricValue::timestamp`].
+    pub timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+
+    /// See [`MetricValue::value`].
+    pub value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+
+    /// See [`MetricValue::window`].
+    pub window: Option<i64>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for MetricValueApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            <MetricValue as crate::Resource>::KIND,
+            2 +
+            self.described_object.as_ref().map_or(0, |_| 1) +
+            self.metric_name.as_ref().map_or(0, |_| 1) +
+            self.selector.as_ref().map_or(0, |_| 1) +
+            self.timestamp.as_ref().map_or(0, |_| 1) +
+            self.value.as_ref().map_or(0, |_| 1) +
+            self.window.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <MetricValue as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <MetricValue as crate::Resource>::KIND)?;
+        if let Some(value) = &self.described_object {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "describedObject", value)?;
+        }
+        if let Some(value) = &self.metric_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricName", value)?;
+        }
+        if let Some(value) = &self.selector {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selector", value)?;
+        }
+        if let Some(value) = &self.timestamp {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "timestamp", value)?;
+        }
+        if let Some(value) = &self.value {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "value", value)?;
+        }
+        if let Some(value) = &self.window {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "window", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for MetricValue {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "metrics::pkg::apis::custom_metrics::v1beta1::MetricValue".to_owned()
+        }
+        else {
+            "io.k8s.metrics.pkg.apis.custom_metrics.v1beta1.MetricValue".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("a metric value for some object".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "apiVersion".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#resources".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("custom.metrics.k8s.io/v1beta1".to_owned())]),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "describedObject".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ObjectReference>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("a reference to the described object".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
+                        "kind".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#types-kinds".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("MetricValue".to_owned())]),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "metricName".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("the name of the metric".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "selector".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("selector represents the label selector that could be used to select this metric, and will generally just be the selector passed in to the query. This is used to disambiguate between multiple metrics with the same name but different label sets.".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
+                        "timestamp".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::Time>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("indicates the time at which the metrics were produced".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
+                        "value".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::api::resource::Quantity>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("the value of the metric for this".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
+                        "window".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("indicates the window ([Timestamp-Window, Timestamp]) from which these metrics were calculated, when returning rate metrics calculated from cumulative metrics (or zero for non-calculated instantaneous metrics).".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Integer))),
+                            format: Some("int64".to_owned()),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                required: std::array::IntoIter::new([
+                    "apiVersion",
+                    "describedObject",
+                    "kind",
+                    "metricName",
+                    "timestamp",
+                    "value",
+                ]).map(std::borrow::ToOwned::to_owned).collect(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for MetricValue {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(MetricValue {
+            described_object: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            metric_name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            selector: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            timestamp: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            value: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            window: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -0,0 +1,14 @@
+
+mod metric_identifier;
+pub use self::metric_identifier::MetricIdentifier;
+#[cfg(feature = "builder")]
+pub use self::metric_identifier::MetricIdentifierBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::metric_identifier::MetricIdentifierApplyConfiguration;
+
+mod metric_value;
+pub use self::metric_value::MetricValue;
+#[cfg(feature = "builder")]
+pub use self::metric_value::MetricValueBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::metric_value::MetricValueApplyConfiguration;
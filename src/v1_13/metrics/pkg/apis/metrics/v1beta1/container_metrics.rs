@@ -0,0 +1,295 @@
+// Generated from definition io.k8s.metrics.pkg.apis.metrics.v1beta1.ContainerMetrics
+
+/// ContainerMetrics sets resource usage metrics of a container.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ContainerMetrics {
+    /// Container name corresponding to the one from pod.spec.containers.
+    pub name: String,
+
+    /// The memory usage is the memory working set.
+    pub usage: std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>,
+}
+
+impl ContainerMetrics {
+    /// Constructs a `ContainerMetrics` from its required fields. Its optional fields are unset.
+    pub fn new(name: impl Into<String>, usage: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>) -> Self {
+        ContainerMetrics {
+            name: name.into(),
+            usage: usage.into(),
+        }
+    }
+}
+
+impl ContainerMetrics {
+    /// Returns this value with [`ContainerMetrics::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
+
+    /// Returns this value with [`ContainerMetrics::usage`] set to the given value.
+    #[must_use]
+    pub fn with_usage(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>) -> Self {
+        self.usage = value.into();
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for ContainerMetrics {}
+
+impl ContainerMetrics {
+    const FIELDS: &'static [&'static str] = &[
+        "name",
+        "usage",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for ContainerMetrics {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = ContainerMetrics;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ContainerMetrics")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_name: Option<String> = None;
+                let mut value_usage: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ContainerMetrics", ContainerMetrics::FIELDS))? {
+                    match key {
+                        Some(0) => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_usage = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(ContainerMetrics {
+                    name: value_name.ok_or_else(|| crate::serde2::missing_field("ContainerMetrics", "name", &["name", "usage"]))?,
+                    usage: value_usage.ok_or_else(|| crate::serde2::missing_field("ContainerMetrics", "usage", &["name", "usage"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ContainerMetrics {
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    usage: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ContainerMetrics",
+            ContainerMetrics::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ContainerMetrics> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ContainerMetrics")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_name = false;
+                let mut has_usage = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ContainerMetrics", ContainerMetrics::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.name))?; has_name = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.usage))?; has_usage = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_name {
+                    return Err(crate::serde2::missing_field("ContainerMetrics", "name", &["name", "usage"]));
+                }
+                if !has_usage {
+                    return Err(crate::serde2::missing_field("ContainerMetrics", "usage", &["name", "usage"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.usage))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ContainerMetrics",
+            ContainerMetrics::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for ContainerMetrics {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ContainerMetrics", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "usage", &self.usage)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            "ContainerMetrics",
+            2,
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "usage", &self.usage)?;
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`ContainerMetrics`]. Created with [`ContainerMetrics::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ContainerMetricsBuilder {
+    name: Option<String>,
+    usage: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>,
+}
+
+#[cfg(feature = "builder")]
+impl ContainerMetrics {
+    /// Starts building a `ContainerMetrics`. Its required fields must be set before [`ContainerMetricsBuilder::build`] is called.
+    pub fn builder() -> ContainerMetricsBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ContainerMetricsBuilder {
+    /// Sets [`ContainerMetrics::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`ContainerMetrics::usage`].
+    pub fn usage(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>) -> Self {
+        self.usage = Some(value.into());
+        self
+    }
+
+    /// Builds the `ContainerMetrics`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ContainerMetrics, crate::MissingFieldError> {
+        Ok(ContainerMetrics {
+            name: self.name.ok_or_else(|| crate::MissingFieldError::new("ContainerMetrics", "name"))?,
+            usage: self.usage.ok_or_else(|| crate::MissingFieldError::new("ContainerMetrics", "usage"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`ContainerMetrics`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ContainerMetricsApplyConfiguration {
+    /// See [`ContainerMetrics::name`].
+    pub name: Option<String>,
+
+    /// See [`ContainerMetrics::usage`].
+    pub usage: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ContainerMetricsApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ContainerMetricsApplyConfiguration",
+            self.name.as_ref().map_or(0, |_| 1) +
+            self.usage.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        if let Some(value) = &self.usage {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "usage", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for ContainerMetrics {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "metrics::pkg::apis::metrics::v1beta1::ContainerMetrics".to_owned()
+        }
+        else {
+            "io.k8s.metrics.pkg.apis.metrics.v1beta1.ContainerMetrics".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("ContainerMetrics sets resource usage metrics of a container.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "name".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Container name corresponding to the one from pod.spec.containers.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "usage".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("The memory usage is the memory working set.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+                            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                                additional_properties: Some(Box::new(__gen.subschema_for::<crate::apimachinery::pkg::api::resource::Quantity>())),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                required: std::array::IntoIter::new([
+                    "name",
+                    "usage",
+                ]).map(std::borrow::ToOwned::to_owned).collect(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ContainerMetrics {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ContainerMetrics {
+            name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            usage: crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
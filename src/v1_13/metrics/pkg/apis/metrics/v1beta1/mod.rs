@@ -0,0 +1,23 @@
+
+mod container_metrics;
+pub use self::container_metrics::ContainerMetrics;
+#[cfg(feature = "builder")]
+pub use self::container_metrics::ContainerMetricsBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::container_metrics::ContainerMetricsApplyConfiguration;
+
+mod node_metrics;
+pub use self::node_metrics::NodeMetrics;
+#[cfg(feature = "builder")]
+pub use self::node_metrics::NodeMetricsBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::node_metrics::NodeMetricsApplyConfiguration;
+#[cfg(feature = "api")] pub use self::node_metrics::ReadNodeMetricsResponse;
+
+mod pod_metrics;
+pub use self::pod_metrics::PodMetrics;
+#[cfg(feature = "builder")]
+pub use self::pod_metrics::PodMetricsBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::pod_metrics::PodMetricsApplyConfiguration;
+#[cfg(feature = "api")] pub use self::pod_metrics::ReadNamespacedPodMetricsResponse;
@@ -0,0 +1,598 @@
+// Generated from definition io.k8s.metrics.pkg.apis.metrics.v1beta1.NodeMetrics
+
+/// NodeMetrics sets resource usage metrics of a node.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct NodeMetrics {
+    /// Standard object's metadata. More info: https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#metadata
+    pub metadata: crate::apimachinery::pkg::apis::meta::v1::ObjectMeta,
+
+    /// The following fields define time interval from which metrics were collected from the interval \[Timestamp-Window, Timestamp\].
+    pub timestamp: crate::apimachinery::pkg::apis::meta::v1::Time,
+
+    /// The memory usage is the memory working set.
+    pub usage: std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>,
+
+    /// The length of the interval from which metrics were collected, in Go's duration format (eg `1m30s`).
+    pub window: String,
+}
+
+impl NodeMetrics {
+    /// Constructs a `NodeMetrics` from its required fields. Its optional fields are unset.
+    pub fn new(metadata: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>, timestamp: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>, usage: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>, window: impl Into<String>) -> Self {
+        NodeMetrics {
+            metadata: metadata.into(),
+            timestamp: timestamp.into(),
+            usage: usage.into(),
+            window: window.into(),
+        }
+    }
+}
+
+impl NodeMetrics {
+    /// Returns this value with [`NodeMetrics::metadata`] set to the given value.
+    #[must_use]
+    pub fn with_metadata(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        self.metadata = value.into();
+        self
+    }
+
+    /// Returns this value with [`NodeMetrics::timestamp`] set to the given value.
+    #[must_use]
+    pub fn with_timestamp(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.timestamp = value.into();
+        self
+    }
+
+    /// Returns this value with [`NodeMetrics::usage`] set to the given value.
+    #[must_use]
+    pub fn with_usage(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>) -> Self {
+        self.usage = value.into();
+        self
+    }
+
+    /// Returns this value with [`NodeMetrics::window`] set to the given value.
+    #[must_use]
+    pub fn with_window(mut self, value: impl Into<String>) -> Self {
+        self.window = value.into();
+        self
+    }
+}
+
+// Begin metrics.k8s.io/v1beta1/NodeMetrics
+
+// Generated from operation listMetricsV1beta1NodeMetrics
+
+impl NodeMetrics {
+    /// list objects of kind NodeMetrics
+    ///
+    /// This operation only supports listing all items of this type.
+    ///
+    /// Use the returned [`crate::ResponseBody`]`<`[`crate::ListResponse`]`<Self>>` constructor, or [`crate::ListResponse`]`<Self>` directly, to parse the HTTP response.
+    ///
+    /// # Arguments
+    ///
+    /// * `optional`
+    ///
+    ///     Optional parameters. Use `Default::default()` to not pass any.
+    #[cfg(feature = "api")]
+    pub fn list_node_metrics(
+        optional: crate::ListOptional<'_>,
+    ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::ListResponse<Self>>), crate::RequestError> {
+        let __url = "/apis/metrics.k8s.io/v1beta1/nodes?".to_owned();
+        let mut __query_pairs = crate::url::form_urlencoded::Serializer::new(__url);
+        optional.__serialize(&mut __query_pairs);
+        let __url = __query_pairs.finish();
+
+        let __request = crate::http::Request::get(__url);
+        let __body = vec![];
+        match __request.body(__body) {
+            Ok(request) => Ok((request, crate::ResponseBody::new)),
+            Err(err) => Err(crate::RequestError::Http(err)),
+        }
+    }
+}
+
+// Generated from operation readMetricsV1beta1NodeMetrics
+
+impl NodeMetrics {
+    /// read the specified NodeMetrics
+    ///
+    /// Use the returned [`crate::ResponseBody`]`<`[`ReadNodeMetricsResponse`]`>` constructor, or [`ReadNodeMetricsResponse`] directly, to parse the HTTP response.
+    ///
+    /// # Arguments
+    ///
+    /// * `name`
+    ///
+    ///     name of the NodeMetrics
+    #[cfg(feature = "api")]
+    pub fn read_node_metrics(
+        name: &str,
+    ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<ReadNodeMetricsResponse>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
+        let __url = format!("/apis/metrics.k8s.io/v1beta1/nodes/{name}",
+            name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
+        );
+
+        let __request = crate::http::Request::get(__url);
+        let __body = vec![];
+        match __request.body(__body) {
+            Ok(request) => Ok((request, crate::ResponseBody::new)),
+            Err(err) => Err(crate::RequestError::Http(err)),
+        }
+    }
+}
+
+/// Use `<ReadNodeMetricsResponse as Response>::try_from_parts` to parse the HTTP response body of [`NodeMetrics::read_node_metrics`]
+#[cfg(feature = "api")]
+#[derive(Debug)]
+pub enum ReadNodeMetricsResponse {
+    Ok(crate::metrics::pkg::apis::metrics::v1beta1::NodeMetrics),
+    Other(Result<Option<crate::serde_json::Value>, crate::serde_json::Error>),
+}
+
+#[cfg(feature = "api")]
+impl crate::Response for ReadNodeMetricsResponse {
+    fn try_from_parts(status_code: crate::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), crate::ResponseError> {
+        match status_code {
+            crate::http::StatusCode::OK => {
+                let result = match crate::serde_json::from_slice(buf) {
+                    Ok(value) => value,
+                    Err(ref err) if err.is_eof() => return Err(crate::ResponseError::NeedMoreData),
+                    Err(err) => return Err(crate::ResponseError::Json(err)),
+                };
+                Ok((ReadNodeMetricsResponse::Ok(result), buf.len()))
+            },
+            _ => {
+                let (result, read) =
+                    if buf.is_empty() {
+                        (Ok(None), 0)
+                    }
+                    else {
+                        match crate::serde_json::from_slice(buf) {
+                            Ok(value) => (Ok(Some(value)), buf.len()),
+                            Err(ref err) if err.is_eof() => return Err(crate::ResponseError::NeedMoreData),
+                            Err(err) => (Err(err), 0),
+                        }
+                    };
+                Ok((ReadNodeMetricsResponse::Other(result), read))
+            },
+        }
+    }
+}
+
+// End metrics.k8s.io/v1beta1/NodeMetrics
+
+impl crate::Resource for NodeMetrics {
+    const API_VERSION: &'static str = "metrics.k8s.io/v1beta1";
+    const GROUP: &'static str = "metrics.k8s.io";
+    const KIND: &'static str = "NodeMetrics";
+    const VERSION: &'static str = "v1beta1";
+    const URL_PATH_SEGMENT: &'static str = "nodes";
+    const VERBS: &'static [&'static str] = &["get", "list"];
+    const SINGULAR: &'static str = "nodemetrics";
+    const SHORT_NAMES: &'static [&'static str] = &[];
+    type Scope = crate::ClusterResourceScope;
+}
+
+impl crate::ListableResource for NodeMetrics {
+    const LIST_KIND: &'static str = "NodeMetricsList";
+}
+
+impl crate::Metadata for NodeMetrics {
+    type Ty = crate::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+    fn metadata(&self) -> &<Self as crate::Metadata>::Ty {
+        &self.metadata
+    }
+
+    fn metadata_mut(&mut self) -> &mut<Self as crate::Metadata>::Ty {
+        &mut self.metadata
+    }
+}
+
+impl std::fmt::Display for NodeMetrics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(<Self as crate::Resource>::URL_PATH_SEGMENT)?;
+        for part in [&self.metadata.namespace, &self.metadata.name].iter().copied().flatten() {
+            write!(f, "/{part}")?;
+        }
+        Ok(())
+    }
+}
+
+impl crate::MergeStrategyInfo for NodeMetrics {}
+
+impl NodeMetrics {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "kind",
+        "metadata",
+        "timestamp",
+        "usage",
+        "window",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for NodeMetrics {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = NodeMetrics;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<Self::Value as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> = None;
+                let mut value_timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
+                let mut value_usage: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>> = None;
+                let mut value_window: Option<String> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NodeMetrics", NodeMetrics::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <Self::Value as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<Self::Value as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <Self::Value as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<Self::Value as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => value_metadata = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_timestamp = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(4) => value_usage = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(5) => value_window = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(NodeMetrics {
+                    metadata: value_metadata.ok_or_else(|| crate::serde2::missing_field("NodeMetrics", "metadata", &["metadata", "timestamp", "usage", "window"]))?,
+                    timestamp: value_timestamp.ok_or_else(|| crate::serde2::missing_field("NodeMetrics", "timestamp", &["metadata", "timestamp", "usage", "window"]))?,
+                    usage: value_usage.ok_or_else(|| crate::serde2::missing_field("NodeMetrics", "usage", &["metadata", "timestamp", "usage", "window"]))?,
+                    window: value_window.ok_or_else(|| crate::serde2::missing_field("NodeMetrics", "window", &["metadata", "timestamp", "usage", "window"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(NodeMetrics {
+                    metadata: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    timestamp: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    usage: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    window: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            NodeMetrics::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, NodeMetrics> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<NodeMetrics as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_metadata = false;
+                let mut has_timestamp = false;
+                let mut has_usage = false;
+                let mut has_window = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("NodeMetrics", NodeMetrics::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <NodeMetrics as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<NodeMetrics as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <NodeMetrics as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<NodeMetrics as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metadata))?; has_metadata = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.timestamp))?; has_timestamp = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.usage))?; has_usage = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.window))?; has_window = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_metadata {
+                    return Err(crate::serde2::missing_field("NodeMetrics", "metadata", &["metadata", "timestamp", "usage", "window"]));
+                }
+                if !has_timestamp {
+                    return Err(crate::serde2::missing_field("NodeMetrics", "timestamp", &["metadata", "timestamp", "usage", "window"]));
+                }
+                if !has_usage {
+                    return Err(crate::serde2::missing_field("NodeMetrics", "usage", &["metadata", "timestamp", "usage", "window"]));
+                }
+                if !has_window {
+                    return Err(crate::serde2::missing_field("NodeMetrics", "window", &["metadata", "timestamp", "usage", "window"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metadata))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.timestamp))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.usage))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.window))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            NodeMetrics::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for NodeMetrics {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct(<Self as crate::Resource>::KIND, 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", &self.metadata)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "timestamp", &self.timestamp)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "usage", &self.usage)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "window", &self.window)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            <Self as crate::Resource>::KIND,
+            6,
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <Self as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <Self as crate::Resource>::KIND)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", &self.metadata)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "timestamp", &self.timestamp)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "usage", &self.usage)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "window", &self.window)?;
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`NodeMetrics`]. Created with [`NodeMetrics::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct NodeMetricsBuilder {
+    metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>,
+    timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+    usage: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>,
+    window: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl NodeMetrics {
+    /// Starts building a `NodeMetrics`. Its required fields must be set before [`NodeMetricsBuilder::build`] is called.
+    pub fn builder() -> NodeMetricsBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl NodeMetricsBuilder {
+    /// Sets [`NodeMetrics::metadata`].
+    pub fn metadata(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        self.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeMetrics::timestamp`].
+    pub fn timestamp(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.timestamp = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeMetrics::usage`].
+    pub fn usage(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>) -> Self {
+        self.usage = Some(value.into());
+        self
+    }
+
+    /// Sets [`NodeMetrics::window`].
+    pub fn window(mut self, value: impl Into<String>) -> Self {
+        self.window = Some(value.into());
+        self
+    }
+
+    /// Builds the `NodeMetrics`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<NodeMetrics, crate::MissingFieldError> {
+        Ok(NodeMetrics {
+            metadata: self.metadata.ok_or_else(|| crate::MissingFieldError::new("NodeMetrics", "metadata"))?,
+            timestamp: self.timestamp.ok_or_else(|| crate::MissingFieldError::new("NodeMetrics", "timestamp"))?,
+            usage: self.usage.ok_or_else(|| crate::MissingFieldError::new("NodeMetrics", "usage"))?,
+            window: self.window.ok_or_else(|| crate::MissingFieldError::new("NodeMetrics", "window"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`NodeMetrics`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NodeMetricsApplyConfiguration {
+    /// See [`NodeMetrics::metadata`].
+    pub metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMetaApplyConfiguration>,
+
+    /// See [`NodeMetrics::timestamp`].
+    pub timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+
+    /// See [`NodeMetrics::usage`].
+    pub usage: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>,
+
+    /// See [`NodeMetrics::window`].
+    pub window: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for NodeMetricsApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            <NodeMetrics as crate::Resource>::KIND,
+            2 +
+            self.metadata.as_ref().map_or(0, |_| 1) +
+            self.timestamp.as_ref().map_or(0, |_| 1) +
+            self.usage.as_ref().map_or(0, |_| 1) +
+            self.window.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <NodeMetrics as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <NodeMetrics as crate::Resource>::KIND)?;
+        if let Some(value) = &self.metadata {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", value)?;
+        }
+        if let Some(value) = &self.timestamp {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "timestamp", value)?;
+        }
+        if let Some(value) = &self.usage {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "usage", value)?;
+        }
+        if let Some(value) = &self.window {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "window", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for NodeMetrics {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "metrics::pkg::apis::metrics::v1beta1::NodeMetrics".to_owned()
+        }
+        else {
+            "io.k8s.metrics.pkg.apis.metrics.v1beta1.NodeMetrics".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("NodeMetrics sets resource usage metrics of a node.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "apiVersion".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#resources".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("metrics.k8s.io/v1beta1".to_owned())]),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "kind".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#types-kinds".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("NodeMetrics".to_owned())]),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "metadata".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Standard object's metadata. More info: https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#metadata".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
+                        "timestamp".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::Time>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("The following fields define time interval from which metrics were collected from the interval [Timestamp-Window, Timestamp].".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
+                        "usage".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("The memory usage is the memory working set.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+                            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                                additional_properties: Some(Box::new(__gen.subschema_for::<crate::apimachinery::pkg::api::resource::Quantity>())),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "window".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("The length of the interval from which metrics were collected, in Go's duration format (eg `1m30s`).".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                required: std::array::IntoIter::new([
+                    "apiVersion",
+                    "kind",
+                    "metadata",
+                    "timestamp",
+                    "usage",
+                    "window",
+                ]).map(std::borrow::ToOwned::to_owned).collect(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for NodeMetrics {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(NodeMetrics {
+            metadata: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            timestamp: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            usage: crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            window: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
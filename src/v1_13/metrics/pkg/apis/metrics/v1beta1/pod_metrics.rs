@@ -0,0 +1,645 @@
+// Generated from definition io.k8s.metrics.pkg.apis.metrics.v1beta1.PodMetrics
+
+/// PodMetrics sets resource usage metrics of a pod.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PodMetrics {
+    /// Metrics for all containers are collected within the same time window.
+    pub containers: Vec<crate::metrics::pkg::apis::metrics::v1beta1::ContainerMetrics>,
+
+    /// Standard object's metadata. More info: https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#metadata
+    pub metadata: crate::apimachinery::pkg::apis::meta::v1::ObjectMeta,
+
+    /// The following fields define time interval from which metrics were collected from the interval \[Timestamp-Window, Timestamp\].
+    pub timestamp: crate::apimachinery::pkg::apis::meta::v1::Time,
+
+    /// The length of the interval from which metrics were collected, in Go's duration format (eg `1m30s`).
+    pub window: String,
+}
+
+impl PodMetrics {
+    /// Constructs a `PodMetrics` from its required fields. Its optional fields are unset.
+    pub fn new(containers: impl Into<Vec<crate::metrics::pkg::apis::metrics::v1beta1::ContainerMetrics>>, metadata: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>, timestamp: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>, window: impl Into<String>) -> Self {
+        PodMetrics {
+            containers: containers.into(),
+            metadata: metadata.into(),
+            timestamp: timestamp.into(),
+            window: window.into(),
+        }
+    }
+}
+
+impl PodMetrics {
+    /// Returns this value with [`PodMetrics::containers`] set to the given value.
+    #[must_use]
+    pub fn with_containers(mut self, value: impl Into<Vec<crate::metrics::pkg::apis::metrics::v1beta1::ContainerMetrics>>) -> Self {
+        self.containers = value.into();
+        self
+    }
+
+    /// Returns this value with [`PodMetrics::metadata`] set to the given value.
+    #[must_use]
+    pub fn with_metadata(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        self.metadata = value.into();
+        self
+    }
+
+    /// Returns this value with [`PodMetrics::timestamp`] set to the given value.
+    #[must_use]
+    pub fn with_timestamp(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.timestamp = value.into();
+        self
+    }
+
+    /// Returns this value with [`PodMetrics::window`] set to the given value.
+    #[must_use]
+    pub fn with_window(mut self, value: impl Into<String>) -> Self {
+        self.window = value.into();
+        self
+    }
+}
+
+// Begin metrics.k8s.io/v1beta1/PodMetrics
+
+// Generated from operation listMetricsV1beta1NamespacedPodMetrics
+
+impl PodMetrics {
+    /// list objects of kind PodMetrics
+    ///
+    /// This operation only supports listing all items of this type.
+    ///
+    /// Use the returned [`crate::ResponseBody`]`<`[`crate::ListResponse`]`<Self>>` constructor, or [`crate::ListResponse`]`<Self>` directly, to parse the HTTP response.
+    ///
+    /// # Arguments
+    ///
+    /// * `namespace`
+    ///
+    ///     object name and auth scope, such as for teams and projects
+    ///
+    /// * `optional`
+    ///
+    ///     Optional parameters. Use `Default::default()` to not pass any.
+    #[cfg(feature = "api")]
+    pub fn list_namespaced_pod_metrics(
+        namespace: &str,
+        optional: crate::ListOptional<'_>,
+    ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::ListResponse<Self>>), crate::RequestError> {
+        crate::validate_path_parameter("namespace", namespace)?;
+        let __url = format!("/apis/metrics.k8s.io/v1beta1/namespaces/{namespace}/pods?",
+            namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
+        );
+        let mut __query_pairs = crate::url::form_urlencoded::Serializer::new(__url);
+        optional.__serialize(&mut __query_pairs);
+        let __url = __query_pairs.finish();
+
+        let __request = crate::http::Request::get(__url);
+        let __body = vec![];
+        match __request.body(__body) {
+            Ok(request) => Ok((request, crate::ResponseBody::new)),
+            Err(err) => Err(crate::RequestError::Http(err)),
+        }
+    }
+}
+
+// Generated from operation listMetricsV1beta1PodMetricsForAllNamespaces
+
+impl PodMetrics {
+    /// list objects of kind PodMetrics
+    ///
+    /// This operation only supports listing all items of this type.
+    ///
+    /// Use the returned [`crate::ResponseBody`]`<`[`crate::ListResponse`]`<Self>>` constructor, or [`crate::ListResponse`]`<Self>` directly, to parse the HTTP response.
+    ///
+    /// # Arguments
+    ///
+    /// * `optional`
+    ///
+    ///     Optional parameters. Use `Default::default()` to not pass any.
+    #[cfg(feature = "api")]
+    pub fn list_pod_metrics_for_all_namespaces(
+        optional: crate::ListOptional<'_>,
+    ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<crate::ListResponse<Self>>), crate::RequestError> {
+        let __url = "/apis/metrics.k8s.io/v1beta1/pods?".to_owned();
+        let mut __query_pairs = crate::url::form_urlencoded::Serializer::new(__url);
+        optional.__serialize(&mut __query_pairs);
+        let __url = __query_pairs.finish();
+
+        let __request = crate::http::Request::get(__url);
+        let __body = vec![];
+        match __request.body(__body) {
+            Ok(request) => Ok((request, crate::ResponseBody::new)),
+            Err(err) => Err(crate::RequestError::Http(err)),
+        }
+    }
+}
+
+// Generated from operation readMetricsV1beta1NamespacedPodMetrics
+
+impl PodMetrics {
+    /// read the specified PodMetrics
+    ///
+    /// Use the returned [`crate::ResponseBody`]`<`[`ReadNamespacedPodMetricsResponse`]`>` constructor, or [`ReadNamespacedPodMetricsResponse`] directly, to parse the HTTP response.
+    ///
+    /// # Arguments
+    ///
+    /// * `name`
+    ///
+    ///     name of the PodMetrics
+    ///
+    /// * `namespace`
+    ///
+    ///     object name and auth scope, such as for teams and projects
+    #[cfg(feature = "api")]
+    pub fn read_namespaced_pod_metrics(
+        name: &str,
+        namespace: &str,
+    ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<ReadNamespacedPodMetricsResponse>), crate::RequestError> {
+        crate::validate_path_parameter("name", name)?;
+        crate::validate_path_parameter("namespace", namespace)?;
+        let __url = format!("/apis/metrics.k8s.io/v1beta1/namespaces/{namespace}/pods/{name}",
+            name = crate::percent_encoding::percent_encode(name.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
+            namespace = crate::percent_encoding::percent_encode(namespace.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
+        );
+
+        let __request = crate::http::Request::get(__url);
+        let __body = vec![];
+        match __request.body(__body) {
+            Ok(request) => Ok((request, crate::ResponseBody::new)),
+            Err(err) => Err(crate::RequestError::Http(err)),
+        }
+    }
+}
+
+/// Use `<ReadNamespacedPodMetricsResponse as Response>::try_from_parts` to parse the HTTP response body of [`PodMetrics::read_namespaced_pod_metrics`]
+#[cfg(feature = "api")]
+#[derive(Debug)]
+pub enum ReadNamespacedPodMetricsResponse {
+    Ok(crate::metrics::pkg::apis::metrics::v1beta1::PodMetrics),
+    Other(Result<Option<crate::serde_json::Value>, crate::serde_json::Error>),
+}
+
+#[cfg(feature = "api")]
+impl crate::Response for ReadNamespacedPodMetricsResponse {
+    fn try_from_parts(status_code: crate::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), crate::ResponseError> {
+        match status_code {
+            crate::http::StatusCode::OK => {
+                let result = match crate::serde_json::from_slice(buf) {
+                    Ok(value) => value,
+                    Err(ref err) if err.is_eof() => return Err(crate::ResponseError::NeedMoreData),
+                    Err(err) => return Err(crate::ResponseError::Json(err)),
+                };
+                Ok((ReadNamespacedPodMetricsResponse::Ok(result), buf.len()))
+            },
+            _ => {
+                let (result, read) =
+                    if buf.is_empty() {
+                        (Ok(None), 0)
+                    }
+                    else {
+                        match crate::serde_json::from_slice(buf) {
+                            Ok(value) => (Ok(Some(value)), buf.len()),
+                            Err(ref err) if err.is_eof() => return Err(crate::ResponseError::NeedMoreData),
+                            Err(err) => (Err(err), 0),
+                        }
+                    };
+                Ok((ReadNamespacedPodMetricsResponse::Other(result), read))
+            },
+        }
+    }
+}
+
+// End metrics.k8s.io/v1beta1/PodMetrics
+
+impl crate::Resource for PodMetrics {
+    const API_VERSION: &'static str = "metrics.k8s.io/v1beta1";
+    const GROUP: &'static str = "metrics.k8s.io";
+    const KIND: &'static str = "PodMetrics";
+    const VERSION: &'static str = "v1beta1";
+    const URL_PATH_SEGMENT: &'static str = "pods";
+    const VERBS: &'static [&'static str] = &["get", "list"];
+    const SINGULAR: &'static str = "podmetrics";
+    const SHORT_NAMES: &'static [&'static str] = &[];
+    type Scope = crate::NamespaceResourceScope;
+}
+
+impl crate::ListableResource for PodMetrics {
+    const LIST_KIND: &'static str = "PodMetricsList";
+}
+
+impl crate::Metadata for PodMetrics {
+    type Ty = crate::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+    fn metadata(&self) -> &<Self as crate::Metadata>::Ty {
+        &self.metadata
+    }
+
+    fn metadata_mut(&mut self) -> &mut<Self as crate::Metadata>::Ty {
+        &mut self.metadata
+    }
+}
+
+impl std::fmt::Display for PodMetrics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(<Self as crate::Resource>::URL_PATH_SEGMENT)?;
+        for part in [&self.metadata.namespace, &self.metadata.name].iter().copied().flatten() {
+            write!(f, "/{part}")?;
+        }
+        Ok(())
+    }
+}
+
+impl crate::MergeStrategyInfo for PodMetrics {}
+
+impl PodMetrics {
+    const FIELDS: &'static [&'static str] = &[
+        "apiVersion",
+        "kind",
+        "containers",
+        "metadata",
+        "timestamp",
+        "window",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for PodMetrics {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = PodMetrics;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<Self::Value as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_containers: Option<Vec<crate::metrics::pkg::apis::metrics::v1beta1::ContainerMetrics>> = None;
+                let mut value_metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> = None;
+                let mut value_timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
+                let mut value_window: Option<String> = None;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodMetrics", PodMetrics::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <Self::Value as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<Self::Value as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <Self::Value as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<Self::Value as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => value_containers = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_metadata = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(4) => value_timestamp = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(5) => value_window = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(PodMetrics {
+                    containers: value_containers.ok_or_else(|| crate::serde2::missing_field("PodMetrics", "containers", &["containers", "metadata", "timestamp", "window"]))?,
+                    metadata: value_metadata.ok_or_else(|| crate::serde2::missing_field("PodMetrics", "metadata", &["containers", "metadata", "timestamp", "window"]))?,
+                    timestamp: value_timestamp.ok_or_else(|| crate::serde2::missing_field("PodMetrics", "timestamp", &["containers", "metadata", "timestamp", "window"]))?,
+                    window: value_window.ok_or_else(|| crate::serde2::missing_field("PodMetrics", "window", &["containers", "metadata", "timestamp", "window"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(PodMetrics {
+                    containers: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    metadata: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    timestamp: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    window: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            PodMetrics::FIELDS,
+            Visitor,
+        )
+    }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, PodMetrics> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(<PodMetrics as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_containers = false;
+                let mut has_metadata = false;
+                let mut has_timestamp = false;
+                let mut has_window = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodMetrics", PodMetrics::FIELDS))? {
+                    match key {
+                        Some(0) => {
+                            let value_api_version: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != <PodMetrics as crate::Resource>::API_VERSION {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_api_version), &<PodMetrics as crate::Resource>::API_VERSION));
+                            }
+                        },
+                        Some(1) => {
+                            let value_kind: String = crate::serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != <PodMetrics as crate::Resource>::KIND {
+                                return Err(crate::serde::de::Error::invalid_value(crate::serde::de::Unexpected::Str(&value_kind), &<PodMetrics as crate::Resource>::KIND));
+                            }
+                        },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.containers))?; has_containers = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metadata))?; has_metadata = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.timestamp))?; has_timestamp = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.window))?; has_window = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_containers {
+                    return Err(crate::serde2::missing_field("PodMetrics", "containers", &["containers", "metadata", "timestamp", "window"]));
+                }
+                if !has_metadata {
+                    return Err(crate::serde2::missing_field("PodMetrics", "metadata", &["containers", "metadata", "timestamp", "window"]));
+                }
+                if !has_timestamp {
+                    return Err(crate::serde2::missing_field("PodMetrics", "timestamp", &["containers", "metadata", "timestamp", "window"]));
+                }
+                if !has_window {
+                    return Err(crate::serde2::missing_field("PodMetrics", "window", &["containers", "metadata", "timestamp", "window"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.containers))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metadata))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.timestamp))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.window))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            PodMetrics::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
+}
+
+impl crate::serde::Serialize for PodMetrics {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct(<Self as crate::Resource>::KIND, 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "containers", &self.containers)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", &self.metadata)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "timestamp", &self.timestamp)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "window", &self.window)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
+        let mut state = serializer.serialize_struct(
+            <Self as crate::Resource>::KIND,
+            6,
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <Self as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <Self as crate::Resource>::KIND)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "containers", &self.containers)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", &self.metadata)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "timestamp", &self.timestamp)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "window", &self.window)?;
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A builder of [`PodMetrics`]. Created with [`PodMetrics::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct PodMetricsBuilder {
+    containers: Option<Vec<crate::metrics::pkg::apis::metrics::v1beta1::ContainerMetrics>>,
+    metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>,
+    timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+    window: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl PodMetrics {
+    /// Starts building a `PodMetrics`. Its required fields must be set before [`PodMetricsBuilder::build`] is called.
+    pub fn builder() -> PodMetricsBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl PodMetricsBuilder {
+    /// Sets [`PodMetrics::containers`].
+    pub fn containers(mut self, value: impl Into<Vec<crate::metrics::pkg::apis::metrics::v1beta1::ContainerMetrics>>) -> Self {
+        self.containers = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodMetrics::metadata`].
+    pub fn metadata(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>) -> Self {
+        self.metadata = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodMetrics::timestamp`].
+    pub fn timestamp(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.timestamp = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodMetrics::window`].
+    pub fn window(mut self, value: impl Into<String>) -> Self {
+        self.window = Some(value.into());
+        self
+    }
+
+    /// Builds the `PodMetrics`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<PodMetrics, crate::MissingFieldError> {
+        Ok(PodMetrics {
+            containers: self.containers.ok_or_else(|| crate::MissingFieldError::new("PodMetrics", "containers"))?,
+            metadata: self.metadata.ok_or_else(|| crate::MissingFieldError::new("PodMetrics", "metadata"))?,
+            timestamp: self.timestamp.ok_or_else(|| crate::MissingFieldError::new("PodMetrics", "timestamp"))?,
+            window: self.window.ok_or_else(|| crate::MissingFieldError::new("PodMetrics", "window"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`PodMetrics`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodMetricsApplyConfiguration {
+    /// See [`PodMetrics::containers`].
+    pub containers: Option<Vec<crate::metrics::pkg::apis::metrics::v1beta1::ContainerMetricsApplyConfiguration>>,
+
+    /// See [`PodMetrics::metadata`].
+    pub metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMetaApplyConfiguration>,
+
+    /// See [`PodMetrics::timestamp`].
+    pub timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+
+    /// See [`PodMetrics::window`].
+    pub window: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for PodMetricsApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            <PodMetrics as crate::Resource>::KIND,
+            2 +
+            self.containers.as_ref().map_or(0, |_| 1) +
+            self.metadata.as_ref().map_or(0, |_| 1) +
+            self.timestamp.as_ref().map_or(0, |_| 1) +
+            self.window.as_ref().map_or(0, |_| 1),
+        )?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <PodMetrics as crate::Resource>::API_VERSION)?;
+        crate::serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <PodMetrics as crate::Resource>::KIND)?;
+        if let Some(value) = &self.containers {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "containers", value)?;
+        }
+        if let Some(value) = &self.metadata {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", value)?;
+        }
+        if let Some(value) = &self.timestamp {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "timestamp", value)?;
+        }
+        if let Some(value) = &self.window {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "window", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for PodMetrics {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "metrics::pkg::apis::metrics::v1beta1::PodMetrics".to_owned()
+        }
+        else {
+            "io.k8s.metrics.pkg.apis.metrics.v1beta1.PodMetrics".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("PodMetrics sets resource usage metrics of a pod.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
+            object: Some(Box::new(crate::schemars::schema::ObjectValidation {
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
+                        "apiVersion".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#resources".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("metrics.k8s.io/v1beta1".to_owned())]),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "containers".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Metrics for all containers are collected within the same time window.".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Array))),
+                            array: Some(Box::new(crate::schemars::schema::ArrayValidation {
+                                items: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(__gen.subschema_for::<crate::metrics::pkg::apis::metrics::v1beta1::ContainerMetrics>()))),
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "kind".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#types-kinds".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            enum_values: Some(vec![crate::serde_json::Value::String("PodMetrics".to_owned())]),
+                            ..Default::default()
+                        }),
+                    );
+                    properties.insert(
+                        "metadata".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("Standard object's metadata. More info: https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#metadata".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
+                        "timestamp".to_owned(),
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::Time>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("The following fields define time interval from which metrics were collected from the interval [Timestamp-Window, Timestamp].".to_owned()),
+                                ..Default::default()
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
+                        "window".to_owned(),
+                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                                description: Some("The length of the interval from which metrics were collected, in Go's duration format (eg `1m30s`).".to_owned()),
+                                ..Default::default()
+                            })),
+                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+                            ..Default::default()
+                        }),
+                    );
+                    properties
+                },
+                required: std::array::IntoIter::new([
+                    "apiVersion",
+                    "containers",
+                    "kind",
+                    "metadata",
+                    "timestamp",
+                    "window",
+                ]).map(std::borrow::ToOwned::to_owned).collect(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for PodMetrics {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(PodMetrics {
+            containers: crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            metadata: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            timestamp: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            window: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
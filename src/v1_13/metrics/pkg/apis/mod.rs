@@ -0,0 +1,5 @@
+pub mod custom_metrics;
+
+pub mod external_metrics;
+
+pub mod metrics;
@@ -68,8 +68,25 @@ pub mod apiextensions_apiserver;
 
 pub mod apimachinery;
 
+pub mod client_go;
+
+pub mod component_base;
+
 pub mod kube_aggregator;
 
+pub mod kube_proxy;
+
+pub mod kubelet;
+
+pub mod metrics;
+
+#[cfg(feature = "reflection")]
+mod type_registry;
+#[cfg(feature = "reflection")]
+pub(crate) use self::type_registry::TYPES;
+
+mod version_conversions;
+
 // Generated from operation getAPIVersions
 
 /// get available API versions
@@ -3275,6 +3292,7 @@ impl crate::Response for GetStorageV1beta1APIResourcesResponse {
 pub fn log_file_handler(
     logpath: &str,
 ) -> Result<(crate::http::Request<Vec<u8>>, fn(crate::http::StatusCode) -> crate::ResponseBody<LogFileHandlerResponse>), crate::RequestError> {
+    crate::validate_path_parameter("logpath", logpath)?;
     let __url = format!("/logs/{logpath}",
         logpath = crate::percent_encoding::percent_encode(logpath.as_bytes(), crate::percent_encoding2::PATH_SEGMENT_ENCODE_SET),
     );
@@ -0,0 +1,498 @@
+// Generated from all definitions
+
+impl std::convert::TryFrom<crate::kube_aggregator::pkg::apis::apiregistration::v1::APIService> for crate::kube_aggregator::pkg::apis::apiregistration::v1beta1::APIService {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::kube_aggregator::pkg::apis::apiregistration::v1::APIService) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::kube_aggregator::pkg::apis::apiregistration::v1beta1::APIService> for crate::kube_aggregator::pkg::apis::apiregistration::v1::APIService {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::kube_aggregator::pkg::apis::apiregistration::v1beta1::APIService) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::apps::v1::ControllerRevision> for crate::api::apps::v1beta2::ControllerRevision {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::apps::v1::ControllerRevision) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::apps::v1beta1::ControllerRevision> for crate::api::apps::v1beta2::ControllerRevision {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::apps::v1beta1::ControllerRevision) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::apps::v1beta2::ControllerRevision> for crate::api::apps::v1::ControllerRevision {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::apps::v1beta2::ControllerRevision) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::apps::v1beta2::ControllerRevision> for crate::api::apps::v1beta1::ControllerRevision {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::apps::v1beta2::ControllerRevision) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::apps::v1::DaemonSet> for crate::api::apps::v1beta2::DaemonSet {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::apps::v1::DaemonSet) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::apps::v1beta2::DaemonSet> for crate::api::apps::v1::DaemonSet {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::apps::v1beta2::DaemonSet) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::apps::v1::Deployment> for crate::api::apps::v1beta2::Deployment {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::apps::v1::Deployment) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::apps::v1beta1::Deployment> for crate::api::apps::v1beta2::Deployment {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::apps::v1beta1::Deployment) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::apps::v1beta2::Deployment> for crate::api::apps::v1::Deployment {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::apps::v1beta2::Deployment) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::apps::v1beta2::Deployment> for crate::api::apps::v1beta1::Deployment {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::apps::v1beta2::Deployment) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::apps::v1::ReplicaSet> for crate::api::apps::v1beta2::ReplicaSet {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::apps::v1::ReplicaSet) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::apps::v1beta2::ReplicaSet> for crate::api::apps::v1::ReplicaSet {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::apps::v1beta2::ReplicaSet) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::apps::v1beta1::Scale> for crate::api::apps::v1beta2::Scale {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::apps::v1beta1::Scale) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::apps::v1beta2::Scale> for crate::api::apps::v1beta1::Scale {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::apps::v1beta2::Scale) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::apps::v1::StatefulSet> for crate::api::apps::v1beta2::StatefulSet {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::apps::v1::StatefulSet) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::apps::v1beta1::StatefulSet> for crate::api::apps::v1beta2::StatefulSet {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::apps::v1beta1::StatefulSet) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::apps::v1beta2::StatefulSet> for crate::api::apps::v1::StatefulSet {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::apps::v1beta2::StatefulSet) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::apps::v1beta2::StatefulSet> for crate::api::apps::v1beta1::StatefulSet {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::apps::v1beta2::StatefulSet) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::authentication::v1::TokenReview> for crate::api::authentication::v1beta1::TokenReview {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::authentication::v1::TokenReview) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::authentication::v1beta1::TokenReview> for crate::api::authentication::v1::TokenReview {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::authentication::v1beta1::TokenReview) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::authorization::v1::LocalSubjectAccessReview> for crate::api::authorization::v1beta1::LocalSubjectAccessReview {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::authorization::v1::LocalSubjectAccessReview) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::authorization::v1beta1::LocalSubjectAccessReview> for crate::api::authorization::v1::LocalSubjectAccessReview {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::authorization::v1beta1::LocalSubjectAccessReview) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::authorization::v1::SelfSubjectAccessReview> for crate::api::authorization::v1beta1::SelfSubjectAccessReview {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::authorization::v1::SelfSubjectAccessReview) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::authorization::v1beta1::SelfSubjectAccessReview> for crate::api::authorization::v1::SelfSubjectAccessReview {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::authorization::v1beta1::SelfSubjectAccessReview) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::authorization::v1::SelfSubjectRulesReview> for crate::api::authorization::v1beta1::SelfSubjectRulesReview {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::authorization::v1::SelfSubjectRulesReview) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::authorization::v1beta1::SelfSubjectRulesReview> for crate::api::authorization::v1::SelfSubjectRulesReview {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::authorization::v1beta1::SelfSubjectRulesReview) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::authorization::v1::SubjectAccessReview> for crate::api::authorization::v1beta1::SubjectAccessReview {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::authorization::v1::SubjectAccessReview) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::authorization::v1beta1::SubjectAccessReview> for crate::api::authorization::v1::SubjectAccessReview {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::authorization::v1beta1::SubjectAccessReview) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::autoscaling::v1::HorizontalPodAutoscaler> for crate::api::autoscaling::v2beta1::HorizontalPodAutoscaler {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::autoscaling::v1::HorizontalPodAutoscaler) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::autoscaling::v2beta1::HorizontalPodAutoscaler> for crate::api::autoscaling::v1::HorizontalPodAutoscaler {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::autoscaling::v2beta1::HorizontalPodAutoscaler) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::autoscaling::v2beta1::HorizontalPodAutoscaler> for crate::api::autoscaling::v2beta2::HorizontalPodAutoscaler {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::autoscaling::v2beta1::HorizontalPodAutoscaler) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::autoscaling::v2beta2::HorizontalPodAutoscaler> for crate::api::autoscaling::v2beta1::HorizontalPodAutoscaler {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::autoscaling::v2beta2::HorizontalPodAutoscaler) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::batch::v1beta1::CronJob> for crate::api::batch::v2alpha1::CronJob {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::batch::v1beta1::CronJob) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::batch::v2alpha1::CronJob> for crate::api::batch::v1beta1::CronJob {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::batch::v2alpha1::CronJob) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::metrics::pkg::apis::custom_metrics::v1beta1::MetricValue> for crate::metrics::pkg::apis::custom_metrics::v1beta2::MetricValue {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::metrics::pkg::apis::custom_metrics::v1beta1::MetricValue) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::metrics::pkg::apis::custom_metrics::v1beta2::MetricValue> for crate::metrics::pkg::apis::custom_metrics::v1beta1::MetricValue {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::metrics::pkg::apis::custom_metrics::v1beta2::MetricValue) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::rbac::v1::ClusterRole> for crate::api::rbac::v1beta1::ClusterRole {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::rbac::v1::ClusterRole) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::rbac::v1alpha1::ClusterRole> for crate::api::rbac::v1beta1::ClusterRole {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::rbac::v1alpha1::ClusterRole) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::rbac::v1beta1::ClusterRole> for crate::api::rbac::v1::ClusterRole {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::rbac::v1beta1::ClusterRole) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::rbac::v1beta1::ClusterRole> for crate::api::rbac::v1alpha1::ClusterRole {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::rbac::v1beta1::ClusterRole) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::rbac::v1::ClusterRoleBinding> for crate::api::rbac::v1beta1::ClusterRoleBinding {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::rbac::v1::ClusterRoleBinding) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::rbac::v1alpha1::ClusterRoleBinding> for crate::api::rbac::v1beta1::ClusterRoleBinding {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::rbac::v1alpha1::ClusterRoleBinding) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::rbac::v1beta1::ClusterRoleBinding> for crate::api::rbac::v1::ClusterRoleBinding {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::rbac::v1beta1::ClusterRoleBinding) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::rbac::v1beta1::ClusterRoleBinding> for crate::api::rbac::v1alpha1::ClusterRoleBinding {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::rbac::v1beta1::ClusterRoleBinding) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::rbac::v1::Role> for crate::api::rbac::v1beta1::Role {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::rbac::v1::Role) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::rbac::v1alpha1::Role> for crate::api::rbac::v1beta1::Role {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::rbac::v1alpha1::Role) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::rbac::v1beta1::Role> for crate::api::rbac::v1::Role {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::rbac::v1beta1::Role) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::rbac::v1beta1::Role> for crate::api::rbac::v1alpha1::Role {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::rbac::v1beta1::Role) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::rbac::v1::RoleBinding> for crate::api::rbac::v1beta1::RoleBinding {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::rbac::v1::RoleBinding) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::rbac::v1alpha1::RoleBinding> for crate::api::rbac::v1beta1::RoleBinding {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::rbac::v1alpha1::RoleBinding) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::rbac::v1beta1::RoleBinding> for crate::api::rbac::v1::RoleBinding {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::rbac::v1beta1::RoleBinding) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::rbac::v1beta1::RoleBinding> for crate::api::rbac::v1alpha1::RoleBinding {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::rbac::v1beta1::RoleBinding) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::scheduling::v1alpha1::PriorityClass> for crate::api::scheduling::v1beta1::PriorityClass {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::scheduling::v1alpha1::PriorityClass) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::scheduling::v1beta1::PriorityClass> for crate::api::scheduling::v1alpha1::PriorityClass {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::scheduling::v1beta1::PriorityClass) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::storage::v1::StorageClass> for crate::api::storage::v1beta1::StorageClass {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::storage::v1::StorageClass) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::storage::v1beta1::StorageClass> for crate::api::storage::v1::StorageClass {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::storage::v1beta1::StorageClass) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::storage::v1::VolumeAttachment> for crate::api::storage::v1beta1::VolumeAttachment {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::storage::v1::VolumeAttachment) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::storage::v1alpha1::VolumeAttachment> for crate::api::storage::v1beta1::VolumeAttachment {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::storage::v1alpha1::VolumeAttachment) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::storage::v1beta1::VolumeAttachment> for crate::api::storage::v1::VolumeAttachment {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::storage::v1beta1::VolumeAttachment) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
+impl std::convert::TryFrom<crate::api::storage::v1beta1::VolumeAttachment> for crate::api::storage::v1alpha1::VolumeAttachment {
+    type Error = crate::TryFromValueError;
+
+    fn try_from(value: crate::api::storage::v1beta1::VolumeAttachment) -> Result<Self, Self::Error> {
+        crate::convert_resource_version(value)
+    }
+}
+
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.admissionregistration.v1beta1.Webhook
 
 /// Webhook describes an admission webhook and the resources and operations it applies to.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct Webhook {
     /// AdmissionReviewVersions is an ordered list of preferred `AdmissionReview` versions the Webhook expects. API server will try to use first version in the list which it supports. If none of the versions specified in this list supported by API server, validation will fail for this object. If a persisted webhook configuration specifies allowed versions and does not include any versions known to the API Server, calls to the webhook will fail and be subject to the failure policy. Default to `\['v1beta1'\]`.
     pub admission_review_versions: Option<Vec<String>>,
@@ -58,51 +58,97 @@ pub struct Webhook {
     pub timeout_seconds: Option<i32>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for Webhook {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_admission_review_versions,
-            Key_client_config,
-            Key_failure_policy,
-            Key_name,
-            Key_namespace_selector,
-            Key_rules,
-            Key_side_effects,
-            Key_timeout_seconds,
-            Other,
+impl Webhook {
+    /// Constructs a `Webhook` from its required fields. Its optional fields are unset.
+    pub fn new(client_config: impl Into<crate::api::admissionregistration::v1beta1::WebhookClientConfig>, name: impl Into<String>) -> Self {
+        Webhook {
+            admission_review_versions: None,
+            client_config: client_config.into(),
+            failure_policy: None,
+            name: name.into(),
+            namespace_selector: None,
+            rules: None,
+            side_effects: None,
+            timeout_seconds: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl Webhook {
+    /// Returns this value with [`Webhook::admission_review_versions`] set to the given value.
+    #[must_use]
+    pub fn with_admission_review_versions(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.admission_review_versions = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`Webhook::client_config`] set to the given value.
+    #[must_use]
+    pub fn with_client_config(mut self, value: impl Into<crate::api::admissionregistration::v1beta1::WebhookClientConfig>) -> Self {
+        self.client_config = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`Webhook::failure_policy`] set to the given value.
+    #[must_use]
+    pub fn with_failure_policy(mut self, value: impl Into<String>) -> Self {
+        self.failure_policy = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "admissionReviewVersions" => Field::Key_admission_review_versions,
-                            "clientConfig" => Field::Key_client_config,
-                            "failurePolicy" => Field::Key_failure_policy,
-                            "name" => Field::Key_name,
-                            "namespaceSelector" => Field::Key_namespace_selector,
-                            "rules" => Field::Key_rules,
-                            "sideEffects" => Field::Key_side_effects,
-                            "timeoutSeconds" => Field::Key_timeout_seconds,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`Webhook::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`Webhook::namespace_selector`] set to the given value.
+    #[must_use]
+    pub fn with_namespace_selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.namespace_selector = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Webhook::rules`] set to the given value.
+    #[must_use]
+    pub fn with_rules(mut self, value: impl Into<Vec<crate::api::admissionregistration::v1beta1::RuleWithOperations>>) -> Self {
+        self.rules = Some(value.into());
+        self
+    }
 
+    /// Returns this value with [`Webhook::side_effects`] set to the given value.
+    #[must_use]
+    pub fn with_side_effects(mut self, value: impl Into<String>) -> Self {
+        self.side_effects = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Webhook::timeout_seconds`] set to the given value.
+    #[must_use]
+    pub fn with_timeout_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.timeout_seconds = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for Webhook {}
+
+impl Webhook {
+    const FIELDS: &'static [&'static str] = &[
+        "admissionReviewVersions",
+        "clientConfig",
+        "failurePolicy",
+        "name",
+        "namespaceSelector",
+        "rules",
+        "sideEffects",
+        "timeoutSeconds",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for Webhook {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -122,52 +168,151 @@ impl<'de> crate::serde::Deserialize<'de> for Webhook {
                 let mut value_side_effects: Option<String> = None;
                 let mut value_timeout_seconds: Option<i32> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Webhook", Webhook::FIELDS))? {
                     match key {
-                        Field::Key_admission_review_versions => value_admission_review_versions = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_client_config => value_client_config = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_failure_policy => value_failure_policy = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_name => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_namespace_selector => value_namespace_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_rules => value_rules = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_side_effects => value_side_effects = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_timeout_seconds => value_timeout_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_admission_review_versions = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_client_config = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_failure_policy = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(4) => value_namespace_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_rules = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_side_effects = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(7) => value_timeout_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(Webhook {
                     admission_review_versions: value_admission_review_versions,
-                    client_config: value_client_config.ok_or_else(|| crate::serde::de::Error::missing_field("clientConfig"))?,
+                    client_config: value_client_config.ok_or_else(|| crate::serde2::missing_field("Webhook", "clientConfig", &["clientConfig", "name"]))?,
                     failure_policy: value_failure_policy,
-                    name: value_name.ok_or_else(|| crate::serde::de::Error::missing_field("name"))?,
+                    name: value_name.ok_or_else(|| crate::serde2::missing_field("Webhook", "name", &["clientConfig", "name"]))?,
                     namespace_selector: value_namespace_selector,
                     rules: value_rules,
                     side_effects: value_side_effects,
                     timeout_seconds: value_timeout_seconds,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(Webhook {
+                    admission_review_versions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    client_config: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    failure_policy: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    namespace_selector: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    rules: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    side_effects: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    timeout_seconds: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "Webhook",
-            &[
-                "admissionReviewVersions",
-                "clientConfig",
-                "failurePolicy",
-                "name",
-                "namespaceSelector",
-                "rules",
-                "sideEffects",
-                "timeoutSeconds",
-            ],
+            Webhook::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, Webhook> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("Webhook")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_admission_review_versions = false;
+                let mut has_client_config = false;
+                let mut has_failure_policy = false;
+                let mut has_name = false;
+                let mut has_namespace_selector = false;
+                let mut has_rules = false;
+                let mut has_side_effects = false;
+                let mut has_timeout_seconds = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Webhook", Webhook::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.admission_review_versions))?; has_admission_review_versions = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.client_config))?; has_client_config = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.failure_policy))?; has_failure_policy = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.name))?; has_name = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.namespace_selector))?; has_namespace_selector = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.rules))?; has_rules = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.side_effects))?; has_side_effects = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.timeout_seconds))?; has_timeout_seconds = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_admission_review_versions {
+                    self.0.admission_review_versions = None;
+                }
+                if !has_client_config {
+                    return Err(crate::serde2::missing_field("Webhook", "clientConfig", &["clientConfig", "name"]));
+                }
+                if !has_failure_policy {
+                    self.0.failure_policy = None;
+                }
+                if !has_name {
+                    return Err(crate::serde2::missing_field("Webhook", "name", &["clientConfig", "name"]));
+                }
+                if !has_namespace_selector {
+                    self.0.namespace_selector = None;
+                }
+                if !has_rules {
+                    self.0.rules = None;
+                }
+                if !has_side_effects {
+                    self.0.side_effects = None;
+                }
+                if !has_timeout_seconds {
+                    self.0.timeout_seconds = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.admission_review_versions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.client_config))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.failure_policy))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.namespace_selector))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.rules))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.side_effects))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.timeout_seconds))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "Webhook",
+            Webhook::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for Webhook {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("Webhook", 8)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "admissionReviewVersions", &self.admission_review_versions)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clientConfig", &self.client_config)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "failurePolicy", &self.failure_policy)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "namespaceSelector", &self.namespace_selector)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rules", &self.rules)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "sideEffects", &self.side_effects)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "timeoutSeconds", &self.timeout_seconds)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "Webhook",
             2 +
@@ -202,10 +347,174 @@ impl crate::serde::Serialize for Webhook {
     }
 }
 
+/// A builder of [`Webhook`]. Created with [`Webhook::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct WebhookBuilder {
+    admission_review_versions: Option<Vec<String>>,
+    client_config: Option<crate::api::admissionregistration::v1beta1::WebhookClientConfig>,
+    failure_policy: Option<String>,
+    name: Option<String>,
+    namespace_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>,
+    rules: Option<Vec<crate::api::admissionregistration::v1beta1::RuleWithOperations>>,
+    side_effects: Option<String>,
+    timeout_seconds: Option<i32>,
+}
+
+#[cfg(feature = "builder")]
+impl Webhook {
+    /// Starts building a `Webhook`. Its required fields must be set before [`WebhookBuilder::build`] is called.
+    pub fn builder() -> WebhookBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl WebhookBuilder {
+    /// Sets [`Webhook::admission_review_versions`].
+    pub fn admission_review_versions(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.admission_review_versions = Some(value.into());
+        self
+    }
+
+    /// Sets [`Webhook::client_config`].
+    pub fn client_config(mut self, value: impl Into<crate::api::admissionregistration::v1beta1::WebhookClientConfig>) -> Self {
+        self.client_config = Some(value.into());
+        self
+    }
+
+    /// Sets [`Webhook::failure_policy`].
+    pub fn failure_policy(mut self, value: impl Into<String>) -> Self {
+        self.failure_policy = Some(value.into());
+        self
+    }
+
+    /// Sets [`Webhook::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`Webhook::namespace_selector`].
+    pub fn namespace_selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.namespace_selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`Webhook::rules`].
+    pub fn rules(mut self, value: impl Into<Vec<crate::api::admissionregistration::v1beta1::RuleWithOperations>>) -> Self {
+        self.rules = Some(value.into());
+        self
+    }
+
+    /// Sets [`Webhook::side_effects`].
+    pub fn side_effects(mut self, value: impl Into<String>) -> Self {
+        self.side_effects = Some(value.into());
+        self
+    }
+
+    /// Sets [`Webhook::timeout_seconds`].
+    pub fn timeout_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.timeout_seconds = Some(value.into());
+        self
+    }
+
+    /// Builds the `Webhook`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<Webhook, crate::MissingFieldError> {
+        Ok(Webhook {
+            admission_review_versions: self.admission_review_versions,
+            client_config: self.client_config.ok_or_else(|| crate::MissingFieldError::new("Webhook", "clientConfig"))?,
+            failure_policy: self.failure_policy,
+            name: self.name.ok_or_else(|| crate::MissingFieldError::new("Webhook", "name"))?,
+            namespace_selector: self.namespace_selector,
+            rules: self.rules,
+            side_effects: self.side_effects,
+            timeout_seconds: self.timeout_seconds,
+        })
+    }
+}
+
+/// The apply configuration of [`Webhook`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WebhookApplyConfiguration {
+    /// See [`Webhook::admission_review_versions`].
+    pub admission_review_versions: Option<Vec<String>>,
+
+    /// See [`Webhook::client_config`].
+    pub client_config: Option<crate::api::admissionregistration::v1beta1::WebhookClientConfigApplyConfiguration>,
+
+    /// See [`Webhook::failure_policy`].
+    pub failure_policy: Option<String>,
+
+    /// See [`Webhook::name`].
+    pub name: Option<String>,
+
+    /// See [`Webhook::namespace_selector`].
+    pub namespace_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorApplyConfiguration>,
+
+    /// See [`Webhook::rules`].
+    pub rules: Option<Vec<crate::api::admissionregistration::v1beta1::RuleWithOperationsApplyConfiguration>>,
+
+    /// See [`Webhook::side_effects`].
+    pub side_effects: Option<String>,
+
+    /// See [`Webhook::timeout_seconds`].
+    pub timeout_seconds: Option<i32>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for WebhookApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "WebhookApplyConfiguration",
+            self.admission_review_versions.as_ref().map_or(0, |_| 1) +
+            self.client_config.as_ref().map_or(0, |_| 1) +
+            self.failure_policy.as_ref().map_or(0, |_| 1) +
+            self.name.as_ref().map_or(0, |_| 1) +
+            self.namespace_selector.as_ref().map_or(0, |_| 1) +
+            self.rules.as_ref().map_or(0, |_| 1) +
+            self.side_effects.as_ref().map_or(0, |_| 1) +
+            self.timeout_seconds.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.admission_review_versions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "admissionReviewVersions", value)?;
+        }
+        if let Some(value) = &self.client_config {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clientConfig", value)?;
+        }
+        if let Some(value) = &self.failure_policy {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "failurePolicy", value)?;
+        }
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        if let Some(value) = &self.namespace_selector {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "namespaceSelector", value)?;
+        }
+        if let Some(value) = &self.rules {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "rules", value)?;
+        }
+        if let Some(value) = &self.side_effects {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "sideEffects", value)?;
+        }
+        if let Some(value) = &self.timeout_seconds {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "timeoutSeconds", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Webhook {
     fn schema_name() -> String {
-        "io.k8s.api.admissionregistration.v1beta1.Webhook".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::admissionregistration::v1beta1::Webhook".to_owned()
+        }
+        else {
+            "io.k8s.api.admissionregistration.v1beta1.Webhook".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -216,8 +525,9 @@ impl crate::schemars::JsonSchema for Webhook {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "admissionReviewVersions".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -236,8 +546,8 @@ impl crate::schemars::JsonSchema for Webhook {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "clientConfig".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::admissionregistration::v1beta1::WebhookClientConfig>().into_object();
@@ -247,8 +557,8 @@ impl crate::schemars::JsonSchema for Webhook {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "failurePolicy".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -258,8 +568,8 @@ impl crate::schemars::JsonSchema for Webhook {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -269,8 +579,8 @@ impl crate::schemars::JsonSchema for Webhook {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "namespaceSelector".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>().into_object();
@@ -280,8 +590,8 @@ impl crate::schemars::JsonSchema for Webhook {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "rules".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -295,8 +605,8 @@ impl crate::schemars::JsonSchema for Webhook {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "sideEffects".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -306,8 +616,8 @@ impl crate::schemars::JsonSchema for Webhook {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "timeoutSeconds".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -318,8 +628,9 @@ impl crate::schemars::JsonSchema for Webhook {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "clientConfig",
                     "name",
@@ -330,3 +641,19 @@ impl crate::schemars::JsonSchema for Webhook {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for Webhook {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(Webhook {
+            admission_review_versions: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            client_config: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            failure_policy: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            namespace_selector: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            rules: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            side_effects: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            timeout_seconds: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
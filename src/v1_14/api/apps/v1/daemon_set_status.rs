@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.apps.v1.DaemonSetStatus
 
 /// DaemonSetStatus represents the current status of a daemon set.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct DaemonSetStatus {
     /// Count of hash collisions for the DaemonSet. The DaemonSet controller uses this field as a collision avoidance mechanism when it needs to create the name for the newest ControllerRevision.
     pub collision_count: Option<i32>,
@@ -34,55 +34,119 @@ pub struct DaemonSetStatus {
     pub updated_number_scheduled: Option<i32>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for DaemonSetStatus {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_collision_count,
-            Key_conditions,
-            Key_current_number_scheduled,
-            Key_desired_number_scheduled,
-            Key_number_available,
-            Key_number_misscheduled,
-            Key_number_ready,
-            Key_number_unavailable,
-            Key_observed_generation,
-            Key_updated_number_scheduled,
-            Other,
+impl DaemonSetStatus {
+    /// Constructs a `DaemonSetStatus` from its required fields. Its optional fields are unset.
+    pub fn new(current_number_scheduled: impl Into<i32>, desired_number_scheduled: impl Into<i32>, number_misscheduled: impl Into<i32>, number_ready: impl Into<i32>) -> Self {
+        DaemonSetStatus {
+            collision_count: None,
+            conditions: None,
+            current_number_scheduled: current_number_scheduled.into(),
+            desired_number_scheduled: desired_number_scheduled.into(),
+            number_available: None,
+            number_misscheduled: number_misscheduled.into(),
+            number_ready: number_ready.into(),
+            number_unavailable: None,
+            observed_generation: None,
+            updated_number_scheduled: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl DaemonSetStatus {
+    /// Returns this value with [`DaemonSetStatus::collision_count`] set to the given value.
+    #[must_use]
+    pub fn with_collision_count(mut self, value: impl Into<i32>) -> Self {
+        self.collision_count = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`DaemonSetStatus::conditions`] set to the given value.
+    #[must_use]
+    pub fn with_conditions(mut self, value: impl Into<Vec<crate::api::apps::v1::DaemonSetCondition>>) -> Self {
+        self.conditions = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`DaemonSetStatus::current_number_scheduled`] set to the given value.
+    #[must_use]
+    pub fn with_current_number_scheduled(mut self, value: impl Into<i32>) -> Self {
+        self.current_number_scheduled = value.into();
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "collisionCount" => Field::Key_collision_count,
-                            "conditions" => Field::Key_conditions,
-                            "currentNumberScheduled" => Field::Key_current_number_scheduled,
-                            "desiredNumberScheduled" => Field::Key_desired_number_scheduled,
-                            "numberAvailable" => Field::Key_number_available,
-                            "numberMisscheduled" => Field::Key_number_misscheduled,
-                            "numberReady" => Field::Key_number_ready,
-                            "numberUnavailable" => Field::Key_number_unavailable,
-                            "observedGeneration" => Field::Key_observed_generation,
-                            "updatedNumberScheduled" => Field::Key_updated_number_scheduled,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`DaemonSetStatus::desired_number_scheduled`] set to the given value.
+    #[must_use]
+    pub fn with_desired_number_scheduled(mut self, value: impl Into<i32>) -> Self {
+        self.desired_number_scheduled = value.into();
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`DaemonSetStatus::number_available`] set to the given value.
+    #[must_use]
+    pub fn with_number_available(mut self, value: impl Into<i32>) -> Self {
+        self.number_available = Some(value.into());
+        self
+    }
 
+    /// Returns this value with [`DaemonSetStatus::number_misscheduled`] set to the given value.
+    #[must_use]
+    pub fn with_number_misscheduled(mut self, value: impl Into<i32>) -> Self {
+        self.number_misscheduled = value.into();
+        self
+    }
+
+    /// Returns this value with [`DaemonSetStatus::number_ready`] set to the given value.
+    #[must_use]
+    pub fn with_number_ready(mut self, value: impl Into<i32>) -> Self {
+        self.number_ready = value.into();
+        self
+    }
+
+    /// Returns this value with [`DaemonSetStatus::number_unavailable`] set to the given value.
+    #[must_use]
+    pub fn with_number_unavailable(mut self, value: impl Into<i32>) -> Self {
+        self.number_unavailable = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`DaemonSetStatus::observed_generation`] set to the given value.
+    #[must_use]
+    pub fn with_observed_generation(mut self, value: impl Into<i64>) -> Self {
+        self.observed_generation = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`DaemonSetStatus::updated_number_scheduled`] set to the given value.
+    #[must_use]
+    pub fn with_updated_number_scheduled(mut self, value: impl Into<i32>) -> Self {
+        self.updated_number_scheduled = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for DaemonSetStatus {
+    const FIELD_PATCH_STRATEGIES: &'static [crate::FieldPatchStrategy] = &[
+        crate::FieldPatchStrategy { field: "conditions", merge: true, retain_keys: false, merge_key: Some("type") },
+    ];
+}
+
+impl DaemonSetStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "collisionCount",
+        "conditions",
+        "currentNumberScheduled",
+        "desiredNumberScheduled",
+        "numberAvailable",
+        "numberMisscheduled",
+        "numberReady",
+        "numberUnavailable",
+        "observedGeneration",
+        "updatedNumberScheduled",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for DaemonSetStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -104,58 +168,171 @@ impl<'de> crate::serde::Deserialize<'de> for DaemonSetStatus {
                 let mut value_observed_generation: Option<i64> = None;
                 let mut value_updated_number_scheduled: Option<i32> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("DaemonSetStatus", DaemonSetStatus::FIELDS))? {
                     match key {
-                        Field::Key_collision_count => value_collision_count = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_conditions => value_conditions = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_current_number_scheduled => value_current_number_scheduled = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_desired_number_scheduled => value_desired_number_scheduled = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_number_available => value_number_available = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_number_misscheduled => value_number_misscheduled = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_number_ready => value_number_ready = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_number_unavailable => value_number_unavailable = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_observed_generation => value_observed_generation = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_updated_number_scheduled => value_updated_number_scheduled = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_collision_count = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_conditions = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_current_number_scheduled = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_desired_number_scheduled = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(4) => value_number_available = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_number_misscheduled = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(6) => value_number_ready = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(7) => value_number_unavailable = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(8) => value_observed_generation = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(9) => value_updated_number_scheduled = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(DaemonSetStatus {
                     collision_count: value_collision_count,
                     conditions: value_conditions,
-                    current_number_scheduled: value_current_number_scheduled.ok_or_else(|| crate::serde::de::Error::missing_field("currentNumberScheduled"))?,
-                    desired_number_scheduled: value_desired_number_scheduled.ok_or_else(|| crate::serde::de::Error::missing_field("desiredNumberScheduled"))?,
+                    current_number_scheduled: value_current_number_scheduled.ok_or_else(|| crate::serde2::missing_field("DaemonSetStatus", "currentNumberScheduled", &["currentNumberScheduled", "desiredNumberScheduled", "numberMisscheduled", "numberReady"]))?,
+                    desired_number_scheduled: value_desired_number_scheduled.ok_or_else(|| crate::serde2::missing_field("DaemonSetStatus", "desiredNumberScheduled", &["currentNumberScheduled", "desiredNumberScheduled", "numberMisscheduled", "numberReady"]))?,
                     number_available: value_number_available,
-                    number_misscheduled: value_number_misscheduled.ok_or_else(|| crate::serde::de::Error::missing_field("numberMisscheduled"))?,
-                    number_ready: value_number_ready.ok_or_else(|| crate::serde::de::Error::missing_field("numberReady"))?,
+                    number_misscheduled: value_number_misscheduled.ok_or_else(|| crate::serde2::missing_field("DaemonSetStatus", "numberMisscheduled", &["currentNumberScheduled", "desiredNumberScheduled", "numberMisscheduled", "numberReady"]))?,
+                    number_ready: value_number_ready.ok_or_else(|| crate::serde2::missing_field("DaemonSetStatus", "numberReady", &["currentNumberScheduled", "desiredNumberScheduled", "numberMisscheduled", "numberReady"]))?,
                     number_unavailable: value_number_unavailable,
                     observed_generation: value_observed_generation,
                     updated_number_scheduled: value_updated_number_scheduled,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(DaemonSetStatus {
+                    collision_count: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    conditions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    current_number_scheduled: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    desired_number_scheduled: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    number_available: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    number_misscheduled: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    number_ready: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    number_unavailable: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                    observed_generation: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?,
+                    updated_number_scheduled: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "DaemonSetStatus",
-            &[
-                "collisionCount",
-                "conditions",
-                "currentNumberScheduled",
-                "desiredNumberScheduled",
-                "numberAvailable",
-                "numberMisscheduled",
-                "numberReady",
-                "numberUnavailable",
-                "observedGeneration",
-                "updatedNumberScheduled",
-            ],
+            DaemonSetStatus::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, DaemonSetStatus> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("DaemonSetStatus")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_collision_count = false;
+                let mut has_conditions = false;
+                let mut has_current_number_scheduled = false;
+                let mut has_desired_number_scheduled = false;
+                let mut has_number_available = false;
+                let mut has_number_misscheduled = false;
+                let mut has_number_ready = false;
+                let mut has_number_unavailable = false;
+                let mut has_observed_generation = false;
+                let mut has_updated_number_scheduled = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("DaemonSetStatus", DaemonSetStatus::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.collision_count))?; has_collision_count = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.conditions))?; has_conditions = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.current_number_scheduled))?; has_current_number_scheduled = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.desired_number_scheduled))?; has_desired_number_scheduled = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.number_available))?; has_number_available = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.number_misscheduled))?; has_number_misscheduled = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.number_ready))?; has_number_ready = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.number_unavailable))?; has_number_unavailable = true; },
+                        Some(8) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.observed_generation))?; has_observed_generation = true; },
+                        Some(9) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.updated_number_scheduled))?; has_updated_number_scheduled = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_collision_count {
+                    self.0.collision_count = None;
+                }
+                if !has_conditions {
+                    self.0.conditions = None;
+                }
+                if !has_current_number_scheduled {
+                    return Err(crate::serde2::missing_field("DaemonSetStatus", "currentNumberScheduled", &["currentNumberScheduled", "desiredNumberScheduled", "numberMisscheduled", "numberReady"]));
+                }
+                if !has_desired_number_scheduled {
+                    return Err(crate::serde2::missing_field("DaemonSetStatus", "desiredNumberScheduled", &["currentNumberScheduled", "desiredNumberScheduled", "numberMisscheduled", "numberReady"]));
+                }
+                if !has_number_available {
+                    self.0.number_available = None;
+                }
+                if !has_number_misscheduled {
+                    return Err(crate::serde2::missing_field("DaemonSetStatus", "numberMisscheduled", &["currentNumberScheduled", "desiredNumberScheduled", "numberMisscheduled", "numberReady"]));
+                }
+                if !has_number_ready {
+                    return Err(crate::serde2::missing_field("DaemonSetStatus", "numberReady", &["currentNumberScheduled", "desiredNumberScheduled", "numberMisscheduled", "numberReady"]));
+                }
+                if !has_number_unavailable {
+                    self.0.number_unavailable = None;
+                }
+                if !has_observed_generation {
+                    self.0.observed_generation = None;
+                }
+                if !has_updated_number_scheduled {
+                    self.0.updated_number_scheduled = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.collision_count))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.conditions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.current_number_scheduled))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.desired_number_scheduled))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.number_available))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.number_misscheduled))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.number_ready))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.number_unavailable))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.observed_generation))?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.updated_number_scheduled))?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "DaemonSetStatus",
+            DaemonSetStatus::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for DaemonSetStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("DaemonSetStatus", 10)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "collisionCount", &self.collision_count)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conditions", &self.conditions)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "currentNumberScheduled", &self.current_number_scheduled)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "desiredNumberScheduled", &self.desired_number_scheduled)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "numberAvailable", &self.number_available)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "numberMisscheduled", &self.number_misscheduled)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "numberReady", &self.number_ready)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "numberUnavailable", &self.number_unavailable)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "observedGeneration", &self.observed_generation)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "updatedNumberScheduled", &self.updated_number_scheduled)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "DaemonSetStatus",
             4 +
@@ -192,10 +369,204 @@ impl crate::serde::Serialize for DaemonSetStatus {
     }
 }
 
+/// A builder of [`DaemonSetStatus`]. Created with [`DaemonSetStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct DaemonSetStatusBuilder {
+    collision_count: Option<i32>,
+    conditions: Option<Vec<crate::api::apps::v1::DaemonSetCondition>>,
+    current_number_scheduled: Option<i32>,
+    desired_number_scheduled: Option<i32>,
+    number_available: Option<i32>,
+    number_misscheduled: Option<i32>,
+    number_ready: Option<i32>,
+    number_unavailable: Option<i32>,
+    observed_generation: Option<i64>,
+    updated_number_scheduled: Option<i32>,
+}
+
+#[cfg(feature = "builder")]
+impl DaemonSetStatus {
+    /// Starts building a `DaemonSetStatus`. Its required fields must be set before [`DaemonSetStatusBuilder::build`] is called.
+    pub fn builder() -> DaemonSetStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl DaemonSetStatusBuilder {
+    /// Sets [`DaemonSetStatus::collision_count`].
+    pub fn collision_count(mut self, value: impl Into<i32>) -> Self {
+        self.collision_count = Some(value.into());
+        self
+    }
+
+    /// Sets [`DaemonSetStatus::conditions`].
+    pub fn conditions(mut self, value: impl Into<Vec<crate::api::apps::v1::DaemonSetCondition>>) -> Self {
+        self.conditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`DaemonSetStatus::current_number_scheduled`].
+    pub fn current_number_scheduled(mut self, value: impl Into<i32>) -> Self {
+        self.current_number_scheduled = Some(value.into());
+        self
+    }
+
+    /// Sets [`DaemonSetStatus::desired_number_scheduled`].
+    pub fn desired_number_scheduled(mut self, value: impl Into<i32>) -> Self {
+        self.desired_number_scheduled = Some(value.into());
+        self
+    }
+
+    /// Sets [`DaemonSetStatus::number_available`].
+    pub fn number_available(mut self, value: impl Into<i32>) -> Self {
+        self.number_available = Some(value.into());
+        self
+    }
+
+    /// Sets [`DaemonSetStatus::number_misscheduled`].
+    pub fn number_misscheduled(mut self, value: impl Into<i32>) -> Self {
+        self.number_misscheduled = Some(value.into());
+        self
+    }
+
+    /// Sets [`DaemonSetStatus::number_ready`].
+    pub fn number_ready(mut self, value: impl Into<i32>) -> Self {
+        self.number_ready = Some(value.into());
+        self
+    }
+
+    /// Sets [`DaemonSetStatus::number_unavailable`].
+    pub fn number_unavailable(mut self, value: impl Into<i32>) -> Self {
+        self.number_unavailable = Some(value.into());
+        self
+    }
+
+    /// Sets [`DaemonSetStatus::observed_generation`].
+    pub fn observed_generation(mut self, value: impl Into<i64>) -> Self {
+        self.observed_generation = Some(value.into());
+        self
+    }
+
+    /// Sets [`DaemonSetStatus::updated_number_scheduled`].
+    pub fn updated_number_scheduled(mut self, value: impl Into<i32>) -> Self {
+        self.updated_number_scheduled = Some(value.into());
+        self
+    }
+
+    /// Builds the `DaemonSetStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<DaemonSetStatus, crate::MissingFieldError> {
+        Ok(DaemonSetStatus {
+            collision_count: self.collision_count,
+            conditions: self.conditions,
+            current_number_scheduled: self.current_number_scheduled.ok_or_else(|| crate::MissingFieldError::new("DaemonSetStatus", "currentNumberScheduled"))?,
+            desired_number_scheduled: self.desired_number_scheduled.ok_or_else(|| crate::MissingFieldError::new("DaemonSetStatus", "desiredNumberScheduled"))?,
+            number_available: self.number_available,
+            number_misscheduled: self.number_misscheduled.ok_or_else(|| crate::MissingFieldError::new("DaemonSetStatus", "numberMisscheduled"))?,
+            number_ready: self.number_ready.ok_or_else(|| crate::MissingFieldError::new("DaemonSetStatus", "numberReady"))?,
+            number_unavailable: self.number_unavailable,
+            observed_generation: self.observed_generation,
+            updated_number_scheduled: self.updated_number_scheduled,
+        })
+    }
+}
+
+/// The apply configuration of [`DaemonSetStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DaemonSetStatusApplyConfiguration {
+    /// See [`DaemonSetStatus::collision_count`].
+    pub collision_count: Option<i32>,
+
+    /// See [`DaemonSetStatus::conditions`].
+    pub conditions: Option<Vec<crate::api::apps::v1::DaemonSetConditionApplyConfiguration>>,
+
+    /// See [`DaemonSetStatus::current_number_scheduled`].
+    pub current_number_scheduled: Option<i32>,
+
+    /// See [`DaemonSetStatus::desired_number_scheduled`].
+    pub desired_number_scheduled: Option<i32>,
+
+    /// See [`DaemonSetStatus::number_available`].
+    pub number_available: Option<i32>,
+
+    /// See [`DaemonSetStatus::number_misscheduled`].
+    pub number_misscheduled: Option<i32>,
+
+    /// See [`DaemonSetStatus::number_ready`].
+    pub number_ready: Option<i32>,
+
+    /// See [`DaemonSetStatus::number_unavailable`].
+    pub number_unavailable: Option<i32>,
+
+    /// See [`DaemonSetStatus::observed_generation`].
+    pub observed_generation: Option<i64>,
+
+    /// See [`DaemonSetStatus::updated_number_scheduled`].
+    pub updated_number_scheduled: Option<i32>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for DaemonSetStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "DaemonSetStatusApplyConfiguration",
+            self.collision_count.as_ref().map_or(0, |_| 1) +
+            self.conditions.as_ref().map_or(0, |_| 1) +
+            self.current_number_scheduled.as_ref().map_or(0, |_| 1) +
+            self.desired_number_scheduled.as_ref().map_or(0, |_| 1) +
+            self.number_available.as_ref().map_or(0, |_| 1) +
+            self.number_misscheduled.as_ref().map_or(0, |_| 1) +
+            self.number_ready.as_ref().map_or(0, |_| 1) +
+            self.number_unavailable.as_ref().map_or(0, |_| 1) +
+            self.observed_generation.as_ref().map_or(0, |_| 1) +
+            self.updated_number_scheduled.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.collision_count {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "collisionCount", value)?;
+        }
+        if let Some(value) = &self.conditions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conditions", value)?;
+        }
+        if let Some(value) = &self.current_number_scheduled {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "currentNumberScheduled", value)?;
+        }
+        if let Some(value) = &self.desired_number_scheduled {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "desiredNumberScheduled", value)?;
+        }
+        if let Some(value) = &self.number_available {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "numberAvailable", value)?;
+        }
+        if let Some(value) = &self.number_misscheduled {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "numberMisscheduled", value)?;
+        }
+        if let Some(value) = &self.number_ready {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "numberReady", value)?;
+        }
+        if let Some(value) = &self.number_unavailable {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "numberUnavailable", value)?;
+        }
+        if let Some(value) = &self.observed_generation {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "observedGeneration", value)?;
+        }
+        if let Some(value) = &self.updated_number_scheduled {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "updatedNumberScheduled", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DaemonSetStatus {
     fn schema_name() -> String {
-        "io.k8s.api.apps.v1.DaemonSetStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::apps::v1::DaemonSetStatus".to_owned()
+        }
+        else {
+            "io.k8s.api.apps.v1.DaemonSetStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -206,8 +577,9 @@ impl crate::schemars::JsonSchema for DaemonSetStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "collisionCount".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -218,8 +590,8 @@ impl crate::schemars::JsonSchema for DaemonSetStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "conditions".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -233,8 +605,8 @@ impl crate::schemars::JsonSchema for DaemonSetStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "currentNumberScheduled".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -245,8 +617,8 @@ impl crate::schemars::JsonSchema for DaemonSetStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "desiredNumberScheduled".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -257,8 +629,8 @@ impl crate::schemars::JsonSchema for DaemonSetStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "numberAvailable".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -269,8 +641,8 @@ impl crate::schemars::JsonSchema for DaemonSetStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "numberMisscheduled".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -281,8 +653,8 @@ impl crate::schemars::JsonSchema for DaemonSetStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "numberReady".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -293,8 +665,8 @@ impl crate::schemars::JsonSchema for DaemonSetStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "numberUnavailable".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -305,8 +677,8 @@ impl crate::schemars::JsonSchema for DaemonSetStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "observedGeneration".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -317,8 +689,8 @@ impl crate::schemars::JsonSchema for DaemonSetStatus {
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "updatedNumberScheduled".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -329,8 +701,9 @@ impl crate::schemars::JsonSchema for DaemonSetStatus {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "currentNumberScheduled",
                     "desiredNumberScheduled",
@@ -343,3 +716,21 @@ impl crate::schemars::JsonSchema for DaemonSetStatus {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for DaemonSetStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(DaemonSetStatus {
+            collision_count: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            conditions: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            current_number_scheduled: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            desired_number_scheduled: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            number_available: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            number_misscheduled: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            number_ready: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            number_unavailable: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            observed_generation: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            updated_number_scheduled: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.auditregistration.v1alpha1.AuditSinkSpec
 
 /// AuditSinkSpec holds the spec for the audit sink
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct AuditSinkSpec {
     /// Policy defines the policy for selecting which events should be sent to the webhook required
     pub policy: crate::api::auditregistration::v1alpha1::Policy,
@@ -10,39 +10,43 @@ pub struct AuditSinkSpec {
     pub webhook: crate::api::auditregistration::v1alpha1::Webhook,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for AuditSinkSpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_policy,
-            Key_webhook,
-            Other,
+impl AuditSinkSpec {
+    /// Constructs a `AuditSinkSpec` from its required fields. Its optional fields are unset.
+    pub fn new(policy: impl Into<crate::api::auditregistration::v1alpha1::Policy>, webhook: impl Into<crate::api::auditregistration::v1alpha1::Webhook>) -> Self {
+        AuditSinkSpec {
+            policy: policy.into(),
+            webhook: webhook.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl AuditSinkSpec {
+    /// Returns this value with [`AuditSinkSpec::policy`] set to the given value.
+    #[must_use]
+    pub fn with_policy(mut self, value: impl Into<crate::api::auditregistration::v1alpha1::Policy>) -> Self {
+        self.policy = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`AuditSinkSpec::webhook`] set to the given value.
+    #[must_use]
+    pub fn with_webhook(mut self, value: impl Into<crate::api::auditregistration::v1alpha1::Webhook>) -> Self {
+        self.webhook = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "policy" => Field::Key_policy,
-                            "webhook" => Field::Key_webhook,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for AuditSinkSpec {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl AuditSinkSpec {
+    const FIELDS: &'static [&'static str] = &[
+        "policy",
+        "webhook",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for AuditSinkSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +60,91 @@ impl<'de> crate::serde::Deserialize<'de> for AuditSinkSpec {
                 let mut value_policy: Option<crate::api::auditregistration::v1alpha1::Policy> = None;
                 let mut value_webhook: Option<crate::api::auditregistration::v1alpha1::Webhook> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("AuditSinkSpec", AuditSinkSpec::FIELDS))? {
                     match key {
-                        Field::Key_policy => value_policy = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_webhook => value_webhook = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_policy = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_webhook = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(AuditSinkSpec {
-                    policy: value_policy.ok_or_else(|| crate::serde::de::Error::missing_field("policy"))?,
-                    webhook: value_webhook.ok_or_else(|| crate::serde::de::Error::missing_field("webhook"))?,
+                    policy: value_policy.ok_or_else(|| crate::serde2::missing_field("AuditSinkSpec", "policy", &["policy", "webhook"]))?,
+                    webhook: value_webhook.ok_or_else(|| crate::serde2::missing_field("AuditSinkSpec", "webhook", &["policy", "webhook"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(AuditSinkSpec {
+                    policy: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    webhook: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "AuditSinkSpec",
-            &[
-                "policy",
-                "webhook",
-            ],
+            AuditSinkSpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, AuditSinkSpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("AuditSinkSpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_policy = false;
+                let mut has_webhook = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("AuditSinkSpec", AuditSinkSpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.policy))?; has_policy = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.webhook))?; has_webhook = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_policy {
+                    return Err(crate::serde2::missing_field("AuditSinkSpec", "policy", &["policy", "webhook"]));
+                }
+                if !has_webhook {
+                    return Err(crate::serde2::missing_field("AuditSinkSpec", "webhook", &["policy", "webhook"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.policy))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.webhook))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "AuditSinkSpec",
+            AuditSinkSpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for AuditSinkSpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("AuditSinkSpec", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "policy", &self.policy)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "webhook", &self.webhook)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "AuditSinkSpec",
             2,
@@ -94,10 +155,84 @@ impl crate::serde::Serialize for AuditSinkSpec {
     }
 }
 
+/// A builder of [`AuditSinkSpec`]. Created with [`AuditSinkSpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct AuditSinkSpecBuilder {
+    policy: Option<crate::api::auditregistration::v1alpha1::Policy>,
+    webhook: Option<crate::api::auditregistration::v1alpha1::Webhook>,
+}
+
+#[cfg(feature = "builder")]
+impl AuditSinkSpec {
+    /// Starts building a `AuditSinkSpec`. Its required fields must be set before [`AuditSinkSpecBuilder::build`] is called.
+    pub fn builder() -> AuditSinkSpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl AuditSinkSpecBuilder {
+    /// Sets [`AuditSinkSpec::policy`].
+    pub fn policy(mut self, value: impl Into<crate::api::auditregistration::v1alpha1::Policy>) -> Self {
+        self.policy = Some(value.into());
+        self
+    }
+
+    /// Sets [`AuditSinkSpec::webhook`].
+    pub fn webhook(mut self, value: impl Into<crate::api::auditregistration::v1alpha1::Webhook>) -> Self {
+        self.webhook = Some(value.into());
+        self
+    }
+
+    /// Builds the `AuditSinkSpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<AuditSinkSpec, crate::MissingFieldError> {
+        Ok(AuditSinkSpec {
+            policy: self.policy.ok_or_else(|| crate::MissingFieldError::new("AuditSinkSpec", "policy"))?,
+            webhook: self.webhook.ok_or_else(|| crate::MissingFieldError::new("AuditSinkSpec", "webhook"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`AuditSinkSpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AuditSinkSpecApplyConfiguration {
+    /// See [`AuditSinkSpec::policy`].
+    pub policy: Option<crate::api::auditregistration::v1alpha1::PolicyApplyConfiguration>,
+
+    /// See [`AuditSinkSpec::webhook`].
+    pub webhook: Option<crate::api::auditregistration::v1alpha1::WebhookApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for AuditSinkSpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "AuditSinkSpecApplyConfiguration",
+            self.policy.as_ref().map_or(0, |_| 1) +
+            self.webhook.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.policy {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "policy", value)?;
+        }
+        if let Some(value) = &self.webhook {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "webhook", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for AuditSinkSpec {
     fn schema_name() -> String {
-        "io.k8s.api.auditregistration.v1alpha1.AuditSinkSpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::auditregistration::v1alpha1::AuditSinkSpec".to_owned()
+        }
+        else {
+            "io.k8s.api.auditregistration.v1alpha1.AuditSinkSpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -108,8 +243,9 @@ impl crate::schemars::JsonSchema for AuditSinkSpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "policy".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::auditregistration::v1alpha1::Policy>().into_object();
@@ -119,8 +255,8 @@ impl crate::schemars::JsonSchema for AuditSinkSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "webhook".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::auditregistration::v1alpha1::Webhook>().into_object();
@@ -130,8 +266,9 @@ impl crate::schemars::JsonSchema for AuditSinkSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "policy",
                     "webhook",
@@ -142,3 +279,13 @@ impl crate::schemars::JsonSchema for AuditSinkSpec {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for AuditSinkSpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(AuditSinkSpec {
+            policy: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            webhook: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
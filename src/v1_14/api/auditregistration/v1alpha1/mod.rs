@@ -1,22 +1,50 @@
 
 mod audit_sink;
 pub use self::audit_sink::AuditSink;
+#[cfg(feature = "builder")]
+pub use self::audit_sink::AuditSinkBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::audit_sink::AuditSinkApplyConfiguration;
 #[cfg(feature = "api")] pub use self::audit_sink::{ReadAuditSinkOptional, ReadAuditSinkResponse};
 
 mod audit_sink_spec;
 pub use self::audit_sink_spec::AuditSinkSpec;
+#[cfg(feature = "builder")]
+pub use self::audit_sink_spec::AuditSinkSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::audit_sink_spec::AuditSinkSpecApplyConfiguration;
 
 mod policy;
 pub use self::policy::Policy;
+#[cfg(feature = "builder")]
+pub use self::policy::PolicyBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::policy::PolicyApplyConfiguration;
 
 mod service_reference;
 pub use self::service_reference::ServiceReference;
+#[cfg(feature = "builder")]
+pub use self::service_reference::ServiceReferenceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::service_reference::ServiceReferenceApplyConfiguration;
 
 mod webhook;
 pub use self::webhook::Webhook;
+#[cfg(feature = "builder")]
+pub use self::webhook::WebhookBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::webhook::WebhookApplyConfiguration;
 
 mod webhook_client_config;
 pub use self::webhook_client_config::WebhookClientConfig;
+#[cfg(feature = "builder")]
+pub use self::webhook_client_config::WebhookClientConfigBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::webhook_client_config::WebhookClientConfigApplyConfiguration;
 
 mod webhook_throttle_config;
 pub use self::webhook_throttle_config::WebhookThrottleConfig;
+#[cfg(feature = "builder")]
+pub use self::webhook_throttle_config::WebhookThrottleConfigBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::webhook_throttle_config::WebhookThrottleConfigApplyConfiguration;
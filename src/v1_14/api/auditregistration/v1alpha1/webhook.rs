@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.auditregistration.v1alpha1.Webhook
 
 /// Webhook holds the configuration of the webhook
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct Webhook {
     /// ClientConfig holds the connection parameters for the webhook required
     pub client_config: crate::api::auditregistration::v1alpha1::WebhookClientConfig,
@@ -10,39 +10,43 @@ pub struct Webhook {
     pub throttle: Option<crate::api::auditregistration::v1alpha1::WebhookThrottleConfig>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for Webhook {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_client_config,
-            Key_throttle,
-            Other,
+impl Webhook {
+    /// Constructs a `Webhook` from its required fields. Its optional fields are unset.
+    pub fn new(client_config: impl Into<crate::api::auditregistration::v1alpha1::WebhookClientConfig>) -> Self {
+        Webhook {
+            client_config: client_config.into(),
+            throttle: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl Webhook {
+    /// Returns this value with [`Webhook::client_config`] set to the given value.
+    #[must_use]
+    pub fn with_client_config(mut self, value: impl Into<crate::api::auditregistration::v1alpha1::WebhookClientConfig>) -> Self {
+        self.client_config = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`Webhook::throttle`] set to the given value.
+    #[must_use]
+    pub fn with_throttle(mut self, value: impl Into<crate::api::auditregistration::v1alpha1::WebhookThrottleConfig>) -> Self {
+        self.throttle = Some(value.into());
+        self
+    }
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for Webhook {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "clientConfig" => Field::Key_client_config,
-                            "throttle" => Field::Key_throttle,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl Webhook {
+    const FIELDS: &'static [&'static str] = &[
+        "clientConfig",
+        "throttle",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for Webhook {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +60,91 @@ impl<'de> crate::serde::Deserialize<'de> for Webhook {
                 let mut value_client_config: Option<crate::api::auditregistration::v1alpha1::WebhookClientConfig> = None;
                 let mut value_throttle: Option<crate::api::auditregistration::v1alpha1::WebhookThrottleConfig> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Webhook", Webhook::FIELDS))? {
                     match key {
-                        Field::Key_client_config => value_client_config = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_throttle => value_throttle = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_client_config = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_throttle = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(Webhook {
-                    client_config: value_client_config.ok_or_else(|| crate::serde::de::Error::missing_field("clientConfig"))?,
+                    client_config: value_client_config.ok_or_else(|| crate::serde2::missing_field("Webhook", "clientConfig", &["clientConfig"]))?,
                     throttle: value_throttle,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(Webhook {
+                    client_config: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    throttle: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "Webhook",
-            &[
-                "clientConfig",
-                "throttle",
-            ],
+            Webhook::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, Webhook> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("Webhook")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_client_config = false;
+                let mut has_throttle = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Webhook", Webhook::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.client_config))?; has_client_config = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.throttle))?; has_throttle = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_client_config {
+                    return Err(crate::serde2::missing_field("Webhook", "clientConfig", &["clientConfig"]));
+                }
+                if !has_throttle {
+                    self.0.throttle = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.client_config))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.throttle))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "Webhook",
+            Webhook::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for Webhook {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("Webhook", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clientConfig", &self.client_config)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "throttle", &self.throttle)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "Webhook",
             1 +
@@ -97,10 +158,84 @@ impl crate::serde::Serialize for Webhook {
     }
 }
 
+/// A builder of [`Webhook`]. Created with [`Webhook::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct WebhookBuilder {
+    client_config: Option<crate::api::auditregistration::v1alpha1::WebhookClientConfig>,
+    throttle: Option<crate::api::auditregistration::v1alpha1::WebhookThrottleConfig>,
+}
+
+#[cfg(feature = "builder")]
+impl Webhook {
+    /// Starts building a `Webhook`. Its required fields must be set before [`WebhookBuilder::build`] is called.
+    pub fn builder() -> WebhookBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl WebhookBuilder {
+    /// Sets [`Webhook::client_config`].
+    pub fn client_config(mut self, value: impl Into<crate::api::auditregistration::v1alpha1::WebhookClientConfig>) -> Self {
+        self.client_config = Some(value.into());
+        self
+    }
+
+    /// Sets [`Webhook::throttle`].
+    pub fn throttle(mut self, value: impl Into<crate::api::auditregistration::v1alpha1::WebhookThrottleConfig>) -> Self {
+        self.throttle = Some(value.into());
+        self
+    }
+
+    /// Builds the `Webhook`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<Webhook, crate::MissingFieldError> {
+        Ok(Webhook {
+            client_config: self.client_config.ok_or_else(|| crate::MissingFieldError::new("Webhook", "clientConfig"))?,
+            throttle: self.throttle,
+        })
+    }
+}
+
+/// The apply configuration of [`Webhook`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WebhookApplyConfiguration {
+    /// See [`Webhook::client_config`].
+    pub client_config: Option<crate::api::auditregistration::v1alpha1::WebhookClientConfigApplyConfiguration>,
+
+    /// See [`Webhook::throttle`].
+    pub throttle: Option<crate::api::auditregistration::v1alpha1::WebhookThrottleConfigApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for WebhookApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "WebhookApplyConfiguration",
+            self.client_config.as_ref().map_or(0, |_| 1) +
+            self.throttle.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.client_config {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "clientConfig", value)?;
+        }
+        if let Some(value) = &self.throttle {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "throttle", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Webhook {
     fn schema_name() -> String {
-        "io.k8s.api.auditregistration.v1alpha1.Webhook".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::auditregistration::v1alpha1::Webhook".to_owned()
+        }
+        else {
+            "io.k8s.api.auditregistration.v1alpha1.Webhook".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -111,8 +246,9 @@ impl crate::schemars::JsonSchema for Webhook {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "clientConfig".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::auditregistration::v1alpha1::WebhookClientConfig>().into_object();
@@ -122,8 +258,8 @@ impl crate::schemars::JsonSchema for Webhook {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "throttle".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::auditregistration::v1alpha1::WebhookThrottleConfig>().into_object();
@@ -133,8 +269,9 @@ impl crate::schemars::JsonSchema for Webhook {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "clientConfig",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -144,3 +281,13 @@ impl crate::schemars::JsonSchema for Webhook {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for Webhook {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(Webhook {
+            client_config: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            throttle: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
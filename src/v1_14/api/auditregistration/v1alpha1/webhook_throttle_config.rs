@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.auditregistration.v1alpha1.WebhookThrottleConfig
 
 /// WebhookThrottleConfig holds the configuration for throttling events
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 pub struct WebhookThrottleConfig {
     /// ThrottleBurst is the maximum number of events sent at the same moment default 15 QPS
     pub burst: Option<i64>,
@@ -10,39 +10,33 @@ pub struct WebhookThrottleConfig {
     pub qps: Option<i64>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for WebhookThrottleConfig {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_burst,
-            Key_qps,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl WebhookThrottleConfig {
+    /// Returns this value with [`WebhookThrottleConfig::burst`] set to the given value.
+    #[must_use]
+    pub fn with_burst(mut self, value: impl Into<i64>) -> Self {
+        self.burst = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`WebhookThrottleConfig::qps`] set to the given value.
+    #[must_use]
+    pub fn with_qps(mut self, value: impl Into<i64>) -> Self {
+        self.qps = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "burst" => Field::Key_burst,
-                            "qps" => Field::Key_qps,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for WebhookThrottleConfig {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl WebhookThrottleConfig {
+    const FIELDS: &'static [&'static str] = &[
+        "burst",
+        "qps",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for WebhookThrottleConfig {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,11 +50,11 @@ impl<'de> crate::serde::Deserialize<'de> for WebhookThrottleConfig {
                 let mut value_burst: Option<i64> = None;
                 let mut value_qps: Option<i64> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("WebhookThrottleConfig", WebhookThrottleConfig::FIELDS))? {
                     match key {
-                        Field::Key_burst => value_burst = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_qps => value_qps = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_burst = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_qps = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -69,21 +63,78 @@ impl<'de> crate::serde::Deserialize<'de> for WebhookThrottleConfig {
                     qps: value_qps,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(WebhookThrottleConfig {
+                    burst: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    qps: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "WebhookThrottleConfig",
-            &[
-                "burst",
-                "qps",
-            ],
+            WebhookThrottleConfig::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, WebhookThrottleConfig> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("WebhookThrottleConfig")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_burst = false;
+                let mut has_qps = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("WebhookThrottleConfig", WebhookThrottleConfig::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.burst))?; has_burst = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.qps))?; has_qps = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_burst {
+                    self.0.burst = None;
+                }
+                if !has_qps {
+                    self.0.qps = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.burst))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.qps))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "WebhookThrottleConfig",
+            WebhookThrottleConfig::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for WebhookThrottleConfig {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("WebhookThrottleConfig", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "burst", &self.burst)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "qps", &self.qps)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "WebhookThrottleConfig",
             self.burst.as_ref().map_or(0, |_| 1) +
@@ -99,10 +150,84 @@ impl crate::serde::Serialize for WebhookThrottleConfig {
     }
 }
 
+/// A builder of [`WebhookThrottleConfig`]. Created with [`WebhookThrottleConfig::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct WebhookThrottleConfigBuilder {
+    burst: Option<i64>,
+    qps: Option<i64>,
+}
+
+#[cfg(feature = "builder")]
+impl WebhookThrottleConfig {
+    /// Starts building a `WebhookThrottleConfig`. Its required fields must be set before [`WebhookThrottleConfigBuilder::build`] is called.
+    pub fn builder() -> WebhookThrottleConfigBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl WebhookThrottleConfigBuilder {
+    /// Sets [`WebhookThrottleConfig::burst`].
+    pub fn burst(mut self, value: impl Into<i64>) -> Self {
+        self.burst = Some(value.into());
+        self
+    }
+
+    /// Sets [`WebhookThrottleConfig::qps`].
+    pub fn qps(mut self, value: impl Into<i64>) -> Self {
+        self.qps = Some(value.into());
+        self
+    }
+
+    /// Builds the `WebhookThrottleConfig`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<WebhookThrottleConfig, crate::MissingFieldError> {
+        Ok(WebhookThrottleConfig {
+            burst: self.burst,
+            qps: self.qps,
+        })
+    }
+}
+
+/// The apply configuration of [`WebhookThrottleConfig`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WebhookThrottleConfigApplyConfiguration {
+    /// See [`WebhookThrottleConfig::burst`].
+    pub burst: Option<i64>,
+
+    /// See [`WebhookThrottleConfig::qps`].
+    pub qps: Option<i64>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for WebhookThrottleConfigApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "WebhookThrottleConfigApplyConfiguration",
+            self.burst.as_ref().map_or(0, |_| 1) +
+            self.qps.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.burst {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "burst", value)?;
+        }
+        if let Some(value) = &self.qps {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "qps", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for WebhookThrottleConfig {
     fn schema_name() -> String {
-        "io.k8s.api.auditregistration.v1alpha1.WebhookThrottleConfig".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::auditregistration::v1alpha1::WebhookThrottleConfig".to_owned()
+        }
+        else {
+            "io.k8s.api.auditregistration.v1alpha1.WebhookThrottleConfig".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -113,8 +238,9 @@ impl crate::schemars::JsonSchema for WebhookThrottleConfig {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "burst".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -125,8 +251,8 @@ impl crate::schemars::JsonSchema for WebhookThrottleConfig {
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "qps".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -137,11 +263,22 @@ impl crate::schemars::JsonSchema for WebhookThrottleConfig {
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for WebhookThrottleConfig {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(WebhookThrottleConfig {
+            burst: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            qps: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,12 +1,28 @@
 
 mod token_review;
 pub use self::token_review::TokenReview;
+#[cfg(feature = "builder")]
+pub use self::token_review::TokenReviewBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::token_review::TokenReviewApplyConfiguration;
 
 mod token_review_spec;
 pub use self::token_review_spec::TokenReviewSpec;
+#[cfg(feature = "builder")]
+pub use self::token_review_spec::TokenReviewSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::token_review_spec::TokenReviewSpecApplyConfiguration;
 
 mod token_review_status;
 pub use self::token_review_status::TokenReviewStatus;
+#[cfg(feature = "builder")]
+pub use self::token_review_status::TokenReviewStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::token_review_status::TokenReviewStatusApplyConfiguration;
 
 mod user_info;
 pub use self::user_info::UserInfo;
+#[cfg(feature = "builder")]
+pub use self::user_info::UserInfoBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::user_info::UserInfoApplyConfiguration;
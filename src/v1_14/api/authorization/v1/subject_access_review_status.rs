@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.authorization.v1.SubjectAccessReviewStatus
 
 /// SubjectAccessReviewStatus
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct SubjectAccessReviewStatus {
     /// Allowed is required. True if the action would be allowed, false otherwise.
     pub allowed: bool,
@@ -16,43 +16,61 @@ pub struct SubjectAccessReviewStatus {
     pub reason: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for SubjectAccessReviewStatus {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_allowed,
-            Key_denied,
-            Key_evaluation_error,
-            Key_reason,
-            Other,
+impl SubjectAccessReviewStatus {
+    /// Constructs a `SubjectAccessReviewStatus` from its required fields. Its optional fields are unset.
+    pub fn new(allowed: impl Into<bool>) -> Self {
+        SubjectAccessReviewStatus {
+            allowed: allowed.into(),
+            denied: None,
+            evaluation_error: None,
+            reason: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl SubjectAccessReviewStatus {
+    /// Returns this value with [`SubjectAccessReviewStatus::allowed`] set to the given value.
+    #[must_use]
+    pub fn with_allowed(mut self, value: impl Into<bool>) -> Self {
+        self.allowed = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`SubjectAccessReviewStatus::denied`] set to the given value.
+    #[must_use]
+    pub fn with_denied(mut self, value: impl Into<bool>) -> Self {
+        self.denied = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`SubjectAccessReviewStatus::evaluation_error`] set to the given value.
+    #[must_use]
+    pub fn with_evaluation_error(mut self, value: impl Into<String>) -> Self {
+        self.evaluation_error = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "allowed" => Field::Key_allowed,
-                            "denied" => Field::Key_denied,
-                            "evaluationError" => Field::Key_evaluation_error,
-                            "reason" => Field::Key_reason,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`SubjectAccessReviewStatus::reason`] set to the given value.
+    #[must_use]
+    pub fn with_reason(mut self, value: impl Into<String>) -> Self {
+        self.reason = Some(value.into());
+        self
+    }
+}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl crate::MergeStrategyInfo for SubjectAccessReviewStatus {}
+
+impl SubjectAccessReviewStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "allowed",
+        "denied",
+        "evaluationError",
+        "reason",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for SubjectAccessReviewStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -68,40 +86,111 @@ impl<'de> crate::serde::Deserialize<'de> for SubjectAccessReviewStatus {
                 let mut value_evaluation_error: Option<String> = None;
                 let mut value_reason: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("SubjectAccessReviewStatus", SubjectAccessReviewStatus::FIELDS))? {
                     match key {
-                        Field::Key_allowed => value_allowed = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_denied => value_denied = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_evaluation_error => value_evaluation_error = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_reason => value_reason = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_allowed = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_denied = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_evaluation_error = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_reason = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(SubjectAccessReviewStatus {
-                    allowed: value_allowed.ok_or_else(|| crate::serde::de::Error::missing_field("allowed"))?,
+                    allowed: value_allowed.ok_or_else(|| crate::serde2::missing_field("SubjectAccessReviewStatus", "allowed", &["allowed"]))?,
                     denied: value_denied,
                     evaluation_error: value_evaluation_error,
                     reason: value_reason,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(SubjectAccessReviewStatus {
+                    allowed: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    denied: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    evaluation_error: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    reason: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "SubjectAccessReviewStatus",
-            &[
-                "allowed",
-                "denied",
-                "evaluationError",
-                "reason",
-            ],
+            SubjectAccessReviewStatus::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, SubjectAccessReviewStatus> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("SubjectAccessReviewStatus")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_allowed = false;
+                let mut has_denied = false;
+                let mut has_evaluation_error = false;
+                let mut has_reason = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("SubjectAccessReviewStatus", SubjectAccessReviewStatus::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.allowed))?; has_allowed = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.denied))?; has_denied = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.evaluation_error))?; has_evaluation_error = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.reason))?; has_reason = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_allowed {
+                    return Err(crate::serde2::missing_field("SubjectAccessReviewStatus", "allowed", &["allowed"]));
+                }
+                if !has_denied {
+                    self.0.denied = None;
+                }
+                if !has_evaluation_error {
+                    self.0.evaluation_error = None;
+                }
+                if !has_reason {
+                    self.0.reason = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.allowed))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.denied))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.evaluation_error))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.reason))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "SubjectAccessReviewStatus",
+            SubjectAccessReviewStatus::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for SubjectAccessReviewStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("SubjectAccessReviewStatus", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "allowed", &self.allowed)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "denied", &self.denied)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "evaluationError", &self.evaluation_error)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reason", &self.reason)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "SubjectAccessReviewStatus",
             1 +
@@ -123,10 +212,114 @@ impl crate::serde::Serialize for SubjectAccessReviewStatus {
     }
 }
 
+/// A builder of [`SubjectAccessReviewStatus`]. Created with [`SubjectAccessReviewStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct SubjectAccessReviewStatusBuilder {
+    allowed: Option<bool>,
+    denied: Option<bool>,
+    evaluation_error: Option<String>,
+    reason: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl SubjectAccessReviewStatus {
+    /// Starts building a `SubjectAccessReviewStatus`. Its required fields must be set before [`SubjectAccessReviewStatusBuilder::build`] is called.
+    pub fn builder() -> SubjectAccessReviewStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl SubjectAccessReviewStatusBuilder {
+    /// Sets [`SubjectAccessReviewStatus::allowed`].
+    pub fn allowed(mut self, value: impl Into<bool>) -> Self {
+        self.allowed = Some(value.into());
+        self
+    }
+
+    /// Sets [`SubjectAccessReviewStatus::denied`].
+    pub fn denied(mut self, value: impl Into<bool>) -> Self {
+        self.denied = Some(value.into());
+        self
+    }
+
+    /// Sets [`SubjectAccessReviewStatus::evaluation_error`].
+    pub fn evaluation_error(mut self, value: impl Into<String>) -> Self {
+        self.evaluation_error = Some(value.into());
+        self
+    }
+
+    /// Sets [`SubjectAccessReviewStatus::reason`].
+    pub fn reason(mut self, value: impl Into<String>) -> Self {
+        self.reason = Some(value.into());
+        self
+    }
+
+    /// Builds the `SubjectAccessReviewStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<SubjectAccessReviewStatus, crate::MissingFieldError> {
+        Ok(SubjectAccessReviewStatus {
+            allowed: self.allowed.ok_or_else(|| crate::MissingFieldError::new("SubjectAccessReviewStatus", "allowed"))?,
+            denied: self.denied,
+            evaluation_error: self.evaluation_error,
+            reason: self.reason,
+        })
+    }
+}
+
+/// The apply configuration of [`SubjectAccessReviewStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SubjectAccessReviewStatusApplyConfiguration {
+    /// See [`SubjectAccessReviewStatus::allowed`].
+    pub allowed: Option<bool>,
+
+    /// See [`SubjectAccessReviewStatus::denied`].
+    pub denied: Option<bool>,
+
+    /// See [`SubjectAccessReviewStatus::evaluation_error`].
+    pub evaluation_error: Option<String>,
+
+    /// See [`SubjectAccessReviewStatus::reason`].
+    pub reason: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for SubjectAccessReviewStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "SubjectAccessReviewStatusApplyConfiguration",
+            self.allowed.as_ref().map_or(0, |_| 1) +
+            self.denied.as_ref().map_or(0, |_| 1) +
+            self.evaluation_error.as_ref().map_or(0, |_| 1) +
+            self.reason.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.allowed {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "allowed", value)?;
+        }
+        if let Some(value) = &self.denied {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "denied", value)?;
+        }
+        if let Some(value) = &self.evaluation_error {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "evaluationError", value)?;
+        }
+        if let Some(value) = &self.reason {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reason", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for SubjectAccessReviewStatus {
     fn schema_name() -> String {
-        "io.k8s.api.authorization.v1.SubjectAccessReviewStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::authorization::v1::SubjectAccessReviewStatus".to_owned()
+        }
+        else {
+            "io.k8s.api.authorization.v1.SubjectAccessReviewStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -137,8 +330,9 @@ impl crate::schemars::JsonSchema for SubjectAccessReviewStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "allowed".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -148,8 +342,8 @@ impl crate::schemars::JsonSchema for SubjectAccessReviewStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "denied".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -159,8 +353,8 @@ impl crate::schemars::JsonSchema for SubjectAccessReviewStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "evaluationError".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -170,8 +364,8 @@ impl crate::schemars::JsonSchema for SubjectAccessReviewStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "reason".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -181,8 +375,9 @@ impl crate::schemars::JsonSchema for SubjectAccessReviewStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "allowed",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -192,3 +387,15 @@ impl crate::schemars::JsonSchema for SubjectAccessReviewStatus {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for SubjectAccessReviewStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(SubjectAccessReviewStatus {
+            allowed: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            denied: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            evaluation_error: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            reason: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,20 +1,40 @@
 
 mod cross_version_object_reference;
 pub use self::cross_version_object_reference::CrossVersionObjectReference;
+#[cfg(feature = "builder")]
+pub use self::cross_version_object_reference::CrossVersionObjectReferenceBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::cross_version_object_reference::CrossVersionObjectReferenceApplyConfiguration;
 
 mod horizontal_pod_autoscaler;
 pub use self::horizontal_pod_autoscaler::HorizontalPodAutoscaler;
+#[cfg(feature = "builder")]
+pub use self::horizontal_pod_autoscaler::HorizontalPodAutoscalerBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::horizontal_pod_autoscaler::HorizontalPodAutoscalerApplyConfiguration;
 #[cfg(feature = "api")] pub use self::horizontal_pod_autoscaler::{ReadNamespacedHorizontalPodAutoscalerOptional, ReadNamespacedHorizontalPodAutoscalerResponse};
 #[cfg(feature = "api")] pub use self::horizontal_pod_autoscaler::{ReadNamespacedHorizontalPodAutoscalerStatusOptional, ReadNamespacedHorizontalPodAutoscalerStatusResponse};
 
 mod horizontal_pod_autoscaler_spec;
 pub use self::horizontal_pod_autoscaler_spec::HorizontalPodAutoscalerSpec;
+#[cfg(feature = "builder")]
+pub use self::horizontal_pod_autoscaler_spec::HorizontalPodAutoscalerSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::horizontal_pod_autoscaler_spec::HorizontalPodAutoscalerSpecApplyConfiguration;
 
 mod horizontal_pod_autoscaler_status;
 pub use self::horizontal_pod_autoscaler_status::HorizontalPodAutoscalerStatus;
+#[cfg(feature = "builder")]
+pub use self::horizontal_pod_autoscaler_status::HorizontalPodAutoscalerStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::horizontal_pod_autoscaler_status::HorizontalPodAutoscalerStatusApplyConfiguration;
 
 mod scale;
 pub use self::scale::Scale;
+#[cfg(feature = "builder")]
+pub use self::scale::ScaleBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::scale::ScaleApplyConfiguration;
 #[cfg(feature = "api")] pub use self::scale::{ReadNamespacedDeploymentScaleOptional, ReadNamespacedDeploymentScaleResponse};
 #[cfg(feature = "api")] pub use self::scale::{ReadNamespacedReplicaSetScaleOptional, ReadNamespacedReplicaSetScaleResponse};
 #[cfg(feature = "api")] pub use self::scale::{ReadNamespacedStatefulSetScaleOptional, ReadNamespacedStatefulSetScaleResponse};
@@ -22,6 +42,14 @@ pub use self::scale::Scale;
 
 mod scale_spec;
 pub use self::scale_spec::ScaleSpec;
+#[cfg(feature = "builder")]
+pub use self::scale_spec::ScaleSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::scale_spec::ScaleSpecApplyConfiguration;
 
 mod scale_status;
 pub use self::scale_status::ScaleStatus;
+#[cfg(feature = "builder")]
+pub use self::scale_status::ScaleStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::scale_status::ScaleStatusApplyConfiguration;
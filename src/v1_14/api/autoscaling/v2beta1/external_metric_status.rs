@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.autoscaling.v2beta1.ExternalMetricStatus
 
 /// ExternalMetricStatus indicates the current value of a global metric not associated with any Kubernetes object.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ExternalMetricStatus {
     /// currentAverageValue is the current value of metric averaged over autoscaled pods.
     pub current_average_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
@@ -16,43 +16,61 @@ pub struct ExternalMetricStatus {
     pub metric_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ExternalMetricStatus {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_current_average_value,
-            Key_current_value,
-            Key_metric_name,
-            Key_metric_selector,
-            Other,
+impl ExternalMetricStatus {
+    /// Constructs a `ExternalMetricStatus` from its required fields. Its optional fields are unset.
+    pub fn new(current_value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>, metric_name: impl Into<String>) -> Self {
+        ExternalMetricStatus {
+            current_average_value: None,
+            current_value: current_value.into(),
+            metric_name: metric_name.into(),
+            metric_selector: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl ExternalMetricStatus {
+    /// Returns this value with [`ExternalMetricStatus::current_average_value`] set to the given value.
+    #[must_use]
+    pub fn with_current_average_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.current_average_value = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ExternalMetricStatus::current_value`] set to the given value.
+    #[must_use]
+    pub fn with_current_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.current_value = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ExternalMetricStatus::metric_name`] set to the given value.
+    #[must_use]
+    pub fn with_metric_name(mut self, value: impl Into<String>) -> Self {
+        self.metric_name = value.into();
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "currentAverageValue" => Field::Key_current_average_value,
-                            "currentValue" => Field::Key_current_value,
-                            "metricName" => Field::Key_metric_name,
-                            "metricSelector" => Field::Key_metric_selector,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`ExternalMetricStatus::metric_selector`] set to the given value.
+    #[must_use]
+    pub fn with_metric_selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.metric_selector = Some(value.into());
+        self
+    }
+}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl crate::MergeStrategyInfo for ExternalMetricStatus {}
+
+impl ExternalMetricStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "currentAverageValue",
+        "currentValue",
+        "metricName",
+        "metricSelector",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ExternalMetricStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -68,40 +86,111 @@ impl<'de> crate::serde::Deserialize<'de> for ExternalMetricStatus {
                 let mut value_metric_name: Option<String> = None;
                 let mut value_metric_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ExternalMetricStatus", ExternalMetricStatus::FIELDS))? {
                     match key {
-                        Field::Key_current_average_value => value_current_average_value = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_current_value => value_current_value = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_metric_name => value_metric_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_metric_selector => value_metric_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_current_average_value = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_current_value = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_metric_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_metric_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ExternalMetricStatus {
                     current_average_value: value_current_average_value,
-                    current_value: value_current_value.ok_or_else(|| crate::serde::de::Error::missing_field("currentValue"))?,
-                    metric_name: value_metric_name.ok_or_else(|| crate::serde::de::Error::missing_field("metricName"))?,
+                    current_value: value_current_value.ok_or_else(|| crate::serde2::missing_field("ExternalMetricStatus", "currentValue", &["currentValue", "metricName"]))?,
+                    metric_name: value_metric_name.ok_or_else(|| crate::serde2::missing_field("ExternalMetricStatus", "metricName", &["currentValue", "metricName"]))?,
                     metric_selector: value_metric_selector,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ExternalMetricStatus {
+                    current_average_value: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    current_value: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    metric_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    metric_selector: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "ExternalMetricStatus",
-            &[
-                "currentAverageValue",
-                "currentValue",
-                "metricName",
-                "metricSelector",
-            ],
+            ExternalMetricStatus::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ExternalMetricStatus> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ExternalMetricStatus")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_current_average_value = false;
+                let mut has_current_value = false;
+                let mut has_metric_name = false;
+                let mut has_metric_selector = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ExternalMetricStatus", ExternalMetricStatus::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.current_average_value))?; has_current_average_value = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.current_value))?; has_current_value = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metric_name))?; has_metric_name = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.metric_selector))?; has_metric_selector = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_current_average_value {
+                    self.0.current_average_value = None;
+                }
+                if !has_current_value {
+                    return Err(crate::serde2::missing_field("ExternalMetricStatus", "currentValue", &["currentValue", "metricName"]));
+                }
+                if !has_metric_name {
+                    return Err(crate::serde2::missing_field("ExternalMetricStatus", "metricName", &["currentValue", "metricName"]));
+                }
+                if !has_metric_selector {
+                    self.0.metric_selector = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.current_average_value))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.current_value))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metric_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.metric_selector))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ExternalMetricStatus",
+            ExternalMetricStatus::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ExternalMetricStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ExternalMetricStatus", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "currentAverageValue", &self.current_average_value)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "currentValue", &self.current_value)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricName", &self.metric_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricSelector", &self.metric_selector)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ExternalMetricStatus",
             2 +
@@ -120,10 +209,114 @@ impl crate::serde::Serialize for ExternalMetricStatus {
     }
 }
 
+/// A builder of [`ExternalMetricStatus`]. Created with [`ExternalMetricStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ExternalMetricStatusBuilder {
+    current_average_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+    current_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+    metric_name: Option<String>,
+    metric_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>,
+}
+
+#[cfg(feature = "builder")]
+impl ExternalMetricStatus {
+    /// Starts building a `ExternalMetricStatus`. Its required fields must be set before [`ExternalMetricStatusBuilder::build`] is called.
+    pub fn builder() -> ExternalMetricStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ExternalMetricStatusBuilder {
+    /// Sets [`ExternalMetricStatus::current_average_value`].
+    pub fn current_average_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.current_average_value = Some(value.into());
+        self
+    }
+
+    /// Sets [`ExternalMetricStatus::current_value`].
+    pub fn current_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.current_value = Some(value.into());
+        self
+    }
+
+    /// Sets [`ExternalMetricStatus::metric_name`].
+    pub fn metric_name(mut self, value: impl Into<String>) -> Self {
+        self.metric_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`ExternalMetricStatus::metric_selector`].
+    pub fn metric_selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.metric_selector = Some(value.into());
+        self
+    }
+
+    /// Builds the `ExternalMetricStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ExternalMetricStatus, crate::MissingFieldError> {
+        Ok(ExternalMetricStatus {
+            current_average_value: self.current_average_value,
+            current_value: self.current_value.ok_or_else(|| crate::MissingFieldError::new("ExternalMetricStatus", "currentValue"))?,
+            metric_name: self.metric_name.ok_or_else(|| crate::MissingFieldError::new("ExternalMetricStatus", "metricName"))?,
+            metric_selector: self.metric_selector,
+        })
+    }
+}
+
+/// The apply configuration of [`ExternalMetricStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ExternalMetricStatusApplyConfiguration {
+    /// See [`ExternalMetricStatus::current_average_value`].
+    pub current_average_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+
+    /// See [`ExternalMetricStatus::current_value`].
+    pub current_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+
+    /// See [`ExternalMetricStatus::metric_name`].
+    pub metric_name: Option<String>,
+
+    /// See [`ExternalMetricStatus::metric_selector`].
+    pub metric_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ExternalMetricStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ExternalMetricStatusApplyConfiguration",
+            self.current_average_value.as_ref().map_or(0, |_| 1) +
+            self.current_value.as_ref().map_or(0, |_| 1) +
+            self.metric_name.as_ref().map_or(0, |_| 1) +
+            self.metric_selector.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.current_average_value {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "currentAverageValue", value)?;
+        }
+        if let Some(value) = &self.current_value {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "currentValue", value)?;
+        }
+        if let Some(value) = &self.metric_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricName", value)?;
+        }
+        if let Some(value) = &self.metric_selector {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricSelector", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ExternalMetricStatus {
     fn schema_name() -> String {
-        "io.k8s.api.autoscaling.v2beta1.ExternalMetricStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::autoscaling::v2beta1::ExternalMetricStatus".to_owned()
+        }
+        else {
+            "io.k8s.api.autoscaling.v2beta1.ExternalMetricStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -134,8 +327,9 @@ impl crate::schemars::JsonSchema for ExternalMetricStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "currentAverageValue".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::api::resource::Quantity>().into_object();
@@ -145,8 +339,8 @@ impl crate::schemars::JsonSchema for ExternalMetricStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "currentValue".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::api::resource::Quantity>().into_object();
@@ -156,8 +350,8 @@ impl crate::schemars::JsonSchema for ExternalMetricStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "metricName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -167,8 +361,8 @@ impl crate::schemars::JsonSchema for ExternalMetricStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "metricSelector".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>().into_object();
@@ -178,8 +372,9 @@ impl crate::schemars::JsonSchema for ExternalMetricStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "currentValue",
                     "metricName",
@@ -190,3 +385,15 @@ impl crate::schemars::JsonSchema for ExternalMetricStatus {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ExternalMetricStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ExternalMetricStatus {
+            current_average_value: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            current_value: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            metric_name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            metric_selector: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.autoscaling.v2beta1.ObjectMetricSource
 
 /// ObjectMetricSource indicates how to scale on a metric describing a kubernetes object (for example, hits-per-second on an Ingress object).
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ObjectMetricSource {
     /// averageValue is the target value of the average of the metric across all relevant pods (as a quantity)
     pub average_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
@@ -19,45 +19,70 @@ pub struct ObjectMetricSource {
     pub target_value: crate::apimachinery::pkg::api::resource::Quantity,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ObjectMetricSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_average_value,
-            Key_metric_name,
-            Key_selector,
-            Key_target,
-            Key_target_value,
-            Other,
+impl ObjectMetricSource {
+    /// Constructs a `ObjectMetricSource` from its required fields. Its optional fields are unset.
+    pub fn new(metric_name: impl Into<String>, target: impl Into<crate::api::autoscaling::v2beta1::CrossVersionObjectReference>, target_value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        ObjectMetricSource {
+            average_value: None,
+            metric_name: metric_name.into(),
+            selector: None,
+            target: target.into(),
+            target_value: target_value.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl ObjectMetricSource {
+    /// Returns this value with [`ObjectMetricSource::average_value`] set to the given value.
+    #[must_use]
+    pub fn with_average_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.average_value = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ObjectMetricSource::metric_name`] set to the given value.
+    #[must_use]
+    pub fn with_metric_name(mut self, value: impl Into<String>) -> Self {
+        self.metric_name = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ObjectMetricSource::selector`] set to the given value.
+    #[must_use]
+    pub fn with_selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.selector = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "averageValue" => Field::Key_average_value,
-                            "metricName" => Field::Key_metric_name,
-                            "selector" => Field::Key_selector,
-                            "target" => Field::Key_target,
-                            "targetValue" => Field::Key_target_value,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`ObjectMetricSource::target`] set to the given value.
+    #[must_use]
+    pub fn with_target(mut self, value: impl Into<crate::api::autoscaling::v2beta1::CrossVersionObjectReference>) -> Self {
+        self.target = value.into();
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`ObjectMetricSource::target_value`] set to the given value.
+    #[must_use]
+    pub fn with_target_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.target_value = value.into();
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for ObjectMetricSource {}
 
+impl ObjectMetricSource {
+    const FIELDS: &'static [&'static str] = &[
+        "averageValue",
+        "metricName",
+        "selector",
+        "target",
+        "targetValue",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for ObjectMetricSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -74,43 +99,121 @@ impl<'de> crate::serde::Deserialize<'de> for ObjectMetricSource {
                 let mut value_target: Option<crate::api::autoscaling::v2beta1::CrossVersionObjectReference> = None;
                 let mut value_target_value: Option<crate::apimachinery::pkg::api::resource::Quantity> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ObjectMetricSource", ObjectMetricSource::FIELDS))? {
                     match key {
-                        Field::Key_average_value => value_average_value = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_metric_name => value_metric_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_selector => value_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_target => value_target = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_target_value => value_target_value = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_average_value = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_metric_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_target = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(4) => value_target_value = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ObjectMetricSource {
                     average_value: value_average_value,
-                    metric_name: value_metric_name.ok_or_else(|| crate::serde::de::Error::missing_field("metricName"))?,
+                    metric_name: value_metric_name.ok_or_else(|| crate::serde2::missing_field("ObjectMetricSource", "metricName", &["metricName", "target", "targetValue"]))?,
                     selector: value_selector,
-                    target: value_target.ok_or_else(|| crate::serde::de::Error::missing_field("target"))?,
-                    target_value: value_target_value.ok_or_else(|| crate::serde::de::Error::missing_field("targetValue"))?,
+                    target: value_target.ok_or_else(|| crate::serde2::missing_field("ObjectMetricSource", "target", &["metricName", "target", "targetValue"]))?,
+                    target_value: value_target_value.ok_or_else(|| crate::serde2::missing_field("ObjectMetricSource", "targetValue", &["metricName", "target", "targetValue"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ObjectMetricSource {
+                    average_value: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    metric_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    selector: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    target: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    target_value: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "ObjectMetricSource",
-            &[
-                "averageValue",
-                "metricName",
-                "selector",
-                "target",
-                "targetValue",
-            ],
+            ObjectMetricSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ObjectMetricSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ObjectMetricSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_average_value = false;
+                let mut has_metric_name = false;
+                let mut has_selector = false;
+                let mut has_target = false;
+                let mut has_target_value = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ObjectMetricSource", ObjectMetricSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.average_value))?; has_average_value = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metric_name))?; has_metric_name = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.selector))?; has_selector = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.target))?; has_target = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.target_value))?; has_target_value = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_average_value {
+                    self.0.average_value = None;
+                }
+                if !has_metric_name {
+                    return Err(crate::serde2::missing_field("ObjectMetricSource", "metricName", &["metricName", "target", "targetValue"]));
+                }
+                if !has_selector {
+                    self.0.selector = None;
+                }
+                if !has_target {
+                    return Err(crate::serde2::missing_field("ObjectMetricSource", "target", &["metricName", "target", "targetValue"]));
+                }
+                if !has_target_value {
+                    return Err(crate::serde2::missing_field("ObjectMetricSource", "targetValue", &["metricName", "target", "targetValue"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.average_value))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metric_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.selector))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.target))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.target_value))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ObjectMetricSource",
+            ObjectMetricSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ObjectMetricSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ObjectMetricSource", 5)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "averageValue", &self.average_value)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricName", &self.metric_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selector", &self.selector)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "target", &self.target)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "targetValue", &self.target_value)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ObjectMetricSource",
             3 +
@@ -130,10 +233,129 @@ impl crate::serde::Serialize for ObjectMetricSource {
     }
 }
 
+/// A builder of [`ObjectMetricSource`]. Created with [`ObjectMetricSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ObjectMetricSourceBuilder {
+    average_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+    metric_name: Option<String>,
+    selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>,
+    target: Option<crate::api::autoscaling::v2beta1::CrossVersionObjectReference>,
+    target_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+}
+
+#[cfg(feature = "builder")]
+impl ObjectMetricSource {
+    /// Starts building a `ObjectMetricSource`. Its required fields must be set before [`ObjectMetricSourceBuilder::build`] is called.
+    pub fn builder() -> ObjectMetricSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ObjectMetricSourceBuilder {
+    /// Sets [`ObjectMetricSource::average_value`].
+    pub fn average_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.average_value = Some(value.into());
+        self
+    }
+
+    /// Sets [`ObjectMetricSource::metric_name`].
+    pub fn metric_name(mut self, value: impl Into<String>) -> Self {
+        self.metric_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`ObjectMetricSource::selector`].
+    pub fn selector(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>) -> Self {
+        self.selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`ObjectMetricSource::target`].
+    pub fn target(mut self, value: impl Into<crate::api::autoscaling::v2beta1::CrossVersionObjectReference>) -> Self {
+        self.target = Some(value.into());
+        self
+    }
+
+    /// Sets [`ObjectMetricSource::target_value`].
+    pub fn target_value(mut self, value: impl Into<crate::apimachinery::pkg::api::resource::Quantity>) -> Self {
+        self.target_value = Some(value.into());
+        self
+    }
+
+    /// Builds the `ObjectMetricSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ObjectMetricSource, crate::MissingFieldError> {
+        Ok(ObjectMetricSource {
+            average_value: self.average_value,
+            metric_name: self.metric_name.ok_or_else(|| crate::MissingFieldError::new("ObjectMetricSource", "metricName"))?,
+            selector: self.selector,
+            target: self.target.ok_or_else(|| crate::MissingFieldError::new("ObjectMetricSource", "target"))?,
+            target_value: self.target_value.ok_or_else(|| crate::MissingFieldError::new("ObjectMetricSource", "targetValue"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`ObjectMetricSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ObjectMetricSourceApplyConfiguration {
+    /// See [`ObjectMetricSource::average_value`].
+    pub average_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+
+    /// See [`ObjectMetricSource::metric_name`].
+    pub metric_name: Option<String>,
+
+    /// See [`ObjectMetricSource::selector`].
+    pub selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorApplyConfiguration>,
+
+    /// See [`ObjectMetricSource::target`].
+    pub target: Option<crate::api::autoscaling::v2beta1::CrossVersionObjectReferenceApplyConfiguration>,
+
+    /// See [`ObjectMetricSource::target_value`].
+    pub target_value: Option<crate::apimachinery::pkg::api::resource::Quantity>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ObjectMetricSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ObjectMetricSourceApplyConfiguration",
+            self.average_value.as_ref().map_or(0, |_| 1) +
+            self.metric_name.as_ref().map_or(0, |_| 1) +
+            self.selector.as_ref().map_or(0, |_| 1) +
+            self.target.as_ref().map_or(0, |_| 1) +
+            self.target_value.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.average_value {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "averageValue", value)?;
+        }
+        if let Some(value) = &self.metric_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metricName", value)?;
+        }
+        if let Some(value) = &self.selector {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "selector", value)?;
+        }
+        if let Some(value) = &self.target {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "target", value)?;
+        }
+        if let Some(value) = &self.target_value {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "targetValue", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ObjectMetricSource {
     fn schema_name() -> String {
-        "io.k8s.api.autoscaling.v2beta1.ObjectMetricSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::autoscaling::v2beta1::ObjectMetricSource".to_owned()
+        }
+        else {
+            "io.k8s.api.autoscaling.v2beta1.ObjectMetricSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -144,8 +366,9 @@ impl crate::schemars::JsonSchema for ObjectMetricSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "averageValue".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::api::resource::Quantity>().into_object();
@@ -155,8 +378,8 @@ impl crate::schemars::JsonSchema for ObjectMetricSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "metricName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -166,8 +389,8 @@ impl crate::schemars::JsonSchema for ObjectMetricSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "selector".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>().into_object();
@@ -177,8 +400,8 @@ impl crate::schemars::JsonSchema for ObjectMetricSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "target".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::autoscaling::v2beta1::CrossVersionObjectReference>().into_object();
@@ -188,8 +411,8 @@ impl crate::schemars::JsonSchema for ObjectMetricSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "targetValue".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::api::resource::Quantity>().into_object();
@@ -199,8 +422,9 @@ impl crate::schemars::JsonSchema for ObjectMetricSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "metricName",
                     "target",
@@ -212,3 +436,16 @@ impl crate::schemars::JsonSchema for ObjectMetricSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ObjectMetricSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ObjectMetricSource {
+            average_value: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            metric_name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            selector: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            target: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            target_value: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.autoscaling.v2beta2.ExternalMetricStatus
 
 /// ExternalMetricStatus indicates the current value of a global metric not associated with any Kubernetes object.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ExternalMetricStatus {
     /// current contains the current value for the given metric
     pub current: crate::api::autoscaling::v2beta2::MetricValueStatus,
@@ -10,39 +10,43 @@ pub struct ExternalMetricStatus {
     pub metric: crate::api::autoscaling::v2beta2::MetricIdentifier,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ExternalMetricStatus {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_current,
-            Key_metric,
-            Other,
+impl ExternalMetricStatus {
+    /// Constructs a `ExternalMetricStatus` from its required fields. Its optional fields are unset.
+    pub fn new(current: impl Into<crate::api::autoscaling::v2beta2::MetricValueStatus>, metric: impl Into<crate::api::autoscaling::v2beta2::MetricIdentifier>) -> Self {
+        ExternalMetricStatus {
+            current: current.into(),
+            metric: metric.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl ExternalMetricStatus {
+    /// Returns this value with [`ExternalMetricStatus::current`] set to the given value.
+    #[must_use]
+    pub fn with_current(mut self, value: impl Into<crate::api::autoscaling::v2beta2::MetricValueStatus>) -> Self {
+        self.current = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ExternalMetricStatus::metric`] set to the given value.
+    #[must_use]
+    pub fn with_metric(mut self, value: impl Into<crate::api::autoscaling::v2beta2::MetricIdentifier>) -> Self {
+        self.metric = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "current" => Field::Key_current,
-                            "metric" => Field::Key_metric,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for ExternalMetricStatus {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl ExternalMetricStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "current",
+        "metric",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ExternalMetricStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +60,91 @@ impl<'de> crate::serde::Deserialize<'de> for ExternalMetricStatus {
                 let mut value_current: Option<crate::api::autoscaling::v2beta2::MetricValueStatus> = None;
                 let mut value_metric: Option<crate::api::autoscaling::v2beta2::MetricIdentifier> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ExternalMetricStatus", ExternalMetricStatus::FIELDS))? {
                     match key {
-                        Field::Key_current => value_current = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_metric => value_metric = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_current = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_metric = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ExternalMetricStatus {
-                    current: value_current.ok_or_else(|| crate::serde::de::Error::missing_field("current"))?,
-                    metric: value_metric.ok_or_else(|| crate::serde::de::Error::missing_field("metric"))?,
+                    current: value_current.ok_or_else(|| crate::serde2::missing_field("ExternalMetricStatus", "current", &["current", "metric"]))?,
+                    metric: value_metric.ok_or_else(|| crate::serde2::missing_field("ExternalMetricStatus", "metric", &["current", "metric"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ExternalMetricStatus {
+                    current: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    metric: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "ExternalMetricStatus",
-            &[
-                "current",
-                "metric",
-            ],
+            ExternalMetricStatus::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ExternalMetricStatus> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ExternalMetricStatus")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_current = false;
+                let mut has_metric = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ExternalMetricStatus", ExternalMetricStatus::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.current))?; has_current = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.metric))?; has_metric = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_current {
+                    return Err(crate::serde2::missing_field("ExternalMetricStatus", "current", &["current", "metric"]));
+                }
+                if !has_metric {
+                    return Err(crate::serde2::missing_field("ExternalMetricStatus", "metric", &["current", "metric"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.current))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.metric))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ExternalMetricStatus",
+            ExternalMetricStatus::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ExternalMetricStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ExternalMetricStatus", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "current", &self.current)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metric", &self.metric)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ExternalMetricStatus",
             2,
@@ -94,10 +155,84 @@ impl crate::serde::Serialize for ExternalMetricStatus {
     }
 }
 
+/// A builder of [`ExternalMetricStatus`]. Created with [`ExternalMetricStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ExternalMetricStatusBuilder {
+    current: Option<crate::api::autoscaling::v2beta2::MetricValueStatus>,
+    metric: Option<crate::api::autoscaling::v2beta2::MetricIdentifier>,
+}
+
+#[cfg(feature = "builder")]
+impl ExternalMetricStatus {
+    /// Starts building a `ExternalMetricStatus`. Its required fields must be set before [`ExternalMetricStatusBuilder::build`] is called.
+    pub fn builder() -> ExternalMetricStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ExternalMetricStatusBuilder {
+    /// Sets [`ExternalMetricStatus::current`].
+    pub fn current(mut self, value: impl Into<crate::api::autoscaling::v2beta2::MetricValueStatus>) -> Self {
+        self.current = Some(value.into());
+        self
+    }
+
+    /// Sets [`ExternalMetricStatus::metric`].
+    pub fn metric(mut self, value: impl Into<crate::api::autoscaling::v2beta2::MetricIdentifier>) -> Self {
+        self.metric = Some(value.into());
+        self
+    }
+
+    /// Builds the `ExternalMetricStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ExternalMetricStatus, crate::MissingFieldError> {
+        Ok(ExternalMetricStatus {
+            current: self.current.ok_or_else(|| crate::MissingFieldError::new("ExternalMetricStatus", "current"))?,
+            metric: self.metric.ok_or_else(|| crate::MissingFieldError::new("ExternalMetricStatus", "metric"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`ExternalMetricStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ExternalMetricStatusApplyConfiguration {
+    /// See [`ExternalMetricStatus::current`].
+    pub current: Option<crate::api::autoscaling::v2beta2::MetricValueStatusApplyConfiguration>,
+
+    /// See [`ExternalMetricStatus::metric`].
+    pub metric: Option<crate::api::autoscaling::v2beta2::MetricIdentifierApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ExternalMetricStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ExternalMetricStatusApplyConfiguration",
+            self.current.as_ref().map_or(0, |_| 1) +
+            self.metric.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.current {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "current", value)?;
+        }
+        if let Some(value) = &self.metric {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "metric", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ExternalMetricStatus {
     fn schema_name() -> String {
-        "io.k8s.api.autoscaling.v2beta2.ExternalMetricStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::autoscaling::v2beta2::ExternalMetricStatus".to_owned()
+        }
+        else {
+            "io.k8s.api.autoscaling.v2beta2.ExternalMetricStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -108,8 +243,9 @@ impl crate::schemars::JsonSchema for ExternalMetricStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "current".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::autoscaling::v2beta2::MetricValueStatus>().into_object();
@@ -119,8 +255,8 @@ impl crate::schemars::JsonSchema for ExternalMetricStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "metric".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::autoscaling::v2beta2::MetricIdentifier>().into_object();
@@ -130,8 +266,9 @@ impl crate::schemars::JsonSchema for ExternalMetricStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "current",
                     "metric",
@@ -142,3 +279,13 @@ impl crate::schemars::JsonSchema for ExternalMetricStatus {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ExternalMetricStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ExternalMetricStatus {
+            current: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            metric: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -1,14 +1,30 @@
 
 mod job;
 pub use self::job::Job;
+#[cfg(feature = "builder")]
+pub use self::job::JobBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::job::JobApplyConfiguration;
 #[cfg(feature = "api")] pub use self::job::{ReadNamespacedJobOptional, ReadNamespacedJobResponse};
 #[cfg(feature = "api")] pub use self::job::{ReadNamespacedJobStatusOptional, ReadNamespacedJobStatusResponse};
 
 mod job_condition;
 pub use self::job_condition::JobCondition;
+#[cfg(feature = "builder")]
+pub use self::job_condition::JobConditionBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::job_condition::JobConditionApplyConfiguration;
 
 mod job_spec;
 pub use self::job_spec::JobSpec;
+#[cfg(feature = "builder")]
+pub use self::job_spec::JobSpecBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::job_spec::JobSpecApplyConfiguration;
 
 mod job_status;
 pub use self::job_status::JobStatus;
+#[cfg(feature = "builder")]
+pub use self::job_status::JobStatusBuilder;
+#[cfg(feature = "apply-configurations")]
+pub use self::job_status::JobStatusApplyConfiguration;
@@ -25,49 +25,88 @@ pub struct CronJobSpec {
     pub suspend: Option<bool>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for CronJobSpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_concurrency_policy,
-            Key_failed_jobs_history_limit,
-            Key_job_template,
-            Key_schedule,
-            Key_starting_deadline_seconds,
-            Key_successful_jobs_history_limit,
-            Key_suspend,
-            Other,
+impl CronJobSpec {
+    /// Constructs a `CronJobSpec` from its required fields. Its optional fields are unset.
+    pub fn new(job_template: impl Into<crate::api::batch::v1beta1::JobTemplateSpec>, schedule: impl Into<String>) -> Self {
+        CronJobSpec {
+            concurrency_policy: None,
+            failed_jobs_history_limit: None,
+            job_template: job_template.into(),
+            schedule: schedule.into(),
+            starting_deadline_seconds: None,
+            successful_jobs_history_limit: None,
+            suspend: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl CronJobSpec {
+    /// Returns this value with [`CronJobSpec::concurrency_policy`] set to the given value.
+    #[must_use]
+    pub fn with_concurrency_policy(mut self, value: impl Into<String>) -> Self {
+        self.concurrency_policy = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`CronJobSpec::failed_jobs_history_limit`] set to the given value.
+    #[must_use]
+    pub fn with_failed_jobs_history_limit(mut self, value: impl Into<i32>) -> Self {
+        self.failed_jobs_history_limit = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`CronJobSpec::job_template`] set to the given value.
+    #[must_use]
+    pub fn with_job_template(mut self, value: impl Into<crate::api::batch::v1beta1::JobTemplateSpec>) -> Self {
+        self.job_template = value.into();
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "concurrencyPolicy" => Field::Key_concurrency_policy,
-                            "failedJobsHistoryLimit" => Field::Key_failed_jobs_history_limit,
-                            "jobTemplate" => Field::Key_job_template,
-                            "schedule" => Field::Key_schedule,
-                            "startingDeadlineSeconds" => Field::Key_starting_deadline_seconds,
-                            "successfulJobsHistoryLimit" => Field::Key_successful_jobs_history_limit,
-                            "suspend" => Field::Key_suspend,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`CronJobSpec::schedule`] set to the given value.
+    #[must_use]
+    pub fn with_schedule(mut self, value: impl Into<String>) -> Self {
+        self.schedule = value.into();
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`CronJobSpec::starting_deadline_seconds`] set to the given value.
+    #[must_use]
+    pub fn with_starting_deadline_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.starting_deadline_seconds = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`CronJobSpec::successful_jobs_history_limit`] set to the given value.
+    #[must_use]
+    pub fn with_successful_jobs_history_limit(mut self, value: impl Into<i32>) -> Self {
+        self.successful_jobs_history_limit = Some(value.into());
+        self
+    }
 
+    /// Returns this value with [`CronJobSpec::suspend`] set to the given value.
+    #[must_use]
+    pub fn with_suspend(mut self, value: impl Into<bool>) -> Self {
+        self.suspend = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for CronJobSpec {}
+
+impl CronJobSpec {
+    const FIELDS: &'static [&'static str] = &[
+        "concurrencyPolicy",
+        "failedJobsHistoryLimit",
+        "jobTemplate",
+        "schedule",
+        "startingDeadlineSeconds",
+        "successfulJobsHistoryLimit",
+        "suspend",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for CronJobSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -86,49 +125,141 @@ impl<'de> crate::serde::Deserialize<'de> for CronJobSpec {
                 let mut value_successful_jobs_history_limit: Option<i32> = None;
                 let mut value_suspend: Option<bool> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CronJobSpec", CronJobSpec::FIELDS))? {
                     match key {
-                        Field::Key_concurrency_policy => value_concurrency_policy = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_failed_jobs_history_limit => value_failed_jobs_history_limit = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_job_template => value_job_template = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_schedule => value_schedule = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_starting_deadline_seconds => value_starting_deadline_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_successful_jobs_history_limit => value_successful_jobs_history_limit = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_suspend => value_suspend = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_concurrency_policy = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_failed_jobs_history_limit = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_job_template = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(3) => value_schedule = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(4) => value_starting_deadline_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_successful_jobs_history_limit = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_suspend = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(CronJobSpec {
                     concurrency_policy: value_concurrency_policy,
                     failed_jobs_history_limit: value_failed_jobs_history_limit,
-                    job_template: value_job_template.ok_or_else(|| crate::serde::de::Error::missing_field("jobTemplate"))?,
-                    schedule: value_schedule.ok_or_else(|| crate::serde::de::Error::missing_field("schedule"))?,
+                    job_template: value_job_template.ok_or_else(|| crate::serde2::missing_field("CronJobSpec", "jobTemplate", &["jobTemplate", "schedule"]))?,
+                    schedule: value_schedule.ok_or_else(|| crate::serde2::missing_field("CronJobSpec", "schedule", &["jobTemplate", "schedule"]))?,
                     starting_deadline_seconds: value_starting_deadline_seconds,
                     successful_jobs_history_limit: value_successful_jobs_history_limit,
                     suspend: value_suspend,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(CronJobSpec {
+                    concurrency_policy: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    failed_jobs_history_limit: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    job_template: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    schedule: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    starting_deadline_seconds: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    successful_jobs_history_limit: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    suspend: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "CronJobSpec",
-            &[
-                "concurrencyPolicy",
-                "failedJobsHistoryLimit",
-                "jobTemplate",
-                "schedule",
-                "startingDeadlineSeconds",
-                "successfulJobsHistoryLimit",
-                "suspend",
-            ],
+            CronJobSpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, CronJobSpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("CronJobSpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_concurrency_policy = false;
+                let mut has_failed_jobs_history_limit = false;
+                let mut has_job_template = false;
+                let mut has_schedule = false;
+                let mut has_starting_deadline_seconds = false;
+                let mut has_successful_jobs_history_limit = false;
+                let mut has_suspend = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CronJobSpec", CronJobSpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.concurrency_policy))?; has_concurrency_policy = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.failed_jobs_history_limit))?; has_failed_jobs_history_limit = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.job_template))?; has_job_template = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.schedule))?; has_schedule = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.starting_deadline_seconds))?; has_starting_deadline_seconds = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.successful_jobs_history_limit))?; has_successful_jobs_history_limit = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.suspend))?; has_suspend = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_concurrency_policy {
+                    self.0.concurrency_policy = None;
+                }
+                if !has_failed_jobs_history_limit {
+                    self.0.failed_jobs_history_limit = None;
+                }
+                if !has_job_template {
+                    return Err(crate::serde2::missing_field("CronJobSpec", "jobTemplate", &["jobTemplate", "schedule"]));
+                }
+                if !has_schedule {
+                    return Err(crate::serde2::missing_field("CronJobSpec", "schedule", &["jobTemplate", "schedule"]));
+                }
+                if !has_starting_deadline_seconds {
+                    self.0.starting_deadline_seconds = None;
+                }
+                if !has_successful_jobs_history_limit {
+                    self.0.successful_jobs_history_limit = None;
+                }
+                if !has_suspend {
+                    self.0.suspend = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.concurrency_policy))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.failed_jobs_history_limit))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.job_template))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.schedule))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.starting_deadline_seconds))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.successful_jobs_history_limit))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.suspend))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "CronJobSpec",
+            CronJobSpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for CronJobSpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("CronJobSpec", 7)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "concurrencyPolicy", &self.concurrency_policy)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "failedJobsHistoryLimit", &self.failed_jobs_history_limit)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "jobTemplate", &self.job_template)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "schedule", &self.schedule)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "startingDeadlineSeconds", &self.starting_deadline_seconds)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "successfulJobsHistoryLimit", &self.successful_jobs_history_limit)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "suspend", &self.suspend)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "CronJobSpec",
             2 +
@@ -159,10 +290,159 @@ impl crate::serde::Serialize for CronJobSpec {
     }
 }
 
+/// A builder of [`CronJobSpec`]. Created with [`CronJobSpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct CronJobSpecBuilder {
+    concurrency_policy: Option<String>,
+    failed_jobs_history_limit: Option<i32>,
+    job_template: Option<crate::api::batch::v1beta1::JobTemplateSpec>,
+    schedule: Option<String>,
+    starting_deadline_seconds: Option<i64>,
+    successful_jobs_history_limit: Option<i32>,
+    suspend: Option<bool>,
+}
+
+#[cfg(feature = "builder")]
+impl CronJobSpec {
+    /// Starts building a `CronJobSpec`. Its required fields must be set before [`CronJobSpecBuilder::build`] is called.
+    pub fn builder() -> CronJobSpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl CronJobSpecBuilder {
+    /// Sets [`CronJobSpec::concurrency_policy`].
+    pub fn concurrency_policy(mut self, value: impl Into<String>) -> Self {
+        self.concurrency_policy = Some(value.into());
+        self
+    }
+
+    /// Sets [`CronJobSpec::failed_jobs_history_limit`].
+    pub fn failed_jobs_history_limit(mut self, value: impl Into<i32>) -> Self {
+        self.failed_jobs_history_limit = Some(value.into());
+        self
+    }
+
+    /// Sets [`CronJobSpec::job_template`].
+    pub fn job_template(mut self, value: impl Into<crate::api::batch::v1beta1::JobTemplateSpec>) -> Self {
+        self.job_template = Some(value.into());
+        self
+    }
+
+    /// Sets [`CronJobSpec::schedule`].
+    pub fn schedule(mut self, value: impl Into<String>) -> Self {
+        self.schedule = Some(value.into());
+        self
+    }
+
+    /// Sets [`CronJobSpec::starting_deadline_seconds`].
+    pub fn starting_deadline_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.starting_deadline_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`CronJobSpec::successful_jobs_history_limit`].
+    pub fn successful_jobs_history_limit(mut self, value: impl Into<i32>) -> Self {
+        self.successful_jobs_history_limit = Some(value.into());
+        self
+    }
+
+    /// Sets [`CronJobSpec::suspend`].
+    pub fn suspend(mut self, value: impl Into<bool>) -> Self {
+        self.suspend = Some(value.into());
+        self
+    }
+
+    /// Builds the `CronJobSpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<CronJobSpec, crate::MissingFieldError> {
+        Ok(CronJobSpec {
+            concurrency_policy: self.concurrency_policy,
+            failed_jobs_history_limit: self.failed_jobs_history_limit,
+            job_template: self.job_template.ok_or_else(|| crate::MissingFieldError::new("CronJobSpec", "jobTemplate"))?,
+            schedule: self.schedule.ok_or_else(|| crate::MissingFieldError::new("CronJobSpec", "schedule"))?,
+            starting_deadline_seconds: self.starting_deadline_seconds,
+            successful_jobs_history_limit: self.successful_jobs_history_limit,
+            suspend: self.suspend,
+        })
+    }
+}
+
+/// The apply configuration of [`CronJobSpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CronJobSpecApplyConfiguration {
+    /// See [`CronJobSpec::concurrency_policy`].
+    pub concurrency_policy: Option<String>,
+
+    /// See [`CronJobSpec::failed_jobs_history_limit`].
+    pub failed_jobs_history_limit: Option<i32>,
+
+    /// See [`CronJobSpec::job_template`].
+    pub job_template: Option<crate::api::batch::v1beta1::JobTemplateSpecApplyConfiguration>,
+
+    /// See [`CronJobSpec::schedule`].
+    pub schedule: Option<String>,
+
+    /// See [`CronJobSpec::starting_deadline_seconds`].
+    pub starting_deadline_seconds: Option<i64>,
+
+    /// See [`CronJobSpec::successful_jobs_history_limit`].
+    pub successful_jobs_history_limit: Option<i32>,
+
+    /// See [`CronJobSpec::suspend`].
+    pub suspend: Option<bool>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for CronJobSpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "CronJobSpecApplyConfiguration",
+            self.concurrency_policy.as_ref().map_or(0, |_| 1) +
+            self.failed_jobs_history_limit.as_ref().map_or(0, |_| 1) +
+            self.job_template.as_ref().map_or(0, |_| 1) +
+            self.schedule.as_ref().map_or(0, |_| 1) +
+            self.starting_deadline_seconds.as_ref().map_or(0, |_| 1) +
+            self.successful_jobs_history_limit.as_ref().map_or(0, |_| 1) +
+            self.suspend.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.concurrency_policy {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "concurrencyPolicy", value)?;
+        }
+        if let Some(value) = &self.failed_jobs_history_limit {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "failedJobsHistoryLimit", value)?;
+        }
+        if let Some(value) = &self.job_template {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "jobTemplate", value)?;
+        }
+        if let Some(value) = &self.schedule {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "schedule", value)?;
+        }
+        if let Some(value) = &self.starting_deadline_seconds {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "startingDeadlineSeconds", value)?;
+        }
+        if let Some(value) = &self.successful_jobs_history_limit {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "successfulJobsHistoryLimit", value)?;
+        }
+        if let Some(value) = &self.suspend {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "suspend", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CronJobSpec {
     fn schema_name() -> String {
-        "io.k8s.api.batch.v1beta1.CronJobSpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::batch::v1beta1::CronJobSpec".to_owned()
+        }
+        else {
+            "io.k8s.api.batch.v1beta1.CronJobSpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -173,8 +453,9 @@ impl crate::schemars::JsonSchema for CronJobSpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "concurrencyPolicy".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -184,8 +465,8 @@ impl crate::schemars::JsonSchema for CronJobSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "failedJobsHistoryLimit".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -196,8 +477,8 @@ impl crate::schemars::JsonSchema for CronJobSpec {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "jobTemplate".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::batch::v1beta1::JobTemplateSpec>().into_object();
@@ -207,8 +488,8 @@ impl crate::schemars::JsonSchema for CronJobSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "schedule".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -218,8 +499,8 @@ impl crate::schemars::JsonSchema for CronJobSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "startingDeadlineSeconds".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -230,8 +511,8 @@ impl crate::schemars::JsonSchema for CronJobSpec {
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "successfulJobsHistoryLimit".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -242,8 +523,8 @@ impl crate::schemars::JsonSchema for CronJobSpec {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "suspend".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -253,8 +534,9 @@ impl crate::schemars::JsonSchema for CronJobSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "jobTemplate",
                     "schedule",
@@ -265,3 +547,18 @@ impl crate::schemars::JsonSchema for CronJobSpec {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for CronJobSpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(CronJobSpec {
+            concurrency_policy: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            failed_jobs_history_limit: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            job_template: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            schedule: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            starting_deadline_seconds: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            successful_jobs_history_limit: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            suspend: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.batch.v1beta1.CronJobStatus
 
 /// CronJobStatus represents the current state of a cron job.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct CronJobStatus {
     /// A list of pointers to currently running jobs.
     pub active: Option<Vec<crate::api::core::v1::ObjectReference>>,
@@ -10,39 +10,33 @@ pub struct CronJobStatus {
     pub last_schedule_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for CronJobStatus {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_active,
-            Key_last_schedule_time,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl CronJobStatus {
+    /// Returns this value with [`CronJobStatus::active`] set to the given value.
+    #[must_use]
+    pub fn with_active(mut self, value: impl Into<Vec<crate::api::core::v1::ObjectReference>>) -> Self {
+        self.active = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`CronJobStatus::last_schedule_time`] set to the given value.
+    #[must_use]
+    pub fn with_last_schedule_time(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.last_schedule_time = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "active" => Field::Key_active,
-                            "lastScheduleTime" => Field::Key_last_schedule_time,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for CronJobStatus {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl CronJobStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "active",
+        "lastScheduleTime",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for CronJobStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,11 +50,11 @@ impl<'de> crate::serde::Deserialize<'de> for CronJobStatus {
                 let mut value_active: Option<Vec<crate::api::core::v1::ObjectReference>> = None;
                 let mut value_last_schedule_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CronJobStatus", CronJobStatus::FIELDS))? {
                     match key {
-                        Field::Key_active => value_active = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_last_schedule_time => value_last_schedule_time = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_active = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_last_schedule_time = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -69,21 +63,78 @@ impl<'de> crate::serde::Deserialize<'de> for CronJobStatus {
                     last_schedule_time: value_last_schedule_time,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(CronJobStatus {
+                    active: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    last_schedule_time: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "CronJobStatus",
-            &[
-                "active",
-                "lastScheduleTime",
-            ],
+            CronJobStatus::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, CronJobStatus> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("CronJobStatus")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_active = false;
+                let mut has_last_schedule_time = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CronJobStatus", CronJobStatus::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.active))?; has_active = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.last_schedule_time))?; has_last_schedule_time = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_active {
+                    self.0.active = None;
+                }
+                if !has_last_schedule_time {
+                    self.0.last_schedule_time = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.active))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.last_schedule_time))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "CronJobStatus",
+            CronJobStatus::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for CronJobStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("CronJobStatus", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "active", &self.active)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "lastScheduleTime", &self.last_schedule_time)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "CronJobStatus",
             self.active.as_ref().map_or(0, |_| 1) +
@@ -99,10 +150,84 @@ impl crate::serde::Serialize for CronJobStatus {
     }
 }
 
+/// A builder of [`CronJobStatus`]. Created with [`CronJobStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct CronJobStatusBuilder {
+    active: Option<Vec<crate::api::core::v1::ObjectReference>>,
+    last_schedule_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+}
+
+#[cfg(feature = "builder")]
+impl CronJobStatus {
+    /// Starts building a `CronJobStatus`. Its required fields must be set before [`CronJobStatusBuilder::build`] is called.
+    pub fn builder() -> CronJobStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl CronJobStatusBuilder {
+    /// Sets [`CronJobStatus::active`].
+    pub fn active(mut self, value: impl Into<Vec<crate::api::core::v1::ObjectReference>>) -> Self {
+        self.active = Some(value.into());
+        self
+    }
+
+    /// Sets [`CronJobStatus::last_schedule_time`].
+    pub fn last_schedule_time(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.last_schedule_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `CronJobStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<CronJobStatus, crate::MissingFieldError> {
+        Ok(CronJobStatus {
+            active: self.active,
+            last_schedule_time: self.last_schedule_time,
+        })
+    }
+}
+
+/// The apply configuration of [`CronJobStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CronJobStatusApplyConfiguration {
+    /// See [`CronJobStatus::active`].
+    pub active: Option<Vec<crate::api::core::v1::ObjectReferenceApplyConfiguration>>,
+
+    /// See [`CronJobStatus::last_schedule_time`].
+    pub last_schedule_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for CronJobStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "CronJobStatusApplyConfiguration",
+            self.active.as_ref().map_or(0, |_| 1) +
+            self.last_schedule_time.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.active {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "active", value)?;
+        }
+        if let Some(value) = &self.last_schedule_time {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "lastScheduleTime", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CronJobStatus {
     fn schema_name() -> String {
-        "io.k8s.api.batch.v1beta1.CronJobStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::batch::v1beta1::CronJobStatus".to_owned()
+        }
+        else {
+            "io.k8s.api.batch.v1beta1.CronJobStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -113,8 +238,9 @@ impl crate::schemars::JsonSchema for CronJobStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "active".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -128,8 +254,8 @@ impl crate::schemars::JsonSchema for CronJobStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "lastScheduleTime".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::Time>().into_object();
@@ -139,11 +265,22 @@ impl crate::schemars::JsonSchema for CronJobStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for CronJobStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(CronJobStatus {
+            active: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            last_schedule_time: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,6 +1,6 @@
 // Generated from definition io.k8s.api.certificates.v1beta1.CertificateSigningRequestStatus
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct CertificateSigningRequestStatus {
     /// If request was approved, the controller will place the issued certificate here.
     pub certificate: Option<crate::ByteString>,
@@ -9,39 +9,33 @@ pub struct CertificateSigningRequestStatus {
     pub conditions: Option<Vec<crate::api::certificates::v1beta1::CertificateSigningRequestCondition>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for CertificateSigningRequestStatus {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_certificate,
-            Key_conditions,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl CertificateSigningRequestStatus {
+    /// Returns this value with [`CertificateSigningRequestStatus::certificate`] set to the given value.
+    #[must_use]
+    pub fn with_certificate(mut self, value: impl Into<crate::ByteString>) -> Self {
+        self.certificate = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`CertificateSigningRequestStatus::conditions`] set to the given value.
+    #[must_use]
+    pub fn with_conditions(mut self, value: impl Into<Vec<crate::api::certificates::v1beta1::CertificateSigningRequestCondition>>) -> Self {
+        self.conditions = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "certificate" => Field::Key_certificate,
-                            "conditions" => Field::Key_conditions,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for CertificateSigningRequestStatus {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl CertificateSigningRequestStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "certificate",
+        "conditions",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for CertificateSigningRequestStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -55,11 +49,11 @@ impl<'de> crate::serde::Deserialize<'de> for CertificateSigningRequestStatus {
                 let mut value_certificate: Option<crate::ByteString> = None;
                 let mut value_conditions: Option<Vec<crate::api::certificates::v1beta1::CertificateSigningRequestCondition>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CertificateSigningRequestStatus", CertificateSigningRequestStatus::FIELDS))? {
                     match key {
-                        Field::Key_certificate => value_certificate = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_conditions => value_conditions = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_certificate = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_conditions = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -68,21 +62,78 @@ impl<'de> crate::serde::Deserialize<'de> for CertificateSigningRequestStatus {
                     conditions: value_conditions,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(CertificateSigningRequestStatus {
+                    certificate: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    conditions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "CertificateSigningRequestStatus",
-            &[
-                "certificate",
-                "conditions",
-            ],
+            CertificateSigningRequestStatus::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, CertificateSigningRequestStatus> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("CertificateSigningRequestStatus")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_certificate = false;
+                let mut has_conditions = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CertificateSigningRequestStatus", CertificateSigningRequestStatus::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.certificate))?; has_certificate = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.conditions))?; has_conditions = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_certificate {
+                    self.0.certificate = None;
+                }
+                if !has_conditions {
+                    self.0.conditions = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.certificate))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.conditions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "CertificateSigningRequestStatus",
+            CertificateSigningRequestStatus::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for CertificateSigningRequestStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("CertificateSigningRequestStatus", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "certificate", &self.certificate)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conditions", &self.conditions)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "CertificateSigningRequestStatus",
             self.certificate.as_ref().map_or(0, |_| 1) +
@@ -98,18 +149,93 @@ impl crate::serde::Serialize for CertificateSigningRequestStatus {
     }
 }
 
+/// A builder of [`CertificateSigningRequestStatus`]. Created with [`CertificateSigningRequestStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct CertificateSigningRequestStatusBuilder {
+    certificate: Option<crate::ByteString>,
+    conditions: Option<Vec<crate::api::certificates::v1beta1::CertificateSigningRequestCondition>>,
+}
+
+#[cfg(feature = "builder")]
+impl CertificateSigningRequestStatus {
+    /// Starts building a `CertificateSigningRequestStatus`. Its required fields must be set before [`CertificateSigningRequestStatusBuilder::build`] is called.
+    pub fn builder() -> CertificateSigningRequestStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl CertificateSigningRequestStatusBuilder {
+    /// Sets [`CertificateSigningRequestStatus::certificate`].
+    pub fn certificate(mut self, value: impl Into<crate::ByteString>) -> Self {
+        self.certificate = Some(value.into());
+        self
+    }
+
+    /// Sets [`CertificateSigningRequestStatus::conditions`].
+    pub fn conditions(mut self, value: impl Into<Vec<crate::api::certificates::v1beta1::CertificateSigningRequestCondition>>) -> Self {
+        self.conditions = Some(value.into());
+        self
+    }
+
+    /// Builds the `CertificateSigningRequestStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<CertificateSigningRequestStatus, crate::MissingFieldError> {
+        Ok(CertificateSigningRequestStatus {
+            certificate: self.certificate,
+            conditions: self.conditions,
+        })
+    }
+}
+
+/// The apply configuration of [`CertificateSigningRequestStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CertificateSigningRequestStatusApplyConfiguration {
+    /// See [`CertificateSigningRequestStatus::certificate`].
+    pub certificate: Option<crate::ByteString>,
+
+    /// See [`CertificateSigningRequestStatus::conditions`].
+    pub conditions: Option<Vec<crate::api::certificates::v1beta1::CertificateSigningRequestConditionApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for CertificateSigningRequestStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "CertificateSigningRequestStatusApplyConfiguration",
+            self.certificate.as_ref().map_or(0, |_| 1) +
+            self.conditions.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.certificate {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "certificate", value)?;
+        }
+        if let Some(value) = &self.conditions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conditions", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CertificateSigningRequestStatus {
     fn schema_name() -> String {
-        "io.k8s.api.certificates.v1beta1.CertificateSigningRequestStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::certificates::v1beta1::CertificateSigningRequestStatus".to_owned()
+        }
+        else {
+            "io.k8s.api.certificates.v1beta1.CertificateSigningRequestStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "certificate".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -120,8 +246,8 @@ impl crate::schemars::JsonSchema for CertificateSigningRequestStatus {
                             format: Some("byte".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "conditions".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -135,11 +261,22 @@ impl crate::schemars::JsonSchema for CertificateSigningRequestStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for CertificateSigningRequestStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(CertificateSigningRequestStatus {
+            certificate: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            conditions: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.coordination.v1.LeaseSpec
 
 /// LeaseSpec is a specification of a Lease.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct LeaseSpec {
     /// acquireTime is a time when the current lease was acquired.
     pub acquire_time: Option<crate::apimachinery::pkg::apis::meta::v1::MicroTime>,
@@ -19,45 +19,57 @@ pub struct LeaseSpec {
     pub renew_time: Option<crate::apimachinery::pkg::apis::meta::v1::MicroTime>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for LeaseSpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_acquire_time,
-            Key_holder_identity,
-            Key_lease_duration_seconds,
-            Key_lease_transitions,
-            Key_renew_time,
-            Other,
-        }
+impl LeaseSpec {
+    /// Returns this value with [`LeaseSpec::acquire_time`] set to the given value.
+    #[must_use]
+    pub fn with_acquire_time(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::MicroTime>) -> Self {
+        self.acquire_time = Some(value.into());
+        self
+    }
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+    /// Returns this value with [`LeaseSpec::holder_identity`] set to the given value.
+    #[must_use]
+    pub fn with_holder_identity(mut self, value: impl Into<String>) -> Self {
+        self.holder_identity = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`LeaseSpec::lease_duration_seconds`] set to the given value.
+    #[must_use]
+    pub fn with_lease_duration_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.lease_duration_seconds = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`LeaseSpec::lease_transitions`] set to the given value.
+    #[must_use]
+    pub fn with_lease_transitions(mut self, value: impl Into<i32>) -> Self {
+        self.lease_transitions = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "acquireTime" => Field::Key_acquire_time,
-                            "holderIdentity" => Field::Key_holder_identity,
-                            "leaseDurationSeconds" => Field::Key_lease_duration_seconds,
-                            "leaseTransitions" => Field::Key_lease_transitions,
-                            "renewTime" => Field::Key_renew_time,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`LeaseSpec::renew_time`] set to the given value.
+    #[must_use]
+    pub fn with_renew_time(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::MicroTime>) -> Self {
+        self.renew_time = Some(value.into());
+        self
+    }
+}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl crate::MergeStrategyInfo for LeaseSpec {}
 
+impl LeaseSpec {
+    const FIELDS: &'static [&'static str] = &[
+        "acquireTime",
+        "holderIdentity",
+        "leaseDurationSeconds",
+        "leaseTransitions",
+        "renewTime",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for LeaseSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -74,14 +86,14 @@ impl<'de> crate::serde::Deserialize<'de> for LeaseSpec {
                 let mut value_lease_transitions: Option<i32> = None;
                 let mut value_renew_time: Option<crate::apimachinery::pkg::apis::meta::v1::MicroTime> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("LeaseSpec", LeaseSpec::FIELDS))? {
                     match key {
-                        Field::Key_acquire_time => value_acquire_time = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_holder_identity => value_holder_identity = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_lease_duration_seconds => value_lease_duration_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_lease_transitions => value_lease_transitions = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_renew_time => value_renew_time = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_acquire_time = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_holder_identity = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_lease_duration_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_lease_transitions = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_renew_time = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -93,24 +105,102 @@ impl<'de> crate::serde::Deserialize<'de> for LeaseSpec {
                     renew_time: value_renew_time,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(LeaseSpec {
+                    acquire_time: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    holder_identity: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    lease_duration_seconds: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    lease_transitions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    renew_time: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "LeaseSpec",
-            &[
-                "acquireTime",
-                "holderIdentity",
-                "leaseDurationSeconds",
-                "leaseTransitions",
-                "renewTime",
-            ],
+            LeaseSpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, LeaseSpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("LeaseSpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_acquire_time = false;
+                let mut has_holder_identity = false;
+                let mut has_lease_duration_seconds = false;
+                let mut has_lease_transitions = false;
+                let mut has_renew_time = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("LeaseSpec", LeaseSpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.acquire_time))?; has_acquire_time = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.holder_identity))?; has_holder_identity = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.lease_duration_seconds))?; has_lease_duration_seconds = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.lease_transitions))?; has_lease_transitions = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.renew_time))?; has_renew_time = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_acquire_time {
+                    self.0.acquire_time = None;
+                }
+                if !has_holder_identity {
+                    self.0.holder_identity = None;
+                }
+                if !has_lease_duration_seconds {
+                    self.0.lease_duration_seconds = None;
+                }
+                if !has_lease_transitions {
+                    self.0.lease_transitions = None;
+                }
+                if !has_renew_time {
+                    self.0.renew_time = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.acquire_time))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.holder_identity))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.lease_duration_seconds))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.lease_transitions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.renew_time))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "LeaseSpec",
+            LeaseSpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for LeaseSpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("LeaseSpec", 5)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "acquireTime", &self.acquire_time)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "holderIdentity", &self.holder_identity)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "leaseDurationSeconds", &self.lease_duration_seconds)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "leaseTransitions", &self.lease_transitions)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "renewTime", &self.renew_time)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "LeaseSpec",
             self.acquire_time.as_ref().map_or(0, |_| 1) +
@@ -138,10 +228,129 @@ impl crate::serde::Serialize for LeaseSpec {
     }
 }
 
+/// A builder of [`LeaseSpec`]. Created with [`LeaseSpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct LeaseSpecBuilder {
+    acquire_time: Option<crate::apimachinery::pkg::apis::meta::v1::MicroTime>,
+    holder_identity: Option<String>,
+    lease_duration_seconds: Option<i32>,
+    lease_transitions: Option<i32>,
+    renew_time: Option<crate::apimachinery::pkg::apis::meta::v1::MicroTime>,
+}
+
+#[cfg(feature = "builder")]
+impl LeaseSpec {
+    /// Starts building a `LeaseSpec`. Its required fields must be set before [`LeaseSpecBuilder::build`] is called.
+    pub fn builder() -> LeaseSpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl LeaseSpecBuilder {
+    /// Sets [`LeaseSpec::acquire_time`].
+    pub fn acquire_time(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::MicroTime>) -> Self {
+        self.acquire_time = Some(value.into());
+        self
+    }
+
+    /// Sets [`LeaseSpec::holder_identity`].
+    pub fn holder_identity(mut self, value: impl Into<String>) -> Self {
+        self.holder_identity = Some(value.into());
+        self
+    }
+
+    /// Sets [`LeaseSpec::lease_duration_seconds`].
+    pub fn lease_duration_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.lease_duration_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`LeaseSpec::lease_transitions`].
+    pub fn lease_transitions(mut self, value: impl Into<i32>) -> Self {
+        self.lease_transitions = Some(value.into());
+        self
+    }
+
+    /// Sets [`LeaseSpec::renew_time`].
+    pub fn renew_time(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::MicroTime>) -> Self {
+        self.renew_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `LeaseSpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<LeaseSpec, crate::MissingFieldError> {
+        Ok(LeaseSpec {
+            acquire_time: self.acquire_time,
+            holder_identity: self.holder_identity,
+            lease_duration_seconds: self.lease_duration_seconds,
+            lease_transitions: self.lease_transitions,
+            renew_time: self.renew_time,
+        })
+    }
+}
+
+/// The apply configuration of [`LeaseSpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LeaseSpecApplyConfiguration {
+    /// See [`LeaseSpec::acquire_time`].
+    pub acquire_time: Option<crate::apimachinery::pkg::apis::meta::v1::MicroTime>,
+
+    /// See [`LeaseSpec::holder_identity`].
+    pub holder_identity: Option<String>,
+
+    /// See [`LeaseSpec::lease_duration_seconds`].
+    pub lease_duration_seconds: Option<i32>,
+
+    /// See [`LeaseSpec::lease_transitions`].
+    pub lease_transitions: Option<i32>,
+
+    /// See [`LeaseSpec::renew_time`].
+    pub renew_time: Option<crate::apimachinery::pkg::apis::meta::v1::MicroTime>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for LeaseSpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "LeaseSpecApplyConfiguration",
+            self.acquire_time.as_ref().map_or(0, |_| 1) +
+            self.holder_identity.as_ref().map_or(0, |_| 1) +
+            self.lease_duration_seconds.as_ref().map_or(0, |_| 1) +
+            self.lease_transitions.as_ref().map_or(0, |_| 1) +
+            self.renew_time.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.acquire_time {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "acquireTime", value)?;
+        }
+        if let Some(value) = &self.holder_identity {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "holderIdentity", value)?;
+        }
+        if let Some(value) = &self.lease_duration_seconds {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "leaseDurationSeconds", value)?;
+        }
+        if let Some(value) = &self.lease_transitions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "leaseTransitions", value)?;
+        }
+        if let Some(value) = &self.renew_time {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "renewTime", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for LeaseSpec {
     fn schema_name() -> String {
-        "io.k8s.api.coordination.v1.LeaseSpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::coordination::v1::LeaseSpec".to_owned()
+        }
+        else {
+            "io.k8s.api.coordination.v1.LeaseSpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -152,8 +361,9 @@ impl crate::schemars::JsonSchema for LeaseSpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "acquireTime".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::MicroTime>().into_object();
@@ -163,8 +373,8 @@ impl crate::schemars::JsonSchema for LeaseSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "holderIdentity".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -174,8 +384,8 @@ impl crate::schemars::JsonSchema for LeaseSpec {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "leaseDurationSeconds".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -186,8 +396,8 @@ impl crate::schemars::JsonSchema for LeaseSpec {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "leaseTransitions".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -198,8 +408,8 @@ impl crate::schemars::JsonSchema for LeaseSpec {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "renewTime".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::MicroTime>().into_object();
@@ -209,11 +419,25 @@ impl crate::schemars::JsonSchema for LeaseSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for LeaseSpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(LeaseSpec {
+            acquire_time: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            holder_identity: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            lease_duration_seconds: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            lease_transitions: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            renew_time: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.ContainerImage
 
 /// Describe a container image
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ContainerImage {
     /// Names by which this image is known. e.g. \["k8s.gcr.io/hyperkube:v1.0.7", "dockerhub.io/google_containers/hyperkube:v1.0.7"\]
     pub names: Option<Vec<String>>,
@@ -10,39 +10,33 @@ pub struct ContainerImage {
     pub size_bytes: Option<i64>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ContainerImage {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_names,
-            Key_size_bytes,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl ContainerImage {
+    /// Returns this value with [`ContainerImage::names`] set to the given value.
+    #[must_use]
+    pub fn with_names(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.names = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ContainerImage::size_bytes`] set to the given value.
+    #[must_use]
+    pub fn with_size_bytes(mut self, value: impl Into<i64>) -> Self {
+        self.size_bytes = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "names" => Field::Key_names,
-                            "sizeBytes" => Field::Key_size_bytes,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for ContainerImage {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl ContainerImage {
+    const FIELDS: &'static [&'static str] = &[
+        "names",
+        "sizeBytes",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ContainerImage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,11 +50,11 @@ impl<'de> crate::serde::Deserialize<'de> for ContainerImage {
                 let mut value_names: Option<Vec<String>> = None;
                 let mut value_size_bytes: Option<i64> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ContainerImage", ContainerImage::FIELDS))? {
                     match key {
-                        Field::Key_names => value_names = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_size_bytes => value_size_bytes = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_names = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_size_bytes = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -69,21 +63,78 @@ impl<'de> crate::serde::Deserialize<'de> for ContainerImage {
                     size_bytes: value_size_bytes,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ContainerImage {
+                    names: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    size_bytes: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "ContainerImage",
-            &[
-                "names",
-                "sizeBytes",
-            ],
+            ContainerImage::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ContainerImage> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ContainerImage")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_names = false;
+                let mut has_size_bytes = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ContainerImage", ContainerImage::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.names))?; has_names = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.size_bytes))?; has_size_bytes = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_names {
+                    self.0.names = None;
+                }
+                if !has_size_bytes {
+                    self.0.size_bytes = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.names))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.size_bytes))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ContainerImage",
+            ContainerImage::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ContainerImage {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ContainerImage", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "names", &self.names)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "sizeBytes", &self.size_bytes)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ContainerImage",
             self.names.as_ref().map_or(0, |_| 1) +
@@ -99,10 +150,84 @@ impl crate::serde::Serialize for ContainerImage {
     }
 }
 
+/// A builder of [`ContainerImage`]. Created with [`ContainerImage::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ContainerImageBuilder {
+    names: Option<Vec<String>>,
+    size_bytes: Option<i64>,
+}
+
+#[cfg(feature = "builder")]
+impl ContainerImage {
+    /// Starts building a `ContainerImage`. Its required fields must be set before [`ContainerImageBuilder::build`] is called.
+    pub fn builder() -> ContainerImageBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ContainerImageBuilder {
+    /// Sets [`ContainerImage::names`].
+    pub fn names(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.names = Some(value.into());
+        self
+    }
+
+    /// Sets [`ContainerImage::size_bytes`].
+    pub fn size_bytes(mut self, value: impl Into<i64>) -> Self {
+        self.size_bytes = Some(value.into());
+        self
+    }
+
+    /// Builds the `ContainerImage`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ContainerImage, crate::MissingFieldError> {
+        Ok(ContainerImage {
+            names: self.names,
+            size_bytes: self.size_bytes,
+        })
+    }
+}
+
+/// The apply configuration of [`ContainerImage`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ContainerImageApplyConfiguration {
+    /// See [`ContainerImage::names`].
+    pub names: Option<Vec<String>>,
+
+    /// See [`ContainerImage::size_bytes`].
+    pub size_bytes: Option<i64>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ContainerImageApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ContainerImageApplyConfiguration",
+            self.names.as_ref().map_or(0, |_| 1) +
+            self.size_bytes.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.names {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "names", value)?;
+        }
+        if let Some(value) = &self.size_bytes {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "sizeBytes", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ContainerImage {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.ContainerImage".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::ContainerImage".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.ContainerImage".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -113,8 +238,9 @@ impl crate::schemars::JsonSchema for ContainerImage {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "names".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -133,8 +259,8 @@ impl crate::schemars::JsonSchema for ContainerImage {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "sizeBytes".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -145,11 +271,22 @@ impl crate::schemars::JsonSchema for ContainerImage {
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ContainerImage {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ContainerImage {
+            names: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            size_bytes: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.CSIPersistentVolumeSource
 
 /// Represents storage that is managed by an external CSI volume driver (Beta feature)
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct CSIPersistentVolumeSource {
     /// ControllerPublishSecretRef is a reference to the secret object containing sensitive information to pass to the CSI driver to complete the CSI ControllerPublishVolume and ControllerUnpublishVolume calls. This field is optional, and may be empty if no secret is required. If the secret object contains more than one secret, all secrets are passed.
     pub controller_publish_secret_ref: Option<crate::api::core::v1::SecretReference>,
@@ -28,51 +28,97 @@ pub struct CSIPersistentVolumeSource {
     pub volume_handle: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for CSIPersistentVolumeSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_controller_publish_secret_ref,
-            Key_driver,
-            Key_fs_type,
-            Key_node_publish_secret_ref,
-            Key_node_stage_secret_ref,
-            Key_read_only,
-            Key_volume_attributes,
-            Key_volume_handle,
-            Other,
+impl CSIPersistentVolumeSource {
+    /// Constructs a `CSIPersistentVolumeSource` from its required fields. Its optional fields are unset.
+    pub fn new(driver: impl Into<String>, volume_handle: impl Into<String>) -> Self {
+        CSIPersistentVolumeSource {
+            controller_publish_secret_ref: None,
+            driver: driver.into(),
+            fs_type: None,
+            node_publish_secret_ref: None,
+            node_stage_secret_ref: None,
+            read_only: None,
+            volume_attributes: None,
+            volume_handle: volume_handle.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl CSIPersistentVolumeSource {
+    /// Returns this value with [`CSIPersistentVolumeSource::controller_publish_secret_ref`] set to the given value.
+    #[must_use]
+    pub fn with_controller_publish_secret_ref(mut self, value: impl Into<crate::api::core::v1::SecretReference>) -> Self {
+        self.controller_publish_secret_ref = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`CSIPersistentVolumeSource::driver`] set to the given value.
+    #[must_use]
+    pub fn with_driver(mut self, value: impl Into<String>) -> Self {
+        self.driver = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`CSIPersistentVolumeSource::fs_type`] set to the given value.
+    #[must_use]
+    pub fn with_fs_type(mut self, value: impl Into<String>) -> Self {
+        self.fs_type = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "controllerPublishSecretRef" => Field::Key_controller_publish_secret_ref,
-                            "driver" => Field::Key_driver,
-                            "fsType" => Field::Key_fs_type,
-                            "nodePublishSecretRef" => Field::Key_node_publish_secret_ref,
-                            "nodeStageSecretRef" => Field::Key_node_stage_secret_ref,
-                            "readOnly" => Field::Key_read_only,
-                            "volumeAttributes" => Field::Key_volume_attributes,
-                            "volumeHandle" => Field::Key_volume_handle,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`CSIPersistentVolumeSource::node_publish_secret_ref`] set to the given value.
+    #[must_use]
+    pub fn with_node_publish_secret_ref(mut self, value: impl Into<crate::api::core::v1::SecretReference>) -> Self {
+        self.node_publish_secret_ref = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`CSIPersistentVolumeSource::node_stage_secret_ref`] set to the given value.
+    #[must_use]
+    pub fn with_node_stage_secret_ref(mut self, value: impl Into<crate::api::core::v1::SecretReference>) -> Self {
+        self.node_stage_secret_ref = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`CSIPersistentVolumeSource::read_only`] set to the given value.
+    #[must_use]
+    pub fn with_read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
 
+    /// Returns this value with [`CSIPersistentVolumeSource::volume_attributes`] set to the given value.
+    #[must_use]
+    pub fn with_volume_attributes(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.volume_attributes = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`CSIPersistentVolumeSource::volume_handle`] set to the given value.
+    #[must_use]
+    pub fn with_volume_handle(mut self, value: impl Into<String>) -> Self {
+        self.volume_handle = value.into();
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for CSIPersistentVolumeSource {}
+
+impl CSIPersistentVolumeSource {
+    const FIELDS: &'static [&'static str] = &[
+        "controllerPublishSecretRef",
+        "driver",
+        "fsType",
+        "nodePublishSecretRef",
+        "nodeStageSecretRef",
+        "readOnly",
+        "volumeAttributes",
+        "volumeHandle",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for CSIPersistentVolumeSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -92,52 +138,151 @@ impl<'de> crate::serde::Deserialize<'de> for CSIPersistentVolumeSource {
                 let mut value_volume_attributes: Option<std::collections::BTreeMap<String, String>> = None;
                 let mut value_volume_handle: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CSIPersistentVolumeSource", CSIPersistentVolumeSource::FIELDS))? {
                     match key {
-                        Field::Key_controller_publish_secret_ref => value_controller_publish_secret_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_driver => value_driver = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_fs_type => value_fs_type = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_node_publish_secret_ref => value_node_publish_secret_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_node_stage_secret_ref => value_node_stage_secret_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_read_only => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_volume_attributes => value_volume_attributes = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_volume_handle => value_volume_handle = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_controller_publish_secret_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_driver = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_fs_type = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_node_publish_secret_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_node_stage_secret_ref = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_read_only = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_volume_attributes = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(7) => value_volume_handle = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(CSIPersistentVolumeSource {
                     controller_publish_secret_ref: value_controller_publish_secret_ref,
-                    driver: value_driver.ok_or_else(|| crate::serde::de::Error::missing_field("driver"))?,
+                    driver: value_driver.ok_or_else(|| crate::serde2::missing_field("CSIPersistentVolumeSource", "driver", &["driver", "volumeHandle"]))?,
                     fs_type: value_fs_type,
                     node_publish_secret_ref: value_node_publish_secret_ref,
                     node_stage_secret_ref: value_node_stage_secret_ref,
                     read_only: value_read_only,
                     volume_attributes: value_volume_attributes,
-                    volume_handle: value_volume_handle.ok_or_else(|| crate::serde::de::Error::missing_field("volumeHandle"))?,
+                    volume_handle: value_volume_handle.ok_or_else(|| crate::serde2::missing_field("CSIPersistentVolumeSource", "volumeHandle", &["driver", "volumeHandle"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(CSIPersistentVolumeSource {
+                    controller_publish_secret_ref: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    driver: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    fs_type: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    node_publish_secret_ref: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    node_stage_secret_ref: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    read_only: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    volume_attributes: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    volume_handle: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "CSIPersistentVolumeSource",
-            &[
-                "controllerPublishSecretRef",
-                "driver",
-                "fsType",
-                "nodePublishSecretRef",
-                "nodeStageSecretRef",
-                "readOnly",
-                "volumeAttributes",
-                "volumeHandle",
-            ],
+            CSIPersistentVolumeSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, CSIPersistentVolumeSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("CSIPersistentVolumeSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_controller_publish_secret_ref = false;
+                let mut has_driver = false;
+                let mut has_fs_type = false;
+                let mut has_node_publish_secret_ref = false;
+                let mut has_node_stage_secret_ref = false;
+                let mut has_read_only = false;
+                let mut has_volume_attributes = false;
+                let mut has_volume_handle = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("CSIPersistentVolumeSource", CSIPersistentVolumeSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.controller_publish_secret_ref))?; has_controller_publish_secret_ref = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.driver))?; has_driver = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.fs_type))?; has_fs_type = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.node_publish_secret_ref))?; has_node_publish_secret_ref = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.node_stage_secret_ref))?; has_node_stage_secret_ref = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.read_only))?; has_read_only = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.volume_attributes))?; has_volume_attributes = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.volume_handle))?; has_volume_handle = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_controller_publish_secret_ref {
+                    self.0.controller_publish_secret_ref = None;
+                }
+                if !has_driver {
+                    return Err(crate::serde2::missing_field("CSIPersistentVolumeSource", "driver", &["driver", "volumeHandle"]));
+                }
+                if !has_fs_type {
+                    self.0.fs_type = None;
+                }
+                if !has_node_publish_secret_ref {
+                    self.0.node_publish_secret_ref = None;
+                }
+                if !has_node_stage_secret_ref {
+                    self.0.node_stage_secret_ref = None;
+                }
+                if !has_read_only {
+                    self.0.read_only = None;
+                }
+                if !has_volume_attributes {
+                    self.0.volume_attributes = None;
+                }
+                if !has_volume_handle {
+                    return Err(crate::serde2::missing_field("CSIPersistentVolumeSource", "volumeHandle", &["driver", "volumeHandle"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.controller_publish_secret_ref))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.driver))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.fs_type))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.node_publish_secret_ref))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.node_stage_secret_ref))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.read_only))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.volume_attributes))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.volume_handle))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "CSIPersistentVolumeSource",
+            CSIPersistentVolumeSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for CSIPersistentVolumeSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("CSIPersistentVolumeSource", 8)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "controllerPublishSecretRef", &self.controller_publish_secret_ref)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "driver", &self.driver)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsType", &self.fs_type)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodePublishSecretRef", &self.node_publish_secret_ref)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeStageSecretRef", &self.node_stage_secret_ref)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", &self.read_only)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeAttributes", &self.volume_attributes)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeHandle", &self.volume_handle)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "CSIPersistentVolumeSource",
             2 +
@@ -172,10 +317,174 @@ impl crate::serde::Serialize for CSIPersistentVolumeSource {
     }
 }
 
+/// A builder of [`CSIPersistentVolumeSource`]. Created with [`CSIPersistentVolumeSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct CSIPersistentVolumeSourceBuilder {
+    controller_publish_secret_ref: Option<crate::api::core::v1::SecretReference>,
+    driver: Option<String>,
+    fs_type: Option<String>,
+    node_publish_secret_ref: Option<crate::api::core::v1::SecretReference>,
+    node_stage_secret_ref: Option<crate::api::core::v1::SecretReference>,
+    read_only: Option<bool>,
+    volume_attributes: Option<std::collections::BTreeMap<String, String>>,
+    volume_handle: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl CSIPersistentVolumeSource {
+    /// Starts building a `CSIPersistentVolumeSource`. Its required fields must be set before [`CSIPersistentVolumeSourceBuilder::build`] is called.
+    pub fn builder() -> CSIPersistentVolumeSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl CSIPersistentVolumeSourceBuilder {
+    /// Sets [`CSIPersistentVolumeSource::controller_publish_secret_ref`].
+    pub fn controller_publish_secret_ref(mut self, value: impl Into<crate::api::core::v1::SecretReference>) -> Self {
+        self.controller_publish_secret_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`CSIPersistentVolumeSource::driver`].
+    pub fn driver(mut self, value: impl Into<String>) -> Self {
+        self.driver = Some(value.into());
+        self
+    }
+
+    /// Sets [`CSIPersistentVolumeSource::fs_type`].
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`CSIPersistentVolumeSource::node_publish_secret_ref`].
+    pub fn node_publish_secret_ref(mut self, value: impl Into<crate::api::core::v1::SecretReference>) -> Self {
+        self.node_publish_secret_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`CSIPersistentVolumeSource::node_stage_secret_ref`].
+    pub fn node_stage_secret_ref(mut self, value: impl Into<crate::api::core::v1::SecretReference>) -> Self {
+        self.node_stage_secret_ref = Some(value.into());
+        self
+    }
+
+    /// Sets [`CSIPersistentVolumeSource::read_only`].
+    pub fn read_only(mut self, value: impl Into<bool>) -> Self {
+        self.read_only = Some(value.into());
+        self
+    }
+
+    /// Sets [`CSIPersistentVolumeSource::volume_attributes`].
+    pub fn volume_attributes(mut self, value: impl Into<std::collections::BTreeMap<String, String>>) -> Self {
+        self.volume_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets [`CSIPersistentVolumeSource::volume_handle`].
+    pub fn volume_handle(mut self, value: impl Into<String>) -> Self {
+        self.volume_handle = Some(value.into());
+        self
+    }
+
+    /// Builds the `CSIPersistentVolumeSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<CSIPersistentVolumeSource, crate::MissingFieldError> {
+        Ok(CSIPersistentVolumeSource {
+            controller_publish_secret_ref: self.controller_publish_secret_ref,
+            driver: self.driver.ok_or_else(|| crate::MissingFieldError::new("CSIPersistentVolumeSource", "driver"))?,
+            fs_type: self.fs_type,
+            node_publish_secret_ref: self.node_publish_secret_ref,
+            node_stage_secret_ref: self.node_stage_secret_ref,
+            read_only: self.read_only,
+            volume_attributes: self.volume_attributes,
+            volume_handle: self.volume_handle.ok_or_else(|| crate::MissingFieldError::new("CSIPersistentVolumeSource", "volumeHandle"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`CSIPersistentVolumeSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CSIPersistentVolumeSourceApplyConfiguration {
+    /// See [`CSIPersistentVolumeSource::controller_publish_secret_ref`].
+    pub controller_publish_secret_ref: Option<crate::api::core::v1::SecretReferenceApplyConfiguration>,
+
+    /// See [`CSIPersistentVolumeSource::driver`].
+    pub driver: Option<String>,
+
+    /// See [`CSIPersistentVolumeSource::fs_type`].
+    pub fs_type: Option<String>,
+
+    /// See [`CSIPersistentVolumeSource::node_publish_secret_ref`].
+    pub node_publish_secret_ref: Option<crate::api::core::v1::SecretReferenceApplyConfiguration>,
+
+    /// See [`CSIPersistentVolumeSource::node_stage_secret_ref`].
+    pub node_stage_secret_ref: Option<crate::api::core::v1::SecretReferenceApplyConfiguration>,
+
+    /// See [`CSIPersistentVolumeSource::read_only`].
+    pub read_only: Option<bool>,
+
+    /// See [`CSIPersistentVolumeSource::volume_attributes`].
+    pub volume_attributes: Option<std::collections::BTreeMap<String, String>>,
+
+    /// See [`CSIPersistentVolumeSource::volume_handle`].
+    pub volume_handle: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for CSIPersistentVolumeSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "CSIPersistentVolumeSourceApplyConfiguration",
+            self.controller_publish_secret_ref.as_ref().map_or(0, |_| 1) +
+            self.driver.as_ref().map_or(0, |_| 1) +
+            self.fs_type.as_ref().map_or(0, |_| 1) +
+            self.node_publish_secret_ref.as_ref().map_or(0, |_| 1) +
+            self.node_stage_secret_ref.as_ref().map_or(0, |_| 1) +
+            self.read_only.as_ref().map_or(0, |_| 1) +
+            self.volume_attributes.as_ref().map_or(0, |_| 1) +
+            self.volume_handle.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.controller_publish_secret_ref {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "controllerPublishSecretRef", value)?;
+        }
+        if let Some(value) = &self.driver {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "driver", value)?;
+        }
+        if let Some(value) = &self.fs_type {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsType", value)?;
+        }
+        if let Some(value) = &self.node_publish_secret_ref {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodePublishSecretRef", value)?;
+        }
+        if let Some(value) = &self.node_stage_secret_ref {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nodeStageSecretRef", value)?;
+        }
+        if let Some(value) = &self.read_only {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "readOnly", value)?;
+        }
+        if let Some(value) = &self.volume_attributes {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeAttributes", value)?;
+        }
+        if let Some(value) = &self.volume_handle {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "volumeHandle", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CSIPersistentVolumeSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.CSIPersistentVolumeSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::CSIPersistentVolumeSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.CSIPersistentVolumeSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -186,8 +495,9 @@ impl crate::schemars::JsonSchema for CSIPersistentVolumeSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "controllerPublishSecretRef".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::SecretReference>().into_object();
@@ -197,8 +507,8 @@ impl crate::schemars::JsonSchema for CSIPersistentVolumeSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "driver".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -208,8 +518,8 @@ impl crate::schemars::JsonSchema for CSIPersistentVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "fsType".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -219,8 +529,8 @@ impl crate::schemars::JsonSchema for CSIPersistentVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "nodePublishSecretRef".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::SecretReference>().into_object();
@@ -230,8 +540,8 @@ impl crate::schemars::JsonSchema for CSIPersistentVolumeSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "nodeStageSecretRef".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::SecretReference>().into_object();
@@ -241,8 +551,8 @@ impl crate::schemars::JsonSchema for CSIPersistentVolumeSource {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "readOnly".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -252,8 +562,8 @@ impl crate::schemars::JsonSchema for CSIPersistentVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "volumeAttributes".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -272,8 +582,8 @@ impl crate::schemars::JsonSchema for CSIPersistentVolumeSource {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "volumeHandle".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -283,8 +593,9 @@ impl crate::schemars::JsonSchema for CSIPersistentVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "driver",
                     "volumeHandle",
@@ -295,3 +606,19 @@ impl crate::schemars::JsonSchema for CSIPersistentVolumeSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for CSIPersistentVolumeSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(CSIPersistentVolumeSource {
+            controller_publish_secret_ref: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            driver: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            fs_type: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            node_publish_secret_ref: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            node_stage_secret_ref: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            read_only: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            volume_attributes: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            volume_handle: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.EnvVar
 
 /// EnvVar represents an environment variable present in a Container.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct EnvVar {
     /// Name of the environment variable. Must be a C_IDENTIFIER.
     pub name: String,
@@ -13,41 +13,52 @@ pub struct EnvVar {
     pub value_from: Option<crate::api::core::v1::EnvVarSource>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for EnvVar {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_name,
-            Key_value,
-            Key_value_from,
-            Other,
+impl EnvVar {
+    /// Constructs a `EnvVar` from its required fields. Its optional fields are unset.
+    pub fn new(name: impl Into<String>) -> Self {
+        EnvVar {
+            name: name.into(),
+            value: None,
+            value_from: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl EnvVar {
+    /// Returns this value with [`EnvVar::name`] set to the given value.
+    #[must_use]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`EnvVar::value`] set to the given value.
+    #[must_use]
+    pub fn with_value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`EnvVar::value_from`] set to the given value.
+    #[must_use]
+    pub fn with_value_from(mut self, value: impl Into<crate::api::core::v1::EnvVarSource>) -> Self {
+        self.value_from = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "name" => Field::Key_name,
-                            "value" => Field::Key_value,
-                            "valueFrom" => Field::Key_value_from,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for EnvVar {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl EnvVar {
+    const FIELDS: &'static [&'static str] = &[
+        "name",
+        "value",
+        "valueFrom",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for EnvVar {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,37 +73,101 @@ impl<'de> crate::serde::Deserialize<'de> for EnvVar {
                 let mut value_value: Option<String> = None;
                 let mut value_value_from: Option<crate::api::core::v1::EnvVarSource> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("EnvVar", EnvVar::FIELDS))? {
                     match key {
-                        Field::Key_name => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_value => value_value = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_value_from => value_value_from = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_name = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_value = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_value_from = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(EnvVar {
-                    name: value_name.ok_or_else(|| crate::serde::de::Error::missing_field("name"))?,
+                    name: value_name.ok_or_else(|| crate::serde2::missing_field("EnvVar", "name", &["name"]))?,
                     value: value_value,
                     value_from: value_value_from,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(EnvVar {
+                    name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    value: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    value_from: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "EnvVar",
-            &[
-                "name",
-                "value",
-                "valueFrom",
-            ],
+            EnvVar::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, EnvVar> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("EnvVar")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_name = false;
+                let mut has_value = false;
+                let mut has_value_from = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("EnvVar", EnvVar::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.name))?; has_name = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.value))?; has_value = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.value_from))?; has_value_from = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_name {
+                    return Err(crate::serde2::missing_field("EnvVar", "name", &["name"]));
+                }
+                if !has_value {
+                    self.0.value = None;
+                }
+                if !has_value_from {
+                    self.0.value_from = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.value))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.value_from))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "EnvVar",
+            EnvVar::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for EnvVar {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("EnvVar", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "value", &self.value)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "valueFrom", &self.value_from)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "EnvVar",
             1 +
@@ -110,10 +185,99 @@ impl crate::serde::Serialize for EnvVar {
     }
 }
 
+/// A builder of [`EnvVar`]. Created with [`EnvVar::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct EnvVarBuilder {
+    name: Option<String>,
+    value: Option<String>,
+    value_from: Option<crate::api::core::v1::EnvVarSource>,
+}
+
+#[cfg(feature = "builder")]
+impl EnvVar {
+    /// Starts building a `EnvVar`. Its required fields must be set before [`EnvVarBuilder::build`] is called.
+    pub fn builder() -> EnvVarBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl EnvVarBuilder {
+    /// Sets [`EnvVar::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`EnvVar::value`].
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Sets [`EnvVar::value_from`].
+    pub fn value_from(mut self, value: impl Into<crate::api::core::v1::EnvVarSource>) -> Self {
+        self.value_from = Some(value.into());
+        self
+    }
+
+    /// Builds the `EnvVar`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<EnvVar, crate::MissingFieldError> {
+        Ok(EnvVar {
+            name: self.name.ok_or_else(|| crate::MissingFieldError::new("EnvVar", "name"))?,
+            value: self.value,
+            value_from: self.value_from,
+        })
+    }
+}
+
+/// The apply configuration of [`EnvVar`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EnvVarApplyConfiguration {
+    /// See [`EnvVar::name`].
+    pub name: Option<String>,
+
+    /// See [`EnvVar::value`].
+    pub value: Option<String>,
+
+    /// See [`EnvVar::value_from`].
+    pub value_from: Option<crate::api::core::v1::EnvVarSourceApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for EnvVarApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "EnvVarApplyConfiguration",
+            self.name.as_ref().map_or(0, |_| 1) +
+            self.value.as_ref().map_or(0, |_| 1) +
+            self.value_from.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "name", value)?;
+        }
+        if let Some(value) = &self.value {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "value", value)?;
+        }
+        if let Some(value) = &self.value_from {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "valueFrom", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for EnvVar {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.EnvVar".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::EnvVar".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.EnvVar".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -124,8 +288,9 @@ impl crate::schemars::JsonSchema for EnvVar {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "name".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -135,8 +300,8 @@ impl crate::schemars::JsonSchema for EnvVar {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "value".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -146,8 +311,8 @@ impl crate::schemars::JsonSchema for EnvVar {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "valueFrom".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::EnvVarSource>().into_object();
@@ -157,8 +322,9 @@ impl crate::schemars::JsonSchema for EnvVar {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "name",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -168,3 +334,14 @@ impl crate::schemars::JsonSchema for EnvVar {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for EnvVar {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(EnvVar {
+            name: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            value: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            value_from: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
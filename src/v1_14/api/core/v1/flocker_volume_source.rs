@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.FlockerVolumeSource
 
 /// Represents a Flocker volume mounted by the Flocker agent. One and only one of datasetName and datasetUUID should be set. Flocker volumes do not support ownership management or SELinux relabeling.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct FlockerVolumeSource {
     /// Name of the dataset stored as metadata -\> name on the dataset for Flocker should be considered as deprecated
     pub dataset_name: Option<String>,
@@ -10,39 +10,33 @@ pub struct FlockerVolumeSource {
     pub dataset_uuid: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for FlockerVolumeSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_dataset_name,
-            Key_dataset_uuid,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl FlockerVolumeSource {
+    /// Returns this value with [`FlockerVolumeSource::dataset_name`] set to the given value.
+    #[must_use]
+    pub fn with_dataset_name(mut self, value: impl Into<String>) -> Self {
+        self.dataset_name = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`FlockerVolumeSource::dataset_uuid`] set to the given value.
+    #[must_use]
+    pub fn with_dataset_uuid(mut self, value: impl Into<String>) -> Self {
+        self.dataset_uuid = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "datasetName" => Field::Key_dataset_name,
-                            "datasetUUID" => Field::Key_dataset_uuid,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for FlockerVolumeSource {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl FlockerVolumeSource {
+    const FIELDS: &'static [&'static str] = &[
+        "datasetName",
+        "datasetUUID",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for FlockerVolumeSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,11 +50,11 @@ impl<'de> crate::serde::Deserialize<'de> for FlockerVolumeSource {
                 let mut value_dataset_name: Option<String> = None;
                 let mut value_dataset_uuid: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("FlockerVolumeSource", FlockerVolumeSource::FIELDS))? {
                     match key {
-                        Field::Key_dataset_name => value_dataset_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_dataset_uuid => value_dataset_uuid = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_dataset_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_dataset_uuid = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -69,21 +63,78 @@ impl<'de> crate::serde::Deserialize<'de> for FlockerVolumeSource {
                     dataset_uuid: value_dataset_uuid,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(FlockerVolumeSource {
+                    dataset_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    dataset_uuid: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "FlockerVolumeSource",
-            &[
-                "datasetName",
-                "datasetUUID",
-            ],
+            FlockerVolumeSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, FlockerVolumeSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("FlockerVolumeSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_dataset_name = false;
+                let mut has_dataset_uuid = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("FlockerVolumeSource", FlockerVolumeSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.dataset_name))?; has_dataset_name = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.dataset_uuid))?; has_dataset_uuid = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_dataset_name {
+                    self.0.dataset_name = None;
+                }
+                if !has_dataset_uuid {
+                    self.0.dataset_uuid = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.dataset_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.dataset_uuid))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "FlockerVolumeSource",
+            FlockerVolumeSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for FlockerVolumeSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("FlockerVolumeSource", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "datasetName", &self.dataset_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "datasetUUID", &self.dataset_uuid)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "FlockerVolumeSource",
             self.dataset_name.as_ref().map_or(0, |_| 1) +
@@ -99,10 +150,84 @@ impl crate::serde::Serialize for FlockerVolumeSource {
     }
 }
 
+/// A builder of [`FlockerVolumeSource`]. Created with [`FlockerVolumeSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct FlockerVolumeSourceBuilder {
+    dataset_name: Option<String>,
+    dataset_uuid: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl FlockerVolumeSource {
+    /// Starts building a `FlockerVolumeSource`. Its required fields must be set before [`FlockerVolumeSourceBuilder::build`] is called.
+    pub fn builder() -> FlockerVolumeSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl FlockerVolumeSourceBuilder {
+    /// Sets [`FlockerVolumeSource::dataset_name`].
+    pub fn dataset_name(mut self, value: impl Into<String>) -> Self {
+        self.dataset_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`FlockerVolumeSource::dataset_uuid`].
+    pub fn dataset_uuid(mut self, value: impl Into<String>) -> Self {
+        self.dataset_uuid = Some(value.into());
+        self
+    }
+
+    /// Builds the `FlockerVolumeSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<FlockerVolumeSource, crate::MissingFieldError> {
+        Ok(FlockerVolumeSource {
+            dataset_name: self.dataset_name,
+            dataset_uuid: self.dataset_uuid,
+        })
+    }
+}
+
+/// The apply configuration of [`FlockerVolumeSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FlockerVolumeSourceApplyConfiguration {
+    /// See [`FlockerVolumeSource::dataset_name`].
+    pub dataset_name: Option<String>,
+
+    /// See [`FlockerVolumeSource::dataset_uuid`].
+    pub dataset_uuid: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for FlockerVolumeSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "FlockerVolumeSourceApplyConfiguration",
+            self.dataset_name.as_ref().map_or(0, |_| 1) +
+            self.dataset_uuid.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.dataset_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "datasetName", value)?;
+        }
+        if let Some(value) = &self.dataset_uuid {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "datasetUUID", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for FlockerVolumeSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.FlockerVolumeSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::FlockerVolumeSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.FlockerVolumeSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -113,8 +238,9 @@ impl crate::schemars::JsonSchema for FlockerVolumeSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "datasetName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -124,8 +250,8 @@ impl crate::schemars::JsonSchema for FlockerVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "datasetUUID".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -135,11 +261,22 @@ impl crate::schemars::JsonSchema for FlockerVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for FlockerVolumeSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(FlockerVolumeSource {
+            dataset_name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            dataset_uuid: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.KeyToPath
 
 /// Maps a string key to a path within a volume.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct KeyToPath {
     /// The key to project.
     pub key: String,
@@ -13,41 +13,52 @@ pub struct KeyToPath {
     pub path: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for KeyToPath {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_key,
-            Key_mode,
-            Key_path,
-            Other,
+impl KeyToPath {
+    /// Constructs a `KeyToPath` from its required fields. Its optional fields are unset.
+    pub fn new(key: impl Into<String>, path: impl Into<String>) -> Self {
+        KeyToPath {
+            key: key.into(),
+            mode: None,
+            path: path.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl KeyToPath {
+    /// Returns this value with [`KeyToPath::key`] set to the given value.
+    #[must_use]
+    pub fn with_key(mut self, value: impl Into<String>) -> Self {
+        self.key = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`KeyToPath::mode`] set to the given value.
+    #[must_use]
+    pub fn with_mode(mut self, value: impl Into<i32>) -> Self {
+        self.mode = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`KeyToPath::path`] set to the given value.
+    #[must_use]
+    pub fn with_path(mut self, value: impl Into<String>) -> Self {
+        self.path = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "key" => Field::Key_key,
-                            "mode" => Field::Key_mode,
-                            "path" => Field::Key_path,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for KeyToPath {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl KeyToPath {
+    const FIELDS: &'static [&'static str] = &[
+        "key",
+        "mode",
+        "path",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for KeyToPath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,37 +73,101 @@ impl<'de> crate::serde::Deserialize<'de> for KeyToPath {
                 let mut value_mode: Option<i32> = None;
                 let mut value_path: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("KeyToPath", KeyToPath::FIELDS))? {
                     match key {
-                        Field::Key_key => value_key = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_mode => value_mode = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_path => value_path = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_key = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_mode = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_path = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(KeyToPath {
-                    key: value_key.ok_or_else(|| crate::serde::de::Error::missing_field("key"))?,
+                    key: value_key.ok_or_else(|| crate::serde2::missing_field("KeyToPath", "key", &["key", "path"]))?,
                     mode: value_mode,
-                    path: value_path.ok_or_else(|| crate::serde::de::Error::missing_field("path"))?,
+                    path: value_path.ok_or_else(|| crate::serde2::missing_field("KeyToPath", "path", &["key", "path"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(KeyToPath {
+                    key: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    mode: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    path: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "KeyToPath",
-            &[
-                "key",
-                "mode",
-                "path",
-            ],
+            KeyToPath::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, KeyToPath> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("KeyToPath")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_key = false;
+                let mut has_mode = false;
+                let mut has_path = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("KeyToPath", KeyToPath::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.key))?; has_key = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.mode))?; has_mode = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.path))?; has_path = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_key {
+                    return Err(crate::serde2::missing_field("KeyToPath", "key", &["key", "path"]));
+                }
+                if !has_mode {
+                    self.0.mode = None;
+                }
+                if !has_path {
+                    return Err(crate::serde2::missing_field("KeyToPath", "path", &["key", "path"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.key))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.mode))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.path))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "KeyToPath",
+            KeyToPath::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for KeyToPath {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("KeyToPath", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "key", &self.key)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "mode", &self.mode)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "path", &self.path)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "KeyToPath",
             2 +
@@ -107,10 +182,99 @@ impl crate::serde::Serialize for KeyToPath {
     }
 }
 
+/// A builder of [`KeyToPath`]. Created with [`KeyToPath::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct KeyToPathBuilder {
+    key: Option<String>,
+    mode: Option<i32>,
+    path: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl KeyToPath {
+    /// Starts building a `KeyToPath`. Its required fields must be set before [`KeyToPathBuilder::build`] is called.
+    pub fn builder() -> KeyToPathBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl KeyToPathBuilder {
+    /// Sets [`KeyToPath::key`].
+    pub fn key(mut self, value: impl Into<String>) -> Self {
+        self.key = Some(value.into());
+        self
+    }
+
+    /// Sets [`KeyToPath::mode`].
+    pub fn mode(mut self, value: impl Into<i32>) -> Self {
+        self.mode = Some(value.into());
+        self
+    }
+
+    /// Sets [`KeyToPath::path`].
+    pub fn path(mut self, value: impl Into<String>) -> Self {
+        self.path = Some(value.into());
+        self
+    }
+
+    /// Builds the `KeyToPath`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<KeyToPath, crate::MissingFieldError> {
+        Ok(KeyToPath {
+            key: self.key.ok_or_else(|| crate::MissingFieldError::new("KeyToPath", "key"))?,
+            mode: self.mode,
+            path: self.path.ok_or_else(|| crate::MissingFieldError::new("KeyToPath", "path"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`KeyToPath`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KeyToPathApplyConfiguration {
+    /// See [`KeyToPath::key`].
+    pub key: Option<String>,
+
+    /// See [`KeyToPath::mode`].
+    pub mode: Option<i32>,
+
+    /// See [`KeyToPath::path`].
+    pub path: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for KeyToPathApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "KeyToPathApplyConfiguration",
+            self.key.as_ref().map_or(0, |_| 1) +
+            self.mode.as_ref().map_or(0, |_| 1) +
+            self.path.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.key {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "key", value)?;
+        }
+        if let Some(value) = &self.mode {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "mode", value)?;
+        }
+        if let Some(value) = &self.path {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "path", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for KeyToPath {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.KeyToPath".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::KeyToPath".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.KeyToPath".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -121,8 +285,9 @@ impl crate::schemars::JsonSchema for KeyToPath {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "key".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -132,8 +297,8 @@ impl crate::schemars::JsonSchema for KeyToPath {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "mode".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -144,8 +309,8 @@ impl crate::schemars::JsonSchema for KeyToPath {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "path".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -155,8 +320,9 @@ impl crate::schemars::JsonSchema for KeyToPath {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "key",
                     "path",
@@ -167,3 +333,14 @@ impl crate::schemars::JsonSchema for KeyToPath {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for KeyToPath {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(KeyToPath {
+            key: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            mode: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            path: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
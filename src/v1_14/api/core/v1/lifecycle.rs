@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.Lifecycle
 
 /// Lifecycle describes actions that the management system should take in response to container lifecycle events. For the PostStart and PreStop lifecycle handlers, management of the container blocks until the action is complete, unless the container process fails, in which case the handler is aborted.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct Lifecycle {
     /// PostStart is called immediately after a container is created. If the handler fails, the container is terminated and restarted according to its restart policy. Other management of the container blocks until the hook completes. More info: https://kubernetes.io/docs/concepts/containers/container-lifecycle-hooks/#container-hooks
     pub post_start: Option<crate::api::core::v1::Handler>,
@@ -10,39 +10,33 @@ pub struct Lifecycle {
     pub pre_stop: Option<crate::api::core::v1::Handler>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for Lifecycle {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_post_start,
-            Key_pre_stop,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl Lifecycle {
+    /// Returns this value with [`Lifecycle::post_start`] set to the given value.
+    #[must_use]
+    pub fn with_post_start(mut self, value: impl Into<crate::api::core::v1::Handler>) -> Self {
+        self.post_start = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`Lifecycle::pre_stop`] set to the given value.
+    #[must_use]
+    pub fn with_pre_stop(mut self, value: impl Into<crate::api::core::v1::Handler>) -> Self {
+        self.pre_stop = Some(value.into());
+        self
+    }
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for Lifecycle {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "postStart" => Field::Key_post_start,
-                            "preStop" => Field::Key_pre_stop,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl Lifecycle {
+    const FIELDS: &'static [&'static str] = &[
+        "postStart",
+        "preStop",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for Lifecycle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,11 +50,11 @@ impl<'de> crate::serde::Deserialize<'de> for Lifecycle {
                 let mut value_post_start: Option<crate::api::core::v1::Handler> = None;
                 let mut value_pre_stop: Option<crate::api::core::v1::Handler> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Lifecycle", Lifecycle::FIELDS))? {
                     match key {
-                        Field::Key_post_start => value_post_start = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_pre_stop => value_pre_stop = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_post_start = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_pre_stop = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -69,21 +63,78 @@ impl<'de> crate::serde::Deserialize<'de> for Lifecycle {
                     pre_stop: value_pre_stop,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(Lifecycle {
+                    post_start: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    pre_stop: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "Lifecycle",
-            &[
-                "postStart",
-                "preStop",
-            ],
+            Lifecycle::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, Lifecycle> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("Lifecycle")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_post_start = false;
+                let mut has_pre_stop = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Lifecycle", Lifecycle::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.post_start))?; has_post_start = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.pre_stop))?; has_pre_stop = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_post_start {
+                    self.0.post_start = None;
+                }
+                if !has_pre_stop {
+                    self.0.pre_stop = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.post_start))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.pre_stop))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "Lifecycle",
+            Lifecycle::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for Lifecycle {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("Lifecycle", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "postStart", &self.post_start)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "preStop", &self.pre_stop)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "Lifecycle",
             self.post_start.as_ref().map_or(0, |_| 1) +
@@ -99,10 +150,84 @@ impl crate::serde::Serialize for Lifecycle {
     }
 }
 
+/// A builder of [`Lifecycle`]. Created with [`Lifecycle::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct LifecycleBuilder {
+    post_start: Option<crate::api::core::v1::Handler>,
+    pre_stop: Option<crate::api::core::v1::Handler>,
+}
+
+#[cfg(feature = "builder")]
+impl Lifecycle {
+    /// Starts building a `Lifecycle`. Its required fields must be set before [`LifecycleBuilder::build`] is called.
+    pub fn builder() -> LifecycleBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl LifecycleBuilder {
+    /// Sets [`Lifecycle::post_start`].
+    pub fn post_start(mut self, value: impl Into<crate::api::core::v1::Handler>) -> Self {
+        self.post_start = Some(value.into());
+        self
+    }
+
+    /// Sets [`Lifecycle::pre_stop`].
+    pub fn pre_stop(mut self, value: impl Into<crate::api::core::v1::Handler>) -> Self {
+        self.pre_stop = Some(value.into());
+        self
+    }
+
+    /// Builds the `Lifecycle`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<Lifecycle, crate::MissingFieldError> {
+        Ok(Lifecycle {
+            post_start: self.post_start,
+            pre_stop: self.pre_stop,
+        })
+    }
+}
+
+/// The apply configuration of [`Lifecycle`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LifecycleApplyConfiguration {
+    /// See [`Lifecycle::post_start`].
+    pub post_start: Option<crate::api::core::v1::HandlerApplyConfiguration>,
+
+    /// See [`Lifecycle::pre_stop`].
+    pub pre_stop: Option<crate::api::core::v1::HandlerApplyConfiguration>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for LifecycleApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "LifecycleApplyConfiguration",
+            self.post_start.as_ref().map_or(0, |_| 1) +
+            self.pre_stop.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.post_start {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "postStart", value)?;
+        }
+        if let Some(value) = &self.pre_stop {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "preStop", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Lifecycle {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.Lifecycle".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::Lifecycle".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.Lifecycle".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -113,8 +238,9 @@ impl crate::schemars::JsonSchema for Lifecycle {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "postStart".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::Handler>().into_object();
@@ -124,8 +250,8 @@ impl crate::schemars::JsonSchema for Lifecycle {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "preStop".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::Handler>().into_object();
@@ -135,11 +261,22 @@ impl crate::schemars::JsonSchema for Lifecycle {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for Lifecycle {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(Lifecycle {
+            post_start: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            pre_stop: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.PhotonPersistentDiskVolumeSource
 
 /// Represents a Photon Controller persistent disk resource.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct PhotonPersistentDiskVolumeSource {
     /// Filesystem type to mount. Must be a filesystem type supported by the host operating system. Ex. "ext4", "xfs", "ntfs". Implicitly inferred to be "ext4" if unspecified.
     pub fs_type: Option<String>,
@@ -10,39 +10,43 @@ pub struct PhotonPersistentDiskVolumeSource {
     pub pd_id: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for PhotonPersistentDiskVolumeSource {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_fs_type,
-            Key_pd_id,
-            Other,
+impl PhotonPersistentDiskVolumeSource {
+    /// Constructs a `PhotonPersistentDiskVolumeSource` from its required fields. Its optional fields are unset.
+    pub fn new(pd_id: impl Into<String>) -> Self {
+        PhotonPersistentDiskVolumeSource {
+            fs_type: None,
+            pd_id: pd_id.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl PhotonPersistentDiskVolumeSource {
+    /// Returns this value with [`PhotonPersistentDiskVolumeSource::fs_type`] set to the given value.
+    #[must_use]
+    pub fn with_fs_type(mut self, value: impl Into<String>) -> Self {
+        self.fs_type = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`PhotonPersistentDiskVolumeSource::pd_id`] set to the given value.
+    #[must_use]
+    pub fn with_pd_id(mut self, value: impl Into<String>) -> Self {
+        self.pd_id = value.into();
+        self
+    }
+}
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+impl crate::MergeStrategyInfo for PhotonPersistentDiskVolumeSource {}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "fsType" => Field::Key_fs_type,
-                            "pdID" => Field::Key_pd_id,
-                            _ => Field::Other,
-                        })
-                    }
-                }
-
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl PhotonPersistentDiskVolumeSource {
+    const FIELDS: &'static [&'static str] = &[
+        "fsType",
+        "pdID",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for PhotonPersistentDiskVolumeSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +60,91 @@ impl<'de> crate::serde::Deserialize<'de> for PhotonPersistentDiskVolumeSource {
                 let mut value_fs_type: Option<String> = None;
                 let mut value_pd_id: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PhotonPersistentDiskVolumeSource", PhotonPersistentDiskVolumeSource::FIELDS))? {
                     match key {
-                        Field::Key_fs_type => value_fs_type = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_pd_id => value_pd_id = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_fs_type = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_pd_id = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(PhotonPersistentDiskVolumeSource {
                     fs_type: value_fs_type,
-                    pd_id: value_pd_id.ok_or_else(|| crate::serde::de::Error::missing_field("pdID"))?,
+                    pd_id: value_pd_id.ok_or_else(|| crate::serde2::missing_field("PhotonPersistentDiskVolumeSource", "pdID", &["pdID"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(PhotonPersistentDiskVolumeSource {
+                    fs_type: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    pd_id: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "PhotonPersistentDiskVolumeSource",
-            &[
-                "fsType",
-                "pdID",
-            ],
+            PhotonPersistentDiskVolumeSource::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, PhotonPersistentDiskVolumeSource> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("PhotonPersistentDiskVolumeSource")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_fs_type = false;
+                let mut has_pd_id = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PhotonPersistentDiskVolumeSource", PhotonPersistentDiskVolumeSource::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.fs_type))?; has_fs_type = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.pd_id))?; has_pd_id = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_fs_type {
+                    self.0.fs_type = None;
+                }
+                if !has_pd_id {
+                    return Err(crate::serde2::missing_field("PhotonPersistentDiskVolumeSource", "pdID", &["pdID"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.fs_type))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.pd_id))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "PhotonPersistentDiskVolumeSource",
+            PhotonPersistentDiskVolumeSource::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for PhotonPersistentDiskVolumeSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("PhotonPersistentDiskVolumeSource", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsType", &self.fs_type)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "pdID", &self.pd_id)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "PhotonPersistentDiskVolumeSource",
             1 +
@@ -97,10 +158,84 @@ impl crate::serde::Serialize for PhotonPersistentDiskVolumeSource {
     }
 }
 
+/// A builder of [`PhotonPersistentDiskVolumeSource`]. Created with [`PhotonPersistentDiskVolumeSource::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct PhotonPersistentDiskVolumeSourceBuilder {
+    fs_type: Option<String>,
+    pd_id: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl PhotonPersistentDiskVolumeSource {
+    /// Starts building a `PhotonPersistentDiskVolumeSource`. Its required fields must be set before [`PhotonPersistentDiskVolumeSourceBuilder::build`] is called.
+    pub fn builder() -> PhotonPersistentDiskVolumeSourceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl PhotonPersistentDiskVolumeSourceBuilder {
+    /// Sets [`PhotonPersistentDiskVolumeSource::fs_type`].
+    pub fn fs_type(mut self, value: impl Into<String>) -> Self {
+        self.fs_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`PhotonPersistentDiskVolumeSource::pd_id`].
+    pub fn pd_id(mut self, value: impl Into<String>) -> Self {
+        self.pd_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `PhotonPersistentDiskVolumeSource`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<PhotonPersistentDiskVolumeSource, crate::MissingFieldError> {
+        Ok(PhotonPersistentDiskVolumeSource {
+            fs_type: self.fs_type,
+            pd_id: self.pd_id.ok_or_else(|| crate::MissingFieldError::new("PhotonPersistentDiskVolumeSource", "pdID"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`PhotonPersistentDiskVolumeSource`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PhotonPersistentDiskVolumeSourceApplyConfiguration {
+    /// See [`PhotonPersistentDiskVolumeSource::fs_type`].
+    pub fs_type: Option<String>,
+
+    /// See [`PhotonPersistentDiskVolumeSource::pd_id`].
+    pub pd_id: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for PhotonPersistentDiskVolumeSourceApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "PhotonPersistentDiskVolumeSourceApplyConfiguration",
+            self.fs_type.as_ref().map_or(0, |_| 1) +
+            self.pd_id.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.fs_type {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsType", value)?;
+        }
+        if let Some(value) = &self.pd_id {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "pdID", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PhotonPersistentDiskVolumeSource {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.PhotonPersistentDiskVolumeSource".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::PhotonPersistentDiskVolumeSource".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.PhotonPersistentDiskVolumeSource".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -111,8 +246,9 @@ impl crate::schemars::JsonSchema for PhotonPersistentDiskVolumeSource {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "fsType".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -122,8 +258,8 @@ impl crate::schemars::JsonSchema for PhotonPersistentDiskVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "pdID".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -133,8 +269,9 @@ impl crate::schemars::JsonSchema for PhotonPersistentDiskVolumeSource {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "pdID",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -144,3 +281,13 @@ impl crate::schemars::JsonSchema for PhotonPersistentDiskVolumeSource {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for PhotonPersistentDiskVolumeSource {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(PhotonPersistentDiskVolumeSource {
+            fs_type: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            pd_id: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
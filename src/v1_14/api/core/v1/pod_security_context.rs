@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.PodSecurityContext
 
 /// PodSecurityContext holds pod-level security attributes and common container settings. Some fields are also present in container.securityContext.  Field values of container.securityContext take precedence over field values of PodSecurityContext.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct PodSecurityContext {
     /// A special supplemental group that applies to all containers in a pod. Some volume types allow the Kubelet to change the ownership of that volume to be owned by the pod:
     ///
@@ -29,49 +29,73 @@ pub struct PodSecurityContext {
     pub sysctls: Option<Vec<crate::api::core::v1::Sysctl>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for PodSecurityContext {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_fs_group,
-            Key_run_as_group,
-            Key_run_as_non_root,
-            Key_run_as_user,
-            Key_se_linux_options,
-            Key_supplemental_groups,
-            Key_sysctls,
-            Other,
-        }
+impl PodSecurityContext {
+    /// Returns this value with [`PodSecurityContext::fs_group`] set to the given value.
+    #[must_use]
+    pub fn with_fs_group(mut self, value: impl Into<i64>) -> Self {
+        self.fs_group = Some(value.into());
+        self
+    }
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+    /// Returns this value with [`PodSecurityContext::run_as_group`] set to the given value.
+    #[must_use]
+    pub fn with_run_as_group(mut self, value: impl Into<i64>) -> Self {
+        self.run_as_group = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`PodSecurityContext::run_as_non_root`] set to the given value.
+    #[must_use]
+    pub fn with_run_as_non_root(mut self, value: impl Into<bool>) -> Self {
+        self.run_as_non_root = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`PodSecurityContext::run_as_user`] set to the given value.
+    #[must_use]
+    pub fn with_run_as_user(mut self, value: impl Into<i64>) -> Self {
+        self.run_as_user = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "fsGroup" => Field::Key_fs_group,
-                            "runAsGroup" => Field::Key_run_as_group,
-                            "runAsNonRoot" => Field::Key_run_as_non_root,
-                            "runAsUser" => Field::Key_run_as_user,
-                            "seLinuxOptions" => Field::Key_se_linux_options,
-                            "supplementalGroups" => Field::Key_supplemental_groups,
-                            "sysctls" => Field::Key_sysctls,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`PodSecurityContext::se_linux_options`] set to the given value.
+    #[must_use]
+    pub fn with_se_linux_options(mut self, value: impl Into<crate::api::core::v1::SELinuxOptions>) -> Self {
+        self.se_linux_options = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`PodSecurityContext::supplemental_groups`] set to the given value.
+    #[must_use]
+    pub fn with_supplemental_groups(mut self, value: impl Into<Vec<i64>>) -> Self {
+        self.supplemental_groups = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodSecurityContext::sysctls`] set to the given value.
+    #[must_use]
+    pub fn with_sysctls(mut self, value: impl Into<Vec<crate::api::core::v1::Sysctl>>) -> Self {
+        self.sysctls = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for PodSecurityContext {}
 
+impl PodSecurityContext {
+    const FIELDS: &'static [&'static str] = &[
+        "fsGroup",
+        "runAsGroup",
+        "runAsNonRoot",
+        "runAsUser",
+        "seLinuxOptions",
+        "supplementalGroups",
+        "sysctls",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for PodSecurityContext {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -90,16 +114,16 @@ impl<'de> crate::serde::Deserialize<'de> for PodSecurityContext {
                 let mut value_supplemental_groups: Option<Vec<i64>> = None;
                 let mut value_sysctls: Option<Vec<crate::api::core::v1::Sysctl>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodSecurityContext", PodSecurityContext::FIELDS))? {
                     match key {
-                        Field::Key_fs_group => value_fs_group = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_run_as_group => value_run_as_group = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_run_as_non_root => value_run_as_non_root = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_run_as_user => value_run_as_user = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_se_linux_options => value_se_linux_options = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_supplemental_groups => value_supplemental_groups = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_sysctls => value_sysctls = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_fs_group = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_run_as_group = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_run_as_non_root = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_run_as_user = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_se_linux_options = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_supplemental_groups = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_sysctls = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -113,26 +137,118 @@ impl<'de> crate::serde::Deserialize<'de> for PodSecurityContext {
                     sysctls: value_sysctls,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(PodSecurityContext {
+                    fs_group: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    run_as_group: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    run_as_non_root: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    run_as_user: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    se_linux_options: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    supplemental_groups: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    sysctls: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "PodSecurityContext",
-            &[
-                "fsGroup",
-                "runAsGroup",
-                "runAsNonRoot",
-                "runAsUser",
-                "seLinuxOptions",
-                "supplementalGroups",
-                "sysctls",
-            ],
+            PodSecurityContext::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, PodSecurityContext> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("PodSecurityContext")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_fs_group = false;
+                let mut has_run_as_group = false;
+                let mut has_run_as_non_root = false;
+                let mut has_run_as_user = false;
+                let mut has_se_linux_options = false;
+                let mut has_supplemental_groups = false;
+                let mut has_sysctls = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodSecurityContext", PodSecurityContext::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.fs_group))?; has_fs_group = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.run_as_group))?; has_run_as_group = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.run_as_non_root))?; has_run_as_non_root = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.run_as_user))?; has_run_as_user = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.se_linux_options))?; has_se_linux_options = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.supplemental_groups))?; has_supplemental_groups = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.sysctls))?; has_sysctls = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_fs_group {
+                    self.0.fs_group = None;
+                }
+                if !has_run_as_group {
+                    self.0.run_as_group = None;
+                }
+                if !has_run_as_non_root {
+                    self.0.run_as_non_root = None;
+                }
+                if !has_run_as_user {
+                    self.0.run_as_user = None;
+                }
+                if !has_se_linux_options {
+                    self.0.se_linux_options = None;
+                }
+                if !has_supplemental_groups {
+                    self.0.supplemental_groups = None;
+                }
+                if !has_sysctls {
+                    self.0.sysctls = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.fs_group))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.run_as_group))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.run_as_non_root))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.run_as_user))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.se_linux_options))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.supplemental_groups))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.sysctls))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "PodSecurityContext",
+            PodSecurityContext::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for PodSecurityContext {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("PodSecurityContext", 7)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsGroup", &self.fs_group)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "runAsGroup", &self.run_as_group)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "runAsNonRoot", &self.run_as_non_root)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "runAsUser", &self.run_as_user)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "seLinuxOptions", &self.se_linux_options)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "supplementalGroups", &self.supplemental_groups)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "sysctls", &self.sysctls)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "PodSecurityContext",
             self.fs_group.as_ref().map_or(0, |_| 1) +
@@ -168,10 +284,159 @@ impl crate::serde::Serialize for PodSecurityContext {
     }
 }
 
+/// A builder of [`PodSecurityContext`]. Created with [`PodSecurityContext::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct PodSecurityContextBuilder {
+    fs_group: Option<i64>,
+    run_as_group: Option<i64>,
+    run_as_non_root: Option<bool>,
+    run_as_user: Option<i64>,
+    se_linux_options: Option<crate::api::core::v1::SELinuxOptions>,
+    supplemental_groups: Option<Vec<i64>>,
+    sysctls: Option<Vec<crate::api::core::v1::Sysctl>>,
+}
+
+#[cfg(feature = "builder")]
+impl PodSecurityContext {
+    /// Starts building a `PodSecurityContext`. Its required fields must be set before [`PodSecurityContextBuilder::build`] is called.
+    pub fn builder() -> PodSecurityContextBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl PodSecurityContextBuilder {
+    /// Sets [`PodSecurityContext::fs_group`].
+    pub fn fs_group(mut self, value: impl Into<i64>) -> Self {
+        self.fs_group = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSecurityContext::run_as_group`].
+    pub fn run_as_group(mut self, value: impl Into<i64>) -> Self {
+        self.run_as_group = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSecurityContext::run_as_non_root`].
+    pub fn run_as_non_root(mut self, value: impl Into<bool>) -> Self {
+        self.run_as_non_root = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSecurityContext::run_as_user`].
+    pub fn run_as_user(mut self, value: impl Into<i64>) -> Self {
+        self.run_as_user = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSecurityContext::se_linux_options`].
+    pub fn se_linux_options(mut self, value: impl Into<crate::api::core::v1::SELinuxOptions>) -> Self {
+        self.se_linux_options = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSecurityContext::supplemental_groups`].
+    pub fn supplemental_groups(mut self, value: impl Into<Vec<i64>>) -> Self {
+        self.supplemental_groups = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodSecurityContext::sysctls`].
+    pub fn sysctls(mut self, value: impl Into<Vec<crate::api::core::v1::Sysctl>>) -> Self {
+        self.sysctls = Some(value.into());
+        self
+    }
+
+    /// Builds the `PodSecurityContext`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<PodSecurityContext, crate::MissingFieldError> {
+        Ok(PodSecurityContext {
+            fs_group: self.fs_group,
+            run_as_group: self.run_as_group,
+            run_as_non_root: self.run_as_non_root,
+            run_as_user: self.run_as_user,
+            se_linux_options: self.se_linux_options,
+            supplemental_groups: self.supplemental_groups,
+            sysctls: self.sysctls,
+        })
+    }
+}
+
+/// The apply configuration of [`PodSecurityContext`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodSecurityContextApplyConfiguration {
+    /// See [`PodSecurityContext::fs_group`].
+    pub fs_group: Option<i64>,
+
+    /// See [`PodSecurityContext::run_as_group`].
+    pub run_as_group: Option<i64>,
+
+    /// See [`PodSecurityContext::run_as_non_root`].
+    pub run_as_non_root: Option<bool>,
+
+    /// See [`PodSecurityContext::run_as_user`].
+    pub run_as_user: Option<i64>,
+
+    /// See [`PodSecurityContext::se_linux_options`].
+    pub se_linux_options: Option<crate::api::core::v1::SELinuxOptionsApplyConfiguration>,
+
+    /// See [`PodSecurityContext::supplemental_groups`].
+    pub supplemental_groups: Option<Vec<i64>>,
+
+    /// See [`PodSecurityContext::sysctls`].
+    pub sysctls: Option<Vec<crate::api::core::v1::SysctlApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for PodSecurityContextApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "PodSecurityContextApplyConfiguration",
+            self.fs_group.as_ref().map_or(0, |_| 1) +
+            self.run_as_group.as_ref().map_or(0, |_| 1) +
+            self.run_as_non_root.as_ref().map_or(0, |_| 1) +
+            self.run_as_user.as_ref().map_or(0, |_| 1) +
+            self.se_linux_options.as_ref().map_or(0, |_| 1) +
+            self.supplemental_groups.as_ref().map_or(0, |_| 1) +
+            self.sysctls.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.fs_group {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "fsGroup", value)?;
+        }
+        if let Some(value) = &self.run_as_group {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "runAsGroup", value)?;
+        }
+        if let Some(value) = &self.run_as_non_root {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "runAsNonRoot", value)?;
+        }
+        if let Some(value) = &self.run_as_user {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "runAsUser", value)?;
+        }
+        if let Some(value) = &self.se_linux_options {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "seLinuxOptions", value)?;
+        }
+        if let Some(value) = &self.supplemental_groups {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "supplementalGroups", value)?;
+        }
+        if let Some(value) = &self.sysctls {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "sysctls", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodSecurityContext {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.PodSecurityContext".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::PodSecurityContext".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.PodSecurityContext".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -182,8 +447,9 @@ impl crate::schemars::JsonSchema for PodSecurityContext {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "fsGroup".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -194,8 +460,8 @@ impl crate::schemars::JsonSchema for PodSecurityContext {
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "runAsGroup".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -206,8 +472,8 @@ impl crate::schemars::JsonSchema for PodSecurityContext {
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "runAsNonRoot".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -217,8 +483,8 @@ impl crate::schemars::JsonSchema for PodSecurityContext {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Boolean))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "runAsUser".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -229,8 +495,8 @@ impl crate::schemars::JsonSchema for PodSecurityContext {
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "seLinuxOptions".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::SELinuxOptions>().into_object();
@@ -240,8 +506,8 @@ impl crate::schemars::JsonSchema for PodSecurityContext {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "supplementalGroups".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -261,8 +527,8 @@ impl crate::schemars::JsonSchema for PodSecurityContext {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "sysctls".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -276,11 +542,27 @@ impl crate::schemars::JsonSchema for PodSecurityContext {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for PodSecurityContext {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(PodSecurityContext {
+            fs_group: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            run_as_group: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            run_as_non_root: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            run_as_user: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            se_linux_options: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            supplemental_groups: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            sysctls: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
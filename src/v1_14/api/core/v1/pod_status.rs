@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.PodStatus
 
 /// PodStatus represents information about the status of a pod. Status may trail the actual state of a system, especially if the node that hosts the pod cannot contact the control plane.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct PodStatus {
     /// Current service state of pod. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#pod-conditions
     pub conditions: Option<Vec<crate::api::core::v1::PodCondition>>,
@@ -41,57 +41,109 @@ pub struct PodStatus {
     pub start_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for PodStatus {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_conditions,
-            Key_container_statuses,
-            Key_host_ip,
-            Key_init_container_statuses,
-            Key_message,
-            Key_nominated_node_name,
-            Key_phase,
-            Key_pod_ip,
-            Key_qos_class,
-            Key_reason,
-            Key_start_time,
-            Other,
-        }
+impl PodStatus {
+    /// Returns this value with [`PodStatus::conditions`] set to the given value.
+    #[must_use]
+    pub fn with_conditions(mut self, value: impl Into<Vec<crate::api::core::v1::PodCondition>>) -> Self {
+        self.conditions = Some(value.into());
+        self
+    }
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+    /// Returns this value with [`PodStatus::container_statuses`] set to the given value.
+    #[must_use]
+    pub fn with_container_statuses(mut self, value: impl Into<Vec<crate::api::core::v1::ContainerStatus>>) -> Self {
+        self.container_statuses = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`PodStatus::host_ip`] set to the given value.
+    #[must_use]
+    pub fn with_host_ip(mut self, value: impl Into<String>) -> Self {
+        self.host_ip = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`PodStatus::init_container_statuses`] set to the given value.
+    #[must_use]
+    pub fn with_init_container_statuses(mut self, value: impl Into<Vec<crate::api::core::v1::ContainerStatus>>) -> Self {
+        self.init_container_statuses = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "conditions" => Field::Key_conditions,
-                            "containerStatuses" => Field::Key_container_statuses,
-                            "hostIP" => Field::Key_host_ip,
-                            "initContainerStatuses" => Field::Key_init_container_statuses,
-                            "message" => Field::Key_message,
-                            "nominatedNodeName" => Field::Key_nominated_node_name,
-                            "phase" => Field::Key_phase,
-                            "podIP" => Field::Key_pod_ip,
-                            "qosClass" => Field::Key_qos_class,
-                            "reason" => Field::Key_reason,
-                            "startTime" => Field::Key_start_time,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`PodStatus::message`] set to the given value.
+    #[must_use]
+    pub fn with_message(mut self, value: impl Into<String>) -> Self {
+        self.message = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`PodStatus::nominated_node_name`] set to the given value.
+    #[must_use]
+    pub fn with_nominated_node_name(mut self, value: impl Into<String>) -> Self {
+        self.nominated_node_name = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodStatus::phase`] set to the given value.
+    #[must_use]
+    pub fn with_phase(mut self, value: impl Into<String>) -> Self {
+        self.phase = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodStatus::pod_ip`] set to the given value.
+    #[must_use]
+    pub fn with_pod_ip(mut self, value: impl Into<String>) -> Self {
+        self.pod_ip = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodStatus::qos_class`] set to the given value.
+    #[must_use]
+    pub fn with_qos_class(mut self, value: impl Into<String>) -> Self {
+        self.qos_class = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`PodStatus::reason`] set to the given value.
+    #[must_use]
+    pub fn with_reason(mut self, value: impl Into<String>) -> Self {
+        self.reason = Some(value.into());
+        self
+    }
 
+    /// Returns this value with [`PodStatus::start_time`] set to the given value.
+    #[must_use]
+    pub fn with_start_time(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for PodStatus {
+    const FIELD_PATCH_STRATEGIES: &'static [crate::FieldPatchStrategy] = &[
+        crate::FieldPatchStrategy { field: "conditions", merge: true, retain_keys: false, merge_key: Some("type") },
+    ];
+}
+
+impl PodStatus {
+    const FIELDS: &'static [&'static str] = &[
+        "conditions",
+        "containerStatuses",
+        "hostIP",
+        "initContainerStatuses",
+        "message",
+        "nominatedNodeName",
+        "phase",
+        "podIP",
+        "qosClass",
+        "reason",
+        "startTime",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for PodStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -114,20 +166,20 @@ impl<'de> crate::serde::Deserialize<'de> for PodStatus {
                 let mut value_reason: Option<String> = None;
                 let mut value_start_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodStatus", PodStatus::FIELDS))? {
                     match key {
-                        Field::Key_conditions => value_conditions = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_container_statuses => value_container_statuses = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_host_ip => value_host_ip = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_init_container_statuses => value_init_container_statuses = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_message => value_message = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_nominated_node_name => value_nominated_node_name = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_phase => value_phase = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_pod_ip => value_pod_ip = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_qos_class => value_qos_class = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_reason => value_reason = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_start_time => value_start_time = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_conditions = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_container_statuses = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_host_ip = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_init_container_statuses = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_message = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_nominated_node_name = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_phase = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(7) => value_pod_ip = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(8) => value_qos_class = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(9) => value_reason = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(10) => value_start_time = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -145,30 +197,150 @@ impl<'de> crate::serde::Deserialize<'de> for PodStatus {
                     start_time: value_start_time,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(PodStatus {
+                    conditions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    container_statuses: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    host_ip: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    init_container_statuses: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    message: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    nominated_node_name: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    phase: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    pod_ip: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                    qos_class: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?,
+                    reason: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?,
+                    start_time: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "PodStatus",
-            &[
-                "conditions",
-                "containerStatuses",
-                "hostIP",
-                "initContainerStatuses",
-                "message",
-                "nominatedNodeName",
-                "phase",
-                "podIP",
-                "qosClass",
-                "reason",
-                "startTime",
-            ],
+            PodStatus::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, PodStatus> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("PodStatus")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_conditions = false;
+                let mut has_container_statuses = false;
+                let mut has_host_ip = false;
+                let mut has_init_container_statuses = false;
+                let mut has_message = false;
+                let mut has_nominated_node_name = false;
+                let mut has_phase = false;
+                let mut has_pod_ip = false;
+                let mut has_qos_class = false;
+                let mut has_reason = false;
+                let mut has_start_time = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("PodStatus", PodStatus::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.conditions))?; has_conditions = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.container_statuses))?; has_container_statuses = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.host_ip))?; has_host_ip = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.init_container_statuses))?; has_init_container_statuses = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.message))?; has_message = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.nominated_node_name))?; has_nominated_node_name = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.phase))?; has_phase = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.pod_ip))?; has_pod_ip = true; },
+                        Some(8) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.qos_class))?; has_qos_class = true; },
+                        Some(9) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.reason))?; has_reason = true; },
+                        Some(10) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.start_time))?; has_start_time = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_conditions {
+                    self.0.conditions = None;
+                }
+                if !has_container_statuses {
+                    self.0.container_statuses = None;
+                }
+                if !has_host_ip {
+                    self.0.host_ip = None;
+                }
+                if !has_init_container_statuses {
+                    self.0.init_container_statuses = None;
+                }
+                if !has_message {
+                    self.0.message = None;
+                }
+                if !has_nominated_node_name {
+                    self.0.nominated_node_name = None;
+                }
+                if !has_phase {
+                    self.0.phase = None;
+                }
+                if !has_pod_ip {
+                    self.0.pod_ip = None;
+                }
+                if !has_qos_class {
+                    self.0.qos_class = None;
+                }
+                if !has_reason {
+                    self.0.reason = None;
+                }
+                if !has_start_time {
+                    self.0.start_time = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.conditions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.container_statuses))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.host_ip))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.init_container_statuses))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.message))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.nominated_node_name))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.phase))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.pod_ip))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.qos_class))?.ok_or_else(|| crate::serde::de::Error::invalid_length(8, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.reason))?.ok_or_else(|| crate::serde::de::Error::invalid_length(9, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.start_time))?.ok_or_else(|| crate::serde::de::Error::invalid_length(10, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "PodStatus",
+            PodStatus::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for PodStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("PodStatus", 11)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conditions", &self.conditions)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "containerStatuses", &self.container_statuses)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostIP", &self.host_ip)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "initContainerStatuses", &self.init_container_statuses)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "message", &self.message)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nominatedNodeName", &self.nominated_node_name)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "phase", &self.phase)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "podIP", &self.pod_ip)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "qosClass", &self.qos_class)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reason", &self.reason)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "startTime", &self.start_time)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "PodStatus",
             self.conditions.as_ref().map_or(0, |_| 1) +
@@ -220,10 +392,219 @@ impl crate::serde::Serialize for PodStatus {
     }
 }
 
+/// A builder of [`PodStatus`]. Created with [`PodStatus::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct PodStatusBuilder {
+    conditions: Option<Vec<crate::api::core::v1::PodCondition>>,
+    container_statuses: Option<Vec<crate::api::core::v1::ContainerStatus>>,
+    host_ip: Option<String>,
+    init_container_statuses: Option<Vec<crate::api::core::v1::ContainerStatus>>,
+    message: Option<String>,
+    nominated_node_name: Option<String>,
+    phase: Option<String>,
+    pod_ip: Option<String>,
+    qos_class: Option<String>,
+    reason: Option<String>,
+    start_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+}
+
+#[cfg(feature = "builder")]
+impl PodStatus {
+    /// Starts building a `PodStatus`. Its required fields must be set before [`PodStatusBuilder::build`] is called.
+    pub fn builder() -> PodStatusBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl PodStatusBuilder {
+    /// Sets [`PodStatus::conditions`].
+    pub fn conditions(mut self, value: impl Into<Vec<crate::api::core::v1::PodCondition>>) -> Self {
+        self.conditions = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodStatus::container_statuses`].
+    pub fn container_statuses(mut self, value: impl Into<Vec<crate::api::core::v1::ContainerStatus>>) -> Self {
+        self.container_statuses = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodStatus::host_ip`].
+    pub fn host_ip(mut self, value: impl Into<String>) -> Self {
+        self.host_ip = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodStatus::init_container_statuses`].
+    pub fn init_container_statuses(mut self, value: impl Into<Vec<crate::api::core::v1::ContainerStatus>>) -> Self {
+        self.init_container_statuses = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodStatus::message`].
+    pub fn message(mut self, value: impl Into<String>) -> Self {
+        self.message = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodStatus::nominated_node_name`].
+    pub fn nominated_node_name(mut self, value: impl Into<String>) -> Self {
+        self.nominated_node_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodStatus::phase`].
+    pub fn phase(mut self, value: impl Into<String>) -> Self {
+        self.phase = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodStatus::pod_ip`].
+    pub fn pod_ip(mut self, value: impl Into<String>) -> Self {
+        self.pod_ip = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodStatus::qos_class`].
+    pub fn qos_class(mut self, value: impl Into<String>) -> Self {
+        self.qos_class = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodStatus::reason`].
+    pub fn reason(mut self, value: impl Into<String>) -> Self {
+        self.reason = Some(value.into());
+        self
+    }
+
+    /// Sets [`PodStatus::start_time`].
+    pub fn start_time(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `PodStatus`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<PodStatus, crate::MissingFieldError> {
+        Ok(PodStatus {
+            conditions: self.conditions,
+            container_statuses: self.container_statuses,
+            host_ip: self.host_ip,
+            init_container_statuses: self.init_container_statuses,
+            message: self.message,
+            nominated_node_name: self.nominated_node_name,
+            phase: self.phase,
+            pod_ip: self.pod_ip,
+            qos_class: self.qos_class,
+            reason: self.reason,
+            start_time: self.start_time,
+        })
+    }
+}
+
+/// The apply configuration of [`PodStatus`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodStatusApplyConfiguration {
+    /// See [`PodStatus::conditions`].
+    pub conditions: Option<Vec<crate::api::core::v1::PodConditionApplyConfiguration>>,
+
+    /// See [`PodStatus::container_statuses`].
+    pub container_statuses: Option<Vec<crate::api::core::v1::ContainerStatusApplyConfiguration>>,
+
+    /// See [`PodStatus::host_ip`].
+    pub host_ip: Option<String>,
+
+    /// See [`PodStatus::init_container_statuses`].
+    pub init_container_statuses: Option<Vec<crate::api::core::v1::ContainerStatusApplyConfiguration>>,
+
+    /// See [`PodStatus::message`].
+    pub message: Option<String>,
+
+    /// See [`PodStatus::nominated_node_name`].
+    pub nominated_node_name: Option<String>,
+
+    /// See [`PodStatus::phase`].
+    pub phase: Option<String>,
+
+    /// See [`PodStatus::pod_ip`].
+    pub pod_ip: Option<String>,
+
+    /// See [`PodStatus::qos_class`].
+    pub qos_class: Option<String>,
+
+    /// See [`PodStatus::reason`].
+    pub reason: Option<String>,
+
+    /// See [`PodStatus::start_time`].
+    pub start_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for PodStatusApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "PodStatusApplyConfiguration",
+            self.conditions.as_ref().map_or(0, |_| 1) +
+            self.container_statuses.as_ref().map_or(0, |_| 1) +
+            self.host_ip.as_ref().map_or(0, |_| 1) +
+            self.init_container_statuses.as_ref().map_or(0, |_| 1) +
+            self.message.as_ref().map_or(0, |_| 1) +
+            self.nominated_node_name.as_ref().map_or(0, |_| 1) +
+            self.phase.as_ref().map_or(0, |_| 1) +
+            self.pod_ip.as_ref().map_or(0, |_| 1) +
+            self.qos_class.as_ref().map_or(0, |_| 1) +
+            self.reason.as_ref().map_or(0, |_| 1) +
+            self.start_time.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.conditions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "conditions", value)?;
+        }
+        if let Some(value) = &self.container_statuses {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "containerStatuses", value)?;
+        }
+        if let Some(value) = &self.host_ip {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hostIP", value)?;
+        }
+        if let Some(value) = &self.init_container_statuses {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "initContainerStatuses", value)?;
+        }
+        if let Some(value) = &self.message {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "message", value)?;
+        }
+        if let Some(value) = &self.nominated_node_name {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "nominatedNodeName", value)?;
+        }
+        if let Some(value) = &self.phase {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "phase", value)?;
+        }
+        if let Some(value) = &self.pod_ip {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "podIP", value)?;
+        }
+        if let Some(value) = &self.qos_class {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "qosClass", value)?;
+        }
+        if let Some(value) = &self.reason {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "reason", value)?;
+        }
+        if let Some(value) = &self.start_time {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "startTime", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodStatus {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.PodStatus".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::PodStatus".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.PodStatus".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -234,8 +615,9 @@ impl crate::schemars::JsonSchema for PodStatus {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "conditions".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -249,8 +631,8 @@ impl crate::schemars::JsonSchema for PodStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "containerStatuses".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -264,8 +646,8 @@ impl crate::schemars::JsonSchema for PodStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "hostIP".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -275,8 +657,8 @@ impl crate::schemars::JsonSchema for PodStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "initContainerStatuses".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -290,8 +672,8 @@ impl crate::schemars::JsonSchema for PodStatus {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "message".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -301,8 +683,8 @@ impl crate::schemars::JsonSchema for PodStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "nominatedNodeName".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -312,8 +694,8 @@ impl crate::schemars::JsonSchema for PodStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "phase".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -323,8 +705,8 @@ impl crate::schemars::JsonSchema for PodStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "podIP".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -334,8 +716,8 @@ impl crate::schemars::JsonSchema for PodStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "qosClass".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -345,8 +727,8 @@ impl crate::schemars::JsonSchema for PodStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "reason".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -356,8 +738,8 @@ impl crate::schemars::JsonSchema for PodStatus {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "startTime".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::Time>().into_object();
@@ -367,11 +749,31 @@ impl crate::schemars::JsonSchema for PodStatus {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for PodStatus {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(PodStatus {
+            conditions: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            container_statuses: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            host_ip: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            init_container_statuses: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            message: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            nominated_node_name: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            phase: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            pod_ip: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            qos_class: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            reason: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            start_time: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
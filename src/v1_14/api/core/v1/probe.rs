@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.Probe
 
 /// Probe describes a health check to be performed against a container to determine whether it is alive or ready to receive traffic.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct Probe {
     /// One and only one of the following should be specified. Exec specifies the action to take.
     pub exec: Option<crate::api::core::v1::ExecAction>,
@@ -28,51 +28,81 @@ pub struct Probe {
     pub timeout_seconds: Option<i32>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for Probe {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_exec,
-            Key_failure_threshold,
-            Key_http_get,
-            Key_initial_delay_seconds,
-            Key_period_seconds,
-            Key_success_threshold,
-            Key_tcp_socket,
-            Key_timeout_seconds,
-            Other,
-        }
+impl Probe {
+    /// Returns this value with [`Probe::exec`] set to the given value.
+    #[must_use]
+    pub fn with_exec(mut self, value: impl Into<crate::api::core::v1::ExecAction>) -> Self {
+        self.exec = Some(value.into());
+        self
+    }
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+    /// Returns this value with [`Probe::failure_threshold`] set to the given value.
+    #[must_use]
+    pub fn with_failure_threshold(mut self, value: impl Into<i32>) -> Self {
+        self.failure_threshold = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`Probe::http_get`] set to the given value.
+    #[must_use]
+    pub fn with_http_get(mut self, value: impl Into<crate::api::core::v1::HTTPGetAction>) -> Self {
+        self.http_get = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`Probe::initial_delay_seconds`] set to the given value.
+    #[must_use]
+    pub fn with_initial_delay_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.initial_delay_seconds = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "exec" => Field::Key_exec,
-                            "failureThreshold" => Field::Key_failure_threshold,
-                            "httpGet" => Field::Key_http_get,
-                            "initialDelaySeconds" => Field::Key_initial_delay_seconds,
-                            "periodSeconds" => Field::Key_period_seconds,
-                            "successThreshold" => Field::Key_success_threshold,
-                            "tcpSocket" => Field::Key_tcp_socket,
-                            "timeoutSeconds" => Field::Key_timeout_seconds,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`Probe::period_seconds`] set to the given value.
+    #[must_use]
+    pub fn with_period_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.period_seconds = Some(value.into());
+        self
+    }
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+    /// Returns this value with [`Probe::success_threshold`] set to the given value.
+    #[must_use]
+    pub fn with_success_threshold(mut self, value: impl Into<i32>) -> Self {
+        self.success_threshold = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Probe::tcp_socket`] set to the given value.
+    #[must_use]
+    pub fn with_tcp_socket(mut self, value: impl Into<crate::api::core::v1::TCPSocketAction>) -> Self {
+        self.tcp_socket = Some(value.into());
+        self
+    }
+
+    /// Returns this value with [`Probe::timeout_seconds`] set to the given value.
+    #[must_use]
+    pub fn with_timeout_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.timeout_seconds = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for Probe {}
 
+impl Probe {
+    const FIELDS: &'static [&'static str] = &[
+        "exec",
+        "failureThreshold",
+        "httpGet",
+        "initialDelaySeconds",
+        "periodSeconds",
+        "successThreshold",
+        "tcpSocket",
+        "timeoutSeconds",
+    ];
+}
+
+impl<'de> crate::serde::Deserialize<'de> for Probe {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -92,17 +122,17 @@ impl<'de> crate::serde::Deserialize<'de> for Probe {
                 let mut value_tcp_socket: Option<crate::api::core::v1::TCPSocketAction> = None;
                 let mut value_timeout_seconds: Option<i32> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Probe", Probe::FIELDS))? {
                     match key {
-                        Field::Key_exec => value_exec = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_failure_threshold => value_failure_threshold = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_http_get => value_http_get = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_initial_delay_seconds => value_initial_delay_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_period_seconds => value_period_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_success_threshold => value_success_threshold = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_tcp_socket => value_tcp_socket = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_timeout_seconds => value_timeout_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_exec = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_failure_threshold = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_http_get = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_initial_delay_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(4) => value_period_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(5) => value_success_threshold = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(6) => value_tcp_socket = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(7) => value_timeout_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -117,27 +147,126 @@ impl<'de> crate::serde::Deserialize<'de> for Probe {
                     timeout_seconds: value_timeout_seconds,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(Probe {
+                    exec: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    failure_threshold: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    http_get: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    initial_delay_seconds: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                    period_seconds: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?,
+                    success_threshold: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?,
+                    tcp_socket: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?,
+                    timeout_seconds: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "Probe",
-            &[
-                "exec",
-                "failureThreshold",
-                "httpGet",
-                "initialDelaySeconds",
-                "periodSeconds",
-                "successThreshold",
-                "tcpSocket",
-                "timeoutSeconds",
-            ],
+            Probe::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, Probe> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("Probe")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_exec = false;
+                let mut has_failure_threshold = false;
+                let mut has_http_get = false;
+                let mut has_initial_delay_seconds = false;
+                let mut has_period_seconds = false;
+                let mut has_success_threshold = false;
+                let mut has_tcp_socket = false;
+                let mut has_timeout_seconds = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Probe", Probe::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.exec))?; has_exec = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.failure_threshold))?; has_failure_threshold = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.http_get))?; has_http_get = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.initial_delay_seconds))?; has_initial_delay_seconds = true; },
+                        Some(4) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.period_seconds))?; has_period_seconds = true; },
+                        Some(5) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.success_threshold))?; has_success_threshold = true; },
+                        Some(6) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.tcp_socket))?; has_tcp_socket = true; },
+                        Some(7) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.timeout_seconds))?; has_timeout_seconds = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_exec {
+                    self.0.exec = None;
+                }
+                if !has_failure_threshold {
+                    self.0.failure_threshold = None;
+                }
+                if !has_http_get {
+                    self.0.http_get = None;
+                }
+                if !has_initial_delay_seconds {
+                    self.0.initial_delay_seconds = None;
+                }
+                if !has_period_seconds {
+                    self.0.period_seconds = None;
+                }
+                if !has_success_threshold {
+                    self.0.success_threshold = None;
+                }
+                if !has_tcp_socket {
+                    self.0.tcp_socket = None;
+                }
+                if !has_timeout_seconds {
+                    self.0.timeout_seconds = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.exec))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.failure_threshold))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.http_get))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.initial_delay_seconds))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.period_seconds))?.ok_or_else(|| crate::serde::de::Error::invalid_length(4, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.success_threshold))?.ok_or_else(|| crate::serde::de::Error::invalid_length(5, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.tcp_socket))?.ok_or_else(|| crate::serde::de::Error::invalid_length(6, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.timeout_seconds))?.ok_or_else(|| crate::serde::de::Error::invalid_length(7, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "Probe",
+            Probe::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for Probe {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("Probe", 8)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "exec", &self.exec)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "failureThreshold", &self.failure_threshold)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "httpGet", &self.http_get)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "initialDelaySeconds", &self.initial_delay_seconds)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "periodSeconds", &self.period_seconds)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "successThreshold", &self.success_threshold)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tcpSocket", &self.tcp_socket)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "timeoutSeconds", &self.timeout_seconds)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "Probe",
             self.exec.as_ref().map_or(0, |_| 1) +
@@ -177,10 +306,174 @@ impl crate::serde::Serialize for Probe {
     }
 }
 
+/// A builder of [`Probe`]. Created with [`Probe::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ProbeBuilder {
+    exec: Option<crate::api::core::v1::ExecAction>,
+    failure_threshold: Option<i32>,
+    http_get: Option<crate::api::core::v1::HTTPGetAction>,
+    initial_delay_seconds: Option<i32>,
+    period_seconds: Option<i32>,
+    success_threshold: Option<i32>,
+    tcp_socket: Option<crate::api::core::v1::TCPSocketAction>,
+    timeout_seconds: Option<i32>,
+}
+
+#[cfg(feature = "builder")]
+impl Probe {
+    /// Starts building a `Probe`. Its required fields must be set before [`ProbeBuilder::build`] is called.
+    pub fn builder() -> ProbeBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ProbeBuilder {
+    /// Sets [`Probe::exec`].
+    pub fn exec(mut self, value: impl Into<crate::api::core::v1::ExecAction>) -> Self {
+        self.exec = Some(value.into());
+        self
+    }
+
+    /// Sets [`Probe::failure_threshold`].
+    pub fn failure_threshold(mut self, value: impl Into<i32>) -> Self {
+        self.failure_threshold = Some(value.into());
+        self
+    }
+
+    /// Sets [`Probe::http_get`].
+    pub fn http_get(mut self, value: impl Into<crate::api::core::v1::HTTPGetAction>) -> Self {
+        self.http_get = Some(value.into());
+        self
+    }
+
+    /// Sets [`Probe::initial_delay_seconds`].
+    pub fn initial_delay_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.initial_delay_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`Probe::period_seconds`].
+    pub fn period_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.period_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`Probe::success_threshold`].
+    pub fn success_threshold(mut self, value: impl Into<i32>) -> Self {
+        self.success_threshold = Some(value.into());
+        self
+    }
+
+    /// Sets [`Probe::tcp_socket`].
+    pub fn tcp_socket(mut self, value: impl Into<crate::api::core::v1::TCPSocketAction>) -> Self {
+        self.tcp_socket = Some(value.into());
+        self
+    }
+
+    /// Sets [`Probe::timeout_seconds`].
+    pub fn timeout_seconds(mut self, value: impl Into<i32>) -> Self {
+        self.timeout_seconds = Some(value.into());
+        self
+    }
+
+    /// Builds the `Probe`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<Probe, crate::MissingFieldError> {
+        Ok(Probe {
+            exec: self.exec,
+            failure_threshold: self.failure_threshold,
+            http_get: self.http_get,
+            initial_delay_seconds: self.initial_delay_seconds,
+            period_seconds: self.period_seconds,
+            success_threshold: self.success_threshold,
+            tcp_socket: self.tcp_socket,
+            timeout_seconds: self.timeout_seconds,
+        })
+    }
+}
+
+/// The apply configuration of [`Probe`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProbeApplyConfiguration {
+    /// See [`Probe::exec`].
+    pub exec: Option<crate::api::core::v1::ExecActionApplyConfiguration>,
+
+    /// See [`Probe::failure_threshold`].
+    pub failure_threshold: Option<i32>,
+
+    /// See [`Probe::http_get`].
+    pub http_get: Option<crate::api::core::v1::HTTPGetActionApplyConfiguration>,
+
+    /// See [`Probe::initial_delay_seconds`].
+    pub initial_delay_seconds: Option<i32>,
+
+    /// See [`Probe::period_seconds`].
+    pub period_seconds: Option<i32>,
+
+    /// See [`Probe::success_threshold`].
+    pub success_threshold: Option<i32>,
+
+    /// See [`Probe::tcp_socket`].
+    pub tcp_socket: Option<crate::api::core::v1::TCPSocketActionApplyConfiguration>,
+
+    /// See [`Probe::timeout_seconds`].
+    pub timeout_seconds: Option<i32>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ProbeApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ProbeApplyConfiguration",
+            self.exec.as_ref().map_or(0, |_| 1) +
+            self.failure_threshold.as_ref().map_or(0, |_| 1) +
+            self.http_get.as_ref().map_or(0, |_| 1) +
+            self.initial_delay_seconds.as_ref().map_or(0, |_| 1) +
+            self.period_seconds.as_ref().map_or(0, |_| 1) +
+            self.success_threshold.as_ref().map_or(0, |_| 1) +
+            self.tcp_socket.as_ref().map_or(0, |_| 1) +
+            self.timeout_seconds.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.exec {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "exec", value)?;
+        }
+        if let Some(value) = &self.failure_threshold {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "failureThreshold", value)?;
+        }
+        if let Some(value) = &self.http_get {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "httpGet", value)?;
+        }
+        if let Some(value) = &self.initial_delay_seconds {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "initialDelaySeconds", value)?;
+        }
+        if let Some(value) = &self.period_seconds {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "periodSeconds", value)?;
+        }
+        if let Some(value) = &self.success_threshold {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "successThreshold", value)?;
+        }
+        if let Some(value) = &self.tcp_socket {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "tcpSocket", value)?;
+        }
+        if let Some(value) = &self.timeout_seconds {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "timeoutSeconds", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Probe {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.Probe".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::Probe".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.Probe".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -191,8 +484,9 @@ impl crate::schemars::JsonSchema for Probe {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "exec".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ExecAction>().into_object();
@@ -202,8 +496,8 @@ impl crate::schemars::JsonSchema for Probe {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "failureThreshold".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -214,8 +508,8 @@ impl crate::schemars::JsonSchema for Probe {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "httpGet".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::HTTPGetAction>().into_object();
@@ -225,8 +519,8 @@ impl crate::schemars::JsonSchema for Probe {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "initialDelaySeconds".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -237,8 +531,8 @@ impl crate::schemars::JsonSchema for Probe {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "periodSeconds".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -249,8 +543,8 @@ impl crate::schemars::JsonSchema for Probe {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "successThreshold".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -261,8 +555,8 @@ impl crate::schemars::JsonSchema for Probe {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "tcpSocket".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::TCPSocketAction>().into_object();
@@ -272,8 +566,8 @@ impl crate::schemars::JsonSchema for Probe {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "timeoutSeconds".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -284,11 +578,63 @@ impl crate::schemars::JsonSchema for Probe {
                             format: Some("int32".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for Probe {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(Probe {
+            exec: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            failure_threshold: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            http_get: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            initial_delay_seconds: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            period_seconds: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            success_threshold: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            tcp_socket: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            timeout_seconds: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
+
+/// The property of a [`Probe`] that is set, out of the properties that are mutually exclusive. Returned by [`Probe::action`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ProbeAction<'a> {
+    /// [`Probe::exec`]
+    Exec(&'a crate::api::core::v1::ExecAction),
+
+    /// [`Probe::http_get`]
+    HttpGet(&'a crate::api::core::v1::HTTPGetAction),
+
+    /// [`Probe::tcp_socket`]
+    TcpSocket(&'a crate::api::core::v1::TCPSocketAction),
+}
+
+impl Probe {
+    /// Returns the property of this `Probe` that is set, out of the properties that are mutually exclusive.
+    ///
+    /// Returns `None` if none of them is set. If more than one of them is set, which the API server rejects,
+    /// the one that comes first in the order of the variants of [`ProbeAction`] is returned.
+    pub fn action(&self) -> Option<ProbeAction<'_>> {
+        if let Some(value) = &self.exec {
+            return Some(ProbeAction::Exec(value));
+        }
+
+        if let Some(value) = &self.http_get {
+            return Some(ProbeAction::HttpGet(value));
+        }
+
+        if let Some(value) = &self.tcp_socket {
+            return Some(ProbeAction::TcpSocket(value));
+        }
+
+        None
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.ResourceQuotaSpec
 
 /// ResourceQuotaSpec defines the desired hard limits to enforce for Quota.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ResourceQuotaSpec {
     /// hard is the set of desired hard limits for each named resource. More info: https://kubernetes.io/docs/concepts/policy/resource-quotas/
     pub hard: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>,
@@ -13,41 +13,41 @@ pub struct ResourceQuotaSpec {
     pub scopes: Option<Vec<String>>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ResourceQuotaSpec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_hard,
-            Key_scope_selector,
-            Key_scopes,
-            Other,
-        }
-
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl ResourceQuotaSpec {
+    /// Returns this value with [`ResourceQuotaSpec::hard`] set to the given value.
+    #[must_use]
+    pub fn with_hard(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>) -> Self {
+        self.hard = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ResourceQuotaSpec::scope_selector`] set to the given value.
+    #[must_use]
+    pub fn with_scope_selector(mut self, value: impl Into<crate::api::core::v1::ScopeSelector>) -> Self {
+        self.scope_selector = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ResourceQuotaSpec::scopes`] set to the given value.
+    #[must_use]
+    pub fn with_scopes(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.scopes = Some(value.into());
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "hard" => Field::Key_hard,
-                            "scopeSelector" => Field::Key_scope_selector,
-                            "scopes" => Field::Key_scopes,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for ResourceQuotaSpec {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl ResourceQuotaSpec {
+    const FIELDS: &'static [&'static str] = &[
+        "hard",
+        "scopeSelector",
+        "scopes",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ResourceQuotaSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,12 +62,12 @@ impl<'de> crate::serde::Deserialize<'de> for ResourceQuotaSpec {
                 let mut value_scope_selector: Option<crate::api::core::v1::ScopeSelector> = None;
                 let mut value_scopes: Option<Vec<String>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ResourceQuotaSpec", ResourceQuotaSpec::FIELDS))? {
                     match key {
-                        Field::Key_hard => value_hard = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_scope_selector => value_scope_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_scopes => value_scopes = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_hard = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_scope_selector = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_scopes = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
@@ -77,22 +77,86 @@ impl<'de> crate::serde::Deserialize<'de> for ResourceQuotaSpec {
                     scopes: value_scopes,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ResourceQuotaSpec {
+                    hard: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    scope_selector: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    scopes: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "ResourceQuotaSpec",
-            &[
-                "hard",
-                "scopeSelector",
-                "scopes",
-            ],
+            ResourceQuotaSpec::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ResourceQuotaSpec> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ResourceQuotaSpec")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_hard = false;
+                let mut has_scope_selector = false;
+                let mut has_scopes = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ResourceQuotaSpec", ResourceQuotaSpec::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.hard))?; has_hard = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.scope_selector))?; has_scope_selector = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.scopes))?; has_scopes = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_hard {
+                    self.0.hard = None;
+                }
+                if !has_scope_selector {
+                    self.0.scope_selector = None;
+                }
+                if !has_scopes {
+                    self.0.scopes = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.hard))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.scope_selector))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.scopes))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ResourceQuotaSpec",
+            ResourceQuotaSpec::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ResourceQuotaSpec {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ResourceQuotaSpec", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hard", &self.hard)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "scopeSelector", &self.scope_selector)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "scopes", &self.scopes)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ResourceQuotaSpec",
             self.hard.as_ref().map_or(0, |_| 1) +
@@ -112,10 +176,99 @@ impl crate::serde::Serialize for ResourceQuotaSpec {
     }
 }
 
+/// A builder of [`ResourceQuotaSpec`]. Created with [`ResourceQuotaSpec::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ResourceQuotaSpecBuilder {
+    hard: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>,
+    scope_selector: Option<crate::api::core::v1::ScopeSelector>,
+    scopes: Option<Vec<String>>,
+}
+
+#[cfg(feature = "builder")]
+impl ResourceQuotaSpec {
+    /// Starts building a `ResourceQuotaSpec`. Its required fields must be set before [`ResourceQuotaSpecBuilder::build`] is called.
+    pub fn builder() -> ResourceQuotaSpecBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ResourceQuotaSpecBuilder {
+    /// Sets [`ResourceQuotaSpec::hard`].
+    pub fn hard(mut self, value: impl Into<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>) -> Self {
+        self.hard = Some(value.into());
+        self
+    }
+
+    /// Sets [`ResourceQuotaSpec::scope_selector`].
+    pub fn scope_selector(mut self, value: impl Into<crate::api::core::v1::ScopeSelector>) -> Self {
+        self.scope_selector = Some(value.into());
+        self
+    }
+
+    /// Sets [`ResourceQuotaSpec::scopes`].
+    pub fn scopes(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.scopes = Some(value.into());
+        self
+    }
+
+    /// Builds the `ResourceQuotaSpec`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ResourceQuotaSpec, crate::MissingFieldError> {
+        Ok(ResourceQuotaSpec {
+            hard: self.hard,
+            scope_selector: self.scope_selector,
+            scopes: self.scopes,
+        })
+    }
+}
+
+/// The apply configuration of [`ResourceQuotaSpec`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ResourceQuotaSpecApplyConfiguration {
+    /// See [`ResourceQuotaSpec::hard`].
+    pub hard: Option<std::collections::BTreeMap<String, crate::apimachinery::pkg::api::resource::Quantity>>,
+
+    /// See [`ResourceQuotaSpec::scope_selector`].
+    pub scope_selector: Option<crate::api::core::v1::ScopeSelectorApplyConfiguration>,
+
+    /// See [`ResourceQuotaSpec::scopes`].
+    pub scopes: Option<Vec<String>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ResourceQuotaSpecApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ResourceQuotaSpecApplyConfiguration",
+            self.hard.as_ref().map_or(0, |_| 1) +
+            self.scope_selector.as_ref().map_or(0, |_| 1) +
+            self.scopes.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.hard {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "hard", value)?;
+        }
+        if let Some(value) = &self.scope_selector {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "scopeSelector", value)?;
+        }
+        if let Some(value) = &self.scopes {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "scopes", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ResourceQuotaSpec {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.ResourceQuotaSpec".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::ResourceQuotaSpec".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.ResourceQuotaSpec".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -126,8 +279,9 @@ impl crate::schemars::JsonSchema for ResourceQuotaSpec {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "hard".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -141,8 +295,8 @@ impl crate::schemars::JsonSchema for ResourceQuotaSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "scopeSelector".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::ScopeSelector>().into_object();
@@ -152,8 +306,8 @@ impl crate::schemars::JsonSchema for ResourceQuotaSpec {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "scopes".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -172,11 +326,23 @@ impl crate::schemars::JsonSchema for ResourceQuotaSpec {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ResourceQuotaSpec {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ResourceQuotaSpec {
+            hard: crate::arbitrary2::option(u, |u| crate::arbitrary2::map(u, crate::arbitrary::Arbitrary::arbitrary))?,
+            scope_selector: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            scopes: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.ServiceAccountTokenProjection
 
 /// ServiceAccountTokenProjection represents a projected service account token volume. This projection can be used to insert a service account token into the pods runtime filesystem for use against APIs (Kubernetes API Server or otherwise).
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct ServiceAccountTokenProjection {
     /// Audience is the intended audience of the token. A recipient of a token must identify itself with an identifier specified in the audience of the token, and otherwise should reject the token. The audience defaults to the identifier of the apiserver.
     pub audience: Option<String>,
@@ -13,41 +13,52 @@ pub struct ServiceAccountTokenProjection {
     pub path: String,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for ServiceAccountTokenProjection {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_audience,
-            Key_expiration_seconds,
-            Key_path,
-            Other,
+impl ServiceAccountTokenProjection {
+    /// Constructs a `ServiceAccountTokenProjection` from its required fields. Its optional fields are unset.
+    pub fn new(path: impl Into<String>) -> Self {
+        ServiceAccountTokenProjection {
+            audience: None,
+            expiration_seconds: None,
+            path: path.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl ServiceAccountTokenProjection {
+    /// Returns this value with [`ServiceAccountTokenProjection::audience`] set to the given value.
+    #[must_use]
+    pub fn with_audience(mut self, value: impl Into<String>) -> Self {
+        self.audience = Some(value.into());
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`ServiceAccountTokenProjection::expiration_seconds`] set to the given value.
+    #[must_use]
+    pub fn with_expiration_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.expiration_seconds = Some(value.into());
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`ServiceAccountTokenProjection::path`] set to the given value.
+    #[must_use]
+    pub fn with_path(mut self, value: impl Into<String>) -> Self {
+        self.path = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "audience" => Field::Key_audience,
-                            "expirationSeconds" => Field::Key_expiration_seconds,
-                            "path" => Field::Key_path,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for ServiceAccountTokenProjection {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl ServiceAccountTokenProjection {
+    const FIELDS: &'static [&'static str] = &[
+        "audience",
+        "expirationSeconds",
+        "path",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for ServiceAccountTokenProjection {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -62,37 +73,101 @@ impl<'de> crate::serde::Deserialize<'de> for ServiceAccountTokenProjection {
                 let mut value_expiration_seconds: Option<i64> = None;
                 let mut value_path: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ServiceAccountTokenProjection", ServiceAccountTokenProjection::FIELDS))? {
                     match key {
-                        Field::Key_audience => value_audience = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_expiration_seconds => value_expiration_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_path => value_path = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_audience = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(1) => value_expiration_seconds = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(2) => value_path = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(ServiceAccountTokenProjection {
                     audience: value_audience,
                     expiration_seconds: value_expiration_seconds,
-                    path: value_path.ok_or_else(|| crate::serde::de::Error::missing_field("path"))?,
+                    path: value_path.ok_or_else(|| crate::serde2::missing_field("ServiceAccountTokenProjection", "path", &["path"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(ServiceAccountTokenProjection {
+                    audience: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    expiration_seconds: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    path: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "ServiceAccountTokenProjection",
-            &[
-                "audience",
-                "expirationSeconds",
-                "path",
-            ],
+            ServiceAccountTokenProjection::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, ServiceAccountTokenProjection> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("ServiceAccountTokenProjection")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_audience = false;
+                let mut has_expiration_seconds = false;
+                let mut has_path = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("ServiceAccountTokenProjection", ServiceAccountTokenProjection::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.audience))?; has_audience = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.expiration_seconds))?; has_expiration_seconds = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.path))?; has_path = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_audience {
+                    self.0.audience = None;
+                }
+                if !has_expiration_seconds {
+                    self.0.expiration_seconds = None;
+                }
+                if !has_path {
+                    return Err(crate::serde2::missing_field("ServiceAccountTokenProjection", "path", &["path"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.audience))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.expiration_seconds))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.path))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ServiceAccountTokenProjection",
+            ServiceAccountTokenProjection::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for ServiceAccountTokenProjection {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("ServiceAccountTokenProjection", 3)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "audience", &self.audience)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "expirationSeconds", &self.expiration_seconds)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "path", &self.path)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "ServiceAccountTokenProjection",
             1 +
@@ -110,10 +185,99 @@ impl crate::serde::Serialize for ServiceAccountTokenProjection {
     }
 }
 
+/// A builder of [`ServiceAccountTokenProjection`]. Created with [`ServiceAccountTokenProjection::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct ServiceAccountTokenProjectionBuilder {
+    audience: Option<String>,
+    expiration_seconds: Option<i64>,
+    path: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl ServiceAccountTokenProjection {
+    /// Starts building a `ServiceAccountTokenProjection`. Its required fields must be set before [`ServiceAccountTokenProjectionBuilder::build`] is called.
+    pub fn builder() -> ServiceAccountTokenProjectionBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl ServiceAccountTokenProjectionBuilder {
+    /// Sets [`ServiceAccountTokenProjection::audience`].
+    pub fn audience(mut self, value: impl Into<String>) -> Self {
+        self.audience = Some(value.into());
+        self
+    }
+
+    /// Sets [`ServiceAccountTokenProjection::expiration_seconds`].
+    pub fn expiration_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.expiration_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets [`ServiceAccountTokenProjection::path`].
+    pub fn path(mut self, value: impl Into<String>) -> Self {
+        self.path = Some(value.into());
+        self
+    }
+
+    /// Builds the `ServiceAccountTokenProjection`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<ServiceAccountTokenProjection, crate::MissingFieldError> {
+        Ok(ServiceAccountTokenProjection {
+            audience: self.audience,
+            expiration_seconds: self.expiration_seconds,
+            path: self.path.ok_or_else(|| crate::MissingFieldError::new("ServiceAccountTokenProjection", "path"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`ServiceAccountTokenProjection`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ServiceAccountTokenProjectionApplyConfiguration {
+    /// See [`ServiceAccountTokenProjection::audience`].
+    pub audience: Option<String>,
+
+    /// See [`ServiceAccountTokenProjection::expiration_seconds`].
+    pub expiration_seconds: Option<i64>,
+
+    /// See [`ServiceAccountTokenProjection::path`].
+    pub path: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for ServiceAccountTokenProjectionApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "ServiceAccountTokenProjectionApplyConfiguration",
+            self.audience.as_ref().map_or(0, |_| 1) +
+            self.expiration_seconds.as_ref().map_or(0, |_| 1) +
+            self.path.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.audience {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "audience", value)?;
+        }
+        if let Some(value) = &self.expiration_seconds {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "expirationSeconds", value)?;
+        }
+        if let Some(value) = &self.path {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "path", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ServiceAccountTokenProjection {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.ServiceAccountTokenProjection".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::ServiceAccountTokenProjection".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.ServiceAccountTokenProjection".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -124,8 +288,9 @@ impl crate::schemars::JsonSchema for ServiceAccountTokenProjection {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "audience".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -135,8 +300,8 @@ impl crate::schemars::JsonSchema for ServiceAccountTokenProjection {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "expirationSeconds".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -147,8 +312,8 @@ impl crate::schemars::JsonSchema for ServiceAccountTokenProjection {
                             format: Some("int64".to_owned()),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "path".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -158,8 +323,9 @@ impl crate::schemars::JsonSchema for ServiceAccountTokenProjection {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "path",
                 ]).map(std::borrow::ToOwned::to_owned).collect(),
@@ -169,3 +335,14 @@ impl crate::schemars::JsonSchema for ServiceAccountTokenProjection {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ServiceAccountTokenProjection {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(ServiceAccountTokenProjection {
+            audience: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            expiration_seconds: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            path: crate::arbitrary::Arbitrary::arbitrary(u)?,
+        }))
+    }
+}
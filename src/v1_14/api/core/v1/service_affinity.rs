@@ -0,0 +1,151 @@
+// Generated from definition io.k8s.api.core.v1.ServiceAffinity
+
+/// ServiceAffinity is the type of the session affinity of a service.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ServiceAffinity {
+    /// `ClientIP`
+    ClientIP,
+
+    /// `None`
+    None,
+
+    /// A value that is not one of the values known to this version of the crate, such as one that was added in a newer version of Kubernetes.
+    Other(String),
+}
+
+impl ServiceAffinity {
+    /// The values known to this version of the crate, in the order of the variants.
+    pub const VALUES: &'static [&'static str] = &["ClientIP", "None"];
+
+    /// The value as it is serialized.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ServiceAffinity::ClientIP => "ClientIP",
+            ServiceAffinity::None => "None",
+            ServiceAffinity::Other(value) => value,
+        }
+    }
+}
+
+impl AsRef<str> for ServiceAffinity {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Default for ServiceAffinity {
+    fn default() -> Self {
+        ServiceAffinity::Other(String::new())
+    }
+}
+
+impl std::fmt::Display for ServiceAffinity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for ServiceAffinity {
+    fn from(value: &str) -> Self {
+        match value {
+            "ClientIP" => ServiceAffinity::ClientIP,
+            "None" => ServiceAffinity::None,
+            value => ServiceAffinity::Other(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServiceAffinity {
+    fn from(value: String) -> Self {
+        match &*value {
+            "ClientIP" => ServiceAffinity::ClientIP,
+            "None" => ServiceAffinity::None,
+            _ => ServiceAffinity::Other(value),
+        }
+    }
+}
+
+impl From<ServiceAffinity> for String {
+    fn from(value: ServiceAffinity) -> Self {
+        match value {
+            ServiceAffinity::Other(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl PartialEq<str> for ServiceAffinity {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for ServiceAffinity {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<'de> crate::serde::Deserialize<'de> for ServiceAffinity {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl crate::serde::de::Visitor<'_> for Visitor {
+            type Value = ServiceAffinity;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("ServiceAffinity")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
+                Ok(v.into())
+            }
+
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E> where E: crate::serde::de::Error {
+                Ok(v.into())
+            }
+        }
+
+        deserializer.deserialize_string(Visitor)
+    }
+}
+
+impl crate::serde::Serialize for ServiceAffinity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for ServiceAffinity {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::ServiceAffinity".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.ServiceAffinity".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("ServiceAffinity is the type of the session affinity of a service.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ServiceAffinity {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        Ok(match u.int_in_range(0..=2_u8)? {
+            0 => ServiceAffinity::ClientIP,
+            1 => ServiceAffinity::None,
+            _ => ServiceAffinity::from(u.arbitrary::<String>()?),
+        })
+    }
+}
@@ -0,0 +1,151 @@
+// Generated from definition io.k8s.api.core.v1.ServiceExternalTrafficPolicyType
+
+/// ServiceExternalTrafficPolicyType describes how nodes route external traffic to the endpoints of a service.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ServiceExternalTrafficPolicyType {
+    /// `Cluster`
+    Cluster,
+
+    /// `Local`
+    Local,
+
+    /// A value that is not one of the values known to this version of the crate, such as one that was added in a newer version of Kubernetes.
+    Other(String),
+}
+
+impl ServiceExternalTrafficPolicyType {
+    /// The values known to this version of the crate, in the order of the variants.
+    pub const VALUES: &'static [&'static str] = &["Cluster", "Local"];
+
+    /// The value as it is serialized.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ServiceExternalTrafficPolicyType::Cluster => "Cluster",
+            ServiceExternalTrafficPolicyType::Local => "Local",
+            ServiceExternalTrafficPolicyType::Other(value) => value,
+        }
+    }
+}
+
+impl AsRef<str> for ServiceExternalTrafficPolicyType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Default for ServiceExternalTrafficPolicyType {
+    fn default() -> Self {
+        ServiceExternalTrafficPolicyType::Other(String::new())
+    }
+}
+
+impl std::fmt::Display for ServiceExternalTrafficPolicyType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for ServiceExternalTrafficPolicyType {
+    fn from(value: &str) -> Self {
+        match value {
+            "Cluster" => ServiceExternalTrafficPolicyType::Cluster,
+            "Local" => ServiceExternalTrafficPolicyType::Local,
+            value => ServiceExternalTrafficPolicyType::Other(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServiceExternalTrafficPolicyType {
+    fn from(value: String) -> Self {
+        match &*value {
+            "Cluster" => ServiceExternalTrafficPolicyType::Cluster,
+            "Local" => ServiceExternalTrafficPolicyType::Local,
+            _ => ServiceExternalTrafficPolicyType::Other(value),
+        }
+    }
+}
+
+impl From<ServiceExternalTrafficPolicyType> for String {
+    fn from(value: ServiceExternalTrafficPolicyType) -> Self {
+        match value {
+            ServiceExternalTrafficPolicyType::Other(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl PartialEq<str> for ServiceExternalTrafficPolicyType {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for ServiceExternalTrafficPolicyType {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<'de> crate::serde::Deserialize<'de> for ServiceExternalTrafficPolicyType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl crate::serde::de::Visitor<'_> for Visitor {
+            type Value = ServiceExternalTrafficPolicyType;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("ServiceExternalTrafficPolicyType")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
+                Ok(v.into())
+            }
+
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E> where E: crate::serde::de::Error {
+                Ok(v.into())
+            }
+        }
+
+        deserializer.deserialize_string(Visitor)
+    }
+}
+
+impl crate::serde::Serialize for ServiceExternalTrafficPolicyType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl crate::schemars::JsonSchema for ServiceExternalTrafficPolicyType {
+    fn schema_name() -> String {
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::ServiceExternalTrafficPolicyType".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.ServiceExternalTrafficPolicyType".to_owned()
+        }
+    }
+
+    fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
+        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
+            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                description: Some("ServiceExternalTrafficPolicyType describes how nodes route external traffic to the endpoints of a service.".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for ServiceExternalTrafficPolicyType {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        Ok(match u.int_in_range(0..=2_u8)? {
+            0 => ServiceExternalTrafficPolicyType::Cluster,
+            1 => ServiceExternalTrafficPolicyType::Local,
+            _ => ServiceExternalTrafficPolicyType::from(u.arbitrary::<String>()?),
+        })
+    }
+}
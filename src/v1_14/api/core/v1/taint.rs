@@ -1,10 +1,10 @@
 // Generated from definition io.k8s.api.core.v1.Taint
 
 /// The node this Taint is attached to has the "effect" on any pod that does not tolerate the Taint.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct Taint {
     /// Required. The effect of the taint on pods that do not tolerate the taint. Valid effects are NoSchedule, PreferNoSchedule and NoExecute.
-    pub effect: String,
+    pub effect: crate::api::core::v1::TaintEffect,
 
     /// Required. The taint key to be applied to a node.
     pub key: String,
@@ -16,43 +16,61 @@ pub struct Taint {
     pub value: Option<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for Taint {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_effect,
-            Key_key,
-            Key_time_added,
-            Key_value,
-            Other,
+impl Taint {
+    /// Constructs a `Taint` from its required fields. Its optional fields are unset.
+    pub fn new(effect: impl Into<crate::api::core::v1::TaintEffect>, key: impl Into<String>) -> Self {
+        Taint {
+            effect: effect.into(),
+            key: key.into(),
+            time_added: None,
+            value: None,
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+impl Taint {
+    /// Returns this value with [`Taint::effect`] set to the given value.
+    #[must_use]
+    pub fn with_effect(mut self, value: impl Into<crate::api::core::v1::TaintEffect>) -> Self {
+        self.effect = value.into();
+        self
+    }
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+    /// Returns this value with [`Taint::key`] set to the given value.
+    #[must_use]
+    pub fn with_key(mut self, value: impl Into<String>) -> Self {
+        self.key = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`Taint::time_added`] set to the given value.
+    #[must_use]
+    pub fn with_time_added(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.time_added = Some(value.into());
+        self
+    }
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "effect" => Field::Key_effect,
-                            "key" => Field::Key_key,
-                            "timeAdded" => Field::Key_time_added,
-                            "value" => Field::Key_value,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+    /// Returns this value with [`Taint::value`] set to the given value.
+    #[must_use]
+    pub fn with_value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl crate::MergeStrategyInfo for Taint {}
+
+impl Taint {
+    const FIELDS: &'static [&'static str] = &[
+        "effect",
+        "key",
+        "timeAdded",
+        "value",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for Taint {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -63,45 +81,116 @@ impl<'de> crate::serde::Deserialize<'de> for Taint {
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
-                let mut value_effect: Option<String> = None;
+                let mut value_effect: Option<crate::api::core::v1::TaintEffect> = None;
                 let mut value_key: Option<String> = None;
                 let mut value_time_added: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
                 let mut value_value: Option<String> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Taint", Taint::FIELDS))? {
                     match key {
-                        Field::Key_effect => value_effect = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_key => value_key = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_time_added => value_time_added = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Key_value => value_value = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_effect = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_key = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(2) => value_time_added = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        Some(3) => value_value = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(Taint {
-                    effect: value_effect.ok_or_else(|| crate::serde::de::Error::missing_field("effect"))?,
-                    key: value_key.ok_or_else(|| crate::serde::de::Error::missing_field("key"))?,
+                    effect: value_effect.ok_or_else(|| crate::serde2::missing_field("Taint", "effect", &["effect", "key"]))?,
+                    key: value_key.ok_or_else(|| crate::serde2::missing_field("Taint", "key", &["effect", "key"]))?,
                     time_added: value_time_added,
                     value: value_value,
                 })
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(Taint {
+                    effect: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    key: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
+                    time_added: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?,
+                    value: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?,
+                })
+            }
         }
 
         deserializer.deserialize_struct(
             "Taint",
-            &[
-                "effect",
-                "key",
-                "timeAdded",
-                "value",
-            ],
+            Taint::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, Taint> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("Taint")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_effect = false;
+                let mut has_key = false;
+                let mut has_time_added = false;
+                let mut has_value = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("Taint", Taint::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.effect))?; has_effect = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.key))?; has_key = true; },
+                        Some(2) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.time_added))?; has_time_added = true; },
+                        Some(3) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.value))?; has_value = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_effect {
+                    return Err(crate::serde2::missing_field("Taint", "effect", &["effect", "key"]));
+                }
+                if !has_key {
+                    return Err(crate::serde2::missing_field("Taint", "key", &["effect", "key"]));
+                }
+                if !has_time_added {
+                    self.0.time_added = None;
+                }
+                if !has_value {
+                    self.0.value = None;
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.effect))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.key))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.time_added))?.ok_or_else(|| crate::serde::de::Error::invalid_length(2, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.value))?.ok_or_else(|| crate::serde::de::Error::invalid_length(3, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "Taint",
+            Taint::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for Taint {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("Taint", 4)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "effect", &self.effect)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "key", &self.key)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "timeAdded", &self.time_added)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "value", &self.value)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "Taint",
             2 +
@@ -120,10 +209,114 @@ impl crate::serde::Serialize for Taint {
     }
 }
 
+/// A builder of [`Taint`]. Created with [`Taint::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct TaintBuilder {
+    effect: Option<crate::api::core::v1::TaintEffect>,
+    key: Option<String>,
+    time_added: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+    value: Option<String>,
+}
+
+#[cfg(feature = "builder")]
+impl Taint {
+    /// Starts building a `Taint`. Its required fields must be set before [`TaintBuilder::build`] is called.
+    pub fn builder() -> TaintBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl TaintBuilder {
+    /// Sets [`Taint::effect`].
+    pub fn effect(mut self, value: impl Into<crate::api::core::v1::TaintEffect>) -> Self {
+        self.effect = Some(value.into());
+        self
+    }
+
+    /// Sets [`Taint::key`].
+    pub fn key(mut self, value: impl Into<String>) -> Self {
+        self.key = Some(value.into());
+        self
+    }
+
+    /// Sets [`Taint::time_added`].
+    pub fn time_added(mut self, value: impl Into<crate::apimachinery::pkg::apis::meta::v1::Time>) -> Self {
+        self.time_added = Some(value.into());
+        self
+    }
+
+    /// Sets [`Taint::value`].
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Builds the `Taint`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<Taint, crate::MissingFieldError> {
+        Ok(Taint {
+            effect: self.effect.ok_or_else(|| crate::MissingFieldError::new("Taint", "effect"))?,
+            key: self.key.ok_or_else(|| crate::MissingFieldError::new("Taint", "key"))?,
+            time_added: self.time_added,
+            value: self.value,
+        })
+    }
+}
+
+/// The apply configuration of [`Taint`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TaintApplyConfiguration {
+    /// See [`Taint::effect`].
+    pub effect: Option<crate::api::core::v1::TaintEffect>,
+
+    /// See [`Taint::key`].
+    pub key: Option<String>,
+
+    /// See [`Taint::time_added`].
+    pub time_added: Option<crate::apimachinery::pkg::apis::meta::v1::Time>,
+
+    /// See [`Taint::value`].
+    pub value: Option<String>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for TaintApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "TaintApplyConfiguration",
+            self.effect.as_ref().map_or(0, |_| 1) +
+            self.key.as_ref().map_or(0, |_| 1) +
+            self.time_added.as_ref().map_or(0, |_| 1) +
+            self.value.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.effect {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "effect", value)?;
+        }
+        if let Some(value) = &self.key {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "key", value)?;
+        }
+        if let Some(value) = &self.time_added {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "timeAdded", value)?;
+        }
+        if let Some(value) = &self.value {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "value", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Taint {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.Taint".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::Taint".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.Taint".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -134,19 +327,20 @@ impl crate::schemars::JsonSchema for Taint {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "effect".to_owned(),
-                        crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
-                            metadata: Some(Box::new(crate::schemars::schema::Metadata {
+                        {
+                            let mut schema_obj = __gen.subschema_for::<crate::api::core::v1::TaintEffect>().into_object();
+                            schema_obj.metadata = Some(Box::new(crate::schemars::schema::Metadata {
                                 description: Some("Required. The effect of the taint on pods that do not tolerate the taint. Valid effects are NoSchedule, PreferNoSchedule and NoExecute.".to_owned()),
                                 ..Default::default()
-                            })),
-                            instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
-                            ..Default::default()
-                        }),
-                    ),
-                    (
+                            }));
+                            crate::schemars::schema::Schema::Object(schema_obj)
+                        },
+                    );
+                    properties.insert(
                         "key".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -156,8 +350,8 @@ impl crate::schemars::JsonSchema for Taint {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "timeAdded".to_owned(),
                         {
                             let mut schema_obj = __gen.subschema_for::<crate::apimachinery::pkg::apis::meta::v1::Time>().into_object();
@@ -167,8 +361,8 @@ impl crate::schemars::JsonSchema for Taint {
                             }));
                             crate::schemars::schema::Schema::Object(schema_obj)
                         },
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "value".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -178,8 +372,9 @@ impl crate::schemars::JsonSchema for Taint {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "effect",
                     "key",
@@ -190,3 +385,15 @@ impl crate::schemars::JsonSchema for Taint {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for Taint {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(Taint {
+            effect: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            key: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            time_added: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+            value: crate::arbitrary2::option(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
@@ -1,7 +1,7 @@
 // Generated from definition io.k8s.api.core.v1.TopologySelectorLabelRequirement
 
 /// A topology selector requirement is a selector that matches given label. This is an alpha feature and may change in the future.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct TopologySelectorLabelRequirement {
     /// The label key that the selector applies to.
     pub key: String,
@@ -10,39 +10,43 @@ pub struct TopologySelectorLabelRequirement {
     pub values: Vec<String>,
 }
 
-impl<'de> crate::serde::Deserialize<'de> for TopologySelectorLabelRequirement {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_key,
-            Key_values,
-            Other,
+impl TopologySelectorLabelRequirement {
+    /// Constructs a `TopologySelectorLabelRequirement` from its required fields. Its optional fields are unset.
+    pub fn new(key: impl Into<String>, values: impl Into<Vec<String>>) -> Self {
+        TopologySelectorLabelRequirement {
+            key: key.into(),
+            values: values.into(),
         }
+    }
+}
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
-
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+impl TopologySelectorLabelRequirement {
+    /// Returns this value with [`TopologySelectorLabelRequirement::key`] set to the given value.
+    #[must_use]
+    pub fn with_key(mut self, value: impl Into<String>) -> Self {
+        self.key = value.into();
+        self
+    }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+    /// Returns this value with [`TopologySelectorLabelRequirement::values`] set to the given value.
+    #[must_use]
+    pub fn with_values(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.values = value.into();
+        self
+    }
+}
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "key" => Field::Key_key,
-                            "values" => Field::Key_values,
-                            _ => Field::Other,
-                        })
-                    }
-                }
+impl crate::MergeStrategyInfo for TopologySelectorLabelRequirement {}
 
-                deserializer.deserialize_identifier(Visitor)
-            }
-        }
+impl TopologySelectorLabelRequirement {
+    const FIELDS: &'static [&'static str] = &[
+        "key",
+        "values",
+    ];
+}
 
+impl<'de> crate::serde::Deserialize<'de> for TopologySelectorLabelRequirement {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
 
         impl<'de> crate::serde::de::Visitor<'de> for Visitor {
@@ -56,34 +60,91 @@ impl<'de> crate::serde::Deserialize<'de> for TopologySelectorLabelRequirement {
                 let mut value_key: Option<String> = None;
                 let mut value_values: Option<Vec<String>> = None;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("TopologySelectorLabelRequirement", TopologySelectorLabelRequirement::FIELDS))? {
                     match key {
-                        Field::Key_key => value_key = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Key_values => value_values = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => value_key = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        Some(1) => value_values = Some(crate::serde::de::MapAccess::next_value(&mut map)?),
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
                 Ok(TopologySelectorLabelRequirement {
-                    key: value_key.ok_or_else(|| crate::serde::de::Error::missing_field("key"))?,
-                    values: value_values.ok_or_else(|| crate::serde::de::Error::missing_field("values"))?,
+                    key: value_key.ok_or_else(|| crate::serde2::missing_field("TopologySelectorLabelRequirement", "key", &["key", "values"]))?,
+                    values: value_values.ok_or_else(|| crate::serde2::missing_field("TopologySelectorLabelRequirement", "values", &["key", "values"]))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(TopologySelectorLabelRequirement {
+                    key: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                    values: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?,
                 })
             }
         }
 
         deserializer.deserialize_struct(
             "TopologySelectorLabelRequirement",
-            &[
-                "key",
-                "values",
-            ],
+            TopologySelectorLabelRequirement::FIELDS,
             Visitor,
         )
     }
+
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, TopologySelectorLabelRequirement> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("TopologySelectorLabelRequirement")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut has_key = false;
+                let mut has_values = false;
+
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("TopologySelectorLabelRequirement", TopologySelectorLabelRequirement::FIELDS))? {
+                    match key {
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.key))?; has_key = true; },
+                        Some(1) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::DeserializeInPlace(&mut self.0.values))?; has_values = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+                if !has_key {
+                    return Err(crate::serde2::missing_field("TopologySelectorLabelRequirement", "key", &["key", "values"]));
+                }
+                if !has_values {
+                    return Err(crate::serde2::missing_field("TopologySelectorLabelRequirement", "values", &["key", "values"]));
+                }
+
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.key))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::DeserializeInPlace(&mut self.0.values))?.ok_or_else(|| crate::serde::de::Error::invalid_length(1, &self))?;
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "TopologySelectorLabelRequirement",
+            TopologySelectorLabelRequirement::FIELDS,
+            InPlaceVisitor(place),
+        )
+    }
 }
 
 impl crate::serde::Serialize for TopologySelectorLabelRequirement {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("TopologySelectorLabelRequirement", 2)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "key", &self.key)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "values", &self.values)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "TopologySelectorLabelRequirement",
             2,
@@ -94,10 +155,84 @@ impl crate::serde::Serialize for TopologySelectorLabelRequirement {
     }
 }
 
+/// A builder of [`TopologySelectorLabelRequirement`]. Created with [`TopologySelectorLabelRequirement::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct TopologySelectorLabelRequirementBuilder {
+    key: Option<String>,
+    values: Option<Vec<String>>,
+}
+
+#[cfg(feature = "builder")]
+impl TopologySelectorLabelRequirement {
+    /// Starts building a `TopologySelectorLabelRequirement`. Its required fields must be set before [`TopologySelectorLabelRequirementBuilder::build`] is called.
+    pub fn builder() -> TopologySelectorLabelRequirementBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl TopologySelectorLabelRequirementBuilder {
+    /// Sets [`TopologySelectorLabelRequirement::key`].
+    pub fn key(mut self, value: impl Into<String>) -> Self {
+        self.key = Some(value.into());
+        self
+    }
+
+    /// Sets [`TopologySelectorLabelRequirement::values`].
+    pub fn values(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.values = Some(value.into());
+        self
+    }
+
+    /// Builds the `TopologySelectorLabelRequirement`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<TopologySelectorLabelRequirement, crate::MissingFieldError> {
+        Ok(TopologySelectorLabelRequirement {
+            key: self.key.ok_or_else(|| crate::MissingFieldError::new("TopologySelectorLabelRequirement", "key"))?,
+            values: self.values.ok_or_else(|| crate::MissingFieldError::new("TopologySelectorLabelRequirement", "values"))?,
+        })
+    }
+}
+
+/// The apply configuration of [`TopologySelectorLabelRequirement`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TopologySelectorLabelRequirementApplyConfiguration {
+    /// See [`TopologySelectorLabelRequirement::key`].
+    pub key: Option<String>,
+
+    /// See [`TopologySelectorLabelRequirement::values`].
+    pub values: Option<Vec<String>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for TopologySelectorLabelRequirementApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "TopologySelectorLabelRequirementApplyConfiguration",
+            self.key.as_ref().map_or(0, |_| 1) +
+            self.values.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.key {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "key", value)?;
+        }
+        if let Some(value) = &self.values {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "values", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for TopologySelectorLabelRequirement {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.TopologySelectorLabelRequirement".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::TopologySelectorLabelRequirement".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.TopologySelectorLabelRequirement".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -108,8 +243,9 @@ impl crate::schemars::JsonSchema for TopologySelectorLabelRequirement {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "key".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -119,8 +255,8 @@ impl crate::schemars::JsonSchema for TopologySelectorLabelRequirement {
                             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::String))),
                             ..Default::default()
                         }),
-                    ),
-                    (
+                    );
+                    properties.insert(
                         "values".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -139,8 +275,9 @@ impl crate::schemars::JsonSchema for TopologySelectorLabelRequirement {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 required: std::array::IntoIter::new([
                     "key",
                     "values",
@@ -151,3 +288,13 @@ impl crate::schemars::JsonSchema for TopologySelectorLabelRequirement {
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for TopologySelectorLabelRequirement {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(TopologySelectorLabelRequirement {
+            key: crate::arbitrary::Arbitrary::arbitrary(u)?,
+            values: crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary)?,
+        }))
+    }
+}
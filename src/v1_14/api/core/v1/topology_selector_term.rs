@@ -1,80 +1,118 @@
 // Generated from definition io.k8s.api.core.v1.TopologySelectorTerm
 
 /// A topology selector term represents the result of label queries. A null or empty topology selector term matches no objects. The requirements of them are ANDed. It provides a subset of functionality as NodeSelectorTerm. This is an alpha feature and may change in the future.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct TopologySelectorTerm {
     /// A list of topology selector requirements by labels.
     pub match_label_expressions: Option<Vec<crate::api::core::v1::TopologySelectorLabelRequirement>>,
 }
 
+impl TopologySelectorTerm {
+    /// Returns this value with [`TopologySelectorTerm::match_label_expressions`] set to the given value.
+    #[must_use]
+    pub fn with_match_label_expressions(mut self, value: impl Into<Vec<crate::api::core::v1::TopologySelectorLabelRequirement>>) -> Self {
+        self.match_label_expressions = Some(value.into());
+        self
+    }
+}
+
+impl crate::MergeStrategyInfo for TopologySelectorTerm {}
+
+impl TopologySelectorTerm {
+    const FIELDS: &'static [&'static str] = &[
+        "matchLabelExpressions",
+    ];
+}
+
 impl<'de> crate::serde::Deserialize<'de> for TopologySelectorTerm {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-        #[allow(non_camel_case_types)]
-        enum Field {
-            Key_match_label_expressions,
-            Other,
-        }
+        struct Visitor;
 
-        impl<'de> crate::serde::Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
-                struct Visitor;
+        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
+            type Value = TopologySelectorTerm;
 
-                impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-                    type Value = Field;
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("TopologySelectorTerm")
+            }
 
-                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.write_str("field identifier")
-                    }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
+                let mut value_match_label_expressions: Option<Vec<crate::api::core::v1::TopologySelectorLabelRequirement>> = None;
 
-                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: crate::serde::de::Error {
-                        Ok(match v {
-                            "matchLabelExpressions" => Field::Key_match_label_expressions,
-                            _ => Field::Other,
-                        })
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("TopologySelectorTerm", TopologySelectorTerm::FIELDS))? {
+                    match key {
+                        Some(0) => value_match_label_expressions = crate::serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
 
-                deserializer.deserialize_identifier(Visitor)
+                Ok(TopologySelectorTerm {
+                    match_label_expressions: value_match_label_expressions,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                Ok(TopologySelectorTerm {
+                    match_label_expressions: crate::serde::de::SeqAccess::next_element(&mut seq)?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?,
+                })
             }
         }
 
-        struct Visitor;
+        deserializer.deserialize_struct(
+            "TopologySelectorTerm",
+            TopologySelectorTerm::FIELDS,
+            Visitor,
+        )
+    }
 
-        impl<'de> crate::serde::de::Visitor<'de> for Visitor {
-            type Value = TopologySelectorTerm;
+    #[cfg(feature = "deserialize-in-place")]
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error> where D: crate::serde::Deserializer<'de> {
+        struct InPlaceVisitor<'a, V>(&'a mut V);
+
+        impl<'de> crate::serde::de::Visitor<'de> for InPlaceVisitor<'_, TopologySelectorTerm> {
+            type Value = ();
 
             fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 f.write_str("TopologySelectorTerm")
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::MapAccess<'de> {
-                let mut value_match_label_expressions: Option<Vec<crate::api::core::v1::TopologySelectorLabelRequirement>> = None;
+                let mut has_match_label_expressions = false;
 
-                while let Some(key) = crate::serde::de::MapAccess::next_key::<Field>(&mut map)? {
+                while let Some(key) = crate::serde::de::MapAccess::next_key_seed(&mut map, crate::serde2::FieldIndex("TopologySelectorTerm", TopologySelectorTerm::FIELDS))? {
                     match key {
-                        Field::Key_match_label_expressions => value_match_label_expressions = crate::serde::de::MapAccess::next_value(&mut map)?,
-                        Field::Other => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
+                        Some(0) => { crate::serde::de::MapAccess::next_value_seed(&mut map, crate::serde2::OptionInPlace(&mut self.0.match_label_expressions))?; has_match_label_expressions = true; },
+                        _ => { let _: crate::serde::de::IgnoredAny = crate::serde::de::MapAccess::next_value(&mut map)?; },
                     }
                 }
+                if !has_match_label_expressions {
+                    self.0.match_label_expressions = None;
+                }
 
-                Ok(TopologySelectorTerm {
-                    match_label_expressions: value_match_label_expressions,
-                })
+                Ok(())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: crate::serde::de::SeqAccess<'de> {
+                crate::serde::de::SeqAccess::next_element_seed(&mut seq, crate::serde2::OptionInPlace(&mut self.0.match_label_expressions))?.ok_or_else(|| crate::serde::de::Error::invalid_length(0, &self))?;
+                Ok(())
             }
         }
 
         deserializer.deserialize_struct(
             "TopologySelectorTerm",
-            &[
-                "matchLabelExpressions",
-            ],
-            Visitor,
+            TopologySelectorTerm::FIELDS,
+            InPlaceVisitor(place),
         )
     }
 }
 
 impl crate::serde::Serialize for TopologySelectorTerm {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("TopologySelectorTerm", 1)?;
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "matchLabelExpressions", &self.match_label_expressions)?;
+            return crate::serde::ser::SerializeStruct::end(state);
+        }
+
         let mut state = serializer.serialize_struct(
             "TopologySelectorTerm",
             self.match_label_expressions.as_ref().map_or(0, |_| 1),
@@ -86,10 +124,69 @@ impl crate::serde::Serialize for TopologySelectorTerm {
     }
 }
 
+/// A builder of [`TopologySelectorTerm`]. Created with [`TopologySelectorTerm::builder`].
+#[cfg(feature = "builder")]
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct TopologySelectorTermBuilder {
+    match_label_expressions: Option<Vec<crate::api::core::v1::TopologySelectorLabelRequirement>>,
+}
+
+#[cfg(feature = "builder")]
+impl TopologySelectorTerm {
+    /// Starts building a `TopologySelectorTerm`. Its required fields must be set before [`TopologySelectorTermBuilder::build`] is called.
+    pub fn builder() -> TopologySelectorTermBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(feature = "builder")]
+impl TopologySelectorTermBuilder {
+    /// Sets [`TopologySelectorTerm::match_label_expressions`].
+    pub fn match_label_expressions(mut self, value: impl Into<Vec<crate::api::core::v1::TopologySelectorLabelRequirement>>) -> Self {
+        self.match_label_expressions = Some(value.into());
+        self
+    }
+
+    /// Builds the `TopologySelectorTerm`. Fails if any of its required fields has not been set.
+    pub fn build(self) -> Result<TopologySelectorTerm, crate::MissingFieldError> {
+        Ok(TopologySelectorTerm {
+            match_label_expressions: self.match_label_expressions,
+        })
+    }
+}
+
+/// The apply configuration of [`TopologySelectorTerm`] for server-side apply. All of its fields are optional, and only the fields that are set are serialized.
+#[cfg(feature = "apply-configurations")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TopologySelectorTermApplyConfiguration {
+    /// See [`TopologySelectorTerm::match_label_expressions`].
+    pub match_label_expressions: Option<Vec<crate::api::core::v1::TopologySelectorLabelRequirementApplyConfiguration>>,
+}
+
+#[cfg(feature = "apply-configurations")]
+impl crate::serde::Serialize for TopologySelectorTermApplyConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "TopologySelectorTermApplyConfiguration",
+            self.match_label_expressions.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.match_label_expressions {
+            crate::serde::ser::SerializeStruct::serialize_field(&mut state, "matchLabelExpressions", value)?;
+        }
+        crate::serde::ser::SerializeStruct::end(state)
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for TopologySelectorTerm {
     fn schema_name() -> String {
-        "io.k8s.api.core.v1.TopologySelectorTerm".to_owned()
+        if cfg!(feature = "schemars-rust-names") {
+            "api::core::v1::TopologySelectorTerm".to_owned()
+        }
+        else {
+            "io.k8s.api.core.v1.TopologySelectorTerm".to_owned()
+        }
     }
 
     fn json_schema(__gen: &mut crate::schemars::gen::SchemaGenerator) -> crate::schemars::schema::Schema {
@@ -100,8 +197,9 @@ impl crate::schemars::JsonSchema for TopologySelectorTerm {
             })),
             instance_type: Some(crate::schemars::schema::SingleOrVec::Single(Box::new(crate::schemars::schema::InstanceType::Object))),
             object: Some(Box::new(crate::schemars::schema::ObjectValidation {
-                properties: std::array::IntoIter::new([
-                    (
+                properties: {
+                    let mut properties = crate::schemars::Map::new();
+                    properties.insert(
                         "matchLabelExpressions".to_owned(),
                         crate::schemars::schema::Schema::Object(crate::schemars::schema::SchemaObject {
                             metadata: Some(Box::new(crate::schemars::schema::Metadata {
@@ -115,11 +213,21 @@ impl crate::schemars::JsonSchema for TopologySelectorTerm {
                             })),
                             ..Default::default()
                         }),
-                    ),
-                ]).collect(),
+                    );
+                    properties
+                },
                 ..Default::default()
             })),
             ..Default::default()
         })
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> crate::arbitrary::Arbitrary<'a> for TopologySelectorTerm {
+    fn arbitrary(u: &mut crate::arbitrary::Unstructured<'a>) -> crate::arbitrary::Result<Self> {
+        crate::arbitrary2::nested(u, |u| Ok(TopologySelectorTerm {
+            match_label_expressions: crate::arbitrary2::option(u, |u| crate::arbitrary2::vec(u, crate::arbitrary::Arbitrary::arbitrary))?,
+        }))
+    }
+}